@@ -1,6 +1,7 @@
 use std::net::{IpAddr, SocketAddr};
 use std::str::FromStr;
 
+use chrono::{DateTime, Utc};
 use ipnet::IpNet;
 use qryvanta_application::WorkflowExecutionMode;
 use qryvanta_core::{AppError, SecretFingerprintRecord, TenantId};
@@ -20,6 +21,9 @@ pub enum EmailProviderConfig {
     Smtp(SmtpRuntimeConfig),
 }
 
+/// Upper bound for `AUTH_BOOTSTRAP_TOKEN_TTL_SECONDS` (30 days).
+pub const MAX_BOOTSTRAP_TOKEN_TTL_SECONDS: u64 = 30 * 24 * 60 * 60;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RateLimitStoreConfig {
     Postgres,
@@ -32,6 +36,12 @@ pub enum WorkflowQueueStatsCacheBackend {
     Redis,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeViewCacheBackend {
+    InMemory,
+    Redis,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SessionStoreBackend {
     Postgres,
@@ -56,6 +66,35 @@ impl PhysicalIsolationMode {
     }
 }
 
+/// Runtime hardening mode selected with `ENVIRONMENT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeEnvironment {
+    Development,
+    Production,
+}
+
+impl RuntimeEnvironment {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Development => "development",
+            Self::Production => "production",
+        }
+    }
+
+    #[must_use]
+    pub fn is_production(self) -> bool {
+        matches!(self, Self::Production)
+    }
+}
+
+/// Token of one operator allowed to call the cross-tenant operator console.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorCredential {
+    pub operator_id: String,
+    pub token: String,
+}
+
 #[derive(Debug, Clone)]
 pub enum TotpEncryptionConfig {
     StaticKey {
@@ -70,9 +109,11 @@ pub enum TotpEncryptionConfig {
 #[derive(Debug, Clone)]
 pub struct ApiConfig {
     pub migrate_only: bool,
+    pub environment: RuntimeEnvironment,
     pub database_url: String,
     pub frontend_url: String,
     pub bootstrap_token: String,
+    pub bootstrap_token_ttl_seconds: u64,
     pub _session_secret: String,
     pub api_host: String,
     pub api_port: u16,
@@ -87,19 +128,37 @@ pub struct ApiConfig {
     pub email_provider: EmailProviderConfig,
     pub workflow_execution_mode: WorkflowExecutionMode,
     pub worker_shared_secret: Option<String>,
+    pub operator_credentials: Vec<OperatorCredential>,
     pub redis_url: Option<String>,
     pub rate_limit_store: RateLimitStoreConfig,
     pub workflow_queue_stats_cache_backend: WorkflowQueueStatsCacheBackend,
     pub workflow_worker_default_lease_seconds: u32,
     pub workflow_worker_max_claim_limit: usize,
     pub workflow_worker_max_partition_count: u32,
+    pub workflow_worker_tenant_claim_limit: usize,
+    pub workflow_worker_backpressure_retry_after_ms: u64,
+    pub workflow_worker_rate_limit_burst: u32,
+    pub workflow_worker_rate_limit_per_second: u32,
     pub workflow_queue_stats_cache_ttl_seconds: u32,
+    pub runtime_view_cache_backend: RuntimeViewCacheBackend,
+    pub runtime_view_cache_ttl_seconds: u32,
     pub runtime_query_max_limit: usize,
     pub runtime_query_max_in_flight: usize,
     pub workflow_burst_max_in_flight: usize,
     pub audit_immutable_mode: bool,
+    pub audit_outbox_relay_interval_ms: u64,
+    pub audit_outbox_relay_batch_size: usize,
+    pub background_job_poll_interval_ms: u64,
+    pub report_subscription_poll_interval_ms: u64,
+    pub report_subscription_tenant_limit: usize,
+    pub audit_export_poll_interval_ms: u64,
+    pub audit_export_batch_size: usize,
+    pub audit_export_file_root: Option<String>,
     pub slow_request_threshold_ms: u64,
     pub slow_query_threshold_ms: u64,
+    pub runtime_query_timeout_ms: u64,
+    pub workflow_dispatch_timeout_ms: u64,
+    pub publish_timeout_ms: u64,
     pub physical_isolation_mode: PhysicalIsolationMode,
     pub physical_isolation_tenant_id: Option<TenantId>,
     pub physical_isolation_schema_template: Option<String>,
@@ -109,6 +168,11 @@ pub struct ApiConfig {
     pub qrywell_sync_poll_interval_ms: u64,
     pub qrywell_sync_batch_size: usize,
     pub qrywell_sync_max_attempts: i32,
+    pub openapi_swagger_ui_enabled: bool,
+    pub release_advisory_url: Option<String>,
+    pub release_advisory_cache_ttl_seconds: u64,
+    pub api_v1_deprecated_at: Option<DateTime<Utc>>,
+    pub api_v1_sunset_at: Option<DateTime<Utc>>,
 }
 
 impl ApiConfig {
@@ -119,6 +183,10 @@ impl ApiConfig {
                 self.workflow_queue_stats_cache_backend,
                 WorkflowQueueStatsCacheBackend::Redis
             )
+            || matches!(
+                self.runtime_view_cache_backend,
+                RuntimeViewCacheBackend::Redis
+            )
             || matches!(self.session_store_backend, SessionStoreBackend::Redis)
     }
 
@@ -176,10 +244,94 @@ impl ApiConfig {
             ));
         }
 
+        for credential in &self.operator_credentials {
+            records.push(SecretFingerprintRecord::from_secret(
+                environment,
+                format!("OPERATOR_API_TOKENS:{}", credential.operator_id).as_str(),
+                credential.token.as_str(),
+            ));
+        }
+
         records
     }
 }
 
+#[cfg(test)]
+impl ApiConfig {
+    /// Local development settings shared by unit tests.
+    pub(crate) fn test_defaults() -> Self {
+        Self {
+            migrate_only: false,
+            environment: RuntimeEnvironment::Development,
+            database_url: "postgres://localhost/qryvanta".to_owned(),
+            frontend_url: "http://localhost:3000".to_owned(),
+            bootstrap_token: "bootstrap-token-with-at-least-32-chars".to_owned(),
+            bootstrap_token_ttl_seconds: 86_400,
+            _session_secret: "session-secret-with-more-than-32-bytes".to_owned(),
+            api_host: "127.0.0.1".to_owned(),
+            api_port: 3001,
+            session_store_backend: SessionStoreBackend::Postgres,
+            webauthn_rp_id: "localhost".to_owned(),
+            webauthn_rp_origin: "http://localhost:3000".to_owned(),
+            cookie_secure: false,
+            trust_proxy_headers: false,
+            trusted_proxy_cidrs: Vec::new(),
+            bootstrap_tenant_id: None,
+            totp_encryption: TotpEncryptionConfig::StaticKey {
+                key_hex: "11".repeat(32),
+            },
+            email_provider: EmailProviderConfig::Console,
+            workflow_execution_mode: WorkflowExecutionMode::Inline,
+            worker_shared_secret: None,
+            operator_credentials: Vec::new(),
+            redis_url: None,
+            rate_limit_store: RateLimitStoreConfig::Postgres,
+            workflow_queue_stats_cache_backend: WorkflowQueueStatsCacheBackend::InMemory,
+            workflow_worker_default_lease_seconds: 30,
+            workflow_worker_max_claim_limit: 25,
+            workflow_worker_max_partition_count: 128,
+            workflow_worker_tenant_claim_limit: 0,
+            workflow_worker_backpressure_retry_after_ms: 5_000,
+            workflow_worker_rate_limit_burst: 20,
+            workflow_worker_rate_limit_per_second: 5,
+            workflow_queue_stats_cache_ttl_seconds: 0,
+            runtime_view_cache_backend: RuntimeViewCacheBackend::InMemory,
+            runtime_view_cache_ttl_seconds: 0,
+            runtime_query_max_limit: 200,
+            runtime_query_max_in_flight: 64,
+            workflow_burst_max_in_flight: 32,
+            audit_immutable_mode: false,
+            audit_outbox_relay_interval_ms: 1_000,
+            audit_outbox_relay_batch_size: 100,
+            background_job_poll_interval_ms: 1_000,
+            report_subscription_poll_interval_ms: 60_000,
+            report_subscription_tenant_limit: 100,
+            audit_export_poll_interval_ms: 5_000,
+            audit_export_batch_size: 500,
+            audit_export_file_root: None,
+            slow_request_threshold_ms: 1_000,
+            slow_query_threshold_ms: 250,
+            runtime_query_timeout_ms: 30_000,
+            workflow_dispatch_timeout_ms: 30_000,
+            publish_timeout_ms: 120_000,
+            physical_isolation_mode: PhysicalIsolationMode::Shared,
+            physical_isolation_tenant_id: None,
+            physical_isolation_schema_template: None,
+            physical_isolation_database_url_template: None,
+            qrywell_api_base_url: None,
+            qrywell_api_key: None,
+            qrywell_sync_poll_interval_ms: 3_000,
+            qrywell_sync_batch_size: 25,
+            qrywell_sync_max_attempts: 12,
+            openapi_swagger_ui_enabled: false,
+            release_advisory_url: None,
+            release_advisory_cache_ttl_seconds: 21_600,
+            api_v1_deprecated_at: None,
+            api_v1_sunset_at: None,
+        }
+    }
+}
+
 mod load;
 mod tracing;
 
@@ -4,12 +4,22 @@ use qryvanta_application::WorkflowExecutionMode;
 use qryvanta_core::AppError;
 
 use crate::api_config::{
-    EmailProviderConfig, RateLimitStoreConfig, SessionStoreBackend, SmtpRuntimeConfig,
-    WorkflowQueueStatsCacheBackend,
+    EmailProviderConfig, RateLimitStoreConfig, RuntimeEnvironment, RuntimeViewCacheBackend,
+    SessionStoreBackend, SmtpRuntimeConfig, WorkflowQueueStatsCacheBackend,
 };
 
 use super::env_parse::required_non_empty_env;
 
+pub(super) fn parse_runtime_environment() -> Result<RuntimeEnvironment, AppError> {
+    match env::var("ENVIRONMENT").unwrap_or_else(|_| "development".to_owned()) {
+        value if value.eq_ignore_ascii_case("development") => Ok(RuntimeEnvironment::Development),
+        value if value.eq_ignore_ascii_case("production") => Ok(RuntimeEnvironment::Production),
+        other => Err(AppError::Validation(format!(
+            "ENVIRONMENT must be either 'development' or 'production', got '{other}'"
+        ))),
+    }
+}
+
 pub(super) fn parse_session_store_backend() -> Result<SessionStoreBackend, AppError> {
     match env::var("SESSION_STORE").unwrap_or_else(|_| "postgres".to_owned()) {
         value if value.eq_ignore_ascii_case("postgres") => Ok(SessionStoreBackend::Postgres),
@@ -77,3 +87,13 @@ pub(super) fn parse_workflow_queue_stats_cache_backend()
         ))),
     }
 }
+
+pub(super) fn parse_runtime_view_cache_backend() -> Result<RuntimeViewCacheBackend, AppError> {
+    match env::var("RUNTIME_VIEW_CACHE_BACKEND").unwrap_or_else(|_| "in_memory".to_owned()) {
+        value if value.eq_ignore_ascii_case("in_memory") => Ok(RuntimeViewCacheBackend::InMemory),
+        value if value.eq_ignore_ascii_case("redis") => Ok(RuntimeViewCacheBackend::Redis),
+        other => Err(AppError::Validation(format!(
+            "RUNTIME_VIEW_CACHE_BACKEND must be either 'in_memory' or 'redis', got '{other}'"
+        ))),
+    }
+}
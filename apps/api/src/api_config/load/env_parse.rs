@@ -1,5 +1,6 @@
 use std::env;
 
+use chrono::{DateTime, Utc};
 use qryvanta_core::{
     AppError, TenantId, optional_secret, required_non_empty_secret, required_secret,
 };
@@ -26,6 +27,16 @@ pub(super) fn parse_optional_tenant_id_env(name: &str) -> Result<Option<TenantId
         .transpose()
 }
 
+pub(super) fn parse_optional_datetime_env(name: &str) -> Result<Option<DateTime<Utc>>, AppError> {
+    parse_optional_non_empty_env(name)?
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value.as_str())
+                .map(|value| value.with_timezone(&Utc))
+                .map_err(|error| AppError::Validation(format!("invalid {name}: {error}")))
+        })
+        .transpose()
+}
+
 pub(super) fn parse_env_u32(name: &str, default: u32) -> Result<u32, AppError> {
     match env::var(name) {
         Ok(value) => value.parse::<u32>().map_err(|error| {
@@ -3,33 +3,38 @@ use std::net::IpAddr;
 use std::str::FromStr;
 
 use ipnet::IpNet;
-use qryvanta_application::WorkflowExecutionMode;
+use qryvanta_application::{DEFAULT_REPORT_SUBSCRIPTION_TENANT_LIMIT, WorkflowExecutionMode};
 use qryvanta_core::{AppError, SecretFingerprintRecord, detect_reused_secret_fingerprints};
 
 use self::choices::{
-    parse_email_provider_config, parse_rate_limit_store, parse_session_store_backend,
-    parse_workflow_execution_mode, parse_workflow_queue_stats_cache_backend,
+    parse_email_provider_config, parse_rate_limit_store, parse_runtime_environment,
+    parse_runtime_view_cache_backend, parse_session_store_backend, parse_workflow_execution_mode,
+    parse_workflow_queue_stats_cache_backend,
 };
 use self::env_parse::{
     parse_env_bool, parse_env_i32, parse_env_u32, parse_env_u64, parse_env_usize,
-    parse_optional_non_empty_env, parse_optional_tenant_id_env, required_env,
-    required_non_empty_env,
+    parse_optional_datetime_env, parse_optional_non_empty_env, parse_optional_tenant_id_env,
+    required_env, required_non_empty_env,
 };
 use self::isolation::{parse_physical_isolation_mode, validate_physical_isolation_config};
-use self::validation::validate_backpressure_config;
+use self::production::validate_production_config;
+use self::validation::{validate_api_version_schedule, validate_backpressure_config};
 use super::{
-    ApiConfig, RateLimitStoreConfig, SessionStoreBackend, TotpEncryptionConfig,
+    ApiConfig, MAX_BOOTSTRAP_TOKEN_TTL_SECONDS, OperatorCredential, RateLimitStoreConfig,
+    RuntimeViewCacheBackend, SessionStoreBackend, TotpEncryptionConfig,
     WorkflowQueueStatsCacheBackend,
 };
 
 mod choices;
 mod env_parse;
 mod isolation;
+mod production;
 mod validation;
 
 impl ApiConfig {
     pub fn load() -> Result<Self, AppError> {
         let migrate_only = env::args().nth(1).as_deref() == Some("migrate");
+        let environment = parse_runtime_environment()?;
 
         let database_url = required_env("DATABASE_URL")?;
         let frontend_url =
@@ -72,6 +77,10 @@ impl ApiConfig {
         let workflow_execution_mode = parse_workflow_execution_mode()?;
 
         let worker_shared_secret = parse_optional_non_empty_env("WORKER_SHARED_SECRET")?;
+        let operator_credentials = parse_operator_credentials(
+            parse_optional_non_empty_env("OPERATOR_API_TOKENS")?.as_deref(),
+            worker_shared_secret.as_deref(),
+        )?;
         let deployment_environment = parse_optional_non_empty_env("DEPLOYMENT_ENVIRONMENT")?
             .map(|value| value.trim().to_owned());
         let secret_reuse_guard_records = parse_secret_reuse_guard_records()?;
@@ -84,11 +93,13 @@ impl ApiConfig {
                 session_secret.as_str(),
                 &totp_encryption,
                 worker_shared_secret.as_deref(),
+                operator_credentials.as_slice(),
             ),
         )?;
         let redis_url = parse_optional_non_empty_env("REDIS_URL")?;
         let rate_limit_store = parse_rate_limit_store()?;
         let workflow_queue_stats_cache_backend = parse_workflow_queue_stats_cache_backend()?;
+        let runtime_view_cache_backend = parse_runtime_view_cache_backend()?;
 
         if matches!(workflow_execution_mode, WorkflowExecutionMode::Queued)
             && worker_shared_secret.is_none()
@@ -104,19 +115,60 @@ impl ApiConfig {
             parse_env_usize("WORKFLOW_WORKER_MAX_CLAIM_LIMIT", 25)?;
         let workflow_worker_max_partition_count =
             parse_env_u32("WORKFLOW_WORKER_MAX_PARTITION_COUNT", 128)?;
+        let workflow_worker_tenant_claim_limit =
+            parse_env_usize("WORKFLOW_WORKER_TENANT_CLAIM_LIMIT", 0)?;
+        let workflow_worker_backpressure_retry_after_ms =
+            parse_env_u64("WORKFLOW_WORKER_BACKPRESSURE_RETRY_AFTER_MS", 5_000)?;
+        let workflow_worker_rate_limit_burst =
+            parse_env_u32("WORKFLOW_WORKER_RATE_LIMIT_BURST", 20)?;
+        let workflow_worker_rate_limit_per_second =
+            parse_env_u32("WORKFLOW_WORKER_RATE_LIMIT_PER_SECOND", 5)?;
         let workflow_queue_stats_cache_ttl_seconds =
             parse_env_u32("WORKFLOW_QUEUE_STATS_CACHE_TTL_SECONDS", 0)?;
+        let runtime_view_cache_ttl_seconds = parse_env_u32("RUNTIME_VIEW_CACHE_TTL_SECONDS", 0)?;
         let runtime_query_max_limit = parse_env_usize("RUNTIME_QUERY_MAX_LIMIT", 200)?;
         let runtime_query_max_in_flight = parse_env_usize("RUNTIME_QUERY_MAX_IN_FLIGHT", 64)?;
         let workflow_burst_max_in_flight = parse_env_usize("WORKFLOW_BURST_MAX_IN_FLIGHT", 32)?;
         let audit_immutable_mode = parse_env_bool("AUDIT_IMMUTABLE_MODE", false)?;
+        let audit_outbox_relay_interval_ms = parse_env_u64("AUDIT_OUTBOX_RELAY_INTERVAL_MS", 1000)?;
+        let audit_outbox_relay_batch_size = parse_env_usize("AUDIT_OUTBOX_RELAY_BATCH_SIZE", 100)?;
+        let background_job_poll_interval_ms =
+            parse_env_u64("BACKGROUND_JOB_POLL_INTERVAL_MS", 1000)?;
+        let report_subscription_poll_interval_ms =
+            parse_env_u64("REPORT_SUBSCRIPTION_POLL_INTERVAL_MS", 60_000)?;
+        let report_subscription_tenant_limit = parse_env_usize(
+            "REPORT_SUBSCRIPTION_TENANT_LIMIT",
+            DEFAULT_REPORT_SUBSCRIPTION_TENANT_LIMIT,
+        )?;
+        let audit_export_poll_interval_ms = parse_env_u64("AUDIT_EXPORT_POLL_INTERVAL_MS", 5_000)?;
+        let audit_export_batch_size = parse_env_usize("AUDIT_EXPORT_BATCH_SIZE", 500)?;
+        let audit_export_file_root = parse_optional_non_empty_env("AUDIT_EXPORT_FILE_ROOT")?;
         let slow_request_threshold_ms = parse_env_u64("SLOW_REQUEST_THRESHOLD_MS", 1000)?;
         let slow_query_threshold_ms = parse_env_u64("SLOW_QUERY_THRESHOLD_MS", 250)?;
+        let runtime_query_timeout_ms = parse_env_u64("RUNTIME_QUERY_TIMEOUT_MS", 30_000)?;
+        let workflow_dispatch_timeout_ms = parse_env_u64("WORKFLOW_DISPATCH_TIMEOUT_MS", 30_000)?;
+        let publish_timeout_ms = parse_env_u64("PUBLISH_TIMEOUT_MS", 120_000)?;
         let qrywell_api_base_url = parse_optional_non_empty_env("QRYWELL_API_BASE_URL")?;
         let qrywell_api_key = parse_optional_non_empty_env("QRYWELL_API_KEY")?;
         let qrywell_sync_poll_interval_ms = parse_env_u64("QRYWELL_SYNC_POLL_INTERVAL_MS", 3000)?;
         let qrywell_sync_batch_size = parse_env_usize("QRYWELL_SYNC_BATCH_SIZE", 25)?;
         let qrywell_sync_max_attempts = parse_env_i32("QRYWELL_SYNC_MAX_ATTEMPTS", 12)?;
+        let openapi_swagger_ui_enabled = parse_env_bool("OPENAPI_SWAGGER_UI_ENABLED", false)?;
+        let release_advisory_url = parse_optional_non_empty_env("RELEASE_ADVISORY_URL")?;
+        let bootstrap_token_ttl_seconds =
+            parse_env_u64("AUTH_BOOTSTRAP_TOKEN_TTL_SECONDS", 86_400)?;
+        if bootstrap_token_ttl_seconds == 0
+            || bootstrap_token_ttl_seconds > MAX_BOOTSTRAP_TOKEN_TTL_SECONDS
+        {
+            return Err(AppError::Validation(format!(
+                "AUTH_BOOTSTRAP_TOKEN_TTL_SECONDS must be between 1 and {MAX_BOOTSTRAP_TOKEN_TTL_SECONDS}"
+            )));
+        }
+        let release_advisory_cache_ttl_seconds =
+            parse_env_u64("RELEASE_ADVISORY_CACHE_TTL_SECONDS", 21_600)?;
+        let api_v1_deprecated_at = parse_optional_datetime_env("API_V1_DEPRECATED_AT")?;
+        let api_v1_sunset_at = parse_optional_datetime_env("API_V1_SUNSET_AT")?;
+        validate_api_version_schedule(api_v1_deprecated_at, api_v1_sunset_at)?;
         let physical_isolation_mode = parse_physical_isolation_mode(
             env::var("PHYSICAL_ISOLATION_MODE")
                 .unwrap_or_else(|_| "shared".to_owned())
@@ -135,6 +187,21 @@ impl ApiConfig {
             physical_isolation_database_url_template.as_deref(),
         )?;
 
+        if audit_outbox_relay_batch_size == 0 {
+            return Err(AppError::Validation(
+                "AUDIT_OUTBOX_RELAY_BATCH_SIZE must be greater than zero".to_owned(),
+            ));
+        }
+        if audit_export_batch_size == 0 || audit_export_batch_size > 5_000 {
+            return Err(AppError::Validation(
+                "AUDIT_EXPORT_BATCH_SIZE must be between 1 and 5000".to_owned(),
+            ));
+        }
+        if report_subscription_tenant_limit == 0 {
+            return Err(AppError::Validation(
+                "REPORT_SUBSCRIPTION_TENANT_LIMIT must be greater than zero".to_owned(),
+            ));
+        }
         if qrywell_sync_batch_size == 0 {
             return Err(AppError::Validation(
                 "QRYWELL_SYNC_BATCH_SIZE must be greater than zero".to_owned(),
@@ -151,6 +218,11 @@ impl ApiConfig {
                 "WORKFLOW_WORKER_MAX_PARTITION_COUNT must be greater than zero".to_owned(),
             ));
         }
+        if workflow_worker_rate_limit_burst > 0 && workflow_worker_rate_limit_per_second == 0 {
+            return Err(AppError::Validation(
+                "WORKFLOW_WORKER_RATE_LIMIT_PER_SECOND must be greater than zero when WORKFLOW_WORKER_RATE_LIMIT_BURST is set".to_owned(),
+            ));
+        }
         validate_backpressure_config(
             runtime_query_max_limit,
             runtime_query_max_in_flight,
@@ -162,19 +234,22 @@ impl ApiConfig {
                 workflow_queue_stats_cache_backend,
                 WorkflowQueueStatsCacheBackend::Redis
             )
+            || matches!(runtime_view_cache_backend, RuntimeViewCacheBackend::Redis)
             || matches!(session_store_backend, SessionStoreBackend::Redis);
         if redis_required && redis_url.is_none() {
             return Err(AppError::Validation(
-                "REDIS_URL is required when RATE_LIMIT_STORE=redis or WORKFLOW_QUEUE_STATS_CACHE_BACKEND=redis"
+                "REDIS_URL is required when RATE_LIMIT_STORE=redis, WORKFLOW_QUEUE_STATS_CACHE_BACKEND=redis, or RUNTIME_VIEW_CACHE_BACKEND=redis"
                     .to_owned(),
             ));
         }
 
-        Ok(Self {
+        let config = Self {
             migrate_only,
+            environment,
             database_url,
             frontend_url,
             bootstrap_token,
+            bootstrap_token_ttl_seconds,
             _session_secret: session_secret,
             api_host,
             api_port,
@@ -189,19 +264,37 @@ impl ApiConfig {
             email_provider,
             workflow_execution_mode,
             worker_shared_secret,
+            operator_credentials,
             redis_url,
             rate_limit_store,
             workflow_queue_stats_cache_backend,
             workflow_worker_default_lease_seconds,
             workflow_worker_max_claim_limit,
             workflow_worker_max_partition_count,
+            workflow_worker_tenant_claim_limit,
+            workflow_worker_backpressure_retry_after_ms,
+            workflow_worker_rate_limit_burst,
+            workflow_worker_rate_limit_per_second,
             workflow_queue_stats_cache_ttl_seconds,
+            runtime_view_cache_backend,
+            runtime_view_cache_ttl_seconds,
             runtime_query_max_limit,
             runtime_query_max_in_flight,
             workflow_burst_max_in_flight,
             audit_immutable_mode,
+            audit_outbox_relay_interval_ms,
+            audit_outbox_relay_batch_size,
+            background_job_poll_interval_ms,
+            report_subscription_poll_interval_ms,
+            report_subscription_tenant_limit,
+            audit_export_poll_interval_ms,
+            audit_export_batch_size,
+            audit_export_file_root,
             slow_request_threshold_ms,
             slow_query_threshold_ms,
+            runtime_query_timeout_ms,
+            workflow_dispatch_timeout_ms,
+            publish_timeout_ms,
             physical_isolation_mode,
             physical_isolation_tenant_id,
             physical_isolation_schema_template,
@@ -211,7 +304,18 @@ impl ApiConfig {
             qrywell_sync_poll_interval_ms,
             qrywell_sync_batch_size,
             qrywell_sync_max_attempts,
-        })
+            openapi_swagger_ui_enabled,
+            release_advisory_url,
+            release_advisory_cache_ttl_seconds,
+            api_v1_deprecated_at,
+            api_v1_sunset_at,
+        };
+
+        if config.environment.is_production() {
+            validate_production_config(&config)?;
+        }
+
+        Ok(config)
     }
 }
 
@@ -344,6 +448,7 @@ fn build_api_secret_fingerprint_records(
     session_secret: &str,
     totp_encryption: &TotpEncryptionConfig,
     worker_shared_secret: Option<&str>,
+    operator_credentials: &[OperatorCredential],
 ) -> Vec<SecretFingerprintRecord> {
     let Some(deployment_environment) = deployment_environment else {
         return Vec::new();
@@ -392,9 +497,67 @@ fn build_api_secret_fingerprint_records(
         ));
     }
 
+    for credential in operator_credentials {
+        records.push(SecretFingerprintRecord::from_secret(
+            deployment_environment,
+            format!("OPERATOR_API_TOKENS:{}", credential.operator_id).as_str(),
+            credential.token.as_str(),
+        ));
+    }
+
     records
 }
 
+/// Parses comma-separated `operator_id:token` entries.
+///
+/// Operator tokens must differ from the worker shared secret so operator
+/// access can be granted and revoked independently of workers.
+fn parse_operator_credentials(
+    raw_value: Option<&str>,
+    worker_shared_secret: Option<&str>,
+) -> Result<Vec<OperatorCredential>, AppError> {
+    let Some(raw_value) = raw_value else {
+        return Ok(Vec::new());
+    };
+
+    let mut credentials: Vec<OperatorCredential> = Vec::new();
+    for entry in raw_value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+    {
+        let (operator_id, token) = entry
+            .split_once(':')
+            .map(|(operator_id, token)| (operator_id.trim(), token.trim()))
+            .filter(|(operator_id, token)| !operator_id.is_empty() && !token.is_empty())
+            .ok_or_else(|| {
+                AppError::Validation(
+                    "OPERATOR_API_TOKENS entries must use the form operator_id:token".to_owned(),
+                )
+            })?;
+        if credentials
+            .iter()
+            .any(|credential| credential.operator_id == operator_id)
+        {
+            return Err(AppError::Validation(format!(
+                "OPERATOR_API_TOKENS lists operator '{operator_id}' more than once"
+            )));
+        }
+        if worker_shared_secret == Some(token) {
+            return Err(AppError::Validation(format!(
+                "OPERATOR_API_TOKENS token of operator '{operator_id}' must differ from WORKER_SHARED_SECRET"
+            )));
+        }
+
+        credentials.push(OperatorCredential {
+            operator_id: operator_id.to_owned(),
+            token: token.to_owned(),
+        });
+    }
+
+    Ok(credentials)
+}
+
 #[cfg(test)]
 mod tests {
     use super::isolation::{parse_physical_isolation_mode, validate_physical_isolation_config};
@@ -453,6 +616,40 @@ mod tests {
         assert!(missing_placeholder.is_err());
     }
 
+    #[test]
+    fn operator_credentials_parse_entries_and_reject_shared_tokens() {
+        let credentials = parse_operator_credentials(
+            Some(" ops-alice:alice-token , ops-bob:bob-token,"),
+            Some("worker-secret"),
+        )
+        .unwrap_or_else(|_| unreachable!());
+        assert_eq!(
+            credentials,
+            vec![
+                OperatorCredential {
+                    operator_id: "ops-alice".to_owned(),
+                    token: "alice-token".to_owned(),
+                },
+                OperatorCredential {
+                    operator_id: "ops-bob".to_owned(),
+                    token: "bob-token".to_owned(),
+                },
+            ]
+        );
+        assert_eq!(
+            parse_operator_credentials(None, None).ok(),
+            Some(Vec::new())
+        );
+
+        assert!(parse_operator_credentials(Some("ops-alice"), None).is_err());
+        assert!(parse_operator_credentials(Some(":token"), None).is_err());
+        assert!(parse_operator_credentials(Some("ops-alice:a,ops-alice:b"), None).is_err());
+        assert!(
+            parse_operator_credentials(Some("ops-alice:worker-secret"), Some("worker-secret"))
+                .is_err()
+        );
+    }
+
     #[test]
     fn backpressure_config_requires_positive_limits() {
         assert!(validate_backpressure_config(200, 64, 32).is_ok());
@@ -461,6 +658,20 @@ mod tests {
         assert!(validate_backpressure_config(200, 64, 0).is_err());
     }
 
+    #[test]
+    fn api_version_schedule_requires_sunset_after_deprecation() {
+        let deprecated_at = chrono::DateTime::parse_from_rfc3339("2027-01-01T00:00:00Z")
+            .map(|value| value.with_timezone(&chrono::Utc))
+            .unwrap_or_else(|_| unreachable!());
+        let sunset_at = deprecated_at + chrono::Duration::days(180);
+
+        assert!(validate_api_version_schedule(None, None).is_ok());
+        assert!(validate_api_version_schedule(Some(deprecated_at), None).is_ok());
+        assert!(validate_api_version_schedule(Some(deprecated_at), Some(sunset_at)).is_ok());
+        assert!(validate_api_version_schedule(None, Some(sunset_at)).is_err());
+        assert!(validate_api_version_schedule(Some(sunset_at), Some(deprecated_at)).is_err());
+    }
+
     #[test]
     fn totp_encryption_key_rejects_all_zero_placeholder() {
         let result = validate_totp_encryption_key(&"0".repeat(64));
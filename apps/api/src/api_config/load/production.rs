@@ -0,0 +1,139 @@
+use qryvanta_core::AppError;
+
+use crate::api_config::{ApiConfig, EmailProviderConfig, TotpEncryptionConfig};
+
+/// Prefix shared by every placeholder value in `.env.example`.
+const PLACEHOLDER_PREFIX: &str = "replace-with-";
+
+/// Rejects insecure settings when `ENVIRONMENT=production`.
+///
+/// Every violation is reported at once so operators can fix them in one pass.
+pub(super) fn validate_production_config(config: &ApiConfig) -> Result<(), AppError> {
+    let mut violations = Vec::new();
+
+    if !config.cookie_secure {
+        violations.push("SESSION_COOKIE_SECURE must be true".to_owned());
+    }
+
+    if matches!(config.email_provider, EmailProviderConfig::Console) {
+        violations.push(
+            "EMAIL_PROVIDER=console is not allowed; configure EMAIL_PROVIDER=smtp".to_owned(),
+        );
+    }
+
+    let static_totp_key = match &config.totp_encryption {
+        TotpEncryptionConfig::StaticKey { key_hex } => Some(key_hex.as_str()),
+        TotpEncryptionConfig::AwsKmsEnvelope {
+            legacy_static_key_hex,
+            ..
+        } => legacy_static_key_hex.as_deref(),
+    };
+    if let Some(key_hex) = static_totp_key
+        && !is_strong_totp_key(key_hex)
+    {
+        violations.push(
+            "TOTP_ENCRYPTION_KEY must be a generated 64-char hex key, not a default or placeholder"
+                .to_owned(),
+        );
+    }
+
+    let secrets = [
+        (
+            "AUTH_BOOTSTRAP_TOKEN",
+            Some(config.bootstrap_token.as_str()),
+        ),
+        ("SESSION_SECRET", Some(config._session_secret.as_str())),
+        (
+            "WORKER_SHARED_SECRET",
+            config.worker_shared_secret.as_deref(),
+        ),
+    ];
+    for (name, value) in secrets {
+        if value.is_some_and(|value| value.starts_with(PLACEHOLDER_PREFIX)) {
+            violations.push(format!("{name} still uses the example placeholder"));
+        }
+    }
+
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    Err(AppError::Validation(format!(
+        "ENVIRONMENT=production refuses insecure settings: {}",
+        violations.join("; ")
+    )))
+}
+
+fn is_strong_totp_key(key_hex: &str) -> bool {
+    let Some(first) = key_hex.chars().next() else {
+        return false;
+    };
+
+    key_hex.len() == 64
+        && key_hex
+            .chars()
+            .all(|character| character.is_ascii_hexdigit())
+        && !key_hex.chars().all(|character| character == first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_config::SmtpRuntimeConfig;
+
+    fn hardened(mut config: ApiConfig) -> ApiConfig {
+        config.cookie_secure = true;
+        config.email_provider = EmailProviderConfig::Smtp(SmtpRuntimeConfig {
+            host: "smtp.example.com".to_owned(),
+            port: 587,
+            username: "qryvanta".to_owned(),
+            password: "smtp-password".to_owned(),
+            from_address: "noreply@example.com".to_owned(),
+        });
+        config.totp_encryption = TotpEncryptionConfig::StaticKey {
+            key_hex: "0123456789abcdef".repeat(4),
+        };
+        config
+    }
+
+    #[test]
+    fn hardened_settings_pass_production_validation() {
+        let config = hardened(ApiConfig::test_defaults());
+        assert!(validate_production_config(&config).is_ok());
+    }
+
+    #[test]
+    fn production_validation_lists_every_insecure_setting() {
+        let mut config = ApiConfig::test_defaults();
+        config.cookie_secure = false;
+        config.email_provider = EmailProviderConfig::Console;
+        config.totp_encryption = TotpEncryptionConfig::StaticKey {
+            key_hex: "1".repeat(64),
+        };
+        config.bootstrap_token = "replace-with-strong-bootstrap-token".to_owned();
+
+        let Err(AppError::Validation(message)) = validate_production_config(&config) else {
+            unreachable!();
+        };
+        assert!(message.contains("SESSION_COOKIE_SECURE must be true"));
+        assert!(message.contains("EMAIL_PROVIDER=console is not allowed"));
+        assert!(message.contains("TOTP_ENCRYPTION_KEY must be a generated 64-char hex key"));
+        assert!(message.contains("AUTH_BOOTSTRAP_TOKEN still uses the example placeholder"));
+    }
+
+    #[test]
+    fn placeholder_totp_key_is_rejected_even_as_kms_fallback() {
+        let mut config = hardened(ApiConfig::test_defaults());
+        config.totp_encryption = TotpEncryptionConfig::AwsKmsEnvelope {
+            kms_key_id: "alias/qryvanta".to_owned(),
+            legacy_static_key_hex: Some("replace-with-64-char-hex-key".to_owned()),
+        };
+        assert!(validate_production_config(&config).is_err());
+
+        config.totp_encryption = TotpEncryptionConfig::AwsKmsEnvelope {
+            kms_key_id: "alias/qryvanta".to_owned(),
+            legacy_static_key_hex: None,
+        };
+        assert!(validate_production_config(&config).is_ok());
+    }
+}
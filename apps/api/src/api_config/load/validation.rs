@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use qryvanta_core::AppError;
 
 pub(super) fn validate_backpressure_config(
@@ -23,3 +24,24 @@ pub(super) fn validate_backpressure_config(
 
     Ok(())
 }
+
+pub(super) fn validate_api_version_schedule(
+    deprecated_at: Option<DateTime<Utc>>,
+    sunset_at: Option<DateTime<Utc>>,
+) -> Result<(), AppError> {
+    let Some(sunset_at) = sunset_at else {
+        return Ok(());
+    };
+    let Some(deprecated_at) = deprecated_at else {
+        return Err(AppError::Validation(
+            "API_V1_SUNSET_AT requires API_V1_DEPRECATED_AT".to_owned(),
+        ));
+    };
+    if sunset_at <= deprecated_at {
+        return Err(AppError::Validation(
+            "API_V1_SUNSET_AT must be later than API_V1_DEPRECATED_AT".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
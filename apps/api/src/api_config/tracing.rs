@@ -1,11 +1,8 @@
-use tracing_subscriber::EnvFilter;
+use qryvanta_core::AppResult;
+use qryvanta_infrastructure::TracingGuard;
 
-pub fn init_tracing() {
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+const SERVICE_NAME: &str = "qryvanta-api";
 
-    tracing_subscriber::fmt()
-        .with_env_filter(env_filter)
-        .with_target(false)
-        .compact()
-        .init();
+pub fn init_tracing() -> AppResult<TracingGuard> {
+    qryvanta_infrastructure::init_tracing(SERVICE_NAME)
 }
@@ -6,9 +6,12 @@ use tower_http::trace::TraceLayer;
 use tower_sessions::{SessionManagerLayer, SessionStore};
 
 use crate::state::AppState;
-use crate::{auth, handlers, middleware};
+use crate::{auth, handlers, middleware, rate_limit_headers};
 
 mod cors;
+mod internal_ops;
+mod openapi;
+mod operator_console;
 mod protected;
 mod public_auth;
 #[cfg(test)]
@@ -16,10 +19,14 @@ mod tests;
 mod worker_internal;
 
 use cors::build_cors_layer;
+use internal_ops::build_internal_ops_routes;
+use openapi::build_openapi_routes;
+use operator_console::build_operator_console_routes;
 use protected::build_protected_routes;
 use public_auth::{
     build_forgot_password_routes, build_invite_accept_routes, build_login_routes,
-    build_register_routes,
+    build_login_tenant_routes, build_record_share_link_routes, build_register_routes,
+    build_tenant_data_export_routes, build_workflow_inbound_webhook_routes,
 };
 use worker_internal::build_worker_internal_routes;
 
@@ -35,15 +42,25 @@ where
     let cors_layer = build_cors_layer(frontend_url)?;
 
     let login_routes = build_login_routes(app_state.clone());
+    let login_tenant_routes = build_login_tenant_routes(app_state.clone());
     let register_routes = build_register_routes(app_state.clone());
     let forgot_password_routes = build_forgot_password_routes(app_state.clone());
     let invite_accept_routes = build_invite_accept_routes(app_state.clone());
+    let record_share_link_routes = build_record_share_link_routes(app_state.clone());
+    let tenant_data_export_routes = build_tenant_data_export_routes(app_state.clone());
+    let workflow_inbound_webhook_routes = build_workflow_inbound_webhook_routes(app_state.clone());
     let worker_internal_routes = build_worker_internal_routes(app_state.clone());
+    let internal_ops_routes = build_internal_ops_routes(app_state.clone());
+    let operator_console_routes = build_operator_console_routes(app_state.clone());
+    let openapi_routes = build_openapi_routes(app_state.openapi_swagger_ui_enabled);
 
     Ok(Router::new()
-        .route("/health", get(handlers::health::health_handler))
+        .route("/health", get(handlers::health::readiness_handler))
+        .route("/health/live", get(handlers::health::liveness_handler))
+        .route("/health/ready", get(handlers::health::readiness_handler))
         .route("/metrics", get(handlers::health::metrics_handler))
         .route("/auth/bootstrap", post(auth::bootstrap_handler))
+        .route("/api/versions", get(handlers::health::api_versions_handler))
         .route(
             "/api/public/workflows/webhooks/{tenant_id}/{webhook_key}",
             post(handlers::workflows::ingest_webhook_trigger_handler),
@@ -60,18 +77,30 @@ where
             "/api/public/workflows/approvals/{tenant_id}/{approval_key}",
             post(handlers::workflows::ingest_approval_trigger_handler),
         )
+        .merge(openapi_routes)
         .merge(login_routes)
+        .merge(login_tenant_routes)
         .merge(register_routes)
         .merge(forgot_password_routes)
         .merge(invite_accept_routes)
+        .merge(record_share_link_routes)
+        .merge(tenant_data_export_routes)
+        .merge(workflow_inbound_webhook_routes)
         .merge(worker_internal_routes)
+        .merge(internal_ops_routes)
+        .merge(operator_console_routes)
         .route("/auth/verify-email", post(auth::verify_email_handler))
+        .route(
+            "/auth/email-change/confirm",
+            post(auth::confirm_email_change_handler),
+        )
         .route("/auth/logout", post(auth::logout_handler))
         .merge(protected_routes)
         .route_layer(from_fn_with_state(
             app_state.clone(),
             middleware::require_same_origin_for_mutations,
         ))
+        .layer(from_fn(rate_limit_headers::apply_rate_limit_headers))
         .layer(from_fn(middleware::apply_security_headers))
         .layer(from_fn_with_state(
             app_state.clone(),
@@ -1,8 +1,12 @@
-use axum::http::header::CONTENT_TYPE;
-use axum::http::{HeaderValue, Method};
+use axum::http::header::{CONTENT_TYPE, LINK, RETRY_AFTER};
+use axum::http::{HeaderName, HeaderValue, Method};
 use qryvanta_core::AppError;
 use tower_http::cors::CorsLayer;
 
+use crate::rate_limit_headers::{
+    RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER, RATE_LIMIT_RESET_HEADER,
+};
+
 pub(super) fn build_cors_layer(frontend_url: &str) -> Result<CorsLayer, AppError> {
     Ok(CorsLayer::new()
         .allow_origin(
@@ -18,5 +22,12 @@ pub(super) fn build_cors_layer(frontend_url: &str) -> Result<CorsLayer, AppError
             Method::DELETE,
             Method::OPTIONS,
         ])
-        .allow_headers([CONTENT_TYPE]))
+        .allow_headers([CONTENT_TYPE])
+        .expose_headers([
+            LINK,
+            RETRY_AFTER,
+            HeaderName::from_static(RATE_LIMIT_LIMIT_HEADER),
+            HeaderName::from_static(RATE_LIMIT_REMAINING_HEADER),
+            HeaderName::from_static(RATE_LIMIT_RESET_HEADER),
+        ]))
 }
@@ -0,0 +1,22 @@
+use axum::Router;
+use axum::middleware::from_fn_with_state;
+use axum::routing::{get, post};
+
+use crate::state::AppState;
+use crate::{auth, handlers, middleware};
+
+pub(super) fn build_internal_ops_routes(app_state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/internal/version",
+            get(handlers::health::version_handler),
+        )
+        .route(
+            "/api/internal/bootstrap-token/rotate",
+            post(auth::rotate_bootstrap_token_handler),
+        )
+        .route_layer(from_fn_with_state(
+            app_state,
+            middleware::require_internal_auth,
+        ))
+}
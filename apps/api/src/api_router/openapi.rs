@@ -0,0 +1,22 @@
+use axum::Router;
+use axum::routing::get;
+
+use crate::handlers;
+use crate::state::AppState;
+
+pub(super) fn build_openapi_routes(swagger_ui_enabled: bool) -> Router<AppState> {
+    let routes = Router::new().route(
+        "/api/openapi.json",
+        get(handlers::openapi::openapi_json_handler),
+    );
+    if !swagger_ui_enabled {
+        return routes;
+    }
+
+    routes
+        .route("/api/docs", get(handlers::openapi::swagger_ui_handler))
+        .route(
+            "/api/docs/initializer.js",
+            get(handlers::openapi::swagger_ui_initializer_handler),
+        )
+}
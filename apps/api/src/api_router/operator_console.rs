@@ -0,0 +1,34 @@
+use axum::Router;
+use axum::middleware::from_fn_with_state;
+use axum::routing::{get, post};
+
+use crate::state::AppState;
+use crate::{handlers, middleware};
+
+pub(super) fn build_operator_console_routes(app_state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/operator/tenants",
+            get(handlers::operator::list_operator_tenants_handler),
+        )
+        .route(
+            "/api/operator/tenants/{tenant_id}/maintenance",
+            post(handlers::operator::run_operator_maintenance_handler),
+        )
+        .route(
+            "/api/operator/users",
+            get(handlers::operator::search_operator_user_handler),
+        )
+        .route(
+            "/api/operator/queue/stats",
+            get(handlers::operator::operator_queue_stats_handler),
+        )
+        .route(
+            "/api/operator/audit-log",
+            get(handlers::operator::list_operator_audit_log_handler),
+        )
+        .route_layer(from_fn_with_state(
+            app_state,
+            middleware::require_operator_auth,
+        ))
+}
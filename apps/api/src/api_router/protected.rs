@@ -1,14 +1,25 @@
 use axum::Router;
+use axum::extract::DefaultBodyLimit;
 use axum::middleware::from_fn_with_state;
 use axum::routing::{delete, get, post, put};
 
 use crate::state::AppState;
-use crate::{auth, handlers, middleware};
+use crate::{api_versioning, auth, handlers, middleware};
 
 pub(super) fn build_protected_routes(app_state: AppState) -> Router<AppState> {
+    let v1_routes = build_api_routes().layer(from_fn_with_state(
+        app_state.clone(),
+        api_versioning::serve_v1,
+    ));
+    let v2_routes = build_api_routes().layer(from_fn_with_state(
+        app_state.clone(),
+        api_versioning::serve_v2,
+    ));
+
     Router::new()
-        .nest("/api", build_api_routes())
-        .nest("/api/v1", build_api_routes())
+        .nest("/api", v1_routes.clone())
+        .nest("/api/v1", v1_routes)
+        .nest("/api/v2", v2_routes)
         .merge(build_authenticated_auth_routes())
         .route_layer(from_fn_with_state(app_state, middleware::require_auth))
 }
@@ -23,6 +34,10 @@ fn build_api_routes() -> Router<AppState> {
             "/apps/{app_logical_name}/entities",
             get(handlers::apps::list_app_entities_handler).post(handlers::apps::bind_app_entity_handler),
         )
+        .route(
+            "/apps/{app_logical_name}/entities/{entity_logical_name}",
+            delete(handlers::apps::unbind_app_entity_handler),
+        )
         .route(
             "/apps/{app_logical_name}/permissions",
             get(handlers::apps::list_app_role_permissions_handler)
@@ -33,6 +48,15 @@ fn build_api_routes() -> Router<AppState> {
             get(handlers::apps::get_app_sitemap_handler)
                 .put(handlers::apps::save_app_sitemap_handler),
         )
+        .route(
+            "/apps/{app_logical_name}/admins",
+            get(handlers::apps::list_app_admins_handler)
+                .post(handlers::apps::grant_app_admin_handler),
+        )
+        .route(
+            "/apps/{app_logical_name}/admins/{subject}",
+            delete(handlers::apps::revoke_app_admin_handler),
+        )
         .route(
             "/apps/{app_logical_name}/publish-checks",
             get(handlers::apps::app_publish_checks_handler),
@@ -54,6 +78,26 @@ fn build_api_routes() -> Router<AppState> {
             "/workflows/runs",
             get(handlers::workflows::list_workflow_runs_handler),
         )
+        .route(
+            "/workflows/queue/stats",
+            get(handlers::workflows::workflow_queue_stats_handler),
+        )
+        .route(
+            "/workflows/queue/stuck-jobs",
+            get(handlers::workflows::list_workflow_stuck_jobs_handler),
+        )
+        .route(
+            "/workflows/queue/jobs/{job_id}/release",
+            post(handlers::workflows::release_workflow_job_handler),
+        )
+        .route(
+            "/workflows/queue/jobs/{job_id}/fail",
+            post(handlers::workflows::fail_workflow_job_handler),
+        )
+        .route(
+            "/workflows/queue/leases/{scope_key}",
+            get(handlers::workflows::get_workflow_worker_lease_handler),
+        )
         .route(
             "/workflows/runs/{run_id}/attempts",
             get(handlers::workflows::list_workflow_run_attempts_handler),
@@ -70,6 +114,33 @@ fn build_api_routes() -> Router<AppState> {
             "/workflows/{workflow_logical_name}/execute",
             post(handlers::workflows::execute_workflow_handler),
         )
+        .route(
+            "/workflows/{workflow_logical_name}/bulk-executions/preview",
+            post(handlers::workflows::preview_workflow_bulk_execution_handler),
+        )
+        .route(
+            "/workflows/{workflow_logical_name}/bulk-executions",
+            post(handlers::workflows::start_workflow_bulk_execution_handler),
+        )
+        .route(
+            "/workflows/bulk-executions/{bulk_execution_id}",
+            get(handlers::workflows::get_workflow_bulk_execution_handler),
+        )
+        .route(
+            "/workflows/{workflow_logical_name}/inbound-webhook",
+            get(handlers::workflows::get_workflow_inbound_webhook_handler)
+                .put(handlers::workflows::configure_workflow_inbound_webhook_handler)
+                .delete(handlers::workflows::revoke_workflow_inbound_webhook_handler),
+        )
+        .route(
+            "/workflows/credentials",
+            get(handlers::workflows::list_workflow_credentials_handler),
+        )
+        .route(
+            "/workflows/credentials/{credential_logical_name}",
+            put(handlers::workflows::save_workflow_credential_handler)
+                .delete(handlers::workflows::delete_workflow_credential_handler),
+        )
         .route(
             "/workflows/triggers/schedule/dispatch",
             post(handlers::workflows::dispatch_schedule_trigger_handler),
@@ -78,10 +149,26 @@ fn build_api_routes() -> Router<AppState> {
             "/workspace/apps",
             get(handlers::apps::list_workspace_apps_handler),
         )
+        .route(
+            "/workspace/feature-flags",
+            get(handlers::security::evaluated_feature_flags_handler),
+        )
+        .route(
+            "/workspace/announcements",
+            get(handlers::announcements::active_announcements_handler),
+        )
+        .route(
+            "/workspace/announcements/{announcement_id}/dismiss",
+            post(handlers::announcements::dismiss_announcement_handler),
+        )
         .route(
             "/workspace/apps/{app_logical_name}/navigation",
             get(handlers::apps::app_navigation_handler),
         )
+        .route(
+            "/workspace/apps/{app_logical_name}/capabilities",
+            get(handlers::apps::workspace_app_capabilities_handler),
+        )
         .route(
             "/workspace/apps/{app_logical_name}/dashboards/{dashboard_logical_name}",
             get(handlers::apps::workspace_dashboard_handler),
@@ -106,6 +193,16 @@ fn build_api_routes() -> Router<AppState> {
             "/workspace/apps/{app_logical_name}/entities/{entity_logical_name}/views",
             get(handlers::apps::workspace_list_views_handler),
         )
+        .route(
+            "/workspace/apps/{app_logical_name}/entities/{entity_logical_name}/views/personal",
+            get(handlers::apps::workspace_list_personal_views_handler)
+                .post(handlers::apps::workspace_create_personal_view_handler),
+        )
+        .route(
+            "/workspace/apps/{app_logical_name}/entities/{entity_logical_name}/views/personal/{view_id}",
+            put(handlers::apps::workspace_update_personal_view_handler)
+                .delete(handlers::apps::workspace_delete_personal_view_handler),
+        )
         .route(
             "/workspace/apps/{app_logical_name}/entities/{entity_logical_name}/views/{view_logical_name}",
             get(handlers::apps::workspace_get_view_handler),
@@ -119,6 +216,10 @@ fn build_api_routes() -> Router<AppState> {
             "/workspace/apps/{app_logical_name}/entities/{entity_logical_name}/records/query",
             post(handlers::apps::workspace_query_records_handler),
         )
+        .route(
+            "/workspace/apps/{app_logical_name}/entities/{entity_logical_name}/records/bulk-update",
+            post(handlers::apps::workspace_bulk_update_records_handler),
+        )
         .route(
             "/workspace/apps/{app_logical_name}/entities/{entity_logical_name}/records/{record_id}",
             get(handlers::apps::workspace_get_record_handler)
@@ -130,9 +231,66 @@ fn build_api_routes() -> Router<AppState> {
             get(handlers::entities::list_entities_handler)
                 .post(handlers::entities::create_entity_handler),
         )
+        .route(
+            "/entities/icon-catalog",
+            get(handlers::entities::entity_icon_catalog_handler),
+        )
+        .route(
+            "/entities/typescript",
+            get(handlers::entities::published_entities_typescript_handler),
+        )
         .route(
             "/entities/{entity_logical_name}",
-            put(handlers::entities::update_entity_handler),
+            put(handlers::entities::update_entity_handler)
+                .delete(handlers::entities::delete_entity_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/dependencies",
+            get(handlers::entities::entity_dependencies_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/deactivate",
+            post(handlers::entities::deactivate_entity_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/reactivate",
+            post(handlers::entities::reactivate_entity_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/slug",
+            get(handlers::entities::get_entity_slug_config_handler)
+                .put(handlers::entities::save_entity_slug_config_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/status-model",
+            get(handlers::entities::get_entity_status_model_handler)
+                .put(handlers::entities::save_entity_status_model_handler)
+                .delete(handlers::entities::delete_entity_status_model_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/history-policy",
+            get(handlers::entities::get_entity_history_policy_handler)
+                .put(handlers::entities::save_entity_history_policy_handler)
+                .delete(handlers::entities::delete_entity_history_policy_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/import-templates",
+            get(handlers::record_imports::list_record_import_templates_handler)
+                .post(handlers::record_imports::create_record_import_template_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/import-templates/{template_id}",
+            put(handlers::record_imports::update_record_import_template_handler)
+                .delete(handlers::record_imports::delete_record_import_template_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/duplicate-rules",
+            get(handlers::entities::list_duplicate_rules_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/duplicate-rules/{rule_logical_name}",
+            put(handlers::entities::save_duplicate_rule_handler)
+                .delete(handlers::entities::delete_duplicate_rule_handler),
         )
         .route(
             "/entities/{entity_logical_name}/fields",
@@ -144,6 +302,24 @@ fn build_api_routes() -> Router<AppState> {
             put(handlers::entities::update_field_handler)
                 .delete(handlers::entities::delete_field_handler),
         )
+        .route(
+            "/entities/{entity_logical_name}/fields/{field_logical_name}/impact",
+            get(handlers::entities::field_impact_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/fields/{field_logical_name}/relation-behavior",
+            put(handlers::entities::save_relation_behavior_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/fields/{field_logical_name}/lookup",
+            get(handlers::entities::get_relation_lookup_config_handler)
+                .put(handlers::entities::save_relation_lookup_config_handler)
+                .delete(handlers::entities::delete_relation_lookup_config_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/relation-behaviors",
+            get(handlers::entities::list_relation_behaviors_handler),
+        )
         .route(
             "/entities/{entity_logical_name}/option-sets",
             get(handlers::entities::list_option_sets_handler)
@@ -200,6 +376,26 @@ fn build_api_routes() -> Router<AppState> {
             "/entities/{entity_logical_name}/published",
             get(handlers::entities::latest_published_schema_handler),
         )
+        .route(
+            "/entities/{entity_logical_name}/published/json-schema",
+            get(handlers::entities::published_json_schema_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/published/versions",
+            get(handlers::entities::list_published_schema_versions_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/published/{version}",
+            get(handlers::entities::published_schema_version_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/published/versions/{version}/rollback-checks",
+            get(handlers::entities::rollback_entity_schema_checks_handler),
+        )
+        .route(
+            "/entities/{entity_logical_name}/published/versions/{version}/rollback",
+            post(handlers::entities::rollback_entity_schema_handler),
+        )
         .route(
             "/publish/checks",
             get(handlers::publish::workspace_publish_checks_handler)
@@ -213,6 +409,19 @@ fn build_api_routes() -> Router<AppState> {
             "/publish/diff",
             post(handlers::publish::workspace_publish_diff_handler),
         )
+        .route(
+            "/publish/locks",
+            get(handlers::publish::list_publish_locks_handler),
+        )
+        .route(
+            "/publish/intents",
+            get(handlers::publish::list_publish_intents_handler)
+                .post(handlers::publish::queue_publish_intent_handler),
+        )
+        .route(
+            "/publish/intents/{intent_id}/cancel",
+            post(handlers::publish::cancel_publish_intent_handler),
+        )
         .route(
             "/portability/export",
             get(handlers::portability::export_workspace_bundle_handler),
@@ -221,6 +430,15 @@ fn build_api_routes() -> Router<AppState> {
             "/portability/import",
             post(handlers::portability::import_workspace_bundle_handler),
         )
+        .route(
+            "/localized-labels/{component_type}/{component_key}",
+            get(handlers::localization::list_localized_labels_handler),
+        )
+        .route(
+            "/localized-labels/{component_type}/{component_key}/{locale}",
+            put(handlers::localization::save_localized_label_handler)
+                .delete(handlers::localization::delete_localized_label_handler),
+        )
         .route(
             "/extensions",
             get(handlers::extensions::list_extensions_handler)
@@ -266,15 +484,201 @@ fn build_api_routes() -> Router<AppState> {
             "/search/qrywell/sync-all",
             post(handlers::search::qrywell_sync_all_handler),
         )
+        .route(
+            "/search/qrywell/sync-jobs",
+            post(handlers::search::queue_qrywell_sync_job_handler),
+        )
+        .route("/jobs", get(handlers::jobs::list_background_jobs_handler))
+        .route(
+            "/jobs/{job_id}",
+            get(handlers::jobs::get_background_job_handler),
+        )
+        .route(
+            "/jobs/{job_id}/cancel",
+            post(handlers::jobs::cancel_background_job_handler),
+        )
+        .route(
+            "/tenant/data-export",
+            post(handlers::tenant_data_exports::request_tenant_data_export_handler),
+        )
+        .route(
+            "/tenant/data-export/{job_id}/links",
+            post(handlers::tenant_data_exports::create_tenant_data_export_link_handler),
+        )
+        .route(
+            "/data-validation/audits",
+            post(handlers::data_validation::queue_data_validation_audit_handler),
+        )
+        .route(
+            "/data-validation/audits/{job_id}/violations",
+            get(handlers::data_validation::list_data_validation_violations_handler),
+        )
+        .route(
+            "/data-validation/schedule",
+            get(handlers::data_validation::get_data_validation_schedule_handler)
+                .put(handlers::data_validation::save_data_validation_schedule_handler)
+                .delete(handlers::data_validation::delete_data_validation_schedule_handler),
+        )
+        .route(
+            "/announcements",
+            get(handlers::announcements::list_announcements_handler)
+                .post(handlers::announcements::publish_announcement_handler),
+        )
+        .route(
+            "/announcements/{announcement_id}",
+            put(handlers::announcements::update_announcement_handler)
+                .delete(handlers::announcements::delete_announcement_handler),
+        )
+        .route(
+            "/record-imports/schedules",
+            get(handlers::record_imports::list_record_import_schedules_handler)
+                .post(handlers::record_imports::create_record_import_schedule_handler),
+        )
+        .route(
+            "/record-imports/schedules/{schedule_id}",
+            put(handlers::record_imports::update_record_import_schedule_handler)
+                .delete(handlers::record_imports::delete_record_import_schedule_handler),
+        )
+        .route(
+            "/report-subscriptions",
+            get(handlers::report_subscriptions::list_report_subscriptions_handler)
+                .post(handlers::report_subscriptions::create_report_subscription_handler),
+        )
+        .route(
+            "/report-subscriptions/{subscription_id}",
+            delete(handlers::report_subscriptions::delete_report_subscription_handler),
+        )
+        .route(
+            "/reporting/projections",
+            get(handlers::reporting_projections::list_reporting_projections_handler),
+        )
+        .route(
+            "/reporting/projections/{logical_name}",
+            put(handlers::reporting_projections::save_reporting_projection_handler)
+                .delete(handlers::reporting_projections::delete_reporting_projection_handler),
+        )
+        .route(
+            "/reporting/projections/{logical_name}/query",
+            post(handlers::reporting_projections::query_reporting_projection_handler),
+        )
+        .route(
+            "/contacts/{record_id}/consents",
+            get(handlers::contacts::list_contact_consents_handler)
+                .post(handlers::contacts::record_contact_consent_handler),
+        )
+        .route(
+            "/contacts/{record_id}/consents/history",
+            get(handlers::contacts::list_contact_consent_history_handler),
+        )
+        .route(
+            "/contact-identity/sources",
+            get(handlers::contacts::list_contact_identity_sources_handler),
+        )
+        .route(
+            "/contact-identity/sources/{entity_logical_name}",
+            put(handlers::contacts::save_contact_identity_source_handler)
+                .delete(handlers::contacts::delete_contact_identity_source_handler),
+        )
+        .route(
+            "/contact-identity/sources/{entity_logical_name}/rebuild",
+            post(handlers::contacts::rebuild_contact_identity_source_handler),
+        )
+        .route(
+            "/contact-identity/matches",
+            get(handlers::contacts::find_contact_identity_matches_handler),
+        )
+        .route(
+            "/contact-identity/masters/{master_contact_id}",
+            get(handlers::contacts::get_master_contact_handler),
+        )
+        .route(
+            "/contact-identity/records/{entity_logical_name}/{record_id}",
+            get(handlers::contacts::get_record_master_contact_handler),
+        )
+        .route(
+            "/contact-identity/records/{entity_logical_name}/{record_id}/resolve",
+            post(handlers::contacts::resolve_contact_identity_handler),
+        )
+        .route(
+            "/contact-identity/records/{entity_logical_name}/{record_id}/master",
+            put(handlers::contacts::link_contact_identity_handler)
+                .delete(handlers::contacts::split_contact_identity_handler),
+        )
+        .route(
+            "/runtime/access-requests",
+            get(handlers::runtime::list_record_access_requests_handler),
+        )
+        .route(
+            "/runtime/access-requests/{request_id}/approve",
+            post(handlers::runtime::approve_record_access_request_handler),
+        )
+        .route(
+            "/runtime/access-requests/{request_id}/reject",
+            post(handlers::runtime::reject_record_access_request_handler),
+        )
+        .route(
+            "/runtime/record-shares/{share_id}/revoke",
+            post(handlers::runtime::revoke_record_share_handler),
+        )
+        .route(
+            "/runtime/share-links/{link_id}/revoke",
+            post(handlers::runtime::revoke_record_share_link_handler),
+        )
+        .route(
+            "/runtime/share-links/{link_id}/views",
+            get(handlers::runtime::list_record_share_link_views_handler),
+        )
+        .route(
+            "/runtime/changesets",
+            post(handlers::runtime::execute_runtime_record_changeset_handler),
+        )
+        .route(
+            "/runtime/field-changes",
+            get(handlers::runtime::list_pending_field_changes_handler),
+        )
+        .route(
+            "/runtime/field-changes/{change_id}/approve",
+            post(handlers::runtime::approve_pending_field_change_handler),
+        )
+        .route(
+            "/runtime/field-changes/{change_id}/reject",
+            post(handlers::runtime::reject_pending_field_change_handler),
+        )
         .route(
             "/runtime/{entity_logical_name}/records",
             get(handlers::runtime::list_runtime_records_handler)
                 .post(handlers::runtime::create_runtime_record_handler),
         )
+        .route(
+            "/runtime/{entity_logical_name}/records/quick-create",
+            post(handlers::runtime::quick_create_runtime_record_handler),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/records/import",
+            post(handlers::record_imports::import_runtime_records_handler).layer(
+                DefaultBodyLimit::max(qryvanta_application::RECORD_IMPORT_MAX_FILE_BYTES),
+            ),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/records/export",
+            get(handlers::runtime::export_runtime_records_handler),
+        )
         .route(
             "/runtime/{entity_logical_name}/records/query",
             post(handlers::runtime::query_runtime_records_handler),
         )
+        .route(
+            "/runtime/{entity_logical_name}/records/aggregate",
+            post(handlers::runtime::aggregate_runtime_records_handler),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/lookups/{field_logical_name}",
+            get(handlers::runtime::search_relation_lookup_handler),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/slugs/{slug}",
+            get(handlers::runtime::resolve_runtime_record_slug_handler),
+        )
         .route(
             "/runtime/{entity_logical_name}/business-rules",
             get(handlers::runtime::list_runtime_business_rules_handler),
@@ -285,11 +689,61 @@ fn build_api_routes() -> Router<AppState> {
                 .put(handlers::runtime::update_runtime_record_handler)
                 .delete(handlers::runtime::delete_runtime_record_handler),
         )
+        .route(
+            "/runtime/{entity_logical_name}/records/by-key/{key_field_logical_name}/{key_value}",
+            put(handlers::runtime::upsert_runtime_record_by_key_handler),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/records/{record_id}/owner",
+            put(handlers::runtime::assign_runtime_record_owner_handler),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/records/{record_id}/status-history",
+            get(handlers::runtime::list_runtime_record_status_history_handler),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/records/{record_id}/history",
+            get(handlers::runtime::list_runtime_record_history_handler),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/records/{record_id}/history/{version}/restore",
+            post(handlers::runtime::restore_runtime_record_version_handler),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/records/{record_id}/relations/{field_logical_name}",
+            get(handlers::runtime::list_runtime_record_relations_handler)
+                .post(handlers::runtime::associate_runtime_record_handler),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/records/{record_id}/relations/{field_logical_name}/{target_record_id}",
+            delete(handlers::runtime::disassociate_runtime_record_handler),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/records/{record_id}/access-requests",
+            post(handlers::runtime::request_record_access_handler),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/records/{record_id}/shares",
+            get(handlers::runtime::list_record_shares_handler),
+        )
+        .route(
+            "/runtime/{entity_logical_name}/records/{record_id}/share-links",
+            get(handlers::runtime::list_record_share_links_handler)
+                .post(handlers::runtime::create_record_share_link_handler),
+        )
         .route(
             "/security/roles",
             get(handlers::security::list_roles_handler)
                 .post(handlers::security::create_role_handler),
         )
+        .route(
+            "/security/permissions",
+            get(handlers::security::list_permission_catalog_handler),
+        )
+        .route(
+            "/security/access-explain",
+            get(handlers::security::explain_access_handler),
+        )
         .route(
             "/security/role-assignments",
             get(handlers::security::list_role_assignments_handler)
@@ -299,6 +753,20 @@ fn build_api_routes() -> Router<AppState> {
             "/security/role-unassignments",
             post(handlers::security::unassign_role_handler),
         )
+        .route(
+            "/security/teams",
+            get(handlers::security::list_teams_handler)
+                .post(handlers::security::create_team_handler),
+        )
+        .route(
+            "/security/teams/{team_name}/members",
+            get(handlers::security::list_team_members_handler)
+                .post(handlers::security::add_team_member_handler),
+        )
+        .route(
+            "/security/teams/{team_name}/members/{subject}",
+            delete(handlers::security::remove_team_member_handler),
+        )
         .route(
             "/security/audit-log",
             get(handlers::security::list_audit_log_handler),
@@ -311,10 +779,18 @@ fn build_api_routes() -> Router<AppState> {
             "/security/audit-log/integrity",
             get(handlers::security::verify_audit_log_integrity_handler),
         )
+        .route(
+            "/security/audit-log/anchors",
+            get(handlers::security::list_audit_chain_anchors_handler),
+        )
         .route(
             "/security/audit-log/purge",
             post(handlers::security::purge_audit_log_handler),
         )
+        .route(
+            "/security/audit-log/purge-jobs",
+            post(handlers::security::queue_audit_log_purge_job_handler),
+        )
         .route(
             "/security/registration-mode",
             get(handlers::security::registration_mode_handler)
@@ -325,6 +801,102 @@ fn build_api_routes() -> Router<AppState> {
             get(handlers::security::audit_retention_policy_handler)
                 .put(handlers::security::update_audit_retention_policy_handler),
         )
+        .route(
+            "/security/workflow-concurrency-policy",
+            get(handlers::security::workflow_concurrency_policy_handler)
+                .put(handlers::security::update_workflow_concurrency_policy_handler),
+        )
+        .route(
+            "/security/encryption-keys",
+            get(handlers::security::list_tenant_encryption_keys_handler)
+                .post(handlers::security::register_tenant_encryption_key_handler),
+        )
+        .route(
+            "/security/encryption-keys/rotate",
+            post(handlers::security::rotate_tenant_encryption_key_handler),
+        )
+        .route(
+            "/security/encryption-keys/shred",
+            post(handlers::security::shred_tenant_encryption_keys_handler),
+        )
+        .route(
+            "/security/audit-export-sinks",
+            get(handlers::security::list_audit_export_sinks_handler)
+                .post(handlers::security::create_audit_export_sink_handler),
+        )
+        .route(
+            "/security/audit-export-sinks/{sink_id}",
+            put(handlers::security::update_audit_export_sink_handler)
+                .delete(handlers::security::delete_audit_export_sink_handler),
+        )
+        .route(
+            "/security/legal-holds",
+            get(handlers::security::list_legal_holds_handler)
+                .post(handlers::security::create_legal_hold_handler),
+        )
+        .route(
+            "/security/legal-holds/{hold_id}/release",
+            post(handlers::security::release_legal_hold_handler),
+        )
+        .route(
+            "/security/compliance-zones/assignments",
+            get(handlers::security::list_compliance_zone_assignments_handler)
+                .post(handlers::security::assign_compliance_zone_handler),
+        )
+        .route(
+            "/security/compliance-zones/assignments/{subject}/{zone}",
+            delete(handlers::security::unassign_compliance_zone_handler),
+        )
+        .route(
+            "/security/compliance-zones/tags",
+            get(handlers::security::list_compliance_zone_tags_handler)
+                .put(handlers::security::save_compliance_zone_tag_handler),
+        )
+        .route(
+            "/security/compliance-zones/tags/{entity_logical_name}",
+            delete(handlers::security::remove_entity_compliance_zone_tag_handler),
+        )
+        .route(
+            "/security/compliance-zones/tags/{entity_logical_name}/{record_id}",
+            delete(handlers::security::remove_record_compliance_zone_tag_handler),
+        )
+        .route(
+            "/security/data-erasure-fields",
+            get(handlers::security::list_data_erasure_fields_handler),
+        )
+        .route(
+            "/security/data-erasure-fields/{entity_logical_name}",
+            put(handlers::security::save_data_erasure_fields_handler),
+        )
+        .route(
+            "/security/data-erasures",
+            post(handlers::security::erase_subject_data_handler),
+        )
+        .route(
+            "/security/dual-control-fields",
+            get(handlers::security::list_dual_control_fields_handler),
+        )
+        .route(
+            "/security/dual-control-fields/{entity_logical_name}",
+            put(handlers::security::save_dual_control_fields_handler),
+        )
+        .route(
+            "/security/feature-flags",
+            get(handlers::security::list_feature_flags_handler),
+        )
+        .route(
+            "/security/feature-flags/{flag_key}",
+            put(handlers::security::save_feature_flag_handler)
+                .delete(handlers::security::delete_feature_flag_handler),
+        )
+        .route(
+            "/security/field-masking-rules",
+            get(handlers::security::list_field_masking_rules_handler),
+        )
+        .route(
+            "/security/field-masking-rules/{role_name}",
+            put(handlers::security::save_field_masking_rules_handler),
+        )
         .route(
             "/security/runtime-field-permissions",
             get(handlers::security::list_runtime_field_permissions_handler)
@@ -339,7 +911,30 @@ fn build_api_routes() -> Router<AppState> {
             "/security/temporary-access-grants/{grant_id}/revoke",
             post(handlers::security::revoke_temporary_access_grant_handler),
         )
+        .route(
+            "/security/mfa-resets",
+            get(handlers::security::list_mfa_reset_requests_handler)
+                .post(handlers::security::create_mfa_reset_request_handler),
+        )
+        .route(
+            "/security/mfa-resets/{request_id}/approve",
+            post(handlers::security::approve_mfa_reset_request_handler),
+        )
         .route("/profile/password", put(auth::change_password_handler))
+        .route(
+            "/profile/email-change",
+            get(auth::email_change_status_handler)
+                .post(auth::request_email_change_handler)
+                .delete(auth::cancel_email_change_handler),
+        )
+        .route(
+            "/profile/data-export",
+            post(auth::request_personal_data_export_handler),
+        )
+        .route(
+            "/profile/data-export/{job_id}",
+            get(auth::download_personal_data_export_handler),
+        )
 }
 
 fn build_authenticated_auth_routes() -> Router<AppState> {
@@ -355,6 +950,11 @@ fn build_authenticated_auth_routes() -> Router<AppState> {
             "/auth/webauthn/register/finish",
             post(auth::webauthn_registration_finish_handler),
         )
+        .route("/auth/passkeys", get(auth::list_passkeys_handler))
+        .route(
+            "/auth/passkeys/{passkey_id}",
+            put(auth::rename_passkey_handler).delete(auth::delete_passkey_handler),
+        )
         .route("/auth/mfa/totp/enroll", post(auth::mfa_enroll_handler))
         .route("/auth/mfa/totp/confirm", post(auth::mfa_confirm_handler))
         .route("/auth/mfa/totp", delete(auth::mfa_disable_handler))
@@ -4,7 +4,7 @@ use axum::routing::{get, post};
 use qryvanta_application::RateLimitRule;
 
 use crate::state::AppState;
-use crate::{auth, middleware};
+use crate::{auth, handlers, middleware};
 
 pub(super) fn build_login_routes(app_state: AppState) -> Router<AppState> {
     let login_rate_rule = RateLimitRule::new("login", 10, 15 * 60);
@@ -12,6 +12,10 @@ pub(super) fn build_login_routes(app_state: AppState) -> Router<AppState> {
     Router::new()
         .route("/auth/login", post(auth::login_handler))
         .route("/auth/login/mfa", post(auth::mfa_verify_handler))
+        .route(
+            "/auth/login/mfa/passkey/start",
+            post(auth::mfa_passkey_start_handler),
+        )
         .route(
             "/auth/webauthn/login/start",
             get(auth::webauthn_login_start_handler),
@@ -24,6 +28,15 @@ pub(super) fn build_login_routes(app_state: AppState) -> Router<AppState> {
         .layer(axum::Extension(login_rate_rule))
 }
 
+pub(super) fn build_login_tenant_routes(app_state: AppState) -> Router<AppState> {
+    let login_tenant_rate_rule = RateLimitRule::new("login_tenant", 20, 15 * 60);
+
+    Router::new()
+        .route("/auth/login/tenant", post(auth::login_tenant_handler))
+        .route_layer(from_fn_with_state(app_state, middleware::rate_limit))
+        .layer(axum::Extension(login_tenant_rate_rule))
+}
+
 pub(super) fn build_register_routes(app_state: AppState) -> Router<AppState> {
     let register_rate_rule = RateLimitRule::new("register", 5, 60 * 60);
 
@@ -51,3 +64,41 @@ pub(super) fn build_invite_accept_routes(app_state: AppState) -> Router<AppState
         .route_layer(from_fn_with_state(app_state, middleware::rate_limit))
         .layer(axum::Extension(invite_accept_rate_rule))
 }
+
+pub(super) fn build_record_share_link_routes(app_state: AppState) -> Router<AppState> {
+    let record_share_link_rate_rule = RateLimitRule::new("record_share_link", 30, 15 * 60);
+
+    Router::new()
+        .route(
+            "/api/public/record-links/{tenant_id}/{token}",
+            get(handlers::runtime::view_public_record_share_link_handler)
+                .post(handlers::runtime::submit_public_record_share_link_password_handler),
+        )
+        .route_layer(from_fn_with_state(app_state, middleware::rate_limit))
+        .layer(axum::Extension(record_share_link_rate_rule))
+}
+
+pub(super) fn build_tenant_data_export_routes(app_state: AppState) -> Router<AppState> {
+    let tenant_data_export_rate_rule =
+        RateLimitRule::new("tenant_data_export_download", 10, 15 * 60);
+
+    Router::new()
+        .route(
+            "/api/public/tenant-exports/{tenant_id}/{token}",
+            get(handlers::tenant_data_exports::download_tenant_data_export_handler),
+        )
+        .route_layer(from_fn_with_state(app_state, middleware::rate_limit))
+        .layer(axum::Extension(tenant_data_export_rate_rule))
+}
+
+pub(super) fn build_workflow_inbound_webhook_routes(app_state: AppState) -> Router<AppState> {
+    let inbound_webhook_rate_rule = RateLimitRule::new("workflow_inbound_webhook", 120, 60);
+
+    Router::new()
+        .route(
+            "/api/public/workflows/inbound/{tenant_id}/{token}",
+            post(handlers::workflows::receive_workflow_inbound_webhook_handler),
+        )
+        .route_layer(from_fn_with_state(app_state, middleware::rate_limit))
+        .layer(axum::Extension(inbound_webhook_rate_rule))
+}
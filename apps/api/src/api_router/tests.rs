@@ -1,19 +1,21 @@
 use axum::Json;
-use axum::extract::{ConnectInfo, Extension, State};
+use axum::extract::{ConnectInfo, Extension, Path, Query, State};
 use axum::response::IntoResponse;
 use qryvanta_application::{
-    AppEntityFormInput, AppEntityViewInput, BindAppEntityInput, ClaimedWorkflowJob, CreateAppInput,
+    AppEntityFormInput, AppEntityViewInput, AuditLogQuery, BackgroundJobKind, BackgroundJobStage,
+    BindAppEntityInput, ClaimedWorkflowJob, CreateAppInput, CreateBackgroundJobInput,
     CreateRoleInput, SaveAppRoleEntityPermissionInput, SaveBusinessRuleInput, SaveFieldInput,
-    SaveFormInput, SaveOptionSetInput, SaveViewInput, SaveWorkflowInput, WorkflowExecutionMode,
-    WorkflowRunListQuery,
+    SaveFormInput, SaveOptionSetInput, SavePersonalViewInput, SaveViewInput, SaveWorkflowInput,
+    WorkflowExecutionMode, WorkflowRunListQuery,
 };
 use qryvanta_core::UserIdentity;
 use qryvanta_domain::{
     BusinessRuleAction, BusinessRuleActionType, BusinessRuleCondition, BusinessRuleOperator,
-    BusinessRuleScope, FieldType, FormFieldPlacement, FormSection, FormTab, FormType,
-    LogicalMode as ViewLogicalMode, OptionSetItem, Permission, SortDirection, ViewColumn,
-    ViewFilterCondition, ViewFilterGroup, ViewSort, ViewType, WorkflowDefinition,
-    WorkflowDefinitionInput, WorkflowLifecycleState, WorkflowStep, WorkflowTrigger,
+    BusinessRuleScope, FieldType, FormFieldPlacement, FormScriptEvents, FormSection, FormTab,
+    FormType, LogicalMode as ViewLogicalMode, OptionSetItem, Permission, SortDirection,
+    SubjectType, ViewColumn, ViewFilterCondition, ViewFilterGroup, ViewSort, ViewType,
+    WorkflowDefinition, WorkflowDefinitionInput, WorkflowLifecycleState, WorkflowRunPriority,
+    WorkflowStep, WorkflowTrigger,
 };
 use reqwest::{Method, StatusCode};
 use serde_json::{Value, json};
@@ -27,11 +29,24 @@ use tower_sessions::{MemoryStore, Session};
 use uuid::Uuid;
 
 use crate::api_config::{
-    ApiConfig, EmailProviderConfig, PhysicalIsolationMode, RateLimitStoreConfig,
-    SessionStoreBackend, TotpEncryptionConfig, WorkflowQueueStatsCacheBackend,
+    ApiConfig, EmailProviderConfig, OperatorCredential, PhysicalIsolationMode,
+    RateLimitStoreConfig, RuntimeEnvironment, RuntimeViewCacheBackend, SessionStoreBackend,
+    SmtpRuntimeConfig, TotpEncryptionConfig, WorkflowQueueStatsCacheBackend,
 };
 use crate::api_services::{build_app_state, build_postgres_session_layer};
-use crate::dto::{AuthStepUpRequest, CreateRoleRequest};
+use crate::auth::register_configured_bootstrap_token;
+use crate::dto::{
+    AuthStepUpRequest, CreateAuditExportSinkRequest, CreateLegalHoldRequest,
+    CreateRecordImportScheduleRequest, CreateRecordShareLinkRequest,
+    CreateReportSubscriptionRequest, CreateRoleRequest, DualControlFieldRequest,
+    EraseSubjectDataRequest, FeatureFlagRoleOverrideRequest, FieldMaskingRuleRequest,
+    QueryReportingProjectionRequest, QueueDataValidationAuditRequest, QueueQrywellSyncJobRequest,
+    RecordContactConsentRequest, RecordImportColumnMappingDto, ReportingProjectionColumnRequest,
+    RequestRecordAccessRequest, SaveAnnouncementRequest, SaveDataErasureFieldsRequest,
+    SaveDataValidationScheduleRequest, SaveDualControlFieldsRequest, SaveFeatureFlagRequest,
+    SaveFieldMaskingRulesRequest, SaveLocalizedLabelRequest, SaveRecordImportTemplateRequest,
+    SaveReportingProjectionRequest, TenantEncryptionKeyRequest, UpdateAuditExportSinkRequest,
+};
 use crate::state::AppState;
 
 use super::build_router;
@@ -580,6 +595,77 @@ async fn entity_routes_hide_cross_tenant_metadata_surfaces() {
         "logical_name",
         scenario.right_secret_field_logical_name.as_str(),
     );
+
+    let published_versions = harness
+        .request(
+            Method::GET,
+            format!(
+                "/api/entities/{}/published/versions",
+                scenario.shared_entity_logical_name
+            )
+            .as_str(),
+            Some(cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(published_versions.status(), StatusCode::OK);
+    let published_versions = published_versions
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let latest_version = &published_versions[0];
+    assert_eq!(latest_version["version"], published_schema["version"]);
+    assert_eq!(
+        latest_version["checksum_sha256"]
+            .as_str()
+            .map(str::len)
+            .unwrap_or_default(),
+        64
+    );
+
+    let published_version = harness
+        .request(
+            Method::GET,
+            format!(
+                "/api/entities/{}/published/{}",
+                scenario.shared_entity_logical_name, latest_version["version"]
+            )
+            .as_str(),
+            Some(cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(published_version.status(), StatusCode::OK);
+    let published_version = published_version
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        published_version["checksum_sha256"],
+        latest_version["checksum_sha256"]
+    );
+    assert_array_missing_string(
+        &published_version["schema"]["fields"],
+        "logical_name",
+        scenario.right_secret_field_logical_name.as_str(),
+    );
+
+    let hidden_published_version = harness
+        .request(
+            Method::GET,
+            format!(
+                "/api/entities/{}/published/1",
+                scenario.right_hidden_entity_logical_name
+            )
+            .as_str(),
+            Some(cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(hidden_published_version.status(), StatusCode::NOT_FOUND);
 }
 
 #[tokio::test]
@@ -687,6 +773,42 @@ async fn workspace_routes_reject_cross_tenant_records_and_components() {
         .await;
     assert_eq!(update_foreign_record.status(), StatusCode::NOT_FOUND);
 
+    let bulk_update = harness
+        .request(
+            Method::POST,
+            format!(
+                "/api/workspace/apps/{}/entities/{}/records/bulk-update",
+                scenario.shared_app_logical_name, scenario.shared_entity_logical_name
+            )
+            .as_str(),
+            Some(cookie.as_str()),
+            Some(json!({
+                "record_ids": [scenario.left_record_id, scenario.right_record_id],
+                "values": {
+                    "name": "Left Record Renamed"
+                }
+            })),
+            true,
+        )
+        .await;
+    assert_eq!(bulk_update.status(), StatusCode::OK);
+    let bulk_update = bulk_update
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(bulk_update["updated"], json!(1));
+    assert_eq!(bulk_update["failed"], json!(1));
+    assert_eq!(bulk_update["results"][0]["status"], json!("updated"));
+    assert_eq!(
+        bulk_update["results"][0]["record"]["data"]["name"],
+        json!("Left Record Renamed")
+    );
+    assert_eq!(bulk_update["results"][1]["status"], json!("failed"));
+    assert_eq!(
+        bulk_update["results"][1]["error"]["code"],
+        json!("not_found")
+    );
+
     let delete_foreign_record = harness
         .request(
             Method::DELETE,
@@ -769,6 +891,26 @@ async fn workspace_routes_reject_cross_tenant_records_and_components() {
         scenario.right_secret_view_logical_name.as_str(),
     );
 
+    let personal_views = harness
+        .request(
+            Method::GET,
+            format!(
+                "/api/workspace/apps/{}/entities/{}/views/personal",
+                scenario.shared_app_logical_name, scenario.shared_entity_logical_name
+            )
+            .as_str(),
+            Some(cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(personal_views.status(), StatusCode::OK);
+    let personal_views = personal_views
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(personal_views.as_array().is_some_and(Vec::is_empty));
+
     let get_secret_workspace_view = harness
         .request(
             Method::GET,
@@ -1030,6 +1172,115 @@ async fn schedule_dispatch_stays_tenant_scoped_for_shared_schedule_keys() {
     assert!(right_runs.is_empty());
 }
 
+#[tokio::test]
+async fn signed_inbound_webhook_starts_workflow_and_rejects_invalid_deliveries() {
+    use hmac::{Hmac, Mac};
+
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let workflow_logical_name = format!("inbound_orders_{suffix}");
+    let user = seed_user(
+        &harness.state,
+        format!("inbound_webhook_{suffix}@example.com").as_str(),
+        "Inbound Webhook",
+    )
+    .await;
+    let _ = save_webhook_workflow(
+        &harness.state,
+        &user.actor,
+        workflow_logical_name.as_str(),
+        format!("orders_{suffix}").as_str(),
+    )
+    .await;
+    let created = harness
+        .state
+        .workflow_service
+        .configure_inbound_webhook(
+            &user.actor,
+            workflow_logical_name.as_str(),
+            Some(json!({
+                "type": "object",
+                "required": ["order_id"],
+                "properties": {"order_id": {"type": "string"}}
+            })),
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to configure inbound webhook: {error}"));
+    let configured =
+        crate::dto::CreatedWorkflowInboundWebhookResponse::new(user.actor.tenant_id(), created);
+    let webhook_path = configured.webhook_path;
+    let signing_secret = configured.signing_secret;
+
+    let deliver = |body: Value, secret: &str| {
+        let body = body.to_string();
+        let timestamp = chrono::Utc::now().timestamp().to_string();
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes())
+            .unwrap_or_else(|_| unreachable!());
+        mac.update(format!("{timestamp}.{body}").as_bytes());
+        harness
+            .client
+            .post(format!("{}{}", harness.base_url, webhook_path))
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header("x-qryvanta-timestamp", timestamp)
+            .header(
+                "x-qryvanta-signature",
+                format!("sha256={}", hex::encode(mac.finalize().into_bytes())),
+            )
+            .body(body)
+            .send()
+    };
+
+    let accepted = deliver(json!({"order_id": "ORD-1"}), signing_secret.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(accepted.status(), StatusCode::ACCEPTED);
+    let run = accepted
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(run["workflow_logical_name"], json!(workflow_logical_name));
+    assert_eq!(
+        run["trigger_payload"]["payload"]["order_id"],
+        json!("ORD-1")
+    );
+
+    let forged = deliver(json!({"order_id": "ORD-2"}), "forged-secret")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(forged.status(), StatusCode::UNAUTHORIZED);
+
+    let invalid = deliver(json!({"order": 3}), signing_secret.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+
+    let webhook = harness
+        .state
+        .workflow_service
+        .get_inbound_webhook(&user.actor, workflow_logical_name.as_str())
+        .await
+        .unwrap_or_else(|error| panic!("failed to load inbound webhook: {error}"))
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(webhook.delivery_count, 1);
+
+    assert!(
+        harness
+            .state
+            .workflow_service
+            .revoke_inbound_webhook(&user.actor, workflow_logical_name.as_str())
+            .await
+            .is_ok()
+    );
+
+    let revoked = deliver(json!({"order_id": "ORD-3"}), signing_secret.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(revoked.status(), StatusCode::NOT_FOUND);
+}
+
 #[tokio::test]
 async fn webhook_ingress_stays_tenant_scoped_for_shared_webhook_keys() {
     let Some(harness) = TestHarness::spawn().await else {
@@ -1851,6 +2102,27 @@ async fn queued_workspace_record_create_drains_and_claims_worker_jobs_through_in
             >= 1
     );
 
+    let tenant_queue_stats_response = harness
+        .request(
+            Method::GET,
+            "/api/workflows/queue/stats?active_window_seconds=120",
+            Some(cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(tenant_queue_stats_response.status(), StatusCode::OK);
+    let tenant_queue_stats_response = tenant_queue_stats_response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(tenant_queue_stats_response["leased_jobs"], json!(1));
+    assert_eq!(
+        tenant_queue_stats_response["served_from_cache"],
+        json!(false)
+    );
+    assert!(tenant_queue_stats_response["computed_at"].is_string());
+
     let claimed_job = claimed_workflow_job_from_response_value(claimed_jobs[0].clone())
         .unwrap_or_else(|_| unreachable!());
     let completed_run = harness
@@ -1907,221 +2179,2866 @@ async fn queued_workspace_record_create_drains_and_claims_worker_jobs_through_in
 }
 
 #[tokio::test]
-async fn auth_me_exposes_available_tenants_and_switching_updates_scope() {
-    let Some(harness) = TestHarness::spawn().await else {
+async fn worker_heartbeat_is_rate_limited_per_worker_id() {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return;
+    };
+    let worker_secret = "rate-limited-worker-secret";
+    let mut config = test_config(database_url.as_str());
+    config.workflow_execution_mode = WorkflowExecutionMode::Queued;
+    config.worker_shared_secret = Some(worker_secret.to_owned());
+    config.workflow_worker_rate_limit_burst = 2;
+    config.workflow_worker_rate_limit_per_second = 1;
+    let Some(harness) = TestHarness::spawn_with_config(config).await else {
         return;
     };
 
-    let suffix = Uuid::new_v4().simple().to_string();
-    let alpha_entity_logical_name = format!("alpha_entity_{suffix}");
-    let alpha_app_logical_name = format!("alpha_app_{suffix}");
-    let bravo_entity_logical_name = format!("bravo_entity_{suffix}");
-    let bravo_app_logical_name = format!("bravo_app_{suffix}");
-
-    let alpha_user = seed_user(
-        &harness.state,
-        format!("alpha_member_{suffix}@example.com").as_str(),
-        "Alpha",
-    )
-    .await;
-    let bravo_owner = seed_user(
-        &harness.state,
-        format!("bravo_owner_{suffix}@example.com").as_str(),
-        "Bravo",
-    )
-    .await;
+    let worker_id = format!("worker-{}", Uuid::new_v4().simple());
+    let heartbeat = json!({
+        "claimed_jobs": 0,
+        "executed_jobs": 0,
+        "failed_jobs": 0
+    });
+    let mut statuses = Vec::new();
+    let mut limited_headers = None;
+    for _ in 0..3 {
+        let response = harness
+            .request_internal_worker(
+                Method::POST,
+                "/api/internal/worker/heartbeat",
+                worker_id.as_str(),
+                worker_secret,
+                Some(heartbeat.clone()),
+            )
+            .await;
+        statuses.push(response.status());
+        limited_headers = Some(response.headers().clone());
+    }
+    assert_eq!(
+        statuses,
+        vec![
+            StatusCode::NO_CONTENT,
+            StatusCode::NO_CONTENT,
+            StatusCode::TOO_MANY_REQUESTS
+        ]
+    );
+    let limited_headers = limited_headers.unwrap_or_default();
+    let header = |name: &str| {
+        limited_headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned)
+    };
+    assert_eq!(header("x-ratelimit-limit").as_deref(), Some("2"));
+    assert_eq!(header("x-ratelimit-remaining").as_deref(), Some("0"));
+    assert_eq!(header("retry-after").as_deref(), Some("1"));
 
-    harness
-        .state
-        .tenant_repository
-        .create_membership(
-            bravo_owner.actor.tenant_id(),
-            alpha_user.actor.subject(),
-            "Alpha in Bravo",
-            Some(alpha_user.email.as_str()),
+    let other_worker_response = harness
+        .request_internal_worker(
+            Method::POST,
+            "/api/internal/worker/heartbeat",
+            "other-worker",
+            worker_secret,
+            Some(heartbeat),
         )
-        .await
-        .unwrap_or_else(|_| unreachable!());
+        .await;
+    assert_eq!(other_worker_response.status(), StatusCode::NO_CONTENT);
+}
 
-    seed_workspace_surface(
-        &harness.state,
-        &alpha_user.actor,
-        WorkspaceSurfaceSeed {
-            entity_logical_name: alpha_entity_logical_name.as_str(),
-            app_logical_name: alpha_app_logical_name.as_str(),
-            extra_field_logical_name: None,
-            extra_option_set_logical_name: None,
-            extra_form_logical_name: None,
-            extra_view_logical_name: None,
-        },
-    )
-    .await;
-    seed_workspace_surface(
-        &harness.state,
-        &bravo_owner.actor,
-        WorkspaceSurfaceSeed {
-            entity_logical_name: bravo_entity_logical_name.as_str(),
-            app_logical_name: bravo_app_logical_name.as_str(),
-            extra_field_logical_name: None,
-            extra_option_set_logical_name: None,
-            extra_form_logical_name: None,
-            extra_view_logical_name: None,
-        },
-    )
-    .await;
+#[tokio::test]
+async fn bootstrap_tokens_are_single_use_and_rotatable() {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return;
+    };
+    let internal_secret = "bootstrap-rotation-secret";
+    let configured_token = format!("bootstrap-{}", Uuid::new_v4().simple());
+    let mut config = test_config(database_url.as_str());
+    config.bootstrap_token = configured_token.clone();
+    config.worker_shared_secret = Some(internal_secret.to_owned());
+    let Some(harness) = TestHarness::spawn_with_config(config).await else {
+        return;
+    };
+    let bootstrap = |token: &str| {
+        let subject = format!("bootstrap-{}", Uuid::new_v4().simple());
+        harness.request(
+            Method::POST,
+            "/auth/bootstrap",
+            None,
+            Some(json!({
+                "subject": subject,
+                "token": token
+            })),
+            true,
+        )
+    };
 
-    let cookie = harness
-        .login(alpha_user.email.as_str(), TEST_PASSWORD)
-        .await;
+    assert_eq!(
+        bootstrap(configured_token.as_str()).await.status(),
+        StatusCode::NO_CONTENT
+    );
+    assert_eq!(
+        bootstrap(configured_token.as_str()).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
 
-    let me_response = harness
-        .request(Method::GET, "/auth/me", Some(cookie.as_str()), None, false)
-        .await;
-    assert_eq!(me_response.status(), StatusCode::OK);
-    let me_payload = me_response
+    let rotate = || {
+        harness
+            .client
+            .post(format!(
+                "{}/api/internal/bootstrap-token/rotate",
+                harness.base_url
+            ))
+            .header(
+                reqwest::header::AUTHORIZATION,
+                format!("Bearer {internal_secret}"),
+            )
+            .send()
+    };
+    let first_rotation = rotate()
+        .await
+        .unwrap_or_else(|_| unreachable!())
         .json::<Value>()
         .await
         .unwrap_or_else(|_| unreachable!());
+    let second_rotation = rotate()
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let first_token = first_rotation["token"].as_str().unwrap_or_default();
+    let second_token = second_rotation["token"].as_str().unwrap_or_default();
+    assert_eq!(second_token.len(), 64);
+    assert!(second_rotation["expires_at"].is_string());
+
     assert_eq!(
-        me_payload["tenant_id"].as_str(),
-        Some(alpha_user.actor.tenant_id().to_string().as_str())
+        bootstrap(first_token).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        bootstrap(second_token).await.status(),
+        StatusCode::NO_CONTENT
+    );
+    assert_eq!(
+        bootstrap(second_token).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+
+    let mut restarted_config = test_config(database_url.as_str());
+    restarted_config.bootstrap_token = configured_token.clone();
+    register_configured_bootstrap_token(&harness.state, &restarted_config)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        bootstrap(configured_token.as_str()).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[tokio::test]
+async fn operator_console_requires_operator_token_and_logs_every_action() {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return;
+    };
+    let operator_token = format!("operator-{}", Uuid::new_v4().simple());
+    let mut config = test_config(database_url.as_str());
+    config.operator_credentials = vec![OperatorCredential {
+        operator_id: "oncall".to_owned(),
+        token: operator_token.clone(),
+    }];
+    let Some(harness) = TestHarness::spawn_with_config(config).await else {
+        return;
+    };
+    let suffix = Uuid::new_v4().simple().to_string();
+    let email = format!("operator_lookup_{suffix}@example.com");
+    let user = seed_user(&harness.state, email.as_str(), "Operator Lookup").await;
+    let tenant_id = user.actor.tenant_id();
+
+    let operator_request = |method: reqwest::Method, path: String, token: &str| {
+        harness
+            .client
+            .request(method, format!("{}{path}", harness.base_url))
+            .header(reqwest::header::AUTHORIZATION, format!("Bearer {token}"))
+    };
+
+    let rejected = operator_request(
+        Method::GET,
+        "/api/operator/tenants".to_owned(),
+        "not-an-operator-token",
+    )
+    .send()
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(rejected.status(), StatusCode::UNAUTHORIZED);
+
+    let session_cookie = harness.login(email.as_str(), TEST_PASSWORD).await;
+    let session_response = harness
+        .request(
+            Method::GET,
+            "/api/operator/tenants",
+            Some(session_cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(session_response.status(), StatusCode::UNAUTHORIZED);
+
+    let lookup = operator_request(
+        Method::GET,
+        format!("/api/operator/users?email={email}"),
+        operator_token.as_str(),
+    )
+    .send()
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(lookup.status(), StatusCode::OK);
+    let lookup = lookup
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(lookup["email"], email);
+    assert_eq!(lookup["memberships"][0]["tenant_id"], tenant_id.to_string());
+
+    let tenants = operator_request(
+        Method::GET,
+        "/api/operator/tenants?limit=200".to_owned(),
+        operator_token.as_str(),
+    )
+    .send()
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(tenants.status(), StatusCode::OK);
+
+    let maintenance = operator_request(
+        Method::POST,
+        format!("/api/operator/tenants/{tenant_id}/maintenance"),
+        operator_token.as_str(),
+    )
+    .json(&json!({ "action": "release_stuck_workflow_jobs" }))
+    .send()
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(maintenance.status(), StatusCode::OK);
+    let maintenance = maintenance
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(maintenance["affected"], 0);
+
+    let unknown_action = operator_request(
+        Method::POST,
+        format!("/api/operator/tenants/{tenant_id}/maintenance"),
+        operator_token.as_str(),
+    )
+    .json(&json!({ "action": "drop_tenant" }))
+    .send()
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(unknown_action.status(), StatusCode::BAD_REQUEST);
+
+    let audit_log = operator_request(
+        Method::GET,
+        "/api/operator/audit-log?limit=500".to_owned(),
+        operator_token.as_str(),
+    )
+    .send()
+    .await
+    .unwrap_or_else(|_| unreachable!())
+    .json::<Vec<Value>>()
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    let tenant_key = tenant_id.to_string();
+    assert!(audit_log.iter().any(|event| {
+        event["operator_id"] == "oncall"
+            && event["action"] == "release_stuck_workflow_jobs"
+            && event["tenant_id"] == tenant_key.as_str()
+    }));
+    assert!(audit_log.iter().any(|event| {
+        event["action"] == "search_user"
+            && event["detail"]
+                .as_str()
+                .is_some_and(|detail| detail.contains(email.as_str()))
+    }));
+}
+
+#[tokio::test]
+async fn internal_version_reports_build_and_migration_level_behind_shared_secret() {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return;
+    };
+    let internal_secret = "internal-version-secret";
+    let mut config = test_config(database_url.as_str());
+    config.worker_shared_secret = Some(internal_secret.to_owned());
+    let Some(harness) = TestHarness::spawn_with_config(config).await else {
+        return;
+    };
+
+    let unauthenticated = harness
+        .client
+        .get(format!("{}/api/internal/version", harness.base_url))
+        .send()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(unauthenticated.status(), StatusCode::UNAUTHORIZED);
+
+    let response = harness
+        .client
+        .get(format!("{}/api/internal/version", harness.base_url))
+        .header(
+            reqwest::header::AUTHORIZATION,
+            format!("Bearer {internal_secret}"),
+        )
+        .send()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(body["version"], json!(env!("CARGO_PKG_VERSION")));
+    assert_eq!(body["migrations"]["up_to_date"], json!(true));
+    assert_eq!(body["migrations"]["pending_count"], json!(0));
+    assert_eq!(
+        body["migrations"]["applied_version"],
+        body["migrations"]["expected_version"]
+    );
+    assert_eq!(body["advisory"]["status"], json!("disabled"));
+}
+
+#[tokio::test]
+async fn health_endpoints_report_liveness_and_per_dependency_readiness() {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return;
+    };
+    let mut config = test_config(database_url.as_str());
+    config.email_provider = EmailProviderConfig::Smtp(SmtpRuntimeConfig {
+        host: "127.0.0.1".to_owned(),
+        port: 1,
+        username: "mailer".to_owned(),
+        password: "mailer-password".to_owned(),
+        from_address: "noreply@example.com".to_owned(),
+    });
+    let Some(harness) = TestHarness::spawn_with_config(config).await else {
+        return;
+    };
+
+    let liveness = harness
+        .client
+        .get(format!("{}/health/live", harness.base_url))
+        .send()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(liveness.status(), StatusCode::OK);
+    let liveness = liveness
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(liveness["status"], json!("ok"));
+    assert_eq!(liveness["version"], json!(env!("CARGO_PKG_VERSION")));
+
+    for path in ["/health/ready", "/health"] {
+        let readiness = harness
+            .client
+            .get(format!("{}{path}", harness.base_url))
+            .send()
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(readiness.status(), StatusCode::OK);
+        let readiness = readiness
+            .json::<Value>()
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(readiness["ready"], json!(true));
+        assert_eq!(readiness["status"], json!("degraded"));
+        assert_eq!(readiness["postgres"]["status"], json!("ok"));
+        assert!(readiness["postgres"]["latency_ms"].is_u64());
+        assert_eq!(readiness["session_store"]["status"], json!("ok"));
+        assert_eq!(readiness["redis"]["status"], json!("disabled"));
+        assert!(readiness["redis"]["latency_ms"].is_null());
+        assert_eq!(readiness["smtp"]["status"], json!("error"));
+        assert!(
+            readiness["smtp"]["detail"]
+                .as_str()
+                .unwrap_or_default()
+                .contains("127.0.0.1:1")
+        );
+    }
+}
+
+#[tokio::test]
+async fn openapi_document_is_public_and_swagger_ui_is_opt_in() {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return;
+    };
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let response = harness
+        .client
+        .get(format!("{}/api/openapi.json", harness.base_url))
+        .send()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(response.status(), StatusCode::OK);
+    let document = response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(
+        document["openapi"]
+            .as_str()
+            .is_some_and(|version| version.starts_with("3.1"))
+    );
+    let records_path = &document["paths"]["/api/runtime/{entity_logical_name}/records"];
+    assert!(records_path["get"].is_object());
+    assert!(records_path["post"].is_object());
+    assert!(document["paths"]["/api/entities/{entity_logical_name}/fields"]["post"].is_object());
+    assert!(document["components"]["schemas"]["RuntimeRecordResponse"].is_object());
+    assert!(document["components"]["securitySchemes"]["session_cookie"].is_object());
+
+    let disabled_docs = harness
+        .client
+        .get(format!("{}/api/docs", harness.base_url))
+        .send()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(disabled_docs.status(), StatusCode::NOT_FOUND);
+
+    let mut config = test_config(database_url.as_str());
+    config.openapi_swagger_ui_enabled = true;
+    let Some(docs_harness) = TestHarness::spawn_with_config(config).await else {
+        return;
+    };
+    let docs = docs_harness
+        .client
+        .get(format!("{}/api/docs", docs_harness.base_url))
+        .send()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(docs.status(), StatusCode::OK);
+    assert!(
+        docs.headers()
+            .get("content-security-policy")
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|policy| policy.contains("https://unpkg.com"))
+    );
+}
+
+#[tokio::test]
+async fn versioned_routes_announce_their_version_and_deprecation_schedule() {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return;
+    };
+    let mut config = test_config(database_url.as_str());
+    config.api_v1_deprecated_at = Some(
+        chrono::DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .map(|value| value.with_timezone(&chrono::Utc))
+            .unwrap_or_else(|_| unreachable!()),
+    );
+    let Some(harness) = TestHarness::spawn_with_config(config).await else {
+        return;
+    };
+    let scenario = seed_scenario(&harness.state).await;
+    let cookie = harness
+        .login(scenario.left_user.email.as_str(), TEST_PASSWORD)
+        .await;
+
+    let versions = harness
+        .request(Method::GET, "/api/versions", None, None, false)
+        .await;
+    assert_eq!(versions.status(), StatusCode::OK);
+    let versions = versions
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(versions["current_version"], json!("v2"));
+    assert_eq!(versions["default_version"], json!("v1"));
+    assert_eq!(versions["versions"][0]["status"], json!("deprecated"));
+    assert_eq!(versions["versions"][0]["successor_version"], json!("v2"));
+    assert_eq!(versions["versions"][1]["status"], json!("current"));
+
+    for (path, version, deprecated) in [
+        ("/api/apps", "v1", true),
+        ("/api/v1/apps", "v1", true),
+        ("/api/v2/apps", "v2", false),
+    ] {
+        let response = harness
+            .request(Method::GET, path, Some(cookie.as_str()), None, false)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response
+                .headers()
+                .get("api-version")
+                .and_then(|value| value.to_str().ok()),
+            Some(version)
+        );
+        assert_eq!(
+            response
+                .headers()
+                .get("deprecation")
+                .and_then(|value| value.to_str().ok()),
+            deprecated.then_some("@1577836800")
+        );
+    }
+}
+
+#[tokio::test]
+async fn auth_me_exposes_available_tenants_and_switching_updates_scope() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let alpha_entity_logical_name = format!("alpha_entity_{suffix}");
+    let alpha_app_logical_name = format!("alpha_app_{suffix}");
+    let bravo_entity_logical_name = format!("bravo_entity_{suffix}");
+    let bravo_app_logical_name = format!("bravo_app_{suffix}");
+
+    let alpha_user = seed_user(
+        &harness.state,
+        format!("alpha_member_{suffix}@example.com").as_str(),
+        "Alpha",
+    )
+    .await;
+    let bravo_owner = seed_user(
+        &harness.state,
+        format!("bravo_owner_{suffix}@example.com").as_str(),
+        "Bravo",
+    )
+    .await;
+
+    harness
+        .state
+        .tenant_repository
+        .create_membership(
+            bravo_owner.actor.tenant_id(),
+            alpha_user.actor.subject(),
+            "Alpha in Bravo",
+            Some(alpha_user.email.as_str()),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    seed_workspace_surface(
+        &harness.state,
+        &alpha_user.actor,
+        WorkspaceSurfaceSeed {
+            entity_logical_name: alpha_entity_logical_name.as_str(),
+            app_logical_name: alpha_app_logical_name.as_str(),
+            extra_field_logical_name: None,
+            extra_option_set_logical_name: None,
+            extra_form_logical_name: None,
+            extra_view_logical_name: None,
+        },
+    )
+    .await;
+    seed_workspace_surface(
+        &harness.state,
+        &bravo_owner.actor,
+        WorkspaceSurfaceSeed {
+            entity_logical_name: bravo_entity_logical_name.as_str(),
+            app_logical_name: bravo_app_logical_name.as_str(),
+            extra_field_logical_name: None,
+            extra_option_set_logical_name: None,
+            extra_form_logical_name: None,
+            extra_view_logical_name: None,
+        },
+    )
+    .await;
+
+    let login_response = harness
+        .request(
+            Method::POST,
+            "/auth/login",
+            None,
+            Some(json!({
+                "email": alpha_user.email,
+                "password": TEST_PASSWORD
+            })),
+            true,
+        )
+        .await;
+    assert_eq!(login_response.status(), StatusCode::OK);
+    let pending_cookie = session_cookie(&login_response);
+    let login_payload = login_response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        login_payload["status"].as_str(),
+        Some("tenant_selection_required")
     );
-    assert_eq!(me_payload["display_name"].as_str(), Some("Alpha"));
     assert_tenant_option_state(
-        &me_payload["available_tenants"],
+        &login_payload["tenants"],
         alpha_user.actor.tenant_id().to_string().as_str(),
-        true,
-        true,
+        false,
+        false,
     );
     assert_tenant_option_state(
-        &me_payload["available_tenants"],
+        &login_payload["tenants"],
         bravo_owner.actor.tenant_id().to_string().as_str(),
         false,
         false,
     );
 
-    let alpha_entities = harness
+    let pending_me_response = harness
+        .request(
+            Method::GET,
+            "/auth/me",
+            Some(pending_cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(pending_me_response.status(), StatusCode::UNAUTHORIZED);
+
+    let select_response = harness
+        .request(
+            Method::POST,
+            "/auth/login/tenant",
+            Some(pending_cookie.as_str()),
+            Some(json!({
+                "tenant_id": alpha_user.actor.tenant_id().to_string()
+            })),
+            true,
+        )
+        .await;
+    assert_eq!(select_response.status(), StatusCode::OK);
+    let cookie = session_cookie(&select_response);
+    let select_payload = select_response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(select_payload["status"].as_str(), Some("authenticated"));
+
+    let me_response = harness
+        .request(Method::GET, "/auth/me", Some(cookie.as_str()), None, false)
+        .await;
+    assert_eq!(me_response.status(), StatusCode::OK);
+    let me_payload = me_response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        me_payload["tenant_id"].as_str(),
+        Some(alpha_user.actor.tenant_id().to_string().as_str())
+    );
+    assert_eq!(me_payload["display_name"].as_str(), Some("Alpha"));
+    assert_tenant_option_state(
+        &me_payload["available_tenants"],
+        alpha_user.actor.tenant_id().to_string().as_str(),
+        true,
+        true,
+    );
+    assert_tenant_option_state(
+        &me_payload["available_tenants"],
+        bravo_owner.actor.tenant_id().to_string().as_str(),
+        false,
+        false,
+    );
+
+    let alpha_entities = harness
+        .request(
+            Method::GET,
+            "/api/entities",
+            Some(cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(alpha_entities.status(), StatusCode::OK);
+    let alpha_entities = alpha_entities
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_array_contains_string(
+        &alpha_entities,
+        "logical_name",
+        alpha_entity_logical_name.as_str(),
+    );
+    assert_array_missing_string(
+        &alpha_entities,
+        "logical_name",
+        bravo_entity_logical_name.as_str(),
+    );
+
+    let switch_response = harness
+        .request(
+            Method::POST,
+            "/auth/switch-tenant",
+            Some(cookie.as_str()),
+            Some(json!({
+                "tenant_id": bravo_owner.actor.tenant_id().to_string()
+            })),
+            true,
+        )
+        .await;
+    assert_eq!(switch_response.status(), StatusCode::OK);
+    let switched_cookie = session_cookie(&switch_response);
+    let switch_payload = switch_response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        switch_payload["tenant_id"].as_str(),
+        Some(bravo_owner.actor.tenant_id().to_string().as_str())
+    );
+    assert_eq!(
+        switch_payload["display_name"].as_str(),
+        Some("Alpha in Bravo")
+    );
+    assert_tenant_option_state(
+        &switch_payload["available_tenants"],
+        alpha_user.actor.tenant_id().to_string().as_str(),
+        false,
+        false,
+    );
+    assert_tenant_option_state(
+        &switch_payload["available_tenants"],
+        bravo_owner.actor.tenant_id().to_string().as_str(),
+        true,
+        true,
+    );
+
+    let switched_entities = harness
+        .request(
+            Method::GET,
+            "/api/entities",
+            Some(switched_cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(switched_entities.status(), StatusCode::OK);
+    let switched_entities = switched_entities
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_array_missing_string(
+        &switched_entities,
+        "logical_name",
+        alpha_entity_logical_name.as_str(),
+    );
+    assert_array_contains_string(
+        &switched_entities,
+        "logical_name",
+        bravo_entity_logical_name.as_str(),
+    );
+
+    let switched_me_response = harness
+        .request(
+            Method::GET,
+            "/auth/me",
+            Some(switched_cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(switched_me_response.status(), StatusCode::OK);
+    let switched_me_payload = switched_me_response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        switched_me_payload["tenant_id"].as_str(),
+        Some(bravo_owner.actor.tenant_id().to_string().as_str())
+    );
+    assert_tenant_option_state(
+        &switched_me_payload["available_tenants"],
+        alpha_user.actor.tenant_id().to_string().as_str(),
+        false,
+        false,
+    );
+    assert_tenant_option_state(
+        &switched_me_payload["available_tenants"],
+        bravo_owner.actor.tenant_id().to_string().as_str(),
+        true,
+        true,
+    );
+
+    let alpha_session_actions = session_audit_actions(
+        &harness.state,
+        &alpha_user.actor,
+        alpha_user.actor.subject(),
+    )
+    .await;
+    assert_eq!(
+        alpha_session_actions,
+        vec![
+            "security.session.tenant_exited".to_owned(),
+            "security.session.tenant_entered".to_owned(),
+        ]
+    );
+    let bravo_session_actions = session_audit_actions(
+        &harness.state,
+        &bravo_owner.actor,
+        alpha_user.actor.subject(),
+    )
+    .await;
+    assert_eq!(
+        bravo_session_actions,
+        vec!["security.session.tenant_entered".to_owned()]
+    );
+}
+
+#[tokio::test]
+async fn high_risk_security_actions_require_recent_step_up() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("step_up_admin_{suffix}@example.com").as_str(),
+        "Step Up Admin",
+    )
+    .await;
+    let session_store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, session_store, None);
+    session
+        .insert("step_up_verified_at", 0_i64)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let blocked_response = match crate::handlers::security::create_role_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(CreateRoleRequest {
+            name: format!("auditor_{suffix}"),
+            permissions: vec!["security.audit.read".to_owned()],
+        }),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected step-up protected role creation to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(blocked_response.status(), StatusCode::FORBIDDEN);
+    let blocked_payload = axum::body::to_bytes(blocked_response.into_body(), usize::MAX)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let blocked_payload: Value =
+        serde_json::from_slice(blocked_payload.as_ref()).unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        blocked_payload["code"].as_str(),
+        Some("forbidden.step_up_required")
+    );
+    assert_eq!(
+        blocked_payload["message"].as_str(),
+        Some("forbidden: step-up authentication required for this action")
+    );
+
+    let step_up_response = crate::auth::step_up_handler(
+        State(harness.state.clone()),
+        axum::http::HeaderMap::new(),
+        ConnectInfo("127.0.0.1:4000".parse().unwrap_or_else(|_| unreachable!())),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(AuthStepUpRequest {
+            password: Some(TEST_PASSWORD.to_owned()),
+            code: None,
+            method: None,
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(step_up_response, StatusCode::NO_CONTENT);
+
+    let allowed_response = crate::handlers::security::create_role_handler(
+        State(harness.state),
+        Extension(actor.actor),
+        session,
+        Json(CreateRoleRequest {
+            name: format!("auditor_{suffix}"),
+            permissions: vec!["security.audit.read".to_owned()],
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(allowed_response.0, StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn tenant_encryption_key_registration_validates_reference_after_step_up() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("byok_admin_{suffix}@example.com").as_str(),
+        "BYOK Admin",
+    )
+    .await;
+    let session_store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, session_store, None);
+    session
+        .insert("step_up_verified_at", 0_i64)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let blocked_response = match crate::handlers::security::register_tenant_encryption_key_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(TenantEncryptionKeyRequest {
+            secret_reference: "aws-sm://tenant-key".to_owned(),
+        }),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected step-up protected key registration to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(blocked_response.status(), StatusCode::FORBIDDEN);
+
+    let step_up_response = crate::auth::step_up_handler(
+        State(harness.state.clone()),
+        axum::http::HeaderMap::new(),
+        ConnectInfo("127.0.0.1:4000".parse().unwrap_or_else(|_| unreachable!())),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(AuthStepUpRequest {
+            password: Some(TEST_PASSWORD.to_owned()),
+            code: None,
+            method: None,
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(step_up_response, StatusCode::NO_CONTENT);
+
+    let invalid_response = match crate::handlers::security::register_tenant_encryption_key_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session,
+        Json(TenantEncryptionKeyRequest {
+            secret_reference: "raw-key-material".to_owned(),
+        }),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected raw key material to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(invalid_response.status(), StatusCode::BAD_REQUEST);
+
+    let keys = crate::handlers::security::list_tenant_encryption_keys_handler(
+        State(harness.state),
+        Extension(actor.actor),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert!(keys.0.is_empty());
+}
+
+#[tokio::test]
+async fn legal_hold_placement_requires_step_up_and_can_be_released() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("legal_hold_admin_{suffix}@example.com").as_str(),
+        "Legal Hold Admin",
+    )
+    .await;
+    let session_store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, session_store, None);
+    session
+        .insert("step_up_verified_at", 0_i64)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let hold_request = || CreateLegalHoldRequest {
+        name: "Matter 42".to_owned(),
+        reason: "pending litigation".to_owned(),
+        scope_type: "audit_log".to_owned(),
+        entity_logical_name: None,
+        record_ids: None,
+        audit_from: Some("2026-01-01T00:00:00Z".to_owned()),
+        audit_until: None,
+    };
+
+    let blocked_response = match crate::handlers::security::create_legal_hold_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(hold_request()),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected step-up protected legal hold placement to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(blocked_response.status(), StatusCode::FORBIDDEN);
+
+    let step_up_response = crate::auth::step_up_handler(
+        State(harness.state.clone()),
+        axum::http::HeaderMap::new(),
+        ConnectInfo("127.0.0.1:4000".parse().unwrap_or_else(|_| unreachable!())),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(AuthStepUpRequest {
+            password: Some(TEST_PASSWORD.to_owned()),
+            code: None,
+            method: None,
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(step_up_response, StatusCode::NO_CONTENT);
+
+    let (status, hold) = crate::handlers::security::create_legal_hold_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(hold_request()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(hold.0.is_active);
+
+    let released = crate::handlers::security::release_legal_hold_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session,
+        Path(hold.0.hold_id.clone()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert!(!released.0.is_active);
+
+    let active_holds = crate::handlers::security::list_legal_holds_handler(
+        State(harness.state),
+        Extension(actor.actor),
+        Query(crate::handlers::security::LegalHoldListQuery {
+            include_released: None,
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert!(active_holds.0.is_empty());
+}
+
+#[tokio::test]
+async fn audit_export_sinks_require_step_up_and_can_be_paused_and_deleted() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("audit_export_admin_{suffix}@example.com").as_str(),
+        "Audit Export Admin",
+    )
+    .await;
+    let session_store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, session_store, None);
+    session
+        .insert("step_up_verified_at", 0_i64)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let sink_request = || CreateAuditExportSinkRequest {
+        name: "Splunk".to_owned(),
+        sink_type: "syslog".to_owned(),
+        bucket: None,
+        prefix: None,
+        host: Some("siem.internal".to_owned()),
+        port: Some(6514),
+        transport: Some("tcp".to_owned()),
+        url: None,
+        auth_header_name: None,
+        auth_secret_ref: None,
+        backfill: Some(true),
+    };
+
+    let blocked_response = match crate::handlers::security::create_audit_export_sink_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(sink_request()),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected step-up protected audit export sink creation to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(blocked_response.status(), StatusCode::FORBIDDEN);
+
+    let step_up_response = crate::auth::step_up_handler(
+        State(harness.state.clone()),
+        axum::http::HeaderMap::new(),
+        ConnectInfo("127.0.0.1:4000".parse().unwrap_or_else(|_| unreachable!())),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(AuthStepUpRequest {
+            password: Some(TEST_PASSWORD.to_owned()),
+            code: None,
+            method: None,
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(step_up_response, StatusCode::NO_CONTENT);
+
+    let (status, sink) = crate::handlers::security::create_audit_export_sink_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session,
+        Json(sink_request()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(sink.0.sink_type, "syslog");
+    assert_eq!(sink.0.transport.as_deref(), Some("tcp"));
+    assert_eq!(sink.0.checkpoint_position, 0);
+
+    let paused = crate::handlers::security::update_audit_export_sink_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path(sink.0.sink_id.clone()),
+        Json(UpdateAuditExportSinkRequest { is_enabled: false }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert!(!paused.0.is_enabled);
+
+    let deleted = crate::handlers::security::delete_audit_export_sink_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path(sink.0.sink_id),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(deleted, StatusCode::NO_CONTENT);
+
+    let sinks = crate::handlers::security::list_audit_export_sinks_handler(
+        State(harness.state),
+        Extension(actor.actor),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert!(sinks.0.is_empty());
+}
+
+#[tokio::test]
+async fn background_jobs_report_progress_and_cancel_for_their_requester_only() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let owner = seed_user(
+        &harness.state,
+        format!("jobs_owner_{suffix}@example.com").as_str(),
+        "Jobs Owner",
+    )
+    .await;
+    let member = seed_tenant_member(
+        &harness.state,
+        owner.actor.tenant_id(),
+        format!("jobs_member_{suffix}@example.com").as_str(),
+        "Jobs Member",
+    )
+    .await;
+    replace_owner_role_with_custom_role(
+        &harness.state,
+        &owner.actor,
+        &member,
+        format!("jobs_reader_{suffix}").as_str(),
+        vec![Permission::MetadataEntityRead],
+    )
+    .await;
+
+    let unconfigured_response = match crate::handlers::search::queue_qrywell_sync_job_handler(
+        State(harness.state.clone()),
+        Extension(owner.actor.clone()),
+        Json(QueueQrywellSyncJobRequest::default()),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected Qrywell sync jobs to require a configured Qrywell API"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(unconfigured_response.status(), StatusCode::BAD_REQUEST);
+
+    let job = harness
+        .state
+        .background_job_service
+        .enqueue_job(
+            &owner.actor,
+            CreateBackgroundJobInput {
+                kind: BackgroundJobKind::QrywellSync,
+                parameters: json!({ "entity_logical_names": ["account"] }),
+                stages: vec![BackgroundJobStage::new("account", None)],
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let fetched = crate::handlers::jobs::get_background_job_handler(
+        State(harness.state.clone()),
+        Extension(owner.actor.clone()),
+        Path(job.job_id.clone()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(fetched.0.status, "queued");
+    assert_eq!(fetched.0.progress_percent, 0);
+    assert_eq!(fetched.0.stages.len(), 1);
+    assert!(!fetched.0.resumed);
+
+    let hidden_response = match crate::handlers::jobs::get_background_job_handler(
+        State(harness.state.clone()),
+        Extension(member.actor.clone()),
+        Path(job.job_id.clone()),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected another member's job to stay hidden"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(hidden_response.status(), StatusCode::NOT_FOUND);
+
+    let listed = crate::handlers::jobs::list_background_jobs_handler(
+        State(harness.state.clone()),
+        Extension(owner.actor.clone()),
+        Query(crate::handlers::jobs::BackgroundJobListQuery { limit: None }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert!(listed.0.iter().any(|listed| listed.job_id == job.job_id));
+
+    let cancelled = crate::handlers::jobs::cancel_background_job_handler(
+        State(harness.state.clone()),
+        Extension(owner.actor.clone()),
+        Path(job.job_id.clone()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(cancelled.0.status, "cancelled");
+
+    let repeated_response = match crate::handlers::jobs::cancel_background_job_handler(
+        State(harness.state),
+        Extension(owner.actor),
+        Path(job.job_id),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected cancelling a finished job to conflict"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(repeated_response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn workflow_list_includes_labels_for_the_callers_locale() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("localization_admin_{suffix}@example.com").as_str(),
+        "Localization Admin",
+    )
+    .await;
+    save_manual_workflow(&harness.state, &actor.actor, "notify_owner").await;
+
+    let saved = crate::handlers::localization::save_localized_label_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path((
+            "workflow".to_owned(),
+            "notify_owner".to_owned(),
+            "DE".to_owned(),
+        )),
+        Json(SaveLocalizedLabelRequest {
+            display_name: "Besitzer benachrichtigen".to_owned(),
+            description: Some("Benachrichtigt den Besitzer".to_owned()),
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(saved.0.locale, "de");
+
+    let mut headers = axum::http::HeaderMap::new();
+    headers.insert(
+        axum::http::header::ACCEPT_LANGUAGE,
+        axum::http::HeaderValue::from_static("de-CH, en;q=0.5"),
+    );
+    let localized = crate::handlers::workflows::list_workflows_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        headers,
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    let workflow = localized
+        .0
+        .iter()
+        .find(|workflow| workflow.logical_name == "notify_owner")
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(workflow.display_name, "notify owner");
+    assert_eq!(
+        workflow
+            .localized_label
+            .as_ref()
+            .map(|label| label.display_name.as_str()),
+        Some("Besitzer benachrichtigen")
+    );
+
+    let unlocalized = crate::handlers::workflows::list_workflows_handler(
+        State(harness.state),
+        Extension(actor.actor),
+        axum::http::HeaderMap::new(),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert!(
+        unlocalized
+            .0
+            .iter()
+            .all(|workflow| workflow.localized_label.is_none())
+    );
+}
+
+#[tokio::test]
+async fn dual_control_field_configuration_requires_step_up() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("dual_control_admin_{suffix}@example.com").as_str(),
+        "Dual Control Admin",
+    )
+    .await;
+    let session_store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, session_store, None);
+    session
+        .insert("step_up_verified_at", 0_i64)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let fields_request = || SaveDualControlFieldsRequest {
+        fields: vec![DualControlFieldRequest {
+            field_logical_name: "credit_limit".to_owned(),
+            approval_window_hours: Some(24),
+        }],
+    };
+
+    let blocked_response = match crate::handlers::security::save_dual_control_fields_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Path("account".to_owned()),
+        Json(fields_request()),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected step-up protected dual-control configuration to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(blocked_response.status(), StatusCode::FORBIDDEN);
+
+    let step_up_response = crate::auth::step_up_handler(
+        State(harness.state.clone()),
+        axum::http::HeaderMap::new(),
+        ConnectInfo("127.0.0.1:4000".parse().unwrap_or_else(|_| unreachable!())),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(AuthStepUpRequest {
+            password: Some(TEST_PASSWORD.to_owned()),
+            code: None,
+            method: None,
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(step_up_response, StatusCode::NO_CONTENT);
+
+    let saved = crate::handlers::security::save_dual_control_fields_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session,
+        Path("account".to_owned()),
+        Json(fields_request()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(saved.0.len(), 1);
+    assert_eq!(saved.0[0].approval_window_hours, 24);
+
+    let listed = crate::handlers::security::list_dual_control_fields_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Query(crate::handlers::security::DualControlFieldQuery {
+            entity_logical_name: Some("account".to_owned()),
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(listed.0.len(), 1);
+    assert_eq!(listed.0[0].field_logical_name, "credit_limit");
+
+    let invalid_status_response =
+        match crate::handlers::runtime::list_pending_field_changes_handler(
+            State(harness.state),
+            Extension(actor.actor),
+            Query(crate::handlers::runtime::PendingFieldChangeListQuery {
+                entity_logical_name: None,
+                record_id: None,
+                status: Some("stale".to_owned()),
+            }),
+        )
+        .await
+        {
+            Ok(_) => panic!("expected unknown pending field change status to be rejected"),
+            Err(error) => error.into_response(),
+        };
+    assert_eq!(invalid_status_response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn data_erasure_requires_step_up_and_an_existing_member() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("data_erasure_admin_{suffix}@example.com").as_str(),
+        "Data Erasure Admin",
+    )
+    .await;
+    let session_store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, session_store, None);
+    session
+        .insert("step_up_verified_at", 0_i64)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let erasure_request = |subject: &str| EraseSubjectDataRequest {
+        subject: subject.to_owned(),
+    };
+
+    let blocked_response = match crate::handlers::security::erase_subject_data_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(erasure_request("someone-else")),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected step-up protected data erasure to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(blocked_response.status(), StatusCode::FORBIDDEN);
+
+    let step_up_response = crate::auth::step_up_handler(
+        State(harness.state.clone()),
+        axum::http::HeaderMap::new(),
+        ConnectInfo("127.0.0.1:4000".parse().unwrap_or_else(|_| unreachable!())),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(AuthStepUpRequest {
+            password: Some(TEST_PASSWORD.to_owned()),
+            code: None,
+            method: None,
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(step_up_response, StatusCode::NO_CONTENT);
+
+    let saved = crate::handlers::security::save_data_erasure_fields_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Path("contact".to_owned()),
+        Json(SaveDataErasureFieldsRequest {
+            field_logical_names: vec!["phone".to_owned()],
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(saved.0.len(), 1);
+
+    let listed = crate::handlers::security::list_data_erasure_fields_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Query(crate::handlers::security::DataErasureFieldQuery {
+            entity_logical_name: Some("contact".to_owned()),
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(listed.0.len(), 1);
+    assert_eq!(listed.0[0].field_logical_name, "phone");
+
+    let self_erasure_response = match crate::handlers::security::erase_subject_data_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(erasure_request(actor.actor.subject())),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected erasure of the acting user to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(self_erasure_response.status(), StatusCode::BAD_REQUEST);
+
+    let unknown_subject_response = match crate::handlers::security::erase_subject_data_handler(
+        State(harness.state),
+        Extension(actor.actor),
+        session,
+        Json(erasure_request(format!("unknown-{suffix}").as_str())),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected erasure of a non-member to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(unknown_subject_response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn field_masking_rules_require_step_up_and_known_modes() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("field_masking_admin_{suffix}@example.com").as_str(),
+        "Field Masking Admin",
+    )
+    .await;
+    let session_store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, session_store, None);
+    session
+        .insert("step_up_verified_at", 0_i64)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let rules_request = |masking_mode: &str| SaveFieldMaskingRulesRequest {
+        rules: vec![FieldMaskingRuleRequest {
+            classification: "pii".to_owned(),
+            masking_mode: masking_mode.to_owned(),
+        }],
+    };
+
+    let blocked_response = match crate::handlers::security::save_field_masking_rules_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Path("tenant_owner".to_owned()),
+        Json(rules_request("partial")),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected step-up protected masking rules to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(blocked_response.status(), StatusCode::FORBIDDEN);
+
+    let step_up_response = crate::auth::step_up_handler(
+        State(harness.state.clone()),
+        axum::http::HeaderMap::new(),
+        ConnectInfo("127.0.0.1:4000".parse().unwrap_or_else(|_| unreachable!())),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(AuthStepUpRequest {
+            password: Some(TEST_PASSWORD.to_owned()),
+            code: None,
+            method: None,
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(step_up_response, StatusCode::NO_CONTENT);
+
+    let unknown_mode_response = match crate::handlers::security::save_field_masking_rules_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Path("tenant_owner".to_owned()),
+        Json(rules_request("blurred")),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected an unknown masking mode to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(unknown_mode_response.status(), StatusCode::BAD_REQUEST);
+
+    let saved = crate::handlers::security::save_field_masking_rules_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session,
+        Path("tenant_owner".to_owned()),
+        Json(rules_request("partial")),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(saved.0.len(), 1);
+
+    let listed = crate::handlers::security::list_field_masking_rules_handler(
+        State(harness.state),
+        Extension(actor.actor),
+        Query(crate::handlers::security::FieldMaskingRuleQuery {
+            role_name: Some("tenant_owner".to_owned()),
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(listed.0.len(), 1);
+    assert_eq!(listed.0[0].classification, "pii");
+    assert_eq!(listed.0[0].masking_mode, "partial");
+}
+
+#[tokio::test]
+async fn feature_flags_require_step_up_and_gate_workflow_management() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("feature_flag_admin_{suffix}@example.com").as_str(),
+        "Feature Flag Admin",
+    )
+    .await;
+    let session_store = Arc::new(MemoryStore::default());
+    let session = Session::new(None, session_store, None);
+    session
+        .insert("step_up_verified_at", 0_i64)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let flag_request =
+        |role_overrides: Vec<FeatureFlagRoleOverrideRequest>| SaveFeatureFlagRequest {
+            description: Some("Workflow builder rollout".to_owned()),
+            enabled: false,
+            role_overrides,
+        };
+
+    let blocked_response = match crate::handlers::security::save_feature_flag_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Path("workflows".to_owned()),
+        Json(flag_request(Vec::new())),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected step-up protected feature flag save to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(blocked_response.status(), StatusCode::FORBIDDEN);
+
+    let step_up_response = crate::auth::step_up_handler(
+        State(harness.state.clone()),
+        axum::http::HeaderMap::new(),
+        ConnectInfo("127.0.0.1:4000".parse().unwrap_or_else(|_| unreachable!())),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Json(AuthStepUpRequest {
+            password: Some(TEST_PASSWORD.to_owned()),
+            code: None,
+            method: None,
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(step_up_response, StatusCode::NO_CONTENT);
+
+    let disabled = crate::handlers::security::save_feature_flag_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Path("workflows".to_owned()),
+        Json(flag_request(Vec::new())),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert!(!disabled.0.enabled);
+    let gated = harness
+        .state
+        .workflow_service
+        .execute_workflow(&actor.actor, "unknown_workflow", json!({}))
+        .await;
+    assert!(matches!(gated, Err(qryvanta_core::AppError::Forbidden(_))));
+
+    let saved = crate::handlers::security::save_feature_flag_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session.clone(),
+        Path("workflows".to_owned()),
+        Json(flag_request(vec![FeatureFlagRoleOverrideRequest {
+            role_name: "tenant_owner".to_owned(),
+            enabled: true,
+        }])),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(saved.0.role_overrides.len(), 1);
+
+    let evaluated = crate::handlers::security::evaluated_feature_flags_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(evaluated.0.len(), 1);
+    assert!(evaluated.0[0].enabled);
+
+    let deleted = crate::handlers::security::delete_feature_flag_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        session,
+        Path("workflows".to_owned()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(deleted, StatusCode::NO_CONTENT);
+    let listed = crate::handlers::security::list_feature_flags_handler(
+        State(harness.state),
+        Extension(actor.actor),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert!(listed.0.is_empty());
+}
+
+#[tokio::test]
+async fn record_access_requests_reject_subjects_with_full_read_access() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("record_access_admin_{suffix}@example.com").as_str(),
+        "Record Access Admin",
+    )
+    .await;
+
+    let response = match crate::handlers::runtime::request_record_access_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path(("account".to_owned(), Uuid::new_v4().to_string())),
+        Json(RequestRecordAccessRequest {
+            reason: "quarterly review".to_owned(),
+            duration_hours: Some(8),
+        }),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected access request from a full reader to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let invalid_status_response =
+        match crate::handlers::runtime::list_record_access_requests_handler(
+            State(harness.state.clone()),
+            Extension(actor.actor.clone()),
+            Query(crate::handlers::runtime::RecordAccessRequestListQuery {
+                entity_logical_name: None,
+                record_id: None,
+                status: Some("expired".to_owned()),
+            }),
+        )
+        .await
+        {
+            Ok(_) => panic!("expected unknown record access request status to be rejected"),
+            Err(error) => error.into_response(),
+        };
+    assert_eq!(invalid_status_response.status(), StatusCode::BAD_REQUEST);
+
+    let requests = crate::handlers::runtime::list_record_access_requests_handler(
+        State(harness.state),
+        Extension(actor.actor),
+        Query(crate::handlers::runtime::RecordAccessRequestListQuery {
+            entity_logical_name: None,
+            record_id: None,
+            status: None,
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert!(requests.0.is_empty());
+}
+
+#[tokio::test]
+async fn record_share_links_render_selected_fields_and_log_external_views() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("share_link_owner_{suffix}@example.com").as_str(),
+        "Share Link Owner",
+    )
+    .await;
+    let entity_logical_name = format!("shared_{suffix}");
+    seed_hidden_entity(&harness.state, &actor.actor, entity_logical_name.as_str()).await;
+    let record = harness
+        .state
+        .metadata_service
+        .create_runtime_record(
+            &actor.actor,
+            entity_logical_name.as_str(),
+            json!({"name": "<b>Ada</b>"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = record.record_id().as_str().to_owned();
+
+    let (status, created) = crate::handlers::runtime::create_record_share_link_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path((entity_logical_name.clone(), record_id.clone())),
+        Json(CreateRecordShareLinkRequest {
+            field_logical_names: vec!["name".to_owned()],
+            expires_in_hours: Some(1),
+            password: Some("share-secret".to_owned()),
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(created.0.link.password_protected);
+    let token = created
+        .0
+        .share_path
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .to_owned();
+    let tenant_id = actor.actor.tenant_id().to_string();
+    let connect_info: std::net::SocketAddr =
+        "127.0.0.1:4000".parse().unwrap_or_else(|_| unreachable!());
+
+    let locked = crate::handlers::runtime::view_public_record_share_link_handler(
+        State(harness.state.clone()),
+        Path((tenant_id.clone(), token.clone())),
+        axum::http::HeaderMap::new(),
+        ConnectInfo(connect_info),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(locked.status(), StatusCode::OK);
+
+    let unlocked = crate::handlers::runtime::submit_public_record_share_link_password_handler(
+        State(harness.state.clone()),
+        Path((tenant_id.clone(), token.clone())),
+        axum::http::HeaderMap::new(),
+        ConnectInfo(connect_info),
+        axum::Form(crate::handlers::runtime::RecordShareLinkPasswordForm {
+            password: "share-secret".to_owned(),
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(unlocked.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(unlocked.into_body(), usize::MAX)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let body = String::from_utf8_lossy(&body);
+    assert!(body.contains("&lt;b&gt;Ada&lt;/b&gt;"));
+    assert!(!body.contains("<b>Ada</b>"));
+
+    let views = crate::handlers::runtime::list_record_share_link_views_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path(created.0.link.link_id.clone()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    let outcomes: Vec<&str> = views.0.iter().map(|view| view.outcome.as_str()).collect();
+    assert_eq!(outcomes, vec!["viewed", "password_required"]);
+
+    let revoked_link = crate::handlers::runtime::revoke_record_share_link_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path(created.0.link.link_id.clone()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert!(revoked_link.0.revoked_at.is_some());
+    let revoked = crate::handlers::runtime::view_public_record_share_link_handler(
+        State(harness.state),
+        Path((tenant_id, token)),
+        axum::http::HeaderMap::new(),
+        ConnectInfo(connect_info),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(revoked.status(), StatusCode::GONE);
+}
+
+#[tokio::test]
+async fn field_impact_lists_workflows_and_saved_queries_referencing_the_field() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let owner = seed_user(
+        &harness.state,
+        format!("impact_owner_{suffix}@example.com").as_str(),
+        "Impact Owner",
+    )
+    .await;
+    let entity_logical_name = format!("impact_{suffix}");
+    let app_logical_name = format!("impact_app_{suffix}");
+    seed_workspace_surface(
+        &harness.state,
+        &owner.actor,
+        WorkspaceSurfaceSeed {
+            entity_logical_name: entity_logical_name.as_str(),
+            app_logical_name: app_logical_name.as_str(),
+            extra_field_logical_name: None,
+            extra_option_set_logical_name: None,
+            extra_form_logical_name: None,
+            extra_view_logical_name: None,
+        },
+    )
+    .await;
+    let workflow_logical_name = format!("impact_notify_{suffix}");
+    harness
+        .state
+        .workflow_service
+        .save_workflow(
+            &owner.actor,
+            SaveWorkflowInput {
+                logical_name: workflow_logical_name.clone(),
+                display_name: "Impact Notify".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::RuntimeRecordCreated {
+                    entity_logical_name: entity_logical_name.clone(),
+                },
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "created {{trigger.payload.name}}".to_owned(),
+                }],
+                max_attempts: 1,
+                is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let saved_query = harness
+        .state
+        .app_service
+        .create_personal_view(
+            &owner.actor,
+            app_logical_name.as_str(),
+            entity_logical_name.as_str(),
+            SavePersonalViewInput {
+                display_name: "By name".to_owned(),
+                columns: vec![
+                    ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!()),
+                ],
+                default_sort: None,
+                filter_criteria: None,
+                is_shared: false,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let cookie = harness.login(owner.email.as_str(), TEST_PASSWORD).await;
+
+    let impact_response = harness
+        .request(
+            Method::GET,
+            format!("/api/entities/{entity_logical_name}/fields/name/impact").as_str(),
+            Some(cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(impact_response.status(), StatusCode::OK);
+    let report = impact_response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(report["field_logical_name"], "name");
+    let impacts = report["impacts"]
+        .as_array()
+        .unwrap_or_else(|| unreachable!());
+    assert!(impacts.iter().any(|impact| {
+        impact["kind"] == "workflow"
+            && impact["severity"] == "low"
+            && impact["component_key"] == workflow_logical_name.as_str()
+            && impact["detail"] == "inactive workflow uses the field in template"
+    }));
+    assert!(impacts.iter().any(|impact| {
+        impact["kind"] == "saved_query"
+            && impact["severity"] == "low"
+            && impact["component_key"] == saved_query.view_id.as_str()
+    }));
+
+    let missing_response = harness
+        .request(
+            Method::GET,
+            format!("/api/entities/{entity_logical_name}/fields/missing/impact").as_str(),
+            Some(cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(missing_response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn report_subscriptions_are_owned_by_the_caller_and_suppressed_with_their_view() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("report_owner_{suffix}@example.com").as_str(),
+        "Report Owner",
+    )
+    .await;
+    let entity_logical_name = format!("report_{suffix}");
+    let view_logical_name = format!("weekly_view_{suffix}");
+    seed_workspace_surface(
+        &harness.state,
+        &actor.actor,
+        WorkspaceSurfaceSeed {
+            entity_logical_name: entity_logical_name.as_str(),
+            app_logical_name: format!("report_app_{suffix}").as_str(),
+            extra_field_logical_name: None,
+            extra_option_set_logical_name: None,
+            extra_form_logical_name: None,
+            extra_view_logical_name: Some(view_logical_name.as_str()),
+        },
+    )
+    .await;
+    let request = |view_logical_name: &str| CreateReportSubscriptionRequest {
+        entity_logical_name: entity_logical_name.clone(),
+        view_logical_name: view_logical_name.to_owned(),
+        frequency: "weekly".to_owned(),
+        format: "xlsx".to_owned(),
+        delivery_hour_utc: 6,
+    };
+
+    let missing_view = crate::handlers::report_subscriptions::create_report_subscription_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Json(request("missing_view")),
+    )
+    .await;
+    assert!(missing_view.is_err());
+
+    let (status, created) =
+        crate::handlers::report_subscriptions::create_report_subscription_handler(
+            State(harness.state.clone()),
+            Extension(actor.actor.clone()),
+            Json(request(view_logical_name.as_str())),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(created.0.recipient_email, actor.email);
+    assert!(created.0.suppressed_at.is_none());
+
+    crate::handlers::entities::delete_view_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path((entity_logical_name.clone(), view_logical_name.clone())),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+
+    let listed = crate::handlers::report_subscriptions::list_report_subscriptions_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(listed.0.len(), 1);
+    assert!(listed.0[0].suppressed_at.is_some());
+
+    let deleted = crate::handlers::report_subscriptions::delete_report_subscription_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path(created.0.subscription_id.clone()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(deleted, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn record_imports_map_uploads_through_templates_and_schedule_recurring_fetches() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("importer_{suffix}@example.com").as_str(),
+        "Importer",
+    )
+    .await;
+    let entity_logical_name = format!("import_{suffix}");
+    seed_workspace_surface(
+        &harness.state,
+        &actor.actor,
+        WorkspaceSurfaceSeed {
+            entity_logical_name: entity_logical_name.as_str(),
+            app_logical_name: format!("import_app_{suffix}").as_str(),
+            extra_field_logical_name: None,
+            extra_option_set_logical_name: None,
+            extra_form_logical_name: None,
+            extra_view_logical_name: None,
+        },
+    )
+    .await;
+
+    let (status, template) =
+        crate::handlers::record_imports::create_record_import_template_handler(
+            State(harness.state.clone()),
+            Extension(actor.actor.clone()),
+            Path(entity_logical_name.clone()),
+            Json(SaveRecordImportTemplateRequest {
+                name: "CRM export".to_owned(),
+                column_mappings: vec![RecordImportColumnMappingDto {
+                    source_column: "Company".to_owned(),
+                    field_logical_name: "name".to_owned(),
+                }],
+            }),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(status, StatusCode::CREATED);
+
+    let summary = crate::handlers::record_imports::import_runtime_records_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path(entity_logical_name.clone()),
+        Query(crate::handlers::record_imports::RecordImportQuery {
+            template_id: Some(template.0.template_id.clone()),
+        }),
+        axum::body::Bytes::from_static(b"Company,Notes\nAcme,first\n,missing name\n"),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(summary.0.imported_count, 1);
+    assert_eq!(summary.0.failed_count, 1);
+    assert_eq!(summary.0.row_errors[0].line_number, 3);
+
+    let (status, schedule) =
+        crate::handlers::record_imports::create_record_import_schedule_handler(
+            State(harness.state.clone()),
+            Extension(actor.actor.clone()),
+            Json(CreateRecordImportScheduleRequest {
+                name: "Nightly accounts".to_owned(),
+                entity_logical_name: entity_logical_name.clone(),
+                template_id: Some(template.0.template_id.clone()),
+                source_kind: "https".to_owned(),
+                source_url: "https://files.example.com/accounts.csv".to_owned(),
+                auth_header_name: None,
+                auth_secret_ref: None,
+                frequency: "daily".to_owned(),
+            }),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(schedule.0.owner_email, actor.email);
+
+    let template_in_use =
+        match crate::handlers::record_imports::delete_record_import_template_handler(
+            State(harness.state.clone()),
+            Extension(actor.actor.clone()),
+            Path((entity_logical_name.clone(), template.0.template_id.clone())),
+        )
+        .await
+        {
+            Ok(_) => panic!("expected template used by a recurring import to be kept"),
+            Err(error) => error.into_response(),
+        };
+    assert_eq!(template_in_use.status(), StatusCode::CONFLICT);
+
+    let deleted = crate::handlers::record_imports::delete_record_import_schedule_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path(schedule.0.schedule_id),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(deleted, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn reporting_projections_follow_runtime_record_writes() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("reporter_{suffix}@example.com").as_str(),
+        "Reporter",
+    )
+    .await;
+    let entity_logical_name = format!("reported_{suffix}");
+    seed_hidden_entity(&harness.state, &actor.actor, entity_logical_name.as_str()).await;
+    let record = harness
+        .state
+        .metadata_service
+        .create_runtime_record(
+            &actor.actor,
+            entity_logical_name.as_str(),
+            json!({"name": "Ada"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let projection = crate::handlers::reporting_projections::save_reporting_projection_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path("record_names".to_owned()),
+        Json(SaveReportingProjectionRequest {
+            display_name: "Record names".to_owned(),
+            root_entity_logical_name: entity_logical_name.clone(),
+            columns: vec![ReportingProjectionColumnRequest {
+                column_name: "record_name".to_owned(),
+                relation_field_logical_name: None,
+                field_logical_name: "name".to_owned(),
+            }],
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        projection.0.source_entity_logical_names,
+        vec![entity_logical_name.clone()]
+    );
+
+    harness
+        .state
+        .metadata_service
+        .update_runtime_record(
+            &actor.actor,
+            entity_logical_name.as_str(),
+            record.record_id().as_str(),
+            json!({"name": "Ada Lovelace"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(
+        harness
+            .state
+            .reporting_projection_service
+            .process_runtime_record_changes(1_000)
+            .await
+            .is_ok()
+    );
+
+    let rows = crate::handlers::reporting_projections::query_reporting_projection_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path("record_names".to_owned()),
+        Json(QueryReportingProjectionRequest {
+            filters: Vec::new(),
+            sort_column: Some("record_name".to_owned()),
+            sort_descending: false,
+            limit: None,
+            offset: None,
+        }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(rows.0.len(), 1);
+    assert_eq!(rows.0[0].record_id, record.record_id().as_str());
+    assert_eq!(rows.0[0].values, json!({"record_name": "Ada Lovelace"}));
+
+    let deleted = crate::handlers::reporting_projections::delete_reporting_projection_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path("record_names".to_owned()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(deleted, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn announcements_are_delivered_to_the_workspace_and_dismissed_per_user() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("announcer_{suffix}@example.com").as_str(),
+        "Announcer",
+    )
+    .await;
+    let request = |audience_apps: Vec<String>| SaveAnnouncementRequest {
+        title: "Maintenance window".to_owned(),
+        body: "Planned downtime on Saturday".to_owned(),
+        severity: "warning".to_owned(),
+        starts_at: None,
+        ends_at: Some((chrono::Utc::now() + chrono::Duration::days(1)).to_rfc3339()),
+        audience_roles: Vec::new(),
+        audience_apps,
+        is_dismissible: true,
+    };
+
+    let invalid_schedule = crate::handlers::announcements::publish_announcement_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Json(SaveAnnouncementRequest {
+            ends_at: Some("not-a-timestamp".to_owned()),
+            ..request(Vec::new())
+        }),
+    )
+    .await;
+    assert!(invalid_schedule.is_err());
+
+    let (status, created) = crate::handlers::announcements::publish_announcement_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Json(request(Vec::new())),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(created.0.severity, "warning");
+    let (_, app_scoped) = crate::handlers::announcements::publish_announcement_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Json(request(vec![format!("app_{suffix}")])),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+
+    let active = |app_logical_name: Option<String>| {
+        crate::handlers::announcements::active_announcements_handler(
+            State(harness.state.clone()),
+            Extension(actor.actor.clone()),
+            Query(crate::handlers::announcements::ActiveAnnouncementsQuery { app_logical_name }),
+        )
+    };
+    let outside_app = active(None).await.unwrap_or_else(|_| unreachable!());
+    assert_eq!(outside_app.0.len(), 1);
+    let inside_app = active(Some(format!("app_{suffix}")))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(inside_app.0.len(), 2);
+
+    let dismissed = crate::handlers::announcements::dismiss_announcement_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path(created.0.announcement_id.clone()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(dismissed, StatusCode::NO_CONTENT);
+    let after_dismiss = active(Some(format!("app_{suffix}")))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(after_dismiss.0.len(), 1);
+    assert_eq!(
+        after_dismiss.0[0].announcement_id,
+        app_scoped.0.announcement_id
+    );
+
+    let listed = crate::handlers::announcements::list_announcements_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(listed.0.len(), 2);
+
+    let deleted = crate::handlers::announcements::delete_announcement_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path(app_scoped.0.announcement_id.clone()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(deleted, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn permission_catalog_groups_permissions_and_custom_roles_require_dependencies() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let owner = seed_user(
+        &harness.state,
+        format!("catalog-{suffix}@example.com").as_str(),
+        "Catalog Owner",
+    )
+    .await;
+    let cookie = harness.login(owner.email.as_str(), TEST_PASSWORD).await;
+
+    let catalog_response = harness
+        .request(
+            Method::GET,
+            "/api/security/permissions",
+            Some(cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(catalog_response.status(), StatusCode::OK);
+    let catalog = catalog_response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let groups = catalog.as_array().unwrap_or_else(|| unreachable!());
+    assert_eq!(
+        groups
+            .iter()
+            .filter_map(|group| group["group"].as_str())
+            .collect::<Vec<_>>(),
+        vec!["metadata", "workflow", "runtime", "security"]
+    );
+    let catalog_size = groups
+        .iter()
+        .filter_map(|group| group["permissions"].as_array())
+        .map(Vec::len)
+        .sum::<usize>();
+    assert_eq!(catalog_size, Permission::all().len());
+    let runtime_write = groups
+        .iter()
+        .filter_map(|group| group["permissions"].as_array())
+        .flatten()
+        .find(|entry| entry["permission"] == "runtime.record.write")
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(runtime_write["implies"], json!(["runtime.record.read"]));
+
+    let incomplete_role_response = harness
+        .request(
+            Method::POST,
+            "/api/security/roles",
+            Some(cookie.as_str()),
+            Some(json!({
+                "name": format!("editor_{suffix}"),
+                "permissions": ["runtime.record.write"]
+            })),
+            true,
+        )
+        .await;
+    assert_eq!(incomplete_role_response.status(), StatusCode::BAD_REQUEST);
+
+    let role_response = harness
+        .request(
+            Method::POST,
+            "/api/security/roles",
+            Some(cookie.as_str()),
+            Some(json!({
+                "name": format!("editor_{suffix}"),
+                "permissions": [
+                    "runtime.record.write",
+                    "runtime.record.read",
+                    "security.audit.read"
+                ]
+            })),
+            true,
+        )
+        .await;
+    assert_eq!(role_response.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn access_explain_traces_tenant_permissions_for_subjects() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let owner = seed_user(
+        &harness.state,
+        format!("explain-{suffix}@example.com").as_str(),
+        "Explain Owner",
+    )
+    .await;
+    let cookie = harness.login(owner.email.as_str(), TEST_PASSWORD).await;
+    let cookie = cookie.as_str();
+    let harness = &harness;
+    let explain = |subject: String, action: &'static str| async move {
+        harness
+            .request(
+                Method::GET,
+                format!(
+                    "/api/security/access-explain?subject={subject}&entity_logical_name=contact&action={action}"
+                )
+                .as_str(),
+                Some(cookie),
+                None,
+                false,
+            )
+            .await
+    };
+
+    let owner_response = explain(owner.actor.subject().to_owned(), "update").await;
+    assert_eq!(owner_response.status(), StatusCode::OK);
+    let owner_explanation = owner_response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(owner_explanation["decision"], "allowed");
+    assert_eq!(
+        owner_explanation["permission_checks"][0]["permission"],
+        "runtime.record.write"
+    );
+    assert_eq!(owner_explanation["permission_checks"][0]["granted"], true);
+    assert_eq!(
+        owner_explanation["trace"]
+            .as_array()
+            .and_then(|trace| trace.last())
+            .and_then(Value::as_str),
+        Some("allowed through tenant permission 'runtime.record.write'")
+    );
+
+    let stranger_response = explain(format!("stranger-{suffix}"), "read").await;
+    assert_eq!(stranger_response.status(), StatusCode::OK);
+    let stranger_explanation = stranger_response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(stranger_explanation["decision"], "denied");
+    assert_eq!(stranger_explanation["roles"], json!([]));
+
+    let invalid_action = explain(owner.actor.subject().to_owned(), "share").await;
+    assert_eq!(invalid_action.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn delegated_app_admins_manage_only_their_app() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let owner = seed_user(
+        &harness.state,
+        format!("app_admin_owner_{suffix}@example.com").as_str(),
+        "App Admin Owner",
+    )
+    .await;
+    let maker = seed_tenant_member(
+        &harness.state,
+        owner.actor.tenant_id(),
+        format!("app_admin_maker_{suffix}@example.com").as_str(),
+        "App Admin Maker",
+    )
+    .await;
+    replace_owner_role_with_custom_role(
+        &harness.state,
+        &owner.actor,
+        &maker,
+        format!("app_maker_{suffix}").as_str(),
+        vec![Permission::MetadataEntityRead],
+    )
+    .await;
+
+    let delegated_app = format!("sales_{suffix}");
+    let other_app = format!("service_{suffix}");
+    for app_logical_name in [&delegated_app, &other_app] {
+        harness
+            .state
+            .app_service
+            .create_app(
+                &owner.actor,
+                CreateAppInput {
+                    logical_name: app_logical_name.clone(),
+                    display_name: app_logical_name.clone(),
+                    description: None,
+                },
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+    }
+
+    let owner_cookie = harness.login(owner.email.as_str(), TEST_PASSWORD).await;
+    let maker_cookie = harness.login(maker.email.as_str(), TEST_PASSWORD).await;
+    let delegated_entities = format!("/api/apps/{delegated_app}/entities");
+
+    let before_grant = harness
+        .request(
+            Method::GET,
+            delegated_entities.as_str(),
+            Some(maker_cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(before_grant.status(), StatusCode::FORBIDDEN);
+
+    let grant = harness
+        .request(
+            Method::POST,
+            format!("/api/apps/{delegated_app}/admins").as_str(),
+            Some(owner_cookie.as_str()),
+            Some(json!({ "subject": maker.actor.subject() })),
+            true,
+        )
+        .await;
+    assert_eq!(grant.status(), StatusCode::CREATED);
+    let grant = grant
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(grant["subject"], maker.actor.subject());
+    assert_eq!(grant["granted_by_subject"], owner.actor.subject());
+
+    let after_grant = harness
+        .request(
+            Method::GET,
+            delegated_entities.as_str(),
+            Some(maker_cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(after_grant.status(), StatusCode::OK);
+
+    let apps = harness
+        .request(
+            Method::GET,
+            "/api/apps",
+            Some(maker_cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(apps.status(), StatusCode::OK);
+    let apps = apps
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(apps.as_array().map(Vec::len), Some(1));
+    assert_eq!(apps[0]["logical_name"], delegated_app.as_str());
+
+    let other_entities = harness
+        .request(
+            Method::GET,
+            format!("/api/apps/{other_app}/entities").as_str(),
+            Some(maker_cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(other_entities.status(), StatusCode::FORBIDDEN);
+
+    let escalation = harness
+        .request(
+            Method::POST,
+            format!("/api/apps/{delegated_app}/admins").as_str(),
+            Some(maker_cookie.as_str()),
+            Some(json!({ "subject": owner.actor.subject() })),
+            true,
+        )
+        .await;
+    assert_eq!(escalation.status(), StatusCode::FORBIDDEN);
+
+    let revoke = harness
+        .request(
+            Method::DELETE,
+            format!("/api/apps/{delegated_app}/admins/{}", maker.actor.subject()).as_str(),
+            Some(owner_cookie.as_str()),
+            None,
+            true,
+        )
+        .await;
+    assert_eq!(revoke.status(), StatusCode::NO_CONTENT);
+
+    let after_revoke = harness
+        .request(
+            Method::GET,
+            delegated_entities.as_str(),
+            Some(maker_cookie.as_str()),
+            None,
+            false,
+        )
+        .await;
+    assert_eq!(after_revoke.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn registered_passkeys_are_managed_and_required_as_second_factor() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let user = seed_user(
+        &harness.state,
+        format!("passkey-{suffix}@example.com").as_str(),
+        "Passkey Owner",
+    )
+    .await;
+    let cookie = harness.login(user.email.as_str(), TEST_PASSWORD).await;
+
+    harness
+        .state
+        .passkey_repository
+        .insert_for_subject(user.actor.subject(), "{}")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let list_response = harness
         .request(
             Method::GET,
-            "/api/entities",
+            "/auth/passkeys",
             Some(cookie.as_str()),
             None,
             false,
         )
         .await;
-    assert_eq!(alpha_entities.status(), StatusCode::OK);
-    let alpha_entities = alpha_entities
+    assert_eq!(list_response.status(), StatusCode::OK);
+    let passkeys = list_response
         .json::<Value>()
         .await
         .unwrap_or_else(|_| unreachable!());
-    assert_array_contains_string(
-        &alpha_entities,
-        "logical_name",
-        alpha_entity_logical_name.as_str(),
-    );
-    assert_array_missing_string(
-        &alpha_entities,
-        "logical_name",
-        bravo_entity_logical_name.as_str(),
-    );
+    assert_eq!(passkeys.as_array().map(Vec::len), Some(1));
+    assert_eq!(passkeys[0]["name"].as_str(), Some("Passkey"));
+    assert!(passkeys[0]["last_used_at"].is_null());
+    let passkey_id = passkeys[0]["passkey_id"]
+        .as_str()
+        .unwrap_or_else(|| unreachable!())
+        .to_owned();
 
-    let switch_response = harness
+    let blank_rename_response = harness
         .request(
-            Method::POST,
-            "/auth/switch-tenant",
+            Method::PUT,
+            format!("/auth/passkeys/{passkey_id}").as_str(),
             Some(cookie.as_str()),
-            Some(json!({
-                "tenant_id": bravo_owner.actor.tenant_id().to_string()
-            })),
+            Some(json!({ "name": "   " })),
             true,
         )
         .await;
-    assert_eq!(switch_response.status(), StatusCode::OK);
-    let switched_cookie = session_cookie(&switch_response);
-    let switch_payload = switch_response
+    assert_eq!(blank_rename_response.status(), StatusCode::BAD_REQUEST);
+
+    let rename_response = harness
+        .request(
+            Method::PUT,
+            format!("/auth/passkeys/{passkey_id}").as_str(),
+            Some(cookie.as_str()),
+            Some(json!({ "name": " Work laptop " })),
+            true,
+        )
+        .await;
+    assert_eq!(rename_response.status(), StatusCode::OK);
+    let renamed = rename_response
         .json::<Value>()
         .await
         .unwrap_or_else(|_| unreachable!());
-    assert_eq!(
-        switch_payload["tenant_id"].as_str(),
-        Some(bravo_owner.actor.tenant_id().to_string().as_str())
-    );
-    assert_eq!(
-        switch_payload["display_name"].as_str(),
-        Some("Alpha in Bravo")
-    );
-    assert_tenant_option_state(
-        &switch_payload["available_tenants"],
-        alpha_user.actor.tenant_id().to_string().as_str(),
-        false,
-        false,
-    );
-    assert_tenant_option_state(
-        &switch_payload["available_tenants"],
-        bravo_owner.actor.tenant_id().to_string().as_str(),
-        true,
-        true,
-    );
+    assert_eq!(renamed["name"].as_str(), Some("Work laptop"));
 
-    let switched_entities = harness
+    let last_factor_delete_response = harness
         .request(
-            Method::GET,
-            "/api/entities",
-            Some(switched_cookie.as_str()),
+            Method::DELETE,
+            format!("/auth/passkeys/{passkey_id}").as_str(),
+            Some(cookie.as_str()),
             None,
-            false,
+            true,
         )
         .await;
-    assert_eq!(switched_entities.status(), StatusCode::OK);
-    let switched_entities = switched_entities
+    assert_eq!(last_factor_delete_response.status(), StatusCode::CONFLICT);
+
+    let unknown_delete_response = harness
+        .request(
+            Method::DELETE,
+            format!("/auth/passkeys/{}", Uuid::new_v4()).as_str(),
+            Some(cookie.as_str()),
+            None,
+            true,
+        )
+        .await;
+    assert_eq!(unknown_delete_response.status(), StatusCode::NOT_FOUND);
+
+    let login_response = harness
+        .request(
+            Method::POST,
+            "/auth/login",
+            None,
+            Some(json!({
+                "email": user.email,
+                "password": TEST_PASSWORD
+            })),
+            true,
+        )
+        .await;
+    assert_eq!(login_response.status(), StatusCode::OK);
+    let pending_cookie = session_cookie(&login_response);
+    let login_payload = login_response
         .json::<Value>()
         .await
         .unwrap_or_else(|_| unreachable!());
-    assert_array_missing_string(
-        &switched_entities,
-        "logical_name",
-        alpha_entity_logical_name.as_str(),
-    );
-    assert_array_contains_string(
-        &switched_entities,
-        "logical_name",
-        bravo_entity_logical_name.as_str(),
+    assert_eq!(login_payload["status"].as_str(), Some("mfa_required"));
+    assert_eq!(login_payload["requires_totp"].as_bool(), Some(false));
+    assert_eq!(login_payload["mfa_methods"], json!(["passkey"]));
+
+    let unchallenged_verify_response = harness
+        .request(
+            Method::POST,
+            "/auth/login/mfa",
+            Some(pending_cookie.as_str()),
+            Some(json!({ "method": "passkey", "credential": {} })),
+            true,
+        )
+        .await;
+    assert_eq!(
+        unchallenged_verify_response.status(),
+        StatusCode::UNAUTHORIZED
     );
 
-    let switched_me_response = harness
+    let pending_me_response = harness
         .request(
             Method::GET,
             "/auth/me",
-            Some(switched_cookie.as_str()),
+            Some(pending_cookie.as_str()),
             None,
             false,
         )
         .await;
-    assert_eq!(switched_me_response.status(), StatusCode::OK);
-    let switched_me_payload = switched_me_response
-        .json::<Value>()
-        .await
-        .unwrap_or_else(|_| unreachable!());
-    assert_eq!(
-        switched_me_payload["tenant_id"].as_str(),
-        Some(bravo_owner.actor.tenant_id().to_string().as_str())
-    );
-    assert_tenant_option_state(
-        &switched_me_payload["available_tenants"],
-        alpha_user.actor.tenant_id().to_string().as_str(),
-        false,
-        false,
-    );
-    assert_tenant_option_state(
-        &switched_me_payload["available_tenants"],
-        bravo_owner.actor.tenant_id().to_string().as_str(),
-        true,
-        true,
-    );
+    assert_eq!(pending_me_response.status(), StatusCode::UNAUTHORIZED);
 }
 
 #[tokio::test]
-async fn high_risk_security_actions_require_recent_step_up() {
+async fn data_validation_audits_queue_for_published_entities_and_schedules_round_trip() {
     let Some(harness) = TestHarness::spawn().await else {
         return;
     };
@@ -2129,74 +5046,93 @@ async fn high_risk_security_actions_require_recent_step_up() {
     let suffix = Uuid::new_v4().simple().to_string();
     let actor = seed_user(
         &harness.state,
-        format!("step_up_admin_{suffix}@example.com").as_str(),
-        "Step Up Admin",
+        format!("data_validation_{suffix}@example.com").as_str(),
+        "Data Validation Owner",
     )
     .await;
-    let session_store = Arc::new(MemoryStore::default());
-    let session = Session::new(None, session_store, None);
-    session
-        .insert("step_up_verified_at", 0_i64)
+    let entity_logical_name = format!("audited_{suffix}");
+    seed_workspace_surface(
+        &harness.state,
+        &actor.actor,
+        WorkspaceSurfaceSeed {
+            entity_logical_name: entity_logical_name.as_str(),
+            app_logical_name: format!("audited_app_{suffix}").as_str(),
+            extra_field_logical_name: None,
+            extra_option_set_logical_name: None,
+            extra_form_logical_name: None,
+            extra_view_logical_name: None,
+        },
+    )
+    .await;
+
+    let unknown_response =
+        match crate::handlers::data_validation::queue_data_validation_audit_handler(
+            State(harness.state.clone()),
+            Extension(actor.actor.clone()),
+            Json(QueueDataValidationAuditRequest {
+                entity_logical_names: Some(vec![format!("missing_{suffix}")]),
+            }),
+        )
         .await
-        .unwrap_or_else(|_| unreachable!());
+        {
+            Ok(_) => panic!("expected audits of unpublished entities to be rejected"),
+            Err(error) => error.into_response(),
+        };
+    assert_eq!(unknown_response.status(), StatusCode::BAD_REQUEST);
 
-    let blocked_response = match crate::handlers::security::create_role_handler(
+    let (status, job) = crate::handlers::data_validation::queue_data_validation_audit_handler(
         State(harness.state.clone()),
         Extension(actor.actor.clone()),
-        session.clone(),
-        Json(CreateRoleRequest {
-            name: format!("auditor_{suffix}"),
-            permissions: vec!["security.audit.read".to_owned()],
+        Json(QueueDataValidationAuditRequest {
+            entity_logical_names: Some(vec![entity_logical_name.clone()]),
         }),
     )
     .await
-    {
-        Ok(_) => panic!("expected step-up protected role creation to be rejected"),
-        Err(error) => error.into_response(),
-    };
-    assert_eq!(blocked_response.status(), StatusCode::FORBIDDEN);
-    let blocked_payload = axum::body::to_bytes(blocked_response.into_body(), usize::MAX)
-        .await
-        .unwrap_or_else(|_| unreachable!());
-    let blocked_payload: Value =
-        serde_json::from_slice(blocked_payload.as_ref()).unwrap_or_else(|_| unreachable!());
-    assert_eq!(
-        blocked_payload["code"].as_str(),
-        Some("forbidden.step_up_required")
-    );
-    assert_eq!(
-        blocked_payload["message"].as_str(),
-        Some("forbidden: step-up authentication required for this action")
-    );
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(job.0.kind, "data_validation_audit");
+    assert_eq!(job.0.stages.len(), 1);
+    assert_eq!(job.0.stages[0].name, entity_logical_name);
 
-    let step_up_response = crate::auth::step_up_handler(
+    let violations = crate::handlers::data_validation::list_data_validation_violations_handler(
         State(harness.state.clone()),
-        axum::http::HeaderMap::new(),
-        ConnectInfo("127.0.0.1:4000".parse().unwrap_or_else(|_| unreachable!())),
         Extension(actor.actor.clone()),
-        session.clone(),
-        Json(AuthStepUpRequest {
-            password: Some(TEST_PASSWORD.to_owned()),
-            code: None,
-            method: None,
-        }),
+        Path(job.0.job_id.clone()),
+        Query(
+            crate::handlers::data_validation::DataValidationViolationListQuery {
+                limit: None,
+                offset: None,
+            },
+        ),
     )
     .await
     .unwrap_or_else(|_| unreachable!());
-    assert_eq!(step_up_response, StatusCode::NO_CONTENT);
+    assert!(violations.0.is_empty());
 
-    let allowed_response = crate::handlers::security::create_role_handler(
+    let schedule = crate::handlers::data_validation::save_data_validation_schedule_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Json(SaveDataValidationScheduleRequest { interval_days: 7 }),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(schedule.0.interval_days, 7);
+    assert_eq!(schedule.0.configured_by_subject, actor.actor.subject());
+
+    let deleted = crate::handlers::data_validation::delete_data_validation_schedule_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(deleted, StatusCode::NO_CONTENT);
+    let fetched = crate::handlers::data_validation::get_data_validation_schedule_handler(
         State(harness.state),
         Extension(actor.actor),
-        session,
-        Json(CreateRoleRequest {
-            name: format!("auditor_{suffix}"),
-            permissions: vec!["security.audit.read".to_owned()],
-        }),
     )
     .await
     .unwrap_or_else(|_| unreachable!());
-    assert_eq!(allowed_response.0, StatusCode::CREATED);
+    assert!(fetched.0.is_none());
 }
 
 #[tokio::test]
@@ -2227,9 +5163,14 @@ async fn workflow_publish_with_outbound_actions_requires_recent_step_up() {
                     subject: "Alert".to_owned(),
                     body: "Investigate".to_owned(),
                     html_body: None,
+                    retry_policy: None,
                 }],
                 max_attempts: 1,
                 is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await
@@ -2319,11 +5260,17 @@ async fn workflow_disable_with_outbound_actions_requires_recent_step_up() {
                     endpoint: "https://example.com/webhook".to_owned(),
                     event: "record.updated".to_owned(),
                     headers: None,
+                    credential: None,
                     header_secret_refs: None,
                     payload: json!({"record_id": "rec-1"}),
+                    retry_policy: None,
                 }],
                 max_attempts: 1,
                 is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await
@@ -2489,12 +5436,18 @@ async fn workflow_routes_require_explicit_workflow_permissions() {
                         "message": "allowed"
                     }
                 ],
-                "max_attempts": 1
+                "max_attempts": 1,
+                "priority": "high"
             })),
             true,
         )
         .await;
     assert_eq!(save_response.status(), StatusCode::CREATED);
+    let saved_workflow = save_response
+        .json::<Value>()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(saved_workflow["priority"], json!("high"));
 
     let workflows = harness
         .request(
@@ -2535,6 +5488,9 @@ impl TestHarness {
     async fn spawn_with_config(config: ApiConfig) -> Option<Self> {
         let pool = test_pool().await?;
         let state = build_app_state(pool.clone(), &config).unwrap_or_else(|_| unreachable!());
+        register_configured_bootstrap_token(&state, &config)
+            .await
+            .unwrap_or_else(|_| unreachable!());
         let session_layer = build_postgres_session_layer(pool, config.cookie_secure)
             .await
             .unwrap_or_else(|_| unreachable!());
@@ -2555,7 +5511,13 @@ impl TestHarness {
 
         Some(Self {
             base_url: format!("http://{address}"),
-            client: reqwest::Client::new(),
+            // Each harness connects from its own loopback address so per-IP
+            // auth rate limits stored in the shared database do not carry
+            // over between tests.
+            client: reqwest::Client::builder()
+                .local_address(loopback_client_address())
+                .build()
+                .unwrap_or_else(|_| unreachable!()),
             state,
             _server: ServerGuard { handle: server },
         })
@@ -2655,9 +5617,11 @@ async fn test_pool() -> Option<PgPool> {
 fn test_config(database_url: &str) -> ApiConfig {
     ApiConfig {
         migrate_only: false,
+        environment: RuntimeEnvironment::Development,
         database_url: database_url.to_owned(),
         frontend_url: FRONTEND_URL.to_owned(),
         bootstrap_token: "bootstrap-test-token-32-bytes-minimum".to_owned(),
+        bootstrap_token_ttl_seconds: 3_600,
         _session_secret: "session-secret-with-more-than-32-bytes".to_owned(),
         api_host: "127.0.0.1".to_owned(),
         api_port: 0,
@@ -2674,19 +5638,37 @@ fn test_config(database_url: &str) -> ApiConfig {
         email_provider: EmailProviderConfig::Console,
         workflow_execution_mode: WorkflowExecutionMode::Inline,
         worker_shared_secret: None,
+        operator_credentials: Vec::new(),
         redis_url: None,
         rate_limit_store: RateLimitStoreConfig::Postgres,
         workflow_queue_stats_cache_backend: WorkflowQueueStatsCacheBackend::InMemory,
         workflow_worker_default_lease_seconds: 60,
         workflow_worker_max_claim_limit: 25,
         workflow_worker_max_partition_count: 8,
+        workflow_worker_tenant_claim_limit: 0,
+        workflow_worker_backpressure_retry_after_ms: 5_000,
+        workflow_worker_rate_limit_burst: 20,
+        workflow_worker_rate_limit_per_second: 5,
         workflow_queue_stats_cache_ttl_seconds: 2,
+        runtime_view_cache_backend: RuntimeViewCacheBackend::InMemory,
+        runtime_view_cache_ttl_seconds: 30,
         runtime_query_max_limit: 200,
         runtime_query_max_in_flight: 8,
         workflow_burst_max_in_flight: 8,
         audit_immutable_mode: true,
+        audit_outbox_relay_interval_ms: 1_000,
+        audit_outbox_relay_batch_size: 100,
+        background_job_poll_interval_ms: 1_000,
+        report_subscription_poll_interval_ms: 60_000,
+        report_subscription_tenant_limit: 100,
+        audit_export_poll_interval_ms: 5_000,
+        audit_export_batch_size: 500,
+        audit_export_file_root: None,
         slow_request_threshold_ms: 2_000,
         slow_query_threshold_ms: 2_000,
+        runtime_query_timeout_ms: 30_000,
+        workflow_dispatch_timeout_ms: 30_000,
+        publish_timeout_ms: 120_000,
         physical_isolation_mode: PhysicalIsolationMode::Shared,
         physical_isolation_tenant_id: None,
         physical_isolation_schema_template: None,
@@ -2696,6 +5678,11 @@ fn test_config(database_url: &str) -> ApiConfig {
         qrywell_sync_poll_interval_ms: 5_000,
         qrywell_sync_batch_size: 100,
         qrywell_sync_max_attempts: 3,
+        openapi_swagger_ui_enabled: false,
+        release_advisory_url: None,
+        release_advisory_cache_ttl_seconds: 60,
+        api_v1_deprecated_at: None,
+        api_v1_sunset_at: None,
     }
 }
 
@@ -2850,6 +5837,16 @@ async fn seed_scenario(state: &AppState) -> SeededScenario {
     }
 }
 
+fn loopback_client_address() -> std::net::IpAddr {
+    let bytes = Uuid::new_v4().into_bytes();
+    std::net::IpAddr::V4(std::net::Ipv4Addr::new(
+        127,
+        bytes[0],
+        bytes[1],
+        1 + bytes[2] % 254,
+    ))
+}
+
 async fn seed_user(state: &AppState, email: &str, display_name: &str) -> SeededUser {
     let password_hash = state
         .user_service
@@ -2944,12 +5941,17 @@ async fn replace_owner_role_with_custom_role(
         .unwrap_or_else(|_| unreachable!());
     state
         .security_admin_service
-        .assign_role(actor, member.actor.subject(), role_name)
+        .assign_role(actor, SubjectType::User, member.actor.subject(), role_name)
         .await
         .unwrap_or_else(|_| unreachable!());
     state
         .security_admin_service
-        .unassign_role(actor, member.actor.subject(), TENANT_OWNER_ROLE)
+        .unassign_role(
+            actor,
+            SubjectType::User,
+            member.actor.subject(),
+            TENANT_OWNER_ROLE,
+        )
         .await
         .unwrap_or_else(|_| unreachable!());
 }
@@ -2988,6 +5990,7 @@ async fn seed_workspace_surface(
                 relation_target_entity: None,
                 option_set_logical_name: None,
                 calculation_expression: None,
+                classification: None,
             },
         )
         .await
@@ -3014,6 +6017,7 @@ async fn seed_workspace_surface(
                     relation_target_entity: None,
                     option_set_logical_name: None,
                     calculation_expression: None,
+                    classification: None,
                 },
             )
             .await
@@ -3056,6 +6060,7 @@ async fn seed_workspace_surface(
                     form_type: FormType::Main,
                     tabs: minimal_form_tabs(),
                     header_fields: Vec::new(),
+                    script_events: FormScriptEvents::default(),
                 },
             )
             .await
@@ -3177,6 +6182,7 @@ async fn seed_hidden_entity(state: &AppState, actor: &UserIdentity, entity_logic
                 relation_target_entity: None,
                 option_set_logical_name: None,
                 calculation_expression: None,
+                classification: None,
             },
         )
         .await
@@ -3207,6 +6213,10 @@ async fn save_manual_workflow(
                 }],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await
@@ -3235,6 +6245,10 @@ async fn save_schedule_workflow(
                 }],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await
@@ -3263,6 +6277,10 @@ async fn save_webhook_workflow(
                 }],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await
@@ -3291,6 +6309,10 @@ async fn save_form_workflow(
                 }],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await
@@ -3319,6 +6341,10 @@ async fn save_inbound_email_workflow(
                 }],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await
@@ -3347,6 +6373,10 @@ async fn save_approval_event_workflow(
                 }],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await
@@ -3375,6 +6405,10 @@ async fn save_runtime_record_created_workflow(
                 }],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await
@@ -3460,6 +6494,24 @@ fn claimed_workflow_job_from_response_value(value: Value) -> Result<ClaimedWorkf
             .get("trigger_payload")
             .cloned()
             .ok_or_else(|| "trigger_payload missing".to_owned())?,
+        continuation: value
+            .get("continuation")
+            .cloned()
+            .filter(|continuation| !continuation.is_null())
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|error| format!("continuation invalid: {error}"))?,
+        trace_context: value
+            .get("trace_context")
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned),
+        priority: value
+            .get("priority")
+            .and_then(Value::as_str)
+            .map(WorkflowRunPriority::parse)
+            .transpose()
+            .map_err(|error| format!("priority invalid: {error}"))?
+            .unwrap_or_default(),
     })
 }
 
@@ -3608,6 +6660,34 @@ fn assert_tenant_option_state(
     );
 }
 
+/// Returns the session audit actions of `subject` in the tenant of `viewer`, newest first.
+async fn session_audit_actions(
+    state: &AppState,
+    viewer: &UserIdentity,
+    subject: &str,
+) -> Vec<String> {
+    let entries = state
+        .security_admin_service
+        .list_audit_log(
+            viewer,
+            AuditLogQuery {
+                limit: 50,
+                offset: 0,
+                action: None,
+                subject: Some(subject.to_owned()),
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    entries
+        .into_iter()
+        .filter(|entry| entry.resource_type == "tenant_session")
+        .inspect(|entry| assert_eq!(entry.resource_id, viewer.tenant_id().to_string()))
+        .map(|entry| entry.action)
+        .collect()
+}
+
 fn session_cookie(response: &reqwest::Response) -> String {
     response
         .headers()
@@ -3621,3 +6701,45 @@ fn session_cookie(response: &reqwest::Response) -> String {
         })
         .unwrap_or_else(|| unreachable!())
 }
+
+#[tokio::test]
+async fn contact_consent_capture_rejects_unknown_contacts() {
+    let Some(harness) = TestHarness::spawn().await else {
+        return;
+    };
+
+    let suffix = Uuid::new_v4().simple().to_string();
+    let actor = seed_user(
+        &harness.state,
+        format!("consent_admin_{suffix}@example.com").as_str(),
+        "Consent Admin",
+    )
+    .await;
+
+    let response = match crate::handlers::contacts::record_contact_consent_handler(
+        State(harness.state.clone()),
+        Extension(actor.actor.clone()),
+        Path(Uuid::new_v4().to_string()),
+        Json(RecordContactConsentRequest {
+            purpose: "marketing".to_owned(),
+            channel: "email".to_owned(),
+            status: "granted".to_owned(),
+            source: "web_form".to_owned(),
+        }),
+    )
+    .await
+    {
+        Ok(_) => panic!("expected consent capture for an unknown contact to be rejected"),
+        Err(error) => error.into_response(),
+    };
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let history = crate::handlers::contacts::list_contact_consent_history_handler(
+        State(harness.state),
+        Extension(actor.actor),
+        Path(Uuid::new_v4().to_string()),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert!(history.0.is_empty());
+}
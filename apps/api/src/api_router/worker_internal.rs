@@ -1,24 +1,44 @@
 use axum::Router;
 use axum::middleware::from_fn_with_state;
 use axum::routing::{get, post};
+use qryvanta_application::TokenBucketRule;
 
 use crate::state::AppState;
 use crate::{handlers, middleware};
 
 pub(super) fn build_worker_internal_routes(app_state: AppState) -> Router<AppState> {
-    Router::new()
+    let claim_rate_rule = worker_rate_rule(&app_state, "worker_claim");
+    let heartbeat_rate_rule = worker_rate_rule(&app_state, "worker_heartbeat");
+
+    let claim_routes = Router::new()
         .route(
             "/api/internal/worker/jobs/claim",
             post(handlers::worker::claim_workflow_jobs_handler),
         )
-        .route(
-            "/api/internal/worker/runtime-events/drain",
-            post(handlers::worker::drain_runtime_record_workflow_events_handler),
-        )
+        .route_layer(from_fn_with_state(
+            app_state.clone(),
+            middleware::rate_limit_worker,
+        ))
+        .layer(axum::Extension(claim_rate_rule));
+
+    let heartbeat_routes = Router::new()
         .route(
             "/api/internal/worker/heartbeat",
             post(handlers::worker::worker_heartbeat_handler),
         )
+        .route_layer(from_fn_with_state(
+            app_state.clone(),
+            middleware::rate_limit_worker,
+        ))
+        .layer(axum::Extension(heartbeat_rate_rule));
+
+    Router::new()
+        .merge(claim_routes)
+        .merge(heartbeat_routes)
+        .route(
+            "/api/internal/worker/runtime-events/drain",
+            post(handlers::worker::drain_runtime_record_workflow_events_handler),
+        )
         .route(
             "/api/internal/worker/jobs/stats",
             get(handlers::worker::workflow_queue_stats_handler),
@@ -28,3 +48,11 @@ pub(super) fn build_worker_internal_routes(app_state: AppState) -> Router<AppSta
             middleware::require_worker_auth,
         ))
 }
+
+fn worker_rate_rule(app_state: &AppState, category: &'static str) -> TokenBucketRule {
+    TokenBucketRule::new(
+        category,
+        app_state.workflow_worker_rate_limit_burst,
+        app_state.workflow_worker_rate_limit_per_second,
+    )
+}
@@ -4,7 +4,10 @@ mod redis;
 mod sessions;
 mod state_builder;
 
-pub use database::connect_and_migrate;
+pub use database::{connect_and_migrate, read_migration_level};
 pub use redis::build_redis_client;
-pub use sessions::{build_postgres_session_layer, build_redis_session_layer};
+pub use sessions::{
+    POSTGRES_SESSION_SCHEMA, POSTGRES_SESSION_TABLE, build_postgres_session_layer,
+    build_redis_session_layer,
+};
 pub use state_builder::build_app_state;
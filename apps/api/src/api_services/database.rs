@@ -1,7 +1,11 @@
 use qryvanta_core::AppError;
 use sqlx::PgPool;
+use sqlx::migrate::Migrator;
 use sqlx::postgres::PgPoolOptions;
 
+/// Schema migrations embedded in the API binary.
+pub static MIGRATOR: Migrator = sqlx::migrate!("../../crates/infrastructure/migrations");
+
 pub async fn connect_and_migrate(database_url: &str) -> Result<PgPool, AppError> {
     let pool = PgPoolOptions::new()
         .max_connections(10)
@@ -9,10 +13,51 @@ pub async fn connect_and_migrate(database_url: &str) -> Result<PgPool, AppError>
         .await
         .map_err(|error| AppError::Internal(format!("failed to connect to database: {error}")))?;
 
-    sqlx::migrate!("../../crates/infrastructure/migrations")
+    MIGRATOR
         .run(&pool)
         .await
         .map_err(|error| AppError::Internal(format!("failed to run migrations: {error}")))?;
 
     Ok(pool)
 }
+
+/// Applied database migrations compared with [`MIGRATOR`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationLevel {
+    pub applied_version: Option<i64>,
+    pub applied_description: Option<String>,
+    pub expected_version: i64,
+    pub pending_count: usize,
+}
+
+/// Reads the applied migration level without running pending migrations.
+pub async fn read_migration_level(pool: &PgPool) -> Result<MigrationLevel, AppError> {
+    let applied = sqlx::query_as::<_, (i64, String)>(
+        "SELECT version, description FROM _sqlx_migrations WHERE success ORDER BY version",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|error| AppError::Internal(format!("failed to read migration level: {error}")))?;
+
+    let expected_version = MIGRATOR
+        .iter()
+        .map(|migration| migration.version)
+        .max()
+        .unwrap_or_default();
+    let pending_count = MIGRATOR
+        .iter()
+        .filter(|migration| {
+            !applied
+                .iter()
+                .any(|(version, _)| *version == migration.version)
+        })
+        .count();
+    let latest_applied = applied.last();
+
+    Ok(MigrationLevel {
+        applied_version: latest_applied.map(|(version, _)| *version),
+        applied_description: latest_applied.map(|(_, description)| description.clone()),
+        expected_version,
+        pending_count,
+    })
+}
@@ -7,12 +7,18 @@ use tower_sessions_sqlx_store::PostgresStore;
 
 use crate::redis_session_store::RedisSessionStore;
 
+/// Schema holding the Postgres session table.
+pub const POSTGRES_SESSION_SCHEMA: &str = "tower_sessions";
+/// Postgres session table name.
+pub const POSTGRES_SESSION_TABLE: &str = "tower_sessions";
+
 pub async fn build_postgres_session_layer(
     pool: PgPool,
     cookie_secure: bool,
 ) -> Result<SessionManagerLayer<PostgresStore>, AppError> {
     let session_store = PostgresStore::new(pool)
-        .with_table_name("tower_sessions")
+        .with_schema_name(POSTGRES_SESSION_SCHEMA)
+        .and_then(|store| store.with_table_name(POSTGRES_SESSION_TABLE))
         .map_err(|error| {
             AppError::Validation(format!("invalid session table name configuration: {error}"))
         })?;
@@ -1,17 +1,31 @@
 use std::sync::Arc;
 
 use qryvanta_application::{
-    AppService, ContactBootstrapService, ExtensionService, MetadataService, WorkflowService,
+    AnnouncementService, AppService, BackgroundJobService, ContactBootstrapService,
+    ContactConsentService, ContactIdentityService, DataValidationService, ExtensionService,
+    LocalizationService, MetadataService, OperationDeadlines, OperationTimeouts,
+    OperatorConsoleService, PersonalDataExportService, PublishCoordinationService,
+    RecordImportService, RecordShareLinkService, ReportSubscriptionService,
+    ReportingProjectionService, TenantDataExportService, WorkflowClaimBackpressurePolicy,
+    WorkflowService,
 };
 use qryvanta_core::AppError;
 use qryvanta_infrastructure::{
-    HttpWorkflowActionDispatcher, TokioWorkflowDelayService, WasmExtensionRuntime,
+    Argon2PasswordHasher, HttpWorkflowActionDispatcher, OpenTelemetryWorkflowTraceContextSource,
+    PostgresAnnouncementRepository, PostgresDataValidationRepository,
+    PostgresOperatorConsoleRepository, PostgresPersonalDataExportRepository,
+    PostgresRecordImportRepository, PostgresReportSubscriptionRepository,
+    PostgresReportingProjectionRepository, PostgresTenantDataExportRepository,
+    RemoteRecordImportSourceFetcher, TokioOperationTimer, TokioWorkflowDelayService,
+    WasmExtensionRuntime,
 };
 use sqlx::PgPool;
 use tokio::sync::Semaphore;
 
-use crate::api_config::ApiConfig;
+use crate::api_config::{ApiConfig, EmailProviderConfig};
+use crate::api_versioning::{ApiVersionLifecycle, ApiVersionSchedule};
 use crate::observability::ApiObservabilityMetrics;
+use crate::release_advisory::ReleaseAdvisoryClient;
 use crate::state::AppState;
 
 use super::redis::build_redis_client;
@@ -30,25 +44,77 @@ pub fn build_app_state(pool: PgPool, config: &ApiConfig) -> Result<AppState, App
         .transpose()?;
 
     let repositories = repositories::build_repository_set(&pool);
-    let security_services = security::build_security_services(&repositories, config);
+    let security_services = security::build_security_services(&repositories, config)?;
     let user_services = users::build_user_services(
         &pool,
         config,
-        repositories.tenant_repository.clone(),
-        repositories.user_repository.clone(),
+        &repositories,
         security_services.authorization_service.clone(),
         security_services.auth_event_service.clone(),
     )?;
     let workflow_queue_stats_cache =
         caches::build_workflow_queue_stats_cache(config, redis_client.clone())?;
+    let runtime_view_result_cache =
+        caches::build_runtime_view_result_cache(config, redis_client.clone())?;
+    let workflow_worker_lease_coordinator =
+        caches::build_workflow_worker_lease_coordinator(redis_client.clone());
     let rate_limit_service = caches::build_rate_limit_service(&pool, config, redis_client.clone())?;
     let webauthn = webauthn::build_webauthn(config)?;
+    let operation_deadlines = build_operation_deadlines(config);
 
     let metadata_service = MetadataService::new(
         repositories.metadata_repository.clone(),
         security_services.authorization_service.clone(),
         repositories.audit_repository.clone(),
+    )
+    .with_legal_hold_repository(repositories.legal_hold_repository.clone())
+    .with_compliance_zone_repository(repositories.compliance_zone_repository.clone())
+    .with_field_change_approval_repository(repositories.field_change_approval_repository.clone())
+    .with_field_masking_repository(repositories.field_masking_repository.clone())
+    .with_record_access_repository(repositories.record_access_repository.clone())
+    .with_record_owner_directory(repositories.security_admin_repository.clone())
+    .with_extension_repository(repositories.extension_repository.clone())
+    .with_app_repository(repositories.app_repository.clone())
+    .with_workflow_repository(repositories.workflow_repository.clone())
+    .with_audit_outbox(true)
+    .with_operation_deadlines(operation_deadlines.clone())
+    .with_view_result_cache(
+        runtime_view_result_cache.clone(),
+        config.runtime_view_cache_ttl_seconds,
+    );
+    let operator_console_service = OperatorConsoleService::new(
+        Arc::new(PostgresOperatorConsoleRepository::new(pool.clone())),
+        repositories.user_repository.clone(),
+        repositories.tenant_repository.clone(),
+        repositories.workflow_repository.clone(),
+    )
+    .with_view_result_cache(runtime_view_result_cache);
+    let background_job_service = BackgroundJobService::new(
+        security_services.authorization_service.clone(),
+        repositories.background_job_repository.clone(),
+        repositories.audit_repository.clone(),
+    );
+    let personal_data_export_service = PersonalDataExportService::new(
+        background_job_service.clone(),
+        Arc::new(PostgresPersonalDataExportRepository::new(pool.clone())),
+        repositories.user_repository.clone(),
+    );
+    let tenant_data_export_service = TenantDataExportService::new(
+        background_job_service.clone(),
+        Arc::new(PostgresTenantDataExportRepository::new(pool.clone())),
+        repositories.audit_repository.clone(),
+    );
+    let data_validation_service = DataValidationService::new(
+        security_services.authorization_service.clone(),
+        background_job_service.clone(),
+        Arc::new(PostgresDataValidationRepository::new(pool.clone())),
+        repositories.audit_repository.clone(),
     );
+    let report_subscription_service = ReportSubscriptionService::new(
+        Arc::new(PostgresReportSubscriptionRepository::new(pool.clone())),
+        super::email::build_email_service(config)?,
+    )
+    .with_tenant_limit(config.report_subscription_tenant_limit);
     let extension_service = ExtensionService::new(
         security_services.authorization_service.clone(),
         repositories.extension_repository.clone(),
@@ -65,7 +131,39 @@ pub fn build_app_state(pool: PgPool, config: &ApiConfig) -> Result<AppState, App
         250,
     ));
 
+    let workflow_service = WorkflowService::new(
+        security_services.authorization_service.clone(),
+        repositories.workflow_repository.clone(),
+        workflow_runtime_service,
+        repositories.audit_repository.clone(),
+        config.workflow_execution_mode,
+    )
+    .with_action_dispatcher(workflow_action_dispatcher)
+    .with_delay_service(Arc::new(TokioWorkflowDelayService))
+    .with_operation_deadlines(operation_deadlines)
+    .with_contact_consent_repository(repositories.contact_consent_repository.clone())
+    .with_queue_stats_cache(
+        workflow_queue_stats_cache,
+        config.workflow_queue_stats_cache_ttl_seconds,
+    )
+    .with_inbound_webhooks(
+        repositories.workflow_repository.clone(),
+        user_services.secret_encryptor.clone(),
+    )
+    .with_credential_repository(repositories.workflow_repository.clone())
+    .with_feature_flags(security_services.feature_flag_service.clone())
+    .with_trace_context_source(Arc::new(OpenTelemetryWorkflowTraceContextSource));
+    let workflow_service = match workflow_worker_lease_coordinator {
+        Some(coordinator) => workflow_service.with_worker_lease_coordinator(coordinator),
+        None => workflow_service,
+    };
+
     Ok(AppState {
+        announcement_service: AnnouncementService::new(
+            security_services.authorization_service.clone(),
+            Arc::new(PostgresAnnouncementRepository::new(pool.clone())),
+            repositories.audit_repository.clone(),
+        ),
         app_service: AppService::new(
             security_services.authorization_service.clone(),
             repositories.app_repository,
@@ -73,32 +171,79 @@ pub fn build_app_state(pool: PgPool, config: &ApiConfig) -> Result<AppState, App
             repositories.audit_repository.clone(),
         ),
         metadata_service: metadata_service.clone(),
+        localization_service: LocalizationService::new(
+            security_services.authorization_service.clone(),
+            repositories.localized_label_repository.clone(),
+            repositories.audit_repository.clone(),
+        ),
+        publish_coordination_service: PublishCoordinationService::new(
+            security_services.authorization_service.clone(),
+            repositories.publish_coordination_repository.clone(),
+            repositories.audit_repository.clone(),
+        ),
+        background_job_service,
+        personal_data_export_service,
+        tenant_data_export_service,
+        data_validation_service,
         extension_service,
         contact_bootstrap_service: ContactBootstrapService::new(
             repositories.metadata_repository.clone(),
             repositories.tenant_repository.clone(),
         ),
+        contact_consent_service: ContactConsentService::new(
+            security_services.authorization_service.clone(),
+            repositories.contact_consent_repository.clone(),
+            repositories.audit_repository.clone(),
+        ),
+        contact_identity_service: ContactIdentityService::new(
+            security_services.authorization_service.clone(),
+            Arc::new(metadata_service.clone()),
+            repositories.contact_identity_repository.clone(),
+            repositories.audit_repository.clone(),
+        ),
         security_admin_service: security_services.security_admin_service,
+        legal_hold_service: security_services.legal_hold_service,
+        audit_export_service: security_services.audit_export_service,
+        compliance_zone_service: security_services.compliance_zone_service,
+        data_erasure_service: security_services.data_erasure_service,
+        feature_flag_service: security_services.feature_flag_service,
+        field_change_approval_service: security_services.field_change_approval_service,
+        field_masking_service: security_services.field_masking_service,
+        record_access_service: security_services.record_access_service,
+        record_import_service: RecordImportService::new(
+            security_services.authorization_service.clone(),
+            Arc::new(PostgresRecordImportRepository::new(pool.clone())),
+            Arc::new(metadata_service.clone()),
+            Arc::new(RemoteRecordImportSourceFetcher::new()),
+            super::email::build_email_service(config)?,
+            repositories.audit_repository.clone(),
+        ),
+        record_share_link_service: RecordShareLinkService::new(
+            security_services.authorization_service.clone(),
+            Arc::new(metadata_service.clone()),
+            repositories.record_share_link_repository.clone(),
+            Arc::new(Argon2PasswordHasher::new()),
+            repositories.audit_repository.clone(),
+        ),
+        report_subscription_service,
+        reporting_projection_service: ReportingProjectionService::new(
+            security_services.authorization_service.clone(),
+            Arc::new(PostgresReportingProjectionRepository::new(pool.clone())),
+            Arc::new(metadata_service.clone()),
+            repositories.audit_repository.clone(),
+        ),
+        operator_console_service,
         authorization_service: security_services.authorization_service.clone(),
         auth_event_service: security_services.auth_event_service,
         user_service: user_services.user_service,
         tenant_access_service: user_services.tenant_access_service,
         auth_token_service: user_services.auth_token_service,
-        workflow_service: WorkflowService::new(
-            security_services.authorization_service,
-            repositories.workflow_repository,
-            workflow_runtime_service,
-            repositories.audit_repository.clone(),
-            config.workflow_execution_mode,
-        )
-        .with_action_dispatcher(workflow_action_dispatcher)
-        .with_delay_service(Arc::new(TokioWorkflowDelayService))
-        .with_queue_stats_cache(
-            workflow_queue_stats_cache,
-            config.workflow_queue_stats_cache_ttl_seconds,
-        ),
+        email_change_service: user_services.email_change_service,
+        workflow_service,
         mfa_service: user_services.mfa_service,
+        tenant_encryption_service: user_services.tenant_encryption_service,
         rate_limit_service,
+        audit_outbox_relay: repositories.audit_repository.clone(),
         tenant_repository: repositories.tenant_repository,
         passkey_repository: repositories.passkey_repository,
         webauthn,
@@ -107,12 +252,20 @@ pub fn build_app_state(pool: PgPool, config: &ApiConfig) -> Result<AppState, App
         trusted_proxy_cidrs: config.trusted_proxy_cidrs.clone(),
         physical_isolation_mode: config.physical_isolation_mode,
         physical_isolation_tenant_id: config.physical_isolation_tenant_id,
-        bootstrap_token: config.bootstrap_token.clone(),
+        bootstrap_token_ttl_seconds: config.bootstrap_token_ttl_seconds,
         bootstrap_tenant_id: config.bootstrap_tenant_id,
         worker_shared_secret: config.worker_shared_secret.clone(),
+        operator_credentials: config.operator_credentials.clone(),
         workflow_worker_default_lease_seconds: config.workflow_worker_default_lease_seconds,
         workflow_worker_max_claim_limit: config.workflow_worker_max_claim_limit,
         workflow_worker_max_partition_count: config.workflow_worker_max_partition_count,
+        workflow_claim_backpressure_policy: WorkflowClaimBackpressurePolicy::new(
+            config.workflow_worker_max_claim_limit,
+            Some(config.workflow_worker_tenant_claim_limit),
+            config.workflow_worker_backpressure_retry_after_ms,
+        ),
+        workflow_worker_rate_limit_burst: config.workflow_worker_rate_limit_burst,
+        workflow_worker_rate_limit_per_second: config.workflow_worker_rate_limit_per_second,
         runtime_query_max_limit: config.runtime_query_max_limit,
         runtime_query_backpressure: Arc::new(Semaphore::new(config.runtime_query_max_in_flight)),
         workflow_burst_backpressure: Arc::new(Semaphore::new(config.workflow_burst_max_in_flight)),
@@ -122,11 +275,50 @@ pub fn build_app_state(pool: PgPool, config: &ApiConfig) -> Result<AppState, App
         postgres_pool: pool,
         redis_client,
         redis_required: config.requires_redis(),
+        session_store_backend: config.session_store_backend,
+        smtp_probe_address: match &config.email_provider {
+            EmailProviderConfig::Smtp(smtp) => Some((smtp.host.clone(), smtp.port)),
+            EmailProviderConfig::Console => None,
+        },
+        started_at: std::time::Instant::now(),
+        audit_outbox_relay_interval_ms: config.audit_outbox_relay_interval_ms,
+        audit_outbox_relay_batch_size: config.audit_outbox_relay_batch_size,
+        background_job_poll_interval_ms: config.background_job_poll_interval_ms,
+        report_subscription_poll_interval_ms: config.report_subscription_poll_interval_ms,
+        audit_export_poll_interval_ms: config.audit_export_poll_interval_ms,
+        audit_export_batch_size: config.audit_export_batch_size,
         qrywell_api_base_url: config.qrywell_api_base_url.clone(),
         qrywell_api_key: config.qrywell_api_key.clone(),
         qrywell_sync_poll_interval_ms: config.qrywell_sync_poll_interval_ms,
         qrywell_sync_batch_size: config.qrywell_sync_batch_size,
         qrywell_sync_max_attempts: config.qrywell_sync_max_attempts,
+        openapi_swagger_ui_enabled: config.openapi_swagger_ui_enabled,
+        api_version_lifecycle: ApiVersionLifecycle {
+            v1: ApiVersionSchedule {
+                deprecated_at: config.api_v1_deprecated_at,
+                sunset_at: config.api_v1_sunset_at,
+            },
+        },
         http_client: reqwest::Client::new(),
+        release_advisory: Arc::new(ReleaseAdvisoryClient::new(
+            config.release_advisory_url.clone(),
+            config.release_advisory_cache_ttl_seconds,
+        )),
     })
 }
+
+/// Maps configured millisecond budgets to deadlines; `0` disables a budget.
+fn build_operation_deadlines(config: &ApiConfig) -> OperationDeadlines {
+    let budget = |milliseconds: u64| {
+        (milliseconds > 0).then(|| std::time::Duration::from_millis(milliseconds))
+    };
+
+    OperationDeadlines::new(
+        Arc::new(TokioOperationTimer),
+        OperationTimeouts {
+            runtime_query: budget(config.runtime_query_timeout_ms),
+            workflow_dispatch: budget(config.workflow_dispatch_timeout_ms),
+            publish: budget(config.publish_timeout_ms),
+        },
+    )
+}
@@ -1,14 +1,21 @@
 use std::sync::Arc;
 
-use qryvanta_application::{RateLimitRepository, RateLimitService, WorkflowQueueStatsCache};
+use qryvanta_application::{
+    RateLimitRepository, RateLimitService, RuntimeViewResultCache, TokenBucketRepository,
+    WorkflowQueueStatsCache, WorkflowWorkerLeaseCoordinator,
+};
 use qryvanta_core::{AppError, AppResult};
 use qryvanta_infrastructure::{
-    InMemoryWorkflowQueueStatsCache, PostgresRateLimitRepository, RedisRateLimitRepository,
-    RedisWorkflowQueueStatsCache,
+    InMemoryRuntimeViewResultCache, InMemoryTokenBucketRepository, InMemoryWorkflowQueueStatsCache,
+    PostgresRateLimitRepository, RedisRateLimitRepository, RedisRuntimeViewResultCache,
+    RedisTokenBucketRepository, RedisWorkflowQueueStatsCache, RedisWorkflowWorkerLeaseCoordinator,
+    WORKFLOW_WORKER_LEASE_KEY_PREFIX,
 };
 use sqlx::PgPool;
 
-use crate::api_config::{ApiConfig, RateLimitStoreConfig, WorkflowQueueStatsCacheBackend};
+use crate::api_config::{
+    ApiConfig, RateLimitStoreConfig, RuntimeViewCacheBackend, WorkflowQueueStatsCacheBackend,
+};
 
 pub(super) fn build_workflow_queue_stats_cache(
     config: &ApiConfig,
@@ -33,23 +40,68 @@ pub(super) fn build_workflow_queue_stats_cache(
     }
 }
 
+pub(super) fn build_runtime_view_result_cache(
+    config: &ApiConfig,
+    redis_client: Option<redis::Client>,
+) -> AppResult<Arc<dyn RuntimeViewResultCache>> {
+    match config.runtime_view_cache_backend {
+        RuntimeViewCacheBackend::InMemory => Ok(Arc::new(InMemoryRuntimeViewResultCache::new())),
+        RuntimeViewCacheBackend::Redis => {
+            let redis_client = redis_client.ok_or_else(|| {
+                AppError::Validation(
+                    "REDIS_URL is required when RUNTIME_VIEW_CACHE_BACKEND=redis".to_owned(),
+                )
+            })?;
+            Ok(Arc::new(RedisRuntimeViewResultCache::new(
+                redis_client,
+                "qryvanta:runtime_view_results",
+            )))
+        }
+    }
+}
+
+/// Reads worker leases from the same keys workers acquire them under.
+pub(super) fn build_workflow_worker_lease_coordinator(
+    redis_client: Option<redis::Client>,
+) -> Option<Arc<dyn WorkflowWorkerLeaseCoordinator>> {
+    redis_client.map(|redis_client| -> Arc<dyn WorkflowWorkerLeaseCoordinator> {
+        Arc::new(RedisWorkflowWorkerLeaseCoordinator::new(
+            redis_client,
+            WORKFLOW_WORKER_LEASE_KEY_PREFIX,
+        ))
+    })
+}
+
 pub(super) fn build_rate_limit_service(
     pool: &PgPool,
     config: &ApiConfig,
     redis_client: Option<redis::Client>,
 ) -> AppResult<RateLimitService> {
-    let rate_limit_repository: Arc<dyn RateLimitRepository> = match config.rate_limit_store {
-        RateLimitStoreConfig::Postgres => Arc::new(PostgresRateLimitRepository::new(pool.clone())),
+    let (rate_limit_repository, token_bucket_repository): (
+        Arc<dyn RateLimitRepository>,
+        Arc<dyn TokenBucketRepository>,
+    ) = match config.rate_limit_store {
+        RateLimitStoreConfig::Postgres => (
+            Arc::new(PostgresRateLimitRepository::new(pool.clone())),
+            Arc::new(InMemoryTokenBucketRepository::new()),
+        ),
         RateLimitStoreConfig::Redis => {
             let redis_client = redis_client.ok_or_else(|| {
                 AppError::Validation("REDIS_URL is required when RATE_LIMIT_STORE=redis".to_owned())
             })?;
-            Arc::new(RedisRateLimitRepository::new(
-                redis_client,
-                "qryvanta:rate_limit",
-            ))
+            (
+                Arc::new(RedisRateLimitRepository::new(
+                    redis_client.clone(),
+                    "qryvanta:rate_limit",
+                )),
+                Arc::new(RedisTokenBucketRepository::new(
+                    redis_client,
+                    "qryvanta:token_bucket",
+                )),
+            )
         }
     };
 
-    Ok(RateLimitService::new(rate_limit_repository))
+    Ok(RateLimitService::new(rate_limit_repository)
+        .with_token_bucket_repository(token_bucket_repository))
 }
@@ -2,9 +2,16 @@ use std::sync::Arc;
 
 use qryvanta_application::TenantRepository;
 use qryvanta_infrastructure::{
-    PostgresAppRepository, PostgresAuditLogRepository, PostgresAuditRepository,
-    PostgresAuthEventRepository, PostgresAuthorizationRepository, PostgresExtensionRepository,
-    PostgresMetadataRepository, PostgresPasskeyRepository, PostgresSecurityAdminRepository,
+    PostgresAppRepository, PostgresAuditExportRepository, PostgresAuditLogRepository,
+    PostgresAuditRepository, PostgresAuthEventRepository, PostgresAuthorizationRepository,
+    PostgresBackgroundJobRepository, PostgresComplianceZoneRepository,
+    PostgresContactConsentRepository, PostgresContactIdentityRepository,
+    PostgresDataErasureRepository, PostgresExtensionRepository, PostgresFeatureFlagRepository,
+    PostgresFieldChangeApprovalRepository, PostgresFieldMaskingRepository,
+    PostgresLegalHoldRepository, PostgresLocalizedLabelRepository, PostgresMetadataRepository,
+    PostgresPasskeyRepository, PostgresPublishCoordinationRepository,
+    PostgresRecordAccessRepository, PostgresRecordShareLinkRepository,
+    PostgresSecurityAdminRepository, PostgresTenantEncryptionKeyRepository,
     PostgresTenantRepository, PostgresUserRepository, PostgresWorkflowRepository,
 };
 use sqlx::PgPool;
@@ -18,8 +25,23 @@ pub(super) struct RepositorySet {
     pub(super) authorization_repository: Arc<PostgresAuthorizationRepository>,
     pub(super) security_admin_repository: Arc<PostgresSecurityAdminRepository>,
     pub(super) audit_log_repository: Arc<PostgresAuditLogRepository>,
+    pub(super) audit_export_repository: Arc<PostgresAuditExportRepository>,
+    pub(super) background_job_repository: Arc<PostgresBackgroundJobRepository>,
+    pub(super) compliance_zone_repository: Arc<PostgresComplianceZoneRepository>,
     pub(super) auth_event_repository: Arc<PostgresAuthEventRepository>,
+    pub(super) contact_consent_repository: Arc<PostgresContactConsentRepository>,
+    pub(super) contact_identity_repository: Arc<PostgresContactIdentityRepository>,
+    pub(super) data_erasure_repository: Arc<PostgresDataErasureRepository>,
+    pub(super) feature_flag_repository: Arc<PostgresFeatureFlagRepository>,
+    pub(super) field_change_approval_repository: Arc<PostgresFieldChangeApprovalRepository>,
+    pub(super) field_masking_repository: Arc<PostgresFieldMaskingRepository>,
+    pub(super) legal_hold_repository: Arc<PostgresLegalHoldRepository>,
+    pub(super) localized_label_repository: Arc<PostgresLocalizedLabelRepository>,
+    pub(super) publish_coordination_repository: Arc<PostgresPublishCoordinationRepository>,
+    pub(super) record_access_repository: Arc<PostgresRecordAccessRepository>,
+    pub(super) record_share_link_repository: Arc<PostgresRecordShareLinkRepository>,
     pub(super) tenant_repository: Arc<dyn TenantRepository>,
+    pub(super) tenant_encryption_key_repository: Arc<PostgresTenantEncryptionKeyRepository>,
     pub(super) passkey_repository: PostgresPasskeyRepository,
     pub(super) user_repository: Arc<PostgresUserRepository>,
 }
@@ -34,8 +56,31 @@ pub(super) fn build_repository_set(pool: &PgPool) -> RepositorySet {
         authorization_repository: Arc::new(PostgresAuthorizationRepository::new(pool.clone())),
         security_admin_repository: Arc::new(PostgresSecurityAdminRepository::new(pool.clone())),
         audit_log_repository: Arc::new(PostgresAuditLogRepository::new(pool.clone())),
+        audit_export_repository: Arc::new(PostgresAuditExportRepository::new(pool.clone())),
+        background_job_repository: Arc::new(PostgresBackgroundJobRepository::new(pool.clone())),
+        compliance_zone_repository: Arc::new(PostgresComplianceZoneRepository::new(pool.clone())),
         auth_event_repository: Arc::new(PostgresAuthEventRepository::new(pool.clone())),
+        contact_consent_repository: Arc::new(PostgresContactConsentRepository::new(pool.clone())),
+        contact_identity_repository: Arc::new(PostgresContactIdentityRepository::new(pool.clone())),
+        data_erasure_repository: Arc::new(PostgresDataErasureRepository::new(pool.clone())),
+        feature_flag_repository: Arc::new(PostgresFeatureFlagRepository::new(pool.clone())),
+        field_change_approval_repository: Arc::new(PostgresFieldChangeApprovalRepository::new(
+            pool.clone(),
+        )),
+        field_masking_repository: Arc::new(PostgresFieldMaskingRepository::new(pool.clone())),
+        legal_hold_repository: Arc::new(PostgresLegalHoldRepository::new(pool.clone())),
+        localized_label_repository: Arc::new(PostgresLocalizedLabelRepository::new(pool.clone())),
+        publish_coordination_repository: Arc::new(PostgresPublishCoordinationRepository::new(
+            pool.clone(),
+        )),
+        record_access_repository: Arc::new(PostgresRecordAccessRepository::new(pool.clone())),
+        record_share_link_repository: Arc::new(PostgresRecordShareLinkRepository::new(
+            pool.clone(),
+        )),
         tenant_repository: Arc::new(PostgresTenantRepository::new(pool.clone())),
+        tenant_encryption_key_repository: Arc::new(PostgresTenantEncryptionKeyRepository::new(
+            pool.clone(),
+        )),
         passkey_repository: PostgresPasskeyRepository::new(pool.clone()),
         user_repository: Arc::new(PostgresUserRepository::new(pool.clone())),
     }
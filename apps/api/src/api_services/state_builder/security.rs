@@ -1,19 +1,39 @@
-use qryvanta_application::{AuthEventService, AuthorizationService, SecurityAdminService};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use qryvanta_application::{
+    AuditExportService, AuthEventService, AuthorizationService, ComplianceZoneService,
+    DataErasureService, FeatureFlagService, FieldChangeApprovalService, FieldMaskingService,
+    LegalHoldService, RecordAccessService, SecurityAdminService,
+};
+
+use qryvanta_core::AppError;
+use qryvanta_infrastructure::SinkAuditExportShipper;
 
 use crate::api_config::ApiConfig;
 
+use super::super::email::build_email_service;
+
 use super::repositories::RepositorySet;
 
 pub(super) struct SecurityServices {
     pub(super) authorization_service: AuthorizationService,
     pub(super) security_admin_service: SecurityAdminService,
+    pub(super) legal_hold_service: LegalHoldService,
+    pub(super) audit_export_service: AuditExportService,
+    pub(super) compliance_zone_service: ComplianceZoneService,
+    pub(super) data_erasure_service: DataErasureService,
+    pub(super) feature_flag_service: FeatureFlagService,
+    pub(super) field_change_approval_service: FieldChangeApprovalService,
+    pub(super) field_masking_service: FieldMaskingService,
+    pub(super) record_access_service: RecordAccessService,
     pub(super) auth_event_service: AuthEventService,
 }
 
 pub(super) fn build_security_services(
     repositories: &RepositorySet,
     config: &ApiConfig,
-) -> SecurityServices {
+) -> Result<SecurityServices, AppError> {
     let authorization_service = AuthorizationService::new(
         repositories.authorization_repository.clone(),
         repositories.audit_repository.clone(),
@@ -25,13 +45,77 @@ pub(super) fn build_security_services(
         repositories.audit_log_repository.clone(),
         repositories.audit_repository.clone(),
     )
+    .with_audit_immutable_mode(config.audit_immutable_mode)
+    .with_mfa_reset(
+        repositories.user_repository.clone(),
+        build_email_service(config)?,
+    );
+
+    let legal_hold_service = LegalHoldService::new(
+        authorization_service.clone(),
+        repositories.legal_hold_repository.clone(),
+        repositories.audit_repository.clone(),
+    );
+
+    let audit_export_service = AuditExportService::new(
+        authorization_service.clone(),
+        repositories.audit_export_repository.clone(),
+        Arc::new(SinkAuditExportShipper::new(
+            config.audit_export_file_root.as_ref().map(PathBuf::from),
+        )),
+        repositories.audit_repository.clone(),
+    );
+
+    let compliance_zone_service = ComplianceZoneService::new(
+        authorization_service.clone(),
+        repositories.compliance_zone_repository.clone(),
+        repositories.audit_repository.clone(),
+    );
+
+    let data_erasure_service = DataErasureService::new(
+        authorization_service.clone(),
+        repositories.data_erasure_repository.clone(),
+        repositories.audit_repository.clone(),
+    )
     .with_audit_immutable_mode(config.audit_immutable_mode);
 
+    let feature_flag_service = FeatureFlagService::new(
+        authorization_service.clone(),
+        repositories.feature_flag_repository.clone(),
+        repositories.audit_repository.clone(),
+    );
+
+    let field_change_approval_service = FieldChangeApprovalService::new(
+        authorization_service.clone(),
+        repositories.field_change_approval_repository.clone(),
+        repositories.audit_repository.clone(),
+    );
+
+    let field_masking_service = FieldMaskingService::new(
+        authorization_service.clone(),
+        repositories.field_masking_repository.clone(),
+        repositories.audit_repository.clone(),
+    );
+
+    let record_access_service = RecordAccessService::new(
+        authorization_service.clone(),
+        repositories.record_access_repository.clone(),
+        repositories.audit_repository.clone(),
+    );
+
     let auth_event_service = AuthEventService::new(repositories.auth_event_repository.clone());
 
-    SecurityServices {
+    Ok(SecurityServices {
         authorization_service,
         security_admin_service,
+        legal_hold_service,
+        audit_export_service,
+        compliance_zone_service,
+        data_erasure_service,
+        feature_flag_service,
+        field_change_approval_service,
+        field_masking_service,
+        record_access_service,
         auth_event_service,
-    }
+    })
 }
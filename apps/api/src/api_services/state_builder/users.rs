@@ -1,47 +1,54 @@
 use std::sync::Arc;
 
 use qryvanta_application::{
-    AuthEventService, AuthTokenService, AuthorizationService, MfaService, TenantAccessService,
-    TenantRepository, UserService,
+    AuthEventService, AuthTokenService, AuthorizationService, EmailChangeService, MfaService,
+    SecretEncryptor, TenantAccessService, TenantEncryptionService, UserService,
 };
 use qryvanta_core::AppError;
 use qryvanta_infrastructure::{
     AesSecretEncryptor, Argon2PasswordHasher, AwsKmsEnvelopeSecretEncryptor,
-    PostgresAuthTokenRepository, PostgresUserRepository, TotpRsProvider,
+    PostgresAuthTokenRepository, PostgresEmailChangeRepository, SecretReferenceTenantKeyProvider,
+    TotpRsProvider,
 };
 use sqlx::PgPool;
 
 use crate::api_config::{ApiConfig, TotpEncryptionConfig};
 
 use super::super::email::build_email_service;
+use super::repositories::RepositorySet;
 
 pub(super) struct UserServices {
     pub(super) user_service: UserService,
     pub(super) tenant_access_service: TenantAccessService,
     pub(super) auth_token_service: AuthTokenService,
+    pub(super) email_change_service: EmailChangeService,
     pub(super) mfa_service: MfaService,
+    pub(super) tenant_encryption_service: TenantEncryptionService,
+    pub(super) secret_encryptor: Arc<dyn SecretEncryptor>,
 }
 
 pub(super) fn build_user_services(
     pool: &PgPool,
     config: &ApiConfig,
-    tenant_repository: Arc<dyn TenantRepository>,
-    user_repository: Arc<PostgresUserRepository>,
+    repositories: &RepositorySet,
     authorization_service: AuthorizationService,
     auth_event_service: AuthEventService,
 ) -> Result<UserServices, AppError> {
+    let tenant_repository = repositories.tenant_repository.clone();
+    let user_repository = repositories.user_repository.clone();
     let password_hasher = Arc::new(Argon2PasswordHasher::new());
 
     let user_service = UserService::new(
         user_repository.clone(),
         password_hasher.clone(),
         tenant_repository.clone(),
-        auth_event_service,
+        auth_event_service.clone(),
     );
     let tenant_access_service = TenantAccessService::new(
         tenant_repository,
         user_repository.clone(),
-        authorization_service,
+        authorization_service.clone(),
+        repositories.audit_repository.clone(),
     );
 
     let auth_token_repository = Arc::new(PostgresAuthTokenRepository::new(pool.clone()));
@@ -51,32 +58,49 @@ pub(super) fn build_user_services(
         email_service,
         config.frontend_url.clone(),
     );
+    let email_change_service = EmailChangeService::new(
+        Arc::new(PostgresEmailChangeRepository::new(pool.clone())),
+        user_repository.clone(),
+        password_hasher.clone(),
+        auth_token_service.clone(),
+        auth_event_service,
+    );
 
     let totp_provider = Arc::new(TotpRsProvider::new("Qryvanta"));
-    let secret_encryptor: Arc<dyn qryvanta_application::SecretEncryptor> =
-        match &config.totp_encryption {
-            TotpEncryptionConfig::StaticKey { key_hex } => {
-                Arc::new(AesSecretEncryptor::from_hex(key_hex)?)
-            }
-            TotpEncryptionConfig::AwsKmsEnvelope {
-                kms_key_id,
-                legacy_static_key_hex,
-            } => Arc::new(AwsKmsEnvelopeSecretEncryptor::new(
-                kms_key_id,
-                legacy_static_key_hex.as_deref(),
-            )?),
-        };
+    let secret_encryptor: Arc<dyn SecretEncryptor> = match &config.totp_encryption {
+        TotpEncryptionConfig::StaticKey { key_hex } => {
+            Arc::new(AesSecretEncryptor::from_hex(key_hex)?)
+        }
+        TotpEncryptionConfig::AwsKmsEnvelope {
+            kms_key_id,
+            legacy_static_key_hex,
+        } => Arc::new(AwsKmsEnvelopeSecretEncryptor::new(
+            kms_key_id,
+            legacy_static_key_hex.as_deref(),
+        )?),
+    };
+    let tenant_encryption_service = TenantEncryptionService::new(
+        authorization_service,
+        repositories.tenant_encryption_key_repository.clone(),
+        Arc::new(SecretReferenceTenantKeyProvider::new()),
+        secret_encryptor.clone(),
+        repositories.audit_repository.clone(),
+    );
     let mfa_service = MfaService::new(
         user_repository,
         password_hasher,
         totp_provider,
-        secret_encryptor,
-    );
+        secret_encryptor.clone(),
+    )
+    .with_tenant_encryption(tenant_encryption_service.clone());
 
     Ok(UserServices {
         user_service,
         tenant_access_service,
         auth_token_service,
+        email_change_service,
         mfa_service,
+        tenant_encryption_service,
+        secret_encryptor,
     })
 }
@@ -0,0 +1,446 @@
+//! Versioned API route prefixes and their lifecycle headers.
+//!
+//! The protected API is served under `/api/v1` and `/api/v2`, and `/api`
+//! stays an alias of `v1`. Every versioned response names its version in
+//! the `api-version` header. Once operators schedule the retirement of `v1`,
+//! its responses also carry RFC 9745 `Deprecation`, RFC 8594 `Sunset` and a
+//! `successor-version` link.
+//!
+//! Breaking DTO changes land in the newest version only. Older versions keep
+//! their contract through [`ApiVersionAdapter`]s, which rewrite JSON request
+//! bodies into the current shape and responses back into the old one.
+
+use axum::body::{Body, to_bytes};
+use axum::extract::{Request, State};
+use axum::http::{HeaderMap, HeaderValue, Method, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::Response;
+use chrono::{DateTime, Utc};
+use qryvanta_core::AppError;
+use serde_json::Value;
+
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+pub const API_VERSION_HEADER: &str = "api-version";
+pub const DEPRECATION_HEADER: &str = "deprecation";
+pub const SUNSET_HEADER: &str = "sunset";
+
+/// Largest JSON body an adapter buffers for rewriting.
+const ADAPTED_BODY_LIMIT_BYTES: usize = 16 * 1024 * 1024;
+
+/// Major version of the HTTP API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiVersion {
+    V1,
+    V2,
+}
+
+impl ApiVersion {
+    /// Versions in release order.
+    pub const ALL: [Self; 2] = [Self::V1, Self::V2];
+
+    /// Version new integrations should target.
+    pub const CURRENT: Self = Self::V2;
+
+    /// Version served by the unprefixed `/api` alias.
+    pub const UNPREFIXED: Self = Self::V1;
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::V1 => "v1",
+            Self::V2 => "v2",
+        }
+    }
+
+    /// Route prefix serving this version.
+    #[must_use]
+    pub fn path_prefix(self) -> &'static str {
+        match self {
+            Self::V1 => "/api/v1",
+            Self::V2 => "/api/v2",
+        }
+    }
+
+    /// Next version, if any.
+    #[must_use]
+    pub fn successor(self) -> Option<Self> {
+        match self {
+            Self::V1 => Some(Self::V2),
+            Self::V2 => None,
+        }
+    }
+}
+
+/// Deprecation schedule of one API version.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ApiVersionSchedule {
+    /// When the version is (or becomes) deprecated.
+    pub deprecated_at: Option<DateTime<Utc>>,
+    /// When the version stops being served.
+    pub sunset_at: Option<DateTime<Utc>>,
+}
+
+/// Deprecation schedules of the served API versions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ApiVersionLifecycle {
+    pub v1: ApiVersionSchedule,
+}
+
+impl ApiVersionLifecycle {
+    /// Schedule of `version`; the current version is never deprecated.
+    #[must_use]
+    pub fn schedule(&self, version: ApiVersion) -> ApiVersionSchedule {
+        match version {
+            ApiVersion::V1 => self.v1,
+            ApiVersion::V2 => ApiVersionSchedule::default(),
+        }
+    }
+
+    /// Lifecycle status of `version` at `now`.
+    #[must_use]
+    pub fn status(&self, version: ApiVersion, now: DateTime<Utc>) -> &'static str {
+        if version == ApiVersion::CURRENT {
+            return "current";
+        }
+        match self.schedule(version).deprecated_at {
+            Some(deprecated_at) if deprecated_at <= now => "deprecated",
+            _ => "supported",
+        }
+    }
+}
+
+/// Keeps an older API version's JSON contract for one group of endpoints.
+///
+/// Paths are relative to the version prefix, e.g. `/entities/account`.
+pub trait ApiVersionAdapter: Send + Sync {
+    /// Whether the adapter rewrites this endpoint.
+    fn applies_to(&self, method: &Method, path: &str) -> bool;
+
+    /// Rewrites an old-version request body into the current shape.
+    fn adapt_request(&self, _body: &mut Value) {}
+
+    /// Rewrites a current response body into the old-version shape.
+    fn adapt_response(&self, _status: StatusCode, _body: &mut Value) {}
+}
+
+/// Adapters that keep `v1` clients working against `v2` handlers.
+///
+/// `v2` launched with the `v1` contract, so none are registered yet.
+fn v1_adapters() -> &'static [&'static dyn ApiVersionAdapter] {
+    &[]
+}
+
+/// Middleware for routes served as `v1`, including the `/api` alias.
+pub async fn serve_v1(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> ApiResult<Response> {
+    let mut response = adapt_exchange(v1_adapters(), request, next).await?;
+    write_version_headers(
+        response.headers_mut(),
+        ApiVersion::V1,
+        state.api_version_lifecycle.schedule(ApiVersion::V1),
+    );
+
+    Ok(response)
+}
+
+/// Middleware for routes served as `v2`.
+pub async fn serve_v2(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> ApiResult<Response> {
+    let mut response = next.run(request).await;
+    write_version_headers(
+        response.headers_mut(),
+        ApiVersion::V2,
+        state.api_version_lifecycle.schedule(ApiVersion::V2),
+    );
+
+    Ok(response)
+}
+
+/// Runs the request through the adapters matching its endpoint.
+///
+/// Bodies are only buffered when an adapter applies, and only JSON bodies
+/// are rewritten.
+async fn adapt_exchange(
+    adapters: &[&dyn ApiVersionAdapter],
+    request: Request,
+    next: Next,
+) -> ApiResult<Response> {
+    let matching = adapters
+        .iter()
+        .filter(|adapter| adapter.applies_to(request.method(), request.uri().path()))
+        .collect::<Vec<_>>();
+    if matching.is_empty() {
+        return Ok(next.run(request).await);
+    }
+
+    let (mut parts, body) = request.into_parts();
+    let body = if is_json(&parts.headers) {
+        let bytes = read_body(body).await?;
+        match serde_json::from_slice::<Value>(&bytes) {
+            Ok(mut value) => {
+                for adapter in &matching {
+                    adapter.adapt_request(&mut value);
+                }
+                let bytes = serde_json::to_vec(&value).map_err(|error| {
+                    AppError::Internal(format!("failed to encode adapted request: {error}"))
+                })?;
+                parts.headers.remove(header::CONTENT_LENGTH);
+                Body::from(bytes)
+            }
+            // Malformed JSON is left for the handler to reject.
+            Err(_) => Body::from(bytes),
+        }
+    } else {
+        body
+    };
+
+    let response = next.run(Request::from_parts(parts, body)).await;
+    if !is_json(response.headers()) {
+        return Ok(response);
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = read_response_body(body).await?;
+    let Ok(mut value) = serde_json::from_slice::<Value>(&bytes) else {
+        return Ok(Response::from_parts(parts, Body::from(bytes)));
+    };
+    for adapter in matching.iter().rev() {
+        adapter.adapt_response(parts.status, &mut value);
+    }
+    let bytes = serde_json::to_vec(&value).map_err(|error| {
+        AppError::Internal(format!("failed to encode adapted response: {error}"))
+    })?;
+    parts.headers.remove(header::CONTENT_LENGTH);
+
+    Ok(Response::from_parts(parts, Body::from(bytes)))
+}
+
+async fn read_body(body: Body) -> ApiResult<axum::body::Bytes> {
+    to_bytes(body, ADAPTED_BODY_LIMIT_BYTES).await.map_err(|_| {
+        AppError::Validation(format!(
+            "request body exceeds {ADAPTED_BODY_LIMIT_BYTES} bytes for this API version"
+        ))
+        .into()
+    })
+}
+
+async fn read_response_body(body: Body) -> ApiResult<axum::body::Bytes> {
+    to_bytes(body, ADAPTED_BODY_LIMIT_BYTES)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to read response for API version adapters: {error}"
+            ))
+            .into()
+        })
+}
+
+fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
+
+fn write_version_headers(
+    headers: &mut HeaderMap,
+    version: ApiVersion,
+    schedule: ApiVersionSchedule,
+) {
+    headers.insert(
+        header::HeaderName::from_static(API_VERSION_HEADER),
+        HeaderValue::from_static(version.as_str()),
+    );
+
+    let Some(deprecated_at) = schedule.deprecated_at else {
+        return;
+    };
+    if let Ok(value) = HeaderValue::from_str(format!("@{}", deprecated_at.timestamp()).as_str()) {
+        headers.insert(header::HeaderName::from_static(DEPRECATION_HEADER), value);
+    }
+    if let Some(sunset_at) = schedule.sunset_at
+        && let Ok(value) = HeaderValue::from_str(
+            sunset_at
+                .format("%a, %d %b %Y %H:%M:%S GMT")
+                .to_string()
+                .as_str(),
+        )
+    {
+        headers.insert(header::HeaderName::from_static(SUNSET_HEADER), value);
+    }
+    if let Some(successor) = version.successor()
+        && let Ok(value) = HeaderValue::from_str(
+            format!("<{}>; rel=\"successor-version\"", successor.path_prefix()).as_str(),
+        )
+    {
+        // Appended so pagination links on the same response are kept.
+        headers.append(header::LINK, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum::extract::Request;
+    use axum::http::{HeaderMap, Method, StatusCode, header};
+    use axum::middleware::{Next, from_fn};
+    use axum::routing::post;
+    use chrono::{TimeZone, Utc};
+    use serde_json::{Value, json};
+    use tokio::net::TcpListener;
+
+    use super::{
+        ApiVersion, ApiVersionAdapter, ApiVersionLifecycle, ApiVersionSchedule, adapt_exchange,
+        write_version_headers,
+    };
+
+    /// Old clients send and receive `title`; current handlers use `name`.
+    struct RenamedTitleAdapter;
+
+    impl ApiVersionAdapter for RenamedTitleAdapter {
+        fn applies_to(&self, method: &Method, path: &str) -> bool {
+            method == Method::POST && path == "/notes"
+        }
+
+        fn adapt_request(&self, body: &mut Value) {
+            if let Some(object) = body.as_object_mut()
+                && let Some(title) = object.remove("title")
+            {
+                object.insert("name".to_owned(), title);
+            }
+        }
+
+        fn adapt_response(&self, _status: StatusCode, body: &mut Value) {
+            if let Some(object) = body.as_object_mut()
+                && let Some(name) = object.remove("name")
+            {
+                object.insert("title".to_owned(), name);
+            }
+        }
+    }
+
+    static ADAPTERS: [&dyn ApiVersionAdapter; 1] = [&RenamedTitleAdapter];
+
+    fn adapted_router() -> Router {
+        Router::new()
+            .route(
+                "/notes",
+                post(|axum::Json(body): axum::Json<Value>| async move {
+                    axum::Json(json!({"name": body["name"], "saved": true}))
+                }),
+            )
+            .route(
+                "/other",
+                post(|axum::Json(body): axum::Json<Value>| async move { axum::Json(body) }),
+            )
+            .layer(from_fn(|request: Request, next: Next| async move {
+                adapt_exchange(&ADAPTERS, request, next).await
+            }))
+    }
+
+    async fn post_json(base_url: &str, path: &str, body: Value) -> Value {
+        let response = reqwest::Client::new()
+            .post(format!("{base_url}{path}"))
+            .json(&body)
+            .send()
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(response.status(), StatusCode::OK);
+        response.json().await.unwrap_or_else(|_| unreachable!())
+    }
+
+    #[tokio::test]
+    async fn adapters_rewrite_matching_requests_and_responses_only() {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        let base_url = format!(
+            "http://{}",
+            listener.local_addr().unwrap_or_else(|_| unreachable!())
+        );
+        let server = tokio::spawn(async move { axum::serve(listener, adapted_router()).await });
+
+        assert_eq!(
+            post_json(base_url.as_str(), "/notes", json!({"title": "Q3"})).await,
+            json!({"title": "Q3", "saved": true})
+        );
+        assert_eq!(
+            post_json(base_url.as_str(), "/other", json!({"title": "Q3"})).await,
+            json!({"title": "Q3"})
+        );
+
+        server.abort();
+    }
+
+    #[test]
+    fn deprecated_versions_announce_their_schedule_and_successor() {
+        let deprecated_at = Utc
+            .with_ymd_and_hms(2027, 1, 1, 0, 0, 0)
+            .single()
+            .unwrap_or_else(|| unreachable!());
+        let sunset_at = Utc
+            .with_ymd_and_hms(2027, 7, 1, 0, 0, 0)
+            .single()
+            .unwrap_or_else(|| unreachable!());
+        let lifecycle = ApiVersionLifecycle {
+            v1: ApiVersionSchedule {
+                deprecated_at: Some(deprecated_at),
+                sunset_at: Some(sunset_at),
+            },
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::LINK,
+            "</api/v1/apps?offset=50>; rel=\"next\""
+                .parse()
+                .unwrap_or_else(|_| unreachable!()),
+        );
+        write_version_headers(
+            &mut headers,
+            ApiVersion::V1,
+            lifecycle.schedule(ApiVersion::V1),
+        );
+        assert_eq!(headers["api-version"], "v1");
+        assert_eq!(headers["deprecation"], "@1798761600");
+        assert_eq!(headers["sunset"], "Thu, 01 Jul 2027 00:00:00 GMT");
+        assert_eq!(
+            headers
+                .get_all(header::LINK)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .collect::<Vec<_>>(),
+            vec![
+                "</api/v1/apps?offset=50>; rel=\"next\"",
+                "</api/v2>; rel=\"successor-version\"",
+            ]
+        );
+
+        let mut headers = HeaderMap::new();
+        write_version_headers(
+            &mut headers,
+            ApiVersion::V2,
+            lifecycle.schedule(ApiVersion::V2),
+        );
+        assert_eq!(headers["api-version"], "v2");
+        assert!(headers.get("deprecation").is_none());
+
+        assert_eq!(
+            lifecycle.status(ApiVersion::V1, deprecated_at),
+            "deprecated"
+        );
+        assert_eq!(
+            lifecycle.status(ApiVersion::V1, deprecated_at - chrono::Duration::days(1)),
+            "supported"
+        );
+        assert_eq!(lifecycle.status(ApiVersion::V2, sunset_at), "current");
+    }
+}
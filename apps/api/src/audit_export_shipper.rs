@@ -0,0 +1,32 @@
+//! Background shipper that streams tenant audit entries to configured export sinks.
+
+use std::time::Duration;
+
+use tracing::{error, info};
+
+use crate::state::AppState;
+
+/// Sinks claimed per poll.
+const AUDIT_EXPORT_SINK_BATCH_SIZE: usize = 25;
+
+pub fn spawn_audit_export_shipper(state: AppState) {
+    tokio::spawn(async move {
+        info!(
+            interval_ms = state.audit_export_poll_interval_ms,
+            batch_size = state.audit_export_batch_size,
+            "audit export shipper started"
+        );
+
+        loop {
+            if let Err(error) = state
+                .audit_export_service
+                .ship_pending(AUDIT_EXPORT_SINK_BATCH_SIZE, state.audit_export_batch_size)
+                .await
+            {
+                error!(error = %error, "audit export shipping failed");
+            }
+
+            tokio::time::sleep(Duration::from_millis(state.audit_export_poll_interval_ms)).await;
+        }
+    });
+}
@@ -0,0 +1,33 @@
+//! Background relay that drains the audit outbox into the tenant audit chains.
+
+use std::time::Duration;
+
+use tracing::{error, info};
+
+use crate::state::AppState;
+
+pub fn spawn_audit_outbox_relay(state: AppState) {
+    tokio::spawn(async move {
+        info!(
+            interval_ms = state.audit_outbox_relay_interval_ms,
+            batch_size = state.audit_outbox_relay_batch_size,
+            "audit outbox relay started"
+        );
+
+        loop {
+            // Keep draining while full batches come back so a backlog clears
+            // without waiting one poll interval per batch.
+            match state
+                .audit_outbox_relay
+                .relay_pending_events(state.audit_outbox_relay_batch_size)
+                .await
+            {
+                Ok(relayed) if relayed >= state.audit_outbox_relay_batch_size => continue,
+                Ok(_) => {}
+                Err(error) => error!(error = %error, "audit outbox relay batch failed"),
+            }
+
+            tokio::time::sleep(Duration::from_millis(state.audit_outbox_relay_interval_ms)).await;
+        }
+    });
+}
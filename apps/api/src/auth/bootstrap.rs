@@ -4,16 +4,19 @@ use axum::http::HeaderMap;
 use axum::http::StatusCode;
 use qryvanta_application::AuthEvent;
 use qryvanta_core::AppError;
-use qryvanta_domain::{AuthEventOutcome, AuthEventType};
+use qryvanta_domain::{AuthEventOutcome, AuthEventType, AuthTokenType};
 use serde::Deserialize;
 use std::net::SocketAddr;
 use tower_sessions::Session;
+use tracing::info;
 
+use crate::api_config::{ApiConfig, MAX_BOOTSTRAP_TOKEN_TTL_SECONDS};
+use crate::dto::BootstrapTokenRotationResponse;
 use crate::error::ApiResult;
 use crate::state::AppState;
 
 use super::session_helpers::{
-    active_identity_for_subject, constant_time_eq, extract_request_context, mark_step_up_verified,
+    active_identity_for_subject, extract_request_context, mark_step_up_verified,
     persist_authenticated_identity,
 };
 
@@ -36,7 +39,15 @@ pub async fn bootstrap_handler(
         state.trust_proxy_headers,
         &state.trusted_proxy_cidrs,
     );
-    if !constant_time_eq(payload.token.as_str(), state.bootstrap_token.as_str()) {
+    let consumed = state
+        .auth_token_service
+        .consume_valid_token(payload.token.as_str(), AuthTokenType::Bootstrap)
+        .await;
+    if let Err(error) = consumed {
+        if !matches!(error, AppError::Unauthorized(_)) {
+            return Err(error.into());
+        }
+
         state
             .auth_event_service
             .record_event(AuthEvent {
@@ -47,7 +58,10 @@ pub async fn bootstrap_handler(
                 user_agent,
             })
             .await?;
-        return Err(AppError::Unauthorized("invalid bootstrap token".to_owned()).into());
+        return Err(AppError::Unauthorized(
+            "invalid, expired, or already used bootstrap token".to_owned(),
+        )
+        .into());
     }
 
     let tenant_id = state
@@ -88,3 +102,64 @@ pub async fn bootstrap_handler(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Revokes outstanding bootstrap tokens and returns a fresh single-use token.
+pub async fn rotate_bootstrap_token_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ConnectInfo(connect_info): ConnectInfo<SocketAddr>,
+) -> ApiResult<Json<BootstrapTokenRotationResponse>> {
+    let (ip_address, user_agent) = extract_request_context(
+        &headers,
+        Some(connect_info),
+        state.trust_proxy_headers,
+        &state.trusted_proxy_cidrs,
+    );
+    let issued = state
+        .auth_token_service
+        .rotate_bootstrap_token(bootstrap_token_ttl(state.bootstrap_token_ttl_seconds))
+        .await?;
+
+    state
+        .auth_event_service
+        .record_event(AuthEvent {
+            subject: None,
+            event_type: AuthEventType::BootstrapTokenRotated,
+            outcome: AuthEventOutcome::Success,
+            ip_address,
+            user_agent,
+        })
+        .await?;
+
+    Ok(Json(BootstrapTokenRotationResponse {
+        token: issued.token,
+        expires_at: issued.expires_at.to_rfc3339(),
+    }))
+}
+
+/// Registers `AUTH_BOOTSTRAP_TOKEN` as a single-use token the first time it is seen.
+pub async fn register_configured_bootstrap_token(
+    state: &AppState,
+    config: &ApiConfig,
+) -> Result<(), AppError> {
+    let registered = state
+        .auth_token_service
+        .register_bootstrap_token(
+            config.bootstrap_token.as_str(),
+            bootstrap_token_ttl(config.bootstrap_token_ttl_seconds),
+        )
+        .await?;
+    if registered {
+        info!(
+            ttl_seconds = config.bootstrap_token_ttl_seconds,
+            "registered configured bootstrap token"
+        );
+    }
+
+    Ok(())
+}
+
+fn bootstrap_token_ttl(ttl_seconds: u64) -> chrono::Duration {
+    let ttl_seconds = ttl_seconds.min(MAX_BOOTSTRAP_TOKEN_TTL_SECONDS);
+    chrono::Duration::seconds(i64::try_from(ttl_seconds).unwrap_or_default())
+}
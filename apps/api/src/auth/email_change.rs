@@ -0,0 +1,151 @@
+use axum::Json;
+use axum::extract::{ConnectInfo, Extension, State};
+use axum::http::{HeaderMap, StatusCode};
+use qryvanta_application::{
+    EmailChangeConfirmationOutcome, PendingEmailChange, RequestEmailChangeParams,
+};
+use qryvanta_core::{AppError, UserIdentity};
+use qryvanta_domain::UserId;
+use std::net::SocketAddr;
+use tower_sessions::Session;
+use uuid::Uuid;
+
+use crate::dto::{
+    ConfirmEmailChangeRequest, ConfirmEmailChangeResponse, EmailChangeRequest,
+    EmailChangeStatusResponse, PendingEmailChangeResponse,
+};
+use crate::error::ApiResult;
+use crate::rate_limit_headers::enforce_rate_limit;
+use crate::state::AppState;
+
+use super::session_helpers::{extract_request_context, require_recent_step_up};
+use super::{confirm_email_change_rate_rule, email_change_request_rate_rule};
+
+/// GET /api/profile/email-change - Pending email change for the profile.
+pub async fn email_change_status_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<EmailChangeStatusResponse>> {
+    let pending = state
+        .email_change_service
+        .pending_change(user_id_from_subject(&user)?)
+        .await?;
+
+    Ok(Json(EmailChangeStatusResponse {
+        pending: pending.map(PendingEmailChangeResponse::from),
+    }))
+}
+
+/// POST /api/profile/email-change - Request an email change confirmed by both addresses.
+pub async fn request_email_change_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ConnectInfo(connect_info): ConnectInfo<SocketAddr>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Json(payload): Json<EmailChangeRequest>,
+) -> ApiResult<Json<EmailChangeStatusResponse>> {
+    let request_rule = email_change_request_rate_rule();
+    enforce_rate_limit(&state, &request_rule, user.subject()).await?;
+
+    let (ip_address, user_agent) = extract_request_context(
+        &headers,
+        Some(connect_info),
+        state.trust_proxy_headers,
+        &state.trusted_proxy_cidrs,
+    );
+    let pending = state
+        .email_change_service
+        .request_change(RequestEmailChangeParams {
+            user_id: user_id_from_subject(&user)?,
+            new_email: payload.new_email,
+            current_password: payload.current_password,
+            recently_stepped_up: require_recent_step_up(&session).await.is_ok(),
+            ip_address,
+            user_agent,
+        })
+        .await?;
+
+    Ok(Json(EmailChangeStatusResponse {
+        pending: Some(PendingEmailChangeResponse::from(pending)),
+    }))
+}
+
+/// DELETE /api/profile/email-change - Cancel the pending email change.
+pub async fn cancel_email_change_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ConnectInfo(connect_info): ConnectInfo<SocketAddr>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<StatusCode> {
+    let (ip_address, user_agent) = extract_request_context(
+        &headers,
+        Some(connect_info),
+        state.trust_proxy_headers,
+        &state.trusted_proxy_cidrs,
+    );
+    state
+        .email_change_service
+        .cancel_change(user_id_from_subject(&user)?, ip_address, user_agent)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /auth/email-change/confirm - Confirm one side of an email change.
+///
+/// Completing the change revokes every session of the user, so the caller
+/// signs in again with the new address.
+pub async fn confirm_email_change_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ConnectInfo(connect_info): ConnectInfo<SocketAddr>,
+    Json(payload): Json<ConfirmEmailChangeRequest>,
+) -> ApiResult<Json<ConfirmEmailChangeResponse>> {
+    let (ip_address, user_agent) = extract_request_context(
+        &headers,
+        Some(connect_info),
+        state.trust_proxy_headers,
+        &state.trusted_proxy_cidrs,
+    );
+    let confirm_rule = confirm_email_change_rate_rule();
+    enforce_rate_limit(
+        &state,
+        &confirm_rule,
+        ip_address.as_deref().unwrap_or("unknown"),
+    )
+    .await?;
+
+    let outcome = state
+        .email_change_service
+        .confirm_change(&payload.token, ip_address, user_agent)
+        .await?;
+    let status = match outcome {
+        EmailChangeConfirmationOutcome::Pending(_) => "awaiting_other_address",
+        EmailChangeConfirmationOutcome::Completed { .. } => "completed",
+    };
+
+    Ok(Json(ConfirmEmailChangeResponse {
+        status: status.to_owned(),
+    }))
+}
+
+fn user_id_from_subject(user: &UserIdentity) -> ApiResult<UserId> {
+    let user_id = Uuid::parse_str(user.subject())
+        .map_err(|error| AppError::Internal(format!("invalid user subject: {error}")))?;
+
+    Ok(UserId::from_uuid(user_id))
+}
+
+impl From<PendingEmailChange> for PendingEmailChangeResponse {
+    fn from(change: PendingEmailChange) -> Self {
+        Self {
+            current_address_confirmed: change.current_confirmed_at.is_some(),
+            new_address_confirmed: change.new_confirmed_at.is_some(),
+            current_email: change.current_email,
+            new_email: change.new_email,
+            requested_at: change.requested_at.to_rfc3339(),
+            expires_at: change.expires_at.to_rfc3339(),
+        }
+    }
+}
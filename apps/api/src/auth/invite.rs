@@ -1,7 +1,7 @@
 use axum::Json;
 use axum::extract::{ConnectInfo, Extension, State};
 use axum::http::HeaderMap;
-use qryvanta_application::AuthEvent;
+use qryvanta_application::{AuthEvent, SessionTenantTransition};
 use qryvanta_core::{AppError, UserIdentity};
 use qryvanta_domain::{
     AuthEventOutcome, AuthEventType, AuthTokenType, EmailAddress, Permission, RegistrationMode,
@@ -13,6 +13,7 @@ use crate::dto::{
     AcceptInviteRequest, AuthLoginResponse as LoginResponse, GenericMessageResponse, InviteRequest,
 };
 use crate::error::ApiResult;
+use crate::rate_limit_headers::enforce_rate_limit;
 use crate::state::AppState;
 
 use super::session_helpers::{
@@ -39,18 +40,17 @@ pub async fn send_invite_handler(
         .await?;
 
     let invite_sender_rule = invite_sender_rate_rule();
-    state
-        .rate_limit_service
-        .check_rate_limit(&invite_sender_rule, user.subject())
-        .await?;
+    enforce_rate_limit(&state, &invite_sender_rule, user.subject()).await?;
 
     let canonical_email = EmailAddress::new(payload.email.as_str())?;
     let invite_recipient_rule = invite_recipient_rate_rule();
     let invite_recipient_key = format!("{}:{}", user.tenant_id(), canonical_email.as_str());
-    state
-        .rate_limit_service
-        .check_rate_limit(&invite_recipient_rule, invite_recipient_key.as_str())
-        .await?;
+    enforce_rate_limit(
+        &state,
+        &invite_recipient_rule,
+        invite_recipient_key.as_str(),
+    )
+    .await?;
 
     let tenant_name = payload
         .tenant_name
@@ -179,6 +179,15 @@ pub async fn accept_invite_handler(
             switch_identity_for_subject(&state, user_id.to_string().as_str(), tenant_id).await?;
         persist_authenticated_identity(&session, &identity).await?;
         mark_step_up_verified(&session).await?;
+        state
+            .tenant_access_service
+            .record_session_tenant_change(
+                user_subject.as_str(),
+                None,
+                tenant_id,
+                SessionTenantTransition::Login,
+            )
+            .await?;
 
         Ok::<String, AppError>(user_id.to_string())
     }
@@ -210,5 +219,7 @@ pub async fn accept_invite_handler(
     Ok(Json(LoginResponse {
         status: "authenticated".to_owned(),
         requires_totp: false,
+        mfa_methods: Vec::new(),
+        tenants: Vec::new(),
     }))
 }
@@ -11,6 +11,7 @@ use tower_sessions::Session;
 use uuid::Uuid;
 
 use crate::error::ApiResult;
+use crate::rate_limit_headers::enforce_rate_limit;
 use crate::state::AppState;
 
 use super::session_helpers::extract_request_context;
@@ -110,10 +111,7 @@ pub async fn mfa_confirm_handler(
         .map_err(|error| AppError::Internal(format!("invalid user subject: {error}")))?;
     let user_id = UserId::from_uuid(user_id_uuid);
     let rate_limit_rule = mfa_enroll_confirm_rate_rule();
-    state
-        .rate_limit_service
-        .check_rate_limit(&rate_limit_rule, user.subject())
-        .await?;
+    enforce_rate_limit(&state, &rate_limit_rule, user.subject()).await?;
 
     let confirm_result = state
         .mfa_service
@@ -171,10 +169,7 @@ pub async fn mfa_disable_handler(
         .map_err(|error| AppError::Internal(format!("invalid user subject: {error}")))?;
     let user_id = UserId::from_uuid(user_id_uuid);
     let rate_limit_rule = mfa_management_rate_rule();
-    state
-        .rate_limit_service
-        .check_rate_limit(&rate_limit_rule, user.subject())
-        .await?;
+    enforce_rate_limit(&state, &rate_limit_rule, user.subject()).await?;
 
     let disable_result = async {
         state
@@ -246,10 +241,7 @@ pub async fn mfa_regenerate_recovery_codes_handler(
         .map_err(|error| AppError::Internal(format!("invalid user subject: {error}")))?;
     let user_id = UserId::from_uuid(user_id_uuid);
     let rate_limit_rule = mfa_management_rate_rule();
-    state
-        .rate_limit_service
-        .check_rate_limit(&rate_limit_rule, user.subject())
-        .await?;
+    enforce_rate_limit(&state, &rate_limit_rule, user.subject()).await?;
 
     let codes_result = async {
         let codes = state
@@ -1,27 +1,43 @@
 use qryvanta_application::RateLimitRule;
 
 mod bootstrap;
+mod email_change;
 mod invite;
 mod mfa;
 mod passkey;
+mod passkey_management;
 mod password;
+mod personal_data_export;
 mod session;
 pub(crate) mod session_helpers;
 mod step_up;
 
-pub use bootstrap::bootstrap_handler;
+pub use bootstrap::{
+    bootstrap_handler, register_configured_bootstrap_token, rotate_bootstrap_token_handler,
+};
+pub use email_change::{
+    cancel_email_change_handler, confirm_email_change_handler, email_change_status_handler,
+    request_email_change_handler,
+};
 pub use invite::{accept_invite_handler, send_invite_handler};
 pub use mfa::{
     mfa_confirm_handler, mfa_disable_handler, mfa_enroll_handler,
     mfa_regenerate_recovery_codes_handler,
 };
 pub use passkey::{
-    webauthn_login_finish_handler, webauthn_login_start_handler,
+    mfa_passkey_start_handler, webauthn_login_finish_handler, webauthn_login_start_handler,
     webauthn_registration_finish_handler, webauthn_registration_start_handler,
 };
+pub use passkey_management::{
+    delete_passkey_handler, list_passkeys_handler, rename_passkey_handler,
+};
 pub use password::{
-    change_password_handler, forgot_password_handler, login_handler, mfa_verify_handler,
-    register_handler, resend_verification_handler, reset_password_handler, verify_email_handler,
+    change_password_handler, forgot_password_handler, login_handler, login_tenant_handler,
+    mfa_verify_handler, register_handler, resend_verification_handler, reset_password_handler,
+    verify_email_handler,
+};
+pub use personal_data_export::{
+    download_personal_data_export_handler, request_personal_data_export_handler,
 };
 pub use session::{logout_handler, me_handler, switch_tenant_handler};
 pub use step_up::step_up_handler;
@@ -31,8 +47,11 @@ pub const SESSION_USER_KEY: &str = "user_identity";
 pub const SESSION_CREATED_AT_KEY: &str = "session_created_at";
 pub const SESSION_STEP_UP_VERIFIED_AT_KEY: &str = "step_up_verified_at";
 pub(super) const SESSION_MFA_PENDING_KEY: &str = "mfa_pending_user_id";
+pub(super) const SESSION_LOGIN_TENANT_KEY: &str = "login_requested_tenant_id";
+pub(super) const SESSION_TENANT_SELECTION_PENDING_KEY: &str = "tenant_selection_pending_subject";
 pub(super) const SESSION_WEBAUTHN_REG_STATE_KEY: &str = "webauthn_reg_state";
 pub(super) const SESSION_WEBAUTHN_AUTH_STATE_KEY: &str = "webauthn_auth_state";
+pub(super) const SESSION_WEBAUTHN_MFA_STATE_KEY: &str = "webauthn_mfa_state";
 
 pub(super) const RESEND_VERIFICATION_RATE_RULE: (i32, i64) = (5, 60 * 60);
 pub(super) const INVITE_SENDER_RATE_RULE: (i32, i64) = (20, 60 * 60);
@@ -42,6 +61,9 @@ pub(super) const MFA_LOGIN_VERIFY_RATE_RULE: (i32, i64) = (8, 10 * 60);
 pub(super) const MFA_ENROLL_CONFIRM_RATE_RULE: (i32, i64) = (8, 10 * 60);
 pub(super) const MFA_MANAGEMENT_RATE_RULE: (i32, i64) = (5, 15 * 60);
 pub(super) const STEP_UP_VERIFY_RATE_RULE: (i32, i64) = (8, 10 * 60);
+pub(super) const EMAIL_CHANGE_REQUEST_RATE_RULE: (i32, i64) = (5, 60 * 60);
+pub(super) const CONFIRM_EMAIL_CHANGE_RATE_RULE: (i32, i64) = (30, 60 * 60);
+pub(super) const PERSONAL_DATA_EXPORT_RATE_RULE: (i32, i64) = (3, 24 * 60 * 60);
 
 pub(super) fn verify_email_rate_rule() -> RateLimitRule {
     RateLimitRule::new(
@@ -91,6 +113,30 @@ pub(super) fn step_up_verify_rate_rule() -> RateLimitRule {
     )
 }
 
+pub(super) fn email_change_request_rate_rule() -> RateLimitRule {
+    RateLimitRule::new(
+        "email_change_request",
+        EMAIL_CHANGE_REQUEST_RATE_RULE.0,
+        EMAIL_CHANGE_REQUEST_RATE_RULE.1,
+    )
+}
+
+pub(super) fn personal_data_export_rate_rule() -> RateLimitRule {
+    RateLimitRule::new(
+        "personal_data_export",
+        PERSONAL_DATA_EXPORT_RATE_RULE.0,
+        PERSONAL_DATA_EXPORT_RATE_RULE.1,
+    )
+}
+
+pub(super) fn confirm_email_change_rate_rule() -> RateLimitRule {
+    RateLimitRule::new(
+        "confirm_email_change",
+        CONFIRM_EMAIL_CHANGE_RATE_RULE.0,
+        CONFIRM_EMAIL_CHANGE_RATE_RULE.1,
+    )
+}
+
 pub(super) fn invite_sender_rate_rule() -> RateLimitRule {
     RateLimitRule::new(
         "invite_sender",
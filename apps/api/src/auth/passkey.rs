@@ -2,7 +2,7 @@ use axum::Json;
 use axum::extract::{ConnectInfo, Query, State};
 use axum::http::HeaderMap;
 use axum::http::StatusCode;
-use qryvanta_application::AuthEvent;
+use qryvanta_application::{AuthEvent, SessionTenantTransition};
 use qryvanta_core::{AppError, UserIdentity};
 use qryvanta_domain::{AuthEventOutcome, AuthEventType};
 use serde::{Deserialize, Serialize};
@@ -21,7 +21,10 @@ use super::session_helpers::{
     active_identity_for_subject, extract_request_context, load_passkeys, mark_step_up_verified,
     persist_authenticated_identity,
 };
-use super::{SESSION_USER_KEY, SESSION_WEBAUTHN_AUTH_STATE_KEY, SESSION_WEBAUTHN_REG_STATE_KEY};
+use super::{
+    SESSION_MFA_PENDING_KEY, SESSION_USER_KEY, SESSION_WEBAUTHN_AUTH_STATE_KEY,
+    SESSION_WEBAUTHN_MFA_STATE_KEY, SESSION_WEBAUTHN_REG_STATE_KEY,
+};
 
 #[derive(Debug, Deserialize)]
 pub struct LoginStartQuery {
@@ -49,7 +52,7 @@ pub async fn webauthn_registration_start_handler(
     let exclude_credentials = (!stored_passkeys.is_empty()).then(|| {
         stored_passkeys
             .iter()
-            .map(|passkey| passkey.cred_id().clone())
+            .map(|(_, passkey)| passkey.cred_id().clone())
             .collect()
     });
 
@@ -139,10 +142,7 @@ pub async fn webauthn_login_start_handler(
         return Err(AppError::Unauthorized("no passkeys enrolled for subject".to_owned()).into());
     }
 
-    let (request_challenge_response, auth_state) = state
-        .webauthn
-        .start_passkey_authentication(&passkeys)
-        .map_err(|error| AppError::Internal(format!("failed to start passkey login: {error}")))?;
+    let (challenge, auth_state) = start_passkey_assertion(&state, &passkeys)?;
 
     session
         .insert(
@@ -152,13 +152,124 @@ pub async fn webauthn_login_start_handler(
         .await
         .map_err(|error| AppError::Internal(format!("failed to persist auth state: {error}")))?;
 
-    Ok(Json(
-        serde_json::to_value(request_challenge_response).map_err(|error| {
-            AppError::Internal(format!(
-                "failed to encode authentication challenge: {error}"
+    Ok(Json(challenge))
+}
+
+/// POST /auth/login/mfa/passkey/start - Start a passkey challenge for a pending MFA login.
+pub async fn mfa_passkey_start_handler(
+    State(state): State<AppState>,
+    session: Session,
+) -> ApiResult<Json<serde_json::Value>> {
+    let pending_subject: String = session
+        .get(SESSION_MFA_PENDING_KEY)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to read MFA pending state: {error}")))?
+        .ok_or_else(|| AppError::Unauthorized("no MFA challenge in progress".to_owned()))?;
+
+    let passkeys = load_passkeys(&state, pending_subject.as_str()).await?;
+    if passkeys.is_empty() {
+        return Err(
+            AppError::Validation("no passkeys are registered for this account".to_owned()).into(),
+        );
+    }
+
+    let (challenge, auth_state) = start_passkey_assertion(&state, &passkeys)?;
+
+    session
+        .insert(SESSION_WEBAUTHN_MFA_STATE_KEY, (passkeys, auth_state))
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to persist passkey MFA state: {error}"))
+        })?;
+
+    Ok(Json(challenge))
+}
+
+/// Verifies the passkey assertion answering a pending MFA challenge.
+///
+/// Returns `false` when the assertion does not verify, so the caller can
+/// record the failed MFA attempt like an invalid TOTP code.
+pub(super) async fn verify_passkey_mfa(
+    state: &AppState,
+    session: &Session,
+    subject: &str,
+    credential: Option<&serde_json::Value>,
+) -> Result<bool, AppError> {
+    let (passkeys, auth_state): (Vec<(Uuid, Passkey)>, PasskeyAuthentication) = session
+        .remove(SESSION_WEBAUTHN_MFA_STATE_KEY)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to read passkey MFA state: {error}")))?
+        .ok_or_else(|| AppError::Unauthorized("no passkey challenge in progress".to_owned()))?;
+
+    let credential = credential
+        .cloned()
+        .ok_or_else(|| AppError::Validation("passkey credential is required".to_owned()))?;
+    let credential = serde_json::from_value::<PublicKeyCredential>(credential)
+        .map_err(|error| AppError::Validation(format!("invalid passkey credential: {error}")))?;
+
+    match finish_passkey_assertion(state, subject, passkeys, &auth_state, &credential).await {
+        Ok(()) => Ok(true),
+        Err(AppError::Unauthorized(_)) => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+fn start_passkey_assertion(
+    state: &AppState,
+    passkeys: &[(Uuid, Passkey)],
+) -> Result<(serde_json::Value, PasskeyAuthentication), AppError> {
+    let credentials = passkeys
+        .iter()
+        .map(|(_, passkey)| passkey.clone())
+        .collect::<Vec<_>>();
+    let (request_challenge_response, auth_state) = state
+        .webauthn
+        .start_passkey_authentication(&credentials)
+        .map_err(|error| AppError::Internal(format!("failed to start passkey login: {error}")))?;
+
+    let challenge = serde_json::to_value(request_challenge_response).map_err(|error| {
+        AppError::Internal(format!(
+            "failed to encode authentication challenge: {error}"
+        ))
+    })?;
+
+    Ok((challenge, auth_state))
+}
+
+/// Verifies a passkey assertion and stores the used credential's updated state.
+async fn finish_passkey_assertion(
+    state: &AppState,
+    subject: &str,
+    passkeys: Vec<(Uuid, Passkey)>,
+    auth_state: &PasskeyAuthentication,
+    credential: &PublicKeyCredential,
+) -> Result<(), AppError> {
+    let auth_result = state
+        .webauthn
+        .finish_passkey_authentication(credential, auth_state)
+        .map_err(|error| {
+            AppError::Unauthorized(format!(
+                "passkey authentication verification failed: {error}"
             ))
-        })?,
-    ))
+        })?;
+
+    let Some((passkey_id, mut passkey)) = passkeys
+        .into_iter()
+        .find(|(_, passkey)| passkey.cred_id() == auth_result.cred_id())
+    else {
+        return Err(AppError::Unauthorized(
+            "passkey is no longer registered".to_owned(),
+        ));
+    };
+    passkey.update_credential(&auth_result);
+
+    let passkey_json = serde_json::to_string(&passkey)
+        .map_err(|error| AppError::Internal(format!("failed to serialize passkey: {error}")))?;
+
+    state
+        .passkey_repository
+        .record_use_for_subject(subject, passkey_id, passkey_json.as_str())
+        .await
 }
 
 pub async fn webauthn_login_finish_handler(
@@ -168,7 +279,7 @@ pub async fn webauthn_login_finish_handler(
     session: Session,
     Json(payload): Json<PublicKeyCredential>,
 ) -> ApiResult<Json<AuthStatusResponse>> {
-    let (subject, mut passkeys, auth_state): (String, Vec<Passkey>, PasskeyAuthentication) =
+    let (subject, passkeys, auth_state): (String, Vec<(Uuid, Passkey)>, PasskeyAuthentication) =
         session
             .get(SESSION_WEBAUTHN_AUTH_STATE_KEY)
             .await
@@ -180,29 +291,7 @@ pub async fn webauthn_login_finish_handler(
         .await
         .map_err(|error| AppError::Internal(format!("failed to clear auth state: {error}")))?;
 
-    let auth_result = state
-        .webauthn
-        .finish_passkey_authentication(&payload, &auth_state)
-        .map_err(|error| {
-            AppError::Unauthorized(format!(
-                "passkey authentication verification failed: {error}"
-            ))
-        })?;
-
-    passkeys.iter_mut().for_each(|passkey| {
-        passkey.update_credential(&auth_result);
-    });
-
-    let serialized_passkeys = passkeys
-        .iter()
-        .map(serde_json::to_string)
-        .collect::<Result<Vec<String>, _>>()
-        .map_err(|error| AppError::Internal(format!("failed to serialize passkeys: {error}")))?;
-
-    state
-        .passkey_repository
-        .replace_for_subject(subject.as_str(), serialized_passkeys.as_slice())
-        .await?;
+    finish_passkey_assertion(&state, subject.as_str(), passkeys, &auth_state, &payload).await?;
 
     let identity = active_identity_for_subject(&state, subject.as_str()).await?;
 
@@ -222,6 +311,15 @@ pub async fn webauthn_login_finish_handler(
         .await?;
     persist_authenticated_identity(&session, &identity).await?;
     mark_step_up_verified(&session).await?;
+    state
+        .tenant_access_service
+        .record_session_tenant_change(
+            subject.as_str(),
+            None,
+            identity.tenant_id(),
+            SessionTenantTransition::Login,
+        )
+        .await?;
 
     let (ip_address, user_agent) = extract_request_context(
         &headers,
@@ -0,0 +1,163 @@
+use axum::Json;
+use axum::extract::{ConnectInfo, Extension, Path, State};
+use axum::http::HeaderMap;
+use axum::http::StatusCode;
+use qryvanta_application::AuthEvent;
+use qryvanta_core::{AppError, UserIdentity};
+use qryvanta_domain::{AuthEventOutcome, AuthEventType, UserId};
+use qryvanta_infrastructure::PasskeyCredentialRecord;
+use std::net::SocketAddr;
+use tower_sessions::Session;
+use uuid::Uuid;
+
+use crate::dto::{PasskeyResponse, RenamePasskeyRequest};
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+use super::session_helpers::{extract_request_context, require_recent_step_up};
+
+const PASSKEY_NAME_MAX_CHARS: usize = 64;
+
+/// GET /auth/passkeys - List the caller's registered passkeys.
+pub async fn list_passkeys_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<PasskeyResponse>>> {
+    let records = state
+        .passkey_repository
+        .list_records_by_subject(user.subject())
+        .await?;
+
+    Ok(Json(records.into_iter().map(passkey_response).collect()))
+}
+
+/// PUT /auth/passkeys/{passkey_id} - Rename one of the caller's passkeys.
+pub async fn rename_passkey_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ConnectInfo(connect_info): ConnectInfo<SocketAddr>,
+    Extension(user): Extension<UserIdentity>,
+    Path(passkey_id): Path<String>,
+    Json(payload): Json<RenamePasskeyRequest>,
+) -> ApiResult<Json<PasskeyResponse>> {
+    let passkey_id = parse_passkey_id(passkey_id.as_str())?;
+    let name = payload.name.trim();
+    if name.is_empty() || name.chars().count() > PASSKEY_NAME_MAX_CHARS {
+        return Err(AppError::Validation(format!(
+            "passkey name must be between 1 and {PASSKEY_NAME_MAX_CHARS} characters"
+        ))
+        .into());
+    }
+
+    let record = state
+        .passkey_repository
+        .rename_for_subject(user.subject(), passkey_id, name)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("passkey '{passkey_id}' not found")))?;
+
+    let (ip_address, user_agent) = extract_request_context(
+        &headers,
+        Some(connect_info),
+        state.trust_proxy_headers,
+        &state.trusted_proxy_cidrs,
+    );
+    state
+        .auth_event_service
+        .record_event(AuthEvent {
+            subject: Some(user.subject().to_owned()),
+            event_type: AuthEventType::PasskeyRenamed,
+            outcome: AuthEventOutcome::Success,
+            ip_address,
+            user_agent,
+        })
+        .await?;
+
+    Ok(Json(passkey_response(record)))
+}
+
+/// DELETE /auth/passkeys/{passkey_id} - Delete one of the caller's passkeys.
+///
+/// Requires a recent step-up. The last passkey can only be deleted while TOTP
+/// remains as a second factor for a password account.
+pub async fn delete_passkey_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ConnectInfo(connect_info): ConnectInfo<SocketAddr>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path(passkey_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    require_recent_step_up(&session).await?;
+    let passkey_id = parse_passkey_id(passkey_id.as_str())?;
+
+    let records = state
+        .passkey_repository
+        .list_records_by_subject(user.subject())
+        .await?;
+    if !records.iter().any(|record| record.id == passkey_id) {
+        return Err(AppError::NotFound(format!("passkey '{passkey_id}' not found")).into());
+    }
+
+    if records.len() == 1 && !has_totp_fallback(&state, user.subject()).await? {
+        return Err(AppError::Conflict(
+            "deleting the last passkey would leave the account without a second factor; enable TOTP first"
+                .to_owned(),
+        )
+        .into());
+    }
+
+    let deleted = state
+        .passkey_repository
+        .delete_for_subject(user.subject(), passkey_id)
+        .await?;
+    if !deleted {
+        return Err(AppError::NotFound(format!("passkey '{passkey_id}' not found")).into());
+    }
+
+    let (ip_address, user_agent) = extract_request_context(
+        &headers,
+        Some(connect_info),
+        state.trust_proxy_headers,
+        &state.trusted_proxy_cidrs,
+    );
+    state
+        .auth_event_service
+        .record_event(AuthEvent {
+            subject: Some(user.subject().to_owned()),
+            event_type: AuthEventType::PasskeyDeleted,
+            outcome: AuthEventOutcome::Success,
+            ip_address,
+            user_agent,
+        })
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Whether the subject can still sign in with a password and TOTP once no
+/// passkey is left.
+async fn has_totp_fallback(state: &AppState, subject: &str) -> Result<bool, AppError> {
+    let Ok(user_id) = Uuid::parse_str(subject).map(UserId::from_uuid) else {
+        return Ok(false);
+    };
+
+    Ok(state
+        .user_service
+        .find_by_id(user_id)
+        .await?
+        .is_some_and(|user| user.password_hash.is_some() && user.totp_enabled))
+}
+
+fn parse_passkey_id(value: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(value)
+        .map_err(|error| AppError::Validation(format!("invalid passkey id '{value}': {error}")))
+}
+
+fn passkey_response(record: PasskeyCredentialRecord) -> PasskeyResponse {
+    PasskeyResponse {
+        passkey_id: record.id.to_string(),
+        name: record.name,
+        created_at: record.created_at.to_rfc3339(),
+        last_used_at: record.last_used_at.map(|timestamp| timestamp.to_rfc3339()),
+    }
+}
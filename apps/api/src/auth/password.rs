@@ -15,18 +15,17 @@ use uuid::Uuid;
 use crate::dto::{
     AuthLoginRequest as LoginRequest, AuthLoginResponse as LoginResponse,
     AuthMfaVerifyRequest as MfaVerifyRequest, AuthRegisterRequest as RegisterRequest,
-    GenericMessageResponse,
+    AuthSwitchTenantRequest, GenericMessageResponse,
 };
 use crate::error::ApiResult;
+use crate::rate_limit_headers::enforce_rate_limit;
 use crate::state::AppState;
 
-use super::session_helpers::{
-    active_identity_for_subject, extract_request_context, mark_step_up_verified,
-    persist_authenticated_identity,
-};
+use super::passkey::verify_passkey_mfa;
+use super::session_helpers::{establish_login_session, extract_request_context, parse_tenant_id};
 use super::{
-    SESSION_MFA_PENDING_KEY, mfa_login_verify_rate_rule, resend_verification_rate_rule,
-    verify_email_rate_rule,
+    SESSION_LOGIN_TENANT_KEY, SESSION_MFA_PENDING_KEY, SESSION_TENANT_SELECTION_PENDING_KEY,
+    mfa_login_verify_rate_rule, resend_verification_rate_rule, verify_email_rate_rule,
 };
 
 #[derive(Debug, Deserialize)]
@@ -143,6 +142,12 @@ pub async fn login_handler(
         &state.trusted_proxy_cidrs,
     );
 
+    let requested_tenant_id = payload
+        .tenant_id
+        .as_deref()
+        .map(parse_tenant_id)
+        .transpose()?;
+
     let outcome = state
         .user_service
         .login(&payload.email, &payload.password, ip_address, user_agent)
@@ -151,26 +156,21 @@ pub async fn login_handler(
     match outcome {
         AuthOutcome::Authenticated(user) => {
             let user_subject = user.id.to_string();
-            let identity = active_identity_for_subject(&state, user_subject.as_str()).await?;
-
-            state
-                .contact_bootstrap_service
-                .ensure_subject_contact(
-                    identity.tenant_id(),
-                    user_subject.as_str(),
-                    identity.display_name(),
-                    identity.email(),
-                )
-                .await?;
-            persist_authenticated_identity(&session, &identity).await?;
-            mark_step_up_verified(&session).await?;
+            let response = establish_login_session(
+                &state,
+                &session,
+                user_subject.as_str(),
+                requested_tenant_id,
+            )
+            .await?;
 
-            Ok(Json(LoginResponse {
-                status: "authenticated".to_owned(),
-                requires_totp: false,
-            }))
+            Ok(Json(response))
         }
-        AuthOutcome::MfaRequired { user_id } => {
+        AuthOutcome::MfaRequired {
+            user_id,
+            totp_enabled,
+            passkeys_enrolled,
+        } => {
             // Store the pending user_id in session for MFA verification.
             session
                 .insert(SESSION_MFA_PENDING_KEY, user_id.to_string())
@@ -178,10 +178,22 @@ pub async fn login_handler(
                 .map_err(|error| {
                     AppError::Internal(format!("failed to persist MFA pending state: {error}"))
                 })?;
+            if let Some(tenant_id) = requested_tenant_id {
+                session
+                    .insert(SESSION_LOGIN_TENANT_KEY, tenant_id.to_string())
+                    .await
+                    .map_err(|error| {
+                        AppError::Internal(format!(
+                            "failed to persist requested login tenant: {error}"
+                        ))
+                    })?;
+            }
 
             Ok(Json(LoginResponse {
                 status: "mfa_required".to_owned(),
-                requires_totp: true,
+                requires_totp: totp_enabled,
+                mfa_methods: mfa_methods(totp_enabled, passkeys_enrolled),
+                tenants: Vec::new(),
             }))
         }
         AuthOutcome::Failed => {
@@ -191,7 +203,20 @@ pub async fn login_handler(
     }
 }
 
-/// POST /auth/login/mfa - Complete MFA challenge with TOTP or recovery code.
+/// Second factors that can answer the MFA challenge of a password login.
+fn mfa_methods(totp_enabled: bool, passkeys_enrolled: bool) -> Vec<String> {
+    let mut methods = Vec::new();
+    if totp_enabled {
+        methods.extend(["totp".to_owned(), "recovery".to_owned()]);
+    }
+    if passkeys_enrolled {
+        methods.push("passkey".to_owned());
+    }
+
+    methods
+}
+
+/// POST /auth/login/mfa - Complete MFA challenge with TOTP, recovery code, or passkey.
 pub async fn mfa_verify_handler(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -211,10 +236,7 @@ pub async fn mfa_verify_handler(
     let method = payload.method.as_deref().unwrap_or("totp");
     let rate_limit_rule = mfa_login_verify_rate_rule();
     let rate_limit_key = format!("{}:{method}", user_id);
-    state
-        .rate_limit_service
-        .check_rate_limit(&rate_limit_rule, rate_limit_key.as_str())
-        .await?;
+    enforce_rate_limit(&state, &rate_limit_rule, rate_limit_key.as_str()).await?;
 
     let valid = match method {
         "recovery" => {
@@ -223,6 +245,15 @@ pub async fn mfa_verify_handler(
                 .verify_recovery_code(user_id, &payload.code)
                 .await?
         }
+        "passkey" => {
+            verify_passkey_mfa(
+                &state,
+                &session,
+                pending_user_id_str.as_str(),
+                payload.credential.as_ref(),
+            )
+            .await?
+        }
         _ => {
             state
                 .mfa_service
@@ -266,21 +297,20 @@ pub async fn mfa_verify_handler(
         .await?
         .ok_or_else(|| AppError::Internal("user not found after MFA".to_owned()))?;
 
-    let user_subject = user.id.to_string();
-
-    let identity = active_identity_for_subject(&state, user_subject.as_str()).await?;
+    let requested_tenant_id = session
+        .remove::<String>(SESSION_LOGIN_TENANT_KEY)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to read requested login tenant: {error}"))
+        })?
+        .as_deref()
+        .map(parse_tenant_id)
+        .transpose()?;
 
-    state
-        .contact_bootstrap_service
-        .ensure_subject_contact(
-            identity.tenant_id(),
-            user_subject.as_str(),
-            identity.display_name(),
-            identity.email(),
-        )
-        .await?;
-    persist_authenticated_identity(&session, &identity).await?;
-    mark_step_up_verified(&session).await?;
+    let user_subject = user.id.to_string();
+    let response =
+        establish_login_session(&state, &session, user_subject.as_str(), requested_tenant_id)
+            .await?;
 
     let (ip_address, user_agent) = extract_request_context(
         &headers,
@@ -299,10 +329,34 @@ pub async fn mfa_verify_handler(
         })
         .await?;
 
-    Ok(Json(LoginResponse {
-        status: "authenticated".to_owned(),
-        requires_totp: false,
-    }))
+    Ok(Json(response))
+}
+
+/// POST /auth/login/tenant - Complete login by choosing one of the subject's tenants.
+pub async fn login_tenant_handler(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<AuthSwitchTenantRequest>,
+) -> ApiResult<Json<LoginResponse>> {
+    let subject: String = session
+        .get(SESSION_TENANT_SELECTION_PENDING_KEY)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to read tenant selection state: {error}"))
+        })?
+        .ok_or_else(|| AppError::Unauthorized("no tenant selection in progress".to_owned()))?;
+    let tenant_id = parse_tenant_id(payload.tenant_id.as_str())?;
+
+    let response =
+        establish_login_session(&state, &session, subject.as_str(), Some(tenant_id)).await?;
+    session
+        .remove_value(SESSION_TENANT_SELECTION_PENDING_KEY)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to clear tenant selection state: {error}"))
+        })?;
+
+    Ok(Json(response))
 }
 
 /// PUT /api/profile/password - Change password (requires auth).
@@ -506,10 +560,12 @@ pub async fn verify_email_handler(
         &state.trusted_proxy_cidrs,
     );
     let verify_rule = verify_email_rate_rule();
-    state
-        .rate_limit_service
-        .check_rate_limit(&verify_rule, ip_address.as_deref().unwrap_or("unknown"))
-        .await?;
+    enforce_rate_limit(
+        &state,
+        &verify_rule,
+        ip_address.as_deref().unwrap_or("unknown"),
+    )
+    .await?;
 
     let verify_result = async {
         let token_record = state
@@ -560,10 +616,7 @@ pub async fn resend_verification_handler(
     Extension(user): Extension<UserIdentity>,
 ) -> ApiResult<Json<GenericMessageResponse>> {
     let resend_rule = resend_verification_rate_rule();
-    state
-        .rate_limit_service
-        .check_rate_limit(&resend_rule, user.subject())
-        .await?;
+    enforce_rate_limit(&state, &resend_rule, user.subject()).await?;
 
     let user_id_uuid = Uuid::parse_str(user.subject())
         .map_err(|error| AppError::Internal(format!("invalid user subject: {error}")))?;
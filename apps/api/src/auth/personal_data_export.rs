@@ -0,0 +1,63 @@
+use axum::Json;
+use axum::extract::{Extension, Path, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use qryvanta_core::{AppError, UserIdentity};
+
+use crate::dto::BackgroundJobResponse;
+use crate::error::ApiResult;
+use crate::rate_limit_headers::enforce_rate_limit;
+use crate::state::AppState;
+
+use super::personal_data_export_rate_rule;
+
+/// POST /api/profile/data-export - Queue a download of the caller's personal data.
+///
+/// Progress is reported by the background job endpoints; the archive is
+/// downloaded once the job completes.
+pub async fn request_personal_data_export_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<(StatusCode, Json<BackgroundJobResponse>)> {
+    let export_rule = personal_data_export_rate_rule();
+    enforce_rate_limit(&state, &export_rule, user.subject()).await?;
+
+    let entity_logical_names = state
+        .metadata_service
+        .list_published_entity_logical_names_unchecked(&user)
+        .await?;
+    let job = state
+        .personal_data_export_service
+        .request_export(&user, entity_logical_names)
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, Json(BackgroundJobResponse::from(job))))
+}
+
+/// GET /api/profile/data-export/{job_id} - Download a completed personal data archive.
+pub async fn download_personal_data_export_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(job_id): Path<String>,
+) -> ApiResult<Response> {
+    let archive = state
+        .personal_data_export_service
+        .download_archive(&user, job_id.as_str())
+        .await?;
+    let body = serde_json::to_vec_pretty(&archive.document).map_err(|error| {
+        AppError::Internal(format!("failed to encode personal data archive: {error}"))
+    })?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/json".to_owned()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", archive.file_name),
+            ),
+            (header::CACHE_CONTROL, "no-store".to_owned()),
+        ],
+        body,
+    )
+        .into_response())
+}
@@ -2,12 +2,11 @@ use axum::Json;
 use axum::extract::{ConnectInfo, State};
 use axum::http::HeaderMap;
 use axum::http::StatusCode;
-use qryvanta_application::AuthEvent;
+use qryvanta_application::{AuthEvent, SessionTenantTransition};
 use qryvanta_core::{AppError, UserIdentity};
 use qryvanta_domain::{AuthEventOutcome, AuthEventType};
 use std::net::SocketAddr;
 use tower_sessions::Session;
-use uuid::Uuid;
 
 use crate::dto::{AuthSwitchTenantRequest, UserIdentityResponse};
 use crate::error::ApiResult;
@@ -15,7 +14,8 @@ use crate::state::AppState;
 
 use super::SESSION_USER_KEY;
 use super::session_helpers::{
-    extract_request_context, persist_authenticated_identity, switch_identity_for_subject,
+    extract_request_context, parse_tenant_id, persist_authenticated_identity,
+    switch_identity_for_subject,
 };
 
 pub async fn logout_handler(
@@ -88,19 +88,19 @@ pub async fn switch_tenant_handler(
         .map_err(|error| AppError::Internal(format!("failed to read session identity: {error}")))?
         .ok_or_else(|| AppError::Unauthorized("authentication required".to_owned()))?;
 
-    let tenant_uuid = Uuid::parse_str(payload.tenant_id.as_str()).map_err(|error| {
-        AppError::Validation(format!(
-            "invalid tenant id '{}': {error}",
-            payload.tenant_id
-        ))
-    })?;
-    let next_identity = switch_identity_for_subject(
-        &state,
-        current_identity.subject(),
-        qryvanta_core::TenantId::from_uuid(tenant_uuid),
-    )
-    .await?;
+    let tenant_id = parse_tenant_id(payload.tenant_id.as_str())?;
+    let next_identity =
+        switch_identity_for_subject(&state, current_identity.subject(), tenant_id).await?;
     persist_authenticated_identity(&session, &next_identity).await?;
+    state
+        .tenant_access_service
+        .record_session_tenant_change(
+            next_identity.subject(),
+            Some(current_identity.tenant_id()),
+            next_identity.tenant_id(),
+            SessionTenantTransition::Switch,
+        )
+        .await?;
 
     let (ip_address, user_agent) = extract_request_context(
         &headers,
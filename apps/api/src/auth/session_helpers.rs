@@ -2,28 +2,38 @@ use std::net::SocketAddr;
 
 use axum::http::HeaderMap;
 use ipnet::IpNet;
+use qryvanta_application::SessionTenantTransition;
 use qryvanta_core::{AppError, TenantId, UserIdentity};
 use tower_sessions::Session;
 use uuid::Uuid;
 use webauthn_rs::prelude::Passkey;
 
+use crate::dto::{AuthLoginResponse, TenantOptionResponse};
 use crate::middleware::extract_client_ip_from_parts;
 use crate::state::AppState;
 
-use super::{SESSION_CREATED_AT_KEY, SESSION_STEP_UP_VERIFIED_AT_KEY, SESSION_USER_KEY};
+use super::{
+    SESSION_CREATED_AT_KEY, SESSION_STEP_UP_VERIFIED_AT_KEY, SESSION_TENANT_SELECTION_PENDING_KEY,
+    SESSION_USER_KEY,
+};
 
 const STEP_UP_MAX_AGE_SECONDS: i64 = 10 * 60;
 
+/// Loads the registered passkeys of a subject keyed by their stored row id.
 pub(super) async fn load_passkeys(
     state: &AppState,
     subject: &str,
-) -> Result<Vec<Passkey>, AppError> {
-    let passkey_json_values = state.passkey_repository.list_by_subject(subject).await?;
+) -> Result<Vec<(Uuid, Passkey)>, AppError> {
+    let records = state
+        .passkey_repository
+        .list_records_by_subject(subject)
+        .await?;
 
-    passkey_json_values
+    records
         .into_iter()
-        .map(|passkey_json| {
-            serde_json::from_str::<Passkey>(&passkey_json)
+        .map(|record| {
+            serde_json::from_str::<Passkey>(&record.credential_json)
+                .map(|passkey| (record.id, passkey))
                 .map_err(|error| AppError::Internal(format!("failed to decode passkey: {error}")))
         })
         .collect()
@@ -49,6 +59,13 @@ pub(super) fn tenant_id_from_invite_metadata(
     Ok(TenantId::from_uuid(tenant_uuid))
 }
 
+pub(super) fn parse_tenant_id(value: &str) -> Result<TenantId, AppError> {
+    let tenant_uuid = Uuid::parse_str(value)
+        .map_err(|error| AppError::Validation(format!("invalid tenant id '{value}': {error}")))?;
+
+    Ok(TenantId::from_uuid(tenant_uuid))
+}
+
 pub(super) fn default_display_name(email: &str) -> &str {
     email.split('@').next().unwrap_or("new user")
 }
@@ -93,6 +110,78 @@ pub(super) async fn switch_identity_for_subject(
     ))
 }
 
+/// Establishes the session of a subject that completed primary authentication.
+///
+/// Subjects with more than one tenant membership pick a tenant first, unless
+/// the login request already named one. The subject is then kept as pending
+/// tenant selection and no identity is stored yet.
+pub(super) async fn establish_login_session(
+    state: &AppState,
+    session: &Session,
+    subject: &str,
+    requested_tenant_id: Option<TenantId>,
+) -> Result<AuthLoginResponse, AppError> {
+    let identity = match requested_tenant_id {
+        Some(tenant_id) => switch_identity_for_subject(state, subject, tenant_id).await?,
+        None => {
+            let tenants = state
+                .tenant_access_service
+                .list_subject_tenants(subject)
+                .await?;
+            if tenants.len() > 1 {
+                session
+                    .insert(SESSION_TENANT_SELECTION_PENDING_KEY, subject)
+                    .await
+                    .map_err(|error| {
+                        AppError::Internal(format!(
+                            "failed to persist tenant selection state: {error}"
+                        ))
+                    })?;
+
+                return Ok(AuthLoginResponse {
+                    status: "tenant_selection_required".to_owned(),
+                    requires_totp: false,
+                    mfa_methods: Vec::new(),
+                    tenants: tenants
+                        .into_iter()
+                        .map(TenantOptionResponse::from_unscoped_selection)
+                        .collect(),
+                });
+            }
+
+            active_identity_for_subject(state, subject).await?
+        }
+    };
+
+    state
+        .contact_bootstrap_service
+        .ensure_subject_contact(
+            identity.tenant_id(),
+            subject,
+            identity.display_name(),
+            identity.email(),
+        )
+        .await?;
+    persist_authenticated_identity(session, &identity).await?;
+    mark_step_up_verified(session).await?;
+    state
+        .tenant_access_service
+        .record_session_tenant_change(
+            subject,
+            None,
+            identity.tenant_id(),
+            SessionTenantTransition::Login,
+        )
+        .await?;
+
+    Ok(AuthLoginResponse {
+        status: "authenticated".to_owned(),
+        requires_totp: false,
+        mfa_methods: Vec::new(),
+        tenants: Vec::new(),
+    })
+}
+
 pub(super) async fn persist_authenticated_identity(
     session: &Session,
     identity: &UserIdentity,
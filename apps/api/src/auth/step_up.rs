@@ -11,6 +11,7 @@ use uuid::Uuid;
 
 use crate::dto::AuthStepUpRequest;
 use crate::error::ApiResult;
+use crate::rate_limit_headers::enforce_rate_limit;
 use crate::state::AppState;
 
 use super::session_helpers::{extract_request_context, mark_step_up_verified};
@@ -25,10 +26,7 @@ pub async fn step_up_handler(
     Json(payload): Json<AuthStepUpRequest>,
 ) -> ApiResult<StatusCode> {
     let rate_limit_rule = step_up_verify_rate_rule();
-    state
-        .rate_limit_service
-        .check_rate_limit(&rate_limit_rule, user.subject())
-        .await?;
+    enforce_rate_limit(&state, &rate_limit_rule, user.subject()).await?;
 
     let user_id_uuid = Uuid::parse_str(user.subject())
         .map_err(|error| AppError::Internal(format!("invalid user subject: {error}")))?;
@@ -0,0 +1,168 @@
+//! Background runner that executes queued jobs and resumes interrupted ones.
+
+mod audit_log_purge;
+mod data_validation_audit;
+mod personal_data_export;
+mod qrywell_sync;
+mod tenant_data_export;
+
+use std::time::Duration;
+
+use serde_json::Value;
+use tracing::{error, info, warn};
+
+use qryvanta_application::{
+    BACKGROUND_JOB_MAX_ATTEMPTS, BackgroundJobKind, BackgroundJobLease, BackgroundJobStage,
+    BackgroundJobStatus,
+};
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+
+use crate::state::AppState;
+
+pub use audit_log_purge::AUDIT_LOG_PURGE_STAGE;
+
+pub fn spawn_background_job_runner(state: AppState) {
+    tokio::spawn(async move {
+        info!(
+            interval_ms = state.background_job_poll_interval_ms,
+            "background job runner started"
+        );
+
+        loop {
+            match state.background_job_service.claim_next_job().await {
+                Ok(Some(lease)) => {
+                    run_claimed_job(&state, lease).await;
+                    continue;
+                }
+                Ok(None) => {}
+                Err(error) => error!(error = %error, "background job claim failed"),
+            }
+
+            tokio::time::sleep(Duration::from_millis(state.background_job_poll_interval_ms)).await;
+        }
+    });
+}
+
+/// Why a job run stopped before finishing its work.
+enum JobRunStop {
+    /// Cancellation was requested.
+    Cancelled,
+    /// Another run took over the job after the lease lapsed.
+    LeaseLost,
+}
+
+/// Result of one job run.
+enum JobRunOutcome {
+    Completed(String),
+    Stopped(JobRunStop),
+}
+
+/// Claimed job with its in-memory progress.
+struct JobRun<'a> {
+    state: &'a AppState,
+    lease: BackgroundJobLease,
+    stages: Vec<BackgroundJobStage>,
+}
+
+impl JobRun<'_> {
+    /// Rebuilds the requester identity the job runs with.
+    fn requester(&self) -> UserIdentity {
+        let job = &self.lease.job;
+        UserIdentity::new(
+            job.requested_by_subject.as_str(),
+            job.requested_by_display_name.as_str(),
+            job.requested_by_email.clone(),
+            self.lease.tenant_id,
+        )
+    }
+
+    fn parameter_str(&self, key: &str) -> AppResult<&str> {
+        self.lease
+            .job
+            .parameters
+            .get(key)
+            .and_then(Value::as_str)
+            .ok_or_else(|| {
+                AppError::Internal(format!("background job parameter '{key}' is missing"))
+            })
+    }
+
+    fn checkpoint_u64(&self, key: &str) -> u64 {
+        self.lease
+            .job
+            .checkpoint
+            .as_ref()
+            .and_then(|checkpoint| checkpoint.get(key))
+            .and_then(Value::as_u64)
+            .unwrap_or(0)
+    }
+
+    /// Stores progress and renews the lease.
+    ///
+    /// Returns why the run must stop, if it must.
+    async fn save_checkpoint(&self, checkpoint: Value) -> AppResult<Option<JobRunStop>> {
+        let saved = self
+            .state
+            .background_job_service
+            .record_job_progress(&self.lease, &self.stages, Some(&checkpoint))
+            .await?;
+
+        Ok(match saved {
+            None => Some(JobRunStop::LeaseLost),
+            Some(job) if job.cancel_requested => Some(JobRunStop::Cancelled),
+            Some(_) => None,
+        })
+    }
+}
+
+async fn run_claimed_job(state: &AppState, lease: BackgroundJobLease) {
+    let job_id = lease.job.job_id.clone();
+    let kind = lease.job.kind;
+
+    let outcome = if lease.job.cancel_requested {
+        Ok(JobRunOutcome::Stopped(JobRunStop::Cancelled))
+    } else if lease.job.attempt_count > BACKGROUND_JOB_MAX_ATTEMPTS {
+        Err(AppError::Internal(format!(
+            "background job was interrupted {} times",
+            lease.job.attempt_count - 1
+        )))
+    } else {
+        if lease.job.attempt_count > 1 {
+            info!(job_id = %job_id, kind = kind.as_str(), "resuming interrupted background job");
+        }
+
+        let mut run = JobRun {
+            state,
+            stages: lease.job.stages.clone(),
+            lease: lease.clone(),
+        };
+        match kind {
+            BackgroundJobKind::AuditLogPurge => audit_log_purge::run(&mut run).await,
+            BackgroundJobKind::QrywellSync => qrywell_sync::run(&mut run).await,
+            BackgroundJobKind::PersonalDataExport => personal_data_export::run(&mut run).await,
+            BackgroundJobKind::DataValidationAudit => data_validation_audit::run(&mut run).await,
+            BackgroundJobKind::TenantDataExport => tenant_data_export::run(&mut run).await,
+        }
+    };
+
+    let (status, status_message) = match outcome {
+        Ok(JobRunOutcome::Completed(message)) => (BackgroundJobStatus::Completed, Some(message)),
+        Ok(JobRunOutcome::Stopped(JobRunStop::Cancelled)) => (BackgroundJobStatus::Cancelled, None),
+        Ok(JobRunOutcome::Stopped(JobRunStop::LeaseLost)) => {
+            warn!(job_id = %job_id, "background job lease lapsed before the run finished");
+            return;
+        }
+        Err(error) => {
+            warn!(job_id = %job_id, kind = kind.as_str(), error = %error, "background job failed");
+            (BackgroundJobStatus::Failed, Some(error.to_string()))
+        }
+    };
+
+    if let Err(error) = state
+        .background_job_service
+        .finish_job(&lease, status, status_message.as_deref())
+        .await
+    {
+        error!(job_id = %job_id, error = %error, "failed to finish background job");
+    }
+}
@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+
+use qryvanta_application::AuditPurgePlan;
+use qryvanta_core::{AppError, AppResult};
+
+use super::{JobRun, JobRunOutcome};
+
+/// Single stage of audit log purge jobs.
+pub const AUDIT_LOG_PURGE_STAGE: &str = "delete_entries";
+
+const AUDIT_LOG_PURGE_BATCH_SIZE: usize = 500;
+
+pub(super) async fn run(run: &mut JobRun<'_>) -> AppResult<JobRunOutcome> {
+    let actor = run.requester();
+    let cutoff = DateTime::parse_from_rfc3339(run.parameter_str("cutoff")?)
+        .map_err(|error| AppError::Internal(format!("invalid audit purge cutoff: {error}")))?
+        .with_timezone(&Utc);
+    let retention_days = run
+        .lease
+        .job
+        .parameters
+        .get("retention_days")
+        .and_then(Value::as_u64)
+        .and_then(|days| u16::try_from(days).ok())
+        .ok_or_else(|| {
+            AppError::Internal("background job parameter 'retention_days' is missing".to_owned())
+        })?;
+    let plan = AuditPurgePlan {
+        retention_days,
+        cutoff,
+    };
+
+    // Deleted entries stay deleted, so a resumed run only needs the running
+    // total; the next batch picks up whatever is still before the cutoff.
+    let mut deleted_count = run.checkpoint_u64("deleted_count");
+    loop {
+        let deleted = run
+            .state
+            .security_admin_service
+            .purge_audit_log_batch(&actor, &plan, AUDIT_LOG_PURGE_BATCH_SIZE)
+            .await?;
+        deleted_count += deleted;
+
+        let finished = deleted < AUDIT_LOG_PURGE_BATCH_SIZE as u64;
+        if let Some(stage) = run.stages.first_mut() {
+            stage.processed = deleted_count;
+            stage.completed = finished;
+        }
+
+        if finished {
+            let result = run
+                .state
+                .security_admin_service
+                .complete_audit_log_purge(&actor, &plan, deleted_count)
+                .await?;
+            return Ok(JobRunOutcome::Completed(format!(
+                "purged {} audit entries older than {} days",
+                result.deleted_count, result.retention_days
+            )));
+        }
+
+        if let Some(stop) = run
+            .save_checkpoint(json!({ "deleted_count": deleted_count }))
+            .await?
+        {
+            return Ok(JobRunOutcome::Stopped(stop));
+        }
+    }
+}
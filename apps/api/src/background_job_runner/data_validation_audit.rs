@@ -0,0 +1,97 @@
+use serde_json::{Value, json};
+
+use qryvanta_core::{AppError, AppResult};
+
+use super::{JobRun, JobRunOutcome};
+
+const DATA_VALIDATION_PAGE_SIZE: usize = 200;
+
+pub(super) async fn run(run: &mut JobRun<'_>) -> AppResult<JobRunOutcome> {
+    let actor = run.requester();
+    let entity_logical_names = run
+        .lease
+        .job
+        .parameters
+        .get("entity_logical_names")
+        .and_then(Value::as_array)
+        .map(|names| {
+            names
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+        })
+        .ok_or_else(|| {
+            AppError::Internal(
+                "background job parameter 'entity_logical_names' is missing".to_owned(),
+            )
+        })?;
+
+    // The checkpoint names the entity and record offset of the next page.
+    // Violations are stored per page, so a page checked again after an
+    // interruption replaces its earlier result instead of duplicating it.
+    let mut entity_index = usize::try_from(run.checkpoint_u64("entity_index")).unwrap_or(0);
+    let mut offset = usize::try_from(run.checkpoint_u64("offset")).unwrap_or(0);
+    let mut violation_count = run.checkpoint_u64("violation_count");
+
+    while let Some(entity_logical_name) = entity_logical_names.get(entity_index) {
+        let page = match run
+            .state
+            .metadata_service
+            .validate_runtime_records_page(
+                &actor,
+                entity_logical_name.as_str(),
+                DATA_VALIDATION_PAGE_SIZE,
+                offset,
+            )
+            .await
+        {
+            Ok(page) => Some(page),
+            // The entity was unpublished or deleted after the audit was queued.
+            Err(AppError::NotFound(_)) => None,
+            Err(error) => return Err(error),
+        };
+
+        let page_len = page.as_ref().map_or(0, |page| page.records_checked);
+        if let Some(page) = page {
+            run.state
+                .data_validation_service
+                .save_page_violations(
+                    &run.lease,
+                    entity_logical_name.as_str(),
+                    offset,
+                    &page.violations,
+                )
+                .await?;
+            violation_count += page.violations.len() as u64;
+        }
+
+        offset += page_len;
+        let entity_finished = page_len < DATA_VALIDATION_PAGE_SIZE;
+        if let Some(stage) = run.stages.get_mut(entity_index) {
+            stage.processed += page_len as u64;
+            stage.completed = entity_finished;
+        }
+        if entity_finished {
+            entity_index += 1;
+            offset = 0;
+        }
+
+        if let Some(stop) = run
+            .save_checkpoint(json!({
+                "entity_index": entity_index,
+                "offset": offset,
+                "violation_count": violation_count,
+            }))
+            .await?
+        {
+            return Ok(JobRunOutcome::Stopped(stop));
+        }
+    }
+
+    let checked_records = run.stages.iter().map(|stage| stage.processed).sum::<u64>();
+    Ok(JobRunOutcome::Completed(format!(
+        "found {violation_count} violations in {checked_records} records of {} entities",
+        entity_logical_names.len()
+    )))
+}
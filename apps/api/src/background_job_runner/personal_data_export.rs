@@ -0,0 +1,140 @@
+use serde_json::{Value, json};
+
+use qryvanta_application::{PersonalDataExportPart, PersonalDataExportService};
+use qryvanta_core::{AppError, AppResult};
+
+use super::{JobRun, JobRunOutcome};
+
+const AUTH_EVENT_PAGE_SIZE: usize = 500;
+const OWNED_RECORD_PAGE_SIZE: usize = 200;
+
+/// Stage indexes, matching `PERSONAL_DATA_EXPORT_STAGES`.
+const PROFILE_STAGE: usize = 0;
+const AUTH_EVENTS_STAGE: usize = 1;
+const OWNED_RECORDS_STAGE: usize = 2;
+
+pub(super) async fn run(run: &mut JobRun<'_>) -> AppResult<JobRunOutcome> {
+    let actor = run.requester();
+    let entity_logical_names = run
+        .lease
+        .job
+        .parameters
+        .get("entity_logical_names")
+        .and_then(Value::as_array)
+        .map(|names| {
+            names
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+        })
+        .ok_or_else(|| {
+            AppError::Internal(
+                "background job parameter 'entity_logical_names' is missing".to_owned(),
+            )
+        })?;
+    let service = &run.state.personal_data_export_service;
+
+    // The checkpoint names the stage, entity and item offset of the next
+    // part. Parts are stored by position, so a part saved just before an
+    // interruption is overwritten rather than duplicated on resume.
+    let mut stage = usize::try_from(run.checkpoint_u64("stage")).unwrap_or(0);
+    let mut entity_index = usize::try_from(run.checkpoint_u64("entity_index")).unwrap_or(0);
+    let mut offset = usize::try_from(run.checkpoint_u64("offset")).unwrap_or(0);
+
+    loop {
+        let (part, stage_finished) = match stage {
+            PROFILE_STAGE => (service.profile_part(&actor).await?, true),
+            AUTH_EVENTS_STAGE => {
+                let part = service
+                    .auth_events_part(&actor, offset, AUTH_EVENT_PAGE_SIZE)
+                    .await?;
+                let finished = part.items.len() < AUTH_EVENT_PAGE_SIZE;
+                (part, finished)
+            }
+            OWNED_RECORDS_STAGE => {
+                let Some(entity_logical_name) = entity_logical_names.get(entity_index) else {
+                    complete_stage(run, stage);
+                    break;
+                };
+                let records = run
+                    .state
+                    .metadata_service
+                    .list_owned_runtime_records(
+                        &actor,
+                        entity_logical_name.as_str(),
+                        OWNED_RECORD_PAGE_SIZE,
+                        offset,
+                    )
+                    .await?;
+                let part = PersonalDataExportService::owned_records_part(
+                    entity_logical_name.as_str(),
+                    offset,
+                    &records,
+                );
+                (part, false)
+            }
+            _ => break,
+        };
+
+        let page_len = part.items.len();
+        save_part(run, &part).await?;
+        offset += page_len;
+
+        // Auth events count items; owned records count finished entities,
+        // matching the stage totals set when the job was queued.
+        if stage == OWNED_RECORDS_STAGE {
+            if page_len < OWNED_RECORD_PAGE_SIZE {
+                entity_index += 1;
+                offset = 0;
+                if let Some(run_stage) = run.stages.get_mut(stage) {
+                    run_stage.processed += 1;
+                }
+            }
+        } else if let Some(run_stage) = run.stages.get_mut(stage) {
+            run_stage.processed += page_len as u64;
+        }
+        if stage_finished {
+            complete_stage(run, stage);
+            stage += 1;
+            offset = 0;
+        }
+
+        if let Some(stop) = run
+            .save_checkpoint(json!({
+                "stage": stage,
+                "entity_index": entity_index,
+                "offset": offset,
+            }))
+            .await?
+        {
+            return Ok(JobRunOutcome::Stopped(stop));
+        }
+    }
+
+    let auth_events = run
+        .stages
+        .get(AUTH_EVENTS_STAGE)
+        .map_or(0, |stage| stage.processed);
+    Ok(JobRunOutcome::Completed(format!(
+        "exported profile, {auth_events} auth events and owned records of {} entities",
+        entity_logical_names.len()
+    )))
+}
+
+fn complete_stage(run: &mut JobRun<'_>, stage: usize) {
+    if let Some(run_stage) = run.stages.get_mut(stage) {
+        run_stage.completed = true;
+    }
+}
+
+async fn save_part(run: &JobRun<'_>, part: &PersonalDataExportPart) -> AppResult<()> {
+    if part.items.is_empty() {
+        return Ok(());
+    }
+
+    run.state
+        .personal_data_export_service
+        .save_part(&run.lease, part)
+        .await
+}
@@ -0,0 +1,93 @@
+use serde_json::{Value, json};
+
+use qryvanta_application::RecordListQuery;
+use qryvanta_core::{AppError, AppResult};
+
+use crate::dto::RuntimeRecordResponse;
+use crate::handlers::search::push_records_to_qrywell;
+
+use super::{JobRun, JobRunOutcome};
+
+const QRYWELL_SYNC_PAGE_SIZE: usize = 200;
+
+pub(super) async fn run(run: &mut JobRun<'_>) -> AppResult<JobRunOutcome> {
+    let actor = run.requester();
+    let entity_logical_names = run
+        .lease
+        .job
+        .parameters
+        .get("entity_logical_names")
+        .and_then(Value::as_array)
+        .map(|names| {
+            names
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+        })
+        .ok_or_else(|| {
+            AppError::Internal(
+                "background job parameter 'entity_logical_names' is missing".to_owned(),
+            )
+        })?;
+
+    // The checkpoint names the entity and record offset of the next page, so
+    // a resumed run neither re-pushes finished entities nor skips records.
+    let mut entity_index = usize::try_from(run.checkpoint_u64("entity_index")).unwrap_or(0);
+    let mut offset = usize::try_from(run.checkpoint_u64("offset")).unwrap_or(0);
+
+    while let Some(entity_logical_name) = entity_logical_names.get(entity_index) {
+        let records = run
+            .state
+            .metadata_service
+            .list_runtime_records(
+                &actor,
+                entity_logical_name.as_str(),
+                RecordListQuery {
+                    limit: QRYWELL_SYNC_PAGE_SIZE,
+                    offset,
+                    owner_subject: None,
+                    include_inactive: true,
+                },
+            )
+            .await?;
+        let page_len = records.len();
+
+        if page_len > 0 {
+            push_records_to_qrywell(
+                run.state,
+                &actor,
+                entity_logical_name.as_str(),
+                &records
+                    .into_iter()
+                    .map(RuntimeRecordResponse::from)
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+        }
+
+        offset += page_len;
+        let entity_finished = page_len < QRYWELL_SYNC_PAGE_SIZE;
+        if let Some(stage) = run.stages.get_mut(entity_index) {
+            stage.processed += page_len as u64;
+            stage.completed = entity_finished;
+        }
+        if entity_finished {
+            entity_index += 1;
+            offset = 0;
+        }
+
+        if let Some(stop) = run
+            .save_checkpoint(json!({ "entity_index": entity_index, "offset": offset }))
+            .await?
+        {
+            return Ok(JobRunOutcome::Stopped(stop));
+        }
+    }
+
+    let synced_records = run.stages.iter().map(|stage| stage.processed).sum::<u64>();
+    Ok(JobRunOutcome::Completed(format!(
+        "pushed {synced_records} records of {} entities to Qrywell",
+        entity_logical_names.len()
+    )))
+}
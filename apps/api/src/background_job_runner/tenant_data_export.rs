@@ -0,0 +1,166 @@
+use serde_json::{Value, json};
+
+use qryvanta_application::{
+    ExportWorkspaceBundleOptions, RecordListQuery, TenantDataExportPart, TenantDataExportService,
+};
+use qryvanta_core::{AppError, AppResult};
+
+use super::{JobRun, JobRunOutcome};
+
+const USER_PAGE_SIZE: usize = 500;
+const RUNTIME_RECORD_PAGE_SIZE: usize = 200;
+const AUDIT_ENTRY_PAGE_SIZE: usize = 500;
+
+/// Stage indexes, matching `TENANT_DATA_EXPORT_STAGES`.
+const METADATA_STAGE: usize = 0;
+const USERS_STAGE: usize = 1;
+const RUNTIME_RECORDS_STAGE: usize = 2;
+const AUDIT_LOG_STAGE: usize = 3;
+
+pub(super) async fn run(run: &mut JobRun<'_>) -> AppResult<JobRunOutcome> {
+    let actor = run.requester();
+    let entity_logical_names = run
+        .lease
+        .job
+        .parameters
+        .get("entity_logical_names")
+        .and_then(Value::as_array)
+        .map(|names| {
+            names
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+        })
+        .ok_or_else(|| {
+            AppError::Internal(
+                "background job parameter 'entity_logical_names' is missing".to_owned(),
+            )
+        })?;
+    let service = &run.state.tenant_data_export_service;
+
+    // Same checkpoint scheme as personal data exports: stage, entity and
+    // item offset of the next part, with parts stored by position.
+    let mut stage = usize::try_from(run.checkpoint_u64("stage")).unwrap_or(0);
+    let mut entity_index = usize::try_from(run.checkpoint_u64("entity_index")).unwrap_or(0);
+    let mut offset = usize::try_from(run.checkpoint_u64("offset")).unwrap_or(0);
+
+    loop {
+        let (part, stage_finished) = match stage {
+            METADATA_STAGE => {
+                let bundle = run
+                    .state
+                    .metadata_service
+                    .export_workspace_bundle(
+                        &actor,
+                        ExportWorkspaceBundleOptions {
+                            include_metadata: true,
+                            include_runtime_data: false,
+                        },
+                    )
+                    .await?;
+                (TenantDataExportService::metadata_part(&bundle)?, true)
+            }
+            USERS_STAGE => {
+                let part = service.users_part(&actor, offset, USER_PAGE_SIZE).await?;
+                let finished = part.items.len() < USER_PAGE_SIZE;
+                (part, finished)
+            }
+            RUNTIME_RECORDS_STAGE => {
+                let Some(entity_logical_name) = entity_logical_names.get(entity_index) else {
+                    complete_stage(run, stage);
+                    stage += 1;
+                    offset = 0;
+                    continue;
+                };
+                let records = run
+                    .state
+                    .metadata_service
+                    .list_runtime_records(
+                        &actor,
+                        entity_logical_name.as_str(),
+                        RecordListQuery {
+                            limit: RUNTIME_RECORD_PAGE_SIZE,
+                            offset,
+                            owner_subject: None,
+                            include_inactive: true,
+                        },
+                    )
+                    .await?;
+                let part = TenantDataExportService::runtime_records_part(
+                    entity_logical_name.as_str(),
+                    offset,
+                    &records,
+                );
+                (part, false)
+            }
+            AUDIT_LOG_STAGE => {
+                let part = service
+                    .audit_log_part(&actor, offset, AUDIT_ENTRY_PAGE_SIZE)
+                    .await?;
+                let finished = part.items.len() < AUDIT_ENTRY_PAGE_SIZE;
+                (part, finished)
+            }
+            _ => break,
+        };
+
+        let page_len = part.items.len();
+        save_part(run, &part).await?;
+        offset += page_len;
+
+        // Runtime records count finished entities, the other stages count
+        // items, matching the stage totals set when the job was queued.
+        if stage == RUNTIME_RECORDS_STAGE {
+            if page_len < RUNTIME_RECORD_PAGE_SIZE {
+                entity_index += 1;
+                offset = 0;
+                if let Some(run_stage) = run.stages.get_mut(stage) {
+                    run_stage.processed += 1;
+                }
+            }
+        } else if let Some(run_stage) = run.stages.get_mut(stage) {
+            run_stage.processed += page_len as u64;
+        }
+        if stage_finished {
+            complete_stage(run, stage);
+            stage += 1;
+            offset = 0;
+        }
+
+        if let Some(stop) = run
+            .save_checkpoint(json!({
+                "stage": stage,
+                "entity_index": entity_index,
+                "offset": offset,
+            }))
+            .await?
+        {
+            return Ok(JobRunOutcome::Stopped(stop));
+        }
+    }
+
+    let processed = |stage: usize| run.stages.get(stage).map_or(0, |stage| stage.processed);
+    Ok(JobRunOutcome::Completed(format!(
+        "exported metadata, {} users, records of {} entities and {} audit entries",
+        processed(USERS_STAGE),
+        entity_logical_names.len(),
+        processed(AUDIT_LOG_STAGE)
+    )))
+}
+
+fn complete_stage(run: &mut JobRun<'_>, stage: usize) {
+    if let Some(run_stage) = run.stages.get_mut(stage) {
+        run_stage.completed = true;
+    }
+}
+
+async fn save_part(run: &JobRun<'_>, part: &TenantDataExportPart) -> AppResult<()> {
+    if part.items.is_empty() {
+        return Ok(());
+    }
+
+    run.state
+        .tenant_data_export_service
+        .save_part(&run.lease, part)
+        .await
+}
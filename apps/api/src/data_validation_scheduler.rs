@@ -0,0 +1,104 @@
+//! Background scheduler that queues due data validation audits.
+
+use std::time::Duration;
+
+use tracing::{error, info, warn};
+
+use qryvanta_application::{DataValidationService, DueDataValidationSchedule};
+use qryvanta_core::AppError;
+
+use crate::state::AppState;
+
+/// Schedules claimed per poll.
+const DATA_VALIDATION_SCHEDULE_BATCH_SIZE: usize = 10;
+
+/// Schedules run in whole days, so a coarse poll is enough.
+const DATA_VALIDATION_SCHEDULE_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+pub fn spawn_data_validation_scheduler(state: AppState) {
+    tokio::spawn(async move {
+        info!(
+            interval_ms = DATA_VALIDATION_SCHEDULE_POLL_INTERVAL.as_millis() as u64,
+            "data validation scheduler started"
+        );
+
+        loop {
+            match state
+                .data_validation_service
+                .claim_due_schedules(DATA_VALIDATION_SCHEDULE_BATCH_SIZE)
+                .await
+            {
+                Ok(due) => {
+                    let full_batch = due.len() >= DATA_VALIDATION_SCHEDULE_BATCH_SIZE;
+                    for schedule in due {
+                        queue_scheduled_audit(&state, schedule).await;
+                    }
+                    if full_batch {
+                        continue;
+                    }
+                }
+                Err(error) => error!(error = %error, "data validation schedule claim failed"),
+            }
+
+            tokio::time::sleep(DATA_VALIDATION_SCHEDULE_POLL_INTERVAL).await;
+        }
+    });
+}
+
+/// Queues the audit of one claimed schedule.
+///
+/// A slot whose audit cannot be queued because the owner lost access or an
+/// audit is still running is skipped; other failures are retried once the
+/// claim lapses.
+async fn queue_scheduled_audit(state: &AppState, due: DueDataValidationSchedule) {
+    let owner = DataValidationService::schedule_owner(&due);
+    let tenant_id = due.tenant_id.to_string();
+    let queued = match state
+        .metadata_service
+        .list_published_entity_logical_names_unchecked(&owner)
+        .await
+    {
+        Ok(entity_logical_names) => {
+            state
+                .data_validation_service
+                .request_audit(&owner, entity_logical_names)
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    match queued {
+        Ok(job) => info!(
+            tenant_id = %tenant_id,
+            job_id = %job.job_id,
+            "scheduled data validation audit queued"
+        ),
+        Err(error @ (AppError::Forbidden(_) | AppError::Conflict(_) | AppError::Validation(_))) => {
+            warn!(
+                tenant_id = %tenant_id,
+                error = %error,
+                "scheduled data validation audit skipped"
+            )
+        }
+        Err(error) => {
+            warn!(
+                tenant_id = %tenant_id,
+                error = %error,
+                "scheduled data validation audit failed; will retry"
+            );
+            return;
+        }
+    }
+
+    if let Err(error) = state
+        .data_validation_service
+        .record_scheduled_run(&due)
+        .await
+    {
+        error!(
+            tenant_id = %tenant_id,
+            error = %error,
+            "failed to record data validation schedule run"
+        );
+    }
+}
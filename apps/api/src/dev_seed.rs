@@ -9,9 +9,10 @@ use qryvanta_application::{
 };
 use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
 use qryvanta_domain::{
-    AppEntityViewMode, AppSitemap, FieldType, FormFieldPlacement, FormSection, FormTab, FormType,
-    Permission, SitemapArea, SitemapGroup, SitemapSubArea, SitemapTarget, SortDirection,
-    ViewColumn, ViewSort, ViewType, WorkflowConditionOperator, WorkflowStep, WorkflowTrigger,
+    AppEntityViewMode, AppSitemap, FieldType, FormFieldPlacement, FormScriptEvents, FormSection,
+    FormTab, FormType, Permission, SitemapArea, SitemapGroup, SitemapSubArea, SitemapTarget,
+    SortDirection, SubjectType, ViewColumn, ViewSort, ViewType, WorkflowConditionOperator,
+    WorkflowRunPriority, WorkflowStep, WorkflowTrigger,
 };
 
 use qryvanta_infrastructure::{Argon2PasswordHasher, begin_tenant_transaction};
@@ -264,6 +265,7 @@ async fn ensure_standard_membership(
         r#"
         DELETE FROM rbac_subject_roles
         WHERE tenant_id = $1
+          AND subject_type = 'user'
           AND subject = $2
           AND role_id IN (
               SELECT id FROM rbac_roles WHERE tenant_id = $1 AND name = 'tenant_owner'
@@ -1256,7 +1258,12 @@ async fn ensure_roles_and_assignments(
     }
 
     security_admin_service
-        .assign_role(actor, standard_subject, DEV_SEED_STANDARD_ROLE)
+        .assign_role(
+            actor,
+            SubjectType::User,
+            standard_subject,
+            DEV_SEED_STANDARD_ROLE,
+        )
         .await?;
 
     Ok(())
@@ -1337,6 +1344,10 @@ async fn seed_workflows(workflow_service: &WorkflowService, actor: &UserIdentity
                 }],
                 max_attempts: 3,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await?;
@@ -1362,13 +1373,17 @@ async fn seed_workflows(workflow_service: &WorkflowService, actor: &UserIdentity
                             "job_title": "Collections",
                             "phone": "+1-555-0199"
                         }),
-                    },
+                        output_key: None, retry_policy: None, },
                     WorkflowStep::LogMessage {
                         message: "Invoice overdue follow-up workflow executed".to_owned(),
                     },
                 ],
                 max_attempts: 3,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await?;
@@ -1671,6 +1686,7 @@ async fn save_form(
                 form_type,
                 tabs,
                 header_fields,
+                script_events: FormScriptEvents::default(),
             },
         )
         .await?;
@@ -1752,6 +1768,7 @@ async fn ensure_entity(
             description,
             plural_display_name,
             icon,
+            None,
         )
         .await?;
     existing_entities.insert(logical_name.to_owned());
@@ -1782,6 +1799,7 @@ async fn save_text_field(
                 relation_target_entity: None,
                 option_set_logical_name: None,
                 calculation_expression: None,
+                classification: None,
             },
         )
         .await?;
@@ -1812,6 +1830,7 @@ async fn save_number_field(
                 relation_target_entity: None,
                 option_set_logical_name: None,
                 calculation_expression: None,
+                classification: None,
             },
         )
         .await?;
@@ -1842,6 +1861,7 @@ async fn save_date_field(
                 relation_target_entity: None,
                 option_set_logical_name: None,
                 calculation_expression: None,
+                classification: None,
             },
         )
         .await?;
@@ -1874,6 +1894,7 @@ async fn save_relation_field(
                 relation_target_entity: Some(relation_target_entity.to_owned()),
                 option_set_logical_name: None,
                 calculation_expression: None,
+                classification: None,
             },
         )
         .await?;
@@ -1928,6 +1949,7 @@ async fn upsert_runtime_record(
                 limit: 500,
                 offset: 0,
                 owner_subject: None,
+                include_inactive: true,
             },
         )
         .await?;
@@ -0,0 +1,367 @@
+//! `qryvanta-api doctor`: validates configuration and dependencies before serving traffic.
+
+use std::time::Duration;
+
+use qryvanta_core::{AppError, DoctorReport};
+use sqlx::postgres::PgPoolOptions;
+use url::Url;
+
+use crate::api_config::{ApiConfig, EmailProviderConfig};
+use crate::api_services::read_migration_level;
+
+const DEPENDENCY_TIMEOUT: Duration = Duration::from_secs(5);
+const RECOMMENDED_TOKEN_LENGTH: usize = 32;
+
+/// Runs every check, prints the report and fails when any check is blocking.
+pub async fn run() -> Result<(), AppError> {
+    let mut report = DoctorReport::new();
+
+    match ApiConfig::load() {
+        Ok(config) => {
+            report.pass(
+                "configuration",
+                "environment variables parsed and validated",
+            );
+            check_static_config(&config, &mut report);
+            check_database(&config, &mut report).await;
+            check_redis(&config, &mut report).await;
+            check_smtp(&config, &mut report).await;
+        }
+        Err(error) => report.error("configuration", error.to_string()),
+    }
+
+    println!("{}", report.render());
+    if report.has_errors() {
+        return Err(AppError::Validation(
+            "doctor found blocking configuration issues".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that need no network access.
+fn check_static_config(config: &ApiConfig, report: &mut DoctorReport) {
+    check_webauthn(config, report);
+
+    let is_production = config.environment.is_production();
+    let serves_https = config.frontend_url.starts_with("https://");
+    if config.cookie_secure {
+        report.pass("cookies", "session cookies are marked Secure");
+    } else if is_production {
+        report.error(
+            "cookies",
+            "SESSION_COOKIE_SECURE=false in production; set it to true so session cookies never travel over plain HTTP",
+        );
+    } else if serves_https {
+        report.warn(
+            "cookies",
+            "FRONTEND_URL uses https but SESSION_COOKIE_SECURE=false; set it to true",
+        );
+    } else {
+        report.pass(
+            "cookies",
+            "insecure cookies accepted for local HTTP development",
+        );
+    }
+
+    check_token_length(
+        report,
+        "AUTH_BOOTSTRAP_TOKEN",
+        config.bootstrap_token.as_str(),
+    );
+    if let Some(worker_shared_secret) = config.worker_shared_secret.as_deref() {
+        check_token_length(report, "WORKER_SHARED_SECRET", worker_shared_secret);
+    }
+    for credential in &config.operator_credentials {
+        check_token_length(
+            report,
+            format!("OPERATOR_API_TOKENS token of '{}'", credential.operator_id).as_str(),
+            credential.token.as_str(),
+        );
+    }
+
+    if is_production && config.bootstrap_tenant_id.is_some() {
+        report.warn(
+            "bootstrap",
+            "DEV_DEFAULT_TENANT_ID is set in production; unset it once the first tenant exists",
+        );
+    }
+    if is_production && matches!(config.email_provider, EmailProviderConfig::Console) {
+        report.warn(
+            "email",
+            "EMAIL_PROVIDER=console in production; invites and password resets are only logged",
+        );
+    }
+}
+
+fn check_webauthn(config: &ApiConfig, report: &mut DoctorReport) {
+    let origin = match Url::parse(&config.webauthn_rp_origin) {
+        Ok(origin) => origin,
+        Err(error) => {
+            report.error(
+                "webauthn",
+                format!("WEBAUTHN_RP_ORIGIN is not a valid URL: {error}"),
+            );
+            return;
+        }
+    };
+    let host = origin.host_str().unwrap_or_default();
+    let rp_id = config.webauthn_rp_id.as_str();
+
+    if host != rp_id && !host.ends_with(&format!(".{rp_id}")) {
+        report.error(
+            "webauthn",
+            format!(
+                "WEBAUTHN_RP_ORIGIN host '{host}' is not WEBAUTHN_RP_ID '{rp_id}' or one of its subdomains; passkey ceremonies will fail"
+            ),
+        );
+        return;
+    }
+    if origin.scheme() != "https" && host != "localhost" {
+        report.error(
+            "webauthn",
+            "WEBAUTHN_RP_ORIGIN must use https outside localhost; browsers refuse passkeys on plain HTTP",
+        );
+        return;
+    }
+
+    let frontend_origin = Url::parse(&config.frontend_url)
+        .ok()
+        .map(|url| url.origin().ascii_serialization());
+    if frontend_origin.as_deref() != Some(origin.origin().ascii_serialization().as_str()) {
+        report.warn(
+            "webauthn",
+            format!(
+                "WEBAUTHN_RP_ORIGIN '{}' differs from FRONTEND_URL '{}'; passkeys registered in the web app will not verify",
+                config.webauthn_rp_origin, config.frontend_url
+            ),
+        );
+        return;
+    }
+
+    report.pass(
+        "webauthn",
+        format!("relying party '{rp_id}' matches origin '{host}'"),
+    );
+}
+
+fn check_token_length(report: &mut DoctorReport, name: &str, value: &str) {
+    if value.len() < RECOMMENDED_TOKEN_LENGTH {
+        report.warn(
+            "secrets",
+            format!(
+                "{name} is {} characters; use at least {RECOMMENDED_TOKEN_LENGTH} random characters",
+                value.len()
+            ),
+        );
+    } else {
+        report.pass("secrets", format!("{name} length is sufficient"));
+    }
+}
+
+async fn check_database(config: &ApiConfig, report: &mut DoctorReport) {
+    let pool = match PgPoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(DEPENDENCY_TIMEOUT)
+        .connect(&config.database_url)
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => {
+            report.error(
+                "database",
+                format!("cannot connect with DATABASE_URL: {error}"),
+            );
+            return;
+        }
+    };
+    report.pass("database", "connected to Postgres");
+
+    match read_migration_level(&pool).await {
+        Ok(level) if level.pending_count == 0 => report.pass(
+            "migrations",
+            format!("schema is at migration {}", level.expected_version),
+        ),
+        Ok(level) => report.warn(
+            "migrations",
+            format!(
+                "{} migrations pending (applied {}, expected {}); they run on the next start or with `qryvanta-api migrate`",
+                level.pending_count,
+                level
+                    .applied_version
+                    .map_or_else(|| "none".to_owned(), |version| version.to_string()),
+                level.expected_version
+            ),
+        ),
+        Err(_) => report.warn(
+            "migrations",
+            "no migration history found; run `qryvanta-api migrate` before first start",
+        ),
+    }
+    pool.close().await;
+}
+
+async fn check_redis(config: &ApiConfig, report: &mut DoctorReport) {
+    let Some(redis_url) = config.redis_url.as_deref() else {
+        report.pass("redis", "not configured; Postgres-backed adapters are used");
+        return;
+    };
+
+    let ping = async {
+        let client = redis::Client::open(redis_url).map_err(|error| error.to_string())?;
+        let mut connection = client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|error| error.to_string())?;
+        redis::cmd("PING")
+            .query_async::<String>(&mut connection)
+            .await
+            .map_err(|error| error.to_string())
+    };
+
+    let failure = match tokio::time::timeout(DEPENDENCY_TIMEOUT, ping).await {
+        Ok(Ok(_)) => {
+            report.pass("redis", "PING succeeded");
+            return;
+        }
+        Ok(Err(error)) => format!("cannot reach REDIS_URL: {error}"),
+        Err(_) => "REDIS_URL did not answer within 5 seconds".to_owned(),
+    };
+
+    if config.requires_redis() {
+        report.error("redis", failure);
+    } else {
+        report.warn(
+            "redis",
+            format!("{failure}; no enabled backend needs it yet"),
+        );
+    }
+}
+
+async fn check_smtp(config: &ApiConfig, report: &mut DoctorReport) {
+    let EmailProviderConfig::Smtp(smtp) = &config.email_provider else {
+        return;
+    };
+
+    let address = (smtp.host.as_str(), smtp.port);
+    match tokio::time::timeout(DEPENDENCY_TIMEOUT, tokio::net::TcpStream::connect(address)).await {
+        Ok(Ok(_)) => report.pass(
+            "smtp",
+            format!("{}:{} accepts connections", smtp.host, smtp.port),
+        ),
+        Ok(Err(error)) => report.error(
+            "smtp",
+            format!("cannot connect to {}:{}: {error}", smtp.host, smtp.port),
+        ),
+        Err(_) => report.error(
+            "smtp",
+            format!(
+                "{}:{} did not accept a connection within 5 seconds",
+                smtp.host, smtp.port
+            ),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qryvanta_core::DoctorSeverity;
+
+    use super::*;
+    use crate::api_config::RuntimeEnvironment;
+
+    fn config(frontend_url: &str, rp_id: &str, rp_origin: &str) -> ApiConfig {
+        let mut config = ApiConfig::test_defaults();
+        config.frontend_url = frontend_url.to_owned();
+        config.webauthn_rp_id = rp_id.to_owned();
+        config.webauthn_rp_origin = rp_origin.to_owned();
+        config.worker_shared_secret = Some("short".to_owned());
+        config
+    }
+
+    fn severity_of(report: &DoctorReport, check: &str) -> Vec<DoctorSeverity> {
+        report
+            .findings()
+            .iter()
+            .filter(|finding| finding.check == check)
+            .map(|finding| finding.severity)
+            .collect()
+    }
+
+    #[test]
+    fn local_defaults_pass_except_short_worker_secret() {
+        let config = config(
+            "http://localhost:3000",
+            "localhost",
+            "http://localhost:3000",
+        );
+        let mut report = DoctorReport::new();
+        check_static_config(&config, &mut report);
+
+        assert_eq!(severity_of(&report, "webauthn"), vec![DoctorSeverity::Pass]);
+        assert_eq!(severity_of(&report, "cookies"), vec![DoctorSeverity::Pass]);
+        assert_eq!(
+            severity_of(&report, "secrets"),
+            vec![DoctorSeverity::Pass, DoctorSeverity::Warning]
+        );
+        assert!(!report.has_errors());
+    }
+
+    #[test]
+    fn production_rejects_insecure_cookies_and_mismatched_relying_party() {
+        let mut config = config(
+            "https://app.example.com",
+            "example.org",
+            "https://app.example.com",
+        );
+        config.environment = RuntimeEnvironment::Production;
+        let mut report = DoctorReport::new();
+        check_static_config(&config, &mut report);
+
+        assert_eq!(
+            severity_of(&report, "webauthn"),
+            vec![DoctorSeverity::Error]
+        );
+        assert_eq!(severity_of(&report, "cookies"), vec![DoctorSeverity::Error]);
+        assert_eq!(severity_of(&report, "email"), vec![DoctorSeverity::Warning]);
+    }
+
+    #[test]
+    fn relying_party_subdomain_origin_must_use_https_and_match_frontend() {
+        let mut report = DoctorReport::new();
+        check_webauthn(
+            &config(
+                "http://app.example.com",
+                "example.com",
+                "http://app.example.com",
+            ),
+            &mut report,
+        );
+        check_webauthn(
+            &config(
+                "https://www.example.com",
+                "example.com",
+                "https://app.example.com",
+            ),
+            &mut report,
+        );
+        check_webauthn(
+            &config(
+                "https://app.example.com",
+                "example.com",
+                "https://app.example.com",
+            ),
+            &mut report,
+        );
+
+        assert_eq!(
+            severity_of(&report, "webauthn"),
+            vec![
+                DoctorSeverity::Error,
+                DoctorSeverity::Warning,
+                DoctorSeverity::Pass
+            ]
+        );
+    }
+}
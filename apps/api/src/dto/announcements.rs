@@ -0,0 +1,69 @@
+use qryvanta_application::Announcement;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Incoming payload for publishing or editing an announcement banner.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-announcement-request.ts"
+)]
+pub struct SaveAnnouncementRequest {
+    pub title: String,
+    pub body: String,
+    #[ts(type = "\"info\" | \"warning\" | \"critical\"")]
+    pub severity: String,
+    pub starts_at: Option<String>,
+    pub ends_at: Option<String>,
+    #[serde(default)]
+    pub audience_roles: Vec<String>,
+    #[serde(default)]
+    pub audience_apps: Vec<String>,
+    #[serde(default = "default_dismissible")]
+    pub is_dismissible: bool,
+}
+
+fn default_dismissible() -> bool {
+    true
+}
+
+/// Announcement banner with its schedule and audience.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/announcement-response.ts"
+)]
+pub struct AnnouncementResponse {
+    pub announcement_id: String,
+    pub title: String,
+    pub body: String,
+    #[ts(type = "\"info\" | \"warning\" | \"critical\"")]
+    pub severity: String,
+    pub starts_at: String,
+    pub ends_at: Option<String>,
+    pub audience_roles: Vec<String>,
+    pub audience_apps: Vec<String>,
+    pub is_dismissible: bool,
+    pub created_by_subject: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<Announcement> for AnnouncementResponse {
+    fn from(value: Announcement) -> Self {
+        Self {
+            announcement_id: value.announcement_id,
+            title: value.title,
+            body: value.body,
+            severity: value.severity.as_str().to_owned(),
+            starts_at: value.starts_at.to_rfc3339(),
+            ends_at: value.ends_at.map(|timestamp| timestamp.to_rfc3339()),
+            audience_roles: value.audience_roles,
+            audience_apps: value.audience_apps,
+            is_dismissible: value.is_dismissible,
+            created_by_subject: value.created_by_subject,
+            created_at: value.created_at.to_rfc3339(),
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
@@ -2,14 +2,17 @@ mod conversions;
 mod types;
 
 pub use types::{
-    AppEntityBindingResponse, AppEntityCapabilitiesResponse, AppPublishChecksResponse, AppResponse,
+    AppAdminGrantResponse, AppEntityBindingResponse, AppEntityCapabilitiesBulkResponse,
+    AppEntityCapabilitiesResponse, AppNavigationResponse, AppPublishChecksResponse, AppResponse,
     AppRoleEntityPermissionResponse, AppSitemapAreaDto, AppSitemapGroupDto, AppSitemapResponse,
     AppSitemapSubAreaDto, AppSitemapTargetDto, BindAppEntityRequest, CreateAppRequest,
-    SaveAppRoleEntityPermissionRequest, SaveAppSitemapRequest, WorkspaceDashboardResponse,
+    GrantAppAdminRequest, PersonalViewResponse, SaveAppRoleEntityPermissionRequest,
+    SaveAppSitemapRequest, SavePersonalViewRequest, WorkspaceDashboardResponse,
 };
 
 #[cfg(test)]
 pub use types::{
-    AppEntityFormDto, AppEntityViewDto, AppEntityViewModeDto, ChartAggregationDto, ChartResponse,
-    ChartTypeDto, DashboardWidgetResponse,
+    AppEntityCapabilitySummaryResponse, AppEntityFieldPermissionSummaryResponse, AppEntityFormDto,
+    AppEntityViewDto, AppEntityViewModeDto, AppNavigationEntityResponse, ChartAggregationDto,
+    ChartResponse, ChartTypeDto, DashboardWidgetResponse,
 };
@@ -4,12 +4,18 @@ use qryvanta_domain::{
     SitemapArea, SitemapGroup, SitemapSubArea, SitemapTarget,
 };
 
+use qryvanta_application::{
+    AppAdminGrant, AppEntityCapabilitySummary, EntityPresentation, PersonalView,
+};
+
 use super::types::{
-    AppEntityBindingResponse, AppEntityCapabilitiesResponse, AppEntityFormDto, AppEntityViewDto,
-    AppEntityViewModeDto, AppResponse, AppRoleEntityPermissionResponse, AppSitemapAreaDto,
-    AppSitemapGroupDto, AppSitemapResponse, AppSitemapSubAreaDto, AppSitemapTargetDto,
-    ChartAggregationDto, ChartResponse, ChartTypeDto, DashboardWidgetResponse,
-    WorkspaceDashboardResponse,
+    AppAdminGrantResponse, AppEntityBindingResponse, AppEntityCapabilitiesBulkResponse,
+    AppEntityCapabilitiesResponse, AppEntityCapabilitySummaryResponse,
+    AppEntityFieldPermissionSummaryResponse, AppEntityFormDto, AppEntityViewDto,
+    AppEntityViewModeDto, AppNavigationEntityResponse, AppNavigationResponse, AppResponse,
+    AppRoleEntityPermissionResponse, AppSitemapAreaDto, AppSitemapGroupDto, AppSitemapResponse,
+    AppSitemapSubAreaDto, AppSitemapTargetDto, ChartAggregationDto, ChartResponse, ChartTypeDto,
+    DashboardWidgetResponse, PersonalViewResponse, WorkspaceDashboardResponse,
 };
 
 impl From<AppDefinition> for AppResponse {
@@ -18,6 +24,7 @@ impl From<AppDefinition> for AppResponse {
             logical_name: value.logical_name().as_str().to_owned(),
             display_name: value.display_name().as_str().to_owned(),
             description: value.description().map(ToOwned::to_owned),
+            localized_label: None,
         }
     }
 }
@@ -105,6 +112,17 @@ impl From<AppEntityRolePermission> for AppRoleEntityPermissionResponse {
     }
 }
 
+impl From<AppAdminGrant> for AppAdminGrantResponse {
+    fn from(value: AppAdminGrant) -> Self {
+        Self {
+            app_logical_name: value.app_logical_name,
+            subject: value.subject,
+            granted_by_subject: value.granted_by_subject,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
+
 impl From<AppSitemap> for AppSitemapResponse {
     fn from(value: AppSitemap) -> Self {
         Self {
@@ -210,14 +228,97 @@ impl TryFrom<AppSitemapTargetDto> for SitemapTarget {
     }
 }
 
-impl From<qryvanta_application::SubjectEntityPermission> for AppEntityCapabilitiesResponse {
-    fn from(value: qryvanta_application::SubjectEntityPermission) -> Self {
+impl AppEntityCapabilitiesResponse {
+    /// Builds capabilities together with the entity's published presentation metadata.
+    pub fn from_permission(
+        value: qryvanta_application::SubjectEntityPermission,
+        presentation: Option<EntityPresentation>,
+    ) -> Self {
+        let (icon, color) = presentation
+            .map(|presentation| (presentation.icon, presentation.color))
+            .unwrap_or_default();
         Self {
             entity_logical_name: value.entity_logical_name,
             can_read: value.can_read,
             can_create: value.can_create,
             can_update: value.can_update,
             can_delete: value.can_delete,
+            icon,
+            color,
+        }
+    }
+}
+
+impl AppEntityCapabilitiesBulkResponse {
+    /// Builds the bulk response from per-entity capability summaries.
+    pub fn from_summaries(
+        app_logical_name: impl Into<String>,
+        summaries: Vec<AppEntityCapabilitySummary>,
+    ) -> Self {
+        Self {
+            app_logical_name: app_logical_name.into(),
+            entities: summaries
+                .into_iter()
+                .map(AppEntityCapabilitySummaryResponse::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<AppEntityCapabilitySummary> for AppEntityCapabilitySummaryResponse {
+    fn from(value: AppEntityCapabilitySummary) -> Self {
+        let binding = AppEntityBindingResponse::from(value.binding);
+        let field_permissions = match value.field_access {
+            Some(field_access) => AppEntityFieldPermissionSummaryResponse {
+                restricted: true,
+                readable_field_logical_names: field_access.readable_fields.into_iter().collect(),
+                writable_field_logical_names: field_access.writable_fields.into_iter().collect(),
+            },
+            None => AppEntityFieldPermissionSummaryResponse {
+                restricted: false,
+                readable_field_logical_names: Vec::new(),
+                writable_field_logical_names: Vec::new(),
+            },
+        };
+
+        Self {
+            capabilities: AppEntityCapabilitiesResponse::from_permission(
+                value.permission,
+                value.presentation,
+            ),
+            navigation_label: binding.navigation_label,
+            forms: binding.forms,
+            list_views: binding.list_views,
+            default_form_logical_name: binding.default_form_logical_name,
+            default_list_view_logical_name: binding.default_list_view_logical_name,
+            default_view_mode: binding.default_view_mode,
+            field_permissions,
+        }
+    }
+}
+
+impl AppNavigationResponse {
+    /// Builds navigation from a subject-filtered sitemap and its entity presentation.
+    pub fn from_sitemap(sitemap: AppSitemap, entities: Vec<EntityPresentation>) -> Self {
+        let sitemap = AppSitemapResponse::from(sitemap);
+        Self {
+            app_logical_name: sitemap.app_logical_name,
+            areas: sitemap.areas,
+            entities: entities
+                .into_iter()
+                .map(AppNavigationEntityResponse::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<EntityPresentation> for AppNavigationEntityResponse {
+    fn from(value: EntityPresentation) -> Self {
+        Self {
+            entity_logical_name: value.entity_logical_name,
+            display_name: value.display_name,
+            icon: value.icon,
+            color: value.color,
         }
     }
 }
@@ -293,3 +394,28 @@ impl From<ChartAggregation> for ChartAggregationDto {
         }
     }
 }
+
+impl From<PersonalView> for PersonalViewResponse {
+    fn from(value: PersonalView) -> Self {
+        Self {
+            view_id: value.view_id,
+            app_logical_name: value.app_logical_name,
+            entity_logical_name: value.entity_logical_name,
+            owner_subject: value.owner_subject,
+            display_name: value.display_name,
+            columns: value
+                .columns
+                .iter()
+                .map(serde_json::to_value)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap_or_default(),
+            default_sort: value
+                .default_sort
+                .and_then(|sort| serde_json::to_value(sort).ok()),
+            filter_criteria: value
+                .filter_criteria
+                .and_then(|group| serde_json::to_value(group).ok()),
+            is_shared: value.is_shared,
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use ts_rs::TS;
 
+use crate::dto::LocalizedLabelResponse;
+
 /// App-scoped default worker view mode.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, TS)]
 #[serde(rename_all = "snake_case")]
@@ -35,6 +38,8 @@ pub struct AppResponse {
     pub logical_name: String,
     pub display_name: String,
     pub description: Option<String>,
+    /// Label matching the caller's `Accept-Language` preferences, when one exists.
+    pub localized_label: Option<LocalizedLabelResponse>,
 }
 
 /// Incoming payload for binding an entity into app navigation.
@@ -138,6 +143,29 @@ pub struct AppRoleEntityPermissionResponse {
     pub can_delete: bool,
 }
 
+/// Incoming payload for granting delegated app administration.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/grant-app-admin-request.ts"
+)]
+pub struct GrantAppAdminRequest {
+    pub subject: String,
+}
+
+/// API representation of a delegated app administrator.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/app-admin-grant-response.ts"
+)]
+pub struct AppAdminGrantResponse {
+    pub app_logical_name: String,
+    pub subject: String,
+    pub granted_by_subject: String,
+    pub created_at: String,
+}
+
 /// API representation of effective app entity capabilities for the current subject.
 #[derive(Debug, Serialize, TS)]
 #[ts(
@@ -150,6 +178,50 @@ pub struct AppEntityCapabilitiesResponse {
     pub can_create: bool,
     pub can_update: bool,
     pub can_delete: bool,
+    pub icon: Option<String>,
+    pub color: Option<String>,
+}
+
+/// Field-level runtime access summary for one workspace entity.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/app-entity-field-permission-summary-response.ts"
+)]
+pub struct AppEntityFieldPermissionSummaryResponse {
+    /// Whether explicit field grants restrict the subject; when `false` all fields are accessible.
+    pub restricted: bool,
+    pub readable_field_logical_names: Vec<String>,
+    pub writable_field_logical_names: Vec<String>,
+}
+
+/// Effective capabilities, surfaces, and default modes for one app entity.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/app-entity-capability-summary-response.ts"
+)]
+pub struct AppEntityCapabilitySummaryResponse {
+    #[serde(flatten)]
+    pub capabilities: AppEntityCapabilitiesResponse,
+    pub navigation_label: Option<String>,
+    pub forms: Vec<AppEntityFormDto>,
+    pub list_views: Vec<AppEntityViewDto>,
+    pub default_form_logical_name: String,
+    pub default_list_view_logical_name: String,
+    pub default_view_mode: AppEntityViewModeDto,
+    pub field_permissions: AppEntityFieldPermissionSummaryResponse,
+}
+
+/// Bulk capability introspection for every entity bound to an app.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/app-entity-capabilities-bulk-response.ts"
+)]
+pub struct AppEntityCapabilitiesBulkResponse {
+    pub app_logical_name: String,
+    pub entities: Vec<AppEntityCapabilitySummaryResponse>,
 }
 
 /// Worker-facing dashboard metadata response.
@@ -246,6 +318,31 @@ pub struct AppSitemapResponse {
     pub areas: Vec<AppSitemapAreaDto>,
 }
 
+/// Worker-facing app navigation with entity presentation metadata.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/app-navigation-response.ts"
+)]
+pub struct AppNavigationResponse {
+    pub app_logical_name: String,
+    pub areas: Vec<AppSitemapAreaDto>,
+    pub entities: Vec<AppNavigationEntityResponse>,
+}
+
+/// Published icon and color of an entity reachable from app navigation.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/app-navigation-entity-response.ts"
+)]
+pub struct AppNavigationEntityResponse {
+    pub entity_logical_name: String,
+    pub display_name: String,
+    pub icon: Option<String>,
+    pub color: Option<String>,
+}
+
 /// App-level publish validation report.
 #[derive(Debug, Serialize, TS)]
 #[ts(
@@ -318,3 +415,42 @@ pub enum AppSitemapTargetDto {
         url: String,
     },
 }
+
+/// Incoming payload for personal view create/update.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-personal-view-request.ts"
+)]
+pub struct SavePersonalViewRequest {
+    pub display_name: String,
+    #[ts(type = "unknown[]")]
+    pub columns: Vec<Value>,
+    #[ts(type = "unknown | null")]
+    pub default_sort: Option<Value>,
+    #[ts(type = "unknown | null")]
+    pub filter_criteria: Option<Value>,
+    #[serde(default)]
+    pub is_shared: bool,
+}
+
+/// API response for personal views.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/personal-view-response.ts"
+)]
+pub struct PersonalViewResponse {
+    pub view_id: String,
+    pub app_logical_name: String,
+    pub entity_logical_name: String,
+    pub owner_subject: String,
+    pub display_name: String,
+    #[ts(type = "unknown[]")]
+    pub columns: Vec<Value>,
+    #[ts(type = "unknown | null")]
+    pub default_sort: Option<Value>,
+    #[ts(type = "unknown | null")]
+    pub filter_criteria: Option<Value>,
+    pub is_shared: bool,
+}
@@ -2,5 +2,8 @@ mod types;
 
 pub use types::{
     AcceptInviteRequest, AuthLoginRequest, AuthLoginResponse, AuthMfaVerifyRequest,
-    AuthRegisterRequest, AuthStepUpRequest, AuthSwitchTenantRequest, InviteRequest,
+    AuthRegisterRequest, AuthStepUpRequest, AuthSwitchTenantRequest,
+    BootstrapTokenRotationResponse, ConfirmEmailChangeRequest, ConfirmEmailChangeResponse,
+    EmailChangeRequest, EmailChangeStatusResponse, InviteRequest, PasskeyResponse,
+    PendingEmailChangeResponse, RenamePasskeyRequest,
 };
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use crate::dto::TenantOptionResponse;
+
 /// Incoming payload for email/password registration.
 #[derive(Debug, Deserialize, TS)]
 #[ts(
@@ -22,6 +24,10 @@ pub struct AuthRegisterRequest {
 pub struct AuthLoginRequest {
     pub email: String,
     pub password: String,
+    /// Tenant to enter directly, skipping the tenant selection step.
+    #[serde(default)]
+    #[ts(optional)]
+    pub tenant_id: Option<String>,
 }
 
 /// Auth status response for login and challenge flows.
@@ -33,20 +39,31 @@ pub struct AuthLoginRequest {
 pub struct AuthLoginResponse {
     pub status: String,
     pub requires_totp: bool,
+    /// Second factors accepted when `status` is `mfa_required`.
+    #[ts(type = "Array<\"totp\" | \"recovery\" | \"passkey\">")]
+    pub mfa_methods: Vec<String>,
+    /// Tenants to choose from when `status` is `tenant_selection_required`.
+    pub tenants: Vec<TenantOptionResponse>,
 }
 
-/// Incoming payload for TOTP or recovery code verification.
+/// Incoming payload for TOTP, recovery code, or passkey verification.
 #[derive(Debug, Deserialize, TS)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/auth-mfa-verify-request.ts"
 )]
 pub struct AuthMfaVerifyRequest {
+    /// TOTP or recovery code; ignored for the `passkey` method.
+    #[serde(default)]
     pub code: String,
     pub method: Option<String>,
+    /// WebAuthn assertion answering the passkey MFA challenge.
+    #[serde(default)]
+    #[ts(optional, type = "unknown")]
+    pub credential: Option<serde_json::Value>,
 }
 
-/// Incoming payload for authenticated tenant switching.
+/// Incoming payload for authenticated tenant switching and login tenant selection.
 #[derive(Debug, Deserialize, TS)]
 #[ts(
     export,
@@ -68,6 +85,29 @@ pub struct AuthStepUpRequest {
     pub method: Option<String>,
 }
 
+/// Registered passkey shown on the security profile.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/passkey-response.ts"
+)]
+pub struct PasskeyResponse {
+    pub passkey_id: String,
+    pub name: String,
+    pub created_at: String,
+    pub last_used_at: Option<String>,
+}
+
+/// Incoming payload for renaming a passkey.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/rename-passkey-request.ts"
+)]
+pub struct RenamePasskeyRequest {
+    pub name: String,
+}
+
 /// Incoming payload for invite creation.
 #[derive(Debug, Deserialize, TS)]
 #[ts(
@@ -90,3 +130,74 @@ pub struct AcceptInviteRequest {
     pub password: Option<String>,
     pub display_name: Option<String>,
 }
+
+/// Newly issued bootstrap token, returned once to the rotating operator.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/bootstrap-token-rotation-response.ts"
+)]
+pub struct BootstrapTokenRotationResponse {
+    pub token: String,
+    pub expires_at: String,
+}
+
+/// Incoming payload for requesting an account email change.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/email-change-request.ts"
+)]
+pub struct EmailChangeRequest {
+    pub new_email: String,
+    /// Current password; may be omitted after a recent step-up verification.
+    #[serde(default)]
+    #[ts(optional)]
+    pub current_password: Option<String>,
+}
+
+/// Incoming payload for confirming one side of an email change.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/confirm-email-change-request.ts"
+)]
+pub struct ConfirmEmailChangeRequest {
+    pub token: String,
+}
+
+/// Result of confirming one side of an email change.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/confirm-email-change-response.ts"
+)]
+pub struct ConfirmEmailChangeResponse {
+    /// `awaiting_other_address` until both addresses confirm, then `completed`.
+    pub status: String,
+}
+
+/// Email change awaiting confirmation from the current and new addresses.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/pending-email-change-response.ts"
+)]
+pub struct PendingEmailChangeResponse {
+    pub current_email: String,
+    pub new_email: String,
+    pub current_address_confirmed: bool,
+    pub new_address_confirmed: bool,
+    pub requested_at: String,
+    pub expires_at: String,
+}
+
+/// Email change state shown on the profile.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/email-change-status-response.ts"
+)]
+pub struct EmailChangeStatusResponse {
+    pub pending: Option<PendingEmailChangeResponse>,
+}
@@ -2,6 +2,8 @@ mod conversions;
 mod types;
 
 pub use types::{
-    GenericMessageResponse, HealthDependencyStatus, HealthResponse, TenantOptionResponse,
-    UserIdentityResponse,
+    ApiVersionDescriptorResponse, ApiVersionsResponse, CapabilityFlagsResponse,
+    GenericMessageResponse, HealthDependencyStatus, HealthResponse, LivenessResponse,
+    MigrationLevelResponse, ReleaseAdvisoryResponse, TenantOptionResponse, UserIdentityResponse,
+    VersionResponse,
 };
@@ -1,7 +1,24 @@
 use qryvanta_application::TenantSelection;
 use qryvanta_core::UserIdentity;
+use qryvanta_domain::Capability;
 
-use super::types::{TenantOptionResponse, UserIdentityResponse};
+use crate::release_advisory::ReleaseAdvisory;
+
+use super::types::{
+    CapabilityFlagsResponse, ReleaseAdvisoryResponse, TenantOptionResponse, UserIdentityResponse,
+};
+
+impl CapabilityFlagsResponse {
+    #[must_use]
+    pub fn from_capabilities(capabilities: &[Capability]) -> Self {
+        Self {
+            can_design_entities: capabilities.contains(&Capability::DesignEntities),
+            can_manage_security: capabilities.contains(&Capability::ManageSecurity),
+            can_publish: capabilities.contains(&Capability::Publish),
+            can_manage_workflows: capabilities.contains(&Capability::ManageWorkflows),
+        }
+    }
+}
 
 impl TenantOptionResponse {
     #[must_use]
@@ -15,29 +32,47 @@ impl TenantOptionResponse {
             display_name: selection.display_name,
             email: selection.email,
             accessible_surfaces: selection.accessible_surfaces,
+            capabilities: CapabilityFlagsResponse::from_capabilities(&selection.capabilities),
             is_current: selection.tenant_id == current_tenant_id,
             is_default: selection.is_default,
         }
     }
+
+    /// Creates an option for a subject that has not entered any tenant yet.
+    #[must_use]
+    pub fn from_unscoped_selection(selection: TenantSelection) -> Self {
+        let tenant_id = selection.tenant_id;
+        Self {
+            is_current: false,
+            ..Self::from_selection(selection, tenant_id)
+        }
+    }
 }
 
 impl UserIdentityResponse {
-    /// Creates a response from the identity and resolved surfaces.
+    /// Creates a response from the identity and resolved surfaces and capabilities.
     #[must_use]
     pub fn from_identity_with_surfaces(
         identity: UserIdentity,
         available_tenants: Vec<TenantSelection>,
     ) -> Self {
+        let current_selection = available_tenants
+            .iter()
+            .find(|selection| selection.tenant_id == identity.tenant_id());
+
         Self {
             subject: identity.subject().to_owned(),
             display_name: identity.display_name().to_owned(),
             email: identity.email().map(ToOwned::to_owned),
             tenant_id: identity.tenant_id().to_string(),
-            accessible_surfaces: available_tenants
-                .iter()
-                .find(|selection| selection.tenant_id == identity.tenant_id())
+            accessible_surfaces: current_selection
                 .map(|selection| selection.accessible_surfaces.clone())
                 .unwrap_or_default(),
+            capabilities: current_selection
+                .map(|selection| {
+                    CapabilityFlagsResponse::from_capabilities(&selection.capabilities)
+                })
+                .unwrap_or_default(),
             available_tenants: available_tenants
                 .into_iter()
                 .map(|selection| {
@@ -56,7 +91,21 @@ impl From<UserIdentity> for UserIdentityResponse {
             email: identity.email().map(ToOwned::to_owned),
             tenant_id: identity.tenant_id().to_string(),
             accessible_surfaces: Vec::new(),
+            capabilities: CapabilityFlagsResponse::default(),
             available_tenants: Vec::new(),
         }
     }
 }
+
+impl From<ReleaseAdvisory> for ReleaseAdvisoryResponse {
+    fn from(value: ReleaseAdvisory) -> Self {
+        Self {
+            status: value.status.as_str().to_owned(),
+            latest_version: value.latest_version,
+            security_fixes_available: value.security_fixes_available,
+            notes_url: value.notes_url,
+            checked_at: value.checked_at.map(|timestamp| timestamp.to_rfc3339()),
+            detail: value.detail,
+        }
+    }
+}
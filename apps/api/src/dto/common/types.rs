@@ -1,17 +1,21 @@
 use serde::Serialize;
 use ts_rs::TS;
 
-/// Health response payload.
+/// Readiness response payload with one probe result per dependency.
 #[derive(Debug, Serialize, TS)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/health-response.ts"
 )]
 pub struct HealthResponse {
+    #[ts(type = "\"ok\" | \"degraded\"")]
     pub status: &'static str,
     pub ready: bool,
+    pub checked_at: String,
     pub postgres: HealthDependencyStatus,
     pub redis: HealthDependencyStatus,
+    pub smtp: HealthDependencyStatus,
+    pub session_store: HealthDependencyStatus,
 }
 
 /// One runtime dependency health status.
@@ -21,8 +25,96 @@ pub struct HealthResponse {
     export_to = "../../../packages/api-types/src/generated/health-dependency-status.ts"
 )]
 pub struct HealthDependencyStatus {
+    #[ts(type = "\"ok\" | \"error\" | \"disabled\"")]
     pub status: &'static str,
     pub detail: Option<String>,
+    #[ts(type = "number | null")]
+    pub latency_ms: Option<u64>,
+}
+
+/// Liveness response payload; never probes dependencies.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/liveness-response.ts"
+)]
+pub struct LivenessResponse {
+    #[ts(type = "\"ok\"")]
+    pub status: &'static str,
+    pub version: String,
+    #[ts(type = "number")]
+    pub uptime_seconds: u64,
+}
+
+/// Build, schema and release currency of one API instance.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/version-response.ts"
+)]
+pub struct VersionResponse {
+    pub version: String,
+    pub commit: Option<String>,
+    pub migrations: MigrationLevelResponse,
+    pub advisory: ReleaseAdvisoryResponse,
+}
+
+/// Served API versions and their deprecation schedules.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/api-versions-response.ts"
+)]
+pub struct ApiVersionsResponse {
+    pub current_version: String,
+    pub default_version: String,
+    pub versions: Vec<ApiVersionDescriptorResponse>,
+}
+
+/// One served API version.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/api-version-descriptor-response.ts"
+)]
+pub struct ApiVersionDescriptorResponse {
+    pub version: String,
+    pub status: String,
+    pub path_prefix: String,
+    pub deprecated_at: Option<String>,
+    pub sunset_at: Option<String>,
+    pub successor_version: Option<String>,
+}
+
+/// Applied versus embedded database migration level.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/migration-level-response.ts"
+)]
+pub struct MigrationLevelResponse {
+    #[ts(type = "number | null")]
+    pub applied_version: Option<i64>,
+    pub applied_description: Option<String>,
+    #[ts(type = "number")]
+    pub expected_version: i64,
+    pub pending_count: usize,
+    pub up_to_date: bool,
+}
+
+/// Upstream release advisory for the running build.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/release-advisory-response.ts"
+)]
+pub struct ReleaseAdvisoryResponse {
+    pub status: String,
+    pub latest_version: Option<String>,
+    pub security_fixes_available: bool,
+    pub notes_url: Option<String>,
+    pub checked_at: Option<String>,
+    pub detail: Option<String>,
 }
 
 /// Generic message response for auth flows.
@@ -47,9 +139,23 @@ pub struct UserIdentityResponse {
     pub email: Option<String>,
     pub tenant_id: String,
     pub accessible_surfaces: Vec<String>,
+    pub capabilities: CapabilityFlagsResponse,
     pub available_tenants: Vec<TenantOptionResponse>,
 }
 
+/// UI capability flags derived from the subject's permissions in a tenant.
+#[derive(Debug, Default, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/capability-flags-response.ts"
+)]
+pub struct CapabilityFlagsResponse {
+    pub can_design_entities: bool,
+    pub can_manage_security: bool,
+    pub can_publish: bool,
+    pub can_manage_workflows: bool,
+}
+
 /// One tenant available to the authenticated user.
 #[derive(Debug, Serialize, TS)]
 #[ts(
@@ -62,6 +168,7 @@ pub struct TenantOptionResponse {
     pub display_name: String,
     pub email: Option<String>,
     pub accessible_surfaces: Vec<String>,
+    pub capabilities: CapabilityFlagsResponse,
     pub is_current: bool,
     pub is_default: bool,
 }
@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Incoming payload for capturing a contact consent change.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/record-contact-consent-request.ts"
+)]
+pub struct RecordContactConsentRequest {
+    pub purpose: String,
+    pub channel: String,
+    pub status: String,
+    pub source: String,
+}
+
+/// API representation of the current consent state for one purpose and channel.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/contact-consent-response.ts"
+)]
+pub struct ContactConsentResponse {
+    pub contact_record_id: String,
+    pub purpose: String,
+    pub channel: String,
+    pub status: String,
+    pub source: String,
+    pub granted_at: Option<String>,
+    pub revoked_at: Option<String>,
+    pub recorded_by_subject: String,
+    pub updated_at: String,
+}
+
+/// API representation of one contact consent history entry.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/contact-consent-change-response.ts"
+)]
+pub struct ContactConsentChangeResponse {
+    pub change_id: String,
+    pub contact_record_id: String,
+    pub purpose: String,
+    pub channel: String,
+    pub previous_status: Option<String>,
+    pub status: String,
+    pub source: String,
+    pub recorded_by_subject: String,
+    pub recorded_at: String,
+}
+
+impl From<qryvanta_application::ContactConsent> for ContactConsentResponse {
+    fn from(value: qryvanta_application::ContactConsent) -> Self {
+        Self {
+            contact_record_id: value.contact_record_id,
+            purpose: value.purpose,
+            channel: value.channel.as_str().to_owned(),
+            status: value.status.as_str().to_owned(),
+            source: value.source,
+            granted_at: value.granted_at.map(|timestamp| timestamp.to_rfc3339()),
+            revoked_at: value.revoked_at.map(|timestamp| timestamp.to_rfc3339()),
+            recorded_by_subject: value.recorded_by_subject,
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<qryvanta_application::ContactConsentChange> for ContactConsentChangeResponse {
+    fn from(value: qryvanta_application::ContactConsentChange) -> Self {
+        Self {
+            change_id: value.change_id,
+            contact_record_id: value.contact_record_id,
+            purpose: value.purpose,
+            channel: value.channel.as_str().to_owned(),
+            previous_status: value
+                .previous_status
+                .map(|status| status.as_str().to_owned()),
+            status: value.status.as_str().to_owned(),
+            source: value.source,
+            recorded_by_subject: value.recorded_by_subject,
+            recorded_at: value.recorded_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Incoming payload for registering an entity as a contact identity source.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-contact-identity-source-request.ts"
+)]
+pub struct SaveContactIdentitySourceRequest {
+    pub email_field_logical_name: Option<String>,
+    pub phone_field_logical_name: Option<String>,
+    #[serde(default)]
+    pub name_field_logical_names: Vec<String>,
+}
+
+/// API representation of an entity taking part in contact matching.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/contact-identity-source-response.ts"
+)]
+pub struct ContactIdentitySourceResponse {
+    pub entity_logical_name: String,
+    pub email_field_logical_name: Option<String>,
+    pub phone_field_logical_name: Option<String>,
+    pub name_field_logical_names: Vec<String>,
+    pub updated_by_subject: String,
+    pub updated_at: String,
+}
+
+/// API representation of a record's link to its master contact.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/contact-identity-link-response.ts"
+)]
+pub struct ContactIdentityLinkResponse {
+    pub master_contact_id: String,
+    pub entity_logical_name: String,
+    pub record_id: String,
+    pub email_key: Option<String>,
+    pub phone_key: Option<String>,
+    pub name_key: Option<String>,
+    pub match_reason: String,
+    pub confidence: u8,
+    pub linked_by_subject: String,
+    pub linked_at: String,
+}
+
+/// API representation of all records resolved to one master contact.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/master-contact-response.ts"
+)]
+pub struct MasterContactResponse {
+    pub master_contact_id: String,
+    pub links: Vec<ContactIdentityLinkResponse>,
+}
+
+/// API representation of a linked record matching a contact lookup.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/contact-identity-match-response.ts"
+)]
+pub struct ContactIdentityMatchResponse {
+    pub link: ContactIdentityLinkResponse,
+    pub match_reason: String,
+    pub confidence: u8,
+}
+
+/// Incoming payload for manually linking a record to a master contact.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/link-contact-identity-request.ts"
+)]
+pub struct LinkContactIdentityRequest {
+    pub master_contact_id: String,
+}
+
+/// API representation of a contact identity source rebuild.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/contact-identity-rebuild-response.ts"
+)]
+pub struct ContactIdentityRebuildResponse {
+    pub entity_logical_name: String,
+    pub scanned_records: usize,
+    pub matched_records: usize,
+}
+
+impl From<qryvanta_application::ContactIdentitySource> for ContactIdentitySourceResponse {
+    fn from(value: qryvanta_application::ContactIdentitySource) -> Self {
+        Self {
+            entity_logical_name: value.entity_logical_name,
+            email_field_logical_name: value.email_field_logical_name,
+            phone_field_logical_name: value.phone_field_logical_name,
+            name_field_logical_names: value.name_field_logical_names,
+            updated_by_subject: value.updated_by_subject,
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<qryvanta_application::ContactIdentityLink> for ContactIdentityLinkResponse {
+    fn from(value: qryvanta_application::ContactIdentityLink) -> Self {
+        Self {
+            master_contact_id: value.master_contact_id,
+            entity_logical_name: value.entity_logical_name,
+            record_id: value.record_id,
+            email_key: value.keys.email,
+            phone_key: value.keys.phone,
+            name_key: value.keys.name,
+            match_reason: value.match_reason.as_str().to_owned(),
+            confidence: value.confidence,
+            linked_by_subject: value.linked_by_subject,
+            linked_at: value.linked_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<qryvanta_application::MasterContact> for MasterContactResponse {
+    fn from(value: qryvanta_application::MasterContact) -> Self {
+        Self {
+            master_contact_id: value.master_contact_id,
+            links: value
+                .links
+                .into_iter()
+                .map(ContactIdentityLinkResponse::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<qryvanta_application::ContactIdentityMatch> for ContactIdentityMatchResponse {
+    fn from(value: qryvanta_application::ContactIdentityMatch) -> Self {
+        Self {
+            link: ContactIdentityLinkResponse::from(value.link),
+            match_reason: value.match_reason.as_str().to_owned(),
+            confidence: value.confidence,
+        }
+    }
+}
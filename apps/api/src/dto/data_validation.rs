@@ -0,0 +1,82 @@
+use qryvanta_application::{DataValidationSchedule, DataValidationViolation};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Incoming payload for queueing a data validation audit.
+#[derive(Debug, Default, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/queue-data-validation-audit-request.ts"
+)]
+pub struct QueueDataValidationAuditRequest {
+    /// Entities to audit; every published entity when omitted.
+    #[serde(default)]
+    pub entity_logical_names: Option<Vec<String>>,
+}
+
+/// Stored record that failed a data validation audit check.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/data-validation-violation-response.ts"
+)]
+pub struct DataValidationViolationResponse {
+    pub entity_logical_name: String,
+    pub record_id: String,
+    #[ts(
+        type = "\"unknown_field\" | \"invalid_value\" | \"missing_required_field\" | \"business_rule\""
+    )]
+    pub rule: String,
+    pub field_logical_name: Option<String>,
+    pub business_rule_logical_name: Option<String>,
+    pub message: String,
+}
+
+/// Incoming payload for scheduling recurring data validation audits.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-data-validation-schedule-request.ts"
+)]
+pub struct SaveDataValidationScheduleRequest {
+    pub interval_days: u16,
+}
+
+/// Recurring data validation audit of the tenant.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/data-validation-schedule-response.ts"
+)]
+pub struct DataValidationScheduleResponse {
+    pub interval_days: u16,
+    pub configured_by_subject: String,
+    pub next_run_at: String,
+    pub last_run_at: Option<String>,
+    pub updated_at: String,
+}
+
+impl From<DataValidationViolation> for DataValidationViolationResponse {
+    fn from(value: DataValidationViolation) -> Self {
+        Self {
+            entity_logical_name: value.entity_logical_name,
+            record_id: value.violation.record_id,
+            rule: value.violation.rule.as_str().to_owned(),
+            field_logical_name: value.violation.field_logical_name,
+            business_rule_logical_name: value.violation.business_rule_logical_name,
+            message: value.violation.message,
+        }
+    }
+}
+
+impl From<DataValidationSchedule> for DataValidationScheduleResponse {
+    fn from(value: DataValidationSchedule) -> Self {
+        Self {
+            interval_days: value.interval_days,
+            configured_by_subject: value.configured_by_subject,
+            next_run_at: value.next_run_at.to_rfc3339(),
+            last_run_at: value.last_run_at.map(|timestamp| timestamp.to_rfc3339()),
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
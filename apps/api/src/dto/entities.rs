@@ -3,10 +3,21 @@ mod types;
 
 pub use types::{
     BusinessRuleResponse, CreateBusinessRuleRequest, CreateEntityRequest, CreateFieldRequest,
-    CreateFormRequest, CreateOptionSetRequest, CreateViewRequest, EntityResponse, FieldResponse,
-    FormResponse, OptionSetResponse, PublishChecksResponse, PublishedSchemaResponse,
-    UpdateEntityRequest, UpdateFieldRequest, ViewResponse,
+    CreateFormRequest, CreateOptionSetRequest, CreateViewRequest, DuplicateRuleResponse,
+    EntityDependencyReportResponse, EntityHistoryPolicyResponse, EntityIconCatalogResponse,
+    EntityResponse, EntitySchemaRollbackChecksResponse, EntitySlugConfigResponse,
+    EntityStatusModelResponse, FieldImpactReportResponse, FieldResponse, FormResponse,
+    OptionSetResponse, PublishChecksResponse, PublishedSchemaResponse,
+    PublishedSchemaVersionResponse, PublishedSchemaVersionSummaryResponse,
+    RelationBehaviorResponse, RelationLookupConfigResponse, SaveDuplicateRuleRequest,
+    SaveEntityHistoryPolicyRequest, SaveEntitySlugConfigRequest, SaveEntityStatusModelRequest,
+    SaveRelationBehaviorRequest, SaveRelationLookupConfigRequest, UpdateEntityRequest,
+    UpdateFieldRequest, ViewResponse, WorkspaceEntitySchemaResponse,
 };
 
 #[cfg(test)]
-pub use types::OptionSetItemDto;
+pub use types::{
+    DuplicateMatchFieldDto, EntityDependencyResponse, FieldImpactResponse, FormScriptEventsDto,
+    OptionSetItemDto, RecordStatusOptionDto, RecordStatusTransitionDto,
+    WorkspaceFormScriptEventsResponse,
+};
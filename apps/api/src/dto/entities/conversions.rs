@@ -1,13 +1,170 @@
+use qryvanta_core::AppError;
 use qryvanta_domain::{
-    BusinessRuleDefinition, EntityDefinition, EntityFieldDefinition, FormDefinition,
-    OptionSetDefinition, OptionSetItem, PublishedEntitySchema, ViewDefinition,
+    BusinessRuleDefinition, DuplicateDetectionRule, DuplicateMatchField, EntityDefinition,
+    EntityFieldDefinition, FormDefinition, FormScriptEvents, OptionSetDefinition, OptionSetItem,
+    PublishedEntitySchema, RecordStatusOption, RecordStatusTransition, ViewDefinition,
 };
 
 use super::types::{
-    BusinessRuleResponse, EntityResponse, FieldResponse, FormResponse, OptionSetItemDto,
-    OptionSetResponse, PublishedSchemaResponse, ViewResponse,
+    BusinessRuleResponse, DuplicateMatchFieldDto, DuplicateRuleResponse,
+    EntityDependencyReportResponse, EntityDependencyResponse, EntityHistoryPolicyResponse,
+    EntityResponse, EntitySchemaRollbackChecksResponse, EntitySlugConfigResponse,
+    EntityStatusModelResponse, FieldImpactReportResponse, FieldImpactResponse, FieldResponse,
+    FormResponse, FormScriptEventsDto, OptionSetItemDto, OptionSetResponse,
+    PublishedSchemaResponse, PublishedSchemaVersionResponse, PublishedSchemaVersionSummaryResponse,
+    RecordStatusOptionDto, RecordStatusTransitionDto, RelationBehaviorResponse,
+    RelationLookupConfigResponse, ViewResponse, WorkspaceEntitySchemaResponse,
+    WorkspaceFormScriptEventsResponse,
 };
 
+impl From<qryvanta_application::RelationBehavior> for RelationBehaviorResponse {
+    fn from(behavior: qryvanta_application::RelationBehavior) -> Self {
+        Self {
+            entity_logical_name: behavior.entity_logical_name,
+            field_logical_name: behavior.field_logical_name,
+            target_entity_logical_name: behavior.target_entity_logical_name,
+            assign_behavior: behavior.assign_behavior.as_str().to_owned(),
+            share_behavior: behavior.share_behavior.as_str().to_owned(),
+            delete_behavior: behavior.delete_behavior.as_str().to_owned(),
+        }
+    }
+}
+
+impl From<qryvanta_application::RelationLookupConfig> for RelationLookupConfigResponse {
+    fn from(config: qryvanta_application::RelationLookupConfig) -> Self {
+        Self {
+            entity_logical_name: config.entity_logical_name,
+            field_logical_name: config.field_logical_name,
+            target_entity_logical_name: config.target_entity_logical_name,
+            search_field_logical_names: config.search_field_logical_names,
+            filter: config
+                .filter
+                .and_then(|group| serde_json::to_value(group).ok()),
+        }
+    }
+}
+
+impl From<qryvanta_application::EntitySlugConfig> for EntitySlugConfigResponse {
+    fn from(config: qryvanta_application::EntitySlugConfig) -> Self {
+        Self {
+            entity_logical_name: config.entity_logical_name,
+            slug_field_logical_name: config.slug_field_logical_name,
+            source_field_logical_name: config.source_field_logical_name,
+        }
+    }
+}
+
+impl From<qryvanta_application::EntityHistoryPolicy> for EntityHistoryPolicyResponse {
+    fn from(policy: qryvanta_application::EntityHistoryPolicy) -> Self {
+        Self {
+            entity_logical_name: policy.entity_logical_name,
+            retention_days: policy.retention_days,
+        }
+    }
+}
+
+impl From<qryvanta_application::EntityStatusConfig> for EntityStatusModelResponse {
+    fn from(config: qryvanta_application::EntityStatusConfig) -> Self {
+        let model = config.model;
+        Self {
+            entity_logical_name: config.entity_logical_name,
+            status_field_logical_name: model.status_field_logical_name().to_owned(),
+            reason_field_logical_name: model.reason_field_logical_name().map(ToOwned::to_owned),
+            initial_status: model.initial_status().to_owned(),
+            statuses: model
+                .statuses()
+                .iter()
+                .cloned()
+                .map(RecordStatusOptionDto::from)
+                .collect(),
+            transitions: model
+                .transitions()
+                .iter()
+                .cloned()
+                .map(RecordStatusTransitionDto::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<RecordStatusOption> for RecordStatusOptionDto {
+    fn from(option: RecordStatusOption) -> Self {
+        Self {
+            value: option.value,
+            label: option.label,
+            reasons: option.reasons,
+            inactive: option.inactive,
+        }
+    }
+}
+
+impl From<RecordStatusOptionDto> for RecordStatusOption {
+    fn from(option: RecordStatusOptionDto) -> Self {
+        Self {
+            value: option.value,
+            label: option.label,
+            reasons: option.reasons,
+            inactive: option.inactive,
+        }
+    }
+}
+
+impl From<RecordStatusTransition> for RecordStatusTransitionDto {
+    fn from(transition: RecordStatusTransition) -> Self {
+        Self {
+            from: transition.from,
+            to: transition.to,
+        }
+    }
+}
+
+impl From<RecordStatusTransitionDto> for RecordStatusTransition {
+    fn from(transition: RecordStatusTransitionDto) -> Self {
+        Self {
+            from: transition.from,
+            to: transition.to,
+        }
+    }
+}
+
+impl From<DuplicateMatchField> for DuplicateMatchFieldDto {
+    fn from(match_field: DuplicateMatchField) -> Self {
+        Self {
+            field_logical_name: match_field.field_logical_name,
+            match_mode: match_field.match_mode.as_str().to_owned(),
+        }
+    }
+}
+
+impl TryFrom<DuplicateMatchFieldDto> for DuplicateMatchField {
+    type Error = AppError;
+
+    fn try_from(match_field: DuplicateMatchFieldDto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            field_logical_name: match_field.field_logical_name,
+            match_mode: match_field.match_mode.parse()?,
+        })
+    }
+}
+
+impl From<DuplicateDetectionRule> for DuplicateRuleResponse {
+    fn from(rule: DuplicateDetectionRule) -> Self {
+        Self {
+            entity_logical_name: rule.entity_logical_name().to_owned(),
+            logical_name: rule.logical_name().to_owned(),
+            display_name: rule.display_name().to_owned(),
+            match_fields: rule
+                .match_fields()
+                .iter()
+                .cloned()
+                .map(DuplicateMatchFieldDto::from)
+                .collect(),
+            action: rule.action().as_str().to_owned(),
+            is_active: rule.is_active(),
+        }
+    }
+}
+
 impl From<EntityDefinition> for EntityResponse {
     fn from(entity: EntityDefinition) -> Self {
         Self {
@@ -18,10 +175,102 @@ impl From<EntityDefinition> for EntityResponse {
                 .plural_display_name()
                 .map(|value| value.as_str().to_owned()),
             icon: entity.icon().map(str::to_owned),
+            color: entity.color().map(str::to_owned),
+            is_active: entity.is_active(),
+        }
+    }
+}
+
+impl From<qryvanta_application::EntityDependencyReport> for EntityDependencyReportResponse {
+    fn from(report: qryvanta_application::EntityDependencyReport) -> Self {
+        Self {
+            can_delete: report.can_delete(),
+            entity_logical_name: report.entity_logical_name,
+            is_active: report.is_active,
+            dependencies: report
+                .dependencies
+                .into_iter()
+                .map(|dependency| EntityDependencyResponse {
+                    kind: dependency.kind.as_str().to_owned(),
+                    component_key: dependency.component_key,
+                    detail: dependency.detail,
+                    blocks_delete: dependency.blocks_delete,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<qryvanta_application::FieldImpactReport> for FieldImpactReportResponse {
+    fn from(report: qryvanta_application::FieldImpactReport) -> Self {
+        Self {
+            highest_severity: report
+                .highest_severity()
+                .map(|severity| severity.as_str().to_owned()),
+            entity_logical_name: report.entity_logical_name,
+            field_logical_name: report.field_logical_name,
+            impacts: report
+                .impacts
+                .into_iter()
+                .map(|impact| FieldImpactResponse {
+                    severity: impact.severity.as_str().to_owned(),
+                    kind: impact.kind.as_str().to_owned(),
+                    component_key: impact.component_key,
+                    detail: impact.detail,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<qryvanta_application::EntitySchemaRollbackChecks> for EntitySchemaRollbackChecksResponse {
+    fn from(checks: qryvanta_application::EntitySchemaRollbackChecks) -> Self {
+        Self {
+            can_rollback: checks.can_rollback(),
+            entity_logical_name: checks.entity_logical_name,
+            current_version: checks.current_version,
+            target_version: checks.target_version,
+            orphaned_fields: checks.orphaned_fields,
+            errors: checks.errors,
         }
     }
 }
 
+impl TryFrom<qryvanta_application::PublishedEntitySchemaVersion>
+    for PublishedSchemaVersionSummaryResponse
+{
+    type Error = AppError;
+
+    fn try_from(
+        value: qryvanta_application::PublishedEntitySchemaVersion,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            checksum_sha256: value.checksum_sha256()?,
+            version: value.schema.version(),
+            published_by_subject: value.published_by_subject,
+            published_at: value.published_at.to_rfc3339(),
+            field_count: value.schema.fields().len(),
+        })
+    }
+}
+
+impl TryFrom<qryvanta_application::PublishedEntitySchemaVersion>
+    for PublishedSchemaVersionResponse
+{
+    type Error = AppError;
+
+    fn try_from(
+        value: qryvanta_application::PublishedEntitySchemaVersion,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            checksum_sha256: value.checksum_sha256()?,
+            published_by_subject: value.published_by_subject,
+            published_at: value.published_at.to_rfc3339(),
+            schema: PublishedSchemaResponse::from(value.schema),
+        })
+    }
+}
+
 impl From<EntityFieldDefinition> for FieldResponse {
     fn from(value: EntityFieldDefinition) -> Self {
         Self {
@@ -43,6 +292,9 @@ impl From<EntityFieldDefinition> for FieldResponse {
             max_length: value.max_length(),
             min_value: value.min_value(),
             max_value: value.max_value(),
+            classification: value
+                .classification()
+                .map(|classification| classification.as_str().to_owned()),
         }
     }
 }
@@ -87,6 +339,8 @@ impl From<PublishedEntitySchema> for PublishedSchemaResponse {
         Self {
             entity_logical_name: value.entity().logical_name().as_str().to_owned(),
             entity_display_name: value.entity().display_name().as_str().to_owned(),
+            entity_icon: value.entity().icon().map(str::to_owned),
+            entity_color: value.entity().color().map(str::to_owned),
             version: value.version(),
             fields: value
                 .fields()
@@ -94,6 +348,16 @@ impl From<PublishedEntitySchema> for PublishedSchemaResponse {
                 .cloned()
                 .map(FieldResponse::from)
                 .collect(),
+            system_fields: value
+                .system_fields()
+                .into_iter()
+                .filter_map(|system_field| {
+                    system_field
+                        .field_definition(value.entity().logical_name().as_str())
+                        .ok()
+                })
+                .map(FieldResponse::from)
+                .collect(),
             option_sets: value
                 .option_sets()
                 .iter()
@@ -118,6 +382,47 @@ impl From<FormDefinition> for FormResponse {
                 .collect::<Result<Vec<_>, _>>()
                 .unwrap_or_default(),
             header_fields: value.header_fields().to_vec(),
+            script_events: FormScriptEventsDto::from(value.script_events()),
+        }
+    }
+}
+
+impl From<&FormScriptEvents> for FormScriptEventsDto {
+    fn from(value: &FormScriptEvents) -> Self {
+        Self {
+            on_load_actions: value.on_load_actions().to_vec(),
+            on_change_fields: value.on_change_fields().to_vec(),
+            on_save_validators: value.on_save_validators().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<FormScriptEventsDto> for FormScriptEvents {
+    type Error = AppError;
+
+    fn try_from(value: FormScriptEventsDto) -> Result<Self, Self::Error> {
+        FormScriptEvents::new(
+            value.on_load_actions,
+            value.on_change_fields,
+            value.on_save_validators,
+        )
+    }
+}
+
+impl WorkspaceEntitySchemaResponse {
+    /// Builds a worker-facing schema payload from the published schema and its forms.
+    pub fn from_schema_and_forms(schema: PublishedEntitySchema, forms: &[FormDefinition]) -> Self {
+        Self {
+            schema: PublishedSchemaResponse::from(schema),
+            form_script_events: forms
+                .iter()
+                .filter(|form| !form.script_events().is_empty())
+                .map(|form| WorkspaceFormScriptEventsResponse {
+                    form_logical_name: form.logical_name().as_str().to_owned(),
+                    form_type: form.form_type().as_str().to_owned(),
+                    script_events: FormScriptEventsDto::from(form.script_events()),
+                })
+                .collect(),
         }
     }
 }
@@ -169,6 +474,7 @@ impl From<BusinessRuleDefinition> for BusinessRuleResponse {
                 .collect::<Result<Vec<_>, _>>()
                 .unwrap_or_default(),
             is_active: value.is_active(),
+            localized_label: None,
         }
     }
 }
@@ -1,9 +1,12 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::dto::LocalizedLabelResponse;
 
 /// Incoming payload for entity creation.
-#[derive(Debug, Deserialize, TS)]
+#[derive(Debug, Deserialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/create-entity-request.ts"
@@ -14,10 +17,11 @@ pub struct CreateEntityRequest {
     pub description: Option<String>,
     pub plural_display_name: Option<String>,
     pub icon: Option<String>,
+    pub color: Option<String>,
 }
 
 /// API representation of an entity.
-#[derive(Debug, Serialize, TS)]
+#[derive(Debug, Serialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/entity-response.ts"
@@ -28,10 +32,89 @@ pub struct EntityResponse {
     pub description: Option<String>,
     pub plural_display_name: Option<String>,
     pub icon: Option<String>,
+    pub color: Option<String>,
+    pub is_active: bool,
+}
+
+/// One component referencing an entity.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/entity-dependency-response.ts"
+)]
+pub struct EntityDependencyResponse {
+    pub kind: String,
+    pub component_key: String,
+    pub detail: String,
+    pub blocks_delete: bool,
+}
+
+/// Dependency report gating entity deletion.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/entity-dependency-report-response.ts"
+)]
+pub struct EntityDependencyReportResponse {
+    pub entity_logical_name: String,
+    pub is_active: bool,
+    pub can_delete: bool,
+    pub dependencies: Vec<EntityDependencyResponse>,
+}
+
+/// One component referencing a field.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/field-impact-response.ts"
+)]
+pub struct FieldImpactResponse {
+    pub severity: String,
+    pub kind: String,
+    pub component_key: String,
+    pub detail: String,
+}
+
+/// Impact analysis reviewed before updating or deleting a field.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/field-impact-report-response.ts"
+)]
+pub struct FieldImpactReportResponse {
+    pub entity_logical_name: String,
+    pub field_logical_name: String,
+    pub highest_severity: Option<String>,
+    pub impacts: Vec<FieldImpactResponse>,
+}
+
+/// Compatibility report for republishing an earlier schema version.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/entity-schema-rollback-checks-response.ts"
+)]
+pub struct EntitySchemaRollbackChecksResponse {
+    pub entity_logical_name: String,
+    pub current_version: i32,
+    pub target_version: i32,
+    pub can_rollback: bool,
+    pub orphaned_fields: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+/// Icon keys accepted for entity metadata.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/entity-icon-catalog-response.ts"
+)]
+pub struct EntityIconCatalogResponse {
+    pub icons: Vec<String>,
 }
 
 /// Incoming payload for entity update.
-#[derive(Debug, Deserialize, TS)]
+#[derive(Debug, Deserialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/update-entity-request.ts"
@@ -41,10 +124,11 @@ pub struct UpdateEntityRequest {
     pub description: Option<String>,
     pub plural_display_name: Option<String>,
     pub icon: Option<String>,
+    pub color: Option<String>,
 }
 
 /// Incoming payload for metadata field create/update.
-#[derive(Debug, Deserialize, TS)]
+#[derive(Debug, Deserialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/create-field-request.ts"
@@ -60,10 +144,15 @@ pub struct CreateFieldRequest {
     pub calculation_expression: Option<String>,
     pub relation_target_entity: Option<String>,
     pub option_set_logical_name: Option<String>,
+    /// Data classification (`pii`, `phi` or `confidential`) driving
+    /// role-based masking.
+    #[serde(default)]
+    #[ts(optional)]
+    pub classification: Option<String>,
 }
 
 /// Incoming payload for metadata field updates.
-#[derive(Debug, Deserialize, TS)]
+#[derive(Debug, Deserialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/update-field-request.ts"
@@ -77,10 +166,217 @@ pub struct UpdateFieldRequest {
     pub max_length: Option<i32>,
     pub min_value: Option<f64>,
     pub max_value: Option<f64>,
+    /// Data classification (`pii`, `phi` or `confidential`) driving
+    /// role-based masking.
+    #[serde(default)]
+    #[ts(optional)]
+    pub classification: Option<String>,
 }
 
-/// API representation of a metadata field definition.
+/// Incoming payload for relation cascade behaviors.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-relation-behavior-request.ts"
+)]
+pub struct SaveRelationBehaviorRequest {
+    pub assign_behavior: String,
+    pub share_behavior: String,
+    /// Behavior applied to child records when the parent is deleted.
+    /// Defaults to `restrict`.
+    #[serde(default)]
+    #[ts(optional)]
+    pub delete_behavior: Option<String>,
+}
+
+/// API representation of relation cascade behaviors for one relation field.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/relation-behavior-response.ts"
+)]
+pub struct RelationBehaviorResponse {
+    pub entity_logical_name: String,
+    pub field_logical_name: String,
+    pub target_entity_logical_name: String,
+    pub assign_behavior: String,
+    pub share_behavior: String,
+    pub delete_behavior: String,
+}
+
+/// Incoming payload for relation lookup search configuration.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-relation-lookup-config-request.ts"
+)]
+pub struct SaveRelationLookupConfigRequest {
+    pub search_field_logical_names: Vec<String>,
+    #[ts(type = "unknown | null")]
+    pub filter: Option<Value>,
+}
+
+/// API representation of the lookup search configuration of a relation field.
 #[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/relation-lookup-config-response.ts"
+)]
+pub struct RelationLookupConfigResponse {
+    pub entity_logical_name: String,
+    pub field_logical_name: String,
+    pub target_entity_logical_name: String,
+    pub search_field_logical_names: Vec<String>,
+    #[ts(type = "unknown | null")]
+    pub filter: Option<Value>,
+}
+
+/// Incoming payload for an entity slug configuration.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-entity-slug-config-request.ts"
+)]
+pub struct SaveEntitySlugConfigRequest {
+    pub slug_field_logical_name: String,
+    pub source_field_logical_name: String,
+}
+
+/// API representation of an entity slug configuration.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/entity-slug-config-response.ts"
+)]
+pub struct EntitySlugConfigResponse {
+    pub entity_logical_name: String,
+    pub slug_field_logical_name: String,
+    pub source_field_logical_name: String,
+}
+
+/// Incoming payload for an entity record history retention policy.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-entity-history-policy-request.ts"
+)]
+pub struct SaveEntityHistoryPolicyRequest {
+    pub retention_days: u16,
+}
+
+/// API representation of an entity record history retention policy.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/entity-history-policy-response.ts"
+)]
+pub struct EntityHistoryPolicyResponse {
+    pub entity_logical_name: String,
+    pub retention_days: u16,
+}
+
+/// API transport representation of one status in a status model.
+#[derive(Debug, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/record-status-option-dto.ts"
+)]
+pub struct RecordStatusOptionDto {
+    pub value: String,
+    pub label: String,
+    #[serde(default)]
+    pub reasons: Vec<String>,
+    #[serde(default)]
+    pub inactive: bool,
+}
+
+/// API transport representation of one allowed status transition.
+#[derive(Debug, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/record-status-transition-dto.ts"
+)]
+pub struct RecordStatusTransitionDto {
+    pub from: String,
+    pub to: String,
+}
+
+/// Incoming payload for an entity status model.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-entity-status-model-request.ts"
+)]
+pub struct SaveEntityStatusModelRequest {
+    pub status_field_logical_name: String,
+    pub reason_field_logical_name: Option<String>,
+    pub initial_status: String,
+    pub statuses: Vec<RecordStatusOptionDto>,
+    #[serde(default)]
+    pub transitions: Vec<RecordStatusTransitionDto>,
+}
+
+/// API representation of an entity status model.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/entity-status-model-response.ts"
+)]
+pub struct EntityStatusModelResponse {
+    pub entity_logical_name: String,
+    pub status_field_logical_name: String,
+    pub reason_field_logical_name: Option<String>,
+    pub initial_status: String,
+    pub statuses: Vec<RecordStatusOptionDto>,
+    pub transitions: Vec<RecordStatusTransitionDto>,
+}
+
+/// API transport representation of one field compared by a duplicate rule.
+#[derive(Debug, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/duplicate-match-field-dto.ts"
+)]
+pub struct DuplicateMatchFieldDto {
+    pub field_logical_name: String,
+    pub match_mode: String,
+}
+
+/// Incoming payload for an entity duplicate-detection rule.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-duplicate-rule-request.ts"
+)]
+pub struct SaveDuplicateRuleRequest {
+    pub display_name: String,
+    pub match_fields: Vec<DuplicateMatchFieldDto>,
+    pub action: String,
+    #[serde(default = "default_true")]
+    pub is_active: bool,
+}
+
+const fn default_true() -> bool {
+    true
+}
+
+/// API representation of an entity duplicate-detection rule.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/duplicate-rule-response.ts"
+)]
+pub struct DuplicateRuleResponse {
+    pub entity_logical_name: String,
+    pub logical_name: String,
+    pub display_name: String,
+    pub match_fields: Vec<DuplicateMatchFieldDto>,
+    pub action: String,
+    pub is_active: bool,
+}
+
+/// API representation of a metadata field definition.
+#[derive(Debug, Serialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/field-response.ts"
@@ -101,6 +397,7 @@ pub struct FieldResponse {
     pub max_length: Option<i32>,
     pub min_value: Option<f64>,
     pub max_value: Option<f64>,
+    pub classification: Option<String>,
 }
 
 /// Incoming payload for option set create/update.
@@ -154,6 +451,23 @@ pub struct CreateFormRequest {
     #[ts(type = "unknown[]")]
     pub tabs: Vec<Value>,
     pub header_fields: Vec<String>,
+    #[serde(default)]
+    pub script_events: FormScriptEventsDto,
+}
+
+/// Client scripting hooks declared for a form.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/form-script-events-dto.ts"
+)]
+pub struct FormScriptEventsDto {
+    #[serde(default)]
+    pub on_load_actions: Vec<String>,
+    #[serde(default)]
+    pub on_change_fields: Vec<String>,
+    #[serde(default)]
+    pub on_save_validators: Vec<String>,
 }
 
 /// API response for standalone forms.
@@ -170,6 +484,7 @@ pub struct FormResponse {
     #[ts(type = "unknown[]")]
     pub tabs: Vec<Value>,
     pub header_fields: Vec<String>,
+    pub script_events: FormScriptEventsDto,
 }
 
 /// Incoming payload for standalone view create/update.
@@ -246,6 +561,8 @@ pub struct BusinessRuleResponse {
     #[ts(type = "unknown[]")]
     pub actions: Vec<Value>,
     pub is_active: bool,
+    /// Label matching the caller's `Accept-Language` preferences, when one exists.
+    pub localized_label: Option<LocalizedLabelResponse>,
 }
 
 /// API representation of a published schema snapshot.
@@ -257,11 +574,66 @@ pub struct BusinessRuleResponse {
 pub struct PublishedSchemaResponse {
     pub entity_logical_name: String,
     pub entity_display_name: String,
+    pub entity_icon: Option<String>,
+    pub entity_color: Option<String>,
     pub version: i32,
     pub fields: Vec<FieldResponse>,
+    /// Read-only platform fields (`created_by`, `created_at`, `modified_by`, `modified_at`, `owner`).
+    pub system_fields: Vec<FieldResponse>,
     pub option_sets: Vec<OptionSetResponse>,
 }
 
+/// Worker-facing published schema with the form scripting hooks clients must run.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workspace-entity-schema-response.ts"
+)]
+pub struct WorkspaceEntitySchemaResponse {
+    #[serde(flatten)]
+    pub schema: PublishedSchemaResponse,
+    pub form_script_events: Vec<WorkspaceFormScriptEventsResponse>,
+}
+
+/// Scripting hooks declared by one published form.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workspace-form-script-events-response.ts"
+)]
+pub struct WorkspaceFormScriptEventsResponse {
+    pub form_logical_name: String,
+    pub form_type: String,
+    pub script_events: FormScriptEventsDto,
+}
+
+/// Summary of one stored published schema version.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/published-schema-version-summary-response.ts"
+)]
+pub struct PublishedSchemaVersionSummaryResponse {
+    pub version: i32,
+    pub published_by_subject: String,
+    pub published_at: String,
+    pub field_count: usize,
+    pub checksum_sha256: String,
+}
+
+/// One stored published schema version with its publish metadata.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/published-schema-version-response.ts"
+)]
+pub struct PublishedSchemaVersionResponse {
+    pub published_by_subject: String,
+    pub published_at: String,
+    pub checksum_sha256: String,
+    pub schema: PublishedSchemaResponse,
+}
+
 /// Publish validation report for one entity.
 #[derive(Debug, Serialize, TS)]
 #[ts(
@@ -0,0 +1,93 @@
+use qryvanta_application::{BackgroundJob, BackgroundJobStage};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Progress counters of one background job stage.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/background-job-stage-response.ts"
+)]
+pub struct BackgroundJobStageResponse {
+    pub name: String,
+    #[ts(type = "number")]
+    pub processed: u64,
+    #[ts(type = "number | null")]
+    pub total: Option<u64>,
+    pub completed: bool,
+}
+
+/// Uniform status of a long-running background job.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/background-job-response.ts"
+)]
+pub struct BackgroundJobResponse {
+    pub job_id: String,
+    #[ts(
+        type = "\"audit_log_purge\" | \"qrywell_sync\" | \"personal_data_export\" | \"data_validation_audit\" | \"tenant_data_export\""
+    )]
+    pub kind: String,
+    #[ts(type = "\"queued\" | \"running\" | \"completed\" | \"failed\" | \"cancelled\"")]
+    pub status: String,
+    pub requested_by_subject: String,
+    pub progress_percent: u8,
+    pub stages: Vec<BackgroundJobStageResponse>,
+    pub cancel_requested: bool,
+    pub attempt_count: u32,
+    pub resumed: bool,
+    pub status_message: Option<String>,
+    pub created_at: String,
+    pub started_at: Option<String>,
+    pub updated_at: String,
+    pub finished_at: Option<String>,
+}
+
+/// Incoming payload for queueing a Qrywell sync job.
+#[derive(Debug, Default, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/queue-qrywell-sync-job-request.ts"
+)]
+pub struct QueueQrywellSyncJobRequest {
+    /// Entities to sync; every entity when omitted.
+    #[serde(default)]
+    pub entity_logical_names: Option<Vec<String>>,
+}
+
+impl From<BackgroundJobStage> for BackgroundJobStageResponse {
+    fn from(value: BackgroundJobStage) -> Self {
+        Self {
+            name: value.name,
+            processed: value.processed,
+            total: value.total,
+            completed: value.completed,
+        }
+    }
+}
+
+impl From<BackgroundJob> for BackgroundJobResponse {
+    fn from(value: BackgroundJob) -> Self {
+        Self {
+            progress_percent: value.progress_percent(),
+            resumed: value.attempt_count > 1,
+            job_id: value.job_id,
+            kind: value.kind.as_str().to_owned(),
+            status: value.status.as_str().to_owned(),
+            requested_by_subject: value.requested_by_subject,
+            stages: value
+                .stages
+                .into_iter()
+                .map(BackgroundJobStageResponse::from)
+                .collect(),
+            cancel_requested: value.cancel_requested,
+            attempt_count: value.attempt_count,
+            status_message: value.status_message,
+            created_at: value.created_at.to_rfc3339(),
+            started_at: value.started_at.map(|timestamp| timestamp.to_rfc3339()),
+            updated_at: value.updated_at.to_rfc3339(),
+            finished_at: value.finished_at.map(|timestamp| timestamp.to_rfc3339()),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use qryvanta_domain::LocalizedDisplayLabel;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Incoming payload for saving a localized display label.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-localized-label-request.ts"
+)]
+pub struct SaveLocalizedLabelRequest {
+    pub display_name: String,
+    pub description: Option<String>,
+}
+
+/// API representation of a localized display label.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/localized-label-response.ts"
+)]
+pub struct LocalizedLabelResponse {
+    pub component_type: String,
+    pub component_key: String,
+    pub locale: String,
+    pub display_name: String,
+    pub description: Option<String>,
+}
+
+impl From<LocalizedDisplayLabel> for LocalizedLabelResponse {
+    fn from(value: LocalizedDisplayLabel) -> Self {
+        Self {
+            component_type: value.component_type().as_str().to_owned(),
+            component_key: value.component_key().as_str().to_owned(),
+            locale: value.locale().as_str().to_owned(),
+            display_name: value.display_name().as_str().to_owned(),
+            description: value.description().map(ToOwned::to_owned),
+        }
+    }
+}
@@ -1,41 +1,82 @@
+mod announcements;
 mod apps;
 mod auth;
 mod common;
+mod contacts;
+mod data_validation;
 mod entities;
 mod extensions;
+mod jobs;
+mod localization;
+mod operator;
 mod portability;
 mod publish;
+mod record_imports;
+mod report_subscriptions;
+mod reporting_projections;
 pub(crate) mod runtime;
 mod search;
 mod security;
+mod tenant_data_exports;
 mod workflows;
 
+pub use announcements::{AnnouncementResponse, SaveAnnouncementRequest};
 pub use apps::{
-    AppEntityBindingResponse, AppEntityCapabilitiesResponse, AppPublishChecksResponse, AppResponse,
+    AppAdminGrantResponse, AppEntityBindingResponse, AppEntityCapabilitiesBulkResponse,
+    AppEntityCapabilitiesResponse, AppNavigationResponse, AppPublishChecksResponse, AppResponse,
     AppRoleEntityPermissionResponse, AppSitemapAreaDto, AppSitemapGroupDto, AppSitemapResponse,
     AppSitemapSubAreaDto, AppSitemapTargetDto, BindAppEntityRequest, CreateAppRequest,
-    SaveAppRoleEntityPermissionRequest, SaveAppSitemapRequest, WorkspaceDashboardResponse,
+    GrantAppAdminRequest, PersonalViewResponse, SaveAppRoleEntityPermissionRequest,
+    SaveAppSitemapRequest, SavePersonalViewRequest, WorkspaceDashboardResponse,
 };
 pub use auth::{
     AcceptInviteRequest, AuthLoginRequest, AuthLoginResponse, AuthMfaVerifyRequest,
-    AuthRegisterRequest, AuthStepUpRequest, AuthSwitchTenantRequest, InviteRequest,
+    AuthRegisterRequest, AuthStepUpRequest, AuthSwitchTenantRequest,
+    BootstrapTokenRotationResponse, ConfirmEmailChangeRequest, ConfirmEmailChangeResponse,
+    EmailChangeRequest, EmailChangeStatusResponse, InviteRequest, PasskeyResponse,
+    PendingEmailChangeResponse, RenamePasskeyRequest,
 };
 #[allow(unused_imports)]
 pub use common::{
-    GenericMessageResponse, HealthDependencyStatus, HealthResponse, TenantOptionResponse,
-    UserIdentityResponse,
+    ApiVersionDescriptorResponse, ApiVersionsResponse, CapabilityFlagsResponse,
+    GenericMessageResponse, HealthDependencyStatus, HealthResponse, LivenessResponse,
+    MigrationLevelResponse, ReleaseAdvisoryResponse, TenantOptionResponse, UserIdentityResponse,
+    VersionResponse,
+};
+pub use contacts::{
+    ContactConsentChangeResponse, ContactConsentResponse, ContactIdentityLinkResponse,
+    ContactIdentityMatchResponse, ContactIdentityRebuildResponse, ContactIdentitySourceResponse,
+    LinkContactIdentityRequest, MasterContactResponse, RecordContactConsentRequest,
+    SaveContactIdentitySourceRequest,
+};
+pub use data_validation::{
+    DataValidationScheduleResponse, DataValidationViolationResponse,
+    QueueDataValidationAuditRequest, SaveDataValidationScheduleRequest,
 };
 pub use entities::{
     BusinessRuleResponse, CreateBusinessRuleRequest, CreateEntityRequest, CreateFieldRequest,
-    CreateFormRequest, CreateOptionSetRequest, CreateViewRequest, EntityResponse, FieldResponse,
-    FormResponse, OptionSetResponse, PublishChecksResponse, PublishedSchemaResponse,
-    UpdateEntityRequest, UpdateFieldRequest, ViewResponse,
+    CreateFormRequest, CreateOptionSetRequest, CreateViewRequest, DuplicateRuleResponse,
+    EntityDependencyReportResponse, EntityHistoryPolicyResponse, EntityIconCatalogResponse,
+    EntityResponse, EntitySchemaRollbackChecksResponse, EntitySlugConfigResponse,
+    EntityStatusModelResponse, FieldImpactReportResponse, FieldResponse, FormResponse,
+    OptionSetResponse, PublishChecksResponse, PublishedSchemaResponse,
+    PublishedSchemaVersionResponse, PublishedSchemaVersionSummaryResponse,
+    RelationBehaviorResponse, RelationLookupConfigResponse, SaveDuplicateRuleRequest,
+    SaveEntityHistoryPolicyRequest, SaveEntitySlugConfigRequest, SaveEntityStatusModelRequest,
+    SaveRelationBehaviorRequest, SaveRelationLookupConfigRequest, UpdateEntityRequest,
+    UpdateFieldRequest, ViewResponse, WorkspaceEntitySchemaResponse,
 };
 pub use extensions::{
     CreateExtensionRequest, ExecuteExtensionActionRequest, ExecuteExtensionActionResponse,
     ExtensionCompatibilityRequest, ExtensionCompatibilityResponse, ExtensionIsolationPolicyDto,
     ExtensionResponse,
 };
+pub use jobs::{BackgroundJobResponse, QueueQrywellSyncJobRequest};
+pub use localization::{LocalizedLabelResponse, SaveLocalizedLabelRequest};
+pub use operator::{
+    OperatorAuditEventResponse, OperatorMaintenanceResponse, OperatorQueueStatsResponse,
+    OperatorTenantResponse, OperatorUserLookupResponse, RunOperatorMaintenanceRequest,
+};
 pub use portability::{
     ImportWorkspacePortableBundleRequest, ImportWorkspacePortableBundleResponse,
     WorkspacePortableBundleResponse,
@@ -43,15 +84,36 @@ pub use portability::{
 pub use publish::{
     AppBindingDiffResponse, AppPublishDiffResponse, EntityPublishDiffResponse,
     PublishCheckCategoryDto, PublishCheckIssueResponse, PublishCheckScopeDto,
-    PublishCheckSeverityDto, PublishFieldDiffItemResponse, PublishSurfaceDeltaItemResponse,
-    PublishSurfaceDiffItemResponse, RunWorkspacePublishRequest, RunWorkspacePublishResponse,
-    WorkflowPublishDiffResponse, WorkspacePublishChecksResponse, WorkspacePublishDiffRequest,
-    WorkspacePublishDiffResponse, WorkspacePublishHistoryEntryResponse,
+    PublishCheckSeverityDto, PublishFieldDiffItemResponse, PublishIntentResponse,
+    PublishLockResponse, PublishSurfaceDeltaItemResponse, PublishSurfaceDiffItemResponse,
+    QueuePublishIntentRequest, ReviewedDraftFingerprintDto, RunWorkspacePublishRequest,
+    RunWorkspacePublishResponse, WorkflowPublishDiffResponse, WorkspacePublishChecksResponse,
+    WorkspacePublishDiffRequest, WorkspacePublishDiffResponse,
+    WorkspacePublishHistoryEntryResponse,
+};
+pub use record_imports::{
+    CreateRecordImportScheduleRequest, RecordImportScheduleResponse, RecordImportSummaryResponse,
+    RecordImportTemplateResponse, SaveRecordImportTemplateRequest,
+    UpdateRecordImportScheduleRequest,
+};
+pub use report_subscriptions::{CreateReportSubscriptionRequest, ReportSubscriptionResponse};
+pub use reporting_projections::{
+    QueryReportingProjectionRequest, ReportingProjectionResponse, ReportingProjectionRowResponse,
+    SaveReportingProjectionRequest,
 };
 pub use runtime::{
-    CreateRuntimeRecordRequest, QueryRuntimeRecordsRequest, RuntimeRecordQueryFilterRequest,
+    AggregateRuntimeRecordsRequest, ApproveRecordAccessRequest, AssignRuntimeRecordOwnerRequest,
+    AssociateRuntimeRecordRequest, BulkUpdateRuntimeRecordsRequest,
+    BulkUpdateRuntimeRecordsResponse, CreateRecordShareLinkRequest, CreateRuntimeRecordRequest,
+    CreatedRecordShareLinkResponse, ExecuteRuntimeRecordChangesetRequest,
+    PendingFieldChangeResponse, QueryRuntimeRecordsRequest, QuickCreateRuntimeRecordRequest,
+    RecordAccessRequestResponse, RecordShareLinkResponse, RecordShareLinkViewResponse,
+    RecordShareResponse, RelationLookupMatchResponse, RequestRecordAccessRequest,
+    RuntimeRecordAggregateRowResponse, RuntimeRecordChangesetResultResponse,
+    RuntimeRecordHistoryEntryResponse, RuntimeRecordOwnerResponse, RuntimeRecordQueryFilterRequest,
     RuntimeRecordQueryGroupRequest, RuntimeRecordQueryLinkEntityRequest, RuntimeRecordResponse,
-    UpdateRuntimeRecordRequest,
+    RuntimeRecordSlugResponse, RuntimeRecordStatusChangeResponse, RuntimeRecordUpsertResponse,
+    UpdateRuntimeRecordRequest, UpsertRuntimeRecordRequest,
 };
 pub use search::{
     QrywellSearchAnalyticsResponse, QrywellSearchClickEventRequest, QrywellSearchHitResponse,
@@ -61,64 +123,158 @@ pub use search::{
     QrywellSyncRequest, QrywellSyncResponse,
 };
 pub use security::{
-    AssignRoleRequest, AuditIntegrityStatusResponse, AuditLogEntryResponse,
-    AuditPurgeResultResponse, AuditRetentionPolicyResponse, CreateRoleRequest,
-    CreateTemporaryAccessGrantRequest, RemoveRoleAssignmentRequest,
+    AccessExplanationResponse, AddSecurityTeamMemberRequest, AssignComplianceZoneRequest,
+    AssignRoleRequest, AuditChainAnchorResponse, AuditExportSinkResponse,
+    AuditIntegrityStatusResponse, AuditLogEntryResponse, AuditPurgeResultResponse,
+    AuditRetentionPolicyResponse, ComplianceZoneAssignmentResponse, ComplianceZoneTagResponse,
+    CreateAuditExportSinkRequest, CreateLegalHoldRequest, CreateMfaResetRequest, CreateRoleRequest,
+    CreateSecurityTeamRequest, CreateTemporaryAccessGrantRequest, DataErasureFieldResponse,
+    DataErasureReportResponse, DualControlFieldResponse, EraseSubjectDataRequest,
+    EvaluatedFeatureFlagResponse, FeatureFlagResponse, FieldMaskingRuleResponse, LegalHoldResponse,
+    MfaResetRequestResponse, PermissionCatalogGroupResponse, RemoveRoleAssignmentRequest,
     RevokeTemporaryAccessGrantRequest, RoleAssignmentResponse, RoleResponse,
-    RuntimeFieldPermissionResponse, SaveRuntimeFieldPermissionsRequest,
-    TemporaryAccessGrantResponse, TenantRegistrationModeResponse,
+    RuntimeFieldPermissionResponse, SaveComplianceZoneTagRequest, SaveDataErasureFieldsRequest,
+    SaveDualControlFieldsRequest, SaveFeatureFlagRequest, SaveFieldMaskingRulesRequest,
+    SaveRuntimeFieldPermissionsRequest, SecurityTeamMemberResponse, SecurityTeamResponse,
+    ShredTenantEncryptionKeysRequest, ShredTenantEncryptionKeysResponse,
+    TemporaryAccessGrantResponse, TenantEncryptionKeyRequest, TenantEncryptionKeyResponse,
+    TenantRegistrationModeResponse, UpdateAuditExportSinkRequest,
     UpdateAuditRetentionPolicyRequest, UpdateTenantRegistrationModeRequest,
+    UpdateWorkflowConcurrencyPolicyRequest, WorkflowConcurrencyPolicyResponse,
 };
+pub use tenant_data_exports::TenantDataExportLinkResponse;
 pub use workflows::{
-    DispatchScheduleTriggerRequest, ExecuteWorkflowRequest, RetryWorkflowStepRequest,
-    RetryWorkflowStepStrategyDto, SaveWorkflowRequest, WorkflowResponse,
-    WorkflowRunAttemptResponse, WorkflowRunReplayResponse, WorkflowRunResponse,
+    ConfigureWorkflowInboundWebhookRequest, CreatedWorkflowInboundWebhookResponse,
+    DispatchScheduleTriggerRequest, ExecuteWorkflowRequest, FailWorkflowJobRequest,
+    RetryWorkflowStepRequest, RetryWorkflowStepStrategyDto, SaveWorkflowCredentialRequest,
+    SaveWorkflowRequest, WorkflowBulkExecutionPreviewResponse, WorkflowBulkExecutionRequest,
+    WorkflowBulkExecutionResponse, WorkflowCredentialResponse, WorkflowInboundWebhookResponse,
+    WorkflowQueueStatsResponse, WorkflowResponse, WorkflowRunAttemptResponse,
+    WorkflowRunReplayResponse, WorkflowRunResponse, WorkflowStuckJobResponse,
+    WorkflowWorkerLeaseResponse,
 };
 
+#[cfg(test)]
+pub use record_imports::RecordImportColumnMappingDto;
+#[cfg(test)]
+pub use reporting_projections::ReportingProjectionColumnRequest;
+#[cfg(test)]
+pub use runtime::RelationCascadeResponse;
+#[cfg(test)]
+pub use security::{
+    DualControlFieldRequest, FeatureFlagRoleOverrideRequest, FeatureFlagRoleOverrideResponse,
+    FieldMaskingRuleRequest,
+};
 #[cfg(test)]
 pub use workflows::WorkflowRunReplayTimelineEventResponse;
 
 #[cfg(test)]
 mod tests {
     use super::apps::{
-        AppEntityFormDto, AppEntityViewDto, ChartAggregationDto, ChartResponse, ChartTypeDto,
-        DashboardWidgetResponse,
+        AppEntityCapabilitySummaryResponse, AppEntityFieldPermissionSummaryResponse,
+        AppEntityFormDto, AppEntityViewDto, AppNavigationEntityResponse, ChartAggregationDto,
+        ChartResponse, ChartTypeDto, DashboardWidgetResponse,
+    };
+    use super::common::{
+        ApiVersionDescriptorResponse, ApiVersionsResponse, HealthDependencyStatus,
+        LivenessResponse, MigrationLevelResponse, ReleaseAdvisoryResponse, VersionResponse,
+    };
+    use super::record_imports::{
+        CreateRecordImportScheduleRequest, RecordImportColumnMappingDto,
+        RecordImportRowErrorResponse, RecordImportScheduleResponse, RecordImportSummaryResponse,
+        RecordImportTemplateResponse, SaveRecordImportTemplateRequest,
+        UpdateRecordImportScheduleRequest,
+    };
+    use super::reporting_projections::{
+        QueryReportingProjectionRequest, ReportingProjectionColumnRequest,
+        ReportingProjectionColumnResponse, ReportingProjectionFilterRequest,
+        ReportingProjectionResponse, ReportingProjectionRowResponse,
+        SaveReportingProjectionRequest,
     };
-    use super::common::HealthDependencyStatus;
+    use super::tenant_data_exports::TenantDataExportLinkResponse;
     use super::{
-        AcceptInviteRequest, AppEntityBindingResponse, AppEntityCapabilitiesResponse,
-        AppPublishChecksResponse, AppResponse, AppRoleEntityPermissionResponse, AppSitemapAreaDto,
-        AppSitemapGroupDto, AppSitemapResponse, AppSitemapSubAreaDto, AppSitemapTargetDto,
-        AssignRoleRequest, AuditIntegrityStatusResponse, AuditLogEntryResponse,
-        AuditPurgeResultResponse, AuditRetentionPolicyResponse, AuthLoginRequest,
-        AuthLoginResponse, AuthMfaVerifyRequest, AuthRegisterRequest, AuthStepUpRequest,
-        AuthSwitchTenantRequest, BindAppEntityRequest, BusinessRuleResponse, CreateAppRequest,
+        AcceptInviteRequest, AccessExplanationResponse, AddSecurityTeamMemberRequest,
+        AggregateRuntimeRecordsRequest, AnnouncementResponse, AppAdminGrantResponse,
+        AppEntityBindingResponse, AppEntityCapabilitiesBulkResponse, AppEntityCapabilitiesResponse,
+        AppNavigationResponse, AppPublishChecksResponse, AppResponse,
+        AppRoleEntityPermissionResponse, AppSitemapAreaDto, AppSitemapGroupDto, AppSitemapResponse,
+        AppSitemapSubAreaDto, AppSitemapTargetDto, ApproveRecordAccessRequest,
+        AssignComplianceZoneRequest, AssignRoleRequest, AssignRuntimeRecordOwnerRequest,
+        AssociateRuntimeRecordRequest, AuditChainAnchorResponse, AuditExportSinkResponse,
+        AuditIntegrityStatusResponse, AuditLogEntryResponse, AuditPurgeResultResponse,
+        AuditRetentionPolicyResponse, AuthLoginRequest, AuthLoginResponse, AuthMfaVerifyRequest,
+        AuthRegisterRequest, AuthStepUpRequest, AuthSwitchTenantRequest, BackgroundJobResponse,
+        BindAppEntityRequest, BootstrapTokenRotationResponse, BusinessRuleResponse,
+        CapabilityFlagsResponse, ComplianceZoneAssignmentResponse, ComplianceZoneTagResponse,
+        ConfigureWorkflowInboundWebhookRequest, ConfirmEmailChangeRequest,
+        ConfirmEmailChangeResponse, ContactConsentChangeResponse, ContactConsentResponse,
+        ContactIdentityLinkResponse, ContactIdentityMatchResponse, ContactIdentityRebuildResponse,
+        ContactIdentitySourceResponse, CreateAppRequest, CreateAuditExportSinkRequest,
         CreateBusinessRuleRequest, CreateEntityRequest, CreateExtensionRequest, CreateFieldRequest,
-        CreateFormRequest, CreateOptionSetRequest, CreateRoleRequest, CreateRuntimeRecordRequest,
-        CreateTemporaryAccessGrantRequest, CreateViewRequest, DispatchScheduleTriggerRequest,
-        EntityResponse, ExecuteExtensionActionRequest, ExecuteExtensionActionResponse,
-        ExecuteWorkflowRequest, ExtensionCompatibilityRequest, ExtensionCompatibilityResponse,
-        ExtensionIsolationPolicyDto, ExtensionResponse, FieldResponse, FormResponse,
-        GenericMessageResponse, HealthResponse, ImportWorkspacePortableBundleRequest,
-        ImportWorkspacePortableBundleResponse, InviteRequest, OptionSetResponse,
+        CreateFormRequest, CreateLegalHoldRequest, CreateMfaResetRequest, CreateOptionSetRequest,
+        CreateRecordShareLinkRequest, CreateReportSubscriptionRequest, CreateRoleRequest,
+        CreateRuntimeRecordRequest, CreateSecurityTeamRequest, CreateTemporaryAccessGrantRequest,
+        CreateViewRequest, CreatedRecordShareLinkResponse, CreatedWorkflowInboundWebhookResponse,
+        DataErasureFieldResponse, DataErasureReportResponse, DataValidationScheduleResponse,
+        DataValidationViolationResponse, DispatchScheduleTriggerRequest, DualControlFieldRequest,
+        DualControlFieldResponse, DuplicateRuleResponse, EmailChangeRequest,
+        EmailChangeStatusResponse, EntityDependencyReportResponse, EntityIconCatalogResponse,
+        EntityResponse, EntitySchemaRollbackChecksResponse, EntitySlugConfigResponse,
+        EntityStatusModelResponse, EraseSubjectDataRequest, EvaluatedFeatureFlagResponse,
+        ExecuteExtensionActionRequest, ExecuteExtensionActionResponse,
+        ExecuteRuntimeRecordChangesetRequest, ExecuteWorkflowRequest,
+        ExtensionCompatibilityRequest, ExtensionCompatibilityResponse, ExtensionIsolationPolicyDto,
+        ExtensionResponse, FailWorkflowJobRequest, FeatureFlagResponse,
+        FeatureFlagRoleOverrideRequest, FeatureFlagRoleOverrideResponse, FieldImpactReportResponse,
+        FieldMaskingRuleRequest, FieldMaskingRuleResponse, FieldResponse, FormResponse,
+        GenericMessageResponse, GrantAppAdminRequest, HealthResponse,
+        ImportWorkspacePortableBundleRequest, ImportWorkspacePortableBundleResponse, InviteRequest,
+        LegalHoldResponse, LinkContactIdentityRequest, LocalizedLabelResponse,
+        MasterContactResponse, MfaResetRequestResponse, OperatorAuditEventResponse,
+        OperatorMaintenanceResponse, OperatorQueueStatsResponse, OperatorTenantResponse,
+        OperatorUserLookupResponse, OptionSetResponse, PasskeyResponse, PendingEmailChangeResponse,
+        PendingFieldChangeResponse, PermissionCatalogGroupResponse, PersonalViewResponse,
         PublishCheckCategoryDto, PublishCheckIssueResponse, PublishCheckScopeDto,
-        PublishCheckSeverityDto, PublishChecksResponse, PublishSurfaceDeltaItemResponse,
-        PublishedSchemaResponse, QrywellSearchAnalyticsResponse, QrywellSearchClickEventRequest,
-        QrywellSearchLowRelevanceClickResponse, QrywellSearchRankMetricResponse,
-        QrywellSearchRequest, QrywellSearchResponse, QrywellSearchTopQueryResponse,
-        QrywellSearchZeroClickQueryResponse, QrywellSyncAllResponse, QrywellSyncHealthResponse,
-        QrywellSyncRequest, QrywellSyncResponse, QueryRuntimeRecordsRequest,
-        RemoveRoleAssignmentRequest, RetryWorkflowStepRequest, RetryWorkflowStepStrategyDto,
+        PublishCheckSeverityDto, PublishChecksResponse, PublishIntentResponse, PublishLockResponse,
+        PublishSurfaceDeltaItemResponse, PublishedSchemaResponse, PublishedSchemaVersionResponse,
+        PublishedSchemaVersionSummaryResponse, QrywellSearchAnalyticsResponse,
+        QrywellSearchClickEventRequest, QrywellSearchLowRelevanceClickResponse,
+        QrywellSearchRankMetricResponse, QrywellSearchRequest, QrywellSearchResponse,
+        QrywellSearchTopQueryResponse, QrywellSearchZeroClickQueryResponse, QrywellSyncAllResponse,
+        QrywellSyncHealthResponse, QrywellSyncRequest, QrywellSyncResponse,
+        QueryRuntimeRecordsRequest, QueueDataValidationAuditRequest, QueuePublishIntentRequest,
+        QueueQrywellSyncJobRequest, QuickCreateRuntimeRecordRequest, RecordAccessRequestResponse,
+        RecordContactConsentRequest, RecordShareLinkResponse, RecordShareLinkViewResponse,
+        RecordShareResponse, RelationBehaviorResponse, RelationCascadeResponse,
+        RelationLookupConfigResponse, RelationLookupMatchResponse, RemoveRoleAssignmentRequest,
+        RenamePasskeyRequest, ReportSubscriptionResponse, RequestRecordAccessRequest,
+        RetryWorkflowStepRequest, RetryWorkflowStepStrategyDto, ReviewedDraftFingerprintDto,
         RevokeTemporaryAccessGrantRequest, RoleAssignmentResponse, RoleResponse,
-        RunWorkspacePublishRequest, RunWorkspacePublishResponse, RuntimeFieldPermissionResponse,
-        RuntimeRecordResponse, SaveAppRoleEntityPermissionRequest, SaveAppSitemapRequest,
-        SaveRuntimeFieldPermissionsRequest, SaveWorkflowRequest, TemporaryAccessGrantResponse,
-        TenantOptionResponse, TenantRegistrationModeResponse, UpdateAuditRetentionPolicyRequest,
-        UpdateEntityRequest, UpdateFieldRequest, UpdateRuntimeRecordRequest,
-        UpdateTenantRegistrationModeRequest, UserIdentityResponse, ViewResponse,
-        WorkflowPublishDiffResponse, WorkflowResponse, WorkflowRunAttemptResponse,
+        RunOperatorMaintenanceRequest, RunWorkspacePublishRequest, RunWorkspacePublishResponse,
+        RuntimeFieldPermissionResponse, RuntimeRecordAggregateRowResponse,
+        RuntimeRecordChangesetResultResponse, RuntimeRecordOwnerResponse, RuntimeRecordResponse,
+        RuntimeRecordSlugResponse, RuntimeRecordStatusChangeResponse, SaveAnnouncementRequest,
+        SaveAppRoleEntityPermissionRequest, SaveAppSitemapRequest, SaveComplianceZoneTagRequest,
+        SaveContactIdentitySourceRequest, SaveDataErasureFieldsRequest,
+        SaveDataValidationScheduleRequest, SaveDualControlFieldsRequest, SaveDuplicateRuleRequest,
+        SaveEntitySlugConfigRequest, SaveEntityStatusModelRequest, SaveFeatureFlagRequest,
+        SaveFieldMaskingRulesRequest, SaveLocalizedLabelRequest, SavePersonalViewRequest,
+        SaveRelationBehaviorRequest, SaveRelationLookupConfigRequest,
+        SaveRuntimeFieldPermissionsRequest, SaveWorkflowCredentialRequest, SaveWorkflowRequest,
+        SecurityTeamMemberResponse, SecurityTeamResponse, ShredTenantEncryptionKeysRequest,
+        ShredTenantEncryptionKeysResponse, TemporaryAccessGrantResponse,
+        TenantEncryptionKeyRequest, TenantEncryptionKeyResponse, TenantOptionResponse,
+        TenantRegistrationModeResponse, UpdateAuditExportSinkRequest,
+        UpdateAuditRetentionPolicyRequest, UpdateEntityRequest, UpdateFieldRequest,
+        UpdateRuntimeRecordRequest, UpdateTenantRegistrationModeRequest,
+        UpdateWorkflowConcurrencyPolicyRequest, UserIdentityResponse, ViewResponse,
+        WorkflowBulkExecutionPreviewResponse, WorkflowBulkExecutionRequest,
+        WorkflowBulkExecutionResponse, WorkflowConcurrencyPolicyResponse,
+        WorkflowCredentialResponse, WorkflowInboundWebhookResponse, WorkflowPublishDiffResponse,
+        WorkflowQueueStatsResponse, WorkflowResponse, WorkflowRunAttemptResponse,
         WorkflowRunReplayResponse, WorkflowRunReplayTimelineEventResponse, WorkflowRunResponse,
-        WorkspaceDashboardResponse, WorkspacePortableBundleResponse,
+        WorkflowStuckJobResponse, WorkflowWorkerLeaseResponse, WorkspaceDashboardResponse,
+        WorkspaceEntitySchemaResponse, WorkspacePortableBundleResponse,
         WorkspacePublishChecksResponse, WorkspacePublishDiffRequest, WorkspacePublishDiffResponse,
         WorkspacePublishHistoryEntryResponse,
     };
@@ -132,14 +288,29 @@ mod tests {
         let config = Config::default();
 
         CreateEntityRequest::export(&config)?;
+        SaveAnnouncementRequest::export(&config)?;
+        AnnouncementResponse::export(&config)?;
         CreateAppRequest::export(&config)?;
         SaveAppSitemapRequest::export(&config)?;
         BindAppEntityRequest::export(&config)?;
         SaveAppRoleEntityPermissionRequest::export(&config)?;
         SaveWorkflowRequest::export(&config)?;
         super::workflows::WorkflowConditionOperatorDto::export(&config)?;
+        super::workflows::WorkflowHttpRetryPolicyDto::export(&config)?;
+        super::workflows::WorkflowInvocationModeDto::export(&config)?;
+        super::workflows::WorkflowStepBackoffStrategyDto::export(&config)?;
+        super::workflows::WorkflowStepRetryErrorClassDto::export(&config)?;
+        super::workflows::WorkflowStepRetryPolicyDto::export(&config)?;
+        super::workflows::WorkflowTriggerFilterDto::export(&config)?;
+        super::workflows::WorkflowTriggerFilterOperatorDto::export(&config)?;
         super::workflows::WorkflowStepDto::export(&config)?;
         ExecuteWorkflowRequest::export(&config)?;
+        ConfigureWorkflowInboundWebhookRequest::export(&config)?;
+        WorkflowInboundWebhookResponse::export(&config)?;
+        CreatedWorkflowInboundWebhookResponse::export(&config)?;
+        super::workflows::WorkflowCredentialAuthDto::export(&config)?;
+        SaveWorkflowCredentialRequest::export(&config)?;
+        WorkflowCredentialResponse::export(&config)?;
         DispatchScheduleTriggerRequest::export(&config)?;
         RetryWorkflowStepRequest::export(&config)?;
         RetryWorkflowStepStrategyDto::export(&config)?;
@@ -149,27 +320,73 @@ mod tests {
         CreateOptionSetRequest::export(&config)?;
         CreateViewRequest::export(&config)?;
         super::entities::OptionSetItemDto::export(&config)?;
+        super::entities::FormScriptEventsDto::export(&config)?;
+        super::entities::WorkspaceFormScriptEventsResponse::export(&config)?;
         OptionSetResponse::export(&config)?;
         PublishChecksResponse::export(&config)?;
+        PublishedSchemaVersionResponse::export(&config)?;
+        PublishedSchemaVersionSummaryResponse::export(&config)?;
         UpdateEntityRequest::export(&config)?;
         UpdateFieldRequest::export(&config)?;
+        SaveRelationBehaviorRequest::export(&config)?;
+        RelationBehaviorResponse::export(&config)?;
+        SaveRelationLookupConfigRequest::export(&config)?;
+        RelationLookupConfigResponse::export(&config)?;
+        SaveEntitySlugConfigRequest::export(&config)?;
+        EntitySlugConfigResponse::export(&config)?;
+        RuntimeRecordSlugResponse::export(&config)?;
+        super::entities::RecordStatusOptionDto::export(&config)?;
+        super::entities::RecordStatusTransitionDto::export(&config)?;
+        SaveEntityStatusModelRequest::export(&config)?;
+        EntityStatusModelResponse::export(&config)?;
+        super::entities::DuplicateMatchFieldDto::export(&config)?;
+        SaveDuplicateRuleRequest::export(&config)?;
+        DuplicateRuleResponse::export(&config)?;
+        RuntimeRecordStatusChangeResponse::export(&config)?;
+        super::runtime::RuntimeRecordFieldChangeResponse::export(&config)?;
+        super::entities::SaveEntityHistoryPolicyRequest::export(&config)?;
+        super::entities::EntityHistoryPolicyResponse::export(&config)?;
+        super::runtime::RuntimeRecordHistoryEntryResponse::export(&config)?;
+        RelationLookupMatchResponse::export(&config)?;
         CreateRoleRequest::export(&config)?;
         CreateRuntimeRecordRequest::export(&config)?;
+        QuickCreateRuntimeRecordRequest::export(&config)?;
+        ExecuteRuntimeRecordChangesetRequest::export(&config)?;
+        super::runtime::RuntimeRecordChangesetOperationRequest::export(&config)?;
         AssignRoleRequest::export(&config)?;
         RemoveRoleAssignmentRequest::export(&config)?;
+        CreateSecurityTeamRequest::export(&config)?;
+        AddSecurityTeamMemberRequest::export(&config)?;
+        SecurityTeamResponse::export(&config)?;
+        SecurityTeamMemberResponse::export(&config)?;
         UpdateTenantRegistrationModeRequest::export(&config)?;
         super::security::RuntimeFieldPermissionInputRequest::export(&config)?;
         SaveRuntimeFieldPermissionsRequest::export(&config)?;
         CreateTemporaryAccessGrantRequest::export(&config)?;
         RevokeTemporaryAccessGrantRequest::export(&config)?;
+        CreateMfaResetRequest::export(&config)?;
         UpdateAuditRetentionPolicyRequest::export(&config)?;
+        UpdateWorkflowConcurrencyPolicyRequest::export(&config)?;
+        TenantEncryptionKeyRequest::export(&config)?;
+        ShredTenantEncryptionKeysRequest::export(&config)?;
+        CreateLegalHoldRequest::export(&config)?;
+        CreateAuditExportSinkRequest::export(&config)?;
+        UpdateAuditExportSinkRequest::export(&config)?;
+        AssignComplianceZoneRequest::export(&config)?;
+        SaveComplianceZoneTagRequest::export(&config)?;
         AuditIntegrityStatusResponse::export(&config)?;
+        AuditChainAnchorResponse::export(&config)?;
         UpdateRuntimeRecordRequest::export(&config)?;
         super::runtime::RuntimeRecordQueryFilterRequest::export(&config)?;
         super::runtime::RuntimeRecordQueryGroupRequest::export(&config)?;
         super::runtime::RuntimeRecordQueryLinkEntityRequest::export(&config)?;
         super::runtime::RuntimeRecordQuerySortRequest::export(&config)?;
         QueryRuntimeRecordsRequest::export(&config)?;
+        super::runtime::RuntimeRecordAggregateRequest::export(&config)?;
+        super::runtime::RuntimeRecordAggregateHavingRequest::export(&config)?;
+        super::runtime::RuntimeRecordAggregateSortRequest::export(&config)?;
+        AggregateRuntimeRecordsRequest::export(&config)?;
+        RuntimeRecordAggregateRowResponse::export(&config)?;
         AuthStepUpRequest::export(&config)?;
         CreateExtensionRequest::export(&config)?;
         ExtensionIsolationPolicyDto::export(&config)?;
@@ -184,15 +401,57 @@ mod tests {
         QrywellSearchRequest::export(&config)?;
         QrywellSearchClickEventRequest::export(&config)?;
         QrywellSyncRequest::export(&config)?;
+        QueueQrywellSyncJobRequest::export(&config)?;
+        BackgroundJobResponse::export(&config)?;
+        CreateReportSubscriptionRequest::export(&config)?;
+        QueueDataValidationAuditRequest::export(&config)?;
+        DataValidationViolationResponse::export(&config)?;
+        SaveDataValidationScheduleRequest::export(&config)?;
+        DataValidationScheduleResponse::export(&config)?;
+        RecordImportColumnMappingDto::export(&config)?;
+        SaveRecordImportTemplateRequest::export(&config)?;
+        RecordImportTemplateResponse::export(&config)?;
+        RecordImportRowErrorResponse::export(&config)?;
+        RecordImportSummaryResponse::export(&config)?;
+        CreateRecordImportScheduleRequest::export(&config)?;
+        UpdateRecordImportScheduleRequest::export(&config)?;
+        RecordImportScheduleResponse::export(&config)?;
+        ReportSubscriptionResponse::export(&config)?;
+        ReportingProjectionColumnRequest::export(&config)?;
+        SaveReportingProjectionRequest::export(&config)?;
+        ReportingProjectionColumnResponse::export(&config)?;
+        ReportingProjectionResponse::export(&config)?;
+        ReportingProjectionFilterRequest::export(&config)?;
+        QueryReportingProjectionRequest::export(&config)?;
+        ReportingProjectionRowResponse::export(&config)?;
+        TenantDataExportLinkResponse::export(&config)?;
+        OperatorTenantResponse::export(&config)?;
+        super::operator::OperatorUserMembershipResponse::export(&config)?;
+        OperatorUserLookupResponse::export(&config)?;
+        OperatorQueueStatsResponse::export(&config)?;
+        RunOperatorMaintenanceRequest::export(&config)?;
+        OperatorMaintenanceResponse::export(&config)?;
+        OperatorAuditEventResponse::export(&config)?;
+        super::jobs::BackgroundJobStageResponse::export(&config)?;
         EntityResponse::export(&config)?;
+        super::entities::EntityDependencyResponse::export(&config)?;
+        EntityDependencyReportResponse::export(&config)?;
+        super::entities::FieldImpactResponse::export(&config)?;
+        FieldImpactReportResponse::export(&config)?;
+        EntitySchemaRollbackChecksResponse::export(&config)?;
+        EntityIconCatalogResponse::export(&config)?;
         AppResponse::export(&config)?;
         AppEntityBindingResponse::export(&config)?;
         AppSitemapResponse::export(&config)?;
+        AppNavigationResponse::export(&config)?;
+        AppNavigationEntityResponse::export(&config)?;
         AppSitemapAreaDto::export(&config)?;
         AppSitemapGroupDto::export(&config)?;
         AppSitemapSubAreaDto::export(&config)?;
         AppSitemapTargetDto::export(&config)?;
         WorkspaceDashboardResponse::export(&config)?;
+        SavePersonalViewRequest::export(&config)?;
+        PersonalViewResponse::export(&config)?;
         DashboardWidgetResponse::export(&config)?;
         ChartResponse::export(&config)?;
         ChartTypeDto::export(&config)?;
@@ -210,17 +469,37 @@ mod tests {
         WorkspacePublishHistoryEntryResponse::export(&config)?;
         RunWorkspacePublishRequest::export(&config)?;
         RunWorkspacePublishResponse::export(&config)?;
+        ReviewedDraftFingerprintDto::export(&config)?;
+        PublishLockResponse::export(&config)?;
+        QueuePublishIntentRequest::export(&config)?;
+        PublishIntentResponse::export(&config)?;
         AppEntityFormDto::export(&config)?;
         AppEntityViewDto::export(&config)?;
         AppEntityCapabilitiesResponse::export(&config)?;
+        AppEntityFieldPermissionSummaryResponse::export(&config)?;
+        AppEntityCapabilitySummaryResponse::export(&config)?;
+        AppEntityCapabilitiesBulkResponse::export(&config)?;
         super::apps::AppEntityViewModeDto::export(&config)?;
         AppRoleEntityPermissionResponse::export(&config)?;
+        AppAdminGrantResponse::export(&config)?;
+        GrantAppAdminRequest::export(&config)?;
         FieldResponse::export(&config)?;
         BusinessRuleResponse::export(&config)?;
         FormResponse::export(&config)?;
         PublishedSchemaResponse::export(&config)?;
+        WorkspaceEntitySchemaResponse::export(&config)?;
         ViewResponse::export(&config)?;
         RuntimeRecordResponse::export(&config)?;
+        AssignRuntimeRecordOwnerRequest::export(&config)?;
+        AssociateRuntimeRecordRequest::export(&config)?;
+        RuntimeRecordOwnerResponse::export(&config)?;
+        RuntimeRecordChangesetResultResponse::export(&config)?;
+        super::runtime::UpsertRuntimeRecordRequest::export(&config)?;
+        super::runtime::RuntimeRecordUpsertResponse::export(&config)?;
+        super::runtime::BulkUpdateRuntimeRecordsRequest::export(&config)?;
+        super::runtime::BulkUpdateRuntimeRecordResultResponse::export(&config)?;
+        super::runtime::BulkUpdateRuntimeRecordsResponse::export(&config)?;
+        RelationCascadeResponse::export(&config)?;
         super::search::QrywellSearchHitResponse::export(&config)?;
         super::search::QrywellSyncFailedJobResponse::export(&config)?;
         QrywellSearchResponse::export(&config)?;
@@ -234,6 +513,13 @@ mod tests {
         QrywellSyncResponse::export(&config)?;
         WorkflowResponse::export(&config)?;
         WorkflowRunResponse::export(&config)?;
+        WorkflowBulkExecutionRequest::export(&config)?;
+        WorkflowBulkExecutionPreviewResponse::export(&config)?;
+        WorkflowBulkExecutionResponse::export(&config)?;
+        WorkflowQueueStatsResponse::export(&config)?;
+        WorkflowStuckJobResponse::export(&config)?;
+        FailWorkflowJobRequest::export(&config)?;
+        WorkflowWorkerLeaseResponse::export(&config)?;
         WorkflowRunAttemptResponse::export(&config)?;
         WorkflowRunReplayResponse::export(&config)?;
         WorkflowRunReplayTimelineEventResponse::export(&config)?;
@@ -244,20 +530,81 @@ mod tests {
         AuditLogEntryResponse::export(&config)?;
         RuntimeFieldPermissionResponse::export(&config)?;
         TemporaryAccessGrantResponse::export(&config)?;
+        MfaResetRequestResponse::export(&config)?;
+        PermissionCatalogGroupResponse::export(&config)?;
+        AccessExplanationResponse::export(&config)?;
         AuditRetentionPolicyResponse::export(&config)?;
+        WorkflowConcurrencyPolicyResponse::export(&config)?;
         AuditPurgeResultResponse::export(&config)?;
+        TenantEncryptionKeyResponse::export(&config)?;
+        ShredTenantEncryptionKeysResponse::export(&config)?;
+        LegalHoldResponse::export(&config)?;
+        AuditExportSinkResponse::export(&config)?;
+        ComplianceZoneAssignmentResponse::export(&config)?;
+        ComplianceZoneTagResponse::export(&config)?;
+        SaveLocalizedLabelRequest::export(&config)?;
+        LocalizedLabelResponse::export(&config)?;
+        SaveDualControlFieldsRequest::export(&config)?;
+        DualControlFieldRequest::export(&config)?;
+        DualControlFieldResponse::export(&config)?;
+        SaveDataErasureFieldsRequest::export(&config)?;
+        DataErasureFieldResponse::export(&config)?;
+        EraseSubjectDataRequest::export(&config)?;
+        DataErasureReportResponse::export(&config)?;
+        SaveFieldMaskingRulesRequest::export(&config)?;
+        FieldMaskingRuleRequest::export(&config)?;
+        FieldMaskingRuleResponse::export(&config)?;
+        SaveFeatureFlagRequest::export(&config)?;
+        FeatureFlagRoleOverrideRequest::export(&config)?;
+        FeatureFlagRoleOverrideResponse::export(&config)?;
+        FeatureFlagResponse::export(&config)?;
+        EvaluatedFeatureFlagResponse::export(&config)?;
+        PendingFieldChangeResponse::export(&config)?;
+        RequestRecordAccessRequest::export(&config)?;
+        ApproveRecordAccessRequest::export(&config)?;
+        RecordAccessRequestResponse::export(&config)?;
+        RecordShareResponse::export(&config)?;
+        CreateRecordShareLinkRequest::export(&config)?;
+        RecordShareLinkResponse::export(&config)?;
+        CreatedRecordShareLinkResponse::export(&config)?;
+        RecordShareLinkViewResponse::export(&config)?;
+        RecordContactConsentRequest::export(&config)?;
+        ContactConsentResponse::export(&config)?;
+        ContactConsentChangeResponse::export(&config)?;
+        SaveContactIdentitySourceRequest::export(&config)?;
+        ContactIdentitySourceResponse::export(&config)?;
+        ContactIdentityLinkResponse::export(&config)?;
+        MasterContactResponse::export(&config)?;
+        ContactIdentityMatchResponse::export(&config)?;
+        LinkContactIdentityRequest::export(&config)?;
+        ContactIdentityRebuildResponse::export(&config)?;
         ErrorResponse::export(&config)?;
         HealthDependencyStatus::export(&config)?;
         HealthResponse::export(&config)?;
+        LivenessResponse::export(&config)?;
+        VersionResponse::export(&config)?;
+        ApiVersionsResponse::export(&config)?;
+        ApiVersionDescriptorResponse::export(&config)?;
+        MigrationLevelResponse::export(&config)?;
+        ReleaseAdvisoryResponse::export(&config)?;
         UserIdentityResponse::export(&config)?;
         AuthRegisterRequest::export(&config)?;
         AuthLoginRequest::export(&config)?;
         AuthLoginResponse::export(&config)?;
         AuthMfaVerifyRequest::export(&config)?;
         AuthSwitchTenantRequest::export(&config)?;
+        BootstrapTokenRotationResponse::export(&config)?;
         GenericMessageResponse::export(&config)?;
         InviteRequest::export(&config)?;
         AcceptInviteRequest::export(&config)?;
+        EmailChangeRequest::export(&config)?;
+        ConfirmEmailChangeRequest::export(&config)?;
+        ConfirmEmailChangeResponse::export(&config)?;
+        PendingEmailChangeResponse::export(&config)?;
+        EmailChangeStatusResponse::export(&config)?;
+        PasskeyResponse::export(&config)?;
+        RenamePasskeyRequest::export(&config)?;
+        CapabilityFlagsResponse::export(&config)?;
         TenantOptionResponse::export(&config)?;
 
         Ok(())
@@ -0,0 +1,210 @@
+use qryvanta_application::{
+    OperatorAuditEvent, OperatorMaintenanceResult, OperatorTenantUsage, OperatorUserLookup,
+    TenantMembership, WorkflowQueueStats,
+};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Tenant with health and usage counters for the operator console.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/operator-tenant-response.ts"
+)]
+pub struct OperatorTenantResponse {
+    pub tenant_id: String,
+    pub name: String,
+    pub created_at: String,
+    #[ts(type = "\"healthy\" | \"degraded\"")]
+    pub health: String,
+    #[ts(type = "number")]
+    pub member_count: i64,
+    #[ts(type = "number")]
+    pub entity_count: i64,
+    #[ts(type = "number")]
+    pub runtime_record_count: i64,
+    #[ts(type = "number")]
+    pub pending_workflow_jobs: i64,
+    #[ts(type = "number")]
+    pub failed_workflow_jobs_last_day: i64,
+    #[ts(type = "number")]
+    pub expired_workflow_leases: i64,
+    pub last_activity_at: Option<String>,
+}
+
+/// Tenant membership of a user found by an operator search.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/operator-user-membership-response.ts"
+)]
+pub struct OperatorUserMembershipResponse {
+    pub tenant_id: String,
+    pub tenant_name: String,
+    pub display_name: String,
+    pub email: Option<String>,
+}
+
+/// User found by an operator email search.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/operator-user-lookup-response.ts"
+)]
+pub struct OperatorUserLookupResponse {
+    pub user_id: String,
+    pub email: String,
+    pub email_verified: bool,
+    pub mfa_enabled: bool,
+    pub locked_until: Option<String>,
+    pub memberships: Vec<OperatorUserMembershipResponse>,
+}
+
+/// Workflow queue and worker stats across all tenants.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/operator-queue-stats-response.ts"
+)]
+pub struct OperatorQueueStatsResponse {
+    #[ts(type = "number")]
+    pub pending_jobs: i64,
+    #[ts(type = "number")]
+    pub leased_jobs: i64,
+    #[ts(type = "number")]
+    pub completed_jobs: i64,
+    #[ts(type = "number")]
+    pub failed_jobs: i64,
+    #[ts(type = "number")]
+    pub expired_leases: i64,
+    #[ts(type = "number")]
+    pub active_workers: i64,
+}
+
+/// Incoming payload for running a tenant maintenance action.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/run-operator-maintenance-request.ts"
+)]
+pub struct RunOperatorMaintenanceRequest {
+    #[ts(
+        type = "\"release_stuck_workflow_jobs\" | \"fail_stuck_workflow_jobs\" | \"flush_view_cache\""
+    )]
+    pub action: String,
+}
+
+/// Outcome of a tenant maintenance action.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/operator-maintenance-response.ts"
+)]
+pub struct OperatorMaintenanceResponse {
+    pub tenant_id: String,
+    #[ts(
+        type = "\"release_stuck_workflow_jobs\" | \"fail_stuck_workflow_jobs\" | \"flush_view_cache\""
+    )]
+    pub action: String,
+    #[ts(type = "number")]
+    pub affected: usize,
+}
+
+/// Entry of the global operator log.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/operator-audit-event-response.ts"
+)]
+pub struct OperatorAuditEventResponse {
+    pub event_id: String,
+    pub operator_id: String,
+    pub action: String,
+    pub tenant_id: Option<String>,
+    pub detail: String,
+    pub created_at: String,
+}
+
+impl From<OperatorTenantUsage> for OperatorTenantResponse {
+    fn from(value: OperatorTenantUsage) -> Self {
+        Self {
+            tenant_id: value.tenant_id.to_string(),
+            health: value.health().as_str().to_owned(),
+            name: value.name,
+            created_at: value.created_at.to_rfc3339(),
+            member_count: value.member_count,
+            entity_count: value.entity_count,
+            runtime_record_count: value.runtime_record_count,
+            pending_workflow_jobs: value.pending_workflow_jobs,
+            failed_workflow_jobs_last_day: value.failed_workflow_jobs_last_day,
+            expired_workflow_leases: value.expired_workflow_leases,
+            last_activity_at: value
+                .last_activity_at
+                .map(|timestamp| timestamp.to_rfc3339()),
+        }
+    }
+}
+
+impl From<TenantMembership> for OperatorUserMembershipResponse {
+    fn from(value: TenantMembership) -> Self {
+        Self {
+            tenant_id: value.tenant_id.to_string(),
+            tenant_name: value.tenant_name,
+            display_name: value.display_name,
+            email: value.email,
+        }
+    }
+}
+
+impl From<OperatorUserLookup> for OperatorUserLookupResponse {
+    fn from(value: OperatorUserLookup) -> Self {
+        Self {
+            user_id: value.user_id.to_string(),
+            email: value.email,
+            email_verified: value.email_verified,
+            mfa_enabled: value.mfa_enabled,
+            locked_until: value.locked_until.map(|timestamp| timestamp.to_rfc3339()),
+            memberships: value
+                .memberships
+                .into_iter()
+                .map(OperatorUserMembershipResponse::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<WorkflowQueueStats> for OperatorQueueStatsResponse {
+    fn from(value: WorkflowQueueStats) -> Self {
+        Self {
+            pending_jobs: value.pending_jobs,
+            leased_jobs: value.leased_jobs,
+            completed_jobs: value.completed_jobs,
+            failed_jobs: value.failed_jobs,
+            expired_leases: value.expired_leases,
+            active_workers: value.active_workers,
+        }
+    }
+}
+
+impl From<OperatorMaintenanceResult> for OperatorMaintenanceResponse {
+    fn from(value: OperatorMaintenanceResult) -> Self {
+        Self {
+            tenant_id: value.tenant_id.to_string(),
+            action: value.action.as_str().to_owned(),
+            affected: value.affected,
+        }
+    }
+}
+
+impl From<OperatorAuditEvent> for OperatorAuditEventResponse {
+    fn from(value: OperatorAuditEvent) -> Self {
+        Self {
+            event_id: value.event_id,
+            operator_id: value.operator_id,
+            action: value.action,
+            tenant_id: value.tenant_id.map(|tenant_id| tenant_id.to_string()),
+            detail: value.detail,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use qryvanta_application::{
+    PublishIntent, PublishLock, PublishTarget, PublishTargetScope, ReviewedDraft,
+};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
@@ -13,7 +16,7 @@ pub enum PublishCheckSeverityDto {
 }
 
 /// Publish check issue scope.
-#[derive(Debug, Clone, Copy, Serialize, TS)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
 #[serde(rename_all = "snake_case")]
 #[ts(
     export,
@@ -88,6 +91,21 @@ pub struct RunWorkspacePublishRequest {
     pub workflow_logical_names: Vec<String>,
     #[serde(default)]
     pub dry_run: bool,
+    /// Draft fingerprints from the reviewed diff; the run fails if any draft changed since.
+    #[serde(default)]
+    pub reviewed_drafts: Vec<ReviewedDraftFingerprintDto>,
+}
+
+/// Draft fingerprint of one component as shown in the publish diff.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/reviewed-draft-fingerprint-dto.ts"
+)]
+pub struct ReviewedDraftFingerprintDto {
+    pub scope: PublishCheckScopeDto,
+    pub logical_name: String,
+    pub draft_fingerprint: String,
 }
 
 /// Result payload for selective workspace publish execution.
@@ -198,6 +216,7 @@ pub struct PublishSurfaceDeltaItemResponse {
 )]
 pub struct EntityPublishDiffResponse {
     pub entity_logical_name: String,
+    pub draft_fingerprint: String,
     pub published_schema_exists: bool,
     pub field_diff: Vec<PublishFieldDiffItemResponse>,
     pub forms: Vec<PublishSurfaceDeltaItemResponse>,
@@ -228,6 +247,7 @@ pub struct AppBindingDiffResponse {
 )]
 pub struct AppPublishDiffResponse {
     pub app_logical_name: String,
+    pub draft_fingerprint: String,
     pub bindings: Vec<AppBindingDiffResponse>,
 }
 
@@ -239,6 +259,7 @@ pub struct AppPublishDiffResponse {
 )]
 pub struct WorkflowPublishDiffResponse {
     pub workflow_logical_name: String,
+    pub draft_fingerprint: String,
     pub lifecycle_state: String,
     pub published_version: Option<i32>,
     pub published_workflow_exists: bool,
@@ -263,3 +284,118 @@ pub struct WorkspacePublishDiffResponse {
     pub app_diffs: Vec<AppPublishDiffResponse>,
     pub workflow_diffs: Vec<WorkflowPublishDiffResponse>,
 }
+
+/// Publish lock held by a running workspace publish.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/publish-lock-response.ts"
+)]
+pub struct PublishLockResponse {
+    pub scope: PublishCheckScopeDto,
+    pub logical_name: String,
+    pub holder_subject: String,
+    pub acquired_at: String,
+    pub expires_at: String,
+}
+
+/// Request payload for queueing a publish behind the locks of another run.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/queue-publish-intent-request.ts"
+)]
+pub struct QueuePublishIntentRequest {
+    #[serde(default)]
+    pub entity_logical_names: Vec<String>,
+    #[serde(default)]
+    pub app_logical_names: Vec<String>,
+    #[serde(default)]
+    pub workflow_logical_names: Vec<String>,
+    #[serde(default)]
+    pub reviewed_drafts: Vec<ReviewedDraftFingerprintDto>,
+}
+
+/// Queued or resolved publish intent.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/publish-intent-response.ts"
+)]
+pub struct PublishIntentResponse {
+    pub intent_id: String,
+    pub requested_by_subject: String,
+    pub status: String,
+    pub status_message: Option<String>,
+    pub entity_logical_names: Vec<String>,
+    pub app_logical_names: Vec<String>,
+    pub workflow_logical_names: Vec<String>,
+    pub created_at: String,
+    pub resolved_at: Option<String>,
+}
+
+impl From<PublishCheckScopeDto> for PublishTargetScope {
+    fn from(value: PublishCheckScopeDto) -> Self {
+        match value {
+            PublishCheckScopeDto::Entity => Self::Entity,
+            PublishCheckScopeDto::App => Self::App,
+            PublishCheckScopeDto::Workflow => Self::Workflow,
+        }
+    }
+}
+
+impl From<PublishTargetScope> for PublishCheckScopeDto {
+    fn from(value: PublishTargetScope) -> Self {
+        match value {
+            PublishTargetScope::Entity => Self::Entity,
+            PublishTargetScope::App => Self::App,
+            PublishTargetScope::Workflow => Self::Workflow,
+        }
+    }
+}
+
+impl From<ReviewedDraftFingerprintDto> for ReviewedDraft {
+    fn from(value: ReviewedDraftFingerprintDto) -> Self {
+        Self {
+            target: PublishTarget::new(value.scope.into(), value.logical_name),
+            draft_fingerprint: value.draft_fingerprint,
+        }
+    }
+}
+
+impl From<PublishLock> for PublishLockResponse {
+    fn from(value: PublishLock) -> Self {
+        Self {
+            scope: value.target.scope.into(),
+            logical_name: value.target.logical_name,
+            holder_subject: value.holder_subject,
+            acquired_at: value.acquired_at.to_rfc3339(),
+            expires_at: value.expires_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<PublishIntent> for PublishIntentResponse {
+    fn from(value: PublishIntent) -> Self {
+        let names_for = |scope: PublishTargetScope| {
+            value
+                .targets
+                .iter()
+                .filter(|target| target.scope == scope)
+                .map(|target| target.logical_name.clone())
+                .collect()
+        };
+
+        Self {
+            entity_logical_names: names_for(PublishTargetScope::Entity),
+            app_logical_names: names_for(PublishTargetScope::App),
+            workflow_logical_names: names_for(PublishTargetScope::Workflow),
+            intent_id: value.intent_id,
+            requested_by_subject: value.requested_by_subject,
+            status: value.status.as_str().to_owned(),
+            status_message: value.status_message,
+            created_at: value.created_at.to_rfc3339(),
+            resolved_at: value.resolved_at.map(|timestamp| timestamp.to_rfc3339()),
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use qryvanta_application::{
+    RecordImportColumnMapping, RecordImportMappingTemplate, RecordImportRowError,
+    RecordImportSchedule, RecordImportSummary,
+};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Source column mapped onto an entity field.
+#[derive(Debug, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/record-import-column-mapping-dto.ts"
+)]
+pub struct RecordImportColumnMappingDto {
+    pub source_column: String,
+    pub field_logical_name: String,
+}
+
+/// Incoming payload for creating or updating an import mapping template.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-record-import-template-request.ts"
+)]
+pub struct SaveRecordImportTemplateRequest {
+    pub name: String,
+    pub column_mappings: Vec<RecordImportColumnMappingDto>,
+}
+
+/// Saved import mapping template of one entity.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/record-import-template-response.ts"
+)]
+pub struct RecordImportTemplateResponse {
+    pub template_id: String,
+    pub entity_logical_name: String,
+    pub name: String,
+    pub column_mappings: Vec<RecordImportColumnMappingDto>,
+    pub updated_by_subject: String,
+    pub updated_at: String,
+}
+
+/// Row of an import file that was not imported.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/record-import-row-error-response.ts"
+)]
+pub struct RecordImportRowErrorResponse {
+    pub line_number: usize,
+    pub message: String,
+}
+
+/// Outcome of importing one CSV file.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/record-import-summary-response.ts"
+)]
+pub struct RecordImportSummaryResponse {
+    pub imported_count: usize,
+    pub failed_count: usize,
+    pub row_errors: Vec<RecordImportRowErrorResponse>,
+}
+
+/// Incoming payload for scheduling a recurring import.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/create-record-import-schedule-request.ts"
+)]
+pub struct CreateRecordImportScheduleRequest {
+    pub name: String,
+    pub entity_logical_name: String,
+    pub template_id: Option<String>,
+    #[ts(type = "\"https\" | \"sftp\"")]
+    pub source_kind: String,
+    pub source_url: String,
+    pub auth_header_name: Option<String>,
+    pub auth_secret_ref: Option<String>,
+    #[ts(type = "\"hourly\" | \"daily\" | \"weekly\"")]
+    pub frequency: String,
+}
+
+/// Incoming payload pausing or resuming a recurring import.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/update-record-import-schedule-request.ts"
+)]
+pub struct UpdateRecordImportScheduleRequest {
+    pub is_enabled: bool,
+}
+
+/// Recurring import of the tenant with its last run.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/record-import-schedule-response.ts"
+)]
+pub struct RecordImportScheduleResponse {
+    pub schedule_id: String,
+    pub name: String,
+    pub entity_logical_name: String,
+    pub template_id: Option<String>,
+    #[ts(type = "\"https\" | \"sftp\"")]
+    pub source_kind: String,
+    pub source_url: String,
+    pub auth_header_name: Option<String>,
+    pub auth_secret_ref: Option<String>,
+    #[ts(type = "\"hourly\" | \"daily\" | \"weekly\"")]
+    pub frequency: String,
+    pub is_enabled: bool,
+    pub owner_subject: String,
+    pub owner_email: String,
+    pub next_run_at: String,
+    pub last_run_at: Option<String>,
+    #[ts(type = "\"imported\" | \"unchanged\" | \"failed\" | null")]
+    pub last_run_status: Option<String>,
+    pub last_imported_count: u32,
+    pub last_failed_count: u32,
+    pub last_error: Option<String>,
+    pub created_at: String,
+}
+
+impl From<RecordImportColumnMappingDto> for RecordImportColumnMapping {
+    fn from(value: RecordImportColumnMappingDto) -> Self {
+        Self {
+            source_column: value.source_column,
+            field_logical_name: value.field_logical_name,
+        }
+    }
+}
+
+impl From<RecordImportColumnMapping> for RecordImportColumnMappingDto {
+    fn from(value: RecordImportColumnMapping) -> Self {
+        Self {
+            source_column: value.source_column,
+            field_logical_name: value.field_logical_name,
+        }
+    }
+}
+
+impl From<RecordImportMappingTemplate> for RecordImportTemplateResponse {
+    fn from(value: RecordImportMappingTemplate) -> Self {
+        Self {
+            template_id: value.template_id,
+            entity_logical_name: value.entity_logical_name,
+            name: value.name,
+            column_mappings: value
+                .column_mappings
+                .into_iter()
+                .map(RecordImportColumnMappingDto::from)
+                .collect(),
+            updated_by_subject: value.updated_by_subject,
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<RecordImportRowError> for RecordImportRowErrorResponse {
+    fn from(value: RecordImportRowError) -> Self {
+        Self {
+            line_number: value.line_number,
+            message: value.message,
+        }
+    }
+}
+
+impl From<RecordImportSummary> for RecordImportSummaryResponse {
+    fn from(value: RecordImportSummary) -> Self {
+        Self {
+            imported_count: value.imported_count,
+            failed_count: value.failed_count,
+            row_errors: value
+                .row_errors
+                .into_iter()
+                .map(RecordImportRowErrorResponse::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<RecordImportSchedule> for RecordImportScheduleResponse {
+    fn from(value: RecordImportSchedule) -> Self {
+        Self {
+            schedule_id: value.schedule_id,
+            name: value.name,
+            entity_logical_name: value.entity_logical_name,
+            template_id: value.template_id,
+            source_kind: value.source_kind.as_str().to_owned(),
+            source_url: value.source_url,
+            auth_header_name: value.auth_header_name,
+            auth_secret_ref: value.auth_secret_ref,
+            frequency: value.frequency.as_str().to_owned(),
+            is_enabled: value.is_enabled,
+            owner_subject: value.owner_subject,
+            owner_email: value.owner_email,
+            next_run_at: value.next_run_at.to_rfc3339(),
+            last_run_at: value.last_run_at.map(|timestamp| timestamp.to_rfc3339()),
+            last_run_status: value
+                .last_run_status
+                .map(|status| status.as_str().to_owned()),
+            last_imported_count: value.last_imported_count,
+            last_failed_count: value.last_failed_count,
+            last_error: value.last_error,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
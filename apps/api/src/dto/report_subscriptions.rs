@@ -0,0 +1,63 @@
+use qryvanta_application::ReportSubscription;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Incoming payload for subscribing to a saved view.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/create-report-subscription-request.ts"
+)]
+pub struct CreateReportSubscriptionRequest {
+    pub entity_logical_name: String,
+    pub view_logical_name: String,
+    #[ts(type = "\"daily\" | \"weekly\"")]
+    pub frequency: String,
+    #[ts(type = "\"csv\" | \"xlsx\"")]
+    pub format: String,
+    pub delivery_hour_utc: u8,
+}
+
+/// Scheduled report subscription owned by the caller.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/report-subscription-response.ts"
+)]
+pub struct ReportSubscriptionResponse {
+    pub subscription_id: String,
+    pub entity_logical_name: String,
+    pub view_logical_name: String,
+    #[ts(type = "\"daily\" | \"weekly\"")]
+    pub frequency: String,
+    #[ts(type = "\"csv\" | \"xlsx\"")]
+    pub format: String,
+    pub delivery_hour_utc: u8,
+    pub recipient_email: String,
+    pub next_delivery_at: String,
+    pub last_delivered_at: Option<String>,
+    pub suppressed_at: Option<String>,
+    pub suppression_reason: Option<String>,
+    pub created_at: String,
+}
+
+impl From<ReportSubscription> for ReportSubscriptionResponse {
+    fn from(value: ReportSubscription) -> Self {
+        Self {
+            subscription_id: value.subscription_id,
+            entity_logical_name: value.entity_logical_name,
+            view_logical_name: value.view_logical_name,
+            frequency: value.frequency.as_str().to_owned(),
+            format: value.format.as_str().to_owned(),
+            delivery_hour_utc: value.delivery_hour_utc,
+            recipient_email: value.recipient_email,
+            next_delivery_at: value.next_delivery_at.to_rfc3339(),
+            last_delivered_at: value
+                .last_delivered_at
+                .map(|timestamp| timestamp.to_rfc3339()),
+            suppressed_at: value.suppressed_at.map(|timestamp| timestamp.to_rfc3339()),
+            suppression_reason: value.suppression_reason,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use qryvanta_application::{
+    ReportingProjection, ReportingProjectionColumn, ReportingProjectionRow,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ts_rs::TS;
+
+/// Requested column of a reporting projection.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/reporting-projection-column-request.ts"
+)]
+pub struct ReportingProjectionColumnRequest {
+    pub column_name: String,
+    pub relation_field_logical_name: Option<String>,
+    pub field_logical_name: String,
+}
+
+/// Incoming payload for creating or redefining a reporting projection.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-reporting-projection-request.ts"
+)]
+pub struct SaveReportingProjectionRequest {
+    pub display_name: String,
+    pub root_entity_logical_name: String,
+    pub columns: Vec<ReportingProjectionColumnRequest>,
+}
+
+/// Column of a saved reporting projection.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/reporting-projection-column-response.ts"
+)]
+pub struct ReportingProjectionColumnResponse {
+    pub column_name: String,
+    pub relation_field_logical_name: Option<String>,
+    pub parent_entity_logical_name: Option<String>,
+    pub field_logical_name: String,
+}
+
+/// Reporting projection definition.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/reporting-projection-response.ts"
+)]
+pub struct ReportingProjectionResponse {
+    pub projection_id: String,
+    pub logical_name: String,
+    pub display_name: String,
+    pub root_entity_logical_name: String,
+    pub source_entity_logical_names: Vec<String>,
+    pub columns: Vec<ReportingProjectionColumnResponse>,
+    pub updated_by_subject: String,
+    pub updated_at: String,
+}
+
+/// Equality filter on one projection column.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/reporting-projection-filter-request.ts"
+)]
+pub struct ReportingProjectionFilterRequest {
+    pub column_name: String,
+    #[ts(type = "unknown")]
+    pub value: Value,
+}
+
+/// Incoming payload for paging through projection rows.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/query-reporting-projection-request.ts"
+)]
+pub struct QueryReportingProjectionRequest {
+    #[serde(default)]
+    pub filters: Vec<ReportingProjectionFilterRequest>,
+    pub sort_column: Option<String>,
+    #[serde(default)]
+    pub sort_descending: bool,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+/// One row of a reporting projection.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/reporting-projection-row-response.ts"
+)]
+pub struct ReportingProjectionRowResponse {
+    pub record_id: String,
+    #[ts(type = "Record<string, unknown>")]
+    pub values: Value,
+    pub refreshed_at: String,
+}
+
+impl From<ReportingProjectionColumn> for ReportingProjectionColumnResponse {
+    fn from(value: ReportingProjectionColumn) -> Self {
+        Self {
+            column_name: value.column_name,
+            relation_field_logical_name: value.relation_field_logical_name,
+            parent_entity_logical_name: value.parent_entity_logical_name,
+            field_logical_name: value.field_logical_name,
+        }
+    }
+}
+
+impl From<ReportingProjection> for ReportingProjectionResponse {
+    fn from(value: ReportingProjection) -> Self {
+        Self {
+            source_entity_logical_names: value.source_entity_logical_names(),
+            projection_id: value.projection_id,
+            logical_name: value.logical_name,
+            display_name: value.display_name,
+            root_entity_logical_name: value.root_entity_logical_name,
+            columns: value
+                .columns
+                .into_iter()
+                .map(ReportingProjectionColumnResponse::from)
+                .collect(),
+            updated_by_subject: value.updated_by_subject,
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<ReportingProjectionRow> for ReportingProjectionRowResponse {
+    fn from(value: ReportingProjectionRow) -> Self {
+        Self {
+            record_id: value.record_id,
+            values: value.values,
+            refreshed_at: value.refreshed_at.to_rfc3339(),
+        }
+    }
+}
@@ -2,10 +2,27 @@ mod conversions;
 mod types;
 
 pub use types::{
-    CreateRuntimeRecordRequest, QueryRuntimeRecordsRequest, RuntimeRecordQueryFilterRequest,
+    AggregateRuntimeRecordsRequest, ApproveRecordAccessRequest, AssignRuntimeRecordOwnerRequest,
+    AssociateRuntimeRecordRequest, BulkUpdateRuntimeRecordsRequest,
+    BulkUpdateRuntimeRecordsResponse, CreateRecordShareLinkRequest, CreateRuntimeRecordRequest,
+    CreatedRecordShareLinkResponse, ExecuteRuntimeRecordChangesetRequest,
+    PendingFieldChangeResponse, QueryRuntimeRecordsRequest, QuickCreateRuntimeRecordRequest,
+    RecordAccessRequestResponse, RecordShareLinkResponse, RecordShareLinkViewResponse,
+    RecordShareResponse, RelationLookupMatchResponse, RequestRecordAccessRequest,
+    RuntimeRecordAggregateRowResponse, RuntimeRecordChangesetResultResponse,
+    RuntimeRecordHistoryEntryResponse, RuntimeRecordOwnerResponse, RuntimeRecordQueryFilterRequest,
     RuntimeRecordQueryGroupRequest, RuntimeRecordQueryLinkEntityRequest, RuntimeRecordResponse,
-    UpdateRuntimeRecordRequest,
+    RuntimeRecordSlugResponse, RuntimeRecordStatusChangeResponse, RuntimeRecordUpsertResponse,
+    UpdateRuntimeRecordRequest, UpsertRuntimeRecordRequest,
 };
 
 #[cfg(test)]
-pub use types::RuntimeRecordQuerySortRequest;
+pub use types::{
+    BulkUpdateRuntimeRecordResultResponse, RuntimeRecordAggregateHavingRequest,
+    RuntimeRecordAggregateRequest, RuntimeRecordAggregateSortRequest,
+    RuntimeRecordChangesetOperationRequest, RuntimeRecordFieldChangeResponse,
+    RuntimeRecordQuerySortRequest,
+};
+
+#[cfg(test)]
+pub use types::RelationCascadeResponse;
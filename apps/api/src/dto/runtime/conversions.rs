@@ -1,6 +1,16 @@
 use qryvanta_domain::RuntimeRecord;
 
-use super::types::RuntimeRecordResponse;
+use crate::error::ErrorResponse;
+
+use super::types::{
+    BulkUpdateRuntimeRecordResultResponse, BulkUpdateRuntimeRecordsResponse,
+    CreatedRecordShareLinkResponse, PendingFieldChangeResponse, RecordAccessRequestResponse,
+    RecordShareLinkResponse, RecordShareLinkViewResponse, RecordShareResponse,
+    RelationCascadeResponse, RelationLookupMatchResponse, RuntimeRecordAggregateRowResponse,
+    RuntimeRecordChangesetResultResponse, RuntimeRecordFieldChangeResponse,
+    RuntimeRecordHistoryEntryResponse, RuntimeRecordOwnerResponse, RuntimeRecordResponse,
+    RuntimeRecordStatusChangeResponse, RuntimeRecordUpsertResponse,
+};
 
 impl From<RuntimeRecord> for RuntimeRecordResponse {
     fn from(value: RuntimeRecord) -> Self {
@@ -8,6 +18,244 @@ impl From<RuntimeRecord> for RuntimeRecordResponse {
             record_id: value.record_id().as_str().to_owned(),
             entity_logical_name: value.entity_logical_name().as_str().to_owned(),
             data: value.data().clone(),
+            version: value.version(),
+        }
+    }
+}
+
+impl From<qryvanta_application::RuntimeRecordAggregateRow> for RuntimeRecordAggregateRowResponse {
+    fn from(value: qryvanta_application::RuntimeRecordAggregateRow) -> Self {
+        Self {
+            group: value.group,
+            values: value.values,
+        }
+    }
+}
+
+impl From<qryvanta_application::RuntimeRecordChangesetResult>
+    for RuntimeRecordChangesetResultResponse
+{
+    fn from(value: qryvanta_application::RuntimeRecordChangesetResult) -> Self {
+        Self {
+            content_id: value.content_id,
+            method: value.method.as_str().to_owned(),
+            record: RuntimeRecordResponse::from(value.record),
+        }
+    }
+}
+
+impl From<qryvanta_application::RuntimeRecordBulkUpdateResult>
+    for BulkUpdateRuntimeRecordsResponse
+{
+    fn from(value: qryvanta_application::RuntimeRecordBulkUpdateResult) -> Self {
+        let updated = value.updated_count();
+        let failed = value.failed_count();
+        let results = value
+            .items
+            .into_iter()
+            .map(|item| match item.outcome {
+                Ok(record) => BulkUpdateRuntimeRecordResultResponse {
+                    record_id: item.record_id,
+                    status: "updated".to_owned(),
+                    record: Some(RuntimeRecordResponse::from(record)),
+                    error: None,
+                },
+                Err(error) => BulkUpdateRuntimeRecordResultResponse {
+                    record_id: item.record_id,
+                    status: "failed".to_owned(),
+                    record: None,
+                    error: Some(ErrorResponse::from(&error)),
+                },
+            })
+            .collect();
+
+        Self {
+            updated,
+            failed,
+            results,
+        }
+    }
+}
+
+impl From<qryvanta_application::RuntimeRecordUpsertResult> for RuntimeRecordUpsertResponse {
+    fn from(value: qryvanta_application::RuntimeRecordUpsertResult) -> Self {
+        Self {
+            outcome: value.outcome.as_str().to_owned(),
+            record: RuntimeRecordResponse::from(value.record),
+        }
+    }
+}
+
+impl From<qryvanta_application::RuntimeRecordOwnerAssignment> for RuntimeRecordOwnerResponse {
+    fn from(value: qryvanta_application::RuntimeRecordOwnerAssignment) -> Self {
+        Self {
+            entity_logical_name: value.entity_logical_name,
+            record_id: value.record_id,
+            previous_owner_subject: value.previous_owner_subject,
+            owner_subject: value.owner_subject,
+            cascades: value
+                .cascades
+                .into_iter()
+                .map(RelationCascadeResponse::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<qryvanta_application::RelationCascadeResult> for RelationCascadeResponse {
+    fn from(value: qryvanta_application::RelationCascadeResult) -> Self {
+        Self {
+            entity_logical_name: value.entity_logical_name,
+            field_logical_name: value.field_logical_name,
+            affected_records: value.affected_records,
+        }
+    }
+}
+
+impl From<qryvanta_application::PendingFieldChange> for PendingFieldChangeResponse {
+    fn from(value: qryvanta_application::PendingFieldChange) -> Self {
+        Self {
+            change_id: value.change_id,
+            entity_logical_name: value.entity_logical_name,
+            record_id: value.record_id,
+            field_logical_name: value.field_logical_name,
+            previous_value: value.previous_value,
+            proposed_value: value.proposed_value,
+            requested_by_subject: value.requested_by_subject,
+            requested_at: value.requested_at.to_rfc3339(),
+            expires_at: value.expires_at.to_rfc3339(),
+            status: value.status.as_str().to_owned(),
+            decided_by_subject: value.decided_by_subject,
+            decided_at: value.decided_at.map(|timestamp| timestamp.to_rfc3339()),
+        }
+    }
+}
+
+impl From<qryvanta_application::RelationLookupMatch> for RelationLookupMatchResponse {
+    fn from(value: qryvanta_application::RelationLookupMatch) -> Self {
+        Self {
+            record_id: value.record.record_id().as_str().to_owned(),
+            label: value.label,
+            data: value.record.data().clone(),
+        }
+    }
+}
+
+impl From<qryvanta_application::RuntimeRecordStatusChange> for RuntimeRecordStatusChangeResponse {
+    fn from(value: qryvanta_application::RuntimeRecordStatusChange) -> Self {
+        Self {
+            change_id: value.change_id,
+            entity_logical_name: value.entity_logical_name,
+            record_id: value.record_id,
+            from_status: value.from_status,
+            to_status: value.to_status,
+            from_reason: value.from_reason,
+            to_reason: value.to_reason,
+            changed_by_subject: value.changed_by_subject,
+            changed_at: value.changed_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<qryvanta_application::RuntimeRecordHistoryEntry> for RuntimeRecordHistoryEntryResponse {
+    fn from(value: qryvanta_application::RuntimeRecordHistoryEntry) -> Self {
+        Self {
+            entry_id: value.entry_id,
+            entity_logical_name: value.entity_logical_name,
+            record_id: value.record_id,
+            record_version: value.record_version,
+            changes: value
+                .changes
+                .into_iter()
+                .map(|change| RuntimeRecordFieldChangeResponse {
+                    field_logical_name: change.field_logical_name,
+                    old_value: change.old_value,
+                    new_value: change.new_value,
+                })
+                .collect(),
+            changed_by_subject: value.changed_by_subject,
+            changed_at: value.changed_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<qryvanta_application::RecordAccessRequest> for RecordAccessRequestResponse {
+    fn from(value: qryvanta_application::RecordAccessRequest) -> Self {
+        Self {
+            request_id: value.request_id,
+            entity_logical_name: value.entity_logical_name,
+            record_id: value.record_id,
+            requested_by_subject: value.requested_by_subject,
+            reason: value.reason,
+            duration_hours: value.duration_hours,
+            routed_to_subject: value.routed_to_subject,
+            status: value.status.as_str().to_owned(),
+            requested_at: value.requested_at.to_rfc3339(),
+            decided_by_subject: value.decided_by_subject,
+            decided_at: value.decided_at.map(|timestamp| timestamp.to_rfc3339()),
+            share_id: value.share_id,
+        }
+    }
+}
+
+impl From<qryvanta_application::RecordShare> for RecordShareResponse {
+    fn from(value: qryvanta_application::RecordShare) -> Self {
+        Self {
+            share_id: value.share_id,
+            entity_logical_name: value.entity_logical_name,
+            record_id: value.record_id,
+            subject: value.subject,
+            request_id: value.request_id,
+            granted_by_subject: value.granted_by_subject,
+            created_at: value.created_at.to_rfc3339(),
+            expires_at: value.expires_at.to_rfc3339(),
+            revoked_by_subject: value.revoked_by_subject,
+            revoked_at: value.revoked_at.map(|timestamp| timestamp.to_rfc3339()),
+        }
+    }
+}
+
+impl From<qryvanta_application::RecordShareLink> for RecordShareLinkResponse {
+    fn from(value: qryvanta_application::RecordShareLink) -> Self {
+        let password_protected = value.requires_password();
+        Self {
+            link_id: value.link_id,
+            entity_logical_name: value.entity_logical_name,
+            record_id: value.record_id,
+            field_logical_names: value.field_logical_names,
+            password_protected,
+            created_by_subject: value.created_by_subject,
+            created_at: value.created_at.to_rfc3339(),
+            expires_at: value.expires_at.to_rfc3339(),
+            revoked_by_subject: value.revoked_by_subject,
+            revoked_at: value.revoked_at.map(|timestamp| timestamp.to_rfc3339()),
+            view_count: value.view_count,
+            last_viewed_at: value.last_viewed_at.map(|timestamp| timestamp.to_rfc3339()),
+        }
+    }
+}
+
+impl From<qryvanta_application::CreatedRecordShareLink> for CreatedRecordShareLinkResponse {
+    fn from(value: qryvanta_application::CreatedRecordShareLink) -> Self {
+        let share_path = format!(
+            "/api/public/record-links/{}/{}",
+            value.link.tenant_id, value.token
+        );
+        Self {
+            link: RecordShareLinkResponse::from(value.link),
+            share_path,
+        }
+    }
+}
+
+impl From<qryvanta_application::RecordShareLinkView> for RecordShareLinkViewResponse {
+    fn from(value: qryvanta_application::RecordShareLinkView) -> Self {
+        Self {
+            link_id: value.link_id,
+            outcome: value.outcome.as_str().to_owned(),
+            ip_address: value.ip_address,
+            user_agent: value.user_agent,
+            viewed_at: value.viewed_at.to_rfc3339(),
         }
     }
 }
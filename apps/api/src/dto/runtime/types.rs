@@ -3,31 +3,147 @@ use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::error::ErrorResponse;
 
 /// Incoming runtime record create payload.
-#[derive(Debug, Deserialize, TS)]
+#[derive(Debug, Deserialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/create-runtime-record-request.ts"
 )]
 pub struct CreateRuntimeRecordRequest {
     #[ts(type = "Record<string, unknown>")]
+    #[schema(value_type = Object)]
+    pub data: Value,
+    /// Confirms the write despite matches of warning duplicate rules.
+    #[serde(default)]
+    #[ts(optional)]
+    pub ignore_duplicate_warnings: Option<bool>,
+}
+
+/// Incoming runtime record quick-create payload.
+#[derive(Debug, Deserialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/quick-create-runtime-record-request.ts"
+)]
+pub struct QuickCreateRuntimeRecordRequest {
+    /// Optional published quick-create form; defaults to the entity's first one.
+    #[serde(default)]
+    pub form_logical_name: Option<String>,
+    #[ts(type = "Record<string, unknown>")]
+    #[schema(value_type = Object)]
     pub data: Value,
 }
 
 /// Incoming runtime record update payload.
-#[derive(Debug, Deserialize, TS)]
+#[derive(Debug, Deserialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/update-runtime-record-request.ts"
 )]
 pub struct UpdateRuntimeRecordRequest {
     #[ts(type = "Record<string, unknown>")]
+    #[schema(value_type = Object)]
     pub data: Value,
+    /// Version the client last read; a stale version fails with a conflict.
+    #[serde(default)]
+    #[ts(type = "number | null")]
+    pub expected_version: Option<i64>,
+    /// Confirms the write despite matches of warning duplicate rules.
+    #[serde(default)]
+    #[ts(optional)]
+    pub ignore_duplicate_warnings: Option<bool>,
 }
 
-/// Incoming runtime record query payload.
+/// Incoming runtime record bulk edit payload.
+#[derive(Debug, Deserialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/bulk-update-runtime-records-request.ts"
+)]
+pub struct BulkUpdateRuntimeRecordsRequest {
+    /// Records selected in the view.
+    pub record_ids: Vec<String>,
+    /// Field values applied to every selected record; other fields keep their values.
+    #[ts(type = "Record<string, unknown>")]
+    #[schema(value_type = Object)]
+    pub values: Value,
+}
+
+/// Incoming runtime record upsert-by-alternate-key payload.
+#[derive(Debug, Deserialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/upsert-runtime-record-request.ts"
+)]
+pub struct UpsertRuntimeRecordRequest {
+    /// Record payload; the key field may be omitted and is set from the path.
+    #[ts(type = "Record<string, unknown>")]
+    #[schema(value_type = Object)]
+    pub data: Value,
+    /// Confirms the write despite matches of warning duplicate rules.
+    #[serde(default)]
+    #[ts(optional)]
+    pub ignore_duplicate_warnings: Option<bool>,
+}
+
+/// Incoming multi-entity runtime record changeset payload.
+#[derive(Debug, Deserialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/execute-runtime-record-changeset-request.ts"
+)]
+pub struct ExecuteRuntimeRecordChangesetRequest {
+    pub operations: Vec<RuntimeRecordChangesetOperationRequest>,
+}
+
+/// One operation in a runtime record changeset.
+///
+/// String values equal to `$<content_id>` resolve to the record id created by
+/// an earlier operation with that content id.
+#[derive(Debug, Deserialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/runtime-record-changeset-operation-request.ts"
+)]
+pub struct RuntimeRecordChangesetOperationRequest {
+    #[serde(default)]
+    pub content_id: Option<String>,
+    #[ts(type = "\"create\" | \"update\"")]
+    pub method: String,
+    pub entity_logical_name: String,
+    #[serde(default)]
+    pub record_id: Option<String>,
+    #[ts(type = "Record<string, unknown>")]
+    #[schema(value_type = Object)]
+    pub data: Value,
+}
+
+/// Incoming runtime record owner reassignment payload.
 #[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/assign-runtime-record-owner-request.ts"
+)]
+pub struct AssignRuntimeRecordOwnerRequest {
+    pub owner_subject: String,
+}
+
+/// Incoming many-to-many record association payload.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/associate-runtime-record-request.ts"
+)]
+pub struct AssociateRuntimeRecordRequest {
+    pub target_record_id: String,
+}
+
+/// Incoming runtime record query payload.
+#[derive(Debug, Deserialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/runtime-record-query-filter-request.ts"
@@ -36,14 +152,17 @@ pub struct RuntimeRecordQueryFilterRequest {
     #[ts(type = "string | null")]
     pub scope_alias: Option<String>,
     pub field_logical_name: String,
-    #[ts(type = "\"eq\" | \"neq\" | \"gt\" | \"gte\" | \"lt\" | \"lte\" | \"contains\" | \"in\"")]
+    #[ts(
+        type = "\"eq\" | \"neq\" | \"gt\" | \"gte\" | \"lt\" | \"lte\" | \"contains\" | \"in\" | \"not_in\" | \"is_null\" | \"is_not_null\" | \"between\" | \"begins_with\" | \"today\" | \"yesterday\" | \"this_month\" | \"last_n_days\""
+    )]
     pub operator: String,
+    #[serde(default)]
     #[ts(type = "unknown")]
     pub field_value: Value,
 }
 
 /// Incoming runtime query where-clause group payload.
-#[derive(Debug, Deserialize, TS)]
+#[derive(Debug, Deserialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/runtime-record-query-group-request.ts"
@@ -52,11 +171,12 @@ pub struct RuntimeRecordQueryGroupRequest {
     #[ts(type = "\"and\" | \"or\" | null")]
     pub logical_mode: Option<String>,
     pub conditions: Option<Vec<RuntimeRecordQueryFilterRequest>>,
+    #[schema(no_recursion)]
     pub groups: Option<Vec<RuntimeRecordQueryGroupRequest>>,
 }
 
 /// Incoming runtime query link-entity payload.
-#[derive(Debug, Deserialize, TS)]
+#[derive(Debug, Deserialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/runtime-record-query-link-entity-request.ts"
@@ -71,7 +191,7 @@ pub struct RuntimeRecordQueryLinkEntityRequest {
 }
 
 /// Incoming runtime record query sort payload.
-#[derive(Debug, Deserialize, TS)]
+#[derive(Debug, Deserialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/runtime-record-query-sort-request.ts"
@@ -85,7 +205,7 @@ pub struct RuntimeRecordQuerySortRequest {
 }
 
 /// Incoming runtime record query payload.
-#[derive(Debug, Deserialize, TS)]
+#[derive(Debug, Deserialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/query-runtime-records-request.ts"
@@ -103,10 +223,89 @@ pub struct QueryRuntimeRecordsRequest {
     /// Legacy exact-match map; converted to `eq` conditions when present.
     #[ts(type = "Record<string, unknown> | null")]
     pub filters: Option<BTreeMap<String, Value>>,
+    /// Returns records in inactive statuses too; defaults to `false`.
+    pub include_inactive: Option<bool>,
+}
+
+/// Incoming runtime record aggregate query payload.
+#[derive(Debug, Deserialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/aggregate-runtime-records-request.ts"
+)]
+pub struct AggregateRuntimeRecordsRequest {
+    /// Maximum number of groups returned after sorting.
+    pub limit: Option<usize>,
+    #[ts(type = "\"and\" | \"or\" | null")]
+    pub logical_mode: Option<String>,
+    #[serde(rename = "where")]
+    pub where_clause: Option<RuntimeRecordQueryGroupRequest>,
+    pub conditions: Option<Vec<RuntimeRecordQueryFilterRequest>>,
+    /// Grouped field logical names; omitted to aggregate all matching records.
+    pub group_by: Option<Vec<String>>,
+    pub aggregates: Vec<RuntimeRecordAggregateRequest>,
+    pub having: Option<Vec<RuntimeRecordAggregateHavingRequest>>,
+    pub sort: Option<Vec<RuntimeRecordAggregateSortRequest>>,
+}
+
+/// One named aggregate in a runtime record aggregate query.
+#[derive(Debug, Deserialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/runtime-record-aggregate-request.ts"
+)]
+pub struct RuntimeRecordAggregateRequest {
+    pub alias: String,
+    #[ts(type = "\"count\" | \"sum\" | \"avg\" | \"min\" | \"max\"")]
+    pub function: String,
+    /// Aggregated field; omitted for `count` to count records.
+    #[serde(default)]
+    pub field_logical_name: Option<String>,
+}
+
+/// Condition on an aggregate value in a runtime record aggregate query.
+#[derive(Debug, Deserialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/runtime-record-aggregate-having-request.ts"
+)]
+pub struct RuntimeRecordAggregateHavingRequest {
+    pub aggregate_alias: String,
+    #[ts(type = "\"eq\" | \"neq\" | \"gt\" | \"gte\" | \"lt\" | \"lte\"")]
+    pub operator: String,
+    pub value: f64,
+}
+
+/// Sort entry in a runtime record aggregate query; set exactly one of the keys.
+#[derive(Debug, Deserialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/runtime-record-aggregate-sort-request.ts"
+)]
+pub struct RuntimeRecordAggregateSortRequest {
+    #[serde(default)]
+    pub field_logical_name: Option<String>,
+    #[serde(default)]
+    pub aggregate_alias: Option<String>,
+    #[ts(type = "\"asc\" | \"desc\" | null")]
+    pub direction: Option<String>,
+}
+
+/// API representation of one runtime record aggregate group.
+#[derive(Debug, Serialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/runtime-record-aggregate-row-response.ts"
+)]
+pub struct RuntimeRecordAggregateRowResponse {
+    #[ts(type = "Record<string, unknown>")]
+    pub group: BTreeMap<String, Value>,
+    #[ts(type = "Record<string, unknown>")]
+    pub values: BTreeMap<String, Value>,
 }
 
 /// API representation of a runtime record.
-#[derive(Debug, Serialize, TS)]
+#[derive(Debug, Serialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/runtime-record-response.ts"
@@ -115,5 +314,314 @@ pub struct RuntimeRecordResponse {
     pub record_id: String,
     pub entity_logical_name: String,
     #[ts(type = "Record<string, unknown>")]
+    #[schema(value_type = Object)]
+    pub data: Value,
+    /// Record version, incremented on every data change.
+    #[ts(type = "number")]
+    pub version: i64,
+}
+
+/// API representation of a runtime record upsert by alternate key.
+#[derive(Debug, Serialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/runtime-record-upsert-response.ts"
+)]
+pub struct RuntimeRecordUpsertResponse {
+    #[ts(type = "\"created\" | \"updated\"")]
+    pub outcome: String,
+    pub record: RuntimeRecordResponse,
+}
+
+/// API representation of one record's bulk edit outcome.
+#[derive(Debug, Serialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/bulk-update-runtime-record-result-response.ts"
+)]
+pub struct BulkUpdateRuntimeRecordResultResponse {
+    pub record_id: String,
+    #[ts(type = "\"updated\" | \"failed\"")]
+    pub status: String,
+    pub record: Option<RuntimeRecordResponse>,
+    pub error: Option<ErrorResponse>,
+}
+
+/// API representation of a runtime record bulk edit.
+#[derive(Debug, Serialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/bulk-update-runtime-records-response.ts"
+)]
+pub struct BulkUpdateRuntimeRecordsResponse {
+    pub updated: usize,
+    pub failed: usize,
+    pub results: Vec<BulkUpdateRuntimeRecordResultResponse>,
+}
+
+/// API representation of a resolved runtime record slug.
+#[derive(Debug, Serialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/runtime-record-slug-response.ts"
+)]
+pub struct RuntimeRecordSlugResponse {
+    pub entity_logical_name: String,
+    pub slug: String,
+    pub record_id: String,
+}
+
+/// API representation of one applied runtime record changeset operation.
+#[derive(Debug, Serialize, TS, ToSchema)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/runtime-record-changeset-result-response.ts"
+)]
+pub struct RuntimeRecordChangesetResultResponse {
+    pub content_id: Option<String>,
+    #[ts(type = "\"create\" | \"update\"")]
+    pub method: String,
+    pub record: RuntimeRecordResponse,
+}
+
+/// API representation of a runtime record ownership transfer.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/runtime-record-owner-response.ts"
+)]
+pub struct RuntimeRecordOwnerResponse {
+    pub entity_logical_name: String,
+    pub record_id: String,
+    pub previous_owner_subject: String,
+    pub owner_subject: String,
+    pub cascades: Vec<RelationCascadeResponse>,
+}
+
+/// API representation of child records reached through one relation cascade.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/relation-cascade-response.ts"
+)]
+pub struct RelationCascadeResponse {
+    pub entity_logical_name: String,
+    pub field_logical_name: String,
+    #[ts(type = "number")]
+    pub affected_records: u64,
+}
+
+/// API representation of a dual-control field change awaiting or past approval.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/pending-field-change-response.ts"
+)]
+pub struct PendingFieldChangeResponse {
+    pub change_id: String,
+    pub entity_logical_name: String,
+    pub record_id: String,
+    pub field_logical_name: String,
+    #[ts(type = "unknown")]
+    pub previous_value: Option<Value>,
+    #[ts(type = "unknown")]
+    pub proposed_value: Option<Value>,
+    pub requested_by_subject: String,
+    pub requested_at: String,
+    pub expires_at: String,
+    #[ts(type = "\"pending\" | \"approved\" | \"rejected\" | \"expired\"")]
+    pub status: String,
+    pub decided_by_subject: Option<String>,
+    pub decided_at: Option<String>,
+}
+
+/// API representation of one relation lookup search result.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/relation-lookup-match-response.ts"
+)]
+pub struct RelationLookupMatchResponse {
+    pub record_id: String,
+    pub label: String,
+    #[ts(type = "Record<string, unknown>")]
     pub data: Value,
 }
+
+/// API representation of one entry of a runtime record's status history.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/runtime-record-status-change-response.ts"
+)]
+pub struct RuntimeRecordStatusChangeResponse {
+    pub change_id: String,
+    pub entity_logical_name: String,
+    pub record_id: String,
+    pub from_status: Option<String>,
+    pub to_status: String,
+    pub from_reason: Option<String>,
+    pub to_reason: Option<String>,
+    pub changed_by_subject: String,
+    pub changed_at: String,
+}
+
+/// API representation of one field changed by a runtime record update.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/runtime-record-field-change-response.ts"
+)]
+pub struct RuntimeRecordFieldChangeResponse {
+    pub field_logical_name: String,
+    /// Value before the update; omitted when the field was unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional, type = "unknown")]
+    pub old_value: Option<Value>,
+    /// Value after the update; omitted when the field was cleared.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional, type = "unknown")]
+    pub new_value: Option<Value>,
+}
+
+/// API representation of one entry of a runtime record's field history.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/runtime-record-history-entry-response.ts"
+)]
+pub struct RuntimeRecordHistoryEntryResponse {
+    pub entry_id: String,
+    pub entity_logical_name: String,
+    pub record_id: String,
+    #[ts(type = "number")]
+    pub record_version: i64,
+    pub changes: Vec<RuntimeRecordFieldChangeResponse>,
+    pub changed_by_subject: String,
+    pub changed_at: String,
+}
+
+/// Incoming payload for requesting access to a runtime record.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/request-record-access-request.ts"
+)]
+pub struct RequestRecordAccessRequest {
+    pub reason: String,
+    pub duration_hours: Option<u16>,
+}
+
+/// Incoming payload for approving a record access request.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/approve-record-access-request.ts"
+)]
+pub struct ApproveRecordAccessRequest {
+    pub duration_hours: Option<u16>,
+}
+
+/// API representation of a record access request.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/record-access-request-response.ts"
+)]
+pub struct RecordAccessRequestResponse {
+    pub request_id: String,
+    pub entity_logical_name: String,
+    pub record_id: String,
+    pub requested_by_subject: String,
+    pub reason: String,
+    pub duration_hours: u16,
+    pub routed_to_subject: String,
+    #[ts(type = "\"pending\" | \"approved\" | \"rejected\"")]
+    pub status: String,
+    pub requested_at: String,
+    pub decided_by_subject: Option<String>,
+    pub decided_at: Option<String>,
+    pub share_id: Option<String>,
+}
+
+/// API representation of a time-boxed record share.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/record-share-response.ts"
+)]
+pub struct RecordShareResponse {
+    pub share_id: String,
+    pub entity_logical_name: String,
+    pub record_id: String,
+    pub subject: String,
+    pub request_id: String,
+    pub granted_by_subject: String,
+    pub created_at: String,
+    pub expires_at: String,
+    pub revoked_by_subject: Option<String>,
+    pub revoked_at: Option<String>,
+}
+
+/// Incoming payload for creating an external share link for a runtime record.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/create-record-share-link-request.ts"
+)]
+pub struct CreateRecordShareLinkRequest {
+    pub field_logical_names: Vec<String>,
+    pub expires_in_hours: Option<u16>,
+    pub password: Option<String>,
+}
+
+/// API representation of an external record share link.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/record-share-link-response.ts"
+)]
+pub struct RecordShareLinkResponse {
+    pub link_id: String,
+    pub entity_logical_name: String,
+    pub record_id: String,
+    pub field_logical_names: Vec<String>,
+    pub password_protected: bool,
+    pub created_by_subject: String,
+    pub created_at: String,
+    pub expires_at: String,
+    pub revoked_by_subject: Option<String>,
+    pub revoked_at: Option<String>,
+    pub view_count: i64,
+    pub last_viewed_at: Option<String>,
+}
+
+/// API representation of a newly created share link.
+///
+/// `share_path` embeds the raw token and is only returned once.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/created-record-share-link-response.ts"
+)]
+pub struct CreatedRecordShareLinkResponse {
+    pub link: RecordShareLinkResponse,
+    pub share_path: String,
+}
+
+/// API representation of one external access attempt on a share link.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/record-share-link-view-response.ts"
+)]
+pub struct RecordShareLinkViewResponse {
+    pub link_id: String,
+    #[ts(
+        type = "\"viewed\" | \"password_required\" | \"invalid_password\" | \"expired\" | \"revoked\" | \"unavailable\""
+    )]
+    pub outcome: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub viewed_at: String,
+}
@@ -2,14 +2,28 @@ mod conversions;
 mod types;
 
 pub use types::{
-    AssignRoleRequest, AuditIntegrityStatusResponse, AuditLogEntryResponse,
-    AuditPurgeResultResponse, AuditRetentionPolicyResponse, CreateRoleRequest,
-    CreateTemporaryAccessGrantRequest, RemoveRoleAssignmentRequest,
+    AccessExplanationResponse, AddSecurityTeamMemberRequest, AssignComplianceZoneRequest,
+    AssignRoleRequest, AuditChainAnchorResponse, AuditExportSinkResponse,
+    AuditIntegrityStatusResponse, AuditLogEntryResponse, AuditPurgeResultResponse,
+    AuditRetentionPolicyResponse, ComplianceZoneAssignmentResponse, ComplianceZoneTagResponse,
+    CreateAuditExportSinkRequest, CreateLegalHoldRequest, CreateMfaResetRequest, CreateRoleRequest,
+    CreateSecurityTeamRequest, CreateTemporaryAccessGrantRequest, DataErasureFieldResponse,
+    DataErasureReportResponse, DualControlFieldResponse, EraseSubjectDataRequest,
+    EvaluatedFeatureFlagResponse, FeatureFlagResponse, FieldMaskingRuleResponse, LegalHoldResponse,
+    MfaResetRequestResponse, PermissionCatalogGroupResponse, RemoveRoleAssignmentRequest,
     RevokeTemporaryAccessGrantRequest, RoleAssignmentResponse, RoleResponse,
-    RuntimeFieldPermissionResponse, SaveRuntimeFieldPermissionsRequest,
-    TemporaryAccessGrantResponse, TenantRegistrationModeResponse,
+    RuntimeFieldPermissionResponse, SaveComplianceZoneTagRequest, SaveDataErasureFieldsRequest,
+    SaveDualControlFieldsRequest, SaveFeatureFlagRequest, SaveFieldMaskingRulesRequest,
+    SaveRuntimeFieldPermissionsRequest, SecurityTeamMemberResponse, SecurityTeamResponse,
+    ShredTenantEncryptionKeysRequest, ShredTenantEncryptionKeysResponse,
+    TemporaryAccessGrantResponse, TenantEncryptionKeyRequest, TenantEncryptionKeyResponse,
+    TenantRegistrationModeResponse, UpdateAuditExportSinkRequest,
     UpdateAuditRetentionPolicyRequest, UpdateTenantRegistrationModeRequest,
+    UpdateWorkflowConcurrencyPolicyRequest, WorkflowConcurrencyPolicyResponse,
 };
 
 #[cfg(test)]
-pub use types::RuntimeFieldPermissionInputRequest;
+pub use types::{
+    DualControlFieldRequest, FeatureFlagRoleOverrideRequest, FeatureFlagRoleOverrideResponse,
+    FieldMaskingRuleRequest, RuntimeFieldPermissionInputRequest,
+};
@@ -1,9 +1,22 @@
-use qryvanta_domain::RegistrationMode;
+use chrono::{DateTime, Utc};
+use qryvanta_application::{AuditExportSinkConfig, AuditExportSyslogTransport, LegalHoldScope};
+use qryvanta_core::AppError;
+use qryvanta_domain::{Permission, PermissionGroup, RegistrationMode};
 
 use super::types::{
+    AccessExplanationAppPermissionResponse, AccessExplanationFieldGrantResponse,
+    AccessExplanationPermissionCheckResponse, AccessExplanationResponse,
+    AccessExplanationRoleResponse, AuditChainAnchorResponse, AuditExportSinkResponse,
     AuditIntegrityStatusResponse, AuditLogEntryResponse, AuditPurgeResultResponse,
-    AuditRetentionPolicyResponse, RoleAssignmentResponse, RoleResponse,
-    RuntimeFieldPermissionResponse, TemporaryAccessGrantResponse, TenantRegistrationModeResponse,
+    AuditRetentionPolicyResponse, ComplianceZoneAssignmentResponse, ComplianceZoneTagResponse,
+    CreateAuditExportSinkRequest, CreateLegalHoldRequest, DataErasureFieldResponse,
+    DataErasureReportResponse, DualControlFieldResponse, FeatureFlagResponse,
+    FeatureFlagRoleOverrideResponse, FieldMaskingRuleResponse, LegalHoldResponse,
+    MfaResetRequestResponse, PermissionCatalogEntryResponse, PermissionCatalogGroupResponse,
+    RoleAssignmentResponse, RoleResponse, RuntimeFieldPermissionResponse,
+    SaveComplianceZoneTagRequest, SecurityTeamMemberResponse, SecurityTeamResponse,
+    TemporaryAccessGrantResponse, TenantEncryptionKeyResponse, TenantRegistrationModeResponse,
+    WorkflowConcurrencyPolicyResponse,
 };
 
 impl From<qryvanta_application::RoleDefinition> for RoleResponse {
@@ -17,10 +30,47 @@ impl From<qryvanta_application::RoleDefinition> for RoleResponse {
                 .into_iter()
                 .map(|permission| permission.as_str().to_owned())
                 .collect(),
+            localized_label: None,
         }
     }
 }
 
+impl From<Permission> for PermissionCatalogEntryResponse {
+    fn from(value: Permission) -> Self {
+        Self {
+            permission: value.as_str().to_owned(),
+            description: value.description().to_owned(),
+            implies: value
+                .implied_permissions()
+                .iter()
+                .map(|permission| permission.as_str().to_owned())
+                .collect(),
+        }
+    }
+}
+
+impl PermissionCatalogGroupResponse {
+    /// Groups catalog permissions by functional area, skipping empty groups.
+    pub fn group_catalog(permissions: &[Permission]) -> Vec<Self> {
+        PermissionGroup::all()
+            .iter()
+            .filter_map(|group| {
+                let entries = permissions
+                    .iter()
+                    .filter(|permission| permission.group() == *group)
+                    .map(|permission| PermissionCatalogEntryResponse::from(*permission))
+                    .collect::<Vec<_>>();
+
+                (!entries.is_empty()).then(|| Self {
+                    group: group.as_str().to_owned(),
+                    label: group.label().to_owned(),
+                    permissions: entries,
+                })
+            })
+            .collect()
+    }
+}
+
 impl From<qryvanta_application::AuditLogEntry> for AuditLogEntryResponse {
     fn from(value: qryvanta_application::AuditLogEntry) -> Self {
         Self {
@@ -45,14 +95,29 @@ impl From<qryvanta_application::AuditIntegrityStatus> for AuditIntegrityStatusRe
             verified_entries: value.verified_entries,
             latest_chain_position: value.latest_chain_position,
             latest_entry_hash: value.latest_entry_hash,
+            verified_from_anchor: value
+                .verified_from_anchor
+                .map(AuditChainAnchorResponse::from),
             failures: value.failures,
         }
     }
 }
 
+impl From<qryvanta_application::AuditChainAnchor> for AuditChainAnchorResponse {
+    fn from(value: qryvanta_application::AuditChainAnchor) -> Self {
+        Self {
+            chain_position: value.chain_position,
+            entry_hash: value.entry_hash,
+            reason: value.reason.as_str().to_owned(),
+            created_at: value.created_at,
+        }
+    }
+}
+
 impl From<qryvanta_application::RoleAssignment> for RoleAssignmentResponse {
     fn from(value: qryvanta_application::RoleAssignment) -> Self {
         Self {
+            subject_type: value.subject_type.as_str().to_owned(),
             subject: value.subject,
             role_id: value.role_id,
             role_name: value.role_name,
@@ -72,6 +137,7 @@ impl From<RegistrationMode> for TenantRegistrationModeResponse {
 impl From<qryvanta_application::RuntimeFieldPermissionEntry> for RuntimeFieldPermissionResponse {
     fn from(value: qryvanta_application::RuntimeFieldPermissionEntry) -> Self {
         Self {
+            subject_type: value.subject_type.as_str().to_owned(),
             subject: value.subject,
             entity_logical_name: value.entity_logical_name,
             field_logical_name: value.field_logical_name,
@@ -82,6 +148,30 @@ impl From<qryvanta_application::RuntimeFieldPermissionEntry> for RuntimeFieldPer
     }
 }
 
+impl From<qryvanta_application::SecurityTeam> for SecurityTeamResponse {
+    fn from(value: qryvanta_application::SecurityTeam) -> Self {
+        Self {
+            team_id: value.team_id,
+            name: value.name,
+            description: value.description,
+            member_count: value.member_count,
+            created_by_subject: value.created_by_subject,
+            created_at: value.created_at,
+        }
+    }
+}
+
+impl From<qryvanta_application::SecurityTeamMember> for SecurityTeamMemberResponse {
+    fn from(value: qryvanta_application::SecurityTeamMember) -> Self {
+        Self {
+            team_name: value.team_name,
+            subject: value.subject,
+            added_by_subject: value.added_by_subject,
+            added_at: value.added_at,
+        }
+    }
+}
+
 impl From<qryvanta_application::TemporaryAccessGrant> for TemporaryAccessGrantResponse {
     fn from(value: qryvanta_application::TemporaryAccessGrant) -> Self {
         Self {
@@ -100,6 +190,21 @@ impl From<qryvanta_application::TemporaryAccessGrant> for TemporaryAccessGrantRe
     }
 }
 
+impl From<qryvanta_application::MfaResetRequest> for MfaResetRequestResponse {
+    fn from(value: qryvanta_application::MfaResetRequest) -> Self {
+        Self {
+            request_id: value.request_id,
+            subject: value.subject,
+            reason: value.reason,
+            requested_by_subject: value.requested_by_subject,
+            requested_at: value.requested_at,
+            expires_at: value.expires_at,
+            approved_by_subject: value.approved_by_subject,
+            approved_at: value.approved_at,
+        }
+    }
+}
+
 impl From<qryvanta_application::AuditRetentionPolicy> for AuditRetentionPolicyResponse {
     fn from(value: qryvanta_application::AuditRetentionPolicy) -> Self {
         Self {
@@ -108,6 +213,14 @@ impl From<qryvanta_application::AuditRetentionPolicy> for AuditRetentionPolicyRe
     }
 }
 
+impl From<qryvanta_application::WorkflowConcurrencyPolicy> for WorkflowConcurrencyPolicyResponse {
+    fn from(value: qryvanta_application::WorkflowConcurrencyPolicy) -> Self {
+        Self {
+            max_concurrent_jobs: value.max_concurrent_jobs,
+        }
+    }
+}
+
 impl From<qryvanta_application::AuditPurgeResult> for AuditPurgeResultResponse {
     fn from(value: qryvanta_application::AuditPurgeResult) -> Self {
         Self {
@@ -116,3 +229,384 @@ impl From<qryvanta_application::AuditPurgeResult> for AuditPurgeResultResponse {
         }
     }
 }
+
+impl From<qryvanta_application::TenantEncryptionKey> for TenantEncryptionKeyResponse {
+    fn from(value: qryvanta_application::TenantEncryptionKey) -> Self {
+        Self {
+            version: value.version,
+            secret_reference: value.secret_reference,
+            key_fingerprint: value.key_fingerprint,
+            status: value.status.as_str().to_owned(),
+            created_by_subject: value.created_by_subject,
+            created_at: value.created_at.to_rfc3339(),
+            retired_at: value.retired_at.map(|timestamp| timestamp.to_rfc3339()),
+            shredded_at: value.shredded_at.map(|timestamp| timestamp.to_rfc3339()),
+        }
+    }
+}
+
+impl TryFrom<CreateAuditExportSinkRequest> for qryvanta_application::CreateAuditExportSinkInput {
+    type Error = AppError;
+
+    fn try_from(value: CreateAuditExportSinkRequest) -> Result<Self, Self::Error> {
+        let required = |field: Option<String>, name: &str| {
+            field.ok_or_else(|| {
+                AppError::Validation(format!(
+                    "{name} is required for {} audit export sinks",
+                    value.sink_type
+                ))
+            })
+        };
+
+        let config = match value.sink_type.as_str() {
+            "ndjson_file" => AuditExportSinkConfig::NdjsonFile,
+            "s3" => AuditExportSinkConfig::S3 {
+                bucket: required(value.bucket, "bucket")?,
+                prefix: value.prefix,
+            },
+            "syslog" => AuditExportSinkConfig::Syslog {
+                host: required(value.host, "host")?,
+                port: value.port.unwrap_or(514),
+                transport: match value.transport.as_deref().unwrap_or("udp") {
+                    "udp" => AuditExportSyslogTransport::Udp,
+                    "tcp" => AuditExportSyslogTransport::Tcp,
+                    other => {
+                        return Err(AppError::Validation(format!(
+                            "unknown syslog transport '{other}'"
+                        )));
+                    }
+                },
+            },
+            "https_webhook" => AuditExportSinkConfig::HttpsWebhook {
+                url: required(value.url, "url")?,
+                auth_header_name: value.auth_header_name,
+                auth_secret_ref: value.auth_secret_ref,
+            },
+            other => {
+                return Err(AppError::Validation(format!(
+                    "unknown audit export sink_type '{other}'"
+                )));
+            }
+        };
+
+        Ok(Self {
+            name: value.name,
+            config,
+            backfill: value.backfill.unwrap_or(false),
+        })
+    }
+}
+
+impl From<qryvanta_application::AuditExportSink> for AuditExportSinkResponse {
+    fn from(value: qryvanta_application::AuditExportSink) -> Self {
+        let mut response = Self {
+            sink_id: value.sink_id,
+            name: value.name,
+            sink_type: value.config.sink_type().to_owned(),
+            bucket: None,
+            prefix: None,
+            host: None,
+            port: None,
+            transport: None,
+            url: None,
+            auth_header_name: None,
+            auth_secret_ref: None,
+            is_enabled: value.is_enabled,
+            checkpoint_position: value.checkpoint_position,
+            last_exported_at: value
+                .last_exported_at
+                .map(|timestamp| timestamp.to_rfc3339()),
+            last_error: value.last_error,
+            created_by_subject: value.created_by_subject,
+            created_at: value.created_at.to_rfc3339(),
+        };
+
+        match value.config {
+            AuditExportSinkConfig::NdjsonFile => {}
+            AuditExportSinkConfig::S3 { bucket, prefix } => {
+                response.bucket = Some(bucket);
+                response.prefix = prefix;
+            }
+            AuditExportSinkConfig::Syslog {
+                host,
+                port,
+                transport,
+            } => {
+                response.host = Some(host);
+                response.port = Some(port);
+                response.transport = Some(
+                    match transport {
+                        AuditExportSyslogTransport::Udp => "udp",
+                        AuditExportSyslogTransport::Tcp => "tcp",
+                    }
+                    .to_owned(),
+                );
+            }
+            AuditExportSinkConfig::HttpsWebhook {
+                url,
+                auth_header_name,
+                auth_secret_ref,
+            } => {
+                response.url = Some(url);
+                response.auth_header_name = auth_header_name;
+                response.auth_secret_ref = auth_secret_ref;
+            }
+        }
+
+        response
+    }
+}
+
+impl TryFrom<CreateLegalHoldRequest> for qryvanta_application::CreateLegalHoldInput {
+    type Error = AppError;
+
+    fn try_from(value: CreateLegalHoldRequest) -> Result<Self, Self::Error> {
+        let scope = match value.scope_type.as_str() {
+            "runtime_records" => LegalHoldScope::RuntimeRecords {
+                entity_logical_name: value.entity_logical_name.ok_or_else(|| {
+                    AppError::Validation(
+                        "entity_logical_name is required for runtime_records legal holds"
+                            .to_owned(),
+                    )
+                })?,
+                record_ids: value.record_ids.unwrap_or_default(),
+            },
+            "audit_log" => LegalHoldScope::AuditLog {
+                from: parse_legal_hold_timestamp(
+                    "audit_from",
+                    value.audit_from.as_deref().ok_or_else(|| {
+                        AppError::Validation(
+                            "audit_from is required for audit_log legal holds".to_owned(),
+                        )
+                    })?,
+                )?,
+                until: value
+                    .audit_until
+                    .as_deref()
+                    .map(|until| parse_legal_hold_timestamp("audit_until", until))
+                    .transpose()?,
+            },
+            other => {
+                return Err(AppError::Validation(format!(
+                    "unknown legal hold scope_type '{other}'"
+                )));
+            }
+        };
+
+        Ok(Self {
+            name: value.name,
+            reason: value.reason,
+            scope,
+        })
+    }
+}
+
+fn parse_legal_hold_timestamp(field: &str, value: &str) -> Result<DateTime<Utc>, AppError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|error| {
+            AppError::Validation(format!("{field} must be an RFC 3339 timestamp: {error}"))
+        })
+}
+
+impl From<qryvanta_application::LegalHold> for LegalHoldResponse {
+    fn from(value: qryvanta_application::LegalHold) -> Self {
+        let is_active = value.is_active();
+        let scope_type = value.scope.scope_type().to_owned();
+        let (entity_logical_name, record_ids, audit_from, audit_until) = match value.scope {
+            LegalHoldScope::RuntimeRecords {
+                entity_logical_name,
+                record_ids,
+            } => (Some(entity_logical_name), record_ids, None, None),
+            LegalHoldScope::AuditLog { from, until } => (
+                None,
+                Vec::new(),
+                Some(from.to_rfc3339()),
+                until.map(|timestamp| timestamp.to_rfc3339()),
+            ),
+        };
+
+        Self {
+            hold_id: value.hold_id,
+            name: value.name,
+            reason: value.reason,
+            scope_type,
+            entity_logical_name,
+            record_ids,
+            audit_from,
+            audit_until,
+            created_by_subject: value.created_by_subject,
+            created_at: value.created_at.to_rfc3339(),
+            released_by_subject: value.released_by_subject,
+            released_at: value.released_at.map(|timestamp| timestamp.to_rfc3339()),
+            is_active,
+        }
+    }
+}
+
+impl From<qryvanta_application::ComplianceZoneAssignment> for ComplianceZoneAssignmentResponse {
+    fn from(value: qryvanta_application::ComplianceZoneAssignment) -> Self {
+        Self {
+            subject: value.subject,
+            zone: value.zone,
+            assigned_by_subject: value.assigned_by_subject,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<SaveComplianceZoneTagRequest> for qryvanta_application::SaveComplianceZoneTagInput {
+    fn from(value: SaveComplianceZoneTagRequest) -> Self {
+        Self {
+            entity_logical_name: value.entity_logical_name,
+            record_id: value.record_id,
+            zone: value.zone,
+        }
+    }
+}
+
+impl From<qryvanta_application::ComplianceZoneTag> for ComplianceZoneTagResponse {
+    fn from(value: qryvanta_application::ComplianceZoneTag) -> Self {
+        Self {
+            entity_logical_name: value.entity_logical_name,
+            record_id: value.record_id,
+            zone: value.zone,
+            tagged_by_subject: value.tagged_by_subject,
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<qryvanta_application::DualControlField> for DualControlFieldResponse {
+    fn from(value: qryvanta_application::DualControlField) -> Self {
+        Self {
+            entity_logical_name: value.entity_logical_name,
+            field_logical_name: value.field_logical_name,
+            approval_window_hours: value.approval_window_hours,
+            updated_by_subject: value.updated_by_subject,
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<qryvanta_application::DataErasureField> for DataErasureFieldResponse {
+    fn from(value: qryvanta_application::DataErasureField) -> Self {
+        Self {
+            entity_logical_name: value.entity_logical_name,
+            field_logical_name: value.field_logical_name,
+            updated_by_subject: value.updated_by_subject,
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<qryvanta_application::FieldMaskingRule> for FieldMaskingRuleResponse {
+    fn from(value: qryvanta_application::FieldMaskingRule) -> Self {
+        Self {
+            role_name: value.role_name,
+            classification: value.classification.as_str().to_owned(),
+            masking_mode: value.masking_mode.as_str().to_owned(),
+            updated_by_subject: value.updated_by_subject,
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<qryvanta_application::FeatureFlag> for FeatureFlagResponse {
+    fn from(value: qryvanta_application::FeatureFlag) -> Self {
+        Self {
+            flag_key: value.flag_key,
+            description: value.description,
+            enabled: value.enabled,
+            role_overrides: value
+                .role_overrides
+                .into_iter()
+                .map(|role_override| FeatureFlagRoleOverrideResponse {
+                    role_name: role_override.role_name,
+                    enabled: role_override.enabled,
+                })
+                .collect(),
+            updated_by_subject: value.updated_by_subject,
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<qryvanta_application::DataErasureReport> for DataErasureReportResponse {
+    fn from(value: qryvanta_application::DataErasureReport) -> Self {
+        Self {
+            pseudonym: value.pseudonym,
+            contact_record_id: value.outcome.contact_record_id,
+            records_pseudonymized: value.outcome.records_pseudonymized,
+            records_erased: value.outcome.records_erased,
+            records_held: value.outcome.records_held,
+            history_entries_scrubbed: value.outcome.history_entries_scrubbed,
+            audit_entries_pseudonymized: value.outcome.audit_entries_pseudonymized,
+            audit_entries_retained: value.outcome.audit_entries_retained,
+            auth_events_anonymized: value.auth_events_anonymized,
+            auth_events_retained: value.auth_events_retained,
+            erased_at: value.erased_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<qryvanta_application::AccessExplanation> for AccessExplanationResponse {
+    fn from(value: qryvanta_application::AccessExplanation) -> Self {
+        Self {
+            subject: value.subject,
+            entity_logical_name: value.entity_logical_name,
+            action: value.action.as_str().to_owned(),
+            decision: value.decision.as_str().to_owned(),
+            roles: value
+                .roles
+                .into_iter()
+                .map(|role| AccessExplanationRoleResponse {
+                    role_name: role.role_name,
+                    team_name: role.team_name,
+                    permissions: role
+                        .permissions
+                        .iter()
+                        .map(|permission| permission.as_str().to_owned())
+                        .collect(),
+                })
+                .collect(),
+            permission_checks: value
+                .permission_checks
+                .into_iter()
+                .map(|check| AccessExplanationPermissionCheckResponse {
+                    permission: check.permission.as_str().to_owned(),
+                    granted: check.is_granted(),
+                    granting_roles: check.granting_roles,
+                    temporary_grant_id: check
+                        .temporary_grant
+                        .as_ref()
+                        .map(|grant| grant.grant_id.clone()),
+                    temporary_grant_expires_at: check.temporary_grant.map(|grant| grant.expires_at),
+                })
+                .collect(),
+            app_permissions: value
+                .app_permissions
+                .into_iter()
+                .map(|permission| AccessExplanationAppPermissionResponse {
+                    app_logical_name: permission.app_logical_name,
+                    role_name: permission.role_name,
+                    app_accessible: permission.app_accessible,
+                    can_read: permission.can_read,
+                    can_create: permission.can_create,
+                    can_update: permission.can_update,
+                    can_delete: permission.can_delete,
+                })
+                .collect(),
+            field_grants: value
+                .field_grants
+                .into_iter()
+                .map(|grant| AccessExplanationFieldGrantResponse {
+                    field_logical_name: grant.field_logical_name,
+                    can_read: grant.can_read,
+                    can_write: grant.can_write,
+                })
+                .collect(),
+            trace: value.trace,
+        }
+    }
+}
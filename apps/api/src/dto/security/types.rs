@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use crate::dto::LocalizedLabelResponse;
+
 /// Incoming payload for custom role creation.
 #[derive(Debug, Deserialize, TS)]
 #[ts(
@@ -19,6 +21,9 @@ pub struct CreateRoleRequest {
     export_to = "../../../packages/api-types/src/generated/assign-role-request.ts"
 )]
 pub struct AssignRoleRequest {
+    /// `user` (default) or `team`; team subjects are addressed by team name.
+    #[serde(default)]
+    pub subject_type: Option<String>,
     pub subject: String,
     pub role_name: String,
 }
@@ -30,6 +35,8 @@ pub struct AssignRoleRequest {
     export_to = "../../../packages/api-types/src/generated/remove-role-assignment-request.ts"
 )]
 pub struct RemoveRoleAssignmentRequest {
+    #[serde(default)]
+    pub subject_type: Option<String>,
     pub subject: String,
     pub role_name: String,
 }
@@ -51,6 +58,9 @@ pub struct UpdateTenantRegistrationModeRequest {
     export_to = "../../../packages/api-types/src/generated/save-runtime-field-permissions-request.ts"
 )]
 pub struct SaveRuntimeFieldPermissionsRequest {
+    /// `user` (default) or `team`; team subjects are addressed by team name.
+    #[serde(default)]
+    pub subject_type: Option<String>,
     pub subject: String,
     pub entity_logical_name: String,
     pub fields: Vec<RuntimeFieldPermissionInputRequest>,
@@ -91,6 +101,17 @@ pub struct RevokeTemporaryAccessGrantRequest {
     pub revoke_reason: Option<String>,
 }
 
+/// Incoming payload for requesting an administrator MFA reset.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/create-mfa-reset-request.ts"
+)]
+pub struct CreateMfaResetRequest {
+    pub subject: String,
+    pub reason: String,
+}
+
 /// Incoming payload for audit retention updates.
 #[derive(Debug, Deserialize, TS)]
 #[ts(
@@ -101,6 +122,109 @@ pub struct UpdateAuditRetentionPolicyRequest {
     pub retention_days: u16,
 }
 
+/// Incoming payload for tenant workflow concurrency updates.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/update-workflow-concurrency-policy-request.ts"
+)]
+pub struct UpdateWorkflowConcurrencyPolicyRequest {
+    pub max_concurrent_jobs: Option<u32>,
+}
+
+/// Incoming payload for tenant encryption key registration and rotation.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/tenant-encryption-key-request.ts"
+)]
+pub struct TenantEncryptionKeyRequest {
+    pub secret_reference: String,
+}
+
+/// Incoming payload for tenant encryption key crypto-shredding.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/shred-tenant-encryption-keys-request.ts"
+)]
+pub struct ShredTenantEncryptionKeysRequest {
+    pub confirm_tenant_id: String,
+}
+
+/// Incoming payload for creating an audit export sink.
+///
+/// `sink_type` selects which destination fields apply: `s3` uses `bucket`
+/// and `prefix`, `syslog` uses `host`, `port` and `transport`, and
+/// `https_webhook` uses `url` with optional auth header fields.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/create-audit-export-sink-request.ts"
+)]
+pub struct CreateAuditExportSinkRequest {
+    pub name: String,
+    pub sink_type: String,
+    pub bucket: Option<String>,
+    pub prefix: Option<String>,
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub transport: Option<String>,
+    pub url: Option<String>,
+    pub auth_header_name: Option<String>,
+    pub auth_secret_ref: Option<String>,
+    pub backfill: Option<bool>,
+}
+
+/// Incoming payload pausing or resuming an audit export sink.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/update-audit-export-sink-request.ts"
+)]
+pub struct UpdateAuditExportSinkRequest {
+    pub is_enabled: bool,
+}
+
+/// Incoming payload for placing a legal hold.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/create-legal-hold-request.ts"
+)]
+pub struct CreateLegalHoldRequest {
+    pub name: String,
+    pub reason: String,
+    pub scope_type: String,
+    pub entity_logical_name: Option<String>,
+    pub record_ids: Option<Vec<String>>,
+    pub audit_from: Option<String>,
+    pub audit_until: Option<String>,
+}
+
+/// Incoming payload assigning a subject to a compliance zone.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/assign-compliance-zone-request.ts"
+)]
+pub struct AssignComplianceZoneRequest {
+    pub subject: String,
+    pub zone: String,
+}
+
+/// Incoming payload restricting an entity, or one of its records, to a compliance zone.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-compliance-zone-tag-request.ts"
+)]
+pub struct SaveComplianceZoneTagRequest {
+    pub entity_logical_name: String,
+    pub record_id: Option<String>,
+    pub zone: String,
+}
+
 /// API representation of an RBAC role.
 #[derive(Debug, Serialize, TS)]
 #[ts(
@@ -112,6 +236,33 @@ pub struct RoleResponse {
     pub name: String,
     pub is_system: bool,
     pub permissions: Vec<String>,
+    /// Label matching the caller's `Accept-Language` preferences, when one exists.
+    pub localized_label: Option<LocalizedLabelResponse>,
+}
+
+/// Permission that custom roles can grant.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/permission-catalog-entry-response.ts"
+)]
+pub struct PermissionCatalogEntryResponse {
+    pub permission: String,
+    pub description: String,
+    /// Permissions a role must also grant alongside this one.
+    pub implies: Vec<String>,
+}
+
+/// Permissions of one functional area, in catalog order.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/permission-catalog-group-response.ts"
+)]
+pub struct PermissionCatalogGroupResponse {
+    pub group: String,
+    pub label: String,
+    pub permissions: Vec<PermissionCatalogEntryResponse>,
 }
 
 /// API representation of an audit log entry.
@@ -144,9 +295,24 @@ pub struct AuditIntegrityStatusResponse {
     pub verified_entries: usize,
     pub latest_chain_position: Option<i64>,
     pub latest_entry_hash: Option<String>,
+    pub verified_from_anchor: Option<AuditChainAnchorResponse>,
     pub failures: Vec<String>,
 }
 
+/// API representation of one tenant audit chain anchor.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/audit-chain-anchor-response.ts"
+)]
+pub struct AuditChainAnchorResponse {
+    pub chain_position: i64,
+    pub entry_hash: String,
+    #[ts(type = "\"periodic\" | \"purge\"")]
+    pub reason: String,
+    pub created_at: String,
+}
+
 /// API representation of a role assignment.
 #[derive(Debug, Serialize, TS)]
 #[ts(
@@ -154,6 +320,7 @@ pub struct AuditIntegrityStatusResponse {
     export_to = "../../../packages/api-types/src/generated/role-assignment-response.ts"
 )]
 pub struct RoleAssignmentResponse {
+    pub subject_type: String,
     pub subject: String,
     pub role_id: String,
     pub role_name: String,
@@ -177,6 +344,7 @@ pub struct TenantRegistrationModeResponse {
     export_to = "../../../packages/api-types/src/generated/runtime-field-permission-response.ts"
 )]
 pub struct RuntimeFieldPermissionResponse {
+    pub subject_type: String,
     pub subject: String,
     pub entity_logical_name: String,
     pub field_logical_name: String,
@@ -185,6 +353,56 @@ pub struct RuntimeFieldPermissionResponse {
     pub updated_at: String,
 }
 
+/// Incoming payload for security team creation.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/create-security-team-request.ts"
+)]
+pub struct CreateSecurityTeamRequest {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Incoming payload for adding a member to a security team.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/add-security-team-member-request.ts"
+)]
+pub struct AddSecurityTeamMemberRequest {
+    pub subject: String,
+}
+
+/// API representation of a security team.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/security-team-response.ts"
+)]
+pub struct SecurityTeamResponse {
+    pub team_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub member_count: u32,
+    pub created_by_subject: String,
+    pub created_at: String,
+}
+
+/// API representation of a security team member.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/security-team-member-response.ts"
+)]
+pub struct SecurityTeamMemberResponse {
+    pub team_name: String,
+    pub subject: String,
+    pub added_by_subject: String,
+    pub added_at: String,
+}
+
 /// API representation of temporary access grant.
 #[derive(Debug, Serialize, TS)]
 #[ts(
@@ -201,6 +419,23 @@ pub struct TemporaryAccessGrantResponse {
     pub revoked_at: Option<String>,
 }
 
+/// API representation of an administrator MFA reset request.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/mfa-reset-request-response.ts"
+)]
+pub struct MfaResetRequestResponse {
+    pub request_id: String,
+    pub subject: String,
+    pub reason: String,
+    pub requested_by_subject: String,
+    pub requested_at: String,
+    pub expires_at: String,
+    pub approved_by_subject: Option<String>,
+    pub approved_at: Option<String>,
+}
+
 /// API representation of audit retention policy.
 #[derive(Debug, Serialize, TS)]
 #[ts(
@@ -211,6 +446,16 @@ pub struct AuditRetentionPolicyResponse {
     pub retention_days: u16,
 }
 
+/// API representation of tenant workflow concurrency policy.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-concurrency-policy-response.ts"
+)]
+pub struct WorkflowConcurrencyPolicyResponse {
+    pub max_concurrent_jobs: Option<u32>,
+}
+
 /// API representation of audit purge operation result.
 #[derive(Debug, Serialize, TS)]
 #[ts(
@@ -221,3 +466,373 @@ pub struct AuditPurgeResultResponse {
     pub deleted_count: u64,
     pub retention_days: u16,
 }
+
+/// API representation of one tenant encryption key version.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/tenant-encryption-key-response.ts"
+)]
+pub struct TenantEncryptionKeyResponse {
+    pub version: u32,
+    pub secret_reference: Option<String>,
+    pub key_fingerprint: String,
+    pub status: String,
+    pub created_by_subject: String,
+    pub created_at: String,
+    pub retired_at: Option<String>,
+    pub shredded_at: Option<String>,
+}
+
+/// API representation of tenant encryption key crypto-shredding result.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/shred-tenant-encryption-keys-response.ts"
+)]
+pub struct ShredTenantEncryptionKeysResponse {
+    pub shredded_versions: u64,
+}
+
+/// API representation of an audit export sink and its delivery checkpoint.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/audit-export-sink-response.ts"
+)]
+pub struct AuditExportSinkResponse {
+    pub sink_id: String,
+    pub name: String,
+    pub sink_type: String,
+    pub bucket: Option<String>,
+    pub prefix: Option<String>,
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub transport: Option<String>,
+    pub url: Option<String>,
+    pub auth_header_name: Option<String>,
+    pub auth_secret_ref: Option<String>,
+    pub is_enabled: bool,
+    pub checkpoint_position: i64,
+    pub last_exported_at: Option<String>,
+    pub last_error: Option<String>,
+    pub created_by_subject: String,
+    pub created_at: String,
+}
+
+/// API representation of a legal hold.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/legal-hold-response.ts"
+)]
+pub struct LegalHoldResponse {
+    pub hold_id: String,
+    pub name: String,
+    pub reason: String,
+    pub scope_type: String,
+    pub entity_logical_name: Option<String>,
+    pub record_ids: Vec<String>,
+    pub audit_from: Option<String>,
+    pub audit_until: Option<String>,
+    pub created_by_subject: String,
+    pub created_at: String,
+    pub released_by_subject: Option<String>,
+    pub released_at: Option<String>,
+    pub is_active: bool,
+}
+
+/// API representation of a compliance zone assignment.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/compliance-zone-assignment-response.ts"
+)]
+pub struct ComplianceZoneAssignmentResponse {
+    pub subject: String,
+    pub zone: String,
+    pub assigned_by_subject: String,
+    pub created_at: String,
+}
+
+/// API representation of a compliance zone restriction.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/compliance-zone-tag-response.ts"
+)]
+pub struct ComplianceZoneTagResponse {
+    pub entity_logical_name: String,
+    pub record_id: Option<String>,
+    pub zone: String,
+    pub tagged_by_subject: String,
+    pub updated_at: String,
+}
+
+/// Incoming payload replacing the dual-control field set for an entity.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-dual-control-fields-request.ts"
+)]
+pub struct SaveDualControlFieldsRequest {
+    pub fields: Vec<DualControlFieldRequest>,
+}
+
+/// Incoming dual-control field item.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/dual-control-field-request.ts"
+)]
+pub struct DualControlFieldRequest {
+    pub field_logical_name: String,
+    pub approval_window_hours: Option<u16>,
+}
+
+/// API representation of a field that requires dual-control approval.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/dual-control-field-response.ts"
+)]
+pub struct DualControlFieldResponse {
+    pub entity_logical_name: String,
+    pub field_logical_name: String,
+    pub approval_window_hours: u16,
+    pub updated_by_subject: String,
+    pub updated_at: String,
+}
+
+/// Incoming payload for replacing an entity's erasure field set.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-data-erasure-fields-request.ts"
+)]
+pub struct SaveDataErasureFieldsRequest {
+    pub field_logical_names: Vec<String>,
+}
+
+/// API representation of a personal data field removed on erasure.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/data-erasure-field-response.ts"
+)]
+pub struct DataErasureFieldResponse {
+    pub entity_logical_name: String,
+    pub field_logical_name: String,
+    pub updated_by_subject: String,
+    pub updated_at: String,
+}
+
+/// One classification and its masking mode in a role's masking rules.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/field-masking-rule-request.ts"
+)]
+pub struct FieldMaskingRuleRequest {
+    /// `pii`, `phi` or `confidential`.
+    pub classification: String,
+    /// `full`, `partial` or `hidden`.
+    pub masking_mode: String,
+}
+
+/// Incoming payload for replacing the masking rules of a role.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-field-masking-rules-request.ts"
+)]
+pub struct SaveFieldMaskingRulesRequest {
+    pub rules: Vec<FieldMaskingRuleRequest>,
+}
+
+/// API representation of how a role sees one field classification.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/field-masking-rule-response.ts"
+)]
+pub struct FieldMaskingRuleResponse {
+    pub role_name: String,
+    pub classification: String,
+    pub masking_mode: String,
+    pub updated_by_subject: String,
+    pub updated_at: String,
+}
+
+/// Flag state for holders of one role.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/feature-flag-role-override-request.ts"
+)]
+pub struct FeatureFlagRoleOverrideRequest {
+    pub role_name: String,
+    pub enabled: bool,
+}
+
+/// Incoming payload for creating or updating a feature flag.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-feature-flag-request.ts"
+)]
+pub struct SaveFeatureFlagRequest {
+    #[serde(default)]
+    #[ts(optional)]
+    pub description: Option<String>,
+    /// Flag state for subjects without an overridden role.
+    pub enabled: bool,
+    /// Replaces the existing role overrides.
+    #[serde(default)]
+    pub role_overrides: Vec<FeatureFlagRoleOverrideRequest>,
+}
+
+/// API representation of a role override of a feature flag.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/feature-flag-role-override-response.ts"
+)]
+pub struct FeatureFlagRoleOverrideResponse {
+    pub role_name: String,
+    pub enabled: bool,
+}
+
+/// API representation of a tenant feature flag.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/feature-flag-response.ts"
+)]
+pub struct FeatureFlagResponse {
+    pub flag_key: String,
+    pub description: Option<String>,
+    pub enabled: bool,
+    pub role_overrides: Vec<FeatureFlagRoleOverrideResponse>,
+    pub updated_by_subject: String,
+    pub updated_at: String,
+}
+
+/// API representation of a feature flag resolved for the caller.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/evaluated-feature-flag-response.ts"
+)]
+pub struct EvaluatedFeatureFlagResponse {
+    pub flag_key: String,
+    pub enabled: bool,
+}
+
+/// Incoming payload for erasing a subject's personal data.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/erase-subject-data-request.ts"
+)]
+pub struct EraseSubjectDataRequest {
+    pub subject: String,
+}
+
+/// API representation of a completed data erasure.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/data-erasure-report-response.ts"
+)]
+pub struct DataErasureReportResponse {
+    pub pseudonym: String,
+    pub contact_record_id: Option<String>,
+    pub records_pseudonymized: u64,
+    pub records_erased: u64,
+    pub records_held: u64,
+    pub history_entries_scrubbed: u64,
+    pub audit_entries_pseudonymized: u64,
+    pub audit_entries_retained: u64,
+    pub auth_events_anonymized: u64,
+    /// `true` when auth events were kept because the subject belongs to another tenant.
+    pub auth_events_retained: bool,
+    pub erased_at: String,
+}
+
+/// Role held by the subject of an access explanation.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/access-explanation-role-response.ts"
+)]
+pub struct AccessExplanationRoleResponse {
+    pub role_name: String,
+    /// Team the role is assigned through, `null` for direct assignments.
+    pub team_name: Option<String>,
+    pub permissions: Vec<String>,
+}
+
+/// Tenant permission evaluated by an access explanation.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/access-explanation-permission-check-response.ts"
+)]
+pub struct AccessExplanationPermissionCheckResponse {
+    pub permission: String,
+    pub granted: bool,
+    pub granting_roles: Vec<String>,
+    pub temporary_grant_id: Option<String>,
+    pub temporary_grant_expires_at: Option<String>,
+}
+
+/// App role permission evaluated by an access explanation.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/access-explanation-app-permission-response.ts"
+)]
+pub struct AccessExplanationAppPermissionResponse {
+    pub app_logical_name: String,
+    pub role_name: String,
+    /// Whether any role of the subject is bound to the app.
+    pub app_accessible: bool,
+    pub can_read: bool,
+    pub can_create: bool,
+    pub can_update: bool,
+    pub can_delete: bool,
+}
+
+/// Field grant evaluated by an access explanation.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/access-explanation-field-grant-response.ts"
+)]
+pub struct AccessExplanationFieldGrantResponse {
+    pub field_logical_name: String,
+    pub can_read: bool,
+    pub can_write: bool,
+}
+
+/// Evaluation trace explaining whether a subject can perform a record action.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/access-explanation-response.ts"
+)]
+pub struct AccessExplanationResponse {
+    pub subject: String,
+    pub entity_logical_name: String,
+    #[ts(type = "\"read\" | \"create\" | \"update\" | \"delete\"")]
+    pub action: String,
+    #[ts(type = "\"allowed\" | \"denied\"")]
+    pub decision: String,
+    pub roles: Vec<AccessExplanationRoleResponse>,
+    pub permission_checks: Vec<AccessExplanationPermissionCheckResponse>,
+    pub app_permissions: Vec<AccessExplanationAppPermissionResponse>,
+    pub field_grants: Vec<AccessExplanationFieldGrantResponse>,
+    /// Human-readable evaluation steps, in order.
+    pub trace: Vec<String>,
+}
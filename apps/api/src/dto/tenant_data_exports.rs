@@ -0,0 +1,32 @@
+use qryvanta_application::CreatedTenantDataExportLink;
+use serde::Serialize;
+use ts_rs::TS;
+
+/// Newly issued download link of a tenant data export.
+///
+/// `download_path` embeds the raw token and is only returned once.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/tenant-data-export-link-response.ts"
+)]
+pub struct TenantDataExportLinkResponse {
+    pub link_id: String,
+    pub job_id: String,
+    pub download_path: String,
+    pub expires_at: String,
+    pub created_at: String,
+}
+
+impl TenantDataExportLinkResponse {
+    #[must_use]
+    pub fn from_created(tenant_id: &str, value: CreatedTenantDataExportLink) -> Self {
+        Self {
+            download_path: format!("/api/public/tenant-exports/{tenant_id}/{}", value.token),
+            link_id: value.link.link_id,
+            job_id: value.link.job_id,
+            expires_at: value.link.expires_at.to_rfc3339(),
+            created_at: value.link.created_at.to_rfc3339(),
+        }
+    }
+}
@@ -2,9 +2,14 @@ mod conversions;
 mod types;
 
 pub use types::{
-    DispatchScheduleTriggerRequest, ExecuteWorkflowRequest, RetryWorkflowStepRequest,
-    RetryWorkflowStepStrategyDto, SaveWorkflowRequest, WorkflowResponse,
-    WorkflowRunAttemptResponse, WorkflowRunReplayResponse, WorkflowRunResponse,
+    ConfigureWorkflowInboundWebhookRequest, CreatedWorkflowInboundWebhookResponse,
+    DispatchScheduleTriggerRequest, ExecuteWorkflowRequest, FailWorkflowJobRequest,
+    RetryWorkflowStepRequest, RetryWorkflowStepStrategyDto, SaveWorkflowCredentialRequest,
+    SaveWorkflowRequest, WorkflowBulkExecutionPreviewResponse, WorkflowBulkExecutionRequest,
+    WorkflowBulkExecutionResponse, WorkflowCredentialResponse, WorkflowInboundWebhookResponse,
+    WorkflowQueueStatsResponse, WorkflowResponse, WorkflowRunAttemptResponse,
+    WorkflowRunReplayResponse, WorkflowRunResponse, WorkflowStuckJobResponse,
+    WorkflowWorkerLeaseResponse,
 };
 
 #[cfg(test)]
@@ -14,4 +19,9 @@ pub use types::WorkflowRunReplayTimelineEventResponse;
 pub use types::WorkflowRunStepTraceResponse;
 
 #[cfg(test)]
-pub use types::{WorkflowConditionOperatorDto, WorkflowStepDto};
+pub use types::{
+    WorkflowConditionOperatorDto, WorkflowCredentialAuthDto, WorkflowHttpRetryPolicyDto,
+    WorkflowInvocationModeDto, WorkflowStepBackoffStrategyDto, WorkflowStepDto,
+    WorkflowStepRetryErrorClassDto, WorkflowStepRetryPolicyDto, WorkflowTriggerFilterDto,
+    WorkflowTriggerFilterOperatorDto,
+};
@@ -1,17 +1,27 @@
+use chrono::Utc;
 use qryvanta_application::{
-    WorkflowRun, WorkflowRunAttempt, WorkflowRunReplay, WorkflowRunReplayTimelineEvent,
-    WorkflowRunStepTrace,
+    CreatedWorkflowInboundWebhook, WorkflowBulkExecutionReport, WorkflowCredentialRecord,
+    WorkflowInboundWebhook, WorkflowQueueStatsSnapshot, WorkflowRun, WorkflowRunAttempt,
+    WorkflowRunReplay, WorkflowRunReplayTimelineEvent, WorkflowRunStepTrace, WorkflowStuckJob,
+    WorkflowWorkerLeaseOwnership,
 };
-use qryvanta_core::AppError;
+use qryvanta_core::{AppError, TenantId};
 use qryvanta_domain::{
-    WorkflowConditionOperator, WorkflowDefinition, WorkflowLifecycleState, WorkflowStep,
-    WorkflowTrigger,
+    WorkflowConditionOperator, WorkflowCredentialAuth, WorkflowDefinition, WorkflowHttpRetryPolicy,
+    WorkflowInvocationMode, WorkflowLifecycleState, WorkflowRunPriority, WorkflowStep,
+    WorkflowStepBackoffStrategy, WorkflowStepRetryErrorClass, WorkflowStepRetryPolicy,
+    WorkflowTrigger, WorkflowTriggerFilter, WorkflowTriggerFilterOperator,
 };
 
 use super::types::{
-    SaveWorkflowRequest, WorkflowConditionOperatorDto, WorkflowResponse,
-    WorkflowRunAttemptResponse, WorkflowRunReplayResponse, WorkflowRunReplayTimelineEventResponse,
-    WorkflowRunResponse, WorkflowRunStepTraceResponse, WorkflowStepDto,
+    CreatedWorkflowInboundWebhookResponse, SaveWorkflowRequest, WorkflowBulkExecutionResponse,
+    WorkflowConditionOperatorDto, WorkflowCredentialAuthDto, WorkflowCredentialResponse,
+    WorkflowHttpRetryPolicyDto, WorkflowInboundWebhookResponse, WorkflowInvocationModeDto,
+    WorkflowQueueStatsResponse, WorkflowResponse, WorkflowRunAttemptResponse,
+    WorkflowRunReplayResponse, WorkflowRunReplayTimelineEventResponse, WorkflowRunResponse,
+    WorkflowRunStepTraceResponse, WorkflowStepBackoffStrategyDto, WorkflowStepDto,
+    WorkflowStepRetryErrorClassDto, WorkflowStepRetryPolicyDto, WorkflowStuckJobResponse,
+    WorkflowTriggerFilterDto, WorkflowTriggerFilterOperatorDto, WorkflowWorkerLeaseResponse,
 };
 
 impl TryFrom<SaveWorkflowRequest> for qryvanta_application::SaveWorkflowInput {
@@ -44,6 +54,14 @@ impl TryFrom<SaveWorkflowRequest> for qryvanta_application::SaveWorkflowInput {
                     )
                 })?,
             },
+            "runtime_record_status_changed" => WorkflowTrigger::RuntimeRecordStatusChanged {
+                entity_logical_name: value.trigger_entity_logical_name.ok_or_else(|| {
+                    AppError::Validation(
+                        "trigger_entity_logical_name is required for runtime_record_status_changed"
+                            .to_owned(),
+                    )
+                })?,
+            },
             "schedule_tick" => WorkflowTrigger::ScheduleTick {
                 schedule_key: value.trigger_entity_logical_name.ok_or_else(|| {
                     AppError::Validation(
@@ -51,6 +69,13 @@ impl TryFrom<SaveWorkflowRequest> for qryvanta_application::SaveWorkflowInput {
                     )
                 })?,
             },
+            "scheduled" => WorkflowTrigger::Scheduled {
+                cron_expression: value.trigger_entity_logical_name.ok_or_else(|| {
+                    AppError::Validation(
+                        "trigger_entity_logical_name is required for scheduled".to_owned(),
+                    )
+                })?,
+            },
             "webhook_received" => WorkflowTrigger::WebhookReceived {
                 webhook_key: value.trigger_entity_logical_name.ok_or_else(|| {
                     AppError::Validation(
@@ -103,6 +128,19 @@ impl TryFrom<SaveWorkflowRequest> for qryvanta_application::SaveWorkflowInput {
             steps,
             max_attempts: value.max_attempts.unwrap_or(3),
             is_enabled: true,
+            trigger_filters: value
+                .trigger_filters
+                .into_iter()
+                .map(WorkflowTriggerFilter::from)
+                .collect(),
+            trigger_changed_fields: value.trigger_changed_fields,
+            trigger_view_logical_name: value.trigger_view_logical_name,
+            priority: value
+                .priority
+                .as_deref()
+                .map(WorkflowRunPriority::parse)
+                .transpose()?
+                .unwrap_or_default(),
         })
     }
 }
@@ -129,9 +167,18 @@ impl From<WorkflowDefinition> for WorkflowResponse {
                 "runtime_record_deleted".to_owned(),
                 Some(entity_logical_name.clone()),
             ),
+            WorkflowTrigger::RuntimeRecordStatusChanged {
+                entity_logical_name,
+            } => (
+                "runtime_record_status_changed".to_owned(),
+                Some(entity_logical_name.clone()),
+            ),
             WorkflowTrigger::ScheduleTick { schedule_key } => {
                 ("schedule_tick".to_owned(), Some(schedule_key.clone()))
             }
+            WorkflowTrigger::Scheduled { cron_expression } => {
+                ("scheduled".to_owned(), Some(cron_expression.clone()))
+            }
             WorkflowTrigger::WebhookReceived { webhook_key } => {
                 ("webhook_received".to_owned(), Some(webhook_key.clone()))
             }
@@ -154,6 +201,14 @@ impl From<WorkflowDefinition> for WorkflowResponse {
             description: value.description().map(ToOwned::to_owned),
             trigger_type,
             trigger_entity_logical_name,
+            trigger_filters: value
+                .trigger_filters()
+                .iter()
+                .cloned()
+                .map(WorkflowTriggerFilterDto::from)
+                .collect(),
+            trigger_changed_fields: value.trigger_changed_fields().to_vec(),
+            trigger_view_logical_name: value.trigger_view_logical_name().map(ToOwned::to_owned),
             steps: value
                 .steps()
                 .iter()
@@ -161,9 +216,11 @@ impl From<WorkflowDefinition> for WorkflowResponse {
                 .map(WorkflowStepDto::from)
                 .collect(),
             max_attempts: value.max_attempts(),
+            priority: value.priority().as_str().to_owned(),
             lifecycle_state: workflow_lifecycle_state_str(value.lifecycle_state()).to_owned(),
             published_version: value.published_version(),
             is_enabled: value.is_enabled(),
+            localized_label: None,
         }
     }
 }
@@ -182,6 +239,61 @@ impl From<WorkflowRun> for WorkflowRunResponse {
             dead_letter_reason: value.dead_letter_reason,
             started_at: value.started_at.to_rfc3339(),
             finished_at: value.finished_at.map(|timestamp| timestamp.to_rfc3339()),
+            resume_at: value.resume_at.map(|timestamp| timestamp.to_rfc3339()),
+            parent_run_id: value.parent_run_id,
+            priority: value.priority.as_str().to_owned(),
+        }
+    }
+}
+
+impl From<WorkflowStuckJob> for WorkflowStuckJobResponse {
+    fn from(value: WorkflowStuckJob) -> Self {
+        Self {
+            job_id: value.job_id,
+            run_id: value.run_id,
+            workflow_logical_name: value.workflow_logical_name,
+            workflow_version: value.workflow_version,
+            leased_by: value.leased_by,
+            lease_expires_at: value.lease_expires_at.to_rfc3339(),
+            run_attempts: value.run_attempts,
+            last_error: value.last_error,
+        }
+    }
+}
+
+impl WorkflowWorkerLeaseResponse {
+    /// Builds one response for a scope, leased or not.
+    pub fn new(scope_key: String, ownership: Option<WorkflowWorkerLeaseOwnership>) -> Self {
+        match ownership {
+            Some(ownership) => Self {
+                scope_key: ownership.scope_key,
+                holder_id: Some(ownership.holder_id),
+                expires_in_seconds: ownership.expires_in_seconds,
+            },
+            None => Self {
+                scope_key,
+                holder_id: None,
+                expires_in_seconds: None,
+            },
+        }
+    }
+}
+
+impl WorkflowQueueStatsResponse {
+    /// Builds one response from a snapshot and the configured cache ttl.
+    pub fn from_snapshot(snapshot: WorkflowQueueStatsSnapshot, cache_ttl_seconds: u32) -> Self {
+        let stats = snapshot.stats;
+        Self {
+            pending_jobs: stats.pending_jobs,
+            leased_jobs: stats.leased_jobs,
+            completed_jobs: stats.completed_jobs,
+            failed_jobs: stats.failed_jobs,
+            expired_leases: stats.expired_leases,
+            active_workers: stats.active_workers,
+            computed_at: snapshot.computed_at.to_rfc3339(),
+            age_seconds: (Utc::now() - snapshot.computed_at).num_seconds().max(0),
+            served_from_cache: snapshot.served_from_cache,
+            cache_ttl_seconds,
         }
     }
 }
@@ -221,6 +333,7 @@ impl From<WorkflowRunStepTrace> for WorkflowRunStepTraceResponse {
             output_payload: value.output_payload,
             error_message: value.error_message,
             duration_ms: value.duration_ms,
+            retries: value.retries,
         }
     }
 }
@@ -262,6 +375,50 @@ impl From<WorkflowRunReplay> for WorkflowRunReplayResponse {
     }
 }
 
+impl From<WorkflowTriggerFilterDto> for WorkflowTriggerFilter {
+    fn from(value: WorkflowTriggerFilterDto) -> Self {
+        Self {
+            field_logical_name: value.field_logical_name,
+            operator: value.operator.into(),
+            value: value.value,
+        }
+    }
+}
+
+impl From<WorkflowTriggerFilter> for WorkflowTriggerFilterDto {
+    fn from(value: WorkflowTriggerFilter) -> Self {
+        Self {
+            field_logical_name: value.field_logical_name,
+            operator: value.operator.into(),
+            value: value.value,
+        }
+    }
+}
+
+impl From<WorkflowTriggerFilterOperatorDto> for WorkflowTriggerFilterOperator {
+    fn from(value: WorkflowTriggerFilterOperatorDto) -> Self {
+        match value {
+            WorkflowTriggerFilterOperatorDto::Changed => Self::Changed,
+            WorkflowTriggerFilterOperatorDto::Equals => Self::Equals,
+            WorkflowTriggerFilterOperatorDto::NotEquals => Self::NotEquals,
+            WorkflowTriggerFilterOperatorDto::ChangedFrom => Self::ChangedFrom,
+            WorkflowTriggerFilterOperatorDto::ChangedTo => Self::ChangedTo,
+        }
+    }
+}
+
+impl From<WorkflowTriggerFilterOperator> for WorkflowTriggerFilterOperatorDto {
+    fn from(value: WorkflowTriggerFilterOperator) -> Self {
+        match value {
+            WorkflowTriggerFilterOperator::Changed => Self::Changed,
+            WorkflowTriggerFilterOperator::Equals => Self::Equals,
+            WorkflowTriggerFilterOperator::NotEquals => Self::NotEquals,
+            WorkflowTriggerFilterOperator::ChangedFrom => Self::ChangedFrom,
+            WorkflowTriggerFilterOperator::ChangedTo => Self::ChangedTo,
+        }
+    }
+}
+
 impl From<WorkflowConditionOperatorDto> for WorkflowConditionOperator {
     fn from(value: WorkflowConditionOperatorDto) -> Self {
         match value {
@@ -282,6 +439,115 @@ impl From<WorkflowConditionOperator> for WorkflowConditionOperatorDto {
     }
 }
 
+impl From<WorkflowInvocationModeDto> for WorkflowInvocationMode {
+    fn from(value: WorkflowInvocationModeDto) -> Self {
+        match value {
+            WorkflowInvocationModeDto::Synchronous => Self::Synchronous,
+            WorkflowInvocationModeDto::Enqueued => Self::Enqueued,
+        }
+    }
+}
+
+impl From<WorkflowInvocationMode> for WorkflowInvocationModeDto {
+    fn from(value: WorkflowInvocationMode) -> Self {
+        match value {
+            WorkflowInvocationMode::Synchronous => Self::Synchronous,
+            WorkflowInvocationMode::Enqueued => Self::Enqueued,
+        }
+    }
+}
+
+impl From<WorkflowStepRetryPolicyDto> for WorkflowStepRetryPolicy {
+    fn from(value: WorkflowStepRetryPolicyDto) -> Self {
+        Self {
+            max_attempts: value.max_attempts,
+            backoff: match value.backoff {
+                WorkflowStepBackoffStrategyDto::Fixed => WorkflowStepBackoffStrategy::Fixed,
+                WorkflowStepBackoffStrategyDto::Linear => WorkflowStepBackoffStrategy::Linear,
+                WorkflowStepBackoffStrategyDto::Exponential => {
+                    WorkflowStepBackoffStrategy::Exponential
+                }
+            },
+            backoff_ms: value.backoff_ms,
+            retry_on: value
+                .retry_on
+                .map_or_else(Self::default_retry_on, |retry_on| {
+                    retry_on
+                        .into_iter()
+                        .map(|error_class| match error_class {
+                            WorkflowStepRetryErrorClassDto::Validation => {
+                                WorkflowStepRetryErrorClass::Validation
+                            }
+                            WorkflowStepRetryErrorClassDto::NotFound => {
+                                WorkflowStepRetryErrorClass::NotFound
+                            }
+                            WorkflowStepRetryErrorClassDto::Conflict => {
+                                WorkflowStepRetryErrorClass::Conflict
+                            }
+                            WorkflowStepRetryErrorClassDto::Unauthorized => {
+                                WorkflowStepRetryErrorClass::Unauthorized
+                            }
+                            WorkflowStepRetryErrorClassDto::Forbidden => {
+                                WorkflowStepRetryErrorClass::Forbidden
+                            }
+                            WorkflowStepRetryErrorClassDto::RateLimited => {
+                                WorkflowStepRetryErrorClass::RateLimited
+                            }
+                            WorkflowStepRetryErrorClassDto::Internal => {
+                                WorkflowStepRetryErrorClass::Internal
+                            }
+                        })
+                        .collect()
+                }),
+        }
+    }
+}
+
+impl From<WorkflowStepRetryPolicy> for WorkflowStepRetryPolicyDto {
+    fn from(value: WorkflowStepRetryPolicy) -> Self {
+        Self {
+            max_attempts: value.max_attempts,
+            backoff: match value.backoff {
+                WorkflowStepBackoffStrategy::Fixed => WorkflowStepBackoffStrategyDto::Fixed,
+                WorkflowStepBackoffStrategy::Linear => WorkflowStepBackoffStrategyDto::Linear,
+                WorkflowStepBackoffStrategy::Exponential => {
+                    WorkflowStepBackoffStrategyDto::Exponential
+                }
+            },
+            backoff_ms: value.backoff_ms,
+            retry_on: Some(
+                value
+                    .retry_on
+                    .into_iter()
+                    .map(|error_class| match error_class {
+                        WorkflowStepRetryErrorClass::Validation => {
+                            WorkflowStepRetryErrorClassDto::Validation
+                        }
+                        WorkflowStepRetryErrorClass::NotFound => {
+                            WorkflowStepRetryErrorClassDto::NotFound
+                        }
+                        WorkflowStepRetryErrorClass::Conflict => {
+                            WorkflowStepRetryErrorClassDto::Conflict
+                        }
+                        WorkflowStepRetryErrorClass::Unauthorized => {
+                            WorkflowStepRetryErrorClassDto::Unauthorized
+                        }
+                        WorkflowStepRetryErrorClass::Forbidden => {
+                            WorkflowStepRetryErrorClassDto::Forbidden
+                        }
+                        WorkflowStepRetryErrorClass::RateLimited => {
+                            WorkflowStepRetryErrorClassDto::RateLimited
+                        }
+                        WorkflowStepRetryErrorClass::Internal => {
+                            WorkflowStepRetryErrorClassDto::Internal
+                        }
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
 impl From<WorkflowStepDto> for WorkflowStep {
     fn from(value: WorkflowStepDto) -> Self {
         match value {
@@ -289,73 +555,102 @@ impl From<WorkflowStepDto> for WorkflowStep {
             WorkflowStepDto::CreateRuntimeRecord {
                 entity_logical_name,
                 data,
+                output_key,
+                retry_policy,
             } => Self::CreateRuntimeRecord {
                 entity_logical_name,
                 data,
+                output_key,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStepDto::UpdateRuntimeRecord {
                 entity_logical_name,
                 record_id,
                 data,
+                retry_policy,
             } => Self::UpdateRuntimeRecord {
                 entity_logical_name,
                 record_id,
                 data,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStepDto::DeleteRuntimeRecord {
                 entity_logical_name,
                 record_id,
+                retry_policy,
             } => Self::DeleteRuntimeRecord {
                 entity_logical_name,
                 record_id,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStepDto::SendEmail {
                 to,
                 subject,
                 body,
                 html_body,
+                retry_policy,
             } => Self::SendEmail {
                 to,
                 subject,
                 body,
                 html_body,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStepDto::HttpRequest {
                 method,
                 url,
                 headers,
                 header_secret_refs,
+                credential,
                 body,
+                expected_status,
+                retry,
+                output_key,
+                retry_policy,
             } => Self::HttpRequest {
                 method,
                 url,
                 headers,
                 header_secret_refs,
+                credential,
                 body,
+                expected_status,
+                retry: retry.map(|retry| WorkflowHttpRetryPolicy {
+                    max_attempts: retry.max_attempts,
+                    backoff_ms: retry.backoff_ms,
+                }),
+                output_key,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStepDto::Webhook {
                 endpoint,
                 event,
                 headers,
                 header_secret_refs,
+                credential,
                 payload,
+                retry_policy,
             } => Self::Webhook {
                 endpoint,
                 event,
                 headers,
                 header_secret_refs,
+                credential,
                 payload,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStepDto::AssignOwner {
                 entity_logical_name,
                 record_id,
                 owner_id,
                 reason,
+                retry_policy,
             } => Self::AssignOwner {
                 entity_logical_name,
                 record_id,
                 owner_id,
                 reason,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStepDto::ApprovalRequest {
                 entity_logical_name,
@@ -365,6 +660,7 @@ impl From<WorkflowStepDto> for WorkflowStep {
                 approver_id,
                 reason,
                 payload,
+                retry_policy,
             } => Self::ApprovalRequest {
                 entity_logical_name,
                 record_id,
@@ -373,6 +669,7 @@ impl From<WorkflowStepDto> for WorkflowStep {
                 approver_id,
                 reason,
                 payload,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStepDto::Delay {
                 duration_ms,
@@ -381,6 +678,37 @@ impl From<WorkflowStepDto> for WorkflowStep {
                 duration_ms,
                 reason,
             },
+            WorkflowStepDto::Wait {
+                duration_seconds,
+                until_field_path,
+                reason,
+            } => Self::Wait {
+                duration_seconds,
+                until_field_path,
+                reason,
+            },
+            WorkflowStepDto::InvokeWorkflow {
+                workflow_logical_name,
+                payload,
+                mode,
+                output_key,
+                retry_policy,
+            } => Self::InvokeWorkflow {
+                workflow_logical_name,
+                payload,
+                mode: WorkflowInvocationMode::from(mode),
+                output_key,
+                retry_policy: retry_policy.map(Into::into),
+            },
+            WorkflowStepDto::TryCatch {
+                try_steps,
+                catch_steps,
+                compensation_steps,
+            } => Self::TryCatch {
+                try_steps: try_steps.into_iter().map(Self::from).collect(),
+                catch_steps: catch_steps.into_iter().map(Self::from).collect(),
+                compensation_steps: compensation_steps.into_iter().map(Self::from).collect(),
+            },
             WorkflowStepDto::Condition {
                 field_path,
                 operator,
@@ -409,73 +737,102 @@ impl From<WorkflowStep> for WorkflowStepDto {
             WorkflowStep::CreateRuntimeRecord {
                 entity_logical_name,
                 data,
+                output_key,
+                retry_policy,
             } => Self::CreateRuntimeRecord {
                 entity_logical_name,
                 data,
+                output_key,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStep::UpdateRuntimeRecord {
                 entity_logical_name,
                 record_id,
                 data,
+                retry_policy,
             } => Self::UpdateRuntimeRecord {
                 entity_logical_name,
                 record_id,
                 data,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStep::DeleteRuntimeRecord {
                 entity_logical_name,
                 record_id,
+                retry_policy,
             } => Self::DeleteRuntimeRecord {
                 entity_logical_name,
                 record_id,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStep::SendEmail {
                 to,
                 subject,
                 body,
                 html_body,
+                retry_policy,
             } => Self::SendEmail {
                 to,
                 subject,
                 body,
                 html_body,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStep::HttpRequest {
                 method,
                 url,
                 headers,
                 header_secret_refs,
+                credential,
                 body,
+                expected_status,
+                retry,
+                output_key,
+                retry_policy,
             } => Self::HttpRequest {
                 method,
                 url,
                 headers,
                 header_secret_refs,
+                credential,
                 body,
+                expected_status,
+                retry: retry.map(|retry| WorkflowHttpRetryPolicyDto {
+                    max_attempts: retry.max_attempts,
+                    backoff_ms: retry.backoff_ms,
+                }),
+                output_key,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStep::Webhook {
                 endpoint,
                 event,
                 headers,
                 header_secret_refs,
+                credential,
                 payload,
+                retry_policy,
             } => Self::Webhook {
                 endpoint,
                 event,
                 headers,
                 header_secret_refs,
+                credential,
                 payload,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStep::AssignOwner {
                 entity_logical_name,
                 record_id,
                 owner_id,
                 reason,
+                retry_policy,
             } => Self::AssignOwner {
                 entity_logical_name,
                 record_id,
                 owner_id,
                 reason,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStep::ApprovalRequest {
                 entity_logical_name,
@@ -485,6 +842,7 @@ impl From<WorkflowStep> for WorkflowStepDto {
                 approver_id,
                 reason,
                 payload,
+                retry_policy,
             } => Self::ApprovalRequest {
                 entity_logical_name,
                 record_id,
@@ -493,6 +851,7 @@ impl From<WorkflowStep> for WorkflowStepDto {
                 approver_id,
                 reason,
                 payload,
+                retry_policy: retry_policy.map(Into::into),
             },
             WorkflowStep::Delay {
                 duration_ms,
@@ -501,6 +860,37 @@ impl From<WorkflowStep> for WorkflowStepDto {
                 duration_ms,
                 reason,
             },
+            WorkflowStep::Wait {
+                duration_seconds,
+                until_field_path,
+                reason,
+            } => Self::Wait {
+                duration_seconds,
+                until_field_path,
+                reason,
+            },
+            WorkflowStep::InvokeWorkflow {
+                workflow_logical_name,
+                payload,
+                mode,
+                output_key,
+                retry_policy,
+            } => Self::InvokeWorkflow {
+                workflow_logical_name,
+                payload,
+                mode: WorkflowInvocationModeDto::from(mode),
+                output_key,
+                retry_policy: retry_policy.map(Into::into),
+            },
+            WorkflowStep::TryCatch {
+                try_steps,
+                catch_steps,
+                compensation_steps,
+            } => Self::TryCatch {
+                try_steps: try_steps.into_iter().map(Self::from).collect(),
+                catch_steps: catch_steps.into_iter().map(Self::from).collect(),
+                compensation_steps: compensation_steps.into_iter().map(Self::from).collect(),
+            },
             WorkflowStep::Condition {
                 field_path,
                 operator,
@@ -521,3 +911,118 @@ impl From<WorkflowStep> for WorkflowStepDto {
         }
     }
 }
+
+impl From<WorkflowInboundWebhook> for WorkflowInboundWebhookResponse {
+    fn from(value: WorkflowInboundWebhook) -> Self {
+        Self {
+            workflow_logical_name: value.workflow_logical_name,
+            payload_schema: value.payload_schema,
+            created_by_subject: value.created_by_subject,
+            created_at: value.created_at.to_rfc3339(),
+            delivery_count: value.delivery_count,
+            last_received_at: value
+                .last_received_at
+                .map(|timestamp| timestamp.to_rfc3339()),
+        }
+    }
+}
+
+impl CreatedWorkflowInboundWebhookResponse {
+    /// Builds one response with the tenant-scoped public delivery path.
+    pub fn new(tenant_id: TenantId, value: CreatedWorkflowInboundWebhook) -> Self {
+        Self {
+            webhook: WorkflowInboundWebhookResponse::from(value.webhook),
+            webhook_path: format!(
+                "/api/public/workflows/inbound/{}/{}",
+                tenant_id, value.token
+            ),
+            signing_secret: value.signing_secret,
+        }
+    }
+}
+
+impl From<WorkflowBulkExecutionReport> for WorkflowBulkExecutionResponse {
+    fn from(value: WorkflowBulkExecutionReport) -> Self {
+        let execution = value.execution;
+        Self {
+            bulk_execution_id: execution.bulk_execution_id,
+            workflow_logical_name: execution.workflow_logical_name,
+            entity_logical_name: execution.entity_logical_name,
+            view_logical_name: execution.view_logical_name,
+            total_records: execution.total_records,
+            requested_by_subject: execution.requested_by_subject,
+            created_at: execution.created_at.to_rfc3339(),
+            pending_runs: value.run_counts.pending,
+            succeeded_runs: value.run_counts.succeeded,
+            dead_lettered_runs: value.run_counts.dead_lettered,
+            completed_at: value.completed_at.map(|timestamp| timestamp.to_rfc3339()),
+        }
+    }
+}
+
+impl From<WorkflowCredentialAuthDto> for WorkflowCredentialAuth {
+    fn from(value: WorkflowCredentialAuthDto) -> Self {
+        match value {
+            WorkflowCredentialAuthDto::ApiKey {
+                header_name,
+                prefix,
+                secret_ref,
+            } => Self::ApiKey {
+                header_name,
+                prefix,
+                secret_ref,
+            },
+            WorkflowCredentialAuthDto::OAuth2ClientCredentials {
+                token_url,
+                client_id,
+                client_secret_ref,
+                scope,
+            } => Self::OAuth2ClientCredentials {
+                token_url,
+                client_id,
+                client_secret_ref,
+                scope,
+            },
+        }
+    }
+}
+
+impl From<WorkflowCredentialAuth> for WorkflowCredentialAuthDto {
+    fn from(value: WorkflowCredentialAuth) -> Self {
+        match value {
+            WorkflowCredentialAuth::ApiKey {
+                header_name,
+                prefix,
+                secret_ref,
+            } => Self::ApiKey {
+                header_name,
+                prefix,
+                secret_ref,
+            },
+            WorkflowCredentialAuth::OAuth2ClientCredentials {
+                token_url,
+                client_id,
+                client_secret_ref,
+                scope,
+            } => Self::OAuth2ClientCredentials {
+                token_url,
+                client_id,
+                client_secret_ref,
+                scope,
+            },
+        }
+    }
+}
+
+impl From<WorkflowCredentialRecord> for WorkflowCredentialResponse {
+    fn from(value: WorkflowCredentialRecord) -> Self {
+        Self {
+            logical_name: value.credential.logical_name().as_str().to_owned(),
+            display_name: value.credential.display_name().as_str().to_owned(),
+            auth: value.credential.auth().clone().into(),
+            allowed_hosts: value.credential.allowed_hosts().to_vec(),
+            updated_by_subject: value.updated_by_subject,
+            updated_at: value.updated_at.to_rfc3339(),
+        }
+    }
+}
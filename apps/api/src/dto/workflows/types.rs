@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use ts_rs::TS;
 
+use crate::dto::{LocalizedLabelResponse, QueryRuntimeRecordsRequest};
+
 /// Condition operators exposed through workflow DTOs.
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, TS)]
 #[serde(rename_all = "snake_case")]
@@ -15,6 +17,109 @@ pub enum WorkflowConditionOperatorDto {
     Exists,
 }
 
+/// Invoke workflow step modes exposed through workflow DTOs.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-invocation-mode-dto.ts"
+)]
+pub enum WorkflowInvocationModeDto {
+    #[default]
+    Synchronous,
+    Enqueued,
+}
+
+/// Record-change trigger filter operators exposed through workflow DTOs.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-trigger-filter-operator-dto.ts"
+)]
+pub enum WorkflowTriggerFilterOperatorDto {
+    Changed,
+    Equals,
+    NotEquals,
+    ChangedFrom,
+    ChangedTo,
+}
+
+/// One record-change filter evaluated before a triggered run is enqueued.
+#[derive(Debug, Clone, Deserialize, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-trigger-filter-dto.ts"
+)]
+pub struct WorkflowTriggerFilterDto {
+    pub field_logical_name: String,
+    pub operator: WorkflowTriggerFilterOperatorDto,
+    #[serde(default)]
+    #[ts(type = "unknown")]
+    pub value: Option<Value>,
+}
+
+/// Per-step retry policy for HTTP request steps.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-http-retry-policy-dto.ts"
+)]
+pub struct WorkflowHttpRetryPolicyDto {
+    pub max_attempts: u8,
+    #[ts(type = "number")]
+    pub backoff_ms: u64,
+}
+
+/// Backoff strategies for workflow step retry policies.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-step-backoff-strategy-dto.ts"
+)]
+pub enum WorkflowStepBackoffStrategyDto {
+    #[default]
+    Fixed,
+    Linear,
+    Exponential,
+}
+
+/// Error classes a workflow step retry policy can retry.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-step-retry-error-class-dto.ts"
+)]
+pub enum WorkflowStepRetryErrorClassDto {
+    Validation,
+    NotFound,
+    Conflict,
+    Unauthorized,
+    Forbidden,
+    RateLimited,
+    Internal,
+}
+
+/// Retry policy applied when a workflow step fails.
+#[derive(Debug, Clone, Deserialize, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-step-retry-policy-dto.ts"
+)]
+pub struct WorkflowStepRetryPolicyDto {
+    pub max_attempts: u8,
+    #[serde(default)]
+    pub backoff: WorkflowStepBackoffStrategyDto,
+    #[serde(default)]
+    #[ts(type = "number")]
+    pub backoff_ms: u64,
+    /// Retried error classes; rate-limited and internal errors when omitted.
+    #[serde(default)]
+    pub retry_on: Option<Vec<WorkflowStepRetryErrorClassDto>>,
+}
+
 /// One workflow canvas step shape used for API transport.
 #[derive(Debug, Clone, Deserialize, Serialize, TS)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -30,22 +135,27 @@ pub enum WorkflowStepDto {
         entity_logical_name: String,
         #[ts(type = "Record<string, unknown>")]
         data: Value,
+        output_key: Option<String>,
+        retry_policy: Option<WorkflowStepRetryPolicyDto>,
     },
     UpdateRuntimeRecord {
         entity_logical_name: String,
         record_id: String,
         #[ts(type = "Record<string, unknown>")]
         data: Value,
+        retry_policy: Option<WorkflowStepRetryPolicyDto>,
     },
     DeleteRuntimeRecord {
         entity_logical_name: String,
         record_id: String,
+        retry_policy: Option<WorkflowStepRetryPolicyDto>,
     },
     SendEmail {
         to: String,
         subject: String,
         body: String,
         html_body: Option<String>,
+        retry_policy: Option<WorkflowStepRetryPolicyDto>,
     },
     HttpRequest {
         method: String,
@@ -54,8 +164,13 @@ pub enum WorkflowStepDto {
         headers: Option<Value>,
         #[ts(type = "Record<string, string> | null")]
         header_secret_refs: Option<Value>,
+        credential: Option<String>,
         #[ts(type = "unknown | null")]
         body: Option<Value>,
+        expected_status: Option<u16>,
+        retry: Option<WorkflowHttpRetryPolicyDto>,
+        output_key: Option<String>,
+        retry_policy: Option<WorkflowStepRetryPolicyDto>,
     },
     Webhook {
         endpoint: String,
@@ -64,14 +179,17 @@ pub enum WorkflowStepDto {
         headers: Option<Value>,
         #[ts(type = "Record<string, string> | null")]
         header_secret_refs: Option<Value>,
+        credential: Option<String>,
         #[ts(type = "Record<string, unknown>")]
         payload: Value,
+        retry_policy: Option<WorkflowStepRetryPolicyDto>,
     },
     AssignOwner {
         entity_logical_name: String,
         record_id: String,
         owner_id: String,
         reason: Option<String>,
+        retry_policy: Option<WorkflowStepRetryPolicyDto>,
     },
     ApprovalRequest {
         entity_logical_name: String,
@@ -82,12 +200,35 @@ pub enum WorkflowStepDto {
         reason: Option<String>,
         #[ts(type = "Record<string, unknown> | null")]
         payload: Option<Value>,
+        retry_policy: Option<WorkflowStepRetryPolicyDto>,
     },
     Delay {
         #[ts(type = "number")]
         duration_ms: u64,
         reason: Option<String>,
     },
+    Wait {
+        #[ts(type = "number | null")]
+        duration_seconds: Option<u64>,
+        until_field_path: Option<String>,
+        reason: Option<String>,
+    },
+    InvokeWorkflow {
+        workflow_logical_name: String,
+        #[ts(type = "Record<string, unknown>")]
+        payload: Value,
+        #[serde(default)]
+        mode: WorkflowInvocationModeDto,
+        output_key: Option<String>,
+        retry_policy: Option<WorkflowStepRetryPolicyDto>,
+    },
+    TryCatch {
+        try_steps: Vec<WorkflowStepDto>,
+        #[serde(default)]
+        catch_steps: Vec<WorkflowStepDto>,
+        #[serde(default)]
+        compensation_steps: Vec<WorkflowStepDto>,
+    },
     Condition {
         field_path: String,
         operator: WorkflowConditionOperatorDto,
@@ -114,6 +255,14 @@ pub struct SaveWorkflowRequest {
     pub trigger_entity_logical_name: Option<String>,
     pub steps: Vec<WorkflowStepDto>,
     pub max_attempts: Option<u16>,
+    #[serde(default)]
+    pub trigger_filters: Vec<WorkflowTriggerFilterDto>,
+    #[serde(default)]
+    pub trigger_changed_fields: Vec<String>,
+    #[serde(default)]
+    pub trigger_view_logical_name: Option<String>,
+    #[serde(default)]
+    pub priority: Option<String>,
 }
 
 /// Incoming payload for manual workflow execution.
@@ -127,6 +276,62 @@ pub struct ExecuteWorkflowRequest {
     pub trigger_payload: Value,
 }
 
+/// Incoming payload selecting the records of a bulk workflow execution.
+///
+/// Records are selected by a saved view or by a runtime record query; when
+/// neither is set every active record of the entity is selected.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-bulk-execution-request.ts"
+)]
+pub struct WorkflowBulkExecutionRequest {
+    pub entity_logical_name: String,
+    pub view_logical_name: Option<String>,
+    /// Runtime record query; `limit` sets the page size used to resolve it.
+    pub query: Option<QueryRuntimeRecordsRequest>,
+    /// Record count confirmed from a preview; required to start the execution.
+    pub expected_record_count: Option<usize>,
+}
+
+/// Record count a bulk workflow execution would target.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-bulk-execution-preview-response.ts"
+)]
+pub struct WorkflowBulkExecutionPreviewResponse {
+    pub workflow_logical_name: String,
+    pub entity_logical_name: String,
+    pub view_logical_name: Option<String>,
+    pub record_count: usize,
+    pub max_records: usize,
+}
+
+/// API representation of one bulk workflow execution and its run summary.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-bulk-execution-response.ts"
+)]
+pub struct WorkflowBulkExecutionResponse {
+    pub bulk_execution_id: String,
+    pub workflow_logical_name: String,
+    pub entity_logical_name: String,
+    pub view_logical_name: Option<String>,
+    #[ts(type = "number")]
+    pub total_records: i64,
+    pub requested_by_subject: String,
+    pub created_at: String,
+    #[ts(type = "number")]
+    pub pending_runs: i64,
+    #[ts(type = "number")]
+    pub succeeded_runs: i64,
+    #[ts(type = "number")]
+    pub dead_lettered_runs: i64,
+    pub completed_at: Option<String>,
+}
+
 /// Incoming payload for dispatching a schedule tick trigger.
 #[derive(Debug, Deserialize, TS)]
 #[ts(
@@ -175,11 +380,17 @@ pub struct WorkflowResponse {
     pub description: Option<String>,
     pub trigger_type: String,
     pub trigger_entity_logical_name: Option<String>,
+    pub trigger_filters: Vec<WorkflowTriggerFilterDto>,
+    pub trigger_changed_fields: Vec<String>,
+    pub trigger_view_logical_name: Option<String>,
     pub steps: Vec<WorkflowStepDto>,
     pub max_attempts: u16,
+    pub priority: String,
     pub lifecycle_state: String,
     pub published_version: Option<i32>,
     pub is_enabled: bool,
+    /// Label matching the caller's `Accept-Language` preferences, when one exists.
+    pub localized_label: Option<LocalizedLabelResponse>,
 }
 
 /// API representation of one workflow run.
@@ -201,6 +412,77 @@ pub struct WorkflowRunResponse {
     pub dead_letter_reason: Option<String>,
     pub started_at: String,
     pub finished_at: Option<String>,
+    pub resume_at: Option<String>,
+    pub parent_run_id: Option<String>,
+    pub priority: String,
+}
+
+/// Tenant-scoped workflow queue stats with cache freshness metadata.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-queue-stats-response.ts"
+)]
+pub struct WorkflowQueueStatsResponse {
+    #[ts(type = "number")]
+    pub pending_jobs: i64,
+    #[ts(type = "number")]
+    pub leased_jobs: i64,
+    #[ts(type = "number")]
+    pub completed_jobs: i64,
+    #[ts(type = "number")]
+    pub failed_jobs: i64,
+    #[ts(type = "number")]
+    pub expired_leases: i64,
+    #[ts(type = "number")]
+    pub active_workers: i64,
+    pub computed_at: String,
+    #[ts(type = "number")]
+    pub age_seconds: i64,
+    pub served_from_cache: bool,
+    pub cache_ttl_seconds: u32,
+}
+
+/// API representation of one queued job still leased after its lease expired.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-stuck-job-response.ts"
+)]
+pub struct WorkflowStuckJobResponse {
+    pub job_id: String,
+    pub run_id: String,
+    pub workflow_logical_name: String,
+    pub workflow_version: i32,
+    pub leased_by: Option<String>,
+    pub lease_expires_at: String,
+    pub run_attempts: i32,
+    pub last_error: Option<String>,
+}
+
+/// Incoming payload for failing one stuck workflow job.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/fail-workflow-job-request.ts"
+)]
+pub struct FailWorkflowJobRequest {
+    /// Dead-letter reason recorded on the run.
+    pub reason: Option<String>,
+}
+
+/// Current holder of one worker coordination scope.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-worker-lease-response.ts"
+)]
+pub struct WorkflowWorkerLeaseResponse {
+    pub scope_key: String,
+    /// Worker holding the lease; `null` when the scope is not leased.
+    pub holder_id: Option<String>,
+    #[ts(type = "number | null")]
+    pub expires_in_seconds: Option<i64>,
 }
 
 /// API representation of one workflow run attempt.
@@ -234,6 +516,7 @@ pub struct WorkflowRunStepTraceResponse {
     pub output_payload: Value,
     pub error_message: Option<String>,
     pub duration_ms: Option<u64>,
+    pub retries: u32,
 }
 
 /// API representation of one replay timeline event.
@@ -270,3 +553,94 @@ pub struct WorkflowRunReplayResponse {
     pub timeline: Vec<WorkflowRunReplayTimelineEventResponse>,
     pub checksum_sha256: String,
 }
+
+/// Incoming payload for configuring or rotating a workflow inbound webhook.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/configure-workflow-inbound-webhook-request.ts"
+)]
+pub struct ConfigureWorkflowInboundWebhookRequest {
+    #[ts(type = "Record<string, unknown> | null")]
+    pub payload_schema: Option<Value>,
+}
+
+/// API representation of a workflow inbound webhook without its credentials.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-inbound-webhook-response.ts"
+)]
+pub struct WorkflowInboundWebhookResponse {
+    pub workflow_logical_name: String,
+    #[ts(type = "Record<string, unknown> | null")]
+    pub payload_schema: Option<Value>,
+    pub created_by_subject: String,
+    pub created_at: String,
+    #[ts(type = "number")]
+    pub delivery_count: i64,
+    pub last_received_at: Option<String>,
+}
+
+/// Newly configured inbound webhook with credentials that are only shown once.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/created-workflow-inbound-webhook-response.ts"
+)]
+pub struct CreatedWorkflowInboundWebhookResponse {
+    pub webhook: WorkflowInboundWebhookResponse,
+    pub webhook_path: String,
+    pub signing_secret: String,
+}
+
+/// Authentication scheme of a workflow credential; secrets are secret-provider references.
+#[derive(Debug, Clone, Deserialize, Serialize, TS)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-credential-auth-dto.ts"
+)]
+pub enum WorkflowCredentialAuthDto {
+    ApiKey {
+        header_name: String,
+        #[serde(default)]
+        prefix: Option<String>,
+        secret_ref: String,
+    },
+    #[serde(rename = "oauth2_client_credentials")]
+    OAuth2ClientCredentials {
+        token_url: String,
+        client_id: String,
+        client_secret_ref: String,
+        #[serde(default)]
+        scope: Option<String>,
+    },
+}
+
+/// Incoming payload for creating or replacing a workflow credential.
+#[derive(Debug, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/save-workflow-credential-request.ts"
+)]
+pub struct SaveWorkflowCredentialRequest {
+    pub display_name: String,
+    pub auth: WorkflowCredentialAuthDto,
+    pub allowed_hosts: Vec<String>,
+}
+
+/// API representation of a workflow credential.
+#[derive(Debug, Serialize, TS)]
+#[ts(
+    export,
+    export_to = "../../../packages/api-types/src/generated/workflow-credential-response.ts"
+)]
+pub struct WorkflowCredentialResponse {
+    pub logical_name: String,
+    pub display_name: String,
+    pub auth: WorkflowCredentialAuthDto,
+    pub allowed_hosts: Vec<String>,
+    pub updated_by_subject: String,
+    pub updated_at: String,
+}
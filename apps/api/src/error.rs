@@ -2,6 +2,7 @@ use axum::Json;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use qryvanta_core::AppError;
+use qryvanta_domain::{DUPLICATE_RECORDS_DETECTED_PREFIX, DuplicateRecordsConflict};
 
 mod codes;
 mod types;
@@ -30,10 +31,26 @@ impl IntoResponse for ApiError {
             AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
             AppError::Forbidden(_) => StatusCode::FORBIDDEN,
             AppError::RateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::Timeout(_) => StatusCode::GATEWAY_TIMEOUT,
             AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
         };
 
-        let payload = Json(ErrorResponse::new(code.to_owned(), self.0.to_string()));
+        let duplicate_conflict = match &self.0 {
+            AppError::Conflict(detail) => DuplicateRecordsConflict::from_error_detail(detail),
+            _ => None,
+        };
+        let payload = Json(match duplicate_conflict {
+            Some(conflict) => ErrorResponse::new(
+                code.to_owned(),
+                format!(
+                    "conflict: {DUPLICATE_RECORDS_DETECTED_PREFIX} for entity '{}' (candidates: {})",
+                    conflict.entity_logical_name,
+                    conflict.candidates.len()
+                ),
+            )
+            .with_details(serde_json::to_value(conflict).unwrap_or_default()),
+            None => ErrorResponse::new(code.to_owned(), self.0.to_string()),
+        });
 
         if is_rate_limited {
             // OWASP: include Retry-After header on 429 responses.
@@ -44,6 +61,12 @@ impl IntoResponse for ApiError {
     }
 }
 
+impl From<&AppError> for ErrorResponse {
+    fn from(value: &AppError) -> Self {
+        Self::new(codes::error_code_for(value).to_owned(), value.to_string())
+    }
+}
+
 /// Standard API result type.
 pub type ApiResult<T> = Result<T, ApiError>;
 
@@ -53,6 +76,9 @@ mod tests {
 
     use super::ApiError;
     use qryvanta_core::AppError;
+    use qryvanta_domain::{
+        DuplicateRecordCandidate, DuplicateRecordsConflict, DuplicateRuleAction,
+    };
 
     #[tokio::test]
     async fn validation_response_contains_stable_publish_code() {
@@ -76,6 +102,49 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn duplicate_conflict_response_carries_candidates() {
+        let response = ApiError(
+            DuplicateRecordsConflict {
+                entity_logical_name: "account".to_owned(),
+                action: DuplicateRuleAction::Warn,
+                candidates: vec![DuplicateRecordCandidate {
+                    record_id: "record-1".to_owned(),
+                    rule_logical_name: "same_name".to_owned(),
+                    action: DuplicateRuleAction::Warn,
+                    matched_fields: vec!["name".to_owned()],
+                }],
+            }
+            .into_error(),
+        )
+        .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CONFLICT);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        let payload: serde_json::Value =
+            serde_json::from_slice(body.as_ref()).unwrap_or_else(|_| unreachable!());
+
+        assert_eq!(
+            payload.get("code").and_then(serde_json::Value::as_str),
+            Some("conflict.runtime.duplicate_detected")
+        );
+        assert_eq!(
+            payload
+                .pointer("/details/candidates/0/record_id")
+                .and_then(serde_json::Value::as_str),
+            Some("record-1")
+        );
+        assert_eq!(
+            payload
+                .pointer("/details/action")
+                .and_then(serde_json::Value::as_str),
+            Some("warn")
+        );
+    }
+
     #[tokio::test]
     async fn rate_limited_response_sets_retry_after_header() {
         let response = ApiError(AppError::RateLimited(
@@ -1,4 +1,9 @@
+use qryvanta_application::{PUBLISH_DRAFT_CHANGED_PREFIX, PUBLISH_LOCKED_PREFIX};
 use qryvanta_core::AppError;
+use qryvanta_domain::{
+    DUPLICATE_RECORDS_DETECTED_PREFIX, RECORD_INACTIVE_PREFIX,
+    RECORD_STATUS_TRANSITION_INVALID_PREFIX,
+};
 
 pub(super) const VALIDATION_GENERIC: &str = "validation.generic";
 pub(super) const VALIDATION_PUBLISH_CHECKS_FAILED: &str = "validation.publish.checks_failed";
@@ -10,10 +15,15 @@ pub(super) const VALIDATION_RUNTIME_PAYLOAD_REQUIRED_FIELD_MISSING: &str =
     "validation.runtime.payload.required_field_missing";
 pub(super) const VALIDATION_RUNTIME_PAYLOAD_CALCULATED_FIELD_READ_ONLY: &str =
     "validation.runtime.payload.calculated_field_read_only";
+pub(super) const VALIDATION_RUNTIME_PAYLOAD_SYSTEM_FIELD_READ_ONLY: &str =
+    "validation.runtime.payload.system_field_read_only";
 pub(super) const VALIDATION_RUNTIME_RELATION_TARGET_MISSING: &str =
     "validation.runtime.relation.target_missing";
 pub(super) const VALIDATION_RUNTIME_BUSINESS_RULE_LOCKED_FIELD: &str =
     "validation.runtime.business_rule.locked_field";
+pub(super) const VALIDATION_RUNTIME_STATUS_TRANSITION_INVALID: &str =
+    "validation.runtime.status.transition_invalid";
+pub(super) const VALIDATION_RUNTIME_RECORD_INACTIVE: &str = "validation.runtime.record.inactive";
 pub(super) const VALIDATION_RUNTIME_QUERY_LIMIT_INVALID: &str =
     "validation.runtime.query.limit_invalid";
 pub(super) const VALIDATION_RUNTIME_QUERY_WHERE_EMPTY: &str =
@@ -44,24 +54,43 @@ pub(super) const VALIDATION_RUNTIME_QUERY_LINK_INVALID: &str =
     "validation.runtime.query.link_invalid";
 pub(super) const NOT_FOUND: &str = "not_found";
 pub(super) const CONFLICT: &str = "conflict";
+pub(super) const CONFLICT_RUNTIME_DUPLICATE_DETECTED: &str = "conflict.runtime.duplicate_detected";
+pub(super) const CONFLICT_PUBLISH_LOCKED: &str = "conflict.publish.locked";
+pub(super) const CONFLICT_PUBLISH_DRAFT_CHANGED: &str = "conflict.publish.draft_changed";
 pub(super) const UNAUTHORIZED: &str = "unauthorized";
 pub(super) const FORBIDDEN: &str = "forbidden";
 pub(super) const FORBIDDEN_STEP_UP_REQUIRED: &str = "forbidden.step_up_required";
 pub(super) const RATE_LIMITED: &str = "rate_limited";
+pub(super) const TIMEOUT: &str = "timeout";
 pub(super) const INTERNAL_ERROR: &str = "internal_error";
 
 pub(super) fn error_code_for(error: &AppError) -> &'static str {
     match error {
         AppError::Validation(detail) => validation_code_for(detail.as_str()),
         AppError::NotFound(_) => NOT_FOUND,
-        AppError::Conflict(_) => CONFLICT,
+        AppError::Conflict(detail) => conflict_code_for(detail.as_str()),
         AppError::Unauthorized(_) => UNAUTHORIZED,
         AppError::Forbidden(detail) => forbidden_code_for(detail.as_str()),
         AppError::RateLimited(_) => RATE_LIMITED,
+        AppError::Timeout(_) => TIMEOUT,
         AppError::Internal(_) => INTERNAL_ERROR,
     }
 }
 
+fn conflict_code_for(detail: &str) -> &'static str {
+    if detail.starts_with(DUPLICATE_RECORDS_DETECTED_PREFIX) {
+        return CONFLICT_RUNTIME_DUPLICATE_DETECTED;
+    }
+    if detail.starts_with(PUBLISH_LOCKED_PREFIX) {
+        return CONFLICT_PUBLISH_LOCKED;
+    }
+    if detail.starts_with(PUBLISH_DRAFT_CHANGED_PREFIX) {
+        return CONFLICT_PUBLISH_DRAFT_CHANGED;
+    }
+
+    CONFLICT
+}
+
 fn forbidden_code_for(detail: &str) -> &'static str {
     if detail == "step-up authentication required for this action" {
         return FORBIDDEN_STEP_UP_REQUIRED;
@@ -87,12 +116,21 @@ fn validation_code_for(detail: &str) -> &'static str {
     if detail.starts_with("calculated field '") && detail.ends_with(" cannot be set directly") {
         return VALIDATION_RUNTIME_PAYLOAD_CALCULATED_FIELD_READ_ONLY;
     }
+    if detail.starts_with("system field '") && detail.ends_with(" is read-only") {
+        return VALIDATION_RUNTIME_PAYLOAD_SYSTEM_FIELD_READ_ONLY;
+    }
     if detail.starts_with("relation field '") && detail.contains("references missing record") {
         return VALIDATION_RUNTIME_RELATION_TARGET_MISSING;
     }
     if detail.starts_with("business rule lock prevents updating field '") {
         return VALIDATION_RUNTIME_BUSINESS_RULE_LOCKED_FIELD;
     }
+    if detail.starts_with(RECORD_STATUS_TRANSITION_INVALID_PREFIX) {
+        return VALIDATION_RUNTIME_STATUS_TRANSITION_INVALID;
+    }
+    if detail.starts_with(RECORD_INACTIVE_PREFIX) {
+        return VALIDATION_RUNTIME_RECORD_INACTIVE;
+    }
 
     if detail == "runtime record query limit must be greater than zero" {
         return VALIDATION_RUNTIME_QUERY_LIMIT_INVALID;
@@ -132,7 +170,7 @@ fn validation_code_for(detail: &str) -> &'static str {
     {
         return VALIDATION_RUNTIME_QUERY_FIELD_TYPE_MISMATCH;
     }
-    if detail.starts_with("operator '") {
+    if detail.starts_with("operator '") || detail.starts_with("filter token '") {
         return VALIDATION_RUNTIME_QUERY_OPERATOR_INVALID;
     }
     if detail.starts_with("sorting is not supported for json field '") {
@@ -169,10 +207,60 @@ mod tests {
         ));
         assert_eq!(payload_code, VALIDATION_RUNTIME_PAYLOAD_NOT_OBJECT);
 
+        let system_field_code = error_code_for(&AppError::Validation(
+            "system field 'created_by' is read-only".to_owned(),
+        ));
+        assert_eq!(
+            system_field_code,
+            VALIDATION_RUNTIME_PAYLOAD_SYSTEM_FIELD_READ_ONLY
+        );
+
         let query_code = error_code_for(&AppError::Validation(
             "runtime record query limit must be greater than zero".to_owned(),
         ));
         assert_eq!(query_code, VALIDATION_RUNTIME_QUERY_LIMIT_INVALID);
+
+        let token_code = error_code_for(&AppError::Validation(
+            "filter token '@my_team' on field 'owner' requires operator 'eq', 'neq', 'in' or 'not_in'"
+                .to_owned(),
+        ));
+        assert_eq!(token_code, VALIDATION_RUNTIME_QUERY_OPERATOR_INVALID);
+
+        let status_code = error_code_for(&AppError::Validation(format!(
+            "{RECORD_STATUS_TRANSITION_INVALID_PREFIX}: 'open' cannot move to 'closed'"
+        )));
+        assert_eq!(status_code, VALIDATION_RUNTIME_STATUS_TRANSITION_INVALID);
+
+        let inactive_code = error_code_for(&AppError::Validation(format!(
+            "{RECORD_INACTIVE_PREFIX}: status 'closed' only allows reactivation"
+        )));
+        assert_eq!(inactive_code, VALIDATION_RUNTIME_RECORD_INACTIVE);
+    }
+
+    #[test]
+    fn classifies_duplicate_record_conflicts() {
+        let duplicate_code = error_code_for(&AppError::Conflict(format!(
+            "{DUPLICATE_RECORDS_DETECTED_PREFIX}: {{}}"
+        )));
+        assert_eq!(duplicate_code, CONFLICT_RUNTIME_DUPLICATE_DETECTED);
+
+        let version_code = error_code_for(&AppError::Conflict(
+            "record 'r1' was modified by another request".to_owned(),
+        ));
+        assert_eq!(version_code, CONFLICT);
+    }
+
+    #[test]
+    fn classifies_publish_coordination_conflicts() {
+        let locked_code = error_code_for(&AppError::Conflict(format!(
+            "{PUBLISH_LOCKED_PREFIX}: entity 'contact' is being published by 'alice'"
+        )));
+        assert_eq!(locked_code, CONFLICT_PUBLISH_LOCKED);
+
+        let changed_code = error_code_for(&AppError::Conflict(format!(
+            "{PUBLISH_DRAFT_CHANGED_PREFIX}: entity 'contact' was edited after the diff was reviewed"
+        )));
+        assert_eq!(changed_code, CONFLICT_PUBLISH_DRAFT_CHANGED);
     }
 
     #[test]
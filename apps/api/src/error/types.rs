@@ -1,8 +1,10 @@
 use serde::Serialize;
+use serde_json::Value;
 use ts_rs::TS;
+use utoipa::ToSchema;
 
 /// API error payload.
-#[derive(Debug, Serialize, TS)]
+#[derive(Debug, Serialize, TS, ToSchema)]
 #[ts(
     export,
     export_to = "../../../packages/api-types/src/generated/error-response.ts"
@@ -10,10 +12,24 @@ use ts_rs::TS;
 pub struct ErrorResponse {
     code: String,
     message: String,
+    /// Structured context for errors clients can act on, such as the
+    /// candidates of a duplicate-record conflict.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional, type = "unknown")]
+    details: Option<Value>,
 }
 
 impl ErrorResponse {
     pub(super) fn new(code: String, message: String) -> Self {
-        Self { code, message }
+        Self {
+            code,
+            message,
+            details: None,
+        }
+    }
+
+    pub(super) fn with_details(mut self, details: Value) -> Self {
+        self.details = Some(details);
+        self
     }
 }
@@ -0,0 +1,135 @@
+use axum::Json;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+
+use qryvanta_application::{AnnouncementSeverity, SaveAnnouncementInput};
+use qryvanta_core::{AppError, UserIdentity};
+
+use crate::dto::{AnnouncementResponse, SaveAnnouncementRequest};
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ActiveAnnouncementsQuery {
+    pub app_logical_name: Option<String>,
+}
+
+pub async fn list_announcements_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<AnnouncementResponse>>> {
+    let announcements = state.announcement_service.list_announcements(&user).await?;
+
+    Ok(Json(
+        announcements
+            .into_iter()
+            .map(AnnouncementResponse::from)
+            .collect(),
+    ))
+}
+
+pub async fn publish_announcement_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Json(payload): Json<SaveAnnouncementRequest>,
+) -> ApiResult<(StatusCode, Json<AnnouncementResponse>)> {
+    let announcement = state
+        .announcement_service
+        .publish_announcement(&user, announcement_input(payload)?)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(AnnouncementResponse::from(announcement)),
+    ))
+}
+
+pub async fn update_announcement_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(announcement_id): Path<String>,
+    Json(payload): Json<SaveAnnouncementRequest>,
+) -> ApiResult<Json<AnnouncementResponse>> {
+    let announcement = state
+        .announcement_service
+        .update_announcement(
+            &user,
+            announcement_id.as_str(),
+            announcement_input(payload)?,
+        )
+        .await?;
+
+    Ok(Json(AnnouncementResponse::from(announcement)))
+}
+
+pub async fn delete_announcement_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(announcement_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .announcement_service
+        .delete_announcement(&user, announcement_id.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn active_announcements_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<ActiveAnnouncementsQuery>,
+) -> ApiResult<Json<Vec<AnnouncementResponse>>> {
+    let announcements = state
+        .announcement_service
+        .active_announcements(&user, query.app_logical_name.as_deref())
+        .await?;
+
+    Ok(Json(
+        announcements
+            .into_iter()
+            .map(AnnouncementResponse::from)
+            .collect(),
+    ))
+}
+
+pub async fn dismiss_announcement_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(announcement_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .announcement_service
+        .dismiss_announcement(&user, announcement_id.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn announcement_input(payload: SaveAnnouncementRequest) -> Result<SaveAnnouncementInput, AppError> {
+    Ok(SaveAnnouncementInput {
+        title: payload.title,
+        body: payload.body,
+        severity: AnnouncementSeverity::parse(payload.severity.as_str())?,
+        starts_at: parse_timestamp("starts_at", payload.starts_at.as_deref())?,
+        ends_at: parse_timestamp("ends_at", payload.ends_at.as_deref())?,
+        audience_roles: payload.audience_roles,
+        audience_apps: payload.audience_apps,
+        is_dismissible: payload.is_dismissible,
+    })
+}
+
+fn parse_timestamp(field: &str, value: Option<&str>) -> Result<Option<DateTime<Utc>>, AppError> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+                .map_err(|error| {
+                    AppError::Validation(format!("{field} must be an RFC 3339 timestamp: {error}"))
+                })
+        })
+        .transpose()
+}
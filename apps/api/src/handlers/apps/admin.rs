@@ -1,28 +1,36 @@
 use axum::Json;
 use axum::extract::{Extension, Path, State};
-use axum::http::StatusCode;
+use axum::http::{HeaderMap, StatusCode};
 use qryvanta_core::UserIdentity;
-use qryvanta_domain::{AppSitemap, SitemapArea, SitemapGroup, SitemapSubArea, SitemapTarget};
+use qryvanta_domain::{
+    AppSitemap, LocalizedComponentType, SitemapArea, SitemapGroup, SitemapSubArea, SitemapTarget,
+};
 
 use crate::dto::{
-    AppEntityBindingResponse, AppPublishChecksResponse, AppResponse,
+    AppAdminGrantResponse, AppEntityBindingResponse, AppPublishChecksResponse, AppResponse,
     AppRoleEntityPermissionResponse, AppSitemapAreaDto, AppSitemapGroupDto, AppSitemapResponse,
     AppSitemapSubAreaDto, AppSitemapTargetDto, BindAppEntityRequest, CreateAppRequest,
-    SaveAppRoleEntityPermissionRequest, SaveAppSitemapRequest,
+    GrantAppAdminRequest, SaveAppRoleEntityPermissionRequest, SaveAppSitemapRequest,
 };
 use crate::error::ApiResult;
+use crate::handlers::localization::resolve_localized_labels;
 use crate::state::AppState;
 
 pub async fn list_apps_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
+    headers: HeaderMap,
 ) -> ApiResult<Json<Vec<AppResponse>>> {
-    let apps = state
-        .app_service
-        .list_apps(&user)
-        .await?
+    let apps = state.app_service.list_apps(&user).await?;
+    let mut localized_labels =
+        resolve_localized_labels(&state, &user, &headers, LocalizedComponentType::App).await?;
+    let apps = apps
         .into_iter()
-        .map(AppResponse::from)
+        .map(|app| {
+            let mut response = AppResponse::from(app);
+            response.localized_label = localized_labels.remove(&response.logical_name);
+            response
+        })
         .collect();
 
     Ok(Json(apps))
@@ -114,6 +122,23 @@ pub async fn bind_app_entity_handler(
     ))
 }
 
+pub async fn unbind_app_entity_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((app_logical_name, entity_logical_name)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    state
+        .app_service
+        .unbind_entity(
+            &user,
+            app_logical_name.as_str(),
+            entity_logical_name.as_str(),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 pub async fn list_app_role_permissions_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -194,6 +219,52 @@ pub async fn save_app_sitemap_handler(
     Ok(Json(AppSitemapResponse::from(saved)))
 }
 
+pub async fn list_app_admins_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(app_logical_name): Path<String>,
+) -> ApiResult<Json<Vec<AppAdminGrantResponse>>> {
+    let admins = state
+        .app_service
+        .list_app_admins(&user, app_logical_name.as_str())
+        .await?
+        .into_iter()
+        .map(AppAdminGrantResponse::from)
+        .collect();
+
+    Ok(Json(admins))
+}
+
+pub async fn grant_app_admin_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(app_logical_name): Path<String>,
+    Json(payload): Json<GrantAppAdminRequest>,
+) -> ApiResult<(StatusCode, Json<AppAdminGrantResponse>)> {
+    let grant = state
+        .app_service
+        .grant_app_admin(&user, app_logical_name.as_str(), payload.subject.as_str())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(AppAdminGrantResponse::from(grant)),
+    ))
+}
+
+pub async fn revoke_app_admin_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((app_logical_name, subject)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    state
+        .app_service
+        .revoke_app_admin(&user, app_logical_name.as_str(), subject.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 pub async fn app_publish_checks_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -3,14 +3,19 @@ mod workspace;
 
 pub use admin::{
     app_publish_checks_handler, bind_app_entity_handler, create_app_handler,
-    get_app_sitemap_handler, list_app_entities_handler, list_app_role_permissions_handler,
-    list_apps_handler, save_app_role_permission_handler, save_app_sitemap_handler,
+    get_app_sitemap_handler, grant_app_admin_handler, list_app_admins_handler,
+    list_app_entities_handler, list_app_role_permissions_handler, list_apps_handler,
+    revoke_app_admin_handler, save_app_role_permission_handler, save_app_sitemap_handler,
+    unbind_app_entity_handler,
 };
 pub use workspace::{
-    app_navigation_handler, list_workspace_apps_handler, workspace_create_record_handler,
-    workspace_dashboard_handler, workspace_delete_record_handler,
+    app_navigation_handler, list_workspace_apps_handler, workspace_app_capabilities_handler,
+    workspace_bulk_update_records_handler, workspace_create_personal_view_handler,
+    workspace_create_record_handler, workspace_dashboard_handler,
+    workspace_delete_personal_view_handler, workspace_delete_record_handler,
     workspace_entity_capabilities_handler, workspace_entity_schema_handler,
     workspace_get_form_handler, workspace_get_record_handler, workspace_get_view_handler,
-    workspace_list_forms_handler, workspace_list_records_handler, workspace_list_views_handler,
-    workspace_query_records_handler, workspace_update_record_handler,
+    workspace_list_forms_handler, workspace_list_personal_views_handler,
+    workspace_list_records_handler, workspace_list_views_handler, workspace_query_records_handler,
+    workspace_update_personal_view_handler, workspace_update_record_handler,
 };
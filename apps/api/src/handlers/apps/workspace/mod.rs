@@ -1,14 +1,19 @@
 mod navigation;
+mod personal_views;
 mod records;
 
 pub use navigation::{
-    app_navigation_handler, list_workspace_apps_handler, workspace_dashboard_handler,
-    workspace_entity_capabilities_handler, workspace_entity_schema_handler,
-    workspace_get_form_handler, workspace_get_view_handler, workspace_list_forms_handler,
-    workspace_list_views_handler,
+    app_navigation_handler, list_workspace_apps_handler, workspace_app_capabilities_handler,
+    workspace_dashboard_handler, workspace_entity_capabilities_handler,
+    workspace_entity_schema_handler, workspace_get_form_handler, workspace_get_view_handler,
+    workspace_list_forms_handler, workspace_list_views_handler,
+};
+pub use personal_views::{
+    workspace_create_personal_view_handler, workspace_delete_personal_view_handler,
+    workspace_list_personal_views_handler, workspace_update_personal_view_handler,
 };
 pub use records::{
-    workspace_create_record_handler, workspace_delete_record_handler, workspace_get_record_handler,
-    workspace_list_records_handler, workspace_query_records_handler,
-    workspace_update_record_handler,
+    workspace_bulk_update_records_handler, workspace_create_record_handler,
+    workspace_delete_record_handler, workspace_get_record_handler, workspace_list_records_handler,
+    workspace_query_records_handler, workspace_update_record_handler,
 };
@@ -3,8 +3,9 @@ use axum::extract::{Extension, Path, State};
 use qryvanta_core::UserIdentity;
 
 use crate::dto::{
-    AppEntityCapabilitiesResponse, AppResponse, AppSitemapResponse, FormResponse,
-    PublishedSchemaResponse, ViewResponse, WorkspaceDashboardResponse,
+    AppEntityCapabilitiesBulkResponse, AppEntityCapabilitiesResponse, AppNavigationResponse,
+    AppResponse, FormResponse, ViewResponse, WorkspaceDashboardResponse,
+    WorkspaceEntitySchemaResponse,
 };
 use crate::error::ApiResult;
 use crate::state::AppState;
@@ -28,13 +29,17 @@ pub async fn app_navigation_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
     Path(app_logical_name): Path<String>,
-) -> ApiResult<Json<AppSitemapResponse>> {
+) -> ApiResult<Json<AppNavigationResponse>> {
     let sitemap = state
         .app_service
         .app_navigation_for_subject(&user, app_logical_name.as_str())
         .await?;
+    let entities = state
+        .app_service
+        .navigation_entity_presentations(&user, &sitemap)
+        .await?;
 
-    Ok(Json(AppSitemapResponse::from(sitemap)))
+    Ok(Json(AppNavigationResponse::from_sitemap(sitemap, entities)))
 }
 
 pub async fn workspace_dashboard_handler(
@@ -58,7 +63,7 @@ pub async fn workspace_entity_schema_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
     Path((app_logical_name, entity_logical_name)): Path<(String, String)>,
-) -> ApiResult<Json<PublishedSchemaResponse>> {
+) -> ApiResult<Json<WorkspaceEntitySchemaResponse>> {
     let schema = state
         .app_service
         .schema_for_subject(
@@ -67,8 +72,34 @@ pub async fn workspace_entity_schema_handler(
             entity_logical_name.as_str(),
         )
         .await?;
+    let forms = state
+        .app_service
+        .list_entity_forms(
+            &user,
+            app_logical_name.as_str(),
+            entity_logical_name.as_str(),
+        )
+        .await?;
+
+    Ok(Json(WorkspaceEntitySchemaResponse::from_schema_and_forms(
+        schema, &forms,
+    )))
+}
+
+pub async fn workspace_app_capabilities_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(app_logical_name): Path<String>,
+) -> ApiResult<Json<AppEntityCapabilitiesBulkResponse>> {
+    let summaries = state
+        .app_service
+        .entity_capabilities_bulk_for_subject(&user, app_logical_name.as_str())
+        .await?;
 
-    Ok(Json(PublishedSchemaResponse::from(schema)))
+    Ok(Json(AppEntityCapabilitiesBulkResponse::from_summaries(
+        app_logical_name,
+        summaries,
+    )))
 }
 
 pub async fn workspace_entity_capabilities_handler(
@@ -84,8 +115,19 @@ pub async fn workspace_entity_capabilities_handler(
             entity_logical_name.as_str(),
         )
         .await?;
+    let presentation = state
+        .app_service
+        .entity_presentation_for_subject(
+            &user,
+            app_logical_name.as_str(),
+            entity_logical_name.as_str(),
+        )
+        .await?;
 
-    Ok(Json(AppEntityCapabilitiesResponse::from(capabilities)))
+    Ok(Json(AppEntityCapabilitiesResponse::from_permission(
+        capabilities,
+        presentation,
+    )))
 }
 
 pub async fn workspace_list_forms_handler(
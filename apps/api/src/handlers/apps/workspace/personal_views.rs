@@ -0,0 +1,121 @@
+use axum::Json;
+use axum::extract::{Extension, Path, State};
+use axum::http::StatusCode;
+
+use qryvanta_application::SavePersonalViewInput;
+use qryvanta_core::{AppError, UserIdentity};
+use qryvanta_domain::{ViewColumn, ViewFilterGroup, ViewSort};
+
+use crate::dto::{PersonalViewResponse, SavePersonalViewRequest};
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+pub async fn workspace_list_personal_views_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((app_logical_name, entity_logical_name)): Path<(String, String)>,
+) -> ApiResult<Json<Vec<PersonalViewResponse>>> {
+    let views = state
+        .app_service
+        .list_personal_views(
+            &user,
+            app_logical_name.as_str(),
+            entity_logical_name.as_str(),
+        )
+        .await?
+        .into_iter()
+        .map(PersonalViewResponse::from)
+        .collect();
+
+    Ok(Json(views))
+}
+
+pub async fn workspace_create_personal_view_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((app_logical_name, entity_logical_name)): Path<(String, String)>,
+    Json(payload): Json<SavePersonalViewRequest>,
+) -> ApiResult<(StatusCode, Json<PersonalViewResponse>)> {
+    let view = state
+        .app_service
+        .create_personal_view(
+            &user,
+            app_logical_name.as_str(),
+            entity_logical_name.as_str(),
+            personal_view_input(payload)?,
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(PersonalViewResponse::from(view))))
+}
+
+pub async fn workspace_update_personal_view_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((app_logical_name, entity_logical_name, view_id)): Path<(String, String, String)>,
+    Json(payload): Json<SavePersonalViewRequest>,
+) -> ApiResult<Json<PersonalViewResponse>> {
+    let view = state
+        .app_service
+        .update_personal_view(
+            &user,
+            app_logical_name.as_str(),
+            entity_logical_name.as_str(),
+            view_id.as_str(),
+            personal_view_input(payload)?,
+        )
+        .await?;
+
+    Ok(Json(PersonalViewResponse::from(view)))
+}
+
+pub async fn workspace_delete_personal_view_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((app_logical_name, entity_logical_name, view_id)): Path<(String, String, String)>,
+) -> ApiResult<StatusCode> {
+    state
+        .app_service
+        .delete_personal_view(
+            &user,
+            app_logical_name.as_str(),
+            entity_logical_name.as_str(),
+            view_id.as_str(),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn personal_view_input(
+    payload: SavePersonalViewRequest,
+) -> Result<SavePersonalViewInput, AppError> {
+    let columns = payload
+        .columns
+        .into_iter()
+        .map(serde_json::from_value::<ViewColumn>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| AppError::Validation(format!("invalid view column payload: {error}")))?;
+    let default_sort = payload
+        .default_sort
+        .map(serde_json::from_value::<ViewSort>)
+        .transpose()
+        .map_err(|error| {
+            AppError::Validation(format!("invalid view default_sort payload: {error}"))
+        })?;
+    let filter_criteria = payload
+        .filter_criteria
+        .map(serde_json::from_value::<ViewFilterGroup>)
+        .transpose()
+        .map_err(|error| {
+            AppError::Validation(format!("invalid view filter_criteria payload: {error}"))
+        })?;
+
+    Ok(SavePersonalViewInput {
+        display_name: payload.display_name,
+        columns,
+        default_sort,
+        filter_criteria,
+        is_shared: payload.is_shared,
+    })
+}
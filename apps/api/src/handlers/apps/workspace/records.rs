@@ -1,29 +1,42 @@
 use axum::Json;
-use axum::extract::{Extension, Path, Query, State};
+use axum::extract::{Extension, OriginalUri, Path, Query, State};
 use axum::http::StatusCode;
+use qryvanta_application::RuntimeRecordBulkUpdateInput;
 use qryvanta_core::UserIdentity;
 use tracing::warn;
 
 use crate::dto::{
-    CreateRuntimeRecordRequest, QueryRuntimeRecordsRequest, RuntimeRecordResponse,
-    UpdateRuntimeRecordRequest,
+    BulkUpdateRuntimeRecordsRequest, BulkUpdateRuntimeRecordsResponse, CreateRuntimeRecordRequest,
+    QueryRuntimeRecordsRequest, RuntimeRecordResponse, UpdateRuntimeRecordRequest,
 };
 use crate::error::ApiResult;
 use crate::handlers::runtime::runtime_record_query_from_request;
+use crate::pagination::{PageWindow, PaginatedJson};
 use crate::state::AppState;
 
 #[derive(Debug, serde::Deserialize)]
 pub struct RuntimeRecordListQuery {
     pub limit: Option<usize>,
     pub offset: Option<usize>,
+    pub include_inactive: Option<bool>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RuntimeRecordQueryParams {
+    pub view: Option<String>,
 }
 
 pub async fn workspace_list_records_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
+    OriginalUri(uri): OriginalUri,
     Path((app_logical_name, entity_logical_name)): Path<(String, String)>,
     Query(query): Query<RuntimeRecordListQuery>,
-) -> ApiResult<Json<Vec<RuntimeRecordResponse>>> {
+) -> ApiResult<PaginatedJson<RuntimeRecordResponse>> {
+    let window = PageWindow {
+        limit: query.limit.unwrap_or(50),
+        offset: query.offset.unwrap_or(0),
+    };
     let records = state
         .app_service
         .list_records(
@@ -31,9 +44,10 @@ pub async fn workspace_list_records_handler(
             app_logical_name.as_str(),
             entity_logical_name.as_str(),
             qryvanta_application::RecordListQuery {
-                limit: query.limit.unwrap_or(50),
-                offset: query.offset.unwrap_or(0),
+                limit: window.limit,
+                offset: window.offset,
                 owner_subject: None,
+                include_inactive: query.include_inactive.unwrap_or(false),
             },
         )
         .await?
@@ -41,7 +55,7 @@ pub async fn workspace_list_records_handler(
         .map(RuntimeRecordResponse::from)
         .collect();
 
-    Ok(Json(records))
+    Ok(PaginatedJson::new(&uri, window, records))
 }
 
 pub async fn workspace_create_record_handler(
@@ -108,6 +122,7 @@ pub async fn workspace_query_records_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
     Path((app_logical_name, entity_logical_name)): Path<(String, String)>,
+    Query(params): Query<RuntimeRecordQueryParams>,
     Json(payload): Json<QueryRuntimeRecordsRequest>,
 ) -> ApiResult<Json<Vec<RuntimeRecordResponse>>> {
     let _query_permit = state.try_acquire_runtime_query_permit()?;
@@ -120,18 +135,34 @@ pub async fn workspace_query_records_handler(
     )
     .await?;
 
-    let records = state
-        .app_service
-        .query_records(
-            &user,
-            app_logical_name.as_str(),
-            entity_logical_name.as_str(),
-            query,
-        )
-        .await?
-        .into_iter()
-        .map(RuntimeRecordResponse::from)
-        .collect();
+    let records = match params.view {
+        Some(view_logical_name) => {
+            state
+                .app_service
+                .query_view_records(
+                    &user,
+                    app_logical_name.as_str(),
+                    entity_logical_name.as_str(),
+                    view_logical_name.as_str(),
+                    query,
+                )
+                .await?
+        }
+        None => {
+            state
+                .app_service
+                .query_records(
+                    &user,
+                    app_logical_name.as_str(),
+                    entity_logical_name.as_str(),
+                    query,
+                )
+                .await?
+        }
+    }
+    .into_iter()
+    .map(RuntimeRecordResponse::from)
+    .collect();
 
     Ok(Json(records))
 }
@@ -188,6 +219,7 @@ pub async fn workspace_update_record_handler(
             entity_logical_name.as_str(),
             record_id.as_str(),
             payload.data,
+            payload.expected_version,
         )
         .await?;
 
@@ -213,6 +245,47 @@ pub async fn workspace_update_record_handler(
     Ok(Json(RuntimeRecordResponse::from(record)))
 }
 
+pub async fn workspace_bulk_update_records_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((app_logical_name, entity_logical_name)): Path<(String, String)>,
+    Json(payload): Json<BulkUpdateRuntimeRecordsRequest>,
+) -> ApiResult<Json<BulkUpdateRuntimeRecordsResponse>> {
+    let result = state
+        .app_service
+        .bulk_update_records(
+            &user,
+            app_logical_name.as_str(),
+            entity_logical_name.as_str(),
+            RuntimeRecordBulkUpdateInput {
+                record_ids: payload.record_ids,
+                values: payload.values,
+            },
+        )
+        .await?;
+
+    if result.updated_count() > 0
+        && let Err(error) = state
+            .workflow_service
+            .drain_runtime_record_workflow_events_inline(
+                &user,
+                state.workflow_worker_max_claim_limit,
+                state.workflow_worker_default_lease_seconds,
+            )
+            .await
+    {
+        warn!(
+            error = %error,
+            tenant_id = %user.tenant_id(),
+            app_logical_name = %app_logical_name,
+            entity_logical_name = %entity_logical_name,
+            "runtime workflow event drain failed after workspace bulk record update"
+        );
+    }
+
+    Ok(Json(BulkUpdateRuntimeRecordsResponse::from(result)))
+}
+
 pub async fn workspace_delete_record_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -0,0 +1,241 @@
+use axum::Json;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::StatusCode;
+
+use qryvanta_application::{
+    ConsentChannel, ContactConsentStatus, RecordContactConsentInput, SaveContactIdentitySourceInput,
+};
+use qryvanta_core::UserIdentity;
+
+use crate::dto::{
+    ContactConsentChangeResponse, ContactConsentResponse, ContactIdentityLinkResponse,
+    ContactIdentityMatchResponse, ContactIdentityRebuildResponse, ContactIdentitySourceResponse,
+    LinkContactIdentityRequest, MasterContactResponse, RecordContactConsentRequest,
+    SaveContactIdentitySourceRequest,
+};
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+pub async fn list_contact_consents_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(record_id): Path<String>,
+) -> ApiResult<Json<Vec<ContactConsentResponse>>> {
+    let consents = state
+        .contact_consent_service
+        .list_consents(&user, record_id.as_str())
+        .await?
+        .into_iter()
+        .map(ContactConsentResponse::from)
+        .collect();
+
+    Ok(Json(consents))
+}
+
+pub async fn record_contact_consent_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(record_id): Path<String>,
+    Json(payload): Json<RecordContactConsentRequest>,
+) -> ApiResult<(StatusCode, Json<ContactConsentChangeResponse>)> {
+    let change = state
+        .contact_consent_service
+        .record_consent(
+            &user,
+            RecordContactConsentInput {
+                contact_record_id: record_id,
+                purpose: payload.purpose,
+                channel: ConsentChannel::parse(payload.channel.as_str())?,
+                status: ContactConsentStatus::parse(payload.status.as_str())?,
+                source: payload.source,
+            },
+        )
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ContactConsentChangeResponse::from(change)),
+    ))
+}
+
+pub async fn list_contact_consent_history_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(record_id): Path<String>,
+) -> ApiResult<Json<Vec<ContactConsentChangeResponse>>> {
+    let history = state
+        .contact_consent_service
+        .list_consent_history(&user, record_id.as_str())
+        .await?
+        .into_iter()
+        .map(ContactConsentChangeResponse::from)
+        .collect();
+
+    Ok(Json(history))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ContactIdentityMatchQuery {
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub name: Option<String>,
+}
+
+pub async fn list_contact_identity_sources_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<ContactIdentitySourceResponse>>> {
+    let sources = state
+        .contact_identity_service
+        .list_sources(&user)
+        .await?
+        .into_iter()
+        .map(ContactIdentitySourceResponse::from)
+        .collect();
+
+    Ok(Json(sources))
+}
+
+pub async fn save_contact_identity_source_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+    Json(payload): Json<SaveContactIdentitySourceRequest>,
+) -> ApiResult<Json<ContactIdentitySourceResponse>> {
+    let source = state
+        .contact_identity_service
+        .save_source(
+            &user,
+            SaveContactIdentitySourceInput {
+                entity_logical_name,
+                email_field_logical_name: payload.email_field_logical_name,
+                phone_field_logical_name: payload.phone_field_logical_name,
+                name_field_logical_names: payload.name_field_logical_names,
+            },
+        )
+        .await?;
+
+    Ok(Json(ContactIdentitySourceResponse::from(source)))
+}
+
+pub async fn delete_contact_identity_source_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .contact_identity_service
+        .delete_source(&user, entity_logical_name.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn rebuild_contact_identity_source_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<Json<ContactIdentityRebuildResponse>> {
+    let summary = state
+        .contact_identity_service
+        .rebuild_source_links(&user, entity_logical_name.as_str())
+        .await?;
+
+    Ok(Json(ContactIdentityRebuildResponse {
+        entity_logical_name,
+        scanned_records: summary.scanned_records,
+        matched_records: summary.matched_records,
+    }))
+}
+
+pub async fn get_master_contact_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(master_contact_id): Path<String>,
+) -> ApiResult<Json<MasterContactResponse>> {
+    let master = state
+        .contact_identity_service
+        .get_master_contact(&user, master_contact_id.as_str())
+        .await?;
+
+    Ok(Json(MasterContactResponse::from(master)))
+}
+
+pub async fn get_record_master_contact_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id)): Path<(String, String)>,
+) -> ApiResult<Json<MasterContactResponse>> {
+    let master = state
+        .contact_identity_service
+        .master_for_record(&user, entity_logical_name.as_str(), record_id.as_str())
+        .await?;
+
+    Ok(Json(MasterContactResponse::from(master)))
+}
+
+pub async fn resolve_contact_identity_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id)): Path<(String, String)>,
+) -> ApiResult<Json<ContactIdentityLinkResponse>> {
+    let link = state
+        .contact_identity_service
+        .resolve_record(&user, entity_logical_name.as_str(), record_id.as_str())
+        .await?;
+
+    Ok(Json(ContactIdentityLinkResponse::from(link)))
+}
+
+pub async fn link_contact_identity_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id)): Path<(String, String)>,
+    Json(payload): Json<LinkContactIdentityRequest>,
+) -> ApiResult<Json<ContactIdentityLinkResponse>> {
+    let link = state
+        .contact_identity_service
+        .link_record_to_master(
+            &user,
+            entity_logical_name.as_str(),
+            record_id.as_str(),
+            payload.master_contact_id.as_str(),
+        )
+        .await?;
+
+    Ok(Json(ContactIdentityLinkResponse::from(link)))
+}
+
+pub async fn split_contact_identity_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id)): Path<(String, String)>,
+) -> ApiResult<Json<ContactIdentityLinkResponse>> {
+    let link = state
+        .contact_identity_service
+        .split_record(&user, entity_logical_name.as_str(), record_id.as_str())
+        .await?;
+
+    Ok(Json(ContactIdentityLinkResponse::from(link)))
+}
+
+pub async fn find_contact_identity_matches_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<ContactIdentityMatchQuery>,
+) -> ApiResult<Json<Vec<ContactIdentityMatchResponse>>> {
+    let matches = state
+        .contact_identity_service
+        .find_matches(
+            &user,
+            query.email.as_deref(),
+            query.phone.as_deref(),
+            query.name.as_deref(),
+        )
+        .await?
+        .into_iter()
+        .map(ContactIdentityMatchResponse::from)
+        .collect();
+
+    Ok(Json(matches))
+}
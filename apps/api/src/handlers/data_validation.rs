@@ -0,0 +1,111 @@
+use axum::Json;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::StatusCode;
+
+use qryvanta_core::{AppError, UserIdentity};
+
+use crate::dto::{
+    BackgroundJobResponse, DataValidationScheduleResponse, DataValidationViolationResponse,
+    QueueDataValidationAuditRequest, SaveDataValidationScheduleRequest,
+};
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct DataValidationViolationListQuery {
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+/// POST /api/data-validation/audits - Queue a re-validation of stored records.
+///
+/// Progress is reported by the background job endpoints; violations can be
+/// listed while the audit runs.
+pub async fn queue_data_validation_audit_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Json(payload): Json<QueueDataValidationAuditRequest>,
+) -> ApiResult<(StatusCode, Json<BackgroundJobResponse>)> {
+    let published_entity_names = state
+        .metadata_service
+        .list_published_entity_logical_names_unchecked(&user)
+        .await?;
+    let entity_logical_names = match payload.entity_logical_names {
+        Some(requested) if !requested.is_empty() => {
+            if let Some(unknown) = requested
+                .iter()
+                .find(|name| !published_entity_names.contains(name))
+            {
+                return Err(AppError::Validation(format!(
+                    "entity '{unknown}' has no published schema"
+                ))
+                .into());
+            }
+            requested
+        }
+        _ => published_entity_names,
+    };
+
+    let job = state
+        .data_validation_service
+        .request_audit(&user, entity_logical_names)
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, Json(BackgroundJobResponse::from(job))))
+}
+
+/// GET /api/data-validation/audits/{job_id}/violations - List records that failed an audit.
+pub async fn list_data_validation_violations_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(job_id): Path<String>,
+    Query(query): Query<DataValidationViolationListQuery>,
+) -> ApiResult<Json<Vec<DataValidationViolationResponse>>> {
+    let violations = state
+        .data_validation_service
+        .list_violations(
+            &user,
+            job_id.as_str(),
+            query.limit.unwrap_or(100),
+            query.offset.unwrap_or(0),
+        )
+        .await?;
+
+    Ok(Json(
+        violations
+            .into_iter()
+            .map(DataValidationViolationResponse::from)
+            .collect(),
+    ))
+}
+
+pub async fn get_data_validation_schedule_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Option<DataValidationScheduleResponse>>> {
+    let schedule = state.data_validation_service.find_schedule(&user).await?;
+
+    Ok(Json(schedule.map(DataValidationScheduleResponse::from)))
+}
+
+pub async fn save_data_validation_schedule_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Json(payload): Json<SaveDataValidationScheduleRequest>,
+) -> ApiResult<Json<DataValidationScheduleResponse>> {
+    let schedule = state
+        .data_validation_service
+        .save_schedule(&user, payload.interval_days)
+        .await?;
+
+    Ok(Json(DataValidationScheduleResponse::from(schedule)))
+}
+
+pub async fn delete_data_validation_schedule_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<StatusCode> {
+    state.data_validation_service.delete_schedule(&user).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,25 +1,47 @@
 use axum::Json;
 use axum::extract::{Extension, Path, State};
-use axum::http::StatusCode;
+use axum::http::{HeaderMap, StatusCode};
 
 use qryvanta_core::{AppError, UserIdentity};
-use qryvanta_domain::{BusinessRuleAction, BusinessRuleCondition, BusinessRuleScope};
+use qryvanta_domain::{
+    BusinessRuleAction, BusinessRuleCondition, BusinessRuleScope, LocalizedComponentType,
+};
 
 use crate::dto::{BusinessRuleResponse, CreateBusinessRuleRequest};
 use crate::error::ApiResult;
+use crate::handlers::localization::resolve_localized_labels;
 use crate::state::AppState;
 
 pub async fn list_business_rules_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
     Path(entity_logical_name): Path<String>,
+    headers: HeaderMap,
 ) -> ApiResult<Json<Vec<BusinessRuleResponse>>> {
     let rules = state
         .metadata_service
         .list_business_rules(&user, entity_logical_name.as_str())
-        .await?
+        .await?;
+    let mut localized_labels = resolve_localized_labels(
+        &state,
+        &user,
+        &headers,
+        LocalizedComponentType::BusinessRule,
+    )
+    .await?;
+    let rules = rules
         .into_iter()
-        .map(BusinessRuleResponse::from)
+        .map(|rule| {
+            let mut response = BusinessRuleResponse::from(rule);
+            response.localized_label = localized_labels.remove(
+                LocalizedComponentType::business_rule_key(
+                    response.entity_logical_name.as_str(),
+                    response.logical_name.as_str(),
+                )
+                .as_str(),
+            );
+            response
+        })
         .collect();
     Ok(Json(rules))
 }
@@ -4,10 +4,27 @@ use axum::http::StatusCode;
 
 use qryvanta_core::UserIdentity;
 
-use crate::dto::{CreateEntityRequest, EntityResponse, UpdateEntityRequest};
-use crate::error::ApiResult;
+use qryvanta_domain::ENTITY_ICON_CATALOG;
+
+use crate::dto::{
+    CreateEntityRequest, DuplicateRuleResponse, EntityDependencyReportResponse,
+    EntityHistoryPolicyResponse, EntityIconCatalogResponse, EntityResponse,
+    EntitySlugConfigResponse, EntityStatusModelResponse, SaveDuplicateRuleRequest,
+    SaveEntityHistoryPolicyRequest, SaveEntitySlugConfigRequest, SaveEntityStatusModelRequest,
+    UpdateEntityRequest,
+};
+use crate::error::{ApiResult, ErrorResponse};
 use crate::state::AppState;
 
+#[utoipa::path(
+    get,
+    path = "/api/entities",
+    tag = "metadata",
+    responses(
+        (status = 200, description = "Entity definitions", body = [EntityResponse]),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn list_entities_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -23,6 +40,16 @@ pub async fn list_entities_handler(
     Ok(Json(entities))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/entities",
+    tag = "metadata",
+    request_body = CreateEntityRequest,
+    responses(
+        (status = 201, description = "Created entity", body = EntityResponse),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn create_entity_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -37,12 +64,26 @@ pub async fn create_entity_handler(
             payload.description,
             payload.plural_display_name,
             payload.icon,
+            payload.color,
         )
         .await?;
 
     Ok((StatusCode::CREATED, Json(EntityResponse::from(entity))))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/entities/{entity_logical_name}",
+    tag = "metadata",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+    ),
+    request_body = UpdateEntityRequest,
+    responses(
+        (status = 200, description = "Updated entity", body = EntityResponse),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn update_entity_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -59,9 +100,277 @@ pub async fn update_entity_handler(
                 description: payload.description,
                 plural_display_name: payload.plural_display_name,
                 icon: payload.icon,
+                color: payload.color,
             },
         )
         .await?;
 
     Ok(Json(EntityResponse::from(entity)))
 }
+
+#[utoipa::path(
+    delete,
+    path = "/api/entities/{entity_logical_name}",
+    tag = "metadata",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+    ),
+    responses(
+        (status = 204, description = "Entity deleted"),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
+pub async fn delete_entity_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .metadata_service
+        .delete_entity(&user, entity_logical_name.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn entity_dependencies_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<Json<EntityDependencyReportResponse>> {
+    let report = state
+        .metadata_service
+        .analyze_entity_dependencies(&user, entity_logical_name.as_str())
+        .await?;
+
+    Ok(Json(EntityDependencyReportResponse::from(report)))
+}
+
+pub async fn deactivate_entity_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<Json<EntityResponse>> {
+    let entity = state
+        .metadata_service
+        .deactivate_entity(&user, entity_logical_name.as_str())
+        .await?;
+
+    Ok(Json(EntityResponse::from(entity)))
+}
+
+pub async fn reactivate_entity_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<Json<EntityResponse>> {
+    let entity = state
+        .metadata_service
+        .reactivate_entity(&user, entity_logical_name.as_str())
+        .await?;
+
+    Ok(Json(EntityResponse::from(entity)))
+}
+
+pub async fn entity_icon_catalog_handler() -> Json<EntityIconCatalogResponse> {
+    Json(EntityIconCatalogResponse {
+        icons: ENTITY_ICON_CATALOG
+            .iter()
+            .map(|icon| (*icon).to_owned())
+            .collect(),
+    })
+}
+
+pub async fn get_entity_slug_config_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<Json<Option<EntitySlugConfigResponse>>> {
+    let config = state
+        .metadata_service
+        .entity_slug_config(&user, entity_logical_name.as_str())
+        .await?
+        .map(EntitySlugConfigResponse::from);
+
+    Ok(Json(config))
+}
+
+pub async fn save_entity_slug_config_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+    Json(payload): Json<SaveEntitySlugConfigRequest>,
+) -> ApiResult<Json<EntitySlugConfigResponse>> {
+    let config = state
+        .metadata_service
+        .save_entity_slug_config(
+            &user,
+            qryvanta_application::SaveEntitySlugConfigInput {
+                entity_logical_name,
+                slug_field_logical_name: payload.slug_field_logical_name,
+                source_field_logical_name: payload.source_field_logical_name,
+            },
+        )
+        .await?;
+
+    Ok(Json(EntitySlugConfigResponse::from(config)))
+}
+
+pub async fn get_entity_status_model_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<Json<Option<EntityStatusModelResponse>>> {
+    let config = state
+        .metadata_service
+        .entity_status_model(&user, entity_logical_name.as_str())
+        .await?
+        .map(EntityStatusModelResponse::from);
+
+    Ok(Json(config))
+}
+
+pub async fn save_entity_status_model_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+    Json(payload): Json<SaveEntityStatusModelRequest>,
+) -> ApiResult<Json<EntityStatusModelResponse>> {
+    let config = state
+        .metadata_service
+        .save_entity_status_model(
+            &user,
+            qryvanta_application::SaveEntityStatusModelInput {
+                entity_logical_name,
+                status_field_logical_name: payload.status_field_logical_name,
+                reason_field_logical_name: payload.reason_field_logical_name,
+                initial_status: payload.initial_status,
+                statuses: payload.statuses.into_iter().map(Into::into).collect(),
+                transitions: payload.transitions.into_iter().map(Into::into).collect(),
+            },
+        )
+        .await?;
+
+    Ok(Json(EntityStatusModelResponse::from(config)))
+}
+
+pub async fn delete_entity_status_model_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .metadata_service
+        .delete_entity_status_model(&user, entity_logical_name.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn get_entity_history_policy_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<Json<Option<EntityHistoryPolicyResponse>>> {
+    let policy = state
+        .metadata_service
+        .entity_history_policy(&user, entity_logical_name.as_str())
+        .await?
+        .map(EntityHistoryPolicyResponse::from);
+
+    Ok(Json(policy))
+}
+
+pub async fn save_entity_history_policy_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+    Json(payload): Json<SaveEntityHistoryPolicyRequest>,
+) -> ApiResult<Json<EntityHistoryPolicyResponse>> {
+    let policy = state
+        .metadata_service
+        .save_entity_history_policy(
+            &user,
+            qryvanta_application::SaveEntityHistoryPolicyInput {
+                entity_logical_name,
+                retention_days: payload.retention_days,
+            },
+        )
+        .await?;
+
+    Ok(Json(EntityHistoryPolicyResponse::from(policy)))
+}
+
+pub async fn delete_entity_history_policy_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .metadata_service
+        .delete_entity_history_policy(&user, entity_logical_name.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn list_duplicate_rules_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<Json<Vec<DuplicateRuleResponse>>> {
+    let rules = state
+        .metadata_service
+        .list_duplicate_detection_rules(&user, entity_logical_name.as_str())
+        .await?
+        .into_iter()
+        .map(DuplicateRuleResponse::from)
+        .collect();
+
+    Ok(Json(rules))
+}
+
+pub async fn save_duplicate_rule_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, rule_logical_name)): Path<(String, String)>,
+    Json(payload): Json<SaveDuplicateRuleRequest>,
+) -> ApiResult<Json<DuplicateRuleResponse>> {
+    let rule = state
+        .metadata_service
+        .save_duplicate_detection_rule(
+            &user,
+            qryvanta_application::SaveDuplicateDetectionRuleInput {
+                entity_logical_name,
+                logical_name: rule_logical_name,
+                display_name: payload.display_name,
+                match_fields: payload
+                    .match_fields
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, _>>()?,
+                action: payload.action.parse()?,
+                is_active: payload.is_active,
+            },
+        )
+        .await?;
+
+    Ok(Json(DuplicateRuleResponse::from(rule)))
+}
+
+pub async fn delete_duplicate_rule_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, rule_logical_name)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    state
+        .metadata_service
+        .delete_duplicate_detection_rule(
+            &user,
+            entity_logical_name.as_str(),
+            rule_logical_name.as_str(),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
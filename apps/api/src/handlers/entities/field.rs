@@ -4,13 +4,32 @@ use axum::Json;
 use axum::extract::{Extension, Path, State};
 use axum::http::StatusCode;
 
-use qryvanta_core::UserIdentity;
-use qryvanta_domain::FieldType;
+use qryvanta_core::{AppError, UserIdentity};
+use qryvanta_domain::{
+    FieldClassification, FieldType, RelationCascadeBehavior, RelationDeleteBehavior,
+    ViewFilterGroup,
+};
 
-use crate::dto::{CreateFieldRequest, FieldResponse, UpdateFieldRequest};
-use crate::error::ApiResult;
+use crate::dto::{
+    CreateFieldRequest, FieldImpactReportResponse, FieldResponse, RelationBehaviorResponse,
+    RelationLookupConfigResponse, SaveRelationBehaviorRequest, SaveRelationLookupConfigRequest,
+    UpdateFieldRequest,
+};
+use crate::error::{ApiResult, ErrorResponse};
 use crate::state::AppState;
 
+#[utoipa::path(
+    get,
+    path = "/api/entities/{entity_logical_name}/fields",
+    tag = "metadata",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+    ),
+    responses(
+        (status = 200, description = "Draft field definitions", body = [FieldResponse]),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn list_fields_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -27,6 +46,19 @@ pub async fn list_fields_handler(
     Ok(Json(fields))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/entities/{entity_logical_name}/fields",
+    tag = "metadata",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+    ),
+    request_body = CreateFieldRequest,
+    responses(
+        (status = 201, description = "Saved field", body = FieldResponse),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn save_field_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -34,6 +66,11 @@ pub async fn save_field_handler(
     Json(payload): Json<CreateFieldRequest>,
 ) -> ApiResult<(StatusCode, Json<FieldResponse>)> {
     let field_type = FieldType::from_str(payload.field_type.as_str())?;
+    let classification = payload
+        .classification
+        .as_deref()
+        .map(FieldClassification::from_str)
+        .transpose()?;
     let field = state
         .metadata_service
         .save_field(
@@ -49,6 +86,7 @@ pub async fn save_field_handler(
                 calculation_expression: payload.calculation_expression,
                 relation_target_entity: payload.relation_target_entity,
                 option_set_logical_name: payload.option_set_logical_name,
+                classification,
             },
         )
         .await?;
@@ -56,12 +94,31 @@ pub async fn save_field_handler(
     Ok((StatusCode::CREATED, Json(FieldResponse::from(field))))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/entities/{entity_logical_name}/fields/{field_logical_name}",
+    tag = "metadata",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+        ("field_logical_name" = String, Path, description = "Field logical name"),
+    ),
+    request_body = UpdateFieldRequest,
+    responses(
+        (status = 200, description = "Updated field", body = FieldResponse),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn update_field_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
     Path((entity_logical_name, field_logical_name)): Path<(String, String)>,
     Json(payload): Json<UpdateFieldRequest>,
 ) -> ApiResult<Json<FieldResponse>> {
+    let classification = payload
+        .classification
+        .as_deref()
+        .map(FieldClassification::from_str)
+        .transpose()?;
     let field = state
         .metadata_service
         .update_field(
@@ -76,6 +133,7 @@ pub async fn update_field_handler(
                 max_length: payload.max_length,
                 min_value: payload.min_value,
                 max_value: payload.max_value,
+                classification,
             },
         )
         .await?;
@@ -83,6 +141,19 @@ pub async fn update_field_handler(
     Ok(Json(FieldResponse::from(field)))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/entities/{entity_logical_name}/fields/{field_logical_name}",
+    tag = "metadata",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+        ("field_logical_name" = String, Path, description = "Field logical name"),
+    ),
+    responses(
+        (status = 204, description = "Field deleted"),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn delete_field_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -99,3 +170,130 @@ pub async fn delete_field_handler(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+pub async fn field_impact_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, field_logical_name)): Path<(String, String)>,
+) -> ApiResult<Json<FieldImpactReportResponse>> {
+    let report = state
+        .metadata_service
+        .analyze_field_impact(
+            &user,
+            entity_logical_name.as_str(),
+            field_logical_name.as_str(),
+        )
+        .await?;
+
+    Ok(Json(FieldImpactReportResponse::from(report)))
+}
+
+pub async fn list_relation_behaviors_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<Json<Vec<RelationBehaviorResponse>>> {
+    let behaviors = state
+        .metadata_service
+        .list_relation_behaviors(&user, Some(entity_logical_name.as_str()))
+        .await?
+        .into_iter()
+        .map(RelationBehaviorResponse::from)
+        .collect();
+
+    Ok(Json(behaviors))
+}
+
+pub async fn save_relation_behavior_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, field_logical_name)): Path<(String, String)>,
+    Json(payload): Json<SaveRelationBehaviorRequest>,
+) -> ApiResult<Json<RelationBehaviorResponse>> {
+    let behavior = state
+        .metadata_service
+        .save_relation_behavior(
+            &user,
+            qryvanta_application::SaveRelationBehaviorInput {
+                entity_logical_name,
+                field_logical_name,
+                assign_behavior: RelationCascadeBehavior::from_str(
+                    payload.assign_behavior.as_str(),
+                )?,
+                share_behavior: RelationCascadeBehavior::from_str(payload.share_behavior.as_str())?,
+                delete_behavior: payload
+                    .delete_behavior
+                    .as_deref()
+                    .map(RelationDeleteBehavior::from_str)
+                    .transpose()?
+                    .unwrap_or_default(),
+            },
+        )
+        .await?;
+
+    Ok(Json(RelationBehaviorResponse::from(behavior)))
+}
+
+pub async fn get_relation_lookup_config_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, field_logical_name)): Path<(String, String)>,
+) -> ApiResult<Json<Option<RelationLookupConfigResponse>>> {
+    let config = state
+        .metadata_service
+        .relation_lookup_config(
+            &user,
+            entity_logical_name.as_str(),
+            field_logical_name.as_str(),
+        )
+        .await?
+        .map(RelationLookupConfigResponse::from);
+
+    Ok(Json(config))
+}
+
+pub async fn save_relation_lookup_config_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, field_logical_name)): Path<(String, String)>,
+    Json(payload): Json<SaveRelationLookupConfigRequest>,
+) -> ApiResult<Json<RelationLookupConfigResponse>> {
+    let filter = payload
+        .filter
+        .map(serde_json::from_value::<ViewFilterGroup>)
+        .transpose()
+        .map_err(|error| {
+            AppError::Validation(format!("invalid relation lookup filter payload: {error}"))
+        })?;
+    let config = state
+        .metadata_service
+        .save_relation_lookup_config(
+            &user,
+            qryvanta_application::SaveRelationLookupConfigInput {
+                entity_logical_name,
+                field_logical_name,
+                search_field_logical_names: payload.search_field_logical_names,
+                filter,
+            },
+        )
+        .await?;
+
+    Ok(Json(RelationLookupConfigResponse::from(config)))
+}
+
+pub async fn delete_relation_lookup_config_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, field_logical_name)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    state
+        .metadata_service
+        .delete_relation_lookup_config(
+            &user,
+            entity_logical_name.as_str(),
+            field_logical_name.as_str(),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
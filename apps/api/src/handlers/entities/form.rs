@@ -5,7 +5,7 @@ use axum::extract::{Extension, Path, State};
 use axum::http::StatusCode;
 
 use qryvanta_core::{AppError, UserIdentity};
-use qryvanta_domain::{FormTab, FormType};
+use qryvanta_domain::{FormScriptEvents, FormTab, FormType};
 
 use crate::dto::{CreateFormRequest, FormResponse};
 use crate::error::ApiResult;
@@ -50,6 +50,7 @@ pub async fn save_form_handler(
                 form_type,
                 tabs,
                 header_fields: payload.header_fields,
+                script_events: FormScriptEvents::try_from(payload.script_events)?,
             },
         )
         .await?;
@@ -110,6 +111,7 @@ pub async fn update_form_handler(
                 form_type,
                 tabs,
                 header_fields: payload.header_fields,
+                script_events: FormScriptEvents::try_from(payload.script_events)?,
             },
         )
         .await?;
@@ -6,13 +6,26 @@ mod option_set;
 mod publish;
 mod view;
 
+use utoipa::OpenApi;
+
 pub use business_rule::{
     delete_business_rule_handler, get_business_rule_handler, list_business_rules_handler,
     save_business_rule_handler, update_business_rule_handler,
 };
-pub use entity::{create_entity_handler, list_entities_handler, update_entity_handler};
+pub use entity::{
+    create_entity_handler, deactivate_entity_handler, delete_duplicate_rule_handler,
+    delete_entity_handler, delete_entity_history_policy_handler,
+    delete_entity_status_model_handler, entity_dependencies_handler, entity_icon_catalog_handler,
+    get_entity_history_policy_handler, get_entity_slug_config_handler,
+    get_entity_status_model_handler, list_duplicate_rules_handler, list_entities_handler,
+    reactivate_entity_handler, save_duplicate_rule_handler, save_entity_history_policy_handler,
+    save_entity_slug_config_handler, save_entity_status_model_handler, update_entity_handler,
+};
 pub use field::{
-    delete_field_handler, list_fields_handler, save_field_handler, update_field_handler,
+    delete_field_handler, delete_relation_lookup_config_handler, field_impact_handler,
+    get_relation_lookup_config_handler, list_fields_handler, list_relation_behaviors_handler,
+    save_field_handler, save_relation_behavior_handler, save_relation_lookup_config_handler,
+    update_field_handler,
 };
 pub use form::{
     delete_form_handler, get_form_handler, list_forms_handler, save_form_handler,
@@ -23,9 +36,26 @@ pub use option_set::{
     save_option_set_handler, update_option_set_handler,
 };
 pub use publish::{
-    latest_published_schema_handler, publish_checks_handler, publish_entity_handler,
+    latest_published_schema_handler, list_published_schema_versions_handler,
+    publish_checks_handler, publish_entity_handler, published_entities_typescript_handler,
+    published_json_schema_handler, published_schema_version_handler,
+    rollback_entity_schema_checks_handler, rollback_entity_schema_handler,
 };
 pub use view::{
     delete_view_handler, get_view_handler, list_views_handler, save_view_handler,
     update_view_handler,
 };
+
+/// OpenAPI description of the entity and field metadata endpoints.
+#[derive(OpenApi)]
+#[openapi(paths(
+    entity::list_entities_handler,
+    entity::create_entity_handler,
+    entity::update_entity_handler,
+    entity::delete_entity_handler,
+    field::list_fields_handler,
+    field::save_field_handler,
+    field::update_field_handler,
+    field::delete_field_handler,
+))]
+pub struct MetadataApiDoc;
@@ -1,9 +1,15 @@
 use axum::Json;
 use axum::extract::{Extension, Path, State};
+use axum::http::header;
+use axum::response::IntoResponse;
 
 use qryvanta_core::{AppError, UserIdentity};
+use serde_json::Value;
 
-use crate::dto::{PublishChecksResponse, PublishedSchemaResponse};
+use crate::dto::{
+    EntitySchemaRollbackChecksResponse, PublishChecksResponse, PublishedSchemaResponse,
+    PublishedSchemaVersionResponse, PublishedSchemaVersionSummaryResponse,
+};
 use crate::error::ApiResult;
 use crate::state::AppState;
 
@@ -54,3 +60,97 @@ pub async fn latest_published_schema_handler(
 
     Ok(Json(PublishedSchemaResponse::from(published_schema)))
 }
+
+pub async fn list_published_schema_versions_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<Json<Vec<PublishedSchemaVersionSummaryResponse>>> {
+    let versions = state
+        .metadata_service
+        .list_published_schema_versions(&user, entity_logical_name.as_str())
+        .await?
+        .into_iter()
+        .map(PublishedSchemaVersionSummaryResponse::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Json(versions))
+}
+
+pub async fn published_schema_version_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, version)): Path<(String, i32)>,
+) -> ApiResult<Json<PublishedSchemaVersionResponse>> {
+    let published_version = state
+        .metadata_service
+        .find_published_schema_version(&user, entity_logical_name.as_str(), version)
+        .await?
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "entity '{}' does not have published schema version {}",
+                entity_logical_name, version
+            ))
+        })?;
+
+    Ok(Json(PublishedSchemaVersionResponse::try_from(
+        published_version,
+    )?))
+}
+
+pub async fn rollback_entity_schema_checks_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, version)): Path<(String, i32)>,
+) -> ApiResult<Json<EntitySchemaRollbackChecksResponse>> {
+    let checks = state
+        .metadata_service
+        .rollback_entity_schema_checks(&user, entity_logical_name.as_str(), version)
+        .await?;
+
+    Ok(Json(EntitySchemaRollbackChecksResponse::from(checks)))
+}
+
+pub async fn rollback_entity_schema_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, version)): Path<(String, i32)>,
+) -> ApiResult<Json<PublishedSchemaResponse>> {
+    let published_schema = state
+        .metadata_service
+        .rollback_entity_schema(&user, entity_logical_name.as_str(), version)
+        .await?;
+
+    Ok(Json(PublishedSchemaResponse::from(published_schema)))
+}
+
+pub async fn published_json_schema_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<Json<Value>> {
+    let document = state
+        .metadata_service
+        .published_entity_json_schema(&user, entity_logical_name.as_str())
+        .await?;
+
+    Ok(Json(document))
+}
+
+pub async fn published_entities_typescript_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<impl IntoResponse> {
+    let source = state
+        .metadata_service
+        .published_entities_typescript(&user)
+        .await?;
+
+    Ok((
+        [(
+            header::CONTENT_TYPE,
+            "application/typescript; charset=utf-8",
+        )],
+        source,
+    ))
+}
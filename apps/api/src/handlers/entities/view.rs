@@ -6,6 +6,7 @@ use axum::http::StatusCode;
 
 use qryvanta_core::{AppError, UserIdentity};
 use qryvanta_domain::{ViewColumn, ViewFilterGroup, ViewSort, ViewType};
+use tracing::warn;
 
 use crate::dto::{CreateViewRequest, ViewResponse};
 use crate::error::ApiResult;
@@ -161,5 +162,24 @@ pub async fn delete_view_handler(
             view_logical_name.as_str(),
         )
         .await?;
+
+    // The view is gone either way; delivery re-checks and suppresses lazily.
+    if let Err(error) = state
+        .report_subscription_service
+        .suppress_subscriptions_for_deleted_view(
+            &user,
+            entity_logical_name.as_str(),
+            view_logical_name.as_str(),
+        )
+        .await
+    {
+        warn!(
+            error = %error,
+            entity_logical_name = %entity_logical_name,
+            view_logical_name = %view_logical_name,
+            "failed to suppress report subscriptions of deleted view"
+        );
+    }
+
     Ok(StatusCode::NO_CONTENT)
 }
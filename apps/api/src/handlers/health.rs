@@ -1,12 +1,26 @@
 use axum::Json;
 use axum::extract::State;
 use axum::http::StatusCode;
+use chrono::Utc;
 
-use crate::dto::{HealthDependencyStatus, HealthResponse};
+use qryvanta_core::AppError;
+
+use crate::api_config::SessionStoreBackend;
+use crate::api_services::read_migration_level;
+use crate::api_versioning::ApiVersion;
+use crate::dto::{
+    ApiVersionDescriptorResponse, ApiVersionsResponse, HealthDependencyStatus, HealthResponse,
+    LivenessResponse, MigrationLevelResponse, VersionResponse,
+};
+use crate::error::ApiResult;
+use crate::release_advisory::{BUILD_COMMIT, BUILD_VERSION};
 use crate::state::AppState;
 
 mod checks;
 mod handlers;
 
-pub use handlers::health_handler;
+pub use handlers::api_versions_handler;
+pub use handlers::liveness_handler;
 pub use handlers::metrics_handler;
+pub use handlers::readiness_handler;
+pub use handlers::version_handler;
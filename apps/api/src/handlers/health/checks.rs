@@ -1,22 +1,23 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
 use redis::AsyncCommands;
 
 use super::*;
+use crate::api_services::{POSTGRES_SESSION_SCHEMA, POSTGRES_SESSION_TABLE};
 
-pub(super) async fn check_postgres(pool: sqlx::PgPool) -> HealthDependencyStatus {
-    let check = sqlx::query_scalar::<_, i32>("SELECT 1")
-        .fetch_one(&pool)
-        .await;
+/// Upper bound for one dependency probe so a hung dependency cannot stall readiness.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
 
-    match check {
-        Ok(_) => HealthDependencyStatus {
-            status: "ok",
-            detail: None,
-        },
-        Err(error) => HealthDependencyStatus {
-            status: "error",
-            detail: Some(format!("postgres check failed: {error}")),
-        },
-    }
+pub(super) async fn check_postgres(pool: sqlx::PgPool) -> HealthDependencyStatus {
+    probe(async move {
+        sqlx::query_scalar::<_, i32>("SELECT 1")
+            .fetch_one(&pool)
+            .await
+            .map(|_| ())
+            .map_err(|error| format!("postgres check failed: {error}"))
+    })
+    .await
 }
 
 pub(super) async fn check_redis(
@@ -28,38 +29,109 @@ pub(super) async fn check_redis(
             HealthDependencyStatus {
                 status: "error",
                 detail: Some("redis client is not configured".to_owned()),
+                latency_ms: None,
             }
         } else {
-            HealthDependencyStatus {
-                status: "disabled",
-                detail: None,
-            }
+            disabled()
         };
     };
 
-    let mut connection = match redis_client.get_multiplexed_async_connection().await {
-        Ok(connection) => connection,
-        Err(error) => {
-            return HealthDependencyStatus {
-                status: "error",
-                detail: Some(format!("redis connection failed: {error}")),
-            };
-        }
+    probe(ping_redis(redis_client)).await
+}
+
+pub(super) async fn check_smtp(address: Option<(String, u16)>) -> HealthDependencyStatus {
+    let Some((host, port)) = address else {
+        return disabled();
     };
 
-    let ping_response = connection.ping::<String>().await;
-    match ping_response {
-        Ok(value) if value.eq_ignore_ascii_case("pong") => HealthDependencyStatus {
+    probe(async move {
+        tokio::net::TcpStream::connect((host.as_str(), port))
+            .await
+            .map(|_| ())
+            .map_err(|error| format!("cannot connect to {host}:{port}: {error}"))
+    })
+    .await
+}
+
+pub(super) async fn check_session_store(
+    backend: SessionStoreBackend,
+    pool: sqlx::PgPool,
+    redis_client: Option<redis::Client>,
+) -> HealthDependencyStatus {
+    match backend {
+        SessionStoreBackend::Postgres => probe(async move {
+            let query = format!(
+                "SELECT 1 FROM \"{POSTGRES_SESSION_SCHEMA}\".\"{POSTGRES_SESSION_TABLE}\" LIMIT 1"
+            );
+            sqlx::query(query.as_str())
+                .fetch_optional(&pool)
+                .await
+                .map(|_| ())
+                .map_err(|error| format!("session store check failed: {error}"))
+        })
+        .await,
+        SessionStoreBackend::Redis => check_redis(redis_client, true).await,
+    }
+}
+
+pub(super) async fn check_migration_level(
+    pool: &sqlx::PgPool,
+) -> Result<MigrationLevelResponse, AppError> {
+    let level = read_migration_level(pool).await?;
+
+    Ok(MigrationLevelResponse {
+        applied_version: level.applied_version,
+        applied_description: level.applied_description,
+        expected_version: level.expected_version,
+        pending_count: level.pending_count,
+        up_to_date: level.pending_count == 0,
+    })
+}
+
+async fn ping_redis(redis_client: redis::Client) -> Result<(), String> {
+    let mut connection = redis_client
+        .get_multiplexed_async_connection()
+        .await
+        .map_err(|error| format!("redis connection failed: {error}"))?;
+
+    match connection.ping::<String>().await {
+        Ok(value) if value.eq_ignore_ascii_case("pong") => Ok(()),
+        Ok(value) => Err(format!("unexpected redis ping response: {value}")),
+        Err(error) => Err(format!("redis ping failed: {error}")),
+    }
+}
+
+async fn probe(check: impl Future<Output = Result<(), String>>) -> HealthDependencyStatus {
+    let started = Instant::now();
+    let outcome = tokio::time::timeout(PROBE_TIMEOUT, check).await;
+    let latency_ms = Some(u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX));
+
+    match outcome {
+        Ok(Ok(())) => HealthDependencyStatus {
             status: "ok",
             detail: None,
+            latency_ms,
         },
-        Ok(value) => HealthDependencyStatus {
+        Ok(Err(detail)) => HealthDependencyStatus {
             status: "error",
-            detail: Some(format!("unexpected redis ping response: {value}")),
+            detail: Some(detail),
+            latency_ms,
         },
-        Err(error) => HealthDependencyStatus {
+        Err(_) => HealthDependencyStatus {
             status: "error",
-            detail: Some(format!("redis ping failed: {error}")),
+            detail: Some(format!(
+                "probe did not complete within {} seconds",
+                PROBE_TIMEOUT.as_secs()
+            )),
+            latency_ms,
         },
     }
 }
+
+fn disabled() -> HealthDependencyStatus {
+    HealthDependencyStatus {
+        status: "disabled",
+        detail: None,
+        latency_ms: None,
+    }
+}
@@ -1,13 +1,50 @@
-use super::checks::{check_postgres, check_redis};
+use super::checks::{
+    check_migration_level, check_postgres, check_redis, check_session_store, check_smtp,
+};
 use super::*;
 use crate::observability::render_metrics_prometheus;
 
-pub async fn health_handler(State(state): State<AppState>) -> (StatusCode, Json<HealthResponse>) {
-    let postgres = check_postgres(state.postgres_pool.clone()).await;
-    let redis = check_redis(state.redis_client.clone(), state.redis_required).await;
+/// Reports that the process is up and serving requests.
+///
+/// Dependencies are not probed, so orchestrators do not restart the API
+/// because Postgres or Redis is briefly unavailable.
+pub async fn liveness_handler(State(state): State<AppState>) -> Json<LivenessResponse> {
+    Json(LivenessResponse {
+        status: "ok",
+        version: BUILD_VERSION.to_owned(),
+        uptime_seconds: state.started_at.elapsed().as_secs(),
+    })
+}
+
+/// Probes every runtime dependency and reports whether the API can take traffic.
+///
+/// The API is ready when Postgres, the session store and any required Redis
+/// backend respond. SMTP and optional Redis failures only degrade the status.
+pub async fn readiness_handler(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<HealthResponse>) {
+    let (postgres, redis, smtp, session_store) = tokio::join!(
+        check_postgres(state.postgres_pool.clone()),
+        check_redis(state.redis_client.clone(), state.redis_required),
+        check_smtp(state.smtp_probe_address.clone()),
+        check_session_store(
+            state.session_store_backend,
+            state.postgres_pool.clone(),
+            state.redis_client.clone(),
+        ),
+    );
 
-    let ready = is_healthy(postgres.status) && (is_healthy(redis.status) || !state.redis_required);
-    let status = if ready { "ok" } else { "degraded" };
+    let ready = is_healthy(postgres.status)
+        && is_healthy(session_store.status)
+        && (is_healthy(redis.status) || !state.redis_required);
+    let all_healthy = [&postgres, &redis, &smtp, &session_store]
+        .into_iter()
+        .all(|dependency| dependency.status != "error");
+    let status = if ready && all_healthy {
+        "ok"
+    } else {
+        "degraded"
+    };
     let http_status = if ready {
         StatusCode::OK
     } else {
@@ -19,8 +56,11 @@ pub async fn health_handler(State(state): State<AppState>) -> (StatusCode, Json<
         Json(HealthResponse {
             status,
             ready,
+            checked_at: Utc::now().to_rfc3339(),
             postgres,
             redis,
+            smtp,
+            session_store,
         }),
     )
 }
@@ -46,3 +86,43 @@ pub async fn metrics_handler(
         metrics,
     )
 }
+
+/// Reports the running build, its migration level and the release advisory.
+pub async fn version_handler(State(state): State<AppState>) -> ApiResult<Json<VersionResponse>> {
+    let migrations = check_migration_level(&state.postgres_pool).await?;
+    let advisory = state.release_advisory.advisory(&state.http_client).await;
+
+    Ok(Json(VersionResponse {
+        version: BUILD_VERSION.to_owned(),
+        commit: BUILD_COMMIT.map(ToOwned::to_owned),
+        migrations,
+        advisory: advisory.into(),
+    }))
+}
+
+/// Lists the served API versions with their deprecation schedules.
+pub async fn api_versions_handler(State(state): State<AppState>) -> Json<ApiVersionsResponse> {
+    let now = Utc::now();
+    let lifecycle = state.api_version_lifecycle;
+
+    Json(ApiVersionsResponse {
+        current_version: ApiVersion::CURRENT.as_str().to_owned(),
+        default_version: ApiVersion::UNPREFIXED.as_str().to_owned(),
+        versions: ApiVersion::ALL
+            .into_iter()
+            .map(|version| {
+                let schedule = lifecycle.schedule(version);
+                ApiVersionDescriptorResponse {
+                    version: version.as_str().to_owned(),
+                    status: lifecycle.status(version, now).to_owned(),
+                    path_prefix: version.path_prefix().to_owned(),
+                    deprecated_at: schedule.deprecated_at.map(|value| value.to_rfc3339()),
+                    sunset_at: schedule.sunset_at.map(|value| value.to_rfc3339()),
+                    successor_version: version
+                        .successor()
+                        .map(|successor| successor.as_str().to_owned()),
+                }
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,54 @@
+use axum::Json;
+use axum::extract::{Extension, Path, Query, State};
+
+use qryvanta_core::UserIdentity;
+
+use crate::dto::BackgroundJobResponse;
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct BackgroundJobListQuery {
+    pub limit: Option<usize>,
+}
+
+pub async fn list_background_jobs_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<BackgroundJobListQuery>,
+) -> ApiResult<Json<Vec<BackgroundJobResponse>>> {
+    let jobs = state
+        .background_job_service
+        .list_jobs(&user, query.limit.unwrap_or(50))
+        .await?;
+
+    Ok(Json(
+        jobs.into_iter().map(BackgroundJobResponse::from).collect(),
+    ))
+}
+
+pub async fn get_background_job_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(job_id): Path<String>,
+) -> ApiResult<Json<BackgroundJobResponse>> {
+    let job = state
+        .background_job_service
+        .get_job(&user, job_id.as_str())
+        .await?;
+
+    Ok(Json(BackgroundJobResponse::from(job)))
+}
+
+pub async fn cancel_background_job_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(job_id): Path<String>,
+) -> ApiResult<Json<BackgroundJobResponse>> {
+    let job = state
+        .background_job_service
+        .cancel_job(&user, job_id.as_str())
+        .await?;
+
+    Ok(Json(BackgroundJobResponse::from(job)))
+}
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use axum::Json;
+use axum::extract::{Extension, Path, State};
+use axum::http::{HeaderMap, StatusCode, header};
+
+use qryvanta_core::UserIdentity;
+use qryvanta_domain::{LocaleTag, LocalizedComponentType, LocalizedDisplayLabel};
+
+use crate::dto::{LocalizedLabelResponse, SaveLocalizedLabelRequest};
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+/// Upper bound on `Accept-Language` entries considered for one request.
+const MAX_PREFERRED_LOCALES: usize = 10;
+
+pub async fn list_localized_labels_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((component_type, component_key)): Path<(String, String)>,
+) -> ApiResult<Json<Vec<LocalizedLabelResponse>>> {
+    let labels = state
+        .localization_service
+        .list_component_labels(
+            &user,
+            component_type.parse::<LocalizedComponentType>()?,
+            component_key.as_str(),
+        )
+        .await?
+        .into_iter()
+        .map(LocalizedLabelResponse::from)
+        .collect();
+
+    Ok(Json(labels))
+}
+
+pub async fn save_localized_label_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((component_type, component_key, locale)): Path<(String, String, String)>,
+    Json(payload): Json<SaveLocalizedLabelRequest>,
+) -> ApiResult<Json<LocalizedLabelResponse>> {
+    let label = LocalizedDisplayLabel::new(
+        component_type.parse::<LocalizedComponentType>()?,
+        component_key,
+        LocaleTag::new(locale)?,
+        payload.display_name,
+        payload.description,
+    )?;
+    let label = state.localization_service.save_label(&user, label).await?;
+
+    Ok(Json(LocalizedLabelResponse::from(label)))
+}
+
+pub async fn delete_localized_label_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((component_type, component_key, locale)): Path<(String, String, String)>,
+) -> ApiResult<StatusCode> {
+    state
+        .localization_service
+        .delete_label(
+            &user,
+            component_type.parse::<LocalizedComponentType>()?,
+            component_key.as_str(),
+            &LocaleTag::new(locale)?,
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Resolves localized labels of one component type for the caller's
+/// `Accept-Language` preferences, keyed by component key.
+pub(crate) async fn resolve_localized_labels(
+    state: &AppState,
+    user: &UserIdentity,
+    headers: &HeaderMap,
+    component_type: LocalizedComponentType,
+) -> ApiResult<HashMap<String, LocalizedLabelResponse>> {
+    let labels = state
+        .localization_service
+        .resolve_labels(user, component_type, &preferred_locales(headers))
+        .await?;
+
+    Ok(labels
+        .into_iter()
+        .map(|(component_key, label)| (component_key, LocalizedLabelResponse::from(label)))
+        .collect())
+}
+
+/// Parses `Accept-Language` into locale preferences ordered by quality.
+///
+/// Wildcards, malformed tags and entries with `q=0` are ignored.
+fn preferred_locales(headers: &HeaderMap) -> Vec<LocaleTag> {
+    let Some(value) = headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return Vec::new();
+    };
+
+    let mut weighted = value
+        .split(',')
+        .take(MAX_PREFERRED_LOCALES)
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let locale = LocaleTag::new(parts.next()?.trim()).ok()?;
+            let quality = parts
+                .find_map(|parameter| parameter.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |quality| quality.trim().parse::<f32>().ok())?;
+            (quality > 0.0).then_some((locale, quality))
+        })
+        .collect::<Vec<_>>();
+    weighted.sort_by(|left, right| right.1.total_cmp(&left.1));
+
+    let mut locales: Vec<LocaleTag> = Vec::with_capacity(weighted.len());
+    for (locale, _) in weighted {
+        if !locales.contains(&locale) {
+            locales.push(locale);
+        }
+    }
+    locales
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, HeaderValue, header};
+
+    use super::preferred_locales;
+
+    #[test]
+    fn accept_language_preferences_are_ordered_by_quality() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT_LANGUAGE,
+            HeaderValue::from_static("fr;q=0.5, de-CH, *;q=0.1, en;q=0, DE-ch;q=0.9, xx-!!"),
+        );
+
+        let locales = preferred_locales(&headers)
+            .into_iter()
+            .map(|locale| locale.as_str().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(locales, vec!["de-CH".to_owned(), "fr".to_owned()]);
+
+        assert!(preferred_locales(&HeaderMap::new()).is_empty());
+    }
+}
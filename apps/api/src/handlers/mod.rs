@@ -1,11 +1,22 @@
+pub mod announcements;
 pub mod apps;
+pub mod contacts;
+pub mod data_validation;
 pub mod entities;
 pub mod extensions;
 pub mod health;
+pub mod jobs;
+pub mod localization;
+pub mod openapi;
+pub mod operator;
 pub mod portability;
 pub mod publish;
+pub mod record_imports;
+pub mod report_subscriptions;
+pub mod reporting_projections;
 pub mod runtime;
 pub mod search;
 pub mod security;
+pub mod tenant_data_exports;
 pub mod worker;
 pub mod workflows;
@@ -0,0 +1,108 @@
+//! OpenAPI 3.1 description of the public integration surface.
+//!
+//! Runtime record and entity metadata endpoints carry `utoipa` annotations;
+//! their schemas come from the same DTOs the TypeScript bindings are
+//! exported from.
+
+use axum::Json;
+use axum::http::{HeaderValue, header};
+use axum::response::{Html, IntoResponse, Response};
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use super::entities::MetadataApiDoc;
+use super::runtime::RuntimeApiDoc;
+
+/// Pinned Swagger UI release loaded by the optional docs page.
+const SWAGGER_UI_DIST_URL: &str = "https://unpkg.com/swagger-ui-dist@5.18.2";
+
+/// Session cookie set by the login endpoints.
+const SESSION_COOKIE_NAME: &str = "id";
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Qryvanta API",
+        description = "Runtime record and metadata endpoints. Every path is also served under `/api/v1` and `/api/v2`."
+    ),
+    modifiers(&SessionCookieSecurity),
+    security(("session_cookie" = [])),
+    tags(
+        (name = "runtime", description = "Runtime records of published entities"),
+        (name = "metadata", description = "Draft entity and field definitions")
+    )
+)]
+struct ApiDoc;
+
+struct SessionCookieSecurity;
+
+impl Modify for SessionCookieSecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "session_cookie",
+            SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new(SESSION_COOKIE_NAME))),
+        );
+    }
+}
+
+/// Builds the OpenAPI document served at `/api/openapi.json`.
+pub fn openapi_document() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(RuntimeApiDoc::openapi());
+    openapi.merge(MetadataApiDoc::openapi());
+    openapi
+}
+
+pub async fn openapi_json_handler() -> Json<utoipa::openapi::OpenApi> {
+    Json(openapi_document())
+}
+
+pub async fn swagger_ui_handler() -> Response {
+    let page = format!(
+        r#"<!doctype html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8" />
+    <title>Qryvanta API</title>
+    <link rel="stylesheet" href="{SWAGGER_UI_DIST_URL}/swagger-ui.css" />
+  </head>
+  <body>
+    <div id="swagger-ui"></div>
+    <script src="{SWAGGER_UI_DIST_URL}/swagger-ui-bundle.js"></script>
+    <script src="/api/docs/initializer.js"></script>
+  </body>
+</html>
+"#
+    );
+
+    with_swagger_ui_policy(Html(page).into_response())
+}
+
+pub async fn swagger_ui_initializer_handler() -> Response {
+    let script =
+        "window.ui = SwaggerUIBundle({ url: '/api/openapi.json', dom_id: '#swagger-ui' });\n";
+
+    with_swagger_ui_policy(
+        (
+            [(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("text/javascript; charset=utf-8"),
+            )],
+            script,
+        )
+            .into_response(),
+    )
+}
+
+/// Allows the pinned Swagger UI assets; the security header middleware keeps
+/// a policy a handler already set.
+fn with_swagger_ui_policy(mut response: Response) -> Response {
+    response.headers_mut().insert(
+        header::CONTENT_SECURITY_POLICY,
+        HeaderValue::from_static(
+            "default-src 'none'; script-src 'self' https://unpkg.com; style-src https://unpkg.com; img-src 'self' data:; connect-src 'self'; frame-ancestors 'none'; base-uri 'none'",
+        ),
+    );
+    response
+}
@@ -0,0 +1,128 @@
+use axum::Json;
+use axum::extract::{Extension, Path, Query, State};
+
+use qryvanta_application::OperatorMaintenanceAction;
+use qryvanta_core::{AppError, TenantId};
+
+use crate::dto::{
+    OperatorAuditEventResponse, OperatorMaintenanceResponse, OperatorQueueStatsResponse,
+    OperatorTenantResponse, OperatorUserLookupResponse, RunOperatorMaintenanceRequest,
+};
+use crate::error::ApiResult;
+use crate::middleware::OperatorIdentity;
+use crate::state::AppState;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct OperatorTenantListQuery {
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct OperatorUserSearchQuery {
+    pub email: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct OperatorQueueStatsQuery {
+    pub active_window_seconds: Option<u32>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct OperatorAuditLogQuery {
+    pub limit: Option<usize>,
+}
+
+/// GET /api/operator/tenants - List tenants with health and usage counters.
+pub async fn list_operator_tenants_handler(
+    State(state): State<AppState>,
+    Extension(operator): Extension<OperatorIdentity>,
+    Query(query): Query<OperatorTenantListQuery>,
+) -> ApiResult<Json<Vec<OperatorTenantResponse>>> {
+    let tenants = state
+        .operator_console_service
+        .list_tenants(
+            operator.operator_id(),
+            query.limit.unwrap_or(50),
+            query.offset.unwrap_or(0),
+        )
+        .await?;
+
+    Ok(Json(
+        tenants
+            .into_iter()
+            .map(OperatorTenantResponse::from)
+            .collect(),
+    ))
+}
+
+/// GET /api/operator/users?email= - Find a user and its memberships across tenants.
+pub async fn search_operator_user_handler(
+    State(state): State<AppState>,
+    Extension(operator): Extension<OperatorIdentity>,
+    Query(query): Query<OperatorUserSearchQuery>,
+) -> ApiResult<Json<OperatorUserLookupResponse>> {
+    let user = state
+        .operator_console_service
+        .search_user(operator.operator_id(), query.email.as_str())
+        .await?
+        .ok_or_else(|| AppError::NotFound("no user is registered with this email".to_owned()))?;
+
+    Ok(Json(OperatorUserLookupResponse::from(user)))
+}
+
+/// GET /api/operator/queue/stats - Aggregate workflow queue stats across tenants.
+pub async fn operator_queue_stats_handler(
+    State(state): State<AppState>,
+    Extension(operator): Extension<OperatorIdentity>,
+    Query(query): Query<OperatorQueueStatsQuery>,
+) -> ApiResult<Json<OperatorQueueStatsResponse>> {
+    let stats = state
+        .operator_console_service
+        .queue_stats(
+            operator.operator_id(),
+            query.active_window_seconds.unwrap_or(120),
+        )
+        .await?;
+
+    Ok(Json(OperatorQueueStatsResponse::from(stats)))
+}
+
+/// POST /api/operator/tenants/{tenant_id}/maintenance - Run a tenant maintenance action.
+pub async fn run_operator_maintenance_handler(
+    State(state): State<AppState>,
+    Extension(operator): Extension<OperatorIdentity>,
+    Path(tenant_id): Path<String>,
+    Json(payload): Json<RunOperatorMaintenanceRequest>,
+) -> ApiResult<Json<OperatorMaintenanceResponse>> {
+    let tenant_id = uuid::Uuid::parse_str(tenant_id.as_str())
+        .map(TenantId::from_uuid)
+        .map_err(|_| AppError::Validation(format!("invalid tenant id '{tenant_id}'")))?;
+    let action = OperatorMaintenanceAction::parse(payload.action.as_str())?;
+
+    let result = state
+        .operator_console_service
+        .run_maintenance(operator.operator_id(), tenant_id, action)
+        .await?;
+
+    Ok(Json(OperatorMaintenanceResponse::from(result)))
+}
+
+/// GET /api/operator/audit-log - List operator log entries, newest first.
+pub async fn list_operator_audit_log_handler(
+    State(state): State<AppState>,
+    Extension(operator): Extension<OperatorIdentity>,
+    Query(query): Query<OperatorAuditLogQuery>,
+) -> ApiResult<Json<Vec<OperatorAuditEventResponse>>> {
+    let events = state
+        .operator_console_service
+        .list_audit_events(operator.operator_id(), query.limit.unwrap_or(100))
+        .await?;
+
+    Ok(Json(
+        events
+            .into_iter()
+            .map(OperatorAuditEventResponse::from)
+            .collect(),
+    ))
+}
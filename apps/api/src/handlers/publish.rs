@@ -3,11 +3,17 @@ use serde::Deserialize;
 
 use crate::state::AppState;
 
+mod coordination;
 mod diff;
+mod fingerprint;
 mod handlers;
 mod history;
 mod issues;
 
+pub use coordination::{
+    cancel_publish_intent_handler, list_publish_intents_handler, list_publish_locks_handler,
+    queue_publish_intent_handler,
+};
 pub use handlers::{
     run_workspace_publish_handler, workspace_publish_checks_handler,
     workspace_publish_diff_handler, workspace_publish_history_handler,
@@ -22,6 +28,7 @@ pub struct PublishState {
     pub metadata_service: qryvanta_application::MetadataService,
     pub workflow_service: qryvanta_application::WorkflowService,
     pub security_admin_service: qryvanta_application::SecurityAdminService,
+    pub publish_coordination_service: qryvanta_application::PublishCoordinationService,
 }
 
 impl FromRef<AppState> for PublishState {
@@ -31,6 +38,7 @@ impl FromRef<AppState> for PublishState {
             metadata_service: input.metadata_service.clone(),
             workflow_service: input.workflow_service.clone(),
             security_admin_service: input.security_admin_service.clone(),
+            publish_coordination_service: input.publish_coordination_service.clone(),
         }
     }
 }
@@ -40,5 +48,10 @@ pub struct PublishHistoryQuery {
     pub limit: Option<usize>,
 }
 
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct PublishIntentListQuery {
+    pub include_resolved: Option<bool>,
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,211 @@
+use axum::Json;
+use axum::extract::{Extension, Path, Query, State};
+use tracing::warn;
+
+use qryvanta_application::{
+    CreatePublishIntentInput, PUBLISH_DRAFT_CHANGED_PREFIX, PUBLISH_LOCKED_PREFIX, PublishIntent,
+    PublishIntentStatus, PublishTargetScope, ReviewedDraft,
+};
+use qryvanta_core::{AppError, UserIdentity};
+
+use crate::dto::{
+    PublishIntentResponse, PublishLockResponse, QueuePublishIntentRequest,
+    ReviewedDraftFingerprintDto, RunWorkspacePublishRequest,
+};
+use crate::error::{ApiError, ApiResult};
+
+use super::handlers::{available_component_names, execute_workspace_publish, publish_targets};
+use super::issues::resolve_requested_names;
+use super::{PublishIntentListQuery, PublishState};
+
+pub async fn list_publish_locks_handler(
+    State(state): State<PublishState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<PublishLockResponse>>> {
+    let locks = state
+        .publish_coordination_service
+        .list_publish_locks(&user)
+        .await?;
+
+    Ok(Json(
+        locks.into_iter().map(PublishLockResponse::from).collect(),
+    ))
+}
+
+pub async fn list_publish_intents_handler(
+    State(state): State<PublishState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<PublishIntentListQuery>,
+) -> ApiResult<Json<Vec<PublishIntentResponse>>> {
+    let intents = state
+        .publish_coordination_service
+        .list_publish_intents(&user, query.include_resolved.unwrap_or(false))
+        .await?;
+
+    Ok(Json(
+        intents
+            .into_iter()
+            .map(PublishIntentResponse::from)
+            .collect(),
+    ))
+}
+
+pub async fn queue_publish_intent_handler(
+    State(state): State<PublishState>,
+    Extension(user): Extension<UserIdentity>,
+    Json(payload): Json<QueuePublishIntentRequest>,
+) -> ApiResult<Json<PublishIntentResponse>> {
+    // Empty selections mean "everything", as for a direct publish run, so the
+    // intent stores the names that selection resolves to right now.
+    let (available_entity_names, available_app_names, available_workflow_names) =
+        available_component_names(&state, &user).await?;
+    let entity_logical_names =
+        resolve_requested_names(payload.entity_logical_names, available_entity_names);
+    let app_logical_names = resolve_requested_names(payload.app_logical_names, available_app_names);
+    let workflow_logical_names =
+        resolve_requested_names(payload.workflow_logical_names, available_workflow_names);
+
+    let intent = state
+        .publish_coordination_service
+        .queue_publish_intent(
+            &user,
+            CreatePublishIntentInput {
+                targets: publish_targets(
+                    &entity_logical_names,
+                    &app_logical_names,
+                    &workflow_logical_names,
+                ),
+                reviewed_drafts: payload
+                    .reviewed_drafts
+                    .into_iter()
+                    .map(ReviewedDraft::from)
+                    .collect(),
+            },
+        )
+        .await?;
+
+    // The blocking run may already be gone; run the queue now instead of waiting.
+    drain_publish_intents(&state, &user).await;
+
+    let intent = state
+        .publish_coordination_service
+        .list_publish_intents(&user, true)
+        .await?
+        .into_iter()
+        .find(|queued| queued.intent_id == intent.intent_id)
+        .unwrap_or(intent);
+
+    Ok(Json(PublishIntentResponse::from(intent)))
+}
+
+pub async fn cancel_publish_intent_handler(
+    State(state): State<PublishState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(intent_id): Path<String>,
+) -> ApiResult<Json<PublishIntentResponse>> {
+    let intent = state
+        .publish_coordination_service
+        .cancel_publish_intent(&user, intent_id.as_str())
+        .await?;
+
+    Ok(Json(PublishIntentResponse::from(intent)))
+}
+
+/// Runs queued publish intents in queue order until one is still blocked.
+///
+/// Failures are logged and never fail the request that triggered the drain.
+pub(super) async fn drain_publish_intents(state: &PublishState, user: &UserIdentity) {
+    let intents = match state
+        .publish_coordination_service
+        .list_publish_intents(user, false)
+        .await
+    {
+        Ok(intents) => intents,
+        Err(error) => {
+            warn!(
+                error = %error,
+                tenant_id = %user.tenant_id(),
+                "failed to list queued publish intents"
+            );
+            return;
+        }
+    };
+
+    for intent in intents {
+        let requester = UserIdentity::new(
+            intent.requested_by_subject.as_str(),
+            intent.requested_by_display_name.as_str(),
+            intent.requested_by_email.clone(),
+            user.tenant_id(),
+        );
+        let intent_id = intent.intent_id.clone();
+
+        let (status, status_message) =
+            match execute_workspace_publish(state, &requester, intent_run_request(intent)).await {
+                Ok(response) if response.is_publishable => (PublishIntentStatus::Completed, None),
+                Ok(response) => (
+                    PublishIntentStatus::Failed,
+                    Some(format!(
+                        "publish checks reported {} issue(s)",
+                        response.issues.len()
+                    )),
+                ),
+                Err(ApiError(AppError::Conflict(message)))
+                    if message.starts_with(PUBLISH_LOCKED_PREFIX) =>
+                {
+                    break;
+                }
+                Err(ApiError(AppError::Conflict(message)))
+                    if message.starts_with(PUBLISH_DRAFT_CHANGED_PREFIX) =>
+                {
+                    (PublishIntentStatus::Conflicted, Some(message))
+                }
+                Err(ApiError(error)) => (PublishIntentStatus::Failed, Some(error.to_string())),
+            };
+
+        if let Err(error) = state
+            .publish_coordination_service
+            .resolve_publish_intent(
+                &requester,
+                intent_id.as_str(),
+                status,
+                status_message.as_deref(),
+            )
+            .await
+        {
+            warn!(
+                error = %error,
+                tenant_id = %user.tenant_id(),
+                intent_id = %intent_id,
+                "failed to resolve queued publish intent"
+            );
+        }
+    }
+}
+
+fn intent_run_request(intent: PublishIntent) -> RunWorkspacePublishRequest {
+    let names_for = |scope| {
+        intent
+            .targets
+            .iter()
+            .filter(|target| target.scope == scope)
+            .map(|target| target.logical_name.clone())
+            .collect()
+    };
+
+    RunWorkspacePublishRequest {
+        entity_logical_names: names_for(PublishTargetScope::Entity),
+        app_logical_names: names_for(PublishTargetScope::App),
+        workflow_logical_names: names_for(PublishTargetScope::Workflow),
+        dry_run: false,
+        reviewed_drafts: intent
+            .reviewed_drafts
+            .into_iter()
+            .map(|reviewed| ReviewedDraftFingerprintDto {
+                scope: reviewed.target.scope.into(),
+                logical_name: reviewed.target.logical_name,
+                draft_fingerprint: reviewed.draft_fingerprint,
+            })
+            .collect(),
+    }
+}
@@ -211,6 +211,7 @@ pub(super) fn compute_view_surface_delta(
 pub(super) fn compute_workflow_diff(
     draft_workflow: &WorkflowDefinition,
     published_workflow: Option<&WorkflowDefinition>,
+    draft_fingerprint: String,
 ) -> WorkflowPublishDiffResponse {
     let published_workflow_exists = published_workflow.is_some();
     let published_trigger_type =
@@ -230,6 +231,7 @@ pub(super) fn compute_workflow_diff(
 
     WorkflowPublishDiffResponse {
         workflow_logical_name: draft_workflow.logical_name().as_str().to_owned(),
+        draft_fingerprint,
         lifecycle_state: draft_workflow.lifecycle_state().as_str().to_owned(),
         published_version: draft_workflow.published_version(),
         published_workflow_exists,
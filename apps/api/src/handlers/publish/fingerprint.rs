@@ -0,0 +1,73 @@
+use qryvanta_application::{PublishTarget, PublishTargetScope, draft_fingerprint};
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{
+    AppEntityBinding, EntityFieldDefinition, FormDefinition, ViewDefinition, WorkflowDefinition,
+};
+
+use super::PublishState;
+
+pub(super) fn entity_draft_fingerprint(
+    fields: &[EntityFieldDefinition],
+    forms: &[FormDefinition],
+    views: &[ViewDefinition],
+) -> AppResult<String> {
+    draft_fingerprint(&(fields, forms, views))
+}
+
+pub(super) fn app_draft_fingerprint(bindings: &[AppEntityBinding]) -> AppResult<String> {
+    draft_fingerprint(&bindings)
+}
+
+/// Covers the same draft content the workflow diff compares, not lifecycle state.
+pub(super) fn workflow_draft_fingerprint(workflow: &WorkflowDefinition) -> AppResult<String> {
+    draft_fingerprint(&(
+        workflow.display_name(),
+        workflow.description(),
+        workflow.trigger(),
+        workflow.steps(),
+        workflow.max_attempts(),
+    ))
+}
+
+/// Loads the current draft of one publish target and returns its fingerprint.
+pub(super) async fn current_draft_fingerprint(
+    state: &PublishState,
+    user: &UserIdentity,
+    target: &PublishTarget,
+) -> AppResult<String> {
+    let logical_name = target.logical_name.as_str();
+    match target.scope {
+        PublishTargetScope::Entity => {
+            let fields = state
+                .metadata_service
+                .list_fields(user, logical_name)
+                .await?;
+            let forms = state
+                .metadata_service
+                .list_forms(user, logical_name)
+                .await?;
+            let views = state
+                .metadata_service
+                .list_views(user, logical_name)
+                .await?;
+            entity_draft_fingerprint(&fields, &forms, &views)
+        }
+        PublishTargetScope::App => {
+            let bindings = state
+                .app_service
+                .list_app_entities(user, logical_name)
+                .await?;
+            app_draft_fingerprint(&bindings)
+        }
+        PublishTargetScope::Workflow => {
+            let workflow = state
+                .workflow_service
+                .find_workflow(user, logical_name)
+                .await?
+                .ok_or_else(|| {
+                    AppError::NotFound(format!("workflow '{logical_name}' does not exist"))
+                })?;
+            workflow_draft_fingerprint(&workflow)
+        }
+    }
+}
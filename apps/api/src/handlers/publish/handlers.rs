@@ -1,8 +1,12 @@
 use axum::Json;
 use axum::extract::{Extension, Query, State};
 
-use qryvanta_application::{AuditLogQuery, WorkspacePublishRunAuditInput};
-use qryvanta_core::UserIdentity;
+use qryvanta_application::{
+    AuditLogQuery, PUBLISH_DRAFT_CHANGED_PREFIX, PUBLISH_LOCKED_PREFIX, PublishLock,
+    PublishLockAcquisition, PublishTarget, PublishTargetScope, ReviewedDraft,
+    WorkspacePublishRunAuditInput,
+};
+use qryvanta_core::{AppError, UserIdentity};
 use qryvanta_domain::AuditAction;
 
 use crate::dto::{
@@ -13,10 +17,15 @@ use crate::dto::{
 };
 use crate::error::ApiResult;
 
+use super::coordination::drain_publish_intents;
 use super::diff::{
     compute_field_diff, compute_form_surface_delta, compute_view_surface_delta,
     compute_workflow_diff,
 };
+use super::fingerprint::{
+    app_draft_fingerprint, current_draft_fingerprint, entity_draft_fingerprint,
+    workflow_draft_fingerprint,
+};
 use super::history::map_workspace_publish_history_entries;
 use super::issues::{
     build_unknown_selection_issues, collect_workspace_issues, partition_known_names,
@@ -62,9 +71,24 @@ pub async fn run_workspace_publish_handler(
     Extension(user): Extension<UserIdentity>,
     Json(payload): Json<RunWorkspacePublishRequest>,
 ) -> ApiResult<Json<RunWorkspacePublishResponse>> {
-    let entities = state.metadata_service.list_entities(&user).await?;
-    let apps = state.app_service.list_apps(&user).await?;
-    let workflows = state.workflow_service.list_workflows(&user).await?;
+    let dry_run = payload.dry_run;
+    let response = execute_workspace_publish(&state, &user, payload).await?;
+    if !dry_run {
+        drain_publish_intents(&state, &user).await;
+    }
+
+    Ok(Json(response))
+}
+
+/// Runs one workspace publish, holding publish locks while components are published.
+pub(super) async fn execute_workspace_publish(
+    state: &PublishState,
+    user: &UserIdentity,
+    payload: RunWorkspacePublishRequest,
+) -> ApiResult<RunWorkspacePublishResponse> {
+    let entities = state.metadata_service.list_entities(user).await?;
+    let apps = state.app_service.list_apps(user).await?;
+    let workflows = state.workflow_service.list_workflows(user).await?;
 
     let available_entity_names = entities
         .iter()
@@ -111,8 +135,8 @@ pub async fn run_workspace_publish_handler(
 
     issues.extend(
         collect_workspace_issues(
-            &state,
-            &user,
+            state,
+            user,
             &known_entity_names,
             &known_app_names,
             &known_workflow_names,
@@ -120,6 +144,24 @@ pub async fn run_workspace_publish_handler(
         .await?,
     );
 
+    let targets = publish_targets(&known_entity_names, &known_app_names, &known_workflow_names);
+    let reviewed_drafts = payload
+        .reviewed_drafts
+        .into_iter()
+        .map(ReviewedDraft::from)
+        .collect::<Vec<_>>();
+    if let Some(reviewed) = reviewed_drafts
+        .iter()
+        .find(|reviewed| !targets.contains(&reviewed.target))
+    {
+        return Err(AppError::Validation(format!(
+            "reviewed {} '{}' is not part of the publish run",
+            reviewed.target.scope.as_str(),
+            reviewed.target.logical_name
+        ))
+        .into());
+    }
+
     let mut published_entities = Vec::new();
     let mut validated_apps = Vec::new();
     let mut published_workflows = Vec::new();
@@ -129,6 +171,7 @@ pub async fn run_workspace_publish_handler(
         validated_apps = known_app_names.clone();
 
         if !should_publish {
+            verify_reviewed_drafts(state, user, &reviewed_drafts).await?;
             let response = RunWorkspacePublishResponse {
                 is_publishable: true,
                 requested_entities: requested_entities.len(),
@@ -140,28 +183,37 @@ pub async fn run_workspace_publish_handler(
                 issues,
             };
 
-            return Ok(Json(response));
+            return Ok(response);
         }
 
-        for entity_logical_name in &known_entity_names {
-            state
-                .metadata_service
-                .publish_entity_with_allowed_unpublished_entities(
-                    &user,
-                    entity_logical_name,
-                    &known_entity_names,
-                )
-                .await?;
-            published_entities.push(entity_logical_name.clone());
-        }
+        let lease = match state
+            .publish_coordination_service
+            .acquire_publish_locks(user, targets.clone())
+            .await?
+        {
+            PublishLockAcquisition::Acquired(lease) => lease,
+            PublishLockAcquisition::Blocked(locks) => {
+                return Err(AppError::Conflict(format!(
+                    "{PUBLISH_LOCKED_PREFIX}: {}; queue a publish intent to run once they are released",
+                    describe_blocking_locks(&locks)
+                ))
+                .into());
+            }
+        };
 
-        for workflow_logical_name in &known_workflow_names {
-            state
-                .workflow_service
-                .publish_workflow(&user, workflow_logical_name.as_str())
-                .await?;
-            published_workflows.push(workflow_logical_name.clone());
-        }
+        let outcome = publish_locked_components(
+            state,
+            user,
+            &reviewed_drafts,
+            &known_entity_names,
+            &known_workflow_names,
+        )
+        .await;
+        state
+            .publish_coordination_service
+            .release_publish_locks(user, &lease)
+            .await?;
+        (published_entities, published_workflows) = outcome?;
     }
 
     let response = RunWorkspacePublishResponse {
@@ -179,7 +231,7 @@ pub async fn run_workspace_publish_handler(
         state
             .security_admin_service
             .record_workspace_publish_run(
-                &user,
+                user,
                 WorkspacePublishRunAuditInput {
                     requested_entities: response.requested_entities,
                     requested_apps: response.requested_apps,
@@ -197,7 +249,122 @@ pub async fn run_workspace_publish_handler(
             .await?;
     }
 
-    Ok(Json(response))
+    Ok(response)
+}
+
+/// Lists the logical names of every entity, app and workflow visible to the user.
+pub(super) async fn available_component_names(
+    state: &PublishState,
+    user: &UserIdentity,
+) -> ApiResult<(Vec<String>, Vec<String>, Vec<String>)> {
+    let entities = state.metadata_service.list_entities(user).await?;
+    let apps = state.app_service.list_apps(user).await?;
+    let workflows = state.workflow_service.list_workflows(user).await?;
+
+    Ok((
+        entities
+            .iter()
+            .map(|entity| entity.logical_name().as_str().to_owned())
+            .collect(),
+        apps.iter()
+            .map(|app| app.logical_name().as_str().to_owned())
+            .collect(),
+        workflows
+            .iter()
+            .map(|workflow| workflow.logical_name().as_str().to_owned())
+            .collect(),
+    ))
+}
+
+pub(super) fn publish_targets(
+    entity_logical_names: &[String],
+    app_logical_names: &[String],
+    workflow_logical_names: &[String],
+) -> Vec<PublishTarget> {
+    let targets_for = |scope: PublishTargetScope, names: &[String]| {
+        names
+            .iter()
+            .map(move |logical_name| PublishTarget::new(scope, logical_name.as_str()))
+            .collect::<Vec<_>>()
+    };
+
+    let mut targets = targets_for(PublishTargetScope::Entity, entity_logical_names);
+    targets.extend(targets_for(PublishTargetScope::App, app_logical_names));
+    targets.extend(targets_for(
+        PublishTargetScope::Workflow,
+        workflow_logical_names,
+    ));
+    targets
+}
+
+fn describe_blocking_locks(locks: &[PublishLock]) -> String {
+    locks
+        .iter()
+        .map(|lock| {
+            format!(
+                "{} '{}' is being published by '{}'",
+                lock.target.scope.as_str(),
+                lock.target.logical_name,
+                lock.holder_subject
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Fails when a draft no longer matches the fingerprint from the reviewed diff.
+async fn verify_reviewed_drafts(
+    state: &PublishState,
+    user: &UserIdentity,
+    reviewed_drafts: &[ReviewedDraft],
+) -> ApiResult<()> {
+    for reviewed in reviewed_drafts {
+        let current = current_draft_fingerprint(state, user, &reviewed.target).await?;
+        if current != reviewed.draft_fingerprint {
+            return Err(AppError::Conflict(format!(
+                "{PUBLISH_DRAFT_CHANGED_PREFIX}: {} '{}' was edited after the diff was reviewed; reload the diff before publishing",
+                reviewed.target.scope.as_str(),
+                reviewed.target.logical_name
+            ))
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+async fn publish_locked_components(
+    state: &PublishState,
+    user: &UserIdentity,
+    reviewed_drafts: &[ReviewedDraft],
+    entity_logical_names: &[String],
+    workflow_logical_names: &[String],
+) -> ApiResult<(Vec<String>, Vec<String>)> {
+    verify_reviewed_drafts(state, user, reviewed_drafts).await?;
+
+    let mut published_entities = Vec::new();
+    for entity_logical_name in entity_logical_names {
+        state
+            .metadata_service
+            .publish_entity_with_allowed_unpublished_entities(
+                user,
+                entity_logical_name,
+                entity_logical_names,
+            )
+            .await?;
+        published_entities.push(entity_logical_name.clone());
+    }
+
+    let mut published_workflows = Vec::new();
+    for workflow_logical_name in workflow_logical_names {
+        state
+            .workflow_service
+            .publish_workflow(user, workflow_logical_name.as_str())
+            .await?;
+        published_workflows.push(workflow_logical_name.clone());
+    }
+
+    Ok((published_entities, published_workflows))
 }
 
 pub async fn workspace_publish_history_handler(
@@ -288,6 +455,7 @@ pub async fn workspace_publish_diff_handler(
 
         entity_diffs.push(EntityPublishDiffResponse {
             entity_logical_name: entity_logical_name.clone(),
+            draft_fingerprint: entity_draft_fingerprint(&draft_fields, &draft_forms, &draft_views)?,
             published_schema_exists: published_schema.is_some(),
             field_diff: compute_field_diff(&draft_fields, published_schema.as_ref()),
             forms: compute_form_surface_delta(&draft_forms, &published_forms),
@@ -304,6 +472,7 @@ pub async fn workspace_publish_diff_handler(
 
         app_diffs.push(AppPublishDiffResponse {
             app_logical_name: app_logical_name.clone(),
+            draft_fingerprint: app_draft_fingerprint(&bindings)?,
             bindings: bindings
                 .iter()
                 .map(|binding| AppBindingDiffResponse {
@@ -366,6 +535,7 @@ pub async fn workspace_publish_diff_handler(
         workflow_diffs.push(compute_workflow_diff(
             &draft_workflow,
             published_workflow.as_ref(),
+            workflow_draft_fingerprint(&draft_workflow)?,
         ));
     }
 
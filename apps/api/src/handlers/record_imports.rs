@@ -0,0 +1,197 @@
+use axum::Json;
+use axum::body::Bytes;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::StatusCode;
+
+use qryvanta_application::{
+    CreateRecordImportScheduleInput, RecordImportColumnMapping, RecordImportFrequency,
+    RecordImportSourceKind, SaveRecordImportMappingTemplateInput,
+};
+use qryvanta_core::UserIdentity;
+
+use crate::dto::{
+    CreateRecordImportScheduleRequest, RecordImportScheduleResponse, RecordImportSummaryResponse,
+    RecordImportTemplateResponse, SaveRecordImportTemplateRequest,
+    UpdateRecordImportScheduleRequest,
+};
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RecordImportQuery {
+    pub template_id: Option<String>,
+}
+
+fn template_input(
+    entity_logical_name: String,
+    payload: SaveRecordImportTemplateRequest,
+) -> SaveRecordImportMappingTemplateInput {
+    SaveRecordImportMappingTemplateInput {
+        entity_logical_name,
+        name: payload.name,
+        column_mappings: payload
+            .column_mappings
+            .into_iter()
+            .map(RecordImportColumnMapping::from)
+            .collect(),
+    }
+}
+
+pub async fn list_record_import_templates_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<Json<Vec<RecordImportTemplateResponse>>> {
+    let templates = state
+        .record_import_service
+        .list_templates(&user, entity_logical_name.as_str())
+        .await?;
+
+    Ok(Json(
+        templates
+            .into_iter()
+            .map(RecordImportTemplateResponse::from)
+            .collect(),
+    ))
+}
+
+pub async fn create_record_import_template_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+    Json(payload): Json<SaveRecordImportTemplateRequest>,
+) -> ApiResult<(StatusCode, Json<RecordImportTemplateResponse>)> {
+    let template = state
+        .record_import_service
+        .create_template(&user, template_input(entity_logical_name, payload))
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(RecordImportTemplateResponse::from(template)),
+    ))
+}
+
+pub async fn update_record_import_template_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, template_id)): Path<(String, String)>,
+    Json(payload): Json<SaveRecordImportTemplateRequest>,
+) -> ApiResult<Json<RecordImportTemplateResponse>> {
+    let template = state
+        .record_import_service
+        .update_template(
+            &user,
+            template_id.as_str(),
+            template_input(entity_logical_name, payload),
+        )
+        .await?;
+
+    Ok(Json(RecordImportTemplateResponse::from(template)))
+}
+
+pub async fn delete_record_import_template_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, template_id)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    state
+        .record_import_service
+        .delete_template(&user, entity_logical_name.as_str(), template_id.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/runtime/{entity_logical_name}/records/import - Import a CSV upload.
+///
+/// The request body is the raw CSV file. Rows are created one by one, so
+/// rejected rows are reported without undoing the imported ones.
+pub async fn import_runtime_records_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+    Query(query): Query<RecordImportQuery>,
+    body: Bytes,
+) -> ApiResult<Json<RecordImportSummaryResponse>> {
+    let summary = state
+        .record_import_service
+        .import_file(
+            &user,
+            entity_logical_name.as_str(),
+            query.template_id.as_deref(),
+            &body,
+        )
+        .await?;
+
+    Ok(Json(RecordImportSummaryResponse::from(summary)))
+}
+
+pub async fn list_record_import_schedules_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<RecordImportScheduleResponse>>> {
+    let schedules = state.record_import_service.list_schedules(&user).await?;
+
+    Ok(Json(
+        schedules
+            .into_iter()
+            .map(RecordImportScheduleResponse::from)
+            .collect(),
+    ))
+}
+
+pub async fn create_record_import_schedule_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Json(payload): Json<CreateRecordImportScheduleRequest>,
+) -> ApiResult<(StatusCode, Json<RecordImportScheduleResponse>)> {
+    let schedule = state
+        .record_import_service
+        .create_schedule(
+            &user,
+            CreateRecordImportScheduleInput {
+                name: payload.name,
+                entity_logical_name: payload.entity_logical_name,
+                template_id: payload.template_id,
+                source_kind: RecordImportSourceKind::parse(payload.source_kind.as_str())?,
+                source_url: payload.source_url,
+                auth_header_name: payload.auth_header_name,
+                auth_secret_ref: payload.auth_secret_ref,
+                frequency: RecordImportFrequency::parse(payload.frequency.as_str())?,
+            },
+        )
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(RecordImportScheduleResponse::from(schedule)),
+    ))
+}
+
+pub async fn update_record_import_schedule_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(schedule_id): Path<String>,
+    Json(payload): Json<UpdateRecordImportScheduleRequest>,
+) -> ApiResult<Json<RecordImportScheduleResponse>> {
+    let schedule = state
+        .record_import_service
+        .set_schedule_enabled(&user, schedule_id.as_str(), payload.is_enabled)
+        .await?;
+
+    Ok(Json(RecordImportScheduleResponse::from(schedule)))
+}
+
+pub async fn delete_record_import_schedule_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(schedule_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .record_import_service
+        .delete_schedule(&user, schedule_id.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,81 @@
+use axum::Json;
+use axum::extract::{Extension, Path, State};
+use axum::http::StatusCode;
+
+use qryvanta_application::{
+    CreateReportSubscriptionInput, ReportSubscriptionFormat, ReportSubscriptionFrequency,
+};
+use qryvanta_core::UserIdentity;
+
+use crate::dto::{CreateReportSubscriptionRequest, ReportSubscriptionResponse};
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+pub async fn list_report_subscriptions_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<ReportSubscriptionResponse>>> {
+    let subscriptions = state
+        .report_subscription_service
+        .list_subscriptions(&user)
+        .await?;
+
+    Ok(Json(
+        subscriptions
+            .into_iter()
+            .map(ReportSubscriptionResponse::from)
+            .collect(),
+    ))
+}
+
+pub async fn create_report_subscription_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Json(payload): Json<CreateReportSubscriptionRequest>,
+) -> ApiResult<(StatusCode, Json<ReportSubscriptionResponse>)> {
+    let frequency = ReportSubscriptionFrequency::parse(payload.frequency.as_str())?;
+    let format = ReportSubscriptionFormat::parse(payload.format.as_str())?;
+
+    // Deliveries export the view with the subscriber's access, so refuse
+    // subscriptions the caller could not export today.
+    state
+        .metadata_service
+        .prepare_runtime_record_export(
+            &user,
+            payload.entity_logical_name.as_str(),
+            payload.view_logical_name.as_str(),
+        )
+        .await?;
+
+    let subscription = state
+        .report_subscription_service
+        .create_subscription(
+            &user,
+            CreateReportSubscriptionInput {
+                entity_logical_name: payload.entity_logical_name,
+                view_logical_name: payload.view_logical_name,
+                frequency,
+                format,
+                delivery_hour_utc: payload.delivery_hour_utc,
+            },
+        )
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ReportSubscriptionResponse::from(subscription)),
+    ))
+}
+
+pub async fn delete_report_subscription_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(subscription_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .report_subscription_service
+        .delete_subscription(&user, subscription_id.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,114 @@
+use axum::Json;
+use axum::extract::{Extension, Path, State};
+use axum::http::StatusCode;
+
+use qryvanta_application::{
+    ReportingProjectionColumnInput, ReportingProjectionFilter, ReportingProjectionQuery,
+    SaveReportingProjectionInput,
+};
+use qryvanta_core::UserIdentity;
+
+use crate::dto::{
+    QueryReportingProjectionRequest, ReportingProjectionResponse, ReportingProjectionRowResponse,
+    SaveReportingProjectionRequest,
+};
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+/// Rows returned when a query does not set a limit.
+const DEFAULT_QUERY_LIMIT: usize = 100;
+
+pub async fn list_reporting_projections_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<ReportingProjectionResponse>>> {
+    let projections = state
+        .reporting_projection_service
+        .list_projections(&user)
+        .await?;
+
+    Ok(Json(
+        projections
+            .into_iter()
+            .map(ReportingProjectionResponse::from)
+            .collect(),
+    ))
+}
+
+pub async fn save_reporting_projection_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(logical_name): Path<String>,
+    Json(payload): Json<SaveReportingProjectionRequest>,
+) -> ApiResult<Json<ReportingProjectionResponse>> {
+    let projection = state
+        .reporting_projection_service
+        .save_projection(
+            &user,
+            SaveReportingProjectionInput {
+                logical_name,
+                display_name: payload.display_name,
+                root_entity_logical_name: payload.root_entity_logical_name,
+                columns: payload
+                    .columns
+                    .into_iter()
+                    .map(|column| ReportingProjectionColumnInput {
+                        column_name: column.column_name,
+                        relation_field_logical_name: column.relation_field_logical_name,
+                        field_logical_name: column.field_logical_name,
+                    })
+                    .collect(),
+            },
+        )
+        .await?;
+
+    Ok(Json(ReportingProjectionResponse::from(projection)))
+}
+
+pub async fn delete_reporting_projection_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(logical_name): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .reporting_projection_service
+        .delete_projection(&user, logical_name.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn query_reporting_projection_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(logical_name): Path<String>,
+    Json(payload): Json<QueryReportingProjectionRequest>,
+) -> ApiResult<Json<Vec<ReportingProjectionRowResponse>>> {
+    let rows = state
+        .reporting_projection_service
+        .query_projection(
+            &user,
+            logical_name.as_str(),
+            ReportingProjectionQuery {
+                filters: payload
+                    .filters
+                    .into_iter()
+                    .map(|filter| ReportingProjectionFilter {
+                        column_name: filter.column_name,
+                        value: filter.value,
+                    })
+                    .collect(),
+                sort_column: payload.sort_column,
+                sort_descending: payload.sort_descending,
+                limit: payload.limit.unwrap_or(DEFAULT_QUERY_LIMIT),
+                offset: payload.offset.unwrap_or_default(),
+            },
+        )
+        .await?;
+
+    Ok(Json(
+        rows.into_iter()
+            .map(ReportingProjectionRowResponse::from)
+            .collect(),
+    ))
+}
@@ -1,25 +1,100 @@
 use axum::Json;
-use axum::extract::{Extension, Path, Query, State};
+use axum::extract::{Extension, OriginalUri, Path, Query, State};
 use axum::http::StatusCode;
 use qryvanta_core::{AppError, UserIdentity};
+use qryvanta_domain::RuntimeRecord;
 use tracing::warn;
+use utoipa::OpenApi;
 
 use crate::dto::{
-    BusinessRuleResponse, CreateRuntimeRecordRequest, QueryRuntimeRecordsRequest,
-    RuntimeRecordResponse, UpdateRuntimeRecordRequest,
+    ApproveRecordAccessRequest, AssignRuntimeRecordOwnerRequest, AssociateRuntimeRecordRequest,
+    BusinessRuleResponse, CreateRecordShareLinkRequest, CreateRuntimeRecordRequest,
+    CreatedRecordShareLinkResponse, ExecuteRuntimeRecordChangesetRequest,
+    PendingFieldChangeResponse, QueryRuntimeRecordsRequest, QuickCreateRuntimeRecordRequest,
+    RecordAccessRequestResponse, RecordShareLinkResponse, RecordShareLinkViewResponse,
+    RecordShareResponse, RelationLookupMatchResponse, RequestRecordAccessRequest,
+    RuntimeRecordChangesetResultResponse, RuntimeRecordHistoryEntryResponse,
+    RuntimeRecordOwnerResponse, RuntimeRecordResponse, RuntimeRecordSlugResponse,
+    RuntimeRecordStatusChangeResponse, RuntimeRecordUpsertResponse, UpdateRuntimeRecordRequest,
+    UpsertRuntimeRecordRequest,
 };
-use crate::error::ApiResult;
+use crate::error::{ApiResult, ErrorResponse};
+use crate::pagination::{PageWindow, PaginatedJson};
 use crate::state::AppState;
 
+mod aggregate;
+mod changesets;
+mod export;
+mod field_changes;
 mod handlers;
+mod lookups;
 mod query;
+mod record_access;
+mod record_history;
+mod relations;
+mod share_links;
+mod status_history;
 
+pub use aggregate::aggregate_runtime_records_handler;
+pub use changesets::execute_runtime_record_changeset_handler;
+pub use export::export_runtime_records_handler;
+pub(crate) use export::{csv_header, csv_row, export_row_cells};
+#[cfg(test)]
+pub use field_changes::PendingFieldChangeListQuery;
+pub use field_changes::{
+    approve_pending_field_change_handler, list_pending_field_changes_handler,
+    reject_pending_field_change_handler,
+};
 pub use handlers::{
-    create_runtime_record_handler, delete_runtime_record_handler, get_runtime_record_handler,
-    list_runtime_business_rules_handler, list_runtime_records_handler,
-    query_runtime_records_handler, update_runtime_record_handler,
+    assign_runtime_record_owner_handler, create_runtime_record_handler,
+    delete_runtime_record_handler, get_runtime_record_handler, list_runtime_business_rules_handler,
+    list_runtime_records_handler, query_runtime_records_handler,
+    quick_create_runtime_record_handler, resolve_runtime_record_slug_handler,
+    update_runtime_record_handler, upsert_runtime_record_by_key_handler,
+};
+pub use lookups::search_relation_lookup_handler;
+
+/// OpenAPI description of the runtime record endpoints.
+#[derive(OpenApi)]
+#[openapi(paths(
+    handlers::list_runtime_records_handler,
+    handlers::create_runtime_record_handler,
+    handlers::quick_create_runtime_record_handler,
+    handlers::query_runtime_records_handler,
+    aggregate::aggregate_runtime_records_handler,
+    handlers::get_runtime_record_handler,
+    handlers::update_runtime_record_handler,
+    handlers::delete_runtime_record_handler,
+    handlers::upsert_runtime_record_by_key_handler,
+    handlers::resolve_runtime_record_slug_handler,
+    changesets::execute_runtime_record_changeset_handler,
+))]
+pub struct RuntimeApiDoc;
+pub(crate) use query::{
+    runtime_record_aggregate_query_from_request, runtime_record_query_from_request,
+};
+#[cfg(test)]
+pub use record_access::RecordAccessRequestListQuery;
+pub use record_access::{
+    approve_record_access_request_handler, list_record_access_requests_handler,
+    list_record_shares_handler, reject_record_access_request_handler,
+    request_record_access_handler, revoke_record_share_handler,
+};
+pub use record_history::{
+    list_runtime_record_history_handler, restore_runtime_record_version_handler,
+};
+pub use relations::{
+    associate_runtime_record_handler, disassociate_runtime_record_handler,
+    list_runtime_record_relations_handler,
+};
+#[cfg(test)]
+pub use share_links::RecordShareLinkPasswordForm;
+pub use share_links::{
+    create_record_share_link_handler, list_record_share_link_views_handler,
+    list_record_share_links_handler, revoke_record_share_link_handler,
+    submit_public_record_share_link_password_handler, view_public_record_share_link_handler,
 };
-pub(crate) use query::runtime_record_query_from_request;
+pub use status_history::list_runtime_record_status_history_handler;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,43 @@
+use crate::dto::{AggregateRuntimeRecordsRequest, RuntimeRecordAggregateRowResponse};
+
+use super::*;
+
+#[utoipa::path(
+    post,
+    path = "/api/runtime/{entity_logical_name}/records/aggregate",
+    tag = "runtime",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+    ),
+    request_body = AggregateRuntimeRecordsRequest,
+    responses(
+        (status = 200, description = "Aggregate groups", body = [RuntimeRecordAggregateRowResponse]),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
+pub async fn aggregate_runtime_records_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+    Json(payload): Json<AggregateRuntimeRecordsRequest>,
+) -> ApiResult<Json<Vec<RuntimeRecordAggregateRowResponse>>> {
+    let _query_permit = state.try_acquire_runtime_query_permit()?;
+    let query = runtime_record_aggregate_query_from_request(
+        &state.metadata_service,
+        &user,
+        entity_logical_name.as_str(),
+        payload,
+        state.runtime_query_max_limit,
+    )
+    .await?;
+
+    let rows = state
+        .metadata_service
+        .aggregate_runtime_records(&user, entity_logical_name.as_str(), query)
+        .await?
+        .into_iter()
+        .map(RuntimeRecordAggregateRowResponse::from)
+        .collect();
+
+    Ok(Json(rows))
+}
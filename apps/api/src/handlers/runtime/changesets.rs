@@ -0,0 +1,100 @@
+use qryvanta_application::{RuntimeRecordChangesetMethod, RuntimeRecordChangesetOperation};
+
+use super::*;
+
+#[utoipa::path(
+    post,
+    path = "/api/runtime/changesets",
+    tag = "runtime",
+    request_body = ExecuteRuntimeRecordChangesetRequest,
+    responses(
+        (status = 200, description = "Results of the operations, applied in one transaction", body = [RuntimeRecordChangesetResultResponse]),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
+pub async fn execute_runtime_record_changeset_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Json(payload): Json<ExecuteRuntimeRecordChangesetRequest>,
+) -> ApiResult<Json<Vec<RuntimeRecordChangesetResultResponse>>> {
+    let operations = payload
+        .operations
+        .into_iter()
+        .map(|operation| {
+            Ok(RuntimeRecordChangesetOperation {
+                content_id: operation.content_id,
+                method: RuntimeRecordChangesetMethod::parse_transport(operation.method.as_str())?,
+                entity_logical_name: operation.entity_logical_name,
+                record_id: operation.record_id,
+                data: operation.data,
+            })
+        })
+        .collect::<Result<Vec<_>, AppError>>()?;
+
+    let results = state
+        .metadata_service
+        .execute_runtime_record_changeset(&user, operations)
+        .await?;
+
+    if let Err(error) = state
+        .workflow_service
+        .drain_runtime_record_workflow_events_inline(
+            &user,
+            state.workflow_worker_max_claim_limit,
+            state.workflow_worker_default_lease_seconds,
+        )
+        .await
+    {
+        warn!(
+            error = %error,
+            tenant_id = %user.tenant_id(),
+            "runtime workflow event drain failed after runtime record changeset"
+        );
+    }
+
+    let mut responses = Vec::with_capacity(results.len());
+    for result in results {
+        let record = &result.record;
+        let entity_logical_name = record.entity_logical_name().as_str().to_owned();
+        if let Err(error) = state
+            .contact_identity_service
+            .sync_record_unchecked(
+                &user,
+                entity_logical_name.as_str(),
+                record.record_id().as_str(),
+                record.data(),
+            )
+            .await
+        {
+            warn!(
+                error = %error,
+                tenant_id = %user.tenant_id(),
+                entity_logical_name = %entity_logical_name,
+                record_id = %record.record_id().as_str(),
+                "contact identity sync failed after runtime record changeset"
+            );
+        }
+
+        let response = RuntimeRecordChangesetResultResponse::from(result);
+        if let Err(error) = crate::qrywell_sync::enqueue_runtime_record_upsert(
+            &state.postgres_pool,
+            user.tenant_id(),
+            entity_logical_name.as_str(),
+            &response.record,
+            state.qrywell_sync_max_attempts,
+        )
+        .await
+        {
+            warn!(
+                error = %error,
+                tenant_id = %user.tenant_id(),
+                entity_logical_name = %entity_logical_name,
+                record_id = %response.record.record_id,
+                "qrywell sync failed after runtime record changeset"
+            );
+        }
+        responses.push(response);
+    }
+
+    Ok(Json(responses))
+}
@@ -0,0 +1,216 @@
+use axum::body::{Body, Bytes};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use futures_util::stream;
+use qryvanta_application::{RuntimeRecordExport, RuntimeRecordExportColumn};
+use serde_json::Value;
+
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RuntimeRecordExportQuery {
+    pub view: String,
+    pub format: Option<String>,
+}
+
+/// Serialization format of a runtime record export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RuntimeRecordExportFormat {
+    Csv,
+    Json,
+}
+
+impl RuntimeRecordExportFormat {
+    fn parse_transport(value: &str) -> Result<Self, AppError> {
+        match value {
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            _ => Err(AppError::Validation(format!(
+                "unknown runtime record export format '{value}'"
+            ))),
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Json => "application/json",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+}
+
+pub async fn export_runtime_records_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+    Query(query): Query<RuntimeRecordExportQuery>,
+) -> ApiResult<Response> {
+    let format = query
+        .format
+        .as_deref()
+        .map_or(Ok(RuntimeRecordExportFormat::Csv), |value| {
+            RuntimeRecordExportFormat::parse_transport(value)
+        })?;
+    let export = state
+        .metadata_service
+        .prepare_runtime_record_export(&user, entity_logical_name.as_str(), query.view.as_str())
+        .await?;
+
+    let disposition = format!(
+        "attachment; filename=\"{}-{}.{}\"",
+        entity_logical_name,
+        query.view,
+        format.extension()
+    );
+    let chunks = stream::unfold(
+        ExportStream {
+            export,
+            format,
+            started: false,
+            wrote_record: false,
+            finished: false,
+        },
+        ExportStream::next_chunk,
+    );
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_owned()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        Body::from_stream(chunks),
+    )
+        .into_response())
+}
+
+/// Streams one encoded chunk per fetched page, framed by the format header and trailer.
+struct ExportStream {
+    export: RuntimeRecordExport,
+    format: RuntimeRecordExportFormat,
+    started: bool,
+    wrote_record: bool,
+    finished: bool,
+}
+
+impl ExportStream {
+    async fn next_chunk(mut self) -> Option<(Result<Bytes, AppError>, Self)> {
+        if self.finished {
+            return None;
+        }
+
+        if !self.started {
+            self.started = true;
+            let header = match self.format {
+                RuntimeRecordExportFormat::Csv => csv_header(self.export.columns()),
+                RuntimeRecordExportFormat::Json => "[".to_owned(),
+            };
+            return Some((Ok(Bytes::from(header)), self));
+        }
+
+        match self.export.next_page().await {
+            Ok(Some(records)) => {
+                let mut chunk = String::new();
+                for record in &records {
+                    match self.format {
+                        RuntimeRecordExportFormat::Csv => {
+                            chunk.push_str(csv_row(self.export.columns(), record).as_str());
+                        }
+                        RuntimeRecordExportFormat::Json => {
+                            if self.wrote_record {
+                                chunk.push(',');
+                            }
+                            chunk.push_str(json_row(self.export.columns(), record).as_str());
+                            self.wrote_record = true;
+                        }
+                    }
+                }
+                Some((Ok(Bytes::from(chunk)), self))
+            }
+            Ok(None) => {
+                self.finished = true;
+                match self.format {
+                    RuntimeRecordExportFormat::Csv => None,
+                    RuntimeRecordExportFormat::Json => Some((Ok(Bytes::from_static(b"]")), self)),
+                }
+            }
+            Err(error) => {
+                warn!(error = %error, "runtime record export aborted");
+                self.finished = true;
+                Some((Err(error), self))
+            }
+        }
+    }
+}
+
+pub(crate) fn csv_header(columns: &[RuntimeRecordExportColumn]) -> String {
+    let cells = std::iter::once("record_id".to_owned())
+        .chain(
+            columns
+                .iter()
+                .map(|column| csv_cell(column.field_logical_name.as_str())),
+        )
+        .collect::<Vec<_>>();
+    format!("{}\r\n", cells.join(","))
+}
+
+pub(crate) fn csv_row(columns: &[RuntimeRecordExportColumn], record: &RuntimeRecord) -> String {
+    let cells = export_row_cells(columns, record)
+        .iter()
+        .map(|text| csv_cell(text.as_str()))
+        .collect::<Vec<_>>();
+    format!("{}\r\n", cells.join(","))
+}
+
+/// Plain-text cells of one exported record, starting with its record id.
+pub(crate) fn export_row_cells(
+    columns: &[RuntimeRecordExportColumn],
+    record: &RuntimeRecord,
+) -> Vec<String> {
+    std::iter::once(record.record_id().as_str().to_owned())
+        .chain(columns.iter().map(|column| {
+            let value = record
+                .data()
+                .get(column.field_logical_name.as_str())
+                .unwrap_or(&Value::Null);
+            match value {
+                Value::Null => String::new(),
+                Value::String(text) => text.clone(),
+                Value::Bool(_) | Value::Number(_) | Value::Array(_) | Value::Object(_) => {
+                    value.to_string()
+                }
+            }
+        }))
+        .collect()
+}
+
+pub(crate) fn json_row(columns: &[RuntimeRecordExportColumn], record: &RuntimeRecord) -> String {
+    let mut object = serde_json::Map::new();
+    object.insert(
+        "record_id".to_owned(),
+        Value::String(record.record_id().as_str().to_owned()),
+    );
+    for column in columns {
+        let value = record
+            .data()
+            .get(column.field_logical_name.as_str())
+            .cloned()
+            .unwrap_or(Value::Null);
+        object.insert(column.field_logical_name.clone(), value);
+    }
+    Value::Object(object).to_string()
+}
+
+fn csv_cell(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
@@ -0,0 +1,119 @@
+use qryvanta_application::{PendingFieldChangeQuery, PendingFieldChangeStatus};
+
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct PendingFieldChangeListQuery {
+    pub entity_logical_name: Option<String>,
+    pub record_id: Option<String>,
+    pub status: Option<String>,
+}
+
+pub async fn list_pending_field_changes_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<PendingFieldChangeListQuery>,
+) -> ApiResult<Json<Vec<PendingFieldChangeResponse>>> {
+    let status = query
+        .status
+        .as_deref()
+        .map(PendingFieldChangeStatus::parse)
+        .transpose()?;
+    let changes = state
+        .metadata_service
+        .list_pending_field_changes(
+            &user,
+            PendingFieldChangeQuery {
+                entity_logical_name: query.entity_logical_name,
+                record_id: query.record_id,
+                status,
+            },
+        )
+        .await?
+        .into_iter()
+        .map(PendingFieldChangeResponse::from)
+        .collect();
+
+    Ok(Json(changes))
+}
+
+pub async fn approve_pending_field_change_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(change_id): Path<String>,
+) -> ApiResult<Json<PendingFieldChangeResponse>> {
+    let change = state
+        .metadata_service
+        .approve_pending_field_change(&user, change_id.as_str())
+        .await?;
+
+    if let Err(error) = state
+        .workflow_service
+        .drain_runtime_record_workflow_events_inline(
+            &user,
+            state.workflow_worker_max_claim_limit,
+            state.workflow_worker_default_lease_seconds,
+        )
+        .await
+    {
+        warn!(
+            error = %error,
+            tenant_id = %user.tenant_id(),
+            change_id = %change.change_id,
+            "runtime workflow event drain failed after field change approval"
+        );
+    }
+
+    match state
+        .metadata_service
+        .get_runtime_record(
+            &user,
+            change.entity_logical_name.as_str(),
+            change.record_id.as_str(),
+        )
+        .await
+    {
+        Ok(record) => {
+            let response = RuntimeRecordResponse::from(record);
+            if let Err(error) = crate::qrywell_sync::enqueue_runtime_record_upsert(
+                &state.postgres_pool,
+                user.tenant_id(),
+                change.entity_logical_name.as_str(),
+                &response,
+                state.qrywell_sync_max_attempts,
+            )
+            .await
+            {
+                warn!(
+                    error = %error,
+                    tenant_id = %user.tenant_id(),
+                    change_id = %change.change_id,
+                    "qrywell sync failed after field change approval"
+                );
+            }
+        }
+        Err(error) => {
+            warn!(
+                error = %error,
+                tenant_id = %user.tenant_id(),
+                change_id = %change.change_id,
+                "runtime record reload failed after field change approval"
+            );
+        }
+    }
+
+    Ok(Json(PendingFieldChangeResponse::from(change)))
+}
+
+pub async fn reject_pending_field_change_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(change_id): Path<String>,
+) -> ApiResult<Json<PendingFieldChangeResponse>> {
+    let change = state
+        .metadata_service
+        .reject_pending_field_change(&user, change_id.as_str())
+        .await?;
+
+    Ok(Json(PendingFieldChangeResponse::from(change)))
+}
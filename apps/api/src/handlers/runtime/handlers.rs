@@ -1,26 +1,63 @@
+use axum::http::HeaderMap;
+use qryvanta_application::RuntimeRecordUpsertOutcome;
+use utoipa::IntoParams;
+
 use super::*;
+use crate::idempotency_key::idempotency_key_header;
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, serde::Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct RuntimeRecordListQuery {
+    /// Page size; defaults to 50.
     pub limit: Option<usize>,
+    /// Number of records to skip; defaults to 0.
     pub offset: Option<usize>,
+    /// Returns records in inactive statuses too; defaults to `false`.
+    pub include_inactive: Option<bool>,
+}
+
+#[derive(Debug, serde::Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RuntimeRecordQueryParams {
+    /// View logical name the query executes; serves repeated executions from
+    /// the view result cache when it is enabled.
+    pub view: Option<String>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/runtime/{entity_logical_name}/records",
+    tag = "runtime",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+        RuntimeRecordListQuery,
+    ),
+    responses(
+        (status = 200, description = "One page of runtime records; a `Link: rel=\"next\"` header points at the next page", body = [RuntimeRecordResponse]),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn list_runtime_records_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
+    OriginalUri(uri): OriginalUri,
     Path(entity_logical_name): Path<String>,
     Query(query): Query<RuntimeRecordListQuery>,
-) -> ApiResult<Json<Vec<RuntimeRecordResponse>>> {
+) -> ApiResult<PaginatedJson<RuntimeRecordResponse>> {
+    let window = PageWindow {
+        limit: query.limit.unwrap_or(50),
+        offset: query.offset.unwrap_or(0),
+    };
     let records = state
         .metadata_service
         .list_runtime_records(
             &user,
             entity_logical_name.as_str(),
             qryvanta_application::RecordListQuery {
-                limit: query.limit.unwrap_or(50),
-                offset: query.offset.unwrap_or(0),
+                limit: window.limit,
+                offset: window.offset,
                 owner_subject: None,
+                include_inactive: query.include_inactive.unwrap_or(false),
             },
         )
         .await?
@@ -28,24 +65,91 @@ pub async fn list_runtime_records_handler(
         .map(RuntimeRecordResponse::from)
         .collect();
 
-    Ok(Json(records))
+    Ok(PaginatedJson::new(&uri, window, records))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/runtime/{entity_logical_name}/records",
+    tag = "runtime",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Returns the record created by an earlier request with the same key instead of creating another"),
+    ),
+    request_body = CreateRuntimeRecordRequest,
+    responses(
+        (status = 201, description = "Created runtime record", body = RuntimeRecordResponse),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn create_runtime_record_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
     Path(entity_logical_name): Path<String>,
+    headers: HeaderMap,
     Json(payload): Json<CreateRuntimeRecordRequest>,
 ) -> ApiResult<(StatusCode, Json<RuntimeRecordResponse>)> {
     let record = state
         .metadata_service
-        .create_runtime_record(&user, entity_logical_name.as_str(), payload.data)
+        .create_runtime_record_with_idempotency_key(
+            &user,
+            entity_logical_name.as_str(),
+            payload.data,
+            payload.ignore_duplicate_warnings.unwrap_or(false),
+            idempotency_key_header(&headers)?,
+        )
+        .await?;
+
+    let response =
+        finish_runtime_record_creation(&state, &user, entity_logical_name.as_str(), record).await;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/runtime/{entity_logical_name}/records/quick-create",
+    tag = "runtime",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+    ),
+    request_body = QuickCreateRuntimeRecordRequest,
+    responses(
+        (status = 201, description = "Created runtime record", body = RuntimeRecordResponse),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
+pub async fn quick_create_runtime_record_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(entity_logical_name): Path<String>,
+    Json(payload): Json<QuickCreateRuntimeRecordRequest>,
+) -> ApiResult<(StatusCode, Json<RuntimeRecordResponse>)> {
+    let record = state
+        .metadata_service
+        .quick_create_runtime_record(
+            &user,
+            entity_logical_name.as_str(),
+            payload.form_logical_name.as_deref(),
+            payload.data,
+        )
         .await?;
 
+    let response =
+        finish_runtime_record_creation(&state, &user, entity_logical_name.as_str(), record).await;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Runs best-effort post-create side effects shared by full and quick-create endpoints.
+async fn finish_runtime_record_creation(
+    state: &AppState,
+    user: &UserIdentity,
+    entity_logical_name: &str,
+    record: RuntimeRecord,
+) -> RuntimeRecordResponse {
     if let Err(error) = state
         .workflow_service
         .drain_runtime_record_workflow_events_inline(
-            &user,
+            user,
             state.workflow_worker_max_claim_limit,
             state.workflow_worker_default_lease_seconds,
         )
@@ -60,11 +164,30 @@ pub async fn create_runtime_record_handler(
         );
     }
 
+    if let Err(error) = state
+        .contact_identity_service
+        .sync_record_unchecked(
+            user,
+            entity_logical_name,
+            record.record_id().as_str(),
+            record.data(),
+        )
+        .await
+    {
+        warn!(
+            error = %error,
+            tenant_id = %user.tenant_id(),
+            entity_logical_name = %entity_logical_name,
+            record_id = %record.record_id().as_str(),
+            "contact identity sync failed after runtime record creation"
+        );
+    }
+
     let response = RuntimeRecordResponse::from(record);
     if let Err(error) = crate::qrywell_sync::enqueue_runtime_record_upsert(
         &state.postgres_pool,
         user.tenant_id(),
-        entity_logical_name.as_str(),
+        entity_logical_name,
         &response,
         state.qrywell_sync_max_attempts,
     )
@@ -79,13 +202,28 @@ pub async fn create_runtime_record_handler(
         );
     }
 
-    Ok((StatusCode::CREATED, Json(response)))
+    response
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/runtime/{entity_logical_name}/records/query",
+    tag = "runtime",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+        RuntimeRecordQueryParams,
+    ),
+    request_body = QueryRuntimeRecordsRequest,
+    responses(
+        (status = 200, description = "Matching runtime records", body = [RuntimeRecordResponse]),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn query_runtime_records_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
     Path(entity_logical_name): Path<String>,
+    Query(params): Query<RuntimeRecordQueryParams>,
     Json(payload): Json<QueryRuntimeRecordsRequest>,
 ) -> ApiResult<Json<Vec<RuntimeRecordResponse>>> {
     let _query_permit = state.try_acquire_runtime_query_permit()?;
@@ -98,17 +236,46 @@ pub async fn query_runtime_records_handler(
     )
     .await?;
 
-    let records = state
-        .metadata_service
-        .query_runtime_records(&user, entity_logical_name.as_str(), query)
-        .await?
-        .into_iter()
-        .map(RuntimeRecordResponse::from)
-        .collect();
+    let records = match params.view {
+        Some(view_logical_name) => {
+            state
+                .metadata_service
+                .query_runtime_view_records(
+                    &user,
+                    entity_logical_name.as_str(),
+                    view_logical_name.as_str(),
+                    query,
+                )
+                .await?
+        }
+        None => {
+            state
+                .metadata_service
+                .query_runtime_records(&user, entity_logical_name.as_str(), query)
+                .await?
+        }
+    }
+    .into_iter()
+    .map(RuntimeRecordResponse::from)
+    .collect();
 
     Ok(Json(records))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/runtime/{entity_logical_name}/records/{record_id}",
+    tag = "runtime",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+        ("record_id" = String, Path, description = "Runtime record id"),
+    ),
+    request_body = UpdateRuntimeRecordRequest,
+    responses(
+        (status = 200, description = "Updated runtime record", body = RuntimeRecordResponse),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn update_runtime_record_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -117,18 +284,98 @@ pub async fn update_runtime_record_handler(
 ) -> ApiResult<Json<RuntimeRecordResponse>> {
     let record = state
         .metadata_service
-        .update_runtime_record(
+        .update_runtime_record_with_duplicate_override(
             &user,
             entity_logical_name.as_str(),
             record_id.as_str(),
             payload.data,
+            payload.expected_version,
+            payload.ignore_duplicate_warnings.unwrap_or(false),
         )
         .await?;
 
+    let response =
+        finish_runtime_record_update(&state, &user, entity_logical_name.as_str(), record).await;
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/runtime/{entity_logical_name}/records/by-key/{key_field_logical_name}/{key_value}",
+    tag = "runtime",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+        ("key_field_logical_name" = String, Path, description = "Unique field used as alternate key"),
+        ("key_value" = String, Path, description = "Alternate key value"),
+    ),
+    request_body = UpsertRuntimeRecordRequest,
+    responses(
+        (status = 201, description = "No record held the key, so one was created", body = RuntimeRecordUpsertResponse),
+        (status = 200, description = "The record holding the key was updated", body = RuntimeRecordUpsertResponse),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
+pub async fn upsert_runtime_record_by_key_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, key_field_logical_name, key_value)): Path<(String, String, String)>,
+    Json(payload): Json<UpsertRuntimeRecordRequest>,
+) -> ApiResult<(StatusCode, Json<RuntimeRecordUpsertResponse>)> {
+    let upsert = state
+        .metadata_service
+        .upsert_runtime_record_by_key(
+            &user,
+            entity_logical_name.as_str(),
+            key_field_logical_name.as_str(),
+            key_value.as_str(),
+            payload.data,
+            payload.ignore_duplicate_warnings.unwrap_or(false),
+        )
+        .await?;
+
+    let (status, record) = match upsert.outcome {
+        RuntimeRecordUpsertOutcome::Created => (
+            StatusCode::CREATED,
+            finish_runtime_record_creation(
+                &state,
+                &user,
+                entity_logical_name.as_str(),
+                upsert.record,
+            )
+            .await,
+        ),
+        RuntimeRecordUpsertOutcome::Updated => (
+            StatusCode::OK,
+            finish_runtime_record_update(
+                &state,
+                &user,
+                entity_logical_name.as_str(),
+                upsert.record,
+            )
+            .await,
+        ),
+    };
+
+    Ok((
+        status,
+        Json(RuntimeRecordUpsertResponse {
+            outcome: upsert.outcome.as_str().to_owned(),
+            record,
+        }),
+    ))
+}
+
+/// Runs best-effort post-update side effects shared by update and upsert endpoints.
+pub(super) async fn finish_runtime_record_update(
+    state: &AppState,
+    user: &UserIdentity,
+    entity_logical_name: &str,
+    record: RuntimeRecord,
+) -> RuntimeRecordResponse {
     if let Err(error) = state
         .workflow_service
         .drain_runtime_record_workflow_events_inline(
-            &user,
+            user,
             state.workflow_worker_max_claim_limit,
             state.workflow_worker_default_lease_seconds,
         )
@@ -143,11 +390,30 @@ pub async fn update_runtime_record_handler(
         );
     }
 
+    if let Err(error) = state
+        .contact_identity_service
+        .sync_record_unchecked(
+            user,
+            entity_logical_name,
+            record.record_id().as_str(),
+            record.data(),
+        )
+        .await
+    {
+        warn!(
+            error = %error,
+            tenant_id = %user.tenant_id(),
+            entity_logical_name = %entity_logical_name,
+            record_id = %record.record_id().as_str(),
+            "contact identity sync failed after runtime record update"
+        );
+    }
+
     let response = RuntimeRecordResponse::from(record);
     if let Err(error) = crate::qrywell_sync::enqueue_runtime_record_upsert(
         &state.postgres_pool,
         user.tenant_id(),
-        entity_logical_name.as_str(),
+        entity_logical_name,
         &response,
         state.qrywell_sync_max_attempts,
     )
@@ -162,9 +428,22 @@ pub async fn update_runtime_record_handler(
         );
     }
 
-    Ok(Json(response))
+    response
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/runtime/{entity_logical_name}/records/{record_id}",
+    tag = "runtime",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+        ("record_id" = String, Path, description = "Runtime record id"),
+    ),
+    responses(
+        (status = 200, description = "Runtime record", body = RuntimeRecordResponse),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn get_runtime_record_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -178,6 +457,49 @@ pub async fn get_runtime_record_handler(
     Ok(Json(RuntimeRecordResponse::from(record)))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/runtime/{entity_logical_name}/slugs/{slug}",
+    tag = "runtime",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+        ("slug" = String, Path, description = "Record slug"),
+    ),
+    responses(
+        (status = 200, description = "Record the slug resolves to", body = RuntimeRecordSlugResponse),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
+pub async fn resolve_runtime_record_slug_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, slug)): Path<(String, String)>,
+) -> ApiResult<Json<RuntimeRecordSlugResponse>> {
+    let record = state
+        .metadata_service
+        .resolve_runtime_record_slug(&user, entity_logical_name.as_str(), slug.as_str())
+        .await?;
+
+    Ok(Json(RuntimeRecordSlugResponse {
+        entity_logical_name,
+        slug,
+        record_id: record.record_id().as_str().to_owned(),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/runtime/{entity_logical_name}/records/{record_id}",
+    tag = "runtime",
+    params(
+        ("entity_logical_name" = String, Path, description = "Entity logical name"),
+        ("record_id" = String, Path, description = "Runtime record id"),
+    ),
+    responses(
+        (status = 204, description = "Runtime record deleted"),
+        (status = "4XX", description = "Request rejected", body = ErrorResponse),
+    )
+)]
 pub async fn delete_runtime_record_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -243,3 +565,22 @@ pub async fn list_runtime_business_rules_handler(
 
     Ok(Json(rules))
 }
+
+pub async fn assign_runtime_record_owner_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id)): Path<(String, String)>,
+    Json(payload): Json<AssignRuntimeRecordOwnerRequest>,
+) -> ApiResult<Json<RuntimeRecordOwnerResponse>> {
+    let assignment = state
+        .metadata_service
+        .assign_runtime_record_owner(
+            &user,
+            entity_logical_name.as_str(),
+            record_id.as_str(),
+            payload.owner_subject.as_str(),
+        )
+        .await?;
+
+    Ok(Json(RuntimeRecordOwnerResponse::from(assignment)))
+}
@@ -0,0 +1,30 @@
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RelationLookupSearchQuery {
+    pub q: Option<String>,
+    pub limit: Option<usize>,
+}
+
+pub async fn search_relation_lookup_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, field_logical_name)): Path<(String, String)>,
+    Query(query): Query<RelationLookupSearchQuery>,
+) -> ApiResult<Json<Vec<RelationLookupMatchResponse>>> {
+    let matches = state
+        .metadata_service
+        .search_relation_lookup(
+            &user,
+            entity_logical_name.as_str(),
+            field_logical_name.as_str(),
+            query.q.as_deref().unwrap_or_default(),
+            query.limit,
+        )
+        .await?
+        .into_iter()
+        .map(RelationLookupMatchResponse::from)
+        .collect();
+
+    Ok(Json(matches))
+}
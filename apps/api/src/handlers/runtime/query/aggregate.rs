@@ -0,0 +1,181 @@
+use super::*;
+
+use crate::dto::AggregateRuntimeRecordsRequest;
+
+pub(crate) async fn runtime_record_aggregate_query_from_request(
+    metadata_service: &qryvanta_application::MetadataService,
+    actor: &UserIdentity,
+    entity_logical_name: &str,
+    payload: AggregateRuntimeRecordsRequest,
+    max_limit: usize,
+) -> Result<qryvanta_application::RuntimeRecordAggregateQuery, AppError> {
+    let AggregateRuntimeRecordsRequest {
+        limit,
+        logical_mode,
+        where_clause,
+        conditions,
+        group_by,
+        aggregates,
+        having,
+        sort,
+    } = payload;
+
+    let schema = metadata_service
+        .latest_published_schema_unchecked(actor, entity_logical_name)
+        .await?
+        .ok_or_else(|| {
+            AppError::Validation(format!(
+                "entity '{}' must be published before runtime records can be aggregated",
+                entity_logical_name
+            ))
+        })?;
+
+    // Aggregate queries have no link entities, so only the root scope resolves.
+    let mut scope_field_types: ScopeFieldTypes = std::collections::BTreeMap::new();
+    scope_field_types.insert(
+        String::new(),
+        schema
+            .queryable_fields()?
+            .iter()
+            .map(|field| (field.logical_name().as_str().to_owned(), field.field_type()))
+            .collect::<std::collections::BTreeMap<_, _>>(),
+    );
+    let field_type = |field_logical_name: &str, context: &str| {
+        scope_field_types
+            .get("")
+            .and_then(|field_types| field_types.get(field_logical_name))
+            .copied()
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "unknown {} field '{}' for entity '{}'",
+                    context, field_logical_name, entity_logical_name
+                ))
+            })
+    };
+
+    let filters = conditions
+        .unwrap_or_default()
+        .into_iter()
+        .map(|condition| {
+            runtime_record_filter_from_request(
+                condition,
+                entity_logical_name,
+                &scope_field_types,
+                true,
+            )
+        })
+        .collect::<Result<Vec<_>, AppError>>()?;
+
+    let where_clause = where_clause
+        .map(|group| {
+            runtime_record_group_from_request(group, entity_logical_name, &scope_field_types)
+        })
+        .transpose()?;
+
+    let logical_mode = logical_mode
+        .as_deref()
+        .map(qryvanta_application::RuntimeRecordLogicalMode::parse_transport)
+        .transpose()?
+        .unwrap_or(qryvanta_application::RuntimeRecordLogicalMode::And);
+
+    let group_by = group_by
+        .unwrap_or_default()
+        .into_iter()
+        .map(|field_logical_name| {
+            Ok(qryvanta_application::RuntimeRecordGroupBy {
+                field_type: field_type(field_logical_name.as_str(), "group-by")?,
+                field_logical_name,
+            })
+        })
+        .collect::<Result<Vec<_>, AppError>>()?;
+
+    let aggregates = aggregates
+        .into_iter()
+        .map(|aggregate| {
+            let field_type = aggregate
+                .field_logical_name
+                .as_deref()
+                .map(|field_logical_name| field_type(field_logical_name, "aggregate"))
+                .transpose()?;
+
+            Ok(qryvanta_application::RuntimeRecordAggregate {
+                alias: aggregate.alias,
+                function: qryvanta_application::RuntimeRecordAggregateFunction::parse_transport(
+                    aggregate.function.as_str(),
+                )?,
+                field_logical_name: aggregate.field_logical_name,
+                field_type,
+            })
+        })
+        .collect::<Result<Vec<_>, AppError>>()?;
+
+    let having = having
+        .unwrap_or_default()
+        .into_iter()
+        .map(|entry| {
+            Ok(qryvanta_application::RuntimeRecordAggregateHaving {
+                aggregate_alias: entry.aggregate_alias,
+                operator: qryvanta_application::RuntimeRecordOperator::parse_transport(
+                    entry.operator.as_str(),
+                )?,
+                value: serde_json::Number::from_f64(entry.value)
+                    .map(serde_json::Value::Number)
+                    .ok_or_else(|| {
+                        AppError::Validation(
+                            "aggregate having value must be a finite number".to_owned(),
+                        )
+                    })?,
+            })
+        })
+        .collect::<Result<Vec<_>, AppError>>()?;
+
+    let sort = sort
+        .unwrap_or_default()
+        .into_iter()
+        .map(|entry| {
+            let key = match (entry.field_logical_name, entry.aggregate_alias) {
+                (Some(field_logical_name), None) => {
+                    qryvanta_application::RuntimeRecordAggregateSortKey::GroupField(
+                        field_logical_name,
+                    )
+                }
+                (None, Some(alias)) => {
+                    qryvanta_application::RuntimeRecordAggregateSortKey::Aggregate(alias)
+                }
+                _ => {
+                    return Err(AppError::Validation(
+                        "aggregate sort entries must set exactly one of field_logical_name or aggregate_alias"
+                            .to_owned(),
+                    ));
+                }
+            };
+            let direction = entry
+                .direction
+                .as_deref()
+                .map(qryvanta_application::RuntimeRecordSortDirection::parse_transport)
+                .transpose()?
+                .unwrap_or(qryvanta_application::RuntimeRecordSortDirection::Asc);
+
+            Ok(qryvanta_application::RuntimeRecordAggregateSort { key, direction })
+        })
+        .collect::<Result<Vec<_>, AppError>>()?;
+
+    let requested_limit = limit.unwrap_or(50);
+    if requested_limit == 0 {
+        return Err(AppError::Validation(
+            "runtime aggregate query limit must be greater than zero".to_owned(),
+        ));
+    }
+
+    Ok(qryvanta_application::RuntimeRecordAggregateQuery {
+        limit: requested_limit.min(max_limit),
+        logical_mode,
+        where_clause,
+        filters,
+        group_by,
+        aggregates,
+        having,
+        sort,
+        owner_subject: None,
+    })
+}
@@ -73,13 +73,21 @@ pub(super) async fn runtime_record_links_from_request(
             )));
         };
 
-        if relation_field.field_type() != qryvanta_domain::FieldType::Relation {
-            return Err(AppError::Validation(format!(
-                "link relation field '{}' on entity '{}' must be of type 'relation'",
-                relation_field_name,
-                parent_schema.entity().logical_name().as_str()
-            )));
-        }
+        let cardinality = match relation_field.field_type() {
+            qryvanta_domain::FieldType::Relation => {
+                qryvanta_application::RuntimeRecordLinkCardinality::ManyToOne
+            }
+            qryvanta_domain::FieldType::ManyToMany => {
+                qryvanta_application::RuntimeRecordLinkCardinality::ManyToMany
+            }
+            _ => {
+                return Err(AppError::Validation(format!(
+                    "link relation field '{}' on entity '{}' must be of type 'relation' or 'many_to_many'",
+                    relation_field_name,
+                    parent_schema.entity().logical_name().as_str()
+                )));
+            }
+        };
 
         let Some(target_entity) = relation_field.relation_target_entity() else {
             return Err(AppError::Validation(format!(
@@ -107,7 +115,7 @@ pub(super) async fn runtime_record_links_from_request(
         scope_field_types.insert(
             alias.clone(),
             target_schema
-                .fields()
+                .queryable_fields()?
                 .iter()
                 .map(|field| (field.logical_name().as_str().to_owned(), field.field_type()))
                 .collect(),
@@ -119,6 +127,7 @@ pub(super) async fn runtime_record_links_from_request(
             relation_field_logical_name: relation_field_name,
             target_entity_logical_name: target_entity.as_str().to_owned(),
             join_type,
+            cardinality,
         });
         scope_entities.insert(alias, target_entity.as_str().to_owned());
     }
@@ -4,10 +4,13 @@ use conditions::{runtime_record_filter_from_request, runtime_record_group_from_r
 use links::runtime_record_links_from_request;
 use scope::{ScopeFieldTypes, normalize_scope_alias};
 
+mod aggregate;
 mod conditions;
 mod links;
 mod scope;
 
+pub(crate) use aggregate::runtime_record_aggregate_query_from_request;
+
 pub(crate) async fn runtime_record_query_from_request(
     metadata_service: &qryvanta_application::MetadataService,
     actor: &UserIdentity,
@@ -24,6 +27,7 @@ pub(crate) async fn runtime_record_query_from_request(
         link_entities,
         sort,
         filters: legacy_filters,
+        include_inactive,
     } = payload;
 
     let root_scope_key = String::new();
@@ -41,7 +45,7 @@ pub(crate) async fn runtime_record_query_from_request(
     scope_field_types.insert(
         root_scope_key.clone(),
         schema
-            .fields()
+            .queryable_fields()?
             .iter()
             .map(|field| (field.logical_name().as_str().to_owned(), field.field_type()))
             .collect::<std::collections::BTreeMap<_, _>>(),
@@ -152,6 +156,7 @@ pub(crate) async fn runtime_record_query_from_request(
         links,
         sort,
         owner_subject: None,
+        include_inactive: include_inactive.unwrap_or(false),
     })
 }
 
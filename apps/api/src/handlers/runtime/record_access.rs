@@ -0,0 +1,122 @@
+use qryvanta_application::{
+    RecordAccessRequestQuery, RecordAccessRequestStatus, RequestRecordAccessInput,
+};
+
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RecordAccessRequestListQuery {
+    pub entity_logical_name: Option<String>,
+    pub record_id: Option<String>,
+    pub status: Option<String>,
+}
+
+pub async fn request_record_access_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id)): Path<(String, String)>,
+    Json(payload): Json<RequestRecordAccessRequest>,
+) -> ApiResult<(StatusCode, Json<RecordAccessRequestResponse>)> {
+    let request = state
+        .record_access_service
+        .request_access(
+            &user,
+            entity_logical_name.as_str(),
+            record_id.as_str(),
+            RequestRecordAccessInput {
+                reason: payload.reason,
+                duration_hours: payload.duration_hours,
+            },
+        )
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(RecordAccessRequestResponse::from(request)),
+    ))
+}
+
+pub async fn list_record_access_requests_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<RecordAccessRequestListQuery>,
+) -> ApiResult<Json<Vec<RecordAccessRequestResponse>>> {
+    let status = query
+        .status
+        .as_deref()
+        .map(RecordAccessRequestStatus::parse)
+        .transpose()?;
+    let requests = state
+        .record_access_service
+        .list_requests(
+            &user,
+            RecordAccessRequestQuery {
+                entity_logical_name: query.entity_logical_name,
+                record_id: query.record_id,
+                status,
+                involving_subject: None,
+            },
+        )
+        .await?
+        .into_iter()
+        .map(RecordAccessRequestResponse::from)
+        .collect();
+
+    Ok(Json(requests))
+}
+
+pub async fn approve_record_access_request_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(request_id): Path<String>,
+    Json(payload): Json<ApproveRecordAccessRequest>,
+) -> ApiResult<Json<RecordAccessRequestResponse>> {
+    let request = state
+        .record_access_service
+        .approve_request(&user, request_id.as_str(), payload.duration_hours)
+        .await?;
+
+    Ok(Json(RecordAccessRequestResponse::from(request)))
+}
+
+pub async fn reject_record_access_request_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(request_id): Path<String>,
+) -> ApiResult<Json<RecordAccessRequestResponse>> {
+    let request = state
+        .record_access_service
+        .reject_request(&user, request_id.as_str())
+        .await?;
+
+    Ok(Json(RecordAccessRequestResponse::from(request)))
+}
+
+pub async fn list_record_shares_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id)): Path<(String, String)>,
+) -> ApiResult<Json<Vec<RecordShareResponse>>> {
+    let shares = state
+        .record_access_service
+        .list_record_shares(&user, entity_logical_name.as_str(), record_id.as_str())
+        .await?
+        .into_iter()
+        .map(RecordShareResponse::from)
+        .collect();
+
+    Ok(Json(shares))
+}
+
+pub async fn revoke_record_share_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(share_id): Path<String>,
+) -> ApiResult<Json<RecordShareResponse>> {
+    let share = state
+        .record_access_service
+        .revoke_share(&user, share_id.as_str())
+        .await?;
+
+    Ok(Json(RecordShareResponse::from(share)))
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+pub async fn list_runtime_record_history_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id)): Path<(String, String)>,
+) -> ApiResult<Json<Vec<RuntimeRecordHistoryEntryResponse>>> {
+    let history = state
+        .metadata_service
+        .runtime_record_history(&user, entity_logical_name.as_str(), record_id.as_str())
+        .await?
+        .into_iter()
+        .map(RuntimeRecordHistoryEntryResponse::from)
+        .collect();
+
+    Ok(Json(history))
+}
+
+/// POST /api/runtime/{entity_logical_name}/records/{record_id}/history/{version}/restore
+///
+/// Writes the values the record held at `version` back as a new version.
+pub async fn restore_runtime_record_version_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id, version)): Path<(String, String, i64)>,
+) -> ApiResult<Json<RuntimeRecordResponse>> {
+    let record = state
+        .metadata_service
+        .restore_runtime_record_version(
+            &user,
+            entity_logical_name.as_str(),
+            record_id.as_str(),
+            version,
+        )
+        .await?;
+
+    let response = super::handlers::finish_runtime_record_update(
+        &state,
+        &user,
+        entity_logical_name.as_str(),
+        record,
+    )
+    .await;
+    Ok(Json(response))
+}
@@ -0,0 +1,70 @@
+use super::*;
+
+pub async fn list_runtime_record_relations_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id, field_logical_name)): Path<(String, String, String)>,
+) -> ApiResult<Json<Vec<RuntimeRecordResponse>>> {
+    let records = state
+        .metadata_service
+        .list_associated_runtime_records(
+            &user,
+            entity_logical_name.as_str(),
+            record_id.as_str(),
+            field_logical_name.as_str(),
+        )
+        .await?
+        .into_iter()
+        .map(RuntimeRecordResponse::from)
+        .collect();
+
+    Ok(Json(records))
+}
+
+pub async fn associate_runtime_record_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id, field_logical_name)): Path<(String, String, String)>,
+    Json(payload): Json<AssociateRuntimeRecordRequest>,
+) -> ApiResult<StatusCode> {
+    let created = state
+        .metadata_service
+        .associate_runtime_records(
+            &user,
+            entity_logical_name.as_str(),
+            record_id.as_str(),
+            field_logical_name.as_str(),
+            payload.target_record_id.as_str(),
+        )
+        .await?;
+
+    Ok(if created {
+        StatusCode::CREATED
+    } else {
+        StatusCode::NO_CONTENT
+    })
+}
+
+pub async fn disassociate_runtime_record_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id, field_logical_name, target_record_id)): Path<(
+        String,
+        String,
+        String,
+        String,
+    )>,
+) -> ApiResult<StatusCode> {
+    state
+        .metadata_service
+        .disassociate_runtime_records(
+            &user,
+            entity_logical_name.as_str(),
+            record_id.as_str(),
+            field_logical_name.as_str(),
+            target_record_id.as_str(),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
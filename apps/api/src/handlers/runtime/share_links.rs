@@ -0,0 +1,262 @@
+use std::net::SocketAddr;
+
+use axum::Form;
+use axum::extract::ConnectInfo;
+use axum::http::{HeaderMap, HeaderValue, header};
+use axum::response::{Html, IntoResponse, Response};
+use qryvanta_application::{
+    CreateRecordShareLinkInput, RecordShareLinkAccess, RecordShareLinkSummary,
+    RecordShareLinkViewContext,
+};
+use qryvanta_core::TenantId;
+use uuid::Uuid;
+
+use crate::middleware::extract_client_ip_from_parts;
+
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct RecordShareLinkPasswordForm {
+    pub password: String,
+}
+
+pub async fn create_record_share_link_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id)): Path<(String, String)>,
+    Json(payload): Json<CreateRecordShareLinkRequest>,
+) -> ApiResult<(StatusCode, Json<CreatedRecordShareLinkResponse>)> {
+    let created = state
+        .record_share_link_service
+        .create_link(
+            &user,
+            entity_logical_name.as_str(),
+            record_id.as_str(),
+            CreateRecordShareLinkInput {
+                field_logical_names: payload.field_logical_names,
+                expires_in_hours: payload.expires_in_hours,
+                password: payload.password,
+            },
+        )
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedRecordShareLinkResponse::from(created)),
+    ))
+}
+
+pub async fn list_record_share_links_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id)): Path<(String, String)>,
+) -> ApiResult<Json<Vec<RecordShareLinkResponse>>> {
+    let links = state
+        .record_share_link_service
+        .list_links(&user, entity_logical_name.as_str(), record_id.as_str())
+        .await?
+        .into_iter()
+        .map(RecordShareLinkResponse::from)
+        .collect();
+
+    Ok(Json(links))
+}
+
+pub async fn revoke_record_share_link_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(link_id): Path<String>,
+) -> ApiResult<Json<RecordShareLinkResponse>> {
+    let link = state
+        .record_share_link_service
+        .revoke_link(&user, link_id.as_str())
+        .await?;
+
+    Ok(Json(RecordShareLinkResponse::from(link)))
+}
+
+pub async fn list_record_share_link_views_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(link_id): Path<String>,
+) -> ApiResult<Json<Vec<RecordShareLinkViewResponse>>> {
+    let views = state
+        .record_share_link_service
+        .list_link_views(&user, link_id.as_str())
+        .await?
+        .into_iter()
+        .map(RecordShareLinkViewResponse::from)
+        .collect();
+
+    Ok(Json(views))
+}
+
+pub async fn view_public_record_share_link_handler(
+    State(state): State<AppState>,
+    Path((tenant_id, token)): Path<(String, String)>,
+    headers: HeaderMap,
+    ConnectInfo(connect_info): ConnectInfo<SocketAddr>,
+) -> ApiResult<Response> {
+    open_public_record_share_link(&state, &tenant_id, &token, None, &headers, connect_info).await
+}
+
+pub async fn submit_public_record_share_link_password_handler(
+    State(state): State<AppState>,
+    Path((tenant_id, token)): Path<(String, String)>,
+    headers: HeaderMap,
+    ConnectInfo(connect_info): ConnectInfo<SocketAddr>,
+    Form(form): Form<RecordShareLinkPasswordForm>,
+) -> ApiResult<Response> {
+    open_public_record_share_link(
+        &state,
+        &tenant_id,
+        &token,
+        Some(form.password.as_str()),
+        &headers,
+        connect_info,
+    )
+    .await
+}
+
+async fn open_public_record_share_link(
+    state: &AppState,
+    tenant_id: &str,
+    token: &str,
+    password: Option<&str>,
+    headers: &HeaderMap,
+    connect_info: SocketAddr,
+) -> ApiResult<Response> {
+    let Ok(tenant_uuid) = Uuid::parse_str(tenant_id) else {
+        return Ok(render_share_link_page(None));
+    };
+    let context = RecordShareLinkViewContext {
+        ip_address: Some(extract_client_ip_from_parts(
+            headers,
+            Some(connect_info),
+            state.trust_proxy_headers,
+            &state.trusted_proxy_cidrs,
+        )),
+        user_agent: headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| !value.is_empty())
+            .map(ToOwned::to_owned),
+    };
+
+    match state
+        .record_share_link_service
+        .open_link(TenantId::from_uuid(tenant_uuid), token, password, context)
+        .await
+    {
+        Ok(access) => Ok(render_share_link_page(Some(access))),
+        Err(AppError::NotFound(_)) => Ok(render_share_link_page(None)),
+        Err(error) => Err(error.into()),
+    }
+}
+
+pub(super) fn render_share_link_page(access: Option<RecordShareLinkAccess>) -> Response {
+    let (status, title, body) = match access {
+        Some(RecordShareLinkAccess::Granted(summary)) => (
+            StatusCode::OK,
+            summary.entity_display_name.clone(),
+            render_summary(&summary),
+        ),
+        Some(RecordShareLinkAccess::PasswordRequired { invalid }) => (
+            if invalid {
+                StatusCode::UNAUTHORIZED
+            } else {
+                StatusCode::OK
+            },
+            "Password required".to_owned(),
+            render_password_form(invalid),
+        ),
+        Some(RecordShareLinkAccess::Unavailable) => (
+            StatusCode::GONE,
+            "Link unavailable".to_owned(),
+            "<p>This link has expired or is no longer available.</p>".to_owned(),
+        ),
+        None => (
+            StatusCode::NOT_FOUND,
+            "Link not found".to_owned(),
+            "<p>This link does not exist.</p>".to_owned(),
+        ),
+    };
+
+    let html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <meta name=\"robots\" content=\"noindex, nofollow\">\n<title>{}</title>\n</head>\n\
+         <body>\n{}\n</body>\n</html>\n",
+        escape_html(title.as_str()),
+        body
+    );
+
+    let mut response = (status, Html(html)).into_response();
+    let response_headers = response.headers_mut();
+    response_headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    response_headers.insert(
+        header::HeaderName::from_static("x-robots-tag"),
+        HeaderValue::from_static("noindex, nofollow"),
+    );
+    response
+}
+
+fn render_summary(summary: &RecordShareLinkSummary) -> String {
+    let rows = summary
+        .fields
+        .iter()
+        .map(|field| {
+            format!(
+                "<tr><th scope=\"row\">{}</th><td>{}</td></tr>",
+                escape_html(field.label.as_str()),
+                escape_html(field.value.as_str())
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "<main>\n<h1>{}</h1>\n<table>\n{}\n</table>\n\
+         <p><small>Read-only summary. This link expires {}.</small></p>\n</main>",
+        escape_html(summary.entity_display_name.as_str()),
+        rows,
+        escape_html(
+            summary
+                .expires_at
+                .format("%Y-%m-%d %H:%M UTC")
+                .to_string()
+                .as_str()
+        )
+    )
+}
+
+fn render_password_form(invalid: bool) -> String {
+    let error = if invalid {
+        "<p role=\"alert\">The password is incorrect.</p>\n"
+    } else {
+        ""
+    };
+
+    format!(
+        "<main>\n<h1>Password required</h1>\n{error}<form method=\"post\">\n\
+         <label for=\"password\">Password</label>\n\
+         <input id=\"password\" name=\"password\" type=\"password\" autocomplete=\"off\" required>\n\
+         <button type=\"submit\">View record</button>\n</form>\n</main>"
+    )
+}
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
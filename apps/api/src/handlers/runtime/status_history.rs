@@ -0,0 +1,17 @@
+use super::*;
+
+pub async fn list_runtime_record_status_history_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path((entity_logical_name, record_id)): Path<(String, String)>,
+) -> ApiResult<Json<Vec<RuntimeRecordStatusChangeResponse>>> {
+    let history = state
+        .metadata_service
+        .runtime_record_status_history(&user, entity_logical_name.as_str(), record_id.as_str())
+        .await?
+        .into_iter()
+        .map(RuntimeRecordStatusChangeResponse::from)
+        .collect();
+
+    Ok(Json(history))
+}
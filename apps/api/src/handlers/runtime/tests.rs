@@ -7,20 +7,30 @@ use axum::response::IntoResponse;
 
 use qryvanta_application::{
     AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, MetadataService,
-    RuntimeFieldGrant, SaveFieldInput, TemporaryPermissionGrant,
+    RecordListQuery, RecordShareLinkAccess, RecordShareLinkSummary, RecordShareLinkSummaryField,
+    RuntimeFieldGrant, RuntimeRecordExportColumn, SaveEntityStatusModelInput, SaveFieldInput,
+    SaveRelationLookupConfigInput, TemporaryPermissionGrant,
 };
 use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
-use qryvanta_domain::{FieldType, Permission};
+use qryvanta_domain::{
+    FieldType, FilterOperator, LogicalMode, Permission, RecordStatusOption, RecordStatusTransition,
+    RuntimeRecord, ViewFilterCondition, ViewFilterGroup,
+};
 use qryvanta_infrastructure::InMemoryMetadataRepository;
 
-use crate::dto::runtime::RuntimeRecordQuerySortRequest;
+use crate::dto::runtime::{
+    RuntimeRecordAggregateHavingRequest, RuntimeRecordAggregateRequest,
+    RuntimeRecordAggregateSortRequest, RuntimeRecordQuerySortRequest,
+};
 use crate::dto::{
-    QueryRuntimeRecordsRequest, RuntimeRecordQueryFilterRequest, RuntimeRecordQueryGroupRequest,
-    RuntimeRecordQueryLinkEntityRequest,
+    AggregateRuntimeRecordsRequest, QueryRuntimeRecordsRequest, RuntimeRecordQueryFilterRequest,
+    RuntimeRecordQueryGroupRequest, RuntimeRecordQueryLinkEntityRequest,
 };
 use crate::error::ApiError;
 
-use super::runtime_record_query_from_request;
+use super::export::{csv_header, csv_row, json_row};
+use super::share_links::render_share_link_page;
+use super::{runtime_record_aggregate_query_from_request, runtime_record_query_from_request};
 
 #[derive(Default)]
 struct NoopAuditRepository;
@@ -67,6 +77,31 @@ impl AuthorizationRepository for FakeAuthorizationRepository {
     ) -> AppResult<Option<TemporaryPermissionGrant>> {
         Ok(None)
     }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<qryvanta_application::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<qryvanta_application::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
 }
 
 async fn seed_metadata_service() -> (MetadataService, UserIdentity) {
@@ -115,6 +150,7 @@ async fn seed_metadata_service() -> (MetadataService, UserIdentity) {
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
@@ -141,6 +177,7 @@ async fn seed_metadata_service() -> (MetadataService, UserIdentity) {
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
@@ -161,6 +198,7 @@ async fn seed_metadata_service() -> (MetadataService, UserIdentity) {
                     calculation_expression: None,
                     relation_target_entity: Some("contact".to_owned()),
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
@@ -199,6 +237,7 @@ async fn runtime_query_payload_rejects_unpublished_entity() {
             link_entities: None,
             sort: None,
             filters: None,
+            include_inactive: None,
         },
         200,
     )
@@ -224,6 +263,7 @@ async fn runtime_query_validation_maps_to_bad_request_response() {
             link_entities: None,
             sort: None,
             filters: None,
+            include_inactive: None,
         },
         200,
     )
@@ -312,6 +352,7 @@ async fn runtime_query_full_where_and_link_entities_executes() {
                 direction: Some("asc".to_owned()),
             }]),
             filters: None,
+            include_inactive: None,
         },
         200,
     )
@@ -333,6 +374,143 @@ async fn runtime_query_full_where_and_link_entities_executes() {
     );
 }
 
+#[tokio::test]
+async fn runtime_query_link_entities_traverse_many_to_many_fields() {
+    let (metadata_service, actor) = seed_metadata_service().await;
+
+    assert!(
+        metadata_service
+            .register_entity(&actor, "tag", "Tag")
+            .await
+            .is_ok()
+    );
+    for (entity_logical_name, logical_name, field_type, relation_target_entity) in [
+        ("tag", "name", FieldType::Text, None),
+        (
+            "deal",
+            "tags",
+            FieldType::ManyToMany,
+            Some("tag".to_owned()),
+        ),
+    ] {
+        assert!(
+            metadata_service
+                .save_field(
+                    &actor,
+                    SaveFieldInput {
+                        entity_logical_name: entity_logical_name.to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type,
+                        is_required: false,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(metadata_service.publish_entity(&actor, "tag").await.is_ok());
+    assert!(
+        metadata_service
+            .publish_entity(&actor, "deal")
+            .await
+            .is_ok()
+    );
+
+    let contact = metadata_service
+        .create_runtime_record(&actor, "contact", serde_json::json!({"name": "Alice"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let mut tag_ids = Vec::new();
+    for name in ["urgent", "vip"] {
+        let tag = metadata_service
+            .create_runtime_record(&actor, "tag", serde_json::json!({"name": name}))
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        tag_ids.push(tag.record_id().as_str().to_owned());
+    }
+    for (title, tag_indexes) in [("Alpha", vec![0, 1]), ("Beta", vec![0]), ("Gamma", vec![])] {
+        let deal = metadata_service
+            .create_runtime_record(
+                &actor,
+                "deal",
+                serde_json::json!({
+                    "title": title,
+                    "owner_contact_id": contact.record_id().as_str()
+                }),
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        for tag_index in tag_indexes {
+            assert!(
+                metadata_service
+                    .associate_runtime_records(
+                        &actor,
+                        "deal",
+                        deal.record_id().as_str(),
+                        "tags",
+                        tag_ids[tag_index].as_str(),
+                    )
+                    .await
+                    .is_ok()
+            );
+        }
+    }
+
+    let query = runtime_record_query_from_request(
+        &metadata_service,
+        &actor,
+        "deal",
+        QueryRuntimeRecordsRequest {
+            limit: Some(50),
+            offset: Some(0),
+            logical_mode: Some("and".to_owned()),
+            where_clause: None,
+            conditions: Some(vec![RuntimeRecordQueryFilterRequest {
+                scope_alias: Some("tag".to_owned()),
+                field_logical_name: "name".to_owned(),
+                operator: "eq".to_owned(),
+                field_value: serde_json::json!("urgent"),
+            }]),
+            link_entities: Some(vec![RuntimeRecordQueryLinkEntityRequest {
+                alias: "tag".to_owned(),
+                parent_alias: None,
+                relation_field_logical_name: "tags".to_owned(),
+                join_type: Some("inner".to_owned()),
+            }]),
+            sort: Some(vec![RuntimeRecordQuerySortRequest {
+                scope_alias: None,
+                field_logical_name: "title".to_owned(),
+                direction: Some("asc".to_owned()),
+            }]),
+            filters: None,
+            include_inactive: None,
+        },
+        200,
+    )
+    .await;
+    assert!(query.is_ok());
+
+    let records = metadata_service
+        .query_runtime_records(&actor, "deal", query.unwrap_or_else(|_| unreachable!()))
+        .await
+        .unwrap_or_default();
+    let titles: Vec<_> = records
+        .iter()
+        .filter_map(|record| record.data().get("title"))
+        .collect();
+    assert_eq!(
+        titles,
+        vec![&serde_json::json!("Alpha"), &serde_json::json!("Beta")]
+    );
+}
+
 #[tokio::test]
 async fn runtime_query_limit_is_clamped_by_backpressure_cap() {
     let (metadata_service, actor) = seed_metadata_service().await;
@@ -350,6 +528,7 @@ async fn runtime_query_limit_is_clamped_by_backpressure_cap() {
             link_entities: None,
             sort: None,
             filters: None,
+            include_inactive: None,
         },
         120,
     )
@@ -358,3 +537,351 @@ async fn runtime_query_limit_is_clamped_by_backpressure_cap() {
     assert!(query.is_ok());
     assert_eq!(query.unwrap_or_else(|_| unreachable!()).limit, 120);
 }
+
+#[tokio::test]
+async fn relation_lookup_search_matches_term_filter_and_limit() {
+    let (metadata_service, actor) = seed_metadata_service().await;
+    for name in ["Ada Lovelace", "Adam Smith", "Grace Hopper"] {
+        assert!(
+            metadata_service
+                .create_runtime_record(&actor, "contact", serde_json::json!({"name": name}))
+                .await
+                .is_ok()
+        );
+    }
+    let search = |term: &'static str, limit| {
+        metadata_service.search_relation_lookup(&actor, "deal", "owner_contact_id", term, limit)
+    };
+    let labels = |matches: AppResult<Vec<qryvanta_application::RelationLookupMatch>>| {
+        matches
+            .unwrap_or_else(|_| unreachable!())
+            .into_iter()
+            .map(|lookup_match| lookup_match.label)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        labels(search("Ada", None).await),
+        vec!["Ada Lovelace", "Adam Smith"]
+    );
+    assert_eq!(labels(search("Ada", Some(1)).await), vec!["Ada Lovelace"]);
+    assert!(labels(search("Turing", None).await).is_empty());
+
+    assert!(
+        metadata_service
+            .save_relation_lookup_config(
+                &actor,
+                SaveRelationLookupConfigInput {
+                    entity_logical_name: "deal".to_owned(),
+                    field_logical_name: "owner_contact_id".to_owned(),
+                    search_field_logical_names: vec!["name".to_owned()],
+                    filter: Some(
+                        ViewFilterGroup::new(
+                            LogicalMode::And,
+                            vec![
+                                ViewFilterCondition::new(
+                                    "name",
+                                    FilterOperator::Neq,
+                                    serde_json::json!("Adam Smith"),
+                                )
+                                .unwrap_or_else(|_| unreachable!()),
+                            ],
+                        )
+                        .unwrap_or_else(|_| unreachable!()),
+                    ),
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert_eq!(labels(search("Ada", None).await), vec!["Ada Lovelace"]);
+}
+
+#[tokio::test]
+async fn runtime_lists_and_queries_hide_inactive_records_unless_requested() {
+    let (metadata_service, actor) = seed_metadata_service().await;
+    for logical_name in ["title", "status"] {
+        assert!(
+            metadata_service
+                .save_field(
+                    &actor,
+                    SaveFieldInput {
+                        entity_logical_name: "contact".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type: FieldType::Text,
+                        is_required: false,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity: None,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(
+        metadata_service
+            .publish_entity(&actor, "contact")
+            .await
+            .is_ok()
+    );
+    let status = |value: &str, inactive: bool| RecordStatusOption {
+        value: value.to_owned(),
+        label: value.to_owned(),
+        reasons: Vec::new(),
+        inactive,
+    };
+    assert!(
+        metadata_service
+            .save_entity_status_model(
+                &actor,
+                SaveEntityStatusModelInput {
+                    entity_logical_name: "contact".to_owned(),
+                    status_field_logical_name: "status".to_owned(),
+                    reason_field_logical_name: None,
+                    initial_status: "active".to_owned(),
+                    statuses: vec![status("active", false), status("inactive", true)],
+                    transitions: vec![RecordStatusTransition {
+                        from: "active".to_owned(),
+                        to: "inactive".to_owned(),
+                    }],
+                },
+            )
+            .await
+            .is_ok()
+    );
+    for (name, status) in [("Ada", "active"), ("Grace", "inactive")] {
+        assert!(
+            metadata_service
+                .create_runtime_record(
+                    &actor,
+                    "contact",
+                    serde_json::json!({"name": name, "status": status}),
+                )
+                .await
+                .is_ok()
+        );
+    }
+
+    let list = |include_inactive| {
+        metadata_service.list_runtime_records(
+            &actor,
+            "contact",
+            RecordListQuery {
+                limit: 50,
+                offset: 0,
+                owner_subject: None,
+                include_inactive,
+            },
+        )
+    };
+    assert_eq!(list(false).await.map(|records| records.len()).ok(), Some(1));
+    assert_eq!(list(true).await.map(|records| records.len()).ok(), Some(2));
+
+    for (include_inactive, expected) in [(None, 1), (Some(true), 2)] {
+        let query = runtime_record_query_from_request(
+            &metadata_service,
+            &actor,
+            "contact",
+            QueryRuntimeRecordsRequest {
+                limit: Some(50),
+                offset: None,
+                logical_mode: None,
+                where_clause: None,
+                conditions: Some(vec![RuntimeRecordQueryFilterRequest {
+                    scope_alias: None,
+                    field_logical_name: "name".to_owned(),
+                    operator: "in".to_owned(),
+                    field_value: serde_json::json!(["Ada", "Grace"]),
+                }]),
+                link_entities: None,
+                sort: None,
+                filters: None,
+                include_inactive,
+            },
+            200,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+        let records = metadata_service
+            .query_runtime_records(&actor, "contact", query)
+            .await
+            .unwrap_or_default();
+        assert_eq!(records.len(), expected);
+    }
+}
+
+#[tokio::test]
+async fn runtime_aggregate_payload_groups_counts_and_clamps_limit() {
+    let (metadata_service, actor) = seed_metadata_service().await;
+
+    for name in ["Alice", "Alice", "Bob"] {
+        assert!(
+            metadata_service
+                .create_runtime_record(&actor, "contact", serde_json::json!({"name": name}))
+                .await
+                .is_ok()
+        );
+    }
+
+    let query = runtime_record_aggregate_query_from_request(
+        &metadata_service,
+        &actor,
+        "contact",
+        AggregateRuntimeRecordsRequest {
+            limit: Some(500),
+            logical_mode: None,
+            where_clause: None,
+            conditions: None,
+            group_by: Some(vec!["name".to_owned()]),
+            aggregates: vec![RuntimeRecordAggregateRequest {
+                alias: "contacts".to_owned(),
+                function: "count".to_owned(),
+                field_logical_name: None,
+            }],
+            having: Some(vec![RuntimeRecordAggregateHavingRequest {
+                aggregate_alias: "contacts".to_owned(),
+                operator: "gte".to_owned(),
+                value: 1.0,
+            }]),
+            sort: Some(vec![RuntimeRecordAggregateSortRequest {
+                field_logical_name: None,
+                aggregate_alias: Some("contacts".to_owned()),
+                direction: Some("desc".to_owned()),
+            }]),
+        },
+        1,
+    )
+    .await;
+    assert!(query.is_ok());
+    let query = query.unwrap_or_else(|_| unreachable!());
+    assert_eq!(query.limit, 1);
+
+    let rows = metadata_service
+        .aggregate_runtime_records(&actor, "contact", query)
+        .await;
+    assert!(rows.is_ok());
+    let rows = rows.unwrap_or_default();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].group.get("name"), Some(&serde_json::json!("Alice")));
+    assert_eq!(rows[0].values.get("contacts"), Some(&serde_json::json!(2)));
+}
+
+#[tokio::test]
+async fn runtime_aggregate_payload_rejects_ambiguous_sort_and_unknown_fields() {
+    let (metadata_service, actor) = seed_metadata_service().await;
+    let payload = |group_by: &str, sort_field: Option<&str>| AggregateRuntimeRecordsRequest {
+        limit: None,
+        logical_mode: None,
+        where_clause: None,
+        conditions: None,
+        group_by: Some(vec![group_by.to_owned()]),
+        aggregates: vec![RuntimeRecordAggregateRequest {
+            alias: "contacts".to_owned(),
+            function: "count".to_owned(),
+            field_logical_name: None,
+        }],
+        having: None,
+        sort: Some(vec![RuntimeRecordAggregateSortRequest {
+            field_logical_name: sort_field.map(str::to_owned),
+            aggregate_alias: Some("contacts".to_owned()),
+            direction: None,
+        }]),
+    };
+
+    let ambiguous_sort = runtime_record_aggregate_query_from_request(
+        &metadata_service,
+        &actor,
+        "contact",
+        payload("name", Some("name")),
+        200,
+    )
+    .await;
+    let response =
+        ApiError::from(ambiguous_sort.err().unwrap_or_else(|| unreachable!())).into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let unknown_field = runtime_record_aggregate_query_from_request(
+        &metadata_service,
+        &actor,
+        "contact",
+        payload("missing", None),
+        200,
+    )
+    .await;
+    assert!(matches!(unknown_field, Err(AppError::Validation(_))));
+}
+
+#[tokio::test]
+async fn record_share_link_page_escapes_record_values() {
+    let response = render_share_link_page(Some(RecordShareLinkAccess::Granted(
+        RecordShareLinkSummary {
+            entity_display_name: "Contact".to_owned(),
+            record_id: "record-1".to_owned(),
+            fields: vec![RecordShareLinkSummaryField {
+                label: "Name".to_owned(),
+                value: "<script>alert('x')</script>".to_owned(),
+            }],
+            expires_at: chrono::Utc::now(),
+        },
+    )));
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response
+            .headers()
+            .get(axum::http::header::CACHE_CONTROL)
+            .and_then(|value| value.to_str().ok()),
+        Some("no-store")
+    );
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let body = String::from_utf8_lossy(&body);
+    assert!(body.contains("&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;"));
+    assert!(!body.contains("<script>"));
+
+    let locked = render_share_link_page(Some(RecordShareLinkAccess::PasswordRequired {
+        invalid: true,
+    }));
+    assert_eq!(locked.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(render_share_link_page(None).status(), StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn runtime_record_export_rows_escape_csv_cells_and_keep_json_values() {
+    let columns = vec![
+        RuntimeRecordExportColumn {
+            field_logical_name: "name".to_owned(),
+            field_type: FieldType::Text,
+        },
+        RuntimeRecordExportColumn {
+            field_logical_name: "score".to_owned(),
+            field_type: FieldType::Number,
+        },
+        RuntimeRecordExportColumn {
+            field_logical_name: "email".to_owned(),
+            field_type: FieldType::Text,
+        },
+    ];
+    let record = RuntimeRecord::new(
+        "record-1",
+        "contact",
+        serde_json::json!({"name": "Lovelace, \"Ada\"", "score": 9.5}),
+    )
+    .unwrap_or_else(|_| unreachable!());
+
+    assert_eq!(csv_header(&columns), "record_id,name,score,email\r\n");
+    assert_eq!(
+        csv_row(&columns, &record),
+        "record-1,\"Lovelace, \"\"Ada\"\"\",9.5,\r\n"
+    );
+    assert_eq!(
+        json_row(&columns, &record),
+        r#"{"email":null,"name":"Lovelace, \"Ada\"","record_id":"record-1","score":9.5}"#
+    );
+}
@@ -3,23 +3,26 @@ mod ingest;
 
 use axum::Json;
 use axum::extract::{Extension, Path, Query, State};
+use axum::http::StatusCode;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, warn};
 
-use qryvanta_application::RecordListQuery;
+use qryvanta_application::{
+    BackgroundJobKind, BackgroundJobStage, CreateBackgroundJobInput, RecordListQuery,
+};
 use qryvanta_core::{AppError, UserIdentity};
 use qryvanta_infrastructure::begin_tenant_transaction;
 
 use self::filters::{QrywellSearchFilters, plan_filters_for_query};
-use self::ingest::push_records_to_qrywell;
+pub(crate) use self::ingest::push_records_to_qrywell;
 
 use crate::dto::{
-    GenericMessageResponse, QrywellSearchAnalyticsResponse, QrywellSearchClickEventRequest,
-    QrywellSearchHitResponse, QrywellSearchLowRelevanceClickResponse,
-    QrywellSearchRankMetricResponse, QrywellSearchRequest, QrywellSearchResponse,
-    QrywellSearchTopQueryResponse, QrywellSearchZeroClickQueryResponse, QrywellSyncAllResponse,
-    QrywellSyncFailedJobResponse, QrywellSyncHealthResponse, QrywellSyncRequest,
-    QrywellSyncResponse, RuntimeRecordResponse,
+    BackgroundJobResponse, GenericMessageResponse, QrywellSearchAnalyticsResponse,
+    QrywellSearchClickEventRequest, QrywellSearchHitResponse,
+    QrywellSearchLowRelevanceClickResponse, QrywellSearchRankMetricResponse, QrywellSearchRequest,
+    QrywellSearchResponse, QrywellSearchTopQueryResponse, QrywellSearchZeroClickQueryResponse,
+    QrywellSyncAllResponse, QrywellSyncFailedJobResponse, QrywellSyncHealthResponse,
+    QrywellSyncRequest, QrywellSyncResponse, QueueQrywellSyncJobRequest, RuntimeRecordResponse,
 };
 use crate::error::ApiResult;
 use crate::state::AppState;
@@ -458,6 +461,7 @@ pub async fn qrywell_sync_entity_handler(
                 limit: payload.limit.unwrap_or(200),
                 offset: payload.offset.unwrap_or(0),
                 owner_subject: None,
+                include_inactive: true,
             },
         )
         .await?;
@@ -509,6 +513,7 @@ pub async fn qrywell_sync_all_handler(
                     limit: payload.limit.unwrap_or(200),
                     offset: payload.offset.unwrap_or(0),
                     owner_subject: None,
+                    include_inactive: true,
                 },
             )
             .await?;
@@ -551,6 +556,62 @@ pub async fn qrywell_sync_all_handler(
     }))
 }
 
+pub async fn queue_qrywell_sync_job_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Json(payload): Json<QueueQrywellSyncJobRequest>,
+) -> ApiResult<(StatusCode, Json<BackgroundJobResponse>)> {
+    if state.qrywell_api_base_url.is_none() {
+        return Err(
+            AppError::Validation("QRYWELL_API_BASE_URL is not configured".to_owned()).into(),
+        );
+    }
+
+    let available_entity_names = state
+        .metadata_service
+        .list_entities(&user)
+        .await?
+        .into_iter()
+        .map(|entity| entity.logical_name().as_str().to_owned())
+        .collect::<Vec<_>>();
+    let entity_logical_names = match payload.entity_logical_names {
+        Some(requested) if !requested.is_empty() => {
+            if let Some(unknown) = requested
+                .iter()
+                .find(|name| !available_entity_names.contains(name))
+            {
+                return Err(
+                    AppError::Validation(format!("entity '{unknown}' does not exist")).into(),
+                );
+            }
+            requested
+        }
+        _ => available_entity_names,
+    };
+
+    // One stage per entity, so the job reports how far each entity got and a
+    // resumed run skips the entities already pushed.
+    let stages = entity_logical_names
+        .iter()
+        .map(|name| BackgroundJobStage::new(name.as_str(), None))
+        .collect();
+    let job = state
+        .background_job_service
+        .enqueue_job(
+            &user,
+            CreateBackgroundJobInput {
+                kind: BackgroundJobKind::QrywellSync,
+                parameters: serde_json::json!({
+                    "entity_logical_names": entity_logical_names,
+                }),
+                stages,
+            },
+        )
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, Json(BackgroundJobResponse::from(job))))
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub struct QrywellSyncHealthQuery {
     pub failed_limit: Option<usize>,
@@ -7,7 +7,7 @@ use serde_json::Value;
 use crate::dto::RuntimeRecordResponse;
 use crate::state::AppState;
 
-pub(super) async fn push_records_to_qrywell(
+pub(crate) async fn push_records_to_qrywell(
     state: &AppState,
     user: &UserIdentity,
     entity_logical_name: &str,
@@ -217,7 +217,7 @@ struct QrywellIngestRecord {
 }
 
 #[derive(Debug, Deserialize)]
-pub(super) struct QrywellIngestResponse {
-    pub(super) indexed_records: usize,
-    pub(super) indexed_chunks: usize,
+pub(crate) struct QrywellIngestResponse {
+    pub(crate) indexed_records: usize,
+    pub(crate) indexed_chunks: usize,
 }
@@ -1,46 +1,120 @@
 use axum::Json;
-use axum::extract::{Extension, Path, Query, State};
+use axum::extract::{Extension, OriginalUri, Path, Query, State};
 use axum::http::StatusCode;
 
 use qryvanta_core::UserIdentity;
-use qryvanta_domain::{Permission, RegistrationMode};
+use qryvanta_domain::{Permission, RegistrationMode, SubjectType, TeamDefinition};
 use tower_sessions::Session;
 
 use crate::auth::session_helpers::require_recent_step_up;
 use crate::dto::{
-    AssignRoleRequest, AuditIntegrityStatusResponse, AuditLogEntryResponse,
-    AuditPurgeResultResponse, AuditRetentionPolicyResponse, CreateRoleRequest,
-    CreateTemporaryAccessGrantRequest, RemoveRoleAssignmentRequest,
-    RevokeTemporaryAccessGrantRequest, RoleAssignmentResponse, RoleResponse,
-    RuntimeFieldPermissionResponse, SaveRuntimeFieldPermissionsRequest,
-    TemporaryAccessGrantResponse, TenantRegistrationModeResponse,
+    AccessExplanationResponse, AddSecurityTeamMemberRequest, AssignComplianceZoneRequest,
+    AssignRoleRequest, AuditChainAnchorResponse, AuditExportSinkResponse,
+    AuditIntegrityStatusResponse, AuditLogEntryResponse, AuditPurgeResultResponse,
+    AuditRetentionPolicyResponse, ComplianceZoneAssignmentResponse, ComplianceZoneTagResponse,
+    CreateAuditExportSinkRequest, CreateLegalHoldRequest, CreateMfaResetRequest, CreateRoleRequest,
+    CreateSecurityTeamRequest, CreateTemporaryAccessGrantRequest, DataErasureFieldResponse,
+    DataErasureReportResponse, DualControlFieldResponse, EraseSubjectDataRequest,
+    FieldMaskingRuleResponse, LegalHoldResponse, MfaResetRequestResponse,
+    PermissionCatalogGroupResponse, RemoveRoleAssignmentRequest, RevokeTemporaryAccessGrantRequest,
+    RoleAssignmentResponse, RoleResponse, RuntimeFieldPermissionResponse,
+    SaveComplianceZoneTagRequest, SaveDataErasureFieldsRequest, SaveDualControlFieldsRequest,
+    SaveFieldMaskingRulesRequest, SaveRuntimeFieldPermissionsRequest, SecurityTeamMemberResponse,
+    SecurityTeamResponse, ShredTenantEncryptionKeysRequest, ShredTenantEncryptionKeysResponse,
+    TemporaryAccessGrantResponse, TenantEncryptionKeyRequest, TenantEncryptionKeyResponse,
+    TenantRegistrationModeResponse, UpdateAuditExportSinkRequest,
     UpdateAuditRetentionPolicyRequest, UpdateTenantRegistrationModeRequest,
+    UpdateWorkflowConcurrencyPolicyRequest, WorkflowConcurrencyPolicyResponse,
 };
 use crate::error::ApiResult;
+use crate::pagination::{PageWindow, PaginatedJson};
 use crate::state::AppState;
 
+mod access_explain;
 mod audit;
+mod audit_exports;
+mod compliance_zones;
+mod data_erasure;
+mod dual_control;
+mod encryption_keys;
+mod feature_flags;
+mod field_masking;
 mod governance;
+mod legal_holds;
+mod mfa_reset;
 mod roles;
 mod runtime_permissions;
+mod teams;
 mod temporary_access;
 
+pub use access_explain::explain_access_handler;
 pub use audit::{
-    export_audit_log_handler, list_audit_log_handler, purge_audit_log_handler,
-    verify_audit_log_integrity_handler,
+    export_audit_log_handler, list_audit_chain_anchors_handler, list_audit_log_handler,
+    purge_audit_log_handler, queue_audit_log_purge_job_handler, verify_audit_log_integrity_handler,
 };
+pub use audit_exports::{
+    create_audit_export_sink_handler, delete_audit_export_sink_handler,
+    list_audit_export_sinks_handler, update_audit_export_sink_handler,
+};
+pub use compliance_zones::{
+    assign_compliance_zone_handler, list_compliance_zone_assignments_handler,
+    list_compliance_zone_tags_handler, remove_entity_compliance_zone_tag_handler,
+    remove_record_compliance_zone_tag_handler, save_compliance_zone_tag_handler,
+    unassign_compliance_zone_handler,
+};
+#[cfg(test)]
+pub use data_erasure::DataErasureFieldQuery;
+pub use data_erasure::{
+    erase_subject_data_handler, list_data_erasure_fields_handler, save_data_erasure_fields_handler,
+};
+#[cfg(test)]
+pub use dual_control::DualControlFieldQuery;
+pub use dual_control::{list_dual_control_fields_handler, save_dual_control_fields_handler};
+pub use encryption_keys::{
+    list_tenant_encryption_keys_handler, register_tenant_encryption_key_handler,
+    rotate_tenant_encryption_key_handler, shred_tenant_encryption_keys_handler,
+};
+pub use feature_flags::{
+    delete_feature_flag_handler, evaluated_feature_flags_handler, list_feature_flags_handler,
+    save_feature_flag_handler,
+};
+#[cfg(test)]
+pub use field_masking::FieldMaskingRuleQuery;
+pub use field_masking::{list_field_masking_rules_handler, save_field_masking_rules_handler};
 pub use governance::{
     audit_retention_policy_handler, registration_mode_handler,
     update_audit_retention_policy_handler, update_registration_mode_handler,
+    update_workflow_concurrency_policy_handler, workflow_concurrency_policy_handler,
+};
+#[cfg(test)]
+pub use legal_holds::LegalHoldListQuery;
+pub use legal_holds::{
+    create_legal_hold_handler, list_legal_holds_handler, release_legal_hold_handler,
+};
+pub use mfa_reset::{
+    approve_mfa_reset_request_handler, create_mfa_reset_request_handler,
+    list_mfa_reset_requests_handler,
 };
 pub use roles::{
-    assign_role_handler, create_role_handler, list_role_assignments_handler, list_roles_handler,
-    unassign_role_handler,
+    assign_role_handler, create_role_handler, list_permission_catalog_handler,
+    list_role_assignments_handler, list_roles_handler, unassign_role_handler,
 };
 pub use runtime_permissions::{
     list_runtime_field_permissions_handler, save_runtime_field_permissions_handler,
 };
+pub use teams::{
+    add_team_member_handler, create_team_handler, list_team_members_handler, list_teams_handler,
+    remove_team_member_handler,
+};
 pub use temporary_access::{
     create_temporary_access_grant_handler, list_temporary_access_grants_handler,
     revoke_temporary_access_grant_handler,
 };
+
+/// Parses an optional transport subject type, defaulting to individual users.
+fn parse_subject_type(value: Option<&str>) -> qryvanta_core::AppResult<SubjectType> {
+    value
+        .map(str::parse::<SubjectType>)
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
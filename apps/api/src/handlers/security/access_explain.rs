@@ -0,0 +1,43 @@
+use qryvanta_core::AppError;
+use qryvanta_domain::AppEntityAction;
+
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct AccessExplainQuery {
+    pub subject: String,
+    pub entity_logical_name: String,
+    pub action: String,
+}
+
+/// GET /api/security/access-explain - Explain a subject's access to a record action.
+pub async fn explain_access_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<AccessExplainQuery>,
+) -> ApiResult<Json<AccessExplanationResponse>> {
+    let action = match query.action.as_str() {
+        "read" => AppEntityAction::Read,
+        "create" => AppEntityAction::Create,
+        "update" => AppEntityAction::Update,
+        "delete" => AppEntityAction::Delete,
+        other => {
+            return Err(AppError::Validation(format!(
+                "unknown action '{other}', expected read, create, update or delete"
+            ))
+            .into());
+        }
+    };
+
+    let explanation = state
+        .authorization_service
+        .explain_runtime_access(
+            &user,
+            query.subject.as_str(),
+            query.entity_logical_name.as_str(),
+            action,
+        )
+        .await?;
+
+    Ok(Json(AccessExplanationResponse::from(explanation)))
+}
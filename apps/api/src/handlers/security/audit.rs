@@ -1,3 +1,10 @@
+use qryvanta_application::{
+    AuditChainVerificationScope, BackgroundJobKind, BackgroundJobStage, CreateBackgroundJobInput,
+};
+
+use crate::background_job_runner::AUDIT_LOG_PURGE_STAGE;
+use crate::dto::BackgroundJobResponse;
+
 use super::*;
 
 #[derive(Debug, serde::Deserialize)]
@@ -11,15 +18,20 @@ pub struct AuditLogQuery {
 pub async fn list_audit_log_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
+    OriginalUri(uri): OriginalUri,
     Query(query): Query<AuditLogQuery>,
-) -> ApiResult<Json<Vec<AuditLogEntryResponse>>> {
+) -> ApiResult<PaginatedJson<AuditLogEntryResponse>> {
+    let window = PageWindow {
+        limit: query.limit.unwrap_or(50),
+        offset: query.offset.unwrap_or(0),
+    };
     let entries = state
         .security_admin_service
         .list_audit_log(
             &user,
             qryvanta_application::AuditLogQuery {
-                limit: query.limit.unwrap_or(50),
-                offset: query.offset.unwrap_or(0),
+                limit: window.limit,
+                offset: window.offset,
                 action: query.action,
                 subject: query.subject,
             },
@@ -29,7 +41,7 @@ pub async fn list_audit_log_handler(
         .map(AuditLogEntryResponse::from)
         .collect();
 
-    Ok(Json(entries))
+    Ok(PaginatedJson::new(&uri, window, entries))
 }
 
 pub async fn export_audit_log_handler(
@@ -56,18 +68,51 @@ pub async fn export_audit_log_handler(
     Ok(Json(entries))
 }
 
+#[derive(Debug, serde::Deserialize)]
+pub struct AuditChainVerificationQuery {
+    pub scope: Option<String>,
+}
+
 pub async fn verify_audit_log_integrity_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
+    Query(query): Query<AuditChainVerificationQuery>,
 ) -> ApiResult<Json<AuditIntegrityStatusResponse>> {
+    let scope = query
+        .scope
+        .as_deref()
+        .map(AuditChainVerificationScope::parse)
+        .transpose()?
+        .unwrap_or_default();
     let status = state
         .security_admin_service
-        .verify_audit_integrity(&user)
+        .verify_audit_chain(&user, scope)
         .await?;
 
     Ok(Json(AuditIntegrityStatusResponse::from(status)))
 }
 
+#[derive(Debug, serde::Deserialize)]
+pub struct AuditChainAnchorQuery {
+    pub limit: Option<usize>,
+}
+
+pub async fn list_audit_chain_anchors_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<AuditChainAnchorQuery>,
+) -> ApiResult<Json<Vec<AuditChainAnchorResponse>>> {
+    let anchors = state
+        .security_admin_service
+        .list_audit_chain_anchors(&user, query.limit.unwrap_or(100))
+        .await?
+        .into_iter()
+        .map(AuditChainAnchorResponse::from)
+        .collect();
+
+    Ok(Json(anchors))
+}
+
 pub async fn purge_audit_log_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -82,3 +127,34 @@ pub async fn purge_audit_log_handler(
 
     Ok(Json(AuditPurgeResultResponse::from(result)))
 }
+
+pub async fn queue_audit_log_purge_job_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+) -> ApiResult<(StatusCode, Json<BackgroundJobResponse>)> {
+    require_recent_step_up(&session).await?;
+
+    // The cutoff is fixed when the job is queued so a resumed run keeps
+    // deleting the same window even if the retention policy changes meanwhile.
+    let plan = state
+        .security_admin_service
+        .plan_audit_log_purge(&user)
+        .await?;
+    let job = state
+        .background_job_service
+        .enqueue_job(
+            &user,
+            CreateBackgroundJobInput {
+                kind: BackgroundJobKind::AuditLogPurge,
+                parameters: serde_json::json!({
+                    "retention_days": plan.retention_days,
+                    "cutoff": plan.cutoff.to_rfc3339(),
+                }),
+                stages: vec![BackgroundJobStage::new(AUDIT_LOG_PURGE_STAGE, None)],
+            },
+        )
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, Json(BackgroundJobResponse::from(job))))
+}
@@ -0,0 +1,62 @@
+use super::*;
+
+pub async fn list_audit_export_sinks_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<AuditExportSinkResponse>>> {
+    let sinks = state
+        .audit_export_service
+        .list_sinks(&user)
+        .await?
+        .into_iter()
+        .map(AuditExportSinkResponse::from)
+        .collect();
+
+    Ok(Json(sinks))
+}
+
+pub async fn create_audit_export_sink_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Json(payload): Json<CreateAuditExportSinkRequest>,
+) -> ApiResult<(StatusCode, Json<AuditExportSinkResponse>)> {
+    require_recent_step_up(&session).await?;
+
+    let sink = state
+        .audit_export_service
+        .create_sink(&user, payload.try_into()?)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(AuditExportSinkResponse::from(sink)),
+    ))
+}
+
+pub async fn update_audit_export_sink_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(sink_id): Path<String>,
+    Json(payload): Json<UpdateAuditExportSinkRequest>,
+) -> ApiResult<Json<AuditExportSinkResponse>> {
+    let sink = state
+        .audit_export_service
+        .set_sink_enabled(&user, sink_id.as_str(), payload.is_enabled)
+        .await?;
+
+    Ok(Json(AuditExportSinkResponse::from(sink)))
+}
+
+pub async fn delete_audit_export_sink_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(sink_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .audit_export_service
+        .delete_sink(&user, sink_id.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
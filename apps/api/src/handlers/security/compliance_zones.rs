@@ -0,0 +1,124 @@
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ComplianceZoneTagListQuery {
+    pub entity_logical_name: Option<String>,
+}
+
+pub async fn list_compliance_zone_assignments_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<ComplianceZoneAssignmentResponse>>> {
+    let assignments = state
+        .compliance_zone_service
+        .list_assignments(&user)
+        .await?
+        .into_iter()
+        .map(ComplianceZoneAssignmentResponse::from)
+        .collect();
+
+    Ok(Json(assignments))
+}
+
+pub async fn assign_compliance_zone_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Json(payload): Json<AssignComplianceZoneRequest>,
+) -> ApiResult<(StatusCode, Json<ComplianceZoneAssignmentResponse>)> {
+    require_recent_step_up(&session).await?;
+
+    let assignment = state
+        .compliance_zone_service
+        .assign_zone(&user, payload.subject.as_str(), payload.zone.as_str())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ComplianceZoneAssignmentResponse::from(assignment)),
+    ))
+}
+
+pub async fn unassign_compliance_zone_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path((subject, zone)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    require_recent_step_up(&session).await?;
+
+    state
+        .compliance_zone_service
+        .unassign_zone(&user, subject.as_str(), zone.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn list_compliance_zone_tags_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<ComplianceZoneTagListQuery>,
+) -> ApiResult<Json<Vec<ComplianceZoneTagResponse>>> {
+    let tags = state
+        .compliance_zone_service
+        .list_tags(&user, query.entity_logical_name.as_deref())
+        .await?
+        .into_iter()
+        .map(ComplianceZoneTagResponse::from)
+        .collect();
+
+    Ok(Json(tags))
+}
+
+pub async fn save_compliance_zone_tag_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Json(payload): Json<SaveComplianceZoneTagRequest>,
+) -> ApiResult<Json<ComplianceZoneTagResponse>> {
+    require_recent_step_up(&session).await?;
+
+    let tag = state
+        .compliance_zone_service
+        .save_tag(&user, payload.into())
+        .await?;
+
+    Ok(Json(ComplianceZoneTagResponse::from(tag)))
+}
+
+pub async fn remove_entity_compliance_zone_tag_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path(entity_logical_name): Path<String>,
+) -> ApiResult<StatusCode> {
+    require_recent_step_up(&session).await?;
+
+    state
+        .compliance_zone_service
+        .remove_tag(&user, entity_logical_name.as_str(), None)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn remove_record_compliance_zone_tag_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path((entity_logical_name, record_id)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    require_recent_step_up(&session).await?;
+
+    state
+        .compliance_zone_service
+        .remove_tag(
+            &user,
+            entity_logical_name.as_str(),
+            Some(record_id.as_str()),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
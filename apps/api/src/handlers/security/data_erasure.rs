@@ -0,0 +1,64 @@
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct DataErasureFieldQuery {
+    pub entity_logical_name: Option<String>,
+}
+
+pub async fn list_data_erasure_fields_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<DataErasureFieldQuery>,
+) -> ApiResult<Json<Vec<DataErasureFieldResponse>>> {
+    let fields = state
+        .data_erasure_service
+        .list_erasure_fields(&user, query.entity_logical_name.as_deref())
+        .await?
+        .into_iter()
+        .map(DataErasureFieldResponse::from)
+        .collect();
+
+    Ok(Json(fields))
+}
+
+pub async fn save_data_erasure_fields_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path(entity_logical_name): Path<String>,
+    Json(payload): Json<SaveDataErasureFieldsRequest>,
+) -> ApiResult<Json<Vec<DataErasureFieldResponse>>> {
+    require_recent_step_up(&session).await?;
+
+    let fields = state
+        .data_erasure_service
+        .save_erasure_fields(
+            &user,
+            qryvanta_application::SaveDataErasureFieldsInput {
+                entity_logical_name,
+                field_logical_names: payload.field_logical_names,
+            },
+        )
+        .await?
+        .into_iter()
+        .map(DataErasureFieldResponse::from)
+        .collect();
+
+    Ok(Json(fields))
+}
+
+pub async fn erase_subject_data_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Json(payload): Json<EraseSubjectDataRequest>,
+) -> ApiResult<Json<DataErasureReportResponse>> {
+    require_recent_step_up(&session).await?;
+
+    let report = state
+        .data_erasure_service
+        .erase_subject(&user, payload.subject.as_str())
+        .await?;
+
+    Ok(Json(DataErasureReportResponse::from(report)))
+}
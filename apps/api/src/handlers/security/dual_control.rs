@@ -0,0 +1,55 @@
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct DualControlFieldQuery {
+    pub entity_logical_name: Option<String>,
+}
+
+pub async fn list_dual_control_fields_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<DualControlFieldQuery>,
+) -> ApiResult<Json<Vec<DualControlFieldResponse>>> {
+    let fields = state
+        .field_change_approval_service
+        .list_dual_control_fields(&user, query.entity_logical_name.as_deref())
+        .await?
+        .into_iter()
+        .map(DualControlFieldResponse::from)
+        .collect();
+
+    Ok(Json(fields))
+}
+
+pub async fn save_dual_control_fields_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path(entity_logical_name): Path<String>,
+    Json(payload): Json<SaveDualControlFieldsRequest>,
+) -> ApiResult<Json<Vec<DualControlFieldResponse>>> {
+    require_recent_step_up(&session).await?;
+
+    let fields = state
+        .field_change_approval_service
+        .save_dual_control_fields(
+            &user,
+            qryvanta_application::SaveDualControlFieldsInput {
+                entity_logical_name,
+                fields: payload
+                    .fields
+                    .into_iter()
+                    .map(|field| qryvanta_application::DualControlFieldInput {
+                        field_logical_name: field.field_logical_name,
+                        approval_window_hours: field.approval_window_hours,
+                    })
+                    .collect(),
+            },
+        )
+        .await?
+        .into_iter()
+        .map(DualControlFieldResponse::from)
+        .collect();
+
+    Ok(Json(fields))
+}
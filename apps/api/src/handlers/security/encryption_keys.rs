@@ -0,0 +1,67 @@
+use super::*;
+
+pub async fn list_tenant_encryption_keys_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<TenantEncryptionKeyResponse>>> {
+    let keys = state.tenant_encryption_service.list_keys(&user).await?;
+
+    Ok(Json(
+        keys.into_iter()
+            .map(TenantEncryptionKeyResponse::from)
+            .collect(),
+    ))
+}
+
+pub async fn register_tenant_encryption_key_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Json(payload): Json<TenantEncryptionKeyRequest>,
+) -> ApiResult<(StatusCode, Json<TenantEncryptionKeyResponse>)> {
+    require_recent_step_up(&session).await?;
+
+    let key = state
+        .tenant_encryption_service
+        .register_key(&user, payload.secret_reference.as_str())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(TenantEncryptionKeyResponse::from(key)),
+    ))
+}
+
+pub async fn rotate_tenant_encryption_key_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Json(payload): Json<TenantEncryptionKeyRequest>,
+) -> ApiResult<Json<TenantEncryptionKeyResponse>> {
+    require_recent_step_up(&session).await?;
+
+    let key = state
+        .tenant_encryption_service
+        .rotate_key(&user, payload.secret_reference.as_str())
+        .await?;
+
+    Ok(Json(TenantEncryptionKeyResponse::from(key)))
+}
+
+pub async fn shred_tenant_encryption_keys_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Json(payload): Json<ShredTenantEncryptionKeysRequest>,
+) -> ApiResult<Json<ShredTenantEncryptionKeysResponse>> {
+    require_recent_step_up(&session).await?;
+
+    let shredded_versions = state
+        .tenant_encryption_service
+        .shred_keys(&user, payload.confirm_tenant_id.as_str())
+        .await?;
+
+    Ok(Json(ShredTenantEncryptionKeysResponse {
+        shredded_versions,
+    }))
+}
@@ -0,0 +1,83 @@
+use crate::dto::{EvaluatedFeatureFlagResponse, FeatureFlagResponse, SaveFeatureFlagRequest};
+
+use super::*;
+
+pub async fn list_feature_flags_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<FeatureFlagResponse>>> {
+    let flags = state
+        .feature_flag_service
+        .list_feature_flags(&user)
+        .await?
+        .into_iter()
+        .map(FeatureFlagResponse::from)
+        .collect();
+
+    Ok(Json(flags))
+}
+
+pub async fn save_feature_flag_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path(flag_key): Path<String>,
+    Json(payload): Json<SaveFeatureFlagRequest>,
+) -> ApiResult<Json<FeatureFlagResponse>> {
+    require_recent_step_up(&session).await?;
+
+    let flag = state
+        .feature_flag_service
+        .save_feature_flag(
+            &user,
+            qryvanta_application::SaveFeatureFlagInput {
+                flag_key,
+                description: payload.description,
+                enabled: payload.enabled,
+                role_overrides: payload
+                    .role_overrides
+                    .into_iter()
+                    .map(
+                        |role_override| qryvanta_application::FeatureFlagRoleOverride {
+                            role_name: role_override.role_name,
+                            enabled: role_override.enabled,
+                        },
+                    )
+                    .collect(),
+            },
+        )
+        .await?;
+
+    Ok(Json(FeatureFlagResponse::from(flag)))
+}
+
+pub async fn delete_feature_flag_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path(flag_key): Path<String>,
+) -> ApiResult<StatusCode> {
+    require_recent_step_up(&session).await?;
+
+    state
+        .feature_flag_service
+        .delete_feature_flag(&user, flag_key.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn evaluated_feature_flags_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<EvaluatedFeatureFlagResponse>>> {
+    let flags = state
+        .feature_flag_service
+        .evaluate_feature_flags(&user)
+        .await?
+        .into_iter()
+        .map(|(flag_key, enabled)| EvaluatedFeatureFlagResponse { flag_key, enabled })
+        .collect();
+
+    Ok(Json(flags))
+}
@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use qryvanta_domain::{FieldClassification, FieldMaskingMode};
+
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct FieldMaskingRuleQuery {
+    pub role_name: Option<String>,
+}
+
+pub async fn list_field_masking_rules_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<FieldMaskingRuleQuery>,
+) -> ApiResult<Json<Vec<FieldMaskingRuleResponse>>> {
+    let rules = state
+        .field_masking_service
+        .list_masking_rules(&user, query.role_name.as_deref())
+        .await?
+        .into_iter()
+        .map(FieldMaskingRuleResponse::from)
+        .collect();
+
+    Ok(Json(rules))
+}
+
+pub async fn save_field_masking_rules_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path(role_name): Path<String>,
+    Json(payload): Json<SaveFieldMaskingRulesRequest>,
+) -> ApiResult<Json<Vec<FieldMaskingRuleResponse>>> {
+    require_recent_step_up(&session).await?;
+
+    let rules = payload
+        .rules
+        .into_iter()
+        .map(|rule| {
+            Ok(qryvanta_application::FieldMaskingRuleInput {
+                classification: FieldClassification::from_str(rule.classification.as_str())?,
+                masking_mode: FieldMaskingMode::from_str(rule.masking_mode.as_str())?,
+            })
+        })
+        .collect::<Result<Vec<_>, qryvanta_core::AppError>>()?;
+    let rules = state
+        .field_masking_service
+        .save_role_masking_rules(
+            &user,
+            qryvanta_application::SaveFieldMaskingRulesInput { role_name, rules },
+        )
+        .await?
+        .into_iter()
+        .map(FieldMaskingRuleResponse::from)
+        .collect();
+
+    Ok(Json(rules))
+}
@@ -28,6 +28,34 @@ pub async fn update_audit_retention_policy_handler(
     Ok(Json(AuditRetentionPolicyResponse::from(policy)))
 }
 
+pub async fn workflow_concurrency_policy_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<WorkflowConcurrencyPolicyResponse>> {
+    let policy = state
+        .security_admin_service
+        .workflow_concurrency_policy(&user)
+        .await?;
+
+    Ok(Json(WorkflowConcurrencyPolicyResponse::from(policy)))
+}
+
+pub async fn update_workflow_concurrency_policy_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Json(payload): Json<UpdateWorkflowConcurrencyPolicyRequest>,
+) -> ApiResult<Json<WorkflowConcurrencyPolicyResponse>> {
+    require_recent_step_up(&session).await?;
+
+    let policy = state
+        .security_admin_service
+        .update_workflow_concurrency_policy(&user, payload.max_concurrent_jobs)
+        .await?;
+
+    Ok(Json(WorkflowConcurrencyPolicyResponse::from(policy)))
+}
+
 pub async fn registration_mode_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
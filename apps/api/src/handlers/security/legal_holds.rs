@@ -0,0 +1,54 @@
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct LegalHoldListQuery {
+    pub include_released: Option<bool>,
+}
+
+pub async fn list_legal_holds_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<LegalHoldListQuery>,
+) -> ApiResult<Json<Vec<LegalHoldResponse>>> {
+    let holds = state
+        .legal_hold_service
+        .list_holds(&user, query.include_released.unwrap_or(false))
+        .await?
+        .into_iter()
+        .map(LegalHoldResponse::from)
+        .collect();
+
+    Ok(Json(holds))
+}
+
+pub async fn create_legal_hold_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Json(payload): Json<CreateLegalHoldRequest>,
+) -> ApiResult<(StatusCode, Json<LegalHoldResponse>)> {
+    require_recent_step_up(&session).await?;
+
+    let hold = state
+        .legal_hold_service
+        .place_hold(&user, payload.try_into()?)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(LegalHoldResponse::from(hold))))
+}
+
+pub async fn release_legal_hold_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path(hold_id): Path<String>,
+) -> ApiResult<Json<LegalHoldResponse>> {
+    require_recent_step_up(&session).await?;
+
+    let hold = state
+        .legal_hold_service
+        .release_hold(&user, hold_id.as_str())
+        .await?;
+
+    Ok(Json(LegalHoldResponse::from(hold)))
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct MfaResetRequestListQuery {
+    pub pending_only: Option<bool>,
+}
+
+pub async fn create_mfa_reset_request_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Json(payload): Json<CreateMfaResetRequest>,
+) -> ApiResult<(StatusCode, Json<MfaResetRequestResponse>)> {
+    require_recent_step_up(&session).await?;
+
+    let request = state
+        .security_admin_service
+        .request_mfa_reset(
+            &user,
+            qryvanta_application::CreateMfaResetRequestInput {
+                subject: payload.subject,
+                reason: payload.reason,
+            },
+        )
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(MfaResetRequestResponse::from(request)),
+    ))
+}
+
+pub async fn list_mfa_reset_requests_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<MfaResetRequestListQuery>,
+) -> ApiResult<Json<Vec<MfaResetRequestResponse>>> {
+    let requests = state
+        .security_admin_service
+        .list_mfa_reset_requests(&user, query.pending_only.unwrap_or(false))
+        .await?
+        .into_iter()
+        .map(MfaResetRequestResponse::from)
+        .collect();
+
+    Ok(Json(requests))
+}
+
+pub async fn approve_mfa_reset_request_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path(request_id): Path<String>,
+) -> ApiResult<Json<MfaResetRequestResponse>> {
+    require_recent_step_up(&session).await?;
+
+    let request = state
+        .security_admin_service
+        .approve_mfa_reset(&user, request_id.as_str())
+        .await?;
+
+    Ok(Json(MfaResetRequestResponse::from(request)))
+}
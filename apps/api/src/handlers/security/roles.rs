@@ -1,20 +1,44 @@
+use axum::http::HeaderMap;
+use qryvanta_domain::LocalizedComponentType;
+
+use crate::handlers::localization::resolve_localized_labels;
+
 use super::*;
 
 pub async fn list_roles_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
+    headers: HeaderMap,
 ) -> ApiResult<Json<Vec<RoleResponse>>> {
-    let roles = state
-        .security_admin_service
-        .list_roles(&user)
-        .await?
+    let roles = state.security_admin_service.list_roles(&user).await?;
+    let mut localized_labels =
+        resolve_localized_labels(&state, &user, &headers, LocalizedComponentType::Role).await?;
+    let roles = roles
         .into_iter()
-        .map(RoleResponse::from)
+        .map(|role| {
+            let mut response = RoleResponse::from(role);
+            response.localized_label = localized_labels.remove(&response.name);
+            response
+        })
         .collect();
 
     Ok(Json(roles))
 }
 
+pub async fn list_permission_catalog_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<PermissionCatalogGroupResponse>>> {
+    let permissions = state
+        .security_admin_service
+        .list_permission_catalog(&user)
+        .await?;
+
+    Ok(Json(PermissionCatalogGroupResponse::group_catalog(
+        permissions,
+    )))
+}
+
 pub async fn create_role_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -53,7 +77,12 @@ pub async fn assign_role_handler(
 
     state
         .security_admin_service
-        .assign_role(&user, payload.subject.as_str(), payload.role_name.as_str())
+        .assign_role(
+            &user,
+            parse_subject_type(payload.subject_type.as_deref())?,
+            payload.subject.as_str(),
+            payload.role_name.as_str(),
+        )
         .await?;
 
     Ok(StatusCode::NO_CONTENT)
@@ -69,7 +98,12 @@ pub async fn unassign_role_handler(
 
     state
         .security_admin_service
-        .unassign_role(&user, payload.subject.as_str(), payload.role_name.as_str())
+        .unassign_role(
+            &user,
+            parse_subject_type(payload.subject_type.as_deref())?,
+            payload.subject.as_str(),
+            payload.role_name.as_str(),
+        )
         .await?;
 
     Ok(StatusCode::NO_CONTENT)
@@ -19,6 +19,7 @@ pub async fn save_runtime_field_permissions_handler(
         .save_runtime_field_permissions(
             &user,
             qryvanta_application::SaveRuntimeFieldPermissionsInput {
+                subject_type: parse_subject_type(payload.subject_type.as_deref())?,
                 subject: payload.subject,
                 entity_logical_name: payload.entity_logical_name,
                 fields: payload
@@ -0,0 +1,85 @@
+use super::*;
+
+pub async fn list_teams_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<SecurityTeamResponse>>> {
+    let teams = state
+        .security_admin_service
+        .list_teams(&user)
+        .await?
+        .into_iter()
+        .map(SecurityTeamResponse::from)
+        .collect();
+
+    Ok(Json(teams))
+}
+
+pub async fn create_team_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Json(payload): Json<CreateSecurityTeamRequest>,
+) -> ApiResult<(StatusCode, Json<SecurityTeamResponse>)> {
+    require_recent_step_up(&session).await?;
+
+    let team = TeamDefinition::new(payload.name, payload.description)?;
+    let team = state
+        .security_admin_service
+        .create_team(&user, team)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(SecurityTeamResponse::from(team))))
+}
+
+pub async fn list_team_members_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(team_name): Path<String>,
+) -> ApiResult<Json<Vec<SecurityTeamMemberResponse>>> {
+    let members = state
+        .security_admin_service
+        .list_team_members(&user, team_name.as_str())
+        .await?
+        .into_iter()
+        .map(SecurityTeamMemberResponse::from)
+        .collect();
+
+    Ok(Json(members))
+}
+
+pub async fn add_team_member_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path(team_name): Path<String>,
+    Json(payload): Json<AddSecurityTeamMemberRequest>,
+) -> ApiResult<(StatusCode, Json<SecurityTeamMemberResponse>)> {
+    require_recent_step_up(&session).await?;
+
+    let member = state
+        .security_admin_service
+        .add_team_member(&user, team_name.as_str(), payload.subject.as_str())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(SecurityTeamMemberResponse::from(member)),
+    ))
+}
+
+pub async fn remove_team_member_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    session: Session,
+    Path((team_name, subject)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    require_recent_step_up(&session).await?;
+
+    state
+        .security_admin_service
+        .remove_team_member(&user, team_name.as_str(), subject.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -44,8 +44,13 @@ pub async fn create_temporary_access_grant_handler(
 pub async fn list_temporary_access_grants_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
+    OriginalUri(uri): OriginalUri,
     Query(query): Query<TemporaryAccessGrantListQuery>,
-) -> ApiResult<Json<Vec<TemporaryAccessGrantResponse>>> {
+) -> ApiResult<PaginatedJson<TemporaryAccessGrantResponse>> {
+    let window = PageWindow {
+        limit: query.limit.unwrap_or(50),
+        offset: query.offset.unwrap_or(0),
+    };
     let grants = state
         .security_admin_service
         .list_temporary_access_grants(
@@ -53,8 +58,8 @@ pub async fn list_temporary_access_grants_handler(
             qryvanta_application::TemporaryAccessGrantQuery {
                 subject: query.subject,
                 active_only: query.active_only.unwrap_or(false),
-                limit: query.limit.unwrap_or(50),
-                offset: query.offset.unwrap_or(0),
+                limit: window.limit,
+                offset: window.offset,
             },
         )
         .await?
@@ -62,7 +67,7 @@ pub async fn list_temporary_access_grants_handler(
         .map(TemporaryAccessGrantResponse::from)
         .collect();
 
-    Ok(Json(grants))
+    Ok(PaginatedJson::new(&uri, window, grants))
 }
 
 pub async fn revoke_temporary_access_grant_handler(
@@ -0,0 +1,81 @@
+use axum::Json;
+use axum::extract::{Extension, Path, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use uuid::Uuid;
+
+use qryvanta_core::{AppError, TenantId, UserIdentity};
+
+use crate::dto::{BackgroundJobResponse, TenantDataExportLinkResponse};
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+/// POST /api/tenant/data-export - Queue an export of the caller's tenant.
+///
+/// Progress is reported by the background job endpoints; the archive is
+/// fetched through a download link once the job completes.
+pub async fn request_tenant_data_export_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<(StatusCode, Json<BackgroundJobResponse>)> {
+    let entity_logical_names = state
+        .metadata_service
+        .list_published_entity_logical_names_unchecked(&user)
+        .await?;
+    let job = state
+        .tenant_data_export_service
+        .request_export(&user, entity_logical_names)
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, Json(BackgroundJobResponse::from(job))))
+}
+
+/// POST /api/tenant/data-export/{job_id}/links - Issue an expiring download link.
+pub async fn create_tenant_data_export_link_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(job_id): Path<String>,
+) -> ApiResult<(StatusCode, Json<TenantDataExportLinkResponse>)> {
+    let created = state
+        .tenant_data_export_service
+        .create_download_link(&user, job_id.as_str())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(TenantDataExportLinkResponse::from_created(
+            user.tenant_id().to_string().as_str(),
+            created,
+        )),
+    ))
+}
+
+/// GET /api/public/tenant-exports/{tenant_id}/{token} - Download an archive through its link.
+pub async fn download_tenant_data_export_handler(
+    State(state): State<AppState>,
+    Path((tenant_id, token)): Path<(String, String)>,
+) -> ApiResult<Response> {
+    let tenant_id = Uuid::parse_str(tenant_id.as_str())
+        .map(TenantId::from_uuid)
+        .map_err(|_| AppError::NotFound("tenant data export link does not exist".to_owned()))?;
+    let archive = state
+        .tenant_data_export_service
+        .download_archive(tenant_id, token.as_str())
+        .await?;
+    let body = serde_json::to_vec(&archive.document).map_err(|error| {
+        AppError::Internal(format!("failed to encode tenant data archive: {error}"))
+    })?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/json".to_owned()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", archive.file_name),
+            ),
+            (header::CACHE_CONTROL, "no-store".to_owned()),
+        ],
+        body,
+    )
+        .into_response())
+}
@@ -6,7 +6,7 @@ use qryvanta_application::{
     RuntimeRecordWorkflowEventDrainResult, WorkflowClaimPartition, WorkflowWorkerHeartbeatInput,
 };
 use qryvanta_core::AppError;
-use qryvanta_domain::{WorkflowStep, WorkflowTrigger};
+use qryvanta_domain::{WorkflowRunPriority, WorkflowStep, WorkflowTrigger};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -12,6 +12,8 @@ pub struct ClaimWorkflowJobsRequest {
 #[derive(Debug, Serialize)]
 pub struct ClaimedWorkflowJobsResponse {
     pub jobs: Vec<ClaimedWorkflowJobResponse>,
+    pub advised_limit: usize,
+    pub retry_after_ms: u64,
 }
 
 #[derive(Debug, Serialize)]
@@ -29,6 +31,9 @@ pub struct ClaimedWorkflowJobResponse {
     pub workflow_max_attempts: u16,
     pub workflow_is_enabled: bool,
     pub trigger_payload: Value,
+    pub continuation: Option<qryvanta_application::WorkflowRunContinuation>,
+    pub trace_context: Option<String>,
+    pub priority: WorkflowRunPriority,
 }
 
 pub async fn claim_workflow_jobs_handler(
@@ -36,14 +41,10 @@ pub async fn claim_workflow_jobs_handler(
     Extension(worker): Extension<WorkerIdentity>,
     Json(payload): Json<ClaimWorkflowJobsRequest>,
 ) -> ApiResult<Json<ClaimedWorkflowJobsResponse>> {
-    let requested_limit = payload
-        .limit
-        .unwrap_or(state.workflow_worker_max_claim_limit);
     let requested_lease_seconds = payload
         .lease_seconds
         .unwrap_or(state.workflow_worker_default_lease_seconds);
 
-    let effective_limit = requested_limit.clamp(1, state.workflow_worker_max_claim_limit);
     let effective_lease_seconds = requested_lease_seconds.max(1);
     let partition = parse_worker_partition(
         payload.partition_count,
@@ -79,6 +80,11 @@ pub async fn claim_workflow_jobs_handler(
         }
     };
 
+    let policy = state.workflow_claim_backpressure_policy;
+    let advice = policy.advise(state.workflow_claim_load_signal(), tenant_filter.is_some());
+    let requested_limit = payload.limit.unwrap_or(policy.max_claim_limit());
+    let effective_limit = policy.effective_limit(requested_limit, advice);
+
     let jobs = state
         .workflow_service
         .claim_jobs_for_worker(
@@ -104,10 +110,17 @@ pub async fn claim_workflow_jobs_handler(
             workflow_max_attempts: job.workflow.max_attempts(),
             workflow_is_enabled: job.workflow.is_enabled(),
             trigger_payload: job.trigger_payload,
+            continuation: job.continuation,
+            trace_context: job.trace_context,
+            priority: job.priority,
         })
         .collect();
 
-    Ok(Json(ClaimedWorkflowJobsResponse { jobs }))
+    Ok(Json(ClaimedWorkflowJobsResponse {
+        jobs,
+        advised_limit: advice.advised_limit,
+        retry_after_ms: advice.retry_after_ms,
+    }))
 }
 
 pub(super) fn parse_tenant_id(value: &str) -> Result<qryvanta_core::TenantId, AppError> {
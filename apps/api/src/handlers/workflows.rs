@@ -1,23 +1,44 @@
 use std::collections::HashMap;
 
 use axum::Json;
-use axum::extract::{Extension, Path, Query, State};
+use axum::body::Bytes;
+use axum::extract::{Extension, OriginalUri, Path, Query, State};
 use axum::http::HeaderMap;
 use axum::http::StatusCode;
-use qryvanta_core::UserIdentity;
+use qryvanta_application::{
+    StartWorkflowBulkExecutionInput, WORKFLOW_BULK_EXECUTION_MAX_RECORDS,
+    WorkflowInboundWebhookDelivery,
+};
+use qryvanta_core::{AppError, UserIdentity};
+use qryvanta_domain::{LocalizedComponentType, RuntimeRecord, WorkflowCredential};
 use serde_json::{Value, json};
 use tower_sessions::Session;
 use uuid::Uuid;
 
 use crate::auth::session_helpers::require_recent_step_up;
 use crate::dto::{
-    DispatchScheduleTriggerRequest, ExecuteWorkflowRequest, RetryWorkflowStepRequest,
-    RetryWorkflowStepStrategyDto, SaveWorkflowRequest, WorkflowResponse,
+    ConfigureWorkflowInboundWebhookRequest, CreatedWorkflowInboundWebhookResponse,
+    DispatchScheduleTriggerRequest, ExecuteWorkflowRequest, FailWorkflowJobRequest,
+    QueryRuntimeRecordsRequest, RetryWorkflowStepRequest, RetryWorkflowStepStrategyDto,
+    SaveWorkflowCredentialRequest, SaveWorkflowRequest, WorkflowBulkExecutionPreviewResponse,
+    WorkflowBulkExecutionRequest, WorkflowBulkExecutionResponse, WorkflowCredentialResponse,
+    WorkflowInboundWebhookResponse, WorkflowQueueStatsResponse, WorkflowResponse,
     WorkflowRunAttemptResponse, WorkflowRunReplayResponse, WorkflowRunResponse,
+    WorkflowStuckJobResponse, WorkflowWorkerLeaseResponse,
 };
 use crate::error::ApiResult;
+use crate::handlers::localization::resolve_localized_labels;
+use crate::handlers::runtime::runtime_record_query_from_request;
+use crate::idempotency_key::idempotency_key_header;
+use crate::pagination::{PageWindow, PaginatedJson};
 use crate::state::AppState;
 
+/// Header carrying the unix timestamp an inbound webhook delivery was signed at.
+pub const INBOUND_WEBHOOK_TIMESTAMP_HEADER: &str = "x-qryvanta-timestamp";
+
+/// Header carrying the `sha256=<hex>` inbound webhook signature.
+pub const INBOUND_WEBHOOK_SIGNATURE_HEADER: &str = "x-qryvanta-signature";
+
 #[derive(Debug, serde::Deserialize)]
 pub struct WorkflowRunListQueryRequest {
     pub workflow_logical_name: Option<String>,
@@ -25,16 +46,31 @@ pub struct WorkflowRunListQueryRequest {
     pub offset: Option<usize>,
 }
 
+#[derive(Debug, serde::Deserialize)]
+pub struct WorkflowQueueStatsQueryRequest {
+    pub active_window_seconds: Option<u32>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct WorkflowStuckJobListQueryRequest {
+    pub limit: Option<usize>,
+}
+
 pub async fn list_workflows_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
+    headers: HeaderMap,
 ) -> ApiResult<Json<Vec<WorkflowResponse>>> {
-    let workflows = state
-        .workflow_service
-        .list_workflows(&user)
-        .await?
+    let workflows = state.workflow_service.list_workflows(&user).await?;
+    let mut localized_labels =
+        resolve_localized_labels(&state, &user, &headers, LocalizedComponentType::Workflow).await?;
+    let workflows = workflows
         .into_iter()
-        .map(WorkflowResponse::from)
+        .map(|workflow| {
+            let mut response = WorkflowResponse::from(workflow);
+            response.localized_label = localized_labels.remove(&response.logical_name);
+            response
+        })
         .collect();
 
     Ok(Json(workflows))
@@ -101,21 +137,218 @@ pub async fn execute_workflow_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
     Path(workflow_logical_name): Path<String>,
+    headers: HeaderMap,
     Json(payload): Json<ExecuteWorkflowRequest>,
 ) -> ApiResult<Json<WorkflowRunResponse>> {
     let _burst_permit = state.try_acquire_workflow_burst_permit()?;
     let run = state
         .workflow_service
-        .execute_workflow(
+        .execute_workflow_with_idempotency_key(
             &user,
             workflow_logical_name.as_str(),
             payload.trigger_payload,
+            idempotency_key_header(&headers)?,
         )
         .await?;
 
     Ok(Json(WorkflowRunResponse::from(run)))
 }
 
+pub async fn preview_workflow_bulk_execution_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(workflow_logical_name): Path<String>,
+    Json(payload): Json<WorkflowBulkExecutionRequest>,
+) -> ApiResult<Json<WorkflowBulkExecutionPreviewResponse>> {
+    state
+        .workflow_service
+        .ensure_bulk_execution_target(
+            &user,
+            workflow_logical_name.as_str(),
+            payload.entity_logical_name.as_str(),
+        )
+        .await?;
+    let records = bulk_execution_records(
+        &state,
+        &user,
+        payload.entity_logical_name.as_str(),
+        payload.view_logical_name.as_deref(),
+        payload.query,
+    )
+    .await?;
+
+    Ok(Json(WorkflowBulkExecutionPreviewResponse {
+        workflow_logical_name,
+        entity_logical_name: payload.entity_logical_name,
+        view_logical_name: payload.view_logical_name,
+        record_count: records.len(),
+        max_records: WORKFLOW_BULK_EXECUTION_MAX_RECORDS,
+    }))
+}
+
+pub async fn start_workflow_bulk_execution_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(workflow_logical_name): Path<String>,
+    Json(payload): Json<WorkflowBulkExecutionRequest>,
+) -> ApiResult<(StatusCode, Json<WorkflowBulkExecutionResponse>)> {
+    let _burst_permit = state.try_acquire_workflow_burst_permit()?;
+    let expected_record_count = payload.expected_record_count.ok_or_else(|| {
+        AppError::Validation(
+            "expected_record_count is required to start a bulk workflow execution".to_owned(),
+        )
+    })?;
+    state
+        .workflow_service
+        .ensure_bulk_execution_target(
+            &user,
+            workflow_logical_name.as_str(),
+            payload.entity_logical_name.as_str(),
+        )
+        .await?;
+    let records = bulk_execution_records(
+        &state,
+        &user,
+        payload.entity_logical_name.as_str(),
+        payload.view_logical_name.as_deref(),
+        payload.query,
+    )
+    .await?;
+
+    let report = state
+        .workflow_service
+        .start_bulk_execution(
+            &user,
+            StartWorkflowBulkExecutionInput {
+                workflow_logical_name,
+                entity_logical_name: payload.entity_logical_name,
+                view_logical_name: payload.view_logical_name,
+                records,
+                expected_record_count,
+            },
+        )
+        .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(WorkflowBulkExecutionResponse::from(report)),
+    ))
+}
+
+pub async fn get_workflow_bulk_execution_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(bulk_execution_id): Path<String>,
+) -> ApiResult<Json<WorkflowBulkExecutionResponse>> {
+    let report = state
+        .workflow_service
+        .bulk_execution_report(&user, bulk_execution_id.as_str())
+        .await?;
+
+    Ok(Json(WorkflowBulkExecutionResponse::from(report)))
+}
+
+pub async fn configure_workflow_inbound_webhook_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(workflow_logical_name): Path<String>,
+    Json(payload): Json<ConfigureWorkflowInboundWebhookRequest>,
+) -> ApiResult<Json<CreatedWorkflowInboundWebhookResponse>> {
+    let created = state
+        .workflow_service
+        .configure_inbound_webhook(
+            &user,
+            workflow_logical_name.as_str(),
+            payload.payload_schema,
+        )
+        .await?;
+
+    Ok(Json(CreatedWorkflowInboundWebhookResponse::new(
+        user.tenant_id(),
+        created,
+    )))
+}
+
+pub async fn get_workflow_inbound_webhook_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(workflow_logical_name): Path<String>,
+) -> ApiResult<Json<WorkflowInboundWebhookResponse>> {
+    let webhook = state
+        .workflow_service
+        .get_inbound_webhook(&user, workflow_logical_name.as_str())
+        .await?
+        .ok_or_else(|| {
+            qryvanta_core::AppError::NotFound(format!(
+                "workflow '{}' has no inbound webhook",
+                workflow_logical_name
+            ))
+        })?;
+
+    Ok(Json(WorkflowInboundWebhookResponse::from(webhook)))
+}
+
+pub async fn revoke_workflow_inbound_webhook_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(workflow_logical_name): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .workflow_service
+        .revoke_inbound_webhook(&user, workflow_logical_name.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn list_workflow_credentials_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+) -> ApiResult<Json<Vec<WorkflowCredentialResponse>>> {
+    let credentials = state
+        .workflow_service
+        .list_workflow_credentials(&user)
+        .await?
+        .into_iter()
+        .map(WorkflowCredentialResponse::from)
+        .collect();
+
+    Ok(Json(credentials))
+}
+
+pub async fn save_workflow_credential_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(credential_logical_name): Path<String>,
+    Json(payload): Json<SaveWorkflowCredentialRequest>,
+) -> ApiResult<Json<WorkflowCredentialResponse>> {
+    let credential = WorkflowCredential::new(
+        credential_logical_name,
+        payload.display_name,
+        payload.auth.into(),
+        payload.allowed_hosts,
+    )?;
+    let record = state
+        .workflow_service
+        .save_workflow_credential(&user, credential)
+        .await?;
+
+    Ok(Json(WorkflowCredentialResponse::from(record)))
+}
+
+pub async fn delete_workflow_credential_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(credential_logical_name): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .workflow_service
+        .delete_workflow_credential(&user, credential_logical_name.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 pub async fn dispatch_schedule_trigger_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
@@ -161,6 +394,44 @@ pub async fn ingest_webhook_trigger_handler(
     Ok((StatusCode::OK, Json(dispatched)))
 }
 
+pub async fn receive_workflow_inbound_webhook_handler(
+    State(state): State<AppState>,
+    Path((tenant_id, token)): Path<(String, String)>,
+    Query(query): Query<HashMap<String, String>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> ApiResult<(StatusCode, Json<WorkflowRunResponse>)> {
+    let tenant_uuid = Uuid::parse_str(tenant_id.as_str()).map_err(|error| {
+        qryvanta_core::AppError::Validation(format!("tenant_id must be a valid UUID: {error}"))
+    })?;
+    let header_value = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned)
+    };
+
+    let run = state
+        .workflow_service
+        .receive_inbound_webhook(
+            qryvanta_core::TenantId::from_uuid(tenant_uuid),
+            token.as_str(),
+            WorkflowInboundWebhookDelivery {
+                timestamp: header_value(INBOUND_WEBHOOK_TIMESTAMP_HEADER),
+                signature: header_value(INBOUND_WEBHOOK_SIGNATURE_HEADER),
+                body: body.to_vec(),
+                request: json!({
+                    "method": "POST",
+                    "headers": header_map_to_json(&headers),
+                    "query": query,
+                }),
+            },
+        )
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, Json(WorkflowRunResponse::from(run))))
+}
+
 pub async fn ingest_form_trigger_handler(
     State(state): State<AppState>,
     Path((tenant_id, form_key)): Path<(String, String)>,
@@ -257,16 +528,21 @@ pub async fn ingest_approval_trigger_handler(
 pub async fn list_workflow_runs_handler(
     State(state): State<AppState>,
     Extension(user): Extension<UserIdentity>,
+    OriginalUri(uri): OriginalUri,
     Query(query): Query<WorkflowRunListQueryRequest>,
-) -> ApiResult<Json<Vec<WorkflowRunResponse>>> {
+) -> ApiResult<PaginatedJson<WorkflowRunResponse>> {
+    let window = PageWindow {
+        limit: query.limit.unwrap_or(50),
+        offset: query.offset.unwrap_or(0),
+    };
     let runs = state
         .workflow_service
         .list_runs(
             &user,
             qryvanta_application::WorkflowRunListQuery {
                 workflow_logical_name: query.workflow_logical_name,
-                limit: query.limit.unwrap_or(50),
-                offset: query.offset.unwrap_or(0),
+                limit: window.limit,
+                offset: window.offset,
             },
         )
         .await?
@@ -274,7 +550,79 @@ pub async fn list_workflow_runs_handler(
         .map(WorkflowRunResponse::from)
         .collect();
 
-    Ok(Json(runs))
+    Ok(PaginatedJson::new(&uri, window, runs))
+}
+
+pub async fn workflow_queue_stats_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<WorkflowQueueStatsQueryRequest>,
+) -> ApiResult<Json<WorkflowQueueStatsResponse>> {
+    let snapshot = state
+        .workflow_service
+        .tenant_queue_stats(&user, query.active_window_seconds.unwrap_or(120).max(1))
+        .await?;
+
+    Ok(Json(WorkflowQueueStatsResponse::from_snapshot(
+        snapshot,
+        state.workflow_service.queue_stats_cache_ttl_seconds(),
+    )))
+}
+
+pub async fn list_workflow_stuck_jobs_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Query(query): Query<WorkflowStuckJobListQueryRequest>,
+) -> ApiResult<Json<Vec<WorkflowStuckJobResponse>>> {
+    let jobs = state
+        .workflow_service
+        .list_stuck_jobs(&user, query.limit)
+        .await?
+        .into_iter()
+        .map(WorkflowStuckJobResponse::from)
+        .collect();
+
+    Ok(Json(jobs))
+}
+
+pub async fn release_workflow_job_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(job_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .workflow_service
+        .release_stuck_job(&user, job_id.as_str())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn fail_workflow_job_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(job_id): Path<String>,
+    Json(payload): Json<FailWorkflowJobRequest>,
+) -> ApiResult<Json<WorkflowRunResponse>> {
+    let run = state
+        .workflow_service
+        .fail_stuck_job(&user, job_id.as_str(), payload.reason.as_deref())
+        .await?;
+
+    Ok(Json(WorkflowRunResponse::from(run)))
+}
+
+pub async fn get_workflow_worker_lease_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<UserIdentity>,
+    Path(scope_key): Path<String>,
+) -> ApiResult<Json<WorkflowWorkerLeaseResponse>> {
+    let ownership = state
+        .workflow_service
+        .inspect_worker_lease(&user, scope_key.as_str())
+        .await?;
+
+    Ok(Json(WorkflowWorkerLeaseResponse::new(scope_key, ownership)))
 }
 
 pub async fn list_workflow_run_attempts_handler(
@@ -330,6 +678,83 @@ pub async fn retry_workflow_run_step_handler(
     Ok(Json(WorkflowRunResponse::from(run)))
 }
 
+/// Resolves the records selected by a saved view or a runtime record query.
+///
+/// Selections larger than the bulk execution limit are rejected rather than
+/// truncated.
+async fn bulk_execution_records(
+    state: &AppState,
+    user: &UserIdentity,
+    entity_logical_name: &str,
+    view_logical_name: Option<&str>,
+    query: Option<QueryRuntimeRecordsRequest>,
+) -> Result<Vec<RuntimeRecord>, AppError> {
+    let limit_error = || {
+        AppError::Validation(format!(
+            "bulk workflow execution selection exceeds {WORKFLOW_BULK_EXECUTION_MAX_RECORDS} records"
+        ))
+    };
+    let mut records = Vec::new();
+
+    if let Some(view_logical_name) = view_logical_name {
+        if query.is_some() {
+            return Err(AppError::Validation(
+                "bulk workflow execution selects records by a view or a query, not both".to_owned(),
+            ));
+        }
+        let mut export = state
+            .metadata_service
+            .prepare_runtime_record_export(user, entity_logical_name, view_logical_name)
+            .await?;
+        while let Some(page) = export.next_page().await? {
+            records.extend(page);
+            if records.len() > WORKFLOW_BULK_EXECUTION_MAX_RECORDS {
+                return Err(limit_error());
+            }
+        }
+        return Ok(records);
+    }
+
+    let payload = query.unwrap_or(QueryRuntimeRecordsRequest {
+        limit: None,
+        offset: None,
+        logical_mode: None,
+        where_clause: None,
+        conditions: None,
+        link_entities: None,
+        sort: None,
+        filters: None,
+        include_inactive: None,
+    });
+    let page_size = payload.limit.unwrap_or(state.runtime_query_max_limit);
+    let mut query = runtime_record_query_from_request(
+        &state.metadata_service,
+        user,
+        entity_logical_name,
+        QueryRuntimeRecordsRequest {
+            limit: Some(page_size),
+            ..payload
+        },
+        state.runtime_query_max_limit,
+    )
+    .await?;
+    loop {
+        let page = state
+            .metadata_service
+            .query_runtime_records(user, entity_logical_name, query.clone())
+            .await?;
+        let page_len = page.len();
+        records.extend(page);
+        if records.len() > WORKFLOW_BULK_EXECUTION_MAX_RECORDS {
+            return Err(limit_error());
+        }
+        if page_len < query.limit || page_len == 0 {
+            return Ok(records);
+        }
+        query.offset += page_len;
+    }
+}
+
 fn header_map_to_json(headers: &HeaderMap) -> serde_json::Map<String, Value> {
     let mut values = serde_json::Map::new();
     for (name, value) in headers {
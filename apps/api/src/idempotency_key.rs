@@ -0,0 +1,63 @@
+//! `Idempotency-Key` request header support.
+//!
+//! Clients that retry a create after a timeout send the same key again; the
+//! service then returns the resource created by the first attempt instead of
+//! writing a duplicate.
+
+use axum::http::HeaderMap;
+use qryvanta_core::AppError;
+
+use crate::error::ApiResult;
+
+/// Request header carrying the client-chosen idempotency key.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Returns the trimmed `Idempotency-Key` header, if the request sent one.
+///
+/// Key format is validated by the services that consume it.
+pub fn idempotency_key_header(headers: &HeaderMap) -> ApiResult<Option<&str>> {
+    let Some(value) = headers.get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(None);
+    };
+
+    let value = value.to_str().map_err(|_| {
+        AppError::Validation(
+            "idempotency key must contain only visible ASCII characters".to_owned(),
+        )
+    })?;
+
+    Ok(Some(value.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, HeaderValue};
+
+    use super::{IDEMPOTENCY_KEY_HEADER, idempotency_key_header};
+
+    #[test]
+    fn header_value_is_trimmed() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            IDEMPOTENCY_KEY_HEADER,
+            HeaderValue::from_static(" order-42 "),
+        );
+
+        assert_eq!(
+            idempotency_key_header(&headers).ok(),
+            Some(Some("order-42"))
+        );
+        assert_eq!(idempotency_key_header(&HeaderMap::new()).ok(), Some(None));
+    }
+
+    #[test]
+    fn non_ascii_header_value_is_rejected() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            IDEMPOTENCY_KEY_HEADER,
+            HeaderValue::from_bytes(b"order-\xff").unwrap_or_else(|_| unreachable!()),
+        );
+
+        assert!(idempotency_key_header(&headers).is_err());
+    }
+}
@@ -5,15 +5,28 @@
 mod api_config;
 mod api_router;
 mod api_services;
+mod api_versioning;
+mod audit_export_shipper;
+mod audit_outbox_relay;
 mod auth;
+mod background_job_runner;
+mod data_validation_scheduler;
 mod dev_seed;
+mod doctor;
 mod dto;
 mod error;
 mod handlers;
+mod idempotency_key;
 mod middleware;
 mod observability;
+mod pagination;
 mod qrywell_sync;
+mod rate_limit_headers;
+mod record_import_scheduler;
 mod redis_session_store;
+mod release_advisory;
+mod report_subscription_dispatcher;
+mod reporting_projector;
 mod state;
 
 use qryvanta_core::AppError;
@@ -25,15 +38,22 @@ use crate::api_config::SessionStoreBackend;
 #[tokio::main]
 async fn main() -> Result<(), AppError> {
     dotenvy::dotenv().ok();
-    api_config::init_tracing();
+    let _tracing_guard = api_config::init_tracing()?;
     let args = std::env::args().collect::<Vec<_>>();
     let command = args.get(1).map(String::as_str);
+    if command == Some("doctor") {
+        return doctor::run().await;
+    }
 
     let config = api_config::ApiConfig::load()?;
     if command == Some("print-secret-fingerprints") {
         print_secret_fingerprints(&config)?;
         return Ok(());
     }
+    info!(
+        environment = config.environment.as_str(),
+        "runtime environment configured"
+    );
     info!(
         physical_isolation_mode = %config.physical_isolation_mode.as_str(),
         physical_isolation_tenant_id = config.physical_isolation_tenant_id.map(|value| value.to_string()),
@@ -64,7 +84,15 @@ async fn main() -> Result<(), AppError> {
     }
 
     let app_state = api_services::build_app_state(pool.clone(), &config)?;
+    auth::register_configured_bootstrap_token(&app_state, &config).await?;
+    audit_export_shipper::spawn_audit_export_shipper(app_state.clone());
+    audit_outbox_relay::spawn_audit_outbox_relay(app_state.clone());
+    background_job_runner::spawn_background_job_runner(app_state.clone());
+    data_validation_scheduler::spawn_data_validation_scheduler(app_state.clone());
     qrywell_sync::spawn_qrywell_sync_worker(app_state.clone());
+    record_import_scheduler::spawn_record_import_scheduler(app_state.clone());
+    report_subscription_dispatcher::spawn_report_subscription_dispatcher(app_state.clone());
+    reporting_projector::spawn_reporting_projector(app_state.clone());
     let app = match config.session_store_backend {
         SessionStoreBackend::Postgres => {
             let session_layer =
@@ -7,15 +7,17 @@ use axum::http::{HeaderValue, Method, header};
 use axum::middleware::Next;
 use axum::response::Response;
 use ipnet::IpNet;
-use qryvanta_application::{RateLimitRule, UserRecord};
+use qryvanta_application::{RateLimitRule, TokenBucketRule, UserRecord};
 use qryvanta_core::{AppError, UserIdentity};
+use qryvanta_infrastructure::continue_trace;
 use tower_sessions::Session;
-use tracing::warn;
+use tracing::{Instrument, info_span, warn};
 use uuid::Uuid;
 
 use crate::auth::session_helpers::constant_time_eq;
 use crate::auth::{SESSION_CREATED_AT_KEY, SESSION_USER_KEY};
 use crate::error::ApiResult;
+use crate::rate_limit_headers::{enforce_rate_limit, enforce_token_bucket};
 use crate::state::AppState;
 
 /// Maximum absolute session lifetime (8 hours).
@@ -23,6 +25,7 @@ use crate::state::AppState;
 /// of activity to limit the window for session hijacking.
 const ABSOLUTE_SESSION_TIMEOUT_SECONDS: i64 = 8 * 60 * 60;
 const TRACE_ID_HEADER: &str = "x-trace-id";
+const TRACEPARENT_HEADER: &str = "traceparent";
 
 #[derive(Debug, Clone)]
 pub struct RequestTraceContext {
@@ -48,6 +51,18 @@ impl WorkerIdentity {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct OperatorIdentity {
+    operator_id: String,
+}
+
+impl OperatorIdentity {
+    #[must_use]
+    pub fn operator_id(&self) -> &str {
+        self.operator_id.as_str()
+    }
+}
+
 pub async fn trace_and_observe(
     State(state): State<AppState>,
     mut request: Request,
@@ -73,10 +88,23 @@ pub async fn trace_and_observe(
 
     let method = request.method().clone();
     let path = request.uri().path().to_owned();
+    let request_span = info_span!(
+        "http.request",
+        trace_id = %trace_id,
+        method = %method,
+        path = %path
+    );
+    if let Some(traceparent) = request
+        .headers()
+        .get(TRACEPARENT_HEADER)
+        .and_then(|value| value.to_str().ok())
+    {
+        continue_trace(&request_span, traceparent);
+    }
 
     state.observability_metrics.on_request_start();
     let started = Instant::now();
-    let mut response = next.run(request).await;
+    let mut response = next.run(request).instrument(request_span).await;
     let elapsed_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
 
     state.observability_metrics.on_request_end(
@@ -124,10 +152,13 @@ fn write_security_headers(headers: &mut axum::http::HeaderMap) {
         header::HeaderName::from_static("referrer-policy"),
         HeaderValue::from_static("strict-origin-when-cross-origin"),
     );
-    headers.insert(
-        header::HeaderName::from_static("content-security-policy"),
-        HeaderValue::from_static("default-src 'none'; frame-ancestors 'none'; base-uri 'none'"),
-    );
+    // Keep a stricter or page-specific policy a handler already chose.
+    if !headers.contains_key(header::CONTENT_SECURITY_POLICY) {
+        headers.insert(
+            header::CONTENT_SECURITY_POLICY,
+            HeaderValue::from_static("default-src 'none'; frame-ancestors 'none'; base-uri 'none'"),
+        );
+    }
     headers.insert(
         header::HeaderName::from_static("permissions-policy"),
         HeaderValue::from_static(
@@ -188,7 +219,8 @@ pub async fn require_same_origin_for_mutations(
     request: Request,
     next: Next,
 ) -> ApiResult<Response> {
-    if request.uri().path().starts_with("/api/internal/worker/")
+    if request.uri().path().starts_with("/api/internal/")
+        || request.uri().path().starts_with("/api/operator/")
         || request
             .uri()
             .path()
@@ -205,6 +237,14 @@ pub async fn require_same_origin_for_mutations(
             .uri()
             .path()
             .starts_with("/api/public/workflows/approvals/")
+        || request
+            .uri()
+            .path()
+            .starts_with("/api/public/workflows/inbound/")
+        || request
+            .uri()
+            .path()
+            .starts_with("/api/public/record-links/")
     {
         return Ok(next.run(request).await);
     }
@@ -247,25 +287,7 @@ pub async fn require_worker_auth(
     mut request: Request,
     next: Next,
 ) -> ApiResult<Response> {
-    let configured_secret = state
-        .worker_shared_secret
-        .as_deref()
-        .ok_or_else(|| AppError::Unauthorized("worker auth is not configured".to_owned()))?;
-
-    let authorization_header = request
-        .headers()
-        .get(header::AUTHORIZATION)
-        .and_then(|value| value.to_str().ok())
-        .ok_or_else(|| AppError::Unauthorized("worker authorization header missing".to_owned()))?;
-
-    let provided_secret = authorization_header
-        .strip_prefix("Bearer ")
-        .map(str::trim)
-        .ok_or_else(|| AppError::Unauthorized("worker auth scheme must be Bearer".to_owned()))?;
-
-    if !constant_time_eq(provided_secret, configured_secret) {
-        return Err(AppError::Unauthorized("worker auth token is invalid".to_owned()).into());
-    }
+    verify_shared_secret_bearer(&state, &request, "worker")?;
 
     let worker_id = request
         .headers()
@@ -285,6 +307,87 @@ pub async fn require_worker_auth(
     Ok(next.run(request).await)
 }
 
+/// Authenticates operator tooling on internal endpoints with the worker shared secret.
+///
+/// Unlike [`require_worker_auth`], no worker identity header is required.
+pub async fn require_internal_auth(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> ApiResult<Response> {
+    verify_shared_secret_bearer(&state, &request, "internal")?;
+    Ok(next.run(request).await)
+}
+
+/// Authenticates the cross-tenant operator console with per-operator tokens.
+///
+/// Operator tokens are configured separately from the worker shared secret,
+/// and the matching operator is attached to the request for the operator log.
+pub async fn require_operator_auth(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> ApiResult<Response> {
+    if state.operator_credentials.is_empty() {
+        return Err(AppError::Unauthorized("operator auth is not configured".to_owned()).into());
+    }
+
+    let provided_token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| AppError::Unauthorized("operator authorization header missing".to_owned()))?
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+        .ok_or_else(|| AppError::Unauthorized("operator auth scheme must be Bearer".to_owned()))?;
+
+    // Compare against every credential so timing does not reveal which one matched.
+    let mut operator_id = None;
+    for credential in &state.operator_credentials {
+        if constant_time_eq(provided_token, credential.token.as_str()) {
+            operator_id = Some(credential.operator_id.clone());
+        }
+    }
+    let operator_id = operator_id
+        .ok_or_else(|| AppError::Unauthorized("operator auth token is invalid".to_owned()))?;
+
+    request
+        .extensions_mut()
+        .insert(OperatorIdentity { operator_id });
+
+    Ok(next.run(request).await)
+}
+
+fn verify_shared_secret_bearer(
+    state: &AppState,
+    request: &Request,
+    channel: &str,
+) -> Result<(), AppError> {
+    let configured_secret = state
+        .worker_shared_secret
+        .as_deref()
+        .ok_or_else(|| AppError::Unauthorized(format!("{channel} auth is not configured")))?;
+
+    let authorization_header = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| AppError::Unauthorized(format!("{channel} authorization header missing")))?;
+
+    let provided_secret = authorization_header
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+        .ok_or_else(|| AppError::Unauthorized(format!("{channel} auth scheme must be Bearer")))?;
+
+    if !constant_time_eq(provided_secret, configured_secret) {
+        return Err(AppError::Unauthorized(format!(
+            "{channel} auth token is invalid"
+        )));
+    }
+
+    Ok(())
+}
+
 fn is_state_changing_method(method: &Method) -> bool {
     matches!(
         *method,
@@ -320,10 +423,42 @@ pub async fn rate_limit(
         state.trust_proxy_headers,
         &state.trusted_proxy_cidrs,
     );
-    state
-        .rate_limit_service
-        .check_rate_limit(&rule, &ip)
-        .await?;
+    enforce_rate_limit(&state, &rule, &ip).await?;
+
+    Ok(next.run(request).await)
+}
+
+/// Token bucket rate limiting middleware for internal worker endpoints.
+///
+/// Runs after worker auth and keys the bucket by worker id, so one
+/// misconfigured worker loop cannot starve the API or other workers.
+pub async fn rate_limit_worker(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> ApiResult<Response> {
+    let rule = request
+        .extensions()
+        .get::<TokenBucketRule>()
+        .copied()
+        .ok_or_else(|| {
+            AppError::Internal(
+                "worker rate limit middleware misconfigured: missing TokenBucketRule extension"
+                    .to_owned(),
+            )
+        })?;
+    let worker_id = request
+        .extensions()
+        .get::<WorkerIdentity>()
+        .map(|worker| worker.worker_id().to_owned())
+        .ok_or_else(|| {
+            AppError::Internal(
+                "worker rate limit middleware misconfigured: missing WorkerIdentity extension"
+                    .to_owned(),
+            )
+        })?;
+
+    enforce_token_bucket(&state, &rule, worker_id.as_str()).await?;
 
     Ok(next.run(request).await)
 }
@@ -566,6 +701,7 @@ mod tests {
             email_verified: true,
             password_hash: Some("hash".to_owned()),
             totp_enabled: false,
+            passkeys_enrolled: false,
             totp_secret_enc: None,
             recovery_codes_hash: None,
             totp_pending_secret_enc: None,
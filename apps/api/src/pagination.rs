@@ -0,0 +1,111 @@
+//! Next-page links for limit/offset list endpoints.
+//!
+//! List payloads stay plain JSON arrays; a full page carries an RFC 8288
+//! `Link: <...>; rel="next"` header pointing at the following offset, so
+//! clients can page until the header disappears.
+
+use axum::Json;
+use axum::http::{HeaderValue, Uri, header};
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+
+/// Limit/offset window a list handler used to fetch one page.
+#[derive(Debug, Clone, Copy)]
+pub struct PageWindow {
+    pub limit: usize,
+    pub offset: usize,
+}
+
+/// JSON array response with a `rel="next"` link when more items may follow.
+pub struct PaginatedJson<T> {
+    items: Vec<T>,
+    next_link: Option<HeaderValue>,
+}
+
+impl<T> PaginatedJson<T> {
+    /// Builds a page response for items fetched from `uri` with `window`.
+    ///
+    /// A page shorter than its limit is treated as the last one.
+    pub fn new(uri: &Uri, window: PageWindow, items: Vec<T>) -> Self {
+        let next_link = (window.limit > 0 && items.len() >= window.limit)
+            .then(|| next_page_link(uri, window))
+            .and_then(|link| HeaderValue::from_str(link.as_str()).ok());
+
+        Self { items, next_link }
+    }
+}
+
+impl<T: Serialize> IntoResponse for PaginatedJson<T> {
+    fn into_response(self) -> Response {
+        let mut response = Json(self.items).into_response();
+        if let Some(next_link) = self.next_link {
+            response.headers_mut().insert(header::LINK, next_link);
+        }
+        response
+    }
+}
+
+fn next_page_link(uri: &Uri, window: PageWindow) -> String {
+    let mut query = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .filter(|pair| {
+            let key = pair.split_once('=').map_or(*pair, |(key, _)| key);
+            key != "limit" && key != "offset"
+        })
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+    query.push(format!("limit={}", window.limit));
+    query.push(format!("offset={}", window.offset + window.limit));
+
+    format!("<{}?{}>; rel=\"next\"", uri.path(), query.join("&"))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::Uri;
+    use axum::response::IntoResponse;
+
+    use super::{PageWindow, PaginatedJson};
+
+    fn link_header(uri: &str, window: PageWindow, items: Vec<u32>) -> Option<String> {
+        let uri = uri.parse::<Uri>().unwrap_or_else(|_| unreachable!());
+        PaginatedJson::new(&uri, window, items)
+            .into_response()
+            .headers()
+            .get("link")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned)
+    }
+
+    #[test]
+    fn full_pages_link_to_the_next_offset_and_keep_other_filters() {
+        assert_eq!(
+            link_header(
+                "/api/security/audit-log?action=auth.login&limit=2&offset=4",
+                PageWindow {
+                    limit: 2,
+                    offset: 4
+                },
+                vec![1, 2],
+            ),
+            Some(
+                "</api/security/audit-log?action=auth.login&limit=2&offset=6>; rel=\"next\""
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            link_header(
+                "/api/runtime/contact",
+                PageWindow {
+                    limit: 2,
+                    offset: 0
+                },
+                vec![1],
+            ),
+            None
+        );
+    }
+}
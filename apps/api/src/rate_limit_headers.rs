@@ -0,0 +1,146 @@
+//! Standard rate-limit and retry response headers.
+//!
+//! Rate limit checks record their quota in a request-scoped slot; the
+//! [`apply_rate_limit_headers`] middleware turns the most restrictive status
+//! into `X-RateLimit-*` headers and a matching `Retry-After` on 429 responses.
+
+use std::sync::{Arc, Mutex};
+
+use axum::extract::Request;
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::Response;
+use qryvanta_application::{RateLimitRule, RateLimitStatus, TokenBucketRule};
+use qryvanta_core::AppResult;
+
+use crate::state::AppState;
+
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "x-ratelimit-limit";
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+pub const RATE_LIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+
+type RateLimitStatusSlot = Arc<Mutex<Option<RateLimitStatus>>>;
+
+tokio::task_local! {
+    static REQUEST_RATE_LIMIT_STATUS: RateLimitStatusSlot;
+}
+
+/// Records the attempt for a window rule and rejects the request when exhausted.
+pub async fn enforce_rate_limit(
+    state: &AppState,
+    rule: &RateLimitRule,
+    key: &str,
+) -> AppResult<()> {
+    let status = state
+        .rate_limit_service
+        .rate_limit_status(rule, key)
+        .await?;
+    record_rate_limit_status(status);
+    status.ensure_allowed()
+}
+
+/// Takes a bucket token and rejects the request when the bucket is empty.
+pub async fn enforce_token_bucket(
+    state: &AppState,
+    rule: &TokenBucketRule,
+    key: &str,
+) -> AppResult<()> {
+    let Some(status) = state
+        .rate_limit_service
+        .token_bucket_status(rule, key)
+        .await?
+    else {
+        return Ok(());
+    };
+    record_rate_limit_status(status);
+    status.ensure_allowed()
+}
+
+/// Keeps the most restrictive status seen while handling the current request.
+fn record_rate_limit_status(status: RateLimitStatus) {
+    let _ = REQUEST_RATE_LIMIT_STATUS.try_with(|slot| {
+        let Ok(mut current) = slot.lock() else {
+            return;
+        };
+        let replace = match current.as_ref() {
+            None => true,
+            Some(existing) => {
+                (existing.allowed && !status.allowed)
+                    || (existing.allowed == status.allowed && status.remaining < existing.remaining)
+            }
+        };
+        if replace {
+            *current = Some(status);
+        }
+    });
+}
+
+/// Adds `X-RateLimit-*` and `Retry-After` headers from checks made by the request.
+pub async fn apply_rate_limit_headers(request: Request, next: Next) -> Response {
+    let slot = RateLimitStatusSlot::default();
+    let mut response = REQUEST_RATE_LIMIT_STATUS
+        .scope(slot.clone(), next.run(request))
+        .await;
+
+    let status = slot.lock().ok().and_then(|current| *current);
+    if let Some(status) = status {
+        let is_rate_limited = response.status() == StatusCode::TOO_MANY_REQUESTS;
+        write_rate_limit_headers(response.headers_mut(), &status, is_rate_limited);
+    }
+
+    response
+}
+
+fn write_rate_limit_headers(
+    headers: &mut HeaderMap,
+    status: &RateLimitStatus,
+    is_rate_limited: bool,
+) {
+    headers.insert(
+        header::HeaderName::from_static(RATE_LIMIT_LIMIT_HEADER),
+        HeaderValue::from(status.limit),
+    );
+    headers.insert(
+        header::HeaderName::from_static(RATE_LIMIT_REMAINING_HEADER),
+        HeaderValue::from(status.remaining),
+    );
+    headers.insert(
+        header::HeaderName::from_static(RATE_LIMIT_RESET_HEADER),
+        HeaderValue::from(status.reset_after_seconds),
+    );
+    if is_rate_limited {
+        headers.insert(
+            header::RETRY_AFTER,
+            HeaderValue::from(status.reset_after_seconds.max(1)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderMap;
+    use qryvanta_application::RateLimitStatus;
+
+    use super::write_rate_limit_headers;
+
+    #[test]
+    fn rejected_status_sets_retry_after_from_reset() {
+        let mut headers = HeaderMap::new();
+        write_rate_limit_headers(
+            &mut headers,
+            &RateLimitStatus {
+                allowed: false,
+                limit: 5,
+                remaining: 0,
+                reset_after_seconds: 42,
+            },
+            true,
+        );
+
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        assert_eq!(header("x-ratelimit-limit"), Some("5"));
+        assert_eq!(header("x-ratelimit-remaining"), Some("0"));
+        assert_eq!(header("x-ratelimit-reset"), Some("42"));
+        assert_eq!(header("retry-after"), Some("42"));
+    }
+}
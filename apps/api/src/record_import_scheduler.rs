@@ -0,0 +1,44 @@
+//! Background scheduler that runs due recurring record imports.
+
+use std::time::Duration;
+
+use tracing::{error, info};
+
+use crate::state::AppState;
+
+/// Imports claimed per poll.
+const RECORD_IMPORT_SCHEDULE_BATCH_SIZE: usize = 5;
+
+/// Imports run at most hourly, so a one-minute poll keeps them on time.
+const RECORD_IMPORT_SCHEDULE_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+pub fn spawn_record_import_scheduler(state: AppState) {
+    tokio::spawn(async move {
+        info!(
+            interval_ms = RECORD_IMPORT_SCHEDULE_POLL_INTERVAL.as_millis() as u64,
+            "record import scheduler started"
+        );
+
+        loop {
+            match state
+                .record_import_service
+                .run_due_schedules(RECORD_IMPORT_SCHEDULE_BATCH_SIZE)
+                .await
+            {
+                Ok(ran) => {
+                    if ran > 0 {
+                        info!(ran, "recurring record imports ran");
+                    }
+                    if ran >= RECORD_IMPORT_SCHEDULE_BATCH_SIZE {
+                        continue;
+                    }
+                }
+                // Imports whose run could not be recorded are retried once
+                // their claim lapses.
+                Err(error) => error!(error = %error, "recurring record import run failed"),
+            }
+
+            tokio::time::sleep(RECORD_IMPORT_SCHEDULE_POLL_INTERVAL).await;
+        }
+    });
+}
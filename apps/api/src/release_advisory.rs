@@ -0,0 +1,268 @@
+//! Build identity and optional upstream release advisory checks.
+
+use std::cmp::Ordering;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use qryvanta_core::AppError;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+/// Semantic version of the running API build.
+pub const BUILD_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Source commit recorded at compile time through `QRYVANTA_BUILD_COMMIT`.
+pub const BUILD_COMMIT: Option<&str> = option_env!("QRYVANTA_BUILD_COMMIT");
+
+const RELEASE_FEED_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Outcome of comparing the running build against the release feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseAdvisoryStatus {
+    /// No release feed is configured; the instance never calls home.
+    Disabled,
+    /// No newer release is published.
+    Current,
+    /// At least one newer release is published.
+    UpdateAvailable,
+    /// The release feed could not be fetched or parsed.
+    Unavailable,
+}
+
+impl ReleaseAdvisoryStatus {
+    /// Returns a stable transport value.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Disabled => "disabled",
+            Self::Current => "current",
+            Self::UpdateAvailable => "update_available",
+            Self::Unavailable => "unavailable",
+        }
+    }
+}
+
+/// Advisory computed for the running build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseAdvisory {
+    pub status: ReleaseAdvisoryStatus,
+    pub latest_version: Option<String>,
+    pub security_fixes_available: bool,
+    pub notes_url: Option<String>,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub detail: Option<String>,
+}
+
+impl ReleaseAdvisory {
+    fn without_check(status: ReleaseAdvisoryStatus, detail: Option<String>) -> Self {
+        Self {
+            status,
+            latest_version: None,
+            security_fixes_available: false,
+            notes_url: None,
+            checked_at: None,
+            detail,
+        }
+    }
+}
+
+/// Release feed document published by the upstream project.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseFeed {
+    pub releases: Vec<ReleaseFeedEntry>,
+}
+
+/// One published release in the feed.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseFeedEntry {
+    pub version: String,
+    #[serde(default)]
+    pub security_fixes: bool,
+    #[serde(default)]
+    pub notes_url: Option<String>,
+}
+
+/// Fetches and caches the release advisory when a feed URL is configured.
+pub struct ReleaseAdvisoryClient {
+    feed_url: Option<String>,
+    cache_ttl: Duration,
+    cached: Mutex<Option<(Instant, ReleaseAdvisory)>>,
+}
+
+impl ReleaseAdvisoryClient {
+    /// Creates a client; `None` disables every outbound check.
+    #[must_use]
+    pub fn new(feed_url: Option<String>, cache_ttl_seconds: u64) -> Self {
+        Self {
+            feed_url,
+            cache_ttl: Duration::from_secs(cache_ttl_seconds),
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Returns the advisory for the running build, reusing a fresh cached check.
+    ///
+    /// Feed failures are reported as [`ReleaseAdvisoryStatus::Unavailable`]
+    /// and are not cached, so the next call retries.
+    pub async fn advisory(&self, http_client: &reqwest::Client) -> ReleaseAdvisory {
+        let Some(feed_url) = self.feed_url.as_deref() else {
+            return ReleaseAdvisory::without_check(ReleaseAdvisoryStatus::Disabled, None);
+        };
+
+        let mut cached = self.cached.lock().await;
+        if let Some((fetched_at, advisory)) = cached.as_ref()
+            && fetched_at.elapsed() < self.cache_ttl
+        {
+            return advisory.clone();
+        }
+
+        match fetch_release_feed(http_client, feed_url).await {
+            Ok(feed) => {
+                let advisory = evaluate_release_feed(BUILD_VERSION, &feed, Utc::now());
+                *cached = Some((Instant::now(), advisory.clone()));
+                advisory
+            }
+            Err(error) => ReleaseAdvisory::without_check(
+                ReleaseAdvisoryStatus::Unavailable,
+                Some(error.to_string()),
+            ),
+        }
+    }
+}
+
+async fn fetch_release_feed(
+    http_client: &reqwest::Client,
+    feed_url: &str,
+) -> Result<ReleaseFeed, AppError> {
+    let response = http_client
+        .get(feed_url)
+        .timeout(RELEASE_FEED_TIMEOUT)
+        .send()
+        .await
+        .map_err(|error| AppError::Internal(format!("release feed request failed: {error}")))?;
+
+    if !response.status().is_success() {
+        return Err(AppError::Internal(format!(
+            "release feed returned status {}",
+            response.status()
+        )));
+    }
+
+    response
+        .json::<ReleaseFeed>()
+        .await
+        .map_err(|error| AppError::Internal(format!("release feed is not valid JSON: {error}")))
+}
+
+/// Compares the running version against every release in the feed.
+///
+/// Entries with unparseable versions are ignored.
+#[must_use]
+pub fn evaluate_release_feed(
+    current_version: &str,
+    feed: &ReleaseFeed,
+    checked_at: DateTime<Utc>,
+) -> ReleaseAdvisory {
+    let Some(current) = parse_release_version(current_version) else {
+        return ReleaseAdvisory::without_check(
+            ReleaseAdvisoryStatus::Unavailable,
+            Some(format!(
+                "running version '{current_version}' is not a release version"
+            )),
+        );
+    };
+
+    let newer = feed
+        .releases
+        .iter()
+        .filter_map(|entry| parse_release_version(&entry.version).map(|version| (version, entry)))
+        .filter(|(version, _)| version.cmp(&current) == Ordering::Greater)
+        .collect::<Vec<_>>();
+
+    let latest = newer.iter().max_by_key(|(version, _)| *version);
+    ReleaseAdvisory {
+        status: if latest.is_some() {
+            ReleaseAdvisoryStatus::UpdateAvailable
+        } else {
+            ReleaseAdvisoryStatus::Current
+        },
+        latest_version: latest.map(|(_, entry)| entry.version.clone()),
+        security_fixes_available: newer.iter().any(|(_, entry)| entry.security_fixes),
+        notes_url: latest.and_then(|(_, entry)| entry.notes_url.clone()),
+        checked_at: Some(checked_at),
+        detail: None,
+    }
+}
+
+/// Parses `MAJOR.MINOR.PATCH` with an optional `v` prefix.
+///
+/// Pre-release and build suffixes are ignored.
+fn parse_release_version(value: &str) -> Option<(u64, u64, u64)> {
+    let core = value
+        .trim()
+        .trim_start_matches('v')
+        .split(['-', '+'])
+        .next()?;
+    let mut parts = core.split('.').map(str::parse::<u64>);
+    let version = (
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+    );
+
+    parts.next().is_none().then_some(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(version: &str, security_fixes: bool) -> ReleaseFeedEntry {
+        ReleaseFeedEntry {
+            version: version.to_owned(),
+            security_fixes,
+            notes_url: Some(format!("https://releases.example/{version}")),
+        }
+    }
+
+    #[test]
+    fn newer_release_with_security_fixes_is_flagged() {
+        let feed = ReleaseFeed {
+            releases: vec![
+                entry("0.1.0", true),
+                entry("v0.2.0", true),
+                entry("0.3.1", false),
+                entry("nightly", true),
+            ],
+        };
+
+        let advisory = evaluate_release_feed("0.1.0", &feed, Utc::now());
+        assert_eq!(advisory.status, ReleaseAdvisoryStatus::UpdateAvailable);
+        assert_eq!(advisory.latest_version.as_deref(), Some("0.3.1"));
+        assert!(advisory.security_fixes_available);
+        assert_eq!(
+            advisory.notes_url.as_deref(),
+            Some("https://releases.example/0.3.1")
+        );
+    }
+
+    #[test]
+    fn older_or_equal_releases_report_current() {
+        let feed = ReleaseFeed {
+            releases: vec![entry("0.9.9", true), entry("1.2.0-rc.1", true)],
+        };
+
+        let advisory = evaluate_release_feed("1.2.0", &feed, Utc::now());
+        assert_eq!(advisory.status, ReleaseAdvisoryStatus::Current);
+        assert!(advisory.latest_version.is_none());
+        assert!(!advisory.security_fixes_available);
+    }
+
+    #[test]
+    fn release_versions_require_three_numeric_parts() {
+        assert_eq!(parse_release_version("v1.10.3"), Some((1, 10, 3)));
+        assert_eq!(parse_release_version("1.2.3+build.7"), Some((1, 2, 3)));
+        assert_eq!(parse_release_version("1.2"), None);
+        assert_eq!(parse_release_version("1.2.3.4"), None);
+    }
+}
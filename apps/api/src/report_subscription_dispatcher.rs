@@ -0,0 +1,187 @@
+//! Background dispatcher that mails due report subscriptions.
+
+mod xlsx;
+
+use std::time::Duration;
+
+use tracing::{error, info, warn};
+
+use qryvanta_application::{
+    DueReportSubscription, EmailAttachment, REPORT_SUBSCRIPTION_MAX_ROWS, ReportDelivery,
+    ReportSubscriptionFormat, ReportSubscriptionService, RuntimeRecordExport,
+};
+use qryvanta_core::{AppError, AppResult};
+
+use crate::handlers::runtime::{csv_header, csv_row, export_row_cells};
+use crate::state::AppState;
+
+/// Subscriptions claimed per poll.
+const REPORT_SUBSCRIPTION_BATCH_SIZE: usize = 10;
+
+pub fn spawn_report_subscription_dispatcher(state: AppState) {
+    tokio::spawn(async move {
+        info!(
+            interval_ms = state.report_subscription_poll_interval_ms,
+            "report subscription dispatcher started"
+        );
+
+        loop {
+            match state
+                .report_subscription_service
+                .claim_due_subscriptions(REPORT_SUBSCRIPTION_BATCH_SIZE)
+                .await
+            {
+                Ok(due) => {
+                    let full_batch = due.len() >= REPORT_SUBSCRIPTION_BATCH_SIZE;
+                    for subscription in due {
+                        dispatch_subscription(&state, subscription).await;
+                    }
+                    if full_batch {
+                        continue;
+                    }
+                }
+                Err(error) => error!(error = %error, "report subscription claim failed"),
+            }
+
+            tokio::time::sleep(Duration::from_millis(
+                state.report_subscription_poll_interval_ms,
+            ))
+            .await;
+        }
+    });
+}
+
+/// Renders and mails one claimed subscription.
+///
+/// Failed deliveries are left claimed and retried once the claim lapses.
+async fn dispatch_subscription(state: &AppState, due: DueReportSubscription) {
+    let subscription = &due.subscription;
+    let subscriber = ReportSubscriptionService::subscriber(&due);
+    let export = match state
+        .metadata_service
+        .prepare_runtime_record_export(
+            &subscriber,
+            subscription.entity_logical_name.as_str(),
+            subscription.view_logical_name.as_str(),
+        )
+        .await
+    {
+        Ok(export) => export,
+        Err(error @ (AppError::NotFound(_) | AppError::Forbidden(_))) => {
+            let reason = match error {
+                AppError::NotFound(_) => "the subscribed view no longer exists",
+                _ => "the subscriber can no longer export the subscribed view",
+            };
+            if let Err(error) = state
+                .report_subscription_service
+                .suppress(&due, reason)
+                .await
+            {
+                error!(
+                    error = %error,
+                    subscription_id = %subscription.subscription_id,
+                    "failed to suppress report subscription"
+                );
+            } else {
+                info!(
+                    subscription_id = %subscription.subscription_id,
+                    reason,
+                    "report subscription suppressed"
+                );
+            }
+            return;
+        }
+        Err(error) => {
+            warn!(
+                error = %error,
+                subscription_id = %subscription.subscription_id,
+                "report subscription export failed; will retry"
+            );
+            return;
+        }
+    };
+
+    let result = match render_report(export, subscription.format).await {
+        Ok((content, row_count, truncated)) => {
+            let delivery = ReportDelivery {
+                attachment: EmailAttachment {
+                    filename: format!(
+                        "{}-{}.{}",
+                        subscription.entity_logical_name,
+                        subscription.view_logical_name,
+                        subscription.format.as_str()
+                    ),
+                    content_type: subscription.format.content_type().to_owned(),
+                    content,
+                },
+                row_count,
+                truncated,
+            };
+            state
+                .report_subscription_service
+                .deliver(&due, delivery)
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        warn!(
+            error = %error,
+            subscription_id = %subscription.subscription_id,
+            "report subscription delivery failed; will retry"
+        );
+    }
+}
+
+/// Renders up to [`REPORT_SUBSCRIPTION_MAX_ROWS`] records of an export.
+///
+/// Returns the file content, the number of records written and whether
+/// further records were left out.
+async fn render_report(
+    mut export: RuntimeRecordExport,
+    format: ReportSubscriptionFormat,
+) -> AppResult<(Vec<u8>, usize, bool)> {
+    let mut csv = String::new();
+    let mut rows = Vec::new();
+    match format {
+        ReportSubscriptionFormat::Csv => csv.push_str(csv_header(export.columns()).as_str()),
+        ReportSubscriptionFormat::Xlsx => rows.push(
+            std::iter::once("record_id".to_owned())
+                .chain(
+                    export
+                        .columns()
+                        .iter()
+                        .map(|column| column.field_logical_name.clone()),
+                )
+                .collect(),
+        ),
+    }
+
+    let mut row_count = 0;
+    let mut truncated = false;
+    'pages: while let Some(records) = export.next_page().await? {
+        for record in &records {
+            if row_count == REPORT_SUBSCRIPTION_MAX_ROWS {
+                truncated = true;
+                break 'pages;
+            }
+            match format {
+                ReportSubscriptionFormat::Csv => {
+                    csv.push_str(csv_row(export.columns(), record).as_str());
+                }
+                ReportSubscriptionFormat::Xlsx => {
+                    rows.push(export_row_cells(export.columns(), record));
+                }
+            }
+            row_count += 1;
+        }
+    }
+
+    let content = match format {
+        ReportSubscriptionFormat::Csv => csv.into_bytes(),
+        ReportSubscriptionFormat::Xlsx => xlsx::render_workbook(&rows)?,
+    };
+
+    Ok((content, row_count, truncated))
+}
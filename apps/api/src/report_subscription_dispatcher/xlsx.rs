@@ -0,0 +1,96 @@
+//! Minimal single-sheet XLSX writer for delivered reports.
+//!
+//! Cells are written as inline strings so the workbook needs no shared string
+//! table or styles part; entries are stored uncompressed.
+
+use std::io::{Cursor, Write};
+
+use qryvanta_core::AppError;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/></Types>"#;
+
+const ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+
+const WORKBOOK: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="Report" sheetId="1" r:id="rId1"/></sheets></workbook>"#;
+
+const WORKBOOK_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/></Relationships>"#;
+
+/// Renders rows of plain-text cells as a one-sheet workbook.
+pub(super) fn render_workbook(rows: &[Vec<String>]) -> Result<Vec<u8>, AppError> {
+    let mut sheet = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>"#,
+    );
+    for row in rows {
+        sheet.push_str("<row>");
+        for cell in row {
+            sheet.push_str(r#"<c t="inlineStr"><is><t xml:space="preserve">"#);
+            push_escaped(&mut sheet, cell.as_str());
+            sheet.push_str("</t></is></c>");
+        }
+        sheet.push_str("</row>");
+    }
+    sheet.push_str("</sheetData></worksheet>");
+
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+    for (name, content) in [
+        ("[Content_Types].xml", CONTENT_TYPES),
+        ("_rels/.rels", ROOT_RELS),
+        ("xl/workbook.xml", WORKBOOK),
+        ("xl/_rels/workbook.xml.rels", WORKBOOK_RELS),
+        ("xl/worksheets/sheet1.xml", sheet.as_str()),
+    ] {
+        writer
+            .start_file(name, options)
+            .map_err(|error| AppError::Internal(format!("failed to write xlsx entry: {error}")))?;
+        writer
+            .write_all(content.as_bytes())
+            .map_err(|error| AppError::Internal(format!("failed to write xlsx entry: {error}")))?;
+    }
+
+    writer
+        .finish()
+        .map(Cursor::into_inner)
+        .map_err(|error| AppError::Internal(format!("failed to finish xlsx archive: {error}")))
+}
+
+/// Escapes XML markup and drops control characters XML 1.0 cannot carry.
+fn push_escaped(target: &mut String, value: &str) {
+    for character in value.chars() {
+        match character {
+            '&' => target.push_str("&amp;"),
+            '<' => target.push_str("&lt;"),
+            '>' => target.push_str("&gt;"),
+            '"' => target.push_str("&quot;"),
+            '\t' | '\n' | '\r' => target.push(character),
+            character if character.is_control() => {}
+            character => target.push(character),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_workbook;
+
+    #[test]
+    fn workbook_is_a_zip_archive_with_escaped_inline_cells() {
+        let bytes = render_workbook(&[
+            vec!["record_id".to_owned(), "name".to_owned()],
+            vec!["1".to_owned(), "Ada & <Grace>\u{0007}".to_owned()],
+        ])
+        .unwrap_or_else(|_| unreachable!());
+
+        assert!(bytes.starts_with(b"PK"));
+        let text = String::from_utf8_lossy(&bytes);
+        assert!(text.contains("xl/worksheets/sheet1.xml"));
+        assert!(text.contains("Ada &amp; &lt;Grace&gt;</t>"));
+    }
+}
@@ -0,0 +1,42 @@
+//! Background projector that keeps reporting projections in step with the
+//! runtime record change feed.
+
+use std::time::Duration;
+
+use tracing::{error, info};
+
+use crate::state::AppState;
+
+/// Change feed entries leased per poll.
+const REPORTING_PROJECTOR_BATCH_SIZE: usize = 200;
+
+/// Reports tolerate a few seconds of lag behind record writes.
+const REPORTING_PROJECTOR_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+pub fn spawn_reporting_projector(state: AppState) {
+    tokio::spawn(async move {
+        info!(
+            interval_ms = REPORTING_PROJECTOR_POLL_INTERVAL.as_millis() as u64,
+            "reporting projector started"
+        );
+
+        loop {
+            match state
+                .reporting_projection_service
+                .process_runtime_record_changes(REPORTING_PROJECTOR_BATCH_SIZE)
+                .await
+            {
+                Ok(processed) => {
+                    if processed >= REPORTING_PROJECTOR_BATCH_SIZE {
+                        continue;
+                    }
+                }
+                // Unacknowledged changes are handed out again once their
+                // lease lapses.
+                Err(error) => error!(error = %error, "reporting projection refresh failed"),
+            }
+
+            tokio::time::sleep(REPORTING_PROJECTOR_POLL_INTERVAL).await;
+        }
+    });
+}
@@ -1,10 +1,19 @@
 use std::sync::Arc;
+use std::time::Instant;
 
 use ipnet::IpNet;
 use qryvanta_application::{
-    AppService, AuthEventService, AuthTokenService, AuthorizationService, ContactBootstrapService,
-    ExtensionService, MetadataService, MfaService, RateLimitService, SecurityAdminService,
-    TenantAccessService, TenantRepository, UserService, WorkflowService,
+    AnnouncementService, AppService, AuditExportService, AuditOutboxRelay, AuthEventService,
+    AuthTokenService, AuthorizationService, BackgroundJobService, ComplianceZoneService,
+    ContactBootstrapService, ContactConsentService, ContactIdentityService, DataErasureService,
+    DataValidationService, EmailChangeService, ExtensionService, FeatureFlagService,
+    FieldChangeApprovalService, FieldMaskingService, LegalHoldService, LocalizationService,
+    MetadataService, MfaService, OperatorConsoleService, PersonalDataExportService,
+    PublishCoordinationService, RateLimitService, RecordAccessService, RecordImportService,
+    RecordShareLinkService, ReportSubscriptionService, ReportingProjectionService,
+    SecurityAdminService, TenantAccessService, TenantDataExportService, TenantEncryptionService,
+    TenantRepository, UserService, WorkflowClaimBackpressurePolicy, WorkflowClaimLoadSignal,
+    WorkflowService,
 };
 use qryvanta_core::{AppError, TenantId};
 use qryvanta_infrastructure::PostgresPasskeyRepository;
@@ -12,25 +21,52 @@ use sqlx::PgPool;
 use tokio::sync::{OwnedSemaphorePermit, Semaphore, TryAcquireError};
 use webauthn_rs::Webauthn;
 
-use crate::api_config::PhysicalIsolationMode;
+use crate::api_config::{OperatorCredential, PhysicalIsolationMode, SessionStoreBackend};
+use crate::api_versioning::ApiVersionLifecycle;
 use crate::observability::ApiObservabilityMetrics;
+use crate::release_advisory::ReleaseAdvisoryClient;
 
 /// Shared application state.
 #[derive(Clone)]
 pub struct AppState {
+    pub announcement_service: AnnouncementService,
     pub app_service: AppService,
     pub metadata_service: MetadataService,
+    pub localization_service: LocalizationService,
+    pub publish_coordination_service: PublishCoordinationService,
+    pub background_job_service: BackgroundJobService,
+    pub personal_data_export_service: PersonalDataExportService,
+    pub tenant_data_export_service: TenantDataExportService,
+    pub data_validation_service: DataValidationService,
     pub extension_service: ExtensionService,
     pub contact_bootstrap_service: ContactBootstrapService,
+    pub contact_consent_service: ContactConsentService,
+    pub contact_identity_service: ContactIdentityService,
     pub security_admin_service: SecurityAdminService,
+    pub legal_hold_service: LegalHoldService,
+    pub audit_export_service: AuditExportService,
+    pub compliance_zone_service: ComplianceZoneService,
+    pub data_erasure_service: DataErasureService,
+    pub feature_flag_service: FeatureFlagService,
+    pub field_change_approval_service: FieldChangeApprovalService,
+    pub field_masking_service: FieldMaskingService,
+    pub record_access_service: RecordAccessService,
+    pub record_import_service: RecordImportService,
+    pub record_share_link_service: RecordShareLinkService,
+    pub report_subscription_service: ReportSubscriptionService,
+    pub reporting_projection_service: ReportingProjectionService,
+    pub operator_console_service: OperatorConsoleService,
     pub authorization_service: AuthorizationService,
     pub auth_event_service: AuthEventService,
     pub user_service: UserService,
     pub tenant_access_service: TenantAccessService,
     pub auth_token_service: AuthTokenService,
+    pub email_change_service: EmailChangeService,
     pub workflow_service: WorkflowService,
     pub mfa_service: MfaService,
+    pub tenant_encryption_service: TenantEncryptionService,
     pub rate_limit_service: RateLimitService,
+    pub audit_outbox_relay: Arc<dyn AuditOutboxRelay>,
     pub tenant_repository: Arc<dyn TenantRepository>,
     pub passkey_repository: PostgresPasskeyRepository,
     pub webauthn: Arc<Webauthn>,
@@ -39,12 +75,16 @@ pub struct AppState {
     pub trusted_proxy_cidrs: Vec<IpNet>,
     pub physical_isolation_mode: PhysicalIsolationMode,
     pub physical_isolation_tenant_id: Option<TenantId>,
-    pub bootstrap_token: String,
+    pub bootstrap_token_ttl_seconds: u64,
     pub bootstrap_tenant_id: Option<TenantId>,
     pub worker_shared_secret: Option<String>,
+    pub operator_credentials: Vec<OperatorCredential>,
     pub workflow_worker_default_lease_seconds: u32,
     pub workflow_worker_max_claim_limit: usize,
     pub workflow_worker_max_partition_count: u32,
+    pub workflow_claim_backpressure_policy: WorkflowClaimBackpressurePolicy,
+    pub workflow_worker_rate_limit_burst: u32,
+    pub workflow_worker_rate_limit_per_second: u32,
     pub runtime_query_max_limit: usize,
     pub runtime_query_backpressure: Arc<Semaphore>,
     pub workflow_burst_backpressure: Arc<Semaphore>,
@@ -54,15 +94,37 @@ pub struct AppState {
     pub postgres_pool: PgPool,
     pub redis_client: Option<redis::Client>,
     pub redis_required: bool,
+    pub session_store_backend: SessionStoreBackend,
+    pub smtp_probe_address: Option<(String, u16)>,
+    pub started_at: Instant,
+    pub audit_outbox_relay_interval_ms: u64,
+    pub audit_outbox_relay_batch_size: usize,
+    pub background_job_poll_interval_ms: u64,
+    pub report_subscription_poll_interval_ms: u64,
+    pub audit_export_poll_interval_ms: u64,
+    pub audit_export_batch_size: usize,
     pub qrywell_api_base_url: Option<String>,
     pub qrywell_api_key: Option<String>,
     pub qrywell_sync_poll_interval_ms: u64,
     pub qrywell_sync_batch_size: usize,
     pub qrywell_sync_max_attempts: i32,
+    pub openapi_swagger_ui_enabled: bool,
+    pub api_version_lifecycle: ApiVersionLifecycle,
     pub http_client: reqwest::Client,
+    pub release_advisory: Arc<ReleaseAdvisoryClient>,
 }
 
 impl AppState {
+    pub fn workflow_claim_load_signal(&self) -> WorkflowClaimLoadSignal {
+        let open_connections = self.postgres_pool.size();
+        let idle_connections = u32::try_from(self.postgres_pool.num_idle()).unwrap_or(u32::MAX);
+
+        WorkflowClaimLoadSignal {
+            database_connections_in_use: open_connections.saturating_sub(idle_connections),
+            database_max_connections: self.postgres_pool.options().get_max_connections(),
+        }
+    }
+
     pub fn try_acquire_runtime_query_permit(&self) -> Result<OwnedSemaphorePermit, AppError> {
         match try_acquire_backpressure_permit(
             self.runtime_query_backpressure.clone(),
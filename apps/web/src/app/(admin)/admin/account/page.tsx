@@ -3,6 +3,8 @@
 import { PageHeader } from "@qryvanta/ui";
 
 import { AccountSnapshotCard } from "@/components/security/account/account-snapshot-card";
+import { DataExportCard } from "@/components/security/account/data-export-card";
+import { EmailChangeCard } from "@/components/security/account/email-change-card";
 import { InviteCard } from "@/components/security/account/invite-card";
 import { MfaCard } from "@/components/security/account/mfa-card";
 import { PasswordCard } from "@/components/security/account/password-card";
@@ -13,27 +15,38 @@ export default function AdminSecurityAccountPage() {
     activeAction,
     confirmCode,
     currentPassword,
+    dataExportJob,
     disablePassword,
+    emailChange,
+    emailChangePassword,
     enrollment,
     inviteEmail,
     inviteTenantName,
     me,
+    newEmail,
     newPassword,
     newRecoveryCodes,
     regeneratePassword,
     status,
+    cancelEmailChange,
     changePassword,
     confirmMfaEnrollment,
     disableMfa,
+    downloadDataExport,
     loadMe,
+    refreshDataExport,
     regenerateRecoveryCodes,
+    requestDataExport,
+    requestEmailChange,
     resendVerification,
     sendInvite,
     setConfirmCode,
     setCurrentPassword,
     setDisablePassword,
+    setEmailChangePassword,
     setInviteEmail,
     setInviteTenantName,
+    setNewEmail,
     setNewPassword,
     setRegeneratePassword,
     startMfaEnrollment,
@@ -46,7 +59,7 @@ export default function AdminSecurityAccountPage() {
       <PageHeader
         eyebrow="Admin Center"
         title="Security Settings"
-        description="Manage account verification, password, MFA, and tenant invites."
+        description="Manage account verification, password, email, MFA, data exports, and tenant invites."
       />
 
       <div className="grid gap-6 lg:grid-cols-2">
@@ -64,6 +77,16 @@ export default function AdminSecurityAccountPage() {
           onCurrentPasswordChange={setCurrentPassword}
           onNewPasswordChange={setNewPassword}
         />
+        <EmailChangeCard
+          busy={busy}
+          emailChange={emailChange}
+          emailChangePassword={emailChangePassword}
+          newEmail={newEmail}
+          onCancelEmailChange={cancelEmailChange}
+          onEmailChangePasswordChange={setEmailChangePassword}
+          onNewEmailChange={setNewEmail}
+          onRequestEmailChange={requestEmailChange}
+        />
         <MfaCard
           busy={busy}
           confirmCode={confirmCode}
@@ -79,6 +102,13 @@ export default function AdminSecurityAccountPage() {
           onRegeneratePasswordChange={setRegeneratePassword}
           onStartMfaEnrollment={startMfaEnrollment}
         />
+        <DataExportCard
+          busy={busy}
+          dataExportJob={dataExportJob}
+          onDownloadDataExport={downloadDataExport}
+          onRefreshDataExport={refreshDataExport}
+          onRequestDataExport={requestDataExport}
+        />
         <InviteCard
           busy={busy}
           inviteEmail={inviteEmail}
@@ -0,0 +1,14 @@
+import { ConfirmEmailChangeForm } from "@/components/auth/confirm-email-change-form";
+
+type ConfirmEmailChangePageProps = {
+  searchParams?: Promise<{
+    token?: string;
+  }>;
+};
+
+export default async function ConfirmEmailChangePage({
+  searchParams,
+}: ConfirmEmailChangePageProps) {
+  const resolvedSearchParams = (await searchParams) ?? {};
+  return <ConfirmEmailChangeForm token={resolvedSearchParams.token ?? ""} />;
+}
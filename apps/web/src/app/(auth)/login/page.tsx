@@ -18,7 +18,9 @@ import {
   type AuthLoginResponse,
   type AuthMfaVerifyRequest,
   type AuthRegisterRequest,
+  type AuthSwitchTenantRequest,
   type GenericMessageResponse,
+  type TenantOptionResponse,
 } from "@/lib/api";
 
 type ErrorResponse = {
@@ -59,6 +61,26 @@ export default function LoginPage() {
   const [status, setStatus] = useState("");
   const [isLoading, setIsLoading] = useState(false);
   const [mfaRequired, setMfaRequired] = useState(false);
+  const [tenantOptions, setTenantOptions] = useState<TenantOptionResponse[]>(
+    [],
+  );
+  const [selectedTenantId, setSelectedTenantId] = useState("");
+
+  function completeLogin(body: AuthLoginResponse) {
+    if (body.status === "tenant_selection_required") {
+      setMfaRequired(false);
+      setTenantOptions(body.tenants);
+      setSelectedTenantId(
+        body.tenants.find((tenant) => tenant.is_default)?.tenant_id ??
+          body.tenants[0]?.tenant_id ??
+          "",
+      );
+      setStatus("Choose the workspace to sign in to.");
+      return;
+    }
+
+    window.location.href = "/";
+  }
 
   async function handleLogin() {
     if (!email || !password) {
@@ -89,7 +111,7 @@ export default function LoginPage() {
         return;
       }
 
-      window.location.href = "/";
+      completeLogin(body);
     } catch {
       setStatus("Login request failed.");
     } finally {
@@ -122,7 +144,7 @@ export default function LoginPage() {
         return;
       }
 
-      window.location.href = "/";
+      completeLogin((await response.json()) as AuthLoginResponse);
     } catch {
       setStatus("MFA request failed.");
     } finally {
@@ -130,6 +152,38 @@ export default function LoginPage() {
     }
   }
 
+  async function handleTenantSelect() {
+    if (!selectedTenantId) {
+      setStatus("Choose a workspace.");
+      return;
+    }
+
+    setIsLoading(true);
+    setStatus("");
+    try {
+      const payload: AuthSwitchTenantRequest = { tenant_id: selectedTenantId };
+      const response = await fetch(`${API_BASE_URL}/auth/login/tenant`, {
+        method: "POST",
+        credentials: "include",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify(payload),
+      });
+
+      if (!response.ok) {
+        setStatus(
+          await readErrorMessage(response, "Workspace selection failed."),
+        );
+        return;
+      }
+
+      completeLogin((await response.json()) as AuthLoginResponse);
+    } catch {
+      setStatus("Workspace selection request failed.");
+    } finally {
+      setIsLoading(false);
+    }
+  }
+
   async function handleRegister() {
     if (!registerEmail || !registerPassword || !registerDisplayName) {
       setStatus("Complete all registration fields.");
@@ -276,6 +330,37 @@ export default function LoginPage() {
               </div>
             ) : null}
 
+            {tenantOptions.length > 0 ? (
+              <div className="space-y-3 rounded-md border border-emerald-100 bg-emerald-50/70 p-4">
+                <Label>Workspace</Label>
+                <div className="space-y-2">
+                  {tenantOptions.map((tenant) => (
+                    <Button
+                      key={tenant.tenant_id}
+                      type="button"
+                      variant={
+                        selectedTenantId === tenant.tenant_id
+                          ? "default"
+                          : "outline"
+                      }
+                      onClick={() => setSelectedTenantId(tenant.tenant_id)}
+                      className="w-full justify-between"
+                    >
+                      <span>{tenant.tenant_name}</span>
+                      <span className="text-xs">{tenant.display_name}</span>
+                    </Button>
+                  ))}
+                </div>
+                <Button
+                  onClick={handleTenantSelect}
+                  disabled={isLoading}
+                  className="w-full"
+                >
+                  Enter workspace
+                </Button>
+              </div>
+            ) : null}
+
             <div className="space-y-2 border-t border-zinc-100 pt-4">
               <Label htmlFor="forgot-email">Forgot password</Label>
               <Input
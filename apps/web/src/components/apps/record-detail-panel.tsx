@@ -258,6 +258,7 @@ export function RecordDetailPanel({
     try {
       const payload: UpdateRuntimeRecordRequest = {
         data: buildPayloadFromForm(),
+        expected_version: record.version,
       };
       const response = await apiFetch(
         `/api/workspace/apps/${appLogicalName}/entities/${entityLogicalName}/records/${record.record_id}`,
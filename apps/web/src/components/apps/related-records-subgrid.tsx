@@ -79,6 +79,7 @@ export function RelatedRecordsSubgrid({
           link_entities: null,
           sort: [],
           filters: null,
+          include_inactive: null,
         };
 
         const recordsResponse = await apiFetch(
@@ -16,7 +16,8 @@ type BusinessRuleAction = {
     | "set_field_value"
     | "lock_field"
     | "unlock_field"
-    | "show_error";
+    | "show_error"
+    | "prevent_save";
   target_field_logical_name: string | null;
   value: unknown;
   error_message: string | null;
@@ -162,7 +163,9 @@ export function evaluateRuleState(
           requiredOverrides.set(targetField, false);
           break;
         case "set_default_value": {
-          const current = formValues[targetField];
+          const current = valuePatches.has(targetField)
+            ? valuePatches.get(targetField)
+            : formValues[targetField];
           if (isEmptyValue(current)) {
             valuePatches.set(targetField, action.value);
           }
@@ -146,6 +146,7 @@ export function parseFormResponse(form: FormResponse): ParsedFormResponse {
     form_type: form.form_type,
     tabs,
     header_fields: form.header_fields,
+    script_events: form.script_events,
   };
 }
 
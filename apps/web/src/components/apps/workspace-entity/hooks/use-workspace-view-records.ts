@@ -87,11 +87,12 @@ export function useWorkspaceViewRecords({
             ]
           : null,
         filters: null,
+        include_inactive: null,
       };
 
       try {
         const response = await apiFetch(
-          `/api/workspace/apps/${appLogicalName}/entities/${entityLogicalName}/records/query`,
+          `/api/workspace/apps/${appLogicalName}/entities/${entityLogicalName}/records/query?view=${encodeURIComponent(activeView.logical_name)}`,
           {
             method: "POST",
             body: JSON.stringify(payload),
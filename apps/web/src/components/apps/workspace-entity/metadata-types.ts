@@ -8,6 +8,8 @@
  * consume them in a type-safe way.
  */
 
+import type { FormScriptEventsDto } from "@/lib/api";
+
 // ---------------------------------------------------------------------------
 // Form types (mirrors crates/domain/src/form.rs serialised output)
 // ---------------------------------------------------------------------------
@@ -88,6 +90,7 @@ export type ParsedFormResponse = {
   form_type: string;
   tabs: FormTab[];
   header_fields: string[];
+  script_events: FormScriptEventsDto;
 };
 
 export type ParsedViewResponse = {
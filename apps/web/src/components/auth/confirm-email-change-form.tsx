@@ -0,0 +1,90 @@
+"use client";
+
+import { useEffect, useState } from "react";
+
+import { Button, Card, CardContent, CardHeader, CardTitle } from "@qryvanta/ui";
+import { API_BASE_URL, type ConfirmEmailChangeResponse } from "@/lib/api";
+
+type ErrorResponse = { message?: string };
+
+type ConfirmEmailChangeFormProps = {
+  token: string;
+};
+
+export function ConfirmEmailChangeForm({ token }: ConfirmEmailChangeFormProps) {
+  const [status, setStatus] = useState("");
+  const [loading, setLoading] = useState(false);
+
+  useEffect(() => {
+    if (!token || typeof window === "undefined") {
+      return;
+    }
+
+    const url = new URL(window.location.href);
+    url.searchParams.delete("token");
+    window.history.replaceState({}, "", url.toString());
+  }, [token]);
+
+  async function confirm() {
+    if (!token) {
+      setStatus("Missing confirmation token.");
+      return;
+    }
+
+    if (token.length > 2048) {
+      setStatus("Confirmation token is invalid.");
+      return;
+    }
+
+    setLoading(true);
+    setStatus("");
+    try {
+      const response = await fetch(
+        `${API_BASE_URL}/auth/email-change/confirm`,
+        {
+          method: "POST",
+          credentials: "include",
+          headers: { "Content-Type": "application/json" },
+          body: JSON.stringify({ token }),
+        },
+      );
+
+      if (!response.ok) {
+        const payload = (await response
+          .json()
+          .catch(() => ({}))) as ErrorResponse;
+        setStatus(payload.message ?? "Confirmation failed.");
+        return;
+      }
+
+      const body = (await response.json()) as ConfirmEmailChangeResponse;
+      setStatus(
+        body.status === "completed"
+          ? "Your email address was changed. Sign in again with the new address."
+          : "Confirmed. Use the link sent to your other address to finish the change.",
+      );
+    } catch {
+      setStatus("Confirmation request failed.");
+    } finally {
+      setLoading(false);
+    }
+  }
+
+  return (
+    <main className="grid min-h-screen place-items-center bg-app px-6 py-12">
+      <Card className="w-full max-w-md">
+        <CardHeader>
+          <CardTitle className="font-serif text-3xl">
+            Confirm Email Change
+          </CardTitle>
+        </CardHeader>
+        <CardContent className="space-y-4">
+          <Button onClick={confirm} disabled={loading} className="w-full">
+            Confirm email change
+          </Button>
+          {status ? <p className="text-sm text-zinc-600">{status}</p> : null}
+        </CardContent>
+      </Card>
+    </main>
+  );
+}
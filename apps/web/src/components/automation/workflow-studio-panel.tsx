@@ -227,7 +227,11 @@ function useWorkflowStudioPanelContent({
   );
 
   const triggerFieldPathSuggestions = useMemo(() => {
-    if (triggerType === "manual" || triggerType === "schedule_tick") {
+    if (
+      triggerType === "manual" ||
+      triggerType === "schedule_tick" ||
+      triggerType === "scheduled"
+    ) {
       return [];
     }
 
@@ -289,7 +293,10 @@ function useWorkflowStudioPanelContent({
         }
       }
 
-      if (workflowTriggerType === "schedule_tick") {
+      if (
+        workflowTriggerType === "schedule_tick" ||
+        workflowTriggerType === "scheduled"
+      ) {
         return {
           tick_at: "2026-03-07T09:00:00Z",
           timezone: "UTC",
@@ -333,7 +340,8 @@ function useWorkflowStudioPanelContent({
       if (
         (workflowTriggerType === "runtime_record_created" ||
           workflowTriggerType === "runtime_record_updated" ||
-          workflowTriggerType === "runtime_record_deleted") &&
+          workflowTriggerType === "runtime_record_deleted" ||
+          workflowTriggerType === "runtime_record_status_changed") &&
         workflowTriggerSchema
       ) {
         const preferredFields = workflowTriggerSchema.fields
@@ -1631,6 +1631,8 @@ export function TriggerCard({
       ? "Manual trigger"
       : triggerType === "schedule_tick"
         ? `Schedule tick · ${triggerEntityLogicalName.trim() || "schedule key not set"}`
+        : triggerType === "scheduled"
+          ? `Cron schedule · ${triggerEntityLogicalName.trim() || "cron expression not set"}`
         : triggerType === "webhook_received"
           ? `Webhook received · ${triggerEntityLogicalName.trim() || "webhook key not set"}`
           : triggerType === "form_submitted"
@@ -1644,7 +1646,9 @@ export function TriggerCard({
               ? "Record updated"
               : triggerType === "runtime_record_deleted"
                 ? "Record deleted"
-                : "Record created"
+                : triggerType === "runtime_record_status_changed"
+                  ? "Record status changed"
+                  : "Record created"
           } · ${triggerEntityLogicalName.trim() || "entity not set"}`;
 
   return (
@@ -1896,6 +1900,22 @@ function CreateRecordForm({
         focusFieldKey={focusedFieldKey}
         onFocusApplied={onFocusApplied}
       />
+      <div className="space-y-1.5">
+        <Label htmlFor={`output_key_${step.id}`}>Output key</Label>
+        <Input
+          id={`output_key_${step.id}`}
+          value={step.outputKey ?? ""}
+          onChange={(e) =>
+            onUpdate((s) =>
+              s.type === "create_runtime_record" ? { ...s, outputKey: e.target.value } : s,
+            )
+          }
+          placeholder="create_invoice"
+        />
+        <p className="text-[11px] text-zinc-500">
+          {"Later steps can reference the created record as {{steps.<output key>.record_id}}."}
+        </p>
+      </div>
       <JsonPreviewCard label="Create payload preview" value={previewPayload} error={previewError} />
     </div>
   );
@@ -2220,6 +2240,79 @@ function HttpRequestForm({
           <TokenChips value={step.url} />
         </div>
       </div>
+      <div className="grid grid-cols-3 gap-2">
+        <div className="space-y-1.5">
+          <Label htmlFor={`http_expected_status_${step.id}`}>Expected status</Label>
+          <Input
+            id={`http_expected_status_${step.id}`}
+            inputMode="numeric"
+            value={step.expectedStatus}
+            onChange={(e) =>
+              onUpdate((s) =>
+                s.type === "http_request" ? { ...s, expectedStatus: e.target.value } : s,
+              )
+            }
+            placeholder="Any 2xx"
+          />
+        </div>
+        <div className="space-y-1.5">
+          <Label htmlFor={`http_retry_attempts_${step.id}`}>Retry attempts</Label>
+          <Input
+            id={`http_retry_attempts_${step.id}`}
+            inputMode="numeric"
+            value={step.retryMaxAttempts}
+            onChange={(e) =>
+              onUpdate((s) =>
+                s.type === "http_request" ? { ...s, retryMaxAttempts: e.target.value } : s,
+              )
+            }
+            placeholder="Default"
+          />
+        </div>
+        <div className="space-y-1.5">
+          <Label htmlFor={`http_retry_backoff_${step.id}`}>Backoff (ms)</Label>
+          <Input
+            id={`http_retry_backoff_${step.id}`}
+            inputMode="numeric"
+            value={step.retryBackoffMs}
+            onChange={(e) =>
+              onUpdate((s) =>
+                s.type === "http_request" ? { ...s, retryBackoffMs: e.target.value } : s,
+              )
+            }
+            placeholder="1000"
+          />
+        </div>
+      </div>
+      <div className="space-y-1.5">
+        <Label htmlFor={`http_output_key_${step.id}`}>Output key</Label>
+        <Input
+          id={`http_output_key_${step.id}`}
+          value={step.outputKey ?? ""}
+          onChange={(e) =>
+            onUpdate((s) =>
+              s.type === "http_request" ? { ...s, outputKey: e.target.value } : s,
+            )
+          }
+          placeholder="Optional, e.g. lookup_customer"
+        />
+      </div>
+      <div className="space-y-1.5">
+        <Label htmlFor={`http_credential_${step.id}`}>Credential</Label>
+        <Input
+          id={`http_credential_${step.id}`}
+          value={step.credential ?? ""}
+          onChange={(e) =>
+            onUpdate((s) =>
+              s.type === "http_request" ? { ...s, credential: e.target.value } : s,
+            )
+          }
+          placeholder="Optional, e.g. erp_api"
+        />
+      </div>
+      <p className="text-[11px] text-zinc-500">
+        {"The response is available to later steps as {{steps.<output key or path>.response.status}} and {{steps.<output key or path>.response.body}}."}
+      </p>
       <StringMapEditor
         label="Headers"
         idPrefix={`http_headers_${step.id}`}
@@ -2704,6 +2797,19 @@ function WebhookForm({
           placeholder="workflow.completed"
         />
       </div>
+      <div className="space-y-1.5">
+        <Label htmlFor={`webhook_credential_${step.id}`}>Credential</Label>
+        <Input
+          id={`webhook_credential_${step.id}`}
+          value={step.credential ?? ""}
+          onChange={(e) =>
+            onUpdate((s) =>
+              s.type === "webhook" ? { ...s, credential: e.target.value } : s,
+            )
+          }
+          placeholder="Optional, e.g. erp_api"
+        />
+      </div>
       <StringMapEditor
         label="Headers"
         idPrefix={`webhook_headers_${step.id}`}
@@ -84,6 +84,7 @@ export function useRuntimeSchemas(triggerType: string, triggerEntityLogicalName:
     if (
       triggerType === "manual" ||
       triggerType === "schedule_tick" ||
+      triggerType === "scheduled" ||
       triggerType === "webhook_received" ||
       triggerType === "form_submitted" ||
       triggerType === "inbound_email_received" ||
@@ -17,6 +17,11 @@ import {
   type TriggerType,
 } from "@/components/automation/workflow-studio/model";
 
+function draftOutputKey(outputKey: string | undefined): string | null {
+  const trimmed = outputKey?.trim() ?? "";
+  return trimmed.length > 0 ? trimmed : null;
+}
+
 type UseWorkflowEditorInput = {
   onResetMessages: () => void;
   onStatusMessage: (message: string | null) => void;
@@ -65,6 +70,8 @@ export function useWorkflowEditor({
         type: "create_runtime_record",
         entity_logical_name: step.entityLogicalName,
         data: parseDraftObjectFields(step.dataFields, "Create record step data"),
+        output_key: draftOutputKey(step.outputKey),
+        retry_policy: step.retryPolicy ?? null,
       };
     }
 
@@ -81,6 +88,7 @@ export function useWorkflowEditor({
         entity_logical_name: step.entityLogicalName,
         record_id: step.recordId,
         data: parseDraftObjectFields(step.dataFields, "Update record step data"),
+        retry_policy: step.retryPolicy ?? null,
       };
     }
 
@@ -96,6 +104,7 @@ export function useWorkflowEditor({
         type: "delete_runtime_record",
         entity_logical_name: step.entityLogicalName,
         record_id: step.recordId,
+        retry_policy: step.retryPolicy ?? null,
       };
     }
 
@@ -118,6 +127,7 @@ export function useWorkflowEditor({
         subject: step.subject,
         body: step.body,
         html_body: step.htmlBody.trim().length > 0 ? step.htmlBody : null,
+        retry_policy: step.retryPolicy ?? null,
       };
     }
 
@@ -139,6 +149,7 @@ export function useWorkflowEditor({
           step.headerSecretRefsJson,
           "HTTP request secret headers",
         ),
+        credential: step.credential?.trim() || null,
         body:
           step.bodyMode === "none"
             ? null
@@ -153,6 +164,22 @@ export function useWorkflowEditor({
                       "HTTP request body",
                     ) as unknown)
               : (parseJsonValue(step.bodyJson, "HTTP request body") as unknown),
+        expected_status:
+          step.expectedStatus.trim().length > 0
+            ? Number.parseInt(step.expectedStatus, 10)
+            : null,
+        retry:
+          step.retryMaxAttempts.trim().length > 0
+            ? {
+                max_attempts: Number.parseInt(step.retryMaxAttempts, 10),
+                backoff_ms:
+                  step.retryBackoffMs.trim().length > 0
+                    ? Number.parseInt(step.retryBackoffMs, 10)
+                    : 1000,
+              }
+            : null,
+        output_key: draftOutputKey(step.outputKey),
+        retry_policy: step.retryPolicy ?? null,
       };
     }
 
@@ -174,7 +201,9 @@ export function useWorkflowEditor({
           step.headerSecretRefsJson,
           "Webhook secret headers",
         ),
+        credential: step.credential?.trim() || null,
         payload: parseDraftObjectFields(step.payloadFields, "Webhook payload"),
+        retry_policy: step.retryPolicy ?? null,
       };
     }
 
@@ -195,6 +224,7 @@ export function useWorkflowEditor({
         record_id: step.recordId,
         owner_id: step.ownerId,
         reason: step.reason.trim().length > 0 ? step.reason : null,
+        retry_policy: step.retryPolicy ?? null,
       };
     }
 
@@ -218,6 +248,7 @@ export function useWorkflowEditor({
         approver_id: step.approverId.trim().length > 0 ? step.approverId : null,
         reason: step.reason.trim().length > 0 ? step.reason : null,
         payload: parseDraftObjectFields(step.payloadFields, "Approval request payload"),
+        retry_policy: step.retryPolicy ?? null,
       };
     }
 
@@ -2,6 +2,7 @@ import {
   type WorkflowConditionOperatorDto,
   type WorkflowRunStepTraceResponse,
   type WorkflowStepDto,
+  type WorkflowStepRetryPolicyDto,
 } from "@/lib/api";
 
 export {
@@ -22,7 +23,9 @@ export type TriggerType =
   | "runtime_record_created"
   | "runtime_record_updated"
   | "runtime_record_deleted"
+  | "runtime_record_status_changed"
   | "schedule_tick"
+  | "scheduled"
   | "webhook_received"
   | "form_submitted"
   | "inbound_email_received"
@@ -40,6 +43,8 @@ export type DraftCreateStep = {
   type: "create_runtime_record";
   entityLogicalName: string;
   dataFields: DraftObjectField[];
+  outputKey?: string;
+  retryPolicy?: WorkflowStepRetryPolicyDto | null;
 };
 
 export type DraftUpdateStep = {
@@ -48,6 +53,7 @@ export type DraftUpdateStep = {
   entityLogicalName: string;
   recordId: string;
   dataFields: DraftObjectField[];
+  retryPolicy?: WorkflowStepRetryPolicyDto | null;
 };
 
 export type DraftDeleteStep = {
@@ -55,6 +61,7 @@ export type DraftDeleteStep = {
   type: "delete_runtime_record";
   entityLogicalName: string;
   recordId: string;
+  retryPolicy?: WorkflowStepRetryPolicyDto | null;
 };
 
 export type DraftSendEmailStep = {
@@ -64,6 +71,7 @@ export type DraftSendEmailStep = {
   subject: string;
   body: string;
   htmlBody: string;
+  retryPolicy?: WorkflowStepRetryPolicyDto | null;
 };
 
 export type DraftValueKind = "string" | "number" | "boolean" | "null" | "json";
@@ -90,12 +98,18 @@ export type DraftHttpRequestStep = {
   url: string;
   headersJson: string;
   headerSecretRefsJson: string;
+  credential?: string | null;
   bodyMode: DraftHttpBodyMode;
   bodyFields: DraftObjectField[];
   bodyArrayItems: DraftArrayItem[];
   bodyScalarKind: DraftValueKind;
   bodyScalarValue: string;
   bodyJson: string;
+  expectedStatus: string;
+  retryMaxAttempts: string;
+  retryBackoffMs: string;
+  outputKey?: string;
+  retryPolicy?: WorkflowStepRetryPolicyDto | null;
 };
 
 export type DraftWebhookStep = {
@@ -105,7 +119,9 @@ export type DraftWebhookStep = {
   event: string;
   headersJson: string;
   headerSecretRefsJson: string;
+  credential?: string | null;
   payloadFields: DraftObjectField[];
+  retryPolicy?: WorkflowStepRetryPolicyDto | null;
 };
 
 export type DraftAssignOwnerStep = {
@@ -115,6 +131,7 @@ export type DraftAssignOwnerStep = {
   recordId: string;
   ownerId: string;
   reason: string;
+  retryPolicy?: WorkflowStepRetryPolicyDto | null;
 };
 
 export type DraftApprovalRequestStep = {
@@ -127,6 +144,7 @@ export type DraftApprovalRequestStep = {
   approverId: string;
   reason: string;
   payloadFields: DraftObjectField[];
+  retryPolicy?: WorkflowStepRetryPolicyDto | null;
 };
 
 export type DraftDelayStep = {
@@ -208,7 +226,9 @@ export const TRIGGER_OPTIONS: Array<{
   { value: "runtime_record_created", label: "Record created" },
   { value: "runtime_record_updated", label: "Record updated" },
   { value: "runtime_record_deleted", label: "Record deleted" },
+  { value: "runtime_record_status_changed", label: "Record status changed" },
   { value: "schedule_tick", label: "Schedule tick" },
+  { value: "scheduled", label: "Cron schedule" },
   { value: "webhook_received", label: "Webhook received" },
   { value: "form_submitted", label: "Form submitted" },
   { value: "inbound_email_received", label: "Inbound email" },
@@ -943,6 +963,19 @@ export function collectWorkflowValidationIssues(
 
   function validateBranch(branchSteps: DraftWorkflowStep[]) {
     for (const step of branchSteps) {
+      if (
+        (step.type === "create_runtime_record" || step.type === "http_request") &&
+        (step.outputKey?.trim().length ?? 0) > 0 &&
+        !/^[a-z][a-z0-9_]{0,63}$/.test(step.outputKey?.trim() ?? "")
+      ) {
+        addIssue({
+          stepId: step.id,
+          level: "error",
+          message:
+            "Output key must start with a lowercase letter and use only lowercase letters, digits or underscores.",
+        });
+      }
+
       if (step.type === "log_message") {
         if (step.message.trim().length === 0) {
           addIssue({
@@ -1095,6 +1128,28 @@ export function collectWorkflowValidationIssues(
           });
         }
 
+        if (
+          step.expectedStatus.trim().length > 0 &&
+          !/^[1-5][0-9]{2}$/.test(step.expectedStatus.trim())
+        ) {
+          addIssue({
+            stepId: step.id,
+            level: "error",
+            message: "HTTP request expected status must be between 100 and 599.",
+          });
+        }
+
+        if (
+          step.retryMaxAttempts.trim().length > 0 &&
+          !/^([1-9]|10)$/.test(step.retryMaxAttempts.trim())
+        ) {
+          addIssue({
+            stepId: step.id,
+            level: "error",
+            message: "HTTP request retry attempts must be between 1 and 10.",
+          });
+        }
+
         try {
           const parsed = JSON.parse(step.headerSecretRefsJson) as unknown;
           const isObject = parsed && typeof parsed === "object" && !Array.isArray(parsed);
@@ -1387,6 +1442,8 @@ export function collectWorkflowValidationIssues(
       message:
         triggerType === "schedule_tick"
           ? "Schedule tick trigger requires a schedule key."
+          : triggerType === "scheduled"
+            ? "Cron schedule trigger requires a cron expression."
           : triggerType === "webhook_received"
             ? "Webhook trigger requires a webhook key."
             : triggerType === "form_submitted"
@@ -1421,6 +1478,8 @@ export function createDraftFromTransport(
       type: "create_runtime_record",
       entityLogicalName: step.entity_logical_name,
       dataFields: createDraftObjectFieldsFromValue(step.data),
+      outputKey: step.output_key ?? "",
+      retryPolicy: step.retry_policy,
     };
   }
 
@@ -1431,6 +1490,7 @@ export function createDraftFromTransport(
       entityLogicalName: step.entity_logical_name,
       recordId: step.record_id,
       dataFields: createDraftObjectFieldsFromValue(step.data),
+      retryPolicy: step.retry_policy,
     };
   }
 
@@ -1440,6 +1500,7 @@ export function createDraftFromTransport(
       type: "delete_runtime_record",
       entityLogicalName: step.entity_logical_name,
       recordId: step.record_id,
+      retryPolicy: step.retry_policy,
     };
   }
 
@@ -1451,6 +1512,7 @@ export function createDraftFromTransport(
       subject: step.subject,
       body: step.body,
       htmlBody: step.html_body ?? "",
+      retryPolicy: step.retry_policy,
     };
   }
 
@@ -1476,6 +1538,7 @@ export function createDraftFromTransport(
       url: step.url,
       headersJson: JSON.stringify(step.headers ?? {}, null, 2),
       headerSecretRefsJson: JSON.stringify(step.header_secret_refs ?? {}, null, 2),
+      credential: step.credential,
       bodyMode,
       bodyFields,
       bodyArrayItems,
@@ -1488,6 +1551,11 @@ export function createDraftFromTransport(
           ? stringifyDraftValue(step.body)
           : "",
       bodyJson: JSON.stringify(step.body ?? null, null, 2),
+      expectedStatus: step.expected_status != null ? String(step.expected_status) : "",
+      retryMaxAttempts: step.retry ? String(step.retry.max_attempts) : "",
+      retryBackoffMs: step.retry ? String(step.retry.backoff_ms) : "",
+      outputKey: step.output_key ?? "",
+      retryPolicy: step.retry_policy,
     };
   }
 
@@ -1499,7 +1567,9 @@ export function createDraftFromTransport(
       event: step.event,
       headersJson: JSON.stringify(step.headers ?? {}, null, 2),
       headerSecretRefsJson: JSON.stringify(step.header_secret_refs ?? {}, null, 2),
+      credential: step.credential,
       payloadFields: createDraftObjectFieldsFromValue(step.payload),
+      retryPolicy: step.retry_policy,
     };
   }
 
@@ -1511,6 +1581,7 @@ export function createDraftFromTransport(
       recordId: step.record_id,
       ownerId: step.owner_id,
       reason: step.reason ?? "",
+      retryPolicy: step.retry_policy,
     };
   }
 
@@ -1525,6 +1596,7 @@ export function createDraftFromTransport(
       approverId: step.approver_id ?? "",
       reason: step.reason ?? "",
       payloadFields: createDraftObjectFieldsFromValue(step.payload ?? {}),
+      retryPolicy: step.retry_policy,
     };
   }
 
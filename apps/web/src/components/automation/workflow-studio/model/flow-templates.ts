@@ -509,6 +509,9 @@ export function createDraftStep(
       bodyScalarKind: "string",
       bodyScalarValue: "",
       bodyJson: JSON.stringify(defaultBody, null, 2),
+      expectedStatus: "",
+      retryMaxAttempts: "",
+      retryBackoffMs: "",
     };
   }
 
@@ -744,6 +747,9 @@ export function createTemplateStep(
         bodyScalarKind: "string",
         bodyScalarValue: "",
         bodyJson: JSON.stringify(defaultBody, null, 2),
+        expectedStatus: "",
+        retryMaxAttempts: "",
+        retryBackoffMs: "",
       };
     case "transform_payload":
       return {
@@ -25,8 +25,10 @@ export function TriggerConfigPanel({
   const isRuntimeEntityTrigger =
     triggerType === "runtime_record_created" ||
     triggerType === "runtime_record_updated" ||
-    triggerType === "runtime_record_deleted";
+    triggerType === "runtime_record_deleted" ||
+    triggerType === "runtime_record_status_changed";
   const isScheduleTrigger = triggerType === "schedule_tick";
+  const isCronTrigger = triggerType === "scheduled";
   const isWebhookTrigger = triggerType === "webhook_received";
   const isFormTrigger = triggerType === "form_submitted";
   const isInboundEmailTrigger = triggerType === "inbound_email_received";
@@ -79,6 +81,8 @@ export function TriggerConfigPanel({
         <Label htmlFor="workflow_trigger_entity">
           {isScheduleTrigger
             ? "Schedule Key"
+            : isCronTrigger
+              ? "Cron Expression (UTC)"
             : isWebhookTrigger
               ? "Webhook Key"
               : isFormTrigger
@@ -116,6 +120,8 @@ export function TriggerConfigPanel({
           placeholder={
             isScheduleTrigger
               ? "hourly"
+              : isCronTrigger
+                ? "0 9 * * mon-fri"
               : isWebhookTrigger
                 ? "customer_created"
                 : isFormTrigger
@@ -130,6 +136,7 @@ export function TriggerConfigPanel({
           disabled={
             !isRuntimeEntityTrigger &&
             !isScheduleTrigger &&
+            !isCronTrigger &&
             !isWebhookTrigger &&
             !isFormTrigger &&
             !isInboundEmailTrigger &&
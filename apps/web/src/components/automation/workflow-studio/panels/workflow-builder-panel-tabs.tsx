@@ -610,8 +610,12 @@ export function TestTab({
         ? "Record updated"
         : triggerType === "runtime_record_deleted"
           ? "Record deleted"
+          : triggerType === "runtime_record_status_changed"
+            ? "Record status changed"
           : triggerType === "schedule_tick"
             ? "Schedule tick"
+            : triggerType === "scheduled"
+              ? "Cron schedule"
             : triggerType === "webhook_received"
               ? "Webhook received"
               : triggerType === "form_submitted"
@@ -45,7 +45,8 @@ type ActionTypeValue =
   | "set_field_value"
   | "lock_field"
   | "unlock_field"
-  | "show_error";
+  | "show_error"
+  | "prevent_save";
 
 type RuleConditionDraft = {
   field_logical_name: string;
@@ -82,6 +83,7 @@ const ACTION_OPTIONS: Array<{ value: ActionTypeValue; label: string }> = [
   { value: "lock_field", label: "Lock field" },
   { value: "unlock_field", label: "Unlock field" },
   { value: "show_error", label: "Show error" },
+  { value: "prevent_save", label: "Prevent save" },
 ];
 
 const DEFAULT_CONDITION: RuleConditionDraft = {
@@ -198,7 +200,11 @@ function parseInitialActions(initialRule: BusinessRuleResponse | null): RuleActi
 }
 
 function actionNeedsTargetField(actionType: ActionTypeValue): boolean {
-  return actionType !== "show_error";
+  return actionType !== "show_error" && actionType !== "prevent_save";
+}
+
+function actionNeedsErrorMessage(actionType: ActionTypeValue): boolean {
+  return actionType === "show_error" || actionType === "prevent_save";
 }
 
 function actionNeedsValue(actionType: ActionTypeValue): boolean {
@@ -452,7 +458,10 @@ function useBusinessRuleDesignerPanelContent({
         return;
       }
 
-      if (action.action_type === "show_error" && action.error_message.trim().length === 0) {
+      if (
+        actionNeedsErrorMessage(action.action_type) &&
+        action.error_message.trim().length === 0
+      ) {
         setErrorMessage(`Action ${String(index + 1)} requires an error message.`);
         return;
       }
@@ -465,8 +474,9 @@ function useBusinessRuleDesignerPanelContent({
         value: actionNeedsValue(action.action_type)
           ? parseValueInput(action.value_input)
           : null,
-        error_message:
-          action.action_type === "show_error" ? action.error_message.trim() : null,
+        error_message: actionNeedsErrorMessage(action.action_type)
+          ? action.error_message.trim()
+          : null,
       });
     }
 
@@ -921,7 +931,7 @@ function useBusinessRuleDesignerPanelContent({
                 </div>
               ) : null}
 
-              {action.action_type === "show_error" ? (
+              {actionNeedsErrorMessage(action.action_type) ? (
                 <div className="space-y-1">
                   <Label htmlFor={`action_error_${String(index)}`}>Error Message</Label>
                   <Input
@@ -777,6 +777,7 @@ export function useEntityWorkbenchPanel({
         link_entities: null,
         sort: parsedSort,
         filters: Object.keys(parsedFilters).length > 0 ? parsedFilters : null,
+        include_inactive: null,
       };
 
       const response = await apiFetch(`/api/runtime/${entityLogicalName}/records/query`, {
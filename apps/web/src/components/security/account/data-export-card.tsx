@@ -0,0 +1,77 @@
+import {
+  Button,
+  Card,
+  CardContent,
+  CardDescription,
+  CardHeader,
+  CardTitle,
+} from "@qryvanta/ui";
+
+import type { BackgroundJobResponse } from "@/lib/api";
+
+type DataExportCardProps = {
+  busy: boolean;
+  dataExportJob: BackgroundJobResponse | null;
+  onDownloadDataExport: () => void;
+  onRefreshDataExport: () => void;
+  onRequestDataExport: () => void;
+};
+
+export function DataExportCard({
+  busy,
+  dataExportJob,
+  onDownloadDataExport,
+  onRefreshDataExport,
+  onRequestDataExport,
+}: DataExportCardProps) {
+  const completed = dataExportJob?.status === "completed";
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Download Your Data</CardTitle>
+        <CardDescription>
+          Export your profile, sign-in history, and the records you own as a
+          JSON archive. Archives can be downloaded for seven days.
+        </CardDescription>
+      </CardHeader>
+      <CardContent className="space-y-3">
+        {dataExportJob ? (
+          <div className="space-y-2 rounded-md border border-zinc-200 bg-zinc-50 p-3 text-sm text-zinc-700">
+            <p>
+              Export {dataExportJob.status} ({dataExportJob.progress_percent}%)
+            </p>
+            {dataExportJob.status_message ? (
+              <p>{dataExportJob.status_message}</p>
+            ) : null}
+            {completed ? (
+              <Button
+                onClick={onDownloadDataExport}
+                disabled={busy}
+                className="w-full"
+              >
+                Download archive
+              </Button>
+            ) : (
+              <Button
+                variant="outline"
+                onClick={onRefreshDataExport}
+                disabled={busy}
+                className="w-full"
+              >
+                Refresh status
+              </Button>
+            )}
+          </div>
+        ) : null}
+        <Button
+          onClick={onRequestDataExport}
+          disabled={busy}
+          className="w-full"
+        >
+          Request data export
+        </Button>
+      </CardContent>
+    </Card>
+  );
+}
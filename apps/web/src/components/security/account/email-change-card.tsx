@@ -0,0 +1,101 @@
+import {
+  Button,
+  Card,
+  CardContent,
+  CardDescription,
+  CardHeader,
+  CardTitle,
+  Input,
+  Label,
+} from "@qryvanta/ui";
+
+import type { EmailChangeStatusResponse } from "@/lib/api";
+
+type EmailChangeCardProps = {
+  busy: boolean;
+  emailChange: EmailChangeStatusResponse | null;
+  emailChangePassword: string;
+  newEmail: string;
+  onCancelEmailChange: () => void;
+  onEmailChangePasswordChange: (value: string) => void;
+  onNewEmailChange: (value: string) => void;
+  onRequestEmailChange: () => void;
+};
+
+export function EmailChangeCard({
+  busy,
+  emailChange,
+  emailChangePassword,
+  newEmail,
+  onCancelEmailChange,
+  onEmailChangePasswordChange,
+  onNewEmailChange,
+  onRequestEmailChange,
+}: EmailChangeCardProps) {
+  const pending = emailChange?.pending ?? null;
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Change Email</CardTitle>
+        <CardDescription>
+          Both your current and new address must confirm the change. All
+          sessions are signed out once it completes.
+        </CardDescription>
+      </CardHeader>
+      <CardContent className="space-y-3">
+        {pending ? (
+          <div className="space-y-2 rounded-md border border-zinc-200 bg-zinc-50 p-3 text-sm text-zinc-700">
+            <p>
+              Pending change to <strong>{pending.new_email}</strong>
+            </p>
+            <p>
+              Current address:{" "}
+              {pending.current_address_confirmed ? "confirmed" : "waiting"}
+            </p>
+            <p>
+              New address:{" "}
+              {pending.new_address_confirmed ? "confirmed" : "waiting"}
+            </p>
+            <p>Expires {new Date(pending.expires_at).toLocaleString()}</p>
+            <Button
+              variant="outline"
+              onClick={onCancelEmailChange}
+              disabled={busy}
+              className="w-full"
+            >
+              Cancel email change
+            </Button>
+          </div>
+        ) : null}
+        <div className="space-y-2">
+          <Label htmlFor="new-email">New email address</Label>
+          <Input
+            id="new-email"
+            type="email"
+            value={newEmail}
+            onChange={(event) => onNewEmailChange(event.target.value)}
+          />
+        </div>
+        <div className="space-y-2">
+          <Label htmlFor="email-change-password">Current password</Label>
+          <Input
+            id="email-change-password"
+            type="password"
+            value={emailChangePassword}
+            onChange={(event) =>
+              onEmailChangePasswordChange(event.target.value)
+            }
+          />
+        </div>
+        <Button
+          onClick={onRequestEmailChange}
+          disabled={busy}
+          className="w-full"
+        >
+          Send confirmation links
+        </Button>
+      </CardContent>
+    </Card>
+  );
+}
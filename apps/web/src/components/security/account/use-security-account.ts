@@ -4,6 +4,9 @@ import { useState } from "react";
 
 import {
   apiFetch,
+  type BackgroundJobResponse,
+  type EmailChangeRequest,
+  type EmailChangeStatusResponse,
   type GenericMessageResponse,
   type InviteRequest,
   type UserIdentityResponse,
@@ -25,6 +28,11 @@ type AccountActionId =
   | "load-me"
   | "resend-verification"
   | "change-password"
+  | "request-email-change"
+  | "cancel-email-change"
+  | "request-data-export"
+  | "refresh-data-export"
+  | "download-data-export"
   | "start-mfa"
   | "confirm-mfa"
   | "disable-mfa"
@@ -53,6 +61,13 @@ export function useSecurityAccount() {
   const [currentPassword, setCurrentPassword] = useState("");
   const [newPassword, setNewPassword] = useState("");
 
+  const [newEmail, setNewEmail] = useState("");
+  const [emailChangePassword, setEmailChangePassword] = useState("");
+  const [emailChange, setEmailChange] =
+    useState<EmailChangeStatusResponse | null>(null);
+  const [dataExportJob, setDataExportJob] =
+    useState<BackgroundJobResponse | null>(null);
+
   const [enrollment, setEnrollment] = useState<TotpEnrollmentResponse | null>(
     null,
   );
@@ -82,6 +97,13 @@ export function useSecurityAccount() {
         return;
       }
       setMe((await response.json()) as UserIdentityResponse);
+
+      const emailChangeResponse = await apiFetch("/api/profile/email-change");
+      if (emailChangeResponse.ok) {
+        setEmailChange(
+          (await emailChangeResponse.json()) as EmailChangeStatusResponse,
+        );
+      }
     }).catch(() => {
       setStatus("Failed to load account.");
     });
@@ -136,6 +158,127 @@ export function useSecurityAccount() {
     });
   }
 
+  async function requestEmailChange() {
+    if (!newEmail) {
+      setStatus("New email address is required.");
+      return;
+    }
+
+    await withAction("request-email-change", async () => {
+      const payload: EmailChangeRequest = {
+        new_email: newEmail,
+        current_password: emailChangePassword || null,
+      };
+      const response = await apiFetch("/api/profile/email-change", {
+        method: "POST",
+        body: JSON.stringify(payload),
+      });
+
+      if (!response.ok) {
+        setStatus(
+          await readErrorMessage(response, "Email change request failed."),
+        );
+        return;
+      }
+
+      setEmailChange((await response.json()) as EmailChangeStatusResponse);
+      setStatus(
+        "Confirmation links were sent to your current and new email addresses.",
+      );
+      setNewEmail("");
+      setEmailChangePassword("");
+    }).catch(() => {
+      setStatus("Email change request failed.");
+    });
+  }
+
+  async function cancelEmailChange() {
+    await withAction("cancel-email-change", async () => {
+      const response = await apiFetch("/api/profile/email-change", {
+        method: "DELETE",
+      });
+
+      if (!response.ok) {
+        setStatus(
+          await readErrorMessage(response, "Failed to cancel email change."),
+        );
+        return;
+      }
+
+      setEmailChange({ pending: null });
+      setStatus("Email change cancelled.");
+    }).catch(() => {
+      setStatus("Failed to cancel email change.");
+    });
+  }
+
+  async function requestDataExport() {
+    await withAction("request-data-export", async () => {
+      const response = await apiFetch("/api/profile/data-export", {
+        method: "POST",
+      });
+
+      if (!response.ok) {
+        setStatus(
+          await readErrorMessage(response, "Data export request failed."),
+        );
+        return;
+      }
+
+      setDataExportJob((await response.json()) as BackgroundJobResponse);
+      setStatus("Your data export was queued.");
+    }).catch(() => {
+      setStatus("Data export request failed.");
+    });
+  }
+
+  async function refreshDataExport() {
+    if (!dataExportJob) {
+      return;
+    }
+
+    await withAction("refresh-data-export", async () => {
+      const response = await apiFetch(`/api/jobs/${dataExportJob.job_id}`);
+      if (!response.ok) {
+        setStatus(
+          await readErrorMessage(response, "Failed to load data export."),
+        );
+        return;
+      }
+
+      setDataExportJob((await response.json()) as BackgroundJobResponse);
+    }).catch(() => {
+      setStatus("Failed to load data export.");
+    });
+  }
+
+  async function downloadDataExport() {
+    if (!dataExportJob) {
+      return;
+    }
+
+    await withAction("download-data-export", async () => {
+      const response = await apiFetch(
+        `/api/profile/data-export/${dataExportJob.job_id}`,
+      );
+      if (!response.ok) {
+        setStatus(
+          await readErrorMessage(response, "Failed to download data export."),
+        );
+        return;
+      }
+
+      const downloadUrl = URL.createObjectURL(await response.blob());
+      const link = document.createElement("a");
+      link.href = downloadUrl;
+      link.download = `personal-data-${new Date().toISOString()}.json`;
+      link.click();
+      URL.revokeObjectURL(downloadUrl);
+    }).catch(() => {
+      setStatus("Failed to download data export.");
+    });
+  }
+
   async function startMfaEnrollment() {
     await withAction("start-mfa", async () => {
       const response = await apiFetch("/auth/mfa/totp/enroll", {
@@ -270,27 +413,38 @@ export function useSecurityAccount() {
     activeAction,
     confirmCode,
     currentPassword,
+    dataExportJob,
     disablePassword,
+    emailChange,
+    emailChangePassword,
     enrollment,
     inviteEmail,
     inviteTenantName,
     me,
+    newEmail,
     newPassword,
     newRecoveryCodes,
     regeneratePassword,
     status,
+    cancelEmailChange,
     changePassword,
     confirmMfaEnrollment,
     disableMfa,
+    downloadDataExport,
     loadMe,
+    refreshDataExport,
     regenerateRecoveryCodes,
+    requestDataExport,
+    requestEmailChange,
     resendVerification,
     sendInvite,
     setConfirmCode,
     setCurrentPassword,
     setDisablePassword,
+    setEmailChangePassword,
     setInviteEmail,
     setInviteTenantName,
+    setNewEmail,
     setNewPassword,
     setRegeneratePassword,
     startMfaEnrollment,
@@ -9,9 +9,16 @@ export const PERMISSION_OPTIONS = [
   "runtime.record.read.own",
   "runtime.record.write",
   "runtime.record.write.own",
+  "runtime.record.assign",
+  "runtime.record.reactivate",
+  "runtime.record_access.approve",
+  "runtime.record_share_link.manage",
   "security.audit.read",
   "security.role.manage",
   "security.invite.send",
+  "security.legal_hold.manage",
+  "security.dual_control.manage",
+  "security.compliance_zone.manage",
 ] as const;
 
 export type EditableFieldPermission = {
@@ -608,6 +608,11 @@ export function useStudioState({
           },
         ],
         header_fields: [],
+        script_events: {
+          on_load_actions: [],
+          on_change_fields: [],
+          on_save_validators: [],
+        },
       };
 
       const response = await apiFetch(
@@ -790,6 +795,11 @@ export function useStudioState({
         form_type: formType,
         tabs: formEditor?.tabs as unknown[],
         header_fields: normalizeHeaderFields(headerFieldsText),
+        script_events: activeFormResponse?.script_events ?? {
+          on_load_actions: [],
+          on_change_fields: [],
+          on_save_validators: [],
+        },
       };
 
       const isEdit = activeFormResponse !== null;
@@ -12,6 +12,9 @@ import {
   type AppPublishChecksResponse,
   type AppResponse,
   type EntityResponse,
+  type PublishIntentResponse,
+  type QueuePublishIntentRequest,
+  type ReviewedDraftFingerprintDto,
   type RunWorkspacePublishRequest,
   type RunWorkspacePublishResponse,
   type WorkflowResponse,
@@ -20,6 +23,9 @@ import {
   type WorkspacePublishDiffResponse,
   type WorkspacePublishHistoryEntryResponse,
 } from "@/lib/api";
+import { apiErrorMessage, readApiError } from "@/lib/api-error";
+
+const PUBLISH_LOCKED_ERROR_CODE = "conflict.publish.locked";
 
 type StudioPublishCanvasProps = {
   apps: AppResponse[];
@@ -308,6 +314,7 @@ export function StudioPublishCanvas({ apps, entities, selectedApp }: StudioPubli
         app_logical_names: workspacePublishDraft.appLogicalNames,
         workflow_logical_names: workspacePublishDraft.workflowLogicalNames,
         dry_run: true,
+        reviewed_drafts: [],
       };
 
       const response = await apiFetch("/api/publish/checks", {
@@ -340,6 +347,64 @@ export function StudioPublishCanvas({ apps, entities, selectedApp }: StudioPubli
     }
   }
 
+  function reviewedDraftFingerprints(): ReviewedDraftFingerprintDto[] {
+    return [
+      ...publishDiff.entityDiffs.map((diff) => ({
+        scope: "entity" as const,
+        logical_name: diff.entity_logical_name,
+        draft_fingerprint: diff.draft_fingerprint,
+      })),
+      ...publishDiff.appDiffs.map((diff) => ({
+        scope: "app" as const,
+        logical_name: diff.app_logical_name,
+        draft_fingerprint: diff.draft_fingerprint,
+      })),
+      ...publishDiff.workflowDiffs.map((diff) => ({
+        scope: "workflow" as const,
+        logical_name: diff.workflow_logical_name,
+        draft_fingerprint: diff.draft_fingerprint,
+      })),
+    ];
+  }
+
+  async function queuePublishIntent(
+    reviewedDrafts: ReviewedDraftFingerprintDto[],
+  ): Promise<void> {
+    const payload: QueuePublishIntentRequest = {
+      entity_logical_names: workspacePublishDraft.entityLogicalNames,
+      app_logical_names: workspacePublishDraft.appLogicalNames,
+      workflow_logical_names: workspacePublishDraft.workflowLogicalNames,
+      reviewed_drafts: reviewedDrafts,
+    };
+
+    const response = await apiFetch("/api/publish/intents", {
+      method: "POST",
+      body: JSON.stringify(payload),
+    });
+    if (!response.ok) {
+      const error = await readApiError(response);
+      setErrorMessage(apiErrorMessage(error, "Unable to queue publish."));
+      return;
+    }
+
+    const intent = (await response.json()) as PublishIntentResponse;
+    if (intent.status === "queued") {
+      setStatusMessage(
+        "Another publish holds locks on this selection. Your publish is queued and runs once it finishes.",
+      );
+      return;
+    }
+
+    await refreshPublishHistory();
+    await refreshPublishDiff();
+    await refreshWorkflows();
+    if (intent.status === "completed") {
+      setStatusMessage("Queued publish completed.");
+    } else {
+      setErrorMessage(intent.status_message ?? `Queued publish ${intent.status}.`);
+    }
+  }
+
   async function handleRunSelectivePublish(): Promise<void> {
     if (selectionValidation.selectionKey !== workspaceSelectionKey) {
       setErrorMessage("Run selection checks before publishing.");
@@ -361,6 +426,7 @@ export function StudioPublishCanvas({ apps, entities, selectedApp }: StudioPubli
         app_logical_names: workspacePublishDraft.appLogicalNames,
         workflow_logical_names: workspacePublishDraft.workflowLogicalNames,
         dry_run: false,
+        reviewed_drafts: reviewedDraftFingerprints(),
       };
 
       const response = await apiFetch("/api/publish/checks", {
@@ -368,8 +434,13 @@ export function StudioPublishCanvas({ apps, entities, selectedApp }: StudioPubli
         body: JSON.stringify(payload),
       });
       if (!response.ok) {
-        const body = (await response.json()) as { message?: string };
-        setErrorMessage(body.message ?? "Unable to run selective publish.");
+        const error = await readApiError(response);
+        if (error?.code === PUBLISH_LOCKED_ERROR_CODE) {
+          await queuePublishIntent(payload.reviewed_drafts);
+          return;
+        }
+        setErrorMessage(apiErrorMessage(error, "Unable to run selective publish."));
+        await refreshPublishDiff();
         return;
       }
 
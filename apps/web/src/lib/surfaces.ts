@@ -10,7 +10,10 @@
  * `/auth/me` which the API resolves from RBAC permissions.
  */
 
-import type { UserIdentityResponse } from "@/lib/api";
+import type {
+  CapabilityFlagsResponse,
+  UserIdentityResponse,
+} from "@/lib/api";
 
 export type SurfaceId = "admin" | "maker" | "worker";
 
@@ -113,3 +116,38 @@ export function readAccessibleSurfaces(user: UserIdentityResponse): string[] {
     (value): value is string => typeof value === "string",
   );
 }
+
+const NO_CAPABILITIES: CapabilityFlagsResponse = {
+  can_design_entities: false,
+  can_manage_security: false,
+  can_publish: false,
+  can_manage_workflows: false,
+};
+
+/**
+ * Reads derived capability flags from `/auth/me` payload safely.
+ *
+ * Flags are computed server-side from RBAC permissions so feature gating
+ * does not need to mirror permission names. Missing flags resolve to `false`.
+ */
+export function readCapabilities(
+  user: UserIdentityResponse,
+): CapabilityFlagsResponse {
+  const candidate = (
+    user as UserIdentityResponse & { capabilities?: unknown }
+  ).capabilities;
+
+  if (typeof candidate !== "object" || candidate === null) {
+    return NO_CAPABILITIES;
+  }
+
+  const flags = candidate as Partial<
+    Record<keyof CapabilityFlagsResponse, unknown>
+  >;
+  return {
+    can_design_entities: flags.can_design_entities === true,
+    can_manage_security: flags.can_manage_security === true,
+    can_publish: flags.can_publish === true,
+    can_manage_workflows: flags.can_manage_workflows === true,
+  };
+}
@@ -5,27 +5,40 @@ use qryvanta_core::{
     AppError, AppResult, SecretFingerprintRecord, TenantId, detect_reused_secret_fingerprints,
     optional_secret, required_secret,
 };
+use qryvanta_domain::WorkflowRunPriority;
 
 #[derive(Debug, Clone)]
 pub(crate) struct WorkerConfig {
+    pub(crate) production: bool,
     pub(crate) database_url: String,
     pub(crate) api_base_url: String,
     pub(crate) worker_shared_secret: String,
     pub(crate) worker_id: String,
+    pub(crate) claim_mode: WorkerClaimMode,
     pub(crate) redis_url: Option<String>,
     pub(crate) coordination_backend: WorkerCoordinationBackend,
     pub(crate) coordination_lease_seconds: u32,
     pub(crate) coordination_scope_key: String,
     pub(crate) lease_loss_strategy: WorkerLeaseLossStrategy,
+    pub(crate) shutdown_drain_timeout_seconds: u64,
     pub(crate) claim_limit: usize,
     pub(crate) max_concurrency: usize,
+    pub(crate) priority_concurrency: WorkerPriorityConcurrency,
     pub(crate) lease_seconds: u32,
     pub(crate) poll_interval_ms: u64,
+    pub(crate) poll_max_interval_ms: u64,
+    pub(crate) poll_jitter_percent: u8,
     pub(crate) partition: Option<WorkflowClaimPartition>,
     pub(crate) physical_isolation_mode: WorkerPhysicalIsolationMode,
     pub(crate) physical_isolation_tenant_id: Option<TenantId>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WorkerClaimMode {
+    Http,
+    Database,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum WorkerCoordinationBackend {
     None,
@@ -38,6 +51,15 @@ pub(crate) enum WorkerLeaseLossStrategy {
     GracefulDrain,
 }
 
+/// In-flight job budgets per run priority, each capped by `WORKER_MAX_CONCURRENCY`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct WorkerPriorityConcurrency {
+    low: usize,
+    normal: usize,
+    high: usize,
+    urgent: usize,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum WorkerPhysicalIsolationMode {
     Shared,
@@ -53,6 +75,13 @@ impl WorkerConfig {
             .trim_end_matches('/')
             .to_owned();
         let worker_shared_secret = required_env("WORKER_SHARED_SECRET")?;
+        let production = parse_production_environment()?;
+        if production && worker_shared_secret.starts_with("replace-with-") {
+            return Err(AppError::Validation(
+                "ENVIRONMENT=production refuses insecure settings: WORKER_SHARED_SECRET still uses the example placeholder"
+                    .to_owned(),
+            ));
+        }
         let deployment_environment =
             optional_secret("DEPLOYMENT_ENVIRONMENT")?.map(|value| value.trim().to_owned());
         let secret_reuse_guard_records = parse_secret_reuse_guard_records()?;
@@ -70,6 +99,11 @@ impl WorkerConfig {
             .map(|value| value.trim().to_owned())
             .filter(|value| !value.is_empty())
             .unwrap_or_else(|| format!("worker-{}", std::process::id()));
+        let claim_mode = WorkerClaimMode::parse(
+            env::var("WORKER_CLAIM_MODE")
+                .unwrap_or_else(|_| "http".to_owned())
+                .as_str(),
+        )?;
         let redis_url = optional_secret("REDIS_URL")?;
         let coordination_backend = WorkerCoordinationBackend::parse(
             env::var("WORKER_COORDINATION_BACKEND")
@@ -82,10 +116,20 @@ impl WorkerConfig {
                 .as_str(),
         )?;
         let coordination_lease_seconds = parse_env_u32("WORKER_COORDINATION_LEASE_SECONDS", 120)?;
+        let shutdown_drain_timeout_seconds =
+            parse_env_u64("WORKER_SHUTDOWN_DRAIN_TIMEOUT_SECONDS", 30)?;
         let claim_limit = parse_env_usize("WORKER_CLAIM_LIMIT", 10)?;
         let max_concurrency = parse_env_usize("WORKER_MAX_CONCURRENCY", 4)?;
+        let priority_concurrency = WorkerPriorityConcurrency::parse(
+            env::var("WORKER_PRIORITY_CONCURRENCY")
+                .unwrap_or_default()
+                .as_str(),
+            max_concurrency,
+        )?;
         let lease_seconds = parse_env_u32("WORKER_LEASE_SECONDS", 30)?;
         let poll_interval_ms = parse_env_u64("WORKER_POLL_INTERVAL_MS", 1500)?;
+        let poll_max_interval_ms = parse_env_u64("WORKER_POLL_MAX_INTERVAL_MS", 15_000)?;
+        let poll_jitter_percent = parse_env_u32("WORKER_POLL_JITTER_PERCENT", 20)?;
         let partition_count = parse_optional_env_u32("WORKER_PARTITION_COUNT")?;
         let partition_index = parse_optional_env_u32("WORKER_PARTITION_INDEX")?;
         let physical_isolation_mode = WorkerPhysicalIsolationMode::parse(
@@ -126,6 +170,22 @@ impl WorkerConfig {
             ));
         }
 
+        if poll_max_interval_ms < poll_interval_ms {
+            return Err(AppError::Validation(
+                "WORKER_POLL_MAX_INTERVAL_MS must be greater than or equal to WORKER_POLL_INTERVAL_MS"
+                    .to_owned(),
+            ));
+        }
+
+        let poll_jitter_percent = u8::try_from(poll_jitter_percent)
+            .ok()
+            .filter(|value| *value <= 100)
+            .ok_or_else(|| {
+                AppError::Validation(
+                    "WORKER_POLL_JITTER_PERCENT must be between 0 and 100".to_owned(),
+                )
+            })?;
+
         let partition = match (partition_count, partition_index) {
             (None, None) => None,
             (Some(count), Some(index)) => Some(WorkflowClaimPartition::new(count, index)?),
@@ -159,19 +219,25 @@ impl WorkerConfig {
         }
 
         Ok(Self {
+            production,
             database_url,
             api_base_url,
             worker_shared_secret,
             worker_id,
+            claim_mode,
             redis_url,
             coordination_backend,
             coordination_lease_seconds,
             coordination_scope_key,
             lease_loss_strategy,
+            shutdown_drain_timeout_seconds,
             claim_limit,
             max_concurrency,
+            priority_concurrency,
             lease_seconds,
             poll_interval_ms,
+            poll_max_interval_ms,
+            poll_jitter_percent,
             partition,
             physical_isolation_mode,
             physical_isolation_tenant_id,
@@ -190,6 +256,110 @@ impl WorkerConfig {
     }
 }
 
+impl WorkerClaimMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Http => "http",
+            Self::Database => "database",
+        }
+    }
+
+    fn parse(value: &str) -> AppResult<Self> {
+        if value.eq_ignore_ascii_case("http") {
+            return Ok(Self::Http);
+        }
+
+        if value.eq_ignore_ascii_case("database") {
+            return Ok(Self::Database);
+        }
+
+        Err(AppError::Validation(format!(
+            "WORKER_CLAIM_MODE must be either 'http' or 'database', got '{value}'"
+        )))
+    }
+}
+
+impl std::fmt::Display for WorkerClaimMode {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+impl WorkerPriorityConcurrency {
+    /// Parses `priority=budget` pairs such as `low=1,normal=4`; unlisted priorities
+    /// may use every slot.
+    fn parse(value: &str, max_concurrency: usize) -> AppResult<Self> {
+        let mut budgets = Self {
+            low: max_concurrency,
+            normal: max_concurrency,
+            high: max_concurrency,
+            urgent: max_concurrency,
+        };
+
+        for entry in value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let (priority, budget) = entry.split_once('=').ok_or_else(|| {
+                AppError::Validation(format!(
+                    "WORKER_PRIORITY_CONCURRENCY entries must use 'priority=budget', got '{entry}'"
+                ))
+            })?;
+            let priority = WorkflowRunPriority::parse(priority.trim()).map_err(|_| {
+                AppError::Validation(format!(
+                    "WORKER_PRIORITY_CONCURRENCY priority must be one of 'low', 'normal', 'high' or 'urgent', got '{}'",
+                    priority.trim()
+                ))
+            })?;
+            let budget = budget.trim().parse::<usize>().map_err(|error| {
+                AppError::Validation(format!(
+                    "invalid WORKER_PRIORITY_CONCURRENCY budget for '{}': {error}",
+                    priority.as_str()
+                ))
+            })?;
+            if budget == 0 {
+                return Err(AppError::Validation(format!(
+                    "WORKER_PRIORITY_CONCURRENCY budget for '{}' must be greater than zero",
+                    priority.as_str()
+                )));
+            }
+
+            *budgets.budget_mut(priority) = budget.min(max_concurrency);
+        }
+
+        Ok(budgets)
+    }
+
+    pub(crate) fn budget(&self, priority: WorkflowRunPriority) -> usize {
+        match priority {
+            WorkflowRunPriority::Low => self.low,
+            WorkflowRunPriority::Normal => self.normal,
+            WorkflowRunPriority::High => self.high,
+            WorkflowRunPriority::Urgent => self.urgent,
+        }
+    }
+
+    fn budget_mut(&mut self, priority: WorkflowRunPriority) -> &mut usize {
+        match priority {
+            WorkflowRunPriority::Low => &mut self.low,
+            WorkflowRunPriority::Normal => &mut self.normal,
+            WorkflowRunPriority::High => &mut self.high,
+            WorkflowRunPriority::Urgent => &mut self.urgent,
+        }
+    }
+}
+
+impl std::fmt::Display for WorkerPriorityConcurrency {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            formatter,
+            "low={},normal={},high={},urgent={}",
+            self.low, self.normal, self.high, self.urgent
+        )
+    }
+}
+
 impl WorkerCoordinationBackend {
     fn as_str(self) -> &'static str {
         match self {
@@ -300,6 +470,17 @@ fn required_env(name: &str) -> AppResult<String> {
     required_secret(name)
 }
 
+fn parse_production_environment() -> AppResult<bool> {
+    let value = env::var("ENVIRONMENT").unwrap_or_else(|_| "development".to_owned());
+    match value.trim().to_ascii_lowercase().as_str() {
+        "development" => Ok(false),
+        "production" => Ok(true),
+        _ => Err(AppError::Validation(format!(
+            "invalid ENVIRONMENT value '{value}', expected 'development' or 'production'"
+        ))),
+    }
+}
+
 fn parse_secret_reuse_guard_records() -> AppResult<Vec<SecretFingerprintRecord>> {
     let Some(raw_value) = optional_secret("SECRET_REUSE_GUARD_FINGERPRINTS")? else {
         return Ok(Vec::new());
@@ -0,0 +1,230 @@
+//! `qryvanta-worker doctor`: validates configuration and dependencies before polling.
+
+use std::env;
+use std::time::Duration;
+
+use qryvanta_core::{AppError, AppResult, DoctorReport};
+use reqwest::{StatusCode, header};
+use serde::Deserialize;
+use sqlx::postgres::PgPoolOptions;
+
+use crate::config::{WorkerClaimMode, WorkerConfig, WorkerCoordinationBackend};
+
+const DEPENDENCY_TIMEOUT: Duration = Duration::from_secs(5);
+const RECOMMENDED_SECRET_LENGTH: usize = 32;
+
+#[derive(Debug, Deserialize)]
+struct ApiVersionResponse {
+    version: String,
+}
+
+/// Runs every check, prints the report and fails when any check is blocking.
+pub(crate) async fn run() -> AppResult<()> {
+    let mut report = DoctorReport::new();
+
+    match WorkerConfig::load() {
+        Ok(config) => {
+            report.pass(
+                "configuration",
+                "environment variables parsed and validated",
+            );
+            check_shared_secret(&config, &mut report);
+            check_database(&config, &mut report).await;
+            check_coordination(&config, &mut report).await;
+            check_api(&config, &mut report).await;
+            check_smtp(&mut report).await;
+        }
+        Err(error) => report.error("configuration", error.to_string()),
+    }
+
+    println!("{}", report.render());
+    if report.has_errors() {
+        return Err(AppError::Validation(
+            "doctor found blocking configuration issues".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_shared_secret(config: &WorkerConfig, report: &mut DoctorReport) {
+    let length = config.worker_shared_secret.len();
+    if length < RECOMMENDED_SECRET_LENGTH {
+        report.warn(
+            "secrets",
+            format!(
+                "WORKER_SHARED_SECRET is {length} characters; use at least {RECOMMENDED_SECRET_LENGTH} random characters"
+            ),
+        );
+    } else {
+        report.pass("secrets", "WORKER_SHARED_SECRET length is sufficient");
+    }
+}
+
+async fn check_database(config: &WorkerConfig, report: &mut DoctorReport) {
+    match PgPoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(DEPENDENCY_TIMEOUT)
+        .connect(&config.database_url)
+        .await
+    {
+        Ok(pool) => {
+            report.pass("database", "connected to Postgres");
+            pool.close().await;
+        }
+        Err(error) => report.error(
+            "database",
+            format!("cannot connect with DATABASE_URL: {error}"),
+        ),
+    }
+}
+
+async fn check_coordination(config: &WorkerConfig, report: &mut DoctorReport) {
+    if config.coordination_backend == WorkerCoordinationBackend::None {
+        report.pass("redis", "lease coordination disabled");
+        return;
+    }
+
+    let Some(redis_url) = config.redis_url.as_deref() else {
+        report.error(
+            "redis",
+            "REDIS_URL is required when WORKER_COORDINATION_BACKEND=redis",
+        );
+        return;
+    };
+
+    let ping = async {
+        let client = redis::Client::open(redis_url).map_err(|error| error.to_string())?;
+        let mut connection = client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|error| error.to_string())?;
+        redis::cmd("PING")
+            .query_async::<String>(&mut connection)
+            .await
+            .map_err(|error| error.to_string())
+    };
+
+    match tokio::time::timeout(DEPENDENCY_TIMEOUT, ping).await {
+        Ok(Ok(_)) => report.pass("redis", "PING succeeded"),
+        Ok(Err(error)) => report.error("redis", format!("cannot reach REDIS_URL: {error}")),
+        Err(_) => report.error("redis", "REDIS_URL did not answer within 5 seconds"),
+    }
+}
+
+async fn check_api(config: &WorkerConfig, report: &mut DoctorReport) {
+    if config.claim_mode == WorkerClaimMode::Database {
+        report.pass(
+            "api",
+            "internal worker channel unused; WORKER_CLAIM_MODE=database claims from Postgres",
+        );
+        return;
+    }
+
+    let http_client = match reqwest::Client::builder()
+        .timeout(DEPENDENCY_TIMEOUT)
+        .build()
+    {
+        Ok(client) => client,
+        Err(error) => {
+            report.error("api", format!("failed to build HTTP client: {error}"));
+            return;
+        }
+    };
+
+    let response = http_client
+        .get(format!("{}/api/internal/version", config.api_base_url))
+        .header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", config.worker_shared_secret),
+        )
+        .send()
+        .await;
+
+    match response {
+        Ok(response) if response.status() == StatusCode::OK => {
+            let api_version = response
+                .json::<ApiVersionResponse>()
+                .await
+                .map(|body| body.version)
+                .unwrap_or_default();
+            let worker_version = env!("CARGO_PKG_VERSION");
+            if api_version == worker_version {
+                report.pass(
+                    "api",
+                    format!(
+                        "{} accepted WORKER_SHARED_SECRET (version {api_version})",
+                        config.api_base_url
+                    ),
+                );
+            } else {
+                report.warn(
+                    "api",
+                    format!(
+                        "API runs version '{api_version}' but this worker is {worker_version}; deploy matching versions"
+                    ),
+                );
+            }
+        }
+        Ok(response) if response.status() == StatusCode::UNAUTHORIZED => report.error(
+            "api",
+            format!(
+                "{} rejected WORKER_SHARED_SECRET; it must match the API value",
+                config.api_base_url
+            ),
+        ),
+        Ok(response) => report.error(
+            "api",
+            format!(
+                "{} answered {} on the internal channel",
+                config.api_base_url,
+                response.status()
+            ),
+        ),
+        Err(error) => report.error(
+            "api",
+            format!(
+                "cannot reach WORKER_API_BASE_URL '{}': {error}",
+                config.api_base_url
+            ),
+        ),
+    }
+}
+
+async fn check_smtp(report: &mut DoctorReport) {
+    let provider = env::var("EMAIL_PROVIDER")
+        .unwrap_or_else(|_| "console".to_owned())
+        .to_lowercase();
+    if provider != "smtp" {
+        return;
+    }
+
+    let host = env::var("SMTP_HOST").ok();
+    let port = env::var("SMTP_PORT")
+        .ok()
+        .and_then(|value| value.parse::<u16>().ok());
+    let complete = ["SMTP_USERNAME", "SMTP_PASSWORD", "SMTP_FROM_ADDRESS"]
+        .iter()
+        .all(|name| env::var(name).is_ok_and(|value| !value.trim().is_empty()));
+    let (Some(host), Some(port), true) = (host, port, complete) else {
+        report.warn(
+            "smtp",
+            "EMAIL_PROVIDER=smtp but SMTP_* variables are incomplete; workflow emails fall back to console",
+        );
+        return;
+    };
+
+    match tokio::time::timeout(
+        DEPENDENCY_TIMEOUT,
+        tokio::net::TcpStream::connect((host.as_str(), port)),
+    )
+    .await
+    {
+        Ok(Ok(_)) => report.pass("smtp", format!("{host}:{port} accepts connections")),
+        Ok(Err(error)) => report.error("smtp", format!("cannot connect to {host}:{port}: {error}")),
+        Err(_) => report.error(
+            "smtp",
+            format!("{host}:{port} did not accept a connection within 5 seconds"),
+        ),
+    }
+}
@@ -1,16 +1,26 @@
-use qryvanta_application::WorkflowService;
-use qryvanta_core::AppResult;
-use qryvanta_domain::{WorkflowDefinition, WorkflowStep};
-use tracing::{info, warn};
+use std::collections::HashMap;
+use std::time::Duration;
 
-use crate::ClaimedWorkflowJobResponse;
-use crate::config::WorkerLeaseLossStrategy;
+use qryvanta_application::{ClaimedWorkflowJob, WorkflowService};
+use qryvanta_core::{AppResult, TenantId};
+use qryvanta_domain::{WorkflowDefinition, WorkflowRunPriority, WorkflowStep};
+use qryvanta_infrastructure::continue_trace;
+use tokio::sync::watch;
+use tokio::time::Instant;
+use tracing::{Instrument, info, info_span, warn};
+
+use crate::config::{WorkerConfig, WorkerLeaseLossStrategy, WorkerPriorityConcurrency};
+use crate::shutdown::wait_for_shutdown;
+
+const SHUTDOWN_RELEASE_REASON: &str = "released by worker during shutdown";
 
 #[derive(Debug, Clone, Copy, Default)]
 pub(crate) struct JobExecutionTotals {
     pub(crate) executed_jobs: u32,
     pub(crate) failed_jobs: u32,
+    pub(crate) released_jobs: u32,
     pub(crate) cancelled_due_to_lease_loss: bool,
+    pub(crate) drained_for_shutdown: bool,
 }
 
 type WorkerExecutionTaskResult = (
@@ -20,59 +30,105 @@ type WorkerExecutionTaskResult = (
     AppResult<qryvanta_application::WorkflowRun>,
 );
 
+/// Lease fencing data kept for started jobs so they can be released on shutdown.
+struct StartedJobLease {
+    tenant_id: TenantId,
+    job_id: String,
+    lease_token: String,
+}
+
 pub(crate) async fn execute_claimed_jobs(
     workflow_service: WorkflowService,
-    worker_id: &str,
-    claimed_jobs: Vec<ClaimedWorkflowJobResponse>,
-    max_concurrency: usize,
-    lease_loss_strategy: WorkerLeaseLossStrategy,
-    mut cancel_signal: Option<tokio::sync::watch::Receiver<bool>>,
+    config: &WorkerConfig,
+    claimed_jobs: Vec<ClaimedWorkflowJob>,
+    mut cancel_signal: Option<watch::Receiver<bool>>,
+    mut shutdown_signal: watch::Receiver<bool>,
 ) -> JobExecutionTotals {
     let mut in_flight = tokio::task::JoinSet::new();
-    let mut remaining_jobs = claimed_jobs.into_iter();
+    let mut remaining_jobs = claimed_jobs;
+    let mut task_priorities: HashMap<tokio::task::Id, WorkflowRunPriority> = HashMap::new();
+    let mut in_flight_by_priority: HashMap<WorkflowRunPriority, usize> = HashMap::new();
     let mut mutating_abort_handles: Vec<tokio::task::AbortHandle> = Vec::new();
-    let worker_id = worker_id.to_owned();
-    let max_concurrency = max_concurrency.max(1);
+    let mut started_jobs: Vec<StartedJobLease> = Vec::new();
+    let worker_id = config.worker_id.clone();
+    let max_concurrency = config.max_concurrency.max(1);
+    let priority_concurrency = config.priority_concurrency;
+    let lease_loss_strategy = config.lease_loss_strategy;
+    let drain_timeout = Duration::from_secs(config.shutdown_drain_timeout_seconds);
     let mut totals = JobExecutionTotals::default();
     let mut lease_loss_detected = false;
+    let mut drain_deadline: Option<Instant> = None;
 
     loop {
-        while !lease_loss_detected && in_flight.len() < max_concurrency {
-            let Some(claimed_job) = remaining_jobs.next() else {
-                break;
-            };
+        if drain_deadline.is_none() && *shutdown_signal.borrow() {
+            drain_deadline = Some(Instant::now() + drain_timeout);
+            totals.drained_for_shutdown = true;
+            release_unstarted_jobs(
+                &workflow_service,
+                worker_id.as_str(),
+                remaining_jobs.drain(..),
+                &mut totals,
+            )
+            .await;
+            info!(
+                worker_id = %worker_id,
+                in_flight = in_flight.len(),
+                drain_timeout_seconds = drain_timeout.as_secs(),
+                "draining in-flight workflow jobs for shutdown"
+            );
+        }
 
-            let queued_job = match claimed_job.try_into_claimed_job() {
-                Ok(job) => job,
-                Err(error) => {
-                    totals.failed_jobs = totals.failed_jobs.saturating_add(1);
-                    warn!(
-                        worker_id = %worker_id,
-                        error = %error,
-                        "failed to parse claimed workflow job payload"
-                    );
-                    continue;
-                }
+        while !lease_loss_detected && drain_deadline.is_none() && in_flight.len() < max_concurrency
+        {
+            let Some(next_index) = next_job_within_budget(
+                &remaining_jobs,
+                &in_flight_by_priority,
+                priority_concurrency,
+            ) else {
+                break;
             };
+            let queued_job = remaining_jobs.remove(next_index);
+            let priority = queued_job.priority;
 
             let workflow_service = workflow_service.clone();
             let worker_id = worker_id.clone();
             let is_mutating = workflow_has_mutating_effects(&queued_job.workflow);
             let job_id = queued_job.job_id.clone();
             let run_id = queued_job.run_id.clone();
-            let abort_handle = in_flight.spawn(async move {
-                let result = workflow_service
-                    .execute_claimed_job(worker_id.as_str(), queued_job)
-                    .await;
-                (worker_id, job_id, run_id, result)
+            started_jobs.push(StartedJobLease {
+                tenant_id: queued_job.tenant_id,
+                job_id: job_id.clone(),
+                lease_token: queued_job.lease_token.clone(),
             });
+            let job_span = info_span!(
+                "workflow.job",
+                worker_id = %worker_id,
+                job_id = %job_id,
+                run_id = %run_id,
+                priority = %priority.as_str(),
+                workflow = %queued_job.workflow.logical_name().as_str()
+            );
+            if let Some(trace_context) = queued_job.trace_context.as_deref() {
+                continue_trace(&job_span, trace_context);
+            }
+            let abort_handle = in_flight.spawn(
+                async move {
+                    let result = workflow_service
+                        .execute_claimed_job(worker_id.as_str(), queued_job)
+                        .await;
+                    (worker_id, job_id, run_id, result)
+                }
+                .instrument(job_span),
+            );
+            task_priorities.insert(abort_handle.id(), priority);
+            *in_flight_by_priority.entry(priority).or_default() += 1;
 
             if is_mutating {
                 mutating_abort_handles.push(abort_handle);
             }
         }
 
-        if lease_loss_detected && in_flight.is_empty() {
+        if (lease_loss_detected || drain_deadline.is_some()) && in_flight.is_empty() {
             break;
         }
 
@@ -89,34 +145,52 @@ pub(crate) async fn execute_claimed_jobs(
             continue;
         }
 
-        let join_result = if let Some(cancel_signal) = cancel_signal.as_mut() {
-            tokio::select! {
-                changed = cancel_signal.changed() => {
-                    if changed.is_ok() && *cancel_signal.borrow() {
-                        lease_loss_detected = true;
-                        totals.cancelled_due_to_lease_loss = true;
-
-                        if matches!(lease_loss_strategy, WorkerLeaseLossStrategy::AbortAll) {
-                            cancel_in_flight_jobs(&mut in_flight, worker_id.as_str()).await;
-                            return totals;
-                        }
+        let join_result = tokio::select! {
+            cancelled = wait_for_lease_loss(cancel_signal.as_mut()), if !lease_loss_detected => {
+                if cancelled {
+                    lease_loss_detected = true;
+                    totals.cancelled_due_to_lease_loss = true;
 
-                        abort_mutating_in_flight_jobs(&mut mutating_abort_handles, worker_id.as_str());
+                    if matches!(lease_loss_strategy, WorkerLeaseLossStrategy::AbortAll) {
+                        cancel_in_flight_jobs(&mut in_flight, worker_id.as_str()).await;
+                        return totals;
                     }
-                    continue;
+
+                    abort_mutating_in_flight_jobs(&mut mutating_abort_handles, worker_id.as_str());
                 }
-                joined = in_flight.join_next() => joined,
+                continue;
+            }
+            () = wait_for_shutdown(&mut shutdown_signal), if drain_deadline.is_none() => continue,
+            () = sleep_until_deadline(drain_deadline) => {
+                abort_undrained_jobs(
+                    &workflow_service,
+                    &mut in_flight,
+                    worker_id.as_str(),
+                    &started_jobs,
+                    &mut totals,
+                )
+                .await;
+                return totals;
             }
-        } else {
-            in_flight.join_next().await
+            joined = in_flight.join_next_with_id() => joined,
         };
 
         let Some(join_result) = join_result else {
             break;
         };
 
+        let task_id = match &join_result {
+            Ok((task_id, _)) => *task_id,
+            Err(error) => error.id(),
+        };
+        if let Some(priority) = task_priorities.remove(&task_id)
+            && let Some(count) = in_flight_by_priority.get_mut(&priority)
+        {
+            *count = count.saturating_sub(1);
+        }
+
         match join_result {
-            Ok((worker_id, job_id, run_id, result)) => match result {
+            Ok((_, (worker_id, job_id, run_id, result))) => match result {
                 Ok(run) => {
                     totals.executed_jobs = totals.executed_jobs.saturating_add(1);
                     info!(
@@ -153,6 +227,24 @@ pub(crate) async fn execute_claimed_jobs(
     totals
 }
 
+/// Returns the first remaining job whose priority still has in-flight budget.
+///
+/// Claims arrive ordered by priority, so this keeps priority-then-FIFO order while
+/// letting lower priorities use slots a capped priority cannot.
+fn next_job_within_budget(
+    remaining_jobs: &[ClaimedWorkflowJob],
+    in_flight_by_priority: &HashMap<WorkflowRunPriority, usize>,
+    priority_concurrency: WorkerPriorityConcurrency,
+) -> Option<usize> {
+    remaining_jobs.iter().position(|job| {
+        in_flight_by_priority
+            .get(&job.priority)
+            .copied()
+            .unwrap_or_default()
+            < priority_concurrency.budget(job.priority)
+    })
+}
+
 fn workflow_has_mutating_effects(workflow: &WorkflowDefinition) -> bool {
     workflow.steps().iter().any(step_is_mutating)
 }
@@ -167,20 +259,136 @@ fn step_is_mutating(step: &WorkflowStep) -> bool {
         | WorkflowStep::HttpRequest { .. }
         | WorkflowStep::Webhook { .. }
         | WorkflowStep::AssignOwner { .. }
-        | WorkflowStep::ApprovalRequest { .. } => true,
-        WorkflowStep::Delay { .. } => false,
+        | WorkflowStep::ApprovalRequest { .. }
+        | WorkflowStep::InvokeWorkflow { .. } => true,
+        WorkflowStep::Delay { .. } | WorkflowStep::Wait { .. } => false,
         WorkflowStep::Condition {
             then_steps,
             else_steps,
             ..
         } => then_steps.iter().any(step_is_mutating) || else_steps.iter().any(step_is_mutating),
+        WorkflowStep::TryCatch {
+            try_steps,
+            catch_steps,
+            compensation_steps,
+        } => try_steps
+            .iter()
+            .chain(catch_steps)
+            .chain(compensation_steps)
+            .any(step_is_mutating),
     }
 }
 
-fn cancellation_requested(cancel_signal: Option<&tokio::sync::watch::Receiver<bool>>) -> bool {
+fn cancellation_requested(cancel_signal: Option<&watch::Receiver<bool>>) -> bool {
     cancel_signal.is_some_and(|receiver| *receiver.borrow())
 }
 
+/// Resolves with the new lease-loss flag; never resolves without a signal.
+async fn wait_for_lease_loss(cancel_signal: Option<&mut watch::Receiver<bool>>) -> bool {
+    let Some(cancel_signal) = cancel_signal else {
+        return std::future::pending().await;
+    };
+
+    match cancel_signal.changed().await {
+        Ok(()) => *cancel_signal.borrow(),
+        Err(_) => std::future::pending().await,
+    }
+}
+
+async fn sleep_until_deadline(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+async fn release_unstarted_jobs(
+    workflow_service: &WorkflowService,
+    worker_id: &str,
+    unstarted_jobs: impl Iterator<Item = ClaimedWorkflowJob>,
+    totals: &mut JobExecutionTotals,
+) {
+    for job in unstarted_jobs {
+        release_job_lease(
+            workflow_service,
+            worker_id,
+            job.tenant_id,
+            job.job_id.as_str(),
+            job.lease_token.as_str(),
+            totals,
+        )
+        .await;
+    }
+}
+
+async fn abort_undrained_jobs(
+    workflow_service: &WorkflowService,
+    worker_tasks: &mut tokio::task::JoinSet<WorkerExecutionTaskResult>,
+    worker_id: &str,
+    started_jobs: &[StartedJobLease],
+    totals: &mut JobExecutionTotals,
+) {
+    warn!(
+        worker_id = %worker_id,
+        in_flight = worker_tasks.len(),
+        "shutdown drain timeout elapsed; aborting in-flight workflow job tasks"
+    );
+
+    worker_tasks.abort_all();
+    while worker_tasks.join_next().await.is_some() {}
+
+    // Finished jobs no longer hold a lease, so only aborted ones are released.
+    for started_job in started_jobs {
+        release_job_lease(
+            workflow_service,
+            worker_id,
+            started_job.tenant_id,
+            started_job.job_id.as_str(),
+            started_job.lease_token.as_str(),
+            totals,
+        )
+        .await;
+    }
+}
+
+async fn release_job_lease(
+    workflow_service: &WorkflowService,
+    worker_id: &str,
+    tenant_id: TenantId,
+    job_id: &str,
+    lease_token: &str,
+    totals: &mut JobExecutionTotals,
+) {
+    match workflow_service
+        .release_claimed_job(
+            worker_id,
+            tenant_id,
+            job_id,
+            lease_token,
+            SHUTDOWN_RELEASE_REASON,
+        )
+        .await
+    {
+        Ok(true) => {
+            totals.released_jobs = totals.released_jobs.saturating_add(1);
+            info!(
+                worker_id = %worker_id,
+                job_id = %job_id,
+                "released workflow job lease for shutdown"
+            );
+        }
+        Ok(false) => {}
+        Err(error) => {
+            warn!(
+                worker_id = %worker_id,
+                job_id = %job_id,
+                error = %error,
+                "failed to release workflow job lease for shutdown; lease expires on its own"
+            );
+        }
+    }
+}
+
 fn abort_mutating_in_flight_jobs(
     abort_handles: &mut Vec<tokio::task::AbortHandle>,
     worker_id: &str,
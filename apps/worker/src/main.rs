@@ -7,19 +7,22 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use qryvanta_application::{
-    AuthorizationService, EmailService, MetadataService, WorkflowExecutionMode, WorkflowService,
-    WorkflowWorkerLease, WorkflowWorkerLeaseCoordinator,
+    AuthorizationService, ClaimedWorkflowJob, EmailService, MetadataService,
+    RuntimeRecordWorkflowEventDrainResult, WorkflowExecutionMode, WorkflowService,
+    WorkflowWorkerHeartbeatInput, WorkflowWorkerLease, WorkflowWorkerLeaseCoordinator,
 };
 use qryvanta_core::{AppError, AppResult, TenantId};
 use qryvanta_domain::{
-    WorkflowDefinition, WorkflowDefinitionInput, WorkflowLifecycleState, WorkflowStep,
-    WorkflowTrigger,
+    WorkflowDefinition, WorkflowDefinitionInput, WorkflowLifecycleState, WorkflowRunPriority,
+    WorkflowStep, WorkflowTrigger,
 };
 use qryvanta_infrastructure::{
-    ConsoleEmailService, HttpWorkflowActionDispatcher, PostgresAuditRepository,
-    PostgresAuthorizationRepository, PostgresMetadataRepository, PostgresWorkflowRepository,
+    ConsoleEmailService, HttpWorkflowActionDispatcher, OpenTelemetryWorkflowTraceContextSource,
+    PostgresAuditRepository, PostgresAuthorizationRepository, PostgresContactConsentRepository,
+    PostgresFieldChangeApprovalRepository, PostgresLegalHoldRepository, PostgresMetadataRepository,
+    PostgresRecordAccessRepository, PostgresWorkflowRepository,
     RedisWorkflowWorkerLeaseCoordinator, SmtpEmailConfig, SmtpEmailService,
-    TokioWorkflowDelayService,
+    TokioWorkflowDelayService, WORKFLOW_WORKER_LEASE_KEY_PREFIX,
 };
 
 use reqwest::header;
@@ -28,13 +31,17 @@ use serde_json::Value;
 use sqlx::PgPool;
 use sqlx::postgres::PgPoolOptions;
 use tracing::{info, warn};
-use tracing_subscriber::EnvFilter;
 
 mod config;
+mod doctor;
 mod job_execution;
+mod polling;
+mod shutdown;
 
-use config::{WorkerConfig, WorkerCoordinationBackend};
+use config::{WorkerClaimMode, WorkerConfig, WorkerCoordinationBackend};
 use job_execution::execute_claimed_jobs;
+use polling::{AdaptivePoller, ClaimAdvice};
+use shutdown::{shutdown_requested, sleep_unless_shutdown, spawn_shutdown_listener};
 
 #[derive(Debug, Serialize)]
 struct ClaimWorkflowJobsRequest {
@@ -70,6 +77,10 @@ struct WorkerHeartbeatRequest {
 #[derive(Debug, Deserialize)]
 struct ClaimedWorkflowJobsResponse {
     jobs: Vec<ClaimedWorkflowJobResponse>,
+    #[serde(default)]
+    advised_limit: Option<usize>,
+    #[serde(default)]
+    retry_after_ms: Option<u64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -94,14 +105,23 @@ struct ClaimedWorkflowJobResponse {
     workflow_max_attempts: u16,
     workflow_is_enabled: bool,
     trigger_payload: Value,
+    #[serde(default)]
+    continuation: Option<qryvanta_application::WorkflowRunContinuation>,
+    #[serde(default)]
+    trace_context: Option<String>,
+    #[serde(default)]
+    priority: WorkflowRunPriority,
 }
 
 #[tokio::main]
 async fn main() -> Result<(), AppError> {
     dotenvy::dotenv().ok();
-    init_tracing();
+    let _tracing_guard = qryvanta_infrastructure::init_tracing("qryvanta-worker")?;
     let args = env::args().collect::<Vec<_>>();
     let command = args.get(1).map(String::as_str);
+    if command == Some("doctor") {
+        return doctor::run().await;
+    }
 
     let config = WorkerConfig::load()?;
     if command == Some("print-secret-fingerprints") {
@@ -109,7 +129,7 @@ async fn main() -> Result<(), AppError> {
         return Ok(());
     }
     let pool = connect_pool(config.database_url.as_str()).await?;
-    let workflow_service = build_workflow_service(pool);
+    let workflow_service = build_workflow_service(pool, config.production)?;
     let lease_coordinator = build_lease_coordinator(&config)?;
     let http_client = reqwest::Client::builder()
         .timeout(Duration::from_secs(15))
@@ -118,6 +138,7 @@ async fn main() -> Result<(), AppError> {
 
     info!(
         worker_id = %config.worker_id,
+        claim_mode = %config.claim_mode,
         api_base_url = %config.api_base_url,
         coordination_backend = %config.coordination_backend,
         coordination_scope_key = %config.coordination_scope_key,
@@ -125,16 +146,29 @@ async fn main() -> Result<(), AppError> {
         lease_loss_strategy = %config.lease_loss_strategy,
         claim_limit = config.claim_limit,
         max_concurrency = config.max_concurrency,
+        priority_concurrency = %config.priority_concurrency,
         lease_seconds = config.lease_seconds,
         poll_interval_ms = config.poll_interval_ms,
+        poll_max_interval_ms = config.poll_max_interval_ms,
+        poll_jitter_percent = config.poll_jitter_percent,
         partition_count = config.partition.map(|value| value.partition_count()),
         partition_index = config.partition.map(|value| value.partition_index()),
         physical_isolation_mode = %config.physical_isolation_mode,
         physical_isolation_tenant_id = config.physical_isolation_tenant_id.map(|value| value.to_string()),
+        shutdown_drain_timeout_seconds = config.shutdown_drain_timeout_seconds,
         "qryvanta-worker started"
     );
 
-    loop {
+    let mut shutdown_signal = spawn_shutdown_listener(config.worker_id.clone());
+
+    let mut poller = AdaptivePoller::new(
+        config.claim_limit,
+        config.poll_interval_ms,
+        config.poll_max_interval_ms,
+        config.poll_jitter_percent,
+    );
+
+    while !shutdown_requested(&shutdown_signal) {
         let lease = match &lease_coordinator {
             Some(coordinator) => match coordinator
                 .try_acquire_lease(
@@ -151,7 +185,12 @@ async fn main() -> Result<(), AppError> {
                         scope_key = %config.coordination_scope_key,
                         "worker lease not acquired; another worker currently owns scope"
                     );
-                    tokio::time::sleep(Duration::from_millis(config.poll_interval_ms)).await;
+                    sleep_unless_shutdown(
+                        &poller,
+                        Duration::from_millis(config.poll_interval_ms),
+                        &mut shutdown_signal,
+                    )
+                    .await;
                     continue;
                 }
                 Err(error) => {
@@ -160,7 +199,12 @@ async fn main() -> Result<(), AppError> {
                         error = %error,
                         "failed to acquire worker coordination lease"
                     );
-                    tokio::time::sleep(Duration::from_millis(config.poll_interval_ms)).await;
+                    sleep_unless_shutdown(
+                        &poller,
+                        Duration::from_millis(config.poll_interval_ms),
+                        &mut shutdown_signal,
+                    )
+                    .await;
                     continue;
                 }
             },
@@ -188,7 +232,9 @@ async fn main() -> Result<(), AppError> {
             &http_client,
             workflow_service.clone(),
             &config,
+            &mut poller,
             cycle_cancel_rx,
+            shutdown_signal.clone(),
         )
         .await;
 
@@ -230,23 +276,42 @@ async fn main() -> Result<(), AppError> {
             );
         }
 
-        if let Err(error) = cycle_result {
-            warn!(
-                worker_id = %config.worker_id,
-                error = %error,
-                "failed to claim workflow jobs"
-            );
-            tokio::time::sleep(Duration::from_millis(config.poll_interval_ms)).await;
+        match cycle_result {
+            Ok(next_delay) => {
+                sleep_unless_shutdown(&poller, next_delay, &mut shutdown_signal).await
+            }
+            Err(error) => {
+                warn!(
+                    worker_id = %config.worker_id,
+                    error = %error,
+                    "failed to claim workflow jobs"
+                );
+                let retry_delay = poller.record_failure();
+                sleep_unless_shutdown(&poller, retry_delay, &mut shutdown_signal).await;
+            }
         }
     }
+
+    if let Err(error) = send_heartbeat(&http_client, &workflow_service, &config, 0, 0, 0).await {
+        warn!(
+            worker_id = %config.worker_id,
+            error = %error,
+            "failed to publish final worker heartbeat"
+        );
+    }
+    info!(worker_id = %config.worker_id, "qryvanta-worker stopped");
+
+    Ok(())
 }
 
 async fn run_worker_cycle(
     http_client: &reqwest::Client,
     workflow_service: WorkflowService,
     config: &WorkerConfig,
+    poller: &mut AdaptivePoller,
     cancel_signal: Option<tokio::sync::watch::Receiver<bool>>,
-) -> AppResult<()> {
+    shutdown_signal: tokio::sync::watch::Receiver<bool>,
+) -> AppResult<Duration> {
     let schedule_result = workflow_service
         .dispatch_due_schedule_ticks(
             config.worker_id.as_str(),
@@ -267,7 +332,8 @@ async fn run_worker_cycle(
         );
     }
 
-    let drain_result = drain_runtime_record_workflow_events(http_client, config).await?;
+    let drain_result =
+        drain_runtime_record_workflow_events(http_client, &workflow_service, config).await?;
     if drain_result.claimed_events > 0
         || drain_result.dispatched_workflows > 0
         || drain_result.released_events > 0
@@ -281,41 +347,73 @@ async fn run_worker_cycle(
         );
     }
 
-    let claimed_jobs = claim_jobs(http_client, config).await?;
-    let claimed_job_count = u32::try_from(claimed_jobs.len()).unwrap_or(u32::MAX);
+    if shutdown_requested(&shutdown_signal) {
+        return Ok(Duration::ZERO);
+    }
+
+    let claim_limit = poller.claim_limit();
+    let ClaimedJobBatch {
+        jobs: claimed_jobs,
+        rejected_jobs,
+        advice: claim_advice,
+    } = claim_jobs(http_client, &workflow_service, config, claim_limit).await?;
+    let claimed_count = claimed_jobs.len().saturating_add(rejected_jobs);
+    let claimed_job_count = u32::try_from(claimed_count).unwrap_or(u32::MAX);
+    let rejected_jobs = u32::try_from(rejected_jobs).unwrap_or(u32::MAX);
+    let next_delay = poller.record_claim(claim_limit, claimed_count, claim_advice);
 
     if claimed_jobs.is_empty() {
-        if let Err(error) = send_heartbeat(http_client, config, 0, 0, 0).await {
+        if let Err(error) = send_heartbeat(
+            http_client,
+            &workflow_service,
+            config,
+            claimed_job_count,
+            0,
+            rejected_jobs,
+        )
+        .await
+        {
             warn!(
                 worker_id = %config.worker_id,
                 error = %error,
                 "failed to publish worker heartbeat"
             );
         }
-        tokio::time::sleep(Duration::from_millis(config.poll_interval_ms)).await;
-        return Ok(());
+        return Ok(next_delay);
     }
 
     info!(
         worker_id = %config.worker_id,
-        claimed_count = claimed_jobs.len(),
+        claimed_count,
+        claim_limit,
+        advised_limit = claim_advice.map(|advice| advice.advised_limit),
+        retry_after_ms = claim_advice.map(|advice| advice.retry_after_ms),
         "claimed workflow jobs"
     );
 
     let execution_totals = execute_claimed_jobs(
-        workflow_service,
-        config.worker_id.as_str(),
+        workflow_service.clone(),
+        config,
         claimed_jobs,
-        config.max_concurrency,
-        config.lease_loss_strategy,
         cancel_signal,
+        shutdown_signal,
     )
     .await;
     let executed_jobs = execution_totals.executed_jobs;
-    let failed_jobs = execution_totals.failed_jobs;
+    let failed_jobs = execution_totals.failed_jobs.saturating_add(rejected_jobs);
+    if execution_totals.drained_for_shutdown {
+        info!(
+            worker_id = %config.worker_id,
+            executed_jobs,
+            failed_jobs,
+            released_jobs = execution_totals.released_jobs,
+            "drained claimed workflow jobs for shutdown"
+        );
+    }
 
     if let Err(error) = send_heartbeat(
         http_client,
+        &workflow_service,
         config,
         claimed_job_count,
         executed_jobs,
@@ -337,7 +435,7 @@ async fn run_worker_cycle(
         ));
     }
 
-    Ok(())
+    Ok(next_delay)
 }
 
 fn build_lease_coordinator(
@@ -357,7 +455,7 @@ fn build_lease_coordinator(
 
             Ok(Some(Arc::new(RedisWorkflowWorkerLeaseCoordinator::new(
                 redis_client,
-                "qryvanta:workflow_worker_lease",
+                WORKFLOW_WORKER_LEASE_KEY_PREFIX,
             ))))
         }
     }
@@ -418,19 +516,29 @@ async fn connect_pool(database_url: &str) -> AppResult<PgPool> {
         .map_err(|error| AppError::Internal(format!("failed to connect to database: {error}")))
 }
 
-fn build_workflow_service(pool: PgPool) -> WorkflowService {
+fn build_workflow_service(pool: PgPool, production: bool) -> AppResult<WorkflowService> {
     let metadata_repository = Arc::new(PostgresMetadataRepository::new(pool.clone()));
     let workflow_repository = Arc::new(PostgresWorkflowRepository::new(pool.clone()));
     let authorization_repository = Arc::new(PostgresAuthorizationRepository::new(pool.clone()));
+    let legal_hold_repository = Arc::new(PostgresLegalHoldRepository::new(pool.clone()));
+    let field_change_approval_repository =
+        Arc::new(PostgresFieldChangeApprovalRepository::new(pool.clone()));
+    let record_access_repository = Arc::new(PostgresRecordAccessRepository::new(pool.clone()));
+    let contact_consent_repository = Arc::new(PostgresContactConsentRepository::new(pool.clone()));
     let audit_repository = Arc::new(PostgresAuditRepository::new(pool));
     let authorization_service =
         AuthorizationService::new(authorization_repository, audit_repository.clone());
-    let runtime_record_service = Arc::new(MetadataService::new(
-        metadata_repository,
-        authorization_service.clone(),
-        audit_repository.clone(),
-    ));
-    let workflow_email_service = build_worker_email_service();
+    let runtime_record_service = Arc::new(
+        MetadataService::new(
+            metadata_repository,
+            authorization_service.clone(),
+            audit_repository.clone(),
+        )
+        .with_legal_hold_repository(legal_hold_repository)
+        .with_field_change_approval_repository(field_change_approval_repository)
+        .with_record_access_repository(record_access_repository),
+    );
+    let workflow_email_service = build_worker_email_service(production)?;
     let workflow_action_dispatcher = Arc::new(HttpWorkflowActionDispatcher::new(
         reqwest::Client::new(),
         workflow_email_service,
@@ -438,18 +546,25 @@ fn build_workflow_service(pool: PgPool) -> WorkflowService {
         250,
     ));
 
-    WorkflowService::new(
+    Ok(WorkflowService::new(
         authorization_service,
-        workflow_repository,
+        workflow_repository.clone(),
         runtime_record_service,
         audit_repository,
         WorkflowExecutionMode::Queued,
     )
     .with_action_dispatcher(workflow_action_dispatcher)
+    .with_credential_repository(workflow_repository)
+    .with_contact_consent_repository(contact_consent_repository)
     .with_delay_service(Arc::new(TokioWorkflowDelayService))
+    .with_trace_context_source(Arc::new(OpenTelemetryWorkflowTraceContextSource)))
 }
 
-fn build_worker_email_service() -> Arc<dyn EmailService> {
+/// Builds the workflow email adapter.
+///
+/// Outside production an unusable SMTP setup falls back to console logging;
+/// in production it refuses to start instead.
+fn build_worker_email_service(production: bool) -> AppResult<Arc<dyn EmailService>> {
     let provider = env::var("EMAIL_PROVIDER")
         .unwrap_or_else(|_| "console".to_owned())
         .to_lowercase();
@@ -475,7 +590,8 @@ fn build_worker_email_service() -> Arc<dyn EmailService> {
             };
 
             match SmtpEmailService::new(config) {
-                Ok(service) => return Arc::new(service),
+                Ok(service) => return Ok(Arc::new(service)),
+                Err(error) if production => return Err(error),
                 Err(error) => {
                     warn!(
                         error = %error,
@@ -483,20 +599,66 @@ fn build_worker_email_service() -> Arc<dyn EmailService> {
                     );
                 }
             }
+        } else if production {
+            return Err(AppError::Validation(
+                "EMAIL_PROVIDER=smtp but SMTP_* environment variables are incomplete".to_owned(),
+            ));
         } else {
             warn!(
                 "EMAIL_PROVIDER=smtp but SMTP_* environment variables are incomplete; falling back to console"
             );
         }
+    } else if production {
+        return Err(AppError::Validation(
+            "ENVIRONMENT=production refuses insecure settings: EMAIL_PROVIDER=console is not allowed; configure EMAIL_PROVIDER=smtp"
+                .to_owned(),
+        ));
     }
 
-    Arc::new(ConsoleEmailService::new())
+    Ok(Arc::new(ConsoleEmailService::new()))
+}
+
+/// Jobs leased by one claim, in the shape the execution loop consumes.
+struct ClaimedJobBatch {
+    jobs: Vec<ClaimedWorkflowJob>,
+    /// Claimed jobs whose payload could not be parsed; their leases expire.
+    rejected_jobs: usize,
+    advice: Option<ClaimAdvice>,
 }
 
 async fn claim_jobs(
+    http_client: &reqwest::Client,
+    workflow_service: &WorkflowService,
+    config: &WorkerConfig,
+    limit: usize,
+) -> AppResult<ClaimedJobBatch> {
+    match config.claim_mode {
+        WorkerClaimMode::Http => claim_jobs_over_http(http_client, config, limit).await,
+        WorkerClaimMode::Database => {
+            let jobs = workflow_service
+                .claim_jobs_for_worker(
+                    config.worker_id.as_str(),
+                    limit,
+                    config.lease_seconds,
+                    config.partition,
+                    config.physical_isolation_tenant_id,
+                )
+                .await?;
+
+            Ok(ClaimedJobBatch {
+                jobs,
+                rejected_jobs: 0,
+                advice: None,
+            })
+        }
+    }
+}
+
+async fn claim_jobs_over_http(
     http_client: &reqwest::Client,
     config: &WorkerConfig,
-) -> AppResult<Vec<ClaimedWorkflowJobResponse>> {
+    limit: usize,
+) -> AppResult<ClaimedJobBatch> {
     let endpoint = format!("{}/api/internal/worker/jobs/claim", config.api_base_url);
     let response = http_client
         .post(endpoint)
@@ -510,7 +672,7 @@ async fn claim_jobs(
             next_worker_trace_id(config.worker_id.as_str()),
         )
         .json(&ClaimWorkflowJobsRequest {
-            limit: config.claim_limit,
+            limit,
             lease_seconds: config.lease_seconds,
             partition_count: config.partition.map(|value| value.partition_count()),
             partition_index: config.partition.map(|value| value.partition_index()),
@@ -545,13 +707,51 @@ async fn claim_jobs(
             ))
         })?;
 
-    Ok(response_body.jobs)
+    let advice = response_body
+        .advised_limit
+        .map(|advised_limit| ClaimAdvice {
+            advised_limit,
+            retry_after_ms: response_body.retry_after_ms.unwrap_or(0),
+        });
+
+    let mut batch = ClaimedJobBatch {
+        jobs: Vec::with_capacity(response_body.jobs.len()),
+        rejected_jobs: 0,
+        advice,
+    };
+    for claimed_job in response_body.jobs {
+        match claimed_job.try_into_claimed_job() {
+            Ok(job) => batch.jobs.push(job),
+            Err(error) => {
+                batch.rejected_jobs = batch.rejected_jobs.saturating_add(1);
+                warn!(
+                    worker_id = %config.worker_id,
+                    error = %error,
+                    "failed to parse claimed workflow job payload"
+                );
+            }
+        }
+    }
+
+    Ok(batch)
 }
 
 async fn drain_runtime_record_workflow_events(
     http_client: &reqwest::Client,
+    workflow_service: &WorkflowService,
     config: &WorkerConfig,
-) -> AppResult<DrainRuntimeRecordWorkflowEventsResponse> {
+) -> AppResult<RuntimeRecordWorkflowEventDrainResult> {
+    if config.claim_mode == WorkerClaimMode::Database {
+        return workflow_service
+            .drain_runtime_record_workflow_events_for_worker(
+                config.worker_id.as_str(),
+                config.claim_limit,
+                config.lease_seconds,
+                config.physical_isolation_tenant_id,
+            )
+            .await;
+    }
+
     let endpoint = format!(
         "{}/api/internal/worker/runtime-events/drain",
         config.api_base_url
@@ -594,23 +794,44 @@ async fn drain_runtime_record_workflow_events(
         )));
     }
 
-    response
+    let response_body = response
         .json::<DrainRuntimeRecordWorkflowEventsResponse>()
         .await
         .map_err(|error| {
             AppError::Internal(format!(
                 "failed to parse runtime workflow event drain response body: {error}"
             ))
-        })
+        })?;
+
+    Ok(RuntimeRecordWorkflowEventDrainResult {
+        claimed_events: response_body.claimed_events,
+        dispatched_workflows: response_body.dispatched_workflows,
+        released_events: response_body.released_events,
+    })
 }
 
 async fn send_heartbeat(
     http_client: &reqwest::Client,
+    workflow_service: &WorkflowService,
     config: &WorkerConfig,
     claimed_jobs: u32,
     executed_jobs: u32,
     failed_jobs: u32,
 ) -> AppResult<()> {
+    if config.claim_mode == WorkerClaimMode::Database {
+        return workflow_service
+            .heartbeat_worker(
+                config.worker_id.as_str(),
+                WorkflowWorkerHeartbeatInput {
+                    claimed_jobs,
+                    executed_jobs,
+                    failed_jobs,
+                    partition: config.partition,
+                },
+            )
+            .await;
+    }
+
     let endpoint = format!("{}/api/internal/worker/heartbeat", config.api_base_url);
     let response = http_client
         .post(endpoint)
@@ -679,7 +900,8 @@ impl ClaimedWorkflowJobResponse {
                 WorkflowLifecycleState::Disabled
             },
             Some(self.workflow_version),
-        )?;
+        )?
+        .with_priority(self.priority);
 
         Ok(qryvanta_application::ClaimedWorkflowJob {
             job_id: self.job_id,
@@ -689,20 +911,13 @@ impl ClaimedWorkflowJobResponse {
             workflow,
             trigger_payload: self.trigger_payload,
             lease_token: self.lease_token,
+            continuation: self.continuation,
+            trace_context: self.trace_context,
+            priority: self.priority,
         })
     }
 }
 
-fn init_tracing() {
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-
-    tracing_subscriber::fmt()
-        .with_env_filter(env_filter)
-        .with_target(false)
-        .compact()
-        .init();
-}
-
 fn print_secret_fingerprints(config: &WorkerConfig) -> Result<(), AppError> {
     let deployment_environment = env::var("DEPLOYMENT_ENVIRONMENT")
         .ok()
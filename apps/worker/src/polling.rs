@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+/// Server-advised claim pacing parsed from the claim endpoint response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ClaimAdvice {
+    pub(crate) advised_limit: usize,
+    pub(crate) retry_after_ms: u64,
+}
+
+/// Adaptive poll pacing for the worker claim loop.
+///
+/// Full batches poll again immediately, partial batches wait the base
+/// interval, and empty batches back off exponentially up to the configured
+/// ceiling. Server advice can only shrink the claim limit and lengthen the
+/// delay. Every delay is jittered so a fleet started together spreads out.
+#[derive(Debug, Clone)]
+pub(crate) struct AdaptivePoller {
+    configured_claim_limit: usize,
+    advised_claim_limit: Option<usize>,
+    base_interval_ms: u64,
+    max_interval_ms: u64,
+    jitter_percent: u8,
+    idle_cycles: u32,
+}
+
+impl AdaptivePoller {
+    pub(crate) fn new(
+        configured_claim_limit: usize,
+        base_interval_ms: u64,
+        max_interval_ms: u64,
+        jitter_percent: u8,
+    ) -> Self {
+        Self {
+            configured_claim_limit: configured_claim_limit.max(1),
+            advised_claim_limit: None,
+            base_interval_ms,
+            max_interval_ms: max_interval_ms.max(base_interval_ms),
+            jitter_percent: jitter_percent.min(100),
+            idle_cycles: 0,
+        }
+    }
+
+    /// Returns the claim limit to request on the next claim call.
+    pub(crate) fn claim_limit(&self) -> usize {
+        match self.advised_claim_limit {
+            Some(advised_limit) => self.configured_claim_limit.min(advised_limit.max(1)),
+            None => self.configured_claim_limit,
+        }
+    }
+
+    /// Records one claim result and returns the un-jittered delay before the next claim.
+    pub(crate) fn record_claim(
+        &mut self,
+        requested_limit: usize,
+        claimed_jobs: usize,
+        advice: Option<ClaimAdvice>,
+    ) -> Duration {
+        self.advised_claim_limit = advice.map(|value| value.advised_limit);
+
+        let delay_ms = if claimed_jobs == 0 {
+            self.idle_cycles = self.idle_cycles.saturating_add(1);
+            self.idle_backoff_ms()
+        } else if claimed_jobs >= requested_limit {
+            self.idle_cycles = 0;
+            0
+        } else {
+            self.idle_cycles = 0;
+            self.base_interval_ms
+        };
+
+        let retry_after_ms = advice.map_or(0, |value| value.retry_after_ms);
+        Duration::from_millis(delay_ms.max(retry_after_ms))
+    }
+
+    /// Records one failed cycle and returns the un-jittered delay before retrying.
+    pub(crate) fn record_failure(&mut self) -> Duration {
+        self.idle_cycles = self.idle_cycles.saturating_add(1);
+        Duration::from_millis(self.idle_backoff_ms())
+    }
+
+    /// Applies symmetric jitter using one random sample in `0..=u32::MAX`.
+    pub(crate) fn jitter(&self, delay: Duration, random_sample: u32) -> Duration {
+        if delay.is_zero() || self.jitter_percent == 0 {
+            return delay;
+        }
+
+        let delay_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+        let spread_ms = delay_ms.saturating_mul(u64::from(self.jitter_percent)) / 100;
+        let offset_ms = spread_ms
+            .saturating_mul(2)
+            .saturating_mul(u64::from(random_sample))
+            / u64::from(u32::MAX);
+
+        Duration::from_millis(delay_ms.saturating_sub(spread_ms).saturating_add(offset_ms))
+    }
+
+    fn idle_backoff_ms(&self) -> u64 {
+        let exponent = self.idle_cycles.saturating_sub(1).min(16);
+        self.base_interval_ms
+            .saturating_mul(1_u64 << exponent)
+            .min(self.max_interval_ms)
+    }
+}
+
+/// Sleeps for the jittered delay.
+pub(crate) async fn sleep_with_jitter(poller: &AdaptivePoller, delay: Duration) {
+    let random_sample =
+        u32::try_from(uuid::Uuid::new_v4().as_u128() & u128::from(u32::MAX)).unwrap_or(0);
+    let jittered = poller.jitter(delay, random_sample);
+    if !jittered.is_zero() {
+        tokio::time::sleep(jittered).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_claims_back_off_exponentially_up_to_ceiling() {
+        let mut poller = AdaptivePoller::new(10, 1_000, 5_000, 0);
+
+        assert_eq!(
+            poller.record_claim(10, 0, None),
+            Duration::from_millis(1_000)
+        );
+        assert_eq!(
+            poller.record_claim(10, 0, None),
+            Duration::from_millis(2_000)
+        );
+        assert_eq!(
+            poller.record_claim(10, 0, None),
+            Duration::from_millis(4_000)
+        );
+        assert_eq!(
+            poller.record_claim(10, 0, None),
+            Duration::from_millis(5_000)
+        );
+        assert_eq!(poller.record_claim(10, 10, None), Duration::ZERO);
+        assert_eq!(
+            poller.record_claim(10, 3, None),
+            Duration::from_millis(1_000)
+        );
+    }
+
+    #[test]
+    fn server_advice_shrinks_limit_and_extends_delay() {
+        let mut poller = AdaptivePoller::new(10, 1_000, 5_000, 0);
+        let advice = ClaimAdvice {
+            advised_limit: 2,
+            retry_after_ms: 3_000,
+        };
+
+        assert_eq!(
+            poller.record_claim(10, 10, Some(advice)),
+            Duration::from_millis(3_000)
+        );
+        assert_eq!(poller.claim_limit(), 2);
+
+        poller.record_claim(2, 2, None);
+        assert_eq!(poller.claim_limit(), 10);
+    }
+
+    #[test]
+    fn jitter_stays_within_configured_spread() {
+        let poller = AdaptivePoller::new(10, 1_000, 5_000, 20);
+        let delay = Duration::from_millis(1_000);
+
+        assert_eq!(poller.jitter(delay, 0), Duration::from_millis(800));
+        assert_eq!(poller.jitter(delay, u32::MAX), Duration::from_millis(1_200));
+        assert_eq!(poller.jitter(Duration::ZERO, u32::MAX), Duration::ZERO);
+    }
+}
@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tracing::{info, warn};
+
+use crate::polling::{AdaptivePoller, sleep_with_jitter};
+
+/// Spawns a task that flips the returned signal on `SIGTERM` or `SIGINT`.
+pub(crate) fn spawn_shutdown_listener(worker_id: String) -> watch::Receiver<bool> {
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    tokio::spawn(async move {
+        let signal = wait_for_termination_signal().await;
+        info!(
+            worker_id = %worker_id,
+            signal,
+            "shutdown signal received; worker stops claiming workflow jobs"
+        );
+        let _ = shutdown_tx.send(true);
+    });
+    shutdown_rx
+}
+
+pub(crate) fn shutdown_requested(shutdown_signal: &watch::Receiver<bool>) -> bool {
+    *shutdown_signal.borrow()
+}
+
+/// Resolves once shutdown was requested; never resolves if the listener is gone.
+pub(crate) async fn wait_for_shutdown(shutdown_signal: &mut watch::Receiver<bool>) {
+    if shutdown_signal
+        .wait_for(|requested| *requested)
+        .await
+        .is_err()
+    {
+        std::future::pending::<()>().await;
+    }
+}
+
+/// Sleeps for the jittered delay unless shutdown is requested first.
+pub(crate) async fn sleep_unless_shutdown(
+    poller: &AdaptivePoller,
+    delay: Duration,
+    shutdown_signal: &mut watch::Receiver<bool>,
+) {
+    tokio::select! {
+        () = sleep_with_jitter(poller, delay) => {}
+        () = wait_for_shutdown(shutdown_signal) => {}
+    }
+}
+
+#[cfg(unix)]
+async fn wait_for_termination_signal() -> &'static str {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    match signal(SignalKind::terminate()) {
+        Ok(mut sigterm) => tokio::select! {
+            _ = sigterm.recv() => "SIGTERM",
+            () = wait_for_ctrl_c() => "SIGINT",
+        },
+        Err(error) => {
+            warn!(error = %error, "failed to install SIGTERM handler");
+            wait_for_ctrl_c().await;
+            "SIGINT"
+        }
+    }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_termination_signal() -> &'static str {
+    wait_for_ctrl_c().await;
+    "ctrl_c"
+}
+
+async fn wait_for_ctrl_c() {
+    if let Err(error) = tokio::signal::ctrl_c().await {
+        warn!(error = %error, "failed to install ctrl-c handler");
+        std::future::pending::<()>().await;
+    }
+}
@@ -0,0 +1,17 @@
+//! Tenant announcement banners.
+//!
+//! Administrators publish banners such as maintenance windows or policy
+//! notices with an optional schedule and an audience limited to roles and
+//! apps. The workspace shows each user the banners that are live, target
+//! them and that they have not dismissed; dismissals persist per user.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    Announcement, AnnouncementRepository, AnnouncementSeverity, SaveAnnouncementInput,
+};
+pub use service::AnnouncementService;
@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+/// Visual severity of an announcement banner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnouncementSeverity {
+    /// Neutral information.
+    Info,
+    /// Something users should plan for, such as a maintenance window.
+    Warning,
+    /// An outage or an action users must take.
+    Critical,
+}
+
+impl AnnouncementSeverity {
+    /// Returns a stable storage value for this severity.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Critical => "critical",
+        }
+    }
+
+    /// Parses a stored or transport severity value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "info" => Ok(Self::Info),
+            "warning" => Ok(Self::Warning),
+            "critical" => Ok(Self::Critical),
+            _ => Err(AppError::Validation(format!(
+                "unknown announcement severity '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Stored announcement banner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Announcement {
+    /// Stable announcement id.
+    pub announcement_id: String,
+    /// Short headline.
+    pub title: String,
+    /// Banner text.
+    pub body: String,
+    /// Visual severity.
+    pub severity: AnnouncementSeverity,
+    /// When the banner starts showing.
+    pub starts_at: DateTime<Utc>,
+    /// When the banner stops showing, if it expires.
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Role names the banner targets; empty targets every user.
+    pub audience_roles: Vec<String>,
+    /// App logical names the banner is shown in; empty shows it everywhere.
+    pub audience_apps: Vec<String>,
+    /// Whether users may dismiss the banner.
+    pub is_dismissible: bool,
+    /// Subject that published the banner.
+    pub created_by_subject: String,
+    /// Creation timestamp.
+    pub created_at: DateTime<Utc>,
+    /// Last edit timestamp.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Announcement {
+    /// Returns whether the banner is scheduled to show at `now`.
+    #[must_use]
+    pub fn is_live_at(&self, now: DateTime<Utc>) -> bool {
+        self.starts_at <= now && self.ends_at.is_none_or(|ends_at| now < ends_at)
+    }
+}
+
+/// Input for publishing or editing an announcement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveAnnouncementInput {
+    /// Short headline.
+    pub title: String,
+    /// Banner text.
+    pub body: String,
+    /// Visual severity.
+    pub severity: AnnouncementSeverity,
+    /// When the banner starts showing; defaults to now.
+    pub starts_at: Option<DateTime<Utc>>,
+    /// When the banner stops showing.
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Role names the banner targets.
+    pub audience_roles: Vec<String>,
+    /// App logical names the banner is shown in.
+    pub audience_apps: Vec<String>,
+    /// Whether users may dismiss the banner.
+    pub is_dismissible: bool,
+}
+
+/// Repository port for announcements and their per-user dismissals.
+#[async_trait]
+pub trait AnnouncementRepository: Send + Sync {
+    /// Persists a new announcement.
+    async fn create_announcement(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        input: SaveAnnouncementInput,
+    ) -> AppResult<Announcement>;
+
+    /// Replaces an announcement's content, returning `None` when it does not exist.
+    async fn update_announcement(
+        &self,
+        tenant_id: TenantId,
+        announcement_id: &str,
+        input: SaveAnnouncementInput,
+    ) -> AppResult<Option<Announcement>>;
+
+    /// Deletes an announcement and its dismissals, returning whether it existed.
+    async fn delete_announcement(
+        &self,
+        tenant_id: TenantId,
+        announcement_id: &str,
+    ) -> AppResult<bool>;
+
+    /// Finds one announcement.
+    async fn find_announcement(
+        &self,
+        tenant_id: TenantId,
+        announcement_id: &str,
+    ) -> AppResult<Option<Announcement>>;
+
+    /// Lists every announcement, newest start first.
+    async fn list_announcements(&self, tenant_id: TenantId) -> AppResult<Vec<Announcement>>;
+
+    /// Lists announcements live at `now`, leaving out dismissible ones the subject dismissed.
+    async fn list_live_announcements_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        now: DateTime<Utc>,
+    ) -> AppResult<Vec<Announcement>>;
+
+    /// Records that a subject dismissed an announcement; repeated dismissals are no-ops.
+    async fn dismiss_announcement(
+        &self,
+        tenant_id: TenantId,
+        announcement_id: &str,
+        subject: &str,
+        dismissed_at: DateTime<Utc>,
+    ) -> AppResult<()>;
+}
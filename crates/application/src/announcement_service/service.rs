@@ -0,0 +1,284 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::{Announcement, AnnouncementRepository, SaveAnnouncementInput};
+
+const MAX_TITLE_LENGTH: usize = 200;
+const MAX_BODY_LENGTH: usize = 4_000;
+const MAX_AUDIENCE_ENTRIES: usize = 50;
+
+/// Application service for tenant announcement banners.
+#[derive(Clone)]
+pub struct AnnouncementService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn AnnouncementRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl AnnouncementService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn AnnouncementRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Lists every announcement of the actor tenant, including scheduled and expired ones.
+    pub async fn list_announcements(&self, actor: &UserIdentity) -> AppResult<Vec<Announcement>> {
+        self.require_manage(actor).await?;
+        self.repository.list_announcements(actor.tenant_id()).await
+    }
+
+    /// Publishes an announcement.
+    pub async fn publish_announcement(
+        &self,
+        actor: &UserIdentity,
+        input: SaveAnnouncementInput,
+    ) -> AppResult<Announcement> {
+        self.require_manage(actor).await?;
+
+        let announcement = self
+            .repository
+            .create_announcement(actor.tenant_id(), actor.subject(), normalize_input(input)?)
+            .await?;
+
+        self.append_audit_event(
+            actor,
+            AuditAction::TenantAnnouncementPublished,
+            announcement.announcement_id.as_str(),
+            Some(&announcement),
+        )
+        .await?;
+
+        Ok(announcement)
+    }
+
+    /// Replaces an announcement's content; existing dismissals are kept.
+    pub async fn update_announcement(
+        &self,
+        actor: &UserIdentity,
+        announcement_id: &str,
+        input: SaveAnnouncementInput,
+    ) -> AppResult<Announcement> {
+        self.require_manage(actor).await?;
+
+        let announcement = self
+            .repository
+            .update_announcement(actor.tenant_id(), announcement_id, normalize_input(input)?)
+            .await?
+            .ok_or_else(|| not_found(announcement_id))?;
+
+        self.append_audit_event(
+            actor,
+            AuditAction::TenantAnnouncementUpdated,
+            announcement_id,
+            Some(&announcement),
+        )
+        .await?;
+
+        Ok(announcement)
+    }
+
+    /// Deletes an announcement.
+    pub async fn delete_announcement(
+        &self,
+        actor: &UserIdentity,
+        announcement_id: &str,
+    ) -> AppResult<()> {
+        self.require_manage(actor).await?;
+
+        if !self
+            .repository
+            .delete_announcement(actor.tenant_id(), announcement_id)
+            .await?
+        {
+            return Err(not_found(announcement_id));
+        }
+
+        self.append_audit_event(
+            actor,
+            AuditAction::TenantAnnouncementDeleted,
+            announcement_id,
+            None,
+        )
+        .await
+    }
+
+    /// Lists banners to show the actor, optionally inside one app.
+    ///
+    /// Role-targeted banners need one of the actor's roles, held directly or
+    /// through a team. App-targeted banners only show inside those apps.
+    pub async fn active_announcements(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: Option<&str>,
+    ) -> AppResult<Vec<Announcement>> {
+        let mut announcements = self
+            .repository
+            .list_live_announcements_for_subject(actor.tenant_id(), actor.subject(), Utc::now())
+            .await?;
+
+        announcements.retain(|announcement| {
+            announcement.audience_apps.is_empty()
+                || app_logical_name.is_some_and(|app| {
+                    announcement
+                        .audience_apps
+                        .iter()
+                        .any(|audience_app| audience_app == app)
+                })
+        });
+
+        if announcements
+            .iter()
+            .any(|announcement| !announcement.audience_roles.is_empty())
+        {
+            let roles = self
+                .authorization_service
+                .role_names(actor.tenant_id(), actor.subject())
+                .await?;
+            announcements.retain(|announcement| {
+                announcement.audience_roles.is_empty()
+                    || announcement
+                        .audience_roles
+                        .iter()
+                        .any(|role| roles.contains(role))
+            });
+        }
+
+        Ok(announcements)
+    }
+
+    /// Hides a dismissible announcement for the actor from now on.
+    pub async fn dismiss_announcement(
+        &self,
+        actor: &UserIdentity,
+        announcement_id: &str,
+    ) -> AppResult<()> {
+        let announcement = self
+            .repository
+            .find_announcement(actor.tenant_id(), announcement_id)
+            .await?
+            .ok_or_else(|| not_found(announcement_id))?;
+        if !announcement.is_dismissible {
+            return Err(AppError::Validation(format!(
+                "announcement '{announcement_id}' cannot be dismissed"
+            )));
+        }
+
+        self.repository
+            .dismiss_announcement(
+                actor.tenant_id(),
+                announcement_id,
+                actor.subject(),
+                Utc::now(),
+            )
+            .await
+    }
+
+    async fn require_manage(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::SecurityRoleManage,
+            )
+            .await
+    }
+
+    async fn append_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        announcement_id: &str,
+        announcement: Option<&Announcement>,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "announcement".to_owned(),
+                resource_id: announcement_id.to_owned(),
+                detail: announcement.map(|announcement| {
+                    serde_json::json!({
+                        "title": announcement.title,
+                        "severity": announcement.severity.as_str(),
+                        "starts_at": announcement.starts_at.to_rfc3339(),
+                        "ends_at": announcement.ends_at.map(|ends_at| ends_at.to_rfc3339()),
+                        "audience_roles": announcement.audience_roles,
+                        "audience_apps": announcement.audience_apps,
+                    })
+                    .to_string()
+                }),
+            })
+            .await
+    }
+}
+
+fn not_found(announcement_id: &str) -> AppError {
+    AppError::NotFound(format!("announcement '{announcement_id}' does not exist"))
+}
+
+fn normalize_input(input: SaveAnnouncementInput) -> AppResult<SaveAnnouncementInput> {
+    let title = input.title.trim().to_owned();
+    if title.is_empty() || title.chars().count() > MAX_TITLE_LENGTH {
+        return Err(AppError::Validation(format!(
+            "announcement title must be between 1 and {MAX_TITLE_LENGTH} characters"
+        )));
+    }
+    let body = input.body.trim().to_owned();
+    if body.is_empty() || body.chars().count() > MAX_BODY_LENGTH {
+        return Err(AppError::Validation(format!(
+            "announcement body must be between 1 and {MAX_BODY_LENGTH} characters"
+        )));
+    }
+
+    let starts_at = input.starts_at.unwrap_or_else(Utc::now);
+    if input.ends_at.is_some_and(|ends_at| ends_at <= starts_at) {
+        return Err(AppError::Validation(
+            "announcement ends_at must be after starts_at".to_owned(),
+        ));
+    }
+
+    Ok(SaveAnnouncementInput {
+        title,
+        body,
+        severity: input.severity,
+        starts_at: Some(starts_at),
+        ends_at: input.ends_at,
+        audience_roles: normalize_audience("audience_roles", input.audience_roles)?,
+        audience_apps: normalize_audience("audience_apps", input.audience_apps)?,
+        is_dismissible: input.is_dismissible,
+    })
+}
+
+fn normalize_audience(field: &str, values: Vec<String>) -> AppResult<Vec<String>> {
+    let mut values = values
+        .into_iter()
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+        .collect::<Vec<_>>();
+    values.sort();
+    values.dedup();
+
+    if values.len() > MAX_AUDIENCE_ENTRIES {
+        return Err(AppError::Validation(format!(
+            "announcement {field} may list at most {MAX_AUDIENCE_ENTRIES} entries"
+        )));
+    }
+
+    Ok(values)
+}
@@ -0,0 +1,431 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    SubjectRoleGrant, TemporaryPermissionGrant,
+};
+
+use super::{
+    Announcement, AnnouncementRepository, AnnouncementService, AnnouncementSeverity,
+    SaveAnnouncementInput,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+    roles: HashMap<(TenantId, String), Vec<String>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<SubjectRoleGrant>> {
+        Ok(self
+            .roles
+            .get(&(tenant_id, subject.to_owned()))
+            .into_iter()
+            .flatten()
+            .map(|role_name| SubjectRoleGrant {
+                role_name: role_name.clone(),
+                team_name: None,
+                permissions: Vec::new(),
+            })
+            .collect())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeAnnouncementRepository {
+    announcements: Mutex<Vec<(TenantId, Announcement)>>,
+    dismissals: Mutex<HashSet<(String, String)>>,
+}
+
+fn stored(
+    announcement_id: String,
+    created_by_subject: &str,
+    input: SaveAnnouncementInput,
+) -> Announcement {
+    let now = Utc::now();
+    Announcement {
+        announcement_id,
+        title: input.title,
+        body: input.body,
+        severity: input.severity,
+        starts_at: input.starts_at.unwrap_or(now),
+        ends_at: input.ends_at,
+        audience_roles: input.audience_roles,
+        audience_apps: input.audience_apps,
+        is_dismissible: input.is_dismissible,
+        created_by_subject: created_by_subject.to_owned(),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+#[async_trait]
+impl AnnouncementRepository for FakeAnnouncementRepository {
+    async fn create_announcement(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        input: SaveAnnouncementInput,
+    ) -> AppResult<Announcement> {
+        let mut announcements = self.announcements.lock().await;
+        let announcement = stored(
+            format!("announcement-{}", announcements.len() + 1),
+            created_by_subject,
+            input,
+        );
+        announcements.push((tenant_id, announcement.clone()));
+        Ok(announcement)
+    }
+
+    async fn update_announcement(
+        &self,
+        tenant_id: TenantId,
+        announcement_id: &str,
+        input: SaveAnnouncementInput,
+    ) -> AppResult<Option<Announcement>> {
+        let mut announcements = self.announcements.lock().await;
+        let Some((_, announcement)) = announcements.iter_mut().find(|(owner, announcement)| {
+            *owner == tenant_id && announcement.announcement_id == announcement_id
+        }) else {
+            return Ok(None);
+        };
+        *announcement = stored(
+            announcement_id.to_owned(),
+            announcement.created_by_subject.as_str(),
+            input,
+        );
+        Ok(Some(announcement.clone()))
+    }
+
+    async fn delete_announcement(
+        &self,
+        tenant_id: TenantId,
+        announcement_id: &str,
+    ) -> AppResult<bool> {
+        let mut announcements = self.announcements.lock().await;
+        let before = announcements.len();
+        announcements.retain(|(owner, announcement)| {
+            !(*owner == tenant_id && announcement.announcement_id == announcement_id)
+        });
+        Ok(announcements.len() != before)
+    }
+
+    async fn find_announcement(
+        &self,
+        tenant_id: TenantId,
+        announcement_id: &str,
+    ) -> AppResult<Option<Announcement>> {
+        Ok(self
+            .announcements
+            .lock()
+            .await
+            .iter()
+            .find(|(owner, announcement)| {
+                *owner == tenant_id && announcement.announcement_id == announcement_id
+            })
+            .map(|(_, announcement)| announcement.clone()))
+    }
+
+    async fn list_announcements(&self, tenant_id: TenantId) -> AppResult<Vec<Announcement>> {
+        Ok(self
+            .announcements
+            .lock()
+            .await
+            .iter()
+            .filter(|(owner, _)| *owner == tenant_id)
+            .map(|(_, announcement)| announcement.clone())
+            .collect())
+    }
+
+    async fn list_live_announcements_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        now: DateTime<Utc>,
+    ) -> AppResult<Vec<Announcement>> {
+        let dismissals = self.dismissals.lock().await;
+        Ok(self
+            .list_announcements(tenant_id)
+            .await?
+            .into_iter()
+            .filter(|announcement| {
+                announcement.is_live_at(now)
+                    && !(announcement.is_dismissible
+                        && dismissals
+                            .contains(&(announcement.announcement_id.clone(), subject.to_owned())))
+            })
+            .collect())
+    }
+
+    async fn dismiss_announcement(
+        &self,
+        _tenant_id: TenantId,
+        announcement_id: &str,
+        subject: &str,
+        _dismissed_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        self.dismissals
+            .lock()
+            .await
+            .insert((announcement_id.to_owned(), subject.to_owned()));
+        Ok(())
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    repository: Arc<FakeAnnouncementRepository>,
+) -> (AnnouncementService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([(
+                (tenant_id, "admin".to_owned()),
+                vec![Permission::SecurityRoleManage],
+            )]),
+            roles: HashMap::from([
+                (
+                    (tenant_id, "sales_rep".to_owned()),
+                    vec!["Sales".to_owned()],
+                ),
+                ((tenant_id, "clerk".to_owned()), vec!["Finance".to_owned()]),
+            ]),
+        }),
+        audit_repository.clone(),
+    );
+    let service =
+        AnnouncementService::new(authorization_service, repository, audit_repository.clone());
+    (service, audit_repository)
+}
+
+fn input(title: &str) -> SaveAnnouncementInput {
+    SaveAnnouncementInput {
+        title: title.to_owned(),
+        body: "Planned maintenance on Saturday.".to_owned(),
+        severity: AnnouncementSeverity::Warning,
+        starts_at: None,
+        ends_at: None,
+        audience_roles: Vec::new(),
+        audience_apps: Vec::new(),
+        is_dismissible: true,
+    }
+}
+
+fn titles(announcements: &[Announcement]) -> Vec<&str> {
+    announcements
+        .iter()
+        .map(|announcement| announcement.title.as_str())
+        .collect()
+}
+
+#[tokio::test]
+async fn publishing_requires_role_manage_and_validates_schedule() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeAnnouncementRepository::default());
+    let (service, audit_repository) = build_service(tenant_id, repository.clone());
+
+    let forbidden = service
+        .publish_announcement(&actor(tenant_id, "sales_rep"), input("Maintenance"))
+        .await;
+    assert!(matches!(forbidden, Err(AppError::Forbidden(_))));
+
+    let now = Utc::now();
+    let inverted = service
+        .publish_announcement(
+            &actor(tenant_id, "admin"),
+            SaveAnnouncementInput {
+                starts_at: Some(now),
+                ends_at: Some(now - Duration::hours(1)),
+                ..input("Maintenance")
+            },
+        )
+        .await;
+    assert!(matches!(inverted, Err(AppError::Validation(_))));
+
+    let announcement = service
+        .publish_announcement(
+            &actor(tenant_id, "admin"),
+            SaveAnnouncementInput {
+                audience_roles: vec![" Sales ".to_owned(), "Sales".to_owned(), " ".to_owned()],
+                ..input("  Maintenance  ")
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(announcement.title, "Maintenance");
+    assert_eq!(announcement.audience_roles, vec!["Sales".to_owned()]);
+    assert_eq!(repository.announcements.lock().await.len(), 1);
+
+    let events = audit_repository.events.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].action, AuditAction::TenantAnnouncementPublished);
+}
+
+#[tokio::test]
+async fn active_announcements_follow_schedule_audience_and_dismissals() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeAnnouncementRepository::default());
+    let (service, _) = build_service(tenant_id, repository);
+    let admin = actor(tenant_id, "admin");
+    let sales_rep = actor(tenant_id, "sales_rep");
+    let clerk = actor(tenant_id, "clerk");
+    let now = Utc::now();
+
+    let everyone = service
+        .publish_announcement(&admin, input("Everyone"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    for announcement in [
+        SaveAnnouncementInput {
+            audience_roles: vec!["Sales".to_owned()],
+            ..input("Sales only")
+        },
+        SaveAnnouncementInput {
+            audience_apps: vec!["crm".to_owned()],
+            is_dismissible: false,
+            ..input("CRM only")
+        },
+        SaveAnnouncementInput {
+            starts_at: Some(now + Duration::days(1)),
+            ..input("Scheduled")
+        },
+        SaveAnnouncementInput {
+            starts_at: Some(now - Duration::days(2)),
+            ends_at: Some(now - Duration::days(1)),
+            ..input("Expired")
+        },
+    ] {
+        service
+            .publish_announcement(&admin, announcement)
+            .await
+            .unwrap_or_else(|_| unreachable!());
+    }
+
+    let sales_in_crm = service
+        .active_announcements(&sales_rep, Some("crm"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        titles(&sales_in_crm),
+        vec!["Everyone", "Sales only", "CRM only"]
+    );
+
+    let clerk_outside_apps = service
+        .active_announcements(&clerk, None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(titles(&clerk_outside_apps), vec!["Everyone"]);
+
+    service
+        .dismiss_announcement(&clerk, everyone.announcement_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(
+        service
+            .active_announcements(&clerk, None)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+    assert_eq!(
+        titles(
+            &service
+                .active_announcements(&sales_rep, None)
+                .await
+                .unwrap_or_else(|_| unreachable!())
+        ),
+        vec!["Everyone", "Sales only"]
+    );
+
+    let crm_only = service
+        .list_announcements(&admin)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .into_iter()
+        .find(|announcement| announcement.title == "CRM only")
+        .unwrap_or_else(|| unreachable!());
+    let undismissible = service
+        .dismiss_announcement(&clerk, crm_only.announcement_id.as_str())
+        .await;
+    assert!(matches!(undismissible, Err(AppError::Validation(_))));
+
+    let missing = service.dismiss_announcement(&clerk, "missing").await;
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+}
@@ -1,12 +1,25 @@
+mod admins;
+mod bulk_updates;
+mod capabilities;
 mod inputs;
 mod permissions;
+mod personal_views;
+mod presentation;
 mod repository;
 mod runtime_records;
 
+pub use admins::AppAdminGrant;
+pub use bulk_updates::{
+    RUNTIME_RECORD_BULK_UPDATE_MAX_RECORDS, RuntimeRecordBulkUpdateInput,
+    RuntimeRecordBulkUpdateItem, RuntimeRecordBulkUpdateResult,
+};
+pub use capabilities::AppEntityCapabilitySummary;
 pub use inputs::{
     AppEntityFormInput, AppEntityViewInput, BindAppEntityInput, CreateAppInput,
-    SaveAppRoleEntityPermissionInput, SaveAppSitemapInput,
+    SaveAppRoleEntityPermissionInput, SaveAppSitemapInput, SavePersonalViewInput,
 };
 pub use permissions::SubjectEntityPermission;
+pub use personal_views::PersonalView;
+pub use presentation::EntityPresentation;
 pub use repository::AppRepository;
 pub use runtime_records::RuntimeRecordService;
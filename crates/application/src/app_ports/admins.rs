@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+
+/// Delegated administration grant for one app.
+///
+/// App administrators manage entity bindings, the sitemap and role entity
+/// permissions of their app without tenant-wide role management.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppAdminGrant {
+    /// App the subject administers.
+    pub app_logical_name: String,
+    /// Subject holding the grant.
+    pub subject: String,
+    /// Subject that granted administration.
+    pub granted_by_subject: String,
+    /// Grant creation timestamp.
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,48 @@
+use qryvanta_core::AppError;
+use qryvanta_domain::RuntimeRecord;
+use serde_json::Value;
+
+/// Maximum number of records one bulk edit may touch.
+pub const RUNTIME_RECORD_BULK_UPDATE_MAX_RECORDS: usize = 500;
+
+/// Input payload for applying the same field values to many records.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeRecordBulkUpdateInput {
+    /// Records selected in the view.
+    pub record_ids: Vec<String>,
+    /// Field values applied to every selected record.
+    pub values: Value,
+}
+
+/// Outcome of a bulk edit for one selected record.
+#[derive(Debug)]
+pub struct RuntimeRecordBulkUpdateItem {
+    /// Selected record identifier.
+    pub record_id: String,
+    /// Updated record, or the error that stopped this record.
+    pub outcome: Result<RuntimeRecord, AppError>,
+}
+
+/// Per-record results of one bulk edit.
+#[derive(Debug)]
+pub struct RuntimeRecordBulkUpdateResult {
+    /// Results in selection order.
+    pub items: Vec<RuntimeRecordBulkUpdateItem>,
+}
+
+impl RuntimeRecordBulkUpdateResult {
+    /// Number of records that were updated.
+    #[must_use]
+    pub fn updated_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.outcome.is_ok())
+            .count()
+    }
+
+    /// Number of records that failed.
+    #[must_use]
+    pub fn failed_count(&self) -> usize {
+        self.items.len() - self.updated_count()
+    }
+}
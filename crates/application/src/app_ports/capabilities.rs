@@ -0,0 +1,18 @@
+use qryvanta_domain::AppEntityBinding;
+
+use crate::RuntimeFieldAccess;
+
+use super::{EntityPresentation, SubjectEntityPermission};
+
+/// Effective worker capabilities for one entity bound into an app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppEntityCapabilitySummary {
+    /// Effective CRUD capabilities for the subject.
+    pub permission: SubjectEntityPermission,
+    /// App binding with forms, list views, and default modes.
+    pub binding: AppEntityBinding,
+    /// Published presentation metadata; `None` until the entity is published.
+    pub presentation: Option<EntityPresentation>,
+    /// Explicit field-level access; `None` when the subject has no field restrictions.
+    pub field_access: Option<RuntimeFieldAccess>,
+}
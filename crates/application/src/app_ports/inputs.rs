@@ -1,4 +1,4 @@
-use qryvanta_domain::{AppEntityViewMode, AppSitemap};
+use qryvanta_domain::{AppEntityViewMode, AppSitemap, ViewColumn, ViewFilterGroup, ViewSort};
 
 /// Input payload for app creation.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -87,3 +87,18 @@ pub struct SaveAppSitemapInput {
     /// Full sitemap definition.
     pub sitemap: AppSitemap,
 }
+
+/// Input payload for creating or updating a personal view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavePersonalViewInput {
+    /// View display name.
+    pub display_name: String,
+    /// Ordered view columns.
+    pub columns: Vec<ViewColumn>,
+    /// Optional default sort.
+    pub default_sort: Option<ViewSort>,
+    /// Optional filter criteria.
+    pub filter_criteria: Option<ViewFilterGroup>,
+    /// Whether every app user with entity read access can use the view.
+    pub is_shared: bool,
+}
@@ -0,0 +1,27 @@
+use qryvanta_domain::{ViewColumn, ViewFilterGroup, ViewSort};
+
+/// User-owned saved view of one entity inside an app.
+///
+/// Personal views are visible to their owner, and to every subject with read
+/// access to the entity in the app once `is_shared` is set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonalView {
+    /// Stable view identifier.
+    pub view_id: String,
+    /// App the view belongs to.
+    pub app_logical_name: String,
+    /// Entity the view lists.
+    pub entity_logical_name: String,
+    /// Subject that owns the view.
+    pub owner_subject: String,
+    /// View display name.
+    pub display_name: String,
+    /// Ordered view columns.
+    pub columns: Vec<ViewColumn>,
+    /// Optional default sort.
+    pub default_sort: Option<ViewSort>,
+    /// Optional filter criteria.
+    pub filter_criteria: Option<ViewFilterGroup>,
+    /// Whether every app user with entity read access can use the view.
+    pub is_shared: bool,
+}
@@ -0,0 +1,26 @@
+use qryvanta_domain::PublishedEntitySchema;
+
+/// Published presentation metadata workspaces use to render an entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityPresentation {
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Entity display name.
+    pub display_name: String,
+    /// Optional icon key from the entity icon catalog.
+    pub icon: Option<String>,
+    /// Optional `#rrggbb` accent color.
+    pub color: Option<String>,
+}
+
+impl From<&PublishedEntitySchema> for EntityPresentation {
+    fn from(schema: &PublishedEntitySchema) -> Self {
+        let entity = schema.entity();
+        Self {
+            entity_logical_name: entity.logical_name().as_str().to_owned(),
+            display_name: entity.display_name().as_str().to_owned(),
+            icon: entity.icon().map(ToOwned::to_owned),
+            color: entity.color().map(ToOwned::to_owned),
+        }
+    }
+}
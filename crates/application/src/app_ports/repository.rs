@@ -3,7 +3,9 @@ use async_trait::async_trait;
 use qryvanta_core::{AppResult, TenantId};
 use qryvanta_domain::{AppDefinition, AppEntityBinding, AppEntityRolePermission, AppSitemap};
 
+use super::admins::AppAdminGrant;
 use super::permissions::SubjectEntityPermission;
+use super::personal_views::PersonalView;
 
 /// Repository port for app definitions and app-scoped permissions.
 #[async_trait]
@@ -35,6 +37,14 @@ pub trait AppRepository: Send + Sync {
         app_logical_name: &str,
     ) -> AppResult<Vec<AppEntityBinding>>;
 
+    /// Removes an entity navigation binding, returning whether one existed.
+    async fn delete_app_entity_binding(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<bool>;
+
     /// Saves app sitemap definition.
     async fn save_sitemap(&self, tenant_id: TenantId, sitemap: AppSitemap) -> AppResult<()>;
 
@@ -90,4 +100,66 @@ pub trait AppRepository: Send + Sync {
         subject: &str,
         app_logical_name: &str,
     ) -> AppResult<Vec<SubjectEntityPermission>>;
+
+    /// Saves a personal view, replacing any view with the same identifier.
+    async fn save_personal_view(&self, tenant_id: TenantId, view: PersonalView) -> AppResult<()>;
+
+    /// Finds a personal view by identifier.
+    async fn find_personal_view(
+        &self,
+        tenant_id: TenantId,
+        view_id: &str,
+    ) -> AppResult<Option<PersonalView>>;
+
+    /// Lists personal views of an app entity owned by the subject or shared.
+    async fn list_personal_views(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+        subject: &str,
+    ) -> AppResult<Vec<PersonalView>>;
+
+    /// Lists every personal view of an entity across apps and owners.
+    async fn list_entity_personal_views(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<PersonalView>>;
+
+    /// Deletes a personal view, returning whether it existed.
+    async fn delete_personal_view(&self, tenant_id: TenantId, view_id: &str) -> AppResult<bool>;
+
+    /// Grants app administration to a subject, returning the stored grant.
+    ///
+    /// Granting an existing administrator returns the original grant.
+    async fn grant_app_admin(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        subject: &str,
+        granted_by_subject: &str,
+    ) -> AppResult<AppAdminGrant>;
+
+    /// Revokes app administration, returning whether a grant existed.
+    async fn revoke_app_admin(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        subject: &str,
+    ) -> AppResult<bool>;
+
+    /// Lists administrators of an app.
+    async fn list_app_admins(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+    ) -> AppResult<Vec<AppAdminGrant>>;
+
+    /// Lists logical names of apps the subject administers.
+    async fn list_administered_apps(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<String>>;
 }
@@ -32,6 +32,20 @@ pub trait RuntimeRecordService: Send + Sync {
         query: RuntimeRecordQuery,
     ) -> AppResult<Vec<RuntimeRecord>>;
 
+    /// Queries runtime records for a view without global permission checks.
+    ///
+    /// Implementations may serve repeated executions from a result cache.
+    async fn query_runtime_view_records_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        _view_logical_name: &str,
+        query: RuntimeRecordQuery,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        self.query_runtime_records_unchecked(actor, entity_logical_name, query)
+            .await
+    }
+
     /// Fetches one runtime record without global permission checks.
     async fn get_runtime_record_unchecked(
         &self,
@@ -49,12 +63,25 @@ pub trait RuntimeRecordService: Send + Sync {
     ) -> AppResult<RuntimeRecord>;
 
     /// Updates runtime record without global permission checks.
+    ///
+    /// A stale `expected_version` fails with a conflict instead of overwriting.
     async fn update_runtime_record_unchecked(
         &self,
         actor: &UserIdentity,
         entity_logical_name: &str,
         record_id: &str,
         data: Value,
+        expected_version: Option<i64>,
+    ) -> AppResult<RuntimeRecord>;
+
+    /// Applies a partial update on top of the stored runtime record without
+    /// global permission checks; only the patched fields must be writable.
+    async fn patch_runtime_record_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        patch: &Value,
     ) -> AppResult<RuntimeRecord>;
 
     /// Deletes runtime record without global permission checks.
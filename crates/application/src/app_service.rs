@@ -13,7 +13,9 @@ use qryvanta_domain::{
 use serde_json::Value;
 
 use crate::app_ports::{
-    AppRepository, BindAppEntityInput, CreateAppInput, RuntimeRecordService,
+    AppAdminGrant, AppEntityCapabilitySummary, AppRepository, BindAppEntityInput, CreateAppInput,
+    EntityPresentation, RUNTIME_RECORD_BULK_UPDATE_MAX_RECORDS, RuntimeRecordBulkUpdateInput,
+    RuntimeRecordBulkUpdateItem, RuntimeRecordBulkUpdateResult, RuntimeRecordService,
     SaveAppRoleEntityPermissionInput, SaveAppSitemapInput, SubjectEntityPermission,
 };
 use crate::{
@@ -23,6 +25,7 @@ use crate::{
 
 mod access;
 mod admin;
+mod personal_views;
 mod publish;
 mod runtime;
 mod sitemap;
@@ -59,6 +62,22 @@ impl RuntimeRecordService for MetadataService {
             .await
     }
 
+    async fn query_runtime_view_records_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+        query: RuntimeRecordQuery,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        self.query_runtime_view_records_unchecked(
+            actor,
+            entity_logical_name,
+            view_logical_name,
+            query,
+        )
+        .await
+    }
+
     async fn get_runtime_record_unchecked(
         &self,
         actor: &UserIdentity,
@@ -85,8 +104,26 @@ impl RuntimeRecordService for MetadataService {
         entity_logical_name: &str,
         record_id: &str,
         data: Value,
+        expected_version: Option<i64>,
+    ) -> AppResult<RuntimeRecord> {
+        self.update_runtime_record_unchecked_with_version(
+            actor,
+            entity_logical_name,
+            record_id,
+            data,
+            expected_version,
+        )
+        .await
+    }
+
+    async fn patch_runtime_record_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        patch: &Value,
     ) -> AppResult<RuntimeRecord> {
-        self.update_runtime_record_unchecked(actor, entity_logical_name, record_id, data)
+        self.patch_runtime_record_unchecked(actor, entity_logical_name, record_id, patch)
             .await
     }
 
@@ -11,6 +11,42 @@ impl AppService {
             .await
     }
 
+    /// Allows tenant role managers and delegated administrators of the app.
+    pub(super) async fn require_app_admin(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+    ) -> AppResult<()> {
+        if self
+            .authorization_service
+            .has_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::SecurityRoleManage,
+            )
+            .await?
+        {
+            return Ok(());
+        }
+
+        let administered_apps = self
+            .repository
+            .list_administered_apps(actor.tenant_id(), actor.subject())
+            .await?;
+        if administered_apps
+            .iter()
+            .any(|administered| administered == app_logical_name)
+        {
+            return Ok(());
+        }
+
+        Err(AppError::Forbidden(format!(
+            "subject '{}' is not an administrator of app '{}'",
+            actor.subject(),
+            app_logical_name
+        )))
+    }
+
     pub(super) async fn require_app_exists(
         &self,
         tenant_id: TenantId,
@@ -28,10 +28,47 @@ impl AppService {
         Ok(app)
     }
 
-    /// Lists all app definitions in tenant scope for administrators.
+    /// Lists app definitions for administrators.
+    ///
+    /// Tenant role managers see every app; delegated app administrators see
+    /// the apps they administer.
     pub async fn list_apps(&self, actor: &UserIdentity) -> AppResult<Vec<AppDefinition>> {
-        self.require_admin(actor).await?;
-        self.repository.list_apps(actor.tenant_id()).await
+        if self
+            .authorization_service
+            .has_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::SecurityRoleManage,
+            )
+            .await?
+        {
+            return self.repository.list_apps(actor.tenant_id()).await;
+        }
+
+        let administered_apps = self
+            .repository
+            .list_administered_apps(actor.tenant_id(), actor.subject())
+            .await?;
+        if administered_apps.is_empty() {
+            return Err(AppError::Forbidden(format!(
+                "subject '{}' is missing permission '{}' in tenant '{}'",
+                actor.subject(),
+                Permission::SecurityRoleManage.as_str(),
+                actor.tenant_id()
+            )));
+        }
+
+        Ok(self
+            .repository
+            .list_apps(actor.tenant_id())
+            .await?
+            .into_iter()
+            .filter(|app| {
+                administered_apps
+                    .iter()
+                    .any(|administered| administered == app.logical_name().as_str())
+            })
+            .collect())
     }
 
     /// Saves app navigation binding for an entity.
@@ -40,7 +77,8 @@ impl AppService {
         actor: &UserIdentity,
         input: BindAppEntityInput,
     ) -> AppResult<AppEntityBinding> {
-        self.require_admin(actor).await?;
+        self.require_app_admin(actor, input.app_logical_name.as_str())
+            .await?;
         self.require_app_exists(actor.tenant_id(), input.app_logical_name.as_str())
             .await?;
 
@@ -101,13 +139,48 @@ impl AppService {
         Ok(binding)
     }
 
+    /// Removes an entity from an app navigation.
+    pub async fn unbind_entity(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<()> {
+        self.require_app_admin(actor, app_logical_name).await?;
+
+        if !self
+            .repository
+            .delete_app_entity_binding(actor.tenant_id(), app_logical_name, entity_logical_name)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "entity '{}' is not bound in app '{}'",
+                entity_logical_name, app_logical_name
+            )));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::AppEntityUnbound,
+                resource_type: "app_entity_binding".to_owned(),
+                resource_id: format!("{app_logical_name}.{entity_logical_name}"),
+                detail: Some(format!(
+                    "unbound entity '{}' from app '{}'",
+                    entity_logical_name, app_logical_name
+                )),
+            })
+            .await
+    }
+
     /// Lists navigation bindings in an app.
     pub async fn list_app_entities(
         &self,
         actor: &UserIdentity,
         app_logical_name: &str,
     ) -> AppResult<Vec<AppEntityBinding>> {
-        self.require_admin(actor).await?;
+        self.require_app_admin(actor, app_logical_name).await?;
         self.repository
             .list_app_entity_bindings(actor.tenant_id(), app_logical_name)
             .await
@@ -119,7 +192,8 @@ impl AppService {
         actor: &UserIdentity,
         input: SaveAppRoleEntityPermissionInput,
     ) -> AppResult<AppEntityRolePermission> {
-        self.require_admin(actor).await?;
+        self.require_app_admin(actor, input.app_logical_name.as_str())
+            .await?;
         self.require_app_exists(actor.tenant_id(), input.app_logical_name.as_str())
             .await?;
 
@@ -167,11 +241,103 @@ impl AppService {
         actor: &UserIdentity,
         app_logical_name: &str,
     ) -> AppResult<Vec<AppEntityRolePermission>> {
-        self.require_admin(actor).await?;
+        self.require_app_admin(actor, app_logical_name).await?;
         self.repository
             .list_app_role_entity_permissions(actor.tenant_id(), app_logical_name)
             .await
     }
+
+    /// Makes a subject administrator of one app.
+    pub async fn grant_app_admin(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+        subject: &str,
+    ) -> AppResult<AppAdminGrant> {
+        self.require_admin(actor).await?;
+        self.require_app_exists(actor.tenant_id(), app_logical_name)
+            .await?;
+
+        let subject = subject.trim();
+        if subject.is_empty() {
+            return Err(AppError::Validation(
+                "subject is required to grant app administration".to_owned(),
+            ));
+        }
+
+        let grant = self
+            .repository
+            .grant_app_admin(
+                actor.tenant_id(),
+                app_logical_name,
+                subject,
+                actor.subject(),
+            )
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::AppAdminGranted,
+                resource_type: "app_admin_grant".to_owned(),
+                resource_id: format!("{app_logical_name}.{subject}"),
+                detail: Some(format!(
+                    "granted administration of app '{}' to subject '{}'",
+                    app_logical_name, subject
+                )),
+            })
+            .await?;
+
+        Ok(grant)
+    }
+
+    /// Revokes a subject's administration of one app.
+    pub async fn revoke_app_admin(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+        subject: &str,
+    ) -> AppResult<()> {
+        self.require_admin(actor).await?;
+
+        if !self
+            .repository
+            .revoke_app_admin(actor.tenant_id(), app_logical_name, subject)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "subject '{}' is not an administrator of app '{}'",
+                subject, app_logical_name
+            )));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::AppAdminRevoked,
+                resource_type: "app_admin_grant".to_owned(),
+                resource_id: format!("{app_logical_name}.{subject}"),
+                detail: Some(format!(
+                    "revoked administration of app '{}' from subject '{}'",
+                    app_logical_name, subject
+                )),
+            })
+            .await
+    }
+
+    /// Lists administrators of one app.
+    pub async fn list_app_admins(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+    ) -> AppResult<Vec<AppAdminGrant>> {
+        self.require_app_admin(actor, app_logical_name).await?;
+        self.repository
+            .list_app_admins(actor.tenant_id(), app_logical_name)
+            .await
+    }
 }
 
 fn resolve_forms(input: &BindAppEntityInput) -> AppResult<Vec<AppEntityForm>> {
@@ -0,0 +1,224 @@
+use super::*;
+
+use std::collections::HashSet;
+
+use crate::app_ports::{PersonalView, SavePersonalViewInput};
+
+impl AppService {
+    /// Lists personal views of an app entity owned by or shared with the actor.
+    pub async fn list_personal_views(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<PersonalView>> {
+        self.require_entity_action(
+            actor,
+            app_logical_name,
+            entity_logical_name,
+            AppEntityAction::Read,
+        )
+        .await?;
+
+        self.repository
+            .list_personal_views(
+                actor.tenant_id(),
+                app_logical_name,
+                entity_logical_name,
+                actor.subject(),
+            )
+            .await
+    }
+
+    /// Creates a personal view owned by the actor.
+    pub async fn create_personal_view(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+        input: SavePersonalViewInput,
+    ) -> AppResult<PersonalView> {
+        self.require_entity_action(
+            actor,
+            app_logical_name,
+            entity_logical_name,
+            AppEntityAction::Read,
+        )
+        .await?;
+        self.validate_personal_view_input(actor, entity_logical_name, &input)
+            .await?;
+
+        let view = PersonalView {
+            view_id: uuid::Uuid::new_v4().to_string(),
+            app_logical_name: app_logical_name.to_owned(),
+            entity_logical_name: entity_logical_name.to_owned(),
+            owner_subject: actor.subject().to_owned(),
+            display_name: input.display_name.trim().to_owned(),
+            columns: input.columns,
+            default_sort: input.default_sort,
+            filter_criteria: input.filter_criteria,
+            is_shared: input.is_shared,
+        };
+        self.repository
+            .save_personal_view(actor.tenant_id(), view.clone())
+            .await?;
+
+        Ok(view)
+    }
+
+    /// Replaces a personal view owned by the actor.
+    pub async fn update_personal_view(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+        view_id: &str,
+        input: SavePersonalViewInput,
+    ) -> AppResult<PersonalView> {
+        self.require_entity_action(
+            actor,
+            app_logical_name,
+            entity_logical_name,
+            AppEntityAction::Read,
+        )
+        .await?;
+        let existing = self
+            .owned_personal_view(actor, app_logical_name, entity_logical_name, view_id)
+            .await?;
+        self.validate_personal_view_input(actor, entity_logical_name, &input)
+            .await?;
+
+        let view = PersonalView {
+            display_name: input.display_name.trim().to_owned(),
+            columns: input.columns,
+            default_sort: input.default_sort,
+            filter_criteria: input.filter_criteria,
+            is_shared: input.is_shared,
+            ..existing
+        };
+        self.repository
+            .save_personal_view(actor.tenant_id(), view.clone())
+            .await?;
+
+        Ok(view)
+    }
+
+    /// Deletes a personal view owned by the actor.
+    pub async fn delete_personal_view(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+        view_id: &str,
+    ) -> AppResult<()> {
+        self.require_entity_action(
+            actor,
+            app_logical_name,
+            entity_logical_name,
+            AppEntityAction::Read,
+        )
+        .await?;
+        self.owned_personal_view(actor, app_logical_name, entity_logical_name, view_id)
+            .await?;
+
+        self.repository
+            .delete_personal_view(actor.tenant_id(), view_id)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn owned_personal_view(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+        view_id: &str,
+    ) -> AppResult<PersonalView> {
+        let view = self
+            .repository
+            .find_personal_view(actor.tenant_id(), view_id)
+            .await?
+            .filter(|view| {
+                view.app_logical_name == app_logical_name
+                    && view.entity_logical_name == entity_logical_name
+            })
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "personal view '{}' does not exist for entity '{}' in app '{}'",
+                    view_id, entity_logical_name, app_logical_name
+                ))
+            })?;
+
+        if view.owner_subject != actor.subject() {
+            return Err(AppError::Forbidden(format!(
+                "subject '{}' does not own personal view '{}'",
+                actor.subject(),
+                view_id
+            )));
+        }
+
+        Ok(view)
+    }
+
+    async fn validate_personal_view_input(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        input: &SavePersonalViewInput,
+    ) -> AppResult<()> {
+        if input.display_name.trim().is_empty() {
+            return Err(AppError::Validation(
+                "personal view display_name must not be empty".to_owned(),
+            ));
+        }
+        if input.columns.is_empty() {
+            return Err(AppError::Validation(
+                "personal view requires at least one column".to_owned(),
+            ));
+        }
+
+        let schema = self
+            .runtime_record_service
+            .latest_published_schema_unchecked(actor, entity_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "entity '{}' has no published schema",
+                    entity_logical_name
+                ))
+            })?;
+        let known_fields = schema
+            .queryable_fields()?
+            .iter()
+            .map(|field| field.logical_name().as_str().to_owned())
+            .collect::<HashSet<_>>();
+
+        let referenced_fields = input
+            .columns
+            .iter()
+            .map(|column| column.field_logical_name().as_str())
+            .chain(
+                input
+                    .default_sort
+                    .iter()
+                    .map(|sort| sort.field_logical_name().as_str()),
+            )
+            .chain(input.filter_criteria.iter().flat_map(|filter| {
+                filter
+                    .conditions()
+                    .iter()
+                    .map(|condition| condition.field_logical_name().as_str())
+            }));
+        for field_logical_name in referenced_fields {
+            if !known_fields.contains(field_logical_name) {
+                return Err(AppError::Validation(format!(
+                    "personal view field '{}.{}' does not exist in the published schema",
+                    entity_logical_name, field_logical_name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -29,6 +29,93 @@ impl AppService {
             })
     }
 
+    /// Returns effective capabilities for every entity bound into an app.
+    ///
+    /// Entities the subject has no app capabilities for are skipped, so a
+    /// workspace can bootstrap all entity surfaces from one call.
+    pub async fn entity_capabilities_bulk_for_subject(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+    ) -> AppResult<Vec<AppEntityCapabilitySummary>> {
+        self.ensure_subject_can_access_app(actor, app_logical_name)
+            .await?;
+
+        let mut permissions = self
+            .repository
+            .list_subject_entity_permissions(actor.tenant_id(), actor.subject(), app_logical_name)
+            .await?;
+        let mut bindings = self
+            .repository
+            .list_app_entity_bindings(actor.tenant_id(), app_logical_name)
+            .await?;
+        bindings.sort_by(|left, right| {
+            left.navigation_order()
+                .cmp(&right.navigation_order())
+                .then_with(|| {
+                    left.entity_logical_name()
+                        .as_str()
+                        .cmp(right.entity_logical_name().as_str())
+                })
+        });
+
+        let mut summaries = Vec::with_capacity(bindings.len());
+        for binding in bindings {
+            let entity_logical_name = binding.entity_logical_name().as_str().to_owned();
+            let Some(index) = permissions
+                .iter()
+                .position(|permission| permission.entity_logical_name == entity_logical_name)
+            else {
+                continue;
+            };
+            let permission = permissions.swap_remove(index);
+
+            let presentation = self
+                .runtime_record_service
+                .latest_published_schema_unchecked(actor, entity_logical_name.as_str())
+                .await?
+                .as_ref()
+                .map(EntityPresentation::from);
+            let field_access = self
+                .authorization_service
+                .runtime_field_access(
+                    actor.tenant_id(),
+                    actor.subject(),
+                    entity_logical_name.as_str(),
+                )
+                .await?;
+
+            summaries.push(AppEntityCapabilitySummary {
+                permission,
+                binding,
+                presentation,
+                field_access,
+            });
+        }
+
+        Ok(summaries)
+    }
+
+    /// Returns published presentation metadata for one app entity.
+    ///
+    /// Returns `None` until the entity has a published schema.
+    pub async fn entity_presentation_for_subject(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityPresentation>> {
+        self.ensure_subject_can_access_app(actor, app_logical_name)
+            .await?;
+
+        Ok(self
+            .runtime_record_service
+            .latest_published_schema_unchecked(actor, entity_logical_name)
+            .await?
+            .as_ref()
+            .map(EntityPresentation::from))
+    }
+
     /// Fetches published schema for a worker-facing app entity.
     pub async fn schema_for_subject(
         &self,
@@ -84,6 +171,44 @@ impl AppService {
         entity_logical_name: &str,
         query: RuntimeRecordQuery,
     ) -> AppResult<Vec<RuntimeRecord>> {
+        self.require_query_access(actor, app_logical_name, entity_logical_name, &query)
+            .await?;
+
+        self.runtime_record_service
+            .query_runtime_records_unchecked(actor, entity_logical_name, query)
+            .await
+    }
+
+    /// Queries runtime records for a view in app scope, which lets the
+    /// runtime serve hot views from its result cache.
+    pub async fn query_view_records(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+        query: RuntimeRecordQuery,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        self.require_query_access(actor, app_logical_name, entity_logical_name, &query)
+            .await?;
+
+        self.runtime_record_service
+            .query_runtime_view_records_unchecked(
+                actor,
+                entity_logical_name,
+                view_logical_name,
+                query,
+            )
+            .await
+    }
+
+    async fn require_query_access(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+        query: &RuntimeRecordQuery,
+    ) -> AppResult<()> {
         self.require_entity_action(
             actor,
             app_logical_name,
@@ -102,9 +227,7 @@ impl AppService {
             .await?;
         }
 
-        self.runtime_record_service
-            .query_runtime_records_unchecked(actor, entity_logical_name, query)
-            .await
+        Ok(())
     }
 
     /// Fetches one runtime record in app scope.
@@ -150,6 +273,8 @@ impl AppService {
     }
 
     /// Updates one runtime record in app scope.
+    ///
+    /// When `expected_version` is set, a concurrent change fails with a conflict.
     pub async fn update_record(
         &self,
         actor: &UserIdentity,
@@ -157,6 +282,7 @@ impl AppService {
         entity_logical_name: &str,
         record_id: &str,
         data: Value,
+        expected_version: Option<i64>,
     ) -> AppResult<RuntimeRecord> {
         self.require_entity_action(
             actor,
@@ -167,10 +293,107 @@ impl AppService {
         .await?;
 
         self.runtime_record_service
-            .update_runtime_record_unchecked(actor, entity_logical_name, record_id, data)
+            .update_runtime_record_unchecked(
+                actor,
+                entity_logical_name,
+                record_id,
+                data,
+                expected_version,
+            )
             .await
     }
 
+    /// Applies the same field values to many records selected in a view.
+    ///
+    /// Each record goes through the regular update path, so business rules,
+    /// field permissions and ownership checks apply per record and one failing
+    /// record does not stop the others. A single audit entry summarizes the run.
+    pub async fn bulk_update_records(
+        &self,
+        actor: &UserIdentity,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+        input: RuntimeRecordBulkUpdateInput,
+    ) -> AppResult<RuntimeRecordBulkUpdateResult> {
+        self.require_entity_action(
+            actor,
+            app_logical_name,
+            entity_logical_name,
+            AppEntityAction::Update,
+        )
+        .await?;
+
+        let fields = input
+            .values
+            .as_object()
+            .filter(|values| !values.is_empty())
+            .map(|values| values.keys().cloned().collect::<Vec<_>>())
+            .ok_or_else(|| {
+                AppError::Validation("bulk edit values must be a non-empty JSON object".to_owned())
+            })?;
+        let mut record_ids = Vec::new();
+        for record_id in input.record_ids {
+            let record_id = record_id.trim().to_owned();
+            if !record_id.is_empty() && !record_ids.contains(&record_id) {
+                record_ids.push(record_id);
+            }
+        }
+        if record_ids.is_empty() {
+            return Err(AppError::Validation(
+                "bulk edit requires at least one record".to_owned(),
+            ));
+        }
+        if record_ids.len() > RUNTIME_RECORD_BULK_UPDATE_MAX_RECORDS {
+            return Err(AppError::Validation(format!(
+                "bulk edit supports at most {RUNTIME_RECORD_BULK_UPDATE_MAX_RECORDS} records"
+            )));
+        }
+
+        let mut items = Vec::with_capacity(record_ids.len());
+        for record_id in record_ids {
+            let outcome = self
+                .runtime_record_service
+                .patch_runtime_record_unchecked(
+                    actor,
+                    entity_logical_name,
+                    record_id.as_str(),
+                    &input.values,
+                )
+                .await;
+            items.push(RuntimeRecordBulkUpdateItem { record_id, outcome });
+        }
+        let result = RuntimeRecordBulkUpdateResult { items };
+
+        let updated_record_ids = result
+            .items
+            .iter()
+            .filter(|item| item.outcome.is_ok())
+            .map(|item| item.record_id.as_str())
+            .collect::<Vec<_>>();
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::RuntimeRecordBulkUpdated,
+                resource_type: "runtime_record_bulk_update".to_owned(),
+                resource_id: entity_logical_name.to_owned(),
+                detail: Some(
+                    serde_json::json!({
+                        "app_logical_name": app_logical_name,
+                        "fields": fields,
+                        "requested": result.items.len(),
+                        "updated": result.updated_count(),
+                        "failed": result.failed_count(),
+                        "updated_record_ids": updated_record_ids,
+                    })
+                    .to_string(),
+                ),
+            })
+            .await?;
+
+        Ok(result)
+    }
+
     /// Lists standalone forms for a worker-facing app entity.
     pub async fn list_entity_forms(
         &self,
@@ -2,22 +2,26 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::Utc;
 use serde_json::{Value, json};
 use tokio::sync::Mutex;
 
 use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
 use qryvanta_domain::{
     AppDefinition, AppEntityBinding, AppEntityForm, AppEntityRolePermission, AppEntityView,
-    AppEntityViewMode, AppSitemap, FormDefinition, FormFieldPlacement, FormSection, FormTab,
-    FormType, Permission, RuntimeRecord, SitemapArea, SitemapGroup, SitemapSubArea, SitemapTarget,
-    ViewColumn, ViewDefinition, ViewType,
+    AppEntityViewMode, AppSitemap, AuditAction, EntityDefinition, EntityFieldDefinition, FieldType,
+    FilterOperator, FormDefinition, FormFieldPlacement, FormSection, FormTab, FormType,
+    LogicalMode, Permission, PublishedEntitySchema, RuntimeRecord, SitemapArea, SitemapGroup,
+    SitemapSubArea, SitemapTarget, SortDirection, ViewColumn, ViewDefinition, ViewFilterCondition,
+    ViewFilterGroup, ViewSort, ViewType,
 };
 
 use crate::{
-    AppEntityFormInput, AppEntityViewInput, AppRepository, AuditEvent, AuditRepository,
-    AuthorizationRepository, AuthorizationService, BindAppEntityInput, CreateAppInput,
-    RecordListQuery, RuntimeFieldGrant, RuntimeRecordLogicalMode, RuntimeRecordQuery,
-    RuntimeRecordService, SaveAppSitemapInput, SubjectEntityPermission, TemporaryPermissionGrant,
+    AppAdminGrant, AppEntityFormInput, AppEntityViewInput, AppRepository, AuditEvent,
+    AuditRepository, AuthorizationRepository, AuthorizationService, BindAppEntityInput,
+    CreateAppInput, PersonalView, RecordListQuery, RuntimeFieldGrant, RuntimeRecordBulkUpdateInput,
+    RuntimeRecordLogicalMode, RuntimeRecordQuery, RuntimeRecordService, SaveAppSitemapInput,
+    SavePersonalViewInput, SubjectEntityPermission, TemporaryPermissionGrant,
 };
 
 use super::AppService;
@@ -70,6 +74,31 @@ impl AuthorizationRepository for FakeAuthorizationRepository {
     ) -> AppResult<Option<TemporaryPermissionGrant>> {
         Ok(None)
     }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
 }
 
 #[derive(Default)]
@@ -78,6 +107,8 @@ struct FakeAppRepository {
     sitemaps: Mutex<HashMap<(TenantId, String), AppSitemap>>,
     subject_permissions: Mutex<HashMap<(TenantId, String, String), Vec<SubjectEntityPermission>>>,
     subject_access: Mutex<HashMap<(TenantId, String, String), bool>>,
+    personal_views: Mutex<HashMap<(TenantId, String), PersonalView>>,
+    app_admins: Mutex<HashMap<(TenantId, String), Vec<AppAdminGrant>>>,
 }
 
 #[async_trait]
@@ -120,6 +151,22 @@ impl AppRepository for FakeAppRepository {
             .unwrap_or_default())
     }
 
+    async fn delete_app_entity_binding(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        let mut bindings = self.bindings.lock().await;
+        let Some(app_bindings) = bindings.get_mut(&(tenant_id, app_logical_name.to_owned())) else {
+            return Ok(false);
+        };
+        let before = app_bindings.len();
+        app_bindings
+            .retain(|binding| binding.entity_logical_name().as_str() != entity_logical_name);
+        Ok(app_bindings.len() != before)
+    }
+
     async fn save_sitemap(&self, tenant_id: TenantId, sitemap: AppSitemap) -> AppResult<()> {
         self.sitemaps.lock().await.insert(
             (tenant_id, sitemap.app_logical_name().as_str().to_owned()),
@@ -213,6 +260,148 @@ impl AppRepository for FakeAppRepository {
             .cloned()
             .unwrap_or_default())
     }
+
+    async fn save_personal_view(&self, tenant_id: TenantId, view: PersonalView) -> AppResult<()> {
+        self.personal_views
+            .lock()
+            .await
+            .insert((tenant_id, view.view_id.clone()), view);
+        Ok(())
+    }
+
+    async fn find_personal_view(
+        &self,
+        tenant_id: TenantId,
+        view_id: &str,
+    ) -> AppResult<Option<PersonalView>> {
+        Ok(self
+            .personal_views
+            .lock()
+            .await
+            .get(&(tenant_id, view_id.to_owned()))
+            .cloned())
+    }
+
+    async fn list_personal_views(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+        subject: &str,
+    ) -> AppResult<Vec<PersonalView>> {
+        let mut views: Vec<PersonalView> = self
+            .personal_views
+            .lock()
+            .await
+            .iter()
+            .filter(|((view_tenant_id, _), view)| {
+                *view_tenant_id == tenant_id
+                    && view.app_logical_name == app_logical_name
+                    && view.entity_logical_name == entity_logical_name
+                    && (view.owner_subject == subject || view.is_shared)
+            })
+            .map(|(_, view)| view.clone())
+            .collect();
+        views.sort_by(|left, right| left.display_name.cmp(&right.display_name));
+        Ok(views)
+    }
+
+    async fn list_entity_personal_views(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<PersonalView>> {
+        Ok(self
+            .personal_views
+            .lock()
+            .await
+            .iter()
+            .filter(|((view_tenant_id, _), view)| {
+                *view_tenant_id == tenant_id && view.entity_logical_name == entity_logical_name
+            })
+            .map(|(_, view)| view.clone())
+            .collect())
+    }
+
+    async fn delete_personal_view(&self, tenant_id: TenantId, view_id: &str) -> AppResult<bool> {
+        Ok(self
+            .personal_views
+            .lock()
+            .await
+            .remove(&(tenant_id, view_id.to_owned()))
+            .is_some())
+    }
+
+    async fn grant_app_admin(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        subject: &str,
+        granted_by_subject: &str,
+    ) -> AppResult<AppAdminGrant> {
+        let mut app_admins = self.app_admins.lock().await;
+        let grants = app_admins
+            .entry((tenant_id, app_logical_name.to_owned()))
+            .or_default();
+        if let Some(existing) = grants.iter().find(|grant| grant.subject == subject) {
+            return Ok(existing.clone());
+        }
+
+        let grant = AppAdminGrant {
+            app_logical_name: app_logical_name.to_owned(),
+            subject: subject.to_owned(),
+            granted_by_subject: granted_by_subject.to_owned(),
+            created_at: Utc::now(),
+        };
+        grants.push(grant.clone());
+        Ok(grant)
+    }
+
+    async fn revoke_app_admin(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        subject: &str,
+    ) -> AppResult<bool> {
+        let mut app_admins = self.app_admins.lock().await;
+        let Some(grants) = app_admins.get_mut(&(tenant_id, app_logical_name.to_owned())) else {
+            return Ok(false);
+        };
+        let before = grants.len();
+        grants.retain(|grant| grant.subject != subject);
+        Ok(grants.len() != before)
+    }
+
+    async fn list_app_admins(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+    ) -> AppResult<Vec<AppAdminGrant>> {
+        Ok(self
+            .app_admins
+            .lock()
+            .await
+            .get(&(tenant_id, app_logical_name.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_administered_apps(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(self
+            .app_admins
+            .lock()
+            .await
+            .iter()
+            .filter(|((grant_tenant_id, _), grants)| {
+                *grant_tenant_id == tenant_id && grants.iter().any(|grant| grant.subject == subject)
+            })
+            .map(|((_, app_logical_name), _)| app_logical_name.clone())
+            .collect())
+    }
 }
 
 #[derive(Default)]
@@ -221,6 +410,7 @@ struct FakeRuntimeRecordService {
     query_calls: Mutex<usize>,
     forms: Mutex<HashMap<(TenantId, String), Vec<FormDefinition>>>,
     views: Mutex<HashMap<(TenantId, String), Vec<ViewDefinition>>>,
+    schemas: Mutex<HashMap<String, PublishedEntitySchema>>,
 }
 
 #[async_trait]
@@ -228,9 +418,9 @@ impl RuntimeRecordService for FakeRuntimeRecordService {
     async fn latest_published_schema_unchecked(
         &self,
         _actor: &UserIdentity,
-        _entity_logical_name: &str,
-    ) -> AppResult<Option<qryvanta_domain::PublishedEntitySchema>> {
-        Ok(None)
+        entity_logical_name: &str,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        Ok(self.schemas.lock().await.get(entity_logical_name).cloned())
     }
 
     async fn list_runtime_records_unchecked(
@@ -284,10 +474,27 @@ impl RuntimeRecordService for FakeRuntimeRecordService {
         entity_logical_name: &str,
         record_id: &str,
         data: Value,
+        _expected_version: Option<i64>,
     ) -> AppResult<RuntimeRecord> {
         RuntimeRecord::new(record_id, entity_logical_name, data)
     }
 
+    async fn patch_runtime_record_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        patch: &Value,
+    ) -> AppResult<RuntimeRecord> {
+        if record_id == "missing" {
+            return Err(AppError::NotFound(format!(
+                "runtime record '{record_id}' does not exist"
+            )));
+        }
+
+        RuntimeRecord::new(record_id, entity_logical_name, patch.clone())
+    }
+
     async fn delete_runtime_record_unchecked(
         &self,
         _actor: &UserIdentity,
@@ -737,6 +944,106 @@ async fn bind_entity_defaults_to_first_surface_when_defaults_omitted() {
     );
 }
 
+#[tokio::test]
+async fn entity_capabilities_bulk_returns_bound_entities_with_capabilities() {
+    let tenant_id = TenantId::new();
+    let actor = actor(tenant_id, "worker");
+    let app_repository = Arc::new(FakeAppRepository::default());
+    let service = build_service(
+        HashMap::new(),
+        app_repository.clone(),
+        Arc::new(FakeRuntimeRecordService::default()),
+    );
+
+    app_repository
+        .subject_access
+        .lock()
+        .await
+        .insert((tenant_id, "worker".to_owned(), "sales".to_owned()), true);
+    let binding = |entity_logical_name: &str, navigation_order: i32| {
+        AppEntityBinding::new(
+            "sales",
+            entity_logical_name,
+            None,
+            navigation_order,
+            vec![
+                AppEntityForm::new("main_form", "Main Form", Vec::new())
+                    .unwrap_or_else(|_| unreachable!()),
+            ],
+            vec![
+                AppEntityView::new("main_view", "Main View", Vec::new())
+                    .unwrap_or_else(|_| unreachable!()),
+            ],
+            "main_form",
+            "main_view",
+            AppEntityViewMode::Json,
+        )
+        .unwrap_or_else(|_| unreachable!())
+    };
+    app_repository.bindings.lock().await.insert(
+        (tenant_id, "sales".to_owned()),
+        vec![
+            binding("invoice", 2),
+            binding("contact", 0),
+            binding("account", 1),
+        ],
+    );
+    app_repository.subject_permissions.lock().await.insert(
+        (tenant_id, "worker".to_owned(), "sales".to_owned()),
+        vec![
+            SubjectEntityPermission {
+                entity_logical_name: "account".to_owned(),
+                can_read: true,
+                can_create: true,
+                can_update: false,
+                can_delete: false,
+            },
+            SubjectEntityPermission {
+                entity_logical_name: "invoice".to_owned(),
+                can_read: true,
+                can_create: false,
+                can_update: false,
+                can_delete: false,
+            },
+        ],
+    );
+
+    let summaries = service
+        .entity_capabilities_bulk_for_subject(&actor, "sales")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let entity_logical_names: Vec<&str> = summaries
+        .iter()
+        .map(|summary| summary.permission.entity_logical_name.as_str())
+        .collect();
+    assert_eq!(entity_logical_names, vec!["account", "invoice"]);
+    assert!(summaries[0].permission.can_create);
+    assert_eq!(
+        summaries[0].binding.default_view_mode(),
+        AppEntityViewMode::Json
+    );
+    assert!(summaries[0].presentation.is_none());
+    assert!(summaries[0].field_access.is_none());
+}
+
+#[tokio::test]
+async fn entity_capabilities_bulk_requires_app_access() {
+    let tenant_id = TenantId::new();
+    let actor = actor(tenant_id, "worker");
+    let service = build_service(
+        HashMap::new(),
+        Arc::new(FakeAppRepository::default()),
+        Arc::new(FakeRuntimeRecordService::default()),
+    );
+
+    let result = service
+        .entity_capabilities_bulk_for_subject(&actor, "sales")
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+}
+
 #[tokio::test]
 async fn create_record_is_forbidden_without_create_capability() {
     let tenant_id = TenantId::new();
@@ -811,6 +1118,98 @@ async fn create_record_calls_runtime_when_create_capability_exists() {
     assert_eq!(*runtime_record_service.create_calls.lock().await, 1);
 }
 
+#[tokio::test]
+async fn bulk_update_records_reports_per_record_results_and_one_summary_audit() {
+    let tenant_id = TenantId::new();
+    let actor = actor(tenant_id, "worker");
+    let app_repository = Arc::new(FakeAppRepository::default());
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let service = AppService::new(
+        AuthorizationService::new(
+            Arc::new(FakeAuthorizationRepository {
+                grants: HashMap::new(),
+            }),
+            audit_repository.clone(),
+        ),
+        app_repository.clone(),
+        Arc::new(FakeRuntimeRecordService::default()),
+        audit_repository.clone(),
+    );
+
+    app_repository
+        .subject_access
+        .lock()
+        .await
+        .insert((tenant_id, "worker".to_owned(), "sales".to_owned()), true);
+    app_repository.subject_permissions.lock().await.insert(
+        (tenant_id, "worker".to_owned(), "sales".to_owned()),
+        vec![SubjectEntityPermission {
+            entity_logical_name: "account".to_owned(),
+            can_read: true,
+            can_create: false,
+            can_update: true,
+            can_delete: false,
+        }],
+    );
+
+    let empty_values = service
+        .bulk_update_records(
+            &actor,
+            "sales",
+            "account",
+            RuntimeRecordBulkUpdateInput {
+                record_ids: vec!["a".to_owned()],
+                values: json!({}),
+            },
+        )
+        .await;
+    assert!(matches!(empty_values, Err(AppError::Validation(_))));
+
+    let result = service
+        .bulk_update_records(
+            &actor,
+            "sales",
+            "account",
+            RuntimeRecordBulkUpdateInput {
+                record_ids: vec![
+                    "a".to_owned(),
+                    "missing".to_owned(),
+                    " a ".to_owned(),
+                    "b".to_owned(),
+                ],
+                values: json!({"status": "closed"}),
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    assert_eq!(
+        result
+            .items
+            .iter()
+            .map(|item| item.record_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["a", "missing", "b"]
+    );
+    assert_eq!(result.updated_count(), 2);
+    assert!(matches!(
+        result.items[1].outcome,
+        Err(AppError::NotFound(_))
+    ));
+
+    let events = audit_repository.events.lock().await;
+    let summaries = events
+        .iter()
+        .filter(|event| event.action == AuditAction::RuntimeRecordBulkUpdated)
+        .collect::<Vec<_>>();
+    assert_eq!(summaries.len(), 1);
+    let detail: Value = serde_json::from_str(summaries[0].detail.as_deref().unwrap_or("{}"))
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(detail["updated"], 2);
+    assert_eq!(detail["failed"], 1);
+    assert_eq!(detail["fields"], json!(["status"]));
+}
+
 #[tokio::test]
 async fn query_records_is_forbidden_without_read_capability() {
     let tenant_id = TenantId::new();
@@ -853,6 +1252,7 @@ async fn query_records_is_forbidden_without_read_capability() {
                 links: Vec::new(),
                 sort: Vec::new(),
                 owner_subject: None,
+                include_inactive: false,
             },
         )
         .await;
@@ -903,6 +1303,7 @@ async fn query_records_calls_runtime_when_read_capability_exists() {
                 links: Vec::new(),
                 sort: Vec::new(),
                 owner_subject: None,
+                include_inactive: false,
             },
         )
         .await;
@@ -2809,3 +3210,204 @@ async fn get_dashboard_for_subject_rejects_unknown_dashboard_logical_name() {
         .await;
     assert!(matches!(result, Err(AppError::NotFound(_))));
 }
+
+#[tokio::test]
+async fn personal_views_are_owner_managed_and_shared_on_request() {
+    let tenant_id = TenantId::new();
+    let owner = actor(tenant_id, "owner");
+    let colleague = actor(tenant_id, "colleague");
+    let app_repository = Arc::new(FakeAppRepository::default());
+    let runtime_record_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::new(),
+        app_repository.clone(),
+        runtime_record_service.clone(),
+    );
+
+    for subject in ["owner", "colleague"] {
+        app_repository
+            .subject_access
+            .lock()
+            .await
+            .insert((tenant_id, subject.to_owned(), "sales".to_owned()), true);
+        app_repository.subject_permissions.lock().await.insert(
+            (tenant_id, subject.to_owned(), "sales".to_owned()),
+            vec![SubjectEntityPermission {
+                entity_logical_name: "account".to_owned(),
+                can_read: true,
+                can_create: false,
+                can_update: false,
+                can_delete: false,
+            }],
+        );
+    }
+    let schema = PublishedEntitySchema::new(
+        EntityDefinition::new("account", "Account").unwrap_or_else(|_| unreachable!()),
+        1,
+        vec![
+            EntityFieldDefinition::new(
+                "account",
+                "name",
+                "Name",
+                FieldType::Text,
+                true,
+                false,
+                None,
+                None,
+            )
+            .unwrap_or_else(|_| unreachable!()),
+        ],
+        Vec::new(),
+    )
+    .unwrap_or_else(|_| unreachable!());
+    runtime_record_service
+        .schemas
+        .lock()
+        .await
+        .insert("account".to_owned(), schema);
+
+    let input = |display_name: &str, is_shared: bool| SavePersonalViewInput {
+        display_name: display_name.to_owned(),
+        columns: vec![ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!())],
+        default_sort: Some(
+            ViewSort::new("name", SortDirection::Asc).unwrap_or_else(|_| unreachable!()),
+        ),
+        filter_criteria: Some(
+            ViewFilterGroup::new(
+                LogicalMode::And,
+                vec![
+                    ViewFilterCondition::new("name", FilterOperator::Contains, json!("acme"))
+                        .unwrap_or_else(|_| unreachable!()),
+                ],
+            )
+            .unwrap_or_else(|_| unreachable!()),
+        ),
+        is_shared,
+    };
+
+    let view = service
+        .create_personal_view(&owner, "sales", "account", input("Acme", false))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(view.owner_subject, "owner");
+    assert!(
+        service
+            .list_personal_views(&colleague, "sales", "account")
+            .await
+            .unwrap_or_default()
+            .is_empty()
+    );
+
+    let unknown_field = SavePersonalViewInput {
+        columns: vec![ViewColumn::new("missing", 0, None, None).unwrap_or_else(|_| unreachable!())],
+        ..input("Broken", false)
+    };
+    assert!(matches!(
+        service
+            .create_personal_view(&owner, "sales", "account", unknown_field)
+            .await,
+        Err(AppError::Validation(_))
+    ));
+    assert!(matches!(
+        service
+            .update_personal_view(
+                &colleague,
+                "sales",
+                "account",
+                view.view_id.as_str(),
+                input("Hijacked", true),
+            )
+            .await,
+        Err(AppError::Forbidden(_))
+    ));
+
+    let shared = service
+        .update_personal_view(
+            &owner,
+            "sales",
+            "account",
+            view.view_id.as_str(),
+            input("Acme shared", true),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(shared.view_id, view.view_id);
+    let colleague_views = service
+        .list_personal_views(&colleague, "sales", "account")
+        .await
+        .unwrap_or_default();
+    assert_eq!(colleague_views, vec![shared]);
+
+    assert!(matches!(
+        service
+            .delete_personal_view(&colleague, "sales", "account", view.view_id.as_str())
+            .await,
+        Err(AppError::Forbidden(_))
+    ));
+    assert!(
+        service
+            .delete_personal_view(&owner, "sales", "account", view.view_id.as_str())
+            .await
+            .is_ok()
+    );
+    assert!(matches!(
+        service
+            .delete_personal_view(&owner, "sales", "account", view.view_id.as_str())
+            .await,
+        Err(AppError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn delegated_app_admin_manages_only_granted_app() {
+    let tenant_id = TenantId::new();
+    let admin = actor(tenant_id, "admin");
+    let maker = actor(tenant_id, "maker");
+    let app_repository = Arc::new(FakeAppRepository::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "admin".to_owned()),
+            vec![Permission::SecurityRoleManage],
+        )]),
+        app_repository,
+        Arc::new(FakeRuntimeRecordService::default()),
+    );
+
+    let denied = service.list_app_entities(&maker, "sales").await;
+    assert!(matches!(denied, Err(AppError::Forbidden(_))));
+
+    let self_grant = service.grant_app_admin(&maker, "sales", "maker").await;
+    assert!(matches!(self_grant, Err(AppError::Forbidden(_))));
+
+    let grant = service
+        .grant_app_admin(&admin, "sales", " maker ")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(grant.subject, "maker");
+    assert_eq!(grant.granted_by_subject, "admin");
+
+    assert!(service.list_app_entities(&maker, "sales").await.is_ok());
+    let admins = service
+        .list_app_admins(&maker, "sales")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(admins.len(), 1);
+
+    let other_app = service.list_app_entities(&maker, "service").await;
+    assert!(matches!(other_app, Err(AppError::Forbidden(_))));
+
+    let escalation = service.grant_app_admin(&maker, "sales", "friend").await;
+    assert!(matches!(escalation, Err(AppError::Forbidden(_))));
+
+    assert!(
+        service
+            .revoke_app_admin(&admin, "sales", "maker")
+            .await
+            .is_ok()
+    );
+    let revoked = service.list_app_entities(&maker, "sales").await;
+    assert!(matches!(revoked, Err(AppError::Forbidden(_))));
+
+    let missing = service.revoke_app_admin(&admin, "sales", "maker").await;
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+}
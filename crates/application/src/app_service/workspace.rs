@@ -44,6 +44,46 @@ impl AppService {
         Self::filter_sitemap_by_permissions(sitemap, permissions)
     }
 
+    /// Resolves published presentation metadata for entity targets of a navigation sitemap.
+    ///
+    /// The sitemap must already be filtered for the subject; entities without a
+    /// published schema are skipped.
+    pub async fn navigation_entity_presentations(
+        &self,
+        actor: &UserIdentity,
+        sitemap: &AppSitemap,
+    ) -> AppResult<Vec<EntityPresentation>> {
+        let mut entity_logical_names = Vec::new();
+        for sub_area in sitemap
+            .areas()
+            .iter()
+            .flat_map(SitemapArea::groups)
+            .flat_map(SitemapGroup::sub_areas)
+        {
+            if let SitemapTarget::Entity {
+                entity_logical_name,
+                ..
+            } = sub_area.target()
+                && !entity_logical_names.contains(entity_logical_name)
+            {
+                entity_logical_names.push(entity_logical_name.clone());
+            }
+        }
+
+        let mut presentations = Vec::with_capacity(entity_logical_names.len());
+        for entity_logical_name in entity_logical_names {
+            if let Some(schema) = self
+                .runtime_record_service
+                .latest_published_schema_unchecked(actor, entity_logical_name.as_str())
+                .await?
+            {
+                presentations.push(EntityPresentation::from(&schema));
+            }
+        }
+
+        Ok(presentations)
+    }
+
     /// Returns a minimal metadata-driven dashboard surface for worker users.
     pub async fn get_dashboard_for_subject(
         &self,
@@ -134,7 +174,7 @@ impl AppService {
         actor: &UserIdentity,
         app_logical_name: &str,
     ) -> AppResult<AppSitemap> {
-        self.require_admin(actor).await?;
+        self.require_app_admin(actor, app_logical_name).await?;
         self.require_app_exists(actor.tenant_id(), app_logical_name)
             .await?;
         let sitemap = if let Some(sitemap) = self
@@ -160,7 +200,8 @@ impl AppService {
         actor: &UserIdentity,
         input: SaveAppSitemapInput,
     ) -> AppResult<AppSitemap> {
-        self.require_admin(actor).await?;
+        self.require_app_admin(actor, input.app_logical_name.as_str())
+            .await?;
         self.require_app_exists(actor.tenant_id(), input.app_logical_name.as_str())
             .await?;
 
@@ -0,0 +1,18 @@
+//! Streaming export of tenant audit entries to external SIEM sinks.
+//!
+//! Each tenant configures sinks (NDJSON files, S3, syslog or an HTTPS
+//! webhook). A background shipper claims sinks across tenants, reads audit
+//! entries past each sink's checkpoint in chain order, delivers them, and
+//! advances the checkpoint only after the sink accepted the batch.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    AuditExportShipper, AuditExportSink, AuditExportSinkConfig, AuditExportSinkRepository,
+    AuditExportSyslogTransport, ClaimedAuditExportSink, CreateAuditExportSinkInput,
+};
+pub use service::{AUDIT_EXPORT_MAX_SINKS_PER_TENANT, AuditExportService};
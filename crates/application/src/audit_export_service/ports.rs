@@ -0,0 +1,190 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use qryvanta_core::{AppResult, TenantId};
+
+use crate::AuditLogEntry;
+
+/// Transport used by a syslog audit export sink.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditExportSyslogTransport {
+    /// One RFC 5424 datagram per entry.
+    Udp,
+    /// RFC 6587 octet-counted frames over one connection per batch.
+    Tcp,
+}
+
+/// Destination an audit export sink ships entries to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AuditExportSinkConfig {
+    /// Daily NDJSON files below the operator-configured export directory.
+    NdjsonFile,
+    /// NDJSON objects uploaded to an S3 bucket, one per shipped batch.
+    S3 {
+        /// Bucket name.
+        bucket: String,
+        /// Optional key prefix.
+        #[serde(default)]
+        prefix: Option<String>,
+    },
+    /// RFC 5424 syslog messages.
+    Syslog {
+        /// Collector host name or address.
+        host: String,
+        /// Collector port.
+        port: u16,
+        /// Network transport.
+        transport: AuditExportSyslogTransport,
+    },
+    /// NDJSON batches posted to an HTTPS endpoint.
+    HttpsWebhook {
+        /// HTTPS endpoint receiving batches.
+        url: String,
+        /// Optional header carrying the resolved secret.
+        #[serde(default)]
+        auth_header_name: Option<String>,
+        /// Secret reference resolving to the header value.
+        #[serde(default)]
+        auth_secret_ref: Option<String>,
+    },
+}
+
+impl AuditExportSinkConfig {
+    /// Returns a stable storage value for the sink type.
+    #[must_use]
+    pub fn sink_type(&self) -> &'static str {
+        match self {
+            Self::NdjsonFile => "ndjson_file",
+            Self::S3 { .. } => "s3",
+            Self::Syslog { .. } => "syslog",
+            Self::HttpsWebhook { .. } => "https_webhook",
+        }
+    }
+}
+
+/// Stored audit export sink with its shipping checkpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditExportSink {
+    /// Stable sink identifier.
+    pub sink_id: String,
+    /// Display name.
+    pub name: String,
+    /// Destination configuration.
+    pub config: AuditExportSinkConfig,
+    /// Whether the shipper delivers to this sink.
+    pub is_enabled: bool,
+    /// Chain position of the last entry delivered to the sink.
+    pub checkpoint_position: i64,
+    /// Last successful delivery.
+    pub last_exported_at: Option<DateTime<Utc>>,
+    /// Error of the last failed delivery, cleared on success.
+    pub last_error: Option<String>,
+    /// Subject that created the sink.
+    pub created_by_subject: String,
+    /// Creation timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Input payload for creating an audit export sink.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateAuditExportSinkInput {
+    /// Display name.
+    pub name: String,
+    /// Destination configuration.
+    pub config: AuditExportSinkConfig,
+    /// Whether existing audit history is shipped, or only entries written
+    /// after the sink is created.
+    pub backfill: bool,
+}
+
+/// Sink claimed for shipping by one shipper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimedAuditExportSink {
+    /// Tenant owning the sink.
+    pub tenant_id: TenantId,
+    /// Claimed sink.
+    pub sink: AuditExportSink,
+}
+
+/// Repository port for audit export sinks and their checkpoints.
+#[async_trait]
+pub trait AuditExportSinkRepository: Send + Sync {
+    /// Stores a new enabled sink.
+    ///
+    /// Without backfill the checkpoint starts at the latest tenant chain position.
+    async fn create_sink(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        input: CreateAuditExportSinkInput,
+    ) -> AppResult<AuditExportSink>;
+
+    /// Lists sinks of a tenant, oldest first.
+    async fn list_sinks(&self, tenant_id: TenantId) -> AppResult<Vec<AuditExportSink>>;
+
+    /// Enables or pauses a sink, returning `None` when it does not exist.
+    async fn set_sink_enabled(
+        &self,
+        tenant_id: TenantId,
+        sink_id: &str,
+        is_enabled: bool,
+    ) -> AppResult<Option<AuditExportSink>>;
+
+    /// Deletes a sink, returning whether it existed.
+    async fn delete_sink(&self, tenant_id: TenantId, sink_id: &str) -> AppResult<bool>;
+
+    /// Claims enabled sinks whose lease lapsed at `now` across tenants.
+    ///
+    /// Claimed sinks are leased until `lease_until` so concurrent shippers
+    /// never deliver the same batch twice.
+    async fn claim_sinks(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<ClaimedAuditExportSink>>;
+
+    /// Lists tenant audit entries after a chain position, oldest first.
+    async fn list_entries_after(
+        &self,
+        tenant_id: TenantId,
+        chain_position: i64,
+        limit: usize,
+    ) -> AppResult<Vec<AuditLogEntry>>;
+
+    /// Advances the sink checkpoint after a successful delivery.
+    async fn record_checkpoint(
+        &self,
+        tenant_id: TenantId,
+        sink_id: &str,
+        chain_position: i64,
+        exported_at: DateTime<Utc>,
+    ) -> AppResult<()>;
+
+    /// Records a failed delivery and releases the lease at `retry_at`.
+    async fn record_failure(
+        &self,
+        tenant_id: TenantId,
+        sink_id: &str,
+        error: &str,
+        retry_at: DateTime<Utc>,
+    ) -> AppResult<()>;
+
+    /// Releases the lease of a sink that is caught up.
+    async fn release_sink(&self, tenant_id: TenantId, sink_id: &str) -> AppResult<()>;
+}
+
+/// Port delivering audit entries to one external sink.
+#[async_trait]
+pub trait AuditExportShipper: Send + Sync {
+    /// Delivers one ordered batch; the batch is retried in full on error.
+    async fn ship(
+        &self,
+        tenant_id: TenantId,
+        sink: &AuditExportSink,
+        entries: &[AuditLogEntry],
+    ) -> AppResult<()>;
+}
@@ -0,0 +1,374 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use qryvanta_core::{AppError, AppResult, UserIdentity, validate_secret_reference};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::{
+    AuditExportShipper, AuditExportSink, AuditExportSinkConfig, AuditExportSinkRepository,
+    ClaimedAuditExportSink, CreateAuditExportSinkInput,
+};
+
+/// Maximum number of export sinks one tenant may configure.
+pub const AUDIT_EXPORT_MAX_SINKS_PER_TENANT: usize = 10;
+
+const MAX_SINK_NAME_LENGTH: usize = 120;
+
+/// Seconds a claimed sink stays leased to one shipper.
+const AUDIT_EXPORT_LEASE_SECONDS: i64 = 120;
+
+/// Seconds a sink waits before a failed delivery is retried.
+const AUDIT_EXPORT_RETRY_SECONDS: i64 = 60;
+
+/// Batches shipped per claimed sink before it is released to the next poll.
+const AUDIT_EXPORT_MAX_BATCHES_PER_CLAIM: usize = 20;
+
+/// Application service for streaming tenant audit entries to external sinks.
+#[derive(Clone)]
+pub struct AuditExportService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn AuditExportSinkRepository>,
+    shipper: Arc<dyn AuditExportShipper>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl AuditExportService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn AuditExportSinkRepository>,
+        shipper: Arc<dyn AuditExportShipper>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            shipper,
+            audit_repository,
+        }
+    }
+
+    /// Lists export sinks for the actor tenant.
+    pub async fn list_sinks(&self, actor: &UserIdentity) -> AppResult<Vec<AuditExportSink>> {
+        self.require_permission(actor, Permission::SecurityAuditRead)
+            .await?;
+        self.repository.list_sinks(actor.tenant_id()).await
+    }
+
+    /// Creates an enabled export sink.
+    pub async fn create_sink(
+        &self,
+        actor: &UserIdentity,
+        input: CreateAuditExportSinkInput,
+    ) -> AppResult<AuditExportSink> {
+        self.require_permission(actor, Permission::SecurityRoleManage)
+            .await?;
+
+        let input = normalize_sink_input(input)?;
+        if self.repository.list_sinks(actor.tenant_id()).await?.len()
+            >= AUDIT_EXPORT_MAX_SINKS_PER_TENANT
+        {
+            return Err(AppError::Conflict(format!(
+                "a tenant may configure at most {AUDIT_EXPORT_MAX_SINKS_PER_TENANT} audit export sinks"
+            )));
+        }
+
+        let backfill = input.backfill;
+        let sink = self
+            .repository
+            .create_sink(actor.tenant_id(), actor.subject(), input)
+            .await?;
+
+        self.append_sink_audit_event(
+            actor,
+            AuditAction::SecurityAuditExportSinkCreated,
+            &sink,
+            serde_json::json!({ "backfill": backfill }),
+        )
+        .await?;
+
+        Ok(sink)
+    }
+
+    /// Enables or pauses an export sink; a resumed sink continues from its checkpoint.
+    pub async fn set_sink_enabled(
+        &self,
+        actor: &UserIdentity,
+        sink_id: &str,
+        is_enabled: bool,
+    ) -> AppResult<AuditExportSink> {
+        self.require_permission(actor, Permission::SecurityRoleManage)
+            .await?;
+
+        let sink = self
+            .repository
+            .set_sink_enabled(actor.tenant_id(), sink_id, is_enabled)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("audit export sink '{sink_id}' does not exist"))
+            })?;
+
+        self.append_sink_audit_event(
+            actor,
+            AuditAction::SecurityAuditExportSinkUpdated,
+            &sink,
+            serde_json::json!({ "is_enabled": is_enabled }),
+        )
+        .await?;
+
+        Ok(sink)
+    }
+
+    /// Deletes an export sink and its checkpoint.
+    pub async fn delete_sink(&self, actor: &UserIdentity, sink_id: &str) -> AppResult<()> {
+        self.require_permission(actor, Permission::SecurityRoleManage)
+            .await?;
+
+        if !self
+            .repository
+            .delete_sink(actor.tenant_id(), sink_id)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "audit export sink '{sink_id}' does not exist"
+            )));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityAuditExportSinkDeleted,
+                resource_type: "audit_export_sink".to_owned(),
+                resource_id: sink_id.to_owned(),
+                detail: None,
+            })
+            .await
+    }
+
+    /// Claims up to `sink_limit` sinks across tenants and ships their pending
+    /// entries in batches of `batch_size`, returning how many entries shipped.
+    ///
+    /// Delivery is at-least-once: a batch that fails part-way is shipped again
+    /// in full, so consumers should deduplicate on `event_id`. A failing sink
+    /// records its error and is retried later without blocking other sinks.
+    pub async fn ship_pending(&self, sink_limit: usize, batch_size: usize) -> AppResult<usize> {
+        let now = Utc::now();
+        let claimed = self
+            .repository
+            .claim_sinks(
+                now,
+                now + Duration::seconds(AUDIT_EXPORT_LEASE_SECONDS),
+                sink_limit,
+            )
+            .await?;
+
+        let mut shipped = 0;
+        for claimed_sink in claimed {
+            shipped += self.ship_claimed_sink(claimed_sink, batch_size).await?;
+        }
+
+        Ok(shipped)
+    }
+
+    async fn ship_claimed_sink(
+        &self,
+        claimed: ClaimedAuditExportSink,
+        batch_size: usize,
+    ) -> AppResult<usize> {
+        let ClaimedAuditExportSink { tenant_id, sink } = claimed;
+        let mut checkpoint = sink.checkpoint_position;
+        let mut shipped = 0;
+
+        for _ in 0..AUDIT_EXPORT_MAX_BATCHES_PER_CLAIM {
+            let entries = self
+                .repository
+                .list_entries_after(tenant_id, checkpoint, batch_size)
+                .await?;
+            let Some(last_position) = entries.last().map(|entry| entry.chain_position) else {
+                break;
+            };
+
+            if let Err(error) = self.shipper.ship(tenant_id, &sink, &entries).await {
+                self.repository
+                    .record_failure(
+                        tenant_id,
+                        sink.sink_id.as_str(),
+                        error.to_string().as_str(),
+                        Utc::now() + Duration::seconds(AUDIT_EXPORT_RETRY_SECONDS),
+                    )
+                    .await?;
+                return Ok(shipped);
+            }
+
+            self.repository
+                .record_checkpoint(tenant_id, sink.sink_id.as_str(), last_position, Utc::now())
+                .await?;
+            checkpoint = last_position;
+            shipped += entries.len();
+
+            if entries.len() < batch_size {
+                break;
+            }
+        }
+
+        self.repository
+            .release_sink(tenant_id, sink.sink_id.as_str())
+            .await?;
+
+        Ok(shipped)
+    }
+
+    async fn require_permission(
+        &self,
+        actor: &UserIdentity,
+        permission: Permission,
+    ) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(actor.tenant_id(), actor.subject(), permission)
+            .await
+    }
+
+    async fn append_sink_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        sink: &AuditExportSink,
+        mut detail: serde_json::Value,
+    ) -> AppResult<()> {
+        detail["name"] = serde_json::Value::from(sink.name.as_str());
+        detail["sink_type"] = serde_json::Value::from(sink.config.sink_type());
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "audit_export_sink".to_owned(),
+                resource_id: sink.sink_id.clone(),
+                detail: Some(detail.to_string()),
+            })
+            .await
+    }
+}
+
+fn normalize_sink_input(
+    input: CreateAuditExportSinkInput,
+) -> AppResult<CreateAuditExportSinkInput> {
+    let name = input.name.trim().to_owned();
+    if name.is_empty() || name.chars().count() > MAX_SINK_NAME_LENGTH {
+        return Err(AppError::Validation(format!(
+            "audit export sink name must be between 1 and {MAX_SINK_NAME_LENGTH} characters"
+        )));
+    }
+
+    let config = match input.config {
+        AuditExportSinkConfig::NdjsonFile => AuditExportSinkConfig::NdjsonFile,
+        AuditExportSinkConfig::S3 { bucket, prefix } => {
+            let bucket = bucket.trim().to_owned();
+            if !(3..=63).contains(&bucket.len())
+                || !bucket.chars().all(|character| {
+                    character.is_ascii_lowercase()
+                        || character.is_ascii_digit()
+                        || character == '-'
+                        || character == '.'
+                })
+            {
+                return Err(AppError::Validation(format!(
+                    "S3 bucket name '{bucket}' is invalid"
+                )));
+            }
+            let prefix = prefix
+                .map(|prefix| prefix.trim().trim_matches('/').to_owned())
+                .filter(|prefix| !prefix.is_empty());
+
+            AuditExportSinkConfig::S3 { bucket, prefix }
+        }
+        AuditExportSinkConfig::Syslog {
+            host,
+            port,
+            transport,
+        } => {
+            let host = host.trim().to_owned();
+            if host.is_empty()
+                || !host.chars().all(|character| {
+                    character.is_ascii_alphanumeric()
+                        || matches!(character, '-' | '.' | ':' | '[' | ']')
+                })
+            {
+                return Err(AppError::Validation(format!(
+                    "syslog host '{host}' is invalid"
+                )));
+            }
+            if port == 0 {
+                return Err(AppError::Validation(
+                    "syslog port must be greater than zero".to_owned(),
+                ));
+            }
+
+            AuditExportSinkConfig::Syslog {
+                host,
+                port,
+                transport,
+            }
+        }
+        AuditExportSinkConfig::HttpsWebhook {
+            url,
+            auth_header_name,
+            auth_secret_ref,
+        } => {
+            let url = url.trim().to_owned();
+            if url
+                .strip_prefix("https://")
+                .is_none_or(|rest| rest.is_empty() || rest.starts_with('/'))
+            {
+                return Err(AppError::Validation(
+                    "audit export webhook url must be an https URL".to_owned(),
+                ));
+            }
+
+            let auth_header_name = auth_header_name
+                .map(|name| name.trim().to_ascii_lowercase())
+                .filter(|name| !name.is_empty());
+            let auth_secret_ref = auth_secret_ref
+                .map(|reference| reference.trim().to_owned())
+                .filter(|reference| !reference.is_empty());
+            match (&auth_header_name, &auth_secret_ref) {
+                (Some(header_name), Some(secret_ref)) => {
+                    if !header_name
+                        .chars()
+                        .all(|character| character.is_ascii_alphanumeric() || character == '-')
+                    {
+                        return Err(AppError::Validation(format!(
+                            "audit export webhook header name '{header_name}' is invalid"
+                        )));
+                    }
+                    validate_secret_reference(secret_ref.as_str())?;
+                }
+                (None, None) => {}
+                _ => {
+                    return Err(AppError::Validation(
+                        "audit export webhook auth requires both a header name and a secret reference"
+                            .to_owned(),
+                    ));
+                }
+            }
+
+            AuditExportSinkConfig::HttpsWebhook {
+                url,
+                auth_header_name,
+                auth_secret_ref,
+            }
+        }
+    };
+
+    Ok(CreateAuditExportSinkInput {
+        name,
+        config,
+        backfill: input.backfill,
+    })
+}
@@ -0,0 +1,505 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditLogEntry, AuditRepository, AuthorizationRepository, AuthorizationService,
+    RuntimeFieldGrant, TemporaryPermissionGrant,
+};
+
+use super::{
+    AuditExportService, AuditExportShipper, AuditExportSink, AuditExportSinkConfig,
+    AuditExportSinkRepository, ClaimedAuditExportSink, CreateAuditExportSinkInput,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditExportSinkRepository {
+    sinks: Mutex<Vec<(TenantId, AuditExportSink, bool)>>,
+    entries: Mutex<Vec<(TenantId, AuditLogEntry)>>,
+}
+
+impl FakeAuditExportSinkRepository {
+    async fn sink(&self, sink_id: &str) -> AuditExportSink {
+        self.sinks
+            .lock()
+            .await
+            .iter()
+            .find(|(_, sink, _)| sink.sink_id == sink_id)
+            .map(|(_, sink, _)| sink.clone())
+            .unwrap_or_else(|| unreachable!())
+    }
+
+    async fn append_entries(&self, tenant_id: TenantId, count: i64) {
+        let mut entries = self.entries.lock().await;
+        let start = entries
+            .iter()
+            .filter(|(entry_tenant_id, _)| *entry_tenant_id == tenant_id)
+            .count() as i64;
+        for chain_position in start + 1..=start + count {
+            entries.push((
+                tenant_id,
+                AuditLogEntry {
+                    event_id: format!("event-{chain_position}"),
+                    subject: "alice".to_owned(),
+                    action: "metadata.entity.created".to_owned(),
+                    resource_type: "entity".to_owned(),
+                    resource_id: "contact".to_owned(),
+                    detail: None,
+                    created_at: Utc::now().to_rfc3339(),
+                    chain_position,
+                    previous_entry_hash: None,
+                    entry_hash: format!("hash-{chain_position}"),
+                },
+            ));
+        }
+    }
+}
+
+#[async_trait]
+impl AuditExportSinkRepository for FakeAuditExportSinkRepository {
+    async fn create_sink(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        input: CreateAuditExportSinkInput,
+    ) -> AppResult<AuditExportSink> {
+        let checkpoint_position = if input.backfill {
+            0
+        } else {
+            self.entries
+                .lock()
+                .await
+                .iter()
+                .filter(|(entry_tenant_id, _)| *entry_tenant_id == tenant_id)
+                .map(|(_, entry)| entry.chain_position)
+                .max()
+                .unwrap_or_default()
+        };
+        let mut sinks = self.sinks.lock().await;
+        let sink = AuditExportSink {
+            sink_id: format!("sink-{}", sinks.len() + 1),
+            name: input.name,
+            config: input.config,
+            is_enabled: true,
+            checkpoint_position,
+            last_exported_at: None,
+            last_error: None,
+            created_by_subject: created_by_subject.to_owned(),
+            created_at: Utc::now(),
+        };
+        sinks.push((tenant_id, sink.clone(), false));
+        Ok(sink)
+    }
+
+    async fn list_sinks(&self, tenant_id: TenantId) -> AppResult<Vec<AuditExportSink>> {
+        Ok(self
+            .sinks
+            .lock()
+            .await
+            .iter()
+            .filter(|(sink_tenant_id, _, _)| *sink_tenant_id == tenant_id)
+            .map(|(_, sink, _)| sink.clone())
+            .collect())
+    }
+
+    async fn set_sink_enabled(
+        &self,
+        tenant_id: TenantId,
+        sink_id: &str,
+        is_enabled: bool,
+    ) -> AppResult<Option<AuditExportSink>> {
+        let mut sinks = self.sinks.lock().await;
+        Ok(sinks
+            .iter_mut()
+            .find(|(sink_tenant_id, sink, _)| {
+                *sink_tenant_id == tenant_id && sink.sink_id == sink_id
+            })
+            .map(|(_, sink, _)| {
+                sink.is_enabled = is_enabled;
+                sink.clone()
+            }))
+    }
+
+    async fn delete_sink(&self, tenant_id: TenantId, sink_id: &str) -> AppResult<bool> {
+        let mut sinks = self.sinks.lock().await;
+        let before = sinks.len();
+        sinks.retain(|(sink_tenant_id, sink, _)| {
+            !(*sink_tenant_id == tenant_id && sink.sink_id == sink_id)
+        });
+        Ok(sinks.len() < before)
+    }
+
+    async fn claim_sinks(
+        &self,
+        _now: DateTime<Utc>,
+        _lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<ClaimedAuditExportSink>> {
+        let mut sinks = self.sinks.lock().await;
+        Ok(sinks
+            .iter_mut()
+            .filter(|(_, sink, leased)| sink.is_enabled && !*leased)
+            .take(limit)
+            .map(|(tenant_id, sink, leased)| {
+                *leased = true;
+                ClaimedAuditExportSink {
+                    tenant_id: *tenant_id,
+                    sink: sink.clone(),
+                }
+            })
+            .collect())
+    }
+
+    async fn list_entries_after(
+        &self,
+        tenant_id: TenantId,
+        chain_position: i64,
+        limit: usize,
+    ) -> AppResult<Vec<AuditLogEntry>> {
+        Ok(self
+            .entries
+            .lock()
+            .await
+            .iter()
+            .filter(|(entry_tenant_id, entry)| {
+                *entry_tenant_id == tenant_id && entry.chain_position > chain_position
+            })
+            .take(limit)
+            .map(|(_, entry)| entry.clone())
+            .collect())
+    }
+
+    async fn record_checkpoint(
+        &self,
+        _tenant_id: TenantId,
+        sink_id: &str,
+        chain_position: i64,
+        exported_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        let mut sinks = self.sinks.lock().await;
+        if let Some((_, sink, _)) = sinks
+            .iter_mut()
+            .find(|(_, sink, _)| sink.sink_id == sink_id)
+        {
+            sink.checkpoint_position = chain_position;
+            sink.last_exported_at = Some(exported_at);
+            sink.last_error = None;
+        }
+        Ok(())
+    }
+
+    async fn record_failure(
+        &self,
+        _tenant_id: TenantId,
+        sink_id: &str,
+        error: &str,
+        _retry_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        let mut sinks = self.sinks.lock().await;
+        if let Some((_, sink, _)) = sinks
+            .iter_mut()
+            .find(|(_, sink, _)| sink.sink_id == sink_id)
+        {
+            sink.last_error = Some(error.to_owned());
+        }
+        Ok(())
+    }
+
+    async fn release_sink(&self, _tenant_id: TenantId, sink_id: &str) -> AppResult<()> {
+        let mut sinks = self.sinks.lock().await;
+        if let Some((_, _, leased)) = sinks
+            .iter_mut()
+            .find(|(_, sink, _)| sink.sink_id == sink_id)
+        {
+            *leased = false;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditExportShipper {
+    batches: Mutex<Vec<Vec<i64>>>,
+    failures_remaining: Mutex<usize>,
+}
+
+#[async_trait]
+impl AuditExportShipper for FakeAuditExportShipper {
+    async fn ship(
+        &self,
+        _tenant_id: TenantId,
+        _sink: &AuditExportSink,
+        entries: &[AuditLogEntry],
+    ) -> AppResult<()> {
+        let mut failures_remaining = self.failures_remaining.lock().await;
+        if *failures_remaining > 0 {
+            *failures_remaining -= 1;
+            return Err(AppError::Internal("collector unavailable".to_owned()));
+        }
+
+        self.batches
+            .lock()
+            .await
+            .push(entries.iter().map(|entry| entry.chain_position).collect());
+        Ok(())
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    permissions: Vec<Permission>,
+    repository: Arc<FakeAuditExportSinkRepository>,
+    shipper: Arc<FakeAuditExportShipper>,
+) -> (AuditExportService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([((tenant_id, "alice".to_owned()), permissions)]),
+        }),
+        audit_repository.clone(),
+    );
+    let service = AuditExportService::new(
+        authorization_service,
+        repository,
+        shipper,
+        audit_repository.clone(),
+    );
+    (service, audit_repository)
+}
+
+fn webhook_input(url: &str) -> CreateAuditExportSinkInput {
+    CreateAuditExportSinkInput {
+        name: " Splunk HEC ".to_owned(),
+        config: AuditExportSinkConfig::HttpsWebhook {
+            url: url.to_owned(),
+            auth_header_name: Some("Authorization".to_owned()),
+            auth_secret_ref: Some("op://vault/splunk/token".to_owned()),
+        },
+        backfill: true,
+    }
+}
+
+#[tokio::test]
+async fn creating_sinks_requires_role_manage_and_validates_config() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeAuditExportSinkRepository::default());
+    let (reader_service, _) = build_service(
+        tenant_id,
+        vec![Permission::SecurityAuditRead],
+        repository.clone(),
+        Arc::new(FakeAuditExportShipper::default()),
+    );
+    let denied = reader_service
+        .create_sink(
+            &actor(tenant_id, "alice"),
+            webhook_input("https://siem.test"),
+        )
+        .await;
+    assert!(matches!(denied, Err(AppError::Forbidden(_))));
+
+    let (service, audit_repository) = build_service(
+        tenant_id,
+        vec![Permission::SecurityRoleManage],
+        repository,
+        Arc::new(FakeAuditExportShipper::default()),
+    );
+    let actor = actor(tenant_id, "alice");
+
+    let sink = service
+        .create_sink(&actor, webhook_input(" https://siem.test/hec "))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(sink.name, "Splunk HEC");
+    assert_eq!(
+        sink.config,
+        AuditExportSinkConfig::HttpsWebhook {
+            url: "https://siem.test/hec".to_owned(),
+            auth_header_name: Some("authorization".to_owned()),
+            auth_secret_ref: Some("op://vault/splunk/token".to_owned()),
+        }
+    );
+    let events = audit_repository.events.lock().await;
+    assert_eq!(
+        events.last().map(|event| event.action),
+        Some(AuditAction::SecurityAuditExportSinkCreated)
+    );
+    drop(events);
+
+    for invalid in [
+        webhook_input("http://siem.test/hec"),
+        CreateAuditExportSinkInput {
+            config: AuditExportSinkConfig::HttpsWebhook {
+                url: "https://siem.test/hec".to_owned(),
+                auth_header_name: Some("authorization".to_owned()),
+                auth_secret_ref: None,
+            },
+            ..webhook_input("https://siem.test/hec")
+        },
+        CreateAuditExportSinkInput {
+            config: AuditExportSinkConfig::S3 {
+                bucket: "Audit_Bucket".to_owned(),
+                prefix: None,
+            },
+            ..webhook_input("https://siem.test/hec")
+        },
+    ] {
+        let result = service.create_sink(&actor, invalid).await;
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+}
+
+#[tokio::test]
+async fn shipping_advances_checkpoints_in_batches_and_resumes_after_failures() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeAuditExportSinkRepository::default());
+    let shipper = Arc::new(FakeAuditExportShipper::default());
+    let (service, _) = build_service(
+        tenant_id,
+        vec![Permission::SecurityRoleManage],
+        repository.clone(),
+        shipper.clone(),
+    );
+    let actor = actor(tenant_id, "alice");
+
+    repository.append_entries(tenant_id, 3).await;
+    let backfill = service
+        .create_sink(&actor, webhook_input("https://siem.test/hec"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let tail = service
+        .create_sink(
+            &actor,
+            CreateAuditExportSinkInput {
+                name: "Archive".to_owned(),
+                config: AuditExportSinkConfig::NdjsonFile,
+                backfill: false,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(tail.checkpoint_position, 3);
+
+    repository.append_entries(tenant_id, 2).await;
+    *shipper.failures_remaining.lock().await = 1;
+    let shipped = service
+        .ship_pending(10, 2)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(shipped, 2);
+    let failed = repository.sink(backfill.sink_id.as_str()).await;
+    assert_eq!(failed.checkpoint_position, 0);
+    assert_eq!(
+        failed.last_error.as_deref(),
+        Some("internal error: collector unavailable")
+    );
+    assert_eq!(
+        repository
+            .sink(tail.sink_id.as_str())
+            .await
+            .checkpoint_position,
+        5
+    );
+
+    // The failed sink keeps its lease until the retry window; release it for the test.
+    repository
+        .release_sink(tenant_id, backfill.sink_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let shipped = service
+        .ship_pending(10, 2)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(shipped, 5);
+    let recovered = repository.sink(backfill.sink_id.as_str()).await;
+    assert_eq!(recovered.checkpoint_position, 5);
+    assert_eq!(recovered.last_error, None);
+    assert_eq!(
+        *shipper.batches.lock().await,
+        vec![vec![4, 5], vec![1, 2], vec![3, 4], vec![5]]
+    );
+}
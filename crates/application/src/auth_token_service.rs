@@ -1,4 +1,5 @@
-//! Auth token management for password resets, email verification, and invites.
+//! Auth token management for password resets, email verification, email changes,
+//! invites, and bootstrap login.
 //!
 //! Tokens are cryptographically random, stored as SHA-256 hashes, single-use,
 //! and time-limited per OWASP Forgot Password Cheat Sheet.
@@ -63,6 +64,12 @@ pub trait AuthTokenRepository: Send + Sync {
         token_type: AuthTokenType,
     ) -> AppResult<()>;
 
+    /// Returns whether a token with this hash was ever issued, used or not.
+    async fn token_exists(&self, token_hash: &str, token_type: AuthTokenType) -> AppResult<bool>;
+
+    /// Invalidates all unused tokens of a given type and returns how many were revoked.
+    async fn invalidate_tokens_of_type(&self, token_type: AuthTokenType) -> AppResult<u64>;
+
     /// Counts tokens created in a time window for rate limiting.
     async fn count_recent_tokens(
         &self,
@@ -72,6 +79,17 @@ pub trait AuthTokenRepository: Send + Sync {
     ) -> AppResult<i64>;
 }
 
+/// File attached to an outgoing email.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailAttachment {
+    /// File name shown to the recipient.
+    pub filename: String,
+    /// MIME content type of the file.
+    pub content_type: String,
+    /// Raw file bytes.
+    pub content: Vec<u8>,
+}
+
 /// Port for sending emails. Infrastructure provides SMTP or console implementations.
 #[async_trait]
 pub trait EmailService: Send + Sync {
@@ -83,6 +101,15 @@ pub trait EmailService: Send + Sync {
         text_body: &str,
         html_body: Option<&str>,
     ) -> AppResult<()>;
+
+    /// Sends a plain-text email with file attachments.
+    async fn send_email_with_attachments(
+        &self,
+        to: &str,
+        subject: &str,
+        text_body: &str,
+        attachments: &[EmailAttachment],
+    ) -> AppResult<()>;
 }
 
 /// Application service for managing auth tokens and related email flows.
@@ -115,11 +142,15 @@ impl AuthTokenService {
     }
 }
 
+mod bootstrap;
 mod consume;
+mod email_change;
 mod email_verification;
 mod invite;
 mod password_reset;
-mod token_crypto;
+pub(crate) mod token_crypto;
+
+pub use bootstrap::IssuedBootstrapToken;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Duration, Utc};
+use qryvanta_domain::AuthTokenType;
+
+use super::token_crypto::{generate_token, hash_token};
+use super::*;
+
+/// Bootstrap tokens are not addressed to a mailbox.
+const BOOTSTRAP_TOKEN_EMAIL: &str = "";
+
+/// Newly issued bootstrap token. Only its hash is persisted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssuedBootstrapToken {
+    /// Raw token value, shown to the operator once.
+    pub token: String,
+    /// Expiration timestamp.
+    pub expires_at: DateTime<Utc>,
+}
+
+impl AuthTokenService {
+    /// Registers the configured bootstrap token the first time it is seen.
+    ///
+    /// Returns `false` when the token was registered before, so a restart
+    /// never revives a consumed or expired configured token.
+    pub async fn register_bootstrap_token(
+        &self,
+        raw_token: &str,
+        ttl: Duration,
+    ) -> AppResult<bool> {
+        let token_hash = hash_token(raw_token);
+        if self
+            .token_repository
+            .token_exists(&token_hash, AuthTokenType::Bootstrap)
+            .await?
+        {
+            return Ok(false);
+        }
+
+        let created = self
+            .token_repository
+            .create_token(
+                None,
+                BOOTSTRAP_TOKEN_EMAIL,
+                &token_hash,
+                AuthTokenType::Bootstrap,
+                Utc::now() + ttl,
+                Some(&serde_json::json!({ "source": "environment" })),
+            )
+            .await;
+
+        match created {
+            Ok(_) => Ok(true),
+            // Another replica registered the same token concurrently.
+            Err(_)
+                if self
+                    .token_repository
+                    .token_exists(&token_hash, AuthTokenType::Bootstrap)
+                    .await? =>
+            {
+                Ok(false)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Revokes every unused bootstrap token and issues a fresh one.
+    pub async fn rotate_bootstrap_token(&self, ttl: Duration) -> AppResult<IssuedBootstrapToken> {
+        self.token_repository
+            .invalidate_tokens_of_type(AuthTokenType::Bootstrap)
+            .await?;
+
+        let (raw_token, token_hash) = generate_token()?;
+        let expires_at = Utc::now() + ttl;
+        self.token_repository
+            .create_token(
+                None,
+                BOOTSTRAP_TOKEN_EMAIL,
+                &token_hash,
+                AuthTokenType::Bootstrap,
+                expires_at,
+                Some(&serde_json::json!({ "source": "rotation" })),
+            )
+            .await?;
+
+        Ok(IssuedBootstrapToken {
+            token: raw_token,
+            expires_at,
+        })
+    }
+}
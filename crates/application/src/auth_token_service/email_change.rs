@@ -0,0 +1,109 @@
+use qryvanta_domain::{AuthTokenType, EmailAddress, UserId};
+
+use crate::EmailChangeConfirmation;
+
+use super::token_crypto::generate_token;
+use super::*;
+
+impl AuthTokenService {
+    /// Issues email change confirmation tokens and sends one to each address.
+    ///
+    /// The current address and the new address each receive their own
+    /// single-use link. Token metadata records which side the token confirms
+    /// and the requested address so a confirmation cannot be replayed against
+    /// a later change request.
+    pub async fn send_email_change_confirmations(
+        &self,
+        user_id: UserId,
+        current_email: &str,
+        new_email: &str,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<()> {
+        let current_email = EmailAddress::new(current_email)?;
+        let new_email = EmailAddress::new(new_email)?;
+
+        // Invalidate confirmation links from earlier change requests.
+        self.token_repository
+            .invalidate_tokens_for_user(user_id, AuthTokenType::EmailChange)
+            .await?;
+
+        let current_token = self
+            .issue_email_change_token(
+                user_id,
+                &current_email,
+                &new_email,
+                EmailChangeConfirmation::CurrentAddress,
+                expires_at,
+            )
+            .await?;
+        let new_token = self
+            .issue_email_change_token(
+                user_id,
+                &new_email,
+                &new_email,
+                EmailChangeConfirmation::NewAddress,
+                expires_at,
+            )
+            .await?;
+
+        let subject = "Confirm your Qryvanta email change";
+        let current_body = format!(
+            "A request was made to change the email address on your Qryvanta account to {}.\n\n\
+             Confirm the change from this address by clicking the link below:\n{}\n\n\
+             This link expires in 24 hours. If you did not request this change, \
+             cancel it from your profile and change your password.",
+            new_email.as_str(),
+            self.email_change_url(&current_token),
+        );
+        let new_body = format!(
+            "A request was made to use this address for a Qryvanta account.\n\n\
+             Confirm the new address by clicking the link below:\n{}\n\n\
+             This link expires in 24 hours.",
+            self.email_change_url(&new_token),
+        );
+
+        self.email_service
+            .send_email(current_email.as_str(), subject, &current_body, None)
+            .await?;
+        self.email_service
+            .send_email(new_email.as_str(), subject, &new_body, None)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn issue_email_change_token(
+        &self,
+        user_id: UserId,
+        recipient: &EmailAddress,
+        new_email: &EmailAddress,
+        confirmation: EmailChangeConfirmation,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<String> {
+        let (raw_token, token_hash) = generate_token()?;
+        let metadata = serde_json::json!({
+            "confirmation": confirmation.as_str(),
+            "new_email": new_email.as_str(),
+        });
+
+        self.token_repository
+            .create_token(
+                Some(user_id),
+                recipient.as_str(),
+                &token_hash,
+                AuthTokenType::EmailChange,
+                expires_at,
+                Some(&metadata),
+            )
+            .await?;
+
+        Ok(raw_token)
+    }
+
+    fn email_change_url(&self, raw_token: &str) -> String {
+        format!(
+            "{}/confirm-email-change?token={}",
+            self.frontend_url, raw_token
+        )
+    }
+}
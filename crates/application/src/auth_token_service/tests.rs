@@ -4,11 +4,15 @@ use async_trait::async_trait;
 use qryvanta_core::AppResult;
 use qryvanta_domain::AuthTokenType;
 
-use super::{AuthTokenRecord, AuthTokenRepository, AuthTokenService, EmailService};
+use super::{
+    AuthTokenRecord, AuthTokenRepository, AuthTokenService, EmailAttachment, EmailService,
+};
 
 #[derive(Default)]
 struct TestTokenRepo {
     created: Mutex<Vec<(String, AuthTokenType, Option<serde_json::Value>)>>,
+    hashes: Mutex<Vec<String>>,
+    invalidated: Mutex<Vec<AuthTokenType>>,
 }
 
 #[async_trait]
@@ -17,11 +21,17 @@ impl AuthTokenRepository for TestTokenRepo {
         &self,
         _user_id: Option<qryvanta_domain::UserId>,
         email: &str,
-        _token_hash: &str,
+        token_hash: &str,
         token_type: AuthTokenType,
         _expires_at: chrono::DateTime<chrono::Utc>,
         metadata: Option<&serde_json::Value>,
     ) -> AppResult<uuid::Uuid> {
+        self.hashes
+            .lock()
+            .map_err(|error| {
+                qryvanta_core::AppError::Internal(format!("failed to lock repo state: {error}"))
+            })?
+            .push(token_hash.to_owned());
         self.created
             .lock()
             .map_err(|error| {
@@ -47,6 +57,27 @@ impl AuthTokenRepository for TestTokenRepo {
         Ok(())
     }
 
+    async fn token_exists(&self, token_hash: &str, _token_type: AuthTokenType) -> AppResult<bool> {
+        Ok(self
+            .hashes
+            .lock()
+            .map_err(|error| {
+                qryvanta_core::AppError::Internal(format!("failed to lock repo state: {error}"))
+            })?
+            .iter()
+            .any(|hash| hash == token_hash))
+    }
+
+    async fn invalidate_tokens_of_type(&self, token_type: AuthTokenType) -> AppResult<u64> {
+        self.invalidated
+            .lock()
+            .map_err(|error| {
+                qryvanta_core::AppError::Internal(format!("failed to lock repo state: {error}"))
+            })?
+            .push(token_type);
+        Ok(0)
+    }
+
     async fn count_recent_tokens(
         &self,
         _email: &str,
@@ -81,6 +112,16 @@ impl EmailService for TestEmailService {
             .push((to.to_owned(), subject.to_owned()));
         Ok(())
     }
+
+    async fn send_email_with_attachments(
+        &self,
+        to: &str,
+        subject: &str,
+        text_body: &str,
+        _attachments: &[EmailAttachment],
+    ) -> AppResult<()> {
+        self.send_email(to, subject, text_body, None).await
+    }
 }
 
 #[tokio::test]
@@ -112,3 +153,45 @@ async fn send_invite_persists_invite_token_and_sends_email() {
     let sent = email.sent.lock().ok().map(|guard| guard.len()).unwrap_or(0);
     assert_eq!(sent, 1);
 }
+
+#[tokio::test]
+async fn configured_bootstrap_token_registers_once_and_rotation_revokes_outstanding() {
+    let repo = Arc::new(TestTokenRepo::default());
+    let service = AuthTokenService::new(
+        repo.clone(),
+        Arc::new(TestEmailService::default()),
+        "http://localhost:3000".to_owned(),
+    );
+    let ttl = chrono::Duration::hours(1);
+
+    let first = service
+        .register_bootstrap_token("configured-bootstrap-token", ttl)
+        .await;
+    let second = service
+        .register_bootstrap_token("configured-bootstrap-token", ttl)
+        .await;
+    assert!(matches!(first, Ok(true)));
+    assert!(matches!(second, Ok(false)));
+
+    let issued = service
+        .rotate_bootstrap_token(ttl)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(issued.token.len(), 64);
+    assert!(issued.expires_at > chrono::Utc::now());
+
+    let hashes = repo
+        .hashes
+        .lock()
+        .map(|guard| guard.clone())
+        .unwrap_or_default();
+    assert_eq!(hashes.len(), 2);
+    assert!(!hashes.contains(&issued.token));
+    assert_eq!(
+        repo.invalidated
+            .lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_default(),
+        vec![AuthTokenType::Bootstrap]
+    );
+}
@@ -5,7 +5,7 @@ use super::*;
 /// Generates a cryptographically random token and its SHA-256 hash.
 ///
 /// Returns `(raw_token_hex, sha256_hash_hex)`.
-pub(super) fn generate_token() -> AppResult<(String, String)> {
+pub(crate) fn generate_token() -> AppResult<(String, String)> {
     use std::fmt::Write;
 
     let mut bytes = [0u8; 32];
@@ -24,7 +24,7 @@ pub(super) fn generate_token() -> AppResult<(String, String)> {
 }
 
 /// Computes the SHA-256 hash of a token string for storage.
-pub(super) fn hash_token(raw_token: &str) -> String {
+pub(crate) fn hash_token(raw_token: &str) -> String {
     use sha2::{Digest, Sha256};
     use std::fmt::Write;
 
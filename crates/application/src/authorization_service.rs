@@ -2,10 +2,14 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use qryvanta_core::{AppResult, TenantId};
-use qryvanta_domain::Permission;
+use qryvanta_domain::{FieldMaskingMode, Permission};
 
 use crate::AuditRepository;
 
+pub use explain::{
+    AccessDecision, AccessExplanation, PermissionCheck, SubjectAppRolePermission, SubjectRoleGrant,
+};
+
 /// Runtime field-level grant row resolved for one subject.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RuntimeFieldGrant {
@@ -24,6 +28,8 @@ pub struct RuntimeFieldAccess {
     pub readable_fields: std::collections::BTreeSet<String>,
     /// Fields writable by the subject.
     pub writable_fields: std::collections::BTreeSet<String>,
+    /// Fields shown masked instead of in full; they are not readable.
+    pub masked_fields: std::collections::BTreeMap<String, FieldMaskingMode>,
 }
 
 /// Active temporary permission grant projection.
@@ -62,6 +68,28 @@ pub trait AuthorizationRepository: Send + Sync {
         subject: &str,
         permission: Permission,
     ) -> AppResult<Option<TemporaryPermissionGrant>>;
+
+    /// Lists other subjects that share at least one security team with a subject.
+    async fn list_team_peer_subjects(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<String>>;
+
+    /// Lists roles a subject holds directly or through its teams.
+    async fn list_role_grants_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<SubjectRoleGrant>>;
+
+    /// Lists app role permissions for an entity across the subject's roles.
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<SubjectAppRolePermission>>;
 }
 
 /// Application service for tenant-scoped authorization checks.
@@ -91,6 +119,7 @@ enum PermissionGrantResolution {
     Missing,
 }
 
+mod explain;
 mod permissions;
 mod runtime_fields;
 mod surfaces;
@@ -0,0 +1,318 @@
+use qryvanta_core::{AppError, UserIdentity};
+use qryvanta_domain::AppEntityAction;
+
+use super::*;
+
+/// Role a subject holds, directly or through a security team.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubjectRoleGrant {
+    /// Role name.
+    pub role_name: String,
+    /// Team the role is assigned through, `None` for direct assignments.
+    pub team_name: Option<String>,
+    /// Permissions granted by the role.
+    pub permissions: Vec<Permission>,
+}
+
+impl SubjectRoleGrant {
+    fn label(&self) -> String {
+        match &self.team_name {
+            Some(team_name) => format!("'{}' via team '{team_name}'", self.role_name),
+            None => format!("'{}'", self.role_name),
+        }
+    }
+}
+
+/// App-scoped entity permission of one role a subject holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubjectAppRolePermission {
+    /// App logical name.
+    pub app_logical_name: String,
+    /// Role the permission is configured for.
+    pub role_name: String,
+    /// Whether any role of the subject is bound to the app.
+    pub app_accessible: bool,
+    /// Read access in the app.
+    pub can_read: bool,
+    /// Create access in the app.
+    pub can_create: bool,
+    /// Update access in the app.
+    pub can_update: bool,
+    /// Delete access in the app.
+    pub can_delete: bool,
+}
+
+impl SubjectAppRolePermission {
+    /// Returns whether the role permission allows an action.
+    #[must_use]
+    pub fn allows(&self, action: AppEntityAction) -> bool {
+        match action {
+            AppEntityAction::Read => self.can_read,
+            AppEntityAction::Create => self.can_create,
+            AppEntityAction::Update => self.can_update,
+            AppEntityAction::Delete => self.can_delete,
+        }
+    }
+}
+
+/// Tenant permission evaluated while explaining an access decision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionCheck {
+    /// Permission that was evaluated.
+    pub permission: Permission,
+    /// Roles granting the permission.
+    pub granting_roles: Vec<String>,
+    /// Active temporary grant, consulted when no role grants the permission.
+    pub temporary_grant: Option<TemporaryPermissionGrant>,
+}
+
+impl PermissionCheck {
+    /// Returns whether a role or a temporary grant provides the permission.
+    #[must_use]
+    pub fn is_granted(&self) -> bool {
+        !self.granting_roles.is_empty() || self.temporary_grant.is_some()
+    }
+}
+
+/// Final outcome of an access explanation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessDecision {
+    /// The subject can perform the action.
+    Allowed,
+    /// The subject cannot perform the action.
+    Denied,
+}
+
+impl AccessDecision {
+    /// Returns a stable transport value for this decision.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Allowed => "allowed",
+            Self::Denied => "denied",
+        }
+    }
+}
+
+/// Evaluation trace of one runtime record action for one subject.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessExplanation {
+    /// Subject the access was evaluated for.
+    pub subject: String,
+    /// Entity the action targets.
+    pub entity_logical_name: String,
+    /// Evaluated action.
+    pub action: AppEntityAction,
+    /// Roles the subject holds.
+    pub roles: Vec<SubjectRoleGrant>,
+    /// Tenant permissions that allow the action, full access first.
+    pub permission_checks: Vec<PermissionCheck>,
+    /// App role permissions configured for the entity.
+    pub app_permissions: Vec<SubjectAppRolePermission>,
+    /// Field-level grants that narrow access to the entity.
+    pub field_grants: Vec<RuntimeFieldGrant>,
+    /// Final decision.
+    pub decision: AccessDecision,
+    /// Human-readable evaluation steps, in order.
+    pub trace: Vec<String>,
+}
+
+impl AuthorizationService {
+    /// Explains whether a subject can perform a runtime record action.
+    ///
+    /// The trace walks the same layers as enforcement: roles held directly
+    /// or through teams, the tenant runtime permissions with temporary grants
+    /// as fallback, app role permissions for the entity and field grants.
+    /// Explaining never records temporary grant usage.
+    pub async fn explain_runtime_access(
+        &self,
+        actor: &UserIdentity,
+        subject: &str,
+        entity_logical_name: &str,
+        action: AppEntityAction,
+    ) -> AppResult<AccessExplanation> {
+        self.require_permission(
+            actor.tenant_id(),
+            actor.subject(),
+            Permission::SecurityRoleManage,
+        )
+        .await?;
+
+        let subject = subject.trim();
+        let entity_logical_name = entity_logical_name.trim();
+        if subject.is_empty() || entity_logical_name.is_empty() {
+            return Err(AppError::Validation(
+                "subject and entity_logical_name are required to explain access".to_owned(),
+            ));
+        }
+
+        let tenant_id = actor.tenant_id();
+        let mut trace = Vec::new();
+
+        let roles = self
+            .repository
+            .list_role_grants_for_subject(tenant_id, subject)
+            .await?;
+        if roles.is_empty() {
+            trace.push(format!("subject '{subject}' holds no roles"));
+        } else {
+            trace.push(format!(
+                "subject '{subject}' holds roles {}",
+                roles
+                    .iter()
+                    .map(SubjectRoleGrant::label)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        let candidate_permissions = match action {
+            AppEntityAction::Read => [
+                Permission::RuntimeRecordRead,
+                Permission::RuntimeRecordReadOwn,
+            ],
+            AppEntityAction::Create | AppEntityAction::Update | AppEntityAction::Delete => [
+                Permission::RuntimeRecordWrite,
+                Permission::RuntimeRecordWriteOwn,
+            ],
+        };
+        let mut permission_checks = Vec::new();
+        for permission in candidate_permissions {
+            let granting = roles
+                .iter()
+                .filter(|role| role.permissions.contains(&permission));
+            let granting_labels: Vec<String> =
+                granting.clone().map(SubjectRoleGrant::label).collect();
+            let mut granting_roles: Vec<String> =
+                granting.map(|role| role.role_name.clone()).collect();
+            granting_roles.dedup();
+            let temporary_grant = if granting_roles.is_empty() {
+                self.repository
+                    .find_active_temporary_permission_grant(tenant_id, subject, permission)
+                    .await?
+            } else {
+                None
+            };
+
+            trace.push(match (&temporary_grant, granting_roles.is_empty()) {
+                (_, false) => format!(
+                    "permission '{}' is granted by role {}",
+                    permission.as_str(),
+                    granting_labels.join(", ")
+                ),
+                (Some(grant), true) => format!(
+                    "permission '{}' is granted by temporary grant '{}' until {}",
+                    permission.as_str(),
+                    grant.grant_id,
+                    grant.expires_at
+                ),
+                (None, true) => format!("permission '{}' is missing", permission.as_str()),
+            });
+            permission_checks.push(PermissionCheck {
+                permission,
+                granting_roles,
+                temporary_grant,
+            });
+        }
+
+        let app_permissions = self
+            .repository
+            .list_app_role_permissions_for_subject(tenant_id, subject, entity_logical_name)
+            .await?;
+        if app_permissions.is_empty() {
+            trace.push(format!(
+                "no app role of the subject configures entity '{entity_logical_name}'"
+            ));
+        }
+        for app_permission in &app_permissions {
+            let prefix = format!(
+                "app '{}' role '{}'",
+                app_permission.app_logical_name, app_permission.role_name
+            );
+            trace.push(
+                match (app_permission.allows(action), app_permission.app_accessible) {
+                    (false, _) => format!("{prefix} does not allow '{}'", action.as_str()),
+                    (true, true) => format!("{prefix} allows '{}'", action.as_str()),
+                    (true, false) => format!(
+                        "{prefix} allows '{}', but no role of the subject is bound to the app",
+                        action.as_str()
+                    ),
+                },
+            );
+        }
+
+        let field_grants = self
+            .repository
+            .list_runtime_field_grants_for_subject(tenant_id, subject, entity_logical_name)
+            .await?;
+        if field_grants.is_empty() {
+            trace.push(format!(
+                "no field grants narrow entity '{entity_logical_name}'"
+            ));
+        } else {
+            let (kind, fields): (&str, Vec<&str>) = match action {
+                AppEntityAction::Read => (
+                    "readable",
+                    field_grants
+                        .iter()
+                        .filter(|grant| grant.can_read)
+                        .map(|grant| grant.field_logical_name.as_str())
+                        .collect(),
+                ),
+                _ => (
+                    "writable",
+                    field_grants
+                        .iter()
+                        .filter(|grant| grant.can_write)
+                        .map(|grant| grant.field_logical_name.as_str())
+                        .collect(),
+                ),
+            };
+            trace.push(if fields.is_empty() {
+                format!("field grants leave no {kind} fields")
+            } else {
+                format!("field grants limit {kind} fields to {}", fields.join(", "))
+            });
+        }
+
+        let tenant_permission = permission_checks
+            .iter()
+            .find(|check| check.is_granted())
+            .map(|check| check.permission);
+        let mut allowing_apps: Vec<&str> = app_permissions
+            .iter()
+            .filter(|app_permission| app_permission.app_accessible && app_permission.allows(action))
+            .map(|app_permission| app_permission.app_logical_name.as_str())
+            .collect();
+        allowing_apps.dedup();
+
+        let decision = if tenant_permission.is_some() || !allowing_apps.is_empty() {
+            AccessDecision::Allowed
+        } else {
+            AccessDecision::Denied
+        };
+        trace.push(match (tenant_permission, allowing_apps.is_empty()) {
+            (Some(permission), _) => format!(
+                "allowed through tenant permission '{}'",
+                permission.as_str()
+            ),
+            (None, false) => format!("allowed in apps {}", allowing_apps.join(", ")),
+            (None, true) => format!(
+                "denied: no tenant permission or app role allows '{}' on entity '{entity_logical_name}'",
+                action.as_str()
+            ),
+        });
+
+        Ok(AccessExplanation {
+            subject: subject.to_owned(),
+            entity_logical_name: entity_logical_name.to_owned(),
+            action,
+            roles,
+            permission_checks,
+            app_permissions,
+            field_grants,
+            decision,
+            trace,
+        })
+    }
+}
@@ -32,6 +32,39 @@ impl AuthorizationService {
         Ok(Some(RuntimeFieldAccess {
             readable_fields,
             writable_fields,
+            masked_fields: std::collections::BTreeMap::new(),
         }))
     }
+
+    /// Returns the subject plus every subject sharing a security team with it.
+    pub async fn team_subjects(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<String>> {
+        let mut subjects = self
+            .repository
+            .list_team_peer_subjects(tenant_id, subject)
+            .await?;
+        subjects.retain(|peer| peer != subject);
+        subjects.insert(0, subject.to_owned());
+        subjects.dedup();
+
+        Ok(subjects)
+    }
+
+    /// Returns the names of roles a subject holds directly or through its teams.
+    pub async fn role_names(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<std::collections::BTreeSet<String>> {
+        Ok(self
+            .repository
+            .list_role_grants_for_subject(tenant_id, subject)
+            .await?
+            .into_iter()
+            .map(|grant| grant.role_name)
+            .collect())
+    }
 }
@@ -1,4 +1,4 @@
-use qryvanta_domain::Surface;
+use qryvanta_domain::{Capability, Surface};
 
 use super::*;
 
@@ -30,4 +30,30 @@ impl AuthorizationService {
 
         Ok(surfaces)
     }
+
+    /// Returns the UI capabilities a subject holds in a tenant.
+    ///
+    /// A capability is held when the subject has every permission it
+    /// requires (logical AND).
+    pub async fn resolve_capabilities(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Capability>> {
+        let permissions = self
+            .repository
+            .list_permissions_for_subject(tenant_id, subject)
+            .await?;
+
+        Ok(Capability::all()
+            .iter()
+            .filter(|capability| {
+                capability
+                    .required_permissions()
+                    .iter()
+                    .all(|required| permissions.contains(required))
+            })
+            .copied()
+            .collect())
+    }
 }
@@ -2,14 +2,15 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use qryvanta_core::{AppResult, TenantId};
-use qryvanta_domain::{Permission, Surface};
+use qryvanta_core::{AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AppEntityAction, Capability, Permission, Surface};
 use tokio::sync::Mutex;
 
 use crate::{AuditEvent, AuditRepository};
 
 use super::{
-    AuthorizationRepository, AuthorizationService, RuntimeFieldGrant, TemporaryPermissionGrant,
+    AccessDecision, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    SubjectAppRolePermission, SubjectRoleGrant, TemporaryPermissionGrant,
 };
 
 #[derive(Default)]
@@ -29,6 +30,7 @@ struct FakeAuthorizationRepository {
     map: HashMap<(TenantId, String), Vec<Permission>>,
     runtime_field_grants: HashMap<(TenantId, String, String), Vec<RuntimeFieldGrant>>,
     temporary_permission_grants: HashMap<(TenantId, String, Permission), TemporaryPermissionGrant>,
+    app_role_permissions: Vec<SubjectAppRolePermission>,
 }
 
 #[async_trait]
@@ -73,6 +75,41 @@ impl AuthorizationRepository for FakeAuthorizationRepository {
             .get(&(tenant_id, subject.to_owned(), permission))
             .cloned())
     }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<SubjectRoleGrant>> {
+        Ok(self
+            .map
+            .get(&(tenant_id, subject.to_owned()))
+            .map(|permissions| {
+                vec![SubjectRoleGrant {
+                    role_name: format!("{subject}_role"),
+                    team_name: None,
+                    permissions: permissions.clone(),
+                }]
+            })
+            .unwrap_or_default())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<SubjectAppRolePermission>> {
+        Ok(self.app_role_permissions.clone())
+    }
 }
 
 #[tokio::test]
@@ -85,6 +122,7 @@ async fn require_permission_allows_granted_subject() {
         )]),
         runtime_field_grants: HashMap::new(),
         temporary_permission_grants: HashMap::new(),
+        app_role_permissions: Vec::new(),
     };
     let service = AuthorizationService::new(
         Arc::new(repository),
@@ -104,6 +142,7 @@ async fn require_permission_denies_missing_grant() {
         map: HashMap::new(),
         runtime_field_grants: HashMap::new(),
         temporary_permission_grants: HashMap::new(),
+        app_role_permissions: Vec::new(),
     };
     let service = AuthorizationService::new(
         Arc::new(repository),
@@ -129,6 +168,7 @@ async fn resolve_accessible_surfaces_returns_matching_surfaces() {
         )]),
         runtime_field_grants: HashMap::new(),
         temporary_permission_grants: HashMap::new(),
+        app_role_permissions: Vec::new(),
     };
     let service = AuthorizationService::new(
         Arc::new(repository),
@@ -156,6 +196,7 @@ async fn resolve_accessible_surfaces_includes_maker_for_workflow_only_grants() {
         )]),
         runtime_field_grants: HashMap::new(),
         temporary_permission_grants: HashMap::new(),
+        app_role_permissions: Vec::new(),
     };
     let service = AuthorizationService::new(
         Arc::new(repository),
@@ -177,6 +218,7 @@ async fn resolve_accessible_surfaces_empty_for_no_permissions() {
         map: HashMap::new(),
         runtime_field_grants: HashMap::new(),
         temporary_permission_grants: HashMap::new(),
+        app_role_permissions: Vec::new(),
     };
     let service = AuthorizationService::new(
         Arc::new(repository),
@@ -188,6 +230,35 @@ async fn resolve_accessible_surfaces_empty_for_no_permissions() {
     assert!(surfaces.unwrap_or_default().is_empty());
 }
 
+#[tokio::test]
+async fn resolve_capabilities_requires_every_capability_permission() {
+    let tenant_id = TenantId::new();
+    let repository = FakeAuthorizationRepository {
+        map: HashMap::from([(
+            (tenant_id, "alice".to_owned()),
+            vec![
+                Permission::MetadataEntityCreate,
+                Permission::WorkflowManage,
+                Permission::SecurityAuditRead,
+            ],
+        )]),
+        runtime_field_grants: HashMap::new(),
+        temporary_permission_grants: HashMap::new(),
+        app_role_permissions: Vec::new(),
+    };
+    let service = AuthorizationService::new(
+        Arc::new(repository),
+        Arc::new(FakeAuditRepository::default()),
+    );
+
+    let capabilities = service
+        .resolve_capabilities(tenant_id, "alice")
+        .await
+        .unwrap_or_default();
+
+    assert_eq!(capabilities, vec![Capability::ManageWorkflows]);
+}
+
 #[tokio::test]
 async fn require_permission_allows_active_temporary_grant() {
     let tenant_id = TenantId::new();
@@ -206,6 +277,7 @@ async fn require_permission_allows_active_temporary_grant() {
                 expires_at: "2099-01-01T00:00:00Z".to_owned(),
             },
         )]),
+        app_role_permissions: Vec::new(),
     };
     let audit_repository = Arc::new(FakeAuditRepository::default());
     let service = AuthorizationService::new(Arc::new(repository), audit_repository.clone());
@@ -218,3 +290,103 @@ async fn require_permission_allows_active_temporary_grant() {
     let events = audit_repository.events.lock().await;
     assert_eq!(events.len(), 1);
 }
+
+#[tokio::test]
+async fn explain_runtime_access_traces_roles_apps_and_field_grants() {
+    let tenant_id = TenantId::new();
+    let repository = FakeAuthorizationRepository {
+        map: HashMap::from([
+            (
+                (tenant_id, "admin".to_owned()),
+                vec![Permission::SecurityRoleManage],
+            ),
+            (
+                (tenant_id, "alice".to_owned()),
+                vec![Permission::RuntimeRecordReadOwn],
+            ),
+        ]),
+        runtime_field_grants: HashMap::from([(
+            (tenant_id, "alice".to_owned(), "contact".to_owned()),
+            vec![
+                RuntimeFieldGrant {
+                    field_logical_name: "name".to_owned(),
+                    can_read: true,
+                    can_write: true,
+                },
+                RuntimeFieldGrant {
+                    field_logical_name: "salary".to_owned(),
+                    can_read: true,
+                    can_write: false,
+                },
+            ],
+        )]),
+        temporary_permission_grants: HashMap::new(),
+        app_role_permissions: vec![
+            SubjectAppRolePermission {
+                app_logical_name: "sales".to_owned(),
+                role_name: "alice_role".to_owned(),
+                app_accessible: true,
+                can_read: true,
+                can_create: false,
+                can_update: true,
+                can_delete: false,
+            },
+            SubjectAppRolePermission {
+                app_logical_name: "service".to_owned(),
+                role_name: "alice_role".to_owned(),
+                app_accessible: false,
+                can_read: true,
+                can_create: true,
+                can_update: true,
+                can_delete: true,
+            },
+        ],
+    };
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let service = AuthorizationService::new(Arc::new(repository), audit_repository.clone());
+    let admin = UserIdentity::new("admin", "Admin", None, tenant_id);
+
+    let read = service
+        .explain_runtime_access(&admin, "alice", "contact", AppEntityAction::Read)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(read.decision, AccessDecision::Allowed);
+    assert!(!read.permission_checks[0].is_granted());
+    assert_eq!(read.permission_checks[1].granting_roles, vec!["alice_role"]);
+    assert_eq!(
+        read.trace.last().map(String::as_str),
+        Some("allowed through tenant permission 'runtime.record.read.own'")
+    );
+
+    let update = service
+        .explain_runtime_access(&admin, "alice", "contact", AppEntityAction::Update)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(update.decision, AccessDecision::Allowed);
+    assert!(
+        update
+            .trace
+            .contains(&"field grants limit writable fields to name".to_owned())
+    );
+    assert_eq!(
+        update.trace.last().map(String::as_str),
+        Some("allowed in apps sales")
+    );
+
+    let delete = service
+        .explain_runtime_access(&admin, "alice", "contact", AppEntityAction::Delete)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(delete.decision, AccessDecision::Denied);
+    assert!(delete.trace.contains(
+        &"app 'service' role 'alice_role' allows 'delete', but no role of the subject is bound to the app"
+            .to_owned()
+    ));
+    assert!(audit_repository.events.lock().await.is_empty());
+
+    let alice = UserIdentity::new("alice", "Alice", None, tenant_id);
+    let forbidden = service
+        .explain_runtime_access(&alice, "alice", "contact", AppEntityAction::Read)
+        .await;
+    assert!(forbidden.is_err());
+}
@@ -0,0 +1,18 @@
+//! Uniform status, cancellation and resumption for long-running jobs.
+//!
+//! Jobs run on behalf of the subject that queued them. A runner holds a
+//! renewable lease while it works and stores per-stage counters and a
+//! checkpoint with every renewal, so a job whose runner died is claimed again
+//! once the lease lapses and continues from its last checkpoint.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    BackgroundJob, BackgroundJobKind, BackgroundJobLease, BackgroundJobRepository,
+    BackgroundJobStage, BackgroundJobStatus, CreateBackgroundJobInput,
+};
+pub use service::{BACKGROUND_JOB_MAX_ATTEMPTS, BackgroundJobService};
@@ -0,0 +1,308 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+use qryvanta_domain::Permission;
+
+/// Kind of work a background job performs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BackgroundJobKind {
+    /// Deletes audit entries older than the retention window in batches.
+    AuditLogPurge,
+    /// Pushes every runtime record of the selected entities to Qrywell.
+    QrywellSync,
+    /// Packages the requester's profile, auth events and owned records.
+    PersonalDataExport,
+    /// Re-validates stored runtime records against the current schema and rules.
+    DataValidationAudit,
+    /// Packages the tenant's metadata, users, runtime records and audit log.
+    TenantDataExport,
+}
+
+impl BackgroundJobKind {
+    /// Returns a stable storage value for this kind.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::AuditLogPurge => "audit_log_purge",
+            Self::QrywellSync => "qrywell_sync",
+            Self::PersonalDataExport => "personal_data_export",
+            Self::DataValidationAudit => "data_validation_audit",
+            Self::TenantDataExport => "tenant_data_export",
+        }
+    }
+
+    /// Parses a stored kind value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "audit_log_purge" => Ok(Self::AuditLogPurge),
+            "qrywell_sync" => Ok(Self::QrywellSync),
+            "personal_data_export" => Ok(Self::PersonalDataExport),
+            "data_validation_audit" => Ok(Self::DataValidationAudit),
+            "tenant_data_export" => Ok(Self::TenantDataExport),
+            _ => Err(AppError::Validation(format!(
+                "unknown background job kind '{value}'"
+            ))),
+        }
+    }
+
+    /// Permission required to queue a job of this kind.
+    ///
+    /// `None` marks self-service kinds that only touch the requester's own data.
+    #[must_use]
+    pub fn required_permission(self) -> Option<Permission> {
+        match self {
+            Self::AuditLogPurge => Some(Permission::SecurityRoleManage),
+            Self::QrywellSync => Some(Permission::MetadataEntityRead),
+            Self::PersonalDataExport => None,
+            Self::DataValidationAudit => Some(Permission::MetadataFieldWrite),
+            Self::TenantDataExport => Some(Permission::SecurityRoleManage),
+        }
+    }
+}
+
+/// Lifecycle state of a background job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundJobStatus {
+    /// Waiting for a runner.
+    Queued,
+    /// Held by a runner, or interrupted and waiting for its lease to lapse.
+    Running,
+    /// Finished successfully.
+    Completed,
+    /// Stopped by an error.
+    Failed,
+    /// Stopped on request.
+    Cancelled,
+}
+
+impl BackgroundJobStatus {
+    /// Returns a stable storage value for this status.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    /// Parses a stored status value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "queued" => Ok(Self::Queued),
+            "running" => Ok(Self::Running),
+            "completed" => Ok(Self::Completed),
+            "failed" => Ok(Self::Failed),
+            "cancelled" => Ok(Self::Cancelled),
+            _ => Err(AppError::Validation(format!(
+                "unknown background job status '{value}'"
+            ))),
+        }
+    }
+
+    /// Returns whether the job can no longer change state.
+    #[must_use]
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// Progress counters of one job stage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackgroundJobStage {
+    /// Stage name, unique within the job.
+    pub name: String,
+    /// Items processed so far.
+    pub processed: u64,
+    /// Items the stage will process, when known up front.
+    pub total: Option<u64>,
+    /// Whether the stage has finished.
+    pub completed: bool,
+}
+
+impl BackgroundJobStage {
+    /// Creates a stage that has not processed anything yet.
+    #[must_use]
+    pub fn new(name: impl Into<String>, total: Option<u64>) -> Self {
+        Self {
+            name: name.into(),
+            processed: 0,
+            total,
+            completed: false,
+        }
+    }
+
+    fn progress_ratio(&self) -> f64 {
+        if self.completed {
+            return 1.0;
+        }
+
+        match self.total {
+            Some(0) | None => 0.0,
+            Some(total) => (self.processed as f64 / total as f64).min(1.0),
+        }
+    }
+}
+
+/// Long-running job and its latest progress.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundJob {
+    /// Stable job identifier.
+    pub job_id: String,
+    /// Work the job performs.
+    pub kind: BackgroundJobKind,
+    /// Lifecycle state.
+    pub status: BackgroundJobStatus,
+    /// Subject that queued the job; the job runs with its permissions.
+    pub requested_by_subject: String,
+    /// Display name of the requester, used when the job runs.
+    pub requested_by_display_name: String,
+    /// Email of the requester, used when the job runs.
+    pub requested_by_email: Option<String>,
+    /// Kind-specific input fixed when the job was queued.
+    pub parameters: Value,
+    /// Per-stage progress counters.
+    pub stages: Vec<BackgroundJobStage>,
+    /// Kind-specific position a resumed run continues from.
+    pub checkpoint: Option<Value>,
+    /// Whether cancellation was requested while the job was running.
+    pub cancel_requested: bool,
+    /// Number of runs that claimed the job.
+    pub attempt_count: u32,
+    /// Outcome detail for finished jobs.
+    pub status_message: Option<String>,
+    /// Queue timestamp.
+    pub created_at: DateTime<Utc>,
+    /// First claim timestamp.
+    pub started_at: Option<DateTime<Utc>>,
+    /// Last state or progress change.
+    pub updated_at: DateTime<Utc>,
+    /// Completion timestamp of finished jobs.
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl BackgroundJob {
+    /// Returns overall progress as a whole percentage.
+    ///
+    /// Stages weigh equally. A stage without a known total counts as zero
+    /// until it completes.
+    #[must_use]
+    pub fn progress_percent(&self) -> u8 {
+        if self.status == BackgroundJobStatus::Completed {
+            return 100;
+        }
+        if self.stages.is_empty() {
+            return 0;
+        }
+
+        let ratio = self
+            .stages
+            .iter()
+            .map(BackgroundJobStage::progress_ratio)
+            .sum::<f64>()
+            / self.stages.len() as f64;
+
+        (ratio * 100.0).floor().clamp(0.0, 100.0) as u8
+    }
+}
+
+/// Job claimed by a runner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundJobLease {
+    /// Tenant owning the job.
+    pub tenant_id: TenantId,
+    /// Claimed job, including the checkpoint to resume from.
+    pub job: BackgroundJob,
+    /// Token identifying the run that holds the lease.
+    pub lease_token: String,
+    /// Time after which the job may be claimed by another run.
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Input payload for queueing a background job.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateBackgroundJobInput {
+    /// Work the job performs.
+    pub kind: BackgroundJobKind,
+    /// Kind-specific input.
+    pub parameters: Value,
+    /// Stages the job reports progress for.
+    pub stages: Vec<BackgroundJobStage>,
+}
+
+/// Repository port for background jobs.
+#[async_trait]
+pub trait BackgroundJobRepository: Send + Sync {
+    /// Stores a queued job.
+    async fn create_background_job(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        requested_by_display_name: &str,
+        requested_by_email: Option<&str>,
+        input: CreateBackgroundJobInput,
+    ) -> AppResult<BackgroundJob>;
+
+    /// Finds one job.
+    async fn find_background_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Option<BackgroundJob>>;
+
+    /// Lists jobs newest first, optionally limited to one requester.
+    async fn list_background_jobs(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: Option<&str>,
+        limit: usize,
+    ) -> AppResult<Vec<BackgroundJob>>;
+
+    /// Requests cancellation of an unfinished job.
+    ///
+    /// Queued jobs are cancelled immediately; running jobs are flagged so
+    /// their runner stops at the next checkpoint. Returns `None` when no
+    /// unfinished job matches.
+    async fn request_background_job_cancellation(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Option<BackgroundJob>>;
+
+    /// Claims the oldest queued job, or a running job whose lease lapsed,
+    /// across all tenants.
+    async fn claim_background_job(
+        &self,
+        lease_token: &str,
+        lease_expires_at: DateTime<Utc>,
+    ) -> AppResult<Option<BackgroundJobLease>>;
+
+    /// Stores progress and renews the lease.
+    ///
+    /// Returns `None` when the lease is no longer held by `lease_token`.
+    async fn save_background_job_progress(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        lease_token: &str,
+        stages: &[BackgroundJobStage],
+        checkpoint: Option<&Value>,
+        lease_expires_at: DateTime<Utc>,
+    ) -> AppResult<Option<BackgroundJob>>;
+
+    /// Moves a claimed job to a terminal status and releases its lease.
+    ///
+    /// Returns `None` when the lease is no longer held by `lease_token`.
+    async fn finish_background_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        lease_token: &str,
+        status: BackgroundJobStatus,
+        status_message: Option<&str>,
+    ) -> AppResult<Option<BackgroundJob>>;
+}
@@ -0,0 +1,248 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::Value;
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::{
+    BackgroundJob, BackgroundJobLease, BackgroundJobRepository, BackgroundJobStage,
+    BackgroundJobStatus, CreateBackgroundJobInput,
+};
+
+/// Runs that claimed a job this many times without finishing it fail the job.
+pub const BACKGROUND_JOB_MAX_ATTEMPTS: u32 = 5;
+
+/// A runner that stops renewing its lease loses the job after this many seconds.
+const BACKGROUND_JOB_LEASE_SECONDS: i64 = 60;
+
+/// Upper bound for job list requests.
+const MAX_BACKGROUND_JOB_LIST_LIMIT: usize = 200;
+
+/// Application service for long-running job status, cancellation and resumption.
+#[derive(Clone)]
+pub struct BackgroundJobService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn BackgroundJobRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl BackgroundJobService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn BackgroundJobRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Queues a job that runs on behalf of the actor.
+    pub async fn enqueue_job(
+        &self,
+        actor: &UserIdentity,
+        input: CreateBackgroundJobInput,
+    ) -> AppResult<BackgroundJob> {
+        if let Some(permission) = input.kind.required_permission() {
+            self.authorization_service
+                .require_permission(actor.tenant_id(), actor.subject(), permission)
+                .await?;
+        }
+
+        let mut stage_names = BTreeSet::new();
+        for stage in &input.stages {
+            if stage.name.trim().is_empty() {
+                return Err(AppError::Validation(
+                    "background job stage name cannot be empty".to_owned(),
+                ));
+            }
+            if !stage_names.insert(stage.name.as_str()) {
+                return Err(AppError::Validation(format!(
+                    "background job stage '{}' is listed more than once",
+                    stage.name
+                )));
+            }
+        }
+
+        let job = self
+            .repository
+            .create_background_job(
+                actor.tenant_id(),
+                actor.subject(),
+                actor.display_name(),
+                actor.email(),
+                input,
+            )
+            .await?;
+
+        self.append_job_audit_event(actor, AuditAction::BackgroundJobQueued, &job)
+            .await?;
+
+        Ok(job)
+    }
+
+    /// Returns one job visible to the actor.
+    pub async fn get_job(&self, actor: &UserIdentity, job_id: &str) -> AppResult<BackgroundJob> {
+        let job = self
+            .repository
+            .find_background_job(actor.tenant_id(), job_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("background job '{job_id}' does not exist"))
+            })?;
+
+        if job.requested_by_subject != actor.subject() && !self.can_manage_all_jobs(actor).await? {
+            return Err(AppError::NotFound(format!(
+                "background job '{job_id}' does not exist"
+            )));
+        }
+
+        Ok(job)
+    }
+
+    /// Lists recent jobs, newest first.
+    ///
+    /// Role managers see every job of the tenant; other subjects see their own.
+    pub async fn list_jobs(
+        &self,
+        actor: &UserIdentity,
+        limit: usize,
+    ) -> AppResult<Vec<BackgroundJob>> {
+        if limit == 0 || limit > MAX_BACKGROUND_JOB_LIST_LIMIT {
+            return Err(AppError::Validation(format!(
+                "background job list limit must be between 1 and {MAX_BACKGROUND_JOB_LIST_LIMIT}"
+            )));
+        }
+
+        let requested_by_subject = if self.can_manage_all_jobs(actor).await? {
+            None
+        } else {
+            Some(actor.subject())
+        };
+
+        self.repository
+            .list_background_jobs(actor.tenant_id(), requested_by_subject, limit)
+            .await
+    }
+
+    /// Cancels a queued job or asks the runner of a running job to stop.
+    pub async fn cancel_job(&self, actor: &UserIdentity, job_id: &str) -> AppResult<BackgroundJob> {
+        let job = self.get_job(actor, job_id).await?;
+        if job.status.is_terminal() {
+            return Err(AppError::Conflict(format!(
+                "background job '{job_id}' already finished as '{}'",
+                job.status.as_str()
+            )));
+        }
+
+        let job = self
+            .repository
+            .request_background_job_cancellation(actor.tenant_id(), job_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::Conflict(format!("background job '{job_id}' already finished"))
+            })?;
+
+        self.append_job_audit_event(actor, AuditAction::BackgroundJobCancelled, &job)
+            .await?;
+
+        Ok(job)
+    }
+
+    /// Claims the next job to run, resuming jobs whose runner stopped renewing its lease.
+    pub async fn claim_next_job(&self) -> AppResult<Option<BackgroundJobLease>> {
+        let lease_token = uuid::Uuid::new_v4().to_string();
+        self.repository
+            .claim_background_job(lease_token.as_str(), lease_expiry())
+            .await
+    }
+
+    /// Stores progress of a claimed job and renews its lease.
+    ///
+    /// Returns `None` when the lease was lost; the caller must stop working
+    /// on the job. A returned job with `cancel_requested` set should be
+    /// finished as cancelled.
+    pub async fn record_job_progress(
+        &self,
+        lease: &BackgroundJobLease,
+        stages: &[BackgroundJobStage],
+        checkpoint: Option<&Value>,
+    ) -> AppResult<Option<BackgroundJob>> {
+        self.repository
+            .save_background_job_progress(
+                lease.tenant_id,
+                lease.job.job_id.as_str(),
+                lease.lease_token.as_str(),
+                stages,
+                checkpoint,
+                lease_expiry(),
+            )
+            .await
+    }
+
+    /// Moves a claimed job to a terminal status.
+    pub async fn finish_job(
+        &self,
+        lease: &BackgroundJobLease,
+        status: BackgroundJobStatus,
+        status_message: Option<&str>,
+    ) -> AppResult<Option<BackgroundJob>> {
+        if !status.is_terminal() {
+            return Err(AppError::Validation(format!(
+                "background job cannot finish as '{}'",
+                status.as_str()
+            )));
+        }
+
+        self.repository
+            .finish_background_job(
+                lease.tenant_id,
+                lease.job.job_id.as_str(),
+                lease.lease_token.as_str(),
+                status,
+                status_message,
+            )
+            .await
+    }
+
+    async fn can_manage_all_jobs(&self, actor: &UserIdentity) -> AppResult<bool> {
+        self.authorization_service
+            .has_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::SecurityRoleManage,
+            )
+            .await
+    }
+
+    async fn append_job_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        job: &BackgroundJob,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "background_job".to_owned(),
+                resource_id: job.job_id.clone(),
+                detail: Some(format!("{} job", job.kind.as_str())),
+            })
+            .await
+    }
+}
+
+fn lease_expiry() -> chrono::DateTime<Utc> {
+    Utc::now() + chrono::Duration::seconds(BACKGROUND_JOB_LEASE_SECONDS)
+}
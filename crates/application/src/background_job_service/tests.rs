@@ -0,0 +1,535 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    TemporaryPermissionGrant,
+};
+
+use super::{
+    BackgroundJob, BackgroundJobKind, BackgroundJobLease, BackgroundJobRepository,
+    BackgroundJobService, BackgroundJobStage, BackgroundJobStatus, CreateBackgroundJobInput,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+struct StoredJob {
+    tenant_id: TenantId,
+    job: BackgroundJob,
+    lease: Option<(String, DateTime<Utc>)>,
+}
+
+#[derive(Default)]
+struct FakeBackgroundJobRepository {
+    jobs: Mutex<Vec<StoredJob>>,
+}
+
+impl FakeBackgroundJobRepository {
+    async fn expire_leases(&self) {
+        for stored in self.jobs.lock().await.iter_mut() {
+            if let Some((_, expires_at)) = stored.lease.as_mut() {
+                *expires_at = Utc::now() - chrono::Duration::seconds(1);
+            }
+        }
+    }
+}
+
+fn holds_lease(stored: &StoredJob, lease_token: &str) -> bool {
+    stored
+        .lease
+        .as_ref()
+        .is_some_and(|(token, _)| token == lease_token)
+}
+
+#[async_trait]
+impl BackgroundJobRepository for FakeBackgroundJobRepository {
+    async fn create_background_job(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        requested_by_display_name: &str,
+        requested_by_email: Option<&str>,
+        input: CreateBackgroundJobInput,
+    ) -> AppResult<BackgroundJob> {
+        let now = Utc::now();
+        let job = BackgroundJob {
+            job_id: uuid::Uuid::new_v4().to_string(),
+            kind: input.kind,
+            status: BackgroundJobStatus::Queued,
+            requested_by_subject: requested_by_subject.to_owned(),
+            requested_by_display_name: requested_by_display_name.to_owned(),
+            requested_by_email: requested_by_email.map(str::to_owned),
+            parameters: input.parameters,
+            stages: input.stages,
+            checkpoint: None,
+            cancel_requested: false,
+            attempt_count: 0,
+            status_message: None,
+            created_at: now,
+            started_at: None,
+            updated_at: now,
+            finished_at: None,
+        };
+        self.jobs.lock().await.push(StoredJob {
+            tenant_id,
+            job: job.clone(),
+            lease: None,
+        });
+        Ok(job)
+    }
+
+    async fn find_background_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Option<BackgroundJob>> {
+        Ok(self
+            .jobs
+            .lock()
+            .await
+            .iter()
+            .find(|stored| stored.tenant_id == tenant_id && stored.job.job_id == job_id)
+            .map(|stored| stored.job.clone()))
+    }
+
+    async fn list_background_jobs(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: Option<&str>,
+        limit: usize,
+    ) -> AppResult<Vec<BackgroundJob>> {
+        Ok(self
+            .jobs
+            .lock()
+            .await
+            .iter()
+            .rev()
+            .filter(|stored| stored.tenant_id == tenant_id)
+            .filter(|stored| {
+                requested_by_subject
+                    .is_none_or(|subject| stored.job.requested_by_subject == subject)
+            })
+            .take(limit)
+            .map(|stored| stored.job.clone())
+            .collect())
+    }
+
+    async fn request_background_job_cancellation(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Option<BackgroundJob>> {
+        let mut jobs = self.jobs.lock().await;
+        let Some(stored) = jobs.iter_mut().find(|stored| {
+            stored.tenant_id == tenant_id
+                && stored.job.job_id == job_id
+                && !stored.job.status.is_terminal()
+        }) else {
+            return Ok(None);
+        };
+
+        stored.job.cancel_requested = true;
+        if stored.job.status == BackgroundJobStatus::Queued {
+            stored.job.status = BackgroundJobStatus::Cancelled;
+            stored.job.finished_at = Some(Utc::now());
+        }
+        Ok(Some(stored.job.clone()))
+    }
+
+    async fn claim_background_job(
+        &self,
+        lease_token: &str,
+        lease_expires_at: DateTime<Utc>,
+    ) -> AppResult<Option<BackgroundJobLease>> {
+        let now = Utc::now();
+        let mut jobs = self.jobs.lock().await;
+        let Some(stored) = jobs.iter_mut().find(|stored| match stored.job.status {
+            BackgroundJobStatus::Queued => true,
+            BackgroundJobStatus::Running => stored
+                .lease
+                .as_ref()
+                .is_none_or(|(_, expires_at)| *expires_at <= now),
+            _ => false,
+        }) else {
+            return Ok(None);
+        };
+
+        stored.job.status = BackgroundJobStatus::Running;
+        stored.job.attempt_count += 1;
+        stored.job.started_at.get_or_insert(now);
+        stored.lease = Some((lease_token.to_owned(), lease_expires_at));
+        Ok(Some(BackgroundJobLease {
+            tenant_id: stored.tenant_id,
+            job: stored.job.clone(),
+            lease_token: lease_token.to_owned(),
+            expires_at: lease_expires_at,
+        }))
+    }
+
+    async fn save_background_job_progress(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        lease_token: &str,
+        stages: &[BackgroundJobStage],
+        checkpoint: Option<&Value>,
+        lease_expires_at: DateTime<Utc>,
+    ) -> AppResult<Option<BackgroundJob>> {
+        let mut jobs = self.jobs.lock().await;
+        let Some(stored) = jobs.iter_mut().find(|stored| {
+            stored.tenant_id == tenant_id
+                && stored.job.job_id == job_id
+                && stored.job.status == BackgroundJobStatus::Running
+                && holds_lease(stored, lease_token)
+        }) else {
+            return Ok(None);
+        };
+
+        stored.job.stages = stages.to_vec();
+        stored.job.checkpoint = checkpoint.cloned();
+        stored.lease = Some((lease_token.to_owned(), lease_expires_at));
+        Ok(Some(stored.job.clone()))
+    }
+
+    async fn finish_background_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        lease_token: &str,
+        status: BackgroundJobStatus,
+        status_message: Option<&str>,
+    ) -> AppResult<Option<BackgroundJob>> {
+        let mut jobs = self.jobs.lock().await;
+        let Some(stored) = jobs.iter_mut().find(|stored| {
+            stored.tenant_id == tenant_id
+                && stored.job.job_id == job_id
+                && stored.job.status == BackgroundJobStatus::Running
+                && holds_lease(stored, lease_token)
+        }) else {
+            return Ok(None);
+        };
+
+        stored.job.status = status;
+        stored.job.status_message = status_message.map(str::to_owned);
+        stored.job.finished_at = Some(Utc::now());
+        stored.lease = None;
+        Ok(Some(stored.job.clone()))
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    repository: Arc<FakeBackgroundJobRepository>,
+) -> (BackgroundJobService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let grants = HashMap::from([
+        (
+            (tenant_id, "alice".to_owned()),
+            vec![Permission::MetadataEntityRead],
+        ),
+        (
+            (tenant_id, "bob".to_owned()),
+            vec![Permission::MetadataEntityRead],
+        ),
+        (
+            (tenant_id, "admin".to_owned()),
+            vec![
+                Permission::MetadataEntityRead,
+                Permission::SecurityRoleManage,
+            ],
+        ),
+    ]);
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository { grants }),
+        audit_repository.clone(),
+    );
+    let service =
+        BackgroundJobService::new(authorization_service, repository, audit_repository.clone());
+    (service, audit_repository)
+}
+
+fn sync_job_input() -> CreateBackgroundJobInput {
+    CreateBackgroundJobInput {
+        kind: BackgroundJobKind::QrywellSync,
+        parameters: json!({ "entity_logical_names": ["contact", "account"] }),
+        stages: vec![
+            BackgroundJobStage::new("contact", Some(10)),
+            BackgroundJobStage::new("account", None),
+        ],
+    }
+}
+
+#[tokio::test]
+async fn jobs_are_visible_to_their_requester_and_role_managers() {
+    let tenant_id = TenantId::new();
+    let (service, audit_repository) =
+        build_service(tenant_id, Arc::new(FakeBackgroundJobRepository::default()));
+    let alice = actor(tenant_id, "alice");
+
+    let job = service
+        .enqueue_job(&alice, sync_job_input())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(job.status, BackgroundJobStatus::Queued);
+    assert_eq!(job.progress_percent(), 0);
+
+    let purge = service
+        .enqueue_job(
+            &alice,
+            CreateBackgroundJobInput {
+                kind: BackgroundJobKind::AuditLogPurge,
+                parameters: json!({}),
+                stages: Vec::new(),
+            },
+        )
+        .await;
+    assert!(matches!(purge, Err(AppError::Forbidden(_))));
+
+    let hidden = service
+        .get_job(&actor(tenant_id, "bob"), job.job_id.as_str())
+        .await;
+    assert!(matches!(hidden, Err(AppError::NotFound(_))));
+    assert!(
+        service
+            .list_jobs(&actor(tenant_id, "bob"), 50)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+
+    let admin = actor(tenant_id, "admin");
+    assert_eq!(
+        service
+            .get_job(&admin, job.job_id.as_str())
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .job_id,
+        job.job_id
+    );
+    assert_eq!(
+        service
+            .list_jobs(&admin, 50)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .len(),
+        1
+    );
+
+    let actions: Vec<AuditAction> = audit_repository
+        .events
+        .lock()
+        .await
+        .iter()
+        .map(|event| event.action)
+        .collect();
+    assert_eq!(actions, vec![AuditAction::BackgroundJobQueued]);
+}
+
+#[tokio::test]
+async fn interrupted_jobs_resume_from_their_checkpoint() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeBackgroundJobRepository::default());
+    let (service, _) = build_service(tenant_id, repository.clone());
+    let alice = actor(tenant_id, "alice");
+    let job = service
+        .enqueue_job(&alice, sync_job_input())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let first_run = service
+        .claim_next_job()
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(first_run.job.job_id, job.job_id);
+    assert_eq!(first_run.job.attempt_count, 1);
+    assert!(
+        service
+            .claim_next_job()
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_none()
+    );
+
+    let mut stages = first_run.job.stages.clone();
+    stages[0].processed = 5;
+    let checkpoint = json!({ "entity_index": 0, "offset": 5 });
+    let progressed = service
+        .record_job_progress(&first_run, &stages, Some(&checkpoint))
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(progressed.progress_percent(), 25);
+
+    repository.expire_leases().await;
+    let resumed = service
+        .claim_next_job()
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(resumed.job.attempt_count, 2);
+    assert_eq!(resumed.job.checkpoint, Some(checkpoint));
+    assert_eq!(resumed.job.stages[0].processed, 5);
+
+    let stale = service
+        .record_job_progress(&first_run, &stages, None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(stale.is_none());
+
+    let finished = service
+        .finish_job(&resumed, BackgroundJobStatus::Completed, None)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(finished.progress_percent(), 100);
+    assert!(finished.finished_at.is_some());
+}
+
+#[tokio::test]
+async fn cancellation_stops_queued_jobs_and_flags_running_ones() {
+    let tenant_id = TenantId::new();
+    let (service, audit_repository) =
+        build_service(tenant_id, Arc::new(FakeBackgroundJobRepository::default()));
+    let alice = actor(tenant_id, "alice");
+
+    let running = service
+        .enqueue_job(&alice, sync_job_input())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let lease = service
+        .claim_next_job()
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    let queued = service
+        .enqueue_job(&alice, sync_job_input())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let cancelled = service
+        .cancel_job(&alice, queued.job_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(cancelled.status, BackgroundJobStatus::Cancelled);
+
+    let forbidden = service
+        .cancel_job(&actor(tenant_id, "bob"), running.job_id.as_str())
+        .await;
+    assert!(matches!(forbidden, Err(AppError::NotFound(_))));
+
+    let flagged = service
+        .cancel_job(&alice, running.job_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(flagged.status, BackgroundJobStatus::Running);
+    assert!(flagged.cancel_requested);
+
+    let acknowledged = service
+        .record_job_progress(&lease, &lease.job.stages, None)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert!(acknowledged.cancel_requested);
+    service
+        .finish_job(&lease, BackgroundJobStatus::Cancelled, None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let again = service.cancel_job(&alice, running.job_id.as_str()).await;
+    assert!(matches!(again, Err(AppError::Conflict(_))));
+
+    let cancellations = audit_repository
+        .events
+        .lock()
+        .await
+        .iter()
+        .filter(|event| event.action == AuditAction::BackgroundJobCancelled)
+        .count();
+    assert_eq!(cancellations, 2);
+}
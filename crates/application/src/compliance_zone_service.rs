@@ -0,0 +1,18 @@
+//! Compliance zones that restrict runtime records to assigned subjects.
+//!
+//! A zone is a short key such as `eu` or `us_gov`. Entities or single
+//! records can be restricted to one zone, and subjects are assigned to the
+//! zones they may work in. Metadata runtime reads and writes reject
+//! restricted records for subjects outside the zone.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    ComplianceZoneAssignment, ComplianceZoneRepository, ComplianceZoneTag,
+    SaveComplianceZoneTagInput, normalize_compliance_zone,
+};
+pub use service::ComplianceZoneService;
@@ -0,0 +1,127 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+const MAX_COMPLIANCE_ZONE_LENGTH: usize = 32;
+
+/// Normalizes a compliance zone key.
+///
+/// Keys are trimmed and lowercased and may contain ASCII letters, digits,
+/// `_`, and `-`.
+pub fn normalize_compliance_zone(value: &str) -> AppResult<String> {
+    let zone = value.trim().to_ascii_lowercase();
+    if zone.is_empty() || zone.len() > MAX_COMPLIANCE_ZONE_LENGTH {
+        return Err(AppError::Validation(format!(
+            "compliance zone must be between 1 and {MAX_COMPLIANCE_ZONE_LENGTH} characters"
+        )));
+    }
+    if !zone
+        .chars()
+        .all(|character| character.is_ascii_alphanumeric() || matches!(character, '_' | '-'))
+    {
+        return Err(AppError::Validation(format!(
+            "compliance zone '{zone}' may only contain letters, digits, '_' and '-'"
+        )));
+    }
+
+    Ok(zone)
+}
+
+/// Zone a subject may access restricted records in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplianceZoneAssignment {
+    /// Assigned subject.
+    pub subject: String,
+    /// Normalized zone key.
+    pub zone: String,
+    /// Subject that made the assignment.
+    pub assigned_by_subject: String,
+    /// Assignment timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Restriction of an entity, or one of its records, to a compliance zone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplianceZoneTag {
+    /// Restricted entity.
+    pub entity_logical_name: String,
+    /// Restricted record, or `None` when every record of the entity is restricted.
+    pub record_id: Option<String>,
+    /// Normalized zone key.
+    pub zone: String,
+    /// Subject that saved the restriction.
+    pub tagged_by_subject: String,
+    /// Last change timestamp.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input payload for restricting an entity or record to a zone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveComplianceZoneTagInput {
+    /// Restricted entity.
+    pub entity_logical_name: String,
+    /// Restricted record, or `None` for the whole entity.
+    pub record_id: Option<String>,
+    /// Zone key.
+    pub zone: String,
+}
+
+/// Repository port for compliance zone assignments and restrictions.
+#[async_trait]
+pub trait ComplianceZoneRepository: Send + Sync {
+    /// Lists zone assignments, optionally for one subject.
+    async fn list_zone_assignments(
+        &self,
+        tenant_id: TenantId,
+        subject: Option<&str>,
+    ) -> AppResult<Vec<ComplianceZoneAssignment>>;
+
+    /// Assigns a subject to a zone; assigning twice keeps the first assignment.
+    async fn assign_zone(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        zone: &str,
+        assigned_by_subject: &str,
+    ) -> AppResult<ComplianceZoneAssignment>;
+
+    /// Removes a zone assignment, returning whether one existed.
+    async fn unassign_zone(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        zone: &str,
+    ) -> AppResult<bool>;
+
+    /// Lists restrictions, optionally for one entity.
+    async fn list_zone_tags(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<ComplianceZoneTag>>;
+
+    /// Creates or replaces the restriction of an entity or record.
+    async fn save_zone_tag(
+        &self,
+        tenant_id: TenantId,
+        input: SaveComplianceZoneTagInput,
+        tagged_by_subject: &str,
+    ) -> AppResult<ComplianceZoneTag>;
+
+    /// Removes the restriction of an entity or record, returning whether one existed.
+    async fn remove_zone_tag(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: Option<&str>,
+    ) -> AppResult<bool>;
+
+    /// Finds the entity-wide restriction plus the restrictions of the listed records.
+    async fn find_zone_tags(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_ids: &[String],
+    ) -> AppResult<Vec<ComplianceZoneTag>>;
+}
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::{
+    ComplianceZoneAssignment, ComplianceZoneRepository, ComplianceZoneTag,
+    SaveComplianceZoneTagInput, normalize_compliance_zone,
+};
+
+/// Application service for compliance zone assignments and restrictions.
+#[derive(Clone)]
+pub struct ComplianceZoneService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn ComplianceZoneRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl ComplianceZoneService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn ComplianceZoneRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Lists zone assignments for the actor tenant.
+    pub async fn list_assignments(
+        &self,
+        actor: &UserIdentity,
+    ) -> AppResult<Vec<ComplianceZoneAssignment>> {
+        self.require_compliance_zone_manage_permission(actor)
+            .await?;
+        self.repository
+            .list_zone_assignments(actor.tenant_id(), None)
+            .await
+    }
+
+    /// Assigns a subject to a compliance zone.
+    pub async fn assign_zone(
+        &self,
+        actor: &UserIdentity,
+        subject: &str,
+        zone: &str,
+    ) -> AppResult<ComplianceZoneAssignment> {
+        self.require_compliance_zone_manage_permission(actor)
+            .await?;
+
+        let subject = normalize_required("subject", subject)?;
+        let zone = normalize_compliance_zone(zone)?;
+        let assignment = self
+            .repository
+            .assign_zone(
+                actor.tenant_id(),
+                subject.as_str(),
+                zone.as_str(),
+                actor.subject(),
+            )
+            .await?;
+
+        self.append_zone_audit_event(
+            actor,
+            AuditAction::SecurityComplianceZoneAssigned,
+            "compliance_zone_assignment",
+            format!("{}:{}", assignment.subject, assignment.zone),
+            serde_json::json!({ "subject": assignment.subject, "zone": assignment.zone }),
+        )
+        .await?;
+
+        Ok(assignment)
+    }
+
+    /// Removes a subject from a compliance zone.
+    pub async fn unassign_zone(
+        &self,
+        actor: &UserIdentity,
+        subject: &str,
+        zone: &str,
+    ) -> AppResult<()> {
+        self.require_compliance_zone_manage_permission(actor)
+            .await?;
+
+        let subject = normalize_required("subject", subject)?;
+        let zone = normalize_compliance_zone(zone)?;
+        if !self
+            .repository
+            .unassign_zone(actor.tenant_id(), subject.as_str(), zone.as_str())
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "subject '{subject}' is not assigned to compliance zone '{zone}'"
+            )));
+        }
+
+        self.append_zone_audit_event(
+            actor,
+            AuditAction::SecurityComplianceZoneUnassigned,
+            "compliance_zone_assignment",
+            format!("{subject}:{zone}"),
+            serde_json::json!({ "subject": subject, "zone": zone }),
+        )
+        .await
+    }
+
+    /// Lists zone restrictions, optionally for one entity.
+    pub async fn list_tags(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<ComplianceZoneTag>> {
+        self.require_compliance_zone_manage_permission(actor)
+            .await?;
+        self.repository
+            .list_zone_tags(actor.tenant_id(), entity_logical_name)
+            .await
+    }
+
+    /// Restricts an entity, or one of its records, to a compliance zone.
+    pub async fn save_tag(
+        &self,
+        actor: &UserIdentity,
+        input: SaveComplianceZoneTagInput,
+    ) -> AppResult<ComplianceZoneTag> {
+        self.require_compliance_zone_manage_permission(actor)
+            .await?;
+
+        let input = SaveComplianceZoneTagInput {
+            entity_logical_name: normalize_required(
+                "entity_logical_name",
+                input.entity_logical_name.as_str(),
+            )?,
+            record_id: input
+                .record_id
+                .as_deref()
+                .map(|record_id| normalize_required("record_id", record_id))
+                .transpose()?,
+            zone: normalize_compliance_zone(input.zone.as_str())?,
+        };
+        let tag = self
+            .repository
+            .save_zone_tag(actor.tenant_id(), input, actor.subject())
+            .await?;
+
+        self.append_zone_audit_event(
+            actor,
+            AuditAction::SecurityComplianceZoneTagged,
+            tag_resource_type(tag.record_id.as_deref()),
+            tag_resource_id(tag.entity_logical_name.as_str(), tag.record_id.as_deref()),
+            serde_json::json!({
+                "entity_logical_name": tag.entity_logical_name,
+                "record_id": tag.record_id,
+                "zone": tag.zone,
+            }),
+        )
+        .await?;
+
+        Ok(tag)
+    }
+
+    /// Removes the zone restriction of an entity or record.
+    pub async fn remove_tag(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: Option<&str>,
+    ) -> AppResult<()> {
+        self.require_compliance_zone_manage_permission(actor)
+            .await?;
+
+        if !self
+            .repository
+            .remove_zone_tag(actor.tenant_id(), entity_logical_name, record_id)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "{} is not restricted to a compliance zone",
+                tag_target_label(entity_logical_name, record_id)
+            )));
+        }
+
+        self.append_zone_audit_event(
+            actor,
+            AuditAction::SecurityComplianceZoneUntagged,
+            tag_resource_type(record_id),
+            tag_resource_id(entity_logical_name, record_id),
+            serde_json::json!({
+                "entity_logical_name": entity_logical_name,
+                "record_id": record_id,
+            }),
+        )
+        .await
+    }
+
+    async fn require_compliance_zone_manage_permission(
+        &self,
+        actor: &UserIdentity,
+    ) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::SecurityComplianceZoneManage,
+            )
+            .await
+    }
+
+    async fn append_zone_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        resource_type: &str,
+        resource_id: String,
+        detail: serde_json::Value,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: resource_type.to_owned(),
+                resource_id,
+                detail: Some(detail.to_string()),
+            })
+            .await
+    }
+}
+
+fn normalize_required(field_name: &str, value: &str) -> AppResult<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(AppError::Validation(format!(
+            "compliance zone {field_name} is required"
+        )));
+    }
+
+    Ok(value.to_owned())
+}
+
+fn tag_resource_type(record_id: Option<&str>) -> &'static str {
+    if record_id.is_some() {
+        "runtime_record"
+    } else {
+        "entity"
+    }
+}
+
+fn tag_resource_id(entity_logical_name: &str, record_id: Option<&str>) -> String {
+    record_id.map_or_else(|| entity_logical_name.to_owned(), str::to_owned)
+}
+
+fn tag_target_label(entity_logical_name: &str, record_id: Option<&str>) -> String {
+    match record_id {
+        Some(record_id) => {
+            format!("runtime record '{record_id}' of entity '{entity_logical_name}'")
+        }
+        None => format!("entity '{entity_logical_name}'"),
+    }
+}
@@ -0,0 +1,355 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    TemporaryPermissionGrant,
+};
+
+use super::{
+    ComplianceZoneAssignment, ComplianceZoneRepository, ComplianceZoneService, ComplianceZoneTag,
+    SaveComplianceZoneTagInput, normalize_compliance_zone,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeComplianceZoneRepository {
+    assignments: Mutex<Vec<ComplianceZoneAssignment>>,
+    tags: Mutex<Vec<ComplianceZoneTag>>,
+}
+
+#[async_trait]
+impl ComplianceZoneRepository for FakeComplianceZoneRepository {
+    async fn list_zone_assignments(
+        &self,
+        _tenant_id: TenantId,
+        subject: Option<&str>,
+    ) -> AppResult<Vec<ComplianceZoneAssignment>> {
+        Ok(self
+            .assignments
+            .lock()
+            .await
+            .iter()
+            .filter(|assignment| subject.is_none_or(|subject| assignment.subject == subject))
+            .cloned()
+            .collect())
+    }
+
+    async fn assign_zone(
+        &self,
+        _tenant_id: TenantId,
+        subject: &str,
+        zone: &str,
+        assigned_by_subject: &str,
+    ) -> AppResult<ComplianceZoneAssignment> {
+        let mut assignments = self.assignments.lock().await;
+        if let Some(existing) = assignments
+            .iter()
+            .find(|assignment| assignment.subject == subject && assignment.zone == zone)
+        {
+            return Ok(existing.clone());
+        }
+
+        let assignment = ComplianceZoneAssignment {
+            subject: subject.to_owned(),
+            zone: zone.to_owned(),
+            assigned_by_subject: assigned_by_subject.to_owned(),
+            created_at: Utc::now(),
+        };
+        assignments.push(assignment.clone());
+        Ok(assignment)
+    }
+
+    async fn unassign_zone(
+        &self,
+        _tenant_id: TenantId,
+        subject: &str,
+        zone: &str,
+    ) -> AppResult<bool> {
+        let mut assignments = self.assignments.lock().await;
+        let count = assignments.len();
+        assignments
+            .retain(|assignment| !(assignment.subject == subject && assignment.zone == zone));
+        Ok(assignments.len() != count)
+    }
+
+    async fn list_zone_tags(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<ComplianceZoneTag>> {
+        Ok(self
+            .tags
+            .lock()
+            .await
+            .iter()
+            .filter(|tag| {
+                entity_logical_name.is_none_or(|entity| tag.entity_logical_name == entity)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn save_zone_tag(
+        &self,
+        _tenant_id: TenantId,
+        input: SaveComplianceZoneTagInput,
+        tagged_by_subject: &str,
+    ) -> AppResult<ComplianceZoneTag> {
+        let mut tags = self.tags.lock().await;
+        tags.retain(|tag| {
+            !(tag.entity_logical_name == input.entity_logical_name
+                && tag.record_id == input.record_id)
+        });
+        let tag = ComplianceZoneTag {
+            entity_logical_name: input.entity_logical_name,
+            record_id: input.record_id,
+            zone: input.zone,
+            tagged_by_subject: tagged_by_subject.to_owned(),
+            updated_at: Utc::now(),
+        };
+        tags.push(tag.clone());
+        Ok(tag)
+    }
+
+    async fn remove_zone_tag(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: Option<&str>,
+    ) -> AppResult<bool> {
+        let mut tags = self.tags.lock().await;
+        let count = tags.len();
+        tags.retain(|tag| {
+            !(tag.entity_logical_name == entity_logical_name
+                && tag.record_id.as_deref() == record_id)
+        });
+        Ok(tags.len() != count)
+    }
+
+    async fn find_zone_tags(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_ids: &[String],
+    ) -> AppResult<Vec<ComplianceZoneTag>> {
+        Ok(self
+            .tags
+            .lock()
+            .await
+            .iter()
+            .filter(|tag| {
+                tag.entity_logical_name == entity_logical_name
+                    && tag
+                        .record_id
+                        .as_ref()
+                        .is_none_or(|record_id| record_ids.contains(record_id))
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    permissions: Vec<Permission>,
+    repository: Arc<FakeComplianceZoneRepository>,
+) -> (ComplianceZoneService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([((tenant_id, "alice".to_owned()), permissions)]),
+        }),
+        audit_repository.clone(),
+    );
+    let service =
+        ComplianceZoneService::new(authorization_service, repository, audit_repository.clone());
+    (service, audit_repository)
+}
+
+#[test]
+fn compliance_zones_are_normalized_and_validated() {
+    assert_eq!(
+        normalize_compliance_zone(" EU-West_1 ").unwrap_or_else(|_| unreachable!()),
+        "eu-west_1"
+    );
+    assert!(matches!(
+        normalize_compliance_zone("  "),
+        Err(AppError::Validation(_))
+    ));
+    assert!(matches!(
+        normalize_compliance_zone("eu west"),
+        Err(AppError::Validation(_))
+    ));
+    assert!(matches!(
+        normalize_compliance_zone(&"a".repeat(33)),
+        Err(AppError::Validation(_))
+    ));
+}
+
+#[tokio::test]
+async fn managing_zones_requires_compliance_zone_permission() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeComplianceZoneRepository::default());
+    let (service, _) = build_service(
+        tenant_id,
+        vec![Permission::SecurityRoleManage],
+        repository.clone(),
+    );
+
+    let result = service
+        .assign_zone(&actor(tenant_id, "alice"), "bob", "eu")
+        .await;
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+    assert!(repository.assignments.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn zone_assignments_and_tags_are_normalized_and_audited() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeComplianceZoneRepository::default());
+    let (service, audit_repository) = build_service(
+        tenant_id,
+        vec![Permission::SecurityComplianceZoneManage],
+        repository.clone(),
+    );
+    let actor = actor(tenant_id, "alice");
+
+    let assignment = service
+        .assign_zone(&actor, " bob ", " EU ")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        (assignment.subject.as_str(), assignment.zone.as_str()),
+        ("bob", "eu")
+    );
+
+    let tag = service
+        .save_tag(
+            &actor,
+            SaveComplianceZoneTagInput {
+                entity_logical_name: "contact".to_owned(),
+                record_id: Some(" record-1 ".to_owned()),
+                zone: "EU".to_owned(),
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(tag.record_id.as_deref(), Some("record-1"));
+    assert_eq!(tag.zone, "eu");
+
+    service
+        .remove_tag(&actor, "contact", Some("record-1"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let removed_again = service
+        .remove_tag(&actor, "contact", Some("record-1"))
+        .await;
+    assert!(matches!(removed_again, Err(AppError::NotFound(_))));
+
+    service
+        .unassign_zone(&actor, "bob", "eu")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let unassigned_again = service.unassign_zone(&actor, "bob", "eu").await;
+    assert!(matches!(unassigned_again, Err(AppError::NotFound(_))));
+
+    let events = audit_repository.events.lock().await;
+    let actions: Vec<AuditAction> = events.iter().map(|event| event.action).collect();
+    assert_eq!(
+        actions,
+        vec![
+            AuditAction::SecurityComplianceZoneAssigned,
+            AuditAction::SecurityComplianceZoneTagged,
+            AuditAction::SecurityComplianceZoneUntagged,
+            AuditAction::SecurityComplianceZoneUnassigned,
+        ]
+    );
+    assert_eq!(events[1].resource_type, "runtime_record");
+    assert_eq!(events[1].resource_id, "record-1");
+}
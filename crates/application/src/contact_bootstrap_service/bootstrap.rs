@@ -52,6 +52,7 @@ impl ContactBootstrapService {
                 Vec::new(),
                 subject,
                 None,
+                None,
             )
             .await?;
 
@@ -8,14 +8,18 @@ use uuid::Uuid;
 
 use qryvanta_core::{AppError, AppResult, TenantId};
 use qryvanta_domain::{
-    BusinessRuleDefinition, EntityDefinition, EntityFieldDefinition, FormDefinition,
-    OptionSetDefinition, PublishedEntitySchema, RuntimeRecord, ViewDefinition,
+    BusinessRuleDefinition, DuplicateDetectionRule, EntityDefinition, EntityFieldDefinition,
+    FormDefinition, OptionSetDefinition, PublishedEntitySchema, RuntimeRecord, ViewDefinition,
 };
 
 use crate::{
-    ClaimedRuntimeRecordWorkflowEvent, ContactBootstrapService, MetadataRepository,
-    RecordListQuery, RuntimeRecordQuery, RuntimeRecordWorkflowEventInput, TenantRepository,
-    UniqueFieldValue,
+    AuditEvent, ClaimedRuntimeRecordWorkflowEvent, ContactBootstrapService, EntityHistoryPolicy,
+    EntitySlugConfig, EntityStatusConfig, MetadataRepository, NewRuntimeRecordHistoryEntry,
+    NewRuntimeRecordStatusChange, PublishedEntitySchemaVersion, RecordListQuery, RelationBehavior,
+    RelationCascadeResult, RelationDeletePlan, RelationLookupConfig, RuntimeRecordAggregateQuery,
+    RuntimeRecordAggregateRow, RuntimeRecordAssociation, RuntimeRecordChangesetWrite,
+    RuntimeRecordHistoryEntry, RuntimeRecordQuery, RuntimeRecordStatusChange,
+    RuntimeRecordWorkflowEventInput, TenantRepository, UniqueFieldValue,
 };
 
 struct FakeMetadataRepository {
@@ -89,6 +93,14 @@ impl MetadataRepository for FakeMetadataRepository {
         Ok(())
     }
 
+    async fn delete_entity(&self, tenant_id: TenantId, logical_name: &str) -> AppResult<()> {
+        self.entities
+            .lock()
+            .await
+            .remove(&(tenant_id, logical_name.to_owned()));
+        Ok(())
+    }
+
     async fn save_field(&self, tenant_id: TenantId, field: EntityFieldDefinition) -> AppResult<()> {
         self.fields.lock().await.insert(
             (
@@ -478,6 +490,84 @@ impl MetadataRepository for FakeMetadataRepository {
             .and_then(|versions| versions.last().cloned()))
     }
 
+    async fn find_published_schema(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        Ok(self
+            .published_schemas
+            .lock()
+            .await
+            .get(&(tenant_id, entity_logical_name.to_owned()))
+            .and_then(|versions| {
+                versions
+                    .iter()
+                    .find(|schema| schema.version() == version)
+                    .cloned()
+            }))
+    }
+
+    async fn list_published_schema_versions(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<PublishedEntitySchemaVersion>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_published_schema_version(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _version: i32,
+    ) -> AppResult<Option<PublishedEntitySchemaVersion>> {
+        Ok(None)
+    }
+
+    async fn rollback_entity_schema(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+        _published_by: &str,
+    ) -> AppResult<PublishedEntitySchema> {
+        let mut published_schemas = self.published_schemas.lock().await;
+        let versions = published_schemas
+            .entry((tenant_id, entity_logical_name.to_owned()))
+            .or_default();
+        let target = versions
+            .iter()
+            .find(|schema| schema.version() == version)
+            .cloned()
+            .ok_or_else(|| AppError::NotFound("published schema version not found".to_owned()))?;
+        let next_version = versions
+            .last()
+            .map(|schema| schema.version() + 1)
+            .unwrap_or(1);
+        let schema = PublishedEntitySchema::new(
+            target.entity().clone(),
+            next_version,
+            target.fields().to_vec(),
+            target.option_sets().to_vec(),
+        )?;
+        versions.push(schema.clone());
+
+        let source_key = (tenant_id, entity_logical_name.to_owned(), version);
+        let target_key = (tenant_id, entity_logical_name.to_owned(), next_version);
+        let mut form_snapshots = self.published_form_snapshots.lock().await;
+        if let Some(forms) = form_snapshots.get(&source_key).cloned() {
+            form_snapshots.insert(target_key.clone(), forms);
+        }
+        let mut view_snapshots = self.published_view_snapshots.lock().await;
+        if let Some(views) = view_snapshots.get(&source_key).cloned() {
+            view_snapshots.insert(target_key, views);
+        }
+
+        Ok(schema)
+    }
+
     async fn save_published_form_snapshots(
         &self,
         tenant_id: TenantId,
@@ -570,6 +660,7 @@ impl MetadataRepository for FakeMetadataRepository {
         _unique_values: Vec<UniqueFieldValue>,
         _created_by_subject: &str,
         _workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        _audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         let generated_record_id = Uuid::new_v4().to_string();
         self.create_runtime_record_with_id(
@@ -580,6 +671,7 @@ impl MetadataRepository for FakeMetadataRepository {
             Vec::new(),
             "bootstrap",
             None,
+            None,
         )
         .await
     }
@@ -593,6 +685,7 @@ impl MetadataRepository for FakeMetadataRepository {
         _unique_values: Vec<UniqueFieldValue>,
         _created_by_subject: &str,
         _workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        _audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         let record = RuntimeRecord::new(record_id, entity_logical_name, data)?;
         self.runtime_records.lock().await.insert(
@@ -606,6 +699,40 @@ impl MetadataRepository for FakeMetadataRepository {
         Ok(record)
     }
 
+    async fn create_runtime_record_with_idempotency_key(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        _idempotency_key: &str,
+        data: Value,
+        unique_values: Vec<UniqueFieldValue>,
+        created_by_subject: &str,
+        workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+    ) -> AppResult<RuntimeRecord> {
+        self.create_runtime_record_with_id(
+            tenant_id,
+            entity_logical_name,
+            record_id,
+            data,
+            unique_values,
+            created_by_subject,
+            workflow_event,
+            audit_event,
+        )
+        .await
+    }
+
+    async fn find_runtime_record_by_idempotency_key(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _idempotency_key: &str,
+    ) -> AppResult<Option<RuntimeRecord>> {
+        Ok(None)
+    }
+
     async fn update_runtime_record(
         &self,
         _tenant_id: TenantId,
@@ -613,13 +740,26 @@ impl MetadataRepository for FakeMetadataRepository {
         _record_id: &str,
         _data: Value,
         _unique_values: Vec<UniqueFieldValue>,
+        _expected_version: Option<i64>,
         _workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        _audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         Err(AppError::Internal(
             "update_runtime_record is not used in contact bootstrap tests".to_owned(),
         ))
     }
 
+    async fn apply_runtime_record_changeset(
+        &self,
+        _tenant_id: TenantId,
+        _created_by_subject: &str,
+        _writes: Vec<RuntimeRecordChangesetWrite>,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        Err(AppError::Internal(
+            "apply_runtime_record_changeset is not used in contact bootstrap tests".to_owned(),
+        ))
+    }
+
     async fn list_runtime_records(
         &self,
         _tenant_id: TenantId,
@@ -638,6 +778,15 @@ impl MetadataRepository for FakeMetadataRepository {
         Ok(Vec::new())
     }
 
+    async fn aggregate_runtime_records(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _query: RuntimeRecordAggregateQuery,
+    ) -> AppResult<Vec<RuntimeRecordAggregateRow>> {
+        Ok(Vec::new())
+    }
+
     async fn find_runtime_record(
         &self,
         tenant_id: TenantId,
@@ -662,6 +811,8 @@ impl MetadataRepository for FakeMetadataRepository {
         entity_logical_name: &str,
         record_id: &str,
         _workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        _audit_event: Option<AuditEvent>,
+        _relation_plan: &RelationDeletePlan,
     ) -> AppResult<()> {
         self.runtime_records.lock().await.remove(&(
             tenant_id,
@@ -725,6 +876,218 @@ impl MetadataRepository for FakeMetadataRepository {
         Ok(false)
     }
 
+    async fn assign_runtime_record_owner(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _record_id: &str,
+        _owner_subject: &str,
+        _cascades: &[RelationBehavior],
+    ) -> AppResult<Option<(String, Vec<RelationCascadeResult>)>> {
+        Ok(None)
+    }
+
+    async fn save_relation_behavior(
+        &self,
+        _tenant_id: TenantId,
+        _updated_by_subject: &str,
+        _behavior: RelationBehavior,
+    ) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn list_relation_behaviors(
+        &self,
+        _tenant_id: TenantId,
+    ) -> AppResult<Vec<RelationBehavior>> {
+        Ok(Vec::new())
+    }
+
+    async fn save_relation_lookup_config(
+        &self,
+        _tenant_id: TenantId,
+        _updated_by_subject: &str,
+        _config: RelationLookupConfig,
+    ) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn find_relation_lookup_config(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _field_logical_name: &str,
+    ) -> AppResult<Option<RelationLookupConfig>> {
+        Ok(None)
+    }
+
+    async fn delete_relation_lookup_config(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _field_logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(false)
+    }
+
+    async fn save_entity_slug_config(
+        &self,
+        _tenant_id: TenantId,
+        _updated_by_subject: &str,
+        _config: EntitySlugConfig,
+    ) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn find_entity_slug_config(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+    ) -> AppResult<Option<EntitySlugConfig>> {
+        Ok(None)
+    }
+
+    async fn save_entity_status_config(
+        &self,
+        _tenant_id: TenantId,
+        _updated_by_subject: &str,
+        _config: EntityStatusConfig,
+    ) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn find_entity_status_config(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+    ) -> AppResult<Option<EntityStatusConfig>> {
+        Ok(None)
+    }
+
+    async fn delete_entity_status_config(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(false)
+    }
+
+    async fn save_duplicate_detection_rule(
+        &self,
+        _tenant_id: TenantId,
+        _updated_by_subject: &str,
+        _rule: DuplicateDetectionRule,
+    ) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn list_duplicate_detection_rules(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<DuplicateDetectionRule>> {
+        Ok(Vec::new())
+    }
+
+    async fn delete_duplicate_detection_rule(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(false)
+    }
+
+    async fn append_runtime_record_status_change(
+        &self,
+        _tenant_id: TenantId,
+        _change: NewRuntimeRecordStatusChange,
+    ) -> AppResult<RuntimeRecordStatusChange> {
+        Err(AppError::Internal(
+            "status history is not supported in this fake".to_owned(),
+        ))
+    }
+
+    async fn list_runtime_record_status_history(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _record_id: &str,
+    ) -> AppResult<Vec<RuntimeRecordStatusChange>> {
+        Ok(Vec::new())
+    }
+
+    async fn save_entity_history_policy(
+        &self,
+        _tenant_id: TenantId,
+        _updated_by_subject: &str,
+        _policy: EntityHistoryPolicy,
+    ) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn find_entity_history_policy(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+    ) -> AppResult<Option<EntityHistoryPolicy>> {
+        Ok(None)
+    }
+
+    async fn delete_entity_history_policy(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(false)
+    }
+
+    async fn append_runtime_record_history_entry(
+        &self,
+        _tenant_id: TenantId,
+        entry: NewRuntimeRecordHistoryEntry,
+        _retention_cutoff: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> AppResult<RuntimeRecordHistoryEntry> {
+        Ok(RuntimeRecordHistoryEntry {
+            entry_id: "history-1".to_owned(),
+            entity_logical_name: entry.entity_logical_name,
+            record_id: entry.record_id,
+            record_version: entry.record_version,
+            changes: entry.changes,
+            changed_by_subject: entry.changed_by_subject,
+            changed_at: chrono::Utc::now(),
+        })
+    }
+
+    async fn list_runtime_record_history(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _record_id: &str,
+        _since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> AppResult<Vec<RuntimeRecordHistoryEntry>> {
+        Ok(Vec::new())
+    }
+
+    async fn purge_runtime_record_history(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _cutoff: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<u64> {
+        Ok(0)
+    }
+
+    async fn find_runtime_record_id_by_unique_value(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _field_logical_name: &str,
+        _field_value_hash: &str,
+    ) -> AppResult<Option<String>> {
+        Ok(None)
+    }
+
     async fn has_relation_reference(
         &self,
         _tenant_id: TenantId,
@@ -733,6 +1096,33 @@ impl MetadataRepository for FakeMetadataRepository {
     ) -> AppResult<bool> {
         Ok(false)
     }
+
+    async fn associate_runtime_records(
+        &self,
+        _tenant_id: TenantId,
+        _associated_by_subject: &str,
+        _association: RuntimeRecordAssociation,
+    ) -> AppResult<bool> {
+        Ok(false)
+    }
+
+    async fn disassociate_runtime_records(
+        &self,
+        _tenant_id: TenantId,
+        _association: &RuntimeRecordAssociation,
+    ) -> AppResult<bool> {
+        Ok(false)
+    }
+
+    async fn list_associated_runtime_record_ids(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _field_logical_name: &str,
+        _record_id: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
 }
 
 #[derive(Default)]
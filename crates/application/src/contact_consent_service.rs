@@ -0,0 +1,17 @@
+//! Consent tracking for contact records.
+//!
+//! Consent is captured per contact, purpose, and channel. Every change is
+//! kept in an append-only history, and workflow email steps consult the
+//! current state so automated sends skip contacts without marketing consent.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    ConsentChannel, ContactConsent, ContactConsentChange, ContactConsentRepository,
+    ContactConsentStatus, MARKETING_CONSENT_PURPOSE, RecordContactConsentInput,
+};
+pub use service::ContactConsentService;
@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+/// Consent purpose checked before automated marketing email sends.
+pub const MARKETING_CONSENT_PURPOSE: &str = "marketing";
+
+/// Communication channel a consent applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsentChannel {
+    /// Email messages.
+    Email,
+    /// SMS or other text messages.
+    Sms,
+    /// Phone calls.
+    Phone,
+    /// Postal mail.
+    Post,
+}
+
+impl ConsentChannel {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::Sms => "sms",
+            Self::Phone => "phone",
+            Self::Post => "post",
+        }
+    }
+
+    /// Parses a storage or transport value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "email" => Ok(Self::Email),
+            "sms" => Ok(Self::Sms),
+            "phone" => Ok(Self::Phone),
+            "post" => Ok(Self::Post),
+            _ => Err(AppError::Validation(format!(
+                "unknown consent channel '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Current consent state for one purpose and channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactConsentStatus {
+    /// Contact agreed to be contacted.
+    Granted,
+    /// Contact withdrew a previous agreement.
+    Revoked,
+}
+
+impl ContactConsentStatus {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Granted => "granted",
+            Self::Revoked => "revoked",
+        }
+    }
+
+    /// Parses a storage or transport value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "granted" => Ok(Self::Granted),
+            "revoked" => Ok(Self::Revoked),
+            _ => Err(AppError::Validation(format!(
+                "unknown consent status '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Current consent record for one contact, purpose, and channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactConsent {
+    /// Owning tenant.
+    pub tenant_id: TenantId,
+    /// Contact runtime record id.
+    pub contact_record_id: String,
+    /// Consent purpose, e.g. `marketing`.
+    pub purpose: String,
+    /// Channel the consent applies to.
+    pub channel: ConsentChannel,
+    /// Current state.
+    pub status: ContactConsentStatus,
+    /// Where the latest change was captured, e.g. a form or import name.
+    pub source: String,
+    /// Most recent grant timestamp.
+    pub granted_at: Option<DateTime<Utc>>,
+    /// Most recent revocation timestamp.
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// Subject that recorded the latest change.
+    pub recorded_by_subject: String,
+    /// Timestamp of the latest change.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// One entry in a contact's consent history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactConsentChange {
+    /// Stable change identifier.
+    pub change_id: String,
+    /// Contact runtime record id.
+    pub contact_record_id: String,
+    /// Consent purpose.
+    pub purpose: String,
+    /// Channel the change applies to.
+    pub channel: ConsentChannel,
+    /// State before the change, absent for first captures.
+    pub previous_status: Option<ContactConsentStatus>,
+    /// State after the change.
+    pub status: ContactConsentStatus,
+    /// Where the change was captured.
+    pub source: String,
+    /// Subject that recorded the change.
+    pub recorded_by_subject: String,
+    /// Change timestamp.
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Input payload for capturing a consent change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordContactConsentInput {
+    /// Contact runtime record id.
+    pub contact_record_id: String,
+    /// Consent purpose.
+    pub purpose: String,
+    /// Channel the consent applies to.
+    pub channel: ConsentChannel,
+    /// New state.
+    pub status: ContactConsentStatus,
+    /// Where the change was captured.
+    pub source: String,
+}
+
+/// Repository port for contact consent records.
+#[async_trait]
+pub trait ContactConsentRepository: Send + Sync {
+    /// Stores the new consent state and appends a history entry.
+    ///
+    /// Returns `NotFound` when the id does not name a contact record.
+    async fn record_consent(
+        &self,
+        tenant_id: TenantId,
+        recorded_by_subject: &str,
+        input: RecordContactConsentInput,
+    ) -> AppResult<ContactConsentChange>;
+
+    /// Lists current consent records for one contact.
+    async fn list_contact_consents(
+        &self,
+        tenant_id: TenantId,
+        contact_record_id: &str,
+    ) -> AppResult<Vec<ContactConsent>>;
+
+    /// Lists consent history for one contact, newest first.
+    async fn list_contact_consent_history(
+        &self,
+        tenant_id: TenantId,
+        contact_record_id: &str,
+    ) -> AppResult<Vec<ContactConsentChange>>;
+
+    /// Resolves email consent for contacts matching an address.
+    ///
+    /// Returns `None` when no contact uses the address, otherwise whether every
+    /// matching contact currently grants consent for the purpose.
+    async fn email_consent_for_address(
+        &self,
+        tenant_id: TenantId,
+        email: &str,
+        purpose: &str,
+    ) -> AppResult<Option<bool>>;
+}
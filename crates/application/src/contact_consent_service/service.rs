@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::{
+    ContactConsent, ContactConsentChange, ContactConsentRepository, ContactConsentStatus,
+    RecordContactConsentInput,
+};
+
+const MAX_PURPOSE_LENGTH: usize = 64;
+const MAX_SOURCE_LENGTH: usize = 200;
+
+/// Application service for capturing and reviewing contact consent.
+#[derive(Clone)]
+pub struct ContactConsentService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn ContactConsentRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl ContactConsentService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn ContactConsentRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Captures a consent grant or revocation for one contact.
+    pub async fn record_consent(
+        &self,
+        actor: &UserIdentity,
+        input: RecordContactConsentInput,
+    ) -> AppResult<ContactConsentChange> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::RuntimeRecordWrite,
+            )
+            .await?;
+
+        let input = normalize_consent_input(input)?;
+        let change = self
+            .repository
+            .record_consent(actor.tenant_id(), actor.subject(), input)
+            .await?;
+
+        let action = match change.status {
+            ContactConsentStatus::Granted => AuditAction::ContactConsentGranted,
+            ContactConsentStatus::Revoked => AuditAction::ContactConsentRevoked,
+        };
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "contact_consent".to_owned(),
+                resource_id: change.contact_record_id.clone(),
+                detail: Some(
+                    serde_json::json!({
+                        "purpose": change.purpose,
+                        "channel": change.channel.as_str(),
+                        "source": change.source,
+                        "previous_status": change.previous_status.map(ContactConsentStatus::as_str),
+                    })
+                    .to_string(),
+                ),
+            })
+            .await?;
+
+        Ok(change)
+    }
+
+    /// Lists the current consent state for one contact.
+    pub async fn list_consents(
+        &self,
+        actor: &UserIdentity,
+        contact_record_id: &str,
+    ) -> AppResult<Vec<ContactConsent>> {
+        self.require_read_permission(actor).await?;
+        self.repository
+            .list_contact_consents(actor.tenant_id(), contact_record_id)
+            .await
+    }
+
+    /// Lists every consent change captured for one contact, newest first.
+    pub async fn list_consent_history(
+        &self,
+        actor: &UserIdentity,
+        contact_record_id: &str,
+    ) -> AppResult<Vec<ContactConsentChange>> {
+        self.require_read_permission(actor).await?;
+        self.repository
+            .list_contact_consent_history(actor.tenant_id(), contact_record_id)
+            .await
+    }
+
+    async fn require_read_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::RuntimeRecordRead,
+            )
+            .await
+    }
+}
+
+fn normalize_consent_input(
+    input: RecordContactConsentInput,
+) -> AppResult<RecordContactConsentInput> {
+    let purpose = input.purpose.trim().to_ascii_lowercase();
+    if purpose.is_empty()
+        || purpose.len() > MAX_PURPOSE_LENGTH
+        || !purpose.chars().all(|character| {
+            character.is_ascii_lowercase() || character.is_ascii_digit() || character == '_'
+        })
+    {
+        return Err(AppError::Validation(format!(
+            "consent purpose must be 1-{MAX_PURPOSE_LENGTH} characters of lowercase letters, digits, or underscores"
+        )));
+    }
+
+    let source = input.source.trim().to_owned();
+    if source.is_empty() || source.chars().count() > MAX_SOURCE_LENGTH {
+        return Err(AppError::Validation(format!(
+            "consent source must be between 1 and {MAX_SOURCE_LENGTH} characters"
+        )));
+    }
+
+    Ok(RecordContactConsentInput {
+        contact_record_id: input.contact_record_id.trim().to_owned(),
+        purpose,
+        channel: input.channel,
+        status: input.status,
+        source,
+    })
+}
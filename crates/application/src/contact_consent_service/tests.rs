@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    TemporaryPermissionGrant,
+};
+
+use super::{
+    ConsentChannel, ContactConsent, ContactConsentChange, ContactConsentRepository,
+    ContactConsentService, ContactConsentStatus, MARKETING_CONSENT_PURPOSE,
+    RecordContactConsentInput,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeConsentRepository {
+    contact_emails: HashMap<String, String>,
+    consents: Mutex<Vec<ContactConsent>>,
+    history: Mutex<Vec<ContactConsentChange>>,
+}
+
+#[async_trait]
+impl ContactConsentRepository for FakeConsentRepository {
+    async fn record_consent(
+        &self,
+        tenant_id: TenantId,
+        recorded_by_subject: &str,
+        input: RecordContactConsentInput,
+    ) -> AppResult<ContactConsentChange> {
+        if !self.contact_emails.contains_key(&input.contact_record_id) {
+            return Err(AppError::NotFound(format!(
+                "contact '{}' does not exist",
+                input.contact_record_id
+            )));
+        }
+
+        let now = Utc::now();
+        let mut consents = self.consents.lock().await;
+        let existing = consents.iter_mut().find(|consent| {
+            consent.contact_record_id == input.contact_record_id
+                && consent.purpose == input.purpose
+                && consent.channel == input.channel
+        });
+        let previous_status = existing.as_ref().map(|consent| consent.status);
+        let (granted_at, revoked_at) = match input.status {
+            ContactConsentStatus::Granted => (
+                Some(now),
+                existing.as_ref().and_then(|consent| consent.revoked_at),
+            ),
+            ContactConsentStatus::Revoked => (
+                existing.as_ref().and_then(|consent| consent.granted_at),
+                Some(now),
+            ),
+        };
+        let consent = ContactConsent {
+            tenant_id,
+            contact_record_id: input.contact_record_id.clone(),
+            purpose: input.purpose.clone(),
+            channel: input.channel,
+            status: input.status,
+            source: input.source.clone(),
+            granted_at,
+            revoked_at,
+            recorded_by_subject: recorded_by_subject.to_owned(),
+            updated_at: now,
+        };
+        match existing {
+            Some(existing) => *existing = consent,
+            None => consents.push(consent),
+        }
+
+        let mut history = self.history.lock().await;
+        let change = ContactConsentChange {
+            change_id: format!("change-{}", history.len() + 1),
+            contact_record_id: input.contact_record_id,
+            purpose: input.purpose,
+            channel: input.channel,
+            previous_status,
+            status: input.status,
+            source: input.source,
+            recorded_by_subject: recorded_by_subject.to_owned(),
+            recorded_at: now,
+        };
+        history.insert(0, change.clone());
+        Ok(change)
+    }
+
+    async fn list_contact_consents(
+        &self,
+        _tenant_id: TenantId,
+        contact_record_id: &str,
+    ) -> AppResult<Vec<ContactConsent>> {
+        Ok(self
+            .consents
+            .lock()
+            .await
+            .iter()
+            .filter(|consent| consent.contact_record_id == contact_record_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_contact_consent_history(
+        &self,
+        _tenant_id: TenantId,
+        contact_record_id: &str,
+    ) -> AppResult<Vec<ContactConsentChange>> {
+        Ok(self
+            .history
+            .lock()
+            .await
+            .iter()
+            .filter(|change| change.contact_record_id == contact_record_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn email_consent_for_address(
+        &self,
+        _tenant_id: TenantId,
+        email: &str,
+        purpose: &str,
+    ) -> AppResult<Option<bool>> {
+        let contact_ids: Vec<&String> = self
+            .contact_emails
+            .iter()
+            .filter(|(_, contact_email)| contact_email.eq_ignore_ascii_case(email))
+            .map(|(contact_id, _)| contact_id)
+            .collect();
+        if contact_ids.is_empty() {
+            return Ok(None);
+        }
+
+        let consents = self.consents.lock().await;
+        Ok(Some(contact_ids.iter().all(|contact_id| {
+            consents.iter().any(|consent| {
+                &&consent.contact_record_id == contact_id
+                    && consent.purpose == purpose
+                    && consent.channel == ConsentChannel::Email
+                    && consent.status == ContactConsentStatus::Granted
+            })
+        })))
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    permissions: Vec<Permission>,
+    repository: Arc<FakeConsentRepository>,
+) -> (ContactConsentService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([((tenant_id, "alice".to_owned()), permissions)]),
+        }),
+        audit_repository.clone(),
+    );
+    let service =
+        ContactConsentService::new(authorization_service, repository, audit_repository.clone());
+    (service, audit_repository)
+}
+
+fn repository_with_contact() -> Arc<FakeConsentRepository> {
+    Arc::new(FakeConsentRepository {
+        contact_emails: HashMap::from([("contact-1".to_owned(), "ada@example.com".to_owned())]),
+        ..FakeConsentRepository::default()
+    })
+}
+
+fn consent_input(status: ContactConsentStatus) -> RecordContactConsentInput {
+    RecordContactConsentInput {
+        contact_record_id: "contact-1".to_owned(),
+        purpose: " Marketing ".to_owned(),
+        channel: ConsentChannel::Email,
+        status,
+        source: "newsletter signup form".to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn record_consent_requires_record_write_permission() {
+    let tenant_id = TenantId::new();
+    let repository = repository_with_contact();
+    let (service, _) = build_service(
+        tenant_id,
+        vec![Permission::RuntimeRecordRead],
+        repository.clone(),
+    );
+
+    let result = service
+        .record_consent(
+            &actor(tenant_id, "alice"),
+            consent_input(ContactConsentStatus::Granted),
+        )
+        .await;
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+    assert!(repository.history.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn record_consent_validates_purpose_and_source() {
+    let tenant_id = TenantId::new();
+    let (service, _) = build_service(
+        tenant_id,
+        vec![Permission::RuntimeRecordWrite],
+        repository_with_contact(),
+    );
+    let actor = actor(tenant_id, "alice");
+
+    let invalid_purpose = service
+        .record_consent(
+            &actor,
+            RecordContactConsentInput {
+                purpose: "marketing emails!".to_owned(),
+                ..consent_input(ContactConsentStatus::Granted)
+            },
+        )
+        .await;
+    assert!(matches!(invalid_purpose, Err(AppError::Validation(_))));
+
+    let missing_source = service
+        .record_consent(
+            &actor,
+            RecordContactConsentInput {
+                source: " ".to_owned(),
+                ..consent_input(ContactConsentStatus::Granted)
+            },
+        )
+        .await;
+    assert!(matches!(missing_source, Err(AppError::Validation(_))));
+
+    let unknown_contact = service
+        .record_consent(
+            &actor,
+            RecordContactConsentInput {
+                contact_record_id: "contact-404".to_owned(),
+                ..consent_input(ContactConsentStatus::Granted)
+            },
+        )
+        .await;
+    assert!(matches!(unknown_contact, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn consent_changes_are_tracked_in_history_and_audit_log() {
+    let tenant_id = TenantId::new();
+    let repository = repository_with_contact();
+    let (service, audit_repository) = build_service(
+        tenant_id,
+        vec![
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
+        ],
+        repository.clone(),
+    );
+    let actor = actor(tenant_id, "alice");
+
+    let granted = service
+        .record_consent(&actor, consent_input(ContactConsentStatus::Granted))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(granted.purpose, MARKETING_CONSENT_PURPOSE);
+    assert_eq!(granted.previous_status, None);
+    assert_eq!(
+        repository
+            .email_consent_for_address(tenant_id, "ADA@example.com", MARKETING_CONSENT_PURPOSE)
+            .await
+            .unwrap_or_else(|_| unreachable!()),
+        Some(true)
+    );
+
+    let revoked = service
+        .record_consent(&actor, consent_input(ContactConsentStatus::Revoked))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(revoked.previous_status, Some(ContactConsentStatus::Granted));
+
+    let consents = service
+        .list_consents(&actor, "contact-1")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(consents.len(), 1);
+    assert_eq!(consents[0].status, ContactConsentStatus::Revoked);
+    assert!(consents[0].granted_at.is_some());
+
+    let history = service
+        .list_consent_history(&actor, "contact-1")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let statuses: Vec<ContactConsentStatus> = history.iter().map(|change| change.status).collect();
+    assert_eq!(
+        statuses,
+        vec![ContactConsentStatus::Revoked, ContactConsentStatus::Granted]
+    );
+
+    let events = audit_repository.events.lock().await;
+    let actions: Vec<AuditAction> = events.iter().map(|event| event.action).collect();
+    assert_eq!(
+        actions,
+        vec![
+            AuditAction::ContactConsentGranted,
+            AuditAction::ContactConsentRevoked,
+        ]
+    );
+}
@@ -0,0 +1,20 @@
+//! Cross-entity contact identity resolution.
+//!
+//! Entities registered as identity sources contribute normalized email, phone,
+//! and person-name keys for each record. Records whose keys match are linked
+//! to a shared master contact so one real-world person can be found across
+//! leads, contacts, and any other contact-like entity.
+
+mod matching;
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    ContactIdentityKeys, ContactIdentityLink, ContactIdentityMatch, ContactIdentityRebuildSummary,
+    ContactIdentityRepository, ContactIdentitySource, ContactMatchReason, MasterContact,
+    NewContactIdentityLink, SaveContactIdentitySourceInput,
+};
+pub use service::ContactIdentityService;
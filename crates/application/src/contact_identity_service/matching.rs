@@ -0,0 +1,220 @@
+use serde_json::Value;
+
+use super::ports::{ContactIdentityKeys, ContactIdentitySource, ContactMatchReason};
+
+/// Minimum Jaro-Winkler similarity for two person names to match.
+pub(super) const FUZZY_NAME_THRESHOLD: f64 = 0.92;
+
+const EMAIL_CONFIDENCE: u8 = 100;
+const PHONE_CONFIDENCE: u8 = 90;
+const MAX_FUZZY_NAME_CONFIDENCE: f64 = 85.0;
+const MIN_PHONE_DIGITS: usize = 7;
+const MAX_PHONE_DIGITS: usize = 15;
+
+/// Best candidate found for a set of identity keys.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct KeyMatch {
+    pub(super) match_reason: ContactMatchReason,
+    pub(super) confidence: u8,
+}
+
+/// Extracts normalized identity keys from a record payload.
+pub(super) fn extract_keys(source: &ContactIdentitySource, data: &Value) -> ContactIdentityKeys {
+    let field_text =
+        |field_logical_name: &str| data.get(field_logical_name).and_then(Value::as_str);
+
+    let name = source
+        .name_field_logical_names
+        .iter()
+        .filter_map(|field_logical_name| field_text(field_logical_name))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    ContactIdentityKeys {
+        email: source
+            .email_field_logical_name
+            .as_deref()
+            .and_then(field_text)
+            .and_then(normalize_email),
+        phone: source
+            .phone_field_logical_name
+            .as_deref()
+            .and_then(field_text)
+            .and_then(normalize_phone),
+        name: normalize_person_name(name.as_str()),
+    }
+}
+
+/// Lowercases and validates an email address.
+pub(super) fn normalize_email(value: &str) -> Option<String> {
+    let email = value.trim().to_lowercase();
+    let (local, domain) = email.split_once('@')?;
+    if local.is_empty()
+        || domain.contains('@')
+        || !domain.contains('.')
+        || domain.starts_with('.')
+        || domain.ends_with('.')
+        || email.chars().any(char::is_whitespace)
+    {
+        return None;
+    }
+
+    Some(email)
+}
+
+/// Reduces a phone number to its digits, keeping an international `+` prefix.
+///
+/// Both `+` and the `00` dialing prefix are treated as international, so
+/// `+49 30 1234567` and `0049 (30) 123-4567` produce the same key.
+pub(super) fn normalize_phone(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    let digits = trimmed
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+
+    let (international, digits) = if trimmed.starts_with('+') {
+        (true, digits.as_str())
+    } else if let Some(rest) = digits.strip_prefix("00") {
+        (true, rest)
+    } else {
+        (false, digits.as_str())
+    };
+
+    if digits.len() < MIN_PHONE_DIGITS || digits.len() > MAX_PHONE_DIGITS {
+        return None;
+    }
+
+    Some(if international {
+        format!("+{digits}")
+    } else {
+        digits.to_owned()
+    })
+}
+
+/// Lowercases a person name and sorts its tokens so word order is ignored.
+pub(super) fn normalize_person_name(value: &str) -> Option<String> {
+    let lowered = value
+        .to_lowercase()
+        .chars()
+        .map(|character| {
+            if character.is_alphanumeric() {
+                character
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>();
+    let mut tokens = lowered.split_whitespace().collect::<Vec<_>>();
+    if tokens.is_empty() {
+        return None;
+    }
+
+    tokens.sort_unstable();
+    Some(tokens.join(" "))
+}
+
+/// Scores how well a candidate's keys match the record's keys.
+///
+/// Exact email and phone keys always match. Names only match fuzzily when
+/// neither side carries an email or phone that contradicts the other.
+pub(super) fn match_keys(
+    keys: &ContactIdentityKeys,
+    candidate: &ContactIdentityKeys,
+) -> Option<KeyMatch> {
+    if keys.email.is_some() && keys.email == candidate.email {
+        return Some(KeyMatch {
+            match_reason: ContactMatchReason::Email,
+            confidence: EMAIL_CONFIDENCE,
+        });
+    }
+
+    if keys.phone.is_some() && keys.phone == candidate.phone {
+        return Some(KeyMatch {
+            match_reason: ContactMatchReason::Phone,
+            confidence: PHONE_CONFIDENCE,
+        });
+    }
+
+    let conflicts = |left: &Option<String>, right: &Option<String>| matches!((left, right), (Some(left), Some(right)) if left != right);
+    if conflicts(&keys.email, &candidate.email) || conflicts(&keys.phone, &candidate.phone) {
+        return None;
+    }
+
+    let (Some(name), Some(candidate_name)) = (keys.name.as_deref(), candidate.name.as_deref())
+    else {
+        return None;
+    };
+    let similarity = jaro_winkler(name, candidate_name);
+    if similarity < FUZZY_NAME_THRESHOLD {
+        return None;
+    }
+
+    Some(KeyMatch {
+        match_reason: ContactMatchReason::FuzzyName,
+        confidence: (similarity * MAX_FUZZY_NAME_CONFIDENCE).round() as u8,
+    })
+}
+
+/// Computes the Jaro-Winkler similarity of two strings in `0.0..=1.0`.
+pub(super) fn jaro_winkler(left: &str, right: &str) -> f64 {
+    let left = left.chars().collect::<Vec<_>>();
+    let right = right.chars().collect::<Vec<_>>();
+    if left.is_empty() && right.is_empty() {
+        return 1.0;
+    }
+    if left.is_empty() || right.is_empty() {
+        return 0.0;
+    }
+
+    let window = (left.len().max(right.len()) / 2).saturating_sub(1);
+    let mut left_matched = vec![false; left.len()];
+    let mut right_matched = vec![false; right.len()];
+    let mut matches = 0_usize;
+
+    for (left_index, left_character) in left.iter().enumerate() {
+        let start = left_index.saturating_sub(window);
+        let end = (left_index + window + 1).min(right.len());
+        for right_index in start..end {
+            if !right_matched[right_index] && right[right_index] == *left_character {
+                left_matched[left_index] = true;
+                right_matched[right_index] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let left_sequence = left
+        .iter()
+        .zip(&left_matched)
+        .filter_map(|(character, matched)| matched.then_some(character));
+    let right_sequence = right
+        .iter()
+        .zip(&right_matched)
+        .filter_map(|(character, matched)| matched.then_some(character));
+    let transpositions = left_sequence
+        .zip(right_sequence)
+        .filter(|(left_character, right_character)| left_character != right_character)
+        .count()
+        / 2;
+
+    let matches = matches as f64;
+    let jaro = (matches / left.len() as f64
+        + matches / right.len() as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0;
+
+    let prefix = left
+        .iter()
+        .zip(&right)
+        .take(4)
+        .take_while(|(left_character, right_character)| left_character == right_character)
+        .count();
+
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
@@ -0,0 +1,248 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+/// Entity whose records take part in global contact matching.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactIdentitySource {
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Field holding the record's email address.
+    pub email_field_logical_name: Option<String>,
+    /// Field holding the record's phone number.
+    pub phone_field_logical_name: Option<String>,
+    /// Fields concatenated, in order, into the record's person name.
+    pub name_field_logical_names: Vec<String>,
+    /// Subject that last saved the source.
+    pub updated_by_subject: String,
+    /// Last update timestamp.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input payload for registering an entity as a contact identity source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveContactIdentitySourceInput {
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Field holding the record's email address.
+    pub email_field_logical_name: Option<String>,
+    /// Field holding the record's phone number.
+    pub phone_field_logical_name: Option<String>,
+    /// Fields concatenated, in order, into the record's person name.
+    pub name_field_logical_names: Vec<String>,
+}
+
+/// Normalized identity keys extracted from one record.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContactIdentityKeys {
+    /// Lowercased email address.
+    pub email: Option<String>,
+    /// Digits-only phone number, prefixed with `+` when international.
+    pub phone: Option<String>,
+    /// Lowercased name tokens in sorted order.
+    pub name: Option<String>,
+}
+
+impl ContactIdentityKeys {
+    /// Returns whether no key could be extracted.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.email.is_none() && self.phone.is_none() && self.name.is_none()
+    }
+
+    /// Returns the blocking key used to narrow fuzzy name candidates.
+    ///
+    /// The block is the initials of the sorted name tokens, so small spelling
+    /// differences inside a token still land in the same block.
+    #[must_use]
+    pub fn name_block(&self) -> Option<String> {
+        self.name.as_deref().map(|name| {
+            name.split(' ')
+                .filter_map(|token| token.chars().next())
+                .collect()
+        })
+    }
+}
+
+/// Why a record was linked to its master contact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactMatchReason {
+    /// Normalized email addresses are equal.
+    Email,
+    /// Normalized phone numbers are equal.
+    Phone,
+    /// Person names are similar and no email or phone contradicts the match.
+    FuzzyName,
+    /// A user linked or split the record explicitly; automatic matching keeps it.
+    Manual,
+    /// No other record matched; the record is its own master contact.
+    Unmatched,
+}
+
+impl ContactMatchReason {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::Phone => "phone",
+            Self::FuzzyName => "fuzzy_name",
+            Self::Manual => "manual",
+            Self::Unmatched => "unmatched",
+        }
+    }
+
+    /// Parses a storage value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "email" => Ok(Self::Email),
+            "phone" => Ok(Self::Phone),
+            "fuzzy_name" => Ok(Self::FuzzyName),
+            "manual" => Ok(Self::Manual),
+            "unmatched" => Ok(Self::Unmatched),
+            _ => Err(AppError::Validation(format!(
+                "unknown contact match reason '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Link data persisted by the repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewContactIdentityLink {
+    /// Master contact the record belongs to.
+    pub master_contact_id: String,
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Runtime record identifier.
+    pub record_id: String,
+    /// Normalized keys extracted from the record.
+    pub keys: ContactIdentityKeys,
+    /// Why the record was linked.
+    pub match_reason: ContactMatchReason,
+    /// Match confidence from 0 to 100.
+    pub confidence: u8,
+}
+
+/// Stored link between a runtime record and its master contact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactIdentityLink {
+    /// Master contact the record belongs to.
+    pub master_contact_id: String,
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Runtime record identifier.
+    pub record_id: String,
+    /// Normalized keys extracted from the record.
+    pub keys: ContactIdentityKeys,
+    /// Why the record was linked.
+    pub match_reason: ContactMatchReason,
+    /// Match confidence from 0 to 100.
+    pub confidence: u8,
+    /// Subject whose write or request produced the link.
+    pub linked_by_subject: String,
+    /// Link timestamp.
+    pub linked_at: DateTime<Utc>,
+}
+
+/// All records resolved to one real-world contact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MasterContact {
+    /// Master contact identifier.
+    pub master_contact_id: String,
+    /// Linked records, oldest first.
+    pub links: Vec<ContactIdentityLink>,
+}
+
+/// Existing link matching a lookup, with the strength of the match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactIdentityMatch {
+    /// Matching link.
+    pub link: ContactIdentityLink,
+    /// Key that matched.
+    pub match_reason: ContactMatchReason,
+    /// Match confidence from 0 to 100.
+    pub confidence: u8,
+}
+
+/// Summary of a source rebuild.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContactIdentityRebuildSummary {
+    /// Records read from the entity.
+    pub scanned_records: usize,
+    /// Records linked to a master shared with at least one other record.
+    pub matched_records: usize,
+}
+
+/// Repository port for contact identity sources and master-contact links.
+#[async_trait]
+pub trait ContactIdentityRepository: Send + Sync {
+    /// Creates or replaces the source configuration for an entity.
+    async fn save_source(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveContactIdentitySourceInput,
+    ) -> AppResult<ContactIdentitySource>;
+
+    /// Lists configured sources ordered by entity logical name.
+    async fn list_sources(&self, tenant_id: TenantId) -> AppResult<Vec<ContactIdentitySource>>;
+
+    /// Finds the source configuration for an entity.
+    async fn find_source(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<ContactIdentitySource>>;
+
+    /// Deletes a source configuration together with the links of its records.
+    ///
+    /// Returns whether a source existed.
+    async fn delete_source(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool>;
+
+    /// Finds the link of one record.
+    async fn find_link(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<ContactIdentityLink>>;
+
+    /// Lists links sharing the email or phone key, or the name block, oldest first.
+    async fn list_candidate_links(
+        &self,
+        tenant_id: TenantId,
+        keys: &ContactIdentityKeys,
+        limit: usize,
+    ) -> AppResult<Vec<ContactIdentityLink>>;
+
+    /// Creates or replaces the link of one record.
+    async fn save_link(
+        &self,
+        tenant_id: TenantId,
+        linked_by_subject: &str,
+        link: NewContactIdentityLink,
+    ) -> AppResult<ContactIdentityLink>;
+
+    /// Removes the link of one record.
+    ///
+    /// Returns whether a link existed.
+    async fn delete_link(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<bool>;
+
+    /// Lists all links of a master contact, oldest first.
+    async fn list_master_links(
+        &self,
+        tenant_id: TenantId,
+        master_contact_id: &str,
+    ) -> AppResult<Vec<ContactIdentityLink>>;
+}
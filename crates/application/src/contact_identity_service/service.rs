@@ -0,0 +1,679 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{AuditAction, FieldType, Permission, PublishedEntitySchema};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationService, RecordListQuery, RuntimeRecordService,
+};
+
+use super::matching::{
+    extract_keys, match_keys, normalize_email, normalize_person_name, normalize_phone,
+};
+use super::ports::{
+    ContactIdentityKeys, ContactIdentityLink, ContactIdentityMatch, ContactIdentityRebuildSummary,
+    ContactIdentityRepository, ContactIdentitySource, ContactMatchReason, MasterContact,
+    NewContactIdentityLink, SaveContactIdentitySourceInput,
+};
+
+const MAX_NAME_FIELDS: usize = 3;
+const MAX_CANDIDATE_LINKS: usize = 200;
+const MAX_LOOKUP_MATCHES: usize = 50;
+const REBUILD_PAGE_SIZE: usize = 200;
+const MANUAL_CONFIDENCE: u8 = 100;
+
+/// Application service resolving contact-like records into master contacts.
+#[derive(Clone)]
+pub struct ContactIdentityService {
+    authorization_service: AuthorizationService,
+    runtime_record_service: Arc<dyn RuntimeRecordService>,
+    repository: Arc<dyn ContactIdentityRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl ContactIdentityService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        runtime_record_service: Arc<dyn RuntimeRecordService>,
+        repository: Arc<dyn ContactIdentityRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            runtime_record_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Registers or reconfigures an entity as a contact identity source.
+    ///
+    /// Existing links are not recomputed; call [`Self::rebuild_source_links`]
+    /// to apply the new field mapping to records written before the change.
+    pub async fn save_source(
+        &self,
+        actor: &UserIdentity,
+        input: SaveContactIdentitySourceInput,
+    ) -> AppResult<ContactIdentitySource> {
+        self.require_configure_permission(actor).await?;
+
+        let entity_logical_name = input.entity_logical_name.trim().to_owned();
+        let schema = self.published_schema(actor, &entity_logical_name).await?;
+        let input = validate_source_input(&schema, input)?;
+
+        let source = self
+            .repository
+            .save_source(actor.tenant_id(), actor.subject(), input)
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::ContactIdentitySourceSaved,
+                resource_type: "contact_identity_source".to_owned(),
+                resource_id: source.entity_logical_name.clone(),
+                detail: Some(
+                    serde_json::json!({
+                        "email_field_logical_name": source.email_field_logical_name,
+                        "phone_field_logical_name": source.phone_field_logical_name,
+                        "name_field_logical_names": source.name_field_logical_names,
+                    })
+                    .to_string(),
+                ),
+            })
+            .await?;
+
+        Ok(source)
+    }
+
+    /// Lists configured contact identity sources.
+    pub async fn list_sources(
+        &self,
+        actor: &UserIdentity,
+    ) -> AppResult<Vec<ContactIdentitySource>> {
+        self.require_read_permission(actor).await?;
+        self.repository.list_sources(actor.tenant_id()).await
+    }
+
+    /// Removes an entity from contact matching and drops the links of its records.
+    pub async fn delete_source(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<()> {
+        self.require_configure_permission(actor).await?;
+
+        if !self
+            .repository
+            .delete_source(actor.tenant_id(), entity_logical_name)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "entity '{entity_logical_name}' is not a contact identity source"
+            )));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::ContactIdentitySourceDeleted,
+                resource_type: "contact_identity_source".to_owned(),
+                resource_id: entity_logical_name.to_owned(),
+                detail: None,
+            })
+            .await
+    }
+
+    /// Re-resolves every record of a source entity.
+    pub async fn rebuild_source_links(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<ContactIdentityRebuildSummary> {
+        self.require_configure_permission(actor).await?;
+        let source = self.require_source(actor, entity_logical_name).await?;
+
+        let mut summary = ContactIdentityRebuildSummary {
+            scanned_records: 0,
+            matched_records: 0,
+        };
+        let mut offset = 0;
+        loop {
+            let records = self
+                .runtime_record_service
+                .list_runtime_records_unchecked(
+                    actor,
+                    entity_logical_name,
+                    RecordListQuery {
+                        limit: REBUILD_PAGE_SIZE,
+                        offset,
+                        owner_subject: None,
+                        include_inactive: true,
+                    },
+                )
+                .await?;
+            let page_len = records.len();
+
+            for record in records {
+                summary.scanned_records += 1;
+                let link = self
+                    .sync_with_source(actor, &source, record.record_id().as_str(), record.data())
+                    .await?;
+                if link.is_some_and(|link| link.match_reason != ContactMatchReason::Unmatched) {
+                    summary.matched_records += 1;
+                }
+            }
+
+            if page_len < REBUILD_PAGE_SIZE {
+                break;
+            }
+            offset += page_len;
+        }
+
+        Ok(summary)
+    }
+
+    /// Resolves one record against existing links on demand.
+    pub async fn resolve_record(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<ContactIdentityLink> {
+        self.require_link_permission(actor).await?;
+        let source = self.require_source(actor, entity_logical_name).await?;
+        let record = self
+            .runtime_record_service
+            .get_runtime_record_unchecked(actor, entity_logical_name, record_id)
+            .await?;
+
+        self.sync_with_source(actor, &source, record_id, record.data())
+            .await?
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "record '{record_id}' has no email, phone, or name values to match"
+                ))
+            })
+    }
+
+    /// Re-resolves a record after it was written, without permission checks.
+    ///
+    /// Returns `None` when the entity is not a contact identity source or the
+    /// record carries no identity values.
+    pub async fn sync_record_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        data: &Value,
+    ) -> AppResult<Option<ContactIdentityLink>> {
+        let Some(source) = self
+            .repository
+            .find_source(actor.tenant_id(), entity_logical_name)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        self.sync_with_source(actor, &source, record_id, data).await
+    }
+
+    /// Returns every record linked to a master contact.
+    pub async fn get_master_contact(
+        &self,
+        actor: &UserIdentity,
+        master_contact_id: &str,
+    ) -> AppResult<MasterContact> {
+        self.require_read_permission(actor).await?;
+        self.load_master(actor, master_contact_id).await
+    }
+
+    /// Returns the master contact a record belongs to.
+    pub async fn master_for_record(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<MasterContact> {
+        self.require_read_permission(actor).await?;
+        let link = self
+            .repository
+            .find_link(actor.tenant_id(), entity_logical_name, record_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "record '{record_id}' of entity '{entity_logical_name}' is not linked to a master contact"
+                ))
+            })?;
+
+        self.load_master(actor, link.master_contact_id.as_str())
+            .await
+    }
+
+    /// Links a record to an existing master contact, overriding automatic matching.
+    pub async fn link_record_to_master(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        master_contact_id: &str,
+    ) -> AppResult<ContactIdentityLink> {
+        self.require_link_permission(actor).await?;
+        let source = self.require_source(actor, entity_logical_name).await?;
+        let record = self
+            .runtime_record_service
+            .get_runtime_record_unchecked(actor, entity_logical_name, record_id)
+            .await?;
+        let master = self.load_master(actor, master_contact_id).await?;
+
+        let previous = self
+            .repository
+            .find_link(actor.tenant_id(), entity_logical_name, record_id)
+            .await?;
+        let link = self
+            .repository
+            .save_link(
+                actor.tenant_id(),
+                actor.subject(),
+                NewContactIdentityLink {
+                    master_contact_id: master.master_contact_id,
+                    entity_logical_name: entity_logical_name.to_owned(),
+                    record_id: record_id.to_owned(),
+                    keys: extract_keys(&source, record.data()),
+                    match_reason: ContactMatchReason::Manual,
+                    confidence: MANUAL_CONFIDENCE,
+                },
+            )
+            .await?;
+
+        self.append_link_event(actor, AuditAction::ContactIdentityLinked, &link, previous)
+            .await?;
+
+        Ok(link)
+    }
+
+    /// Moves a record to a new master contact of its own, overriding automatic matching.
+    pub async fn split_record(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<ContactIdentityLink> {
+        self.require_link_permission(actor).await?;
+        let previous = self
+            .repository
+            .find_link(actor.tenant_id(), entity_logical_name, record_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "record '{record_id}' of entity '{entity_logical_name}' is not linked to a master contact"
+                ))
+            })?;
+
+        let link = self
+            .repository
+            .save_link(
+                actor.tenant_id(),
+                actor.subject(),
+                NewContactIdentityLink {
+                    master_contact_id: Uuid::new_v4().to_string(),
+                    entity_logical_name: entity_logical_name.to_owned(),
+                    record_id: record_id.to_owned(),
+                    keys: previous.keys.clone(),
+                    match_reason: ContactMatchReason::Manual,
+                    confidence: MANUAL_CONFIDENCE,
+                },
+            )
+            .await?;
+
+        self.append_link_event(
+            actor,
+            AuditAction::ContactIdentityUnlinked,
+            &link,
+            Some(previous),
+        )
+        .await?;
+
+        Ok(link)
+    }
+
+    /// Finds linked records matching free-form contact details, strongest first.
+    pub async fn find_matches(
+        &self,
+        actor: &UserIdentity,
+        email: Option<&str>,
+        phone: Option<&str>,
+        name: Option<&str>,
+    ) -> AppResult<Vec<ContactIdentityMatch>> {
+        self.require_read_permission(actor).await?;
+
+        let keys = ContactIdentityKeys {
+            email: email.and_then(normalize_email),
+            phone: phone.and_then(normalize_phone),
+            name: name.and_then(normalize_person_name),
+        };
+        if keys.is_empty() {
+            return Err(AppError::Validation(
+                "provide a valid email, phone, or name to match".to_owned(),
+            ));
+        }
+
+        let mut matches = self
+            .repository
+            .list_candidate_links(actor.tenant_id(), &keys, MAX_CANDIDATE_LINKS)
+            .await?
+            .into_iter()
+            .filter_map(|link| {
+                match_keys(&keys, &link.keys).map(|key_match| ContactIdentityMatch {
+                    link,
+                    match_reason: key_match.match_reason,
+                    confidence: key_match.confidence,
+                })
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by(|left, right| right.confidence.cmp(&left.confidence));
+        matches.truncate(MAX_LOOKUP_MATCHES);
+
+        Ok(matches)
+    }
+
+    async fn sync_with_source(
+        &self,
+        actor: &UserIdentity,
+        source: &ContactIdentitySource,
+        record_id: &str,
+        data: &Value,
+    ) -> AppResult<Option<ContactIdentityLink>> {
+        let tenant_id = actor.tenant_id();
+        let entity_logical_name = source.entity_logical_name.as_str();
+        let keys = extract_keys(source, data);
+        let existing = self
+            .repository
+            .find_link(tenant_id, entity_logical_name, record_id)
+            .await?;
+
+        if let Some(existing) = existing
+            .as_ref()
+            .filter(|link| link.match_reason == ContactMatchReason::Manual)
+        {
+            let link = self
+                .repository
+                .save_link(
+                    tenant_id,
+                    actor.subject(),
+                    NewContactIdentityLink {
+                        master_contact_id: existing.master_contact_id.clone(),
+                        entity_logical_name: entity_logical_name.to_owned(),
+                        record_id: record_id.to_owned(),
+                        keys,
+                        match_reason: ContactMatchReason::Manual,
+                        confidence: existing.confidence,
+                    },
+                )
+                .await?;
+            return Ok(Some(link));
+        }
+
+        if keys.is_empty() {
+            if existing.is_some() {
+                self.repository
+                    .delete_link(tenant_id, entity_logical_name, record_id)
+                    .await?;
+            }
+            return Ok(None);
+        }
+
+        let best_match = self
+            .repository
+            .list_candidate_links(tenant_id, &keys, MAX_CANDIDATE_LINKS)
+            .await?
+            .into_iter()
+            .filter(|candidate| {
+                candidate.entity_logical_name != entity_logical_name
+                    || candidate.record_id != record_id
+            })
+            .filter_map(|candidate| {
+                match_keys(&keys, &candidate.keys).map(|key_match| (candidate, key_match))
+            })
+            .reduce(|best, current| {
+                if current.1.confidence > best.1.confidence {
+                    current
+                } else {
+                    best
+                }
+            });
+
+        let (master_contact_id, match_reason, confidence) = match best_match {
+            Some((candidate, key_match)) => (
+                candidate.master_contact_id,
+                key_match.match_reason,
+                key_match.confidence,
+            ),
+            None => (
+                self.unshared_master_id(actor, existing.as_ref()).await?,
+                ContactMatchReason::Unmatched,
+                0,
+            ),
+        };
+
+        self.repository
+            .save_link(
+                tenant_id,
+                actor.subject(),
+                NewContactIdentityLink {
+                    master_contact_id,
+                    entity_logical_name: entity_logical_name.to_owned(),
+                    record_id: record_id.to_owned(),
+                    keys,
+                    match_reason,
+                    confidence,
+                },
+            )
+            .await
+            .map(Some)
+    }
+
+    /// Keeps the record's current master when no other record shares it.
+    async fn unshared_master_id(
+        &self,
+        actor: &UserIdentity,
+        existing: Option<&ContactIdentityLink>,
+    ) -> AppResult<String> {
+        if let Some(existing) = existing {
+            let members = self
+                .repository
+                .list_master_links(actor.tenant_id(), existing.master_contact_id.as_str())
+                .await?;
+            if members.iter().all(|member| {
+                member.entity_logical_name == existing.entity_logical_name
+                    && member.record_id == existing.record_id
+            }) {
+                return Ok(existing.master_contact_id.clone());
+            }
+        }
+
+        Ok(Uuid::new_v4().to_string())
+    }
+
+    async fn load_master(
+        &self,
+        actor: &UserIdentity,
+        master_contact_id: &str,
+    ) -> AppResult<MasterContact> {
+        let links = self
+            .repository
+            .list_master_links(actor.tenant_id(), master_contact_id)
+            .await?;
+        if links.is_empty() {
+            return Err(AppError::NotFound(format!(
+                "master contact '{master_contact_id}' does not exist"
+            )));
+        }
+
+        Ok(MasterContact {
+            master_contact_id: master_contact_id.to_owned(),
+            links,
+        })
+    }
+
+    async fn require_source(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<ContactIdentitySource> {
+        self.repository
+            .find_source(actor.tenant_id(), entity_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "entity '{entity_logical_name}' is not a contact identity source"
+                ))
+            })
+    }
+
+    async fn published_schema(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<PublishedEntitySchema> {
+        self.runtime_record_service
+            .latest_published_schema_unchecked(actor, entity_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "entity '{entity_logical_name}' has no published schema"
+                ))
+            })
+    }
+
+    async fn append_link_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        link: &ContactIdentityLink,
+        previous: Option<ContactIdentityLink>,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "contact_identity_link".to_owned(),
+                resource_id: format!("{}:{}", link.entity_logical_name, link.record_id),
+                detail: Some(
+                    serde_json::json!({
+                        "master_contact_id": link.master_contact_id,
+                        "previous_master_contact_id": previous.map(|previous| previous.master_contact_id),
+                    })
+                    .to_string(),
+                ),
+            })
+            .await
+    }
+
+    async fn require_configure_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await
+    }
+
+    async fn require_link_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::RuntimeRecordWrite,
+            )
+            .await
+    }
+
+    async fn require_read_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::RuntimeRecordRead,
+            )
+            .await
+    }
+}
+
+fn validate_source_input(
+    schema: &PublishedEntitySchema,
+    input: SaveContactIdentitySourceInput,
+) -> AppResult<SaveContactIdentitySourceInput> {
+    let normalize = |value: Option<String>| {
+        value
+            .map(|value| value.trim().to_owned())
+            .filter(|value| !value.is_empty())
+    };
+    let email_field_logical_name = normalize(input.email_field_logical_name);
+    let phone_field_logical_name = normalize(input.phone_field_logical_name);
+    let name_field_logical_names = input
+        .name_field_logical_names
+        .into_iter()
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+        .collect::<Vec<_>>();
+
+    if email_field_logical_name.is_none()
+        && phone_field_logical_name.is_none()
+        && name_field_logical_names.is_empty()
+    {
+        return Err(AppError::Validation(
+            "a contact identity source needs an email, phone, or name field".to_owned(),
+        ));
+    }
+    if name_field_logical_names.len() > MAX_NAME_FIELDS {
+        return Err(AppError::Validation(format!(
+            "a contact identity source can combine at most {MAX_NAME_FIELDS} name fields"
+        )));
+    }
+
+    let mut seen = HashSet::new();
+    for field_logical_name in email_field_logical_name
+        .iter()
+        .chain(phone_field_logical_name.iter())
+        .chain(name_field_logical_names.iter())
+    {
+        if !seen.insert(field_logical_name.as_str()) {
+            return Err(AppError::Validation(format!(
+                "field '{field_logical_name}' is mapped more than once"
+            )));
+        }
+
+        let field = schema
+            .fields()
+            .iter()
+            .find(|field| field.logical_name().as_str() == field_logical_name)
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "field '{field_logical_name}' does not exist on entity '{}'",
+                    schema.entity().logical_name().as_str()
+                ))
+            })?;
+        if field.field_type() != FieldType::Text {
+            return Err(AppError::Validation(format!(
+                "field '{field_logical_name}' must be a text field to be used for contact matching"
+            )));
+        }
+    }
+
+    Ok(SaveContactIdentitySourceInput {
+        entity_logical_name: schema.entity().logical_name().as_str().to_owned(),
+        email_field_logical_name,
+        phone_field_logical_name,
+        name_field_logical_names,
+    })
+}
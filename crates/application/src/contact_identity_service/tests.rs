@@ -0,0 +1,764 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::{Value, json};
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{
+    AuditAction, EntityDefinition, EntityFieldDefinition, FieldType, FormDefinition, Permission,
+    PublishedEntitySchema, RuntimeRecord, ViewDefinition,
+};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RecordListQuery,
+    RuntimeFieldGrant, RuntimeRecordQuery, RuntimeRecordService, TemporaryPermissionGrant,
+};
+
+use super::matching::{jaro_winkler, normalize_email, normalize_person_name, normalize_phone};
+use super::{
+    ContactIdentityKeys, ContactIdentityLink, ContactIdentityRepository, ContactIdentityService,
+    ContactIdentitySource, ContactMatchReason, NewContactIdentityLink,
+    SaveContactIdentitySourceInput,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+struct FakeRuntimeRecordService {
+    records: HashMap<(String, String), Value>,
+}
+
+#[async_trait]
+impl RuntimeRecordService for FakeRuntimeRecordService {
+    async fn latest_published_schema_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        Ok(Some(person_schema(entity_logical_name)))
+    }
+
+    async fn list_runtime_records_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+        query: RecordListQuery,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        let mut records = self
+            .records
+            .iter()
+            .filter(|((entity, _), _)| entity == entity_logical_name)
+            .map(|((entity, record_id), data)| {
+                RuntimeRecord::new(record_id.as_str(), entity.as_str(), data.clone())
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+        records.sort_by(|left, right| left.record_id().as_str().cmp(right.record_id().as_str()));
+        Ok(records
+            .into_iter()
+            .skip(query.offset)
+            .take(query.limit)
+            .collect())
+    }
+
+    async fn query_runtime_records_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+        _query: RuntimeRecordQuery,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        Ok(Vec::new())
+    }
+
+    async fn get_runtime_record_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<RuntimeRecord> {
+        let data = self
+            .records
+            .get(&(entity_logical_name.to_owned(), record_id.to_owned()))
+            .cloned()
+            .ok_or_else(|| {
+                AppError::NotFound(format!("runtime record '{record_id}' does not exist"))
+            })?;
+        RuntimeRecord::new(record_id, entity_logical_name, data)
+    }
+
+    async fn create_runtime_record_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+        data: Value,
+    ) -> AppResult<RuntimeRecord> {
+        RuntimeRecord::new("record-1", entity_logical_name, data)
+    }
+
+    async fn update_runtime_record_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        data: Value,
+        _expected_version: Option<i64>,
+    ) -> AppResult<RuntimeRecord> {
+        RuntimeRecord::new(record_id, entity_logical_name, data)
+    }
+
+    async fn patch_runtime_record_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        patch: &Value,
+    ) -> AppResult<RuntimeRecord> {
+        RuntimeRecord::new(record_id, entity_logical_name, patch.clone())
+    }
+
+    async fn delete_runtime_record_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+        _record_id: &str,
+    ) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn list_forms_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<FormDefinition>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_form_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+        _form_logical_name: &str,
+    ) -> AppResult<Option<FormDefinition>> {
+        Ok(None)
+    }
+
+    async fn list_views_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<ViewDefinition>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_view_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+        _view_logical_name: &str,
+    ) -> AppResult<Option<ViewDefinition>> {
+        Ok(None)
+    }
+}
+
+#[derive(Default)]
+struct FakeContactIdentityRepository {
+    sources: Mutex<Vec<ContactIdentitySource>>,
+    links: Mutex<Vec<ContactIdentityLink>>,
+}
+
+#[async_trait]
+impl ContactIdentityRepository for FakeContactIdentityRepository {
+    async fn save_source(
+        &self,
+        _tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveContactIdentitySourceInput,
+    ) -> AppResult<ContactIdentitySource> {
+        let source = ContactIdentitySource {
+            entity_logical_name: input.entity_logical_name,
+            email_field_logical_name: input.email_field_logical_name,
+            phone_field_logical_name: input.phone_field_logical_name,
+            name_field_logical_names: input.name_field_logical_names,
+            updated_by_subject: updated_by_subject.to_owned(),
+            updated_at: Utc::now(),
+        };
+        let mut sources = self.sources.lock().await;
+        sources.retain(|existing| existing.entity_logical_name != source.entity_logical_name);
+        sources.push(source.clone());
+        Ok(source)
+    }
+
+    async fn list_sources(&self, _tenant_id: TenantId) -> AppResult<Vec<ContactIdentitySource>> {
+        Ok(self.sources.lock().await.clone())
+    }
+
+    async fn find_source(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<ContactIdentitySource>> {
+        Ok(self
+            .sources
+            .lock()
+            .await
+            .iter()
+            .find(|source| source.entity_logical_name == entity_logical_name)
+            .cloned())
+    }
+
+    async fn delete_source(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        let mut sources = self.sources.lock().await;
+        let before = sources.len();
+        sources.retain(|source| source.entity_logical_name != entity_logical_name);
+        self.links
+            .lock()
+            .await
+            .retain(|link| link.entity_logical_name != entity_logical_name);
+        Ok(sources.len() != before)
+    }
+
+    async fn find_link(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<ContactIdentityLink>> {
+        Ok(self
+            .links
+            .lock()
+            .await
+            .iter()
+            .find(|link| {
+                link.entity_logical_name == entity_logical_name && link.record_id == record_id
+            })
+            .cloned())
+    }
+
+    async fn list_candidate_links(
+        &self,
+        _tenant_id: TenantId,
+        keys: &ContactIdentityKeys,
+        limit: usize,
+    ) -> AppResult<Vec<ContactIdentityLink>> {
+        let shares =
+            |left: &Option<String>, right: &Option<String>| left.is_some() && left == right;
+        Ok(self
+            .links
+            .lock()
+            .await
+            .iter()
+            .filter(|link| {
+                shares(&keys.email, &link.keys.email)
+                    || shares(&keys.phone, &link.keys.phone)
+                    || shares(&keys.name_block(), &link.keys.name_block())
+            })
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_link(
+        &self,
+        _tenant_id: TenantId,
+        linked_by_subject: &str,
+        link: NewContactIdentityLink,
+    ) -> AppResult<ContactIdentityLink> {
+        let stored = ContactIdentityLink {
+            master_contact_id: link.master_contact_id,
+            entity_logical_name: link.entity_logical_name,
+            record_id: link.record_id,
+            keys: link.keys,
+            match_reason: link.match_reason,
+            confidence: link.confidence,
+            linked_by_subject: linked_by_subject.to_owned(),
+            linked_at: Utc::now(),
+        };
+        let mut links = self.links.lock().await;
+        if let Some(existing) = links.iter_mut().find(|existing| {
+            existing.entity_logical_name == stored.entity_logical_name
+                && existing.record_id == stored.record_id
+        }) {
+            *existing = stored.clone();
+        } else {
+            links.push(stored.clone());
+        }
+        Ok(stored)
+    }
+
+    async fn delete_link(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<bool> {
+        let mut links = self.links.lock().await;
+        let before = links.len();
+        links.retain(|link| {
+            link.entity_logical_name != entity_logical_name || link.record_id != record_id
+        });
+        Ok(links.len() != before)
+    }
+
+    async fn list_master_links(
+        &self,
+        _tenant_id: TenantId,
+        master_contact_id: &str,
+    ) -> AppResult<Vec<ContactIdentityLink>> {
+        Ok(self
+            .links
+            .lock()
+            .await
+            .iter()
+            .filter(|link| link.master_contact_id == master_contact_id)
+            .cloned()
+            .collect())
+    }
+}
+
+fn person_schema(entity_logical_name: &str) -> PublishedEntitySchema {
+    let entity =
+        EntityDefinition::new(entity_logical_name, "Person").unwrap_or_else(|_| unreachable!());
+    let field = |logical_name: &str, field_type: FieldType| {
+        EntityFieldDefinition::new(
+            entity_logical_name,
+            logical_name,
+            logical_name,
+            field_type,
+            false,
+            false,
+            None,
+            None,
+        )
+        .unwrap_or_else(|_| unreachable!())
+    };
+    let fields = vec![
+        field("email", FieldType::Text),
+        field("phone", FieldType::Text),
+        field("first_name", FieldType::Text),
+        field("last_name", FieldType::Text),
+        field("score", FieldType::Number),
+    ];
+    PublishedEntitySchema::new(entity, 1, fields, Vec::new()).unwrap_or_else(|_| unreachable!())
+}
+
+struct Harness {
+    service: ContactIdentityService,
+    repository: Arc<FakeContactIdentityRepository>,
+    audit_repository: Arc<FakeAuditRepository>,
+    actor: UserIdentity,
+}
+
+fn build_harness(permissions: Vec<Permission>, records: Vec<(&str, &str, Value)>) -> Harness {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("alice", "Alice", None, tenant_id);
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let repository = Arc::new(FakeContactIdentityRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([((tenant_id, "alice".to_owned()), permissions)]),
+        }),
+        audit_repository.clone(),
+    );
+    let runtime_record_service = Arc::new(FakeRuntimeRecordService {
+        records: records
+            .into_iter()
+            .map(|(entity, record_id, data)| ((entity.to_owned(), record_id.to_owned()), data))
+            .collect(),
+    });
+
+    Harness {
+        service: ContactIdentityService::new(
+            authorization_service,
+            runtime_record_service,
+            repository.clone(),
+            audit_repository.clone(),
+        ),
+        repository,
+        audit_repository,
+        actor,
+    }
+}
+
+fn source_input(entity_logical_name: &str) -> SaveContactIdentitySourceInput {
+    SaveContactIdentitySourceInput {
+        entity_logical_name: entity_logical_name.to_owned(),
+        email_field_logical_name: Some("email".to_owned()),
+        phone_field_logical_name: Some("phone".to_owned()),
+        name_field_logical_names: vec!["first_name".to_owned(), "last_name".to_owned()],
+    }
+}
+
+#[test]
+fn identity_keys_are_normalized() {
+    assert_eq!(
+        normalize_email("  Jane.Doe@Example.COM "),
+        Some("jane.doe@example.com".to_owned())
+    );
+    assert_eq!(normalize_email("jane@localhost"), None);
+    assert_eq!(normalize_email("jane@@example.com"), None);
+
+    assert_eq!(
+        normalize_phone("+49 30 1234567"),
+        Some("+49301234567".to_owned())
+    );
+    assert_eq!(
+        normalize_phone("0049 (30) 123-4567"),
+        Some("+49301234567".to_owned())
+    );
+    assert_eq!(normalize_phone("12-34"), None);
+
+    assert_eq!(
+        normalize_person_name("Doe, Jane"),
+        Some("doe jane".to_owned())
+    );
+    assert_eq!(normalize_person_name(" - "), None);
+
+    assert!(jaro_winkler("doe jane", "doe jayne") > 0.92);
+    assert!(jaro_winkler("doe jane", "smith john") < 0.6);
+    assert!((jaro_winkler("martha", "marhta") - 0.961).abs() < 0.001);
+}
+
+#[tokio::test]
+async fn records_across_entities_resolve_to_one_master_contact() {
+    let harness = build_harness(
+        vec![
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+        ],
+        Vec::new(),
+    );
+    for entity in ["contact", "lead"] {
+        harness
+            .service
+            .save_source(&harness.actor, source_input(entity))
+            .await
+            .unwrap_or_else(|_| unreachable!());
+    }
+
+    let contact = harness
+        .service
+        .sync_record_unchecked(
+            &harness.actor,
+            "contact",
+            "contact-1",
+            &json!({"email": "Jane@Example.com", "first_name": "Jane", "last_name": "Doe"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(contact.match_reason, ContactMatchReason::Unmatched);
+
+    let lead_by_email = harness
+        .service
+        .sync_record_unchecked(
+            &harness.actor,
+            "lead",
+            "lead-1",
+            &json!({"email": " jane@example.COM", "phone": "+1 555 010 0000"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(lead_by_email.match_reason, ContactMatchReason::Email);
+    assert_eq!(lead_by_email.master_contact_id, contact.master_contact_id);
+
+    let lead_by_phone = harness
+        .service
+        .sync_record_unchecked(
+            &harness.actor,
+            "lead",
+            "lead-2",
+            &json!({"phone": "001 (555) 010-0000"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(lead_by_phone.match_reason, ContactMatchReason::Phone);
+    assert_eq!(lead_by_phone.master_contact_id, contact.master_contact_id);
+
+    let namesake = harness
+        .service
+        .sync_record_unchecked(
+            &harness.actor,
+            "lead",
+            "lead-4",
+            &json!({"email": "other@example.org", "first_name": "Jane", "last_name": "Doe"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(namesake.match_reason, ContactMatchReason::Unmatched);
+    assert_ne!(namesake.master_contact_id, contact.master_contact_id);
+
+    let lead_by_name = harness
+        .service
+        .sync_record_unchecked(
+            &harness.actor,
+            "lead",
+            "lead-3",
+            &json!({"first_name": "Jayne", "last_name": "Doe"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(lead_by_name.match_reason, ContactMatchReason::FuzzyName);
+    assert_eq!(lead_by_name.master_contact_id, contact.master_contact_id);
+
+    let not_a_source = harness
+        .service
+        .sync_record_unchecked(
+            &harness.actor,
+            "account",
+            "account-1",
+            &json!({"email": "jane@example.com"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(not_a_source.is_none());
+
+    let master = harness
+        .service
+        .master_for_record(&harness.actor, "lead", "lead-2")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(master.links.len(), 4);
+
+    let matches = harness
+        .service
+        .find_matches(&harness.actor, None, None, Some("jane doe"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(matches.len(), 3);
+    assert!(
+        matches
+            .iter()
+            .all(|found| found.match_reason == ContactMatchReason::FuzzyName)
+    );
+
+    let master = harness
+        .service
+        .get_master_contact(&harness.actor, contact.master_contact_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(master.links.len(), 4);
+}
+
+#[tokio::test]
+async fn manual_split_and_link_survive_automatic_matching() {
+    let jane = json!({"email": "jane@example.com", "first_name": "Jane", "last_name": "Doe"});
+    let harness = build_harness(
+        vec![
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+            Permission::RuntimeRecordWrite,
+        ],
+        vec![
+            ("contact", "contact-1", jane.clone()),
+            ("contact", "contact-2", jane.clone()),
+            ("contact", "contact-3", json!({"phone": "555 123 4567"})),
+        ],
+    );
+    harness
+        .service
+        .save_source(&harness.actor, source_input("contact"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let summary = harness
+        .service
+        .rebuild_source_links(&harness.actor, "contact")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(summary.scanned_records, 3);
+    assert_eq!(summary.matched_records, 1);
+
+    let split = harness
+        .service
+        .split_record(&harness.actor, "contact", "contact-2")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(split.match_reason, ContactMatchReason::Manual);
+
+    let resynced = harness
+        .service
+        .sync_record_unchecked(&harness.actor, "contact", "contact-2", &jane)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(resynced.master_contact_id, split.master_contact_id);
+
+    let original = harness
+        .repository
+        .find_link(harness.actor.tenant_id(), "contact", "contact-1")
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    let linked = harness
+        .service
+        .link_record_to_master(
+            &harness.actor,
+            "contact",
+            "contact-3",
+            original.master_contact_id.as_str(),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(linked.master_contact_id, original.master_contact_id);
+    assert_eq!(linked.keys.phone.as_deref(), Some("5551234567"));
+
+    let missing_master = harness
+        .service
+        .link_record_to_master(&harness.actor, "contact", "contact-3", "missing")
+        .await;
+    assert!(matches!(missing_master, Err(AppError::NotFound(_))));
+
+    let actions = harness
+        .audit_repository
+        .events
+        .lock()
+        .await
+        .iter()
+        .map(|event| event.action)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actions,
+        vec![
+            AuditAction::ContactIdentitySourceSaved,
+            AuditAction::ContactIdentityUnlinked,
+            AuditAction::ContactIdentityLinked,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn source_configuration_is_validated_and_permission_gated() {
+    let harness = build_harness(vec![Permission::MetadataFieldWrite], Vec::new());
+
+    let non_text = harness
+        .service
+        .save_source(
+            &harness.actor,
+            SaveContactIdentitySourceInput {
+                phone_field_logical_name: Some("score".to_owned()),
+                ..source_input("contact")
+            },
+        )
+        .await;
+    assert!(matches!(non_text, Err(AppError::Validation(_))));
+
+    let duplicate = harness
+        .service
+        .save_source(
+            &harness.actor,
+            SaveContactIdentitySourceInput {
+                phone_field_logical_name: Some("email".to_owned()),
+                ..source_input("contact")
+            },
+        )
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Validation(_))));
+
+    let empty = harness
+        .service
+        .save_source(
+            &harness.actor,
+            SaveContactIdentitySourceInput {
+                entity_logical_name: "contact".to_owned(),
+                email_field_logical_name: Some(" ".to_owned()),
+                phone_field_logical_name: None,
+                name_field_logical_names: Vec::new(),
+            },
+        )
+        .await;
+    assert!(matches!(empty, Err(AppError::Validation(_))));
+
+    let reader = build_harness(vec![Permission::RuntimeRecordRead], Vec::new());
+    let forbidden = reader
+        .service
+        .save_source(&reader.actor, source_input("contact"))
+        .await;
+    assert!(matches!(forbidden, Err(AppError::Forbidden(_))));
+    let forbidden_split = reader
+        .service
+        .split_record(&reader.actor, "contact", "contact-1")
+        .await;
+    assert!(matches!(forbidden_split, Err(AppError::Forbidden(_))));
+}
@@ -0,0 +1,20 @@
+//! Per-subject erasure of personal data (right to be forgotten).
+//!
+//! Erasing a subject replaces every reference to it in the tenant with one
+//! generated pseudonym, so memberships, role assignments, record ownership and
+//! audit entries still point at a single consistent subject afterwards. The
+//! subject's contact record is anonymized in place, and fields configured per
+//! entity are removed from the records the subject owns together with their
+//! change history. Data under an active legal hold is kept.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    DataErasureField, DataErasureReport, DataErasureRepository, SaveDataErasureFieldsInput,
+    SubjectErasure, SubjectErasureOutcome,
+};
+pub use service::{DataErasureService, ERASED_SUBJECT_DISPLAY_NAME};
@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppResult, TenantId};
+
+/// Personal data field removed from a subject's owned records on erasure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataErasureField {
+    /// Entity the field belongs to.
+    pub entity_logical_name: String,
+    /// Field removed from owned records.
+    pub field_logical_name: String,
+    /// Subject that last saved the entity's field set.
+    pub updated_by_subject: String,
+    /// Time the entity's field set was last saved.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input for replacing the erasure field set of one entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveDataErasureFieldsInput {
+    /// Entity whose field set is replaced.
+    pub entity_logical_name: String,
+    /// Fields removed from owned records; empty clears the set.
+    pub field_logical_names: Vec<String>,
+}
+
+/// Tenant-scoped erasure of one subject.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubjectErasure {
+    /// Subject being erased.
+    pub subject: String,
+    /// Pseudonym replacing the subject everywhere in the tenant.
+    pub pseudonym: String,
+    /// Display name written to the membership and contact record.
+    pub display_name: String,
+    /// Fields removed from owned records, keyed by entity.
+    pub fields_by_entity: BTreeMap<String, Vec<String>>,
+    /// Whether audit entries of the subject are pseudonymized.
+    pub pseudonymize_audit_log: bool,
+}
+
+/// Rows changed by a tenant-scoped subject erasure.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubjectErasureOutcome {
+    /// Anonymized contact record of the subject, if one was mapped.
+    pub contact_record_id: Option<String>,
+    /// Records whose owner or creator references were pseudonymized.
+    pub records_pseudonymized: u64,
+    /// Owned records that had configured personal fields removed.
+    pub records_erased: u64,
+    /// Owned records skipped because a legal hold covers them.
+    pub records_held: u64,
+    /// Record history entries that had erased values removed.
+    pub history_entries_scrubbed: u64,
+    /// Audit entries whose actor was pseudonymized.
+    pub audit_entries_pseudonymized: u64,
+    /// Audit entries of the subject left unchanged.
+    pub audit_entries_retained: u64,
+}
+
+/// Report of a completed subject erasure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataErasureReport {
+    /// Pseudonym that replaced the subject.
+    pub pseudonym: String,
+    /// Tenant-scoped changes.
+    pub outcome: SubjectErasureOutcome,
+    /// Auth events anonymized.
+    pub auth_events_anonymized: u64,
+    /// Whether auth events were kept because the subject belongs to another tenant.
+    pub auth_events_retained: bool,
+    /// Time the erasure completed.
+    pub erased_at: DateTime<Utc>,
+}
+
+/// Repository port for personal data erasure.
+#[async_trait]
+pub trait DataErasureRepository: Send + Sync {
+    /// Lists erasure fields, optionally for one entity.
+    async fn list_erasure_fields(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<DataErasureField>>;
+
+    /// Replaces the erasure field set of one entity.
+    async fn save_erasure_fields(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveDataErasureFieldsInput,
+    ) -> AppResult<Vec<DataErasureField>>;
+
+    /// Applies a subject erasure in one tenant transaction.
+    ///
+    /// Returns `None` without changing anything when the subject is not a
+    /// member of the tenant.
+    async fn erase_subject(
+        &self,
+        tenant_id: TenantId,
+        erasure: &SubjectErasure,
+    ) -> AppResult<Option<SubjectErasureOutcome>>;
+
+    /// Returns whether the subject is still a member of any tenant.
+    async fn subject_has_memberships(&self, subject: &str) -> AppResult<bool>;
+
+    /// Replaces the subject of its auth events and clears caller details.
+    async fn anonymize_auth_events(&self, subject: &str, pseudonym: &str) -> AppResult<u64>;
+}
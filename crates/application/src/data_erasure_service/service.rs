@@ -0,0 +1,253 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::json;
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission, SystemField};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::{
+    DataErasureField, DataErasureReport, DataErasureRepository, SaveDataErasureFieldsInput,
+    SubjectErasure,
+};
+
+/// Display name written over an erased subject's membership and contact record.
+pub const ERASED_SUBJECT_DISPLAY_NAME: &str = "Erased user";
+
+/// Application service for erasing a subject's personal data.
+#[derive(Clone)]
+pub struct DataErasureService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn DataErasureRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+    audit_immutable_mode: bool,
+}
+
+impl DataErasureService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn DataErasureRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            audit_repository,
+            audit_immutable_mode: false,
+        }
+    }
+
+    /// Enables or disables immutable audit mode for this service instance.
+    ///
+    /// Audit entries keep their original actor while the mode is enabled.
+    #[must_use]
+    pub fn with_audit_immutable_mode(mut self, enabled: bool) -> Self {
+        self.audit_immutable_mode = enabled;
+        self
+    }
+
+    /// Lists erasure fields, optionally for one entity.
+    pub async fn list_erasure_fields(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<DataErasureField>> {
+        self.require_role_manage_permission(actor).await?;
+        self.repository
+            .list_erasure_fields(actor.tenant_id(), entity_logical_name)
+            .await
+    }
+
+    /// Replaces the erasure field set of one entity.
+    pub async fn save_erasure_fields(
+        &self,
+        actor: &UserIdentity,
+        input: SaveDataErasureFieldsInput,
+    ) -> AppResult<Vec<DataErasureField>> {
+        self.require_role_manage_permission(actor).await?;
+
+        let input = normalize_save_input(input)?;
+        let entity_logical_name = input.entity_logical_name.clone();
+        let fields = self
+            .repository
+            .save_erasure_fields(actor.tenant_id(), actor.subject(), input)
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityDataErasureFieldsSaved,
+                resource_type: "data_erasure_fields".to_owned(),
+                resource_id: entity_logical_name,
+                detail: Some(
+                    json!({
+                        "fields": fields
+                            .iter()
+                            .map(|field| field.field_logical_name.as_str())
+                            .collect::<Vec<_>>(),
+                    })
+                    .to_string(),
+                ),
+            })
+            .await?;
+
+        Ok(fields)
+    }
+
+    /// Erases the personal data of a tenant member.
+    ///
+    /// Auth events are not tenant-scoped, so they are only anonymized once the
+    /// subject belongs to no other tenant. The audit entry recording the
+    /// erasure names the pseudonym, never the erased subject.
+    pub async fn erase_subject(
+        &self,
+        actor: &UserIdentity,
+        subject: &str,
+    ) -> AppResult<DataErasureReport> {
+        self.require_role_manage_permission(actor).await?;
+
+        let subject = subject.trim();
+        if subject.is_empty() {
+            return Err(AppError::Validation(
+                "data erasure subject is required".to_owned(),
+            ));
+        }
+        if subject == actor.subject() {
+            return Err(AppError::Validation(
+                "subjects cannot erase their own data".to_owned(),
+            ));
+        }
+
+        let mut fields_by_entity = BTreeMap::<String, Vec<String>>::new();
+        for field in self
+            .repository
+            .list_erasure_fields(actor.tenant_id(), None)
+            .await?
+        {
+            fields_by_entity
+                .entry(field.entity_logical_name)
+                .or_default()
+                .push(field.field_logical_name);
+        }
+
+        let pseudonym = format!("erased-{}", uuid::Uuid::new_v4());
+        let outcome = self
+            .repository
+            .erase_subject(
+                actor.tenant_id(),
+                &SubjectErasure {
+                    subject: subject.to_owned(),
+                    pseudonym: pseudonym.clone(),
+                    display_name: ERASED_SUBJECT_DISPLAY_NAME.to_owned(),
+                    fields_by_entity,
+                    pseudonymize_audit_log: !self.audit_immutable_mode,
+                },
+            )
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "subject '{subject}' is not a member of tenant '{}'",
+                    actor.tenant_id()
+                ))
+            })?;
+
+        let auth_events_retained = self.repository.subject_has_memberships(subject).await?;
+        let auth_events_anonymized = if auth_events_retained {
+            0
+        } else {
+            self.repository
+                .anonymize_auth_events(subject, pseudonym.as_str())
+                .await?
+        };
+
+        let report = DataErasureReport {
+            pseudonym,
+            outcome,
+            auth_events_anonymized,
+            auth_events_retained,
+            erased_at: Utc::now(),
+        };
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityDataErased,
+                resource_type: "data_erasure".to_owned(),
+                resource_id: report.pseudonym.clone(),
+                detail: Some(
+                    json!({
+                        "contact_record_id": report.outcome.contact_record_id,
+                        "records_pseudonymized": report.outcome.records_pseudonymized,
+                        "records_erased": report.outcome.records_erased,
+                        "records_held": report.outcome.records_held,
+                        "history_entries_scrubbed": report.outcome.history_entries_scrubbed,
+                        "audit_entries_pseudonymized": report.outcome.audit_entries_pseudonymized,
+                        "audit_entries_retained": report.outcome.audit_entries_retained,
+                        "auth_events_anonymized": report.auth_events_anonymized,
+                        "auth_events_retained": report.auth_events_retained,
+                    })
+                    .to_string(),
+                ),
+            })
+            .await?;
+
+        Ok(report)
+    }
+
+    async fn require_role_manage_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::SecurityRoleManage,
+            )
+            .await
+    }
+}
+
+fn normalize_save_input(
+    input: SaveDataErasureFieldsInput,
+) -> AppResult<SaveDataErasureFieldsInput> {
+    let entity_logical_name = input.entity_logical_name.trim().to_owned();
+    if entity_logical_name.is_empty() {
+        return Err(AppError::Validation(
+            "data erasure entity_logical_name is required".to_owned(),
+        ));
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut field_logical_names = Vec::with_capacity(input.field_logical_names.len());
+    for field_logical_name in input.field_logical_names {
+        let field_logical_name = field_logical_name.trim().to_owned();
+        if field_logical_name.is_empty() {
+            return Err(AppError::Validation(
+                "data erasure field_logical_name must not be empty".to_owned(),
+            ));
+        }
+        // System fields hold subjects, which erasure pseudonymizes on every record.
+        if SystemField::parse(field_logical_name.as_str()).is_some() {
+            return Err(AppError::Validation(format!(
+                "system field '{field_logical_name}' cannot be erased"
+            )));
+        }
+        if !seen.insert(field_logical_name.clone()) {
+            return Err(AppError::Validation(format!(
+                "data erasure field '{field_logical_name}' is listed more than once"
+            )));
+        }
+
+        field_logical_names.push(field_logical_name);
+    }
+
+    Ok(SaveDataErasureFieldsInput {
+        entity_logical_name,
+        field_logical_names,
+    })
+}
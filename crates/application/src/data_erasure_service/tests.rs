@@ -0,0 +1,366 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    TemporaryPermissionGrant,
+};
+
+use super::{
+    DataErasureField, DataErasureRepository, DataErasureService, ERASED_SUBJECT_DISPLAY_NAME,
+    SaveDataErasureFieldsInput, SubjectErasure, SubjectErasureOutcome,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeDataErasureRepository {
+    fields: Mutex<Vec<DataErasureField>>,
+    members: Vec<String>,
+    members_elsewhere: Vec<String>,
+    erasures: Mutex<Vec<SubjectErasure>>,
+    anonymized_auth_subjects: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl DataErasureRepository for FakeDataErasureRepository {
+    async fn list_erasure_fields(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<DataErasureField>> {
+        Ok(self
+            .fields
+            .lock()
+            .await
+            .iter()
+            .filter(|field| {
+                entity_logical_name.is_none_or(|entity| field.entity_logical_name == entity)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn save_erasure_fields(
+        &self,
+        _tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveDataErasureFieldsInput,
+    ) -> AppResult<Vec<DataErasureField>> {
+        let mut fields = self.fields.lock().await;
+        fields.retain(|field| field.entity_logical_name != input.entity_logical_name);
+        let saved: Vec<DataErasureField> = input
+            .field_logical_names
+            .into_iter()
+            .map(|field_logical_name| DataErasureField {
+                entity_logical_name: input.entity_logical_name.clone(),
+                field_logical_name,
+                updated_by_subject: updated_by_subject.to_owned(),
+                updated_at: Utc::now(),
+            })
+            .collect();
+        fields.extend(saved.iter().cloned());
+        Ok(saved)
+    }
+
+    async fn erase_subject(
+        &self,
+        _tenant_id: TenantId,
+        erasure: &SubjectErasure,
+    ) -> AppResult<Option<SubjectErasureOutcome>> {
+        if !self.members.contains(&erasure.subject) {
+            return Ok(None);
+        }
+
+        self.erasures.lock().await.push(erasure.clone());
+        Ok(Some(SubjectErasureOutcome {
+            contact_record_id: Some("contact-1".to_owned()),
+            records_pseudonymized: 3,
+            records_erased: 2,
+            audit_entries_pseudonymized: 5,
+            ..SubjectErasureOutcome::default()
+        }))
+    }
+
+    async fn subject_has_memberships(&self, subject: &str) -> AppResult<bool> {
+        Ok(self
+            .members_elsewhere
+            .iter()
+            .any(|member| member == subject))
+    }
+
+    async fn anonymize_auth_events(&self, subject: &str, _pseudonym: &str) -> AppResult<u64> {
+        self.anonymized_auth_subjects
+            .lock()
+            .await
+            .push(subject.to_owned());
+        Ok(4)
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    permissions: Vec<Permission>,
+    repository: FakeDataErasureRepository,
+) -> (
+    DataErasureService,
+    Arc<FakeDataErasureRepository>,
+    Arc<FakeAuditRepository>,
+) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let repository = Arc::new(repository);
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([((tenant_id, "alice".to_owned()), permissions)]),
+        }),
+        audit_repository.clone(),
+    );
+    let service = DataErasureService::new(
+        authorization_service,
+        repository.clone(),
+        audit_repository.clone(),
+    );
+    (service, repository, audit_repository)
+}
+
+fn save_input(fields: Vec<&str>) -> SaveDataErasureFieldsInput {
+    SaveDataErasureFieldsInput {
+        entity_logical_name: " account ".to_owned(),
+        field_logical_names: fields.into_iter().map(str::to_owned).collect(),
+    }
+}
+
+#[tokio::test]
+async fn erasure_requires_role_manage_permission_and_another_subject() {
+    let tenant_id = TenantId::new();
+    let (service, repository, _) = build_service(
+        tenant_id,
+        vec![Permission::SecurityAuditRead],
+        FakeDataErasureRepository {
+            members: vec!["alice".to_owned(), "bob".to_owned()],
+            ..FakeDataErasureRepository::default()
+        },
+    );
+    let forbidden = service
+        .erase_subject(&actor(tenant_id, "alice"), "bob")
+        .await;
+    assert!(matches!(forbidden, Err(AppError::Forbidden(_))));
+
+    let (service, _, _) = build_service(
+        tenant_id,
+        vec![Permission::SecurityRoleManage],
+        FakeDataErasureRepository::default(),
+    );
+    let own_data = service
+        .erase_subject(&actor(tenant_id, "alice"), " alice ")
+        .await;
+    assert!(matches!(own_data, Err(AppError::Validation(_))));
+    let not_member = service
+        .erase_subject(&actor(tenant_id, "alice"), "mallory")
+        .await;
+    assert!(matches!(not_member, Err(AppError::NotFound(_))));
+    assert!(repository.erasures.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn erasure_applies_configured_fields_and_reports_under_a_pseudonym() {
+    let tenant_id = TenantId::new();
+    let (service, repository, audit_repository) = build_service(
+        tenant_id,
+        vec![Permission::SecurityRoleManage],
+        FakeDataErasureRepository {
+            members: vec!["bob".to_owned(), "carol".to_owned()],
+            members_elsewhere: vec!["carol".to_owned()],
+            ..FakeDataErasureRepository::default()
+        },
+    );
+    let actor = actor(tenant_id, "alice");
+    service
+        .save_erasure_fields(&actor, save_input(vec!["phone", " personal_email "]))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let report = service
+        .erase_subject(&actor, "bob")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(report.pseudonym.starts_with("erased-"));
+    assert_eq!(report.outcome.records_erased, 2);
+    assert_eq!(report.auth_events_anonymized, 4);
+    assert!(!report.auth_events_retained);
+
+    let erasures = repository.erasures.lock().await;
+    assert_eq!(erasures[0].pseudonym, report.pseudonym);
+    assert_eq!(erasures[0].display_name, ERASED_SUBJECT_DISPLAY_NAME);
+    assert_eq!(
+        erasures[0].fields_by_entity.get("account"),
+        Some(&vec!["phone".to_owned(), "personal_email".to_owned()])
+    );
+    assert!(erasures[0].pseudonymize_audit_log);
+    drop(erasures);
+
+    // A subject that is still a member elsewhere keeps its shared auth events.
+    let report = service
+        .erase_subject(&actor, "carol")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(report.auth_events_retained);
+    assert_eq!(report.auth_events_anonymized, 0);
+    assert_eq!(
+        repository.anonymized_auth_subjects.lock().await.as_slice(),
+        ["bob".to_owned()]
+    );
+
+    let events = audit_repository.events.lock().await;
+    let erased = events
+        .iter()
+        .filter(|event| event.action == AuditAction::SecurityDataErased)
+        .collect::<Vec<_>>();
+    assert_eq!(erased.len(), 2);
+    assert!(erased.iter().all(|event| {
+        event.resource_id.starts_with("erased-")
+            && event
+                .detail
+                .as_deref()
+                .is_some_and(|detail| !detail.contains("bob") && !detail.contains("carol"))
+    }));
+}
+
+#[tokio::test]
+async fn immutable_audit_mode_keeps_audit_actors() {
+    let tenant_id = TenantId::new();
+    let (service, repository, _) = build_service(
+        tenant_id,
+        vec![Permission::SecurityRoleManage],
+        FakeDataErasureRepository {
+            members: vec!["bob".to_owned()],
+            ..FakeDataErasureRepository::default()
+        },
+    );
+    let service = service.with_audit_immutable_mode(true);
+
+    service
+        .erase_subject(&actor(tenant_id, "alice"), "bob")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(!repository.erasures.lock().await[0].pseudonymize_audit_log);
+}
+
+#[tokio::test]
+async fn erasure_fields_reject_system_and_duplicate_fields() {
+    let tenant_id = TenantId::new();
+    let (service, _, audit_repository) = build_service(
+        tenant_id,
+        vec![Permission::SecurityRoleManage],
+        FakeDataErasureRepository::default(),
+    );
+    let actor = actor(tenant_id, "alice");
+
+    let system_field = service
+        .save_erasure_fields(&actor, save_input(vec!["owner"]))
+        .await;
+    assert!(matches!(system_field, Err(AppError::Validation(_))));
+    let duplicate = service
+        .save_erasure_fields(&actor, save_input(vec!["phone", "phone "]))
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Validation(_))));
+
+    let saved = service
+        .save_erasure_fields(&actor, save_input(vec!["phone"]))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(saved[0].entity_logical_name, "account");
+    let listed = service
+        .list_erasure_fields(&actor, Some("account"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(listed, saved);
+    assert_eq!(
+        audit_repository.events.lock().await[0].action,
+        AuditAction::SecurityDataErasureFieldsSaved
+    );
+}
@@ -0,0 +1,20 @@
+//! On-demand and scheduled data validation audits.
+//!
+//! An audit re-checks stored runtime records against the current published
+//! schema and active entity business rules, so admins can find data that
+//! drifted after schema evolution. Each audit runs as a background job and
+//! stores its violations by page, so a resumed run replaces what an
+//! interrupted run already stored. A tenant may also schedule audits to run
+//! every few days on behalf of the admin who configured the schedule.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    DataValidationRepository, DataValidationSchedule, DataValidationViolation,
+    DueDataValidationSchedule,
+};
+pub use service::{DATA_VALIDATION_MAX_INTERVAL_DAYS, DataValidationService};
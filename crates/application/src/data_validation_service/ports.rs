@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppResult, TenantId};
+
+use crate::RuntimeRecordViolation;
+
+/// Violation stored for one audited entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataValidationViolation {
+    /// Entity of the failing record.
+    pub entity_logical_name: String,
+    /// Failed check.
+    pub violation: RuntimeRecordViolation,
+}
+
+/// Recurring data validation audit of a tenant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataValidationSchedule {
+    /// Days between two audits.
+    pub interval_days: u16,
+    /// Subject that configured the schedule; audits run with its permissions.
+    pub configured_by_subject: String,
+    /// Display name of the configuring subject.
+    pub configured_by_display_name: String,
+    /// Email of the configuring subject.
+    pub configured_by_email: Option<String>,
+    /// Next scheduled audit.
+    pub next_run_at: DateTime<Utc>,
+    /// Last audit queued by the schedule, when any.
+    pub last_run_at: Option<DateTime<Utc>>,
+    /// Last configuration change.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Schedule claimed for a run by one scheduler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueDataValidationSchedule {
+    /// Tenant owning the schedule.
+    pub tenant_id: TenantId,
+    /// Slot the audit was scheduled for.
+    pub scheduled_for: DateTime<Utc>,
+    /// Claimed schedule.
+    pub schedule: DataValidationSchedule,
+}
+
+/// Repository port for data validation audit results and schedules.
+#[async_trait]
+pub trait DataValidationRepository: Send + Sync {
+    /// Stores the violations of one record page, replacing any stored for
+    /// the same job, entity and offset.
+    async fn save_page_violations(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        entity_logical_name: &str,
+        page_offset: u64,
+        violations: &[RuntimeRecordViolation],
+    ) -> AppResult<()>;
+
+    /// Lists violations of a job ordered by entity, record page and position.
+    async fn list_violations(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<DataValidationViolation>>;
+
+    /// Finds the tenant schedule.
+    async fn find_schedule(&self, tenant_id: TenantId)
+    -> AppResult<Option<DataValidationSchedule>>;
+
+    /// Creates or replaces the tenant schedule.
+    async fn save_schedule(
+        &self,
+        tenant_id: TenantId,
+        schedule: &DataValidationSchedule,
+    ) -> AppResult<DataValidationSchedule>;
+
+    /// Deletes the tenant schedule and returns whether one existed.
+    async fn delete_schedule(&self, tenant_id: TenantId) -> AppResult<bool>;
+
+    /// Claims schedules due at `now` across tenants.
+    ///
+    /// Claimed schedules are pushed to `retry_at` so another scheduler does
+    /// not queue them again; a failed run is retried then.
+    async fn claim_due_schedules(
+        &self,
+        now: DateTime<Utc>,
+        retry_at: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<DueDataValidationSchedule>>;
+
+    /// Records a scheduled run and schedules the next one.
+    async fn record_scheduled_run(
+        &self,
+        tenant_id: TenantId,
+        run_at: DateTime<Utc>,
+        next_run_at: DateTime<Utc>,
+    ) -> AppResult<()>;
+}
@@ -0,0 +1,301 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationService, BackgroundJob, BackgroundJobKind,
+    BackgroundJobLease, BackgroundJobService, BackgroundJobStage, CreateBackgroundJobInput,
+    RuntimeRecordViolation,
+};
+
+use super::{
+    DataValidationRepository, DataValidationSchedule, DataValidationViolation,
+    DueDataValidationSchedule,
+};
+
+/// Longest interval between two scheduled audits.
+pub const DATA_VALIDATION_MAX_INTERVAL_DAYS: u16 = 90;
+
+/// Minutes a claimed schedule waits before a failed run is retried.
+const DATA_VALIDATION_SCHEDULE_RETRY_MINUTES: i64 = 30;
+
+/// Upper bound for violation list requests.
+const MAX_DATA_VALIDATION_VIOLATION_LIST_LIMIT: usize = 500;
+
+/// Application service for data validation audits.
+#[derive(Clone)]
+pub struct DataValidationService {
+    authorization_service: AuthorizationService,
+    background_job_service: BackgroundJobService,
+    repository: Arc<dyn DataValidationRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl DataValidationService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        background_job_service: BackgroundJobService,
+        repository: Arc<dyn DataValidationRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            background_job_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Queues an audit of the listed entities on behalf of the actor.
+    ///
+    /// Only one unfinished audit visible to the actor may exist at a time.
+    pub async fn request_audit(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_names: Vec<String>,
+    ) -> AppResult<BackgroundJob> {
+        if entity_logical_names.is_empty() {
+            return Err(AppError::Validation(
+                "data validation audits need at least one published entity".to_owned(),
+            ));
+        }
+
+        let running_audit = self
+            .background_job_service
+            .list_jobs(actor, 200)
+            .await?
+            .into_iter()
+            .any(|job| {
+                job.kind == BackgroundJobKind::DataValidationAudit && !job.status.is_terminal()
+            });
+        if running_audit {
+            return Err(AppError::Conflict(
+                "a data validation audit is already in progress".to_owned(),
+            ));
+        }
+
+        // One stage per entity, so the job reports how many records of each
+        // entity were checked.
+        let stages = entity_logical_names
+            .iter()
+            .map(|name| BackgroundJobStage::new(name.as_str(), None))
+            .collect();
+
+        self.background_job_service
+            .enqueue_job(
+                actor,
+                CreateBackgroundJobInput {
+                    kind: BackgroundJobKind::DataValidationAudit,
+                    parameters: json!({ "entity_logical_names": entity_logical_names }),
+                    stages,
+                },
+            )
+            .await
+    }
+
+    /// Stores violations found by the run holding the lease.
+    pub async fn save_page_violations(
+        &self,
+        lease: &BackgroundJobLease,
+        entity_logical_name: &str,
+        page_offset: usize,
+        violations: &[RuntimeRecordViolation],
+    ) -> AppResult<()> {
+        self.repository
+            .save_page_violations(
+                lease.tenant_id,
+                lease.job.job_id.as_str(),
+                entity_logical_name,
+                page_offset as u64,
+                violations,
+            )
+            .await
+    }
+
+    /// Lists the violations reported by an audit.
+    ///
+    /// Violations of a running audit are listed as far as it got.
+    pub async fn list_violations(
+        &self,
+        actor: &UserIdentity,
+        job_id: &str,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<DataValidationViolation>> {
+        self.require_audit_permission(actor).await?;
+        if limit == 0 || limit > MAX_DATA_VALIDATION_VIOLATION_LIST_LIMIT {
+            return Err(AppError::Validation(format!(
+                "violation list limit must be between 1 and {MAX_DATA_VALIDATION_VIOLATION_LIST_LIMIT}"
+            )));
+        }
+
+        let job = self.background_job_service.get_job(actor, job_id).await?;
+        if job.kind != BackgroundJobKind::DataValidationAudit {
+            return Err(AppError::NotFound(format!(
+                "data validation audit '{job_id}' does not exist"
+            )));
+        }
+
+        self.repository
+            .list_violations(actor.tenant_id(), job_id, limit, offset)
+            .await
+    }
+
+    /// Returns the tenant schedule, if one is configured.
+    pub async fn find_schedule(
+        &self,
+        actor: &UserIdentity,
+    ) -> AppResult<Option<DataValidationSchedule>> {
+        self.require_audit_permission(actor).await?;
+        self.repository.find_schedule(actor.tenant_id()).await
+    }
+
+    /// Schedules audits every `interval_days`, run with the actor's permissions.
+    ///
+    /// The first scheduled audit runs one interval from now.
+    pub async fn save_schedule(
+        &self,
+        actor: &UserIdentity,
+        interval_days: u16,
+    ) -> AppResult<DataValidationSchedule> {
+        self.require_audit_permission(actor).await?;
+        if interval_days == 0 || interval_days > DATA_VALIDATION_MAX_INTERVAL_DAYS {
+            return Err(AppError::Validation(format!(
+                "interval_days must be between 1 and {DATA_VALIDATION_MAX_INTERVAL_DAYS}"
+            )));
+        }
+
+        let last_run_at = self
+            .repository
+            .find_schedule(actor.tenant_id())
+            .await?
+            .and_then(|schedule| schedule.last_run_at);
+        let now = Utc::now();
+        let schedule = self
+            .repository
+            .save_schedule(
+                actor.tenant_id(),
+                &DataValidationSchedule {
+                    interval_days,
+                    configured_by_subject: actor.subject().to_owned(),
+                    configured_by_display_name: actor.display_name().to_owned(),
+                    configured_by_email: actor.email().map(str::to_owned),
+                    next_run_at: now + Duration::days(i64::from(interval_days)),
+                    last_run_at,
+                    updated_at: now,
+                },
+            )
+            .await?;
+
+        self.append_schedule_audit_event(
+            actor,
+            AuditAction::DataValidationScheduleSaved,
+            Some(format!("every {interval_days} days")),
+        )
+        .await?;
+
+        Ok(schedule)
+    }
+
+    /// Removes the tenant schedule.
+    pub async fn delete_schedule(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.require_audit_permission(actor).await?;
+        if !self.repository.delete_schedule(actor.tenant_id()).await? {
+            return Err(AppError::NotFound(
+                "no data validation schedule is configured".to_owned(),
+            ));
+        }
+
+        self.append_schedule_audit_event(actor, AuditAction::DataValidationScheduleDeleted, None)
+            .await
+    }
+
+    /// Claims schedules due for a run across tenants.
+    pub async fn claim_due_schedules(
+        &self,
+        limit: usize,
+    ) -> AppResult<Vec<DueDataValidationSchedule>> {
+        let now = Utc::now();
+        self.repository
+            .claim_due_schedules(
+                now,
+                now + Duration::minutes(DATA_VALIDATION_SCHEDULE_RETRY_MINUTES),
+                limit,
+            )
+            .await
+    }
+
+    /// Rebuilds the identity a claimed schedule queues its audit with.
+    #[must_use]
+    pub fn schedule_owner(due: &DueDataValidationSchedule) -> UserIdentity {
+        let schedule = &due.schedule;
+        UserIdentity::new(
+            schedule.configured_by_subject.as_str(),
+            schedule.configured_by_display_name.as_str(),
+            schedule.configured_by_email.clone(),
+            due.tenant_id,
+        )
+    }
+
+    /// Records that a claimed schedule ran and schedules the next run.
+    pub async fn record_scheduled_run(&self, due: &DueDataValidationSchedule) -> AppResult<()> {
+        let run_at = Utc::now();
+        self.repository
+            .record_scheduled_run(
+                due.tenant_id,
+                run_at,
+                next_run_after(due.scheduled_for, due.schedule.interval_days, run_at),
+            )
+            .await
+    }
+
+    async fn require_audit_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await
+    }
+
+    async fn append_schedule_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        detail: Option<String>,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "data_validation_schedule".to_owned(),
+                resource_id: actor.tenant_id().to_string(),
+                detail,
+            })
+            .await
+    }
+}
+
+/// Advances a run slot by whole intervals until it lies after `now`.
+///
+/// Slots missed while no scheduler ran are skipped rather than replayed.
+pub(super) fn next_run_after(
+    scheduled_for: DateTime<Utc>,
+    interval_days: u16,
+    now: DateTime<Utc>,
+) -> DateTime<Utc> {
+    let interval = Duration::days(i64::from(interval_days.max(1)));
+    let mut next = scheduled_for + interval;
+    while next <= now {
+        next += interval;
+    }
+    next
+}
@@ -0,0 +1,600 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{Value, json};
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, BackgroundJob,
+    BackgroundJobKind, BackgroundJobLease, BackgroundJobRepository, BackgroundJobService,
+    BackgroundJobStage, BackgroundJobStatus, CreateBackgroundJobInput, RuntimeFieldGrant,
+    RuntimeRecordValidationRule, RuntimeRecordViolation, TemporaryPermissionGrant,
+};
+
+use super::service::next_run_after;
+use super::{
+    DataValidationRepository, DataValidationSchedule, DataValidationService,
+    DataValidationViolation, DueDataValidationSchedule,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeBackgroundJobRepository {
+    jobs: Mutex<Vec<(TenantId, BackgroundJob)>>,
+}
+
+#[async_trait]
+impl BackgroundJobRepository for FakeBackgroundJobRepository {
+    async fn create_background_job(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        requested_by_display_name: &str,
+        requested_by_email: Option<&str>,
+        input: CreateBackgroundJobInput,
+    ) -> AppResult<BackgroundJob> {
+        let now = Utc::now();
+        let job = BackgroundJob {
+            job_id: uuid::Uuid::new_v4().to_string(),
+            kind: input.kind,
+            status: BackgroundJobStatus::Queued,
+            requested_by_subject: requested_by_subject.to_owned(),
+            requested_by_display_name: requested_by_display_name.to_owned(),
+            requested_by_email: requested_by_email.map(str::to_owned),
+            parameters: input.parameters,
+            stages: input.stages,
+            checkpoint: None,
+            cancel_requested: false,
+            attempt_count: 0,
+            status_message: None,
+            created_at: now,
+            started_at: None,
+            updated_at: now,
+            finished_at: None,
+        };
+        self.jobs
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .push((tenant_id, job.clone()));
+        Ok(job)
+    }
+
+    async fn find_background_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Option<BackgroundJob>> {
+        Ok(self
+            .jobs
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .find(|(job_tenant_id, job)| *job_tenant_id == tenant_id && job.job_id == job_id)
+            .map(|(_, job)| job.clone()))
+    }
+
+    async fn list_background_jobs(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: Option<&str>,
+        limit: usize,
+    ) -> AppResult<Vec<BackgroundJob>> {
+        Ok(self
+            .jobs
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .rev()
+            .filter(|(job_tenant_id, job)| {
+                *job_tenant_id == tenant_id
+                    && requested_by_subject
+                        .is_none_or(|subject| job.requested_by_subject == subject)
+            })
+            .take(limit)
+            .map(|(_, job)| job.clone())
+            .collect())
+    }
+
+    async fn request_background_job_cancellation(
+        &self,
+        _tenant_id: TenantId,
+        _job_id: &str,
+    ) -> AppResult<Option<BackgroundJob>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn claim_background_job(
+        &self,
+        lease_token: &str,
+        lease_expires_at: DateTime<Utc>,
+    ) -> AppResult<Option<BackgroundJobLease>> {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|_| unreachable!());
+        let Some((tenant_id, job)) = jobs
+            .iter_mut()
+            .find(|(_, job)| job.status == BackgroundJobStatus::Queued)
+        else {
+            return Ok(None);
+        };
+
+        job.status = BackgroundJobStatus::Running;
+        job.attempt_count += 1;
+        Ok(Some(BackgroundJobLease {
+            tenant_id: *tenant_id,
+            job: job.clone(),
+            lease_token: lease_token.to_owned(),
+            expires_at: lease_expires_at,
+        }))
+    }
+
+    async fn save_background_job_progress(
+        &self,
+        _tenant_id: TenantId,
+        _job_id: &str,
+        _lease_token: &str,
+        _stages: &[BackgroundJobStage],
+        _checkpoint: Option<&Value>,
+        _lease_expires_at: DateTime<Utc>,
+    ) -> AppResult<Option<BackgroundJob>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn finish_background_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        _lease_token: &str,
+        status: BackgroundJobStatus,
+        status_message: Option<&str>,
+    ) -> AppResult<Option<BackgroundJob>> {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|_| unreachable!());
+        let Some((_, job)) = jobs
+            .iter_mut()
+            .find(|(job_tenant_id, job)| *job_tenant_id == tenant_id && job.job_id == job_id)
+        else {
+            return Ok(None);
+        };
+
+        job.status = status;
+        job.status_message = status_message.map(str::to_owned);
+        job.finished_at = Some(Utc::now());
+        Ok(Some(job.clone()))
+    }
+}
+
+/// Tenant, job, entity and page offset of a stored violation.
+type StoredViolation = (TenantId, String, String, u64, RuntimeRecordViolation);
+
+#[derive(Default)]
+struct FakeDataValidationRepository {
+    violations: Mutex<Vec<StoredViolation>>,
+    schedules: Mutex<HashMap<TenantId, DataValidationSchedule>>,
+}
+
+#[async_trait]
+impl DataValidationRepository for FakeDataValidationRepository {
+    async fn save_page_violations(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        entity_logical_name: &str,
+        page_offset: u64,
+        violations: &[RuntimeRecordViolation],
+    ) -> AppResult<()> {
+        let mut stored = self.violations.lock().unwrap_or_else(|_| unreachable!());
+        stored.retain(|(_, stored_job_id, stored_entity, stored_offset, _)| {
+            !(stored_job_id == job_id
+                && stored_entity == entity_logical_name
+                && *stored_offset == page_offset)
+        });
+        stored.extend(violations.iter().map(|violation| {
+            (
+                tenant_id,
+                job_id.to_owned(),
+                entity_logical_name.to_owned(),
+                page_offset,
+                violation.clone(),
+            )
+        }));
+        Ok(())
+    }
+
+    async fn list_violations(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<DataValidationViolation>> {
+        Ok(self
+            .violations
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .filter(|(stored_tenant_id, stored_job_id, _, _, _)| {
+                *stored_tenant_id == tenant_id && stored_job_id == job_id
+            })
+            .skip(offset)
+            .take(limit)
+            .map(
+                |(_, _, entity_logical_name, _, violation)| DataValidationViolation {
+                    entity_logical_name: entity_logical_name.clone(),
+                    violation: violation.clone(),
+                },
+            )
+            .collect())
+    }
+
+    async fn find_schedule(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<Option<DataValidationSchedule>> {
+        Ok(self
+            .schedules
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .get(&tenant_id)
+            .cloned())
+    }
+
+    async fn save_schedule(
+        &self,
+        tenant_id: TenantId,
+        schedule: &DataValidationSchedule,
+    ) -> AppResult<DataValidationSchedule> {
+        self.schedules
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .insert(tenant_id, schedule.clone());
+        Ok(schedule.clone())
+    }
+
+    async fn delete_schedule(&self, tenant_id: TenantId) -> AppResult<bool> {
+        Ok(self
+            .schedules
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .remove(&tenant_id)
+            .is_some())
+    }
+
+    async fn claim_due_schedules(
+        &self,
+        now: DateTime<Utc>,
+        retry_at: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<DueDataValidationSchedule>> {
+        let mut schedules = self.schedules.lock().unwrap_or_else(|_| unreachable!());
+        Ok(schedules
+            .iter_mut()
+            .filter(|(_, schedule)| schedule.next_run_at <= now)
+            .take(limit)
+            .map(|(tenant_id, schedule)| {
+                let scheduled_for = schedule.next_run_at;
+                schedule.next_run_at = retry_at;
+                DueDataValidationSchedule {
+                    tenant_id: *tenant_id,
+                    scheduled_for,
+                    schedule: schedule.clone(),
+                }
+            })
+            .collect())
+    }
+
+    async fn record_scheduled_run(
+        &self,
+        tenant_id: TenantId,
+        run_at: DateTime<Utc>,
+        next_run_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        if let Some(schedule) = self
+            .schedules
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .get_mut(&tenant_id)
+        {
+            schedule.last_run_at = Some(run_at);
+            schedule.next_run_at = next_run_at;
+        }
+        Ok(())
+    }
+}
+
+struct Harness {
+    tenant_id: TenantId,
+    admin: UserIdentity,
+    audit_repository: Arc<FakeAuditRepository>,
+    background_job_service: BackgroundJobService,
+    repository: Arc<FakeDataValidationRepository>,
+    service: DataValidationService,
+}
+
+fn build_harness() -> Harness {
+    let tenant_id = TenantId::new();
+    let admin = UserIdentity::new("admin", "Admin", None, tenant_id);
+    let grants = HashMap::from([(
+        (tenant_id, "admin".to_owned()),
+        vec![Permission::MetadataFieldWrite],
+    )]);
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository { grants }),
+        audit_repository.clone(),
+    );
+    let background_job_service = BackgroundJobService::new(
+        authorization_service.clone(),
+        Arc::new(FakeBackgroundJobRepository::default()),
+        audit_repository.clone(),
+    );
+    let repository = Arc::new(FakeDataValidationRepository::default());
+    let service = DataValidationService::new(
+        authorization_service,
+        background_job_service.clone(),
+        repository.clone(),
+        audit_repository.clone(),
+    );
+
+    Harness {
+        tenant_id,
+        admin,
+        audit_repository,
+        background_job_service,
+        repository,
+        service,
+    }
+}
+
+fn violation(record_id: &str, rule: RuntimeRecordValidationRule) -> RuntimeRecordViolation {
+    RuntimeRecordViolation {
+        record_id: record_id.to_owned(),
+        rule,
+        field_logical_name: Some("name".to_owned()),
+        business_rule_logical_name: None,
+        message: "invalid".to_owned(),
+    }
+}
+
+fn audit_actions(harness: &Harness) -> Vec<AuditAction> {
+    harness
+        .audit_repository
+        .events
+        .lock()
+        .unwrap_or_else(|_| unreachable!())
+        .iter()
+        .map(|event| event.action)
+        .collect()
+}
+
+#[tokio::test]
+async fn audits_report_stored_violations_replacing_resumed_pages() {
+    let harness = build_harness();
+    let empty = harness
+        .service
+        .request_audit(&harness.admin, Vec::new())
+        .await;
+    assert!(matches!(empty, Err(AppError::Validation(_))));
+
+    let job = harness
+        .service
+        .request_audit(
+            &harness.admin,
+            vec!["contact".to_owned(), "deal".to_owned()],
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(job.kind, BackgroundJobKind::DataValidationAudit);
+    assert_eq!(job.stages.len(), 2);
+    assert_eq!(
+        job.parameters["entity_logical_names"],
+        json!(["contact", "deal"])
+    );
+    let duplicate = harness
+        .service
+        .request_audit(&harness.admin, vec!["contact".to_owned()])
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+
+    let lease = harness
+        .background_job_service
+        .claim_next_job()
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    let first_attempt = [
+        violation("record-1", RuntimeRecordValidationRule::InvalidValue),
+        violation("record-2", RuntimeRecordValidationRule::UnknownField),
+    ];
+    let resumed_attempt = [violation(
+        "record-1",
+        RuntimeRecordValidationRule::InvalidValue,
+    )];
+    for violations in [&first_attempt[..], &resumed_attempt[..]] {
+        harness
+            .service
+            .save_page_violations(&lease, "contact", 0, violations)
+            .await
+            .unwrap_or_else(|_| unreachable!());
+    }
+
+    let report = harness
+        .service
+        .list_violations(&harness.admin, &job.job_id, 50, 0)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(report.len(), 1);
+    assert_eq!(report[0].entity_logical_name, "contact");
+    assert_eq!(report[0].violation.record_id, "record-1");
+
+    let outsider = UserIdentity::new("outsider", "Outsider", None, harness.tenant_id);
+    let forbidden = harness
+        .service
+        .list_violations(&outsider, &job.job_id, 50, 0)
+        .await;
+    assert!(matches!(forbidden, Err(AppError::Forbidden(_))));
+    let oversized = harness
+        .service
+        .list_violations(&harness.admin, &job.job_id, 501, 0)
+        .await;
+    assert!(matches!(oversized, Err(AppError::Validation(_))));
+}
+
+#[tokio::test]
+async fn schedules_are_audited_claimed_and_advanced() {
+    let harness = build_harness();
+    let invalid = harness.service.save_schedule(&harness.admin, 0).await;
+    assert!(matches!(invalid, Err(AppError::Validation(_))));
+
+    let saved = harness
+        .service
+        .save_schedule(&harness.admin, 7)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(saved.next_run_at > Utc::now() + Duration::days(6));
+    assert!(
+        harness
+            .service
+            .claim_due_schedules(10)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+
+    let overdue = saved.next_run_at - Duration::days(15);
+    if let Some(schedule) = harness
+        .repository
+        .schedules
+        .lock()
+        .unwrap_or_else(|_| unreachable!())
+        .get_mut(&harness.tenant_id)
+    {
+        schedule.next_run_at = overdue;
+    }
+    let due = harness
+        .service
+        .claim_due_schedules(10)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].scheduled_for, overdue);
+    assert_eq!(
+        DataValidationService::schedule_owner(&due[0]),
+        harness.admin
+    );
+
+    harness
+        .service
+        .record_scheduled_run(&due[0])
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let advanced = harness
+        .service
+        .find_schedule(&harness.admin)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert!(advanced.last_run_at.is_some());
+    assert_eq!((advanced.next_run_at - overdue).num_days() % 7, 0);
+    assert!(advanced.next_run_at > Utc::now());
+
+    harness
+        .service
+        .delete_schedule(&harness.admin)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let missing = harness.service.delete_schedule(&harness.admin).await;
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+    assert_eq!(
+        audit_actions(&harness),
+        vec![
+            AuditAction::DataValidationScheduleSaved,
+            AuditAction::DataValidationScheduleDeleted,
+        ]
+    );
+}
+
+#[test]
+fn missed_runs_are_skipped_rather_than_replayed() {
+    let scheduled_for = Utc::now() - Duration::days(10);
+    let next = next_run_after(scheduled_for, 3, Utc::now());
+    assert_eq!(next, scheduled_for + Duration::days(12));
+}
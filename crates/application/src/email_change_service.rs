@@ -0,0 +1,15 @@
+//! Account email changes confirmed from both the current and the new address.
+//!
+//! A change request stores a pending change and mails a single-use link to
+//! each address. The change is applied only after both links are used; the
+//! account email is then replaced, marked verified, and every session of the
+//! user is revoked. Each step records an auth event.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{EmailChangeConfirmation, EmailChangeRepository, PendingEmailChange};
+pub use service::{EmailChangeConfirmationOutcome, EmailChangeService, RequestEmailChangeParams};
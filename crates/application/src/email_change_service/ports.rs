@@ -0,0 +1,106 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppError, AppResult};
+use qryvanta_domain::UserId;
+
+/// Address that confirms one side of a pending email change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailChangeConfirmation {
+    /// The address currently on the account.
+    CurrentAddress,
+    /// The requested replacement address.
+    NewAddress,
+}
+
+impl EmailChangeConfirmation {
+    /// Returns the storage string stored in confirmation token metadata.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::CurrentAddress => "current",
+            Self::NewAddress => "new",
+        }
+    }
+}
+
+impl FromStr for EmailChangeConfirmation {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "current" => Ok(Self::CurrentAddress),
+            "new" => Ok(Self::NewAddress),
+            _ => Err(AppError::Validation(format!(
+                "unknown email change confirmation '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Email change awaiting confirmation from both addresses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingEmailChange {
+    /// User requesting the change.
+    pub user_id: UserId,
+    /// Account email when the change was requested.
+    pub current_email: String,
+    /// Requested replacement email.
+    pub new_email: String,
+    /// When the current address confirmed the change, if it has.
+    pub current_confirmed_at: Option<DateTime<Utc>>,
+    /// When the new address confirmed the change, if it has.
+    pub new_confirmed_at: Option<DateTime<Utc>>,
+    /// Request timestamp.
+    pub requested_at: DateTime<Utc>,
+    /// Time after which the pending change can no longer be confirmed.
+    pub expires_at: DateTime<Utc>,
+}
+
+impl PendingEmailChange {
+    /// Returns whether both addresses have confirmed the change.
+    #[must_use]
+    pub fn is_fully_confirmed(&self) -> bool {
+        self.current_confirmed_at.is_some() && self.new_confirmed_at.is_some()
+    }
+
+    /// Returns whether the change can no longer be confirmed.
+    #[must_use]
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// Repository port for pending email changes.
+#[async_trait]
+pub trait EmailChangeRepository: Send + Sync {
+    /// Stores a pending change, replacing any earlier pending change of the user.
+    async fn save_pending_change(&self, change: &PendingEmailChange) -> AppResult<()>;
+
+    /// Loads the pending change of a user, expired or not.
+    async fn find_pending_change(&self, user_id: UserId) -> AppResult<Option<PendingEmailChange>>;
+
+    /// Records a confirmation for the unexpired pending change to `new_email`.
+    ///
+    /// Returns the updated change, or `None` when no matching unexpired
+    /// change exists.
+    async fn confirm_pending_change(
+        &self,
+        user_id: UserId,
+        new_email: &str,
+        confirmation: EmailChangeConfirmation,
+    ) -> AppResult<Option<PendingEmailChange>>;
+
+    /// Applies a fully confirmed change to `new_email` in one transaction.
+    ///
+    /// Replaces and verifies the account email, revokes all sessions of the
+    /// user, and removes the pending change. Returns `false` when no fully
+    /// confirmed change to `new_email` exists and `AppError::Conflict` when
+    /// the address was taken by another account in the meantime.
+    async fn complete_pending_change(&self, user_id: UserId, new_email: &str) -> AppResult<bool>;
+
+    /// Removes the pending change of a user and returns whether one existed.
+    async fn delete_pending_change(&self, user_id: UserId) -> AppResult<bool>;
+}
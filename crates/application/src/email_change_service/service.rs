@@ -0,0 +1,335 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use qryvanta_core::{AppError, AppResult};
+use qryvanta_domain::{AuthEventOutcome, AuthEventType, AuthTokenType, EmailAddress, UserId};
+
+use crate::{
+    AuthEvent, AuthEventService, AuthTokenRecord, AuthTokenService, PasswordHasher, UserRepository,
+};
+
+use super::{EmailChangeConfirmation, EmailChangeRepository, PendingEmailChange};
+
+const EMAIL_CHANGE_TTL_HOURS: i64 = 24;
+
+/// Parameters for requesting an email change.
+pub struct RequestEmailChangeParams {
+    /// User requesting the change.
+    pub user_id: UserId,
+    /// Requested replacement address.
+    pub new_email: String,
+    /// Current password, required for password accounts without a recent step-up.
+    pub current_password: Option<String>,
+    /// Whether the session completed step-up verification recently.
+    pub recently_stepped_up: bool,
+    /// IP address from the request (for audit logging).
+    pub ip_address: Option<String>,
+    /// User-Agent header from the request (for audit logging).
+    pub user_agent: Option<String>,
+}
+
+/// Result of confirming one side of an email change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailChangeConfirmationOutcome {
+    /// The other address has not confirmed yet.
+    Pending(PendingEmailChange),
+    /// Both addresses confirmed; the email was replaced and sessions revoked.
+    Completed {
+        /// User whose email changed.
+        user_id: UserId,
+        /// Email now on the account.
+        new_email: String,
+    },
+}
+
+/// Application service for dual-confirmed account email changes.
+#[derive(Clone)]
+pub struct EmailChangeService {
+    repository: Arc<dyn EmailChangeRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    auth_token_service: AuthTokenService,
+    auth_event_service: AuthEventService,
+}
+
+impl EmailChangeService {
+    /// Creates a new email change service.
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EmailChangeRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        auth_token_service: AuthTokenService,
+        auth_event_service: AuthEventService,
+    ) -> Self {
+        Self {
+            repository,
+            user_repository,
+            password_hasher,
+            auth_token_service,
+            auth_event_service,
+        }
+    }
+
+    /// Returns the unexpired pending change of a user, if any.
+    pub async fn pending_change(&self, user_id: UserId) -> AppResult<Option<PendingEmailChange>> {
+        Ok(self
+            .repository
+            .find_pending_change(user_id)
+            .await?
+            .filter(|change| !change.is_expired(Utc::now())))
+    }
+
+    /// Starts an email change and mails a confirmation link to both addresses.
+    ///
+    /// Password accounts must supply the current password unless the session
+    /// passed step-up verification recently; passkey-only accounts always need
+    /// the step-up. A new request replaces any earlier pending change.
+    pub async fn request_change(
+        &self,
+        params: RequestEmailChangeParams,
+    ) -> AppResult<PendingEmailChange> {
+        let RequestEmailChangeParams {
+            user_id,
+            new_email,
+            current_password,
+            recently_stepped_up,
+            ip_address,
+            user_agent,
+        } = params;
+
+        let result = self
+            .start_change(user_id, &new_email, current_password, recently_stepped_up)
+            .await;
+        let outcome = match &result {
+            Ok(_) => AuthEventOutcome::Success,
+            Err(AppError::Unauthorized(_)) => AuthEventOutcome::InvalidPassword,
+            Err(_) => AuthEventOutcome::Failed,
+        };
+        self.record_event(
+            Some(user_id),
+            AuthEventType::EmailChangeRequested,
+            outcome,
+            ip_address,
+            user_agent,
+        )
+        .await?;
+
+        result
+    }
+
+    /// Confirms one side of a pending change with a mailed token.
+    ///
+    /// Once both addresses have confirmed, the change is applied and every
+    /// session of the user is revoked.
+    pub async fn confirm_change(
+        &self,
+        raw_token: &str,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+    ) -> AppResult<EmailChangeConfirmationOutcome> {
+        let mut user_id = None;
+        let confirmed = async {
+            let token = self
+                .auth_token_service
+                .consume_valid_token(raw_token, AuthTokenType::EmailChange)
+                .await?;
+            user_id = token.user_id;
+            self.confirm_token(&token).await
+        }
+        .await;
+        self.record_event(
+            user_id,
+            AuthEventType::EmailChangeConfirmed,
+            if confirmed.is_ok() {
+                AuthEventOutcome::Success
+            } else {
+                AuthEventOutcome::Failed
+            },
+            ip_address.clone(),
+            user_agent.clone(),
+        )
+        .await?;
+        let change = confirmed?;
+
+        if !change.is_fully_confirmed() {
+            return Ok(EmailChangeConfirmationOutcome::Pending(change));
+        }
+
+        let completed = self
+            .repository
+            .complete_pending_change(change.user_id, &change.new_email)
+            .await
+            .and_then(|completed| {
+                if completed {
+                    Ok(())
+                } else {
+                    Err(AppError::Conflict(
+                        "email change is no longer pending".to_owned(),
+                    ))
+                }
+            });
+        self.record_event(
+            Some(change.user_id),
+            AuthEventType::EmailChangeCompleted,
+            if completed.is_ok() {
+                AuthEventOutcome::Success
+            } else {
+                AuthEventOutcome::Failed
+            },
+            ip_address,
+            user_agent,
+        )
+        .await?;
+        completed?;
+
+        Ok(EmailChangeConfirmationOutcome::Completed {
+            user_id: change.user_id,
+            new_email: change.new_email,
+        })
+    }
+
+    /// Cancels the pending change of a user and invalidates its links.
+    pub async fn cancel_change(
+        &self,
+        user_id: UserId,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+    ) -> AppResult<()> {
+        if !self.repository.delete_pending_change(user_id).await? {
+            return Err(AppError::NotFound("no email change is pending".to_owned()));
+        }
+        self.auth_token_service
+            .token_repository()
+            .invalidate_tokens_for_user(user_id, AuthTokenType::EmailChange)
+            .await?;
+
+        self.record_event(
+            Some(user_id),
+            AuthEventType::EmailChangeCancelled,
+            AuthEventOutcome::Success,
+            ip_address,
+            user_agent,
+        )
+        .await
+    }
+
+    async fn start_change(
+        &self,
+        user_id: UserId,
+        new_email: &str,
+        current_password: Option<String>,
+        recently_stepped_up: bool,
+    ) -> AppResult<PendingEmailChange> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("user not found".to_owned()))?;
+
+        match (user.password_hash.as_deref(), current_password) {
+            (Some(stored_hash), Some(current_password)) => {
+                if !self
+                    .password_hasher
+                    .verify_password(&current_password, stored_hash)?
+                {
+                    return Err(AppError::Unauthorized(
+                        "current password is incorrect".to_owned(),
+                    ));
+                }
+            }
+            _ if recently_stepped_up => {}
+            (Some(_), None) => {
+                return Err(AppError::Unauthorized(
+                    "current password is required to change the email address".to_owned(),
+                ));
+            }
+            (None, _) => {
+                return Err(AppError::Unauthorized(
+                    "step-up verification is required to change the email address".to_owned(),
+                ));
+            }
+        }
+
+        let new_email = EmailAddress::new(new_email)?;
+        if new_email.as_str() == user.email.to_lowercase() {
+            return Err(AppError::Validation(
+                "new email address matches the current address".to_owned(),
+            ));
+        }
+        if self
+            .user_repository
+            .find_by_email(new_email.as_str())
+            .await?
+            .is_some()
+        {
+            return Err(AppError::Conflict(
+                "email address is already in use".to_owned(),
+            ));
+        }
+
+        let requested_at = Utc::now();
+        let change = PendingEmailChange {
+            user_id,
+            current_email: user.email,
+            new_email: new_email.into(),
+            current_confirmed_at: None,
+            new_confirmed_at: None,
+            requested_at,
+            expires_at: requested_at + chrono::Duration::hours(EMAIL_CHANGE_TTL_HOURS),
+        };
+        self.repository.save_pending_change(&change).await?;
+        self.auth_token_service
+            .send_email_change_confirmations(
+                user_id,
+                &change.current_email,
+                &change.new_email,
+                change.expires_at,
+            )
+            .await?;
+
+        Ok(change)
+    }
+
+    async fn confirm_token(&self, token: &AuthTokenRecord) -> AppResult<PendingEmailChange> {
+        let user_id = token
+            .user_id
+            .ok_or_else(|| AppError::Internal("email change token has no user_id".to_owned()))?;
+        let metadata_value = |key: &str| {
+            token
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.get(key))
+                .and_then(serde_json::Value::as_str)
+                .ok_or_else(|| AppError::Internal(format!("email change token is missing '{key}'")))
+        };
+        let confirmation = EmailChangeConfirmation::from_str(metadata_value("confirmation")?)?;
+        let new_email = metadata_value("new_email")?;
+
+        self.repository
+            .confirm_pending_change(user_id, new_email, confirmation)
+            .await?
+            .ok_or_else(|| AppError::Conflict("email change is no longer pending".to_owned()))
+    }
+
+    async fn record_event(
+        &self,
+        user_id: Option<UserId>,
+        event_type: AuthEventType,
+        outcome: AuthEventOutcome,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+    ) -> AppResult<()> {
+        self.auth_event_service
+            .record_event(AuthEvent {
+                subject: user_id.map(|user_id| user_id.to_string()),
+                event_type,
+                outcome,
+                ip_address,
+                user_agent,
+            })
+            .await
+    }
+}
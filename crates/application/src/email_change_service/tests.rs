@@ -0,0 +1,672 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+use qryvanta_domain::{AuthEventOutcome, AuthEventType, AuthTokenType, UserId};
+
+use crate::{
+    AuthEvent, AuthEventRepository, AuthEventService, AuthTokenRecord, AuthTokenRepository,
+    AuthTokenService, EmailAttachment, EmailService, PasswordHasher, UserRecord, UserRepository,
+};
+
+use super::{
+    EmailChangeConfirmation, EmailChangeConfirmationOutcome, EmailChangeRepository,
+    EmailChangeService, PendingEmailChange, RequestEmailChangeParams,
+};
+
+#[derive(Default)]
+struct FakeUserRepository {
+    users: Mutex<Vec<UserRecord>>,
+}
+
+#[async_trait]
+impl UserRepository for FakeUserRepository {
+    async fn find_by_email(&self, email: &str) -> AppResult<Option<UserRecord>> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .find(|user| user.email == email)
+            .cloned())
+    }
+
+    async fn find_by_id(&self, user_id: UserId) -> AppResult<Option<UserRecord>> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .find(|user| user.id == user_id)
+            .cloned())
+    }
+
+    async fn create(
+        &self,
+        _email: &str,
+        _password_hash: Option<&str>,
+        _email_verified: bool,
+    ) -> AppResult<UserId> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_password(&self, _user_id: UserId, _password_hash: &str) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn revoke_sessions(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn default_tenant_id(&self, _user_id: UserId) -> AppResult<Option<TenantId>> {
+        Ok(None)
+    }
+
+    async fn set_default_tenant_id(&self, _user_id: UserId, _tenant_id: TenantId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn record_failed_login(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn reset_failed_logins(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn mark_email_verified(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_display_name(
+        &self,
+        _user_id: UserId,
+        _tenant_id: TenantId,
+        _display_name: &str,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_email(&self, _user_id: UserId, _new_email: &str) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn enable_totp(
+        &self,
+        _user_id: UserId,
+        _totp_secret_enc: &[u8],
+        _recovery_codes_hash: &serde_json::Value,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn begin_totp_enrollment(
+        &self,
+        _user_id: UserId,
+        _totp_secret_enc: &[u8],
+        _recovery_codes_hash: &serde_json::Value,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn confirm_totp_enrollment(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn disable_totp(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_recovery_codes(
+        &self,
+        _user_id: UserId,
+        _recovery_codes_hash: &serde_json::Value,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn find_by_subject(&self, _subject: &str) -> AppResult<Option<UserRecord>> {
+        Ok(None)
+    }
+}
+
+struct FakeEmailChangeRepository {
+    users: Arc<FakeUserRepository>,
+    pending: Mutex<Option<PendingEmailChange>>,
+}
+
+#[async_trait]
+impl EmailChangeRepository for FakeEmailChangeRepository {
+    async fn save_pending_change(&self, change: &PendingEmailChange) -> AppResult<()> {
+        *self.pending.lock().unwrap_or_else(|_| unreachable!()) = Some(change.clone());
+        Ok(())
+    }
+
+    async fn find_pending_change(&self, user_id: UserId) -> AppResult<Option<PendingEmailChange>> {
+        Ok(self
+            .pending
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .clone()
+            .filter(|change| change.user_id == user_id))
+    }
+
+    async fn confirm_pending_change(
+        &self,
+        user_id: UserId,
+        new_email: &str,
+        confirmation: EmailChangeConfirmation,
+    ) -> AppResult<Option<PendingEmailChange>> {
+        let mut pending = self.pending.lock().unwrap_or_else(|_| unreachable!());
+        let Some(change) = pending.as_mut().filter(|change| {
+            change.user_id == user_id
+                && change.new_email == new_email
+                && !change.is_expired(Utc::now())
+        }) else {
+            return Ok(None);
+        };
+        match confirmation {
+            EmailChangeConfirmation::CurrentAddress => {
+                change.current_confirmed_at = Some(Utc::now())
+            }
+            EmailChangeConfirmation::NewAddress => change.new_confirmed_at = Some(Utc::now()),
+        }
+        Ok(Some(change.clone()))
+    }
+
+    async fn complete_pending_change(&self, user_id: UserId, new_email: &str) -> AppResult<bool> {
+        let mut pending = self.pending.lock().unwrap_or_else(|_| unreachable!());
+        if !pending.as_ref().is_some_and(|change| {
+            change.user_id == user_id
+                && change.new_email == new_email
+                && change.is_fully_confirmed()
+        }) {
+            return Ok(false);
+        }
+        *pending = None;
+
+        let mut users = self.users.users.lock().unwrap_or_else(|_| unreachable!());
+        let user = users
+            .iter_mut()
+            .find(|user| user.id == user_id)
+            .unwrap_or_else(|| unreachable!());
+        user.email = new_email.to_owned();
+        user.email_verified = true;
+        user.auth_sessions_revoked_after = Some(Utc::now());
+        Ok(true)
+    }
+
+    async fn delete_pending_change(&self, user_id: UserId) -> AppResult<bool> {
+        let mut pending = self.pending.lock().unwrap_or_else(|_| unreachable!());
+        let existed = pending
+            .as_ref()
+            .is_some_and(|change| change.user_id == user_id);
+        if existed {
+            *pending = None;
+        }
+        Ok(existed)
+    }
+}
+
+#[derive(Default)]
+struct FakeTokenRepository {
+    tokens: Mutex<HashMap<String, AuthTokenRecord>>,
+}
+
+#[async_trait]
+impl AuthTokenRepository for FakeTokenRepository {
+    async fn create_token(
+        &self,
+        user_id: Option<UserId>,
+        email: &str,
+        token_hash: &str,
+        token_type: AuthTokenType,
+        expires_at: chrono::DateTime<chrono::Utc>,
+        metadata: Option<&serde_json::Value>,
+    ) -> AppResult<uuid::Uuid> {
+        let id = uuid::Uuid::new_v4();
+        self.tokens
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .insert(
+                token_hash.to_owned(),
+                AuthTokenRecord {
+                    id,
+                    user_id,
+                    email: email.to_owned(),
+                    token_hash: token_hash.to_owned(),
+                    token_type: token_type.as_str().to_owned(),
+                    expires_at,
+                    used_at: None,
+                    metadata: metadata.cloned(),
+                },
+            );
+        Ok(id)
+    }
+
+    async fn consume_valid_token(
+        &self,
+        token_hash: &str,
+        token_type: AuthTokenType,
+    ) -> AppResult<Option<AuthTokenRecord>> {
+        let mut tokens = self.tokens.lock().unwrap_or_else(|_| unreachable!());
+        let Some(token) = tokens
+            .get_mut(token_hash)
+            .filter(|token| token.used_at.is_none() && token.token_type == token_type.as_str())
+        else {
+            return Ok(None);
+        };
+        token.used_at = Some(Utc::now());
+        Ok(Some(token.clone()))
+    }
+
+    async fn invalidate_tokens_for_user(
+        &self,
+        user_id: UserId,
+        token_type: AuthTokenType,
+    ) -> AppResult<()> {
+        for token in self
+            .tokens
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .values_mut()
+        {
+            if token.user_id == Some(user_id)
+                && token.token_type == token_type.as_str()
+                && token.used_at.is_none()
+            {
+                token.used_at = Some(Utc::now());
+            }
+        }
+        Ok(())
+    }
+
+    async fn token_exists(&self, token_hash: &str, _token_type: AuthTokenType) -> AppResult<bool> {
+        Ok(self
+            .tokens
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .contains_key(token_hash))
+    }
+
+    async fn invalidate_tokens_of_type(&self, _token_type: AuthTokenType) -> AppResult<u64> {
+        Ok(0)
+    }
+
+    async fn count_recent_tokens(
+        &self,
+        _email: &str,
+        _token_type: AuthTokenType,
+        _since: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<i64> {
+        Ok(0)
+    }
+}
+
+#[derive(Default)]
+struct FakeEmailService {
+    sent: Mutex<Vec<(String, String)>>,
+}
+
+impl FakeEmailService {
+    fn token_sent_to(&self, recipient: &str) -> String {
+        let sent = self.sent.lock().unwrap_or_else(|_| unreachable!());
+        let (_, body) = sent
+            .iter()
+            .rev()
+            .find(|(to, _)| to == recipient)
+            .unwrap_or_else(|| panic!("no email sent to {recipient}"));
+        body.split("token=")
+            .nth(1)
+            .and_then(|rest| rest.split_whitespace().next())
+            .unwrap_or_else(|| panic!("email to {recipient} has no token link"))
+            .to_owned()
+    }
+}
+
+#[async_trait]
+impl EmailService for FakeEmailService {
+    async fn send_email(
+        &self,
+        to: &str,
+        _subject: &str,
+        text_body: &str,
+        _html_body: Option<&str>,
+    ) -> AppResult<()> {
+        self.sent
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .push((to.to_owned(), text_body.to_owned()));
+        Ok(())
+    }
+
+    async fn send_email_with_attachments(
+        &self,
+        to: &str,
+        subject: &str,
+        text_body: &str,
+        _attachments: &[EmailAttachment],
+    ) -> AppResult<()> {
+        self.send_email(to, subject, text_body, None).await
+    }
+}
+
+#[derive(Default)]
+struct FakeAuthEventRepository {
+    events: Mutex<Vec<AuthEvent>>,
+}
+
+impl FakeAuthEventRepository {
+    fn recorded(&self) -> Vec<(AuthEventType, AuthEventOutcome)> {
+        self.events
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .map(|event| (event.event_type, event.outcome))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl AuthEventRepository for FakeAuthEventRepository {
+    async fn append_event(&self, event: AuthEvent) -> AppResult<()> {
+        self.events
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .push(event);
+        Ok(())
+    }
+}
+
+struct PlainPasswordHasher;
+
+impl PasswordHasher for PlainPasswordHasher {
+    fn hash_password(&self, password: &str) -> AppResult<String> {
+        Ok(format!("hashed:{password}"))
+    }
+
+    fn verify_password(&self, password: &str, hash: &str) -> AppResult<bool> {
+        Ok(hash == format!("hashed:{password}"))
+    }
+}
+
+struct Harness {
+    service: EmailChangeService,
+    users: Arc<FakeUserRepository>,
+    emails: Arc<FakeEmailService>,
+    events: Arc<FakeAuthEventRepository>,
+    user_id: UserId,
+}
+
+fn user(email: &str, password_hash: Option<&str>) -> UserRecord {
+    UserRecord {
+        id: UserId::new(),
+        email: email.to_owned(),
+        email_verified: true,
+        password_hash: password_hash.map(str::to_owned),
+        totp_enabled: false,
+        passkeys_enrolled: false,
+        totp_secret_enc: None,
+        recovery_codes_hash: None,
+        totp_pending_secret_enc: None,
+        recovery_codes_pending_hash: None,
+        failed_login_count: 0,
+        locked_until: None,
+        password_changed_at: None,
+        auth_sessions_revoked_after: None,
+        default_tenant_id: None,
+    }
+}
+
+fn build_harness(password_hash: Option<&str>) -> Harness {
+    let account = user("alice@example.com", password_hash);
+    let user_id = account.id;
+    let users = Arc::new(FakeUserRepository {
+        users: Mutex::new(vec![account, user("taken@example.com", None)]),
+    });
+    let emails = Arc::new(FakeEmailService::default());
+    let events = Arc::new(FakeAuthEventRepository::default());
+    let service = EmailChangeService::new(
+        Arc::new(FakeEmailChangeRepository {
+            users: users.clone(),
+            pending: Mutex::new(None),
+        }),
+        users.clone(),
+        Arc::new(PlainPasswordHasher),
+        AuthTokenService::new(
+            Arc::new(FakeTokenRepository::default()),
+            emails.clone(),
+            "http://localhost:3000".to_owned(),
+        ),
+        AuthEventService::new(events.clone()),
+    );
+
+    Harness {
+        service,
+        users,
+        emails,
+        events,
+        user_id,
+    }
+}
+
+fn request(
+    user_id: UserId,
+    new_email: &str,
+    current_password: Option<&str>,
+    recently_stepped_up: bool,
+) -> RequestEmailChangeParams {
+    RequestEmailChangeParams {
+        user_id,
+        new_email: new_email.to_owned(),
+        current_password: current_password.map(str::to_owned),
+        recently_stepped_up,
+        ip_address: None,
+        user_agent: None,
+    }
+}
+
+#[tokio::test]
+async fn email_change_applies_only_after_both_addresses_confirm() {
+    let harness = build_harness(Some("hashed:secret"));
+    let pending = harness
+        .service
+        .request_change(request(
+            harness.user_id,
+            " Alice.New@Example.com ",
+            Some("secret"),
+            false,
+        ))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(pending.new_email, "alice.new@example.com");
+
+    let current_token = harness.emails.token_sent_to("alice@example.com");
+    let new_token = harness.emails.token_sent_to("alice.new@example.com");
+
+    let outcome = harness
+        .service
+        .confirm_change(&new_token, None, None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let EmailChangeConfirmationOutcome::Pending(change) = outcome else {
+        panic!("change must wait for the current address");
+    };
+    assert!(change.new_confirmed_at.is_some() && change.current_confirmed_at.is_none());
+    assert!(matches!(
+        harness.service.confirm_change(&new_token, None, None).await,
+        Err(AppError::Unauthorized(_))
+    ));
+
+    let outcome = harness
+        .service
+        .confirm_change(&current_token, None, None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        outcome,
+        EmailChangeConfirmationOutcome::Completed {
+            user_id: harness.user_id,
+            new_email: "alice.new@example.com".to_owned(),
+        }
+    );
+
+    let account = harness
+        .users
+        .find_by_id(harness.user_id)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(account.email, "alice.new@example.com");
+    assert!(account.email_verified);
+    assert!(account.auth_sessions_revoked_after.is_some());
+    assert!(
+        harness
+            .service
+            .pending_change(harness.user_id)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_none()
+    );
+
+    assert_eq!(
+        harness.events.recorded(),
+        vec![
+            (
+                AuthEventType::EmailChangeRequested,
+                AuthEventOutcome::Success
+            ),
+            (
+                AuthEventType::EmailChangeConfirmed,
+                AuthEventOutcome::Success
+            ),
+            (
+                AuthEventType::EmailChangeConfirmed,
+                AuthEventOutcome::Failed
+            ),
+            (
+                AuthEventType::EmailChangeConfirmed,
+                AuthEventOutcome::Success
+            ),
+            (
+                AuthEventType::EmailChangeCompleted,
+                AuthEventOutcome::Success
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn email_change_requires_reauthentication_and_an_unused_address() {
+    let harness = build_harness(Some("hashed:secret"));
+
+    let wrong_password = harness
+        .service
+        .request_change(request(
+            harness.user_id,
+            "new@example.com",
+            Some("nope"),
+            true,
+        ))
+        .await;
+    assert!(matches!(wrong_password, Err(AppError::Unauthorized(_))));
+    let missing_password = harness
+        .service
+        .request_change(request(harness.user_id, "new@example.com", None, false))
+        .await;
+    assert!(matches!(missing_password, Err(AppError::Unauthorized(_))));
+    let taken = harness
+        .service
+        .request_change(request(harness.user_id, "taken@example.com", None, true))
+        .await;
+    assert!(matches!(taken, Err(AppError::Conflict(_))));
+    let unchanged = harness
+        .service
+        .request_change(request(harness.user_id, "ALICE@example.com", None, true))
+        .await;
+    assert!(matches!(unchanged, Err(AppError::Validation(_))));
+    assert!(
+        harness
+            .emails
+            .sent
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+
+    let passkey_only = build_harness(None);
+    let without_step_up = passkey_only
+        .service
+        .request_change(request(
+            passkey_only.user_id,
+            "new@example.com",
+            Some("secret"),
+            false,
+        ))
+        .await;
+    assert!(matches!(without_step_up, Err(AppError::Unauthorized(_))));
+    assert!(
+        passkey_only
+            .service
+            .request_change(request(passkey_only.user_id, "new@example.com", None, true))
+            .await
+            .is_ok()
+    );
+
+    assert_eq!(
+        harness.events.recorded()[..2],
+        [
+            (
+                AuthEventType::EmailChangeRequested,
+                AuthEventOutcome::InvalidPassword
+            ),
+            (
+                AuthEventType::EmailChangeRequested,
+                AuthEventOutcome::InvalidPassword
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn cancelled_email_change_invalidates_confirmation_links() {
+    let harness = build_harness(Some("hashed:secret"));
+    harness
+        .service
+        .request_change(request(harness.user_id, "new@example.com", None, true))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let token = harness.emails.token_sent_to("new@example.com");
+
+    harness
+        .service
+        .cancel_change(harness.user_id, None, None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(matches!(
+        harness.service.confirm_change(&token, None, None).await,
+        Err(AppError::Unauthorized(_))
+    ));
+    assert!(matches!(
+        harness
+            .service
+            .cancel_change(harness.user_id, None, None)
+            .await,
+        Err(AppError::NotFound(_))
+    ));
+    assert_eq!(
+        harness
+            .events
+            .recorded()
+            .last()
+            .map(|(event_type, _)| *event_type),
+        Some(AuthEventType::EmailChangeConfirmed)
+    );
+    assert!(harness.events.recorded().contains(&(
+        AuthEventType::EmailChangeCancelled,
+        AuthEventOutcome::Success
+    )));
+}
@@ -409,6 +409,31 @@ mod tests {
         ) -> AppResult<Option<TemporaryPermissionGrant>> {
             Ok(None)
         }
+
+        async fn list_team_peer_subjects(
+            &self,
+            _tenant_id: TenantId,
+            _subject: &str,
+        ) -> AppResult<Vec<String>> {
+            Ok(Vec::new())
+        }
+
+        async fn list_role_grants_for_subject(
+            &self,
+            _tenant_id: TenantId,
+            _subject: &str,
+        ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+            Ok(Vec::new())
+        }
+
+        async fn list_app_role_permissions_for_subject(
+            &self,
+            _tenant_id: TenantId,
+            _subject: &str,
+            _entity_logical_name: &str,
+        ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+            Ok(Vec::new())
+        }
     }
 
     #[derive(Default)]
@@ -0,0 +1,17 @@
+//! Per-tenant feature flags for gradual rollout.
+//!
+//! A flag has a tenant-wide default and optional per-role overrides. Services
+//! gating a capability ask whether a flag is enabled for a subject and supply
+//! the state to assume while the tenant has not configured the flag.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    FeatureFlag, FeatureFlagRepository, FeatureFlagRoleOverride, SaveFeatureFlagInput,
+    WORKFLOWS_FEATURE_FLAG,
+};
+pub use service::FeatureFlagService;
@@ -0,0 +1,93 @@
+use std::collections::BTreeSet;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppResult, TenantId};
+
+/// Flag gating workflow authoring and manual execution.
+pub const WORKFLOWS_FEATURE_FLAG: &str = "workflows";
+
+/// Flag state for holders of one role.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureFlagRoleOverride {
+    /// Role the override applies to.
+    pub role_name: String,
+    /// Flag state for holders of the role.
+    pub enabled: bool,
+}
+
+/// Tenant feature flag with its role overrides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureFlag {
+    /// Stable flag key.
+    pub flag_key: String,
+    /// Optional operator note.
+    pub description: Option<String>,
+    /// Flag state for subjects without an overridden role.
+    pub enabled: bool,
+    /// Per-role overrides.
+    pub role_overrides: Vec<FeatureFlagRoleOverride>,
+    /// Subject that last saved the flag.
+    pub updated_by_subject: String,
+    /// Last change timestamp.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl FeatureFlag {
+    /// Resolves the flag for a set of held roles.
+    ///
+    /// Overrides of held roles win over the tenant default, and the flag is
+    /// enabled when any of them enables it.
+    #[must_use]
+    pub fn is_enabled_for_roles(&self, role_names: &BTreeSet<String>) -> bool {
+        let mut overrides = self
+            .role_overrides
+            .iter()
+            .filter(|role_override| role_names.contains(role_override.role_name.as_str()))
+            .peekable();
+        if overrides.peek().is_none() {
+            return self.enabled;
+        }
+
+        overrides.any(|role_override| role_override.enabled)
+    }
+}
+
+/// Input payload for creating or updating a feature flag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveFeatureFlagInput {
+    /// Stable flag key.
+    pub flag_key: String,
+    /// Optional operator note.
+    pub description: Option<String>,
+    /// Flag state for subjects without an overridden role.
+    pub enabled: bool,
+    /// Per-role overrides; replaces the existing set.
+    pub role_overrides: Vec<FeatureFlagRoleOverride>,
+}
+
+/// Repository port for tenant feature flags.
+#[async_trait]
+pub trait FeatureFlagRepository: Send + Sync {
+    /// Lists every feature flag of a tenant.
+    async fn list_feature_flags(&self, tenant_id: TenantId) -> AppResult<Vec<FeatureFlag>>;
+
+    /// Finds one feature flag.
+    async fn find_feature_flag(
+        &self,
+        tenant_id: TenantId,
+        flag_key: &str,
+    ) -> AppResult<Option<FeatureFlag>>;
+
+    /// Creates or replaces a feature flag and its role overrides.
+    async fn save_feature_flag(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveFeatureFlagInput,
+    ) -> AppResult<FeatureFlag>;
+
+    /// Deletes a feature flag and returns whether it existed.
+    async fn delete_feature_flag(&self, tenant_id: TenantId, flag_key: &str) -> AppResult<bool>;
+}
@@ -0,0 +1,265 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use serde_json::json;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::{FeatureFlag, FeatureFlagRepository, SaveFeatureFlagInput};
+
+const MAX_FEATURE_FLAG_KEY_LENGTH: usize = 64;
+
+/// Application service for managing and evaluating tenant feature flags.
+#[derive(Clone)]
+pub struct FeatureFlagService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn FeatureFlagRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl FeatureFlagService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn FeatureFlagRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Lists every feature flag of the actor's tenant.
+    pub async fn list_feature_flags(&self, actor: &UserIdentity) -> AppResult<Vec<FeatureFlag>> {
+        self.require_role_manage_permission(actor).await?;
+        self.repository.list_feature_flags(actor.tenant_id()).await
+    }
+
+    /// Creates or replaces a feature flag and its role overrides.
+    pub async fn save_feature_flag(
+        &self,
+        actor: &UserIdentity,
+        input: SaveFeatureFlagInput,
+    ) -> AppResult<FeatureFlag> {
+        self.require_role_manage_permission(actor).await?;
+
+        let input = normalize_save_input(input)?;
+        let flag = self
+            .repository
+            .save_feature_flag(actor.tenant_id(), actor.subject(), input)
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityFeatureFlagSaved,
+                resource_type: "feature_flag".to_owned(),
+                resource_id: flag.flag_key.clone(),
+                detail: Some(
+                    json!({
+                        "enabled": flag.enabled,
+                        "role_overrides": flag
+                            .role_overrides
+                            .iter()
+                            .map(|role_override| {
+                                json!({
+                                    "role_name": role_override.role_name,
+                                    "enabled": role_override.enabled,
+                                })
+                            })
+                            .collect::<Vec<_>>(),
+                    })
+                    .to_string(),
+                ),
+            })
+            .await?;
+
+        Ok(flag)
+    }
+
+    /// Deletes a feature flag, reverting callers to their built-in default.
+    pub async fn delete_feature_flag(&self, actor: &UserIdentity, flag_key: &str) -> AppResult<()> {
+        self.require_role_manage_permission(actor).await?;
+
+        let flag_key = normalize_flag_key(flag_key)?;
+        if !self
+            .repository
+            .delete_feature_flag(actor.tenant_id(), flag_key.as_str())
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "feature flag '{flag_key}' does not exist"
+            )));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityFeatureFlagDeleted,
+                resource_type: "feature_flag".to_owned(),
+                resource_id: flag_key,
+                detail: None,
+            })
+            .await
+    }
+
+    /// Returns whether a flag is enabled for a subject.
+    ///
+    /// `enabled_by_default` applies while the tenant has not configured the
+    /// flag, so capabilities can be rolled out either opt-in or opt-out.
+    pub async fn is_enabled(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        flag_key: &str,
+        enabled_by_default: bool,
+    ) -> AppResult<bool> {
+        let Some(flag) = self
+            .repository
+            .find_feature_flag(tenant_id, flag_key)
+            .await?
+        else {
+            return Ok(enabled_by_default);
+        };
+        if flag.role_overrides.is_empty() {
+            return Ok(flag.enabled);
+        }
+
+        let role_names = self
+            .authorization_service
+            .role_names(tenant_id, subject)
+            .await?;
+        Ok(flag.is_enabled_for_roles(&role_names))
+    }
+
+    /// Fails with a forbidden error unless a flag is enabled for the actor.
+    pub async fn require_enabled(
+        &self,
+        actor: &UserIdentity,
+        flag_key: &str,
+        enabled_by_default: bool,
+    ) -> AppResult<()> {
+        if self
+            .is_enabled(
+                actor.tenant_id(),
+                actor.subject(),
+                flag_key,
+                enabled_by_default,
+            )
+            .await?
+        {
+            return Ok(());
+        }
+
+        Err(AppError::Forbidden(format!(
+            "feature '{flag_key}' is not enabled for subject '{}' in tenant '{}'",
+            actor.subject(),
+            actor.tenant_id()
+        )))
+    }
+
+    /// Resolves every configured flag for the actor.
+    ///
+    /// Flags the tenant has not configured are left out; callers fall back to
+    /// their own defaults for those.
+    pub async fn evaluate_feature_flags(
+        &self,
+        actor: &UserIdentity,
+    ) -> AppResult<BTreeMap<String, bool>> {
+        let flags = self
+            .repository
+            .list_feature_flags(actor.tenant_id())
+            .await?;
+        let role_names = if flags.iter().any(|flag| !flag.role_overrides.is_empty()) {
+            self.authorization_service
+                .role_names(actor.tenant_id(), actor.subject())
+                .await?
+        } else {
+            BTreeSet::new()
+        };
+
+        Ok(flags
+            .iter()
+            .map(|flag| {
+                (
+                    flag.flag_key.clone(),
+                    flag.is_enabled_for_roles(&role_names),
+                )
+            })
+            .collect())
+    }
+
+    async fn require_role_manage_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::SecurityRoleManage,
+            )
+            .await
+    }
+}
+
+fn normalize_flag_key(flag_key: &str) -> AppResult<String> {
+    let flag_key = flag_key.trim();
+    if flag_key.is_empty() {
+        return Err(AppError::Validation(
+            "feature flag key is required".to_owned(),
+        ));
+    }
+    if flag_key.len() > MAX_FEATURE_FLAG_KEY_LENGTH
+        || !flag_key.chars().all(|character| {
+            character.is_ascii_lowercase()
+                || character.is_ascii_digit()
+                || matches!(character, '_' | '-' | '.')
+        })
+    {
+        return Err(AppError::Validation(format!(
+            "feature flag key '{flag_key}' must be at most {MAX_FEATURE_FLAG_KEY_LENGTH} lowercase letters, digits, '_', '-' or '.'"
+        )));
+    }
+
+    Ok(flag_key.to_owned())
+}
+
+fn normalize_save_input(input: SaveFeatureFlagInput) -> AppResult<SaveFeatureFlagInput> {
+    let flag_key = normalize_flag_key(input.flag_key.as_str())?;
+    let description = input
+        .description
+        .map(|description| description.trim().to_owned())
+        .filter(|description| !description.is_empty());
+
+    let mut role_names = BTreeSet::new();
+    let mut role_overrides = Vec::with_capacity(input.role_overrides.len());
+    for mut role_override in input.role_overrides {
+        role_override.role_name = role_override.role_name.trim().to_owned();
+        if role_override.role_name.is_empty() {
+            return Err(AppError::Validation(
+                "feature flag override role name is required".to_owned(),
+            ));
+        }
+        if !role_names.insert(role_override.role_name.clone()) {
+            return Err(AppError::Validation(format!(
+                "role '{}' is listed more than once",
+                role_override.role_name
+            )));
+        }
+
+        role_overrides.push(role_override);
+    }
+
+    Ok(SaveFeatureFlagInput {
+        flag_key,
+        description,
+        enabled: input.enabled,
+        role_overrides,
+    })
+}
@@ -0,0 +1,360 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    SubjectRoleGrant, TemporaryPermissionGrant,
+};
+
+use super::{
+    FeatureFlag, FeatureFlagRepository, FeatureFlagRoleOverride, FeatureFlagService,
+    SaveFeatureFlagInput,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+    role_names: HashMap<(TenantId, String), Vec<String>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<SubjectRoleGrant>> {
+        Ok(self
+            .role_names
+            .get(&(tenant_id, subject.to_owned()))
+            .into_iter()
+            .flatten()
+            .map(|role_name| SubjectRoleGrant {
+                role_name: role_name.clone(),
+                team_name: None,
+                permissions: Vec::new(),
+            })
+            .collect())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeFeatureFlagRepository {
+    flags: Mutex<Vec<FeatureFlag>>,
+}
+
+#[async_trait]
+impl FeatureFlagRepository for FakeFeatureFlagRepository {
+    async fn list_feature_flags(&self, _tenant_id: TenantId) -> AppResult<Vec<FeatureFlag>> {
+        Ok(self.flags.lock().await.clone())
+    }
+
+    async fn find_feature_flag(
+        &self,
+        _tenant_id: TenantId,
+        flag_key: &str,
+    ) -> AppResult<Option<FeatureFlag>> {
+        Ok(self
+            .flags
+            .lock()
+            .await
+            .iter()
+            .find(|flag| flag.flag_key == flag_key)
+            .cloned())
+    }
+
+    async fn save_feature_flag(
+        &self,
+        _tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveFeatureFlagInput,
+    ) -> AppResult<FeatureFlag> {
+        let flag = FeatureFlag {
+            flag_key: input.flag_key,
+            description: input.description,
+            enabled: input.enabled,
+            role_overrides: input.role_overrides,
+            updated_by_subject: updated_by_subject.to_owned(),
+            updated_at: Utc::now(),
+        };
+        let mut flags = self.flags.lock().await;
+        flags.retain(|existing| existing.flag_key != flag.flag_key);
+        flags.push(flag.clone());
+        Ok(flag)
+    }
+
+    async fn delete_feature_flag(&self, _tenant_id: TenantId, flag_key: &str) -> AppResult<bool> {
+        let mut flags = self.flags.lock().await;
+        let before = flags.len();
+        flags.retain(|flag| flag.flag_key != flag_key);
+        Ok(flags.len() != before)
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    permissions: Vec<Permission>,
+) -> (FeatureFlagService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([((tenant_id, "alice".to_owned()), permissions)]),
+            role_names: HashMap::from([
+                (
+                    (tenant_id, "bob".to_owned()),
+                    vec!["beta_testers".to_owned()],
+                ),
+                (
+                    (tenant_id, "carol".to_owned()),
+                    vec!["beta_testers".to_owned(), "support".to_owned()],
+                ),
+                ((tenant_id, "dave".to_owned()), vec!["support".to_owned()]),
+            ]),
+        }),
+        audit_repository.clone(),
+    );
+    let service = FeatureFlagService::new(
+        authorization_service,
+        Arc::new(FakeFeatureFlagRepository::default()),
+        audit_repository.clone(),
+    );
+    (service, audit_repository)
+}
+
+fn role_override(role_name: &str, enabled: bool) -> FeatureFlagRoleOverride {
+    FeatureFlagRoleOverride {
+        role_name: role_name.to_owned(),
+        enabled,
+    }
+}
+
+fn save_input(
+    flag_key: &str,
+    enabled: bool,
+    role_overrides: Vec<FeatureFlagRoleOverride>,
+) -> SaveFeatureFlagInput {
+    SaveFeatureFlagInput {
+        flag_key: flag_key.to_owned(),
+        description: Some("  ".to_owned()),
+        enabled,
+        role_overrides,
+    }
+}
+
+#[test]
+fn enabling_override_wins_over_other_held_overrides() {
+    let flag = FeatureFlag {
+        flag_key: "workflows".to_owned(),
+        description: None,
+        enabled: false,
+        role_overrides: vec![role_override("beta", true), role_override("support", false)],
+        updated_by_subject: "alice".to_owned(),
+        updated_at: Utc::now(),
+    };
+
+    let roles = |names: &[&str]| {
+        names
+            .iter()
+            .map(|name| (*name).to_owned())
+            .collect::<BTreeSet<_>>()
+    };
+    assert!(!flag.is_enabled_for_roles(&roles(&[])));
+    assert!(flag.is_enabled_for_roles(&roles(&["beta", "support"])));
+    assert!(!flag.is_enabled_for_roles(&roles(&["support", "sales"])));
+}
+
+#[tokio::test]
+async fn flag_management_requires_role_manage_permission() {
+    let tenant_id = TenantId::new();
+    let (service, _) = build_service(tenant_id, vec![Permission::SecurityAuditRead]);
+    let actor = actor(tenant_id, "alice");
+
+    let listed = service.list_feature_flags(&actor).await;
+    assert!(matches!(listed, Err(AppError::Forbidden(_))));
+    let saved = service
+        .save_feature_flag(&actor, save_input("workflows", true, Vec::new()))
+        .await;
+    assert!(matches!(saved, Err(AppError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn saving_validates_keys_and_overrides_and_is_audited() {
+    let tenant_id = TenantId::new();
+    let (service, audit_repository) =
+        build_service(tenant_id, vec![Permission::SecurityRoleManage]);
+    let actor = actor(tenant_id, "alice");
+
+    let invalid_key = service
+        .save_feature_flag(&actor, save_input("New Workflows", true, Vec::new()))
+        .await;
+    assert!(matches!(invalid_key, Err(AppError::Validation(_))));
+    let duplicate_role = service
+        .save_feature_flag(
+            &actor,
+            save_input(
+                "workflows",
+                true,
+                vec![role_override("beta", true), role_override(" beta ", false)],
+            ),
+        )
+        .await;
+    assert!(matches!(duplicate_role, Err(AppError::Validation(_))));
+
+    let saved = service
+        .save_feature_flag(
+            &actor,
+            save_input(" workflows ", false, vec![role_override(" beta ", true)]),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(saved.flag_key, "workflows");
+    assert_eq!(saved.description, None);
+    assert_eq!(saved.role_overrides, vec![role_override("beta", true)]);
+
+    let missing = service.delete_feature_flag(&actor, "file_fields").await;
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+    service
+        .delete_feature_flag(&actor, "workflows")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let actions = audit_repository
+        .events
+        .lock()
+        .await
+        .iter()
+        .map(|event| event.action)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actions,
+        vec![
+            AuditAction::SecurityFeatureFlagSaved,
+            AuditAction::SecurityFeatureFlagDeleted
+        ]
+    );
+}
+
+#[tokio::test]
+async fn evaluation_applies_role_overrides_and_caller_defaults() {
+    let tenant_id = TenantId::new();
+    let (service, _) = build_service(tenant_id, vec![Permission::SecurityRoleManage]);
+
+    assert!(
+        service
+            .is_enabled(tenant_id, "bob", "file_fields", true)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+    let unconfigured = service
+        .require_enabled(&actor(tenant_id, "bob"), "file_fields", false)
+        .await;
+    assert!(matches!(unconfigured, Err(AppError::Forbidden(_))));
+
+    service
+        .save_feature_flag(
+            &actor(tenant_id, "alice"),
+            save_input(
+                "file_fields",
+                false,
+                vec![
+                    role_override("beta_testers", true),
+                    role_override("support", false),
+                ],
+            ),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    for (subject, expected) in [
+        ("alice", false),
+        ("bob", true),
+        ("carol", true),
+        ("dave", false),
+    ] {
+        assert_eq!(
+            service
+                .is_enabled(tenant_id, subject, "file_fields", true)
+                .await
+                .unwrap_or_else(|_| unreachable!()),
+            expected,
+            "{subject}"
+        );
+    }
+
+    let evaluated = service
+        .evaluate_feature_flags(&actor(tenant_id, "bob"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(evaluated.get("file_fields"), Some(&true));
+    assert_eq!(evaluated.len(), 1);
+}
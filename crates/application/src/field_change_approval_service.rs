@@ -0,0 +1,20 @@
+//! Dual-control (four-eyes) approval for sensitive runtime fields.
+//!
+//! Fields marked for dual control are never changed directly: record updates
+//! that touch them leave the stored value in place and open a pending change
+//! that a second subject must approve before it is applied. Pending changes
+//! expire after the field's approval window.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    DEFAULT_APPROVAL_WINDOW_HOURS, DualControlField, DualControlFieldInput,
+    FIELD_CHANGE_REQUESTED_APPROVAL_KEY, FieldChangeApprovalRepository, NewPendingFieldChange,
+    PendingFieldChange, PendingFieldChangeQuery, PendingFieldChangeStatus,
+    SaveDualControlFieldsInput,
+};
+pub use service::FieldChangeApprovalService;
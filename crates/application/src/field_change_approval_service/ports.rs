@@ -0,0 +1,212 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::RuntimeRecordWorkflowEventInput;
+
+/// Approval key used for `approval_event_received` notifications on new pending changes.
+pub const FIELD_CHANGE_REQUESTED_APPROVAL_KEY: &str = "field_change_requested";
+
+/// Approval window applied when a dual-control field does not set one.
+pub const DEFAULT_APPROVAL_WINDOW_HOURS: u16 = 72;
+
+/// One runtime field that requires dual-control approval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DualControlField {
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Field logical name.
+    pub field_logical_name: String,
+    /// Hours a pending change stays approvable before it expires.
+    pub approval_window_hours: u16,
+    /// Subject that last saved the entity's dual-control set.
+    pub updated_by_subject: String,
+    /// Last save timestamp.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input item for one dual-control field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DualControlFieldInput {
+    /// Field logical name.
+    pub field_logical_name: String,
+    /// Optional approval window override in hours.
+    pub approval_window_hours: Option<u16>,
+}
+
+/// Input payload replacing the dual-control field set of one entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveDualControlFieldsInput {
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Complete dual-control field set; an empty list clears it.
+    pub fields: Vec<DualControlFieldInput>,
+}
+
+/// Lifecycle status of a pending field change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingFieldChangeStatus {
+    /// Awaiting a second subject's decision.
+    Pending,
+    /// Approved and applied to the record.
+    Approved,
+    /// Rejected or withdrawn without applying.
+    Rejected,
+    /// Approval window elapsed without a decision.
+    Expired,
+}
+
+impl PendingFieldChangeStatus {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Approved => "approved",
+            Self::Rejected => "rejected",
+            Self::Expired => "expired",
+        }
+    }
+
+    /// Parses a storage or transport value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "pending" => Ok(Self::Pending),
+            "approved" => Ok(Self::Approved),
+            "rejected" => Ok(Self::Rejected),
+            "expired" => Ok(Self::Expired),
+            _ => Err(AppError::Validation(format!(
+                "unknown pending field change status '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Stored change to a dual-control field awaiting or past a decision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingFieldChange {
+    /// Stable change identifier.
+    pub change_id: String,
+    /// Owning tenant.
+    pub tenant_id: TenantId,
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Runtime record identifier.
+    pub record_id: String,
+    /// Field logical name.
+    pub field_logical_name: String,
+    /// Field value when the change was requested; absent when unset.
+    pub previous_value: Option<Value>,
+    /// Requested field value; absent when the change clears the field.
+    pub proposed_value: Option<Value>,
+    /// Subject whose update opened the change.
+    pub requested_by_subject: String,
+    /// Request timestamp.
+    pub requested_at: DateTime<Utc>,
+    /// Time after which the change can no longer be approved.
+    pub expires_at: DateTime<Utc>,
+    /// Current lifecycle status.
+    pub status: PendingFieldChangeStatus,
+    /// Subject that approved or rejected the change.
+    pub decided_by_subject: Option<String>,
+    /// Decision timestamp.
+    pub decided_at: Option<DateTime<Utc>>,
+}
+
+impl PendingFieldChange {
+    /// Returns whether the change still awaits a decision at `now`.
+    #[must_use]
+    pub fn is_open(&self, now: DateTime<Utc>) -> bool {
+        self.status == PendingFieldChangeStatus::Pending && self.expires_at > now
+    }
+}
+
+/// Pending change opened by a runtime record update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewPendingFieldChange {
+    /// Stable change identifier.
+    pub change_id: String,
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Runtime record identifier.
+    pub record_id: String,
+    /// Field logical name.
+    pub field_logical_name: String,
+    /// Field value when the change was requested.
+    pub previous_value: Option<Value>,
+    /// Requested field value.
+    pub proposed_value: Option<Value>,
+    /// Time after which the change expires.
+    pub expires_at: DateTime<Utc>,
+    /// Workflow event enqueued with the change to notify approvers.
+    pub notification: Option<RuntimeRecordWorkflowEventInput>,
+}
+
+/// Filters for listing pending field changes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PendingFieldChangeQuery {
+    /// Optional entity filter.
+    pub entity_logical_name: Option<String>,
+    /// Optional record filter.
+    pub record_id: Option<String>,
+    /// Optional status filter.
+    pub status: Option<PendingFieldChangeStatus>,
+}
+
+/// Repository port for dual-control field policies and pending changes.
+#[async_trait]
+pub trait FieldChangeApprovalRepository: Send + Sync {
+    /// Lists dual-control fields, optionally for one entity.
+    async fn list_dual_control_fields(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<DualControlField>>;
+
+    /// Replaces the dual-control field set of one entity.
+    async fn save_dual_control_fields(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveDualControlFieldsInput,
+    ) -> AppResult<Vec<DualControlField>>;
+
+    /// Stores new pending changes and enqueues their notifications.
+    ///
+    /// Fails with a conflict when an open change already exists for the same
+    /// record field.
+    async fn create_pending_changes(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        changes: Vec<NewPendingFieldChange>,
+    ) -> AppResult<Vec<PendingFieldChange>>;
+
+    /// Lists pending changes, newest first.
+    async fn list_pending_changes(
+        &self,
+        tenant_id: TenantId,
+        query: PendingFieldChangeQuery,
+    ) -> AppResult<Vec<PendingFieldChange>>;
+
+    /// Finds one pending change by identifier.
+    async fn find_pending_change(
+        &self,
+        tenant_id: TenantId,
+        change_id: &str,
+    ) -> AppResult<Option<PendingFieldChange>>;
+
+    /// Records a decision on an open change, returning `None` when it is no longer open.
+    async fn resolve_pending_change(
+        &self,
+        tenant_id: TenantId,
+        change_id: &str,
+        decided_by_subject: &str,
+        status: PendingFieldChangeStatus,
+    ) -> AppResult<Option<PendingFieldChange>>;
+
+    /// Marks pending changes past their approval window as expired.
+    async fn expire_stale_changes(&self, tenant_id: TenantId) -> AppResult<u64>;
+}
@@ -0,0 +1,141 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::{
+    DualControlField, DualControlFieldInput, FieldChangeApprovalRepository,
+    SaveDualControlFieldsInput,
+};
+
+const MAX_APPROVAL_WINDOW_HOURS: u16 = 720;
+
+/// Application service for managing which runtime fields require dual control.
+#[derive(Clone)]
+pub struct FieldChangeApprovalService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn FieldChangeApprovalRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl FieldChangeApprovalService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn FieldChangeApprovalRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Lists dual-control fields, optionally for one entity.
+    pub async fn list_dual_control_fields(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<DualControlField>> {
+        self.require_dual_control_manage_permission(actor).await?;
+        self.repository
+            .list_dual_control_fields(actor.tenant_id(), entity_logical_name)
+            .await
+    }
+
+    /// Replaces the dual-control field set of one entity.
+    pub async fn save_dual_control_fields(
+        &self,
+        actor: &UserIdentity,
+        input: SaveDualControlFieldsInput,
+    ) -> AppResult<Vec<DualControlField>> {
+        self.require_dual_control_manage_permission(actor).await?;
+
+        let input = normalize_save_input(input)?;
+        let entity_logical_name = input.entity_logical_name.clone();
+        let fields = self
+            .repository
+            .save_dual_control_fields(actor.tenant_id(), actor.subject(), input)
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityDualControlFieldsSaved,
+                resource_type: "dual_control_fields".to_owned(),
+                resource_id: entity_logical_name,
+                detail: Some(
+                    serde_json::json!({
+                        "fields": fields
+                            .iter()
+                            .map(|field| field.field_logical_name.as_str())
+                            .collect::<Vec<_>>(),
+                    })
+                    .to_string(),
+                ),
+            })
+            .await?;
+
+        Ok(fields)
+    }
+
+    async fn require_dual_control_manage_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::SecurityDualControlManage,
+            )
+            .await
+    }
+}
+
+fn normalize_save_input(
+    input: SaveDualControlFieldsInput,
+) -> AppResult<SaveDualControlFieldsInput> {
+    let entity_logical_name = input.entity_logical_name.trim().to_owned();
+    if entity_logical_name.is_empty() {
+        return Err(AppError::Validation(
+            "dual-control entity_logical_name is required".to_owned(),
+        ));
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut fields = Vec::with_capacity(input.fields.len());
+    for field in input.fields {
+        let field_logical_name = field.field_logical_name.trim().to_owned();
+        if field_logical_name.is_empty() {
+            return Err(AppError::Validation(
+                "dual-control field_logical_name must not be empty".to_owned(),
+            ));
+        }
+        if !seen.insert(field_logical_name.clone()) {
+            return Err(AppError::Validation(format!(
+                "dual-control field '{field_logical_name}' is listed more than once"
+            )));
+        }
+        if let Some(hours) = field.approval_window_hours
+            && !(1..=MAX_APPROVAL_WINDOW_HOURS).contains(&hours)
+        {
+            return Err(AppError::Validation(format!(
+                "dual-control approval window must be between 1 and {MAX_APPROVAL_WINDOW_HOURS} hours"
+            )));
+        }
+
+        fields.push(DualControlFieldInput {
+            field_logical_name,
+            approval_window_hours: field.approval_window_hours,
+        });
+    }
+
+    Ok(SaveDualControlFieldsInput {
+        entity_logical_name,
+        fields,
+    })
+}
@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    TemporaryPermissionGrant,
+};
+
+use super::{
+    DEFAULT_APPROVAL_WINDOW_HOURS, DualControlField, DualControlFieldInput,
+    FieldChangeApprovalRepository, FieldChangeApprovalService, NewPendingFieldChange,
+    PendingFieldChange, PendingFieldChangeQuery, PendingFieldChangeStatus,
+    SaveDualControlFieldsInput,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeFieldChangeApprovalRepository {
+    fields: Mutex<HashMap<TenantId, Vec<DualControlField>>>,
+}
+
+#[async_trait]
+impl FieldChangeApprovalRepository for FakeFieldChangeApprovalRepository {
+    async fn list_dual_control_fields(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<DualControlField>> {
+        Ok(self
+            .fields
+            .lock()
+            .await
+            .get(&tenant_id)
+            .into_iter()
+            .flatten()
+            .filter(|field| {
+                entity_logical_name.is_none_or(|entity| field.entity_logical_name == entity)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn save_dual_control_fields(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveDualControlFieldsInput,
+    ) -> AppResult<Vec<DualControlField>> {
+        let mut fields = self.fields.lock().await;
+        let tenant_fields = fields.entry(tenant_id).or_default();
+        tenant_fields.retain(|field| field.entity_logical_name != input.entity_logical_name);
+        let saved: Vec<DualControlField> = input
+            .fields
+            .into_iter()
+            .map(|field| DualControlField {
+                entity_logical_name: input.entity_logical_name.clone(),
+                field_logical_name: field.field_logical_name,
+                approval_window_hours: field
+                    .approval_window_hours
+                    .unwrap_or(DEFAULT_APPROVAL_WINDOW_HOURS),
+                updated_by_subject: updated_by_subject.to_owned(),
+                updated_at: Utc::now(),
+            })
+            .collect();
+        tenant_fields.extend(saved.iter().cloned());
+        Ok(saved)
+    }
+
+    async fn create_pending_changes(
+        &self,
+        _tenant_id: TenantId,
+        _requested_by_subject: &str,
+        _changes: Vec<NewPendingFieldChange>,
+    ) -> AppResult<Vec<PendingFieldChange>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_pending_changes(
+        &self,
+        _tenant_id: TenantId,
+        _query: PendingFieldChangeQuery,
+    ) -> AppResult<Vec<PendingFieldChange>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_pending_change(
+        &self,
+        _tenant_id: TenantId,
+        _change_id: &str,
+    ) -> AppResult<Option<PendingFieldChange>> {
+        Ok(None)
+    }
+
+    async fn resolve_pending_change(
+        &self,
+        _tenant_id: TenantId,
+        _change_id: &str,
+        _decided_by_subject: &str,
+        _status: PendingFieldChangeStatus,
+    ) -> AppResult<Option<PendingFieldChange>> {
+        Ok(None)
+    }
+
+    async fn expire_stale_changes(&self, _tenant_id: TenantId) -> AppResult<u64> {
+        Ok(0)
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    permissions: Vec<Permission>,
+) -> (FieldChangeApprovalService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([((tenant_id, "alice".to_owned()), permissions)]),
+        }),
+        audit_repository.clone(),
+    );
+    let service = FieldChangeApprovalService::new(
+        authorization_service,
+        Arc::new(FakeFieldChangeApprovalRepository::default()),
+        audit_repository.clone(),
+    );
+    (service, audit_repository)
+}
+
+fn save_input(fields: Vec<(&str, Option<u16>)>) -> SaveDualControlFieldsInput {
+    SaveDualControlFieldsInput {
+        entity_logical_name: " account ".to_owned(),
+        fields: fields
+            .into_iter()
+            .map(|(name, hours)| DualControlFieldInput {
+                field_logical_name: name.to_owned(),
+                approval_window_hours: hours,
+            })
+            .collect(),
+    }
+}
+
+#[tokio::test]
+async fn saving_dual_control_fields_requires_dual_control_permission() {
+    let tenant_id = TenantId::new();
+    let (service, _) = build_service(tenant_id, vec![Permission::RuntimeRecordWrite]);
+
+    let result = service
+        .save_dual_control_fields(&actor(tenant_id, "alice"), save_input(vec![("iban", None)]))
+        .await;
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn save_dual_control_fields_validates_and_replaces_entity_set() {
+    let tenant_id = TenantId::new();
+    let (service, audit_repository) =
+        build_service(tenant_id, vec![Permission::SecurityDualControlManage]);
+    let actor = actor(tenant_id, "alice");
+
+    let duplicate = service
+        .save_dual_control_fields(&actor, save_input(vec![("iban", None), (" iban ", None)]))
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Validation(_))));
+    let zero_window = service
+        .save_dual_control_fields(&actor, save_input(vec![("iban", Some(0))]))
+        .await;
+    assert!(matches!(zero_window, Err(AppError::Validation(_))));
+
+    service
+        .save_dual_control_fields(
+            &actor,
+            save_input(vec![("iban", None), ("credit_limit", Some(4))]),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let saved = service
+        .save_dual_control_fields(&actor, save_input(vec![("credit_limit", Some(8))]))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(saved.len(), 1);
+
+    let listed = service
+        .list_dual_control_fields(&actor, Some("account"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].field_logical_name, "credit_limit");
+    assert_eq!(listed[0].approval_window_hours, 8);
+
+    let events = audit_repository.events.lock().await;
+    assert_eq!(events.len(), 2);
+    assert!(events.iter().all(
+        |event| event.action == AuditAction::SecurityDualControlFieldsSaved
+            && event.resource_id == "account"
+    ));
+}
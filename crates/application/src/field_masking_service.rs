@@ -0,0 +1,18 @@
+//! Role-based masking of classified runtime fields.
+//!
+//! Fields carry an optional data classification in their metadata. Masking
+//! rules decide, per role and classification, whether values are fully
+//! masked, partially masked, or hidden. Metadata runtime reads apply the
+//! rules of every role a subject holds, directly or through a team.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    FieldMaskingRepository, FieldMaskingRule, FieldMaskingRuleInput, SaveFieldMaskingRulesInput,
+    effective_field_masking_modes,
+};
+pub use service::FieldMaskingService;
@@ -0,0 +1,83 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppResult, TenantId};
+use qryvanta_domain::{FieldClassification, FieldMaskingMode};
+
+/// Masking applied to one classification for holders of a role.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldMaskingRule {
+    /// Role the rule applies to.
+    pub role_name: String,
+    /// Masked classification.
+    pub classification: FieldClassification,
+    /// How values are shown.
+    pub masking_mode: FieldMaskingMode,
+    /// Subject that last saved the rule.
+    pub updated_by_subject: String,
+    /// Last change timestamp.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// One classification and its masking mode in a role's rule set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldMaskingRuleInput {
+    /// Masked classification.
+    pub classification: FieldClassification,
+    /// How values are shown.
+    pub masking_mode: FieldMaskingMode,
+}
+
+/// Input payload for replacing the masking rules of a role.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveFieldMaskingRulesInput {
+    /// Role whose rules are replaced.
+    pub role_name: String,
+    /// New rules; an empty list removes every rule of the role.
+    pub rules: Vec<FieldMaskingRuleInput>,
+}
+
+/// Repository port for field masking rules.
+#[async_trait]
+pub trait FieldMaskingRepository: Send + Sync {
+    /// Lists masking rules, optionally for one role.
+    async fn list_masking_rules(
+        &self,
+        tenant_id: TenantId,
+        role_name: Option<&str>,
+    ) -> AppResult<Vec<FieldMaskingRule>>;
+
+    /// Replaces the masking rules of a role and returns the saved set.
+    async fn save_role_masking_rules(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveFieldMaskingRulesInput,
+    ) -> AppResult<Vec<FieldMaskingRule>>;
+}
+
+/// Resolves the masking mode per classification for a set of held roles.
+///
+/// Only roles with a rule for a classification take part, and the least
+/// restrictive of their modes applies. Classifications without any such
+/// rule are left unmasked.
+#[must_use]
+pub fn effective_field_masking_modes(
+    rules: &[FieldMaskingRule],
+    role_names: &BTreeSet<String>,
+) -> BTreeMap<FieldClassification, FieldMaskingMode> {
+    let mut modes = BTreeMap::<FieldClassification, FieldMaskingMode>::new();
+    for rule in rules
+        .iter()
+        .filter(|rule| role_names.contains(rule.role_name.as_str()))
+    {
+        modes
+            .entry(rule.classification)
+            .and_modify(|mode| *mode = mode.least_restrictive(rule.masking_mode))
+            .or_insert(rule.masking_mode);
+    }
+
+    modes
+}
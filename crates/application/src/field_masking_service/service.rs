@@ -0,0 +1,125 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use serde_json::json;
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::{FieldMaskingRepository, FieldMaskingRule, SaveFieldMaskingRulesInput};
+
+/// Application service for managing field masking rules.
+#[derive(Clone)]
+pub struct FieldMaskingService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn FieldMaskingRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl FieldMaskingService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn FieldMaskingRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Lists masking rules, optionally for one role.
+    pub async fn list_masking_rules(
+        &self,
+        actor: &UserIdentity,
+        role_name: Option<&str>,
+    ) -> AppResult<Vec<FieldMaskingRule>> {
+        self.require_role_manage_permission(actor).await?;
+        self.repository
+            .list_masking_rules(actor.tenant_id(), role_name)
+            .await
+    }
+
+    /// Replaces the masking rules of one role.
+    pub async fn save_role_masking_rules(
+        &self,
+        actor: &UserIdentity,
+        input: SaveFieldMaskingRulesInput,
+    ) -> AppResult<Vec<FieldMaskingRule>> {
+        self.require_role_manage_permission(actor).await?;
+
+        let input = normalize_save_input(input)?;
+        let role_name = input.role_name.clone();
+        let rules = self
+            .repository
+            .save_role_masking_rules(actor.tenant_id(), actor.subject(), input)
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityFieldMaskingRulesSaved,
+                resource_type: "field_masking_rules".to_owned(),
+                resource_id: role_name,
+                detail: Some(
+                    json!({
+                        "rules": rules
+                            .iter()
+                            .map(|rule| {
+                                json!({
+                                    "classification": rule.classification.as_str(),
+                                    "masking_mode": rule.masking_mode.as_str(),
+                                })
+                            })
+                            .collect::<Vec<_>>(),
+                    })
+                    .to_string(),
+                ),
+            })
+            .await?;
+
+        Ok(rules)
+    }
+
+    async fn require_role_manage_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::SecurityRoleManage,
+            )
+            .await
+    }
+}
+
+fn normalize_save_input(
+    input: SaveFieldMaskingRulesInput,
+) -> AppResult<SaveFieldMaskingRulesInput> {
+    let role_name = input.role_name.trim().to_owned();
+    if role_name.is_empty() {
+        return Err(AppError::Validation(
+            "field masking role name is required".to_owned(),
+        ));
+    }
+
+    let mut classifications = BTreeSet::new();
+    for rule in &input.rules {
+        if !classifications.insert(rule.classification) {
+            return Err(AppError::Validation(format!(
+                "classification '{}' is listed more than once",
+                rule.classification.as_str()
+            )));
+        }
+    }
+
+    Ok(SaveFieldMaskingRulesInput {
+        role_name,
+        rules: input.rules,
+    })
+}
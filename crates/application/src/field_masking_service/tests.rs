@@ -0,0 +1,299 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, FieldClassification, FieldMaskingMode, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    TemporaryPermissionGrant,
+};
+
+use super::{
+    FieldMaskingRepository, FieldMaskingRule, FieldMaskingRuleInput, FieldMaskingService,
+    SaveFieldMaskingRulesInput, effective_field_masking_modes,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeFieldMaskingRepository {
+    rules: Mutex<Vec<FieldMaskingRule>>,
+}
+
+#[async_trait]
+impl FieldMaskingRepository for FakeFieldMaskingRepository {
+    async fn list_masking_rules(
+        &self,
+        _tenant_id: TenantId,
+        role_name: Option<&str>,
+    ) -> AppResult<Vec<FieldMaskingRule>> {
+        Ok(self
+            .rules
+            .lock()
+            .await
+            .iter()
+            .filter(|rule| role_name.is_none_or(|role_name| rule.role_name == role_name))
+            .cloned()
+            .collect())
+    }
+
+    async fn save_role_masking_rules(
+        &self,
+        _tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveFieldMaskingRulesInput,
+    ) -> AppResult<Vec<FieldMaskingRule>> {
+        let mut rules = self.rules.lock().await;
+        rules.retain(|rule| rule.role_name != input.role_name);
+        let saved: Vec<FieldMaskingRule> = input
+            .rules
+            .into_iter()
+            .map(|rule| masking_rule(input.role_name.as_str(), rule, updated_by_subject))
+            .collect();
+        rules.extend(saved.iter().cloned());
+        Ok(saved)
+    }
+}
+
+fn masking_rule(
+    role_name: &str,
+    rule: FieldMaskingRuleInput,
+    updated_by_subject: &str,
+) -> FieldMaskingRule {
+    FieldMaskingRule {
+        role_name: role_name.to_owned(),
+        classification: rule.classification,
+        masking_mode: rule.masking_mode,
+        updated_by_subject: updated_by_subject.to_owned(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn rule_input(
+    classification: FieldClassification,
+    masking_mode: FieldMaskingMode,
+) -> FieldMaskingRuleInput {
+    FieldMaskingRuleInput {
+        classification,
+        masking_mode,
+    }
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    permissions: Vec<Permission>,
+) -> (FieldMaskingService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([((tenant_id, "alice".to_owned()), permissions)]),
+        }),
+        audit_repository.clone(),
+    );
+    let service = FieldMaskingService::new(
+        authorization_service,
+        Arc::new(FakeFieldMaskingRepository::default()),
+        audit_repository.clone(),
+    );
+    (service, audit_repository)
+}
+
+#[tokio::test]
+async fn masking_rules_require_role_manage_permission_and_are_audited() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("alice", "alice", None, tenant_id);
+    let input = SaveFieldMaskingRulesInput {
+        role_name: " support ".to_owned(),
+        rules: vec![
+            rule_input(FieldClassification::Pii, FieldMaskingMode::Partial),
+            rule_input(FieldClassification::Phi, FieldMaskingMode::Hidden),
+        ],
+    };
+
+    let (service, _) = build_service(tenant_id, vec![Permission::SecurityAuditRead]);
+    let forbidden = service.save_role_masking_rules(&actor, input.clone()).await;
+    assert!(matches!(forbidden, Err(AppError::Forbidden(_))));
+
+    let (service, audit_repository) =
+        build_service(tenant_id, vec![Permission::SecurityRoleManage]);
+    let saved = service
+        .save_role_masking_rules(&actor, input)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(saved.len(), 2);
+    assert!(saved.iter().all(|rule| rule.role_name == "support"));
+    assert_eq!(
+        service
+            .list_masking_rules(&actor, Some("support"))
+            .await
+            .unwrap_or_else(|_| unreachable!()),
+        saved
+    );
+
+    let events = audit_repository.events.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0].action,
+        AuditAction::SecurityFieldMaskingRulesSaved
+    );
+    assert_eq!(events[0].resource_id, "support");
+}
+
+#[tokio::test]
+async fn masking_rules_reject_blank_roles_and_repeated_classifications() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("alice", "alice", None, tenant_id);
+    let (service, audit_repository) =
+        build_service(tenant_id, vec![Permission::SecurityRoleManage]);
+
+    let blank_role = service
+        .save_role_masking_rules(
+            &actor,
+            SaveFieldMaskingRulesInput {
+                role_name: "  ".to_owned(),
+                rules: Vec::new(),
+            },
+        )
+        .await;
+    assert!(matches!(blank_role, Err(AppError::Validation(_))));
+
+    let repeated = service
+        .save_role_masking_rules(
+            &actor,
+            SaveFieldMaskingRulesInput {
+                role_name: "support".to_owned(),
+                rules: vec![
+                    rule_input(FieldClassification::Pii, FieldMaskingMode::Full),
+                    rule_input(FieldClassification::Pii, FieldMaskingMode::Hidden),
+                ],
+            },
+        )
+        .await;
+    assert!(matches!(repeated, Err(AppError::Validation(_))));
+    assert!(audit_repository.events.lock().await.is_empty());
+}
+
+#[test]
+fn effective_modes_use_the_least_restrictive_rule_of_held_roles() {
+    let rules = vec![
+        masking_rule(
+            "support",
+            rule_input(FieldClassification::Pii, FieldMaskingMode::Hidden),
+            "alice",
+        ),
+        masking_rule(
+            "billing",
+            rule_input(FieldClassification::Pii, FieldMaskingMode::Partial),
+            "alice",
+        ),
+        masking_rule(
+            "billing",
+            rule_input(FieldClassification::Confidential, FieldMaskingMode::Full),
+            "alice",
+        ),
+        masking_rule(
+            "auditor",
+            rule_input(FieldClassification::Phi, FieldMaskingMode::Hidden),
+            "alice",
+        ),
+    ];
+
+    let support_only = effective_field_masking_modes(
+        &rules,
+        &BTreeSet::from(["support".to_owned(), "reader".to_owned()]),
+    );
+    assert_eq!(
+        support_only,
+        BTreeMap::from([(FieldClassification::Pii, FieldMaskingMode::Hidden)])
+    );
+
+    let support_and_billing = effective_field_masking_modes(
+        &rules,
+        &BTreeSet::from(["support".to_owned(), "billing".to_owned()]),
+    );
+    assert_eq!(
+        support_and_billing,
+        BTreeMap::from([
+            (FieldClassification::Pii, FieldMaskingMode::Partial),
+            (FieldClassification::Confidential, FieldMaskingMode::Full),
+        ])
+    );
+}
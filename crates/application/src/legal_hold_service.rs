@@ -0,0 +1,14 @@
+//! Compliance legal holds on runtime records and audit data.
+//!
+//! A hold names a set of runtime records or an audit log range. While the
+//! hold is active, record deletes and audit retention purges skip the held
+//! items; releasing the hold restores normal retention behavior.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{CreateLegalHoldInput, LegalHold, LegalHoldRepository, LegalHoldScope};
+pub use service::LegalHoldService;
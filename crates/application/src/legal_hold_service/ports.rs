@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppResult, TenantId};
+
+/// Items covered by one legal hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LegalHoldScope {
+    /// Runtime records of one entity; an empty id list holds every record.
+    RuntimeRecords {
+        /// Entity logical name.
+        entity_logical_name: String,
+        /// Held record ids, or empty for the whole entity.
+        record_ids: Vec<String>,
+    },
+    /// Audit log entries created within a time range.
+    AuditLog {
+        /// Inclusive range start.
+        from: DateTime<Utc>,
+        /// Exclusive range end, or open-ended when absent.
+        until: Option<DateTime<Utc>>,
+    },
+}
+
+impl LegalHoldScope {
+    /// Returns a stable storage value for the scope kind.
+    #[must_use]
+    pub fn scope_type(&self) -> &'static str {
+        match self {
+            Self::RuntimeRecords { .. } => "runtime_records",
+            Self::AuditLog { .. } => "audit_log",
+        }
+    }
+
+    /// Returns whether this scope covers the given runtime record.
+    #[must_use]
+    pub fn covers_runtime_record(&self, entity_logical_name: &str, record_id: &str) -> bool {
+        match self {
+            Self::RuntimeRecords {
+                entity_logical_name: held_entity,
+                record_ids,
+            } => {
+                held_entity == entity_logical_name
+                    && (record_ids.is_empty() || record_ids.iter().any(|id| id == record_id))
+            }
+            Self::AuditLog { .. } => false,
+        }
+    }
+}
+
+/// Stored legal hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegalHold {
+    /// Stable hold identifier.
+    pub hold_id: String,
+    /// Owning tenant.
+    pub tenant_id: TenantId,
+    /// Short hold name, e.g. a matter or case reference.
+    pub name: String,
+    /// Reason recorded for auditors.
+    pub reason: String,
+    /// Held items.
+    pub scope: LegalHoldScope,
+    /// Subject that placed the hold.
+    pub created_by_subject: String,
+    /// Placement timestamp.
+    pub created_at: DateTime<Utc>,
+    /// Subject that released the hold.
+    pub released_by_subject: Option<String>,
+    /// Release timestamp, absent while the hold is active.
+    pub released_at: Option<DateTime<Utc>>,
+}
+
+impl LegalHold {
+    /// Returns whether the hold is still in effect.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.released_at.is_none()
+    }
+}
+
+/// Input payload for placing a legal hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateLegalHoldInput {
+    /// Short hold name.
+    pub name: String,
+    /// Reason recorded for auditors.
+    pub reason: String,
+    /// Held items.
+    pub scope: LegalHoldScope,
+}
+
+/// Repository port for legal holds.
+#[async_trait]
+pub trait LegalHoldRepository: Send + Sync {
+    /// Stores a new active hold.
+    async fn create_hold(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        input: CreateLegalHoldInput,
+    ) -> AppResult<LegalHold>;
+
+    /// Lists holds for a tenant, newest first.
+    async fn list_holds(
+        &self,
+        tenant_id: TenantId,
+        include_released: bool,
+    ) -> AppResult<Vec<LegalHold>>;
+
+    /// Releases an active hold, returning `None` when no active hold matches.
+    async fn release_hold(
+        &self,
+        tenant_id: TenantId,
+        hold_id: &str,
+        released_by_subject: &str,
+    ) -> AppResult<Option<LegalHold>>;
+
+    /// Finds an active hold covering one runtime record.
+    async fn find_active_record_hold(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<LegalHold>>;
+}
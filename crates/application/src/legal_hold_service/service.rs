@@ -0,0 +1,188 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::{CreateLegalHoldInput, LegalHold, LegalHoldRepository, LegalHoldScope};
+
+const MAX_HOLD_NAME_LENGTH: usize = 120;
+const MAX_HELD_RECORD_IDS: usize = 1000;
+
+/// Application service for placing and releasing compliance legal holds.
+#[derive(Clone)]
+pub struct LegalHoldService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn LegalHoldRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl LegalHoldService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn LegalHoldRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Lists legal holds for the actor tenant.
+    pub async fn list_holds(
+        &self,
+        actor: &UserIdentity,
+        include_released: bool,
+    ) -> AppResult<Vec<LegalHold>> {
+        self.require_legal_hold_manage_permission(actor).await?;
+        self.repository
+            .list_holds(actor.tenant_id(), include_released)
+            .await
+    }
+
+    /// Places a legal hold on runtime records or an audit log range.
+    pub async fn place_hold(
+        &self,
+        actor: &UserIdentity,
+        input: CreateLegalHoldInput,
+    ) -> AppResult<LegalHold> {
+        self.require_legal_hold_manage_permission(actor).await?;
+
+        let input = normalize_hold_input(input)?;
+        let hold = self
+            .repository
+            .create_hold(actor.tenant_id(), actor.subject(), input)
+            .await?;
+
+        self.append_hold_audit_event(actor, AuditAction::SecurityLegalHoldPlaced, &hold)
+            .await?;
+
+        Ok(hold)
+    }
+
+    /// Releases an active legal hold.
+    pub async fn release_hold(&self, actor: &UserIdentity, hold_id: &str) -> AppResult<LegalHold> {
+        self.require_legal_hold_manage_permission(actor).await?;
+
+        let hold = self
+            .repository
+            .release_hold(actor.tenant_id(), hold_id, actor.subject())
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("active legal hold '{hold_id}' does not exist"))
+            })?;
+
+        self.append_hold_audit_event(actor, AuditAction::SecurityLegalHoldReleased, &hold)
+            .await?;
+
+        Ok(hold)
+    }
+
+    async fn require_legal_hold_manage_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::SecurityLegalHoldManage,
+            )
+            .await
+    }
+
+    async fn append_hold_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        hold: &LegalHold,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "legal_hold".to_owned(),
+                resource_id: hold.hold_id.clone(),
+                detail: Some(
+                    serde_json::json!({
+                        "name": hold.name,
+                        "scope_type": hold.scope.scope_type(),
+                    })
+                    .to_string(),
+                ),
+            })
+            .await
+    }
+}
+
+fn normalize_hold_input(input: CreateLegalHoldInput) -> AppResult<CreateLegalHoldInput> {
+    let name = input.name.trim().to_owned();
+    if name.is_empty() || name.chars().count() > MAX_HOLD_NAME_LENGTH {
+        return Err(AppError::Validation(format!(
+            "legal hold name must be between 1 and {MAX_HOLD_NAME_LENGTH} characters"
+        )));
+    }
+
+    let reason = input.reason.trim().to_owned();
+    if reason.is_empty() {
+        return Err(AppError::Validation(
+            "legal hold reason is required".to_owned(),
+        ));
+    }
+
+    let scope = match input.scope {
+        LegalHoldScope::RuntimeRecords {
+            entity_logical_name,
+            record_ids,
+        } => {
+            let entity_logical_name = entity_logical_name.trim().to_owned();
+            if entity_logical_name.is_empty() {
+                return Err(AppError::Validation(
+                    "legal hold entity_logical_name is required".to_owned(),
+                ));
+            }
+
+            let mut seen = BTreeSet::new();
+            let mut normalized_ids = Vec::new();
+            for record_id in record_ids {
+                let record_id = record_id.trim().to_owned();
+                if record_id.is_empty() {
+                    return Err(AppError::Validation(
+                        "legal hold record ids must not be empty".to_owned(),
+                    ));
+                }
+                if seen.insert(record_id.clone()) {
+                    normalized_ids.push(record_id);
+                }
+            }
+            if normalized_ids.len() > MAX_HELD_RECORD_IDS {
+                return Err(AppError::Validation(format!(
+                    "legal hold cannot list more than {MAX_HELD_RECORD_IDS} record ids; hold the whole entity instead"
+                )));
+            }
+
+            LegalHoldScope::RuntimeRecords {
+                entity_logical_name,
+                record_ids: normalized_ids,
+            }
+        }
+        LegalHoldScope::AuditLog { from, until } => {
+            if until.is_some_and(|until| until <= from) {
+                return Err(AppError::Validation(
+                    "legal hold audit range end must be after its start".to_owned(),
+                ));
+            }
+            LegalHoldScope::AuditLog { from, until }
+        }
+    };
+
+    Ok(CreateLegalHoldInput {
+        name,
+        reason,
+        scope,
+    })
+}
@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    TemporaryPermissionGrant,
+};
+
+use super::{
+    CreateLegalHoldInput, LegalHold, LegalHoldRepository, LegalHoldScope, LegalHoldService,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeLegalHoldRepository {
+    holds: Mutex<Vec<LegalHold>>,
+}
+
+#[async_trait]
+impl LegalHoldRepository for FakeLegalHoldRepository {
+    async fn create_hold(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        input: CreateLegalHoldInput,
+    ) -> AppResult<LegalHold> {
+        let mut holds = self.holds.lock().await;
+        let hold = LegalHold {
+            hold_id: format!("hold-{}", holds.len() + 1),
+            tenant_id,
+            name: input.name,
+            reason: input.reason,
+            scope: input.scope,
+            created_by_subject: created_by_subject.to_owned(),
+            created_at: Utc::now(),
+            released_by_subject: None,
+            released_at: None,
+        };
+        holds.push(hold.clone());
+        Ok(hold)
+    }
+
+    async fn list_holds(
+        &self,
+        tenant_id: TenantId,
+        include_released: bool,
+    ) -> AppResult<Vec<LegalHold>> {
+        Ok(self
+            .holds
+            .lock()
+            .await
+            .iter()
+            .filter(|hold| hold.tenant_id == tenant_id && (include_released || hold.is_active()))
+            .cloned()
+            .collect())
+    }
+
+    async fn release_hold(
+        &self,
+        tenant_id: TenantId,
+        hold_id: &str,
+        released_by_subject: &str,
+    ) -> AppResult<Option<LegalHold>> {
+        let mut holds = self.holds.lock().await;
+        let Some(hold) = holds.iter_mut().find(|hold| {
+            hold.tenant_id == tenant_id && hold.hold_id == hold_id && hold.is_active()
+        }) else {
+            return Ok(None);
+        };
+        hold.released_at = Some(Utc::now());
+        hold.released_by_subject = Some(released_by_subject.to_owned());
+        Ok(Some(hold.clone()))
+    }
+
+    async fn find_active_record_hold(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<LegalHold>> {
+        Ok(self
+            .holds
+            .lock()
+            .await
+            .iter()
+            .find(|hold| {
+                hold.tenant_id == tenant_id
+                    && hold.is_active()
+                    && hold
+                        .scope
+                        .covers_runtime_record(entity_logical_name, record_id)
+            })
+            .cloned())
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    subject: &str,
+    permissions: Vec<Permission>,
+    repository: Arc<FakeLegalHoldRepository>,
+) -> (LegalHoldService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([((tenant_id, subject.to_owned()), permissions)]),
+        }),
+        audit_repository.clone(),
+    );
+    let service =
+        LegalHoldService::new(authorization_service, repository, audit_repository.clone());
+    (service, audit_repository)
+}
+
+fn record_hold_input(record_ids: Vec<&str>) -> CreateLegalHoldInput {
+    CreateLegalHoldInput {
+        name: " Matter 42 ".to_owned(),
+        reason: "pending litigation".to_owned(),
+        scope: LegalHoldScope::RuntimeRecords {
+            entity_logical_name: "contact".to_owned(),
+            record_ids: record_ids.into_iter().map(str::to_owned).collect(),
+        },
+    }
+}
+
+#[tokio::test]
+async fn placing_holds_requires_legal_hold_permission() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeLegalHoldRepository::default());
+    let (service, _) = build_service(
+        tenant_id,
+        "alice",
+        vec![Permission::SecurityRoleManage],
+        repository.clone(),
+    );
+
+    let result = service
+        .place_hold(&actor(tenant_id, "alice"), record_hold_input(Vec::new()))
+        .await;
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+    assert!(repository.holds.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn place_hold_normalizes_input_and_rejects_invalid_scopes() {
+    let tenant_id = TenantId::new();
+    let (service, _) = build_service(
+        tenant_id,
+        "alice",
+        vec![Permission::SecurityLegalHoldManage],
+        Arc::new(FakeLegalHoldRepository::default()),
+    );
+    let actor = actor(tenant_id, "alice");
+
+    let hold = service
+        .place_hold(&actor, record_hold_input(vec![" r-1 ", "r-1", "r-2"]))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(hold.name, "Matter 42");
+    assert_eq!(
+        hold.scope,
+        LegalHoldScope::RuntimeRecords {
+            entity_logical_name: "contact".to_owned(),
+            record_ids: vec!["r-1".to_owned(), "r-2".to_owned()],
+        }
+    );
+
+    let blank_reason = service
+        .place_hold(
+            &actor,
+            CreateLegalHoldInput {
+                reason: "  ".to_owned(),
+                ..record_hold_input(Vec::new())
+            },
+        )
+        .await;
+    assert!(matches!(blank_reason, Err(AppError::Validation(_))));
+
+    let now = Utc::now();
+    let inverted_range = service
+        .place_hold(
+            &actor,
+            CreateLegalHoldInput {
+                scope: LegalHoldScope::AuditLog {
+                    from: now,
+                    until: Some(now - Duration::days(1)),
+                },
+                ..record_hold_input(Vec::new())
+            },
+        )
+        .await;
+    assert!(matches!(inverted_range, Err(AppError::Validation(_))));
+}
+
+#[tokio::test]
+async fn released_holds_are_audited_and_hidden_from_active_list() {
+    let tenant_id = TenantId::new();
+    let (service, audit_repository) = build_service(
+        tenant_id,
+        "alice",
+        vec![Permission::SecurityLegalHoldManage],
+        Arc::new(FakeLegalHoldRepository::default()),
+    );
+    let actor = actor(tenant_id, "alice");
+
+    let hold = service
+        .place_hold(&actor, record_hold_input(Vec::new()))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let released = service
+        .release_hold(&actor, hold.hold_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(released.released_by_subject.as_deref(), Some("alice"));
+
+    let released_again = service.release_hold(&actor, hold.hold_id.as_str()).await;
+    assert!(matches!(released_again, Err(AppError::NotFound(_))));
+
+    let active = service
+        .list_holds(&actor, false)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(active.is_empty());
+    let all = service
+        .list_holds(&actor, true)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(all.len(), 1);
+
+    let events = audit_repository.events.lock().await;
+    let actions: Vec<AuditAction> = events.iter().map(|event| event.action).collect();
+    assert_eq!(
+        actions,
+        vec![
+            AuditAction::SecurityLegalHoldPlaced,
+            AuditAction::SecurityLegalHoldReleased,
+        ]
+    );
+}
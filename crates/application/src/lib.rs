@@ -2,40 +2,110 @@
 
 #![forbid(unsafe_code)]
 
+mod announcement_service;
 mod app_ports;
 mod app_service;
+mod audit_export_service;
 mod auth_event_service;
 mod auth_token_service;
 mod authorization_service;
+mod background_job_service;
+mod compliance_zone_service;
 mod contact_bootstrap_service;
+mod contact_consent_service;
+mod contact_identity_service;
+mod data_erasure_service;
+mod data_validation_service;
+mod email_change_service;
 mod extension_ports;
 mod extension_service;
+mod feature_flag_service;
+mod field_change_approval_service;
+mod field_masking_service;
+mod legal_hold_service;
+mod localization_service;
 mod metadata_ports;
 mod metadata_service;
 mod mfa_service;
+mod operation_timeouts;
+mod operator_console_service;
+mod personal_data_export_service;
+mod publish_coordination_service;
 mod rate_limit_service;
+mod record_access_service;
+mod record_import_service;
+mod record_share_link_service;
+mod report_subscription_service;
+mod reporting_projection_service;
 mod security_admin_ports;
 mod security_admin_service;
 mod tenant_access_service;
+mod tenant_data_export_service;
+mod tenant_encryption_service;
 mod user_service;
 mod workflow_ports;
 mod workflow_service;
 
+pub use announcement_service::{
+    Announcement, AnnouncementRepository, AnnouncementService, AnnouncementSeverity,
+    SaveAnnouncementInput,
+};
 pub use app_ports::{
-    AppEntityFormInput, AppEntityViewInput, AppRepository, BindAppEntityInput, CreateAppInput,
-    RuntimeRecordService, SaveAppRoleEntityPermissionInput, SaveAppSitemapInput,
+    AppAdminGrant, AppEntityCapabilitySummary, AppEntityFormInput, AppEntityViewInput,
+    AppRepository, BindAppEntityInput, CreateAppInput, EntityPresentation, PersonalView,
+    RUNTIME_RECORD_BULK_UPDATE_MAX_RECORDS, RuntimeRecordBulkUpdateInput,
+    RuntimeRecordBulkUpdateItem, RuntimeRecordBulkUpdateResult, RuntimeRecordService,
+    SaveAppRoleEntityPermissionInput, SaveAppSitemapInput, SavePersonalViewInput,
     SubjectEntityPermission,
 };
 pub use app_service::AppService;
+pub use audit_export_service::{
+    AUDIT_EXPORT_MAX_SINKS_PER_TENANT, AuditExportService, AuditExportShipper, AuditExportSink,
+    AuditExportSinkConfig, AuditExportSinkRepository, AuditExportSyslogTransport,
+    ClaimedAuditExportSink, CreateAuditExportSinkInput,
+};
 pub use auth_event_service::{AuthEvent, AuthEventRepository, AuthEventService};
 pub use auth_token_service::{
-    AuthTokenRecord, AuthTokenRepository, AuthTokenService, EmailService,
+    AuthTokenRecord, AuthTokenRepository, AuthTokenService, EmailAttachment, EmailService,
+    IssuedBootstrapToken,
 };
 pub use authorization_service::{
-    AuthorizationRepository, AuthorizationService, RuntimeFieldAccess, RuntimeFieldGrant,
-    TemporaryPermissionGrant,
+    AccessDecision, AccessExplanation, AuthorizationRepository, AuthorizationService,
+    PermissionCheck, RuntimeFieldAccess, RuntimeFieldGrant, SubjectAppRolePermission,
+    SubjectRoleGrant, TemporaryPermissionGrant,
+};
+pub use background_job_service::{
+    BACKGROUND_JOB_MAX_ATTEMPTS, BackgroundJob, BackgroundJobKind, BackgroundJobLease,
+    BackgroundJobRepository, BackgroundJobService, BackgroundJobStage, BackgroundJobStatus,
+    CreateBackgroundJobInput,
+};
+pub use compliance_zone_service::{
+    ComplianceZoneAssignment, ComplianceZoneRepository, ComplianceZoneService, ComplianceZoneTag,
+    SaveComplianceZoneTagInput, normalize_compliance_zone,
 };
 pub use contact_bootstrap_service::ContactBootstrapService;
+pub use contact_consent_service::{
+    ConsentChannel, ContactConsent, ContactConsentChange, ContactConsentRepository,
+    ContactConsentService, ContactConsentStatus, MARKETING_CONSENT_PURPOSE,
+    RecordContactConsentInput,
+};
+pub use contact_identity_service::{
+    ContactIdentityKeys, ContactIdentityLink, ContactIdentityMatch, ContactIdentityRebuildSummary,
+    ContactIdentityRepository, ContactIdentityService, ContactIdentitySource, ContactMatchReason,
+    MasterContact, NewContactIdentityLink, SaveContactIdentitySourceInput,
+};
+pub use data_erasure_service::{
+    DataErasureField, DataErasureReport, DataErasureRepository, DataErasureService,
+    ERASED_SUBJECT_DISPLAY_NAME, SaveDataErasureFieldsInput, SubjectErasure, SubjectErasureOutcome,
+};
+pub use data_validation_service::{
+    DATA_VALIDATION_MAX_INTERVAL_DAYS, DataValidationRepository, DataValidationSchedule,
+    DataValidationService, DataValidationViolation, DueDataValidationSchedule,
+};
+pub use email_change_service::{
+    EmailChangeConfirmation, EmailChangeConfirmationOutcome, EmailChangeRepository,
+    EmailChangeService, PendingEmailChange, RequestEmailChangeParams,
+};
 pub use extension_ports::{
     ExecuteExtensionActionInput, ExtensionActionResult, ExtensionActionType, ExtensionRepository,
     ExtensionRuntime, RuntimeExtensionActionRequest,
@@ -43,46 +113,163 @@ pub use extension_ports::{
 pub use extension_service::{
     ExtensionCompatibilityReport, ExtensionService, RegisterExtensionInput,
 };
+pub use feature_flag_service::{
+    FeatureFlag, FeatureFlagRepository, FeatureFlagRoleOverride, FeatureFlagService,
+    SaveFeatureFlagInput, WORKFLOWS_FEATURE_FLAG,
+};
+pub use field_change_approval_service::{
+    DEFAULT_APPROVAL_WINDOW_HOURS, DualControlField, DualControlFieldInput,
+    FIELD_CHANGE_REQUESTED_APPROVAL_KEY, FieldChangeApprovalRepository, FieldChangeApprovalService,
+    NewPendingFieldChange, PendingFieldChange, PendingFieldChangeQuery, PendingFieldChangeStatus,
+    SaveDualControlFieldsInput,
+};
+pub use field_masking_service::{
+    FieldMaskingRepository, FieldMaskingRule, FieldMaskingRuleInput, FieldMaskingService,
+    SaveFieldMaskingRulesInput, effective_field_masking_modes,
+};
+pub use legal_hold_service::{
+    CreateLegalHoldInput, LegalHold, LegalHoldRepository, LegalHoldScope, LegalHoldService,
+};
+pub use localization_service::{LocalizationService, LocalizedLabelRepository};
 pub use metadata_ports::{
-    AuditEvent, AuditRepository, MetadataComponentsRepository, MetadataDefinitionsRepository,
-    MetadataPublishRepository, MetadataRepository, MetadataRepositoryByConcern,
-    MetadataRuntimeRepository, RecordListQuery, RuntimeRecordConditionGroup,
-    RuntimeRecordConditionNode, RuntimeRecordFilter, RuntimeRecordJoinType, RuntimeRecordLink,
-    RuntimeRecordLogicalMode, RuntimeRecordOperator, RuntimeRecordQuery, RuntimeRecordSort,
-    RuntimeRecordSortDirection, SaveBusinessRuleInput, SaveFieldInput, SaveFormInput,
-    SaveOptionSetInput, SaveViewInput, TenantMembership, TenantRepository, UniqueFieldValue,
-    UpdateEntityInput, UpdateFieldInput,
+    AuditEvent, AuditOutboxRelay, AuditRepository, EntityDependency, EntityDependencyKind,
+    EntityDependencyReport, EntityHistoryPolicy, EntitySchemaRollbackChecks, EntitySlugConfig,
+    EntityStatusConfig, FieldImpact, FieldImpactKind, FieldImpactReport, FieldImpactSeverity,
+    MetadataComponentsRepository, MetadataDefinitionsRepository, MetadataPublishRepository,
+    MetadataRepository, MetadataRepositoryByConcern, MetadataRuntimeRepository,
+    NewRuntimeRecordHistoryEntry, NewRuntimeRecordStatusChange, PublishedEntitySchemaVersion,
+    RECORD_HISTORY_MAX_RETENTION_DAYS, RUNTIME_RECORD_AGGREGATE_MAX_AGGREGATES,
+    RUNTIME_RECORD_AGGREGATE_MAX_GROUP_FIELDS, RUNTIME_RECORD_CHANGESET_MAX_OPERATIONS,
+    RecordListQuery, RelationBehavior, RelationCascadeResult, RelationDeletePlan,
+    RelationDeletedRecord, RelationLookupConfig, RelationLookupMatch, RelationRelinkedRecord,
+    RuntimeRecordAggregate, RuntimeRecordAggregateFunction, RuntimeRecordAggregateHaving,
+    RuntimeRecordAggregateQuery, RuntimeRecordAggregateRow, RuntimeRecordAggregateSort,
+    RuntimeRecordAggregateSortKey, RuntimeRecordAssociation, RuntimeRecordChangesetMethod,
+    RuntimeRecordChangesetOperation, RuntimeRecordChangesetResult, RuntimeRecordChangesetWrite,
+    RuntimeRecordConditionGroup, RuntimeRecordConditionNode, RuntimeRecordFieldChange,
+    RuntimeRecordFilter, RuntimeRecordGroupBy, RuntimeRecordHistoryEntry, RuntimeRecordJoinType,
+    RuntimeRecordLink, RuntimeRecordLinkCardinality, RuntimeRecordLogicalMode,
+    RuntimeRecordOperator, RuntimeRecordOwnerAssignment, RuntimeRecordOwnerDirectory,
+    RuntimeRecordQuery, RuntimeRecordSort, RuntimeRecordSortDirection, RuntimeRecordStatusChange,
+    RuntimeRecordUpsertOutcome, RuntimeRecordUpsertResult, RuntimeViewCacheKey,
+    RuntimeViewCacheLookup, RuntimeViewResultCache, SaveBusinessRuleInput,
+    SaveDuplicateDetectionRuleInput, SaveEntityHistoryPolicyInput, SaveEntitySlugConfigInput,
+    SaveEntityStatusModelInput, SaveFieldInput, SaveFormInput, SaveOptionSetInput,
+    SaveRelationBehaviorInput, SaveRelationLookupConfigInput, SaveViewInput, TenantMembership,
+    TenantRepository, UniqueFieldValue, UpdateEntityInput, UpdateFieldInput,
 };
 pub use metadata_service::{
     ExportWorkspaceBundleOptions, ImportWorkspaceBundleOptions, ImportWorkspaceBundleResult,
-    MetadataService, PortableEntityBundle, PortableRuntimeRecord, WorkspacePortableBundle,
+    MetadataService, PortableEntityBundle, PortableRuntimeRecord, RUNTIME_RECORD_EXPORT_PAGE_SIZE,
+    RuntimeRecordExport, RuntimeRecordExportColumn, RuntimeRecordValidationPage,
+    RuntimeRecordValidationRule, RuntimeRecordViolation, WorkspacePortableBundle,
     WorkspacePortablePayload,
 };
 pub use mfa_service::{MfaService, SecretEncryptor, TotpEnrollment, TotpProvider};
+pub use operation_timeouts::{
+    OperationDeadline, OperationDeadlines, OperationTimeouts, OperationTimer, TimedOperation,
+    ensure_operation_budget_remaining, remaining_operation_budget,
+};
+pub use operator_console_service::{
+    OPERATOR_AUDIT_LOG_MAX_LIMIT, OPERATOR_TENANT_LIST_MAX_LIMIT, OperatorAuditEvent,
+    OperatorConsoleRepository, OperatorConsoleService, OperatorMaintenanceAction,
+    OperatorMaintenanceResult, OperatorTenantHealth, OperatorTenantUsage, OperatorUserLookup,
+};
+pub use personal_data_export_service::{
+    PERSONAL_DATA_EXPORT_STAGES, PersonalAuthEventEntry, PersonalDataArchive,
+    PersonalDataExportPart, PersonalDataExportRepository, PersonalDataExportSection,
+    PersonalDataExportService,
+};
+pub use publish_coordination_service::{
+    CreatePublishIntentInput, PUBLISH_DRAFT_CHANGED_PREFIX, PUBLISH_LOCKED_PREFIX,
+    PublishCoordinationRepository, PublishCoordinationService, PublishIntent, PublishIntentStatus,
+    PublishLock, PublishLockAcquisition, PublishLockLease, PublishTarget, PublishTargetScope,
+    ReviewedDraft, draft_fingerprint,
+};
 pub use qryvanta_domain::{AuthEventOutcome, AuthEventType};
-pub use rate_limit_service::{AttemptInfo, RateLimitRepository, RateLimitRule, RateLimitService};
+pub use rate_limit_service::{
+    AttemptInfo, RateLimitRepository, RateLimitRule, RateLimitService, RateLimitStatus,
+    TokenBucketDecision, TokenBucketRepository, TokenBucketRule,
+};
+pub use record_access_service::{
+    DEFAULT_RECORD_SHARE_HOURS, NewRecordAccessRequest, RECORD_ACCESS_DECIDED_APPROVAL_KEY,
+    RECORD_ACCESS_REQUESTED_APPROVAL_KEY, RecordAccessRepository, RecordAccessRequest,
+    RecordAccessRequestQuery, RecordAccessRequestStatus, RecordAccessService, RecordShare,
+    RequestRecordAccessInput,
+};
+pub use record_import_service::{
+    CreateRecordImportScheduleInput, DueRecordImportSchedule, NewRecordImportSchedule,
+    RECORD_IMPORT_MAX_FILE_BYTES, RECORD_IMPORT_MAX_ROWS, RECORD_IMPORT_MAX_SCHEDULES_PER_TENANT,
+    RecordImportColumnMapping, RecordImportFrequency, RecordImportMappingTemplate,
+    RecordImportRepository, RecordImportRowError, RecordImportRunStatus,
+    RecordImportRuntimeService, RecordImportSchedule, RecordImportScheduleRun, RecordImportService,
+    RecordImportSourceFetcher, RecordImportSourceKind, RecordImportSummary,
+    SaveRecordImportMappingTemplateInput,
+};
+pub use record_share_link_service::{
+    CreateRecordShareLinkInput, CreatedRecordShareLink, DEFAULT_RECORD_SHARE_LINK_HOURS,
+    NewRecordShareLink, RecordShareLink, RecordShareLinkAccess, RecordShareLinkRepository,
+    RecordShareLinkService, RecordShareLinkSummary, RecordShareLinkSummaryField,
+    RecordShareLinkView, RecordShareLinkViewContext, RecordShareLinkViewOutcome,
+};
+pub use report_subscription_service::{
+    CreateReportSubscriptionInput, DEFAULT_REPORT_SUBSCRIPTION_TENANT_LIMIT, DueReportSubscription,
+    NewReportSubscription, REPORT_SUBSCRIPTION_MAX_ROWS, ReportDelivery, ReportSubscription,
+    ReportSubscriptionFormat, ReportSubscriptionFrequency, ReportSubscriptionRepository,
+    ReportSubscriptionService,
+};
+pub use reporting_projection_service::{
+    NewReportingProjection, REPORTING_PROJECTION_MAX_COLUMNS, REPORTING_PROJECTION_MAX_PER_TENANT,
+    REPORTING_PROJECTION_MAX_QUERY_ROWS, ReportingProjection, ReportingProjectionColumn,
+    ReportingProjectionColumnInput, ReportingProjectionFilter, ReportingProjectionQuery,
+    ReportingProjectionRepository, ReportingProjectionRow, ReportingProjectionRowUpdate,
+    ReportingProjectionRuntimeService, ReportingProjectionService, RuntimeRecordChange,
+    SaveReportingProjectionInput,
+};
 pub use security_admin_ports::{
-    AuditIntegrityStatus, AuditLogEntry, AuditLogQuery, AuditLogRepository, AuditPurgeResult,
-    AuditRetentionPolicy, CreateRoleInput, CreateTemporaryAccessGrantInput, RoleAssignment,
-    RoleDefinition, RuntimeFieldPermissionEntry, RuntimeFieldPermissionInput,
-    SaveRuntimeFieldPermissionsInput, SecurityAdminRepository, TemporaryAccessGrant,
-    TemporaryAccessGrantQuery, WorkspacePublishRunAuditInput,
+    AuditChainAnchor, AuditChainAnchorReason, AuditChainVerificationScope, AuditIntegrityStatus,
+    AuditLogEntry, AuditLogQuery, AuditLogRepository, AuditPurgePlan, AuditPurgeResult,
+    AuditRetentionPolicy, CreateMfaResetRequestInput, CreateRoleInput,
+    CreateTemporaryAccessGrantInput, MfaResetRequest, RoleAssignment, RoleDefinition,
+    RuntimeFieldPermissionEntry, RuntimeFieldPermissionInput, SaveRuntimeFieldPermissionsInput,
+    SecurityAdminRepository, SecurityTeam, SecurityTeamMember, TemporaryAccessGrant,
+    TemporaryAccessGrantQuery, WorkflowConcurrencyPolicy, WorkspacePublishRunAuditInput,
 };
 pub use security_admin_service::SecurityAdminService;
-pub use tenant_access_service::{TenantAccessService, TenantSelection};
+pub use tenant_access_service::{SessionTenantTransition, TenantAccessService, TenantSelection};
+pub use tenant_data_export_service::{
+    CreatedTenantDataExportLink, NewTenantDataExportLink, TENANT_DATA_EXPORT_STAGES,
+    TenantDataArchive, TenantDataExportLink, TenantDataExportPart, TenantDataExportRepository,
+    TenantDataExportSection, TenantDataExportService, TenantMemberEntry,
+};
+pub use tenant_encryption_service::{
+    TenantEncryptionKey, TenantEncryptionKeyRepository, TenantEncryptionKeyStatus,
+    TenantEncryptionService, TenantKeyMaterial, TenantKeyMaterialProvider,
+};
 pub use user_service::{
     AuthOutcome, PasswordHasher, RegisterParams, UserRecord, UserRepository, UserService,
 };
 pub use workflow_ports::{
     ClaimedRuntimeRecordWorkflowEvent, ClaimedWorkflowJob, ClaimedWorkflowScheduleTick,
-    CompleteWorkflowRunInput, CreateWorkflowRunInput, RuntimeRecordWorkflowEventDrainResult,
-    RuntimeRecordWorkflowEventInput, SaveWorkflowInput, WorkflowActionDispatchRequest,
-    WorkflowActionDispatchType, WorkflowActionDispatcher, WorkflowClaimPartition,
-    WorkflowDelayService, WorkflowExecutionMode, WorkflowQueueStats, WorkflowQueueStatsCache,
-    WorkflowQueueStatsQuery, WorkflowRepository, WorkflowRun, WorkflowRunAttempt,
-    WorkflowRunAttemptStatus, WorkflowRunListQuery, WorkflowRunReplay,
-    WorkflowRunReplayTimelineEvent, WorkflowRunStatus, WorkflowRunStepTrace,
-    WorkflowRuntimeRecordService, WorkflowScheduleTickDrainResult, WorkflowScheduledTrigger,
+    CompleteWorkflowRunInput, CreateWorkflowRunInput, CreatedWorkflowInboundWebhook,
+    NewWorkflowBulkExecution, NewWorkflowInboundWebhook, RuntimeRecordWorkflowEventDrainResult,
+    RuntimeRecordWorkflowEventInput, SaveWorkflowInput, StartWorkflowBulkExecutionInput,
+    SuspendWorkflowRunInput, WORKFLOW_BULK_EXECUTION_MAX_RECORDS,
+    WORKFLOW_INBOUND_WEBHOOK_TOLERANCE_SECONDS, WORKFLOW_STUCK_JOB_DEFAULT_LIMIT,
+    WORKFLOW_STUCK_JOB_MAX_LIMIT, WorkflowActionCredential, WorkflowActionDispatchRequest,
+    WorkflowActionDispatchResponse, WorkflowActionDispatchType, WorkflowActionDispatcher,
+    WorkflowBulkExecution, WorkflowBulkExecutionReport, WorkflowBulkExecutionRunCounts,
+    WorkflowClaimAdvice, WorkflowClaimBackpressurePolicy, WorkflowClaimLoadSignal,
+    WorkflowClaimPartition, WorkflowCredentialRecord, WorkflowCredentialRepository,
+    WorkflowDelayService, WorkflowExecutionMode, WorkflowInboundWebhook,
+    WorkflowInboundWebhookCredentials, WorkflowInboundWebhookDelivery,
+    WorkflowInboundWebhookRepository, WorkflowQueueStats, WorkflowQueueStatsCache,
+    WorkflowQueueStatsQuery, WorkflowQueueStatsSnapshot, WorkflowRepository, WorkflowRun,
+    WorkflowRunAttempt, WorkflowRunAttemptStatus, WorkflowRunContinuation, WorkflowRunListQuery,
+    WorkflowRunReplay, WorkflowRunReplayTimelineEvent, WorkflowRunStatus, WorkflowRunStepTrace,
+    WorkflowRuntimeRecordService, WorkflowScheduleKind, WorkflowScheduleTickDrainResult,
+    WorkflowScheduledTrigger, WorkflowStuckJob, WorkflowTraceContextSource,
     WorkflowWorkerHeartbeatInput, WorkflowWorkerLease, WorkflowWorkerLeaseCoordinator,
+    WorkflowWorkerLeaseOwnership,
 };
 pub use workflow_service::WorkflowService;
@@ -0,0 +1,14 @@
+//! Localized display labels for workflows, business rules, roles and apps.
+//!
+//! Labels translate a component's display name and description per locale.
+//! List endpoints resolve them against the caller's preferred locales while
+//! the source metadata keeps its original names.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::LocalizedLabelRepository;
+pub use service::LocalizationService;
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+
+use qryvanta_core::{AppResult, TenantId};
+use qryvanta_domain::{LocaleTag, LocalizedComponentType, LocalizedDisplayLabel};
+
+/// Repository port for localized display labels.
+#[async_trait]
+pub trait LocalizedLabelRepository: Send + Sync {
+    /// Creates or replaces the label for one component and locale.
+    async fn save_localized_label(
+        &self,
+        tenant_id: TenantId,
+        label: LocalizedDisplayLabel,
+    ) -> AppResult<()>;
+
+    /// Deletes the label for one component and locale, returning whether it existed.
+    async fn delete_localized_label(
+        &self,
+        tenant_id: TenantId,
+        component_type: LocalizedComponentType,
+        component_key: &str,
+        locale: &LocaleTag,
+    ) -> AppResult<bool>;
+
+    /// Lists labels of one component type, optionally narrowed to one component.
+    async fn list_localized_labels(
+        &self,
+        tenant_id: TenantId,
+        component_type: LocalizedComponentType,
+        component_key: Option<&str>,
+    ) -> AppResult<Vec<LocalizedDisplayLabel>>;
+}
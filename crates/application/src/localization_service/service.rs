@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{
+    AuditAction, LocaleTag, LocalizedComponentType, LocalizedDisplayLabel, Permission,
+    select_localized_display_label,
+};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::LocalizedLabelRepository;
+
+/// Application service for managing and resolving localized display labels.
+#[derive(Clone)]
+pub struct LocalizationService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn LocalizedLabelRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl LocalizationService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn LocalizedLabelRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Lists every localized label of one component.
+    pub async fn list_component_labels(
+        &self,
+        actor: &UserIdentity,
+        component_type: LocalizedComponentType,
+        component_key: &str,
+    ) -> AppResult<Vec<LocalizedDisplayLabel>> {
+        self.require_component_manage_permission(actor, component_type)
+            .await?;
+        self.repository
+            .list_localized_labels(actor.tenant_id(), component_type, Some(component_key))
+            .await
+    }
+
+    /// Creates or replaces a localized label.
+    pub async fn save_label(
+        &self,
+        actor: &UserIdentity,
+        label: LocalizedDisplayLabel,
+    ) -> AppResult<LocalizedDisplayLabel> {
+        self.require_component_manage_permission(actor, label.component_type())
+            .await?;
+
+        self.repository
+            .save_localized_label(actor.tenant_id(), label.clone())
+            .await?;
+
+        self.append_label_audit_event(
+            actor,
+            AuditAction::MetadataLocalizedLabelSaved,
+            label.component_type(),
+            label.component_key().as_str(),
+            label.locale(),
+        )
+        .await?;
+
+        Ok(label)
+    }
+
+    /// Removes the label of one component for one locale.
+    pub async fn delete_label(
+        &self,
+        actor: &UserIdentity,
+        component_type: LocalizedComponentType,
+        component_key: &str,
+        locale: &LocaleTag,
+    ) -> AppResult<()> {
+        self.require_component_manage_permission(actor, component_type)
+            .await?;
+
+        if !self
+            .repository
+            .delete_localized_label(actor.tenant_id(), component_type, component_key, locale)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "{} '{}' has no localized label for locale '{}'",
+                component_type.as_str(),
+                component_key,
+                locale.as_str()
+            )));
+        }
+
+        self.append_label_audit_event(
+            actor,
+            AuditAction::MetadataLocalizedLabelDeleted,
+            component_type,
+            component_key,
+            locale,
+        )
+        .await
+    }
+
+    /// Resolves the best matching label for every component of one type,
+    /// keyed by component key.
+    ///
+    /// This backs list responses that the caller is already authorized to
+    /// read, so it performs no permission check of its own.
+    pub async fn resolve_labels(
+        &self,
+        actor: &UserIdentity,
+        component_type: LocalizedComponentType,
+        preferred_locales: &[LocaleTag],
+    ) -> AppResult<HashMap<String, LocalizedDisplayLabel>> {
+        if preferred_locales.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut labels_by_component: HashMap<String, Vec<LocalizedDisplayLabel>> = HashMap::new();
+        for label in self
+            .repository
+            .list_localized_labels(actor.tenant_id(), component_type, None)
+            .await?
+        {
+            labels_by_component
+                .entry(label.component_key().as_str().to_owned())
+                .or_default()
+                .push(label);
+        }
+
+        Ok(labels_by_component
+            .into_iter()
+            .filter_map(|(component_key, labels)| {
+                select_localized_display_label(&labels, preferred_locales)
+                    .cloned()
+                    .map(|label| (component_key, label))
+            })
+            .collect())
+    }
+
+    async fn require_component_manage_permission(
+        &self,
+        actor: &UserIdentity,
+        component_type: LocalizedComponentType,
+    ) -> AppResult<()> {
+        let permission = match component_type {
+            LocalizedComponentType::Workflow => Permission::WorkflowManage,
+            LocalizedComponentType::BusinessRule => Permission::MetadataFieldWrite,
+            LocalizedComponentType::Role | LocalizedComponentType::App => {
+                Permission::SecurityRoleManage
+            }
+        };
+
+        self.authorization_service
+            .require_permission(actor.tenant_id(), actor.subject(), permission)
+            .await
+    }
+
+    async fn append_label_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        component_type: LocalizedComponentType,
+        component_key: &str,
+        locale: &LocaleTag,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: component_type.as_str().to_owned(),
+                resource_id: component_key.to_owned(),
+                detail: Some(format!("locale '{}'", locale.as_str())),
+            })
+            .await
+    }
+}
@@ -0,0 +1,369 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{
+    AuditAction, LocaleTag, LocalizedComponentType, LocalizedDisplayLabel, Permission,
+};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    TemporaryPermissionGrant,
+};
+
+use super::{LocalizationService, LocalizedLabelRepository};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeLocalizedLabelRepository {
+    labels: Mutex<Vec<(TenantId, LocalizedDisplayLabel)>>,
+}
+
+#[async_trait]
+impl LocalizedLabelRepository for FakeLocalizedLabelRepository {
+    async fn save_localized_label(
+        &self,
+        tenant_id: TenantId,
+        label: LocalizedDisplayLabel,
+    ) -> AppResult<()> {
+        let mut labels = self.labels.lock().await;
+        labels.retain(|(stored_tenant_id, stored)| {
+            !(*stored_tenant_id == tenant_id
+                && stored.component_type() == label.component_type()
+                && stored.component_key() == label.component_key()
+                && stored.locale() == label.locale())
+        });
+        labels.push((tenant_id, label));
+        Ok(())
+    }
+
+    async fn delete_localized_label(
+        &self,
+        tenant_id: TenantId,
+        component_type: LocalizedComponentType,
+        component_key: &str,
+        locale: &LocaleTag,
+    ) -> AppResult<bool> {
+        let mut labels = self.labels.lock().await;
+        let before = labels.len();
+        labels.retain(|(stored_tenant_id, stored)| {
+            !(*stored_tenant_id == tenant_id
+                && stored.component_type() == component_type
+                && stored.component_key().as_str() == component_key
+                && stored.locale() == locale)
+        });
+        Ok(labels.len() != before)
+    }
+
+    async fn list_localized_labels(
+        &self,
+        tenant_id: TenantId,
+        component_type: LocalizedComponentType,
+        component_key: Option<&str>,
+    ) -> AppResult<Vec<LocalizedDisplayLabel>> {
+        Ok(self
+            .labels
+            .lock()
+            .await
+            .iter()
+            .filter(|(stored_tenant_id, stored)| {
+                *stored_tenant_id == tenant_id
+                    && stored.component_type() == component_type
+                    && component_key.is_none_or(|key| stored.component_key().as_str() == key)
+            })
+            .map(|(_, stored)| stored.clone())
+            .collect())
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn locale(value: &str) -> LocaleTag {
+    LocaleTag::new(value).unwrap_or_else(|_| unreachable!())
+}
+
+fn label(
+    component_type: LocalizedComponentType,
+    component_key: &str,
+    locale_tag: &str,
+    display_name: &str,
+) -> LocalizedDisplayLabel {
+    LocalizedDisplayLabel::new(
+        component_type,
+        component_key,
+        locale(locale_tag),
+        display_name,
+        None,
+    )
+    .unwrap_or_else(|_| unreachable!())
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    subject: &str,
+    permissions: Vec<Permission>,
+    repository: Arc<FakeLocalizedLabelRepository>,
+) -> (LocalizationService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([((tenant_id, subject.to_owned()), permissions)]),
+        }),
+        audit_repository.clone(),
+    );
+    let service =
+        LocalizationService::new(authorization_service, repository, audit_repository.clone());
+    (service, audit_repository)
+}
+
+#[tokio::test]
+async fn saving_labels_requires_the_component_manage_permission() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeLocalizedLabelRepository::default());
+    let (service, audit_repository) = build_service(
+        tenant_id,
+        "alice",
+        vec![Permission::WorkflowManage],
+        repository.clone(),
+    );
+    let actor = actor(tenant_id, "alice");
+
+    let result = service
+        .save_label(
+            &actor,
+            label(
+                LocalizedComponentType::Role,
+                "sales_manager",
+                "de",
+                "Vertriebsleitung",
+            ),
+        )
+        .await;
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+
+    service
+        .save_label(
+            &actor,
+            label(
+                LocalizedComponentType::Workflow,
+                "notify_owner",
+                "de",
+                "Besitzer benachrichtigen",
+            ),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(repository.labels.lock().await.len(), 1);
+    assert!(audit_repository.events.lock().await.iter().any(|event| {
+        event.action == AuditAction::MetadataLocalizedLabelSaved
+            && event.resource_type == "workflow"
+            && event.resource_id == "notify_owner"
+    }));
+}
+
+#[tokio::test]
+async fn saving_a_label_twice_replaces_it_and_delete_reports_missing_labels() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeLocalizedLabelRepository::default());
+    let (service, audit_repository) = build_service(
+        tenant_id,
+        "alice",
+        vec![Permission::SecurityRoleManage],
+        repository.clone(),
+    );
+    let actor = actor(tenant_id, "alice");
+
+    for display_name in ["Verkauf", "Vertrieb"] {
+        service
+            .save_label(
+                &actor,
+                label(LocalizedComponentType::App, "sales", "de", display_name),
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+    }
+
+    let labels = service
+        .list_component_labels(&actor, LocalizedComponentType::App, "sales")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].display_name().as_str(), "Vertrieb");
+
+    service
+        .delete_label(&actor, LocalizedComponentType::App, "sales", &locale("de"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(
+        audit_repository
+            .events
+            .lock()
+            .await
+            .iter()
+            .any(|event| event.action == AuditAction::MetadataLocalizedLabelDeleted)
+    );
+
+    let result = service
+        .delete_label(&actor, LocalizedComponentType::App, "sales", &locale("de"))
+        .await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn resolve_labels_picks_the_best_locale_per_component() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeLocalizedLabelRepository::default());
+    for stored in [
+        label(
+            LocalizedComponentType::Workflow,
+            "notify_owner",
+            "de",
+            "Besitzer benachrichtigen",
+        ),
+        label(
+            LocalizedComponentType::Workflow,
+            "notify_owner",
+            "fr",
+            "Avertir le propriétaire",
+        ),
+        label(
+            LocalizedComponentType::Workflow,
+            "close_case",
+            "fr-CA",
+            "Fermer le dossier",
+        ),
+        label(
+            LocalizedComponentType::App,
+            "notify_owner",
+            "fr",
+            "Application",
+        ),
+    ] {
+        repository
+            .save_localized_label(tenant_id, stored)
+            .await
+            .unwrap_or_else(|_| unreachable!());
+    }
+    repository
+        .save_localized_label(
+            TenantId::new(),
+            label(
+                LocalizedComponentType::Workflow,
+                "escalate",
+                "fr",
+                "Escalader",
+            ),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let (service, _) = build_service(tenant_id, "maker", Vec::new(), repository);
+    let actor = actor(tenant_id, "maker");
+
+    let resolved = service
+        .resolve_labels(
+            &actor,
+            LocalizedComponentType::Workflow,
+            &[locale("fr-FR"), locale("de")],
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(resolved.len(), 2);
+    assert_eq!(
+        resolved
+            .get("notify_owner")
+            .map(|label| label.display_name().as_str()),
+        Some("Avertir le propriétaire")
+    );
+    assert_eq!(
+        resolved
+            .get("close_case")
+            .map(|label| label.locale().as_str()),
+        Some("fr-CA")
+    );
+
+    let unresolved = service
+        .resolve_labels(&actor, LocalizedComponentType::Workflow, &[])
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(unresolved.is_empty());
+}
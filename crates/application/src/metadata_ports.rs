@@ -1,21 +1,68 @@
 mod audit;
+mod entity_dependencies;
+mod field_impacts;
 mod metadata_inputs;
 mod metadata_repository;
+mod published_schema_versions;
+mod record_associations;
+mod record_history;
+mod record_owners;
+mod record_slugs;
+mod record_status;
+mod relation_behaviors;
+mod runtime_aggregate;
+mod runtime_changesets;
 mod runtime_query;
+mod runtime_upserts;
+mod runtime_view_cache;
+mod schema_rollback;
 mod tenant;
 
-pub use audit::{AuditEvent, AuditRepository};
+pub use audit::{AuditEvent, AuditOutboxRelay, AuditRepository};
+pub use entity_dependencies::{EntityDependency, EntityDependencyKind, EntityDependencyReport};
+pub use field_impacts::{FieldImpact, FieldImpactKind, FieldImpactReport, FieldImpactSeverity};
 pub use metadata_inputs::{
-    SaveBusinessRuleInput, SaveFieldInput, SaveFormInput, SaveOptionSetInput, SaveViewInput,
+    SaveBusinessRuleInput, SaveDuplicateDetectionRuleInput, SaveEntityHistoryPolicyInput,
+    SaveEntitySlugConfigInput, SaveEntityStatusModelInput, SaveFieldInput, SaveFormInput,
+    SaveOptionSetInput, SaveRelationBehaviorInput, SaveRelationLookupConfigInput, SaveViewInput,
     UpdateEntityInput, UpdateFieldInput,
 };
 pub use metadata_repository::{
     MetadataComponentsRepository, MetadataDefinitionsRepository, MetadataPublishRepository,
     MetadataRepository, MetadataRepositoryByConcern, MetadataRuntimeRepository,
 };
+pub use published_schema_versions::PublishedEntitySchemaVersion;
+pub use record_associations::RuntimeRecordAssociation;
+pub use record_history::{
+    EntityHistoryPolicy, NewRuntimeRecordHistoryEntry, RECORD_HISTORY_MAX_RETENTION_DAYS,
+    RuntimeRecordFieldChange, RuntimeRecordHistoryEntry,
+};
+pub use record_owners::RuntimeRecordOwnerDirectory;
+pub use record_slugs::EntitySlugConfig;
+pub use record_status::{
+    EntityStatusConfig, NewRuntimeRecordStatusChange, RuntimeRecordStatusChange,
+};
+pub use relation_behaviors::{
+    RelationBehavior, RelationCascadeResult, RelationDeletePlan, RelationDeletedRecord,
+    RelationLookupConfig, RelationLookupMatch, RelationRelinkedRecord,
+};
+pub use runtime_aggregate::{
+    RUNTIME_RECORD_AGGREGATE_MAX_AGGREGATES, RUNTIME_RECORD_AGGREGATE_MAX_GROUP_FIELDS,
+    RuntimeRecordAggregate, RuntimeRecordAggregateFunction, RuntimeRecordAggregateHaving,
+    RuntimeRecordAggregateQuery, RuntimeRecordAggregateRow, RuntimeRecordAggregateSort,
+    RuntimeRecordAggregateSortKey, RuntimeRecordGroupBy,
+};
+pub use runtime_changesets::{
+    RUNTIME_RECORD_CHANGESET_MAX_OPERATIONS, RuntimeRecordChangesetMethod,
+    RuntimeRecordChangesetOperation, RuntimeRecordChangesetResult, RuntimeRecordChangesetWrite,
+};
 pub use runtime_query::{
     RecordListQuery, RuntimeRecordConditionGroup, RuntimeRecordConditionNode, RuntimeRecordFilter,
-    RuntimeRecordJoinType, RuntimeRecordLink, RuntimeRecordLogicalMode, RuntimeRecordOperator,
+    RuntimeRecordJoinType, RuntimeRecordLink, RuntimeRecordLinkCardinality,
+    RuntimeRecordLogicalMode, RuntimeRecordOperator, RuntimeRecordOwnerAssignment,
     RuntimeRecordQuery, RuntimeRecordSort, RuntimeRecordSortDirection, UniqueFieldValue,
 };
+pub use runtime_upserts::{RuntimeRecordUpsertOutcome, RuntimeRecordUpsertResult};
+pub use runtime_view_cache::{RuntimeViewCacheKey, RuntimeViewCacheLookup, RuntimeViewResultCache};
+pub use schema_rollback::EntitySchemaRollbackChecks;
 pub use tenant::{TenantMembership, TenantRepository};
@@ -25,3 +25,11 @@ pub trait AuditRepository: Send + Sync {
     /// Persists one audit event.
     async fn append_event(&self, event: AuditEvent) -> AppResult<()>;
 }
+
+/// Port for moving audit events committed through the transactional outbox
+/// into the append-only audit log.
+#[async_trait]
+pub trait AuditOutboxRelay: Send + Sync {
+    /// Relays up to `limit` pending outbox events and returns how many moved.
+    async fn relay_pending_events(&self, limit: usize) -> AppResult<usize>;
+}
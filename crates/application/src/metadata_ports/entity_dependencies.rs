@@ -0,0 +1,75 @@
+/// Kind of metadata component that depends on an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EntityDependencyKind {
+    /// Form of the entity itself, or a form elsewhere with a sub-grid on it.
+    Form,
+    /// View of the entity itself.
+    View,
+    /// App navigation binding for the entity.
+    AppBinding,
+    /// Relation or many-to-many field on another entity targeting it.
+    Relation,
+    /// Workflow whose trigger or steps target the entity.
+    Workflow,
+    /// Active legal hold covering the entity's runtime records.
+    LegalHold,
+}
+
+impl EntityDependencyKind {
+    /// Returns a stable transport value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Form => "form",
+            Self::View => "view",
+            Self::AppBinding => "app_binding",
+            Self::Relation => "relation",
+            Self::Workflow => "workflow",
+            Self::LegalHold => "legal_hold",
+        }
+    }
+}
+
+/// One component that references an entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityDependency {
+    /// Dependent component kind.
+    pub kind: EntityDependencyKind,
+    /// Stable key of the dependent component, e.g. `contact.main_form`.
+    pub component_key: String,
+    /// Operator-facing explanation of the reference.
+    pub detail: String,
+    /// Whether the reference must be removed before the entity can be deleted.
+    ///
+    /// Components owned by the entity itself are removed with it and never block.
+    pub blocks_delete: bool,
+}
+
+/// Components that reference one entity, used to gate entity deletion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityDependencyReport {
+    /// Analyzed entity logical name.
+    pub entity_logical_name: String,
+    /// Whether the entity currently accepts runtime record writes.
+    pub is_active: bool,
+    /// Dependencies ordered by kind and component key.
+    pub dependencies: Vec<EntityDependency>,
+}
+
+impl EntityDependencyReport {
+    /// Returns the dependencies that prevent deleting the entity.
+    pub fn blocking_dependencies(&self) -> impl Iterator<Item = &EntityDependency> {
+        self.dependencies
+            .iter()
+            .filter(|dependency| dependency.blocks_delete)
+    }
+
+    /// Returns whether the entity can be deleted right now.
+    ///
+    /// Entities must be deactivated first and must not be referenced by any
+    /// component outside the entity.
+    #[must_use]
+    pub fn can_delete(&self) -> bool {
+        !self.is_active && self.blocking_dependencies().next().is_none()
+    }
+}
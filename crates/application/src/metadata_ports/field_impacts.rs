@@ -0,0 +1,86 @@
+/// Kind of metadata component that references a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FieldImpactKind {
+    /// Form placing the field, listing it in the header, or sub-grid using it.
+    Form,
+    /// View listing, sorting or filtering on the field.
+    View,
+    /// Business rule with a condition on or an action targeting the field.
+    BusinessRule,
+    /// Calculated field whose expression reads the field.
+    CalculatedField,
+    /// Workflow whose trigger filters, conditions, templates or steps use the field.
+    Workflow,
+    /// Saved personal view listing, sorting or filtering on the field.
+    SavedQuery,
+}
+
+impl FieldImpactKind {
+    /// Returns a stable transport value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Form => "form",
+            Self::View => "view",
+            Self::BusinessRule => "business_rule",
+            Self::CalculatedField => "calculated_field",
+            Self::Workflow => "workflow",
+            Self::SavedQuery => "saved_query",
+        }
+    }
+}
+
+/// How badly a field change breaks a referencing component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FieldImpactSeverity {
+    /// Changes behavior that enforces data or runs automatically.
+    High,
+    /// Changes what builders and users see in configured surfaces.
+    Medium,
+    /// Affects inactive components or individual users' saved queries.
+    Low,
+}
+
+impl FieldImpactSeverity {
+    /// Returns a stable transport value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::High => "high",
+            Self::Medium => "medium",
+            Self::Low => "low",
+        }
+    }
+}
+
+/// One component that references a field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldImpact {
+    /// Severity of changing or deleting the field for this component.
+    pub severity: FieldImpactSeverity,
+    /// Dependent component kind.
+    pub kind: FieldImpactKind,
+    /// Stable key of the dependent component, e.g. `contact.main_form`.
+    pub component_key: String,
+    /// Operator-facing explanation of the reference.
+    pub detail: String,
+}
+
+/// Components that reference one field, reviewed before updating or deleting it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldImpactReport {
+    /// Entity owning the analyzed field.
+    pub entity_logical_name: String,
+    /// Analyzed field logical name.
+    pub field_logical_name: String,
+    /// Impacts ordered by severity, kind and component key.
+    pub impacts: Vec<FieldImpact>,
+}
+
+impl FieldImpactReport {
+    /// Returns the most severe impact, if the field is referenced at all.
+    #[must_use]
+    pub fn highest_severity(&self) -> Option<FieldImpactSeverity> {
+        self.impacts.iter().map(|impact| impact.severity).min()
+    }
+}
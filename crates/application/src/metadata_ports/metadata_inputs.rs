@@ -1,6 +1,8 @@
 use qryvanta_domain::{
-    BusinessRuleAction, BusinessRuleCondition, BusinessRuleScope, FieldType, FormTab, FormType,
-    OptionSetItem, ViewColumn, ViewFilterGroup, ViewSort, ViewType,
+    BusinessRuleAction, BusinessRuleCondition, BusinessRuleScope, DuplicateMatchField,
+    DuplicateRuleAction, FieldClassification, FieldType, FormScriptEvents, FormTab, FormType,
+    OptionSetItem, RecordStatusOption, RecordStatusTransition, RelationCascadeBehavior,
+    RelationDeleteBehavior, ViewColumn, ViewFilterGroup, ViewSort, ViewType,
 };
 use serde_json::Value;
 
@@ -27,6 +29,8 @@ pub struct SaveFieldInput {
     pub option_set_logical_name: Option<String>,
     /// Optional calculation expression for computed fields.
     pub calculation_expression: Option<String>,
+    /// Optional data classification driving role-based masking.
+    pub classification: Option<FieldClassification>,
 }
 
 /// Input payload for option set create/update operations.
@@ -57,6 +61,8 @@ pub struct SaveFormInput {
     pub tabs: Vec<FormTab>,
     /// Header field logical names.
     pub header_fields: Vec<String>,
+    /// Client scripting hooks.
+    pub script_events: FormScriptEvents,
 }
 
 /// Input payload for view create/update operations.
@@ -114,6 +120,8 @@ pub struct UpdateEntityInput {
     pub plural_display_name: Option<String>,
     /// Optional icon key.
     pub icon: Option<String>,
+    /// Optional `#rrggbb` accent color.
+    pub color: Option<String>,
 }
 
 /// Input payload for metadata field update operations.
@@ -137,4 +145,88 @@ pub struct UpdateFieldInput {
     pub min_value: Option<f64>,
     /// Optional number maximum value constraint.
     pub max_value: Option<f64>,
+    /// Optional data classification driving role-based masking.
+    pub classification: Option<FieldClassification>,
+}
+
+/// Input payload for configuring relation cascade behaviors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveRelationBehaviorInput {
+    /// Child entity that owns the relation field.
+    pub entity_logical_name: String,
+    /// Relation field logical name.
+    pub field_logical_name: String,
+    /// Propagation applied when a parent record changes owner.
+    pub assign_behavior: RelationCascadeBehavior,
+    /// Propagation applied when a parent record is shared.
+    pub share_behavior: RelationCascadeBehavior,
+    /// Handling of child records when a parent record is deleted.
+    pub delete_behavior: RelationDeleteBehavior,
+}
+
+/// Input payload for configuring relation lookup search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaveRelationLookupConfigInput {
+    /// Entity that owns the relation field.
+    pub entity_logical_name: String,
+    /// Relation field logical name.
+    pub field_logical_name: String,
+    /// Target text fields matched by the search term.
+    pub search_field_logical_names: Vec<String>,
+    /// Optional filter every lookup result must satisfy.
+    pub filter: Option<ViewFilterGroup>,
+}
+
+/// Input payload for configuring an entity slug.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveEntitySlugConfigInput {
+    /// Entity whose records carry slugs.
+    pub entity_logical_name: String,
+    /// Unique text field storing the slug.
+    pub slug_field_logical_name: String,
+    /// Text field the slug is generated from.
+    pub source_field_logical_name: String,
+}
+
+/// Input payload for configuring an entity record history retention policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveEntityHistoryPolicyInput {
+    /// Entity whose record history is retained.
+    pub entity_logical_name: String,
+    /// Days history entries are kept before they are purged.
+    pub retention_days: u16,
+}
+
+/// Input payload for configuring an entity status model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveEntityStatusModelInput {
+    /// Entity whose records follow the state machine.
+    pub entity_logical_name: String,
+    /// Text field storing the status.
+    pub status_field_logical_name: String,
+    /// Optional text field storing the status reason.
+    pub reason_field_logical_name: Option<String>,
+    /// Status new records start in.
+    pub initial_status: String,
+    /// Declared statuses with their allowed reasons.
+    pub statuses: Vec<RecordStatusOption>,
+    /// Allowed status transitions.
+    pub transitions: Vec<RecordStatusTransition>,
+}
+
+/// Input payload for creating or updating a duplicate-detection rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveDuplicateDetectionRuleInput {
+    /// Entity whose records the rule compares.
+    pub entity_logical_name: String,
+    /// Rule logical name, unique per entity.
+    pub logical_name: String,
+    /// Rule display name.
+    pub display_name: String,
+    /// Fields that must all match.
+    pub match_fields: Vec<DuplicateMatchField>,
+    /// Whether a match warns or blocks the write.
+    pub action: DuplicateRuleAction,
+    /// Whether the rule is evaluated.
+    pub is_active: bool,
 }
@@ -1,12 +1,20 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use qryvanta_core::{AppResult, TenantId};
 use qryvanta_domain::{
-    BusinessRuleDefinition, EntityDefinition, EntityFieldDefinition, FormDefinition,
-    OptionSetDefinition, PublishedEntitySchema, RuntimeRecord, ViewDefinition,
+    BusinessRuleDefinition, DuplicateDetectionRule, EntityDefinition, EntityFieldDefinition,
+    FormDefinition, OptionSetDefinition, PublishedEntitySchema, RuntimeRecord, ViewDefinition,
 };
 use serde_json::Value;
 
-use super::{RecordListQuery, RuntimeRecordQuery, UniqueFieldValue};
+use super::{
+    AuditEvent, EntityHistoryPolicy, EntitySlugConfig, EntityStatusConfig,
+    NewRuntimeRecordHistoryEntry, NewRuntimeRecordStatusChange, PublishedEntitySchemaVersion,
+    RecordListQuery, RelationBehavior, RelationCascadeResult, RelationDeletePlan,
+    RelationLookupConfig, RuntimeRecordAggregateQuery, RuntimeRecordAggregateRow,
+    RuntimeRecordAssociation, RuntimeRecordChangesetWrite, RuntimeRecordHistoryEntry,
+    RuntimeRecordQuery, RuntimeRecordStatusChange, UniqueFieldValue,
+};
 use crate::{ClaimedRuntimeRecordWorkflowEvent, RuntimeRecordWorkflowEventInput};
 
 /// Legacy aggregate repository port for metadata and runtime persistence.
@@ -28,6 +36,10 @@ pub trait MetadataRepository: Send + Sync {
     /// Updates an existing entity definition.
     async fn update_entity(&self, tenant_id: TenantId, entity: EntityDefinition) -> AppResult<()>;
 
+    /// Deletes an entity definition with its fields, components, entity-scoped
+    /// configuration and runtime records.
+    async fn delete_entity(&self, tenant_id: TenantId, logical_name: &str) -> AppResult<()>;
+
     /// Saves or updates an entity field definition.
     async fn save_field(&self, tenant_id: TenantId, field: EntityFieldDefinition) -> AppResult<()>;
 
@@ -191,6 +203,39 @@ pub trait MetadataRepository: Send + Sync {
         entity_logical_name: &str,
     ) -> AppResult<Option<PublishedEntitySchema>>;
 
+    /// Returns one published schema version for an entity.
+    async fn find_published_schema(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchema>>;
+
+    /// Lists every published schema version for an entity, newest first.
+    async fn list_published_schema_versions(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<PublishedEntitySchemaVersion>>;
+
+    /// Returns one published schema version with its publish metadata.
+    async fn find_published_schema_version(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchemaVersion>>;
+
+    /// Republishes a prior schema version, with its form and view snapshots,
+    /// as the newest published version.
+    async fn rollback_entity_schema(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+        published_by: &str,
+    ) -> AppResult<PublishedEntitySchema>;
+
     /// Persists published form snapshots for an entity/schema version.
     async fn save_published_form_snapshots(
         &self,
@@ -224,6 +269,10 @@ pub trait MetadataRepository: Send + Sync {
     ) -> AppResult<Vec<ViewDefinition>>;
 
     /// Creates a runtime record and attaches unique field index entries.
+    ///
+    /// The optional workflow event and audit event are committed in the same
+    /// transaction as the record, as they are for every runtime record write.
+    #[allow(clippy::too_many_arguments)]
     async fn create_runtime_record(
         &self,
         tenant_id: TenantId,
@@ -232,9 +281,11 @@ pub trait MetadataRepository: Send + Sync {
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord>;
 
     /// Creates a runtime record with a caller-provided stable identifier.
+    #[allow(clippy::too_many_arguments)]
     async fn create_runtime_record_with_id(
         &self,
         tenant_id: TenantId,
@@ -244,9 +295,42 @@ pub trait MetadataRepository: Send + Sync {
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+    ) -> AppResult<RuntimeRecord>;
+
+    /// Creates a runtime record with a caller-provided identifier under an
+    /// idempotency key.
+    ///
+    /// Returns a conflict when the entity already has a record created under
+    /// the key.
+    #[allow(clippy::too_many_arguments)]
+    async fn create_runtime_record_with_idempotency_key(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        idempotency_key: &str,
+        data: Value,
+        unique_values: Vec<UniqueFieldValue>,
+        created_by_subject: &str,
+        workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord>;
 
+    /// Returns the runtime record created for an entity under an idempotency key.
+    async fn find_runtime_record_by_idempotency_key(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        idempotency_key: &str,
+    ) -> AppResult<Option<RuntimeRecord>>;
+
     /// Updates a runtime record and replaces unique field index entries.
+    ///
+    /// Every update increments the record version. When `expected_version` is
+    /// set and no longer matches the stored version, nothing is written and a
+    /// conflict is returned.
+    #[allow(clippy::too_many_arguments)]
     async fn update_runtime_record(
         &self,
         tenant_id: TenantId,
@@ -254,9 +338,21 @@ pub trait MetadataRepository: Send + Sync {
         record_id: &str,
         data: Value,
         unique_values: Vec<UniqueFieldValue>,
+        expected_version: Option<i64>,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord>;
 
+    /// Applies a changeset of runtime record writes in one transaction.
+    ///
+    /// Either every write is persisted or none is. Records are returned in write order.
+    async fn apply_runtime_record_changeset(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        writes: Vec<RuntimeRecordChangesetWrite>,
+    ) -> AppResult<Vec<RuntimeRecord>>;
+
     /// Lists runtime records for an entity.
     async fn list_runtime_records(
         &self,
@@ -273,6 +369,14 @@ pub trait MetadataRepository: Send + Sync {
         query: RuntimeRecordQuery,
     ) -> AppResult<Vec<RuntimeRecord>>;
 
+    /// Groups the runtime records matching the query conditions and aggregates each group.
+    async fn aggregate_runtime_records(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        query: RuntimeRecordAggregateQuery,
+    ) -> AppResult<Vec<RuntimeRecordAggregateRow>>;
+
     /// Finds a runtime record by identifier.
     async fn find_runtime_record(
         &self,
@@ -282,12 +386,18 @@ pub trait MetadataRepository: Send + Sync {
     ) -> AppResult<Option<RuntimeRecord>>;
 
     /// Deletes a runtime record by identifier.
+    ///
+    /// The relation plan's child deletes and relinks are applied in the same
+    /// transaction as the record delete.
+    #[allow(clippy::too_many_arguments)]
     async fn delete_runtime_record(
         &self,
         tenant_id: TenantId,
         entity_logical_name: &str,
         record_id: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+        relation_plan: &RelationDeletePlan,
     ) -> AppResult<()>;
 
     /// Claims one batch of pending runtime-record workflow events.
@@ -335,6 +445,193 @@ pub trait MetadataRepository: Send + Sync {
         subject: &str,
     ) -> AppResult<bool>;
 
+    /// Transfers a runtime record to a new owner subject.
+    ///
+    /// Child records reached through `cascades` are reassigned in the same
+    /// transaction. Returns the previous owner subject with per-relation cascade
+    /// counts, or `None` when the record does not exist.
+    async fn assign_runtime_record_owner(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        owner_subject: &str,
+        cascades: &[RelationBehavior],
+    ) -> AppResult<Option<(String, Vec<RelationCascadeResult>)>>;
+
+    /// Persists cascade behaviors for one relation field.
+    async fn save_relation_behavior(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        behavior: RelationBehavior,
+    ) -> AppResult<()>;
+
+    /// Lists configured relation cascade behaviors for a tenant.
+    async fn list_relation_behaviors(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<RelationBehavior>>;
+
+    /// Persists the lookup search configuration for a relation field.
+    async fn save_relation_lookup_config(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        config: RelationLookupConfig,
+    ) -> AppResult<()>;
+
+    /// Finds the lookup search configuration for a relation field.
+    async fn find_relation_lookup_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<Option<RelationLookupConfig>>;
+
+    /// Deletes the lookup search configuration for a relation field, returning
+    /// whether one existed.
+    async fn delete_relation_lookup_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<bool>;
+
+    /// Persists the slug configuration for an entity.
+    async fn save_entity_slug_config(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        config: EntitySlugConfig,
+    ) -> AppResult<()>;
+
+    /// Finds the slug configuration for an entity.
+    async fn find_entity_slug_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntitySlugConfig>>;
+
+    /// Persists the status model for an entity.
+    async fn save_entity_status_config(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        config: EntityStatusConfig,
+    ) -> AppResult<()>;
+
+    /// Finds the status model for an entity.
+    async fn find_entity_status_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityStatusConfig>>;
+
+    /// Deletes the status model for an entity, returning whether one existed.
+    async fn delete_entity_status_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool>;
+
+    /// Creates or replaces a duplicate-detection rule of an entity.
+    async fn save_duplicate_detection_rule(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        rule: DuplicateDetectionRule,
+    ) -> AppResult<()>;
+
+    /// Lists the duplicate-detection rules of an entity ordered by logical name.
+    async fn list_duplicate_detection_rules(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<DuplicateDetectionRule>>;
+
+    /// Deletes a duplicate-detection rule, returning whether it existed.
+    async fn delete_duplicate_detection_rule(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        logical_name: &str,
+    ) -> AppResult<bool>;
+
+    /// Appends a status history entry and enqueues its optional notification.
+    async fn append_runtime_record_status_change(
+        &self,
+        tenant_id: TenantId,
+        change: NewRuntimeRecordStatusChange,
+    ) -> AppResult<RuntimeRecordStatusChange>;
+
+    /// Lists the status history of one record, oldest first.
+    async fn list_runtime_record_status_history(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RuntimeRecordStatusChange>>;
+
+    /// Creates or replaces the record history retention policy of an entity.
+    async fn save_entity_history_policy(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        policy: EntityHistoryPolicy,
+    ) -> AppResult<()>;
+
+    /// Finds the record history retention policy of an entity.
+    async fn find_entity_history_policy(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityHistoryPolicy>>;
+
+    /// Deletes the record history retention policy of an entity, returning whether one existed.
+    async fn delete_entity_history_policy(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool>;
+
+    /// Appends a record history entry.
+    ///
+    /// When `retention_cutoff` is set, older entries of the same record are
+    /// purged with the append.
+    async fn append_runtime_record_history_entry(
+        &self,
+        tenant_id: TenantId,
+        entry: NewRuntimeRecordHistoryEntry,
+        retention_cutoff: Option<DateTime<Utc>>,
+    ) -> AppResult<RuntimeRecordHistoryEntry>;
+
+    /// Lists the field history of one record changed at or after `since`, oldest first.
+    async fn list_runtime_record_history(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<RuntimeRecordHistoryEntry>>;
+
+    /// Deletes history entries of an entity changed before `cutoff`, returning the count.
+    async fn purge_runtime_record_history(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        cutoff: DateTime<Utc>,
+    ) -> AppResult<u64>;
+
+    /// Finds the runtime record holding a unique field value hash.
+    async fn find_runtime_record_id_by_unique_value(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        field_value_hash: &str,
+    ) -> AppResult<Option<String>>;
+
     /// Returns whether any relation field currently references a runtime record.
     async fn has_relation_reference(
         &self,
@@ -342,6 +639,33 @@ pub trait MetadataRepository: Send + Sync {
         target_entity_logical_name: &str,
         target_record_id: &str,
     ) -> AppResult<bool>;
+
+    /// Links two runtime records through a many-to-many field.
+    ///
+    /// Returns `false` when the association already exists.
+    async fn associate_runtime_records(
+        &self,
+        tenant_id: TenantId,
+        associated_by_subject: &str,
+        association: RuntimeRecordAssociation,
+    ) -> AppResult<bool>;
+
+    /// Removes one many-to-many association, returning whether it existed.
+    async fn disassociate_runtime_records(
+        &self,
+        tenant_id: TenantId,
+        association: &RuntimeRecordAssociation,
+    ) -> AppResult<bool>;
+
+    /// Lists target record identifiers associated with a record through a
+    /// many-to-many field, oldest association first.
+    async fn list_associated_runtime_record_ids(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<String>>;
 }
 
 /// Focused metadata definition repository trait.
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use qryvanta_core::{AppError, AppResult};
+use qryvanta_domain::PublishedEntitySchema;
+use sha2::{Digest, Sha256};
+
+/// One stored published schema version with its publish metadata.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublishedEntitySchemaVersion {
+    /// Immutable published schema snapshot.
+    pub schema: PublishedEntitySchema,
+    /// Subject that published the version.
+    pub published_by_subject: String,
+    /// Publish timestamp.
+    pub published_at: DateTime<Utc>,
+}
+
+impl PublishedEntitySchemaVersion {
+    /// Returns the SHA-256 checksum of the serialized schema snapshot.
+    pub fn checksum_sha256(&self) -> AppResult<String> {
+        let encoded = serde_json::to_vec(&self.schema).map_err(|error| {
+            AppError::Internal(format!(
+                "failed to encode published schema checksum input: {error}"
+            ))
+        })?;
+
+        let digest = Sha256::digest(encoded);
+        Ok(digest.iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+}
@@ -0,0 +1,17 @@
+/// Association between two runtime records through a many-to-many field.
+///
+/// The field lives on `entity_logical_name`; associations are stored in an
+/// implicit junction and never appear in record payloads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeRecordAssociation {
+    /// Entity that owns the many-to-many field.
+    pub entity_logical_name: String,
+    /// Many-to-many field logical name.
+    pub field_logical_name: String,
+    /// Source record identifier.
+    pub record_id: String,
+    /// Target entity resolved from the field metadata.
+    pub target_entity_logical_name: String,
+    /// Associated target record identifier.
+    pub target_record_id: String,
+}
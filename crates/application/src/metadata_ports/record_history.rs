@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+/// Longest record history retention window an entity may configure.
+pub const RECORD_HISTORY_MAX_RETENTION_DAYS: u16 = 3650;
+
+/// Record history retention configured for one entity.
+///
+/// Entities without a policy keep their field history indefinitely.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityHistoryPolicy {
+    /// Entity whose record history is retained.
+    pub entity_logical_name: String,
+    /// Days history entries are kept before they are purged.
+    pub retention_days: u16,
+}
+
+impl EntityHistoryPolicy {
+    /// Oldest change timestamp still inside the retention window.
+    #[must_use]
+    pub fn cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - chrono::Duration::days(i64::from(self.retention_days))
+    }
+}
+
+/// Old and new value of one field changed by a record update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeRecordFieldChange {
+    /// Changed field.
+    pub field_logical_name: String,
+    /// Value before the update, absent when the field was unset.
+    pub old_value: Option<Value>,
+    /// Value after the update, absent when the field was cleared.
+    pub new_value: Option<Value>,
+}
+
+/// Field changes persisted to a record's history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewRuntimeRecordHistoryEntry {
+    /// Entity of the updated record.
+    pub entity_logical_name: String,
+    /// Updated record.
+    pub record_id: String,
+    /// Record version written by the update.
+    pub record_version: i64,
+    /// Fields whose values changed, ordered by logical name.
+    pub changes: Vec<RuntimeRecordFieldChange>,
+    /// Subject that updated the record.
+    pub changed_by_subject: String,
+}
+
+/// One entry of a record's field history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeRecordHistoryEntry {
+    /// Stable history entry identifier.
+    pub entry_id: String,
+    /// Entity of the updated record.
+    pub entity_logical_name: String,
+    /// Updated record.
+    pub record_id: String,
+    /// Record version written by the update.
+    pub record_version: i64,
+    /// Fields whose values changed, ordered by logical name.
+    pub changes: Vec<RuntimeRecordFieldChange>,
+    /// Subject that updated the record.
+    pub changed_by_subject: String,
+    /// Change timestamp.
+    pub changed_at: DateTime<Utc>,
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use qryvanta_core::{AppResult, TenantId};
+
+/// Port resolving which subjects runtime records can be assigned to.
+#[async_trait]
+pub trait RuntimeRecordOwnerDirectory: Send + Sync {
+    /// Returns whether `owner_subject` is a tenant member or a security team
+    /// name in the tenant.
+    async fn is_assignable_owner(
+        &self,
+        tenant_id: TenantId,
+        owner_subject: &str,
+    ) -> AppResult<bool>;
+}
@@ -0,0 +1,14 @@
+/// Slug configuration for one entity.
+///
+/// Records expose a human-readable identifier in `slug_field_logical_name`.
+/// When a write leaves the slug empty it is generated from the value of
+/// `source_field_logical_name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntitySlugConfig {
+    /// Entity whose records carry slugs.
+    pub entity_logical_name: String,
+    /// Unique text field storing the slug.
+    pub slug_field_logical_name: String,
+    /// Text field the slug is generated from.
+    pub source_field_logical_name: String,
+}
@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use qryvanta_domain::RecordStatusModel;
+
+use crate::RuntimeRecordWorkflowEventInput;
+
+/// Status state machine configured for one entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityStatusConfig {
+    /// Entity whose records follow the state machine.
+    pub entity_logical_name: String,
+    /// Validated statuses, reasons and transitions.
+    pub model: RecordStatusModel,
+}
+
+/// Status change persisted to a record's status history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewRuntimeRecordStatusChange {
+    /// Entity of the changed record.
+    pub entity_logical_name: String,
+    /// Changed record.
+    pub record_id: String,
+    /// Status before the change, absent for newly created records.
+    pub from_status: Option<String>,
+    /// Status after the change.
+    pub to_status: String,
+    /// Status reason before the change.
+    pub from_reason: Option<String>,
+    /// Status reason after the change.
+    pub to_reason: Option<String>,
+    /// Subject that changed the status.
+    pub changed_by_subject: String,
+    /// Optional `runtime_record_status_changed` event enqueued with the entry.
+    pub notification: Option<RuntimeRecordWorkflowEventInput>,
+}
+
+/// One entry of a record's status history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeRecordStatusChange {
+    /// Stable history entry identifier.
+    pub change_id: String,
+    /// Entity of the changed record.
+    pub entity_logical_name: String,
+    /// Changed record.
+    pub record_id: String,
+    /// Status before the change, absent for newly created records.
+    pub from_status: Option<String>,
+    /// Status after the change.
+    pub to_status: String,
+    /// Status reason before the change.
+    pub from_reason: Option<String>,
+    /// Status reason after the change.
+    pub to_reason: Option<String>,
+    /// Subject that changed the status.
+    pub changed_by_subject: String,
+    /// Change timestamp.
+    pub changed_at: DateTime<Utc>,
+}
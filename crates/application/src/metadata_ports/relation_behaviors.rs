@@ -0,0 +1,96 @@
+use qryvanta_domain::{
+    RelationCascadeBehavior, RelationDeleteBehavior, RuntimeRecord, ViewFilterGroup,
+};
+
+use crate::RuntimeRecordWorkflowEventInput;
+
+/// Cascade configuration for one relation field.
+///
+/// The relation field lives on the child entity and points at the parent
+/// (target) entity whose assign, share and delete actions propagate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelationBehavior {
+    /// Child entity that owns the relation field.
+    pub entity_logical_name: String,
+    /// Relation field logical name on the child entity.
+    pub field_logical_name: String,
+    /// Parent entity referenced by the relation field.
+    pub target_entity_logical_name: String,
+    /// Propagation applied when a parent record changes owner.
+    pub assign_behavior: RelationCascadeBehavior,
+    /// Propagation applied when a parent record is shared.
+    pub share_behavior: RelationCascadeBehavior,
+    /// Handling of child records when a parent record is deleted.
+    pub delete_behavior: RelationDeleteBehavior,
+}
+
+/// Number of child records reached through one relation during a cascade.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelationCascadeResult {
+    /// Child entity logical name.
+    pub entity_logical_name: String,
+    /// Relation field logical name on the child entity.
+    pub field_logical_name: String,
+    /// Child records updated or shared.
+    pub affected_records: u64,
+}
+
+/// Child record changes applied in the same transaction as a record delete.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RelationDeletePlan {
+    /// Child records deleted along with the parent record.
+    pub deleted_records: Vec<RelationDeletedRecord>,
+    /// Child records whose relation value is cleared or moved to another parent.
+    pub relinked_records: Vec<RelationRelinkedRecord>,
+}
+
+/// Child record removed by a cascading delete.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelationDeletedRecord {
+    /// Child entity logical name.
+    pub entity_logical_name: String,
+    /// Child record identifier.
+    pub record_id: String,
+    /// Optional delete trigger event persisted with the removal.
+    pub workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+}
+
+/// Child record whose relation value changes because its parent is deleted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelationRelinkedRecord {
+    /// Child entity logical name.
+    pub entity_logical_name: String,
+    /// Child record identifier.
+    pub record_id: String,
+    /// Relation field logical name on the child entity.
+    pub field_logical_name: String,
+    /// New parent record, or `None` to clear the relation.
+    pub parent_record_id: Option<String>,
+}
+
+/// Lookup search configuration for one relation field.
+///
+/// Lookup controls search the target entity by `search_field_logical_names`
+/// and only offer records matching the optional `filter`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelationLookupConfig {
+    /// Entity that owns the relation field.
+    pub entity_logical_name: String,
+    /// Relation field logical name.
+    pub field_logical_name: String,
+    /// Entity referenced by the relation field.
+    pub target_entity_logical_name: String,
+    /// Target text fields matched by the search term, in display order.
+    pub search_field_logical_names: Vec<String>,
+    /// Optional filter every lookup result must satisfy.
+    pub filter: Option<ViewFilterGroup>,
+}
+
+/// One record offered by a relation lookup search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelationLookupMatch {
+    /// Matched target record.
+    pub record: RuntimeRecord,
+    /// Display label taken from the first non-empty search field.
+    pub label: String,
+}
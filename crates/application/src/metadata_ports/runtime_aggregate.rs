@@ -0,0 +1,351 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use qryvanta_core::{AppError, AppResult};
+use qryvanta_domain::{FieldType, RuntimeRecord};
+use serde_json::{Number, Value};
+
+use super::runtime_query::{
+    RuntimeRecordConditionGroup, RuntimeRecordFilter, RuntimeRecordLogicalMode,
+    RuntimeRecordOperator, RuntimeRecordQuery, RuntimeRecordSortDirection,
+};
+
+/// Maximum number of group-by fields in one aggregate query.
+pub const RUNTIME_RECORD_AGGREGATE_MAX_GROUP_FIELDS: usize = 5;
+
+/// Maximum number of aggregates in one aggregate query.
+pub const RUNTIME_RECORD_AGGREGATE_MAX_AGGREGATES: usize = 10;
+
+/// Aggregate function applied to each group of runtime records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeRecordAggregateFunction {
+    /// Number of records, or of records with a non-null field value.
+    Count,
+    /// Sum of a number field.
+    Sum,
+    /// Average of a number field.
+    Avg,
+    /// Smallest field value.
+    Min,
+    /// Largest field value.
+    Max,
+}
+
+impl RuntimeRecordAggregateFunction {
+    /// Parses transport value into an aggregate function.
+    pub fn parse_transport(value: &str) -> AppResult<Self> {
+        match value {
+            "count" => Ok(Self::Count),
+            "sum" => Ok(Self::Sum),
+            "avg" => Ok(Self::Avg),
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            _ => Err(AppError::Validation(format!(
+                "unknown runtime aggregate function '{value}'"
+            ))),
+        }
+    }
+
+    /// Returns the stable transport value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Count => "count",
+            Self::Sum => "sum",
+            Self::Avg => "avg",
+            Self::Min => "min",
+            Self::Max => "max",
+        }
+    }
+}
+
+/// Field the aggregated records are grouped by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeRecordGroupBy {
+    /// Field logical name.
+    pub field_logical_name: String,
+    /// Field type from the published schema.
+    pub field_type: FieldType,
+}
+
+/// One named aggregate computed per group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeRecordAggregate {
+    /// Result key of the aggregate value.
+    pub alias: String,
+    /// Aggregate function.
+    pub function: RuntimeRecordAggregateFunction,
+    /// Aggregated field; `None` only for `count`, which then counts records.
+    pub field_logical_name: Option<String>,
+    /// Field type from the published schema.
+    pub field_type: Option<FieldType>,
+}
+
+impl RuntimeRecordAggregate {
+    /// Returns whether the aggregate produces numbers.
+    #[must_use]
+    pub fn is_numeric(&self) -> bool {
+        match self.function {
+            RuntimeRecordAggregateFunction::Count
+            | RuntimeRecordAggregateFunction::Sum
+            | RuntimeRecordAggregateFunction::Avg => true,
+            RuntimeRecordAggregateFunction::Min | RuntimeRecordAggregateFunction::Max => {
+                self.field_type == Some(FieldType::Number)
+            }
+        }
+    }
+}
+
+/// Condition on an aggregate value that groups must satisfy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeRecordAggregateHaving {
+    /// Alias of the compared aggregate.
+    pub aggregate_alias: String,
+    /// Comparison operator; only `eq`, `neq`, `gt`, `gte`, `lt` and `lte` are supported.
+    pub operator: RuntimeRecordOperator,
+    /// Number compared against the aggregate value.
+    pub value: Value,
+}
+
+/// Value an aggregate query result is sorted by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuntimeRecordAggregateSortKey {
+    /// One of the group-by fields.
+    GroupField(String),
+    /// One of the aggregates, by alias.
+    Aggregate(String),
+}
+
+/// Sort instruction for aggregate query results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeRecordAggregateSort {
+    /// Sorted value.
+    pub key: RuntimeRecordAggregateSortKey,
+    /// Sort direction.
+    pub direction: RuntimeRecordSortDirection,
+}
+
+/// Grouped aggregation over the runtime records matching a set of conditions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeRecordAggregateQuery {
+    /// Maximum number of groups returned after sorting (top-N).
+    pub limit: usize,
+    /// Logical composition mode for conditions.
+    pub logical_mode: RuntimeRecordLogicalMode,
+    /// Optional recursive where-clause tree.
+    pub where_clause: Option<RuntimeRecordConditionGroup>,
+    /// Typed record conditions applied before grouping.
+    pub filters: Vec<RuntimeRecordFilter>,
+    /// Group-by fields; an empty list aggregates all matching records as one group.
+    pub group_by: Vec<RuntimeRecordGroupBy>,
+    /// Aggregates computed per group.
+    pub aggregates: Vec<RuntimeRecordAggregate>,
+    /// Conditions on aggregate values, all of which must hold.
+    pub having: Vec<RuntimeRecordAggregateHaving>,
+    /// Sort instructions; groups are ordered by group-by fields when empty.
+    pub sort: Vec<RuntimeRecordAggregateSort>,
+    /// Optional subject ownership filter.
+    pub owner_subject: Option<String>,
+}
+
+/// One group of an aggregate query result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeRecordAggregateRow {
+    /// Group-by field values keyed by field logical name.
+    pub group: BTreeMap<String, Value>,
+    /// Aggregate values keyed by alias; `null` when no value could be aggregated.
+    pub values: BTreeMap<String, Value>,
+}
+
+impl RuntimeRecordAggregateQuery {
+    /// Returns the record query selecting the records that are aggregated.
+    #[must_use]
+    pub fn record_query(&self) -> RuntimeRecordQuery {
+        RuntimeRecordQuery {
+            limit: usize::MAX,
+            offset: 0,
+            logical_mode: self.logical_mode,
+            where_clause: self.where_clause.clone(),
+            filters: self.filters.clone(),
+            links: Vec::new(),
+            sort: Vec::new(),
+            owner_subject: self.owner_subject.clone(),
+            include_inactive: true,
+        }
+    }
+
+    /// Aggregates already-filtered records in memory.
+    ///
+    /// Used by repositories without native aggregation support.
+    #[must_use]
+    pub fn aggregate_records(&self, records: &[RuntimeRecord]) -> Vec<RuntimeRecordAggregateRow> {
+        let mut groups: Vec<(Vec<Value>, Vec<&RuntimeRecord>)> = Vec::new();
+        for record in records {
+            let key = self
+                .group_by
+                .iter()
+                .map(|group_by| field_value(record, group_by.field_logical_name.as_str()))
+                .collect::<Vec<_>>();
+            match groups.iter_mut().find(|(group_key, _)| *group_key == key) {
+                Some((_, members)) => members.push(record),
+                None => groups.push((key, vec![record])),
+            }
+        }
+
+        if groups.is_empty() && self.group_by.is_empty() {
+            groups.push((Vec::new(), Vec::new()));
+        }
+
+        let mut rows = groups
+            .into_iter()
+            .map(|(key, members)| RuntimeRecordAggregateRow {
+                group: self
+                    .group_by
+                    .iter()
+                    .map(|group_by| group_by.field_logical_name.clone())
+                    .zip(key)
+                    .collect(),
+                values: self
+                    .aggregates
+                    .iter()
+                    .map(|aggregate| {
+                        (
+                            aggregate.alias.clone(),
+                            aggregate_value(aggregate, &members),
+                        )
+                    })
+                    .collect(),
+            })
+            .filter(|row| self.having.iter().all(|having| having_matches(having, row)))
+            .collect::<Vec<_>>();
+
+        rows.sort_by(|left, right| {
+            for sort in &self.sort {
+                let (left_value, right_value) = match &sort.key {
+                    RuntimeRecordAggregateSortKey::GroupField(field) => {
+                        (left.group.get(field), right.group.get(field))
+                    }
+                    RuntimeRecordAggregateSortKey::Aggregate(alias) => {
+                        (left.values.get(alias), right.values.get(alias))
+                    }
+                };
+                let ordering = compare_values(
+                    left_value.unwrap_or(&Value::Null),
+                    right_value.unwrap_or(&Value::Null),
+                );
+                let ordering = match sort.direction {
+                    RuntimeRecordSortDirection::Asc => ordering,
+                    RuntimeRecordSortDirection::Desc => ordering.reverse(),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+
+            self.group_by
+                .iter()
+                .map(|group_by| {
+                    compare_values(
+                        left.group
+                            .get(&group_by.field_logical_name)
+                            .unwrap_or(&Value::Null),
+                        right
+                            .group
+                            .get(&group_by.field_logical_name)
+                            .unwrap_or(&Value::Null),
+                    )
+                })
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+        rows.truncate(self.limit);
+        rows
+    }
+}
+
+fn field_value(record: &RuntimeRecord, field_logical_name: &str) -> Value {
+    record
+        .data()
+        .get(field_logical_name)
+        .cloned()
+        .unwrap_or(Value::Null)
+}
+
+fn aggregate_value(aggregate: &RuntimeRecordAggregate, members: &[&RuntimeRecord]) -> Value {
+    let values = aggregate
+        .field_logical_name
+        .as_deref()
+        .map(|field| {
+            members
+                .iter()
+                .map(|record| field_value(record, field))
+                .filter(|value| !value.is_null())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    match aggregate.function {
+        RuntimeRecordAggregateFunction::Count => match aggregate.field_logical_name {
+            Some(_) => Value::from(values.len()),
+            None => Value::from(members.len()),
+        },
+        RuntimeRecordAggregateFunction::Sum | RuntimeRecordAggregateFunction::Avg => {
+            let numbers = values.iter().filter_map(Value::as_f64).collect::<Vec<_>>();
+            if numbers.is_empty() {
+                return Value::Null;
+            }
+
+            let sum = numbers.iter().sum::<f64>();
+            let result = if aggregate.function == RuntimeRecordAggregateFunction::Sum {
+                sum
+            } else {
+                sum / numbers.len() as f64
+            };
+            Number::from_f64(result).map_or(Value::Null, Value::Number)
+        }
+        RuntimeRecordAggregateFunction::Min => values
+            .into_iter()
+            .min_by(compare_values)
+            .unwrap_or(Value::Null),
+        RuntimeRecordAggregateFunction::Max => values
+            .into_iter()
+            .max_by(compare_values)
+            .unwrap_or(Value::Null),
+    }
+}
+
+fn having_matches(having: &RuntimeRecordAggregateHaving, row: &RuntimeRecordAggregateRow) -> bool {
+    let (Some(actual), Some(expected)) = (
+        row.values
+            .get(&having.aggregate_alias)
+            .and_then(Value::as_f64),
+        having.value.as_f64(),
+    ) else {
+        return false;
+    };
+
+    match having.operator {
+        RuntimeRecordOperator::Eq => actual == expected,
+        RuntimeRecordOperator::Neq => actual != expected,
+        RuntimeRecordOperator::Gt => actual > expected,
+        RuntimeRecordOperator::Gte => actual >= expected,
+        RuntimeRecordOperator::Lt => actual < expected,
+        RuntimeRecordOperator::Lte => actual <= expected,
+        _ => false,
+    }
+}
+
+/// Orders nulls last, numbers numerically and other values by their text.
+fn compare_values(left: &Value, right: &Value) -> Ordering {
+    match (left, right) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => Ordering::Greater,
+        (_, Value::Null) => Ordering::Less,
+        (Value::Number(left), Value::Number(right)) => left
+            .as_f64()
+            .partial_cmp(&right.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(left), Value::String(right)) => left.cmp(right),
+        _ => left.to_string().cmp(&right.to_string()),
+    }
+}
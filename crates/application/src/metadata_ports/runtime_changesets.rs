@@ -0,0 +1,88 @@
+use qryvanta_core::{AppError, AppResult};
+use qryvanta_domain::RuntimeRecord;
+use serde_json::Value;
+
+use super::{AuditEvent, UniqueFieldValue};
+use crate::RuntimeRecordWorkflowEventInput;
+
+/// Maximum number of operations accepted in one runtime record changeset.
+pub const RUNTIME_RECORD_CHANGESET_MAX_OPERATIONS: usize = 100;
+
+/// Write method of one runtime record changeset operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeRecordChangesetMethod {
+    /// Creates a new record with a server-assigned identifier.
+    Create,
+    /// Updates an existing record or a record created earlier in the changeset.
+    Update,
+}
+
+impl RuntimeRecordChangesetMethod {
+    /// Parses transport value into a changeset method.
+    pub fn parse_transport(value: &str) -> AppResult<Self> {
+        match value {
+            "create" => Ok(Self::Create),
+            "update" => Ok(Self::Update),
+            _ => Err(AppError::Validation(format!(
+                "unknown runtime record changeset method '{value}'"
+            ))),
+        }
+    }
+
+    /// Returns a stable transport value.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+        }
+    }
+}
+
+/// One requested operation in a runtime record changeset.
+///
+/// String values equal to `$<content_id>` in `data` or `record_id` resolve to
+/// the record identifier produced by an earlier operation with that content id.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeRecordChangesetOperation {
+    /// Optional caller-chosen identifier later operations can reference.
+    pub content_id: Option<String>,
+    /// Write method.
+    pub method: RuntimeRecordChangesetMethod,
+    /// Target entity logical name.
+    pub entity_logical_name: String,
+    /// Target record identifier; required for updates and rejected for creates.
+    pub record_id: Option<String>,
+    /// Record payload.
+    pub data: Value,
+}
+
+/// One validated write applied by the repository inside the changeset transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeRecordChangesetWrite {
+    /// Write method.
+    pub method: RuntimeRecordChangesetMethod,
+    /// Target entity logical name.
+    pub entity_logical_name: String,
+    /// Resolved record identifier, pre-assigned for creates.
+    pub record_id: String,
+    /// Normalized record payload.
+    pub data: Value,
+    /// Unique field index entries for the record.
+    pub unique_values: Vec<UniqueFieldValue>,
+    /// Optional workflow trigger event enqueued with the write.
+    pub workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+    /// Optional audit event committed to the audit outbox with the write.
+    pub audit_event: Option<AuditEvent>,
+}
+
+/// Outcome of one applied changeset operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeRecordChangesetResult {
+    /// Content id supplied with the operation.
+    pub content_id: Option<String>,
+    /// Write method.
+    pub method: RuntimeRecordChangesetMethod,
+    /// Record state after the write.
+    pub record: RuntimeRecord,
+}
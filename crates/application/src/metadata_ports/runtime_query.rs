@@ -1,7 +1,10 @@
+use chrono::{Datelike, Days, Months, NaiveDate};
 use qryvanta_core::AppResult;
 use qryvanta_domain::FieldType;
 use serde_json::Value;
 
+use super::relation_behaviors::RelationCascadeResult;
+
 /// Logical composition mode for runtime query conditions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RuntimeRecordLogicalMode {
@@ -52,6 +55,24 @@ pub enum RuntimeRecordOperator {
     Contains,
     /// Membership in a set of values.
     In,
+    /// Value is absent, null, an empty string or an empty list.
+    IsNull,
+    /// Value is present and not empty.
+    IsNotNull,
+    /// Value is present and outside a set of values.
+    NotIn,
+    /// Inclusive range given as a `[low, high]` pair.
+    Between,
+    /// Case-sensitive text prefix match.
+    BeginsWith,
+    /// Date falls on the current UTC day.
+    Today,
+    /// Date falls on the previous UTC day.
+    Yesterday,
+    /// Date falls in the current UTC calendar month.
+    ThisMonth,
+    /// Date falls within the last N UTC days, including today.
+    LastNDays,
 }
 
 impl RuntimeRecordOperator {
@@ -66,6 +87,15 @@ impl RuntimeRecordOperator {
             "lte" => Ok(Self::Lte),
             "contains" => Ok(Self::Contains),
             "in" => Ok(Self::In),
+            "is_null" => Ok(Self::IsNull),
+            "is_not_null" => Ok(Self::IsNotNull),
+            "not_in" => Ok(Self::NotIn),
+            "between" => Ok(Self::Between),
+            "begins_with" => Ok(Self::BeginsWith),
+            "today" => Ok(Self::Today),
+            "yesterday" => Ok(Self::Yesterday),
+            "this_month" => Ok(Self::ThisMonth),
+            "last_n_days" => Ok(Self::LastNDays),
             _ => Err(qryvanta_core::AppError::Validation(format!(
                 "unknown runtime query operator '{value}'"
             ))),
@@ -84,6 +114,50 @@ impl RuntimeRecordOperator {
             Self::Lte => "lte",
             Self::Contains => "contains",
             Self::In => "in",
+            Self::IsNull => "is_null",
+            Self::IsNotNull => "is_not_null",
+            Self::NotIn => "not_in",
+            Self::Between => "between",
+            Self::BeginsWith => "begins_with",
+            Self::Today => "today",
+            Self::Yesterday => "yesterday",
+            Self::ThisMonth => "this_month",
+            Self::LastNDays => "last_n_days",
+        }
+    }
+
+    /// Returns whether the operator compares against a date window relative to today.
+    #[must_use]
+    pub fn is_relative_date(&self) -> bool {
+        matches!(
+            self,
+            Self::Today | Self::Yesterday | Self::ThisMonth | Self::LastNDays
+        )
+    }
+
+    /// Returns the `[start, end)` date window of a relative date operator.
+    ///
+    /// `value` carries the day count for `last_n_days` and is ignored otherwise.
+    /// Returns `None` for other operators or an invalid day count.
+    #[must_use]
+    pub fn relative_date_window(
+        &self,
+        value: &Value,
+        today: NaiveDate,
+    ) -> Option<(NaiveDate, NaiveDate)> {
+        let tomorrow = today.succ_opt()?;
+        match self {
+            Self::Today => Some((today, tomorrow)),
+            Self::Yesterday => Some((today.pred_opt()?, today)),
+            Self::ThisMonth => {
+                let start = today.with_day(1)?;
+                Some((start, start.checked_add_months(Months::new(1))?))
+            }
+            Self::LastNDays => {
+                let days = value.as_u64().filter(|days| (1..=3650).contains(days))?;
+                Some((today.checked_sub_days(Days::new(days - 1))?, tomorrow))
+            }
+            _ => None,
         }
     }
 }
@@ -128,6 +202,16 @@ impl RuntimeRecordJoinType {
     }
 }
 
+/// Cardinality of the relation field a runtime query link traverses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RuntimeRecordLinkCardinality {
+    /// Parent records store the target record identifier in their payload.
+    #[default]
+    ManyToOne,
+    /// Parent and target records are paired through record associations.
+    ManyToMany,
+}
+
 impl RuntimeRecordSortDirection {
     /// Parses transport value into sort direction.
     pub fn parse_transport(value: &str) -> AppResult<Self> {
@@ -168,6 +252,8 @@ pub struct RecordListQuery {
     pub offset: usize,
     /// Optional subject ownership filter.
     pub owner_subject: Option<String>,
+    /// Whether records in inactive statuses are returned.
+    pub include_inactive: bool,
 }
 
 /// Typed condition for runtime record queries.
@@ -211,6 +297,8 @@ pub struct RuntimeRecordLink {
     pub target_entity_logical_name: String,
     /// Join behavior for missing relation targets.
     pub join_type: RuntimeRecordJoinType,
+    /// Relation cardinality resolved from the relation field type.
+    pub cardinality: RuntimeRecordLinkCardinality,
 }
 
 /// Recursive runtime query condition tree.
@@ -250,4 +338,21 @@ pub struct RuntimeRecordQuery {
     pub sort: Vec<RuntimeRecordSort>,
     /// Optional subject ownership filter.
     pub owner_subject: Option<String>,
+    /// Whether records in inactive statuses are returned.
+    pub include_inactive: bool,
+}
+
+/// Result of transferring a runtime record to a new owner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeRecordOwnerAssignment {
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Runtime record identifier.
+    pub record_id: String,
+    /// Owner subject before the transfer.
+    pub previous_owner_subject: String,
+    /// Owner subject after the transfer.
+    pub owner_subject: String,
+    /// Child records reassigned through relation cascade behaviors.
+    pub cascades: Vec<RelationCascadeResult>,
 }
@@ -0,0 +1,30 @@
+use qryvanta_domain::RuntimeRecord;
+
+/// Whether a runtime record upsert created or updated its record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeRecordUpsertOutcome {
+    /// No record held the alternate key value, so one was created.
+    Created,
+    /// The record holding the alternate key value was updated.
+    Updated,
+}
+
+impl RuntimeRecordUpsertOutcome {
+    /// Returns a stable transport value.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+        }
+    }
+}
+
+/// Result of a runtime record upsert by alternate key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeRecordUpsertResult {
+    /// Whether the record was created or updated.
+    pub outcome: RuntimeRecordUpsertOutcome,
+    /// Record state after the write.
+    pub record: RuntimeRecord,
+}
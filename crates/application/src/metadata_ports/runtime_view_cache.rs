@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use qryvanta_core::{AppResult, TenantId};
+use qryvanta_domain::RuntimeRecord;
+
+/// Identifies one cached view result.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RuntimeViewCacheKey {
+    /// Tenant the view runs in.
+    pub tenant_id: TenantId,
+    /// Entity the view queries.
+    pub entity_logical_name: String,
+    /// View logical name.
+    pub view_logical_name: String,
+    /// Stable digest of the executed query.
+    pub query_fingerprint: String,
+    /// Subject the result was scoped to by read scope and field access.
+    pub subject: String,
+}
+
+/// Cache lookup result with the entity generation it was read at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeViewCacheLookup {
+    /// Cached records, when a live entry exists.
+    pub records: Option<Vec<RuntimeRecord>>,
+    /// Entity generation observed by the lookup.
+    pub generation: i64,
+}
+
+/// Optional cache port for hot view results.
+///
+/// Every entity has a generation that record writes advance, so results
+/// computed before a write can never be stored for readers after it.
+#[async_trait]
+pub trait RuntimeViewResultCache: Send + Sync {
+    /// Returns the cached records for one view execution.
+    async fn get_view_result(&self, key: &RuntimeViewCacheKey)
+    -> AppResult<RuntimeViewCacheLookup>;
+
+    /// Stores records computed at the given entity generation with ttl.
+    async fn set_view_result(
+        &self,
+        key: RuntimeViewCacheKey,
+        generation: i64,
+        records: Vec<RuntimeRecord>,
+        ttl_seconds: u32,
+    ) -> AppResult<()>;
+
+    /// Advances the entity generation after record writes.
+    async fn invalidate_entity(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<()>;
+}
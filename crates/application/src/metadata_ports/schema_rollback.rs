@@ -0,0 +1,24 @@
+/// Compatibility report for republishing an earlier published schema version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntitySchemaRollbackChecks {
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Latest published schema version.
+    pub current_version: i32,
+    /// Published schema version that would be republished.
+    pub target_version: i32,
+    /// Fields of the current version missing from the target version.
+    ///
+    /// Stored values are kept but are no longer exposed through the schema.
+    pub orphaned_fields: Vec<String>,
+    /// Runtime data incompatibilities that prevent the rollback.
+    pub errors: Vec<String>,
+}
+
+impl EntitySchemaRollbackChecks {
+    /// Returns whether the rollback can be applied.
+    #[must_use]
+    pub fn can_rollback(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
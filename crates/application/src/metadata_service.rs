@@ -5,22 +5,34 @@ use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
 use qryvanta_domain::{
     AuditAction, BusinessRuleActionType, BusinessRuleCondition, BusinessRuleDefinition,
     BusinessRuleDefinitionInput, BusinessRuleOperator, BusinessRuleScope, EntityDefinition,
-    EntityFieldDefinition, EntityFieldMutableUpdateInput, FieldType, FormDefinition,
-    FormFieldPlacement, FormSection, FormTab, FormType, OptionSetDefinition, Permission,
-    PublishedEntitySchema, RuntimeRecord, SortDirection, ViewColumn, ViewDefinition, ViewSort,
-    ViewType,
+    EntityFieldDefinition, EntityFieldMutableUpdateInput, FieldMaskingMode, FieldType,
+    FormDefinition, FormFieldPlacement, FormSection, FormTab, FormType, OptionSetDefinition,
+    Permission, PublishedEntitySchema, RuntimeRecord, SortDirection, SubjectFilterToken,
+    SystemField, ViewColumn, ViewDefinition, ViewSort, ViewType,
 };
 use serde_json::Value;
 use sha2::{Digest, Sha256};
 
 use crate::AuthorizationService;
+use crate::app_ports::AppRepository;
+use crate::compliance_zone_service::ComplianceZoneRepository;
+use crate::extension_ports::ExtensionRepository;
+use crate::field_change_approval_service::FieldChangeApprovalRepository;
+use crate::field_masking_service::FieldMaskingRepository;
+use crate::legal_hold_service::LegalHoldRepository;
 use crate::metadata_ports::{
-    AuditEvent, AuditRepository, MetadataRepositoryByConcern, RecordListQuery,
-    RuntimeRecordConditionGroup, RuntimeRecordConditionNode, RuntimeRecordFilter,
-    RuntimeRecordOperator, RuntimeRecordQuery, RuntimeRecordSort, SaveBusinessRuleInput,
-    SaveFieldInput, SaveFormInput, SaveOptionSetInput, SaveViewInput, UniqueFieldValue,
-    UpdateEntityInput, UpdateFieldInput,
+    AuditEvent, AuditRepository, EntityDependency, EntityDependencyKind, EntityDependencyReport,
+    MetadataRepositoryByConcern, PublishedEntitySchemaVersion, RecordListQuery, RelationBehavior,
+    RuntimeRecordAssociation, RuntimeRecordConditionGroup, RuntimeRecordConditionNode,
+    RuntimeRecordFilter, RuntimeRecordLink, RuntimeRecordLinkCardinality, RuntimeRecordOperator,
+    RuntimeRecordOwnerAssignment, RuntimeRecordOwnerDirectory, RuntimeRecordQuery,
+    RuntimeRecordSort, RuntimeViewCacheKey, RuntimeViewResultCache, SaveBusinessRuleInput,
+    SaveFieldInput, SaveFormInput, SaveOptionSetInput, SaveRelationBehaviorInput, SaveViewInput,
+    UniqueFieldValue, UpdateEntityInput, UpdateFieldInput,
 };
+use crate::operation_timeouts::{OperationDeadlines, TimedOperation};
+use crate::record_access_service::RecordAccessRepository;
+use crate::workflow_ports::WorkflowRepository;
 
 /// Application service for metadata and runtime record operations.
 #[derive(Clone)]
@@ -28,6 +40,19 @@ pub struct MetadataService {
     repository: Arc<dyn MetadataRepositoryByConcern>,
     authorization_service: AuthorizationService,
     audit_repository: Arc<dyn AuditRepository>,
+    legal_hold_repository: Option<Arc<dyn LegalHoldRepository>>,
+    compliance_zone_repository: Option<Arc<dyn ComplianceZoneRepository>>,
+    field_change_approval_repository: Option<Arc<dyn FieldChangeApprovalRepository>>,
+    field_masking_repository: Option<Arc<dyn FieldMaskingRepository>>,
+    record_access_repository: Option<Arc<dyn RecordAccessRepository>>,
+    record_owner_directory: Option<Arc<dyn RuntimeRecordOwnerDirectory>>,
+    extension_repository: Option<Arc<dyn ExtensionRepository>>,
+    app_repository: Option<Arc<dyn AppRepository>>,
+    workflow_repository: Option<Arc<dyn WorkflowRepository>>,
+    audit_outbox_enabled: bool,
+    operation_deadlines: Option<OperationDeadlines>,
+    view_result_cache: Option<Arc<dyn RuntimeViewResultCache>>,
+    view_result_cache_ttl_seconds: u32,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -42,7 +67,15 @@ struct EntityBusinessRuleEffects {
     visibility_overrides: BTreeMap<String, bool>,
     lock_overrides: BTreeMap<String, bool>,
     value_patches: BTreeMap<String, Value>,
-    error_messages: Vec<String>,
+    rejections: Vec<BusinessRuleRejection>,
+}
+
+/// Save rejection raised by a `show_error` or `prevent_save` rule action.
+#[derive(Debug)]
+struct BusinessRuleRejection {
+    rule_logical_name: String,
+    action_type: BusinessRuleActionType,
+    message: String,
 }
 
 impl EntityBusinessRuleEffects {
@@ -57,12 +90,29 @@ impl EntityBusinessRuleEffects {
 mod definitions_business_rules;
 mod definitions_components;
 mod definitions_entities;
+mod duplicate_detection;
+mod entity_lifecycle;
+mod field_impacts;
 mod portability;
 mod publish;
 mod publish_access;
 mod publish_defaults;
 mod publish_validation;
+mod record_associations;
+mod record_history;
+mod record_slugs;
+mod record_status;
+mod relation_behaviors;
+mod relation_lookups;
+mod reporting_projections;
 mod runtime_access;
+mod runtime_aggregate;
+mod runtime_changesets;
+mod runtime_compliance_zones;
+mod runtime_export;
+mod runtime_field_approvals;
+mod runtime_field_masking;
+mod runtime_patches;
 mod runtime_payload;
 mod runtime_payload_calculation;
 mod runtime_payload_normalization;
@@ -71,14 +121,26 @@ mod runtime_payload_rules;
 mod runtime_query;
 mod runtime_query_links;
 mod runtime_query_validation;
+mod runtime_quick_create;
 mod runtime_records_read;
 mod runtime_records_write;
+mod runtime_upserts;
+mod runtime_validation_audit;
+mod runtime_view_cache;
 mod runtime_write;
+mod schema_export;
+mod schema_rollback;
 
 pub use portability::{
     ExportWorkspaceBundleOptions, ImportWorkspaceBundleOptions, ImportWorkspaceBundleResult,
     PortableEntityBundle, PortableRuntimeRecord, WorkspacePortableBundle, WorkspacePortablePayload,
 };
+pub use runtime_export::{
+    RUNTIME_RECORD_EXPORT_PAGE_SIZE, RuntimeRecordExport, RuntimeRecordExportColumn,
+};
+pub use runtime_validation_audit::{
+    RuntimeRecordValidationPage, RuntimeRecordValidationRule, RuntimeRecordViolation,
+};
 
 impl MetadataService {
     /// Creates a new metadata service from a repository implementation.
@@ -92,7 +154,192 @@ impl MetadataService {
             repository,
             authorization_service,
             audit_repository,
+            legal_hold_repository: None,
+            compliance_zone_repository: None,
+            field_change_approval_repository: None,
+            field_masking_repository: None,
+            record_access_repository: None,
+            record_owner_directory: None,
+            extension_repository: None,
+            app_repository: None,
+            workflow_repository: None,
+            audit_outbox_enabled: false,
+            operation_deadlines: None,
+            view_result_cache: None,
+            view_result_cache_ttl_seconds: 0,
+        }
+    }
+
+    /// Enables legal hold enforcement for runtime record deletes.
+    #[must_use]
+    pub fn with_legal_hold_repository(
+        mut self,
+        legal_hold_repository: Arc<dyn LegalHoldRepository>,
+    ) -> Self {
+        self.legal_hold_repository = Some(legal_hold_repository);
+        self
+    }
+
+    /// Enables compliance zone enforcement for runtime record reads and writes.
+    #[must_use]
+    pub fn with_compliance_zone_repository(
+        mut self,
+        compliance_zone_repository: Arc<dyn ComplianceZoneRepository>,
+    ) -> Self {
+        self.compliance_zone_repository = Some(compliance_zone_repository);
+        self
+    }
+
+    /// Enables dual-control approval for sensitive runtime fields.
+    #[must_use]
+    pub fn with_field_change_approval_repository(
+        mut self,
+        field_change_approval_repository: Arc<dyn FieldChangeApprovalRepository>,
+    ) -> Self {
+        self.field_change_approval_repository = Some(field_change_approval_repository);
+        self
+    }
+
+    /// Enables role-based masking of classified fields in runtime reads.
+    #[must_use]
+    pub fn with_field_masking_repository(
+        mut self,
+        field_masking_repository: Arc<dyn FieldMaskingRepository>,
+    ) -> Self {
+        self.field_masking_repository = Some(field_masking_repository);
+        self
+    }
+
+    /// Enables record shares created by approved access requests.
+    #[must_use]
+    pub fn with_record_access_repository(
+        mut self,
+        record_access_repository: Arc<dyn RecordAccessRepository>,
+    ) -> Self {
+        self.record_access_repository = Some(record_access_repository);
+        self
+    }
+
+    /// Restricts record owner assignment to tenant members and teams.
+    #[must_use]
+    pub fn with_record_owner_directory(
+        mut self,
+        record_owner_directory: Arc<dyn RuntimeRecordOwnerDirectory>,
+    ) -> Self {
+        self.record_owner_directory = Some(record_owner_directory);
+        self
+    }
+
+    /// Enables custom action references in form scripting events.
+    #[must_use]
+    pub fn with_extension_repository(
+        mut self,
+        extension_repository: Arc<dyn ExtensionRepository>,
+    ) -> Self {
+        self.extension_repository = Some(extension_repository);
+        self
+    }
+
+    /// Enables app binding checks in entity dependency analysis and saved query
+    /// checks in field impact analysis.
+    #[must_use]
+    pub fn with_app_repository(mut self, app_repository: Arc<dyn AppRepository>) -> Self {
+        self.app_repository = Some(app_repository);
+        self
+    }
+
+    /// Enables workflow checks in entity dependency and field impact analysis.
+    #[must_use]
+    pub fn with_workflow_repository(
+        mut self,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+    ) -> Self {
+        self.workflow_repository = Some(workflow_repository);
+        self
+    }
+
+    /// Hands runtime record audit events to the repository so they commit
+    /// with the record write instead of being appended afterwards.
+    ///
+    /// Enable this only when the repository writes an audit outbox that an
+    /// [`crate::AuditOutboxRelay`] drains into the audit log.
+    #[must_use]
+    pub fn with_audit_outbox(mut self, enabled: bool) -> Self {
+        self.audit_outbox_enabled = enabled;
+        self
+    }
+
+    /// Bounds runtime queries and publish with per-operation latency budgets.
+    #[must_use]
+    pub fn with_operation_deadlines(mut self, operation_deadlines: OperationDeadlines) -> Self {
+        self.operation_deadlines = Some(operation_deadlines);
+        self
+    }
+
+    /// Enables short-lived result caching for view executions.
+    #[must_use]
+    pub fn with_view_result_cache(
+        mut self,
+        view_result_cache: Arc<dyn RuntimeViewResultCache>,
+        ttl_seconds: u32,
+    ) -> Self {
+        self.view_result_cache = Some(view_result_cache);
+        self.view_result_cache_ttl_seconds = ttl_seconds;
+        self
+    }
+
+    async fn within_operation_budget<T>(
+        &self,
+        operation: TimedOperation,
+        future: impl Future<Output = AppResult<T>>,
+    ) -> AppResult<T> {
+        match &self.operation_deadlines {
+            Some(operation_deadlines) => operation_deadlines.run(operation, future).await,
+            None => future.await,
+        }
+    }
+
+    pub(super) async fn runtime_record_shared_with_actor(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<bool> {
+        let Some(record_access_repository) = &self.record_access_repository else {
+            return Ok(false);
+        };
+
+        record_access_repository
+            .has_active_share(
+                actor.tenant_id(),
+                entity_logical_name,
+                record_id,
+                actor.subject(),
+            )
+            .await
+    }
+
+    pub(super) async fn require_runtime_record_not_held(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<()> {
+        let Some(legal_hold_repository) = &self.legal_hold_repository else {
+            return Ok(());
+        };
+
+        if let Some(hold) = legal_hold_repository
+            .find_active_record_hold(tenant_id, entity_logical_name, record_id)
+            .await?
+        {
+            return Err(AppError::Conflict(format!(
+                "runtime record '{}' in entity '{}' is under legal hold '{}' and cannot be deleted",
+                record_id, entity_logical_name, hold.name
+            )));
         }
+
+        Ok(())
     }
 
     pub(super) async fn require_entity_exists(
@@ -195,7 +195,8 @@ impl MetadataService {
             input.form_type,
             input.tabs,
             input.header_fields,
-        )?;
+        )?
+        .with_script_events(input.script_events);
 
         let schema = self
             .published_schema_for_runtime(actor.tenant_id(), form.entity_logical_name().as_str())
@@ -8,11 +8,12 @@ impl MetadataService {
         logical_name: impl Into<String>,
         display_name: impl Into<String>,
     ) -> AppResult<EntityDefinition> {
-        self.register_entity_with_details(actor, logical_name, display_name, None, None, None)
+        self.register_entity_with_details(actor, logical_name, display_name, None, None, None, None)
             .await
     }
 
     /// Registers a new entity definition with optional enriched metadata.
+    #[allow(clippy::too_many_arguments)]
     pub async fn register_entity_with_details(
         &self,
         actor: &UserIdentity,
@@ -21,6 +22,7 @@ impl MetadataService {
         description: Option<String>,
         plural_display_name: Option<String>,
         icon: Option<String>,
+        color: Option<String>,
     ) -> AppResult<EntityDefinition> {
         self.authorization_service
             .require_permission(
@@ -44,6 +46,7 @@ impl MetadataService {
             description,
             plural_display_name,
             icon,
+            color,
         )?;
         self.repository
             .save_entity(actor.tenant_id(), entity.clone())
@@ -110,6 +113,7 @@ impl MetadataService {
             input.description,
             input.plural_display_name,
             input.icon,
+            input.color,
         )?;
 
         self.repository
@@ -190,16 +194,25 @@ impl MetadataService {
             None,
             None,
             None,
-        )?;
+        )?
+        .with_classification(input.classification);
 
-        if let Some(existing) = self
+        let existing_field = self
             .repository
             .find_field(
                 actor.tenant_id(),
                 field.entity_logical_name().as_str(),
                 field.logical_name().as_str(),
             )
-            .await?
+            .await?;
+        if existing_field.is_none() && SystemField::parse(field.logical_name().as_str()).is_some() {
+            return Err(AppError::Validation(format!(
+                "field logical name '{}' is reserved for a system field",
+                field.logical_name().as_str()
+            )));
+        }
+
+        if let Some(existing) = existing_field
             && self
                 .repository
                 .field_exists_in_published_schema(
@@ -311,6 +324,7 @@ impl MetadataService {
                 max_length: input.max_length,
                 min_value: input.min_value,
                 max_value: input.max_value,
+                classification: input.classification,
             })?;
 
         self.repository
@@ -0,0 +1,299 @@
+use super::runtime_records_write::is_internal_workflow_subject;
+use super::*;
+use crate::{RuntimeRecordLogicalMode, SaveDuplicateDetectionRuleInput};
+use qryvanta_domain::{
+    DuplicateDetectionRule, DuplicateMatchMode, DuplicateRecordCandidate, DuplicateRecordsConflict,
+    DuplicateRuleAction, normalize_duplicate_text,
+};
+
+/// Records fetched per rule before fuzzy comparison narrows them down.
+const DUPLICATE_RULE_SCAN_LIMIT: usize = 50;
+
+/// Candidates reported per rule.
+const DUPLICATE_RULE_CANDIDATE_LIMIT: usize = 10;
+
+impl MetadataService {
+    /// Creates or replaces a duplicate-detection rule of an entity.
+    ///
+    /// Match fields must be fields of the entity, and fuzzy matching is only
+    /// available for text fields.
+    pub async fn save_duplicate_detection_rule(
+        &self,
+        actor: &UserIdentity,
+        input: SaveDuplicateDetectionRuleInput,
+    ) -> AppResult<DuplicateDetectionRule> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await?;
+
+        let rule = DuplicateDetectionRule::new(
+            input.entity_logical_name,
+            input.logical_name,
+            input.display_name,
+            input.match_fields,
+            input.action,
+            input.is_active,
+        )?;
+        let fields = self
+            .repository
+            .list_fields(actor.tenant_id(), rule.entity_logical_name())
+            .await?;
+        for match_field in rule.match_fields() {
+            let field = fields
+                .iter()
+                .find(|field| field.logical_name().as_str() == match_field.field_logical_name)
+                .ok_or_else(|| {
+                    AppError::Validation(format!(
+                        "duplicate rule field '{}.{}' does not exist",
+                        rule.entity_logical_name(),
+                        match_field.field_logical_name
+                    ))
+                })?;
+            if match_field.match_mode == DuplicateMatchMode::Fuzzy
+                && field.field_type() != FieldType::Text
+            {
+                return Err(AppError::Validation(format!(
+                    "duplicate rule field '{}.{}' must be a text field for fuzzy matching",
+                    rule.entity_logical_name(),
+                    match_field.field_logical_name
+                )));
+            }
+        }
+
+        self.repository
+            .save_duplicate_detection_rule(actor.tenant_id(), actor.subject(), rule.clone())
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::MetadataDuplicateRuleSaved,
+                resource_type: "entity_definition".to_owned(),
+                resource_id: format!("{}.{}", rule.entity_logical_name(), rule.logical_name()),
+                detail: Some(format!(
+                    "saved {} duplicate rule '{}' of entity '{}' matching {} fields",
+                    rule.action().as_str(),
+                    rule.logical_name(),
+                    rule.entity_logical_name(),
+                    rule.match_fields().len()
+                )),
+            })
+            .await?;
+
+        Ok(rule)
+    }
+
+    /// Lists the duplicate-detection rules of an entity.
+    pub async fn list_duplicate_detection_rules(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<DuplicateDetectionRule>> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldRead,
+            )
+            .await?;
+
+        self.repository
+            .list_duplicate_detection_rules(actor.tenant_id(), entity_logical_name)
+            .await
+    }
+
+    /// Removes a duplicate-detection rule of an entity.
+    pub async fn delete_duplicate_detection_rule(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        logical_name: &str,
+    ) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await?;
+
+        if !self
+            .repository
+            .delete_duplicate_detection_rule(actor.tenant_id(), entity_logical_name, logical_name)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "duplicate rule '{}' does not exist for entity '{}'",
+                logical_name, entity_logical_name
+            )));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::MetadataDuplicateRuleDeleted,
+                resource_type: "entity_definition".to_owned(),
+                resource_id: format!("{}.{}", entity_logical_name, logical_name),
+                detail: Some(format!(
+                    "removed duplicate rule '{}' of entity '{}'",
+                    logical_name, entity_logical_name
+                )),
+            })
+            .await
+    }
+
+    /// Rejects a runtime write whose data matches existing records under an
+    /// active duplicate rule.
+    ///
+    /// Blocking rules always reject the write. Warning rules are skipped when
+    /// the caller confirmed the write or a workflow performs it. The conflict
+    /// carries the matched records so clients can offer a merge.
+    pub(super) async fn ensure_no_duplicate_records(
+        &self,
+        actor: &UserIdentity,
+        schema: &PublishedEntitySchema,
+        record_data: &Value,
+        record_id: Option<&str>,
+        ignore_duplicate_warnings: bool,
+    ) -> AppResult<()> {
+        let entity_logical_name = schema.entity().logical_name().as_str();
+        let ignore_warnings =
+            ignore_duplicate_warnings || is_internal_workflow_subject(actor.subject());
+        let rules = self
+            .repository
+            .list_duplicate_detection_rules(actor.tenant_id(), entity_logical_name)
+            .await?
+            .into_iter()
+            .filter(|rule| {
+                rule.is_active() && !(ignore_warnings && rule.action() == DuplicateRuleAction::Warn)
+            })
+            .collect::<Vec<_>>();
+        if rules.is_empty() {
+            return Ok(());
+        }
+
+        let fields = schema.queryable_fields()?;
+        let mut candidates = Vec::new();
+        for rule in &rules {
+            let Some(mut query) = duplicate_rule_query(rule, &fields, record_data) else {
+                continue;
+            };
+            self.exclude_inactive_records(actor.tenant_id(), schema, &mut query)
+                .await?;
+            let matches = self
+                .repository
+                .query_runtime_records(actor.tenant_id(), entity_logical_name, query)
+                .await?
+                .into_iter()
+                .filter(|record| Some(record.record_id().as_str()) != record_id)
+                .filter(|record| fuzzy_fields_match(rule, record_data, record.data()))
+                .take(DUPLICATE_RULE_CANDIDATE_LIMIT);
+            candidates.extend(matches.map(|record| {
+                DuplicateRecordCandidate {
+                    record_id: record.record_id().as_str().to_owned(),
+                    rule_logical_name: rule.logical_name().to_owned(),
+                    action: rule.action(),
+                    matched_fields: rule
+                        .match_fields()
+                        .iter()
+                        .map(|match_field| match_field.field_logical_name.clone())
+                        .collect(),
+                }
+            }));
+        }
+
+        let Some(action) = candidates.iter().map(|candidate| candidate.action).max() else {
+            return Ok(());
+        };
+        Err(DuplicateRecordsConflict {
+            entity_logical_name: entity_logical_name.to_owned(),
+            action,
+            candidates,
+        }
+        .into_error())
+    }
+}
+
+/// Builds the candidate query of a rule, or `None` when the record leaves a
+/// match field empty or the rule references an unpublished field.
+///
+/// Fuzzy fields narrow candidates by their longest word; the exact fuzzy
+/// comparison happens on the fetched records.
+fn duplicate_rule_query(
+    rule: &DuplicateDetectionRule,
+    fields: &[EntityFieldDefinition],
+    record_data: &Value,
+) -> Option<RuntimeRecordQuery> {
+    let mut filters = Vec::new();
+    for match_field in rule.match_fields() {
+        let field = fields
+            .iter()
+            .find(|field| field.logical_name().as_str() == match_field.field_logical_name)?;
+        let value = record_data
+            .get(match_field.field_logical_name.as_str())
+            .filter(|value| !value.is_null())?;
+        let (operator, field_value) = match (match_field.match_mode, value.as_str()) {
+            (_, Some(text)) if text.trim().is_empty() => return None,
+            (DuplicateMatchMode::Fuzzy, Some(text)) => {
+                let longest_word = text
+                    .split(|character: char| !character.is_alphanumeric())
+                    .max_by_key(|word| word.chars().count())
+                    .filter(|word| !word.is_empty())?;
+                (
+                    RuntimeRecordOperator::Contains,
+                    Value::String(longest_word.to_owned()),
+                )
+            }
+            _ => (RuntimeRecordOperator::Eq, value.clone()),
+        };
+        filters.push(RuntimeRecordFilter {
+            scope_alias: None,
+            field_logical_name: match_field.field_logical_name.clone(),
+            operator,
+            field_type: field.field_type(),
+            field_value,
+        });
+    }
+
+    Some(RuntimeRecordQuery {
+        limit: DUPLICATE_RULE_SCAN_LIMIT,
+        offset: 0,
+        logical_mode: RuntimeRecordLogicalMode::And,
+        where_clause: None,
+        filters,
+        links: Vec::new(),
+        sort: Vec::new(),
+        owner_subject: None,
+        include_inactive: false,
+    })
+}
+
+fn fuzzy_fields_match(
+    rule: &DuplicateDetectionRule,
+    record_data: &Value,
+    candidate_data: &Value,
+) -> bool {
+    rule.match_fields()
+        .iter()
+        .filter(|match_field| match_field.match_mode == DuplicateMatchMode::Fuzzy)
+        .all(|match_field| {
+            let text = |data: &Value| {
+                data.get(match_field.field_logical_name.as_str())
+                    .and_then(Value::as_str)
+                    .map(normalize_duplicate_text)
+            };
+            match (text(record_data), text(candidate_data)) {
+                (Some(expected), Some(candidate)) => expected == candidate,
+                _ => {
+                    record_data.get(match_field.field_logical_name.as_str())
+                        == candidate_data.get(match_field.field_logical_name.as_str())
+                }
+            }
+        })
+}
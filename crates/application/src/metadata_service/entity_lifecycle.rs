@@ -0,0 +1,353 @@
+use super::*;
+use crate::LegalHoldScope;
+
+impl MetadataService {
+    /// Reports every component that references an entity.
+    ///
+    /// Forms and views of the entity itself are listed for context but are
+    /// deleted with it. Relations, sub-grids on other entities' forms, app
+    /// bindings, enabled workflows and active legal holds block deletion.
+    pub async fn analyze_entity_dependencies(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<EntityDependencyReport> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataEntityRead,
+            )
+            .await?;
+
+        let tenant_id = actor.tenant_id();
+        let entity = self
+            .find_entity_for_lifecycle(tenant_id, entity_logical_name)
+            .await?;
+        let mut dependencies = Vec::new();
+
+        for form in self
+            .repository
+            .list_forms(tenant_id, entity_logical_name)
+            .await?
+        {
+            dependencies.push(EntityDependency {
+                kind: EntityDependencyKind::Form,
+                component_key: format!("{}.{}", entity_logical_name, form.logical_name().as_str()),
+                detail: "form of the entity; deleted with it".to_owned(),
+                blocks_delete: false,
+            });
+        }
+
+        for view in self
+            .repository
+            .list_views(tenant_id, entity_logical_name)
+            .await?
+        {
+            dependencies.push(EntityDependency {
+                kind: EntityDependencyKind::View,
+                component_key: format!("{}.{}", entity_logical_name, view.logical_name().as_str()),
+                detail: "view of the entity; deleted with it".to_owned(),
+                blocks_delete: false,
+            });
+        }
+
+        for other in self.repository.list_entities(tenant_id).await? {
+            let other_logical_name = other.logical_name().as_str();
+            if other_logical_name == entity_logical_name {
+                continue;
+            }
+
+            for field in self
+                .repository
+                .list_fields(tenant_id, other_logical_name)
+                .await?
+            {
+                if field
+                    .relation_target_entity()
+                    .is_some_and(|target| target.as_str() == entity_logical_name)
+                {
+                    dependencies.push(EntityDependency {
+                        kind: EntityDependencyKind::Relation,
+                        component_key: format!(
+                            "{}.{}",
+                            other_logical_name,
+                            field.logical_name().as_str()
+                        ),
+                        detail: format!("{} field targets the entity", field.field_type().as_str()),
+                        blocks_delete: true,
+                    });
+                }
+            }
+
+            for form in self
+                .repository
+                .list_forms(tenant_id, other_logical_name)
+                .await?
+            {
+                for subgrid in form
+                    .tabs()
+                    .iter()
+                    .flat_map(|tab| tab.sections())
+                    .flat_map(|section| section.subgrids())
+                    .filter(|subgrid| {
+                        subgrid.target_entity_logical_name().as_str() == entity_logical_name
+                    })
+                {
+                    dependencies.push(EntityDependency {
+                        kind: EntityDependencyKind::Form,
+                        component_key: format!(
+                            "{}.{}",
+                            other_logical_name,
+                            form.logical_name().as_str()
+                        ),
+                        detail: format!(
+                            "sub-grid '{}' lists records of the entity",
+                            subgrid.logical_name().as_str()
+                        ),
+                        blocks_delete: true,
+                    });
+                }
+            }
+        }
+
+        if let Some(app_repository) = &self.app_repository {
+            for app in app_repository.list_apps(tenant_id).await? {
+                let app_logical_name = app.logical_name().as_str();
+                if app_repository
+                    .list_app_entity_bindings(tenant_id, app_logical_name)
+                    .await?
+                    .iter()
+                    .any(|binding| binding.entity_logical_name().as_str() == entity_logical_name)
+                {
+                    dependencies.push(EntityDependency {
+                        kind: EntityDependencyKind::AppBinding,
+                        component_key: app_logical_name.to_owned(),
+                        detail: "app navigation binds the entity".to_owned(),
+                        blocks_delete: true,
+                    });
+                }
+            }
+        }
+
+        if let Some(workflow_repository) = &self.workflow_repository {
+            for workflow in workflow_repository.list_workflows(tenant_id).await? {
+                if !workflow.references_entity(entity_logical_name) {
+                    continue;
+                }
+
+                let blocks_delete = workflow.is_enabled();
+                dependencies.push(EntityDependency {
+                    kind: EntityDependencyKind::Workflow,
+                    component_key: workflow.logical_name().as_str().to_owned(),
+                    detail: if blocks_delete {
+                        "enabled workflow triggers on or writes to the entity".to_owned()
+                    } else {
+                        "workflow is not enabled; republishing it after deletion fails".to_owned()
+                    },
+                    blocks_delete,
+                });
+            }
+        }
+
+        if let Some(legal_hold_repository) = &self.legal_hold_repository {
+            for hold in legal_hold_repository.list_holds(tenant_id, false).await? {
+                if hold.is_active()
+                    && matches!(
+                        &hold.scope,
+                        LegalHoldScope::RuntimeRecords {
+                            entity_logical_name: held_entity,
+                            ..
+                        } if held_entity == entity_logical_name
+                    )
+                {
+                    dependencies.push(EntityDependency {
+                        kind: EntityDependencyKind::LegalHold,
+                        component_key: hold.hold_id,
+                        detail: format!("legal hold '{}' covers runtime records", hold.name),
+                        blocks_delete: true,
+                    });
+                }
+            }
+        }
+
+        dependencies.sort_by(|left, right| {
+            left.kind
+                .cmp(&right.kind)
+                .then_with(|| left.component_key.cmp(&right.component_key))
+        });
+        dependencies.dedup();
+
+        Ok(EntityDependencyReport {
+            entity_logical_name: entity_logical_name.to_owned(),
+            is_active: entity.is_active(),
+            dependencies,
+        })
+    }
+
+    /// Stops runtime record creates and updates for an entity.
+    ///
+    /// Existing records stay readable and deletable. Deactivating an already
+    /// inactive entity is a no-op.
+    pub async fn deactivate_entity(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<EntityDefinition> {
+        self.set_entity_active(actor, entity_logical_name, false)
+            .await
+    }
+
+    /// Allows runtime record writes for a deactivated entity again.
+    pub async fn reactivate_entity(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<EntityDefinition> {
+        self.set_entity_active(actor, entity_logical_name, true)
+            .await
+    }
+
+    /// Deletes a deactivated entity together with its fields, forms, views,
+    /// entity-scoped configuration and runtime records.
+    ///
+    /// Fails with a conflict while the entity is active or while any blocking
+    /// dependency from [`Self::analyze_entity_dependencies`] remains.
+    pub async fn delete_entity(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataEntityCreate,
+            )
+            .await?;
+
+        let report = self
+            .analyze_entity_dependencies(actor, entity_logical_name)
+            .await?;
+        if report.is_active {
+            return Err(AppError::Conflict(format!(
+                "entity '{}' must be deactivated before it can be deleted",
+                entity_logical_name
+            )));
+        }
+
+        let blocking = report
+            .blocking_dependencies()
+            .map(|dependency| {
+                format!(
+                    "{} '{}'",
+                    dependency.kind.as_str(),
+                    dependency.component_key
+                )
+            })
+            .collect::<Vec<_>>();
+        if !blocking.is_empty() {
+            return Err(AppError::Conflict(format!(
+                "entity '{}' is still referenced by {}",
+                entity_logical_name,
+                blocking.join(", ")
+            )));
+        }
+
+        self.repository
+            .delete_entity(actor.tenant_id(), entity_logical_name)
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::MetadataEntityDeleted,
+                resource_type: "entity_definition".to_owned(),
+                resource_id: entity_logical_name.to_owned(),
+                detail: Some(format!("deleted metadata entity '{}'", entity_logical_name)),
+            })
+            .await
+    }
+
+    pub(super) async fn require_entity_accepts_record_writes(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<()> {
+        let entity = self
+            .repository
+            .find_entity(tenant_id, entity_logical_name)
+            .await?;
+
+        if entity.is_some_and(|entity| !entity.is_active()) {
+            return Err(AppError::Conflict(format!(
+                "entity '{}' is deactivated and does not accept record writes",
+                entity_logical_name
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn set_entity_active(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        is_active: bool,
+    ) -> AppResult<EntityDefinition> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataEntityCreate,
+            )
+            .await?;
+
+        let entity = self
+            .find_entity_for_lifecycle(actor.tenant_id(), entity_logical_name)
+            .await?;
+        if entity.is_active() == is_active {
+            return Ok(entity);
+        }
+
+        let updated = entity.with_active(is_active);
+        self.repository
+            .update_entity(actor.tenant_id(), updated.clone())
+            .await?;
+
+        let (action, verb) = if is_active {
+            (AuditAction::MetadataEntityReactivated, "reactivated")
+        } else {
+            (AuditAction::MetadataEntityDeactivated, "deactivated")
+        };
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "entity_definition".to_owned(),
+                resource_id: entity_logical_name.to_owned(),
+                detail: Some(format!("{verb} metadata entity '{}'", entity_logical_name)),
+            })
+            .await?;
+
+        Ok(updated)
+    }
+
+    async fn find_entity_for_lifecycle(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<EntityDefinition> {
+        self.repository
+            .find_entity(tenant_id, entity_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "entity '{}' does not exist for tenant '{}'",
+                    entity_logical_name, tenant_id
+                ))
+            })
+    }
+}
@@ -0,0 +1,324 @@
+use qryvanta_domain::ViewFilterGroup;
+
+use super::*;
+use crate::{FieldImpact, FieldImpactKind, FieldImpactReport, FieldImpactSeverity};
+
+impl MetadataService {
+    /// Reports every component that references a field, ranked by severity.
+    ///
+    /// Builders review the report before updating or deleting the field.
+    /// Active business rules, calculated fields, enabled workflows, view
+    /// filters and sub-grid relations rank high; form placements, view columns
+    /// and sorts rank medium; inactive rules and workflows and users' saved
+    /// queries rank low.
+    pub async fn analyze_field_impact(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<FieldImpactReport> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldRead,
+            )
+            .await?;
+
+        let tenant_id = actor.tenant_id();
+        self.require_entity_exists(tenant_id, entity_logical_name)
+            .await?;
+        if self
+            .repository
+            .find_field(tenant_id, entity_logical_name, field_logical_name)
+            .await?
+            .is_none()
+        {
+            return Err(AppError::NotFound(format!(
+                "field '{}.{}' does not exist for tenant '{}'",
+                entity_logical_name, field_logical_name, tenant_id
+            )));
+        }
+
+        let mut impacts = Vec::new();
+
+        for form in self
+            .repository
+            .list_forms(tenant_id, entity_logical_name)
+            .await?
+        {
+            let component_key = format!("{}.{}", entity_logical_name, form.logical_name().as_str());
+            let placed = form
+                .tabs()
+                .iter()
+                .flat_map(|tab| tab.sections())
+                .flat_map(|section| section.fields())
+                .any(|placement| placement.field_logical_name().as_str() == field_logical_name);
+            if placed {
+                impacts.push(FieldImpact {
+                    severity: FieldImpactSeverity::Medium,
+                    kind: FieldImpactKind::Form,
+                    component_key: component_key.clone(),
+                    detail: "form places the field".to_owned(),
+                });
+            }
+            if form
+                .header_fields()
+                .iter()
+                .any(|header_field| header_field == field_logical_name)
+            {
+                impacts.push(FieldImpact {
+                    severity: FieldImpactSeverity::Medium,
+                    kind: FieldImpactKind::Form,
+                    component_key: component_key.clone(),
+                    detail: "form header shows the field".to_owned(),
+                });
+            }
+            if form
+                .script_events()
+                .on_change_fields()
+                .iter()
+                .any(|change_field| change_field == field_logical_name)
+            {
+                impacts.push(FieldImpact {
+                    severity: FieldImpactSeverity::Medium,
+                    kind: FieldImpactKind::Form,
+                    component_key,
+                    detail: "form script handles changes of the field".to_owned(),
+                });
+            }
+        }
+
+        for other in self.repository.list_entities(tenant_id).await? {
+            let other_logical_name = other.logical_name().as_str();
+            for form in self
+                .repository
+                .list_forms(tenant_id, other_logical_name)
+                .await?
+            {
+                for subgrid in form
+                    .tabs()
+                    .iter()
+                    .flat_map(|tab| tab.sections())
+                    .flat_map(|section| section.subgrids())
+                    .filter(|subgrid| {
+                        subgrid.target_entity_logical_name().as_str() == entity_logical_name
+                    })
+                {
+                    let component_key =
+                        format!("{}.{}", other_logical_name, form.logical_name().as_str());
+                    if subgrid.relation_field_logical_name().as_str() == field_logical_name {
+                        impacts.push(FieldImpact {
+                            severity: FieldImpactSeverity::High,
+                            kind: FieldImpactKind::Form,
+                            component_key: component_key.clone(),
+                            detail: format!(
+                                "sub-grid '{}' joins records through the field",
+                                subgrid.logical_name().as_str()
+                            ),
+                        });
+                    }
+                    if subgrid
+                        .columns()
+                        .iter()
+                        .any(|column| column == field_logical_name)
+                    {
+                        impacts.push(FieldImpact {
+                            severity: FieldImpactSeverity::Medium,
+                            kind: FieldImpactKind::Form,
+                            component_key,
+                            detail: format!(
+                                "sub-grid '{}' lists the field as a column",
+                                subgrid.logical_name().as_str()
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+
+        for view in self
+            .repository
+            .list_views(tenant_id, entity_logical_name)
+            .await?
+        {
+            let component_key = format!("{}.{}", entity_logical_name, view.logical_name().as_str());
+            for (severity, detail) in Self::view_field_usages(
+                view.columns(),
+                view.default_sort(),
+                view.filter_criteria(),
+                field_logical_name,
+            ) {
+                impacts.push(FieldImpact {
+                    severity,
+                    kind: FieldImpactKind::View,
+                    component_key: component_key.clone(),
+                    detail: detail.to_owned(),
+                });
+            }
+        }
+
+        for rule in self
+            .repository
+            .list_business_rules(tenant_id, entity_logical_name)
+            .await?
+        {
+            let in_condition = rule
+                .conditions()
+                .iter()
+                .any(|condition| condition.field_logical_name().as_str() == field_logical_name);
+            let in_action = rule.actions().iter().any(|action| {
+                action
+                    .target_field_logical_name()
+                    .is_some_and(|target| target.as_str() == field_logical_name)
+            });
+            let detail = match (in_condition, in_action) {
+                (true, true) => "rule conditions on and acts on the field",
+                (true, false) => "rule conditions on the field",
+                (false, true) => "rule acts on the field",
+                (false, false) => continue,
+            };
+            let (severity, state) = if rule.is_active() {
+                (FieldImpactSeverity::High, "active")
+            } else {
+                (FieldImpactSeverity::Low, "inactive")
+            };
+            impacts.push(FieldImpact {
+                severity,
+                kind: FieldImpactKind::BusinessRule,
+                component_key: format!("{}.{}", entity_logical_name, rule.logical_name().as_str()),
+                detail: format!("{state} {detail}"),
+            });
+        }
+
+        for field in self
+            .repository
+            .list_fields(tenant_id, entity_logical_name)
+            .await?
+        {
+            if field.logical_name().as_str() == field_logical_name {
+                continue;
+            }
+            let Some(expression) = field.calculation_expression() else {
+                continue;
+            };
+            if Self::calculation_reads_field(expression, field_logical_name) {
+                impacts.push(FieldImpact {
+                    severity: FieldImpactSeverity::High,
+                    kind: FieldImpactKind::CalculatedField,
+                    component_key: format!(
+                        "{}.{}",
+                        entity_logical_name,
+                        field.logical_name().as_str()
+                    ),
+                    detail: format!("calculation '{expression}' reads the field"),
+                });
+            }
+        }
+
+        if let Some(workflow_repository) = &self.workflow_repository {
+            for workflow in workflow_repository.list_workflows(tenant_id).await? {
+                let usages = workflow.field_usages(entity_logical_name, field_logical_name);
+                if usages.is_empty() {
+                    continue;
+                }
+
+                let (severity, state) = if workflow.is_enabled() {
+                    (FieldImpactSeverity::High, "enabled")
+                } else {
+                    (FieldImpactSeverity::Low, "inactive")
+                };
+                impacts.push(FieldImpact {
+                    severity,
+                    kind: FieldImpactKind::Workflow,
+                    component_key: workflow.logical_name().as_str().to_owned(),
+                    detail: format!("{state} workflow uses the field in {}", usages.join(", ")),
+                });
+            }
+        }
+
+        if let Some(app_repository) = &self.app_repository {
+            for view in app_repository
+                .list_entity_personal_views(tenant_id, entity_logical_name)
+                .await?
+            {
+                let usages = Self::view_field_usages(
+                    view.columns.as_slice(),
+                    view.default_sort.as_ref(),
+                    view.filter_criteria.as_ref(),
+                    field_logical_name,
+                );
+                if usages.is_empty() {
+                    continue;
+                }
+
+                impacts.push(FieldImpact {
+                    severity: FieldImpactSeverity::Low,
+                    kind: FieldImpactKind::SavedQuery,
+                    component_key: view.view_id,
+                    detail: format!(
+                        "'{}' saved by '{}' in app '{}' {}",
+                        view.display_name,
+                        view.owner_subject,
+                        view.app_logical_name,
+                        usages
+                            .iter()
+                            .map(|(_, detail)| *detail)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                });
+            }
+        }
+
+        impacts.sort_by(|left, right| {
+            left.severity
+                .cmp(&right.severity)
+                .then_with(|| left.kind.cmp(&right.kind))
+                .then_with(|| left.component_key.cmp(&right.component_key))
+        });
+
+        Ok(FieldImpactReport {
+            entity_logical_name: entity_logical_name.to_owned(),
+            field_logical_name: field_logical_name.to_owned(),
+            impacts,
+        })
+    }
+
+    fn view_field_usages(
+        columns: &[ViewColumn],
+        default_sort: Option<&ViewSort>,
+        filter_criteria: Option<&ViewFilterGroup>,
+        field_logical_name: &str,
+    ) -> Vec<(FieldImpactSeverity, &'static str)> {
+        let mut usages = Vec::new();
+        if filter_criteria.is_some_and(|filter| {
+            filter
+                .conditions()
+                .iter()
+                .any(|condition| condition.field_logical_name().as_str() == field_logical_name)
+        }) {
+            usages.push((FieldImpactSeverity::High, "filters on the field"));
+        }
+        if columns
+            .iter()
+            .any(|column| column.field_logical_name().as_str() == field_logical_name)
+        {
+            usages.push((FieldImpactSeverity::Medium, "lists the field as a column"));
+        }
+        if default_sort.is_some_and(|sort| sort.field_logical_name().as_str() == field_logical_name)
+        {
+            usages.push((FieldImpactSeverity::Medium, "sorts by the field"));
+        }
+        usages
+    }
+
+    fn calculation_reads_field(expression: &str, field_logical_name: &str) -> bool {
+        ["add", "concat"].iter().any(|function_name| {
+            Self::parse_calculation_call(expression, function_name)
+                .ok()
+                .flatten()
+                .is_some_and(|args| args.iter().any(|arg| arg.trim() == field_logical_name))
+        })
+    }
+}
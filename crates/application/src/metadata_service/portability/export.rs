@@ -189,6 +189,7 @@ impl MetadataService {
                         limit: page_limit,
                         offset,
                         owner_subject: None,
+                        include_inactive: true,
                     },
                 )
                 .await?;
@@ -30,6 +30,7 @@ impl MetadataService {
                             .plural_display_name()
                             .map(|value| value.as_str().to_owned()),
                         icon: entity_definition.icon().map(ToOwned::to_owned),
+                        color: entity_definition.color().map(ToOwned::to_owned),
                     },
                 )
                 .await?;
@@ -43,6 +44,7 @@ impl MetadataService {
                         .plural_display_name()
                         .map(|value| value.as_str().to_owned()),
                     entity_definition.icon().map(ToOwned::to_owned),
+                    entity_definition.color().map(ToOwned::to_owned),
                 )
                 .await?;
             }
@@ -84,6 +86,7 @@ impl MetadataService {
                         calculation_expression: field
                             .calculation_expression()
                             .map(ToOwned::to_owned),
+                        classification: field.classification(),
                     },
                 )
                 .await?;
@@ -102,6 +105,7 @@ impl MetadataService {
                         max_length: field.max_length(),
                         min_value: field.min_value(),
                         max_value: field.max_value(),
+                        classification: field.classification(),
                     },
                 )
                 .await?;
@@ -145,6 +149,7 @@ impl MetadataService {
                             .iter()
                             .map(|value| value.as_str().to_owned())
                             .collect(),
+                        script_events: form.script_events().clone(),
                     },
                 )
                 .await?;
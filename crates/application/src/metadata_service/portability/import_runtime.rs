@@ -137,8 +137,18 @@ impl MetadataService {
             let unique_values = Self::unique_values_for_record(schema, &plan.rewritten_data)?;
 
             if plan.will_create {
-                let created = self
-                    .repository
+                let audit_event = AuditEvent {
+                    tenant_id: actor.tenant_id(),
+                    subject: actor.subject().to_owned(),
+                    action: AuditAction::RuntimeRecordCreated,
+                    resource_type: "runtime_record".to_owned(),
+                    resource_id: plan.target_record_id.clone(),
+                    detail: Some(format!(
+                        "imported runtime record '{}' for entity '{}'",
+                        plan.target_record_id, plan.entity_logical_name
+                    )),
+                };
+                self.repository
                     .create_runtime_record_with_id(
                         actor.tenant_id(),
                         plan.entity_logical_name.as_str(),
@@ -147,26 +157,29 @@ impl MetadataService {
                         unique_values,
                         actor.subject(),
                         None,
+                        self.runtime_record_outbox_audit_event(&audit_event),
                     )
                     .await?;
 
-                self.audit_repository
-                    .append_event(AuditEvent {
-                        tenant_id: actor.tenant_id(),
-                        subject: actor.subject().to_owned(),
-                        action: AuditAction::RuntimeRecordCreated,
-                        resource_type: "runtime_record".to_owned(),
-                        resource_id: created.record_id().as_str().to_owned(),
-                        detail: Some(format!(
-                            "imported runtime record '{}' for entity '{}'",
-                            created.record_id().as_str(),
-                            plan.entity_logical_name
-                        )),
-                    })
-                    .await?;
+                self.append_runtime_record_audit_event(audit_event).await?;
+                self.invalidate_runtime_view_results(
+                    actor.tenant_id(),
+                    plan.entity_logical_name.as_str(),
+                )
+                .await;
             } else {
-                let updated = self
-                    .repository
+                let audit_event = AuditEvent {
+                    tenant_id: actor.tenant_id(),
+                    subject: actor.subject().to_owned(),
+                    action: AuditAction::RuntimeRecordUpdated,
+                    resource_type: "runtime_record".to_owned(),
+                    resource_id: plan.target_record_id.clone(),
+                    detail: Some(format!(
+                        "imported runtime record update '{}' for entity '{}'",
+                        plan.target_record_id, plan.entity_logical_name
+                    )),
+                };
+                self.repository
                     .update_runtime_record(
                         actor.tenant_id(),
                         plan.entity_logical_name.as_str(),
@@ -174,23 +187,17 @@ impl MetadataService {
                         plan.rewritten_data,
                         unique_values,
                         None,
+                        None,
+                        self.runtime_record_outbox_audit_event(&audit_event),
                     )
                     .await?;
 
-                self.audit_repository
-                    .append_event(AuditEvent {
-                        tenant_id: actor.tenant_id(),
-                        subject: actor.subject().to_owned(),
-                        action: AuditAction::RuntimeRecordUpdated,
-                        resource_type: "runtime_record".to_owned(),
-                        resource_id: updated.record_id().as_str().to_owned(),
-                        detail: Some(format!(
-                            "imported runtime record update '{}' for entity '{}'",
-                            updated.record_id().as_str(),
-                            plan.entity_logical_name
-                        )),
-                    })
-                    .await?;
+                self.append_runtime_record_audit_event(audit_event).await?;
+                self.invalidate_runtime_view_results(
+                    actor.tenant_id(),
+                    plan.entity_logical_name.as_str(),
+                )
+                .await;
             }
         }
 
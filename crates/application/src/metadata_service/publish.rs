@@ -35,40 +35,53 @@ impl MetadataService {
             )
             .await?;
 
-        let entity = self
-            .repository
-            .find_entity(actor.tenant_id(), entity_logical_name)
-            .await?
-            .ok_or_else(|| {
-                AppError::NotFound(format!(
-                    "entity '{}' does not exist for tenant '{}'",
-                    entity_logical_name,
-                    actor.tenant_id()
-                ))
-            })?;
+        // Only the read-only validation phase is budgeted; the publish writes
+        // below are not transactional together and must not stop halfway.
+        let (entity, fields, option_sets) = self
+            .within_operation_budget(TimedOperation::Publish, async {
+                let entity = self
+                    .repository
+                    .find_entity(actor.tenant_id(), entity_logical_name)
+                    .await?
+                    .ok_or_else(|| {
+                        AppError::NotFound(format!(
+                            "entity '{}' does not exist for tenant '{}'",
+                            entity_logical_name,
+                            actor.tenant_id()
+                        ))
+                    })?;
 
-        let fields = self
-            .repository
-            .list_fields(actor.tenant_id(), entity_logical_name)
-            .await?;
-        let option_sets = self
-            .repository
-            .list_option_sets(actor.tenant_id(), entity_logical_name)
-            .await?;
+                let fields = self
+                    .repository
+                    .list_fields(actor.tenant_id(), entity_logical_name)
+                    .await?;
+                let option_sets = self
+                    .repository
+                    .list_option_sets(actor.tenant_id(), entity_logical_name)
+                    .await?;
 
-        let publish_errors = self
-            .collect_publish_validation_errors(
-                actor.tenant_id(),
-                entity_logical_name,
-                &fields,
-                allowed_unpublished_entity_logical_names,
-            )
+                let mut publish_errors = Self::collect_entity_presentation_errors(&entity);
+                publish_errors.extend(
+                    self.collect_publish_validation_errors(
+                        actor.tenant_id(),
+                        entity_logical_name,
+                        &fields,
+                        allowed_unpublished_entity_logical_names,
+                    )
+                    .await?,
+                );
+                if !publish_errors.is_empty() {
+                    return Err(AppError::Validation(
+                        Self::format_publish_validation_errors(
+                            entity_logical_name,
+                            &publish_errors,
+                        ),
+                    ));
+                }
+
+                Ok((entity, fields, option_sets))
+            })
             .await?;
-        if !publish_errors.is_empty() {
-            return Err(AppError::Validation(
-                Self::format_publish_validation_errors(entity_logical_name, &publish_errors),
-            ));
-        }
 
         let published_schema = self
             .repository
@@ -125,6 +138,12 @@ impl MetadataService {
                 )),
             })
             .await?;
+        // A new schema version can change redaction and queryable fields.
+        self.invalidate_runtime_view_results(
+            actor.tenant_id(),
+            published_schema.entity().logical_name().as_str(),
+        )
+        .await;
 
         Ok(published_schema)
     }
@@ -147,54 +166,101 @@ impl MetadataService {
         entity_logical_name: &str,
         allowed_unpublished_entity_logical_names: &[String],
     ) -> AppResult<Vec<String>> {
+        self.within_operation_budget(TimedOperation::Publish, async move {
+            self.authorization_service
+                .require_permission(
+                    actor.tenant_id(),
+                    actor.subject(),
+                    Permission::MetadataEntityCreate,
+                )
+                .await?;
+
+            self.authorization_service
+                .require_permission(
+                    actor.tenant_id(),
+                    actor.subject(),
+                    Permission::MetadataFieldWrite,
+                )
+                .await?;
+
+            let entity = self
+                .repository
+                .find_entity(actor.tenant_id(), entity_logical_name)
+                .await?
+                .ok_or_else(|| {
+                    AppError::NotFound(format!(
+                        "entity '{}' does not exist for tenant '{}'",
+                        entity_logical_name,
+                        actor.tenant_id()
+                    ))
+                })?;
+
+            let fields = self
+                .repository
+                .list_fields(actor.tenant_id(), entity_logical_name)
+                .await?;
+
+            let mut errors = Self::collect_entity_presentation_errors(&entity);
+            errors.extend(
+                self.collect_publish_validation_errors(
+                    actor.tenant_id(),
+                    entity_logical_name,
+                    &fields,
+                    allowed_unpublished_entity_logical_names,
+                )
+                .await?,
+            );
+
+            Ok(errors)
+        })
+        .await
+    }
+
+    /// Returns the latest published metadata schema for an entity.
+    pub async fn latest_published_schema(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
         self.authorization_service
             .require_permission(
                 actor.tenant_id(),
                 actor.subject(),
-                Permission::MetadataEntityCreate,
+                Permission::MetadataEntityRead,
             )
             .await?;
 
+        self.repository
+            .latest_published_schema(actor.tenant_id(), entity_logical_name)
+            .await
+    }
+
+    /// Lists every published schema version of an entity, newest first.
+    pub async fn list_published_schema_versions(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<PublishedEntitySchemaVersion>> {
         self.authorization_service
             .require_permission(
                 actor.tenant_id(),
                 actor.subject(),
-                Permission::MetadataFieldWrite,
+                Permission::MetadataEntityRead,
             )
             .await?;
 
-        let _entity = self
-            .repository
-            .find_entity(actor.tenant_id(), entity_logical_name)
-            .await?
-            .ok_or_else(|| {
-                AppError::NotFound(format!(
-                    "entity '{}' does not exist for tenant '{}'",
-                    entity_logical_name,
-                    actor.tenant_id()
-                ))
-            })?;
-
-        let fields = self
-            .repository
-            .list_fields(actor.tenant_id(), entity_logical_name)
-            .await?;
-
-        self.collect_publish_validation_errors(
-            actor.tenant_id(),
-            entity_logical_name,
-            &fields,
-            allowed_unpublished_entity_logical_names,
-        )
-        .await
+        self.repository
+            .list_published_schema_versions(actor.tenant_id(), entity_logical_name)
+            .await
     }
 
-    /// Returns the latest published metadata schema for an entity.
-    pub async fn latest_published_schema(
+    /// Returns one published schema version of an entity.
+    pub async fn find_published_schema_version(
         &self,
         actor: &UserIdentity,
         entity_logical_name: &str,
-    ) -> AppResult<Option<PublishedEntitySchema>> {
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchemaVersion>> {
         self.authorization_service
             .require_permission(
                 actor.tenant_id(),
@@ -204,7 +270,7 @@ impl MetadataService {
             .await?;
 
         self.repository
-            .latest_published_schema(actor.tenant_id(), entity_logical_name)
+            .find_published_schema_version(actor.tenant_id(), entity_logical_name, version)
             .await
     }
 }
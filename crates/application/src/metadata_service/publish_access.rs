@@ -58,6 +58,28 @@ impl MetadataService {
             .await
     }
 
+    /// Returns the logical names of entities with a published schema, without
+    /// permission checks.
+    pub async fn list_published_entity_logical_names_unchecked(
+        &self,
+        actor: &UserIdentity,
+    ) -> AppResult<Vec<String>> {
+        let mut logical_names = Vec::new();
+        for entity in self.repository.list_entities(actor.tenant_id()).await? {
+            let logical_name = entity.logical_name().as_str();
+            if self
+                .repository
+                .latest_published_schema(actor.tenant_id(), logical_name)
+                .await?
+                .is_some()
+            {
+                logical_names.push(logical_name.to_owned());
+            }
+        }
+
+        Ok(logical_names)
+    }
+
     /// Returns latest published form snapshots for an entity.
     pub async fn list_latest_published_form_snapshots(
         &self,
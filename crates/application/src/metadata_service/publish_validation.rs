@@ -1,4 +1,5 @@
 use super::*;
+use qryvanta_domain::RelationDeleteBehavior;
 
 impl MetadataService {
     fn published_field_names(schema: &PublishedEntitySchema) -> BTreeSet<String> {
@@ -117,6 +118,73 @@ impl MetadataService {
                 }
             }
         }
+
+        self.validate_form_script_events(tenant_id, &field_names, form)
+            .await
+    }
+
+    async fn validate_form_script_events(
+        &self,
+        tenant_id: TenantId,
+        field_names: &BTreeSet<String>,
+        form: &FormDefinition,
+    ) -> AppResult<()> {
+        let script_events = form.script_events();
+        let placed_fields: BTreeSet<&str> = form
+            .tabs()
+            .iter()
+            .flat_map(FormTab::sections)
+            .flat_map(FormSection::fields)
+            .map(|field| field.field_logical_name().as_str())
+            .chain(form.header_fields().iter().map(String::as_str))
+            .collect();
+        for field_logical_name in script_events.on_change_fields() {
+            if !field_names.contains(field_logical_name) {
+                return Err(AppError::Validation(format!(
+                    "form on_change field '{}' does not exist in published schema for entity '{}'",
+                    field_logical_name,
+                    form.entity_logical_name().as_str()
+                )));
+            }
+            if !placed_fields.contains(field_logical_name.as_str()) {
+                return Err(AppError::Validation(format!(
+                    "form on_change field '{}' must be placed on form '{}'",
+                    field_logical_name,
+                    form.logical_name().as_str()
+                )));
+            }
+        }
+
+        let referenced_actions = script_events.referenced_actions();
+        if referenced_actions.is_empty() {
+            return Ok(());
+        }
+        let Some(extension_repository) = &self.extension_repository else {
+            return Err(AppError::Validation(
+                "form script events cannot reference custom actions in this deployment".to_owned(),
+            ));
+        };
+        for action_logical_name in referenced_actions {
+            let extension = extension_repository
+                .find_extension(tenant_id, action_logical_name)
+                .await?;
+            match extension {
+                Some(extension) if extension.is_published() => {}
+                Some(_) => {
+                    return Err(AppError::Validation(format!(
+                        "form script custom action '{}' must be published",
+                        action_logical_name
+                    )));
+                }
+                None => {
+                    return Err(AppError::Validation(format!(
+                        "form script custom action '{}' does not exist",
+                        action_logical_name
+                    )));
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -124,7 +192,13 @@ impl MetadataService {
         schema: &PublishedEntitySchema,
         view: &ViewDefinition,
     ) -> AppResult<()> {
-        let field_names = Self::published_field_names(schema);
+        let mut field_names = Self::published_field_names(schema);
+        field_names.extend(
+            schema
+                .system_fields()
+                .into_iter()
+                .map(|field| field.as_str().to_owned()),
+        );
         for column in view.columns() {
             if !field_names.contains(column.field_logical_name().as_str()) {
                 return Err(AppError::Validation(format!(
@@ -311,6 +385,17 @@ impl MetadataService {
         Ok(errors)
     }
 
+    pub(super) fn collect_entity_presentation_errors(entity: &EntityDefinition) -> Vec<String> {
+        match entity.icon() {
+            Some(icon) if !entity.has_known_icon() => vec![format!(
+                "entity '{}' icon '{}' is not in the entity icon catalog",
+                entity.logical_name().as_str(),
+                icon
+            )],
+            _ => Vec::new(),
+        }
+    }
+
     pub(super) async fn collect_publish_validation_errors(
         &self,
         tenant_id: TenantId,
@@ -352,7 +437,7 @@ impl MetadataService {
             .collect();
 
         for field in fields {
-            if field.field_type() != FieldType::Relation {
+            if !field.field_type().is_relation() {
                 continue;
             }
 
@@ -397,6 +482,35 @@ impl MetadataService {
             }
         }
 
+        let relation_behaviors = self.repository.list_relation_behaviors(tenant_id).await?;
+        for behavior in relation_behaviors.iter().filter(|behavior| {
+            behavior.entity_logical_name == entity_logical_name
+                && behavior.delete_behavior != RelationDeleteBehavior::Restrict
+        }) {
+            let field = fields.iter().find(|field| {
+                field.logical_name().as_str() == behavior.field_logical_name
+                    && field.field_type() == FieldType::Relation
+                    && field.relation_target_entity().map(|target| target.as_str())
+                        == Some(behavior.target_entity_logical_name.as_str())
+            });
+            let Some(field) = field else {
+                errors.push(format!(
+                    "relation field '{}' declares delete behavior '{}' but is no longer a relation to entity '{}'",
+                    behavior.field_logical_name,
+                    behavior.delete_behavior.as_str(),
+                    behavior.target_entity_logical_name
+                ));
+                continue;
+            };
+            if let Some(error) = super::relation_behaviors::relation_delete_behavior_error(
+                entity_logical_name,
+                field,
+                behavior.delete_behavior,
+            ) {
+                errors.push(error);
+            }
+        }
+
         let forms = self
             .repository
             .list_forms(tenant_id, entity_logical_name)
@@ -0,0 +1,250 @@
+use super::*;
+
+impl MetadataService {
+    /// Associates a runtime record with a target record through a
+    /// many-to-many field.
+    ///
+    /// Requires write access to the source record and read access to the
+    /// target record. Returns `false` when the records were already associated.
+    pub async fn associate_runtime_records(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        field_logical_name: &str,
+        target_record_id: &str,
+    ) -> AppResult<bool> {
+        let association = self
+            .authorize_runtime_record_association(
+                actor,
+                entity_logical_name,
+                record_id,
+                field_logical_name,
+                target_record_id,
+            )
+            .await?;
+
+        let created = self
+            .repository
+            .associate_runtime_records(actor.tenant_id(), actor.subject(), association.clone())
+            .await?;
+
+        if created {
+            self.audit_repository
+                .append_event(AuditEvent {
+                    tenant_id: actor.tenant_id(),
+                    subject: actor.subject().to_owned(),
+                    action: AuditAction::RuntimeRecordAssociated,
+                    resource_type: "runtime_record".to_owned(),
+                    resource_id: record_id.to_owned(),
+                    detail: Some(format!(
+                        "associated runtime record '{}' with '{}' record '{}' through field '{}.{}'",
+                        record_id,
+                        association.target_entity_logical_name,
+                        target_record_id,
+                        entity_logical_name,
+                        association.field_logical_name
+                    )),
+                })
+                .await?;
+        }
+
+        Ok(created)
+    }
+
+    /// Removes a many-to-many association between two runtime records.
+    pub async fn disassociate_runtime_records(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        field_logical_name: &str,
+        target_record_id: &str,
+    ) -> AppResult<()> {
+        let association = self
+            .authorize_runtime_record_association(
+                actor,
+                entity_logical_name,
+                record_id,
+                field_logical_name,
+                target_record_id,
+            )
+            .await?;
+
+        if !self
+            .repository
+            .disassociate_runtime_records(actor.tenant_id(), &association)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "runtime record '{}' is not associated with '{}' record '{}' through field '{}'",
+                record_id,
+                association.target_entity_logical_name,
+                target_record_id,
+                association.field_logical_name
+            )));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::RuntimeRecordDisassociated,
+                resource_type: "runtime_record".to_owned(),
+                resource_id: record_id.to_owned(),
+                detail: Some(format!(
+                    "disassociated runtime record '{}' from '{}' record '{}' through field '{}.{}'",
+                    record_id,
+                    association.target_entity_logical_name,
+                    target_record_id,
+                    entity_logical_name,
+                    association.field_logical_name
+                )),
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// Lists the target records associated with a runtime record through a
+    /// many-to-many field.
+    ///
+    /// Targets the actor cannot read are omitted.
+    pub async fn list_associated_runtime_records(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        field_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        self.get_runtime_record(actor, entity_logical_name, record_id)
+            .await?;
+        let target_entity_logical_name = self
+            .many_to_many_target_entity(actor.tenant_id(), entity_logical_name, field_logical_name)
+            .await?;
+
+        let target_record_ids = self
+            .repository
+            .list_associated_runtime_record_ids(
+                actor.tenant_id(),
+                entity_logical_name,
+                field_logical_name,
+                record_id,
+            )
+            .await?;
+
+        let mut records = Vec::with_capacity(target_record_ids.len());
+        for target_record_id in target_record_ids {
+            match self
+                .get_runtime_record(
+                    actor,
+                    target_entity_logical_name.as_str(),
+                    target_record_id.as_str(),
+                )
+                .await
+            {
+                Ok(record) => records.push(record),
+                Err(AppError::Forbidden(_) | AppError::NotFound(_)) => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(records)
+    }
+
+    async fn authorize_runtime_record_association(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        field_logical_name: &str,
+        target_record_id: &str,
+    ) -> AppResult<RuntimeRecordAssociation> {
+        let write_scope = self.runtime_write_scope_for_actor(actor).await?;
+
+        if write_scope == RuntimeAccessScope::Own
+            && !self
+                .repository
+                .runtime_record_owned_by_subject(
+                    actor.tenant_id(),
+                    entity_logical_name,
+                    record_id,
+                    actor.subject(),
+                )
+                .await?
+        {
+            return Err(AppError::Forbidden(format!(
+                "subject '{}' can only change associations of owned runtime records for entity '{}'",
+                actor.subject(),
+                entity_logical_name
+            )));
+        }
+
+        self.require_compliance_zone_access(actor, entity_logical_name, Some(record_id))
+            .await?;
+
+        let target_entity_logical_name = self
+            .many_to_many_target_entity(actor.tenant_id(), entity_logical_name, field_logical_name)
+            .await?;
+
+        if !self
+            .repository
+            .runtime_record_exists(actor.tenant_id(), entity_logical_name, record_id)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "runtime record '{}' does not exist for entity '{}'",
+                record_id, entity_logical_name
+            )));
+        }
+
+        self.get_runtime_record(actor, target_entity_logical_name.as_str(), target_record_id)
+            .await?;
+
+        Ok(RuntimeRecordAssociation {
+            entity_logical_name: entity_logical_name.to_owned(),
+            field_logical_name: field_logical_name.to_owned(),
+            record_id: record_id.to_owned(),
+            target_entity_logical_name,
+            target_record_id: target_record_id.to_owned(),
+        })
+    }
+
+    async fn many_to_many_target_entity(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<String> {
+        let schema = self
+            .published_schema_for_runtime(tenant_id, entity_logical_name)
+            .await?;
+        let field = schema
+            .fields()
+            .iter()
+            .find(|field| field.logical_name().as_str() == field_logical_name)
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "field '{}' does not exist on published entity '{}'",
+                    field_logical_name, entity_logical_name
+                ))
+            })?;
+
+        if field.field_type() != FieldType::ManyToMany {
+            return Err(AppError::Validation(format!(
+                "field '{}' on entity '{}' must be of type 'many_to_many'",
+                field_logical_name, entity_logical_name
+            )));
+        }
+
+        field
+            .relation_target_entity()
+            .map(|target| target.as_str().to_owned())
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "many_to_many field '{}' on entity '{}' is missing relation target metadata",
+                    field_logical_name, entity_logical_name
+                ))
+            })
+    }
+}
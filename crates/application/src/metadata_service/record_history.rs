@@ -0,0 +1,434 @@
+use super::*;
+use crate::{
+    EntityHistoryPolicy, NewRuntimeRecordHistoryEntry, RECORD_HISTORY_MAX_RETENTION_DAYS,
+    RuntimeRecordFieldChange, RuntimeRecordHistoryEntry, SaveEntityHistoryPolicyInput,
+};
+
+impl MetadataService {
+    /// Saves the record history retention policy of an entity.
+    ///
+    /// History entries already older than the new window are purged
+    /// immediately; later entries are purged as their records change.
+    pub async fn save_entity_history_policy(
+        &self,
+        actor: &UserIdentity,
+        input: SaveEntityHistoryPolicyInput,
+    ) -> AppResult<EntityHistoryPolicy> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await?;
+
+        if input.retention_days == 0 || input.retention_days > RECORD_HISTORY_MAX_RETENTION_DAYS {
+            return Err(AppError::Validation(format!(
+                "record history retention_days must be between 1 and {}",
+                RECORD_HISTORY_MAX_RETENTION_DAYS
+            )));
+        }
+        if self
+            .repository
+            .find_entity(actor.tenant_id(), input.entity_logical_name.as_str())
+            .await?
+            .is_none()
+        {
+            return Err(AppError::NotFound(format!(
+                "entity '{}' does not exist",
+                input.entity_logical_name
+            )));
+        }
+
+        let policy = EntityHistoryPolicy {
+            entity_logical_name: input.entity_logical_name,
+            retention_days: input.retention_days,
+        };
+        self.repository
+            .save_entity_history_policy(actor.tenant_id(), actor.subject(), policy.clone())
+            .await?;
+        let purged = self
+            .repository
+            .purge_runtime_record_history(
+                actor.tenant_id(),
+                policy.entity_logical_name.as_str(),
+                policy.cutoff(chrono::Utc::now()),
+            )
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::MetadataEntityHistoryPolicySaved,
+                resource_type: "entity_definition".to_owned(),
+                resource_id: policy.entity_logical_name.clone(),
+                detail: Some(format!(
+                    "set record history retention of entity '{}' to {} day(s) and purged {} expired entries",
+                    policy.entity_logical_name, policy.retention_days, purged
+                )),
+            })
+            .await?;
+
+        Ok(policy)
+    }
+
+    /// Returns the record history retention policy of an entity, if any.
+    pub async fn entity_history_policy(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityHistoryPolicy>> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldRead,
+            )
+            .await?;
+
+        self.repository
+            .find_entity_history_policy(actor.tenant_id(), entity_logical_name)
+            .await
+    }
+
+    /// Removes the record history retention policy of an entity.
+    ///
+    /// Without a policy, history entries are kept indefinitely.
+    pub async fn delete_entity_history_policy(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await?;
+
+        if !self
+            .repository
+            .delete_entity_history_policy(actor.tenant_id(), entity_logical_name)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "entity '{}' does not define a record history policy",
+                entity_logical_name
+            )));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::MetadataEntityHistoryPolicyDeleted,
+                resource_type: "entity_definition".to_owned(),
+                resource_id: entity_logical_name.to_owned(),
+                detail: Some(format!(
+                    "removed record history retention of entity '{}'",
+                    entity_logical_name
+                )),
+            })
+            .await
+    }
+
+    /// Lists the field history of a runtime record, oldest first.
+    ///
+    /// Read scope applies as for [`Self::get_runtime_record`]. Changes to
+    /// masked fields show masked values, changes to other fields the actor
+    /// cannot read are left out, and entries without any remaining change are
+    /// skipped. Entries outside the entity's retention
+    /// window are never returned, even before they are purged.
+    pub async fn runtime_record_history(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RuntimeRecordHistoryEntry>> {
+        self.get_runtime_record(actor, entity_logical_name, record_id)
+            .await?;
+
+        let field_access = self
+            .runtime_field_access_for_actor(actor, entity_logical_name)
+            .await?;
+        let since = self
+            .repository
+            .find_entity_history_policy(actor.tenant_id(), entity_logical_name)
+            .await?
+            .map(|policy| policy.cutoff(chrono::Utc::now()));
+        let entries = self
+            .repository
+            .list_runtime_record_history(actor.tenant_id(), entity_logical_name, record_id, since)
+            .await?;
+
+        let Some(field_access) = field_access else {
+            return Ok(entries);
+        };
+
+        Ok(entries
+            .into_iter()
+            .filter_map(|mut entry| {
+                entry.changes = entry
+                    .changes
+                    .into_iter()
+                    .filter_map(|mut change| {
+                        let field_logical_name = change.field_logical_name.as_str();
+                        if field_access.readable_fields.contains(field_logical_name) {
+                            return Some(change);
+                        }
+
+                        let masking_mode = field_access.masked_fields.get(field_logical_name)?;
+                        if *masking_mode == FieldMaskingMode::Hidden {
+                            return None;
+                        }
+                        change.old_value = change
+                            .old_value
+                            .and_then(|value| masking_mode.mask_value(&value));
+                        change.new_value = change
+                            .new_value
+                            .and_then(|value| masking_mode.mask_value(&value));
+                        Some(change)
+                    })
+                    .collect();
+                (!entry.changes.is_empty()).then_some(entry)
+            })
+            .collect())
+    }
+
+    /// Restores a runtime record to the values it held at `version`.
+    ///
+    /// The earlier state is rebuilt by undoing every history entry written
+    /// after `version`. That is only complete while nothing after `version`
+    /// was purged: the entity keeps history indefinitely, the record was
+    /// created inside the retention window, or an entry for `version` or an
+    /// earlier one is still retained. Other versions are rejected as not
+    /// found. Fields no longer in the published schema are
+    /// dropped and the result is written as a regular update: it is validated
+    /// against the current schema, only the reverted fields must be writable,
+    /// and it becomes a new version instead of rewriting history.
+    pub async fn restore_runtime_record_version(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        version: i64,
+    ) -> AppResult<RuntimeRecord> {
+        self.runtime_write_scope_for_actor(actor).await?;
+        self.get_runtime_record(actor, entity_logical_name, record_id)
+            .await?;
+
+        let existing_record = self
+            .repository
+            .find_runtime_record(actor.tenant_id(), entity_logical_name, record_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "runtime record '{}' does not exist for entity '{}'",
+                    record_id, entity_logical_name
+                ))
+            })?;
+        if version < 1 || version >= existing_record.version() {
+            return Err(AppError::Validation(format!(
+                "runtime record '{}' can only be restored to a version from 1 to before its current version {}",
+                record_id,
+                existing_record.version()
+            )));
+        }
+
+        let since = self
+            .repository
+            .find_entity_history_policy(actor.tenant_id(), entity_logical_name)
+            .await?
+            .map(|policy| policy.cutoff(chrono::Utc::now()));
+        let entries = self
+            .repository
+            .list_runtime_record_history(actor.tenant_id(), entity_logical_name, record_id, since)
+            .await?;
+        // Creating a record writes no history entry, so the history back to
+        // version 1 is also complete when nothing of the record can have
+        // been purged yet.
+        let created_within_retention = match since {
+            None => true,
+            Some(cutoff) => existing_record
+                .data()
+                .get(SystemField::CreatedAt.as_str())
+                .and_then(Value::as_str)
+                .and_then(|created_at| chrono::DateTime::parse_from_rfc3339(created_at).ok())
+                .is_some_and(|created_at| created_at >= cutoff),
+        };
+        if !created_within_retention && !entries.iter().any(|entry| entry.record_version <= version)
+        {
+            return Err(AppError::NotFound(format!(
+                "version {} of runtime record '{}' is not in its retained history",
+                version, record_id
+            )));
+        }
+
+        let schema = self
+            .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+            .await?;
+        let restored_data =
+            restored_runtime_record_data(&schema, existing_record.data(), &entries, version);
+        let current_data =
+            restored_runtime_record_data(&schema, existing_record.data(), &[], version);
+        let reverted_fields = runtime_record_field_changes(&current_data, &restored_data)
+            .into_iter()
+            .map(|change| (change.field_logical_name, Value::Null))
+            .collect::<serde_json::Map<_, _>>();
+        if reverted_fields.is_empty() {
+            return Err(AppError::Validation(format!(
+                "runtime record '{}' already matches version {}",
+                record_id, version
+            )));
+        }
+
+        let record = self
+            .write_runtime_record_update_unchecked(
+                actor,
+                entity_logical_name,
+                record_id,
+                restored_data,
+                Some(existing_record.version()),
+                Some(&Value::Object(reverted_fields.clone())),
+            )
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::RuntimeRecordRestored,
+                resource_type: "runtime_record".to_owned(),
+                resource_id: record_id.to_owned(),
+                detail: Some(format!(
+                    "restored runtime record '{}' for entity '{}' from version {} to version {} as version {}, reverting fields: {}",
+                    record_id,
+                    entity_logical_name,
+                    existing_record.version(),
+                    version,
+                    record.version(),
+                    reverted_fields
+                        .keys()
+                        .map(String::as_str)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            })
+            .await?;
+
+        Ok(record)
+    }
+
+    /// Appends a field history entry when an update changed record values.
+    ///
+    /// System fields such as `modified_at` change on every write and are not
+    /// recorded.
+    pub(super) async fn record_runtime_record_history(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        schema: &PublishedEntitySchema,
+        previous_data: &Value,
+        record: &RuntimeRecord,
+    ) -> AppResult<()> {
+        let system_fields = schema.system_fields();
+        let mut changes = runtime_record_field_changes(previous_data, record.data());
+        changes.retain(|change| {
+            !system_fields
+                .iter()
+                .any(|system_field| system_field.as_str() == change.field_logical_name)
+        });
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let retention_cutoff = self
+            .repository
+            .find_entity_history_policy(actor.tenant_id(), entity_logical_name)
+            .await?
+            .map(|policy| policy.cutoff(chrono::Utc::now()));
+        self.repository
+            .append_runtime_record_history_entry(
+                actor.tenant_id(),
+                NewRuntimeRecordHistoryEntry {
+                    entity_logical_name: entity_logical_name.to_owned(),
+                    record_id: record.record_id().as_str().to_owned(),
+                    record_version: record.version(),
+                    changes,
+                    changed_by_subject: actor.subject().to_owned(),
+                },
+                retention_cutoff,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Rebuilds the writable payload a record held at `version` by undoing the
+/// changes of later history entries, newest first.
+///
+/// System and calculated fields are left to the write path, and fields that
+/// are no longer published are dropped.
+fn restored_runtime_record_data(
+    schema: &PublishedEntitySchema,
+    current_data: &Value,
+    entries: &[RuntimeRecordHistoryEntry],
+    version: i64,
+) -> Value {
+    let mut object = current_data.as_object().cloned().unwrap_or_default();
+    for entry in entries
+        .iter()
+        .rev()
+        .filter(|entry| entry.record_version > version)
+    {
+        for change in &entry.changes {
+            match &change.old_value {
+                Some(value) => {
+                    object.insert(change.field_logical_name.clone(), value.clone());
+                }
+                None => {
+                    object.remove(change.field_logical_name.as_str());
+                }
+            }
+        }
+    }
+
+    let writable_fields = schema
+        .fields()
+        .iter()
+        .filter(|field| field.calculation_expression().is_none())
+        .map(|field| field.logical_name().as_str())
+        .collect::<BTreeSet<_>>();
+    object.retain(|field_logical_name, _| writable_fields.contains(field_logical_name.as_str()));
+
+    Value::Object(object)
+}
+
+/// Compares two record payloads field by field, ordered by logical name.
+fn runtime_record_field_changes(
+    previous_data: &Value,
+    current_data: &Value,
+) -> Vec<RuntimeRecordFieldChange> {
+    let empty = serde_json::Map::new();
+    let previous = previous_data.as_object().unwrap_or(&empty);
+    let current = current_data.as_object().unwrap_or(&empty);
+
+    previous
+        .keys()
+        .chain(current.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter_map(|field_logical_name| {
+            let old_value = previous.get(field_logical_name);
+            let new_value = current.get(field_logical_name);
+            (old_value != new_value).then(|| RuntimeRecordFieldChange {
+                field_logical_name: field_logical_name.clone(),
+                old_value: old_value.cloned(),
+                new_value: new_value.cloned(),
+            })
+        })
+        .collect()
+}
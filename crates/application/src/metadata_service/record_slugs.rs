@@ -0,0 +1,255 @@
+use super::*;
+use crate::{EntitySlugConfig, SaveEntitySlugConfigInput};
+use qryvanta_domain::{RECORD_SLUG_MAX_LENGTH, slugify, validate_record_slug};
+
+/// Upper bound on numbered suffixes tried when a generated slug is taken.
+const RECORD_SLUG_SUFFIX_ATTEMPTS: usize = 100;
+
+impl MetadataService {
+    /// Saves the slug field and its generation source for an entity.
+    ///
+    /// The slug field must be a unique text field so the runtime unique index
+    /// guarantees one record per slug.
+    pub async fn save_entity_slug_config(
+        &self,
+        actor: &UserIdentity,
+        input: SaveEntitySlugConfigInput,
+    ) -> AppResult<EntitySlugConfig> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await?;
+
+        if input.slug_field_logical_name == input.source_field_logical_name {
+            return Err(AppError::Validation(format!(
+                "slug field '{}.{}' cannot also be its own source field",
+                input.entity_logical_name, input.slug_field_logical_name
+            )));
+        }
+
+        let slug_field = self
+            .require_slug_text_field(
+                actor.tenant_id(),
+                input.entity_logical_name.as_str(),
+                input.slug_field_logical_name.as_str(),
+            )
+            .await?;
+        if !slug_field.is_unique() {
+            return Err(AppError::Validation(format!(
+                "slug field '{}.{}' must be unique",
+                input.entity_logical_name, input.slug_field_logical_name
+            )));
+        }
+        self.require_slug_text_field(
+            actor.tenant_id(),
+            input.entity_logical_name.as_str(),
+            input.source_field_logical_name.as_str(),
+        )
+        .await?;
+
+        let config = EntitySlugConfig {
+            entity_logical_name: input.entity_logical_name,
+            slug_field_logical_name: input.slug_field_logical_name,
+            source_field_logical_name: input.source_field_logical_name,
+        };
+        self.repository
+            .save_entity_slug_config(actor.tenant_id(), actor.subject(), config.clone())
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::MetadataEntitySlugSaved,
+                resource_type: "entity_definition".to_owned(),
+                resource_id: config.entity_logical_name.clone(),
+                detail: Some(format!(
+                    "set slug field of entity '{}' to '{}' generated from '{}'",
+                    config.entity_logical_name,
+                    config.slug_field_logical_name,
+                    config.source_field_logical_name
+                )),
+            })
+            .await?;
+
+        Ok(config)
+    }
+
+    /// Returns the slug configuration for an entity, if any.
+    pub async fn entity_slug_config(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntitySlugConfig>> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldRead,
+            )
+            .await?;
+
+        self.repository
+            .find_entity_slug_config(actor.tenant_id(), entity_logical_name)
+            .await
+    }
+
+    /// Resolves a record slug to the runtime record it identifies.
+    ///
+    /// Read scope applies as for [`Self::get_runtime_record`], so callers only
+    /// resolve slugs of records they could open.
+    pub async fn resolve_runtime_record_slug(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        slug: &str,
+    ) -> AppResult<RuntimeRecord> {
+        let config = self
+            .repository
+            .find_entity_slug_config(actor.tenant_id(), entity_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "entity '{}' does not define a slug field",
+                    entity_logical_name
+                ))
+            })?;
+
+        let record_id = self
+            .repository
+            .find_runtime_record_id_by_unique_value(
+                actor.tenant_id(),
+                entity_logical_name,
+                config.slug_field_logical_name.as_str(),
+                Self::hash_json_value(&Value::String(slug.to_owned()))?.as_str(),
+            )
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "no runtime record with slug '{}' exists for entity '{}'",
+                    slug, entity_logical_name
+                ))
+            })?;
+
+        self.get_runtime_record(actor, entity_logical_name, record_id.as_str())
+            .await
+    }
+
+    /// Fills and validates the slug field of a normalized record payload.
+    ///
+    /// An empty slug keeps the existing record's slug, or is generated from
+    /// the source field with a numbered suffix when the base slug is taken.
+    pub(super) async fn apply_record_slug(
+        &self,
+        tenant_id: TenantId,
+        schema: &PublishedEntitySchema,
+        object: &mut serde_json::Map<String, Value>,
+        existing_record_data: Option<&Value>,
+    ) -> AppResult<()> {
+        let entity_logical_name = schema.entity().logical_name().as_str();
+        let Some(config) = self
+            .repository
+            .find_entity_slug_config(tenant_id, entity_logical_name)
+            .await?
+        else {
+            return Ok(());
+        };
+        let slug_field = config.slug_field_logical_name.as_str();
+        if !schema
+            .fields()
+            .iter()
+            .any(|field| field.logical_name().as_str() == slug_field)
+        {
+            return Ok(());
+        }
+
+        if let Some(slug) = object
+            .get(slug_field)
+            .and_then(Value::as_str)
+            .filter(|slug| !slug.is_empty())
+        {
+            return validate_record_slug(slug);
+        }
+
+        if let Some(existing_slug) = existing_record_data
+            .and_then(|data| data.get(slug_field))
+            .and_then(Value::as_str)
+            .filter(|slug| !slug.is_empty())
+        {
+            object.insert(
+                slug_field.to_owned(),
+                Value::String(existing_slug.to_owned()),
+            );
+            return Ok(());
+        }
+
+        let base = object
+            .get(config.source_field_logical_name.as_str())
+            .and_then(Value::as_str)
+            .map(slugify)
+            .unwrap_or_default();
+        if base.is_empty() {
+            return Ok(());
+        }
+
+        for attempt in 1..=RECORD_SLUG_SUFFIX_ATTEMPTS {
+            let candidate = if attempt == 1 {
+                base.clone()
+            } else {
+                let suffix = format!("-{attempt}");
+                let mut prefix = base.clone();
+                prefix.truncate(RECORD_SLUG_MAX_LENGTH - suffix.len());
+                format!("{}{suffix}", prefix.trim_end_matches('-'))
+            };
+
+            let taken = self
+                .repository
+                .find_runtime_record_id_by_unique_value(
+                    tenant_id,
+                    entity_logical_name,
+                    slug_field,
+                    Self::hash_json_value(&Value::String(candidate.clone()))?.as_str(),
+                )
+                .await?
+                .is_some();
+            if !taken {
+                object.insert(slug_field.to_owned(), Value::String(candidate));
+                return Ok(());
+            }
+        }
+
+        Err(AppError::Conflict(format!(
+            "could not generate a free slug from '{}' for entity '{}'",
+            base, entity_logical_name
+        )))
+    }
+
+    async fn require_slug_text_field(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<EntityFieldDefinition> {
+        let field = self
+            .repository
+            .find_field(tenant_id, entity_logical_name, field_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "field '{}.{}' does not exist for tenant '{}'",
+                    entity_logical_name, field_logical_name, tenant_id
+                ))
+            })?;
+        if field.field_type() != FieldType::Text {
+            return Err(AppError::Validation(format!(
+                "slug configuration requires '{}.{}' to be a text field",
+                entity_logical_name, field_logical_name
+            )));
+        }
+
+        Ok(field)
+    }
+}
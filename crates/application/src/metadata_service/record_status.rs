@@ -0,0 +1,500 @@
+use super::*;
+use crate::{
+    EntityStatusConfig, NewRuntimeRecordStatusChange, RuntimeRecordLogicalMode,
+    RuntimeRecordStatusChange, SaveEntityStatusModelInput,
+};
+use qryvanta_domain::{RECORD_INACTIVE_PREFIX, RecordStatusModel, WorkflowTrigger};
+
+impl MetadataService {
+    /// Saves the status state machine of an entity.
+    ///
+    /// The status field and the optional reason field must be text fields of
+    /// the entity. Existing records keep their stored values; the model
+    /// applies to subsequent writes.
+    pub async fn save_entity_status_model(
+        &self,
+        actor: &UserIdentity,
+        input: SaveEntityStatusModelInput,
+    ) -> AppResult<EntityStatusConfig> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await?;
+
+        let model = RecordStatusModel::new(
+            input.status_field_logical_name,
+            input.reason_field_logical_name,
+            input.initial_status,
+            input.statuses,
+            input.transitions,
+        )?;
+        self.require_status_text_field(
+            actor.tenant_id(),
+            input.entity_logical_name.as_str(),
+            model.status_field_logical_name(),
+        )
+        .await?;
+        if let Some(reason_field_logical_name) = model.reason_field_logical_name() {
+            self.require_status_text_field(
+                actor.tenant_id(),
+                input.entity_logical_name.as_str(),
+                reason_field_logical_name,
+            )
+            .await?;
+        }
+
+        let config = EntityStatusConfig {
+            entity_logical_name: input.entity_logical_name,
+            model,
+        };
+        self.repository
+            .save_entity_status_config(actor.tenant_id(), actor.subject(), config.clone())
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::MetadataEntityStatusModelSaved,
+                resource_type: "entity_definition".to_owned(),
+                resource_id: config.entity_logical_name.clone(),
+                detail: Some(format!(
+                    "set status model of entity '{}' on field '{}' with {} statuses and {} transitions",
+                    config.entity_logical_name,
+                    config.model.status_field_logical_name(),
+                    config.model.statuses().len(),
+                    config.model.transitions().len()
+                )),
+            })
+            .await?;
+
+        Ok(config)
+    }
+
+    /// Returns the status state machine of an entity, if any.
+    pub async fn entity_status_model(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityStatusConfig>> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldRead,
+            )
+            .await?;
+
+        self.repository
+            .find_entity_status_config(actor.tenant_id(), entity_logical_name)
+            .await
+    }
+
+    /// Removes the status state machine of an entity.
+    ///
+    /// Status history already recorded for its records is retained.
+    pub async fn delete_entity_status_model(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await?;
+
+        if !self
+            .repository
+            .delete_entity_status_config(actor.tenant_id(), entity_logical_name)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "entity '{}' does not define a status model",
+                entity_logical_name
+            )));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::MetadataEntityStatusModelDeleted,
+                resource_type: "entity_definition".to_owned(),
+                resource_id: entity_logical_name.to_owned(),
+                detail: Some(format!(
+                    "removed status model of entity '{}'",
+                    entity_logical_name
+                )),
+            })
+            .await
+    }
+
+    /// Lists the status history of a runtime record, oldest first.
+    ///
+    /// Read scope applies as for [`Self::get_runtime_record`].
+    pub async fn runtime_record_status_history(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RuntimeRecordStatusChange>> {
+        self.get_runtime_record(actor, entity_logical_name, record_id)
+            .await?;
+
+        self.repository
+            .list_runtime_record_status_history(actor.tenant_id(), entity_logical_name, record_id)
+            .await
+    }
+
+    /// Fills and validates the status and status reason of a record payload.
+    ///
+    /// New records default to the initial status. Updates that omit the
+    /// status keep the stored one, and status changes must follow a declared
+    /// transition. A reason carried over from the previous status falls back
+    /// to the new status's default reason when it is not allowed there.
+    /// Records in an inactive status only accept updates that reactivate
+    /// them, which requires `runtime.record.reactivate`.
+    pub(super) async fn apply_record_status(
+        &self,
+        tenant_id: TenantId,
+        schema: &PublishedEntitySchema,
+        object: &mut serde_json::Map<String, Value>,
+        existing_record_data: Option<&Value>,
+        actor_subject: &str,
+    ) -> AppResult<()> {
+        let entity_logical_name = schema.entity().logical_name().as_str();
+        let Some(config) = self
+            .repository
+            .find_entity_status_config(tenant_id, entity_logical_name)
+            .await?
+        else {
+            return Ok(());
+        };
+        let model = &config.model;
+        let status_field = model.status_field_logical_name();
+        if !schema
+            .fields()
+            .iter()
+            .any(|field| field.logical_name().as_str() == status_field)
+        {
+            return Ok(());
+        }
+
+        let previous_status = existing_record_data.and_then(|data| text_value(data, status_field));
+        let requested_status = object
+            .get(status_field)
+            .and_then(Value::as_str)
+            .filter(|status| !status.is_empty());
+        if let Some(previous) = previous_status.filter(|status| model.is_inactive(status)) {
+            match requested_status.filter(|status| !model.is_inactive(status)) {
+                Some(requested) => {
+                    model.ensure_transition(previous, requested)?;
+                    self.authorization_service
+                        .require_permission(
+                            tenant_id,
+                            actor_subject,
+                            Permission::RuntimeRecordReactivate,
+                        )
+                        .await?;
+                }
+                None => {
+                    return Err(AppError::Validation(format!(
+                        "{RECORD_INACTIVE_PREFIX}: status '{previous}' only allows reactivation"
+                    )));
+                }
+            }
+        }
+
+        let status = match (previous_status, requested_status) {
+            (Some(previous), Some(requested)) => {
+                model.ensure_transition(previous, requested)?;
+                requested
+            }
+            (Some(previous), None) => previous,
+            (None, Some(requested)) => {
+                model.ensure_transition(requested, requested)?;
+                requested
+            }
+            (None, None) => model.initial_status(),
+        }
+        .to_owned();
+
+        if let Some(reason_field) = model.reason_field_logical_name() {
+            let previous_reason =
+                existing_record_data.and_then(|data| text_value(data, reason_field));
+            let same_status = previous_status == Some(status.as_str());
+            let requested_reason = match object
+                .get(reason_field)
+                .and_then(Value::as_str)
+                .filter(|reason| !reason.is_empty())
+            {
+                Some(reason)
+                    if !same_status
+                        && Some(reason) == previous_reason
+                        && model.resolve_reason(status.as_str(), Some(reason)).is_err() =>
+                {
+                    None
+                }
+                Some(reason) => Some(reason),
+                None if same_status => previous_reason,
+                None => None,
+            };
+            if let Some(reason) = model.resolve_reason(status.as_str(), requested_reason)? {
+                object.insert(reason_field.to_owned(), Value::String(reason));
+            }
+        }
+        object.insert(status_field.to_owned(), Value::String(status));
+
+        Ok(())
+    }
+
+    /// Appends a status history entry when a write changed status or reason.
+    ///
+    /// Updates also enqueue a `runtime_record_status_changed` workflow event
+    /// in the same transaction as the history entry.
+    pub(super) async fn record_runtime_record_status_change(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        previous_data: Option<&Value>,
+        current_data: &Value,
+    ) -> AppResult<()> {
+        let Some(config) = self
+            .repository
+            .find_entity_status_config(actor.tenant_id(), entity_logical_name)
+            .await?
+        else {
+            return Ok(());
+        };
+        let model = &config.model;
+        let Some(to_status) = text_value(current_data, model.status_field_logical_name()) else {
+            return Ok(());
+        };
+        let from_status =
+            previous_data.and_then(|data| text_value(data, model.status_field_logical_name()));
+        let reason_field = model.reason_field_logical_name();
+        let from_reason = previous_data
+            .zip(reason_field)
+            .and_then(|(data, reason_field)| text_value(data, reason_field));
+        let to_reason =
+            reason_field.and_then(|reason_field| text_value(current_data, reason_field));
+        if from_status == Some(to_status) && from_reason == to_reason {
+            return Ok(());
+        }
+
+        let notification = previous_data.and_then(|previous_data| {
+            Self::runtime_record_workflow_event_input(
+                actor,
+                WorkflowTrigger::RuntimeRecordStatusChanged {
+                    entity_logical_name: entity_logical_name.to_owned(),
+                },
+                serde_json::json!({
+                    "entity_logical_name": entity_logical_name,
+                    "record_id": record_id,
+                    "id": record_id,
+                    "event": "status_changed",
+                    "previous_status": from_status,
+                    "status": to_status,
+                    "previous_reason": from_reason,
+                    "reason": to_reason,
+                    "previous": previous_data,
+                    "record": current_data,
+                    "data": current_data,
+                }),
+            )
+        });
+        let change = self
+            .repository
+            .append_runtime_record_status_change(
+                actor.tenant_id(),
+                NewRuntimeRecordStatusChange {
+                    entity_logical_name: entity_logical_name.to_owned(),
+                    record_id: record_id.to_owned(),
+                    from_status: from_status.map(ToOwned::to_owned),
+                    to_status: to_status.to_owned(),
+                    from_reason: from_reason.map(ToOwned::to_owned),
+                    to_reason: to_reason.map(ToOwned::to_owned),
+                    changed_by_subject: actor.subject().to_owned(),
+                    notification,
+                },
+            )
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::RuntimeRecordStatusChanged,
+                resource_type: "runtime_record".to_owned(),
+                resource_id: change.record_id.clone(),
+                detail: Some(format!(
+                    "moved runtime record '{}' of entity '{}' from '{}' to '{}'",
+                    change.record_id,
+                    change.entity_logical_name,
+                    change.from_status.as_deref().unwrap_or("none"),
+                    change.to_status
+                )),
+            })
+            .await
+    }
+
+    /// Lists runtime records, leaving out inactive records unless requested.
+    pub(super) async fn list_runtime_records_for_schema(
+        &self,
+        tenant_id: TenantId,
+        schema: &PublishedEntitySchema,
+        query: RecordListQuery,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        let entity_logical_name = schema.entity().logical_name().as_str();
+        let active_condition = if query.include_inactive {
+            None
+        } else {
+            self.active_record_condition(tenant_id, schema).await?
+        };
+        let Some(active_condition) = active_condition else {
+            return self
+                .repository
+                .list_runtime_records(tenant_id, entity_logical_name, query)
+                .await;
+        };
+
+        self.repository
+            .query_runtime_records(
+                tenant_id,
+                entity_logical_name,
+                RuntimeRecordQuery {
+                    limit: query.limit,
+                    offset: query.offset,
+                    logical_mode: RuntimeRecordLogicalMode::And,
+                    where_clause: Some(active_condition),
+                    filters: Vec::new(),
+                    links: Vec::new(),
+                    sort: Vec::new(),
+                    owner_subject: query.owner_subject,
+                    include_inactive: false,
+                },
+            )
+            .await
+    }
+
+    /// Narrows a runtime query to active records unless it opts into
+    /// inactive ones.
+    pub(super) async fn exclude_inactive_records(
+        &self,
+        tenant_id: TenantId,
+        schema: &PublishedEntitySchema,
+        query: &mut RuntimeRecordQuery,
+    ) -> AppResult<()> {
+        if query.include_inactive {
+            return Ok(());
+        }
+        let Some(active_condition) = self.active_record_condition(tenant_id, schema).await? else {
+            return Ok(());
+        };
+
+        query.where_clause = Some(match query.where_clause.take() {
+            Some(where_clause) => RuntimeRecordConditionGroup {
+                logical_mode: RuntimeRecordLogicalMode::And,
+                nodes: vec![
+                    RuntimeRecordConditionNode::Group(where_clause),
+                    RuntimeRecordConditionNode::Group(active_condition),
+                ],
+            },
+            None => active_condition,
+        });
+
+        Ok(())
+    }
+
+    /// Builds the condition matching records outside every inactive status.
+    ///
+    /// Records without a stored status count as active.
+    async fn active_record_condition(
+        &self,
+        tenant_id: TenantId,
+        schema: &PublishedEntitySchema,
+    ) -> AppResult<Option<RuntimeRecordConditionGroup>> {
+        let Some(config) = self
+            .repository
+            .find_entity_status_config(tenant_id, schema.entity().logical_name().as_str())
+            .await?
+        else {
+            return Ok(None);
+        };
+        let status_field = config.model.status_field_logical_name();
+        let inactive_statuses = config.model.inactive_statuses();
+        if inactive_statuses.is_empty()
+            || !schema
+                .fields()
+                .iter()
+                .any(|field| field.logical_name().as_str() == status_field)
+        {
+            return Ok(None);
+        }
+
+        let status_filter = |operator, field_value| {
+            RuntimeRecordConditionNode::Filter(RuntimeRecordFilter {
+                scope_alias: None,
+                field_logical_name: status_field.to_owned(),
+                operator,
+                field_type: FieldType::Text,
+                field_value,
+            })
+        };
+
+        Ok(Some(RuntimeRecordConditionGroup {
+            logical_mode: RuntimeRecordLogicalMode::Or,
+            nodes: vec![
+                status_filter(RuntimeRecordOperator::IsNull, Value::Null),
+                status_filter(
+                    RuntimeRecordOperator::NotIn,
+                    Value::Array(
+                        inactive_statuses
+                            .into_iter()
+                            .map(|status| Value::String(status.to_owned()))
+                            .collect(),
+                    ),
+                ),
+            ],
+        }))
+    }
+
+    async fn require_status_text_field(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<()> {
+        let field = self
+            .repository
+            .find_field(tenant_id, entity_logical_name, field_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "field '{}.{}' does not exist for tenant '{}'",
+                    entity_logical_name, field_logical_name, tenant_id
+                ))
+            })?;
+        if field.field_type() != FieldType::Text {
+            return Err(AppError::Validation(format!(
+                "status model requires '{}.{}' to be a text field",
+                entity_logical_name, field_logical_name
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+fn text_value<'a>(data: &'a Value, field_logical_name: &str) -> Option<&'a str> {
+    data.get(field_logical_name)
+        .and_then(Value::as_str)
+        .filter(|value| !value.is_empty())
+}
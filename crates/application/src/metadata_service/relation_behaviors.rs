@@ -0,0 +1,414 @@
+use super::runtime_records_write::record_payload_for_deleted;
+use super::*;
+use crate::{
+    RelationCascadeResult, RelationDeletePlan, RelationDeletedRecord, RelationRelinkedRecord,
+    RuntimeRecordLogicalMode,
+};
+use qryvanta_domain::{RelationDeleteBehavior, WorkflowTrigger};
+
+/// Child records fetched per query while planning a relation delete.
+const RELATION_DELETE_PAGE_SIZE: usize = 500;
+
+/// Upper bound on child records one delete may remove or relink.
+const RELATION_DELETE_MAX_AFFECTED_RECORDS: usize = 5_000;
+
+impl MetadataService {
+    /// Saves assign, share and delete behaviors for a relation field.
+    ///
+    /// The field lives on the child entity; its relation target is the parent
+    /// whose owner changes, shares and deletes propagate to the child records.
+    pub async fn save_relation_behavior(
+        &self,
+        actor: &UserIdentity,
+        input: SaveRelationBehaviorInput,
+    ) -> AppResult<RelationBehavior> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await?;
+
+        let field = self
+            .repository
+            .find_field(
+                actor.tenant_id(),
+                input.entity_logical_name.as_str(),
+                input.field_logical_name.as_str(),
+            )
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "field '{}.{}' does not exist for tenant '{}'",
+                    input.entity_logical_name,
+                    input.field_logical_name,
+                    actor.tenant_id()
+                ))
+            })?;
+        let target_entity_logical_name = match (field.field_type(), field.relation_target_entity())
+        {
+            (FieldType::Relation, Some(target)) => target.as_str().to_owned(),
+            _ => {
+                return Err(AppError::Validation(format!(
+                    "field '{}.{}' is not a relation field",
+                    input.entity_logical_name, input.field_logical_name
+                )));
+            }
+        };
+
+        if let Some(error) = relation_delete_behavior_error(
+            input.entity_logical_name.as_str(),
+            &field,
+            input.delete_behavior,
+        ) {
+            return Err(AppError::Validation(error));
+        }
+
+        let behavior = RelationBehavior {
+            entity_logical_name: input.entity_logical_name,
+            field_logical_name: input.field_logical_name,
+            target_entity_logical_name,
+            assign_behavior: input.assign_behavior,
+            share_behavior: input.share_behavior,
+            delete_behavior: input.delete_behavior,
+        };
+        self.repository
+            .save_relation_behavior(actor.tenant_id(), actor.subject(), behavior.clone())
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::MetadataRelationBehaviorSaved,
+                resource_type: "entity_field_definition".to_owned(),
+                resource_id: format!(
+                    "{}.{}",
+                    behavior.entity_logical_name, behavior.field_logical_name
+                ),
+                detail: Some(format!(
+                    "set relation behavior on '{}.{}' to assign '{}', share '{}' and delete '{}'",
+                    behavior.entity_logical_name,
+                    behavior.field_logical_name,
+                    behavior.assign_behavior.as_str(),
+                    behavior.share_behavior.as_str(),
+                    behavior.delete_behavior.as_str()
+                )),
+            })
+            .await?;
+
+        Ok(behavior)
+    }
+
+    /// Lists relation cascade behaviors, optionally limited to one child entity.
+    pub async fn list_relation_behaviors(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<RelationBehavior>> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldRead,
+            )
+            .await?;
+
+        let behaviors = self
+            .repository
+            .list_relation_behaviors(actor.tenant_id())
+            .await?;
+
+        Ok(behaviors
+            .into_iter()
+            .filter(|behavior| {
+                entity_logical_name
+                    .map(|entity| behavior.entity_logical_name == entity)
+                    .unwrap_or(true)
+            })
+            .collect())
+    }
+
+    /// Returns relations whose assign behavior cascades from the parent entity.
+    pub(super) async fn assign_cascades_for_parent(
+        &self,
+        tenant_id: TenantId,
+        target_entity_logical_name: &str,
+    ) -> AppResult<Vec<RelationBehavior>> {
+        Ok(self
+            .repository
+            .list_relation_behaviors(tenant_id)
+            .await?
+            .into_iter()
+            .filter(|behavior| {
+                behavior.target_entity_logical_name == target_entity_logical_name
+                    && behavior.assign_behavior.cascades()
+            })
+            .collect())
+    }
+
+    /// Plans how child records follow the delete of a parent record.
+    ///
+    /// Relations without a configured behavior restrict the delete. Records
+    /// removed by a cascade apply their own relations in turn, and the plan
+    /// fails with a conflict when a restricting relation references a record
+    /// that is not deleted along with it.
+    pub(super) async fn plan_relation_delete(
+        &self,
+        actor: &UserIdentity,
+        record: &RuntimeRecord,
+    ) -> AppResult<(RelationDeletePlan, Vec<RelationCascadeResult>)> {
+        let tenant_id = actor.tenant_id();
+        let entity_logical_name = record.entity_logical_name().as_str();
+        let record_id = record.record_id().as_str();
+        if !self
+            .repository
+            .has_relation_reference(tenant_id, entity_logical_name, record_id)
+            .await?
+        {
+            return Ok((RelationDeletePlan::default(), Vec::new()));
+        }
+
+        let behaviors = self.repository.list_relation_behaviors(tenant_id).await?;
+        let relations = self.published_relation_fields(tenant_id).await?;
+        let mut deleted_keys =
+            HashSet::from([(entity_logical_name.to_owned(), record_id.to_owned())]);
+        let mut pending_parents = vec![record.clone()];
+        let mut plan = RelationDeletePlan::default();
+        let mut restricted = Vec::new();
+        let mut affected_counts = BTreeMap::<(String, String), u64>::new();
+
+        while let Some(parent) = pending_parents.pop() {
+            let parent_entity = parent.entity_logical_name().as_str();
+            let parent_id = parent.record_id().as_str();
+            for (child_entity, field_logical_name) in relations
+                .iter()
+                .filter(|(_, _, target)| target == parent_entity)
+                .map(|(child_entity, field_logical_name, _)| (child_entity, field_logical_name))
+            {
+                let delete_behavior = behaviors
+                    .iter()
+                    .find(|behavior| {
+                        &behavior.entity_logical_name == child_entity
+                            && &behavior.field_logical_name == field_logical_name
+                    })
+                    .map(|behavior| behavior.delete_behavior)
+                    .unwrap_or_default();
+                let children = self
+                    .relation_children(tenant_id, child_entity, field_logical_name, parent_id)
+                    .await?;
+
+                for child in children {
+                    let child_id = child.record_id().as_str().to_owned();
+                    match delete_behavior {
+                        RelationDeleteBehavior::Restrict => {
+                            restricted.push((child_entity.clone(), child_id, field_logical_name));
+                        }
+                        RelationDeleteBehavior::Cascade => {
+                            if !deleted_keys.insert((child_entity.clone(), child_id.clone())) {
+                                continue;
+                            }
+                            self.require_runtime_record_not_held(
+                                tenant_id,
+                                child_entity,
+                                child_id.as_str(),
+                            )
+                            .await?;
+                            *affected_counts
+                                .entry((child_entity.clone(), field_logical_name.clone()))
+                                .or_default() += 1;
+                            plan.deleted_records.push(RelationDeletedRecord {
+                                entity_logical_name: child_entity.clone(),
+                                record_id: child_id.clone(),
+                                workflow_event: Self::runtime_record_workflow_event_input(
+                                    actor,
+                                    WorkflowTrigger::RuntimeRecordDeleted {
+                                        entity_logical_name: child_entity.clone(),
+                                    },
+                                    record_payload_for_deleted(
+                                        child_entity,
+                                        child_id.as_str(),
+                                        Some(child.data()),
+                                    ),
+                                ),
+                            });
+                            pending_parents.push(child);
+                        }
+                        RelationDeleteBehavior::SetNull | RelationDeleteBehavior::Reparent => {
+                            let parent_record_id = (delete_behavior
+                                == RelationDeleteBehavior::Reparent)
+                                .then(|| parent.data().get(field_logical_name.as_str()))
+                                .flatten()
+                                .and_then(Value::as_str)
+                                .map(str::to_owned);
+                            plan.relinked_records.push(RelationRelinkedRecord {
+                                entity_logical_name: child_entity.clone(),
+                                record_id: child_id,
+                                field_logical_name: field_logical_name.clone(),
+                                parent_record_id,
+                            });
+                        }
+                    }
+
+                    if plan.deleted_records.len() + plan.relinked_records.len()
+                        > RELATION_DELETE_MAX_AFFECTED_RECORDS
+                    {
+                        return Err(AppError::Conflict(format!(
+                            "runtime record '{}' in entity '{}' cannot be deleted because it would affect more than {} related records",
+                            record_id, entity_logical_name, RELATION_DELETE_MAX_AFFECTED_RECORDS
+                        )));
+                    }
+                }
+            }
+        }
+
+        if let Some((child_entity, child_id, field_logical_name)) =
+            restricted.into_iter().find(|(child_entity, child_id, _)| {
+                !deleted_keys.contains(&(child_entity.clone(), child_id.clone()))
+            })
+        {
+            return Err(AppError::Conflict(format!(
+                "runtime record '{}' in entity '{}' cannot be deleted because it is still referenced by relation fields (record '{}' in '{}.{}')",
+                record_id, entity_logical_name, child_id, child_entity, field_logical_name
+            )));
+        }
+
+        plan.relinked_records.retain(|relinked| {
+            !deleted_keys.contains(&(
+                relinked.entity_logical_name.clone(),
+                relinked.record_id.clone(),
+            ))
+        });
+        for relinked in &mut plan.relinked_records {
+            if relinked.parent_record_id.as_ref().is_some_and(|parent_id| {
+                deleted_keys.contains(&(relinked.entity_logical_name.clone(), parent_id.clone()))
+            }) {
+                relinked.parent_record_id = None;
+            }
+            *affected_counts
+                .entry((
+                    relinked.entity_logical_name.clone(),
+                    relinked.field_logical_name.clone(),
+                ))
+                .or_default() += 1;
+        }
+
+        let results = affected_counts
+            .into_iter()
+            .map(
+                |((entity_logical_name, field_logical_name), affected_records)| {
+                    RelationCascadeResult {
+                        entity_logical_name,
+                        field_logical_name,
+                        affected_records,
+                    }
+                },
+            )
+            .collect();
+
+        Ok((plan, results))
+    }
+
+    /// Returns `(child entity, relation field, target entity)` for every
+    /// relation field of the latest published schemas.
+    async fn published_relation_fields(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<(String, String, String)>> {
+        let mut relations = Vec::new();
+        for entity in self.repository.list_entities(tenant_id).await? {
+            let Some(schema) = self
+                .repository
+                .latest_published_schema(tenant_id, entity.logical_name().as_str())
+                .await?
+            else {
+                continue;
+            };
+            relations.extend(schema.fields().iter().filter_map(|field| {
+                match (field.field_type(), field.relation_target_entity()) {
+                    (FieldType::Relation, Some(target)) => Some((
+                        entity.logical_name().as_str().to_owned(),
+                        field.logical_name().as_str().to_owned(),
+                        target.as_str().to_owned(),
+                    )),
+                    _ => None,
+                }
+            }));
+        }
+
+        Ok(relations)
+    }
+
+    /// Returns every record of an entity whose relation field points at a parent.
+    async fn relation_children(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        parent_record_id: &str,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        let mut children = Vec::new();
+        loop {
+            let page = self
+                .repository
+                .query_runtime_records(
+                    tenant_id,
+                    entity_logical_name,
+                    RuntimeRecordQuery {
+                        limit: RELATION_DELETE_PAGE_SIZE,
+                        offset: children.len(),
+                        logical_mode: RuntimeRecordLogicalMode::And,
+                        where_clause: None,
+                        filters: vec![RuntimeRecordFilter {
+                            scope_alias: None,
+                            field_logical_name: field_logical_name.to_owned(),
+                            operator: RuntimeRecordOperator::Eq,
+                            field_type: FieldType::Relation,
+                            field_value: Value::String(parent_record_id.to_owned()),
+                        }],
+                        links: Vec::new(),
+                        sort: Vec::new(),
+                        owner_subject: None,
+                        include_inactive: true,
+                    },
+                )
+                .await?;
+            let page_len = page.len();
+            children.extend(page);
+            if page_len < RELATION_DELETE_PAGE_SIZE
+                || children.len() > RELATION_DELETE_MAX_AFFECTED_RECORDS
+            {
+                return Ok(children);
+            }
+        }
+    }
+}
+
+/// Returns why a delete behavior cannot apply to a relation field, if it cannot.
+pub(super) fn relation_delete_behavior_error(
+    entity_logical_name: &str,
+    field: &EntityFieldDefinition,
+    delete_behavior: RelationDeleteBehavior,
+) -> Option<String> {
+    let field_logical_name = field.logical_name().as_str();
+    if delete_behavior.clears_relation() && field.is_required() {
+        return Some(format!(
+            "relation field '{}.{}' is required and cannot use delete behavior '{}'",
+            entity_logical_name,
+            field_logical_name,
+            delete_behavior.as_str()
+        ));
+    }
+    let is_self_referencing = field
+        .relation_target_entity()
+        .is_some_and(|target| target.as_str() == entity_logical_name);
+    if delete_behavior == RelationDeleteBehavior::Reparent && !is_self_referencing {
+        return Some(format!(
+            "relation field '{}.{}' must reference its own entity to use delete behavior 'reparent'",
+            entity_logical_name, field_logical_name
+        ));
+    }
+
+    None
+}
@@ -0,0 +1,396 @@
+use super::*;
+use crate::metadata_ports::RuntimeRecordSortDirection;
+use crate::{
+    RelationLookupConfig, RelationLookupMatch, RuntimeRecordLogicalMode,
+    SaveRelationLookupConfigInput,
+};
+
+/// Number of lookup results returned when the caller sets no limit.
+const RELATION_LOOKUP_DEFAULT_LIMIT: usize = 20;
+
+/// Upper bound on lookup results regardless of the requested limit.
+const RELATION_LOOKUP_MAX_LIMIT: usize = 50;
+
+impl MetadataService {
+    /// Saves the lookup search configuration of a relation field.
+    ///
+    /// Search fields must be text fields of the relation target and filter
+    /// conditions must reference target fields.
+    pub async fn save_relation_lookup_config(
+        &self,
+        actor: &UserIdentity,
+        input: SaveRelationLookupConfigInput,
+    ) -> AppResult<RelationLookupConfig> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await?;
+
+        let field = self
+            .repository
+            .find_field(
+                actor.tenant_id(),
+                input.entity_logical_name.as_str(),
+                input.field_logical_name.as_str(),
+            )
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "field '{}.{}' does not exist for tenant '{}'",
+                    input.entity_logical_name,
+                    input.field_logical_name,
+                    actor.tenant_id()
+                ))
+            })?;
+        let target_entity_logical_name = relation_target(
+            &field,
+            input.entity_logical_name.as_str(),
+            input.field_logical_name.as_str(),
+        )?;
+
+        let target_fields = self
+            .repository
+            .list_fields(actor.tenant_id(), target_entity_logical_name.as_str())
+            .await?;
+        let mut seen_search_fields = HashSet::new();
+        for search_field in &input.search_field_logical_names {
+            if !seen_search_fields.insert(search_field.as_str()) {
+                return Err(AppError::Validation(format!(
+                    "lookup search field '{}' is listed more than once",
+                    search_field
+                )));
+            }
+            match target_fields
+                .iter()
+                .find(|field| field.logical_name().as_str() == search_field)
+            {
+                Some(field) if field.field_type() == FieldType::Text => {}
+                Some(_) => {
+                    return Err(AppError::Validation(format!(
+                        "lookup search field '{}.{}' must be a text field",
+                        target_entity_logical_name, search_field
+                    )));
+                }
+                None => {
+                    return Err(AppError::Validation(format!(
+                        "lookup search field '{}.{}' does not exist",
+                        target_entity_logical_name, search_field
+                    )));
+                }
+            }
+        }
+        if let Some(filter) = &input.filter {
+            for condition in filter.conditions() {
+                let condition_field = condition.field_logical_name().as_str();
+                if !target_fields
+                    .iter()
+                    .any(|field| field.logical_name().as_str() == condition_field)
+                {
+                    return Err(AppError::Validation(format!(
+                        "lookup filter field '{}.{}' does not exist",
+                        target_entity_logical_name, condition_field
+                    )));
+                }
+            }
+        }
+
+        let config = RelationLookupConfig {
+            entity_logical_name: input.entity_logical_name,
+            field_logical_name: input.field_logical_name,
+            target_entity_logical_name,
+            search_field_logical_names: input.search_field_logical_names,
+            filter: input.filter,
+        };
+        self.repository
+            .save_relation_lookup_config(actor.tenant_id(), actor.subject(), config.clone())
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::MetadataRelationLookupSaved,
+                resource_type: "entity_field_definition".to_owned(),
+                resource_id: format!(
+                    "{}.{}",
+                    config.entity_logical_name, config.field_logical_name
+                ),
+                detail: Some(format!(
+                    "set lookup of '{}.{}' to search {} fields with {} filter conditions",
+                    config.entity_logical_name,
+                    config.field_logical_name,
+                    config.search_field_logical_names.len(),
+                    config
+                        .filter
+                        .as_ref()
+                        .map(|filter| filter.conditions().len())
+                        .unwrap_or_default()
+                )),
+            })
+            .await?;
+
+        Ok(config)
+    }
+
+    /// Returns the lookup search configuration of a relation field, if any.
+    pub async fn relation_lookup_config(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<Option<RelationLookupConfig>> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldRead,
+            )
+            .await?;
+
+        self.repository
+            .find_relation_lookup_config(actor.tenant_id(), entity_logical_name, field_logical_name)
+            .await
+    }
+
+    /// Removes the lookup search configuration of a relation field.
+    pub async fn delete_relation_lookup_config(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await?;
+
+        if !self
+            .repository
+            .delete_relation_lookup_config(
+                actor.tenant_id(),
+                entity_logical_name,
+                field_logical_name,
+            )
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "field '{}.{}' does not define a lookup configuration",
+                entity_logical_name, field_logical_name
+            )));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::MetadataRelationLookupDeleted,
+                resource_type: "entity_field_definition".to_owned(),
+                resource_id: format!("{}.{}", entity_logical_name, field_logical_name),
+                detail: Some(format!(
+                    "removed lookup configuration of '{}.{}'",
+                    entity_logical_name, field_logical_name
+                )),
+            })
+            .await
+    }
+
+    /// Searches the target entity of a published relation field for lookup
+    /// candidates.
+    ///
+    /// The term matches the configured search fields, or the target's `name`
+    /// field (else its first text field) when none are configured. Results
+    /// satisfy the configured lookup filter, respect the actor's read scope
+    /// and field access, exclude inactive records and are capped at 50.
+    pub async fn search_relation_lookup(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        term: &str,
+        limit: Option<usize>,
+    ) -> AppResult<Vec<RelationLookupMatch>> {
+        let schema = self
+            .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+            .await?;
+        let field = schema
+            .fields()
+            .iter()
+            .find(|field| field.logical_name().as_str() == field_logical_name)
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "field '{}.{}' does not exist for tenant '{}'",
+                    entity_logical_name,
+                    field_logical_name,
+                    actor.tenant_id()
+                ))
+            })?;
+        let target_entity_logical_name =
+            relation_target(field, entity_logical_name, field_logical_name)?;
+
+        let config = self
+            .repository
+            .find_relation_lookup_config(actor.tenant_id(), entity_logical_name, field_logical_name)
+            .await?;
+        let target_schema = self
+            .published_schema_for_runtime(actor.tenant_id(), target_entity_logical_name.as_str())
+            .await?;
+        let field_access = self
+            .runtime_field_access_for_actor(actor, target_entity_logical_name.as_str())
+            .await?;
+        let target_fields = target_schema.queryable_fields()?;
+        let field_type = |logical_name: &str| {
+            target_fields
+                .iter()
+                .find(|field| field.logical_name().as_str() == logical_name)
+                .map(EntityFieldDefinition::field_type)
+                .ok_or_else(|| {
+                    AppError::Validation(format!(
+                        "lookup of '{}.{}' references unknown field '{}.{}'",
+                        entity_logical_name,
+                        field_logical_name,
+                        target_entity_logical_name,
+                        logical_name
+                    ))
+                })
+        };
+
+        let configured_search_fields = config
+            .as_ref()
+            .map(|config| config.search_field_logical_names.clone())
+            .unwrap_or_default();
+        let search_fields = if configured_search_fields.is_empty() {
+            default_lookup_search_field(target_schema.fields())
+                .into_iter()
+                .collect::<Vec<_>>()
+        } else {
+            configured_search_fields
+        };
+        let search_fields = search_fields
+            .into_iter()
+            .filter(|search_field| {
+                field_access
+                    .as_ref()
+                    .is_none_or(|access| access.readable_fields.contains(search_field))
+            })
+            .collect::<Vec<_>>();
+
+        let term = term.trim();
+        let where_clause = if term.is_empty() {
+            None
+        } else {
+            if search_fields.is_empty() {
+                return Err(AppError::Validation(format!(
+                    "lookup of '{}.{}' has no searchable fields on entity '{}'",
+                    entity_logical_name, field_logical_name, target_entity_logical_name
+                )));
+            }
+            Some(RuntimeRecordConditionGroup {
+                logical_mode: RuntimeRecordLogicalMode::Or,
+                nodes: search_fields
+                    .iter()
+                    .map(|search_field| {
+                        Ok(RuntimeRecordConditionNode::Filter(RuntimeRecordFilter {
+                            scope_alias: None,
+                            field_logical_name: search_field.clone(),
+                            operator: RuntimeRecordOperator::Contains,
+                            field_type: field_type(search_field)?,
+                            field_value: Value::String(term.to_owned()),
+                        }))
+                    })
+                    .collect::<AppResult<Vec<_>>>()?,
+            })
+        };
+        let (logical_mode, filters) =
+            match config.as_ref().and_then(|config| config.filter.as_ref()) {
+                Some(filter) => Self::runtime_view_filters(filter, &field_type)?,
+                None => (RuntimeRecordLogicalMode::And, Vec::new()),
+            };
+        let sort = search_fields
+            .first()
+            .map(|search_field| {
+                Ok(RuntimeRecordSort {
+                    scope_alias: None,
+                    field_logical_name: search_field.clone(),
+                    field_type: field_type(search_field)?,
+                    direction: RuntimeRecordSortDirection::Asc,
+                })
+            })
+            .transpose()?
+            .into_iter()
+            .collect();
+
+        let records = self
+            .query_runtime_records(
+                actor,
+                target_entity_logical_name.as_str(),
+                RuntimeRecordQuery {
+                    limit: limit
+                        .unwrap_or(RELATION_LOOKUP_DEFAULT_LIMIT)
+                        .clamp(1, RELATION_LOOKUP_MAX_LIMIT),
+                    offset: 0,
+                    logical_mode,
+                    where_clause,
+                    filters,
+                    links: Vec::new(),
+                    sort,
+                    owner_subject: None,
+                    include_inactive: false,
+                },
+            )
+            .await?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| {
+                let label = search_fields
+                    .iter()
+                    .find_map(|search_field| {
+                        record
+                            .data()
+                            .get(search_field)
+                            .and_then(Value::as_str)
+                            .filter(|value| !value.is_empty())
+                    })
+                    .unwrap_or(record.record_id().as_str())
+                    .to_owned();
+                RelationLookupMatch { record, label }
+            })
+            .collect())
+    }
+}
+
+fn relation_target(
+    field: &EntityFieldDefinition,
+    entity_logical_name: &str,
+    field_logical_name: &str,
+) -> AppResult<String> {
+    match (field.field_type(), field.relation_target_entity()) {
+        (FieldType::Relation, Some(target)) => Ok(target.as_str().to_owned()),
+        _ => Err(AppError::Validation(format!(
+            "field '{}.{}' is not a relation field",
+            entity_logical_name, field_logical_name
+        ))),
+    }
+}
+
+fn default_lookup_search_field(fields: &[EntityFieldDefinition]) -> Option<String> {
+    let text_fields = fields
+        .iter()
+        .filter(|field| field.field_type() == FieldType::Text)
+        .map(|field| field.logical_name().as_str());
+    let mut first_text_field = None;
+    for logical_name in text_fields {
+        if logical_name == "name" {
+            return Some(logical_name.to_owned());
+        }
+        first_text_field.get_or_insert(logical_name);
+    }
+
+    first_text_field.map(str::to_owned)
+}
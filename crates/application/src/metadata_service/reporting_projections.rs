@@ -0,0 +1,56 @@
+use super::*;
+use crate::ReportingProjectionRuntimeService;
+
+#[async_trait::async_trait]
+impl ReportingProjectionRuntimeService for MetadataService {
+    async fn latest_published_schema_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        self.latest_published_schema_unchecked(actor, entity_logical_name)
+            .await
+    }
+
+    async fn find_runtime_record_for_projection(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<RuntimeRecord>> {
+        self.repository
+            .find_runtime_record(tenant_id, entity_logical_name, record_id)
+            .await
+    }
+
+    async fn require_entity_report_access(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        field_logical_names: &[String],
+    ) -> AppResult<()> {
+        if self.runtime_read_scope_for_actor(actor).await? != RuntimeAccessScope::All {
+            return Err(AppError::Forbidden(format!(
+                "subject '{}' needs read access to all records of entity '{}' to query reporting projections",
+                actor.subject(),
+                entity_logical_name
+            )));
+        }
+
+        if let Some(field_access) = self
+            .runtime_field_access_for_actor(actor, entity_logical_name)
+            .await?
+            && let Some(field_logical_name) = field_logical_names
+                .iter()
+                .find(|field| !field_access.readable_fields.contains(field.as_str()))
+        {
+            return Err(AppError::Forbidden(format!(
+                "field '{}' of entity '{}' is not readable for this subject",
+                field_logical_name, entity_logical_name
+            )));
+        }
+
+        self.require_compliance_zone_access_to_entity_records(actor, entity_logical_name)
+            .await
+    }
+}
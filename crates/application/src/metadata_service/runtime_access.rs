@@ -138,8 +138,12 @@ impl MetadataService {
         actor: &UserIdentity,
         entity_logical_name: &str,
     ) -> AppResult<Option<crate::RuntimeFieldAccess>> {
-        self.authorization_service
+        let field_access = self
+            .authorization_service
             .runtime_field_access(actor.tenant_id(), actor.subject(), entity_logical_name)
+            .await?;
+
+        self.apply_runtime_field_masking(actor, entity_logical_name, field_access)
             .await
     }
 
@@ -198,6 +202,12 @@ impl MetadataService {
             for (key, value) in object {
                 if field_access.readable_fields.contains(key.as_str()) {
                     redacted.insert(key.clone(), value.clone());
+                } else if let Some(masked_value) = field_access
+                    .masked_fields
+                    .get(key.as_str())
+                    .and_then(|masking_mode| masking_mode.mask_value(value))
+                {
+                    redacted.insert(key.clone(), masked_value);
                 }
             }
         }
@@ -207,5 +217,6 @@ impl MetadataService {
             record.entity_logical_name().as_str(),
             Value::Object(redacted),
         )
+        .map(|redacted_record| redacted_record.with_version(record.version()))
     }
 }
@@ -0,0 +1,257 @@
+use std::collections::BTreeSet;
+
+use crate::metadata_ports::{
+    RUNTIME_RECORD_AGGREGATE_MAX_AGGREGATES, RUNTIME_RECORD_AGGREGATE_MAX_GROUP_FIELDS,
+    RuntimeRecordAggregate, RuntimeRecordAggregateFunction, RuntimeRecordAggregateQuery,
+    RuntimeRecordAggregateRow, RuntimeRecordAggregateSortKey,
+};
+
+use super::*;
+
+impl MetadataService {
+    /// Groups runtime records matching the query conditions and aggregates each group.
+    ///
+    /// Grouped, aggregated and filtered fields must all be readable for the
+    /// actor, and Own-scope readers only aggregate records they own.
+    pub async fn aggregate_runtime_records(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        mut query: RuntimeRecordAggregateQuery,
+    ) -> AppResult<Vec<RuntimeRecordAggregateRow>> {
+        self.within_operation_budget(TimedOperation::RuntimeQuery, async move {
+            let read_scope = self.runtime_read_scope_for_actor(actor).await?;
+            let field_access = self
+                .runtime_field_access_for_actor(actor, entity_logical_name)
+                .await?;
+
+            if read_scope == RuntimeAccessScope::Own {
+                query.owner_subject = Some(actor.subject().to_owned());
+            }
+
+            let schema = self
+                .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+                .await?;
+            // Aggregates cannot drop single rows, so any restricted record the
+            // actor may not read rejects the whole aggregate.
+            self.require_compliance_zone_access_to_entity_records(actor, entity_logical_name)
+                .await?;
+
+            if query.limit == 0 {
+                return Err(AppError::Validation(
+                    "runtime aggregate query limit must be greater than zero".to_owned(),
+                ));
+            }
+
+            let mut record_query = query.record_query();
+            record_query.limit = query.limit;
+            self.validate_runtime_query(
+                actor,
+                entity_logical_name,
+                &schema,
+                &mut record_query,
+                field_access.as_ref(),
+            )
+            .await?;
+            query.filters = record_query.filters;
+            query.where_clause = record_query.where_clause;
+
+            let fields = schema.queryable_fields()?;
+            let resolve_field = |field_logical_name: &str, field_type: FieldType, context: &str| {
+                let field = fields
+                    .iter()
+                    .find(|field| field.logical_name().as_str() == field_logical_name)
+                    .ok_or_else(|| {
+                        AppError::Validation(format!(
+                            "unknown {} field '{}' for entity '{}'",
+                            context, field_logical_name, entity_logical_name
+                        ))
+                    })?;
+
+                if field.field_type() != field_type {
+                    return Err(AppError::Validation(format!(
+                        "{} field type mismatch for '{}': expected '{}', got '{}'",
+                        context,
+                        field_logical_name,
+                        field.field_type().as_str(),
+                        field_type.as_str()
+                    )));
+                }
+
+                if field_access
+                    .as_ref()
+                    .is_some_and(|access| !access.readable_fields.contains(field_logical_name))
+                {
+                    return Err(AppError::Forbidden(format!(
+                        "field '{}' is not readable for {}",
+                        field_logical_name, context
+                    )));
+                }
+
+                if field_type == FieldType::Json {
+                    return Err(AppError::Validation(format!(
+                        "{} is not supported for json field '{}'",
+                        context, field_logical_name
+                    )));
+                }
+
+                Ok(())
+            };
+
+            if query.group_by.len() > RUNTIME_RECORD_AGGREGATE_MAX_GROUP_FIELDS {
+                return Err(AppError::Validation(format!(
+                    "runtime aggregate query supports at most {} group-by fields",
+                    RUNTIME_RECORD_AGGREGATE_MAX_GROUP_FIELDS
+                )));
+            }
+
+            let mut group_fields = BTreeSet::new();
+            for group_by in &query.group_by {
+                resolve_field(
+                    group_by.field_logical_name.as_str(),
+                    group_by.field_type,
+                    "aggregate grouping",
+                )?;
+                if !group_fields.insert(group_by.field_logical_name.as_str()) {
+                    return Err(AppError::Validation(format!(
+                        "duplicate group-by field '{}'",
+                        group_by.field_logical_name
+                    )));
+                }
+            }
+
+            if query.aggregates.is_empty()
+                || query.aggregates.len() > RUNTIME_RECORD_AGGREGATE_MAX_AGGREGATES
+            {
+                return Err(AppError::Validation(format!(
+                    "runtime aggregate query must include between 1 and {} aggregates",
+                    RUNTIME_RECORD_AGGREGATE_MAX_AGGREGATES
+                )));
+            }
+
+            let mut aliases = BTreeSet::new();
+            for aggregate in &query.aggregates {
+                Self::validate_runtime_aggregate(aggregate, &resolve_field)?;
+                if !aliases.insert(aggregate.alias.as_str()) {
+                    return Err(AppError::Validation(format!(
+                        "duplicate aggregate alias '{}'",
+                        aggregate.alias
+                    )));
+                }
+            }
+
+            let find_aggregate = |alias: &str| {
+                query
+                    .aggregates
+                    .iter()
+                    .find(|aggregate| aggregate.alias == alias)
+                    .ok_or_else(|| {
+                        AppError::Validation(format!("unknown aggregate alias '{}'", alias))
+                    })
+            };
+
+            for having in &query.having {
+                let aggregate = find_aggregate(having.aggregate_alias.as_str())?;
+                if !matches!(
+                    having.operator,
+                    RuntimeRecordOperator::Eq
+                        | RuntimeRecordOperator::Neq
+                        | RuntimeRecordOperator::Gt
+                        | RuntimeRecordOperator::Gte
+                        | RuntimeRecordOperator::Lt
+                        | RuntimeRecordOperator::Lte
+                ) {
+                    return Err(AppError::Validation(format!(
+                        "operator '{}' is not supported in aggregate having conditions",
+                        having.operator.as_str()
+                    )));
+                }
+
+                if !aggregate.is_numeric() {
+                    return Err(AppError::Validation(format!(
+                        "having condition on aggregate '{}' requires a numeric aggregate",
+                        aggregate.alias
+                    )));
+                }
+
+                if !having.value.is_number() {
+                    return Err(AppError::Validation(format!(
+                        "having condition on aggregate '{}' must compare against a number",
+                        aggregate.alias
+                    )));
+                }
+            }
+
+            for sort in &query.sort {
+                match &sort.key {
+                    RuntimeRecordAggregateSortKey::GroupField(field_logical_name) => {
+                        if !group_fields.contains(field_logical_name.as_str()) {
+                            return Err(AppError::Validation(format!(
+                                "aggregate sort field '{}' must be a group-by field",
+                                field_logical_name
+                            )));
+                        }
+                    }
+                    RuntimeRecordAggregateSortKey::Aggregate(alias) => {
+                        find_aggregate(alias.as_str())?;
+                    }
+                }
+            }
+
+            self.repository
+                .aggregate_runtime_records(actor.tenant_id(), entity_logical_name, query)
+                .await
+        })
+        .await
+    }
+
+    fn validate_runtime_aggregate(
+        aggregate: &RuntimeRecordAggregate,
+        resolve_field: &impl Fn(&str, FieldType, &str) -> AppResult<()>,
+    ) -> AppResult<()> {
+        if aggregate.alias.trim().is_empty() {
+            return Err(AppError::Validation(
+                "aggregate alias must not be empty".to_owned(),
+            ));
+        }
+
+        let (field_logical_name, field_type) = match (
+            aggregate.field_logical_name.as_deref(),
+            aggregate.field_type,
+        ) {
+            (Some(field_logical_name), Some(field_type)) => (field_logical_name, field_type),
+            (None, None) if aggregate.function == RuntimeRecordAggregateFunction::Count => {
+                return Ok(());
+            }
+            _ => {
+                return Err(AppError::Validation(format!(
+                    "aggregate '{}' with function '{}' requires a field and field type",
+                    aggregate.alias,
+                    aggregate.function.as_str()
+                )));
+            }
+        };
+
+        resolve_field(field_logical_name, field_type, "aggregates")?;
+
+        let supported = match aggregate.function {
+            RuntimeRecordAggregateFunction::Count => true,
+            RuntimeRecordAggregateFunction::Sum | RuntimeRecordAggregateFunction::Avg => {
+                field_type == FieldType::Number
+            }
+            RuntimeRecordAggregateFunction::Min | RuntimeRecordAggregateFunction::Max => {
+                field_type != FieldType::Boolean
+            }
+        };
+        if !supported {
+            return Err(AppError::Validation(format!(
+                "aggregate function '{}' is not supported for field '{}' with type '{}'",
+                aggregate.function.as_str(),
+                field_logical_name,
+                field_type.as_str()
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,411 @@
+use std::collections::HashMap;
+
+use super::runtime_records_write::{record_payload_for_created, record_payload_for_updated};
+use super::*;
+use crate::{
+    RUNTIME_RECORD_CHANGESET_MAX_OPERATIONS, RuntimeRecordChangesetMethod,
+    RuntimeRecordChangesetOperation, RuntimeRecordChangesetResult, RuntimeRecordChangesetWrite,
+};
+use qryvanta_domain::WorkflowTrigger;
+use uuid::Uuid;
+
+const CONTENT_ID_MAX_LENGTH: usize = 64;
+
+impl MetadataService {
+    /// Applies a graph of dependent runtime record writes atomically.
+    ///
+    /// Operations run in order. String values equal to `$<content_id>` resolve
+    /// to the record id produced by an earlier operation, so children can
+    /// reference parents created in the same changeset. Either every operation
+    /// is committed or none is.
+    pub async fn execute_runtime_record_changeset(
+        &self,
+        actor: &UserIdentity,
+        operations: Vec<RuntimeRecordChangesetOperation>,
+    ) -> AppResult<Vec<RuntimeRecordChangesetResult>> {
+        if operations.is_empty() {
+            return Err(AppError::Validation(
+                "runtime record changeset must contain at least one operation".to_owned(),
+            ));
+        }
+        if operations.len() > RUNTIME_RECORD_CHANGESET_MAX_OPERATIONS {
+            return Err(AppError::Validation(format!(
+                "runtime record changeset must not contain more than {RUNTIME_RECORD_CHANGESET_MAX_OPERATIONS} operations"
+            )));
+        }
+
+        let write_scope = self.runtime_write_scope_for_actor(actor).await?;
+        let declared_content_ids = changeset_content_ids(&operations)?;
+
+        let mut resolved_content_ids = HashMap::<String, String>::new();
+        let mut pending_records = HashMap::<String, (String, Value)>::new();
+        let mut writes = Vec::with_capacity(operations.len());
+        let mut field_accesses = Vec::with_capacity(operations.len());
+        let mut previous_data = Vec::with_capacity(operations.len());
+        let mut schemas = Vec::with_capacity(operations.len());
+        let mut pending_field_changes = Vec::new();
+
+        for (index, operation) in operations.iter().enumerate() {
+            let entity_logical_name = operation.entity_logical_name.as_str();
+            let data = resolve_changeset_placeholders(
+                operation.data.clone(),
+                &resolved_content_ids,
+                &declared_content_ids,
+                index,
+            )?;
+
+            let field_access = self
+                .runtime_field_access_for_actor(actor, entity_logical_name)
+                .await?;
+            if let Some(access) = &field_access {
+                Self::enforce_writable_fields(&data, access)?;
+            }
+
+            let schema = self
+                .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+                .await?;
+
+            let (write, existing_data) = match operation.method {
+                RuntimeRecordChangesetMethod::Create => {
+                    if operation.record_id.is_some() {
+                        return Err(AppError::Validation(format!(
+                            "changeset operation {index} creates a record and must not set record_id"
+                        )));
+                    }
+                    self.require_compliance_zone_access(actor, entity_logical_name, None)
+                        .await?;
+
+                    let record_id = Uuid::new_v4().to_string();
+                    let normalized_data = self
+                        .normalize_record_payload_with_entity_business_rules(
+                            actor.tenant_id(),
+                            entity_logical_name,
+                            &schema,
+                            data,
+                            None,
+                            actor.subject(),
+                        )
+                        .await?;
+                    self.validate_relation_values_with_pending(
+                        &schema,
+                        actor.tenant_id(),
+                        &normalized_data,
+                        &pending_records,
+                    )
+                    .await?;
+
+                    let write = RuntimeRecordChangesetWrite {
+                        method: RuntimeRecordChangesetMethod::Create,
+                        entity_logical_name: entity_logical_name.to_owned(),
+                        unique_values: Self::unique_values_for_record(&schema, &normalized_data)?,
+                        workflow_event: Self::runtime_record_workflow_event_input(
+                            actor,
+                            WorkflowTrigger::RuntimeRecordCreated {
+                                entity_logical_name: entity_logical_name.to_owned(),
+                            },
+                            record_payload_for_created(
+                                entity_logical_name,
+                                &normalized_data,
+                                Some(record_id.as_str()),
+                            ),
+                        ),
+                        audit_event: None,
+                        record_id,
+                        data: normalized_data,
+                    };
+                    (write, None)
+                }
+                RuntimeRecordChangesetMethod::Update => {
+                    let record_id = operation
+                        .record_id
+                        .as_deref()
+                        .map(|record_id| {
+                            resolve_changeset_placeholders(
+                                Value::String(record_id.to_owned()),
+                                &resolved_content_ids,
+                                &declared_content_ids,
+                                index,
+                            )
+                        })
+                        .transpose()?
+                        .and_then(|record_id| record_id.as_str().map(ToOwned::to_owned))
+                        .ok_or_else(|| {
+                            AppError::Validation(format!(
+                                "changeset operation {index} updates a record and must set record_id"
+                            ))
+                        })?;
+
+                    let existing_data = match pending_records.get(record_id.as_str()) {
+                        Some((pending_entity, pending_data)) => {
+                            if pending_entity != entity_logical_name {
+                                return Err(AppError::Validation(format!(
+                                    "changeset operation {index} targets record '{record_id}' as entity '{entity_logical_name}' but it was staged as entity '{pending_entity}'"
+                                )));
+                            }
+                            pending_data.clone()
+                        }
+                        None => {
+                            self.require_compliance_zone_access(
+                                actor,
+                                entity_logical_name,
+                                Some(record_id.as_str()),
+                            )
+                            .await?;
+
+                            if write_scope == RuntimeAccessScope::Own
+                                && !self
+                                    .repository
+                                    .runtime_record_owned_by_subject(
+                                        actor.tenant_id(),
+                                        entity_logical_name,
+                                        record_id.as_str(),
+                                        actor.subject(),
+                                    )
+                                    .await?
+                            {
+                                return Err(AppError::Forbidden(format!(
+                                    "subject '{}' can only update owned runtime records for entity '{}'",
+                                    actor.subject(),
+                                    entity_logical_name
+                                )));
+                            }
+
+                            self.repository
+                                .find_runtime_record(
+                                    actor.tenant_id(),
+                                    entity_logical_name,
+                                    record_id.as_str(),
+                                )
+                                .await?
+                                .ok_or_else(|| {
+                                    AppError::NotFound(format!(
+                                        "runtime record '{}' does not exist for entity '{}'",
+                                        record_id, entity_logical_name
+                                    ))
+                                })?
+                                .data()
+                                .clone()
+                        }
+                    };
+
+                    let normalized_data = self
+                        .normalize_record_payload_with_entity_business_rules(
+                            actor.tenant_id(),
+                            entity_logical_name,
+                            &schema,
+                            data,
+                            Some(&existing_data),
+                            actor.subject(),
+                        )
+                        .await?;
+                    let (normalized_data, field_changes) = self
+                        .split_dual_control_field_changes(
+                            actor,
+                            entity_logical_name,
+                            record_id.as_str(),
+                            &existing_data,
+                            normalized_data,
+                        )
+                        .await?;
+                    pending_field_changes.extend(field_changes);
+                    self.validate_relation_values_with_pending(
+                        &schema,
+                        actor.tenant_id(),
+                        &normalized_data,
+                        &pending_records,
+                    )
+                    .await?;
+
+                    let write = RuntimeRecordChangesetWrite {
+                        method: RuntimeRecordChangesetMethod::Update,
+                        entity_logical_name: entity_logical_name.to_owned(),
+                        unique_values: Self::unique_values_for_record(&schema, &normalized_data)?,
+                        workflow_event: Self::runtime_record_workflow_event_input(
+                            actor,
+                            WorkflowTrigger::RuntimeRecordUpdated {
+                                entity_logical_name: entity_logical_name.to_owned(),
+                            },
+                            record_payload_for_updated(
+                                entity_logical_name,
+                                record_id.as_str(),
+                                Some(&existing_data),
+                                &normalized_data,
+                            ),
+                        ),
+                        audit_event: None,
+                        record_id,
+                        data: normalized_data,
+                    };
+                    (write, Some(existing_data))
+                }
+            };
+
+            if let Some(content_id) = &operation.content_id {
+                resolved_content_ids.insert(content_id.clone(), write.record_id.clone());
+            }
+            pending_records.insert(
+                write.record_id.clone(),
+                (write.entity_logical_name.clone(), write.data.clone()),
+            );
+            field_accesses.push(field_access);
+            previous_data.push(existing_data);
+            schemas.push(schema);
+            writes.push(write);
+        }
+
+        let audit_events: Vec<AuditEvent> = writes
+            .iter()
+            .map(|write| {
+                let (action, verb) = match write.method {
+                    RuntimeRecordChangesetMethod::Create => {
+                        (AuditAction::RuntimeRecordCreated, "created")
+                    }
+                    RuntimeRecordChangesetMethod::Update => {
+                        (AuditAction::RuntimeRecordUpdated, "updated")
+                    }
+                };
+                AuditEvent {
+                    tenant_id: actor.tenant_id(),
+                    subject: actor.subject().to_owned(),
+                    action,
+                    resource_type: "runtime_record".to_owned(),
+                    resource_id: write.record_id.clone(),
+                    detail: Some(format!(
+                        "{verb} runtime record '{}' for entity '{}' in changeset",
+                        write.record_id, write.entity_logical_name
+                    )),
+                }
+            })
+            .collect();
+        for (write, audit_event) in writes.iter_mut().zip(&audit_events) {
+            write.audit_event = self.runtime_record_outbox_audit_event(audit_event);
+        }
+
+        let records = self
+            .repository
+            .apply_runtime_record_changeset(actor.tenant_id(), actor.subject(), writes)
+            .await?;
+
+        for audit_event in audit_events {
+            self.append_runtime_record_audit_event(audit_event).await?;
+        }
+        let written_entities: BTreeSet<&str> = operations
+            .iter()
+            .map(|operation| operation.entity_logical_name.as_str())
+            .collect();
+        for entity_logical_name in written_entities {
+            self.invalidate_runtime_view_results(actor.tenant_id(), entity_logical_name)
+                .await;
+        }
+        for (((operation, record), previous_data), schema) in operations
+            .iter()
+            .zip(&records)
+            .zip(&previous_data)
+            .zip(&schemas)
+        {
+            self.record_runtime_record_status_change(
+                actor,
+                operation.entity_logical_name.as_str(),
+                record.record_id().as_str(),
+                previous_data.as_ref(),
+                record.data(),
+            )
+            .await?;
+            if let Some(previous_data) = previous_data {
+                self.record_runtime_record_history(
+                    actor,
+                    operation.entity_logical_name.as_str(),
+                    schema,
+                    previous_data,
+                    record,
+                )
+                .await?;
+            }
+        }
+        self.record_pending_field_changes(actor, pending_field_changes)
+            .await?;
+
+        operations
+            .into_iter()
+            .zip(records)
+            .zip(field_accesses)
+            .map(|((operation, record), field_access)| {
+                Ok(RuntimeRecordChangesetResult {
+                    content_id: operation.content_id,
+                    method: operation.method,
+                    record: Self::redact_runtime_record_if_needed(record, field_access.as_ref())?,
+                })
+            })
+            .collect()
+    }
+}
+
+fn changeset_content_ids(
+    operations: &[RuntimeRecordChangesetOperation],
+) -> AppResult<HashSet<String>> {
+    let mut content_ids = HashSet::new();
+    for (index, operation) in operations.iter().enumerate() {
+        let Some(content_id) = &operation.content_id else {
+            continue;
+        };
+
+        if content_id.is_empty()
+            || content_id.len() > CONTENT_ID_MAX_LENGTH
+            || !content_id.chars().all(|character| {
+                character.is_ascii_alphanumeric() || matches!(character, '_' | '-')
+            })
+        {
+            return Err(AppError::Validation(format!(
+                "changeset operation {index} content_id '{content_id}' must be 1-{CONTENT_ID_MAX_LENGTH} letters, digits, '_' or '-'"
+            )));
+        }
+
+        if !content_ids.insert(content_id.clone()) {
+            return Err(AppError::Validation(format!(
+                "changeset content_id '{content_id}' is used by more than one operation"
+            )));
+        }
+    }
+
+    Ok(content_ids)
+}
+
+fn resolve_changeset_placeholders(
+    value: Value,
+    resolved: &HashMap<String, String>,
+    declared: &HashSet<String>,
+    operation_index: usize,
+) -> AppResult<Value> {
+    match value {
+        Value::String(text) => {
+            let Some(content_id) = text.strip_prefix('$') else {
+                return Ok(Value::String(text));
+            };
+
+            if let Some(record_id) = resolved.get(content_id) {
+                return Ok(Value::String(record_id.clone()));
+            }
+            if declared.contains(content_id) {
+                return Err(AppError::Validation(format!(
+                    "changeset operation {operation_index} references content_id '{content_id}' before the operation that defines it"
+                )));
+            }
+
+            Ok(Value::String(text))
+        }
+        Value::Array(items) => items
+            .into_iter()
+            .map(|item| resolve_changeset_placeholders(item, resolved, declared, operation_index))
+            .collect::<AppResult<Vec<_>>>()
+            .map(Value::Array),
+        Value::Object(object) => object
+            .into_iter()
+            .map(|(key, item)| {
+                resolve_changeset_placeholders(item, resolved, declared, operation_index)
+                    .map(|item| (key, item))
+            })
+            .collect::<AppResult<serde_json::Map<_, _>>>()
+            .map(Value::Object),
+        other => Ok(other),
+    }
+}
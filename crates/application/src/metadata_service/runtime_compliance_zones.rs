@@ -0,0 +1,153 @@
+use super::*;
+
+use crate::ComplianceZoneTag;
+
+impl MetadataService {
+    /// Rejects access when the entity, or the given record, is restricted to
+    /// a compliance zone the actor is not assigned to.
+    pub(super) async fn require_compliance_zone_access(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: Option<&str>,
+    ) -> AppResult<()> {
+        let Some(compliance_zone_repository) = &self.compliance_zone_repository else {
+            return Ok(());
+        };
+
+        let record_ids = record_id
+            .map(|record_id| vec![record_id.to_owned()])
+            .unwrap_or_default();
+        let tags = compliance_zone_repository
+            .find_zone_tags(actor.tenant_id(), entity_logical_name, &record_ids)
+            .await?;
+        if tags.is_empty() {
+            return Ok(());
+        }
+
+        let assigned_zones = self.assigned_compliance_zones(actor).await?;
+        match tags
+            .iter()
+            .find(|tag| !assigned_zones.contains(tag.zone.as_str()))
+        {
+            Some(tag) => Err(compliance_zone_mismatch(actor, tag, &assigned_zones)),
+            None => Ok(()),
+        }
+    }
+
+    /// Drops records restricted to compliance zones the actor is not
+    /// assigned to, and rejects the read when the whole entity is.
+    pub(super) async fn filter_runtime_records_by_compliance_zone(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        records: Vec<RuntimeRecord>,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        let Some(compliance_zone_repository) = &self.compliance_zone_repository else {
+            return Ok(records);
+        };
+
+        let record_ids = records
+            .iter()
+            .map(|record| record.record_id().as_str().to_owned())
+            .collect::<Vec<_>>();
+        let tags = compliance_zone_repository
+            .find_zone_tags(actor.tenant_id(), entity_logical_name, &record_ids)
+            .await?;
+        if tags.is_empty() {
+            return Ok(records);
+        }
+
+        let assigned_zones = self.assigned_compliance_zones(actor).await?;
+        if let Some(entity_tag) = tags
+            .iter()
+            .find(|tag| tag.record_id.is_none() && !assigned_zones.contains(tag.zone.as_str()))
+        {
+            return Err(compliance_zone_mismatch(actor, entity_tag, &assigned_zones));
+        }
+
+        let restricted_record_ids = tags
+            .iter()
+            .filter(|tag| !assigned_zones.contains(tag.zone.as_str()))
+            .filter_map(|tag| tag.record_id.as_deref())
+            .collect::<BTreeSet<_>>();
+
+        Ok(records
+            .into_iter()
+            .filter(|record| !restricted_record_ids.contains(record.record_id().as_str()))
+            .collect())
+    }
+
+    /// Rejects entity-wide reads, such as aggregates, when any record of the
+    /// entity is restricted to a zone the actor is not assigned to.
+    pub(super) async fn require_compliance_zone_access_to_entity_records(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<()> {
+        let Some(compliance_zone_repository) = &self.compliance_zone_repository else {
+            return Ok(());
+        };
+
+        let tags = compliance_zone_repository
+            .list_zone_tags(actor.tenant_id(), Some(entity_logical_name))
+            .await?;
+        if tags.is_empty() {
+            return Ok(());
+        }
+
+        let assigned_zones = self.assigned_compliance_zones(actor).await?;
+        match tags
+            .iter()
+            .find(|tag| !assigned_zones.contains(tag.zone.as_str()))
+        {
+            Some(tag) => Err(compliance_zone_mismatch(actor, tag, &assigned_zones)),
+            None => Ok(()),
+        }
+    }
+
+    async fn assigned_compliance_zones(&self, actor: &UserIdentity) -> AppResult<BTreeSet<String>> {
+        let Some(compliance_zone_repository) = &self.compliance_zone_repository else {
+            return Ok(BTreeSet::new());
+        };
+
+        Ok(compliance_zone_repository
+            .list_zone_assignments(actor.tenant_id(), Some(actor.subject()))
+            .await?
+            .into_iter()
+            .map(|assignment| assignment.zone)
+            .collect())
+    }
+}
+
+fn compliance_zone_mismatch(
+    actor: &UserIdentity,
+    tag: &ComplianceZoneTag,
+    assigned_zones: &BTreeSet<String>,
+) -> AppError {
+    let target = match &tag.record_id {
+        Some(record_id) => format!(
+            "runtime record '{}' of entity '{}'",
+            record_id, tag.entity_logical_name
+        ),
+        None => format!("entity '{}'", tag.entity_logical_name),
+    };
+    let assignment = if assigned_zones.is_empty() {
+        "is not assigned to any compliance zone".to_owned()
+    } else {
+        format!(
+            "is only assigned to compliance zones '{}'",
+            assigned_zones
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join("', '")
+        )
+    };
+
+    AppError::Forbidden(format!(
+        "{target} is restricted to compliance zone '{}' and subject '{}' {assignment}",
+        tag.zone,
+        actor.subject()
+    ))
+}
@@ -0,0 +1,240 @@
+use qryvanta_domain::{FilterOperator, LogicalMode, ViewFilterGroup};
+
+use crate::RuntimeFieldAccess;
+use crate::metadata_ports::{RuntimeRecordLogicalMode, RuntimeRecordSortDirection};
+
+use super::*;
+
+/// Number of records fetched per export page.
+pub const RUNTIME_RECORD_EXPORT_PAGE_SIZE: usize = 500;
+
+/// One exported column resolved from a saved view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeRecordExportColumn {
+    /// Field logical name.
+    pub field_logical_name: String,
+    /// Field type from the published schema.
+    pub field_type: FieldType,
+}
+
+/// Page-by-page cursor over runtime records selected by a saved view.
+///
+/// The view, field access and read scope are resolved once when the export is
+/// prepared; each page then runs the same validated query with the next offset.
+pub struct RuntimeRecordExport {
+    service: MetadataService,
+    actor: UserIdentity,
+    entity_logical_name: String,
+    columns: Vec<RuntimeRecordExportColumn>,
+    query: RuntimeRecordQuery,
+    field_access: Option<RuntimeFieldAccess>,
+    exhausted: bool,
+}
+
+impl RuntimeRecordExport {
+    /// Returns the exported columns in view order.
+    #[must_use]
+    pub fn columns(&self) -> &[RuntimeRecordExportColumn] {
+        &self.columns
+    }
+
+    /// Fetches the next page of records, or `None` once the export is complete.
+    pub async fn next_page(&mut self) -> AppResult<Option<Vec<RuntimeRecord>>> {
+        if self.exhausted {
+            return Ok(None);
+        }
+
+        let records = self
+            .service
+            .repository
+            .query_runtime_records(
+                self.actor.tenant_id(),
+                self.entity_logical_name.as_str(),
+                self.query.clone(),
+            )
+            .await?;
+
+        self.query.offset += records.len();
+        self.exhausted = records.len() < self.query.limit;
+        if records.is_empty() {
+            return Ok(None);
+        }
+
+        // A page may shrink when records of the page are restricted to
+        // compliance zones of other subjects; the offset still advances by
+        // the unfiltered page size.
+        let records = self
+            .service
+            .filter_runtime_records_by_compliance_zone(
+                &self.actor,
+                self.entity_logical_name.as_str(),
+                records,
+            )
+            .await?;
+
+        MetadataService::redact_runtime_records_if_needed(records, self.field_access.as_ref())
+            .map(Some)
+    }
+}
+
+impl MetadataService {
+    /// Prepares an export of the records matching a saved view.
+    ///
+    /// Columns the actor cannot read are dropped, and Own-scope readers only
+    /// export records they own.
+    pub async fn prepare_runtime_record_export(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+    ) -> AppResult<RuntimeRecordExport> {
+        let read_scope = self.runtime_read_scope_for_actor(actor).await?;
+        let field_access = self
+            .runtime_field_access_for_actor(actor, entity_logical_name)
+            .await?;
+        let schema = self
+            .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+            .await?;
+        self.require_compliance_zone_access(actor, entity_logical_name, None)
+            .await?;
+        let view = self
+            .repository
+            .find_view(actor.tenant_id(), entity_logical_name, view_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "view '{}' does not exist for entity '{}'",
+                    view_logical_name, entity_logical_name
+                ))
+            })?;
+
+        let fields = schema.queryable_fields()?;
+        let field_type = |field_logical_name: &str| {
+            fields
+                .iter()
+                .find(|field| field.logical_name().as_str() == field_logical_name)
+                .map(EntityFieldDefinition::field_type)
+                .ok_or_else(|| {
+                    AppError::Validation(format!(
+                        "view '{}' references unknown field '{}'",
+                        view_logical_name, field_logical_name
+                    ))
+                })
+        };
+
+        let mut columns = Vec::new();
+        for column in view.columns() {
+            let field_logical_name = column.field_logical_name().as_str();
+            if field_access.as_ref().is_some_and(|access| {
+                !access.readable_fields.contains(field_logical_name)
+                    && access
+                        .masked_fields
+                        .get(field_logical_name)
+                        .is_none_or(|mode| *mode == FieldMaskingMode::Hidden)
+            }) {
+                continue;
+            }
+
+            columns.push(RuntimeRecordExportColumn {
+                field_logical_name: field_logical_name.to_owned(),
+                field_type: field_type(field_logical_name)?,
+            });
+        }
+
+        if columns.is_empty() {
+            return Err(AppError::Forbidden(format!(
+                "no columns of view '{}' are readable for this subject",
+                view_logical_name
+            )));
+        }
+
+        let (logical_mode, filters) = match view.filter_criteria() {
+            Some(group) => Self::runtime_view_filters(group, &field_type)?,
+            None => (RuntimeRecordLogicalMode::And, Vec::new()),
+        };
+        let sort = view
+            .default_sort()
+            .map(|sort| {
+                Ok(RuntimeRecordSort {
+                    scope_alias: None,
+                    field_logical_name: sort.field_logical_name().as_str().to_owned(),
+                    field_type: field_type(sort.field_logical_name().as_str())?,
+                    direction: match sort.direction() {
+                        SortDirection::Asc => RuntimeRecordSortDirection::Asc,
+                        SortDirection::Desc => RuntimeRecordSortDirection::Desc,
+                    },
+                })
+            })
+            .transpose()?
+            .into_iter()
+            .collect();
+
+        let mut query = RuntimeRecordQuery {
+            limit: RUNTIME_RECORD_EXPORT_PAGE_SIZE,
+            offset: 0,
+            logical_mode,
+            where_clause: None,
+            filters,
+            links: Vec::new(),
+            sort,
+            owner_subject: (read_scope == RuntimeAccessScope::Own)
+                .then(|| actor.subject().to_owned()),
+            include_inactive: false,
+        };
+        self.validate_runtime_query(
+            actor,
+            entity_logical_name,
+            &schema,
+            &mut query,
+            field_access.as_ref(),
+        )
+        .await?;
+        self.exclude_inactive_records(actor.tenant_id(), &schema, &mut query)
+            .await?;
+
+        Ok(RuntimeRecordExport {
+            service: self.clone(),
+            actor: actor.clone(),
+            entity_logical_name: entity_logical_name.to_owned(),
+            columns,
+            query,
+            field_access,
+            exhausted: false,
+        })
+    }
+
+    pub(super) fn runtime_view_filters(
+        group: &ViewFilterGroup,
+        field_type: &impl Fn(&str) -> AppResult<FieldType>,
+    ) -> AppResult<(RuntimeRecordLogicalMode, Vec<RuntimeRecordFilter>)> {
+        let logical_mode = match group.logical_mode() {
+            LogicalMode::And => RuntimeRecordLogicalMode::And,
+            LogicalMode::Or => RuntimeRecordLogicalMode::Or,
+        };
+        let filters = group
+            .conditions()
+            .iter()
+            .map(|condition| {
+                let field_logical_name = condition.field_logical_name().as_str();
+                Ok(RuntimeRecordFilter {
+                    scope_alias: None,
+                    field_logical_name: field_logical_name.to_owned(),
+                    operator: match condition.operator() {
+                        FilterOperator::Eq => RuntimeRecordOperator::Eq,
+                        FilterOperator::Neq => RuntimeRecordOperator::Neq,
+                        FilterOperator::Gt => RuntimeRecordOperator::Gt,
+                        FilterOperator::Gte => RuntimeRecordOperator::Gte,
+                        FilterOperator::Lt => RuntimeRecordOperator::Lt,
+                        FilterOperator::Lte => RuntimeRecordOperator::Lte,
+                        FilterOperator::Contains => RuntimeRecordOperator::Contains,
+                        FilterOperator::In => RuntimeRecordOperator::In,
+                    },
+                    field_type: field_type(field_logical_name)?,
+                    field_value: condition.value().clone(),
+                })
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+
+        Ok((logical_mode, filters))
+    }
+}
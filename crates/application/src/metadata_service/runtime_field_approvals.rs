@@ -0,0 +1,428 @@
+use chrono::{Duration, Utc};
+use qryvanta_domain::WorkflowTrigger;
+use uuid::Uuid;
+
+use super::runtime_records_write::record_payload_for_updated;
+use super::*;
+use crate::RuntimeRecordWorkflowEventInput;
+use crate::field_change_approval_service::{
+    FIELD_CHANGE_REQUESTED_APPROVAL_KEY, NewPendingFieldChange, PendingFieldChange,
+    PendingFieldChangeQuery, PendingFieldChangeStatus,
+};
+
+impl MetadataService {
+    /// Lists dual-control field changes for the actor tenant.
+    pub async fn list_pending_field_changes(
+        &self,
+        actor: &UserIdentity,
+        query: PendingFieldChangeQuery,
+    ) -> AppResult<Vec<PendingFieldChange>> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::RuntimeRecordRead,
+            )
+            .await?;
+
+        let repository = self.require_field_change_approval_repository()?;
+        repository.expire_stale_changes(actor.tenant_id()).await?;
+        repository
+            .list_pending_changes(actor.tenant_id(), query)
+            .await
+    }
+
+    /// Approves an open dual-control field change and applies it to the record.
+    ///
+    /// The approver must differ from the requester, and the field must still
+    /// hold the value it had when the change was requested.
+    pub async fn approve_pending_field_change(
+        &self,
+        actor: &UserIdentity,
+        change_id: &str,
+    ) -> AppResult<PendingFieldChange> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::RuntimeRecordWrite,
+            )
+            .await?;
+
+        let repository = self.require_field_change_approval_repository()?;
+        let change = self.find_open_field_change(actor, change_id).await?;
+        if change.requested_by_subject == actor.subject() {
+            return Err(AppError::Forbidden(format!(
+                "pending field change '{}' must be approved by a subject other than its requester",
+                change_id
+            )));
+        }
+
+        let entity_logical_name = change.entity_logical_name.as_str();
+        let record_id = change.record_id.as_str();
+        let field_logical_name = change.field_logical_name.as_str();
+        let mut applied_data = serde_json::Map::new();
+        applied_data.insert(
+            field_logical_name.to_owned(),
+            change.proposed_value.clone().unwrap_or(Value::Null),
+        );
+        let applied_data = Value::Object(applied_data);
+        if let Some(access) = self
+            .runtime_field_access_for_actor(actor, entity_logical_name)
+            .await?
+        {
+            Self::enforce_writable_fields(&applied_data, &access)?;
+        }
+
+        let schema = self
+            .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+            .await?;
+        let existing_record = self
+            .repository
+            .find_runtime_record(actor.tenant_id(), entity_logical_name, record_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "runtime record '{}' does not exist for entity '{}'",
+                    record_id, entity_logical_name
+                ))
+            })?;
+        if existing_record.data().get(field_logical_name) != change.previous_value.as_ref() {
+            return Err(AppError::Conflict(format!(
+                "field '{}' on runtime record '{}' changed after the approval request; reject it and submit a new change",
+                field_logical_name, record_id
+            )));
+        }
+
+        let mut data = existing_record.data().clone();
+        let object = data.as_object_mut().ok_or_else(|| {
+            AppError::Internal(format!(
+                "runtime record '{}' for entity '{}' is not a JSON object",
+                record_id, entity_logical_name
+            ))
+        })?;
+        Self::strip_system_field_values(&schema, object);
+        match &change.proposed_value {
+            Some(value) => {
+                object.insert(field_logical_name.to_owned(), value.clone());
+            }
+            None => {
+                object.remove(field_logical_name);
+            }
+        }
+
+        let normalized_data = self
+            .normalize_record_payload_with_entity_business_rules(
+                actor.tenant_id(),
+                entity_logical_name,
+                &schema,
+                data,
+                Some(existing_record.data()),
+                actor.subject(),
+            )
+            .await?;
+        self.validate_relation_values(&schema, actor.tenant_id(), &normalized_data)
+            .await?;
+        let unique_values = Self::unique_values_for_record(&schema, &normalized_data)?;
+
+        let record = self
+            .repository
+            .update_runtime_record(
+                actor.tenant_id(),
+                entity_logical_name,
+                record_id,
+                normalized_data.clone(),
+                unique_values,
+                None,
+                Self::runtime_record_workflow_event_input(
+                    actor,
+                    WorkflowTrigger::RuntimeRecordUpdated {
+                        entity_logical_name: entity_logical_name.to_owned(),
+                    },
+                    record_payload_for_updated(
+                        entity_logical_name,
+                        record_id,
+                        Some(existing_record.data()),
+                        &normalized_data,
+                    ),
+                ),
+                None,
+            )
+            .await?;
+        self.invalidate_runtime_view_results(actor.tenant_id(), entity_logical_name)
+            .await;
+        self.record_runtime_record_history(
+            actor,
+            entity_logical_name,
+            &schema,
+            existing_record.data(),
+            &record,
+        )
+        .await?;
+
+        let approved = repository
+            .resolve_pending_change(
+                actor.tenant_id(),
+                change_id,
+                actor.subject(),
+                PendingFieldChangeStatus::Approved,
+            )
+            .await?
+            .ok_or_else(|| {
+                AppError::Conflict(format!(
+                    "pending field change '{}' was decided concurrently",
+                    change_id
+                ))
+            })?;
+
+        self.append_field_change_audit_event(
+            actor,
+            AuditAction::RuntimeFieldChangeApproved,
+            &approved,
+        )
+        .await?;
+
+        Ok(approved)
+    }
+
+    /// Rejects an open dual-control field change without applying it.
+    ///
+    /// Requesters may withdraw their own changes; anyone else needs runtime
+    /// record write access.
+    pub async fn reject_pending_field_change(
+        &self,
+        actor: &UserIdentity,
+        change_id: &str,
+    ) -> AppResult<PendingFieldChange> {
+        let repository = self.require_field_change_approval_repository()?;
+        let change = self.find_open_field_change(actor, change_id).await?;
+        if change.requested_by_subject != actor.subject() {
+            self.authorization_service
+                .require_permission(
+                    actor.tenant_id(),
+                    actor.subject(),
+                    Permission::RuntimeRecordWrite,
+                )
+                .await?;
+        }
+
+        let rejected = repository
+            .resolve_pending_change(
+                actor.tenant_id(),
+                change_id,
+                actor.subject(),
+                PendingFieldChangeStatus::Rejected,
+            )
+            .await?
+            .ok_or_else(|| {
+                AppError::Conflict(format!(
+                    "pending field change '{}' was decided concurrently",
+                    change_id
+                ))
+            })?;
+
+        self.append_field_change_audit_event(
+            actor,
+            AuditAction::RuntimeFieldChangeRejected,
+            &rejected,
+        )
+        .await?;
+
+        Ok(rejected)
+    }
+
+    /// Holds back changes to dual-control fields from an update payload.
+    ///
+    /// Returns the payload with those fields reset to their stored values and
+    /// the pending changes to open once the update succeeds.
+    pub(super) async fn split_dual_control_field_changes(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        existing_data: &Value,
+        data: Value,
+    ) -> AppResult<(Value, Vec<NewPendingFieldChange>)> {
+        let Some(repository) = &self.field_change_approval_repository else {
+            return Ok((data, Vec::new()));
+        };
+
+        let dual_control_fields = repository
+            .list_dual_control_fields(actor.tenant_id(), Some(entity_logical_name))
+            .await?;
+        if dual_control_fields.is_empty() {
+            return Ok((data, Vec::new()));
+        }
+
+        let Value::Object(mut object) = data else {
+            return Err(AppError::Validation(
+                "runtime record payload must be a JSON object".to_owned(),
+            ));
+        };
+
+        let now = Utc::now();
+        let mut changes = Vec::new();
+        for field in dual_control_fields {
+            let field_logical_name = field.field_logical_name.as_str();
+            let previous_value = existing_data.get(field_logical_name).cloned();
+            let proposed_value = object.get(field_logical_name).cloned();
+            if previous_value == proposed_value {
+                continue;
+            }
+
+            match &previous_value {
+                Some(value) => {
+                    object.insert(field_logical_name.to_owned(), value.clone());
+                }
+                None => {
+                    object.remove(field_logical_name);
+                }
+            }
+
+            let change_id = Uuid::new_v4().to_string();
+            let expires_at = now + Duration::hours(i64::from(field.approval_window_hours));
+            let notification = RuntimeRecordWorkflowEventInput {
+                trigger: WorkflowTrigger::ApprovalEventReceived {
+                    approval_key: FIELD_CHANGE_REQUESTED_APPROVAL_KEY.to_owned(),
+                },
+                record_id: record_id.to_owned(),
+                payload: serde_json::json!({
+                    "event": "approval_event_received",
+                    "approval_key": FIELD_CHANGE_REQUESTED_APPROVAL_KEY,
+                    "change_id": change_id,
+                    "entity_logical_name": entity_logical_name,
+                    "record_id": record_id,
+                    "field_logical_name": field_logical_name,
+                    "previous_value": previous_value,
+                    "proposed_value": proposed_value,
+                    "requested_by_subject": actor.subject(),
+                    "expires_at": expires_at.to_rfc3339(),
+                }),
+                emitted_by_subject: actor.subject().to_owned(),
+            };
+
+            changes.push(NewPendingFieldChange {
+                change_id,
+                entity_logical_name: entity_logical_name.to_owned(),
+                record_id: record_id.to_owned(),
+                field_logical_name: field_logical_name.to_owned(),
+                previous_value,
+                proposed_value,
+                expires_at,
+                notification: Some(notification),
+            });
+        }
+
+        if !changes.is_empty() {
+            repository.expire_stale_changes(actor.tenant_id()).await?;
+            let open_changes = repository
+                .list_pending_changes(
+                    actor.tenant_id(),
+                    PendingFieldChangeQuery {
+                        entity_logical_name: Some(entity_logical_name.to_owned()),
+                        record_id: Some(record_id.to_owned()),
+                        status: Some(PendingFieldChangeStatus::Pending),
+                    },
+                )
+                .await?;
+            if let Some(open_change) = open_changes.iter().find(|open_change| {
+                changes
+                    .iter()
+                    .any(|change| change.field_logical_name == open_change.field_logical_name)
+            }) {
+                return Err(AppError::Conflict(format!(
+                    "field '{}' on runtime record '{}' already has a pending change '{}' awaiting approval",
+                    open_change.field_logical_name, record_id, open_change.change_id
+                )));
+            }
+        }
+
+        Ok((Value::Object(object), changes))
+    }
+
+    pub(super) async fn record_pending_field_changes(
+        &self,
+        actor: &UserIdentity,
+        changes: Vec<NewPendingFieldChange>,
+    ) -> AppResult<()> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+        let repository = self.require_field_change_approval_repository()?;
+
+        let created = repository
+            .create_pending_changes(actor.tenant_id(), actor.subject(), changes)
+            .await?;
+        for change in &created {
+            self.append_field_change_audit_event(
+                actor,
+                AuditAction::RuntimeFieldChangeRequested,
+                change,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_open_field_change(
+        &self,
+        actor: &UserIdentity,
+        change_id: &str,
+    ) -> AppResult<PendingFieldChange> {
+        let repository = self.require_field_change_approval_repository()?;
+        repository.expire_stale_changes(actor.tenant_id()).await?;
+
+        let change = repository
+            .find_pending_change(actor.tenant_id(), change_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("pending field change '{change_id}' does not exist"))
+            })?;
+        if !change.is_open(Utc::now()) {
+            return Err(AppError::Conflict(format!(
+                "pending field change '{}' is already {}",
+                change_id,
+                change.status.as_str()
+            )));
+        }
+
+        Ok(change)
+    }
+
+    fn require_field_change_approval_repository(
+        &self,
+    ) -> AppResult<&Arc<dyn FieldChangeApprovalRepository>> {
+        self.field_change_approval_repository
+            .as_ref()
+            .ok_or_else(|| {
+                AppError::Conflict("dual-control field approvals are not enabled".to_owned())
+            })
+    }
+
+    async fn append_field_change_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        change: &PendingFieldChange,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "runtime_field_change".to_owned(),
+                resource_id: change.change_id.clone(),
+                detail: Some(
+                    serde_json::json!({
+                        "entity_logical_name": change.entity_logical_name,
+                        "record_id": change.record_id,
+                        "field_logical_name": change.field_logical_name,
+                        "requested_by_subject": change.requested_by_subject,
+                    })
+                    .to_string(),
+                ),
+            })
+            .await
+    }
+}
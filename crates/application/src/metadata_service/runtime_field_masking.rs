@@ -0,0 +1,133 @@
+use super::*;
+
+use crate::RuntimeFieldAccess;
+use crate::field_masking_service::effective_field_masking_modes;
+
+impl MetadataService {
+    /// Resolves how the actor sees classified fields of an entity.
+    ///
+    /// Classifications are read from the latest published schema, so a new
+    /// classification applies once the entity is published again. Returns
+    /// `None` when no field of the entity is masked for the actor.
+    async fn runtime_masked_fields_for_actor(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<(PublishedEntitySchema, BTreeMap<String, FieldMaskingMode>)>> {
+        let Some(field_masking_repository) = &self.field_masking_repository else {
+            return Ok(None);
+        };
+
+        let rules = field_masking_repository
+            .list_masking_rules(actor.tenant_id(), None)
+            .await?;
+        if rules.is_empty() {
+            return Ok(None);
+        }
+
+        let Some(schema) = self
+            .repository
+            .latest_published_schema(actor.tenant_id(), entity_logical_name)
+            .await?
+        else {
+            return Ok(None);
+        };
+        if schema
+            .fields()
+            .iter()
+            .all(|field| field.classification().is_none())
+        {
+            return Ok(None);
+        }
+
+        let role_names = self
+            .authorization_service
+            .role_names(actor.tenant_id(), actor.subject())
+            .await?;
+        let modes = effective_field_masking_modes(&rules, &role_names);
+        let masked_fields = schema
+            .fields()
+            .iter()
+            .filter_map(|field| {
+                let mode = modes.get(&field.classification()?)?;
+                Some((field.logical_name().as_str().to_owned(), *mode))
+            })
+            .collect::<BTreeMap<_, _>>();
+        if masked_fields.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some((schema, masked_fields)))
+    }
+
+    /// Narrows field access so masked fields are no longer readable.
+    ///
+    /// Without field grants every schema and system field starts out readable
+    /// and writable. Fields a grant already hides stay hidden.
+    pub(super) async fn apply_runtime_field_masking(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        field_access: Option<RuntimeFieldAccess>,
+    ) -> AppResult<Option<RuntimeFieldAccess>> {
+        let Some((schema, masked_fields)) = self
+            .runtime_masked_fields_for_actor(actor, entity_logical_name)
+            .await?
+        else {
+            return Ok(field_access);
+        };
+
+        let mut field_access = field_access.unwrap_or_else(|| {
+            let fields = schema
+                .fields()
+                .iter()
+                .map(|field| field.logical_name().as_str().to_owned())
+                .chain(
+                    SystemField::ALL
+                        .iter()
+                        .map(|system_field| system_field.as_str().to_owned()),
+                )
+                .collect::<BTreeSet<_>>();
+            RuntimeFieldAccess {
+                readable_fields: fields.clone(),
+                writable_fields: fields,
+                masked_fields: BTreeMap::new(),
+            }
+        });
+        for (field_logical_name, masking_mode) in masked_fields {
+            if field_access.readable_fields.remove(&field_logical_name) {
+                field_access
+                    .masked_fields
+                    .insert(field_logical_name, masking_mode);
+            }
+        }
+
+        Ok(Some(field_access))
+    }
+
+    /// Restores stored values whose masked form was sent back unchanged.
+    ///
+    /// Clients that save a record they read keep the stored value instead of
+    /// overwriting it with its masked form.
+    pub(super) fn restore_unchanged_masked_values(
+        data: &mut Value,
+        existing_data: &Value,
+        field_access: &RuntimeFieldAccess,
+    ) {
+        let Some(object) = data.as_object_mut() else {
+            return;
+        };
+
+        for (field_logical_name, value) in object.iter_mut() {
+            let Some(masking_mode) = field_access.masked_fields.get(field_logical_name) else {
+                continue;
+            };
+            let Some(existing) = existing_data.get(field_logical_name) else {
+                continue;
+            };
+            if masking_mode.mask_value(existing).as_ref() == Some(value) {
+                *value = existing.clone();
+            }
+        }
+    }
+}
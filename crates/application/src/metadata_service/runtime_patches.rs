@@ -0,0 +1,62 @@
+use super::*;
+
+impl MetadataService {
+    /// Applies `patch` on top of the stored runtime record without global
+    /// permission checks.
+    ///
+    /// Only the patched fields must be writable for the actor; every other
+    /// stored value is carried over, including fields the actor cannot read.
+    /// The write is pinned to the version that was read, so a concurrent
+    /// change fails with a conflict instead of being overwritten.
+    pub async fn patch_runtime_record_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        patch: &Value,
+    ) -> AppResult<RuntimeRecord> {
+        let patch_object = patch.as_object().ok_or_else(|| {
+            AppError::Validation("runtime record payload must be a JSON object".to_owned())
+        })?;
+        let schema = self
+            .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+            .await?;
+        let existing_record = self
+            .repository
+            .find_runtime_record(actor.tenant_id(), entity_logical_name, record_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "runtime record '{}' does not exist for entity '{}'",
+                    record_id, entity_logical_name
+                ))
+            })?;
+
+        let mut data = existing_record.data().clone();
+        let object = data.as_object_mut().ok_or_else(|| {
+            AppError::Internal(format!(
+                "runtime record '{}' for entity '{}' is not a JSON object",
+                record_id, entity_logical_name
+            ))
+        })?;
+        Self::strip_system_field_values(&schema, object);
+        for field in schema.fields() {
+            if field.calculation_expression().is_some() {
+                object.remove(field.logical_name().as_str());
+            }
+        }
+        for (field_logical_name, value) in patch_object {
+            object.insert(field_logical_name.clone(), value.clone());
+        }
+
+        self.write_runtime_record_update_unchecked(
+            actor,
+            entity_logical_name,
+            record_id,
+            data,
+            Some(existing_record.version()),
+            Some(patch),
+        )
+        .await
+    }
+}
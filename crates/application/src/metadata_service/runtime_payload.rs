@@ -8,7 +8,11 @@ impl MetadataService {
         schema: &PublishedEntitySchema,
         data: Value,
         existing_record_data: Option<&Value>,
+        actor_subject: &str,
     ) -> AppResult<Value> {
+        self.require_entity_accepts_record_writes(tenant_id, entity_logical_name)
+            .await?;
+
         let mut object = Self::normalize_record_payload_without_required(schema, data)?;
         Self::apply_calculated_field_values(schema, &mut object)?;
 
@@ -32,14 +36,78 @@ impl MetadataService {
             Self::enforce_locked_field_changes(schema, existing_record_data, &object, &effects)?;
         }
 
+        self.apply_record_slug(tenant_id, schema, &mut object, existing_record_data)
+            .await?;
+        self.apply_record_status(
+            tenant_id,
+            schema,
+            &mut object,
+            existing_record_data,
+            actor_subject,
+        )
+        .await?;
         Self::apply_calculated_field_values(schema, &mut object)?;
         Self::validate_record_values(schema, &object)?;
         Self::enforce_required_fields_with_business_rules(schema, &object, &effects)?;
 
-        if !effects.error_messages.is_empty() {
-            return Err(AppError::Validation(effects.error_messages.join(" ")));
+        if !effects.rejections.is_empty() {
+            return Err(self
+                .reject_record_payload_with_business_rules(
+                    tenant_id,
+                    entity_logical_name,
+                    existing_record_data.is_some(),
+                    actor_subject,
+                    &effects.rejections,
+                )
+                .await);
         }
 
+        Self::apply_system_field_values(schema, &mut object, actor_subject, existing_record_data);
+
         Ok(Value::Object(object))
     }
+
+    async fn reject_record_payload_with_business_rules(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        is_update: bool,
+        actor_subject: &str,
+        rejections: &[BusinessRuleRejection],
+    ) -> AppError {
+        let detail = serde_json::json!({
+            "operation": if is_update { "update" } else { "create" },
+            "rejections": rejections
+                .iter()
+                .map(|rejection| serde_json::json!({
+                    "rule_logical_name": rejection.rule_logical_name,
+                    "action_type": rejection.action_type,
+                    "message": rejection.message,
+                }))
+                .collect::<Vec<_>>(),
+        });
+
+        if let Err(error) = self
+            .audit_repository
+            .append_event(AuditEvent {
+                tenant_id,
+                subject: actor_subject.to_owned(),
+                action: AuditAction::RuntimeRecordBusinessRuleRejected,
+                resource_type: "entity_definition".to_owned(),
+                resource_id: entity_logical_name.to_owned(),
+                detail: Some(detail.to_string()),
+            })
+            .await
+        {
+            return error;
+        }
+
+        AppError::Validation(
+            rejections
+                .iter()
+                .map(|rejection| rejection.message.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
 }
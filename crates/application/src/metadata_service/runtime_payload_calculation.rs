@@ -85,7 +85,7 @@ impl MetadataService {
         )))
     }
 
-    fn parse_calculation_call(
+    pub(super) fn parse_calculation_call(
         expression: &str,
         function_name: &str,
     ) -> AppResult<Option<Vec<String>>> {
@@ -1,3 +1,5 @@
+use chrono::{SecondsFormat, Utc};
+
 use super::*;
 
 impl MetadataService {
@@ -29,7 +31,17 @@ impl MetadataService {
             })
             .collect();
 
+        let system_fields = schema.system_fields();
         for key in object.keys() {
+            if SystemField::parse(key.as_str())
+                .is_some_and(|system_field| system_fields.contains(&system_field))
+            {
+                return Err(AppError::Validation(format!(
+                    "system field '{}' is read-only",
+                    key
+                )));
+            }
+
             if !allowed_fields.contains(key.as_str()) {
                 return Err(AppError::Validation(format!(
                     "unknown field '{}' for entity '{}'",
@@ -68,6 +80,43 @@ impl MetadataService {
         Ok(object)
     }
 
+    /// Stamps system field values; creation and owner values carry over on update.
+    pub(super) fn apply_system_field_values(
+        schema: &PublishedEntitySchema,
+        object: &mut serde_json::Map<String, Value>,
+        actor_subject: &str,
+        existing_record_data: Option<&Value>,
+    ) {
+        let now = Value::String(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true));
+        let subject = Value::String(actor_subject.to_owned());
+
+        for system_field in schema.system_fields() {
+            let existing_value = existing_record_data
+                .and_then(|data| data.get(system_field.as_str()))
+                .filter(|value| !value.is_null())
+                .cloned();
+            let value = match system_field {
+                SystemField::CreatedBy | SystemField::Owner => {
+                    existing_value.unwrap_or_else(|| subject.clone())
+                }
+                SystemField::CreatedAt => existing_value.unwrap_or_else(|| now.clone()),
+                SystemField::ModifiedBy => subject.clone(),
+                SystemField::ModifiedAt => now.clone(),
+            };
+            object.insert(system_field.as_str().to_owned(), value);
+        }
+    }
+
+    /// Removes stored system field values before re-normalizing a full record.
+    pub(super) fn strip_system_field_values(
+        schema: &PublishedEntitySchema,
+        object: &mut serde_json::Map<String, Value>,
+    ) {
+        for system_field in schema.system_fields() {
+            object.remove(system_field.as_str());
+        }
+    }
+
     pub(super) fn validate_record_values(
         schema: &PublishedEntitySchema,
         object: &serde_json::Map<String, Value>,
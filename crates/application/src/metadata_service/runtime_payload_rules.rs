@@ -1,6 +1,12 @@
 use super::*;
 
 impl MetadataService {
+    /// Evaluates active entity-scoped rules against one normalized payload.
+    ///
+    /// Conditions always see the submitted payload, never values patched by
+    /// other rules. Rules run in logical-name order and actions in their
+    /// declared order, so the last matching `set_field_value` wins while the
+    /// first `set_default_value` to fill an empty field wins.
     pub(super) async fn evaluate_entity_business_rule_effects(
         &self,
         tenant_id: TenantId,
@@ -8,10 +14,36 @@ impl MetadataService {
         normalized_data: &Value,
     ) -> AppResult<EntityBusinessRuleEffects> {
         let rules = self
+            .sorted_entity_business_rules(tenant_id, entity_logical_name)
+            .await?;
+
+        Ok(Self::entity_business_rule_effects(&rules, normalized_data))
+    }
+
+    /// Loads an entity's business rules in evaluation order.
+    pub(super) async fn sorted_entity_business_rules(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<BusinessRuleDefinition>> {
+        let mut rules = self
             .repository
             .list_business_rules(tenant_id, entity_logical_name)
             .await?;
+        rules.sort_by(|left, right| {
+            left.logical_name()
+                .as_str()
+                .cmp(right.logical_name().as_str())
+        });
+
+        Ok(rules)
+    }
 
+    /// Evaluates entity-scoped rules, sorted by logical name, against record data.
+    pub(super) fn entity_business_rule_effects(
+        rules: &[BusinessRuleDefinition],
+        normalized_data: &Value,
+    ) -> EntityBusinessRuleEffects {
         let mut effects = EntityBusinessRuleEffects::default();
         let normalized_object = normalized_data.as_object();
 
@@ -20,7 +52,7 @@ impl MetadataService {
                 continue;
             }
 
-            if !Self::business_rule_matches(&rule, normalized_data) {
+            if !Self::business_rule_matches(rule, normalized_data) {
                 continue;
             }
 
@@ -62,9 +94,14 @@ impl MetadataService {
                             continue;
                         };
 
-                        let is_empty = Self::business_rule_default_target_is_empty(
-                            normalized_object.and_then(|object| object.get(target_field.as_str())),
-                        );
+                        let current_value = effects
+                            .value_patches
+                            .get(target_field.as_str())
+                            .or_else(|| {
+                                normalized_object
+                                    .and_then(|object| object.get(target_field.as_str()))
+                            });
+                        let is_empty = Self::business_rule_default_target_is_empty(current_value);
                         if is_empty {
                             effects
                                 .value_patches
@@ -97,18 +134,20 @@ impl MetadataService {
                                 .insert(target_field.as_str().to_owned(), false);
                         }
                     }
-                    BusinessRuleActionType::ShowError => {
+                    BusinessRuleActionType::ShowError | BusinessRuleActionType::PreventSave => {
                         if let Some(error_message) = action.error_message() {
-                            effects
-                                .error_messages
-                                .push(error_message.as_str().to_owned());
+                            effects.rejections.push(BusinessRuleRejection {
+                                rule_logical_name: rule.logical_name().as_str().to_owned(),
+                                action_type: action.action_type(),
+                                message: error_message.as_str().to_owned(),
+                            });
                         }
                     }
                 }
             }
         }
 
-        Ok(effects)
+        effects
     }
 
     fn business_rule_default_target_is_empty(value: Option<&Value>) -> bool {
@@ -15,6 +15,8 @@ impl MetadataService {
             ));
         }
 
+        self.resolve_subject_filter_tokens(actor, query).await?;
+
         let mut schema_cache = BTreeMap::new();
         schema_cache.insert(root_entity_logical_name.to_owned(), root_schema.clone());
         let alias_entities = self
@@ -60,7 +62,7 @@ impl MetadataService {
                 filter.field_logical_name.as_str(),
                 "filter",
             )?;
-            Self::validate_runtime_query_filter(field, filter)?;
+            Self::validate_runtime_query_filter(&field, filter)?;
         }
 
         if let Some(where_clause) = &query.where_clause {
@@ -72,8 +74,20 @@ impl MetadataService {
             )?;
         }
 
+        let many_to_many_aliases = Self::many_to_many_scope_aliases(&query.links);
         let mut seen_sort_fields = BTreeSet::new();
         for sort in &query.sort {
+            if let Some(alias) = sort
+                .scope_alias
+                .as_deref()
+                .filter(|alias| many_to_many_aliases.contains(*alias))
+            {
+                return Err(AppError::Validation(format!(
+                    "runtime query cannot sort by field '{}' of alias '{}' because it is reached through a many_to_many link",
+                    sort.field_logical_name, alias
+                )));
+            }
+
             let sort_scope_key = sort.scope_alias.clone().unwrap_or_default();
             if !seen_sort_fields.insert((sort_scope_key.clone(), sort.field_logical_name.clone())) {
                 return Err(AppError::Validation(format!(
@@ -95,9 +109,95 @@ impl MetadataService {
                 sort.field_logical_name.as_str(),
                 "sort",
             )?;
-            Self::validate_runtime_query_sort(field, sort)?;
+            Self::validate_runtime_query_sort(&field, sort)?;
         }
 
         Ok(())
     }
+
+    /// Replaces `@me` and `@my_team` filter values with the actor's subjects.
+    async fn resolve_subject_filter_tokens(
+        &self,
+        actor: &UserIdentity,
+        query: &mut RuntimeRecordQuery,
+    ) -> AppResult<()> {
+        let mut filters: Vec<&mut RuntimeRecordFilter> = query.filters.iter_mut().collect();
+        if let Some(where_clause) = query.where_clause.as_mut() {
+            collect_group_filters(where_clause, &mut filters);
+        }
+
+        let mentions_token = |value: &Value, token: SubjectFilterToken| match value {
+            Value::Array(values) => values
+                .iter()
+                .any(|value| SubjectFilterToken::parse(value) == Some(token)),
+            _ => SubjectFilterToken::parse(value) == Some(token),
+        };
+        let team_subjects = if filters
+            .iter()
+            .any(|filter| mentions_token(&filter.field_value, SubjectFilterToken::CurrentTeam))
+        {
+            self.authorization_service
+                .team_subjects(actor.tenant_id(), actor.subject())
+                .await?
+        } else {
+            Vec::new()
+        };
+        let resolve = |value: &Value| match SubjectFilterToken::parse(value) {
+            Some(SubjectFilterToken::CurrentSubject) => {
+                vec![Value::String(actor.subject().to_owned())]
+            }
+            Some(SubjectFilterToken::CurrentTeam) => team_subjects
+                .iter()
+                .map(|subject| Value::String(subject.clone()))
+                .collect(),
+            None => vec![value.clone()],
+        };
+
+        for filter in filters {
+            match (
+                &filter.field_value,
+                SubjectFilterToken::parse(&filter.field_value),
+            ) {
+                (Value::Array(values), _) => {
+                    filter.field_value = Value::Array(values.iter().flat_map(resolve).collect());
+                }
+                (_, Some(SubjectFilterToken::CurrentSubject)) => {
+                    filter.field_value = Value::String(actor.subject().to_owned());
+                }
+                (_, Some(SubjectFilterToken::CurrentTeam)) => {
+                    filter.operator = match filter.operator {
+                        RuntimeRecordOperator::Eq | RuntimeRecordOperator::In => {
+                            RuntimeRecordOperator::In
+                        }
+                        RuntimeRecordOperator::Neq | RuntimeRecordOperator::NotIn => {
+                            RuntimeRecordOperator::NotIn
+                        }
+                        _ => {
+                            return Err(AppError::Validation(format!(
+                                "filter token '{}' on field '{}' requires operator 'eq', 'neq', 'in' or 'not_in'",
+                                SubjectFilterToken::CurrentTeam.as_str(),
+                                filter.field_logical_name
+                            )));
+                        }
+                    };
+                    filter.field_value = Value::Array(resolve(&filter.field_value));
+                }
+                (_, None) => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn collect_group_filters<'a>(
+    group: &'a mut RuntimeRecordConditionGroup,
+    filters: &mut Vec<&'a mut RuntimeRecordFilter>,
+) {
+    for node in &mut group.nodes {
+        match node {
+            RuntimeRecordConditionNode::Filter(filter) => filters.push(filter),
+            RuntimeRecordConditionNode::Group(group) => collect_group_filters(group, filters),
+        }
+    }
 }
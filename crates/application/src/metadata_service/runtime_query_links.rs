@@ -68,12 +68,16 @@ impl MetadataService {
                 )));
             };
 
-            if relation_field.field_type() != FieldType::Relation {
-                return Err(AppError::Validation(format!(
-                    "link relation field '{}' on entity '{}' must be of type 'relation'",
-                    relation_field_name, parent_entity_logical_name
-                )));
-            }
+            let cardinality = match relation_field.field_type() {
+                FieldType::Relation => RuntimeRecordLinkCardinality::ManyToOne,
+                FieldType::ManyToMany => RuntimeRecordLinkCardinality::ManyToMany,
+                _ => {
+                    return Err(AppError::Validation(format!(
+                        "link relation field '{}' on entity '{}' must be of type 'relation' or 'many_to_many'",
+                        relation_field_name, parent_entity_logical_name
+                    )));
+                }
+            };
 
             let Some(target_entity) = relation_field.relation_target_entity() else {
                 return Err(AppError::Validation(format!(
@@ -98,12 +102,30 @@ impl MetadataService {
 
             link.target_entity_logical_name = target_entity.as_str().to_owned();
             link.relation_field_logical_name = relation_field_name.to_owned();
+            link.cardinality = cardinality;
             alias_entities.insert(link.alias.clone(), target_entity.as_str().to_owned());
         }
 
         Ok(alias_entities)
     }
 
+    /// Returns the link aliases that fan out through a many-to-many link,
+    /// directly or through one of their parent aliases.
+    pub(super) fn many_to_many_scope_aliases(links: &[RuntimeRecordLink]) -> BTreeSet<String> {
+        let mut aliases = BTreeSet::new();
+        for link in links {
+            let parent_fans_out = link
+                .parent_alias
+                .as_deref()
+                .is_some_and(|parent_alias| aliases.contains(parent_alias));
+            if parent_fans_out || link.cardinality == RuntimeRecordLinkCardinality::ManyToMany {
+                aliases.insert(link.alias.clone());
+            }
+        }
+
+        aliases
+    }
+
     pub(super) async fn load_runtime_query_schema(
         &self,
         tenant_id: TenantId,
@@ -121,14 +143,14 @@ impl MetadataService {
         Ok(schema)
     }
 
-    pub(super) fn resolve_query_field_definition<'a>(
+    pub(super) fn resolve_query_field_definition(
         root_entity_logical_name: &str,
         alias_entities: &BTreeMap<String, String>,
-        schema_cache: &'a BTreeMap<String, PublishedEntitySchema>,
+        schema_cache: &BTreeMap<String, PublishedEntitySchema>,
         scope_alias: Option<&str>,
         field_logical_name: &str,
         context: &str,
-    ) -> AppResult<&'a EntityFieldDefinition> {
+    ) -> AppResult<EntityFieldDefinition> {
         let scope_entity = match scope_alias {
             Some(alias) => alias_entities
                 .get(alias)
@@ -147,8 +169,8 @@ impl MetadataService {
         })?;
 
         let field = schema
-            .fields()
-            .iter()
+            .queryable_fields()?
+            .into_iter()
             .find(|field| field.logical_name().as_str() == field_logical_name)
             .ok_or_else(|| match scope_alias {
                 Some(alias) => AppError::Validation(format!(
@@ -103,7 +103,7 @@ impl MetadataService {
                         filter.field_logical_name.as_str(),
                         "filter",
                     )?;
-                    Self::validate_runtime_query_filter(field, filter)?;
+                    Self::validate_runtime_query_filter(&field, filter)?;
                 }
                 RuntimeRecordConditionNode::Group(nested_group) => {
                     Self::validate_runtime_query_group(
@@ -140,46 +140,39 @@ impl MetadataService {
             | RuntimeRecordOperator::Gte
             | RuntimeRecordOperator::Lt
             | RuntimeRecordOperator::Lte => {
-                if !matches!(
-                    field.field_type(),
-                    FieldType::Number | FieldType::Date | FieldType::DateTime
-                ) {
-                    return Err(AppError::Validation(format!(
-                        "operator '{}' is not supported for field '{}' with type '{}'",
-                        filter.operator.as_str(),
-                        filter.field_logical_name,
-                        field.field_type().as_str()
-                    )));
-                }
-
+                Self::require_ordered_field_type(field, filter)?;
                 field.validate_runtime_value(&filter.field_value)?;
             }
-            RuntimeRecordOperator::Contains => {
+            RuntimeRecordOperator::Contains | RuntimeRecordOperator::BeginsWith => {
                 if field.field_type() != FieldType::Text {
                     return Err(AppError::Validation(format!(
-                        "operator 'contains' requires text field type for '{}'",
+                        "operator '{}' requires text field type for '{}'",
+                        filter.operator.as_str(),
                         filter.field_logical_name
                     )));
                 }
 
                 if !filter.field_value.is_string() {
                     return Err(AppError::Validation(format!(
-                        "operator 'contains' requires string value for '{}'",
+                        "operator '{}' requires string value for '{}'",
+                        filter.operator.as_str(),
                         filter.field_logical_name
                     )));
                 }
             }
-            RuntimeRecordOperator::In => {
+            RuntimeRecordOperator::In | RuntimeRecordOperator::NotIn => {
                 let values = filter.field_value.as_array().ok_or_else(|| {
                     AppError::Validation(format!(
-                        "operator 'in' requires array value for '{}'",
+                        "operator '{}' requires array value for '{}'",
+                        filter.operator.as_str(),
                         filter.field_logical_name
                     ))
                 })?;
 
                 if values.is_empty() {
                     return Err(AppError::Validation(format!(
-                        "operator 'in' requires at least one value for '{}'",
+                        "operator '{}' requires at least one value for '{}'",
+                        filter.operator.as_str(),
                         filter.field_logical_name
                     )));
                 }
@@ -188,11 +181,87 @@ impl MetadataService {
                     field.validate_runtime_value(value)?;
                 }
             }
+            RuntimeRecordOperator::IsNull | RuntimeRecordOperator::IsNotNull => {
+                if !filter.field_value.is_null() {
+                    return Err(AppError::Validation(format!(
+                        "operator '{}' does not take a value for '{}'",
+                        filter.operator.as_str(),
+                        filter.field_logical_name
+                    )));
+                }
+            }
+            RuntimeRecordOperator::Between => {
+                Self::require_ordered_field_type(field, filter)?;
+                let bounds = filter
+                    .field_value
+                    .as_array()
+                    .filter(|bounds| bounds.len() == 2)
+                    .ok_or_else(|| {
+                        AppError::Validation(format!(
+                            "operator 'between' requires a [low, high] array value for '{}'",
+                            filter.field_logical_name
+                        ))
+                    })?;
+
+                for bound in bounds {
+                    field.validate_runtime_value(bound)?;
+                }
+            }
+            RuntimeRecordOperator::Today
+            | RuntimeRecordOperator::Yesterday
+            | RuntimeRecordOperator::ThisMonth
+            | RuntimeRecordOperator::LastNDays => {
+                if !matches!(field.field_type(), FieldType::Date | FieldType::DateTime) {
+                    return Err(AppError::Validation(format!(
+                        "operator '{}' requires date or datetime field type for '{}'",
+                        filter.operator.as_str(),
+                        filter.field_logical_name
+                    )));
+                }
+
+                if filter.operator == RuntimeRecordOperator::LastNDays {
+                    if filter
+                        .operator
+                        .relative_date_window(&filter.field_value, chrono::Utc::now().date_naive())
+                        .is_none()
+                    {
+                        return Err(AppError::Validation(format!(
+                            "operator 'last_n_days' requires a day count between 1 and 3650 for '{}'",
+                            filter.field_logical_name
+                        )));
+                    }
+                } else if !filter.field_value.is_null() {
+                    return Err(AppError::Validation(format!(
+                        "operator '{}' does not take a value for '{}'",
+                        filter.operator.as_str(),
+                        filter.field_logical_name
+                    )));
+                }
+            }
         }
 
         Ok(())
     }
 
+    fn require_ordered_field_type(
+        field: &EntityFieldDefinition,
+        filter: &RuntimeRecordFilter,
+    ) -> AppResult<()> {
+        if matches!(
+            field.field_type(),
+            FieldType::Number | FieldType::Date | FieldType::DateTime
+        ) {
+            return Ok(());
+        }
+
+        Err(AppError::Validation(format!(
+            "operator '{}' is not supported for field '{}' with type '{}'",
+            filter.operator.as_str(),
+            filter.field_logical_name,
+            field.field_type().as_str()
+        )))
+    }
+
     pub(super) fn validate_runtime_query_sort(
         field: &EntityFieldDefinition,
         sort: &RuntimeRecordSort,
@@ -0,0 +1,84 @@
+use super::*;
+
+impl MetadataService {
+    /// Creates a runtime record from a reduced payload bound to a published quick-create form.
+    ///
+    /// Only fields placed on the quick-create form are accepted. Defaults, calculated
+    /// fields, and business rules are applied exactly as for a full create.
+    pub async fn quick_create_runtime_record(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        form_logical_name: Option<&str>,
+        data: Value,
+    ) -> AppResult<RuntimeRecord> {
+        let payload = data.as_object().ok_or_else(|| {
+            AppError::Validation("quick-create payload must be a JSON object".to_owned())
+        })?;
+
+        let form = self
+            .published_quick_create_form(actor.tenant_id(), entity_logical_name, form_logical_name)
+            .await?;
+        let allowed_fields = form
+            .tabs()
+            .iter()
+            .flat_map(|tab| tab.sections())
+            .flat_map(|section| section.fields())
+            .map(|placement| placement.field_logical_name().as_str())
+            .collect::<HashSet<_>>();
+
+        if let Some(field) = payload
+            .keys()
+            .find(|field| !allowed_fields.contains(field.as_str()))
+        {
+            return Err(AppError::Validation(format!(
+                "field '{field}' is not on quick-create form '{}'",
+                form.logical_name().as_str()
+            )));
+        }
+
+        self.create_runtime_record(actor, entity_logical_name, data)
+            .await
+    }
+
+    async fn published_quick_create_form(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        form_logical_name: Option<&str>,
+    ) -> AppResult<FormDefinition> {
+        let forms = self
+            .repository
+            .list_latest_published_form_snapshots(tenant_id, entity_logical_name)
+            .await?;
+
+        let form = match form_logical_name {
+            Some(form_logical_name) => forms
+                .into_iter()
+                .find(|form| form.logical_name().as_str() == form_logical_name)
+                .ok_or_else(|| {
+                    AppError::NotFound(format!(
+                        "published form '{form_logical_name}' does not exist for entity '{entity_logical_name}'"
+                    ))
+                })?,
+            None => forms
+                .into_iter()
+                .find(|form| form.form_type() == FormType::QuickCreate)
+                .ok_or_else(|| {
+                    AppError::NotFound(format!(
+                        "entity '{entity_logical_name}' has no published quick_create form"
+                    ))
+                })?,
+        };
+
+        if form.form_type() != FormType::QuickCreate {
+            return Err(AppError::Validation(format!(
+                "form '{}' is a '{}' form, not a quick_create form",
+                form.logical_name().as_str(),
+                form.form_type().as_str()
+            )));
+        }
+
+        Ok(form)
+    }
+}
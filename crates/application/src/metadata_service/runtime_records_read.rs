@@ -8,24 +8,30 @@ impl MetadataService {
         entity_logical_name: &str,
         mut query: RecordListQuery,
     ) -> AppResult<Vec<RuntimeRecord>> {
-        let read_scope = self.runtime_read_scope_for_actor(actor).await?;
-        let field_access = self
-            .runtime_field_access_for_actor(actor, entity_logical_name)
-            .await?;
-
-        if read_scope == RuntimeAccessScope::Own {
-            query.owner_subject = Some(actor.subject().to_owned());
-        }
-
-        self.published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
-            .await?;
-
-        let records = self
-            .repository
-            .list_runtime_records(actor.tenant_id(), entity_logical_name, query)
-            .await?;
-
-        Self::redact_runtime_records_if_needed(records, field_access.as_ref())
+        self.within_operation_budget(TimedOperation::RuntimeQuery, async move {
+            let read_scope = self.runtime_read_scope_for_actor(actor).await?;
+            let field_access = self
+                .runtime_field_access_for_actor(actor, entity_logical_name)
+                .await?;
+
+            if read_scope == RuntimeAccessScope::Own {
+                query.owner_subject = Some(actor.subject().to_owned());
+            }
+
+            let schema = self
+                .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+                .await?;
+
+            let records = self
+                .list_runtime_records_for_schema(actor.tenant_id(), &schema, query)
+                .await?;
+            let records = self
+                .filter_runtime_records_by_compliance_zone(actor, entity_logical_name, records)
+                .await?;
+
+            Self::redact_runtime_records_if_needed(records, field_access.as_ref())
+        })
+        .await
     }
 
     /// Queries runtime records with exact-match field filters.
@@ -35,33 +41,41 @@ impl MetadataService {
         entity_logical_name: &str,
         mut query: RuntimeRecordQuery,
     ) -> AppResult<Vec<RuntimeRecord>> {
-        let read_scope = self.runtime_read_scope_for_actor(actor).await?;
-        let field_access = self
-            .runtime_field_access_for_actor(actor, entity_logical_name)
-            .await?;
-
-        if read_scope == RuntimeAccessScope::Own {
-            query.owner_subject = Some(actor.subject().to_owned());
-        }
-
-        let schema = self
-            .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
-            .await?;
-        self.validate_runtime_query(
-            actor,
-            entity_logical_name,
-            &schema,
-            &mut query,
-            field_access.as_ref(),
-        )
-        .await?;
-
-        let records = self
-            .repository
-            .query_runtime_records(actor.tenant_id(), entity_logical_name, query)
+        self.within_operation_budget(TimedOperation::RuntimeQuery, async move {
+            let read_scope = self.runtime_read_scope_for_actor(actor).await?;
+            let field_access = self
+                .runtime_field_access_for_actor(actor, entity_logical_name)
+                .await?;
+
+            if read_scope == RuntimeAccessScope::Own {
+                query.owner_subject = Some(actor.subject().to_owned());
+            }
+
+            let schema = self
+                .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+                .await?;
+            self.validate_runtime_query(
+                actor,
+                entity_logical_name,
+                &schema,
+                &mut query,
+                field_access.as_ref(),
+            )
             .await?;
+            self.exclude_inactive_records(actor.tenant_id(), &schema, &mut query)
+                .await?;
 
-        Self::redact_runtime_records_if_needed(records, field_access.as_ref())
+            let records = self
+                .repository
+                .query_runtime_records(actor.tenant_id(), entity_logical_name, query)
+                .await?;
+            let records = self
+                .filter_runtime_records_by_compliance_zone(actor, entity_logical_name, records)
+                .await?;
+
+            Self::redact_runtime_records_if_needed(records, field_access.as_ref())
+        })
+        .await
     }
 
     /// Lists runtime records without global permission checks.
@@ -71,27 +85,73 @@ impl MetadataService {
         entity_logical_name: &str,
         mut query: RecordListQuery,
     ) -> AppResult<Vec<RuntimeRecord>> {
-        let read_scope = self
-            .runtime_read_scope_for_actor_optional(actor)
-            .await?
-            .unwrap_or(RuntimeAccessScope::All);
-        let field_access = self
-            .runtime_field_access_for_actor(actor, entity_logical_name)
-            .await?;
-
-        if read_scope == RuntimeAccessScope::Own {
-            query.owner_subject = Some(actor.subject().to_owned());
-        }
-
-        self.published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
-            .await?;
-
-        let records = self
-            .repository
-            .list_runtime_records(actor.tenant_id(), entity_logical_name, query)
-            .await?;
+        self.within_operation_budget(TimedOperation::RuntimeQuery, async move {
+            let read_scope = self
+                .runtime_read_scope_for_actor_optional(actor)
+                .await?
+                .unwrap_or(RuntimeAccessScope::All);
+            let field_access = self
+                .runtime_field_access_for_actor(actor, entity_logical_name)
+                .await?;
+
+            if read_scope == RuntimeAccessScope::Own {
+                query.owner_subject = Some(actor.subject().to_owned());
+            }
+
+            let schema = self
+                .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+                .await?;
+
+            let records = self
+                .list_runtime_records_for_schema(actor.tenant_id(), &schema, query)
+                .await?;
+            let records = self
+                .filter_runtime_records_by_compliance_zone(actor, entity_logical_name, records)
+                .await?;
+
+            Self::redact_runtime_records_if_needed(records, field_access.as_ref())
+        })
+        .await
+    }
 
-        Self::redact_runtime_records_if_needed(records, field_access.as_ref())
+    /// Lists runtime records owned by the actor, including inactive ones.
+    ///
+    /// Ownership alone grants access, so no read permission is required;
+    /// compliance zones and field access still apply.
+    pub async fn list_owned_runtime_records(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        self.within_operation_budget(TimedOperation::RuntimeQuery, async move {
+            let field_access = self
+                .runtime_field_access_for_actor(actor, entity_logical_name)
+                .await?;
+            let schema = self
+                .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+                .await?;
+
+            let records = self
+                .list_runtime_records_for_schema(
+                    actor.tenant_id(),
+                    &schema,
+                    RecordListQuery {
+                        limit,
+                        offset,
+                        owner_subject: Some(actor.subject().to_owned()),
+                        include_inactive: true,
+                    },
+                )
+                .await?;
+            let records = self
+                .filter_runtime_records_by_compliance_zone(actor, entity_logical_name, records)
+                .await?;
+
+            Self::redact_runtime_records_if_needed(records, field_access.as_ref())
+        })
+        .await
     }
 
     /// Queries runtime records without global permission checks.
@@ -101,36 +161,44 @@ impl MetadataService {
         entity_logical_name: &str,
         mut query: RuntimeRecordQuery,
     ) -> AppResult<Vec<RuntimeRecord>> {
-        let read_scope = self
-            .runtime_read_scope_for_actor_optional(actor)
-            .await?
-            .unwrap_or(RuntimeAccessScope::All);
-        let field_access = self
-            .runtime_field_access_for_actor(actor, entity_logical_name)
-            .await?;
-
-        if read_scope == RuntimeAccessScope::Own {
-            query.owner_subject = Some(actor.subject().to_owned());
-        }
-
-        let schema = self
-            .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
-            .await?;
-        self.validate_runtime_query(
-            actor,
-            entity_logical_name,
-            &schema,
-            &mut query,
-            field_access.as_ref(),
-        )
-        .await?;
-
-        let records = self
-            .repository
-            .query_runtime_records(actor.tenant_id(), entity_logical_name, query)
+        self.within_operation_budget(TimedOperation::RuntimeQuery, async move {
+            let read_scope = self
+                .runtime_read_scope_for_actor_optional(actor)
+                .await?
+                .unwrap_or(RuntimeAccessScope::All);
+            let field_access = self
+                .runtime_field_access_for_actor(actor, entity_logical_name)
+                .await?;
+
+            if read_scope == RuntimeAccessScope::Own {
+                query.owner_subject = Some(actor.subject().to_owned());
+            }
+
+            let schema = self
+                .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+                .await?;
+            self.validate_runtime_query(
+                actor,
+                entity_logical_name,
+                &schema,
+                &mut query,
+                field_access.as_ref(),
+            )
             .await?;
+            self.exclude_inactive_records(actor.tenant_id(), &schema, &mut query)
+                .await?;
 
-        Self::redact_runtime_records_if_needed(records, field_access.as_ref())
+            let records = self
+                .repository
+                .query_runtime_records(actor.tenant_id(), entity_logical_name, query)
+                .await?;
+            let records = self
+                .filter_runtime_records_by_compliance_zone(actor, entity_logical_name, records)
+                .await?;
+
+            Self::redact_runtime_records_if_needed(records, field_access.as_ref())
+        })
+        .await
     }
 
     /// Gets a runtime record by identifier.
@@ -155,9 +223,12 @@ impl MetadataService {
                     actor.subject(),
                 )
                 .await?
+            && !self
+                .runtime_record_shared_with_actor(actor, entity_logical_name, record_id)
+                .await?
         {
             return Err(AppError::Forbidden(format!(
-                "subject '{}' can only read owned runtime records for entity '{}'",
+                "subject '{}' can only read owned or shared runtime records for entity '{}'",
                 actor.subject(),
                 entity_logical_name
             )));
@@ -165,6 +236,8 @@ impl MetadataService {
 
         self.published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
             .await?;
+        self.require_compliance_zone_access(actor, entity_logical_name, Some(record_id))
+            .await?;
 
         let record = self
             .repository
@@ -228,9 +301,12 @@ impl MetadataService {
                     actor.subject(),
                 )
                 .await?
+            && !self
+                .runtime_record_shared_with_actor(actor, entity_logical_name, record_id)
+                .await?
         {
             return Err(AppError::Forbidden(format!(
-                "subject '{}' can only read owned runtime records for entity '{}'",
+                "subject '{}' can only read owned or shared runtime records for entity '{}'",
                 actor.subject(),
                 entity_logical_name
             )));
@@ -238,6 +314,8 @@ impl MetadataService {
 
         self.published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
             .await?;
+        self.require_compliance_zone_access(actor, entity_logical_name, Some(record_id))
+            .await?;
 
         let record = self
             .repository
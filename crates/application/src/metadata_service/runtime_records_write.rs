@@ -1,6 +1,7 @@
 use super::*;
-use crate::RuntimeRecordWorkflowEventInput;
-use qryvanta_domain::WorkflowTrigger;
+use crate::{RelationCascadeResult, RuntimeRecordWorkflowEventInput};
+use qryvanta_domain::{WorkflowTrigger, validate_idempotency_key};
+use uuid::Uuid;
 
 impl MetadataService {
     /// Creates a runtime record using the latest published entity schema.
@@ -9,8 +10,45 @@ impl MetadataService {
         actor: &UserIdentity,
         entity_logical_name: &str,
         data: Value,
+    ) -> AppResult<RuntimeRecord> {
+        self.create_runtime_record_with_duplicate_override(actor, entity_logical_name, data, false)
+            .await
+    }
+
+    /// Creates a runtime record, skipping warning-level duplicate rules when
+    /// `ignore_duplicate_warnings` is set. Blocking rules always apply.
+    pub async fn create_runtime_record_with_duplicate_override(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        data: Value,
+        ignore_duplicate_warnings: bool,
+    ) -> AppResult<RuntimeRecord> {
+        self.create_runtime_record_with_idempotency_key(
+            actor,
+            entity_logical_name,
+            data,
+            ignore_duplicate_warnings,
+            None,
+        )
+        .await
+    }
+
+    /// Creates a runtime record at most once per entity and idempotency key.
+    ///
+    /// Replaying a key returns the record created by the first request
+    /// instead of writing a new one.
+    pub async fn create_runtime_record_with_idempotency_key(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        data: Value,
+        ignore_duplicate_warnings: bool,
+        idempotency_key: Option<&str>,
     ) -> AppResult<RuntimeRecord> {
         self.runtime_write_scope_for_actor(actor).await?;
+        self.require_compliance_zone_access(actor, entity_logical_name, None)
+            .await?;
 
         let field_access = self
             .runtime_field_access_for_actor(actor, entity_logical_name)
@@ -19,6 +57,29 @@ impl MetadataService {
             Self::enforce_writable_fields(&data, access)?;
         }
 
+        if let Some(idempotency_key) = idempotency_key {
+            validate_idempotency_key(idempotency_key)?;
+            if let Some(record) = self
+                .repository
+                .find_runtime_record_by_idempotency_key(
+                    actor.tenant_id(),
+                    entity_logical_name,
+                    idempotency_key,
+                )
+                .await?
+            {
+                return self
+                    .replayed_runtime_record(
+                        actor,
+                        entity_logical_name,
+                        idempotency_key,
+                        record,
+                        field_access.as_ref(),
+                    )
+                    .await;
+            }
+        }
+
         let schema = self
             .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
             .await?;
@@ -29,48 +90,168 @@ impl MetadataService {
                 &schema,
                 data,
                 None,
+                actor.subject(),
             )
             .await?;
         self.validate_relation_values(&schema, actor.tenant_id(), &normalized_data)
             .await?;
+        self.ensure_no_duplicate_records(
+            actor,
+            &schema,
+            &normalized_data,
+            None,
+            ignore_duplicate_warnings,
+        )
+        .await?;
         let unique_values = Self::unique_values_for_record(&schema, &normalized_data)?;
 
-        let record = self
-            .repository
-            .create_runtime_record(
-                actor.tenant_id(),
-                entity_logical_name,
-                normalized_data.clone(),
-                unique_values,
-                actor.subject(),
-                Self::runtime_record_workflow_event_input(
-                    actor,
-                    WorkflowTrigger::RuntimeRecordCreated {
-                        entity_logical_name: entity_logical_name.to_owned(),
-                    },
-                    record_payload_for_created(entity_logical_name, &normalized_data, None),
-                ),
-            )
-            .await?;
+        // The identifier is assigned up front so the audit event can commit
+        // with the record when the audit outbox is enabled.
+        let record_id = Uuid::new_v4().to_string();
+        let audit_event = AuditEvent {
+            tenant_id: actor.tenant_id(),
+            subject: actor.subject().to_owned(),
+            action: AuditAction::RuntimeRecordCreated,
+            resource_type: "runtime_record".to_owned(),
+            resource_id: record_id.clone(),
+            detail: Some(format!(
+                "created runtime record '{}' for entity '{}'",
+                record_id, entity_logical_name
+            )),
+        };
+        let workflow_event = Self::runtime_record_workflow_event_input(
+            actor,
+            WorkflowTrigger::RuntimeRecordCreated {
+                entity_logical_name: entity_logical_name.to_owned(),
+            },
+            record_payload_for_created(entity_logical_name, &normalized_data, None),
+        );
+        let outbox_audit_event = self.runtime_record_outbox_audit_event(&audit_event);
+        let record = match idempotency_key {
+            Some(idempotency_key) => {
+                let created = self
+                    .repository
+                    .create_runtime_record_with_idempotency_key(
+                        actor.tenant_id(),
+                        entity_logical_name,
+                        record_id.as_str(),
+                        idempotency_key,
+                        normalized_data.clone(),
+                        unique_values,
+                        actor.subject(),
+                        workflow_event,
+                        outbox_audit_event,
+                    )
+                    .await;
+                match created {
+                    Ok(record) => record,
+                    // A concurrent request with the same key won the insert.
+                    Err(AppError::Conflict(message)) => {
+                        let Some(record) = self
+                            .repository
+                            .find_runtime_record_by_idempotency_key(
+                                actor.tenant_id(),
+                                entity_logical_name,
+                                idempotency_key,
+                            )
+                            .await?
+                        else {
+                            return Err(AppError::Conflict(message));
+                        };
+                        return self
+                            .replayed_runtime_record(
+                                actor,
+                                entity_logical_name,
+                                idempotency_key,
+                                record,
+                                field_access.as_ref(),
+                            )
+                            .await;
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+            None => {
+                self.repository
+                    .create_runtime_record_with_id(
+                        actor.tenant_id(),
+                        entity_logical_name,
+                        record_id.as_str(),
+                        normalized_data.clone(),
+                        unique_values,
+                        actor.subject(),
+                        workflow_event,
+                        outbox_audit_event,
+                    )
+                    .await?
+            }
+        };
 
-        self.audit_repository
-            .append_event(AuditEvent {
-                tenant_id: actor.tenant_id(),
-                subject: actor.subject().to_owned(),
-                action: AuditAction::RuntimeRecordCreated,
-                resource_type: "runtime_record".to_owned(),
-                resource_id: record.record_id().as_str().to_owned(),
-                detail: Some(format!(
-                    "created runtime record '{}' for entity '{}'",
-                    record.record_id().as_str(),
-                    entity_logical_name
-                )),
-            })
-            .await?;
+        self.append_runtime_record_audit_event(audit_event).await?;
+        self.invalidate_runtime_view_results(actor.tenant_id(), entity_logical_name)
+            .await;
+        self.record_runtime_record_status_change(
+            actor,
+            entity_logical_name,
+            record.record_id().as_str(),
+            None,
+            record.data(),
+        )
+        .await?;
 
         Self::redact_runtime_record_if_needed(record, field_access.as_ref())
     }
 
+    /// Returns a record found under an idempotency key, provided the actor
+    /// could read it.
+    ///
+    /// Keys are scoped to the entity rather than the caller, so a key another
+    /// subject already used is reported as a conflict instead of leaking
+    /// their record.
+    async fn replayed_runtime_record(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        idempotency_key: &str,
+        record: RuntimeRecord,
+        field_access: Option<&crate::RuntimeFieldAccess>,
+    ) -> AppResult<RuntimeRecord> {
+        let record_id = record.record_id().as_str();
+        let readable = match self.runtime_read_scope_for_actor_optional(actor).await? {
+            Some(RuntimeAccessScope::All) => true,
+            read_scope => {
+                self.repository
+                    .runtime_record_owned_by_subject(
+                        actor.tenant_id(),
+                        entity_logical_name,
+                        record_id,
+                        actor.subject(),
+                    )
+                    .await?
+                    || (read_scope.is_some()
+                        && self
+                            .runtime_record_shared_with_actor(actor, entity_logical_name, record_id)
+                            .await?)
+            }
+        };
+        let readable = readable
+            && match self
+                .require_compliance_zone_access(actor, entity_logical_name, Some(record_id))
+                .await
+            {
+                Ok(()) => true,
+                Err(AppError::Forbidden(_)) => false,
+                Err(error) => return Err(error),
+            };
+        if !readable {
+            return Err(AppError::Conflict(format!(
+                "idempotency key '{idempotency_key}' was already used for another runtime record of entity '{entity_logical_name}'"
+            )));
+        }
+
+        Self::redact_runtime_record_if_needed(record, field_access)
+    }
+
     /// Creates a runtime record without global permission checks.
     pub async fn create_runtime_record_unchecked(
         &self,
@@ -79,6 +260,8 @@ impl MetadataService {
         data: Value,
     ) -> AppResult<RuntimeRecord> {
         self.runtime_write_scope_for_actor_optional(actor).await?;
+        self.require_compliance_zone_access(actor, entity_logical_name, None)
+            .await?;
 
         let field_access = self
             .runtime_field_access_for_actor(actor, entity_logical_name)
@@ -97,17 +280,35 @@ impl MetadataService {
                 &schema,
                 data,
                 None,
+                actor.subject(),
             )
             .await?;
         self.validate_relation_values(&schema, actor.tenant_id(), &normalized_data)
             .await?;
+        self.ensure_no_duplicate_records(actor, &schema, &normalized_data, None, false)
+            .await?;
         let unique_values = Self::unique_values_for_record(&schema, &normalized_data)?;
 
+        // The identifier is assigned up front so the audit event can commit
+        // with the record when the audit outbox is enabled.
+        let record_id = Uuid::new_v4().to_string();
+        let audit_event = AuditEvent {
+            tenant_id: actor.tenant_id(),
+            subject: actor.subject().to_owned(),
+            action: AuditAction::RuntimeRecordCreated,
+            resource_type: "runtime_record".to_owned(),
+            resource_id: record_id.clone(),
+            detail: Some(format!(
+                "created runtime record '{}' for entity '{}'",
+                record_id, entity_logical_name
+            )),
+        };
         let record = self
             .repository
-            .create_runtime_record(
+            .create_runtime_record_with_id(
                 actor.tenant_id(),
                 entity_logical_name,
+                record_id.as_str(),
                 normalized_data.clone(),
                 unique_values,
                 actor.subject(),
@@ -118,23 +319,21 @@ impl MetadataService {
                     },
                     record_payload_for_created(entity_logical_name, &normalized_data, None),
                 ),
+                self.runtime_record_outbox_audit_event(&audit_event),
             )
             .await?;
 
-        self.audit_repository
-            .append_event(AuditEvent {
-                tenant_id: actor.tenant_id(),
-                subject: actor.subject().to_owned(),
-                action: AuditAction::RuntimeRecordCreated,
-                resource_type: "runtime_record".to_owned(),
-                resource_id: record.record_id().as_str().to_owned(),
-                detail: Some(format!(
-                    "created runtime record '{}' for entity '{}'",
-                    record.record_id().as_str(),
-                    entity_logical_name
-                )),
-            })
-            .await?;
+        self.append_runtime_record_audit_event(audit_event).await?;
+        self.invalidate_runtime_view_results(actor.tenant_id(), entity_logical_name)
+            .await;
+        self.record_runtime_record_status_change(
+            actor,
+            entity_logical_name,
+            record.record_id().as_str(),
+            None,
+            record.data(),
+        )
+        .await?;
 
         Self::redact_runtime_record_if_needed(record, field_access.as_ref())
     }
@@ -146,6 +345,43 @@ impl MetadataService {
         entity_logical_name: &str,
         record_id: &str,
         data: Value,
+    ) -> AppResult<RuntimeRecord> {
+        self.update_runtime_record_with_version(actor, entity_logical_name, record_id, data, None)
+            .await
+    }
+
+    /// Updates a runtime record, rejecting the write with a conflict when
+    /// `expected_version` no longer matches the stored record version.
+    pub async fn update_runtime_record_with_version(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        data: Value,
+        expected_version: Option<i64>,
+    ) -> AppResult<RuntimeRecord> {
+        self.update_runtime_record_with_duplicate_override(
+            actor,
+            entity_logical_name,
+            record_id,
+            data,
+            expected_version,
+            false,
+        )
+        .await
+    }
+
+    /// Updates a runtime record like [`Self::update_runtime_record_with_version`],
+    /// skipping warning-level duplicate rules when `ignore_duplicate_warnings`
+    /// is set. Blocking rules always apply.
+    pub async fn update_runtime_record_with_duplicate_override(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        mut data: Value,
+        expected_version: Option<i64>,
+        ignore_duplicate_warnings: bool,
     ) -> AppResult<RuntimeRecord> {
         let write_scope = self.runtime_write_scope_for_actor(actor).await?;
 
@@ -167,6 +403,9 @@ impl MetadataService {
             )));
         }
 
+        self.require_compliance_zone_access(actor, entity_logical_name, Some(record_id))
+            .await?;
+
         let field_access = self
             .runtime_field_access_for_actor(actor, entity_logical_name)
             .await?;
@@ -187,6 +426,10 @@ impl MetadataService {
                     record_id, entity_logical_name
                 ))
             })?;
+        Self::ensure_runtime_record_version(&existing_record, expected_version)?;
+        if let Some(access) = &field_access {
+            Self::restore_unchanged_masked_values(&mut data, existing_record.data(), access);
+        }
         let normalized_data = self
             .normalize_record_payload_with_entity_business_rules(
                 actor.tenant_id(),
@@ -194,12 +437,41 @@ impl MetadataService {
                 &schema,
                 data,
                 Some(existing_record.data()),
+                actor.subject(),
+            )
+            .await?;
+        let (normalized_data, pending_field_changes) = self
+            .split_dual_control_field_changes(
+                actor,
+                entity_logical_name,
+                record_id,
+                existing_record.data(),
+                normalized_data,
             )
             .await?;
         self.validate_relation_values(&schema, actor.tenant_id(), &normalized_data)
             .await?;
+        self.ensure_no_duplicate_records(
+            actor,
+            &schema,
+            &normalized_data,
+            Some(record_id),
+            ignore_duplicate_warnings,
+        )
+        .await?;
         let unique_values = Self::unique_values_for_record(&schema, &normalized_data)?;
 
+        let audit_event = AuditEvent {
+            tenant_id: actor.tenant_id(),
+            subject: actor.subject().to_owned(),
+            action: AuditAction::RuntimeRecordUpdated,
+            resource_type: "runtime_record".to_owned(),
+            resource_id: record_id.to_owned(),
+            detail: Some(format!(
+                "updated runtime record '{}' for entity '{}'",
+                record_id, entity_logical_name
+            )),
+        };
         let record = self
             .repository
             .update_runtime_record(
@@ -208,6 +480,7 @@ impl MetadataService {
                 record_id,
                 normalized_data.clone(),
                 unique_values,
+                expected_version,
                 Self::runtime_record_workflow_event_input(
                     actor,
                     WorkflowTrigger::RuntimeRecordUpdated {
@@ -220,22 +493,30 @@ impl MetadataService {
                         &normalized_data,
                     ),
                 ),
+                self.runtime_record_outbox_audit_event(&audit_event),
             )
             .await?;
 
-        self.audit_repository
-            .append_event(AuditEvent {
-                tenant_id: actor.tenant_id(),
-                subject: actor.subject().to_owned(),
-                action: AuditAction::RuntimeRecordUpdated,
-                resource_type: "runtime_record".to_owned(),
-                resource_id: record.record_id().as_str().to_owned(),
-                detail: Some(format!(
-                    "updated runtime record '{}' for entity '{}'",
-                    record.record_id().as_str(),
-                    entity_logical_name
-                )),
-            })
+        self.append_runtime_record_audit_event(audit_event).await?;
+        self.invalidate_runtime_view_results(actor.tenant_id(), entity_logical_name)
+            .await;
+        self.record_runtime_record_status_change(
+            actor,
+            entity_logical_name,
+            record.record_id().as_str(),
+            Some(existing_record.data()),
+            record.data(),
+        )
+        .await?;
+        self.record_runtime_record_history(
+            actor,
+            entity_logical_name,
+            &schema,
+            existing_record.data(),
+            &record,
+        )
+        .await?;
+        self.record_pending_field_changes(actor, pending_field_changes)
             .await?;
 
         Self::redact_runtime_record_if_needed(record, field_access.as_ref())
@@ -248,6 +529,48 @@ impl MetadataService {
         entity_logical_name: &str,
         record_id: &str,
         data: Value,
+    ) -> AppResult<RuntimeRecord> {
+        self.update_runtime_record_unchecked_with_version(
+            actor,
+            entity_logical_name,
+            record_id,
+            data,
+            None,
+        )
+        .await
+    }
+
+    /// Updates a runtime record without global permission checks, rejecting
+    /// the write with a conflict when `expected_version` is stale.
+    pub async fn update_runtime_record_unchecked_with_version(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        data: Value,
+        expected_version: Option<i64>,
+    ) -> AppResult<RuntimeRecord> {
+        self.write_runtime_record_update_unchecked(
+            actor,
+            entity_logical_name,
+            record_id,
+            data,
+            expected_version,
+            None,
+        )
+        .await
+    }
+
+    /// Shared unchecked update path. Field permissions are enforced on
+    /// `written_fields` when given, otherwise on the whole payload.
+    pub(super) async fn write_runtime_record_update_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        mut data: Value,
+        expected_version: Option<i64>,
+        written_fields: Option<&Value>,
     ) -> AppResult<RuntimeRecord> {
         let write_scope = self
             .runtime_write_scope_for_actor_optional(actor)
@@ -272,11 +595,14 @@ impl MetadataService {
             )));
         }
 
+        self.require_compliance_zone_access(actor, entity_logical_name, Some(record_id))
+            .await?;
+
         let field_access = self
             .runtime_field_access_for_actor(actor, entity_logical_name)
             .await?;
         if let Some(access) = &field_access {
-            Self::enforce_writable_fields(&data, access)?;
+            Self::enforce_writable_fields(written_fields.unwrap_or(&data), access)?;
         }
 
         let schema = self
@@ -292,6 +618,10 @@ impl MetadataService {
                     record_id, entity_logical_name
                 ))
             })?;
+        Self::ensure_runtime_record_version(&existing_record, expected_version)?;
+        if let Some(access) = &field_access {
+            Self::restore_unchanged_masked_values(&mut data, existing_record.data(), access);
+        }
         let normalized_data = self
             .normalize_record_payload_with_entity_business_rules(
                 actor.tenant_id(),
@@ -299,12 +629,35 @@ impl MetadataService {
                 &schema,
                 data,
                 Some(existing_record.data()),
+                actor.subject(),
+            )
+            .await?;
+        let (normalized_data, pending_field_changes) = self
+            .split_dual_control_field_changes(
+                actor,
+                entity_logical_name,
+                record_id,
+                existing_record.data(),
+                normalized_data,
             )
             .await?;
         self.validate_relation_values(&schema, actor.tenant_id(), &normalized_data)
             .await?;
+        self.ensure_no_duplicate_records(actor, &schema, &normalized_data, Some(record_id), false)
+            .await?;
         let unique_values = Self::unique_values_for_record(&schema, &normalized_data)?;
 
+        let audit_event = AuditEvent {
+            tenant_id: actor.tenant_id(),
+            subject: actor.subject().to_owned(),
+            action: AuditAction::RuntimeRecordUpdated,
+            resource_type: "runtime_record".to_owned(),
+            resource_id: record_id.to_owned(),
+            detail: Some(format!(
+                "updated runtime record '{}' for entity '{}'",
+                record_id, entity_logical_name
+            )),
+        };
         let record = self
             .repository
             .update_runtime_record(
@@ -313,6 +666,7 @@ impl MetadataService {
                 record_id,
                 normalized_data.clone(),
                 unique_values,
+                expected_version,
                 Self::runtime_record_workflow_event_input(
                     actor,
                     WorkflowTrigger::RuntimeRecordUpdated {
@@ -325,28 +679,158 @@ impl MetadataService {
                         &normalized_data,
                     ),
                 ),
+                self.runtime_record_outbox_audit_event(&audit_event),
             )
             .await?;
 
-        self.audit_repository
-            .append_event(AuditEvent {
-                tenant_id: actor.tenant_id(),
-                subject: actor.subject().to_owned(),
-                action: AuditAction::RuntimeRecordUpdated,
-                resource_type: "runtime_record".to_owned(),
-                resource_id: record.record_id().as_str().to_owned(),
-                detail: Some(format!(
-                    "updated runtime record '{}' for entity '{}'",
-                    record.record_id().as_str(),
-                    entity_logical_name
-                )),
-            })
+        self.append_runtime_record_audit_event(audit_event).await?;
+        self.invalidate_runtime_view_results(actor.tenant_id(), entity_logical_name)
+            .await;
+        self.record_runtime_record_status_change(
+            actor,
+            entity_logical_name,
+            record.record_id().as_str(),
+            Some(existing_record.data()),
+            record.data(),
+        )
+        .await?;
+        self.record_runtime_record_history(
+            actor,
+            entity_logical_name,
+            &schema,
+            existing_record.data(),
+            &record,
+        )
+        .await?;
+        self.record_pending_field_changes(actor, pending_field_changes)
             .await?;
 
         Self::redact_runtime_record_if_needed(record, field_access.as_ref())
     }
 
-    /// Deletes a runtime record after enforcing relation-reference safeguards.
+    /// Transfers a runtime record to another owner subject.
+    ///
+    /// Requires `runtime.record.assign`; subjects limited to owned-record writes
+    /// can only hand over records they currently own. When an owner directory
+    /// is configured, the new owner must be a tenant member or team.
+    pub async fn assign_runtime_record_owner(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        owner_subject: &str,
+    ) -> AppResult<RuntimeRecordOwnerAssignment> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::RuntimeRecordAssign,
+            )
+            .await?;
+        let write_scope = self.runtime_write_scope_for_actor(actor).await?;
+
+        let owner_subject = owner_subject.trim();
+        if owner_subject.is_empty() {
+            return Err(AppError::Validation(
+                "runtime record owner subject must not be empty".to_owned(),
+            ));
+        }
+
+        if let Some(record_owner_directory) = &self.record_owner_directory
+            && !record_owner_directory
+                .is_assignable_owner(actor.tenant_id(), owner_subject)
+                .await?
+        {
+            return Err(AppError::Validation(format!(
+                "runtime record owner '{owner_subject}' is not a member or team of this tenant"
+            )));
+        }
+
+        self.published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+            .await?;
+
+        if write_scope == RuntimeAccessScope::Own
+            && !self
+                .repository
+                .runtime_record_owned_by_subject(
+                    actor.tenant_id(),
+                    entity_logical_name,
+                    record_id,
+                    actor.subject(),
+                )
+                .await?
+        {
+            return Err(AppError::Forbidden(format!(
+                "subject '{}' can only assign owned runtime records for entity '{}'",
+                actor.subject(),
+                entity_logical_name
+            )));
+        }
+
+        self.require_compliance_zone_access(actor, entity_logical_name, Some(record_id))
+            .await?;
+
+        let cascade_behaviors = self
+            .assign_cascades_for_parent(actor.tenant_id(), entity_logical_name)
+            .await?;
+        let (previous_owner_subject, cascades) = self
+            .repository
+            .assign_runtime_record_owner(
+                actor.tenant_id(),
+                entity_logical_name,
+                record_id,
+                owner_subject,
+                &cascade_behaviors,
+            )
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "runtime record '{}' does not exist for entity '{}'",
+                    record_id, entity_logical_name
+                ))
+            })?;
+
+        if previous_owner_subject != owner_subject {
+            self.audit_repository
+                .append_event(AuditEvent {
+                    tenant_id: actor.tenant_id(),
+                    subject: actor.subject().to_owned(),
+                    action: AuditAction::RuntimeRecordOwnerAssigned,
+                    resource_type: "runtime_record".to_owned(),
+                    resource_id: record_id.to_owned(),
+                    detail: Some(format!(
+                        "assigned runtime record '{}' for entity '{}' from '{}' to '{}'{}",
+                        record_id,
+                        entity_logical_name,
+                        previous_owner_subject,
+                        owner_subject,
+                        cascade_audit_suffix(&cascades)
+                    )),
+                })
+                .await?;
+        }
+
+        self.invalidate_runtime_view_results(actor.tenant_id(), entity_logical_name)
+            .await;
+        for cascade in &cascades {
+            self.invalidate_runtime_view_results(
+                actor.tenant_id(),
+                cascade.entity_logical_name.as_str(),
+            )
+            .await;
+        }
+
+        Ok(RuntimeRecordOwnerAssignment {
+            entity_logical_name: entity_logical_name.to_owned(),
+            record_id: record_id.to_owned(),
+            previous_owner_subject,
+            owner_subject: owner_subject.to_owned(),
+            cascades,
+        })
+    }
+
+    /// Deletes a runtime record after enforcing legal hold safeguards and the
+    /// delete behaviors of relation fields referencing it.
     pub async fn delete_runtime_record(
         &self,
         actor: &UserIdentity,
@@ -375,6 +859,8 @@ impl MetadataService {
 
         self.published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
             .await?;
+        self.require_compliance_zone_access(actor, entity_logical_name, Some(record_id))
+            .await?;
 
         let existing_record = self
             .repository
@@ -387,51 +873,8 @@ impl MetadataService {
             )));
         };
 
-        if self
-            .repository
-            .has_relation_reference(actor.tenant_id(), entity_logical_name, record_id)
-            .await?
-        {
-            return Err(AppError::Conflict(format!(
-                "runtime record '{}' in entity '{}' cannot be deleted because it is still referenced by relation fields",
-                record_id, entity_logical_name
-            )));
-        }
-
-        self.repository
-            .delete_runtime_record(
-                actor.tenant_id(),
-                entity_logical_name,
-                record_id,
-                Self::runtime_record_workflow_event_input(
-                    actor,
-                    WorkflowTrigger::RuntimeRecordDeleted {
-                        entity_logical_name: entity_logical_name.to_owned(),
-                    },
-                    record_payload_for_deleted(
-                        entity_logical_name,
-                        record_id,
-                        Some(existing_record.data()),
-                    ),
-                ),
-            )
-            .await?;
-
-        self.audit_repository
-            .append_event(AuditEvent {
-                tenant_id: actor.tenant_id(),
-                subject: actor.subject().to_owned(),
-                action: AuditAction::RuntimeRecordDeleted,
-                resource_type: "runtime_record".to_owned(),
-                resource_id: record_id.to_owned(),
-                detail: Some(format!(
-                    "deleted runtime record '{}' for entity '{}'",
-                    record_id, entity_logical_name
-                )),
-            })
-            .await?;
-
-        Ok(())
+        self.delete_runtime_record_with_relations(actor, &existing_record)
+            .await
     }
 
     /// Deletes a runtime record without global permission checks.
@@ -466,6 +909,8 @@ impl MetadataService {
 
         self.published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
             .await?;
+        self.require_compliance_zone_access(actor, entity_logical_name, Some(record_id))
+            .await?;
 
         let existing_record = self
             .repository
@@ -478,17 +923,36 @@ impl MetadataService {
             )));
         };
 
-        if self
-            .repository
-            .has_relation_reference(actor.tenant_id(), entity_logical_name, record_id)
-            .await?
-        {
-            return Err(AppError::Conflict(format!(
-                "runtime record '{}' in entity '{}' cannot be deleted because it is still referenced by relation fields",
-                record_id, entity_logical_name
-            )));
-        }
+        self.delete_runtime_record_with_relations(actor, &existing_record)
+            .await
+    }
+
+    /// Deletes a held-free record together with the children its relation
+    /// delete behaviors remove or relink, in one repository transaction.
+    async fn delete_runtime_record_with_relations(
+        &self,
+        actor: &UserIdentity,
+        record: &RuntimeRecord,
+    ) -> AppResult<()> {
+        let entity_logical_name = record.entity_logical_name().as_str();
+        let record_id = record.record_id().as_str();
+        self.require_runtime_record_not_held(actor.tenant_id(), entity_logical_name, record_id)
+            .await?;
 
+        let (relation_plan, cascades) = self.plan_relation_delete(actor, record).await?;
+        let audit_event = AuditEvent {
+            tenant_id: actor.tenant_id(),
+            subject: actor.subject().to_owned(),
+            action: AuditAction::RuntimeRecordDeleted,
+            resource_type: "runtime_record".to_owned(),
+            resource_id: record_id.to_owned(),
+            detail: Some(format!(
+                "deleted runtime record '{}' for entity '{}'{}",
+                record_id,
+                entity_logical_name,
+                cascade_audit_suffix(&cascades)
+            )),
+        };
         self.repository
             .delete_runtime_record(
                 actor.tenant_id(),
@@ -499,33 +963,46 @@ impl MetadataService {
                     WorkflowTrigger::RuntimeRecordDeleted {
                         entity_logical_name: entity_logical_name.to_owned(),
                     },
-                    record_payload_for_deleted(
-                        entity_logical_name,
-                        record_id,
-                        Some(existing_record.data()),
-                    ),
+                    record_payload_for_deleted(entity_logical_name, record_id, Some(record.data())),
                 ),
+                self.runtime_record_outbox_audit_event(&audit_event),
+                &relation_plan,
             )
             .await?;
 
-        self.audit_repository
-            .append_event(AuditEvent {
-                tenant_id: actor.tenant_id(),
-                subject: actor.subject().to_owned(),
-                action: AuditAction::RuntimeRecordDeleted,
-                resource_type: "runtime_record".to_owned(),
-                resource_id: record_id.to_owned(),
-                detail: Some(format!(
-                    "deleted runtime record '{}' for entity '{}'",
-                    record_id, entity_logical_name
-                )),
-            })
-            .await?;
+        self.invalidate_runtime_view_results(actor.tenant_id(), entity_logical_name)
+            .await;
+        for cascade in &cascades {
+            self.invalidate_runtime_view_results(
+                actor.tenant_id(),
+                cascade.entity_logical_name.as_str(),
+            )
+            .await;
+        }
 
-        Ok(())
+        self.append_runtime_record_audit_event(audit_event).await
     }
 
-    fn runtime_record_workflow_event_input(
+    /// Rejects a stale write before any business rules run; the repository
+    /// re-checks the version atomically when persisting.
+    fn ensure_runtime_record_version(
+        record: &RuntimeRecord,
+        expected_version: Option<i64>,
+    ) -> AppResult<()> {
+        match expected_version {
+            Some(expected_version) if expected_version != record.version() => {
+                Err(AppError::Conflict(format!(
+                    "runtime record '{}' was modified concurrently: expected version {} but current version is {}",
+                    record.record_id().as_str(),
+                    expected_version,
+                    record.version()
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn runtime_record_workflow_event_input(
         actor: &UserIdentity,
         trigger: WorkflowTrigger,
         payload: Value,
@@ -547,13 +1024,35 @@ impl MetadataService {
             emitted_by_subject: actor.subject().to_owned(),
         })
     }
+
+    /// Returns the audit event the repository should commit with a runtime
+    /// record write, which is only the case when the audit outbox is enabled.
+    pub(super) fn runtime_record_outbox_audit_event(
+        &self,
+        audit_event: &AuditEvent,
+    ) -> Option<AuditEvent> {
+        self.audit_outbox_enabled.then(|| audit_event.clone())
+    }
+
+    /// Appends a runtime record audit event unless the write already committed
+    /// it through the audit outbox.
+    pub(super) async fn append_runtime_record_audit_event(
+        &self,
+        audit_event: AuditEvent,
+    ) -> AppResult<()> {
+        if self.audit_outbox_enabled {
+            return Ok(());
+        }
+
+        self.audit_repository.append_event(audit_event).await
+    }
 }
 
-fn is_internal_workflow_subject(subject: &str) -> bool {
+pub(super) fn is_internal_workflow_subject(subject: &str) -> bool {
     subject == "workflow-runtime" || subject.starts_with("workflow-worker:")
 }
 
-fn record_payload_for_created(
+pub(super) fn record_payload_for_created(
     entity_logical_name: &str,
     record_data: &Value,
     record_id_override: Option<&str>,
@@ -589,7 +1088,7 @@ fn record_payload_for_created(
     payload
 }
 
-fn record_payload_for_updated(
+pub(super) fn record_payload_for_updated(
     entity_logical_name: &str,
     record_id: &str,
     previous_data: Option<&Value>,
@@ -606,7 +1105,7 @@ fn record_payload_for_updated(
     })
 }
 
-fn record_payload_for_deleted(
+pub(super) fn record_payload_for_deleted(
     entity_logical_name: &str,
     record_id: &str,
     deleted_data: Option<&Value>,
@@ -620,3 +1119,21 @@ fn record_payload_for_deleted(
         "data": deleted_data,
     })
 }
+
+fn cascade_audit_suffix(cascades: &[RelationCascadeResult]) -> String {
+    let cascaded = cascades
+        .iter()
+        .filter(|cascade| cascade.affected_records > 0)
+        .map(|cascade| {
+            format!(
+                "{}.{}={}",
+                cascade.entity_logical_name, cascade.field_logical_name, cascade.affected_records
+            )
+        })
+        .collect::<Vec<_>>();
+    if cascaded.is_empty() {
+        return String::new();
+    }
+
+    format!(" with cascades {}", cascaded.join(", "))
+}
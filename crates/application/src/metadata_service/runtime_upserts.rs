@@ -0,0 +1,192 @@
+use super::*;
+use crate::{RuntimeRecordUpsertOutcome, RuntimeRecordUpsertResult};
+
+impl MetadataService {
+    /// Creates or updates the runtime record identified by an alternate key.
+    ///
+    /// The key field must be a unique field of the published schema; its
+    /// value is taken from `key_value` and written into the payload, so the
+    /// payload may omit it but must not contradict it. Create and update go
+    /// through the regular write paths, including their permission checks.
+    pub async fn upsert_runtime_record_by_key(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        key_field_logical_name: &str,
+        key_value: &str,
+        data: Value,
+        ignore_duplicate_warnings: bool,
+    ) -> AppResult<RuntimeRecordUpsertResult> {
+        let schema = self
+            .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+            .await?;
+        let key_field = schema
+            .fields()
+            .iter()
+            .find(|field| field.logical_name().as_str() == key_field_logical_name)
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "field '{}.{}' does not exist in the published schema",
+                    entity_logical_name, key_field_logical_name
+                ))
+            })?;
+        if !key_field.is_unique() {
+            return Err(AppError::Validation(format!(
+                "field '{}.{}' is not an alternate key; only unique fields identify records",
+                entity_logical_name, key_field_logical_name
+            )));
+        }
+
+        let key = Self::alternate_key_value(entity_logical_name, key_field, key_value)?;
+        let mut data = data;
+        let object = data.as_object_mut().ok_or_else(|| {
+            AppError::Validation("runtime record payload must be a JSON object".to_owned())
+        })?;
+        if let Some(payload_key) = object.get(key_field_logical_name)
+            && payload_key != &key
+        {
+            return Err(AppError::Validation(format!(
+                "payload value of key field '{}.{}' does not match the key '{}'",
+                entity_logical_name, key_field_logical_name, key_value
+            )));
+        }
+        object.insert(key_field_logical_name.to_owned(), key.clone());
+        let key_hash = Self::hash_json_value(&key)?;
+
+        if let Some(record_id) = self
+            .repository
+            .find_runtime_record_id_by_unique_value(
+                actor.tenant_id(),
+                entity_logical_name,
+                key_field_logical_name,
+                key_hash.as_str(),
+            )
+            .await?
+        {
+            return self
+                .update_runtime_record_by_key(
+                    actor,
+                    entity_logical_name,
+                    record_id.as_str(),
+                    data,
+                    ignore_duplicate_warnings,
+                )
+                .await;
+        }
+
+        match self
+            .create_runtime_record_with_duplicate_override(
+                actor,
+                entity_logical_name,
+                data.clone(),
+                ignore_duplicate_warnings,
+            )
+            .await
+        {
+            Ok(record) => Ok(RuntimeRecordUpsertResult {
+                outcome: RuntimeRecordUpsertOutcome::Created,
+                record,
+            }),
+            // A concurrent upsert may have created the record between the
+            // lookup and the insert; the unique index rejects the second
+            // create, which then becomes an update.
+            Err(AppError::Conflict(message)) => {
+                let Some(record_id) = self
+                    .repository
+                    .find_runtime_record_id_by_unique_value(
+                        actor.tenant_id(),
+                        entity_logical_name,
+                        key_field_logical_name,
+                        key_hash.as_str(),
+                    )
+                    .await?
+                else {
+                    return Err(AppError::Conflict(message));
+                };
+
+                self.update_runtime_record_by_key(
+                    actor,
+                    entity_logical_name,
+                    record_id.as_str(),
+                    data,
+                    ignore_duplicate_warnings,
+                )
+                .await
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn update_runtime_record_by_key(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        data: Value,
+        ignore_duplicate_warnings: bool,
+    ) -> AppResult<RuntimeRecordUpsertResult> {
+        let record = self
+            .update_runtime_record_with_duplicate_override(
+                actor,
+                entity_logical_name,
+                record_id,
+                data,
+                None,
+                ignore_duplicate_warnings,
+            )
+            .await?;
+
+        Ok(RuntimeRecordUpsertResult {
+            outcome: RuntimeRecordUpsertOutcome::Updated,
+            record,
+        })
+    }
+
+    /// Converts a key taken from a URL path segment into the field's JSON value.
+    fn alternate_key_value(
+        entity_logical_name: &str,
+        field: &EntityFieldDefinition,
+        raw_value: &str,
+    ) -> AppResult<Value> {
+        let value = match field.field_type() {
+            FieldType::Text | FieldType::Date | FieldType::DateTime | FieldType::Relation => {
+                Value::String(raw_value.to_owned())
+            }
+            FieldType::Number | FieldType::Choice => {
+                serde_json::from_str::<serde_json::Number>(raw_value)
+                    .map(Value::Number)
+                    .map_err(|_| {
+                        AppError::Validation(format!(
+                            "key '{}' is not a number for field '{}.{}'",
+                            raw_value,
+                            entity_logical_name,
+                            field.logical_name().as_str()
+                        ))
+                    })?
+            }
+            FieldType::Boolean => match raw_value {
+                "true" => Value::Bool(true),
+                "false" => Value::Bool(false),
+                _ => {
+                    return Err(AppError::Validation(format!(
+                        "key '{}' is not a boolean for field '{}.{}'",
+                        raw_value,
+                        entity_logical_name,
+                        field.logical_name().as_str()
+                    )));
+                }
+            },
+            FieldType::Json | FieldType::MultiChoice | FieldType::ManyToMany => {
+                return Err(AppError::Validation(format!(
+                    "field '{}.{}' of type '{}' cannot be used as an alternate key",
+                    entity_logical_name,
+                    field.logical_name().as_str(),
+                    field.field_type().as_str()
+                )));
+            }
+        };
+        field.validate_runtime_value(&value)?;
+
+        Ok(value)
+    }
+}
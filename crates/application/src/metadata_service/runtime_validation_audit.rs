@@ -0,0 +1,222 @@
+use super::*;
+
+/// Kind of check a stored runtime record failed during a validation audit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeRecordValidationRule {
+    /// The record stores a key the published schema no longer defines.
+    UnknownField,
+    /// A stored value no longer satisfies its field type or option set.
+    InvalidValue,
+    /// A field that is now required (directly or by business rule) is absent.
+    MissingRequiredField,
+    /// An active entity business rule would reject the record on save.
+    BusinessRule,
+}
+
+impl RuntimeRecordValidationRule {
+    /// Returns the stable storage value.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::UnknownField => "unknown_field",
+            Self::InvalidValue => "invalid_value",
+            Self::MissingRequiredField => "missing_required_field",
+            Self::BusinessRule => "business_rule",
+        }
+    }
+
+    /// Parses a stored rule value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "unknown_field" => Ok(Self::UnknownField),
+            "invalid_value" => Ok(Self::InvalidValue),
+            "missing_required_field" => Ok(Self::MissingRequiredField),
+            "business_rule" => Ok(Self::BusinessRule),
+            _ => Err(AppError::Validation(format!(
+                "unknown runtime record validation rule '{value}'"
+            ))),
+        }
+    }
+}
+
+/// One check a stored runtime record failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeRecordViolation {
+    /// Record identifier.
+    pub record_id: String,
+    /// Failed check.
+    pub rule: RuntimeRecordValidationRule,
+    /// Field the check applies to, when field-scoped.
+    pub field_logical_name: Option<String>,
+    /// Business rule that rejected the record, for business rule violations.
+    pub business_rule_logical_name: Option<String>,
+    /// Human-readable failure message.
+    pub message: String,
+}
+
+/// Result of validating one page of stored runtime records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeRecordValidationPage {
+    /// Number of records read for this page.
+    pub records_checked: usize,
+    /// Violations found in this page, in record order.
+    pub violations: Vec<RuntimeRecordViolation>,
+}
+
+impl MetadataService {
+    /// Re-validates one page of stored records against the current published
+    /// schema and active entity business rules.
+    ///
+    /// Unlike the save path this collects every failure instead of stopping at
+    /// the first, and never rewrites the stored data.
+    pub async fn validate_runtime_records_page(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<RuntimeRecordValidationPage> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await?;
+
+        let schema = self
+            .published_schema_for_runtime(actor.tenant_id(), entity_logical_name)
+            .await?;
+        let rules = self
+            .sorted_entity_business_rules(actor.tenant_id(), entity_logical_name)
+            .await?;
+        let records = self
+            .list_runtime_records_for_schema(
+                actor.tenant_id(),
+                &schema,
+                RecordListQuery {
+                    limit,
+                    offset,
+                    owner_subject: None,
+                    include_inactive: true,
+                },
+            )
+            .await?;
+
+        let mut violations = Vec::new();
+        for record in &records {
+            violations.extend(Self::runtime_record_violations(&schema, &rules, record));
+        }
+
+        Ok(RuntimeRecordValidationPage {
+            records_checked: records.len(),
+            violations,
+        })
+    }
+
+    fn runtime_record_violations(
+        schema: &PublishedEntitySchema,
+        rules: &[BusinessRuleDefinition],
+        record: &RuntimeRecord,
+    ) -> Vec<RuntimeRecordViolation> {
+        let record_id = record.record_id().as_str();
+        let violation = |rule, field: Option<&str>, message: String| RuntimeRecordViolation {
+            record_id: record_id.to_owned(),
+            rule,
+            field_logical_name: field.map(str::to_owned),
+            business_rule_logical_name: None,
+            message,
+        };
+
+        let Some(object) = record.data().as_object() else {
+            return vec![violation(
+                RuntimeRecordValidationRule::InvalidValue,
+                None,
+                "stored record data is not a JSON object".to_owned(),
+            )];
+        };
+
+        let mut violations = Vec::new();
+        let system_fields = schema.system_fields();
+        for key in object.keys() {
+            let is_system_field = SystemField::parse(key.as_str())
+                .is_some_and(|system_field| system_fields.contains(&system_field));
+            let is_schema_field = schema
+                .fields()
+                .iter()
+                .any(|field| field.logical_name().as_str() == key);
+            if !is_system_field && !is_schema_field {
+                violations.push(violation(
+                    RuntimeRecordValidationRule::UnknownField,
+                    Some(key),
+                    format!(
+                        "unknown field '{}' for entity '{}'",
+                        key,
+                        schema.entity().logical_name().as_str()
+                    ),
+                ));
+            }
+        }
+
+        for field in schema.fields() {
+            let field_name = field.logical_name().as_str();
+            let Some(value) = object.get(field_name) else {
+                continue;
+            };
+            let result = field.validate_runtime_value(value).and_then(|()| {
+                Self::validate_choice_value_against_option_set(schema, field, value)
+            });
+            if let Err(error) = result {
+                violations.push(violation(
+                    RuntimeRecordValidationRule::InvalidValue,
+                    Some(field_name),
+                    Self::violation_message(error),
+                ));
+            }
+        }
+
+        let effects = Self::entity_business_rule_effects(rules, record.data());
+        for field in schema.fields() {
+            if field.calculation_expression().is_some() {
+                continue;
+            }
+
+            let field_name = field.logical_name().as_str();
+            let is_required = effects
+                .required_overrides
+                .get(field_name)
+                .copied()
+                .unwrap_or_else(|| field.is_required());
+            if is_required
+                && !effects.is_field_hidden(field_name)
+                && !object.contains_key(field_name)
+            {
+                violations.push(violation(
+                    RuntimeRecordValidationRule::MissingRequiredField,
+                    Some(field_name),
+                    format!("missing required field '{}'", field_name),
+                ));
+            }
+        }
+
+        for rejection in effects.rejections {
+            violations.push(RuntimeRecordViolation {
+                business_rule_logical_name: Some(rejection.rule_logical_name),
+                ..violation(
+                    RuntimeRecordValidationRule::BusinessRule,
+                    None,
+                    rejection.message,
+                )
+            });
+        }
+
+        violations
+    }
+
+    fn violation_message(error: AppError) -> String {
+        match error {
+            AppError::Validation(message) => message,
+            other => other.to_string(),
+        }
+    }
+}
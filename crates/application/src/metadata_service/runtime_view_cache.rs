@@ -0,0 +1,116 @@
+use super::*;
+
+impl MetadataService {
+    /// Queries runtime records for a view, serving repeated executions from
+    /// the view result cache until a record write to the entity or the ttl
+    /// invalidates them.
+    ///
+    /// Results are cached per subject because read scope, record shares,
+    /// compliance zones, and field access all shape what one caller sees.
+    pub async fn query_runtime_view_records(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+        query: RuntimeRecordQuery,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        if self.view_result_cache_enabled() {
+            // Cache hits still require runtime read access so revoked subjects
+            // are not served results computed before the revocation.
+            self.runtime_read_scope_for_actor(actor).await?;
+        }
+
+        self.cached_runtime_view_records(
+            actor,
+            entity_logical_name,
+            view_logical_name,
+            &query,
+            self.query_runtime_records(actor, entity_logical_name, query.clone()),
+        )
+        .await
+    }
+
+    /// Queries runtime records for a view without global permission checks,
+    /// sharing the view result cache with [`Self::query_runtime_view_records`].
+    pub async fn query_runtime_view_records_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+        query: RuntimeRecordQuery,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        self.cached_runtime_view_records(
+            actor,
+            entity_logical_name,
+            view_logical_name,
+            &query,
+            self.query_runtime_records_unchecked(actor, entity_logical_name, query.clone()),
+        )
+        .await
+    }
+
+    /// Drops cached view results for an entity after its records change.
+    pub(super) async fn invalidate_runtime_view_results(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) {
+        if let Some(cache) = &self.view_result_cache {
+            // Invalidation is best-effort: the record write has already been
+            // committed and cached results still expire by ttl.
+            let _ = cache
+                .invalidate_entity(tenant_id, entity_logical_name)
+                .await;
+        }
+    }
+
+    fn view_result_cache_enabled(&self) -> bool {
+        self.view_result_cache.is_some() && self.view_result_cache_ttl_seconds > 0
+    }
+
+    async fn cached_runtime_view_records(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+        query: &RuntimeRecordQuery,
+        execute: impl Future<Output = AppResult<Vec<RuntimeRecord>>>,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        let Some(cache) = self
+            .view_result_cache
+            .as_ref()
+            .filter(|_| self.view_result_cache_ttl_seconds > 0)
+        else {
+            return execute.await;
+        };
+
+        let key = RuntimeViewCacheKey {
+            tenant_id: actor.tenant_id(),
+            entity_logical_name: entity_logical_name.to_owned(),
+            view_logical_name: view_logical_name.to_owned(),
+            query_fingerprint: Self::runtime_view_query_fingerprint(query),
+            subject: actor.subject().to_owned(),
+        };
+        let lookup = cache.get_view_result(&key).await?;
+        if let Some(records) = lookup.records {
+            return Ok(records);
+        }
+
+        let records = execute.await?;
+        cache
+            .set_view_result(
+                key,
+                lookup.generation,
+                records.clone(),
+                self.view_result_cache_ttl_seconds,
+            )
+            .await?;
+
+        Ok(records)
+    }
+
+    fn runtime_view_query_fingerprint(query: &RuntimeRecordQuery) -> String {
+        let digest = Sha256::digest(format!("{query:?}"));
+        digest.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use super::*;
 
 impl MetadataService {
@@ -50,6 +52,19 @@ impl MetadataService {
         schema: &PublishedEntitySchema,
         tenant_id: TenantId,
         data: &Value,
+    ) -> AppResult<()> {
+        self.validate_relation_values_with_pending(schema, tenant_id, data, &HashMap::new())
+            .await
+    }
+
+    /// Validates relation values, treating records staged earlier in the same
+    /// changeset (keyed by record id) as existing.
+    pub(super) async fn validate_relation_values_with_pending(
+        &self,
+        schema: &PublishedEntitySchema,
+        tenant_id: TenantId,
+        data: &Value,
+        pending_records: &HashMap<String, (String, Value)>,
     ) -> AppResult<()> {
         let object = data.as_object().ok_or_else(|| {
             AppError::Validation("runtime record payload must be a JSON object".to_owned())
@@ -70,10 +85,14 @@ impl MetadataService {
                 continue;
             };
 
-            let exists = self
-                .repository
-                .runtime_record_exists(tenant_id, relation_target.as_str(), record_id)
-                .await?;
+            let pending = pending_records
+                .get(record_id)
+                .is_some_and(|(entity, _)| entity == relation_target.as_str());
+            let exists = pending
+                || self
+                    .repository
+                    .runtime_record_exists(tenant_id, relation_target.as_str(), record_id)
+                    .await?;
 
             if !exists {
                 return Err(AppError::Validation(format!(
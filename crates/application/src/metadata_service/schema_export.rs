@@ -0,0 +1,363 @@
+use super::*;
+use serde_json::{Map, json};
+
+/// JSON Schema dialect used for published schema exports.
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+const TYPESCRIPT_PREAMBLE: &[&str] = &[
+    "// Generated from published Qryvanta entity schemas. Do not edit by hand.",
+    "",
+    "/** Runtime record id, branded with the entity it belongs to. */",
+    "export type RecordId<TEntity extends string = string> = string & {",
+    "  readonly __entity?: TEntity;",
+    "};",
+    "",
+];
+
+const TYPESCRIPT_EPILOGUE: &[&str] = &[
+    "",
+    "export type EntityLogicalName = keyof EntityRecordMap;",
+    "",
+    "/** Runtime record envelope with typed data. */",
+    "export interface TypedRuntimeRecord<TEntity extends EntityLogicalName> {",
+    "  record_id: RecordId<TEntity>;",
+    "  entity_logical_name: TEntity;",
+    "  data: EntityRecordMap[TEntity];",
+    "}",
+    "",
+];
+
+impl MetadataService {
+    /// Renders the latest published schema of an entity as JSON Schema.
+    ///
+    /// The document describes one runtime record payload: field types,
+    /// required fields, option set enums and relation targets.
+    pub async fn published_entity_json_schema(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Value> {
+        let schema = self
+            .latest_published_schema(actor, entity_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "entity '{}' does not have a published schema",
+                    entity_logical_name
+                ))
+            })?;
+
+        Ok(render_json_schema(&schema))
+    }
+
+    /// Generates TypeScript declarations for every published entity of a tenant.
+    ///
+    /// Each entity gets a record interface, option sets become literal unions
+    /// with label maps, and relation fields are branded with their target.
+    pub async fn published_entities_typescript(&self, actor: &UserIdentity) -> AppResult<String> {
+        let entities = self.list_entities(actor).await?;
+
+        let mut schemas = Vec::new();
+        for entity in entities {
+            if let Some(schema) = self
+                .repository
+                .latest_published_schema(actor.tenant_id(), entity.logical_name().as_str())
+                .await?
+            {
+                schemas.push(schema);
+            }
+        }
+        schemas.sort_by(|left, right| {
+            left.entity()
+                .logical_name()
+                .as_str()
+                .cmp(right.entity().logical_name().as_str())
+        });
+
+        Ok(render_typescript(&schemas))
+    }
+}
+
+fn render_json_schema(schema: &PublishedEntitySchema) -> Value {
+    let entity = schema.entity();
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+
+    for field in record_data_fields(schema) {
+        let logical_name = field.logical_name().as_str();
+        properties.insert(
+            logical_name.to_owned(),
+            field_json_schema(field, schema.option_sets()),
+        );
+        if field.is_required() && field.calculation_expression().is_none() {
+            required.push(Value::String(logical_name.to_owned()));
+        }
+    }
+    for system_field in schema.system_fields() {
+        let mut property = match system_field.field_type() {
+            FieldType::DateTime => json!({ "type": "string", "format": "date-time" }),
+            _ => json!({ "type": "string" }),
+        };
+        property["title"] = Value::String(system_field.display_name().to_owned());
+        property["readOnly"] = Value::Bool(true);
+        properties.insert(system_field.as_str().to_owned(), property);
+    }
+
+    let mut document = json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "$id": format!(
+            "urn:qryvanta:entity:{}:v{}",
+            entity.logical_name().as_str(),
+            schema.version()
+        ),
+        "title": entity.display_name().as_str(),
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+        "x-qryvanta-entity": entity.logical_name().as_str(),
+        "x-qryvanta-version": schema.version(),
+    });
+    if let Some(description) = entity.description() {
+        document["description"] = Value::String(description.to_owned());
+    }
+
+    document
+}
+
+fn field_json_schema(field: &EntityFieldDefinition, option_sets: &[OptionSetDefinition]) -> Value {
+    let mut property = match field.field_type() {
+        FieldType::Text => {
+            let mut property = json!({ "type": "string" });
+            if let Some(max_length) = field.max_length() {
+                property["maxLength"] = json!(max_length);
+            }
+            property
+        }
+        FieldType::Number => {
+            let mut property = json!({ "type": "number" });
+            if let Some(minimum) = field.min_value() {
+                property["minimum"] = json!(minimum);
+            }
+            if let Some(maximum) = field.max_value() {
+                property["maximum"] = json!(maximum);
+            }
+            property
+        }
+        FieldType::Boolean => json!({ "type": "boolean" }),
+        FieldType::Date => json!({ "type": "string", "format": "date" }),
+        FieldType::DateTime => json!({ "type": "string", "format": "date-time" }),
+        FieldType::Json => json!({}),
+        FieldType::Choice => option_enum_schema(field, option_sets),
+        FieldType::MultiChoice => json!({
+            "type": "array",
+            "items": option_enum_schema(field, option_sets),
+            "uniqueItems": true,
+        }),
+        FieldType::Relation => {
+            let mut property = json!({ "type": "string", "format": "uuid" });
+            if let Some(target) = field.relation_target_entity() {
+                property["x-qryvanta-relation"] = Value::String(target.as_str().to_owned());
+            }
+            property
+        }
+        FieldType::ManyToMany => {
+            let mut property = json!({
+                "type": "array",
+                "items": { "type": "string", "format": "uuid" },
+                "uniqueItems": true,
+            });
+            if let Some(target) = field.relation_target_entity() {
+                property["x-qryvanta-relation"] = Value::String(target.as_str().to_owned());
+            }
+            property
+        }
+    };
+
+    property["title"] = Value::String(field.display_name().as_str().to_owned());
+    if let Some(description) = field.description() {
+        property["description"] = Value::String(description.to_owned());
+    }
+    if let Some(default_value) = field.default_value() {
+        property["default"] = default_value.clone();
+    }
+    if field.calculation_expression().is_some() {
+        property["readOnly"] = Value::Bool(true);
+    }
+    if let Some(classification) = field.classification() {
+        property["x-qryvanta-classification"] = Value::String(classification.as_str().to_owned());
+    }
+
+    property
+}
+
+fn option_enum_schema(field: &EntityFieldDefinition, option_sets: &[OptionSetDefinition]) -> Value {
+    let mut property = json!({ "type": "integer" });
+    let Some(option_set) = field.option_set_logical_name().and_then(|logical_name| {
+        option_sets
+            .iter()
+            .find(|option_set| option_set.logical_name().as_str() == logical_name.as_str())
+    }) else {
+        return property;
+    };
+
+    property["enum"] = Value::Array(
+        option_set
+            .options()
+            .iter()
+            .map(|option| json!(option.value()))
+            .collect(),
+    );
+    property["x-qryvanta-option-set"] =
+        Value::String(option_set.logical_name().as_str().to_owned());
+    property["x-qryvanta-option-labels"] = Value::Object(
+        option_set
+            .options()
+            .iter()
+            .map(|option| {
+                (
+                    option.value().to_string(),
+                    Value::String(option.label().as_str().to_owned()),
+                )
+            })
+            .collect(),
+    );
+
+    property
+}
+
+fn render_typescript(schemas: &[PublishedEntitySchema]) -> String {
+    let mut output = TYPESCRIPT_PREAMBLE.join("\n");
+
+    for schema in schemas {
+        let entity = schema.entity();
+        let type_prefix = pascal_case(entity.logical_name().as_str());
+
+        for option_set in schema.option_sets() {
+            let type_name = format!(
+                "{type_prefix}{}",
+                pascal_case(option_set.logical_name().as_str())
+            );
+            let values = option_set
+                .options()
+                .iter()
+                .map(|option| option.value().to_string())
+                .collect::<Vec<_>>();
+            output.push_str(&format!(
+                "\n/** {} option set of {}. */\nexport type {type_name} = {};\n",
+                doc_text(option_set.display_name().as_str()),
+                doc_text(entity.display_name().as_str()),
+                if values.is_empty() {
+                    "never".to_owned()
+                } else {
+                    values.join(" | ")
+                }
+            ));
+            output.push_str(&format!(
+                "export const {type_name}Labels: Record<{type_name}, string> = {{\n"
+            ));
+            for option in option_set.options() {
+                output.push_str(&format!(
+                    "  {}: {},\n",
+                    option.value(),
+                    typescript_string(option.label().as_str())
+                ));
+            }
+            output.push_str("};\n");
+        }
+
+        output.push_str(&format!(
+            "\n/** {} record data (published version {}). */\nexport interface {type_prefix}Record {{\n",
+            doc_text(entity.display_name().as_str()),
+            schema.version()
+        ));
+        for field in record_data_fields(schema) {
+            let optional = if field.is_required() { "" } else { "?" };
+            output.push_str(&format!(
+                "  /** {} */\n  {}{optional}: {};\n",
+                doc_text(field.display_name().as_str()),
+                field.logical_name().as_str(),
+                typescript_field_type(field, type_prefix.as_str())
+            ));
+        }
+        for system_field in schema.system_fields() {
+            output.push_str(&format!(
+                "  /** {} (system field) */\n  readonly {}?: string;\n",
+                system_field.display_name(),
+                system_field.as_str()
+            ));
+        }
+        output.push_str("}\n");
+    }
+
+    output.push_str("\n/** Record data types keyed by entity logical name. */\nexport interface EntityRecordMap {\n");
+    for schema in schemas {
+        let logical_name = schema.entity().logical_name().as_str();
+        output.push_str(&format!(
+            "  {logical_name}: {}Record;\n",
+            pascal_case(logical_name)
+        ));
+    }
+    output.push_str("}\n");
+    output.push_str(&TYPESCRIPT_EPILOGUE.join("\n"));
+
+    output
+}
+
+fn typescript_field_type(field: &EntityFieldDefinition, type_prefix: &str) -> String {
+    let option_type = || {
+        field
+            .option_set_logical_name()
+            .map(|logical_name| format!("{type_prefix}{}", pascal_case(logical_name.as_str())))
+            .unwrap_or_else(|| "number".to_owned())
+    };
+
+    match field.field_type() {
+        FieldType::Text | FieldType::Date | FieldType::DateTime => "string".to_owned(),
+        FieldType::Number => "number".to_owned(),
+        FieldType::Boolean => "boolean".to_owned(),
+        FieldType::Json => "unknown".to_owned(),
+        FieldType::Choice => option_type(),
+        FieldType::MultiChoice => format!("{}[]", option_type()),
+        FieldType::Relation => match field.relation_target_entity() {
+            Some(target) => format!("RecordId<{}>", typescript_string(target.as_str())),
+            None => "RecordId".to_owned(),
+        },
+        FieldType::ManyToMany => match field.relation_target_entity() {
+            Some(target) => format!("RecordId<{}>[]", typescript_string(target.as_str())),
+            None => "RecordId[]".to_owned(),
+        },
+    }
+}
+
+/// Fields stored in the record payload; many-to-many associations are not.
+fn record_data_fields(
+    schema: &PublishedEntitySchema,
+) -> impl Iterator<Item = &EntityFieldDefinition> {
+    schema
+        .fields()
+        .iter()
+        .filter(|field| field.field_type() != FieldType::ManyToMany)
+}
+
+fn pascal_case(logical_name: &str) -> String {
+    logical_name
+        .split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            let mut characters = segment.chars();
+            characters
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + characters.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn typescript_string(value: &str) -> String {
+    Value::String(value.to_owned()).to_string()
+}
+
+fn doc_text(value: &str) -> String {
+    value.replace("*/", "*\\/")
+}
@@ -0,0 +1,261 @@
+use super::*;
+use crate::EntitySchemaRollbackChecks;
+use crate::metadata_ports::RuntimeRecordLogicalMode;
+
+impl MetadataService {
+    /// Checks whether an earlier published schema version can be republished.
+    ///
+    /// Fields of the latest version that the target version lacks are listed as
+    /// orphaned. Changes that existing runtime data cannot satisfy are errors.
+    pub async fn rollback_entity_schema_checks(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<EntitySchemaRollbackChecks> {
+        self.require_schema_rollback_permissions(actor).await?;
+
+        let tenant_id = actor.tenant_id();
+        let current = self
+            .repository
+            .latest_published_schema(tenant_id, entity_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "entity '{}' has no published schema for tenant '{}'",
+                    entity_logical_name, tenant_id
+                ))
+            })?;
+        let target = self
+            .repository
+            .find_published_schema(tenant_id, entity_logical_name, version)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "published schema version {} for entity '{}' does not exist",
+                    version, entity_logical_name
+                ))
+            })?;
+        if target.version() == current.version() {
+            return Err(AppError::Validation(format!(
+                "published schema version {} is already the latest version of entity '{}'",
+                version, entity_logical_name
+            )));
+        }
+
+        let current_fields_by_name: BTreeMap<&str, &EntityFieldDefinition> = current
+            .fields()
+            .iter()
+            .map(|field| (field.logical_name().as_str(), field))
+            .collect();
+        let target_field_names: BTreeSet<&str> = target
+            .fields()
+            .iter()
+            .map(|field| field.logical_name().as_str())
+            .collect();
+
+        let orphaned_fields = current
+            .fields()
+            .iter()
+            .map(|field| field.logical_name().as_str())
+            .filter(|field_name| !target_field_names.contains(field_name))
+            .map(str::to_owned)
+            .collect();
+
+        let mut errors = Vec::new();
+        for target_field in target.fields() {
+            let field_name = target_field.logical_name().as_str();
+            let current_field = current_fields_by_name.get(field_name).copied();
+
+            if let Some(current_field) = current_field {
+                let mut changes = Vec::new();
+                if current_field.field_type() != target_field.field_type() {
+                    changes.push(format!(
+                        "type from '{}' to '{}'",
+                        current_field.field_type().as_str(),
+                        target_field.field_type().as_str()
+                    ));
+                }
+                if current_field
+                    .relation_target_entity()
+                    .map(|value| value.as_str())
+                    != target_field
+                        .relation_target_entity()
+                        .map(|value| value.as_str())
+                {
+                    changes.push("relation target".to_owned());
+                }
+                if current_field
+                    .option_set_logical_name()
+                    .map(|value| value.as_str())
+                    != target_field
+                        .option_set_logical_name()
+                        .map(|value| value.as_str())
+                {
+                    changes.push("option set reference".to_owned());
+                }
+                if !current_field.is_unique() && target_field.is_unique() {
+                    changes.push("uniqueness to unique".to_owned());
+                }
+
+                if !changes.is_empty()
+                    && self
+                        .runtime_records_match_field(
+                            tenant_id,
+                            entity_logical_name,
+                            current_field,
+                            RuntimeRecordOperator::IsNotNull,
+                        )
+                        .await?
+                {
+                    errors.push(format!(
+                        "rollback check failed: field '{}.{}' would change {} while runtime records hold values",
+                        entity_logical_name,
+                        field_name,
+                        changes.join(", ")
+                    ));
+                }
+            }
+
+            let becomes_required = target_field.is_required()
+                && current_field.is_none_or(|field| !field.is_required());
+            if becomes_required
+                && self
+                    .runtime_records_match_field(
+                        tenant_id,
+                        entity_logical_name,
+                        target_field,
+                        RuntimeRecordOperator::IsNull,
+                    )
+                    .await?
+            {
+                errors.push(format!(
+                    "rollback check failed: field '{}.{}' would become required while runtime records have no value",
+                    entity_logical_name, field_name
+                ));
+            }
+        }
+
+        Ok(EntitySchemaRollbackChecks {
+            entity_logical_name: entity_logical_name.to_owned(),
+            current_version: current.version(),
+            target_version: target.version(),
+            orphaned_fields,
+            errors,
+        })
+    }
+
+    /// Republishes an earlier published schema version as the newest version.
+    ///
+    /// Form and view snapshots of the target version are republished with it.
+    /// Draft metadata is left untouched.
+    pub async fn rollback_entity_schema(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<PublishedEntitySchema> {
+        let checks = self
+            .rollback_entity_schema_checks(actor, entity_logical_name, version)
+            .await?;
+        if !checks.can_rollback() {
+            let mut message = format!(
+                "rollback checks failed for entity '{}':",
+                entity_logical_name
+            );
+            for error in &checks.errors {
+                message.push_str("\n- ");
+                message.push_str(error);
+            }
+            return Err(AppError::Validation(message));
+        }
+
+        let published_schema = self
+            .repository
+            .rollback_entity_schema(
+                actor.tenant_id(),
+                entity_logical_name,
+                version,
+                actor.subject(),
+            )
+            .await?;
+
+        let mut detail = format!(
+            "rolled back metadata entity '{}' from version {} to version {} as version {}",
+            entity_logical_name,
+            checks.current_version,
+            checks.target_version,
+            published_schema.version()
+        );
+        if !checks.orphaned_fields.is_empty() {
+            detail.push_str(&format!(
+                "; orphaned fields: {}",
+                checks.orphaned_fields.join(", ")
+            ));
+        }
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::MetadataEntitySchemaRolledBack,
+                resource_type: "entity_definition".to_owned(),
+                resource_id: entity_logical_name.to_owned(),
+                detail: Some(detail),
+            })
+            .await?;
+
+        Ok(published_schema)
+    }
+
+    async fn require_schema_rollback_permissions(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataEntityCreate,
+            )
+            .await?;
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataFieldWrite,
+            )
+            .await
+    }
+
+    async fn runtime_records_match_field(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field: &EntityFieldDefinition,
+        operator: RuntimeRecordOperator,
+    ) -> AppResult<bool> {
+        let records = self
+            .repository
+            .query_runtime_records(
+                tenant_id,
+                entity_logical_name,
+                RuntimeRecordQuery {
+                    limit: 1,
+                    offset: 0,
+                    logical_mode: RuntimeRecordLogicalMode::And,
+                    where_clause: None,
+                    filters: vec![RuntimeRecordFilter {
+                        scope_alias: None,
+                        field_logical_name: field.logical_name().as_str().to_owned(),
+                        operator,
+                        field_type: field.field_type(),
+                        field_value: Value::Null,
+                    }],
+                    links: Vec::new(),
+                    sort: Vec::new(),
+                    owner_subject: None,
+                    include_inactive: true,
+                },
+            )
+            .await?;
+
+        Ok(!records.is_empty())
+    }
+}
@@ -5,10 +5,16 @@ use async_trait::async_trait;
 use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
 use qryvanta_domain::{
     AuditAction, BusinessRuleAction, BusinessRuleActionType, BusinessRuleCondition,
-    BusinessRuleDefinition, BusinessRuleOperator, BusinessRuleScope, EntityDefinition,
-    EntityFieldDefinition, FieldType, FormDefinition, FormFieldPlacement, FormSection, FormTab,
-    FormType, OptionSetDefinition, OptionSetItem, Permission, PublishedEntitySchema, RuntimeRecord,
-    ViewColumn, ViewDefinition, ViewType,
+    BusinessRuleDefinition, BusinessRuleOperator, BusinessRuleScope, DuplicateDetectionRule,
+    DuplicateMatchField, DuplicateMatchMode, DuplicateRecordsConflict, DuplicateRuleAction,
+    EntityDefinition, EntityFieldDefinition, ExtensionCapability, ExtensionDefinition,
+    ExtensionIsolationPolicy, ExtensionManifest, ExtensionManifestInput, ExtensionRuntimeKind,
+    FieldClassification, FieldMaskingMode, FieldType, FilterOperator, FormDefinition,
+    FormFieldPlacement, FormScriptEvents, FormSection, FormTab, FormType, LogicalMode,
+    OptionSetDefinition, OptionSetItem, Permission, PublishedEntitySchema, RECORD_INACTIVE_PREFIX,
+    RECORD_STATUS_TRANSITION_INVALID_PREFIX, RecordStatusOption, RecordStatusTransition,
+    RelationCascadeBehavior, RelationDeleteBehavior, RuntimeRecord, SortDirection, ViewColumn,
+    ViewDefinition, ViewFilterCondition, ViewFilterGroup, ViewSort, ViewType, WorkflowTrigger,
 };
 use serde_json::{Value, json};
 use tokio::sync::Mutex;
@@ -16,12 +22,32 @@ use uuid::Uuid;
 
 use crate::{
     AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService,
-    ClaimedRuntimeRecordWorkflowEvent, ExportWorkspaceBundleOptions, ImportWorkspaceBundleOptions,
-    MetadataRepository, RecordListQuery, RuntimeFieldGrant, RuntimeRecordFilter,
-    RuntimeRecordLogicalMode, RuntimeRecordOperator, RuntimeRecordQuery,
-    RuntimeRecordSortDirection, RuntimeRecordWorkflowEventInput, SaveBusinessRuleInput,
-    SaveFieldInput, SaveFormInput, SaveOptionSetInput, SaveViewInput, TemporaryPermissionGrant,
-    UniqueFieldValue, UpdateFieldInput,
+    ClaimedRuntimeRecordWorkflowEvent, ComplianceZoneAssignment, ComplianceZoneRepository,
+    ComplianceZoneTag, CreateLegalHoldInput, DualControlField, EntityDependencyKind,
+    EntityHistoryPolicy, EntitySlugConfig, EntityStatusConfig, ExportWorkspaceBundleOptions,
+    ExtensionRepository, FieldChangeApprovalRepository, FieldImpactKind, FieldImpactSeverity,
+    FieldMaskingRepository, FieldMaskingRule, ImportWorkspaceBundleOptions, LegalHold,
+    LegalHoldRepository, LegalHoldScope, MetadataRepository, NewPendingFieldChange,
+    NewRecordAccessRequest, NewRuntimeRecordHistoryEntry, NewRuntimeRecordStatusChange,
+    PendingFieldChange, PendingFieldChangeQuery, PendingFieldChangeStatus,
+    PublishedEntitySchemaVersion, RecordAccessRepository, RecordAccessRequest,
+    RecordAccessRequestQuery, RecordListQuery, RecordShare, RelationBehavior,
+    RelationCascadeResult, RelationDeletePlan, RelationLookupConfig, RuntimeFieldGrant,
+    RuntimeRecordAggregate, RuntimeRecordAggregateFunction, RuntimeRecordAggregateHaving,
+    RuntimeRecordAggregateQuery, RuntimeRecordAggregateRow, RuntimeRecordAggregateSort,
+    RuntimeRecordAggregateSortKey, RuntimeRecordAssociation, RuntimeRecordChangesetMethod,
+    RuntimeRecordChangesetOperation, RuntimeRecordChangesetWrite, RuntimeRecordFilter,
+    RuntimeRecordGroupBy, RuntimeRecordHistoryEntry, RuntimeRecordJoinType, RuntimeRecordLink,
+    RuntimeRecordLinkCardinality, RuntimeRecordLogicalMode, RuntimeRecordOperator,
+    RuntimeRecordOwnerDirectory, RuntimeRecordQuery, RuntimeRecordSort, RuntimeRecordSortDirection,
+    RuntimeRecordStatusChange, RuntimeRecordUpsertOutcome, RuntimeRecordValidationRule,
+    RuntimeRecordWorkflowEventInput, RuntimeViewCacheKey, RuntimeViewCacheLookup,
+    RuntimeViewResultCache, SaveBusinessRuleInput, SaveComplianceZoneTagInput,
+    SaveDualControlFieldsInput, SaveDuplicateDetectionRuleInput, SaveEntityHistoryPolicyInput,
+    SaveEntitySlugConfigInput, SaveEntityStatusModelInput, SaveFieldInput,
+    SaveFieldMaskingRulesInput, SaveFormInput, SaveOptionSetInput, SaveRelationBehaviorInput,
+    SaveRelationLookupConfigInput, SaveViewInput, TemporaryPermissionGrant, UniqueFieldValue,
+    UpdateEntityInput, UpdateFieldInput,
 };
 
 use super::MetadataService;
@@ -37,8 +63,20 @@ struct FakeRepository {
     published_form_snapshots: Mutex<HashMap<(TenantId, String, i32), Vec<FormDefinition>>>,
     published_view_snapshots: Mutex<HashMap<(TenantId, String, i32), Vec<ViewDefinition>>>,
     runtime_records: Mutex<HashMap<(TenantId, String, String), RuntimeRecord>>,
+    runtime_record_idempotency_keys: Mutex<HashMap<(TenantId, String, String), String>>,
     record_owners: Mutex<HashMap<(TenantId, String, String), String>>,
     unique_values: Mutex<HashMap<(TenantId, String, String, String), String>>,
+    relation_behaviors: Mutex<HashMap<(TenantId, String, String), RelationBehavior>>,
+    relation_lookups: Mutex<HashMap<(TenantId, String, String), RelationLookupConfig>>,
+    slug_configs: Mutex<HashMap<(TenantId, String), EntitySlugConfig>>,
+    status_configs: Mutex<HashMap<(TenantId, String), EntityStatusConfig>>,
+    duplicate_rules: Mutex<HashMap<(TenantId, String, String), DuplicateDetectionRule>>,
+    status_history: Mutex<Vec<(TenantId, RuntimeRecordStatusChange)>>,
+    history_policies: Mutex<HashMap<(TenantId, String), EntityHistoryPolicy>>,
+    record_history: Mutex<Vec<(TenantId, RuntimeRecordHistoryEntry)>>,
+    status_notifications: Mutex<Vec<RuntimeRecordWorkflowEventInput>>,
+    record_associations: Mutex<Vec<(TenantId, RuntimeRecordAssociation)>>,
+    outboxed_audit_events: Mutex<Vec<AuditEvent>>,
 }
 
 impl FakeRepository {
@@ -54,8 +92,20 @@ impl FakeRepository {
             published_form_snapshots: Mutex::new(HashMap::new()),
             published_view_snapshots: Mutex::new(HashMap::new()),
             runtime_records: Mutex::new(HashMap::new()),
+            runtime_record_idempotency_keys: Mutex::new(HashMap::new()),
             record_owners: Mutex::new(HashMap::new()),
             unique_values: Mutex::new(HashMap::new()),
+            relation_behaviors: Mutex::new(HashMap::new()),
+            relation_lookups: Mutex::new(HashMap::new()),
+            slug_configs: Mutex::new(HashMap::new()),
+            status_configs: Mutex::new(HashMap::new()),
+            duplicate_rules: Mutex::new(HashMap::new()),
+            status_history: Mutex::new(Vec::new()),
+            history_policies: Mutex::new(HashMap::new()),
+            record_history: Mutex::new(Vec::new()),
+            status_notifications: Mutex::new(Vec::new()),
+            record_associations: Mutex::new(Vec::new()),
+            outboxed_audit_events: Mutex::new(Vec::new()),
         }
     }
 }
@@ -119,6 +169,46 @@ impl MetadataRepository for FakeRepository {
         Ok(())
     }
 
+    async fn delete_entity(&self, tenant_id: TenantId, logical_name: &str) -> AppResult<()> {
+        if self
+            .entities
+            .lock()
+            .await
+            .remove(&(tenant_id, logical_name.to_owned()))
+            .is_none()
+        {
+            return Err(AppError::NotFound(format!(
+                "entity '{}' does not exist for tenant '{}'",
+                logical_name, tenant_id
+            )));
+        }
+
+        let owned = |key_tenant: &TenantId, key_entity: &String| {
+            *key_tenant == tenant_id && key_entity == logical_name
+        };
+        self.fields
+            .lock()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.forms
+            .lock()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.views
+            .lock()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.published_schemas
+            .lock()
+            .await
+            .retain(|(key_tenant, key_entity), _| !owned(key_tenant, key_entity));
+        self.runtime_records
+            .lock()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        Ok(())
+    }
+
     async fn save_field(&self, tenant_id: TenantId, field: EntityFieldDefinition) -> AppResult<()> {
         let key = (
             tenant_id,
@@ -523,6 +613,104 @@ impl MetadataRepository for FakeRepository {
             .and_then(|versions| versions.last().cloned()))
     }
 
+    async fn find_published_schema(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        Ok(self
+            .published_schemas
+            .lock()
+            .await
+            .get(&(tenant_id, entity_logical_name.to_owned()))
+            .and_then(|versions| {
+                versions
+                    .iter()
+                    .find(|schema| schema.version() == version)
+                    .cloned()
+            }))
+    }
+
+    async fn list_published_schema_versions(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<PublishedEntitySchemaVersion>> {
+        Ok(self
+            .published_schemas
+            .lock()
+            .await
+            .get(&(tenant_id, entity_logical_name.to_owned()))
+            .map(|versions| {
+                versions
+                    .iter()
+                    .rev()
+                    .map(|schema| PublishedEntitySchemaVersion {
+                        schema: schema.clone(),
+                        published_by_subject: "system".to_owned(),
+                        published_at: chrono::Utc::now(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn find_published_schema_version(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchemaVersion>> {
+        Ok(self
+            .list_published_schema_versions(tenant_id, entity_logical_name)
+            .await?
+            .into_iter()
+            .find(|stored| stored.schema.version() == version))
+    }
+
+    async fn rollback_entity_schema(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+        _published_by: &str,
+    ) -> AppResult<PublishedEntitySchema> {
+        let mut published_schemas = self.published_schemas.lock().await;
+        let versions = published_schemas
+            .entry((tenant_id, entity_logical_name.to_owned()))
+            .or_default();
+        let target = versions
+            .iter()
+            .find(|schema| schema.version() == version)
+            .cloned()
+            .ok_or_else(|| AppError::NotFound("published schema version not found".to_owned()))?;
+        let next_version = versions
+            .last()
+            .map(|schema| schema.version() + 1)
+            .unwrap_or(1);
+        let schema = PublishedEntitySchema::new(
+            target.entity().clone(),
+            next_version,
+            target.fields().to_vec(),
+            target.option_sets().to_vec(),
+        )?;
+        versions.push(schema.clone());
+
+        let source_key = (tenant_id, entity_logical_name.to_owned(), version);
+        let target_key = (tenant_id, entity_logical_name.to_owned(), next_version);
+        let mut form_snapshots = self.published_form_snapshots.lock().await;
+        if let Some(forms) = form_snapshots.get(&source_key).cloned() {
+            form_snapshots.insert(target_key.clone(), forms);
+        }
+        let mut view_snapshots = self.published_view_snapshots.lock().await;
+        if let Some(views) = view_snapshots.get(&source_key).cloned() {
+            view_snapshots.insert(target_key, views);
+        }
+
+        Ok(schema)
+    }
+
     async fn save_published_form_snapshots(
         &self,
         tenant_id: TenantId,
@@ -615,6 +803,7 @@ impl MetadataRepository for FakeRepository {
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         _workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         let record_id = Uuid::new_v4().to_string();
         self.create_runtime_record_with_id(
@@ -625,6 +814,7 @@ impl MetadataRepository for FakeRepository {
             unique_values,
             created_by_subject,
             None,
+            audit_event,
         )
         .await
     }
@@ -638,6 +828,7 @@ impl MetadataRepository for FakeRepository {
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         _workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         let record = RuntimeRecord::new(record_id, entity_logical_name, data)?;
         let record_key = (
@@ -692,10 +883,87 @@ impl MetadataRepository for FakeRepository {
             .lock()
             .await
             .insert(record_key, created_by_subject.to_owned());
+        self.outboxed_audit_events.lock().await.extend(audit_event);
+
+        Ok(record)
+    }
+
+    async fn create_runtime_record_with_idempotency_key(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        idempotency_key: &str,
+        data: Value,
+        unique_values: Vec<UniqueFieldValue>,
+        created_by_subject: &str,
+        workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+    ) -> AppResult<RuntimeRecord> {
+        let key = (
+            tenant_id,
+            entity_logical_name.to_owned(),
+            idempotency_key.to_owned(),
+        );
+        if self
+            .runtime_record_idempotency_keys
+            .lock()
+            .await
+            .contains_key(&key)
+        {
+            return Err(AppError::Conflict(format!(
+                "entity '{entity_logical_name}' already has a record for this idempotency key"
+            )));
+        }
+
+        let record = self
+            .create_runtime_record_with_id(
+                tenant_id,
+                entity_logical_name,
+                record_id,
+                data,
+                unique_values,
+                created_by_subject,
+                workflow_event,
+                audit_event,
+            )
+            .await?;
+        self.runtime_record_idempotency_keys
+            .lock()
+            .await
+            .insert(key, record.record_id().as_str().to_owned());
 
         Ok(record)
     }
 
+    async fn find_runtime_record_by_idempotency_key(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        idempotency_key: &str,
+    ) -> AppResult<Option<RuntimeRecord>> {
+        let Some(record_id) = self
+            .runtime_record_idempotency_keys
+            .lock()
+            .await
+            .get(&(
+                tenant_id,
+                entity_logical_name.to_owned(),
+                idempotency_key.to_owned(),
+            ))
+            .cloned()
+        else {
+            return Ok(None);
+        };
+
+        Ok(self
+            .runtime_records
+            .lock()
+            .await
+            .get(&(tenant_id, entity_logical_name.to_owned(), record_id))
+            .cloned())
+    }
+
     async fn update_runtime_record(
         &self,
         tenant_id: TenantId,
@@ -703,18 +971,32 @@ impl MetadataRepository for FakeRepository {
         record_id: &str,
         data: Value,
         unique_values: Vec<UniqueFieldValue>,
+        expected_version: Option<i64>,
         _workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         let record_key = (
             tenant_id,
             entity_logical_name.to_owned(),
             record_id.to_owned(),
         );
-        if !self.runtime_records.lock().await.contains_key(&record_key) {
+        let Some(current_version) = self
+            .runtime_records
+            .lock()
+            .await
+            .get(&record_key)
+            .map(RuntimeRecord::version)
+        else {
             return Err(AppError::NotFound(format!(
                 "runtime record '{}' does not exist",
                 record_id
             )));
+        };
+        if expected_version.is_some_and(|expected_version| expected_version != current_version) {
+            return Err(AppError::Conflict(format!(
+                "runtime record '{}' was modified concurrently",
+                record_id
+            )));
         }
 
         let mut unique_index = self.unique_values.lock().await;
@@ -754,15 +1036,72 @@ impl MetadataRepository for FakeRepository {
             );
         }
 
-        let updated = RuntimeRecord::new(record_id, entity_logical_name, data)?;
+        let updated = RuntimeRecord::new(record_id, entity_logical_name, data)?
+            .with_version(current_version + 1);
         self.runtime_records
             .lock()
             .await
             .insert(record_key, updated.clone());
+        self.outboxed_audit_events.lock().await.extend(audit_event);
 
         Ok(updated)
     }
 
+    async fn apply_runtime_record_changeset(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        writes: Vec<RuntimeRecordChangesetWrite>,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        let records_snapshot = self.runtime_records.lock().await.clone();
+        let owners_snapshot = self.record_owners.lock().await.clone();
+        let unique_snapshot = self.unique_values.lock().await.clone();
+
+        let mut records = Vec::with_capacity(writes.len());
+        for write in writes {
+            let result = match write.method {
+                RuntimeRecordChangesetMethod::Create => {
+                    self.create_runtime_record_with_id(
+                        tenant_id,
+                        write.entity_logical_name.as_str(),
+                        write.record_id.as_str(),
+                        write.data,
+                        write.unique_values,
+                        created_by_subject,
+                        write.workflow_event,
+                        write.audit_event,
+                    )
+                    .await
+                }
+                RuntimeRecordChangesetMethod::Update => {
+                    self.update_runtime_record(
+                        tenant_id,
+                        write.entity_logical_name.as_str(),
+                        write.record_id.as_str(),
+                        write.data,
+                        write.unique_values,
+                        None,
+                        write.workflow_event,
+                        write.audit_event,
+                    )
+                    .await
+                }
+            };
+
+            match result {
+                Ok(record) => records.push(record),
+                Err(error) => {
+                    *self.runtime_records.lock().await = records_snapshot;
+                    *self.record_owners.lock().await = owners_snapshot;
+                    *self.unique_values.lock().await = unique_snapshot;
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(records)
+    }
+
     async fn list_runtime_records(
         &self,
         tenant_id: TenantId,
@@ -843,7 +1182,7 @@ impl MetadataRepository for FakeRepository {
                         .and_then(|data| data.get(filter.field_logical_name.as_str()));
 
                     let Some(value) = value else {
-                        return false;
+                        return filter.operator == RuntimeRecordOperator::IsNull;
                     };
 
                     match filter.operator {
@@ -879,6 +1218,23 @@ impl MetadataRepository for FakeRepository {
                             .as_array()
                             .map(|values| values.iter().any(|candidate| candidate == value))
                             .unwrap_or(false),
+                        RuntimeRecordOperator::NotIn => filter
+                            .field_value
+                            .as_array()
+                            .map(|values| values.iter().all(|candidate| candidate != value))
+                            .unwrap_or(false),
+                        RuntimeRecordOperator::BeginsWith => value
+                            .as_str()
+                            .zip(filter.field_value.as_str())
+                            .map(|(left, right)| left.starts_with(right))
+                            .unwrap_or(false),
+                        RuntimeRecordOperator::IsNull => value.is_null(),
+                        RuntimeRecordOperator::IsNotNull => !value.is_null(),
+                        RuntimeRecordOperator::Between
+                        | RuntimeRecordOperator::Today
+                        | RuntimeRecordOperator::Yesterday
+                        | RuntimeRecordOperator::ThisMonth
+                        | RuntimeRecordOperator::LastNDays => false,
                     }
                 };
 
@@ -932,6 +1288,18 @@ impl MetadataRepository for FakeRepository {
             .collect())
     }
 
+    async fn aggregate_runtime_records(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        query: RuntimeRecordAggregateQuery,
+    ) -> AppResult<Vec<RuntimeRecordAggregateRow>> {
+        let records = self
+            .query_runtime_records(tenant_id, entity_logical_name, query.record_query())
+            .await?;
+        Ok(query.aggregate_records(&records))
+    }
+
     async fn find_runtime_record(
         &self,
         tenant_id: TenantId,
@@ -956,31 +1324,76 @@ impl MetadataRepository for FakeRepository {
         entity_logical_name: &str,
         record_id: &str,
         _workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+        relation_plan: &RelationDeletePlan,
     ) -> AppResult<()> {
-        let removed = self.runtime_records.lock().await.remove(&(
+        if !self.runtime_records.lock().await.contains_key(&(
             tenant_id,
             entity_logical_name.to_owned(),
             record_id.to_owned(),
-        ));
-        if removed.is_none() {
+        )) {
             return Err(AppError::NotFound(format!(
                 "runtime record '{}' does not exist for entity '{}'",
                 record_id, entity_logical_name
             )));
         }
 
-        self.unique_values
-            .lock()
-            .await
-            .retain(|(_, entity, _, _), existing_record_id| {
-                !(entity == entity_logical_name && existing_record_id == record_id)
-            });
+        {
+            let mut runtime_records = self.runtime_records.lock().await;
+            for relinked in &relation_plan.relinked_records {
+                let key = (
+                    tenant_id,
+                    relinked.entity_logical_name.clone(),
+                    relinked.record_id.clone(),
+                );
+                let Some(record) = runtime_records.get(&key) else {
+                    continue;
+                };
+                let mut data = record.data().clone();
+                if let Some(object) = data.as_object_mut() {
+                    object.insert(
+                        relinked.field_logical_name.clone(),
+                        relinked
+                            .parent_record_id
+                            .clone()
+                            .map_or(Value::Null, Value::String),
+                    );
+                }
+                let relinked_record = RuntimeRecord::new(
+                    relinked.record_id.as_str(),
+                    relinked.entity_logical_name.as_str(),
+                    data,
+                )?;
+                runtime_records.insert(key, relinked_record);
+            }
+        }
 
-        self.record_owners.lock().await.remove(&(
-            tenant_id,
-            entity_logical_name.to_owned(),
-            record_id.to_owned(),
-        ));
+        let deleted_records = relation_plan
+            .deleted_records
+            .iter()
+            .map(|deleted| {
+                (
+                    deleted.entity_logical_name.as_str(),
+                    deleted.record_id.as_str(),
+                )
+            })
+            .chain(std::iter::once((entity_logical_name, record_id)));
+        for (entity_logical_name, record_id) in deleted_records {
+            let key = (
+                tenant_id,
+                entity_logical_name.to_owned(),
+                record_id.to_owned(),
+            );
+            self.runtime_records.lock().await.remove(&key);
+            self.unique_values
+                .lock()
+                .await
+                .retain(|(_, entity, _, _), existing_record_id| {
+                    !(entity == entity_logical_name && existing_record_id == record_id)
+                });
+            self.record_owners.lock().await.remove(&key);
+        }
+        self.outboxed_audit_events.lock().await.extend(audit_event);
 
         Ok(())
     }
@@ -1049,539 +1462,816 @@ impl MetadataRepository for FakeRepository {
             .unwrap_or(false))
     }
 
-    async fn has_relation_reference(
+    async fn assign_runtime_record_owner(
         &self,
         tenant_id: TenantId,
-        target_entity_logical_name: &str,
-        target_record_id: &str,
-    ) -> AppResult<bool> {
-        let published_schemas = self.published_schemas.lock().await;
+        entity_logical_name: &str,
+        record_id: &str,
+        owner_subject: &str,
+        cascades: &[RelationBehavior],
+    ) -> AppResult<Option<(String, Vec<RelationCascadeResult>)>> {
+        let record_key = (
+            tenant_id,
+            entity_logical_name.to_owned(),
+            record_id.to_owned(),
+        );
         let runtime_records = self.runtime_records.lock().await;
+        if !runtime_records.contains_key(&record_key) {
+            return Ok(None);
+        }
 
-        for ((schema_tenant_id, _), versions) in published_schemas.iter() {
-            if schema_tenant_id != &tenant_id {
-                continue;
-            }
-
-            let Some(schema) = versions.last() else {
-                continue;
-            };
-
-            let relation_fields: Vec<&EntityFieldDefinition> = schema
-                .fields()
-                .iter()
-                .filter(|field| {
-                    field.field_type() == FieldType::Relation
-                        && field
-                            .relation_target_entity()
-                            .map(|target| target.as_str() == target_entity_logical_name)
-                            .unwrap_or(false)
-                })
-                .collect();
-
-            if relation_fields.is_empty() {
-                continue;
-            }
-
-            for ((record_tenant_id, record_entity, _), record) in runtime_records.iter() {
-                if record_tenant_id != &tenant_id
-                    || record_entity != schema.entity().logical_name().as_str()
+        let mut record_owners = self.record_owners.lock().await;
+        let previous_owner = record_owners
+            .insert(record_key, owner_subject.to_owned())
+            .unwrap_or_default();
+
+        let mut results = Vec::new();
+        for cascade in cascades {
+            let mut affected_records = 0;
+            for (key, record) in runtime_records.iter() {
+                if key.0 != tenant_id
+                    || key.1 != cascade.entity_logical_name
+                    || record
+                        .data()
+                        .get(cascade.field_logical_name.as_str())
+                        .and_then(Value::as_str)
+                        != Some(record_id)
                 {
                     continue;
                 }
 
-                let Some(data) = record.data().as_object() else {
+                let child_owner = record_owners.get(key).cloned().unwrap_or_default();
+                if child_owner == owner_subject
+                    || (cascade.assign_behavior == RelationCascadeBehavior::CascadeUserOwned
+                        && child_owner != previous_owner)
+                {
                     continue;
-                };
-
-                if relation_fields.iter().any(|field| {
-                    data.get(field.logical_name().as_str())
-                        .and_then(Value::as_str)
-                        .map(|value| value == target_record_id)
-                        .unwrap_or(false)
-                }) {
-                    return Ok(true);
                 }
+
+                record_owners.insert(key.clone(), owner_subject.to_owned());
+                affected_records += 1;
             }
+            results.push(RelationCascadeResult {
+                entity_logical_name: cascade.entity_logical_name.clone(),
+                field_logical_name: cascade.field_logical_name.clone(),
+                affected_records,
+            });
         }
 
-        Ok(false)
+        Ok(Some((previous_owner, results)))
     }
-}
-
-#[derive(Default)]
-struct FakeAuditRepository {
-    events: Mutex<Vec<AuditEvent>>,
-}
 
-#[async_trait]
-impl AuditRepository for FakeAuditRepository {
-    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
-        self.events.lock().await.push(event);
+    async fn save_relation_behavior(
+        &self,
+        tenant_id: TenantId,
+        _updated_by_subject: &str,
+        behavior: RelationBehavior,
+    ) -> AppResult<()> {
+        self.relation_behaviors.lock().await.insert(
+            (
+                tenant_id,
+                behavior.entity_logical_name.clone(),
+                behavior.field_logical_name.clone(),
+            ),
+            behavior,
+        );
         Ok(())
     }
-}
-
-struct FakeAuthorizationRepository {
-    grants: HashMap<(TenantId, String), Vec<Permission>>,
-    runtime_field_grants: HashMap<(TenantId, String, String), Vec<RuntimeFieldGrant>>,
-}
 
-#[async_trait]
-impl AuthorizationRepository for FakeAuthorizationRepository {
-    async fn list_permissions_for_subject(
+    async fn list_relation_behaviors(
         &self,
         tenant_id: TenantId,
-        subject: &str,
-    ) -> AppResult<Vec<Permission>> {
+    ) -> AppResult<Vec<RelationBehavior>> {
         Ok(self
-            .grants
-            .get(&(tenant_id, subject.to_owned()))
-            .cloned()
-            .unwrap_or_default())
+            .relation_behaviors
+            .lock()
+            .await
+            .iter()
+            .filter(|((behavior_tenant_id, _, _), _)| behavior_tenant_id == &tenant_id)
+            .map(|(_, behavior)| behavior.clone())
+            .collect())
     }
 
-    async fn list_runtime_field_grants_for_subject(
+    async fn save_relation_lookup_config(
+        &self,
+        tenant_id: TenantId,
+        _updated_by_subject: &str,
+        config: RelationLookupConfig,
+    ) -> AppResult<()> {
+        self.relation_lookups.lock().await.insert(
+            (
+                tenant_id,
+                config.entity_logical_name.clone(),
+                config.field_logical_name.clone(),
+            ),
+            config,
+        );
+        Ok(())
+    }
+
+    async fn find_relation_lookup_config(
         &self,
         tenant_id: TenantId,
-        subject: &str,
         entity_logical_name: &str,
-    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        field_logical_name: &str,
+    ) -> AppResult<Option<RelationLookupConfig>> {
         Ok(self
-            .runtime_field_grants
+            .relation_lookups
+            .lock()
+            .await
             .get(&(
                 tenant_id,
-                subject.to_owned(),
                 entity_logical_name.to_owned(),
+                field_logical_name.to_owned(),
             ))
-            .cloned()
-            .unwrap_or_default())
+            .cloned())
     }
 
-    async fn find_active_temporary_permission_grant(
+    async fn delete_relation_lookup_config(
         &self,
-        _tenant_id: TenantId,
-        _subject: &str,
-        _permission: Permission,
-    ) -> AppResult<Option<TemporaryPermissionGrant>> {
-        Ok(None)
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(self
+            .relation_lookups
+            .lock()
+            .await
+            .remove(&(
+                tenant_id,
+                entity_logical_name.to_owned(),
+                field_logical_name.to_owned(),
+            ))
+            .is_some())
     }
-}
-
-fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
-    UserIdentity::new(subject, subject, None, tenant_id)
-}
 
-fn build_service(
-    grants: HashMap<(TenantId, String), Vec<Permission>>,
-) -> (MetadataService, Arc<FakeAuditRepository>) {
-    build_service_with_runtime_field_grants(grants, HashMap::new())
-}
+    async fn save_entity_slug_config(
+        &self,
+        tenant_id: TenantId,
+        _updated_by_subject: &str,
+        config: EntitySlugConfig,
+    ) -> AppResult<()> {
+        self.slug_configs
+            .lock()
+            .await
+            .insert((tenant_id, config.entity_logical_name.clone()), config);
+        Ok(())
+    }
 
-fn build_service_with_runtime_field_grants(
-    grants: HashMap<(TenantId, String), Vec<Permission>>,
-    runtime_field_grants: HashMap<(TenantId, String, String), Vec<RuntimeFieldGrant>>,
-) -> (MetadataService, Arc<FakeAuditRepository>) {
-    let audit_repository = Arc::new(FakeAuditRepository::default());
-    let authorization_service = AuthorizationService::new(
-        Arc::new(FakeAuthorizationRepository {
-            grants,
-            runtime_field_grants,
-        }),
-        audit_repository.clone(),
-    );
-    let service = MetadataService::new(
-        Arc::new(FakeRepository::new()),
-        authorization_service,
-        audit_repository.clone(),
-    );
-    (service, audit_repository)
-}
+    async fn find_entity_slug_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntitySlugConfig>> {
+        Ok(self
+            .slug_configs
+            .lock()
+            .await
+            .get(&(tenant_id, entity_logical_name.to_owned()))
+            .cloned())
+    }
 
-async fn register_publish_entity_with_text_fields(
-    service: &MetadataService,
-    actor: &UserIdentity,
-    entity_logical_name: &str,
-    entity_display_name: &str,
-    fields: &[&str],
-) -> AppResult<()> {
-    service
-        .register_entity(actor, entity_logical_name, entity_display_name)
-        .await?;
+    async fn save_entity_status_config(
+        &self,
+        tenant_id: TenantId,
+        _updated_by_subject: &str,
+        config: EntityStatusConfig,
+    ) -> AppResult<()> {
+        self.status_configs
+            .lock()
+            .await
+            .insert((tenant_id, config.entity_logical_name.clone()), config);
+        Ok(())
+    }
 
-    for (index, field_logical_name) in fields.iter().enumerate() {
-        service
-            .save_field(
-                actor,
-                SaveFieldInput {
-                    entity_logical_name: entity_logical_name.to_owned(),
-                    logical_name: (*field_logical_name).to_owned(),
-                    display_name: (*field_logical_name).to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: index == 0,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
-            .await?;
+    async fn find_entity_status_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityStatusConfig>> {
+        Ok(self
+            .status_configs
+            .lock()
+            .await
+            .get(&(tenant_id, entity_logical_name.to_owned()))
+            .cloned())
     }
 
-    service.publish_entity(actor, entity_logical_name).await?;
-    Ok(())
-}
+    async fn delete_entity_status_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(self
+            .status_configs
+            .lock()
+            .await
+            .remove(&(tenant_id, entity_logical_name.to_owned()))
+            .is_some())
+    }
 
-#[tokio::test]
-async fn register_entity_persists_data_and_writes_audit_event() {
-    let tenant_id = TenantId::new();
-    let subject = "alice";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataEntityRead,
-            Permission::MetadataFieldWrite,
-        ],
-    )]);
-    let (service, audit_repository) = build_service(grants);
-    let actor = actor(tenant_id, subject);
+    async fn save_duplicate_detection_rule(
+        &self,
+        tenant_id: TenantId,
+        _updated_by_subject: &str,
+        rule: DuplicateDetectionRule,
+    ) -> AppResult<()> {
+        self.duplicate_rules.lock().await.insert(
+            (
+                tenant_id,
+                rule.entity_logical_name().to_owned(),
+                rule.logical_name().to_owned(),
+            ),
+            rule,
+        );
+        Ok(())
+    }
 
-    let created = service.register_entity(&actor, "contact", "Contact").await;
-    assert!(created.is_ok());
+    async fn list_duplicate_detection_rules(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<DuplicateDetectionRule>> {
+        let mut rules = self
+            .duplicate_rules
+            .lock()
+            .await
+            .iter()
+            .filter(|((stored_tenant_id, stored_entity, _), _)| {
+                stored_tenant_id == &tenant_id && stored_entity == entity_logical_name
+            })
+            .map(|(_, rule)| rule.clone())
+            .collect::<Vec<_>>();
+        rules.sort_by(|left, right| left.logical_name().cmp(right.logical_name()));
+        Ok(rules)
+    }
 
-    let entities = service.list_entities(&actor).await;
-    assert!(entities.is_ok());
-    assert_eq!(entities.unwrap_or_default().len(), 1);
+    async fn delete_duplicate_detection_rule(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(self
+            .duplicate_rules
+            .lock()
+            .await
+            .remove(&(
+                tenant_id,
+                entity_logical_name.to_owned(),
+                logical_name.to_owned(),
+            ))
+            .is_some())
+    }
 
-    let events = audit_repository.events.lock().await;
-    assert_eq!(events.len(), 1);
-    assert_eq!(events[0].action, AuditAction::MetadataEntityCreated);
-    assert_eq!(events[0].resource_id, "contact");
-}
+    async fn append_runtime_record_status_change(
+        &self,
+        tenant_id: TenantId,
+        change: NewRuntimeRecordStatusChange,
+    ) -> AppResult<RuntimeRecordStatusChange> {
+        let stored = RuntimeRecordStatusChange {
+            change_id: Uuid::new_v4().to_string(),
+            entity_logical_name: change.entity_logical_name,
+            record_id: change.record_id,
+            from_status: change.from_status,
+            to_status: change.to_status,
+            from_reason: change.from_reason,
+            to_reason: change.to_reason,
+            changed_by_subject: change.changed_by_subject,
+            changed_at: chrono::Utc::now(),
+        };
+        self.status_history
+            .lock()
+            .await
+            .push((tenant_id, stored.clone()));
+        if let Some(notification) = change.notification {
+            self.status_notifications.lock().await.push(notification);
+        }
+        Ok(stored)
+    }
 
-#[tokio::test]
-async fn save_field_requires_field_write_permission() {
-    let tenant_id = TenantId::new();
-    let subject = "bob";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![Permission::MetadataEntityCreate],
-    )]);
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
-    let created = service.register_entity(&actor, "account", "Account").await;
-    assert!(created.is_err());
-}
+    async fn list_runtime_record_status_history(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RuntimeRecordStatusChange>> {
+        Ok(self
+            .status_history
+            .lock()
+            .await
+            .iter()
+            .filter(|(change_tenant_id, change)| {
+                change_tenant_id == &tenant_id
+                    && change.entity_logical_name == entity_logical_name
+                    && change.record_id == record_id
+            })
+            .map(|(_, change)| change.clone())
+            .collect())
+    }
 
-#[tokio::test]
-async fn publish_entity_requires_fields() {
-    let tenant_id = TenantId::new();
-    let subject = "carol";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
-        ],
-    )]);
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
+    async fn save_entity_history_policy(
+        &self,
+        tenant_id: TenantId,
+        _updated_by_subject: &str,
+        policy: EntityHistoryPolicy,
+    ) -> AppResult<()> {
+        self.history_policies
+            .lock()
+            .await
+            .insert((tenant_id, policy.entity_logical_name.clone()), policy);
+        Ok(())
+    }
 
-    let created = service.register_entity(&actor, "contact", "Contact").await;
-    assert!(created.is_ok());
-
-    let publish_result = service.publish_entity(&actor, "contact").await;
-    assert!(matches!(publish_result, Err(AppError::Validation(_))));
-}
+    async fn find_entity_history_policy(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityHistoryPolicy>> {
+        Ok(self
+            .history_policies
+            .lock()
+            .await
+            .get(&(tenant_id, entity_logical_name.to_owned()))
+            .cloned())
+    }
 
-#[tokio::test]
-async fn publish_checks_report_missing_fields() {
-    let tenant_id = TenantId::new();
-    let subject = "carol";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
-        ],
-    )]);
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
+    async fn delete_entity_history_policy(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(self
+            .history_policies
+            .lock()
+            .await
+            .remove(&(tenant_id, entity_logical_name.to_owned()))
+            .is_some())
+    }
 
-    let created = service.register_entity(&actor, "contact", "Contact").await;
-    assert!(created.is_ok());
+    async fn append_runtime_record_history_entry(
+        &self,
+        tenant_id: TenantId,
+        entry: NewRuntimeRecordHistoryEntry,
+        retention_cutoff: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> AppResult<RuntimeRecordHistoryEntry> {
+        let stored = RuntimeRecordHistoryEntry {
+            entry_id: Uuid::new_v4().to_string(),
+            entity_logical_name: entry.entity_logical_name,
+            record_id: entry.record_id,
+            record_version: entry.record_version,
+            changes: entry.changes,
+            changed_by_subject: entry.changed_by_subject,
+            changed_at: chrono::Utc::now(),
+        };
+        let mut history = self.record_history.lock().await;
+        if let Some(cutoff) = retention_cutoff {
+            history.retain(|(entry_tenant_id, existing)| {
+                entry_tenant_id != &tenant_id
+                    || existing.entity_logical_name != stored.entity_logical_name
+                    || existing.record_id != stored.record_id
+                    || existing.changed_at >= cutoff
+            });
+        }
+        history.push((tenant_id, stored.clone()));
+        Ok(stored)
+    }
 
-    let result = service.publish_checks(&actor, "contact").await;
-    assert!(result.is_ok());
-    let errors = result.unwrap_or_default();
-    assert_eq!(errors.len(), 1);
-    assert!(errors[0].contains("requires at least one field"));
-}
+    async fn list_runtime_record_history(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> AppResult<Vec<RuntimeRecordHistoryEntry>> {
+        Ok(self
+            .record_history
+            .lock()
+            .await
+            .iter()
+            .filter(|(entry_tenant_id, entry)| {
+                entry_tenant_id == &tenant_id
+                    && entry.entity_logical_name == entity_logical_name
+                    && entry.record_id == record_id
+                    && since.is_none_or(|since| entry.changed_at >= since)
+            })
+            .map(|(_, entry)| entry.clone())
+            .collect())
+    }
 
-#[tokio::test]
-async fn publish_checks_pass_for_valid_draft() {
-    let tenant_id = TenantId::new();
-    let subject = "olivia";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
-        ],
-    )]);
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
+    async fn purge_runtime_record_history(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        cutoff: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<u64> {
+        let mut history = self.record_history.lock().await;
+        let before = history.len();
+        history.retain(|(entry_tenant_id, entry)| {
+            entry_tenant_id != &tenant_id
+                || entry.entity_logical_name != entity_logical_name
+                || entry.changed_at >= cutoff
+        });
+        Ok(u64::try_from(before - history.len()).unwrap_or(u64::MAX))
+    }
 
-    assert!(
-        service
-            .register_entity(&actor, "contact", "Contact")
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "name".to_owned(),
-                    display_name: "Name".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: true,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
+    async fn find_runtime_record_id_by_unique_value(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        field_value_hash: &str,
+    ) -> AppResult<Option<String>> {
+        Ok(self
+            .unique_values
+            .lock()
             .await
-            .is_ok()
-    );
+            .get(&(
+                tenant_id,
+                entity_logical_name.to_owned(),
+                field_logical_name.to_owned(),
+                field_value_hash.to_owned(),
+            ))
+            .cloned())
+    }
 
-    let result = service.publish_checks(&actor, "contact").await;
-    assert!(result.is_ok());
-    assert!(result.unwrap_or_default().is_empty());
-}
+    async fn has_relation_reference(
+        &self,
+        tenant_id: TenantId,
+        target_entity_logical_name: &str,
+        target_record_id: &str,
+    ) -> AppResult<bool> {
+        let published_schemas = self.published_schemas.lock().await;
+        let runtime_records = self.runtime_records.lock().await;
 
-#[tokio::test]
-async fn publish_checks_report_relation_target_dependencies() {
-    let tenant_id = TenantId::new();
-    let subject = "nora";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
-        ],
-    )]);
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
+        for ((schema_tenant_id, _), versions) in published_schemas.iter() {
+            if schema_tenant_id != &tenant_id {
+                continue;
+            }
 
-    assert!(
-        service
-            .register_entity(&actor, "contact", "Contact")
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .register_entity(&actor, "account", "Account")
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "account".to_owned(),
-                    logical_name: "name".to_owned(),
-                    display_name: "Name".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: true,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "account_id".to_owned(),
-                    display_name: "Account".to_owned(),
-                    field_type: FieldType::Relation,
-                    is_required: false,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: Some("account".to_owned()),
-                    option_set_logical_name: None,
-                },
-            )
-            .await
-            .is_ok()
-    );
+            let Some(schema) = versions.last() else {
+                continue;
+            };
 
-    let result = service.publish_checks(&actor, "contact").await;
-    assert!(result.is_ok());
-    let errors = result.unwrap_or_default();
-    assert!(errors.iter().any(|error| {
-        error.contains("requires a published schema or inclusion in this publish selection")
-    }));
-}
+            let relation_fields: Vec<&EntityFieldDefinition> = schema
+                .fields()
+                .iter()
+                .filter(|field| {
+                    field.field_type() == FieldType::Relation
+                        && field
+                            .relation_target_entity()
+                            .map(|target| target.as_str() == target_entity_logical_name)
+                            .unwrap_or(false)
+                })
+                .collect();
 
-#[tokio::test]
-async fn publish_checks_allow_selected_unpublished_relation_dependencies() {
-    let tenant_id = TenantId::new();
-    let subject = "nora";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
-        ],
-    )]);
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
+            if relation_fields.is_empty() {
+                continue;
+            }
 
-    assert!(
-        service
-            .register_entity(&actor, "contact", "Contact")
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .register_entity(&actor, "account", "Account")
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "account_id".to_owned(),
-                    display_name: "Account".to_owned(),
-                    field_type: FieldType::Relation,
-                    is_required: false,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: Some("account".to_owned()),
-                    option_set_logical_name: None,
-                },
-            )
+            for ((record_tenant_id, record_entity, _), record) in runtime_records.iter() {
+                if record_tenant_id != &tenant_id
+                    || record_entity != schema.entity().logical_name().as_str()
+                {
+                    continue;
+                }
+
+                let Some(data) = record.data().as_object() else {
+                    continue;
+                };
+
+                if relation_fields.iter().any(|field| {
+                    data.get(field.logical_name().as_str())
+                        .and_then(Value::as_str)
+                        .map(|value| value == target_record_id)
+                        .unwrap_or(false)
+                }) {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn associate_runtime_records(
+        &self,
+        tenant_id: TenantId,
+        _associated_by_subject: &str,
+        association: RuntimeRecordAssociation,
+    ) -> AppResult<bool> {
+        let mut associations = self.record_associations.lock().await;
+        if associations.contains(&(tenant_id, association.clone())) {
+            return Ok(false);
+        }
+
+        associations.push((tenant_id, association));
+        Ok(true)
+    }
+
+    async fn disassociate_runtime_records(
+        &self,
+        tenant_id: TenantId,
+        association: &RuntimeRecordAssociation,
+    ) -> AppResult<bool> {
+        let mut associations = self.record_associations.lock().await;
+        let previous_len = associations.len();
+        associations.retain(|stored| stored != &(tenant_id, association.clone()));
+        Ok(associations.len() < previous_len)
+    }
+
+    async fn list_associated_runtime_record_ids(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(self
+            .record_associations
+            .lock()
             .await
-            .is_ok()
-    );
+            .iter()
+            .filter(|(stored_tenant_id, association)| {
+                stored_tenant_id == &tenant_id
+                    && association.entity_logical_name == entity_logical_name
+                    && association.field_logical_name == field_logical_name
+                    && association.record_id == record_id
+            })
+            .map(|(_, association)| association.target_record_id.clone())
+            .collect())
+    }
+}
 
-    let result = service
-        .publish_checks_with_allowed_unpublished_entities(
-            &actor,
-            "contact",
-            &["account".to_owned()],
-        )
-        .await;
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
 
-    assert!(result.is_ok());
-    assert!(result.unwrap_or_default().is_empty());
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
 }
 
-#[tokio::test]
-async fn publish_checks_report_compatibility_break_when_field_becomes_required() {
-    let tenant_id = TenantId::new();
-    let subject = "nora";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
-        ],
-    )]);
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+    runtime_field_grants: HashMap<(TenantId, String, String), Vec<RuntimeFieldGrant>>,
+    team_peers: HashMap<(TenantId, String), Vec<String>>,
+    role_names: HashMap<(TenantId, String), Vec<String>>,
+}
 
-    assert!(
-        service
-            .register_entity(&actor, "contact", "Contact")
-            .await
-            .is_ok()
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(self
+            .runtime_field_grants
+            .get(&(
+                tenant_id,
+                subject.to_owned(),
+                entity_logical_name.to_owned(),
+            ))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(self
+            .team_peers
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(self
+            .role_names
+            .get(&(tenant_id, subject.to_owned()))
+            .into_iter()
+            .flatten()
+            .map(|role_name| crate::SubjectRoleGrant {
+                role_name: role_name.clone(),
+                team_name: None,
+                permissions: Vec::new(),
+            })
+            .collect())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn build_service(
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+) -> (MetadataService, Arc<FakeAuditRepository>) {
+    build_service_with_runtime_field_grants(grants, HashMap::new())
+}
+
+fn build_service_with_runtime_field_grants(
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+    runtime_field_grants: HashMap<(TenantId, String, String), Vec<RuntimeFieldGrant>>,
+) -> (MetadataService, Arc<FakeAuditRepository>) {
+    build_service_with_team_peers(grants, runtime_field_grants, HashMap::new())
+}
+
+fn build_service_with_team_peers(
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+    runtime_field_grants: HashMap<(TenantId, String, String), Vec<RuntimeFieldGrant>>,
+    team_peers: HashMap<(TenantId, String), Vec<String>>,
+) -> (MetadataService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants,
+            runtime_field_grants,
+            team_peers,
+            role_names: HashMap::new(),
+        }),
+        audit_repository.clone(),
     );
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "nickname".to_owned(),
-                    display_name: "Nickname".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: false,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
-            .await
-            .is_ok()
+    let service = MetadataService::new(
+        Arc::new(FakeRepository::new()),
+        authorization_service,
+        audit_repository.clone(),
     );
-    assert!(service.publish_entity(&actor, "contact").await.is_ok());
+    (service, audit_repository)
+}
 
-    assert!(
+async fn register_publish_entity_with_text_fields(
+    service: &MetadataService,
+    actor: &UserIdentity,
+    entity_logical_name: &str,
+    entity_display_name: &str,
+    fields: &[&str],
+) -> AppResult<()> {
+    service
+        .register_entity(actor, entity_logical_name, entity_display_name)
+        .await?;
+
+    for (index, field_logical_name) in fields.iter().enumerate() {
         service
             .save_field(
-                &actor,
+                actor,
                 SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "nickname".to_owned(),
-                    display_name: "Nickname".to_owned(),
+                    entity_logical_name: entity_logical_name.to_owned(),
+                    logical_name: (*field_logical_name).to_owned(),
+                    display_name: (*field_logical_name).to_owned(),
                     field_type: FieldType::Text,
-                    is_required: true,
+                    is_required: index == 0,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
-            .await
-            .is_ok()
-    );
+            .await?;
+    }
 
-    let checks = service.publish_checks(&actor, "contact").await;
-    assert!(checks.is_ok());
-    let errors = checks.unwrap_or_default();
-    assert!(errors.iter().any(|message| {
-        message.contains("compatibility check failed")
-            && message.contains("cannot be tightened from optional to required")
-    }));
+    service.publish_entity(actor, entity_logical_name).await?;
+    Ok(())
 }
 
 #[tokio::test]
-async fn publish_checks_report_compatibility_break_when_field_becomes_unique() {
+async fn register_entity_persists_data_and_writes_audit_event() {
     let tenant_id = TenantId::new();
-    let subject = "nora";
+    let subject = "alice";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataEntityRead,
+            Permission::MetadataFieldWrite,
+        ],
+    )]);
+    let (service, audit_repository) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    let created = service.register_entity(&actor, "contact", "Contact").await;
+    assert!(created.is_ok());
+
+    let entities = service.list_entities(&actor).await;
+    assert!(entities.is_ok());
+    assert_eq!(entities.unwrap_or_default().len(), 1);
+
+    let events = audit_repository.events.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].action, AuditAction::MetadataEntityCreated);
+    assert_eq!(events[0].resource_id, "contact");
+}
+
+#[tokio::test]
+async fn save_field_requires_field_write_permission() {
+    let tenant_id = TenantId::new();
+    let subject = "bob";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![Permission::MetadataEntityCreate],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+    let created = service.register_entity(&actor, "account", "Account").await;
+    assert!(created.is_err());
+}
+
+#[tokio::test]
+async fn publish_entity_requires_fields() {
+    let tenant_id = TenantId::new();
+    let subject = "carol";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::MetadataFieldRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    let created = service.register_entity(&actor, "contact", "Contact").await;
+    assert!(created.is_ok());
+
+    let publish_result = service.publish_entity(&actor, "contact").await;
+    assert!(matches!(publish_result, Err(AppError::Validation(_))));
+}
+
+#[tokio::test]
+async fn publish_checks_report_missing_fields() {
+    let tenant_id = TenantId::new();
+    let subject = "carol";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::MetadataFieldRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    let created = service.register_entity(&actor, "contact", "Contact").await;
+    assert!(created.is_ok());
+
+    let result = service.publish_checks(&actor, "contact").await;
+    assert!(result.is_ok());
+    let errors = result.unwrap_or_default();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("requires at least one field"));
+}
+
+#[tokio::test]
+async fn publish_checks_pass_for_valid_draft() {
+    let tenant_id = TenantId::new();
+    let subject = "olivia";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
@@ -1605,54 +2295,115 @@ async fn publish_checks_report_compatibility_break_when_field_becomes_unique() {
                 &actor,
                 SaveFieldInput {
                     entity_logical_name: "contact".to_owned(),
-                    logical_name: "email".to_owned(),
-                    display_name: "Email".to_owned(),
+                    logical_name: "name".to_owned(),
+                    display_name: "Name".to_owned(),
                     field_type: FieldType::Text,
-                    is_required: false,
+                    is_required: true,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
-    assert!(service.publish_entity(&actor, "contact").await.is_ok());
 
+    let result = service.publish_checks(&actor, "contact").await;
+    assert!(result.is_ok());
+    assert!(result.unwrap_or_default().is_empty());
+}
+
+#[tokio::test]
+async fn publish_checks_flag_unknown_entity_icons() {
+    let tenant_id = TenantId::new();
+    let subject = "iris";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::MetadataFieldRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    assert!(
+        service
+            .register_entity_with_details(
+                &actor,
+                "contact",
+                "Contact",
+                None,
+                None,
+                Some("spaceship".to_owned()),
+                Some("#1F6FEB".to_owned()),
+            )
+            .await
+            .is_ok()
+    );
     assert!(
         service
             .save_field(
                 &actor,
                 SaveFieldInput {
                     entity_logical_name: "contact".to_owned(),
-                    logical_name: "email".to_owned(),
-                    display_name: "Email".to_owned(),
+                    logical_name: "name".to_owned(),
+                    display_name: "Name".to_owned(),
                     field_type: FieldType::Text,
-                    is_required: false,
-                    is_unique: true,
+                    is_required: true,
+                    is_unique: false,
                     default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
 
-    let checks = service.publish_checks(&actor, "contact").await;
-    assert!(checks.is_ok());
-    let errors = checks.unwrap_or_default();
-    assert!(errors.iter().any(|message| {
-        message.contains("compatibility check failed")
-            && message.contains("cannot be tightened from non-unique to unique")
-    }));
+    let errors = service
+        .publish_checks(&actor, "contact")
+        .await
+        .unwrap_or_default();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("icon 'spaceship' is not in the entity icon catalog"));
+    assert!(matches!(
+        service.publish_entity(&actor, "contact").await,
+        Err(AppError::Validation(_))
+    ));
+
+    let updated = service
+        .update_entity(
+            &actor,
+            UpdateEntityInput {
+                logical_name: "contact".to_owned(),
+                display_name: "Contact".to_owned(),
+                description: None,
+                plural_display_name: None,
+                icon: Some("users".to_owned()),
+                color: Some("#1F6FEB".to_owned()),
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(updated.color(), Some("#1f6feb"));
+
+    let published = service
+        .publish_entity(&actor, "contact")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(published.entity().icon(), Some("users"));
+    assert_eq!(published.entity().color(), Some("#1f6feb"));
 }
 
 #[tokio::test]
-async fn publish_checks_report_compatibility_break_when_max_length_is_added_after_publish() {
+async fn publish_checks_report_relation_target_dependencies() {
     let tenant_id = TenantId::new();
     let subject = "nora";
     let grants = HashMap::from([(
@@ -1672,57 +2423,65 @@ async fn publish_checks_report_compatibility_break_when_max_length_is_added_afte
             .await
             .is_ok()
     );
+    assert!(
+        service
+            .register_entity(&actor, "account", "Account")
+            .await
+            .is_ok()
+    );
     assert!(
         service
             .save_field(
                 &actor,
                 SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "note".to_owned(),
-                    display_name: "Note".to_owned(),
+                    entity_logical_name: "account".to_owned(),
+                    logical_name: "name".to_owned(),
+                    display_name: "Name".to_owned(),
                     field_type: FieldType::Text,
-                    is_required: false,
+                    is_required: true,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "account_id".to_owned(),
+                    display_name: "Account".to_owned(),
+                    field_type: FieldType::Relation,
+                    is_required: false,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: Some("account".to_owned()),
+                    option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
-    assert!(service.publish_entity(&actor, "contact").await.is_ok());
-
-    let updated = service
-        .update_field(
-            &actor,
-            UpdateFieldInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "note".to_owned(),
-                display_name: "Note".to_owned(),
-                description: None,
-                default_value: None,
-                calculation_expression: None,
-                max_length: Some(255),
-                min_value: None,
-                max_value: None,
-            },
-        )
-        .await;
-    assert!(updated.is_ok());
 
-    let checks = service.publish_checks(&actor, "contact").await;
-    assert!(checks.is_ok());
-    let errors = checks.unwrap_or_default();
-    assert!(errors.iter().any(|message| {
-        message.contains("compatibility check failed")
-            && message.contains("cannot add a max_length constraint after publish")
+    let result = service.publish_checks(&actor, "contact").await;
+    assert!(result.is_ok());
+    let errors = result.unwrap_or_default();
+    assert!(errors.iter().any(|error| {
+        error.contains("requires a published schema or inclusion in this publish selection")
     }));
 }
 
 #[tokio::test]
-async fn publish_checks_report_compatibility_break_when_minimum_is_increased() {
+async fn publish_checks_allow_selected_unpublished_relation_dependencies() {
     let tenant_id = TenantId::new();
     let subject = "nora";
     let grants = HashMap::from([(
@@ -1738,7 +2497,13 @@ async fn publish_checks_report_compatibility_break_when_minimum_is_increased() {
 
     assert!(
         service
-            .register_entity(&actor, "invoice", "Invoice")
+            .register_entity(&actor, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .register_entity(&actor, "account", "Account")
             .await
             .is_ok()
     );
@@ -1747,69 +2512,37 @@ async fn publish_checks_report_compatibility_break_when_minimum_is_increased() {
             .save_field(
                 &actor,
                 SaveFieldInput {
-                    entity_logical_name: "invoice".to_owned(),
-                    logical_name: "amount".to_owned(),
-                    display_name: "Amount".to_owned(),
-                    field_type: FieldType::Number,
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "account_id".to_owned(),
+                    display_name: "Account".to_owned(),
+                    field_type: FieldType::Relation,
                     is_required: false,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
-                    relation_target_entity: None,
+                    relation_target_entity: Some("account".to_owned()),
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
-    let initial_updated = service
-        .update_field(
+
+    let result = service
+        .publish_checks_with_allowed_unpublished_entities(
             &actor,
-            UpdateFieldInput {
-                entity_logical_name: "invoice".to_owned(),
-                logical_name: "amount".to_owned(),
-                display_name: "Amount".to_owned(),
-                description: None,
-                default_value: None,
-                calculation_expression: None,
-                max_length: None,
-                min_value: Some(0.0),
-                max_value: None,
-            },
-        )
-        .await;
-    assert!(initial_updated.is_ok());
-    assert!(service.publish_entity(&actor, "invoice").await.is_ok());
-
-    let tightened = service
-        .update_field(
-            &actor,
-            UpdateFieldInput {
-                entity_logical_name: "invoice".to_owned(),
-                logical_name: "amount".to_owned(),
-                display_name: "Amount".to_owned(),
-                description: None,
-                default_value: None,
-                calculation_expression: None,
-                max_length: None,
-                min_value: Some(10.0),
-                max_value: None,
-            },
+            "contact",
+            &["account".to_owned()],
         )
         .await;
-    assert!(tightened.is_ok());
 
-    let checks = service.publish_checks(&actor, "invoice").await;
-    assert!(checks.is_ok());
-    let errors = checks.unwrap_or_default();
-    assert!(errors.iter().any(|message| {
-        message.contains("compatibility check failed")
-            && message.contains("cannot increase minimum constraint from 0 to 10")
-    }));
+    assert!(result.is_ok());
+    assert!(result.unwrap_or_default().is_empty());
 }
 
 #[tokio::test]
-async fn publish_checks_report_compatibility_break_when_option_value_is_removed() {
+async fn publish_checks_report_compatibility_break_when_field_becomes_required() {
     let tenant_id = TenantId::new();
     let subject = "nora";
     let grants = HashMap::from([(
@@ -1829,38 +2562,22 @@ async fn publish_checks_report_compatibility_break_when_option_value_is_removed(
             .await
             .is_ok()
     );
-    assert!(
-        service
-            .save_option_set(
-                &actor,
-                SaveOptionSetInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "status".to_owned(),
-                    display_name: "Status".to_owned(),
-                    options: vec![
-                        OptionSetItem::new(1, "Open", None, 0).unwrap_or_else(|_| unreachable!()),
-                        OptionSetItem::new(2, "Closed", None, 1).unwrap_or_else(|_| unreachable!()),
-                    ],
-                },
-            )
-            .await
-            .is_ok()
-    );
     assert!(
         service
             .save_field(
                 &actor,
                 SaveFieldInput {
                     entity_logical_name: "contact".to_owned(),
-                    logical_name: "status".to_owned(),
-                    display_name: "Status".to_owned(),
-                    field_type: FieldType::Choice,
+                    logical_name: "nickname".to_owned(),
+                    display_name: "Nickname".to_owned(),
+                    field_type: FieldType::Text,
                     is_required: false,
                     is_unique: false,
-                    default_value: Some(json!(1)),
+                    default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
-                    option_set_logical_name: Some("status".to_owned()),
+                    option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
@@ -1870,15 +2587,20 @@ async fn publish_checks_report_compatibility_break_when_option_value_is_removed(
 
     assert!(
         service
-            .save_option_set(
+            .save_field(
                 &actor,
-                SaveOptionSetInput {
+                SaveFieldInput {
                     entity_logical_name: "contact".to_owned(),
-                    logical_name: "status".to_owned(),
-                    display_name: "Status".to_owned(),
-                    options: vec![
-                        OptionSetItem::new(1, "Open", None, 0).unwrap_or_else(|_| unreachable!()),
-                    ],
+                    logical_name: "nickname".to_owned(),
+                    display_name: "Nickname".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
@@ -1890,12 +2612,12 @@ async fn publish_checks_report_compatibility_break_when_option_value_is_removed(
     let errors = checks.unwrap_or_default();
     assert!(errors.iter().any(|message| {
         message.contains("compatibility check failed")
-            && message.contains("cannot remove option value '2'")
+            && message.contains("cannot be tightened from optional to required")
     }));
 }
 
 #[tokio::test]
-async fn publish_entity_rejects_compatibility_breaking_changes() {
+async fn publish_checks_report_compatibility_break_when_field_becomes_unique() {
     let tenant_id = TenantId::new();
     let subject = "nora";
     let grants = HashMap::from([(
@@ -1921,8 +2643,8 @@ async fn publish_entity_rejects_compatibility_breaking_changes() {
                 &actor,
                 SaveFieldInput {
                     entity_logical_name: "contact".to_owned(),
-                    logical_name: "nickname".to_owned(),
-                    display_name: "Nickname".to_owned(),
+                    logical_name: "email".to_owned(),
+                    display_name: "Email".to_owned(),
                     field_type: FieldType::Text,
                     is_required: false,
                     is_unique: false,
@@ -1930,6 +2652,7 @@ async fn publish_entity_rejects_compatibility_breaking_changes() {
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
@@ -1943,31 +2666,33 @@ async fn publish_entity_rejects_compatibility_breaking_changes() {
                 &actor,
                 SaveFieldInput {
                     entity_logical_name: "contact".to_owned(),
-                    logical_name: "nickname".to_owned(),
-                    display_name: "Nickname".to_owned(),
+                    logical_name: "email".to_owned(),
+                    display_name: "Email".to_owned(),
                     field_type: FieldType::Text,
-                    is_required: true,
-                    is_unique: false,
+                    is_required: false,
+                    is_unique: true,
                     default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
 
-    let publish = service.publish_entity(&actor, "contact").await;
-    assert!(
-        matches!(publish, Err(AppError::Validation(message)) if message
-        .contains("compatibility check failed")
-        && message.contains("cannot be tightened from optional to required"))
-    );
+    let checks = service.publish_checks(&actor, "contact").await;
+    assert!(checks.is_ok());
+    let errors = checks.unwrap_or_default();
+    assert!(errors.iter().any(|message| {
+        message.contains("compatibility check failed")
+            && message.contains("cannot be tightened from non-unique to unique")
+    }));
 }
 
 #[tokio::test]
-async fn publish_entity_allow_selected_unpublished_relation_dependencies() {
+async fn publish_checks_report_compatibility_break_when_max_length_is_added_after_publish() {
     let tenant_id = TenantId::new();
     let subject = "nora";
     let grants = HashMap::from([(
@@ -1987,150 +2712,67 @@ async fn publish_entity_allow_selected_unpublished_relation_dependencies() {
             .await
             .is_ok()
     );
-    assert!(
-        service
-            .register_entity(&actor, "account", "Account")
-            .await
-            .is_ok()
-    );
     assert!(
         service
             .save_field(
                 &actor,
                 SaveFieldInput {
                     entity_logical_name: "contact".to_owned(),
-                    logical_name: "account_id".to_owned(),
-                    display_name: "Account".to_owned(),
-                    field_type: FieldType::Relation,
+                    logical_name: "note".to_owned(),
+                    display_name: "Note".to_owned(),
+                    field_type: FieldType::Text,
                     is_required: false,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
-                    relation_target_entity: Some("account".to_owned()),
+                    relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
+    assert!(service.publish_entity(&actor, "contact").await.is_ok());
 
-    let published = service
-        .publish_entity_with_allowed_unpublished_entities(
-            &actor,
-            "contact",
-            &["account".to_owned()],
-        )
-        .await;
-
-    assert!(published.is_ok());
-    assert_eq!(published.unwrap_or_else(|_| unreachable!()).version(), 1);
-}
-
-#[tokio::test]
-async fn create_runtime_record_applies_defaults_and_writes_audit_event() {
-    let tenant_id = TenantId::new();
-    let subject = "dan";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::RuntimeRecordWrite,
-            Permission::RuntimeRecordRead,
-        ],
-    )]);
-    let (service, audit_repository) = build_service(grants);
-    let actor = actor(tenant_id, subject);
-
-    let created = service.register_entity(&actor, "contact", "Contact").await;
-    assert!(created.is_ok());
-
-    let saved_field = service
-        .save_field(
+    let updated = service
+        .update_field(
             &actor,
-            SaveFieldInput {
+            UpdateFieldInput {
                 entity_logical_name: "contact".to_owned(),
-                logical_name: "name".to_owned(),
-                display_name: "Name".to_owned(),
-                field_type: FieldType::Text,
-                is_required: true,
-                is_unique: true,
+                logical_name: "note".to_owned(),
+                display_name: "Note".to_owned(),
+                description: None,
                 default_value: None,
                 calculation_expression: None,
-                relation_target_entity: None,
-                option_set_logical_name: None,
-            },
-        )
-        .await;
-    assert!(saved_field.is_ok());
-
-    let saved_default = service
-        .save_field(
-            &actor,
-            SaveFieldInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "active".to_owned(),
-                display_name: "Active".to_owned(),
-                field_type: FieldType::Boolean,
-                is_required: false,
-                is_unique: false,
-                default_value: Some(json!(true)),
-                calculation_expression: None,
-                relation_target_entity: None,
-                option_set_logical_name: None,
-            },
-        )
-        .await;
-    assert!(saved_default.is_ok());
-
-    let published = service.publish_entity(&actor, "contact").await;
-    assert!(published.is_ok());
-
-    let created_record = service
-        .create_runtime_record(&actor, "contact", json!({"name": "Alice"}))
-        .await;
-    assert!(created_record.is_ok());
-    let created_record = created_record.unwrap_or_else(|_| unreachable!());
-
-    let data = created_record.data().as_object();
-    assert!(data.is_some());
-    assert_eq!(
-        data.and_then(|object| object.get("active")),
-        Some(&json!(true))
-    );
-
-    let listed = service
-        .list_runtime_records(
-            &actor,
-            "contact",
-            RecordListQuery {
-                limit: 20,
-                offset: 0,
-                owner_subject: None,
+                max_length: Some(255),
+                min_value: None,
+                max_value: None,
+                classification: None,
             },
         )
         .await;
-    assert!(listed.is_ok());
-    assert_eq!(listed.unwrap_or_default().len(), 1);
+    assert!(updated.is_ok());
 
-    let events = audit_repository.events.lock().await;
-    assert!(events.iter().any(|event| {
-        event.action == AuditAction::RuntimeRecordCreated
-            && event.resource_id == created_record.record_id().as_str()
+    let checks = service.publish_checks(&actor, "contact").await;
+    assert!(checks.is_ok());
+    let errors = checks.unwrap_or_default();
+    assert!(errors.iter().any(|message| {
+        message.contains("compatibility check failed")
+            && message.contains("cannot add a max_length constraint after publish")
     }));
 }
 
 #[tokio::test]
-async fn query_runtime_records_filters_and_paginates() {
+async fn publish_checks_report_compatibility_break_when_minimum_is_increased() {
     let tenant_id = TenantId::new();
-    let subject = "grace";
+    let subject = "nora";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
-            Permission::RuntimeRecordWrite,
-            Permission::RuntimeRecordRead,
+            Permission::MetadataFieldRead,
         ],
     )]);
     let (service, _) = build_service(grants);
@@ -2138,7 +2780,7 @@ async fn query_runtime_records_filters_and_paginates() {
 
     assert!(
         service
-            .register_entity(&actor, "contact", "Contact")
+            .register_entity(&actor, "invoice", "Invoice")
             .await
             .is_ok()
     );
@@ -2147,108 +2789,80 @@ async fn query_runtime_records_filters_and_paginates() {
             .save_field(
                 &actor,
                 SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "name".to_owned(),
-                    display_name: "Name".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: true,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "active".to_owned(),
-                    display_name: "Active".to_owned(),
-                    field_type: FieldType::Boolean,
-                    is_required: true,
+                    entity_logical_name: "invoice".to_owned(),
+                    logical_name: "amount".to_owned(),
+                    display_name: "Amount".to_owned(),
+                    field_type: FieldType::Number,
+                    is_required: false,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
-    assert!(service.publish_entity(&actor, "contact").await.is_ok());
-
-    assert!(
-        service
-            .create_runtime_record(&actor, "contact", json!({"name": "Alice", "active": true}))
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .create_runtime_record(&actor, "contact", json!({"name": "Bob", "active": false}))
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .create_runtime_record(&actor, "contact", json!({"name": "Carol", "active": true}))
-            .await
-            .is_ok()
-    );
+    let initial_updated = service
+        .update_field(
+            &actor,
+            UpdateFieldInput {
+                entity_logical_name: "invoice".to_owned(),
+                logical_name: "amount".to_owned(),
+                display_name: "Amount".to_owned(),
+                description: None,
+                default_value: None,
+                calculation_expression: None,
+                max_length: None,
+                min_value: Some(0.0),
+                max_value: None,
+                classification: None,
+            },
+        )
+        .await;
+    assert!(initial_updated.is_ok());
+    assert!(service.publish_entity(&actor, "invoice").await.is_ok());
 
-    let queried = service
-        .query_runtime_records(
+    let tightened = service
+        .update_field(
             &actor,
-            "contact",
-            RuntimeRecordQuery {
-                limit: 1,
-                offset: 1,
-                logical_mode: RuntimeRecordLogicalMode::And,
-                where_clause: None,
-                filters: vec![RuntimeRecordFilter {
-                    scope_alias: None,
-                    field_logical_name: "active".to_owned(),
-                    operator: RuntimeRecordOperator::Eq,
-                    field_type: FieldType::Boolean,
-                    field_value: json!(true),
-                }],
-                links: Vec::new(),
-                sort: Vec::new(),
-                owner_subject: None,
+            UpdateFieldInput {
+                entity_logical_name: "invoice".to_owned(),
+                logical_name: "amount".to_owned(),
+                display_name: "Amount".to_owned(),
+                description: None,
+                default_value: None,
+                calculation_expression: None,
+                max_length: None,
+                min_value: Some(10.0),
+                max_value: None,
+                classification: None,
             },
         )
         .await;
-    assert!(queried.is_ok());
+    assert!(tightened.is_ok());
 
-    let queried = queried.unwrap_or_default();
-    assert_eq!(queried.len(), 1);
-    assert_eq!(
-        queried[0]
-            .data()
-            .as_object()
-            .and_then(|value| value.get("active")),
-        Some(&json!(true))
-    );
+    let checks = service.publish_checks(&actor, "invoice").await;
+    assert!(checks.is_ok());
+    let errors = checks.unwrap_or_default();
+    assert!(errors.iter().any(|message| {
+        message.contains("compatibility check failed")
+            && message.contains("cannot increase minimum constraint from 0 to 10")
+    }));
 }
 
 #[tokio::test]
-async fn create_runtime_record_computes_calculated_number_field() {
+async fn publish_checks_report_compatibility_break_when_option_value_is_removed() {
     let tenant_id = TenantId::new();
-    let subject = "calc";
+    let subject = "nora";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
-            Permission::RuntimeRecordWrite,
-            Permission::RuntimeRecordRead,
+            Permission::MetadataFieldRead,
         ],
     )]);
     let (service, _) = build_service(grants);
@@ -2256,25 +2870,22 @@ async fn create_runtime_record_computes_calculated_number_field() {
 
     assert!(
         service
-            .register_entity(&actor, "line", "Line")
+            .register_entity(&actor, "contact", "Contact")
             .await
             .is_ok()
     );
     assert!(
         service
-            .save_field(
+            .save_option_set(
                 &actor,
-                SaveFieldInput {
-                    entity_logical_name: "line".to_owned(),
-                    logical_name: "quantity".to_owned(),
-                    display_name: "Quantity".to_owned(),
-                    field_type: FieldType::Number,
-                    is_required: true,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
+                SaveOptionSetInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "status".to_owned(),
+                    display_name: "Status".to_owned(),
+                    options: vec![
+                        OptionSetItem::new(1, "Open", None, 0).unwrap_or_else(|_| unreachable!()),
+                        OptionSetItem::new(2, "Closed", None, 1).unwrap_or_else(|_| unreachable!()),
+                    ],
                 },
             )
             .await
@@ -2285,69 +2896,60 @@ async fn create_runtime_record_computes_calculated_number_field() {
             .save_field(
                 &actor,
                 SaveFieldInput {
-                    entity_logical_name: "line".to_owned(),
-                    logical_name: "unit_price".to_owned(),
-                    display_name: "Unit Price".to_owned(),
-                    field_type: FieldType::Number,
-                    is_required: true,
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "status".to_owned(),
+                    display_name: "Status".to_owned(),
+                    field_type: FieldType::Choice,
+                    is_required: false,
                     is_unique: false,
-                    default_value: None,
+                    default_value: Some(json!(1)),
                     calculation_expression: None,
                     relation_target_entity: None,
-                    option_set_logical_name: None,
+                    option_set_logical_name: Some("status".to_owned()),
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
+    assert!(service.publish_entity(&actor, "contact").await.is_ok());
+
     assert!(
         service
-            .save_field(
+            .save_option_set(
                 &actor,
-                SaveFieldInput {
-                    entity_logical_name: "line".to_owned(),
-                    logical_name: "total".to_owned(),
-                    display_name: "Total".to_owned(),
-                    field_type: FieldType::Number,
-                    is_required: false,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: Some("add(quantity, unit_price)".to_owned()),
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
+                SaveOptionSetInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "status".to_owned(),
+                    display_name: "Status".to_owned(),
+                    options: vec![
+                        OptionSetItem::new(1, "Open", None, 0).unwrap_or_else(|_| unreachable!()),
+                    ],
                 },
             )
             .await
             .is_ok()
     );
-    assert!(service.publish_entity(&actor, "line").await.is_ok());
-
-    let created = service
-        .create_runtime_record(&actor, "line", json!({"quantity": 2, "unit_price": 5}))
-        .await;
-    assert!(created.is_ok());
-    let created = created.unwrap_or_else(|_| unreachable!());
 
-    assert_eq!(
-        created
-            .data()
-            .as_object()
-            .and_then(|value| value.get("total")),
-        Some(&json!(7.0))
-    );
+    let checks = service.publish_checks(&actor, "contact").await;
+    assert!(checks.is_ok());
+    let errors = checks.unwrap_or_default();
+    assert!(errors.iter().any(|message| {
+        message.contains("compatibility check failed")
+            && message.contains("cannot remove option value '2'")
+    }));
 }
 
 #[tokio::test]
-async fn create_runtime_record_rejects_direct_value_for_calculated_field() {
+async fn publish_entity_rejects_compatibility_breaking_changes() {
     let tenant_id = TenantId::new();
-    let subject = "calc_guard";
+    let subject = "nora";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
-            Permission::RuntimeRecordWrite,
-            Permission::RuntimeRecordRead,
+            Permission::MetadataFieldRead,
         ],
     )]);
     let (service, _) = build_service(grants);
@@ -2355,7 +2957,7 @@ async fn create_runtime_record_rejects_direct_value_for_calculated_field() {
 
     assert!(
         service
-            .register_entity(&actor, "person", "Person")
+            .register_entity(&actor, "contact", "Contact")
             .await
             .is_ok()
     );
@@ -2364,29 +2966,32 @@ async fn create_runtime_record_rejects_direct_value_for_calculated_field() {
             .save_field(
                 &actor,
                 SaveFieldInput {
-                    entity_logical_name: "person".to_owned(),
-                    logical_name: "first_name".to_owned(),
-                    display_name: "First Name".to_owned(),
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "nickname".to_owned(),
+                    display_name: "Nickname".to_owned(),
                     field_type: FieldType::Text,
-                    is_required: true,
+                    is_required: false,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
+    assert!(service.publish_entity(&actor, "contact").await.is_ok());
+
     assert!(
         service
             .save_field(
                 &actor,
                 SaveFieldInput {
-                    entity_logical_name: "person".to_owned(),
-                    logical_name: "last_name".to_owned(),
-                    display_name: "Last Name".to_owned(),
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "nickname".to_owned(),
+                    display_name: "Nickname".to_owned(),
                     field_type: FieldType::Text,
                     is_required: true,
                     is_unique: false,
@@ -2394,57 +2999,31 @@ async fn create_runtime_record_rejects_direct_value_for_calculated_field() {
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
-    assert!(service
-        .save_field(
-            &actor,
-            SaveFieldInput {
-                entity_logical_name: "person".to_owned(),
-                logical_name: "full_name".to_owned(),
-                display_name: "Full Name".to_owned(),
-                field_type: FieldType::Text,
-                is_required: false,
-                is_unique: false,
-                default_value: None,
-                calculation_expression: Some("concat(first_name, \" \", last_name)".to_owned(),),
-                relation_target_entity: None,
-                option_set_logical_name: None,
-            },
-        )
-        .await
-        .is_ok());
-    assert!(service.publish_entity(&actor, "person").await.is_ok());
-
-    let result = service
-        .create_runtime_record(
-            &actor,
-            "person",
-            json!({
-                "first_name": "Ada",
-                "last_name": "Lovelace",
-                "full_name": "Injected Value"
-            }),
-        )
-        .await;
-
-    assert!(matches!(result, Err(AppError::Validation(_))));
-}
 
-#[tokio::test]
-async fn create_runtime_record_blocks_on_entity_business_rule_show_error() {
+    let publish = service.publish_entity(&actor, "contact").await;
+    assert!(
+        matches!(publish, Err(AppError::Validation(message)) if message
+        .contains("compatibility check failed")
+        && message.contains("cannot be tightened from optional to required"))
+    );
+}
+
+#[tokio::test]
+async fn publish_entity_allow_selected_unpublished_relation_dependencies() {
     let tenant_id = TenantId::new();
-    let subject = "rule_creator";
+    let subject = "nora";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
-            Permission::RuntimeRecordWrite,
-            Permission::RuntimeRecordRead,
+            Permission::MetadataFieldRead,
         ],
     )]);
     let (service, _) = build_service(grants);
@@ -2458,21 +3037,7 @@ async fn create_runtime_record_blocks_on_entity_business_rule_show_error() {
     );
     assert!(
         service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "name".to_owned(),
-                    display_name: "Name".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: true,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
+            .register_entity(&actor, "account", "Account")
             .await
             .is_ok()
     );
@@ -2482,165 +3047,240 @@ async fn create_runtime_record_blocks_on_entity_business_rule_show_error() {
                 &actor,
                 SaveFieldInput {
                     entity_logical_name: "contact".to_owned(),
-                    logical_name: "active".to_owned(),
-                    display_name: "Active".to_owned(),
-                    field_type: FieldType::Boolean,
-                    is_required: true,
+                    logical_name: "account_id".to_owned(),
+                    display_name: "Account".to_owned(),
+                    field_type: FieldType::Relation,
+                    is_required: false,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
-                    relation_target_entity: None,
+                    relation_target_entity: Some("account".to_owned()),
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
-    assert!(service.publish_entity(&actor, "contact").await.is_ok());
 
-    let condition = BusinessRuleCondition::new("active", BusinessRuleOperator::Eq, json!(false));
-    assert!(condition.is_ok());
-    let action = BusinessRuleAction::new(
-        BusinessRuleActionType::ShowError,
-        None,
-        None,
-        Some("Active must be true".to_owned()),
-    );
-    assert!(action.is_ok());
-    let saved_rule = service
-        .save_business_rule(
+    let published = service
+        .publish_entity_with_allowed_unpublished_entities(
             &actor,
-            SaveBusinessRuleInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "active_guard".to_owned(),
-                display_name: "Active Guard".to_owned(),
-                scope: BusinessRuleScope::Entity,
-                form_logical_name: None,
-                conditions: vec![condition.unwrap_or_else(|_| unreachable!())],
-                actions: vec![action.unwrap_or_else(|_| unreachable!())],
-                is_active: true,
-            },
+            "contact",
+            &["account".to_owned()],
         )
         .await;
-    assert!(saved_rule.is_ok());
 
-    let result = service
-        .create_runtime_record(&actor, "contact", json!({"name": "Alice", "active": false}))
-        .await;
-    assert!(matches!(result, Err(AppError::Validation(_))));
+    assert!(published.is_ok());
+    assert_eq!(published.unwrap_or_else(|_| unreachable!()).version(), 1);
 }
 
 #[tokio::test]
-async fn update_runtime_record_blocks_on_entity_business_rule_show_error() {
+async fn rollback_entity_schema_republishes_prior_version_after_data_checks() {
     let tenant_id = TenantId::new();
-    let subject = "rule_editor";
+    let subject = "nora";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
+            Permission::MetadataFieldRead,
             Permission::RuntimeRecordWrite,
             Permission::RuntimeRecordRead,
         ],
     )]);
-    let (service, _) = build_service(grants);
+    let (service, audit_repository) = build_service(grants);
     let actor = actor(tenant_id, subject);
 
     assert!(
-        service
-            .register_entity(&actor, "contact", "Contact")
+        register_publish_entity_with_text_fields(&service, &actor, "contact", "Contact", &["name"])
             .await
             .is_ok()
     );
+    for (logical_name, is_required) in [("name", false), ("nickname", false)] {
+        assert!(
+            service
+                .save_field(
+                    &actor,
+                    SaveFieldInput {
+                        entity_logical_name: "contact".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type: FieldType::Text,
+                        is_required,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity: None,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(service.publish_entity(&actor, "contact").await.is_ok());
+
+    let checks = service
+        .rollback_entity_schema_checks(&actor, "contact", 1)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(checks.can_rollback());
+    assert_eq!(checks.current_version, 2);
+    assert_eq!(checks.orphaned_fields, vec!["nickname".to_owned()]);
+
+    let record = service
+        .create_runtime_record(&actor, "contact", json!({"nickname": "Al"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let checks = service
+        .rollback_entity_schema_checks(&actor, "contact", 1)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(!checks.can_rollback());
+    assert!(checks.errors[0].contains("'contact.name' would become required"));
+    assert!(matches!(
+        service.rollback_entity_schema(&actor, "contact", 1).await,
+        Err(AppError::Validation(message)) if message.contains("rollback checks failed")
+    ));
+
     assert!(
         service
-            .save_field(
+            .update_runtime_record(
                 &actor,
-                SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "name".to_owned(),
-                    display_name: "Name".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: true,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
+                "contact",
+                record.record_id().as_str(),
+                json!({"name": "Alice", "nickname": "Al"}),
             )
             .await
             .is_ok()
     );
-    assert!(
+    let rolled_back = service
+        .rollback_entity_schema(&actor, "contact", 1)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(rolled_back.version(), 3);
+    assert_eq!(rolled_back.fields().len(), 1);
+    assert!(rolled_back.fields()[0].is_required());
+    assert!(matches!(
         service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "active".to_owned(),
-                    display_name: "Active".to_owned(),
-                    field_type: FieldType::Boolean,
-                    is_required: true,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
-            .await
-            .is_ok()
-    );
-    assert!(service.publish_entity(&actor, "contact").await.is_ok());
+            .rollback_entity_schema_checks(&actor, "contact", 3)
+            .await,
+        Err(AppError::Validation(_))
+    ));
 
-    let condition = BusinessRuleCondition::new("active", BusinessRuleOperator::Eq, json!(false));
-    assert!(condition.is_ok());
-    let action = BusinessRuleAction::new(
-        BusinessRuleActionType::ShowError,
-        None,
-        None,
-        Some("Active must be true".to_owned()),
-    );
-    assert!(action.is_ok());
-    let saved_rule = service
-        .save_business_rule(
+    let events = audit_repository.events.lock().await;
+    assert!(events.iter().any(|event| {
+        event.action == AuditAction::MetadataEntitySchemaRolledBack
+            && event
+                .detail
+                .as_deref()
+                .is_some_and(|detail| detail.contains("orphaned fields: nickname"))
+    }));
+}
+
+#[tokio::test]
+async fn create_runtime_record_applies_defaults_and_writes_audit_event() {
+    let tenant_id = TenantId::new();
+    let subject = "dan";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
+        ],
+    )]);
+    let (service, audit_repository) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    let created = service.register_entity(&actor, "contact", "Contact").await;
+    assert!(created.is_ok());
+
+    let saved_field = service
+        .save_field(
             &actor,
-            SaveBusinessRuleInput {
+            SaveFieldInput {
                 entity_logical_name: "contact".to_owned(),
-                logical_name: "active_guard".to_owned(),
-                display_name: "Active Guard".to_owned(),
-                scope: BusinessRuleScope::Entity,
-                form_logical_name: None,
-                conditions: vec![condition.unwrap_or_else(|_| unreachable!())],
-                actions: vec![action.unwrap_or_else(|_| unreachable!())],
-                is_active: true,
+                logical_name: "name".to_owned(),
+                display_name: "Name".to_owned(),
+                field_type: FieldType::Text,
+                is_required: true,
+                is_unique: true,
+                default_value: None,
+                calculation_expression: None,
+                relation_target_entity: None,
+                option_set_logical_name: None,
+                classification: None,
             },
         )
         .await;
-    assert!(saved_rule.is_ok());
+    assert!(saved_field.is_ok());
 
-    let created = service
-        .create_runtime_record(&actor, "contact", json!({"name": "Alice", "active": true}))
+    let saved_default = service
+        .save_field(
+            &actor,
+            SaveFieldInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "active".to_owned(),
+                display_name: "Active".to_owned(),
+                field_type: FieldType::Boolean,
+                is_required: false,
+                is_unique: false,
+                default_value: Some(json!(true)),
+                calculation_expression: None,
+                relation_target_entity: None,
+                option_set_logical_name: None,
+                classification: None,
+            },
+        )
         .await;
-    assert!(created.is_ok());
-    let created = created.unwrap_or_else(|_| unreachable!());
+    assert!(saved_default.is_ok());
 
-    let result = service
-        .update_runtime_record(
+    let published = service.publish_entity(&actor, "contact").await;
+    assert!(published.is_ok());
+
+    let created_record = service
+        .create_runtime_record(&actor, "contact", json!({"name": "Alice"}))
+        .await;
+    assert!(created_record.is_ok());
+    let created_record = created_record.unwrap_or_else(|_| unreachable!());
+
+    let data = created_record.data().as_object();
+    assert!(data.is_some());
+    assert_eq!(
+        data.and_then(|object| object.get("active")),
+        Some(&json!(true))
+    );
+
+    let listed = service
+        .list_runtime_records(
             &actor,
             "contact",
-            created.record_id().as_str(),
-            json!({"name": "Alice", "active": false}),
+            RecordListQuery {
+                limit: 20,
+                offset: 0,
+                owner_subject: None,
+                include_inactive: false,
+            },
         )
         .await;
-    assert!(matches!(result, Err(AppError::Validation(_))));
+    assert!(listed.is_ok());
+    assert_eq!(listed.unwrap_or_default().len(), 1);
+
+    let events = audit_repository.events.lock().await;
+    assert!(events.iter().any(|event| {
+        event.action == AuditAction::RuntimeRecordCreated
+            && event.resource_id == created_record.record_id().as_str()
+    }));
 }
 
 #[tokio::test]
-async fn create_runtime_record_applies_entity_business_rule_value_actions() {
+async fn create_runtime_record_with_idempotency_key_returns_the_first_record() {
     let tenant_id = TenantId::new();
-    let subject = "rule_value_actions";
+    let subject = "dan";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
@@ -2650,136 +3290,172 @@ async fn create_runtime_record_applies_entity_business_rule_value_actions() {
             Permission::RuntimeRecordRead,
         ],
     )]);
-    let (service, _) = build_service(grants);
+    let (service, audit_repository) = build_service(grants);
     let actor = actor(tenant_id, subject);
 
-    assert!(
-        service
-            .register_entity(&actor, "lead", "Lead")
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "lead".to_owned(),
-                    logical_name: "name".to_owned(),
-                    display_name: "Name".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: true,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "lead".to_owned(),
-                    logical_name: "stage".to_owned(),
-                    display_name: "Stage".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: false,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "lead".to_owned(),
-                    logical_name: "tier".to_owned(),
-                    display_name: "Tier".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: false,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
-            .await
-            .is_ok()
-    );
-    assert!(service.publish_entity(&actor, "lead").await.is_ok());
-
-    let condition =
-        BusinessRuleCondition::new("name", BusinessRuleOperator::Contains, json!("acme"));
-    assert!(condition.is_ok());
-    let set_default_stage = BusinessRuleAction::new(
-        BusinessRuleActionType::SetDefaultValue,
-        Some("stage".to_owned()),
-        Some(json!("new")),
-        None,
-    );
-    assert!(set_default_stage.is_ok());
-    let set_tier = BusinessRuleAction::new(
-        BusinessRuleActionType::SetFieldValue,
-        Some("tier".to_owned()),
-        Some(json!("vip")),
-        None,
-    );
-    assert!(set_tier.is_ok());
-
-    let saved_rule = service
-        .save_business_rule(
+    let created = service.register_entity(&actor, "contact", "Contact").await;
+    assert!(created.is_ok());
+    let saved_field = service
+        .save_field(
             &actor,
-            SaveBusinessRuleInput {
-                entity_logical_name: "lead".to_owned(),
-                logical_name: "lead_defaults".to_owned(),
-                display_name: "Lead Defaults".to_owned(),
-                scope: BusinessRuleScope::Entity,
-                form_logical_name: None,
-                conditions: vec![condition.unwrap_or_else(|_| unreachable!())],
-                actions: vec![
-                    set_default_stage.unwrap_or_else(|_| unreachable!()),
-                    set_tier.unwrap_or_else(|_| unreachable!()),
-                ],
-                is_active: true,
+            SaveFieldInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "name".to_owned(),
+                display_name: "Name".to_owned(),
+                field_type: FieldType::Text,
+                is_required: true,
+                is_unique: true,
+                default_value: None,
+                calculation_expression: None,
+                relation_target_entity: None,
+                option_set_logical_name: None,
+                classification: None,
             },
         )
         .await;
-    assert!(saved_rule.is_ok());
+    assert!(saved_field.is_ok());
+    let published = service.publish_entity(&actor, "contact").await;
+    assert!(published.is_ok());
 
-    let created = service
-        .create_runtime_record(&actor, "lead", json!({"name": "Acme Corporation"}))
+    let first = service
+        .create_runtime_record_with_idempotency_key(
+            &actor,
+            "contact",
+            json!({"name": "Alice"}),
+            false,
+            Some("contact-import-1"),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let replayed = service
+        .create_runtime_record_with_idempotency_key(
+            &actor,
+            "contact",
+            json!({"name": "Alice"}),
+            false,
+            Some("contact-import-1"),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(replayed.record_id(), first.record_id());
+
+    let invalid_key = service
+        .create_runtime_record_with_idempotency_key(
+            &actor,
+            "contact",
+            json!({"name": "Bob"}),
+            false,
+            Some("contact import"),
+        )
         .await;
-    assert!(created.is_ok());
-    let created = created.unwrap_or_else(|_| unreachable!());
-    let data = created.data().as_object();
-    assert!(data.is_some());
-    assert_eq!(
-        data.and_then(|object| object.get("stage")),
-        Some(&json!("new"))
-    );
+    assert!(matches!(invalid_key, Err(AppError::Validation(_))));
+
+    let events = audit_repository.events.lock().await;
     assert_eq!(
-        data.and_then(|object| object.get("tier")),
-        Some(&json!("vip"))
+        events
+            .iter()
+            .filter(|event| event.action == AuditAction::RuntimeRecordCreated)
+            .count(),
+        1
     );
 }
 
 #[tokio::test]
-async fn create_runtime_record_honors_requiredness_overrides() {
+async fn create_runtime_record_with_idempotency_key_does_not_replay_other_subjects_records() {
     let tenant_id = TenantId::new();
-    let subject = "rule_required_overrides";
+    let grants = HashMap::from([
+        (
+            (tenant_id, "dan".to_owned()),
+            vec![
+                Permission::MetadataEntityCreate,
+                Permission::MetadataFieldWrite,
+                Permission::RuntimeRecordWrite,
+                Permission::RuntimeRecordRead,
+            ],
+        ),
+        (
+            (tenant_id, "erin".to_owned()),
+            vec![
+                Permission::RuntimeRecordWriteOwn,
+                Permission::RuntimeRecordReadOwn,
+            ],
+        ),
+        (
+            (tenant_id, "fay".to_owned()),
+            vec![
+                Permission::RuntimeRecordWrite,
+                Permission::RuntimeRecordRead,
+            ],
+        ),
+    ]);
+    let (service, _) = build_service(grants);
+    let owner = actor(tenant_id, "dan");
+    let own_scope_actor = actor(tenant_id, "erin");
+    let all_scope_actor = actor(tenant_id, "fay");
+
+    let created = service.register_entity(&owner, "contact", "Contact").await;
+    assert!(created.is_ok());
+    let saved_field = service
+        .save_field(
+            &owner,
+            SaveFieldInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "name".to_owned(),
+                display_name: "Name".to_owned(),
+                field_type: FieldType::Text,
+                is_required: true,
+                is_unique: false,
+                default_value: None,
+                calculation_expression: None,
+                relation_target_entity: None,
+                option_set_logical_name: None,
+                classification: None,
+            },
+        )
+        .await;
+    assert!(saved_field.is_ok());
+    let published = service.publish_entity(&owner, "contact").await;
+    assert!(published.is_ok());
+
+    let first = service
+        .create_runtime_record_with_idempotency_key(
+            &owner,
+            "contact",
+            json!({"name": "Alice"}),
+            false,
+            Some("contact-import-1"),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let other_subject = service
+        .create_runtime_record_with_idempotency_key(
+            &own_scope_actor,
+            "contact",
+            json!({"name": "Mallory"}),
+            false,
+            Some("contact-import-1"),
+        )
+        .await;
+    assert!(matches!(other_subject, Err(AppError::Conflict(_))));
+
+    let readable_replay = service
+        .create_runtime_record_with_idempotency_key(
+            &all_scope_actor,
+            "contact",
+            json!({"name": "Alice"}),
+            false,
+            Some("contact-import-1"),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(readable_replay.record_id(), first.record_id());
+}
+
+#[tokio::test]
+async fn quick_create_runtime_record_accepts_only_quick_create_form_fields() {
+    let tenant_id = TenantId::new();
+    let subject = "quinn";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
@@ -2789,147 +3465,327 @@ async fn create_runtime_record_honors_requiredness_overrides() {
             Permission::RuntimeRecordRead,
         ],
     )]);
-    let (service, _) = build_service(grants);
+    let (service, audit_repository) = build_service(grants);
     let actor = actor(tenant_id, subject);
 
-    assert!(
-        service
-            .register_entity(&actor, "contact", "Contact")
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
+    let seeded = register_publish_entity_with_text_fields(
+        &service,
+        &actor,
+        "phone_call",
+        "Phone Call",
+        &["subject", "phone_number", "notes"],
+    )
+    .await;
+    assert!(seeded.is_ok());
+
+    let without_form = service
+        .quick_create_runtime_record(&actor, "phone_call", None, json!({"subject": "Call"}))
+        .await;
+    assert!(matches!(without_form, Err(AppError::NotFound(_))));
+
+    let placements = ["subject", "phone_number"]
+        .iter()
+        .enumerate()
+        .map(|(row, field)| {
+            FormFieldPlacement::new(*field, 0, row as i32, true, false, None, None)
+                .unwrap_or_else(|_| unreachable!())
+        })
+        .collect();
+    let section = FormSection::new("quick", "Quick", 0, true, 1, placements, Vec::new())
+        .unwrap_or_else(|_| unreachable!());
+    let tab =
+        FormTab::new("quick", "Quick", 0, true, vec![section]).unwrap_or_else(|_| unreachable!());
+    let saved_form = service
+        .save_form(
+            &actor,
+            SaveFormInput {
+                entity_logical_name: "phone_call".to_owned(),
+                logical_name: "dialer".to_owned(),
+                display_name: "Dialer".to_owned(),
+                form_type: FormType::QuickCreate,
+                tabs: vec![tab],
+                header_fields: Vec::new(),
+                script_events: FormScriptEvents::default(),
+            },
+        )
+        .await;
+    assert!(saved_form.is_ok());
+    assert!(service.publish_entity(&actor, "phone_call").await.is_ok());
+
+    let extra_field = service
+        .quick_create_runtime_record(
+            &actor,
+            "phone_call",
+            None,
+            json!({"subject": "Call", "notes": "left voicemail"}),
+        )
+        .await;
+    assert!(matches!(
+        extra_field,
+        Err(AppError::Validation(message)) if message.contains("'notes' is not on quick-create form 'dialer'")
+    ));
+
+    let main_form = service
+        .quick_create_runtime_record(
+            &actor,
+            "phone_call",
+            Some("main_form"),
+            json!({"subject": "Call"}),
+        )
+        .await;
+    assert!(matches!(main_form, Err(AppError::Validation(_))));
+
+    let created = service
+        .quick_create_runtime_record(
+            &actor,
+            "phone_call",
+            Some("dialer"),
+            json!({"subject": "Inbound call", "phone_number": "+15550100"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(created.data()["phone_number"], json!("+15550100"));
+
+    let events = audit_repository.events.lock().await;
+    assert!(events.iter().any(|event| {
+        event.action == AuditAction::RuntimeRecordCreated
+            && event.resource_id == created.record_id().as_str()
+    }));
+}
+
+async fn setup_changeset_entities(service: &MetadataService, actor: &UserIdentity) {
+    for (entity_logical_name, display_name) in [("account", "Account"), ("contact", "Contact")] {
+        let registered = service
+            .register_entity(actor, entity_logical_name, display_name)
+            .await;
+        assert!(registered.is_ok());
+    }
+
+    for (entity_logical_name, logical_name, field_type, is_unique, relation_target_entity) in [
+        ("account", "name", FieldType::Text, true, None),
+        ("contact", "name", FieldType::Text, false, None),
+        (
+            "contact",
+            "account_id",
+            FieldType::Relation,
+            false,
+            Some("account".to_owned()),
+        ),
+    ] {
+        let saved = service
             .save_field(
-                &actor,
+                actor,
                 SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "name".to_owned(),
-                    display_name: "Name".to_owned(),
-                    field_type: FieldType::Text,
+                    entity_logical_name: entity_logical_name.to_owned(),
+                    logical_name: logical_name.to_owned(),
+                    display_name: logical_name.to_owned(),
+                    field_type,
                     is_required: true,
-                    is_unique: false,
+                    is_unique,
                     default_value: None,
                     calculation_expression: None,
-                    relation_target_entity: None,
+                    relation_target_entity,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "phone".to_owned(),
-                    display_name: "Phone".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: false,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
-            .await
-            .is_ok()
+            .await;
+        assert!(saved.is_ok());
+    }
+
+    for entity_logical_name in ["account", "contact"] {
+        let published = service.publish_entity(actor, entity_logical_name).await;
+        assert!(published.is_ok());
+    }
+}
+
+fn changeset_operation(
+    content_id: Option<&str>,
+    method: RuntimeRecordChangesetMethod,
+    entity_logical_name: &str,
+    record_id: Option<&str>,
+    data: Value,
+) -> RuntimeRecordChangesetOperation {
+    RuntimeRecordChangesetOperation {
+        content_id: content_id.map(ToOwned::to_owned),
+        method,
+        entity_logical_name: entity_logical_name.to_owned(),
+        record_id: record_id.map(ToOwned::to_owned),
+        data,
+    }
+}
+
+#[tokio::test]
+async fn runtime_record_changeset_resolves_content_id_placeholders() {
+    let tenant_id = TenantId::new();
+    let subject = "alice";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+            Permission::RuntimeRecordWrite,
+        ],
+    )]);
+    let (service, audit_repository) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+    setup_changeset_entities(&service, &actor).await;
+
+    let results = service
+        .execute_runtime_record_changeset(
+            &actor,
+            vec![
+                changeset_operation(
+                    Some("acme"),
+                    RuntimeRecordChangesetMethod::Create,
+                    "account",
+                    None,
+                    json!({"name": "Acme"}),
+                ),
+                changeset_operation(
+                    Some("ada"),
+                    RuntimeRecordChangesetMethod::Create,
+                    "contact",
+                    None,
+                    json!({"name": "Ada", "account_id": "$acme"}),
+                ),
+                changeset_operation(
+                    None,
+                    RuntimeRecordChangesetMethod::Update,
+                    "account",
+                    Some("$acme"),
+                    json!({"name": "Acme Corp"}),
+                ),
+            ],
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    assert_eq!(results.len(), 3);
+    let account_id = results[0].record.record_id().as_str().to_owned();
+    assert_eq!(results[0].content_id.as_deref(), Some("acme"));
+    assert_eq!(
+        results[1].record.data().get("account_id"),
+        Some(&json!(account_id))
     );
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "active".to_owned(),
-                    display_name: "Active".to_owned(),
-                    field_type: FieldType::Boolean,
-                    is_required: true,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
-            .await
-            .is_ok()
+    assert_eq!(results[2].record.record_id().as_str(), account_id);
+    assert_eq!(
+        results[2].record.data().get("name"),
+        Some(&json!("Acme Corp"))
     );
-    assert!(service.publish_entity(&actor, "contact").await.is_ok());
 
-    let requires_phone_condition =
-        BusinessRuleCondition::new("name", BusinessRuleOperator::Eq, json!("Alice"));
-    assert!(requires_phone_condition.is_ok());
-    let require_phone_action = BusinessRuleAction::new(
-        BusinessRuleActionType::SetRequired,
-        Some("phone".to_owned()),
-        None,
-        None,
-    );
-    assert!(require_phone_action.is_ok());
-    let optional_active_condition =
-        BusinessRuleCondition::new("name", BusinessRuleOperator::Eq, json!("Guest"));
-    assert!(optional_active_condition.is_ok());
-    let optional_active_action = BusinessRuleAction::new(
-        BusinessRuleActionType::SetOptional,
-        Some("active".to_owned()),
-        None,
-        None,
+    let account = service
+        .get_runtime_record(&actor, "account", account_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(account.data().get("name"), Some(&json!("Acme Corp")));
+
+    let audit_events = audit_repository.events.lock().await;
+    assert_eq!(
+        audit_events
+            .iter()
+            .filter(|event| event
+                .detail
+                .as_deref()
+                .is_some_and(|detail| detail.ends_with("in changeset")))
+            .count(),
+        3
     );
-    assert!(optional_active_action.is_ok());
+}
 
-    let save_required_rule = service
-        .save_business_rule(
-            &actor,
-            SaveBusinessRuleInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "phone_required_for_alice".to_owned(),
-                display_name: "Phone Required For Alice".to_owned(),
-                scope: BusinessRuleScope::Entity,
-                form_logical_name: None,
-                conditions: vec![requires_phone_condition.unwrap_or_else(|_| unreachable!())],
-                actions: vec![require_phone_action.unwrap_or_else(|_| unreachable!())],
-                is_active: true,
-            },
-        )
-        .await;
-    assert!(save_required_rule.is_ok());
+#[tokio::test]
+async fn runtime_record_changeset_rolls_back_all_operations_on_failure() {
+    let tenant_id = TenantId::new();
+    let subject = "alice";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+            Permission::RuntimeRecordWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+    setup_changeset_entities(&service, &actor).await;
 
-    let save_optional_rule = service
-        .save_business_rule(
+    let duplicate = service
+        .execute_runtime_record_changeset(
             &actor,
-            SaveBusinessRuleInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "active_optional_for_guest".to_owned(),
-                display_name: "Active Optional For Guest".to_owned(),
-                scope: BusinessRuleScope::Entity,
-                form_logical_name: None,
-                conditions: vec![optional_active_condition.unwrap_or_else(|_| unreachable!())],
-                actions: vec![optional_active_action.unwrap_or_else(|_| unreachable!())],
-                is_active: true,
-            },
+            vec![
+                changeset_operation(
+                    Some("first"),
+                    RuntimeRecordChangesetMethod::Create,
+                    "account",
+                    None,
+                    json!({"name": "Acme"}),
+                ),
+                changeset_operation(
+                    Some("child"),
+                    RuntimeRecordChangesetMethod::Create,
+                    "contact",
+                    None,
+                    json!({"name": "Ada", "account_id": "$first"}),
+                ),
+                changeset_operation(
+                    None,
+                    RuntimeRecordChangesetMethod::Create,
+                    "account",
+                    None,
+                    json!({"name": "Acme"}),
+                ),
+            ],
         )
         .await;
-    assert!(save_optional_rule.is_ok());
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
 
-    let missing_phone = service
-        .create_runtime_record(&actor, "contact", json!({"name": "Alice", "active": true}))
-        .await;
-    assert!(matches!(missing_phone, Err(AppError::Validation(_))));
+    for entity_logical_name in ["account", "contact"] {
+        let records = service
+            .list_runtime_records(
+                &actor,
+                entity_logical_name,
+                RecordListQuery {
+                    limit: 20,
+                    offset: 0,
+                    owner_subject: None,
+                    include_inactive: false,
+                },
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert!(records.is_empty());
+    }
 
-    let missing_active = service
-        .create_runtime_record(&actor, "contact", json!({"name": "Guest"}))
+    let forward_reference = service
+        .execute_runtime_record_changeset(
+            &actor,
+            vec![
+                changeset_operation(
+                    Some("child"),
+                    RuntimeRecordChangesetMethod::Create,
+                    "contact",
+                    None,
+                    json!({"name": "Ada", "account_id": "$parent"}),
+                ),
+                changeset_operation(
+                    Some("parent"),
+                    RuntimeRecordChangesetMethod::Create,
+                    "account",
+                    None,
+                    json!({"name": "Acme"}),
+                ),
+            ],
+        )
         .await;
-    assert!(missing_active.is_ok());
+    assert!(matches!(forward_reference, Err(AppError::Validation(_))));
 }
 
 #[tokio::test]
-async fn update_runtime_record_preserves_hidden_values_and_blocks_locked_changes() {
+async fn query_runtime_records_filters_and_paginates() {
     let tenant_id = TenantId::new();
-    let subject = "rule_locking";
+    let subject = "grace";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
@@ -2963,6 +3819,7 @@ async fn update_runtime_record_preserves_hidden_values_and_blocks_locked_changes
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
@@ -2974,15 +3831,16 @@ async fn update_runtime_record_preserves_hidden_values_and_blocks_locked_changes
                 &actor,
                 SaveFieldInput {
                     entity_logical_name: "contact".to_owned(),
-                    logical_name: "status".to_owned(),
-                    display_name: "Status".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: false,
+                    logical_name: "active".to_owned(),
+                    display_name: "Active".to_owned(),
+                    field_type: FieldType::Boolean,
+                    is_required: true,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
@@ -2990,240 +3848,271 @@ async fn update_runtime_record_preserves_hidden_values_and_blocks_locked_changes
     );
     assert!(service.publish_entity(&actor, "contact").await.is_ok());
 
-    let created = service
-        .create_runtime_record(
-            &actor,
-            "contact",
-            json!({"name": "Alice", "status": "open"}),
-        )
-        .await;
-    assert!(created.is_ok());
-    let created = created.unwrap_or_else(|_| unreachable!());
-
-    let condition = BusinessRuleCondition::new("name", BusinessRuleOperator::Eq, json!("Alice"));
-    assert!(condition.is_ok());
-    let hide_status = BusinessRuleAction::new(
-        BusinessRuleActionType::HideField,
-        Some("status".to_owned()),
-        None,
-        None,
+    assert!(
+        service
+            .create_runtime_record(&actor, "contact", json!({"name": "Alice", "active": true}))
+            .await
+            .is_ok()
     );
-    assert!(hide_status.is_ok());
-    let lock_status = BusinessRuleAction::new(
-        BusinessRuleActionType::LockField,
-        Some("status".to_owned()),
-        None,
-        None,
+    assert!(
+        service
+            .create_runtime_record(&actor, "contact", json!({"name": "Bob", "active": false}))
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .create_runtime_record(&actor, "contact", json!({"name": "Carol", "active": true}))
+            .await
+            .is_ok()
     );
-    assert!(lock_status.is_ok());
 
-    let saved_rule = service
-        .save_business_rule(
+    let queried = service
+        .query_runtime_records(
             &actor,
-            SaveBusinessRuleInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "lock_status".to_owned(),
-                display_name: "Lock Status".to_owned(),
-                scope: BusinessRuleScope::Entity,
-                form_logical_name: None,
-                conditions: vec![condition.unwrap_or_else(|_| unreachable!())],
-                actions: vec![
-                    hide_status.unwrap_or_else(|_| unreachable!()),
-                    lock_status.unwrap_or_else(|_| unreachable!()),
-                ],
-                is_active: true,
+            "contact",
+            RuntimeRecordQuery {
+                limit: 1,
+                offset: 1,
+                logical_mode: RuntimeRecordLogicalMode::And,
+                where_clause: None,
+                filters: vec![RuntimeRecordFilter {
+                    scope_alias: None,
+                    field_logical_name: "active".to_owned(),
+                    operator: RuntimeRecordOperator::Eq,
+                    field_type: FieldType::Boolean,
+                    field_value: json!(true),
+                }],
+                links: Vec::new(),
+                sort: Vec::new(),
+                owner_subject: None,
+                include_inactive: false,
             },
         )
         .await;
-    assert!(saved_rule.is_ok());
+    assert!(queried.is_ok());
 
-    let updated_hidden = service
-        .update_runtime_record(
-            &actor,
-            "contact",
-            created.record_id().as_str(),
-            json!({"name": "Alice"}),
-        )
-        .await;
-    assert!(updated_hidden.is_ok());
-    let updated_hidden = updated_hidden.unwrap_or_else(|_| unreachable!());
+    let queried = queried.unwrap_or_default();
+    assert_eq!(queried.len(), 1);
     assert_eq!(
-        updated_hidden
+        queried[0]
             .data()
             .as_object()
-            .and_then(|object| object.get("status")),
-        Some(&json!("open"))
+            .and_then(|value| value.get("active")),
+        Some(&json!(true))
     );
+}
 
-    let locked_change = service
-        .update_runtime_record(
-            &actor,
-            "contact",
-            created.record_id().as_str(),
-            json!({"name": "Alice", "status": "closed"}),
-        )
-        .await;
-    assert!(matches!(locked_change, Err(AppError::Validation(_))));
+async fn register_deal_entity(service: &MetadataService, actor: &UserIdentity) {
+    assert!(service.register_entity(actor, "deal", "Deal").await.is_ok());
+    for (logical_name, field_type) in [("stage", FieldType::Text), ("amount", FieldType::Number)] {
+        assert!(
+            service
+                .save_field(
+                    actor,
+                    SaveFieldInput {
+                        entity_logical_name: "deal".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type,
+                        is_required: false,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity: None,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(service.publish_entity(actor, "deal").await.is_ok());
+}
+
+fn deal_aggregate_query(aggregates: Vec<RuntimeRecordAggregate>) -> RuntimeRecordAggregateQuery {
+    RuntimeRecordAggregateQuery {
+        limit: 10,
+        logical_mode: RuntimeRecordLogicalMode::And,
+        where_clause: None,
+        filters: Vec::new(),
+        group_by: vec![RuntimeRecordGroupBy {
+            field_logical_name: "stage".to_owned(),
+            field_type: FieldType::Text,
+        }],
+        aggregates,
+        having: Vec::new(),
+        sort: Vec::new(),
+        owner_subject: None,
+    }
+}
+
+fn deal_amount_aggregate(
+    alias: &str,
+    function: RuntimeRecordAggregateFunction,
+) -> RuntimeRecordAggregate {
+    RuntimeRecordAggregate {
+        alias: alias.to_owned(),
+        function,
+        field_logical_name: Some("amount".to_owned()),
+        field_type: Some(FieldType::Number),
+    }
 }
 
 #[tokio::test]
-async fn query_runtime_records_requires_runtime_read_permission() {
+async fn aggregate_runtime_records_groups_filters_and_ranks_groups() {
     let tenant_id = TenantId::new();
-    let subject = "heidi";
     let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
+        (tenant_id, "grace".to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
             Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
         ],
     )]);
     let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
-
-    assert!(
-        service
-            .register_entity(&actor, "contact", "Contact")
-            .await
-            .is_ok()
-    );
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "name".to_owned(),
-                    display_name: "Name".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: true,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: None,
-                },
-            )
-            .await
-            .is_ok()
-    );
-    assert!(service.publish_entity(&actor, "contact").await.is_ok());
-
-    let queried = service
-        .query_runtime_records(
-            &actor,
-            "contact",
-            RuntimeRecordQuery {
-                limit: 50,
-                offset: 0,
-                logical_mode: RuntimeRecordLogicalMode::And,
-                where_clause: None,
-                filters: vec![RuntimeRecordFilter {
-                    scope_alias: None,
-                    field_logical_name: "name".to_owned(),
-                    operator: RuntimeRecordOperator::Eq,
-                    field_type: FieldType::Text,
-                    field_value: json!("Alice"),
-                }],
-                links: Vec::new(),
-                sort: Vec::new(),
-                owner_subject: None,
-            },
-        )
-        .await;
+    let actor = actor(tenant_id, "grace");
+    register_deal_entity(&service, &actor).await;
+
+    for data in [
+        json!({"stage": "open", "amount": 10}),
+        json!({"stage": "open", "amount": 30}),
+        json!({"stage": "won", "amount": 50}),
+        json!({"stage": "lost", "amount": 5}),
+        json!({"stage": "lost"}),
+    ] {
+        assert!(
+            service
+                .create_runtime_record(&actor, "deal", data)
+                .await
+                .is_ok()
+        );
+    }
 
-    assert!(matches!(queried, Err(AppError::Forbidden(_))));
+    let mut query = deal_aggregate_query(vec![
+        RuntimeRecordAggregate {
+            alias: "deals".to_owned(),
+            function: RuntimeRecordAggregateFunction::Count,
+            field_logical_name: None,
+            field_type: None,
+        },
+        deal_amount_aggregate("total", RuntimeRecordAggregateFunction::Sum),
+        deal_amount_aggregate("largest", RuntimeRecordAggregateFunction::Max),
+    ]);
+    query.filters = vec![RuntimeRecordFilter {
+        scope_alias: None,
+        field_logical_name: "stage".to_owned(),
+        operator: RuntimeRecordOperator::Neq,
+        field_type: FieldType::Text,
+        field_value: json!("won"),
+    }];
+    query.having = vec![RuntimeRecordAggregateHaving {
+        aggregate_alias: "deals".to_owned(),
+        operator: RuntimeRecordOperator::Gte,
+        value: json!(2),
+    }];
+    query.sort = vec![RuntimeRecordAggregateSort {
+        key: RuntimeRecordAggregateSortKey::Aggregate("total".to_owned()),
+        direction: RuntimeRecordSortDirection::Desc,
+    }];
+    query.limit = 1;
+
+    let rows = service
+        .aggregate_runtime_records(&actor, "deal", query)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].group.get("stage"), Some(&json!("open")));
+    assert_eq!(rows[0].values.get("deals"), Some(&json!(2)));
+    assert_eq!(rows[0].values.get("total"), Some(&json!(40.0)));
+    assert_eq!(rows[0].values.get("largest"), Some(&json!(30)));
 }
 
 #[tokio::test]
-async fn delete_runtime_record_blocks_when_relation_exists() {
+async fn aggregate_runtime_records_enforces_field_permissions_and_types() {
     let tenant_id = TenantId::new();
-    let subject = "eve";
     let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
+        (tenant_id, "alice".to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
-            Permission::RuntimeRecordWrite,
             Permission::RuntimeRecordRead,
         ],
     )]);
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
-
-    let created_contact = service.register_entity(&actor, "contact", "Contact").await;
-    assert!(created_contact.is_ok());
-    let created_deal = service.register_entity(&actor, "deal", "Deal").await;
-    assert!(created_deal.is_ok());
+    let runtime_field_grants = HashMap::from([(
+        (tenant_id, "alice".to_owned(), "deal".to_owned()),
+        vec![RuntimeFieldGrant {
+            field_logical_name: "stage".to_owned(),
+            can_read: true,
+            can_write: false,
+        }],
+    )]);
+    let (service, _) = build_service_with_runtime_field_grants(grants, runtime_field_grants);
+    let alice = actor(tenant_id, "alice");
+    register_deal_entity(&service, &alice).await;
 
-    let contact_name_field = service
-        .save_field(
-            &actor,
-            SaveFieldInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "name".to_owned(),
-                display_name: "Name".to_owned(),
-                field_type: FieldType::Text,
-                is_required: true,
-                is_unique: false,
-                default_value: None,
-                calculation_expression: None,
-                relation_target_entity: None,
-                option_set_logical_name: None,
-            },
+    let unreadable = service
+        .aggregate_runtime_records(
+            &alice,
+            "deal",
+            deal_aggregate_query(vec![deal_amount_aggregate(
+                "total",
+                RuntimeRecordAggregateFunction::Sum,
+            )]),
         )
         .await;
-    assert!(contact_name_field.is_ok());
+    assert!(matches!(unreadable, Err(AppError::Forbidden(_))));
 
-    let deal_owner_field = service
-        .save_field(
-            &actor,
-            SaveFieldInput {
-                entity_logical_name: "deal".to_owned(),
-                logical_name: "owner_contact_id".to_owned(),
-                display_name: "Owner Contact".to_owned(),
-                field_type: FieldType::Relation,
-                is_required: true,
-                is_unique: false,
-                default_value: None,
-                calculation_expression: None,
-                relation_target_entity: Some("contact".to_owned()),
-                option_set_logical_name: None,
-            },
+    let non_numeric_sum = service
+        .aggregate_runtime_records(
+            &alice,
+            "deal",
+            deal_aggregate_query(vec![RuntimeRecordAggregate {
+                alias: "total".to_owned(),
+                function: RuntimeRecordAggregateFunction::Sum,
+                field_logical_name: Some("stage".to_owned()),
+                field_type: Some(FieldType::Text),
+            }]),
         )
         .await;
-    assert!(deal_owner_field.is_ok());
-
-    let published_contact = service.publish_entity(&actor, "contact").await;
-    assert!(published_contact.is_ok());
-    let published_deal = service.publish_entity(&actor, "deal").await;
-    assert!(published_deal.is_ok());
-
-    let contact_record = service
-        .create_runtime_record(&actor, "contact", json!({"name": "Alice"}))
+    assert!(matches!(non_numeric_sum, Err(AppError::Validation(_))));
+
+    let mut unknown_sort = deal_aggregate_query(vec![RuntimeRecordAggregate {
+        alias: "deals".to_owned(),
+        function: RuntimeRecordAggregateFunction::Count,
+        field_logical_name: None,
+        field_type: None,
+    }]);
+    unknown_sort.sort = vec![RuntimeRecordAggregateSort {
+        key: RuntimeRecordAggregateSortKey::Aggregate("total".to_owned()),
+        direction: RuntimeRecordSortDirection::Asc,
+    }];
+    let unknown_sort = service
+        .aggregate_runtime_records(&alice, "deal", unknown_sort)
         .await;
-    assert!(contact_record.is_ok());
-    let contact_record = contact_record.unwrap_or_else(|_| unreachable!());
+    assert!(matches!(unknown_sort, Err(AppError::Validation(_))));
 
-    let created_deal_record = service
-        .create_runtime_record(
-            &actor,
+    let counted = service
+        .aggregate_runtime_records(
+            &alice,
             "deal",
-            json!({"owner_contact_id": contact_record.record_id().as_str()}),
+            deal_aggregate_query(vec![RuntimeRecordAggregate {
+                alias: "deals".to_owned(),
+                function: RuntimeRecordAggregateFunction::Count,
+                field_logical_name: None,
+                field_type: None,
+            }]),
         )
         .await;
-    assert!(created_deal_record.is_ok());
-
-    let delete_result = service
-        .delete_runtime_record(&actor, "contact", contact_record.record_id().as_str())
-        .await;
-    assert!(matches!(delete_result, Err(AppError::Conflict(_))));
+    assert!(counted.is_ok());
 }
 
 #[tokio::test]
-async fn get_and_delete_runtime_record_succeed_when_unreferenced() {
+async fn create_runtime_record_computes_calculated_number_field() {
     let tenant_id = TenantId::new();
-    let subject = "frank";
+    let subject = "calc";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
@@ -3233,317 +4122,125 @@ async fn get_and_delete_runtime_record_succeed_when_unreferenced() {
             Permission::RuntimeRecordRead,
         ],
     )]);
-    let (service, audit_repository) = build_service(grants);
-    let actor = actor(tenant_id, subject);
-
-    let created_entity = service.register_entity(&actor, "note", "Note").await;
-    assert!(created_entity.is_ok());
-    let saved_field = service
-        .save_field(
-            &actor,
-            SaveFieldInput {
-                entity_logical_name: "note".to_owned(),
-                logical_name: "title".to_owned(),
-                display_name: "Title".to_owned(),
-                field_type: FieldType::Text,
-                is_required: true,
-                is_unique: false,
-                default_value: None,
-                calculation_expression: None,
-                relation_target_entity: None,
-                option_set_logical_name: None,
-            },
-        )
-        .await;
-    assert!(saved_field.is_ok());
-
-    let published = service.publish_entity(&actor, "note").await;
-    assert!(published.is_ok());
-
-    let created_record = service
-        .create_runtime_record(&actor, "note", json!({"title": "A"}))
-        .await;
-    assert!(created_record.is_ok());
-    let created_record = created_record.unwrap_or_else(|_| unreachable!());
-
-    let fetched = service
-        .get_runtime_record(&actor, "note", created_record.record_id().as_str())
-        .await;
-    assert!(fetched.is_ok());
-
-    let deleted = service
-        .delete_runtime_record(&actor, "note", created_record.record_id().as_str())
-        .await;
-    assert!(deleted.is_ok());
-
-    let refetch = service
-        .get_runtime_record(&actor, "note", created_record.record_id().as_str())
-        .await;
-    assert!(matches!(refetch, Err(AppError::NotFound(_))));
-
-    let events = audit_repository.events.lock().await;
-    assert!(events.iter().any(|event| {
-        event.action == AuditAction::RuntimeRecordDeleted
-            && event.resource_id == created_record.record_id().as_str()
-    }));
-}
-
-#[tokio::test]
-async fn list_runtime_records_unchecked_honors_own_read_scope_when_configured() {
-    let tenant_id = TenantId::new();
-    let grants = HashMap::from([(
-        (tenant_id, "alice".to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::RuntimeRecordReadOwn,
-            Permission::RuntimeRecordWriteOwn,
-        ],
-    )]);
     let (service, _) = build_service(grants);
-    let alice = actor(tenant_id, "alice");
-    let bob = actor(tenant_id, "bob");
+    let actor = actor(tenant_id, subject);
 
     assert!(
         service
-            .register_entity(&alice, "contact", "Contact")
+            .register_entity(&actor, "line", "Line")
             .await
             .is_ok()
     );
     assert!(
         service
             .save_field(
-                &alice,
+                &actor,
                 SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "name".to_owned(),
-                    display_name: "Name".to_owned(),
-                    field_type: FieldType::Text,
+                    entity_logical_name: "line".to_owned(),
+                    logical_name: "quantity".to_owned(),
+                    display_name: "Quantity".to_owned(),
+                    field_type: FieldType::Number,
                     is_required: true,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
-    assert!(service.publish_entity(&alice, "contact").await.is_ok());
-
-    let alice_record = service
-        .create_runtime_record_unchecked(&alice, "contact", json!({"name": "Alice"}))
-        .await;
-    assert!(alice_record.is_ok());
-
-    let bob_record = service
-        .create_runtime_record_unchecked(&bob, "contact", json!({"name": "Bob"}))
-        .await;
-    assert!(bob_record.is_ok());
-
-    let listed = service
-        .list_runtime_records_unchecked(
-            &alice,
-            "contact",
-            RecordListQuery {
-                limit: 20,
-                offset: 0,
-                owner_subject: None,
-            },
-        )
-        .await;
-    assert!(listed.is_ok());
-    let listed = listed.unwrap_or_default();
-    assert_eq!(listed.len(), 1);
-    assert_eq!(
-        listed[0]
-            .data()
-            .as_object()
-            .and_then(|value| value.get("name")),
-        Some(&json!("Alice"))
-    );
-}
-
-#[tokio::test]
-async fn query_runtime_records_unchecked_honors_own_read_scope_when_configured() {
-    let tenant_id = TenantId::new();
-    let grants = HashMap::from([(
-        (tenant_id, "alice".to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::RuntimeRecordReadOwn,
-            Permission::RuntimeRecordWriteOwn,
-        ],
-    )]);
-    let (service, _) = build_service(grants);
-    let alice = actor(tenant_id, "alice");
-    let bob = actor(tenant_id, "bob");
-
-    assert!(
-        service
-            .register_entity(&alice, "contact", "Contact")
-            .await
-            .is_ok()
-    );
     assert!(
         service
             .save_field(
-                &alice,
+                &actor,
                 SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "name".to_owned(),
-                    display_name: "Name".to_owned(),
-                    field_type: FieldType::Text,
+                    entity_logical_name: "line".to_owned(),
+                    logical_name: "unit_price".to_owned(),
+                    display_name: "Unit Price".to_owned(),
+                    field_type: FieldType::Number,
                     is_required: true,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
-    assert!(service.publish_entity(&alice, "contact").await.is_ok());
-
-    let alice_record = service
-        .create_runtime_record_unchecked(&alice, "contact", json!({"name": "Alice"}))
-        .await;
-    assert!(alice_record.is_ok());
-
-    let bob_record = service
-        .create_runtime_record_unchecked(&bob, "contact", json!({"name": "Bob"}))
-        .await;
-    assert!(bob_record.is_ok());
-
-    let queried = service
-        .query_runtime_records_unchecked(
-            &alice,
-            "contact",
-            RuntimeRecordQuery {
-                limit: 20,
-                offset: 0,
-                logical_mode: RuntimeRecordLogicalMode::And,
-                where_clause: None,
-                filters: vec![RuntimeRecordFilter {
-                    scope_alias: None,
-                    field_logical_name: "name".to_owned(),
-                    operator: RuntimeRecordOperator::Contains,
-                    field_type: FieldType::Text,
-                    field_value: json!("o"),
-                }],
-                links: Vec::new(),
-                sort: Vec::new(),
-                owner_subject: None,
-            },
-        )
-        .await;
-    assert!(queried.is_ok());
-    let queried = queried.unwrap_or_default();
-    assert_eq!(queried.len(), 0);
-}
-
-#[tokio::test]
-async fn update_runtime_record_unchecked_blocks_non_owned_records_for_own_write_scope() {
-    let tenant_id = TenantId::new();
-    let grants = HashMap::from([(
-        (tenant_id, "alice".to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::RuntimeRecordWriteOwn,
-        ],
-    )]);
-    let (service, _) = build_service(grants);
-    let alice = actor(tenant_id, "alice");
-    let bob = actor(tenant_id, "bob");
-
-    assert!(
-        service
-            .register_entity(&alice, "task", "Task")
-            .await
-            .is_ok()
-    );
     assert!(
         service
             .save_field(
-                &alice,
+                &actor,
                 SaveFieldInput {
-                    entity_logical_name: "task".to_owned(),
-                    logical_name: "title".to_owned(),
-                    display_name: "Title".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: true,
+                    entity_logical_name: "line".to_owned(),
+                    logical_name: "total".to_owned(),
+                    display_name: "Total".to_owned(),
+                    field_type: FieldType::Number,
+                    is_required: false,
                     is_unique: false,
                     default_value: None,
-                    calculation_expression: None,
+                    calculation_expression: Some("add(quantity, unit_price)".to_owned()),
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
-    assert!(service.publish_entity(&alice, "task").await.is_ok());
-
-    let bob_record = service
-        .create_runtime_record_unchecked(&bob, "task", json!({"title": "Owned by bob"}))
-        .await;
-    assert!(bob_record.is_ok());
-    let bob_record = bob_record.unwrap_or_else(|_| unreachable!());
+    assert!(service.publish_entity(&actor, "line").await.is_ok());
 
-    let update_result = service
-        .update_runtime_record_unchecked(
-            &alice,
-            "task",
-            bob_record.record_id().as_str(),
-            json!({"title": "Alice edit"}),
-        )
+    let created = service
+        .create_runtime_record(&actor, "line", json!({"quantity": 2, "unit_price": 5}))
         .await;
+    assert!(created.is_ok());
+    let created = created.unwrap_or_else(|_| unreachable!());
 
-    assert!(matches!(update_result, Err(AppError::Forbidden(_))));
+    assert_eq!(
+        created
+            .data()
+            .as_object()
+            .and_then(|value| value.get("total")),
+        Some(&json!(7.0))
+    );
 }
 
 #[tokio::test]
-async fn get_runtime_record_unchecked_redacts_using_runtime_field_permissions() {
+async fn create_runtime_record_rejects_direct_value_for_calculated_field() {
     let tenant_id = TenantId::new();
+    let subject = "calc_guard";
     let grants = HashMap::from([(
-        (tenant_id, "alice".to_owned()),
+        (tenant_id, subject.to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
             Permission::RuntimeRecordRead,
         ],
     )]);
-    let runtime_field_grants = HashMap::from([(
-        (tenant_id, "alice".to_owned(), "contact".to_owned()),
-        vec![RuntimeFieldGrant {
-            field_logical_name: "email".to_owned(),
-            can_read: true,
-            can_write: false,
-        }],
-    )]);
-    let (service, _) = build_service_with_runtime_field_grants(grants, runtime_field_grants);
-    let alice = actor(tenant_id, "alice");
-    let bob = actor(tenant_id, "bob");
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
 
     assert!(
         service
-            .register_entity(&alice, "contact", "Contact")
+            .register_entity(&actor, "person", "Person")
             .await
             .is_ok()
     );
     assert!(
         service
             .save_field(
-                &alice,
+                &actor,
                 SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "email".to_owned(),
-                    display_name: "Email".to_owned(),
+                    entity_logical_name: "person".to_owned(),
+                    logical_name: "first_name".to_owned(),
+                    display_name: "First Name".to_owned(),
                     field_type: FieldType::Text,
                     is_required: true,
                     is_unique: false,
@@ -3551,6 +4248,7 @@ async fn get_runtime_record_unchecked_redacts_using_runtime_field_permissions()
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
@@ -3559,11 +4257,11 @@ async fn get_runtime_record_unchecked_redacts_using_runtime_field_permissions()
     assert!(
         service
             .save_field(
-                &alice,
+                &actor,
                 SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "secret".to_owned(),
-                    display_name: "Secret".to_owned(),
+                    entity_logical_name: "person".to_owned(),
+                    logical_name: "last_name".to_owned(),
+                    display_name: "Last Name".to_owned(),
                     field_type: FieldType::Text,
                     is_required: true,
                     is_unique: false,
@@ -3571,46 +4269,59 @@ async fn get_runtime_record_unchecked_redacts_using_runtime_field_permissions()
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
-    assert!(service.publish_entity(&alice, "contact").await.is_ok());
-
-    let created = service
-        .create_runtime_record_unchecked(
-            &bob,
-            "contact",
-            json!({"email": "a@qryvanta.dev", "secret": "top"}),
+    assert!(service
+        .save_field(
+            &actor,
+            SaveFieldInput {
+                entity_logical_name: "person".to_owned(),
+                logical_name: "full_name".to_owned(),
+                display_name: "Full Name".to_owned(),
+                field_type: FieldType::Text,
+                is_required: false,
+                is_unique: false,
+                default_value: None,
+                calculation_expression: Some("concat(first_name, \" \", last_name)".to_owned(),),
+                relation_target_entity: None,
+                option_set_logical_name: None,
+                classification: None,
+            },
         )
-        .await;
-    assert!(created.is_ok());
-    let created = created.unwrap_or_else(|_| unreachable!());
+        .await
+        .is_ok());
+    assert!(service.publish_entity(&actor, "person").await.is_ok());
 
-    let fetched = service
-        .get_runtime_record_unchecked(&alice, "contact", created.record_id().as_str())
+    let result = service
+        .create_runtime_record(
+            &actor,
+            "person",
+            json!({
+                "first_name": "Ada",
+                "last_name": "Lovelace",
+                "full_name": "Injected Value"
+            }),
+        )
         .await;
-    assert!(fetched.is_ok());
-    let fetched = fetched.unwrap_or_else(|_| unreachable!());
 
-    let data = fetched.data().as_object();
-    assert!(data.is_some());
-    let data = data.unwrap_or_else(|| unreachable!());
-    assert_eq!(data.get("email"), Some(&json!("a@qryvanta.dev")));
-    assert!(data.get("secret").is_none());
+    assert!(matches!(result, Err(AppError::Validation(_))));
 }
 
 #[tokio::test]
-async fn update_field_updates_mutable_metadata_properties() {
+async fn create_runtime_record_blocks_on_entity_business_rule_show_error() {
     let tenant_id = TenantId::new();
-    let subject = "ivy";
+    let subject = "rule_creator";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
         ],
     )]);
     let (service, _) = build_service(grants);
@@ -3637,72 +4348,28 @@ async fn update_field_updates_mutable_metadata_properties() {
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
-
-    let updated = service
-        .update_field(
-            &actor,
-            UpdateFieldInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "name".to_owned(),
-                display_name: "Full Name".to_owned(),
-                description: Some("Primary full name".to_owned()),
-                default_value: Some(json!("Anonymous")),
-                calculation_expression: None,
-                max_length: Some(255),
-                min_value: None,
-                max_value: None,
-            },
-        )
-        .await;
-    assert!(updated.is_ok());
-    let updated = updated.unwrap_or_else(|_| unreachable!());
-    assert_eq!(updated.display_name().as_str(), "Full Name");
-    assert_eq!(updated.description(), Some("Primary full name"));
-    assert_eq!(updated.max_length(), Some(255));
-    assert_eq!(updated.default_value(), Some(&json!("Anonymous")));
-}
-
-#[tokio::test]
-async fn save_field_rejects_type_change_for_published_field() {
-    let tenant_id = TenantId::new();
-    let subject = "sam";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
-        ],
-    )]);
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
-
-    assert!(
-        service
-            .register_entity(&actor, "contact", "Contact")
-            .await
-            .is_ok()
-    );
     assert!(
         service
             .save_field(
                 &actor,
                 SaveFieldInput {
                     entity_logical_name: "contact".to_owned(),
-                    logical_name: "name".to_owned(),
-                    display_name: "Name".to_owned(),
-                    field_type: FieldType::Text,
+                    logical_name: "active".to_owned(),
+                    display_name: "Active".to_owned(),
+                    field_type: FieldType::Boolean,
                     is_required: true,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
@@ -3710,214 +4377,199 @@ async fn save_field_rejects_type_change_for_published_field() {
     );
     assert!(service.publish_entity(&actor, "contact").await.is_ok());
 
-    let changed = service
-        .save_field(
+    let condition = BusinessRuleCondition::new("active", BusinessRuleOperator::Eq, json!(false));
+    assert!(condition.is_ok());
+    let action = BusinessRuleAction::new(
+        BusinessRuleActionType::ShowError,
+        None,
+        None,
+        Some("Active must be true".to_owned()),
+    );
+    assert!(action.is_ok());
+    let saved_rule = service
+        .save_business_rule(
             &actor,
-            SaveFieldInput {
+            SaveBusinessRuleInput {
                 entity_logical_name: "contact".to_owned(),
-                logical_name: "name".to_owned(),
-                display_name: "Name".to_owned(),
-                field_type: FieldType::Number,
-                is_required: true,
-                is_unique: false,
-                default_value: None,
-                calculation_expression: None,
-                relation_target_entity: None,
-                option_set_logical_name: None,
+                logical_name: "active_guard".to_owned(),
+                display_name: "Active Guard".to_owned(),
+                scope: BusinessRuleScope::Entity,
+                form_logical_name: None,
+                conditions: vec![condition.unwrap_or_else(|_| unreachable!())],
+                actions: vec![action.unwrap_or_else(|_| unreachable!())],
+                is_active: true,
             },
         )
         .await;
+    assert!(saved_rule.is_ok());
 
-    assert!(
-        matches!(changed, Err(AppError::Validation(message)) if message
-        .contains("field type cannot be changed for published field"))
-    );
+    let result = service
+        .create_runtime_record(&actor, "contact", json!({"name": "Alice", "active": false}))
+        .await;
+    assert!(matches!(result, Err(AppError::Validation(_))));
 }
 
 #[tokio::test]
-async fn save_field_rejects_relation_target_change_for_published_field() {
+async fn validate_runtime_records_page_reports_records_failing_newer_rules() {
     let tenant_id = TenantId::new();
-    let subject = "sam";
+    let subject = "data_auditor";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
         ],
     )]);
     let (service, _) = build_service(grants);
     let actor = actor(tenant_id, subject);
-
     assert!(
-        service
-            .register_entity(&actor, "contact", "Contact")
-            .await
-            .is_ok()
+        register_publish_entity_with_text_fields(
+            &service,
+            &actor,
+            "contact",
+            "Contact",
+            &["name", "nickname"],
+        )
+        .await
+        .is_ok()
     );
+
+    let legacy = service
+        .create_runtime_record(
+            &actor,
+            "contact",
+            json!({"name": "Alice", "nickname": "legacy"}),
+        )
+        .await;
+    assert!(legacy.is_ok());
+    let legacy_record_id = legacy
+        .map(|record| record.record_id().as_str().to_owned())
+        .unwrap_or_default();
     assert!(
         service
-            .register_entity(&actor, "account", "Account")
+            .create_runtime_record(&actor, "contact", json!({"name": "Bob"}))
             .await
             .is_ok()
     );
+
+    let condition =
+        BusinessRuleCondition::new("nickname", BusinessRuleOperator::Eq, json!("legacy"));
+    let action = BusinessRuleAction::new(
+        BusinessRuleActionType::ShowError,
+        None,
+        None,
+        Some("Legacy nicknames are retired".to_owned()),
+    );
     assert!(
         service
-            .save_field(
+            .save_business_rule(
                 &actor,
-                SaveFieldInput {
+                SaveBusinessRuleInput {
                     entity_logical_name: "contact".to_owned(),
-                    logical_name: "manager_id".to_owned(),
-                    display_name: "Manager".to_owned(),
-                    field_type: FieldType::Relation,
-                    is_required: false,
-                    is_unique: false,
-                    default_value: None,
-                    calculation_expression: None,
-                    relation_target_entity: Some("contact".to_owned()),
-                    option_set_logical_name: None,
+                    logical_name: "retire_legacy".to_owned(),
+                    display_name: "Retire Legacy".to_owned(),
+                    scope: BusinessRuleScope::Entity,
+                    form_logical_name: None,
+                    conditions: vec![condition.unwrap_or_else(|_| unreachable!())],
+                    actions: vec![action.unwrap_or_else(|_| unreachable!())],
+                    is_active: true,
                 },
             )
             .await
             .is_ok()
     );
-    assert!(service.publish_entity(&actor, "contact").await.is_ok());
 
-    let changed = service
-        .save_field(
-            &actor,
-            SaveFieldInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "manager_id".to_owned(),
-                display_name: "Manager".to_owned(),
-                field_type: FieldType::Relation,
-                is_required: false,
-                is_unique: false,
-                default_value: None,
-                calculation_expression: None,
-                relation_target_entity: Some("account".to_owned()),
-                option_set_logical_name: None,
-            },
-        )
-        .await;
-
-    assert!(
-        matches!(changed, Err(AppError::Validation(message)) if message
-        .contains("relation target cannot be changed for published field"))
+    let page = service
+        .validate_runtime_records_page(&actor, "contact", 50, 0)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(page.records_checked, 2);
+    assert_eq!(page.violations.len(), 1);
+    let violation = &page.violations[0];
+    assert_eq!(violation.record_id, legacy_record_id);
+    assert_eq!(violation.rule, RuntimeRecordValidationRule::BusinessRule);
+    assert_eq!(
+        violation.business_rule_logical_name.as_deref(),
+        Some("retire_legacy")
     );
+
+    let reader = UserIdentity::new("reader", "reader", None, tenant_id);
+    let forbidden = service
+        .validate_runtime_records_page(&reader, "contact", 50, 0)
+        .await;
+    assert!(matches!(forbidden, Err(AppError::Forbidden(_))));
 }
 
 #[tokio::test]
-async fn save_field_rejects_option_set_change_for_published_field() {
+async fn update_runtime_record_with_stale_version_returns_conflict() {
     let tenant_id = TenantId::new();
-    let subject = "sam";
     let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
+        (tenant_id, "alice".to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
         ],
     )]);
     let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
-
-    assert!(
-        service
-            .register_entity(&actor, "contact", "Contact")
-            .await
-            .is_ok()
-    );
-
+    let alice = actor(tenant_id, "alice");
     assert!(
-        service
-            .save_option_set(
-                &actor,
-                SaveOptionSetInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "status_primary".to_owned(),
-                    display_name: "Status Primary".to_owned(),
-                    options: vec![
-                        OptionSetItem::new(1, "Open", None, 0).unwrap_or_else(|_| unreachable!())
-                    ],
-                },
-            )
+        register_publish_entity_with_text_fields(&service, &alice, "task", "Task", &["title"])
             .await
             .is_ok()
     );
 
-    assert!(
-        service
-            .save_option_set(
-                &actor,
-                SaveOptionSetInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "status_secondary".to_owned(),
-                    display_name: "Status Secondary".to_owned(),
-                    options: vec![
-                        OptionSetItem::new(1, "Open", None, 0).unwrap_or_else(|_| unreachable!())
-                    ],
-                },
-            )
-            .await
-            .is_ok()
-    );
+    let record = service
+        .create_runtime_record(&alice, "task", json!({"title": "Draft"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = record.record_id().as_str();
 
-    assert!(
-        service
-            .save_field(
-                &actor,
-                SaveFieldInput {
-                    entity_logical_name: "contact".to_owned(),
-                    logical_name: "status".to_owned(),
-                    display_name: "Status".to_owned(),
-                    field_type: FieldType::Choice,
-                    is_required: true,
-                    is_unique: false,
-                    default_value: Some(json!(1)),
-                    calculation_expression: None,
-                    relation_target_entity: None,
-                    option_set_logical_name: Some("status_primary".to_owned()),
-                },
-            )
-            .await
-            .is_ok()
-    );
-    assert!(service.publish_entity(&actor, "contact").await.is_ok());
+    let first_edit = service
+        .update_runtime_record_with_version(
+            &alice,
+            "task",
+            record_id,
+            json!({"title": "First edit"}),
+            Some(record.version()),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(first_edit.version(), record.version() + 1);
 
-    let changed = service
-        .save_field(
-            &actor,
-            SaveFieldInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "status".to_owned(),
-                display_name: "Status".to_owned(),
-                field_type: FieldType::Choice,
-                is_required: true,
-                is_unique: false,
-                default_value: Some(json!(1)),
-                calculation_expression: None,
-                relation_target_entity: None,
-                option_set_logical_name: Some("status_secondary".to_owned()),
-            },
+    let stale_edit = service
+        .update_runtime_record_with_version(
+            &alice,
+            "task",
+            record_id,
+            json!({"title": "Stale edit"}),
+            Some(record.version()),
         )
         .await;
+    assert!(matches!(stale_edit, Err(AppError::Conflict(_))));
 
-    assert!(
-        matches!(changed, Err(AppError::Validation(message)) if message
-        .contains("option set reference cannot be changed for published field"))
-    );
+    let stored = service
+        .get_runtime_record(&alice, "task", record_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(stored.data()["title"], json!("First edit"));
+    assert_eq!(stored.version(), first_edit.version());
 }
 
 #[tokio::test]
-async fn delete_field_rejects_published_fields() {
+async fn update_runtime_record_blocks_on_entity_business_rule_show_error() {
     let tenant_id = TenantId::new();
-    let subject = "judy";
+    let subject = "rule_editor";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
         ],
     )]);
     let (service, _) = build_service(grants);
@@ -3944,1074 +4596,7022 @@ async fn delete_field_rejects_published_fields() {
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
-    assert!(service.publish_entity(&actor, "contact").await.is_ok());
-
-    let deleted = service.delete_field(&actor, "contact", "name").await;
-    assert!(matches!(deleted, Err(AppError::Conflict(_))));
-}
-
-#[tokio::test]
-async fn delete_field_allows_unpublished_draft_fields() {
-    let tenant_id = TenantId::new();
-    let subject = "kate";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
-        ],
-    )]);
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
-
-    assert!(
-        service
-            .register_entity(&actor, "contact", "Contact")
-            .await
-            .is_ok()
-    );
     assert!(
         service
             .save_field(
                 &actor,
                 SaveFieldInput {
                     entity_logical_name: "contact".to_owned(),
-                    logical_name: "temporary_note".to_owned(),
-                    display_name: "Temporary Note".to_owned(),
-                    field_type: FieldType::Text,
-                    is_required: false,
+                    logical_name: "active".to_owned(),
+                    display_name: "Active".to_owned(),
+                    field_type: FieldType::Boolean,
+                    is_required: true,
                     is_unique: false,
                     default_value: None,
                     calculation_expression: None,
                     relation_target_entity: None,
                     option_set_logical_name: None,
+                    classification: None,
                 },
             )
             .await
             .is_ok()
     );
+    assert!(service.publish_entity(&actor, "contact").await.is_ok());
 
-    let deleted = service
-        .delete_field(&actor, "contact", "temporary_note")
+    let condition = BusinessRuleCondition::new("active", BusinessRuleOperator::Eq, json!(false));
+    assert!(condition.is_ok());
+    let action = BusinessRuleAction::new(
+        BusinessRuleActionType::ShowError,
+        None,
+        None,
+        Some("Active must be true".to_owned()),
+    );
+    assert!(action.is_ok());
+    let saved_rule = service
+        .save_business_rule(
+            &actor,
+            SaveBusinessRuleInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "active_guard".to_owned(),
+                display_name: "Active Guard".to_owned(),
+                scope: BusinessRuleScope::Entity,
+                form_logical_name: None,
+                conditions: vec![condition.unwrap_or_else(|_| unreachable!())],
+                actions: vec![action.unwrap_or_else(|_| unreachable!())],
+                is_active: true,
+            },
+        )
         .await;
-    assert!(deleted.is_ok());
+    assert!(saved_rule.is_ok());
 
-    let listed = service.list_fields(&actor, "contact").await;
-    assert!(listed.is_ok());
-    assert!(
-        listed
-            .unwrap_or_default()
-            .iter()
-            .all(|field| field.logical_name().as_str() != "temporary_note")
-    );
+    let created = service
+        .create_runtime_record(&actor, "contact", json!({"name": "Alice", "active": true}))
+        .await;
+    assert!(created.is_ok());
+    let created = created.unwrap_or_else(|_| unreachable!());
+
+    let result = service
+        .update_runtime_record(
+            &actor,
+            "contact",
+            created.record_id().as_str(),
+            json!({"name": "Alice", "active": false}),
+        )
+        .await;
+    assert!(matches!(result, Err(AppError::Validation(_))));
+}
+
+fn entity_business_rule_input(
+    logical_name: &str,
+    conditions: Vec<BusinessRuleCondition>,
+    actions: Vec<BusinessRuleAction>,
+) -> SaveBusinessRuleInput {
+    SaveBusinessRuleInput {
+        entity_logical_name: "task".to_owned(),
+        logical_name: logical_name.to_owned(),
+        display_name: logical_name.to_owned(),
+        scope: BusinessRuleScope::Entity,
+        form_logical_name: None,
+        conditions,
+        actions,
+        is_active: true,
+    }
 }
 
 #[tokio::test]
-async fn save_form_rejects_sparse_tab_positions() {
+async fn prevent_save_business_rule_rejects_writes_and_audits_the_rejection() {
     let tenant_id = TenantId::new();
-    let subject = "laura";
     let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
+        (tenant_id, "alice".to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
         ],
     )]);
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
-
-    let seeded = register_publish_entity_with_text_fields(
-        &service,
-        &actor,
-        "contact",
-        "Contact",
-        &["name", "email"],
-    )
-    .await;
-    assert!(seeded.is_ok());
+    let (service, audit_repository) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        register_publish_entity_with_text_fields(
+            &service,
+            &alice,
+            "task",
+            "Task",
+            &["title", "status"]
+        )
+        .await
+        .is_ok()
+    );
 
-    let summary_field = FormFieldPlacement::new("name", 0, 0, true, false, None, None)
+    let condition = BusinessRuleCondition::new("status", BusinessRuleOperator::Eq, json!("closed"))
         .unwrap_or_else(|_| unreachable!());
-    let details_field = FormFieldPlacement::new("email", 0, 0, true, false, None, None)
-        .unwrap_or_else(|_| unreachable!());
-
-    let summary_section = FormSection::new(
-        "summary_section",
-        "Summary",
-        0,
-        true,
-        1,
-        vec![summary_field],
-        Vec::new(),
-    )
-    .unwrap_or_else(|_| unreachable!());
-    let details_section = FormSection::new(
-        "details_section",
-        "Details",
-        0,
-        true,
-        1,
-        vec![details_field],
-        Vec::new(),
+    let action = BusinessRuleAction::new(
+        BusinessRuleActionType::PreventSave,
+        None,
+        None,
+        Some("Closed tasks cannot be saved.".to_owned()),
     )
     .unwrap_or_else(|_| unreachable!());
-
-    let summary_tab = FormTab::new("summary", "Summary", 0, true, vec![summary_section])
+    service
+        .save_business_rule(
+            &alice,
+            entity_business_rule_input("closed_guard", vec![condition], vec![action]),
+        )
+        .await
         .unwrap_or_else(|_| unreachable!());
-    let details_tab = FormTab::new("details", "Details", 2, true, vec![details_section])
+
+    let record = service
+        .create_runtime_record(&alice, "task", json!({"title": "Draft", "status": "open"}))
+        .await
         .unwrap_or_else(|_| unreachable!());
 
     let result = service
-        .save_form(
-            &actor,
-            SaveFormInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "main_form".to_owned(),
-                display_name: "Main Form".to_owned(),
-                form_type: FormType::Main,
-                tabs: vec![summary_tab, details_tab],
-                header_fields: Vec::new(),
-            },
+        .update_runtime_record(
+            &alice,
+            "task",
+            record.record_id().as_str(),
+            json!({"title": "Draft", "status": "closed"}),
         )
         .await;
+    assert!(
+        matches!(result, Err(AppError::Validation(message)) if message == "Closed tasks cannot be saved.")
+    );
 
-    match result {
-        Err(AppError::Validation(message)) => {
-            assert!(
-                message
-                    .contains("form tab positions must form contiguous sequence starting at zero")
-            );
-        }
-        _ => panic!("expected form position validation failure"),
-    }
+    let events = audit_repository.events.lock().await;
+    let rejection = events
+        .iter()
+        .find(|event| event.action == AuditAction::RuntimeRecordBusinessRuleRejected)
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(rejection.resource_id, "task");
+    let detail: Value = serde_json::from_str(rejection.detail.as_deref().unwrap_or_default())
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(detail["operation"], json!("update"));
+    assert_eq!(
+        detail["rejections"][0]["rule_logical_name"],
+        json!("closed_guard")
+    );
+    assert_eq!(
+        detail["rejections"][0]["action_type"],
+        json!("prevent_save")
+    );
 }
 
 #[tokio::test]
-async fn save_view_rejects_duplicate_column_positions() {
+async fn business_rule_value_actions_apply_in_rule_and_action_order() {
     let tenant_id = TenantId::new();
-    let subject = "maria";
     let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
+        (tenant_id, "alice".to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
         ],
     )]);
     let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
-
-    let seeded = register_publish_entity_with_text_fields(
-        &service,
-        &actor,
-        "contact",
-        "Contact",
-        &["name", "email"],
-    )
-    .await;
-    assert!(seeded.is_ok());
-
-    let result = service
-        .save_view(
-            &actor,
-            SaveViewInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "main_view".to_owned(),
-                display_name: "Main View".to_owned(),
-                view_type: ViewType::Grid,
-                columns: vec![
-                    ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!()),
-                    ViewColumn::new("email", 0, None, None).unwrap_or_else(|_| unreachable!()),
-                ],
-                default_sort: None,
-                filter_criteria: None,
-                is_default: true,
-            },
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        register_publish_entity_with_text_fields(
+            &service,
+            &alice,
+            "task",
+            "Task",
+            &["title", "priority", "queue"]
         )
-        .await;
+        .await
+        .is_ok()
+    );
 
-    match result {
-        Err(AppError::Validation(message)) => {
-            assert!(message.contains("duplicate view column position"));
-        }
-        _ => panic!("expected view position validation failure"),
+    let always = || {
+        BusinessRuleCondition::new("title", BusinessRuleOperator::Neq, Value::Null)
+            .unwrap_or_else(|_| unreachable!())
+    };
+    let value_action = |action_type, field: &str, value: &str| {
+        BusinessRuleAction::new(
+            action_type,
+            Some(field.to_owned()),
+            Some(json!(value)),
+            None,
+        )
+        .unwrap_or_else(|_| unreachable!())
+    };
+    for (logical_name, actions) in [
+        (
+            "b_rule",
+            vec![
+                value_action(BusinessRuleActionType::SetFieldValue, "priority", "high"),
+                value_action(BusinessRuleActionType::SetDefaultValue, "queue", "triage"),
+            ],
+        ),
+        (
+            "a_rule",
+            vec![
+                value_action(BusinessRuleActionType::SetFieldValue, "priority", "low"),
+                value_action(BusinessRuleActionType::SetDefaultValue, "queue", "general"),
+                value_action(BusinessRuleActionType::SetFieldValue, "priority", "medium"),
+            ],
+        ),
+    ] {
+        service
+            .save_business_rule(
+                &alice,
+                entity_business_rule_input(logical_name, vec![always()], actions),
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
     }
+
+    let record = service
+        .create_runtime_record(&alice, "task", json!({"title": "Draft"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(record.data()["priority"], json!("high"));
+    assert_eq!(record.data()["queue"], json!("general"));
+
+    let record = service
+        .create_runtime_record(&alice, "task", json!({"title": "Draft", "queue": "vip"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(record.data()["queue"], json!("vip"));
 }
 
 #[tokio::test]
-async fn save_form_normalizes_nested_layout_order_by_position() {
+async fn create_runtime_record_applies_entity_business_rule_value_actions() {
     let tenant_id = TenantId::new();
-    let subject = "nina";
+    let subject = "rule_value_actions";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
         ],
     )]);
     let (service, _) = build_service(grants);
     let actor = actor(tenant_id, subject);
 
-    let seeded = register_publish_entity_with_text_fields(
-        &service,
-        &actor,
-        "contact",
-        "Contact",
-        &["title", "name", "email", "phone"],
-    )
-    .await;
-    assert!(seeded.is_ok());
-
-    let summary_field = FormFieldPlacement::new("phone", 0, 0, true, false, None, None)
-        .unwrap_or_else(|_| unreachable!());
-    let details_field_late = FormFieldPlacement::new("email", 0, 1, true, false, None, None)
-        .unwrap_or_else(|_| unreachable!());
-    let details_field_early = FormFieldPlacement::new("name", 0, 0, true, false, None, None)
-        .unwrap_or_else(|_| unreachable!());
-    let intro_field = FormFieldPlacement::new("title", 0, 0, true, false, None, None)
-        .unwrap_or_else(|_| unreachable!());
-
-    let summary_section = FormSection::new(
-        "summary",
-        "Summary",
-        1,
-        true,
-        1,
-        vec![summary_field],
-        Vec::new(),
-    )
-    .unwrap_or_else(|_| unreachable!());
-    let details_section = FormSection::new(
-        "details",
-        "Details",
-        0,
-        true,
-        1,
-        vec![details_field_late, details_field_early],
-        Vec::new(),
-    )
-    .unwrap_or_else(|_| unreachable!());
-    let intro_section = FormSection::new(
-        "intro_section",
-        "Intro",
-        0,
-        true,
-        1,
-        vec![intro_field],
-        Vec::new(),
-    )
-    .unwrap_or_else(|_| unreachable!());
-
-    let main_tab = FormTab::new(
-        "main_tab",
-        "Main",
-        1,
-        true,
-        vec![summary_section, details_section],
-    )
-    .unwrap_or_else(|_| unreachable!());
-    let intro_tab = FormTab::new("intro_tab", "Intro", 0, true, vec![intro_section])
-        .unwrap_or_else(|_| unreachable!());
-
-    let saved = service
-        .save_form(
-            &actor,
-            SaveFormInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "main_form".to_owned(),
-                display_name: "Main Form".to_owned(),
-                form_type: FormType::Main,
-                tabs: vec![main_tab, intro_tab],
-                header_fields: Vec::new(),
-            },
-        )
-        .await;
-
-    assert!(saved.is_ok());
-    let saved = saved.unwrap_or_else(|_| unreachable!());
-    let tab_order: Vec<&str> = saved
-        .tabs()
-        .iter()
-        .map(|tab| tab.logical_name().as_str())
-        .collect();
-    assert_eq!(tab_order, vec!["intro_tab", "main_tab"]);
-
-    let main_sections = saved.tabs()[1].sections();
-    let section_order: Vec<&str> = main_sections
-        .iter()
-        .map(|section| section.logical_name().as_str())
-        .collect();
-    assert_eq!(section_order, vec!["details", "summary"]);
-
-    let details_fields: Vec<&str> = main_sections[0]
-        .fields()
-        .iter()
-        .map(|field| field.field_logical_name().as_str())
-        .collect();
-    assert_eq!(details_fields, vec!["name", "email"]);
-}
-
-#[tokio::test]
-async fn save_view_normalizes_column_order_by_position() {
-    let tenant_id = TenantId::new();
-    let subject = "olga";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-        ],
-    )]);
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
+    assert!(
+        service
+            .register_entity(&actor, "lead", "Lead")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "lead".to_owned(),
+                    logical_name: "name".to_owned(),
+                    display_name: "Name".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "lead".to_owned(),
+                    logical_name: "stage".to_owned(),
+                    display_name: "Stage".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: false,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "lead".to_owned(),
+                    logical_name: "tier".to_owned(),
+                    display_name: "Tier".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: false,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&actor, "lead").await.is_ok());
 
-    let seeded = register_publish_entity_with_text_fields(
-        &service,
-        &actor,
-        "contact",
-        "Contact",
-        &["name", "email", "phone"],
-    )
-    .await;
-    assert!(seeded.is_ok());
+    let condition =
+        BusinessRuleCondition::new("name", BusinessRuleOperator::Contains, json!("acme"));
+    assert!(condition.is_ok());
+    let set_default_stage = BusinessRuleAction::new(
+        BusinessRuleActionType::SetDefaultValue,
+        Some("stage".to_owned()),
+        Some(json!("new")),
+        None,
+    );
+    assert!(set_default_stage.is_ok());
+    let set_tier = BusinessRuleAction::new(
+        BusinessRuleActionType::SetFieldValue,
+        Some("tier".to_owned()),
+        Some(json!("vip")),
+        None,
+    );
+    assert!(set_tier.is_ok());
 
-    let saved = service
-        .save_view(
+    let saved_rule = service
+        .save_business_rule(
             &actor,
-            SaveViewInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "custom_view".to_owned(),
-                display_name: "Custom View".to_owned(),
-                view_type: ViewType::Grid,
-                columns: vec![
-                    ViewColumn::new("email", 1, None, None).unwrap_or_else(|_| unreachable!()),
-                    ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!()),
-                    ViewColumn::new("phone", 2, None, None).unwrap_or_else(|_| unreachable!()),
+            SaveBusinessRuleInput {
+                entity_logical_name: "lead".to_owned(),
+                logical_name: "lead_defaults".to_owned(),
+                display_name: "Lead Defaults".to_owned(),
+                scope: BusinessRuleScope::Entity,
+                form_logical_name: None,
+                conditions: vec![condition.unwrap_or_else(|_| unreachable!())],
+                actions: vec![
+                    set_default_stage.unwrap_or_else(|_| unreachable!()),
+                    set_tier.unwrap_or_else(|_| unreachable!()),
                 ],
-                default_sort: None,
-                filter_criteria: None,
-                is_default: false,
+                is_active: true,
             },
         )
         .await;
+    assert!(saved_rule.is_ok());
 
-    assert!(saved.is_ok());
-    let saved = saved.unwrap_or_else(|_| unreachable!());
-    let column_order: Vec<&str> = saved
-        .columns()
-        .iter()
-        .map(|column| column.field_logical_name().as_str())
-        .collect();
-    assert_eq!(column_order, vec!["name", "email", "phone"]);
+    let created = service
+        .create_runtime_record(&actor, "lead", json!({"name": "Acme Corporation"}))
+        .await;
+    assert!(created.is_ok());
+    let created = created.unwrap_or_else(|_| unreachable!());
+    let data = created.data().as_object();
+    assert!(data.is_some());
+    assert_eq!(
+        data.and_then(|object| object.get("stage")),
+        Some(&json!("new"))
+    );
+    assert_eq!(
+        data.and_then(|object| object.get("tier")),
+        Some(&json!("vip"))
+    );
 }
 
 #[tokio::test]
-async fn save_form_supports_reorder_then_undo_redo_transitions() {
+async fn create_runtime_record_honors_requiredness_overrides() {
     let tenant_id = TenantId::new();
-    let subject = "paula";
+    let subject = "rule_required_overrides";
     let grants = HashMap::from([(
         (tenant_id, subject.to_owned()),
         vec![
             Permission::MetadataEntityCreate,
             Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
         ],
     )]);
     let (service, _) = build_service(grants);
     let actor = actor(tenant_id, subject);
 
-    let seeded = register_publish_entity_with_text_fields(
-        &service,
-        &actor,
-        "contact",
-        "Contact",
-        &["title", "name", "email", "phone"],
-    )
-    .await;
-    assert!(seeded.is_ok());
+    assert!(
+        service
+            .register_entity(&actor, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "name".to_owned(),
+                    display_name: "Name".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "phone".to_owned(),
+                    display_name: "Phone".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: false,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "active".to_owned(),
+                    display_name: "Active".to_owned(),
+                    field_type: FieldType::Boolean,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&actor, "contact").await.is_ok());
 
-    let initial = service
-        .save_form(
+    let requires_phone_condition =
+        BusinessRuleCondition::new("name", BusinessRuleOperator::Eq, json!("Alice"));
+    assert!(requires_phone_condition.is_ok());
+    let require_phone_action = BusinessRuleAction::new(
+        BusinessRuleActionType::SetRequired,
+        Some("phone".to_owned()),
+        None,
+        None,
+    );
+    assert!(require_phone_action.is_ok());
+    let optional_active_condition =
+        BusinessRuleCondition::new("name", BusinessRuleOperator::Eq, json!("Guest"));
+    assert!(optional_active_condition.is_ok());
+    let optional_active_action = BusinessRuleAction::new(
+        BusinessRuleActionType::SetOptional,
+        Some("active".to_owned()),
+        None,
+        None,
+    );
+    assert!(optional_active_action.is_ok());
+
+    let save_required_rule = service
+        .save_business_rule(
             &actor,
-            SaveFormInput {
+            SaveBusinessRuleInput {
                 entity_logical_name: "contact".to_owned(),
-                logical_name: "custom_form".to_owned(),
-                display_name: "Custom Form".to_owned(),
-                form_type: FormType::Main,
-                tabs: vec![
-                    FormTab::new(
-                        "intro_tab",
-                        "Intro",
-                        0,
-                        true,
-                        vec![
-                            FormSection::new(
-                                "intro_section",
-                                "Intro Section",
-                                0,
-                                true,
-                                1,
-                                vec![
-                                    FormFieldPlacement::new("title", 0, 0, true, false, None, None)
-                                        .unwrap_or_else(|_| unreachable!()),
-                                ],
-                                Vec::new(),
-                            )
-                            .unwrap_or_else(|_| unreachable!()),
-                        ],
-                    )
-                    .unwrap_or_else(|_| unreachable!()),
-                    FormTab::new(
-                        "main_tab",
-                        "Main",
-                        1,
-                        true,
-                        vec![
-                            FormSection::new(
-                                "details",
-                                "Details",
-                                0,
-                                true,
-                                1,
-                                vec![
-                                    FormFieldPlacement::new("name", 0, 0, true, false, None, None)
-                                        .unwrap_or_else(|_| unreachable!()),
-                                    FormFieldPlacement::new("email", 0, 1, true, false, None, None)
-                                        .unwrap_or_else(|_| unreachable!()),
-                                ],
-                                Vec::new(),
-                            )
-                            .unwrap_or_else(|_| unreachable!()),
-                            FormSection::new(
-                                "summary",
-                                "Summary",
-                                1,
-                                true,
-                                1,
-                                vec![
-                                    FormFieldPlacement::new("phone", 0, 0, true, false, None, None)
-                                        .unwrap_or_else(|_| unreachable!()),
-                                ],
-                                Vec::new(),
-                            )
-                            .unwrap_or_else(|_| unreachable!()),
-                        ],
-                    )
-                    .unwrap_or_else(|_| unreachable!()),
-                ],
-                header_fields: Vec::new(),
+                logical_name: "phone_required_for_alice".to_owned(),
+                display_name: "Phone Required For Alice".to_owned(),
+                scope: BusinessRuleScope::Entity,
+                form_logical_name: None,
+                conditions: vec![requires_phone_condition.unwrap_or_else(|_| unreachable!())],
+                actions: vec![require_phone_action.unwrap_or_else(|_| unreachable!())],
+                is_active: true,
             },
         )
         .await;
-    assert!(initial.is_ok());
+    assert!(save_required_rule.is_ok());
 
-    let reordered = service
-        .save_form(
+    let save_optional_rule = service
+        .save_business_rule(
             &actor,
-            SaveFormInput {
+            SaveBusinessRuleInput {
                 entity_logical_name: "contact".to_owned(),
-                logical_name: "custom_form".to_owned(),
-                display_name: "Custom Form".to_owned(),
-                form_type: FormType::Main,
-                tabs: vec![
-                    FormTab::new(
-                        "main_tab",
-                        "Main",
-                        0,
-                        true,
-                        vec![
-                            FormSection::new(
-                                "summary",
-                                "Summary",
-                                0,
-                                true,
-                                1,
-                                vec![
-                                    FormFieldPlacement::new("phone", 0, 0, true, false, None, None)
-                                        .unwrap_or_else(|_| unreachable!()),
-                                ],
-                                Vec::new(),
-                            )
-                            .unwrap_or_else(|_| unreachable!()),
-                            FormSection::new(
-                                "details",
-                                "Details",
-                                1,
-                                true,
-                                1,
-                                vec![
-                                    FormFieldPlacement::new("email", 0, 0, true, false, None, None)
-                                        .unwrap_or_else(|_| unreachable!()),
-                                    FormFieldPlacement::new("name", 0, 1, true, false, None, None)
-                                        .unwrap_or_else(|_| unreachable!()),
-                                ],
-                                Vec::new(),
-                            )
-                            .unwrap_or_else(|_| unreachable!()),
-                        ],
-                    )
-                    .unwrap_or_else(|_| unreachable!()),
-                    FormTab::new(
-                        "intro_tab",
-                        "Intro",
-                        1,
-                        true,
-                        vec![
-                            FormSection::new(
-                                "intro_section",
-                                "Intro Section",
-                                0,
-                                true,
-                                1,
-                                vec![
-                                    FormFieldPlacement::new("title", 0, 0, true, false, None, None)
-                                        .unwrap_or_else(|_| unreachable!()),
-                                ],
-                                Vec::new(),
-                            )
-                            .unwrap_or_else(|_| unreachable!()),
-                        ],
-                    )
-                    .unwrap_or_else(|_| unreachable!()),
-                ],
-                header_fields: Vec::new(),
+                logical_name: "active_optional_for_guest".to_owned(),
+                display_name: "Active Optional For Guest".to_owned(),
+                scope: BusinessRuleScope::Entity,
+                form_logical_name: None,
+                conditions: vec![optional_active_condition.unwrap_or_else(|_| unreachable!())],
+                actions: vec![optional_active_action.unwrap_or_else(|_| unreachable!())],
+                is_active: true,
             },
         )
         .await;
-    assert!(reordered.is_ok());
-    let reordered = reordered.unwrap_or_else(|_| unreachable!());
-    let reordered_tabs: Vec<&str> = reordered
-        .tabs()
-        .iter()
-        .map(|tab| tab.logical_name().as_str())
-        .collect();
-    assert_eq!(reordered_tabs, vec!["main_tab", "intro_tab"]);
-    let reordered_main_sections: Vec<&str> = reordered.tabs()[0]
-        .sections()
-        .iter()
-        .map(|section| section.logical_name().as_str())
-        .collect();
-    assert_eq!(reordered_main_sections, vec!["summary", "details"]);
-    let reordered_details_fields: Vec<&str> = reordered.tabs()[0].sections()[1]
-        .fields()
-        .iter()
-        .map(|field| field.field_logical_name().as_str())
-        .collect();
-    assert_eq!(reordered_details_fields, vec!["email", "name"]);
+    assert!(save_optional_rule.is_ok());
 
-    let undone = service
-        .save_form(
+    let missing_phone = service
+        .create_runtime_record(&actor, "contact", json!({"name": "Alice", "active": true}))
+        .await;
+    assert!(matches!(missing_phone, Err(AppError::Validation(_))));
+
+    let missing_active = service
+        .create_runtime_record(&actor, "contact", json!({"name": "Guest"}))
+        .await;
+    assert!(missing_active.is_ok());
+}
+
+#[tokio::test]
+async fn update_runtime_record_preserves_hidden_values_and_blocks_locked_changes() {
+    let tenant_id = TenantId::new();
+    let subject = "rule_locking";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    assert!(
+        service
+            .register_entity(&actor, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "name".to_owned(),
+                    display_name: "Name".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "status".to_owned(),
+                    display_name: "Status".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: false,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&actor, "contact").await.is_ok());
+
+    let created = service
+        .create_runtime_record(
             &actor,
-            SaveFormInput {
+            "contact",
+            json!({"name": "Alice", "status": "open"}),
+        )
+        .await;
+    assert!(created.is_ok());
+    let created = created.unwrap_or_else(|_| unreachable!());
+
+    let condition = BusinessRuleCondition::new("name", BusinessRuleOperator::Eq, json!("Alice"));
+    assert!(condition.is_ok());
+    let hide_status = BusinessRuleAction::new(
+        BusinessRuleActionType::HideField,
+        Some("status".to_owned()),
+        None,
+        None,
+    );
+    assert!(hide_status.is_ok());
+    let lock_status = BusinessRuleAction::new(
+        BusinessRuleActionType::LockField,
+        Some("status".to_owned()),
+        None,
+        None,
+    );
+    assert!(lock_status.is_ok());
+
+    let saved_rule = service
+        .save_business_rule(
+            &actor,
+            SaveBusinessRuleInput {
                 entity_logical_name: "contact".to_owned(),
-                logical_name: "custom_form".to_owned(),
-                display_name: "Custom Form".to_owned(),
-                form_type: FormType::Main,
-                tabs: vec![
-                    FormTab::new(
-                        "intro_tab",
-                        "Intro",
-                        0,
-                        true,
-                        vec![
-                            FormSection::new(
-                                "intro_section",
-                                "Intro Section",
-                                0,
-                                true,
-                                1,
-                                vec![
-                                    FormFieldPlacement::new("title", 0, 0, true, false, None, None)
-                                        .unwrap_or_else(|_| unreachable!()),
-                                ],
-                                Vec::new(),
-                            )
-                            .unwrap_or_else(|_| unreachable!()),
-                        ],
-                    )
-                    .unwrap_or_else(|_| unreachable!()),
-                    FormTab::new(
-                        "main_tab",
-                        "Main",
-                        1,
-                        true,
-                        vec![
-                            FormSection::new(
-                                "details",
-                                "Details",
-                                0,
-                                true,
-                                1,
-                                vec![
-                                    FormFieldPlacement::new("name", 0, 0, true, false, None, None)
-                                        .unwrap_or_else(|_| unreachable!()),
-                                    FormFieldPlacement::new("email", 0, 1, true, false, None, None)
-                                        .unwrap_or_else(|_| unreachable!()),
-                                ],
-                                Vec::new(),
-                            )
-                            .unwrap_or_else(|_| unreachable!()),
-                            FormSection::new(
-                                "summary",
-                                "Summary",
-                                1,
-                                true,
-                                1,
-                                vec![
-                                    FormFieldPlacement::new("phone", 0, 0, true, false, None, None)
-                                        .unwrap_or_else(|_| unreachable!()),
-                                ],
-                                Vec::new(),
-                            )
-                            .unwrap_or_else(|_| unreachable!()),
-                        ],
-                    )
-                    .unwrap_or_else(|_| unreachable!()),
+                logical_name: "lock_status".to_owned(),
+                display_name: "Lock Status".to_owned(),
+                scope: BusinessRuleScope::Entity,
+                form_logical_name: None,
+                conditions: vec![condition.unwrap_or_else(|_| unreachable!())],
+                actions: vec![
+                    hide_status.unwrap_or_else(|_| unreachable!()),
+                    lock_status.unwrap_or_else(|_| unreachable!()),
                 ],
-                header_fields: Vec::new(),
+                is_active: true,
             },
         )
         .await;
-    assert!(undone.is_ok());
-
-    let redone = service
-        .save_form(
-            &actor,
-            SaveFormInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "custom_form".to_owned(),
-                display_name: "Custom Form".to_owned(),
-                form_type: FormType::Main,
-                tabs: reordered.tabs().to_vec(),
-                header_fields: Vec::new(),
-            },
+    assert!(saved_rule.is_ok());
+
+    let updated_hidden = service
+        .update_runtime_record(
+            &actor,
+            "contact",
+            created.record_id().as_str(),
+            json!({"name": "Alice"}),
+        )
+        .await;
+    assert!(updated_hidden.is_ok());
+    let updated_hidden = updated_hidden.unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        updated_hidden
+            .data()
+            .as_object()
+            .and_then(|object| object.get("status")),
+        Some(&json!("open"))
+    );
+
+    let locked_change = service
+        .update_runtime_record(
+            &actor,
+            "contact",
+            created.record_id().as_str(),
+            json!({"name": "Alice", "status": "closed"}),
+        )
+        .await;
+    assert!(matches!(locked_change, Err(AppError::Validation(_))));
+}
+
+#[tokio::test]
+async fn query_runtime_records_requires_runtime_read_permission() {
+    let tenant_id = TenantId::new();
+    let subject = "heidi";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    assert!(
+        service
+            .register_entity(&actor, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "name".to_owned(),
+                    display_name: "Name".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&actor, "contact").await.is_ok());
+
+    let queried = service
+        .query_runtime_records(
+            &actor,
+            "contact",
+            RuntimeRecordQuery {
+                limit: 50,
+                offset: 0,
+                logical_mode: RuntimeRecordLogicalMode::And,
+                where_clause: None,
+                filters: vec![RuntimeRecordFilter {
+                    scope_alias: None,
+                    field_logical_name: "name".to_owned(),
+                    operator: RuntimeRecordOperator::Eq,
+                    field_type: FieldType::Text,
+                    field_value: json!("Alice"),
+                }],
+                links: Vec::new(),
+                sort: Vec::new(),
+                owner_subject: None,
+                include_inactive: false,
+            },
+        )
+        .await;
+
+    assert!(matches!(queried, Err(AppError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn delete_runtime_record_blocks_when_relation_exists() {
+    let tenant_id = TenantId::new();
+    let subject = "eve";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    let created_contact = service.register_entity(&actor, "contact", "Contact").await;
+    assert!(created_contact.is_ok());
+    let created_deal = service.register_entity(&actor, "deal", "Deal").await;
+    assert!(created_deal.is_ok());
+
+    let contact_name_field = service
+        .save_field(
+            &actor,
+            SaveFieldInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "name".to_owned(),
+                display_name: "Name".to_owned(),
+                field_type: FieldType::Text,
+                is_required: true,
+                is_unique: false,
+                default_value: None,
+                calculation_expression: None,
+                relation_target_entity: None,
+                option_set_logical_name: None,
+                classification: None,
+            },
+        )
+        .await;
+    assert!(contact_name_field.is_ok());
+
+    let deal_owner_field = service
+        .save_field(
+            &actor,
+            SaveFieldInput {
+                entity_logical_name: "deal".to_owned(),
+                logical_name: "owner_contact_id".to_owned(),
+                display_name: "Owner Contact".to_owned(),
+                field_type: FieldType::Relation,
+                is_required: true,
+                is_unique: false,
+                default_value: None,
+                calculation_expression: None,
+                relation_target_entity: Some("contact".to_owned()),
+                option_set_logical_name: None,
+                classification: None,
+            },
+        )
+        .await;
+    assert!(deal_owner_field.is_ok());
+
+    let published_contact = service.publish_entity(&actor, "contact").await;
+    assert!(published_contact.is_ok());
+    let published_deal = service.publish_entity(&actor, "deal").await;
+    assert!(published_deal.is_ok());
+
+    let contact_record = service
+        .create_runtime_record(&actor, "contact", json!({"name": "Alice"}))
+        .await;
+    assert!(contact_record.is_ok());
+    let contact_record = contact_record.unwrap_or_else(|_| unreachable!());
+
+    let created_deal_record = service
+        .create_runtime_record(
+            &actor,
+            "deal",
+            json!({"owner_contact_id": contact_record.record_id().as_str()}),
+        )
+        .await;
+    assert!(created_deal_record.is_ok());
+
+    let delete_result = service
+        .delete_runtime_record(&actor, "contact", contact_record.record_id().as_str())
+        .await;
+    assert!(matches!(delete_result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn many_to_many_associations_are_managed_through_record_relations() {
+    let tenant_id = TenantId::new();
+    let subject = "olivia";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
+        ],
+    )]);
+    let (service, audit_repository) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    assert!(
+        register_publish_entity_with_text_fields(&service, &actor, "tag", "Tag", &["name"])
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .register_entity(&actor, "deal", "Deal")
+            .await
+            .is_ok()
+    );
+    for (logical_name, field_type, relation_target_entity) in [
+        ("title", FieldType::Text, None),
+        ("tags", FieldType::ManyToMany, Some("tag".to_owned())),
+    ] {
+        assert!(
+            service
+                .save_field(
+                    &actor,
+                    SaveFieldInput {
+                        entity_logical_name: "deal".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type,
+                        is_required: false,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(service.publish_entity(&actor, "deal").await.is_ok());
+
+    let tag = service
+        .create_runtime_record(&actor, "tag", json!({"name": "vip"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let deal = service
+        .create_runtime_record(&actor, "deal", json!({"title": "Alpha"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let deal_id = deal.record_id().as_str();
+    let tag_id = tag.record_id().as_str();
+
+    let payload_write = service
+        .create_runtime_record(&actor, "deal", json!({"title": "Beta", "tags": [tag_id]}))
+        .await;
+    assert!(matches!(payload_write, Err(AppError::Validation(_))));
+
+    let associated = service
+        .associate_runtime_records(&actor, "deal", deal_id, "tags", tag_id)
+        .await;
+    assert!(matches!(associated, Ok(true)));
+    let associated_again = service
+        .associate_runtime_records(&actor, "deal", deal_id, "tags", tag_id)
+        .await;
+    assert!(matches!(associated_again, Ok(false)));
+    let wrong_field = service
+        .associate_runtime_records(&actor, "deal", deal_id, "title", tag_id)
+        .await;
+    assert!(matches!(wrong_field, Err(AppError::Validation(_))));
+    let missing_target = service
+        .associate_runtime_records(&actor, "deal", deal_id, "tags", "missing-tag")
+        .await;
+    assert!(matches!(missing_target, Err(AppError::NotFound(_))));
+
+    let listed = service
+        .list_associated_runtime_records(&actor, "deal", deal_id, "tags")
+        .await
+        .unwrap_or_default();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].record_id().as_str(), tag_id);
+
+    let sorted_through_link = service
+        .query_runtime_records(
+            &actor,
+            "deal",
+            RuntimeRecordQuery {
+                limit: 10,
+                offset: 0,
+                logical_mode: RuntimeRecordLogicalMode::And,
+                where_clause: None,
+                filters: Vec::new(),
+                links: vec![RuntimeRecordLink {
+                    alias: "tag".to_owned(),
+                    parent_alias: None,
+                    relation_field_logical_name: "tags".to_owned(),
+                    target_entity_logical_name: String::new(),
+                    join_type: RuntimeRecordJoinType::Inner,
+                    cardinality: RuntimeRecordLinkCardinality::default(),
+                }],
+                sort: vec![RuntimeRecordSort {
+                    scope_alias: Some("tag".to_owned()),
+                    field_logical_name: "name".to_owned(),
+                    field_type: FieldType::Text,
+                    direction: RuntimeRecordSortDirection::Asc,
+                }],
+                owner_subject: None,
+                include_inactive: false,
+            },
+        )
+        .await;
+    assert!(matches!(sorted_through_link, Err(AppError::Validation(_))));
+
+    assert!(
+        service
+            .disassociate_runtime_records(&actor, "deal", deal_id, "tags", tag_id)
+            .await
+            .is_ok()
+    );
+    let disassociated_again = service
+        .disassociate_runtime_records(&actor, "deal", deal_id, "tags", tag_id)
+        .await;
+    assert!(matches!(disassociated_again, Err(AppError::NotFound(_))));
+
+    let events = audit_repository.events.lock().await;
+    assert_eq!(
+        events
+            .iter()
+            .filter(|event| event.action == AuditAction::RuntimeRecordAssociated)
+            .count(),
+        1
+    );
+    assert!(
+        events
+            .iter()
+            .any(|event| event.action == AuditAction::RuntimeRecordDisassociated)
+    );
+}
+
+#[tokio::test]
+async fn get_and_delete_runtime_record_succeed_when_unreferenced() {
+    let tenant_id = TenantId::new();
+    let subject = "frank";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
+        ],
+    )]);
+    let (service, audit_repository) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    let created_entity = service.register_entity(&actor, "note", "Note").await;
+    assert!(created_entity.is_ok());
+    let saved_field = service
+        .save_field(
+            &actor,
+            SaveFieldInput {
+                entity_logical_name: "note".to_owned(),
+                logical_name: "title".to_owned(),
+                display_name: "Title".to_owned(),
+                field_type: FieldType::Text,
+                is_required: true,
+                is_unique: false,
+                default_value: None,
+                calculation_expression: None,
+                relation_target_entity: None,
+                option_set_logical_name: None,
+                classification: None,
+            },
+        )
+        .await;
+    assert!(saved_field.is_ok());
+
+    let published = service.publish_entity(&actor, "note").await;
+    assert!(published.is_ok());
+
+    let created_record = service
+        .create_runtime_record(&actor, "note", json!({"title": "A"}))
+        .await;
+    assert!(created_record.is_ok());
+    let created_record = created_record.unwrap_or_else(|_| unreachable!());
+
+    let fetched = service
+        .get_runtime_record(&actor, "note", created_record.record_id().as_str())
+        .await;
+    assert!(fetched.is_ok());
+
+    let deleted = service
+        .delete_runtime_record(&actor, "note", created_record.record_id().as_str())
+        .await;
+    assert!(deleted.is_ok());
+
+    let refetch = service
+        .get_runtime_record(&actor, "note", created_record.record_id().as_str())
+        .await;
+    assert!(matches!(refetch, Err(AppError::NotFound(_))));
+
+    let events = audit_repository.events.lock().await;
+    assert!(events.iter().any(|event| {
+        event.action == AuditAction::RuntimeRecordDeleted
+            && event.resource_id == created_record.record_id().as_str()
+    }));
+}
+
+#[tokio::test]
+async fn audit_outbox_commits_runtime_record_audit_events_with_the_write() {
+    let tenant_id = TenantId::new();
+    let subject = "olga";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
+        ],
+    )]);
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let repository = Arc::new(FakeRepository::new());
+    let service = MetadataService::new(
+        repository.clone(),
+        AuthorizationService::new(
+            Arc::new(FakeAuthorizationRepository {
+                grants,
+                runtime_field_grants: HashMap::new(),
+                team_peers: HashMap::new(),
+                role_names: HashMap::new(),
+            }),
+            audit_repository.clone(),
+        ),
+        audit_repository.clone(),
+    )
+    .with_audit_outbox(true);
+    let actor = actor(tenant_id, subject);
+    register_publish_entity_with_text_fields(&service, &actor, "note", "Note", &["title"])
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let record = service
+        .create_runtime_record(&actor, "note", json!({"title": "A"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = record.record_id().as_str().to_owned();
+    service
+        .update_runtime_record(&actor, "note", record_id.as_str(), json!({"title": "B"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    service
+        .delete_runtime_record(&actor, "note", record_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let outboxed: Vec<(AuditAction, String)> = repository
+        .outboxed_audit_events
+        .lock()
+        .await
+        .iter()
+        .map(|event| (event.action, event.resource_id.clone()))
+        .collect();
+    assert_eq!(
+        outboxed,
+        vec![
+            (AuditAction::RuntimeRecordCreated, record_id.clone()),
+            (AuditAction::RuntimeRecordUpdated, record_id.clone()),
+            (AuditAction::RuntimeRecordDeleted, record_id),
+        ]
+    );
+    assert!(!audit_repository.events.lock().await.iter().any(|event| {
+        matches!(
+            event.action,
+            AuditAction::RuntimeRecordCreated
+                | AuditAction::RuntimeRecordUpdated
+                | AuditAction::RuntimeRecordDeleted
+        )
+    }));
+}
+
+struct FakeLegalHoldRepository {
+    hold: LegalHold,
+}
+
+#[async_trait]
+impl LegalHoldRepository for FakeLegalHoldRepository {
+    async fn create_hold(
+        &self,
+        _tenant_id: TenantId,
+        _created_by_subject: &str,
+        _input: CreateLegalHoldInput,
+    ) -> AppResult<LegalHold> {
+        Err(AppError::Internal("not used in metadata tests".to_owned()))
+    }
+
+    async fn list_holds(
+        &self,
+        _tenant_id: TenantId,
+        _include_released: bool,
+    ) -> AppResult<Vec<LegalHold>> {
+        Ok(vec![self.hold.clone()])
+    }
+
+    async fn release_hold(
+        &self,
+        _tenant_id: TenantId,
+        _hold_id: &str,
+        _released_by_subject: &str,
+    ) -> AppResult<Option<LegalHold>> {
+        Ok(None)
+    }
+
+    async fn find_active_record_hold(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<LegalHold>> {
+        Ok((self.hold.tenant_id == tenant_id
+            && self
+                .hold
+                .scope
+                .covers_runtime_record(entity_logical_name, record_id))
+        .then(|| self.hold.clone()))
+    }
+}
+
+#[tokio::test]
+async fn delete_runtime_record_blocks_records_under_legal_hold() {
+    let tenant_id = TenantId::new();
+    let subject = "frank";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let service = service.with_legal_hold_repository(Arc::new(FakeLegalHoldRepository {
+        hold: LegalHold {
+            hold_id: "hold-1".to_owned(),
+            tenant_id,
+            name: "Matter 42".to_owned(),
+            reason: "litigation".to_owned(),
+            scope: LegalHoldScope::RuntimeRecords {
+                entity_logical_name: "note".to_owned(),
+                record_ids: Vec::new(),
+            },
+            created_by_subject: "compliance".to_owned(),
+            created_at: chrono::Utc::now(),
+            released_by_subject: None,
+            released_at: None,
+        },
+    }));
+    let actor = actor(tenant_id, subject);
+
+    let registered =
+        register_publish_entity_with_text_fields(&service, &actor, "note", "Note", &["title"])
+            .await;
+    assert!(registered.is_ok());
+
+    let created_record = service
+        .create_runtime_record(&actor, "note", json!({"title": "A"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = created_record.record_id().as_str();
+
+    let deleted = service
+        .delete_runtime_record(&actor, "note", record_id)
+        .await;
+    assert!(matches!(deleted, Err(AppError::Conflict(_))));
+    let deleted_unchecked = service
+        .delete_runtime_record_unchecked(&actor, "note", record_id)
+        .await;
+    assert!(matches!(deleted_unchecked, Err(AppError::Conflict(_))));
+
+    let refetch = service.get_runtime_record(&actor, "note", record_id).await;
+    assert!(refetch.is_ok());
+}
+
+struct FakeComplianceZoneRepository {
+    assignments: Vec<ComplianceZoneAssignment>,
+    tags: Mutex<Vec<ComplianceZoneTag>>,
+}
+
+impl FakeComplianceZoneRepository {
+    fn with_assignments(assignments: &[(&str, &str)]) -> Self {
+        Self {
+            assignments: assignments
+                .iter()
+                .map(|(subject, zone)| ComplianceZoneAssignment {
+                    subject: (*subject).to_owned(),
+                    zone: (*zone).to_owned(),
+                    assigned_by_subject: "compliance".to_owned(),
+                    created_at: chrono::Utc::now(),
+                })
+                .collect(),
+            tags: Mutex::new(Vec::new()),
+        }
+    }
+
+    async fn tag(&self, entity_logical_name: &str, record_id: Option<&str>, zone: &str) {
+        self.tags.lock().await.push(ComplianceZoneTag {
+            entity_logical_name: entity_logical_name.to_owned(),
+            record_id: record_id.map(ToOwned::to_owned),
+            zone: zone.to_owned(),
+            tagged_by_subject: "compliance".to_owned(),
+            updated_at: chrono::Utc::now(),
+        });
+    }
+}
+
+#[async_trait]
+impl ComplianceZoneRepository for FakeComplianceZoneRepository {
+    async fn list_zone_assignments(
+        &self,
+        _tenant_id: TenantId,
+        subject: Option<&str>,
+    ) -> AppResult<Vec<ComplianceZoneAssignment>> {
+        Ok(self
+            .assignments
+            .iter()
+            .filter(|assignment| subject.is_none_or(|subject| assignment.subject == subject))
+            .cloned()
+            .collect())
+    }
+
+    async fn assign_zone(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _zone: &str,
+        _assigned_by_subject: &str,
+    ) -> AppResult<ComplianceZoneAssignment> {
+        Err(AppError::Internal("not used in metadata tests".to_owned()))
+    }
+
+    async fn unassign_zone(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _zone: &str,
+    ) -> AppResult<bool> {
+        Err(AppError::Internal("not used in metadata tests".to_owned()))
+    }
+
+    async fn list_zone_tags(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<ComplianceZoneTag>> {
+        Ok(self
+            .tags
+            .lock()
+            .await
+            .iter()
+            .filter(|tag| {
+                entity_logical_name.is_none_or(|entity| tag.entity_logical_name == entity)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn save_zone_tag(
+        &self,
+        _tenant_id: TenantId,
+        _input: SaveComplianceZoneTagInput,
+        _tagged_by_subject: &str,
+    ) -> AppResult<ComplianceZoneTag> {
+        Err(AppError::Internal("not used in metadata tests".to_owned()))
+    }
+
+    async fn remove_zone_tag(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _record_id: Option<&str>,
+    ) -> AppResult<bool> {
+        Err(AppError::Internal("not used in metadata tests".to_owned()))
+    }
+
+    async fn find_zone_tags(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_ids: &[String],
+    ) -> AppResult<Vec<ComplianceZoneTag>> {
+        Ok(self
+            .tags
+            .lock()
+            .await
+            .iter()
+            .filter(|tag| {
+                tag.entity_logical_name == entity_logical_name
+                    && tag
+                        .record_id
+                        .as_ref()
+                        .is_none_or(|record_id| record_ids.contains(record_id))
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+#[tokio::test]
+async fn runtime_records_restricted_to_a_compliance_zone_require_an_assignment() {
+    let tenant_id = TenantId::new();
+    let permissions = vec![
+        Permission::MetadataEntityCreate,
+        Permission::MetadataFieldWrite,
+        Permission::RuntimeRecordWrite,
+        Permission::RuntimeRecordRead,
+    ];
+    let grants = HashMap::from([
+        ((tenant_id, "frank".to_owned()), permissions.clone()),
+        ((tenant_id, "grace".to_owned()), permissions),
+    ]);
+    let (service, _) = build_service(grants);
+    let zones = Arc::new(FakeComplianceZoneRepository::with_assignments(&[
+        ("frank", "us"),
+        ("grace", "eu"),
+    ]));
+    let service = service.with_compliance_zone_repository(zones.clone());
+    let frank = actor(tenant_id, "frank");
+    let grace = actor(tenant_id, "grace");
+
+    let registered =
+        register_publish_entity_with_text_fields(&service, &frank, "note", "Note", &["title"])
+            .await;
+    assert!(registered.is_ok());
+
+    let restricted = service
+        .create_runtime_record(&frank, "note", json!({"title": "EU"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let open = service
+        .create_runtime_record(&frank, "note", json!({"title": "Open"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let restricted_id = restricted.record_id().as_str();
+    zones.tag("note", Some(restricted_id), "eu").await;
+
+    let denied = service
+        .get_runtime_record(&frank, "note", restricted_id)
+        .await;
+    let Err(AppError::Forbidden(message)) = denied else {
+        panic!("expected compliance zone denial, got {denied:?}");
+    };
+    assert!(message.contains("compliance zone 'eu'"));
+    assert!(message.contains("compliance zones 'us'"));
+
+    let updated = service
+        .update_runtime_record(&frank, "note", restricted_id, json!({"title": "US"}))
+        .await;
+    assert!(matches!(updated, Err(AppError::Forbidden(_))));
+
+    let listed = service
+        .list_runtime_records(
+            &frank,
+            "note",
+            RecordListQuery {
+                limit: 20,
+                offset: 0,
+                owner_subject: None,
+                include_inactive: false,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let listed_ids: Vec<&str> = listed
+        .iter()
+        .map(|record| record.record_id().as_str())
+        .collect();
+    assert_eq!(listed_ids, vec![open.record_id().as_str()]);
+
+    let allowed = service
+        .get_runtime_record(&grace, "note", restricted_id)
+        .await;
+    assert!(allowed.is_ok());
+
+    zones.tag("note", None, "eu").await;
+    let created = service
+        .create_runtime_record(&frank, "note", json!({"title": "Blocked"}))
+        .await;
+    assert!(matches!(created, Err(AppError::Forbidden(_))));
+    let created_in_zone = service
+        .create_runtime_record(&grace, "note", json!({"title": "Allowed"}))
+        .await;
+    assert!(created_in_zone.is_ok());
+}
+
+#[derive(Default)]
+struct FakeViewResultCache {
+    entries: Mutex<HashMap<RuntimeViewCacheKey, (i64, Vec<RuntimeRecord>)>>,
+    generations: Mutex<HashMap<(TenantId, String), i64>>,
+    hits: Mutex<usize>,
+}
+
+#[async_trait]
+impl RuntimeViewResultCache for FakeViewResultCache {
+    async fn get_view_result(
+        &self,
+        key: &RuntimeViewCacheKey,
+    ) -> AppResult<RuntimeViewCacheLookup> {
+        let generation = self
+            .generations
+            .lock()
+            .await
+            .get(&(key.tenant_id, key.entity_logical_name.clone()))
+            .copied()
+            .unwrap_or(0);
+        let records = self
+            .entries
+            .lock()
+            .await
+            .get(key)
+            .filter(|(entry_generation, _)| *entry_generation == generation)
+            .map(|(_, records)| records.clone());
+        if records.is_some() {
+            *self.hits.lock().await += 1;
+        }
+
+        Ok(RuntimeViewCacheLookup {
+            records,
+            generation,
+        })
+    }
+
+    async fn set_view_result(
+        &self,
+        key: RuntimeViewCacheKey,
+        generation: i64,
+        records: Vec<RuntimeRecord>,
+        _ttl_seconds: u32,
+    ) -> AppResult<()> {
+        self.entries.lock().await.insert(key, (generation, records));
+        Ok(())
+    }
+
+    async fn invalidate_entity(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<()> {
+        *self
+            .generations
+            .lock()
+            .await
+            .entry((tenant_id, entity_logical_name.to_owned()))
+            .or_insert(0) += 1;
+        Ok(())
+    }
+}
+
+fn note_view_query() -> RuntimeRecordQuery {
+    RuntimeRecordQuery {
+        limit: 50,
+        offset: 0,
+        logical_mode: RuntimeRecordLogicalMode::And,
+        where_clause: None,
+        filters: Vec::new(),
+        links: Vec::new(),
+        sort: Vec::new(),
+        owner_subject: None,
+        include_inactive: false,
+    }
+}
+
+#[tokio::test]
+async fn view_results_are_cached_per_subject_until_an_entity_write() {
+    let tenant_id = TenantId::new();
+    let permissions = vec![
+        Permission::MetadataEntityCreate,
+        Permission::MetadataFieldWrite,
+        Permission::RuntimeRecordWrite,
+        Permission::RuntimeRecordRead,
+    ];
+    let grants = HashMap::from([
+        ((tenant_id, "alice".to_owned()), permissions.clone()),
+        ((tenant_id, "bob".to_owned()), permissions),
+    ]);
+    let (service, _) = build_service(grants);
+    let cache = Arc::new(FakeViewResultCache::default());
+    let service = service.with_view_result_cache(cache.clone(), 30);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    let registered =
+        register_publish_entity_with_text_fields(&service, &alice, "note", "Note", &["title"])
+            .await;
+    assert!(registered.is_ok());
+    service
+        .create_runtime_record(&alice, "note", json!({"title": "First"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    for _ in 0..2 {
+        let records = service
+            .query_runtime_view_records(&alice, "note", "active_notes", note_view_query())
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(records.len(), 1);
+    }
+    assert_eq!(*cache.hits.lock().await, 1);
+
+    let bob_records = service
+        .query_runtime_view_records(&bob, "note", "active_notes", note_view_query())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(bob_records.len(), 1);
+    assert_eq!(*cache.hits.lock().await, 1);
+
+    service
+        .create_runtime_record(&bob, "note", json!({"title": "Second"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let refreshed = service
+        .query_runtime_view_records(&alice, "note", "active_notes", note_view_query())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(refreshed.len(), 2);
+    assert_eq!(*cache.hits.lock().await, 1);
+}
+
+struct FakeFieldChangeApprovalRepository {
+    fields: Vec<DualControlField>,
+    changes: Mutex<Vec<PendingFieldChange>>,
+}
+
+impl FakeFieldChangeApprovalRepository {
+    fn with_fields(entity_logical_name: &str, field_logical_names: &[&str]) -> Self {
+        Self {
+            fields: field_logical_names
+                .iter()
+                .map(|field_logical_name| DualControlField {
+                    entity_logical_name: entity_logical_name.to_owned(),
+                    field_logical_name: (*field_logical_name).to_owned(),
+                    approval_window_hours: 24,
+                    updated_by_subject: "compliance".to_owned(),
+                    updated_at: chrono::Utc::now(),
+                })
+                .collect(),
+            changes: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl FieldChangeApprovalRepository for FakeFieldChangeApprovalRepository {
+    async fn list_dual_control_fields(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<DualControlField>> {
+        Ok(self
+            .fields
+            .iter()
+            .filter(|field| {
+                entity_logical_name.is_none_or(|entity| field.entity_logical_name == entity)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn save_dual_control_fields(
+        &self,
+        _tenant_id: TenantId,
+        _updated_by_subject: &str,
+        _input: SaveDualControlFieldsInput,
+    ) -> AppResult<Vec<DualControlField>> {
+        Ok(self.fields.clone())
+    }
+
+    async fn create_pending_changes(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        changes: Vec<NewPendingFieldChange>,
+    ) -> AppResult<Vec<PendingFieldChange>> {
+        let created: Vec<PendingFieldChange> = changes
+            .into_iter()
+            .map(|change| PendingFieldChange {
+                change_id: change.change_id,
+                tenant_id,
+                entity_logical_name: change.entity_logical_name,
+                record_id: change.record_id,
+                field_logical_name: change.field_logical_name,
+                previous_value: change.previous_value,
+                proposed_value: change.proposed_value,
+                requested_by_subject: requested_by_subject.to_owned(),
+                requested_at: chrono::Utc::now(),
+                expires_at: change.expires_at,
+                status: PendingFieldChangeStatus::Pending,
+                decided_by_subject: None,
+                decided_at: None,
+            })
+            .collect();
+        self.changes.lock().await.extend(created.iter().cloned());
+        Ok(created)
+    }
+
+    async fn list_pending_changes(
+        &self,
+        tenant_id: TenantId,
+        query: PendingFieldChangeQuery,
+    ) -> AppResult<Vec<PendingFieldChange>> {
+        Ok(self
+            .changes
+            .lock()
+            .await
+            .iter()
+            .filter(|change| {
+                change.tenant_id == tenant_id
+                    && query
+                        .record_id
+                        .as_deref()
+                        .is_none_or(|record_id| change.record_id == record_id)
+                    && query.status.is_none_or(|status| change.status == status)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_pending_change(
+        &self,
+        tenant_id: TenantId,
+        change_id: &str,
+    ) -> AppResult<Option<PendingFieldChange>> {
+        Ok(self
+            .changes
+            .lock()
+            .await
+            .iter()
+            .find(|change| change.tenant_id == tenant_id && change.change_id == change_id)
+            .cloned())
+    }
+
+    async fn resolve_pending_change(
+        &self,
+        tenant_id: TenantId,
+        change_id: &str,
+        decided_by_subject: &str,
+        status: PendingFieldChangeStatus,
+    ) -> AppResult<Option<PendingFieldChange>> {
+        let now = chrono::Utc::now();
+        let mut changes = self.changes.lock().await;
+        let Some(change) = changes.iter_mut().find(|change| {
+            change.tenant_id == tenant_id && change.change_id == change_id && change.is_open(now)
+        }) else {
+            return Ok(None);
+        };
+        change.status = status;
+        change.decided_by_subject = Some(decided_by_subject.to_owned());
+        change.decided_at = Some(now);
+        Ok(Some(change.clone()))
+    }
+
+    async fn expire_stale_changes(&self, tenant_id: TenantId) -> AppResult<u64> {
+        let now = chrono::Utc::now();
+        let mut expired = 0;
+        for change in self.changes.lock().await.iter_mut() {
+            if change.tenant_id == tenant_id
+                && change.status == PendingFieldChangeStatus::Pending
+                && change.expires_at <= now
+            {
+                change.status = PendingFieldChangeStatus::Expired;
+                expired += 1;
+            }
+        }
+        Ok(expired)
+    }
+}
+
+fn dual_control_grants(tenant_id: TenantId) -> HashMap<(TenantId, String), Vec<Permission>> {
+    let permissions = vec![
+        Permission::MetadataEntityCreate,
+        Permission::MetadataFieldWrite,
+        Permission::RuntimeRecordWrite,
+        Permission::RuntimeRecordRead,
+    ];
+    HashMap::from([
+        ((tenant_id, "alice".to_owned()), permissions.clone()),
+        ((tenant_id, "bob".to_owned()), permissions),
+    ])
+}
+
+#[tokio::test]
+async fn update_runtime_record_defers_dual_control_fields_until_second_subject_approves() {
+    let tenant_id = TenantId::new();
+    let (service, audit_repository) = build_service(dual_control_grants(tenant_id));
+    let approvals = Arc::new(FakeFieldChangeApprovalRepository::with_fields(
+        "account",
+        &["iban"],
+    ));
+    let service = service.with_field_change_approval_repository(approvals.clone());
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    let registered = register_publish_entity_with_text_fields(
+        &service,
+        &alice,
+        "account",
+        "Account",
+        &["name", "iban"],
+    )
+    .await;
+    assert!(registered.is_ok());
+    let created = service
+        .create_runtime_record(&alice, "account", json!({"name": "Acme", "iban": "DE01"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = created.record_id().as_str().to_owned();
+
+    let updated = service
+        .update_runtime_record(
+            &alice,
+            "account",
+            record_id.as_str(),
+            json!({"name": "Acme GmbH", "iban": "DE02"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(updated.data()["name"], json!("Acme GmbH"));
+    assert_eq!(updated.data()["iban"], json!("DE01"));
+
+    let pending = service
+        .list_pending_field_changes(&bob, PendingFieldChangeQuery::default())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(pending.len(), 1);
+    let change_id = pending[0].change_id.clone();
+    assert_eq!(pending[0].proposed_value, Some(json!("DE02")));
+
+    let overlapping = service
+        .update_runtime_record(
+            &bob,
+            "account",
+            record_id.as_str(),
+            json!({"name": "Acme GmbH", "iban": "DE03"}),
+        )
+        .await;
+    assert!(matches!(overlapping, Err(AppError::Conflict(_))));
+
+    let self_approval = service
+        .approve_pending_field_change(&alice, change_id.as_str())
+        .await;
+    assert!(matches!(self_approval, Err(AppError::Forbidden(_))));
+
+    let approved = service
+        .approve_pending_field_change(&bob, change_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(approved.status, PendingFieldChangeStatus::Approved);
+    assert_eq!(approved.decided_by_subject.as_deref(), Some("bob"));
+
+    let refetched = service
+        .get_runtime_record(&alice, "account", record_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(refetched.data()["iban"], json!("DE02"));
+
+    let approved_again = service
+        .approve_pending_field_change(&bob, change_id.as_str())
+        .await;
+    assert!(matches!(approved_again, Err(AppError::Conflict(_))));
+
+    let events = audit_repository.events.lock().await;
+    assert!(
+        events
+            .iter()
+            .any(|event| event.action == AuditAction::RuntimeFieldChangeRequested)
+    );
+    assert!(
+        events
+            .iter()
+            .any(|event| event.action == AuditAction::RuntimeFieldChangeApproved)
+    );
+}
+
+#[tokio::test]
+async fn expired_and_rejected_field_changes_are_never_applied() {
+    let tenant_id = TenantId::new();
+    let (service, _) = build_service(dual_control_grants(tenant_id));
+    let approvals = Arc::new(FakeFieldChangeApprovalRepository::with_fields(
+        "account",
+        &["iban"],
+    ));
+    let service = service.with_field_change_approval_repository(approvals.clone());
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    let registered = register_publish_entity_with_text_fields(
+        &service,
+        &alice,
+        "account",
+        "Account",
+        &["name", "iban"],
+    )
+    .await;
+    assert!(registered.is_ok());
+    let created = service
+        .create_runtime_record(&alice, "account", json!({"name": "Acme", "iban": "DE01"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = created.record_id().as_str().to_owned();
+
+    service
+        .update_runtime_record(
+            &alice,
+            "account",
+            record_id.as_str(),
+            json!({"name": "Acme", "iban": "DE02"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let expired_change_id = {
+        let mut changes = approvals.changes.lock().await;
+        changes[0].expires_at = chrono::Utc::now() - chrono::Duration::minutes(1);
+        changes[0].change_id.clone()
+    };
+
+    let expired = service
+        .approve_pending_field_change(&bob, expired_change_id.as_str())
+        .await;
+    assert!(matches!(expired, Err(AppError::Conflict(_))));
+    let expired_changes = service
+        .list_pending_field_changes(
+            &bob,
+            PendingFieldChangeQuery {
+                status: Some(PendingFieldChangeStatus::Expired),
+                ..PendingFieldChangeQuery::default()
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(expired_changes.len(), 1);
+
+    service
+        .update_runtime_record(
+            &alice,
+            "account",
+            record_id.as_str(),
+            json!({"name": "Acme", "iban": "DE03"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let open_changes = service
+        .list_pending_field_changes(
+            &alice,
+            PendingFieldChangeQuery {
+                status: Some(PendingFieldChangeStatus::Pending),
+                ..PendingFieldChangeQuery::default()
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(open_changes.len(), 1);
+
+    let withdrawn = service
+        .reject_pending_field_change(&alice, open_changes[0].change_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(withdrawn.status, PendingFieldChangeStatus::Rejected);
+
+    let refetched = service
+        .get_runtime_record(&bob, "account", record_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(refetched.data()["iban"], json!("DE01"));
+}
+
+#[tokio::test]
+async fn list_runtime_records_unchecked_honors_own_read_scope_when_configured() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordReadOwn,
+            Permission::RuntimeRecordWriteOwn,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    assert!(
+        service
+            .register_entity(&alice, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &alice,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "name".to_owned(),
+                    display_name: "Name".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&alice, "contact").await.is_ok());
+
+    let alice_record = service
+        .create_runtime_record_unchecked(&alice, "contact", json!({"name": "Alice"}))
+        .await;
+    assert!(alice_record.is_ok());
+
+    let bob_record = service
+        .create_runtime_record_unchecked(&bob, "contact", json!({"name": "Bob"}))
+        .await;
+    assert!(bob_record.is_ok());
+
+    let listed = service
+        .list_runtime_records_unchecked(
+            &alice,
+            "contact",
+            RecordListQuery {
+                limit: 20,
+                offset: 0,
+                owner_subject: None,
+                include_inactive: false,
+            },
+        )
+        .await;
+    assert!(listed.is_ok());
+    let listed = listed.unwrap_or_default();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(
+        listed[0]
+            .data()
+            .as_object()
+            .and_then(|value| value.get("name")),
+        Some(&json!("Alice"))
+    );
+}
+
+#[tokio::test]
+async fn query_runtime_records_unchecked_honors_own_read_scope_when_configured() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordReadOwn,
+            Permission::RuntimeRecordWriteOwn,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    assert!(
+        service
+            .register_entity(&alice, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &alice,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "name".to_owned(),
+                    display_name: "Name".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&alice, "contact").await.is_ok());
+
+    let alice_record = service
+        .create_runtime_record_unchecked(&alice, "contact", json!({"name": "Alice"}))
+        .await;
+    assert!(alice_record.is_ok());
+
+    let bob_record = service
+        .create_runtime_record_unchecked(&bob, "contact", json!({"name": "Bob"}))
+        .await;
+    assert!(bob_record.is_ok());
+
+    let queried = service
+        .query_runtime_records_unchecked(
+            &alice,
+            "contact",
+            RuntimeRecordQuery {
+                limit: 20,
+                offset: 0,
+                logical_mode: RuntimeRecordLogicalMode::And,
+                where_clause: None,
+                filters: vec![RuntimeRecordFilter {
+                    scope_alias: None,
+                    field_logical_name: "name".to_owned(),
+                    operator: RuntimeRecordOperator::Contains,
+                    field_type: FieldType::Text,
+                    field_value: json!("o"),
+                }],
+                links: Vec::new(),
+                sort: Vec::new(),
+                owner_subject: None,
+                include_inactive: false,
+            },
+        )
+        .await;
+    assert!(queried.is_ok());
+    let queried = queried.unwrap_or_default();
+    assert_eq!(queried.len(), 0);
+}
+
+#[tokio::test]
+async fn update_runtime_record_unchecked_blocks_non_owned_records_for_own_write_scope() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWriteOwn,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    assert!(
+        service
+            .register_entity(&alice, "task", "Task")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &alice,
+                SaveFieldInput {
+                    entity_logical_name: "task".to_owned(),
+                    logical_name: "title".to_owned(),
+                    display_name: "Title".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&alice, "task").await.is_ok());
+
+    let bob_record = service
+        .create_runtime_record_unchecked(&bob, "task", json!({"title": "Owned by bob"}))
+        .await;
+    assert!(bob_record.is_ok());
+    let bob_record = bob_record.unwrap_or_else(|_| unreachable!());
+
+    let update_result = service
+        .update_runtime_record_unchecked(
+            &alice,
+            "task",
+            bob_record.record_id().as_str(),
+            json!({"title": "Alice edit"}),
+        )
+        .await;
+
+    assert!(matches!(update_result, Err(AppError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn assign_runtime_record_owner_transfers_own_scope_access_and_audits() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([
+        (
+            (tenant_id, "alice".to_owned()),
+            vec![
+                Permission::MetadataEntityCreate,
+                Permission::MetadataFieldWrite,
+                Permission::RuntimeRecordWrite,
+                Permission::RuntimeRecordAssign,
+            ],
+        ),
+        (
+            (tenant_id, "bob".to_owned()),
+            vec![Permission::RuntimeRecordWriteOwn],
+        ),
+    ]);
+    let (service, audit_repository) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+    assert!(
+        register_publish_entity_with_text_fields(&service, &alice, "task", "Task", &["title"])
+            .await
+            .is_ok()
+    );
+
+    let record = service
+        .create_runtime_record(&alice, "task", json!({"title": "Handover"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = record.record_id().as_str();
+
+    let denied = service
+        .update_runtime_record(&bob, "task", record_id, json!({"title": "Bob edit"}))
+        .await;
+    assert!(matches!(denied, Err(AppError::Forbidden(_))));
+
+    let assignment = service
+        .assign_runtime_record_owner(&alice, "task", record_id, " bob ")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(assignment.previous_owner_subject, "alice");
+    assert_eq!(assignment.owner_subject, "bob");
+
+    let updated = service
+        .update_runtime_record(&bob, "task", record_id, json!({"title": "Bob edit"}))
+        .await;
+    assert!(updated.is_ok());
+
+    let events = audit_repository.events.lock().await;
+    assert!(events.iter().any(|event| {
+        event.action == AuditAction::RuntimeRecordOwnerAssigned
+            && event.resource_id == record_id
+            && event.subject == "alice"
+    }));
+}
+
+#[tokio::test]
+async fn assign_runtime_record_owner_requires_assign_permission_and_ownership() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([
+        (
+            (tenant_id, "alice".to_owned()),
+            vec![
+                Permission::MetadataEntityCreate,
+                Permission::MetadataFieldWrite,
+                Permission::RuntimeRecordWrite,
+            ],
+        ),
+        (
+            (tenant_id, "bob".to_owned()),
+            vec![
+                Permission::RuntimeRecordWriteOwn,
+                Permission::RuntimeRecordAssign,
+            ],
+        ),
+    ]);
+    let (service, _) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+    assert!(
+        register_publish_entity_with_text_fields(&service, &alice, "task", "Task", &["title"])
+            .await
+            .is_ok()
+    );
+
+    let record = service
+        .create_runtime_record(&alice, "task", json!({"title": "Owned by alice"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = record.record_id().as_str();
+
+    let without_permission = service
+        .assign_runtime_record_owner(&alice, "task", record_id, "bob")
+        .await;
+    assert!(matches!(without_permission, Err(AppError::Forbidden(_))));
+
+    let not_owned = service
+        .assign_runtime_record_owner(&bob, "task", record_id, "bob")
+        .await;
+    assert!(matches!(not_owned, Err(AppError::Forbidden(_))));
+
+    let empty_owner = service
+        .assign_runtime_record_owner(&bob, "task", record_id, "  ")
+        .await;
+    assert!(matches!(empty_owner, Err(AppError::Validation(_))));
+}
+
+struct FakeRecordOwnerDirectory {
+    owners: Vec<&'static str>,
+}
+
+#[async_trait]
+impl RuntimeRecordOwnerDirectory for FakeRecordOwnerDirectory {
+    async fn is_assignable_owner(
+        &self,
+        _tenant_id: TenantId,
+        owner_subject: &str,
+    ) -> AppResult<bool> {
+        Ok(self.owners.contains(&owner_subject))
+    }
+}
+
+#[tokio::test]
+async fn assign_runtime_record_owner_requires_tenant_member_or_team() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordAssign,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let service = service.with_record_owner_directory(Arc::new(FakeRecordOwnerDirectory {
+        owners: vec!["alice", "bob", "support"],
+    }));
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        register_publish_entity_with_text_fields(&service, &alice, "task", "Task", &["title"])
+            .await
+            .is_ok()
+    );
+
+    let record = service
+        .create_runtime_record(&alice, "task", json!({"title": "Handover"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = record.record_id().as_str();
+
+    let unknown_owner = service
+        .assign_runtime_record_owner(&alice, "task", record_id, "bbo")
+        .await;
+    assert!(matches!(unknown_owner, Err(AppError::Validation(_))));
+
+    let to_member = service
+        .assign_runtime_record_owner(&alice, "task", record_id, "bob")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(to_member.owner_subject, "bob");
+
+    let to_team = service
+        .assign_runtime_record_owner(&alice, "task", record_id, "support")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(to_team.previous_owner_subject, "bob");
+    assert_eq!(to_team.owner_subject, "support");
+}
+
+#[tokio::test]
+async fn assign_runtime_record_owner_cascades_to_children_per_relation_behavior() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldRead,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordAssign,
+        ],
+    )]);
+    let (service, audit_repository) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    let carol = actor(tenant_id, "carol");
+    assert!(
+        register_publish_entity_with_text_fields(&service, &alice, "account", "Account", &["name"])
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .register_entity(&alice, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    for (logical_name, field_type, relation_target_entity) in [
+        ("name", FieldType::Text, None),
+        (
+            "account_id",
+            FieldType::Relation,
+            Some("account".to_owned()),
+        ),
+    ] {
+        assert!(
+            service
+                .save_field(
+                    &alice,
+                    SaveFieldInput {
+                        entity_logical_name: "contact".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type,
+                        is_required: false,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(service.publish_entity(&alice, "contact").await.is_ok());
+
+    let not_relation = service
+        .save_relation_behavior(
+            &alice,
+            SaveRelationBehaviorInput {
+                entity_logical_name: "contact".to_owned(),
+                field_logical_name: "name".to_owned(),
+                assign_behavior: RelationCascadeBehavior::CascadeAll,
+                share_behavior: RelationCascadeBehavior::None,
+                delete_behavior: RelationDeleteBehavior::Restrict,
+            },
+        )
+        .await;
+    assert!(matches!(not_relation, Err(AppError::Validation(_))));
+
+    let behavior = service
+        .save_relation_behavior(
+            &alice,
+            SaveRelationBehaviorInput {
+                entity_logical_name: "contact".to_owned(),
+                field_logical_name: "account_id".to_owned(),
+                assign_behavior: RelationCascadeBehavior::CascadeUserOwned,
+                share_behavior: RelationCascadeBehavior::None,
+                delete_behavior: RelationDeleteBehavior::Restrict,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(behavior.target_entity_logical_name, "account");
+
+    let account = service
+        .create_runtime_record(&alice, "account", json!({"name": "Contoso"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let account_id = account.record_id().as_str();
+    let owned_contact = service
+        .create_runtime_record(
+            &alice,
+            "contact",
+            json!({"name": "Ada", "account_id": account_id}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let foreign_contact = service
+        .create_runtime_record_unchecked(
+            &carol,
+            "contact",
+            json!({"name": "Grace", "account_id": account_id}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let assignment = service
+        .assign_runtime_record_owner(&alice, "account", account_id, "bob")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        assignment.cascades,
+        vec![RelationCascadeResult {
+            entity_logical_name: "contact".to_owned(),
+            field_logical_name: "account_id".to_owned(),
+            affected_records: 1,
+        }]
+    );
+
+    for (record, expected_owner) in [(&owned_contact, "bob"), (&foreign_contact, "carol")] {
+        let owned = service
+            .repository
+            .runtime_record_owned_by_subject(
+                tenant_id,
+                "contact",
+                record.record_id().as_str(),
+                expected_owner,
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert!(owned, "expected contact owned by {expected_owner}");
+    }
+
+    let assigned_event = audit_repository
+        .events
+        .lock()
+        .await
+        .iter()
+        .find(|event| event.action == AuditAction::RuntimeRecordOwnerAssigned)
+        .and_then(|event| event.detail.clone())
+        .unwrap_or_default();
+    assert!(assigned_event.contains("with cascades contact.account_id=1"));
+}
+
+#[tokio::test]
+async fn delete_runtime_record_applies_relation_delete_behaviors() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldRead,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+            Permission::RuntimeRecordWrite,
+        ],
+    )]);
+    let (service, audit_repository) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        register_publish_entity_with_text_fields(&service, &alice, "account", "Account", &["name"])
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .register_entity(&alice, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    for (entity_logical_name, logical_name, field_type, relation_target_entity) in [
+        (
+            "account",
+            "parent_id",
+            FieldType::Relation,
+            Some("account".to_owned()),
+        ),
+        ("contact", "name", FieldType::Text, None),
+        (
+            "contact",
+            "account_id",
+            FieldType::Relation,
+            Some("account".to_owned()),
+        ),
+    ] {
+        assert!(
+            service
+                .save_field(
+                    &alice,
+                    SaveFieldInput {
+                        entity_logical_name: entity_logical_name.to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type,
+                        is_required: false,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(service.publish_entity(&alice, "account").await.is_ok());
+    assert!(service.publish_entity(&alice, "contact").await.is_ok());
+
+    let save_delete_behavior =
+        |entity_logical_name: &str, field_logical_name: &str, delete_behavior| {
+            service.save_relation_behavior(
+                &alice,
+                SaveRelationBehaviorInput {
+                    entity_logical_name: entity_logical_name.to_owned(),
+                    field_logical_name: field_logical_name.to_owned(),
+                    assign_behavior: RelationCascadeBehavior::None,
+                    share_behavior: RelationCascadeBehavior::None,
+                    delete_behavior,
+                },
+            )
+        };
+    let reparent_other_entity =
+        save_delete_behavior("contact", "account_id", RelationDeleteBehavior::Reparent).await;
+    assert!(matches!(
+        reparent_other_entity,
+        Err(AppError::Validation(_))
+    ));
+    assert!(
+        save_delete_behavior("account", "parent_id", RelationDeleteBehavior::Reparent)
+            .await
+            .is_ok()
+    );
+    assert!(
+        save_delete_behavior("contact", "account_id", RelationDeleteBehavior::Cascade)
+            .await
+            .is_ok()
+    );
+
+    let create = |entity_logical_name: &'static str, data: Value| {
+        service.create_runtime_record(&alice, entity_logical_name, data)
+    };
+    let grandparent = create("account", json!({"name": "Holding"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let grandparent_id = grandparent.record_id().as_str();
+    let parent = create(
+        "account",
+        json!({"name": "Contoso", "parent_id": grandparent_id}),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    let parent_id = parent.record_id().as_str();
+    let child = create(
+        "account",
+        json!({"name": "Contoso West", "parent_id": parent_id}),
+    )
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    let child_id = child.record_id().as_str();
+    let parent_contact = create("contact", json!({"name": "Ada", "account_id": parent_id}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let child_contact = create("contact", json!({"name": "Grace", "account_id": child_id}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    assert!(
+        service
+            .delete_runtime_record(&alice, "account", parent_id)
+            .await
+            .is_ok()
+    );
+    let reparented = service
+        .get_runtime_record(&alice, "account", child_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(reparented.data()["parent_id"], json!(grandparent_id));
+    let deleted_contact = service
+        .get_runtime_record(&alice, "contact", parent_contact.record_id().as_str())
+        .await;
+    assert!(matches!(deleted_contact, Err(AppError::NotFound(_))));
+    let deleted_event = audit_repository
+        .events
+        .lock()
+        .await
+        .iter()
+        .rfind(|event| event.action == AuditAction::RuntimeRecordDeleted)
+        .and_then(|event| event.detail.clone())
+        .unwrap_or_default();
+    assert!(deleted_event.contains("with cascades account.parent_id=1, contact.account_id=1"));
+
+    assert!(
+        save_delete_behavior("contact", "account_id", RelationDeleteBehavior::Restrict)
+            .await
+            .is_ok()
+    );
+    let restricted = service
+        .delete_runtime_record(&alice, "account", child_id)
+        .await;
+    assert!(matches!(restricted, Err(AppError::Conflict(_))));
+
+    assert!(
+        save_delete_behavior("contact", "account_id", RelationDeleteBehavior::SetNull)
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .delete_runtime_record(&alice, "account", child_id)
+            .await
+            .is_ok()
+    );
+    let detached_contact = service
+        .get_runtime_record(&alice, "contact", child_contact.record_id().as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(detached_contact.data()["account_id"], Value::Null);
+
+    assert!(
+        service
+            .save_field(
+                &alice,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "account_id".to_owned(),
+                    display_name: "account_id".to_owned(),
+                    field_type: FieldType::Relation,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: Some("account".to_owned()),
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    let publish_error = service
+        .publish_entity(&alice, "contact")
+        .await
+        .err()
+        .map(|error| error.to_string())
+        .unwrap_or_default();
+    assert!(publish_error.contains("cannot use delete behavior 'set_null'"));
+}
+
+struct FakeRecordShareRepository {
+    share: RecordShare,
+}
+
+#[async_trait]
+impl RecordAccessRepository for FakeRecordShareRepository {
+    async fn find_record_owner(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _record_id: &str,
+    ) -> AppResult<Option<String>> {
+        Ok(None)
+    }
+
+    async fn create_request(
+        &self,
+        _tenant_id: TenantId,
+        _requested_by_subject: &str,
+        _request: NewRecordAccessRequest,
+    ) -> AppResult<RecordAccessRequest> {
+        Err(AppError::Internal("not used in metadata tests".to_owned()))
+    }
+
+    async fn list_requests(
+        &self,
+        _tenant_id: TenantId,
+        _query: RecordAccessRequestQuery,
+    ) -> AppResult<Vec<RecordAccessRequest>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_request(
+        &self,
+        _tenant_id: TenantId,
+        _request_id: &str,
+    ) -> AppResult<Option<RecordAccessRequest>> {
+        Ok(None)
+    }
+
+    async fn list_share_cascades(
+        &self,
+        _tenant_id: TenantId,
+        _target_entity_logical_name: &str,
+    ) -> AppResult<Vec<RelationBehavior>> {
+        Ok(Vec::new())
+    }
+
+    async fn approve_request(
+        &self,
+        _tenant_id: TenantId,
+        _request_id: &str,
+        _decided_by_subject: &str,
+        _share_expires_at: chrono::DateTime<chrono::Utc>,
+        _share_cascades: &[RelationBehavior],
+        _notification: Option<RuntimeRecordWorkflowEventInput>,
+    ) -> AppResult<Option<(RecordAccessRequest, RecordShare, Vec<RelationCascadeResult>)>> {
+        Ok(None)
+    }
+
+    async fn reject_request(
+        &self,
+        _tenant_id: TenantId,
+        _request_id: &str,
+        _decided_by_subject: &str,
+        _notification: Option<RuntimeRecordWorkflowEventInput>,
+    ) -> AppResult<Option<RecordAccessRequest>> {
+        Ok(None)
+    }
+
+    async fn has_active_share(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        subject: &str,
+    ) -> AppResult<bool> {
+        Ok(self.share.tenant_id == tenant_id
+            && self.share.entity_logical_name == entity_logical_name
+            && self.share.record_id == record_id
+            && self.share.subject == subject
+            && self.share.is_active(chrono::Utc::now()))
+    }
+
+    async fn list_active_shares(
+        &self,
+        _tenant_id: TenantId,
+        _entity_logical_name: &str,
+        _record_id: &str,
+    ) -> AppResult<Vec<RecordShare>> {
+        Ok(vec![self.share.clone()])
+    }
+
+    async fn find_share(
+        &self,
+        _tenant_id: TenantId,
+        _share_id: &str,
+    ) -> AppResult<Option<RecordShare>> {
+        Ok(Some(self.share.clone()))
+    }
+
+    async fn revoke_share(
+        &self,
+        _tenant_id: TenantId,
+        _share_id: &str,
+        _revoked_by_subject: &str,
+    ) -> AppResult<Option<RecordShare>> {
+        Ok(None)
+    }
+}
+
+#[tokio::test]
+async fn get_runtime_record_allows_own_scope_readers_with_active_record_share() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([
+        (
+            (tenant_id, "alice".to_owned()),
+            vec![
+                Permission::MetadataEntityCreate,
+                Permission::MetadataFieldWrite,
+                Permission::RuntimeRecordReadOwn,
+                Permission::RuntimeRecordWriteOwn,
+            ],
+        ),
+        (
+            (tenant_id, "bob".to_owned()),
+            vec![Permission::RuntimeRecordReadOwn],
+        ),
+        (
+            (tenant_id, "carol".to_owned()),
+            vec![Permission::RuntimeRecordReadOwn],
+        ),
+    ]);
+    let (service, _) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+    let carol = actor(tenant_id, "carol");
+
+    assert!(
+        service
+            .register_entity(&alice, "task", "Task")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &alice,
+                SaveFieldInput {
+                    entity_logical_name: "task".to_owned(),
+                    logical_name: "title".to_owned(),
+                    display_name: "Title".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&alice, "task").await.is_ok());
+
+    let record = service
+        .create_runtime_record(&alice, "task", json!({"title": "Quarterly plan"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = record.record_id().as_str().to_owned();
+
+    let unshared = service
+        .get_runtime_record(&bob, "task", record_id.as_str())
+        .await;
+    assert!(matches!(unshared, Err(AppError::Forbidden(_))));
+
+    let now = chrono::Utc::now();
+    let service = service.with_record_access_repository(Arc::new(FakeRecordShareRepository {
+        share: RecordShare {
+            share_id: "share-1".to_owned(),
+            tenant_id,
+            entity_logical_name: "task".to_owned(),
+            record_id: record_id.clone(),
+            subject: "bob".to_owned(),
+            request_id: "request-1".to_owned(),
+            granted_by_subject: "alice".to_owned(),
+            created_at: now,
+            expires_at: now + chrono::Duration::hours(1),
+            revoked_by_subject: None,
+            revoked_at: None,
+        },
+    }));
+
+    let shared = service
+        .get_runtime_record(&bob, "task", record_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(shared.data().get("title"), Some(&json!("Quarterly plan")));
+
+    let other_subject = service
+        .get_runtime_record(&carol, "task", record_id.as_str())
+        .await;
+    assert!(matches!(other_subject, Err(AppError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn patch_runtime_record_unchecked_only_requires_patched_fields_to_be_writable() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+        ],
+    )]);
+    let runtime_field_grants = HashMap::from([(
+        (tenant_id, "alice".to_owned(), "contact".to_owned()),
+        vec![RuntimeFieldGrant {
+            field_logical_name: "email".to_owned(),
+            can_read: true,
+            can_write: true,
+        }],
+    )]);
+    let (service, _) = build_service_with_runtime_field_grants(grants, runtime_field_grants);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+    assert!(
+        register_publish_entity_with_text_fields(
+            &service,
+            &alice,
+            "contact",
+            "Contact",
+            &["email", "secret"],
+        )
+        .await
+        .is_ok()
+    );
+
+    let created = service
+        .create_runtime_record_unchecked(
+            &bob,
+            "contact",
+            json!({"email": "a@qryvanta.dev", "secret": "top"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = created.record_id().as_str().to_owned();
+
+    let patched = service
+        .patch_runtime_record_unchecked(
+            &alice,
+            "contact",
+            record_id.as_str(),
+            &json!({"email": "b@qryvanta.dev"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(patched.data().get("email"), Some(&json!("b@qryvanta.dev")));
+    assert!(patched.data().get("secret").is_none());
+
+    let stored = service
+        .get_runtime_record_unchecked(&bob, "contact", record_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(stored.data().get("secret"), Some(&json!("top")));
+
+    let forbidden = service
+        .patch_runtime_record_unchecked(
+            &alice,
+            "contact",
+            record_id.as_str(),
+            &json!({"secret": "changed"}),
+        )
+        .await;
+    assert!(matches!(forbidden, Err(AppError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn get_runtime_record_unchecked_redacts_using_runtime_field_permissions() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+        ],
+    )]);
+    let runtime_field_grants = HashMap::from([(
+        (tenant_id, "alice".to_owned(), "contact".to_owned()),
+        vec![RuntimeFieldGrant {
+            field_logical_name: "email".to_owned(),
+            can_read: true,
+            can_write: false,
+        }],
+    )]);
+    let (service, _) = build_service_with_runtime_field_grants(grants, runtime_field_grants);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    assert!(
+        service
+            .register_entity(&alice, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &alice,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "email".to_owned(),
+                    display_name: "Email".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &alice,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "secret".to_owned(),
+                    display_name: "Secret".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&alice, "contact").await.is_ok());
+
+    let created = service
+        .create_runtime_record_unchecked(
+            &bob,
+            "contact",
+            json!({"email": "a@qryvanta.dev", "secret": "top"}),
+        )
+        .await;
+    assert!(created.is_ok());
+    let created = created.unwrap_or_else(|_| unreachable!());
+
+    let fetched = service
+        .get_runtime_record_unchecked(&alice, "contact", created.record_id().as_str())
+        .await;
+    assert!(fetched.is_ok());
+    let fetched = fetched.unwrap_or_else(|_| unreachable!());
+
+    let data = fetched.data().as_object();
+    assert!(data.is_some());
+    let data = data.unwrap_or_else(|| unreachable!());
+    assert_eq!(data.get("email"), Some(&json!("a@qryvanta.dev")));
+    assert!(data.get("secret").is_none());
+}
+
+struct FakeFieldMaskingRepository {
+    rules: Vec<FieldMaskingRule>,
+}
+
+#[async_trait]
+impl FieldMaskingRepository for FakeFieldMaskingRepository {
+    async fn list_masking_rules(
+        &self,
+        _tenant_id: TenantId,
+        _role_name: Option<&str>,
+    ) -> AppResult<Vec<FieldMaskingRule>> {
+        Ok(self.rules.clone())
+    }
+
+    async fn save_role_masking_rules(
+        &self,
+        _tenant_id: TenantId,
+        _updated_by_subject: &str,
+        _input: SaveFieldMaskingRulesInput,
+    ) -> AppResult<Vec<FieldMaskingRule>> {
+        Err(AppError::Internal("not used in metadata tests".to_owned()))
+    }
+}
+
+#[tokio::test]
+async fn runtime_reads_mask_classified_fields_for_masked_roles() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([
+        (
+            (tenant_id, "alice".to_owned()),
+            vec![
+                Permission::MetadataEntityCreate,
+                Permission::MetadataFieldWrite,
+                Permission::RuntimeRecordRead,
+                Permission::RuntimeRecordWrite,
+            ],
+        ),
+        (
+            (tenant_id, "bob".to_owned()),
+            vec![
+                Permission::RuntimeRecordRead,
+                Permission::RuntimeRecordWrite,
+            ],
+        ),
+    ]);
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let service = MetadataService::new(
+        Arc::new(FakeRepository::new()),
+        AuthorizationService::new(
+            Arc::new(FakeAuthorizationRepository {
+                grants,
+                runtime_field_grants: HashMap::new(),
+                team_peers: HashMap::new(),
+                role_names: HashMap::from([(
+                    (tenant_id, "bob".to_owned()),
+                    vec!["support".to_owned()],
+                )]),
+            }),
+            audit_repository.clone(),
+        ),
+        audit_repository,
+    )
+    .with_field_masking_repository(Arc::new(FakeFieldMaskingRepository {
+        rules: vec![FieldMaskingRule {
+            role_name: "support".to_owned(),
+            classification: FieldClassification::Pii,
+            masking_mode: FieldMaskingMode::Partial,
+            updated_by_subject: "alice".to_owned(),
+            updated_at: chrono::Utc::now(),
+        }],
+    }));
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    assert!(
+        service
+            .register_entity(&alice, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    for (logical_name, classification) in [
+        ("email", None),
+        ("national_id", Some(FieldClassification::Pii)),
+    ] {
+        assert!(
+            service
+                .save_field(
+                    &alice,
+                    SaveFieldInput {
+                        entity_logical_name: "contact".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type: FieldType::Text,
+                        is_required: false,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity: None,
+                        option_set_logical_name: None,
+                        classification,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(service.publish_entity(&alice, "contact").await.is_ok());
+
+    let created = service
+        .create_runtime_record(
+            &alice,
+            "contact",
+            json!({"email": "a@qryvanta.dev", "national_id": "123-45-6789"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = created.record_id().as_str().to_owned();
+
+    let masked = service
+        .get_runtime_record(&bob, "contact", record_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(masked.data()["email"], json!("a@qryvanta.dev"));
+    assert_eq!(masked.data()["national_id"], json!("*******6789"));
+
+    // Saving the masked value back keeps the stored value.
+    let updated = service
+        .update_runtime_record(
+            &bob,
+            "contact",
+            record_id.as_str(),
+            json!({"email": "b@qryvanta.dev", "national_id": "*******6789"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(updated.data()["national_id"], json!("*******6789"));
+
+    let unmasked = service
+        .get_runtime_record(&alice, "contact", record_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(unmasked.data()["email"], json!("b@qryvanta.dev"));
+    assert_eq!(unmasked.data()["national_id"], json!("123-45-6789"));
+
+    let filtered = service
+        .query_runtime_records(
+            &bob,
+            "contact",
+            RuntimeRecordQuery {
+                limit: 10,
+                offset: 0,
+                logical_mode: RuntimeRecordLogicalMode::And,
+                where_clause: None,
+                filters: vec![RuntimeRecordFilter {
+                    scope_alias: None,
+                    field_logical_name: "national_id".to_owned(),
+                    operator: RuntimeRecordOperator::Eq,
+                    field_type: FieldType::Text,
+                    field_value: json!("123-45-6789"),
+                }],
+                links: Vec::new(),
+                sort: Vec::new(),
+                owner_subject: None,
+                include_inactive: false,
+            },
+        )
+        .await;
+    assert!(matches!(filtered, Err(AppError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn runtime_record_history_ignores_system_field_churn() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+    let carol = actor(tenant_id, "carol");
+
+    assert!(
+        service
+            .register_entity(&alice, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &alice,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "email".to_owned(),
+                    display_name: "Email".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: false,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&alice, "contact").await.is_ok());
+
+    let created = service
+        .create_runtime_record_unchecked(&bob, "contact", json!({"email": "a@qryvanta.dev"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = created.record_id().as_str().to_owned();
+
+    // Only modified_by and modified_at change here.
+    let touched = service
+        .update_runtime_record_unchecked(
+            &carol,
+            "contact",
+            record_id.as_str(),
+            json!({"email": "a@qryvanta.dev"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(touched.data()["modified_by"], json!("carol"));
+    assert!(
+        service
+            .update_runtime_record_unchecked(
+                &bob,
+                "contact",
+                record_id.as_str(),
+                json!({"email": "b@qryvanta.dev"}),
+            )
+            .await
+            .is_ok()
+    );
+
+    let history = service
+        .repository
+        .list_runtime_record_history(tenant_id, "contact", record_id.as_str(), None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].changed_by_subject, "bob");
+    assert_eq!(history[0].changes.len(), 1);
+    assert_eq!(history[0].changes[0].field_logical_name, "email");
+}
+
+#[tokio::test]
+async fn runtime_record_history_tracks_updates_and_hides_unreadable_fields() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+        ],
+    )]);
+    let runtime_field_grants = HashMap::from([(
+        (tenant_id, "alice".to_owned(), "contact".to_owned()),
+        vec![RuntimeFieldGrant {
+            field_logical_name: "email".to_owned(),
+            can_read: true,
+            can_write: false,
+        }],
+    )]);
+    let (service, audit_repository) =
+        build_service_with_runtime_field_grants(grants, runtime_field_grants);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    assert!(
+        service
+            .register_entity(&alice, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    for logical_name in ["email", "secret"] {
+        assert!(
+            service
+                .save_field(
+                    &alice,
+                    SaveFieldInput {
+                        entity_logical_name: "contact".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type: FieldType::Text,
+                        is_required: false,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity: None,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(service.publish_entity(&alice, "contact").await.is_ok());
+
+    let created = service
+        .create_runtime_record_unchecked(
+            &bob,
+            "contact",
+            json!({"email": "a@qryvanta.dev", "secret": "top"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = created.record_id().as_str().to_owned();
+    assert!(
+        service
+            .update_runtime_record_unchecked(
+                &bob,
+                "contact",
+                record_id.as_str(),
+                json!({"email": "b@qryvanta.dev", "secret": "top"}),
+            )
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .update_runtime_record_unchecked(
+                &bob,
+                "contact",
+                record_id.as_str(),
+                json!({"email": "b@qryvanta.dev"}),
+            )
+            .await
+            .is_ok()
+    );
+
+    let bob_history = service
+        .repository
+        .list_runtime_record_history(tenant_id, "contact", record_id.as_str(), None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(bob_history.len(), 2);
+    assert_eq!(bob_history[0].changed_by_subject, "bob");
+    assert_eq!(bob_history[0].record_version, 2);
+    assert_eq!(bob_history[0].changes.len(), 1);
+    assert_eq!(bob_history[0].changes[0].field_logical_name, "email");
+    assert_eq!(
+        bob_history[0].changes[0].old_value,
+        Some(json!("a@qryvanta.dev"))
+    );
+    assert_eq!(
+        bob_history[0].changes[0].new_value,
+        Some(json!("b@qryvanta.dev"))
+    );
+    assert_eq!(bob_history[1].changes[0].field_logical_name, "secret");
+    assert_eq!(bob_history[1].changes[0].new_value, None);
+
+    let alice_history = service
+        .runtime_record_history(&alice, "contact", record_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(alice_history.len(), 1);
+    assert_eq!(alice_history[0].changes[0].field_logical_name, "email");
+
+    let invalid_policy = service
+        .save_entity_history_policy(
+            &alice,
+            SaveEntityHistoryPolicyInput {
+                entity_logical_name: "contact".to_owned(),
+                retention_days: 0,
+            },
+        )
+        .await;
+    assert!(matches!(invalid_policy, Err(AppError::Validation(_))));
+
+    let policy = service
+        .save_entity_history_policy(
+            &alice,
+            SaveEntityHistoryPolicyInput {
+                entity_logical_name: "contact".to_owned(),
+                retention_days: 30,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(policy.retention_days, 30);
+    assert_eq!(
+        service
+            .repository
+            .list_runtime_record_history(tenant_id, "contact", record_id.as_str(), None)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .len(),
+        2
+    );
+    let purged = service
+        .repository
+        .purge_runtime_record_history(
+            tenant_id,
+            "contact",
+            chrono::Utc::now() + chrono::Duration::seconds(1),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(purged, 2);
+
+    assert!(
+        service
+            .delete_entity_history_policy(&alice, "contact")
+            .await
+            .is_ok()
+    );
+    let events = audit_repository.events.lock().await;
+    assert!(
+        events
+            .iter()
+            .any(|event| event.action == AuditAction::MetadataEntityHistoryPolicySaved)
+    );
+    assert!(
+        events
+            .iter()
+            .any(|event| event.action == AuditAction::MetadataEntityHistoryPolicyDeleted)
+    );
+}
+
+#[tokio::test]
+async fn update_field_updates_mutable_metadata_properties() {
+    let tenant_id = TenantId::new();
+    let subject = "ivy";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::MetadataFieldRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    assert!(
+        service
+            .register_entity(&actor, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "name".to_owned(),
+                    display_name: "Name".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+
+    let updated = service
+        .update_field(
+            &actor,
+            UpdateFieldInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "name".to_owned(),
+                display_name: "Full Name".to_owned(),
+                description: Some("Primary full name".to_owned()),
+                default_value: Some(json!("Anonymous")),
+                calculation_expression: None,
+                max_length: Some(255),
+                min_value: None,
+                max_value: None,
+                classification: None,
+            },
+        )
+        .await;
+    assert!(updated.is_ok());
+    let updated = updated.unwrap_or_else(|_| unreachable!());
+    assert_eq!(updated.display_name().as_str(), "Full Name");
+    assert_eq!(updated.description(), Some("Primary full name"));
+    assert_eq!(updated.max_length(), Some(255));
+    assert_eq!(updated.default_value(), Some(&json!("Anonymous")));
+}
+
+#[tokio::test]
+async fn save_field_rejects_type_change_for_published_field() {
+    let tenant_id = TenantId::new();
+    let subject = "sam";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::MetadataFieldRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    assert!(
+        service
+            .register_entity(&actor, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "name".to_owned(),
+                    display_name: "Name".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&actor, "contact").await.is_ok());
+
+    let changed = service
+        .save_field(
+            &actor,
+            SaveFieldInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "name".to_owned(),
+                display_name: "Name".to_owned(),
+                field_type: FieldType::Number,
+                is_required: true,
+                is_unique: false,
+                default_value: None,
+                calculation_expression: None,
+                relation_target_entity: None,
+                option_set_logical_name: None,
+                classification: None,
+            },
+        )
+        .await;
+
+    assert!(
+        matches!(changed, Err(AppError::Validation(message)) if message
+        .contains("field type cannot be changed for published field"))
+    );
+}
+
+#[tokio::test]
+async fn save_field_rejects_relation_target_change_for_published_field() {
+    let tenant_id = TenantId::new();
+    let subject = "sam";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::MetadataFieldRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    assert!(
+        service
+            .register_entity(&actor, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .register_entity(&actor, "account", "Account")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "manager_id".to_owned(),
+                    display_name: "Manager".to_owned(),
+                    field_type: FieldType::Relation,
+                    is_required: false,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: Some("contact".to_owned()),
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&actor, "contact").await.is_ok());
+
+    let changed = service
+        .save_field(
+            &actor,
+            SaveFieldInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "manager_id".to_owned(),
+                display_name: "Manager".to_owned(),
+                field_type: FieldType::Relation,
+                is_required: false,
+                is_unique: false,
+                default_value: None,
+                calculation_expression: None,
+                relation_target_entity: Some("account".to_owned()),
+                option_set_logical_name: None,
+                classification: None,
+            },
+        )
+        .await;
+
+    assert!(
+        matches!(changed, Err(AppError::Validation(message)) if message
+        .contains("relation target cannot be changed for published field"))
+    );
+}
+
+#[tokio::test]
+async fn save_field_rejects_option_set_change_for_published_field() {
+    let tenant_id = TenantId::new();
+    let subject = "sam";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::MetadataFieldRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    assert!(
+        service
+            .register_entity(&actor, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+
+    assert!(
+        service
+            .save_option_set(
+                &actor,
+                SaveOptionSetInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "status_primary".to_owned(),
+                    display_name: "Status Primary".to_owned(),
+                    options: vec![
+                        OptionSetItem::new(1, "Open", None, 0).unwrap_or_else(|_| unreachable!())
+                    ],
+                },
+            )
+            .await
+            .is_ok()
+    );
+
+    assert!(
+        service
+            .save_option_set(
+                &actor,
+                SaveOptionSetInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "status_secondary".to_owned(),
+                    display_name: "Status Secondary".to_owned(),
+                    options: vec![
+                        OptionSetItem::new(1, "Open", None, 0).unwrap_or_else(|_| unreachable!())
+                    ],
+                },
+            )
+            .await
+            .is_ok()
+    );
+
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "status".to_owned(),
+                    display_name: "Status".to_owned(),
+                    field_type: FieldType::Choice,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: Some(json!(1)),
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: Some("status_primary".to_owned()),
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&actor, "contact").await.is_ok());
+
+    let changed = service
+        .save_field(
+            &actor,
+            SaveFieldInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "status".to_owned(),
+                display_name: "Status".to_owned(),
+                field_type: FieldType::Choice,
+                is_required: true,
+                is_unique: false,
+                default_value: Some(json!(1)),
+                calculation_expression: None,
+                relation_target_entity: None,
+                option_set_logical_name: Some("status_secondary".to_owned()),
+                classification: None,
+            },
+        )
+        .await;
+
+    assert!(
+        matches!(changed, Err(AppError::Validation(message)) if message
+        .contains("option set reference cannot be changed for published field"))
+    );
+}
+
+#[tokio::test]
+async fn delete_field_rejects_published_fields() {
+    let tenant_id = TenantId::new();
+    let subject = "judy";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    assert!(
+        service
+            .register_entity(&actor, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "name".to_owned(),
+                    display_name: "Name".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: true,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&actor, "contact").await.is_ok());
+
+    let deleted = service.delete_field(&actor, "contact", "name").await;
+    assert!(matches!(deleted, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn delete_field_allows_unpublished_draft_fields() {
+    let tenant_id = TenantId::new();
+    let subject = "kate";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::MetadataFieldRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    assert!(
+        service
+            .register_entity(&actor, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &actor,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "temporary_note".to_owned(),
+                    display_name: "Temporary Note".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: false,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+
+    let deleted = service
+        .delete_field(&actor, "contact", "temporary_note")
+        .await;
+    assert!(deleted.is_ok());
+
+    let listed = service.list_fields(&actor, "contact").await;
+    assert!(listed.is_ok());
+    assert!(
+        listed
+            .unwrap_or_default()
+            .iter()
+            .all(|field| field.logical_name().as_str() != "temporary_note")
+    );
+}
+
+#[tokio::test]
+async fn save_form_rejects_sparse_tab_positions() {
+    let tenant_id = TenantId::new();
+    let subject = "laura";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    let seeded = register_publish_entity_with_text_fields(
+        &service,
+        &actor,
+        "contact",
+        "Contact",
+        &["name", "email"],
+    )
+    .await;
+    assert!(seeded.is_ok());
+
+    let summary_field = FormFieldPlacement::new("name", 0, 0, true, false, None, None)
+        .unwrap_or_else(|_| unreachable!());
+    let details_field = FormFieldPlacement::new("email", 0, 0, true, false, None, None)
+        .unwrap_or_else(|_| unreachable!());
+
+    let summary_section = FormSection::new(
+        "summary_section",
+        "Summary",
+        0,
+        true,
+        1,
+        vec![summary_field],
+        Vec::new(),
+    )
+    .unwrap_or_else(|_| unreachable!());
+    let details_section = FormSection::new(
+        "details_section",
+        "Details",
+        0,
+        true,
+        1,
+        vec![details_field],
+        Vec::new(),
+    )
+    .unwrap_or_else(|_| unreachable!());
+
+    let summary_tab = FormTab::new("summary", "Summary", 0, true, vec![summary_section])
+        .unwrap_or_else(|_| unreachable!());
+    let details_tab = FormTab::new("details", "Details", 2, true, vec![details_section])
+        .unwrap_or_else(|_| unreachable!());
+
+    let result = service
+        .save_form(
+            &actor,
+            SaveFormInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "main_form".to_owned(),
+                display_name: "Main Form".to_owned(),
+                form_type: FormType::Main,
+                tabs: vec![summary_tab, details_tab],
+                header_fields: Vec::new(),
+                script_events: FormScriptEvents::default(),
+            },
+        )
+        .await;
+
+    match result {
+        Err(AppError::Validation(message)) => {
+            assert!(
+                message
+                    .contains("form tab positions must form contiguous sequence starting at zero")
+            );
+        }
+        _ => panic!("expected form position validation failure"),
+    }
+}
+
+#[derive(Default)]
+struct FakeExtensionRepository {
+    extensions: Mutex<HashMap<(TenantId, String), ExtensionDefinition>>,
+}
+
+#[async_trait]
+impl ExtensionRepository for FakeExtensionRepository {
+    async fn save_extension(
+        &self,
+        tenant_id: TenantId,
+        definition: ExtensionDefinition,
+    ) -> AppResult<()> {
+        self.extensions.lock().await.insert(
+            (
+                tenant_id,
+                definition.manifest().logical_name().as_str().to_owned(),
+            ),
+            definition,
+        );
+        Ok(())
+    }
+
+    async fn find_extension(
+        &self,
+        tenant_id: TenantId,
+        logical_name: &str,
+    ) -> AppResult<Option<ExtensionDefinition>> {
+        Ok(self
+            .extensions
+            .lock()
+            .await
+            .get(&(tenant_id, logical_name.to_owned()))
+            .cloned())
+    }
+
+    async fn list_extensions(&self, tenant_id: TenantId) -> AppResult<Vec<ExtensionDefinition>> {
+        Ok(self
+            .extensions
+            .lock()
+            .await
+            .iter()
+            .filter(|((stored_tenant_id, _), _)| *stored_tenant_id == tenant_id)
+            .map(|(_, definition)| definition.clone())
+            .collect())
+    }
+}
+
+fn custom_action_extension(logical_name: &str) -> ExtensionDefinition {
+    let isolation_policy = ExtensionIsolationPolicy::new(128, 1000, 16, false, Vec::new())
+        .unwrap_or_else(|_| unreachable!());
+    let manifest = ExtensionManifest::new(ExtensionManifestInput {
+        logical_name: logical_name.to_owned(),
+        display_name: logical_name.to_owned(),
+        package_version: "1.0.0".to_owned(),
+        runtime_api_version: "1.0".to_owned(),
+        runtime_kind: ExtensionRuntimeKind::Wasm,
+        requested_capabilities: vec![ExtensionCapability::RuntimeRecordRead],
+        isolation_policy,
+    })
+    .unwrap_or_else(|_| unreachable!());
+
+    ExtensionDefinition::new(manifest, "sha256").unwrap_or_else(|_| unreachable!())
+}
+
+#[tokio::test]
+async fn save_form_validates_script_event_fields_and_custom_actions() {
+    let tenant_id = TenantId::new();
+    let subject = "nadia";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let extension_repository = Arc::new(FakeExtensionRepository::default());
+    let service = service.with_extension_repository(extension_repository.clone());
+    let actor = actor(tenant_id, subject);
+
+    let seeded = register_publish_entity_with_text_fields(
+        &service,
+        &actor,
+        "contact",
+        "Contact",
+        &["name", "email"],
+    )
+    .await;
+    assert!(seeded.is_ok());
+
+    let form_input = |script_events: FormScriptEvents| {
+        let placement = FormFieldPlacement::new("name", 0, 0, true, false, None, None)
+            .unwrap_or_else(|_| unreachable!());
+        let section = FormSection::new("main", "Main", 0, true, 1, vec![placement], Vec::new())
+            .unwrap_or_else(|_| unreachable!());
+        let tab = FormTab::new("general", "General", 0, true, vec![section])
+            .unwrap_or_else(|_| unreachable!());
+        SaveFormInput {
+            entity_logical_name: "contact".to_owned(),
+            logical_name: "main_form".to_owned(),
+            display_name: "Main Form".to_owned(),
+            form_type: FormType::Main,
+            tabs: vec![tab],
+            header_fields: Vec::new(),
+            script_events,
+        }
+    };
+    let script_events = |on_change_fields: &[&str]| {
+        FormScriptEvents::new(
+            vec!["prefill_contact".to_owned()],
+            on_change_fields
+                .iter()
+                .map(|field| (*field).to_owned())
+                .collect(),
+            vec!["check_duplicates".to_owned()],
+        )
+        .unwrap_or_else(|_| unreachable!())
+    };
+
+    let unplaced_field = service
+        .save_form(&actor, form_input(script_events(&["email"])))
+        .await;
+    assert!(matches!(
+        unplaced_field,
+        Err(AppError::Validation(message)) if message.contains("must be placed on form")
+    ));
+
+    let missing_action = service
+        .save_form(&actor, form_input(script_events(&["name"])))
+        .await;
+    assert!(matches!(
+        missing_action,
+        Err(AppError::Validation(message))
+            if message.contains("custom action 'prefill_contact' does not exist")
+    ));
+
+    for logical_name in ["prefill_contact", "check_duplicates"] {
+        let saved = extension_repository
+            .save_extension(tenant_id, custom_action_extension(logical_name).publish())
+            .await;
+        assert!(saved.is_ok());
+    }
+    let saved = service
+        .save_form(&actor, form_input(script_events(&["name"])))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(saved.script_events().on_change_fields(), ["name"]);
+    assert_eq!(
+        saved.script_events().on_save_validators(),
+        ["check_duplicates"]
+    );
+
+    let disabled = extension_repository
+        .save_extension(
+            tenant_id,
+            custom_action_extension("check_duplicates").disable(),
+        )
+        .await;
+    assert!(disabled.is_ok());
+    let unpublished_action = service
+        .save_form(&actor, form_input(script_events(&["name"])))
+        .await;
+    assert!(matches!(
+        unpublished_action,
+        Err(AppError::Validation(message)) if message.contains("must be published")
+    ));
+}
+
+#[tokio::test]
+async fn save_view_rejects_duplicate_column_positions() {
+    let tenant_id = TenantId::new();
+    let subject = "maria";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    let seeded = register_publish_entity_with_text_fields(
+        &service,
+        &actor,
+        "contact",
+        "Contact",
+        &["name", "email"],
+    )
+    .await;
+    assert!(seeded.is_ok());
+
+    let result = service
+        .save_view(
+            &actor,
+            SaveViewInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "main_view".to_owned(),
+                display_name: "Main View".to_owned(),
+                view_type: ViewType::Grid,
+                columns: vec![
+                    ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!()),
+                    ViewColumn::new("email", 0, None, None).unwrap_or_else(|_| unreachable!()),
+                ],
+                default_sort: None,
+                filter_criteria: None,
+                is_default: true,
+            },
+        )
+        .await;
+
+    match result {
+        Err(AppError::Validation(message)) => {
+            assert!(message.contains("duplicate view column position"));
+        }
+        _ => panic!("expected view position validation failure"),
+    }
+}
+
+#[tokio::test]
+async fn save_form_normalizes_nested_layout_order_by_position() {
+    let tenant_id = TenantId::new();
+    let subject = "nina";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    let seeded = register_publish_entity_with_text_fields(
+        &service,
+        &actor,
+        "contact",
+        "Contact",
+        &["title", "name", "email", "phone"],
+    )
+    .await;
+    assert!(seeded.is_ok());
+
+    let summary_field = FormFieldPlacement::new("phone", 0, 0, true, false, None, None)
+        .unwrap_or_else(|_| unreachable!());
+    let details_field_late = FormFieldPlacement::new("email", 0, 1, true, false, None, None)
+        .unwrap_or_else(|_| unreachable!());
+    let details_field_early = FormFieldPlacement::new("name", 0, 0, true, false, None, None)
+        .unwrap_or_else(|_| unreachable!());
+    let intro_field = FormFieldPlacement::new("title", 0, 0, true, false, None, None)
+        .unwrap_or_else(|_| unreachable!());
+
+    let summary_section = FormSection::new(
+        "summary",
+        "Summary",
+        1,
+        true,
+        1,
+        vec![summary_field],
+        Vec::new(),
+    )
+    .unwrap_or_else(|_| unreachable!());
+    let details_section = FormSection::new(
+        "details",
+        "Details",
+        0,
+        true,
+        1,
+        vec![details_field_late, details_field_early],
+        Vec::new(),
+    )
+    .unwrap_or_else(|_| unreachable!());
+    let intro_section = FormSection::new(
+        "intro_section",
+        "Intro",
+        0,
+        true,
+        1,
+        vec![intro_field],
+        Vec::new(),
+    )
+    .unwrap_or_else(|_| unreachable!());
+
+    let main_tab = FormTab::new(
+        "main_tab",
+        "Main",
+        1,
+        true,
+        vec![summary_section, details_section],
+    )
+    .unwrap_or_else(|_| unreachable!());
+    let intro_tab = FormTab::new("intro_tab", "Intro", 0, true, vec![intro_section])
+        .unwrap_or_else(|_| unreachable!());
+
+    let saved = service
+        .save_form(
+            &actor,
+            SaveFormInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "main_form".to_owned(),
+                display_name: "Main Form".to_owned(),
+                form_type: FormType::Main,
+                tabs: vec![main_tab, intro_tab],
+                header_fields: Vec::new(),
+                script_events: FormScriptEvents::default(),
+            },
+        )
+        .await;
+
+    assert!(saved.is_ok());
+    let saved = saved.unwrap_or_else(|_| unreachable!());
+    let tab_order: Vec<&str> = saved
+        .tabs()
+        .iter()
+        .map(|tab| tab.logical_name().as_str())
+        .collect();
+    assert_eq!(tab_order, vec!["intro_tab", "main_tab"]);
+
+    let main_sections = saved.tabs()[1].sections();
+    let section_order: Vec<&str> = main_sections
+        .iter()
+        .map(|section| section.logical_name().as_str())
+        .collect();
+    assert_eq!(section_order, vec!["details", "summary"]);
+
+    let details_fields: Vec<&str> = main_sections[0]
+        .fields()
+        .iter()
+        .map(|field| field.field_logical_name().as_str())
+        .collect();
+    assert_eq!(details_fields, vec!["name", "email"]);
+}
+
+#[tokio::test]
+async fn save_view_normalizes_column_order_by_position() {
+    let tenant_id = TenantId::new();
+    let subject = "olga";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    let seeded = register_publish_entity_with_text_fields(
+        &service,
+        &actor,
+        "contact",
+        "Contact",
+        &["name", "email", "phone"],
+    )
+    .await;
+    assert!(seeded.is_ok());
+
+    let saved = service
+        .save_view(
+            &actor,
+            SaveViewInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "custom_view".to_owned(),
+                display_name: "Custom View".to_owned(),
+                view_type: ViewType::Grid,
+                columns: vec![
+                    ViewColumn::new("email", 1, None, None).unwrap_or_else(|_| unreachable!()),
+                    ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!()),
+                    ViewColumn::new("phone", 2, None, None).unwrap_or_else(|_| unreachable!()),
+                ],
+                default_sort: None,
+                filter_criteria: None,
+                is_default: false,
+            },
+        )
+        .await;
+
+    assert!(saved.is_ok());
+    let saved = saved.unwrap_or_else(|_| unreachable!());
+    let column_order: Vec<&str> = saved
+        .columns()
+        .iter()
+        .map(|column| column.field_logical_name().as_str())
+        .collect();
+    assert_eq!(column_order, vec!["name", "email", "phone"]);
+}
+
+#[tokio::test]
+async fn runtime_record_export_follows_view_columns_filters_and_read_scope() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([
+        (
+            (tenant_id, "alice".to_owned()),
+            vec![
+                Permission::MetadataEntityCreate,
+                Permission::MetadataFieldWrite,
+                Permission::RuntimeRecordRead,
+                Permission::RuntimeRecordWrite,
+            ],
+        ),
+        (
+            (tenant_id, "bob".to_owned()),
+            vec![
+                Permission::RuntimeRecordReadOwn,
+                Permission::RuntimeRecordWriteOwn,
+            ],
+        ),
+    ]);
+    let runtime_field_grants = HashMap::from([(
+        (tenant_id, "bob".to_owned(), "contact".to_owned()),
+        vec![RuntimeFieldGrant {
+            field_logical_name: "name".to_owned(),
+            can_read: true,
+            can_write: true,
+        }],
+    )]);
+    let (service, _) = build_service_with_runtime_field_grants(grants, runtime_field_grants);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    let seeded = register_publish_entity_with_text_fields(
+        &service,
+        &alice,
+        "contact",
+        "Contact",
+        &["name", "email", "phone"],
+    )
+    .await;
+    assert!(seeded.is_ok());
+    let saved = service
+        .save_view(
+            &alice,
+            SaveViewInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "export_view".to_owned(),
+                display_name: "Export View".to_owned(),
+                view_type: ViewType::Grid,
+                columns: vec![
+                    ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!()),
+                    ViewColumn::new("email", 1, None, None).unwrap_or_else(|_| unreachable!()),
+                ],
+                default_sort: Some(
+                    ViewSort::new("name", SortDirection::Desc).unwrap_or_else(|_| unreachable!()),
+                ),
+                filter_criteria: Some(
+                    ViewFilterGroup::new(
+                        LogicalMode::And,
+                        vec![
+                            ViewFilterCondition::new(
+                                "name",
+                                FilterOperator::Neq,
+                                json!("Archived"),
+                            )
+                            .unwrap_or_else(|_| unreachable!()),
+                        ],
+                    )
+                    .unwrap_or_else(|_| unreachable!()),
+                ),
+                is_default: false,
+            },
+        )
+        .await;
+    assert!(saved.is_ok());
+
+    for name in ["Ada", "Grace", "Archived"] {
+        assert!(
+            service
+                .create_runtime_record(
+                    &alice,
+                    "contact",
+                    json!({"name": name, "email": "a@example.com", "phone": "1"}),
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(
+        service
+            .create_runtime_record(&bob, "contact", json!({"name": "Bob"}))
+            .await
+            .is_ok()
+    );
+
+    let mut export = service
+        .prepare_runtime_record_export(&alice, "contact", "export_view")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let columns: Vec<&str> = export
+        .columns()
+        .iter()
+        .map(|column| column.field_logical_name.as_str())
+        .collect();
+    assert_eq!(columns, vec!["name", "email"]);
+    let page = export
+        .next_page()
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_default();
+    let names: Vec<&Value> = page
+        .iter()
+        .filter_map(|record| record.data().get("name"))
+        .collect();
+    assert_eq!(names, vec!["Grace", "Bob", "Ada"]);
+    assert!(matches!(export.next_page().await, Ok(None)));
+
+    let mut own_export = service
+        .prepare_runtime_record_export(&bob, "contact", "export_view")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(own_export.columns().len(), 1);
+    let own_page = own_export
+        .next_page()
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_default();
+    assert_eq!(own_page.len(), 1);
+    assert_eq!(own_page[0].data(), &json!({"name": "Bob"}));
+
+    let missing = service
+        .prepare_runtime_record_export(&alice, "contact", "missing_view")
+        .await;
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn runtime_query_validates_operator_values_per_field_type() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        service
+            .register_entity(&alice, "task", "Task")
+            .await
+            .is_ok()
+    );
+    for (logical_name, field_type) in [("title", FieldType::Text), ("due", FieldType::Date)] {
+        assert!(
+            service
+                .save_field(
+                    &alice,
+                    SaveFieldInput {
+                        entity_logical_name: "task".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type,
+                        is_required: false,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity: None,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(service.publish_entity(&alice, "task").await.is_ok());
+
+    let query = |field: &str, field_type, operator, field_value| RuntimeRecordQuery {
+        limit: 10,
+        offset: 0,
+        logical_mode: RuntimeRecordLogicalMode::And,
+        where_clause: None,
+        filters: vec![RuntimeRecordFilter {
+            scope_alias: None,
+            field_logical_name: field.to_owned(),
+            operator,
+            field_type,
+            field_value,
+        }],
+        links: Vec::new(),
+        sort: Vec::new(),
+        owner_subject: None,
+        include_inactive: false,
+    };
+    let accepted = [
+        query(
+            "title",
+            FieldType::Text,
+            RuntimeRecordOperator::IsNull,
+            Value::Null,
+        ),
+        query(
+            "title",
+            FieldType::Text,
+            RuntimeRecordOperator::BeginsWith,
+            json!("Q"),
+        ),
+        query(
+            "title",
+            FieldType::Text,
+            RuntimeRecordOperator::NotIn,
+            json!(["a", "b"]),
+        ),
+        query(
+            "due",
+            FieldType::Date,
+            RuntimeRecordOperator::Between,
+            json!(["2026-01-01", "2026-12-31"]),
+        ),
+        query(
+            "due",
+            FieldType::Date,
+            RuntimeRecordOperator::ThisMonth,
+            Value::Null,
+        ),
+        query(
+            "due",
+            FieldType::Date,
+            RuntimeRecordOperator::LastNDays,
+            json!(30),
+        ),
+    ];
+    for accepted_query in accepted {
+        assert!(
+            service
+                .query_runtime_records(&alice, "task", accepted_query)
+                .await
+                .is_ok()
+        );
+    }
+
+    let rejected = [
+        query(
+            "title",
+            FieldType::Text,
+            RuntimeRecordOperator::IsNull,
+            json!("x"),
+        ),
+        query(
+            "title",
+            FieldType::Text,
+            RuntimeRecordOperator::Between,
+            json!(["a", "b"]),
+        ),
+        query(
+            "due",
+            FieldType::Date,
+            RuntimeRecordOperator::BeginsWith,
+            json!("2026"),
+        ),
+        query(
+            "due",
+            FieldType::Date,
+            RuntimeRecordOperator::Between,
+            json!(["2026-01-01"]),
+        ),
+        query(
+            "title",
+            FieldType::Text,
+            RuntimeRecordOperator::Today,
+            Value::Null,
+        ),
+        query(
+            "due",
+            FieldType::Date,
+            RuntimeRecordOperator::LastNDays,
+            json!(0),
+        ),
+        query(
+            "due",
+            FieldType::Date,
+            RuntimeRecordOperator::Yesterday,
+            json!(1),
+        ),
+    ];
+    for rejected_query in rejected {
+        assert!(matches!(
+            service
+                .query_runtime_records(&alice, "task", rejected_query)
+                .await,
+            Err(AppError::Validation(_))
+        ));
+    }
+}
+
+#[tokio::test]
+async fn runtime_query_resolves_subject_filter_tokens_for_the_actor() {
+    let tenant_id = TenantId::new();
+    let permissions = vec![
+        Permission::MetadataEntityCreate,
+        Permission::MetadataFieldWrite,
+        Permission::RuntimeRecordRead,
+        Permission::RuntimeRecordWrite,
+    ];
+    let grants = HashMap::from([
+        ((tenant_id, "alice".to_owned()), permissions.clone()),
+        ((tenant_id, "bob".to_owned()), permissions.clone()),
+        ((tenant_id, "carol".to_owned()), permissions),
+    ]);
+    let team_peers = HashMap::from([((tenant_id, "alice".to_owned()), vec!["bob".to_owned()])]);
+    let (service, _) = build_service_with_team_peers(grants, HashMap::new(), team_peers);
+    let seeded = register_publish_entity_with_text_fields(
+        &service,
+        &actor(tenant_id, "alice"),
+        "contact",
+        "Contact",
+        &["name"],
+    )
+    .await;
+    assert!(seeded.is_ok());
+    for subject in ["alice", "bob", "carol"] {
+        assert!(
+            service
+                .create_runtime_record(
+                    &actor(tenant_id, subject),
+                    "contact",
+                    json!({"name": subject})
+                )
+                .await
+                .is_ok()
+        );
+    }
+
+    let owner_query = |operator: RuntimeRecordOperator, token: &str| RuntimeRecordQuery {
+        limit: 10,
+        offset: 0,
+        logical_mode: RuntimeRecordLogicalMode::And,
+        where_clause: None,
+        filters: vec![RuntimeRecordFilter {
+            scope_alias: None,
+            field_logical_name: "owner".to_owned(),
+            operator,
+            field_type: FieldType::Text,
+            field_value: json!(token),
+        }],
+        links: Vec::new(),
+        sort: Vec::new(),
+        owner_subject: None,
+        include_inactive: false,
+    };
+    let owners = |records: Vec<RuntimeRecord>| {
+        let mut owners: Vec<String> = records
+            .iter()
+            .filter_map(|record| record.data()["owner"].as_str().map(str::to_owned))
+            .collect();
+        owners.sort();
+        owners
+    };
+
+    let mine = service
+        .query_runtime_records(
+            &actor(tenant_id, "alice"),
+            "contact",
+            owner_query(RuntimeRecordOperator::Eq, "@me"),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(owners(mine), vec!["alice"]);
+
+    let team = service
+        .query_runtime_records(
+            &actor(tenant_id, "alice"),
+            "contact",
+            owner_query(RuntimeRecordOperator::Eq, "@my_team"),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(owners(team), vec!["alice", "bob"]);
+
+    let teamless = service
+        .query_runtime_records(
+            &actor(tenant_id, "carol"),
+            "contact",
+            owner_query(RuntimeRecordOperator::Eq, "@my_team"),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(owners(teamless), vec!["carol"]);
+
+    let unsupported = service
+        .query_runtime_records(
+            &actor(tenant_id, "alice"),
+            "contact",
+            owner_query(RuntimeRecordOperator::Contains, "@my_team"),
+        )
+        .await;
+    assert!(matches!(unsupported, Err(AppError::Validation(_))));
+}
+
+#[tokio::test]
+async fn save_form_supports_reorder_then_undo_redo_transitions() {
+    let tenant_id = TenantId::new();
+    let subject = "paula";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::MetadataFieldRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    let seeded = register_publish_entity_with_text_fields(
+        &service,
+        &actor,
+        "contact",
+        "Contact",
+        &["title", "name", "email", "phone"],
+    )
+    .await;
+    assert!(seeded.is_ok());
+
+    let initial = service
+        .save_form(
+            &actor,
+            SaveFormInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "custom_form".to_owned(),
+                display_name: "Custom Form".to_owned(),
+                form_type: FormType::Main,
+                tabs: vec![
+                    FormTab::new(
+                        "intro_tab",
+                        "Intro",
+                        0,
+                        true,
+                        vec![
+                            FormSection::new(
+                                "intro_section",
+                                "Intro Section",
+                                0,
+                                true,
+                                1,
+                                vec![
+                                    FormFieldPlacement::new("title", 0, 0, true, false, None, None)
+                                        .unwrap_or_else(|_| unreachable!()),
+                                ],
+                                Vec::new(),
+                            )
+                            .unwrap_or_else(|_| unreachable!()),
+                        ],
+                    )
+                    .unwrap_or_else(|_| unreachable!()),
+                    FormTab::new(
+                        "main_tab",
+                        "Main",
+                        1,
+                        true,
+                        vec![
+                            FormSection::new(
+                                "details",
+                                "Details",
+                                0,
+                                true,
+                                1,
+                                vec![
+                                    FormFieldPlacement::new("name", 0, 0, true, false, None, None)
+                                        .unwrap_or_else(|_| unreachable!()),
+                                    FormFieldPlacement::new("email", 0, 1, true, false, None, None)
+                                        .unwrap_or_else(|_| unreachable!()),
+                                ],
+                                Vec::new(),
+                            )
+                            .unwrap_or_else(|_| unreachable!()),
+                            FormSection::new(
+                                "summary",
+                                "Summary",
+                                1,
+                                true,
+                                1,
+                                vec![
+                                    FormFieldPlacement::new("phone", 0, 0, true, false, None, None)
+                                        .unwrap_or_else(|_| unreachable!()),
+                                ],
+                                Vec::new(),
+                            )
+                            .unwrap_or_else(|_| unreachable!()),
+                        ],
+                    )
+                    .unwrap_or_else(|_| unreachable!()),
+                ],
+                header_fields: Vec::new(),
+                script_events: FormScriptEvents::default(),
+            },
+        )
+        .await;
+    assert!(initial.is_ok());
+
+    let reordered = service
+        .save_form(
+            &actor,
+            SaveFormInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "custom_form".to_owned(),
+                display_name: "Custom Form".to_owned(),
+                form_type: FormType::Main,
+                tabs: vec![
+                    FormTab::new(
+                        "main_tab",
+                        "Main",
+                        0,
+                        true,
+                        vec![
+                            FormSection::new(
+                                "summary",
+                                "Summary",
+                                0,
+                                true,
+                                1,
+                                vec![
+                                    FormFieldPlacement::new("phone", 0, 0, true, false, None, None)
+                                        .unwrap_or_else(|_| unreachable!()),
+                                ],
+                                Vec::new(),
+                            )
+                            .unwrap_or_else(|_| unreachable!()),
+                            FormSection::new(
+                                "details",
+                                "Details",
+                                1,
+                                true,
+                                1,
+                                vec![
+                                    FormFieldPlacement::new("email", 0, 0, true, false, None, None)
+                                        .unwrap_or_else(|_| unreachable!()),
+                                    FormFieldPlacement::new("name", 0, 1, true, false, None, None)
+                                        .unwrap_or_else(|_| unreachable!()),
+                                ],
+                                Vec::new(),
+                            )
+                            .unwrap_or_else(|_| unreachable!()),
+                        ],
+                    )
+                    .unwrap_or_else(|_| unreachable!()),
+                    FormTab::new(
+                        "intro_tab",
+                        "Intro",
+                        1,
+                        true,
+                        vec![
+                            FormSection::new(
+                                "intro_section",
+                                "Intro Section",
+                                0,
+                                true,
+                                1,
+                                vec![
+                                    FormFieldPlacement::new("title", 0, 0, true, false, None, None)
+                                        .unwrap_or_else(|_| unreachable!()),
+                                ],
+                                Vec::new(),
+                            )
+                            .unwrap_or_else(|_| unreachable!()),
+                        ],
+                    )
+                    .unwrap_or_else(|_| unreachable!()),
+                ],
+                header_fields: Vec::new(),
+                script_events: FormScriptEvents::default(),
+            },
+        )
+        .await;
+    assert!(reordered.is_ok());
+    let reordered = reordered.unwrap_or_else(|_| unreachable!());
+    let reordered_tabs: Vec<&str> = reordered
+        .tabs()
+        .iter()
+        .map(|tab| tab.logical_name().as_str())
+        .collect();
+    assert_eq!(reordered_tabs, vec!["main_tab", "intro_tab"]);
+    let reordered_main_sections: Vec<&str> = reordered.tabs()[0]
+        .sections()
+        .iter()
+        .map(|section| section.logical_name().as_str())
+        .collect();
+    assert_eq!(reordered_main_sections, vec!["summary", "details"]);
+    let reordered_details_fields: Vec<&str> = reordered.tabs()[0].sections()[1]
+        .fields()
+        .iter()
+        .map(|field| field.field_logical_name().as_str())
+        .collect();
+    assert_eq!(reordered_details_fields, vec!["email", "name"]);
+
+    let undone = service
+        .save_form(
+            &actor,
+            SaveFormInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "custom_form".to_owned(),
+                display_name: "Custom Form".to_owned(),
+                form_type: FormType::Main,
+                tabs: vec![
+                    FormTab::new(
+                        "intro_tab",
+                        "Intro",
+                        0,
+                        true,
+                        vec![
+                            FormSection::new(
+                                "intro_section",
+                                "Intro Section",
+                                0,
+                                true,
+                                1,
+                                vec![
+                                    FormFieldPlacement::new("title", 0, 0, true, false, None, None)
+                                        .unwrap_or_else(|_| unreachable!()),
+                                ],
+                                Vec::new(),
+                            )
+                            .unwrap_or_else(|_| unreachable!()),
+                        ],
+                    )
+                    .unwrap_or_else(|_| unreachable!()),
+                    FormTab::new(
+                        "main_tab",
+                        "Main",
+                        1,
+                        true,
+                        vec![
+                            FormSection::new(
+                                "details",
+                                "Details",
+                                0,
+                                true,
+                                1,
+                                vec![
+                                    FormFieldPlacement::new("name", 0, 0, true, false, None, None)
+                                        .unwrap_or_else(|_| unreachable!()),
+                                    FormFieldPlacement::new("email", 0, 1, true, false, None, None)
+                                        .unwrap_or_else(|_| unreachable!()),
+                                ],
+                                Vec::new(),
+                            )
+                            .unwrap_or_else(|_| unreachable!()),
+                            FormSection::new(
+                                "summary",
+                                "Summary",
+                                1,
+                                true,
+                                1,
+                                vec![
+                                    FormFieldPlacement::new("phone", 0, 0, true, false, None, None)
+                                        .unwrap_or_else(|_| unreachable!()),
+                                ],
+                                Vec::new(),
+                            )
+                            .unwrap_or_else(|_| unreachable!()),
+                        ],
+                    )
+                    .unwrap_or_else(|_| unreachable!()),
+                ],
+                header_fields: Vec::new(),
+                script_events: FormScriptEvents::default(),
+            },
+        )
+        .await;
+    assert!(undone.is_ok());
+
+    let redone = service
+        .save_form(
+            &actor,
+            SaveFormInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "custom_form".to_owned(),
+                display_name: "Custom Form".to_owned(),
+                form_type: FormType::Main,
+                tabs: reordered.tabs().to_vec(),
+                header_fields: Vec::new(),
+                script_events: FormScriptEvents::default(),
+            },
+        )
+        .await;
+    assert!(redone.is_ok());
+    let redone = redone.unwrap_or_else(|_| unreachable!());
+    let redone_tabs: Vec<&str> = redone
+        .tabs()
+        .iter()
+        .map(|tab| tab.logical_name().as_str())
+        .collect();
+    assert_eq!(redone_tabs, vec!["main_tab", "intro_tab"]);
+
+    let listed = service
+        .find_form(&actor, "contact", "custom_form")
+        .await
+        .unwrap_or(None);
+    assert!(listed.is_some());
+    let listed = listed.unwrap_or_else(|| unreachable!());
+    let listed_tabs: Vec<&str> = listed
+        .tabs()
+        .iter()
+        .map(|tab| tab.logical_name().as_str())
+        .collect();
+    assert_eq!(listed_tabs, vec!["main_tab", "intro_tab"]);
+}
+
+#[tokio::test]
+async fn save_view_supports_column_reorder_then_undo_redo_transitions() {
+    let tenant_id = TenantId::new();
+    let subject = "quinn";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldWrite,
+            Permission::MetadataFieldRead,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    let seeded = register_publish_entity_with_text_fields(
+        &service,
+        &actor,
+        "contact",
+        "Contact",
+        &["name", "email", "phone"],
+    )
+    .await;
+    assert!(seeded.is_ok());
+
+    let initial = service
+        .save_view(
+            &actor,
+            SaveViewInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "custom_view".to_owned(),
+                display_name: "Custom View".to_owned(),
+                view_type: ViewType::Grid,
+                columns: vec![
+                    ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!()),
+                    ViewColumn::new("email", 1, None, None).unwrap_or_else(|_| unreachable!()),
+                    ViewColumn::new("phone", 2, None, None).unwrap_or_else(|_| unreachable!()),
+                ],
+                default_sort: None,
+                filter_criteria: None,
+                is_default: false,
+            },
+        )
+        .await;
+    assert!(initial.is_ok());
+
+    let reordered = service
+        .save_view(
+            &actor,
+            SaveViewInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "custom_view".to_owned(),
+                display_name: "Custom View".to_owned(),
+                view_type: ViewType::Grid,
+                columns: vec![
+                    ViewColumn::new("email", 0, None, None).unwrap_or_else(|_| unreachable!()),
+                    ViewColumn::new("name", 1, None, None).unwrap_or_else(|_| unreachable!()),
+                    ViewColumn::new("phone", 2, None, None).unwrap_or_else(|_| unreachable!()),
+                ],
+                default_sort: None,
+                filter_criteria: None,
+                is_default: false,
+            },
+        )
+        .await;
+    assert!(reordered.is_ok());
+    let reordered = reordered.unwrap_or_else(|_| unreachable!());
+    let reordered_columns: Vec<&str> = reordered
+        .columns()
+        .iter()
+        .map(|column| column.field_logical_name().as_str())
+        .collect();
+    assert_eq!(reordered_columns, vec!["email", "name", "phone"]);
+
+    let undone = service
+        .save_view(
+            &actor,
+            SaveViewInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "custom_view".to_owned(),
+                display_name: "Custom View".to_owned(),
+                view_type: ViewType::Grid,
+                columns: vec![
+                    ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!()),
+                    ViewColumn::new("email", 1, None, None).unwrap_or_else(|_| unreachable!()),
+                    ViewColumn::new("phone", 2, None, None).unwrap_or_else(|_| unreachable!()),
+                ],
+                default_sort: None,
+                filter_criteria: None,
+                is_default: false,
+            },
+        )
+        .await;
+    assert!(undone.is_ok());
+
+    let redone = service
+        .save_view(
+            &actor,
+            SaveViewInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "custom_view".to_owned(),
+                display_name: "Custom View".to_owned(),
+                view_type: ViewType::Grid,
+                columns: reordered.columns().to_vec(),
+                default_sort: None,
+                filter_criteria: None,
+                is_default: false,
+            },
+        )
+        .await;
+    assert!(redone.is_ok());
+    let redone = redone.unwrap_or_else(|_| unreachable!());
+    let redone_columns: Vec<&str> = redone
+        .columns()
+        .iter()
+        .map(|column| column.field_logical_name().as_str())
+        .collect();
+    assert_eq!(redone_columns, vec!["email", "name", "phone"]);
+
+    let listed = service
+        .find_view(&actor, "contact", "custom_view")
+        .await
+        .unwrap_or(None);
+    assert!(listed.is_some());
+    let listed = listed.unwrap_or_else(|| unreachable!());
+    let listed_columns: Vec<&str> = listed
+        .columns()
+        .iter()
+        .map(|column| column.field_logical_name().as_str())
+        .collect();
+    assert_eq!(listed_columns, vec!["email", "name", "phone"]);
+}
+
+#[tokio::test]
+async fn portability_export_import_round_trip_remaps_relations_deterministically() {
+    let source_tenant_id = TenantId::new();
+    let target_tenant_id = TenantId::new();
+    let subject = "porter";
+    let permissions = vec![
+        Permission::MetadataEntityCreate,
+        Permission::MetadataEntityRead,
+        Permission::MetadataFieldRead,
+        Permission::MetadataFieldWrite,
+        Permission::RuntimeRecordRead,
+        Permission::RuntimeRecordWrite,
+    ];
+
+    let source_grants =
+        HashMap::from([((source_tenant_id, subject.to_owned()), permissions.clone())]);
+    let target_grants = HashMap::from([((target_tenant_id, subject.to_owned()), permissions)]);
+
+    let (source_service, _) = build_service(source_grants);
+    let (target_service, _) = build_service(target_grants);
+
+    let source_actor = actor(source_tenant_id, subject);
+    let target_actor = actor(target_tenant_id, subject);
+
+    source_service
+        .register_entity(&source_actor, "account", "Account")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    source_service
+        .save_field(
+            &source_actor,
+            SaveFieldInput {
+                entity_logical_name: "account".to_owned(),
+                logical_name: "name".to_owned(),
+                display_name: "Name".to_owned(),
+                field_type: FieldType::Text,
+                is_required: true,
+                is_unique: false,
+                default_value: None,
+                calculation_expression: None,
+                relation_target_entity: None,
+                option_set_logical_name: None,
+                classification: None,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    source_service
+        .publish_entity(&source_actor, "account")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    source_service
+        .register_entity(&source_actor, "contact", "Contact")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    source_service
+        .save_field(
+            &source_actor,
+            SaveFieldInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "name".to_owned(),
+                display_name: "Name".to_owned(),
+                field_type: FieldType::Text,
+                is_required: true,
+                is_unique: false,
+                default_value: None,
+                calculation_expression: None,
+                relation_target_entity: None,
+                option_set_logical_name: None,
+                classification: None,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    source_service
+        .save_field(
+            &source_actor,
+            SaveFieldInput {
+                entity_logical_name: "contact".to_owned(),
+                logical_name: "account_id".to_owned(),
+                display_name: "Account".to_owned(),
+                field_type: FieldType::Relation,
+                is_required: true,
+                is_unique: false,
+                default_value: None,
+                calculation_expression: None,
+                relation_target_entity: Some("account".to_owned()),
+                option_set_logical_name: None,
+                classification: None,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    source_service
+        .publish_entity_with_allowed_unpublished_entities(
+            &source_actor,
+            "contact",
+            &["account".to_owned(), "contact".to_owned()],
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let account_record = source_service
+        .create_runtime_record(&source_actor, "account", json!({"name": "ACME"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let source_account_record_id = account_record.record_id().as_str().to_owned();
+
+    let contact_record = source_service
+        .create_runtime_record(
+            &source_actor,
+            "contact",
+            json!({"name": "Jane", "account_id": source_account_record_id}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let source_contact_record_id = contact_record.record_id().as_str().to_owned();
+
+    let bundle = source_service
+        .export_workspace_bundle(&source_actor, ExportWorkspaceBundleOptions::default())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let dry_run = target_service
+        .import_workspace_bundle(
+            &target_actor,
+            bundle.clone(),
+            ImportWorkspaceBundleOptions {
+                dry_run: true,
+                remap_record_ids: true,
+                ..ImportWorkspaceBundleOptions::default()
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    assert!(dry_run.dry_run);
+    assert_eq!(dry_run.entities_processed, 2);
+    assert_eq!(dry_run.runtime_records_discovered, 2);
+    assert_eq!(dry_run.runtime_records_remapped, 2);
+
+    target_service
+        .import_workspace_bundle(
+            &target_actor,
+            bundle,
+            ImportWorkspaceBundleOptions {
+                remap_record_ids: true,
+                ..ImportWorkspaceBundleOptions::default()
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let imported_accounts = target_service
+        .list_runtime_records(
+            &target_actor,
+            "account",
+            RecordListQuery {
+                limit: 10,
+                offset: 0,
+                owner_subject: None,
+                include_inactive: false,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(imported_accounts.len(), 1);
+
+    let imported_contacts = target_service
+        .list_runtime_records(
+            &target_actor,
+            "contact",
+            RecordListQuery {
+                limit: 10,
+                offset: 0,
+                owner_subject: None,
+                include_inactive: false,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(imported_contacts.len(), 1);
+
+    let imported_account_id = imported_accounts[0].record_id().as_str().to_owned();
+    let imported_contact_data = imported_contacts[0].data();
+
+    assert_ne!(source_account_record_id, imported_account_id);
+    assert_ne!(
+        source_contact_record_id,
+        imported_contacts[0].record_id().as_str()
+    );
+    assert_eq!(
+        imported_contact_data
+            .get("account_id")
+            .and_then(Value::as_str)
+            .unwrap_or_default(),
+        imported_account_id
+    );
+}
+
+#[tokio::test]
+async fn portability_import_rejects_checksum_mismatch() {
+    let tenant_id = TenantId::new();
+    let subject = "porter";
+    let grants = HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataEntityRead,
+            Permission::MetadataFieldRead,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+            Permission::RuntimeRecordWrite,
+        ],
+    )]);
+
+    let (service, _) = build_service(grants);
+    let actor = actor(tenant_id, subject);
+
+    service
+        .register_entity(&actor, "account", "Account")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    service
+        .save_field(
+            &actor,
+            SaveFieldInput {
+                entity_logical_name: "account".to_owned(),
+                logical_name: "name".to_owned(),
+                display_name: "Name".to_owned(),
+                field_type: FieldType::Text,
+                is_required: true,
+                is_unique: false,
+                default_value: None,
+                calculation_expression: None,
+                relation_target_entity: None,
+                option_set_logical_name: None,
+                classification: None,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let mut bundle = service
+        .export_workspace_bundle(
+            &actor,
+            ExportWorkspaceBundleOptions {
+                include_metadata: true,
+                include_runtime_data: false,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    bundle.payload_sha256 = "bad-checksum".to_owned();
+
+    let result = service
+        .import_workspace_bundle(
+            &actor,
+            bundle,
+            ImportWorkspaceBundleOptions {
+                dry_run: true,
+                import_metadata: true,
+                import_runtime_data: false,
+                remap_record_ids: false,
+            },
+        )
+        .await;
+
+    assert!(result.is_err());
+    assert!(matches!(result, Err(AppError::Validation(_))));
+}
+
+#[tokio::test]
+async fn runtime_record_upsert_by_alternate_key_creates_then_updates() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldRead,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+            Permission::RuntimeRecordWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        service
+            .register_entity(&alice, "product", "Product")
+            .await
+            .is_ok()
+    );
+    for (logical_name, field_type, is_unique) in [
+        ("name", FieldType::Text, false),
+        ("sku", FieldType::Text, true),
+        ("erp_number", FieldType::Number, true),
+    ] {
+        assert!(
+            service
+                .save_field(
+                    &alice,
+                    SaveFieldInput {
+                        entity_logical_name: "product".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type,
+                        is_required: false,
+                        is_unique,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity: None,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(service.publish_entity(&alice, "product").await.is_ok());
+
+    let created = service
+        .upsert_runtime_record_by_key(
+            &alice,
+            "product",
+            "sku",
+            "SKU-1",
+            json!({"name": "Widget"}),
+            false,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(created.outcome, RuntimeRecordUpsertOutcome::Created);
+    assert_eq!(created.record.data()["sku"], json!("SKU-1"));
+
+    let updated = service
+        .upsert_runtime_record_by_key(
+            &alice,
+            "product",
+            "sku",
+            "SKU-1",
+            json!({"name": "Widget v2", "sku": "SKU-1"}),
+            false,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(updated.outcome, RuntimeRecordUpsertOutcome::Updated);
+    assert_eq!(updated.record.record_id(), created.record.record_id());
+    assert_eq!(updated.record.data()["name"], json!("Widget v2"));
+
+    let by_number = service
+        .upsert_runtime_record_by_key(
+            &alice,
+            "product",
+            "erp_number",
+            "42",
+            json!({"name": "Gadget"}),
+            false,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(by_number.outcome, RuntimeRecordUpsertOutcome::Created);
+    assert_eq!(by_number.record.data()["erp_number"], json!(42));
+
+    let not_a_key = service
+        .upsert_runtime_record_by_key(&alice, "product", "name", "Widget", json!({}), false)
+        .await;
+    assert!(matches!(not_a_key, Err(AppError::Validation(_))));
+    let contradicting = service
+        .upsert_runtime_record_by_key(
+            &alice,
+            "product",
+            "sku",
+            "SKU-1",
+            json!({"sku": "SKU-2"}),
+            false,
+        )
+        .await;
+    assert!(matches!(contradicting, Err(AppError::Validation(_))));
+    let not_a_number = service
+        .upsert_runtime_record_by_key(&alice, "product", "erp_number", "abc", json!({}), false)
+        .await;
+    assert!(matches!(not_a_number, Err(AppError::Validation(_))));
+}
+
+#[tokio::test]
+async fn runtime_record_slugs_are_generated_unique_and_resolvable() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldRead,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+            Permission::RuntimeRecordWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        service
+            .register_entity(&alice, "article", "Article")
+            .await
+            .is_ok()
+    );
+    for (logical_name, is_unique) in [("title", false), ("slug", true)] {
+        assert!(
+            service
+                .save_field(
+                    &alice,
+                    SaveFieldInput {
+                        entity_logical_name: "article".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type: FieldType::Text,
+                        is_required: false,
+                        is_unique,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity: None,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(service.publish_entity(&alice, "article").await.is_ok());
+
+    let not_unique = service
+        .save_entity_slug_config(
+            &alice,
+            SaveEntitySlugConfigInput {
+                entity_logical_name: "article".to_owned(),
+                slug_field_logical_name: "title".to_owned(),
+                source_field_logical_name: "slug".to_owned(),
+            },
+        )
+        .await;
+    assert!(matches!(not_unique, Err(AppError::Validation(_))));
+    assert!(
+        service
+            .save_entity_slug_config(
+                &alice,
+                SaveEntitySlugConfigInput {
+                    entity_logical_name: "article".to_owned(),
+                    slug_field_logical_name: "slug".to_owned(),
+                    source_field_logical_name: "title".to_owned(),
+                },
+            )
+            .await
+            .is_ok()
+    );
+
+    let first = service
+        .create_runtime_record(&alice, "article", json!({"title": "Hello, World!"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(first.data()["slug"], json!("hello-world"));
+    let second = service
+        .create_runtime_record(&alice, "article", json!({"title": "Hello World"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(second.data()["slug"], json!("hello-world-2"));
+
+    let renamed = service
+        .update_runtime_record(
+            &alice,
+            "article",
+            first.record_id().as_str(),
+            json!({"title": "Renamed"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(renamed.data()["slug"], json!("hello-world"));
+
+    let invalid = service
+        .create_runtime_record(
+            &alice,
+            "article",
+            json!({"title": "Other", "slug": "Not A Slug"}),
+        )
+        .await;
+    assert!(matches!(invalid, Err(AppError::Validation(_))));
+    let duplicate = service
+        .create_runtime_record(
+            &alice,
+            "article",
+            json!({"title": "Other", "slug": "hello-world"}),
+        )
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+
+    let resolved = service
+        .resolve_runtime_record_slug(&alice, "article", "hello-world-2")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(resolved.record_id(), second.record_id());
+    let missing = service
+        .resolve_runtime_record_slug(&alice, "article", "missing")
+        .await;
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn runtime_record_status_transitions_are_enforced_and_recorded() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldRead,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+            Permission::RuntimeRecordWrite,
+        ],
+    )]);
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let repository = Arc::new(FakeRepository::new());
+    let service = MetadataService::new(
+        repository.clone(),
+        AuthorizationService::new(
+            Arc::new(FakeAuthorizationRepository {
+                grants,
+                runtime_field_grants: HashMap::new(),
+                team_peers: HashMap::new(),
+                role_names: HashMap::new(),
+            }),
+            audit_repository.clone(),
+        ),
+        audit_repository.clone(),
+    );
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        register_publish_entity_with_text_fields(
+            &service,
+            &alice,
+            "case",
+            "Case",
+            &["title", "status", "status_reason"],
+        )
+        .await
+        .is_ok()
+    );
+
+    let status = |value: &str, reasons: &[&str]| RecordStatusOption {
+        value: value.to_owned(),
+        label: value.to_owned(),
+        reasons: reasons.iter().map(|reason| (*reason).to_owned()).collect(),
+        inactive: false,
+    };
+    let transition = |from: &str, to: &str| RecordStatusTransition {
+        from: from.to_owned(),
+        to: to.to_owned(),
+    };
+    let model_input = |status_field: &str| SaveEntityStatusModelInput {
+        entity_logical_name: "case".to_owned(),
+        status_field_logical_name: status_field.to_owned(),
+        reason_field_logical_name: Some("status_reason".to_owned()),
+        initial_status: "open".to_owned(),
+        statuses: vec![
+            status("open", &["new", "waiting"]),
+            status("resolved", &["fixed", "duplicate"]),
+            status("closed", &["archived"]),
+        ],
+        transitions: vec![
+            transition("open", "resolved"),
+            transition("resolved", "closed"),
+        ],
+    };
+    let missing_field = service
+        .save_entity_status_model(&alice, model_input("state"))
+        .await;
+    assert!(matches!(missing_field, Err(AppError::NotFound(_))));
+    assert!(
+        service
+            .save_entity_status_model(&alice, model_input("status"))
+            .await
+            .is_ok()
+    );
+
+    let created = service
+        .create_runtime_record(&alice, "case", json!({"title": "Printer"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(created.data()["status"], json!("open"));
+    assert_eq!(created.data()["status_reason"], json!("new"));
+    let record_id = created.record_id().as_str().to_owned();
+
+    let skipped = service
+        .update_runtime_record(
+            &alice,
+            "case",
+            record_id.as_str(),
+            json!({"title": "Printer", "status": "closed"}),
+        )
+        .await;
+    let message = match skipped {
+        Err(AppError::Validation(message)) => message,
+        _ => unreachable!(),
+    };
+    assert!(message.starts_with(RECORD_STATUS_TRANSITION_INVALID_PREFIX));
+    let wrong_reason = service
+        .update_runtime_record(
+            &alice,
+            "case",
+            record_id.as_str(),
+            json!({"title": "Printer", "status": "resolved", "status_reason": "waiting"}),
+        )
+        .await;
+    assert!(matches!(wrong_reason, Err(AppError::Validation(_))));
+
+    let resolved = service
+        .update_runtime_record(
+            &alice,
+            "case",
+            record_id.as_str(),
+            json!({"title": "Printer", "status": "resolved", "status_reason": "new"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(resolved.data()["status_reason"], json!("fixed"));
+    let renamed = service
+        .update_runtime_record(
+            &alice,
+            "case",
+            record_id.as_str(),
+            json!({"title": "Printer jam"}),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(renamed.data()["status"], json!("resolved"));
+
+    let history = service
+        .runtime_record_status_history(&alice, "case", record_id.as_str())
+        .await
+        .unwrap_or_default();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].from_status, None);
+    assert_eq!(history[0].to_status, "open");
+    assert_eq!(history[1].from_status.as_deref(), Some("open"));
+    assert_eq!(history[1].to_status, "resolved");
+    assert_eq!(history[1].to_reason.as_deref(), Some("fixed"));
+
+    let notifications = repository.status_notifications.lock().await;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(
+        notifications[0].trigger,
+        WorkflowTrigger::RuntimeRecordStatusChanged {
+            entity_logical_name: "case".to_owned(),
+        }
+    );
+    assert_eq!(notifications[0].payload["previous_status"], json!("open"));
+    assert_eq!(notifications[0].payload["status"], json!("resolved"));
+    drop(notifications);
+    assert!(
+        audit_repository
+            .events
+            .lock()
+            .await
+            .iter()
+            .any(|event| event.action == AuditAction::RuntimeRecordStatusChanged)
+    );
+
+    assert!(
+        service
+            .delete_entity_status_model(&alice, "case")
+            .await
+            .is_ok()
+    );
+    let reopened = service
+        .update_runtime_record(
+            &alice,
+            "case",
+            record_id.as_str(),
+            json!({"title": "Printer jam", "status": "anything"}),
+        )
+        .await;
+    assert!(reopened.is_ok());
+}
+
+#[tokio::test]
+async fn inactive_records_only_accept_reactivation_by_permitted_subjects() {
+    let tenant_id = TenantId::new();
+    let permissions = vec![
+        Permission::MetadataEntityCreate,
+        Permission::MetadataFieldRead,
+        Permission::MetadataFieldWrite,
+        Permission::RuntimeRecordRead,
+        Permission::RuntimeRecordWrite,
+    ];
+    let mut supervisor_permissions = permissions.clone();
+    supervisor_permissions.push(Permission::RuntimeRecordReactivate);
+    let grants = HashMap::from([
+        ((tenant_id, "alice".to_owned()), permissions),
+        ((tenant_id, "bob".to_owned()), supervisor_permissions),
+    ]);
+    let (service, _) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+    assert!(
+        register_publish_entity_with_text_fields(
+            &service,
+            &alice,
+            "case",
+            "Case",
+            &["title", "status"],
+        )
+        .await
+        .is_ok()
+    );
+    let status = |value: &str, inactive: bool| RecordStatusOption {
+        value: value.to_owned(),
+        label: value.to_owned(),
+        reasons: Vec::new(),
+        inactive,
+    };
+    let transition = |from: &str, to: &str| RecordStatusTransition {
+        from: from.to_owned(),
+        to: to.to_owned(),
+    };
+    assert!(
+        service
+            .save_entity_status_model(
+                &alice,
+                SaveEntityStatusModelInput {
+                    entity_logical_name: "case".to_owned(),
+                    status_field_logical_name: "status".to_owned(),
+                    reason_field_logical_name: None,
+                    initial_status: "open".to_owned(),
+                    statuses: vec![status("open", false), status("closed", true)],
+                    transitions: vec![transition("open", "closed"), transition("closed", "open")],
+                },
+            )
+            .await
+            .is_ok()
+    );
+
+    let created = service
+        .create_runtime_record(&alice, "case", json!({"title": "Printer"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record_id = created.record_id().as_str().to_owned();
+    assert!(
+        service
+            .update_runtime_record(
+                &alice,
+                "case",
+                record_id.as_str(),
+                json!({"title": "Printer", "status": "closed"}),
+            )
+            .await
+            .is_ok()
+    );
+
+    let edit = service
+        .update_runtime_record(
+            &alice,
+            "case",
+            record_id.as_str(),
+            json!({"title": "Printer jam"}),
+        )
+        .await;
+    assert!(matches!(
+        edit,
+        Err(AppError::Validation(message)) if message.starts_with(RECORD_INACTIVE_PREFIX)
+    ));
+    let unpermitted = service
+        .update_runtime_record(
+            &alice,
+            "case",
+            record_id.as_str(),
+            json!({"title": "Printer", "status": "open"}),
         )
         .await;
-    assert!(redone.is_ok());
-    let redone = redone.unwrap_or_else(|_| unreachable!());
-    let redone_tabs: Vec<&str> = redone
-        .tabs()
-        .iter()
-        .map(|tab| tab.logical_name().as_str())
-        .collect();
-    assert_eq!(redone_tabs, vec!["main_tab", "intro_tab"]);
+    assert!(matches!(unpermitted, Err(AppError::Forbidden(_))));
 
-    let listed = service
-        .find_form(&actor, "contact", "custom_form")
+    let reactivated = service
+        .update_runtime_record(
+            &bob,
+            "case",
+            record_id.as_str(),
+            json!({"title": "Printer jam", "status": "open"}),
+        )
         .await
-        .unwrap_or(None);
-    assert!(listed.is_some());
-    let listed = listed.unwrap_or_else(|| unreachable!());
-    let listed_tabs: Vec<&str> = listed
-        .tabs()
-        .iter()
-        .map(|tab| tab.logical_name().as_str())
-        .collect();
-    assert_eq!(listed_tabs, vec!["main_tab", "intro_tab"]);
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(reactivated.data()["status"], json!("open"));
+    assert_eq!(reactivated.data()["title"], json!("Printer jam"));
 }
 
 #[tokio::test]
-async fn save_view_supports_column_reorder_then_undo_redo_transitions() {
+async fn runtime_records_expose_read_only_system_fields() {
     let tenant_id = TenantId::new();
-    let subject = "quinn";
-    let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
-        vec![
-            Permission::MetadataEntityCreate,
-            Permission::MetadataFieldWrite,
-            Permission::MetadataFieldRead,
-        ],
-    )]);
+    let permissions = vec![
+        Permission::MetadataEntityCreate,
+        Permission::MetadataFieldRead,
+        Permission::MetadataFieldWrite,
+        Permission::RuntimeRecordRead,
+        Permission::RuntimeRecordWrite,
+    ];
+    let grants = HashMap::from([
+        ((tenant_id, "alice".to_owned()), permissions.clone()),
+        ((tenant_id, "bob".to_owned()), permissions),
+    ]);
     let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+    assert!(
+        service
+            .register_entity(&alice, "ticket", "Ticket")
+            .await
+            .is_ok()
+    );
+    let field_input = |logical_name: &str| SaveFieldInput {
+        entity_logical_name: "ticket".to_owned(),
+        logical_name: logical_name.to_owned(),
+        display_name: logical_name.to_owned(),
+        field_type: FieldType::Text,
+        is_required: false,
+        is_unique: false,
+        default_value: None,
+        calculation_expression: None,
+        relation_target_entity: None,
+        option_set_logical_name: None,
+        classification: None,
+    };
+    let reserved = service.save_field(&alice, field_input("created_by")).await;
+    assert!(matches!(reserved, Err(AppError::Validation(_))));
+    assert!(
+        service
+            .save_field(&alice, field_input("title"))
+            .await
+            .is_ok()
+    );
+    assert!(service.publish_entity(&alice, "ticket").await.is_ok());
 
-    let seeded = register_publish_entity_with_text_fields(
-        &service,
-        &actor,
-        "contact",
-        "Contact",
-        &["name", "email", "phone"],
-    )
-    .await;
-    assert!(seeded.is_ok());
+    let created = service
+        .create_runtime_record(&alice, "ticket", json!({"title": "Printer"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(created.data()["created_by"], json!("alice"));
+    assert_eq!(created.data()["modified_by"], json!("alice"));
+    assert_eq!(created.data()["owner"], json!("alice"));
+    assert!(created.data()["created_at"].is_string());
 
-    let initial = service
-        .save_view(
-            &actor,
-            SaveViewInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "custom_view".to_owned(),
-                display_name: "Custom View".to_owned(),
-                view_type: ViewType::Grid,
-                columns: vec![
-                    ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!()),
-                    ViewColumn::new("email", 1, None, None).unwrap_or_else(|_| unreachable!()),
-                    ViewColumn::new("phone", 2, None, None).unwrap_or_else(|_| unreachable!()),
-                ],
-                default_sort: None,
-                filter_criteria: None,
-                is_default: false,
-            },
+    let updated = service
+        .update_runtime_record(
+            &bob,
+            "ticket",
+            created.record_id().as_str(),
+            json!({"title": "Printer jam"}),
         )
-        .await;
-    assert!(initial.is_ok());
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(updated.data()["created_by"], json!("alice"));
+    assert_eq!(updated.data()["created_at"], created.data()["created_at"]);
+    assert_eq!(updated.data()["modified_by"], json!("bob"));
+    assert_eq!(updated.data()["owner"], json!("alice"));
 
-    let reordered = service
-        .save_view(
-            &actor,
-            SaveViewInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "custom_view".to_owned(),
-                display_name: "Custom View".to_owned(),
-                view_type: ViewType::Grid,
-                columns: vec![
-                    ViewColumn::new("email", 0, None, None).unwrap_or_else(|_| unreachable!()),
-                    ViewColumn::new("name", 1, None, None).unwrap_or_else(|_| unreachable!()),
-                    ViewColumn::new("phone", 2, None, None).unwrap_or_else(|_| unreachable!()),
-                ],
-                default_sort: None,
-                filter_criteria: None,
-                is_default: false,
-            },
+    let client_write = service
+        .update_runtime_record(
+            &bob,
+            "ticket",
+            created.record_id().as_str(),
+            json!({"title": "Printer jam", "owner": "bob"}),
         )
         .await;
-    assert!(reordered.is_ok());
-    let reordered = reordered.unwrap_or_else(|_| unreachable!());
-    let reordered_columns: Vec<&str> = reordered
-        .columns()
-        .iter()
-        .map(|column| column.field_logical_name().as_str())
-        .collect();
-    assert_eq!(reordered_columns, vec!["email", "name", "phone"]);
+    assert!(matches!(
+        client_write,
+        Err(AppError::Validation(message)) if message == "system field 'owner' is read-only"
+    ));
 
-    let undone = service
-        .save_view(
-            &actor,
-            SaveViewInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "custom_view".to_owned(),
-                display_name: "Custom View".to_owned(),
-                view_type: ViewType::Grid,
-                columns: vec![
-                    ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!()),
-                    ViewColumn::new("email", 1, None, None).unwrap_or_else(|_| unreachable!()),
-                    ViewColumn::new("phone", 2, None, None).unwrap_or_else(|_| unreachable!()),
-                ],
-                default_sort: None,
-                filter_criteria: None,
-                is_default: false,
+    let owned = service
+        .query_runtime_records(
+            &alice,
+            "ticket",
+            RuntimeRecordQuery {
+                limit: 10,
+                offset: 0,
+                logical_mode: RuntimeRecordLogicalMode::And,
+                where_clause: None,
+                filters: vec![RuntimeRecordFilter {
+                    scope_alias: None,
+                    field_logical_name: "modified_by".to_owned(),
+                    operator: RuntimeRecordOperator::Eq,
+                    field_type: FieldType::Text,
+                    field_value: json!("bob"),
+                }],
+                links: Vec::new(),
+                sort: vec![RuntimeRecordSort {
+                    scope_alias: None,
+                    field_logical_name: "created_at".to_owned(),
+                    field_type: FieldType::DateTime,
+                    direction: RuntimeRecordSortDirection::Desc,
+                }],
+                owner_subject: None,
+                include_inactive: false,
             },
         )
-        .await;
-    assert!(undone.is_ok());
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(owned.len(), 1);
+}
 
-    let redone = service
-        .save_view(
-            &actor,
-            SaveViewInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "custom_view".to_owned(),
-                display_name: "Custom View".to_owned(),
-                view_type: ViewType::Grid,
-                columns: reordered.columns().to_vec(),
-                default_sort: None,
-                filter_criteria: None,
-                is_default: false,
-            },
-        )
-        .await;
-    assert!(redone.is_ok());
-    let redone = redone.unwrap_or_else(|_| unreachable!());
-    let redone_columns: Vec<&str> = redone
-        .columns()
-        .iter()
-        .map(|column| column.field_logical_name().as_str())
-        .collect();
-    assert_eq!(redone_columns, vec!["email", "name", "phone"]);
+#[tokio::test]
+async fn published_schema_exports_render_json_schema_and_typescript() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataEntityRead,
+            Permission::MetadataFieldWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        register_publish_entity_with_text_fields(&service, &alice, "account", "Account", &["name"])
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .register_entity(&alice, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_option_set(
+                &alice,
+                SaveOptionSetInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "status".to_owned(),
+                    display_name: "Status".to_owned(),
+                    options: vec![
+                        OptionSetItem::new(1, "Open", None, 0).unwrap_or_else(|_| unreachable!()),
+                        OptionSetItem::new(2, "Closed", None, 1).unwrap_or_else(|_| unreachable!()),
+                    ],
+                },
+            )
+            .await
+            .is_ok()
+    );
+    for (logical_name, field_type, is_required, relation_target_entity, option_set) in [
+        ("name", FieldType::Text, true, None, None),
+        (
+            "account_id",
+            FieldType::Relation,
+            false,
+            Some("account".to_owned()),
+            None,
+        ),
+        (
+            "status",
+            FieldType::Choice,
+            false,
+            None,
+            Some("status".to_owned()),
+        ),
+        ("birthday", FieldType::Date, false, None, None),
+    ] {
+        assert!(
+            service
+                .save_field(
+                    &alice,
+                    SaveFieldInput {
+                        entity_logical_name: "contact".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type,
+                        is_required,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity,
+                        option_set_logical_name: option_set,
+                        classification: (logical_name == "birthday")
+                            .then_some(FieldClassification::Pii),
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
 
-    let listed = service
-        .find_view(&actor, "contact", "custom_view")
+    let unpublished = service
+        .published_entity_json_schema(&alice, "contact")
+        .await;
+    assert!(matches!(unpublished, Err(AppError::NotFound(_))));
+    assert!(service.publish_entity(&alice, "contact").await.is_ok());
+
+    let document = service
+        .published_entity_json_schema(&alice, "contact")
         .await
-        .unwrap_or(None);
-    assert!(listed.is_some());
-    let listed = listed.unwrap_or_else(|| unreachable!());
-    let listed_columns: Vec<&str> = listed
-        .columns()
-        .iter()
-        .map(|column| column.field_logical_name().as_str())
-        .collect();
-    assert_eq!(listed_columns, vec!["email", "name", "phone"]);
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(document["type"], json!("object"));
+    assert_eq!(document["required"], json!(["name"]));
+    assert_eq!(document["additionalProperties"], json!(false));
+    let properties = &document["properties"];
+    assert_eq!(properties["name"]["type"], json!("string"));
+    assert_eq!(properties["account_id"]["format"], json!("uuid"));
+    assert_eq!(
+        properties["account_id"]["x-qryvanta-relation"],
+        json!("account")
+    );
+    assert_eq!(properties["status"]["type"], json!("integer"));
+    assert_eq!(properties["status"]["enum"], json!([1, 2]));
+    assert_eq!(
+        properties["status"]["x-qryvanta-option-labels"]["2"],
+        json!("Closed")
+    );
+    assert_eq!(properties["birthday"]["format"], json!("date"));
+    assert_eq!(
+        properties["birthday"]["x-qryvanta-classification"],
+        json!("pii")
+    );
+    assert!(
+        properties["name"]
+            .get("x-qryvanta-classification")
+            .is_none()
+    );
+
+    let typescript = service
+        .published_entities_typescript(&alice)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    for expected in [
+        "export type ContactStatus = 1 | 2;",
+        "  2: \"Closed\",",
+        "export interface ContactRecord {",
+        "  name: string;",
+        "  account_id?: RecordId<\"account\">;",
+        "  status?: ContactStatus;",
+        "  account: AccountRecord;",
+        "  contact: ContactRecord;",
+    ] {
+        assert!(typescript.contains(expected), "missing `{expected}`");
+    }
 }
 
 #[tokio::test]
-async fn portability_export_import_round_trip_remaps_relations_deterministically() {
-    let source_tenant_id = TenantId::new();
-    let target_tenant_id = TenantId::new();
-    let subject = "porter";
-    let permissions = vec![
-        Permission::MetadataEntityCreate,
-        Permission::MetadataEntityRead,
-        Permission::MetadataFieldRead,
-        Permission::MetadataFieldWrite,
-        Permission::RuntimeRecordRead,
-        Permission::RuntimeRecordWrite,
-    ];
-
-    let source_grants =
-        HashMap::from([((source_tenant_id, subject.to_owned()), permissions.clone())]);
-    let target_grants = HashMap::from([((target_tenant_id, subject.to_owned()), permissions)]);
+async fn relation_lookup_search_applies_configured_filter_and_labels() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldRead,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+            Permission::RuntimeRecordWrite,
+        ],
+    )]);
+    let (service, audit_repository) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        register_publish_entity_with_text_fields(
+            &service,
+            &alice,
+            "account",
+            "Account",
+            &["name", "status"]
+        )
+        .await
+        .is_ok()
+    );
+    assert!(
+        service
+            .register_entity(&alice, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    for (logical_name, field_type, relation_target_entity) in [
+        ("name", FieldType::Text, None),
+        (
+            "account_id",
+            FieldType::Relation,
+            Some("account".to_owned()),
+        ),
+    ] {
+        assert!(
+            service
+                .save_field(
+                    &alice,
+                    SaveFieldInput {
+                        entity_logical_name: "contact".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type,
+                        is_required: false,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(service.publish_entity(&alice, "contact").await.is_ok());
 
-    let (source_service, _) = build_service(source_grants);
-    let (target_service, _) = build_service(target_grants);
+    let lookup_input =
+        |field_logical_name: &str, search_field: &str| SaveRelationLookupConfigInput {
+            entity_logical_name: "contact".to_owned(),
+            field_logical_name: field_logical_name.to_owned(),
+            search_field_logical_names: vec![search_field.to_owned()],
+            filter: Some(
+                ViewFilterGroup::new(
+                    LogicalMode::And,
+                    vec![
+                        ViewFilterCondition::new("status", FilterOperator::Eq, json!("active"))
+                            .unwrap_or_else(|_| unreachable!()),
+                    ],
+                )
+                .unwrap_or_else(|_| unreachable!()),
+            ),
+        };
+    assert!(matches!(
+        service
+            .save_relation_lookup_config(&alice, lookup_input("name", "name"))
+            .await,
+        Err(AppError::Validation(_))
+    ));
+    assert!(matches!(
+        service
+            .save_relation_lookup_config(&alice, lookup_input("account_id", "missing"))
+            .await,
+        Err(AppError::Validation(_))
+    ));
+    let config = service
+        .save_relation_lookup_config(&alice, lookup_input("account_id", "name"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(config.target_entity_logical_name, "account");
+    assert_eq!(
+        audit_repository
+            .events
+            .lock()
+            .await
+            .last()
+            .map(|event| event.action),
+        Some(AuditAction::MetadataRelationLookupSaved)
+    );
 
-    let source_actor = actor(source_tenant_id, subject);
-    let target_actor = actor(target_tenant_id, subject);
+    for (name, status) in [("Contoso", "active"), ("Fabrikam", "dormant")] {
+        assert!(
+            service
+                .create_runtime_record(&alice, "account", json!({"name": name, "status": status}))
+                .await
+                .is_ok()
+        );
+    }
 
-    source_service
-        .register_entity(&source_actor, "account", "Account")
+    let matches = service
+        .search_relation_lookup(&alice, "contact", "account_id", "", Some(500))
         .await
         .unwrap_or_else(|_| unreachable!());
-    source_service
-        .save_field(
-            &source_actor,
-            SaveFieldInput {
-                entity_logical_name: "account".to_owned(),
-                logical_name: "name".to_owned(),
-                display_name: "Name".to_owned(),
-                field_type: FieldType::Text,
-                is_required: true,
-                is_unique: false,
-                default_value: None,
-                calculation_expression: None,
-                relation_target_entity: None,
-                option_set_logical_name: None,
-            },
+    assert_eq!(
+        matches
+            .iter()
+            .map(|lookup_match| lookup_match.label.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Contoso"]
+    );
+    assert!(matches!(
+        service
+            .search_relation_lookup(&alice, "contact", "name", "Con", None)
+            .await,
+        Err(AppError::Validation(_))
+    ));
+
+    assert!(
+        service
+            .delete_relation_lookup_config(&alice, "contact", "account_id")
+            .await
+            .is_ok()
+    );
+    assert!(matches!(
+        service
+            .delete_relation_lookup_config(&alice, "contact", "account_id")
+            .await,
+        Err(AppError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn duplicate_rules_warn_or_block_runtime_writes() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataFieldRead,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordRead,
+            Permission::RuntimeRecordWrite,
+        ],
+    )]);
+    let (service, audit_repository) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        register_publish_entity_with_text_fields(
+            &service,
+            &alice,
+            "account",
+            "Account",
+            &["name", "email"]
         )
         .await
-        .unwrap_or_else(|_| unreachable!());
-    source_service
-        .publish_entity(&source_actor, "account")
-        .await
-        .unwrap_or_else(|_| unreachable!());
+        .is_ok()
+    );
 
-    source_service
-        .register_entity(&source_actor, "contact", "Contact")
-        .await
-        .unwrap_or_else(|_| unreachable!());
-    source_service
-        .save_field(
-            &source_actor,
-            SaveFieldInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "name".to_owned(),
-                display_name: "Name".to_owned(),
-                field_type: FieldType::Text,
-                is_required: true,
-                is_unique: false,
-                default_value: None,
-                calculation_expression: None,
-                relation_target_entity: None,
-                option_set_logical_name: None,
-            },
+    let rule_input = |logical_name: &str,
+                      field_logical_name: &str,
+                      match_mode: DuplicateMatchMode,
+                      action: DuplicateRuleAction| {
+        SaveDuplicateDetectionRuleInput {
+            entity_logical_name: "account".to_owned(),
+            logical_name: logical_name.to_owned(),
+            display_name: logical_name.to_owned(),
+            match_fields: vec![DuplicateMatchField {
+                field_logical_name: field_logical_name.to_owned(),
+                match_mode,
+            }],
+            action,
+            is_active: true,
+        }
+    };
+    assert!(matches!(
+        service
+            .save_duplicate_detection_rule(
+                &alice,
+                rule_input(
+                    "missing",
+                    "phone",
+                    DuplicateMatchMode::Exact,
+                    DuplicateRuleAction::Warn
+                ),
+            )
+            .await,
+        Err(AppError::Validation(_))
+    ));
+    for (logical_name, field_logical_name, match_mode, action) in [
+        (
+            "same_name",
+            "name",
+            DuplicateMatchMode::Fuzzy,
+            DuplicateRuleAction::Warn,
+        ),
+        (
+            "same_email",
+            "email",
+            DuplicateMatchMode::Exact,
+            DuplicateRuleAction::Block,
+        ),
+    ] {
+        assert!(
+            service
+                .save_duplicate_detection_rule(
+                    &alice,
+                    rule_input(logical_name, field_logical_name, match_mode, action),
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert_eq!(
+        audit_repository
+            .events
+            .lock()
+            .await
+            .last()
+            .map(|event| event.action),
+        Some(AuditAction::MetadataDuplicateRuleSaved)
+    );
+
+    let existing = service
+        .create_runtime_record(
+            &alice,
+            "account",
+            json!({"name": "Acme Corporation", "email": "sales@acme.test"}),
         )
         .await
         .unwrap_or_else(|_| unreachable!());
-    source_service
-        .save_field(
-            &source_actor,
-            SaveFieldInput {
-                entity_logical_name: "contact".to_owned(),
-                logical_name: "account_id".to_owned(),
-                display_name: "Account".to_owned(),
-                field_type: FieldType::Relation,
-                is_required: true,
-                is_unique: false,
-                default_value: None,
-                calculation_expression: None,
-                relation_target_entity: Some("account".to_owned()),
-                option_set_logical_name: None,
-            },
+
+    let warning = service
+        .create_runtime_record(
+            &alice,
+            "account",
+            json!({"name": "ACME-Corporation", "email": "info@acme.test"}),
+        )
+        .await;
+    let Err(AppError::Conflict(detail)) = warning else {
+        unreachable!();
+    };
+    let conflict = DuplicateRecordsConflict::from_error_detail(detail.as_str())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(conflict.action, DuplicateRuleAction::Warn);
+    assert_eq!(conflict.candidates.len(), 1);
+    assert_eq!(
+        conflict.candidates[0].record_id,
+        existing.record_id().as_str()
+    );
+    assert_eq!(conflict.candidates[0].rule_logical_name, "same_name");
+
+    let confirmed = service
+        .create_runtime_record_with_duplicate_override(
+            &alice,
+            "account",
+            json!({"name": "ACME-Corporation", "email": "info@acme.test"}),
+            true,
         )
         .await
         .unwrap_or_else(|_| unreachable!());
-    source_service
-        .publish_entity_with_allowed_unpublished_entities(
-            &source_actor,
-            "contact",
-            &["account".to_owned(), "contact".to_owned()],
-        )
+
+    for ignore_duplicate_warnings in [false, true] {
+        let blocked = service
+            .create_runtime_record_with_duplicate_override(
+                &alice,
+                "account",
+                json!({"name": "Initech", "email": "sales@acme.test"}),
+                ignore_duplicate_warnings,
+            )
+            .await;
+        let Err(AppError::Conflict(detail)) = blocked else {
+            unreachable!();
+        };
+        assert_eq!(
+            DuplicateRecordsConflict::from_error_detail(detail.as_str())
+                .map(|conflict| conflict.action),
+            Some(DuplicateRuleAction::Block)
+        );
+    }
+
+    assert!(matches!(
+        service
+            .update_runtime_record_with_duplicate_override(
+                &alice,
+                "account",
+                confirmed.record_id().as_str(),
+                json!({"name": "ACME-Corporation", "email": "sales@acme.test"}),
+                None,
+                true,
+            )
+            .await,
+        Err(AppError::Conflict(_))
+    ));
+    assert!(
+        service
+            .update_runtime_record_with_duplicate_override(
+                &alice,
+                "account",
+                confirmed.record_id().as_str(),
+                json!({"name": "ACME-Corporation", "email": "billing@acme.test"}),
+                None,
+                true,
+            )
+            .await
+            .is_ok()
+    );
+
+    assert!(
+        service
+            .delete_duplicate_detection_rule(&alice, "account", "same_email")
+            .await
+            .is_ok()
+    );
+    assert!(matches!(
+        service
+            .delete_duplicate_detection_rule(&alice, "account", "same_email")
+            .await,
+        Err(AppError::NotFound(_))
+    ));
+    let rules = service
+        .list_duplicate_detection_rules(&alice, "account")
         .await
         .unwrap_or_else(|_| unreachable!());
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].logical_name(), "same_name");
+}
+
+#[tokio::test]
+async fn entity_lifecycle_blocks_writes_and_gates_delete_on_dependencies() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataEntityRead,
+            Permission::MetadataFieldWrite,
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
+        ],
+    )]);
+    let (service, audit_repository) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        register_publish_entity_with_text_fields(&service, &alice, "account", "Account", &["name"])
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .register_entity(&alice, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &alice,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "account_id".to_owned(),
+                    display_name: "Account".to_owned(),
+                    field_type: FieldType::Relation,
+                    is_required: false,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: None,
+                    relation_target_entity: Some("account".to_owned()),
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
 
-    let account_record = source_service
-        .create_runtime_record(&source_actor, "account", json!({"name": "ACME"}))
-        .await
-        .unwrap_or_else(|_| unreachable!());
-    let source_account_record_id = account_record.record_id().as_str().to_owned();
+    assert!(matches!(
+        service.delete_entity(&alice, "account").await,
+        Err(AppError::Conflict(message)) if message.contains("must be deactivated")
+    ));
 
-    let contact_record = source_service
-        .create_runtime_record(
-            &source_actor,
-            "contact",
-            json!({"name": "Jane", "account_id": source_account_record_id}),
-        )
+    let deactivated = service
+        .deactivate_entity(&alice, "account")
         .await
         .unwrap_or_else(|_| unreachable!());
-    let source_contact_record_id = contact_record.record_id().as_str().to_owned();
+    assert!(!deactivated.is_active());
+    assert!(matches!(
+        service
+            .create_runtime_record(&alice, "account", json!({"name": "Acme"}))
+            .await,
+        Err(AppError::Conflict(message)) if message.contains("is deactivated")
+    ));
 
-    let bundle = source_service
-        .export_workspace_bundle(&source_actor, ExportWorkspaceBundleOptions::default())
+    let report = service
+        .analyze_entity_dependencies(&alice, "account")
         .await
         .unwrap_or_else(|_| unreachable!());
+    assert!(!report.can_delete());
+    let blocking = report.blocking_dependencies().collect::<Vec<_>>();
+    assert_eq!(blocking.len(), 1);
+    assert_eq!(blocking[0].kind, EntityDependencyKind::Relation);
+    assert_eq!(blocking[0].component_key, "contact.account_id");
+    assert!(matches!(
+        service.delete_entity(&alice, "account").await,
+        Err(AppError::Conflict(message)) if message.contains("relation 'contact.account_id'")
+    ));
 
-    let dry_run = target_service
-        .import_workspace_bundle(
-            &target_actor,
-            bundle.clone(),
-            ImportWorkspaceBundleOptions {
-                dry_run: true,
-                remap_record_ids: true,
-                ..ImportWorkspaceBundleOptions::default()
-            },
-        )
+    assert!(
+        service
+            .delete_field(&alice, "contact", "account_id")
+            .await
+            .is_ok()
+    );
+    assert!(service.delete_entity(&alice, "account").await.is_ok());
+    assert!(matches!(
+        service.analyze_entity_dependencies(&alice, "account").await,
+        Err(AppError::NotFound(_))
+    ));
+
+    let actions = audit_repository
+        .events
+        .lock()
         .await
-        .unwrap_or_else(|_| unreachable!());
-
-    assert!(dry_run.dry_run);
-    assert_eq!(dry_run.entities_processed, 2);
-    assert_eq!(dry_run.runtime_records_discovered, 2);
-    assert_eq!(dry_run.runtime_records_remapped, 2);
+        .iter()
+        .map(|event| event.action)
+        .collect::<Vec<_>>();
+    assert!(actions.contains(&AuditAction::MetadataEntityDeactivated));
+    assert!(actions.contains(&AuditAction::MetadataEntityDeleted));
+}
 
-    target_service
-        .import_workspace_bundle(
-            &target_actor,
-            bundle,
-            ImportWorkspaceBundleOptions {
-                remap_record_ids: true,
-                ..ImportWorkspaceBundleOptions::default()
-            },
+#[tokio::test]
+async fn field_impact_ranks_rules_calculations_and_view_references() {
+    let tenant_id = TenantId::new();
+    let grants = HashMap::from([(
+        (tenant_id, "alice".to_owned()),
+        vec![
+            Permission::MetadataEntityCreate,
+            Permission::MetadataEntityRead,
+            Permission::MetadataFieldRead,
+            Permission::MetadataFieldWrite,
+        ],
+    )]);
+    let (service, _) = build_service(grants);
+    let alice = actor(tenant_id, "alice");
+    assert!(
+        register_publish_entity_with_text_fields(
+            &service,
+            &alice,
+            "contact",
+            "Contact",
+            &["name", "nickname"]
         )
         .await
-        .unwrap_or_else(|_| unreachable!());
+        .is_ok()
+    );
+    assert!(
+        service
+            .save_field(
+                &alice,
+                SaveFieldInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "label".to_owned(),
+                    display_name: "Label".to_owned(),
+                    field_type: FieldType::Text,
+                    is_required: false,
+                    is_unique: false,
+                    default_value: None,
+                    calculation_expression: Some("concat(name, \" \", nickname)".to_owned()),
+                    relation_target_entity: None,
+                    option_set_logical_name: None,
+                    classification: None,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_view(
+                &alice,
+                SaveViewInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "nicknamed".to_owned(),
+                    display_name: "Nicknamed".to_owned(),
+                    view_type: ViewType::Grid,
+                    columns: vec![
+                        ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!()),
+                    ],
+                    default_sort: Some(
+                        ViewSort::new("nickname", SortDirection::Asc)
+                            .unwrap_or_else(|_| unreachable!()),
+                    ),
+                    filter_criteria: Some(
+                        ViewFilterGroup::new(
+                            LogicalMode::And,
+                            vec![
+                                ViewFilterCondition::new(
+                                    "nickname",
+                                    FilterOperator::Neq,
+                                    json!(""),
+                                )
+                                .unwrap_or_else(|_| unreachable!()),
+                            ],
+                        )
+                        .unwrap_or_else(|_| unreachable!()),
+                    ),
+                    is_default: false,
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(
+        service
+            .save_business_rule(
+                &alice,
+                SaveBusinessRuleInput {
+                    entity_logical_name: "contact".to_owned(),
+                    logical_name: "nickname_guard".to_owned(),
+                    display_name: "Nickname Guard".to_owned(),
+                    scope: BusinessRuleScope::Entity,
+                    form_logical_name: None,
+                    conditions: vec![
+                        BusinessRuleCondition::new(
+                            "nickname",
+                            BusinessRuleOperator::Eq,
+                            json!("anon"),
+                        )
+                        .unwrap_or_else(|_| unreachable!()),
+                    ],
+                    actions: vec![
+                        BusinessRuleAction::new(
+                            BusinessRuleActionType::ShowError,
+                            None,
+                            None,
+                            Some("Nickname is reserved".to_owned()),
+                        )
+                        .unwrap_or_else(|_| unreachable!()),
+                    ],
+                    is_active: false,
+                },
+            )
+            .await
+            .is_ok()
+    );
 
-    let imported_accounts = target_service
-        .list_runtime_records(
-            &target_actor,
-            "account",
-            RecordListQuery {
-                limit: 10,
-                offset: 0,
-                owner_subject: None,
-            },
-        )
+    let report = service
+        .analyze_field_impact(&alice, "contact", "nickname")
         .await
         .unwrap_or_else(|_| unreachable!());
-    assert_eq!(imported_accounts.len(), 1);
+    let impacts = report
+        .impacts
+        .iter()
+        .map(|impact| {
+            (
+                impact.severity,
+                impact.kind,
+                impact.component_key.as_str(),
+                impact.detail.as_str(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(report.highest_severity(), Some(FieldImpactSeverity::High));
+    assert!(impacts.contains(&(
+        FieldImpactSeverity::High,
+        FieldImpactKind::View,
+        "contact.nicknamed",
+        "filters on the field"
+    )));
+    assert!(impacts.contains(&(
+        FieldImpactSeverity::Medium,
+        FieldImpactKind::View,
+        "contact.nicknamed",
+        "sorts by the field"
+    )));
+    assert!(impacts.contains(&(
+        FieldImpactSeverity::High,
+        FieldImpactKind::CalculatedField,
+        "contact.label",
+        "calculation 'concat(name, \" \", nickname)' reads the field"
+    )));
+    assert!(impacts.contains(&(
+        FieldImpactSeverity::Low,
+        FieldImpactKind::BusinessRule,
+        "contact.nickname_guard",
+        "inactive rule conditions on the field"
+    )));
+    assert!(
+        report
+            .impacts
+            .windows(2)
+            .all(|pair| pair[0].severity <= pair[1].severity)
+    );
 
-    let imported_contacts = target_service
-        .list_runtime_records(
-            &target_actor,
-            "contact",
-            RecordListQuery {
-                limit: 10,
-                offset: 0,
-                owner_subject: None,
-            },
-        )
+    let unreferenced = service
+        .analyze_field_impact(&alice, "contact", "label")
         .await
         .unwrap_or_else(|_| unreachable!());
-    assert_eq!(imported_contacts.len(), 1);
-
-    let imported_account_id = imported_accounts[0].record_id().as_str().to_owned();
-    let imported_contact_data = imported_contacts[0].data();
-
-    assert_ne!(source_account_record_id, imported_account_id);
-    assert_ne!(
-        source_contact_record_id,
-        imported_contacts[0].record_id().as_str()
-    );
-    assert_eq!(
-        imported_contact_data
-            .get("account_id")
-            .and_then(Value::as_str)
-            .unwrap_or_default(),
-        imported_account_id
-    );
+    assert!(unreferenced.impacts.is_empty());
+    assert_eq!(unreferenced.highest_severity(), None);
+    assert!(matches!(
+        service
+            .analyze_field_impact(&alice, "contact", "missing")
+            .await,
+        Err(AppError::NotFound(_))
+    ));
 }
 
 #[tokio::test]
-async fn portability_import_rejects_checksum_mismatch() {
+async fn restore_runtime_record_version_reverts_later_changes_as_new_version() {
     let tenant_id = TenantId::new();
-    let subject = "porter";
     let grants = HashMap::from([(
-        (tenant_id, subject.to_owned()),
+        (tenant_id, "alice".to_owned()),
         vec![
             Permission::MetadataEntityCreate,
-            Permission::MetadataEntityRead,
-            Permission::MetadataFieldRead,
             Permission::MetadataFieldWrite,
             Permission::RuntimeRecordRead,
             Permission::RuntimeRecordWrite,
         ],
     )]);
+    let (service, audit_repository) =
+        build_service_with_runtime_field_grants(grants, HashMap::new());
+    let alice = actor(tenant_id, "alice");
 
-    let (service, _) = build_service(grants);
-    let actor = actor(tenant_id, subject);
+    assert!(
+        service
+            .register_entity(&alice, "contact", "Contact")
+            .await
+            .is_ok()
+    );
+    for logical_name in ["name", "phone"] {
+        assert!(
+            service
+                .save_field(
+                    &alice,
+                    SaveFieldInput {
+                        entity_logical_name: "contact".to_owned(),
+                        logical_name: logical_name.to_owned(),
+                        display_name: logical_name.to_owned(),
+                        field_type: FieldType::Text,
+                        is_required: false,
+                        is_unique: false,
+                        default_value: None,
+                        calculation_expression: None,
+                        relation_target_entity: None,
+                        option_set_logical_name: None,
+                        classification: None,
+                    },
+                )
+                .await
+                .is_ok()
+        );
+    }
+    assert!(service.publish_entity(&alice, "contact").await.is_ok());
+    assert!(
+        service
+            .save_entity_history_policy(
+                &alice,
+                SaveEntityHistoryPolicyInput {
+                    entity_logical_name: "contact".to_owned(),
+                    retention_days: 30,
+                },
+            )
+            .await
+            .is_ok()
+    );
 
-    service
-        .register_entity(&actor, "account", "Account")
+    let created = service
+        .create_runtime_record(&alice, "contact", json!({"name": "Ada"}))
         .await
         .unwrap_or_else(|_| unreachable!());
-    service
-        .save_field(
-            &actor,
-            SaveFieldInput {
-                entity_logical_name: "account".to_owned(),
-                logical_name: "name".to_owned(),
-                display_name: "Name".to_owned(),
-                field_type: FieldType::Text,
-                is_required: true,
-                is_unique: false,
-                default_value: None,
-                calculation_expression: None,
-                relation_target_entity: None,
-                option_set_logical_name: None,
-            },
-        )
+    let record_id = created.record_id().as_str().to_owned();
+    for data in [
+        json!({"name": "Ada Lovelace", "phone": "555"}),
+        json!({"name": "Grace"}),
+    ] {
+        assert!(
+            service
+                .update_runtime_record(&alice, "contact", record_id.as_str(), data)
+                .await
+                .is_ok()
+        );
+    }
+
+    let current = service
+        .restore_runtime_record_version(&alice, "contact", record_id.as_str(), 3)
+        .await;
+    assert!(matches!(current, Err(AppError::Validation(_))));
+    let before_creation = service
+        .restore_runtime_record_version(&alice, "contact", record_id.as_str(), 0)
+        .await;
+    assert!(matches!(before_creation, Err(AppError::Validation(_))));
+
+    let restored = service
+        .restore_runtime_record_version(&alice, "contact", record_id.as_str(), 2)
         .await
         .unwrap_or_else(|_| unreachable!());
+    assert_eq!(restored.version(), 4);
+    assert_eq!(restored.data().get("name"), Some(&json!("Ada Lovelace")));
+    assert_eq!(restored.data().get("phone"), Some(&json!("555")));
 
-    let mut bundle = service
-        .export_workspace_bundle(
-            &actor,
-            ExportWorkspaceBundleOptions {
-                include_metadata: true,
-                include_runtime_data: false,
-            },
-        )
+    let history = service
+        .runtime_record_history(&alice, "contact", record_id.as_str())
         .await
         .unwrap_or_else(|_| unreachable!());
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[2].record_version, 4);
+    assert_eq!(history[2].changes.len(), 2);
 
-    bundle.payload_sha256 = "bad-checksum".to_owned();
-
-    let result = service
-        .import_workspace_bundle(
-            &actor,
-            bundle,
-            ImportWorkspaceBundleOptions {
-                dry_run: true,
-                import_metadata: true,
-                import_runtime_data: false,
-                remap_record_ids: false,
-            },
-        )
+    let unchanged = service
+        .restore_runtime_record_version(&alice, "contact", record_id.as_str(), 2)
         .await;
+    assert!(matches!(unchanged, Err(AppError::Validation(_))));
 
-    assert!(result.is_err());
-    assert!(matches!(result, Err(AppError::Validation(_))));
+    let as_created = service
+        .restore_runtime_record_version(&alice, "contact", record_id.as_str(), 1)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(as_created.version(), 5);
+    assert_eq!(as_created.data().get("name"), Some(&json!("Ada")));
+    assert_eq!(as_created.data().get("phone"), None);
+
+    let events = audit_repository.events.lock().await;
+    let restored_events = events
+        .iter()
+        .filter(|event| event.action == AuditAction::RuntimeRecordRestored)
+        .collect::<Vec<_>>();
+    assert_eq!(restored_events.len(), 2);
+    assert_eq!(restored_events[0].resource_id, record_id);
+    assert!(
+        restored_events[0]
+            .detail
+            .as_deref()
+            .is_some_and(|detail| detail.contains("to version 2 as version 4"))
+    );
 }
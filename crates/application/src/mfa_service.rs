@@ -9,8 +9,9 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
+use crate::TenantEncryptionService;
 use crate::user_service::{PasswordHasher, UserRepository};
-use qryvanta_core::AppResult;
+use qryvanta_core::{AppResult, TenantId};
 
 /// TOTP enrollment data returned to the user for QR code display.
 #[derive(Debug, Clone)]
@@ -50,6 +51,7 @@ pub struct MfaService {
     password_hasher: Arc<dyn PasswordHasher>,
     totp_provider: Arc<dyn TotpProvider>,
     secret_encryptor: Arc<dyn SecretEncryptor>,
+    tenant_encryption: Option<TenantEncryptionService>,
 }
 
 impl MfaService {
@@ -66,6 +68,36 @@ impl MfaService {
             password_hasher,
             totp_provider,
             secret_encryptor,
+            tenant_encryption: None,
+        }
+    }
+
+    /// Encrypts new TOTP secrets with the user's tenant-supplied key when registered.
+    #[must_use]
+    pub fn with_tenant_encryption(mut self, tenant_encryption: TenantEncryptionService) -> Self {
+        self.tenant_encryption = Some(tenant_encryption);
+        self
+    }
+
+    async fn encrypt_secret(
+        &self,
+        tenant_id: Option<TenantId>,
+        plaintext: &[u8],
+    ) -> AppResult<Vec<u8>> {
+        match (&self.tenant_encryption, tenant_id) {
+            (Some(tenant_encryption), Some(tenant_id)) => {
+                tenant_encryption
+                    .encrypt_for_tenant(tenant_id, plaintext)
+                    .await
+            }
+            _ => self.secret_encryptor.encrypt(plaintext),
+        }
+    }
+
+    async fn decrypt_secret(&self, ciphertext: &[u8]) -> AppResult<Vec<u8>> {
+        match &self.tenant_encryption {
+            Some(tenant_encryption) => tenant_encryption.decrypt(ciphertext).await,
+            None => self.secret_encryptor.decrypt(ciphertext),
         }
     }
 }
@@ -339,6 +371,7 @@ mod tests {
             email_verified: true,
             password_hash: Some("password".to_owned()),
             totp_enabled: false,
+            passkeys_enrolled: false,
             totp_secret_enc: None,
             recovery_codes_hash: None,
             totp_pending_secret_enc: None,
@@ -396,4 +429,32 @@ mod tests {
         let result = service.verify_totp(user_id, "123456").await;
         assert!(matches!(result, Err(AppError::Validation(_))));
     }
+
+    #[tokio::test]
+    async fn disable_totp_keeps_the_last_second_factor() {
+        let mut user = sample_user();
+        user.totp_enabled = true;
+        let repository = FakeUserRepository::with_user(user);
+        let user_id = repository.snapshot().id;
+        let service = build_service(Arc::new(repository.clone()));
+
+        let result = service.disable_totp(user_id, "password").await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+        assert!(repository.snapshot().totp_enabled);
+
+        repository
+            .state
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .user
+            .as_mut()
+            .unwrap_or_else(|| unreachable!())
+            .passkeys_enrolled = true;
+
+        service
+            .disable_totp(user_id, "password")
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert!(!repository.snapshot().totp_enabled);
+    }
 }
@@ -25,7 +25,9 @@ impl MfaService {
         let (secret_bytes, secret_base32, otpauth_uri) =
             self.totp_provider.generate_secret(&user.email)?;
 
-        let encrypted_secret = self.secret_encryptor.encrypt(&secret_bytes)?;
+        let encrypted_secret = self
+            .encrypt_secret(user.default_tenant_id, &secret_bytes)
+            .await?;
         let recovery_codes = generate_recovery_codes();
         let hashed_codes = hash_recovery_codes(&recovery_codes);
 
@@ -54,7 +56,7 @@ impl MfaService {
             ));
         };
 
-        let secret_bytes = self.secret_encryptor.decrypt(encrypted_secret)?;
+        let secret_bytes = self.decrypt_secret(encrypted_secret).await?;
         let valid = self.totp_provider.verify_code(&secret_bytes, code)?;
 
         if !valid {
@@ -6,6 +6,9 @@ use super::*;
 
 impl MfaService {
     /// Disables TOTP for a user. Requires password re-authentication.
+    ///
+    /// Accounts must keep at least one second factor, so TOTP can only be
+    /// disabled while a passkey is registered.
     pub async fn disable_totp(&self, user_id: UserId, password: &str) -> AppResult<()> {
         let user = self
             .user_repository
@@ -27,6 +30,13 @@ impl MfaService {
             return Err(AppError::Unauthorized("incorrect password".to_owned()));
         }
 
+        if !user.passkeys_enrolled {
+            return Err(AppError::Conflict(
+                "disabling TOTP would leave the account without a second factor; register a passkey first"
+                    .to_owned(),
+            ));
+        }
+
         self.user_repository.disable_totp(user_id).await
     }
 
@@ -25,7 +25,7 @@ impl MfaService {
             ));
         };
 
-        let secret_bytes = self.secret_encryptor.decrypt(encrypted_secret)?;
+        let secret_bytes = self.decrypt_secret(encrypted_secret).await?;
         self.totp_provider.verify_code(&secret_bytes, code)
     }
 
@@ -0,0 +1,206 @@
+//! Latency budgets for long-running application operations.
+//!
+//! A budgeted operation runs inside an [`OperationDeadline`] scope. Postgres
+//! repositories read [`remaining_operation_budget`] when they open a tenant
+//! transaction and apply it as `statement_timeout`, so the database cancels a
+//! statement that outlives the budget and rolls its transaction back.
+//! Workflow dispatch checks the deadline between steps instead of abandoning
+//! a step midway.
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::Poll;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+use qryvanta_core::{AppError, AppResult};
+
+tokio::task_local! {
+    static CURRENT_DEADLINE: OperationDeadline;
+}
+
+/// Returns the budget left for the operation running on the current task.
+///
+/// Returns `None` outside a budgeted operation.
+#[must_use]
+pub fn remaining_operation_budget() -> Option<Duration> {
+    CURRENT_DEADLINE
+        .try_with(|deadline| deadline.remaining())
+        .ok()
+}
+
+/// Fails with `AppError::Timeout` once the current operation's budget is spent.
+pub fn ensure_operation_budget_remaining() -> AppResult<()> {
+    match CURRENT_DEADLINE.try_with(|deadline| *deadline) {
+        Ok(deadline) if deadline.remaining().is_zero() => Err(deadline.timeout_error()),
+        _ => Ok(()),
+    }
+}
+
+/// Application operations with a configurable latency budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimedOperation {
+    /// Runtime record lists, queries, and aggregates.
+    RuntimeQuery,
+    /// Workflow trigger dispatch and manual workflow execution.
+    WorkflowDispatch,
+    /// Entity publish.
+    Publish,
+}
+
+impl TimedOperation {
+    /// Returns a stable label for error messages and logs.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::RuntimeQuery => "runtime query",
+            Self::WorkflowDispatch => "workflow dispatch",
+            Self::Publish => "publish",
+        }
+    }
+}
+
+/// Per-operation latency budgets; `None` leaves an operation unbounded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OperationTimeouts {
+    /// Budget for runtime record lists, queries, and aggregates.
+    pub runtime_query: Option<Duration>,
+    /// Budget for workflow dispatch.
+    pub workflow_dispatch: Option<Duration>,
+    /// Budget for entity publish.
+    pub publish: Option<Duration>,
+}
+
+impl OperationTimeouts {
+    /// Returns the budget configured for an operation.
+    #[must_use]
+    pub fn budget_for(&self, operation: TimedOperation) -> Option<Duration> {
+        match operation {
+            TimedOperation::RuntimeQuery => self.runtime_query,
+            TimedOperation::WorkflowDispatch => self.workflow_dispatch,
+            TimedOperation::Publish => self.publish,
+        }
+    }
+}
+
+/// Deadline of one budgeted operation.
+#[derive(Debug, Clone, Copy)]
+pub struct OperationDeadline {
+    operation: TimedOperation,
+    budget: Duration,
+    expires_at: Instant,
+}
+
+impl OperationDeadline {
+    /// Starts the budget of an operation now.
+    #[must_use]
+    pub fn start(operation: TimedOperation, budget: Duration) -> Self {
+        Self {
+            operation,
+            budget,
+            expires_at: Instant::now() + budget,
+        }
+    }
+
+    /// Returns the budget left before the deadline.
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.expires_at.saturating_duration_since(Instant::now())
+    }
+
+    /// Runs a future with this deadline visible to
+    /// [`remaining_operation_budget`].
+    ///
+    /// An enclosing deadline that expires earlier stays in effect.
+    pub async fn scope<F>(self, future: F) -> F::Output
+    where
+        F: Future,
+    {
+        let deadline = match CURRENT_DEADLINE.try_with(|outer| *outer) {
+            Ok(outer) if outer.expires_at <= self.expires_at => outer,
+            _ => self,
+        };
+        CURRENT_DEADLINE.scope(deadline, future).await
+    }
+
+    fn timeout_error(&self) -> AppError {
+        AppError::Timeout(format!(
+            "{} exceeded its {} ms budget and was cancelled",
+            self.operation.as_str(),
+            self.budget.as_millis()
+        ))
+    }
+}
+
+/// Timer port used to enforce operation budgets.
+#[async_trait]
+pub trait OperationTimer: Send + Sync {
+    /// Completes once the duration has elapsed.
+    async fn sleep(&self, duration: Duration);
+}
+
+/// Enforces [`OperationTimeouts`] with an [`OperationTimer`].
+#[derive(Clone)]
+pub struct OperationDeadlines {
+    timer: Arc<dyn OperationTimer>,
+    timeouts: OperationTimeouts,
+}
+
+impl OperationDeadlines {
+    /// Creates deadlines from a timer and per-operation budgets.
+    #[must_use]
+    pub fn new(timer: Arc<dyn OperationTimer>, timeouts: OperationTimeouts) -> Self {
+        Self { timer, timeouts }
+    }
+
+    /// Starts the deadline of an operation, if it has a budget.
+    #[must_use]
+    pub fn start(&self, operation: TimedOperation) -> Option<OperationDeadline> {
+        self.timeouts
+            .budget_for(operation)
+            .map(|budget| OperationDeadline::start(operation, budget))
+    }
+
+    /// Runs a repository-bound operation within its budget.
+    ///
+    /// Repository calls inside the operation see the remaining budget and
+    /// apply it as a statement timeout. Errors returned after the deadline,
+    /// such as a statement the database cancelled, surface as
+    /// `AppError::Timeout`. The timer only backstops work that never reaches
+    /// the database.
+    pub async fn run<T, F>(&self, operation: TimedOperation, future: F) -> AppResult<T>
+    where
+        F: Future<Output = AppResult<T>>,
+    {
+        let Some(deadline) = self.start(operation) else {
+            return future.await;
+        };
+
+        let mut future = pin!(deadline.scope(future));
+        let mut backstop = self.timer.sleep(deadline.budget);
+        std::future::poll_fn(|context| {
+            if let Poll::Ready(result) = future.as_mut().poll(context) {
+                return Poll::Ready(match result {
+                    Err(error)
+                        if deadline.remaining().is_zero()
+                            && !matches!(error, AppError::Timeout(_)) =>
+                    {
+                        Err(deadline.timeout_error())
+                    }
+                    result => result,
+                });
+            }
+            if backstop.as_mut().poll(context).is_ready() {
+                return Poll::Ready(Err(deadline.timeout_error()));
+            }
+
+            Poll::Pending
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use qryvanta_core::{AppError, AppResult};
+
+use super::{
+    OperationDeadline, OperationDeadlines, OperationTimeouts, OperationTimer, TimedOperation,
+    ensure_operation_budget_remaining, remaining_operation_budget,
+};
+
+struct ElapsedTimer;
+
+#[async_trait]
+impl OperationTimer for ElapsedTimer {
+    async fn sleep(&self, _duration: Duration) {}
+}
+
+struct NeverTimer;
+
+#[async_trait]
+impl OperationTimer for NeverTimer {
+    async fn sleep(&self, _duration: Duration) {
+        std::future::pending::<()>().await;
+    }
+}
+
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+fn runtime_query_budget() -> OperationTimeouts {
+    OperationTimeouts {
+        runtime_query: Some(Duration::from_millis(250)),
+        ..OperationTimeouts::default()
+    }
+}
+
+#[tokio::test]
+async fn operations_over_budget_are_cancelled_with_timeout_error() {
+    let deadlines = OperationDeadlines::new(Arc::new(ElapsedTimer), runtime_query_budget());
+    let dropped = Arc::new(AtomicBool::new(false));
+    let guard = DropFlag(dropped.clone());
+
+    let result: AppResult<()> = deadlines
+        .run(TimedOperation::RuntimeQuery, async move {
+            let _guard = guard;
+            std::future::pending::<AppResult<()>>().await
+        })
+        .await;
+
+    let Err(AppError::Timeout(message)) = result else {
+        panic!("expected timeout, got {result:?}");
+    };
+    assert_eq!(
+        message,
+        "runtime query exceeded its 250 ms budget and was cancelled"
+    );
+    assert!(dropped.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn operations_within_budget_or_without_one_return_their_result() {
+    let deadlines = OperationDeadlines::new(Arc::new(NeverTimer), runtime_query_budget());
+    let within_budget = deadlines
+        .run(TimedOperation::RuntimeQuery, async { Ok(7) })
+        .await;
+    assert_eq!(within_budget.unwrap_or_else(|_| unreachable!()), 7);
+
+    let unbudgeted = OperationDeadlines::new(Arc::new(ElapsedTimer), runtime_query_budget());
+    let publish = unbudgeted
+        .run(TimedOperation::Publish, async {
+            Err::<(), _>(AppError::Conflict("draft changed".to_owned()))
+        })
+        .await;
+    assert!(matches!(publish, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn budgeted_operations_expose_their_remaining_budget_to_repositories() {
+    assert_eq!(remaining_operation_budget(), None);
+
+    let deadlines = OperationDeadlines::new(Arc::new(NeverTimer), runtime_query_budget());
+    let remaining = deadlines
+        .run(TimedOperation::RuntimeQuery, async {
+            Ok(remaining_operation_budget())
+        })
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(remaining.is_some_and(|remaining| remaining <= Duration::from_millis(250)));
+
+    let nested = OperationDeadline::start(TimedOperation::WorkflowDispatch, Duration::ZERO)
+        .scope(deadlines.run(TimedOperation::RuntimeQuery, async {
+            Ok(remaining_operation_budget())
+        }))
+        .await;
+    assert_eq!(nested.ok(), Some(Some(Duration::ZERO)));
+}
+
+#[tokio::test]
+async fn errors_after_a_spent_budget_surface_as_timeouts() {
+    let timeouts = OperationTimeouts {
+        runtime_query: Some(Duration::ZERO),
+        ..OperationTimeouts::default()
+    };
+    let deadlines = OperationDeadlines::new(Arc::new(NeverTimer), timeouts);
+
+    let cancelled_statement: AppResult<()> = deadlines
+        .run(TimedOperation::RuntimeQuery, async {
+            ensure_operation_budget_remaining()?;
+            Err(AppError::Internal(
+                "canceling statement due to statement timeout".to_owned(),
+            ))
+        })
+        .await;
+
+    let Err(AppError::Timeout(message)) = cancelled_statement else {
+        panic!("expected timeout, got {cancelled_statement:?}");
+    };
+    assert_eq!(
+        message,
+        "runtime query exceeded its 0 ms budget and was cancelled"
+    );
+    assert!(ensure_operation_budget_remaining().is_ok());
+}
@@ -0,0 +1,21 @@
+//! Cross-tenant operator console.
+//!
+//! Operators authenticate with their own credentials instead of a tenant
+//! session. They can list tenants with health and usage, look up a user by
+//! email across tenants, read aggregate workflow queue stats and run
+//! maintenance actions for one tenant. Every console action is appended to a
+//! global operator log that no tenant can read.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    OperatorAuditEvent, OperatorConsoleRepository, OperatorMaintenanceAction,
+    OperatorMaintenanceResult, OperatorTenantHealth, OperatorTenantUsage, OperatorUserLookup,
+};
+pub use service::{
+    OPERATOR_AUDIT_LOG_MAX_LIMIT, OPERATOR_TENANT_LIST_MAX_LIMIT, OperatorConsoleService,
+};
@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+use qryvanta_domain::UserId;
+
+use crate::TenantMembership;
+
+/// Health summary of one tenant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperatorTenantHealth {
+    /// No recent workflow failures or expired leases.
+    Healthy,
+    /// Workflow jobs failed in the last day or leases expired.
+    Degraded,
+}
+
+impl OperatorTenantHealth {
+    /// Returns a stable transport value for this health state.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Healthy => "healthy",
+            Self::Degraded => "degraded",
+        }
+    }
+}
+
+/// Usage counters of one tenant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorTenantUsage {
+    /// Tenant identifier.
+    pub tenant_id: TenantId,
+    /// Tenant display name.
+    pub name: String,
+    /// Tenant creation time.
+    pub created_at: DateTime<Utc>,
+    /// Tenant memberships.
+    pub member_count: i64,
+    /// Defined entities.
+    pub entity_count: i64,
+    /// Stored runtime records.
+    pub runtime_record_count: i64,
+    /// Workflow jobs waiting to be claimed.
+    pub pending_workflow_jobs: i64,
+    /// Workflow jobs that failed in the last day.
+    pub failed_workflow_jobs_last_day: i64,
+    /// Leased workflow jobs whose lease expired.
+    pub expired_workflow_leases: i64,
+    /// Latest tenant audit entry, when any.
+    pub last_activity_at: Option<DateTime<Utc>>,
+}
+
+impl OperatorTenantUsage {
+    /// Derives the tenant health from its workflow counters.
+    #[must_use]
+    pub fn health(&self) -> OperatorTenantHealth {
+        if self.failed_workflow_jobs_last_day > 0 || self.expired_workflow_leases > 0 {
+            OperatorTenantHealth::Degraded
+        } else {
+            OperatorTenantHealth::Healthy
+        }
+    }
+}
+
+/// User found by an operator email search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorUserLookup {
+    /// User identifier.
+    pub user_id: UserId,
+    /// Canonical email address.
+    pub email: String,
+    /// Whether the email address has been verified.
+    pub email_verified: bool,
+    /// Whether TOTP or a passkey protects the account.
+    pub mfa_enabled: bool,
+    /// Account lock expiry, when locked.
+    pub locked_until: Option<DateTime<Utc>>,
+    /// Tenants the user belongs to.
+    pub memberships: Vec<TenantMembership>,
+}
+
+/// Per-tenant maintenance action operators can run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperatorMaintenanceAction {
+    /// Returns workflow jobs with expired leases to pending.
+    ReleaseStuckWorkflowJobs,
+    /// Fails workflow jobs with expired leases and dead-letters their runs.
+    FailStuckWorkflowJobs,
+    /// Invalidates cached view results of every tenant entity.
+    FlushViewCache,
+}
+
+impl OperatorMaintenanceAction {
+    /// Returns a stable transport value for this action.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ReleaseStuckWorkflowJobs => "release_stuck_workflow_jobs",
+            Self::FailStuckWorkflowJobs => "fail_stuck_workflow_jobs",
+            Self::FlushViewCache => "flush_view_cache",
+        }
+    }
+
+    /// Parses a transport action value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "release_stuck_workflow_jobs" => Ok(Self::ReleaseStuckWorkflowJobs),
+            "fail_stuck_workflow_jobs" => Ok(Self::FailStuckWorkflowJobs),
+            "flush_view_cache" => Ok(Self::FlushViewCache),
+            _ => Err(AppError::Validation(format!(
+                "unknown operator maintenance action '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Outcome of one maintenance action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorMaintenanceResult {
+    /// Tenant the action ran for.
+    pub tenant_id: TenantId,
+    /// Action that ran.
+    pub action: OperatorMaintenanceAction,
+    /// Jobs or entities the action touched.
+    pub affected: usize,
+}
+
+/// Entry of the global operator log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorAuditEvent {
+    /// Event identifier.
+    pub event_id: String,
+    /// Operator that acted.
+    pub operator_id: String,
+    /// Console action name.
+    pub action: String,
+    /// Tenant the action targeted, when any.
+    pub tenant_id: Option<TenantId>,
+    /// Human-readable summary.
+    pub detail: String,
+    /// Time the action ran.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Repository port for cross-tenant operator reads and the operator log.
+#[async_trait]
+pub trait OperatorConsoleRepository: Send + Sync {
+    /// Lists tenant usage ordered by tenant creation time.
+    async fn list_tenant_usage(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<OperatorTenantUsage>>;
+
+    /// Returns the tenant name, when the tenant exists.
+    async fn find_tenant_name(&self, tenant_id: TenantId) -> AppResult<Option<String>>;
+
+    /// Lists the logical names of every entity of a tenant.
+    async fn list_entity_logical_names(&self, tenant_id: TenantId) -> AppResult<Vec<String>>;
+
+    /// Appends one entry to the operator log.
+    async fn append_audit_event(
+        &self,
+        operator_id: &str,
+        action: &str,
+        tenant_id: Option<TenantId>,
+        detail: &str,
+    ) -> AppResult<()>;
+
+    /// Lists operator log entries, newest first.
+    async fn list_audit_events(&self, limit: usize) -> AppResult<Vec<OperatorAuditEvent>>;
+}
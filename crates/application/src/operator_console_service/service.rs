@@ -0,0 +1,282 @@
+use std::sync::Arc;
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::workflow_ports::WORKFLOW_STUCK_JOB_MAX_LIMIT;
+use crate::{
+    RuntimeViewResultCache, TenantRepository, UserRepository, WorkflowQueueStats,
+    WorkflowQueueStatsQuery, WorkflowRepository,
+};
+
+use super::{
+    OperatorAuditEvent, OperatorConsoleRepository, OperatorMaintenanceAction,
+    OperatorMaintenanceResult, OperatorTenantUsage, OperatorUserLookup,
+};
+
+/// Upper bound for tenant list requests.
+pub const OPERATOR_TENANT_LIST_MAX_LIMIT: usize = 200;
+
+/// Upper bound for operator log requests.
+pub const OPERATOR_AUDIT_LOG_MAX_LIMIT: usize = 500;
+
+/// Application service for cross-tenant operator actions.
+#[derive(Clone)]
+pub struct OperatorConsoleService {
+    repository: Arc<dyn OperatorConsoleRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    tenant_repository: Arc<dyn TenantRepository>,
+    workflow_repository: Arc<dyn WorkflowRepository>,
+    view_result_cache: Option<Arc<dyn RuntimeViewResultCache>>,
+}
+
+impl OperatorConsoleService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn OperatorConsoleRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        tenant_repository: Arc<dyn TenantRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            user_repository,
+            tenant_repository,
+            workflow_repository,
+            view_result_cache: None,
+        }
+    }
+
+    /// Lets the view cache flush action invalidate cached view results.
+    #[must_use]
+    pub fn with_view_result_cache(
+        mut self,
+        view_result_cache: Arc<dyn RuntimeViewResultCache>,
+    ) -> Self {
+        self.view_result_cache = Some(view_result_cache);
+        self
+    }
+
+    /// Lists tenants with usage counters, oldest tenant first.
+    pub async fn list_tenants(
+        &self,
+        operator_id: &str,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<OperatorTenantUsage>> {
+        let tenants = self
+            .repository
+            .list_tenant_usage(limit.clamp(1, OPERATOR_TENANT_LIST_MAX_LIMIT), offset)
+            .await?;
+
+        self.repository
+            .append_audit_event(
+                operator_id,
+                "list_tenants",
+                None,
+                format!("listed {} tenants from offset {offset}", tenants.len()).as_str(),
+            )
+            .await?;
+
+        Ok(tenants)
+    }
+
+    /// Finds a user by email together with every tenant membership.
+    pub async fn search_user(
+        &self,
+        operator_id: &str,
+        email: &str,
+    ) -> AppResult<Option<OperatorUserLookup>> {
+        let email = email.trim();
+        if email.is_empty() {
+            return Err(AppError::Validation(
+                "email is required to search users".to_owned(),
+            ));
+        }
+
+        let lookup = match self.user_repository.find_by_email(email).await? {
+            Some(user) => Some(OperatorUserLookup {
+                memberships: self
+                    .tenant_repository
+                    .list_memberships_for_subject(user.id.to_string().as_str())
+                    .await?,
+                user_id: user.id,
+                email: user.email,
+                email_verified: user.email_verified,
+                mfa_enabled: user.totp_enabled || user.passkeys_enrolled,
+                locked_until: user.locked_until,
+            }),
+            None => None,
+        };
+
+        let detail = match &lookup {
+            Some(user) => format!(
+                "searched user '{email}' and found '{}' in {} tenants",
+                user.user_id,
+                user.memberships.len()
+            ),
+            None => format!("searched user '{email}' without a match"),
+        };
+        self.repository
+            .append_audit_event(operator_id, "search_user", None, detail.as_str())
+            .await?;
+
+        Ok(lookup)
+    }
+
+    /// Returns workflow queue and worker stats across all tenants.
+    pub async fn queue_stats(
+        &self,
+        operator_id: &str,
+        active_window_seconds: u32,
+    ) -> AppResult<WorkflowQueueStats> {
+        if active_window_seconds == 0 {
+            return Err(AppError::Validation(
+                "active_window_seconds must be greater than zero".to_owned(),
+            ));
+        }
+
+        let stats = self
+            .workflow_repository
+            .queue_stats(WorkflowQueueStatsQuery {
+                active_window_seconds,
+                partition: None,
+                tenant_id: None,
+            })
+            .await?;
+
+        self.repository
+            .append_audit_event(
+                operator_id,
+                "view_queue_stats",
+                None,
+                format!(
+                    "viewed queue stats: {} pending, {} failed, {} expired leases",
+                    stats.pending_jobs, stats.failed_jobs, stats.expired_leases
+                )
+                .as_str(),
+            )
+            .await?;
+
+        Ok(stats)
+    }
+
+    /// Runs one maintenance action for a tenant.
+    pub async fn run_maintenance(
+        &self,
+        operator_id: &str,
+        tenant_id: TenantId,
+        action: OperatorMaintenanceAction,
+    ) -> AppResult<OperatorMaintenanceResult> {
+        let tenant_name = self
+            .repository
+            .find_tenant_name(tenant_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("tenant '{tenant_id}' does not exist")))?;
+
+        let affected = match action {
+            OperatorMaintenanceAction::ReleaseStuckWorkflowJobs => {
+                let reason = format!("released by operator '{operator_id}' during maintenance");
+                self.resolve_stuck_jobs(tenant_id, |job_id| {
+                    let reason = reason.as_str();
+                    async move {
+                        self.workflow_repository
+                            .release_stuck_job(tenant_id, job_id.as_str(), reason)
+                            .await
+                    }
+                })
+                .await?
+            }
+            OperatorMaintenanceAction::FailStuckWorkflowJobs => {
+                let reason = format!("failed by operator '{operator_id}' during maintenance");
+                self.resolve_stuck_jobs(tenant_id, |job_id| {
+                    let reason = reason.as_str();
+                    async move {
+                        self.workflow_repository
+                            .fail_stuck_job(tenant_id, job_id.as_str(), reason)
+                            .await
+                            .map(|run| run.is_some())
+                    }
+                })
+                .await?
+            }
+            OperatorMaintenanceAction::FlushViewCache => {
+                let cache = self.view_result_cache.as_ref().ok_or_else(|| {
+                    AppError::Conflict("runtime view result cache is not configured".to_owned())
+                })?;
+                let entity_logical_names =
+                    self.repository.list_entity_logical_names(tenant_id).await?;
+                for entity_logical_name in &entity_logical_names {
+                    cache
+                        .invalidate_entity(tenant_id, entity_logical_name.as_str())
+                        .await?;
+                }
+                entity_logical_names.len()
+            }
+        };
+
+        self.repository
+            .append_audit_event(
+                operator_id,
+                action.as_str(),
+                Some(tenant_id),
+                format!(
+                    "ran {} for tenant '{tenant_name}': {affected} affected",
+                    action.as_str()
+                )
+                .as_str(),
+            )
+            .await?;
+
+        Ok(OperatorMaintenanceResult {
+            tenant_id,
+            action,
+            affected,
+        })
+    }
+
+    /// Lists operator log entries, newest first.
+    pub async fn list_audit_events(
+        &self,
+        operator_id: &str,
+        limit: usize,
+    ) -> AppResult<Vec<OperatorAuditEvent>> {
+        let events = self
+            .repository
+            .list_audit_events(limit.clamp(1, OPERATOR_AUDIT_LOG_MAX_LIMIT))
+            .await?;
+
+        self.repository
+            .append_audit_event(
+                operator_id,
+                "view_audit_log",
+                None,
+                format!("viewed {} operator log entries", events.len()).as_str(),
+            )
+            .await?;
+
+        Ok(events)
+    }
+
+    /// Applies `resolve` to every stuck job of the tenant and counts the
+    /// jobs it resolved.
+    async fn resolve_stuck_jobs<F, Fut>(&self, tenant_id: TenantId, resolve: F) -> AppResult<usize>
+    where
+        F: Fn(String) -> Fut,
+        Fut: std::future::Future<Output = AppResult<bool>>,
+    {
+        let stuck_jobs = self
+            .workflow_repository
+            .list_stuck_jobs(tenant_id, WORKFLOW_STUCK_JOB_MAX_LIMIT)
+            .await?;
+
+        let mut resolved = 0;
+        for job in stuck_jobs {
+            if resolve(job.job_id).await? {
+                resolved += 1;
+            }
+        }
+
+        Ok(resolved)
+    }
+}
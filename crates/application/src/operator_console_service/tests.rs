@@ -0,0 +1,932 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+use qryvanta_domain::{
+    RegistrationMode, RuntimeRecord, UserId, WorkflowDefinition, WorkflowTrigger,
+};
+
+use crate::{
+    ClaimedWorkflowJob, ClaimedWorkflowScheduleTick, CompleteWorkflowRunInput,
+    CreateWorkflowRunInput, NewWorkflowBulkExecution, RuntimeViewCacheKey, RuntimeViewCacheLookup,
+    RuntimeViewResultCache, SuspendWorkflowRunInput, TenantMembership, TenantRepository,
+    UserRecord, UserRepository, WorkflowBulkExecution, WorkflowBulkExecutionRunCounts,
+    WorkflowClaimPartition, WorkflowQueueStats, WorkflowQueueStatsQuery, WorkflowRepository,
+    WorkflowRun, WorkflowRunAttempt, WorkflowRunListQuery, WorkflowScheduledTrigger,
+    WorkflowStuckJob, WorkflowWorkerHeartbeatInput,
+};
+
+use super::{
+    OperatorAuditEvent, OperatorConsoleRepository, OperatorConsoleService,
+    OperatorMaintenanceAction, OperatorTenantHealth, OperatorTenantUsage,
+};
+
+#[derive(Default)]
+struct FakeOperatorConsoleRepository {
+    tenants: Vec<OperatorTenantUsage>,
+    entities: HashMap<TenantId, Vec<String>>,
+    events: Mutex<Vec<OperatorAuditEvent>>,
+}
+
+#[async_trait]
+impl OperatorConsoleRepository for FakeOperatorConsoleRepository {
+    async fn list_tenant_usage(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<OperatorTenantUsage>> {
+        Ok(self
+            .tenants
+            .iter()
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_tenant_name(&self, tenant_id: TenantId) -> AppResult<Option<String>> {
+        Ok(self
+            .tenants
+            .iter()
+            .find(|tenant| tenant.tenant_id == tenant_id)
+            .map(|tenant| tenant.name.clone()))
+    }
+
+    async fn list_entity_logical_names(&self, tenant_id: TenantId) -> AppResult<Vec<String>> {
+        Ok(self.entities.get(&tenant_id).cloned().unwrap_or_default())
+    }
+
+    async fn append_audit_event(
+        &self,
+        operator_id: &str,
+        action: &str,
+        tenant_id: Option<TenantId>,
+        detail: &str,
+    ) -> AppResult<()> {
+        let mut events = self.events.lock().unwrap_or_else(|_| unreachable!());
+        let event_id = events.len().to_string();
+        events.push(OperatorAuditEvent {
+            event_id,
+            operator_id: operator_id.to_owned(),
+            action: action.to_owned(),
+            tenant_id,
+            detail: detail.to_owned(),
+            created_at: Utc::now(),
+        });
+        Ok(())
+    }
+
+    async fn list_audit_events(&self, limit: usize) -> AppResult<Vec<OperatorAuditEvent>> {
+        Ok(self
+            .events
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .rev()
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
+
+struct FakeUserRepository {
+    user: UserRecord,
+}
+
+#[async_trait]
+impl UserRepository for FakeUserRepository {
+    async fn find_by_email(&self, email: &str) -> AppResult<Option<UserRecord>> {
+        Ok(self
+            .user
+            .email
+            .eq_ignore_ascii_case(email)
+            .then(|| self.user.clone()))
+    }
+
+    async fn find_by_id(&self, _user_id: UserId) -> AppResult<Option<UserRecord>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn create(
+        &self,
+        _email: &str,
+        _password_hash: Option<&str>,
+        _email_verified: bool,
+    ) -> AppResult<UserId> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_password(&self, _user_id: UserId, _password_hash: &str) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn revoke_sessions(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn default_tenant_id(&self, _user_id: UserId) -> AppResult<Option<TenantId>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn set_default_tenant_id(&self, _user_id: UserId, _tenant_id: TenantId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn record_failed_login(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn reset_failed_logins(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn mark_email_verified(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_display_name(
+        &self,
+        _user_id: UserId,
+        _tenant_id: TenantId,
+        _display_name: &str,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_email(&self, _user_id: UserId, _new_email: &str) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn enable_totp(
+        &self,
+        _user_id: UserId,
+        _totp_secret_enc: &[u8],
+        _recovery_codes_hash: &serde_json::Value,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn begin_totp_enrollment(
+        &self,
+        _user_id: UserId,
+        _totp_secret_enc: &[u8],
+        _recovery_codes_hash: &serde_json::Value,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn confirm_totp_enrollment(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn disable_totp(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_recovery_codes(
+        &self,
+        _user_id: UserId,
+        _recovery_codes_hash: &serde_json::Value,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn find_by_subject(&self, _subject: &str) -> AppResult<Option<UserRecord>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+}
+
+struct FakeTenantRepository {
+    memberships: HashMap<String, Vec<TenantMembership>>,
+}
+
+#[async_trait]
+impl TenantRepository for FakeTenantRepository {
+    async fn find_tenant_for_subject(&self, _subject: &str) -> AppResult<Option<TenantId>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn registration_mode_for_tenant(
+        &self,
+        _tenant_id: TenantId,
+    ) -> AppResult<RegistrationMode> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn create_membership(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _display_name: &str,
+        _email: Option<&str>,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn ensure_membership_for_subject(
+        &self,
+        _subject: &str,
+        _display_name: &str,
+        _email: Option<&str>,
+        _preferred_tenant_id: Option<TenantId>,
+    ) -> AppResult<TenantId> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn list_memberships_for_subject(
+        &self,
+        subject: &str,
+    ) -> AppResult<Vec<TenantMembership>> {
+        Ok(self.memberships.get(subject).cloned().unwrap_or_default())
+    }
+
+    async fn contact_record_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Option<String>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn save_contact_record_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _contact_record_id: &str,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+}
+
+#[derive(Default)]
+struct FakeWorkflowRepository {
+    stuck_jobs: Mutex<HashMap<TenantId, Vec<WorkflowStuckJob>>>,
+    released: Mutex<Vec<(TenantId, String, String)>>,
+}
+
+#[async_trait]
+impl WorkflowRepository for FakeWorkflowRepository {
+    async fn save_workflow(
+        &self,
+        _tenant_id: TenantId,
+        _workflow: WorkflowDefinition,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn list_workflows(&self, _tenant_id: TenantId) -> AppResult<Vec<WorkflowDefinition>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn find_workflow(
+        &self,
+        _tenant_id: TenantId,
+        _logical_name: &str,
+    ) -> AppResult<Option<WorkflowDefinition>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn find_published_workflow(
+        &self,
+        _tenant_id: TenantId,
+        _logical_name: &str,
+    ) -> AppResult<Option<WorkflowDefinition>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn find_published_workflow_version(
+        &self,
+        _tenant_id: TenantId,
+        _logical_name: &str,
+        _version: i32,
+    ) -> AppResult<Option<WorkflowDefinition>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn publish_workflow(
+        &self,
+        _tenant_id: TenantId,
+        _logical_name: &str,
+        _published_by: &str,
+    ) -> AppResult<WorkflowDefinition> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn disable_workflow(
+        &self,
+        _tenant_id: TenantId,
+        _logical_name: &str,
+    ) -> AppResult<WorkflowDefinition> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn list_enabled_workflows_for_trigger(
+        &self,
+        _tenant_id: TenantId,
+        _trigger: &WorkflowTrigger,
+    ) -> AppResult<Vec<WorkflowDefinition>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn list_enabled_schedule_triggers(
+        &self,
+        _tenant_filter: Option<TenantId>,
+    ) -> AppResult<Vec<WorkflowScheduledTrigger>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn claim_schedule_tick(
+        &self,
+        _tenant_id: TenantId,
+        _schedule_key: &str,
+        _slot_key: &str,
+        _scheduled_for: DateTime<Utc>,
+        _worker_id: &str,
+        _lease_seconds: u32,
+    ) -> AppResult<Option<ClaimedWorkflowScheduleTick>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn complete_schedule_tick(
+        &self,
+        _tenant_id: TenantId,
+        _schedule_key: &str,
+        _slot_key: &str,
+        _worker_id: &str,
+        _lease_token: &str,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn release_schedule_tick(
+        &self,
+        _tenant_id: TenantId,
+        _schedule_key: &str,
+        _slot_key: &str,
+        _worker_id: &str,
+        _lease_token: &str,
+        _error_message: &str,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn create_run(
+        &self,
+        _tenant_id: TenantId,
+        _input: CreateWorkflowRunInput,
+    ) -> AppResult<WorkflowRun> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn enqueue_run_job(&self, _tenant_id: TenantId, _run_id: &str) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn claim_jobs(
+        &self,
+        _worker_id: &str,
+        _limit: usize,
+        _lease_seconds: u32,
+        _partition: Option<WorkflowClaimPartition>,
+        _tenant_filter: Option<TenantId>,
+    ) -> AppResult<Vec<ClaimedWorkflowJob>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn complete_job(
+        &self,
+        _tenant_id: TenantId,
+        _job_id: &str,
+        _worker_id: &str,
+        _lease_token: &str,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn fail_job(
+        &self,
+        _tenant_id: TenantId,
+        _job_id: &str,
+        _worker_id: &str,
+        _lease_token: &str,
+        _error_message: &str,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn suspend_run_job(
+        &self,
+        _tenant_id: TenantId,
+        _job_id: &str,
+        _worker_id: &str,
+        _lease_token: &str,
+        _input: SuspendWorkflowRunInput,
+    ) -> AppResult<WorkflowRun> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn list_stuck_jobs(
+        &self,
+        tenant_id: TenantId,
+        limit: usize,
+    ) -> AppResult<Vec<WorkflowStuckJob>> {
+        Ok(self
+            .stuck_jobs
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .get(&tenant_id)
+            .map(|jobs| jobs.iter().take(limit).cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn release_stuck_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        reason: &str,
+    ) -> AppResult<bool> {
+        let mut stuck_jobs = self.stuck_jobs.lock().unwrap_or_else(|_| unreachable!());
+        let Some(jobs) = stuck_jobs.get_mut(&tenant_id) else {
+            return Ok(false);
+        };
+        let count = jobs.len();
+        jobs.retain(|job| job.job_id != job_id);
+        if jobs.len() == count {
+            return Ok(false);
+        }
+
+        self.released
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .push((tenant_id, job_id.to_owned(), reason.to_owned()));
+        Ok(true)
+    }
+
+    async fn release_job(
+        &self,
+        _tenant_id: TenantId,
+        _job_id: &str,
+        _worker_id: &str,
+        _lease_token: &str,
+        _reason: &str,
+    ) -> AppResult<bool> {
+        Ok(false)
+    }
+
+    async fn fail_stuck_job(
+        &self,
+        _tenant_id: TenantId,
+        _job_id: &str,
+        _reason: &str,
+    ) -> AppResult<Option<WorkflowRun>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn upsert_worker_heartbeat(
+        &self,
+        _worker_id: &str,
+        _input: WorkflowWorkerHeartbeatInput,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn queue_stats(&self, query: WorkflowQueueStatsQuery) -> AppResult<WorkflowQueueStats> {
+        assert!(query.tenant_id.is_none());
+        assert!(query.partition.is_none());
+        Ok(WorkflowQueueStats {
+            pending_jobs: 4,
+            leased_jobs: 1,
+            completed_jobs: 9,
+            failed_jobs: 2,
+            expired_leases: 1,
+            active_workers: 3,
+        })
+    }
+
+    async fn append_run_attempt(
+        &self,
+        _tenant_id: TenantId,
+        _attempt: WorkflowRunAttempt,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn complete_run(
+        &self,
+        _tenant_id: TenantId,
+        _input: CompleteWorkflowRunInput,
+    ) -> AppResult<WorkflowRun> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn list_runs(
+        &self,
+        _tenant_id: TenantId,
+        _query: WorkflowRunListQuery,
+    ) -> AppResult<Vec<WorkflowRun>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn find_run(
+        &self,
+        _tenant_id: TenantId,
+        _run_id: &str,
+    ) -> AppResult<Option<WorkflowRun>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn find_run_by_idempotency_key(
+        &self,
+        _tenant_id: TenantId,
+        _workflow_logical_name: &str,
+        _idempotency_key: &str,
+    ) -> AppResult<Option<WorkflowRun>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn list_run_attempts(
+        &self,
+        _tenant_id: TenantId,
+        _run_id: &str,
+    ) -> AppResult<Vec<WorkflowRunAttempt>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn create_bulk_execution(
+        &self,
+        _tenant_id: TenantId,
+        _execution: NewWorkflowBulkExecution,
+    ) -> AppResult<WorkflowBulkExecution> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn find_bulk_execution(
+        &self,
+        _tenant_id: TenantId,
+        _bulk_execution_id: &str,
+    ) -> AppResult<Option<WorkflowBulkExecution>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn bulk_execution_run_counts(
+        &self,
+        _tenant_id: TenantId,
+        _bulk_execution_id: &str,
+    ) -> AppResult<WorkflowBulkExecutionRunCounts> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+}
+
+#[derive(Default)]
+struct FakeViewResultCache {
+    invalidated: Mutex<Vec<(TenantId, String)>>,
+}
+
+#[async_trait]
+impl RuntimeViewResultCache for FakeViewResultCache {
+    async fn get_view_result(
+        &self,
+        _key: &RuntimeViewCacheKey,
+    ) -> AppResult<RuntimeViewCacheLookup> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn set_view_result(
+        &self,
+        _key: RuntimeViewCacheKey,
+        _generation: i64,
+        _records: Vec<RuntimeRecord>,
+        _ttl_seconds: u32,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn invalidate_entity(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<()> {
+        self.invalidated
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .push((tenant_id, entity_logical_name.to_owned()));
+        Ok(())
+    }
+}
+
+fn tenant_usage(name: &str, failed_workflow_jobs_last_day: i64) -> OperatorTenantUsage {
+    OperatorTenantUsage {
+        tenant_id: TenantId::new(),
+        name: name.to_owned(),
+        created_at: Utc::now(),
+        member_count: 3,
+        entity_count: 2,
+        runtime_record_count: 40,
+        pending_workflow_jobs: 1,
+        failed_workflow_jobs_last_day,
+        expired_workflow_leases: 0,
+        last_activity_at: None,
+    }
+}
+
+fn user_record(user_id: UserId) -> UserRecord {
+    UserRecord {
+        id: user_id,
+        email: "alex@example.com".to_owned(),
+        email_verified: true,
+        password_hash: None,
+        totp_enabled: false,
+        passkeys_enrolled: true,
+        totp_secret_enc: None,
+        recovery_codes_hash: None,
+        totp_pending_secret_enc: None,
+        recovery_codes_pending_hash: None,
+        failed_login_count: 0,
+        locked_until: None,
+        password_changed_at: None,
+        auth_sessions_revoked_after: None,
+        default_tenant_id: None,
+    }
+}
+
+fn stuck_job(job_id: &str) -> WorkflowStuckJob {
+    WorkflowStuckJob {
+        job_id: job_id.to_owned(),
+        run_id: format!("run-{job_id}"),
+        workflow_logical_name: "notify_owner".to_owned(),
+        workflow_version: 1,
+        leased_by: Some("worker-a".to_owned()),
+        lease_expires_at: Utc::now(),
+        run_attempts: 1,
+        last_error: None,
+    }
+}
+
+struct Fixture {
+    service: OperatorConsoleService,
+    repository: Arc<FakeOperatorConsoleRepository>,
+    workflow_repository: Arc<FakeWorkflowRepository>,
+    view_result_cache: Arc<FakeViewResultCache>,
+    healthy_tenant: TenantId,
+    degraded_tenant: TenantId,
+    user_id: UserId,
+}
+
+fn fixture() -> Fixture {
+    let healthy = tenant_usage("Acme", 0);
+    let degraded = tenant_usage("Globex", 2);
+    let healthy_tenant = healthy.tenant_id;
+    let degraded_tenant = degraded.tenant_id;
+    let user_id = UserId::new();
+
+    let repository = Arc::new(FakeOperatorConsoleRepository {
+        tenants: vec![healthy, degraded],
+        entities: HashMap::from([(
+            degraded_tenant,
+            vec!["account".to_owned(), "contact".to_owned()],
+        )]),
+        events: Mutex::new(Vec::new()),
+    });
+    let tenant_repository = Arc::new(FakeTenantRepository {
+        memberships: HashMap::from([(
+            user_id.to_string(),
+            vec![TenantMembership {
+                tenant_id: degraded_tenant,
+                tenant_name: "Globex".to_owned(),
+                display_name: "Alex".to_owned(),
+                email: Some("alex@example.com".to_owned()),
+            }],
+        )]),
+    });
+    let workflow_repository = Arc::new(FakeWorkflowRepository::default());
+    workflow_repository
+        .stuck_jobs
+        .lock()
+        .unwrap_or_else(|_| unreachable!())
+        .insert(
+            degraded_tenant,
+            vec![stuck_job("job-1"), stuck_job("job-2")],
+        );
+    let view_result_cache = Arc::new(FakeViewResultCache::default());
+
+    let service = OperatorConsoleService::new(
+        repository.clone(),
+        Arc::new(FakeUserRepository {
+            user: user_record(user_id),
+        }),
+        tenant_repository,
+        workflow_repository.clone(),
+    )
+    .with_view_result_cache(view_result_cache.clone());
+
+    Fixture {
+        service,
+        repository,
+        workflow_repository,
+        view_result_cache,
+        healthy_tenant,
+        degraded_tenant,
+        user_id,
+    }
+}
+
+#[tokio::test]
+async fn console_reads_are_recorded_in_operator_log() {
+    let fixture = fixture();
+
+    let tenants = fixture
+        .service
+        .list_tenants("ops-alice", 1000, 0)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(tenants.len(), 2);
+    assert_eq!(tenants[0].tenant_id, fixture.healthy_tenant);
+    assert_eq!(tenants[0].health(), OperatorTenantHealth::Healthy);
+    assert_eq!(tenants[1].health(), OperatorTenantHealth::Degraded);
+
+    let user = fixture
+        .service
+        .search_user("ops-alice", " Alex@Example.com ")
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(user.user_id, fixture.user_id);
+    assert!(user.mfa_enabled);
+    assert_eq!(user.memberships.len(), 1);
+    assert_eq!(user.memberships[0].tenant_id, fixture.degraded_tenant);
+
+    let missing = fixture
+        .service
+        .search_user("ops-alice", "nobody@example.com")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(missing.is_none());
+
+    let stats = fixture
+        .service
+        .queue_stats("ops-alice", 60)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(stats.pending_jobs, 4);
+
+    let events = fixture
+        .service
+        .list_audit_events("ops-bob", 10)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let actions: Vec<&str> = events.iter().map(|event| event.action.as_str()).collect();
+    assert_eq!(
+        actions,
+        vec![
+            "view_queue_stats",
+            "search_user",
+            "search_user",
+            "list_tenants"
+        ]
+    );
+    assert!(events.iter().all(|event| event.operator_id == "ops-alice"));
+    assert!(events[2].detail.contains("in 1 tenants"));
+
+    let logged = fixture
+        .repository
+        .events
+        .lock()
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(logged.len(), 5);
+    assert_eq!(logged[4].operator_id, "ops-bob");
+    assert_eq!(logged[4].action, "view_audit_log");
+}
+
+#[tokio::test]
+async fn console_rejects_blank_searches_and_zero_windows() {
+    let fixture = fixture();
+
+    let blank = fixture.service.search_user("ops-alice", "  ").await;
+    assert!(matches!(blank, Err(AppError::Validation(_))));
+
+    let zero_window = fixture.service.queue_stats("ops-alice", 0).await;
+    assert!(matches!(zero_window, Err(AppError::Validation(_))));
+
+    assert!(
+        fixture
+            .repository
+            .events
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn maintenance_releases_stuck_jobs_and_flushes_view_cache() {
+    let fixture = fixture();
+
+    let released = fixture
+        .service
+        .run_maintenance(
+            "ops-alice",
+            fixture.degraded_tenant,
+            OperatorMaintenanceAction::ReleaseStuckWorkflowJobs,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(released.affected, 2);
+    {
+        let released_jobs = fixture
+            .workflow_repository
+            .released
+            .lock()
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(released_jobs.len(), 2);
+        assert!(released_jobs.iter().all(|(tenant_id, _, reason)| *tenant_id
+            == fixture.degraded_tenant
+            && reason.contains("ops-alice")));
+    }
+
+    let flushed = fixture
+        .service
+        .run_maintenance(
+            "ops-alice",
+            fixture.degraded_tenant,
+            OperatorMaintenanceAction::FlushViewCache,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(flushed.affected, 2);
+    assert_eq!(
+        fixture
+            .view_result_cache
+            .invalidated
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .as_slice(),
+        &[
+            (fixture.degraded_tenant, "account".to_owned()),
+            (fixture.degraded_tenant, "contact".to_owned()),
+        ]
+    );
+
+    let events = fixture
+        .repository
+        .events
+        .lock()
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].action, "release_stuck_workflow_jobs");
+    assert_eq!(events[0].tenant_id, Some(fixture.degraded_tenant));
+    assert!(events[0].detail.contains("Globex"));
+    assert_eq!(events[1].action, "flush_view_cache");
+}
+
+#[tokio::test]
+async fn maintenance_rejects_unknown_tenants_and_actions() {
+    let fixture = fixture();
+
+    let unknown_tenant = fixture
+        .service
+        .run_maintenance(
+            "ops-alice",
+            TenantId::new(),
+            OperatorMaintenanceAction::ReleaseStuckWorkflowJobs,
+        )
+        .await;
+    assert!(matches!(unknown_tenant, Err(AppError::NotFound(_))));
+    assert!(matches!(
+        OperatorMaintenanceAction::parse("drop_tenant"),
+        Err(AppError::Validation(_))
+    ));
+    assert_eq!(
+        OperatorMaintenanceAction::parse("fail_stuck_workflow_jobs")
+            .unwrap_or_else(|_| unreachable!()),
+        OperatorMaintenanceAction::FailStuckWorkflowJobs
+    );
+
+    let without_cache = OperatorConsoleService::new(
+        fixture.repository.clone(),
+        Arc::new(FakeUserRepository {
+            user: user_record(fixture.user_id),
+        }),
+        Arc::new(FakeTenantRepository {
+            memberships: HashMap::new(),
+        }),
+        fixture.workflow_repository.clone(),
+    )
+    .run_maintenance(
+        "ops-alice",
+        fixture.healthy_tenant,
+        OperatorMaintenanceAction::FlushViewCache,
+    )
+    .await;
+    assert!(matches!(without_cache, Err(AppError::Conflict(_))));
+    assert!(
+        fixture
+            .repository
+            .events
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+}
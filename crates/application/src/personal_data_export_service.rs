@@ -0,0 +1,19 @@
+//! Self-service downloads of a user's personal data.
+//!
+//! A requester queues a background job that collects their profile, their
+//! auth events, and every runtime record they own into stored archive parts.
+//! Once the job completes, only the requester can download the assembled
+//! archive, for a limited time. Parts are keyed by position, so a resumed
+//! run overwrites what an interrupted run already stored.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    PersonalAuthEventEntry, PersonalDataExportPart, PersonalDataExportRepository,
+    PersonalDataExportSection,
+};
+pub use service::{PERSONAL_DATA_EXPORT_STAGES, PersonalDataArchive, PersonalDataExportService};
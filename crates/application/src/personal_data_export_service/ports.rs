@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+/// Section of a personal data export archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersonalDataExportSection {
+    /// Account profile of the requester.
+    Profile,
+    /// Auth events recorded for the requester.
+    AuthEvents,
+    /// Runtime records owned by the requester.
+    OwnedRecords,
+}
+
+impl PersonalDataExportSection {
+    /// Returns a stable storage value for this section.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Profile => "profile",
+            Self::AuthEvents => "auth_events",
+            Self::OwnedRecords => "owned_records",
+        }
+    }
+
+    /// Parses a stored section value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "profile" => Ok(Self::Profile),
+            "auth_events" => Ok(Self::AuthEvents),
+            "owned_records" => Ok(Self::OwnedRecords),
+            _ => Err(AppError::Validation(format!(
+                "unknown personal data export section '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Stored fragment of a personal data export archive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonalDataExportPart {
+    /// Archive section the items belong to.
+    pub section: PersonalDataExportSection,
+    /// Entity of owned-record parts; `None` for other sections.
+    pub entity_logical_name: Option<String>,
+    /// Position of the first item within its section or entity.
+    pub item_offset: u64,
+    /// Exported items.
+    pub items: Vec<Value>,
+}
+
+/// Auth event of the requester included in an export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersonalAuthEventEntry {
+    /// Stable event type identifier.
+    pub event_type: String,
+    /// Event outcome label.
+    pub outcome: String,
+    /// Caller IP address if recorded.
+    pub ip_address: Option<String>,
+    /// Caller user-agent if recorded.
+    pub user_agent: Option<String>,
+    /// Event timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Repository port for personal data export archives.
+#[async_trait]
+pub trait PersonalDataExportRepository: Send + Sync {
+    /// Stores an archive part, replacing any part at the same position.
+    async fn save_export_part(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        subject: &str,
+        part: &PersonalDataExportPart,
+    ) -> AppResult<()>;
+
+    /// Lists the parts of an archive ordered by section, entity and offset.
+    async fn list_export_parts(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Vec<PersonalDataExportPart>>;
+
+    /// Deletes every stored archive of a subject and returns the part count.
+    async fn delete_exports_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<u64>;
+
+    /// Lists auth events of a subject, oldest first.
+    async fn list_auth_events_for_subject(
+        &self,
+        subject: &str,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<PersonalAuthEventEntry>>;
+}
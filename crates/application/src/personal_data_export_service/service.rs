@@ -0,0 +1,311 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value, json};
+use uuid::Uuid;
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{RuntimeRecord, UserId};
+
+use crate::{
+    BackgroundJob, BackgroundJobKind, BackgroundJobLease, BackgroundJobService, BackgroundJobStage,
+    BackgroundJobStatus, CreateBackgroundJobInput, UserRepository,
+};
+
+use super::{PersonalDataExportPart, PersonalDataExportRepository, PersonalDataExportSection};
+
+/// Stages of a personal data export job, in run order.
+pub const PERSONAL_DATA_EXPORT_STAGES: [PersonalDataExportSection; 3] = [
+    PersonalDataExportSection::Profile,
+    PersonalDataExportSection::AuthEvents,
+    PersonalDataExportSection::OwnedRecords,
+];
+
+/// Completed archives can be downloaded for this many days.
+const PERSONAL_DATA_ARCHIVE_RETENTION_DAYS: i64 = 7;
+
+/// Format marker written into every archive.
+const PERSONAL_DATA_ARCHIVE_FORMAT: &str = "qryvanta.personal_data_export.v1";
+
+/// Assembled personal data archive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonalDataArchive {
+    /// Job that generated the archive.
+    pub job_id: String,
+    /// Suggested download file name.
+    pub file_name: String,
+    /// Archive document.
+    pub document: Value,
+}
+
+/// Application service for self-service personal data exports.
+#[derive(Clone)]
+pub struct PersonalDataExportService {
+    background_job_service: BackgroundJobService,
+    repository: Arc<dyn PersonalDataExportRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl PersonalDataExportService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        background_job_service: BackgroundJobService,
+        repository: Arc<dyn PersonalDataExportRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            background_job_service,
+            repository,
+            user_repository,
+        }
+    }
+
+    /// Queues an export of the actor's personal data.
+    ///
+    /// Owned records are collected from the listed entities. Earlier archives
+    /// of the actor are deleted; an export that is still running must finish
+    /// or be cancelled first.
+    pub async fn request_export(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_names: Vec<String>,
+    ) -> AppResult<BackgroundJob> {
+        let running_export = self
+            .background_job_service
+            .list_jobs(actor, 200)
+            .await?
+            .into_iter()
+            .any(|job| {
+                job.kind == BackgroundJobKind::PersonalDataExport
+                    && job.requested_by_subject == actor.subject()
+                    && !job.status.is_terminal()
+            });
+        if running_export {
+            return Err(AppError::Conflict(
+                "a personal data export is already in progress".to_owned(),
+            ));
+        }
+
+        self.repository
+            .delete_exports_for_subject(actor.tenant_id(), actor.subject())
+            .await?;
+
+        self.background_job_service
+            .enqueue_job(
+                actor,
+                CreateBackgroundJobInput {
+                    kind: BackgroundJobKind::PersonalDataExport,
+                    parameters: json!({ "entity_logical_names": entity_logical_names }),
+                    stages: PERSONAL_DATA_EXPORT_STAGES
+                        .iter()
+                        .map(|section| {
+                            let total = match section {
+                                PersonalDataExportSection::Profile => Some(1),
+                                PersonalDataExportSection::AuthEvents => None,
+                                PersonalDataExportSection::OwnedRecords => {
+                                    Some(entity_logical_names.len() as u64)
+                                }
+                            };
+                            BackgroundJobStage::new(section.as_str(), total)
+                        })
+                        .collect(),
+                },
+            )
+            .await
+    }
+
+    /// Builds the profile part of an export.
+    ///
+    /// Credentials and MFA secrets are left out; only whether they exist is
+    /// reported.
+    pub async fn profile_part(&self, actor: &UserIdentity) -> AppResult<PersonalDataExportPart> {
+        let account = match Uuid::parse_str(actor.subject()) {
+            Ok(user_id) => self
+                .user_repository
+                .find_by_id(UserId::from_uuid(user_id))
+                .await?
+                .map(|user| {
+                    json!({
+                        "user_id": user.id.to_string(),
+                        "email": user.email,
+                        "email_verified": user.email_verified,
+                        "has_password": user.password_hash.is_some(),
+                        "totp_enabled": user.totp_enabled,
+                        "password_changed_at": user.password_changed_at.map(|at| at.to_rfc3339()),
+                        "default_tenant_id": user.default_tenant_id.map(|id| id.to_string()),
+                    })
+                }),
+            Err(_) => None,
+        };
+
+        Ok(PersonalDataExportPart {
+            section: PersonalDataExportSection::Profile,
+            entity_logical_name: None,
+            item_offset: 0,
+            items: vec![json!({
+                "subject": actor.subject(),
+                "display_name": actor.display_name(),
+                "email": actor.email(),
+                "tenant_id": actor.tenant_id().to_string(),
+                "account": account,
+            })],
+        })
+    }
+
+    /// Builds one page of the auth events part of an export.
+    pub async fn auth_events_part(
+        &self,
+        actor: &UserIdentity,
+        offset: usize,
+        limit: usize,
+    ) -> AppResult<PersonalDataExportPart> {
+        let events = self
+            .repository
+            .list_auth_events_for_subject(actor.subject(), limit, offset)
+            .await?;
+
+        Ok(PersonalDataExportPart {
+            section: PersonalDataExportSection::AuthEvents,
+            entity_logical_name: None,
+            item_offset: offset as u64,
+            items: events
+                .into_iter()
+                .map(|event| {
+                    json!({
+                        "event_type": event.event_type,
+                        "outcome": event.outcome,
+                        "ip_address": event.ip_address,
+                        "user_agent": event.user_agent,
+                        "created_at": event.created_at.to_rfc3339(),
+                    })
+                })
+                .collect(),
+        })
+    }
+
+    /// Builds one page of the owned records part of an export.
+    #[must_use]
+    pub fn owned_records_part(
+        entity_logical_name: &str,
+        offset: usize,
+        records: &[RuntimeRecord],
+    ) -> PersonalDataExportPart {
+        PersonalDataExportPart {
+            section: PersonalDataExportSection::OwnedRecords,
+            entity_logical_name: Some(entity_logical_name.to_owned()),
+            item_offset: offset as u64,
+            items: records
+                .iter()
+                .map(|record| {
+                    json!({
+                        "record_id": record.record_id().as_str(),
+                        "data": record.data(),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Stores a part produced by the run holding the lease.
+    pub async fn save_part(
+        &self,
+        lease: &BackgroundJobLease,
+        part: &PersonalDataExportPart,
+    ) -> AppResult<()> {
+        self.repository
+            .save_export_part(
+                lease.tenant_id,
+                lease.job.job_id.as_str(),
+                lease.job.requested_by_subject.as_str(),
+                part,
+            )
+            .await
+    }
+
+    /// Assembles the archive of a completed export for its requester.
+    ///
+    /// Archives are private to the requester, including towards role
+    /// managers who can otherwise see every job.
+    pub async fn download_archive(
+        &self,
+        actor: &UserIdentity,
+        job_id: &str,
+    ) -> AppResult<PersonalDataArchive> {
+        let job = self.background_job_service.get_job(actor, job_id).await?;
+        if job.kind != BackgroundJobKind::PersonalDataExport
+            || job.requested_by_subject != actor.subject()
+        {
+            return Err(AppError::NotFound(format!(
+                "personal data export '{job_id}' does not exist"
+            )));
+        }
+        if job.status != BackgroundJobStatus::Completed {
+            return Err(AppError::Conflict(format!(
+                "personal data export '{job_id}' is {}",
+                job.status.as_str()
+            )));
+        }
+        let generated_at = job.finished_at.unwrap_or(job.updated_at);
+        if Self::archive_expired(generated_at, Utc::now()) {
+            return Err(AppError::NotFound(format!(
+                "personal data export '{job_id}' has expired"
+            )));
+        }
+
+        let parts = self
+            .repository
+            .list_export_parts(actor.tenant_id(), job_id)
+            .await?;
+
+        Ok(PersonalDataArchive {
+            job_id: job.job_id.clone(),
+            file_name: format!("personal-data-{}.json", generated_at.format("%Y%m%d")),
+            document: Self::assemble_archive(&job, generated_at, parts),
+        })
+    }
+
+    fn archive_expired(generated_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        now - generated_at > chrono::Duration::days(PERSONAL_DATA_ARCHIVE_RETENTION_DAYS)
+    }
+
+    fn assemble_archive(
+        job: &BackgroundJob,
+        generated_at: DateTime<Utc>,
+        parts: Vec<PersonalDataExportPart>,
+    ) -> Value {
+        let mut profile = Value::Null;
+        let mut auth_events = Vec::new();
+        let mut owned_records = Map::new();
+
+        for part in parts {
+            match part.section {
+                PersonalDataExportSection::Profile => {
+                    if let Some(item) = part.items.into_iter().next() {
+                        profile = item;
+                    }
+                }
+                PersonalDataExportSection::AuthEvents => auth_events.extend(part.items),
+                PersonalDataExportSection::OwnedRecords => {
+                    let entity_logical_name = part.entity_logical_name.unwrap_or_default();
+                    if let Value::Array(records) = owned_records
+                        .entry(entity_logical_name)
+                        .or_insert_with(|| Value::Array(Vec::new()))
+                    {
+                        records.extend(part.items);
+                    }
+                }
+            }
+        }
+
+        json!({
+            "format": PERSONAL_DATA_ARCHIVE_FORMAT,
+            "job_id": job.job_id,
+            "subject": job.requested_by_subject,
+            "generated_at": generated_at.to_rfc3339(),
+            "profile": profile,
+            "auth_events": auth_events,
+            "owned_records": owned_records,
+        })
+    }
+}
@@ -0,0 +1,617 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{Permission, RuntimeRecord, UserId};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, BackgroundJob,
+    BackgroundJobLease, BackgroundJobRepository, BackgroundJobService, BackgroundJobStage,
+    BackgroundJobStatus, CreateBackgroundJobInput, RuntimeFieldGrant, TemporaryPermissionGrant,
+    UserRecord, UserRepository,
+};
+
+use super::{
+    PersonalAuthEventEntry, PersonalDataExportPart, PersonalDataExportRepository,
+    PersonalDataExportSection, PersonalDataExportService,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository;
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, _event: AuditEvent) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeBackgroundJobRepository {
+    jobs: Mutex<Vec<(TenantId, BackgroundJob)>>,
+}
+
+#[async_trait]
+impl BackgroundJobRepository for FakeBackgroundJobRepository {
+    async fn create_background_job(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        requested_by_display_name: &str,
+        requested_by_email: Option<&str>,
+        input: CreateBackgroundJobInput,
+    ) -> AppResult<BackgroundJob> {
+        let now = Utc::now();
+        let job = BackgroundJob {
+            job_id: uuid::Uuid::new_v4().to_string(),
+            kind: input.kind,
+            status: BackgroundJobStatus::Queued,
+            requested_by_subject: requested_by_subject.to_owned(),
+            requested_by_display_name: requested_by_display_name.to_owned(),
+            requested_by_email: requested_by_email.map(str::to_owned),
+            parameters: input.parameters,
+            stages: input.stages,
+            checkpoint: None,
+            cancel_requested: false,
+            attempt_count: 0,
+            status_message: None,
+            created_at: now,
+            started_at: None,
+            updated_at: now,
+            finished_at: None,
+        };
+        self.jobs
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .push((tenant_id, job.clone()));
+        Ok(job)
+    }
+
+    async fn find_background_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Option<BackgroundJob>> {
+        Ok(self
+            .jobs
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .find(|(job_tenant_id, job)| *job_tenant_id == tenant_id && job.job_id == job_id)
+            .map(|(_, job)| job.clone()))
+    }
+
+    async fn list_background_jobs(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: Option<&str>,
+        limit: usize,
+    ) -> AppResult<Vec<BackgroundJob>> {
+        Ok(self
+            .jobs
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .rev()
+            .filter(|(job_tenant_id, job)| {
+                *job_tenant_id == tenant_id
+                    && requested_by_subject
+                        .is_none_or(|subject| job.requested_by_subject == subject)
+            })
+            .take(limit)
+            .map(|(_, job)| job.clone())
+            .collect())
+    }
+
+    async fn request_background_job_cancellation(
+        &self,
+        _tenant_id: TenantId,
+        _job_id: &str,
+    ) -> AppResult<Option<BackgroundJob>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn claim_background_job(
+        &self,
+        lease_token: &str,
+        lease_expires_at: DateTime<Utc>,
+    ) -> AppResult<Option<BackgroundJobLease>> {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|_| unreachable!());
+        let Some((tenant_id, job)) = jobs
+            .iter_mut()
+            .find(|(_, job)| job.status == BackgroundJobStatus::Queued)
+        else {
+            return Ok(None);
+        };
+
+        job.status = BackgroundJobStatus::Running;
+        job.attempt_count += 1;
+        Ok(Some(BackgroundJobLease {
+            tenant_id: *tenant_id,
+            job: job.clone(),
+            lease_token: lease_token.to_owned(),
+            expires_at: lease_expires_at,
+        }))
+    }
+
+    async fn save_background_job_progress(
+        &self,
+        _tenant_id: TenantId,
+        _job_id: &str,
+        _lease_token: &str,
+        _stages: &[BackgroundJobStage],
+        _checkpoint: Option<&Value>,
+        _lease_expires_at: DateTime<Utc>,
+    ) -> AppResult<Option<BackgroundJob>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn finish_background_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        _lease_token: &str,
+        status: BackgroundJobStatus,
+        status_message: Option<&str>,
+    ) -> AppResult<Option<BackgroundJob>> {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|_| unreachable!());
+        let Some((_, job)) = jobs
+            .iter_mut()
+            .find(|(job_tenant_id, job)| *job_tenant_id == tenant_id && job.job_id == job_id)
+        else {
+            return Ok(None);
+        };
+
+        job.status = status;
+        job.status_message = status_message.map(str::to_owned);
+        job.finished_at = Some(Utc::now());
+        Ok(Some(job.clone()))
+    }
+}
+
+struct FakeUserRepository {
+    users: Mutex<Vec<UserRecord>>,
+}
+
+#[async_trait]
+impl UserRepository for FakeUserRepository {
+    async fn find_by_email(&self, email: &str) -> AppResult<Option<UserRecord>> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .find(|user| user.email == email)
+            .cloned())
+    }
+
+    async fn find_by_id(&self, user_id: UserId) -> AppResult<Option<UserRecord>> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .find(|user| user.id == user_id)
+            .cloned())
+    }
+
+    async fn create(
+        &self,
+        _email: &str,
+        _password_hash: Option<&str>,
+        _email_verified: bool,
+    ) -> AppResult<UserId> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_password(&self, _user_id: UserId, _password_hash: &str) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn revoke_sessions(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn default_tenant_id(&self, _user_id: UserId) -> AppResult<Option<TenantId>> {
+        Ok(None)
+    }
+
+    async fn set_default_tenant_id(&self, _user_id: UserId, _tenant_id: TenantId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn record_failed_login(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn reset_failed_logins(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn mark_email_verified(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_display_name(
+        &self,
+        _user_id: UserId,
+        _tenant_id: TenantId,
+        _display_name: &str,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_email(&self, _user_id: UserId, _new_email: &str) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn enable_totp(
+        &self,
+        _user_id: UserId,
+        _totp_secret_enc: &[u8],
+        _recovery_codes_hash: &serde_json::Value,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn begin_totp_enrollment(
+        &self,
+        _user_id: UserId,
+        _totp_secret_enc: &[u8],
+        _recovery_codes_hash: &serde_json::Value,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn confirm_totp_enrollment(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn disable_totp(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_recovery_codes(
+        &self,
+        _user_id: UserId,
+        _recovery_codes_hash: &serde_json::Value,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn find_by_subject(&self, _subject: &str) -> AppResult<Option<UserRecord>> {
+        Ok(None)
+    }
+}
+
+#[derive(Default)]
+struct FakePersonalDataExportRepository {
+    parts: Mutex<Vec<(TenantId, String, String, PersonalDataExportPart)>>,
+}
+
+#[async_trait]
+impl PersonalDataExportRepository for FakePersonalDataExportRepository {
+    async fn save_export_part(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        subject: &str,
+        part: &PersonalDataExportPart,
+    ) -> AppResult<()> {
+        let mut parts = self.parts.lock().unwrap_or_else(|_| unreachable!());
+        parts.retain(|(_, stored_job_id, _, stored)| {
+            !(stored_job_id == job_id
+                && stored.section == part.section
+                && stored.entity_logical_name == part.entity_logical_name
+                && stored.item_offset == part.item_offset)
+        });
+        parts.push((
+            tenant_id,
+            job_id.to_owned(),
+            subject.to_owned(),
+            part.clone(),
+        ));
+        Ok(())
+    }
+
+    async fn list_export_parts(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Vec<PersonalDataExportPart>> {
+        Ok(self
+            .parts
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .filter(|(part_tenant_id, part_job_id, _, _)| {
+                *part_tenant_id == tenant_id && part_job_id == job_id
+            })
+            .map(|(_, _, _, part)| part.clone())
+            .collect())
+    }
+
+    async fn delete_exports_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<u64> {
+        let mut parts = self.parts.lock().unwrap_or_else(|_| unreachable!());
+        let count = parts.len();
+        parts.retain(|(part_tenant_id, _, part_subject, _)| {
+            !(*part_tenant_id == tenant_id && part_subject == subject)
+        });
+        Ok((count - parts.len()) as u64)
+    }
+
+    async fn list_auth_events_for_subject(
+        &self,
+        _subject: &str,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<PersonalAuthEventEntry>> {
+        Ok(["auth.login", "auth.logout"]
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|event_type| PersonalAuthEventEntry {
+                event_type: event_type.to_owned(),
+                outcome: "success".to_owned(),
+                ip_address: Some("203.0.113.7".to_owned()),
+                user_agent: None,
+                created_at: Utc::now(),
+            })
+            .collect())
+    }
+}
+
+struct Harness {
+    tenant_id: TenantId,
+    alice: UserIdentity,
+    background_job_service: BackgroundJobService,
+    repository: Arc<FakePersonalDataExportRepository>,
+    service: PersonalDataExportService,
+}
+
+fn build_harness() -> Harness {
+    let tenant_id = TenantId::new();
+    let user_id = UserId::new();
+    let alice = UserIdentity::new(
+        user_id.to_string(),
+        "Alice",
+        Some("alice@example.com".to_owned()),
+        tenant_id,
+    );
+    let grants = HashMap::from([(
+        (tenant_id, "admin".to_owned()),
+        vec![Permission::SecurityRoleManage],
+    )]);
+    let audit_repository = Arc::new(FakeAuditRepository);
+    let background_job_service = BackgroundJobService::new(
+        AuthorizationService::new(
+            Arc::new(FakeAuthorizationRepository { grants }),
+            audit_repository.clone(),
+        ),
+        Arc::new(FakeBackgroundJobRepository::default()),
+        audit_repository,
+    );
+    let users = Arc::new(FakeUserRepository {
+        users: Mutex::new(vec![UserRecord {
+            id: user_id,
+            email: "alice@example.com".to_owned(),
+            email_verified: true,
+            password_hash: Some("argon2-hash".to_owned()),
+            totp_enabled: false,
+            passkeys_enrolled: false,
+            totp_secret_enc: None,
+            recovery_codes_hash: None,
+            totp_pending_secret_enc: None,
+            recovery_codes_pending_hash: None,
+            failed_login_count: 0,
+            locked_until: None,
+            password_changed_at: None,
+            auth_sessions_revoked_after: None,
+            default_tenant_id: None,
+        }]),
+    });
+    let repository = Arc::new(FakePersonalDataExportRepository::default());
+    let service =
+        PersonalDataExportService::new(background_job_service.clone(), repository.clone(), users);
+
+    Harness {
+        tenant_id,
+        alice,
+        background_job_service,
+        repository,
+        service,
+    }
+}
+
+async fn run_export(harness: &Harness) -> BackgroundJob {
+    let lease = harness
+        .background_job_service
+        .claim_next_job()
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    let actor = &harness.alice;
+
+    let profile = harness
+        .service
+        .profile_part(actor)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let auth_events = harness
+        .service
+        .auth_events_part(actor, 0, 10)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record = RuntimeRecord::new("record-1", "contact", json!({ "name": "Alice" }))
+        .unwrap_or_else(|_| unreachable!());
+    let records = PersonalDataExportService::owned_records_part("contact", 0, &[record]);
+    for part in [&profile, &auth_events, &records, &records] {
+        harness
+            .service
+            .save_part(&lease, part)
+            .await
+            .unwrap_or_else(|_| unreachable!());
+    }
+
+    harness
+        .background_job_service
+        .finish_job(&lease, BackgroundJobStatus::Completed, None)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!())
+}
+
+#[tokio::test]
+async fn archives_are_assembled_for_their_requester_only() {
+    let harness = build_harness();
+    let job = harness
+        .service
+        .request_export(&harness.alice, vec!["contact".to_owned()])
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(job.stages.len(), 3);
+
+    let early = harness
+        .service
+        .download_archive(&harness.alice, &job.job_id)
+        .await;
+    assert!(matches!(early, Err(AppError::Conflict(_))));
+
+    run_export(&harness).await;
+    let archive = harness
+        .service
+        .download_archive(&harness.alice, &job.job_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let document = archive.document;
+    assert_eq!(
+        document["profile"]["account"]["email"],
+        json!("alice@example.com")
+    );
+    assert_eq!(document["profile"]["account"]["has_password"], json!(true));
+    assert!(!document.to_string().contains("argon2-hash"));
+    assert_eq!(document["auth_events"].as_array().map(Vec::len), Some(2));
+    assert_eq!(
+        document["owned_records"]["contact"],
+        json!([{ "record_id": "record-1", "data": { "name": "Alice" } }])
+    );
+
+    let admin = UserIdentity::new("admin", "Admin", None, harness.tenant_id);
+    let by_admin = harness.service.download_archive(&admin, &job.job_id).await;
+    assert!(matches!(by_admin, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn new_requests_wait_for_running_exports_and_replace_old_archives() {
+    let harness = build_harness();
+    harness
+        .service
+        .request_export(&harness.alice, Vec::new())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let duplicate = harness
+        .service
+        .request_export(&harness.alice, Vec::new())
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+
+    let finished = run_export(&harness).await;
+    assert_eq!(finished.status, BackgroundJobStatus::Completed);
+    assert_eq!(
+        harness
+            .repository
+            .parts
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .len(),
+        3
+    );
+
+    harness
+        .service
+        .request_export(&harness.alice, Vec::new())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(
+        harness
+            .repository
+            .parts
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+    assert_eq!(
+        PersonalDataExportSection::parse("owned_records").unwrap_or_else(|_| unreachable!()),
+        PersonalDataExportSection::OwnedRecords
+    );
+}
@@ -0,0 +1,20 @@
+//! Concurrency control for workspace publish runs.
+//!
+//! A publish run locks every entity, app and workflow it covers. Runs that
+//! overlap a locked component fail fast or queue an intent that runs once the
+//! locks are released. Reviewed draft fingerprints let a run detect drafts
+//! that changed after the admin looked at the diff.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    CreatePublishIntentInput, PUBLISH_DRAFT_CHANGED_PREFIX, PUBLISH_LOCKED_PREFIX,
+    PublishCoordinationRepository, PublishIntent, PublishIntentStatus, PublishLock,
+    PublishLockAcquisition, PublishLockLease, PublishTarget, PublishTargetScope, ReviewedDraft,
+    draft_fingerprint,
+};
+pub use service::PublishCoordinationService;
@@ -0,0 +1,259 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// Prefix of the conflict raised when publish locks are held by another run.
+///
+/// API clients map this prefix to a stable error code, so it must not change.
+pub const PUBLISH_LOCKED_PREFIX: &str = "publish locks are held by another run";
+
+/// Prefix of the conflict raised when a draft changed after its diff was reviewed.
+///
+/// API clients map this prefix to a stable error code, so it must not change.
+pub const PUBLISH_DRAFT_CHANGED_PREFIX: &str = "draft changed since the reviewed diff";
+
+/// Returns the SHA-256 fingerprint of a serialized draft.
+///
+/// Publish diffs hand this value to reviewers so a later publish can detect
+/// edits made after the review.
+pub fn draft_fingerprint<T: Serialize + ?Sized>(draft: &T) -> AppResult<String> {
+    let encoded = serde_json::to_vec(draft).map_err(|error| {
+        AppError::Internal(format!("failed to encode draft fingerprint input: {error}"))
+    })?;
+
+    let digest = Sha256::digest(encoded);
+    Ok(digest.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+/// Kind of workspace component a publish run covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PublishTargetScope {
+    /// Entity schema, forms and views.
+    Entity,
+    /// App navigation and bindings.
+    App,
+    /// Workflow definition.
+    Workflow,
+}
+
+impl PublishTargetScope {
+    /// Returns a stable storage value for this scope.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Entity => "entity",
+            Self::App => "app",
+            Self::Workflow => "workflow",
+        }
+    }
+
+    /// Parses a stored scope value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "entity" => Ok(Self::Entity),
+            "app" => Ok(Self::App),
+            "workflow" => Ok(Self::Workflow),
+            _ => Err(AppError::Validation(format!(
+                "unknown publish target scope '{value}'"
+            ))),
+        }
+    }
+}
+
+/// One workspace component selected for publish.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PublishTarget {
+    /// Component kind.
+    pub scope: PublishTargetScope,
+    /// Component logical name.
+    pub logical_name: String,
+}
+
+impl PublishTarget {
+    /// Creates a publish target.
+    #[must_use]
+    pub fn new(scope: PublishTargetScope, logical_name: impl Into<String>) -> Self {
+        Self {
+            scope,
+            logical_name: logical_name.into(),
+        }
+    }
+}
+
+/// Draft fingerprint an admin saw in the publish diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewedDraft {
+    /// Reviewed component.
+    pub target: PublishTarget,
+    /// Draft fingerprint returned by the diff.
+    pub draft_fingerprint: String,
+}
+
+/// Publish lock held on one component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishLock {
+    /// Locked component.
+    pub target: PublishTarget,
+    /// Subject running the publish.
+    pub holder_subject: String,
+    /// Acquisition timestamp.
+    pub acquired_at: DateTime<Utc>,
+    /// Time after which the lock is considered abandoned.
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Locks taken by one publish run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishLockLease {
+    /// Token identifying the run that holds the locks.
+    pub lock_token: String,
+    /// Subject running the publish.
+    pub holder_subject: String,
+    /// Locked components.
+    pub targets: Vec<PublishTarget>,
+    /// Time after which the locks are considered abandoned.
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Result of a publish lock request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublishLockAcquisition {
+    /// All requested locks are held by the returned lease.
+    Acquired(PublishLockLease),
+    /// Other runs hold some of the requested locks; nothing was locked.
+    Blocked(Vec<PublishLock>),
+}
+
+/// Lifecycle state of a queued publish intent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublishIntentStatus {
+    /// Waiting for the blocking locks to be released.
+    Queued,
+    /// Published successfully.
+    Completed,
+    /// Skipped because a reviewed draft changed while queued.
+    Conflicted,
+    /// Publish checks or the publish itself failed.
+    Failed,
+    /// Cancelled by the requester.
+    Cancelled,
+}
+
+impl PublishIntentStatus {
+    /// Returns a stable storage value for this status.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Completed => "completed",
+            Self::Conflicted => "conflicted",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    /// Parses a stored status value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "queued" => Ok(Self::Queued),
+            "completed" => Ok(Self::Completed),
+            "conflicted" => Ok(Self::Conflicted),
+            "failed" => Ok(Self::Failed),
+            "cancelled" => Ok(Self::Cancelled),
+            _ => Err(AppError::Validation(format!(
+                "unknown publish intent status '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Publish request waiting for locks held by another run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishIntent {
+    /// Stable intent identifier.
+    pub intent_id: String,
+    /// Subject that queued the intent.
+    pub requested_by_subject: String,
+    /// Display name of the requester, used when the intent runs.
+    pub requested_by_display_name: String,
+    /// Email of the requester, used when the intent runs.
+    pub requested_by_email: Option<String>,
+    /// Components to publish.
+    pub targets: Vec<PublishTarget>,
+    /// Draft fingerprints the requester reviewed.
+    pub reviewed_drafts: Vec<ReviewedDraft>,
+    /// Lifecycle state.
+    pub status: PublishIntentStatus,
+    /// Outcome detail for resolved intents.
+    pub status_message: Option<String>,
+    /// Queue timestamp.
+    pub created_at: DateTime<Utc>,
+    /// Resolution timestamp.
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+/// Input payload for queueing a publish intent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatePublishIntentInput {
+    /// Components to publish.
+    pub targets: Vec<PublishTarget>,
+    /// Draft fingerprints the requester reviewed.
+    pub reviewed_drafts: Vec<ReviewedDraft>,
+}
+
+/// Repository port for publish locks and queued publish intents.
+#[async_trait]
+pub trait PublishCoordinationRepository: Send + Sync {
+    /// Takes every lock of the lease or none of them.
+    ///
+    /// Expired locks are discarded first. Returns the unexpired locks of other
+    /// runs that block the lease; an empty list means the lease now holds all
+    /// of its targets.
+    async fn acquire_publish_locks(
+        &self,
+        tenant_id: TenantId,
+        lease: &PublishLockLease,
+    ) -> AppResult<Vec<PublishLock>>;
+
+    /// Releases every lock held by one lease.
+    async fn release_publish_locks(&self, tenant_id: TenantId, lock_token: &str) -> AppResult<()>;
+
+    /// Lists unexpired publish locks for a tenant.
+    async fn list_publish_locks(&self, tenant_id: TenantId) -> AppResult<Vec<PublishLock>>;
+
+    /// Stores a queued publish intent.
+    async fn create_publish_intent(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        requested_by_display_name: &str,
+        requested_by_email: Option<&str>,
+        input: CreatePublishIntentInput,
+    ) -> AppResult<PublishIntent>;
+
+    /// Lists publish intents for a tenant in queue order, oldest first.
+    async fn list_publish_intents(
+        &self,
+        tenant_id: TenantId,
+        include_resolved: bool,
+    ) -> AppResult<Vec<PublishIntent>>;
+
+    /// Finds one publish intent.
+    async fn find_publish_intent(
+        &self,
+        tenant_id: TenantId,
+        intent_id: &str,
+    ) -> AppResult<Option<PublishIntent>>;
+
+    /// Resolves a queued intent, returning `None` when no queued intent matches.
+    async fn resolve_publish_intent(
+        &self,
+        tenant_id: TenantId,
+        intent_id: &str,
+        status: PublishIntentStatus,
+        status_message: Option<&str>,
+    ) -> AppResult<Option<PublishIntent>>;
+}
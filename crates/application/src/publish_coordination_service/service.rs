@@ -0,0 +1,266 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::{
+    CreatePublishIntentInput, PublishCoordinationRepository, PublishIntent, PublishIntentStatus,
+    PublishLock, PublishLockAcquisition, PublishLockLease, PublishTarget,
+};
+
+/// Locks of a crashed publish run are ignored after this many seconds.
+const PUBLISH_LOCK_TTL_SECONDS: i64 = 5 * 60;
+
+/// Application service coordinating concurrent workspace publish runs.
+#[derive(Clone)]
+pub struct PublishCoordinationService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn PublishCoordinationRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl PublishCoordinationService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn PublishCoordinationRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Locks the selected components for one publish run, all or nothing.
+    pub async fn acquire_publish_locks(
+        &self,
+        actor: &UserIdentity,
+        targets: Vec<PublishTarget>,
+    ) -> AppResult<PublishLockAcquisition> {
+        self.require_publish_permission(actor).await?;
+
+        let lease = PublishLockLease {
+            lock_token: uuid::Uuid::new_v4().to_string(),
+            holder_subject: actor.subject().to_owned(),
+            targets: normalize_targets(targets)?,
+            expires_at: Utc::now() + chrono::Duration::seconds(PUBLISH_LOCK_TTL_SECONDS),
+        };
+        let blocking_locks = self
+            .repository
+            .acquire_publish_locks(actor.tenant_id(), &lease)
+            .await?;
+
+        if blocking_locks.is_empty() {
+            Ok(PublishLockAcquisition::Acquired(lease))
+        } else {
+            Ok(PublishLockAcquisition::Blocked(blocking_locks))
+        }
+    }
+
+    /// Releases the locks of a finished publish run.
+    pub async fn release_publish_locks(
+        &self,
+        actor: &UserIdentity,
+        lease: &PublishLockLease,
+    ) -> AppResult<()> {
+        self.repository
+            .release_publish_locks(actor.tenant_id(), lease.lock_token.as_str())
+            .await
+    }
+
+    /// Lists publish locks currently held in the actor tenant.
+    pub async fn list_publish_locks(&self, actor: &UserIdentity) -> AppResult<Vec<PublishLock>> {
+        self.require_read_permission(actor).await?;
+        self.repository.list_publish_locks(actor.tenant_id()).await
+    }
+
+    /// Queues a publish request to run once the blocking locks are released.
+    pub async fn queue_publish_intent(
+        &self,
+        actor: &UserIdentity,
+        input: CreatePublishIntentInput,
+    ) -> AppResult<PublishIntent> {
+        self.require_publish_permission(actor).await?;
+
+        let targets = normalize_targets(input.targets)?;
+        if targets.is_empty() {
+            return Err(AppError::Validation(
+                "publish intent requires at least one entity, app or workflow".to_owned(),
+            ));
+        }
+        if let Some(reviewed) = input
+            .reviewed_drafts
+            .iter()
+            .find(|reviewed| !targets.contains(&reviewed.target))
+        {
+            return Err(AppError::Validation(format!(
+                "reviewed {} '{}' is not part of the publish intent",
+                reviewed.target.scope.as_str(),
+                reviewed.target.logical_name
+            )));
+        }
+
+        let intent = self
+            .repository
+            .create_publish_intent(
+                actor.tenant_id(),
+                actor.subject(),
+                actor.display_name(),
+                actor.email(),
+                CreatePublishIntentInput {
+                    targets,
+                    reviewed_drafts: input.reviewed_drafts,
+                },
+            )
+            .await?;
+
+        self.append_intent_audit_event(actor, AuditAction::MetadataPublishIntentQueued, &intent)
+            .await?;
+
+        Ok(intent)
+    }
+
+    /// Lists publish intents of the actor tenant in queue order.
+    pub async fn list_publish_intents(
+        &self,
+        actor: &UserIdentity,
+        include_resolved: bool,
+    ) -> AppResult<Vec<PublishIntent>> {
+        self.require_read_permission(actor).await?;
+        self.repository
+            .list_publish_intents(actor.tenant_id(), include_resolved)
+            .await
+    }
+
+    /// Cancels a queued intent of the actor.
+    pub async fn cancel_publish_intent(
+        &self,
+        actor: &UserIdentity,
+        intent_id: &str,
+    ) -> AppResult<PublishIntent> {
+        self.require_publish_permission(actor).await?;
+
+        let intent = self
+            .repository
+            .find_publish_intent(actor.tenant_id(), intent_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("publish intent '{intent_id}' does not exist"))
+            })?;
+        if intent.requested_by_subject != actor.subject() {
+            return Err(AppError::Forbidden(format!(
+                "publish intent '{intent_id}' was queued by another subject"
+            )));
+        }
+
+        let intent = self
+            .resolve_queued_intent(actor, intent_id, PublishIntentStatus::Cancelled, None)
+            .await?;
+        self.append_intent_audit_event(actor, AuditAction::MetadataPublishIntentCancelled, &intent)
+            .await?;
+
+        Ok(intent)
+    }
+
+    /// Records the outcome of a queued intent that ran on behalf of its requester.
+    pub async fn resolve_publish_intent(
+        &self,
+        requester: &UserIdentity,
+        intent_id: &str,
+        status: PublishIntentStatus,
+        status_message: Option<&str>,
+    ) -> AppResult<PublishIntent> {
+        self.require_publish_permission(requester).await?;
+        self.resolve_queued_intent(requester, intent_id, status, status_message)
+            .await
+    }
+
+    async fn resolve_queued_intent(
+        &self,
+        actor: &UserIdentity,
+        intent_id: &str,
+        status: PublishIntentStatus,
+        status_message: Option<&str>,
+    ) -> AppResult<PublishIntent> {
+        self.repository
+            .resolve_publish_intent(actor.tenant_id(), intent_id, status, status_message)
+            .await?
+            .ok_or_else(|| {
+                AppError::Conflict(format!("publish intent '{intent_id}' is no longer queued"))
+            })
+    }
+
+    async fn require_publish_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataEntityCreate,
+            )
+            .await
+    }
+
+    async fn require_read_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::MetadataEntityRead,
+            )
+            .await
+    }
+
+    async fn append_intent_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        intent: &PublishIntent,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "publish_intent".to_owned(),
+                resource_id: intent.intent_id.clone(),
+                detail: Some(
+                    serde_json::json!({
+                        "targets": intent
+                            .targets
+                            .iter()
+                            .map(|target| format!(
+                                "{}:{}",
+                                target.scope.as_str(),
+                                target.logical_name
+                            ))
+                            .collect::<Vec<_>>(),
+                    })
+                    .to_string(),
+                ),
+            })
+            .await
+    }
+}
+
+fn normalize_targets(targets: Vec<PublishTarget>) -> AppResult<Vec<PublishTarget>> {
+    let mut normalized = BTreeSet::new();
+    for target in targets {
+        let logical_name = target.logical_name.trim();
+        if logical_name.is_empty() {
+            return Err(AppError::Validation(format!(
+                "publish {} logical name cannot be empty",
+                target.scope.as_str()
+            )));
+        }
+        normalized.insert(PublishTarget::new(target.scope, logical_name));
+    }
+
+    Ok(normalized.into_iter().collect())
+}
@@ -0,0 +1,422 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    TemporaryPermissionGrant,
+};
+
+use super::{
+    CreatePublishIntentInput, PublishCoordinationRepository, PublishCoordinationService,
+    PublishIntent, PublishIntentStatus, PublishLock, PublishLockAcquisition, PublishLockLease,
+    PublishTarget, PublishTargetScope, ReviewedDraft,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakePublishCoordinationRepository {
+    locks: Mutex<Vec<(String, PublishLock)>>,
+    intents: Mutex<Vec<PublishIntent>>,
+}
+
+#[async_trait]
+impl PublishCoordinationRepository for FakePublishCoordinationRepository {
+    async fn acquire_publish_locks(
+        &self,
+        _tenant_id: TenantId,
+        lease: &PublishLockLease,
+    ) -> AppResult<Vec<PublishLock>> {
+        let mut locks = self.locks.lock().await;
+        let now = Utc::now();
+        locks.retain(|(_, lock)| lock.expires_at > now);
+
+        let blocking: Vec<PublishLock> = locks
+            .iter()
+            .filter(|(token, lock)| {
+                token != &lease.lock_token && lease.targets.contains(&lock.target)
+            })
+            .map(|(_, lock)| lock.clone())
+            .collect();
+        if blocking.is_empty() {
+            for target in &lease.targets {
+                locks.push((
+                    lease.lock_token.clone(),
+                    PublishLock {
+                        target: target.clone(),
+                        holder_subject: lease.holder_subject.clone(),
+                        acquired_at: now,
+                        expires_at: lease.expires_at,
+                    },
+                ));
+            }
+        }
+        Ok(blocking)
+    }
+
+    async fn release_publish_locks(&self, _tenant_id: TenantId, lock_token: &str) -> AppResult<()> {
+        self.locks
+            .lock()
+            .await
+            .retain(|(token, _)| token != lock_token);
+        Ok(())
+    }
+
+    async fn list_publish_locks(&self, _tenant_id: TenantId) -> AppResult<Vec<PublishLock>> {
+        Ok(self
+            .locks
+            .lock()
+            .await
+            .iter()
+            .map(|(_, lock)| lock.clone())
+            .collect())
+    }
+
+    async fn create_publish_intent(
+        &self,
+        _tenant_id: TenantId,
+        requested_by_subject: &str,
+        requested_by_display_name: &str,
+        requested_by_email: Option<&str>,
+        input: CreatePublishIntentInput,
+    ) -> AppResult<PublishIntent> {
+        let mut intents = self.intents.lock().await;
+        let intent = PublishIntent {
+            intent_id: format!("intent-{}", intents.len() + 1),
+            requested_by_subject: requested_by_subject.to_owned(),
+            requested_by_display_name: requested_by_display_name.to_owned(),
+            requested_by_email: requested_by_email.map(str::to_owned),
+            targets: input.targets,
+            reviewed_drafts: input.reviewed_drafts,
+            status: PublishIntentStatus::Queued,
+            status_message: None,
+            created_at: Utc::now(),
+            resolved_at: None,
+        };
+        intents.push(intent.clone());
+        Ok(intent)
+    }
+
+    async fn list_publish_intents(
+        &self,
+        _tenant_id: TenantId,
+        include_resolved: bool,
+    ) -> AppResult<Vec<PublishIntent>> {
+        Ok(self
+            .intents
+            .lock()
+            .await
+            .iter()
+            .filter(|intent| include_resolved || intent.status == PublishIntentStatus::Queued)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_publish_intent(
+        &self,
+        _tenant_id: TenantId,
+        intent_id: &str,
+    ) -> AppResult<Option<PublishIntent>> {
+        Ok(self
+            .intents
+            .lock()
+            .await
+            .iter()
+            .find(|intent| intent.intent_id == intent_id)
+            .cloned())
+    }
+
+    async fn resolve_publish_intent(
+        &self,
+        _tenant_id: TenantId,
+        intent_id: &str,
+        status: PublishIntentStatus,
+        status_message: Option<&str>,
+    ) -> AppResult<Option<PublishIntent>> {
+        let mut intents = self.intents.lock().await;
+        let Some(intent) = intents.iter_mut().find(|intent| {
+            intent.intent_id == intent_id && intent.status == PublishIntentStatus::Queued
+        }) else {
+            return Ok(None);
+        };
+        intent.status = status;
+        intent.status_message = status_message.map(str::to_owned);
+        intent.resolved_at = Some(Utc::now());
+        Ok(Some(intent.clone()))
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    subjects: &[&str],
+    repository: Arc<FakePublishCoordinationRepository>,
+) -> (PublishCoordinationService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: subjects
+                .iter()
+                .map(|subject| {
+                    (
+                        (tenant_id, (*subject).to_owned()),
+                        vec![
+                            Permission::MetadataEntityCreate,
+                            Permission::MetadataEntityRead,
+                        ],
+                    )
+                })
+                .collect(),
+        }),
+        audit_repository.clone(),
+    );
+    let service = PublishCoordinationService::new(
+        authorization_service,
+        repository,
+        audit_repository.clone(),
+    );
+    (service, audit_repository)
+}
+
+fn entity(logical_name: &str) -> PublishTarget {
+    PublishTarget::new(PublishTargetScope::Entity, logical_name)
+}
+
+#[tokio::test]
+async fn overlapping_publish_runs_are_blocked_until_locks_are_released() {
+    let tenant_id = TenantId::new();
+    let (service, _) = build_service(
+        tenant_id,
+        &["alice", "bob"],
+        Arc::new(FakePublishCoordinationRepository::default()),
+    );
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    let PublishLockAcquisition::Acquired(lease) = service
+        .acquire_publish_locks(&alice, vec![entity(" contact "), entity("contact")])
+        .await
+        .unwrap_or_else(|_| unreachable!())
+    else {
+        unreachable!()
+    };
+    assert_eq!(lease.targets, vec![entity("contact")]);
+
+    let blocked = service
+        .acquire_publish_locks(&bob, vec![entity("account"), entity("contact")])
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let PublishLockAcquisition::Blocked(blocking_locks) = blocked else {
+        unreachable!()
+    };
+    assert_eq!(blocking_locks.len(), 1);
+    assert_eq!(blocking_locks[0].holder_subject, "alice");
+    assert_eq!(
+        service
+            .list_publish_locks(&bob)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .len(),
+        1
+    );
+
+    service
+        .release_publish_locks(&alice, &lease)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let retried = service
+        .acquire_publish_locks(&bob, vec![entity("account"), entity("contact")])
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(matches!(retried, PublishLockAcquisition::Acquired(_)));
+}
+
+#[tokio::test]
+async fn queued_intents_reject_reviewed_drafts_outside_their_targets() {
+    let tenant_id = TenantId::new();
+    let (service, audit_repository) = build_service(
+        tenant_id,
+        &["alice"],
+        Arc::new(FakePublishCoordinationRepository::default()),
+    );
+    let alice = actor(tenant_id, "alice");
+
+    let empty = service
+        .queue_publish_intent(
+            &alice,
+            CreatePublishIntentInput {
+                targets: Vec::new(),
+                reviewed_drafts: Vec::new(),
+            },
+        )
+        .await;
+    assert!(matches!(empty, Err(AppError::Validation(_))));
+
+    let stray_review = service
+        .queue_publish_intent(
+            &alice,
+            CreatePublishIntentInput {
+                targets: vec![entity("contact")],
+                reviewed_drafts: vec![ReviewedDraft {
+                    target: entity("account"),
+                    draft_fingerprint: "abc".to_owned(),
+                }],
+            },
+        )
+        .await;
+    assert!(matches!(stray_review, Err(AppError::Validation(_))));
+
+    let intent = service
+        .queue_publish_intent(
+            &alice,
+            CreatePublishIntentInput {
+                targets: vec![entity("contact")],
+                reviewed_drafts: vec![ReviewedDraft {
+                    target: entity("contact"),
+                    draft_fingerprint: "abc".to_owned(),
+                }],
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(intent.status, PublishIntentStatus::Queued);
+    assert_eq!(
+        audit_repository
+            .events
+            .lock()
+            .await
+            .iter()
+            .map(|event| event.action)
+            .collect::<Vec<_>>(),
+        vec![AuditAction::MetadataPublishIntentQueued]
+    );
+}
+
+#[tokio::test]
+async fn only_the_requester_can_cancel_a_queued_intent() {
+    let tenant_id = TenantId::new();
+    let (service, _) = build_service(
+        tenant_id,
+        &["alice", "bob"],
+        Arc::new(FakePublishCoordinationRepository::default()),
+    );
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    let intent = service
+        .queue_publish_intent(
+            &alice,
+            CreatePublishIntentInput {
+                targets: vec![entity("contact")],
+                reviewed_drafts: Vec::new(),
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let by_bob = service
+        .cancel_publish_intent(&bob, intent.intent_id.as_str())
+        .await;
+    assert!(matches!(by_bob, Err(AppError::Forbidden(_))));
+
+    let cancelled = service
+        .cancel_publish_intent(&alice, intent.intent_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(cancelled.status, PublishIntentStatus::Cancelled);
+
+    let again = service
+        .cancel_publish_intent(&alice, intent.intent_id.as_str())
+        .await;
+    assert!(matches!(again, Err(AppError::Conflict(_))));
+    assert!(
+        service
+            .list_publish_intents(&alice, false)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+}
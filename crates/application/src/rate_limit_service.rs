@@ -2,12 +2,13 @@
 //!
 //! Implements a sliding-window rate limiter backed by the `auth_rate_limits`
 //! database table. Follows OWASP Credential Stuffing Prevention cheat sheet
-//! recommendations for per-IP and per-endpoint throttling.
+//! recommendations for per-IP and per-endpoint throttling. High-frequency
+//! internal callers use an optional token bucket port instead.
 
 mod config;
 mod ports;
 mod service;
 
-pub use config::RateLimitRule;
-pub use ports::{AttemptInfo, RateLimitRepository};
+pub use config::{RateLimitRule, RateLimitStatus, TokenBucketRule};
+pub use ports::{AttemptInfo, RateLimitRepository, TokenBucketDecision, TokenBucketRepository};
 pub use service::RateLimitService;
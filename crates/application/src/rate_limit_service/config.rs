@@ -1,3 +1,5 @@
+use qryvanta_core::{AppError, AppResult};
+
 /// Configuration for a rate limit rule.
 #[derive(Debug, Clone)]
 pub struct RateLimitRule {
@@ -20,3 +22,56 @@ impl RateLimitRule {
         }
     }
 }
+
+/// Configuration for a token bucket rate limit rule.
+///
+/// Buckets start full at `capacity` tokens, every request takes one token,
+/// and tokens refill continuously at `refill_per_second` up to `capacity`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenBucketRule {
+    /// The route or category name (e.g., "worker_claim").
+    pub category: &'static str,
+    /// Maximum burst size in requests.
+    pub capacity: u32,
+    /// Sustained request rate in tokens per second.
+    pub refill_per_second: u32,
+}
+
+impl TokenBucketRule {
+    /// Creates a new token bucket rule.
+    #[must_use]
+    pub fn new(category: &'static str, capacity: u32, refill_per_second: u32) -> Self {
+        Self {
+            category,
+            capacity,
+            refill_per_second,
+        }
+    }
+}
+
+/// Quota state reported to clients after a rate limit check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// Whether the checked request may proceed.
+    pub allowed: bool,
+    /// Requests allowed per window, or bucket capacity.
+    pub limit: u32,
+    /// Requests left before the caller is throttled.
+    pub remaining: u32,
+    /// Seconds until the quota is fully available again.
+    pub reset_after_seconds: u64,
+}
+
+impl RateLimitStatus {
+    /// Converts a rejected status into `AppError::RateLimited`.
+    pub fn ensure_allowed(&self) -> AppResult<()> {
+        if self.allowed {
+            return Ok(());
+        }
+
+        Err(AppError::RateLimited(format!(
+            "too many requests, retry after {} seconds",
+            self.reset_after_seconds.max(1)
+        )))
+    }
+}
@@ -28,3 +28,26 @@ pub struct AttemptInfo {
     /// When the current window started.
     pub window_started_at: DateTime<Utc>,
 }
+
+/// Repository port for token bucket rate limiting.
+#[async_trait]
+pub trait TokenBucketRepository: Send + Sync {
+    /// Refills the bucket for the given key and takes one token when available.
+    async fn take_token(
+        &self,
+        key: &str,
+        capacity: u32,
+        refill_per_second: u32,
+    ) -> AppResult<TokenBucketDecision>;
+}
+
+/// Outcome of one token bucket take attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenBucketDecision {
+    /// Whether a token was taken and the request may proceed.
+    pub allowed: bool,
+    /// Whole tokens left in the bucket after this attempt.
+    pub remaining_tokens: u32,
+    /// Milliseconds until the next token is available when rejected.
+    pub retry_after_ms: u64,
+}
@@ -2,22 +2,36 @@ use std::sync::Arc;
 
 use chrono::Utc;
 
-use qryvanta_core::{AppError, AppResult};
+use qryvanta_core::AppResult;
 
-use super::config::RateLimitRule;
-use super::ports::RateLimitRepository;
+use super::config::{RateLimitRule, RateLimitStatus, TokenBucketRule};
+use super::ports::{RateLimitRepository, TokenBucketRepository};
 
 /// Application service for rate limiting.
 #[derive(Clone)]
 pub struct RateLimitService {
     repository: Arc<dyn RateLimitRepository>,
+    token_bucket_repository: Option<Arc<dyn TokenBucketRepository>>,
 }
 
 impl RateLimitService {
     /// Creates a new rate limit service.
     #[must_use]
     pub fn new(repository: Arc<dyn RateLimitRepository>) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            token_bucket_repository: None,
+        }
+    }
+
+    /// Adds token bucket rate limiting for high-frequency callers.
+    #[must_use]
+    pub fn with_token_bucket_repository(
+        mut self,
+        token_bucket_repository: Arc<dyn TokenBucketRepository>,
+    ) -> Self {
+        self.token_bucket_repository = Some(token_bucket_repository);
+        self
     }
 
     /// Checks whether the given key is within the rate limit.
@@ -28,19 +42,84 @@ impl RateLimitService {
     /// The key should be formatted as `"{category}:{identifier}"` where
     /// identifier is typically an IP address or email.
     pub async fn check_rate_limit(&self, rule: &RateLimitRule, key: &str) -> AppResult<()> {
+        self.rate_limit_status(rule, key).await?.ensure_allowed()
+    }
+
+    /// Records an attempt and returns the resulting window quota.
+    ///
+    /// Unlike [`Self::check_rate_limit`], a rejected attempt is reported in
+    /// the returned status so callers can surface retry metadata.
+    pub async fn rate_limit_status(
+        &self,
+        rule: &RateLimitRule,
+        key: &str,
+    ) -> AppResult<RateLimitStatus> {
         let composite_key = format!("{}:{key}", rule.category);
         let info = self
             .repository
             .record_attempt(&composite_key, rule.window_seconds)
             .await?;
 
-        if info.attempt_count > rule.max_attempts {
-            return Err(AppError::RateLimited(
-                "too many requests, please try again later".to_owned(),
-            ));
+        let window_ends_at =
+            info.window_started_at + chrono::Duration::seconds(rule.window_seconds);
+        let reset_after_seconds = (window_ends_at - Utc::now()).num_seconds().max(0);
+        let limit = u32::try_from(rule.max_attempts.max(0)).unwrap_or(0);
+        let used = u32::try_from(info.attempt_count.max(0)).unwrap_or(0);
+
+        Ok(RateLimitStatus {
+            allowed: info.attempt_count <= rule.max_attempts,
+            limit,
+            remaining: limit.saturating_sub(used),
+            reset_after_seconds: u64::try_from(reset_after_seconds).unwrap_or(0),
+        })
+    }
+
+    /// Takes one token from the bucket identified by rule category and key.
+    ///
+    /// Returns `Err(AppError::RateLimited)` when the bucket is empty. Rules
+    /// with zero capacity and services without a token bucket repository
+    /// allow every request.
+    pub async fn check_token_bucket(&self, rule: &TokenBucketRule, key: &str) -> AppResult<()> {
+        match self.token_bucket_status(rule, key).await? {
+            Some(status) => status.ensure_allowed(),
+            None => Ok(()),
+        }
+    }
+
+    /// Takes one token and returns the resulting bucket quota.
+    ///
+    /// Returns `None` when token buckets are disabled for the rule.
+    pub async fn token_bucket_status(
+        &self,
+        rule: &TokenBucketRule,
+        key: &str,
+    ) -> AppResult<Option<RateLimitStatus>> {
+        let Some(token_bucket_repository) = &self.token_bucket_repository else {
+            return Ok(None);
+        };
+
+        if rule.capacity == 0 {
+            return Ok(None);
         }
 
-        Ok(())
+        let composite_key = format!("{}:{key}", rule.category);
+        let decision = token_bucket_repository
+            .take_token(&composite_key, rule.capacity, rule.refill_per_second)
+            .await?;
+
+        let reset_after_seconds = if decision.allowed {
+            let missing_tokens = u64::from(rule.capacity.saturating_sub(decision.remaining_tokens));
+            missing_tokens.div_ceil(u64::from(rule.refill_per_second.max(1)))
+        } else {
+            decision.retry_after_ms.div_ceil(1_000)
+        };
+
+        Ok(Some(RateLimitStatus {
+            allowed: decision.allowed,
+            limit: rule.capacity,
+            remaining: decision.remaining_tokens,
+            reset_after_seconds,
+        }))
     }
 
     /// Removes expired rate limit entries. Intended for periodic cleanup.
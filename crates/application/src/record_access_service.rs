@@ -0,0 +1,20 @@
+//! Access requests for runtime records the requester cannot read.
+//!
+//! A subject limited to owned records can ask for access to a record owned by
+//! someone else. The request is routed to the record owner, and subjects
+//! holding `runtime.record_access.approve` can decide any request. Approval
+//! creates a time-boxed record share that lets the requester read the record
+//! until the share expires or is revoked.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    DEFAULT_RECORD_SHARE_HOURS, NewRecordAccessRequest, RECORD_ACCESS_DECIDED_APPROVAL_KEY,
+    RECORD_ACCESS_REQUESTED_APPROVAL_KEY, RecordAccessRepository, RecordAccessRequest,
+    RecordAccessRequestQuery, RecordAccessRequestStatus, RecordShare, RequestRecordAccessInput,
+};
+pub use service::RecordAccessService;
@@ -0,0 +1,260 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::{RelationBehavior, RelationCascadeResult, RuntimeRecordWorkflowEventInput};
+
+/// Approval key used for `approval_event_received` notifications on new access requests.
+pub const RECORD_ACCESS_REQUESTED_APPROVAL_KEY: &str = "record_access_requested";
+
+/// Approval key used for `approval_event_received` notifications on access decisions.
+pub const RECORD_ACCESS_DECIDED_APPROVAL_KEY: &str = "record_access_decided";
+
+/// Share duration applied when a request does not ask for one.
+pub const DEFAULT_RECORD_SHARE_HOURS: u16 = 24;
+
+/// Lifecycle status of a record access request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordAccessRequestStatus {
+    /// Awaiting a decision.
+    Pending,
+    /// Approved; a record share was created.
+    Approved,
+    /// Rejected by an approver or withdrawn by the requester.
+    Rejected,
+}
+
+impl RecordAccessRequestStatus {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Approved => "approved",
+            Self::Rejected => "rejected",
+        }
+    }
+
+    /// Parses a storage or transport value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "pending" => Ok(Self::Pending),
+            "approved" => Ok(Self::Approved),
+            "rejected" => Ok(Self::Rejected),
+            _ => Err(AppError::Validation(format!(
+                "unknown record access request status '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Input payload for requesting access to a runtime record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestRecordAccessInput {
+    /// Business justification shown to approvers.
+    pub reason: String,
+    /// Requested share duration in hours.
+    pub duration_hours: Option<u16>,
+}
+
+/// Stored record access request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordAccessRequest {
+    /// Stable request identifier.
+    pub request_id: String,
+    /// Owning tenant.
+    pub tenant_id: TenantId,
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Runtime record identifier.
+    pub record_id: String,
+    /// Subject asking for access.
+    pub requested_by_subject: String,
+    /// Business justification.
+    pub reason: String,
+    /// Requested share duration in hours.
+    pub duration_hours: u16,
+    /// Record owner the request was routed to.
+    pub routed_to_subject: String,
+    /// Current lifecycle status.
+    pub status: RecordAccessRequestStatus,
+    /// Request timestamp.
+    pub requested_at: DateTime<Utc>,
+    /// Subject that approved or rejected the request.
+    pub decided_by_subject: Option<String>,
+    /// Decision timestamp.
+    pub decided_at: Option<DateTime<Utc>>,
+    /// Share created on approval.
+    pub share_id: Option<String>,
+}
+
+/// Access request to persist, with its approver notification.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewRecordAccessRequest {
+    /// Stable request identifier.
+    pub request_id: String,
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Runtime record identifier.
+    pub record_id: String,
+    /// Business justification.
+    pub reason: String,
+    /// Requested share duration in hours.
+    pub duration_hours: u16,
+    /// Record owner the request is routed to.
+    pub routed_to_subject: String,
+    /// Workflow trigger event enqueued with the request.
+    pub notification: Option<RuntimeRecordWorkflowEventInput>,
+}
+
+/// Filters for listing record access requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecordAccessRequestQuery {
+    /// Restricts to one entity.
+    pub entity_logical_name: Option<String>,
+    /// Restricts to one record.
+    pub record_id: Option<String>,
+    /// Restricts to one status.
+    pub status: Option<RecordAccessRequestStatus>,
+    /// Restricts to requests made by or routed to this subject.
+    pub involving_subject: Option<String>,
+}
+
+/// Time-boxed grant letting one subject read one runtime record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordShare {
+    /// Stable share identifier.
+    pub share_id: String,
+    /// Owning tenant.
+    pub tenant_id: TenantId,
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Runtime record identifier.
+    pub record_id: String,
+    /// Subject the record is shared with.
+    pub subject: String,
+    /// Request that produced the share.
+    pub request_id: String,
+    /// Subject that approved the share.
+    pub granted_by_subject: String,
+    /// Share creation timestamp.
+    pub created_at: DateTime<Utc>,
+    /// Time after which the share no longer grants access.
+    pub expires_at: DateTime<Utc>,
+    /// Subject that revoked the share early.
+    pub revoked_by_subject: Option<String>,
+    /// Revocation timestamp.
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl RecordShare {
+    /// Returns whether the share grants access at `now`.
+    #[must_use]
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+}
+
+/// Repository port for record access requests and shares.
+#[async_trait]
+pub trait RecordAccessRepository: Send + Sync {
+    /// Returns the owner subject of a runtime record, or `None` when it does not exist.
+    async fn find_record_owner(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<String>>;
+
+    /// Persists a pending request and enqueues its notification.
+    ///
+    /// Returns a conflict when the subject already has a pending request for the record.
+    async fn create_request(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        request: NewRecordAccessRequest,
+    ) -> AppResult<RecordAccessRequest>;
+
+    /// Lists requests matching the query, newest first.
+    async fn list_requests(
+        &self,
+        tenant_id: TenantId,
+        query: RecordAccessRequestQuery,
+    ) -> AppResult<Vec<RecordAccessRequest>>;
+
+    /// Finds one request by identifier.
+    async fn find_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+    ) -> AppResult<Option<RecordAccessRequest>>;
+
+    /// Lists relations whose share behavior cascades from the parent entity.
+    async fn list_share_cascades(
+        &self,
+        tenant_id: TenantId,
+        target_entity_logical_name: &str,
+    ) -> AppResult<Vec<RelationBehavior>>;
+
+    /// Approves a pending request and creates its record share atomically.
+    ///
+    /// Child records reached through `share_cascades` receive shares linked to
+    /// the parent share in the same transaction. Returns `None` when the request
+    /// is no longer pending.
+    async fn approve_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+        decided_by_subject: &str,
+        share_expires_at: DateTime<Utc>,
+        share_cascades: &[RelationBehavior],
+        notification: Option<RuntimeRecordWorkflowEventInput>,
+    ) -> AppResult<Option<(RecordAccessRequest, RecordShare, Vec<RelationCascadeResult>)>>;
+
+    /// Rejects a pending request.
+    ///
+    /// Returns `None` when the request is no longer pending.
+    async fn reject_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+        decided_by_subject: &str,
+        notification: Option<RuntimeRecordWorkflowEventInput>,
+    ) -> AppResult<Option<RecordAccessRequest>>;
+
+    /// Returns whether the subject holds an active share for the record.
+    async fn has_active_share(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        subject: &str,
+    ) -> AppResult<bool>;
+
+    /// Lists active shares for a record.
+    async fn list_active_shares(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RecordShare>>;
+
+    /// Finds one share by identifier.
+    async fn find_share(
+        &self,
+        tenant_id: TenantId,
+        share_id: &str,
+    ) -> AppResult<Option<RecordShare>>;
+
+    /// Revokes an active share together with the shares cascaded from it.
+    ///
+    /// Returns `None` when the share is already revoked or expired.
+    async fn revoke_share(
+        &self,
+        tenant_id: TenantId,
+        share_id: &str,
+        revoked_by_subject: &str,
+    ) -> AppResult<Option<RecordShare>>;
+}
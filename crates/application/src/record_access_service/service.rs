@@ -0,0 +1,498 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission, WorkflowTrigger};
+use uuid::Uuid;
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService, RuntimeRecordWorkflowEventInput};
+
+use super::ports::{
+    DEFAULT_RECORD_SHARE_HOURS, NewRecordAccessRequest, RECORD_ACCESS_DECIDED_APPROVAL_KEY,
+    RECORD_ACCESS_REQUESTED_APPROVAL_KEY, RecordAccessRepository, RecordAccessRequest,
+    RecordAccessRequestQuery, RecordAccessRequestStatus, RecordShare, RequestRecordAccessInput,
+};
+
+const MAX_RECORD_SHARE_HOURS: u16 = 720;
+const MAX_REASON_LENGTH: usize = 500;
+
+/// Application service for record access requests and time-boxed record shares.
+#[derive(Clone)]
+pub struct RecordAccessService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn RecordAccessRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl RecordAccessService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn RecordAccessRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Requests read access to a runtime record owned by another subject.
+    pub async fn request_access(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        input: RequestRecordAccessInput,
+    ) -> AppResult<RecordAccessRequest> {
+        if self
+            .authorization_service
+            .has_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::RuntimeRecordRead,
+            )
+            .await?
+        {
+            return Err(AppError::Conflict(format!(
+                "subject '{}' can already read every runtime record",
+                actor.subject()
+            )));
+        }
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::RuntimeRecordReadOwn,
+            )
+            .await?;
+
+        let reason = input.reason.trim().to_owned();
+        if reason.is_empty() {
+            return Err(AppError::Validation(
+                "record access request reason must not be empty".to_owned(),
+            ));
+        }
+        if reason.chars().count() > MAX_REASON_LENGTH {
+            return Err(AppError::Validation(format!(
+                "record access request reason must be at most {MAX_REASON_LENGTH} characters"
+            )));
+        }
+        let duration_hours = validate_share_hours(input.duration_hours)?;
+
+        let owner_subject = self
+            .repository
+            .find_record_owner(actor.tenant_id(), entity_logical_name, record_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "runtime record '{}' does not exist for entity '{}'",
+                    record_id, entity_logical_name
+                ))
+            })?;
+        if owner_subject == actor.subject() {
+            return Err(AppError::Conflict(format!(
+                "subject '{}' already owns runtime record '{}'",
+                actor.subject(),
+                record_id
+            )));
+        }
+        if self
+            .repository
+            .has_active_share(
+                actor.tenant_id(),
+                entity_logical_name,
+                record_id,
+                actor.subject(),
+            )
+            .await?
+        {
+            return Err(AppError::Conflict(format!(
+                "runtime record '{}' is already shared with subject '{}'",
+                record_id,
+                actor.subject()
+            )));
+        }
+
+        let request_id = Uuid::new_v4().to_string();
+        let notification = RuntimeRecordWorkflowEventInput {
+            trigger: WorkflowTrigger::ApprovalEventReceived {
+                approval_key: RECORD_ACCESS_REQUESTED_APPROVAL_KEY.to_owned(),
+            },
+            record_id: record_id.to_owned(),
+            payload: serde_json::json!({
+                "event": "approval_event_received",
+                "approval_key": RECORD_ACCESS_REQUESTED_APPROVAL_KEY,
+                "request_id": request_id,
+                "entity_logical_name": entity_logical_name,
+                "record_id": record_id,
+                "requested_by_subject": actor.subject(),
+                "routed_to_subject": owner_subject,
+                "reason": reason,
+                "duration_hours": duration_hours,
+            }),
+            emitted_by_subject: actor.subject().to_owned(),
+        };
+
+        let request = self
+            .repository
+            .create_request(
+                actor.tenant_id(),
+                actor.subject(),
+                NewRecordAccessRequest {
+                    request_id,
+                    entity_logical_name: entity_logical_name.to_owned(),
+                    record_id: record_id.to_owned(),
+                    reason,
+                    duration_hours,
+                    routed_to_subject: owner_subject,
+                    notification: Some(notification),
+                },
+            )
+            .await?;
+
+        self.append_request_audit_event(actor, AuditAction::RuntimeRecordAccessRequested, &request)
+            .await?;
+
+        Ok(request)
+    }
+
+    /// Lists access requests visible to the actor.
+    ///
+    /// Approvers see every request; other subjects see requests they made or
+    /// that were routed to them.
+    pub async fn list_requests(
+        &self,
+        actor: &UserIdentity,
+        mut query: RecordAccessRequestQuery,
+    ) -> AppResult<Vec<RecordAccessRequest>> {
+        if !self.is_access_approver(actor).await? {
+            query.involving_subject = Some(actor.subject().to_owned());
+        }
+
+        self.repository
+            .list_requests(actor.tenant_id(), query)
+            .await
+    }
+
+    /// Approves a pending request and shares the record with its requester.
+    pub async fn approve_request(
+        &self,
+        actor: &UserIdentity,
+        request_id: &str,
+        duration_hours: Option<u16>,
+    ) -> AppResult<RecordAccessRequest> {
+        let request = self.find_pending_request(actor, request_id).await?;
+        if request.requested_by_subject == actor.subject() {
+            return Err(AppError::Forbidden(format!(
+                "record access request '{}' must be approved by a subject other than its requester",
+                request_id
+            )));
+        }
+        self.require_decision_rights(actor, &request).await?;
+
+        let duration_hours = match duration_hours {
+            Some(hours) => validate_share_hours(Some(hours))?,
+            None => request.duration_hours,
+        };
+        let share_expires_at = Utc::now() + Duration::hours(i64::from(duration_hours));
+        let notification = decision_notification(
+            actor,
+            &request,
+            RecordAccessRequestStatus::Approved,
+            Some(share_expires_at.to_rfc3339()),
+        );
+
+        let share_cascades = self
+            .repository
+            .list_share_cascades(actor.tenant_id(), request.entity_logical_name.as_str())
+            .await?;
+        let (approved, share, cascades) = self
+            .repository
+            .approve_request(
+                actor.tenant_id(),
+                request_id,
+                actor.subject(),
+                share_expires_at,
+                &share_cascades,
+                Some(notification),
+            )
+            .await?
+            .ok_or_else(|| {
+                AppError::Conflict(format!(
+                    "record access request '{}' was decided concurrently",
+                    request_id
+                ))
+            })?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::RuntimeRecordAccessApproved,
+                resource_type: "runtime_record_access_request".to_owned(),
+                resource_id: approved.request_id.clone(),
+                detail: Some(
+                    serde_json::json!({
+                        "entity_logical_name": approved.entity_logical_name,
+                        "record_id": approved.record_id,
+                        "requested_by_subject": approved.requested_by_subject,
+                        "share_id": share.share_id,
+                        "share_expires_at": share.expires_at.to_rfc3339(),
+                        "cascades": cascades
+                            .iter()
+                            .map(|cascade| serde_json::json!({
+                                "entity_logical_name": cascade.entity_logical_name,
+                                "field_logical_name": cascade.field_logical_name,
+                                "shared_records": cascade.affected_records,
+                            }))
+                            .collect::<Vec<_>>(),
+                    })
+                    .to_string(),
+                ),
+            })
+            .await?;
+
+        Ok(approved)
+    }
+
+    /// Rejects a pending request without sharing the record.
+    ///
+    /// Requesters may withdraw their own requests.
+    pub async fn reject_request(
+        &self,
+        actor: &UserIdentity,
+        request_id: &str,
+    ) -> AppResult<RecordAccessRequest> {
+        let request = self.find_pending_request(actor, request_id).await?;
+        if request.requested_by_subject != actor.subject() {
+            self.require_decision_rights(actor, &request).await?;
+        }
+
+        let notification =
+            decision_notification(actor, &request, RecordAccessRequestStatus::Rejected, None);
+        let rejected = self
+            .repository
+            .reject_request(
+                actor.tenant_id(),
+                request_id,
+                actor.subject(),
+                Some(notification),
+            )
+            .await?
+            .ok_or_else(|| {
+                AppError::Conflict(format!(
+                    "record access request '{}' was decided concurrently",
+                    request_id
+                ))
+            })?;
+
+        self.append_request_audit_event(actor, AuditAction::RuntimeRecordAccessRejected, &rejected)
+            .await?;
+
+        Ok(rejected)
+    }
+
+    /// Lists active shares for a record the actor owns or may approve access to.
+    pub async fn list_record_shares(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RecordShare>> {
+        self.require_record_share_rights(actor, entity_logical_name, record_id)
+            .await?;
+
+        self.repository
+            .list_active_shares(actor.tenant_id(), entity_logical_name, record_id)
+            .await
+    }
+
+    /// Revokes an active record share before it expires.
+    pub async fn revoke_share(
+        &self,
+        actor: &UserIdentity,
+        share_id: &str,
+    ) -> AppResult<RecordShare> {
+        let share = self
+            .repository
+            .find_share(actor.tenant_id(), share_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("record share '{share_id}' does not exist"))
+            })?;
+        self.require_record_share_rights(
+            actor,
+            share.entity_logical_name.as_str(),
+            share.record_id.as_str(),
+        )
+        .await?;
+
+        let revoked = self
+            .repository
+            .revoke_share(actor.tenant_id(), share_id, actor.subject())
+            .await?
+            .ok_or_else(|| {
+                AppError::Conflict(format!("record share '{share_id}' is no longer active"))
+            })?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::RuntimeRecordShareRevoked,
+                resource_type: "runtime_record_share".to_owned(),
+                resource_id: revoked.share_id.clone(),
+                detail: Some(
+                    serde_json::json!({
+                        "entity_logical_name": revoked.entity_logical_name,
+                        "record_id": revoked.record_id,
+                        "subject": revoked.subject,
+                    })
+                    .to_string(),
+                ),
+            })
+            .await?;
+
+        Ok(revoked)
+    }
+
+    async fn find_pending_request(
+        &self,
+        actor: &UserIdentity,
+        request_id: &str,
+    ) -> AppResult<RecordAccessRequest> {
+        let request = self
+            .repository
+            .find_request(actor.tenant_id(), request_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "record access request '{request_id}' does not exist"
+                ))
+            })?;
+        if request.status != RecordAccessRequestStatus::Pending {
+            return Err(AppError::Conflict(format!(
+                "record access request '{}' is already {}",
+                request_id,
+                request.status.as_str()
+            )));
+        }
+
+        Ok(request)
+    }
+
+    async fn require_decision_rights(
+        &self,
+        actor: &UserIdentity,
+        request: &RecordAccessRequest,
+    ) -> AppResult<()> {
+        self.require_record_share_rights(
+            actor,
+            request.entity_logical_name.as_str(),
+            request.record_id.as_str(),
+        )
+        .await
+    }
+
+    async fn require_record_share_rights(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<()> {
+        if self.is_access_approver(actor).await? {
+            return Ok(());
+        }
+
+        let owner_subject = self
+            .repository
+            .find_record_owner(actor.tenant_id(), entity_logical_name, record_id)
+            .await?;
+        if owner_subject.as_deref() == Some(actor.subject()) {
+            return Ok(());
+        }
+
+        Err(AppError::Forbidden(format!(
+            "subject '{}' may not decide access to runtime record '{}'",
+            actor.subject(),
+            record_id
+        )))
+    }
+
+    async fn is_access_approver(&self, actor: &UserIdentity) -> AppResult<bool> {
+        self.authorization_service
+            .has_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::RuntimeRecordAccessApprove,
+            )
+            .await
+    }
+
+    async fn append_request_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        request: &RecordAccessRequest,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "runtime_record_access_request".to_owned(),
+                resource_id: request.request_id.clone(),
+                detail: Some(
+                    serde_json::json!({
+                        "entity_logical_name": request.entity_logical_name,
+                        "record_id": request.record_id,
+                        "requested_by_subject": request.requested_by_subject,
+                        "routed_to_subject": request.routed_to_subject,
+                        "duration_hours": request.duration_hours,
+                    })
+                    .to_string(),
+                ),
+            })
+            .await
+    }
+}
+
+fn validate_share_hours(duration_hours: Option<u16>) -> AppResult<u16> {
+    let duration_hours = duration_hours.unwrap_or(DEFAULT_RECORD_SHARE_HOURS);
+    if duration_hours == 0 || duration_hours > MAX_RECORD_SHARE_HOURS {
+        return Err(AppError::Validation(format!(
+            "record share duration must be between 1 and {MAX_RECORD_SHARE_HOURS} hours"
+        )));
+    }
+
+    Ok(duration_hours)
+}
+
+fn decision_notification(
+    actor: &UserIdentity,
+    request: &RecordAccessRequest,
+    status: RecordAccessRequestStatus,
+    share_expires_at: Option<String>,
+) -> RuntimeRecordWorkflowEventInput {
+    RuntimeRecordWorkflowEventInput {
+        trigger: WorkflowTrigger::ApprovalEventReceived {
+            approval_key: RECORD_ACCESS_DECIDED_APPROVAL_KEY.to_owned(),
+        },
+        record_id: request.record_id.clone(),
+        payload: serde_json::json!({
+            "event": "approval_event_received",
+            "approval_key": RECORD_ACCESS_DECIDED_APPROVAL_KEY,
+            "request_id": request.request_id,
+            "entity_logical_name": request.entity_logical_name,
+            "record_id": request.record_id,
+            "requested_by_subject": request.requested_by_subject,
+            "status": status.as_str(),
+            "decided_by_subject": actor.subject(),
+            "share_expires_at": share_expires_at,
+        }),
+        emitted_by_subject: actor.subject().to_owned(),
+    }
+}
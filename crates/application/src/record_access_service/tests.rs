@@ -0,0 +1,745 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{
+    AuditAction, Permission, RelationCascadeBehavior, RelationDeleteBehavior, WorkflowTrigger,
+};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RelationBehavior,
+    RelationCascadeResult, RuntimeFieldGrant, RuntimeRecordWorkflowEventInput,
+    TemporaryPermissionGrant,
+};
+
+use super::{
+    NewRecordAccessRequest, RECORD_ACCESS_DECIDED_APPROVAL_KEY,
+    RECORD_ACCESS_REQUESTED_APPROVAL_KEY, RecordAccessRepository, RecordAccessRequest,
+    RecordAccessRequestQuery, RecordAccessRequestStatus, RecordAccessService, RecordShare,
+    RequestRecordAccessInput,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+struct FakeChildRecord {
+    entity_logical_name: String,
+    record_id: String,
+    field_logical_name: String,
+    parent_record_id: String,
+}
+
+#[derive(Default)]
+struct FakeRecordAccessRepository {
+    owners: HashMap<(String, String), String>,
+    children: Vec<FakeChildRecord>,
+    relation_behaviors: Vec<RelationBehavior>,
+    requests: Mutex<Vec<RecordAccessRequest>>,
+    shares: Mutex<Vec<RecordShare>>,
+    cascaded_from: Mutex<HashMap<String, String>>,
+    notifications: Mutex<Vec<RuntimeRecordWorkflowEventInput>>,
+}
+
+#[async_trait]
+impl RecordAccessRepository for FakeRecordAccessRepository {
+    async fn find_record_owner(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<String>> {
+        Ok(self
+            .owners
+            .get(&(entity_logical_name.to_owned(), record_id.to_owned()))
+            .cloned())
+    }
+
+    async fn create_request(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        request: NewRecordAccessRequest,
+    ) -> AppResult<RecordAccessRequest> {
+        let mut requests = self.requests.lock().await;
+        if requests.iter().any(|existing| {
+            existing.record_id == request.record_id
+                && existing.requested_by_subject == requested_by_subject
+                && existing.status == RecordAccessRequestStatus::Pending
+        }) {
+            return Err(AppError::Conflict("pending request exists".to_owned()));
+        }
+
+        let stored = RecordAccessRequest {
+            request_id: request.request_id,
+            tenant_id,
+            entity_logical_name: request.entity_logical_name,
+            record_id: request.record_id,
+            requested_by_subject: requested_by_subject.to_owned(),
+            reason: request.reason,
+            duration_hours: request.duration_hours,
+            routed_to_subject: request.routed_to_subject,
+            status: RecordAccessRequestStatus::Pending,
+            requested_at: Utc::now(),
+            decided_by_subject: None,
+            decided_at: None,
+            share_id: None,
+        };
+        requests.push(stored.clone());
+        if let Some(notification) = request.notification {
+            self.notifications.lock().await.push(notification);
+        }
+        Ok(stored)
+    }
+
+    async fn list_requests(
+        &self,
+        tenant_id: TenantId,
+        query: RecordAccessRequestQuery,
+    ) -> AppResult<Vec<RecordAccessRequest>> {
+        Ok(self
+            .requests
+            .lock()
+            .await
+            .iter()
+            .filter(|request| request.tenant_id == tenant_id)
+            .filter(|request| {
+                query.involving_subject.as_deref().is_none_or(|subject| {
+                    request.requested_by_subject == subject || request.routed_to_subject == subject
+                })
+            })
+            .filter(|request| query.status.is_none_or(|status| request.status == status))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+    ) -> AppResult<Option<RecordAccessRequest>> {
+        Ok(self
+            .requests
+            .lock()
+            .await
+            .iter()
+            .find(|request| request.tenant_id == tenant_id && request.request_id == request_id)
+            .cloned())
+    }
+
+    async fn list_share_cascades(
+        &self,
+        _tenant_id: TenantId,
+        target_entity_logical_name: &str,
+    ) -> AppResult<Vec<RelationBehavior>> {
+        Ok(self
+            .relation_behaviors
+            .iter()
+            .filter(|behavior| {
+                behavior.target_entity_logical_name == target_entity_logical_name
+                    && behavior.share_behavior.cascades()
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn approve_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+        decided_by_subject: &str,
+        share_expires_at: DateTime<Utc>,
+        share_cascades: &[RelationBehavior],
+        notification: Option<RuntimeRecordWorkflowEventInput>,
+    ) -> AppResult<Option<(RecordAccessRequest, RecordShare, Vec<RelationCascadeResult>)>> {
+        let mut requests = self.requests.lock().await;
+        let Some(request) = requests.iter_mut().find(|request| {
+            request.tenant_id == tenant_id
+                && request.request_id == request_id
+                && request.status == RecordAccessRequestStatus::Pending
+        }) else {
+            return Ok(None);
+        };
+
+        let mut shares = self.shares.lock().await;
+        let share = RecordShare {
+            share_id: format!("share-{}", shares.len() + 1),
+            tenant_id,
+            entity_logical_name: request.entity_logical_name.clone(),
+            record_id: request.record_id.clone(),
+            subject: request.requested_by_subject.clone(),
+            request_id: request.request_id.clone(),
+            granted_by_subject: decided_by_subject.to_owned(),
+            created_at: Utc::now(),
+            expires_at: share_expires_at,
+            revoked_by_subject: None,
+            revoked_at: None,
+        };
+        shares.push(share.clone());
+
+        let parent_owner = self.owners.get(&(
+            request.entity_logical_name.clone(),
+            request.record_id.clone(),
+        ));
+        let mut cascaded_from = self.cascaded_from.lock().await;
+        let mut cascades = Vec::new();
+        for cascade in share_cascades {
+            let mut affected_records = 0;
+            for child in self.children.iter().filter(|child| {
+                child.entity_logical_name == cascade.entity_logical_name
+                    && child.field_logical_name == cascade.field_logical_name
+                    && child.parent_record_id == request.record_id
+            }) {
+                let child_owner = self
+                    .owners
+                    .get(&(child.entity_logical_name.clone(), child.record_id.clone()));
+                if cascade.share_behavior == RelationCascadeBehavior::CascadeUserOwned
+                    && child_owner != parent_owner
+                {
+                    continue;
+                }
+
+                let child_share = RecordShare {
+                    share_id: format!("share-{}", shares.len() + 1),
+                    entity_logical_name: child.entity_logical_name.clone(),
+                    record_id: child.record_id.clone(),
+                    ..share.clone()
+                };
+                cascaded_from.insert(child_share.share_id.clone(), share.share_id.clone());
+                shares.push(child_share);
+                affected_records += 1;
+            }
+            cascades.push(RelationCascadeResult {
+                entity_logical_name: cascade.entity_logical_name.clone(),
+                field_logical_name: cascade.field_logical_name.clone(),
+                affected_records,
+            });
+        }
+
+        request.status = RecordAccessRequestStatus::Approved;
+        request.decided_by_subject = Some(decided_by_subject.to_owned());
+        request.decided_at = Some(Utc::now());
+        request.share_id = Some(share.share_id.clone());
+        if let Some(notification) = notification {
+            self.notifications.lock().await.push(notification);
+        }
+
+        Ok(Some((request.clone(), share, cascades)))
+    }
+
+    async fn reject_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+        decided_by_subject: &str,
+        notification: Option<RuntimeRecordWorkflowEventInput>,
+    ) -> AppResult<Option<RecordAccessRequest>> {
+        let mut requests = self.requests.lock().await;
+        let Some(request) = requests.iter_mut().find(|request| {
+            request.tenant_id == tenant_id
+                && request.request_id == request_id
+                && request.status == RecordAccessRequestStatus::Pending
+        }) else {
+            return Ok(None);
+        };
+
+        request.status = RecordAccessRequestStatus::Rejected;
+        request.decided_by_subject = Some(decided_by_subject.to_owned());
+        request.decided_at = Some(Utc::now());
+        if let Some(notification) = notification {
+            self.notifications.lock().await.push(notification);
+        }
+
+        Ok(Some(request.clone()))
+    }
+
+    async fn has_active_share(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        subject: &str,
+    ) -> AppResult<bool> {
+        let now = Utc::now();
+        Ok(self.shares.lock().await.iter().any(|share| {
+            share.tenant_id == tenant_id
+                && share.entity_logical_name == entity_logical_name
+                && share.record_id == record_id
+                && share.subject == subject
+                && share.is_active(now)
+        }))
+    }
+
+    async fn list_active_shares(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RecordShare>> {
+        let now = Utc::now();
+        Ok(self
+            .shares
+            .lock()
+            .await
+            .iter()
+            .filter(|share| {
+                share.tenant_id == tenant_id
+                    && share.entity_logical_name == entity_logical_name
+                    && share.record_id == record_id
+                    && share.is_active(now)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_share(
+        &self,
+        tenant_id: TenantId,
+        share_id: &str,
+    ) -> AppResult<Option<RecordShare>> {
+        Ok(self
+            .shares
+            .lock()
+            .await
+            .iter()
+            .find(|share| share.tenant_id == tenant_id && share.share_id == share_id)
+            .cloned())
+    }
+
+    async fn revoke_share(
+        &self,
+        tenant_id: TenantId,
+        share_id: &str,
+        revoked_by_subject: &str,
+    ) -> AppResult<Option<RecordShare>> {
+        let now = Utc::now();
+        let mut shares = self.shares.lock().await;
+        let Some(share) = shares.iter_mut().find(|share| {
+            share.tenant_id == tenant_id && share.share_id == share_id && share.is_active(now)
+        }) else {
+            return Ok(None);
+        };
+
+        share.revoked_by_subject = Some(revoked_by_subject.to_owned());
+        share.revoked_at = Some(now);
+        let revoked = share.clone();
+
+        let cascaded_from = self.cascaded_from.lock().await;
+        for child_share in shares.iter_mut().filter(|child_share| {
+            cascaded_from.get(&child_share.share_id).map(String::as_str) == Some(share_id)
+                && child_share.is_active(now)
+        }) {
+            child_share.revoked_by_subject = Some(revoked_by_subject.to_owned());
+            child_share.revoked_at = Some(now);
+        }
+
+        Ok(Some(revoked))
+    }
+}
+
+fn build_service(
+    tenant_id: TenantId,
+) -> (
+    RecordAccessService,
+    Arc<FakeRecordAccessRepository>,
+    Arc<FakeAuditRepository>,
+) {
+    let grants = HashMap::from([
+        (
+            (tenant_id, "alice".to_owned()),
+            vec![
+                Permission::RuntimeRecordReadOwn,
+                Permission::RuntimeRecordWriteOwn,
+            ],
+        ),
+        (
+            (tenant_id, "bob".to_owned()),
+            vec![Permission::RuntimeRecordReadOwn],
+        ),
+        (
+            (tenant_id, "carol".to_owned()),
+            vec![Permission::RuntimeRecordReadOwn],
+        ),
+        (
+            (tenant_id, "dana".to_owned()),
+            vec![
+                Permission::RuntimeRecordRead,
+                Permission::RuntimeRecordAccessApprove,
+            ],
+        ),
+    ]);
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let repository = Arc::new(FakeRecordAccessRepository {
+        owners: HashMap::from([(
+            ("account".to_owned(), "record-1".to_owned()),
+            "alice".to_owned(),
+        )]),
+        ..FakeRecordAccessRepository::default()
+    });
+    let service = RecordAccessService::new(
+        AuthorizationService::new(
+            Arc::new(FakeAuthorizationRepository { grants }),
+            audit_repository.clone(),
+        ),
+        repository.clone(),
+        audit_repository.clone(),
+    );
+
+    (service, repository, audit_repository)
+}
+
+fn access_input(reason: &str) -> RequestRecordAccessInput {
+    RequestRecordAccessInput {
+        reason: reason.to_owned(),
+        duration_hours: Some(8),
+    }
+}
+
+#[tokio::test]
+async fn access_request_is_routed_to_owner_and_approval_creates_time_boxed_share() {
+    let tenant_id = TenantId::new();
+    let (service, repository, audit_repository) = build_service(tenant_id);
+    let alice = UserIdentity::new("alice", "Alice", None, tenant_id);
+    let bob = UserIdentity::new("bob", "Bob", None, tenant_id);
+
+    let request = service
+        .request_access(
+            &bob,
+            "account",
+            "record-1",
+            access_input(" quarterly review "),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(request.routed_to_subject, "alice");
+    assert_eq!(request.reason, "quarterly review");
+    assert_eq!(request.duration_hours, 8);
+    assert!(matches!(
+        repository.notifications.lock().await.first(),
+        Some(RuntimeRecordWorkflowEventInput {
+            trigger: WorkflowTrigger::ApprovalEventReceived { approval_key },
+            ..
+        }) if approval_key == RECORD_ACCESS_REQUESTED_APPROVAL_KEY
+    ));
+
+    let duplicate = service
+        .request_access(&bob, "account", "record-1", access_input("again"))
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+
+    let self_approval = service
+        .approve_request(&bob, request.request_id.as_str(), None)
+        .await;
+    assert!(matches!(self_approval, Err(AppError::Forbidden(_))));
+
+    let approved = service
+        .approve_request(&alice, request.request_id.as_str(), None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(approved.status, RecordAccessRequestStatus::Approved);
+    assert_eq!(approved.decided_by_subject.as_deref(), Some("alice"));
+
+    let shares = service
+        .list_record_shares(&alice, "account", "record-1")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(shares.len(), 1);
+    assert_eq!(shares[0].subject, "bob");
+    let share_minutes = (shares[0].expires_at - shares[0].created_at).num_minutes();
+    assert!((8 * 60 - 1..=8 * 60).contains(&share_minutes));
+    assert!(matches!(
+        repository.notifications.lock().await.last(),
+        Some(RuntimeRecordWorkflowEventInput {
+            trigger: WorkflowTrigger::ApprovalEventReceived { approval_key },
+            ..
+        }) if approval_key == RECORD_ACCESS_DECIDED_APPROVAL_KEY
+    ));
+
+    let revoked = service
+        .revoke_share(&alice, shares[0].share_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(revoked.revoked_at.is_some());
+    assert!(
+        !repository
+            .has_active_share(tenant_id, "account", "record-1", "bob")
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+
+    let actions: Vec<AuditAction> = audit_repository
+        .events
+        .lock()
+        .await
+        .iter()
+        .map(|event| event.action)
+        .collect();
+    assert_eq!(
+        actions,
+        vec![
+            AuditAction::RuntimeRecordAccessRequested,
+            AuditAction::RuntimeRecordAccessApproved,
+            AuditAction::RuntimeRecordShareRevoked,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn access_requests_reject_invalid_requesters_and_unrelated_deciders() {
+    let tenant_id = TenantId::new();
+    let (service, _, _) = build_service(tenant_id);
+    let alice = UserIdentity::new("alice", "Alice", None, tenant_id);
+    let bob = UserIdentity::new("bob", "Bob", None, tenant_id);
+    let carol = UserIdentity::new("carol", "Carol", None, tenant_id);
+    let dana = UserIdentity::new("dana", "Dana", None, tenant_id);
+
+    let owner_request = service
+        .request_access(&alice, "account", "record-1", access_input("mine"))
+        .await;
+    assert!(matches!(owner_request, Err(AppError::Conflict(_))));
+
+    let full_reader_request = service
+        .request_access(&dana, "account", "record-1", access_input("audit"))
+        .await;
+    assert!(matches!(full_reader_request, Err(AppError::Conflict(_))));
+
+    let blank_reason = service
+        .request_access(&bob, "account", "record-1", access_input("   "))
+        .await;
+    assert!(matches!(blank_reason, Err(AppError::Validation(_))));
+
+    let missing_record = service
+        .request_access(&bob, "account", "record-404", access_input("review"))
+        .await;
+    assert!(matches!(missing_record, Err(AppError::NotFound(_))));
+
+    let request = service
+        .request_access(&bob, "account", "record-1", access_input("review"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let carol_visible = service
+        .list_requests(&carol, RecordAccessRequestQuery::default())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(carol_visible.is_empty());
+    let carol_decision = service
+        .reject_request(&carol, request.request_id.as_str())
+        .await;
+    assert!(matches!(carol_decision, Err(AppError::Forbidden(_))));
+
+    let approver_visible = service
+        .list_requests(&dana, RecordAccessRequestQuery::default())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(approver_visible.len(), 1);
+    let rejected = service
+        .reject_request(&dana, request.request_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(rejected.status, RecordAccessRequestStatus::Rejected);
+
+    let decided_again = service
+        .approve_request(&alice, request.request_id.as_str(), None)
+        .await;
+    assert!(matches!(decided_again, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn approval_cascades_shares_to_child_records_and_revocation_follows() {
+    let tenant_id = TenantId::new();
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let repository = Arc::new(FakeRecordAccessRepository {
+        owners: HashMap::from([
+            (
+                ("account".to_owned(), "record-1".to_owned()),
+                "alice".to_owned(),
+            ),
+            (("contact".to_owned(), "c-1".to_owned()), "alice".to_owned()),
+            (("contact".to_owned(), "c-2".to_owned()), "carol".to_owned()),
+            (("task".to_owned(), "t-1".to_owned()), "carol".to_owned()),
+        ]),
+        children: vec![
+            FakeChildRecord {
+                entity_logical_name: "contact".to_owned(),
+                record_id: "c-1".to_owned(),
+                field_logical_name: "account_id".to_owned(),
+                parent_record_id: "record-1".to_owned(),
+            },
+            FakeChildRecord {
+                entity_logical_name: "contact".to_owned(),
+                record_id: "c-2".to_owned(),
+                field_logical_name: "account_id".to_owned(),
+                parent_record_id: "record-1".to_owned(),
+            },
+            FakeChildRecord {
+                entity_logical_name: "task".to_owned(),
+                record_id: "t-1".to_owned(),
+                field_logical_name: "account_id".to_owned(),
+                parent_record_id: "record-1".to_owned(),
+            },
+        ],
+        relation_behaviors: vec![
+            RelationBehavior {
+                entity_logical_name: "contact".to_owned(),
+                field_logical_name: "account_id".to_owned(),
+                target_entity_logical_name: "account".to_owned(),
+                assign_behavior: RelationCascadeBehavior::None,
+                share_behavior: RelationCascadeBehavior::CascadeUserOwned,
+                delete_behavior: RelationDeleteBehavior::Restrict,
+            },
+            RelationBehavior {
+                entity_logical_name: "task".to_owned(),
+                field_logical_name: "account_id".to_owned(),
+                target_entity_logical_name: "account".to_owned(),
+                assign_behavior: RelationCascadeBehavior::CascadeAll,
+                share_behavior: RelationCascadeBehavior::None,
+                delete_behavior: RelationDeleteBehavior::Restrict,
+            },
+        ],
+        ..FakeRecordAccessRepository::default()
+    });
+    let grants = HashMap::from([
+        (
+            (tenant_id, "alice".to_owned()),
+            vec![Permission::RuntimeRecordReadOwn],
+        ),
+        (
+            (tenant_id, "bob".to_owned()),
+            vec![Permission::RuntimeRecordReadOwn],
+        ),
+    ]);
+    let service = RecordAccessService::new(
+        AuthorizationService::new(
+            Arc::new(FakeAuthorizationRepository { grants }),
+            audit_repository.clone(),
+        ),
+        repository.clone(),
+        audit_repository.clone(),
+    );
+    let alice = UserIdentity::new("alice", "Alice", None, tenant_id);
+    let bob = UserIdentity::new("bob", "Bob", None, tenant_id);
+
+    let request = service
+        .request_access(&bob, "account", "record-1", access_input("renewal"))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    service
+        .approve_request(&alice, request.request_id.as_str(), None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    for (entity, record_id, expected) in [
+        ("contact", "c-1", true),
+        ("contact", "c-2", false),
+        ("task", "t-1", false),
+    ] {
+        let shared = repository
+            .has_active_share(tenant_id, entity, record_id, "bob")
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(
+            shared, expected,
+            "unexpected share state for {entity}/{record_id}"
+        );
+    }
+    let approval_detail = audit_repository
+        .events
+        .lock()
+        .await
+        .iter()
+        .find(|event| event.action == AuditAction::RuntimeRecordAccessApproved)
+        .and_then(|event| event.detail.clone())
+        .unwrap_or_default();
+    assert!(approval_detail.contains("\"shared_records\":1"));
+
+    let parent_share = service
+        .list_record_shares(&alice, "account", "record-1")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    service
+        .revoke_share(&alice, parent_share[0].share_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(
+        !repository
+            .has_active_share(tenant_id, "contact", "c-1", "bob")
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+}
@@ -0,0 +1,58 @@
+//! CSV record imports with saved mapping templates and recurring imports.
+//!
+//! A mapping template saves which file columns feed which fields of one
+//! entity. Files are uploaded directly or fetched on a schedule from an
+//! HTTPS or SFTP location; a fetched file whose SHA-256 matches the last
+//! imported one is skipped. Every row becomes a record created with the
+//! importing user's access, and failed scheduled runs are mailed to the
+//! schedule owner.
+
+mod csv;
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use qryvanta_core::{AppResult, UserIdentity};
+use qryvanta_domain::{PublishedEntitySchema, RuntimeRecord};
+
+use crate::MetadataService;
+
+pub use ports::{
+    CreateRecordImportScheduleInput, DueRecordImportSchedule, NewRecordImportSchedule,
+    RecordImportColumnMapping, RecordImportFrequency, RecordImportMappingTemplate,
+    RecordImportRepository, RecordImportRowError, RecordImportRunStatus,
+    RecordImportRuntimeService, RecordImportSchedule, RecordImportScheduleRun,
+    RecordImportSourceFetcher, RecordImportSourceKind, RecordImportSummary,
+    SaveRecordImportMappingTemplateInput,
+};
+pub use service::{
+    RECORD_IMPORT_MAX_FILE_BYTES, RECORD_IMPORT_MAX_ROWS, RECORD_IMPORT_MAX_SCHEDULES_PER_TENANT,
+    RecordImportService,
+};
+
+#[async_trait]
+impl RecordImportRuntimeService for MetadataService {
+    async fn latest_published_schema_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        self.latest_published_schema_unchecked(actor, entity_logical_name)
+            .await
+    }
+
+    async fn create_runtime_record(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        data: Value,
+    ) -> AppResult<RuntimeRecord> {
+        self.create_runtime_record(actor, entity_logical_name, data)
+            .await
+    }
+}
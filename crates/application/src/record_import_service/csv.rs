@@ -0,0 +1,299 @@
+use std::collections::{HashMap, HashSet};
+
+use serde_json::{Map, Number, Value};
+
+use qryvanta_core::{AppError, AppResult};
+use qryvanta_domain::{EntityFieldDefinition, FieldType, PublishedEntitySchema};
+
+use super::RecordImportMappingTemplate;
+
+/// Separator between values of a multi-choice cell.
+const MULTI_CHOICE_SEPARATOR: char = ';';
+
+/// Data row of a parsed CSV file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct CsvRow {
+    /// One-based line the row starts on.
+    pub(super) line_number: usize,
+    /// Cell values in column order.
+    pub(super) cells: Vec<String>,
+}
+
+/// Parsed CSV file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct CsvTable {
+    /// Trimmed column headers.
+    pub(super) header: Vec<String>,
+    /// Data rows; blank lines are skipped.
+    pub(super) rows: Vec<CsvRow>,
+}
+
+/// Column of the file imported into one field.
+#[derive(Debug, Clone)]
+pub(super) struct ImportColumn {
+    index: usize,
+    field_logical_name: String,
+    field_type: FieldType,
+}
+
+/// Parses an RFC 4180 CSV file with a header row.
+///
+/// Accepts UTF-8 with or without a byte order mark, `\n` or `\r\n` line
+/// endings and quoted cells spanning lines.
+pub(super) fn parse_csv(content: &[u8], max_rows: usize) -> AppResult<CsvTable> {
+    let text = std::str::from_utf8(content)
+        .map_err(|_| AppError::Validation("import file must be UTF-8 encoded".to_owned()))?;
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+    let mut records: Vec<CsvRow> = Vec::new();
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut cell_was_quoted = false;
+    let mut line_number = 1;
+    let mut row_line_number = 1;
+    let mut characters = text.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        if in_quotes {
+            match character {
+                '"' if characters.peek() == Some(&'"') => {
+                    characters.next();
+                    cell.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line_number += 1;
+                    cell.push('\n');
+                }
+                _ => cell.push(character),
+            }
+            continue;
+        }
+
+        match character {
+            '"' if cell.is_empty() && !cell_was_quoted => {
+                in_quotes = true;
+                cell_was_quoted = true;
+            }
+            '"' => {
+                return Err(AppError::Validation(format!(
+                    "import file has an unexpected quote on line {line_number}"
+                )));
+            }
+            ',' => {
+                cells.push(std::mem::take(&mut cell));
+                cell_was_quoted = false;
+            }
+            '\r' if characters.peek() == Some(&'\n') => {}
+            '\n' => {
+                cells.push(std::mem::take(&mut cell));
+                cell_was_quoted = false;
+                push_row(&mut records, std::mem::take(&mut cells), row_line_number);
+                line_number += 1;
+                row_line_number = line_number;
+            }
+            _ if cell_was_quoted => {
+                return Err(AppError::Validation(format!(
+                    "import file has text after a closing quote on line {line_number}"
+                )));
+            }
+            _ => cell.push(character),
+        }
+
+        if records.len() > max_rows + 1 {
+            return Err(AppError::Validation(format!(
+                "import file may contain at most {max_rows} rows"
+            )));
+        }
+    }
+
+    if in_quotes {
+        return Err(AppError::Validation(format!(
+            "import file has an unterminated quote starting on line {row_line_number}"
+        )));
+    }
+    if !cell.is_empty() || cell_was_quoted || !cells.is_empty() {
+        cells.push(cell);
+        push_row(&mut records, cells, row_line_number);
+    }
+
+    let mut records = records.into_iter();
+    let header = records
+        .next()
+        .ok_or_else(|| AppError::Validation("import file is empty".to_owned()))?
+        .cells
+        .into_iter()
+        .map(|column| column.trim().to_owned())
+        .collect::<Vec<_>>();
+    let mut seen = HashSet::new();
+    for column in &header {
+        if column.is_empty() {
+            return Err(AppError::Validation(
+                "import file header contains an empty column name".to_owned(),
+            ));
+        }
+        if !seen.insert(column.as_str()) {
+            return Err(AppError::Validation(format!(
+                "import file header repeats column '{column}'"
+            )));
+        }
+    }
+
+    let rows = records.collect::<Vec<_>>();
+    if rows.len() > max_rows {
+        return Err(AppError::Validation(format!(
+            "import file may contain at most {max_rows} rows"
+        )));
+    }
+
+    Ok(CsvTable { header, rows })
+}
+
+fn push_row(records: &mut Vec<CsvRow>, cells: Vec<String>, line_number: usize) {
+    let is_blank = cells.len() == 1 && cells[0].trim().is_empty();
+    if !is_blank {
+        records.push(CsvRow { line_number, cells });
+    }
+}
+
+/// Resolves which file columns feed which fields.
+///
+/// With a template only mapped columns are imported and each must be
+/// present in the header. Without one, every header must name a field.
+pub(super) fn resolve_import_columns(
+    header: &[String],
+    template: Option<&RecordImportMappingTemplate>,
+    schema: &PublishedEntitySchema,
+) -> AppResult<Vec<ImportColumn>> {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| (field.logical_name().as_str(), field))
+        .collect::<HashMap<_, _>>();
+    let column_index = |source_column: &str| {
+        header
+            .iter()
+            .position(|column| column == source_column)
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "import file is missing mapped column '{source_column}'"
+                ))
+            })
+    };
+
+    let pairs = match template {
+        Some(template) => template
+            .column_mappings
+            .iter()
+            .map(|mapping| {
+                Ok((
+                    column_index(mapping.source_column.as_str())?,
+                    mapping.field_logical_name.as_str(),
+                ))
+            })
+            .collect::<AppResult<Vec<_>>>()?,
+        None => header
+            .iter()
+            .enumerate()
+            .map(|(index, column)| (index, column.as_str()))
+            .collect(),
+    };
+
+    pairs
+        .into_iter()
+        .map(|(index, field_logical_name)| {
+            let field = fields.get(field_logical_name).ok_or_else(|| {
+                AppError::Validation(format!(
+                    "import column '{}' does not match a published field of entity '{}'",
+                    header[index],
+                    schema.entity().logical_name().as_str()
+                ))
+            })?;
+            import_column(index, field)
+        })
+        .collect()
+}
+
+fn import_column(index: usize, field: &EntityFieldDefinition) -> AppResult<ImportColumn> {
+    if field.field_type() == FieldType::ManyToMany {
+        return Err(AppError::Validation(format!(
+            "many_to_many field '{}' cannot be imported",
+            field.logical_name().as_str()
+        )));
+    }
+
+    Ok(ImportColumn {
+        index,
+        field_logical_name: field.logical_name().as_str().to_owned(),
+        field_type: field.field_type(),
+    })
+}
+
+/// Converts one row into record data; empty cells leave their field unset.
+pub(super) fn row_record_data(row: &CsvRow, columns: &[ImportColumn]) -> AppResult<Value> {
+    let mut data = Map::new();
+    for column in columns {
+        let cell = row
+            .cells
+            .get(column.index)
+            .map(|cell| cell.trim())
+            .unwrap_or_default();
+        if cell.is_empty() {
+            continue;
+        }
+
+        let value = cell_value(column.field_type, cell).map_err(|message| {
+            AppError::Validation(format!(
+                "column for field '{}': {message}",
+                column.field_logical_name
+            ))
+        })?;
+        data.insert(column.field_logical_name.clone(), value);
+    }
+
+    Ok(Value::Object(data))
+}
+
+fn cell_value(field_type: FieldType, cell: &str) -> Result<Value, String> {
+    match field_type {
+        FieldType::Text
+        | FieldType::Date
+        | FieldType::DateTime
+        | FieldType::Relation
+        | FieldType::ManyToMany => Ok(Value::String(cell.to_owned())),
+        FieldType::Number => cell
+            .parse::<i64>()
+            .map(Value::from)
+            .or_else(|_| {
+                cell.parse::<f64>()
+                    .ok()
+                    .and_then(Number::from_f64)
+                    .map(Value::Number)
+                    .ok_or(())
+            })
+            .map_err(|()| format!("'{cell}' is not a number")),
+        FieldType::Boolean => match cell.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "1" => Ok(Value::Bool(true)),
+            "false" | "no" | "0" => Ok(Value::Bool(false)),
+            _ => Err(format!("'{cell}' is not a boolean")),
+        },
+        FieldType::Choice => choice_value(cell),
+        FieldType::MultiChoice => cell
+            .split(MULTI_CHOICE_SEPARATOR)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(choice_value)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        FieldType::Json => {
+            serde_json::from_str(cell).map_err(|error| format!("invalid JSON: {error}"))
+        }
+    }
+}
+
+fn choice_value(cell: &str) -> Result<Value, String> {
+    cell.parse::<i64>()
+        .map(Value::from)
+        .map_err(|_| format!("'{cell}' is not an option value"))
+}
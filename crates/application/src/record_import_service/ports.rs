@@ -0,0 +1,395 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde_json::Value;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{PublishedEntitySchema, RuntimeRecord};
+
+/// Maps one source file column onto an entity field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordImportColumnMapping {
+    /// Header of the source column.
+    pub source_column: String,
+    /// Field receiving the column values.
+    pub field_logical_name: String,
+}
+
+/// Saved column mapping reused by uploads and scheduled imports of one entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordImportMappingTemplate {
+    /// Stable template identifier.
+    pub template_id: String,
+    /// Entity the template imports into.
+    pub entity_logical_name: String,
+    /// Display name, unique per entity.
+    pub name: String,
+    /// Column mappings in source order.
+    pub column_mappings: Vec<RecordImportColumnMapping>,
+    /// Subject that last saved the template.
+    pub updated_by_subject: String,
+    /// Last change.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input payload for creating or updating a mapping template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveRecordImportMappingTemplateInput {
+    /// Entity the template imports into.
+    pub entity_logical_name: String,
+    /// Display name, unique per entity.
+    pub name: String,
+    /// Column mappings in source order.
+    pub column_mappings: Vec<RecordImportColumnMapping>,
+}
+
+/// Remote location a recurring import fetches its file from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordImportSourceKind {
+    /// File downloaded with an HTTPS `GET`.
+    Https,
+    /// File downloaded over SFTP.
+    Sftp,
+}
+
+impl RecordImportSourceKind {
+    /// Returns a stable storage value for this source kind.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Https => "https",
+            Self::Sftp => "sftp",
+        }
+    }
+
+    /// Parses a stored or transport source kind value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "https" => Ok(Self::Https),
+            "sftp" => Ok(Self::Sftp),
+            _ => Err(AppError::Validation(format!(
+                "unknown record import source kind '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Cadence of a recurring import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordImportFrequency {
+    /// Runs once an hour.
+    Hourly,
+    /// Runs once a day.
+    Daily,
+    /// Runs once a week.
+    Weekly,
+}
+
+impl RecordImportFrequency {
+    /// Returns a stable storage value for this frequency.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Hourly => "hourly",
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+        }
+    }
+
+    /// Parses a stored or transport frequency value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "hourly" => Ok(Self::Hourly),
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            _ => Err(AppError::Validation(format!(
+                "unknown record import frequency '{value}'"
+            ))),
+        }
+    }
+
+    /// Returns the time between two runs.
+    #[must_use]
+    pub fn period(self) -> Duration {
+        match self {
+            Self::Hourly => Duration::hours(1),
+            Self::Daily => Duration::days(1),
+            Self::Weekly => Duration::weeks(1),
+        }
+    }
+}
+
+/// Outcome of the last run of a recurring import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordImportRunStatus {
+    /// A new file was fetched and its rows imported, possibly with row failures.
+    Imported,
+    /// The fetched file matched the last imported file and was skipped.
+    Unchanged,
+    /// The file could not be fetched, parsed or mapped.
+    Failed,
+}
+
+impl RecordImportRunStatus {
+    /// Returns a stable storage value for this status.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Imported => "imported",
+            Self::Unchanged => "unchanged",
+            Self::Failed => "failed",
+        }
+    }
+
+    /// Parses a stored status value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "imported" => Ok(Self::Imported),
+            "unchanged" => Ok(Self::Unchanged),
+            "failed" => Ok(Self::Failed),
+            _ => Err(AppError::Validation(format!(
+                "unknown record import run status '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Stored recurring import of a remote file into one entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordImportSchedule {
+    /// Stable schedule identifier.
+    pub schedule_id: String,
+    /// Display name.
+    pub name: String,
+    /// Entity rows are imported into.
+    pub entity_logical_name: String,
+    /// Mapping template applied to the file; headers must name fields when unset.
+    pub template_id: Option<String>,
+    /// Protocol of the remote file location.
+    pub source_kind: RecordImportSourceKind,
+    /// `https://` or `sftp://` location of the file.
+    pub source_url: String,
+    /// Header carrying the resolved secret on HTTPS sources.
+    pub auth_header_name: Option<String>,
+    /// Secret reference resolving to the HTTPS header value or SFTP password.
+    pub auth_secret_ref: Option<String>,
+    /// Run cadence.
+    pub frequency: RecordImportFrequency,
+    /// Whether the scheduler runs the import.
+    pub is_enabled: bool,
+    /// Subject that created the schedule; rows are created with its access.
+    pub owner_subject: String,
+    /// Display name of the owner.
+    pub owner_display_name: String,
+    /// Address failure notifications are mailed to.
+    pub owner_email: String,
+    /// Next scheduled run.
+    pub next_run_at: DateTime<Utc>,
+    /// Last run, when any.
+    pub last_run_at: Option<DateTime<Utc>>,
+    /// Outcome of the last run.
+    pub last_run_status: Option<RecordImportRunStatus>,
+    /// SHA-256 of the last imported file, used to skip unchanged files.
+    pub last_file_sha256: Option<String>,
+    /// Rows imported by the last run that processed a file.
+    pub last_imported_count: u32,
+    /// Rows rejected by the last run that processed a file.
+    pub last_failed_count: u32,
+    /// Error of the last failed run or first rejected row of the last
+    /// imported file.
+    pub last_error: Option<String>,
+    /// Creation timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Input payload for creating a recurring import.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateRecordImportScheduleInput {
+    /// Display name.
+    pub name: String,
+    /// Entity rows are imported into.
+    pub entity_logical_name: String,
+    /// Mapping template applied to the file.
+    pub template_id: Option<String>,
+    /// Protocol of the remote file location.
+    pub source_kind: RecordImportSourceKind,
+    /// `https://` or `sftp://` location of the file.
+    pub source_url: String,
+    /// Header carrying the resolved secret on HTTPS sources.
+    pub auth_header_name: Option<String>,
+    /// Secret reference resolving to the HTTPS header value or SFTP password.
+    pub auth_secret_ref: Option<String>,
+    /// Run cadence.
+    pub frequency: RecordImportFrequency,
+}
+
+/// New recurring import to persist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewRecordImportSchedule {
+    /// Validated schedule settings.
+    pub input: CreateRecordImportScheduleInput,
+    /// Owning subject.
+    pub owner_subject: String,
+    /// Display name of the owner.
+    pub owner_display_name: String,
+    /// Address failure notifications are mailed to.
+    pub owner_email: String,
+    /// First scheduled run.
+    pub next_run_at: DateTime<Utc>,
+}
+
+/// Schedule claimed for a run by one scheduler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueRecordImportSchedule {
+    /// Tenant owning the schedule.
+    pub tenant_id: TenantId,
+    /// Slot the run was scheduled for.
+    pub scheduled_for: DateTime<Utc>,
+    /// Claimed schedule.
+    pub schedule: RecordImportSchedule,
+}
+
+/// Result of one recurring import run to persist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordImportScheduleRun {
+    /// Run outcome.
+    pub status: RecordImportRunStatus,
+    /// File hash to remember; `None` keeps the stored hash.
+    pub file_sha256: Option<String>,
+    /// Rows imported; ignored for unchanged and failed runs.
+    pub imported_count: u32,
+    /// Rows rejected; ignored for unchanged and failed runs.
+    pub failed_count: u32,
+    /// Error of a failed run or first rejected row; ignored for unchanged runs.
+    pub error: Option<String>,
+    /// When the run finished.
+    pub ran_at: DateTime<Utc>,
+    /// Next scheduled run.
+    pub next_run_at: DateTime<Utc>,
+}
+
+/// Row rejected while importing a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordImportRowError {
+    /// One-based line of the row in the file, counting the header as line 1.
+    pub line_number: usize,
+    /// Why the row was rejected.
+    pub message: String,
+}
+
+/// Summary of one processed import file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordImportSummary {
+    /// Rows created as records.
+    pub imported_count: usize,
+    /// Rows rejected.
+    pub failed_count: usize,
+    /// First rejected rows with their errors.
+    pub row_errors: Vec<RecordImportRowError>,
+}
+
+/// Repository port for mapping templates and recurring imports.
+#[async_trait]
+pub trait RecordImportRepository: Send + Sync {
+    /// Lists the mapping templates of an entity ordered by name.
+    async fn list_templates(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<RecordImportMappingTemplate>>;
+
+    /// Finds a mapping template by identifier.
+    async fn find_template(
+        &self,
+        tenant_id: TenantId,
+        template_id: &str,
+    ) -> AppResult<Option<RecordImportMappingTemplate>>;
+
+    /// Stores a new mapping template; a duplicate name within the entity conflicts.
+    async fn create_template(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveRecordImportMappingTemplateInput,
+    ) -> AppResult<RecordImportMappingTemplate>;
+
+    /// Replaces a mapping template, returning `None` when it does not exist.
+    async fn update_template(
+        &self,
+        tenant_id: TenantId,
+        template_id: &str,
+        updated_by_subject: &str,
+        input: SaveRecordImportMappingTemplateInput,
+    ) -> AppResult<Option<RecordImportMappingTemplate>>;
+
+    /// Deletes a mapping template, returning whether it existed.
+    async fn delete_template(&self, tenant_id: TenantId, template_id: &str) -> AppResult<bool>;
+
+    /// Lists recurring imports of a tenant, oldest first.
+    async fn list_schedules(&self, tenant_id: TenantId) -> AppResult<Vec<RecordImportSchedule>>;
+
+    /// Stores a new enabled recurring import.
+    async fn create_schedule(
+        &self,
+        tenant_id: TenantId,
+        schedule: NewRecordImportSchedule,
+    ) -> AppResult<RecordImportSchedule>;
+
+    /// Enables or pauses a recurring import, returning `None` when it does not exist.
+    ///
+    /// A resumed import runs at `next_run_at`.
+    async fn set_schedule_enabled(
+        &self,
+        tenant_id: TenantId,
+        schedule_id: &str,
+        is_enabled: bool,
+        next_run_at: DateTime<Utc>,
+    ) -> AppResult<Option<RecordImportSchedule>>;
+
+    /// Deletes a recurring import, returning whether it existed.
+    async fn delete_schedule(&self, tenant_id: TenantId, schedule_id: &str) -> AppResult<bool>;
+
+    /// Claims enabled imports due at `now` across tenants.
+    ///
+    /// Claimed imports are pushed to `retry_at` so another scheduler does
+    /// not run them again; an interrupted run is retried then.
+    async fn claim_due_schedules(
+        &self,
+        now: DateTime<Utc>,
+        retry_at: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<DueRecordImportSchedule>>;
+
+    /// Records the outcome of a run and schedules the next one.
+    async fn record_schedule_run(
+        &self,
+        tenant_id: TenantId,
+        schedule_id: &str,
+        run: RecordImportScheduleRun,
+    ) -> AppResult<()>;
+}
+
+/// Port downloading the file of a recurring import.
+#[async_trait]
+pub trait RecordImportSourceFetcher: Send + Sync {
+    /// Downloads the file, failing when it exceeds `max_bytes`.
+    async fn fetch(&self, schedule: &RecordImportSchedule, max_bytes: usize) -> AppResult<Vec<u8>>;
+}
+
+/// Runtime record gateway used by record imports.
+#[async_trait]
+pub trait RecordImportRuntimeService: Send + Sync {
+    /// Returns the latest published schema for an entity.
+    async fn latest_published_schema_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<PublishedEntitySchema>>;
+
+    /// Creates a runtime record with the actor's permissions and field access.
+    async fn create_runtime_record(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        data: Value,
+    ) -> AppResult<RuntimeRecord>;
+}
@@ -0,0 +1,870 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+use qryvanta_core::{AppError, AppResult, UserIdentity, validate_secret_reference};
+use qryvanta_domain::{AuditAction, FieldType, Permission, PublishedEntitySchema};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService, EmailService};
+
+use super::csv::{parse_csv, resolve_import_columns, row_record_data};
+use super::ports::{
+    CreateRecordImportScheduleInput, DueRecordImportSchedule, NewRecordImportSchedule,
+    RecordImportColumnMapping, RecordImportFrequency, RecordImportMappingTemplate,
+    RecordImportRepository, RecordImportRowError, RecordImportRunStatus,
+    RecordImportRuntimeService, RecordImportSchedule, RecordImportScheduleRun,
+    RecordImportSourceFetcher, RecordImportSourceKind, RecordImportSummary,
+    SaveRecordImportMappingTemplateInput,
+};
+
+/// Largest file accepted by uploads and recurring imports.
+pub const RECORD_IMPORT_MAX_FILE_BYTES: usize = 10 * 1024 * 1024;
+
+/// Maximum number of data rows in one import file.
+pub const RECORD_IMPORT_MAX_ROWS: usize = 10_000;
+
+/// Maximum number of recurring imports one tenant may configure.
+pub const RECORD_IMPORT_MAX_SCHEDULES_PER_TENANT: usize = 25;
+
+const MAX_NAME_LENGTH: usize = 120;
+
+const MAX_TEMPLATE_COLUMNS: usize = 500;
+
+/// Rejected rows reported per import; further rejections are only counted.
+const MAX_REPORTED_ROW_ERRORS: usize = 50;
+
+/// Minutes a claimed schedule waits before an interrupted run is retried.
+const RECORD_IMPORT_SCHEDULE_RETRY_MINUTES: i64 = 30;
+
+/// Application service for CSV record imports, mapping templates and
+/// recurring imports from remote files.
+#[derive(Clone)]
+pub struct RecordImportService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn RecordImportRepository>,
+    runtime_service: Arc<dyn RecordImportRuntimeService>,
+    fetcher: Arc<dyn RecordImportSourceFetcher>,
+    email_service: Arc<dyn EmailService>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl RecordImportService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn RecordImportRepository>,
+        runtime_service: Arc<dyn RecordImportRuntimeService>,
+        fetcher: Arc<dyn RecordImportSourceFetcher>,
+        email_service: Arc<dyn EmailService>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            runtime_service,
+            fetcher,
+            email_service,
+            audit_repository,
+        }
+    }
+
+    /// Lists the mapping templates of an entity.
+    pub async fn list_templates(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<RecordImportMappingTemplate>> {
+        self.require_permission(actor, Permission::MetadataFieldRead)
+            .await?;
+        self.repository
+            .list_templates(actor.tenant_id(), entity_logical_name)
+            .await
+    }
+
+    /// Saves a new mapping template for a published entity.
+    pub async fn create_template(
+        &self,
+        actor: &UserIdentity,
+        input: SaveRecordImportMappingTemplateInput,
+    ) -> AppResult<RecordImportMappingTemplate> {
+        self.require_permission(actor, Permission::MetadataFieldWrite)
+            .await?;
+
+        let input = self.normalize_template_input(actor, input).await?;
+        let template = self
+            .repository
+            .create_template(actor.tenant_id(), actor.subject(), input)
+            .await?;
+        self.append_template_audit_event(actor, AuditAction::RecordImportTemplateSaved, &template)
+            .await?;
+
+        Ok(template)
+    }
+
+    /// Replaces the name and column mappings of a template.
+    pub async fn update_template(
+        &self,
+        actor: &UserIdentity,
+        template_id: &str,
+        input: SaveRecordImportMappingTemplateInput,
+    ) -> AppResult<RecordImportMappingTemplate> {
+        self.require_permission(actor, Permission::MetadataFieldWrite)
+            .await?;
+
+        self.entity_template(actor, input.entity_logical_name.as_str(), template_id)
+            .await?;
+        let input = self.normalize_template_input(actor, input).await?;
+        let template = self
+            .repository
+            .update_template(actor.tenant_id(), template_id, actor.subject(), input)
+            .await?
+            .ok_or_else(|| template_not_found(template_id))?;
+        self.append_template_audit_event(actor, AuditAction::RecordImportTemplateSaved, &template)
+            .await?;
+
+        Ok(template)
+    }
+
+    /// Deletes a mapping template that no recurring import uses.
+    pub async fn delete_template(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        template_id: &str,
+    ) -> AppResult<()> {
+        self.require_permission(actor, Permission::MetadataFieldWrite)
+            .await?;
+
+        let template = self
+            .entity_template(actor, entity_logical_name, template_id)
+            .await?;
+        if let Some(schedule) = self
+            .repository
+            .list_schedules(actor.tenant_id())
+            .await?
+            .into_iter()
+            .find(|schedule| schedule.template_id.as_deref() == Some(template_id))
+        {
+            return Err(AppError::Conflict(format!(
+                "record import template '{}' is used by recurring import '{}'",
+                template.name, schedule.name
+            )));
+        }
+
+        if !self
+            .repository
+            .delete_template(actor.tenant_id(), template_id)
+            .await?
+        {
+            return Err(template_not_found(template_id));
+        }
+
+        self.append_template_audit_event(actor, AuditAction::RecordImportTemplateDeleted, &template)
+            .await
+    }
+
+    /// Imports the rows of an uploaded CSV file as new records.
+    ///
+    /// Rows are created one by one with the actor's permissions and field
+    /// access; rejected rows are reported without undoing imported ones.
+    pub async fn import_file(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        template_id: Option<&str>,
+        content: &[u8],
+    ) -> AppResult<RecordImportSummary> {
+        let summary = self
+            .import_content(actor, entity_logical_name, template_id, content)
+            .await?;
+        self.append_import_audit_event(actor, entity_logical_name, template_id, None, &summary)
+            .await?;
+
+        Ok(summary)
+    }
+
+    /// Lists the recurring imports of the actor tenant.
+    pub async fn list_schedules(
+        &self,
+        actor: &UserIdentity,
+    ) -> AppResult<Vec<RecordImportSchedule>> {
+        self.require_permission(actor, Permission::MetadataFieldRead)
+            .await?;
+        self.repository.list_schedules(actor.tenant_id()).await
+    }
+
+    /// Creates an enabled recurring import owned by the actor.
+    ///
+    /// Runs create records with the owner's access, and failures are mailed
+    /// to the owner. The first run is due immediately.
+    pub async fn create_schedule(
+        &self,
+        actor: &UserIdentity,
+        input: CreateRecordImportScheduleInput,
+    ) -> AppResult<RecordImportSchedule> {
+        self.require_permission(actor, Permission::MetadataFieldWrite)
+            .await?;
+
+        let owner_email = actor.email().ok_or_else(|| {
+            AppError::Validation(
+                "recurring imports require an email address on the account for failure notifications"
+                    .to_owned(),
+            )
+        })?;
+        let input = normalize_schedule_input(input)?;
+        if let Some(template_id) = input.template_id.as_deref() {
+            self.entity_template(actor, input.entity_logical_name.as_str(), template_id)
+                .await?;
+        }
+        self.published_schema(actor, input.entity_logical_name.as_str())
+            .await?;
+        if self
+            .repository
+            .list_schedules(actor.tenant_id())
+            .await?
+            .len()
+            >= RECORD_IMPORT_MAX_SCHEDULES_PER_TENANT
+        {
+            return Err(AppError::Conflict(format!(
+                "a tenant may configure at most {RECORD_IMPORT_MAX_SCHEDULES_PER_TENANT} recurring imports"
+            )));
+        }
+
+        let schedule = self
+            .repository
+            .create_schedule(
+                actor.tenant_id(),
+                NewRecordImportSchedule {
+                    input,
+                    owner_subject: actor.subject().to_owned(),
+                    owner_display_name: actor.display_name().to_owned(),
+                    owner_email: owner_email.to_owned(),
+                    next_run_at: Utc::now(),
+                },
+            )
+            .await?;
+        self.append_schedule_audit_event(
+            actor,
+            AuditAction::RecordImportScheduleSaved,
+            &schedule,
+            json!({ "created": true }),
+        )
+        .await?;
+
+        Ok(schedule)
+    }
+
+    /// Pauses or resumes a recurring import; a resumed import runs right away.
+    pub async fn set_schedule_enabled(
+        &self,
+        actor: &UserIdentity,
+        schedule_id: &str,
+        is_enabled: bool,
+    ) -> AppResult<RecordImportSchedule> {
+        self.require_permission(actor, Permission::MetadataFieldWrite)
+            .await?;
+
+        let schedule = self
+            .repository
+            .set_schedule_enabled(actor.tenant_id(), schedule_id, is_enabled, Utc::now())
+            .await?
+            .ok_or_else(|| schedule_not_found(schedule_id))?;
+        self.append_schedule_audit_event(
+            actor,
+            AuditAction::RecordImportScheduleSaved,
+            &schedule,
+            json!({ "is_enabled": is_enabled }),
+        )
+        .await?;
+
+        Ok(schedule)
+    }
+
+    /// Deletes a recurring import.
+    pub async fn delete_schedule(&self, actor: &UserIdentity, schedule_id: &str) -> AppResult<()> {
+        self.require_permission(actor, Permission::MetadataFieldWrite)
+            .await?;
+
+        if !self
+            .repository
+            .delete_schedule(actor.tenant_id(), schedule_id)
+            .await?
+        {
+            return Err(schedule_not_found(schedule_id));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::RecordImportScheduleDeleted,
+                resource_type: "record_import_schedule".to_owned(),
+                resource_id: schedule_id.to_owned(),
+                detail: None,
+            })
+            .await
+    }
+
+    /// Claims up to `limit` due recurring imports across tenants and runs
+    /// them, returning how many ran.
+    ///
+    /// A file identical to the last imported one is skipped. Failed runs and
+    /// runs with rejected rows are mailed to the schedule owner; a failed
+    /// file is fetched again at the next slot.
+    pub async fn run_due_schedules(&self, limit: usize) -> AppResult<usize> {
+        let now = Utc::now();
+        let claimed = self
+            .repository
+            .claim_due_schedules(
+                now,
+                now + Duration::minutes(RECORD_IMPORT_SCHEDULE_RETRY_MINUTES),
+                limit,
+            )
+            .await?;
+
+        let ran = claimed.len();
+        let mut first_error = None;
+        for due in claimed {
+            if let Err(error) = self.run_schedule(due).await {
+                first_error.get_or_insert(error);
+            }
+        }
+
+        match first_error {
+            Some(error) => Err(error),
+            None => Ok(ran),
+        }
+    }
+
+    /// Rebuilds the identity a claimed import creates records with.
+    #[must_use]
+    pub fn schedule_owner(due: &DueRecordImportSchedule) -> UserIdentity {
+        let schedule = &due.schedule;
+        UserIdentity::new(
+            schedule.owner_subject.as_str(),
+            schedule.owner_display_name.as_str(),
+            Some(schedule.owner_email.clone()),
+            due.tenant_id,
+        )
+    }
+
+    async fn run_schedule(&self, due: DueRecordImportSchedule) -> AppResult<()> {
+        let owner = Self::schedule_owner(&due);
+        let schedule = &due.schedule;
+        let outcome = match self
+            .fetcher
+            .fetch(schedule, RECORD_IMPORT_MAX_FILE_BYTES)
+            .await
+        {
+            Ok(content) => {
+                let file_sha256 = hex::encode(Sha256::digest(&content));
+                if schedule.last_file_sha256.as_deref() == Some(file_sha256.as_str()) {
+                    Ok(None)
+                } else {
+                    self.import_content(
+                        &owner,
+                        schedule.entity_logical_name.as_str(),
+                        schedule.template_id.as_deref(),
+                        &content,
+                    )
+                    .await
+                    .map(|summary| Some((file_sha256, summary)))
+                }
+            }
+            Err(error) => Err(error),
+        };
+
+        let ran_at = Utc::now();
+        let next_run_at = next_run_after(due.scheduled_for, schedule.frequency, ran_at);
+        let run = match &outcome {
+            Ok(None) => RecordImportScheduleRun {
+                status: RecordImportRunStatus::Unchanged,
+                file_sha256: None,
+                imported_count: 0,
+                failed_count: 0,
+                error: None,
+                ran_at,
+                next_run_at,
+            },
+            Ok(Some((file_sha256, summary))) => RecordImportScheduleRun {
+                status: RecordImportRunStatus::Imported,
+                file_sha256: Some(file_sha256.clone()),
+                imported_count: u32::try_from(summary.imported_count).unwrap_or(u32::MAX),
+                failed_count: u32::try_from(summary.failed_count).unwrap_or(u32::MAX),
+                error: summary.row_errors.first().map(row_error_line),
+                ran_at,
+                next_run_at,
+            },
+            Err(error) => RecordImportScheduleRun {
+                status: RecordImportRunStatus::Failed,
+                file_sha256: None,
+                imported_count: 0,
+                failed_count: 0,
+                error: Some(error.to_string()),
+                ran_at,
+                next_run_at,
+            },
+        };
+        self.repository
+            .record_schedule_run(due.tenant_id, schedule.schedule_id.as_str(), run)
+            .await?;
+
+        match outcome {
+            Ok(None) => Ok(()),
+            Ok(Some((_, summary))) => {
+                self.append_import_audit_event(
+                    &owner,
+                    schedule.entity_logical_name.as_str(),
+                    schedule.template_id.as_deref(),
+                    Some(schedule.schedule_id.as_str()),
+                    &summary,
+                )
+                .await?;
+                if summary.failed_count > 0 {
+                    self.notify_rejected_rows(schedule, &summary).await?;
+                }
+                Ok(())
+            }
+            Err(error) => self.notify_failed_run(schedule, &error, next_run_at).await,
+        }
+    }
+
+    async fn import_content(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        template_id: Option<&str>,
+        content: &[u8],
+    ) -> AppResult<RecordImportSummary> {
+        if content.len() > RECORD_IMPORT_MAX_FILE_BYTES {
+            return Err(AppError::Validation(format!(
+                "import file exceeds {RECORD_IMPORT_MAX_FILE_BYTES} bytes"
+            )));
+        }
+
+        let template = match template_id {
+            Some(template_id) => Some(
+                self.entity_template(actor, entity_logical_name, template_id)
+                    .await?,
+            ),
+            None => None,
+        };
+        let schema = self.published_schema(actor, entity_logical_name).await?;
+        let table = parse_csv(content, RECORD_IMPORT_MAX_ROWS)?;
+        let columns = resolve_import_columns(&table.header, template.as_ref(), &schema)?;
+
+        let mut summary = RecordImportSummary {
+            imported_count: 0,
+            failed_count: 0,
+            row_errors: Vec::new(),
+        };
+        for row in &table.rows {
+            let created = match row_record_data(row, &columns) {
+                Ok(data) => {
+                    self.runtime_service
+                        .create_runtime_record(actor, entity_logical_name, data)
+                        .await
+                }
+                Err(error) => Err(error),
+            };
+
+            match created {
+                Ok(_) => summary.imported_count += 1,
+                // Missing access fails every row alike, so stop at the first.
+                Err(error @ AppError::Forbidden(_)) => return Err(error),
+                Err(error) => {
+                    summary.failed_count += 1;
+                    if summary.row_errors.len() < MAX_REPORTED_ROW_ERRORS {
+                        summary.row_errors.push(RecordImportRowError {
+                            line_number: row.line_number,
+                            message: error.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+
+    async fn normalize_template_input(
+        &self,
+        actor: &UserIdentity,
+        input: SaveRecordImportMappingTemplateInput,
+    ) -> AppResult<SaveRecordImportMappingTemplateInput> {
+        let name = normalize_name(input.name.as_str(), "record import template")?;
+        if input.column_mappings.is_empty() || input.column_mappings.len() > MAX_TEMPLATE_COLUMNS {
+            return Err(AppError::Validation(format!(
+                "record import templates must map between 1 and {MAX_TEMPLATE_COLUMNS} columns"
+            )));
+        }
+
+        let schema = self
+            .published_schema(actor, input.entity_logical_name.as_str())
+            .await?;
+        let mut source_columns = HashSet::new();
+        let mut field_logical_names = HashSet::new();
+        let mut column_mappings = Vec::with_capacity(input.column_mappings.len());
+        for mapping in input.column_mappings {
+            let source_column = mapping.source_column.trim().to_owned();
+            let field_logical_name = mapping.field_logical_name.trim().to_owned();
+            if source_column.is_empty() {
+                return Err(AppError::Validation(
+                    "record import template source columns must not be empty".to_owned(),
+                ));
+            }
+            if !source_columns.insert(source_column.clone()) {
+                return Err(AppError::Validation(format!(
+                    "record import template maps column '{source_column}' more than once"
+                )));
+            }
+            if !field_logical_names.insert(field_logical_name.clone()) {
+                return Err(AppError::Validation(format!(
+                    "record import template maps more than one column to field '{field_logical_name}'"
+                )));
+            }
+
+            let field = schema
+                .fields()
+                .iter()
+                .find(|field| field.logical_name().as_str() == field_logical_name)
+                .ok_or_else(|| {
+                    AppError::Validation(format!(
+                        "field '{}' is not published on entity '{}'",
+                        field_logical_name, input.entity_logical_name
+                    ))
+                })?;
+            if field.field_type() == FieldType::ManyToMany {
+                return Err(AppError::Validation(format!(
+                    "many_to_many field '{field_logical_name}' cannot be imported"
+                )));
+            }
+
+            column_mappings.push(RecordImportColumnMapping {
+                source_column,
+                field_logical_name,
+            });
+        }
+
+        Ok(SaveRecordImportMappingTemplateInput {
+            entity_logical_name: input.entity_logical_name,
+            name,
+            column_mappings,
+        })
+    }
+
+    async fn entity_template(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        template_id: &str,
+    ) -> AppResult<RecordImportMappingTemplate> {
+        self.repository
+            .find_template(actor.tenant_id(), template_id)
+            .await?
+            .filter(|template| template.entity_logical_name == entity_logical_name)
+            .ok_or_else(|| template_not_found(template_id))
+    }
+
+    async fn published_schema(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<PublishedEntitySchema> {
+        self.runtime_service
+            .latest_published_schema_unchecked(actor, entity_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "entity '{entity_logical_name}' has no published schema"
+                ))
+            })
+    }
+
+    async fn notify_failed_run(
+        &self,
+        schedule: &RecordImportSchedule,
+        error: &AppError,
+        next_run_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        let subject = format!("Qryvanta import failed: {}", schedule.name);
+        let body = format!(
+            "The recurring import '{}' into '{}' could not import {}.\n\n{error}\n\n\
+             No records were imported. The file is fetched again at the next run on {}.",
+            schedule.name,
+            schedule.entity_logical_name,
+            schedule.source_url,
+            next_run_at.to_rfc3339()
+        );
+
+        self.email_service
+            .send_email(
+                schedule.owner_email.as_str(),
+                subject.as_str(),
+                body.as_str(),
+                None,
+            )
+            .await
+    }
+
+    async fn notify_rejected_rows(
+        &self,
+        schedule: &RecordImportSchedule,
+        summary: &RecordImportSummary,
+    ) -> AppResult<()> {
+        let subject = format!("Qryvanta import rejected rows: {}", schedule.name);
+        let mut body = format!(
+            "The recurring import '{}' into '{}' imported {} rows from {} and rejected {}:\n",
+            schedule.name,
+            schedule.entity_logical_name,
+            summary.imported_count,
+            schedule.source_url,
+            summary.failed_count
+        );
+        for row_error in &summary.row_errors {
+            body.push_str(&format!("\n- {}", row_error_line(row_error)));
+        }
+        if summary.failed_count > summary.row_errors.len() {
+            body.push_str(&format!(
+                "\n- and {} more",
+                summary.failed_count - summary.row_errors.len()
+            ));
+        }
+        body.push_str(
+            "\n\nThe file is not imported again until it changes; fix the rejected rows \
+             in a new version of the file.",
+        );
+
+        self.email_service
+            .send_email(
+                schedule.owner_email.as_str(),
+                subject.as_str(),
+                body.as_str(),
+                None,
+            )
+            .await
+    }
+
+    async fn require_permission(
+        &self,
+        actor: &UserIdentity,
+        permission: Permission,
+    ) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(actor.tenant_id(), actor.subject(), permission)
+            .await
+    }
+
+    async fn append_template_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        template: &RecordImportMappingTemplate,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "record_import_template".to_owned(),
+                resource_id: template.template_id.clone(),
+                detail: Some(
+                    json!({
+                        "entity_logical_name": template.entity_logical_name,
+                        "name": template.name,
+                        "column_count": template.column_mappings.len(),
+                    })
+                    .to_string(),
+                ),
+            })
+            .await
+    }
+
+    async fn append_schedule_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        schedule: &RecordImportSchedule,
+        mut detail: serde_json::Value,
+    ) -> AppResult<()> {
+        detail["name"] = serde_json::Value::from(schedule.name.as_str());
+        detail["entity_logical_name"] =
+            serde_json::Value::from(schedule.entity_logical_name.as_str());
+        detail["source_kind"] = serde_json::Value::from(schedule.source_kind.as_str());
+        detail["frequency"] = serde_json::Value::from(schedule.frequency.as_str());
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "record_import_schedule".to_owned(),
+                resource_id: schedule.schedule_id.clone(),
+                detail: Some(detail.to_string()),
+            })
+            .await
+    }
+
+    async fn append_import_audit_event(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        template_id: Option<&str>,
+        schedule_id: Option<&str>,
+        summary: &RecordImportSummary,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::RecordImportCompleted,
+                resource_type: "entity_definition".to_owned(),
+                resource_id: entity_logical_name.to_owned(),
+                detail: Some(
+                    json!({
+                        "template_id": template_id,
+                        "schedule_id": schedule_id,
+                        "imported_count": summary.imported_count,
+                        "failed_count": summary.failed_count,
+                    })
+                    .to_string(),
+                ),
+            })
+            .await
+    }
+}
+
+fn normalize_name(name: &str, kind: &str) -> AppResult<String> {
+    let name = name.trim().to_owned();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(AppError::Validation(format!(
+            "{kind} name must be between 1 and {MAX_NAME_LENGTH} characters"
+        )));
+    }
+
+    Ok(name)
+}
+
+fn normalize_schedule_input(
+    input: CreateRecordImportScheduleInput,
+) -> AppResult<CreateRecordImportScheduleInput> {
+    let name = normalize_name(input.name.as_str(), "recurring import")?;
+    let source_url = input.source_url.trim().to_owned();
+    let scheme = match input.source_kind {
+        RecordImportSourceKind::Https => "https://",
+        RecordImportSourceKind::Sftp => "sftp://",
+    };
+    let Some((authority, path)) = source_url
+        .strip_prefix(scheme)
+        .and_then(|rest| rest.split_once('/'))
+    else {
+        return Err(AppError::Validation(format!(
+            "{} import sources must be a {scheme} URL with a file path",
+            input.source_kind.as_str()
+        )));
+    };
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    if host.is_empty() || path.is_empty() || source_url.chars().any(char::is_whitespace) {
+        return Err(AppError::Validation(format!(
+            "{} import sources must be a {scheme} URL with a file path",
+            input.source_kind.as_str()
+        )));
+    }
+
+    let auth_header_name = input
+        .auth_header_name
+        .map(|name| name.trim().to_ascii_lowercase())
+        .filter(|name| !name.is_empty());
+    let auth_secret_ref = input
+        .auth_secret_ref
+        .map(|reference| reference.trim().to_owned())
+        .filter(|reference| !reference.is_empty());
+    if let Some(secret_ref) = auth_secret_ref.as_deref() {
+        validate_secret_reference(secret_ref)?;
+    }
+    match input.source_kind {
+        RecordImportSourceKind::Https => match (&auth_header_name, &auth_secret_ref) {
+            (Some(header_name), Some(_)) => {
+                if !header_name
+                    .chars()
+                    .all(|character| character.is_ascii_alphanumeric() || character == '-')
+                {
+                    return Err(AppError::Validation(format!(
+                        "record import header name '{header_name}' is invalid"
+                    )));
+                }
+            }
+            (None, None) => {}
+            _ => {
+                return Err(AppError::Validation(
+                    "https import auth requires both a header name and a secret reference"
+                        .to_owned(),
+                ));
+            }
+        },
+        RecordImportSourceKind::Sftp => {
+            if auth_header_name.is_some() {
+                return Err(AppError::Validation(
+                    "sftp import sources do not accept an auth header name".to_owned(),
+                ));
+            }
+            if auth_secret_ref.is_some() && !authority.contains('@') {
+                return Err(AppError::Validation(
+                    "sftp import sources with a password must name the user in the URL".to_owned(),
+                ));
+            }
+        }
+    }
+
+    let template_id = input
+        .template_id
+        .map(|template_id| template_id.trim().to_owned())
+        .filter(|template_id| !template_id.is_empty());
+
+    Ok(CreateRecordImportScheduleInput {
+        name,
+        entity_logical_name: input.entity_logical_name,
+        template_id,
+        source_kind: input.source_kind,
+        source_url,
+        auth_header_name,
+        auth_secret_ref,
+        frequency: input.frequency,
+    })
+}
+
+fn row_error_line(row_error: &RecordImportRowError) -> String {
+    format!("line {}: {}", row_error.line_number, row_error.message)
+}
+
+fn template_not_found(template_id: &str) -> AppError {
+    AppError::NotFound(format!(
+        "record import template '{template_id}' does not exist"
+    ))
+}
+
+fn schedule_not_found(schedule_id: &str) -> AppError {
+    AppError::NotFound(format!("recurring import '{schedule_id}' does not exist"))
+}
+
+/// Advances a run slot by whole periods until it lies after `now`.
+///
+/// Slots missed while no scheduler ran are skipped rather than replayed.
+pub(super) fn next_run_after(
+    scheduled_for: DateTime<Utc>,
+    frequency: RecordImportFrequency,
+    now: DateTime<Utc>,
+) -> DateTime<Utc> {
+    let period = frequency.period();
+    let mut next = scheduled_for + period;
+    while next <= now {
+        next += period;
+    }
+    next
+}
@@ -0,0 +1,834 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{Value, json};
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{
+    AuditAction, EntityDefinition, EntityFieldDefinition, FieldType, Permission,
+    PublishedEntitySchema, RuntimeRecord,
+};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, EmailAttachment,
+    EmailService, RuntimeFieldGrant, TemporaryPermissionGrant,
+};
+
+use super::{
+    CreateRecordImportScheduleInput, DueRecordImportSchedule, NewRecordImportSchedule,
+    RecordImportColumnMapping, RecordImportFrequency, RecordImportMappingTemplate,
+    RecordImportRepository, RecordImportRunStatus, RecordImportRuntimeService,
+    RecordImportSchedule, RecordImportScheduleRun, RecordImportService, RecordImportSourceFetcher,
+    RecordImportSourceKind, SaveRecordImportMappingTemplateInput,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeRecordImportRepository {
+    templates: Mutex<Vec<(TenantId, RecordImportMappingTemplate)>>,
+    schedules: Mutex<Vec<(TenantId, RecordImportSchedule)>>,
+}
+
+impl FakeRecordImportRepository {
+    async fn schedule(&self, schedule_id: &str) -> RecordImportSchedule {
+        self.schedules
+            .lock()
+            .await
+            .iter()
+            .find(|(_, schedule)| schedule.schedule_id == schedule_id)
+            .map(|(_, schedule)| schedule.clone())
+            .unwrap_or_else(|| unreachable!())
+    }
+
+    async fn make_due(&self, schedule_id: &str) {
+        if let Some((_, schedule)) = self
+            .schedules
+            .lock()
+            .await
+            .iter_mut()
+            .find(|(_, schedule)| schedule.schedule_id == schedule_id)
+        {
+            schedule.next_run_at = Utc::now() - Duration::minutes(1);
+        }
+    }
+}
+
+#[async_trait]
+impl RecordImportRepository for FakeRecordImportRepository {
+    async fn list_templates(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<RecordImportMappingTemplate>> {
+        Ok(self
+            .templates
+            .lock()
+            .await
+            .iter()
+            .filter(|(template_tenant_id, template)| {
+                *template_tenant_id == tenant_id
+                    && template.entity_logical_name == entity_logical_name
+            })
+            .map(|(_, template)| template.clone())
+            .collect())
+    }
+
+    async fn find_template(
+        &self,
+        tenant_id: TenantId,
+        template_id: &str,
+    ) -> AppResult<Option<RecordImportMappingTemplate>> {
+        Ok(self
+            .templates
+            .lock()
+            .await
+            .iter()
+            .find(|(template_tenant_id, template)| {
+                *template_tenant_id == tenant_id && template.template_id == template_id
+            })
+            .map(|(_, template)| template.clone()))
+    }
+
+    async fn create_template(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveRecordImportMappingTemplateInput,
+    ) -> AppResult<RecordImportMappingTemplate> {
+        let mut templates = self.templates.lock().await;
+        let template = RecordImportMappingTemplate {
+            template_id: format!("template-{}", templates.len() + 1),
+            entity_logical_name: input.entity_logical_name,
+            name: input.name,
+            column_mappings: input.column_mappings,
+            updated_by_subject: updated_by_subject.to_owned(),
+            updated_at: Utc::now(),
+        };
+        templates.push((tenant_id, template.clone()));
+        Ok(template)
+    }
+
+    async fn update_template(
+        &self,
+        tenant_id: TenantId,
+        template_id: &str,
+        updated_by_subject: &str,
+        input: SaveRecordImportMappingTemplateInput,
+    ) -> AppResult<Option<RecordImportMappingTemplate>> {
+        let mut templates = self.templates.lock().await;
+        Ok(templates
+            .iter_mut()
+            .find(|(template_tenant_id, template)| {
+                *template_tenant_id == tenant_id && template.template_id == template_id
+            })
+            .map(|(_, template)| {
+                template.name = input.name;
+                template.column_mappings = input.column_mappings;
+                template.updated_by_subject = updated_by_subject.to_owned();
+                template.clone()
+            }))
+    }
+
+    async fn delete_template(&self, tenant_id: TenantId, template_id: &str) -> AppResult<bool> {
+        let mut templates = self.templates.lock().await;
+        let before = templates.len();
+        templates.retain(|(template_tenant_id, template)| {
+            *template_tenant_id != tenant_id || template.template_id != template_id
+        });
+        Ok(templates.len() < before)
+    }
+
+    async fn list_schedules(&self, tenant_id: TenantId) -> AppResult<Vec<RecordImportSchedule>> {
+        Ok(self
+            .schedules
+            .lock()
+            .await
+            .iter()
+            .filter(|(schedule_tenant_id, _)| *schedule_tenant_id == tenant_id)
+            .map(|(_, schedule)| schedule.clone())
+            .collect())
+    }
+
+    async fn create_schedule(
+        &self,
+        tenant_id: TenantId,
+        schedule: NewRecordImportSchedule,
+    ) -> AppResult<RecordImportSchedule> {
+        let mut schedules = self.schedules.lock().await;
+        let input = schedule.input;
+        let stored = RecordImportSchedule {
+            schedule_id: format!("schedule-{}", schedules.len() + 1),
+            name: input.name,
+            entity_logical_name: input.entity_logical_name,
+            template_id: input.template_id,
+            source_kind: input.source_kind,
+            source_url: input.source_url,
+            auth_header_name: input.auth_header_name,
+            auth_secret_ref: input.auth_secret_ref,
+            frequency: input.frequency,
+            is_enabled: true,
+            owner_subject: schedule.owner_subject,
+            owner_display_name: schedule.owner_display_name,
+            owner_email: schedule.owner_email,
+            next_run_at: schedule.next_run_at,
+            last_run_at: None,
+            last_run_status: None,
+            last_file_sha256: None,
+            last_imported_count: 0,
+            last_failed_count: 0,
+            last_error: None,
+            created_at: Utc::now(),
+        };
+        schedules.push((tenant_id, stored.clone()));
+        Ok(stored)
+    }
+
+    async fn set_schedule_enabled(
+        &self,
+        tenant_id: TenantId,
+        schedule_id: &str,
+        is_enabled: bool,
+        next_run_at: DateTime<Utc>,
+    ) -> AppResult<Option<RecordImportSchedule>> {
+        let mut schedules = self.schedules.lock().await;
+        Ok(schedules
+            .iter_mut()
+            .find(|(schedule_tenant_id, schedule)| {
+                *schedule_tenant_id == tenant_id && schedule.schedule_id == schedule_id
+            })
+            .map(|(_, schedule)| {
+                schedule.is_enabled = is_enabled;
+                if is_enabled {
+                    schedule.next_run_at = next_run_at;
+                }
+                schedule.clone()
+            }))
+    }
+
+    async fn delete_schedule(&self, tenant_id: TenantId, schedule_id: &str) -> AppResult<bool> {
+        let mut schedules = self.schedules.lock().await;
+        let before = schedules.len();
+        schedules.retain(|(schedule_tenant_id, schedule)| {
+            *schedule_tenant_id != tenant_id || schedule.schedule_id != schedule_id
+        });
+        Ok(schedules.len() < before)
+    }
+
+    async fn claim_due_schedules(
+        &self,
+        now: DateTime<Utc>,
+        retry_at: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<DueRecordImportSchedule>> {
+        let mut schedules = self.schedules.lock().await;
+        Ok(schedules
+            .iter_mut()
+            .filter(|(_, schedule)| schedule.is_enabled && schedule.next_run_at <= now)
+            .take(limit)
+            .map(|(tenant_id, schedule)| {
+                let scheduled_for = schedule.next_run_at;
+                schedule.next_run_at = retry_at;
+                DueRecordImportSchedule {
+                    tenant_id: *tenant_id,
+                    scheduled_for,
+                    schedule: schedule.clone(),
+                }
+            })
+            .collect())
+    }
+
+    async fn record_schedule_run(
+        &self,
+        tenant_id: TenantId,
+        schedule_id: &str,
+        run: RecordImportScheduleRun,
+    ) -> AppResult<()> {
+        let mut schedules = self.schedules.lock().await;
+        let (_, schedule) = schedules
+            .iter_mut()
+            .find(|(schedule_tenant_id, schedule)| {
+                *schedule_tenant_id == tenant_id && schedule.schedule_id == schedule_id
+            })
+            .ok_or_else(|| AppError::NotFound(schedule_id.to_owned()))?;
+        schedule.last_run_at = Some(run.ran_at);
+        schedule.last_run_status = Some(run.status);
+        schedule.next_run_at = run.next_run_at;
+        match run.status {
+            RecordImportRunStatus::Imported => {
+                schedule.last_file_sha256 = run.file_sha256;
+                schedule.last_imported_count = run.imported_count;
+                schedule.last_failed_count = run.failed_count;
+                schedule.last_error = run.error;
+            }
+            RecordImportRunStatus::Failed => schedule.last_error = run.error,
+            RecordImportRunStatus::Unchanged => {}
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeSourceFetcher {
+    content: Mutex<Option<Vec<u8>>>,
+    fetches: Mutex<usize>,
+}
+
+impl FakeSourceFetcher {
+    async fn serve(&self, content: Option<&str>) {
+        *self.content.lock().await = content.map(|content| content.as_bytes().to_vec());
+    }
+}
+
+#[async_trait]
+impl RecordImportSourceFetcher for FakeSourceFetcher {
+    async fn fetch(
+        &self,
+        schedule: &RecordImportSchedule,
+        _max_bytes: usize,
+    ) -> AppResult<Vec<u8>> {
+        *self.fetches.lock().await += 1;
+        self.content.lock().await.clone().ok_or_else(|| {
+            AppError::Internal(format!(
+                "record import source '{}' responded with status 503",
+                schedule.source_url
+            ))
+        })
+    }
+}
+
+struct FakeRuntimeService {
+    schema: PublishedEntitySchema,
+    writers: Vec<String>,
+    records: Mutex<Vec<(String, Value)>>,
+}
+
+#[async_trait]
+impl RecordImportRuntimeService for FakeRuntimeService {
+    async fn latest_published_schema_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        Ok(
+            (self.schema.entity().logical_name().as_str() == entity_logical_name)
+                .then(|| self.schema.clone()),
+        )
+    }
+
+    async fn create_runtime_record(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        data: Value,
+    ) -> AppResult<RuntimeRecord> {
+        if !self.writers.iter().any(|writer| writer == actor.subject()) {
+            return Err(AppError::Forbidden(format!(
+                "subject '{}' cannot create records",
+                actor.subject()
+            )));
+        }
+        if data.get("name").is_none() {
+            return Err(AppError::Validation(
+                "missing required field 'name'".to_owned(),
+            ));
+        }
+
+        let mut records = self.records.lock().await;
+        let record_id = format!("record-{}", records.len() + 1);
+        records.push((actor.subject().to_owned(), data.clone()));
+        RuntimeRecord::new(record_id, entity_logical_name, data)
+    }
+}
+
+#[derive(Default)]
+struct FakeEmailService {
+    sent: Mutex<Vec<(String, String, String)>>,
+}
+
+#[async_trait]
+impl EmailService for FakeEmailService {
+    async fn send_email(
+        &self,
+        to: &str,
+        subject: &str,
+        text_body: &str,
+        _html_body: Option<&str>,
+    ) -> AppResult<()> {
+        self.sent
+            .lock()
+            .await
+            .push((to.to_owned(), subject.to_owned(), text_body.to_owned()));
+        Ok(())
+    }
+
+    async fn send_email_with_attachments(
+        &self,
+        to: &str,
+        subject: &str,
+        text_body: &str,
+        _attachments: &[EmailAttachment],
+    ) -> AppResult<()> {
+        self.send_email(to, subject, text_body, None).await
+    }
+}
+
+struct Harness {
+    service: RecordImportService,
+    repository: Arc<FakeRecordImportRepository>,
+    fetcher: Arc<FakeSourceFetcher>,
+    runtime: Arc<FakeRuntimeService>,
+    email: Arc<FakeEmailService>,
+    audit: Arc<FakeAuditRepository>,
+    admin: UserIdentity,
+}
+
+fn field(logical_name: &str, field_type: FieldType) -> EntityFieldDefinition {
+    EntityFieldDefinition::new(
+        "account",
+        logical_name,
+        logical_name,
+        field_type,
+        false,
+        false,
+        None,
+        None,
+    )
+    .unwrap_or_else(|_| unreachable!())
+}
+
+fn harness() -> Harness {
+    let tenant_id = TenantId::new();
+    let admin = UserIdentity::new(
+        "alice",
+        "Alice",
+        Some("alice@example.com".to_owned()),
+        tenant_id,
+    );
+    let repository = Arc::new(FakeRecordImportRepository::default());
+    let fetcher = Arc::new(FakeSourceFetcher::default());
+    let runtime = Arc::new(FakeRuntimeService {
+        schema: PublishedEntitySchema::new(
+            EntityDefinition::new("account", "Account").unwrap_or_else(|_| unreachable!()),
+            1,
+            vec![
+                field("name", FieldType::Text),
+                field("employees", FieldType::Number),
+                field("active", FieldType::Boolean),
+            ],
+            Vec::new(),
+        )
+        .unwrap_or_else(|_| unreachable!()),
+        writers: vec!["alice".to_owned()],
+        records: Mutex::new(Vec::new()),
+    });
+    let email = Arc::new(FakeEmailService::default());
+    let audit = Arc::new(FakeAuditRepository::default());
+    let service = RecordImportService::new(
+        AuthorizationService::new(
+            Arc::new(FakeAuthorizationRepository {
+                grants: HashMap::from([(
+                    (tenant_id, "alice".to_owned()),
+                    vec![
+                        Permission::MetadataFieldRead,
+                        Permission::MetadataFieldWrite,
+                    ],
+                )]),
+            }),
+            audit.clone(),
+        ),
+        repository.clone(),
+        runtime.clone(),
+        fetcher.clone(),
+        email.clone(),
+        audit.clone(),
+    );
+
+    Harness {
+        service,
+        repository,
+        fetcher,
+        runtime,
+        email,
+        audit,
+        admin,
+    }
+}
+
+fn mapping(source_column: &str, field_logical_name: &str) -> RecordImportColumnMapping {
+    RecordImportColumnMapping {
+        source_column: source_column.to_owned(),
+        field_logical_name: field_logical_name.to_owned(),
+    }
+}
+
+fn schedule_input(template_id: Option<String>) -> CreateRecordImportScheduleInput {
+    CreateRecordImportScheduleInput {
+        name: "Nightly accounts".to_owned(),
+        entity_logical_name: "account".to_owned(),
+        template_id,
+        source_kind: RecordImportSourceKind::Https,
+        source_url: "https://files.example.com/exports/accounts.csv".to_owned(),
+        auth_header_name: None,
+        auth_secret_ref: None,
+        frequency: RecordImportFrequency::Daily,
+    }
+}
+
+#[tokio::test]
+async fn uploaded_files_map_template_columns_and_report_rejected_rows() {
+    let harness = harness();
+    let template = harness
+        .service
+        .create_template(
+            &harness.admin,
+            SaveRecordImportMappingTemplateInput {
+                entity_logical_name: "account".to_owned(),
+                name: " CRM export ".to_owned(),
+                column_mappings: vec![
+                    mapping("Company", "name"),
+                    mapping("Staff", "employees"),
+                    mapping("Active?", "active"),
+                ],
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(template.name, "CRM export");
+
+    let content = "\u{feff}Company,Staff,Active?,Ignored\r\n\
+                   \"Acme, Inc.\",12,yes,x\r\n\
+                   \"Multi\nline\",1.5,no,\r\n\
+                   \r\n\
+                   Broken,many,yes,\r\n\
+                   ,3,true,\r\n";
+    let summary = harness
+        .service
+        .import_file(
+            &harness.admin,
+            "account",
+            Some(template.template_id.as_str()),
+            content.as_bytes(),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    assert_eq!(summary.imported_count, 2);
+    assert_eq!(summary.failed_count, 2);
+    assert_eq!(
+        summary
+            .row_errors
+            .iter()
+            .map(|row_error| row_error.line_number)
+            .collect::<Vec<_>>(),
+        vec![6, 7]
+    );
+    assert!(summary.row_errors[0].message.contains("not a number"));
+    assert_eq!(
+        harness
+            .runtime
+            .records
+            .lock()
+            .await
+            .iter()
+            .map(|(_, data)| data.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            json!({"name": "Acme, Inc.", "employees": 12, "active": true}),
+            json!({"name": "Multi\nline", "employees": 1.5, "active": false}),
+        ]
+    );
+
+    let unmapped = harness
+        .service
+        .import_file(&harness.admin, "account", None, content.as_bytes())
+        .await;
+    assert!(matches!(unmapped, Err(AppError::Validation(message)) if message.contains("Company")));
+
+    let unknown_field = harness
+        .service
+        .create_template(
+            &harness.admin,
+            SaveRecordImportMappingTemplateInput {
+                entity_logical_name: "account".to_owned(),
+                name: "Bad".to_owned(),
+                column_mappings: vec![mapping("Company", "missing")],
+            },
+        )
+        .await;
+    assert!(matches!(unknown_field, Err(AppError::Validation(_))));
+
+    let events = harness.audit.events.lock().await;
+    assert!(
+        events
+            .iter()
+            .any(|event| event.action == AuditAction::RecordImportCompleted)
+    );
+}
+
+#[tokio::test]
+async fn scheduled_imports_skip_unchanged_files_and_notify_owner_on_failures() {
+    let harness = harness();
+    let template = harness
+        .service
+        .create_template(
+            &harness.admin,
+            SaveRecordImportMappingTemplateInput {
+                entity_logical_name: "account".to_owned(),
+                name: "CRM export".to_owned(),
+                column_mappings: vec![mapping("Company", "name")],
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let schedule = harness
+        .service
+        .create_schedule(&harness.admin, schedule_input(Some(template.template_id)))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let schedule_id = schedule.schedule_id.as_str();
+
+    harness.fetcher.serve(Some("Company\nAcme\nGlobex\n")).await;
+    assert_eq!(harness.service.run_due_schedules(10).await.ok(), Some(1));
+    let imported = harness.repository.schedule(schedule_id).await;
+    assert_eq!(
+        imported.last_run_status,
+        Some(RecordImportRunStatus::Imported)
+    );
+    assert_eq!(imported.last_imported_count, 2);
+    assert!(imported.next_run_at > Utc::now() + Duration::hours(23));
+    assert_eq!(harness.runtime.records.lock().await.len(), 2);
+    assert!(harness.email.sent.lock().await.is_empty());
+
+    // Not due yet, so nothing is fetched.
+    assert_eq!(harness.service.run_due_schedules(10).await.ok(), Some(0));
+    assert_eq!(*harness.fetcher.fetches.lock().await, 1);
+
+    harness.repository.make_due(schedule_id).await;
+    assert_eq!(harness.service.run_due_schedules(10).await.ok(), Some(1));
+    let unchanged = harness.repository.schedule(schedule_id).await;
+    assert_eq!(
+        unchanged.last_run_status,
+        Some(RecordImportRunStatus::Unchanged)
+    );
+    assert_eq!(unchanged.last_file_sha256, imported.last_file_sha256);
+    assert_eq!(harness.runtime.records.lock().await.len(), 2);
+
+    harness.fetcher.serve(None).await;
+    harness.repository.make_due(schedule_id).await;
+    assert_eq!(harness.service.run_due_schedules(10).await.ok(), Some(1));
+    let failed = harness.repository.schedule(schedule_id).await;
+    assert_eq!(failed.last_run_status, Some(RecordImportRunStatus::Failed));
+    assert!(
+        failed
+            .last_error
+            .as_deref()
+            .is_some_and(|error| error.contains("status 503"))
+    );
+    assert_eq!(failed.last_file_sha256, imported.last_file_sha256);
+
+    harness
+        .fetcher
+        .serve(Some("Company,Other\nInitech,x\n,y\n"))
+        .await;
+    harness.repository.make_due(schedule_id).await;
+    assert_eq!(harness.service.run_due_schedules(10).await.ok(), Some(1));
+    let partial = harness.repository.schedule(schedule_id).await;
+    assert_eq!(partial.last_imported_count, 1);
+    assert_eq!(partial.last_failed_count, 1);
+    assert_ne!(partial.last_file_sha256, imported.last_file_sha256);
+
+    let sent = harness.email.sent.lock().await;
+    assert_eq!(sent.len(), 2);
+    assert!(sent.iter().all(|(to, _, _)| to == "alice@example.com"));
+    assert!(sent[0].1.contains("import failed"));
+    assert!(sent[0].2.contains("status 503"));
+    assert!(sent[1].1.contains("rejected rows"));
+    assert!(sent[1].2.contains("line 3: "));
+    assert!(sent[1].2.contains("missing required field 'name'"));
+}
+
+#[tokio::test]
+async fn recurring_import_settings_are_validated() {
+    let harness = harness();
+
+    let mut plain_http = schedule_input(None);
+    plain_http.source_url = "http://files.example.com/accounts.csv".to_owned();
+    assert!(matches!(
+        harness
+            .service
+            .create_schedule(&harness.admin, plain_http)
+            .await,
+        Err(AppError::Validation(_))
+    ));
+
+    let mut sftp_password_without_user = schedule_input(None);
+    sftp_password_without_user.source_kind = RecordImportSourceKind::Sftp;
+    sftp_password_without_user.source_url = "sftp://files.example.com/accounts.csv".to_owned();
+    sftp_password_without_user.auth_secret_ref = Some("op://vault/sftp/password".to_owned());
+    assert!(matches!(
+        harness
+            .service
+            .create_schedule(&harness.admin, sftp_password_without_user)
+            .await,
+        Err(AppError::Validation(_))
+    ));
+
+    let mut other_entity = schedule_input(None);
+    other_entity.entity_logical_name = "contact".to_owned();
+    assert!(matches!(
+        harness
+            .service
+            .create_schedule(&harness.admin, other_entity)
+            .await,
+        Err(AppError::NotFound(_))
+    ));
+
+    let no_email = UserIdentity::new("alice", "Alice", None, harness.admin.tenant_id());
+    assert!(matches!(
+        harness
+            .service
+            .create_schedule(&no_email, schedule_input(None))
+            .await,
+        Err(AppError::Validation(_))
+    ));
+
+    let template = harness
+        .service
+        .create_template(
+            &harness.admin,
+            SaveRecordImportMappingTemplateInput {
+                entity_logical_name: "account".to_owned(),
+                name: "CRM export".to_owned(),
+                column_mappings: vec![mapping("Company", "name")],
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let mut sftp = schedule_input(Some(template.template_id.clone()));
+    sftp.source_kind = RecordImportSourceKind::Sftp;
+    sftp.source_url = "sftp://importer@files.example.com/outbox/accounts.csv".to_owned();
+    sftp.auth_secret_ref = Some("op://vault/sftp/password".to_owned());
+    let schedule = harness
+        .service
+        .create_schedule(&harness.admin, sftp)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(schedule.owner_email, "alice@example.com");
+
+    assert!(matches!(
+        harness
+            .service
+            .delete_template(&harness.admin, "account", template.template_id.as_str())
+            .await,
+        Err(AppError::Conflict(_))
+    ));
+
+    let paused = harness
+        .service
+        .set_schedule_enabled(&harness.admin, schedule.schedule_id.as_str(), false)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(!paused.is_enabled);
+
+    harness
+        .service
+        .delete_schedule(&harness.admin, schedule.schedule_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    harness
+        .service
+        .delete_template(&harness.admin, "account", template.template_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let outsider = UserIdentity::new(
+        "mallory",
+        "Mallory",
+        Some("mallory@example.com".to_owned()),
+        harness.admin.tenant_id(),
+    );
+    assert!(matches!(
+        harness
+            .service
+            .create_schedule(&outsider, schedule_input(None))
+            .await,
+        Err(AppError::Forbidden(_))
+    ));
+}
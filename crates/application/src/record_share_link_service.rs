@@ -0,0 +1,20 @@
+//! Read-only external share links for runtime record summaries.
+//!
+//! Subjects holding `runtime.record_share_link.manage` can publish a link that
+//! renders selected fields of a record they can read to anyone holding the
+//! URL. Links expire, may require a password, can be revoked, and every
+//! external access attempt is logged with its outcome.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    CreateRecordShareLinkInput, CreatedRecordShareLink, DEFAULT_RECORD_SHARE_LINK_HOURS,
+    NewRecordShareLink, RecordShareLink, RecordShareLinkAccess, RecordShareLinkRepository,
+    RecordShareLinkSummary, RecordShareLinkSummaryField, RecordShareLinkView,
+    RecordShareLinkViewContext, RecordShareLinkViewOutcome,
+};
+pub use service::RecordShareLinkService;
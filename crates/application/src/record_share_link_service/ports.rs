@@ -0,0 +1,261 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+/// Link lifetime applied when the creator does not ask for one.
+pub const DEFAULT_RECORD_SHARE_LINK_HOURS: u16 = 72;
+
+/// Input payload for creating an external share link for a runtime record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateRecordShareLinkInput {
+    /// Field logical names rendered in the shared summary, in display order.
+    pub field_logical_names: Vec<String>,
+    /// Requested link lifetime in hours.
+    pub expires_in_hours: Option<u16>,
+    /// Optional password viewers must enter before the summary is rendered.
+    pub password: Option<String>,
+}
+
+/// Link data persisted by the repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewRecordShareLink {
+    /// Stable link identifier.
+    pub link_id: String,
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Runtime record identifier.
+    pub record_id: String,
+    /// Field logical names rendered in the shared summary.
+    pub field_logical_names: Vec<String>,
+    /// SHA-256 hash of the raw link token.
+    pub token_hash: String,
+    /// Password hash when the link is password-protected.
+    pub password_hash: Option<String>,
+    /// Expiry timestamp.
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Stored external share link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordShareLink {
+    /// Stable link identifier.
+    pub link_id: String,
+    /// Owning tenant.
+    pub tenant_id: TenantId,
+    /// Entity logical name.
+    pub entity_logical_name: String,
+    /// Runtime record identifier.
+    pub record_id: String,
+    /// Field logical names rendered in the shared summary.
+    pub field_logical_names: Vec<String>,
+    /// Password hash when the link is password-protected.
+    pub password_hash: Option<String>,
+    /// Subject that created the link; the summary is read with their field access.
+    pub created_by_subject: String,
+    /// Creation timestamp.
+    pub created_at: DateTime<Utc>,
+    /// Expiry timestamp.
+    pub expires_at: DateTime<Utc>,
+    /// Subject that revoked the link.
+    pub revoked_by_subject: Option<String>,
+    /// Revocation timestamp.
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// Number of successful external views.
+    pub view_count: i64,
+    /// Timestamp of the last successful external view.
+    pub last_viewed_at: Option<DateTime<Utc>>,
+}
+
+impl RecordShareLink {
+    /// Returns whether the link may be opened at `now`.
+    #[must_use]
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+
+    /// Returns whether viewers must enter a password.
+    #[must_use]
+    pub fn requires_password(&self) -> bool {
+        self.password_hash.is_some()
+    }
+}
+
+/// Newly created link together with its raw token.
+///
+/// The raw token is only available at creation time; storage keeps its hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedRecordShareLink {
+    /// Stored link.
+    pub link: RecordShareLink,
+    /// Raw token embedded in the external URL.
+    pub token: String,
+}
+
+/// Outcome recorded for one external access attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordShareLinkViewOutcome {
+    /// The summary was rendered.
+    Viewed,
+    /// The link is password-protected and no password was supplied.
+    PasswordRequired,
+    /// The supplied password did not match.
+    InvalidPassword,
+    /// The link expired.
+    Expired,
+    /// The link was revoked.
+    Revoked,
+    /// The record or the creator's access to it no longer exists.
+    Unavailable,
+}
+
+impl RecordShareLinkViewOutcome {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Viewed => "viewed",
+            Self::PasswordRequired => "password_required",
+            Self::InvalidPassword => "invalid_password",
+            Self::Expired => "expired",
+            Self::Revoked => "revoked",
+            Self::Unavailable => "unavailable",
+        }
+    }
+
+    /// Parses a storage value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "viewed" => Ok(Self::Viewed),
+            "password_required" => Ok(Self::PasswordRequired),
+            "invalid_password" => Ok(Self::InvalidPassword),
+            "expired" => Ok(Self::Expired),
+            "revoked" => Ok(Self::Revoked),
+            "unavailable" => Ok(Self::Unavailable),
+            _ => Err(AppError::Validation(format!(
+                "unknown record share link view outcome '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Request metadata captured for an external view.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecordShareLinkViewContext {
+    /// Client IP address.
+    pub ip_address: Option<String>,
+    /// Client user agent.
+    pub user_agent: Option<String>,
+}
+
+/// Stored access log entry for an external view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordShareLinkView {
+    /// Link identifier.
+    pub link_id: String,
+    /// Access outcome.
+    pub outcome: RecordShareLinkViewOutcome,
+    /// Client IP address.
+    pub ip_address: Option<String>,
+    /// Client user agent.
+    pub user_agent: Option<String>,
+    /// Access timestamp.
+    pub viewed_at: DateTime<Utc>,
+}
+
+/// One rendered field of a shared record summary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordShareLinkSummaryField {
+    /// Field display name.
+    pub label: String,
+    /// Display value; empty when the record has no value.
+    pub value: String,
+}
+
+/// Read-only record summary served to external viewers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordShareLinkSummary {
+    /// Entity display name.
+    pub entity_display_name: String,
+    /// Runtime record identifier.
+    pub record_id: String,
+    /// Selected fields in display order.
+    pub fields: Vec<RecordShareLinkSummaryField>,
+    /// Link expiry timestamp.
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Result of opening an external share link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordShareLinkAccess {
+    /// The summary can be rendered.
+    Granted(RecordShareLinkSummary),
+    /// The viewer must submit a password; `invalid` is set after a failed attempt.
+    PasswordRequired {
+        /// Whether a wrong password was just submitted.
+        invalid: bool,
+    },
+    /// The link is expired, revoked, or no longer resolves to a readable record.
+    Unavailable,
+}
+
+/// Repository port for external record share links and their access log.
+#[async_trait]
+pub trait RecordShareLinkRepository: Send + Sync {
+    /// Persists a new link.
+    async fn create_link(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        link: NewRecordShareLink,
+    ) -> AppResult<RecordShareLink>;
+
+    /// Lists links for a record, newest first.
+    async fn list_links(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RecordShareLink>>;
+
+    /// Finds one link by identifier.
+    async fn find_link(
+        &self,
+        tenant_id: TenantId,
+        link_id: &str,
+    ) -> AppResult<Option<RecordShareLink>>;
+
+    /// Finds one link by the hash of its raw token.
+    async fn find_link_by_token_hash(
+        &self,
+        tenant_id: TenantId,
+        token_hash: &str,
+    ) -> AppResult<Option<RecordShareLink>>;
+
+    /// Revokes a link.
+    ///
+    /// Returns `None` when the link is already revoked.
+    async fn revoke_link(
+        &self,
+        tenant_id: TenantId,
+        link_id: &str,
+        revoked_by_subject: &str,
+    ) -> AppResult<Option<RecordShareLink>>;
+
+    /// Appends an access log entry and updates view counters for successful views.
+    async fn record_view(
+        &self,
+        tenant_id: TenantId,
+        link_id: &str,
+        outcome: RecordShareLinkViewOutcome,
+        context: RecordShareLinkViewContext,
+    ) -> AppResult<()>;
+
+    /// Lists access log entries for a link, newest first.
+    async fn list_views(
+        &self,
+        tenant_id: TenantId,
+        link_id: &str,
+        limit: usize,
+    ) -> AppResult<Vec<RecordShareLinkView>>;
+}
@@ -0,0 +1,521 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{
+    AuditAction, EntityFieldDefinition, FieldType, Permission, PublishedEntitySchema, RuntimeRecord,
+};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::auth_token_service::token_crypto::{generate_token, hash_token};
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationService, PasswordHasher, RuntimeRecordService,
+};
+
+use super::ports::{
+    CreateRecordShareLinkInput, CreatedRecordShareLink, DEFAULT_RECORD_SHARE_LINK_HOURS,
+    NewRecordShareLink, RecordShareLink, RecordShareLinkAccess, RecordShareLinkRepository,
+    RecordShareLinkSummary, RecordShareLinkSummaryField, RecordShareLinkView,
+    RecordShareLinkViewContext, RecordShareLinkViewOutcome,
+};
+
+const MAX_RECORD_SHARE_LINK_HOURS: u16 = 720;
+const MAX_SHARED_FIELDS: usize = 50;
+const MIN_PASSWORD_LENGTH: usize = 8;
+const MAX_PASSWORD_LENGTH: usize = 128;
+const MAX_VIEW_LOG_ENTRIES: usize = 200;
+
+/// Application service for read-only external share links to runtime records.
+#[derive(Clone)]
+pub struct RecordShareLinkService {
+    authorization_service: AuthorizationService,
+    runtime_record_service: Arc<dyn RuntimeRecordService>,
+    repository: Arc<dyn RecordShareLinkRepository>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl RecordShareLinkService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        runtime_record_service: Arc<dyn RuntimeRecordService>,
+        repository: Arc<dyn RecordShareLinkRepository>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            runtime_record_service,
+            repository,
+            password_hasher,
+            audit_repository,
+        }
+    }
+
+    /// Creates a share link exposing the selected fields of a record the actor can read.
+    pub async fn create_link(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        input: CreateRecordShareLinkInput,
+    ) -> AppResult<CreatedRecordShareLink> {
+        self.require_link_rights(actor, entity_logical_name, record_id)
+            .await?;
+
+        let schema = self
+            .runtime_record_service
+            .latest_published_schema_unchecked(actor, entity_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "entity '{}' has no published schema",
+                    entity_logical_name
+                ))
+            })?;
+        let field_logical_names = validate_field_selection(&schema, input.field_logical_names)?;
+        let expires_in_hours = validate_link_hours(input.expires_in_hours)?;
+        let password_hash = match input.password {
+            Some(password) => {
+                validate_password(password.as_str())?;
+                Some(self.password_hasher.hash_password(password.as_str())?)
+            }
+            None => None,
+        };
+
+        let (token, token_hash) = generate_token()?;
+        let link = self
+            .repository
+            .create_link(
+                actor.tenant_id(),
+                actor.subject(),
+                NewRecordShareLink {
+                    link_id: Uuid::new_v4().to_string(),
+                    entity_logical_name: entity_logical_name.to_owned(),
+                    record_id: record_id.to_owned(),
+                    field_logical_names,
+                    token_hash,
+                    password_hash,
+                    expires_at: Utc::now() + Duration::hours(i64::from(expires_in_hours)),
+                },
+            )
+            .await?;
+
+        self.append_link_audit_event(actor, AuditAction::RuntimeRecordShareLinkCreated, &link)
+            .await?;
+
+        Ok(CreatedRecordShareLink { link, token })
+    }
+
+    /// Lists share links created for a record.
+    pub async fn list_links(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RecordShareLink>> {
+        self.require_link_rights(actor, entity_logical_name, record_id)
+            .await?;
+
+        self.repository
+            .list_links(actor.tenant_id(), entity_logical_name, record_id)
+            .await
+    }
+
+    /// Revokes a share link so it can no longer be opened.
+    pub async fn revoke_link(
+        &self,
+        actor: &UserIdentity,
+        link_id: &str,
+    ) -> AppResult<RecordShareLink> {
+        let link = self.find_managed_link(actor, link_id).await?;
+        if link.revoked_at.is_some() {
+            return Err(AppError::Conflict(format!(
+                "record share link '{}' is already revoked",
+                link_id
+            )));
+        }
+
+        let revoked = self
+            .repository
+            .revoke_link(actor.tenant_id(), link_id, actor.subject())
+            .await?
+            .ok_or_else(|| {
+                AppError::Conflict(format!(
+                    "record share link '{}' was revoked concurrently",
+                    link_id
+                ))
+            })?;
+
+        self.append_link_audit_event(actor, AuditAction::RuntimeRecordShareLinkRevoked, &revoked)
+            .await?;
+
+        Ok(revoked)
+    }
+
+    /// Lists the external access log for a share link.
+    pub async fn list_link_views(
+        &self,
+        actor: &UserIdentity,
+        link_id: &str,
+    ) -> AppResult<Vec<RecordShareLinkView>> {
+        self.find_managed_link(actor, link_id).await?;
+
+        self.repository
+            .list_views(actor.tenant_id(), link_id, MAX_VIEW_LOG_ENTRIES)
+            .await
+    }
+
+    /// Opens a share link on behalf of an unauthenticated external viewer.
+    ///
+    /// Every attempt against an existing link is logged with its outcome. The
+    /// record is read with the link creator's identity, so the link stops
+    /// resolving once the creator loses access and field-level redaction for
+    /// the creator still applies.
+    pub async fn open_link(
+        &self,
+        tenant_id: TenantId,
+        token: &str,
+        password: Option<&str>,
+        context: RecordShareLinkViewContext,
+    ) -> AppResult<RecordShareLinkAccess> {
+        let link = self
+            .repository
+            .find_link_by_token_hash(tenant_id, hash_token(token).as_str())
+            .await?
+            .ok_or_else(|| AppError::NotFound("record share link does not exist".to_owned()))?;
+
+        let (outcome, access) = self.resolve_access(&link, password).await?;
+        self.repository
+            .record_view(tenant_id, link.link_id.as_str(), outcome, context)
+            .await?;
+
+        Ok(access)
+    }
+
+    async fn resolve_access(
+        &self,
+        link: &RecordShareLink,
+        password: Option<&str>,
+    ) -> AppResult<(RecordShareLinkViewOutcome, RecordShareLinkAccess)> {
+        if link.revoked_at.is_some() {
+            return Ok((
+                RecordShareLinkViewOutcome::Revoked,
+                RecordShareLinkAccess::Unavailable,
+            ));
+        }
+        if !link.is_active(Utc::now()) {
+            return Ok((
+                RecordShareLinkViewOutcome::Expired,
+                RecordShareLinkAccess::Unavailable,
+            ));
+        }
+
+        if let Some(password_hash) = link.password_hash.as_deref() {
+            let Some(password) = password else {
+                return Ok((
+                    RecordShareLinkViewOutcome::PasswordRequired,
+                    RecordShareLinkAccess::PasswordRequired { invalid: false },
+                ));
+            };
+            if !self
+                .password_hasher
+                .verify_password(password, password_hash)?
+            {
+                return Ok((
+                    RecordShareLinkViewOutcome::InvalidPassword,
+                    RecordShareLinkAccess::PasswordRequired { invalid: true },
+                ));
+            }
+        }
+
+        let creator = UserIdentity::new(
+            link.created_by_subject.as_str(),
+            link.created_by_subject.as_str(),
+            None,
+            link.tenant_id,
+        );
+        match self.load_summary(&creator, link).await {
+            Ok(Some(summary)) => Ok((
+                RecordShareLinkViewOutcome::Viewed,
+                RecordShareLinkAccess::Granted(summary),
+            )),
+            Ok(None) | Err(AppError::Forbidden(_) | AppError::NotFound(_)) => Ok((
+                RecordShareLinkViewOutcome::Unavailable,
+                RecordShareLinkAccess::Unavailable,
+            )),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn load_summary(
+        &self,
+        creator: &UserIdentity,
+        link: &RecordShareLink,
+    ) -> AppResult<Option<RecordShareLinkSummary>> {
+        if !self
+            .authorization_service
+            .has_permission(
+                creator.tenant_id(),
+                creator.subject(),
+                Permission::RuntimeRecordShareLinkManage,
+            )
+            .await?
+        {
+            return Ok(None);
+        }
+        self.require_record_read(
+            creator,
+            link.entity_logical_name.as_str(),
+            link.record_id.as_str(),
+        )
+        .await?;
+
+        let Some(schema) = self
+            .runtime_record_service
+            .latest_published_schema_unchecked(creator, link.entity_logical_name.as_str())
+            .await?
+        else {
+            return Ok(None);
+        };
+        let record = self
+            .runtime_record_service
+            .get_runtime_record_unchecked(
+                creator,
+                link.entity_logical_name.as_str(),
+                link.record_id.as_str(),
+            )
+            .await?;
+
+        Ok(Some(build_summary(&schema, &record, link)))
+    }
+
+    async fn require_link_rights(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::RuntimeRecordShareLinkManage,
+            )
+            .await?;
+
+        self.require_record_read(actor, entity_logical_name, record_id)
+            .await
+    }
+
+    async fn require_record_read(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<()> {
+        if !self
+            .authorization_service
+            .has_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::RuntimeRecordRead,
+            )
+            .await?
+        {
+            self.authorization_service
+                .require_permission(
+                    actor.tenant_id(),
+                    actor.subject(),
+                    Permission::RuntimeRecordReadOwn,
+                )
+                .await?;
+        }
+
+        self.runtime_record_service
+            .get_runtime_record_unchecked(actor, entity_logical_name, record_id)
+            .await
+            .map(|_| ())
+    }
+
+    async fn find_managed_link(
+        &self,
+        actor: &UserIdentity,
+        link_id: &str,
+    ) -> AppResult<RecordShareLink> {
+        let link = self
+            .repository
+            .find_link(actor.tenant_id(), link_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("record share link '{}' does not exist", link_id))
+            })?;
+        self.require_link_rights(
+            actor,
+            link.entity_logical_name.as_str(),
+            link.record_id.as_str(),
+        )
+        .await?;
+
+        Ok(link)
+    }
+
+    async fn append_link_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        link: &RecordShareLink,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "runtime_record_share_link".to_owned(),
+                resource_id: link.link_id.clone(),
+                detail: Some(
+                    serde_json::json!({
+                        "entity_logical_name": link.entity_logical_name,
+                        "record_id": link.record_id,
+                        "field_logical_names": link.field_logical_names,
+                        "password_protected": link.requires_password(),
+                        "expires_at": link.expires_at.to_rfc3339(),
+                    })
+                    .to_string(),
+                ),
+            })
+            .await
+    }
+}
+
+fn validate_field_selection(
+    schema: &PublishedEntitySchema,
+    field_logical_names: Vec<String>,
+) -> AppResult<Vec<String>> {
+    if field_logical_names.is_empty() {
+        return Err(AppError::Validation(
+            "record share link must include at least one field".to_owned(),
+        ));
+    }
+    if field_logical_names.len() > MAX_SHARED_FIELDS {
+        return Err(AppError::Validation(format!(
+            "record share link can include at most {MAX_SHARED_FIELDS} fields"
+        )));
+    }
+
+    let mut seen = HashSet::new();
+    for field_logical_name in &field_logical_names {
+        if !seen.insert(field_logical_name.as_str()) {
+            return Err(AppError::Validation(format!(
+                "field '{}' is selected more than once",
+                field_logical_name
+            )));
+        }
+        if !schema
+            .fields()
+            .iter()
+            .any(|field| field.logical_name().as_str() == field_logical_name)
+        {
+            return Err(AppError::Validation(format!(
+                "field '{}' does not exist on entity '{}'",
+                field_logical_name,
+                schema.entity().logical_name().as_str()
+            )));
+        }
+    }
+
+    Ok(field_logical_names)
+}
+
+fn validate_link_hours(expires_in_hours: Option<u16>) -> AppResult<u16> {
+    let expires_in_hours = expires_in_hours.unwrap_or(DEFAULT_RECORD_SHARE_LINK_HOURS);
+    if expires_in_hours == 0 || expires_in_hours > MAX_RECORD_SHARE_LINK_HOURS {
+        return Err(AppError::Validation(format!(
+            "record share link lifetime must be between 1 and {MAX_RECORD_SHARE_LINK_HOURS} hours"
+        )));
+    }
+
+    Ok(expires_in_hours)
+}
+
+fn validate_password(password: &str) -> AppResult<()> {
+    let length = password.chars().count();
+    if !(MIN_PASSWORD_LENGTH..=MAX_PASSWORD_LENGTH).contains(&length) {
+        return Err(AppError::Validation(format!(
+            "record share link password must be between {MIN_PASSWORD_LENGTH} and {MAX_PASSWORD_LENGTH} characters"
+        )));
+    }
+
+    Ok(())
+}
+
+fn build_summary(
+    schema: &PublishedEntitySchema,
+    record: &RuntimeRecord,
+    link: &RecordShareLink,
+) -> RecordShareLinkSummary {
+    let fields = link
+        .field_logical_names
+        .iter()
+        .filter_map(|field_logical_name| {
+            let field = schema
+                .fields()
+                .iter()
+                .find(|field| field.logical_name().as_str() == field_logical_name)?;
+            let value = record
+                .data()
+                .get(field_logical_name.as_str())
+                .map(|value| display_value(schema, field, value))
+                .unwrap_or_default();
+
+            Some(RecordShareLinkSummaryField {
+                label: field.display_name().as_str().to_owned(),
+                value,
+            })
+        })
+        .collect();
+
+    RecordShareLinkSummary {
+        entity_display_name: schema.entity().display_name().as_str().to_owned(),
+        record_id: link.record_id.clone(),
+        fields,
+        expires_at: link.expires_at,
+    }
+}
+
+fn display_value(
+    schema: &PublishedEntitySchema,
+    field: &EntityFieldDefinition,
+    value: &Value,
+) -> String {
+    let option_label = |option_value: &Value| -> Option<String> {
+        let option_value = i32::try_from(option_value.as_i64()?).ok()?;
+        let option_set_logical_name = field.option_set_logical_name()?;
+        schema
+            .option_sets()
+            .iter()
+            .find(|set| set.logical_name().as_str() == option_set_logical_name.as_str())?
+            .options()
+            .iter()
+            .find(|item| item.value() == option_value)
+            .map(|item| item.label().as_str().to_owned())
+    };
+
+    match (field.field_type(), value) {
+        (_, Value::Null) => String::new(),
+        (FieldType::Choice, _) => option_label(value).unwrap_or_else(|| value.to_string()),
+        (FieldType::MultiChoice, Value::Array(items)) => items
+            .iter()
+            .map(|item| option_label(item).unwrap_or_else(|| item.to_string()))
+            .collect::<Vec<_>>()
+            .join(", "),
+        (FieldType::Boolean, Value::Bool(true)) => "Yes".to_owned(),
+        (FieldType::Boolean, Value::Bool(false)) => "No".to_owned(),
+        (_, Value::String(text)) => text.clone(),
+        _ => value.to_string(),
+    }
+}
@@ -0,0 +1,650 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::{Value, json};
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{
+    AuditAction, EntityDefinition, EntityFieldDefinition, FieldType, FormDefinition, Permission,
+    PublishedEntitySchema, RuntimeRecord, ViewDefinition,
+};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, PasswordHasher,
+    RecordListQuery, RuntimeFieldGrant, RuntimeRecordQuery, RuntimeRecordService,
+    TemporaryPermissionGrant,
+};
+
+use super::{
+    CreateRecordShareLinkInput, NewRecordShareLink, RecordShareLink, RecordShareLinkAccess,
+    RecordShareLinkRepository, RecordShareLinkService, RecordShareLinkView,
+    RecordShareLinkViewContext, RecordShareLinkViewOutcome,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+struct FakePasswordHasher;
+
+impl PasswordHasher for FakePasswordHasher {
+    fn hash_password(&self, password: &str) -> AppResult<String> {
+        Ok(format!("hashed:{password}"))
+    }
+
+    fn verify_password(&self, password: &str, hash: &str) -> AppResult<bool> {
+        Ok(hash == format!("hashed:{password}"))
+    }
+}
+
+struct FakeRuntimeRecordService {
+    schema: PublishedEntitySchema,
+    records: HashMap<String, Value>,
+}
+
+#[async_trait]
+impl RuntimeRecordService for FakeRuntimeRecordService {
+    async fn latest_published_schema_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        Ok(Some(self.schema.clone()))
+    }
+
+    async fn list_runtime_records_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+        _query: RecordListQuery,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        Ok(Vec::new())
+    }
+
+    async fn query_runtime_records_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+        _query: RuntimeRecordQuery,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        Ok(Vec::new())
+    }
+
+    async fn get_runtime_record_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<RuntimeRecord> {
+        let data = self.records.get(record_id).cloned().ok_or_else(|| {
+            AppError::NotFound(format!("runtime record '{record_id}' does not exist"))
+        })?;
+        RuntimeRecord::new(record_id, entity_logical_name, data)
+    }
+
+    async fn create_runtime_record_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+        data: Value,
+    ) -> AppResult<RuntimeRecord> {
+        RuntimeRecord::new("record-1", entity_logical_name, data)
+    }
+
+    async fn update_runtime_record_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        data: Value,
+        _expected_version: Option<i64>,
+    ) -> AppResult<RuntimeRecord> {
+        RuntimeRecord::new(record_id, entity_logical_name, data)
+    }
+
+    async fn patch_runtime_record_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+        record_id: &str,
+        patch: &Value,
+    ) -> AppResult<RuntimeRecord> {
+        RuntimeRecord::new(record_id, entity_logical_name, patch.clone())
+    }
+
+    async fn delete_runtime_record_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+        _record_id: &str,
+    ) -> AppResult<()> {
+        Ok(())
+    }
+
+    async fn list_forms_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<FormDefinition>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_form_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+        _form_logical_name: &str,
+    ) -> AppResult<Option<FormDefinition>> {
+        Ok(None)
+    }
+
+    async fn list_views_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<ViewDefinition>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_view_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        _entity_logical_name: &str,
+        _view_logical_name: &str,
+    ) -> AppResult<Option<ViewDefinition>> {
+        Ok(None)
+    }
+}
+
+#[derive(Default)]
+struct FakeRecordShareLinkRepository {
+    links: Mutex<Vec<(String, RecordShareLink)>>,
+    views: Mutex<Vec<RecordShareLinkView>>,
+}
+
+#[async_trait]
+impl RecordShareLinkRepository for FakeRecordShareLinkRepository {
+    async fn create_link(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        link: NewRecordShareLink,
+    ) -> AppResult<RecordShareLink> {
+        let stored = RecordShareLink {
+            link_id: link.link_id,
+            tenant_id,
+            entity_logical_name: link.entity_logical_name,
+            record_id: link.record_id,
+            field_logical_names: link.field_logical_names,
+            password_hash: link.password_hash,
+            created_by_subject: created_by_subject.to_owned(),
+            created_at: Utc::now(),
+            expires_at: link.expires_at,
+            revoked_by_subject: None,
+            revoked_at: None,
+            view_count: 0,
+            last_viewed_at: None,
+        };
+        self.links
+            .lock()
+            .await
+            .push((link.token_hash, stored.clone()));
+        Ok(stored)
+    }
+
+    async fn list_links(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RecordShareLink>> {
+        Ok(self
+            .links
+            .lock()
+            .await
+            .iter()
+            .filter(|(_, link)| {
+                link.entity_logical_name == entity_logical_name && link.record_id == record_id
+            })
+            .map(|(_, link)| link.clone())
+            .collect())
+    }
+
+    async fn find_link(
+        &self,
+        _tenant_id: TenantId,
+        link_id: &str,
+    ) -> AppResult<Option<RecordShareLink>> {
+        Ok(self
+            .links
+            .lock()
+            .await
+            .iter()
+            .find(|(_, link)| link.link_id == link_id)
+            .map(|(_, link)| link.clone()))
+    }
+
+    async fn find_link_by_token_hash(
+        &self,
+        _tenant_id: TenantId,
+        token_hash: &str,
+    ) -> AppResult<Option<RecordShareLink>> {
+        Ok(self
+            .links
+            .lock()
+            .await
+            .iter()
+            .find(|(hash, _)| hash == token_hash)
+            .map(|(_, link)| link.clone()))
+    }
+
+    async fn revoke_link(
+        &self,
+        _tenant_id: TenantId,
+        link_id: &str,
+        revoked_by_subject: &str,
+    ) -> AppResult<Option<RecordShareLink>> {
+        let mut links = self.links.lock().await;
+        let Some((_, link)) = links
+            .iter_mut()
+            .find(|(_, link)| link.link_id == link_id && link.revoked_at.is_none())
+        else {
+            return Ok(None);
+        };
+        link.revoked_by_subject = Some(revoked_by_subject.to_owned());
+        link.revoked_at = Some(Utc::now());
+        Ok(Some(link.clone()))
+    }
+
+    async fn record_view(
+        &self,
+        _tenant_id: TenantId,
+        link_id: &str,
+        outcome: RecordShareLinkViewOutcome,
+        context: RecordShareLinkViewContext,
+    ) -> AppResult<()> {
+        self.views.lock().await.push(RecordShareLinkView {
+            link_id: link_id.to_owned(),
+            outcome,
+            ip_address: context.ip_address,
+            user_agent: context.user_agent,
+            viewed_at: Utc::now(),
+        });
+        Ok(())
+    }
+
+    async fn list_views(
+        &self,
+        _tenant_id: TenantId,
+        link_id: &str,
+        limit: usize,
+    ) -> AppResult<Vec<RecordShareLinkView>> {
+        Ok(self
+            .views
+            .lock()
+            .await
+            .iter()
+            .rev()
+            .filter(|view| view.link_id == link_id)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
+
+fn contact_schema() -> PublishedEntitySchema {
+    let entity = EntityDefinition::new("contact", "Contact").unwrap_or_else(|_| unreachable!());
+    let fields = vec![
+        EntityFieldDefinition::new(
+            "contact",
+            "name",
+            "Name",
+            FieldType::Text,
+            true,
+            false,
+            None,
+            None,
+        )
+        .unwrap_or_else(|_| unreachable!()),
+        EntityFieldDefinition::new(
+            "contact",
+            "is_active",
+            "Active",
+            FieldType::Boolean,
+            false,
+            false,
+            None,
+            None,
+        )
+        .unwrap_or_else(|_| unreachable!()),
+        EntityFieldDefinition::new(
+            "contact",
+            "salary",
+            "Salary",
+            FieldType::Number,
+            false,
+            false,
+            None,
+            None,
+        )
+        .unwrap_or_else(|_| unreachable!()),
+    ];
+    PublishedEntitySchema::new(entity, 1, fields, Vec::new()).unwrap_or_else(|_| unreachable!())
+}
+
+struct Harness {
+    service: RecordShareLinkService,
+    repository: Arc<FakeRecordShareLinkRepository>,
+    audit_repository: Arc<FakeAuditRepository>,
+}
+
+fn build_harness(grants: HashMap<(TenantId, String), Vec<Permission>>) -> Harness {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let repository = Arc::new(FakeRecordShareLinkRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository { grants }),
+        audit_repository.clone(),
+    );
+    let runtime_record_service = Arc::new(FakeRuntimeRecordService {
+        schema: contact_schema(),
+        records: HashMap::from([(
+            "record-1".to_owned(),
+            json!({"name": "Ada Lovelace", "is_active": true, "salary": 1000}),
+        )]),
+    });
+
+    Harness {
+        service: RecordShareLinkService::new(
+            authorization_service,
+            runtime_record_service,
+            repository.clone(),
+            Arc::new(FakePasswordHasher),
+            audit_repository.clone(),
+        ),
+        repository,
+        audit_repository,
+    }
+}
+
+fn link_manager_grants(
+    tenant_id: TenantId,
+    subject: &str,
+) -> HashMap<(TenantId, String), Vec<Permission>> {
+    HashMap::from([(
+        (tenant_id, subject.to_owned()),
+        vec![
+            Permission::RuntimeRecordRead,
+            Permission::RuntimeRecordShareLinkManage,
+        ],
+    )])
+}
+
+#[tokio::test]
+async fn share_link_renders_only_selected_fields_after_password_check() {
+    let tenant_id = TenantId::new();
+    let alice = UserIdentity::new("alice", "alice", None, tenant_id);
+    let harness = build_harness(link_manager_grants(tenant_id, "alice"));
+
+    let unknown_field = harness
+        .service
+        .create_link(
+            &alice,
+            "contact",
+            "record-1",
+            CreateRecordShareLinkInput {
+                field_logical_names: vec!["name".to_owned(), "ssn".to_owned()],
+                expires_in_hours: None,
+                password: None,
+            },
+        )
+        .await;
+    assert!(matches!(unknown_field, Err(AppError::Validation(_))));
+
+    let created = harness
+        .service
+        .create_link(
+            &alice,
+            "contact",
+            "record-1",
+            CreateRecordShareLinkInput {
+                field_logical_names: vec!["name".to_owned(), "is_active".to_owned()],
+                expires_in_hours: Some(24),
+                password: Some("correct horse".to_owned()),
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(created.link.requires_password());
+    assert_eq!(created.token.len(), 64);
+
+    let without_password = harness
+        .service
+        .open_link(
+            tenant_id,
+            created.token.as_str(),
+            None,
+            RecordShareLinkViewContext::default(),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        without_password,
+        RecordShareLinkAccess::PasswordRequired { invalid: false }
+    );
+
+    let wrong_password = harness
+        .service
+        .open_link(
+            tenant_id,
+            created.token.as_str(),
+            Some("wrong password"),
+            RecordShareLinkViewContext::default(),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        wrong_password,
+        RecordShareLinkAccess::PasswordRequired { invalid: true }
+    );
+
+    let granted = harness
+        .service
+        .open_link(
+            tenant_id,
+            created.token.as_str(),
+            Some("correct horse"),
+            RecordShareLinkViewContext {
+                ip_address: Some("203.0.113.7".to_owned()),
+                user_agent: Some("curl/8".to_owned()),
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let RecordShareLinkAccess::Granted(summary) = granted else {
+        unreachable!();
+    };
+    assert_eq!(summary.entity_display_name, "Contact");
+    let rendered: Vec<(&str, &str)> = summary
+        .fields
+        .iter()
+        .map(|field| (field.label.as_str(), field.value.as_str()))
+        .collect();
+    assert_eq!(rendered, vec![("Name", "Ada Lovelace"), ("Active", "Yes")]);
+
+    let outcomes: Vec<RecordShareLinkViewOutcome> = harness
+        .service
+        .list_link_views(&alice, created.link.link_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .into_iter()
+        .map(|view| view.outcome)
+        .collect();
+    assert_eq!(
+        outcomes,
+        vec![
+            RecordShareLinkViewOutcome::Viewed,
+            RecordShareLinkViewOutcome::InvalidPassword,
+            RecordShareLinkViewOutcome::PasswordRequired,
+        ]
+    );
+    assert!(
+        harness
+            .audit_repository
+            .events
+            .lock()
+            .await
+            .iter()
+            .any(|event| event.action == AuditAction::RuntimeRecordShareLinkCreated)
+    );
+}
+
+#[tokio::test]
+async fn revoked_share_link_is_unavailable_and_requires_manage_permission() {
+    let tenant_id = TenantId::new();
+    let alice = UserIdentity::new("alice", "alice", None, tenant_id);
+    let bob = UserIdentity::new("bob", "bob", None, tenant_id);
+    let mut grants = link_manager_grants(tenant_id, "alice");
+    grants.insert(
+        (tenant_id, "bob".to_owned()),
+        vec![Permission::RuntimeRecordRead],
+    );
+    let harness = build_harness(grants);
+
+    let denied = harness
+        .service
+        .create_link(
+            &bob,
+            "contact",
+            "record-1",
+            CreateRecordShareLinkInput {
+                field_logical_names: vec!["name".to_owned()],
+                expires_in_hours: None,
+                password: None,
+            },
+        )
+        .await;
+    assert!(matches!(denied, Err(AppError::Forbidden(_))));
+
+    let created = harness
+        .service
+        .create_link(
+            &alice,
+            "contact",
+            "record-1",
+            CreateRecordShareLinkInput {
+                field_logical_names: vec!["name".to_owned()],
+                expires_in_hours: None,
+                password: None,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let revoked = harness
+        .service
+        .revoke_link(&alice, created.link.link_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(revoked.revoked_by_subject.as_deref(), Some("alice"));
+
+    let access = harness
+        .service
+        .open_link(
+            tenant_id,
+            created.token.as_str(),
+            None,
+            RecordShareLinkViewContext::default(),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(access, RecordShareLinkAccess::Unavailable);
+
+    let unknown_token = harness
+        .service
+        .open_link(
+            tenant_id,
+            "not-a-token",
+            None,
+            RecordShareLinkViewContext::default(),
+        )
+        .await;
+    assert!(matches!(unknown_token, Err(AppError::NotFound(_))));
+
+    let views = harness.repository.views.lock().await;
+    assert_eq!(views.len(), 1);
+    assert_eq!(views[0].outcome, RecordShareLinkViewOutcome::Revoked);
+}
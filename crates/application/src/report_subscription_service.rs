@@ -0,0 +1,23 @@
+//! Scheduled email delivery of saved views.
+//!
+//! A user subscribes to a saved view with a daily or weekly cadence and a
+//! file format. A dispatcher claims due subscriptions across tenants, renders
+//! the view as the subscriber would see it, and mails the file. Subscriptions
+//! are suppressed rather than deleted when their view disappears or the
+//! subscriber can no longer export it, so the owner can see why delivery
+//! stopped.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    DueReportSubscription, NewReportSubscription, ReportSubscription, ReportSubscriptionFormat,
+    ReportSubscriptionFrequency, ReportSubscriptionRepository,
+};
+pub use service::{
+    CreateReportSubscriptionInput, DEFAULT_REPORT_SUBSCRIPTION_TENANT_LIMIT,
+    REPORT_SUBSCRIPTION_MAX_ROWS, ReportDelivery, ReportSubscriptionService,
+};
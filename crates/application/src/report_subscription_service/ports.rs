@@ -0,0 +1,215 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+/// Delivery cadence of a report subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportSubscriptionFrequency {
+    /// Delivered once a day.
+    Daily,
+    /// Delivered once a week, on the weekday of the first delivery.
+    Weekly,
+}
+
+impl ReportSubscriptionFrequency {
+    /// Returns a stable storage value for this frequency.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+        }
+    }
+
+    /// Parses a stored or transport frequency value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            _ => Err(AppError::Validation(format!(
+                "unknown report subscription frequency '{value}'"
+            ))),
+        }
+    }
+
+    /// Returns the time between two deliveries.
+    #[must_use]
+    pub fn period(self) -> Duration {
+        match self {
+            Self::Daily => Duration::days(1),
+            Self::Weekly => Duration::weeks(1),
+        }
+    }
+}
+
+/// File format of a delivered report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportSubscriptionFormat {
+    /// Comma-separated values.
+    Csv,
+    /// Office Open XML spreadsheet.
+    Xlsx,
+}
+
+impl ReportSubscriptionFormat {
+    /// Returns a stable storage value for this format.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Xlsx => "xlsx",
+        }
+    }
+
+    /// Parses a stored or transport format value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "csv" => Ok(Self::Csv),
+            "xlsx" => Ok(Self::Xlsx),
+            _ => Err(AppError::Validation(format!(
+                "unknown report subscription format '{value}'"
+            ))),
+        }
+    }
+
+    /// Returns the MIME content type of delivered files.
+    #[must_use]
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+}
+
+/// Stored report subscription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportSubscription {
+    /// Stable subscription id.
+    pub subscription_id: String,
+    /// Subscriber subject; reports render with this subject's access.
+    pub subject: String,
+    /// Subscriber display name captured at subscription time.
+    pub subscriber_display_name: String,
+    /// Address reports are mailed to.
+    pub recipient_email: String,
+    /// Entity of the subscribed view.
+    pub entity_logical_name: String,
+    /// Subscribed view.
+    pub view_logical_name: String,
+    /// Delivery cadence.
+    pub frequency: ReportSubscriptionFrequency,
+    /// Delivered file format.
+    pub format: ReportSubscriptionFormat,
+    /// Hour of day (UTC) deliveries are scheduled for.
+    pub delivery_hour_utc: u8,
+    /// Next scheduled delivery.
+    pub next_delivery_at: DateTime<Utc>,
+    /// Last successful delivery, when any.
+    pub last_delivered_at: Option<DateTime<Utc>>,
+    /// When delivery was suppressed, when it was.
+    pub suppressed_at: Option<DateTime<Utc>>,
+    /// Why delivery was suppressed.
+    pub suppression_reason: Option<String>,
+    /// Creation timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+/// New report subscription to persist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewReportSubscription {
+    /// Subscriber subject.
+    pub subject: String,
+    /// Subscriber display name.
+    pub subscriber_display_name: String,
+    /// Address reports are mailed to.
+    pub recipient_email: String,
+    /// Entity of the subscribed view.
+    pub entity_logical_name: String,
+    /// Subscribed view.
+    pub view_logical_name: String,
+    /// Delivery cadence.
+    pub frequency: ReportSubscriptionFrequency,
+    /// Delivered file format.
+    pub format: ReportSubscriptionFormat,
+    /// Hour of day (UTC) deliveries are scheduled for.
+    pub delivery_hour_utc: u8,
+    /// First scheduled delivery.
+    pub next_delivery_at: DateTime<Utc>,
+}
+
+/// Subscription claimed for delivery by one dispatcher.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueReportSubscription {
+    /// Tenant owning the subscription.
+    pub tenant_id: TenantId,
+    /// Slot the delivery was scheduled for.
+    pub scheduled_for: DateTime<Utc>,
+    /// Claimed subscription.
+    pub subscription: ReportSubscription,
+}
+
+/// Repository port for report subscriptions.
+#[async_trait]
+pub trait ReportSubscriptionRepository: Send + Sync {
+    /// Persists a subscription unless the tenant already holds `tenant_limit` active ones.
+    async fn create_subscription(
+        &self,
+        tenant_id: TenantId,
+        subscription: NewReportSubscription,
+        tenant_limit: usize,
+    ) -> AppResult<ReportSubscription>;
+
+    /// Lists subscriptions owned by a subject, newest first.
+    async fn list_subscriptions(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<ReportSubscription>>;
+
+    /// Deletes a subscription owned by a subject.
+    async fn delete_subscription(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        subscription_id: &str,
+    ) -> AppResult<()>;
+
+    /// Suppresses every active subscription of a view and returns how many changed.
+    async fn suppress_subscriptions_for_view(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+        reason: &str,
+    ) -> AppResult<u64>;
+
+    /// Suppresses one subscription.
+    async fn suppress_subscription(
+        &self,
+        tenant_id: TenantId,
+        subscription_id: &str,
+        reason: &str,
+    ) -> AppResult<()>;
+
+    /// Claims active subscriptions due at `now` across tenants.
+    ///
+    /// Claimed subscriptions are pushed to `retry_at` so another dispatcher
+    /// does not deliver them again; a failed delivery is retried then.
+    async fn claim_due_subscriptions(
+        &self,
+        now: DateTime<Utc>,
+        retry_at: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<DueReportSubscription>>;
+
+    /// Records a successful delivery and schedules the next one.
+    async fn record_delivery(
+        &self,
+        tenant_id: TenantId,
+        subscription_id: &str,
+        delivered_at: DateTime<Utc>,
+        next_delivery_at: DateTime<Utc>,
+    ) -> AppResult<()>;
+}
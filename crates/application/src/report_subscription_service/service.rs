@@ -0,0 +1,266 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+
+use qryvanta_core::{AppError, AppResult, UserIdentity};
+
+use crate::{EmailAttachment, EmailService};
+
+use super::{
+    DueReportSubscription, NewReportSubscription, ReportSubscription, ReportSubscriptionFormat,
+    ReportSubscriptionFrequency, ReportSubscriptionRepository,
+};
+
+/// Default number of active report subscriptions a tenant may hold.
+pub const DEFAULT_REPORT_SUBSCRIPTION_TENANT_LIMIT: usize = 100;
+
+/// Maximum number of records included in one delivered report.
+pub const REPORT_SUBSCRIPTION_MAX_ROWS: usize = 10_000;
+
+/// Minutes a claimed subscription waits before a failed delivery is retried.
+const REPORT_SUBSCRIPTION_RETRY_MINUTES: i64 = 30;
+
+/// Input for subscribing to a saved view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateReportSubscriptionInput {
+    /// Entity of the view.
+    pub entity_logical_name: String,
+    /// Saved view to deliver.
+    pub view_logical_name: String,
+    /// Delivery cadence.
+    pub frequency: ReportSubscriptionFrequency,
+    /// Delivered file format.
+    pub format: ReportSubscriptionFormat,
+    /// Hour of day (UTC) to deliver at.
+    pub delivery_hour_utc: u8,
+}
+
+/// Rendered report ready to be mailed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportDelivery {
+    /// Rendered file.
+    pub attachment: EmailAttachment,
+    /// Number of records in the file.
+    pub row_count: usize,
+    /// Whether records beyond [`REPORT_SUBSCRIPTION_MAX_ROWS`] were left out.
+    pub truncated: bool,
+}
+
+/// Application service for scheduled report subscriptions.
+#[derive(Clone)]
+pub struct ReportSubscriptionService {
+    repository: Arc<dyn ReportSubscriptionRepository>,
+    email_service: Arc<dyn EmailService>,
+    tenant_limit: usize,
+}
+
+impl ReportSubscriptionService {
+    /// Creates a new report subscription service.
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn ReportSubscriptionRepository>,
+        email_service: Arc<dyn EmailService>,
+    ) -> Self {
+        Self {
+            repository,
+            email_service,
+            tenant_limit: DEFAULT_REPORT_SUBSCRIPTION_TENANT_LIMIT,
+        }
+    }
+
+    /// Overrides how many active subscriptions one tenant may hold.
+    #[must_use]
+    pub fn with_tenant_limit(mut self, tenant_limit: usize) -> Self {
+        self.tenant_limit = tenant_limit;
+        self
+    }
+
+    /// Subscribes the actor to a saved view.
+    ///
+    /// Callers must first confirm the actor can export the view; deliveries
+    /// re-check access each time and suppress the subscription when it is gone.
+    pub async fn create_subscription(
+        &self,
+        actor: &UserIdentity,
+        input: CreateReportSubscriptionInput,
+    ) -> AppResult<ReportSubscription> {
+        if input.delivery_hour_utc > 23 {
+            return Err(AppError::Validation(
+                "delivery_hour_utc must be between 0 and 23".to_owned(),
+            ));
+        }
+        let recipient_email = actor.email().ok_or_else(|| {
+            AppError::Validation(
+                "report subscriptions require an email address on the account".to_owned(),
+            )
+        })?;
+
+        self.repository
+            .create_subscription(
+                actor.tenant_id(),
+                NewReportSubscription {
+                    subject: actor.subject().to_owned(),
+                    subscriber_display_name: actor.display_name().to_owned(),
+                    recipient_email: recipient_email.to_owned(),
+                    entity_logical_name: input.entity_logical_name,
+                    view_logical_name: input.view_logical_name,
+                    frequency: input.frequency,
+                    format: input.format,
+                    delivery_hour_utc: input.delivery_hour_utc,
+                    next_delivery_at: first_delivery_after(Utc::now(), input.delivery_hour_utc),
+                },
+                self.tenant_limit,
+            )
+            .await
+    }
+
+    /// Lists the actor's subscriptions.
+    pub async fn list_subscriptions(
+        &self,
+        actor: &UserIdentity,
+    ) -> AppResult<Vec<ReportSubscription>> {
+        self.repository
+            .list_subscriptions(actor.tenant_id(), actor.subject())
+            .await
+    }
+
+    /// Deletes one of the actor's subscriptions.
+    pub async fn delete_subscription(
+        &self,
+        actor: &UserIdentity,
+        subscription_id: &str,
+    ) -> AppResult<()> {
+        self.repository
+            .delete_subscription(actor.tenant_id(), actor.subject(), subscription_id)
+            .await
+    }
+
+    /// Suppresses subscriptions of a view the actor just deleted.
+    pub async fn suppress_subscriptions_for_deleted_view(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+    ) -> AppResult<u64> {
+        self.repository
+            .suppress_subscriptions_for_view(
+                actor.tenant_id(),
+                entity_logical_name,
+                view_logical_name,
+                "the subscribed view was deleted",
+            )
+            .await
+    }
+
+    /// Claims subscriptions due for delivery across tenants.
+    pub async fn claim_due_subscriptions(
+        &self,
+        limit: usize,
+    ) -> AppResult<Vec<DueReportSubscription>> {
+        let now = Utc::now();
+        self.repository
+            .claim_due_subscriptions(
+                now,
+                now + Duration::minutes(REPORT_SUBSCRIPTION_RETRY_MINUTES),
+                limit,
+            )
+            .await
+    }
+
+    /// Rebuilds the identity a claimed report renders with.
+    #[must_use]
+    pub fn subscriber(due: &DueReportSubscription) -> UserIdentity {
+        let subscription = &due.subscription;
+        UserIdentity::new(
+            subscription.subject.as_str(),
+            subscription.subscriber_display_name.as_str(),
+            Some(subscription.recipient_email.clone()),
+            due.tenant_id,
+        )
+    }
+
+    /// Mails a rendered report and schedules the next delivery.
+    pub async fn deliver(
+        &self,
+        due: &DueReportSubscription,
+        delivery: ReportDelivery,
+    ) -> AppResult<()> {
+        let subscription = &due.subscription;
+        let subject = format!(
+            "Qryvanta report: {} / {}",
+            subscription.entity_logical_name, subscription.view_logical_name
+        );
+        let mut body = format!(
+            "Your {} report for view '{}' on '{}' is attached with {} records.",
+            subscription.frequency.as_str(),
+            subscription.view_logical_name,
+            subscription.entity_logical_name,
+            delivery.row_count
+        );
+        if delivery.truncated {
+            body.push_str(&format!(
+                "\n\nOnly the first {REPORT_SUBSCRIPTION_MAX_ROWS} records are included; \
+                 export the view from the app for the full result."
+            ));
+        }
+        body.push_str("\n\nManage report subscriptions from your profile.");
+
+        self.email_service
+            .send_email_with_attachments(
+                subscription.recipient_email.as_str(),
+                subject.as_str(),
+                body.as_str(),
+                std::slice::from_ref(&delivery.attachment),
+            )
+            .await?;
+
+        let delivered_at = Utc::now();
+        self.repository
+            .record_delivery(
+                due.tenant_id,
+                subscription.subscription_id.as_str(),
+                delivered_at,
+                next_delivery_after(due.scheduled_for, subscription.frequency, delivered_at),
+            )
+            .await
+    }
+
+    /// Stops delivering a claimed subscription.
+    pub async fn suppress(&self, due: &DueReportSubscription, reason: &str) -> AppResult<()> {
+        self.repository
+            .suppress_subscription(
+                due.tenant_id,
+                due.subscription.subscription_id.as_str(),
+                reason,
+            )
+            .await
+    }
+}
+
+/// Returns the first occurrence of `delivery_hour_utc` after `now`.
+pub(super) fn first_delivery_after(now: DateTime<Utc>, delivery_hour_utc: u8) -> DateTime<Utc> {
+    let hour =
+        NaiveTime::from_hms_opt(u32::from(delivery_hour_utc), 0, 0).unwrap_or(NaiveTime::MIN);
+    let today = now.date_naive().and_time(hour).and_utc();
+    if today > now {
+        today
+    } else {
+        today + Duration::days(1)
+    }
+}
+
+/// Advances a delivered slot by whole periods until it lies after `now`.
+///
+/// Slots missed while no dispatcher ran are skipped rather than replayed.
+pub(super) fn next_delivery_after(
+    scheduled_for: DateTime<Utc>,
+    frequency: ReportSubscriptionFrequency,
+    now: DateTime<Utc>,
+) -> DateTime<Utc> {
+    let period = frequency.period();
+    let mut next = scheduled_for + period;
+    while next <= now {
+        next += period;
+    }
+    next
+}
@@ -0,0 +1,380 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+
+use crate::{EmailAttachment, EmailService};
+
+use super::service::{first_delivery_after, next_delivery_after};
+use super::{
+    CreateReportSubscriptionInput, DueReportSubscription, NewReportSubscription, ReportDelivery,
+    ReportSubscription, ReportSubscriptionFormat, ReportSubscriptionFrequency,
+    ReportSubscriptionRepository, ReportSubscriptionService,
+};
+
+#[derive(Default)]
+struct FakeReportSubscriptionRepository {
+    subscriptions: Mutex<Vec<(TenantId, ReportSubscription)>>,
+}
+
+impl FakeReportSubscriptionRepository {
+    fn snapshot(&self) -> Vec<ReportSubscription> {
+        self.subscriptions
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .map(|(_, subscription)| subscription.clone())
+            .collect()
+    }
+}
+
+#[async_trait]
+impl ReportSubscriptionRepository for FakeReportSubscriptionRepository {
+    async fn create_subscription(
+        &self,
+        tenant_id: TenantId,
+        subscription: NewReportSubscription,
+        tenant_limit: usize,
+    ) -> AppResult<ReportSubscription> {
+        let mut subscriptions = self.subscriptions.lock().unwrap_or_else(|_| unreachable!());
+        let active = subscriptions
+            .iter()
+            .filter(|(tenant, stored)| *tenant == tenant_id && stored.suppressed_at.is_none())
+            .count();
+        if active >= tenant_limit {
+            return Err(AppError::Conflict(
+                "tenant report subscription limit reached".to_owned(),
+            ));
+        }
+
+        let stored = ReportSubscription {
+            subscription_id: format!("sub-{}", subscriptions.len() + 1),
+            subject: subscription.subject,
+            subscriber_display_name: subscription.subscriber_display_name,
+            recipient_email: subscription.recipient_email,
+            entity_logical_name: subscription.entity_logical_name,
+            view_logical_name: subscription.view_logical_name,
+            frequency: subscription.frequency,
+            format: subscription.format,
+            delivery_hour_utc: subscription.delivery_hour_utc,
+            next_delivery_at: subscription.next_delivery_at,
+            last_delivered_at: None,
+            suppressed_at: None,
+            suppression_reason: None,
+            created_at: Utc::now(),
+        };
+        subscriptions.push((tenant_id, stored.clone()));
+        Ok(stored)
+    }
+
+    async fn list_subscriptions(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<ReportSubscription>> {
+        Ok(self
+            .subscriptions
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .filter(|(tenant, stored)| *tenant == tenant_id && stored.subject == subject)
+            .map(|(_, stored)| stored.clone())
+            .collect())
+    }
+
+    async fn delete_subscription(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        subscription_id: &str,
+    ) -> AppResult<()> {
+        let mut subscriptions = self.subscriptions.lock().unwrap_or_else(|_| unreachable!());
+        let before = subscriptions.len();
+        subscriptions.retain(|(tenant, stored)| {
+            !(*tenant == tenant_id
+                && stored.subject == subject
+                && stored.subscription_id == subscription_id)
+        });
+        if subscriptions.len() == before {
+            return Err(AppError::NotFound(format!(
+                "report subscription '{subscription_id}' not found"
+            )));
+        }
+        Ok(())
+    }
+
+    async fn suppress_subscriptions_for_view(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+        reason: &str,
+    ) -> AppResult<u64> {
+        let mut changed = 0;
+        for (tenant, stored) in self
+            .subscriptions
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter_mut()
+        {
+            if *tenant == tenant_id
+                && stored.entity_logical_name == entity_logical_name
+                && stored.view_logical_name == view_logical_name
+                && stored.suppressed_at.is_none()
+            {
+                stored.suppressed_at = Some(Utc::now());
+                stored.suppression_reason = Some(reason.to_owned());
+                changed += 1;
+            }
+        }
+        Ok(changed)
+    }
+
+    async fn suppress_subscription(
+        &self,
+        _tenant_id: TenantId,
+        _subscription_id: &str,
+        _reason: &str,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn claim_due_subscriptions(
+        &self,
+        _now: DateTime<Utc>,
+        _retry_at: DateTime<Utc>,
+        _limit: usize,
+    ) -> AppResult<Vec<DueReportSubscription>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn record_delivery(
+        &self,
+        tenant_id: TenantId,
+        subscription_id: &str,
+        delivered_at: DateTime<Utc>,
+        next_delivery_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        let mut subscriptions = self.subscriptions.lock().unwrap_or_else(|_| unreachable!());
+        let (_, stored) = subscriptions
+            .iter_mut()
+            .find(|(tenant, stored)| {
+                *tenant == tenant_id && stored.subscription_id == subscription_id
+            })
+            .unwrap_or_else(|| unreachable!());
+        stored.last_delivered_at = Some(delivered_at);
+        stored.next_delivery_at = next_delivery_at;
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeEmailService {
+    sent: Mutex<Vec<(String, String, Vec<EmailAttachment>)>>,
+}
+
+#[async_trait]
+impl EmailService for FakeEmailService {
+    async fn send_email(
+        &self,
+        to: &str,
+        _subject: &str,
+        text_body: &str,
+        _html_body: Option<&str>,
+    ) -> AppResult<()> {
+        self.send_email_with_attachments(to, "", text_body, &[])
+            .await
+    }
+
+    async fn send_email_with_attachments(
+        &self,
+        to: &str,
+        _subject: &str,
+        text_body: &str,
+        attachments: &[EmailAttachment],
+    ) -> AppResult<()> {
+        self.sent.lock().unwrap_or_else(|_| unreachable!()).push((
+            to.to_owned(),
+            text_body.to_owned(),
+            attachments.to_vec(),
+        ));
+        Ok(())
+    }
+}
+
+fn actor(tenant_id: TenantId, email: Option<&str>) -> UserIdentity {
+    UserIdentity::new("alice", "Alice", email.map(str::to_owned), tenant_id)
+}
+
+fn input(hour: u8) -> CreateReportSubscriptionInput {
+    CreateReportSubscriptionInput {
+        entity_logical_name: "contact".to_owned(),
+        view_logical_name: "active_contacts".to_owned(),
+        frequency: ReportSubscriptionFrequency::Weekly,
+        format: ReportSubscriptionFormat::Csv,
+        delivery_hour_utc: hour,
+    }
+}
+
+fn build_service() -> (
+    ReportSubscriptionService,
+    Arc<FakeReportSubscriptionRepository>,
+    Arc<FakeEmailService>,
+) {
+    let repository = Arc::new(FakeReportSubscriptionRepository::default());
+    let email_service = Arc::new(FakeEmailService::default());
+    let service = ReportSubscriptionService::new(repository.clone(), email_service.clone());
+    (service, repository, email_service)
+}
+
+#[test]
+fn first_delivery_is_the_next_occurrence_of_the_hour() {
+    let now = Utc
+        .with_ymd_and_hms(2026, 3, 2, 9, 30, 0)
+        .single()
+        .unwrap_or_else(|| unreachable!());
+
+    assert_eq!(
+        first_delivery_after(now, 14),
+        Utc.with_ymd_and_hms(2026, 3, 2, 14, 0, 0)
+            .single()
+            .unwrap_or_else(|| unreachable!())
+    );
+    assert_eq!(
+        first_delivery_after(now, 9),
+        Utc.with_ymd_and_hms(2026, 3, 3, 9, 0, 0)
+            .single()
+            .unwrap_or_else(|| unreachable!())
+    );
+}
+
+#[test]
+fn next_delivery_skips_slots_missed_while_offline() {
+    let scheduled_for = Utc
+        .with_ymd_and_hms(2026, 3, 2, 7, 0, 0)
+        .single()
+        .unwrap_or_else(|| unreachable!());
+
+    assert_eq!(
+        next_delivery_after(
+            scheduled_for,
+            ReportSubscriptionFrequency::Daily,
+            scheduled_for + Duration::minutes(5)
+        ),
+        scheduled_for + Duration::days(1)
+    );
+    assert_eq!(
+        next_delivery_after(
+            scheduled_for,
+            ReportSubscriptionFrequency::Weekly,
+            scheduled_for + Duration::days(15)
+        ),
+        scheduled_for + Duration::weeks(3)
+    );
+}
+
+#[tokio::test]
+async fn create_subscription_validates_input_and_enforces_tenant_limit() {
+    let (service, _, _) = build_service();
+    let service = service.with_tenant_limit(1);
+    let tenant_id = TenantId::new();
+
+    let missing_email = service
+        .create_subscription(&actor(tenant_id, None), input(7))
+        .await;
+    assert!(matches!(missing_email, Err(AppError::Validation(_))));
+
+    let bad_hour = service
+        .create_subscription(&actor(tenant_id, Some("alice@example.com")), input(24))
+        .await;
+    assert!(matches!(bad_hour, Err(AppError::Validation(_))));
+
+    let created = service
+        .create_subscription(&actor(tenant_id, Some("alice@example.com")), input(7))
+        .await;
+    assert!(created.is_ok());
+    let created = created.unwrap_or_else(|_| unreachable!());
+    assert_eq!(created.recipient_email, "alice@example.com");
+    assert!(created.next_delivery_at > Utc::now());
+
+    let over_limit = service
+        .create_subscription(&actor(tenant_id, Some("alice@example.com")), input(8))
+        .await;
+    assert!(matches!(over_limit, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn deliver_mails_the_report_and_schedules_the_next_slot() {
+    let (service, repository, email_service) = build_service();
+    let tenant_id = TenantId::new();
+    let created = service
+        .create_subscription(&actor(tenant_id, Some("alice@example.com")), input(7))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let scheduled_for = Utc::now() - Duration::hours(1);
+    let due = DueReportSubscription {
+        tenant_id,
+        scheduled_for,
+        subscription: created,
+    };
+
+    assert_eq!(
+        ReportSubscriptionService::subscriber(&due).email(),
+        Some("alice@example.com")
+    );
+
+    let attachment = EmailAttachment {
+        filename: "active_contacts.csv".to_owned(),
+        content_type: "text/csv; charset=utf-8".to_owned(),
+        content: b"name\nAda\n".to_vec(),
+    };
+    let delivered = service
+        .deliver(
+            &due,
+            ReportDelivery {
+                attachment: attachment.clone(),
+                row_count: 1,
+                truncated: true,
+            },
+        )
+        .await;
+    assert!(delivered.is_ok());
+
+    let sent = email_service.sent.lock().unwrap_or_else(|_| unreachable!());
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].0, "alice@example.com");
+    assert!(sent[0].1.contains("Only the first"));
+    assert_eq!(sent[0].2, vec![attachment]);
+
+    let stored = repository.snapshot();
+    assert!(stored[0].last_delivered_at.is_some());
+    assert_eq!(
+        stored[0].next_delivery_at,
+        scheduled_for + Duration::weeks(1)
+    );
+}
+
+#[tokio::test]
+async fn deleting_a_view_suppresses_its_subscriptions() {
+    let (service, repository, _) = build_service();
+    let tenant_id = TenantId::new();
+    let owner = actor(tenant_id, Some("alice@example.com"));
+    service
+        .create_subscription(&owner, input(7))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let suppressed = service
+        .suppress_subscriptions_for_deleted_view(&owner, "contact", "active_contacts")
+        .await;
+    assert_eq!(suppressed.ok(), Some(1));
+
+    let stored = repository.snapshot();
+    assert!(stored[0].suppressed_at.is_some());
+    assert_eq!(
+        stored[0].suppression_reason.as_deref(),
+        Some("the subscribed view was deleted")
+    );
+}
@@ -0,0 +1,26 @@
+//! Denormalized reporting tables maintained from the runtime record change feed.
+//!
+//! A projection is declared per tenant: one row per record of a root entity,
+//! with columns read from the record itself or from parent records reached
+//! through its lookup fields. The database captures every write to the
+//! entities a projection reads on a change feed; a projector leases feed
+//! entries, rebuilds the affected rows from the current records and
+//! acknowledges them. Reports then page through the rows without running
+//! multi-link runtime queries.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    NewReportingProjection, ReportingProjection, ReportingProjectionColumn,
+    ReportingProjectionColumnInput, ReportingProjectionFilter, ReportingProjectionQuery,
+    ReportingProjectionRepository, ReportingProjectionRow, ReportingProjectionRowUpdate,
+    ReportingProjectionRuntimeService, RuntimeRecordChange, SaveReportingProjectionInput,
+};
+pub use service::{
+    REPORTING_PROJECTION_MAX_COLUMNS, REPORTING_PROJECTION_MAX_PER_TENANT,
+    REPORTING_PROJECTION_MAX_QUERY_ROWS, ReportingProjectionService,
+};
@@ -0,0 +1,284 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use qryvanta_core::{AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{PublishedEntitySchema, RuntimeRecord};
+
+/// Requested column of a reporting projection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportingProjectionColumnInput {
+    /// Column name in projection rows.
+    pub column_name: String,
+    /// Lookup field of the root entity whose parent record supplies the
+    /// value; `None` reads the root record itself.
+    pub relation_field_logical_name: Option<String>,
+    /// Field read from the root or parent record.
+    pub field_logical_name: String,
+}
+
+/// Column of a saved reporting projection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportingProjectionColumn {
+    /// Column name in projection rows.
+    pub column_name: String,
+    /// Lookup field of the root entity joining the parent record, if any.
+    pub relation_field_logical_name: Option<String>,
+    /// Entity the lookup field points to, resolved when the projection is saved.
+    pub parent_entity_logical_name: Option<String>,
+    /// Field read from the root or parent record.
+    pub field_logical_name: String,
+}
+
+impl ReportingProjectionColumn {
+    /// Entity the column value is read from.
+    #[must_use]
+    pub fn source_entity_logical_name<'a>(&'a self, root_entity_logical_name: &'a str) -> &'a str {
+        self.parent_entity_logical_name
+            .as_deref()
+            .unwrap_or(root_entity_logical_name)
+    }
+}
+
+/// Input payload for creating or redefining a reporting projection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveReportingProjectionInput {
+    /// Stable projection name, unique per tenant.
+    pub logical_name: String,
+    /// Display name.
+    pub display_name: String,
+    /// Entity with one projection row per record.
+    pub root_entity_logical_name: String,
+    /// Projected columns in output order.
+    pub columns: Vec<ReportingProjectionColumnInput>,
+}
+
+/// Validated projection definition persisted by the repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewReportingProjection {
+    /// Stable projection name, unique per tenant.
+    pub logical_name: String,
+    /// Display name.
+    pub display_name: String,
+    /// Entity with one projection row per record.
+    pub root_entity_logical_name: String,
+    /// Projected columns in output order.
+    pub columns: Vec<ReportingProjectionColumn>,
+}
+
+impl NewReportingProjection {
+    /// Entities whose record changes refresh this projection, root first.
+    #[must_use]
+    pub fn source_entity_logical_names(&self) -> Vec<String> {
+        source_entity_logical_names(&self.root_entity_logical_name, &self.columns)
+    }
+}
+
+/// Denormalized reporting table maintained from the runtime record change feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportingProjection {
+    /// Stable projection identifier.
+    pub projection_id: String,
+    /// Stable projection name, unique per tenant.
+    pub logical_name: String,
+    /// Display name.
+    pub display_name: String,
+    /// Entity with one projection row per record.
+    pub root_entity_logical_name: String,
+    /// Projected columns in output order.
+    pub columns: Vec<ReportingProjectionColumn>,
+    /// Subject that last saved the projection.
+    pub updated_by_subject: String,
+    /// Last change of the definition.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ReportingProjection {
+    /// Entities whose record changes refresh this projection, root first.
+    #[must_use]
+    pub fn source_entity_logical_names(&self) -> Vec<String> {
+        source_entity_logical_names(&self.root_entity_logical_name, &self.columns)
+    }
+}
+
+fn source_entity_logical_names(
+    root_entity_logical_name: &str,
+    columns: &[ReportingProjectionColumn],
+) -> Vec<String> {
+    let mut entity_logical_names = vec![root_entity_logical_name.to_owned()];
+    for column in columns {
+        if let Some(parent) = &column.parent_entity_logical_name
+            && !entity_logical_names.contains(parent)
+        {
+            entity_logical_names.push(parent.clone());
+        }
+    }
+    entity_logical_names
+}
+
+/// One projection row, keyed by the root record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportingProjectionRow {
+    /// Root record the row was built from.
+    pub record_id: String,
+    /// Column values keyed by column name.
+    pub values: Value,
+    /// When the row was last rebuilt.
+    pub refreshed_at: DateTime<Utc>,
+}
+
+/// Rebuilt projection row written by the projector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportingProjectionRowUpdate {
+    /// Root record the row was built from.
+    pub record_id: String,
+    /// Parent records joined into the row; their changes refresh it.
+    pub parent_record_ids: Vec<String>,
+    /// Column values keyed by column name.
+    pub values: Value,
+}
+
+/// Equality filter on one projection column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportingProjectionFilter {
+    /// Filtered column.
+    pub column_name: String,
+    /// Value the column must equal.
+    pub value: Value,
+}
+
+/// Query over the rows of one projection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportingProjectionQuery {
+    /// Filters that must all match.
+    pub filters: Vec<ReportingProjectionFilter>,
+    /// Column to sort by; rows are ordered by record id without one.
+    pub sort_column: Option<String>,
+    /// Whether the sort column is ordered descending.
+    pub sort_descending: bool,
+    /// Maximum rows returned.
+    pub limit: usize,
+    /// Rows skipped before the first returned one.
+    pub offset: usize,
+}
+
+/// Runtime record write captured by the change feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeRecordChange {
+    /// Feed position, increasing with every captured write.
+    pub change_id: i64,
+    /// Tenant of the changed record.
+    pub tenant_id: TenantId,
+    /// Entity of the changed record.
+    pub entity_logical_name: String,
+    /// Created, updated or deleted record.
+    pub record_id: String,
+}
+
+/// Repository port for reporting projections, their rows and the change feed.
+#[async_trait]
+pub trait ReportingProjectionRepository: Send + Sync {
+    /// Lists the projections of a tenant ordered by logical name.
+    async fn list_projections(&self, tenant_id: TenantId) -> AppResult<Vec<ReportingProjection>>;
+
+    /// Finds a projection by logical name.
+    async fn find_projection(
+        &self,
+        tenant_id: TenantId,
+        logical_name: &str,
+    ) -> AppResult<Option<ReportingProjection>>;
+
+    /// Creates or redefines a projection.
+    ///
+    /// Existing rows are dropped and every record of the root entity is
+    /// queued on the change feed, so the projector rebuilds the table.
+    async fn save_projection(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        projection: NewReportingProjection,
+    ) -> AppResult<ReportingProjection>;
+
+    /// Deletes a projection with its rows, returning whether it existed.
+    async fn delete_projection(&self, tenant_id: TenantId, logical_name: &str) -> AppResult<bool>;
+
+    /// Inserts or replaces one projection row.
+    async fn upsert_projection_row(
+        &self,
+        tenant_id: TenantId,
+        projection_id: &str,
+        row: ReportingProjectionRowUpdate,
+    ) -> AppResult<()>;
+
+    /// Deletes the row of a root record, if any.
+    async fn delete_projection_row(
+        &self,
+        tenant_id: TenantId,
+        projection_id: &str,
+        record_id: &str,
+    ) -> AppResult<()>;
+
+    /// Lists root records whose rows joined the given parent record.
+    async fn list_projection_rows_joining(
+        &self,
+        tenant_id: TenantId,
+        projection_id: &str,
+        parent_record_id: &str,
+    ) -> AppResult<Vec<String>>;
+
+    /// Returns one page of projection rows.
+    async fn query_projection_rows(
+        &self,
+        tenant_id: TenantId,
+        projection_id: &str,
+        query: &ReportingProjectionQuery,
+    ) -> AppResult<Vec<ReportingProjectionRow>>;
+
+    /// Leases up to `limit` unprocessed change feed entries across tenants,
+    /// oldest first.
+    ///
+    /// Leased entries are hidden until `lease_until`; entries that are not
+    /// acknowledged by then are handed out again.
+    async fn claim_runtime_record_changes(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<RuntimeRecordChange>>;
+
+    /// Removes processed change feed entries.
+    async fn acknowledge_runtime_record_changes(
+        &self,
+        tenant_id: TenantId,
+        change_ids: &[i64],
+    ) -> AppResult<()>;
+}
+
+/// Runtime record gateway used by reporting projections.
+#[async_trait]
+pub trait ReportingProjectionRuntimeService: Send + Sync {
+    /// Returns the latest published schema for an entity.
+    async fn latest_published_schema_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<PublishedEntitySchema>>;
+
+    /// Loads a record without access checks so the projector can rebuild rows.
+    async fn find_runtime_record_for_projection(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<RuntimeRecord>>;
+
+    /// Rejects the read unless the actor may read the given fields on every
+    /// record of the entity, because projection rows are not filtered per
+    /// record.
+    async fn require_entity_report_access(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        field_logical_names: &[String],
+    ) -> AppResult<()>;
+}
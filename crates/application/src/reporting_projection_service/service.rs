@@ -0,0 +1,538 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use serde_json::{Map, Value, json};
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, FieldType, Permission, PublishedEntitySchema, RuntimeRecord};
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService};
+
+use super::ports::{
+    NewReportingProjection, ReportingProjection, ReportingProjectionColumn,
+    ReportingProjectionQuery, ReportingProjectionRepository, ReportingProjectionRow,
+    ReportingProjectionRowUpdate, ReportingProjectionRuntimeService, RuntimeRecordChange,
+    SaveReportingProjectionInput,
+};
+
+/// Maximum number of columns in one projection.
+pub const REPORTING_PROJECTION_MAX_COLUMNS: usize = 100;
+
+/// Maximum number of projections one tenant may define.
+pub const REPORTING_PROJECTION_MAX_PER_TENANT: usize = 50;
+
+/// Maximum number of rows returned by one projection query.
+pub const REPORTING_PROJECTION_MAX_QUERY_ROWS: usize = 1_000;
+
+const MAX_NAME_LENGTH: usize = 64;
+
+const MAX_DISPLAY_NAME_LENGTH: usize = 120;
+
+/// Minutes a leased change waits before an interrupted refresh is retried.
+const CHANGE_LEASE_MINUTES: i64 = 5;
+
+/// Application service for reporting projections: denormalized tables of a
+/// root entity joined with its lookup parents, kept current from the runtime
+/// record change feed.
+#[derive(Clone)]
+pub struct ReportingProjectionService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn ReportingProjectionRepository>,
+    runtime_service: Arc<dyn ReportingProjectionRuntimeService>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl ReportingProjectionService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn ReportingProjectionRepository>,
+        runtime_service: Arc<dyn ReportingProjectionRuntimeService>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            runtime_service,
+            audit_repository,
+        }
+    }
+
+    /// Lists the projections of the actor tenant.
+    pub async fn list_projections(
+        &self,
+        actor: &UserIdentity,
+    ) -> AppResult<Vec<ReportingProjection>> {
+        self.require_permission(actor, Permission::MetadataFieldRead)
+            .await?;
+        self.repository.list_projections(actor.tenant_id()).await
+    }
+
+    /// Creates or redefines a projection over published entities.
+    ///
+    /// Saving drops the existing rows and queues every root record, so the
+    /// projection is empty until the projector has rebuilt it.
+    pub async fn save_projection(
+        &self,
+        actor: &UserIdentity,
+        input: SaveReportingProjectionInput,
+    ) -> AppResult<ReportingProjection> {
+        self.require_permission(actor, Permission::MetadataFieldWrite)
+            .await?;
+
+        let projection = self.normalize_projection_input(actor, input).await?;
+        let projections = self.repository.list_projections(actor.tenant_id()).await?;
+        let created = !projections
+            .iter()
+            .any(|existing| existing.logical_name == projection.logical_name);
+        if created && projections.len() >= REPORTING_PROJECTION_MAX_PER_TENANT {
+            return Err(AppError::Conflict(format!(
+                "a tenant may define at most {REPORTING_PROJECTION_MAX_PER_TENANT} reporting projections"
+            )));
+        }
+
+        let projection = self
+            .repository
+            .save_projection(actor.tenant_id(), actor.subject(), projection)
+            .await?;
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::ReportingProjectionSaved,
+                resource_type: "reporting_projection".to_owned(),
+                resource_id: projection.logical_name.clone(),
+                detail: Some(
+                    json!({
+                        "created": created,
+                        "root_entity_logical_name": projection.root_entity_logical_name,
+                        "source_entity_logical_names": projection.source_entity_logical_names(),
+                        "column_count": projection.columns.len(),
+                    })
+                    .to_string(),
+                ),
+            })
+            .await?;
+
+        Ok(projection)
+    }
+
+    /// Deletes a projection and its rows.
+    pub async fn delete_projection(
+        &self,
+        actor: &UserIdentity,
+        logical_name: &str,
+    ) -> AppResult<()> {
+        self.require_permission(actor, Permission::MetadataFieldWrite)
+            .await?;
+
+        if !self
+            .repository
+            .delete_projection(actor.tenant_id(), logical_name)
+            .await?
+        {
+            return Err(projection_not_found(logical_name));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::ReportingProjectionDeleted,
+                resource_type: "reporting_projection".to_owned(),
+                resource_id: logical_name.to_owned(),
+                detail: None,
+            })
+            .await
+    }
+
+    /// Returns one page of projection rows.
+    ///
+    /// Rows are not filtered per record, so the actor must be able to read
+    /// every projected field on all records of each joined entity.
+    pub async fn query_projection(
+        &self,
+        actor: &UserIdentity,
+        logical_name: &str,
+        query: ReportingProjectionQuery,
+    ) -> AppResult<Vec<ReportingProjectionRow>> {
+        let projection = self
+            .repository
+            .find_projection(actor.tenant_id(), logical_name)
+            .await?
+            .ok_or_else(|| projection_not_found(logical_name))?;
+
+        let mut fields_by_entity: BTreeMap<&str, BTreeSet<String>> = BTreeMap::new();
+        for column in &projection.columns {
+            let root = projection.root_entity_logical_name.as_str();
+            if let Some(relation_field) = &column.relation_field_logical_name {
+                fields_by_entity
+                    .entry(root)
+                    .or_default()
+                    .insert(relation_field.clone());
+            }
+            fields_by_entity
+                .entry(column.source_entity_logical_name(root))
+                .or_default()
+                .insert(column.field_logical_name.clone());
+        }
+        for (entity_logical_name, fields) in fields_by_entity {
+            self.runtime_service
+                .require_entity_report_access(
+                    actor,
+                    entity_logical_name,
+                    &fields.into_iter().collect::<Vec<_>>(),
+                )
+                .await?;
+        }
+
+        if query.limit == 0 || query.limit > REPORTING_PROJECTION_MAX_QUERY_ROWS {
+            return Err(AppError::Validation(format!(
+                "reporting projection query limit must be between 1 and {REPORTING_PROJECTION_MAX_QUERY_ROWS}"
+            )));
+        }
+        let column_names = projection
+            .columns
+            .iter()
+            .map(|column| column.column_name.as_str())
+            .collect::<HashSet<_>>();
+        for column_name in query
+            .filters
+            .iter()
+            .map(|filter| filter.column_name.as_str())
+            .chain(query.sort_column.as_deref())
+        {
+            if !column_names.contains(column_name) {
+                return Err(AppError::Validation(format!(
+                    "reporting projection '{}' has no column '{}'",
+                    projection.logical_name, column_name
+                )));
+            }
+        }
+
+        self.repository
+            .query_projection_rows(actor.tenant_id(), projection.projection_id.as_str(), &query)
+            .await
+    }
+
+    /// Leases up to `limit` change feed entries across tenants and refreshes
+    /// the projection rows they affect, returning how many were processed.
+    ///
+    /// Each row is rebuilt from the current records, so entries may be
+    /// applied in any order and more than once. Entries of a tenant whose
+    /// refresh fails stay on the feed and are retried once their lease lapses.
+    pub async fn process_runtime_record_changes(&self, limit: usize) -> AppResult<usize> {
+        let now = Utc::now();
+        let changes = self
+            .repository
+            .claim_runtime_record_changes(now, now + Duration::minutes(CHANGE_LEASE_MINUTES), limit)
+            .await?;
+
+        let processed = changes.len();
+        let mut changes_by_tenant: HashMap<TenantId, Vec<RuntimeRecordChange>> = HashMap::new();
+        for change in changes {
+            changes_by_tenant
+                .entry(change.tenant_id)
+                .or_default()
+                .push(change);
+        }
+
+        let mut first_error = None;
+        for (tenant_id, changes) in changes_by_tenant {
+            let refreshed = self.refresh_tenant_projections(tenant_id, &changes).await;
+            let result = match refreshed {
+                Ok(()) => {
+                    let change_ids = changes
+                        .iter()
+                        .map(|change| change.change_id)
+                        .collect::<Vec<_>>();
+                    self.repository
+                        .acknowledge_runtime_record_changes(tenant_id, &change_ids)
+                        .await
+                }
+                Err(error) => Err(error),
+            };
+            if let Err(error) = result {
+                first_error.get_or_insert(error);
+            }
+        }
+
+        match first_error {
+            Some(error) => Err(error),
+            None => Ok(processed),
+        }
+    }
+
+    async fn refresh_tenant_projections(
+        &self,
+        tenant_id: TenantId,
+        changes: &[RuntimeRecordChange],
+    ) -> AppResult<()> {
+        let projections = self.repository.list_projections(tenant_id).await?;
+        let mut stale_rows = BTreeSet::new();
+        for (index, projection) in projections.iter().enumerate() {
+            let root = projection.root_entity_logical_name.as_str();
+            let parent_entities = projection
+                .columns
+                .iter()
+                .filter_map(|column| column.parent_entity_logical_name.as_deref())
+                .collect::<HashSet<_>>();
+            for change in changes {
+                let entity_logical_name = change.entity_logical_name.as_str();
+                if entity_logical_name == root {
+                    stale_rows.insert((index, change.record_id.clone()));
+                }
+                if parent_entities.contains(entity_logical_name) {
+                    for record_id in self
+                        .repository
+                        .list_projection_rows_joining(
+                            tenant_id,
+                            projection.projection_id.as_str(),
+                            change.record_id.as_str(),
+                        )
+                        .await?
+                    {
+                        stale_rows.insert((index, record_id));
+                    }
+                }
+            }
+        }
+
+        let mut records = BTreeMap::new();
+        for (index, record_id) in stale_rows {
+            let projection = &projections[index];
+            let root = projection.root_entity_logical_name.as_str();
+            let Some(root_record) = self
+                .cached_record(tenant_id, &mut records, root, record_id.as_str())
+                .await?
+            else {
+                self.repository
+                    .delete_projection_row(
+                        tenant_id,
+                        projection.projection_id.as_str(),
+                        record_id.as_str(),
+                    )
+                    .await?;
+                continue;
+            };
+
+            let mut values = Map::new();
+            let mut parent_record_ids = BTreeSet::new();
+            for column in &projection.columns {
+                let value = match (
+                    &column.relation_field_logical_name,
+                    &column.parent_entity_logical_name,
+                ) {
+                    (Some(relation_field), Some(parent_entity)) => {
+                        match root_record
+                            .data()
+                            .get(relation_field)
+                            .and_then(Value::as_str)
+                        {
+                            Some(parent_record_id) => {
+                                parent_record_ids.insert(parent_record_id.to_owned());
+                                self.cached_record(
+                                    tenant_id,
+                                    &mut records,
+                                    parent_entity,
+                                    parent_record_id,
+                                )
+                                .await?
+                                .and_then(|parent| {
+                                    parent.data().get(&column.field_logical_name).cloned()
+                                })
+                            }
+                            None => None,
+                        }
+                    }
+                    _ => root_record.data().get(&column.field_logical_name).cloned(),
+                };
+                values.insert(column.column_name.clone(), value.unwrap_or(Value::Null));
+            }
+
+            self.repository
+                .upsert_projection_row(
+                    tenant_id,
+                    projection.projection_id.as_str(),
+                    ReportingProjectionRowUpdate {
+                        record_id,
+                        parent_record_ids: parent_record_ids.into_iter().collect(),
+                        values: Value::Object(values),
+                    },
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn cached_record(
+        &self,
+        tenant_id: TenantId,
+        records: &mut BTreeMap<(String, String), Option<RuntimeRecord>>,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<RuntimeRecord>> {
+        let key = (entity_logical_name.to_owned(), record_id.to_owned());
+        if let Some(record) = records.get(&key) {
+            return Ok(record.clone());
+        }
+
+        let record = self
+            .runtime_service
+            .find_runtime_record_for_projection(tenant_id, entity_logical_name, record_id)
+            .await?;
+        records.insert(key, record.clone());
+        Ok(record)
+    }
+
+    async fn normalize_projection_input(
+        &self,
+        actor: &UserIdentity,
+        input: SaveReportingProjectionInput,
+    ) -> AppResult<NewReportingProjection> {
+        let logical_name = normalize_identifier(input.logical_name.as_str(), "projection")?;
+        let display_name = input.display_name.trim().to_owned();
+        if display_name.is_empty() || display_name.chars().count() > MAX_DISPLAY_NAME_LENGTH {
+            return Err(AppError::Validation(format!(
+                "reporting projection display names must be between 1 and {MAX_DISPLAY_NAME_LENGTH} characters"
+            )));
+        }
+        if input.columns.is_empty() || input.columns.len() > REPORTING_PROJECTION_MAX_COLUMNS {
+            return Err(AppError::Validation(format!(
+                "reporting projections must define between 1 and {REPORTING_PROJECTION_MAX_COLUMNS} columns"
+            )));
+        }
+
+        let root_entity_logical_name = input.root_entity_logical_name.trim().to_owned();
+        let root_schema = self
+            .published_schema(actor, root_entity_logical_name.as_str())
+            .await?;
+        let mut parent_schemas = BTreeMap::new();
+        let mut column_names = HashSet::new();
+        let mut columns = Vec::with_capacity(input.columns.len());
+        for column in input.columns {
+            let column_name = normalize_identifier(column.column_name.as_str(), "column")?;
+            if !column_names.insert(column_name.clone()) {
+                return Err(AppError::Validation(format!(
+                    "reporting projection defines column '{column_name}' more than once"
+                )));
+            }
+            let field_logical_name = column.field_logical_name.trim().to_owned();
+
+            let parent_entity_logical_name = match column.relation_field_logical_name.as_deref() {
+                Some(relation_field) => {
+                    let relation = projected_field(&root_schema, relation_field.trim())?;
+                    let parent_entity =
+                        match (relation.field_type(), relation.relation_target_entity()) {
+                            (FieldType::Relation, Some(target)) => target.as_str().to_owned(),
+                            _ => {
+                                return Err(AppError::Validation(format!(
+                                    "field '{}' of entity '{}' is not a lookup field",
+                                    relation_field.trim(),
+                                    root_entity_logical_name
+                                )));
+                            }
+                        };
+                    if !parent_schemas.contains_key(&parent_entity) {
+                        let parent_schema =
+                            self.published_schema(actor, parent_entity.as_str()).await?;
+                        parent_schemas.insert(parent_entity.clone(), parent_schema);
+                    }
+                    projected_field(&parent_schemas[&parent_entity], field_logical_name.as_str())?;
+                    Some(parent_entity)
+                }
+                None => {
+                    projected_field(&root_schema, field_logical_name.as_str())?;
+                    None
+                }
+            };
+
+            columns.push(ReportingProjectionColumn {
+                column_name,
+                relation_field_logical_name: column
+                    .relation_field_logical_name
+                    .map(|relation_field| relation_field.trim().to_owned()),
+                parent_entity_logical_name,
+                field_logical_name,
+            });
+        }
+
+        Ok(NewReportingProjection {
+            logical_name,
+            display_name,
+            root_entity_logical_name,
+            columns,
+        })
+    }
+
+    async fn published_schema(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<PublishedEntitySchema> {
+        self.runtime_service
+            .latest_published_schema_unchecked(actor, entity_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "entity '{entity_logical_name}' has no published schema"
+                ))
+            })
+    }
+
+    async fn require_permission(
+        &self,
+        actor: &UserIdentity,
+        permission: Permission,
+    ) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(actor.tenant_id(), actor.subject(), permission)
+            .await
+    }
+}
+
+/// Finds a field that holds a record value, including system fields.
+fn projected_field(
+    schema: &PublishedEntitySchema,
+    field_logical_name: &str,
+) -> AppResult<qryvanta_domain::EntityFieldDefinition> {
+    schema
+        .queryable_fields()?
+        .into_iter()
+        .find(|field| field.logical_name().as_str() == field_logical_name)
+        .ok_or_else(|| {
+            AppError::Validation(format!(
+                "field '{}' is not published on entity '{}'",
+                field_logical_name,
+                schema.entity().logical_name().as_str()
+            ))
+        })
+}
+
+fn normalize_identifier(value: &str, kind: &str) -> AppResult<String> {
+    let value = value.trim();
+    let is_valid = value.len() <= MAX_NAME_LENGTH
+        && value
+            .chars()
+            .next()
+            .is_some_and(|character| character.is_ascii_lowercase())
+        && value.chars().all(|character| {
+            character.is_ascii_lowercase() || character.is_ascii_digit() || character == '_'
+        });
+    if !is_valid {
+        return Err(AppError::Validation(format!(
+            "reporting {kind} names must start with a lowercase letter, use only lowercase letters, digits and underscores, and be at most {MAX_NAME_LENGTH} characters"
+        )));
+    }
+
+    Ok(value.to_owned())
+}
+
+fn projection_not_found(logical_name: &str) -> AppError {
+    AppError::NotFound(format!(
+        "reporting projection '{logical_name}' does not exist"
+    ))
+}
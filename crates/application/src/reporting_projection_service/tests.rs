@@ -0,0 +1,730 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{
+    AuditAction, EntityDefinition, EntityFieldDefinition, FieldType, Permission,
+    PublishedEntitySchema, RuntimeRecord,
+};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    TemporaryPermissionGrant,
+};
+
+use super::{
+    NewReportingProjection, ReportingProjection, ReportingProjectionColumnInput,
+    ReportingProjectionFilter, ReportingProjectionQuery, ReportingProjectionRepository,
+    ReportingProjectionRow, ReportingProjectionRowUpdate, ReportingProjectionRuntimeService,
+    ReportingProjectionService, RuntimeRecordChange, SaveReportingProjectionInput,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+struct FakeProjectionRow {
+    parent_record_ids: Vec<String>,
+    values: Value,
+}
+
+#[derive(Default)]
+struct FakeReportingProjectionRepository {
+    projections: Mutex<Vec<(TenantId, ReportingProjection)>>,
+    rows: Mutex<HashMap<(String, String), FakeProjectionRow>>,
+    changes: Mutex<Vec<(RuntimeRecordChange, Option<DateTime<Utc>>)>>,
+}
+
+impl FakeReportingProjectionRepository {
+    /// Mimics the database trigger capturing a runtime record write.
+    async fn capture(&self, tenant_id: TenantId, entity_logical_name: &str, record_id: &str) {
+        let mut changes = self.changes.lock().await;
+        let change_id = i64::try_from(changes.len()).unwrap_or(i64::MAX) + 1;
+        changes.push((
+            RuntimeRecordChange {
+                change_id,
+                tenant_id,
+                entity_logical_name: entity_logical_name.to_owned(),
+                record_id: record_id.to_owned(),
+            },
+            None,
+        ));
+    }
+
+    async fn pending_changes(&self) -> usize {
+        self.changes.lock().await.len()
+    }
+}
+
+#[async_trait]
+impl ReportingProjectionRepository for FakeReportingProjectionRepository {
+    async fn list_projections(&self, tenant_id: TenantId) -> AppResult<Vec<ReportingProjection>> {
+        Ok(self
+            .projections
+            .lock()
+            .await
+            .iter()
+            .filter(|(projection_tenant_id, _)| *projection_tenant_id == tenant_id)
+            .map(|(_, projection)| projection.clone())
+            .collect())
+    }
+
+    async fn find_projection(
+        &self,
+        tenant_id: TenantId,
+        logical_name: &str,
+    ) -> AppResult<Option<ReportingProjection>> {
+        Ok(self
+            .list_projections(tenant_id)
+            .await?
+            .into_iter()
+            .find(|projection| projection.logical_name == logical_name))
+    }
+
+    async fn save_projection(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        projection: NewReportingProjection,
+    ) -> AppResult<ReportingProjection> {
+        let mut projections = self.projections.lock().await;
+        projections.retain(|(projection_tenant_id, existing)| {
+            *projection_tenant_id != tenant_id || existing.logical_name != projection.logical_name
+        });
+        let saved = ReportingProjection {
+            projection_id: format!("projection-{}", projection.logical_name),
+            logical_name: projection.logical_name,
+            display_name: projection.display_name,
+            root_entity_logical_name: projection.root_entity_logical_name,
+            columns: projection.columns,
+            updated_by_subject: updated_by_subject.to_owned(),
+            updated_at: Utc::now(),
+        };
+        projections.push((tenant_id, saved.clone()));
+        Ok(saved)
+    }
+
+    async fn delete_projection(&self, tenant_id: TenantId, logical_name: &str) -> AppResult<bool> {
+        let mut projections = self.projections.lock().await;
+        let before = projections.len();
+        projections.retain(|(projection_tenant_id, projection)| {
+            *projection_tenant_id != tenant_id || projection.logical_name != logical_name
+        });
+        Ok(projections.len() != before)
+    }
+
+    async fn upsert_projection_row(
+        &self,
+        _tenant_id: TenantId,
+        projection_id: &str,
+        row: ReportingProjectionRowUpdate,
+    ) -> AppResult<()> {
+        self.rows.lock().await.insert(
+            (projection_id.to_owned(), row.record_id),
+            FakeProjectionRow {
+                parent_record_ids: row.parent_record_ids,
+                values: row.values,
+            },
+        );
+        Ok(())
+    }
+
+    async fn delete_projection_row(
+        &self,
+        _tenant_id: TenantId,
+        projection_id: &str,
+        record_id: &str,
+    ) -> AppResult<()> {
+        self.rows
+            .lock()
+            .await
+            .remove(&(projection_id.to_owned(), record_id.to_owned()));
+        Ok(())
+    }
+
+    async fn list_projection_rows_joining(
+        &self,
+        _tenant_id: TenantId,
+        projection_id: &str,
+        parent_record_id: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(self
+            .rows
+            .lock()
+            .await
+            .iter()
+            .filter(|((row_projection_id, _), row)| {
+                row_projection_id == projection_id
+                    && row
+                        .parent_record_ids
+                        .iter()
+                        .any(|parent| parent == parent_record_id)
+            })
+            .map(|((_, record_id), _)| record_id.clone())
+            .collect())
+    }
+
+    async fn query_projection_rows(
+        &self,
+        _tenant_id: TenantId,
+        projection_id: &str,
+        query: &ReportingProjectionQuery,
+    ) -> AppResult<Vec<ReportingProjectionRow>> {
+        let mut rows =
+            self.rows
+                .lock()
+                .await
+                .iter()
+                .filter(|((row_projection_id, _), row)| {
+                    row_projection_id == projection_id
+                        && query.filters.iter().all(|filter| {
+                            row.values.get(&filter.column_name) == Some(&filter.value)
+                        })
+                })
+                .map(|((_, record_id), row)| ReportingProjectionRow {
+                    record_id: record_id.clone(),
+                    values: row.values.clone(),
+                    refreshed_at: Utc::now(),
+                })
+                .collect::<Vec<_>>();
+        rows.sort_by(|left, right| left.record_id.cmp(&right.record_id));
+        Ok(rows
+            .into_iter()
+            .skip(query.offset)
+            .take(query.limit)
+            .collect())
+    }
+
+    async fn claim_runtime_record_changes(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<RuntimeRecordChange>> {
+        Ok(self
+            .changes
+            .lock()
+            .await
+            .iter_mut()
+            .filter(|(_, leased_until)| leased_until.is_none_or(|leased_until| leased_until <= now))
+            .take(limit)
+            .map(|(change, leased_until)| {
+                *leased_until = Some(lease_until);
+                change.clone()
+            })
+            .collect())
+    }
+
+    async fn acknowledge_runtime_record_changes(
+        &self,
+        tenant_id: TenantId,
+        change_ids: &[i64],
+    ) -> AppResult<()> {
+        self.changes.lock().await.retain(|(change, _)| {
+            change.tenant_id != tenant_id || !change_ids.contains(&change.change_id)
+        });
+        Ok(())
+    }
+}
+
+struct FakeRuntimeService {
+    schemas: Vec<PublishedEntitySchema>,
+    records: Mutex<HashMap<(String, String), Value>>,
+    readers: Vec<String>,
+}
+
+#[async_trait]
+impl ReportingProjectionRuntimeService for FakeRuntimeService {
+    async fn latest_published_schema_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        Ok(self
+            .schemas
+            .iter()
+            .find(|schema| schema.entity().logical_name().as_str() == entity_logical_name)
+            .cloned())
+    }
+
+    async fn find_runtime_record_for_projection(
+        &self,
+        _tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<RuntimeRecord>> {
+        self.records
+            .lock()
+            .await
+            .get(&(entity_logical_name.to_owned(), record_id.to_owned()))
+            .map(|data| RuntimeRecord::new(record_id, entity_logical_name, data.clone()))
+            .transpose()
+    }
+
+    async fn require_entity_report_access(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        _field_logical_names: &[String],
+    ) -> AppResult<()> {
+        if self.readers.iter().any(|reader| reader == actor.subject()) {
+            return Ok(());
+        }
+
+        Err(AppError::Forbidden(format!(
+            "subject '{}' cannot read all records of entity '{}'",
+            actor.subject(),
+            entity_logical_name
+        )))
+    }
+}
+
+struct Harness {
+    service: ReportingProjectionService,
+    repository: Arc<FakeReportingProjectionRepository>,
+    runtime: Arc<FakeRuntimeService>,
+    audit: Arc<FakeAuditRepository>,
+    admin: UserIdentity,
+}
+
+impl Harness {
+    async fn write_record(&self, entity_logical_name: &str, record_id: &str, data: Value) {
+        self.runtime
+            .records
+            .lock()
+            .await
+            .insert((entity_logical_name.to_owned(), record_id.to_owned()), data);
+        self.repository
+            .capture(self.admin.tenant_id(), entity_logical_name, record_id)
+            .await;
+    }
+
+    async fn delete_record(&self, entity_logical_name: &str, record_id: &str) {
+        self.runtime
+            .records
+            .lock()
+            .await
+            .remove(&(entity_logical_name.to_owned(), record_id.to_owned()));
+        self.repository
+            .capture(self.admin.tenant_id(), entity_logical_name, record_id)
+            .await;
+    }
+}
+
+fn schema(
+    entity_logical_name: &str,
+    fields: Vec<(&str, FieldType, Option<&str>)>,
+) -> PublishedEntitySchema {
+    PublishedEntitySchema::new(
+        EntityDefinition::new(entity_logical_name, entity_logical_name)
+            .unwrap_or_else(|_| unreachable!()),
+        1,
+        fields
+            .into_iter()
+            .map(|(logical_name, field_type, relation_target)| {
+                EntityFieldDefinition::new(
+                    entity_logical_name,
+                    logical_name,
+                    logical_name,
+                    field_type,
+                    false,
+                    false,
+                    None,
+                    relation_target.map(str::to_owned),
+                )
+                .unwrap_or_else(|_| unreachable!())
+            })
+            .collect(),
+        Vec::new(),
+    )
+    .unwrap_or_else(|_| unreachable!())
+}
+
+fn harness() -> Harness {
+    let tenant_id = TenantId::new();
+    let admin = UserIdentity::new("alice", "Alice", None, tenant_id);
+    let repository = Arc::new(FakeReportingProjectionRepository::default());
+    let runtime = Arc::new(FakeRuntimeService {
+        schemas: vec![
+            schema(
+                "account",
+                vec![
+                    ("name", FieldType::Text, None),
+                    ("region", FieldType::Text, None),
+                ],
+            ),
+            schema(
+                "contact",
+                vec![
+                    ("full_name", FieldType::Text, None),
+                    ("account_id", FieldType::Relation, Some("account")),
+                ],
+            ),
+        ],
+        records: Mutex::new(HashMap::new()),
+        readers: vec!["alice".to_owned()],
+    });
+    let audit = Arc::new(FakeAuditRepository::default());
+    let service = ReportingProjectionService::new(
+        AuthorizationService::new(
+            Arc::new(FakeAuthorizationRepository {
+                grants: HashMap::from([(
+                    (tenant_id, "alice".to_owned()),
+                    vec![
+                        Permission::MetadataFieldRead,
+                        Permission::MetadataFieldWrite,
+                    ],
+                )]),
+            }),
+            audit.clone(),
+        ),
+        repository.clone(),
+        runtime.clone(),
+        audit.clone(),
+    );
+
+    Harness {
+        service,
+        repository,
+        runtime,
+        audit,
+        admin,
+    }
+}
+
+fn column(
+    column_name: &str,
+    relation_field_logical_name: Option<&str>,
+    field_logical_name: &str,
+) -> ReportingProjectionColumnInput {
+    ReportingProjectionColumnInput {
+        column_name: column_name.to_owned(),
+        relation_field_logical_name: relation_field_logical_name.map(str::to_owned),
+        field_logical_name: field_logical_name.to_owned(),
+    }
+}
+
+fn contact_accounts(columns: Vec<ReportingProjectionColumnInput>) -> SaveReportingProjectionInput {
+    SaveReportingProjectionInput {
+        logical_name: "contact_accounts".to_owned(),
+        display_name: "Contacts with accounts".to_owned(),
+        root_entity_logical_name: "contact".to_owned(),
+        columns,
+    }
+}
+
+fn query(filters: Vec<ReportingProjectionFilter>, limit: usize) -> ReportingProjectionQuery {
+    ReportingProjectionQuery {
+        filters,
+        sort_column: None,
+        sort_descending: false,
+        limit,
+        offset: 0,
+    }
+}
+
+#[tokio::test]
+async fn projections_join_parent_fields_and_follow_the_change_feed() {
+    let harness = harness();
+    let projection = harness
+        .service
+        .save_projection(
+            &harness.admin,
+            contact_accounts(vec![
+                column("contact_name", None, "full_name"),
+                column("account_name", Some("account_id"), "name"),
+                column("account_region", Some("account_id"), "region"),
+            ]),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        projection.columns[1].parent_entity_logical_name.as_deref(),
+        Some("account")
+    );
+    assert_eq!(
+        projection.source_entity_logical_names(),
+        vec!["contact".to_owned(), "account".to_owned()]
+    );
+
+    harness
+        .write_record("account", "a1", json!({"name": "Acme", "region": "emea"}))
+        .await;
+    harness
+        .write_record("account", "a2", json!({"name": "Globex", "region": "amer"}))
+        .await;
+    harness
+        .write_record(
+            "contact",
+            "c1",
+            json!({"full_name": "Ada", "account_id": "a1"}),
+        )
+        .await;
+    harness
+        .write_record(
+            "contact",
+            "c2",
+            json!({"full_name": "Grace", "account_id": "a1"}),
+        )
+        .await;
+    harness
+        .write_record("contact", "c3", json!({"full_name": "Linus"}))
+        .await;
+    assert_eq!(
+        harness
+            .service
+            .process_runtime_record_changes(10)
+            .await
+            .unwrap_or_else(|_| unreachable!()),
+        5
+    );
+    assert_eq!(harness.repository.pending_changes().await, 0);
+
+    let rows = harness
+        .service
+        .query_projection(&harness.admin, "contact_accounts", query(Vec::new(), 10))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(rows.len(), 3);
+    assert_eq!(
+        rows[0].values,
+        json!({"contact_name": "Ada", "account_name": "Acme", "account_region": "emea"})
+    );
+    assert_eq!(
+        rows[2].values,
+        json!({"contact_name": "Linus", "account_name": null, "account_region": null})
+    );
+
+    harness
+        .write_record(
+            "account",
+            "a1",
+            json!({"name": "Acme Corp", "region": "emea"}),
+        )
+        .await;
+    harness
+        .write_record(
+            "contact",
+            "c2",
+            json!({"full_name": "Grace", "account_id": "a2"}),
+        )
+        .await;
+    harness.delete_record("contact", "c3").await;
+    assert!(
+        harness
+            .service
+            .process_runtime_record_changes(10)
+            .await
+            .is_ok()
+    );
+
+    let emea = harness
+        .service
+        .query_projection(
+            &harness.admin,
+            "contact_accounts",
+            query(
+                vec![ReportingProjectionFilter {
+                    column_name: "account_region".to_owned(),
+                    value: json!("emea"),
+                }],
+                10,
+            ),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(emea.len(), 1);
+    assert_eq!(emea[0].record_id, "c1");
+    assert_eq!(emea[0].values["account_name"], json!("Acme Corp"));
+    let all = harness
+        .service
+        .query_projection(&harness.admin, "contact_accounts", query(Vec::new(), 10))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[1].values["account_name"], json!("Globex"));
+
+    let bob = UserIdentity::new("bob", "Bob", None, harness.admin.tenant_id());
+    let forbidden = harness
+        .service
+        .query_projection(&bob, "contact_accounts", query(Vec::new(), 10))
+        .await;
+    assert!(matches!(forbidden, Err(AppError::Forbidden(_))));
+
+    assert!(
+        harness
+            .service
+            .delete_projection(&harness.admin, "contact_accounts")
+            .await
+            .is_ok()
+    );
+    let events = harness.audit.events.lock().await;
+    assert!(
+        events
+            .iter()
+            .any(|event| event.action == AuditAction::ReportingProjectionSaved)
+    );
+    assert!(
+        events
+            .iter()
+            .any(|event| event.action == AuditAction::ReportingProjectionDeleted)
+    );
+}
+
+#[tokio::test]
+async fn projection_definitions_and_queries_are_validated() {
+    let harness = harness();
+
+    let not_a_lookup = harness
+        .service
+        .save_projection(
+            &harness.admin,
+            contact_accounts(vec![column("account_name", Some("full_name"), "name")]),
+        )
+        .await;
+    assert!(matches!(not_a_lookup, Err(AppError::Validation(_))));
+    let unknown_parent_field = harness
+        .service
+        .save_projection(
+            &harness.admin,
+            contact_accounts(vec![column(
+                "account_size",
+                Some("account_id"),
+                "employees",
+            )]),
+        )
+        .await;
+    assert!(matches!(unknown_parent_field, Err(AppError::Validation(_))));
+    let duplicate_column = harness
+        .service
+        .save_projection(
+            &harness.admin,
+            contact_accounts(vec![
+                column("name", None, "full_name"),
+                column("name", Some("account_id"), "name"),
+            ]),
+        )
+        .await;
+    assert!(matches!(duplicate_column, Err(AppError::Validation(_))));
+    let mut invalid_name = contact_accounts(vec![column("contact_name", None, "full_name")]);
+    invalid_name.logical_name = "Contact Accounts".to_owned();
+    assert!(matches!(
+        harness
+            .service
+            .save_projection(&harness.admin, invalid_name)
+            .await,
+        Err(AppError::Validation(_))
+    ));
+
+    assert!(
+        harness
+            .service
+            .save_projection(
+                &harness.admin,
+                contact_accounts(vec![column("contact_name", None, "created_at")]),
+            )
+            .await
+            .is_ok()
+    );
+    let unknown_column = harness
+        .service
+        .query_projection(
+            &harness.admin,
+            "contact_accounts",
+            query(
+                vec![ReportingProjectionFilter {
+                    column_name: "full_name".to_owned(),
+                    value: json!("Ada"),
+                }],
+                10,
+            ),
+        )
+        .await;
+    assert!(matches!(unknown_column, Err(AppError::Validation(_))));
+    let too_many_rows = harness
+        .service
+        .query_projection(&harness.admin, "contact_accounts", query(Vec::new(), 5_000))
+        .await;
+    assert!(matches!(too_many_rows, Err(AppError::Validation(_))));
+    let missing = harness
+        .service
+        .query_projection(&harness.admin, "missing", query(Vec::new(), 10))
+        .await;
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+}
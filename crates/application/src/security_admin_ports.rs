@@ -1,19 +1,26 @@
 mod audit;
 mod governance;
+mod mfa_reset;
 mod repositories;
 mod roles;
 mod runtime_permissions;
+mod teams;
 mod temporary_access;
 
 pub use audit::{
-    AuditIntegrityStatus, AuditLogEntry, AuditLogQuery, WorkspacePublishRunAuditInput,
+    AuditChainAnchor, AuditChainAnchorReason, AuditChainVerificationScope, AuditIntegrityStatus,
+    AuditLogEntry, AuditLogQuery, WorkspacePublishRunAuditInput,
 };
-pub use governance::{AuditPurgeResult, AuditRetentionPolicy};
+pub use governance::{
+    AuditPurgePlan, AuditPurgeResult, AuditRetentionPolicy, WorkflowConcurrencyPolicy,
+};
+pub use mfa_reset::{CreateMfaResetRequestInput, MfaResetRequest};
 pub use repositories::{AuditLogRepository, SecurityAdminRepository};
 pub use roles::{CreateRoleInput, RoleAssignment, RoleDefinition};
 pub use runtime_permissions::{
     RuntimeFieldPermissionEntry, RuntimeFieldPermissionInput, SaveRuntimeFieldPermissionsInput,
 };
+pub use teams::{SecurityTeam, SecurityTeamMember};
 pub use temporary_access::{
     CreateTemporaryAccessGrantInput, TemporaryAccessGrant, TemporaryAccessGrantQuery,
 };
@@ -1,3 +1,5 @@
+use qryvanta_core::{AppError, AppResult};
+
 /// Audit log entry projection for administrative views.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AuditLogEntry {
@@ -34,10 +36,79 @@ pub struct AuditIntegrityStatus {
     pub latest_chain_position: Option<i64>,
     /// Latest verified entry hash, if any entries exist.
     pub latest_entry_hash: Option<String>,
+    /// Anchor the verification started from, when it did not start at the chain head.
+    pub verified_from_anchor: Option<AuditChainAnchor>,
     /// Human-readable verification failures.
     pub failures: Vec<String>,
 }
 
+/// How much of the tenant audit chain a verification run scans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AuditChainVerificationScope {
+    /// Re-hash every retained entry and check every anchor.
+    #[default]
+    Full,
+    /// Re-hash only the entries after the latest anchor.
+    SinceLatestAnchor,
+}
+
+impl AuditChainVerificationScope {
+    /// Parses a transport value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "full" => Ok(Self::Full),
+            "since_latest_anchor" => Ok(Self::SinceLatestAnchor),
+            _ => Err(AppError::Validation(format!(
+                "unknown audit chain verification scope '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Why an audit chain anchor was recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditChainAnchorReason {
+    /// Recorded every fixed number of chain positions.
+    Periodic,
+    /// Recorded for the last entry of a purged range so the gap stays verifiable.
+    Purge,
+}
+
+impl AuditChainAnchorReason {
+    /// Returns the stable transport value.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Periodic => "periodic",
+            Self::Purge => "purge",
+        }
+    }
+
+    /// Parses a stored value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "periodic" => Ok(Self::Periodic),
+            "purge" => Ok(Self::Purge),
+            _ => Err(AppError::Validation(format!(
+                "unknown audit chain anchor reason '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Checkpoint of the tenant audit chain at one position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditChainAnchor {
+    /// Anchored chain position.
+    pub chain_position: i64,
+    /// Entry hash at the anchored position.
+    pub entry_hash: String,
+    /// Why the anchor was recorded.
+    pub reason: AuditChainAnchorReason,
+    /// Anchor timestamp in RFC3339.
+    pub created_at: String,
+}
+
 /// Query parameters for audit log listing.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AuditLogQuery {
@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+
 /// Audit retention policy projection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AuditRetentionPolicy {
@@ -5,6 +7,13 @@ pub struct AuditRetentionPolicy {
     pub retention_days: u16,
 }
 
+/// Tenant workflow concurrency policy projection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkflowConcurrencyPolicy {
+    /// Max queued workflow jobs leased to workers at once; `None` is unlimited.
+    pub max_concurrent_jobs: Option<u32>,
+}
+
 /// Audit purge operation result.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AuditPurgeResult {
@@ -13,3 +22,14 @@ pub struct AuditPurgeResult {
     /// Effective retention window in days.
     pub retention_days: u16,
 }
+
+/// Audit purge whose cutoff is fixed when it is planned.
+///
+/// Batched purges keep deleting the same range when they resume later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditPurgePlan {
+    /// Retention window in days the cutoff was derived from.
+    pub retention_days: u16,
+    /// Entries created before this instant are purged.
+    pub cutoff: DateTime<Utc>,
+}
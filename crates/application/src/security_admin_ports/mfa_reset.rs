@@ -0,0 +1,29 @@
+/// Input payload for requesting an administrator MFA reset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateMfaResetRequestInput {
+    /// Subject whose MFA should be reset.
+    pub subject: String,
+    /// Justification recorded for the approving administrator.
+    pub reason: String,
+}
+
+/// Administrator MFA reset request projection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MfaResetRequest {
+    /// Stable request id.
+    pub request_id: String,
+    /// Subject whose MFA should be reset.
+    pub subject: String,
+    /// Justification recorded by the requesting administrator.
+    pub reason: String,
+    /// Administrator who opened the request.
+    pub requested_by_subject: String,
+    /// Request timestamp in RFC3339.
+    pub requested_at: String,
+    /// Approval deadline in RFC3339.
+    pub expires_at: String,
+    /// Second administrator who approved the reset, when approved.
+    pub approved_by_subject: Option<String>,
+    /// Approval timestamp in RFC3339, when approved.
+    pub approved_at: Option<String>,
+}
@@ -1,12 +1,18 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use qryvanta_core::{AppResult, TenantId};
-use qryvanta_domain::RegistrationMode;
+use qryvanta_domain::{RegistrationMode, SubjectType, TeamDefinition};
 
-use super::audit::{AuditIntegrityStatus, AuditLogEntry, AuditLogQuery};
-use super::governance::AuditRetentionPolicy;
+use super::audit::{
+    AuditChainAnchor, AuditChainVerificationScope, AuditIntegrityStatus, AuditLogEntry,
+    AuditLogQuery,
+};
+use super::governance::{AuditRetentionPolicy, WorkflowConcurrencyPolicy};
+use super::mfa_reset::{CreateMfaResetRequestInput, MfaResetRequest};
 use super::roles::{CreateRoleInput, RoleAssignment, RoleDefinition};
 use super::runtime_permissions::{RuntimeFieldPermissionEntry, SaveRuntimeFieldPermissionsInput};
+use super::teams::{SecurityTeam, SecurityTeamMember};
 use super::temporary_access::{
     CreateTemporaryAccessGrantInput, TemporaryAccessGrant, TemporaryAccessGrantQuery,
 };
@@ -24,18 +30,22 @@ pub trait SecurityAdminRepository: Send + Sync {
         input: CreateRoleInput,
     ) -> AppResult<RoleDefinition>;
 
-    /// Assigns an existing role to a subject.
+    /// Assigns an existing role to a user subject or a team.
+    ///
+    /// Team subjects are addressed by team name.
     async fn assign_role_to_subject(
         &self,
         tenant_id: TenantId,
+        subject_type: SubjectType,
         subject: &str,
         role_name: &str,
     ) -> AppResult<()>;
 
-    /// Removes a role assignment from a subject.
+    /// Removes a role assignment from a user subject or a team.
     async fn remove_role_from_subject(
         &self,
         tenant_id: TenantId,
+        subject_type: SubjectType,
         subject: &str,
         role_name: &str,
     ) -> AppResult<()>;
@@ -58,6 +68,41 @@ pub trait SecurityAdminRepository: Send + Sync {
         entity_logical_name: Option<&str>,
     ) -> AppResult<Vec<RuntimeFieldPermissionEntry>>;
 
+    /// Creates a security team.
+    async fn create_team(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        team: TeamDefinition,
+    ) -> AppResult<SecurityTeam>;
+
+    /// Lists security teams in tenant scope.
+    async fn list_teams(&self, tenant_id: TenantId) -> AppResult<Vec<SecurityTeam>>;
+
+    /// Adds a tenant member to a team.
+    async fn add_team_member(
+        &self,
+        tenant_id: TenantId,
+        added_by_subject: &str,
+        team_name: &str,
+        subject: &str,
+    ) -> AppResult<SecurityTeamMember>;
+
+    /// Removes a member from a team.
+    async fn remove_team_member(
+        &self,
+        tenant_id: TenantId,
+        team_name: &str,
+        subject: &str,
+    ) -> AppResult<()>;
+
+    /// Lists members of a team.
+    async fn list_team_members(
+        &self,
+        tenant_id: TenantId,
+        team_name: &str,
+    ) -> AppResult<Vec<SecurityTeamMember>>;
+
     /// Creates a temporary privileged access grant.
     async fn create_temporary_access_grant(
         &self,
@@ -82,6 +127,43 @@ pub trait SecurityAdminRepository: Send + Sync {
         query: TemporaryAccessGrantQuery,
     ) -> AppResult<Vec<TemporaryAccessGrant>>;
 
+    /// Creates a pending MFA reset request for a tenant member.
+    ///
+    /// Fails with not found when the subject is not a member of the tenant and
+    /// with conflict when an unexpired request is already pending.
+    async fn create_mfa_reset_request(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        input: CreateMfaResetRequestInput,
+        expires_at: DateTime<Utc>,
+    ) -> AppResult<MfaResetRequest>;
+
+    /// Returns one MFA reset request.
+    async fn find_mfa_reset_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+    ) -> AppResult<Option<MfaResetRequest>>;
+
+    /// Marks a pending, unexpired MFA reset request approved.
+    ///
+    /// The approver must differ from both the requesting administrator and
+    /// the target subject; otherwise the request is left untouched.
+    async fn approve_mfa_reset_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+        approved_by_subject: &str,
+    ) -> AppResult<MfaResetRequest>;
+
+    /// Lists MFA reset requests, newest first.
+    async fn list_mfa_reset_requests(
+        &self,
+        tenant_id: TenantId,
+        pending_only: bool,
+    ) -> AppResult<Vec<MfaResetRequest>>;
+
     /// Returns the tenant registration mode.
     async fn registration_mode(&self, tenant_id: TenantId) -> AppResult<RegistrationMode>;
 
@@ -101,6 +183,19 @@ pub trait SecurityAdminRepository: Send + Sync {
         tenant_id: TenantId,
         retention_days: u16,
     ) -> AppResult<AuditRetentionPolicy>;
+
+    /// Returns tenant workflow concurrency policy.
+    async fn workflow_concurrency_policy(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<WorkflowConcurrencyPolicy>;
+
+    /// Updates and returns tenant workflow concurrency policy.
+    async fn set_workflow_concurrency_policy(
+        &self,
+        tenant_id: TenantId,
+        max_concurrent_jobs: Option<u32>,
+    ) -> AppResult<WorkflowConcurrencyPolicy>;
 }
 
 /// Repository port for reading tenant audit logs.
@@ -121,12 +216,38 @@ pub trait AuditLogRepository: Send + Sync {
     ) -> AppResult<Vec<AuditLogEntry>>;
 
     /// Purges tenant audit entries older than the retention window.
+    ///
+    /// Entries inside an active audit legal hold range are kept.
     async fn purge_entries_older_than(
         &self,
         tenant_id: TenantId,
         retention_days: u16,
     ) -> AppResult<u64>;
 
+    /// Purges up to `limit` of the oldest tenant audit entries created before `cutoff`.
+    ///
+    /// Entries inside an active audit legal hold range are kept.
+    async fn purge_entries_created_before(
+        &self,
+        tenant_id: TenantId,
+        cutoff: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<u64>;
+
     /// Verifies tenant audit-chain integrity.
-    async fn verify_integrity(&self, tenant_id: TenantId) -> AppResult<AuditIntegrityStatus>;
+    ///
+    /// Purged ranges are accepted only when an anchor records the last purged
+    /// entry hash, so gaps without an anchor are reported as failures.
+    async fn verify_integrity(
+        &self,
+        tenant_id: TenantId,
+        scope: AuditChainVerificationScope,
+    ) -> AppResult<AuditIntegrityStatus>;
+
+    /// Lists tenant audit chain anchors, newest first.
+    async fn list_chain_anchors(
+        &self,
+        tenant_id: TenantId,
+        limit: usize,
+    ) -> AppResult<Vec<AuditChainAnchor>>;
 }
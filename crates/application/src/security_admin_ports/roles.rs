@@ -1,4 +1,4 @@
-use qryvanta_domain::Permission;
+use qryvanta_domain::{Permission, SubjectType};
 
 /// Role definition returned to callers.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -16,7 +16,9 @@ pub struct RoleDefinition {
 /// Assignment projection mapping a subject to a role.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RoleAssignment {
-    /// Subject identifier.
+    /// Kind of subject holding the role.
+    pub subject_type: SubjectType,
+    /// Subject identifier, or the team name for team assignments.
     pub subject: String,
     /// Role identifier.
     pub role_id: String,
@@ -1,3 +1,5 @@
+use qryvanta_domain::SubjectType;
+
 /// Field-level runtime permission update item.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RuntimeFieldPermissionInput {
@@ -12,7 +14,9 @@ pub struct RuntimeFieldPermissionInput {
 /// Input payload for subject runtime field permission updates.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SaveRuntimeFieldPermissionsInput {
-    /// Subject principal identifier.
+    /// Kind of subject receiving the grants.
+    pub subject_type: SubjectType,
+    /// Subject principal identifier, or the team name for team grants.
     pub subject: String,
     /// Entity logical name.
     pub entity_logical_name: String,
@@ -23,7 +27,9 @@ pub struct SaveRuntimeFieldPermissionsInput {
 /// Runtime field permission projection returned to callers.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RuntimeFieldPermissionEntry {
-    /// Subject principal identifier.
+    /// Kind of subject holding the grant.
+    pub subject_type: SubjectType,
+    /// Subject principal identifier, or the team name for team grants.
     pub subject: String,
     /// Entity logical name.
     pub entity_logical_name: String,
@@ -0,0 +1,29 @@
+/// Security team projection returned to callers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityTeam {
+    /// Stable team identifier.
+    pub team_id: String,
+    /// Unique team name in tenant scope.
+    pub name: String,
+    /// Optional team description.
+    pub description: Option<String>,
+    /// Number of team members.
+    pub member_count: u32,
+    /// Subject that created the team.
+    pub created_by_subject: String,
+    /// Creation timestamp in RFC3339.
+    pub created_at: String,
+}
+
+/// Membership projection mapping a subject to a team.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityTeamMember {
+    /// Team name.
+    pub team_name: String,
+    /// Member subject identifier.
+    pub subject: String,
+    /// Subject that added the member.
+    pub added_by_subject: String,
+    /// Membership timestamp in RFC3339.
+    pub added_at: String,
+}
@@ -6,11 +6,13 @@ use qryvanta_domain::{Permission, RegistrationMode};
 use crate::security_admin_ports::{
     AuditLogRepository, SecurityAdminRepository, WorkspacePublishRunAuditInput,
 };
-use crate::{AuditRepository, AuthorizationService};
+use crate::{AuditRepository, AuthorizationService, EmailService, UserRepository};
 
 mod governance;
+mod mfa_reset;
 mod roles;
 mod runtime_permissions;
+mod teams;
 mod temporary_access;
 
 /// Application service for security administration workflows.
@@ -21,6 +23,14 @@ pub struct SecurityAdminService {
     audit_log_repository: Arc<dyn AuditLogRepository>,
     audit_repository: Arc<dyn AuditRepository>,
     audit_immutable_mode: bool,
+    mfa_reset: Option<MfaResetDependencies>,
+}
+
+/// Account dependencies required by administrator MFA resets.
+#[derive(Clone)]
+struct MfaResetDependencies {
+    user_repository: Arc<dyn UserRepository>,
+    email_service: Arc<dyn EmailService>,
 }
 
 impl SecurityAdminService {
@@ -38,6 +48,7 @@ impl SecurityAdminService {
             audit_log_repository,
             audit_repository,
             audit_immutable_mode: false,
+            mfa_reset: None,
         }
     }
 
@@ -48,6 +59,20 @@ impl SecurityAdminService {
         self
     }
 
+    /// Enables four-eyes administrator MFA resets backed by the user store.
+    #[must_use]
+    pub fn with_mfa_reset(
+        mut self,
+        user_repository: Arc<dyn UserRepository>,
+        email_service: Arc<dyn EmailService>,
+    ) -> Self {
+        self.mfa_reset = Some(MfaResetDependencies {
+            user_repository,
+            email_service,
+        });
+        self
+    }
+
     pub(super) async fn require_role_manage_permission(
         &self,
         actor: &UserIdentity,
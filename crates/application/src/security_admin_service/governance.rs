@@ -4,8 +4,9 @@ use qryvanta_domain::AuditAction;
 
 use crate::AuditEvent;
 use crate::security_admin_ports::{
-    AuditIntegrityStatus, AuditLogEntry, AuditLogQuery, AuditPurgeResult, AuditRetentionPolicy,
-    WorkspacePublishRunAuditInput,
+    AuditChainAnchor, AuditChainVerificationScope, AuditIntegrityStatus, AuditLogEntry,
+    AuditLogQuery, AuditPurgePlan, AuditPurgeResult, AuditRetentionPolicy,
+    WorkflowConcurrencyPolicy, WorkspacePublishRunAuditInput,
 };
 
 impl SecurityAdminService {
@@ -38,9 +39,31 @@ impl SecurityAdminService {
         &self,
         actor: &UserIdentity,
     ) -> AppResult<AuditIntegrityStatus> {
+        self.verify_audit_chain(actor, AuditChainVerificationScope::Full)
+            .await
+    }
+
+    /// Verifies the tenant audit chain, optionally starting at the latest anchor.
+    pub async fn verify_audit_chain(
+        &self,
+        actor: &UserIdentity,
+        scope: AuditChainVerificationScope,
+    ) -> AppResult<AuditIntegrityStatus> {
+        self.require_audit_read_permission(actor).await?;
+        self.audit_log_repository
+            .verify_integrity(actor.tenant_id(), scope)
+            .await
+    }
+
+    /// Lists recent tenant audit chain anchors.
+    pub async fn list_audit_chain_anchors(
+        &self,
+        actor: &UserIdentity,
+        limit: usize,
+    ) -> AppResult<Vec<AuditChainAnchor>> {
         self.require_audit_read_permission(actor).await?;
         self.audit_log_repository
-            .verify_integrity(actor.tenant_id())
+            .list_chain_anchors(actor.tenant_id(), limit.clamp(1, 500))
             .await
     }
 
@@ -162,19 +185,64 @@ impl SecurityAdminService {
         Ok(policy)
     }
 
-    /// Purges audit entries older than the configured retention policy.
-    pub async fn purge_audit_log_entries(
+    /// Returns tenant workflow concurrency policy for administrative users.
+    pub async fn workflow_concurrency_policy(
         &self,
         actor: &UserIdentity,
-    ) -> AppResult<AuditPurgeResult> {
+    ) -> AppResult<WorkflowConcurrencyPolicy> {
         self.require_role_manage_permission(actor).await?;
+        self.repository
+            .workflow_concurrency_policy(actor.tenant_id())
+            .await
+    }
 
-        if self.audit_immutable_mode {
-            return Err(qryvanta_core::AppError::Forbidden(
-                "audit log is immutable; purge is disabled".to_owned(),
+    /// Updates tenant workflow concurrency policy and emits an audit event.
+    ///
+    /// `None` removes the cap so the tenant only competes through fair-share claiming.
+    pub async fn update_workflow_concurrency_policy(
+        &self,
+        actor: &UserIdentity,
+        max_concurrent_jobs: Option<u32>,
+    ) -> AppResult<WorkflowConcurrencyPolicy> {
+        self.require_role_manage_permission(actor).await?;
+
+        if max_concurrent_jobs == Some(0) {
+            return Err(qryvanta_core::AppError::Validation(
+                "workflow max_concurrent_jobs must be greater than zero".to_owned(),
             ));
         }
 
+        let policy = self
+            .repository
+            .set_workflow_concurrency_policy(actor.tenant_id(), max_concurrent_jobs)
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityTenantWorkflowConcurrencyUpdated,
+                resource_type: "tenant".to_owned(),
+                resource_id: actor.tenant_id().to_string(),
+                detail: Some(match policy.max_concurrent_jobs {
+                    Some(max_concurrent_jobs) => format!(
+                        "set workflow concurrency policy to {max_concurrent_jobs} concurrent job(s)"
+                    ),
+                    None => "removed workflow concurrency limit".to_owned(),
+                }),
+            })
+            .await?;
+
+        Ok(policy)
+    }
+
+    /// Purges audit entries older than the configured retention policy.
+    pub async fn purge_audit_log_entries(
+        &self,
+        actor: &UserIdentity,
+    ) -> AppResult<AuditPurgeResult> {
+        self.require_audit_purge_allowed(actor).await?;
+
         let policy = self
             .repository
             .audit_retention_policy(actor.tenant_id())
@@ -184,6 +252,80 @@ impl SecurityAdminService {
             .purge_entries_older_than(actor.tenant_id(), policy.retention_days)
             .await?;
 
+        self.append_audit_purge_event(actor, deleted_count, policy.retention_days)
+            .await?;
+
+        Ok(AuditPurgeResult {
+            deleted_count,
+            retention_days: policy.retention_days,
+        })
+    }
+
+    /// Fixes the cutoff of a batched audit purge from the current retention policy.
+    pub async fn plan_audit_log_purge(&self, actor: &UserIdentity) -> AppResult<AuditPurgePlan> {
+        self.require_audit_purge_allowed(actor).await?;
+
+        let policy = self
+            .repository
+            .audit_retention_policy(actor.tenant_id())
+            .await?;
+
+        Ok(AuditPurgePlan {
+            retention_days: policy.retention_days,
+            cutoff: chrono::Utc::now() - chrono::Duration::days(i64::from(policy.retention_days)),
+        })
+    }
+
+    /// Purges one batch of a planned audit purge, returning the number of deleted entries.
+    ///
+    /// A batch smaller than `limit` means nothing is left to purge.
+    pub async fn purge_audit_log_batch(
+        &self,
+        actor: &UserIdentity,
+        plan: &AuditPurgePlan,
+        limit: usize,
+    ) -> AppResult<u64> {
+        self.require_audit_purge_allowed(actor).await?;
+
+        self.audit_log_repository
+            .purge_entries_created_before(actor.tenant_id(), plan.cutoff, limit)
+            .await
+    }
+
+    /// Records the audit event of a finished batched purge.
+    pub async fn complete_audit_log_purge(
+        &self,
+        actor: &UserIdentity,
+        plan: &AuditPurgePlan,
+        deleted_count: u64,
+    ) -> AppResult<AuditPurgeResult> {
+        self.append_audit_purge_event(actor, deleted_count, plan.retention_days)
+            .await?;
+
+        Ok(AuditPurgeResult {
+            deleted_count,
+            retention_days: plan.retention_days,
+        })
+    }
+
+    async fn require_audit_purge_allowed(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.require_role_manage_permission(actor).await?;
+
+        if self.audit_immutable_mode {
+            return Err(qryvanta_core::AppError::Forbidden(
+                "audit log is immutable; purge is disabled".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn append_audit_purge_event(
+        &self,
+        actor: &UserIdentity,
+        deleted_count: u64,
+        retention_days: u16,
+    ) -> AppResult<()> {
         self.audit_repository
             .append_event(AuditEvent {
                 tenant_id: actor.tenant_id(),
@@ -193,14 +335,9 @@ impl SecurityAdminService {
                 resource_id: actor.tenant_id().to_string(),
                 detail: Some(format!(
                     "purged {} audit entries older than {} day(s)",
-                    deleted_count, policy.retention_days
+                    deleted_count, retention_days
                 )),
             })
-            .await?;
-
-        Ok(AuditPurgeResult {
-            deleted_count,
-            retention_days: policy.retention_days,
-        })
+            .await
     }
 }
@@ -0,0 +1,196 @@
+use super::*;
+
+use chrono::{Duration, Utc};
+use qryvanta_core::AppError;
+use qryvanta_domain::AuditAction;
+
+use crate::security_admin_ports::{CreateMfaResetRequestInput, MfaResetRequest};
+use crate::{AuditEvent, UserRecord};
+
+/// Hours a second administrator has to approve an MFA reset request.
+const MFA_RESET_APPROVAL_WINDOW_HOURS: i64 = 24;
+
+impl SecurityAdminService {
+    /// Opens an MFA reset request for a locked-out tenant member.
+    ///
+    /// The reset only takes effect once a different administrator approves it.
+    pub async fn request_mfa_reset(
+        &self,
+        actor: &UserIdentity,
+        input: CreateMfaResetRequestInput,
+    ) -> AppResult<MfaResetRequest> {
+        self.require_role_manage_permission(actor).await?;
+        let dependencies = self.mfa_reset_dependencies()?;
+
+        if input.reason.trim().is_empty() {
+            return Err(AppError::Validation(
+                "mfa reset reason must not be empty".to_owned(),
+            ));
+        }
+        if input.subject == actor.subject() {
+            return Err(AppError::Validation(
+                "administrators cannot request an mfa reset for themselves".to_owned(),
+            ));
+        }
+
+        let user = self
+            .find_mfa_reset_user(dependencies, input.subject.as_str())
+            .await?;
+        if !user.totp_enabled {
+            return Err(AppError::Validation(format!(
+                "subject '{}' does not have mfa enabled",
+                input.subject
+            )));
+        }
+
+        let request = self
+            .repository
+            .create_mfa_reset_request(
+                actor.tenant_id(),
+                actor.subject(),
+                input,
+                Utc::now() + Duration::hours(MFA_RESET_APPROVAL_WINDOW_HOURS),
+            )
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityMfaResetRequested,
+                resource_type: "security_mfa_reset_request".to_owned(),
+                resource_id: request.request_id.clone(),
+                detail: Some(format!(
+                    "requested mfa reset for '{}': {}",
+                    request.subject, request.reason
+                )),
+            })
+            .await?;
+
+        let body = format!(
+            "An administrator requested a reset of the multi-factor authentication \
+             on your Qryvanta account.\n\nReason: {}\n\n\
+             The reset takes effect only after a second administrator approves it. \
+             If you did not ask for this, contact your administrator.",
+            request.reason
+        );
+        dependencies
+            .email_service
+            .send_email(
+                user.email.as_str(),
+                "MFA reset requested for your Qryvanta account",
+                &body,
+                None,
+            )
+            .await?;
+
+        Ok(request)
+    }
+
+    /// Approves a pending MFA reset request and removes MFA from the account.
+    ///
+    /// The approver must not be the administrator who opened the request or
+    /// the subject being reset. Existing sessions of the subject are revoked.
+    pub async fn approve_mfa_reset(
+        &self,
+        actor: &UserIdentity,
+        request_id: &str,
+    ) -> AppResult<MfaResetRequest> {
+        self.require_role_manage_permission(actor).await?;
+        let dependencies = self.mfa_reset_dependencies()?;
+
+        let pending = self
+            .repository
+            .find_mfa_reset_request(actor.tenant_id(), request_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("mfa reset request '{request_id}' was not found"))
+            })?;
+        if pending.requested_by_subject == actor.subject() {
+            return Err(AppError::Forbidden(
+                "mfa reset requires approval by a second administrator".to_owned(),
+            ));
+        }
+        if pending.subject == actor.subject() {
+            return Err(AppError::Forbidden(
+                "administrators cannot approve an mfa reset for themselves".to_owned(),
+            ));
+        }
+
+        let user = self
+            .find_mfa_reset_user(dependencies, pending.subject.as_str())
+            .await?;
+        let request = self
+            .repository
+            .approve_mfa_reset_request(actor.tenant_id(), request_id, actor.subject())
+            .await?;
+
+        dependencies.user_repository.disable_totp(user.id).await?;
+        dependencies
+            .user_repository
+            .revoke_sessions(user.id)
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityMfaResetApproved,
+                resource_type: "security_mfa_reset_request".to_owned(),
+                resource_id: request.request_id.clone(),
+                detail: Some(format!(
+                    "approved mfa reset for '{}' requested by '{}'",
+                    request.subject, request.requested_by_subject
+                )),
+            })
+            .await?;
+
+        let body = "Multi-factor authentication was removed from your Qryvanta account \
+                    after approval by two administrators, and all of your sessions were \
+                    signed out.\n\nSign in with your password and enroll a new \
+                    authenticator from your profile. If you did not ask for this, \
+                    contact your administrator immediately.";
+        dependencies
+            .email_service
+            .send_email(
+                user.email.as_str(),
+                "MFA was reset on your Qryvanta account",
+                body,
+                None,
+            )
+            .await?;
+
+        Ok(request)
+    }
+
+    /// Lists MFA reset requests for administrators.
+    pub async fn list_mfa_reset_requests(
+        &self,
+        actor: &UserIdentity,
+        pending_only: bool,
+    ) -> AppResult<Vec<MfaResetRequest>> {
+        self.require_role_manage_permission(actor).await?;
+
+        self.repository
+            .list_mfa_reset_requests(actor.tenant_id(), pending_only)
+            .await
+    }
+
+    fn mfa_reset_dependencies(&self) -> AppResult<&MfaResetDependencies> {
+        self.mfa_reset.as_ref().ok_or_else(|| {
+            AppError::Internal("mfa reset is not configured for this deployment".to_owned())
+        })
+    }
+
+    async fn find_mfa_reset_user(
+        &self,
+        dependencies: &MfaResetDependencies,
+        subject: &str,
+    ) -> AppResult<UserRecord> {
+        dependencies
+            .user_repository
+            .find_by_subject(subject)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("user '{subject}' was not found")))
+    }
+}
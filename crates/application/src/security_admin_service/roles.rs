@@ -1,6 +1,6 @@
 use super::*;
 
-use qryvanta_domain::AuditAction;
+use qryvanta_domain::{AuditAction, SubjectType};
 
 use crate::AuditEvent;
 use crate::security_admin_ports::{CreateRoleInput, RoleAssignment, RoleDefinition};
@@ -12,14 +12,35 @@ impl SecurityAdminService {
         self.repository.list_roles(actor.tenant_id()).await
     }
 
+    /// Returns the permission catalog custom roles are built from.
+    pub async fn list_permission_catalog(
+        &self,
+        actor: &UserIdentity,
+    ) -> AppResult<&'static [Permission]> {
+        self.require_role_manage_permission(actor).await?;
+        Ok(Permission::all())
+    }
+
     /// Creates a custom role and emits an audit event.
+    ///
+    /// The grant set may be any combination of permissions as long as every
+    /// implied permission is granted too.
     pub async fn create_role(
         &self,
         actor: &UserIdentity,
-        input: CreateRoleInput,
+        mut input: CreateRoleInput,
     ) -> AppResult<RoleDefinition> {
         self.require_role_manage_permission(actor).await?;
 
+        let mut permissions = Vec::with_capacity(input.permissions.len());
+        for permission in input.permissions {
+            if !permissions.contains(&permission) {
+                permissions.push(permission);
+            }
+        }
+        Permission::validate_grant_set(&permissions)?;
+        input.permissions = permissions;
+
         let role = self
             .repository
             .create_role(actor.tenant_id(), input)
@@ -39,17 +60,18 @@ impl SecurityAdminService {
         Ok(role)
     }
 
-    /// Assigns a role to a subject and emits an audit event.
+    /// Assigns a role to a user subject or a team and emits an audit event.
     pub async fn assign_role(
         &self,
         actor: &UserIdentity,
+        subject_type: SubjectType,
         subject: &str,
         role_name: &str,
     ) -> AppResult<()> {
         self.require_role_manage_permission(actor).await?;
 
         self.repository
-            .assign_role_to_subject(actor.tenant_id(), subject, role_name)
+            .assign_role_to_subject(actor.tenant_id(), subject_type, subject, role_name)
             .await?;
 
         self.audit_repository
@@ -59,22 +81,26 @@ impl SecurityAdminService {
                 action: AuditAction::SecurityRoleAssigned,
                 resource_type: "rbac_subject_role".to_owned(),
                 resource_id: format!("{subject}:{role_name}"),
-                detail: Some(format!("assigned role '{role_name}' to '{subject}'")),
+                detail: Some(format!(
+                    "assigned role '{role_name}' to {} '{subject}'",
+                    subject_type.as_str()
+                )),
             })
             .await
     }
 
-    /// Removes a role assignment from a subject and emits an audit event.
+    /// Removes a role assignment from a user subject or a team and emits an audit event.
     pub async fn unassign_role(
         &self,
         actor: &UserIdentity,
+        subject_type: SubjectType,
         subject: &str,
         role_name: &str,
     ) -> AppResult<()> {
         self.require_role_manage_permission(actor).await?;
 
         self.repository
-            .remove_role_from_subject(actor.tenant_id(), subject, role_name)
+            .remove_role_from_subject(actor.tenant_id(), subject_type, subject, role_name)
             .await?;
 
         self.audit_repository
@@ -84,7 +110,10 @@ impl SecurityAdminService {
                 action: AuditAction::SecurityRoleUnassigned,
                 resource_type: "rbac_subject_role".to_owned(),
                 resource_id: format!("{subject}:{role_name}"),
-                detail: Some(format!("removed role '{role_name}' from '{subject}'")),
+                detail: Some(format!(
+                    "removed role '{role_name}' from {} '{subject}'",
+                    subject_type.as_str()
+                )),
             })
             .await
     }
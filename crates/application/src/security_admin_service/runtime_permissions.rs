@@ -27,8 +27,9 @@ impl SecurityAdminService {
                 resource_type: "runtime_subject_field_permissions".to_owned(),
                 resource_id: format!("{}:{}", input.subject, input.entity_logical_name),
                 detail: Some(format!(
-                    "saved {} runtime field permission entries for subject '{}' and entity '{}'",
+                    "saved {} runtime field permission entries for {} '{}' and entity '{}'",
                     entries.len(),
+                    input.subject_type.as_str(),
                     input.subject,
                     input.entity_logical_name
                 )),
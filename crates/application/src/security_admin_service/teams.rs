@@ -0,0 +1,107 @@
+use super::*;
+
+use qryvanta_domain::{AuditAction, TeamDefinition};
+
+use crate::AuditEvent;
+use crate::security_admin_ports::{SecurityTeam, SecurityTeamMember};
+
+impl SecurityAdminService {
+    /// Returns tenant security teams for administrative users.
+    pub async fn list_teams(&self, actor: &UserIdentity) -> AppResult<Vec<SecurityTeam>> {
+        self.require_role_manage_permission(actor).await?;
+        self.repository.list_teams(actor.tenant_id()).await
+    }
+
+    /// Creates a security team and emits an audit event.
+    pub async fn create_team(
+        &self,
+        actor: &UserIdentity,
+        team: TeamDefinition,
+    ) -> AppResult<SecurityTeam> {
+        self.require_role_manage_permission(actor).await?;
+
+        let team = self
+            .repository
+            .create_team(actor.tenant_id(), actor.subject(), team)
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityTeamCreated,
+                resource_type: "security_team".to_owned(),
+                resource_id: team.name.clone(),
+                detail: Some(format!("created team '{}'", team.name)),
+            })
+            .await?;
+
+        Ok(team)
+    }
+
+    /// Adds a tenant member to a team and emits an audit event.
+    pub async fn add_team_member(
+        &self,
+        actor: &UserIdentity,
+        team_name: &str,
+        subject: &str,
+    ) -> AppResult<SecurityTeamMember> {
+        self.require_role_manage_permission(actor).await?;
+
+        let member = self
+            .repository
+            .add_team_member(actor.tenant_id(), actor.subject(), team_name, subject)
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityTeamMemberAdded,
+                resource_type: "security_team_member".to_owned(),
+                resource_id: format!("{team_name}:{subject}"),
+                detail: Some(format!("added '{subject}' to team '{team_name}'")),
+            })
+            .await?;
+
+        Ok(member)
+    }
+
+    /// Removes a member from a team and emits an audit event.
+    pub async fn remove_team_member(
+        &self,
+        actor: &UserIdentity,
+        team_name: &str,
+        subject: &str,
+    ) -> AppResult<()> {
+        self.require_role_manage_permission(actor).await?;
+
+        self.repository
+            .remove_team_member(actor.tenant_id(), team_name, subject)
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::SecurityTeamMemberRemoved,
+                resource_type: "security_team_member".to_owned(),
+                resource_id: format!("{team_name}:{subject}"),
+                detail: Some(format!("removed '{subject}' from team '{team_name}'")),
+            })
+            .await
+    }
+
+    /// Returns members of a team for administrative users.
+    pub async fn list_team_members(
+        &self,
+        actor: &UserIdentity,
+        team_name: &str,
+    ) -> AppResult<Vec<SecurityTeamMember>> {
+        self.require_role_manage_permission(actor).await?;
+
+        self.repository
+            .list_team_members(actor.tenant_id(), team_name)
+            .await
+    }
+}
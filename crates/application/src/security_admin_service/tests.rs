@@ -5,17 +5,21 @@ use async_trait::async_trait;
 use tokio::sync::Mutex;
 
 use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
-use qryvanta_domain::{Permission, RegistrationMode};
+use qryvanta_domain::{
+    AuditAction, Permission, RegistrationMode, SubjectType, TeamDefinition, UserId,
+};
 
 use crate::security_admin_ports::{
-    AuditIntegrityStatus, AuditLogEntry, AuditLogQuery, AuditLogRepository, AuditRetentionPolicy,
-    CreateRoleInput, CreateTemporaryAccessGrantInput, RoleAssignment, RoleDefinition,
-    RuntimeFieldPermissionEntry, SaveRuntimeFieldPermissionsInput, SecurityAdminRepository,
-    TemporaryAccessGrant, TemporaryAccessGrantQuery, WorkspacePublishRunAuditInput,
+    AuditChainAnchor, AuditChainVerificationScope, AuditIntegrityStatus, AuditLogEntry,
+    AuditLogQuery, AuditLogRepository, AuditRetentionPolicy, CreateMfaResetRequestInput,
+    CreateRoleInput, CreateTemporaryAccessGrantInput, MfaResetRequest, RoleAssignment,
+    RoleDefinition, RuntimeFieldPermissionEntry, SaveRuntimeFieldPermissionsInput,
+    SecurityAdminRepository, SecurityTeam, SecurityTeamMember, TemporaryAccessGrant,
+    TemporaryAccessGrantQuery, WorkflowConcurrencyPolicy, WorkspacePublishRunAuditInput,
 };
 use crate::{
-    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
-    TemporaryPermissionGrant,
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, EmailAttachment,
+    EmailService, RuntimeFieldGrant, TemporaryPermissionGrant, UserRecord, UserRepository,
 };
 
 use super::SecurityAdminService;
@@ -55,13 +59,41 @@ impl AuthorizationRepository for FakeAuthorizationRepository {
     ) -> AppResult<Option<TemporaryPermissionGrant>> {
         Ok(None)
     }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
 }
 
 struct FakeSecurityAdminRepository {
     roles: Mutex<Vec<RoleDefinition>>,
-    assignments: Mutex<Vec<(TenantId, String, String)>>,
+    assignments: Mutex<Vec<(TenantId, SubjectType, String, String)>>,
+    team_members: Mutex<Vec<SecurityTeamMember>>,
     registration_mode: Mutex<RegistrationMode>,
     audit_retention_days: Mutex<u16>,
+    workflow_max_concurrent_jobs: Mutex<Option<u32>>,
+    mfa_reset_requests: Mutex<Vec<MfaResetRequest>>,
 }
 
 impl Default for FakeSecurityAdminRepository {
@@ -69,8 +101,11 @@ impl Default for FakeSecurityAdminRepository {
         Self {
             roles: Mutex::new(Vec::new()),
             assignments: Mutex::new(Vec::new()),
+            team_members: Mutex::new(Vec::new()),
             registration_mode: Mutex::new(RegistrationMode::InviteOnly),
             audit_retention_days: Mutex::new(365),
+            workflow_max_concurrent_jobs: Mutex::new(None),
+            mfa_reset_requests: Mutex::new(Vec::new()),
         }
     }
 }
@@ -99,28 +134,35 @@ impl SecurityAdminRepository for FakeSecurityAdminRepository {
     async fn assign_role_to_subject(
         &self,
         tenant_id: TenantId,
+        subject_type: SubjectType,
         subject: &str,
         role_name: &str,
     ) -> AppResult<()> {
-        self.assignments
-            .lock()
-            .await
-            .push((tenant_id, subject.to_owned(), role_name.to_owned()));
+        self.assignments.lock().await.push((
+            tenant_id,
+            subject_type,
+            subject.to_owned(),
+            role_name.to_owned(),
+        ));
         Ok(())
     }
 
     async fn remove_role_from_subject(
         &self,
         tenant_id: TenantId,
+        subject_type: SubjectType,
         subject: &str,
         role_name: &str,
     ) -> AppResult<()> {
         let mut assignments = self.assignments.lock().await;
-        assignments.retain(|(stored_tenant_id, stored_subject, stored_role_name)| {
-            !(stored_tenant_id == &tenant_id
-                && stored_subject == subject
-                && stored_role_name == role_name)
-        });
+        assignments.retain(
+            |(stored_tenant_id, stored_subject_type, stored_subject, stored_role_name)| {
+                !(stored_tenant_id == &tenant_id
+                    && stored_subject_type == &subject_type
+                    && stored_subject == subject
+                    && stored_role_name == role_name)
+            },
+        );
         Ok(())
     }
 
@@ -145,6 +187,75 @@ impl SecurityAdminRepository for FakeSecurityAdminRepository {
         Ok(Vec::new())
     }
 
+    async fn create_team(
+        &self,
+        _tenant_id: TenantId,
+        created_by_subject: &str,
+        team: TeamDefinition,
+    ) -> AppResult<SecurityTeam> {
+        Ok(SecurityTeam {
+            team_id: "team-1".to_owned(),
+            name: team.name().as_str().to_owned(),
+            description: team.description().map(ToOwned::to_owned),
+            member_count: 0,
+            created_by_subject: created_by_subject.to_owned(),
+            created_at: "2026-01-01T00:00:00Z".to_owned(),
+        })
+    }
+
+    async fn list_teams(&self, _tenant_id: TenantId) -> AppResult<Vec<SecurityTeam>> {
+        Ok(Vec::new())
+    }
+
+    async fn add_team_member(
+        &self,
+        _tenant_id: TenantId,
+        added_by_subject: &str,
+        team_name: &str,
+        subject: &str,
+    ) -> AppResult<SecurityTeamMember> {
+        let member = SecurityTeamMember {
+            team_name: team_name.to_owned(),
+            subject: subject.to_owned(),
+            added_by_subject: added_by_subject.to_owned(),
+            added_at: "2026-01-01T00:00:00Z".to_owned(),
+        };
+        self.team_members.lock().await.push(member.clone());
+        Ok(member)
+    }
+
+    async fn remove_team_member(
+        &self,
+        _tenant_id: TenantId,
+        team_name: &str,
+        subject: &str,
+    ) -> AppResult<()> {
+        let mut members = self.team_members.lock().await;
+        let before = members.len();
+        members.retain(|member| !(member.team_name == team_name && member.subject == subject));
+        if members.len() == before {
+            return Err(AppError::NotFound(format!(
+                "subject '{subject}' is not a member of team '{team_name}'"
+            )));
+        }
+        Ok(())
+    }
+
+    async fn list_team_members(
+        &self,
+        _tenant_id: TenantId,
+        team_name: &str,
+    ) -> AppResult<Vec<SecurityTeamMember>> {
+        Ok(self
+            .team_members
+            .lock()
+            .await
+            .iter()
+            .filter(|member| member.team_name == team_name)
+            .cloned()
+            .collect())
+    }
+
     async fn create_temporary_access_grant(
         &self,
         _tenant_id: TenantId,
@@ -180,6 +291,88 @@ impl SecurityAdminRepository for FakeSecurityAdminRepository {
         Ok(Vec::new())
     }
 
+    async fn create_mfa_reset_request(
+        &self,
+        _tenant_id: TenantId,
+        requested_by_subject: &str,
+        input: CreateMfaResetRequestInput,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<MfaResetRequest> {
+        let mut requests = self.mfa_reset_requests.lock().await;
+        if requests
+            .iter()
+            .any(|request| request.subject == input.subject && request.approved_at.is_none())
+        {
+            return Err(AppError::Conflict(format!(
+                "an mfa reset for '{}' is already pending",
+                input.subject
+            )));
+        }
+
+        let request = MfaResetRequest {
+            request_id: format!("mfa-reset-{}", requests.len() + 1),
+            subject: input.subject,
+            reason: input.reason,
+            requested_by_subject: requested_by_subject.to_owned(),
+            requested_at: "2026-01-01T00:00:00Z".to_owned(),
+            expires_at: expires_at.to_rfc3339(),
+            approved_by_subject: None,
+            approved_at: None,
+        };
+        requests.push(request.clone());
+        Ok(request)
+    }
+
+    async fn find_mfa_reset_request(
+        &self,
+        _tenant_id: TenantId,
+        request_id: &str,
+    ) -> AppResult<Option<MfaResetRequest>> {
+        Ok(self
+            .mfa_reset_requests
+            .lock()
+            .await
+            .iter()
+            .find(|request| request.request_id == request_id)
+            .cloned())
+    }
+
+    async fn approve_mfa_reset_request(
+        &self,
+        _tenant_id: TenantId,
+        request_id: &str,
+        approved_by_subject: &str,
+    ) -> AppResult<MfaResetRequest> {
+        let mut requests = self.mfa_reset_requests.lock().await;
+        let request = requests
+            .iter_mut()
+            .find(|request| {
+                request.request_id == request_id
+                    && request.approved_at.is_none()
+                    && request.requested_by_subject != approved_by_subject
+                    && request.subject != approved_by_subject
+            })
+            .ok_or_else(|| AppError::Conflict("mfa reset request is not pending".to_owned()))?;
+        request.approved_by_subject = Some(approved_by_subject.to_owned());
+        request.approved_at = Some("2026-01-01T01:00:00Z".to_owned());
+        Ok(request.clone())
+    }
+
+    async fn list_mfa_reset_requests(
+        &self,
+        _tenant_id: TenantId,
+        pending_only: bool,
+    ) -> AppResult<Vec<MfaResetRequest>> {
+        Ok(self
+            .mfa_reset_requests
+            .lock()
+            .await
+            .iter()
+            .filter(|request| !pending_only || request.approved_at.is_none())
+            .cloned()
+            .collect())
+    }
+
     async fn registration_mode(&self, _tenant_id: TenantId) -> AppResult<RegistrationMode> {
         Ok(*self.registration_mode.lock().await)
     }
@@ -214,6 +407,27 @@ impl SecurityAdminRepository for FakeSecurityAdminRepository {
             retention_days: *stored_days,
         })
     }
+
+    async fn workflow_concurrency_policy(
+        &self,
+        _tenant_id: TenantId,
+    ) -> AppResult<WorkflowConcurrencyPolicy> {
+        Ok(WorkflowConcurrencyPolicy {
+            max_concurrent_jobs: *self.workflow_max_concurrent_jobs.lock().await,
+        })
+    }
+
+    async fn set_workflow_concurrency_policy(
+        &self,
+        _tenant_id: TenantId,
+        max_concurrent_jobs: Option<u32>,
+    ) -> AppResult<WorkflowConcurrencyPolicy> {
+        let mut stored_limit = self.workflow_max_concurrent_jobs.lock().await;
+        *stored_limit = max_concurrent_jobs;
+        Ok(WorkflowConcurrencyPolicy {
+            max_concurrent_jobs: *stored_limit,
+        })
+    }
 }
 
 struct FakeAuditLogRepository {
@@ -247,9 +461,30 @@ impl AuditLogRepository for FakeAuditLogRepository {
         Ok(0)
     }
 
-    async fn verify_integrity(&self, _tenant_id: TenantId) -> AppResult<AuditIntegrityStatus> {
+    async fn purge_entries_created_before(
+        &self,
+        _tenant_id: TenantId,
+        _cutoff: chrono::DateTime<chrono::Utc>,
+        _limit: usize,
+    ) -> AppResult<u64> {
+        Ok(0)
+    }
+
+    async fn verify_integrity(
+        &self,
+        _tenant_id: TenantId,
+        _scope: AuditChainVerificationScope,
+    ) -> AppResult<AuditIntegrityStatus> {
         Ok(self.integrity_status.clone())
     }
+
+    async fn list_chain_anchors(
+        &self,
+        _tenant_id: TenantId,
+        _limit: usize,
+    ) -> AppResult<Vec<AuditChainAnchor>> {
+        Ok(Vec::new())
+    }
 }
 
 #[derive(Default)]
@@ -291,6 +526,7 @@ fn service_with_permissions(
                 verified_entries: 0,
                 latest_chain_position: None,
                 latest_entry_hash: None,
+                verified_from_anchor: None,
                 failures: Vec::new(),
             },
         }),
@@ -339,6 +575,50 @@ async fn create_role_writes_audit_event() {
     assert_eq!(audit_repository.events.lock().await.len(), 1);
 }
 
+#[tokio::test]
+async fn create_role_accepts_custom_grant_sets_with_their_dependencies() {
+    let tenant_id = TenantId::new();
+    let actor = actor(tenant_id, "alice");
+    let (service, audit_repository) =
+        service_with_permissions(tenant_id, "alice", vec![Permission::SecurityRoleManage]);
+
+    let incomplete = service
+        .create_role(
+            &actor,
+            CreateRoleInput {
+                name: "editor".to_owned(),
+                permissions: vec![Permission::RuntimeRecordWrite],
+            },
+        )
+        .await;
+    assert!(matches!(incomplete, Err(AppError::Validation(_))));
+    assert!(audit_repository.events.lock().await.is_empty());
+
+    let role = service
+        .create_role(
+            &actor,
+            CreateRoleInput {
+                name: "editor".to_owned(),
+                permissions: vec![
+                    Permission::RuntimeRecordWrite,
+                    Permission::RuntimeRecordRead,
+                    Permission::RuntimeRecordWrite,
+                    Permission::SecurityAuditRead,
+                ],
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        role.permissions,
+        vec![
+            Permission::RuntimeRecordWrite,
+            Permission::RuntimeRecordRead,
+            Permission::SecurityAuditRead,
+        ]
+    );
+}
+
 #[tokio::test]
 async fn record_workspace_publish_run_writes_audit_event() {
     let tenant_id = TenantId::new();
@@ -405,7 +685,9 @@ async fn unassign_role_requires_manage_permission() {
     let actor = actor(tenant_id, "alice");
     let (service, _) = service_with_permissions(tenant_id, "alice", Vec::new());
 
-    let result = service.unassign_role(&actor, "bob", "ops").await;
+    let result = service
+        .unassign_role(&actor, SubjectType::User, "bob", "ops")
+        .await;
 
     assert!(matches!(result, Err(AppError::Forbidden(_))));
 }
@@ -448,6 +730,57 @@ async fn update_registration_mode_writes_audit_event() {
     );
 }
 
+#[tokio::test]
+async fn update_workflow_concurrency_policy_validates_and_writes_audit_event() {
+    let tenant_id = TenantId::new();
+    let actor = actor(tenant_id, "alice");
+    let (service, audit_repository) =
+        service_with_permissions(tenant_id, "alice", vec![Permission::SecurityRoleManage]);
+
+    let rejected = service
+        .update_workflow_concurrency_policy(&actor, Some(0))
+        .await;
+    assert!(matches!(rejected, Err(AppError::Validation(_))));
+
+    let capped = service
+        .update_workflow_concurrency_policy(&actor, Some(25))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(capped.max_concurrent_jobs, Some(25));
+    let stored = service
+        .workflow_concurrency_policy(&actor)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(stored, capped);
+
+    let uncapped = service
+        .update_workflow_concurrency_policy(&actor, None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(uncapped.max_concurrent_jobs, None);
+
+    let events = audit_repository.events.lock().await;
+    assert_eq!(events.len(), 2);
+    assert!(
+        events
+            .iter()
+            .all(|event| event.action == AuditAction::SecurityTenantWorkflowConcurrencyUpdated)
+    );
+}
+
+#[tokio::test]
+async fn workflow_concurrency_policy_requires_manage_permission() {
+    let tenant_id = TenantId::new();
+    let actor = actor(tenant_id, "alice");
+    let (service, _) = service_with_permissions(tenant_id, "alice", Vec::new());
+
+    let result = service
+        .update_workflow_concurrency_policy(&actor, Some(5))
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+}
+
 #[tokio::test]
 async fn purge_audit_log_entries_rejects_when_immutable_mode_enabled() {
     let tenant_id = TenantId::new();
@@ -476,3 +809,470 @@ async fn verify_audit_integrity_requires_audit_permission() {
 
     assert!(matches!(result, Err(AppError::Forbidden(_))));
 }
+
+#[tokio::test]
+async fn create_team_requires_manage_permission() {
+    let tenant_id = TenantId::new();
+    let actor = actor(tenant_id, "alice");
+    let (service, _) = service_with_permissions(tenant_id, "alice", Vec::new());
+
+    let team = TeamDefinition::new("Field Sales", None).unwrap_or_else(|_| unreachable!());
+    let result = service.create_team(&actor, team).await;
+
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn team_membership_changes_write_audit_events() {
+    let tenant_id = TenantId::new();
+    let actor = actor(tenant_id, "alice");
+    let (service, audit_repository) =
+        service_with_permissions(tenant_id, "alice", vec![Permission::SecurityRoleManage]);
+
+    let team = TeamDefinition::new("Field Sales", Some("EMEA reps".to_owned()))
+        .unwrap_or_else(|_| unreachable!());
+    let created = service
+        .create_team(&actor, team)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(created.name, "Field Sales");
+
+    let member = service
+        .add_team_member(&actor, "Field Sales", "bob")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(member.added_by_subject, "alice");
+
+    let members = service
+        .list_team_members(&actor, "Field Sales")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(members.len(), 1);
+
+    assert!(
+        service
+            .remove_team_member(&actor, "Field Sales", "bob")
+            .await
+            .is_ok()
+    );
+    assert!(matches!(
+        service
+            .remove_team_member(&actor, "Field Sales", "bob")
+            .await,
+        Err(AppError::NotFound(_))
+    ));
+
+    let actions = audit_repository
+        .events
+        .lock()
+        .await
+        .iter()
+        .map(|event| event.action)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actions,
+        vec![
+            qryvanta_domain::AuditAction::SecurityTeamCreated,
+            qryvanta_domain::AuditAction::SecurityTeamMemberAdded,
+            qryvanta_domain::AuditAction::SecurityTeamMemberRemoved,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn assign_role_to_team_records_team_subject_type() {
+    let tenant_id = TenantId::new();
+    let actor = actor(tenant_id, "alice");
+    let (service, audit_repository) =
+        service_with_permissions(tenant_id, "alice", vec![Permission::SecurityRoleManage]);
+
+    let result = service
+        .assign_role(&actor, SubjectType::Team, "Field Sales", "ops")
+        .await;
+
+    assert!(result.is_ok());
+    let events = audit_repository.events.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0].detail.as_deref(),
+        Some("assigned role 'ops' to team 'Field Sales'")
+    );
+}
+
+struct FakeUserRepository {
+    users: Mutex<Vec<UserRecord>>,
+    revoked_sessions: Mutex<Vec<UserId>>,
+}
+
+#[async_trait]
+impl UserRepository for FakeUserRepository {
+    async fn find_by_email(&self, email: &str) -> AppResult<Option<UserRecord>> {
+        Ok(self
+            .users
+            .lock()
+            .await
+            .iter()
+            .find(|user| user.email == email)
+            .cloned())
+    }
+
+    async fn find_by_id(&self, user_id: UserId) -> AppResult<Option<UserRecord>> {
+        Ok(self
+            .users
+            .lock()
+            .await
+            .iter()
+            .find(|user| user.id == user_id)
+            .cloned())
+    }
+
+    async fn create(
+        &self,
+        _email: &str,
+        _password_hash: Option<&str>,
+        _email_verified: bool,
+    ) -> AppResult<UserId> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_password(&self, _user_id: UserId, _password_hash: &str) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn revoke_sessions(&self, user_id: UserId) -> AppResult<()> {
+        self.revoked_sessions.lock().await.push(user_id);
+        Ok(())
+    }
+
+    async fn default_tenant_id(&self, _user_id: UserId) -> AppResult<Option<TenantId>> {
+        Ok(None)
+    }
+
+    async fn set_default_tenant_id(&self, _user_id: UserId, _tenant_id: TenantId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn record_failed_login(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn reset_failed_logins(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn mark_email_verified(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_display_name(
+        &self,
+        _user_id: UserId,
+        _tenant_id: TenantId,
+        _display_name: &str,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn update_email(&self, _user_id: UserId, _new_email: &str) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn enable_totp(
+        &self,
+        _user_id: UserId,
+        _totp_secret_enc: &[u8],
+        _recovery_codes_hash: &serde_json::Value,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn begin_totp_enrollment(
+        &self,
+        _user_id: UserId,
+        _totp_secret_enc: &[u8],
+        _recovery_codes_hash: &serde_json::Value,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn confirm_totp_enrollment(&self, _user_id: UserId) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn disable_totp(&self, user_id: UserId) -> AppResult<()> {
+        for user in self.users.lock().await.iter_mut() {
+            if user.id == user_id {
+                user.totp_enabled = false;
+                user.totp_secret_enc = None;
+                user.recovery_codes_hash = None;
+            }
+        }
+        Ok(())
+    }
+
+    async fn update_recovery_codes(
+        &self,
+        _user_id: UserId,
+        _recovery_codes_hash: &serde_json::Value,
+    ) -> AppResult<()> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn find_by_subject(&self, subject: &str) -> AppResult<Option<UserRecord>> {
+        Ok(self
+            .users
+            .lock()
+            .await
+            .iter()
+            .find(|user| user.id.to_string() == subject)
+            .cloned())
+    }
+}
+
+#[derive(Default)]
+struct FakeEmailService {
+    sent: Mutex<Vec<(String, String)>>,
+}
+
+#[async_trait]
+impl EmailService for FakeEmailService {
+    async fn send_email(
+        &self,
+        to: &str,
+        subject: &str,
+        _text_body: &str,
+        _html_body: Option<&str>,
+    ) -> AppResult<()> {
+        self.sent
+            .lock()
+            .await
+            .push((to.to_owned(), subject.to_owned()));
+        Ok(())
+    }
+
+    async fn send_email_with_attachments(
+        &self,
+        to: &str,
+        subject: &str,
+        text_body: &str,
+        _attachments: &[EmailAttachment],
+    ) -> AppResult<()> {
+        self.send_email(to, subject, text_body, None).await
+    }
+}
+
+struct MfaResetHarness {
+    service: SecurityAdminService,
+    audit_repository: Arc<FakeAuditRepository>,
+    users: Arc<FakeUserRepository>,
+    emails: Arc<FakeEmailService>,
+    locked_out_subject: String,
+}
+
+fn mfa_reset_harness(tenant_id: TenantId) -> MfaResetHarness {
+    let locked_out = UserRecord {
+        id: UserId::new(),
+        email: "locked@example.com".to_owned(),
+        email_verified: true,
+        password_hash: Some("hash".to_owned()),
+        totp_enabled: true,
+        passkeys_enrolled: false,
+        totp_secret_enc: Some(vec![1, 2, 3]),
+        recovery_codes_hash: Some(serde_json::json!([])),
+        totp_pending_secret_enc: None,
+        recovery_codes_pending_hash: None,
+        failed_login_count: 0,
+        locked_until: None,
+        password_changed_at: None,
+        auth_sessions_revoked_after: None,
+        default_tenant_id: None,
+    };
+    let locked_out_subject = locked_out.id.to_string();
+    let users = Arc::new(FakeUserRepository {
+        users: Mutex::new(vec![locked_out]),
+        revoked_sessions: Mutex::new(Vec::new()),
+    });
+    let emails = Arc::new(FakeEmailService::default());
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([
+                (
+                    (tenant_id, "alice".to_owned()),
+                    vec![Permission::SecurityRoleManage],
+                ),
+                (
+                    (tenant_id, "bob".to_owned()),
+                    vec![Permission::SecurityRoleManage],
+                ),
+            ]),
+        }),
+        audit_repository.clone(),
+    );
+    let service = SecurityAdminService::new(
+        authorization_service,
+        Arc::new(FakeSecurityAdminRepository::default()),
+        Arc::new(FakeAuditLogRepository {
+            entries: Vec::new(),
+            integrity_status: AuditIntegrityStatus {
+                is_valid: true,
+                verified_entries: 0,
+                latest_chain_position: None,
+                latest_entry_hash: None,
+                verified_from_anchor: None,
+                failures: Vec::new(),
+            },
+        }),
+        audit_repository.clone(),
+    )
+    .with_mfa_reset(users.clone(), emails.clone());
+
+    MfaResetHarness {
+        service,
+        audit_repository,
+        users,
+        emails,
+        locked_out_subject,
+    }
+}
+
+#[tokio::test]
+async fn mfa_reset_requires_second_administrator_approval() {
+    let tenant_id = TenantId::new();
+    let harness = mfa_reset_harness(tenant_id);
+    let alice = actor(tenant_id, "alice");
+
+    let request = harness
+        .service
+        .request_mfa_reset(
+            &alice,
+            CreateMfaResetRequestInput {
+                subject: harness.locked_out_subject.clone(),
+                reason: "lost authenticator device".to_owned(),
+            },
+        )
+        .await;
+    assert!(request.is_ok());
+    let request = request.unwrap_or_else(|_| unreachable!());
+
+    let self_approval = harness
+        .service
+        .approve_mfa_reset(&alice, request.request_id.as_str())
+        .await;
+    assert!(matches!(self_approval, Err(AppError::Forbidden(_))));
+    assert!(harness.users.users.lock().await[0].totp_enabled);
+}
+
+#[tokio::test]
+async fn approved_mfa_reset_disables_totp_emails_user_and_audits() {
+    let tenant_id = TenantId::new();
+    let harness = mfa_reset_harness(tenant_id);
+    let alice = actor(tenant_id, "alice");
+    let bob = actor(tenant_id, "bob");
+
+    let request = harness
+        .service
+        .request_mfa_reset(
+            &alice,
+            CreateMfaResetRequestInput {
+                subject: harness.locked_out_subject.clone(),
+                reason: "lost authenticator device".to_owned(),
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let approved = harness
+        .service
+        .approve_mfa_reset(&bob, request.request_id.as_str())
+        .await;
+    assert!(approved.is_ok());
+    let approved = approved.unwrap_or_else(|_| unreachable!());
+    assert_eq!(approved.approved_by_subject.as_deref(), Some("bob"));
+
+    {
+        let users = harness.users.users.lock().await;
+        assert!(!users[0].totp_enabled);
+        assert!(users[0].totp_secret_enc.is_none());
+        assert_eq!(
+            harness.users.revoked_sessions.lock().await.as_slice(),
+            &[users[0].id]
+        );
+    }
+
+    {
+        let sent = harness.emails.sent.lock().await;
+        assert_eq!(sent.len(), 2);
+        assert!(sent.iter().all(|(to, _)| to == "locked@example.com"));
+    }
+
+    let actions = harness
+        .audit_repository
+        .events
+        .lock()
+        .await
+        .iter()
+        .map(|event| event.action)
+        .collect::<Vec<_>>();
+    assert!(actions.contains(&AuditAction::SecurityMfaResetRequested));
+    assert!(actions.contains(&AuditAction::SecurityMfaResetApproved));
+
+    let repeat = harness
+        .service
+        .approve_mfa_reset(&bob, request.request_id.as_str())
+        .await;
+    assert!(matches!(repeat, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn mfa_reset_request_requires_enrolled_mfa_and_reason() {
+    let tenant_id = TenantId::new();
+    let harness = mfa_reset_harness(tenant_id);
+    let alice = actor(tenant_id, "alice");
+
+    let missing_reason = harness
+        .service
+        .request_mfa_reset(
+            &alice,
+            CreateMfaResetRequestInput {
+                subject: harness.locked_out_subject.clone(),
+                reason: "  ".to_owned(),
+            },
+        )
+        .await;
+    assert!(matches!(missing_reason, Err(AppError::Validation(_))));
+
+    harness.users.users.lock().await[0].totp_enabled = false;
+    let not_enrolled = harness
+        .service
+        .request_mfa_reset(
+            &alice,
+            CreateMfaResetRequestInput {
+                subject: harness.locked_out_subject.clone(),
+                reason: "lost authenticator device".to_owned(),
+            },
+        )
+        .await;
+    assert!(matches!(not_enrolled, Err(AppError::Validation(_))));
+    assert!(harness.emails.sent.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn mfa_reset_is_unavailable_without_account_dependencies() {
+    let tenant_id = TenantId::new();
+    let actor = actor(tenant_id, "alice");
+    let (service, _) =
+        service_with_permissions(tenant_id, "alice", vec![Permission::SecurityRoleManage]);
+
+    let result = service
+        .request_mfa_reset(
+            &actor,
+            CreateMfaResetRequestInput {
+                subject: "bob".to_owned(),
+                reason: "lost authenticator device".to_owned(),
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Internal(_))));
+}
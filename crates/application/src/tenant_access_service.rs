@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use qryvanta_core::{AppError, AppResult, TenantId};
+use qryvanta_domain::{AuditAction, Capability};
 
-use crate::{AuthorizationService, TenantRepository, UserRepository};
+use crate::{AuditEvent, AuditRepository, AuthorizationService, TenantRepository, UserRepository};
 
 /// One tenant option resolved for an authenticated subject.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -17,16 +18,39 @@ pub struct TenantSelection {
     pub email: Option<String>,
     /// Surfaces available to the subject in this tenant.
     pub accessible_surfaces: Vec<String>,
+    /// UI capabilities the subject holds in this tenant.
+    pub capabilities: Vec<Capability>,
     /// Whether this tenant is the persisted default.
     pub is_default: bool,
 }
 
+/// How an authenticated session moved into or out of a tenant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionTenantTransition {
+    /// The session was established by a login flow.
+    Login,
+    /// The session was re-scoped by an explicit tenant switch.
+    Switch,
+}
+
+impl SessionTenantTransition {
+    /// Returns a stable label used in audit details.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Login => "login",
+            Self::Switch => "tenant_switch",
+        }
+    }
+}
+
 /// Resolves the active tenant and available tenant memberships for a subject.
 #[derive(Clone)]
 pub struct TenantAccessService {
     tenant_repository: Arc<dyn TenantRepository>,
     user_repository: Arc<dyn UserRepository>,
     authorization_service: AuthorizationService,
+    audit_repository: Arc<dyn AuditRepository>,
 }
 
 impl TenantAccessService {
@@ -36,15 +60,18 @@ impl TenantAccessService {
         tenant_repository: Arc<dyn TenantRepository>,
         user_repository: Arc<dyn UserRepository>,
         authorization_service: AuthorizationService,
+        audit_repository: Arc<dyn AuditRepository>,
     ) -> Self {
         Self {
             tenant_repository,
             user_repository,
             authorization_service,
+            audit_repository,
         }
     }
 
-    /// Lists all tenant memberships for a subject with accessible surfaces.
+    /// Lists all tenant memberships for a subject with accessible surfaces and
+    /// capabilities.
     pub async fn list_subject_tenants(&self, subject: &str) -> AppResult<Vec<TenantSelection>> {
         let memberships = self
             .tenant_repository
@@ -58,6 +85,10 @@ impl TenantAccessService {
                 .authorization_service
                 .resolve_accessible_surfaces(membership.tenant_id, subject)
                 .await?;
+            let capabilities = self
+                .authorization_service
+                .resolve_capabilities(membership.tenant_id, subject)
+                .await?;
             selections.push(TenantSelection {
                 tenant_id: membership.tenant_id,
                 tenant_name: membership.tenant_name,
@@ -67,6 +98,7 @@ impl TenantAccessService {
                     .into_iter()
                     .map(|surface| surface.as_str().to_owned())
                     .collect(),
+                capabilities,
                 is_default: default_tenant_id == Some(membership.tenant_id),
             });
         }
@@ -122,6 +154,49 @@ impl TenantAccessService {
         })
     }
 
+    /// Records that a session became scoped to a tenant, and left the previous
+    /// tenant when it was re-scoped.
+    ///
+    /// Each entry is written to the audit log of the tenant it describes and
+    /// never names the other tenant, so tenant admins only see their own side
+    /// of a multi-tenant session.
+    pub async fn record_session_tenant_change(
+        &self,
+        subject: &str,
+        previous_tenant_id: Option<TenantId>,
+        tenant_id: TenantId,
+        transition: SessionTenantTransition,
+    ) -> AppResult<()> {
+        if let Some(previous_tenant_id) =
+            previous_tenant_id.filter(|previous| *previous != tenant_id)
+        {
+            self.audit_repository
+                .append_event(AuditEvent {
+                    tenant_id: previous_tenant_id,
+                    subject: subject.to_owned(),
+                    action: AuditAction::SecuritySessionTenantExited,
+                    resource_type: "tenant_session".to_owned(),
+                    resource_id: previous_tenant_id.to_string(),
+                    detail: Some(format!("session left tenant via {}", transition.as_str())),
+                })
+                .await?;
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id,
+                subject: subject.to_owned(),
+                action: AuditAction::SecuritySessionTenantEntered,
+                resource_type: "tenant_session".to_owned(),
+                resource_id: tenant_id.to_string(),
+                detail: Some(format!(
+                    "session entered tenant via {}",
+                    transition.as_str()
+                )),
+            })
+            .await
+    }
+
     async fn default_tenant_id_for_subject(&self, subject: &str) -> AppResult<Option<TenantId>> {
         let Some(user_id) = parse_subject_user_id(subject) else {
             return Ok(None);
@@ -5,12 +5,12 @@ use async_trait::async_trait;
 use tokio::sync::Mutex;
 
 use qryvanta_core::{AppResult, TenantId};
-use qryvanta_domain::{Permission, UserId};
+use qryvanta_domain::{AuditAction, Permission, UserId};
 
 use crate::{
     AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
-    TemporaryPermissionGrant, TenantAccessService, TenantMembership, TenantRepository, UserRecord,
-    UserRepository,
+    SessionTenantTransition, TemporaryPermissionGrant, TenantAccessService, TenantMembership,
+    TenantRepository, UserRecord, UserRepository,
 };
 
 #[derive(Default)]
@@ -223,14 +223,42 @@ impl AuthorizationRepository for FakeAuthorizationRepository {
     ) -> AppResult<Option<TemporaryPermissionGrant>> {
         Ok(None)
     }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
 }
 
 #[derive(Default)]
-struct NoopAuditRepository;
+struct RecordingAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
 
 #[async_trait]
-impl AuditRepository for NoopAuditRepository {
-    async fn append_event(&self, _event: AuditEvent) -> AppResult<()> {
+impl AuditRepository for RecordingAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
         Ok(())
     }
 }
@@ -240,6 +268,22 @@ fn service_with_memberships(
     memberships: Vec<TenantMembership>,
     defaults: HashMap<UserId, TenantId>,
     permissions: HashMap<(TenantId, String), Vec<Permission>>,
+) -> TenantAccessService {
+    service_with_audit(
+        subject,
+        memberships,
+        defaults,
+        permissions,
+        Arc::new(RecordingAuditRepository::default()),
+    )
+}
+
+fn service_with_audit(
+    subject: &str,
+    memberships: Vec<TenantMembership>,
+    defaults: HashMap<UserId, TenantId>,
+    permissions: HashMap<(TenantId, String), Vec<Permission>>,
+    audit_repository: Arc<RecordingAuditRepository>,
 ) -> TenantAccessService {
     let tenant_repository = Arc::new(FakeTenantRepository {
         memberships: Mutex::new(HashMap::from([(subject.to_owned(), memberships)])),
@@ -249,10 +293,15 @@ fn service_with_memberships(
     });
     let authorization_service = AuthorizationService::new(
         Arc::new(FakeAuthorizationRepository { permissions }),
-        Arc::new(NoopAuditRepository),
+        audit_repository.clone(),
     );
 
-    TenantAccessService::new(tenant_repository, user_repository, authorization_service)
+    TenantAccessService::new(
+        tenant_repository,
+        user_repository,
+        authorization_service,
+        audit_repository,
+    )
 }
 
 #[tokio::test]
@@ -348,3 +397,69 @@ async fn switch_active_tenant_rejects_non_membership() {
         .await;
     assert!(matches!(result, Err(qryvanta_core::AppError::Forbidden(_))));
 }
+
+#[tokio::test]
+async fn session_tenant_change_audits_each_tenant_separately() {
+    let subject = UserId::default().to_string();
+    let first_tenant = TenantId::new();
+    let second_tenant = TenantId::new();
+    let audit_repository = Arc::new(RecordingAuditRepository::default());
+    let service = service_with_audit(
+        subject.as_str(),
+        Vec::new(),
+        HashMap::new(),
+        HashMap::new(),
+        audit_repository.clone(),
+    );
+
+    service
+        .record_session_tenant_change(
+            subject.as_str(),
+            None,
+            first_tenant,
+            SessionTenantTransition::Login,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    service
+        .record_session_tenant_change(
+            subject.as_str(),
+            Some(first_tenant),
+            second_tenant,
+            SessionTenantTransition::Switch,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let events = audit_repository.events.lock().await;
+    let summary: Vec<(TenantId, AuditAction)> = events
+        .iter()
+        .map(|event| (event.tenant_id, event.action))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (first_tenant, AuditAction::SecuritySessionTenantEntered),
+            (first_tenant, AuditAction::SecuritySessionTenantExited),
+            (second_tenant, AuditAction::SecuritySessionTenantEntered),
+        ]
+    );
+    for event in events.iter() {
+        let other_tenant = if event.tenant_id == first_tenant {
+            second_tenant
+        } else {
+            first_tenant
+        };
+        assert!(
+            event
+                .detail
+                .as_deref()
+                .is_some_and(|detail| !detail.contains(other_tenant.to_string().as_str()))
+        );
+        assert!(
+            !event
+                .resource_id
+                .contains(other_tenant.to_string().as_str())
+        );
+    }
+}
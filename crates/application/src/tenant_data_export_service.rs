@@ -0,0 +1,20 @@
+//! Tenant-wide data exports for portability and GDPR requests.
+//!
+//! A role manager queues a background job that collects the tenant's
+//! metadata, members, runtime records and audit log into stored archive
+//! parts. Once the job completes, the archive is fetched through an expiring
+//! download link whose token is only shown once, so it can be handed to
+//! tools that hold no session. Parts are keyed by position, so a resumed run
+//! overwrites what an interrupted run already stored.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    CreatedTenantDataExportLink, NewTenantDataExportLink, TenantDataExportLink,
+    TenantDataExportPart, TenantDataExportRepository, TenantDataExportSection, TenantMemberEntry,
+};
+pub use service::{TENANT_DATA_EXPORT_STAGES, TenantDataArchive, TenantDataExportService};
@@ -0,0 +1,168 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::AuditLogEntry;
+
+/// Section of a tenant data export archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TenantDataExportSection {
+    /// Entity, field, form, view and publish definitions.
+    Metadata,
+    /// Tenant members and their account links.
+    Users,
+    /// Runtime records of every published entity.
+    RuntimeRecords,
+    /// Tenant audit log, oldest first.
+    AuditLog,
+}
+
+impl TenantDataExportSection {
+    /// Returns a stable storage value for this section.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Metadata => "metadata",
+            Self::Users => "users",
+            Self::RuntimeRecords => "runtime_records",
+            Self::AuditLog => "audit_log",
+        }
+    }
+
+    /// Parses a stored section value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "metadata" => Ok(Self::Metadata),
+            "users" => Ok(Self::Users),
+            "runtime_records" => Ok(Self::RuntimeRecords),
+            "audit_log" => Ok(Self::AuditLog),
+            _ => Err(AppError::Validation(format!(
+                "unknown tenant data export section '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Stored fragment of a tenant data export archive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TenantDataExportPart {
+    /// Archive section the items belong to.
+    pub section: TenantDataExportSection,
+    /// Entity of runtime record parts; `None` for other sections.
+    pub entity_logical_name: Option<String>,
+    /// Position of the first item within its section or entity.
+    pub item_offset: u64,
+    /// Exported items.
+    pub items: Vec<Value>,
+}
+
+/// Tenant member included in an export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TenantMemberEntry {
+    /// Member subject.
+    pub subject: String,
+    /// Member display name.
+    pub display_name: String,
+    /// Member email, if known.
+    pub email: Option<String>,
+    /// Linked local account, if any.
+    pub user_id: Option<String>,
+    /// Membership timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Input payload for storing a download link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewTenantDataExportLink {
+    /// Completed export job the link serves.
+    pub job_id: String,
+    /// SHA-256 hash of the link token.
+    pub token_hash: String,
+    /// Subject that issued the link.
+    pub created_by_subject: String,
+    /// Completion time of the export, written into the archive.
+    pub generated_at: DateTime<Utc>,
+    /// Time after which the link stops working.
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Stored download link of a tenant data export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TenantDataExportLink {
+    /// Stable link identifier.
+    pub link_id: String,
+    /// Completed export job the link serves.
+    pub job_id: String,
+    /// Subject that issued the link.
+    pub created_by_subject: String,
+    /// Completion time of the export.
+    pub generated_at: DateTime<Utc>,
+    /// Time after which the link stops working.
+    pub expires_at: DateTime<Utc>,
+    /// Issue timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Newly issued download link with its one-time visible token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedTenantDataExportLink {
+    /// Stored link.
+    pub link: TenantDataExportLink,
+    /// Raw token; only its hash is stored.
+    pub token: String,
+}
+
+/// Repository port for tenant data export archives.
+#[async_trait]
+pub trait TenantDataExportRepository: Send + Sync {
+    /// Stores an archive part, replacing any part at the same position.
+    async fn save_export_part(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        part: &TenantDataExportPart,
+    ) -> AppResult<()>;
+
+    /// Lists the parts of an archive ordered by section, entity and offset.
+    async fn list_export_parts(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Vec<TenantDataExportPart>>;
+
+    /// Deletes the parts and links of every archive except `keep_job_id`
+    /// and returns the deleted part count.
+    async fn delete_other_exports(&self, tenant_id: TenantId, keep_job_id: &str) -> AppResult<u64>;
+
+    /// Lists tenant members ordered by membership time.
+    async fn list_tenant_members(
+        &self,
+        tenant_id: TenantId,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<TenantMemberEntry>>;
+
+    /// Lists audit entries ordered by chain position.
+    async fn list_audit_entries(
+        &self,
+        tenant_id: TenantId,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<AuditLogEntry>>;
+
+    /// Stores a download link.
+    async fn create_download_link(
+        &self,
+        tenant_id: TenantId,
+        link: NewTenantDataExportLink,
+    ) -> AppResult<TenantDataExportLink>;
+
+    /// Finds a download link by token hash.
+    async fn find_download_link(
+        &self,
+        tenant_id: TenantId,
+        token_hash: &str,
+    ) -> AppResult<Option<TenantDataExportLink>>;
+}
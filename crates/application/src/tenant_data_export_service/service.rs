@@ -0,0 +1,403 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value, json};
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, RuntimeRecord};
+
+use crate::auth_token_service::token_crypto::{generate_token, hash_token};
+use crate::{
+    AuditEvent, AuditRepository, BackgroundJob, BackgroundJobKind, BackgroundJobLease,
+    BackgroundJobService, BackgroundJobStage, BackgroundJobStatus, CreateBackgroundJobInput,
+    WorkspacePortableBundle,
+};
+
+use super::ports::{
+    CreatedTenantDataExportLink, NewTenantDataExportLink, TenantDataExportPart,
+    TenantDataExportRepository, TenantDataExportSection,
+};
+
+/// Stages of a tenant data export job, in run order.
+pub const TENANT_DATA_EXPORT_STAGES: [TenantDataExportSection; 4] = [
+    TenantDataExportSection::Metadata,
+    TenantDataExportSection::Users,
+    TenantDataExportSection::RuntimeRecords,
+    TenantDataExportSection::AuditLog,
+];
+
+/// Completed archives can be downloaded for this many days.
+const TENANT_DATA_ARCHIVE_RETENTION_DAYS: i64 = 7;
+
+/// Download links stop working after this many hours, or when the archive expires.
+const TENANT_DATA_EXPORT_LINK_HOURS: i64 = 24;
+
+/// Format marker written into every archive.
+const TENANT_DATA_ARCHIVE_FORMAT: &str = "qryvanta.tenant_data_export.v1";
+
+/// Assembled tenant data archive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TenantDataArchive {
+    /// Job that generated the archive.
+    pub job_id: String,
+    /// Suggested download file name.
+    pub file_name: String,
+    /// Archive document.
+    pub document: Value,
+}
+
+/// Application service for tenant-wide data exports.
+#[derive(Clone)]
+pub struct TenantDataExportService {
+    background_job_service: BackgroundJobService,
+    repository: Arc<dyn TenantDataExportRepository>,
+    audit_repository: Arc<dyn AuditRepository>,
+}
+
+impl TenantDataExportService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        background_job_service: BackgroundJobService,
+        repository: Arc<dyn TenantDataExportRepository>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            background_job_service,
+            repository,
+            audit_repository,
+        }
+    }
+
+    /// Queues an export of the actor's tenant.
+    ///
+    /// Runtime records are collected from the listed entities. Earlier
+    /// archives of the tenant and their links are deleted; an export that is
+    /// still running must finish or be cancelled first.
+    pub async fn request_export(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_names: Vec<String>,
+    ) -> AppResult<BackgroundJob> {
+        let running_export = self
+            .background_job_service
+            .list_jobs(actor, 200)
+            .await?
+            .into_iter()
+            .any(|job| {
+                job.kind == BackgroundJobKind::TenantDataExport && !job.status.is_terminal()
+            });
+        if running_export {
+            return Err(AppError::Conflict(
+                "a tenant data export is already in progress".to_owned(),
+            ));
+        }
+
+        let job = self
+            .background_job_service
+            .enqueue_job(
+                actor,
+                CreateBackgroundJobInput {
+                    kind: BackgroundJobKind::TenantDataExport,
+                    parameters: json!({ "entity_logical_names": entity_logical_names }),
+                    stages: TENANT_DATA_EXPORT_STAGES
+                        .iter()
+                        .map(|section| {
+                            let total = match section {
+                                TenantDataExportSection::Metadata => Some(1),
+                                TenantDataExportSection::RuntimeRecords => {
+                                    Some(entity_logical_names.len() as u64)
+                                }
+                                TenantDataExportSection::Users
+                                | TenantDataExportSection::AuditLog => None,
+                            };
+                            BackgroundJobStage::new(section.as_str(), total)
+                        })
+                        .collect(),
+                },
+            )
+            .await?;
+
+        self.repository
+            .delete_other_exports(actor.tenant_id(), job.job_id.as_str())
+            .await?;
+
+        Ok(job)
+    }
+
+    /// Builds the metadata part of an export from a metadata-only workspace bundle.
+    ///
+    /// The bundle is stored unchanged, so it can be imported into another
+    /// workspace as is.
+    pub fn metadata_part(bundle: &WorkspacePortableBundle) -> AppResult<TenantDataExportPart> {
+        let bundle = serde_json::to_value(bundle).map_err(|error| {
+            AppError::Internal(format!("failed to encode workspace bundle: {error}"))
+        })?;
+
+        Ok(TenantDataExportPart {
+            section: TenantDataExportSection::Metadata,
+            entity_logical_name: None,
+            item_offset: 0,
+            items: vec![bundle],
+        })
+    }
+
+    /// Builds one page of the users part of an export.
+    ///
+    /// Credentials and MFA secrets are never part of a membership and are
+    /// therefore never exported.
+    pub async fn users_part(
+        &self,
+        actor: &UserIdentity,
+        offset: usize,
+        limit: usize,
+    ) -> AppResult<TenantDataExportPart> {
+        let members = self
+            .repository
+            .list_tenant_members(actor.tenant_id(), limit, offset)
+            .await?;
+
+        Ok(TenantDataExportPart {
+            section: TenantDataExportSection::Users,
+            entity_logical_name: None,
+            item_offset: offset as u64,
+            items: members
+                .into_iter()
+                .map(|member| {
+                    json!({
+                        "subject": member.subject,
+                        "display_name": member.display_name,
+                        "email": member.email,
+                        "user_id": member.user_id,
+                        "member_since": member.created_at.to_rfc3339(),
+                    })
+                })
+                .collect(),
+        })
+    }
+
+    /// Builds one page of the runtime records part of an export.
+    #[must_use]
+    pub fn runtime_records_part(
+        entity_logical_name: &str,
+        offset: usize,
+        records: &[RuntimeRecord],
+    ) -> TenantDataExportPart {
+        TenantDataExportPart {
+            section: TenantDataExportSection::RuntimeRecords,
+            entity_logical_name: Some(entity_logical_name.to_owned()),
+            item_offset: offset as u64,
+            items: records
+                .iter()
+                .map(|record| {
+                    json!({
+                        "record_id": record.record_id().as_str(),
+                        "data": record.data(),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Builds one page of the audit log part of an export.
+    pub async fn audit_log_part(
+        &self,
+        actor: &UserIdentity,
+        offset: usize,
+        limit: usize,
+    ) -> AppResult<TenantDataExportPart> {
+        let entries = self
+            .repository
+            .list_audit_entries(actor.tenant_id(), limit, offset)
+            .await?;
+
+        Ok(TenantDataExportPart {
+            section: TenantDataExportSection::AuditLog,
+            entity_logical_name: None,
+            item_offset: offset as u64,
+            items: entries
+                .into_iter()
+                .map(|entry| {
+                    json!({
+                        "event_id": entry.event_id,
+                        "subject": entry.subject,
+                        "action": entry.action,
+                        "resource_type": entry.resource_type,
+                        "resource_id": entry.resource_id,
+                        "detail": entry.detail,
+                        "created_at": entry.created_at,
+                        "chain_position": entry.chain_position,
+                        "previous_entry_hash": entry.previous_entry_hash,
+                        "entry_hash": entry.entry_hash,
+                    })
+                })
+                .collect(),
+        })
+    }
+
+    /// Stores a part produced by the run holding the lease.
+    pub async fn save_part(
+        &self,
+        lease: &BackgroundJobLease,
+        part: &TenantDataExportPart,
+    ) -> AppResult<()> {
+        self.repository
+            .save_export_part(lease.tenant_id, lease.job.job_id.as_str(), part)
+            .await
+    }
+
+    /// Issues an expiring download link for a completed export.
+    ///
+    /// The link outlives neither the archive nor a day, whichever ends first.
+    pub async fn create_download_link(
+        &self,
+        actor: &UserIdentity,
+        job_id: &str,
+    ) -> AppResult<CreatedTenantDataExportLink> {
+        let job = self.background_job_service.get_job(actor, job_id).await?;
+        if job.kind != BackgroundJobKind::TenantDataExport {
+            return Err(AppError::NotFound(format!(
+                "tenant data export '{job_id}' does not exist"
+            )));
+        }
+        if job.status != BackgroundJobStatus::Completed {
+            return Err(AppError::Conflict(format!(
+                "tenant data export '{job_id}' is {}",
+                job.status.as_str()
+            )));
+        }
+
+        let now = Utc::now();
+        let generated_at = job.finished_at.unwrap_or(job.updated_at);
+        let archive_expires_at =
+            generated_at + chrono::Duration::days(TENANT_DATA_ARCHIVE_RETENTION_DAYS);
+        if archive_expires_at <= now {
+            return Err(AppError::NotFound(format!(
+                "tenant data export '{job_id}' has expired"
+            )));
+        }
+
+        let (token, token_hash) = generate_token()?;
+        let link = self
+            .repository
+            .create_download_link(
+                actor.tenant_id(),
+                NewTenantDataExportLink {
+                    job_id: job.job_id.clone(),
+                    token_hash,
+                    created_by_subject: actor.subject().to_owned(),
+                    generated_at,
+                    expires_at: archive_expires_at
+                        .min(now + chrono::Duration::hours(TENANT_DATA_EXPORT_LINK_HOURS)),
+                },
+            )
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::TenantDataExportLinkCreated,
+                resource_type: "tenant_data_export".to_owned(),
+                resource_id: job.job_id,
+                detail: Some(format!(
+                    "download link expires at {}",
+                    link.expires_at.to_rfc3339()
+                )),
+            })
+            .await?;
+
+        Ok(CreatedTenantDataExportLink { link, token })
+    }
+
+    /// Assembles the archive behind a download link for an unauthenticated caller.
+    pub async fn download_archive(
+        &self,
+        tenant_id: TenantId,
+        token: &str,
+    ) -> AppResult<TenantDataArchive> {
+        let link = self
+            .repository
+            .find_download_link(tenant_id, hash_token(token).as_str())
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound("tenant data export link does not exist".to_owned())
+            })?;
+        if link.expires_at <= Utc::now() {
+            return Err(AppError::NotFound(
+                "tenant data export link has expired".to_owned(),
+            ));
+        }
+
+        let parts = self
+            .repository
+            .list_export_parts(tenant_id, link.job_id.as_str())
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id,
+                subject: link.created_by_subject.clone(),
+                action: AuditAction::TenantDataExportDownloaded,
+                resource_type: "tenant_data_export".to_owned(),
+                resource_id: link.job_id.clone(),
+                detail: Some(format!("downloaded through link {}", link.link_id)),
+            })
+            .await?;
+
+        Ok(TenantDataArchive {
+            job_id: link.job_id.clone(),
+            file_name: format!("tenant-data-{}.json", link.generated_at.format("%Y%m%d")),
+            document: Self::assemble_archive(
+                tenant_id,
+                link.job_id.as_str(),
+                link.generated_at,
+                parts,
+            ),
+        })
+    }
+
+    fn assemble_archive(
+        tenant_id: TenantId,
+        job_id: &str,
+        generated_at: DateTime<Utc>,
+        parts: Vec<TenantDataExportPart>,
+    ) -> Value {
+        let mut metadata = Value::Null;
+        let mut users = Vec::new();
+        let mut runtime_records = Map::new();
+        let mut audit_log = Vec::new();
+
+        for part in parts {
+            match part.section {
+                TenantDataExportSection::Metadata => {
+                    if let Some(item) = part.items.into_iter().next() {
+                        metadata = item;
+                    }
+                }
+                TenantDataExportSection::Users => users.extend(part.items),
+                TenantDataExportSection::RuntimeRecords => {
+                    let entity_logical_name = part.entity_logical_name.unwrap_or_default();
+                    if let Value::Array(records) = runtime_records
+                        .entry(entity_logical_name)
+                        .or_insert_with(|| Value::Array(Vec::new()))
+                    {
+                        records.extend(part.items);
+                    }
+                }
+                TenantDataExportSection::AuditLog => audit_log.extend(part.items),
+            }
+        }
+
+        json!({
+            "format": TENANT_DATA_ARCHIVE_FORMAT,
+            "job_id": job_id,
+            "tenant_id": tenant_id.to_string(),
+            "generated_at": generated_at.to_rfc3339(),
+            "metadata": metadata,
+            "users": users,
+            "runtime_records": runtime_records,
+            "audit_log": audit_log,
+        })
+    }
+}
@@ -0,0 +1,580 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission, RuntimeRecord};
+
+use crate::{
+    AuditEvent, AuditLogEntry, AuditRepository, AuthorizationRepository, AuthorizationService,
+    BackgroundJob, BackgroundJobLease, BackgroundJobRepository, BackgroundJobService,
+    BackgroundJobStage, BackgroundJobStatus, CreateBackgroundJobInput, RuntimeFieldGrant,
+    TemporaryPermissionGrant,
+};
+
+use super::{
+    NewTenantDataExportLink, TenantDataExportLink, TenantDataExportPart,
+    TenantDataExportRepository, TenantDataExportSection, TenantDataExportService,
+    TenantMemberEntry,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeBackgroundJobRepository {
+    jobs: Mutex<Vec<(TenantId, BackgroundJob)>>,
+}
+
+#[async_trait]
+impl BackgroundJobRepository for FakeBackgroundJobRepository {
+    async fn create_background_job(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        requested_by_display_name: &str,
+        requested_by_email: Option<&str>,
+        input: CreateBackgroundJobInput,
+    ) -> AppResult<BackgroundJob> {
+        let now = Utc::now();
+        let job = BackgroundJob {
+            job_id: uuid::Uuid::new_v4().to_string(),
+            kind: input.kind,
+            status: BackgroundJobStatus::Queued,
+            requested_by_subject: requested_by_subject.to_owned(),
+            requested_by_display_name: requested_by_display_name.to_owned(),
+            requested_by_email: requested_by_email.map(str::to_owned),
+            parameters: input.parameters,
+            stages: input.stages,
+            checkpoint: None,
+            cancel_requested: false,
+            attempt_count: 0,
+            status_message: None,
+            created_at: now,
+            started_at: None,
+            updated_at: now,
+            finished_at: None,
+        };
+        self.jobs
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .push((tenant_id, job.clone()));
+        Ok(job)
+    }
+
+    async fn find_background_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Option<BackgroundJob>> {
+        Ok(self
+            .jobs
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .find(|(job_tenant_id, job)| *job_tenant_id == tenant_id && job.job_id == job_id)
+            .map(|(_, job)| job.clone()))
+    }
+
+    async fn list_background_jobs(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: Option<&str>,
+        limit: usize,
+    ) -> AppResult<Vec<BackgroundJob>> {
+        Ok(self
+            .jobs
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .rev()
+            .filter(|(job_tenant_id, job)| {
+                *job_tenant_id == tenant_id
+                    && requested_by_subject
+                        .is_none_or(|subject| job.requested_by_subject == subject)
+            })
+            .take(limit)
+            .map(|(_, job)| job.clone())
+            .collect())
+    }
+
+    async fn request_background_job_cancellation(
+        &self,
+        _tenant_id: TenantId,
+        _job_id: &str,
+    ) -> AppResult<Option<BackgroundJob>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn claim_background_job(
+        &self,
+        lease_token: &str,
+        lease_expires_at: DateTime<Utc>,
+    ) -> AppResult<Option<BackgroundJobLease>> {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|_| unreachable!());
+        let Some((tenant_id, job)) = jobs
+            .iter_mut()
+            .find(|(_, job)| job.status == BackgroundJobStatus::Queued)
+        else {
+            return Ok(None);
+        };
+
+        job.status = BackgroundJobStatus::Running;
+        job.attempt_count += 1;
+        Ok(Some(BackgroundJobLease {
+            tenant_id: *tenant_id,
+            job: job.clone(),
+            lease_token: lease_token.to_owned(),
+            expires_at: lease_expires_at,
+        }))
+    }
+
+    async fn save_background_job_progress(
+        &self,
+        _tenant_id: TenantId,
+        _job_id: &str,
+        _lease_token: &str,
+        _stages: &[BackgroundJobStage],
+        _checkpoint: Option<&Value>,
+        _lease_expires_at: DateTime<Utc>,
+    ) -> AppResult<Option<BackgroundJob>> {
+        Err(AppError::Internal("unused in test".to_owned()))
+    }
+
+    async fn finish_background_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        _lease_token: &str,
+        status: BackgroundJobStatus,
+        status_message: Option<&str>,
+    ) -> AppResult<Option<BackgroundJob>> {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|_| unreachable!());
+        let Some((_, job)) = jobs
+            .iter_mut()
+            .find(|(job_tenant_id, job)| *job_tenant_id == tenant_id && job.job_id == job_id)
+        else {
+            return Ok(None);
+        };
+
+        job.status = status;
+        job.status_message = status_message.map(str::to_owned);
+        job.finished_at = Some(Utc::now());
+        Ok(Some(job.clone()))
+    }
+}
+
+#[derive(Default)]
+struct FakeTenantDataExportRepository {
+    parts: Mutex<Vec<(TenantId, String, TenantDataExportPart)>>,
+    links: Mutex<Vec<(TenantId, String, TenantDataExportLink)>>,
+}
+
+#[async_trait]
+impl TenantDataExportRepository for FakeTenantDataExportRepository {
+    async fn save_export_part(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        part: &TenantDataExportPart,
+    ) -> AppResult<()> {
+        let mut parts = self.parts.lock().unwrap_or_else(|_| unreachable!());
+        parts.retain(|(_, stored_job_id, stored)| {
+            !(stored_job_id == job_id
+                && stored.section == part.section
+                && stored.entity_logical_name == part.entity_logical_name
+                && stored.item_offset == part.item_offset)
+        });
+        parts.push((tenant_id, job_id.to_owned(), part.clone()));
+        Ok(())
+    }
+
+    async fn list_export_parts(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Vec<TenantDataExportPart>> {
+        Ok(self
+            .parts
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .filter(|(part_tenant_id, part_job_id, _)| {
+                *part_tenant_id == tenant_id && part_job_id == job_id
+            })
+            .map(|(_, _, part)| part.clone())
+            .collect())
+    }
+
+    async fn delete_other_exports(&self, tenant_id: TenantId, keep_job_id: &str) -> AppResult<u64> {
+        self.links
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .retain(|(link_tenant_id, _, link)| {
+                *link_tenant_id != tenant_id || link.job_id == keep_job_id
+            });
+        let mut parts = self.parts.lock().unwrap_or_else(|_| unreachable!());
+        let count = parts.len();
+        parts.retain(|(part_tenant_id, part_job_id, _)| {
+            *part_tenant_id != tenant_id || part_job_id == keep_job_id
+        });
+        Ok((count - parts.len()) as u64)
+    }
+
+    async fn list_tenant_members(
+        &self,
+        _tenant_id: TenantId,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<TenantMemberEntry>> {
+        Ok(["admin", "bob"]
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|subject| TenantMemberEntry {
+                subject: subject.to_owned(),
+                display_name: subject.to_uppercase(),
+                email: Some(format!("{subject}@example.com")),
+                user_id: None,
+                created_at: Utc::now(),
+            })
+            .collect())
+    }
+
+    async fn list_audit_entries(
+        &self,
+        _tenant_id: TenantId,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<AuditLogEntry>> {
+        Ok((1..=3)
+            .skip(offset)
+            .take(limit)
+            .map(|position| AuditLogEntry {
+                event_id: format!("event-{position}"),
+                subject: "admin".to_owned(),
+                action: "runtime.record.created".to_owned(),
+                resource_type: "runtime_record".to_owned(),
+                resource_id: format!("record-{position}"),
+                detail: None,
+                created_at: Utc::now().to_rfc3339(),
+                chain_position: position,
+                previous_entry_hash: None,
+                entry_hash: format!("hash-{position}"),
+            })
+            .collect())
+    }
+
+    async fn create_download_link(
+        &self,
+        tenant_id: TenantId,
+        link: NewTenantDataExportLink,
+    ) -> AppResult<TenantDataExportLink> {
+        let stored = TenantDataExportLink {
+            link_id: uuid::Uuid::new_v4().to_string(),
+            job_id: link.job_id,
+            created_by_subject: link.created_by_subject,
+            generated_at: link.generated_at,
+            expires_at: link.expires_at,
+            created_at: Utc::now(),
+        };
+        self.links.lock().unwrap_or_else(|_| unreachable!()).push((
+            tenant_id,
+            link.token_hash,
+            stored.clone(),
+        ));
+        Ok(stored)
+    }
+
+    async fn find_download_link(
+        &self,
+        tenant_id: TenantId,
+        token_hash: &str,
+    ) -> AppResult<Option<TenantDataExportLink>> {
+        Ok(self
+            .links
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .iter()
+            .find(|(link_tenant_id, link_token_hash, _)| {
+                *link_tenant_id == tenant_id && link_token_hash == token_hash
+            })
+            .map(|(_, _, link)| link.clone()))
+    }
+}
+
+struct Harness {
+    tenant_id: TenantId,
+    admin: UserIdentity,
+    background_job_service: BackgroundJobService,
+    repository: Arc<FakeTenantDataExportRepository>,
+    audit_repository: Arc<FakeAuditRepository>,
+    service: TenantDataExportService,
+}
+
+fn build_harness() -> Harness {
+    let tenant_id = TenantId::new();
+    let admin = UserIdentity::new("admin", "Admin", None, tenant_id);
+    let grants = HashMap::from([(
+        (tenant_id, "admin".to_owned()),
+        vec![Permission::SecurityRoleManage],
+    )]);
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let background_job_service = BackgroundJobService::new(
+        AuthorizationService::new(
+            Arc::new(FakeAuthorizationRepository { grants }),
+            audit_repository.clone(),
+        ),
+        Arc::new(FakeBackgroundJobRepository::default()),
+        audit_repository.clone(),
+    );
+    let repository = Arc::new(FakeTenantDataExportRepository::default());
+    let service = TenantDataExportService::new(
+        background_job_service.clone(),
+        repository.clone(),
+        audit_repository.clone(),
+    );
+
+    Harness {
+        tenant_id,
+        admin,
+        background_job_service,
+        repository,
+        audit_repository,
+        service,
+    }
+}
+
+async fn run_export(harness: &Harness) -> BackgroundJob {
+    let lease = harness
+        .background_job_service
+        .claim_next_job()
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    let actor = &harness.admin;
+
+    let users = harness
+        .service
+        .users_part(actor, 0, 10)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let audit_log = harness
+        .service
+        .audit_log_part(actor, 0, 10)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let record = RuntimeRecord::new("record-1", "contact", json!({ "name": "Alice" }))
+        .unwrap_or_else(|_| unreachable!());
+    let records = TenantDataExportService::runtime_records_part("contact", 0, &[record]);
+    for part in [&users, &audit_log, &records, &records] {
+        harness
+            .service
+            .save_part(&lease, part)
+            .await
+            .unwrap_or_else(|_| unreachable!());
+    }
+
+    harness
+        .background_job_service
+        .finish_job(&lease, BackgroundJobStatus::Completed, None)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!())
+}
+
+#[tokio::test]
+async fn archives_are_downloaded_through_expiring_links() {
+    let harness = build_harness();
+    let member = UserIdentity::new("bob", "Bob", None, harness.tenant_id);
+    let forbidden = harness
+        .service
+        .request_export(&member, vec!["contact".to_owned()])
+        .await;
+    assert!(matches!(forbidden, Err(AppError::Forbidden(_))));
+
+    let job = harness
+        .service
+        .request_export(&harness.admin, vec!["contact".to_owned()])
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(job.stages.len(), 4);
+
+    let early = harness
+        .service
+        .create_download_link(&harness.admin, &job.job_id)
+        .await;
+    assert!(matches!(early, Err(AppError::Conflict(_))));
+
+    run_export(&harness).await;
+    let created = harness
+        .service
+        .create_download_link(&harness.admin, &job.job_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(created.link.expires_at <= Utc::now() + chrono::Duration::hours(24));
+    let by_member = harness
+        .service
+        .create_download_link(&member, &job.job_id)
+        .await;
+    assert!(matches!(by_member, Err(AppError::NotFound(_))));
+
+    let archive = harness
+        .service
+        .download_archive(harness.tenant_id, created.token.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let document = archive.document;
+    assert_eq!(document["tenant_id"], json!(harness.tenant_id.to_string()));
+    assert_eq!(document["users"].as_array().map(Vec::len), Some(2));
+    assert_eq!(document["audit_log"][2]["chain_position"], json!(3));
+    assert_eq!(
+        document["runtime_records"]["contact"],
+        json!([{ "record_id": "record-1", "data": { "name": "Alice" } }])
+    );
+
+    let other_tenant = harness
+        .service
+        .download_archive(TenantId::new(), created.token.as_str())
+        .await;
+    assert!(matches!(other_tenant, Err(AppError::NotFound(_))));
+    let wrong_token = harness
+        .service
+        .download_archive(harness.tenant_id, "not-a-token")
+        .await;
+    assert!(matches!(wrong_token, Err(AppError::NotFound(_))));
+
+    let actions = harness
+        .audit_repository
+        .events
+        .lock()
+        .unwrap_or_else(|_| unreachable!())
+        .iter()
+        .map(|event| event.action)
+        .collect::<Vec<_>>();
+    assert!(actions.contains(&AuditAction::TenantDataExportLinkCreated));
+    assert!(actions.contains(&AuditAction::TenantDataExportDownloaded));
+}
+
+#[tokio::test]
+async fn new_requests_wait_for_running_exports_and_replace_old_archives() {
+    let harness = build_harness();
+    let first = harness
+        .service
+        .request_export(&harness.admin, Vec::new())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let duplicate = harness
+        .service
+        .request_export(&harness.admin, Vec::new())
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+
+    run_export(&harness).await;
+    let created = harness
+        .service
+        .create_download_link(&harness.admin, &first.job_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    harness
+        .service
+        .request_export(&harness.admin, Vec::new())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(
+        harness
+            .repository
+            .parts
+            .lock()
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+    let stale = harness
+        .service
+        .download_archive(harness.tenant_id, created.token.as_str())
+        .await;
+    assert!(matches!(stale, Err(AppError::NotFound(_))));
+    assert_eq!(
+        TenantDataExportSection::parse("runtime_records").unwrap_or_else(|_| unreachable!()),
+        TenantDataExportSection::RuntimeRecords
+    );
+}
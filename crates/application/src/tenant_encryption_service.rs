@@ -0,0 +1,18 @@
+//! Tenant-supplied encryption keys ("bring your own key").
+//!
+//! Enterprise tenants register a secret-provider reference for their own key
+//! material. The key is validated with a round-trip check at onboarding,
+//! rotated per tenant with older versions kept for decryption, and can be
+//! crypto-shredded when the tenant is offboarded.
+
+mod ports;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+pub use ports::{
+    TenantEncryptionKey, TenantEncryptionKeyRepository, TenantEncryptionKeyStatus,
+    TenantKeyMaterial, TenantKeyMaterialProvider,
+};
+pub use service::TenantEncryptionService;
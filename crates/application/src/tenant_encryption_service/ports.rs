@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::SecretEncryptor;
+
+/// Lifecycle state of one tenant-supplied encryption key version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TenantEncryptionKeyStatus {
+    /// Key encrypts new data and decrypts existing data.
+    Active,
+    /// Key only decrypts data written before the last rotation.
+    Retired,
+    /// Key reference was destroyed and data written with it is unreadable.
+    Shredded,
+}
+
+impl TenantEncryptionKeyStatus {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Retired => "retired",
+            Self::Shredded => "shredded",
+        }
+    }
+
+    /// Parses a storage value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "active" => Ok(Self::Active),
+            "retired" => Ok(Self::Retired),
+            "shredded" => Ok(Self::Shredded),
+            _ => Err(AppError::Internal(format!(
+                "unknown tenant encryption key status '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Stored metadata for one tenant encryption key version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TenantEncryptionKey {
+    /// Owning tenant.
+    pub tenant_id: TenantId,
+    /// Monotonic key version, starting at one.
+    pub version: u32,
+    /// Secret-provider reference for the key material, cleared once shredded.
+    pub secret_reference: Option<String>,
+    /// Stable fingerprint of the key material for rotation checks.
+    pub key_fingerprint: String,
+    /// Current lifecycle state.
+    pub status: TenantEncryptionKeyStatus,
+    /// Subject that registered this key version.
+    pub created_by_subject: String,
+    /// Registration timestamp.
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when a rotation retired this version.
+    pub retired_at: Option<DateTime<Utc>>,
+    /// Timestamp when this version was crypto-shredded.
+    pub shredded_at: Option<DateTime<Utc>>,
+}
+
+/// Resolved key material for one secret reference.
+#[derive(Clone)]
+pub struct TenantKeyMaterial {
+    /// Encryptor keyed with the resolved material.
+    pub encryptor: Arc<dyn SecretEncryptor>,
+    /// Stable fingerprint of the resolved material.
+    pub key_fingerprint: String,
+}
+
+/// Repository port for tenant encryption key metadata.
+#[async_trait]
+pub trait TenantEncryptionKeyRepository: Send + Sync {
+    /// Lists every key version for a tenant, newest first.
+    async fn list_keys(&self, tenant_id: TenantId) -> AppResult<Vec<TenantEncryptionKey>>;
+
+    /// Finds one key version for a tenant.
+    async fn find_key(
+        &self,
+        tenant_id: TenantId,
+        version: u32,
+    ) -> AppResult<Option<TenantEncryptionKey>>;
+
+    /// Stores a new active key version and retires the previous active one.
+    async fn insert_active_key(
+        &self,
+        tenant_id: TenantId,
+        secret_reference: &str,
+        key_fingerprint: &str,
+        created_by_subject: &str,
+    ) -> AppResult<TenantEncryptionKey>;
+
+    /// Marks every key version shredded and clears stored secret references.
+    async fn shred_keys(&self, tenant_id: TenantId) -> AppResult<u64>;
+}
+
+/// Port resolving secret-provider references into usable key material.
+#[async_trait]
+pub trait TenantKeyMaterialProvider: Send + Sync {
+    /// Resolves and validates key material for one secret reference.
+    async fn load_key_material(&self, secret_reference: &str) -> AppResult<TenantKeyMaterial>;
+}
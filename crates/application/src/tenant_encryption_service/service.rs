@@ -0,0 +1,393 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity, validate_secret_reference};
+use qryvanta_domain::{AuditAction, Permission};
+use uuid::Uuid;
+
+use crate::{AuditEvent, AuditRepository, AuthorizationService, SecretEncryptor};
+
+use super::ports::{
+    TenantEncryptionKey, TenantEncryptionKeyRepository, TenantEncryptionKeyStatus,
+    TenantKeyMaterial, TenantKeyMaterialProvider,
+};
+
+/// Magic prefix marking ciphertext written with a tenant-supplied key.
+const ENVELOPE_MAGIC: &[u8; 4] = b"QTK1";
+/// Envelope header: magic, tenant UUID, and big-endian key version.
+const ENVELOPE_HEADER_LEN: usize = 4 + 16 + 4;
+/// Probe payload used to prove new key material can round-trip data.
+const KEY_CHECK_PROBE: &[u8] = b"qryvanta-tenant-key-check";
+
+type ResolvedKeyCache = HashMap<(TenantId, u32), Arc<dyn SecretEncryptor>>;
+
+/// Application service for tenant-supplied ("bring your own key") encryption.
+///
+/// Tenants without a registered key keep using the platform encryptor.
+/// Ciphertext written with a tenant key carries a small envelope naming the
+/// tenant and key version, so retired versions stay readable after rotation
+/// and shredded versions fail closed.
+#[derive(Clone)]
+pub struct TenantEncryptionService {
+    authorization_service: AuthorizationService,
+    repository: Arc<dyn TenantEncryptionKeyRepository>,
+    key_material_provider: Arc<dyn TenantKeyMaterialProvider>,
+    platform_encryptor: Arc<dyn SecretEncryptor>,
+    audit_repository: Arc<dyn AuditRepository>,
+    resolved_keys: Arc<RwLock<ResolvedKeyCache>>,
+}
+
+impl TenantEncryptionService {
+    /// Creates a new service from required dependencies.
+    #[must_use]
+    pub fn new(
+        authorization_service: AuthorizationService,
+        repository: Arc<dyn TenantEncryptionKeyRepository>,
+        key_material_provider: Arc<dyn TenantKeyMaterialProvider>,
+        platform_encryptor: Arc<dyn SecretEncryptor>,
+        audit_repository: Arc<dyn AuditRepository>,
+    ) -> Self {
+        Self {
+            authorization_service,
+            repository,
+            key_material_provider,
+            platform_encryptor,
+            audit_repository,
+            resolved_keys: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Lists key versions registered for the actor tenant.
+    pub async fn list_keys(&self, actor: &UserIdentity) -> AppResult<Vec<TenantEncryptionKey>> {
+        self.require_role_manage_permission(actor).await?;
+        self.repository.list_keys(actor.tenant_id()).await
+    }
+
+    /// Registers the first tenant-supplied key after validating it round-trips data.
+    pub async fn register_key(
+        &self,
+        actor: &UserIdentity,
+        secret_reference: &str,
+    ) -> AppResult<TenantEncryptionKey> {
+        self.require_role_manage_permission(actor).await?;
+
+        let keys = self.repository.list_keys(actor.tenant_id()).await?;
+        if keys
+            .iter()
+            .any(|key| key.status == TenantEncryptionKeyStatus::Shredded)
+        {
+            return Err(AppError::Conflict(
+                "tenant encryption keys were shredded and cannot be re-registered".to_owned(),
+            ));
+        }
+        if keys
+            .iter()
+            .any(|key| key.status == TenantEncryptionKeyStatus::Active)
+        {
+            return Err(AppError::Conflict(
+                "tenant encryption key is already registered; rotate it instead".to_owned(),
+            ));
+        }
+
+        let secret_reference = secret_reference.trim();
+        let material = self.load_validated_key_material(secret_reference).await?;
+        let key = self
+            .repository
+            .insert_active_key(
+                actor.tenant_id(),
+                secret_reference,
+                material.key_fingerprint.as_str(),
+                actor.subject(),
+            )
+            .await?;
+        self.cache_encryptor(key.tenant_id, key.version, material.encryptor)?;
+
+        self.append_key_audit_event(
+            actor,
+            AuditAction::SecurityTenantEncryptionKeyRegistered,
+            serde_json::json!({
+                "version": key.version,
+                "key_fingerprint": key.key_fingerprint,
+            }),
+        )
+        .await?;
+
+        Ok(key)
+    }
+
+    /// Rotates to a new tenant-supplied key, keeping prior versions for decryption.
+    pub async fn rotate_key(
+        &self,
+        actor: &UserIdentity,
+        secret_reference: &str,
+    ) -> AppResult<TenantEncryptionKey> {
+        self.require_role_manage_permission(actor).await?;
+
+        let keys = self.repository.list_keys(actor.tenant_id()).await?;
+        let previous_key = keys
+            .iter()
+            .find(|key| key.status == TenantEncryptionKeyStatus::Active)
+            .cloned()
+            .ok_or_else(|| {
+                AppError::NotFound("tenant encryption key is not registered".to_owned())
+            })?;
+
+        let secret_reference = secret_reference.trim();
+        let material = self.load_validated_key_material(secret_reference).await?;
+        if keys
+            .iter()
+            .any(|key| key.key_fingerprint == material.key_fingerprint)
+        {
+            return Err(AppError::Validation(
+                "rotated tenant encryption key must differ from previous key versions".to_owned(),
+            ));
+        }
+
+        let key = self
+            .repository
+            .insert_active_key(
+                actor.tenant_id(),
+                secret_reference,
+                material.key_fingerprint.as_str(),
+                actor.subject(),
+            )
+            .await?;
+        self.cache_encryptor(key.tenant_id, key.version, material.encryptor)?;
+
+        self.append_key_audit_event(
+            actor,
+            AuditAction::SecurityTenantEncryptionKeyRotated,
+            serde_json::json!({
+                "previous_version": previous_key.version,
+                "version": key.version,
+                "key_fingerprint": key.key_fingerprint,
+            }),
+        )
+        .await?;
+
+        Ok(key)
+    }
+
+    /// Crypto-shreds every tenant key version for offboarding.
+    ///
+    /// The caller must echo the tenant id as confirmation. Data encrypted
+    /// with the shredded versions becomes permanently unreadable.
+    pub async fn shred_keys(
+        &self,
+        actor: &UserIdentity,
+        confirm_tenant_id: &str,
+    ) -> AppResult<u64> {
+        self.require_role_manage_permission(actor).await?;
+
+        if confirm_tenant_id.trim() != actor.tenant_id().to_string() {
+            return Err(AppError::Validation(
+                "confirmation must match the tenant id being shredded".to_owned(),
+            ));
+        }
+
+        let shredded_count = self.repository.shred_keys(actor.tenant_id()).await?;
+        if shredded_count == 0 {
+            return Err(AppError::NotFound(
+                "tenant has no encryption keys to shred".to_owned(),
+            ));
+        }
+
+        self.resolved_keys
+            .write()
+            .map_err(|_| AppError::Internal("tenant key cache lock poisoned".to_owned()))?
+            .retain(|(tenant_id, _), _| *tenant_id != actor.tenant_id());
+
+        self.append_key_audit_event(
+            actor,
+            AuditAction::SecurityTenantEncryptionKeysShredded,
+            serde_json::json!({ "shredded_versions": shredded_count }),
+        )
+        .await?;
+
+        Ok(shredded_count)
+    }
+
+    /// Encrypts data for one tenant.
+    ///
+    /// Uses the tenant's active key when registered and the platform
+    /// encryptor otherwise. Tenants whose keys were shredded cannot store new
+    /// encrypted data.
+    pub async fn encrypt_for_tenant(
+        &self,
+        tenant_id: TenantId,
+        plaintext: &[u8],
+    ) -> AppResult<Vec<u8>> {
+        let keys = self.repository.list_keys(tenant_id).await?;
+        let Some(active_key) = keys
+            .iter()
+            .find(|key| key.status == TenantEncryptionKeyStatus::Active)
+        else {
+            if keys
+                .iter()
+                .any(|key| key.status == TenantEncryptionKeyStatus::Shredded)
+            {
+                return Err(AppError::Forbidden(
+                    "tenant encryption keys were shredded".to_owned(),
+                ));
+            }
+
+            return self.platform_encryptor.encrypt(plaintext);
+        };
+
+        let encryptor = self.resolve_encryptor(active_key).await?;
+        let ciphertext = encryptor.encrypt(plaintext)?;
+
+        let mut envelope = Vec::with_capacity(ENVELOPE_HEADER_LEN + ciphertext.len());
+        envelope.extend_from_slice(ENVELOPE_MAGIC);
+        envelope.extend_from_slice(tenant_id.as_uuid().as_bytes());
+        envelope.extend_from_slice(&active_key.version.to_be_bytes());
+        envelope.extend_from_slice(&ciphertext);
+        Ok(envelope)
+    }
+
+    /// Decrypts data written by [`Self::encrypt_for_tenant`] or the platform encryptor.
+    pub async fn decrypt(&self, ciphertext: &[u8]) -> AppResult<Vec<u8>> {
+        let Some((tenant_id, version, inner_ciphertext)) = parse_envelope(ciphertext) else {
+            return self.platform_encryptor.decrypt(ciphertext);
+        };
+
+        // Key status is always read from storage so a shred on any API
+        // instance takes effect everywhere; only resolved material is cached.
+        let key = self
+            .repository
+            .find_key(tenant_id, version)
+            .await?
+            .ok_or_else(|| {
+                AppError::Internal(format!(
+                    "tenant encryption key version {version} is missing"
+                ))
+            })?;
+
+        self.resolve_encryptor(&key)
+            .await?
+            .decrypt(inner_ciphertext)
+    }
+
+    async fn require_role_manage_permission(&self, actor: &UserIdentity) -> AppResult<()> {
+        self.authorization_service
+            .require_permission(
+                actor.tenant_id(),
+                actor.subject(),
+                Permission::SecurityRoleManage,
+            )
+            .await
+    }
+
+    async fn load_validated_key_material(
+        &self,
+        secret_reference: &str,
+    ) -> AppResult<TenantKeyMaterial> {
+        validate_secret_reference(secret_reference)?;
+
+        let material = self
+            .key_material_provider
+            .load_key_material(secret_reference)
+            .await?;
+
+        let probe = material.encryptor.encrypt(KEY_CHECK_PROBE)?;
+        if material.encryptor.decrypt(&probe)? != KEY_CHECK_PROBE {
+            return Err(AppError::Validation(
+                "tenant encryption key failed the round-trip check".to_owned(),
+            ));
+        }
+
+        Ok(material)
+    }
+
+    async fn resolve_encryptor(
+        &self,
+        key: &TenantEncryptionKey,
+    ) -> AppResult<Arc<dyn SecretEncryptor>> {
+        let secret_reference = match (key.status, key.secret_reference.as_deref()) {
+            (TenantEncryptionKeyStatus::Shredded, _) | (_, None) => {
+                return Err(AppError::Forbidden(format!(
+                    "tenant encryption key version {} was shredded",
+                    key.version
+                )));
+            }
+            (_, Some(secret_reference)) => secret_reference,
+        };
+
+        if let Some(encryptor) = self.cached_encryptor(key.tenant_id, key.version)? {
+            return Ok(encryptor);
+        }
+
+        let material = self
+            .key_material_provider
+            .load_key_material(secret_reference)
+            .await?;
+        if material.key_fingerprint != key.key_fingerprint {
+            return Err(AppError::Internal(format!(
+                "tenant encryption key version {} no longer matches its registered fingerprint",
+                key.version
+            )));
+        }
+
+        self.cache_encryptor(key.tenant_id, key.version, material.encryptor.clone())?;
+        Ok(material.encryptor)
+    }
+
+    fn cached_encryptor(
+        &self,
+        tenant_id: TenantId,
+        version: u32,
+    ) -> AppResult<Option<Arc<dyn SecretEncryptor>>> {
+        Ok(self
+            .resolved_keys
+            .read()
+            .map_err(|_| AppError::Internal("tenant key cache lock poisoned".to_owned()))?
+            .get(&(tenant_id, version))
+            .cloned())
+    }
+
+    fn cache_encryptor(
+        &self,
+        tenant_id: TenantId,
+        version: u32,
+        encryptor: Arc<dyn SecretEncryptor>,
+    ) -> AppResult<()> {
+        self.resolved_keys
+            .write()
+            .map_err(|_| AppError::Internal("tenant key cache lock poisoned".to_owned()))?
+            .insert((tenant_id, version), encryptor);
+        Ok(())
+    }
+
+    async fn append_key_audit_event(
+        &self,
+        actor: &UserIdentity,
+        action: AuditAction,
+        detail: serde_json::Value,
+    ) -> AppResult<()> {
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action,
+                resource_type: "tenant_encryption_key".to_owned(),
+                resource_id: actor.tenant_id().to_string(),
+                detail: Some(detail.to_string()),
+            })
+            .await
+    }
+}
+
+fn parse_envelope(ciphertext: &[u8]) -> Option<(TenantId, u32, &[u8])> {
+    if ciphertext.len() <= ENVELOPE_HEADER_LEN || !ciphertext.starts_with(ENVELOPE_MAGIC) {
+        return None;
+    }
+
+    let tenant_bytes: [u8; 16] = ciphertext[4..20].try_into().ok()?;
+    let version_bytes: [u8; 4] = ciphertext[20..ENVELOPE_HEADER_LEN].try_into().ok()?;
+
+    Some((
+        TenantId::from_uuid(Uuid::from_bytes(tenant_bytes)),
+        u32::from_be_bytes(version_bytes),
+        &ciphertext[ENVELOPE_HEADER_LEN..],
+    ))
+}
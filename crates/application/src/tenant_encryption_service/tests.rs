@@ -0,0 +1,396 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::Mutex;
+
+use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
+use qryvanta_domain::{AuditAction, Permission};
+
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    SecretEncryptor, TemporaryPermissionGrant,
+};
+
+use super::{
+    TenantEncryptionKey, TenantEncryptionKeyRepository, TenantEncryptionKeyStatus,
+    TenantEncryptionService, TenantKeyMaterial, TenantKeyMaterialProvider,
+};
+
+struct FakeAuthorizationRepository {
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+}
+
+#[async_trait]
+impl AuthorizationRepository for FakeAuthorizationRepository {
+    async fn list_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<Permission>> {
+        Ok(self
+            .grants
+            .get(&(tenant_id, subject.to_owned()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_runtime_field_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<RuntimeFieldGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn find_active_temporary_permission_grant(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _permission: Permission,
+    ) -> AppResult<Option<TemporaryPermissionGrant>> {
+        Ok(None)
+    }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
+}
+
+#[derive(Default)]
+struct FakeAuditRepository {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepository for FakeAuditRepository {
+    async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
+        self.events.lock().await.push(event);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct FakeKeyRepository {
+    keys: Mutex<Vec<TenantEncryptionKey>>,
+}
+
+#[async_trait]
+impl TenantEncryptionKeyRepository for FakeKeyRepository {
+    async fn list_keys(&self, tenant_id: TenantId) -> AppResult<Vec<TenantEncryptionKey>> {
+        let mut keys: Vec<TenantEncryptionKey> = self
+            .keys
+            .lock()
+            .await
+            .iter()
+            .filter(|key| key.tenant_id == tenant_id)
+            .cloned()
+            .collect();
+        keys.sort_by(|left, right| right.version.cmp(&left.version));
+        Ok(keys)
+    }
+
+    async fn find_key(
+        &self,
+        tenant_id: TenantId,
+        version: u32,
+    ) -> AppResult<Option<TenantEncryptionKey>> {
+        Ok(self
+            .keys
+            .lock()
+            .await
+            .iter()
+            .find(|key| key.tenant_id == tenant_id && key.version == version)
+            .cloned())
+    }
+
+    async fn insert_active_key(
+        &self,
+        tenant_id: TenantId,
+        secret_reference: &str,
+        key_fingerprint: &str,
+        created_by_subject: &str,
+    ) -> AppResult<TenantEncryptionKey> {
+        let mut keys = self.keys.lock().await;
+        let mut next_version = 1;
+        for key in keys.iter_mut().filter(|key| key.tenant_id == tenant_id) {
+            next_version = next_version.max(key.version + 1);
+            if key.status == TenantEncryptionKeyStatus::Active {
+                key.status = TenantEncryptionKeyStatus::Retired;
+                key.retired_at = Some(Utc::now());
+            }
+        }
+
+        let key = TenantEncryptionKey {
+            tenant_id,
+            version: next_version,
+            secret_reference: Some(secret_reference.to_owned()),
+            key_fingerprint: key_fingerprint.to_owned(),
+            status: TenantEncryptionKeyStatus::Active,
+            created_by_subject: created_by_subject.to_owned(),
+            created_at: Utc::now(),
+            retired_at: None,
+            shredded_at: None,
+        };
+        keys.push(key.clone());
+        Ok(key)
+    }
+
+    async fn shred_keys(&self, tenant_id: TenantId) -> AppResult<u64> {
+        let mut shredded_count = 0;
+        for key in self
+            .keys
+            .lock()
+            .await
+            .iter_mut()
+            .filter(|key| key.tenant_id == tenant_id)
+        {
+            key.status = TenantEncryptionKeyStatus::Shredded;
+            key.secret_reference = None;
+            key.shredded_at = Some(Utc::now());
+            shredded_count += 1;
+        }
+        Ok(shredded_count)
+    }
+}
+
+struct XorEncryptor {
+    key: u8,
+}
+
+impl SecretEncryptor for XorEncryptor {
+    fn encrypt(&self, plaintext: &[u8]) -> AppResult<Vec<u8>> {
+        Ok(plaintext.iter().map(|byte| byte ^ self.key).collect())
+    }
+
+    fn decrypt(&self, ciphertext: &[u8]) -> AppResult<Vec<u8>> {
+        Ok(ciphertext.iter().map(|byte| byte ^ self.key).collect())
+    }
+}
+
+struct FakeKeyMaterialProvider;
+
+#[async_trait]
+impl TenantKeyMaterialProvider for FakeKeyMaterialProvider {
+    async fn load_key_material(&self, secret_reference: &str) -> AppResult<TenantKeyMaterial> {
+        let key = match secret_reference {
+            "aws-sm://tenant-key-a" => 0x11,
+            "aws-sm://tenant-key-b" => 0x22,
+            _ => {
+                return Err(AppError::Validation(format!(
+                    "unknown secret reference '{secret_reference}'"
+                )));
+            }
+        };
+
+        Ok(TenantKeyMaterial {
+            encryptor: Arc::new(XorEncryptor { key }),
+            key_fingerprint: format!("fingerprint-{key}"),
+        })
+    }
+}
+
+fn actor(tenant_id: TenantId, subject: &str) -> UserIdentity {
+    UserIdentity::new(subject, subject, None, tenant_id)
+}
+
+fn build_service(
+    tenant_id: TenantId,
+    subject: &str,
+    permissions: Vec<Permission>,
+    repository: Arc<FakeKeyRepository>,
+) -> (TenantEncryptionService, Arc<FakeAuditRepository>) {
+    let audit_repository = Arc::new(FakeAuditRepository::default());
+    let authorization_service = AuthorizationService::new(
+        Arc::new(FakeAuthorizationRepository {
+            grants: HashMap::from([((tenant_id, subject.to_owned()), permissions)]),
+        }),
+        audit_repository.clone(),
+    );
+    let service = TenantEncryptionService::new(
+        authorization_service,
+        repository,
+        Arc::new(FakeKeyMaterialProvider),
+        Arc::new(XorEncryptor { key: 0x55 }),
+        audit_repository.clone(),
+    );
+    (service, audit_repository)
+}
+
+#[tokio::test]
+async fn register_key_requires_manage_permission() {
+    let tenant_id = TenantId::new();
+    let (service, _) = build_service(
+        tenant_id,
+        "alice",
+        Vec::new(),
+        Arc::new(FakeKeyRepository::default()),
+    );
+
+    let result = service
+        .register_key(&actor(tenant_id, "alice"), "aws-sm://tenant-key-a")
+        .await;
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn register_key_rejects_unresolvable_references() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeKeyRepository::default());
+    let (service, _) = build_service(
+        tenant_id,
+        "alice",
+        vec![Permission::SecurityRoleManage],
+        repository.clone(),
+    );
+
+    let invalid_format = service
+        .register_key(&actor(tenant_id, "alice"), "plain-text-key")
+        .await;
+    assert!(matches!(invalid_format, Err(AppError::Validation(_))));
+
+    let unresolvable = service
+        .register_key(&actor(tenant_id, "alice"), "aws-sm://missing")
+        .await;
+    assert!(matches!(unresolvable, Err(AppError::Validation(_))));
+    assert!(repository.keys.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn rotated_keys_keep_older_ciphertext_readable() {
+    let tenant_id = TenantId::new();
+    let repository = Arc::new(FakeKeyRepository::default());
+    let (service, audit_repository) = build_service(
+        tenant_id,
+        "alice",
+        vec![Permission::SecurityRoleManage],
+        repository.clone(),
+    );
+    let actor = actor(tenant_id, "alice");
+
+    let first_key = service
+        .register_key(&actor, "aws-sm://tenant-key-a")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(first_key.version, 1);
+    let first_ciphertext = service
+        .encrypt_for_tenant(tenant_id, b"totp-secret")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let duplicate = service.register_key(&actor, "aws-sm://tenant-key-b").await;
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+
+    let reused = service.rotate_key(&actor, "aws-sm://tenant-key-a").await;
+    assert!(matches!(reused, Err(AppError::Validation(_))));
+
+    let second_key = service
+        .rotate_key(&actor, "aws-sm://tenant-key-b")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(second_key.version, 2);
+    let second_ciphertext = service
+        .encrypt_for_tenant(tenant_id, b"totp-secret")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_ne!(first_ciphertext, second_ciphertext);
+
+    let (cold_service, _) = build_service(tenant_id, "alice", Vec::new(), repository);
+    for ciphertext in [&first_ciphertext, &second_ciphertext] {
+        let plaintext = cold_service
+            .decrypt(ciphertext)
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(plaintext, b"totp-secret");
+    }
+
+    let events = audit_repository.events.lock().await;
+    let actions: Vec<AuditAction> = events.iter().map(|event| event.action).collect();
+    assert_eq!(
+        actions,
+        vec![
+            AuditAction::SecurityTenantEncryptionKeyRegistered,
+            AuditAction::SecurityTenantEncryptionKeyRotated,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn shredded_keys_make_tenant_ciphertext_unreadable() {
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    let repository = Arc::new(FakeKeyRepository::default());
+    let (service, audit_repository) = build_service(
+        tenant_id,
+        "alice",
+        vec![Permission::SecurityRoleManage],
+        repository,
+    );
+    let actor = actor(tenant_id, "alice");
+
+    service
+        .register_key(&actor, "aws-sm://tenant-key-a")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let tenant_ciphertext = service
+        .encrypt_for_tenant(tenant_id, b"totp-secret")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let platform_ciphertext = service
+        .encrypt_for_tenant(other_tenant_id, b"totp-secret")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let unconfirmed = service
+        .shred_keys(&actor, &other_tenant_id.to_string())
+        .await;
+    assert!(matches!(unconfirmed, Err(AppError::Validation(_))));
+
+    let shredded_count = service
+        .shred_keys(&actor, &tenant_id.to_string())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(shredded_count, 1);
+
+    let unreadable = service.decrypt(&tenant_ciphertext).await;
+    assert!(matches!(unreadable, Err(AppError::Forbidden(_))));
+    let new_write = service.encrypt_for_tenant(tenant_id, b"totp-secret").await;
+    assert!(matches!(new_write, Err(AppError::Forbidden(_))));
+    let re_register = service.register_key(&actor, "aws-sm://tenant-key-b").await;
+    assert!(matches!(re_register, Err(AppError::Conflict(_))));
+
+    let other_plaintext = service
+        .decrypt(&platform_ciphertext)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(other_plaintext, b"totp-secret");
+
+    let events = audit_repository.events.lock().await;
+    assert_eq!(
+        events.last().map(|event| event.action),
+        Some(AuditAction::SecurityTenantEncryptionKeysShredded)
+    );
+}
@@ -26,6 +26,8 @@ pub struct UserRecord {
     pub password_hash: Option<String>,
     /// Whether TOTP MFA is enabled.
     pub totp_enabled: bool,
+    /// Whether at least one passkey is registered for the user.
+    pub passkeys_enrolled: bool,
     /// Encrypted TOTP secret, if enrolled.
     pub totp_secret_enc: Option<Vec<u8>>,
     /// Hashed recovery codes as JSON array, if enrolled.
@@ -145,10 +147,14 @@ pub trait PasswordHasher: Send + Sync {
 pub enum AuthOutcome {
     /// Authentication succeeded. Session can be established.
     Authenticated(Box<UserRecord>),
-    /// Password was correct but TOTP verification is required.
+    /// Password was correct but a second factor is required.
     MfaRequired {
         /// The user ID awaiting MFA.
         user_id: UserId,
+        /// Whether the TOTP and recovery code factors can satisfy the challenge.
+        totp_enabled: bool,
+        /// Whether a registered passkey can satisfy the challenge.
+        passkeys_enrolled: bool,
     },
     /// Authentication failed. Generic message prevents enumeration.
     Failed,
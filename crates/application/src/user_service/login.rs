@@ -72,8 +72,8 @@ impl UserService {
         // Password correct -- reset failed login counter.
         self.user_repository.reset_failed_logins(user.id).await?;
 
-        // Check if MFA is required.
-        if user.totp_enabled {
+        // Check if MFA is required. Registered passkeys count as a second factor.
+        if user.totp_enabled || user.passkeys_enrolled {
             self.auth_event_service
                 .record_event(AuthEvent {
                     subject: Some(user.id.to_string()),
@@ -84,7 +84,11 @@ impl UserService {
                 })
                 .await?;
 
-            return Ok(AuthOutcome::MfaRequired { user_id: user.id });
+            return Ok(AuthOutcome::MfaRequired {
+                user_id: user.id,
+                totp_enabled: user.totp_enabled,
+                passkeys_enrolled: user.passkeys_enrolled,
+            });
         }
 
         self.auth_event_service
@@ -1,26 +1,51 @@
 mod action_dispatcher;
+mod bulk_execution;
 mod cache;
+mod claim_advice;
+mod credentials;
 mod delay;
 mod execution;
+mod inbound_webhook;
 mod lease;
+mod recovery;
 mod repository;
 mod runtime_events;
 mod runtime_records;
 mod schedule;
+mod trace_context;
 
 pub use action_dispatcher::{
-    WorkflowActionDispatchRequest, WorkflowActionDispatchType, WorkflowActionDispatcher,
+    WorkflowActionCredential, WorkflowActionDispatchRequest, WorkflowActionDispatchResponse,
+    WorkflowActionDispatchType, WorkflowActionDispatcher,
+};
+pub use bulk_execution::{
+    NewWorkflowBulkExecution, StartWorkflowBulkExecutionInput, WORKFLOW_BULK_EXECUTION_MAX_RECORDS,
+    WorkflowBulkExecution, WorkflowBulkExecutionReport, WorkflowBulkExecutionRunCounts,
 };
 pub use cache::WorkflowQueueStatsCache;
+pub use claim_advice::{
+    WorkflowClaimAdvice, WorkflowClaimBackpressurePolicy, WorkflowClaimLoadSignal,
+};
+pub use credentials::{WorkflowCredentialRecord, WorkflowCredentialRepository};
 pub use delay::WorkflowDelayService;
 pub use execution::{
     ClaimedWorkflowJob, CompleteWorkflowRunInput, CreateWorkflowRunInput, SaveWorkflowInput,
-    WorkflowClaimPartition, WorkflowExecutionMode, WorkflowQueueStats, WorkflowQueueStatsQuery,
-    WorkflowRun, WorkflowRunAttempt, WorkflowRunAttemptStatus, WorkflowRunListQuery,
-    WorkflowRunReplay, WorkflowRunReplayTimelineEvent, WorkflowRunStatus, WorkflowRunStepTrace,
+    SuspendWorkflowRunInput, WorkflowClaimPartition, WorkflowExecutionMode, WorkflowQueueStats,
+    WorkflowQueueStatsQuery, WorkflowQueueStatsSnapshot, WorkflowRun, WorkflowRunAttempt,
+    WorkflowRunAttemptStatus, WorkflowRunContinuation, WorkflowRunListQuery, WorkflowRunReplay,
+    WorkflowRunReplayTimelineEvent, WorkflowRunStatus, WorkflowRunStepTrace,
     WorkflowWorkerHeartbeatInput, WorkflowWorkerLease,
 };
-pub use lease::WorkflowWorkerLeaseCoordinator;
+pub use inbound_webhook::{
+    CreatedWorkflowInboundWebhook, NewWorkflowInboundWebhook,
+    WORKFLOW_INBOUND_WEBHOOK_TOLERANCE_SECONDS, WorkflowInboundWebhook,
+    WorkflowInboundWebhookCredentials, WorkflowInboundWebhookDelivery,
+    WorkflowInboundWebhookRepository,
+};
+pub use lease::{WorkflowWorkerLeaseCoordinator, WorkflowWorkerLeaseOwnership};
+pub use recovery::{
+    WORKFLOW_STUCK_JOB_DEFAULT_LIMIT, WORKFLOW_STUCK_JOB_MAX_LIMIT, WorkflowStuckJob,
+};
 pub use repository::WorkflowRepository;
 pub use runtime_events::{
     ClaimedRuntimeRecordWorkflowEvent, RuntimeRecordWorkflowEventDrainResult,
@@ -28,5 +53,7 @@ pub use runtime_events::{
 };
 pub use runtime_records::WorkflowRuntimeRecordService;
 pub use schedule::{
-    ClaimedWorkflowScheduleTick, WorkflowScheduleTickDrainResult, WorkflowScheduledTrigger,
+    ClaimedWorkflowScheduleTick, WorkflowScheduleKind, WorkflowScheduleTickDrainResult,
+    WorkflowScheduledTrigger,
 };
+pub use trace_context::WorkflowTraceContextSource;
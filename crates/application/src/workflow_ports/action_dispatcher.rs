@@ -1,5 +1,6 @@
 use async_trait::async_trait;
-use qryvanta_core::AppResult;
+use qryvanta_core::{AppResult, TenantId};
+use qryvanta_domain::WorkflowCredential;
 use serde_json::Value;
 
 /// External action dispatch type for workflow integration actions.
@@ -26,11 +27,36 @@ pub struct WorkflowActionDispatchRequest {
     pub idempotency_key: String,
     /// Payload object from workflow step action data.
     pub payload: Value,
+    /// Credential the dispatcher injects into the outbound request.
+    pub credential: Option<WorkflowActionCredential>,
+}
+
+/// Tenant credential resolved for one outbound dispatch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowActionCredential {
+    /// Tenant owning the credential; scopes cached access tokens.
+    pub tenant_id: TenantId,
+    /// Credential configuration with secret references.
+    pub credential: WorkflowCredential,
+}
+
+/// Remote response captured from an HTTP-based integration dispatch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkflowActionDispatchResponse {
+    /// HTTP status code returned by the remote endpoint.
+    pub status_code: u16,
+    /// Response body parsed as JSON, or a JSON string when the body is not JSON.
+    pub body: Value,
 }
 
 /// Port for external integration dispatch operations.
 #[async_trait]
 pub trait WorkflowActionDispatcher: Send + Sync {
     /// Dispatches one integration action request.
-    async fn dispatch_action(&self, request: WorkflowActionDispatchRequest) -> AppResult<()>;
+    ///
+    /// Returns the remote response for HTTP-based dispatches and `None` otherwise.
+    async fn dispatch_action(
+        &self,
+        request: WorkflowActionDispatchRequest,
+    ) -> AppResult<Option<WorkflowActionDispatchResponse>>;
 }
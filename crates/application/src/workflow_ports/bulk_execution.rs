@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use qryvanta_domain::RuntimeRecord;
+
+/// Maximum number of records one bulk workflow execution may target.
+pub const WORKFLOW_BULK_EXECUTION_MAX_RECORDS: usize = 5_000;
+
+/// Bulk workflow execution data persisted by the repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewWorkflowBulkExecution {
+    /// Workflow enqueued for every selected record.
+    pub workflow_logical_name: String,
+    /// Entity of the selected records.
+    pub entity_logical_name: String,
+    /// View that selected the records, when not selected by an ad-hoc query.
+    pub view_logical_name: Option<String>,
+    /// Number of selected records.
+    pub total_records: i64,
+    /// Subject that started the execution.
+    pub requested_by_subject: String,
+}
+
+/// Stored bulk workflow execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowBulkExecution {
+    /// Stable bulk execution identifier.
+    pub bulk_execution_id: String,
+    /// Workflow enqueued for every selected record.
+    pub workflow_logical_name: String,
+    /// Entity of the selected records.
+    pub entity_logical_name: String,
+    /// View that selected the records, when not selected by an ad-hoc query.
+    pub view_logical_name: Option<String>,
+    /// Number of selected records.
+    pub total_records: i64,
+    /// Subject that started the execution.
+    pub requested_by_subject: String,
+    /// Start timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Run status counts of one bulk workflow execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WorkflowBulkExecutionRunCounts {
+    /// Runs queued, executing or waiting.
+    pub pending: i64,
+    /// Runs that finished successfully.
+    pub succeeded: i64,
+    /// Runs that exhausted their attempts.
+    pub dead_lettered: i64,
+    /// Finish timestamp of the latest finished run.
+    pub last_finished_at: Option<DateTime<Utc>>,
+}
+
+impl WorkflowBulkExecutionRunCounts {
+    /// Returns the number of runs created for the execution.
+    #[must_use]
+    pub fn total(&self) -> i64 {
+        self.pending + self.succeeded + self.dead_lettered
+    }
+}
+
+/// Summarized progress of one bulk workflow execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowBulkExecutionReport {
+    /// Stored execution.
+    pub execution: WorkflowBulkExecution,
+    /// Run status counts.
+    pub run_counts: WorkflowBulkExecutionRunCounts,
+    /// Time the last run finished, once every run finished.
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// Input for starting a bulk workflow execution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartWorkflowBulkExecutionInput {
+    /// Workflow enqueued for every selected record.
+    pub workflow_logical_name: String,
+    /// Entity of the selected records.
+    pub entity_logical_name: String,
+    /// View that selected the records, when not selected by an ad-hoc query.
+    pub view_logical_name: Option<String>,
+    /// Selected records.
+    pub records: Vec<RuntimeRecord>,
+    /// Record count the caller confirmed from a preview.
+    pub expected_record_count: usize,
+}
@@ -1,22 +1,25 @@
 use async_trait::async_trait;
 use qryvanta_core::AppResult;
 
-use super::execution::{WorkflowQueueStats, WorkflowQueueStatsQuery};
+use super::execution::{WorkflowQueueStatsQuery, WorkflowQueueStatsSnapshot};
 
 /// Optional cache port for queue stats.
 #[async_trait]
 pub trait WorkflowQueueStatsCache: Send + Sync {
-    /// Returns cached queue stats for one query.
+    /// Returns a cached queue stats snapshot for one query.
     async fn get_queue_stats(
         &self,
         query: WorkflowQueueStatsQuery,
-    ) -> AppResult<Option<WorkflowQueueStats>>;
+    ) -> AppResult<Option<WorkflowQueueStatsSnapshot>>;
 
-    /// Stores queue stats for one query with ttl.
+    /// Stores a queue stats snapshot for one query with ttl.
     async fn set_queue_stats(
         &self,
         query: WorkflowQueueStatsQuery,
-        stats: WorkflowQueueStats,
+        snapshot: WorkflowQueueStatsSnapshot,
         ttl_seconds: u32,
     ) -> AppResult<()>;
+
+    /// Drops every cached snapshot after queue state changes.
+    async fn invalidate_queue_stats(&self) -> AppResult<()>;
 }
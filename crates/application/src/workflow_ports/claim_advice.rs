@@ -0,0 +1,122 @@
+/// Database pool utilization observed while serving one worker claim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkflowClaimLoadSignal {
+    /// Connections currently checked out of the pool.
+    pub database_connections_in_use: u32,
+    /// Upper bound of connections the pool may open.
+    pub database_max_connections: u32,
+}
+
+impl WorkflowClaimLoadSignal {
+    /// Returns pool utilization as a whole percentage in `0..=100`.
+    #[must_use]
+    pub fn utilization_percent(&self) -> u32 {
+        if self.database_max_connections == 0 {
+            return 100;
+        }
+
+        let in_use = u64::from(
+            self.database_connections_in_use
+                .min(self.database_max_connections),
+        );
+        let percent = in_use * 100 / u64::from(self.database_max_connections);
+        u32::try_from(percent).unwrap_or(100)
+    }
+}
+
+/// Server-advised claim pacing returned to workers with every claim response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkflowClaimAdvice {
+    /// Largest batch the worker should request on its next claim.
+    pub advised_limit: usize,
+    /// Minimum delay the worker should wait before its next claim.
+    pub retry_after_ms: u64,
+}
+
+/// Policy converting load signals and tenant caps into worker claim advice.
+///
+/// Below the elevated threshold workers may claim the full configured limit.
+/// Between the elevated and saturated thresholds the limit is halved and
+/// workers are asked to wait half of the configured backoff. At or above the
+/// saturated threshold workers receive a single-job limit and the full backoff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkflowClaimBackpressurePolicy {
+    max_claim_limit: usize,
+    tenant_claim_limit: Option<usize>,
+    saturated_retry_after_ms: u64,
+}
+
+impl WorkflowClaimBackpressurePolicy {
+    /// Pool utilization percentage at which claim limits start shrinking.
+    pub const ELEVATED_UTILIZATION_PERCENT: u32 = 70;
+    /// Pool utilization percentage at which claims are reduced to one job.
+    pub const SATURATED_UTILIZATION_PERCENT: u32 = 90;
+
+    /// Creates one claim backpressure policy.
+    ///
+    /// `max_claim_limit` is clamped to at least one job and a zero
+    /// `tenant_claim_limit` is treated as no tenant cap.
+    #[must_use]
+    pub fn new(
+        max_claim_limit: usize,
+        tenant_claim_limit: Option<usize>,
+        saturated_retry_after_ms: u64,
+    ) -> Self {
+        Self {
+            max_claim_limit: max_claim_limit.max(1),
+            tenant_claim_limit: tenant_claim_limit.filter(|limit| *limit > 0),
+            saturated_retry_after_ms,
+        }
+    }
+
+    /// Returns the configured global claim limit.
+    #[must_use]
+    pub fn max_claim_limit(&self) -> usize {
+        self.max_claim_limit
+    }
+
+    /// Returns the configured per-tenant claim cap, if any.
+    #[must_use]
+    pub fn tenant_claim_limit(&self) -> Option<usize> {
+        self.tenant_claim_limit
+    }
+
+    /// Derives claim advice for the current load and claim scope.
+    #[must_use]
+    pub fn advise(
+        &self,
+        signal: WorkflowClaimLoadSignal,
+        is_tenant_scoped: bool,
+    ) -> WorkflowClaimAdvice {
+        let scope_limit = match (is_tenant_scoped, self.tenant_claim_limit) {
+            (true, Some(tenant_claim_limit)) => self.max_claim_limit.min(tenant_claim_limit),
+            _ => self.max_claim_limit,
+        };
+
+        let utilization_percent = signal.utilization_percent();
+        if utilization_percent >= Self::SATURATED_UTILIZATION_PERCENT {
+            return WorkflowClaimAdvice {
+                advised_limit: 1,
+                retry_after_ms: self.saturated_retry_after_ms,
+            };
+        }
+
+        if utilization_percent >= Self::ELEVATED_UTILIZATION_PERCENT {
+            return WorkflowClaimAdvice {
+                advised_limit: (scope_limit / 2).max(1),
+                retry_after_ms: self.saturated_retry_after_ms / 2,
+            };
+        }
+
+        WorkflowClaimAdvice {
+            advised_limit: scope_limit,
+            retry_after_ms: 0,
+        }
+    }
+
+    /// Clamps one requested claim limit to the advised limit.
+    #[must_use]
+    pub fn effective_limit(&self, requested_limit: usize, advice: WorkflowClaimAdvice) -> usize {
+        requested_limit.clamp(1, advice.advised_limit.max(1))
+    }
+}
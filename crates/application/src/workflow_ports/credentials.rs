@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use qryvanta_core::{AppResult, TenantId};
+use qryvanta_domain::WorkflowCredential;
+
+/// Stored workflow credential with its change metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowCredentialRecord {
+    /// Credential configuration; secrets are references only.
+    pub credential: WorkflowCredential,
+    /// Subject that last saved the credential.
+    pub updated_by_subject: String,
+    /// Last save timestamp.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Repository port for named outbound workflow credentials.
+#[async_trait]
+pub trait WorkflowCredentialRepository: Send + Sync {
+    /// Creates or replaces one credential by logical name.
+    async fn save_workflow_credential(
+        &self,
+        tenant_id: TenantId,
+        credential: WorkflowCredential,
+        updated_by_subject: &str,
+    ) -> AppResult<WorkflowCredentialRecord>;
+
+    /// Lists tenant credentials ordered by logical name.
+    async fn list_workflow_credentials(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<WorkflowCredentialRecord>>;
+
+    /// Finds one credential by logical name.
+    async fn find_workflow_credential(
+        &self,
+        tenant_id: TenantId,
+        logical_name: &str,
+    ) -> AppResult<Option<WorkflowCredentialRecord>>;
+
+    /// Deletes one credential, returning whether it existed.
+    async fn delete_workflow_credential(
+        &self,
+        tenant_id: TenantId,
+        logical_name: &str,
+    ) -> AppResult<bool>;
+}
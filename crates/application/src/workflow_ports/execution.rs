@@ -1,6 +1,8 @@
 use chrono::{DateTime, Utc};
 use qryvanta_core::{AppError, AppResult, TenantId};
-use qryvanta_domain::{WorkflowDefinition, WorkflowStep, WorkflowTrigger};
+use qryvanta_domain::{
+    WorkflowDefinition, WorkflowRunPriority, WorkflowStep, WorkflowTrigger, WorkflowTriggerFilter,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -30,6 +32,14 @@ pub struct SaveWorkflowInput {
     pub max_attempts: u16,
     /// Whether workflow is enabled.
     pub is_enabled: bool,
+    /// Record-change filters evaluated before a run is enqueued.
+    pub trigger_filters: Vec<WorkflowTriggerFilter>,
+    /// Fields of which at least one must change before a run is enqueued.
+    pub trigger_changed_fields: Vec<String>,
+    /// View whose filter criteria the triggering record must match.
+    pub trigger_view_logical_name: Option<String>,
+    /// Claim priority inherited by runs of this workflow.
+    pub priority: WorkflowRunPriority,
 }
 
 /// Workflow run listing query.
@@ -48,6 +58,8 @@ pub struct WorkflowRunListQuery {
 pub enum WorkflowRunStatus {
     /// Run started and is currently executing.
     Running,
+    /// Run is suspended at a wait step until its continuation is due.
+    Waiting,
     /// Run finished successfully.
     Succeeded,
     /// Run failed and exhausted retries.
@@ -60,6 +72,7 @@ impl WorkflowRunStatus {
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Running => "running",
+            Self::Waiting => "waiting",
             Self::Succeeded => "succeeded",
             Self::DeadLettered => "dead_lettered",
         }
@@ -69,6 +82,7 @@ impl WorkflowRunStatus {
     pub fn parse(value: &str) -> AppResult<Self> {
         match value {
             "running" => Ok(Self::Running),
+            "waiting" => Ok(Self::Waiting),
             "succeeded" => Ok(Self::Succeeded),
             "dead_lettered" => Ok(Self::DeadLettered),
             _ => Err(AppError::Validation(format!(
@@ -85,6 +99,8 @@ pub enum WorkflowRunAttemptStatus {
     Succeeded,
     /// Attempt failed.
     Failed,
+    /// Attempt reached a wait step and suspended the run.
+    Waiting,
 }
 
 impl WorkflowRunAttemptStatus {
@@ -94,6 +110,7 @@ impl WorkflowRunAttemptStatus {
         match self {
             Self::Succeeded => "succeeded",
             Self::Failed => "failed",
+            Self::Waiting => "waiting",
         }
     }
 
@@ -102,6 +119,7 @@ impl WorkflowRunAttemptStatus {
         match value {
             "succeeded" => Ok(Self::Succeeded),
             "failed" => Ok(Self::Failed),
+            "waiting" => Ok(Self::Waiting),
             _ => Err(AppError::Validation(format!(
                 "unknown workflow run attempt status '{value}'"
             ))),
@@ -134,6 +152,12 @@ pub struct WorkflowRun {
     pub started_at: DateTime<Utc>,
     /// Run finish timestamp when completed.
     pub finished_at: Option<DateTime<Utc>>,
+    /// Time a waiting run becomes claimable again.
+    pub resume_at: Option<DateTime<Utc>>,
+    /// Run whose invoke workflow step started this run.
+    pub parent_run_id: Option<String>,
+    /// Claim priority of the run's queued jobs.
+    pub priority: WorkflowRunPriority,
 }
 
 /// Persisted workflow run attempt record.
@@ -170,6 +194,9 @@ pub struct WorkflowRunStepTrace {
     pub error_message: Option<String>,
     /// Duration spent executing this step in milliseconds.
     pub duration_ms: Option<u64>,
+    /// Times the step was retried under its retry policy before this outcome.
+    #[serde(default)]
+    pub retries: u32,
 }
 
 /// Reconstructed deterministic replay model for one workflow run.
@@ -225,6 +252,14 @@ pub struct CreateWorkflowRunInput {
     pub trigger_entity_logical_name: Option<String>,
     /// Trigger payload.
     pub trigger_payload: Value,
+    /// Run whose invoke workflow step started this run.
+    pub parent_run_id: Option<String>,
+    /// W3C trace context of the request that created the run.
+    pub trace_context: Option<String>,
+    /// Claim priority of the run's queued jobs.
+    pub priority: WorkflowRunPriority,
+    /// Key that deduplicates retried run creation for the same workflow.
+    pub idempotency_key: Option<String>,
 }
 
 /// Internal run completion payload for repository implementations.
@@ -240,6 +275,30 @@ pub struct CompleteWorkflowRunInput {
     pub dead_letter_reason: Option<String>,
 }
 
+/// Persisted resume point of a run suspended by a wait step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowRunContinuation {
+    /// Path of the wait step the run resumes after (e.g. "2", "1.then.0").
+    pub resume_after_step_path: String,
+    /// Time the run becomes claimable again.
+    pub resume_at: DateTime<Utc>,
+    /// Attempt that reached the wait step.
+    pub attempt_number: i32,
+    /// Step outputs captured before the wait, exposed again as `{{steps.*}}`.
+    pub step_outputs: Value,
+}
+
+/// Internal run suspension payload for repository implementations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuspendWorkflowRunInput {
+    /// Run identifier.
+    pub run_id: String,
+    /// Total attempts executed so far.
+    pub attempts: i32,
+    /// Resume point persisted with the run.
+    pub continuation: WorkflowRunContinuation,
+}
+
 /// Claimed queued workflow job returned to one worker.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ClaimedWorkflowJob {
@@ -257,6 +316,12 @@ pub struct ClaimedWorkflowJob {
     pub trigger_payload: Value,
     /// Job lease token used for fencing-token completion checks.
     pub lease_token: String,
+    /// Resume point when the run was suspended by a wait step.
+    pub continuation: Option<WorkflowRunContinuation>,
+    /// W3C trace context captured when the run was created.
+    pub trace_context: Option<String>,
+    /// Claim priority of the run.
+    pub priority: WorkflowRunPriority,
 }
 
 /// Worker heartbeat payload persisted for queue observability.
@@ -289,6 +354,17 @@ pub struct WorkflowQueueStats {
     pub active_workers: i64,
 }
 
+/// Queue stats together with freshness metadata for polling dashboards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkflowQueueStatsSnapshot {
+    /// Aggregated queue stats.
+    pub stats: WorkflowQueueStats,
+    /// Time the stats were computed from the repository.
+    pub computed_at: DateTime<Utc>,
+    /// Whether the snapshot was served from the queue stats cache.
+    pub served_from_cache: bool,
+}
+
 /// Optional queue partition selector for worker job claims.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct WorkflowClaimPartition {
@@ -303,6 +379,8 @@ pub struct WorkflowQueueStatsQuery {
     pub active_window_seconds: u32,
     /// Optional tenant-hash partition scope.
     pub partition: Option<WorkflowClaimPartition>,
+    /// Optional tenant scope for job counts; worker counts stay global.
+    pub tenant_id: Option<TenantId>,
 }
 
 /// One distributed worker lease claim.
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use qryvanta_core::{AppResult, TenantId};
+use serde_json::Value;
+
+/// Maximum age in seconds of a signed inbound webhook delivery.
+pub const WORKFLOW_INBOUND_WEBHOOK_TOLERANCE_SECONDS: i64 = 300;
+
+/// Inbound webhook data persisted by the repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewWorkflowInboundWebhook {
+    /// Workflow started by deliveries.
+    pub workflow_logical_name: String,
+    /// Optional JSON schema every delivered payload must satisfy.
+    pub payload_schema: Option<Value>,
+    /// SHA-256 hash of the raw URL token.
+    pub token_hash: String,
+    /// Encrypted HMAC signing secret.
+    pub signing_secret_ciphertext: Vec<u8>,
+    /// Subject that configured the webhook.
+    pub created_by_subject: String,
+}
+
+/// Stored inbound webhook for one workflow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowInboundWebhook {
+    /// Workflow started by deliveries.
+    pub workflow_logical_name: String,
+    /// Optional JSON schema every delivered payload must satisfy.
+    pub payload_schema: Option<Value>,
+    /// Subject that configured the webhook.
+    pub created_by_subject: String,
+    /// Creation timestamp.
+    pub created_at: DateTime<Utc>,
+    /// Number of accepted deliveries.
+    pub delivery_count: i64,
+    /// Timestamp of the last accepted delivery.
+    pub last_received_at: Option<DateTime<Utc>>,
+}
+
+/// Stored inbound webhook together with its encrypted signing secret.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowInboundWebhookCredentials {
+    /// Stored webhook.
+    pub webhook: WorkflowInboundWebhook,
+    /// Encrypted HMAC signing secret.
+    pub signing_secret_ciphertext: Vec<u8>,
+}
+
+/// Newly configured inbound webhook together with its raw credentials.
+///
+/// The URL token and signing secret are only available at configuration time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedWorkflowInboundWebhook {
+    /// Stored webhook.
+    pub webhook: WorkflowInboundWebhook,
+    /// Raw token embedded in the inbound URL.
+    pub token: String,
+    /// Raw HMAC-SHA256 signing secret shared with the sender.
+    pub signing_secret: String,
+}
+
+/// One signed request received on an inbound webhook URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowInboundWebhookDelivery {
+    /// Unix timestamp in seconds sent by the caller.
+    pub timestamp: Option<String>,
+    /// Signature header value in `sha256=<hex>` form.
+    pub signature: Option<String>,
+    /// Raw request body the signature was computed over.
+    pub body: Vec<u8>,
+    /// Request metadata exposed to the workflow as `request`.
+    pub request: Value,
+}
+
+/// Repository port for per-workflow inbound webhooks.
+#[async_trait]
+pub trait WorkflowInboundWebhookRepository: Send + Sync {
+    /// Saves the webhook of a workflow, replacing any previous one.
+    async fn save_inbound_webhook(
+        &self,
+        tenant_id: TenantId,
+        webhook: NewWorkflowInboundWebhook,
+    ) -> AppResult<WorkflowInboundWebhook>;
+
+    /// Finds the webhook of a workflow.
+    async fn find_inbound_webhook(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+    ) -> AppResult<Option<WorkflowInboundWebhook>>;
+
+    /// Finds a webhook and its signing secret by URL token hash.
+    async fn find_inbound_webhook_by_token_hash(
+        &self,
+        tenant_id: TenantId,
+        token_hash: &str,
+    ) -> AppResult<Option<WorkflowInboundWebhookCredentials>>;
+
+    /// Deletes the webhook of a workflow, returning whether one existed.
+    async fn delete_inbound_webhook(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+    ) -> AppResult<bool>;
+
+    /// Records one accepted delivery.
+    async fn record_inbound_webhook_delivery(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+        received_at: DateTime<Utc>,
+    ) -> AppResult<()>;
+}
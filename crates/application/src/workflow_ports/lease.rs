@@ -3,6 +3,17 @@ use qryvanta_core::AppResult;
 
 use super::execution::WorkflowWorkerLease;
 
+/// Current holder of one coordination scope as seen by the coordinator backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowWorkerLeaseOwnership {
+    /// Coordination scope key.
+    pub scope_key: String,
+    /// Worker holding the lease.
+    pub holder_id: String,
+    /// Seconds until the lease expires unless renewed, when the backend reports it.
+    pub expires_in_seconds: Option<i64>,
+}
+
 /// Distributed coordination port for worker lease claims.
 #[async_trait]
 pub trait WorkflowWorkerLeaseCoordinator: Send + Sync {
@@ -20,4 +31,10 @@ pub trait WorkflowWorkerLeaseCoordinator: Send + Sync {
     /// Renews one existing lease and returns false when token ownership changed.
     async fn renew_lease(&self, lease: &WorkflowWorkerLease, lease_seconds: u32)
     -> AppResult<bool>;
+
+    /// Returns the current holder of one scope, if the scope is leased.
+    async fn inspect_lease(
+        &self,
+        scope_key: &str,
+    ) -> AppResult<Option<WorkflowWorkerLeaseOwnership>>;
 }
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+
+/// Number of stuck jobs listed when the caller sets no limit.
+pub const WORKFLOW_STUCK_JOB_DEFAULT_LIMIT: usize = 50;
+
+/// Upper bound on stuck jobs listed per request.
+pub const WORKFLOW_STUCK_JOB_MAX_LIMIT: usize = 200;
+
+/// Queued job still leased after its lease expired.
+///
+/// Workers that crash mid-execution leave their jobs leased until another
+/// worker reclaims them; operators can release or fail these jobs instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowStuckJob {
+    /// Queue job identifier.
+    pub job_id: String,
+    /// Run executed by the job.
+    pub run_id: String,
+    /// Workflow of the run.
+    pub workflow_logical_name: String,
+    /// Published workflow version of the run.
+    pub workflow_version: i32,
+    /// Worker that holds the expired lease.
+    pub leased_by: Option<String>,
+    /// Time the lease expired.
+    pub lease_expires_at: DateTime<Utc>,
+    /// Attempts recorded on the run.
+    pub run_attempts: i32,
+    /// Last queue error recorded on the job.
+    pub last_error: Option<String>,
+}
@@ -2,11 +2,15 @@ use async_trait::async_trait;
 use qryvanta_core::{AppResult, TenantId};
 use qryvanta_domain::{WorkflowDefinition, WorkflowTrigger};
 
+use super::bulk_execution::{
+    NewWorkflowBulkExecution, WorkflowBulkExecution, WorkflowBulkExecutionRunCounts,
+};
 use super::execution::{
-    ClaimedWorkflowJob, CompleteWorkflowRunInput, CreateWorkflowRunInput, WorkflowClaimPartition,
-    WorkflowQueueStats, WorkflowQueueStatsQuery, WorkflowRun, WorkflowRunAttempt,
-    WorkflowRunListQuery, WorkflowWorkerHeartbeatInput,
+    ClaimedWorkflowJob, CompleteWorkflowRunInput, CreateWorkflowRunInput, SuspendWorkflowRunInput,
+    WorkflowClaimPartition, WorkflowQueueStats, WorkflowQueueStatsQuery, WorkflowRun,
+    WorkflowRunAttempt, WorkflowRunListQuery, WorkflowWorkerHeartbeatInput,
 };
+use super::recovery::WorkflowStuckJob;
 use super::schedule::{ClaimedWorkflowScheduleTick, WorkflowScheduledTrigger};
 use chrono::{DateTime, Utc};
 
@@ -106,6 +110,9 @@ pub trait WorkflowRepository: Send + Sync {
     ) -> AppResult<()>;
 
     /// Creates a new workflow run record in running state.
+    ///
+    /// Returns a conflict when the workflow already has a run created under
+    /// the input's idempotency key.
     async fn create_run(
         &self,
         tenant_id: TenantId,
@@ -113,6 +120,8 @@ pub trait WorkflowRepository: Send + Sync {
     ) -> AppResult<WorkflowRun>;
 
     /// Enqueues one workflow run for worker execution.
+    ///
+    /// Does nothing when the run already has a job.
     async fn enqueue_run_job(&self, tenant_id: TenantId, run_id: &str) -> AppResult<()>;
 
     /// Claims queued jobs for one worker with a bounded lease.
@@ -144,6 +153,61 @@ pub trait WorkflowRepository: Send + Sync {
         error_message: &str,
     ) -> AppResult<()>;
 
+    /// Suspends the run of one leased job at a wait step.
+    ///
+    /// Persists the continuation on the run, marks it waiting and returns the
+    /// job to pending so it is not claimable before `resume_at`.
+    async fn suspend_run_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        worker_id: &str,
+        lease_token: &str,
+        input: SuspendWorkflowRunInput,
+    ) -> AppResult<WorkflowRun>;
+
+    /// Lists leased jobs whose lease expired, oldest expiry first.
+    async fn list_stuck_jobs(
+        &self,
+        tenant_id: TenantId,
+        limit: usize,
+    ) -> AppResult<Vec<WorkflowStuckJob>>;
+
+    /// Returns one stuck job to pending so the next claim picks it up.
+    ///
+    /// Returns false when the job does not exist, is not leased or its lease
+    /// has not expired.
+    async fn release_stuck_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        reason: &str,
+    ) -> AppResult<bool>;
+
+    /// Returns one job leased by a worker to pending before its lease expires.
+    ///
+    /// Returns false when the job is no longer leased by `worker_id` with a
+    /// matching lease token, for example because it already completed.
+    async fn release_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        worker_id: &str,
+        lease_token: &str,
+        reason: &str,
+    ) -> AppResult<bool>;
+
+    /// Marks one stuck job failed and dead-letters its run.
+    ///
+    /// Returns `None` when the job does not exist, is not leased or its lease
+    /// has not expired.
+    async fn fail_stuck_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        reason: &str,
+    ) -> AppResult<Option<WorkflowRun>>;
+
     /// Updates one worker heartbeat snapshot.
     async fn upsert_worker_heartbeat(
         &self,
@@ -178,10 +242,42 @@ pub trait WorkflowRepository: Send + Sync {
     /// Returns one workflow run by run id.
     async fn find_run(&self, tenant_id: TenantId, run_id: &str) -> AppResult<Option<WorkflowRun>>;
 
+    /// Returns the run created for a workflow under an idempotency key.
+    async fn find_run_by_idempotency_key(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+        idempotency_key: &str,
+    ) -> AppResult<Option<WorkflowRun>>;
+
     /// Lists attempts for one run.
     async fn list_run_attempts(
         &self,
         tenant_id: TenantId,
         run_id: &str,
     ) -> AppResult<Vec<WorkflowRunAttempt>>;
+
+    /// Stores a new bulk workflow execution.
+    async fn create_bulk_execution(
+        &self,
+        tenant_id: TenantId,
+        execution: NewWorkflowBulkExecution,
+    ) -> AppResult<WorkflowBulkExecution>;
+
+    /// Returns one bulk workflow execution by id.
+    async fn find_bulk_execution(
+        &self,
+        tenant_id: TenantId,
+        bulk_execution_id: &str,
+    ) -> AppResult<Option<WorkflowBulkExecution>>;
+
+    /// Counts the runs of one bulk workflow execution by status.
+    ///
+    /// Runs belong to an execution through the `bulk_execution_id` of their
+    /// trigger payload.
+    async fn bulk_execution_run_counts(
+        &self,
+        tenant_id: TenantId,
+        bulk_execution_id: &str,
+    ) -> AppResult<WorkflowBulkExecutionRunCounts>;
 }
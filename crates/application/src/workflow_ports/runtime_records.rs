@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use qryvanta_core::{AppResult, TenantId, UserIdentity};
-use qryvanta_domain::RuntimeRecord;
+use qryvanta_domain::{RuntimeRecord, ViewDefinition};
 use serde_json::Value;
 
 use super::ClaimedRuntimeRecordWorkflowEvent;
@@ -15,6 +15,30 @@ pub trait WorkflowRuntimeRecordService: Send + Sync {
         entity_logical_name: &str,
     ) -> AppResult<bool>;
 
+    /// Returns field logical names from the latest published schema, if any.
+    async fn published_entity_field_names(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<Vec<String>>>;
+
+    /// Loads an entity view without permission checks.
+    async fn find_view_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+    ) -> AppResult<Option<ViewDefinition>>;
+
+    /// Loads the record a relation field points at, or `None` when it no longer exists.
+    async fn get_related_runtime_record_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        relation_field_logical_name: &str,
+        related_record_id: &str,
+    ) -> AppResult<Option<RuntimeRecord>>;
+
     /// Creates runtime record without permission checks.
     async fn create_runtime_record_unchecked(
         &self,
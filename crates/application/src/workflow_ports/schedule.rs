@@ -1,12 +1,23 @@
 use chrono::{DateTime, Utc};
 use qryvanta_core::TenantId;
 
+/// Scheduling grammar used by one schedule trigger source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkflowScheduleKind {
+    /// Built-in `schedule_tick` keys such as `hourly` or `daily_utc_0900`.
+    ScheduleTick,
+    /// `scheduled` triggers carrying a cron expression.
+    Cron,
+}
+
 /// One tenant-scoped enabled schedule trigger source.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WorkflowScheduledTrigger {
     /// Tenant owning the workflow schedule.
     pub tenant_id: TenantId,
-    /// Stable schedule key from the workflow trigger.
+    /// Scheduling grammar of `schedule_key`.
+    pub kind: WorkflowScheduleKind,
+    /// Stable schedule key or cron expression from the workflow trigger.
     pub schedule_key: String,
 }
 
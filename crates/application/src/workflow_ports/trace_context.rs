@@ -0,0 +1,8 @@
+/// Source of the distributed trace context active while a run is created.
+///
+/// Contexts are W3C `traceparent` values, so queued runs can be continued
+/// by the worker that executes them.
+pub trait WorkflowTraceContextSource: Send + Sync {
+    /// Returns the current trace context, or `None` outside a sampled trace.
+    fn current_trace_context(&self) -> Option<String>;
+}
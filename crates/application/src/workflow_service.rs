@@ -4,28 +4,40 @@ use async_trait::async_trait;
 use chrono::Utc;
 use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
 use qryvanta_domain::{
-    AuditAction, Permission, RuntimeRecord, WorkflowConditionOperator, WorkflowDefinition,
-    WorkflowDefinitionInput, WorkflowStep, WorkflowTrigger, is_sensitive_workflow_header_name,
-    redact_sensitive_workflow_headers, redact_workflow_header_secret_refs,
+    AuditAction, Permission, RuntimeRecord, ViewDefinition, WorkflowConditionOperator,
+    WorkflowDefinition, WorkflowDefinitionInput, WorkflowStep, WorkflowTrigger,
+    is_sensitive_workflow_header_name, redact_sensitive_workflow_headers,
+    redact_workflow_header_secret_refs,
 };
 use serde_json::Value;
 
+use crate::contact_consent_service::{ContactConsentRepository, MARKETING_CONSENT_PURPOSE};
 use crate::metadata_service::MetadataService;
+use crate::operation_timeouts::{OperationDeadline, OperationDeadlines, TimedOperation};
 use crate::workflow_ports::{
     ClaimedRuntimeRecordWorkflowEvent, ClaimedWorkflowJob, CompleteWorkflowRunInput,
     CreateWorkflowRunInput, SaveWorkflowInput, WorkflowActionDispatcher, WorkflowClaimPartition,
-    WorkflowDelayService, WorkflowExecutionMode, WorkflowQueueStats, WorkflowQueueStatsCache,
-    WorkflowQueueStatsQuery, WorkflowRepository, WorkflowRun, WorkflowRunAttempt,
-    WorkflowRunAttemptStatus, WorkflowRunListQuery, WorkflowRunReplay,
+    WorkflowCredentialRepository, WorkflowDelayService, WorkflowExecutionMode,
+    WorkflowInboundWebhookRepository, WorkflowQueueStats, WorkflowQueueStatsCache,
+    WorkflowQueueStatsQuery, WorkflowQueueStatsSnapshot, WorkflowRepository, WorkflowRun,
+    WorkflowRunAttempt, WorkflowRunAttemptStatus, WorkflowRunListQuery, WorkflowRunReplay,
     WorkflowRunReplayTimelineEvent, WorkflowRunStatus, WorkflowRunStepTrace,
-    WorkflowRuntimeRecordService, WorkflowWorkerHeartbeatInput,
+    WorkflowRuntimeRecordService, WorkflowTraceContextSource, WorkflowWorkerHeartbeatInput,
+    WorkflowWorkerLeaseCoordinator,
+};
+use crate::{
+    AuditEvent, AuditRepository, AuthorizationService, FeatureFlagService, SecretEncryptor,
+    WORKFLOWS_FEATURE_FLAG,
 };
-use crate::{AuditEvent, AuditRepository, AuthorizationService};
 
+mod bulk_execution;
+mod credentials;
 mod definitions;
 mod dispatch;
 mod execution;
+mod inbound_webhooks;
 mod queue;
+mod recovery;
 
 #[async_trait]
 impl WorkflowRuntimeRecordService for MetadataService {
@@ -40,6 +52,69 @@ impl WorkflowRuntimeRecordService for MetadataService {
             .is_some())
     }
 
+    async fn published_entity_field_names(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<Vec<String>>> {
+        Ok(self
+            .latest_published_schema_unchecked(actor, entity_logical_name)
+            .await?
+            .map(|schema| {
+                schema
+                    .fields()
+                    .iter()
+                    .map(|field| field.logical_name().as_str().to_owned())
+                    .collect()
+            }))
+    }
+
+    async fn find_view_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+    ) -> AppResult<Option<ViewDefinition>> {
+        self.find_view_unchecked(actor, entity_logical_name, view_logical_name)
+            .await
+    }
+
+    async fn get_related_runtime_record_unchecked(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+        relation_field_logical_name: &str,
+        related_record_id: &str,
+    ) -> AppResult<Option<RuntimeRecord>> {
+        let schema = self
+            .latest_published_schema_unchecked(actor, entity_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "entity '{entity_logical_name}' has no published schema"
+                ))
+            })?;
+        let target_entity = schema
+            .fields()
+            .iter()
+            .find(|field| field.logical_name().as_str() == relation_field_logical_name)
+            .and_then(|field| field.relation_target_entity())
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "field '{relation_field_logical_name}' is not a relation field on entity '{entity_logical_name}'"
+                ))
+            })?;
+
+        match self
+            .get_runtime_record_unchecked(actor, target_entity.as_str(), related_record_id)
+            .await
+        {
+            Ok(record) => Ok(Some(record)),
+            Err(AppError::NotFound(_)) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
     async fn create_runtime_record_unchecked(
         &self,
         actor: &UserIdentity,
@@ -120,10 +195,18 @@ pub struct WorkflowService {
     runtime_record_service: Arc<dyn WorkflowRuntimeRecordService>,
     action_dispatcher: Option<Arc<dyn WorkflowActionDispatcher>>,
     delay_service: Option<Arc<dyn WorkflowDelayService>>,
+    contact_consent_repository: Option<Arc<dyn ContactConsentRepository>>,
     audit_repository: Arc<dyn AuditRepository>,
     execution_mode: WorkflowExecutionMode,
     queue_stats_cache: Option<Arc<dyn WorkflowQueueStatsCache>>,
     queue_stats_cache_ttl_seconds: u32,
+    inbound_webhook_repository: Option<Arc<dyn WorkflowInboundWebhookRepository>>,
+    inbound_webhook_secret_encryptor: Option<Arc<dyn SecretEncryptor>>,
+    credential_repository: Option<Arc<dyn WorkflowCredentialRepository>>,
+    worker_lease_coordinator: Option<Arc<dyn WorkflowWorkerLeaseCoordinator>>,
+    operation_deadlines: Option<OperationDeadlines>,
+    feature_flag_service: Option<FeatureFlagService>,
+    trace_context_source: Option<Arc<dyn WorkflowTraceContextSource>>,
 }
 
 impl WorkflowService {
@@ -142,10 +225,18 @@ impl WorkflowService {
             runtime_record_service,
             action_dispatcher: None,
             delay_service: None,
+            contact_consent_repository: None,
             audit_repository,
             execution_mode,
             queue_stats_cache: None,
             queue_stats_cache_ttl_seconds: 0,
+            inbound_webhook_repository: None,
+            inbound_webhook_secret_encryptor: None,
+            credential_repository: None,
+            worker_lease_coordinator: None,
+            operation_deadlines: None,
+            feature_flag_service: None,
+            trace_context_source: None,
         }
     }
 
@@ -177,6 +268,81 @@ impl WorkflowService {
         self.delay_service = Some(delay_service);
         self
     }
+
+    /// Blocks email steps to contacts without marketing email consent.
+    #[must_use]
+    pub fn with_contact_consent_repository(
+        mut self,
+        contact_consent_repository: Arc<dyn ContactConsentRepository>,
+    ) -> Self {
+        self.contact_consent_repository = Some(contact_consent_repository);
+        self
+    }
+
+    /// Enables signed per-workflow inbound webhooks.
+    #[must_use]
+    pub fn with_inbound_webhooks(
+        mut self,
+        repository: Arc<dyn WorkflowInboundWebhookRepository>,
+        secret_encryptor: Arc<dyn SecretEncryptor>,
+    ) -> Self {
+        self.inbound_webhook_repository = Some(repository);
+        self.inbound_webhook_secret_encryptor = Some(secret_encryptor);
+        self
+    }
+
+    /// Enables named outbound credentials for HTTP and webhook steps.
+    #[must_use]
+    pub fn with_credential_repository(
+        mut self,
+        credential_repository: Arc<dyn WorkflowCredentialRepository>,
+    ) -> Self {
+        self.credential_repository = Some(credential_repository);
+        self
+    }
+
+    /// Lets operators inspect worker coordination leases.
+    #[must_use]
+    pub fn with_worker_lease_coordinator(
+        mut self,
+        worker_lease_coordinator: Arc<dyn WorkflowWorkerLeaseCoordinator>,
+    ) -> Self {
+        self.worker_lease_coordinator = Some(worker_lease_coordinator);
+        self
+    }
+
+    /// Bounds inline workflow runs started by dispatch with a latency budget.
+    #[must_use]
+    pub fn with_operation_deadlines(mut self, operation_deadlines: OperationDeadlines) -> Self {
+        self.operation_deadlines = Some(operation_deadlines);
+        self
+    }
+
+    /// Gates workflow management behind the tenant's `workflows` feature flag.
+    ///
+    /// The flag is enabled until a tenant configures it. Published workflows
+    /// keep reacting to their triggers while it is disabled.
+    #[must_use]
+    pub fn with_feature_flags(mut self, feature_flag_service: FeatureFlagService) -> Self {
+        self.feature_flag_service = Some(feature_flag_service);
+        self
+    }
+
+    /// Records the active trace context on new runs so workers continue the trace.
+    #[must_use]
+    pub fn with_trace_context_source(
+        mut self,
+        trace_context_source: Arc<dyn WorkflowTraceContextSource>,
+    ) -> Self {
+        self.trace_context_source = Some(trace_context_source);
+        self
+    }
+
+    fn current_trace_context(&self) -> Option<String> {
+        self.trace_context_source
+            .as_ref()
+            .and_then(|source| source.current_trace_context())
+    }
 }
 
 #[cfg(test)]
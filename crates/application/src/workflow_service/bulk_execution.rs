@@ -0,0 +1,203 @@
+use crate::workflow_ports::{
+    NewWorkflowBulkExecution, StartWorkflowBulkExecutionInput, WORKFLOW_BULK_EXECUTION_MAX_RECORDS,
+    WorkflowBulkExecutionReport, WorkflowBulkExecutionRunCounts,
+};
+
+use super::*;
+
+impl WorkflowService {
+    /// Checks that a workflow can be bulk executed against records of an entity.
+    ///
+    /// The workflow must be published and enabled, and workflows triggered by
+    /// records of one entity only accept records of that entity. Bulk runs
+    /// always go through the queue, so queued execution mode is required.
+    pub async fn ensure_bulk_execution_target(
+        &self,
+        actor: &UserIdentity,
+        workflow_logical_name: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<()> {
+        self.require_workflow_manage(actor).await?;
+        self.bulk_execution_workflow(
+            actor.tenant_id(),
+            workflow_logical_name,
+            entity_logical_name,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Enqueues one workflow run per selected record.
+    ///
+    /// `expected_record_count` must match the selection, so a selection that
+    /// changed since the caller's preview is rejected instead of enqueued. The
+    /// runs are executed by queue workers at their configured claim rate.
+    pub async fn start_bulk_execution(
+        &self,
+        actor: &UserIdentity,
+        input: StartWorkflowBulkExecutionInput,
+    ) -> AppResult<WorkflowBulkExecutionReport> {
+        self.require_workflow_manage(actor).await?;
+        let workflow = self
+            .bulk_execution_workflow(
+                actor.tenant_id(),
+                input.workflow_logical_name.as_str(),
+                input.entity_logical_name.as_str(),
+            )
+            .await?;
+
+        if input.records.is_empty() {
+            return Err(AppError::Validation(
+                "bulk workflow execution requires at least one record".to_owned(),
+            ));
+        }
+        if input.records.len() > WORKFLOW_BULK_EXECUTION_MAX_RECORDS {
+            return Err(AppError::Validation(format!(
+                "bulk workflow execution is limited to {WORKFLOW_BULK_EXECUTION_MAX_RECORDS} records"
+            )));
+        }
+        if input.records.len() != input.expected_record_count {
+            return Err(AppError::Conflict(format!(
+                "record selection changed since preview: expected {} records, found {}",
+                input.expected_record_count,
+                input.records.len()
+            )));
+        }
+
+        let execution = self
+            .repository
+            .create_bulk_execution(
+                actor.tenant_id(),
+                NewWorkflowBulkExecution {
+                    workflow_logical_name: workflow.logical_name().as_str().to_owned(),
+                    entity_logical_name: input.entity_logical_name.clone(),
+                    view_logical_name: input.view_logical_name,
+                    total_records: i64::try_from(input.records.len()).unwrap_or(i64::MAX),
+                    requested_by_subject: actor.subject().to_owned(),
+                },
+            )
+            .await?;
+
+        let workflow_actor = UserIdentity::new(
+            "workflow-runtime",
+            "workflow-runtime",
+            None,
+            actor.tenant_id(),
+        );
+        for record in &input.records {
+            let payload = serde_json::json!({
+                "entity_logical_name": input.entity_logical_name,
+                "record_id": record.record_id().as_str(),
+                "id": record.record_id().as_str(),
+                "record": record.data(),
+                "triggered_by": actor.subject(),
+                "bulk_execution_id": execution.bulk_execution_id,
+            });
+            self.enqueue_workflow_definition(&workflow_actor, &workflow, payload, None, None)
+                .await?;
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::WorkflowBulkExecutionStarted,
+                resource_type: "workflow_bulk_execution".to_owned(),
+                resource_id: execution.bulk_execution_id.clone(),
+                detail: Some(format!(
+                    "enqueued workflow '{}' for {} '{}' records",
+                    execution.workflow_logical_name,
+                    execution.total_records,
+                    execution.entity_logical_name
+                )),
+            })
+            .await?;
+
+        Ok(WorkflowBulkExecutionReport {
+            run_counts: WorkflowBulkExecutionRunCounts {
+                pending: execution.total_records,
+                ..WorkflowBulkExecutionRunCounts::default()
+            },
+            execution,
+            completed_at: None,
+        })
+    }
+
+    /// Summarizes the runs of a bulk workflow execution.
+    ///
+    /// The report is complete once every selected record has a finished run.
+    pub async fn bulk_execution_report(
+        &self,
+        actor: &UserIdentity,
+        bulk_execution_id: &str,
+    ) -> AppResult<WorkflowBulkExecutionReport> {
+        self.require_workflow_read(actor).await?;
+        let execution = self
+            .repository
+            .find_bulk_execution(actor.tenant_id(), bulk_execution_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "bulk workflow execution '{}' does not exist for tenant '{}'",
+                    bulk_execution_id,
+                    actor.tenant_id()
+                ))
+            })?;
+        let run_counts = self
+            .repository
+            .bulk_execution_run_counts(actor.tenant_id(), bulk_execution_id)
+            .await?;
+        let completed_at = (run_counts.pending == 0
+            && run_counts.total() >= execution.total_records)
+            .then_some(run_counts.last_finished_at)
+            .flatten();
+
+        Ok(WorkflowBulkExecutionReport {
+            execution,
+            run_counts,
+            completed_at,
+        })
+    }
+
+    async fn bulk_execution_workflow(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<WorkflowDefinition> {
+        if self.execution_mode != WorkflowExecutionMode::Queued {
+            return Err(AppError::Conflict(
+                "queued workflow execution mode is not enabled".to_owned(),
+            ));
+        }
+
+        let workflow = self
+            .repository
+            .find_published_workflow(tenant_id, workflow_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "workflow '{}' does not have a published version for tenant '{}'",
+                    workflow_logical_name, tenant_id
+                ))
+            })?;
+        if !workflow.is_enabled() {
+            return Err(AppError::Conflict(format!(
+                "workflow '{}' is disabled",
+                workflow.logical_name().as_str()
+            )));
+        }
+        if let Some(trigger_entity) = workflow.trigger().runtime_record_entity_logical_name()
+            && trigger_entity != entity_logical_name
+        {
+            return Err(AppError::Validation(format!(
+                "workflow '{}' is triggered by '{}' records and cannot run for '{}' records",
+                workflow.logical_name().as_str(),
+                trigger_entity,
+                entity_logical_name
+            )));
+        }
+
+        Ok(workflow)
+    }
+}
@@ -0,0 +1,145 @@
+use qryvanta_domain::WorkflowCredential;
+
+use crate::workflow_ports::{WorkflowActionCredential, WorkflowCredentialRecord};
+
+use super::*;
+
+impl WorkflowService {
+    /// Creates or replaces a named outbound credential.
+    ///
+    /// Only secret references are stored; the dispatcher resolves them when a
+    /// step referencing the credential calls an allowed host.
+    pub async fn save_workflow_credential(
+        &self,
+        actor: &UserIdentity,
+        credential: WorkflowCredential,
+    ) -> AppResult<WorkflowCredentialRecord> {
+        self.require_workflow_manage(actor).await?;
+        let repository = self.credential_repository()?;
+
+        let record = repository
+            .save_workflow_credential(actor.tenant_id(), credential, actor.subject())
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::WorkflowCredentialSaved,
+                resource_type: "workflow_credential".to_owned(),
+                resource_id: record.credential.logical_name().as_str().to_owned(),
+                detail: Some(format!(
+                    "saved {} workflow credential '{}' for host(s) {}",
+                    record.credential.auth().kind(),
+                    record.credential.logical_name().as_str(),
+                    record.credential.allowed_hosts().join(", ")
+                )),
+            })
+            .await?;
+
+        Ok(record)
+    }
+
+    /// Lists outbound credentials configured for the tenant.
+    pub async fn list_workflow_credentials(
+        &self,
+        actor: &UserIdentity,
+    ) -> AppResult<Vec<WorkflowCredentialRecord>> {
+        self.require_workflow_read(actor).await?;
+
+        self.credential_repository()?
+            .list_workflow_credentials(actor.tenant_id())
+            .await
+    }
+
+    /// Deletes one outbound credential.
+    ///
+    /// Steps still referencing the credential fail at dispatch time.
+    pub async fn delete_workflow_credential(
+        &self,
+        actor: &UserIdentity,
+        logical_name: &str,
+    ) -> AppResult<()> {
+        self.require_workflow_manage(actor).await?;
+
+        if !self
+            .credential_repository()?
+            .delete_workflow_credential(actor.tenant_id(), logical_name)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "workflow credential '{logical_name}' does not exist"
+            )));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::WorkflowCredentialDeleted,
+                resource_type: "workflow_credential".to_owned(),
+                resource_id: logical_name.to_owned(),
+                detail: Some(format!("deleted workflow credential '{logical_name}'")),
+            })
+            .await
+    }
+
+    /// Rejects workflows whose steps reference credentials that do not exist.
+    pub(super) async fn validate_step_credentials(
+        &self,
+        actor: &UserIdentity,
+        workflow: &WorkflowDefinition,
+    ) -> AppResult<()> {
+        let credential_names = workflow.credential_logical_names();
+        if credential_names.is_empty() {
+            return Ok(());
+        }
+
+        let repository = self.credential_repository()?;
+        for credential_name in credential_names {
+            if repository
+                .find_workflow_credential(actor.tenant_id(), credential_name)
+                .await?
+                .is_none()
+            {
+                return Err(AppError::Validation(format!(
+                    "workflow step references unknown credential '{credential_name}'"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Loads the credential a step injects into its outbound request.
+    pub(super) async fn resolve_action_credential(
+        &self,
+        tenant_id: TenantId,
+        credential: Option<&str>,
+    ) -> AppResult<Option<WorkflowActionCredential>> {
+        let Some(credential_name) = credential else {
+            return Ok(None);
+        };
+
+        let record = self
+            .credential_repository()?
+            .find_workflow_credential(tenant_id, credential_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "workflow credential '{credential_name}' does not exist"
+                ))
+            })?;
+
+        Ok(Some(WorkflowActionCredential {
+            tenant_id,
+            credential: record.credential,
+        }))
+    }
+
+    fn credential_repository(&self) -> AppResult<&dyn WorkflowCredentialRepository> {
+        self.credential_repository
+            .as_deref()
+            .ok_or_else(|| AppError::Internal("workflow credentials are not configured".to_owned()))
+    }
+}
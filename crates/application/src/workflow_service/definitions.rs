@@ -1,7 +1,8 @@
 use super::*;
+use qryvanta_domain::WorkflowInvocationMode;
 use serde::Serialize;
 use sha2::{Digest, Sha256};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 impl WorkflowService {
     /// Saves one workflow definition.
@@ -19,7 +20,19 @@ impl WorkflowService {
             trigger: input.trigger,
             steps: input.steps,
             max_attempts: input.max_attempts,
-        })?;
+        })?
+        .with_trigger_filters(input.trigger_filters)?
+        .with_trigger_scope(
+            input.trigger_changed_fields,
+            input.trigger_view_logical_name,
+        )?
+        .with_priority(input.priority);
+        self.validate_trigger_filter_fields(actor, &workflow)
+            .await?;
+        self.validate_trigger_view(actor, &workflow).await?;
+        self.validate_step_credentials(actor, &workflow).await?;
+        self.reject_circular_workflow_invocations(actor, &workflow)
+            .await?;
 
         self.repository
             .save_workflow(actor.tenant_id(), workflow.clone())
@@ -50,6 +63,125 @@ impl WorkflowService {
         Ok(workflow)
     }
 
+    async fn validate_trigger_filter_fields(
+        &self,
+        actor: &UserIdentity,
+        workflow: &WorkflowDefinition,
+    ) -> AppResult<()> {
+        let Some(entity_logical_name) = workflow.trigger().entity_logical_name() else {
+            return Ok(());
+        };
+        if workflow.trigger_filters().is_empty() && workflow.trigger_changed_fields().is_empty() {
+            return Ok(());
+        }
+
+        let field_names = self
+            .runtime_record_service
+            .published_entity_field_names(actor, entity_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "trigger filters require a published schema for entity '{entity_logical_name}'"
+                ))
+            })?;
+        for filter in workflow.trigger_filters() {
+            if !field_names
+                .iter()
+                .any(|field_name| field_name == &filter.field_logical_name)
+            {
+                return Err(AppError::Validation(format!(
+                    "trigger filter field '{}' does not exist on entity '{}'",
+                    filter.field_logical_name, entity_logical_name
+                )));
+            }
+        }
+        for changed_field in workflow.trigger_changed_fields() {
+            if !field_names.contains(changed_field) {
+                return Err(AppError::Validation(format!(
+                    "trigger changed field '{}' does not exist on entity '{}'",
+                    changed_field, entity_logical_name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn validate_trigger_view(
+        &self,
+        actor: &UserIdentity,
+        workflow: &WorkflowDefinition,
+    ) -> AppResult<()> {
+        let (Some(entity_logical_name), Some(view_logical_name)) = (
+            workflow.trigger().runtime_record_entity_logical_name(),
+            workflow.trigger_view_logical_name(),
+        ) else {
+            return Ok(());
+        };
+
+        let view = self
+            .runtime_record_service
+            .find_view_unchecked(actor, entity_logical_name, view_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "trigger view '{}' does not exist on entity '{}'",
+                    view_logical_name, entity_logical_name
+                ))
+            })?;
+        let filter_criteria = view.filter_criteria().ok_or_else(|| {
+            AppError::Validation(format!(
+                "trigger view '{view_logical_name}' has no filter criteria"
+            ))
+        })?;
+        if filter_criteria
+            .conditions()
+            .iter()
+            .any(|condition| condition.uses_subject_token())
+        {
+            return Err(AppError::Validation(format!(
+                "trigger view '{view_logical_name}' filters on the querying subject, which workflow triggers cannot resolve"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Rejects invoke workflow steps that lead back to the saved workflow through
+    /// the current drafts of the workflows they invoke.
+    async fn reject_circular_workflow_invocations(
+        &self,
+        actor: &UserIdentity,
+        workflow: &WorkflowDefinition,
+    ) -> AppResult<()> {
+        if workflow.invoked_workflow_logical_names().is_empty() {
+            return Ok(());
+        }
+
+        let workflows = self.repository.list_workflows(actor.tenant_id()).await?;
+        let mut invocations = workflows
+            .iter()
+            .map(|candidate| {
+                (
+                    candidate.logical_name().as_str(),
+                    candidate.invoked_workflow_logical_names(),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        let root = workflow.logical_name().as_str();
+        invocations.insert(root, workflow.invoked_workflow_logical_names());
+
+        let mut path = vec![root];
+        if find_invocation_cycle(&invocations, &mut path, &mut HashSet::new()) {
+            return Err(AppError::Validation(format!(
+                "workflow invocation cycle detected: {}",
+                path.join(" -> ")
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Publishes the current workflow draft as the next active immutable version.
     pub async fn publish_workflow(
         &self,
@@ -249,6 +381,36 @@ impl WorkflowService {
             }
         }
 
+        if self.execution_mode == WorkflowExecutionMode::Inline && workflow.contains_wait_steps() {
+            errors.push(format!(
+                "execution check failed: workflow '{}' uses wait steps, which require queued workflow execution mode",
+                workflow.logical_name().as_str()
+            ));
+        }
+
+        for invoked_workflow_logical_name in workflow.invoked_workflow_logical_names() {
+            let invoked_workflow = self
+                .repository
+                .find_published_workflow(actor.tenant_id(), invoked_workflow_logical_name)
+                .await?;
+            if invoked_workflow.is_none() {
+                errors.push(format!(
+                    "dependency check failed: workflow '{}' -> workflow '{}' requires a published version",
+                    workflow.logical_name().as_str(),
+                    invoked_workflow_logical_name
+                ));
+            }
+        }
+
+        if self.execution_mode == WorkflowExecutionMode::Inline
+            && workflow.steps().iter().any(contains_enqueued_invocation)
+        {
+            errors.push(format!(
+                "execution check failed: workflow '{}' enqueues invoked workflows, which requires queued workflow execution mode",
+                workflow.logical_name().as_str()
+            ));
+        }
+
         errors.extend(collect_workflow_governance_violations(&workflow));
 
         Ok(errors)
@@ -332,7 +494,15 @@ impl WorkflowService {
                 actor.subject(),
                 Permission::WorkflowManage,
             )
-            .await
+            .await?;
+
+        if let Some(feature_flag_service) = &self.feature_flag_service {
+            feature_flag_service
+                .require_enabled(actor, WORKFLOWS_FEATURE_FLAG, true)
+                .await?;
+        }
+
+        Ok(())
     }
 
     pub(super) async fn require_workflow_read(&self, actor: &UserIdentity) -> AppResult<()> {
@@ -345,7 +515,7 @@ impl WorkflowService {
 fn collect_workflow_entity_references(workflow: &WorkflowDefinition) -> Vec<String> {
     let mut referenced_entities = Vec::new();
 
-    if let Some(entity_logical_name) = workflow_entity_reference_from_trigger(workflow.trigger()) {
+    if let Some(entity_logical_name) = workflow.trigger().runtime_record_entity_logical_name() {
         referenced_entities.push(entity_logical_name.to_owned());
     }
 
@@ -362,26 +532,6 @@ fn collect_workflow_entity_references(workflow: &WorkflowDefinition) -> Vec<Stri
     unique_entities
 }
 
-fn workflow_entity_reference_from_trigger(trigger: &WorkflowTrigger) -> Option<&str> {
-    match trigger {
-        WorkflowTrigger::RuntimeRecordCreated {
-            entity_logical_name,
-        }
-        | WorkflowTrigger::RuntimeRecordUpdated {
-            entity_logical_name,
-        }
-        | WorkflowTrigger::RuntimeRecordDeleted {
-            entity_logical_name,
-        } => Some(entity_logical_name.as_str()),
-        WorkflowTrigger::Manual
-        | WorkflowTrigger::ScheduleTick { .. }
-        | WorkflowTrigger::WebhookReceived { .. }
-        | WorkflowTrigger::FormSubmitted { .. }
-        | WorkflowTrigger::InboundEmailReceived { .. }
-        | WorkflowTrigger::ApprovalEventReceived { .. } => None,
-    }
-}
-
 fn collect_step_entity_references(steps: &[WorkflowStep], referenced_entities: &mut Vec<String>) {
     for step in steps {
         match step {
@@ -413,15 +563,81 @@ fn collect_step_entity_references(steps: &[WorkflowStep], referenced_entities: &
                 collect_step_entity_references(then_steps, referenced_entities);
                 collect_step_entity_references(else_steps, referenced_entities);
             }
+            WorkflowStep::TryCatch {
+                try_steps,
+                catch_steps,
+                compensation_steps,
+            } => {
+                collect_step_entity_references(try_steps, referenced_entities);
+                collect_step_entity_references(catch_steps, referenced_entities);
+                collect_step_entity_references(compensation_steps, referenced_entities);
+            }
             WorkflowStep::LogMessage { .. }
             | WorkflowStep::SendEmail { .. }
             | WorkflowStep::HttpRequest { .. }
             | WorkflowStep::Webhook { .. }
-            | WorkflowStep::Delay { .. } => {}
+            | WorkflowStep::Delay { .. }
+            | WorkflowStep::Wait { .. }
+            | WorkflowStep::InvokeWorkflow { .. } => {}
         }
     }
 }
 
+/// Depth-first search for an invocation chain returning to the first workflow on `path`.
+///
+/// On success `path` holds the full cycle, starting and ending with that workflow.
+fn find_invocation_cycle<'a>(
+    invocations: &HashMap<&'a str, Vec<&'a str>>,
+    path: &mut Vec<&'a str>,
+    visited: &mut HashSet<&'a str>,
+) -> bool {
+    let (Some(&root), Some(&current)) = (path.first(), path.last()) else {
+        return false;
+    };
+
+    for &invoked in invocations.get(current).into_iter().flatten() {
+        if invoked == root {
+            path.push(invoked);
+            return true;
+        }
+        if !visited.insert(invoked) {
+            continue;
+        }
+
+        path.push(invoked);
+        if find_invocation_cycle(invocations, path, visited) {
+            return true;
+        }
+        path.pop();
+    }
+
+    false
+}
+
+fn contains_enqueued_invocation(step: &WorkflowStep) -> bool {
+    match step {
+        WorkflowStep::InvokeWorkflow { mode, .. } => *mode == WorkflowInvocationMode::Enqueued,
+        WorkflowStep::Condition {
+            then_steps,
+            else_steps,
+            ..
+        } => then_steps
+            .iter()
+            .chain(else_steps)
+            .any(contains_enqueued_invocation),
+        WorkflowStep::TryCatch {
+            try_steps,
+            catch_steps,
+            compensation_steps,
+        } => try_steps
+            .iter()
+            .chain(catch_steps)
+            .chain(compensation_steps)
+            .any(contains_enqueued_invocation),
+        _ => false,
+    }
+}
+
 fn collect_workflow_governance_violations(workflow: &WorkflowDefinition) -> Vec<String> {
     let mut violations = Vec::new();
     collect_step_governance_violations(
@@ -484,6 +700,24 @@ fn collect_step_governance_violations(
                     violations,
                 );
             }
+            WorkflowStep::TryCatch {
+                try_steps,
+                catch_steps,
+                compensation_steps,
+            } => {
+                for (branch, branch_steps) in [
+                    ("try", try_steps),
+                    ("catch", catch_steps),
+                    ("compensate", compensation_steps),
+                ] {
+                    collect_step_governance_violations(
+                        workflow_logical_name,
+                        branch_steps,
+                        format!("{step_path}.{branch}").as_str(),
+                        violations,
+                    );
+                }
+            }
             WorkflowStep::LogMessage { .. }
             | WorkflowStep::CreateRuntimeRecord { .. }
             | WorkflowStep::UpdateRuntimeRecord { .. }
@@ -491,7 +725,9 @@ fn collect_step_governance_violations(
             | WorkflowStep::SendEmail { .. }
             | WorkflowStep::AssignOwner { .. }
             | WorkflowStep::ApprovalRequest { .. }
-            | WorkflowStep::Delay { .. } => {}
+            | WorkflowStep::Delay { .. }
+            | WorkflowStep::Wait { .. }
+            | WorkflowStep::InvokeWorkflow { .. } => {}
         }
     }
 }
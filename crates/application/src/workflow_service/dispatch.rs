@@ -1,8 +1,10 @@
 use super::*;
-use crate::WorkflowScheduleTickDrainResult;
+use crate::{WorkflowScheduleKind, WorkflowScheduleTickDrainResult};
 use chrono::{Datelike, Timelike};
+use qryvanta_domain::CronSchedule;
 
 const SCHEDULE_CLOCK_SKEW_TOLERANCE_SECONDS: i64 = 300;
+const CRON_MISFIRE_WINDOW_HOURS: i64 = 24;
 
 struct ScheduleTickSlot {
     slot_key: String,
@@ -15,11 +17,29 @@ impl WorkflowService {
         actor: &UserIdentity,
         trigger: WorkflowTrigger,
         mut payload: Value,
+        idempotency_key: Option<&str>,
     ) -> AppResult<usize> {
-        let workflows = self
+        let candidates = self
             .repository
             .list_enabled_workflows_for_trigger(actor.tenant_id(), &trigger)
-            .await?;
+            .await?
+            .into_iter()
+            .filter(|workflow| {
+                workflow.trigger_filters_match(payload.get("previous"), payload.get("record"))
+                    && workflow.trigger_changed_fields_match(
+                        payload.get("previous"),
+                        payload.get("record"),
+                    )
+            });
+        let mut workflows = Vec::new();
+        for workflow in candidates {
+            if self
+                .trigger_view_matches(actor, &workflow, payload.get("record"))
+                .await?
+            {
+                workflows.push(workflow);
+            }
+        }
 
         if workflows.is_empty() {
             return Ok(0);
@@ -42,12 +62,24 @@ impl WorkflowService {
         for workflow in workflows {
             let result = match self.execution_mode {
                 WorkflowExecutionMode::Inline => {
-                    self.execute_workflow_definition(&workflow_actor, &workflow, payload.clone())
-                        .await
+                    self.execute_workflow_definition(
+                        &workflow_actor,
+                        &workflow,
+                        payload.clone(),
+                        None,
+                        idempotency_key,
+                    )
+                    .await
                 }
                 WorkflowExecutionMode::Queued => {
-                    self.enqueue_workflow_definition(&workflow_actor, &workflow, payload.clone())
-                        .await
+                    self.enqueue_workflow_definition(
+                        &workflow_actor,
+                        &workflow,
+                        payload.clone(),
+                        None,
+                        idempotency_key,
+                    )
+                    .await
                 }
             };
 
@@ -59,24 +91,93 @@ impl WorkflowService {
         Ok(executed)
     }
 
+    /// Returns whether the triggering record matches the workflow's trigger view.
+    ///
+    /// A view that was deleted or lost its filter criteria since the workflow
+    /// was saved matches nothing, so scoped deliveries never widen silently.
+    async fn trigger_view_matches(
+        &self,
+        actor: &UserIdentity,
+        workflow: &WorkflowDefinition,
+        record: Option<&Value>,
+    ) -> AppResult<bool> {
+        let (Some(entity_logical_name), Some(view_logical_name)) = (
+            workflow.trigger().runtime_record_entity_logical_name(),
+            workflow.trigger_view_logical_name(),
+        ) else {
+            return Ok(true);
+        };
+        let Some(record) = record else {
+            return Ok(false);
+        };
+
+        Ok(self
+            .runtime_record_service
+            .find_view_unchecked(actor, entity_logical_name, view_logical_name)
+            .await?
+            .and_then(|view| view.filter_criteria().cloned())
+            .is_some_and(|filter_criteria| filter_criteria.matches(record)))
+    }
+
     /// Executes a workflow by logical name using manual trigger context.
     pub async fn execute_workflow(
+        &self,
+        actor: &UserIdentity,
+        workflow_logical_name: &str,
+        trigger_payload: Value,
+    ) -> AppResult<WorkflowRun> {
+        self.execute_workflow_with_idempotency_key(
+            actor,
+            workflow_logical_name,
+            trigger_payload,
+            None,
+        )
+        .await
+    }
+
+    /// Executes a workflow by logical name, returning the run an earlier
+    /// request created under the same `idempotency_key` instead of starting
+    /// another one.
+    pub async fn execute_workflow_with_idempotency_key(
         &self,
         actor: &UserIdentity,
         workflow_logical_name: &str,
         mut trigger_payload: Value,
+        idempotency_key: Option<&str>,
     ) -> AppResult<WorkflowRun> {
         self.require_workflow_manage(actor).await?;
 
+        if let Some(payload_object) = trigger_payload.as_object_mut() {
+            payload_object
+                .entry("triggered_by".to_owned())
+                .or_insert_with(|| Value::String(actor.subject().to_owned()));
+        }
+
+        self.run_published_workflow(
+            actor.tenant_id(),
+            workflow_logical_name,
+            trigger_payload,
+            idempotency_key,
+        )
+        .await
+    }
+
+    /// Runs or enqueues one enabled published workflow regardless of its trigger.
+    pub(super) async fn run_published_workflow(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+        trigger_payload: Value,
+        idempotency_key: Option<&str>,
+    ) -> AppResult<WorkflowRun> {
         let workflow = self
             .repository
-            .find_published_workflow(actor.tenant_id(), workflow_logical_name)
+            .find_published_workflow(tenant_id, workflow_logical_name)
             .await?
             .ok_or_else(|| {
                 AppError::NotFound(format!(
                     "workflow '{}' does not have a published version for tenant '{}'",
-                    workflow_logical_name,
-                    actor.tenant_id()
+                    workflow_logical_name, tenant_id
                 ))
             })?;
 
@@ -87,27 +188,29 @@ impl WorkflowService {
             )));
         }
 
-        if let Some(payload_object) = trigger_payload.as_object_mut() {
-            payload_object
-                .entry("triggered_by".to_owned())
-                .or_insert_with(|| Value::String(actor.subject().to_owned()));
-        }
-
-        let workflow_actor = UserIdentity::new(
-            "workflow-runtime",
-            "workflow-runtime",
-            None,
-            actor.tenant_id(),
-        );
+        let workflow_actor =
+            UserIdentity::new("workflow-runtime", "workflow-runtime", None, tenant_id);
 
         match self.execution_mode {
             WorkflowExecutionMode::Inline => {
-                self.execute_workflow_definition(&workflow_actor, &workflow, trigger_payload)
-                    .await
+                self.execute_workflow_definition(
+                    &workflow_actor,
+                    &workflow,
+                    trigger_payload,
+                    None,
+                    idempotency_key,
+                )
+                .await
             }
             WorkflowExecutionMode::Queued => {
-                self.enqueue_workflow_definition(&workflow_actor, &workflow, trigger_payload)
-                    .await
+                self.enqueue_workflow_definition(
+                    &workflow_actor,
+                    &workflow,
+                    trigger_payload,
+                    None,
+                    idempotency_key,
+                )
+                .await
             }
         }
     }
@@ -145,6 +248,7 @@ impl WorkflowService {
                 entity_logical_name: entity_logical_name.to_owned(),
             },
             payload,
+            None,
         )
         .await
     }
@@ -174,6 +278,7 @@ impl WorkflowService {
                 entity_logical_name: entity_logical_name.to_owned(),
             },
             payload,
+            None,
         )
         .await
     }
@@ -201,6 +306,7 @@ impl WorkflowService {
                 entity_logical_name: entity_logical_name.to_owned(),
             },
             payload,
+            None,
         )
         .await
     }
@@ -211,6 +317,17 @@ impl WorkflowService {
         actor: &UserIdentity,
         schedule_key: &str,
         payload: Option<Value>,
+    ) -> AppResult<usize> {
+        self.dispatch_schedule_tick_with_idempotency_key(actor, schedule_key, payload, None)
+            .await
+    }
+
+    async fn dispatch_schedule_tick_with_idempotency_key(
+        &self,
+        actor: &UserIdentity,
+        schedule_key: &str,
+        payload: Option<Value>,
+        idempotency_key: Option<&str>,
     ) -> AppResult<usize> {
         let event_payload = Self::normalize_schedule_tick_payload(schedule_key, payload)?;
 
@@ -220,6 +337,7 @@ impl WorkflowService {
                 schedule_key: schedule_key.to_owned(),
             },
             event_payload,
+            idempotency_key,
         )
         .await
     }
@@ -255,6 +373,7 @@ impl WorkflowService {
                 webhook_key: webhook_key.to_owned(),
             },
             payload,
+            None,
         )
         .await
     }
@@ -289,6 +408,7 @@ impl WorkflowService {
                 form_key: form_key.to_owned(),
             },
             payload,
+            None,
         )
         .await
     }
@@ -323,6 +443,7 @@ impl WorkflowService {
                 mailbox_key: mailbox_key.to_owned(),
             },
             payload,
+            None,
         )
         .await
     }
@@ -358,6 +479,7 @@ impl WorkflowService {
                 approval_key: approval_key.to_owned(),
             },
             payload,
+            None,
         )
         .await
     }
@@ -381,8 +503,15 @@ impl WorkflowService {
         let mut result = WorkflowScheduleTickDrainResult::default();
 
         for trigger in triggers {
-            let Some(slot) = Self::due_schedule_tick_slot(trigger.schedule_key.as_str(), now)?
-            else {
+            let slot = match trigger.kind {
+                WorkflowScheduleKind::ScheduleTick => {
+                    Self::due_schedule_tick_slot(trigger.schedule_key.as_str(), now)?
+                }
+                WorkflowScheduleKind::Cron => {
+                    Self::due_cron_slot(trigger.schedule_key.as_str(), now)?
+                }
+            };
+            let Some(slot) = slot else {
                 continue;
             };
 
@@ -409,17 +538,43 @@ impl WorkflowService {
                 claimed.tenant_id,
             );
 
-            match self
-                .dispatch_schedule_tick(
-                    &scheduler_actor,
-                    claimed.schedule_key.as_str(),
-                    Some(serde_json::json!({
-                        "tick_at": claimed.scheduled_for.to_rfc3339(),
-                        "timezone": "UTC",
-                    })),
-                )
-                .await
-            {
+            // A slot re-claimed after its lease lapsed mid-dispatch reuses the
+            // key, so runs created before the lapse are not started twice. Run
+            // keys are scoped per workflow, and cron expressions contain
+            // spaces, so only the slot key goes into the key.
+            let idempotency_key = format!("schedule:{}", claimed.slot_key);
+            let dispatch_result = match trigger.kind {
+                WorkflowScheduleKind::ScheduleTick => {
+                    self.dispatch_schedule_tick_with_idempotency_key(
+                        &scheduler_actor,
+                        claimed.schedule_key.as_str(),
+                        Some(serde_json::json!({
+                            "tick_at": claimed.scheduled_for.to_rfc3339(),
+                            "timezone": "UTC",
+                        })),
+                        Some(idempotency_key.as_str()),
+                    )
+                    .await
+                }
+                WorkflowScheduleKind::Cron => {
+                    self.dispatch_trigger(
+                        &scheduler_actor,
+                        WorkflowTrigger::Scheduled {
+                            cron_expression: claimed.schedule_key.clone(),
+                        },
+                        serde_json::json!({
+                            "cron_expression": claimed.schedule_key,
+                            "event": "scheduled",
+                            "tick_at_utc": claimed.scheduled_for.to_rfc3339(),
+                            "timezone": "UTC",
+                        }),
+                        Some(idempotency_key.as_str()),
+                    )
+                    .await
+                }
+            };
+
+            match dispatch_result {
                 Ok(dispatched) => {
                     result.dispatched_workflows += dispatched;
                     self.repository
@@ -580,6 +735,66 @@ impl WorkflowService {
         }
     }
 
+    /// Returns the latest cron occurrence at or before `now` inside the misfire window.
+    ///
+    /// Older missed occurrences are skipped rather than replayed.
+    fn due_cron_slot(
+        cron_expression: &str,
+        now: chrono::DateTime<Utc>,
+    ) -> AppResult<Option<ScheduleTickSlot>> {
+        let schedule = CronSchedule::parse(cron_expression)?;
+        let today = now.date_naive();
+
+        for day_offset in 0..=CRON_MISFIRE_WINDOW_HOURS / 24 {
+            let date = today - chrono::Duration::days(day_offset);
+            if !schedule.matches_date(
+                date.day(),
+                date.month(),
+                date.weekday().num_days_from_sunday(),
+            ) {
+                continue;
+            }
+
+            let latest_hour = if day_offset == 0 { now.hour() } else { 23 };
+            for hour in (0..=latest_hour).rev() {
+                if !schedule.matches_hour(hour) {
+                    continue;
+                }
+
+                let latest_minute = if day_offset == 0 && hour == now.hour() {
+                    now.minute()
+                } else {
+                    59
+                };
+                let Some(minute) = (0..=latest_minute)
+                    .rev()
+                    .find(|minute| schedule.matches_minute(*minute))
+                else {
+                    continue;
+                };
+
+                let tick_at_utc = date
+                    .and_hms_opt(hour, minute, 0)
+                    .map(|value| chrono::DateTime::<Utc>::from_naive_utc_and_offset(value, Utc))
+                    .ok_or_else(|| {
+                        AppError::Internal(format!(
+                            "failed to normalize cron occurrence for '{cron_expression}'"
+                        ))
+                    })?;
+                if now - tick_at_utc > chrono::Duration::hours(CRON_MISFIRE_WINDOW_HOURS) {
+                    return Ok(None);
+                }
+
+                return Ok(Some(ScheduleTickSlot {
+                    slot_key: tick_at_utc.format("cron:%Y%m%d%H%M").to_string(),
+                    tick_at_utc,
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
     fn parse_utc_time_schedule_key(schedule_key: &str) -> AppResult<Option<(u32, u32, bool)>> {
         let (raw_time, weekdays_only, prefix) =
             if let Some(raw_time) = schedule_key.strip_prefix("daily_utc_") {
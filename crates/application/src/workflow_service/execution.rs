@@ -1,5 +1,11 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
 use super::*;
-use crate::workflow_ports::WorkflowRunStepTrace;
+use chrono::DateTime;
+
+use crate::workflow_ports::{WorkflowRunContinuation, WorkflowRunStepTrace};
+use qryvanta_domain::validate_idempotency_key;
 
 mod actions;
 mod trace;
@@ -12,6 +18,83 @@ struct WorkflowExecutionContext<'a> {
     trigger_entity_logical_name: Option<&'a str>,
     run_id: &'a str,
     attempt_number: i32,
+    step_outputs: &'a Value,
+    related_records: &'a WorkflowRelatedRecordCache,
+}
+
+/// Related records loaded by `related.` condition paths, shared by every attempt of a run.
+#[derive(Default)]
+struct WorkflowRelatedRecordCache {
+    records: Mutex<HashMap<WorkflowRelatedRecordKey, Option<RuntimeRecord>>>,
+}
+
+/// Source entity, relation field and related record id of one lookup.
+type WorkflowRelatedRecordKey = (String, String, String);
+
+impl WorkflowRelatedRecordCache {
+    fn get(&self, key: &WorkflowRelatedRecordKey) -> AppResult<Option<Option<RuntimeRecord>>> {
+        Ok(self.lock()?.get(key).cloned())
+    }
+
+    fn insert(
+        &self,
+        key: WorkflowRelatedRecordKey,
+        record: Option<RuntimeRecord>,
+    ) -> AppResult<()> {
+        self.lock()?.insert(key, record);
+        Ok(())
+    }
+
+    fn lock(
+        &self,
+    ) -> AppResult<
+        std::sync::MutexGuard<'_, HashMap<WorkflowRelatedRecordKey, Option<RuntimeRecord>>>,
+    > {
+        self.records.lock().map_err(|_| {
+            AppError::Internal("workflow related record cache lock is poisoned".to_owned())
+        })
+    }
+}
+
+/// Values produced by one executed step that later steps may reference.
+#[derive(Default)]
+struct WorkflowStepResult {
+    /// Identifier of the record created by the step.
+    record_id: Option<String>,
+    /// Remote response captured from an HTTP-based dispatch.
+    response: Option<crate::workflow_ports::WorkflowActionDispatchResponse>,
+    /// Resume time requested by a wait step that suspends the run.
+    resume_at: Option<DateTime<Utc>>,
+    /// Child run started by an invoke workflow step.
+    invoked_run: Option<WorkflowRun>,
+}
+
+/// Point where a wait step paused the run.
+struct WorkflowWaitSuspension {
+    step_path: String,
+    resume_at: DateTime<Utc>,
+}
+
+/// Enclosing step list to continue when a suspended run resumes.
+struct WorkflowResumeFrame<'a> {
+    steps: &'a [WorkflowStep],
+    path_prefix: String,
+    start_index: usize,
+}
+
+/// Outcome of executing a run until it finishes or reaches a wait step.
+pub(super) enum WorkflowRunExecution {
+    Completed(WorkflowRun),
+    Suspended {
+        attempts: i32,
+        continuation: WorkflowRunContinuation,
+    },
+}
+
+/// Run returned by run creation, and whether an earlier request already created it.
+enum WorkflowRunCreation {
+    Created(WorkflowRun),
+    Existing(WorkflowRun),
 }
 
 impl WorkflowService {
@@ -20,31 +103,47 @@ impl WorkflowService {
         actor: &UserIdentity,
         workflow: &WorkflowDefinition,
         trigger_payload: Value,
+        parent_run_id: Option<&str>,
+        idempotency_key: Option<&str>,
     ) -> AppResult<WorkflowRun> {
-        let run = self
-            .repository
-            .create_run(
-                actor.tenant_id(),
-                CreateWorkflowRunInput {
-                    workflow_logical_name: workflow.logical_name().as_str().to_owned(),
-                    workflow_version: workflow.published_version().ok_or_else(|| {
-                        AppError::Conflict(format!(
-                            "workflow '{}' must be published before execution",
-                            workflow.logical_name().as_str()
-                        ))
-                    })?,
-                    trigger_type: workflow.trigger().trigger_type().to_owned(),
-                    trigger_entity_logical_name: workflow
-                        .trigger()
-                        .entity_logical_name()
-                        .map(ToOwned::to_owned),
-                    trigger_payload: trigger_payload.clone(),
-                },
+        let run = match self
+            .create_workflow_run(
+                actor,
+                workflow,
+                trigger_payload.clone(),
+                parent_run_id,
+                idempotency_key,
+            )
+            .await?
+        {
+            WorkflowRunCreation::Created(run) => run,
+            WorkflowRunCreation::Existing(run) => return Ok(run),
+        };
+
+        let dispatch_deadline = self
+            .operation_deadlines
+            .as_ref()
+            .and_then(|operation_deadlines| {
+                operation_deadlines.start(TimedOperation::WorkflowDispatch)
+            });
+        let execution = self
+            .execute_existing_run(
+                actor,
+                workflow,
+                run.run_id.as_str(),
+                trigger_payload,
+                None,
+                dispatch_deadline,
             )
             .await?;
 
-        self.execute_existing_run(actor, workflow, run.run_id.as_str(), trigger_payload)
-            .await
+        match execution {
+            WorkflowRunExecution::Completed(run) => Ok(run),
+            WorkflowRunExecution::Suspended { .. } => Err(AppError::Conflict(format!(
+                "workflow '{}' reached a wait step outside queued execution",
+                workflow.logical_name().as_str()
+            ))),
+        }
     }
 
     pub(super) async fn enqueue_workflow_definition(
@@ -52,17 +151,74 @@ impl WorkflowService {
         actor: &UserIdentity,
         workflow: &WorkflowDefinition,
         trigger_payload: Value,
+        parent_run_id: Option<&str>,
+        idempotency_key: Option<&str>,
     ) -> AppResult<WorkflowRun> {
-        let run = self
+        let run = match self
+            .create_workflow_run(
+                actor,
+                workflow,
+                trigger_payload,
+                parent_run_id,
+                idempotency_key,
+            )
+            .await?
+        {
+            WorkflowRunCreation::Created(run) => run,
+            // The first request may have created the run and then failed to
+            // enqueue it. Enqueueing is idempotent per run, so a retry makes
+            // sure a run that has not started yet still gets its job.
+            WorkflowRunCreation::Existing(run) if run.status == WorkflowRunStatus::Running => run,
+            WorkflowRunCreation::Existing(run) => return Ok(run),
+        };
+
+        self.repository
+            .enqueue_run_job(actor.tenant_id(), run.run_id.as_str())
+            .await?;
+        self.invalidate_queue_stats_cache().await;
+
+        Ok(run)
+    }
+
+    /// Creates a run for a published workflow.
+    ///
+    /// When a run was already created for the workflow under `idempotency_key`,
+    /// that run is returned instead, including when a concurrent request wins
+    /// the insert.
+    async fn create_workflow_run(
+        &self,
+        actor: &UserIdentity,
+        workflow: &WorkflowDefinition,
+        trigger_payload: Value,
+        parent_run_id: Option<&str>,
+        idempotency_key: Option<&str>,
+    ) -> AppResult<WorkflowRunCreation> {
+        let workflow_logical_name = workflow.logical_name().as_str();
+        if let Some(idempotency_key) = idempotency_key {
+            validate_idempotency_key(idempotency_key)?;
+            if let Some(run) = self
+                .repository
+                .find_run_by_idempotency_key(
+                    actor.tenant_id(),
+                    workflow_logical_name,
+                    idempotency_key,
+                )
+                .await?
+            {
+                return Ok(WorkflowRunCreation::Existing(run));
+            }
+        }
+
+        let created = self
             .repository
             .create_run(
                 actor.tenant_id(),
                 CreateWorkflowRunInput {
-                    workflow_logical_name: workflow.logical_name().as_str().to_owned(),
+                    workflow_logical_name: workflow_logical_name.to_owned(),
                     workflow_version: workflow.published_version().ok_or_else(|| {
                         AppError::Conflict(format!(
                             "workflow '{}' must be published before execution",
-                            workflow.logical_name().as_str()
+                            workflow_logical_name
                         ))
                     })?,
                     trigger_type: workflow.trigger().trigger_type().to_owned(),
@@ -71,53 +227,106 @@ impl WorkflowService {
                         .entity_logical_name()
                         .map(ToOwned::to_owned),
                     trigger_payload,
+                    parent_run_id: parent_run_id.map(ToOwned::to_owned),
+                    trace_context: self.current_trace_context(),
+                    priority: workflow.priority(),
+                    idempotency_key: idempotency_key.map(ToOwned::to_owned),
                 },
             )
-            .await?;
-
-        self.repository
-            .enqueue_run_job(actor.tenant_id(), run.run_id.as_str())
-            .await?;
+            .await;
 
-        Ok(run)
+        match (created, idempotency_key) {
+            (Ok(run), _) => Ok(WorkflowRunCreation::Created(run)),
+            (Err(AppError::Conflict(message)), Some(idempotency_key)) => self
+                .repository
+                .find_run_by_idempotency_key(
+                    actor.tenant_id(),
+                    workflow_logical_name,
+                    idempotency_key,
+                )
+                .await?
+                .map(WorkflowRunCreation::Existing)
+                .ok_or(AppError::Conflict(message)),
+            (Err(error), _) => Err(error),
+        }
     }
 
+    /// Executes a run's attempts, resuming after the continuation's wait step when given.
+    ///
+    /// Attempt numbers continue from the attempt that suspended, and each resumed
+    /// attempt gets the full `max_attempts` budget again.
     pub(super) async fn execute_existing_run(
         &self,
         actor: &UserIdentity,
         workflow: &WorkflowDefinition,
         run_id: &str,
         trigger_payload: Value,
-    ) -> AppResult<WorkflowRun> {
+        continuation: Option<&WorkflowRunContinuation>,
+        deadline: Option<OperationDeadline>,
+    ) -> AppResult<WorkflowRunExecution> {
         let mut last_error: Option<String> = None;
+        let (first_attempt, base_step_outputs, resume_after_step_path) = match continuation {
+            Some(continuation) => (
+                continuation.attempt_number + 1,
+                continuation.step_outputs.clone(),
+                Some(continuation.resume_after_step_path.as_str()),
+            ),
+            None => (1, Value::Null, None),
+        };
+        let last_attempt = first_attempt - 1 + i32::from(workflow.max_attempts());
+        let related_records = WorkflowRelatedRecordCache::default();
 
-        for attempt_number in 1..=i32::from(workflow.max_attempts()) {
+        for attempt_number in first_attempt..=last_attempt {
             let context = WorkflowExecutionContext {
                 trigger_payload: &trigger_payload,
                 trigger_type: workflow.trigger().trigger_type(),
                 trigger_entity_logical_name: workflow.trigger().entity_logical_name(),
                 run_id,
                 attempt_number,
+                step_outputs: &base_step_outputs,
+                related_records: &related_records,
+            };
+            // Only the steps run inside the deadline; attempt and run bookkeeping
+            // must still be written after the budget is spent.
+            let steps = self.execute_workflow_steps_with_trace(
+                actor,
+                workflow,
+                context,
+                resume_after_step_path,
+            );
+            let attempt_result = match deadline {
+                Some(deadline) => deadline.scope(steps).await,
+                None => steps.await,
             };
-            let attempt_result = self
-                .execute_workflow_steps_with_trace(actor, workflow, context)
-                .await;
-            let (status, error_message, step_traces) = match attempt_result {
-                Ok(step_traces) => (
-                    WorkflowRunAttemptStatus::Succeeded,
+            let mut timeout_reason = None;
+            let (status, error_message, step_traces, suspension) = match attempt_result {
+                Ok((step_traces, suspension)) => (
+                    if suspension.is_some() {
+                        WorkflowRunAttemptStatus::Waiting
+                    } else {
+                        WorkflowRunAttemptStatus::Succeeded
+                    },
                     None::<String>,
                     step_traces,
+                    suspension,
                 ),
                 Err(error_with_trace) => {
+                    if let AppError::Timeout(reason) = &error_with_trace.error {
+                        timeout_reason = Some(reason.clone());
+                    }
                     let message = error_with_trace.error.to_string();
                     last_error = Some(message.clone());
                     (
                         WorkflowRunAttemptStatus::Failed,
                         Some(message),
                         error_with_trace.step_traces,
+                        None,
                     )
                 }
             };
+            let step_outputs = suspension
+                .as_ref()
+                .map(|_| Self::step_outputs_from_traces(&base_step_outputs, &step_traces));
 
             self.repository
                 .append_run_attempt(
@@ -133,6 +342,36 @@ impl WorkflowService {
                 )
                 .await?;
 
+            if let Some(reason) = timeout_reason {
+                // A spent budget fails every further attempt, so stop retrying.
+                let dead_lettered_run = self
+                    .repository
+                    .complete_run(
+                        actor.tenant_id(),
+                        CompleteWorkflowRunInput {
+                            run_id: run_id.to_owned(),
+                            status: WorkflowRunStatus::DeadLettered,
+                            attempts: attempt_number,
+                            dead_letter_reason: Some(reason.clone()),
+                        },
+                    )
+                    .await?;
+                self.append_run_audit(actor, &dead_lettered_run).await?;
+                return Err(AppError::Timeout(reason));
+            }
+
+            if let (Some(suspension), Some(step_outputs)) = (suspension, step_outputs) {
+                return Ok(WorkflowRunExecution::Suspended {
+                    attempts: attempt_number,
+                    continuation: WorkflowRunContinuation {
+                        resume_after_step_path: suspension.step_path,
+                        resume_at: suspension.resume_at,
+                        attempt_number,
+                        step_outputs,
+                    },
+                });
+            }
+
             if status == WorkflowRunAttemptStatus::Succeeded {
                 let completed_run = self
                     .repository
@@ -148,7 +387,7 @@ impl WorkflowService {
                     .await?;
 
                 self.append_run_audit(actor, &completed_run).await?;
-                return Ok(completed_run);
+                return Ok(WorkflowRunExecution::Completed(completed_run));
             }
         }
 
@@ -159,14 +398,14 @@ impl WorkflowService {
                 CompleteWorkflowRunInput {
                     run_id: run_id.to_owned(),
                     status: WorkflowRunStatus::DeadLettered,
-                    attempts: i32::from(workflow.max_attempts()),
+                    attempts: last_attempt,
                     dead_letter_reason: last_error,
                 },
             )
             .await?;
 
         self.append_run_audit(actor, &completed_run).await?;
-        Ok(completed_run)
+        Ok(WorkflowRunExecution::Completed(completed_run))
     }
 
     pub(super) async fn retry_step_for_run(
@@ -178,6 +417,20 @@ impl WorkflowService {
     ) -> AppResult<WorkflowRun> {
         let attempt_number = run.attempts + 1;
         let mut traces = Vec::new();
+        let related_records = WorkflowRelatedRecordCache::default();
+        let previous_attempt = self
+            .repository
+            .list_run_attempts(actor.tenant_id(), run.run_id.as_str())
+            .await?
+            .into_iter()
+            .max_by_key(|attempt| attempt.attempt_number);
+        let previous_step_outputs = Self::step_outputs_from_traces(
+            &Value::Null,
+            previous_attempt
+                .as_ref()
+                .map(|attempt| attempt.step_traces.as_slice())
+                .unwrap_or_default(),
+        );
 
         let result = self
             .execute_single_step_path_with_trace(
@@ -189,6 +442,8 @@ impl WorkflowService {
                     trigger_entity_logical_name: workflow.trigger().entity_logical_name(),
                     run_id: run.run_id.as_str(),
                     attempt_number,
+                    step_outputs: &previous_step_outputs,
+                    related_records: &related_records,
                 },
                 step_path,
                 &mut traces,
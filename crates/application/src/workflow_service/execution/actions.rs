@@ -1,5 +1,9 @@
 use super::*;
-use crate::workflow_ports::{WorkflowActionDispatchRequest, WorkflowActionDispatchType};
+use crate::workflow_ports::{
+    WorkflowActionCredential, WorkflowActionDispatchRequest, WorkflowActionDispatchResponse,
+    WorkflowActionDispatchType,
+};
+use qryvanta_domain::{WORKFLOW_INVOCATION_MAX_DEPTH, WorkflowInvocationMode};
 use serde_json::Value;
 
 impl WorkflowService {
@@ -7,10 +11,11 @@ impl WorkflowService {
         &self,
         dispatch_type: WorkflowActionDispatchType,
         payload: Value,
+        credential: Option<WorkflowActionCredential>,
         context: WorkflowExecutionContext<'_>,
         step_path: &str,
         step_type: &str,
-    ) -> AppResult<()> {
+    ) -> AppResult<Option<WorkflowActionDispatchResponse>> {
         let Some(dispatcher) = self.action_dispatcher.clone() else {
             return Err(AppError::Validation(format!(
                 "workflow action '{step_type}' requires configured integration dispatcher"
@@ -23,35 +28,161 @@ impl WorkflowService {
             step_path: step_path.to_owned(),
             idempotency_key: format!("{}:{}", context.run_id, step_path),
             payload,
+            credential,
         };
 
         dispatcher.dispatch_action(request).await
     }
 
+    async fn require_marketing_email_consent(
+        &self,
+        actor: &UserIdentity,
+        recipient: &str,
+    ) -> AppResult<()> {
+        let Some(contact_consent_repository) = &self.contact_consent_repository else {
+            return Ok(());
+        };
+
+        let consent = contact_consent_repository
+            .email_consent_for_address(actor.tenant_id(), recipient, MARKETING_CONSENT_PURPOSE)
+            .await?;
+        if consent == Some(false) {
+            return Err(AppError::Forbidden(format!(
+                "email recipient '{recipient}' is a contact without active '{MARKETING_CONSENT_PURPOSE}' email consent"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Starts a child run of a published workflow linked to the current run.
+    ///
+    /// Synchronous invocations fail when the child run dead-letters; enqueued
+    /// invocations return the running child run immediately.
+    async fn invoke_workflow(
+        &self,
+        actor: &UserIdentity,
+        workflow_logical_name: &str,
+        payload: Value,
+        mode: WorkflowInvocationMode,
+        context: WorkflowExecutionContext<'_>,
+    ) -> AppResult<WorkflowRun> {
+        let depth = self
+            .workflow_invocation_depth(actor, context.run_id)
+            .await?
+            + 1;
+        if depth > WORKFLOW_INVOCATION_MAX_DEPTH {
+            return Err(AppError::Conflict(format!(
+                "invoking workflow '{workflow_logical_name}' exceeds the maximum invocation depth of {WORKFLOW_INVOCATION_MAX_DEPTH}"
+            )));
+        }
+
+        let workflow = self
+            .repository
+            .find_published_workflow(actor.tenant_id(), workflow_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "invoked workflow '{workflow_logical_name}' does not have a published version"
+                ))
+            })?;
+        if !workflow.is_enabled() {
+            return Err(AppError::Conflict(format!(
+                "invoked workflow '{workflow_logical_name}' is disabled"
+            )));
+        }
+
+        match mode {
+            WorkflowInvocationMode::Enqueued => {
+                self.enqueue_workflow_definition(
+                    actor,
+                    &workflow,
+                    payload,
+                    Some(context.run_id),
+                    None,
+                )
+                .await
+            }
+            WorkflowInvocationMode::Synchronous => {
+                let child_run = self
+                    .execute_workflow_definition(
+                        actor,
+                        &workflow,
+                        payload,
+                        Some(context.run_id),
+                        None,
+                    )
+                    .await?;
+                if child_run.status == WorkflowRunStatus::DeadLettered {
+                    return Err(AppError::Conflict(format!(
+                        "invoked workflow '{workflow_logical_name}' run '{}' failed: {}",
+                        child_run.run_id,
+                        child_run
+                            .dead_letter_reason
+                            .as_deref()
+                            .unwrap_or("unknown error")
+                    )));
+                }
+
+                Ok(child_run)
+            }
+        }
+    }
+
+    /// Counts the ancestors of a run, stopping once the depth limit is reached.
+    async fn workflow_invocation_depth(
+        &self,
+        actor: &UserIdentity,
+        run_id: &str,
+    ) -> AppResult<usize> {
+        let mut depth = 0;
+        let mut current_run_id = run_id.to_owned();
+        while depth < WORKFLOW_INVOCATION_MAX_DEPTH {
+            let Some(parent_run_id) = self
+                .repository
+                .find_run(actor.tenant_id(), current_run_id.as_str())
+                .await?
+                .and_then(|run| run.parent_run_id)
+            else {
+                break;
+            };
+            depth += 1;
+            current_run_id = parent_run_id;
+        }
+
+        Ok(depth)
+    }
+
     pub(super) async fn execute_action(
         &self,
         actor: &UserIdentity,
         step: &WorkflowStep,
-    ) -> AppResult<()> {
+    ) -> AppResult<WorkflowStepResult> {
         match step {
-            WorkflowStep::LogMessage { .. } => Ok(()),
+            WorkflowStep::LogMessage { .. } => Ok(WorkflowStepResult::default()),
             WorkflowStep::CreateRuntimeRecord {
                 entity_logical_name,
                 data,
+                ..
             } => {
-                self.runtime_record_service
+                let record = self
+                    .runtime_record_service
                     .create_runtime_record_unchecked(
                         actor,
                         entity_logical_name.as_str(),
                         data.clone(),
                     )
                     .await?;
-                Ok(())
+                Ok(WorkflowStepResult {
+                    record_id: Some(record.record_id().as_str().to_owned()),
+                    ..WorkflowStepResult::default()
+                })
             }
             WorkflowStep::UpdateRuntimeRecord {
                 entity_logical_name,
                 record_id,
                 data,
+                retry_policy: _,
             } => {
                 self.runtime_record_service
                     .update_runtime_record_unchecked(
@@ -61,11 +192,12 @@ impl WorkflowService {
                         data.clone(),
                     )
                     .await?;
-                Ok(())
+                Ok(WorkflowStepResult::default())
             }
             WorkflowStep::DeleteRuntimeRecord {
                 entity_logical_name,
                 record_id,
+                retry_policy: _,
             } => {
                 self.runtime_record_service
                     .delete_runtime_record_unchecked(
@@ -74,12 +206,14 @@ impl WorkflowService {
                         record_id.as_str(),
                     )
                     .await?;
-                Ok(())
+                Ok(WorkflowStepResult::default())
             }
             WorkflowStep::SendEmail { .. }
             | WorkflowStep::HttpRequest { .. }
             | WorkflowStep::Webhook { .. }
-            | WorkflowStep::Delay { .. } => Err(AppError::Validation(
+            | WorkflowStep::Delay { .. }
+            | WorkflowStep::Wait { .. }
+            | WorkflowStep::InvokeWorkflow { .. } => Err(AppError::Validation(
                 "native integration steps require execution context".to_owned(),
             )),
             WorkflowStep::AssignOwner {
@@ -87,6 +221,7 @@ impl WorkflowService {
                 record_id,
                 owner_id,
                 reason,
+                retry_policy: _,
             } => {
                 self.runtime_record_service
                     .create_runtime_record_unchecked(
@@ -100,7 +235,7 @@ impl WorkflowService {
                         }),
                     )
                     .await?;
-                Ok(())
+                Ok(WorkflowStepResult::default())
             }
             WorkflowStep::ApprovalRequest {
                 entity_logical_name,
@@ -110,6 +245,7 @@ impl WorkflowService {
                 approver_id,
                 reason,
                 payload,
+                retry_policy: _,
             } => {
                 self.runtime_record_service
                     .create_runtime_record_unchecked(
@@ -127,51 +263,67 @@ impl WorkflowService {
                         }),
                     )
                     .await?;
-                Ok(())
+                Ok(WorkflowStepResult::default())
             }
+            WorkflowStep::TryCatch { .. } => Err(AppError::Validation(
+                "try_catch step cannot execute as an action".to_owned(),
+            )),
             WorkflowStep::Condition { .. } => Err(AppError::Validation(
                 "condition step cannot execute as an action".to_owned(),
             )),
         }
     }
 
+    /// Executes one interpolated step and returns the values it produced.
     pub(super) async fn execute_resolved_step(
         &self,
         actor: &UserIdentity,
         step: &WorkflowStep,
         context: WorkflowExecutionContext<'_>,
         step_path: &str,
-    ) -> AppResult<()> {
+    ) -> AppResult<WorkflowStepResult> {
         match step {
             WorkflowStep::SendEmail {
                 to,
                 subject,
                 body,
                 html_body,
+                retry_policy: _,
             } => {
-                return self
-                    .dispatch_external_action(
-                        WorkflowActionDispatchType::Email,
-                        serde_json::json!({
-                            "to": to,
-                            "subject": subject,
-                            "body": body,
-                            "html_body": html_body,
-                        }),
-                        context,
-                        step_path,
-                        "send_email",
-                    )
-                    .await;
+                self.require_marketing_email_consent(actor, to.as_str())
+                    .await?;
+
+                self.dispatch_external_action(
+                    WorkflowActionDispatchType::Email,
+                    serde_json::json!({
+                        "to": to,
+                        "subject": subject,
+                        "body": body,
+                        "html_body": html_body,
+                    }),
+                    None,
+                    context,
+                    step_path,
+                    "send_email",
+                )
+                .await?;
+                return Ok(WorkflowStepResult::default());
             }
             WorkflowStep::HttpRequest {
                 method,
                 url,
                 headers,
                 header_secret_refs,
+                credential,
                 body,
+                expected_status,
+                retry,
+                ..
             } => {
-                return self
+                let credential = self
+                    .resolve_action_credential(actor.tenant_id(), credential.as_deref())
+                    .await?;
+                let response = self
                     .dispatch_external_action(
                         WorkflowActionDispatchType::HttpRequest,
                         serde_json::json!({
@@ -180,35 +332,48 @@ impl WorkflowService {
                             "headers": headers,
                             "header_secret_refs": header_secret_refs,
                             "body": body,
+                            "expected_status": expected_status,
+                            "retry": retry,
                         }),
+                        credential,
                         context,
                         step_path,
                         "http_request",
                     )
-                    .await;
+                    .await?;
+                return Ok(WorkflowStepResult {
+                    response,
+                    ..WorkflowStepResult::default()
+                });
             }
             WorkflowStep::Webhook {
                 endpoint,
                 event,
                 headers,
                 header_secret_refs,
+                credential,
                 payload,
+                retry_policy: _,
             } => {
-                return self
-                    .dispatch_external_action(
-                        WorkflowActionDispatchType::Webhook,
-                        serde_json::json!({
-                            "endpoint": endpoint,
-                            "event": event,
-                            "headers": headers,
-                            "header_secret_refs": header_secret_refs,
-                            "payload": payload,
-                        }),
-                        context,
-                        step_path,
-                        "webhook",
-                    )
-                    .await;
+                let credential = self
+                    .resolve_action_credential(actor.tenant_id(), credential.as_deref())
+                    .await?;
+                self.dispatch_external_action(
+                    WorkflowActionDispatchType::Webhook,
+                    serde_json::json!({
+                        "endpoint": endpoint,
+                        "event": event,
+                        "headers": headers,
+                        "header_secret_refs": header_secret_refs,
+                        "payload": payload,
+                    }),
+                    credential,
+                    context,
+                    step_path,
+                    "webhook",
+                )
+                .await?;
+                return Ok(WorkflowStepResult::default());
             }
             WorkflowStep::Delay { duration_ms, .. } => {
                 let Some(delay_service) = self.delay_service.clone() else {
@@ -218,7 +383,45 @@ impl WorkflowService {
                 };
 
                 delay_service.sleep(*duration_ms).await?;
-                return Ok(());
+                return Ok(WorkflowStepResult::default());
+            }
+            WorkflowStep::Wait {
+                duration_seconds,
+                until_field_path,
+                ..
+            } => {
+                if self.execution_mode != WorkflowExecutionMode::Queued {
+                    return Err(AppError::Conflict(
+                        "workflow action 'wait' requires queued workflow execution mode".to_owned(),
+                    ));
+                }
+
+                let resume_at =
+                    Self::wait_resume_at(*duration_seconds, until_field_path.as_deref(), context)?;
+                return Ok(WorkflowStepResult {
+                    resume_at: (resume_at > Utc::now()).then_some(resume_at),
+                    ..WorkflowStepResult::default()
+                });
+            }
+            WorkflowStep::InvokeWorkflow {
+                workflow_logical_name,
+                payload,
+                mode,
+                ..
+            } => {
+                let invoked_run = self
+                    .invoke_workflow(
+                        actor,
+                        workflow_logical_name.as_str(),
+                        payload.clone(),
+                        *mode,
+                        context,
+                    )
+                    .await?;
+                return Ok(WorkflowStepResult {
+                    invoked_run: Some(invoked_run),
+                    ..WorkflowStepResult::default()
+                });
             }
             WorkflowStep::LogMessage { .. }
             | WorkflowStep::CreateRuntimeRecord { .. }
@@ -226,6 +429,7 @@ impl WorkflowService {
             | WorkflowStep::DeleteRuntimeRecord { .. }
             | WorkflowStep::AssignOwner { .. }
             | WorkflowStep::ApprovalRequest { .. }
+            | WorkflowStep::TryCatch { .. }
             | WorkflowStep::Condition { .. } => {}
         }
 
@@ -2,18 +2,100 @@ use super::*;
 
 use std::time::Instant;
 
+use crate::operation_timeouts::ensure_operation_budget_remaining;
+
 impl WorkflowService {
+    /// Executes the workflow steps, or only those after `resume_after_step_path` when resuming.
+    ///
+    /// Returns the suspension point when a wait step paused the run.
     pub(super) async fn execute_workflow_steps_with_trace(
         &self,
         actor: &UserIdentity,
         workflow: &WorkflowDefinition,
         context: WorkflowExecutionContext<'_>,
-    ) -> Result<Vec<WorkflowRunStepTrace>, WorkflowExecutionErrorWithTrace> {
+        resume_after_step_path: Option<&str>,
+    ) -> Result<
+        (Vec<WorkflowRunStepTrace>, Option<WorkflowWaitSuspension>),
+        WorkflowExecutionErrorWithTrace,
+    > {
         let mut traces = Vec::new();
 
-        self.execute_steps_with_trace(actor, workflow.steps(), context, "", &mut traces)
-            .await?;
-        Ok(traces)
+        let Some(resume_after_step_path) = resume_after_step_path else {
+            let suspension = self
+                .execute_steps_with_trace(actor, workflow.steps(), context, "", 0, &mut traces)
+                .await?;
+            return Ok((traces, suspension));
+        };
+
+        let frames =
+            Self::resume_frames(workflow.steps(), resume_after_step_path).map_err(|error| {
+                WorkflowExecutionErrorWithTrace {
+                    error,
+                    step_traces: Vec::new(),
+                }
+            })?;
+        for frame in frames.iter().rev() {
+            let suspension = self
+                .execute_steps_with_trace(
+                    actor,
+                    frame.steps,
+                    context,
+                    frame.path_prefix.as_str(),
+                    frame.start_index,
+                    &mut traces,
+                )
+                .await?;
+            if suspension.is_some() {
+                return Ok((traces, suspension));
+            }
+        }
+
+        Ok((traces, None))
+    }
+
+    /// Splits a wait step path into the enclosing step lists, innermost last.
+    ///
+    /// Each frame continues its list after the step on the path, so resuming skips
+    /// the wait step itself and never re-evaluates the conditions around it.
+    fn resume_frames<'a>(
+        steps: &'a [WorkflowStep],
+        step_path: &str,
+    ) -> AppResult<Vec<WorkflowResumeFrame<'a>>> {
+        let invalid_path = || {
+            AppError::Validation(format!(
+                "workflow continuation step path '{step_path}' does not match the workflow"
+            ))
+        };
+
+        let mut frames = Vec::new();
+        let mut branch_steps = steps;
+        let mut path_prefix = String::new();
+        let mut segments = step_path.split('.');
+        while let Some(index_segment) = segments.next() {
+            let index = index_segment.parse::<usize>().map_err(|_| invalid_path())?;
+            let step = branch_steps.get(index).ok_or_else(invalid_path)?;
+            frames.push(WorkflowResumeFrame {
+                steps: branch_steps,
+                path_prefix: path_prefix.clone(),
+                start_index: index + 1,
+            });
+
+            let Some(branch) = segments.next() else {
+                break;
+            };
+            branch_steps = match (step, branch) {
+                (WorkflowStep::Condition { then_steps, .. }, "then") => then_steps.as_slice(),
+                (WorkflowStep::Condition { else_steps, .. }, "else") => else_steps.as_slice(),
+                _ => return Err(invalid_path()),
+            };
+            path_prefix = if path_prefix.is_empty() {
+                format!("{index}.{branch}")
+            } else {
+                format!("{path_prefix}.{index}.{branch}")
+            };
+        }
+
+        Ok(frames)
     }
 
     pub(super) async fn execute_single_step_path_with_trace(
@@ -35,8 +117,29 @@ impl WorkflowService {
             | WorkflowStep::Webhook { .. }
             | WorkflowStep::AssignOwner { .. }
             | WorkflowStep::ApprovalRequest { .. }
-            | WorkflowStep::Delay { .. } => self
-                .execute_step_with_trace(actor, step, context, step_path, traces)
+            | WorkflowStep::Delay { .. }
+            | WorkflowStep::Wait { .. }
+            | WorkflowStep::InvokeWorkflow { .. } => {
+                let suspension = self
+                    .execute_step_with_trace(actor, step, context, step_path, traces)
+                    .await
+                    .map_err(|error| error.error)?;
+                Self::reject_retry_suspension(suspension)
+            }
+            WorkflowStep::TryCatch {
+                try_steps,
+                catch_steps,
+                compensation_steps,
+            } => self
+                .execute_try_catch_with_trace(
+                    actor,
+                    try_steps,
+                    catch_steps,
+                    compensation_steps,
+                    context,
+                    step_path,
+                    traces,
+                )
                 .await
                 .map_err(|error| error.error),
             WorkflowStep::Condition {
@@ -49,13 +152,22 @@ impl WorkflowService {
                 else_steps,
             } => {
                 let started_at = Instant::now();
+                let step_outputs = Self::step_outputs_from_traces(context.step_outputs, traces);
+                let value_context = WorkflowExecutionContext {
+                    step_outputs: &step_outputs,
+                    ..context
+                };
                 let resolved_value = value
                     .as_ref()
-                    .map(|selected_value| Self::interpolate_json_value(selected_value, context))
+                    .map(|selected_value| {
+                        Self::interpolate_json_value(selected_value, value_context)
+                    })
                     .transpose()?;
+                let selected_value = self
+                    .condition_field_value(actor, value_context, field_path.as_str())
+                    .await?;
                 let passes = Self::evaluate_condition(
-                    context.trigger_payload,
-                    field_path.as_str(),
+                    selected_value.as_ref(),
                     *operator,
                     resolved_value.as_ref(),
                 )?;
@@ -74,51 +186,68 @@ impl WorkflowService {
                     }),
                     error_message: None,
                     duration_ms: Some(started_at.elapsed().as_millis() as u64),
+                    retries: 0,
                 });
 
-                if passes {
-                    let then_prefix = format!("{}.then", step_path);
-                    self.execute_steps_with_trace(
-                        actor,
-                        then_steps.as_slice(),
-                        context,
-                        then_prefix.as_str(),
-                        traces,
-                    )
-                    .await
-                    .map_err(|error| error.error)
+                let (branch_steps, branch_prefix) = if passes {
+                    (then_steps.as_slice(), format!("{}.then", step_path))
                 } else {
-                    let else_prefix = format!("{}.else", step_path);
-                    self.execute_steps_with_trace(
+                    (else_steps.as_slice(), format!("{}.else", step_path))
+                };
+                let suspension = self
+                    .execute_steps_with_trace(
                         actor,
-                        else_steps.as_slice(),
+                        branch_steps,
                         context,
-                        else_prefix.as_str(),
+                        branch_prefix.as_str(),
+                        0,
                         traces,
                     )
                     .await
-                    .map_err(|error| error.error)
-                }
+                    .map_err(|error| error.error)?;
+                Self::reject_retry_suspension(suspension)
             }
         }
     }
 
+    /// Step retries run outside the job queue, so they cannot pause at a wait step.
+    fn reject_retry_suspension(suspension: Option<WorkflowWaitSuspension>) -> AppResult<()> {
+        match suspension {
+            Some(suspension) => Err(AppError::Conflict(format!(
+                "step retry cannot suspend at wait step '{}'",
+                suspension.step_path
+            ))),
+            None => Ok(()),
+        }
+    }
+
     pub(super) fn execute_steps_with_trace<'a>(
         &'a self,
         actor: &'a UserIdentity,
         steps: &'a [WorkflowStep],
         context: WorkflowExecutionContext<'a>,
         path_prefix: &'a str,
+        start_index: usize,
         traces: &'a mut Vec<WorkflowRunStepTrace>,
     ) -> std::pin::Pin<
         Box<
-            dyn std::future::Future<Output = Result<(), WorkflowExecutionErrorWithTrace>>
-                + Send
+            dyn std::future::Future<
+                    Output = Result<
+                        Option<WorkflowWaitSuspension>,
+                        WorkflowExecutionErrorWithTrace,
+                    >,
+                > + Send
                 + 'a,
         >,
     > {
         Box::pin(async move {
-            for (index, step) in steps.iter().enumerate() {
+            for (index, step) in steps.iter().enumerate().skip(start_index) {
+                ensure_operation_budget_remaining().map_err(|error| {
+                    WorkflowExecutionErrorWithTrace {
+                        error,
+                        step_traces: traces.clone(),
+                    }
+                })?;
                 let step_path = if path_prefix.is_empty() {
                     index.to_string()
                 } else {
@@ -135,10 +264,32 @@ impl WorkflowService {
                     | WorkflowStep::Webhook { .. }
                     | WorkflowStep::AssignOwner { .. }
                     | WorkflowStep::ApprovalRequest { .. }
-                    | WorkflowStep::Delay { .. } => {
-                        self.execute_step_with_trace(
+                    | WorkflowStep::Delay { .. }
+                    | WorkflowStep::Wait { .. }
+                    | WorkflowStep::InvokeWorkflow { .. } => {
+                        let suspension = self
+                            .execute_step_with_trace(
+                                actor,
+                                step,
+                                context,
+                                step_path.as_str(),
+                                traces,
+                            )
+                            .await?;
+                        if suspension.is_some() {
+                            return Ok(suspension);
+                        }
+                    }
+                    WorkflowStep::TryCatch {
+                        try_steps,
+                        catch_steps,
+                        compensation_steps,
+                    } => {
+                        self.execute_try_catch_with_trace(
                             actor,
-                            step,
+                            try_steps,
+                            catch_steps,
+                            compensation_steps,
                             context,
                             step_path.as_str(),
                             traces,
@@ -155,28 +306,36 @@ impl WorkflowService {
                         else_steps,
                     } => {
                         let condition_started_at = Instant::now();
+                        let step_outputs =
+                            Self::step_outputs_from_traces(context.step_outputs, traces);
+                        let value_context = WorkflowExecutionContext {
+                            step_outputs: &step_outputs,
+                            ..context
+                        };
                         let resolved_value = value
                             .as_ref()
                             .map(|selected_value| {
-                                Self::interpolate_json_value(selected_value, context)
+                                Self::interpolate_json_value(selected_value, value_context)
                             })
                             .transpose()
                             .map_err(|error| WorkflowExecutionErrorWithTrace {
                                 error,
                                 step_traces: traces.clone(),
                             })?;
-                        let passes = Self::evaluate_condition(
-                            context.trigger_payload,
-                            field_path.as_str(),
-                            *operator,
-                            resolved_value.as_ref(),
-                        )
-                        .map_err(|error| {
-                            WorkflowExecutionErrorWithTrace {
+                        let passes = self
+                            .condition_field_value(actor, value_context, field_path.as_str())
+                            .await
+                            .and_then(|selected_value| {
+                                Self::evaluate_condition(
+                                    selected_value.as_ref(),
+                                    *operator,
+                                    resolved_value.as_ref(),
+                                )
+                            })
+                            .map_err(|error| WorkflowExecutionErrorWithTrace {
                                 error,
                                 step_traces: traces.clone(),
-                            }
-                        })?;
+                            })?;
 
                         let condition_duration_ms =
                             condition_started_at.elapsed().as_millis() as u64;
@@ -195,37 +354,140 @@ impl WorkflowService {
                             }),
                             error_message: None,
                             duration_ms: Some(condition_duration_ms),
+                            retries: 0,
                         });
 
-                        if passes {
-                            let then_prefix = format!("{}.then", step_path);
-                            self.execute_steps_with_trace(
-                                actor,
-                                then_steps.as_slice(),
-                                context,
-                                then_prefix.as_str(),
-                                traces,
-                            )
-                            .await?;
+                        let (branch_steps, branch_prefix) = if passes {
+                            (then_steps.as_slice(), format!("{}.then", step_path))
                         } else {
-                            let else_prefix = format!("{}.else", step_path);
-                            self.execute_steps_with_trace(
+                            (else_steps.as_slice(), format!("{}.else", step_path))
+                        };
+                        let suspension = self
+                            .execute_steps_with_trace(
                                 actor,
-                                else_steps.as_slice(),
+                                branch_steps,
                                 context,
-                                else_prefix.as_str(),
+                                branch_prefix.as_str(),
+                                0,
                                 traces,
                             )
                             .await?;
+                        if suspension.is_some() {
+                            return Ok(suspension);
                         }
                     }
                 }
             }
 
-            Ok(())
+            Ok(None)
         })
     }
 
+    /// Executes a try/catch group given its try, catch and compensation steps.
+    ///
+    /// When a try step fails the group trace records the failure as `caught` (or
+    /// `failed` without catch steps), then compensation and catch steps run under
+    /// `<path>.compensate.*` and `<path>.catch.*`. Catch steps read the failure as
+    /// `{{steps.<path>.error}}`; the group only succeeds when catch steps handle it.
+    #[allow(clippy::too_many_arguments)]
+    async fn execute_try_catch_with_trace(
+        &self,
+        actor: &UserIdentity,
+        try_steps: &[WorkflowStep],
+        catch_steps: &[WorkflowStep],
+        compensation_steps: &[WorkflowStep],
+        context: WorkflowExecutionContext<'_>,
+        step_path: &str,
+        traces: &mut Vec<WorkflowRunStepTrace>,
+    ) -> Result<(), WorkflowExecutionErrorWithTrace> {
+        let started_at = Instant::now();
+        let try_prefix = format!("{step_path}.try");
+        let failure = match self
+            .execute_steps_with_trace(actor, try_steps, context, try_prefix.as_str(), 0, traces)
+            .await
+        {
+            Ok(_) => None,
+            Err(error_with_trace) => Some(error_with_trace.error),
+        };
+
+        let Some(error) = failure else {
+            traces.push(WorkflowRunStepTrace {
+                step_path: step_path.to_owned(),
+                step_type: "try_catch".to_owned(),
+                status: "succeeded".to_owned(),
+                input_payload: context.trigger_payload.clone(),
+                output_payload: serde_json::json!({ "outcome": "succeeded" }),
+                error_message: None,
+                duration_ms: Some(started_at.elapsed().as_millis() as u64),
+                retries: 0,
+            });
+            return Ok(());
+        };
+
+        let failed_step_path = traces
+            .iter()
+            .rev()
+            .find(|trace| trace.status == "failed")
+            .map(|trace| trace.step_path.clone());
+        let caught = !catch_steps.is_empty();
+        let group_trace_index = traces.len();
+        traces.push(WorkflowRunStepTrace {
+            step_path: step_path.to_owned(),
+            step_type: "try_catch".to_owned(),
+            status: if caught { "caught" } else { "failed" }.to_owned(),
+            input_payload: context.trigger_payload.clone(),
+            output_payload: serde_json::json!({
+                "outcome": if caught { "caught" } else { "failed" },
+                "error": error.to_string(),
+                "failed_step_path": failed_step_path,
+            }),
+            error_message: Some(error.to_string()),
+            duration_ms: Some(started_at.elapsed().as_millis() as u64),
+            retries: 0,
+        });
+
+        let compensation_prefix = format!("{step_path}.compensate");
+        let catch_prefix = format!("{step_path}.catch");
+        let handled = async {
+            self.execute_steps_with_trace(
+                actor,
+                compensation_steps,
+                context,
+                compensation_prefix.as_str(),
+                0,
+                traces,
+            )
+            .await?;
+            self.execute_steps_with_trace(
+                actor,
+                catch_steps,
+                context,
+                catch_prefix.as_str(),
+                0,
+                traces,
+            )
+            .await
+        }
+        .await;
+
+        if let Err(handler_error) = handled {
+            traces[group_trace_index].status = "failed".to_owned();
+            return Err(WorkflowExecutionErrorWithTrace {
+                error: handler_error.error,
+                step_traces: traces.clone(),
+            });
+        }
+
+        if caught {
+            Ok(())
+        } else {
+            Err(WorkflowExecutionErrorWithTrace {
+                error,
+                step_traces: traces.clone(),
+            })
+        }
+    }
+
     pub(super) async fn execute_step_with_trace(
         &self,
         actor: &UserIdentity,
@@ -233,7 +495,12 @@ impl WorkflowService {
         context: WorkflowExecutionContext<'_>,
         step_path: &str,
         traces: &mut Vec<WorkflowRunStepTrace>,
-    ) -> Result<(), WorkflowExecutionErrorWithTrace> {
+    ) -> Result<Option<WorkflowWaitSuspension>, WorkflowExecutionErrorWithTrace> {
+        let step_outputs = Self::step_outputs_from_traces(context.step_outputs, traces);
+        let context = WorkflowExecutionContext {
+            step_outputs: &step_outputs,
+            ..context
+        };
         let resolved_step = Self::interpolate_step(step, context).map_err(|error| {
             WorkflowExecutionErrorWithTrace {
                 error,
@@ -242,23 +509,27 @@ impl WorkflowService {
         })?;
         let step_type = resolved_step.step_type().to_owned();
         let input_payload = context.trigger_payload.clone();
-        let output_payload = match &resolved_step {
+        let mut output_payload = match &resolved_step {
             WorkflowStep::LogMessage { message } => {
                 serde_json::json!({ "message": message })
             }
             WorkflowStep::CreateRuntimeRecord {
                 entity_logical_name,
                 data,
+                output_key,
+                retry_policy: _,
             } => {
                 serde_json::json!({
                     "entity_logical_name": entity_logical_name,
                     "data": data,
+                    "output_key": output_key,
                 })
             }
             WorkflowStep::UpdateRuntimeRecord {
                 entity_logical_name,
                 record_id,
                 data,
+                retry_policy: _,
             } => {
                 serde_json::json!({
                     "entity_logical_name": entity_logical_name,
@@ -269,6 +540,7 @@ impl WorkflowService {
             WorkflowStep::DeleteRuntimeRecord {
                 entity_logical_name,
                 record_id,
+                retry_policy: _,
             } => {
                 serde_json::json!({
                     "entity_logical_name": entity_logical_name,
@@ -280,6 +552,7 @@ impl WorkflowService {
                 subject,
                 body,
                 html_body,
+                retry_policy: _,
             } => {
                 serde_json::json!({
                     "to": to,
@@ -293,14 +566,23 @@ impl WorkflowService {
                 url,
                 headers,
                 header_secret_refs,
+                credential,
                 body,
+                expected_status,
+                retry,
+                output_key,
+                retry_policy: _,
             } => {
                 serde_json::json!({
                     "method": method,
                     "url": url,
                     "headers": redact_sensitive_workflow_headers(headers.as_ref()),
                     "header_secret_refs": redact_workflow_header_secret_refs(header_secret_refs.as_ref()),
+                    "credential": credential,
                     "body": body,
+                    "expected_status": expected_status,
+                    "retry": retry,
+                    "output_key": output_key,
                 })
             }
             WorkflowStep::Webhook {
@@ -308,13 +590,16 @@ impl WorkflowService {
                 event,
                 headers,
                 header_secret_refs,
+                credential,
                 payload,
+                retry_policy: _,
             } => {
                 serde_json::json!({
                     "endpoint": endpoint,
                     "event": event,
                     "headers": redact_sensitive_workflow_headers(headers.as_ref()),
                     "header_secret_refs": redact_workflow_header_secret_refs(header_secret_refs.as_ref()),
+                    "credential": credential,
                     "payload": payload,
                 })
             }
@@ -323,6 +608,7 @@ impl WorkflowService {
                 record_id,
                 owner_id,
                 reason,
+                retry_policy: _,
             } => {
                 serde_json::json!({
                     "entity_logical_name": entity_logical_name,
@@ -339,6 +625,7 @@ impl WorkflowService {
                 approver_id,
                 reason,
                 payload,
+                retry_policy: _,
             } => {
                 serde_json::json!({
                     "entity_logical_name": entity_logical_name,
@@ -359,6 +646,39 @@ impl WorkflowService {
                     "reason": reason,
                 })
             }
+            WorkflowStep::Wait {
+                duration_seconds,
+                until_field_path,
+                reason,
+            } => {
+                serde_json::json!({
+                    "duration_seconds": duration_seconds,
+                    "until_field_path": until_field_path,
+                    "reason": reason,
+                })
+            }
+            WorkflowStep::InvokeWorkflow {
+                workflow_logical_name,
+                payload,
+                mode,
+                output_key,
+                retry_policy: _,
+            } => {
+                serde_json::json!({
+                    "workflow_logical_name": workflow_logical_name,
+                    "payload": payload,
+                    "mode": mode,
+                    "output_key": output_key,
+                })
+            }
+            WorkflowStep::TryCatch { .. } => {
+                return Err(WorkflowExecutionErrorWithTrace {
+                    error: AppError::Validation(
+                        "try_catch step cannot execute as an action".to_owned(),
+                    ),
+                    step_traces: traces.clone(),
+                });
+            }
             WorkflowStep::Condition { .. } => {
                 return Err(WorkflowExecutionErrorWithTrace {
                     error: AppError::Validation(
@@ -370,22 +690,84 @@ impl WorkflowService {
         };
 
         let started_at = Instant::now();
-        match self
-            .execute_resolved_step(actor, &resolved_step, context, step_path)
-            .await
-        {
-            Ok(()) => {
+        let mut retries = 0_u32;
+        let outcome = loop {
+            let outcome = self
+                .execute_resolved_step(actor, &resolved_step, context, step_path)
+                .await;
+            let Err(error) = &outcome else {
+                break outcome;
+            };
+            let Some(retry_policy) = resolved_step
+                .retry_policy()
+                .filter(|policy| policy.should_retry(error, retries + 1))
+            else {
+                break outcome;
+            };
+
+            retries += 1;
+            let backoff_ms = retry_policy.backoff_before_retry_ms(retries);
+            if backoff_ms > 0
+                && let Some(delay_service) = self.delay_service.clone()
+                && let Err(error) = delay_service.sleep(backoff_ms).await
+            {
+                break Err(error);
+            }
+        };
+
+        match outcome {
+            Ok(result) => {
+                if let Value::Object(fields) = &mut output_payload {
+                    if let Some(record_id) = result.record_id {
+                        fields.insert("record_id".to_owned(), Value::String(record_id));
+                    }
+                    if let Some(response) = result.response {
+                        fields.insert(
+                            "response".to_owned(),
+                            serde_json::json!({
+                                "status": response.status_code,
+                                "body": response.body,
+                            }),
+                        );
+                    }
+                    if let Some(invoked_run) = &result.invoked_run {
+                        fields.insert(
+                            "run_id".to_owned(),
+                            Value::String(invoked_run.run_id.clone()),
+                        );
+                        fields.insert(
+                            "status".to_owned(),
+                            Value::String(invoked_run.status.as_str().to_owned()),
+                        );
+                    }
+                    if let Some(resume_at) = result.resume_at {
+                        fields.insert(
+                            "resume_at".to_owned(),
+                            Value::String(resume_at.to_rfc3339()),
+                        );
+                    }
+                }
+
+                let status = if result.resume_at.is_some() {
+                    "waiting"
+                } else {
+                    "succeeded"
+                };
                 traces.push(WorkflowRunStepTrace {
                     step_path: step_path.to_owned(),
                     step_type,
-                    status: "succeeded".to_owned(),
+                    status: status.to_owned(),
                     input_payload,
                     output_payload,
                     error_message: None,
                     duration_ms: Some(started_at.elapsed().as_millis() as u64),
+                    retries,
                 });
 
-                Ok(())
+                Ok(result.resume_at.map(|resume_at| WorkflowWaitSuspension {
+                    step_path: step_path.to_owned(),
+                    resume_at,
+                }))
             }
             Err(error) => {
                 let message = error.to_string();
@@ -397,6 +779,7 @@ impl WorkflowService {
                     output_payload,
                     error_message: Some(message),
                     duration_ms: Some(started_at.elapsed().as_millis() as u64),
+                    retries,
                 });
 
                 Err(WorkflowExecutionErrorWithTrace {
@@ -1,5 +1,8 @@
 use super::*;
 
+use chrono::{DateTime, NaiveDate};
+use qryvanta_domain::WorkflowRelatedFieldPath;
+
 impl WorkflowService {
     pub(super) fn interpolate_step(
         step: &WorkflowStep,
@@ -12,31 +15,40 @@ impl WorkflowService {
             WorkflowStep::CreateRuntimeRecord {
                 entity_logical_name,
                 data,
+                output_key,
+                retry_policy,
             } => Ok(WorkflowStep::CreateRuntimeRecord {
                 entity_logical_name: Self::interpolate_string(entity_logical_name, context),
                 data: Self::interpolate_json_value(data, context)?,
+                output_key: output_key.clone(),
+                retry_policy: retry_policy.clone(),
             }),
             WorkflowStep::UpdateRuntimeRecord {
                 entity_logical_name,
                 record_id,
                 data,
+                retry_policy,
             } => Ok(WorkflowStep::UpdateRuntimeRecord {
                 entity_logical_name: Self::interpolate_string(entity_logical_name, context),
                 record_id: Self::interpolate_string(record_id, context),
                 data: Self::interpolate_json_value(data, context)?,
+                retry_policy: retry_policy.clone(),
             }),
             WorkflowStep::DeleteRuntimeRecord {
                 entity_logical_name,
                 record_id,
+                retry_policy,
             } => Ok(WorkflowStep::DeleteRuntimeRecord {
                 entity_logical_name: Self::interpolate_string(entity_logical_name, context),
                 record_id: Self::interpolate_string(record_id, context),
+                retry_policy: retry_policy.clone(),
             }),
             WorkflowStep::SendEmail {
                 to,
                 subject,
                 body,
                 html_body,
+                retry_policy,
             } => Ok(WorkflowStep::SendEmail {
                 to: Self::interpolate_string(to, context),
                 subject: Self::interpolate_string(subject, context),
@@ -44,13 +56,19 @@ impl WorkflowService {
                 html_body: html_body
                     .as_ref()
                     .map(|value| Self::interpolate_string(value, context)),
+                retry_policy: retry_policy.clone(),
             }),
             WorkflowStep::HttpRequest {
                 method,
                 url,
                 headers,
                 header_secret_refs,
+                credential,
                 body,
+                expected_status,
+                retry,
+                output_key,
+                retry_policy,
             } => Ok(WorkflowStep::HttpRequest {
                 method: Self::interpolate_string(method, context),
                 url: Self::interpolate_string(url, context),
@@ -59,17 +77,24 @@ impl WorkflowService {
                     .map(|value| Self::interpolate_json_value(value, context))
                     .transpose()?,
                 header_secret_refs: header_secret_refs.clone(),
+                credential: credential.clone(),
                 body: body
                     .as_ref()
                     .map(|value| Self::interpolate_json_value(value, context))
                     .transpose()?,
+                expected_status: *expected_status,
+                retry: *retry,
+                output_key: output_key.clone(),
+                retry_policy: retry_policy.clone(),
             }),
             WorkflowStep::Webhook {
                 endpoint,
                 event,
                 headers,
                 header_secret_refs,
+                credential,
                 payload,
+                retry_policy,
             } => Ok(WorkflowStep::Webhook {
                 endpoint: Self::interpolate_string(endpoint, context),
                 event: Self::interpolate_string(event, context),
@@ -78,13 +103,16 @@ impl WorkflowService {
                     .map(|value| Self::interpolate_json_value(value, context))
                     .transpose()?,
                 header_secret_refs: header_secret_refs.clone(),
+                credential: credential.clone(),
                 payload: Self::interpolate_json_value(payload, context)?,
+                retry_policy: retry_policy.clone(),
             }),
             WorkflowStep::AssignOwner {
                 entity_logical_name,
                 record_id,
                 owner_id,
                 reason,
+                retry_policy,
             } => Ok(WorkflowStep::AssignOwner {
                 entity_logical_name: Self::interpolate_string(entity_logical_name, context),
                 record_id: Self::interpolate_string(record_id, context),
@@ -92,6 +120,7 @@ impl WorkflowService {
                 reason: reason
                     .as_ref()
                     .map(|value| Self::interpolate_string(value, context)),
+                retry_policy: retry_policy.clone(),
             }),
             WorkflowStep::ApprovalRequest {
                 entity_logical_name,
@@ -101,6 +130,7 @@ impl WorkflowService {
                 approver_id,
                 reason,
                 payload,
+                retry_policy,
             } => Ok(WorkflowStep::ApprovalRequest {
                 entity_logical_name: Self::interpolate_string(entity_logical_name, context),
                 record_id: Self::interpolate_string(record_id, context),
@@ -118,6 +148,7 @@ impl WorkflowService {
                     .as_ref()
                     .map(|value| Self::interpolate_json_value(value, context))
                     .transpose()?,
+                retry_policy: retry_policy.clone(),
             }),
             WorkflowStep::Delay {
                 duration_ms,
@@ -128,6 +159,33 @@ impl WorkflowService {
                     .as_ref()
                     .map(|value| Self::interpolate_string(value, context)),
             }),
+            WorkflowStep::Wait {
+                duration_seconds,
+                until_field_path,
+                reason,
+            } => Ok(WorkflowStep::Wait {
+                duration_seconds: *duration_seconds,
+                until_field_path: until_field_path.clone(),
+                reason: reason
+                    .as_ref()
+                    .map(|value| Self::interpolate_string(value, context)),
+            }),
+            WorkflowStep::InvokeWorkflow {
+                workflow_logical_name,
+                payload,
+                mode,
+                output_key,
+                retry_policy,
+            } => Ok(WorkflowStep::InvokeWorkflow {
+                workflow_logical_name: workflow_logical_name.clone(),
+                payload: Self::interpolate_json_value(payload, context)?,
+                mode: *mode,
+                output_key: output_key.clone(),
+                retry_policy: retry_policy.clone(),
+            }),
+            WorkflowStep::TryCatch { .. } => Err(AppError::Validation(
+                "try_catch step cannot be interpolated as an executable action".to_owned(),
+            )),
             WorkflowStep::Condition { .. } => Err(AppError::Validation(
                 "condition step cannot be interpolated as an executable action".to_owned(),
             )),
@@ -221,6 +279,9 @@ impl WorkflowService {
             "run.id" => Some(Value::String(context.run_id.to_owned())),
             "run.attempt" => Some(Value::Number(context.attempt_number.into())),
             "now.iso" => Some(Value::String(Utc::now().to_rfc3339())),
+            _ if token.starts_with("steps.") => {
+                Self::payload_value_by_path(context.step_outputs, &token["steps.".len()..]).cloned()
+            }
             _ => {
                 let path = token
                     .strip_prefix("trigger.payload.")
@@ -232,6 +293,52 @@ impl WorkflowService {
         }
     }
 
+    /// Merges succeeded step trace outputs into a nested object keyed by step path segments.
+    ///
+    /// Step `1.then.0` is reachable as `{{steps.1.then.0.<field>}}`; a step with an
+    /// `output_key` is also reachable as `{{steps.<output_key>.<field>}}`. Try/catch
+    /// groups that caught a failure expose it to their catch steps the same way.
+    pub(super) fn step_outputs_from_traces(base: &Value, traces: &[WorkflowRunStepTrace]) -> Value {
+        let mut outputs = base.as_object().cloned().unwrap_or_default();
+        for trace in traces
+            .iter()
+            .filter(|trace| trace.status == "succeeded" || trace.status == "caught")
+        {
+            if let Some(output_key) = trace
+                .output_payload
+                .get("output_key")
+                .and_then(Value::as_str)
+            {
+                outputs.insert(output_key.to_owned(), trace.output_payload.clone());
+            }
+
+            let mut node = &mut outputs;
+            let mut segments = trace.step_path.split('.').peekable();
+            while let Some(segment) = segments.next() {
+                let entry = node
+                    .entry(segment.to_owned())
+                    .or_insert_with(|| Value::Object(serde_json::Map::new()));
+                if !entry.is_object() {
+                    *entry = Value::Object(serde_json::Map::new());
+                }
+                let Value::Object(entry_fields) = entry else {
+                    break;
+                };
+
+                if segments.peek().is_none() {
+                    if let Value::Object(output_fields) = &trace.output_payload {
+                        entry_fields.extend(output_fields.clone());
+                    }
+                    break;
+                }
+
+                node = entry_fields;
+            }
+        }
+
+        Value::Object(outputs)
+    }
+
     pub(super) fn value_to_string(value: &Value) -> String {
         match value {
             Value::Null => "null".to_owned(),
@@ -242,13 +349,160 @@ impl WorkflowService {
         }
     }
 
-    pub(super) fn evaluate_condition(
-        trigger_payload: &Value,
+    /// Resolves when a wait step resumes: a fixed duration from now or a timestamp field.
+    ///
+    /// Field values must be RFC 3339 timestamps or `YYYY-MM-DD` dates (midnight UTC).
+    pub(super) fn wait_resume_at(
+        duration_seconds: Option<u64>,
+        until_field_path: Option<&str>,
+        context: WorkflowExecutionContext<'_>,
+    ) -> AppResult<DateTime<Utc>> {
+        if let Some(duration_seconds) = duration_seconds {
+            let seconds = i64::try_from(duration_seconds).map_err(|_| {
+                AppError::Validation(format!(
+                    "wait step duration_seconds '{duration_seconds}' is out of range"
+                ))
+            })?;
+            return Ok(Utc::now() + chrono::Duration::seconds(seconds));
+        }
+
+        let field_path = until_field_path.ok_or_else(|| {
+            AppError::Validation(
+                "wait step requires duration_seconds or until_field_path".to_owned(),
+            )
+        })?;
+        let (payload, path) = Self::condition_field_source(context, field_path);
+        let raw_value = Self::payload_value_by_path(payload, path)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "wait step field '{field_path}' must contain a timestamp or date string"
+                ))
+            })?;
+
+        if let Ok(timestamp) = DateTime::parse_from_rfc3339(raw_value) {
+            return Ok(timestamp.with_timezone(&Utc));
+        }
+
+        NaiveDate::parse_from_str(raw_value, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|midnight| midnight.and_utc())
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "wait step field '{field_path}' value '{raw_value}' is not an RFC 3339 timestamp or YYYY-MM-DD date"
+                ))
+            })
+    }
+
+    /// Selects the payload a condition `field_path` reads from.
+    ///
+    /// Paths prefixed with `steps.` read earlier step outputs; all others read the trigger payload.
+    pub(super) fn condition_field_source<'a>(
+        context: WorkflowExecutionContext<'a>,
+        field_path: &'a str,
+    ) -> (&'a Value, &'a str) {
+        match field_path.strip_prefix("steps.") {
+            Some(step_path) => (context.step_outputs, step_path),
+            None => (context.trigger_payload, field_path),
+        }
+    }
+
+    /// Resolves the value a condition `field_path` selects.
+    ///
+    /// `related.` paths follow relation fields from the trigger record, loading each
+    /// related record once per run; a missing link or record selects no value.
+    pub(super) async fn condition_field_value(
+        &self,
+        actor: &UserIdentity,
+        context: WorkflowExecutionContext<'_>,
         field_path: &str,
+    ) -> AppResult<Option<Value>> {
+        let Some(related_path) = WorkflowRelatedFieldPath::parse(field_path)? else {
+            let (payload, path) = Self::condition_field_source(context, field_path);
+            return Ok(Self::payload_value_by_path(payload, path).cloned());
+        };
+
+        let mut entity_logical_name = context
+            .trigger_entity_logical_name
+            .filter(|_| context.trigger_type.starts_with("runtime_record_"))
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "condition path '{field_path}' requires a runtime record trigger"
+                ))
+            })?
+            .to_owned();
+        let mut data = context
+            .trigger_payload
+            .get("data")
+            .cloned()
+            .unwrap_or(Value::Null);
+
+        for relation_field in related_path.relation_fields() {
+            let Some(related_record_id) = data
+                .get(*relation_field)
+                .and_then(Value::as_str)
+                .filter(|value| !value.trim().is_empty())
+            else {
+                return Ok(None);
+            };
+
+            let Some(record) = self
+                .related_runtime_record(
+                    actor,
+                    context,
+                    entity_logical_name.as_str(),
+                    relation_field,
+                    related_record_id,
+                )
+                .await?
+            else {
+                return Ok(None);
+            };
+
+            entity_logical_name = record.entity_logical_name().as_str().to_owned();
+            data = record.data().clone();
+        }
+
+        Ok(Self::payload_value_by_path(&data, related_path.field_logical_name()).cloned())
+    }
+
+    async fn related_runtime_record(
+        &self,
+        actor: &UserIdentity,
+        context: WorkflowExecutionContext<'_>,
+        entity_logical_name: &str,
+        relation_field: &str,
+        related_record_id: &str,
+    ) -> AppResult<Option<RuntimeRecord>> {
+        let key = (
+            entity_logical_name.to_owned(),
+            relation_field.to_owned(),
+            related_record_id.to_owned(),
+        );
+        if let Some(record) = context.related_records.get(&key)? {
+            return Ok(record);
+        }
+
+        let record = self
+            .runtime_record_service
+            .get_related_runtime_record_unchecked(
+                actor,
+                entity_logical_name,
+                relation_field,
+                related_record_id,
+            )
+            .await?;
+        context.related_records.insert(key, record.clone())?;
+        Ok(record)
+    }
+
+    pub(super) fn evaluate_condition(
+        selected_value: Option<&Value>,
         operator: WorkflowConditionOperator,
         value: Option<&Value>,
     ) -> AppResult<bool> {
-        let selected_value = Self::payload_value_by_path(trigger_payload, field_path);
         match operator {
             WorkflowConditionOperator::Exists => Ok(selected_value.is_some()),
             WorkflowConditionOperator::Equals => {
@@ -323,6 +577,28 @@ impl WorkflowService {
                 continue;
             }
 
+            if matches!(segment, "try" | "catch" | "compensate") {
+                let Some(WorkflowStep::TryCatch {
+                    try_steps,
+                    catch_steps,
+                    compensation_steps,
+                }) = selected_step
+                else {
+                    return Err(AppError::Validation(format!(
+                        "invalid workflow step path '{}': expected try_catch for {} branch",
+                        step_path, segment
+                    )));
+                };
+
+                branch_steps = match segment {
+                    "try" => try_steps.as_slice(),
+                    "catch" => catch_steps.as_slice(),
+                    _ => compensation_steps.as_slice(),
+                };
+                selected_step = None;
+                continue;
+            }
+
             let index = segment.parse::<usize>().map_err(|error| {
                 AppError::Validation(format!(
                     "invalid workflow step path '{}': segment '{}' is not an index ({error})",
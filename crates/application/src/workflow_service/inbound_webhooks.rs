@@ -0,0 +1,249 @@
+use hmac::{Hmac, Mac};
+use qryvanta_domain::PayloadSchema;
+use sha2::Sha256;
+
+use crate::auth_token_service::token_crypto::{generate_token, hash_token};
+use crate::workflow_ports::{
+    CreatedWorkflowInboundWebhook, NewWorkflowInboundWebhook,
+    WORKFLOW_INBOUND_WEBHOOK_TOLERANCE_SECONDS, WorkflowInboundWebhook,
+    WorkflowInboundWebhookDelivery,
+};
+
+use super::*;
+
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+impl WorkflowService {
+    /// Creates or rotates the signed inbound webhook of a workflow.
+    ///
+    /// Rotating replaces both the URL token and the signing secret, so the
+    /// previous URL stops accepting deliveries immediately.
+    pub async fn configure_inbound_webhook(
+        &self,
+        actor: &UserIdentity,
+        workflow_logical_name: &str,
+        payload_schema: Option<Value>,
+    ) -> AppResult<CreatedWorkflowInboundWebhook> {
+        self.require_workflow_manage(actor).await?;
+        let (repository, secret_encryptor) = self.inbound_webhook_dependencies()?;
+
+        let workflow = self
+            .repository
+            .find_workflow(actor.tenant_id(), workflow_logical_name)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "workflow '{}' does not exist for tenant '{}'",
+                    workflow_logical_name,
+                    actor.tenant_id()
+                ))
+            })?;
+
+        if let Some(payload_schema) = &payload_schema {
+            PayloadSchema::parse(payload_schema.clone())?;
+        }
+
+        let (token, token_hash) = generate_token()?;
+        let (signing_secret, _) = generate_token()?;
+        let signing_secret_ciphertext = secret_encryptor.encrypt(signing_secret.as_bytes())?;
+
+        let webhook = repository
+            .save_inbound_webhook(
+                actor.tenant_id(),
+                NewWorkflowInboundWebhook {
+                    workflow_logical_name: workflow.logical_name().as_str().to_owned(),
+                    payload_schema,
+                    token_hash,
+                    signing_secret_ciphertext,
+                    created_by_subject: actor.subject().to_owned(),
+                },
+            )
+            .await?;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::WorkflowInboundWebhookConfigured,
+                resource_type: "workflow_inbound_webhook".to_owned(),
+                resource_id: webhook.workflow_logical_name.clone(),
+                detail: Some(format!(
+                    "configured inbound webhook for workflow '{}' {} payload schema",
+                    webhook.workflow_logical_name,
+                    if webhook.payload_schema.is_some() {
+                        "with"
+                    } else {
+                        "without"
+                    }
+                )),
+            })
+            .await?;
+
+        Ok(CreatedWorkflowInboundWebhook {
+            webhook,
+            token,
+            signing_secret,
+        })
+    }
+
+    /// Returns the inbound webhook of a workflow without its credentials.
+    pub async fn get_inbound_webhook(
+        &self,
+        actor: &UserIdentity,
+        workflow_logical_name: &str,
+    ) -> AppResult<Option<WorkflowInboundWebhook>> {
+        self.require_workflow_read(actor).await?;
+        let (repository, _) = self.inbound_webhook_dependencies()?;
+
+        repository
+            .find_inbound_webhook(actor.tenant_id(), workflow_logical_name)
+            .await
+    }
+
+    /// Revokes the inbound webhook of a workflow.
+    pub async fn revoke_inbound_webhook(
+        &self,
+        actor: &UserIdentity,
+        workflow_logical_name: &str,
+    ) -> AppResult<()> {
+        self.require_workflow_manage(actor).await?;
+        let (repository, _) = self.inbound_webhook_dependencies()?;
+
+        if !repository
+            .delete_inbound_webhook(actor.tenant_id(), workflow_logical_name)
+            .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "workflow '{}' has no inbound webhook",
+                workflow_logical_name
+            )));
+        }
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::WorkflowInboundWebhookRevoked,
+                resource_type: "workflow_inbound_webhook".to_owned(),
+                resource_id: workflow_logical_name.to_owned(),
+                detail: Some(format!(
+                    "revoked inbound webhook for workflow '{}'",
+                    workflow_logical_name
+                )),
+            })
+            .await
+    }
+
+    /// Verifies a signed inbound webhook delivery and starts its workflow.
+    ///
+    /// The signature is the hex HMAC-SHA256 of `"{timestamp}.{body}"` keyed
+    /// with the webhook signing secret. Deliveries whose timestamp is more
+    /// than five minutes away from the server clock are rejected to limit
+    /// replays.
+    pub async fn receive_inbound_webhook(
+        &self,
+        tenant_id: TenantId,
+        token: &str,
+        delivery: WorkflowInboundWebhookDelivery,
+    ) -> AppResult<WorkflowRun> {
+        let (repository, secret_encryptor) = self.inbound_webhook_dependencies()?;
+
+        let credentials = repository
+            .find_inbound_webhook_by_token_hash(tenant_id, hash_token(token).as_str())
+            .await?
+            .ok_or_else(|| AppError::NotFound("inbound webhook not found".to_owned()))?;
+
+        let received_at = Utc::now();
+        let timestamp = delivery
+            .timestamp
+            .as_deref()
+            .map(str::trim)
+            .ok_or_else(|| {
+                AppError::Unauthorized("inbound webhook timestamp is missing".to_owned())
+            })?;
+        let timestamp_seconds = timestamp.parse::<i64>().map_err(|_| {
+            AppError::Unauthorized("inbound webhook timestamp must be unix seconds".to_owned())
+        })?;
+        if received_at.timestamp().abs_diff(timestamp_seconds)
+            > WORKFLOW_INBOUND_WEBHOOK_TOLERANCE_SECONDS.unsigned_abs()
+        {
+            return Err(AppError::Unauthorized(
+                "inbound webhook timestamp is outside the allowed window".to_owned(),
+            ));
+        }
+
+        let signature = delivery
+            .signature
+            .as_deref()
+            .map(str::trim)
+            .and_then(|signature| signature.strip_prefix(SIGNATURE_PREFIX))
+            .and_then(|signature| hex::decode(signature).ok())
+            .ok_or_else(|| {
+                AppError::Unauthorized(format!(
+                    "inbound webhook signature must use the '{SIGNATURE_PREFIX}<hex>' format"
+                ))
+            })?;
+        let signing_secret = secret_encryptor.decrypt(&credentials.signing_secret_ciphertext)?;
+        let mut mac = Hmac::<Sha256>::new_from_slice(&signing_secret).map_err(|error| {
+            AppError::Internal(format!("invalid inbound webhook signing secret: {error}"))
+        })?;
+        mac.update(timestamp.as_bytes());
+        mac.update(b".");
+        mac.update(&delivery.body);
+        mac.verify_slice(&signature).map_err(|_| {
+            AppError::Unauthorized("inbound webhook signature is invalid".to_owned())
+        })?;
+
+        let payload = serde_json::from_slice::<Value>(&delivery.body).map_err(|error| {
+            AppError::Validation(format!("inbound webhook body must be valid JSON: {error}"))
+        })?;
+        let webhook = credentials.webhook;
+        if let Some(payload_schema) = &webhook.payload_schema {
+            PayloadSchema::parse(payload_schema.clone())?.validate(&payload)?;
+        }
+
+        // The signature covers the timestamp and body, so a sender retrying the
+        // same delivery gets the run its first attempt created.
+        let idempotency_key = format!("inbound-webhook:{}", hex::encode(&signature));
+        let run = self
+            .run_published_workflow(
+                tenant_id,
+                webhook.workflow_logical_name.as_str(),
+                serde_json::json!({
+                    "event": "inbound_webhook",
+                    "workflow_logical_name": webhook.workflow_logical_name,
+                    "request": delivery.request,
+                    "payload": payload.clone(),
+                    "data": payload,
+                }),
+                Some(idempotency_key.as_str()),
+            )
+            .await?;
+
+        repository
+            .record_inbound_webhook_delivery(
+                tenant_id,
+                webhook.workflow_logical_name.as_str(),
+                received_at,
+            )
+            .await?;
+
+        Ok(run)
+    }
+
+    fn inbound_webhook_dependencies(
+        &self,
+    ) -> AppResult<(&dyn WorkflowInboundWebhookRepository, &dyn SecretEncryptor)> {
+        match (
+            &self.inbound_webhook_repository,
+            &self.inbound_webhook_secret_encryptor,
+        ) {
+            (Some(repository), Some(secret_encryptor)) => {
+                Ok((repository.as_ref(), secret_encryptor.as_ref()))
+            }
+            _ => Err(AppError::Internal(
+                "workflow inbound webhooks are not configured".to_owned(),
+            )),
+        }
+    }
+}
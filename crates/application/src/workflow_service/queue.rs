@@ -1,5 +1,7 @@
 use crate::RuntimeRecordWorkflowEventDrainResult;
+use crate::workflow_ports::SuspendWorkflowRunInput;
 
+use super::execution::WorkflowRunExecution;
 use super::*;
 
 impl WorkflowService {
@@ -120,8 +122,16 @@ impl WorkflowService {
             event.tenant_id,
         );
 
-        self.dispatch_trigger(&actor, event.trigger.clone(), event.payload.clone())
-            .await
+        // Redelivered events reuse the key, so a retry after a partial dispatch
+        // does not start the already created runs again.
+        let idempotency_key = format!("runtime-record-event:{}", event.event_id);
+        self.dispatch_trigger(
+            &actor,
+            event.trigger.clone(),
+            event.payload.clone(),
+            Some(idempotency_key.as_str()),
+        )
+        .await
     }
 
     /// Claims queued workflow jobs for one worker.
@@ -202,14 +212,38 @@ impl WorkflowService {
                 &job.workflow,
                 job.run_id.as_str(),
                 job.trigger_payload,
+                job.continuation.as_ref(),
+                None,
             )
             .await;
 
         match run_result {
-            Ok(run) => {
+            Ok(WorkflowRunExecution::Completed(run)) => {
                 self.repository
                     .complete_job(tenant_id, job_id.as_str(), worker_id, lease_token.as_str())
                     .await?;
+                self.invalidate_queue_stats_cache().await;
+                Ok(run)
+            }
+            Ok(WorkflowRunExecution::Suspended {
+                attempts,
+                continuation,
+            }) => {
+                let run = self
+                    .repository
+                    .suspend_run_job(
+                        tenant_id,
+                        job_id.as_str(),
+                        worker_id,
+                        lease_token.as_str(),
+                        SuspendWorkflowRunInput {
+                            run_id: job.run_id.clone(),
+                            attempts,
+                            continuation,
+                        },
+                    )
+                    .await?;
+                self.invalidate_queue_stats_cache().await;
                 Ok(run)
             }
             Err(error) => {
@@ -230,11 +264,54 @@ impl WorkflowService {
                     )));
                 }
 
+                self.invalidate_queue_stats_cache().await;
                 Err(error)
             }
         }
     }
 
+    /// Returns one claimed queued job to pending without executing it.
+    ///
+    /// Workers call this while shutting down so other workers can pick the job
+    /// up immediately instead of waiting for its lease to expire. Returns false
+    /// when the job is no longer leased by this worker with `lease_token`.
+    pub async fn release_claimed_job(
+        &self,
+        worker_id: &str,
+        tenant_id: TenantId,
+        job_id: &str,
+        lease_token: &str,
+        reason: &str,
+    ) -> AppResult<bool> {
+        if self.execution_mode != WorkflowExecutionMode::Queued {
+            return Err(AppError::Conflict(
+                "queued workflow execution mode is not enabled".to_owned(),
+            ));
+        }
+
+        if worker_id.trim().is_empty() {
+            return Err(AppError::Validation(
+                "worker_id must not be empty".to_owned(),
+            ));
+        }
+
+        if lease_token.trim().is_empty() {
+            return Err(AppError::Validation(
+                "claimed workflow job lease_token must not be empty".to_owned(),
+            ));
+        }
+
+        let released = self
+            .repository
+            .release_job(tenant_id, job_id, worker_id, lease_token, reason)
+            .await?;
+        if released {
+            self.invalidate_queue_stats_cache().await;
+        }
+
+        Ok(released)
+    }
+
     /// Stores one worker heartbeat snapshot for queue observability.
     pub async fn heartbeat_worker(
         &self,
@@ -270,40 +347,91 @@ impl WorkflowService {
         active_window_seconds: u32,
         partition: Option<WorkflowClaimPartition>,
     ) -> AppResult<WorkflowQueueStats> {
+        let snapshot = self
+            .queue_stats_snapshot(WorkflowQueueStatsQuery {
+                active_window_seconds,
+                partition,
+                tenant_id: None,
+            })
+            .await?;
+
+        Ok(snapshot.stats)
+    }
+
+    /// Returns tenant-scoped queue stats with freshness metadata for dashboards.
+    pub async fn tenant_queue_stats(
+        &self,
+        actor: &UserIdentity,
+        active_window_seconds: u32,
+    ) -> AppResult<WorkflowQueueStatsSnapshot> {
+        self.require_workflow_read(actor).await?;
+        self.queue_stats_snapshot(WorkflowQueueStatsQuery {
+            active_window_seconds,
+            partition: None,
+            tenant_id: Some(actor.tenant_id()),
+        })
+        .await
+    }
+
+    /// Returns the configured queue stats cache ttl in seconds.
+    #[must_use]
+    pub fn queue_stats_cache_ttl_seconds(&self) -> u32 {
+        if self.queue_stats_cache.is_some() {
+            self.queue_stats_cache_ttl_seconds
+        } else {
+            0
+        }
+    }
+
+    async fn queue_stats_snapshot(
+        &self,
+        query: WorkflowQueueStatsQuery,
+    ) -> AppResult<WorkflowQueueStatsSnapshot> {
         if self.execution_mode != WorkflowExecutionMode::Queued {
             return Err(AppError::Conflict(
                 "queued workflow execution mode is not enabled".to_owned(),
             ));
         }
 
-        if active_window_seconds == 0 {
+        if query.active_window_seconds == 0 {
             return Err(AppError::Validation(
                 "active_window_seconds must be greater than zero".to_owned(),
             ));
         }
 
-        let query = WorkflowQueueStatsQuery {
-            active_window_seconds,
-            partition,
-        };
-
         if self.queue_stats_cache_ttl_seconds > 0
             && let Some(cache) = &self.queue_stats_cache
-            && let Some(stats) = cache.get_queue_stats(query).await?
+            && let Some(snapshot) = cache.get_queue_stats(query).await?
         {
-            return Ok(stats);
+            return Ok(WorkflowQueueStatsSnapshot {
+                served_from_cache: true,
+                ..snapshot
+            });
         }
 
-        let stats = self.repository.queue_stats(query).await?;
+        let snapshot = WorkflowQueueStatsSnapshot {
+            stats: self.repository.queue_stats(query).await?,
+            computed_at: Utc::now(),
+            served_from_cache: false,
+        };
 
         if self.queue_stats_cache_ttl_seconds > 0
             && let Some(cache) = &self.queue_stats_cache
         {
             cache
-                .set_queue_stats(query, stats, self.queue_stats_cache_ttl_seconds)
+                .set_queue_stats(query, snapshot, self.queue_stats_cache_ttl_seconds)
                 .await?;
         }
 
-        Ok(stats)
+        Ok(snapshot)
+    }
+
+    /// Drops cached queue stats after a queue mutation.
+    pub(super) async fn invalidate_queue_stats_cache(&self) {
+        if let Some(cache) = &self.queue_stats_cache {
+            // Invalidation is best-effort: cached snapshots still expire by ttl and the
+            // queue mutation that triggered it has already been committed.
+            let _ = cache.invalidate_queue_stats().await;
+        }
     }
 }
@@ -0,0 +1,138 @@
+use crate::workflow_ports::{
+    WORKFLOW_STUCK_JOB_DEFAULT_LIMIT, WORKFLOW_STUCK_JOB_MAX_LIMIT, WorkflowStuckJob,
+    WorkflowWorkerLeaseOwnership,
+};
+
+use super::*;
+
+/// Dead-letter reason recorded when an operator fails a job without one.
+const DEFAULT_STUCK_JOB_FAILURE_REASON: &str = "failed by operator after worker lease expired";
+
+impl WorkflowService {
+    /// Lists tenant jobs still leased after their lease expired.
+    pub async fn list_stuck_jobs(
+        &self,
+        actor: &UserIdentity,
+        limit: Option<usize>,
+    ) -> AppResult<Vec<WorkflowStuckJob>> {
+        self.require_workflow_manage(actor).await?;
+        self.require_queued_execution_mode()?;
+
+        self.repository
+            .list_stuck_jobs(
+                actor.tenant_id(),
+                limit
+                    .unwrap_or(WORKFLOW_STUCK_JOB_DEFAULT_LIMIT)
+                    .clamp(1, WORKFLOW_STUCK_JOB_MAX_LIMIT),
+            )
+            .await
+    }
+
+    /// Returns a stuck job to the queue so the next worker claim retries it.
+    ///
+    /// Only jobs whose lease already expired can be released, so a worker
+    /// still inside its lease is never interrupted.
+    pub async fn release_stuck_job(&self, actor: &UserIdentity, job_id: &str) -> AppResult<()> {
+        self.require_workflow_manage(actor).await?;
+        self.require_queued_execution_mode()?;
+
+        let reason = format!(
+            "released by '{}' after worker lease expired",
+            actor.subject()
+        );
+        if !self
+            .repository
+            .release_stuck_job(actor.tenant_id(), job_id, reason.as_str())
+            .await?
+        {
+            return Err(stuck_job_conflict(job_id));
+        }
+        self.invalidate_queue_stats_cache().await;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::WorkflowJobReleased,
+                resource_type: "workflow_job".to_owned(),
+                resource_id: job_id.to_owned(),
+                detail: Some(format!(
+                    "released stuck workflow job '{job_id}' to the queue"
+                )),
+            })
+            .await
+    }
+
+    /// Fails a stuck job and dead-letters its run without retrying it.
+    pub async fn fail_stuck_job(
+        &self,
+        actor: &UserIdentity,
+        job_id: &str,
+        reason: Option<&str>,
+    ) -> AppResult<WorkflowRun> {
+        self.require_workflow_manage(actor).await?;
+        self.require_queued_execution_mode()?;
+
+        let reason = reason
+            .map(str::trim)
+            .filter(|reason| !reason.is_empty())
+            .unwrap_or(DEFAULT_STUCK_JOB_FAILURE_REASON);
+        let run = self
+            .repository
+            .fail_stuck_job(actor.tenant_id(), job_id, reason)
+            .await?
+            .ok_or_else(|| stuck_job_conflict(job_id))?;
+        self.invalidate_queue_stats_cache().await;
+
+        self.audit_repository
+            .append_event(AuditEvent {
+                tenant_id: actor.tenant_id(),
+                subject: actor.subject().to_owned(),
+                action: AuditAction::WorkflowJobFailed,
+                resource_type: "workflow_job".to_owned(),
+                resource_id: job_id.to_owned(),
+                detail: Some(format!(
+                    "failed stuck workflow job '{job_id}' and dead-lettered run '{}': {reason}",
+                    run.run_id
+                )),
+            })
+            .await?;
+
+        Ok(run)
+    }
+
+    /// Returns the worker currently holding a coordination scope lease.
+    pub async fn inspect_worker_lease(
+        &self,
+        actor: &UserIdentity,
+        scope_key: &str,
+    ) -> AppResult<Option<WorkflowWorkerLeaseOwnership>> {
+        self.require_workflow_manage(actor).await?;
+        let coordinator = self.worker_lease_coordinator.as_ref().ok_or_else(|| {
+            AppError::Conflict("workflow worker lease coordination is not configured".to_owned())
+        })?;
+        if scope_key.trim().is_empty() {
+            return Err(AppError::Validation(
+                "workflow worker lease scope_key must not be empty".to_owned(),
+            ));
+        }
+
+        coordinator.inspect_lease(scope_key).await
+    }
+
+    fn require_queued_execution_mode(&self) -> AppResult<()> {
+        if self.execution_mode != WorkflowExecutionMode::Queued {
+            return Err(AppError::Conflict(
+                "queued workflow execution mode is not enabled".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn stuck_job_conflict(job_id: &str) -> AppError {
+    AppError::Conflict(format!(
+        "workflow job '{job_id}' is not stuck: it must be leased with an expired lease"
+    ))
+}
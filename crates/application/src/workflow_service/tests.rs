@@ -3,26 +3,39 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use chrono::Utc;
-use serde_json::json;
+use serde_json::{Value, json};
 use tokio::sync::Mutex;
 
 use qryvanta_core::{AppError, AppResult, TenantId, UserIdentity};
 use qryvanta_domain::{
-    Permission, WorkflowConditionOperator, WorkflowDefinition, WorkflowLifecycleState,
-    WorkflowStep, WorkflowTrigger,
+    Permission, WorkflowConditionOperator, WorkflowCredential, WorkflowCredentialAuth,
+    WorkflowDefinition, WorkflowHttpRetryPolicy, WorkflowInvocationMode, WorkflowLifecycleState,
+    WorkflowRunPriority, WorkflowStep, WorkflowStepBackoffStrategy, WorkflowStepRetryErrorClass,
+    WorkflowStepRetryPolicy, WorkflowTrigger, WorkflowTriggerFilter, WorkflowTriggerFilterOperator,
 };
 
 use crate::workflow_ports::{
     ClaimedRuntimeRecordWorkflowEvent, ClaimedWorkflowJob, CompleteWorkflowRunInput,
-    CreateWorkflowRunInput, SaveWorkflowInput, WorkflowActionDispatchRequest,
-    WorkflowActionDispatchType, WorkflowActionDispatcher, WorkflowClaimPartition,
-    WorkflowDelayService, WorkflowExecutionMode, WorkflowQueueStats, WorkflowQueueStatsQuery,
-    WorkflowRepository, WorkflowRun, WorkflowRunAttempt, WorkflowRunAttemptStatus,
-    WorkflowRunListQuery, WorkflowRunStatus, WorkflowRuntimeRecordService,
-    WorkflowScheduledTrigger, WorkflowWorkerHeartbeatInput,
+    CreateWorkflowRunInput, NewWorkflowBulkExecution, NewWorkflowInboundWebhook, SaveWorkflowInput,
+    StartWorkflowBulkExecutionInput, SuspendWorkflowRunInput, WorkflowActionDispatchRequest,
+    WorkflowActionDispatchResponse, WorkflowActionDispatchType, WorkflowActionDispatcher,
+    WorkflowBulkExecution, WorkflowBulkExecutionRunCounts, WorkflowClaimBackpressurePolicy,
+    WorkflowClaimLoadSignal, WorkflowClaimPartition, WorkflowCredentialRecord,
+    WorkflowCredentialRepository, WorkflowDelayService, WorkflowExecutionMode,
+    WorkflowInboundWebhook, WorkflowInboundWebhookCredentials, WorkflowInboundWebhookDelivery,
+    WorkflowInboundWebhookRepository, WorkflowQueueStats, WorkflowQueueStatsCache,
+    WorkflowQueueStatsQuery, WorkflowQueueStatsSnapshot, WorkflowRepository, WorkflowRun,
+    WorkflowRunAttempt, WorkflowRunAttemptStatus, WorkflowRunContinuation, WorkflowRunListQuery,
+    WorkflowRunStatus, WorkflowRuntimeRecordService, WorkflowScheduleKind,
+    WorkflowScheduledTrigger, WorkflowStuckJob, WorkflowTraceContextSource,
+    WorkflowWorkerHeartbeatInput, WorkflowWorkerLease, WorkflowWorkerLeaseCoordinator,
+    WorkflowWorkerLeaseOwnership,
 };
 use crate::{
-    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService, RuntimeFieldGrant,
+    AuditEvent, AuditRepository, AuthorizationRepository, AuthorizationService,
+    ContactConsentChange, ContactConsentRepository, FeatureFlag, FeatureFlagRepository,
+    FeatureFlagService, OperationDeadlines, OperationTimeouts, OperationTimer,
+    RecordContactConsentInput, RuntimeFieldGrant, SaveFeatureFlagInput, SecretEncryptor,
     TemporaryPermissionGrant,
 };
 
@@ -31,6 +44,38 @@ use super::WorkflowService;
 #[derive(Default)]
 struct FakeAuditRepository;
 
+#[derive(Default)]
+struct FakeQueueStatsCache {
+    entries: Mutex<HashMap<WorkflowQueueStatsQuery, WorkflowQueueStatsSnapshot>>,
+    invalidations: Mutex<usize>,
+}
+
+#[async_trait]
+impl WorkflowQueueStatsCache for FakeQueueStatsCache {
+    async fn get_queue_stats(
+        &self,
+        query: WorkflowQueueStatsQuery,
+    ) -> AppResult<Option<WorkflowQueueStatsSnapshot>> {
+        Ok(self.entries.lock().await.get(&query).copied())
+    }
+
+    async fn set_queue_stats(
+        &self,
+        query: WorkflowQueueStatsQuery,
+        snapshot: WorkflowQueueStatsSnapshot,
+        _ttl_seconds: u32,
+    ) -> AppResult<()> {
+        self.entries.lock().await.insert(query, snapshot);
+        Ok(())
+    }
+
+    async fn invalidate_queue_stats(&self) -> AppResult<()> {
+        self.entries.lock().await.clear();
+        *self.invalidations.lock().await += 1;
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl AuditRepository for FakeAuditRepository {
     async fn append_event(&self, _event: AuditEvent) -> AppResult<()> {
@@ -73,6 +118,31 @@ impl AuthorizationRepository for FakeAuthorizationRepository {
     ) -> AppResult<Option<TemporaryPermissionGrant>> {
         Ok(None)
     }
+
+    async fn list_team_peer_subjects(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+    ) -> AppResult<Vec<crate::SubjectRoleGrant>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        _tenant_id: TenantId,
+        _subject: &str,
+        _entity_logical_name: &str,
+    ) -> AppResult<Vec<crate::SubjectAppRolePermission>> {
+        Ok(Vec::new())
+    }
 }
 
 #[derive(Default)]
@@ -80,10 +150,14 @@ struct FakeWorkflowRepository {
     workflows: Mutex<HashMap<(TenantId, String), WorkflowDefinition>>,
     published_workflows: Mutex<HashMap<(TenantId, String, i32), WorkflowDefinition>>,
     runs: Mutex<Vec<WorkflowRun>>,
+    run_trace_contexts: Mutex<HashMap<String, String>>,
+    run_idempotency_keys: Mutex<HashMap<(String, String), String>>,
     attempts: Mutex<Vec<WorkflowRunAttempt>>,
     jobs: Mutex<Vec<FakeQueuedJob>>,
     schedule_ticks: Mutex<Vec<FakeScheduleTick>>,
+    bulk_executions: Mutex<Vec<WorkflowBulkExecution>>,
     fail_list_enabled_workflows_remaining: Mutex<i32>,
+    fail_enqueue_run_job_remaining: Mutex<i32>,
 }
 
 #[derive(Clone)]
@@ -95,8 +169,22 @@ struct FakeQueuedJob {
     leased_by: Option<String>,
     lease_token: Option<String>,
     lease_version: u32,
+    lease_expires_at: Option<chrono::DateTime<Utc>>,
     completed: bool,
     failed: bool,
+    available_at: Option<chrono::DateTime<Utc>>,
+    continuation: Option<WorkflowRunContinuation>,
+}
+
+impl FakeQueuedJob {
+    fn is_stuck(&self, now: chrono::DateTime<Utc>) -> bool {
+        self.leased_by.is_some()
+            && !self.completed
+            && !self.failed
+            && self
+                .lease_expires_at
+                .is_some_and(|lease_expires_at| lease_expires_at <= now)
+    }
 }
 
 #[derive(Clone)]
@@ -317,6 +405,16 @@ impl WorkflowRepository for FakeWorkflowRepository {
         input: CreateWorkflowRunInput,
     ) -> AppResult<WorkflowRun> {
         let run_id = format!("run-{}", self.runs.lock().await.len() + 1);
+        if let Some(idempotency_key) = input.idempotency_key.clone() {
+            let mut run_idempotency_keys = self.run_idempotency_keys.lock().await;
+            let key = (input.workflow_logical_name.clone(), idempotency_key);
+            if run_idempotency_keys.contains_key(&key) {
+                return Err(AppError::Conflict(
+                    "workflow run idempotency key already used".to_owned(),
+                ));
+            }
+            run_idempotency_keys.insert(key, run_id.clone());
+        }
         let run = WorkflowRun {
             run_id,
             workflow_logical_name: input.workflow_logical_name,
@@ -329,8 +427,17 @@ impl WorkflowRepository for FakeWorkflowRepository {
             dead_letter_reason: None,
             started_at: Utc::now(),
             finished_at: None,
+            resume_at: None,
+            parent_run_id: input.parent_run_id,
+            priority: input.priority,
         };
 
+        if let Some(trace_context) = input.trace_context {
+            self.run_trace_contexts
+                .lock()
+                .await
+                .insert(run.run_id.clone(), trace_context);
+        }
         self.runs.lock().await.push(run.clone());
         Ok(run)
     }
@@ -360,10 +467,14 @@ impl WorkflowRepository for FakeWorkflowRepository {
                 continue;
             };
 
-            let qryvanta_domain::WorkflowTrigger::ScheduleTick { schedule_key } =
-                published.trigger()
-            else {
-                continue;
+            let (kind, schedule_key) = match published.trigger() {
+                qryvanta_domain::WorkflowTrigger::ScheduleTick { schedule_key } => {
+                    (WorkflowScheduleKind::ScheduleTick, schedule_key)
+                }
+                qryvanta_domain::WorkflowTrigger::Scheduled { cron_expression } => {
+                    (WorkflowScheduleKind::Cron, cron_expression)
+                }
+                _ => continue,
             };
 
             if tenant_filter
@@ -373,6 +484,7 @@ impl WorkflowRepository for FakeWorkflowRepository {
             {
                 triggers.push(WorkflowScheduledTrigger {
                     tenant_id: *stored_tenant_id,
+                    kind,
                     schedule_key: schedule_key.clone(),
                 });
             }
@@ -519,7 +631,17 @@ impl WorkflowRepository for FakeWorkflowRepository {
     }
 
     async fn enqueue_run_job(&self, tenant_id: TenantId, run_id: &str) -> AppResult<()> {
+        let mut failures_remaining = self.fail_enqueue_run_job_remaining.lock().await;
+        if *failures_remaining > 0 {
+            *failures_remaining -= 1;
+            return Err(AppError::Internal("simulated enqueue failure".to_owned()));
+        }
+        drop(failures_remaining);
+
         let mut jobs = self.jobs.lock().await;
+        if jobs.iter().any(|job| job.run_id == run_id) {
+            return Ok(());
+        }
         let runs = self.runs.lock().await;
         let workflow_version = runs
             .iter()
@@ -535,8 +657,11 @@ impl WorkflowRepository for FakeWorkflowRepository {
             leased_by: None,
             lease_token: None,
             lease_version: 0,
+            lease_expires_at: None,
             completed: false,
             failed: false,
+            available_at: None,
+            continuation: None,
         });
         Ok(())
     }
@@ -545,31 +670,52 @@ impl WorkflowRepository for FakeWorkflowRepository {
         &self,
         worker_id: &str,
         limit: usize,
-        _lease_seconds: u32,
+        lease_seconds: u32,
         _partition: Option<WorkflowClaimPartition>,
         tenant_filter: Option<TenantId>,
     ) -> AppResult<Vec<ClaimedWorkflowJob>> {
         let mut jobs = self.jobs.lock().await;
         let published_workflows = self.published_workflows.lock().await;
-        let runs = self.runs.lock().await;
+        let mut runs = self.runs.lock().await;
         let mut claimed = Vec::new();
+        let now = Utc::now();
 
-        for job in jobs
-            .iter_mut()
-            .filter(|entry| {
+        let mut claimable_indexes: Vec<usize> = jobs
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| {
                 entry.leased_by.is_none()
                     && !entry.completed
                     && !entry.failed
+                    && entry
+                        .available_at
+                        .is_none_or(|available_at| available_at <= now)
                     && tenant_filter
                         .map(|selected_tenant_id| entry.tenant_id == selected_tenant_id)
                         .unwrap_or(true)
             })
-            .take(limit)
-        {
+            .map(|(index, _)| index)
+            .collect();
+        // Stable sort keeps FIFO order within one priority.
+        claimable_indexes.sort_by_key(|index| {
+            std::cmp::Reverse(
+                runs.iter()
+                    .find(|run| run.run_id == jobs[*index].run_id)
+                    .map(|run| run.priority)
+                    .unwrap_or_default(),
+            )
+        });
+
+        for index in claimable_indexes.into_iter().take(limit) {
+            let job = &mut jobs[index];
             let run = runs
-                .iter()
+                .iter_mut()
                 .find(|run| run.run_id == job.run_id)
                 .ok_or_else(|| AppError::NotFound(format!("run '{}' not found", job.run_id)))?;
+            if job.continuation.is_some() {
+                run.status = WorkflowRunStatus::Running;
+                run.resume_at = None;
+            }
             let workflow = published_workflows
                 .get(&(
                     job.tenant_id,
@@ -586,6 +732,7 @@ impl WorkflowRepository for FakeWorkflowRepository {
 
             job.leased_by = Some(worker_id.to_owned());
             job.lease_version = job.lease_version.saturating_add(1);
+            job.lease_expires_at = Some(now + chrono::Duration::seconds(i64::from(lease_seconds)));
             let lease_token = format!("lease-{}-{}", job.job_id, job.lease_version);
             job.lease_token = Some(lease_token.clone());
             claimed.push(ClaimedWorkflowJob {
@@ -596,6 +743,14 @@ impl WorkflowRepository for FakeWorkflowRepository {
                 workflow,
                 trigger_payload: run.trigger_payload.clone(),
                 lease_token,
+                continuation: job.continuation.clone(),
+                trace_context: self
+                    .run_trace_contexts
+                    .lock()
+                    .await
+                    .get(&job.run_id)
+                    .cloned(),
+                priority: run.priority,
             });
         }
 
@@ -663,6 +818,151 @@ impl WorkflowRepository for FakeWorkflowRepository {
         Ok(())
     }
 
+    async fn suspend_run_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        worker_id: &str,
+        lease_token: &str,
+        input: SuspendWorkflowRunInput,
+    ) -> AppResult<WorkflowRun> {
+        let mut jobs = self.jobs.lock().await;
+        let job = jobs
+            .iter_mut()
+            .find(|entry| entry.tenant_id == tenant_id && entry.job_id == job_id)
+            .ok_or_else(|| AppError::NotFound(format!("job '{job_id}' not found")))?;
+
+        if job.leased_by.as_deref() != Some(worker_id)
+            || job.lease_token.as_deref() != Some(lease_token)
+        {
+            return Err(AppError::Conflict(format!(
+                "job '{job_id}' is not leased by worker '{worker_id}' with matching lease token"
+            )));
+        }
+
+        job.leased_by = None;
+        job.lease_token = None;
+        job.available_at = Some(input.continuation.resume_at);
+
+        let mut runs = self.runs.lock().await;
+        let run = runs
+            .iter_mut()
+            .find(|run| run.run_id == input.run_id)
+            .ok_or_else(|| AppError::NotFound(format!("run '{}' not found", input.run_id)))?;
+        run.status = WorkflowRunStatus::Waiting;
+        run.attempts = input.attempts;
+        run.resume_at = Some(input.continuation.resume_at);
+        job.continuation = Some(input.continuation);
+        Ok(run.clone())
+    }
+
+    async fn list_stuck_jobs(
+        &self,
+        tenant_id: TenantId,
+        limit: usize,
+    ) -> AppResult<Vec<WorkflowStuckJob>> {
+        let jobs = self.jobs.lock().await;
+        let runs = self.runs.lock().await;
+        let now = Utc::now();
+
+        Ok(jobs
+            .iter()
+            .filter(|job| job.tenant_id == tenant_id && job.is_stuck(now))
+            .take(limit)
+            .filter_map(|job| {
+                let run = runs.iter().find(|run| run.run_id == job.run_id)?;
+                Some(WorkflowStuckJob {
+                    job_id: job.job_id.clone(),
+                    run_id: job.run_id.clone(),
+                    workflow_logical_name: run.workflow_logical_name.clone(),
+                    workflow_version: job.workflow_version,
+                    leased_by: job.leased_by.clone(),
+                    lease_expires_at: job.lease_expires_at?,
+                    run_attempts: run.attempts,
+                    last_error: None,
+                })
+            })
+            .collect())
+    }
+
+    async fn release_stuck_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        _reason: &str,
+    ) -> AppResult<bool> {
+        let mut jobs = self.jobs.lock().await;
+        let now = Utc::now();
+        let Some(job) = jobs.iter_mut().find(|entry| {
+            entry.tenant_id == tenant_id && entry.job_id == job_id && entry.is_stuck(now)
+        }) else {
+            return Ok(false);
+        };
+
+        job.leased_by = None;
+        job.lease_token = None;
+        job.lease_expires_at = None;
+        job.available_at = Some(now);
+        Ok(true)
+    }
+
+    async fn release_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        worker_id: &str,
+        lease_token: &str,
+        _reason: &str,
+    ) -> AppResult<bool> {
+        let mut jobs = self.jobs.lock().await;
+        let Some(job) = jobs.iter_mut().find(|entry| {
+            entry.tenant_id == tenant_id
+                && entry.job_id == job_id
+                && !entry.completed
+                && !entry.failed
+                && entry.leased_by.as_deref() == Some(worker_id)
+                && entry.lease_token.as_deref() == Some(lease_token)
+        }) else {
+            return Ok(false);
+        };
+
+        job.leased_by = None;
+        job.lease_token = None;
+        job.lease_expires_at = None;
+        job.available_at = Some(Utc::now());
+        Ok(true)
+    }
+
+    async fn fail_stuck_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        reason: &str,
+    ) -> AppResult<Option<WorkflowRun>> {
+        let mut jobs = self.jobs.lock().await;
+        let now = Utc::now();
+        let Some(job) = jobs.iter_mut().find(|entry| {
+            entry.tenant_id == tenant_id && entry.job_id == job_id && entry.is_stuck(now)
+        }) else {
+            return Ok(None);
+        };
+
+        job.failed = true;
+        job.leased_by = None;
+        job.lease_token = None;
+        job.lease_expires_at = None;
+
+        let mut runs = self.runs.lock().await;
+        let run = runs
+            .iter_mut()
+            .find(|run| run.run_id == job.run_id)
+            .ok_or_else(|| AppError::NotFound(format!("run '{}' not found", job.run_id)))?;
+        run.status = WorkflowRunStatus::DeadLettered;
+        run.dead_letter_reason = Some(reason.to_owned());
+        run.finished_at = Some(now);
+        Ok(Some(run.clone()))
+    }
+
     async fn upsert_worker_heartbeat(
         &self,
         _worker_id: &str,
@@ -671,15 +971,35 @@ impl WorkflowRepository for FakeWorkflowRepository {
         Ok(())
     }
 
-    async fn queue_stats(&self, _query: WorkflowQueueStatsQuery) -> AppResult<WorkflowQueueStats> {
-        Ok(WorkflowQueueStats {
+    async fn queue_stats(&self, query: WorkflowQueueStatsQuery) -> AppResult<WorkflowQueueStats> {
+        let jobs = self.jobs.lock().await;
+        let scoped_jobs = jobs.iter().filter(|job| {
+            query
+                .tenant_id
+                .is_none_or(|tenant_id| job.tenant_id == tenant_id)
+        });
+        let mut stats = WorkflowQueueStats {
             pending_jobs: 0,
             leased_jobs: 0,
             completed_jobs: 0,
             failed_jobs: 0,
             expired_leases: 0,
             active_workers: 0,
-        })
+        };
+
+        for job in scoped_jobs {
+            if job.completed {
+                stats.completed_jobs += 1;
+            } else if job.failed {
+                stats.failed_jobs += 1;
+            } else if job.leased_by.is_some() {
+                stats.leased_jobs += 1;
+            } else {
+                stats.pending_jobs += 1;
+            }
+        }
+
+        Ok(stats)
     }
 
     async fn append_run_attempt(
@@ -727,6 +1047,24 @@ impl WorkflowRepository for FakeWorkflowRepository {
             .cloned())
     }
 
+    async fn find_run_by_idempotency_key(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+        idempotency_key: &str,
+    ) -> AppResult<Option<WorkflowRun>> {
+        let run_id = self
+            .run_idempotency_keys
+            .lock()
+            .await
+            .get(&(workflow_logical_name.to_owned(), idempotency_key.to_owned()))
+            .cloned();
+        match run_id {
+            Some(run_id) => self.find_run(tenant_id, run_id.as_str()).await,
+            None => Ok(None),
+        }
+    }
+
     async fn list_run_attempts(
         &self,
         _tenant_id: TenantId,
@@ -741,12 +1079,68 @@ impl WorkflowRepository for FakeWorkflowRepository {
             .cloned()
             .collect())
     }
+
+    async fn create_bulk_execution(
+        &self,
+        _tenant_id: TenantId,
+        execution: NewWorkflowBulkExecution,
+    ) -> AppResult<WorkflowBulkExecution> {
+        let mut bulk_executions = self.bulk_executions.lock().await;
+        let execution = WorkflowBulkExecution {
+            bulk_execution_id: format!("bulk-{}", bulk_executions.len() + 1),
+            workflow_logical_name: execution.workflow_logical_name,
+            entity_logical_name: execution.entity_logical_name,
+            view_logical_name: execution.view_logical_name,
+            total_records: execution.total_records,
+            requested_by_subject: execution.requested_by_subject,
+            created_at: Utc::now(),
+        };
+        bulk_executions.push(execution.clone());
+        Ok(execution)
+    }
+
+    async fn find_bulk_execution(
+        &self,
+        _tenant_id: TenantId,
+        bulk_execution_id: &str,
+    ) -> AppResult<Option<WorkflowBulkExecution>> {
+        Ok(self
+            .bulk_executions
+            .lock()
+            .await
+            .iter()
+            .find(|execution| execution.bulk_execution_id == bulk_execution_id)
+            .cloned())
+    }
+
+    async fn bulk_execution_run_counts(
+        &self,
+        _tenant_id: TenantId,
+        bulk_execution_id: &str,
+    ) -> AppResult<WorkflowBulkExecutionRunCounts> {
+        let mut counts = WorkflowBulkExecutionRunCounts::default();
+        for run in self.runs.lock().await.iter().filter(|run| {
+            run.trigger_payload["bulk_execution_id"].as_str() == Some(bulk_execution_id)
+        }) {
+            match run.status {
+                WorkflowRunStatus::Succeeded => counts.succeeded += 1,
+                WorkflowRunStatus::DeadLettered => counts.dead_lettered += 1,
+                _ => counts.pending += 1,
+            }
+            counts.last_finished_at = counts.last_finished_at.max(run.finished_at);
+        }
+        Ok(counts)
+    }
 }
 
 struct FakeRuntimeRecordService {
     assume_entities_published: bool,
     failures_remaining: Mutex<i32>,
     published_entities: Mutex<HashSet<String>>,
+    published_fields: Mutex<HashMap<String, Vec<String>>>,
+    views: Mutex<HashMap<(String, String), qryvanta_domain::ViewDefinition>>,
+    related_records: Mutex<HashMap<(String, String, String), qryvanta_domain::RuntimeRecord>>,
+    related_record_lookups: Mutex<usize>,
     created_records: Mutex<Vec<(String, serde_json::Value)>>,
     updated_records: Mutex<Vec<(String, String, serde_json::Value)>>,
     deleted_records: Mutex<Vec<(String, String)>>,
@@ -762,6 +1156,10 @@ impl Default for FakeRuntimeRecordService {
             assume_entities_published: true,
             failures_remaining: Mutex::new(0),
             published_entities: Mutex::new(HashSet::new()),
+            published_fields: Mutex::new(HashMap::new()),
+            views: Mutex::new(HashMap::new()),
+            related_records: Mutex::new(HashMap::new()),
+            related_record_lookups: Mutex::new(0),
             created_records: Mutex::new(Vec::new()),
             updated_records: Mutex::new(Vec::new()),
             deleted_records: Mutex::new(Vec::new()),
@@ -788,6 +1186,62 @@ impl WorkflowRuntimeRecordService for FakeRuntimeRecordService {
                 .contains(entity_logical_name))
     }
 
+    async fn published_entity_field_names(
+        &self,
+        actor: &UserIdentity,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<Vec<String>>> {
+        if !self
+            .has_published_entity_schema(actor, entity_logical_name)
+            .await?
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            self.published_fields
+                .lock()
+                .await
+                .get(entity_logical_name)
+                .cloned()
+                .unwrap_or_default(),
+        ))
+    }
+
+    async fn find_view_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+    ) -> AppResult<Option<qryvanta_domain::ViewDefinition>> {
+        Ok(self
+            .views
+            .lock()
+            .await
+            .get(&(entity_logical_name.to_owned(), view_logical_name.to_owned()))
+            .cloned())
+    }
+
+    async fn get_related_runtime_record_unchecked(
+        &self,
+        _actor: &UserIdentity,
+        entity_logical_name: &str,
+        relation_field_logical_name: &str,
+        related_record_id: &str,
+    ) -> AppResult<Option<qryvanta_domain::RuntimeRecord>> {
+        *self.related_record_lookups.lock().await += 1;
+        Ok(self
+            .related_records
+            .lock()
+            .await
+            .get(&(
+                entity_logical_name.to_owned(),
+                relation_field_logical_name.to_owned(),
+                related_record_id.to_owned(),
+            ))
+            .cloned())
+    }
+
     async fn update_runtime_record_unchecked(
         &self,
         _actor: &UserIdentity,
@@ -915,11 +1369,15 @@ struct FakeActionDispatcher {
     dispatched_requests: Mutex<Vec<WorkflowActionDispatchRequest>>,
     failures_remaining: Mutex<i32>,
     failure_messages: Mutex<Vec<String>>,
+    responses: Mutex<Vec<WorkflowActionDispatchResponse>>,
 }
 
 #[async_trait]
 impl WorkflowActionDispatcher for FakeActionDispatcher {
-    async fn dispatch_action(&self, request: WorkflowActionDispatchRequest) -> AppResult<()> {
+    async fn dispatch_action(
+        &self,
+        request: WorkflowActionDispatchRequest,
+    ) -> AppResult<Option<WorkflowActionDispatchResponse>> {
         self.dispatched_requests.lock().await.push(request);
 
         let mut failure_messages = self.failure_messages.lock().await;
@@ -935,7 +1393,77 @@ impl WorkflowActionDispatcher for FakeActionDispatcher {
             ));
         }
 
-        Ok(())
+        let mut responses = self.responses.lock().await;
+        Ok((!responses.is_empty()).then(|| responses.remove(0)))
+    }
+}
+
+#[derive(Default)]
+struct FakeCredentialRepository {
+    credentials: Mutex<HashMap<(TenantId, String), WorkflowCredentialRecord>>,
+}
+
+#[async_trait]
+impl WorkflowCredentialRepository for FakeCredentialRepository {
+    async fn save_workflow_credential(
+        &self,
+        tenant_id: TenantId,
+        credential: WorkflowCredential,
+        updated_by_subject: &str,
+    ) -> AppResult<WorkflowCredentialRecord> {
+        let record = WorkflowCredentialRecord {
+            credential,
+            updated_by_subject: updated_by_subject.to_owned(),
+            updated_at: Utc::now(),
+        };
+        self.credentials.lock().await.insert(
+            (
+                tenant_id,
+                record.credential.logical_name().as_str().to_owned(),
+            ),
+            record.clone(),
+        );
+        Ok(record)
+    }
+
+    async fn list_workflow_credentials(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<WorkflowCredentialRecord>> {
+        Ok(self
+            .credentials
+            .lock()
+            .await
+            .iter()
+            .filter(|((record_tenant_id, _), _)| *record_tenant_id == tenant_id)
+            .map(|(_, record)| record.clone())
+            .collect())
+    }
+
+    async fn find_workflow_credential(
+        &self,
+        tenant_id: TenantId,
+        logical_name: &str,
+    ) -> AppResult<Option<WorkflowCredentialRecord>> {
+        Ok(self
+            .credentials
+            .lock()
+            .await
+            .get(&(tenant_id, logical_name.to_owned()))
+            .cloned())
+    }
+
+    async fn delete_workflow_credential(
+        &self,
+        tenant_id: TenantId,
+        logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(self
+            .credentials
+            .lock()
+            .await
+            .remove(&(tenant_id, logical_name.to_owned()))
+            .is_some())
     }
 }
 
@@ -952,12 +1480,101 @@ impl WorkflowDelayService for FakeDelayService {
     }
 }
 
-fn build_service(
-    grants: HashMap<(TenantId, String), Vec<Permission>>,
-    repository: Arc<FakeWorkflowRepository>,
-    runtime_service: Arc<FakeRuntimeRecordService>,
-    execution_mode: WorkflowExecutionMode,
-    action_dispatcher: Option<Arc<FakeActionDispatcher>>,
+struct FakeTraceContextSource;
+
+impl WorkflowTraceContextSource for FakeTraceContextSource {
+    fn current_trace_context(&self) -> Option<String> {
+        Some(FAKE_TRACE_CONTEXT.to_owned())
+    }
+}
+
+const FAKE_TRACE_CONTEXT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+struct FakeContactConsentRepository {
+    email_consents: HashMap<String, bool>,
+}
+
+#[async_trait]
+impl ContactConsentRepository for FakeContactConsentRepository {
+    async fn record_consent(
+        &self,
+        _tenant_id: TenantId,
+        _recorded_by_subject: &str,
+        _input: RecordContactConsentInput,
+    ) -> AppResult<ContactConsentChange> {
+        Err(AppError::Internal("not used in workflow tests".to_owned()))
+    }
+
+    async fn list_contact_consents(
+        &self,
+        _tenant_id: TenantId,
+        _contact_record_id: &str,
+    ) -> AppResult<Vec<crate::ContactConsent>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_contact_consent_history(
+        &self,
+        _tenant_id: TenantId,
+        _contact_record_id: &str,
+    ) -> AppResult<Vec<ContactConsentChange>> {
+        Ok(Vec::new())
+    }
+
+    async fn email_consent_for_address(
+        &self,
+        _tenant_id: TenantId,
+        email: &str,
+        _purpose: &str,
+    ) -> AppResult<Option<bool>> {
+        Ok(self.email_consents.get(email).copied())
+    }
+}
+
+struct FakeFeatureFlagRepository {
+    flags: Vec<FeatureFlag>,
+}
+
+#[async_trait]
+impl FeatureFlagRepository for FakeFeatureFlagRepository {
+    async fn list_feature_flags(&self, _tenant_id: TenantId) -> AppResult<Vec<FeatureFlag>> {
+        Ok(self.flags.clone())
+    }
+
+    async fn find_feature_flag(
+        &self,
+        _tenant_id: TenantId,
+        flag_key: &str,
+    ) -> AppResult<Option<FeatureFlag>> {
+        Ok(self
+            .flags
+            .iter()
+            .find(|flag| flag.flag_key == flag_key)
+            .cloned())
+    }
+
+    async fn save_feature_flag(
+        &self,
+        _tenant_id: TenantId,
+        _updated_by_subject: &str,
+        _input: SaveFeatureFlagInput,
+    ) -> AppResult<FeatureFlag> {
+        Err(AppError::Internal(
+            "feature flags are read-only in workflow tests".to_owned(),
+        ))
+    }
+
+    async fn delete_feature_flag(&self, _tenant_id: TenantId, _flag_key: &str) -> AppResult<bool> {
+        Ok(false)
+    }
+}
+
+fn build_service(
+    grants: HashMap<(TenantId, String), Vec<Permission>>,
+    repository: Arc<FakeWorkflowRepository>,
+    runtime_service: Arc<FakeRuntimeRecordService>,
+    execution_mode: WorkflowExecutionMode,
+    action_dispatcher: Option<Arc<FakeActionDispatcher>>,
 ) -> WorkflowService {
     let audit_repository = Arc::new(FakeAuditRepository);
     let authorization_service = AuthorizationService::new(
@@ -1011,9 +1628,15 @@ async fn execute_workflow_dead_letters_after_max_attempts() {
                 steps: vec![WorkflowStep::CreateRuntimeRecord {
                     entity_logical_name: "contact".to_owned(),
                     data: json!({"name": "Alice"}),
+                    output_key: None,
+                    retry_policy: None,
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1034,6 +1657,147 @@ async fn execute_workflow_dead_letters_after_max_attempts() {
     assert_eq!(attempts.unwrap_or_default().len(), 2);
 }
 
+struct ElapsedTimer;
+
+#[async_trait]
+impl OperationTimer for ElapsedTimer {
+    async fn sleep(&self, _duration: std::time::Duration) {}
+}
+
+#[tokio::test]
+async fn execute_workflow_over_dispatch_budget_dead_letters_between_steps() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository.clone(),
+        runtime_service.clone(),
+        WorkflowExecutionMode::Inline,
+        None,
+    )
+    .with_operation_deadlines(OperationDeadlines::new(
+        Arc::new(ElapsedTimer),
+        OperationTimeouts {
+            workflow_dispatch: Some(std::time::Duration::ZERO),
+            ..OperationTimeouts::default()
+        },
+    ));
+
+    let saved = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "create_contact".to_owned(),
+                display_name: "Create Contact".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::CreateRuntimeRecord {
+                    entity_logical_name: "contact".to_owned(),
+                    data: json!({"name": "Alice"}),
+                    output_key: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 3,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(saved.is_ok());
+
+    let result = service
+        .execute_workflow(&actor, "create_contact", json!({"manual": true}))
+        .await;
+    assert!(matches!(result, Err(AppError::Timeout(_))));
+    assert!(runtime_service.created_records.lock().await.is_empty());
+
+    let runs = repository.runs.lock().await.clone();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].status, WorkflowRunStatus::DeadLettered);
+    assert_eq!(runs[0].attempts, 1);
+    assert_eq!(
+        runs[0].dead_letter_reason.as_deref(),
+        Some("workflow dispatch exceeded its 0 ms budget and was cancelled")
+    );
+}
+
+#[tokio::test]
+async fn disabled_workflows_feature_flag_blocks_management_but_not_reads() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let grants = HashMap::from([(
+        (tenant_id, "maker".to_owned()),
+        vec![Permission::WorkflowManage, Permission::WorkflowRead],
+    )]);
+    let audit_repository = Arc::new(FakeAuditRepository);
+    let feature_flag_service = FeatureFlagService::new(
+        AuthorizationService::new(
+            Arc::new(FakeAuthorizationRepository {
+                grants: grants.clone(),
+            }),
+            audit_repository.clone(),
+        ),
+        Arc::new(FakeFeatureFlagRepository {
+            flags: vec![FeatureFlag {
+                flag_key: crate::WORKFLOWS_FEATURE_FLAG.to_owned(),
+                description: None,
+                enabled: false,
+                role_overrides: Vec::new(),
+                updated_by_subject: "admin".to_owned(),
+                updated_at: Utc::now(),
+            }],
+        }),
+        audit_repository,
+    );
+    let service = build_service(
+        grants,
+        Arc::new(FakeWorkflowRepository::default()),
+        Arc::new(FakeRuntimeRecordService::default()),
+        WorkflowExecutionMode::Inline,
+        None,
+    )
+    .with_feature_flags(feature_flag_service);
+
+    let saved = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "create_contact".to_owned(),
+                display_name: "Create Contact".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::CreateRuntimeRecord {
+                    entity_logical_name: "contact".to_owned(),
+                    data: json!({"name": "Alice"}),
+                    output_key: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 1,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(matches!(saved, Err(AppError::Forbidden(_))));
+    let executed = service
+        .execute_workflow(&actor, "create_contact", json!({}))
+        .await;
+    assert!(matches!(executed, Err(AppError::Forbidden(_))));
+    assert!(service.list_workflows(&actor).await.is_ok());
+}
+
 #[tokio::test]
 async fn retry_run_step_retries_failed_action_without_new_run() {
     let tenant_id = TenantId::new();
@@ -1064,9 +1828,15 @@ async fn retry_run_step_retries_failed_action_without_new_run() {
                 steps: vec![WorkflowStep::CreateRuntimeRecord {
                     entity_logical_name: "contact".to_owned(),
                     data: json!({"name": "Alice"}),
+                    output_key: None,
+                    retry_policy: None,
                 }],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1128,9 +1898,15 @@ async fn replay_run_reconstructs_ordered_timeline_and_stable_checksum() {
                 steps: vec![WorkflowStep::CreateRuntimeRecord {
                     entity_logical_name: "contact".to_owned(),
                     data: json!({"name": "Alice"}),
+                    output_key: None,
+                    retry_policy: None,
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1204,6 +1980,10 @@ async fn replay_run_rejects_mismatched_workflow_name() {
                 }],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1256,6 +2036,10 @@ async fn dispatch_runtime_record_created_executes_matching_workflows() {
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1301,6 +2085,10 @@ async fn dispatch_runtime_record_updated_executes_matching_workflows() {
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1320,6 +2108,210 @@ async fn dispatch_runtime_record_updated_executes_matching_workflows() {
     assert_eq!(dispatched.unwrap_or_default(), 1);
 }
 
+#[tokio::test]
+async fn dispatch_runtime_record_updated_skips_workflows_whose_trigger_filters_do_not_match() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    runtime_service.published_fields.lock().await.insert(
+        "contact".to_owned(),
+        vec!["status".to_owned(), "name".to_owned()],
+    );
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+    let input = |field_logical_name: &str| SaveWorkflowInput {
+        logical_name: "on_contact_closed".to_owned(),
+        display_name: "On Contact Closed".to_owned(),
+        description: None,
+        trigger: WorkflowTrigger::RuntimeRecordUpdated {
+            entity_logical_name: "contact".to_owned(),
+        },
+        steps: vec![WorkflowStep::LogMessage {
+            message: "closed".to_owned(),
+        }],
+        max_attempts: 2,
+        is_enabled: true,
+        trigger_filters: vec![WorkflowTriggerFilter {
+            field_logical_name: field_logical_name.to_owned(),
+            operator: WorkflowTriggerFilterOperator::ChangedTo,
+            value: Some(json!("closed")),
+        }],
+        trigger_changed_fields: Vec::new(),
+        trigger_view_logical_name: None,
+        priority: WorkflowRunPriority::Normal,
+    };
+
+    let unknown_field = service.save_workflow(&actor, input("stage")).await;
+    assert!(matches!(unknown_field, Err(AppError::Validation(_))));
+    assert!(service.save_workflow(&actor, input("status")).await.is_ok());
+
+    let unrelated_change = service
+        .dispatch_runtime_record_updated(
+            &actor,
+            "contact",
+            "record-1",
+            Some(&json!({"status": "closed", "name": "Ada"})),
+            &json!({"status": "closed", "name": "Ada L."}),
+        )
+        .await;
+    assert_eq!(unrelated_change.unwrap_or_default(), 0);
+
+    let matching_change = service
+        .dispatch_runtime_record_updated(
+            &actor,
+            "contact",
+            "record-1",
+            Some(&json!({"status": "open"})),
+            &json!({"status": "closed"}),
+        )
+        .await;
+    assert_eq!(matching_change.unwrap_or_default(), 1);
+}
+
+#[tokio::test]
+async fn dispatch_runtime_record_updated_requires_watched_field_change_and_view_match() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    runtime_service.published_fields.lock().await.insert(
+        "contact".to_owned(),
+        vec!["status".to_owned(), "tier".to_owned(), "name".to_owned()],
+    );
+    let view = |logical_name: &str, value: Value| {
+        qryvanta_domain::ViewDefinition::new(
+            "contact",
+            logical_name,
+            logical_name,
+            qryvanta_domain::ViewType::Grid,
+            vec![
+                qryvanta_domain::ViewColumn::new("name", 0, None, None)
+                    .unwrap_or_else(|_| unreachable!()),
+            ],
+            None,
+            Some(
+                qryvanta_domain::ViewFilterGroup::new(
+                    qryvanta_domain::LogicalMode::And,
+                    vec![
+                        qryvanta_domain::ViewFilterCondition::new(
+                            "tier",
+                            qryvanta_domain::FilterOperator::Eq,
+                            value,
+                        )
+                        .unwrap_or_else(|_| unreachable!()),
+                    ],
+                )
+                .unwrap_or_else(|_| unreachable!()),
+            ),
+            false,
+        )
+        .unwrap_or_else(|_| unreachable!())
+    };
+    runtime_service.views.lock().await.extend([
+        (
+            ("contact".to_owned(), "vip_contacts".to_owned()),
+            view("vip_contacts", json!("vip")),
+        ),
+        (
+            ("contact".to_owned(), "my_contacts".to_owned()),
+            view("my_contacts", json!("@me")),
+        ),
+    ]);
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+    let input = |changed_field: &str, view_logical_name: &str| SaveWorkflowInput {
+        logical_name: "sync_vip_contacts".to_owned(),
+        display_name: "Sync VIP Contacts".to_owned(),
+        description: None,
+        trigger: WorkflowTrigger::RuntimeRecordUpdated {
+            entity_logical_name: "contact".to_owned(),
+        },
+        steps: vec![WorkflowStep::LogMessage {
+            message: "synced".to_owned(),
+        }],
+        max_attempts: 2,
+        is_enabled: true,
+        trigger_filters: Vec::new(),
+        trigger_changed_fields: vec![changed_field.to_owned()],
+        trigger_view_logical_name: Some(view_logical_name.to_owned()),
+        priority: WorkflowRunPriority::Normal,
+    };
+
+    let unknown_field = service
+        .save_workflow(&actor, input("stage", "vip_contacts"))
+        .await;
+    assert!(matches!(unknown_field, Err(AppError::Validation(_))));
+    let unknown_view = service
+        .save_workflow(&actor, input("status", "missing_view"))
+        .await;
+    assert!(matches!(unknown_view, Err(AppError::Validation(_))));
+    let subject_view = service
+        .save_workflow(&actor, input("status", "my_contacts"))
+        .await;
+    assert!(matches!(subject_view, Err(AppError::Validation(_))));
+    assert!(
+        service
+            .save_workflow(&actor, input("status", "vip_contacts"))
+            .await
+            .is_ok()
+    );
+
+    let dispatch = |previous: Value, current: Value| {
+        let service = service.clone();
+        let actor = actor.clone();
+        async move {
+            service
+                .dispatch_runtime_record_updated(
+                    &actor,
+                    "contact",
+                    "record-1",
+                    Some(&previous),
+                    &current,
+                )
+                .await
+                .unwrap_or_default()
+        }
+    };
+
+    let unwatched_change = dispatch(
+        json!({"status": "open", "tier": "vip", "name": "Ada"}),
+        json!({"status": "open", "tier": "vip", "name": "Ada L."}),
+    )
+    .await;
+    assert_eq!(unwatched_change, 0);
+
+    let outside_view = dispatch(
+        json!({"status": "open", "tier": "basic"}),
+        json!({"status": "closed", "tier": "basic"}),
+    )
+    .await;
+    assert_eq!(outside_view, 0);
+
+    let matching = dispatch(
+        json!({"status": "open", "tier": "vip"}),
+        json!({"status": "closed", "tier": "vip"}),
+    )
+    .await;
+    assert_eq!(matching, 1);
+}
+
 #[tokio::test]
 async fn dispatch_schedule_tick_executes_matching_workflows() {
     let tenant_id = TenantId::new();
@@ -1352,6 +2344,10 @@ async fn dispatch_schedule_tick_executes_matching_workflows() {
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1401,6 +2397,10 @@ async fn dispatch_schedule_tick_normalizes_timestamp_timezone_and_clock_skew() {
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1467,6 +2467,10 @@ async fn dispatch_schedule_tick_rejects_invalid_tick_timestamp() {
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1520,6 +2524,10 @@ async fn dispatch_webhook_received_executes_matching_workflows() {
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1598,6 +2606,10 @@ async fn dispatch_form_submitted_executes_matching_workflows() {
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1670,6 +2682,10 @@ async fn dispatch_inbound_email_received_executes_matching_workflows() {
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1751,6 +2767,10 @@ async fn dispatch_approval_event_received_executes_matching_workflows() {
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1835,6 +2855,10 @@ async fn dispatch_due_schedule_ticks_enqueues_due_runs_once_per_slot() {
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1874,53 +2898,164 @@ async fn dispatch_due_schedule_ticks_enqueues_due_runs_once_per_slot() {
 }
 
 #[tokio::test]
-async fn dispatch_due_schedule_ticks_skips_non_matching_tenant_scope() {
-    let tenant_a = TenantId::new();
-    let tenant_b = TenantId::new();
-    let actor_a = UserIdentity::new("maker-a", "maker-a", None, tenant_a);
-    let actor_b = UserIdentity::new("maker-b", "maker-b", None, tenant_b);
+async fn dispatch_due_schedule_ticks_enqueues_cron_scheduled_workflows() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
     let service = build_service(
-        HashMap::from([
-            (
-                (tenant_a, "maker-a".to_owned()),
-                vec![Permission::WorkflowManage, Permission::WorkflowRead],
-            ),
-            (
-                (tenant_b, "maker-b".to_owned()),
-                vec![Permission::WorkflowManage, Permission::WorkflowRead],
-            ),
-        ]),
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
         repository.clone(),
         runtime_service,
         WorkflowExecutionMode::Queued,
         None,
     );
 
-    for (actor, logical_name, schedule_key) in [
-        (&actor_a, "tenant_a_schedule", "hourly"),
-        (&actor_b, "tenant_b_schedule", "hourly"),
-    ] {
-        let save_result = service
-            .save_workflow(
-                actor,
-                SaveWorkflowInput {
-                    logical_name: logical_name.to_owned(),
-                    display_name: logical_name.to_owned(),
-                    description: None,
-                    trigger: WorkflowTrigger::ScheduleTick {
-                        schedule_key: schedule_key.to_owned(),
-                    },
-                    steps: vec![WorkflowStep::LogMessage {
-                        message: "tick".to_owned(),
-                    }],
-                    max_attempts: 2,
-                    is_enabled: true,
+    let save_result = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "minutely_cron".to_owned(),
+                display_name: "Minutely Cron".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Scheduled {
+                    cron_expression: "* * * * *".to_owned(),
                 },
-            )
-            .await;
-        assert!(save_result.is_ok());
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "tick".to_owned(),
+                }],
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(save_result.is_ok());
+
+    let first = service
+        .dispatch_due_schedule_ticks("worker-alpha", 30, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(first.claimed_ticks, 1);
+    assert_eq!(first.dispatched_workflows, 1);
+
+    let runs = repository.runs.lock().await.clone();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].trigger_type, "scheduled");
+    assert_eq!(
+        runs[0].trigger_payload["cron_expression"],
+        json!("* * * * *")
+    );
+    assert_eq!(runs[0].trigger_payload["event"], json!("scheduled"));
+
+    let ticks = repository.schedule_ticks.lock().await.clone();
+    assert_eq!(ticks.len(), 1);
+    assert!(ticks[0].slot_key.starts_with("cron:"));
+    assert!(ticks[0].completed);
+}
+
+#[tokio::test]
+async fn save_workflow_rejects_invalid_cron_expression() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository.clone(),
+        runtime_service,
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+
+    let result = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "broken_cron".to_owned(),
+                display_name: "Broken Cron".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Scheduled {
+                    cron_expression: "0 25 * * *".to_owned(),
+                },
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "tick".to_owned(),
+                }],
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Validation(_))));
+    assert!(repository.workflows.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn dispatch_due_schedule_ticks_skips_non_matching_tenant_scope() {
+    let tenant_a = TenantId::new();
+    let tenant_b = TenantId::new();
+    let actor_a = UserIdentity::new("maker-a", "maker-a", None, tenant_a);
+    let actor_b = UserIdentity::new("maker-b", "maker-b", None, tenant_b);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([
+            (
+                (tenant_a, "maker-a".to_owned()),
+                vec![Permission::WorkflowManage, Permission::WorkflowRead],
+            ),
+            (
+                (tenant_b, "maker-b".to_owned()),
+                vec![Permission::WorkflowManage, Permission::WorkflowRead],
+            ),
+        ]),
+        repository.clone(),
+        runtime_service,
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+
+    for (actor, logical_name, schedule_key) in [
+        (&actor_a, "tenant_a_schedule", "hourly"),
+        (&actor_b, "tenant_b_schedule", "hourly"),
+    ] {
+        let save_result = service
+            .save_workflow(
+                actor,
+                SaveWorkflowInput {
+                    logical_name: logical_name.to_owned(),
+                    display_name: logical_name.to_owned(),
+                    description: None,
+                    trigger: WorkflowTrigger::ScheduleTick {
+                        schedule_key: schedule_key.to_owned(),
+                    },
+                    steps: vec![WorkflowStep::LogMessage {
+                        message: "tick".to_owned(),
+                    }],
+                    max_attempts: 2,
+                    is_enabled: true,
+                    trigger_filters: Vec::new(),
+                    trigger_changed_fields: Vec::new(),
+                    trigger_view_logical_name: None,
+                    priority: WorkflowRunPriority::Normal,
+                },
+            )
+            .await;
+        assert!(save_result.is_ok());
     }
 
     let result = service
@@ -1970,13 +3105,22 @@ async fn execute_workflow_dispatches_external_integration_actions_with_idempoten
                     method: "POST".to_owned(),
                     url: "https://example.org/hook".to_owned(),
                     headers: None,
+                    credential: None,
                     header_secret_refs: None,
                     body: Some(json!({
                         "record_id": "{{trigger.payload.record_id}}"
                     })),
+                    expected_status: None,
+                    retry: None,
+                    output_key: None,
+                    retry_policy: None,
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
@@ -1999,14 +3143,20 @@ async fn execute_workflow_dispatches_external_integration_actions_with_idempoten
 }
 
 #[tokio::test]
-async fn external_integration_idempotency_key_is_stable_across_run_retries() {
+async fn execute_workflow_captures_http_response_for_later_steps() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
     let action_dispatcher = Arc::new(FakeActionDispatcher::default());
-    *action_dispatcher.failures_remaining.lock().await = 1;
-
+    action_dispatcher
+        .responses
+        .lock()
+        .await
+        .push(WorkflowActionDispatchResponse {
+            status_code: 201,
+            body: json!({"id": "ext-42"}),
+        });
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
@@ -2018,454 +3168,455 @@ async fn external_integration_idempotency_key_is_stable_across_run_retries() {
         Some(action_dispatcher.clone()),
     );
 
-    let saved = service
-        .save_workflow(
-            &actor,
-            SaveWorkflowInput {
-                logical_name: "retry_http_dispatch".to_owned(),
-                display_name: "Retry HTTP Dispatch".to_owned(),
-                description: None,
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::HttpRequest {
-                    method: "POST".to_owned(),
-                    url: "https://example.org/retry".to_owned(),
-                    headers: None,
-                    header_secret_refs: None,
-                    body: None,
-                }],
-                max_attempts: 2,
-                is_enabled: true,
-            },
-        )
-        .await;
-    assert!(saved.is_ok());
+    let save_result =
+        service
+            .save_workflow(
+                &actor,
+                SaveWorkflowInput {
+                    logical_name: "sync_external".to_owned(),
+                    display_name: "Sync External".to_owned(),
+                    description: None,
+                    trigger: WorkflowTrigger::Manual,
+                    steps: vec![
+                    WorkflowStep::HttpRequest {
+                        method: "POST".to_owned(),
+                        url: "https://example.org/records/{{trigger.payload.record_id}}".to_owned(),
+                        headers: None,
+                        credential: None,
+                        header_secret_refs: None,
+                        body: None,
+                        expected_status: Some(201),
+                        retry: Some(WorkflowHttpRetryPolicy {
+                            max_attempts: 4,
+                            backoff_ms: 250,
+                        }),
+                        output_key: None, retry_policy: None, },
+                    WorkflowStep::LogMessage {
+                        message:
+                            "external id {{steps.0.response.body.id}} ({{steps.0.response.status}})"
+                                .to_owned(),
+                    },
+                ],
+                    max_attempts: 1,
+                    is_enabled: true,
+                    trigger_filters: Vec::new(),
+                    trigger_changed_fields: Vec::new(),
+                    trigger_view_logical_name: None,
+                    priority: WorkflowRunPriority::Normal,
+                },
+            )
+            .await;
+    assert!(save_result.is_ok());
 
     let run = service
-        .execute_workflow(&actor, "retry_http_dispatch", json!({"source": "wf-06"}))
-        .await;
-    assert!(run.is_ok());
-    let run = run.unwrap_or_else(|_| unreachable!());
+        .execute_workflow(&actor, "sync_external", json!({"record_id": "rec-9"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
     assert_eq!(run.status, WorkflowRunStatus::Succeeded);
-    assert_eq!(run.attempts, 2);
 
     let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
-    assert_eq!(dispatched.len(), 2);
-    assert_eq!(dispatched[0].idempotency_key, format!("{}:0", run.run_id));
-    assert_eq!(dispatched[1].idempotency_key, format!("{}:0", run.run_id));
+    assert_eq!(dispatched.len(), 1);
+    assert_eq!(
+        dispatched[0].payload["url"],
+        json!("https://example.org/records/rec-9")
+    );
+    assert_eq!(dispatched[0].payload["expected_status"], json!(201));
+    assert_eq!(
+        dispatched[0].payload["retry"],
+        json!({"max_attempts": 4, "backoff_ms": 250})
+    );
+
+    let attempts = service
+        .list_run_attempts(&actor, run.run_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(attempts.len(), 1);
+    let traces = &attempts[0].step_traces;
+    assert_eq!(
+        traces[0].output_payload["response"],
+        json!({"status": 201, "body": {"id": "ext-42"}})
+    );
+    assert_eq!(
+        traces[1].output_payload["message"],
+        json!("external id ext-42 (201)")
+    );
 }
 
 #[tokio::test]
-async fn external_integration_idempotency_key_is_stable_for_step_retry() {
+async fn execute_workflow_passes_named_step_outputs_to_later_steps() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
-    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
-    *action_dispatcher.failures_remaining.lock().await = 1;
-
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
         repository,
-        runtime_service,
+        runtime_service.clone(),
         WorkflowExecutionMode::Inline,
-        Some(action_dispatcher.clone()),
+        None,
     );
 
-    let saved = service
+    let save_result = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "retry_webhook_dispatch".to_owned(),
-                display_name: "Retry Webhook Dispatch".to_owned(),
+                logical_name: "invoice_with_lines".to_owned(),
+                display_name: "Invoice With Lines".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::Webhook {
-                    endpoint: "https://example.org/retry-webhook".to_owned(),
-                    event: "updated".to_owned(),
-                    headers: None,
-                    header_secret_refs: None,
-                    payload: json!({"source": "{{trigger.payload.source}}"}),
-                }],
+                steps: vec![
+                    WorkflowStep::CreateRuntimeRecord {
+                        entity_logical_name: "invoice".to_owned(),
+                        data: json!({"number": "{{trigger.payload.number}}"}),
+                        output_key: Some("create_invoice".to_owned()),
+                        retry_policy: None,
+                    },
+                    WorkflowStep::Condition {
+                        field_path: "steps.create_invoice.record_id".to_owned(),
+                        operator: WorkflowConditionOperator::Exists,
+                        value: None,
+                        then_label: None,
+                        else_label: None,
+                        then_steps: vec![WorkflowStep::CreateRuntimeRecord {
+                            entity_logical_name: "invoice_line".to_owned(),
+                            data: json!({
+                                "invoice": "{{steps.create_invoice.record_id}}",
+                                "label": "line for {{steps.create_invoice.data.number}}"
+                            }),
+                            output_key: None,
+                            retry_policy: None,
+                        }],
+                        else_steps: Vec::new(),
+                    },
+                ],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
-    assert!(saved.is_ok());
+    assert!(save_result.is_ok());
 
     let run = service
-        .execute_workflow(&actor, "retry_webhook_dispatch", json!({"source": "wf-06"}))
-        .await;
-    assert!(run.is_ok());
-    let run = run.unwrap_or_else(|_| unreachable!());
-    assert_eq!(run.status, WorkflowRunStatus::DeadLettered);
-    assert_eq!(run.attempts, 1);
-
-    let retried = service
-        .retry_run_step(&actor, "retry_webhook_dispatch", run.run_id.as_str(), "0")
-        .await;
-    assert!(retried.is_ok());
-    let retried = retried.unwrap_or_else(|_| unreachable!());
-    assert_eq!(retried.status, WorkflowRunStatus::Succeeded);
-    assert_eq!(retried.attempts, 2);
+        .execute_workflow(&actor, "invoice_with_lines", json!({"number": "INV-7"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
 
-    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
-    assert_eq!(dispatched.len(), 2);
-    assert_eq!(dispatched[0].idempotency_key, format!("{}:0", run.run_id));
-    assert_eq!(dispatched[1].idempotency_key, format!("{}:0", run.run_id));
-    assert_eq!(
-        dispatched[0].dispatch_type,
-        WorkflowActionDispatchType::Webhook
-    );
+    let created = runtime_service.created_records.lock().await.clone();
+    assert_eq!(created.len(), 2);
+    assert_eq!(created[1].0, "invoice_line");
     assert_eq!(
-        dispatched[1].dispatch_type,
-        WorkflowActionDispatchType::Webhook
+        created[1].1,
+        json!({"invoice": "record-1", "label": "line for INV-7"})
     );
+
+    let attempts = service
+        .list_run_attempts(&actor, run.run_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let traces = &attempts[0].step_traces;
+    assert_eq!(traces[0].output_payload["record_id"], json!("record-1"));
+    assert_eq!(traces[1].output_payload["passes"], json!(true));
 }
 
 #[tokio::test]
-async fn outbound_http_action_dead_letters_after_repeated_429_failures() {
+async fn save_workflow_rejects_step_output_references_without_earlier_step() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
-    let repository = Arc::new(FakeWorkflowRepository::default());
-    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
-    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
-    action_dispatcher.failure_messages.lock().await.extend([
-        "429 Too Many Requests from downstream".to_owned(),
-        "429 Too Many Requests from downstream".to_owned(),
-    ]);
-
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
-        repository,
-        runtime_service,
+        Arc::new(FakeWorkflowRepository::default()),
+        Arc::new(FakeRuntimeRecordService::default()),
         WorkflowExecutionMode::Inline,
-        Some(action_dispatcher.clone()),
+        None,
     );
 
-    let saved = service
+    let result = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "dead_letter_http_429".to_owned(),
-                display_name: "Dead Letter HTTP 429".to_owned(),
+                logical_name: "invoice_with_lines".to_owned(),
+                display_name: "Invoice With Lines".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::HttpRequest {
-                    method: "POST".to_owned(),
-                    url: "https://example.org/rate-limited".to_owned(),
-                    headers: None,
-                    header_secret_refs: None,
-                    body: Some(json!({ "record_id": "{{trigger.payload.record_id}}" })),
-                }],
-                max_attempts: 2,
+                steps: vec![
+                    WorkflowStep::CreateRuntimeRecord {
+                        entity_logical_name: "invoice_line".to_owned(),
+                        data: json!({"invoice": "{{steps.create_invoice.record_id}}"}),
+                        output_key: None,
+                        retry_policy: None,
+                    },
+                    WorkflowStep::CreateRuntimeRecord {
+                        entity_logical_name: "invoice".to_owned(),
+                        data: json!({}),
+                        output_key: Some("create_invoice".to_owned()),
+                        retry_policy: None,
+                    },
+                ],
+                max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
-    assert!(saved.is_ok());
 
-    let run = service
-        .execute_workflow(
-            &actor,
-            "dead_letter_http_429",
-            json!({ "record_id": "rec-429" }),
-        )
-        .await;
-    assert!(run.is_ok());
-    let run = run.unwrap_or_else(|_| unreachable!());
-    assert_eq!(run.status, WorkflowRunStatus::DeadLettered);
-    assert_eq!(run.attempts, 2);
-    assert_eq!(
-        run.dead_letter_reason.as_deref(),
-        Some("internal error: 429 Too Many Requests from downstream")
-    );
+    assert!(matches!(result, Err(AppError::Validation(_))));
+}
 
-    let attempts = service
-        .list_run_attempts(&actor, run.run_id.as_str())
-        .await
-        .unwrap_or_else(|_| unreachable!());
-    assert_eq!(attempts.len(), 2);
-    assert!(
-        attempts
-            .iter()
-            .all(|attempt| attempt.status == WorkflowRunAttemptStatus::Failed)
-    );
-    assert!(attempts.iter().all(|attempt| {
-        attempt.error_message.as_deref()
-            == Some("internal error: 429 Too Many Requests from downstream")
-    }));
+fn invoke_workflow_input(
+    logical_name: &str,
+    steps: Vec<WorkflowStep>,
+    is_enabled: bool,
+) -> SaveWorkflowInput {
+    SaveWorkflowInput {
+        logical_name: logical_name.to_owned(),
+        display_name: logical_name.to_owned(),
+        description: None,
+        trigger: WorkflowTrigger::Manual,
+        steps,
+        max_attempts: 1,
+        is_enabled,
+        trigger_filters: Vec::new(),
+        trigger_changed_fields: Vec::new(),
+        trigger_view_logical_name: None,
+        priority: WorkflowRunPriority::Normal,
+    }
+}
 
-    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
-    assert_eq!(dispatched.len(), 2);
-    assert_eq!(dispatched[0].idempotency_key, format!("{}:0", run.run_id));
-    assert_eq!(dispatched[1].idempotency_key, format!("{}:0", run.run_id));
+fn invoke_workflow_step(workflow_logical_name: &str) -> WorkflowStep {
+    WorkflowStep::InvokeWorkflow {
+        workflow_logical_name: workflow_logical_name.to_owned(),
+        payload: json!({"name": "{{trigger.payload.name}}"}),
+        mode: WorkflowInvocationMode::Synchronous,
+        output_key: None,
+        retry_policy: None,
+    }
 }
 
 #[tokio::test]
-async fn outbound_webhook_action_dead_letters_after_repeated_5xx_failures() {
+async fn invoke_workflow_step_runs_child_inline_and_links_it_to_parent_run() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
-    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
-    action_dispatcher.failure_messages.lock().await.extend([
-        "502 Bad Gateway from downstream".to_owned(),
-        "503 Service Unavailable from downstream".to_owned(),
-    ]);
-
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
-        repository,
-        runtime_service,
+        repository.clone(),
+        runtime_service.clone(),
         WorkflowExecutionMode::Inline,
-        Some(action_dispatcher.clone()),
+        None,
     );
 
-    let saved = service
+    let child = service
         .save_workflow(
             &actor,
-            SaveWorkflowInput {
-                logical_name: "dead_letter_webhook_5xx".to_owned(),
-                display_name: "Dead Letter Webhook 5xx".to_owned(),
-                description: None,
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::Webhook {
-                    endpoint: "https://example.org/downstream-webhook".to_owned(),
-                    event: "contact.created".to_owned(),
-                    headers: None,
-                    header_secret_refs: None,
-                    payload: json!({
-                        "record_id": "{{trigger.payload.record_id}}",
-                        "status": "{{trigger.payload.status}}"
-                    }),
+            invoke_workflow_input(
+                "create_contact",
+                vec![WorkflowStep::CreateRuntimeRecord {
+                    entity_logical_name: "contact".to_owned(),
+                    data: json!({"name": "{{trigger.payload.name}}"}),
+                    output_key: None,
+                    retry_policy: None,
                 }],
-                max_attempts: 2,
-                is_enabled: true,
-            },
+                true,
+            ),
         )
         .await;
-    assert!(saved.is_ok());
-
-    let run = service
-        .execute_workflow(
+    assert!(child.is_ok());
+    let parent = service
+        .save_workflow(
             &actor,
-            "dead_letter_webhook_5xx",
-            json!({ "record_id": "rec-5xx", "status": "new" }),
+            invoke_workflow_input(
+                "onboard_contact",
+                vec![
+                    WorkflowStep::InvokeWorkflow {
+                        workflow_logical_name: "create_contact".to_owned(),
+                        payload: json!({"name": "{{trigger.payload.contact_name}}"}),
+                        mode: WorkflowInvocationMode::Synchronous,
+                        output_key: Some("child".to_owned()),
+                        retry_policy: None,
+                    },
+                    WorkflowStep::LogMessage {
+                        message: "child run {{steps.child.run_id}}".to_owned(),
+                    },
+                ],
+                true,
+            ),
         )
         .await;
-    assert!(run.is_ok());
-    let run = run.unwrap_or_else(|_| unreachable!());
-    assert_eq!(run.status, WorkflowRunStatus::DeadLettered);
-    assert_eq!(run.attempts, 2);
-    assert_eq!(
-        run.dead_letter_reason.as_deref(),
-        Some("internal error: 503 Service Unavailable from downstream")
-    );
+    assert!(parent.is_ok());
 
-    let attempts = service
-        .list_run_attempts(&actor, run.run_id.as_str())
+    let run = service
+        .execute_workflow(&actor, "onboard_contact", json!({"contact_name": "Alice"}))
         .await
         .unwrap_or_else(|_| unreachable!());
-    assert_eq!(attempts.len(), 2);
-    assert_eq!(attempts[0].status, WorkflowRunAttemptStatus::Failed);
-    assert_eq!(attempts[1].status, WorkflowRunAttemptStatus::Failed);
+    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
+    assert_eq!(run.parent_run_id, None);
+
+    let child_run = repository
+        .runs
+        .lock()
+        .await
+        .iter()
+        .find(|candidate| candidate.workflow_logical_name == "create_contact")
+        .cloned()
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(child_run.status, WorkflowRunStatus::Succeeded);
     assert_eq!(
-        attempts[0].error_message.as_deref(),
-        Some("internal error: 502 Bad Gateway from downstream")
+        child_run.parent_run_id.as_deref(),
+        Some(run.run_id.as_str())
     );
+    assert_eq!(child_run.trigger_payload, json!({"name": "Alice"}));
     assert_eq!(
-        attempts[1].error_message.as_deref(),
-        Some("internal error: 503 Service Unavailable from downstream")
+        runtime_service.created_records.lock().await[0].1,
+        json!({"name": "Alice"})
     );
 
-    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
-    assert_eq!(dispatched.len(), 2);
-    assert_eq!(dispatched[0].idempotency_key, format!("{}:0", run.run_id));
-    assert_eq!(dispatched[1].idempotency_key, format!("{}:0", run.run_id));
-    assert_eq!(
-        dispatched[0].dispatch_type,
-        WorkflowActionDispatchType::Webhook
-    );
+    let attempts = service
+        .list_run_attempts(&actor, run.run_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let traces = &attempts[0].step_traces;
+    assert_eq!(traces[0].step_type, "invoke_workflow");
+    assert_eq!(traces[0].output_payload["run_id"], json!(child_run.run_id));
+    assert_eq!(traces[0].output_payload["status"], json!("succeeded"));
     assert_eq!(
-        dispatched[1].dispatch_type,
-        WorkflowActionDispatchType::Webhook
+        traces[1].output_payload["message"],
+        json!(format!("child run {}", child_run.run_id))
     );
 }
 
 #[tokio::test]
-async fn outbound_email_action_dead_letters_after_repeated_provider_failures() {
+async fn save_workflow_rejects_circular_workflow_invocations() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
-    let repository = Arc::new(FakeWorkflowRepository::default());
-    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
-    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
-    action_dispatcher.failure_messages.lock().await.extend([
-        "502 Bad Gateway from email provider".to_owned(),
-        "503 Service Unavailable from email provider".to_owned(),
-    ]);
-
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
-        repository,
-        runtime_service,
+        Arc::new(FakeWorkflowRepository::default()),
+        Arc::new(FakeRuntimeRecordService::default()),
         WorkflowExecutionMode::Inline,
-        Some(action_dispatcher.clone()),
+        None,
     );
 
-    let saved = service
-        .save_workflow(
-            &actor,
-            SaveWorkflowInput {
-                logical_name: "dead_letter_email_provider_5xx".to_owned(),
-                display_name: "Dead Letter Email Provider 5xx".to_owned(),
-                description: None,
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::SendEmail {
-                    to: "ops@qryvanta.test".to_owned(),
-                    subject: "Workflow delivery failed".to_owned(),
-                    body: "record {{trigger.payload.record_id}} failed".to_owned(),
-                    html_body: None,
-                }],
-                max_attempts: 2,
-                is_enabled: true,
-            },
-        )
-        .await;
-    assert!(saved.is_ok());
+    for (logical_name, invoked) in [("flow_a", "flow_b"), ("flow_b", "flow_c")] {
+        let saved = service
+            .save_workflow(
+                &actor,
+                invoke_workflow_input(logical_name, vec![invoke_workflow_step(invoked)], false),
+            )
+            .await;
+        assert!(saved.is_ok());
+    }
 
-    let run = service
-        .execute_workflow(
+    let result = service
+        .save_workflow(
             &actor,
-            "dead_letter_email_provider_5xx",
-            json!({ "record_id": "rec-email-5xx" }),
+            invoke_workflow_input("flow_c", vec![invoke_workflow_step("flow_a")], false),
         )
         .await;
-    assert!(run.is_ok());
-    let run = run.unwrap_or_else(|_| unreachable!());
-    assert_eq!(run.status, WorkflowRunStatus::DeadLettered);
-    assert_eq!(run.attempts, 2);
-    assert_eq!(
-        run.dead_letter_reason.as_deref(),
-        Some("internal error: 503 Service Unavailable from email provider")
-    );
-
-    let attempts = service
-        .list_run_attempts(&actor, run.run_id.as_str())
-        .await
-        .unwrap_or_else(|_| unreachable!());
-    assert_eq!(attempts.len(), 2);
-    assert_eq!(attempts[0].status, WorkflowRunAttemptStatus::Failed);
-    assert_eq!(attempts[1].status, WorkflowRunAttemptStatus::Failed);
-    assert_eq!(
-        attempts[0].error_message.as_deref(),
-        Some("internal error: 502 Bad Gateway from email provider")
-    );
-    assert_eq!(
-        attempts[1].error_message.as_deref(),
-        Some("internal error: 503 Service Unavailable from email provider")
-    );
-
-    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
-    assert_eq!(dispatched.len(), 2);
-    assert_eq!(
-        dispatched[0].dispatch_type,
-        WorkflowActionDispatchType::Email
-    );
-    assert_eq!(
-        dispatched[1].dispatch_type,
-        WorkflowActionDispatchType::Email
-    );
-    assert_eq!(dispatched[0].idempotency_key, format!("{}:0", run.run_id));
-    assert_eq!(dispatched[1].idempotency_key, format!("{}:0", run.run_id));
+    assert!(matches!(
+        result,
+        Err(AppError::Validation(message))
+            if message == "workflow invocation cycle detected: flow_c -> flow_a -> flow_b -> flow_c"
+    ));
 }
 
 #[tokio::test]
-async fn external_integration_idempotency_key_uses_deterministic_nested_step_path() {
+async fn invoke_workflow_step_dead_letters_beyond_max_invocation_depth() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
-    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
-
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
         repository,
-        runtime_service,
+        runtime_service.clone(),
         WorkflowExecutionMode::Inline,
-        Some(action_dispatcher.clone()),
+        None,
     );
 
-    let saved = service
+    let leaf = service
         .save_workflow(
             &actor,
-            SaveWorkflowInput {
-                logical_name: "nested_email_dispatch".to_owned(),
-                display_name: "Nested Email Dispatch".to_owned(),
-                description: None,
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::Condition {
-                    field_path: "status".to_owned(),
-                    operator: WorkflowConditionOperator::Equals,
-                    value: Some(json!("open")),
-                    then_label: None,
-                    else_label: None,
-                    then_steps: vec![WorkflowStep::SendEmail {
-                        to: "ops@qryvanta.test".to_owned(),
-                        subject: "Workflow update".to_owned(),
-                        body: "status changed".to_owned(),
-                        html_body: None,
-                    }],
-                    else_steps: Vec::new(),
+            invoke_workflow_input(
+                "level_6",
+                vec![WorkflowStep::CreateRuntimeRecord {
+                    entity_logical_name: "contact".to_owned(),
+                    data: json!({"name": "{{trigger.payload.name}}"}),
+                    output_key: None,
+                    retry_policy: None,
                 }],
-                max_attempts: 1,
-                is_enabled: true,
-            },
+                true,
+            ),
         )
         .await;
-    assert!(saved.is_ok());
+    assert!(leaf.is_ok());
+    for level in (0..6).rev() {
+        let saved = service
+            .save_workflow(
+                &actor,
+                invoke_workflow_input(
+                    format!("level_{level}").as_str(),
+                    vec![invoke_workflow_step(
+                        format!("level_{}", level + 1).as_str(),
+                    )],
+                    true,
+                ),
+            )
+            .await;
+        assert!(saved.is_ok());
+    }
 
-    let run = service
-        .execute_workflow(&actor, "nested_email_dispatch", json!({"status": "open"}))
-        .await;
-    assert!(run.is_ok());
-    let run = run.unwrap_or_else(|_| unreachable!());
-    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
+    let deepest_allowed = service
+        .execute_workflow(&actor, "level_1", json!({"name": "Alice"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(deepest_allowed.status, WorkflowRunStatus::Succeeded);
+    assert_eq!(runtime_service.created_records.lock().await.len(), 1);
 
-    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
-    assert_eq!(dispatched.len(), 1);
-    assert_eq!(
-        dispatched[0].dispatch_type,
-        WorkflowActionDispatchType::Email
-    );
-    assert_eq!(
-        dispatched[0].idempotency_key,
-        format!("{}:0.then.0", run.run_id)
+    let too_deep = service
+        .execute_workflow(&actor, "level_0", json!({"name": "Bob"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(too_deep.status, WorkflowRunStatus::DeadLettered);
+    assert!(
+        too_deep
+            .dead_letter_reason
+            .unwrap_or_default()
+            .contains("exceeds the maximum invocation depth of 5")
     );
-    assert_eq!(dispatched[0].step_path, "0.then.0");
+    assert_eq!(runtime_service.created_records.lock().await.len(), 1);
 }
 
 #[tokio::test]
-async fn native_update_record_step_updates_runtime_record() {
+async fn try_catch_step_runs_catch_steps_and_handles_try_failures() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    *runtime_service.failures_remaining.lock().await = 1;
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
@@ -2481,40 +3632,85 @@ async fn native_update_record_step_updates_runtime_record() {
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "update_contact".to_owned(),
-                display_name: "Update Contact".to_owned(),
+                logical_name: "guarded_create".to_owned(),
+                display_name: "Guarded Create".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::UpdateRuntimeRecord {
-                    entity_logical_name: "contact".to_owned(),
-                    record_id: "{{trigger.payload.record_id}}".to_owned(),
-                    data: json!({"status": "qualified"}),
+                steps: vec![WorkflowStep::TryCatch {
+                    try_steps: vec![WorkflowStep::CreateRuntimeRecord {
+                        entity_logical_name: "contact".to_owned(),
+                        data: json!({"name": "Alice"}),
+                        output_key: None,
+                        retry_policy: None,
+                    }],
+                    catch_steps: vec![WorkflowStep::CreateRuntimeRecord {
+                        entity_logical_name: "workflow_error".to_owned(),
+                        data: json!({
+                            "message": "{{steps.0.error}}",
+                            "step": "{{steps.0.failed_step_path}}"
+                        }),
+                        output_key: None,
+                        retry_policy: None,
+                    }],
+                    compensation_steps: vec![WorkflowStep::LogMessage {
+                        message: "compensating".to_owned(),
+                    }],
                 }],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
     assert!(saved.is_ok());
 
-    let executed = service
-        .execute_workflow(&actor, "update_contact", json!({"record_id": "rec-42"}))
-        .await;
-    assert!(executed.is_ok());
+    let run = service
+        .execute_workflow(&actor, "guarded_create", json!({}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
 
-    let updated = runtime_service.updated_records.lock().await.clone();
-    assert_eq!(updated.len(), 1);
-    assert_eq!(updated[0].0, "contact");
-    assert_eq!(updated[0].1, "rec-42");
-    assert_eq!(updated[0].2["status"], json!("qualified"));
+    let created = runtime_service.created_records.lock().await.clone();
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].0, "workflow_error");
+    assert_eq!(
+        created[0].1,
+        json!({
+            "message": "internal error: simulated workflow action failure",
+            "step": "0.try.0"
+        })
+    );
+
+    let attempts = service
+        .list_run_attempts(&actor, run.run_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let traces = attempts[0]
+        .step_traces
+        .iter()
+        .map(|trace| (trace.step_path.as_str(), trace.status.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        traces,
+        vec![
+            ("0.try.0", "failed"),
+            ("0", "caught"),
+            ("0.compensate.0", "succeeded"),
+            ("0.catch.0", "succeeded"),
+        ]
+    );
 }
 
 #[tokio::test]
-async fn native_delete_record_step_deletes_runtime_record() {
+async fn try_catch_step_without_catch_steps_compensates_then_fails_attempt() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    *runtime_service.failures_remaining.lock().await = 1;
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
@@ -2530,143 +3726,244 @@ async fn native_delete_record_step_deletes_runtime_record() {
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "delete_contact".to_owned(),
-                display_name: "Delete Contact".to_owned(),
+                logical_name: "compensated_create".to_owned(),
+                display_name: "Compensated Create".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::DeleteRuntimeRecord {
-                    entity_logical_name: "contact".to_owned(),
-                    record_id: "rec-7".to_owned(),
-                }],
+                steps: vec![
+                    WorkflowStep::TryCatch {
+                        try_steps: vec![WorkflowStep::CreateRuntimeRecord {
+                            entity_logical_name: "contact".to_owned(),
+                            data: json!({"name": "Alice"}),
+                            output_key: None,
+                            retry_policy: None,
+                        }],
+                        catch_steps: Vec::new(),
+                        compensation_steps: vec![WorkflowStep::DeleteRuntimeRecord {
+                            entity_logical_name: "contact".to_owned(),
+                            record_id: "record-stale".to_owned(),
+                            retry_policy: None,
+                        }],
+                    },
+                    WorkflowStep::LogMessage {
+                        message: "unreachable".to_owned(),
+                    },
+                ],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
     assert!(saved.is_ok());
 
-    let executed = service
-        .execute_workflow(&actor, "delete_contact", json!({}))
-        .await;
-    assert!(executed.is_ok());
+    let run = service
+        .execute_workflow(&actor, "compensated_create", json!({}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(run.status, WorkflowRunStatus::DeadLettered);
+    assert_eq!(
+        run.dead_letter_reason.as_deref(),
+        Some("internal error: simulated workflow action failure")
+    );
+    assert_eq!(
+        runtime_service.deleted_records.lock().await.clone(),
+        vec![("contact".to_owned(), "record-stale".to_owned())]
+    );
 
-    let deleted = runtime_service.deleted_records.lock().await.clone();
+    let attempts = service
+        .list_run_attempts(&actor, run.run_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let traces = attempts[0]
+        .step_traces
+        .iter()
+        .map(|trace| (trace.step_path.as_str(), trace.status.as_str()))
+        .collect::<Vec<_>>();
     assert_eq!(
-        deleted.as_slice(),
-        [("contact".to_owned(), "rec-7".to_owned())]
+        traces,
+        vec![
+            ("0.try.0", "failed"),
+            ("0", "failed"),
+            ("0.compensate.0", "succeeded"),
+        ]
     );
 }
 
 #[tokio::test]
-async fn native_assign_owner_step_creates_assignment_record() {
+async fn external_integration_idempotency_key_is_stable_across_run_retries() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
+    *action_dispatcher.failures_remaining.lock().await = 1;
+
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
         repository,
-        runtime_service.clone(),
+        runtime_service,
         WorkflowExecutionMode::Inline,
-        None,
+        Some(action_dispatcher.clone()),
     );
 
     let saved = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "assign_owner".to_owned(),
-                display_name: "Assign Owner".to_owned(),
+                logical_name: "retry_http_dispatch".to_owned(),
+                display_name: "Retry HTTP Dispatch".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::AssignOwner {
-                    entity_logical_name: "lead".to_owned(),
-                    record_id: "lead-1".to_owned(),
-                    owner_id: "triage_queue".to_owned(),
-                    reason: Some("auto routing".to_owned()),
+                steps: vec![WorkflowStep::HttpRequest {
+                    method: "POST".to_owned(),
+                    url: "https://example.org/retry".to_owned(),
+                    headers: None,
+                    credential: None,
+                    header_secret_refs: None,
+                    body: None,
+                    expected_status: None,
+                    retry: None,
+                    output_key: None,
+                    retry_policy: None,
                 }],
-                max_attempts: 1,
+                max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
     assert!(saved.is_ok());
 
-    let executed = service
-        .execute_workflow(&actor, "assign_owner", json!({}))
+    let run = service
+        .execute_workflow(&actor, "retry_http_dispatch", json!({"source": "wf-06"}))
         .await;
-    assert!(executed.is_ok());
+    assert!(run.is_ok());
+    let run = run.unwrap_or_else(|_| unreachable!());
+    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
+    assert_eq!(run.attempts, 2);
 
-    let created = runtime_service.created_records.lock().await.clone();
-    assert_eq!(created.len(), 1);
-    assert_eq!(created[0].0, "record_assignment");
-    assert_eq!(created[0].1["owner_id"], json!("triage_queue"));
-    assert_eq!(created[0].1["source_entity"], json!("lead"));
+    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
+    assert_eq!(dispatched.len(), 2);
+    assert_eq!(dispatched[0].idempotency_key, format!("{}:0", run.run_id));
+    assert_eq!(dispatched[1].idempotency_key, format!("{}:0", run.run_id));
 }
 
 #[tokio::test]
-async fn native_approval_request_step_creates_approval_record() {
+async fn step_retry_policy_retries_failed_step_within_one_attempt() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
+    *action_dispatcher.failures_remaining.lock().await = 2;
+
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
-        repository,
-        runtime_service.clone(),
+        repository.clone(),
+        runtime_service,
         WorkflowExecutionMode::Inline,
-        None,
+        Some(action_dispatcher.clone()),
     );
 
-    let saved = service
-        .save_workflow(
-            &actor,
-            SaveWorkflowInput {
-                logical_name: "approval_request".to_owned(),
-                display_name: "Approval Request".to_owned(),
-                description: None,
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::ApprovalRequest {
-                    entity_logical_name: "quote".to_owned(),
-                    record_id: "quote-2".to_owned(),
-                    request_type: "discount_override".to_owned(),
-                    requested_by: None,
-                    approver_id: Some("manager-7".to_owned()),
-                    reason: Some("requires manager approval".to_owned()),
-                    payload: Some(json!({"discount": 20})),
-                }],
-                max_attempts: 1,
-                is_enabled: true,
-            },
-        )
+    let webhook_step = |retry_on: Vec<WorkflowStepRetryErrorClass>| WorkflowStep::Webhook {
+        endpoint: "https://example.org/flaky".to_owned(),
+        event: "updated".to_owned(),
+        headers: None,
+        credential: None,
+        header_secret_refs: None,
+        payload: json!({"source": "step-retry"}),
+        retry_policy: Some(WorkflowStepRetryPolicy {
+            max_attempts: 3,
+            backoff: WorkflowStepBackoffStrategy::Exponential,
+            backoff_ms: 250,
+            retry_on,
+        }),
+    };
+    for (logical_name, retry_on) in [
+        ("flaky_webhook", vec![WorkflowStepRetryErrorClass::Internal]),
+        (
+            "strict_webhook",
+            vec![WorkflowStepRetryErrorClass::RateLimited],
+        ),
+    ] {
+        let saved = service
+            .save_workflow(
+                &actor,
+                SaveWorkflowInput {
+                    logical_name: logical_name.to_owned(),
+                    display_name: logical_name.to_owned(),
+                    description: None,
+                    trigger: WorkflowTrigger::Manual,
+                    steps: vec![webhook_step(retry_on)],
+                    max_attempts: 1,
+                    is_enabled: true,
+                    trigger_filters: Vec::new(),
+                    trigger_changed_fields: Vec::new(),
+                    trigger_view_logical_name: None,
+                    priority: WorkflowRunPriority::Normal,
+                },
+            )
+            .await;
+        assert!(saved.is_ok());
+    }
+
+    let run = service
+        .execute_workflow(&actor, "flaky_webhook", json!({}))
         .await;
-    assert!(saved.is_ok());
+    assert!(run.is_ok());
+    let run = run.unwrap_or_else(|_| unreachable!());
+    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
+    assert_eq!(run.attempts, 1);
+    assert_eq!(action_dispatcher.dispatched_requests.lock().await.len(), 3);
 
-    let executed = service
-        .execute_workflow(&actor, "approval_request", json!({}))
+    let attempts = repository
+        .list_run_attempts(tenant_id, run.run_id.as_str())
+        .await
+        .unwrap_or_default();
+    assert_eq!(attempts.len(), 1);
+    assert_eq!(attempts[0].step_traces.len(), 1);
+    assert_eq!(attempts[0].step_traces[0].status, "succeeded");
+    assert_eq!(attempts[0].step_traces[0].retries, 2);
+
+    *action_dispatcher.failures_remaining.lock().await = 1;
+    let run = service
+        .execute_workflow(&actor, "strict_webhook", json!({}))
         .await;
-    assert!(executed.is_ok());
+    assert!(run.is_ok());
+    let run = run.unwrap_or_else(|_| unreachable!());
+    assert_eq!(run.status, WorkflowRunStatus::DeadLettered);
+    assert_eq!(action_dispatcher.dispatched_requests.lock().await.len(), 4);
 
-    let created = runtime_service.created_records.lock().await.clone();
-    assert_eq!(created.len(), 1);
-    assert_eq!(created[0].0, "approval_request");
-    assert_eq!(created[0].1["status"], json!("pending"));
-    assert_eq!(created[0].1["approver_id"], json!("manager-7"));
-    assert_eq!(created[0].1["requested_by"], json!("workflow-runtime"));
+    let attempts = repository
+        .list_run_attempts(tenant_id, run.run_id.as_str())
+        .await
+        .unwrap_or_default();
+    assert_eq!(attempts[0].step_traces[0].status, "failed");
+    assert_eq!(attempts[0].step_traces[0].retries, 0);
 }
 
 #[tokio::test]
-async fn native_delay_step_executes_successfully() {
+async fn external_integration_idempotency_key_is_stable_for_step_retry() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
+    *action_dispatcher.failures_remaining.lock().await = 1;
+
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
@@ -2675,42 +3972,79 @@ async fn native_delay_step_executes_successfully() {
         repository,
         runtime_service,
         WorkflowExecutionMode::Inline,
-        None,
+        Some(action_dispatcher.clone()),
     );
 
     let saved = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "delay_native".to_owned(),
-                display_name: "Delay Native".to_owned(),
+                logical_name: "retry_webhook_dispatch".to_owned(),
+                display_name: "Retry Webhook Dispatch".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::Delay {
-                    duration_ms: 1,
-                    reason: Some("wait for consistency".to_owned()),
+                steps: vec![WorkflowStep::Webhook {
+                    endpoint: "https://example.org/retry-webhook".to_owned(),
+                    event: "updated".to_owned(),
+                    headers: None,
+                    credential: None,
+                    header_secret_refs: None,
+                    payload: json!({"source": "{{trigger.payload.source}}"}),
+                    retry_policy: None,
                 }],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
     assert!(saved.is_ok());
 
     let run = service
-        .execute_workflow(&actor, "delay_native", json!({}))
+        .execute_workflow(&actor, "retry_webhook_dispatch", json!({"source": "wf-06"}))
         .await;
     assert!(run.is_ok());
     let run = run.unwrap_or_else(|_| unreachable!());
-    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
+    assert_eq!(run.status, WorkflowRunStatus::DeadLettered);
+    assert_eq!(run.attempts, 1);
+
+    let retried = service
+        .retry_run_step(&actor, "retry_webhook_dispatch", run.run_id.as_str(), "0")
+        .await;
+    assert!(retried.is_ok());
+    let retried = retried.unwrap_or_else(|_| unreachable!());
+    assert_eq!(retried.status, WorkflowRunStatus::Succeeded);
+    assert_eq!(retried.attempts, 2);
+
+    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
+    assert_eq!(dispatched.len(), 2);
+    assert_eq!(dispatched[0].idempotency_key, format!("{}:0", run.run_id));
+    assert_eq!(dispatched[1].idempotency_key, format!("{}:0", run.run_id));
+    assert_eq!(
+        dispatched[0].dispatch_type,
+        WorkflowActionDispatchType::Webhook
+    );
+    assert_eq!(
+        dispatched[1].dispatch_type,
+        WorkflowActionDispatchType::Webhook
+    );
 }
 
 #[tokio::test]
-async fn execute_workflow_condition_branch_uses_trigger_payload() {
+async fn outbound_http_action_dead_letters_after_repeated_429_failures() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
+    action_dispatcher.failure_messages.lock().await.extend([
+        "429 Too Many Requests from downstream".to_owned(),
+        "429 Too Many Requests from downstream".to_owned(),
+    ]);
+
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
@@ -2719,118 +4053,189 @@ async fn execute_workflow_condition_branch_uses_trigger_payload() {
         repository,
         runtime_service,
         WorkflowExecutionMode::Inline,
-        None,
+        Some(action_dispatcher.clone()),
     );
 
-    let save_result = service
+    let saved = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "branch_by_status".to_owned(),
-                display_name: "Branch By Status".to_owned(),
+                logical_name: "dead_letter_http_429".to_owned(),
+                display_name: "Dead Letter HTTP 429".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::Condition {
-                    field_path: "status".to_owned(),
-                    operator: WorkflowConditionOperator::Equals,
-                    value: Some(json!("open")),
-                    then_label: Some("Matched".to_owned()),
-                    else_label: Some("Unmatched".to_owned()),
-                    then_steps: vec![WorkflowStep::LogMessage {
-                        message: "open-path".to_owned(),
-                    }],
-                    else_steps: vec![WorkflowStep::CreateRuntimeRecord {
-                        entity_logical_name: "task".to_owned(),
-                        data: json!({"title": "follow-up"}),
-                    }],
-                }],
-                max_attempts: 2,
-                is_enabled: true,
-            },
+                steps: vec![WorkflowStep::HttpRequest {
+                    method: "POST".to_owned(),
+                    url: "https://example.org/rate-limited".to_owned(),
+                    headers: None,
+                    credential: None,
+                    header_secret_refs: None,
+                    body: Some(json!({ "record_id": "{{trigger.payload.record_id}}" })),
+                    expected_status: None,
+                    retry: None,
+                    output_key: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
         )
         .await;
-    assert!(save_result.is_ok());
+    assert!(saved.is_ok());
 
     let run = service
-        .execute_workflow(&actor, "branch_by_status", json!({"status": "open"}))
+        .execute_workflow(
+            &actor,
+            "dead_letter_http_429",
+            json!({ "record_id": "rec-429" }),
+        )
         .await;
     assert!(run.is_ok());
     let run = run.unwrap_or_else(|_| unreachable!());
-    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
-    assert_eq!(run.attempts, 1);
+    assert_eq!(run.status, WorkflowRunStatus::DeadLettered);
+    assert_eq!(run.attempts, 2);
+    assert_eq!(
+        run.dead_letter_reason.as_deref(),
+        Some("internal error: 429 Too Many Requests from downstream")
+    );
+
+    let attempts = service
+        .list_run_attempts(&actor, run.run_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(attempts.len(), 2);
+    assert!(
+        attempts
+            .iter()
+            .all(|attempt| attempt.status == WorkflowRunAttemptStatus::Failed)
+    );
+    assert!(attempts.iter().all(|attempt| {
+        attempt.error_message.as_deref()
+            == Some("internal error: 429 Too Many Requests from downstream")
+    }));
+
+    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
+    assert_eq!(dispatched.len(), 2);
+    assert_eq!(dispatched[0].idempotency_key, format!("{}:0", run.run_id));
+    assert_eq!(dispatched[1].idempotency_key, format!("{}:0", run.run_id));
 }
 
 #[tokio::test]
-async fn execute_workflow_interpolates_trigger_and_run_tokens_in_actions() {
+async fn outbound_webhook_action_dead_letters_after_repeated_5xx_failures() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
+    action_dispatcher.failure_messages.lock().await.extend([
+        "502 Bad Gateway from downstream".to_owned(),
+        "503 Service Unavailable from downstream".to_owned(),
+    ]);
+
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
         repository,
-        runtime_service.clone(),
+        runtime_service,
         WorkflowExecutionMode::Inline,
-        None,
+        Some(action_dispatcher.clone()),
     );
 
-    let save_result = service
+    let saved = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "interpolate_runtime_tokens".to_owned(),
-                display_name: "Interpolate Runtime Tokens".to_owned(),
+                logical_name: "dead_letter_webhook_5xx".to_owned(),
+                display_name: "Dead Letter Webhook 5xx".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::CreateRuntimeRecord {
-                    entity_logical_name: "{{trigger.payload.target_entity}}".to_owned(),
-                    data: json!({
-                        "title": "Record {{trigger.payload.record_id}}",
+                steps: vec![WorkflowStep::Webhook {
+                    endpoint: "https://example.org/downstream-webhook".to_owned(),
+                    event: "contact.created".to_owned(),
+                    headers: None,
+                    credential: None,
+                    header_secret_refs: None,
+                    payload: json!({
                         "record_id": "{{trigger.payload.record_id}}",
-                        "run_id": "{{run.id}}",
-                        "attempt": "{{run.attempt}}",
-                        "owner": "{{trigger.payload.owner}}",
+                        "status": "{{trigger.payload.status}}"
                     }),
+                    retry_policy: None,
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
-    assert!(save_result.is_ok());
+    assert!(saved.is_ok());
 
     let run = service
         .execute_workflow(
             &actor,
-            "interpolate_runtime_tokens",
-            json!({
-                "target_entity": "contact",
-                "record_id": "rec-42",
-                "owner": "ops@qryvanta.test"
-            }),
+            "dead_letter_webhook_5xx",
+            json!({ "record_id": "rec-5xx", "status": "new" }),
         )
         .await;
     assert!(run.is_ok());
+    let run = run.unwrap_or_else(|_| unreachable!());
+    assert_eq!(run.status, WorkflowRunStatus::DeadLettered);
+    assert_eq!(run.attempts, 2);
+    assert_eq!(
+        run.dead_letter_reason.as_deref(),
+        Some("internal error: 503 Service Unavailable from downstream")
+    );
 
-    let created_records = runtime_service.created_records.lock().await.clone();
-    assert_eq!(created_records.len(), 1);
-    assert_eq!(created_records[0].0, "contact");
-    assert_eq!(created_records[0].1["title"], json!("Record rec-42"));
-    assert_eq!(created_records[0].1["record_id"], json!("rec-42"));
-    assert_eq!(created_records[0].1["attempt"], json!(1));
-    assert_eq!(created_records[0].1["owner"], json!("ops@qryvanta.test"));
-    assert!(created_records[0].1["run_id"].as_str().is_some());
+    let attempts = service
+        .list_run_attempts(&actor, run.run_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts[0].status, WorkflowRunAttemptStatus::Failed);
+    assert_eq!(attempts[1].status, WorkflowRunAttemptStatus::Failed);
+    assert_eq!(
+        attempts[0].error_message.as_deref(),
+        Some("internal error: 502 Bad Gateway from downstream")
+    );
+    assert_eq!(
+        attempts[1].error_message.as_deref(),
+        Some("internal error: 503 Service Unavailable from downstream")
+    );
+
+    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
+    assert_eq!(dispatched.len(), 2);
+    assert_eq!(dispatched[0].idempotency_key, format!("{}:0", run.run_id));
+    assert_eq!(dispatched[1].idempotency_key, format!("{}:0", run.run_id));
+    assert_eq!(
+        dispatched[0].dispatch_type,
+        WorkflowActionDispatchType::Webhook
+    );
+    assert_eq!(
+        dispatched[1].dispatch_type,
+        WorkflowActionDispatchType::Webhook
+    );
 }
 
 #[tokio::test]
-async fn queued_mode_enqueues_and_worker_executes_claimed_job() {
+async fn outbound_email_action_dead_letters_after_repeated_provider_failures() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
+    action_dispatcher.failure_messages.lock().await.extend([
+        "502 Bad Gateway from email provider".to_owned(),
+        "503 Service Unavailable from email provider".to_owned(),
+    ]);
+
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
@@ -2838,89 +4243,98 @@ async fn queued_mode_enqueues_and_worker_executes_claimed_job() {
         )]),
         repository,
         runtime_service,
-        WorkflowExecutionMode::Queued,
-        None,
+        WorkflowExecutionMode::Inline,
+        Some(action_dispatcher.clone()),
     );
 
-    let save_result = service
+    let saved = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "queued_contact_create".to_owned(),
-                display_name: "Queued Contact Create".to_owned(),
+                logical_name: "dead_letter_email_provider_5xx".to_owned(),
+                display_name: "Dead Letter Email Provider 5xx".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::LogMessage {
-                    message: "queued".to_owned(),
+                steps: vec![WorkflowStep::SendEmail {
+                    to: "ops@qryvanta.test".to_owned(),
+                    subject: "Workflow delivery failed".to_owned(),
+                    body: "record {{trigger.payload.record_id}} failed".to_owned(),
+                    html_body: None,
+                    retry_policy: None,
                 }],
                 max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
-    assert!(save_result.is_ok());
+    assert!(saved.is_ok());
 
-    let enqueued_run = service
-        .execute_workflow(&actor, "queued_contact_create", json!({"source": "test"}))
+    let run = service
+        .execute_workflow(
+            &actor,
+            "dead_letter_email_provider_5xx",
+            json!({ "record_id": "rec-email-5xx" }),
+        )
         .await;
-    assert!(enqueued_run.is_ok());
-    let enqueued_run = enqueued_run.unwrap_or_else(|_| unreachable!());
-    assert_eq!(enqueued_run.status, WorkflowRunStatus::Running);
+    assert!(run.is_ok());
+    let run = run.unwrap_or_else(|_| unreachable!());
+    assert_eq!(run.status, WorkflowRunStatus::DeadLettered);
+    assert_eq!(run.attempts, 2);
+    assert_eq!(
+        run.dead_letter_reason.as_deref(),
+        Some("internal error: 503 Service Unavailable from email provider")
+    );
 
-    let claimed_jobs = service
-        .claim_jobs_for_worker("worker-alpha", 10, 30, None, None)
-        .await;
-    assert!(claimed_jobs.is_ok());
-    let mut claimed_jobs = claimed_jobs.unwrap_or_default();
-    assert_eq!(claimed_jobs.len(), 1);
+    let attempts = service
+        .list_run_attempts(&actor, run.run_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts[0].status, WorkflowRunAttemptStatus::Failed);
+    assert_eq!(attempts[1].status, WorkflowRunAttemptStatus::Failed);
+    assert_eq!(
+        attempts[0].error_message.as_deref(),
+        Some("internal error: 502 Bad Gateway from email provider")
+    );
+    assert_eq!(
+        attempts[1].error_message.as_deref(),
+        Some("internal error: 503 Service Unavailable from email provider")
+    );
 
-    let completed = service
-        .execute_claimed_job("worker-alpha", claimed_jobs.remove(0))
-        .await;
-    assert!(completed.is_ok());
-    let completed = completed.unwrap_or_else(|_| unreachable!());
-    assert_eq!(completed.status, WorkflowRunStatus::Succeeded);
+    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
+    assert_eq!(dispatched.len(), 2);
+    assert_eq!(
+        dispatched[0].dispatch_type,
+        WorkflowActionDispatchType::Email
+    );
+    assert_eq!(
+        dispatched[1].dispatch_type,
+        WorkflowActionDispatchType::Email
+    );
+    assert_eq!(dispatched[0].idempotency_key, format!("{}:0", run.run_id));
+    assert_eq!(dispatched[1].idempotency_key, format!("{}:0", run.run_id));
 }
 
 #[tokio::test]
-async fn queued_runtime_event_flow_covers_outbox_job_execution_and_replay_history() {
+async fn external_integration_idempotency_key_uses_deterministic_nested_step_path() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
     let action_dispatcher = Arc::new(FakeActionDispatcher::default());
-    runtime_service
-        .queued_events
-        .lock()
-        .await
-        .push(ClaimedRuntimeRecordWorkflowEvent {
-            event_id: "event-e2e-1".to_owned(),
-            tenant_id,
-            trigger: WorkflowTrigger::RuntimeRecordCreated {
-                entity_logical_name: "contact".to_owned(),
-            },
-            record_id: "record-e2e-1".to_owned(),
-            payload: json!({
-                "entity_logical_name": "contact",
-                "record_id": "record-e2e-1",
-                "id": "record-e2e-1",
-                "name": "Alice",
-                "status": "new",
-                "record": {"name": "Alice", "status": "new"},
-                "data": {"name": "Alice", "status": "new"},
-                "event": "created"
-            }),
-            emitted_by_subject: "maker".to_owned(),
-            lease_token: "lease-e2e-1".to_owned(),
-        });
+
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
-        repository.clone(),
-        runtime_service.clone(),
-        WorkflowExecutionMode::Queued,
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
         Some(action_dispatcher.clone()),
     );
 
@@ -2928,190 +4342,112 @@ async fn queued_runtime_event_flow_covers_outbox_job_execution_and_replay_histor
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "contact_created_http_e2e".to_owned(),
-                display_name: "Contact Created HTTP E2E".to_owned(),
+                logical_name: "nested_email_dispatch".to_owned(),
+                display_name: "Nested Email Dispatch".to_owned(),
                 description: None,
-                trigger: WorkflowTrigger::RuntimeRecordCreated {
-                    entity_logical_name: "contact".to_owned(),
-                },
-                steps: vec![WorkflowStep::HttpRequest {
-                    method: "POST".to_owned(),
-                    url: "https://example.org/contact-created".to_owned(),
-                    headers: None,
-                    header_secret_refs: None,
-                    body: Some(json!({
-                        "record_id": "{{trigger.payload.record_id}}",
-                        "name": "{{trigger.payload.name}}",
-                        "status": "{{trigger.payload.status}}"
-                    })),
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::Condition {
+                    field_path: "status".to_owned(),
+                    operator: WorkflowConditionOperator::Equals,
+                    value: Some(json!("open")),
+                    then_label: None,
+                    else_label: None,
+                    then_steps: vec![WorkflowStep::SendEmail {
+                        to: "ops@qryvanta.test".to_owned(),
+                        subject: "Workflow update".to_owned(),
+                        body: "status changed".to_owned(),
+                        html_body: None,
+                        retry_policy: None,
+                    }],
+                    else_steps: Vec::new(),
                 }],
-                max_attempts: 2,
+                max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
     assert!(saved.is_ok());
 
-    let drain_result = service
-        .drain_runtime_record_workflow_events_for_worker("worker-alpha", 10, 30, Some(tenant_id))
-        .await;
-    assert!(drain_result.is_ok());
-    let drain_result = drain_result.unwrap_or_else(|_| unreachable!());
-    assert_eq!(drain_result.claimed_events, 1);
-    assert_eq!(drain_result.dispatched_workflows, 1);
-    assert_eq!(drain_result.released_events, 0);
-    assert_eq!(
-        runtime_service.completed_event_ids.lock().await.as_slice(),
-        ["event-e2e-1"]
-    );
-
-    let claimed_jobs = service
-        .claim_jobs_for_worker("worker-beta", 10, 30, None, Some(tenant_id))
-        .await;
-    assert!(claimed_jobs.is_ok());
-    let mut claimed_jobs = claimed_jobs.unwrap_or_default();
-    assert_eq!(claimed_jobs.len(), 1);
-
     let run = service
-        .execute_claimed_job("worker-beta", claimed_jobs.remove(0))
+        .execute_workflow(&actor, "nested_email_dispatch", json!({"status": "open"}))
         .await;
     assert!(run.is_ok());
     let run = run.unwrap_or_else(|_| unreachable!());
     assert_eq!(run.status, WorkflowRunStatus::Succeeded);
-    assert_eq!(run.attempts, 1);
-    assert_eq!(run.trigger_payload["record_id"], json!("record-e2e-1"));
 
-    let dispatched_requests = action_dispatcher.dispatched_requests.lock().await.clone();
-    assert_eq!(dispatched_requests.len(), 1);
-    assert_eq!(
-        dispatched_requests[0].dispatch_type,
-        WorkflowActionDispatchType::HttpRequest
-    );
-    assert_eq!(
-        dispatched_requests[0].payload["body"]["record_id"],
-        json!("record-e2e-1")
-    );
+    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
+    assert_eq!(dispatched.len(), 1);
     assert_eq!(
-        dispatched_requests[0].payload["body"]["name"],
-        json!("Alice")
+        dispatched[0].dispatch_type,
+        WorkflowActionDispatchType::Email
     );
     assert_eq!(
-        dispatched_requests[0].payload["body"]["status"],
-        json!("new")
+        dispatched[0].idempotency_key,
+        format!("{}:0.then.0", run.run_id)
     );
-
-    let attempts = service
-        .list_run_attempts(&actor, run.run_id.as_str())
-        .await
-        .unwrap_or_else(|_| unreachable!());
-    assert_eq!(attempts.len(), 1);
-    assert_eq!(attempts[0].status, WorkflowRunAttemptStatus::Succeeded);
-    assert_eq!(attempts[0].step_traces.len(), 1);
-    assert_eq!(attempts[0].step_traces[0].step_path, "0");
-
-    let replay = service
-        .replay_run(&actor, "contact_created_http_e2e", run.run_id.as_str())
-        .await;
-    assert!(replay.is_ok());
-    let replay = replay.unwrap_or_else(|_| unreachable!());
-    assert_eq!(replay.run.run_id, run.run_id);
-    assert_eq!(replay.attempts.len(), 1);
-    assert_eq!(replay.timeline.len(), 1);
-    assert_eq!(replay.timeline[0].step_path, "0");
-    assert_eq!(replay.timeline[0].attempt_status.as_str(), "succeeded");
+    assert_eq!(dispatched[0].step_path, "0.then.0");
 }
 
 #[tokio::test]
-async fn queued_mode_claims_can_be_filtered_to_one_tenant() {
-    let left_tenant = TenantId::new();
-    let right_tenant = TenantId::new();
-    let left_actor = UserIdentity::new("left-maker", "left-maker", None, left_tenant);
-    let right_actor = UserIdentity::new("right-maker", "right-maker", None, right_tenant);
+async fn native_update_record_step_updates_runtime_record() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
     let service = build_service(
-        HashMap::from([
-            (
-                (left_tenant, "left-maker".to_owned()),
-                vec![Permission::WorkflowManage, Permission::WorkflowRead],
-            ),
-            (
-                (right_tenant, "right-maker".to_owned()),
-                vec![Permission::WorkflowManage, Permission::WorkflowRead],
-            ),
-        ]),
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
         repository,
-        runtime_service,
-        WorkflowExecutionMode::Queued,
+        runtime_service.clone(),
+        WorkflowExecutionMode::Inline,
         None,
     );
 
-    let left_saved = service
-        .save_workflow(
-            &left_actor,
-            SaveWorkflowInput {
-                logical_name: "queued_contact_create".to_owned(),
-                display_name: "Queued Contact Create".to_owned(),
-                description: None,
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::LogMessage {
-                    message: "queued".to_owned(),
-                }],
-                max_attempts: 2,
-                is_enabled: true,
-            },
-        )
-        .await;
-    assert!(left_saved.is_ok());
-
-    let right_saved = service
+    let saved = service
         .save_workflow(
-            &right_actor,
+            &actor,
             SaveWorkflowInput {
-                logical_name: "queued_contact_create".to_owned(),
-                display_name: "Queued Contact Create".to_owned(),
+                logical_name: "update_contact".to_owned(),
+                display_name: "Update Contact".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::LogMessage {
-                    message: "queued".to_owned(),
+                steps: vec![WorkflowStep::UpdateRuntimeRecord {
+                    entity_logical_name: "contact".to_owned(),
+                    record_id: "{{trigger.payload.record_id}}".to_owned(),
+                    data: json!({"status": "qualified"}),
+                    retry_policy: None,
                 }],
-                max_attempts: 2,
+                max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
-    assert!(right_saved.is_ok());
-
-    let left_enqueued = service
-        .execute_workflow(
-            &left_actor,
-            "queued_contact_create",
-            json!({"source": "left"}),
-        )
-        .await;
-    assert!(left_enqueued.is_ok());
+    assert!(saved.is_ok());
 
-    let right_enqueued = service
-        .execute_workflow(
-            &right_actor,
-            "queued_contact_create",
-            json!({"source": "right"}),
-        )
+    let executed = service
+        .execute_workflow(&actor, "update_contact", json!({"record_id": "rec-42"}))
         .await;
-    assert!(right_enqueued.is_ok());
+    assert!(executed.is_ok());
 
-    let claimed_jobs = service
-        .claim_jobs_for_worker("worker-alpha", 10, 30, None, Some(left_tenant))
-        .await;
-    assert!(claimed_jobs.is_ok());
-    let claimed_jobs = claimed_jobs.unwrap_or_default();
-    assert_eq!(claimed_jobs.len(), 1);
-    assert_eq!(claimed_jobs[0].tenant_id, left_tenant);
-    assert_eq!(claimed_jobs[0].trigger_payload["source"], json!("left"));
+    let updated = runtime_service.updated_records.lock().await.clone();
+    assert_eq!(updated.len(), 1);
+    assert_eq!(updated[0].0, "contact");
+    assert_eq!(updated[0].1, "rec-42");
+    assert_eq!(updated[0].2["status"], json!("qualified"));
 }
 
 #[tokio::test]
-async fn queued_mode_does_not_double_claim_same_job_while_lease_is_active() {
+async fn native_delete_record_step_deletes_runtime_record() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
@@ -3122,55 +4458,49 @@ async fn queued_mode_does_not_double_claim_same_job_while_lease_is_active() {
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
         repository,
-        runtime_service,
-        WorkflowExecutionMode::Queued,
+        runtime_service.clone(),
+        WorkflowExecutionMode::Inline,
         None,
     );
 
-    let save_result = service
+    let saved = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "queued_contact_create_single_claim".to_owned(),
-                display_name: "Queued Contact Create Single Claim".to_owned(),
+                logical_name: "delete_contact".to_owned(),
+                display_name: "Delete Contact".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::LogMessage {
-                    message: "queued".to_owned(),
+                steps: vec![WorkflowStep::DeleteRuntimeRecord {
+                    entity_logical_name: "contact".to_owned(),
+                    record_id: "rec-7".to_owned(),
+                    retry_policy: None,
                 }],
-                max_attempts: 2,
+                max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
-    assert!(save_result.is_ok());
-
-    let enqueued_run = service
-        .execute_workflow(
-            &actor,
-            "queued_contact_create_single_claim",
-            json!({"source": "test"}),
-        )
-        .await;
-    assert!(enqueued_run.is_ok());
+    assert!(saved.is_ok());
 
-    let first_claim = service
-        .claim_jobs_for_worker("worker-alpha", 10, 30, None, None)
+    let executed = service
+        .execute_workflow(&actor, "delete_contact", json!({}))
         .await;
-    assert!(first_claim.is_ok());
-    let first_claim = first_claim.unwrap_or_default();
-    assert_eq!(first_claim.len(), 1);
+    assert!(executed.is_ok());
 
-    let second_claim = service
-        .claim_jobs_for_worker("worker-beta", 10, 30, None, None)
-        .await;
-    assert!(second_claim.is_ok());
-    let second_claim = second_claim.unwrap_or_default();
-    assert!(second_claim.is_empty());
+    let deleted = runtime_service.deleted_records.lock().await.clone();
+    assert_eq!(
+        deleted.as_slice(),
+        [("contact".to_owned(), "rec-7".to_owned())]
+    );
 }
 
 #[tokio::test]
-async fn queued_mode_rejects_claimed_job_with_empty_lease_token() {
+async fn native_assign_owner_step_creates_assignment_record() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
@@ -3181,56 +4511,51 @@ async fn queued_mode_rejects_claimed_job_with_empty_lease_token() {
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
         repository,
-        runtime_service,
-        WorkflowExecutionMode::Queued,
+        runtime_service.clone(),
+        WorkflowExecutionMode::Inline,
         None,
     );
 
-    let save_result = service
+    let saved = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "queued_contact_create_empty_token".to_owned(),
-                display_name: "Queued Contact Create Empty Token".to_owned(),
+                logical_name: "assign_owner".to_owned(),
+                display_name: "Assign Owner".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::LogMessage {
-                    message: "queued".to_owned(),
+                steps: vec![WorkflowStep::AssignOwner {
+                    entity_logical_name: "lead".to_owned(),
+                    record_id: "lead-1".to_owned(),
+                    owner_id: "triage_queue".to_owned(),
+                    reason: Some("auto routing".to_owned()),
+                    retry_policy: None,
                 }],
-                max_attempts: 2,
+                max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
-    assert!(save_result.is_ok());
-
-    let enqueued_run = service
-        .execute_workflow(
-            &actor,
-            "queued_contact_create_empty_token",
-            json!({"source": "test"}),
-        )
-        .await;
-    assert!(enqueued_run.is_ok());
+    assert!(saved.is_ok());
 
-    let claimed_jobs = service
-        .claim_jobs_for_worker("worker-alpha", 10, 30, None, None)
+    let executed = service
+        .execute_workflow(&actor, "assign_owner", json!({}))
         .await;
-    assert!(claimed_jobs.is_ok());
-    let mut claimed_jobs = claimed_jobs.unwrap_or_default();
-    assert_eq!(claimed_jobs.len(), 1);
-
-    let mut claimed_job = claimed_jobs.remove(0);
-    claimed_job.lease_token = String::new();
+    assert!(executed.is_ok());
 
-    let completed = service
-        .execute_claimed_job("worker-alpha", claimed_job)
-        .await;
-    assert!(completed.is_err());
+    let created = runtime_service.created_records.lock().await.clone();
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].0, "record_assignment");
+    assert_eq!(created[0].1["owner_id"], json!("triage_queue"));
+    assert_eq!(created[0].1["source_entity"], json!("lead"));
 }
 
 #[tokio::test]
-async fn queued_mode_rejects_claimed_job_with_stale_lease_token() {
+async fn native_approval_request_step_creates_approval_record() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
@@ -3240,96 +4565,67 @@ async fn queued_mode_rejects_claimed_job_with_stale_lease_token() {
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
-        repository.clone(),
-        runtime_service,
-        WorkflowExecutionMode::Queued,
+        repository,
+        runtime_service.clone(),
+        WorkflowExecutionMode::Inline,
         None,
     );
 
-    let save_result = service
+    let saved = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "queued_contact_create_stale_token".to_owned(),
-                display_name: "Queued Contact Create Stale Token".to_owned(),
+                logical_name: "approval_request".to_owned(),
+                display_name: "Approval Request".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::LogMessage {
-                    message: "queued".to_owned(),
+                steps: vec![WorkflowStep::ApprovalRequest {
+                    entity_logical_name: "quote".to_owned(),
+                    record_id: "quote-2".to_owned(),
+                    request_type: "discount_override".to_owned(),
+                    requested_by: None,
+                    approver_id: Some("manager-7".to_owned()),
+                    reason: Some("requires manager approval".to_owned()),
+                    payload: Some(json!({"discount": 20})),
+                    retry_policy: None,
                 }],
-                max_attempts: 2,
+                max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
-    assert!(save_result.is_ok());
-
-    let enqueued_run = service
-        .execute_workflow(
-            &actor,
-            "queued_contact_create_stale_token",
-            json!({"source": "test"}),
-        )
-        .await;
-    assert!(enqueued_run.is_ok());
-
-    let claimed_jobs = service
-        .claim_jobs_for_worker("worker-alpha", 10, 30, None, None)
-        .await;
-    assert!(claimed_jobs.is_ok());
-    let mut claimed_jobs = claimed_jobs.unwrap_or_default();
-    assert_eq!(claimed_jobs.len(), 1);
-
-    let mut claimed_job = claimed_jobs.remove(0);
-    claimed_job.lease_token = "stale-lease-token".to_owned();
+    assert!(saved.is_ok());
 
-    let completed = service
-        .execute_claimed_job("worker-alpha", claimed_job)
+    let executed = service
+        .execute_workflow(&actor, "approval_request", json!({}))
         .await;
-    assert!(completed.is_err());
+    assert!(executed.is_ok());
 
-    let jobs = repository.jobs.lock().await.clone();
-    assert_eq!(jobs.len(), 1);
-    assert!(!jobs[0].completed);
-    assert!(!jobs[0].failed);
-    assert_eq!(jobs[0].leased_by.as_deref(), Some("worker-alpha"));
+    let created = runtime_service.created_records.lock().await.clone();
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].0, "approval_request");
+    assert_eq!(created[0].1["status"], json!("pending"));
+    assert_eq!(created[0].1["approver_id"], json!("manager-7"));
+    assert_eq!(created[0].1["requested_by"], json!("workflow-runtime"));
 }
 
 #[tokio::test]
-async fn drain_runtime_record_workflow_events_dispatches_matching_workflows() {
+async fn native_delay_step_executes_successfully() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
-    runtime_service
-        .queued_events
-        .lock()
-        .await
-        .push(ClaimedRuntimeRecordWorkflowEvent {
-            event_id: "event-1".to_owned(),
-            tenant_id,
-            trigger: WorkflowTrigger::RuntimeRecordCreated {
-                entity_logical_name: "contact".to_owned(),
-            },
-            record_id: "record-1".to_owned(),
-            payload: json!({
-                "entity_logical_name": "contact",
-                "record_id": "record-1",
-                "id": "record-1",
-                "record": {"name": "Alice"},
-                "data": {"name": "Alice"},
-                "event": "created"
-            }),
-            emitted_by_subject: "maker".to_owned(),
-            lease_token: "lease-1".to_owned(),
-        });
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
-        repository.clone(),
-        runtime_service.clone(),
+        repository,
+        runtime_service,
         WorkflowExecutionMode::Inline,
         None,
     );
@@ -3338,261 +4634,274 @@ async fn drain_runtime_record_workflow_events_dispatches_matching_workflows() {
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "contact_created_log".to_owned(),
-                display_name: "Contact Created Log".to_owned(),
+                logical_name: "delay_native".to_owned(),
+                display_name: "Delay Native".to_owned(),
                 description: None,
-                trigger: WorkflowTrigger::RuntimeRecordCreated {
-                    entity_logical_name: "contact".to_owned(),
-                },
-                steps: vec![WorkflowStep::LogMessage {
-                    message: "created".to_owned(),
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::Delay {
+                    duration_ms: 1,
+                    reason: Some("wait for consistency".to_owned()),
                 }],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
     assert!(saved.is_ok());
 
-    let result = service
-        .drain_runtime_record_workflow_events_for_worker("worker-alpha", 10, 30, Some(tenant_id))
+    let run = service
+        .execute_workflow(&actor, "delay_native", json!({}))
         .await;
-    assert!(result.is_ok());
-    let result = result.unwrap_or_else(|_| unreachable!());
-    assert_eq!(result.claimed_events, 1);
-    assert_eq!(result.dispatched_workflows, 1);
-    assert_eq!(result.released_events, 0);
-    assert_eq!(
-        runtime_service.completed_event_ids.lock().await.as_slice(),
-        ["event-1"]
-    );
-    assert!(runtime_service.released_event_ids.lock().await.is_empty());
-    assert_eq!(repository.runs.lock().await.len(), 1);
+    assert!(run.is_ok());
+    let run = run.unwrap_or_else(|_| unreachable!());
+    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
 }
 
 #[tokio::test]
-async fn drain_runtime_record_workflow_events_completes_after_workflow_dead_letters() {
+async fn execute_workflow_condition_branch_uses_trigger_payload() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
-    runtime_service
-        .queued_events
-        .lock()
-        .await
-        .push(ClaimedRuntimeRecordWorkflowEvent {
-            event_id: "event-2".to_owned(),
-            tenant_id,
-            trigger: WorkflowTrigger::RuntimeRecordCreated {
-                entity_logical_name: "contact".to_owned(),
-            },
-            record_id: "record-2".to_owned(),
-            payload: json!({
-                "entity_logical_name": "contact",
-                "record_id": "record-2",
-                "id": "record-2",
-                "record": {"name": "Alice"},
-                "data": {"name": "Alice"},
-                "event": "created"
-            }),
-            emitted_by_subject: "maker".to_owned(),
-            lease_token: "lease-2".to_owned(),
-        });
-    *runtime_service.failures_remaining.lock().await = 1;
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
         repository,
-        runtime_service.clone(),
+        runtime_service,
         WorkflowExecutionMode::Inline,
         None,
     );
 
-    let saved = service
+    let save_result = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "contact_created_create".to_owned(),
-                display_name: "Contact Created Create".to_owned(),
+                logical_name: "branch_by_status".to_owned(),
+                display_name: "Branch By Status".to_owned(),
                 description: None,
-                trigger: WorkflowTrigger::RuntimeRecordCreated {
-                    entity_logical_name: "contact".to_owned(),
-                },
-                steps: vec![WorkflowStep::CreateRuntimeRecord {
-                    entity_logical_name: "contact".to_owned(),
-                    data: json!({"name": "Follow Up"}),
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::Condition {
+                    field_path: "status".to_owned(),
+                    operator: WorkflowConditionOperator::Equals,
+                    value: Some(json!("open")),
+                    then_label: Some("Matched".to_owned()),
+                    else_label: Some("Unmatched".to_owned()),
+                    then_steps: vec![WorkflowStep::LogMessage {
+                        message: "open-path".to_owned(),
+                    }],
+                    else_steps: vec![WorkflowStep::CreateRuntimeRecord {
+                        entity_logical_name: "task".to_owned(),
+                        data: json!({"title": "follow-up"}),
+                        output_key: None,
+                        retry_policy: None,
+                    }],
                 }],
-                max_attempts: 1,
+                max_attempts: 2,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
-    assert!(saved.is_ok());
+    assert!(save_result.is_ok());
 
-    let result = service
-        .drain_runtime_record_workflow_events_for_worker("worker-alpha", 10, 30, Some(tenant_id))
+    let run = service
+        .execute_workflow(&actor, "branch_by_status", json!({"status": "open"}))
         .await;
-    assert!(result.is_ok());
-    let result = result.unwrap_or_else(|_| unreachable!());
-    assert_eq!(result.claimed_events, 1);
-    assert_eq!(result.dispatched_workflows, 1);
-    assert_eq!(result.released_events, 0);
-    assert_eq!(
-        runtime_service.completed_event_ids.lock().await.as_slice(),
-        ["event-2"]
-    );
-    assert!(runtime_service.released_event_ids.lock().await.is_empty());
+    assert!(run.is_ok());
+    let run = run.unwrap_or_else(|_| unreachable!());
+    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
+    assert_eq!(run.attempts, 1);
 }
 
 #[tokio::test]
-async fn drain_runtime_record_workflow_events_releases_then_retries_transient_dispatch_failures() {
+async fn condition_related_paths_load_related_records_once_per_run() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
-    runtime_service
-        .queued_events
-        .lock()
-        .await
-        .push(ClaimedRuntimeRecordWorkflowEvent {
-            event_id: "event-retry-1".to_owned(),
-            tenant_id,
-            trigger: WorkflowTrigger::RuntimeRecordCreated {
-                entity_logical_name: "contact".to_owned(),
-            },
-            record_id: "record-retry-1".to_owned(),
-            payload: json!({
-                "entity_logical_name": "contact",
-                "record_id": "record-retry-1",
-                "id": "record-retry-1",
-                "record": {"name": "Alice"},
-                "data": {"name": "Alice"},
-                "event": "created"
-            }),
-            emitted_by_subject: "maker".to_owned(),
-            lease_token: "lease-retry-1".to_owned(),
-        });
-    *repository
-        .fail_list_enabled_workflows_remaining
-        .lock()
-        .await = 1;
-
+    runtime_service.related_records.lock().await.insert(
+        (
+            "contact".to_owned(),
+            "account".to_owned(),
+            "account-1".to_owned(),
+        ),
+        qryvanta_domain::RuntimeRecord::new(
+            "account-1",
+            "account",
+            json!({"tier": "gold", "parent_account": "account-0"}),
+        )
+        .unwrap_or_else(|_| unreachable!()),
+    );
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
-        repository.clone(),
+        repository,
         runtime_service.clone(),
         WorkflowExecutionMode::Inline,
         None,
     );
 
-    let saved = service
+    let condition = |field_path: &str, operator, value, title: &str| WorkflowStep::Condition {
+        field_path: field_path.to_owned(),
+        operator,
+        value,
+        then_label: None,
+        else_label: None,
+        then_steps: vec![WorkflowStep::CreateRuntimeRecord {
+            entity_logical_name: "task".to_owned(),
+            data: json!({"title": title}),
+            output_key: None,
+            retry_policy: None,
+        }],
+        else_steps: Vec::new(),
+    };
+    let save_result = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "contact_created_retry_log".to_owned(),
-                display_name: "Contact Created Retry Log".to_owned(),
+                logical_name: "gold_account_contacts".to_owned(),
+                display_name: "Gold Account Contacts".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::RuntimeRecordCreated {
                     entity_logical_name: "contact".to_owned(),
                 },
-                steps: vec![WorkflowStep::LogMessage {
-                    message: "created".to_owned(),
-                }],
+                steps: vec![
+                    condition(
+                        "related.account.tier",
+                        WorkflowConditionOperator::Equals,
+                        Some(json!("gold")),
+                        "gold",
+                    ),
+                    condition(
+                        "related.account.tier",
+                        WorkflowConditionOperator::NotEquals,
+                        Some(json!("silver")),
+                        "not-silver",
+                    ),
+                    condition(
+                        "related.account.parent_account.name",
+                        WorkflowConditionOperator::Exists,
+                        None,
+                        "has-parent",
+                    ),
+                ],
                 max_attempts: 1,
                 is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
-    assert!(saved.is_ok());
+    assert!(save_result.is_ok());
 
-    let first = service
-        .drain_runtime_record_workflow_events_for_worker("worker-alpha", 10, 30, Some(tenant_id))
-        .await
-        .unwrap_or_else(|_| unreachable!());
-    assert_eq!(first.claimed_events, 1);
-    assert_eq!(first.dispatched_workflows, 0);
-    assert_eq!(first.released_events, 1);
-    assert!(runtime_service.completed_event_ids.lock().await.is_empty());
-    assert_eq!(
-        runtime_service.released_event_ids.lock().await.as_slice(),
-        ["event-retry-1"]
-    );
-    assert_eq!(repository.runs.lock().await.len(), 0);
-    assert_eq!(runtime_service.queued_events.lock().await.len(), 1);
+    let dispatched = service
+        .dispatch_runtime_record_created(
+            &actor,
+            "contact",
+            "contact-1",
+            &json!({"name": "Alice", "account": "account-1"}),
+        )
+        .await;
+    assert_eq!(dispatched.unwrap_or_default(), 1);
 
-    let second = service
-        .drain_runtime_record_workflow_events_for_worker("worker-alpha", 10, 30, Some(tenant_id))
+    let created_titles = runtime_service
+        .created_records
+        .lock()
         .await
-        .unwrap_or_else(|_| unreachable!());
-    assert_eq!(second.claimed_events, 1);
-    assert_eq!(second.dispatched_workflows, 1);
-    assert_eq!(second.released_events, 0);
-    assert_eq!(
-        runtime_service.completed_event_ids.lock().await.as_slice(),
-        ["event-retry-1"]
-    );
-    assert_eq!(
-        runtime_service.released_event_ids.lock().await.as_slice(),
-        ["event-retry-1"]
-    );
-    assert_eq!(repository.runs.lock().await.len(), 1);
-    assert!(runtime_service.queued_events.lock().await.is_empty());
-}
-
-#[test]
-fn workflow_claim_partition_rejects_invalid_index() {
-    let partition = WorkflowClaimPartition::new(4, 4);
-    assert!(partition.is_err());
-}
-
-#[test]
-fn workflow_claim_partition_accepts_valid_values() {
-    let partition = WorkflowClaimPartition::new(8, 3);
-    assert!(partition.is_ok());
-    let partition = partition.unwrap_or_else(|_| unreachable!());
-    assert_eq!(partition.partition_count(), 8);
-    assert_eq!(partition.partition_index(), 3);
+        .iter()
+        .map(|(_, data)| data["title"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(created_titles, vec![json!("gold"), json!("not-silver")]);
+    // account-1 is cached after the first lookup; only the missing parent adds another.
+    assert_eq!(*runtime_service.related_record_lookups.lock().await, 2);
 }
 
 #[tokio::test]
-async fn queued_mode_supports_worker_heartbeat_and_queue_stats() {
+async fn execute_workflow_interpolates_trigger_and_run_tokens_in_actions() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
     let service = build_service(
-        HashMap::new(),
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
         repository,
-        runtime_service,
-        WorkflowExecutionMode::Queued,
+        runtime_service.clone(),
+        WorkflowExecutionMode::Inline,
         None,
     );
 
-    let heartbeat = service
-        .heartbeat_worker(
-            "worker-alpha",
-            WorkflowWorkerHeartbeatInput {
-                claimed_jobs: 2,
-                executed_jobs: 2,
-                failed_jobs: 0,
-                partition: None,
+    let save_result = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "interpolate_runtime_tokens".to_owned(),
+                display_name: "Interpolate Runtime Tokens".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::CreateRuntimeRecord {
+                    entity_logical_name: "{{trigger.payload.target_entity}}".to_owned(),
+                    data: json!({
+                        "title": "Record {{trigger.payload.record_id}}",
+                        "record_id": "{{trigger.payload.record_id}}",
+                        "run_id": "{{run.id}}",
+                        "attempt": "{{run.attempt}}",
+                        "owner": "{{trigger.payload.owner}}",
+                    }),
+                    output_key: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
-    assert!(heartbeat.is_ok());
+    assert!(save_result.is_ok());
 
-    let stats = service.queue_stats(120).await;
-    assert!(stats.is_ok());
-    let stats = stats.unwrap_or_else(|_| unreachable!());
-    assert_eq!(stats.pending_jobs, 0);
-    assert_eq!(stats.active_workers, 0);
+    let run = service
+        .execute_workflow(
+            &actor,
+            "interpolate_runtime_tokens",
+            json!({
+                "target_entity": "contact",
+                "record_id": "rec-42",
+                "owner": "ops@qryvanta.test"
+            }),
+        )
+        .await;
+    assert!(run.is_ok());
+
+    let created_records = runtime_service.created_records.lock().await.clone();
+    assert_eq!(created_records.len(), 1);
+    assert_eq!(created_records[0].0, "contact");
+    assert_eq!(created_records[0].1["title"], json!("Record rec-42"));
+    assert_eq!(created_records[0].1["record_id"], json!("rec-42"));
+    assert_eq!(created_records[0].1["attempt"], json!(1));
+    assert_eq!(created_records[0].1["owner"], json!("ops@qryvanta.test"));
+    assert!(created_records[0].1["run_id"].as_str().is_some());
 }
 
 #[tokio::test]
-async fn draft_save_does_not_dispatch_until_workflow_is_published() {
+async fn queued_mode_enqueues_and_worker_executes_claimed_job() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
@@ -3604,50 +4913,56 @@ async fn draft_save_does_not_dispatch_until_workflow_is_published() {
         )]),
         repository,
         runtime_service,
-        WorkflowExecutionMode::Inline,
+        WorkflowExecutionMode::Queued,
         None,
     );
 
-    service
+    let save_result = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "lead_capture".to_owned(),
-                display_name: "Lead Capture".to_owned(),
+                logical_name: "queued_contact_create".to_owned(),
+                display_name: "Queued Contact Create".to_owned(),
                 description: None,
-                trigger: WorkflowTrigger::WebhookReceived {
-                    webhook_key: "leads".to_owned(),
-                },
+                trigger: WorkflowTrigger::Manual,
                 steps: vec![WorkflowStep::LogMessage {
-                    message: "captured".to_owned(),
+                    message: "queued".to_owned(),
                 }],
                 max_attempts: 2,
-                is_enabled: false,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
-        .await
-        .unwrap_or_else(|_| unreachable!());
+        .await;
+    assert!(save_result.is_ok());
 
-    let before_publish = service
-        .dispatch_webhook_received(tenant_id, "leads", json!({"lead_id": "lead-1"}))
-        .await
-        .unwrap_or_else(|_| unreachable!());
-    assert_eq!(before_publish, 0);
+    let enqueued_run = service
+        .execute_workflow(&actor, "queued_contact_create", json!({"source": "test"}))
+        .await;
+    assert!(enqueued_run.is_ok());
+    let enqueued_run = enqueued_run.unwrap_or_else(|_| unreachable!());
+    assert_eq!(enqueued_run.status, WorkflowRunStatus::Running);
 
-    service
-        .publish_workflow(&actor, "lead_capture")
-        .await
-        .unwrap_or_else(|_| unreachable!());
+    let claimed_jobs = service
+        .claim_jobs_for_worker("worker-alpha", 10, 30, None, None)
+        .await;
+    assert!(claimed_jobs.is_ok());
+    let mut claimed_jobs = claimed_jobs.unwrap_or_default();
+    assert_eq!(claimed_jobs.len(), 1);
 
-    let after_publish = service
-        .dispatch_webhook_received(tenant_id, "leads", json!({"lead_id": "lead-2"}))
-        .await
-        .unwrap_or_else(|_| unreachable!());
-    assert_eq!(after_publish, 1);
+    let completed = service
+        .execute_claimed_job("worker-alpha", claimed_jobs.remove(0))
+        .await;
+    assert!(completed.is_ok());
+    let completed = completed.unwrap_or_else(|_| unreachable!());
+    assert_eq!(completed.status, WorkflowRunStatus::Succeeded);
 }
 
 #[tokio::test]
-async fn metadata_permissions_do_not_grant_workflow_access() {
+async fn queued_runs_carry_trace_context_to_claimed_jobs() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
@@ -3655,45 +4970,56 @@ async fn metadata_permissions_do_not_grant_workflow_access() {
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
-            vec![
-                Permission::MetadataFieldWrite,
-                Permission::MetadataFieldRead,
-            ],
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
         repository,
         runtime_service,
-        WorkflowExecutionMode::Inline,
+        WorkflowExecutionMode::Queued,
         None,
-    );
+    )
+    .with_trace_context_source(Arc::new(FakeTraceContextSource));
 
     let save_result = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "blocked_workflow".to_owned(),
-                display_name: "Blocked Workflow".to_owned(),
+                logical_name: "traced_contact_create".to_owned(),
+                display_name: "Traced Contact Create".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
                 steps: vec![WorkflowStep::LogMessage {
-                    message: "blocked".to_owned(),
+                    message: "traced".to_owned(),
                 }],
                 max_attempts: 1,
-                is_enabled: false,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await;
+    assert!(save_result.is_ok());
     assert!(
-        matches!(save_result, Err(AppError::Forbidden(message)) if message.contains("workflow.manage"))
+        service
+            .execute_workflow(&actor, "traced_contact_create", json!({}))
+            .await
+            .is_ok()
     );
 
-    let list_result = service.list_workflows(&actor).await;
-    assert!(
-        matches!(list_result, Err(AppError::Forbidden(message)) if message.contains("workflow.read"))
+    let claimed_jobs = service
+        .claim_jobs_for_worker("worker-alpha", 10, 30, None, None)
+        .await
+        .unwrap_or_default();
+    assert_eq!(claimed_jobs.len(), 1);
+    assert_eq!(
+        claimed_jobs[0].trace_context.as_deref(),
+        Some(FAKE_TRACE_CONTEXT)
     );
 }
 
 #[tokio::test]
-async fn workflow_permissions_allow_access_without_metadata_permissions() {
+async fn released_claimed_jobs_are_claimable_by_other_workers() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
@@ -3705,38 +5031,1525 @@ async fn workflow_permissions_allow_access_without_metadata_permissions() {
         )]),
         repository,
         runtime_service,
-        WorkflowExecutionMode::Inline,
+        WorkflowExecutionMode::Queued,
         None,
     );
 
-    service
+    let save_result = service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "granted_workflow".to_owned(),
-                display_name: "Granted Workflow".to_owned(),
+                logical_name: "drained_contact_create".to_owned(),
+                display_name: "Drained Contact Create".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
                 steps: vec![WorkflowStep::LogMessage {
-                    message: "allowed".to_owned(),
+                    message: "drained".to_owned(),
                 }],
                 max_attempts: 1,
-                is_enabled: false,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
+        .await;
+    assert!(save_result.is_ok());
+    assert!(
+        service
+            .execute_workflow(&actor, "drained_contact_create", json!({}))
+            .await
+            .is_ok()
+    );
+
+    let claimed_jobs = service
+        .claim_jobs_for_worker("worker-alpha", 10, 300, None, None)
         .await
-        .unwrap_or_else(|_| unreachable!());
+        .unwrap_or_default();
+    assert_eq!(claimed_jobs.len(), 1);
+    let job = &claimed_jobs[0];
 
-    let workflows = service
-        .list_workflows(&actor)
+    let wrong_token = service
+        .release_claimed_job(
+            "worker-alpha",
+            tenant_id,
+            job.job_id.as_str(),
+            "stale-token",
+            "worker shutdown",
+        )
+        .await;
+    assert!(matches!(wrong_token, Ok(false)));
+
+    let released = service
+        .release_claimed_job(
+            "worker-alpha",
+            tenant_id,
+            job.job_id.as_str(),
+            job.lease_token.as_str(),
+            "worker shutdown",
+        )
+        .await;
+    assert!(matches!(released, Ok(true)));
+
+    let reclaimed_jobs = service
+        .claim_jobs_for_worker("worker-beta", 10, 300, None, None)
         .await
-        .unwrap_or_else(|_| unreachable!());
-    assert_eq!(workflows.len(), 1);
-    assert_eq!(workflows[0].logical_name().as_str(), "granted_workflow");
+        .unwrap_or_default();
+    assert_eq!(reclaimed_jobs.len(), 1);
+    assert_eq!(reclaimed_jobs[0].job_id, job.job_id);
+
+    let released_again = service
+        .release_claimed_job(
+            "worker-alpha",
+            tenant_id,
+            job.job_id.as_str(),
+            job.lease_token.as_str(),
+            "worker shutdown",
+        )
+        .await;
+    assert!(matches!(released_again, Ok(false)));
 }
 
 #[tokio::test]
-async fn workflow_publish_checks_report_unpublished_entity_dependencies() {
+async fn queued_wait_step_suspends_run_and_resumes_after_wait() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository.clone(),
+        runtime_service.clone(),
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+
+    let save_result = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "follow_up_later".to_owned(),
+                display_name: "Follow Up Later".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![
+                    WorkflowStep::CreateRuntimeRecord {
+                        entity_logical_name: "contact".to_owned(),
+                        data: json!({"name": "Alice"}),
+                        output_key: None,
+                        retry_policy: None,
+                    },
+                    WorkflowStep::Condition {
+                        field_path: "follow_up_at".to_owned(),
+                        operator: WorkflowConditionOperator::Exists,
+                        value: None,
+                        then_label: None,
+                        else_label: None,
+                        then_steps: vec![
+                            WorkflowStep::Wait {
+                                duration_seconds: None,
+                                until_field_path: Some("follow_up_at".to_owned()),
+                                reason: Some("wait for follow-up date".to_owned()),
+                            },
+                            WorkflowStep::CreateRuntimeRecord {
+                                entity_logical_name: "task".to_owned(),
+                                data: json!({"title": "Follow up"}),
+                                output_key: None,
+                                retry_policy: None,
+                            },
+                        ],
+                        else_steps: Vec::new(),
+                    },
+                ],
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(save_result.is_ok());
+
+    let follow_up_at = (Utc::now() + chrono::Duration::hours(1)).to_rfc3339();
+    let enqueued_run = service
+        .execute_workflow(
+            &actor,
+            "follow_up_later",
+            json!({"follow_up_at": follow_up_at}),
+        )
+        .await;
+    assert!(enqueued_run.is_ok());
+
+    let mut claimed_jobs = service
+        .claim_jobs_for_worker("worker-alpha", 10, 30, None, None)
+        .await
+        .unwrap_or_default();
+    assert_eq!(claimed_jobs.len(), 1);
+    let suspended = service
+        .execute_claimed_job("worker-alpha", claimed_jobs.remove(0))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(suspended.status, WorkflowRunStatus::Waiting);
+    assert!(suspended.resume_at.is_some());
+    assert_eq!(runtime_service.created_records.lock().await.len(), 1);
+
+    let early_claim = service
+        .claim_jobs_for_worker("worker-alpha", 10, 30, None, None)
+        .await
+        .unwrap_or_default();
+    assert!(early_claim.is_empty());
+
+    repository.jobs.lock().await[0].available_at = Some(Utc::now());
+    let mut claimed_jobs = service
+        .claim_jobs_for_worker("worker-alpha", 10, 30, None, None)
+        .await
+        .unwrap_or_default();
+    assert_eq!(claimed_jobs.len(), 1);
+    let continuation = claimed_jobs[0].continuation.clone();
+    assert_eq!(
+        continuation.map(|continuation| continuation.resume_after_step_path),
+        Some("1.then.0".to_owned())
+    );
+
+    let completed = service
+        .execute_claimed_job("worker-alpha", claimed_jobs.remove(0))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(completed.status, WorkflowRunStatus::Succeeded);
+    assert_eq!(completed.attempts, 2);
+
+    let created = runtime_service.created_records.lock().await.clone();
+    assert_eq!(created.len(), 2);
+    assert_eq!(created[1].0, "task");
+
+    let attempts = repository.attempts.lock().await.clone();
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts[0].status, WorkflowRunAttemptStatus::Waiting);
+    assert_eq!(attempts[1].status, WorkflowRunAttemptStatus::Succeeded);
+    assert_eq!(attempts[1].step_traces[0].step_path, "1.then.1");
+}
+
+#[tokio::test]
+async fn queued_runtime_event_flow_covers_outbox_job_execution_and_replay_history() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
+    runtime_service
+        .queued_events
+        .lock()
+        .await
+        .push(ClaimedRuntimeRecordWorkflowEvent {
+            event_id: "event-e2e-1".to_owned(),
+            tenant_id,
+            trigger: WorkflowTrigger::RuntimeRecordCreated {
+                entity_logical_name: "contact".to_owned(),
+            },
+            record_id: "record-e2e-1".to_owned(),
+            payload: json!({
+                "entity_logical_name": "contact",
+                "record_id": "record-e2e-1",
+                "id": "record-e2e-1",
+                "name": "Alice",
+                "status": "new",
+                "record": {"name": "Alice", "status": "new"},
+                "data": {"name": "Alice", "status": "new"},
+                "event": "created"
+            }),
+            emitted_by_subject: "maker".to_owned(),
+            lease_token: "lease-e2e-1".to_owned(),
+        });
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository.clone(),
+        runtime_service.clone(),
+        WorkflowExecutionMode::Queued,
+        Some(action_dispatcher.clone()),
+    );
+
+    let saved = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "contact_created_http_e2e".to_owned(),
+                display_name: "Contact Created HTTP E2E".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::RuntimeRecordCreated {
+                    entity_logical_name: "contact".to_owned(),
+                },
+                steps: vec![WorkflowStep::HttpRequest {
+                    method: "POST".to_owned(),
+                    url: "https://example.org/contact-created".to_owned(),
+                    headers: None,
+                    credential: None,
+                    header_secret_refs: None,
+                    body: Some(json!({
+                        "record_id": "{{trigger.payload.record_id}}",
+                        "name": "{{trigger.payload.name}}",
+                        "status": "{{trigger.payload.status}}"
+                    })),
+                    expected_status: None,
+                    retry: None,
+                    output_key: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(saved.is_ok());
+
+    let drain_result = service
+        .drain_runtime_record_workflow_events_for_worker("worker-alpha", 10, 30, Some(tenant_id))
+        .await;
+    assert!(drain_result.is_ok());
+    let drain_result = drain_result.unwrap_or_else(|_| unreachable!());
+    assert_eq!(drain_result.claimed_events, 1);
+    assert_eq!(drain_result.dispatched_workflows, 1);
+    assert_eq!(drain_result.released_events, 0);
+    assert_eq!(
+        runtime_service.completed_event_ids.lock().await.as_slice(),
+        ["event-e2e-1"]
+    );
+
+    let claimed_jobs = service
+        .claim_jobs_for_worker("worker-beta", 10, 30, None, Some(tenant_id))
+        .await;
+    assert!(claimed_jobs.is_ok());
+    let mut claimed_jobs = claimed_jobs.unwrap_or_default();
+    assert_eq!(claimed_jobs.len(), 1);
+
+    let run = service
+        .execute_claimed_job("worker-beta", claimed_jobs.remove(0))
+        .await;
+    assert!(run.is_ok());
+    let run = run.unwrap_or_else(|_| unreachable!());
+    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
+    assert_eq!(run.attempts, 1);
+    assert_eq!(run.trigger_payload["record_id"], json!("record-e2e-1"));
+
+    let dispatched_requests = action_dispatcher.dispatched_requests.lock().await.clone();
+    assert_eq!(dispatched_requests.len(), 1);
+    assert_eq!(
+        dispatched_requests[0].dispatch_type,
+        WorkflowActionDispatchType::HttpRequest
+    );
+    assert_eq!(
+        dispatched_requests[0].payload["body"]["record_id"],
+        json!("record-e2e-1")
+    );
+    assert_eq!(
+        dispatched_requests[0].payload["body"]["name"],
+        json!("Alice")
+    );
+    assert_eq!(
+        dispatched_requests[0].payload["body"]["status"],
+        json!("new")
+    );
+
+    let attempts = service
+        .list_run_attempts(&actor, run.run_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(attempts.len(), 1);
+    assert_eq!(attempts[0].status, WorkflowRunAttemptStatus::Succeeded);
+    assert_eq!(attempts[0].step_traces.len(), 1);
+    assert_eq!(attempts[0].step_traces[0].step_path, "0");
+
+    let replay = service
+        .replay_run(&actor, "contact_created_http_e2e", run.run_id.as_str())
+        .await;
+    assert!(replay.is_ok());
+    let replay = replay.unwrap_or_else(|_| unreachable!());
+    assert_eq!(replay.run.run_id, run.run_id);
+    assert_eq!(replay.attempts.len(), 1);
+    assert_eq!(replay.timeline.len(), 1);
+    assert_eq!(replay.timeline[0].step_path, "0");
+    assert_eq!(replay.timeline[0].attempt_status.as_str(), "succeeded");
+}
+
+#[tokio::test]
+async fn queued_mode_claims_can_be_filtered_to_one_tenant() {
+    let left_tenant = TenantId::new();
+    let right_tenant = TenantId::new();
+    let left_actor = UserIdentity::new("left-maker", "left-maker", None, left_tenant);
+    let right_actor = UserIdentity::new("right-maker", "right-maker", None, right_tenant);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([
+            (
+                (left_tenant, "left-maker".to_owned()),
+                vec![Permission::WorkflowManage, Permission::WorkflowRead],
+            ),
+            (
+                (right_tenant, "right-maker".to_owned()),
+                vec![Permission::WorkflowManage, Permission::WorkflowRead],
+            ),
+        ]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+
+    let left_saved = service
+        .save_workflow(
+            &left_actor,
+            SaveWorkflowInput {
+                logical_name: "queued_contact_create".to_owned(),
+                display_name: "Queued Contact Create".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "queued".to_owned(),
+                }],
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(left_saved.is_ok());
+
+    let right_saved = service
+        .save_workflow(
+            &right_actor,
+            SaveWorkflowInput {
+                logical_name: "queued_contact_create".to_owned(),
+                display_name: "Queued Contact Create".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "queued".to_owned(),
+                }],
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(right_saved.is_ok());
+
+    let left_enqueued = service
+        .execute_workflow(
+            &left_actor,
+            "queued_contact_create",
+            json!({"source": "left"}),
+        )
+        .await;
+    assert!(left_enqueued.is_ok());
+
+    let right_enqueued = service
+        .execute_workflow(
+            &right_actor,
+            "queued_contact_create",
+            json!({"source": "right"}),
+        )
+        .await;
+    assert!(right_enqueued.is_ok());
+
+    let claimed_jobs = service
+        .claim_jobs_for_worker("worker-alpha", 10, 30, None, Some(left_tenant))
+        .await;
+    assert!(claimed_jobs.is_ok());
+    let claimed_jobs = claimed_jobs.unwrap_or_default();
+    assert_eq!(claimed_jobs.len(), 1);
+    assert_eq!(claimed_jobs[0].tenant_id, left_tenant);
+    assert_eq!(claimed_jobs[0].trigger_payload["source"], json!("left"));
+}
+
+#[tokio::test]
+async fn queued_mode_does_not_double_claim_same_job_while_lease_is_active() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+
+    let save_result = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "queued_contact_create_single_claim".to_owned(),
+                display_name: "Queued Contact Create Single Claim".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "queued".to_owned(),
+                }],
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(save_result.is_ok());
+
+    let enqueued_run = service
+        .execute_workflow(
+            &actor,
+            "queued_contact_create_single_claim",
+            json!({"source": "test"}),
+        )
+        .await;
+    assert!(enqueued_run.is_ok());
+
+    let first_claim = service
+        .claim_jobs_for_worker("worker-alpha", 10, 30, None, None)
+        .await;
+    assert!(first_claim.is_ok());
+    let first_claim = first_claim.unwrap_or_default();
+    assert_eq!(first_claim.len(), 1);
+
+    let second_claim = service
+        .claim_jobs_for_worker("worker-beta", 10, 30, None, None)
+        .await;
+    assert!(second_claim.is_ok());
+    let second_claim = second_claim.unwrap_or_default();
+    assert!(second_claim.is_empty());
+}
+
+#[tokio::test]
+async fn queued_mode_rejects_claimed_job_with_empty_lease_token() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+
+    let save_result = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "queued_contact_create_empty_token".to_owned(),
+                display_name: "Queued Contact Create Empty Token".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "queued".to_owned(),
+                }],
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(save_result.is_ok());
+
+    let enqueued_run = service
+        .execute_workflow(
+            &actor,
+            "queued_contact_create_empty_token",
+            json!({"source": "test"}),
+        )
+        .await;
+    assert!(enqueued_run.is_ok());
+
+    let claimed_jobs = service
+        .claim_jobs_for_worker("worker-alpha", 10, 30, None, None)
+        .await;
+    assert!(claimed_jobs.is_ok());
+    let mut claimed_jobs = claimed_jobs.unwrap_or_default();
+    assert_eq!(claimed_jobs.len(), 1);
+
+    let mut claimed_job = claimed_jobs.remove(0);
+    claimed_job.lease_token = String::new();
+
+    let completed = service
+        .execute_claimed_job("worker-alpha", claimed_job)
+        .await;
+    assert!(completed.is_err());
+}
+
+#[tokio::test]
+async fn queued_mode_rejects_claimed_job_with_stale_lease_token() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository.clone(),
+        runtime_service,
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+
+    let save_result = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "queued_contact_create_stale_token".to_owned(),
+                display_name: "Queued Contact Create Stale Token".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "queued".to_owned(),
+                }],
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(save_result.is_ok());
+
+    let enqueued_run = service
+        .execute_workflow(
+            &actor,
+            "queued_contact_create_stale_token",
+            json!({"source": "test"}),
+        )
+        .await;
+    assert!(enqueued_run.is_ok());
+
+    let claimed_jobs = service
+        .claim_jobs_for_worker("worker-alpha", 10, 30, None, None)
+        .await;
+    assert!(claimed_jobs.is_ok());
+    let mut claimed_jobs = claimed_jobs.unwrap_or_default();
+    assert_eq!(claimed_jobs.len(), 1);
+
+    let mut claimed_job = claimed_jobs.remove(0);
+    claimed_job.lease_token = "stale-lease-token".to_owned();
+
+    let completed = service
+        .execute_claimed_job("worker-alpha", claimed_job)
+        .await;
+    assert!(completed.is_err());
+
+    let jobs = repository.jobs.lock().await.clone();
+    assert_eq!(jobs.len(), 1);
+    assert!(!jobs[0].completed);
+    assert!(!jobs[0].failed);
+    assert_eq!(jobs[0].leased_by.as_deref(), Some("worker-alpha"));
+}
+
+#[tokio::test]
+async fn drain_runtime_record_workflow_events_dispatches_matching_workflows() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    runtime_service
+        .queued_events
+        .lock()
+        .await
+        .push(ClaimedRuntimeRecordWorkflowEvent {
+            event_id: "event-1".to_owned(),
+            tenant_id,
+            trigger: WorkflowTrigger::RuntimeRecordCreated {
+                entity_logical_name: "contact".to_owned(),
+            },
+            record_id: "record-1".to_owned(),
+            payload: json!({
+                "entity_logical_name": "contact",
+                "record_id": "record-1",
+                "id": "record-1",
+                "record": {"name": "Alice"},
+                "data": {"name": "Alice"},
+                "event": "created"
+            }),
+            emitted_by_subject: "maker".to_owned(),
+            lease_token: "lease-1".to_owned(),
+        });
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository.clone(),
+        runtime_service.clone(),
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    let saved = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "contact_created_log".to_owned(),
+                display_name: "Contact Created Log".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::RuntimeRecordCreated {
+                    entity_logical_name: "contact".to_owned(),
+                },
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "created".to_owned(),
+                }],
+                max_attempts: 1,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(saved.is_ok());
+
+    let result = service
+        .drain_runtime_record_workflow_events_for_worker("worker-alpha", 10, 30, Some(tenant_id))
+        .await;
+    assert!(result.is_ok());
+    let result = result.unwrap_or_else(|_| unreachable!());
+    assert_eq!(result.claimed_events, 1);
+    assert_eq!(result.dispatched_workflows, 1);
+    assert_eq!(result.released_events, 0);
+    assert_eq!(
+        runtime_service.completed_event_ids.lock().await.as_slice(),
+        ["event-1"]
+    );
+    assert!(runtime_service.released_event_ids.lock().await.is_empty());
+    assert_eq!(repository.runs.lock().await.len(), 1);
+}
+
+#[tokio::test]
+async fn execute_workflow_with_idempotency_key_returns_the_first_run() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository.clone(),
+        runtime_service,
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+
+    let saved = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "send_invoice".to_owned(),
+                display_name: "Send Invoice".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "sent".to_owned(),
+                }],
+                max_attempts: 1,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(saved.is_ok());
+
+    let first = service
+        .execute_workflow_with_idempotency_key(
+            &actor,
+            "send_invoice",
+            json!({"invoice": 42}),
+            Some("invoice-42"),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let retried = service
+        .execute_workflow_with_idempotency_key(
+            &actor,
+            "send_invoice",
+            json!({"invoice": 42}),
+            Some("invoice-42"),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(retried.run_id, first.run_id);
+    assert_eq!(repository.runs.lock().await.len(), 1);
+    assert_eq!(repository.jobs.lock().await.len(), 1);
+
+    let other = service
+        .execute_workflow_with_idempotency_key(
+            &actor,
+            "send_invoice",
+            json!({"invoice": 43}),
+            Some("invoice-43"),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_ne!(other.run_id, first.run_id);
+    assert_eq!(repository.jobs.lock().await.len(), 2);
+
+    let invalid = service
+        .execute_workflow_with_idempotency_key(
+            &actor,
+            "send_invoice",
+            json!({}),
+            Some("invoice 44"),
+        )
+        .await;
+    assert!(matches!(invalid, Err(AppError::Validation(_))));
+    assert_eq!(repository.runs.lock().await.len(), 2);
+}
+
+#[tokio::test]
+async fn execute_workflow_with_idempotency_key_enqueues_runs_whose_first_enqueue_failed() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository.clone(),
+        runtime_service,
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+
+    let saved = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "send_invoice".to_owned(),
+                display_name: "Send Invoice".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "sent".to_owned(),
+                }],
+                max_attempts: 1,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(saved.is_ok());
+
+    *repository.fail_enqueue_run_job_remaining.lock().await = 1;
+    let failed = service
+        .execute_workflow_with_idempotency_key(
+            &actor,
+            "send_invoice",
+            json!({"invoice": 42}),
+            Some("invoice-42"),
+        )
+        .await;
+    assert!(matches!(failed, Err(AppError::Internal(_))));
+    assert_eq!(repository.runs.lock().await.len(), 1);
+    assert!(repository.jobs.lock().await.is_empty());
+
+    let retried = service
+        .execute_workflow_with_idempotency_key(
+            &actor,
+            "send_invoice",
+            json!({"invoice": 42}),
+            Some("invoice-42"),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(repository.runs.lock().await.len(), 1);
+    let jobs = repository.jobs.lock().await.clone();
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].run_id, retried.run_id);
+
+    let replayed = service
+        .execute_workflow_with_idempotency_key(
+            &actor,
+            "send_invoice",
+            json!({"invoice": 42}),
+            Some("invoice-42"),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(replayed.run_id, retried.run_id);
+    assert_eq!(repository.jobs.lock().await.len(), 1);
+}
+
+#[tokio::test]
+async fn redelivered_runtime_record_workflow_events_do_not_duplicate_runs() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository.clone(),
+        runtime_service.clone(),
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+
+    let saved = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "contact_created_sync".to_owned(),
+                display_name: "Contact Created Sync".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::RuntimeRecordCreated {
+                    entity_logical_name: "contact".to_owned(),
+                },
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "created".to_owned(),
+                }],
+                max_attempts: 1,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(saved.is_ok());
+
+    for lease_token in ["lease-1", "lease-2"] {
+        runtime_service
+            .queued_events
+            .lock()
+            .await
+            .push(ClaimedRuntimeRecordWorkflowEvent {
+                event_id: "event-1".to_owned(),
+                tenant_id,
+                trigger: WorkflowTrigger::RuntimeRecordCreated {
+                    entity_logical_name: "contact".to_owned(),
+                },
+                record_id: "record-1".to_owned(),
+                payload: json!({
+                    "entity_logical_name": "contact",
+                    "record_id": "record-1",
+                    "record": {"name": "Alice"},
+                    "event": "created"
+                }),
+                emitted_by_subject: "maker".to_owned(),
+                lease_token: lease_token.to_owned(),
+            });
+        let result = service
+            .drain_runtime_record_workflow_events_for_worker(
+                "worker-alpha",
+                10,
+                30,
+                Some(tenant_id),
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(result.claimed_events, 1);
+        assert_eq!(result.dispatched_workflows, 1);
+    }
+
+    assert_eq!(repository.runs.lock().await.len(), 1);
+    assert_eq!(repository.jobs.lock().await.len(), 1);
+}
+#[tokio::test]
+async fn drain_runtime_record_workflow_events_completes_after_workflow_dead_letters() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    runtime_service
+        .queued_events
+        .lock()
+        .await
+        .push(ClaimedRuntimeRecordWorkflowEvent {
+            event_id: "event-2".to_owned(),
+            tenant_id,
+            trigger: WorkflowTrigger::RuntimeRecordCreated {
+                entity_logical_name: "contact".to_owned(),
+            },
+            record_id: "record-2".to_owned(),
+            payload: json!({
+                "entity_logical_name": "contact",
+                "record_id": "record-2",
+                "id": "record-2",
+                "record": {"name": "Alice"},
+                "data": {"name": "Alice"},
+                "event": "created"
+            }),
+            emitted_by_subject: "maker".to_owned(),
+            lease_token: "lease-2".to_owned(),
+        });
+    *runtime_service.failures_remaining.lock().await = 1;
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service.clone(),
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    let saved = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "contact_created_create".to_owned(),
+                display_name: "Contact Created Create".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::RuntimeRecordCreated {
+                    entity_logical_name: "contact".to_owned(),
+                },
+                steps: vec![WorkflowStep::CreateRuntimeRecord {
+                    entity_logical_name: "contact".to_owned(),
+                    data: json!({"name": "Follow Up"}),
+                    output_key: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 1,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(saved.is_ok());
+
+    let result = service
+        .drain_runtime_record_workflow_events_for_worker("worker-alpha", 10, 30, Some(tenant_id))
+        .await;
+    assert!(result.is_ok());
+    let result = result.unwrap_or_else(|_| unreachable!());
+    assert_eq!(result.claimed_events, 1);
+    assert_eq!(result.dispatched_workflows, 1);
+    assert_eq!(result.released_events, 0);
+    assert_eq!(
+        runtime_service.completed_event_ids.lock().await.as_slice(),
+        ["event-2"]
+    );
+    assert!(runtime_service.released_event_ids.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn drain_runtime_record_workflow_events_releases_then_retries_transient_dispatch_failures() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    runtime_service
+        .queued_events
+        .lock()
+        .await
+        .push(ClaimedRuntimeRecordWorkflowEvent {
+            event_id: "event-retry-1".to_owned(),
+            tenant_id,
+            trigger: WorkflowTrigger::RuntimeRecordCreated {
+                entity_logical_name: "contact".to_owned(),
+            },
+            record_id: "record-retry-1".to_owned(),
+            payload: json!({
+                "entity_logical_name": "contact",
+                "record_id": "record-retry-1",
+                "id": "record-retry-1",
+                "record": {"name": "Alice"},
+                "data": {"name": "Alice"},
+                "event": "created"
+            }),
+            emitted_by_subject: "maker".to_owned(),
+            lease_token: "lease-retry-1".to_owned(),
+        });
+    *repository
+        .fail_list_enabled_workflows_remaining
+        .lock()
+        .await = 1;
+
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository.clone(),
+        runtime_service.clone(),
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    let saved = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "contact_created_retry_log".to_owned(),
+                display_name: "Contact Created Retry Log".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::RuntimeRecordCreated {
+                    entity_logical_name: "contact".to_owned(),
+                },
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "created".to_owned(),
+                }],
+                max_attempts: 1,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(saved.is_ok());
+
+    let first = service
+        .drain_runtime_record_workflow_events_for_worker("worker-alpha", 10, 30, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(first.claimed_events, 1);
+    assert_eq!(first.dispatched_workflows, 0);
+    assert_eq!(first.released_events, 1);
+    assert!(runtime_service.completed_event_ids.lock().await.is_empty());
+    assert_eq!(
+        runtime_service.released_event_ids.lock().await.as_slice(),
+        ["event-retry-1"]
+    );
+    assert_eq!(repository.runs.lock().await.len(), 0);
+    assert_eq!(runtime_service.queued_events.lock().await.len(), 1);
+
+    let second = service
+        .drain_runtime_record_workflow_events_for_worker("worker-alpha", 10, 30, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(second.claimed_events, 1);
+    assert_eq!(second.dispatched_workflows, 1);
+    assert_eq!(second.released_events, 0);
+    assert_eq!(
+        runtime_service.completed_event_ids.lock().await.as_slice(),
+        ["event-retry-1"]
+    );
+    assert_eq!(
+        runtime_service.released_event_ids.lock().await.as_slice(),
+        ["event-retry-1"]
+    );
+    assert_eq!(repository.runs.lock().await.len(), 1);
+    assert!(runtime_service.queued_events.lock().await.is_empty());
+}
+
+#[test]
+fn workflow_claim_partition_rejects_invalid_index() {
+    let partition = WorkflowClaimPartition::new(4, 4);
+    assert!(partition.is_err());
+}
+
+#[test]
+fn workflow_claim_partition_accepts_valid_values() {
+    let partition = WorkflowClaimPartition::new(8, 3);
+    assert!(partition.is_ok());
+    let partition = partition.unwrap_or_else(|_| unreachable!());
+    assert_eq!(partition.partition_count(), 8);
+    assert_eq!(partition.partition_index(), 3);
+}
+
+#[test]
+fn workflow_claim_backpressure_policy_grants_full_limit_under_normal_load() {
+    let policy = WorkflowClaimBackpressurePolicy::new(20, Some(5), 4_000);
+    let signal = WorkflowClaimLoadSignal {
+        database_connections_in_use: 2,
+        database_max_connections: 10,
+    };
+
+    let advice = policy.advise(signal, false);
+    assert_eq!(advice.advised_limit, 20);
+    assert_eq!(advice.retry_after_ms, 0);
+
+    let tenant_advice = policy.advise(signal, true);
+    assert_eq!(tenant_advice.advised_limit, 5);
+    assert_eq!(policy.effective_limit(50, tenant_advice), 5);
+    assert_eq!(policy.effective_limit(0, tenant_advice), 1);
+}
+
+#[test]
+fn workflow_claim_backpressure_policy_shrinks_limits_as_pool_saturates() {
+    let policy = WorkflowClaimBackpressurePolicy::new(20, None, 4_000);
+
+    let elevated = policy.advise(
+        WorkflowClaimLoadSignal {
+            database_connections_in_use: 8,
+            database_max_connections: 10,
+        },
+        false,
+    );
+    assert_eq!(elevated.advised_limit, 10);
+    assert_eq!(elevated.retry_after_ms, 2_000);
+
+    let saturated = policy.advise(
+        WorkflowClaimLoadSignal {
+            database_connections_in_use: 10,
+            database_max_connections: 10,
+        },
+        true,
+    );
+    assert_eq!(saturated.advised_limit, 1);
+    assert_eq!(saturated.retry_after_ms, 4_000);
+}
+
+#[tokio::test]
+async fn queued_mode_supports_worker_heartbeat_and_queue_stats() {
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::new(),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+
+    let heartbeat = service
+        .heartbeat_worker(
+            "worker-alpha",
+            WorkflowWorkerHeartbeatInput {
+                claimed_jobs: 2,
+                executed_jobs: 2,
+                failed_jobs: 0,
+                partition: None,
+            },
+        )
+        .await;
+    assert!(heartbeat.is_ok());
+
+    let stats = service.queue_stats(120).await;
+    assert!(stats.is_ok());
+    let stats = stats.unwrap_or_else(|_| unreachable!());
+    assert_eq!(stats.pending_jobs, 0);
+    assert_eq!(stats.active_workers, 0);
+}
+
+#[tokio::test]
+async fn tenant_queue_stats_are_cached_and_invalidated_by_queue_mutations() {
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let other_actor = UserIdentity::new("maker", "maker", None, other_tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let cache = Arc::new(FakeQueueStatsCache::default());
+    let service = build_service(
+        HashMap::from([
+            (
+                (tenant_id, "maker".to_owned()),
+                vec![Permission::WorkflowManage, Permission::WorkflowRead],
+            ),
+            (
+                (other_tenant_id, "maker".to_owned()),
+                vec![Permission::WorkflowManage, Permission::WorkflowRead],
+            ),
+        ]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Queued,
+        None,
+    )
+    .with_queue_stats_cache(cache.clone(), 30);
+    assert_eq!(service.queue_stats_cache_ttl_seconds(), 30);
+
+    for current_actor in [&actor, &other_actor] {
+        let saved = service
+            .save_workflow(
+                current_actor,
+                SaveWorkflowInput {
+                    logical_name: "queued_stats".to_owned(),
+                    display_name: "Queued Stats".to_owned(),
+                    description: None,
+                    trigger: WorkflowTrigger::Manual,
+                    steps: vec![WorkflowStep::LogMessage {
+                        message: "queued".to_owned(),
+                    }],
+                    max_attempts: 1,
+                    is_enabled: true,
+                    trigger_filters: Vec::new(),
+                    trigger_changed_fields: Vec::new(),
+                    trigger_view_logical_name: None,
+                    priority: WorkflowRunPriority::Normal,
+                },
+            )
+            .await;
+        assert!(saved.is_ok());
+    }
+
+    let enqueued = service
+        .execute_workflow(&other_actor, "queued_stats", json!({}))
+        .await;
+    assert!(enqueued.is_ok());
+
+    let first = service
+        .tenant_queue_stats(&actor, 120)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(!first.served_from_cache);
+    assert_eq!(first.stats.pending_jobs, 0);
+
+    let cached = service
+        .tenant_queue_stats(&actor, 120)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(cached.served_from_cache);
+    assert_eq!(cached.computed_at, first.computed_at);
+
+    let enqueued = service
+        .execute_workflow(&actor, "queued_stats", json!({}))
+        .await;
+    assert!(enqueued.is_ok());
+    assert_eq!(*cache.invalidations.lock().await, 2);
+
+    let refreshed = service
+        .tenant_queue_stats(&actor, 120)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(!refreshed.served_from_cache);
+    assert_eq!(refreshed.stats.pending_jobs, 1);
+
+    let mut claimed = service
+        .claim_jobs_for_worker("worker-alpha", 10, 30, None, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(claimed.len(), 1);
+    let executed = service
+        .execute_claimed_job("worker-alpha", claimed.remove(0))
+        .await;
+    assert!(executed.is_ok());
+    assert_eq!(*cache.invalidations.lock().await, 3);
+
+    let completed = service
+        .tenant_queue_stats(&actor, 120)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(!completed.served_from_cache);
+    assert_eq!(completed.stats.pending_jobs, 0);
+    assert_eq!(completed.stats.completed_jobs, 1);
+}
+
+#[tokio::test]
+async fn tenant_queue_stats_require_workflow_read() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("viewer", "viewer", None, tenant_id);
+    let service = build_service(
+        HashMap::new(),
+        Arc::new(FakeWorkflowRepository::default()),
+        Arc::new(FakeRuntimeRecordService::default()),
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+
+    let result = service.tenant_queue_stats(&actor, 120).await;
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn draft_save_does_not_dispatch_until_workflow_is_published() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "lead_capture".to_owned(),
+                display_name: "Lead Capture".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::WebhookReceived {
+                    webhook_key: "leads".to_owned(),
+                },
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "captured".to_owned(),
+                }],
+                max_attempts: 2,
+                is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let before_publish = service
+        .dispatch_webhook_received(tenant_id, "leads", json!({"lead_id": "lead-1"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(before_publish, 0);
+
+    service
+        .publish_workflow(&actor, "lead_capture")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let after_publish = service
+        .dispatch_webhook_received(tenant_id, "leads", json!({"lead_id": "lead-2"}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(after_publish, 1);
+}
+
+#[tokio::test]
+async fn metadata_permissions_do_not_grant_workflow_access() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![
+                Permission::MetadataFieldWrite,
+                Permission::MetadataFieldRead,
+            ],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    let save_result = service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "blocked_workflow".to_owned(),
+                display_name: "Blocked Workflow".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "blocked".to_owned(),
+                }],
+                max_attempts: 1,
+                is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await;
+    assert!(
+        matches!(save_result, Err(AppError::Forbidden(message)) if message.contains("workflow.manage"))
+    );
+
+    let list_result = service.list_workflows(&actor).await;
+    assert!(
+        matches!(list_result, Err(AppError::Forbidden(message)) if message.contains("workflow.read"))
+    );
+}
+
+#[tokio::test]
+async fn workflow_permissions_allow_access_without_metadata_permissions() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "granted_workflow".to_owned(),
+                display_name: "Granted Workflow".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "allowed".to_owned(),
+                }],
+                max_attempts: 1,
+                is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let workflows = service
+        .list_workflows(&actor)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(workflows.len(), 1);
+    assert_eq!(workflows[0].logical_name().as_str(), "granted_workflow");
+}
+
+#[tokio::test]
+async fn workflow_publish_checks_report_unpublished_entity_dependencies() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
@@ -3750,91 +6563,917 @@ async fn workflow_publish_checks_report_unpublished_entity_dependencies() {
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
         repository,
-        runtime_service,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "contact_flow".to_owned(),
+                display_name: "Contact Flow".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::RuntimeRecordCreated {
+                    entity_logical_name: "contact".to_owned(),
+                },
+                steps: vec![WorkflowStep::AssignOwner {
+                    entity_logical_name: "contact".to_owned(),
+                    record_id: "{{trigger.record_id}}".to_owned(),
+                    owner_id: "owner-1".to_owned(),
+                    reason: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 1,
+                is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let errors = service
+        .publish_checks(&actor, "contact_flow")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("workflow 'contact_flow' -> entity 'contact'"));
+}
+
+#[tokio::test]
+async fn workflow_publish_checks_allow_selected_unpublished_entities() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "account_followup".to_owned(),
+                display_name: "Account Followup".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::CreateRuntimeRecord {
+                    entity_logical_name: "account".to_owned(),
+                    data: json!({"name": "Acme"}),
+                    output_key: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 1,
+                is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let errors = service
+        .publish_checks_with_allowed_unpublished_entities(
+            &actor,
+            "account_followup",
+            &["account".to_owned()],
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(errors.is_empty());
+}
+
+#[tokio::test]
+async fn workflow_publish_checks_report_inline_credential_headers() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "external_sync".to_owned(),
+                display_name: "External Sync".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::HttpRequest {
+                    method: "POST".to_owned(),
+                    url: "https://example.com/sync".to_owned(),
+                    headers: Some(json!({
+                        "authorization": "Bearer secret-value",
+                        "content-type": "application/json",
+                    })),
+                    credential: None,
+                    header_secret_refs: None,
+                    body: None,
+                    expected_status: None,
+                    retry: None,
+                    output_key: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 1,
+                is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let errors = service
+        .publish_checks(&actor, "external_sync")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("disallowed inline credential header 'authorization'"));
+}
+
+#[tokio::test]
+async fn workflow_publish_rejects_inline_credential_headers() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "blocked_publish".to_owned(),
+                display_name: "Blocked Publish".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::Webhook {
+                    endpoint: "https://example.com/webhook".to_owned(),
+                    event: "lead.created".to_owned(),
+                    headers: Some(json!({
+                        "x-api-key": "top-secret"
+                    })),
+                    credential: None,
+                    header_secret_refs: None,
+                    payload: json!({"lead_id": "lead-1"}),
+                    retry_policy: None,
+                }],
+                max_attempts: 1,
+                is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let publish_result = service.publish_workflow(&actor, "blocked_publish").await;
+    assert!(
+        matches!(publish_result, Err(AppError::Validation(message)) if message.contains("disallowed inline credential header 'x-api-key'"))
+    );
+}
+
+#[tokio::test]
+async fn workflow_publish_allows_sensitive_headers_when_backed_by_secret_refs() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "secret_backed_publish".to_owned(),
+                display_name: "Secret Backed Publish".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::Webhook {
+                    endpoint: "https://example.com/webhook".to_owned(),
+                    event: "lead.created".to_owned(),
+                    headers: Some(json!({
+                        "content-type": "application/json"
+                    })),
+                    credential: None,
+                    header_secret_refs: Some(json!({
+                        "authorization": "op://vault/item/password"
+                    })),
+                    payload: json!({"lead_id": "lead-1"}),
+                    retry_policy: None,
+                }],
+                max_attempts: 1,
+                is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let errors = service
+        .publish_checks(&actor, "secret_backed_publish")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(errors.is_empty());
+}
+
+#[tokio::test]
+async fn workflow_publish_step_up_detection_tracks_outbound_drafts() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "ops_notify".to_owned(),
+                display_name: "Ops Notify".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::SendEmail {
+                    to: "ops@example.com".to_owned(),
+                    subject: "Alert".to_owned(),
+                    body: "Check workflow".to_owned(),
+                    html_body: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 1,
+                is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    assert!(
+        service
+            .publish_requires_recent_step_up(&actor, "ops_notify")
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+}
+
+#[tokio::test]
+async fn workflow_disable_step_up_detection_tracks_active_outbound_versions() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    );
+
+    service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "ops_webhook".to_owned(),
+                display_name: "Ops Webhook".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::Webhook {
+                    endpoint: "https://example.com/notify".to_owned(),
+                    event: "incident.created".to_owned(),
+                    headers: None,
+                    credential: None,
+                    header_secret_refs: None,
+                    payload: json!({"severity": "high"}),
+                    retry_policy: None,
+                }],
+                max_attempts: 1,
+                is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    service
+        .publish_workflow(&actor, "ops_webhook")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    assert!(
+        service
+            .disable_requires_recent_step_up(&actor, "ops_webhook")
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+}
+
+#[tokio::test]
+async fn draft_changes_do_not_replace_current_published_workflow_until_publish() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
+
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        runtime_service,
+        WorkflowExecutionMode::Inline,
+        None,
+    )
+    .with_action_dispatcher(action_dispatcher.clone());
+
+    service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "versioned_email".to_owned(),
+                display_name: "Versioned Email".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::SendEmail {
+                    to: "ops@example.com".to_owned(),
+                    subject: "v1".to_owned(),
+                    body: "first".to_owned(),
+                    html_body: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "versioned_email".to_owned(),
+                display_name: "Versioned Email".to_owned(),
+                description: Some("draft update".to_owned()),
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::SendEmail {
+                    to: "ops@example.com".to_owned(),
+                    subject: "v2".to_owned(),
+                    body: "second".to_owned(),
+                    html_body: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 2,
+                is_enabled: false,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    service
+        .execute_workflow(&actor, "versioned_email", json!({}))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
+    assert_eq!(dispatched.len(), 1);
+    assert_eq!(dispatched[0].payload["subject"], json!("v1"));
+}
+
+#[tokio::test]
+async fn send_email_steps_skip_contacts_without_marketing_consent() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        Arc::new(FakeWorkflowRepository::default()),
+        Arc::new(FakeRuntimeRecordService::default()),
+        WorkflowExecutionMode::Inline,
+        Some(action_dispatcher.clone()),
+    )
+    .with_contact_consent_repository(Arc::new(FakeContactConsentRepository {
+        email_consents: HashMap::from([
+            ("opted-in@example.com".to_owned(), true),
+            ("opted-out@example.com".to_owned(), false),
+        ]),
+    }));
+
+    service
+        .save_workflow(
+            &actor,
+            SaveWorkflowInput {
+                logical_name: "newsletter".to_owned(),
+                display_name: "Newsletter".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::SendEmail {
+                    to: "{{trigger.payload.email}}".to_owned(),
+                    subject: "News".to_owned(),
+                    body: "Monthly update".to_owned(),
+                    html_body: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 1,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    for (email, expected_status) in [
+        ("opted-in@example.com", WorkflowRunStatus::Succeeded),
+        ("ops@example.com", WorkflowRunStatus::Succeeded),
+        ("opted-out@example.com", WorkflowRunStatus::DeadLettered),
+    ] {
+        let run = service
+            .execute_workflow(&actor, "newsletter", json!({ "email": email }))
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(run.status, expected_status, "unexpected status for {email}");
+    }
+
+    let recipients: Vec<String> = action_dispatcher
+        .dispatched_requests
+        .lock()
+        .await
+        .iter()
+        .map(|request| {
+            request.payload["to"]
+                .as_str()
+                .unwrap_or_default()
+                .to_owned()
+        })
+        .collect();
+    assert_eq!(
+        recipients,
+        vec![
+            "opted-in@example.com".to_owned(),
+            "ops@example.com".to_owned()
+        ]
+    );
+}
+
+#[derive(Default)]
+struct FakeInboundWebhookRepository {
+    webhooks: Mutex<HashMap<(TenantId, String), (String, WorkflowInboundWebhookCredentials)>>,
+}
+
+#[async_trait]
+impl WorkflowInboundWebhookRepository for FakeInboundWebhookRepository {
+    async fn save_inbound_webhook(
+        &self,
+        tenant_id: TenantId,
+        webhook: NewWorkflowInboundWebhook,
+    ) -> AppResult<WorkflowInboundWebhook> {
+        let stored = WorkflowInboundWebhook {
+            workflow_logical_name: webhook.workflow_logical_name.clone(),
+            payload_schema: webhook.payload_schema,
+            created_by_subject: webhook.created_by_subject,
+            created_at: Utc::now(),
+            delivery_count: 0,
+            last_received_at: None,
+        };
+        self.webhooks.lock().await.insert(
+            (tenant_id, webhook.workflow_logical_name),
+            (
+                webhook.token_hash,
+                WorkflowInboundWebhookCredentials {
+                    webhook: stored.clone(),
+                    signing_secret_ciphertext: webhook.signing_secret_ciphertext,
+                },
+            ),
+        );
+        Ok(stored)
+    }
+
+    async fn find_inbound_webhook(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+    ) -> AppResult<Option<WorkflowInboundWebhook>> {
+        Ok(self
+            .webhooks
+            .lock()
+            .await
+            .get(&(tenant_id, workflow_logical_name.to_owned()))
+            .map(|(_, credentials)| credentials.webhook.clone()))
+    }
+
+    async fn find_inbound_webhook_by_token_hash(
+        &self,
+        tenant_id: TenantId,
+        token_hash: &str,
+    ) -> AppResult<Option<WorkflowInboundWebhookCredentials>> {
+        Ok(self
+            .webhooks
+            .lock()
+            .await
+            .iter()
+            .find(|((webhook_tenant_id, _), (stored_hash, _))| {
+                *webhook_tenant_id == tenant_id && stored_hash == token_hash
+            })
+            .map(|(_, (_, credentials))| credentials.clone()))
+    }
+
+    async fn delete_inbound_webhook(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(self
+            .webhooks
+            .lock()
+            .await
+            .remove(&(tenant_id, workflow_logical_name.to_owned()))
+            .is_some())
+    }
+
+    async fn record_inbound_webhook_delivery(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+        received_at: chrono::DateTime<Utc>,
+    ) -> AppResult<()> {
+        if let Some((_, credentials)) = self
+            .webhooks
+            .lock()
+            .await
+            .get_mut(&(tenant_id, workflow_logical_name.to_owned()))
+        {
+            credentials.webhook.delivery_count += 1;
+            credentials.webhook.last_received_at = Some(received_at);
+        }
+        Ok(())
+    }
+}
+
+struct ReversingSecretEncryptor;
+
+impl SecretEncryptor for ReversingSecretEncryptor {
+    fn encrypt(&self, plaintext: &[u8]) -> AppResult<Vec<u8>> {
+        Ok(plaintext.iter().rev().copied().collect())
+    }
+
+    fn decrypt(&self, ciphertext: &[u8]) -> AppResult<Vec<u8>> {
+        Ok(ciphertext.iter().rev().copied().collect())
+    }
+}
+
+fn sign_inbound_webhook(signing_secret: &str, timestamp: i64, body: &[u8]) -> String {
+    use hmac::{Hmac, Mac};
+
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(signing_secret.as_bytes())
+        .unwrap_or_else(|_| unreachable!());
+    mac.update(format!("{timestamp}.").as_bytes());
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+fn signed_delivery(
+    signing_secret: &str,
+    timestamp: i64,
+    body: Value,
+) -> WorkflowInboundWebhookDelivery {
+    let body = body.to_string().into_bytes();
+    WorkflowInboundWebhookDelivery {
+        timestamp: Some(timestamp.to_string()),
+        signature: Some(sign_inbound_webhook(signing_secret, timestamp, &body)),
+        body,
+        request: json!({"method": "POST"}),
+    }
+}
+
+async fn inbound_webhook_service(
+    tenant_id: TenantId,
+    repository: Arc<FakeWorkflowRepository>,
+    webhook_repository: Arc<FakeInboundWebhookRepository>,
+) -> WorkflowService {
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let service = build_service(
+        HashMap::from([(
+            (tenant_id, "maker".to_owned()),
+            vec![Permission::WorkflowManage, Permission::WorkflowRead],
+        )]),
+        repository,
+        Arc::new(FakeRuntimeRecordService::default()),
         WorkflowExecutionMode::Inline,
         None,
-    );
+    )
+    .with_inbound_webhooks(webhook_repository, Arc::new(ReversingSecretEncryptor));
 
     service
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "contact_flow".to_owned(),
-                display_name: "Contact Flow".to_owned(),
+                logical_name: "order_intake".to_owned(),
+                display_name: "Order Intake".to_owned(),
                 description: None,
-                trigger: WorkflowTrigger::RuntimeRecordCreated {
-                    entity_logical_name: "contact".to_owned(),
-                },
-                steps: vec![WorkflowStep::AssignOwner {
-                    entity_logical_name: "contact".to_owned(),
-                    record_id: "{{trigger.record_id}}".to_owned(),
-                    owner_id: "owner-1".to_owned(),
-                    reason: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "order received".to_owned(),
                 }],
                 max_attempts: 1,
-                is_enabled: false,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await
         .unwrap_or_else(|_| unreachable!());
 
-    let errors = service
-        .publish_checks(&actor, "contact_flow")
-        .await
-        .unwrap_or_else(|_| unreachable!());
-    assert_eq!(errors.len(), 1);
-    assert!(errors[0].contains("workflow 'contact_flow' -> entity 'contact'"));
+    service
 }
 
 #[tokio::test]
-async fn workflow_publish_checks_allow_selected_unpublished_entities() {
+async fn receive_inbound_webhook_runs_workflow_for_signed_valid_payload() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
-    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
-    let service = build_service(
-        HashMap::from([(
-            (tenant_id, "maker".to_owned()),
-            vec![Permission::WorkflowManage, Permission::WorkflowRead],
-        )]),
-        repository,
-        runtime_service,
-        WorkflowExecutionMode::Inline,
-        None,
-    );
+    let webhook_repository = Arc::new(FakeInboundWebhookRepository::default());
+    let service =
+        inbound_webhook_service(tenant_id, repository.clone(), webhook_repository.clone()).await;
 
-    service
-        .save_workflow(
+    let created = service
+        .configure_inbound_webhook(
             &actor,
-            SaveWorkflowInput {
-                logical_name: "account_followup".to_owned(),
-                display_name: "Account Followup".to_owned(),
-                description: None,
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::CreateRuntimeRecord {
-                    entity_logical_name: "account".to_owned(),
-                    data: json!({"name": "Acme"}),
-                }],
-                max_attempts: 1,
-                is_enabled: false,
-            },
+            "order_intake",
+            Some(json!({
+                "type": "object",
+                "required": ["order_id"],
+                "properties": {"order_id": {"type": "string"}}
+            })),
         )
         .await
         .unwrap_or_else(|_| unreachable!());
+    let stored_secret = webhook_repository
+        .webhooks
+        .lock()
+        .await
+        .values()
+        .map(|(_, credentials)| credentials.signing_secret_ciphertext.clone())
+        .next()
+        .unwrap_or_default();
+    assert_ne!(stored_secret, created.signing_secret.as_bytes());
 
-    let errors = service
-        .publish_checks_with_allowed_unpublished_entities(
+    let run = service
+        .receive_inbound_webhook(
+            tenant_id,
+            created.token.as_str(),
+            signed_delivery(
+                created.signing_secret.as_str(),
+                Utc::now().timestamp(),
+                json!({"order_id": "ORD-7"}),
+            ),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
+    assert_eq!(run.trigger_payload["event"], json!("inbound_webhook"));
+    assert_eq!(run.trigger_payload["payload"]["order_id"], json!("ORD-7"));
+    assert_eq!(run.trigger_payload["request"]["method"], json!("POST"));
+
+    let webhook = service
+        .get_inbound_webhook(&actor, "order_intake")
+        .await
+        .unwrap_or_default()
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(webhook.delivery_count, 1);
+    assert!(webhook.last_received_at.is_some());
+}
+
+#[tokio::test]
+async fn receive_inbound_webhook_rejects_bad_signatures_stale_timestamps_and_invalid_payloads() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let repository = Arc::new(FakeWorkflowRepository::default());
+    let service = inbound_webhook_service(
+        tenant_id,
+        repository.clone(),
+        Arc::new(FakeInboundWebhookRepository::default()),
+    )
+    .await;
+    let created = service
+        .configure_inbound_webhook(
             &actor,
-            "account_followup",
-            &["account".to_owned()],
+            "order_intake",
+            Some(json!({
+                "type": "object",
+                "required": ["order_id"],
+                "additionalProperties": false,
+                "properties": {"order_id": {"type": "string"}}
+            })),
         )
         .await
         .unwrap_or_else(|_| unreachable!());
-    assert!(errors.is_empty());
+    let now = Utc::now().timestamp();
+
+    let wrong_secret = service
+        .receive_inbound_webhook(
+            tenant_id,
+            created.token.as_str(),
+            signed_delivery("not-the-secret", now, json!({"order_id": "ORD-1"})),
+        )
+        .await;
+    assert!(matches!(wrong_secret, Err(AppError::Unauthorized(_))));
+
+    let mut tampered = signed_delivery(
+        created.signing_secret.as_str(),
+        now,
+        json!({"order_id": "ORD-1"}),
+    );
+    tampered.body = json!({"order_id": "ORD-2"}).to_string().into_bytes();
+    let tampered = service
+        .receive_inbound_webhook(tenant_id, created.token.as_str(), tampered)
+        .await;
+    assert!(matches!(tampered, Err(AppError::Unauthorized(_))));
+
+    let stale = service
+        .receive_inbound_webhook(
+            tenant_id,
+            created.token.as_str(),
+            signed_delivery(
+                created.signing_secret.as_str(),
+                now - 600,
+                json!({"order_id": "ORD-1"}),
+            ),
+        )
+        .await;
+    assert!(matches!(stale, Err(AppError::Unauthorized(_))));
+
+    let invalid_payload = service
+        .receive_inbound_webhook(
+            tenant_id,
+            created.token.as_str(),
+            signed_delivery(
+                created.signing_secret.as_str(),
+                now,
+                json!({"order_id": 7, "extra": true}),
+            ),
+        )
+        .await;
+    assert!(matches!(invalid_payload, Err(AppError::Validation(_))));
+
+    let other_tenant = service
+        .receive_inbound_webhook(
+            TenantId::new(),
+            created.token.as_str(),
+            signed_delivery(
+                created.signing_secret.as_str(),
+                now,
+                json!({"order_id": "ORD-1"}),
+            ),
+        )
+        .await;
+    assert!(matches!(other_tenant, Err(AppError::NotFound(_))));
+
+    assert!(repository.runs.lock().await.is_empty());
 }
 
 #[tokio::test]
-async fn workflow_publish_checks_report_inline_credential_headers() {
+async fn configure_inbound_webhook_rotates_credentials_and_rejects_unsupported_schemas() {
+    let tenant_id = TenantId::new();
+    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let service = inbound_webhook_service(
+        tenant_id,
+        Arc::new(FakeWorkflowRepository::default()),
+        Arc::new(FakeInboundWebhookRepository::default()),
+    )
+    .await;
+
+    let unsupported = service
+        .configure_inbound_webhook(&actor, "order_intake", Some(json!({"oneOf": []})))
+        .await;
+    assert!(matches!(unsupported, Err(AppError::Validation(_))));
+
+    let missing_workflow = service
+        .configure_inbound_webhook(&actor, "missing", None)
+        .await;
+    assert!(matches!(missing_workflow, Err(AppError::NotFound(_))));
+
+    let first = service
+        .configure_inbound_webhook(&actor, "order_intake", None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let second = service
+        .configure_inbound_webhook(&actor, "order_intake", None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_ne!(first.token, second.token);
+
+    let now = Utc::now().timestamp();
+    let rotated_out = service
+        .receive_inbound_webhook(
+            tenant_id,
+            first.token.as_str(),
+            signed_delivery(first.signing_secret.as_str(), now, json!({})),
+        )
+        .await;
+    assert!(matches!(rotated_out, Err(AppError::NotFound(_))));
+
+    assert!(
+        service
+            .revoke_inbound_webhook(&actor, "order_intake")
+            .await
+            .is_ok()
+    );
+    let revoked = service
+        .receive_inbound_webhook(
+            tenant_id,
+            second.token.as_str(),
+            signed_delivery(second.signing_secret.as_str(), now, json!({})),
+        )
+        .await;
+    assert!(matches!(revoked, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn bulk_execution_enqueues_selected_records_and_reports_completion() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
@@ -3844,9 +7483,9 @@ async fn workflow_publish_checks_report_inline_credential_headers() {
             (tenant_id, "maker".to_owned()),
             vec![Permission::WorkflowManage, Permission::WorkflowRead],
         )]),
-        repository,
+        repository.clone(),
         runtime_service,
-        WorkflowExecutionMode::Inline,
+        WorkflowExecutionMode::Queued,
         None,
     );
 
@@ -3854,49 +7493,208 @@ async fn workflow_publish_checks_report_inline_credential_headers() {
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "external_sync".to_owned(),
-                display_name: "External Sync".to_owned(),
+                logical_name: "contact_refresh".to_owned(),
+                display_name: "Contact Refresh".to_owned(),
                 description: None,
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::HttpRequest {
-                    method: "POST".to_owned(),
-                    url: "https://example.com/sync".to_owned(),
-                    headers: Some(json!({
-                        "authorization": "Bearer secret-value",
-                        "content-type": "application/json",
-                    })),
-                    header_secret_refs: None,
-                    body: None,
+                trigger: WorkflowTrigger::RuntimeRecordUpdated {
+                    entity_logical_name: "contact".to_owned(),
+                },
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "refreshed".to_owned(),
                 }],
-                max_attempts: 1,
-                is_enabled: false,
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await
         .unwrap_or_else(|_| unreachable!());
+    service
+        .publish_workflow(&actor, "contact_refresh")
+        .await
+        .unwrap_or_else(|_| unreachable!());
 
-    let errors = service
-        .publish_checks(&actor, "external_sync")
+    let wrong_entity = service
+        .ensure_bulk_execution_target(&actor, "contact_refresh", "account")
+        .await;
+    assert!(matches!(wrong_entity, Err(AppError::Validation(_))));
+
+    let records = ["contact-1", "contact-2"]
+        .into_iter()
+        .map(|record_id| {
+            qryvanta_domain::RuntimeRecord::new(record_id, "contact", json!({"name": record_id}))
+                .unwrap_or_else(|_| unreachable!())
+        })
+        .collect::<Vec<_>>();
+    let input = |expected_record_count| StartWorkflowBulkExecutionInput {
+        workflow_logical_name: "contact_refresh".to_owned(),
+        entity_logical_name: "contact".to_owned(),
+        view_logical_name: Some("active_contacts".to_owned()),
+        records: records.clone(),
+        expected_record_count,
+    };
+
+    let stale_preview = service.start_bulk_execution(&actor, input(3)).await;
+    assert!(matches!(stale_preview, Err(AppError::Conflict(_))));
+    assert!(repository.runs.lock().await.is_empty());
+
+    let started = service
+        .start_bulk_execution(&actor, input(2))
         .await
         .unwrap_or_else(|_| unreachable!());
-    assert_eq!(errors.len(), 1);
-    assert!(errors[0].contains("disallowed inline credential header 'authorization'"));
+    assert_eq!(started.run_counts.pending, 2);
+    let execution = started.execution;
+    assert_eq!(execution.total_records, 2);
+    assert_eq!(repository.jobs.lock().await.len(), 2);
+    let run_ids = repository
+        .runs
+        .lock()
+        .await
+        .iter()
+        .map(|run| {
+            assert_eq!(
+                run.trigger_payload["bulk_execution_id"],
+                json!(execution.bulk_execution_id)
+            );
+            run.run_id.clone()
+        })
+        .collect::<Vec<_>>();
+
+    let report = service
+        .bulk_execution_report(&actor, execution.bulk_execution_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(report.run_counts.pending, 2);
+    assert_eq!(report.completed_at, None);
+
+    for (run_id, status) in run_ids.into_iter().zip([
+        WorkflowRunStatus::Succeeded,
+        WorkflowRunStatus::DeadLettered,
+    ]) {
+        repository
+            .complete_run(
+                tenant_id,
+                CompleteWorkflowRunInput {
+                    run_id,
+                    status,
+                    attempts: 1,
+                    dead_letter_reason: None,
+                },
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+    }
+
+    let report = service
+        .bulk_execution_report(&actor, execution.bulk_execution_id.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(report.run_counts.succeeded, 1);
+    assert_eq!(report.run_counts.dead_lettered, 1);
+    assert_eq!(report.run_counts.pending, 0);
+    assert!(report.completed_at.is_some());
+
+    let missing = service.bulk_execution_report(&actor, "missing").await;
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+}
+
+#[derive(Default)]
+struct FakeWorkerLeaseCoordinator {
+    leases: Mutex<HashMap<String, WorkflowWorkerLease>>,
+}
+
+#[async_trait]
+impl WorkflowWorkerLeaseCoordinator for FakeWorkerLeaseCoordinator {
+    async fn try_acquire_lease(
+        &self,
+        scope_key: &str,
+        holder_id: &str,
+        _lease_seconds: u32,
+    ) -> AppResult<Option<WorkflowWorkerLease>> {
+        let mut leases = self.leases.lock().await;
+        if leases.contains_key(scope_key) {
+            return Ok(None);
+        }
+
+        let lease = WorkflowWorkerLease {
+            scope_key: scope_key.to_owned(),
+            token: format!("{holder_id}:token"),
+            holder_id: holder_id.to_owned(),
+        };
+        leases.insert(scope_key.to_owned(), lease.clone());
+        Ok(Some(lease))
+    }
+
+    async fn release_lease(&self, lease: &WorkflowWorkerLease) -> AppResult<()> {
+        self.leases.lock().await.remove(lease.scope_key.as_str());
+        Ok(())
+    }
+
+    async fn renew_lease(
+        &self,
+        lease: &WorkflowWorkerLease,
+        _lease_seconds: u32,
+    ) -> AppResult<bool> {
+        Ok(self
+            .leases
+            .lock()
+            .await
+            .contains_key(lease.scope_key.as_str()))
+    }
+
+    async fn inspect_lease(
+        &self,
+        scope_key: &str,
+    ) -> AppResult<Option<WorkflowWorkerLeaseOwnership>> {
+        Ok(self
+            .leases
+            .lock()
+            .await
+            .get(scope_key)
+            .map(|lease| WorkflowWorkerLeaseOwnership {
+                scope_key: lease.scope_key.clone(),
+                holder_id: lease.holder_id.clone(),
+                expires_in_seconds: Some(30),
+            }))
+    }
+}
+
+async fn expire_job_lease(repository: &FakeWorkflowRepository, job_id: &str) {
+    for job in repository
+        .jobs
+        .lock()
+        .await
+        .iter_mut()
+        .filter(|job| job.job_id == job_id)
+    {
+        job.lease_expires_at = Some(Utc::now() - chrono::Duration::seconds(1));
+    }
 }
 
 #[tokio::test]
-async fn workflow_publish_rejects_inline_credential_headers() {
+async fn stuck_jobs_can_be_listed_released_and_failed_after_lease_expiry() {
     let tenant_id = TenantId::new();
-    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let actor = UserIdentity::new("operator", "operator", None, tenant_id);
+    let viewer = UserIdentity::new("viewer", "viewer", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
     let service = build_service(
-        HashMap::from([(
-            (tenant_id, "maker".to_owned()),
-            vec![Permission::WorkflowManage, Permission::WorkflowRead],
-        )]),
-        repository,
+        HashMap::from([
+            (
+                (tenant_id, "operator".to_owned()),
+                vec![Permission::WorkflowManage, Permission::WorkflowRead],
+            ),
+            (
+                (tenant_id, "viewer".to_owned()),
+                vec![Permission::WorkflowRead],
+            ),
+        ]),
+        repository.clone(),
         runtime_service,
-        WorkflowExecutionMode::Inline,
+        WorkflowExecutionMode::Queued,
         None,
     );
 
@@ -3904,134 +7702,156 @@ async fn workflow_publish_rejects_inline_credential_headers() {
         .save_workflow(
             &actor,
             SaveWorkflowInput {
-                logical_name: "blocked_publish".to_owned(),
-                display_name: "Blocked Publish".to_owned(),
+                logical_name: "stuck_recovery".to_owned(),
+                display_name: "Stuck Recovery".to_owned(),
                 description: None,
                 trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::Webhook {
-                    endpoint: "https://example.com/webhook".to_owned(),
-                    event: "lead.created".to_owned(),
-                    headers: Some(json!({
-                        "x-api-key": "top-secret"
-                    })),
-                    header_secret_refs: None,
-                    payload: json!({"lead_id": "lead-1"}),
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "queued".to_owned(),
                 }],
-                max_attempts: 1,
-                is_enabled: false,
+                max_attempts: 2,
+                is_enabled: true,
+                trigger_filters: Vec::new(),
+                trigger_changed_fields: Vec::new(),
+                trigger_view_logical_name: None,
+                priority: WorkflowRunPriority::Normal,
             },
         )
         .await
         .unwrap_or_else(|_| unreachable!());
+    for _ in 0..2 {
+        service
+            .execute_workflow(&actor, "stuck_recovery", json!({"source": "test"}))
+            .await
+            .unwrap_or_else(|_| unreachable!());
+    }
+
+    let active = service
+        .claim_jobs_for_worker("worker-alive", 1, 300, None, None)
+        .await
+        .unwrap_or_default();
+    assert_eq!(active.len(), 1);
+    let active_job_id = active[0].job_id.clone();
+    let release_active = service
+        .release_stuck_job(&actor, active_job_id.as_str())
+        .await;
+    assert!(matches!(release_active, Err(AppError::Conflict(_))));
+
+    let crashed = service
+        .claim_jobs_for_worker("worker-crashed", 1, 30, None, None)
+        .await
+        .unwrap_or_default();
+    assert_eq!(crashed.len(), 1);
+    let stuck_job_id = crashed[0].job_id.clone();
+    expire_job_lease(&repository, stuck_job_id.as_str()).await;
+
+    let denied = service.list_stuck_jobs(&viewer, None).await;
+    assert!(matches!(denied, Err(AppError::Forbidden(_))));
+
+    let stuck = service
+        .list_stuck_jobs(&actor, None)
+        .await
+        .unwrap_or_default();
+    assert_eq!(stuck.len(), 1);
+    assert_eq!(stuck[0].job_id, stuck_job_id);
+    assert_eq!(stuck[0].leased_by.as_deref(), Some("worker-crashed"));
 
-    let publish_result = service.publish_workflow(&actor, "blocked_publish").await;
     assert!(
-        matches!(publish_result, Err(AppError::Validation(message)) if message.contains("disallowed inline credential header 'x-api-key'"))
+        service
+            .release_stuck_job(&actor, stuck_job_id.as_str())
+            .await
+            .is_ok()
     );
-}
-
-#[tokio::test]
-async fn workflow_publish_allows_sensitive_headers_when_backed_by_secret_refs() {
-    let tenant_id = TenantId::new();
-    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
-    let repository = Arc::new(FakeWorkflowRepository::default());
-    let runtime_service = Arc::new(FakeRuntimeRecordService::default());
-    let service = build_service(
-        HashMap::from([(
-            (tenant_id, "maker".to_owned()),
-            vec![Permission::WorkflowManage, Permission::WorkflowRead],
-        )]),
-        repository,
-        runtime_service,
-        WorkflowExecutionMode::Inline,
-        None,
+    assert!(
+        service
+            .list_stuck_jobs(&actor, None)
+            .await
+            .unwrap_or_default()
+            .is_empty()
     );
 
-    service
-        .save_workflow(
-            &actor,
-            SaveWorkflowInput {
-                logical_name: "secret_backed_publish".to_owned(),
-                display_name: "Secret Backed Publish".to_owned(),
-                description: None,
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::Webhook {
-                    endpoint: "https://example.com/webhook".to_owned(),
-                    event: "lead.created".to_owned(),
-                    headers: Some(json!({
-                        "content-type": "application/json"
-                    })),
-                    header_secret_refs: Some(json!({
-                        "authorization": "op://vault/item/password"
-                    })),
-                    payload: json!({"lead_id": "lead-1"}),
-                }],
-                max_attempts: 1,
-                is_enabled: false,
-            },
-        )
+    let reclaimed = service
+        .claim_jobs_for_worker("worker-crashed", 1, 30, None, None)
         .await
-        .unwrap_or_else(|_| unreachable!());
+        .unwrap_or_default();
+    assert_eq!(reclaimed.len(), 1);
+    assert_eq!(reclaimed[0].job_id, stuck_job_id);
+    expire_job_lease(&repository, stuck_job_id.as_str()).await;
 
-    let errors = service
-        .publish_checks(&actor, "secret_backed_publish")
+    let failed_run = service
+        .fail_stuck_job(&actor, stuck_job_id.as_str(), Some("poison payload"))
         .await
         .unwrap_or_else(|_| unreachable!());
-    assert!(errors.is_empty());
+    assert_eq!(failed_run.status, WorkflowRunStatus::DeadLettered);
+    assert_eq!(
+        failed_run.dead_letter_reason.as_deref(),
+        Some("poison payload")
+    );
+
+    let failed_again = service
+        .fail_stuck_job(&actor, stuck_job_id.as_str(), None)
+        .await;
+    assert!(matches!(failed_again, Err(AppError::Conflict(_))));
 }
 
 #[tokio::test]
-async fn workflow_publish_step_up_detection_tracks_outbound_drafts() {
+async fn inspect_worker_lease_reports_coordinator_holder() {
     let tenant_id = TenantId::new();
-    let actor = UserIdentity::new("maker", "maker", None, tenant_id);
+    let actor = UserIdentity::new("operator", "operator", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let grants = HashMap::from([(
+        (tenant_id, "operator".to_owned()),
+        vec![Permission::WorkflowManage],
+    )]);
+
+    let unconfigured = build_service(
+        grants.clone(),
+        repository.clone(),
+        runtime_service.clone(),
+        WorkflowExecutionMode::Queued,
+        None,
+    );
+    let missing_coordinator = unconfigured
+        .inspect_worker_lease(&actor, "partition:0")
+        .await;
+    assert!(matches!(missing_coordinator, Err(AppError::Conflict(_))));
+
+    let coordinator = Arc::new(FakeWorkerLeaseCoordinator::default());
     let service = build_service(
-        HashMap::from([(
-            (tenant_id, "maker".to_owned()),
-            vec![Permission::WorkflowManage, Permission::WorkflowRead],
-        )]),
+        grants,
         repository,
         runtime_service,
-        WorkflowExecutionMode::Inline,
+        WorkflowExecutionMode::Queued,
         None,
-    );
+    )
+    .with_worker_lease_coordinator(coordinator.clone());
 
-    service
-        .save_workflow(
-            &actor,
-            SaveWorkflowInput {
-                logical_name: "ops_notify".to_owned(),
-                display_name: "Ops Notify".to_owned(),
-                description: None,
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::SendEmail {
-                    to: "ops@example.com".to_owned(),
-                    subject: "Alert".to_owned(),
-                    body: "Check workflow".to_owned(),
-                    html_body: None,
-                }],
-                max_attempts: 1,
-                is_enabled: false,
-            },
-        )
+    let unleased = service.inspect_worker_lease(&actor, "partition:0").await;
+    assert!(matches!(unleased, Ok(None)));
+
+    coordinator
+        .try_acquire_lease("partition:0", "worker-alpha", 30)
         .await
         .unwrap_or_else(|_| unreachable!());
+    let ownership = service
+        .inspect_worker_lease(&actor, "partition:0")
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(ownership.holder_id, "worker-alpha");
 
-    assert!(
-        service
-            .publish_requires_recent_step_up(&actor, "ops_notify")
-            .await
-            .unwrap_or_else(|_| unreachable!())
-    );
+    let blank_scope = service.inspect_worker_lease(&actor, " ").await;
+    assert!(matches!(blank_scope, Err(AppError::Validation(_))));
 }
 
 #[tokio::test]
-async fn workflow_disable_step_up_detection_tracks_active_outbound_versions() {
+async fn http_request_steps_dispatch_with_saved_workflow_credentials() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
+    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
@@ -4040,51 +7860,82 @@ async fn workflow_disable_step_up_detection_tracks_active_outbound_versions() {
         repository,
         runtime_service,
         WorkflowExecutionMode::Inline,
-        None,
-    );
+        Some(action_dispatcher.clone()),
+    )
+    .with_credential_repository(Arc::new(FakeCredentialRepository::default()));
+
+    let input = |credential: &str| SaveWorkflowInput {
+        logical_name: "erp_sync".to_owned(),
+        display_name: "ERP Sync".to_owned(),
+        description: None,
+        trigger: WorkflowTrigger::Manual,
+        steps: vec![WorkflowStep::HttpRequest {
+            method: "POST".to_owned(),
+            url: "https://api.example.com/orders".to_owned(),
+            headers: None,
+            credential: Some(credential.to_owned()),
+            header_secret_refs: None,
+            body: Some(json!({ "record_id": "{{trigger.payload.record_id}}" })),
+            expected_status: None,
+            retry: None,
+            output_key: None,
+            retry_policy: None,
+        }],
+        max_attempts: 1,
+        is_enabled: true,
+        trigger_filters: Vec::new(),
+        trigger_changed_fields: Vec::new(),
+        trigger_view_logical_name: None,
+        priority: WorkflowRunPriority::Normal,
+    };
+
+    let unknown = service.save_workflow(&actor, input("erp")).await;
+    assert!(matches!(unknown, Err(AppError::Validation(message)) if message.contains("'erp'")));
 
     service
-        .save_workflow(
+        .save_workflow_credential(
             &actor,
-            SaveWorkflowInput {
-                logical_name: "ops_webhook".to_owned(),
-                display_name: "Ops Webhook".to_owned(),
-                description: None,
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::Webhook {
-                    endpoint: "https://example.com/notify".to_owned(),
-                    event: "incident.created".to_owned(),
-                    headers: None,
-                    header_secret_refs: None,
-                    payload: json!({"severity": "high"}),
-                }],
-                max_attempts: 1,
-                is_enabled: false,
-            },
+            WorkflowCredential::new(
+                "erp",
+                "ERP",
+                WorkflowCredentialAuth::ApiKey {
+                    header_name: "x-api-key".to_owned(),
+                    prefix: None,
+                    secret_ref: "op://vault/erp/key".to_owned(),
+                },
+                vec!["api.example.com".to_owned()],
+            )
+            .unwrap_or_else(|_| unreachable!()),
         )
         .await
         .unwrap_or_else(|_| unreachable!());
     service
-        .publish_workflow(&actor, "ops_webhook")
+        .save_workflow(&actor, input("erp"))
         .await
         .unwrap_or_else(|_| unreachable!());
 
-    assert!(
-        service
-            .disable_requires_recent_step_up(&actor, "ops_webhook")
-            .await
-            .unwrap_or_else(|_| unreachable!())
-    );
+    let run = service
+        .execute_workflow(&actor, "erp_sync", json!({ "record_id": "rec-1" }))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(run.status, WorkflowRunStatus::Succeeded);
+
+    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
+    assert_eq!(dispatched.len(), 1);
+    let credential = dispatched[0]
+        .credential
+        .as_ref()
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(credential.tenant_id, tenant_id);
+    assert_eq!(credential.credential.logical_name().as_str(), "erp");
 }
 
 #[tokio::test]
-async fn draft_changes_do_not_replace_current_published_workflow_until_publish() {
+async fn urgent_runs_are_claimed_before_earlier_bulk_runs() {
     let tenant_id = TenantId::new();
     let actor = UserIdentity::new("maker", "maker", None, tenant_id);
     let repository = Arc::new(FakeWorkflowRepository::default());
     let runtime_service = Arc::new(FakeRuntimeRecordService::default());
-    let action_dispatcher = Arc::new(FakeActionDispatcher::default());
-
     let service = build_service(
         HashMap::from([(
             (tenant_id, "maker".to_owned()),
@@ -4092,59 +7943,60 @@ async fn draft_changes_do_not_replace_current_published_workflow_until_publish()
         )]),
         repository,
         runtime_service,
-        WorkflowExecutionMode::Inline,
+        WorkflowExecutionMode::Queued,
         None,
-    )
-    .with_action_dispatcher(action_dispatcher.clone());
+    );
 
-    service
-        .save_workflow(
-            &actor,
-            SaveWorkflowInput {
-                logical_name: "versioned_email".to_owned(),
-                display_name: "Versioned Email".to_owned(),
-                description: None,
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::SendEmail {
-                    to: "ops@example.com".to_owned(),
-                    subject: "v1".to_owned(),
-                    body: "first".to_owned(),
-                    html_body: None,
-                }],
-                max_attempts: 2,
-                is_enabled: true,
-            },
-        )
-        .await
-        .unwrap_or_else(|_| unreachable!());
+    for (logical_name, priority) in [
+        ("bulk_backfill", WorkflowRunPriority::Low),
+        ("urgent_escalation", WorkflowRunPriority::Urgent),
+    ] {
+        let save_result = service
+            .save_workflow(
+                &actor,
+                SaveWorkflowInput {
+                    logical_name: logical_name.to_owned(),
+                    display_name: logical_name.to_owned(),
+                    description: None,
+                    trigger: WorkflowTrigger::Manual,
+                    steps: vec![WorkflowStep::LogMessage {
+                        message: logical_name.to_owned(),
+                    }],
+                    max_attempts: 1,
+                    is_enabled: true,
+                    trigger_filters: Vec::new(),
+                    trigger_changed_fields: Vec::new(),
+                    trigger_view_logical_name: None,
+                    priority,
+                },
+            )
+            .await;
+        assert!(save_result.is_ok());
+    }
 
-    service
-        .save_workflow(
-            &actor,
-            SaveWorkflowInput {
-                logical_name: "versioned_email".to_owned(),
-                display_name: "Versioned Email".to_owned(),
-                description: Some("draft update".to_owned()),
-                trigger: WorkflowTrigger::Manual,
-                steps: vec![WorkflowStep::SendEmail {
-                    to: "ops@example.com".to_owned(),
-                    subject: "v2".to_owned(),
-                    body: "second".to_owned(),
-                    html_body: None,
-                }],
-                max_attempts: 2,
-                is_enabled: false,
-            },
-        )
+    for _ in 0..2 {
+        let bulk_run = service
+            .execute_workflow(&actor, "bulk_backfill", json!({}))
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(bulk_run.priority, WorkflowRunPriority::Low);
+    }
+    let urgent_run = service
+        .execute_workflow(&actor, "urgent_escalation", json!({}))
         .await
         .unwrap_or_else(|_| unreachable!());
+    assert_eq!(urgent_run.priority, WorkflowRunPriority::Urgent);
 
-    service
-        .execute_workflow(&actor, "versioned_email", json!({}))
+    let claimed_jobs = service
+        .claim_jobs_for_worker("worker-alpha", 2, 300, None, None)
         .await
-        .unwrap_or_else(|_| unreachable!());
-
-    let dispatched = action_dispatcher.dispatched_requests.lock().await.clone();
-    assert_eq!(dispatched.len(), 1);
-    assert_eq!(dispatched[0].payload["subject"], json!("v1"));
+        .unwrap_or_default();
+    assert_eq!(claimed_jobs.len(), 2);
+    assert_eq!(claimed_jobs[0].run_id, urgent_run.run_id);
+    assert_eq!(claimed_jobs[0].priority, WorkflowRunPriority::Urgent);
+    assert_eq!(
+        claimed_jobs[0].workflow.priority(),
+        WorkflowRunPriority::Urgent
+    );
+    assert_eq!(claimed_jobs[1].priority, WorkflowRunPriority::Low);
 }
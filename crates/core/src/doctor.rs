@@ -0,0 +1,153 @@
+//! Configuration self-check findings shared by the `doctor` subcommands.
+
+use std::fmt::Write as _;
+
+/// Severity of one doctor finding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DoctorSeverity {
+    /// Check passed.
+    Pass,
+    /// Setting works but is risky or unusual for production.
+    Warning,
+    /// Setting prevents the process from serving traffic correctly.
+    Error,
+}
+
+impl DoctorSeverity {
+    /// Returns the fixed-width label used in printed reports.
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Pass => "ok",
+            Self::Warning => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// One actionable doctor finding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorFinding {
+    /// Severity of the finding.
+    pub severity: DoctorSeverity,
+    /// Short check name, for example `database` or `webauthn`.
+    pub check: String,
+    /// What was found and, for failures, how to fix it.
+    pub message: String,
+}
+
+/// Ordered findings collected by one doctor run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DoctorReport {
+    findings: Vec<DoctorFinding>,
+}
+
+impl DoctorReport {
+    /// Creates an empty report.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a passing check.
+    pub fn pass(&mut self, check: impl Into<String>, message: impl Into<String>) {
+        self.push(DoctorSeverity::Pass, check, message);
+    }
+
+    /// Records a warning.
+    pub fn warn(&mut self, check: impl Into<String>, message: impl Into<String>) {
+        self.push(DoctorSeverity::Warning, check, message);
+    }
+
+    /// Records a blocking error.
+    pub fn error(&mut self, check: impl Into<String>, message: impl Into<String>) {
+        self.push(DoctorSeverity::Error, check, message);
+    }
+
+    /// Returns findings in the order they were recorded.
+    #[must_use]
+    pub fn findings(&self) -> &[DoctorFinding] {
+        self.findings.as_slice()
+    }
+
+    /// Returns the number of findings with the given severity.
+    #[must_use]
+    pub fn count(&self, severity: DoctorSeverity) -> usize {
+        self.findings
+            .iter()
+            .filter(|finding| finding.severity == severity)
+            .count()
+    }
+
+    /// Returns whether any finding blocks startup.
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.count(DoctorSeverity::Error) > 0
+    }
+
+    /// Renders one line per finding followed by a summary line.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let check_width = self
+            .findings
+            .iter()
+            .map(|finding| finding.check.len())
+            .max()
+            .unwrap_or_default();
+
+        let mut output = String::new();
+        for finding in &self.findings {
+            let _ = writeln!(
+                output,
+                "[{:<5}] {:<check_width$}  {}",
+                finding.severity.label(),
+                finding.check,
+                finding.message
+            );
+        }
+        let _ = write!(
+            output,
+            "{} passed, {} warnings, {} errors",
+            self.count(DoctorSeverity::Pass),
+            self.count(DoctorSeverity::Warning),
+            self.count(DoctorSeverity::Error)
+        );
+
+        output
+    }
+
+    fn push(
+        &mut self,
+        severity: DoctorSeverity,
+        check: impl Into<String>,
+        message: impl Into<String>,
+    ) {
+        self.findings.push(DoctorFinding {
+            severity,
+            check: check.into(),
+            message: message.into(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DoctorReport, DoctorSeverity};
+
+    #[test]
+    fn report_counts_severities_and_renders_summary() {
+        let mut report = DoctorReport::new();
+        report.pass("database", "connected");
+        report.warn("cookies", "SESSION_COOKIE_SECURE=false");
+        assert!(!report.has_errors());
+
+        report.error("webauthn", "origin host does not match WEBAUTHN_RP_ID");
+        assert!(report.has_errors());
+        assert_eq!(report.count(DoctorSeverity::Warning), 1);
+
+        let rendered = report.render();
+        assert!(rendered.starts_with("[ok   ] database  connected\n"));
+        assert!(rendered.contains("[error] webauthn  origin host"));
+        assert!(rendered.ends_with("1 passed, 1 warnings, 1 errors"));
+    }
+}
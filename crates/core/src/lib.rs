@@ -4,6 +4,8 @@
 
 /// Authentication primitives shared across services.
 pub mod auth;
+/// Configuration self-check reporting.
+pub mod doctor;
 pub mod secret;
 
 use std::fmt::{Display, Formatter};
@@ -13,6 +15,7 @@ use thiserror::Error;
 use uuid::Uuid;
 
 pub use auth::UserIdentity;
+pub use doctor::{DoctorFinding, DoctorReport, DoctorSeverity};
 pub use secret::{
     SecretFingerprintRecord, detect_reused_secret_fingerprints, optional_secret,
     required_non_empty_secret, required_secret, resolve_secret_reference, secret_fingerprint,
@@ -115,6 +118,10 @@ pub enum AppError {
     #[error("rate limited: {0}")]
     RateLimited(String),
 
+    /// Operation exceeded its latency budget and was cancelled.
+    #[error("timeout: {0}")]
+    Timeout(String),
+
     /// Internal unexpected error.
     #[error("internal error: {0}")]
     Internal(String),
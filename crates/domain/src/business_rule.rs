@@ -44,6 +44,11 @@ pub enum BusinessRuleOperator {
 }
 
 /// Supported action kinds for business rules.
+///
+/// Entity-scoped rules are enforced on every runtime record create and
+/// update. `SetDefaultValue`, `SetFieldValue`, `ShowError` and `PreventSave`
+/// change or reject the stored payload; the remaining actions shape forms
+/// and required-field checks.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum BusinessRuleActionType {
@@ -65,6 +70,8 @@ pub enum BusinessRuleActionType {
     UnlockField,
     /// Emit a validation error.
     ShowError,
+    /// Reject the save with a message, without surfacing it while editing.
+    PreventSave,
 }
 
 /// One business-rule condition.
@@ -158,9 +165,13 @@ impl BusinessRuleAction {
             ));
         }
 
-        if action_type == BusinessRuleActionType::ShowError && error_message.is_none() {
+        if matches!(
+            action_type,
+            BusinessRuleActionType::ShowError | BusinessRuleActionType::PreventSave
+        ) && error_message.is_none()
+        {
             return Err(AppError::Validation(
-                "show_error action requires error_message".to_owned(),
+                "show_error and prevent_save actions require error_message".to_owned(),
             ));
         }
 
@@ -0,0 +1,280 @@
+use qryvanta_core::{AppError, AppResult};
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+const MAX_DAYS_IN_MONTH: [u32; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+struct CronField {
+    label: &'static str,
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+    names_start: u32,
+}
+
+const MINUTE_FIELD: CronField = CronField {
+    label: "minute",
+    min: 0,
+    max: 59,
+    names: &[],
+    names_start: 0,
+};
+const HOUR_FIELD: CronField = CronField {
+    label: "hour",
+    min: 0,
+    max: 23,
+    names: &[],
+    names_start: 0,
+};
+const DAY_OF_MONTH_FIELD: CronField = CronField {
+    label: "day-of-month",
+    min: 1,
+    max: 31,
+    names: &[],
+    names_start: 0,
+};
+const MONTH_FIELD: CronField = CronField {
+    label: "month",
+    min: 1,
+    max: 12,
+    names: &MONTH_NAMES,
+    names_start: 1,
+};
+const DAY_OF_WEEK_FIELD: CronField = CronField {
+    label: "day-of-week",
+    min: 0,
+    max: 7,
+    names: &WEEKDAY_NAMES,
+    names_start: 0,
+};
+
+/// Parsed five-field cron expression evaluated against UTC wall-clock time.
+///
+/// Supports `*`, lists, ranges, steps, month and weekday names, `7` as Sunday
+/// and the `@yearly`, `@monthly`, `@weekly`, `@daily` and `@hourly` macros.
+/// When both day fields are restricted a time matches if either one matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    /// Parses and validates a cron expression.
+    pub fn parse(expression: &str) -> AppResult<Self> {
+        let trimmed = expression.trim();
+        let expanded = match trimmed.to_ascii_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            _ if trimmed.starts_with('@') => {
+                return Err(AppError::Validation(format!(
+                    "cron expression '{trimmed}' uses an unknown macro"
+                )));
+            }
+            _ => trimmed,
+        };
+
+        let fields = expanded.split_whitespace().collect::<Vec<_>>();
+        let [minute, hour, day_of_month, month, day_of_week] = fields.as_slice() else {
+            return Err(AppError::Validation(format!(
+                "cron expression '{trimmed}' must have 5 fields (minute hour day-of-month month day-of-week)"
+            )));
+        };
+
+        let mut days_of_week = parse_field(&DAY_OF_WEEK_FIELD, day_of_week, trimmed)?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        let schedule = Self {
+            minutes: parse_field(&MINUTE_FIELD, minute, trimmed)?,
+            hours: parse_field(&HOUR_FIELD, hour, trimmed)?,
+            days_of_month: parse_field(&DAY_OF_MONTH_FIELD, day_of_month, trimmed)?,
+            months: parse_field(&MONTH_FIELD, month, trimmed)?,
+            days_of_week,
+            day_of_month_restricted: !day_of_month.starts_with('*'),
+            day_of_week_restricted: !day_of_week.starts_with('*'),
+        };
+
+        if !schedule.can_fire() {
+            return Err(AppError::Validation(format!(
+                "cron expression '{trimmed}' never matches a calendar date"
+            )));
+        }
+
+        Ok(schedule)
+    }
+
+    /// Returns whether the schedule fires on the given calendar day.
+    ///
+    /// `month` is 1-based and `day_of_week` counts from Sunday as 0.
+    #[must_use]
+    pub fn matches_date(&self, day_of_month: u32, month: u32, day_of_week: u32) -> bool {
+        if !has_bit(self.months, month) {
+            return false;
+        }
+
+        let day_of_month_matches = has_bit(self.days_of_month, day_of_month);
+        let day_of_week_matches = has_bit(self.days_of_week, day_of_week);
+        if self.day_of_month_restricted && self.day_of_week_restricted {
+            day_of_month_matches || day_of_week_matches
+        } else {
+            day_of_month_matches && day_of_week_matches
+        }
+    }
+
+    /// Returns whether the schedule fires during the given hour.
+    #[must_use]
+    pub fn matches_hour(&self, hour: u32) -> bool {
+        has_bit(self.hours, hour)
+    }
+
+    /// Returns whether the schedule fires at the given minute.
+    #[must_use]
+    pub fn matches_minute(&self, minute: u32) -> bool {
+        has_bit(self.minutes, minute)
+    }
+
+    fn can_fire(&self) -> bool {
+        if self.day_of_week_restricted {
+            return true;
+        }
+
+        (1..=12).any(|month| {
+            has_bit(self.months, month)
+                && (1..=MAX_DAYS_IN_MONTH[month as usize - 1])
+                    .any(|day| has_bit(self.days_of_month, day))
+        })
+    }
+}
+
+fn has_bit(bits: u64, value: u32) -> bool {
+    value < 64 && bits & (1 << value) != 0
+}
+
+fn parse_field(field: &CronField, raw: &str, expression: &str) -> AppResult<u64> {
+    let invalid = || {
+        AppError::Validation(format!(
+            "cron expression '{expression}' has invalid {} field '{raw}'",
+            field.label
+        ))
+    };
+
+    let mut bits = 0_u64;
+    for item in raw.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => {
+                let step = step.parse::<u32>().map_err(|_| invalid())?;
+                if step == 0 {
+                    return Err(invalid());
+                }
+                (range, Some(step))
+            }
+            None => (item, None),
+        };
+
+        let (start, end) = if range == "*" {
+            (field.min, field.max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                parse_value(field, start).ok_or_else(invalid)?,
+                parse_value(field, end).ok_or_else(invalid)?,
+            )
+        } else {
+            let start = parse_value(field, range).ok_or_else(invalid)?;
+            (start, if step.is_some() { field.max } else { start })
+        };
+
+        if start > end {
+            return Err(invalid());
+        }
+
+        let step = step.unwrap_or(1) as usize;
+        for value in (start..=end).step_by(step) {
+            bits |= 1 << value;
+        }
+    }
+
+    Ok(bits)
+}
+
+fn parse_value(field: &CronField, raw: &str) -> Option<u32> {
+    let value = match raw.parse::<u32>() {
+        Ok(value) => value,
+        Err(_) => {
+            let lowered = raw.to_ascii_lowercase();
+            let index = field.names.iter().position(|name| *name == lowered)?;
+            u32::try_from(index).ok()? + field.names_start
+        }
+    };
+
+    (field.min..=field.max).contains(&value).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CronSchedule;
+
+    #[test]
+    fn parses_lists_ranges_and_steps() {
+        let schedule =
+            CronSchedule::parse("*/15 9-17 * * mon-fri").unwrap_or_else(|_| unreachable!());
+
+        assert!(schedule.matches_minute(0));
+        assert!(schedule.matches_minute(45));
+        assert!(!schedule.matches_minute(10));
+        assert!(schedule.matches_hour(9));
+        assert!(!schedule.matches_hour(18));
+        assert!(schedule.matches_date(14, 3, 1));
+        assert!(!schedule.matches_date(14, 3, 0));
+    }
+
+    #[test]
+    fn expands_macros_and_treats_seven_as_sunday() {
+        let weekly = CronSchedule::parse("@weekly").unwrap_or_else(|_| unreachable!());
+        let sunday = CronSchedule::parse("0 0 * * 7").unwrap_or_else(|_| unreachable!());
+
+        assert_eq!(weekly, sunday);
+        assert!(weekly.matches_date(5, 1, 0));
+        assert!(!weekly.matches_date(6, 1, 1));
+    }
+
+    #[test]
+    fn restricted_day_fields_match_either_day() {
+        let schedule = CronSchedule::parse("0 8 1 * fri").unwrap_or_else(|_| unreachable!());
+
+        assert!(schedule.matches_date(1, 6, 3));
+        assert!(schedule.matches_date(13, 6, 5));
+        assert!(!schedule.matches_date(13, 6, 4));
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        for expression in [
+            "",
+            "* * * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "*/0 * * * *",
+            "30-10 * * * *",
+            "0 0 31 feb *",
+            "0 0 * * funday",
+            "@every_minute",
+        ] {
+            assert!(
+                CronSchedule::parse(expression).is_err(),
+                "expected '{expression}' to be rejected"
+            );
+        }
+    }
+}
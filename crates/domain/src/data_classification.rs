@@ -0,0 +1,193 @@
+use std::str::FromStr;
+
+use qryvanta_core::AppError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Placeholder shown in place of a masked value.
+pub const MASKED_VALUE_PLACEHOLDER: &str = "********";
+
+/// Number of trailing characters left visible by partial masking.
+pub const PARTIAL_MASK_VISIBLE_CHARACTERS: usize = 4;
+
+/// Sensitivity class a field can be tagged with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldClassification {
+    /// Personally identifiable information.
+    Pii,
+    /// Protected health information.
+    Phi,
+    /// Confidential business information.
+    Confidential,
+}
+
+impl FieldClassification {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pii => "pii",
+            Self::Phi => "phi",
+            Self::Confidential => "confidential",
+        }
+    }
+}
+
+impl FromStr for FieldClassification {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pii" => Ok(Self::Pii),
+            "phi" => Ok(Self::Phi),
+            "confidential" => Ok(Self::Confidential),
+            _ => Err(AppError::Validation(format!(
+                "unknown field classification '{value}'"
+            ))),
+        }
+    }
+}
+
+/// How values of a classified field are shown to a role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldMaskingMode {
+    /// The whole value is replaced by a placeholder.
+    Full,
+    /// Only the last characters of text and numbers stay visible.
+    Partial,
+    /// The field is left out.
+    Hidden,
+}
+
+impl FieldMaskingMode {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Partial => "partial",
+            Self::Hidden => "hidden",
+        }
+    }
+
+    /// Returns the mode revealing more of the value.
+    #[must_use]
+    pub fn least_restrictive(self, other: Self) -> Self {
+        if other.rank() < self.rank() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the masked form of a value, or `None` when the field is hidden.
+    ///
+    /// Null values stay null, since there is nothing to mask.
+    #[must_use]
+    pub fn mask_value(&self, value: &Value) -> Option<Value> {
+        if *self == Self::Hidden {
+            return None;
+        }
+        if value.is_null() {
+            return Some(Value::Null);
+        }
+
+        let text = match (self, value) {
+            (Self::Partial, Value::String(text)) => Some(text.clone()),
+            (Self::Partial, Value::Number(number)) => Some(number.to_string()),
+            _ => None,
+        };
+        let Some(text) = text else {
+            return Some(Value::String(MASKED_VALUE_PLACEHOLDER.to_owned()));
+        };
+
+        let length = text.chars().count();
+        if length <= PARTIAL_MASK_VISIBLE_CHARACTERS {
+            return Some(Value::String(MASKED_VALUE_PLACEHOLDER.to_owned()));
+        }
+
+        let visible = text
+            .chars()
+            .skip(length - PARTIAL_MASK_VISIBLE_CHARACTERS)
+            .collect::<String>();
+        Some(Value::String(format!(
+            "{}{visible}",
+            "*".repeat(length - PARTIAL_MASK_VISIBLE_CHARACTERS)
+        )))
+    }
+
+    fn rank(self) -> u8 {
+        match self {
+            Self::Partial => 0,
+            Self::Full => 1,
+            Self::Hidden => 2,
+        }
+    }
+}
+
+impl FromStr for FieldMaskingMode {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "full" => Ok(Self::Full),
+            "partial" => Ok(Self::Partial),
+            "hidden" => Ok(Self::Hidden),
+            _ => Err(AppError::Validation(format!(
+                "unknown field masking mode '{value}'"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn partial_masking_keeps_the_last_characters_of_text_and_numbers() {
+        let mode = FieldMaskingMode::Partial;
+
+        assert_eq!(
+            mode.mask_value(&json!("123-45-6789")),
+            Some(json!("*******6789"))
+        );
+        assert_eq!(
+            mode.mask_value(&json!(4111111111_i64)),
+            Some(json!("******1111"))
+        );
+        assert_eq!(mode.mask_value(&json!("1234")), Some(json!("********")));
+        assert_eq!(mode.mask_value(&json!(true)), Some(json!("********")));
+        assert_eq!(mode.mask_value(&Value::Null), Some(Value::Null));
+    }
+
+    #[test]
+    fn full_and_hidden_masking_reveal_nothing() {
+        assert_eq!(
+            FieldMaskingMode::Full.mask_value(&json!({ "street": "Main" })),
+            Some(json!("********"))
+        );
+        assert_eq!(FieldMaskingMode::Hidden.mask_value(&json!("x")), None);
+    }
+
+    #[test]
+    fn least_restrictive_mode_wins() {
+        assert_eq!(
+            FieldMaskingMode::Hidden.least_restrictive(FieldMaskingMode::Full),
+            FieldMaskingMode::Full
+        );
+        assert_eq!(
+            FieldMaskingMode::Full.least_restrictive(FieldMaskingMode::Partial),
+            FieldMaskingMode::Partial
+        );
+        assert_eq!(
+            "phi".parse::<FieldClassification>().ok(),
+            Some(FieldClassification::Phi)
+        );
+        assert!("secret".parse::<FieldMaskingMode>().is_err());
+    }
+}
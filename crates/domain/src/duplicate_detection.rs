@@ -0,0 +1,342 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use qryvanta_core::{AppError, AppResult};
+use serde::{Deserialize, Serialize};
+
+/// Prefix of the conflict raised when a write matches duplicate records.
+///
+/// API clients map this prefix to a stable error code and parse the JSON
+/// payload that follows it, so it must not change.
+pub const DUPLICATE_RECORDS_DETECTED_PREFIX: &str = "duplicate records detected";
+
+/// How a duplicate rule compares one field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateMatchMode {
+    /// Values must be equal.
+    Exact,
+    /// Text values must be equal ignoring case, punctuation and spacing.
+    Fuzzy,
+}
+
+impl DuplicateMatchMode {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Exact => "exact",
+            Self::Fuzzy => "fuzzy",
+        }
+    }
+}
+
+impl FromStr for DuplicateMatchMode {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "exact" => Ok(Self::Exact),
+            "fuzzy" => Ok(Self::Fuzzy),
+            _ => Err(AppError::Validation(format!(
+                "unknown duplicate match mode '{value}'"
+            ))),
+        }
+    }
+}
+
+/// What happens when a write matches a duplicate rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateRuleAction {
+    /// The write is rejected unless the caller confirms it.
+    Warn,
+    /// The write is always rejected.
+    Block,
+}
+
+impl DuplicateRuleAction {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Warn => "warn",
+            Self::Block => "block",
+        }
+    }
+}
+
+impl FromStr for DuplicateRuleAction {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "warn" => Ok(Self::Warn),
+            "block" => Ok(Self::Block),
+            _ => Err(AppError::Validation(format!(
+                "unknown duplicate rule action '{value}'"
+            ))),
+        }
+    }
+}
+
+/// One field compared by a duplicate rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DuplicateMatchField {
+    /// Compared field.
+    pub field_logical_name: String,
+    /// Comparison mode.
+    pub match_mode: DuplicateMatchMode,
+}
+
+/// Duplicate-detection rule of one entity.
+///
+/// A record duplicates another when every match field has a value and all of
+/// them match. Rules are evaluated when runtime records are created or
+/// updated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateDetectionRule {
+    entity_logical_name: String,
+    logical_name: String,
+    display_name: String,
+    match_fields: Vec<DuplicateMatchField>,
+    action: DuplicateRuleAction,
+    is_active: bool,
+}
+
+impl DuplicateDetectionRule {
+    /// Creates a validated duplicate rule.
+    pub fn new(
+        entity_logical_name: impl Into<String>,
+        logical_name: impl Into<String>,
+        display_name: impl Into<String>,
+        match_fields: Vec<DuplicateMatchField>,
+        action: DuplicateRuleAction,
+        is_active: bool,
+    ) -> AppResult<Self> {
+        let entity_logical_name = entity_logical_name.into();
+        let logical_name = logical_name.into();
+        let display_name = display_name.into();
+
+        if entity_logical_name.trim().is_empty() || logical_name.trim().is_empty() {
+            return Err(AppError::Validation(
+                "duplicate rule entity and logical name must not be empty".to_owned(),
+            ));
+        }
+        if display_name.trim().is_empty() {
+            return Err(AppError::Validation(
+                "duplicate rule display_name must not be empty".to_owned(),
+            ));
+        }
+        if match_fields.is_empty() {
+            return Err(AppError::Validation(format!(
+                "duplicate rule '{logical_name}' requires at least one match field"
+            )));
+        }
+
+        let mut seen_fields = HashSet::new();
+        for match_field in &match_fields {
+            if match_field.field_logical_name.trim().is_empty() {
+                return Err(AppError::Validation(format!(
+                    "duplicate rule '{logical_name}' declares an empty match field"
+                )));
+            }
+            if !seen_fields.insert(match_field.field_logical_name.as_str()) {
+                return Err(AppError::Validation(format!(
+                    "duplicate rule '{}' matches field '{}' more than once",
+                    logical_name, match_field.field_logical_name
+                )));
+            }
+        }
+
+        Ok(Self {
+            entity_logical_name,
+            logical_name,
+            display_name,
+            match_fields,
+            action,
+            is_active,
+        })
+    }
+
+    /// Returns the entity whose records the rule compares.
+    #[must_use]
+    pub fn entity_logical_name(&self) -> &str {
+        self.entity_logical_name.as_str()
+    }
+
+    /// Returns the rule logical name.
+    #[must_use]
+    pub fn logical_name(&self) -> &str {
+        self.logical_name.as_str()
+    }
+
+    /// Returns the rule display name.
+    #[must_use]
+    pub fn display_name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    /// Returns the compared fields.
+    #[must_use]
+    pub fn match_fields(&self) -> &[DuplicateMatchField] {
+        &self.match_fields
+    }
+
+    /// Returns the action taken on a match.
+    #[must_use]
+    pub fn action(&self) -> DuplicateRuleAction {
+        self.action
+    }
+
+    /// Returns whether the rule is evaluated.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+}
+
+/// Normalizes a text value for fuzzy duplicate comparison.
+///
+/// Letters are lowercased, punctuation is dropped and whitespace runs
+/// collapse to one space.
+#[must_use]
+pub fn normalize_duplicate_text(value: &str) -> String {
+    value
+        .split(|character: char| !character.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Existing record matched by a duplicate rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DuplicateRecordCandidate {
+    /// Matched record.
+    pub record_id: String,
+    /// Rule that matched.
+    pub rule_logical_name: String,
+    /// Action of the matching rule.
+    pub action: DuplicateRuleAction,
+    /// Fields compared by the matching rule.
+    pub matched_fields: Vec<String>,
+}
+
+/// Duplicate records found for a runtime write.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DuplicateRecordsConflict {
+    /// Entity of the written record.
+    pub entity_logical_name: String,
+    /// Strictest action among the matching rules.
+    pub action: DuplicateRuleAction,
+    /// Matched records.
+    pub candidates: Vec<DuplicateRecordCandidate>,
+}
+
+impl DuplicateRecordsConflict {
+    /// Converts the conflict into a conflict error carrying the candidates.
+    #[must_use]
+    pub fn into_error(self) -> AppError {
+        let payload = serde_json::to_string(&self).unwrap_or_default();
+        AppError::Conflict(format!("{DUPLICATE_RECORDS_DETECTED_PREFIX}: {payload}"))
+    }
+
+    /// Parses the payload of a conflict error raised by [`Self::into_error`].
+    #[must_use]
+    pub fn from_error_detail(detail: &str) -> Option<Self> {
+        let payload = detail
+            .strip_prefix(DUPLICATE_RECORDS_DETECTED_PREFIX)?
+            .strip_prefix(": ")?;
+        serde_json::from_str(payload).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qryvanta_core::AppError;
+
+    use super::{
+        DuplicateDetectionRule, DuplicateMatchField, DuplicateMatchMode, DuplicateRecordCandidate,
+        DuplicateRecordsConflict, DuplicateRuleAction, normalize_duplicate_text,
+    };
+
+    fn match_field(field_logical_name: &str) -> DuplicateMatchField {
+        DuplicateMatchField {
+            field_logical_name: field_logical_name.to_owned(),
+            match_mode: DuplicateMatchMode::Exact,
+        }
+    }
+
+    #[test]
+    fn rejects_rules_without_distinct_match_fields() {
+        assert!(
+            DuplicateDetectionRule::new(
+                "account",
+                "same_name",
+                "Same name",
+                Vec::new(),
+                DuplicateRuleAction::Warn,
+                true,
+            )
+            .is_err()
+        );
+        assert!(
+            DuplicateDetectionRule::new(
+                "account",
+                "same_name",
+                "Same name",
+                vec![match_field("name"), match_field("name")],
+                DuplicateRuleAction::Warn,
+                true,
+            )
+            .is_err()
+        );
+        assert!(
+            DuplicateDetectionRule::new(
+                "account",
+                "same_name",
+                "Same name",
+                vec![match_field("name")],
+                DuplicateRuleAction::Block,
+                true,
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn fuzzy_normalization_ignores_case_punctuation_and_spacing() {
+        assert_eq!(normalize_duplicate_text("  ACME,  Corp. "), "acme corp");
+        assert_eq!(
+            normalize_duplicate_text("acme corp"),
+            normalize_duplicate_text("Acme-Corp")
+        );
+    }
+
+    #[test]
+    fn conflict_payload_round_trips_through_error_detail() {
+        let conflict = DuplicateRecordsConflict {
+            entity_logical_name: "account".to_owned(),
+            action: DuplicateRuleAction::Warn,
+            candidates: vec![DuplicateRecordCandidate {
+                record_id: "record-1".to_owned(),
+                rule_logical_name: "same_name".to_owned(),
+                action: DuplicateRuleAction::Warn,
+                matched_fields: vec!["name".to_owned()],
+            }],
+        };
+
+        let AppError::Conflict(detail) = conflict.clone().into_error() else {
+            unreachable!();
+        };
+        assert_eq!(
+            DuplicateRecordsConflict::from_error_detail(detail.as_str()),
+            Some(conflict)
+        );
+        assert_eq!(
+            DuplicateRecordsConflict::from_error_detail("record was modified"),
+            None
+        );
+    }
+}
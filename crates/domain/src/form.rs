@@ -394,6 +394,107 @@ impl FormTab {
     }
 }
 
+/// Maximum number of entries per form scripting event list.
+pub const FORM_SCRIPT_EVENT_MAX_ENTRIES: usize = 25;
+
+/// Client scripting hooks declared for a form.
+///
+/// Every client runs the same declared hooks: load and save hooks reference
+/// custom actions by logical name and change hooks list the fields whose edits
+/// raise a change event.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormScriptEvents {
+    #[serde(default)]
+    on_load_actions: Vec<String>,
+    #[serde(default)]
+    on_change_fields: Vec<String>,
+    #[serde(default)]
+    on_save_validators: Vec<String>,
+}
+
+impl FormScriptEvents {
+    /// Creates validated form scripting hooks.
+    pub fn new(
+        on_load_actions: Vec<String>,
+        on_change_fields: Vec<String>,
+        on_save_validators: Vec<String>,
+    ) -> AppResult<Self> {
+        Ok(Self {
+            on_load_actions: normalize_script_event_entries("on_load_actions", on_load_actions)?,
+            on_change_fields: normalize_script_event_entries("on_change_fields", on_change_fields)?,
+            on_save_validators: normalize_script_event_entries(
+                "on_save_validators",
+                on_save_validators,
+            )?,
+        })
+    }
+
+    /// Returns custom actions run when the form loads, in order.
+    #[must_use]
+    pub fn on_load_actions(&self) -> &[String] {
+        &self.on_load_actions
+    }
+
+    /// Returns fields whose edits raise a change event.
+    #[must_use]
+    pub fn on_change_fields(&self) -> &[String] {
+        &self.on_change_fields
+    }
+
+    /// Returns custom actions that must accept the record before it is saved, in order.
+    #[must_use]
+    pub fn on_save_validators(&self) -> &[String] {
+        &self.on_save_validators
+    }
+
+    /// Returns every referenced custom action without duplicates.
+    #[must_use]
+    pub fn referenced_actions(&self) -> Vec<&str> {
+        let mut seen = HashSet::new();
+        self.on_load_actions
+            .iter()
+            .chain(&self.on_save_validators)
+            .map(String::as_str)
+            .filter(|action| seen.insert(*action))
+            .collect()
+    }
+
+    /// Returns whether no hooks are declared.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.on_load_actions.is_empty()
+            && self.on_change_fields.is_empty()
+            && self.on_save_validators.is_empty()
+    }
+}
+
+fn normalize_script_event_entries(event: &str, entries: Vec<String>) -> AppResult<Vec<String>> {
+    if entries.len() > FORM_SCRIPT_EVENT_MAX_ENTRIES {
+        return Err(AppError::Validation(format!(
+            "form script event '{event}' supports at most {FORM_SCRIPT_EVENT_MAX_ENTRIES} entries"
+        )));
+    }
+
+    let mut normalized = Vec::with_capacity(entries.len());
+    let mut seen = HashSet::new();
+    for entry in entries {
+        let trimmed = entry.trim().to_owned();
+        if trimmed.is_empty() {
+            return Err(AppError::Validation(format!(
+                "form script event '{event}' cannot contain empty names"
+            )));
+        }
+        if !seen.insert(trimmed.clone()) {
+            return Err(AppError::Validation(format!(
+                "duplicate entry '{trimmed}' in form script event '{event}'"
+            )));
+        }
+        normalized.push(trimmed);
+    }
+
+    Ok(normalized)
+}
+
 /// Standalone entity form definition.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FormDefinition {
@@ -403,6 +504,8 @@ pub struct FormDefinition {
     form_type: FormType,
     tabs: Vec<FormTab>,
     header_fields: Vec<String>,
+    #[serde(default)]
+    script_events: FormScriptEvents,
 }
 
 impl FormDefinition {
@@ -491,9 +594,17 @@ impl FormDefinition {
             form_type,
             tabs: sorted_tabs,
             header_fields: normalized_header_fields,
+            script_events: FormScriptEvents::default(),
         })
     }
 
+    /// Returns a copy with the given client scripting hooks.
+    #[must_use]
+    pub fn with_script_events(mut self, script_events: FormScriptEvents) -> Self {
+        self.script_events = script_events;
+        self
+    }
+
     /// Returns parent entity logical name.
     #[must_use]
     pub fn entity_logical_name(&self) -> &NonEmptyString {
@@ -529,6 +640,12 @@ impl FormDefinition {
     pub fn header_fields(&self) -> &[String] {
         &self.header_fields
     }
+
+    /// Returns declared client scripting hooks.
+    #[must_use]
+    pub fn script_events(&self) -> &FormScriptEvents {
+        &self.script_events
+    }
 }
 
 fn positions_are_contiguous(mut positions: Vec<i32>) -> bool {
@@ -543,7 +660,10 @@ fn positions_are_contiguous(mut positions: Vec<i32>) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::{FormDefinition, FormFieldPlacement, FormSection, FormSubgrid, FormTab, FormType};
+    use super::{
+        FormDefinition, FormFieldPlacement, FormScriptEvents, FormSection, FormSubgrid, FormTab,
+        FormType,
+    };
 
     #[test]
     fn form_field_placement_rejects_negative_position() {
@@ -754,4 +874,56 @@ mod tests {
             .collect();
         assert_eq!(tab_order, vec!["early", "late"]);
     }
+
+    #[test]
+    fn form_script_events_normalize_and_reject_duplicates() {
+        let events = FormScriptEvents::new(
+            vec![" prefill_defaults ".to_owned()],
+            vec!["status".to_owned(), "owner_id".to_owned()],
+            vec!["check_credit".to_owned(), "prefill_defaults".to_owned()],
+        )
+        .unwrap_or_else(|_| unreachable!());
+        assert_eq!(events.on_load_actions(), ["prefill_defaults"]);
+        assert_eq!(
+            events.referenced_actions(),
+            vec!["prefill_defaults", "check_credit"]
+        );
+
+        let duplicate = FormScriptEvents::new(
+            Vec::new(),
+            vec!["status".to_owned(), " status".to_owned()],
+            Vec::new(),
+        );
+        assert!(duplicate.is_err());
+
+        let empty = FormScriptEvents::new(Vec::new(), Vec::new(), vec![" ".to_owned()]);
+        assert!(empty.is_err());
+    }
+
+    #[test]
+    fn form_without_script_events_deserializes_with_empty_hooks() {
+        let placement = FormFieldPlacement::new("name", 0, 0, true, false, None, None)
+            .unwrap_or_else(|_| unreachable!());
+        let section = FormSection::new("main", "Main", 0, true, 1, vec![placement], Vec::new())
+            .unwrap_or_else(|_| unreachable!());
+        let tab = FormTab::new("general", "General", 0, true, vec![section])
+            .unwrap_or_else(|_| unreachable!());
+        let form = FormDefinition::new(
+            "contact",
+            "main_form",
+            "Main Form",
+            FormType::Main,
+            vec![tab],
+            Vec::new(),
+        )
+        .unwrap_or_else(|_| unreachable!());
+
+        let mut form_json = serde_json::to_value(&form).unwrap_or_else(|_| unreachable!());
+        if let Some(object) = form_json.as_object_mut() {
+            object.remove("script_events");
+        }
+        let parsed: FormDefinition =
+            serde_json::from_value(form_json).unwrap_or_else(|_| unreachable!());
+        assert!(parsed.script_events().is_empty());
+    }
 }
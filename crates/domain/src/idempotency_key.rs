@@ -0,0 +1,49 @@
+use qryvanta_core::{AppError, AppResult};
+
+/// Maximum length of a client or trigger supplied idempotency key.
+pub const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
+
+/// Validates that an idempotency key is 1-255 visible ASCII characters.
+///
+/// Keys are compared verbatim, so surrounding whitespace is rejected rather
+/// than trimmed away.
+pub fn validate_idempotency_key(value: &str) -> AppResult<()> {
+    let well_formed = !value.is_empty()
+        && value.len() <= IDEMPOTENCY_KEY_MAX_LENGTH
+        && value.chars().all(|character| character.is_ascii_graphic());
+
+    if !well_formed {
+        return Err(AppError::Validation(format!(
+            "idempotency key must be 1-{IDEMPOTENCY_KEY_MAX_LENGTH} visible ASCII characters"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IDEMPOTENCY_KEY_MAX_LENGTH, validate_idempotency_key};
+
+    #[test]
+    fn validate_idempotency_key_accepts_visible_ascii() {
+        assert!(validate_idempotency_key("7f9c2ba4-e88f-11ee-a1b2-0242ac120002").is_ok());
+        assert!(validate_idempotency_key("order:42/retry=1").is_ok());
+        assert!(validate_idempotency_key(&"k".repeat(IDEMPOTENCY_KEY_MAX_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn validate_idempotency_key_rejects_malformed_values() {
+        let too_long = "k".repeat(IDEMPOTENCY_KEY_MAX_LENGTH + 1);
+        for value in [
+            "",
+            " key",
+            "key ",
+            "two words",
+            "schlüssel",
+            too_long.as_str(),
+        ] {
+            assert!(validate_idempotency_key(value).is_err(), "{value}");
+        }
+    }
+}
@@ -4,14 +4,26 @@
 
 mod app;
 mod business_rule;
+mod cron;
 mod dashboard;
+mod data_classification;
+mod duplicate_detection;
 mod extension;
 mod form;
+mod idempotency_key;
+mod localization;
 mod metadata;
+mod payload_schema;
+mod record_slug;
+mod record_status;
+mod relation_behavior;
 mod security;
+mod system_field;
+mod team;
 mod user;
 mod view;
 mod workflow;
+mod workflow_credential;
 
 pub use app::{
     AppDefinition, AppEntityAction, AppEntityBinding, AppEntityForm, AppEntityRolePermission,
@@ -22,29 +34,66 @@ pub use business_rule::{
     BusinessRuleAction, BusinessRuleActionType, BusinessRuleCondition, BusinessRuleDefinition,
     BusinessRuleDefinitionInput, BusinessRuleOperator, BusinessRuleScope,
 };
+pub use cron::CronSchedule;
 pub use dashboard::{
     ChartAggregation, ChartDefinition, ChartType, DashboardDefinition, DashboardWidget,
 };
+pub use data_classification::{
+    FieldClassification, FieldMaskingMode, MASKED_VALUE_PLACEHOLDER,
+    PARTIAL_MASK_VISIBLE_CHARACTERS,
+};
+pub use duplicate_detection::{
+    DUPLICATE_RECORDS_DETECTED_PREFIX, DuplicateDetectionRule, DuplicateMatchField,
+    DuplicateMatchMode, DuplicateRecordCandidate, DuplicateRecordsConflict, DuplicateRuleAction,
+    normalize_duplicate_text,
+};
 pub use extension::{
     ExtensionCapability, ExtensionDefinition, ExtensionIsolationPolicy, ExtensionLifecycleState,
     ExtensionManifest, ExtensionManifestInput, ExtensionRuntimeKind,
 };
-pub use form::{FormDefinition, FormFieldPlacement, FormSection, FormSubgrid, FormTab, FormType};
+pub use form::{
+    FORM_SCRIPT_EVENT_MAX_ENTRIES, FormDefinition, FormFieldPlacement, FormScriptEvents,
+    FormSection, FormSubgrid, FormTab, FormType,
+};
+pub use idempotency_key::{IDEMPOTENCY_KEY_MAX_LENGTH, validate_idempotency_key};
+pub use localization::{
+    LOCALIZED_DESCRIPTION_MAX_LENGTH, LOCALIZED_DISPLAY_NAME_MAX_LENGTH, LocaleTag,
+    LocalizedComponentType, LocalizedDisplayLabel, select_localized_display_label,
+};
 pub use metadata::{
-    EntityDefinition, EntityFieldDefinition, EntityFieldMutableUpdateInput, FieldType,
-    OptionSetDefinition, OptionSetItem, PublishedEntitySchema, RuntimeRecord,
+    ENTITY_ICON_CATALOG, EntityDefinition, EntityFieldDefinition, EntityFieldMutableUpdateInput,
+    FieldType, OptionSetDefinition, OptionSetItem, PublishedEntitySchema, RuntimeRecord,
+    is_known_entity_icon,
 };
-pub use security::{AuditAction, AuthEventOutcome, AuthEventType, Permission, Surface};
+pub use payload_schema::PayloadSchema;
+pub use record_slug::{RECORD_SLUG_MAX_LENGTH, slugify, validate_record_slug};
+pub use record_status::{
+    RECORD_INACTIVE_PREFIX, RECORD_STATUS_TRANSITION_INVALID_PREFIX, RecordStatusModel,
+    RecordStatusOption, RecordStatusTransition,
+};
+pub use relation_behavior::{RelationCascadeBehavior, RelationDeleteBehavior};
+pub use security::{
+    AuditAction, AuthEventOutcome, AuthEventType, Capability, Permission, PermissionGroup, Surface,
+};
+pub use system_field::SystemField;
+pub use team::{SubjectType, TEAM_NAME_MAX_LENGTH, TeamDefinition};
 pub use user::{
     AuthTokenType, EmailAddress, PASSWORD_MAX_LENGTH, PASSWORD_MIN_LENGTH_WITH_MFA,
     PASSWORD_MIN_LENGTH_WITHOUT_MFA, RegistrationMode, UserId, validate_password,
 };
 pub use view::{
-    FilterOperator, LogicalMode, SortDirection, ViewColumn, ViewDefinition, ViewFilterCondition,
-    ViewFilterGroup, ViewSort, ViewType,
+    FilterOperator, LogicalMode, SortDirection, SubjectFilterToken, ViewColumn, ViewDefinition,
+    ViewFilterCondition, ViewFilterGroup, ViewSort, ViewType,
 };
 pub use workflow::{
-    WorkflowConditionOperator, WorkflowDefinition, WorkflowDefinitionInput, WorkflowLifecycleState,
-    WorkflowStep, WorkflowTrigger, is_sensitive_workflow_header_name,
-    redact_sensitive_workflow_headers, redact_workflow_header_secret_refs,
+    WORKFLOW_INVOCATION_MAX_DEPTH, WorkflowConditionOperator, WorkflowDefinition,
+    WorkflowDefinitionInput, WorkflowHttpRetryPolicy, WorkflowInvocationMode,
+    WorkflowLifecycleState, WorkflowRelatedFieldPath, WorkflowRunPriority, WorkflowStep,
+    WorkflowStepBackoffStrategy, WorkflowStepRetryErrorClass, WorkflowStepRetryPolicy,
+    WorkflowTrigger, WorkflowTriggerFilter, WorkflowTriggerFilterOperator,
+    is_sensitive_workflow_header_name, redact_sensitive_workflow_headers,
+    redact_workflow_header_secret_refs,
+};
+pub use workflow_credential::{
+    WORKFLOW_CREDENTIAL_MAX_ALLOWED_HOSTS, WorkflowCredential, WorkflowCredentialAuth,
 };
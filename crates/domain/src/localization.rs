@@ -0,0 +1,330 @@
+use std::str::FromStr;
+
+use qryvanta_core::{AppError, AppResult, NonEmptyString};
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of a localized display name.
+pub const LOCALIZED_DISPLAY_NAME_MAX_LENGTH: usize = 200;
+
+/// Maximum length of a localized description.
+pub const LOCALIZED_DESCRIPTION_MAX_LENGTH: usize = 2000;
+
+/// Metadata component kinds that carry localized display labels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LocalizedComponentType {
+    /// Workflow definitions, keyed by workflow logical name.
+    Workflow,
+    /// Business rules, keyed by `<entity>.<rule>` logical names.
+    BusinessRule,
+    /// Security roles, keyed by role name.
+    Role,
+    /// Apps, keyed by app logical name.
+    App,
+}
+
+impl LocalizedComponentType {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Workflow => "workflow",
+            Self::BusinessRule => "business_rule",
+            Self::Role => "role",
+            Self::App => "app",
+        }
+    }
+
+    /// Builds the component key of a business rule.
+    #[must_use]
+    pub fn business_rule_key(entity_logical_name: &str, rule_logical_name: &str) -> String {
+        format!("{entity_logical_name}.{rule_logical_name}")
+    }
+}
+
+impl FromStr for LocalizedComponentType {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "workflow" => Ok(Self::Workflow),
+            "business_rule" => Ok(Self::BusinessRule),
+            "role" => Ok(Self::Role),
+            "app" => Ok(Self::App),
+            _ => Err(AppError::Validation(format!(
+                "unknown localized component type '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Normalized BCP 47 language tag such as `de` or `fr-CA`.
+///
+/// The primary language subtag is stored lowercase and two-letter region
+/// subtags uppercase, so `FR-ca` and `fr-CA` address the same translation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct LocaleTag(String);
+
+impl LocaleTag {
+    /// Parses and normalizes a language tag.
+    pub fn new(value: impl AsRef<str>) -> AppResult<Self> {
+        let value = value.as_ref().trim();
+        let invalid =
+            || AppError::Validation(format!("'{value}' is not a valid BCP 47 language tag"));
+
+        let mut subtags = value.split(['-', '_']);
+        let language = subtags.next().unwrap_or_default();
+        if !(2..=3).contains(&language.len())
+            || !language
+                .chars()
+                .all(|character| character.is_ascii_alphabetic())
+        {
+            return Err(invalid());
+        }
+
+        let mut normalized = language.to_ascii_lowercase();
+        for subtag in subtags {
+            if subtag.is_empty()
+                || subtag.len() > 8
+                || !subtag
+                    .chars()
+                    .all(|character| character.is_ascii_alphanumeric())
+            {
+                return Err(invalid());
+            }
+
+            normalized.push('-');
+            if subtag.len() == 2
+                && subtag
+                    .chars()
+                    .all(|character| character.is_ascii_alphabetic())
+            {
+                normalized.push_str(subtag.to_ascii_uppercase().as_str());
+            } else {
+                normalized.push_str(subtag.to_ascii_lowercase().as_str());
+            }
+        }
+
+        Ok(Self(normalized))
+    }
+
+    /// Returns the normalized tag.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns the primary language subtag, e.g. `fr` for `fr-CA`.
+    #[must_use]
+    pub fn language(&self) -> &str {
+        self.0.split('-').next().unwrap_or_default()
+    }
+}
+
+impl TryFrom<String> for LocaleTag {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<LocaleTag> for String {
+    fn from(value: LocaleTag) -> Self {
+        value.0
+    }
+}
+
+/// Localized display name and description of one metadata component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocalizedDisplayLabel {
+    component_type: LocalizedComponentType,
+    component_key: NonEmptyString,
+    locale: LocaleTag,
+    display_name: NonEmptyString,
+    description: Option<String>,
+}
+
+impl LocalizedDisplayLabel {
+    /// Creates a validated localized label.
+    pub fn new(
+        component_type: LocalizedComponentType,
+        component_key: impl Into<String>,
+        locale: LocaleTag,
+        display_name: impl Into<String>,
+        description: Option<String>,
+    ) -> AppResult<Self> {
+        let display_name = NonEmptyString::new(display_name.into().trim())?;
+        if display_name.as_str().chars().count() > LOCALIZED_DISPLAY_NAME_MAX_LENGTH {
+            return Err(AppError::Validation(format!(
+                "localized display name must be at most {LOCALIZED_DISPLAY_NAME_MAX_LENGTH} characters"
+            )));
+        }
+
+        let description = description.and_then(|value| {
+            let trimmed = value.trim().to_owned();
+            (!trimmed.is_empty()).then_some(trimmed)
+        });
+        if description
+            .as_ref()
+            .is_some_and(|value| value.chars().count() > LOCALIZED_DESCRIPTION_MAX_LENGTH)
+        {
+            return Err(AppError::Validation(format!(
+                "localized description must be at most {LOCALIZED_DESCRIPTION_MAX_LENGTH} characters"
+            )));
+        }
+
+        Ok(Self {
+            component_type,
+            component_key: NonEmptyString::new(component_key)?,
+            locale,
+            display_name,
+            description,
+        })
+    }
+
+    /// Returns the localized component kind.
+    #[must_use]
+    pub fn component_type(&self) -> LocalizedComponentType {
+        self.component_type
+    }
+
+    /// Returns the component key.
+    #[must_use]
+    pub fn component_key(&self) -> &NonEmptyString {
+        &self.component_key
+    }
+
+    /// Returns the label locale.
+    #[must_use]
+    pub fn locale(&self) -> &LocaleTag {
+        &self.locale
+    }
+
+    /// Returns the localized display name.
+    #[must_use]
+    pub fn display_name(&self) -> &NonEmptyString {
+        &self.display_name
+    }
+
+    /// Returns the optional localized description.
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+}
+
+/// Picks the label that best matches a caller's ordered locale preferences.
+///
+/// Each preference first matches its exact tag and then any label sharing
+/// its primary language, so `de-CH` falls back to `de` or `de-DE` before the
+/// next preference is considered.
+#[must_use]
+pub fn select_localized_display_label<'a>(
+    labels: &'a [LocalizedDisplayLabel],
+    preferred_locales: &[LocaleTag],
+) -> Option<&'a LocalizedDisplayLabel> {
+    preferred_locales.iter().find_map(|preferred| {
+        labels
+            .iter()
+            .find(|label| label.locale() == preferred)
+            .or_else(|| {
+                labels
+                    .iter()
+                    .find(|label| label.locale().language() == preferred.language())
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(locale: &str, display_name: &str) -> LocalizedDisplayLabel {
+        LocalizedDisplayLabel::new(
+            LocalizedComponentType::Workflow,
+            "notify_owner",
+            LocaleTag::new(locale).unwrap_or_else(|_| unreachable!()),
+            display_name,
+            None,
+        )
+        .unwrap_or_else(|_| unreachable!())
+    }
+
+    #[test]
+    fn locale_tags_are_normalized() {
+        let tag = LocaleTag::new("FR_ca").unwrap_or_else(|_| unreachable!());
+        assert_eq!(tag.as_str(), "fr-CA");
+        assert_eq!(tag.language(), "fr");
+
+        let script = LocaleTag::new("zh-Hant-TW").unwrap_or_else(|_| unreachable!());
+        assert_eq!(script.as_str(), "zh-hant-TW");
+
+        assert!(LocaleTag::new("").is_err());
+        assert!(LocaleTag::new("english").is_err());
+        assert!(LocaleTag::new("en--US").is_err());
+        assert!(LocaleTag::new("*").is_err());
+    }
+
+    #[test]
+    fn labels_reject_blank_or_oversized_names() {
+        let locale = LocaleTag::new("de").unwrap_or_else(|_| unreachable!());
+        assert!(
+            LocalizedDisplayLabel::new(
+                LocalizedComponentType::App,
+                "sales",
+                locale.clone(),
+                "  ",
+                None
+            )
+            .is_err()
+        );
+        assert!(
+            LocalizedDisplayLabel::new(
+                LocalizedComponentType::App,
+                "sales",
+                locale.clone(),
+                "x".repeat(LOCALIZED_DISPLAY_NAME_MAX_LENGTH + 1),
+                None
+            )
+            .is_err()
+        );
+
+        let label = LocalizedDisplayLabel::new(
+            LocalizedComponentType::App,
+            "sales",
+            locale,
+            " Vertrieb ",
+            Some("   ".to_owned()),
+        )
+        .unwrap_or_else(|_| unreachable!());
+        assert_eq!(label.display_name().as_str(), "Vertrieb");
+        assert_eq!(label.description(), None);
+    }
+
+    #[test]
+    fn selection_prefers_exact_tags_then_primary_language() {
+        let labels = vec![
+            label("de-DE", "Besitzer benachrichtigen"),
+            label("fr", "Avertir"),
+        ];
+        let preferences = |tags: &[&str]| {
+            tags.iter()
+                .map(|tag| LocaleTag::new(tag).unwrap_or_else(|_| unreachable!()))
+                .collect::<Vec<_>>()
+        };
+
+        let selected = select_localized_display_label(&labels, &preferences(&["fr-CA", "de"]));
+        assert_eq!(
+            selected.map(|label| label.display_name().as_str()),
+            Some("Avertir")
+        );
+
+        let selected = select_localized_display_label(&labels, &preferences(&["de-CH"]));
+        assert_eq!(selected.map(|label| label.locale().as_str()), Some("de-DE"));
+
+        assert!(select_localized_display_label(&labels, &preferences(&["ja"])).is_none());
+        assert!(select_localized_display_label(&labels, &[]).is_none());
+    }
+}
@@ -5,6 +5,8 @@ use qryvanta_core::{AppError, AppResult, NonEmptyString};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::{FieldClassification, SystemField};
+
 /// Metadata definition for a business entity.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EntityDefinition {
@@ -13,6 +15,14 @@ pub struct EntityDefinition {
     description: Option<String>,
     plural_display_name: Option<NonEmptyString>,
     icon: Option<String>,
+    #[serde(default)]
+    color: Option<String>,
+    #[serde(default = "entity_is_active_by_default")]
+    is_active: bool,
+}
+
+fn entity_is_active_by_default() -> bool {
+    true
 }
 
 impl EntityDefinition {
@@ -21,16 +31,19 @@ impl EntityDefinition {
         logical_name: impl Into<String>,
         display_name: impl Into<String>,
     ) -> AppResult<Self> {
-        Self::new_with_details(logical_name, display_name, None, None, None)
+        Self::new_with_details(logical_name, display_name, None, None, None, None)
     }
 
     /// Creates a new entity definition with optional enriched metadata fields.
+    ///
+    /// Colors must be `#rrggbb` hex values and are stored in lowercase.
     pub fn new_with_details(
         logical_name: impl Into<String>,
         display_name: impl Into<String>,
         description: Option<String>,
         plural_display_name: Option<String>,
         icon: Option<String>,
+        color: Option<String>,
     ) -> AppResult<Self> {
         Ok(Self {
             logical_name: NonEmptyString::new(logical_name)?,
@@ -41,6 +54,10 @@ impl EntityDefinition {
                 .map(NonEmptyString::new)
                 .transpose()?,
             icon: normalize_optional_text(icon),
+            color: normalize_optional_text(color)
+                .map(normalize_entity_color)
+                .transpose()?,
+            is_active: true,
         })
     }
 
@@ -74,6 +91,36 @@ impl EntityDefinition {
         self.icon.as_deref()
     }
 
+    /// Returns whether the icon key is part of the entity icon catalog.
+    ///
+    /// Entities without an icon are considered valid.
+    #[must_use]
+    pub fn has_known_icon(&self) -> bool {
+        self.icon.as_deref().is_none_or(is_known_entity_icon)
+    }
+
+    /// Returns optional accent color as a lowercase `#rrggbb` value.
+    #[must_use]
+    pub fn color(&self) -> Option<&str> {
+        self.color.as_deref()
+    }
+
+    /// Returns whether runtime records of the entity can still be written.
+    ///
+    /// Deactivated entities stay readable but reject record creates and
+    /// updates until they are reactivated or deleted.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    /// Returns a copy with the given lifecycle state.
+    #[must_use]
+    pub fn with_active(mut self, is_active: bool) -> Self {
+        self.is_active = is_active;
+        self
+    }
+
     /// Returns a copy with updated mutable metadata fields.
     pub fn with_updates(
         &self,
@@ -81,6 +128,7 @@ impl EntityDefinition {
         description: Option<String>,
         plural_display_name: Option<String>,
         icon: Option<String>,
+        color: Option<String>,
     ) -> AppResult<Self> {
         Self::new_with_details(
             self.logical_name.as_str(),
@@ -88,10 +136,101 @@ impl EntityDefinition {
             description,
             plural_display_name,
             icon,
+            color,
         )
+        .map(|entity| entity.with_active(self.is_active))
     }
 }
 
+/// Icon keys that workspaces can render for entities.
+///
+/// Keys follow the kebab-case names of the icon set bundled with the web app.
+pub const ENTITY_ICON_CATALOG: &[&str] = &[
+    "activity",
+    "archive",
+    "badge-check",
+    "banknote",
+    "bell",
+    "book-open",
+    "bookmark",
+    "box",
+    "briefcase",
+    "building",
+    "building-2",
+    "calendar",
+    "car",
+    "chart-bar",
+    "chart-pie",
+    "circle-dollar-sign",
+    "clipboard-list",
+    "clock",
+    "contact",
+    "credit-card",
+    "database",
+    "file",
+    "file-text",
+    "flag",
+    "folder",
+    "gift",
+    "globe",
+    "graduation-cap",
+    "hammer",
+    "handshake",
+    "heart",
+    "home",
+    "inbox",
+    "key",
+    "landmark",
+    "layers",
+    "layout-dashboard",
+    "life-buoy",
+    "lightbulb",
+    "list-checks",
+    "mail",
+    "map-pin",
+    "megaphone",
+    "message-square",
+    "package",
+    "phone",
+    "receipt",
+    "rocket",
+    "scale",
+    "settings",
+    "shield",
+    "shopping-cart",
+    "star",
+    "store",
+    "tag",
+    "target",
+    "ticket",
+    "truck",
+    "user",
+    "users",
+    "wallet",
+    "wrench",
+];
+
+/// Returns whether the icon key is part of [`ENTITY_ICON_CATALOG`].
+#[must_use]
+pub fn is_known_entity_icon(icon: &str) -> bool {
+    ENTITY_ICON_CATALOG.contains(&icon)
+}
+
+fn normalize_entity_color(color: String) -> AppResult<String> {
+    let is_hex_color = color.len() == 7
+        && color.starts_with('#')
+        && color[1..]
+            .chars()
+            .all(|character| character.is_ascii_hexdigit());
+    if !is_hex_color {
+        return Err(AppError::Validation(format!(
+            "entity color '{color}' must be a hex value like '#1f6feb'"
+        )));
+    }
+
+    Ok(color.to_ascii_lowercase())
+}
+
 /// Supported metadata field types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -114,6 +253,8 @@ pub enum FieldType {
     MultiChoice,
     /// Many-to-one relation field.
     Relation,
+    /// Many-to-many relation field stored as record associations.
+    ManyToMany,
 }
 
 impl FieldType {
@@ -130,10 +271,23 @@ impl FieldType {
             Self::Choice => "choice",
             Self::MultiChoice => "multichoice",
             Self::Relation => "relation",
+            Self::ManyToMany => "many_to_many",
         }
     }
 
+    /// Returns whether the field type points at records of another entity.
+    #[must_use]
+    pub fn is_relation(self) -> bool {
+        matches!(self, Self::Relation | Self::ManyToMany)
+    }
+
     fn validate_value(self, value: &Value) -> AppResult<()> {
+        if self == Self::ManyToMany {
+            return Err(AppError::Validation(
+                "many_to_many field values are managed through record associations".to_owned(),
+            ));
+        }
+
         let is_valid = match self {
             Self::Text | Self::Date | Self::DateTime => value.is_string(),
             Self::Number => value.is_number(),
@@ -145,6 +299,7 @@ impl FieldType {
                 .as_str()
                 .map(|text| !text.trim().is_empty())
                 .unwrap_or(false),
+            Self::ManyToMany => false,
         };
 
         if !is_valid {
@@ -172,6 +327,7 @@ impl FromStr for FieldType {
             "choice" => Ok(Self::Choice),
             "multichoice" => Ok(Self::MultiChoice),
             "relation" => Ok(Self::Relation),
+            "many_to_many" => Ok(Self::ManyToMany),
             _ => Err(AppError::Validation(format!(
                 "unknown field type '{value}'"
             ))),
@@ -318,6 +474,8 @@ pub struct EntityFieldDefinition {
     max_length: Option<i32>,
     min_value: Option<f64>,
     max_value: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    classification: Option<FieldClassification>,
 }
 
 /// Input payload for updating mutable metadata field attributes.
@@ -337,6 +495,8 @@ pub struct EntityFieldMutableUpdateInput {
     pub min_value: Option<f64>,
     /// Optional number maximum value constraint.
     pub max_value: Option<f64>,
+    /// Optional data classification.
+    pub classification: Option<FieldClassification>,
 }
 
 impl EntityFieldDefinition {
@@ -435,19 +595,25 @@ impl EntityFieldDefinition {
             .map(NonEmptyString::new)
             .transpose()?;
 
-        match (field_type, relation_target_entity.is_some()) {
-            (FieldType::Relation, false) => {
+        match (field_type.is_relation(), relation_target_entity.is_some()) {
+            (true, false) => {
                 return Err(AppError::Validation(
                     "relation fields require relation_target_entity".to_owned(),
                 ));
             }
-            (FieldType::Relation, true) => {}
-            (_, true) => {
+            (true, true) => {}
+            (false, true) => {
                 return Err(AppError::Validation(
                     "relation_target_entity is only allowed for relation fields".to_owned(),
                 ));
             }
-            (_, false) => {}
+            (false, false) => {}
+        }
+
+        if field_type == FieldType::ManyToMany && (is_required || is_unique) {
+            return Err(AppError::Validation(
+                "many_to_many fields cannot be required or unique".to_owned(),
+            ));
         }
 
         match (field_type, option_set_logical_name.is_some()) {
@@ -546,9 +712,17 @@ impl EntityFieldDefinition {
             max_length,
             min_value,
             max_value,
+            classification: None,
         })
     }
 
+    /// Returns a copy tagged with a data classification.
+    #[must_use]
+    pub fn with_classification(mut self, classification: Option<FieldClassification>) -> Self {
+        self.classification = classification;
+        self
+    }
+
     /// Returns the field's parent entity logical name.
     #[must_use]
     pub fn entity_logical_name(&self) -> &NonEmptyString {
@@ -633,6 +807,12 @@ impl EntityFieldDefinition {
         self.max_value
     }
 
+    /// Returns the field's data classification.
+    #[must_use]
+    pub fn classification(&self) -> Option<FieldClassification> {
+        self.classification
+    }
+
     /// Returns a copy with updated mutable metadata fields.
     pub fn with_mutable_updates(
         &self,
@@ -660,6 +840,7 @@ impl EntityFieldDefinition {
             min_value,
             max_value,
         )
+        .map(|field| field.with_classification(self.classification))
     }
 
     /// Returns a copy with updated mutable metadata fields and calculation expression.
@@ -675,6 +856,7 @@ impl EntityFieldDefinition {
             max_length,
             min_value,
             max_value,
+            classification,
         } = input;
 
         Self::new_with_details_and_calculation(
@@ -695,6 +877,7 @@ impl EntityFieldDefinition {
             min_value,
             max_value,
         )
+        .map(|field| field.with_classification(classification))
     }
 
     /// Validates a runtime value against this field definition.
@@ -836,6 +1019,50 @@ impl PublishedEntitySchema {
     pub fn option_sets(&self) -> &[OptionSetDefinition] {
         &self.option_sets
     }
+
+    /// Returns the system fields exposed by this schema.
+    ///
+    /// A published field with the same logical name takes precedence, so
+    /// schemas that defined one of the names before it was reserved keep
+    /// their own field.
+    #[must_use]
+    pub fn system_fields(&self) -> Vec<SystemField> {
+        SystemField::ALL
+            .into_iter()
+            .filter(|system_field| {
+                !self
+                    .fields
+                    .iter()
+                    .any(|field| field.logical_name().as_str() == system_field.as_str())
+            })
+            .collect()
+    }
+
+    /// Returns published fields followed by read-only system field definitions.
+    ///
+    /// Many-to-many fields hold no record value and are traversed through
+    /// query links instead.
+    pub fn queryable_fields(&self) -> AppResult<Vec<EntityFieldDefinition>> {
+        let entity_logical_name = self.entity.logical_name().as_str();
+        let mut fields: Vec<_> = self
+            .fields
+            .iter()
+            .filter(|field| field.field_type() != FieldType::ManyToMany)
+            .cloned()
+            .collect();
+        for system_field in self.system_fields() {
+            fields.push(system_field.field_definition(entity_logical_name)?);
+        }
+
+        Ok(fields)
+    }
+}
+
+/// Version assigned to a runtime record when it is created.
+const RUNTIME_RECORD_INITIAL_VERSION: i64 = 1;
+
+fn initial_runtime_record_version() -> i64 {
+    RUNTIME_RECORD_INITIAL_VERSION
 }
 
 /// Runtime record payload persisted for an entity.
@@ -844,6 +1071,8 @@ pub struct RuntimeRecord {
     record_id: NonEmptyString,
     entity_logical_name: NonEmptyString,
     data: Value,
+    #[serde(default = "initial_runtime_record_version")]
+    version: i64,
 }
 
 impl RuntimeRecord {
@@ -863,9 +1092,17 @@ impl RuntimeRecord {
             record_id: NonEmptyString::new(record_id)?,
             entity_logical_name: NonEmptyString::new(entity_logical_name)?,
             data,
+            version: RUNTIME_RECORD_INITIAL_VERSION,
         })
     }
 
+    /// Sets the stored record version used for optimistic concurrency checks.
+    #[must_use]
+    pub fn with_version(mut self, version: i64) -> Self {
+        self.version = version;
+        self
+    }
+
     /// Returns the stable runtime record identifier.
     #[must_use]
     pub fn record_id(&self) -> &NonEmptyString {
@@ -883,6 +1120,12 @@ impl RuntimeRecord {
     pub fn data(&self) -> &Value {
         &self.data
     }
+
+    /// Returns the record version, incremented on every data change.
+    #[must_use]
+    pub fn version(&self) -> i64 {
+        self.version
+    }
 }
 
 #[cfg(test)]
@@ -891,8 +1134,8 @@ mod tests {
     use serde_json::json;
 
     use super::{
-        EntityDefinition, EntityFieldDefinition, FieldType, OptionSetDefinition, OptionSetItem,
-        PublishedEntitySchema, RuntimeRecord,
+        EntityDefinition, EntityFieldDefinition, FieldClassification, FieldType,
+        OptionSetDefinition, OptionSetItem, PublishedEntitySchema, RuntimeRecord,
     };
 
     #[test]
@@ -901,6 +1144,65 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn entity_lifecycle_state_survives_updates_and_defaults_to_active() {
+        let entity = EntityDefinition::new("account", "Account")
+            .unwrap_or_else(|_| unreachable!())
+            .with_active(false);
+        let updated = entity
+            .with_updates("Accounts", None, None, None, None)
+            .unwrap_or_else(|_| unreachable!());
+        assert!(!updated.is_active());
+
+        let legacy: EntityDefinition = serde_json::from_value(json!({
+            "logical_name": "account",
+            "display_name": "Account",
+            "description": null,
+            "plural_display_name": null,
+            "icon": null
+        }))
+        .unwrap_or_else(|_| unreachable!());
+        assert!(legacy.is_active());
+    }
+
+    #[test]
+    fn entity_color_is_validated_and_icon_checked_against_catalog() {
+        let entity = EntityDefinition::new_with_details(
+            "account",
+            "Account",
+            None,
+            None,
+            Some("building-2".to_owned()),
+            Some(" #1F6FEB ".to_owned()),
+        )
+        .unwrap_or_else(|_| unreachable!());
+        assert_eq!(entity.color(), Some("#1f6feb"));
+        assert!(entity.has_known_icon());
+
+        let unknown_icon = EntityDefinition::new_with_details(
+            "account",
+            "Account",
+            None,
+            None,
+            Some("spaceship".to_owned()),
+            None,
+        )
+        .unwrap_or_else(|_| unreachable!());
+        assert!(!unknown_icon.has_known_icon());
+
+        for invalid in ["blue", "#12345", "#1234567", "#gggggg"] {
+            let result = EntityDefinition::new_with_details(
+                "account",
+                "Account",
+                None,
+                None,
+                None,
+                Some(invalid.to_owned()),
+            );
+            assert!(result.is_err(), "color '{invalid}' should be rejected");
+        }
+    }
+
     #[test]
     fn relation_fields_require_target_entity() {
         let result = EntityFieldDefinition::new(
@@ -916,6 +1218,50 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn many_to_many_fields_reject_record_values_and_constraints() {
+        let field = EntityFieldDefinition::new(
+            "contact",
+            "tags",
+            "Tags",
+            FieldType::ManyToMany,
+            false,
+            false,
+            None,
+            Some("tag".to_owned()),
+        )
+        .unwrap_or_else(|_| unreachable!());
+        assert!(field.validate_runtime_value(&json!(["tag-1"])).is_err());
+        assert_eq!(
+            "many_to_many".parse::<FieldType>().ok(),
+            Some(FieldType::ManyToMany)
+        );
+
+        let required = EntityFieldDefinition::new(
+            "contact",
+            "tags",
+            "Tags",
+            FieldType::ManyToMany,
+            true,
+            false,
+            None,
+            Some("tag".to_owned()),
+        );
+        assert!(required.is_err());
+
+        let missing_target = EntityFieldDefinition::new(
+            "contact",
+            "tags",
+            "Tags",
+            FieldType::ManyToMany,
+            false,
+            false,
+            None,
+            None,
+        );
+        assert!(missing_target.is_err());
+    }
+
     #[test]
     fn published_schema_rejects_duplicate_fields() {
         let entity = EntityDefinition::new("contact", "Contact").unwrap_or_else(|_| unreachable!());
@@ -1063,6 +1409,41 @@ mod tests {
         assert!(parsed.option_sets().is_empty());
     }
 
+    #[test]
+    fn published_schema_keeps_field_classification() {
+        let entity = EntityDefinition::new("contact", "Contact").unwrap_or_else(|_| unreachable!());
+        let field = EntityFieldDefinition::new(
+            "contact",
+            "email",
+            "Email",
+            FieldType::Text,
+            false,
+            false,
+            None,
+            None,
+        )
+        .unwrap_or_else(|_| unreachable!())
+        .with_classification(Some(FieldClassification::Pii));
+        let schema = PublishedEntitySchema::new(entity, 1, vec![field], Vec::new())
+            .unwrap_or_else(|_| unreachable!());
+
+        let mut schema_json = serde_json::to_value(&schema).unwrap_or_else(|_| unreachable!());
+        assert_eq!(schema_json["fields"][0]["classification"], json!("pii"));
+        let parsed: PublishedEntitySchema =
+            serde_json::from_value(schema_json.clone()).unwrap_or_else(|_| unreachable!());
+        assert_eq!(
+            parsed.fields()[0].classification(),
+            Some(FieldClassification::Pii)
+        );
+
+        if let Some(field) = schema_json["fields"][0].as_object_mut() {
+            field.remove("classification");
+        }
+        let legacy: PublishedEntitySchema =
+            serde_json::from_value(schema_json).unwrap_or_else(|_| unreachable!());
+        assert_eq!(legacy.fields()[0].classification(), None);
+    }
+
     fn spaced_text_strategy() -> impl Strategy<Value = String> {
         proptest::string::string_regex("[\\t\\n\\r A-Za-z0-9_-]{0,32}")
             .unwrap_or_else(|_| unreachable!())
@@ -1081,6 +1462,7 @@ mod tests {
                 Some(description.clone()),
                 Some(plural_display_name.clone()),
                 Some(icon.clone()),
+                None,
             )
             .unwrap_or_else(|_| unreachable!());
 
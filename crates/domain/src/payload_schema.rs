@@ -0,0 +1,427 @@
+use qryvanta_core::{AppError, AppResult};
+use serde_json::{Map, Value};
+
+/// JSON value types a payload schema node can require.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PayloadSchemaType {
+    Object,
+    Array,
+    String,
+    Number,
+    Integer,
+    Boolean,
+    Null,
+}
+
+impl PayloadSchemaType {
+    fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "object" => Ok(Self::Object),
+            "array" => Ok(Self::Array),
+            "string" => Ok(Self::String),
+            "number" => Ok(Self::Number),
+            "integer" => Ok(Self::Integer),
+            "boolean" => Ok(Self::Boolean),
+            "null" => Ok(Self::Null),
+            _ => Err(AppError::Validation(format!(
+                "unknown payload schema type '{value}'"
+            ))),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Object => "object",
+            Self::Array => "array",
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Integer => "integer",
+            Self::Boolean => "boolean",
+            Self::Null => "null",
+        }
+    }
+
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            Self::Object => value.is_object(),
+            Self::Array => value.is_array(),
+            Self::String => value.is_string(),
+            Self::Number => value.is_number(),
+            Self::Integer => {
+                value.is_i64()
+                    || value.is_u64()
+                    || value.as_f64().is_some_and(|number| number.fract() == 0.0)
+            }
+            Self::Boolean => value.is_boolean(),
+            Self::Null => value.is_null(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct PayloadSchemaNode {
+    types: Vec<PayloadSchemaType>,
+    properties: Vec<(String, PayloadSchemaNode)>,
+    required: Vec<String>,
+    additional_properties: bool,
+    items: Option<Box<PayloadSchemaNode>>,
+    allowed_values: Option<Vec<Value>>,
+    min_length: Option<u64>,
+    max_length: Option<u64>,
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    min_items: Option<u64>,
+    max_items: Option<u64>,
+}
+
+/// Subset of JSON Schema used to validate inbound workflow payloads.
+///
+/// Supports `type` (a name or a list of names), `properties`, `required`,
+/// boolean `additionalProperties`, `items`, `enum`, `minLength`, `maxLength`,
+/// `minimum`, `maximum`, `minItems` and `maxItems`, plus the annotation
+/// keywords `$schema`, `title` and `description`. Any other keyword is
+/// rejected when the schema is parsed so unsupported constraints are never
+/// silently ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayloadSchema {
+    document: Value,
+    root: PayloadSchemaNode,
+}
+
+impl PayloadSchema {
+    /// Parses and validates a payload schema document.
+    pub fn parse(document: Value) -> AppResult<Self> {
+        let root = parse_node(&document, "$")?;
+        Ok(Self { document, root })
+    }
+
+    /// Returns the original schema document.
+    #[must_use]
+    pub fn document(&self) -> &Value {
+        &self.document
+    }
+
+    /// Validates a payload, reporting the path of the first violation.
+    pub fn validate(&self, payload: &Value) -> AppResult<()> {
+        validate_node(&self.root, payload, "$")
+    }
+}
+
+fn parse_node(document: &Value, path: &str) -> AppResult<PayloadSchemaNode> {
+    let Some(object) = document.as_object() else {
+        return Err(AppError::Validation(format!(
+            "payload schema at '{path}' must be an object"
+        )));
+    };
+
+    let mut node = PayloadSchemaNode {
+        additional_properties: true,
+        ..PayloadSchemaNode::default()
+    };
+    for (keyword, value) in object {
+        match keyword.as_str() {
+            "$schema" | "title" | "description" => {}
+            "type" => node.types = parse_types(value, path)?,
+            "properties" => {
+                let properties = expect_object(value, path, keyword)?;
+                for (name, property) in properties {
+                    let property_path = format!("{path}.{name}");
+                    node.properties
+                        .push((name.clone(), parse_node(property, &property_path)?));
+                }
+            }
+            "required" => {
+                let names = value.as_array().ok_or_else(|| {
+                    keyword_error(path, keyword, "must be an array of property names")
+                })?;
+                for name in names {
+                    let name = name.as_str().ok_or_else(|| {
+                        keyword_error(path, keyword, "must be an array of property names")
+                    })?;
+                    node.required.push(name.to_owned());
+                }
+            }
+            "additionalProperties" => {
+                node.additional_properties = value
+                    .as_bool()
+                    .ok_or_else(|| keyword_error(path, keyword, "must be a boolean"))?;
+            }
+            "items" => {
+                node.items = Some(Box::new(parse_node(value, &format!("{path}[]"))?));
+            }
+            "enum" => {
+                let values = value
+                    .as_array()
+                    .filter(|values| !values.is_empty())
+                    .ok_or_else(|| keyword_error(path, keyword, "must be a non-empty array"))?;
+                node.allowed_values = Some(values.clone());
+            }
+            "minLength" => node.min_length = Some(expect_count(value, path, keyword)?),
+            "maxLength" => node.max_length = Some(expect_count(value, path, keyword)?),
+            "minItems" => node.min_items = Some(expect_count(value, path, keyword)?),
+            "maxItems" => node.max_items = Some(expect_count(value, path, keyword)?),
+            "minimum" => node.minimum = Some(expect_number(value, path, keyword)?),
+            "maximum" => node.maximum = Some(expect_number(value, path, keyword)?),
+            _ => {
+                return Err(AppError::Validation(format!(
+                    "payload schema keyword '{keyword}' at '{path}' is not supported"
+                )));
+            }
+        }
+    }
+
+    Ok(node)
+}
+
+fn parse_types(value: &Value, path: &str) -> AppResult<Vec<PayloadSchemaType>> {
+    match value {
+        Value::String(name) => Ok(vec![PayloadSchemaType::parse(name)?]),
+        Value::Array(names) if !names.is_empty() => names
+            .iter()
+            .map(|name| {
+                name.as_str()
+                    .ok_or_else(|| keyword_error(path, "type", "must list type names"))
+                    .and_then(PayloadSchemaType::parse)
+            })
+            .collect(),
+        _ => Err(keyword_error(
+            path,
+            "type",
+            "must be a type name or a non-empty list of type names",
+        )),
+    }
+}
+
+fn expect_object<'a>(
+    value: &'a Value,
+    path: &str,
+    keyword: &str,
+) -> AppResult<&'a Map<String, Value>> {
+    value
+        .as_object()
+        .ok_or_else(|| keyword_error(path, keyword, "must be an object"))
+}
+
+fn expect_count(value: &Value, path: &str, keyword: &str) -> AppResult<u64> {
+    value
+        .as_u64()
+        .ok_or_else(|| keyword_error(path, keyword, "must be a non-negative integer"))
+}
+
+fn expect_number(value: &Value, path: &str, keyword: &str) -> AppResult<f64> {
+    value
+        .as_f64()
+        .ok_or_else(|| keyword_error(path, keyword, "must be a number"))
+}
+
+fn keyword_error(path: &str, keyword: &str, message: &str) -> AppError {
+    AppError::Validation(format!(
+        "payload schema keyword '{keyword}' at '{path}' {message}"
+    ))
+}
+
+fn validate_node(node: &PayloadSchemaNode, value: &Value, path: &str) -> AppResult<()> {
+    if !node.types.is_empty() && !node.types.iter().any(|kind| kind.matches(value)) {
+        let expected = node
+            .types
+            .iter()
+            .map(|kind| kind.as_str())
+            .collect::<Vec<_>>()
+            .join(" or ");
+        return Err(violation(path, format!("must be of type {expected}")));
+    }
+
+    if let Some(allowed_values) = &node.allowed_values
+        && !allowed_values.contains(value)
+    {
+        return Err(violation(
+            path,
+            "must be one of the allowed values".to_owned(),
+        ));
+    }
+
+    match value {
+        Value::Object(object) => {
+            for name in &node.required {
+                if !object.contains_key(name) {
+                    return Err(violation(
+                        path,
+                        format!("is missing required property '{name}'"),
+                    ));
+                }
+            }
+
+            for (name, property_value) in object {
+                let property_path = format!("{path}.{name}");
+                match node
+                    .properties
+                    .iter()
+                    .find(|(property_name, _)| property_name == name)
+                {
+                    Some((_, property)) => {
+                        validate_node(property, property_value, &property_path)?;
+                    }
+                    None if !node.additional_properties => {
+                        return Err(violation(
+                            path,
+                            format!("does not allow additional property '{name}'"),
+                        ));
+                    }
+                    None => {}
+                }
+            }
+        }
+        Value::Array(items) => {
+            let count = items.len() as u64;
+            if let Some(min_items) = node.min_items
+                && count < min_items
+            {
+                return Err(violation(
+                    path,
+                    format!("must contain at least {min_items} items"),
+                ));
+            }
+            if let Some(max_items) = node.max_items
+                && count > max_items
+            {
+                return Err(violation(
+                    path,
+                    format!("must contain at most {max_items} items"),
+                ));
+            }
+            if let Some(item_schema) = &node.items {
+                for (index, item) in items.iter().enumerate() {
+                    validate_node(item_schema, item, &format!("{path}[{index}]"))?;
+                }
+            }
+        }
+        Value::String(text) => {
+            let length = text.chars().count() as u64;
+            if let Some(min_length) = node.min_length
+                && length < min_length
+            {
+                return Err(violation(
+                    path,
+                    format!("must be at least {min_length} characters long"),
+                ));
+            }
+            if let Some(max_length) = node.max_length
+                && length > max_length
+            {
+                return Err(violation(
+                    path,
+                    format!("must be at most {max_length} characters long"),
+                ));
+            }
+        }
+        Value::Number(number) => {
+            let number = number.as_f64().unwrap_or_default();
+            if let Some(minimum) = node.minimum
+                && number < minimum
+            {
+                return Err(violation(path, format!("must be at least {minimum}")));
+            }
+            if let Some(maximum) = node.maximum
+                && number > maximum
+            {
+                return Err(violation(path, format!("must be at most {maximum}")));
+            }
+        }
+        Value::Bool(_) | Value::Null => {}
+    }
+
+    Ok(())
+}
+
+fn violation(path: &str, message: String) -> AppError {
+    AppError::Validation(format!("payload at '{path}' {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::PayloadSchema;
+
+    fn order_schema() -> PayloadSchema {
+        PayloadSchema::parse(json!({
+            "type": "object",
+            "required": ["order_id", "lines"],
+            "additionalProperties": false,
+            "properties": {
+                "order_id": {"type": "string", "minLength": 3, "maxLength": 12},
+                "status": {"enum": ["open", "closed"]},
+                "lines": {
+                    "type": "array",
+                    "minItems": 1,
+                    "items": {
+                        "type": "object",
+                        "required": ["quantity"],
+                        "properties": {"quantity": {"type": "integer", "minimum": 1}}
+                    }
+                },
+                "note": {"type": ["string", "null"]}
+            }
+        }))
+        .unwrap_or_else(|_| unreachable!())
+    }
+
+    #[test]
+    fn accepts_payload_matching_schema() {
+        let result = order_schema().validate(&json!({
+            "order_id": "ORD-1",
+            "status": "open",
+            "lines": [{"quantity": 2}],
+            "note": null
+        }));
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn reports_path_of_first_violation() {
+        let result = order_schema().validate(&json!({
+            "order_id": "ORD-1",
+            "lines": [{"quantity": 2}, {"quantity": 0}]
+        }));
+
+        let message = result
+            .err()
+            .map(|error| error.to_string())
+            .unwrap_or_default();
+        assert!(message.contains("$.lines[1].quantity"), "{message}");
+    }
+
+    #[test]
+    fn rejects_missing_required_and_additional_properties() {
+        let schema = order_schema();
+
+        assert!(schema.validate(&json!({"order_id": "ORD-1"})).is_err());
+        assert!(
+            schema
+                .validate(&json!({"order_id": "ORD-1", "lines": [{"quantity": 1}], "extra": 1}))
+                .is_err()
+        );
+        assert!(
+            schema
+                .validate(&json!({"order_id": "ORD-1", "lines": [], "status": "open"}))
+                .is_err()
+        );
+        assert!(
+            schema
+                .validate(
+                    &json!({"order_id": "ORD-1", "lines": [{"quantity": 1}], "status": "void"})
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_keywords_and_malformed_schemas() {
+        assert!(PayloadSchema::parse(json!({"type": "object", "oneOf": []})).is_err());
+        assert!(PayloadSchema::parse(json!({"type": "uuid"})).is_err());
+        assert!(PayloadSchema::parse(json!({"required": "order_id"})).is_err());
+        assert!(PayloadSchema::parse(json!({"properties": {"id": true}})).is_err());
+        assert!(PayloadSchema::parse(json!([])).is_err());
+    }
+}
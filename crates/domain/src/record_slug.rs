@@ -0,0 +1,84 @@
+use qryvanta_core::{AppError, AppResult};
+
+/// Maximum length of a runtime record slug.
+pub const RECORD_SLUG_MAX_LENGTH: usize = 96;
+
+/// Derives a URL-safe slug from free text.
+///
+/// ASCII letters and digits are kept in lowercase; every other run of
+/// characters collapses into a single `-`. The result is trimmed to
+/// [`RECORD_SLUG_MAX_LENGTH`] and may be empty when the text has no letters
+/// or digits.
+#[must_use]
+pub fn slugify(value: &str) -> String {
+    let mut slug = String::with_capacity(value.len().min(RECORD_SLUG_MAX_LENGTH));
+    let mut pending_separator = false;
+
+    for character in value.chars() {
+        if character.is_ascii_alphanumeric() {
+            if pending_separator && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_separator = false;
+            slug.push(character.to_ascii_lowercase());
+        } else {
+            pending_separator = true;
+        }
+
+        if slug.len() >= RECORD_SLUG_MAX_LENGTH {
+            break;
+        }
+    }
+
+    slug.truncate(RECORD_SLUG_MAX_LENGTH);
+    slug.trim_end_matches('-').to_owned()
+}
+
+/// Validates that a slug uses lowercase letters, digits and single hyphens.
+pub fn validate_record_slug(value: &str) -> AppResult<()> {
+    let well_formed = !value.is_empty()
+        && value.len() <= RECORD_SLUG_MAX_LENGTH
+        && !value.starts_with('-')
+        && !value.ends_with('-')
+        && !value.contains("--")
+        && value.chars().all(|character| {
+            character.is_ascii_lowercase() || character.is_ascii_digit() || character == '-'
+        });
+
+    if !well_formed {
+        return Err(AppError::Validation(format!(
+            "slug '{value}' must be 1-{RECORD_SLUG_MAX_LENGTH} lowercase letters, digits or single hyphens"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RECORD_SLUG_MAX_LENGTH, slugify, validate_record_slug};
+
+    #[test]
+    fn slugify_collapses_separators_and_lowercases() {
+        assert_eq!(slugify("  Contoso Ltd. (EMEA) "), "contoso-ltd-emea");
+        assert_eq!(slugify("Müller & Söhne"), "m-ller-s-hne");
+        assert_eq!(slugify("---"), "");
+        assert_eq!(slugify(&"a".repeat(200)).len(), RECORD_SLUG_MAX_LENGTH);
+        assert!(validate_record_slug(slugify("Quarterly Report 2026").as_str()).is_ok());
+    }
+
+    #[test]
+    fn validate_record_slug_rejects_malformed_values() {
+        assert!(validate_record_slug("contoso-ltd").is_ok());
+        for value in [
+            "",
+            "Contoso",
+            "-contoso",
+            "contoso-",
+            "con--toso",
+            "con toso",
+        ] {
+            assert!(validate_record_slug(value).is_err(), "{value}");
+        }
+    }
+}
@@ -0,0 +1,426 @@
+use std::collections::HashSet;
+
+use qryvanta_core::{AppError, AppResult};
+use serde::{Deserialize, Serialize};
+
+/// Prefix of the validation error raised for a disallowed status transition.
+///
+/// API clients map this prefix to a stable error code, so it must not change.
+pub const RECORD_STATUS_TRANSITION_INVALID_PREFIX: &str = "invalid status transition";
+
+/// Prefix of the validation error raised when editing an inactive record.
+///
+/// API clients map this prefix to a stable error code, so it must not change.
+pub const RECORD_INACTIVE_PREFIX: &str = "record is inactive";
+
+/// One status a record can be in, together with its allowed status reasons.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordStatusOption {
+    /// Stored status value.
+    pub value: String,
+    /// Display label.
+    pub label: String,
+    /// Allowed status reasons. The first reason is the default.
+    #[serde(default)]
+    pub reasons: Vec<String>,
+    /// Whether records in this status are inactive.
+    #[serde(default)]
+    pub inactive: bool,
+}
+
+/// Allowed move from one status to another.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordStatusTransition {
+    /// Status the record is in.
+    pub from: String,
+    /// Status the record may move to.
+    pub to: String,
+}
+
+/// Status and status-reason state machine of one entity.
+///
+/// Records start in `initial_status` unless created with another known
+/// status. Updates may keep the status or move along a declared transition;
+/// every other status change is rejected. Statuses flagged `inactive` hide
+/// their records from default listings and lock them against edits other
+/// than reactivation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordStatusModel {
+    status_field_logical_name: String,
+    reason_field_logical_name: Option<String>,
+    initial_status: String,
+    statuses: Vec<RecordStatusOption>,
+    transitions: Vec<RecordStatusTransition>,
+}
+
+impl RecordStatusModel {
+    /// Creates a validated status model.
+    pub fn new(
+        status_field_logical_name: impl Into<String>,
+        reason_field_logical_name: Option<String>,
+        initial_status: impl Into<String>,
+        statuses: Vec<RecordStatusOption>,
+        transitions: Vec<RecordStatusTransition>,
+    ) -> AppResult<Self> {
+        let status_field_logical_name = status_field_logical_name.into();
+        let initial_status = initial_status.into();
+
+        if status_field_logical_name.trim().is_empty() {
+            return Err(AppError::Validation(
+                "status_field_logical_name must not be empty".to_owned(),
+            ));
+        }
+        if let Some(reason_field_logical_name) = &reason_field_logical_name {
+            if reason_field_logical_name.trim().is_empty() {
+                return Err(AppError::Validation(
+                    "reason_field_logical_name must not be empty when set".to_owned(),
+                ));
+            }
+            if reason_field_logical_name == &status_field_logical_name {
+                return Err(AppError::Validation(format!(
+                    "status field '{status_field_logical_name}' cannot also be the reason field"
+                )));
+            }
+        }
+        if statuses.is_empty() {
+            return Err(AppError::Validation(
+                "status model requires at least one status".to_owned(),
+            ));
+        }
+
+        let mut seen_values = HashSet::new();
+        for status in &statuses {
+            if status.value.trim().is_empty() || status.label.trim().is_empty() {
+                return Err(AppError::Validation(
+                    "status value and label must not be empty".to_owned(),
+                ));
+            }
+            if !seen_values.insert(status.value.as_str()) {
+                return Err(AppError::Validation(format!(
+                    "status '{}' is declared more than once",
+                    status.value
+                )));
+            }
+
+            let mut seen_reasons = HashSet::new();
+            for reason in &status.reasons {
+                if reason.trim().is_empty() {
+                    return Err(AppError::Validation(format!(
+                        "status '{}' declares an empty reason",
+                        status.value
+                    )));
+                }
+                if !seen_reasons.insert(reason.as_str()) {
+                    return Err(AppError::Validation(format!(
+                        "status '{}' declares reason '{}' more than once",
+                        status.value, reason
+                    )));
+                }
+            }
+
+            match (&reason_field_logical_name, status.reasons.is_empty()) {
+                (Some(_), true) => {
+                    return Err(AppError::Validation(format!(
+                        "status '{}' requires at least one reason when a reason field is set",
+                        status.value
+                    )));
+                }
+                (None, false) => {
+                    return Err(AppError::Validation(format!(
+                        "status '{}' declares reasons but the model has no reason field",
+                        status.value
+                    )));
+                }
+                _ => {}
+            }
+        }
+
+        match statuses
+            .iter()
+            .find(|status| status.value == initial_status)
+        {
+            None => {
+                return Err(AppError::Validation(format!(
+                    "initial status '{initial_status}' is not a declared status"
+                )));
+            }
+            Some(status) if status.inactive => {
+                return Err(AppError::Validation(format!(
+                    "initial status '{initial_status}' cannot be inactive"
+                )));
+            }
+            Some(_) => {}
+        }
+
+        let mut seen_transitions = HashSet::new();
+        for transition in &transitions {
+            for value in [&transition.from, &transition.to] {
+                if !seen_values.contains(value.as_str()) {
+                    return Err(AppError::Validation(format!(
+                        "transition '{}' -> '{}' references unknown status '{}'",
+                        transition.from, transition.to, value
+                    )));
+                }
+            }
+            if transition.from == transition.to {
+                return Err(AppError::Validation(format!(
+                    "transition '{}' -> '{}' must change the status",
+                    transition.from, transition.to
+                )));
+            }
+            if !seen_transitions.insert((transition.from.as_str(), transition.to.as_str())) {
+                return Err(AppError::Validation(format!(
+                    "transition '{}' -> '{}' is declared more than once",
+                    transition.from, transition.to
+                )));
+            }
+        }
+
+        Ok(Self {
+            status_field_logical_name,
+            reason_field_logical_name,
+            initial_status,
+            statuses,
+            transitions,
+        })
+    }
+
+    /// Returns the text field holding the status.
+    #[must_use]
+    pub fn status_field_logical_name(&self) -> &str {
+        self.status_field_logical_name.as_str()
+    }
+
+    /// Returns the optional text field holding the status reason.
+    #[must_use]
+    pub fn reason_field_logical_name(&self) -> Option<&str> {
+        self.reason_field_logical_name.as_deref()
+    }
+
+    /// Returns the status new records start in.
+    #[must_use]
+    pub fn initial_status(&self) -> &str {
+        self.initial_status.as_str()
+    }
+
+    /// Returns the declared statuses.
+    #[must_use]
+    pub fn statuses(&self) -> &[RecordStatusOption] {
+        &self.statuses
+    }
+
+    /// Returns the declared transitions.
+    #[must_use]
+    pub fn transitions(&self) -> &[RecordStatusTransition] {
+        &self.transitions
+    }
+
+    /// Returns a declared status by value.
+    #[must_use]
+    pub fn status(&self, value: &str) -> Option<&RecordStatusOption> {
+        self.statuses.iter().find(|status| status.value == value)
+    }
+
+    /// Returns whether a status is declared and flagged inactive.
+    #[must_use]
+    pub fn is_inactive(&self, value: &str) -> bool {
+        self.status(value).is_some_and(|status| status.inactive)
+    }
+
+    /// Returns the values of all inactive statuses.
+    #[must_use]
+    pub fn inactive_statuses(&self) -> Vec<&str> {
+        self.statuses
+            .iter()
+            .filter(|status| status.inactive)
+            .map(|status| status.value.as_str())
+            .collect()
+    }
+
+    /// Returns whether a record may move from `from` to `to`.
+    ///
+    /// Keeping the current status is always allowed.
+    #[must_use]
+    pub fn allows_transition(&self, from: &str, to: &str) -> bool {
+        from == to
+            || self
+                .transitions
+                .iter()
+                .any(|transition| transition.from == from && transition.to == to)
+    }
+
+    /// Rejects a status change that is not a declared transition.
+    pub fn ensure_transition(&self, from: &str, to: &str) -> AppResult<()> {
+        if self.status(to).is_none() {
+            return Err(AppError::Validation(format!(
+                "{RECORD_STATUS_TRANSITION_INVALID_PREFIX}: '{to}' is not a declared status"
+            )));
+        }
+        if !self.allows_transition(from, to) {
+            return Err(AppError::Validation(format!(
+                "{RECORD_STATUS_TRANSITION_INVALID_PREFIX}: '{from}' cannot move to '{to}'"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the status reason for a status, defaulting to its first reason.
+    ///
+    /// Returns `None` when the model has no reason field.
+    pub fn resolve_reason(&self, status: &str, reason: Option<&str>) -> AppResult<Option<String>> {
+        if self.reason_field_logical_name.is_none() {
+            return Ok(None);
+        }
+        let Some(option) = self.status(status) else {
+            return Err(AppError::Validation(format!(
+                "{RECORD_STATUS_TRANSITION_INVALID_PREFIX}: '{status}' is not a declared status"
+            )));
+        };
+
+        match reason {
+            None => Ok(option.reasons.first().cloned()),
+            Some(reason) if option.reasons.iter().any(|allowed| allowed == reason) => {
+                Ok(Some(reason.to_owned()))
+            }
+            Some(reason) => Err(AppError::Validation(format!(
+                "status reason '{reason}' is not allowed for status '{status}'"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RECORD_STATUS_TRANSITION_INVALID_PREFIX, RecordStatusModel, RecordStatusOption,
+        RecordStatusTransition,
+    };
+
+    fn status(value: &str, reasons: &[&str]) -> RecordStatusOption {
+        RecordStatusOption {
+            value: value.to_owned(),
+            label: value.to_uppercase(),
+            reasons: reasons.iter().map(|reason| (*reason).to_owned()).collect(),
+            inactive: value == "closed",
+        }
+    }
+
+    fn transition(from: &str, to: &str) -> RecordStatusTransition {
+        RecordStatusTransition {
+            from: from.to_owned(),
+            to: to.to_owned(),
+        }
+    }
+
+    fn case_model() -> RecordStatusModel {
+        RecordStatusModel::new(
+            "status",
+            Some("status_reason".to_owned()),
+            "open",
+            vec![
+                status("open", &["new", "in_progress"]),
+                status("resolved", &["fixed", "duplicate"]),
+                status("closed", &["archived"]),
+            ],
+            vec![
+                transition("open", "resolved"),
+                transition("resolved", "open"),
+                transition("resolved", "closed"),
+            ],
+        )
+        .unwrap_or_else(|_| unreachable!())
+    }
+
+    #[test]
+    fn transitions_follow_declared_edges() {
+        let model = case_model();
+
+        assert!(model.ensure_transition("open", "resolved").is_ok());
+        assert!(model.ensure_transition("open", "open").is_ok());
+
+        let message = model
+            .ensure_transition("open", "closed")
+            .err()
+            .map(|error| error.to_string())
+            .unwrap_or_default();
+        assert!(message.contains(RECORD_STATUS_TRANSITION_INVALID_PREFIX));
+        assert!(model.ensure_transition("open", "missing").is_err());
+    }
+
+    #[test]
+    fn reasons_default_to_first_and_must_belong_to_status() {
+        let model = case_model();
+
+        assert_eq!(
+            model.resolve_reason("resolved", None).ok().flatten(),
+            Some("fixed".to_owned())
+        );
+        assert_eq!(
+            model
+                .resolve_reason("resolved", Some("duplicate"))
+                .ok()
+                .flatten(),
+            Some("duplicate".to_owned())
+        );
+        assert!(model.resolve_reason("resolved", Some("new")).is_err());
+    }
+
+    #[test]
+    fn reports_inactive_statuses() {
+        let model = case_model();
+
+        assert!(model.is_inactive("closed"));
+        assert!(!model.is_inactive("open"));
+        assert!(!model.is_inactive("missing"));
+        assert_eq!(model.inactive_statuses(), vec!["closed"]);
+        assert!(
+            RecordStatusModel::new(
+                "status",
+                None,
+                "closed",
+                vec![status("open", &[]), status("closed", &[])],
+                vec![],
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_inconsistent_models() {
+        assert!(
+            RecordStatusModel::new("status", None, "missing", vec![status("open", &[])], vec![])
+                .is_err()
+        );
+        assert!(
+            RecordStatusModel::new(
+                "status",
+                None,
+                "open",
+                vec![status("open", &[]), status("open", &[])],
+                vec![],
+            )
+            .is_err()
+        );
+        assert!(
+            RecordStatusModel::new(
+                "status",
+                Some("reason".to_owned()),
+                "open",
+                vec![status("open", &[])],
+                vec![],
+            )
+            .is_err()
+        );
+        assert!(
+            RecordStatusModel::new(
+                "status",
+                None,
+                "open",
+                vec![status("open", &[]), status("closed", &[])],
+                vec![transition("open", "archived")],
+            )
+            .is_err()
+        );
+    }
+}
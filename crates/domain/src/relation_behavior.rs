@@ -0,0 +1,139 @@
+use std::str::FromStr;
+
+use qryvanta_core::AppError;
+use serde::{Deserialize, Serialize};
+
+/// How an action on a parent record propagates to records that reference it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelationCascadeBehavior {
+    /// The action applies to the parent record only.
+    #[default]
+    None,
+    /// The action applies to every child record.
+    CascadeAll,
+    /// The action applies to child records owned by the parent's owner.
+    CascadeUserOwned,
+}
+
+impl RelationCascadeBehavior {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::CascadeAll => "cascade_all",
+            Self::CascadeUserOwned => "cascade_user_owned",
+        }
+    }
+
+    /// Returns whether the behavior propagates to any child record.
+    #[must_use]
+    pub fn cascades(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+}
+
+impl FromStr for RelationCascadeBehavior {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(Self::None),
+            "cascade_all" => Ok(Self::CascadeAll),
+            "cascade_user_owned" => Ok(Self::CascadeUserOwned),
+            _ => Err(AppError::Validation(format!(
+                "unknown relation cascade behavior '{value}'"
+            ))),
+        }
+    }
+}
+
+/// What happens to records that reference a parent record being deleted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelationDeleteBehavior {
+    /// The parent cannot be deleted while child records reference it.
+    #[default]
+    Restrict,
+    /// Child records are deleted with the parent.
+    Cascade,
+    /// The relation value of child records is cleared.
+    SetNull,
+    /// Child records move to the parent's own parent in a self-referencing
+    /// hierarchy, or are cleared when the parent has none.
+    Reparent,
+}
+
+impl RelationDeleteBehavior {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Restrict => "restrict",
+            Self::Cascade => "cascade",
+            Self::SetNull => "set_null",
+            Self::Reparent => "reparent",
+        }
+    }
+
+    /// Returns whether the behavior may clear the relation value of a child.
+    #[must_use]
+    pub fn clears_relation(&self) -> bool {
+        matches!(self, Self::SetNull | Self::Reparent)
+    }
+}
+
+impl FromStr for RelationDeleteBehavior {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "restrict" => Ok(Self::Restrict),
+            "cascade" => Ok(Self::Cascade),
+            "set_null" => Ok(Self::SetNull),
+            "reparent" => Ok(Self::Reparent),
+            _ => Err(AppError::Validation(format!(
+                "unknown relation delete behavior '{value}'"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{RelationCascadeBehavior, RelationDeleteBehavior};
+
+    #[test]
+    fn relation_cascade_behavior_roundtrip_storage_value() {
+        for behavior in [
+            RelationCascadeBehavior::None,
+            RelationCascadeBehavior::CascadeAll,
+            RelationCascadeBehavior::CascadeUserOwned,
+        ] {
+            let restored = RelationCascadeBehavior::from_str(behavior.as_str());
+            assert_eq!(restored.ok(), Some(behavior));
+        }
+        assert!(RelationCascadeBehavior::from_str("restrict").is_err());
+        assert!(!RelationCascadeBehavior::None.cascades());
+        assert!(RelationCascadeBehavior::CascadeUserOwned.cascades());
+    }
+
+    #[test]
+    fn relation_delete_behavior_roundtrip_storage_value() {
+        for behavior in [
+            RelationDeleteBehavior::Restrict,
+            RelationDeleteBehavior::Cascade,
+            RelationDeleteBehavior::SetNull,
+            RelationDeleteBehavior::Reparent,
+        ] {
+            let restored = RelationDeleteBehavior::from_str(behavior.as_str());
+            assert_eq!(restored.ok(), Some(behavior));
+        }
+        assert!(RelationDeleteBehavior::from_str("none").is_err());
+        assert!(RelationDeleteBehavior::Reparent.clears_relation());
+        assert!(!RelationDeleteBehavior::Cascade.clears_relation());
+    }
+}
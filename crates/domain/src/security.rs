@@ -48,6 +48,9 @@ impl Surface {
                 Permission::SecurityRoleManage,
                 Permission::SecurityAuditRead,
                 Permission::SecurityInviteSend,
+                Permission::SecurityLegalHoldManage,
+                Permission::SecurityDualControlManage,
+                Permission::SecurityComplianceZoneManage,
             ],
             Self::Maker => &[
                 Permission::MetadataEntityRead,
@@ -82,6 +85,116 @@ impl FromStr for Surface {
     }
 }
 
+/// Coarse UI capabilities derived from permissions.
+///
+/// Frontends show or hide features from these flags instead of mapping
+/// permissions themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Capability {
+    /// Create and edit entity and field definitions.
+    DesignEntities,
+    /// Manage roles, assignments, and other security settings.
+    ManageSecurity,
+    /// Publish entity schemas.
+    Publish,
+    /// Create and change workflows.
+    ManageWorkflows,
+}
+
+impl Capability {
+    /// Returns a stable transport value for this capability.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DesignEntities => "design_entities",
+            Self::ManageSecurity => "manage_security",
+            Self::Publish => "publish",
+            Self::ManageWorkflows => "manage_workflows",
+        }
+    }
+
+    /// Returns all known capabilities.
+    #[must_use]
+    pub fn all() -> &'static [Self] {
+        &[
+            Self::DesignEntities,
+            Self::ManageSecurity,
+            Self::Publish,
+            Self::ManageWorkflows,
+        ]
+    }
+
+    /// Returns the permissions that grant this capability.
+    ///
+    /// Unlike surfaces, a subject needs **every** returned permission
+    /// (logical AND), matching the checks of the operations behind it.
+    #[must_use]
+    pub fn required_permissions(&self) -> &'static [Permission] {
+        match self {
+            Self::DesignEntities => &[
+                Permission::MetadataEntityCreate,
+                Permission::MetadataFieldWrite,
+            ],
+            Self::ManageSecurity => &[Permission::SecurityRoleManage],
+            Self::Publish => &[
+                Permission::MetadataEntityCreate,
+                Permission::MetadataFieldWrite,
+            ],
+            Self::ManageWorkflows => &[Permission::WorkflowManage],
+        }
+    }
+}
+
+/// Functional areas used to group permissions in role editors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionGroup {
+    /// Entity, field, and schema configuration.
+    Metadata,
+    /// Workflow authoring and execution.
+    Workflow,
+    /// Runtime record access.
+    Runtime,
+    /// Tenant security, audit, and compliance administration.
+    Security,
+}
+
+impl PermissionGroup {
+    /// Returns a stable transport value for this group.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Metadata => "metadata",
+            Self::Workflow => "workflow",
+            Self::Runtime => "runtime",
+            Self::Security => "security",
+        }
+    }
+
+    /// Returns a display label for this group.
+    #[must_use]
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Metadata => "Metadata",
+            Self::Workflow => "Workflows",
+            Self::Runtime => "Records",
+            Self::Security => "Security",
+        }
+    }
+
+    /// Returns all known groups in display order.
+    #[must_use]
+    pub fn all() -> &'static [Self] {
+        &[
+            Self::Metadata,
+            Self::Workflow,
+            Self::Runtime,
+            Self::Security,
+        ]
+    }
+}
+
 /// Permissions enforced by application policy checks.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -106,12 +219,26 @@ pub enum Permission {
     RuntimeRecordWrite,
     /// Allows mutating only runtime records owned by the subject.
     RuntimeRecordWriteOwn,
+    /// Allows transferring runtime record ownership to another subject.
+    RuntimeRecordAssign,
+    /// Allows moving inactive runtime records back to an active status.
+    RuntimeRecordReactivate,
+    /// Allows deciding record access requests for records the subject does not own.
+    RuntimeRecordAccessApprove,
+    /// Allows creating and managing external share links for runtime records.
+    RuntimeRecordShareLinkManage,
     /// Allows reading audit log entries.
     SecurityAuditRead,
     /// Allows managing roles and grants.
     SecurityRoleManage,
     /// Allows sending tenant invite emails.
     SecurityInviteSend,
+    /// Allows placing and releasing compliance legal holds.
+    SecurityLegalHoldManage,
+    /// Allows marking runtime fields as requiring dual-control approval.
+    SecurityDualControlManage,
+    /// Allows assigning compliance zones and restricting records to them.
+    SecurityComplianceZoneManage,
 }
 
 impl Permission {
@@ -129,9 +256,16 @@ impl Permission {
             Self::RuntimeRecordReadOwn => "runtime.record.read.own",
             Self::RuntimeRecordWrite => "runtime.record.write",
             Self::RuntimeRecordWriteOwn => "runtime.record.write.own",
+            Self::RuntimeRecordAssign => "runtime.record.assign",
+            Self::RuntimeRecordReactivate => "runtime.record.reactivate",
+            Self::RuntimeRecordAccessApprove => "runtime.record_access.approve",
+            Self::RuntimeRecordShareLinkManage => "runtime.record_share_link.manage",
             Self::SecurityAuditRead => "security.audit.read",
             Self::SecurityRoleManage => "security.role.manage",
             Self::SecurityInviteSend => "security.invite.send",
+            Self::SecurityLegalHoldManage => "security.legal_hold.manage",
+            Self::SecurityDualControlManage => "security.dual_control.manage",
+            Self::SecurityComplianceZoneManage => "security.compliance_zone.manage",
         }
     }
 
@@ -149,9 +283,16 @@ impl Permission {
             Permission::RuntimeRecordReadOwn,
             Permission::RuntimeRecordWrite,
             Permission::RuntimeRecordWriteOwn,
+            Permission::RuntimeRecordAssign,
+            Permission::RuntimeRecordReactivate,
+            Permission::RuntimeRecordAccessApprove,
+            Permission::RuntimeRecordShareLinkManage,
             Permission::SecurityAuditRead,
             Permission::SecurityRoleManage,
             Permission::SecurityInviteSend,
+            Permission::SecurityLegalHoldManage,
+            Permission::SecurityDualControlManage,
+            Permission::SecurityComplianceZoneManage,
         ];
 
         ALL
@@ -161,6 +302,104 @@ impl Permission {
     pub fn from_transport(value: &str) -> Result<Self, AppError> {
         Self::from_str(value)
     }
+
+    /// Returns the functional group this permission belongs to.
+    #[must_use]
+    pub fn group(&self) -> PermissionGroup {
+        match self {
+            Self::MetadataEntityRead
+            | Self::MetadataEntityCreate
+            | Self::MetadataFieldRead
+            | Self::MetadataFieldWrite => PermissionGroup::Metadata,
+            Self::WorkflowRead | Self::WorkflowManage => PermissionGroup::Workflow,
+            Self::RuntimeRecordRead
+            | Self::RuntimeRecordReadOwn
+            | Self::RuntimeRecordWrite
+            | Self::RuntimeRecordWriteOwn
+            | Self::RuntimeRecordAssign
+            | Self::RuntimeRecordReactivate
+            | Self::RuntimeRecordAccessApprove
+            | Self::RuntimeRecordShareLinkManage => PermissionGroup::Runtime,
+            Self::SecurityAuditRead
+            | Self::SecurityRoleManage
+            | Self::SecurityInviteSend
+            | Self::SecurityLegalHoldManage
+            | Self::SecurityDualControlManage
+            | Self::SecurityComplianceZoneManage => PermissionGroup::Security,
+        }
+    }
+
+    /// Returns a short description of what this permission allows.
+    #[must_use]
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::MetadataEntityRead => "Read entity definitions",
+            Self::MetadataEntityCreate => "Create entity definitions",
+            Self::MetadataFieldRead => "Read field definitions",
+            Self::MetadataFieldWrite => "Change field definitions and publish schemas",
+            Self::WorkflowRead => "Read workflows and their run history",
+            Self::WorkflowManage => "Save, publish, disable, and run workflows",
+            Self::RuntimeRecordRead => "Read all records",
+            Self::RuntimeRecordReadOwn => "Read owned records",
+            Self::RuntimeRecordWrite => "Create and change all records",
+            Self::RuntimeRecordWriteOwn => "Create and change owned records",
+            Self::RuntimeRecordAssign => "Transfer record ownership",
+            Self::RuntimeRecordReactivate => "Reactivate inactive records",
+            Self::RuntimeRecordAccessApprove => "Decide access requests for records of others",
+            Self::RuntimeRecordShareLinkManage => "Create and revoke external record share links",
+            Self::SecurityAuditRead => "Read the audit log",
+            Self::SecurityRoleManage => "Manage roles, assignments, and security settings",
+            Self::SecurityInviteSend => "Invite people to the tenant",
+            Self::SecurityLegalHoldManage => "Place and release legal holds",
+            Self::SecurityDualControlManage => "Require dual-control approval for fields",
+            Self::SecurityComplianceZoneManage => "Assign compliance zones",
+        }
+    }
+
+    /// Returns the permissions a role must also grant alongside this one.
+    ///
+    /// Dependencies are direct; checking every permission of a role covers
+    /// transitive chains.
+    #[must_use]
+    pub fn implied_permissions(&self) -> &'static [Permission] {
+        match self {
+            Self::MetadataEntityCreate => &[Permission::MetadataEntityRead],
+            Self::MetadataFieldWrite => &[Permission::MetadataFieldRead],
+            Self::WorkflowManage => &[Permission::WorkflowRead],
+            Self::RuntimeRecordWrite => &[Permission::RuntimeRecordRead],
+            Self::RuntimeRecordWriteOwn => &[Permission::RuntimeRecordReadOwn],
+            Self::RuntimeRecordAssign
+            | Self::RuntimeRecordAccessApprove
+            | Self::RuntimeRecordShareLinkManage => &[Permission::RuntimeRecordRead],
+            Self::RuntimeRecordReactivate => &[Permission::RuntimeRecordWrite],
+            _ => &[],
+        }
+    }
+
+    /// Validates that a grant set includes every implied permission.
+    pub fn validate_grant_set(permissions: &[Self]) -> Result<(), AppError> {
+        let missing = permissions
+            .iter()
+            .flat_map(|permission| {
+                permission
+                    .implied_permissions()
+                    .iter()
+                    .filter(|implied| !permissions.contains(implied))
+                    .map(move |implied| {
+                        format!("'{}' requires '{}'", permission.as_str(), implied.as_str())
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::Validation(format!(
+                "role permissions are incomplete: {}",
+                missing.join(", ")
+            )))
+        }
+    }
 }
 
 impl FromStr for Permission {
@@ -178,9 +417,16 @@ impl FromStr for Permission {
             "runtime.record.read.own" => Ok(Self::RuntimeRecordReadOwn),
             "runtime.record.write" => Ok(Self::RuntimeRecordWrite),
             "runtime.record.write.own" => Ok(Self::RuntimeRecordWriteOwn),
+            "runtime.record.assign" => Ok(Self::RuntimeRecordAssign),
+            "runtime.record.reactivate" => Ok(Self::RuntimeRecordReactivate),
+            "runtime.record_access.approve" => Ok(Self::RuntimeRecordAccessApprove),
+            "runtime.record_share_link.manage" => Ok(Self::RuntimeRecordShareLinkManage),
             "security.audit.read" => Ok(Self::SecurityAuditRead),
             "security.role.manage" => Ok(Self::SecurityRoleManage),
             "security.invite.send" => Ok(Self::SecurityInviteSend),
+            "security.legal_hold.manage" => Ok(Self::SecurityLegalHoldManage),
+            "security.dual_control.manage" => Ok(Self::SecurityDualControlManage),
+            "security.compliance_zone.manage" => Ok(Self::SecurityComplianceZoneManage),
             _ => Err(AppError::Validation(format!(
                 "unknown permission value '{value}'"
             ))),
@@ -196,8 +442,14 @@ pub enum AuditAction {
     AppCreated,
     /// Emitted when an entity is bound into an app navigation.
     AppEntityBound,
+    /// Emitted when an entity is removed from an app navigation.
+    AppEntityUnbound,
     /// Emitted when role permissions are updated for an app entity.
     AppRoleEntityPermissionSaved,
+    /// Emitted when a subject is made administrator of one app.
+    AppAdminGranted,
+    /// Emitted when a subject stops administering one app.
+    AppAdminRevoked,
     /// Emitted when a workflow definition is created or updated.
     WorkflowSaved,
     /// Emitted when a workflow draft is published.
@@ -206,20 +458,140 @@ pub enum AuditAction {
     WorkflowDisabled,
     /// Emitted when a workflow run reaches a terminal state.
     WorkflowRunCompleted,
+    /// Emitted when a workflow inbound webhook is configured or rotated.
+    WorkflowInboundWebhookConfigured,
+    /// Emitted when a workflow inbound webhook is revoked.
+    WorkflowInboundWebhookRevoked,
+    /// Emitted when an outbound workflow credential is created or replaced.
+    WorkflowCredentialSaved,
+    /// Emitted when an outbound workflow credential is deleted.
+    WorkflowCredentialDeleted,
+    /// Emitted when a workflow is enqueued for a bulk record selection.
+    WorkflowBulkExecutionStarted,
+    /// Emitted when an operator returns a stuck workflow job to the queue.
+    WorkflowJobReleased,
+    /// Emitted when an operator fails a stuck workflow job.
+    WorkflowJobFailed,
+    /// Emitted when a long-running background job is queued.
+    BackgroundJobQueued,
+    /// Emitted when cancellation of a background job is requested.
+    BackgroundJobCancelled,
+    /// Emitted when the scheduled data validation audit is configured.
+    DataValidationScheduleSaved,
+    /// Emitted when the scheduled data validation audit is removed.
+    DataValidationScheduleDeleted,
+    /// Emitted when a record import mapping template is created or updated.
+    RecordImportTemplateSaved,
+    /// Emitted when a record import mapping template is removed.
+    RecordImportTemplateDeleted,
+    /// Emitted when a recurring record import is created, paused or resumed.
+    RecordImportScheduleSaved,
+    /// Emitted when a recurring record import is removed.
+    RecordImportScheduleDeleted,
+    /// Emitted when an uploaded or scheduled import file was processed.
+    RecordImportCompleted,
+    /// Emitted when a reporting projection is created or redefined.
+    ReportingProjectionSaved,
+    /// Emitted when a reporting projection is deleted.
+    ReportingProjectionDeleted,
+    /// Emitted when a download link for a tenant data export is issued.
+    TenantDataExportLinkCreated,
+    /// Emitted when a tenant data export archive is downloaded.
+    TenantDataExportDownloaded,
     /// Emitted when an entity definition is created.
     MetadataEntityCreated,
+    /// Emitted when an entity is deactivated for runtime record writes.
+    MetadataEntityDeactivated,
+    /// Emitted when a deactivated entity accepts runtime record writes again.
+    MetadataEntityReactivated,
+    /// Emitted when an entity definition and its records are deleted.
+    MetadataEntityDeleted,
     /// Emitted when a metadata field is created or updated.
     MetadataFieldSaved,
+    /// Emitted when relation cascade behaviors are configured.
+    MetadataRelationBehaviorSaved,
+    /// Emitted when a relation lookup configuration is saved.
+    MetadataRelationLookupSaved,
+    /// Emitted when a relation lookup configuration is removed.
+    MetadataRelationLookupDeleted,
+    /// Emitted when an entity slug configuration is saved.
+    MetadataEntitySlugSaved,
+    /// Emitted when an entity status model is saved.
+    MetadataEntityStatusModelSaved,
+    /// Emitted when an entity status model is removed.
+    MetadataEntityStatusModelDeleted,
+    /// Emitted when an entity record history retention policy is saved.
+    MetadataEntityHistoryPolicySaved,
+    /// Emitted when an entity record history retention policy is removed.
+    MetadataEntityHistoryPolicyDeleted,
+    /// Emitted when an entity duplicate-detection rule is saved.
+    MetadataDuplicateRuleSaved,
+    /// Emitted when an entity duplicate-detection rule is removed.
+    MetadataDuplicateRuleDeleted,
+    /// Emitted when a localized display label is saved for a metadata component.
+    MetadataLocalizedLabelSaved,
+    /// Emitted when a localized display label is removed from a metadata component.
+    MetadataLocalizedLabelDeleted,
     /// Emitted when draft metadata is published.
     MetadataEntityPublished,
+    /// Emitted when an earlier published schema is republished as the newest version.
+    MetadataEntitySchemaRolledBack,
     /// Emitted when a workspace publish run completes.
     MetadataWorkspacePublished,
+    /// Emitted when a publish request is queued behind publish locks.
+    MetadataPublishIntentQueued,
+    /// Emitted when a queued publish request is cancelled.
+    MetadataPublishIntentCancelled,
     /// Emitted when a runtime record is created.
     RuntimeRecordCreated,
     /// Emitted when a runtime record is updated.
     RuntimeRecordUpdated,
     /// Emitted when a runtime record is deleted.
     RuntimeRecordDeleted,
+    /// Emitted once per bulk edit, summarizing the records it changed.
+    RuntimeRecordBulkUpdated,
+    /// Emitted when a runtime record is restored to an earlier version.
+    RuntimeRecordRestored,
+    /// Emitted when a runtime record is reassigned to a new owner.
+    RuntimeRecordOwnerAssigned,
+    /// Emitted when two runtime records are associated through a many-to-many field.
+    RuntimeRecordAssociated,
+    /// Emitted when a many-to-many association between runtime records is removed.
+    RuntimeRecordDisassociated,
+    /// Emitted when a runtime record moves to another status.
+    RuntimeRecordStatusChanged,
+    /// Emitted when a business rule rejects a runtime record create or update.
+    RuntimeRecordBusinessRuleRejected,
+    /// Emitted when a dual-control field change is deferred for approval.
+    RuntimeFieldChangeRequested,
+    /// Emitted when a pending dual-control field change is approved and applied.
+    RuntimeFieldChangeApproved,
+    /// Emitted when a pending dual-control field change is rejected.
+    RuntimeFieldChangeRejected,
+    /// Emitted when a subject requests access to a runtime record.
+    RuntimeRecordAccessRequested,
+    /// Emitted when a record access request is approved and a share is created.
+    RuntimeRecordAccessApproved,
+    /// Emitted when a record access request is rejected or withdrawn.
+    RuntimeRecordAccessRejected,
+    /// Emitted when a time-boxed record share is revoked early.
+    RuntimeRecordShareRevoked,
+    /// Emitted when an external share link is created for a runtime record.
+    RuntimeRecordShareLinkCreated,
+    /// Emitted when an external share link for a runtime record is revoked.
+    RuntimeRecordShareLinkRevoked,
+    /// Emitted when a contact grants consent for a purpose and channel.
+    ContactConsentGranted,
+    /// Emitted when a contact revokes consent for a purpose and channel.
+    ContactConsentRevoked,
+    /// Emitted when an entity is configured as a contact identity source.
+    ContactIdentitySourceSaved,
+    /// Emitted when an entity stops being a contact identity source.
+    ContactIdentitySourceDeleted,
+    /// Emitted when a record is manually linked to a master contact.
+    ContactIdentityLinked,
+    /// Emitted when a record is manually split from its master contact.
+    ContactIdentityUnlinked,
     /// Emitted when a custom role is created.
     SecurityRoleCreated,
     /// Emitted when a role is assigned to a subject.
@@ -228,18 +600,76 @@ pub enum AuditAction {
     SecurityRoleUnassigned,
     /// Emitted when runtime field permissions are updated for a subject.
     SecurityRuntimeFieldPermissionsSaved,
+    /// Emitted when a security team is created.
+    SecurityTeamCreated,
+    /// Emitted when a member is added to a security team.
+    SecurityTeamMemberAdded,
+    /// Emitted when a member is removed from a security team.
+    SecurityTeamMemberRemoved,
     /// Emitted when temporary privileged access is granted.
     SecurityTemporaryAccessGranted,
     /// Emitted when temporary privileged access is revoked.
     SecurityTemporaryAccessRevoked,
     /// Emitted when temporary privileged access is used for authorization.
     SecurityTemporaryAccessUsed,
+    /// Emitted when an administrator requests an MFA reset for a locked-out user.
+    SecurityMfaResetRequested,
+    /// Emitted when a second administrator approves an MFA reset and MFA is removed.
+    SecurityMfaResetApproved,
     /// Emitted when tenant registration mode is updated.
     SecurityTenantRegistrationModeUpdated,
     /// Emitted when audit retention policy is updated.
     SecurityAuditRetentionUpdated,
     /// Emitted when audit entries are purged by retention policy.
     SecurityAuditEntriesPurged,
+    /// Emitted when tenant workflow concurrency policy is updated.
+    SecurityTenantWorkflowConcurrencyUpdated,
+    /// Emitted when an audit export sink is created.
+    SecurityAuditExportSinkCreated,
+    /// Emitted when an audit export sink is enabled or paused.
+    SecurityAuditExportSinkUpdated,
+    /// Emitted when an audit export sink is deleted.
+    SecurityAuditExportSinkDeleted,
+    /// Emitted when a tenant-supplied encryption key is registered.
+    SecurityTenantEncryptionKeyRegistered,
+    /// Emitted when a tenant-supplied encryption key is rotated.
+    SecurityTenantEncryptionKeyRotated,
+    /// Emitted when tenant-supplied encryption keys are crypto-shredded.
+    SecurityTenantEncryptionKeysShredded,
+    /// Emitted when a legal hold is placed on records or audit data.
+    SecurityLegalHoldPlaced,
+    /// Emitted when a legal hold is released.
+    SecurityLegalHoldReleased,
+    /// Emitted when the dual-control field set for an entity is saved.
+    SecurityDualControlFieldsSaved,
+    /// Emitted when the personal data fields erased for an entity are saved.
+    SecurityDataErasureFieldsSaved,
+    /// Emitted when a subject's personal data is erased.
+    SecurityDataErased,
+    /// Emitted when the field masking rules of a role are saved.
+    SecurityFieldMaskingRulesSaved,
+    /// Emitted when a subject is assigned to a compliance zone.
+    SecurityComplianceZoneAssigned,
+    /// Emitted when a subject is removed from a compliance zone.
+    SecurityComplianceZoneUnassigned,
+    /// Emitted when an entity or record is restricted to a compliance zone.
+    SecurityComplianceZoneTagged,
+    /// Emitted when a compliance zone restriction is removed.
+    SecurityComplianceZoneUntagged,
+    /// Emitted when a feature flag is created or updated.
+    SecurityFeatureFlagSaved,
+    /// Emitted when a feature flag is deleted.
+    SecurityFeatureFlagDeleted,
+    /// Emitted when an authenticated session becomes scoped to a tenant.
+    SecuritySessionTenantEntered,
+    /// Emitted when an authenticated session leaves a tenant for another one.
+    SecuritySessionTenantExited,
+    /// Emitted when an announcement banner is published to the workspace.
+    TenantAnnouncementPublished,
+    /// Emitted when an announcement banner is edited.
+    TenantAnnouncementUpdated,
+    /// Emitted when an announcement banner is deleted.
+    TenantAnnouncementDeleted,
 }
 
 impl AuditAction {
@@ -249,32 +679,130 @@ impl AuditAction {
         match self {
             Self::AppCreated => "app.created",
             Self::AppEntityBound => "app.entity.bound",
+            Self::AppEntityUnbound => "app.entity.unbound",
             Self::AppRoleEntityPermissionSaved => "app.role_entity_permission.saved",
+            Self::AppAdminGranted => "app.admin.granted",
+            Self::AppAdminRevoked => "app.admin.revoked",
             Self::WorkflowSaved => "workflow.saved",
             Self::WorkflowPublished => "workflow.published",
             Self::WorkflowDisabled => "workflow.disabled",
             Self::WorkflowRunCompleted => "workflow.run.completed",
+            Self::WorkflowInboundWebhookConfigured => "workflow.inbound_webhook.configured",
+            Self::WorkflowInboundWebhookRevoked => "workflow.inbound_webhook.revoked",
+            Self::WorkflowCredentialSaved => "workflow.credential.saved",
+            Self::WorkflowCredentialDeleted => "workflow.credential.deleted",
+            Self::WorkflowBulkExecutionStarted => "workflow.bulk_execution.started",
+            Self::WorkflowJobReleased => "workflow.job.released",
+            Self::WorkflowJobFailed => "workflow.job.failed",
+            Self::BackgroundJobQueued => "background_job.queued",
+            Self::BackgroundJobCancelled => "background_job.cancelled",
+            Self::DataValidationScheduleSaved => "data_validation.schedule.saved",
+            Self::DataValidationScheduleDeleted => "data_validation.schedule.deleted",
+            Self::RecordImportTemplateSaved => "record_import.template.saved",
+            Self::RecordImportTemplateDeleted => "record_import.template.deleted",
+            Self::RecordImportScheduleSaved => "record_import.schedule.saved",
+            Self::RecordImportScheduleDeleted => "record_import.schedule.deleted",
+            Self::RecordImportCompleted => "record_import.completed",
+            Self::ReportingProjectionSaved => "reporting.projection.saved",
+            Self::ReportingProjectionDeleted => "reporting.projection.deleted",
+            Self::TenantDataExportLinkCreated => "tenant.data_export.link_created",
+            Self::TenantDataExportDownloaded => "tenant.data_export.downloaded",
             Self::MetadataEntityCreated => "metadata.entity.created",
+            Self::MetadataEntityDeactivated => "metadata.entity.deactivated",
+            Self::MetadataEntityReactivated => "metadata.entity.reactivated",
+            Self::MetadataEntityDeleted => "metadata.entity.deleted",
             Self::MetadataFieldSaved => "metadata.field.saved",
+            Self::MetadataRelationBehaviorSaved => "metadata.relation_behavior.saved",
+            Self::MetadataRelationLookupSaved => "metadata.relation_lookup.saved",
+            Self::MetadataRelationLookupDeleted => "metadata.relation_lookup.deleted",
+            Self::MetadataEntitySlugSaved => "metadata.entity_slug.saved",
+            Self::MetadataEntityStatusModelSaved => "metadata.entity_status_model.saved",
+            Self::MetadataEntityStatusModelDeleted => "metadata.entity_status_model.deleted",
+            Self::MetadataEntityHistoryPolicySaved => "metadata.entity_history_policy.saved",
+            Self::MetadataEntityHistoryPolicyDeleted => "metadata.entity_history_policy.deleted",
+            Self::MetadataDuplicateRuleSaved => "metadata.duplicate_rule.saved",
+            Self::MetadataDuplicateRuleDeleted => "metadata.duplicate_rule.deleted",
+            Self::MetadataLocalizedLabelSaved => "metadata.localized_label.saved",
+            Self::MetadataLocalizedLabelDeleted => "metadata.localized_label.deleted",
             Self::MetadataEntityPublished => "metadata.entity.published",
+            Self::MetadataEntitySchemaRolledBack => "metadata.entity.schema_rolled_back",
             Self::MetadataWorkspacePublished => "metadata.workspace.published",
+            Self::MetadataPublishIntentQueued => "metadata.publish_intent.queued",
+            Self::MetadataPublishIntentCancelled => "metadata.publish_intent.cancelled",
             Self::RuntimeRecordCreated => "runtime.record.created",
             Self::RuntimeRecordUpdated => "runtime.record.updated",
             Self::RuntimeRecordDeleted => "runtime.record.deleted",
+            Self::RuntimeRecordBulkUpdated => "runtime.record.bulk_updated",
+            Self::RuntimeRecordRestored => "runtime.record.restored",
+            Self::RuntimeRecordOwnerAssigned => "runtime.record.owner.assigned",
+            Self::RuntimeRecordAssociated => "runtime.record.associated",
+            Self::RuntimeRecordDisassociated => "runtime.record.disassociated",
+            Self::RuntimeRecordStatusChanged => "runtime.record.status.changed",
+            Self::RuntimeRecordBusinessRuleRejected => "runtime.record.business_rule_rejected",
+            Self::RuntimeFieldChangeRequested => "runtime.field_change.requested",
+            Self::RuntimeFieldChangeApproved => "runtime.field_change.approved",
+            Self::RuntimeFieldChangeRejected => "runtime.field_change.rejected",
+            Self::RuntimeRecordAccessRequested => "runtime.record_access.requested",
+            Self::RuntimeRecordAccessApproved => "runtime.record_access.approved",
+            Self::RuntimeRecordAccessRejected => "runtime.record_access.rejected",
+            Self::RuntimeRecordShareRevoked => "runtime.record_share.revoked",
+            Self::RuntimeRecordShareLinkCreated => "runtime.record_share_link.created",
+            Self::RuntimeRecordShareLinkRevoked => "runtime.record_share_link.revoked",
+            Self::ContactConsentGranted => "contact.consent.granted",
+            Self::ContactConsentRevoked => "contact.consent.revoked",
+            Self::ContactIdentitySourceSaved => "contact.identity.source.saved",
+            Self::ContactIdentitySourceDeleted => "contact.identity.source.deleted",
+            Self::ContactIdentityLinked => "contact.identity.linked",
+            Self::ContactIdentityUnlinked => "contact.identity.unlinked",
             Self::SecurityRoleCreated => "security.role.created",
             Self::SecurityRoleAssigned => "security.role.assigned",
             Self::SecurityRoleUnassigned => "security.role.unassigned",
             Self::SecurityRuntimeFieldPermissionsSaved => {
                 "security.runtime.field_permissions.saved"
             }
+            Self::SecurityTeamCreated => "security.team.created",
+            Self::SecurityTeamMemberAdded => "security.team.member.added",
+            Self::SecurityTeamMemberRemoved => "security.team.member.removed",
             Self::SecurityTemporaryAccessGranted => "security.temporary_access.granted",
             Self::SecurityTemporaryAccessRevoked => "security.temporary_access.revoked",
             Self::SecurityTemporaryAccessUsed => "security.temporary_access.used",
+            Self::SecurityMfaResetRequested => "security.mfa_reset.requested",
+            Self::SecurityMfaResetApproved => "security.mfa_reset.approved",
             Self::SecurityTenantRegistrationModeUpdated => {
                 "security.tenant.registration_mode.updated"
             }
             Self::SecurityAuditRetentionUpdated => "security.audit.retention.updated",
             Self::SecurityAuditEntriesPurged => "security.audit.entries.purged",
+            Self::SecurityTenantWorkflowConcurrencyUpdated => {
+                "security.tenant.workflow_concurrency.updated"
+            }
+            Self::SecurityAuditExportSinkCreated => "security.audit.export_sink.created",
+            Self::SecurityAuditExportSinkUpdated => "security.audit.export_sink.updated",
+            Self::SecurityAuditExportSinkDeleted => "security.audit.export_sink.deleted",
+            Self::SecurityTenantEncryptionKeyRegistered => {
+                "security.tenant.encryption_key.registered"
+            }
+            Self::SecurityTenantEncryptionKeyRotated => "security.tenant.encryption_key.rotated",
+            Self::SecurityTenantEncryptionKeysShredded => {
+                "security.tenant.encryption_keys.shredded"
+            }
+            Self::SecurityLegalHoldPlaced => "security.legal_hold.placed",
+            Self::SecurityLegalHoldReleased => "security.legal_hold.released",
+            Self::SecurityDualControlFieldsSaved => "security.dual_control.fields.saved",
+            Self::SecurityDataErasureFieldsSaved => "security.data_erasure.fields.saved",
+            Self::SecurityDataErased => "security.data_erasure.completed",
+            Self::SecurityFieldMaskingRulesSaved => "security.field_masking.rules.saved",
+            Self::SecurityComplianceZoneAssigned => "security.compliance_zone.assigned",
+            Self::SecurityComplianceZoneUnassigned => "security.compliance_zone.unassigned",
+            Self::SecurityComplianceZoneTagged => "security.compliance_zone.tagged",
+            Self::SecurityComplianceZoneUntagged => "security.compliance_zone.untagged",
+            Self::SecurityFeatureFlagSaved => "security.feature_flag.saved",
+            Self::SecurityFeatureFlagDeleted => "security.feature_flag.deleted",
+            Self::SecuritySessionTenantEntered => "security.session.tenant_entered",
+            Self::SecuritySessionTenantExited => "security.session.tenant_exited",
+            Self::TenantAnnouncementPublished => "tenant.announcement.published",
+            Self::TenantAnnouncementUpdated => "tenant.announcement.updated",
+            Self::TenantAnnouncementDeleted => "tenant.announcement.deleted",
         }
     }
 }
@@ -307,6 +835,14 @@ pub enum AuthEventType {
     EmailVerificationSent,
     /// Emitted when email verification completes.
     EmailVerificationCompleted,
+    /// Emitted when an authenticated user requests an email address change.
+    EmailChangeRequested,
+    /// Emitted when the current or new address confirms a pending email change.
+    EmailChangeConfirmed,
+    /// Emitted when both addresses have confirmed and the email change is applied.
+    EmailChangeCompleted,
+    /// Emitted when a pending email change is cancelled.
+    EmailChangeCancelled,
     /// Emitted when a tenant invite email is sent.
     InviteSent,
     /// Emitted when an invite token is accepted.
@@ -315,8 +851,14 @@ pub enum AuthEventType {
     PasskeyRegistrationCompleted,
     /// Emitted when a passkey login succeeds.
     PasskeyLogin,
+    /// Emitted when a user renames one of their passkeys.
+    PasskeyRenamed,
+    /// Emitted when a user deletes one of their passkeys.
+    PasskeyDeleted,
     /// Emitted when bootstrap token login succeeds.
     BootstrapLogin,
+    /// Emitted when an operator rotates the bootstrap token.
+    BootstrapTokenRotated,
     /// Emitted when an authenticated session logs out.
     SessionLogout,
     /// Emitted when an authenticated user switches tenant context.
@@ -342,11 +884,18 @@ impl AuthEventType {
             Self::PasswordResetCompleted => "auth.password.reset.completed",
             Self::EmailVerificationSent => "auth.email.verification.sent",
             Self::EmailVerificationCompleted => "auth.email.verification.completed",
+            Self::EmailChangeRequested => "auth.email.change.requested",
+            Self::EmailChangeConfirmed => "auth.email.change.confirmed",
+            Self::EmailChangeCompleted => "auth.email.change.completed",
+            Self::EmailChangeCancelled => "auth.email.change.cancelled",
             Self::InviteSent => "auth.invite.sent",
             Self::InviteAccepted => "auth.invite.accepted",
             Self::PasskeyRegistrationCompleted => "auth.passkey.registration.completed",
             Self::PasskeyLogin => "auth.passkey.login",
+            Self::PasskeyRenamed => "auth.passkey.renamed",
+            Self::PasskeyDeleted => "auth.passkey.deleted",
             Self::BootstrapLogin => "auth.bootstrap.login",
+            Self::BootstrapTokenRotated => "auth.bootstrap.token_rotated",
             Self::SessionLogout => "auth.session.logout",
             Self::SessionTenantSwitched => "auth.session.tenant_switched",
             Self::SessionStepUpVerification => "auth.session.step_up.verification",
@@ -391,7 +940,11 @@ impl AuthEventOutcome {
 mod tests {
     use std::str::FromStr;
 
-    use super::{AuthEventOutcome, AuthEventType, Permission, Surface};
+    use qryvanta_core::AppError;
+
+    use super::{
+        AuthEventOutcome, AuthEventType, Capability, Permission, PermissionGroup, Surface,
+    };
 
     #[test]
     fn permission_roundtrip_storage_value() {
@@ -404,6 +957,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn every_permission_has_catalog_metadata() {
+        for permission in Permission::all() {
+            assert!(PermissionGroup::all().contains(&permission.group()));
+            assert!(!permission.description().is_empty());
+            assert!(
+                permission
+                    .implied_permissions()
+                    .iter()
+                    .all(|implied| implied.group() == permission.group())
+            );
+        }
+    }
+
+    #[test]
+    fn grant_sets_must_include_implied_permissions() {
+        let incomplete = Permission::validate_grant_set(&[Permission::RuntimeRecordWrite]);
+        assert!(
+            matches!(incomplete, Err(AppError::Validation(message)) if message.contains("'runtime.record.write' requires 'runtime.record.read'"))
+        );
+
+        let transitive = Permission::validate_grant_set(&[
+            Permission::RuntimeRecordReactivate,
+            Permission::RuntimeRecordWrite,
+        ]);
+        assert!(transitive.is_err());
+
+        assert!(
+            Permission::validate_grant_set(&[
+                Permission::RuntimeRecordRead,
+                Permission::RuntimeRecordWrite,
+                Permission::RuntimeRecordReactivate,
+            ])
+            .is_ok()
+        );
+    }
+
     #[test]
     fn unknown_permission_is_rejected() {
         let parsed = Permission::from_str("metadata.entity.unknown");
@@ -436,6 +1026,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn every_capability_has_at_least_one_permission() {
+        for capability in Capability::all() {
+            assert!(
+                !capability.required_permissions().is_empty(),
+                "capability {:?} must have at least one required permission",
+                capability
+            );
+        }
+    }
+
     #[test]
     fn auth_event_type_storage_values_are_stable() {
         assert_eq!(AuthEventType::PasswordLogin.as_str(), "auth.password.login");
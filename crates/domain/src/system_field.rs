@@ -0,0 +1,98 @@
+use qryvanta_core::AppResult;
+
+use crate::{EntityFieldDefinition, FieldType};
+
+/// Platform-managed audit field stamped on every runtime record.
+///
+/// System fields are part of each published schema, can be filtered and
+/// sorted like regular fields, and are never writable by clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SystemField {
+    /// Subject that created the record.
+    CreatedBy,
+    /// Creation timestamp.
+    CreatedAt,
+    /// Subject that last changed the record data.
+    ModifiedBy,
+    /// Timestamp of the last data change.
+    ModifiedAt,
+    /// Subject that currently owns the record.
+    Owner,
+}
+
+impl SystemField {
+    /// All system fields in presentation order.
+    pub const ALL: [Self; 5] = [
+        Self::CreatedBy,
+        Self::CreatedAt,
+        Self::ModifiedBy,
+        Self::ModifiedAt,
+        Self::Owner,
+    ];
+
+    /// Returns the reserved field logical name.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::CreatedBy => "created_by",
+            Self::CreatedAt => "created_at",
+            Self::ModifiedBy => "modified_by",
+            Self::ModifiedAt => "modified_at",
+            Self::Owner => "owner",
+        }
+    }
+
+    /// Resolves a system field from its reserved logical name.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|field| field.as_str() == value)
+    }
+
+    /// Returns the display name used in published schemas.
+    #[must_use]
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::CreatedBy => "Created By",
+            Self::CreatedAt => "Created At",
+            Self::ModifiedBy => "Modified By",
+            Self::ModifiedAt => "Modified At",
+            Self::Owner => "Owner",
+        }
+    }
+
+    /// Returns the runtime value type of the field.
+    #[must_use]
+    pub fn field_type(self) -> FieldType {
+        match self {
+            Self::CreatedAt | Self::ModifiedAt => FieldType::DateTime,
+            Self::CreatedBy | Self::ModifiedBy | Self::Owner => FieldType::Text,
+        }
+    }
+
+    /// Builds the read-only field definition exposed for an entity.
+    pub fn field_definition(self, entity_logical_name: &str) -> AppResult<EntityFieldDefinition> {
+        EntityFieldDefinition::new(
+            entity_logical_name,
+            self.as_str(),
+            self.display_name(),
+            self.field_type(),
+            false,
+            false,
+            None,
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SystemField;
+
+    #[test]
+    fn system_field_names_round_trip() {
+        for field in SystemField::ALL {
+            assert_eq!(SystemField::parse(field.as_str()), Some(field));
+        }
+        assert_eq!(SystemField::parse("name"), None);
+    }
+}
@@ -0,0 +1,111 @@
+use std::str::FromStr;
+
+use qryvanta_core::{AppError, AppResult, NonEmptyString};
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of a team name.
+pub const TEAM_NAME_MAX_LENGTH: usize = 100;
+
+/// Kind of principal that role assignments and field grants target.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubjectType {
+    /// An individual tenant member identified by subject.
+    #[default]
+    User,
+    /// A tenant team; grants apply to every member.
+    Team,
+}
+
+impl SubjectType {
+    /// Returns a stable storage value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Team => "team",
+        }
+    }
+}
+
+impl FromStr for SubjectType {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "user" => Ok(Self::User),
+            "team" => Ok(Self::Team),
+            _ => Err(AppError::Validation(format!(
+                "unknown subject type '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Named group of tenant members that can hold roles and field grants.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamDefinition {
+    name: NonEmptyString,
+    description: Option<String>,
+}
+
+impl TeamDefinition {
+    /// Creates a validated team definition.
+    pub fn new(name: impl Into<String>, description: Option<String>) -> AppResult<Self> {
+        let name = NonEmptyString::new(name.into().trim())?;
+        if name.as_str().chars().count() > TEAM_NAME_MAX_LENGTH {
+            return Err(AppError::Validation(format!(
+                "team name must be at most {TEAM_NAME_MAX_LENGTH} characters"
+            )));
+        }
+
+        let description = description.and_then(|value| {
+            let trimmed = value.trim().to_owned();
+            (!trimmed.is_empty()).then_some(trimmed)
+        });
+
+        Ok(Self { name, description })
+    }
+
+    /// Returns the unique team name.
+    #[must_use]
+    pub fn name(&self) -> &NonEmptyString {
+        &self.name
+    }
+
+    /// Returns an optional team description.
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{SubjectType, TEAM_NAME_MAX_LENGTH, TeamDefinition};
+
+    #[test]
+    fn subject_type_roundtrip_storage_value() {
+        for subject_type in [SubjectType::User, SubjectType::Team] {
+            let restored = SubjectType::from_str(subject_type.as_str());
+            assert_eq!(restored.ok(), Some(subject_type));
+        }
+        assert!(SubjectType::from_str("group").is_err());
+    }
+
+    #[test]
+    fn team_definition_trims_name_and_blank_description() {
+        let team = TeamDefinition::new("  Field Sales ", Some("   ".to_owned()))
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(team.name().as_str(), "Field Sales");
+        assert_eq!(team.description(), None);
+    }
+
+    #[test]
+    fn team_definition_rejects_blank_or_long_names() {
+        assert!(TeamDefinition::new("  ", None).is_err());
+        assert!(TeamDefinition::new("a".repeat(TEAM_NAME_MAX_LENGTH + 1), None).is_err());
+    }
+}
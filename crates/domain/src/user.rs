@@ -221,6 +221,10 @@ pub enum AuthTokenType {
     PasswordReset,
     /// Tenant invite token.
     Invite,
+    /// Single-use bootstrap login token.
+    Bootstrap,
+    /// Email change confirmation token for the current or new address.
+    EmailChange,
 }
 
 impl AuthTokenType {
@@ -231,6 +235,8 @@ impl AuthTokenType {
             Self::EmailVerification => "email_verification",
             Self::PasswordReset => "password_reset",
             Self::Invite => "invite",
+            Self::Bootstrap => "bootstrap",
+            Self::EmailChange => "email_change",
         }
     }
 }
@@ -243,6 +249,8 @@ impl FromStr for AuthTokenType {
             "email_verification" => Ok(Self::EmailVerification),
             "password_reset" => Ok(Self::PasswordReset),
             "invite" => Ok(Self::Invite),
+            "bootstrap" => Ok(Self::Bootstrap),
+            "email_change" => Ok(Self::EmailChange),
             _ => Err(AppError::Validation(format!(
                 "unknown auth token type '{value}'"
             ))),
@@ -80,6 +80,39 @@ pub enum FilterOperator {
     In,
 }
 
+/// Filter value token resolved server-side against the querying subject.
+///
+/// Tokens let one saved view express ownership scoping for every user, e.g.
+/// `owner eq "@me"` for "My records".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubjectFilterToken {
+    /// `@me`: the querying subject.
+    CurrentSubject,
+    /// `@my_team`: the querying subject and every subject sharing a security team with it.
+    CurrentTeam,
+}
+
+impl SubjectFilterToken {
+    /// Returns the token as written in filter values.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::CurrentSubject => "@me",
+            Self::CurrentTeam => "@my_team",
+        }
+    }
+
+    /// Resolves a token from a filter value, if the value is one.
+    #[must_use]
+    pub fn parse(value: &Value) -> Option<Self> {
+        match value.as_str()? {
+            "@me" => Some(Self::CurrentSubject),
+            "@my_team" => Some(Self::CurrentTeam),
+            _ => None,
+        }
+    }
+}
+
 /// View column definition.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ViewColumn {
@@ -150,6 +183,12 @@ impl ViewSort {
     pub fn field_logical_name(&self) -> &NonEmptyString {
         &self.field_logical_name
     }
+
+    /// Returns sort direction.
+    #[must_use]
+    pub fn direction(&self) -> SortDirection {
+        self.direction
+    }
 }
 
 /// One filter condition in a view filter group.
@@ -179,6 +218,73 @@ impl ViewFilterCondition {
     pub fn field_logical_name(&self) -> &NonEmptyString {
         &self.field_logical_name
     }
+
+    /// Returns condition operator.
+    #[must_use]
+    pub fn operator(&self) -> FilterOperator {
+        self.operator
+    }
+
+    /// Returns condition comparison value.
+    #[must_use]
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Evaluates the condition against runtime record data.
+    ///
+    /// Mirrors runtime query semantics: a missing field never matches,
+    /// `contains` ignores case and ordered comparisons only apply to two
+    /// numbers or two strings. Subject tokens never match because no
+    /// querying subject is known.
+    #[must_use]
+    pub fn matches(&self, data: &Value) -> bool {
+        let Some(value) = data
+            .get(self.field_logical_name.as_str())
+            .filter(|value| !value.is_null())
+        else {
+            return false;
+        };
+        if self.uses_subject_token() {
+            return false;
+        }
+
+        let ordering = || match (value, &self.value) {
+            (Value::Number(left), Value::Number(right)) => left
+                .as_f64()
+                .zip(right.as_f64())
+                .and_then(|(left, right)| left.partial_cmp(&right)),
+            (Value::String(left), Value::String(right)) => Some(left.cmp(right)),
+            _ => None,
+        };
+
+        match self.operator {
+            FilterOperator::Eq => value == &self.value,
+            FilterOperator::Neq => value != &self.value,
+            FilterOperator::Gt => ordering().is_some_and(|ordering| ordering.is_gt()),
+            FilterOperator::Gte => ordering().is_some_and(|ordering| ordering.is_ge()),
+            FilterOperator::Lt => ordering().is_some_and(|ordering| ordering.is_lt()),
+            FilterOperator::Lte => ordering().is_some_and(|ordering| ordering.is_le()),
+            FilterOperator::Contains => {
+                value
+                    .as_str()
+                    .zip(self.value.as_str())
+                    .is_some_and(|(stored, expected)| {
+                        stored.to_lowercase().contains(&expected.to_lowercase())
+                    })
+            }
+            FilterOperator::In => self
+                .value
+                .as_array()
+                .is_some_and(|candidates| candidates.contains(value)),
+        }
+    }
+
+    /// Returns whether the comparison value is a subject token such as `@me`.
+    #[must_use]
+    pub fn uses_subject_token(&self) -> bool {
+        SubjectFilterToken::parse(&self.value).is_some()
+    }
 }
 
 /// Grouped view filter criteria.
@@ -203,11 +309,32 @@ impl ViewFilterGroup {
         })
     }
 
+    /// Returns group logical mode.
+    #[must_use]
+    pub fn logical_mode(&self) -> LogicalMode {
+        self.logical_mode
+    }
+
     /// Returns group conditions.
     #[must_use]
     pub fn conditions(&self) -> &[ViewFilterCondition] {
         &self.conditions
     }
+
+    /// Evaluates the group against runtime record data.
+    #[must_use]
+    pub fn matches(&self, data: &Value) -> bool {
+        match self.logical_mode {
+            LogicalMode::And => self
+                .conditions
+                .iter()
+                .all(|condition| condition.matches(data)),
+            LogicalMode::Or => self
+                .conditions
+                .iter()
+                .any(|condition| condition.matches(data)),
+        }
+    }
 }
 
 /// Standalone view definition.
@@ -348,7 +475,26 @@ fn positions_are_contiguous(mut positions: Vec<i32>) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::{ViewColumn, ViewDefinition, ViewType};
+    use serde_json::json;
+
+    use super::{
+        FilterOperator, LogicalMode, SubjectFilterToken, ViewColumn, ViewDefinition,
+        ViewFilterCondition, ViewFilterGroup, ViewType,
+    };
+
+    #[test]
+    fn subject_filter_tokens_parse_only_known_string_values() {
+        assert_eq!(
+            SubjectFilterToken::parse(&json!("@me")),
+            Some(SubjectFilterToken::CurrentSubject)
+        );
+        assert_eq!(
+            SubjectFilterToken::parse(&json!("@my_team")),
+            Some(SubjectFilterToken::CurrentTeam)
+        );
+        assert_eq!(SubjectFilterToken::parse(&json!("me")), None);
+        assert_eq!(SubjectFilterToken::parse(&json!(["@me"])), None);
+    }
 
     #[test]
     fn view_column_rejects_negative_position() {
@@ -419,4 +565,38 @@ mod tests {
             .collect();
         assert_eq!(column_order, vec!["name", "email", "phone"]);
     }
+
+    #[test]
+    fn view_filter_groups_match_record_data() {
+        let condition = |field: &str, operator, value| {
+            ViewFilterCondition::new(field, operator, value).unwrap_or_else(|_| unreachable!())
+        };
+        let record = json!({"status": "Open", "amount": 250, "title": "Renewal Quote"});
+
+        let open_and_large = ViewFilterGroup::new(
+            LogicalMode::And,
+            vec![
+                condition("status", FilterOperator::In, json!(["Open", "Pending"])),
+                condition("amount", FilterOperator::Gte, json!(250.0)),
+                condition("title", FilterOperator::Contains, json!("quote")),
+            ],
+        )
+        .unwrap_or_else(|_| unreachable!());
+        assert!(open_and_large.matches(&record));
+        assert!(!open_and_large.matches(&json!({"status": "Open", "amount": 10})));
+
+        let closed_or_owned = ViewFilterGroup::new(
+            LogicalMode::Or,
+            vec![
+                condition("status", FilterOperator::Eq, json!("Closed")),
+                condition("owner", FilterOperator::Eq, json!("@me")),
+            ],
+        )
+        .unwrap_or_else(|_| unreachable!());
+        assert!(!closed_or_owned.matches(&json!({"status": "Open", "owner": "@me"})));
+        assert!(closed_or_owned.matches(&json!({"status": "Closed"})));
+
+        assert!(!condition("amount", FilterOperator::Lt, json!("300")).matches(&record));
+        assert!(!condition("missing", FilterOperator::Neq, json!("x")).matches(&record));
+    }
 }
@@ -2,6 +2,8 @@ use qryvanta_core::{AppError, AppResult, NonEmptyString, validate_secret_referen
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::CronSchedule;
+
 /// Stable workflow release lifecycle states.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -38,6 +40,76 @@ impl WorkflowLifecycleState {
     }
 }
 
+/// Queue priority of workflow runs.
+///
+/// Workers claim higher priorities first and runs of equal priority in
+/// creation order.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowRunPriority {
+    /// Bulk and backfill work that may wait behind everything else.
+    Low,
+    /// Default priority.
+    #[default]
+    Normal,
+    /// Work that should overtake normal runs.
+    High,
+    /// Work that should overtake every other run.
+    Urgent,
+}
+
+impl WorkflowRunPriority {
+    /// Every priority, lowest first.
+    pub const ALL: [Self; 4] = [Self::Low, Self::Normal, Self::High, Self::Urgent];
+
+    /// Returns stable storage value.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Normal => "normal",
+            Self::High => "high",
+            Self::Urgent => "urgent",
+        }
+    }
+
+    /// Parses a stable storage value.
+    pub fn parse(value: &str) -> AppResult<Self> {
+        match value {
+            "low" => Ok(Self::Low),
+            "normal" => Ok(Self::Normal),
+            "high" => Ok(Self::High),
+            "urgent" => Ok(Self::Urgent),
+            _ => Err(AppError::Validation(format!(
+                "unknown workflow run priority '{value}'"
+            ))),
+        }
+    }
+
+    /// Returns the rank persisted for claim ordering; higher ranks are claimed first.
+    #[must_use]
+    pub fn rank(self) -> i16 {
+        match self {
+            Self::Low => 0,
+            Self::Normal => 1,
+            Self::High => 2,
+            Self::Urgent => 3,
+        }
+    }
+
+    /// Parses a persisted claim-ordering rank.
+    pub fn from_rank(rank: i16) -> AppResult<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|priority| priority.rank() == rank)
+            .ok_or_else(|| {
+                AppError::Validation(format!("unknown workflow run priority rank '{rank}'"))
+            })
+    }
+}
+
 /// Workflow trigger source.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -59,11 +131,21 @@ pub enum WorkflowTrigger {
         /// Entity logical name that emits the trigger.
         entity_logical_name: String,
     },
+    /// Runtime record status change trigger.
+    RuntimeRecordStatusChanged {
+        /// Entity logical name that emits the trigger.
+        entity_logical_name: String,
+    },
     /// Scheduler tick trigger.
     ScheduleTick {
         /// Schedule key for the tick source (for example: hourly, daily_utc_0900).
         schedule_key: String,
     },
+    /// Cron-scheduled trigger evaluated in UTC.
+    Scheduled {
+        /// Five-field cron expression (for example: `0 9 * * mon-fri`).
+        cron_expression: String,
+    },
     /// Inbound webhook trigger.
     WebhookReceived {
         /// Stable webhook key routed from the ingress endpoint.
@@ -95,7 +177,9 @@ impl WorkflowTrigger {
             Self::RuntimeRecordCreated { .. } => "runtime_record_created",
             Self::RuntimeRecordUpdated { .. } => "runtime_record_updated",
             Self::RuntimeRecordDeleted { .. } => "runtime_record_deleted",
+            Self::RuntimeRecordStatusChanged { .. } => "runtime_record_status_changed",
             Self::ScheduleTick { .. } => "schedule_tick",
+            Self::Scheduled { .. } => "scheduled",
             Self::WebhookReceived { .. } => "webhook_received",
             Self::FormSubmitted { .. } => "form_submitted",
             Self::InboundEmailReceived { .. } => "inbound_email_received",
@@ -117,13 +201,43 @@ impl WorkflowTrigger {
             Self::RuntimeRecordDeleted {
                 entity_logical_name,
             } => Some(entity_logical_name.as_str()),
+            Self::RuntimeRecordStatusChanged {
+                entity_logical_name,
+            } => Some(entity_logical_name.as_str()),
             Self::ScheduleTick { schedule_key } => Some(schedule_key.as_str()),
+            Self::Scheduled { cron_expression } => Some(cron_expression.as_str()),
             Self::WebhookReceived { webhook_key } => Some(webhook_key.as_str()),
             Self::FormSubmitted { form_key } => Some(form_key.as_str()),
             Self::InboundEmailReceived { mailbox_key } => Some(mailbox_key.as_str()),
             Self::ApprovalEventReceived { approval_key } => Some(approval_key.as_str()),
         }
     }
+
+    /// Returns the entity whose runtime records fire the trigger, if any.
+    #[must_use]
+    pub fn runtime_record_entity_logical_name(&self) -> Option<&str> {
+        match self {
+            Self::RuntimeRecordCreated {
+                entity_logical_name,
+            }
+            | Self::RuntimeRecordUpdated {
+                entity_logical_name,
+            }
+            | Self::RuntimeRecordDeleted {
+                entity_logical_name,
+            }
+            | Self::RuntimeRecordStatusChanged {
+                entity_logical_name,
+            } => Some(entity_logical_name.as_str()),
+            Self::Manual
+            | Self::ScheduleTick { .. }
+            | Self::Scheduled { .. }
+            | Self::WebhookReceived { .. }
+            | Self::FormSubmitted { .. }
+            | Self::InboundEmailReceived { .. }
+            | Self::ApprovalEventReceived { .. } => None,
+        }
+    }
 }
 
 /// Condition operator used by workflow branch steps.
@@ -138,6 +252,283 @@ pub enum WorkflowConditionOperator {
     Exists,
 }
 
+/// Comparison applied by a record-change trigger filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowTriggerFilterOperator {
+    /// True when the field value differs between the previous and current record.
+    Changed,
+    /// True when the current field value equals the configured value.
+    Equals,
+    /// True when the current field value does not equal the configured value.
+    NotEquals,
+    /// True when the field changed away from the configured previous value.
+    ChangedFrom,
+    /// True when the field changed to the configured current value.
+    ChangedTo,
+}
+
+impl WorkflowTriggerFilterOperator {
+    /// Returns whether the operator compares against a configured value.
+    #[must_use]
+    pub fn requires_value(self) -> bool {
+        !matches!(self, Self::Changed)
+    }
+}
+
+/// Field condition a record-change event must satisfy before a run is started.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowTriggerFilter {
+    /// Field logical name on the trigger entity.
+    pub field_logical_name: String,
+    /// Comparison to apply.
+    pub operator: WorkflowTriggerFilterOperator,
+    /// Comparison value for value-based operators.
+    #[serde(default)]
+    pub value: Option<Value>,
+}
+
+impl WorkflowTriggerFilter {
+    /// Evaluates the filter against the previous and current record data.
+    ///
+    /// A missing field and an explicit `null` are treated as the same value.
+    #[must_use]
+    pub fn matches(&self, previous: Option<&Value>, current: Option<&Value>) -> bool {
+        let previous_value = self.field_value(previous);
+        let current_value = self.field_value(current);
+        let changed = previous_value != current_value;
+        let expected = self.value.as_ref().filter(|value| !value.is_null());
+
+        match self.operator {
+            WorkflowTriggerFilterOperator::Changed => changed,
+            WorkflowTriggerFilterOperator::Equals => current_value == expected,
+            WorkflowTriggerFilterOperator::NotEquals => current_value != expected,
+            WorkflowTriggerFilterOperator::ChangedFrom => changed && previous_value == expected,
+            WorkflowTriggerFilterOperator::ChangedTo => changed && current_value == expected,
+        }
+    }
+
+    fn field_value<'a>(&self, record: Option<&'a Value>) -> Option<&'a Value> {
+        record
+            .and_then(|data| data.get(self.field_logical_name.as_str()))
+            .filter(|value| !value.is_null())
+    }
+}
+
+/// Maximum number of trigger filters one workflow may configure.
+pub const MAX_WORKFLOW_TRIGGER_FILTERS: usize = 20;
+
+/// Maximum number of delivery attempts an HTTP request step may configure.
+pub const WORKFLOW_HTTP_RETRY_MAX_ATTEMPTS: u8 = 10;
+
+/// Maximum linear backoff base an HTTP request step may configure.
+pub const WORKFLOW_HTTP_RETRY_MAX_BACKOFF_MS: u64 = 60_000;
+
+/// Maximum length of a step `output_key`.
+pub const WORKFLOW_STEP_OUTPUT_KEY_MAX_LENGTH: usize = 64;
+
+/// Per-step retry policy for outbound HTTP request steps.
+///
+/// Transport errors, `429` and `5xx` responses are retried with a linear
+/// backoff of `backoff_ms * attempt` until `max_attempts` is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowHttpRetryPolicy {
+    /// Total delivery attempts including the first one.
+    pub max_attempts: u8,
+    /// Backoff base in milliseconds between attempts.
+    pub backoff_ms: u64,
+}
+
+/// Maximum number of executions a step retry policy may configure.
+pub const WORKFLOW_STEP_RETRY_MAX_ATTEMPTS: u8 = 10;
+
+/// Maximum backoff base a step retry policy may configure.
+pub const WORKFLOW_STEP_RETRY_MAX_BACKOFF_MS: u64 = 60_000;
+
+/// How the delay between retries of a failed step grows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowStepBackoffStrategy {
+    /// Waits `backoff_ms` before every retry.
+    #[default]
+    Fixed,
+    /// Waits `backoff_ms * retry` before each retry.
+    Linear,
+    /// Waits `backoff_ms * 2^(retry - 1)` before each retry.
+    Exponential,
+}
+
+/// Class of step failure a retry policy can retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowStepRetryErrorClass {
+    /// Invalid input or violated invariant.
+    Validation,
+    /// Missing target resource.
+    NotFound,
+    /// Conflicting write.
+    Conflict,
+    /// Missing authentication.
+    Unauthorized,
+    /// Blocked by authorization policy.
+    Forbidden,
+    /// Rate limit exceeded.
+    RateLimited,
+    /// Unexpected or transient failure, including integration errors and timeouts.
+    Internal,
+}
+
+impl WorkflowStepRetryErrorClass {
+    /// Classifies an application error.
+    #[must_use]
+    pub fn of(error: &AppError) -> Self {
+        match error {
+            AppError::Validation(_) => Self::Validation,
+            AppError::NotFound(_) => Self::NotFound,
+            AppError::Conflict(_) => Self::Conflict,
+            AppError::Unauthorized(_) => Self::Unauthorized,
+            AppError::Forbidden(_) => Self::Forbidden,
+            AppError::RateLimited(_) => Self::RateLimited,
+            AppError::Internal(_) | AppError::Timeout(_) => Self::Internal,
+        }
+    }
+}
+
+/// Retry policy for one workflow step.
+///
+/// A failed step is executed again within the same run attempt while its error
+/// class is listed in `retry_on` and fewer than `max_attempts` executions were
+/// made. Once retries are exhausted the step fails the attempt and the
+/// workflow-level `max_attempts` applies.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowStepRetryPolicy {
+    /// Total step executions including the first one.
+    pub max_attempts: u8,
+    /// Delay growth between retries.
+    #[serde(default)]
+    pub backoff: WorkflowStepBackoffStrategy,
+    /// Backoff base in milliseconds.
+    #[serde(default)]
+    pub backoff_ms: u64,
+    /// Error classes that are retried; rate-limited and internal errors by default.
+    #[serde(default = "WorkflowStepRetryPolicy::default_retry_on")]
+    pub retry_on: Vec<WorkflowStepRetryErrorClass>,
+}
+
+impl WorkflowStepRetryPolicy {
+    /// Returns the error classes retried when `retry_on` is omitted.
+    #[must_use]
+    pub fn default_retry_on() -> Vec<WorkflowStepRetryErrorClass> {
+        vec![
+            WorkflowStepRetryErrorClass::RateLimited,
+            WorkflowStepRetryErrorClass::Internal,
+        ]
+    }
+
+    /// Returns whether a failed execution should be retried.
+    ///
+    /// `executions` is the number of executions made so far, including the failed one.
+    #[must_use]
+    pub fn should_retry(&self, error: &AppError, executions: u32) -> bool {
+        executions < u32::from(self.max_attempts)
+            && self
+                .retry_on
+                .contains(&WorkflowStepRetryErrorClass::of(error))
+    }
+
+    /// Returns the delay in milliseconds before the given retry, starting at 1.
+    #[must_use]
+    pub fn backoff_before_retry_ms(&self, retry: u32) -> u64 {
+        let retry = retry.max(1);
+        match self.backoff {
+            WorkflowStepBackoffStrategy::Fixed => self.backoff_ms,
+            WorkflowStepBackoffStrategy::Linear => self.backoff_ms.saturating_mul(u64::from(retry)),
+            WorkflowStepBackoffStrategy::Exponential => self
+                .backoff_ms
+                .saturating_mul(2_u64.saturating_pow(retry - 1)),
+        }
+    }
+}
+
+/// Maximum number of relation hops a condition `related.` path may traverse.
+pub const WORKFLOW_RELATED_LOOKUP_MAX_DEPTH: usize = 3;
+
+/// Condition `field_path` that reads a field from a record related to the trigger record.
+///
+/// Written as `related.<relation_field>[.<relation_field>...].<field>`: every
+/// segment but the last names a relation field followed from the trigger record,
+/// and the last segment names the field read from the final related record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowRelatedFieldPath<'a> {
+    relation_fields: Vec<&'a str>,
+    field_logical_name: &'a str,
+}
+
+impl<'a> WorkflowRelatedFieldPath<'a> {
+    /// Parses a condition path, returning `None` when it does not start with `related.`.
+    pub fn parse(field_path: &'a str) -> AppResult<Option<Self>> {
+        let Some(path) = field_path.trim().strip_prefix("related.") else {
+            return Ok(None);
+        };
+
+        let mut segments = path.split('.').collect::<Vec<_>>();
+        if segments.iter().any(|segment| segment.trim().is_empty()) {
+            return Err(AppError::Validation(format!(
+                "condition step related path '{field_path}' must not contain empty segments"
+            )));
+        }
+
+        let field_logical_name = segments.pop().unwrap_or_default();
+        if segments.is_empty() {
+            return Err(AppError::Validation(format!(
+                "condition step related path '{field_path}' must name a relation field and a related field"
+            )));
+        }
+
+        if segments.len() > WORKFLOW_RELATED_LOOKUP_MAX_DEPTH {
+            return Err(AppError::Validation(format!(
+                "condition step related path '{field_path}' must traverse at most {WORKFLOW_RELATED_LOOKUP_MAX_DEPTH} relations"
+            )));
+        }
+
+        Ok(Some(Self {
+            relation_fields: segments,
+            field_logical_name,
+        }))
+    }
+
+    /// Returns relation fields in traversal order, starting at the trigger record.
+    #[must_use]
+    pub fn relation_fields(&self) -> &[&'a str] {
+        &self.relation_fields
+    }
+
+    /// Returns the field read from the last related record.
+    #[must_use]
+    pub fn field_logical_name(&self) -> &'a str {
+        self.field_logical_name
+    }
+}
+
+/// Maximum nesting depth of runs started by invoke workflow steps.
+///
+/// A top-level run has depth zero; each invoked child run is one deeper.
+pub const WORKFLOW_INVOCATION_MAX_DEPTH: usize = 5;
+
+/// How an invoke workflow step runs the invoked workflow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowInvocationMode {
+    /// Runs the child workflow inline and fails the step when the child run fails.
+    #[default]
+    Synchronous,
+    /// Enqueues the child run for a worker and continues immediately.
+    Enqueued,
+}
+
+/// Longest duration a wait step may suspend a run for (one year).
+pub const MAX_WAIT_DURATION_SECONDS: u64 = 366 * 86_400;
+
 /// One workflow canvas step.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -153,6 +544,12 @@ pub enum WorkflowStep {
         entity_logical_name: String,
         /// JSON object payload for record creation.
         data: Value,
+        /// Optional run context key exposing this step's output as `{{steps.<key>.*}}`.
+        #[serde(default)]
+        output_key: Option<String>,
+        /// Optional retry policy applied when this step fails.
+        #[serde(default)]
+        retry_policy: Option<WorkflowStepRetryPolicy>,
     },
     /// Runtime record update step.
     UpdateRuntimeRecord {
@@ -162,6 +559,9 @@ pub enum WorkflowStep {
         record_id: String,
         /// JSON object payload for record update.
         data: Value,
+        /// Optional retry policy applied when this step fails.
+        #[serde(default)]
+        retry_policy: Option<WorkflowStepRetryPolicy>,
     },
     /// Runtime record delete step.
     DeleteRuntimeRecord {
@@ -169,6 +569,9 @@ pub enum WorkflowStep {
         entity_logical_name: String,
         /// Target record identifier.
         record_id: String,
+        /// Optional retry policy applied when this step fails.
+        #[serde(default)]
+        retry_policy: Option<WorkflowStepRetryPolicy>,
     },
     /// Outbound email delivery step.
     SendEmail {
@@ -180,6 +583,9 @@ pub enum WorkflowStep {
         body: String,
         /// Optional HTML body for rich email rendering.
         html_body: Option<String>,
+        /// Optional retry policy applied when this step fails.
+        #[serde(default)]
+        retry_policy: Option<WorkflowStepRetryPolicy>,
     },
     /// Outbound HTTP request action step.
     HttpRequest {
@@ -191,8 +597,23 @@ pub enum WorkflowStep {
         headers: Option<Value>,
         /// Optional HTTP header -> secret reference map.
         header_secret_refs: Option<Value>,
+        /// Optional workflow credential injected at dispatch time.
+        #[serde(default)]
+        credential: Option<String>,
         /// Optional request body payload.
         body: Option<Value>,
+        /// Optional exact response status required for success; any `2xx` when unset.
+        #[serde(default)]
+        expected_status: Option<u16>,
+        /// Optional retry policy overriding the dispatcher defaults.
+        #[serde(default)]
+        retry: Option<WorkflowHttpRetryPolicy>,
+        /// Optional run context key exposing this step's output as `{{steps.<key>.*}}`.
+        #[serde(default)]
+        output_key: Option<String>,
+        /// Optional retry policy applied when this step fails.
+        #[serde(default)]
+        retry_policy: Option<WorkflowStepRetryPolicy>,
     },
     /// Outbound webhook dispatch step.
     Webhook {
@@ -204,8 +625,14 @@ pub enum WorkflowStep {
         headers: Option<Value>,
         /// Optional webhook header -> secret reference map.
         header_secret_refs: Option<Value>,
+        /// Optional workflow credential injected at dispatch time.
+        #[serde(default)]
+        credential: Option<String>,
         /// JSON object payload sent to the endpoint.
         payload: Value,
+        /// Optional retry policy applied when this step fails.
+        #[serde(default)]
+        retry_policy: Option<WorkflowStepRetryPolicy>,
     },
     /// Assigns ownership of a target record.
     AssignOwner {
@@ -217,6 +644,9 @@ pub enum WorkflowStep {
         owner_id: String,
         /// Optional assignment reason.
         reason: Option<String>,
+        /// Optional retry policy applied when this step fails.
+        #[serde(default)]
+        retry_policy: Option<WorkflowStepRetryPolicy>,
     },
     /// Creates an approval request for a target record.
     ApprovalRequest {
@@ -234,6 +664,9 @@ pub enum WorkflowStep {
         reason: Option<String>,
         /// Optional structured request payload.
         payload: Option<Value>,
+        /// Optional retry policy applied when this step fails.
+        #[serde(default)]
+        retry_policy: Option<WorkflowStepRetryPolicy>,
     },
     /// In-worker delay step.
     Delay {
@@ -242,6 +675,51 @@ pub enum WorkflowStep {
         /// Optional operator-facing reason for the delay.
         reason: Option<String>,
     },
+    /// Durable wait that suspends the run and resumes it from a queued job.
+    ///
+    /// Exactly one of `duration_seconds` and `until_field_path` is set.
+    Wait {
+        /// Seconds to wait after the step is reached.
+        #[serde(default)]
+        duration_seconds: Option<u64>,
+        /// Dot-separated payload path holding an RFC 3339 timestamp or date to wait for.
+        #[serde(default)]
+        until_field_path: Option<String>,
+        /// Optional operator-facing reason for the wait.
+        #[serde(default)]
+        reason: Option<String>,
+    },
+    /// Starts a run of another published workflow.
+    InvokeWorkflow {
+        /// Logical name of the invoked workflow.
+        workflow_logical_name: String,
+        /// JSON object passed as the child run trigger payload.
+        payload: Value,
+        /// Whether the child run executes inline or on the job queue.
+        #[serde(default)]
+        mode: WorkflowInvocationMode,
+        /// Optional run context key exposing this step's output as `{{steps.<key>.*}}`.
+        #[serde(default)]
+        output_key: Option<String>,
+        /// Optional retry policy applied when this step fails.
+        #[serde(default)]
+        retry_policy: Option<WorkflowStepRetryPolicy>,
+    },
+    /// Step group that handles failures of its try steps.
+    ///
+    /// When a try step fails, compensation steps run first to undo earlier work,
+    /// then catch steps run. The failure is handled when catch steps are defined
+    /// and succeed; otherwise it still fails the attempt after compensation.
+    TryCatch {
+        /// Steps executed first.
+        try_steps: Vec<WorkflowStep>,
+        /// Steps executed after a try step fails.
+        #[serde(default)]
+        catch_steps: Vec<WorkflowStep>,
+        /// Steps executed after a try step fails, before catch steps.
+        #[serde(default)]
+        compensation_steps: Vec<WorkflowStep>,
+    },
     /// Conditional branch that executes one branch of nested steps.
     Condition {
         /// Dot-separated payload path to evaluate.
@@ -276,10 +754,55 @@ impl WorkflowStep {
             Self::AssignOwner { .. } => "assign_owner",
             Self::ApprovalRequest { .. } => "approval_request",
             Self::Delay { .. } => "delay",
+            Self::Wait { .. } => "wait",
+            Self::InvokeWorkflow { .. } => "invoke_workflow",
+            Self::TryCatch { .. } => "try_catch",
             Self::Condition { .. } => "condition",
         }
     }
 
+    /// Returns the run context key this step publishes its output under, if any.
+    #[must_use]
+    pub fn output_key(&self) -> Option<&str> {
+        match self {
+            Self::CreateRuntimeRecord { output_key, .. }
+            | Self::HttpRequest { output_key, .. }
+            | Self::InvokeWorkflow { output_key, .. } => output_key.as_deref(),
+            Self::LogMessage { .. }
+            | Self::UpdateRuntimeRecord { .. }
+            | Self::DeleteRuntimeRecord { .. }
+            | Self::SendEmail { .. }
+            | Self::Webhook { .. }
+            | Self::AssignOwner { .. }
+            | Self::ApprovalRequest { .. }
+            | Self::Delay { .. }
+            | Self::Wait { .. }
+            | Self::TryCatch { .. }
+            | Self::Condition { .. } => None,
+        }
+    }
+
+    /// Returns the retry policy applied when this step fails, if any.
+    #[must_use]
+    pub fn retry_policy(&self) -> Option<&WorkflowStepRetryPolicy> {
+        match self {
+            Self::CreateRuntimeRecord { retry_policy, .. }
+            | Self::UpdateRuntimeRecord { retry_policy, .. }
+            | Self::DeleteRuntimeRecord { retry_policy, .. }
+            | Self::SendEmail { retry_policy, .. }
+            | Self::HttpRequest { retry_policy, .. }
+            | Self::Webhook { retry_policy, .. }
+            | Self::AssignOwner { retry_policy, .. }
+            | Self::ApprovalRequest { retry_policy, .. }
+            | Self::InvokeWorkflow { retry_policy, .. } => retry_policy.as_ref(),
+            Self::LogMessage { .. }
+            | Self::Delay { .. }
+            | Self::Wait { .. }
+            | Self::TryCatch { .. }
+            | Self::Condition { .. } => None,
+        }
+    }
+
     /// Returns whether this step or any nested branch contains executable work.
     #[must_use]
     pub fn contains_executable_step(&self) -> bool {
@@ -293,7 +816,9 @@ impl WorkflowStep {
             | Self::Webhook { .. }
             | Self::AssignOwner { .. }
             | Self::ApprovalRequest { .. }
-            | Self::Delay { .. } => true,
+            | Self::Delay { .. }
+            | Self::Wait { .. }
+            | Self::InvokeWorkflow { .. } => true,
             Self::Condition {
                 then_steps,
                 else_steps,
@@ -302,6 +827,9 @@ impl WorkflowStep {
                 then_steps.iter().any(Self::contains_executable_step)
                     || else_steps.iter().any(Self::contains_executable_step)
             }
+            Self::TryCatch { try_steps, .. } => {
+                try_steps.iter().any(Self::contains_executable_step)
+            }
         }
     }
 
@@ -322,17 +850,273 @@ impl WorkflowStep {
                         .iter()
                         .any(Self::contains_outbound_integration_step)
             }
+            Self::TryCatch {
+                try_steps,
+                catch_steps,
+                compensation_steps,
+            } => try_steps
+                .iter()
+                .chain(catch_steps)
+                .chain(compensation_steps)
+                .any(Self::contains_outbound_integration_step),
             Self::LogMessage { .. }
             | Self::CreateRuntimeRecord { .. }
             | Self::UpdateRuntimeRecord { .. }
             | Self::DeleteRuntimeRecord { .. }
             | Self::AssignOwner { .. }
             | Self::ApprovalRequest { .. }
-            | Self::Delay { .. } => false,
+            | Self::Delay { .. }
+            | Self::Wait { .. }
+            | Self::InvokeWorkflow { .. } => false,
+        }
+    }
+
+    /// Returns whether this step or any nested branch is a durable wait step.
+    #[must_use]
+    pub fn contains_wait_step(&self) -> bool {
+        match self {
+            Self::Wait { .. } => true,
+            Self::Condition {
+                then_steps,
+                else_steps,
+                ..
+            } => {
+                then_steps.iter().any(Self::contains_wait_step)
+                    || else_steps.iter().any(Self::contains_wait_step)
+            }
+            Self::TryCatch {
+                try_steps,
+                catch_steps,
+                compensation_steps,
+            } => try_steps
+                .iter()
+                .chain(catch_steps)
+                .chain(compensation_steps)
+                .any(Self::contains_wait_step),
+            _ => false,
+        }
+    }
+
+    /// Appends the logical names of workflows invoked by this step or any nested branch.
+    pub fn collect_invoked_workflows<'a>(&'a self, invoked: &mut Vec<&'a str>) {
+        match self {
+            Self::InvokeWorkflow {
+                workflow_logical_name,
+                ..
+            } => invoked.push(workflow_logical_name.as_str()),
+            Self::Condition {
+                then_steps,
+                else_steps,
+                ..
+            } => {
+                for step in then_steps.iter().chain(else_steps) {
+                    step.collect_invoked_workflows(invoked);
+                }
+            }
+            Self::TryCatch {
+                try_steps,
+                catch_steps,
+                compensation_steps,
+            } => {
+                for step in try_steps
+                    .iter()
+                    .chain(compensation_steps)
+                    .chain(catch_steps)
+                {
+                    step.collect_invoked_workflows(invoked);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Appends the workflow credentials referenced by this step or any nested branch.
+    pub fn collect_credentials<'a>(&'a self, credentials: &mut Vec<&'a str>) {
+        match self {
+            Self::HttpRequest {
+                credential: Some(credential),
+                ..
+            }
+            | Self::Webhook {
+                credential: Some(credential),
+                ..
+            } => credentials.push(credential.as_str()),
+            Self::Condition {
+                then_steps,
+                else_steps,
+                ..
+            } => {
+                for step in then_steps.iter().chain(else_steps) {
+                    step.collect_credentials(credentials);
+                }
+            }
+            Self::TryCatch {
+                try_steps,
+                catch_steps,
+                compensation_steps,
+            } => {
+                for step in try_steps
+                    .iter()
+                    .chain(compensation_steps)
+                    .chain(catch_steps)
+                {
+                    step.collect_credentials(credentials);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Appends how this step or any nested branch uses a runtime field.
+    ///
+    /// Condition and wait paths only resolve against the trigger payload, so
+    /// they count when the workflow triggers on the field's entity.
+    pub fn collect_field_usages(
+        &self,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        triggers_on_entity: bool,
+        usages: &mut Vec<&'static str>,
+    ) {
+        match self {
+            Self::CreateRuntimeRecord {
+                entity_logical_name: target,
+                data,
+                ..
+            }
+            | Self::UpdateRuntimeRecord {
+                entity_logical_name: target,
+                data,
+                ..
+            } => {
+                if target == entity_logical_name
+                    && data
+                        .as_object()
+                        .is_some_and(|object| object.contains_key(field_logical_name))
+                {
+                    usages.push("step data");
+                }
+            }
+            Self::Wait {
+                until_field_path: Some(path),
+                ..
+            } => {
+                if triggers_on_entity && payload_path_refers_to_field(path, field_logical_name) {
+                    usages.push("wait");
+                }
+            }
+            Self::Condition {
+                field_path,
+                then_steps,
+                else_steps,
+                ..
+            } => {
+                if triggers_on_entity
+                    && payload_path_refers_to_field(field_path, field_logical_name)
+                {
+                    usages.push("condition");
+                }
+                for step in then_steps.iter().chain(else_steps) {
+                    step.collect_field_usages(
+                        entity_logical_name,
+                        field_logical_name,
+                        triggers_on_entity,
+                        usages,
+                    );
+                }
+            }
+            Self::TryCatch {
+                try_steps,
+                catch_steps,
+                compensation_steps,
+            } => {
+                for step in try_steps
+                    .iter()
+                    .chain(compensation_steps)
+                    .chain(catch_steps)
+                {
+                    step.collect_field_usages(
+                        entity_logical_name,
+                        field_logical_name,
+                        triggers_on_entity,
+                        usages,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Appends the runtime entities targeted by this step or any nested branch.
+    pub fn collect_target_entities<'a>(&'a self, entities: &mut Vec<&'a str>) {
+        match self {
+            Self::CreateRuntimeRecord {
+                entity_logical_name,
+                ..
+            }
+            | Self::UpdateRuntimeRecord {
+                entity_logical_name,
+                ..
+            }
+            | Self::DeleteRuntimeRecord {
+                entity_logical_name,
+                ..
+            }
+            | Self::AssignOwner {
+                entity_logical_name,
+                ..
+            }
+            | Self::ApprovalRequest {
+                entity_logical_name,
+                ..
+            } => entities.push(entity_logical_name.as_str()),
+            Self::Condition {
+                then_steps,
+                else_steps,
+                ..
+            } => {
+                for step in then_steps.iter().chain(else_steps) {
+                    step.collect_target_entities(entities);
+                }
+            }
+            Self::TryCatch {
+                try_steps,
+                catch_steps,
+                compensation_steps,
+            } => {
+                for step in try_steps
+                    .iter()
+                    .chain(compensation_steps)
+                    .chain(catch_steps)
+                {
+                    step.collect_target_entities(entities);
+                }
+            }
+            _ => {}
         }
     }
 }
 
+/// Returns whether a trigger payload path resolves to a record field.
+///
+/// Record trigger payloads expose field values at the top level and under
+/// `record`, `data` and `previous`.
+fn payload_path_refers_to_field(path: &str, field_logical_name: &str) -> bool {
+    let path = ["record.", "data.", "previous."]
+        .iter()
+        .find_map(|prefix| path.strip_prefix(prefix))
+        .unwrap_or(path);
+    path.split('.').next() == Some(field_logical_name)
+}
+
+/// Iterates trimmed `{{ ... }}` template tokens in a string.
+fn template_tokens(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split("{{")
+        .skip(1)
+        .filter_map(|segment| segment.split_once("}}").map(|(token, _)| token.trim()))
+}
+
 /// Tenant-scoped workflow definition.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WorkflowDefinition {
@@ -344,6 +1128,14 @@ pub struct WorkflowDefinition {
     max_attempts: u16,
     lifecycle_state: WorkflowLifecycleState,
     published_version: Option<i32>,
+    #[serde(default)]
+    trigger_filters: Vec<WorkflowTriggerFilter>,
+    #[serde(default)]
+    trigger_changed_fields: Vec<String>,
+    #[serde(default)]
+    trigger_view_logical_name: Option<String>,
+    #[serde(default)]
+    priority: WorkflowRunPriority,
 }
 
 /// Input payload used to construct a validated workflow definition.
@@ -389,6 +1181,19 @@ impl WorkflowDefinition {
 
         validate_trigger(&trigger)?;
         validate_steps(steps.as_slice())?;
+        let mut invoked = Vec::new();
+        for step in &steps {
+            step.collect_invoked_workflows(&mut invoked);
+        }
+        if invoked.contains(&logical_name.trim()) {
+            return Err(AppError::Validation(format!(
+                "workflow '{}' must not invoke itself",
+                logical_name.trim()
+            )));
+        }
+        if trigger.runtime_record_entity_logical_name().is_none() {
+            reject_related_condition_paths(steps.as_slice())?;
+        }
 
         let description = description.and_then(|value| {
             let trimmed = value.trim().to_owned();
@@ -404,6 +1209,10 @@ impl WorkflowDefinition {
             max_attempts,
             lifecycle_state: WorkflowLifecycleState::Draft,
             published_version: None,
+            trigger_filters: Vec::new(),
+            trigger_changed_fields: Vec::new(),
+            trigger_view_logical_name: None,
+            priority: WorkflowRunPriority::Normal,
         })
     }
 
@@ -443,6 +1252,64 @@ impl WorkflowDefinition {
         self.max_attempts
     }
 
+    /// Returns record-change filters evaluated before a run is started.
+    #[must_use]
+    pub fn trigger_filters(&self) -> &[WorkflowTriggerFilter] {
+        self.trigger_filters.as_slice()
+    }
+
+    /// Returns whether a record-change event passes every trigger filter.
+    #[must_use]
+    pub fn trigger_filters_match(&self, previous: Option<&Value>, current: Option<&Value>) -> bool {
+        self.trigger_filters
+            .iter()
+            .all(|filter| filter.matches(previous, current))
+    }
+
+    /// Returns fields of which at least one must change before a run is started.
+    #[must_use]
+    pub fn trigger_changed_fields(&self) -> &[String] {
+        self.trigger_changed_fields.as_slice()
+    }
+
+    /// Returns the view whose filter criteria the triggering record must match.
+    #[must_use]
+    pub fn trigger_view_logical_name(&self) -> Option<&str> {
+        self.trigger_view_logical_name.as_deref()
+    }
+
+    /// Returns whether a record-change event changes at least one watched field.
+    ///
+    /// Workflows without watched fields match every event.
+    #[must_use]
+    pub fn trigger_changed_fields_match(
+        &self,
+        previous: Option<&Value>,
+        current: Option<&Value>,
+    ) -> bool {
+        let field_value = |record: Option<&Value>, field_logical_name: &str| {
+            record
+                .and_then(|data| data.get(field_logical_name))
+                .filter(|value| !value.is_null())
+                .cloned()
+        };
+
+        self.trigger_changed_fields.is_empty()
+            || self
+                .trigger_changed_fields
+                .iter()
+                .any(|field_logical_name| {
+                    field_value(previous, field_logical_name)
+                        != field_value(current, field_logical_name)
+                })
+    }
+
+    /// Returns the queue priority given to runs of this workflow.
+    #[must_use]
+    pub fn priority(&self) -> WorkflowRunPriority {
+        self.priority
+    }
+
     /// Returns workflow release lifecycle state.
     #[must_use]
     pub fn lifecycle_state(&self) -> WorkflowLifecycleState {
@@ -469,6 +1336,198 @@ impl WorkflowDefinition {
             .any(WorkflowStep::contains_outbound_integration_step)
     }
 
+    /// Returns the logical names of workflows invoked by any step, in step order.
+    #[must_use]
+    pub fn invoked_workflow_logical_names(&self) -> Vec<&str> {
+        let mut invoked = Vec::new();
+        for step in &self.steps {
+            step.collect_invoked_workflows(&mut invoked);
+        }
+        invoked
+    }
+
+    /// Returns the workflow credentials referenced by any step, deduplicated in step order.
+    #[must_use]
+    pub fn credential_logical_names(&self) -> Vec<&str> {
+        let mut credentials = Vec::new();
+        for step in &self.steps {
+            step.collect_credentials(&mut credentials);
+        }
+        let mut seen = std::collections::HashSet::new();
+        credentials.retain(|credential| seen.insert(*credential));
+        credentials
+    }
+
+    /// Returns whether the trigger or any step targets the given runtime entity.
+    #[must_use]
+    pub fn references_entity(&self, entity_logical_name: &str) -> bool {
+        if self.trigger.runtime_record_entity_logical_name() == Some(entity_logical_name) {
+            return true;
+        }
+
+        let mut entities = Vec::new();
+        for step in &self.steps {
+            step.collect_target_entities(&mut entities);
+        }
+        entities.contains(&entity_logical_name)
+    }
+
+    /// Returns how the workflow uses a runtime field, deduplicated and sorted.
+    ///
+    /// Covers trigger filters and changed fields, condition and wait paths,
+    /// `{{trigger.*}}` template tokens and record create/update step data.
+    #[must_use]
+    pub fn field_usages(
+        &self,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> Vec<&'static str> {
+        let triggers_on_entity =
+            self.trigger.runtime_record_entity_logical_name() == Some(entity_logical_name);
+        let mut usages = Vec::new();
+
+        if triggers_on_entity
+            && self
+                .trigger_filters
+                .iter()
+                .any(|filter| filter.field_logical_name == field_logical_name)
+        {
+            usages.push("trigger filter");
+        }
+
+        if triggers_on_entity
+            && self
+                .trigger_changed_fields
+                .iter()
+                .any(|changed_field| changed_field == field_logical_name)
+        {
+            usages.push("trigger changed field");
+        }
+
+        for step in &self.steps {
+            step.collect_field_usages(
+                entity_logical_name,
+                field_logical_name,
+                triggers_on_entity,
+                &mut usages,
+            );
+        }
+
+        if triggers_on_entity
+            && serde_json::to_string(&self.steps).is_ok_and(|serialized| {
+                template_tokens(serialized.as_str())
+                    .filter(|token| !matches!(*token, "trigger.type" | "trigger.entity"))
+                    .any(|token| {
+                        token
+                            .strip_prefix("trigger.payload.")
+                            .or_else(|| token.strip_prefix("trigger."))
+                            .is_some_and(|path| {
+                                payload_path_refers_to_field(path, field_logical_name)
+                            })
+                    })
+            })
+        {
+            usages.push("template");
+        }
+
+        usages.sort_unstable();
+        usages.dedup();
+        usages
+    }
+
+    /// Returns whether any step suspends the run with a durable wait.
+    #[must_use]
+    pub fn contains_wait_steps(&self) -> bool {
+        self.steps.iter().any(WorkflowStep::contains_wait_step)
+    }
+
+    /// Attaches validated record-change trigger filters.
+    ///
+    /// Filters are only supported on record created, updated and status
+    /// changed triggers; the change-based operators are not available on the
+    /// created trigger.
+    pub fn with_trigger_filters(
+        mut self,
+        trigger_filters: Vec<WorkflowTriggerFilter>,
+    ) -> AppResult<Self> {
+        validate_trigger_filters(&self.trigger, trigger_filters.as_slice())?;
+        self.trigger_filters = trigger_filters;
+        Ok(self)
+    }
+
+    /// Scopes a record trigger to changes of watched fields and records matching a view.
+    ///
+    /// Watched fields require an updated or status changed trigger; the view
+    /// requires any record trigger except delete.
+    pub fn with_trigger_scope(
+        mut self,
+        changed_fields: Vec<String>,
+        view_logical_name: Option<String>,
+    ) -> AppResult<Self> {
+        let mut trigger_changed_fields: Vec<String> = Vec::new();
+        for field_logical_name in changed_fields {
+            let field_logical_name = field_logical_name.trim();
+            if field_logical_name.is_empty() {
+                return Err(AppError::Validation(
+                    "trigger changed fields must not be empty".to_owned(),
+                ));
+            }
+            if !trigger_changed_fields
+                .iter()
+                .any(|existing| existing == field_logical_name)
+            {
+                trigger_changed_fields.push(field_logical_name.to_owned());
+            }
+        }
+        let trigger_view_logical_name = view_logical_name.and_then(|value| {
+            let trimmed = value.trim().to_owned();
+            (!trimmed.is_empty()).then_some(trimmed)
+        });
+
+        if !trigger_changed_fields.is_empty() {
+            if !matches!(
+                self.trigger,
+                WorkflowTrigger::RuntimeRecordUpdated { .. }
+                    | WorkflowTrigger::RuntimeRecordStatusChanged { .. }
+            ) {
+                return Err(AppError::Validation(format!(
+                    "trigger changed fields require a runtime_record_updated or runtime_record_status_changed trigger, not '{}'",
+                    self.trigger.trigger_type()
+                )));
+            }
+            if trigger_changed_fields.len() > MAX_WORKFLOW_TRIGGER_FILTERS {
+                return Err(AppError::Validation(format!(
+                    "workflow trigger supports at most {MAX_WORKFLOW_TRIGGER_FILTERS} changed fields"
+                )));
+            }
+        }
+
+        if trigger_view_logical_name.is_some()
+            && !matches!(
+                self.trigger,
+                WorkflowTrigger::RuntimeRecordCreated { .. }
+                    | WorkflowTrigger::RuntimeRecordUpdated { .. }
+                    | WorkflowTrigger::RuntimeRecordStatusChanged { .. }
+            )
+        {
+            return Err(AppError::Validation(format!(
+                "trigger views are only supported on record created, updated and status changed triggers, not '{}'",
+                self.trigger.trigger_type()
+            )));
+        }
+
+        self.trigger_changed_fields = trigger_changed_fields;
+        self.trigger_view_logical_name = trigger_view_logical_name;
+        Ok(self)
+    }
+
+    /// Sets the queue priority given to runs of this workflow.
+    #[must_use]
+    pub fn with_priority(mut self, priority: WorkflowRunPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
     /// Rehydrates persisted publish metadata onto a validated workflow draft or snapshot.
     pub fn with_publish_state(
         mut self,
@@ -506,6 +1565,70 @@ impl WorkflowDefinition {
     }
 }
 
+fn validate_trigger_filters(
+    trigger: &WorkflowTrigger,
+    trigger_filters: &[WorkflowTriggerFilter],
+) -> AppResult<()> {
+    if trigger_filters.is_empty() {
+        return Ok(());
+    }
+
+    let supports_change_operators = match trigger {
+        WorkflowTrigger::RuntimeRecordUpdated { .. }
+        | WorkflowTrigger::RuntimeRecordStatusChanged { .. } => true,
+        WorkflowTrigger::RuntimeRecordCreated { .. } => false,
+        _ => {
+            return Err(AppError::Validation(format!(
+                "trigger filters are only supported on record created, updated and status changed triggers, not '{}'",
+                trigger.trigger_type()
+            )));
+        }
+    };
+
+    if trigger_filters.len() > MAX_WORKFLOW_TRIGGER_FILTERS {
+        return Err(AppError::Validation(format!(
+            "workflow trigger supports at most {MAX_WORKFLOW_TRIGGER_FILTERS} filters"
+        )));
+    }
+
+    for filter in trigger_filters {
+        if filter.field_logical_name.trim().is_empty() {
+            return Err(AppError::Validation(
+                "trigger filter field_logical_name must not be empty".to_owned(),
+            ));
+        }
+
+        let is_change_operator = matches!(
+            filter.operator,
+            WorkflowTriggerFilterOperator::Changed
+                | WorkflowTriggerFilterOperator::ChangedFrom
+                | WorkflowTriggerFilterOperator::ChangedTo
+        );
+        if is_change_operator && !supports_change_operators {
+            return Err(AppError::Validation(format!(
+                "trigger filter on field '{}' compares old and new values, which requires a runtime_record_updated or runtime_record_status_changed trigger",
+                filter.field_logical_name
+            )));
+        }
+
+        if filter.operator.requires_value() && filter.value.is_none() {
+            return Err(AppError::Validation(format!(
+                "trigger filter on field '{}' requires a comparison value",
+                filter.field_logical_name
+            )));
+        }
+
+        if !filter.operator.requires_value() && filter.value.is_some() {
+            return Err(AppError::Validation(format!(
+                "trigger filter 'changed' on field '{}' must not set a comparison value",
+                filter.field_logical_name
+            )));
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_trigger(trigger: &WorkflowTrigger) -> AppResult<()> {
     match trigger {
         WorkflowTrigger::Manual => Ok(()),
@@ -517,6 +1640,9 @@ fn validate_trigger(trigger: &WorkflowTrigger) -> AppResult<()> {
         }
         | WorkflowTrigger::RuntimeRecordDeleted {
             entity_logical_name,
+        }
+        | WorkflowTrigger::RuntimeRecordStatusChanged {
+            entity_logical_name,
         } => {
             if entity_logical_name.trim().is_empty() {
                 return Err(AppError::Validation(
@@ -535,6 +1661,9 @@ fn validate_trigger(trigger: &WorkflowTrigger) -> AppResult<()> {
 
             Ok(())
         }
+        WorkflowTrigger::Scheduled { cron_expression } => {
+            CronSchedule::parse(cron_expression).map(|_| ())
+        }
         WorkflowTrigger::WebhookReceived { webhook_key } => {
             if webhook_key.trim().is_empty() {
                 return Err(AppError::Validation(
@@ -839,6 +1968,9 @@ fn validate_http_request_step(
     url: &str,
     headers: Option<&Value>,
     header_secret_refs: Option<&Value>,
+    credential: Option<&str>,
+    expected_status: Option<u16>,
+    retry: Option<&WorkflowHttpRetryPolicy>,
 ) -> AppResult<()> {
     if method.trim().is_empty() {
         return Err(AppError::Validation(
@@ -852,9 +1984,32 @@ fn validate_http_request_step(
         ));
     }
 
+    if let Some(status) = expected_status
+        && !(100..=599).contains(&status)
+    {
+        return Err(AppError::Validation(format!(
+            "http_request step expected_status {status} must be between 100 and 599"
+        )));
+    }
+
+    if let Some(retry) = retry {
+        if retry.max_attempts == 0 || retry.max_attempts > WORKFLOW_HTTP_RETRY_MAX_ATTEMPTS {
+            return Err(AppError::Validation(format!(
+                "http_request step retry max_attempts must be between 1 and {WORKFLOW_HTTP_RETRY_MAX_ATTEMPTS}"
+            )));
+        }
+
+        if retry.backoff_ms > WORKFLOW_HTTP_RETRY_MAX_BACKOFF_MS {
+            return Err(AppError::Validation(format!(
+                "http_request step retry backoff_ms must be less than or equal to {WORKFLOW_HTTP_RETRY_MAX_BACKOFF_MS}"
+            )));
+        }
+    }
+
     let headers = validate_headers(headers, "http_request")?;
     let header_secret_refs = validate_header_secret_refs(header_secret_refs, "http_request")?;
-    validate_duplicate_header_sources(headers, header_secret_refs, "http_request")
+    validate_duplicate_header_sources(headers, header_secret_refs, "http_request")?;
+    validate_step_credential(credential, "http_request")
 }
 
 fn validate_webhook_step(
@@ -862,6 +2017,7 @@ fn validate_webhook_step(
     event: &str,
     headers: Option<&Value>,
     header_secret_refs: Option<&Value>,
+    credential: Option<&str>,
     payload: &Value,
 ) -> AppResult<()> {
     if endpoint.trim().is_empty() {
@@ -884,7 +2040,18 @@ fn validate_webhook_step(
 
     let headers = validate_headers(headers, "webhook")?;
     let header_secret_refs = validate_header_secret_refs(header_secret_refs, "webhook")?;
-    validate_duplicate_header_sources(headers, header_secret_refs, "webhook")
+    validate_duplicate_header_sources(headers, header_secret_refs, "webhook")?;
+    validate_step_credential(credential, "webhook")
+}
+
+fn validate_step_credential(credential: Option<&str>, step_type: &str) -> AppResult<()> {
+    if credential.is_some_and(|credential| credential.trim().is_empty()) {
+        return Err(AppError::Validation(format!(
+            "{step_type} step credential must not be empty when provided"
+        )));
+    }
+
+    Ok(())
 }
 
 fn validate_assign_owner_step(
@@ -964,90 +2131,409 @@ fn validate_approval_request_step(
     Ok(())
 }
 
-fn validate_delay_step(duration_ms: u64, reason: Option<&str>) -> AppResult<()> {
-    if duration_ms == 0 {
-        return Err(AppError::Validation(
-            "delay step requires duration_ms greater than zero".to_owned(),
-        ));
+fn validate_delay_step(duration_ms: u64, reason: Option<&str>) -> AppResult<()> {
+    if duration_ms == 0 {
+        return Err(AppError::Validation(
+            "delay step requires duration_ms greater than zero".to_owned(),
+        ));
+    }
+
+    if duration_ms > 86_400_000 {
+        return Err(AppError::Validation(
+            "delay step duration_ms must be less than or equal to 86400000".to_owned(),
+        ));
+    }
+
+    if let Some(value) = reason
+        && value.trim().is_empty()
+    {
+        return Err(AppError::Validation(
+            "delay step reason must not be empty when provided".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Related-record conditions traverse relations of the trigger record, so only
+/// runtime record triggers can use them.
+fn reject_related_condition_paths(steps: &[WorkflowStep]) -> AppResult<()> {
+    for step in steps {
+        if let WorkflowStep::Condition {
+            field_path,
+            then_steps,
+            else_steps,
+            ..
+        } = step
+        {
+            if WorkflowRelatedFieldPath::parse(field_path)?.is_some() {
+                return Err(AppError::Validation(format!(
+                    "condition step related path '{field_path}' requires a runtime record trigger"
+                )));
+            }
+
+            reject_related_condition_paths(then_steps)?;
+            reject_related_condition_paths(else_steps)?;
+        }
+
+        if let WorkflowStep::TryCatch {
+            try_steps,
+            catch_steps,
+            compensation_steps,
+        } = step
+        {
+            reject_related_condition_paths(try_steps)?;
+            reject_related_condition_paths(catch_steps)?;
+            reject_related_condition_paths(compensation_steps)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_invoke_workflow_step(workflow_logical_name: &str, payload: &Value) -> AppResult<()> {
+    if workflow_logical_name.trim().is_empty() {
+        return Err(AppError::Validation(
+            "invoke_workflow step workflow_logical_name must not be empty".to_owned(),
+        ));
+    }
+
+    if !payload.is_object() {
+        return Err(AppError::Validation(
+            "invoke_workflow step payload must be a JSON object".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_wait_step(
+    duration_seconds: Option<u64>,
+    until_field_path: Option<&str>,
+    reason: Option<&str>,
+) -> AppResult<()> {
+    match (duration_seconds, until_field_path) {
+        (Some(_), Some(_)) | (None, None) => {
+            return Err(AppError::Validation(
+                "wait step requires exactly one of duration_seconds or until_field_path".to_owned(),
+            ));
+        }
+        (Some(0), None) => {
+            return Err(AppError::Validation(
+                "wait step requires duration_seconds greater than zero".to_owned(),
+            ));
+        }
+        (Some(duration_seconds), None) if duration_seconds > MAX_WAIT_DURATION_SECONDS => {
+            return Err(AppError::Validation(format!(
+                "wait step duration_seconds must be less than or equal to {MAX_WAIT_DURATION_SECONDS}"
+            )));
+        }
+        (None, Some(field_path)) if field_path.trim().is_empty() => {
+            return Err(AppError::Validation(
+                "wait step until_field_path must not be empty".to_owned(),
+            ));
+        }
+        _ => {}
+    }
+
+    if let Some(value) = reason
+        && value.trim().is_empty()
+    {
+        return Err(AppError::Validation(
+            "wait step reason must not be empty when provided".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_steps(steps: &[WorkflowStep]) -> AppResult<()> {
+    if steps.is_empty() {
+        return Err(AppError::Validation(
+            "workflow steps must include at least one step".to_owned(),
+        ));
+    }
+
+    if !steps.iter().any(WorkflowStep::contains_executable_step) {
+        return Err(AppError::Validation(
+            "workflow steps must include at least one executable step".to_owned(),
+        ));
+    }
+
+    for step in steps {
+        validate_step(step)?;
+    }
+
+    validate_step_output_keys(steps, &mut Vec::new())?;
+    validate_step_output_references(steps, &[])
+}
+
+fn validate_step_output_keys<'a>(
+    steps: &'a [WorkflowStep],
+    seen_keys: &mut Vec<&'a str>,
+) -> AppResult<()> {
+    for step in steps {
+        if let Some(output_key) = step.output_key() {
+            let mut characters = output_key.chars();
+            let valid = output_key.len() <= WORKFLOW_STEP_OUTPUT_KEY_MAX_LENGTH
+                && characters
+                    .next()
+                    .is_some_and(|character| character.is_ascii_lowercase())
+                && characters.all(|character| {
+                    character.is_ascii_lowercase() || character.is_ascii_digit() || character == '_'
+                });
+            if !valid {
+                return Err(AppError::Validation(format!(
+                    "{} step output_key '{output_key}' must start with a lowercase letter and contain only lowercase letters, digits or '_' (max {WORKFLOW_STEP_OUTPUT_KEY_MAX_LENGTH})",
+                    step.step_type()
+                )));
+            }
+
+            if seen_keys.contains(&output_key) {
+                return Err(AppError::Validation(format!(
+                    "workflow step output_key '{output_key}' is used by more than one step"
+                )));
+            }
+            seen_keys.push(output_key);
+        }
+
+        if let WorkflowStep::Condition {
+            then_steps,
+            else_steps,
+            ..
+        } = step
+        {
+            validate_step_output_keys(then_steps, seen_keys)?;
+            validate_step_output_keys(else_steps, seen_keys)?;
+        }
+
+        if let WorkflowStep::TryCatch {
+            try_steps,
+            catch_steps,
+            compensation_steps,
+        } = step
+        {
+            validate_step_output_keys(try_steps, seen_keys)?;
+            validate_step_output_keys(catch_steps, seen_keys)?;
+            validate_step_output_keys(compensation_steps, seen_keys)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that every `steps.<key>` reference names an output key published by
+/// an earlier step on the same execution path. Keys set inside a condition
+/// branch stay scoped to that branch.
+fn validate_step_output_references(
+    steps: &[WorkflowStep],
+    inherited_keys: &[&str],
+) -> AppResult<()> {
+    let mut available_keys = inherited_keys.to_vec();
+    for step in steps {
+        match step {
+            WorkflowStep::Condition {
+                field_path,
+                value,
+                then_steps,
+                else_steps,
+                ..
+            } => {
+                if let Some(path) = field_path.trim().strip_prefix("steps.") {
+                    validate_step_output_reference(path, &available_keys, step.step_type())?;
+                }
+                if let Some(value) = value {
+                    validate_step_output_template_references(
+                        value,
+                        &available_keys,
+                        step.step_type(),
+                    )?;
+                }
+
+                validate_step_output_references(then_steps, &available_keys)?;
+                validate_step_output_references(else_steps, &available_keys)?;
+            }
+            WorkflowStep::TryCatch {
+                try_steps,
+                catch_steps,
+                compensation_steps,
+            } => {
+                validate_step_output_references(try_steps, &available_keys)?;
+                validate_step_output_references(catch_steps, &available_keys)?;
+                validate_step_output_references(compensation_steps, &available_keys)?;
+            }
+            _ => {
+                let step_value = serde_json::to_value(step).map_err(|error| {
+                    AppError::Internal(format!("failed to inspect workflow step: {error}"))
+                })?;
+                validate_step_output_template_references(
+                    &step_value,
+                    &available_keys,
+                    step.step_type(),
+                )?;
+            }
+        }
+
+        if let Some(output_key) = step.output_key() {
+            available_keys.push(output_key);
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_step_output_template_references(
+    value: &Value,
+    available_keys: &[&str],
+    step_type: &str,
+) -> AppResult<()> {
+    match value {
+        Value::String(content) => {
+            let mut rest = content.as_str();
+            while let Some(start) = rest.find("{{") {
+                let after_head = &rest[start + 2..];
+                let Some(end) = after_head.find("}}") else {
+                    break;
+                };
+
+                if let Some(path) = after_head[..end].trim().strip_prefix("steps.") {
+                    validate_step_output_reference(path, available_keys, step_type)?;
+                }
+                rest = &after_head[end + 2..];
+            }
+
+            Ok(())
+        }
+        Value::Array(items) => items.iter().try_for_each(|item| {
+            validate_step_output_template_references(item, available_keys, step_type)
+        }),
+        Value::Object(fields) => fields.values().try_for_each(|item| {
+            validate_step_output_template_references(item, available_keys, step_type)
+        }),
+        Value::Null | Value::Bool(_) | Value::Number(_) => Ok(()),
     }
+}
 
-    if duration_ms > 86_400_000 {
-        return Err(AppError::Validation(
-            "delay step duration_ms must be less than or equal to 86400000".to_owned(),
-        ));
+fn validate_step_output_reference(
+    path: &str,
+    available_keys: &[&str],
+    step_type: &str,
+) -> AppResult<()> {
+    let key = path.split('.').next().unwrap_or_default();
+    // Positional references such as `steps.0.response` address step paths directly.
+    if key.chars().all(|character| character.is_ascii_digit()) && !key.is_empty() {
+        return Ok(());
     }
 
-    if let Some(value) = reason
-        && value.trim().is_empty()
-    {
-        return Err(AppError::Validation(
-            "delay step reason must not be empty when provided".to_owned(),
-        ));
+    if !available_keys.contains(&key) {
+        return Err(AppError::Validation(format!(
+            "{step_type} step references 'steps.{path}' but no earlier step on the same path sets output_key '{key}'"
+        )));
     }
 
     Ok(())
 }
 
-fn validate_steps(steps: &[WorkflowStep]) -> AppResult<()> {
-    if steps.is_empty() {
-        return Err(AppError::Validation(
-            "workflow steps must include at least one step".to_owned(),
-        ));
+fn validate_step_retry_policy(
+    retry_policy: &WorkflowStepRetryPolicy,
+    step_type: &str,
+) -> AppResult<()> {
+    if retry_policy.max_attempts == 0
+        || retry_policy.max_attempts > WORKFLOW_STEP_RETRY_MAX_ATTEMPTS
+    {
+        return Err(AppError::Validation(format!(
+            "{step_type} step retry_policy max_attempts must be between 1 and {WORKFLOW_STEP_RETRY_MAX_ATTEMPTS}"
+        )));
     }
 
-    if !steps.iter().any(WorkflowStep::contains_executable_step) {
-        return Err(AppError::Validation(
-            "workflow steps must include at least one executable step".to_owned(),
-        ));
+    if retry_policy.backoff_ms > WORKFLOW_STEP_RETRY_MAX_BACKOFF_MS {
+        return Err(AppError::Validation(format!(
+            "{step_type} step retry_policy backoff_ms must be less than or equal to {WORKFLOW_STEP_RETRY_MAX_BACKOFF_MS}"
+        )));
     }
 
-    for step in steps {
-        validate_step(step)?;
+    if retry_policy.retry_on.is_empty() {
+        return Err(AppError::Validation(format!(
+            "{step_type} step retry_policy retry_on must list at least one error class"
+        )));
+    }
+
+    for (index, error_class) in retry_policy.retry_on.iter().enumerate() {
+        if retry_policy.retry_on[..index].contains(error_class) {
+            return Err(AppError::Validation(format!(
+                "{step_type} step retry_policy retry_on must not repeat error classes"
+            )));
+        }
     }
 
     Ok(())
 }
 
 fn validate_step(step: &WorkflowStep) -> AppResult<()> {
+    if let Some(retry_policy) = step.retry_policy() {
+        validate_step_retry_policy(retry_policy, step.step_type())?;
+    }
+
     match step {
         WorkflowStep::LogMessage { message } => validate_log_message_step(message),
         WorkflowStep::CreateRuntimeRecord {
             entity_logical_name,
             data,
+            output_key: _,
+            retry_policy: _,
         } => validate_create_runtime_record_step(entity_logical_name, data),
         WorkflowStep::UpdateRuntimeRecord {
             entity_logical_name,
             record_id,
             data,
+            retry_policy: _,
         } => validate_update_runtime_record_step(entity_logical_name, record_id, data),
         WorkflowStep::DeleteRuntimeRecord {
             entity_logical_name,
             record_id,
+            retry_policy: _,
         } => validate_delete_runtime_record_step(entity_logical_name, record_id),
         WorkflowStep::SendEmail {
             to,
             subject,
             body,
             html_body,
+            retry_policy: _,
         } => validate_send_email_step(to, subject, body, html_body.as_deref()),
         WorkflowStep::HttpRequest {
             method,
             url,
             headers,
             header_secret_refs,
+            credential,
             body: _,
-        } => validate_http_request_step(method, url, headers.as_ref(), header_secret_refs.as_ref()),
+            expected_status,
+            retry,
+            output_key: _,
+            retry_policy: _,
+        } => validate_http_request_step(
+            method,
+            url,
+            headers.as_ref(),
+            header_secret_refs.as_ref(),
+            credential.as_deref(),
+            *expected_status,
+            retry.as_ref(),
+        ),
         WorkflowStep::Webhook {
             endpoint,
             event,
             headers,
             header_secret_refs,
+            credential,
             payload,
+            retry_policy: _,
         } => validate_webhook_step(
             endpoint,
             event,
             headers.as_ref(),
             header_secret_refs.as_ref(),
+            credential.as_deref(),
             payload,
         ),
         WorkflowStep::AssignOwner {
@@ -1055,6 +2541,7 @@ fn validate_step(step: &WorkflowStep) -> AppResult<()> {
             record_id,
             owner_id,
             reason,
+            retry_policy: _,
         } => {
             validate_assign_owner_step(entity_logical_name, record_id, owner_id, reason.as_deref())
         }
@@ -1066,6 +2553,7 @@ fn validate_step(step: &WorkflowStep) -> AppResult<()> {
             approver_id,
             reason,
             payload,
+            retry_policy: _,
         } => validate_approval_request_step(
             entity_logical_name,
             record_id,
@@ -1079,6 +2567,55 @@ fn validate_step(step: &WorkflowStep) -> AppResult<()> {
             duration_ms,
             reason,
         } => validate_delay_step(*duration_ms, reason.as_deref()),
+        WorkflowStep::Wait {
+            duration_seconds,
+            until_field_path,
+            reason,
+        } => validate_wait_step(
+            *duration_seconds,
+            until_field_path.as_deref(),
+            reason.as_deref(),
+        ),
+        WorkflowStep::InvokeWorkflow {
+            workflow_logical_name,
+            payload,
+            mode: _,
+            output_key: _,
+            retry_policy: _,
+        } => validate_invoke_workflow_step(workflow_logical_name, payload),
+        WorkflowStep::TryCatch {
+            try_steps,
+            catch_steps,
+            compensation_steps,
+        } => {
+            if try_steps.is_empty() {
+                return Err(AppError::Validation(
+                    "try_catch step must define at least one try step".to_owned(),
+                ));
+            }
+
+            if catch_steps.is_empty() && compensation_steps.is_empty() {
+                return Err(AppError::Validation(
+                    "try_catch step must define catch or compensation steps".to_owned(),
+                ));
+            }
+
+            if step.contains_wait_step() {
+                return Err(AppError::Validation(
+                    "try_catch step must not contain wait steps".to_owned(),
+                ));
+            }
+
+            for child_step in try_steps
+                .iter()
+                .chain(catch_steps)
+                .chain(compensation_steps)
+            {
+                validate_step(child_step)?;
+            }
+
+            Ok(())
+        }
         WorkflowStep::Condition {
             field_path,
             operator,
@@ -1094,6 +2631,8 @@ fn validate_step(step: &WorkflowStep) -> AppResult<()> {
                 ));
             }
 
+            WorkflowRelatedFieldPath::parse(field_path)?;
+
             match operator {
                 WorkflowConditionOperator::Equals | WorkflowConditionOperator::NotEquals => {
                     if value.is_none() {
@@ -1149,10 +2688,16 @@ fn validate_step(step: &WorkflowStep) -> AppResult<()> {
 #[cfg(test)]
 mod tests {
     use super::{
-        WorkflowConditionOperator, WorkflowDefinition, WorkflowDefinitionInput, WorkflowStep,
-        WorkflowTrigger, is_sensitive_workflow_header_name, redact_sensitive_workflow_headers,
-        redact_workflow_header_secret_refs,
+        WORKFLOW_HTTP_RETRY_MAX_ATTEMPTS, WORKFLOW_HTTP_RETRY_MAX_BACKOFF_MS,
+        WorkflowConditionOperator, WorkflowDefinition, WorkflowDefinitionInput,
+        WorkflowHttpRetryPolicy, WorkflowInvocationMode, WorkflowRelatedFieldPath,
+        WorkflowRunPriority, WorkflowStep, WorkflowStepBackoffStrategy,
+        WorkflowStepRetryErrorClass, WorkflowStepRetryPolicy, WorkflowTrigger,
+        WorkflowTriggerFilter, WorkflowTriggerFilterOperator, is_sensitive_workflow_header_name,
+        redact_sensitive_workflow_headers, redact_workflow_header_secret_refs,
     };
+    use qryvanta_core::AppError;
+    use serde_json::{Value, json};
 
     #[test]
     fn workflow_requires_positive_attempts() {
@@ -1170,6 +2715,30 @@ mod tests {
         assert!(workflow.is_err());
     }
 
+    #[test]
+    fn scheduled_trigger_requires_valid_cron_expression() {
+        let build = |cron_expression: &str| {
+            WorkflowDefinition::new(WorkflowDefinitionInput {
+                logical_name: "nightly_digest".to_owned(),
+                display_name: "Nightly Digest".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Scheduled {
+                    cron_expression: cron_expression.to_owned(),
+                },
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "digest".to_owned(),
+                }],
+                max_attempts: 3,
+            })
+        };
+
+        let workflow = build("30 2 * * *").unwrap_or_else(|_| unreachable!());
+        assert_eq!(workflow.trigger().trigger_type(), "scheduled");
+        assert_eq!(workflow.trigger().entity_logical_name(), Some("30 2 * * *"));
+        assert!(build("30 2 * *").is_err());
+        assert!(build("61 2 * * *").is_err());
+    }
+
     #[test]
     fn create_runtime_record_step_requires_object_payload() {
         let workflow = WorkflowDefinition::new(WorkflowDefinitionInput {
@@ -1180,6 +2749,8 @@ mod tests {
             steps: vec![WorkflowStep::CreateRuntimeRecord {
                 entity_logical_name: "contact".to_owned(),
                 data: serde_json::json!("invalid"),
+                output_key: None,
+                retry_policy: None,
             }],
             max_attempts: 3,
         });
@@ -1187,6 +2758,26 @@ mod tests {
         assert!(workflow.is_err());
     }
 
+    #[test]
+    fn run_priorities_round_trip_through_storage_values_and_ranks() {
+        for priority in WorkflowRunPriority::ALL {
+            assert_eq!(
+                WorkflowRunPriority::parse(priority.as_str()).ok(),
+                Some(priority)
+            );
+            assert_eq!(
+                WorkflowRunPriority::from_rank(priority.rank()).ok(),
+                Some(priority)
+            );
+        }
+
+        assert!(WorkflowRunPriority::Urgent.rank() > WorkflowRunPriority::High.rank());
+        assert!(WorkflowRunPriority::Normal.rank() > WorkflowRunPriority::Low.rank());
+        assert_eq!(WorkflowRunPriority::default(), WorkflowRunPriority::Normal);
+        assert!(WorkflowRunPriority::parse("critical").is_err());
+        assert!(WorkflowRunPriority::from_rank(4).is_err());
+    }
+
     #[test]
     fn condition_step_requires_at_least_one_branch_step() {
         let workflow = WorkflowDefinition::new(WorkflowDefinitionInput {
@@ -1221,6 +2812,7 @@ mod tests {
                 subject: "hello".to_owned(),
                 body: "world".to_owned(),
                 html_body: None,
+                retry_policy: None,
             }],
             max_attempts: 3,
         });
@@ -1239,8 +2831,13 @@ mod tests {
                 method: "POST".to_owned(),
                 url: "https://example.org/hook".to_owned(),
                 headers: Some(serde_json::json!({"x-attempt": 1})),
+                credential: None,
                 header_secret_refs: None,
                 body: None,
+                expected_status: None,
+                retry: None,
+                output_key: None,
+                retry_policy: None,
             }],
             max_attempts: 3,
         });
@@ -1259,8 +2856,10 @@ mod tests {
                 endpoint: "https://example.org/webhook".to_owned(),
                 event: "record.updated".to_owned(),
                 headers: None,
+                credential: None,
                 header_secret_refs: None,
                 payload: serde_json::json!("invalid"),
+                retry_policy: None,
             }],
             max_attempts: 3,
         });
@@ -1361,6 +2960,7 @@ mod tests {
                     subject: "follow-up".to_owned(),
                     body: "check workflow output".to_owned(),
                     html_body: None,
+                    retry_policy: None,
                 }],
             }],
             max_attempts: 3,
@@ -1369,6 +2969,222 @@ mod tests {
         assert!(workflow.is_ok());
     }
 
+    #[test]
+    fn related_condition_paths_require_record_trigger_and_bounded_depth() {
+        let workflow = |trigger: WorkflowTrigger, field_path: &str| {
+            WorkflowDefinition::new(WorkflowDefinitionInput {
+                logical_name: "related_check".to_owned(),
+                display_name: "Related Check".to_owned(),
+                description: None,
+                trigger,
+                steps: vec![WorkflowStep::Condition {
+                    field_path: field_path.to_owned(),
+                    operator: WorkflowConditionOperator::Exists,
+                    value: None,
+                    then_label: None,
+                    else_label: None,
+                    then_steps: vec![WorkflowStep::LogMessage {
+                        message: "related".to_owned(),
+                    }],
+                    else_steps: Vec::new(),
+                }],
+                max_attempts: 1,
+            })
+        };
+        let record_trigger = || WorkflowTrigger::RuntimeRecordUpdated {
+            entity_logical_name: "contact".to_owned(),
+        };
+
+        assert!(workflow(record_trigger(), "related.account.parent.owner.name").is_ok());
+        assert!(workflow(record_trigger(), "related.a.b.c.d.name").is_err());
+        assert!(workflow(record_trigger(), "related.account").is_err());
+        assert!(workflow(record_trigger(), "related.account..name").is_err());
+        assert!(workflow(WorkflowTrigger::Manual, "related.account.name").is_err());
+
+        let path = WorkflowRelatedFieldPath::parse("related.account.parent.name")
+            .unwrap_or_else(|_| unreachable!())
+            .unwrap_or_else(|| unreachable!());
+        assert_eq!(path.relation_fields(), ["account", "parent"]);
+        assert_eq!(path.field_logical_name(), "name");
+        assert!(matches!(
+            WorkflowRelatedFieldPath::parse("status"),
+            Ok(None)
+        ));
+    }
+
+    #[test]
+    fn invoke_workflow_steps_reject_self_invocation_and_non_object_payloads() {
+        let workflow = |workflow_logical_name: &str, payload: Value| {
+            WorkflowDefinition::new(WorkflowDefinitionInput {
+                logical_name: "parent_flow".to_owned(),
+                display_name: "Parent Flow".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::Condition {
+                    field_path: "priority".to_owned(),
+                    operator: WorkflowConditionOperator::Exists,
+                    value: None,
+                    then_label: None,
+                    else_label: None,
+                    then_steps: vec![WorkflowStep::InvokeWorkflow {
+                        workflow_logical_name: workflow_logical_name.to_owned(),
+                        payload,
+                        mode: WorkflowInvocationMode::Enqueued,
+                        output_key: Some("child".to_owned()),
+                        retry_policy: None,
+                    }],
+                    else_steps: Vec::new(),
+                }],
+                max_attempts: 1,
+            })
+        };
+
+        let valid = workflow(
+            "child_flow",
+            serde_json::json!({"priority": "{{trigger.priority}}"}),
+        )
+        .unwrap_or_else(|_| unreachable!());
+        assert_eq!(valid.invoked_workflow_logical_names(), ["child_flow"]);
+        assert!(workflow("parent_flow", serde_json::json!({})).is_err());
+        assert!(workflow(" ", serde_json::json!({})).is_err());
+        assert!(workflow("child_flow", serde_json::json!(["priority"])).is_err());
+
+        let step: WorkflowStep = serde_json::from_value(serde_json::json!({
+            "type": "invoke_workflow",
+            "workflow_logical_name": "child_flow",
+            "payload": {},
+        }))
+        .unwrap_or_else(|_| unreachable!());
+        assert!(matches!(
+            step,
+            WorkflowStep::InvokeWorkflow {
+                mode: WorkflowInvocationMode::Synchronous,
+                output_key: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn try_catch_steps_require_try_steps_handlers_and_no_waits() {
+        let log = |message: &str| WorkflowStep::LogMessage {
+            message: message.to_owned(),
+        };
+        let workflow = |step: WorkflowStep| {
+            WorkflowDefinition::new(WorkflowDefinitionInput {
+                logical_name: "guarded".to_owned(),
+                display_name: "Guarded".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![step],
+                max_attempts: 1,
+            })
+        };
+
+        assert!(
+            workflow(WorkflowStep::TryCatch {
+                try_steps: vec![log("try")],
+                catch_steps: vec![log("catch")],
+                compensation_steps: Vec::new(),
+            })
+            .is_ok()
+        );
+        assert!(
+            workflow(WorkflowStep::TryCatch {
+                try_steps: Vec::new(),
+                catch_steps: vec![log("catch")],
+                compensation_steps: Vec::new(),
+            })
+            .is_err()
+        );
+        assert!(
+            workflow(WorkflowStep::TryCatch {
+                try_steps: vec![log("try")],
+                catch_steps: Vec::new(),
+                compensation_steps: Vec::new(),
+            })
+            .is_err()
+        );
+        assert!(
+            workflow(WorkflowStep::TryCatch {
+                try_steps: vec![WorkflowStep::Wait {
+                    duration_seconds: Some(60),
+                    until_field_path: None,
+                    reason: None,
+                }],
+                catch_steps: Vec::new(),
+                compensation_steps: vec![log("undo")],
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn step_retry_policies_validate_bounds_and_compute_backoff() {
+        let workflow = |retry_policy: WorkflowStepRetryPolicy| {
+            WorkflowDefinition::new(WorkflowDefinitionInput {
+                logical_name: "retrying".to_owned(),
+                display_name: "Retrying".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::DeleteRuntimeRecord {
+                    entity_logical_name: "contact".to_owned(),
+                    record_id: "contact-1".to_owned(),
+                    retry_policy: Some(retry_policy),
+                }],
+                max_attempts: 1,
+            })
+        };
+        let policy = |max_attempts: u8, retry_on: Vec<WorkflowStepRetryErrorClass>| {
+            WorkflowStepRetryPolicy {
+                max_attempts,
+                backoff: WorkflowStepBackoffStrategy::Exponential,
+                backoff_ms: 100,
+                retry_on,
+            }
+        };
+
+        assert!(workflow(policy(3, vec![WorkflowStepRetryErrorClass::Internal])).is_ok());
+        assert!(workflow(policy(0, vec![WorkflowStepRetryErrorClass::Internal])).is_err());
+        assert!(workflow(policy(11, vec![WorkflowStepRetryErrorClass::Internal])).is_err());
+        assert!(workflow(policy(3, Vec::new())).is_err());
+        assert!(
+            workflow(policy(
+                3,
+                vec![
+                    WorkflowStepRetryErrorClass::Conflict,
+                    WorkflowStepRetryErrorClass::Conflict,
+                ],
+            ))
+            .is_err()
+        );
+
+        let exponential = policy(3, vec![WorkflowStepRetryErrorClass::Internal]);
+        assert_eq!(exponential.backoff_before_retry_ms(1), 100);
+        assert_eq!(exponential.backoff_before_retry_ms(3), 400);
+        let linear = WorkflowStepRetryPolicy {
+            backoff: WorkflowStepBackoffStrategy::Linear,
+            ..exponential.clone()
+        };
+        assert_eq!(linear.backoff_before_retry_ms(3), 300);
+
+        let internal = AppError::Internal("timeout".to_owned());
+        assert!(exponential.should_retry(&internal, 2));
+        assert!(!exponential.should_retry(&internal, 3));
+        assert!(!exponential.should_retry(&AppError::Validation("bad".to_owned()), 1));
+
+        let parsed: WorkflowStepRetryPolicy =
+            serde_json::from_value(json!({ "max_attempts": 2 })).unwrap_or_else(|_| unreachable!());
+        assert_eq!(parsed.backoff, WorkflowStepBackoffStrategy::Fixed);
+        assert_eq!(
+            parsed.retry_on,
+            vec![
+                WorkflowStepRetryErrorClass::RateLimited,
+                WorkflowStepRetryErrorClass::Internal,
+            ]
+        );
+    }
+
     #[test]
     fn update_runtime_record_step_requires_record_id() {
         let workflow = WorkflowDefinition::new(WorkflowDefinitionInput {
@@ -1380,6 +3196,7 @@ mod tests {
                 entity_logical_name: "contact".to_owned(),
                 record_id: " ".to_owned(),
                 data: serde_json::json!({"name": "Alice"}),
+                retry_policy: None,
             }],
             max_attempts: 3,
         });
@@ -1402,6 +3219,7 @@ mod tests {
                 approver_id: None,
                 reason: None,
                 payload: Some(serde_json::json!("bad")),
+                retry_policy: None,
             }],
             max_attempts: 3,
         });
@@ -1426,6 +3244,185 @@ mod tests {
         assert!(workflow.is_err());
     }
 
+    #[test]
+    fn wait_step_requires_exactly_one_resume_source() {
+        let wait_workflow = |duration_seconds: Option<u64>, until_field_path: Option<&str>| {
+            WorkflowDefinition::new(WorkflowDefinitionInput {
+                logical_name: "follow_up".to_owned(),
+                display_name: "Follow Up".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::Wait {
+                    duration_seconds,
+                    until_field_path: until_field_path.map(ToOwned::to_owned),
+                    reason: None,
+                }],
+                max_attempts: 3,
+            })
+        };
+
+        assert!(wait_workflow(Some(259_200), None).is_ok());
+        assert!(wait_workflow(None, Some("record.follow_up_at")).is_ok());
+        assert!(wait_workflow(None, None).is_err());
+        assert!(wait_workflow(Some(60), Some("record.follow_up_at")).is_err());
+        assert!(wait_workflow(Some(0), None).is_err());
+        assert!(wait_workflow(None, Some(" ")).is_err());
+    }
+
+    #[test]
+    fn trigger_filters_compare_old_and_new_values_on_update_triggers() {
+        let filtered = |trigger: WorkflowTrigger, filters: Vec<WorkflowTriggerFilter>| {
+            WorkflowDefinition::new(WorkflowDefinitionInput {
+                logical_name: "escalate".to_owned(),
+                display_name: "Escalate".to_owned(),
+                description: None,
+                trigger,
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "escalated".to_owned(),
+                }],
+                max_attempts: 1,
+            })
+            .and_then(|workflow| workflow.with_trigger_filters(filters))
+        };
+        let updated = || WorkflowTrigger::RuntimeRecordUpdated {
+            entity_logical_name: "ticket".to_owned(),
+        };
+        let filter = |operator, value: Option<serde_json::Value>| WorkflowTriggerFilter {
+            field_logical_name: "status".to_owned(),
+            operator,
+            value,
+        };
+
+        let workflow = filtered(
+            updated(),
+            vec![filter(
+                WorkflowTriggerFilterOperator::ChangedTo,
+                Some(json!("escalated")),
+            )],
+        )
+        .unwrap_or_else(|_| unreachable!());
+        let open = json!({"status": "open", "title": "A"});
+        let escalated = json!({"status": "escalated", "title": "A"});
+        assert!(workflow.trigger_filters_match(Some(&open), Some(&escalated)));
+        assert!(!workflow.trigger_filters_match(Some(&escalated), Some(&escalated)));
+        assert!(!workflow.trigger_filters_match(Some(&escalated), Some(&open)));
+
+        assert!(
+            filter(WorkflowTriggerFilterOperator::Changed, None)
+                .matches(Some(&json!({})), Some(&open))
+        );
+        assert!(
+            filter(
+                WorkflowTriggerFilterOperator::ChangedFrom,
+                Some(json!("open"))
+            )
+            .matches(Some(&open), Some(&escalated))
+        );
+
+        assert!(
+            filtered(
+                WorkflowTrigger::RuntimeRecordCreated {
+                    entity_logical_name: "ticket".to_owned(),
+                },
+                vec![filter(WorkflowTriggerFilterOperator::Changed, None)],
+            )
+            .is_err()
+        );
+        assert!(
+            filtered(
+                WorkflowTrigger::RuntimeRecordStatusChanged {
+                    entity_logical_name: "ticket".to_owned(),
+                },
+                vec![filter(
+                    WorkflowTriggerFilterOperator::ChangedTo,
+                    Some(json!("escalated"))
+                )],
+            )
+            .is_ok()
+        );
+        assert!(
+            filtered(
+                WorkflowTrigger::Manual,
+                vec![filter(
+                    WorkflowTriggerFilterOperator::Equals,
+                    Some(json!("open"))
+                )],
+            )
+            .is_err()
+        );
+        assert!(
+            filtered(
+                updated(),
+                vec![filter(WorkflowTriggerFilterOperator::Equals, None)]
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn trigger_scope_watches_any_listed_field_on_record_triggers() {
+        let scoped = |trigger: WorkflowTrigger, fields: Vec<&str>, view: Option<&str>| {
+            WorkflowDefinition::new(WorkflowDefinitionInput {
+                logical_name: "sync_ticket".to_owned(),
+                display_name: "Sync Ticket".to_owned(),
+                description: None,
+                trigger,
+                steps: vec![WorkflowStep::LogMessage {
+                    message: "synced".to_owned(),
+                }],
+                max_attempts: 1,
+            })
+            .and_then(|workflow| {
+                workflow.with_trigger_scope(
+                    fields.into_iter().map(ToOwned::to_owned).collect(),
+                    view.map(ToOwned::to_owned),
+                )
+            })
+        };
+        let updated = || WorkflowTrigger::RuntimeRecordUpdated {
+            entity_logical_name: "ticket".to_owned(),
+        };
+
+        let workflow = scoped(
+            updated(),
+            vec![" status ", "priority", "status"],
+            Some(" open_tickets "),
+        )
+        .unwrap_or_else(|_| unreachable!());
+        assert_eq!(workflow.trigger_changed_fields(), ["status", "priority"]);
+        assert_eq!(workflow.trigger_view_logical_name(), Some("open_tickets"));
+
+        let open = json!({"status": "open", "priority": 1, "title": "A"});
+        let retitled = json!({"status": "open", "priority": 1, "title": "B"});
+        let reprioritized = json!({"status": "open", "priority": 2, "title": "A"});
+        assert!(!workflow.trigger_changed_fields_match(Some(&open), Some(&retitled)));
+        assert!(workflow.trigger_changed_fields_match(Some(&open), Some(&reprioritized)));
+        assert!(
+            !workflow
+                .trigger_changed_fields_match(Some(&json!({"status": null})), Some(&json!({})))
+        );
+
+        let unscoped = scoped(updated(), Vec::new(), None).unwrap_or_else(|_| unreachable!());
+        assert!(unscoped.trigger_changed_fields_match(Some(&open), Some(&retitled)));
+
+        let created = || WorkflowTrigger::RuntimeRecordCreated {
+            entity_logical_name: "ticket".to_owned(),
+        };
+        assert!(scoped(created(), vec!["status"], None).is_err());
+        assert!(scoped(created(), Vec::new(), Some("open_tickets")).is_ok());
+        assert!(
+            scoped(
+                WorkflowTrigger::RuntimeRecordDeleted {
+                    entity_logical_name: "ticket".to_owned(),
+                },
+                Vec::new(),
+                Some("open_tickets"),
+            )
+            .is_err()
+        );
+        assert!(scoped(updated(), vec![" "], None).is_err());
+    }
+
     #[test]
     fn workflow_detects_outbound_integration_steps_inside_conditions() {
         let workflow = WorkflowDefinition::new(WorkflowDefinitionInput {
@@ -1443,8 +3440,13 @@ mod tests {
                     method: "POST".to_owned(),
                     url: "https://example.com/hook".to_owned(),
                     headers: None,
+                    credential: None,
                     header_secret_refs: None,
                     body: None,
+                    expected_status: None,
+                    retry: None,
+                    output_key: None,
+                    retry_policy: None,
                 }],
                 else_steps: vec![WorkflowStep::LogMessage {
                     message: "noop".to_owned(),
@@ -1500,11 +3502,16 @@ mod tests {
                 method: "POST".to_owned(),
                 url: "https://example.org/hook".to_owned(),
                 headers: None,
+                credential: None,
                 header_secret_refs: Some(serde_json::json!({
                     "authorization": "bearer+op://vault/item/password",
                     "x-basic-auth": "basic+aws-sm://prod/basic-creds"
                 })),
                 body: None,
+                expected_status: None,
+                retry: None,
+                output_key: None,
+                retry_policy: None,
             }],
             max_attempts: 3,
         });
@@ -1523,10 +3530,15 @@ mod tests {
                 method: "POST".to_owned(),
                 url: "https://example.org/hook".to_owned(),
                 headers: Some(serde_json::json!({"authorization": "Bearer token"})),
+                credential: None,
                 header_secret_refs: Some(serde_json::json!({
                     "Authorization": "op://vault/item/password"
                 })),
                 body: None,
+                expected_status: None,
+                retry: None,
+                output_key: None,
+                retry_policy: None,
             }],
             max_attempts: 3,
         });
@@ -1534,6 +3546,136 @@ mod tests {
         assert!(workflow.is_err());
     }
 
+    #[test]
+    fn http_request_step_validates_expected_status_and_retry_policy() {
+        let build = |expected_status: Option<u16>, retry: Option<WorkflowHttpRetryPolicy>| {
+            WorkflowDefinition::new(WorkflowDefinitionInput {
+                logical_name: "sync_external".to_owned(),
+                display_name: "Sync External".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps: vec![WorkflowStep::HttpRequest {
+                    method: "POST".to_owned(),
+                    url: "https://example.org/records".to_owned(),
+                    headers: None,
+                    credential: None,
+                    header_secret_refs: None,
+                    body: None,
+                    expected_status,
+                    retry,
+                    output_key: None,
+                    retry_policy: None,
+                }],
+                max_attempts: 1,
+            })
+        };
+        let retry = |max_attempts: u8, backoff_ms: u64| {
+            Some(WorkflowHttpRetryPolicy {
+                max_attempts,
+                backoff_ms,
+            })
+        };
+
+        assert!(build(Some(201), retry(3, 500)).is_ok());
+        assert!(build(Some(700), None).is_err());
+        assert!(build(None, retry(0, 500)).is_err());
+        assert!(build(None, retry(WORKFLOW_HTTP_RETRY_MAX_ATTEMPTS + 1, 500)).is_err());
+        assert!(build(None, retry(3, WORKFLOW_HTTP_RETRY_MAX_BACKOFF_MS + 1)).is_err());
+    }
+
+    #[test]
+    fn http_request_step_deserializes_without_retry_fields() {
+        let step = serde_json::from_value::<WorkflowStep>(serde_json::json!({
+            "type": "http_request",
+            "method": "GET",
+            "url": "https://example.org/status",
+            "headers": null,
+            "header_secret_refs": null,
+            "body": null
+        }))
+        .unwrap_or_else(|_| unreachable!());
+
+        assert!(matches!(
+            step,
+            WorkflowStep::HttpRequest {
+                expected_status: None,
+                retry: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn step_output_references_require_earlier_output_key_on_same_path() {
+        let create =
+            |output_key: Option<&str>, data: serde_json::Value| WorkflowStep::CreateRuntimeRecord {
+                entity_logical_name: "invoice".to_owned(),
+                data,
+                output_key: output_key.map(ToOwned::to_owned),
+                retry_policy: None,
+            };
+        let build = |steps: Vec<WorkflowStep>| {
+            WorkflowDefinition::new(WorkflowDefinitionInput {
+                logical_name: "invoice_flow".to_owned(),
+                display_name: "Invoice Flow".to_owned(),
+                description: None,
+                trigger: WorkflowTrigger::Manual,
+                steps,
+                max_attempts: 1,
+            })
+        };
+        let reference = serde_json::json!({"invoice": "{{steps.create_invoice.record_id}}"});
+        let branch = |then_steps: Vec<WorkflowStep>| WorkflowStep::Condition {
+            field_path: "status".to_owned(),
+            operator: WorkflowConditionOperator::Exists,
+            value: None,
+            then_label: None,
+            else_label: None,
+            then_steps,
+            else_steps: Vec::new(),
+        };
+
+        assert!(
+            build(vec![
+                create(Some("create_invoice"), serde_json::json!({})),
+                branch(vec![create(None, reference.clone())]),
+            ])
+            .is_ok()
+        );
+        assert!(
+            build(vec![
+                create(None, reference.clone()),
+                create(Some("create_invoice"), serde_json::json!({})),
+            ])
+            .is_err()
+        );
+        assert!(
+            build(vec![
+                branch(vec![create(Some("create_invoice"), serde_json::json!({}))]),
+                create(None, reference.clone()),
+            ])
+            .is_err()
+        );
+        assert!(
+            build(vec![
+                create(Some("create_invoice"), serde_json::json!({})),
+                create(Some("create_invoice"), serde_json::json!({})),
+            ])
+            .is_err()
+        );
+        assert!(build(vec![create(Some("Create-Invoice"), serde_json::json!({}))]).is_err());
+        assert!(
+            build(vec![
+                create(Some("create_invoice"), serde_json::json!({})),
+                create(
+                    None,
+                    serde_json::json!({"previous": "{{steps.0.record_id}}"})
+                ),
+            ])
+            .is_ok()
+        );
+    }
+
     #[test]
     fn webhook_step_accepts_secret_header_refs() {
         let workflow = WorkflowDefinition::new(WorkflowDefinitionInput {
@@ -1545,14 +3687,118 @@ mod tests {
                 endpoint: "https://example.org/webhook".to_owned(),
                 event: "record.updated".to_owned(),
                 headers: Some(serde_json::json!({"content-type": "application/json"})),
+                credential: None,
                 header_secret_refs: Some(serde_json::json!({
                     "authorization": "op://vault/item/password"
                 })),
                 payload: serde_json::json!({"ok": true}),
+                retry_policy: None,
             }],
             max_attempts: 3,
         });
 
         assert!(workflow.is_ok());
     }
+
+    #[test]
+    fn entity_references_cover_record_triggers_and_nested_steps() {
+        let workflow = WorkflowDefinition::new(WorkflowDefinitionInput {
+            logical_name: "escalate_ticket".to_owned(),
+            display_name: "Escalate Ticket".to_owned(),
+            description: None,
+            trigger: WorkflowTrigger::RuntimeRecordCreated {
+                entity_logical_name: "ticket".to_owned(),
+            },
+            steps: vec![WorkflowStep::TryCatch {
+                try_steps: vec![WorkflowStep::CreateRuntimeRecord {
+                    entity_logical_name: "escalation".to_owned(),
+                    data: serde_json::json!({"ticket": "{{trigger.record_id}}"}),
+                    output_key: None,
+                    retry_policy: None,
+                }],
+                catch_steps: vec![WorkflowStep::LogMessage {
+                    message: "escalation failed".to_owned(),
+                }],
+                compensation_steps: Vec::new(),
+            }],
+            max_attempts: 1,
+        })
+        .unwrap_or_else(|_| unreachable!());
+
+        assert!(workflow.references_entity("ticket"));
+        assert!(workflow.references_entity("escalation"));
+        assert!(!workflow.references_entity("contact"));
+
+        let scheduled = WorkflowDefinition::new(WorkflowDefinitionInput {
+            logical_name: "nightly".to_owned(),
+            display_name: "Nightly".to_owned(),
+            description: None,
+            trigger: WorkflowTrigger::ScheduleTick {
+                schedule_key: "ticket".to_owned(),
+            },
+            steps: vec![WorkflowStep::LogMessage {
+                message: "tick".to_owned(),
+            }],
+            max_attempts: 1,
+        })
+        .unwrap_or_else(|_| unreachable!());
+        assert!(!scheduled.references_entity("ticket"));
+    }
+
+    #[test]
+    fn field_usages_cover_filters_conditions_templates_and_step_data() {
+        let workflow = WorkflowDefinition::new(WorkflowDefinitionInput {
+            logical_name: "escalate_ticket".to_owned(),
+            display_name: "Escalate Ticket".to_owned(),
+            description: None,
+            trigger: WorkflowTrigger::RuntimeRecordUpdated {
+                entity_logical_name: "ticket".to_owned(),
+            },
+            steps: vec![WorkflowStep::Condition {
+                field_path: "record.priority".to_owned(),
+                operator: WorkflowConditionOperator::Exists,
+                value: None,
+                then_label: None,
+                else_label: None,
+                then_steps: vec![WorkflowStep::SendEmail {
+                    to: "support@example.com".to_owned(),
+                    subject: "Ticket {{ trigger.payload.title }} escalated".to_owned(),
+                    body: "Escalated".to_owned(),
+                    html_body: None,
+                    retry_policy: None,
+                }],
+                else_steps: vec![WorkflowStep::CreateRuntimeRecord {
+                    entity_logical_name: "escalation".to_owned(),
+                    data: json!({"summary": "{{trigger.record_id}}"}),
+                    output_key: None,
+                    retry_policy: None,
+                }],
+            }],
+            max_attempts: 1,
+        })
+        .and_then(|workflow| {
+            workflow.with_trigger_filters(vec![WorkflowTriggerFilter {
+                field_logical_name: "status".to_owned(),
+                operator: WorkflowTriggerFilterOperator::Changed,
+                value: None,
+            }])
+        })
+        .unwrap_or_else(|_| unreachable!());
+
+        assert_eq!(
+            workflow.field_usages("ticket", "status"),
+            vec!["trigger filter"]
+        );
+        assert_eq!(
+            workflow.field_usages("ticket", "priority"),
+            vec!["condition"]
+        );
+        assert_eq!(workflow.field_usages("ticket", "title"), vec!["template"]);
+        assert_eq!(
+            workflow.field_usages("escalation", "summary"),
+            vec!["step data"]
+        );
+        assert!(workflow.field_usages("ticket", "summary").is_empty());
+        assert!(workflow.field_usages("contact", "status").is_empty());
+    }
 }
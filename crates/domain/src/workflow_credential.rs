@@ -0,0 +1,313 @@
+use qryvanta_core::{AppError, AppResult, NonEmptyString, validate_secret_reference};
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of destination hosts one workflow credential may allow.
+pub const WORKFLOW_CREDENTIAL_MAX_ALLOWED_HOSTS: usize = 20;
+
+/// Authentication scheme injected by a workflow credential.
+///
+/// Secrets are stored as secret-provider references and only resolved at
+/// dispatch time, so neither the credential nor any workflow definition
+/// ever contains raw secret material.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WorkflowCredentialAuth {
+    /// Static API key sent in one request header.
+    ApiKey {
+        /// Header receiving the key.
+        header_name: String,
+        /// Optional value prefix such as `Bearer`.
+        #[serde(default)]
+        prefix: Option<String>,
+        /// Secret reference resolving to the key.
+        secret_ref: String,
+    },
+    /// OAuth2 client credentials grant with cached, refreshed access tokens.
+    #[serde(rename = "oauth2_client_credentials")]
+    OAuth2ClientCredentials {
+        /// HTTPS token endpoint.
+        token_url: String,
+        /// OAuth2 client identifier.
+        client_id: String,
+        /// Secret reference resolving to the client secret.
+        client_secret_ref: String,
+        /// Optional space-separated scope list.
+        #[serde(default)]
+        scope: Option<String>,
+    },
+}
+
+impl WorkflowCredentialAuth {
+    /// Returns a stable scheme name.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ApiKey { .. } => "api_key",
+            Self::OAuth2ClientCredentials { .. } => "oauth2_client_credentials",
+        }
+    }
+
+    /// Returns the request header the credential writes.
+    #[must_use]
+    pub fn header_name(&self) -> &str {
+        match self {
+            Self::ApiKey { header_name, .. } => header_name.as_str(),
+            Self::OAuth2ClientCredentials { .. } => "authorization",
+        }
+    }
+
+    fn normalized(self) -> AppResult<Self> {
+        match self {
+            Self::ApiKey {
+                header_name,
+                prefix,
+                secret_ref,
+            } => {
+                let header_name = header_name.trim().to_ascii_lowercase();
+                if header_name.is_empty()
+                    || !header_name
+                        .chars()
+                        .all(|character| character.is_ascii_alphanumeric() || character == '-')
+                {
+                    return Err(AppError::Validation(format!(
+                        "workflow credential header name '{header_name}' is invalid"
+                    )));
+                }
+                validate_secret_reference(secret_ref.as_str())?;
+
+                Ok(Self::ApiKey {
+                    header_name,
+                    prefix: normalized_optional(prefix),
+                    secret_ref: secret_ref.trim().to_owned(),
+                })
+            }
+            Self::OAuth2ClientCredentials {
+                token_url,
+                client_id,
+                client_secret_ref,
+                scope,
+            } => {
+                let token_url = token_url.trim().to_owned();
+                if token_url
+                    .strip_prefix("https://")
+                    .is_none_or(|rest| rest.is_empty() || rest.starts_with('/'))
+                {
+                    return Err(AppError::Validation(
+                        "workflow credential token_url must be an https URL".to_owned(),
+                    ));
+                }
+                let client_id = NonEmptyString::new(client_id.trim())?;
+                validate_secret_reference(client_secret_ref.as_str())?;
+
+                Ok(Self::OAuth2ClientCredentials {
+                    token_url,
+                    client_id: client_id.as_str().to_owned(),
+                    client_secret_ref: client_secret_ref.trim().to_owned(),
+                    scope: normalized_optional(scope),
+                })
+            }
+        }
+    }
+}
+
+/// Named tenant credential that workflow HTTP and webhook steps reference.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowCredential {
+    logical_name: NonEmptyString,
+    display_name: NonEmptyString,
+    auth: WorkflowCredentialAuth,
+    allowed_hosts: Vec<String>,
+}
+
+impl WorkflowCredential {
+    /// Creates a validated workflow credential.
+    ///
+    /// `allowed_hosts` lists exact host names or `*.example.com` wildcards
+    /// matching any subdomain; requests to other hosts are refused.
+    pub fn new(
+        logical_name: impl Into<String>,
+        display_name: impl Into<String>,
+        auth: WorkflowCredentialAuth,
+        allowed_hosts: Vec<String>,
+    ) -> AppResult<Self> {
+        let logical_name = NonEmptyString::new(logical_name.into().trim())?;
+        let display_name = NonEmptyString::new(display_name.into().trim())?;
+        let auth = auth.normalized()?;
+
+        let mut normalized_hosts: Vec<String> = Vec::with_capacity(allowed_hosts.len());
+        for host in allowed_hosts {
+            let host = host.trim().to_ascii_lowercase();
+            let bare_host = host.strip_prefix("*.").unwrap_or(host.as_str());
+            if bare_host.is_empty()
+                || bare_host.starts_with('.')
+                || bare_host.ends_with('.')
+                || !bare_host.chars().all(|character| {
+                    character.is_ascii_alphanumeric() || character == '-' || character == '.'
+                })
+            {
+                return Err(AppError::Validation(format!(
+                    "workflow credential allowed host '{host}' is invalid"
+                )));
+            }
+            if !normalized_hosts.contains(&host) {
+                normalized_hosts.push(host);
+            }
+        }
+
+        if normalized_hosts.is_empty() {
+            return Err(AppError::Validation(
+                "workflow credential requires at least one allowed host".to_owned(),
+            ));
+        }
+        if normalized_hosts.len() > WORKFLOW_CREDENTIAL_MAX_ALLOWED_HOSTS {
+            return Err(AppError::Validation(format!(
+                "workflow credential allows at most {WORKFLOW_CREDENTIAL_MAX_ALLOWED_HOSTS} hosts"
+            )));
+        }
+
+        Ok(Self {
+            logical_name,
+            display_name,
+            auth,
+            allowed_hosts: normalized_hosts,
+        })
+    }
+
+    /// Returns the unique credential name referenced by workflow steps.
+    #[must_use]
+    pub fn logical_name(&self) -> &NonEmptyString {
+        &self.logical_name
+    }
+
+    /// Returns the display name.
+    #[must_use]
+    pub fn display_name(&self) -> &NonEmptyString {
+        &self.display_name
+    }
+
+    /// Returns the injected authentication scheme.
+    #[must_use]
+    pub fn auth(&self) -> &WorkflowCredentialAuth {
+        &self.auth
+    }
+
+    /// Returns the destination host allowlist.
+    #[must_use]
+    pub fn allowed_hosts(&self) -> &[String] {
+        self.allowed_hosts.as_slice()
+    }
+
+    /// Returns whether requests to `host` may carry this credential.
+    #[must_use]
+    pub fn allows_host(&self, host: &str) -> bool {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        self.allowed_hosts
+            .iter()
+            .any(|allowed| match allowed.strip_prefix("*.") {
+                Some(domain) => host
+                    .strip_suffix(domain)
+                    .is_some_and(|subdomain| subdomain.len() > 1 && subdomain.ends_with('.')),
+                None => *allowed == host,
+            })
+    }
+}
+
+fn normalized_optional(value: Option<String>) -> Option<String> {
+    value.and_then(|value| {
+        let trimmed = value.trim().to_owned();
+        (!trimmed.is_empty()).then_some(trimmed)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        WORKFLOW_CREDENTIAL_MAX_ALLOWED_HOSTS, WorkflowCredential, WorkflowCredentialAuth,
+    };
+
+    fn api_key() -> WorkflowCredentialAuth {
+        WorkflowCredentialAuth::ApiKey {
+            header_name: " X-Api-Key ".to_owned(),
+            prefix: Some("  ".to_owned()),
+            secret_ref: "op://vault/item/key".to_owned(),
+        }
+    }
+
+    #[test]
+    fn credential_normalizes_header_and_matches_exact_and_wildcard_hosts() {
+        let credential = WorkflowCredential::new(
+            "erp",
+            "ERP",
+            api_key(),
+            vec![
+                "API.Example.com".to_owned(),
+                "*.erp.example.com".to_owned(),
+                "api.example.com".to_owned(),
+            ],
+        )
+        .unwrap_or_else(|_| unreachable!());
+
+        assert_eq!(credential.auth().header_name(), "x-api-key");
+        assert_eq!(
+            credential.auth(),
+            &WorkflowCredentialAuth::ApiKey {
+                header_name: "x-api-key".to_owned(),
+                prefix: None,
+                secret_ref: "op://vault/item/key".to_owned(),
+            }
+        );
+        assert_eq!(credential.allowed_hosts().len(), 2);
+        assert!(credential.allows_host("api.example.com"));
+        assert!(credential.allows_host("eu.erp.example.com"));
+        assert!(!credential.allows_host("erp.example.com"));
+        assert!(!credential.allows_host("evilerp.example.com"));
+        assert!(!credential.allows_host("api.example.com.evil.test"));
+    }
+
+    #[test]
+    fn credential_rejects_invalid_hosts_secrets_and_token_urls() {
+        assert!(WorkflowCredential::new("erp", "ERP", api_key(), Vec::new()).is_err());
+        assert!(
+            WorkflowCredential::new("erp", "ERP", api_key(), vec!["https://x.test".to_owned()])
+                .is_err()
+        );
+        assert!(
+            WorkflowCredential::new(
+                "erp",
+                "ERP",
+                api_key(),
+                (0..=WORKFLOW_CREDENTIAL_MAX_ALLOWED_HOSTS)
+                    .map(|index| format!("host-{index}.test"))
+                    .collect(),
+            )
+            .is_err()
+        );
+        assert!(
+            WorkflowCredential::new(
+                "erp",
+                "ERP",
+                WorkflowCredentialAuth::ApiKey {
+                    header_name: "x-api-key".to_owned(),
+                    prefix: None,
+                    secret_ref: "plain-text-secret".to_owned(),
+                },
+                vec!["api.example.com".to_owned()],
+            )
+            .is_err()
+        );
+        assert!(
+            WorkflowCredential::new(
+                "erp",
+                "ERP",
+                WorkflowCredentialAuth::OAuth2ClientCredentials {
+                    token_url: "http://auth.example.com/token".to_owned(),
+                    client_id: "qryvanta".to_owned(),
+                    client_secret_ref: "op://vault/item/secret".to_owned(),
+                    scope: None,
+                },
+                vec!["api.example.com".to_owned()],
+            )
+            .is_err()
+        );
+    }
+}
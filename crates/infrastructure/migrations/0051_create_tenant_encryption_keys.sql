@@ -0,0 +1,32 @@
+CREATE TABLE IF NOT EXISTS tenant_encryption_keys (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    version INTEGER NOT NULL,
+    secret_reference TEXT,
+    key_fingerprint TEXT NOT NULL,
+    status TEXT NOT NULL,
+    created_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    retired_at TIMESTAMPTZ,
+    shredded_at TIMESTAMPTZ,
+    PRIMARY KEY (tenant_id, version),
+    CONSTRAINT chk_tenant_encryption_keys_version
+        CHECK (version > 0),
+    CONSTRAINT chk_tenant_encryption_keys_status
+        CHECK (status IN ('active', 'retired', 'shredded')),
+    CONSTRAINT chk_tenant_encryption_keys_shredded_reference
+        CHECK (
+            (status = 'shredded' AND secret_reference IS NULL)
+            OR (status <> 'shredded' AND secret_reference IS NOT NULL)
+        )
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_tenant_encryption_keys_single_active
+    ON tenant_encryption_keys (tenant_id)
+    WHERE status = 'active';
+
+ALTER TABLE tenant_encryption_keys ENABLE ROW LEVEL SECURITY;
+ALTER TABLE tenant_encryption_keys FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON tenant_encryption_keys;
+CREATE POLICY qryvanta_tenant_isolation ON tenant_encryption_keys
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
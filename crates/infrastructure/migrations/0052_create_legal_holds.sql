@@ -0,0 +1,43 @@
+CREATE TABLE IF NOT EXISTS legal_holds (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    scope_type TEXT NOT NULL,
+    entity_logical_name TEXT,
+    record_ids TEXT[] NOT NULL DEFAULT '{}',
+    audit_from TIMESTAMPTZ,
+    audit_until TIMESTAMPTZ,
+    created_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    released_by_subject TEXT,
+    released_at TIMESTAMPTZ,
+    CONSTRAINT chk_legal_holds_scope
+        CHECK (
+            (
+                scope_type = 'runtime_records'
+                AND entity_logical_name IS NOT NULL
+                AND audit_from IS NULL
+                AND audit_until IS NULL
+            )
+            OR (
+                scope_type = 'audit_log'
+                AND entity_logical_name IS NULL
+                AND audit_from IS NOT NULL
+                AND (audit_until IS NULL OR audit_until > audit_from)
+            )
+        ),
+    CONSTRAINT chk_legal_holds_release
+        CHECK ((released_at IS NULL) = (released_by_subject IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_legal_holds_active_scope
+    ON legal_holds (tenant_id, scope_type, entity_logical_name)
+    WHERE released_at IS NULL;
+
+ALTER TABLE legal_holds ENABLE ROW LEVEL SECURITY;
+ALTER TABLE legal_holds FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON legal_holds;
+CREATE POLICY qryvanta_tenant_isolation ON legal_holds
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
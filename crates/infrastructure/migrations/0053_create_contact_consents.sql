@@ -0,0 +1,58 @@
+CREATE TABLE IF NOT EXISTS contact_consents (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    contact_record_id UUID NOT NULL REFERENCES runtime_records(id) ON DELETE CASCADE,
+    purpose TEXT NOT NULL,
+    channel TEXT NOT NULL,
+    status TEXT NOT NULL,
+    source TEXT NOT NULL,
+    granted_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    recorded_by_subject TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, contact_record_id, purpose, channel),
+    CONSTRAINT chk_contact_consents_channel
+        CHECK (channel IN ('email', 'sms', 'phone', 'post')),
+    CONSTRAINT chk_contact_consents_status
+        CHECK (status IN ('granted', 'revoked'))
+);
+
+CREATE TABLE IF NOT EXISTS contact_consent_changes (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    contact_record_id UUID NOT NULL REFERENCES runtime_records(id) ON DELETE CASCADE,
+    purpose TEXT NOT NULL,
+    channel TEXT NOT NULL,
+    previous_status TEXT,
+    status TEXT NOT NULL,
+    source TEXT NOT NULL,
+    recorded_by_subject TEXT NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_contact_consent_changes_channel
+        CHECK (channel IN ('email', 'sms', 'phone', 'post')),
+    CONSTRAINT chk_contact_consent_changes_status
+        CHECK (
+            status IN ('granted', 'revoked')
+            AND (previous_status IS NULL OR previous_status IN ('granted', 'revoked'))
+        )
+);
+
+CREATE INDEX IF NOT EXISTS idx_contact_consent_changes_contact
+    ON contact_consent_changes (tenant_id, contact_record_id, recorded_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_runtime_records_contact_email
+    ON runtime_records (tenant_id, lower(data->>'email'))
+    WHERE entity_logical_name = 'contact';
+
+ALTER TABLE contact_consents ENABLE ROW LEVEL SECURITY;
+ALTER TABLE contact_consents FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON contact_consents;
+CREATE POLICY qryvanta_tenant_isolation ON contact_consents
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE contact_consent_changes ENABLE ROW LEVEL SECURITY;
+ALTER TABLE contact_consent_changes FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON contact_consent_changes;
+CREATE POLICY qryvanta_tenant_isolation ON contact_consent_changes
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
@@ -0,0 +1,73 @@
+CREATE TABLE IF NOT EXISTS runtime_dual_control_fields (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    field_logical_name TEXT NOT NULL,
+    approval_window_hours INTEGER NOT NULL DEFAULT 72,
+    updated_by_subject TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, entity_logical_name, field_logical_name),
+    CONSTRAINT chk_runtime_dual_control_fields_window
+        CHECK (approval_window_hours BETWEEN 1 AND 720)
+);
+
+CREATE TABLE IF NOT EXISTS runtime_pending_field_changes (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    record_id TEXT NOT NULL,
+    field_logical_name TEXT NOT NULL,
+    previous_value JSONB,
+    proposed_value JSONB,
+    requested_by_subject TEXT NOT NULL,
+    requested_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending',
+    decided_by_subject TEXT,
+    decided_at TIMESTAMPTZ,
+    CONSTRAINT chk_runtime_pending_field_changes_status
+        CHECK (status IN ('pending', 'approved', 'rejected', 'expired')),
+    CONSTRAINT chk_runtime_pending_field_changes_decision
+        CHECK (
+            (status IN ('approved', 'rejected') AND decided_by_subject IS NOT NULL AND decided_at IS NOT NULL)
+            OR (status IN ('pending', 'expired') AND decided_by_subject IS NULL)
+        )
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_runtime_pending_field_changes_open
+    ON runtime_pending_field_changes (tenant_id, entity_logical_name, record_id, field_logical_name)
+    WHERE status = 'pending';
+
+CREATE INDEX IF NOT EXISTS idx_runtime_pending_field_changes_expiry
+    ON runtime_pending_field_changes (tenant_id, expires_at)
+    WHERE status = 'pending';
+
+CREATE INDEX IF NOT EXISTS idx_runtime_pending_field_changes_recent
+    ON runtime_pending_field_changes (tenant_id, requested_at DESC);
+
+ALTER TABLE runtime_dual_control_fields ENABLE ROW LEVEL SECURITY;
+ALTER TABLE runtime_dual_control_fields FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON runtime_dual_control_fields;
+CREATE POLICY qryvanta_tenant_isolation ON runtime_dual_control_fields
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE runtime_pending_field_changes ENABLE ROW LEVEL SECURITY;
+ALTER TABLE runtime_pending_field_changes FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON runtime_pending_field_changes;
+CREATE POLICY qryvanta_tenant_isolation ON runtime_pending_field_changes
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE workflow_runtime_trigger_events
+    DROP CONSTRAINT IF EXISTS chk_workflow_runtime_trigger_events_trigger_type;
+
+ALTER TABLE workflow_runtime_trigger_events
+    ADD CONSTRAINT chk_workflow_runtime_trigger_events_trigger_type
+        CHECK (
+            trigger_type IN (
+                'runtime_record_created',
+                'runtime_record_updated',
+                'runtime_record_deleted',
+                'approval_event_received'
+            )
+        );
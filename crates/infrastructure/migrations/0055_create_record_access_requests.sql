@@ -0,0 +1,69 @@
+CREATE TABLE IF NOT EXISTS runtime_record_access_requests (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    record_id TEXT NOT NULL,
+    requested_by_subject TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    duration_hours INTEGER NOT NULL,
+    routed_to_subject TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending',
+    requested_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    decided_by_subject TEXT,
+    decided_at TIMESTAMPTZ,
+    CONSTRAINT chk_runtime_record_access_requests_status
+        CHECK (status IN ('pending', 'approved', 'rejected')),
+    CONSTRAINT chk_runtime_record_access_requests_duration
+        CHECK (duration_hours BETWEEN 1 AND 720),
+    CONSTRAINT chk_runtime_record_access_requests_decision
+        CHECK (
+            (status = 'pending' AND decided_by_subject IS NULL AND decided_at IS NULL)
+            OR (status <> 'pending' AND decided_by_subject IS NOT NULL AND decided_at IS NOT NULL)
+        )
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_runtime_record_access_requests_open
+    ON runtime_record_access_requests (tenant_id, entity_logical_name, record_id, requested_by_subject)
+    WHERE status = 'pending';
+
+CREATE INDEX IF NOT EXISTS idx_runtime_record_access_requests_routed
+    ON runtime_record_access_requests (tenant_id, routed_to_subject, status);
+
+CREATE INDEX IF NOT EXISTS idx_runtime_record_access_requests_recent
+    ON runtime_record_access_requests (tenant_id, requested_at DESC);
+
+CREATE TABLE IF NOT EXISTS runtime_record_shares (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    record_id TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    request_id UUID NOT NULL REFERENCES runtime_record_access_requests(id) ON DELETE CASCADE,
+    granted_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_by_subject TEXT,
+    revoked_at TIMESTAMPTZ,
+    CONSTRAINT chk_runtime_record_shares_window
+        CHECK (expires_at > created_at),
+    CONSTRAINT chk_runtime_record_shares_revocation
+        CHECK ((revoked_at IS NULL) = (revoked_by_subject IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_runtime_record_shares_lookup
+    ON runtime_record_shares (tenant_id, entity_logical_name, record_id, subject, expires_at)
+    WHERE revoked_at IS NULL;
+
+ALTER TABLE runtime_record_access_requests ENABLE ROW LEVEL SECURITY;
+ALTER TABLE runtime_record_access_requests FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON runtime_record_access_requests;
+CREATE POLICY qryvanta_tenant_isolation ON runtime_record_access_requests
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE runtime_record_shares ENABLE ROW LEVEL SECURITY;
+ALTER TABLE runtime_record_shares FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON runtime_record_shares;
+CREATE POLICY qryvanta_tenant_isolation ON runtime_record_shares
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
@@ -0,0 +1,57 @@
+CREATE TABLE IF NOT EXISTS runtime_record_share_links (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    record_id TEXT NOT NULL,
+    field_logical_names TEXT[] NOT NULL,
+    token_hash TEXT NOT NULL,
+    password_hash TEXT,
+    created_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_by_subject TEXT,
+    revoked_at TIMESTAMPTZ,
+    view_count BIGINT NOT NULL DEFAULT 0,
+    last_viewed_at TIMESTAMPTZ,
+    CONSTRAINT chk_runtime_record_share_links_fields
+        CHECK (cardinality(field_logical_names) BETWEEN 1 AND 50),
+    CONSTRAINT chk_runtime_record_share_links_window
+        CHECK (expires_at > created_at),
+    CONSTRAINT chk_runtime_record_share_links_revocation
+        CHECK ((revoked_at IS NULL) = (revoked_by_subject IS NULL))
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_runtime_record_share_links_token
+    ON runtime_record_share_links (tenant_id, token_hash);
+
+CREATE INDEX IF NOT EXISTS idx_runtime_record_share_links_record
+    ON runtime_record_share_links (tenant_id, entity_logical_name, record_id, created_at DESC);
+
+CREATE TABLE IF NOT EXISTS runtime_record_share_link_views (
+    id BIGSERIAL PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    link_id UUID NOT NULL REFERENCES runtime_record_share_links(id) ON DELETE CASCADE,
+    outcome TEXT NOT NULL,
+    ip_address TEXT,
+    user_agent TEXT,
+    viewed_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_runtime_record_share_link_views_outcome
+        CHECK (outcome IN ('viewed', 'password_required', 'invalid_password', 'expired', 'revoked', 'unavailable'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_runtime_record_share_link_views_link
+    ON runtime_record_share_link_views (tenant_id, link_id, viewed_at DESC);
+
+ALTER TABLE runtime_record_share_links ENABLE ROW LEVEL SECURITY;
+ALTER TABLE runtime_record_share_links FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON runtime_record_share_links;
+CREATE POLICY qryvanta_tenant_isolation ON runtime_record_share_links
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE runtime_record_share_link_views ENABLE ROW LEVEL SECURITY;
+ALTER TABLE runtime_record_share_link_views FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON runtime_record_share_link_views;
+CREATE POLICY qryvanta_tenant_isolation ON runtime_record_share_link_views
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
@@ -0,0 +1,9 @@
+ALTER TABLE entity_definitions
+    ADD COLUMN IF NOT EXISTS color TEXT;
+
+ALTER TABLE entity_definitions
+    DROP CONSTRAINT IF EXISTS entity_definitions_color_format;
+
+ALTER TABLE entity_definitions
+    ADD CONSTRAINT entity_definitions_color_format
+    CHECK (color IS NULL OR color ~ '^#[0-9a-f]{6}$');
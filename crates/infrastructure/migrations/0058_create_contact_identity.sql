@@ -0,0 +1,65 @@
+CREATE TABLE IF NOT EXISTS contact_identity_sources (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    email_field_logical_name TEXT,
+    phone_field_logical_name TEXT,
+    name_field_logical_names TEXT[] NOT NULL DEFAULT '{}',
+    updated_by_subject TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, entity_logical_name),
+    CONSTRAINT chk_contact_identity_sources_fields
+        CHECK (
+            email_field_logical_name IS NOT NULL
+            OR phone_field_logical_name IS NOT NULL
+            OR cardinality(name_field_logical_names) > 0
+        )
+);
+
+CREATE TABLE IF NOT EXISTS contact_identity_links (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    record_id UUID NOT NULL REFERENCES runtime_records(id) ON DELETE CASCADE,
+    master_contact_id UUID NOT NULL,
+    email_key TEXT,
+    phone_key TEXT,
+    name_key TEXT,
+    name_block TEXT,
+    match_reason TEXT NOT NULL,
+    confidence SMALLINT NOT NULL,
+    linked_by_subject TEXT NOT NULL,
+    linked_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, entity_logical_name, record_id),
+    CONSTRAINT chk_contact_identity_links_match_reason
+        CHECK (match_reason IN ('email', 'phone', 'fuzzy_name', 'manual', 'unmatched')),
+    CONSTRAINT chk_contact_identity_links_confidence
+        CHECK (confidence BETWEEN 0 AND 100)
+);
+
+CREATE INDEX IF NOT EXISTS idx_contact_identity_links_master
+    ON contact_identity_links (tenant_id, master_contact_id, linked_at);
+
+CREATE INDEX IF NOT EXISTS idx_contact_identity_links_email
+    ON contact_identity_links (tenant_id, email_key)
+    WHERE email_key IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_contact_identity_links_phone
+    ON contact_identity_links (tenant_id, phone_key)
+    WHERE phone_key IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_contact_identity_links_name_block
+    ON contact_identity_links (tenant_id, name_block)
+    WHERE name_block IS NOT NULL;
+
+ALTER TABLE contact_identity_sources ENABLE ROW LEVEL SECURITY;
+ALTER TABLE contact_identity_sources FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON contact_identity_sources;
+CREATE POLICY qryvanta_tenant_isolation ON contact_identity_sources
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE contact_identity_links ENABLE ROW LEVEL SECURITY;
+ALTER TABLE contact_identity_links FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON contact_identity_links;
+CREATE POLICY qryvanta_tenant_isolation ON contact_identity_links
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
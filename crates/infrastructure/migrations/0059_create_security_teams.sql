@@ -0,0 +1,84 @@
+CREATE TABLE IF NOT EXISTS security_teams (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT,
+    created_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (tenant_id, name)
+);
+
+CREATE TABLE IF NOT EXISTS security_team_members (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    team_id UUID NOT NULL REFERENCES security_teams(id) ON DELETE CASCADE,
+    subject TEXT NOT NULL,
+    added_by_subject TEXT NOT NULL,
+    added_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, team_id, subject)
+);
+
+CREATE INDEX IF NOT EXISTS idx_security_team_members_subject
+    ON security_team_members (tenant_id, subject);
+
+ALTER TABLE security_teams ENABLE ROW LEVEL SECURITY;
+ALTER TABLE security_teams FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON security_teams;
+CREATE POLICY qryvanta_tenant_isolation ON security_teams
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE security_team_members ENABLE ROW LEVEL SECURITY;
+ALTER TABLE security_team_members FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON security_team_members;
+CREATE POLICY qryvanta_tenant_isolation ON security_team_members
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+-- Team grants store the team id in `subject` next to a `team` subject type.
+ALTER TABLE rbac_subject_roles
+    ADD COLUMN IF NOT EXISTS subject_type TEXT NOT NULL DEFAULT 'user';
+
+ALTER TABLE rbac_subject_roles
+    DROP CONSTRAINT IF EXISTS chk_rbac_subject_roles_subject_type;
+
+ALTER TABLE rbac_subject_roles
+    ADD CONSTRAINT chk_rbac_subject_roles_subject_type
+    CHECK (subject_type IN ('user', 'team'));
+
+ALTER TABLE rbac_subject_roles
+    DROP CONSTRAINT IF EXISTS rbac_subject_roles_pkey;
+
+ALTER TABLE rbac_subject_roles
+    ADD CONSTRAINT rbac_subject_roles_pkey
+    PRIMARY KEY (tenant_id, subject_type, subject, role_id);
+
+ALTER TABLE runtime_subject_field_permissions
+    ADD COLUMN IF NOT EXISTS subject_type TEXT NOT NULL DEFAULT 'user';
+
+ALTER TABLE runtime_subject_field_permissions
+    DROP CONSTRAINT IF EXISTS chk_runtime_subject_field_permissions_subject_type;
+
+ALTER TABLE runtime_subject_field_permissions
+    ADD CONSTRAINT chk_runtime_subject_field_permissions_subject_type
+    CHECK (subject_type IN ('user', 'team'));
+
+ALTER TABLE runtime_subject_field_permissions
+    DROP CONSTRAINT IF EXISTS runtime_subject_field_permissions_pkey;
+
+ALTER TABLE runtime_subject_field_permissions
+    ADD CONSTRAINT runtime_subject_field_permissions_pkey
+    PRIMARY KEY (tenant_id, subject_type, subject, entity_logical_name, field_logical_name);
+
+-- Principals a user acts as: the user itself plus every team it belongs to.
+CREATE OR REPLACE FUNCTION qryvanta_subject_principals(p_tenant_id UUID, p_subject TEXT)
+RETURNS TABLE (subject_type TEXT, subject TEXT)
+LANGUAGE SQL
+STABLE
+AS $$
+    SELECT 'user'::TEXT, p_subject
+    UNION ALL
+    SELECT 'team'::TEXT, members.team_id::TEXT
+    FROM security_team_members AS members
+    WHERE members.tenant_id = p_tenant_id
+        AND members.subject = p_subject
+$$;
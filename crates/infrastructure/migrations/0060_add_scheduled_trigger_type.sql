@@ -0,0 +1,39 @@
+ALTER TABLE workflow_definitions
+    DROP CONSTRAINT IF EXISTS chk_workflow_definitions_trigger_type;
+
+ALTER TABLE workflow_definitions
+    ADD CONSTRAINT chk_workflow_definitions_trigger_type
+        CHECK (
+            trigger_type IN (
+                'manual',
+                'runtime_record_created',
+                'runtime_record_updated',
+                'runtime_record_deleted',
+                'schedule_tick',
+                'scheduled',
+                'webhook_received',
+                'form_submitted',
+                'inbound_email_received',
+                'approval_event_received'
+            )
+        );
+
+ALTER TABLE workflow_published_versions
+    DROP CONSTRAINT IF EXISTS chk_workflow_published_versions_trigger_type;
+
+ALTER TABLE workflow_published_versions
+    ADD CONSTRAINT chk_workflow_published_versions_trigger_type
+        CHECK (
+            trigger_type IN (
+                'manual',
+                'runtime_record_created',
+                'runtime_record_updated',
+                'runtime_record_deleted',
+                'schedule_tick',
+                'scheduled',
+                'webhook_received',
+                'form_submitted',
+                'inbound_email_received',
+                'approval_event_received'
+            )
+        );
@@ -0,0 +1,38 @@
+CREATE TABLE IF NOT EXISTS runtime_relation_behaviors (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    field_logical_name TEXT NOT NULL,
+    target_entity_logical_name TEXT NOT NULL,
+    assign_behavior TEXT NOT NULL DEFAULT 'none',
+    share_behavior TEXT NOT NULL DEFAULT 'none',
+    updated_by_subject TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, entity_logical_name, field_logical_name),
+    CONSTRAINT chk_runtime_relation_behaviors_assign
+        CHECK (assign_behavior IN ('none', 'cascade_all', 'cascade_user_owned')),
+    CONSTRAINT chk_runtime_relation_behaviors_share
+        CHECK (share_behavior IN ('none', 'cascade_all', 'cascade_user_owned'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_runtime_relation_behaviors_target
+    ON runtime_relation_behaviors (tenant_id, target_entity_logical_name);
+
+ALTER TABLE runtime_relation_behaviors ENABLE ROW LEVEL SECURITY;
+ALTER TABLE runtime_relation_behaviors FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON runtime_relation_behaviors;
+CREATE POLICY qryvanta_tenant_isolation ON runtime_relation_behaviors
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+-- Shares cascaded to child records point at the parent share so revocation follows.
+ALTER TABLE runtime_record_shares
+    ADD COLUMN IF NOT EXISTS cascaded_from_share_id UUID
+    REFERENCES runtime_record_shares(id) ON DELETE CASCADE;
+
+CREATE INDEX IF NOT EXISTS idx_runtime_record_shares_cascaded_from
+    ON runtime_record_shares (tenant_id, cascaded_from_share_id)
+    WHERE cascaded_from_share_id IS NOT NULL;
+
+-- Cascaded ownership updates match children by relation value.
+CREATE INDEX IF NOT EXISTS idx_runtime_records_entity_owner
+    ON runtime_records (tenant_id, entity_logical_name, created_by_subject);
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS entity_slug_configs (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    slug_field_logical_name TEXT NOT NULL,
+    source_field_logical_name TEXT NOT NULL,
+    updated_by_subject TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, entity_logical_name)
+);
+
+ALTER TABLE entity_slug_configs ENABLE ROW LEVEL SECURITY;
+ALTER TABLE entity_slug_configs FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON entity_slug_configs;
+CREATE POLICY qryvanta_tenant_isolation ON entity_slug_configs
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
@@ -0,0 +1,25 @@
+ALTER TABLE workflow_execution_runs
+    ADD COLUMN IF NOT EXISTS resume_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS continuation JSONB;
+
+ALTER TABLE workflow_execution_runs
+    DROP CONSTRAINT IF EXISTS chk_workflow_execution_runs_status;
+
+ALTER TABLE workflow_execution_runs
+    ADD CONSTRAINT chk_workflow_execution_runs_status
+        CHECK (status IN ('running', 'waiting', 'succeeded', 'dead_lettered'));
+
+ALTER TABLE workflow_execution_attempts
+    DROP CONSTRAINT IF EXISTS chk_workflow_execution_attempts_status;
+
+ALTER TABLE workflow_execution_attempts
+    ADD CONSTRAINT chk_workflow_execution_attempts_status
+        CHECK (status IN ('succeeded', 'failed', 'waiting'));
+
+ALTER TABLE workflow_execution_jobs
+    ADD COLUMN IF NOT EXISTS available_at TIMESTAMPTZ NOT NULL DEFAULT now();
+
+DROP INDEX IF EXISTS idx_workflow_execution_jobs_claim;
+
+CREATE INDEX IF NOT EXISTS idx_workflow_execution_jobs_claim
+    ON workflow_execution_jobs (status, available_at, lease_expires_at, created_at);
@@ -0,0 +1,5 @@
+ALTER TABLE workflow_definitions
+    ADD COLUMN IF NOT EXISTS trigger_filters JSONB NOT NULL DEFAULT '[]'::jsonb;
+
+ALTER TABLE workflow_published_versions
+    ADD COLUMN IF NOT EXISTS trigger_filters JSONB NOT NULL DEFAULT '[]'::jsonb;
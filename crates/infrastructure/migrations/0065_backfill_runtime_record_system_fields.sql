@@ -0,0 +1,16 @@
+-- System fields (created_by, created_at, modified_by, modified_at, owner) are
+-- stamped into record data on every write. Backfill records written before
+-- that from the audit columns; existing keys win so fields published under
+-- the reserved names keep their values.
+ALTER TABLE runtime_records NO FORCE ROW LEVEL SECURITY;
+
+UPDATE runtime_records
+SET data = jsonb_build_object(
+        'created_by', created_by_subject,
+        'created_at', to_char(created_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS.MS"Z"'),
+        'modified_by', created_by_subject,
+        'modified_at', to_char(updated_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS.MS"Z"'),
+        'owner', created_by_subject
+    ) || data;
+
+ALTER TABLE runtime_records FORCE ROW LEVEL SECURITY;
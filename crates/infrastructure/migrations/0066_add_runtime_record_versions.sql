@@ -0,0 +1,4 @@
+-- Optimistic concurrency: every data change increments the record version so
+-- clients can send the version they last read and get a conflict when stale.
+ALTER TABLE runtime_records
+    ADD COLUMN IF NOT EXISTS version BIGINT NOT NULL DEFAULT 1;
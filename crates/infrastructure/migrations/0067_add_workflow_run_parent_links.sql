@@ -0,0 +1,7 @@
+ALTER TABLE workflow_execution_runs
+    ADD COLUMN IF NOT EXISTS parent_run_id UUID
+        REFERENCES workflow_execution_runs (id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_workflow_execution_runs_parent
+    ON workflow_execution_runs (tenant_id, parent_run_id)
+    WHERE parent_run_id IS NOT NULL;
@@ -0,0 +1,26 @@
+CREATE TABLE IF NOT EXISTS workflow_inbound_webhooks (
+    tenant_id UUID NOT NULL,
+    workflow_logical_name TEXT NOT NULL,
+    token_hash TEXT NOT NULL,
+    signing_secret_ciphertext BYTEA NOT NULL,
+    payload_schema JSONB,
+    created_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    delivery_count BIGINT NOT NULL DEFAULT 0,
+    last_received_at TIMESTAMPTZ,
+    PRIMARY KEY (tenant_id, workflow_logical_name),
+    CONSTRAINT fk_workflow_inbound_webhooks_workflow
+        FOREIGN KEY (tenant_id, workflow_logical_name)
+        REFERENCES workflow_definitions (tenant_id, logical_name)
+        ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_workflow_inbound_webhooks_token
+    ON workflow_inbound_webhooks (tenant_id, token_hash);
+
+ALTER TABLE workflow_inbound_webhooks ENABLE ROW LEVEL SECURITY;
+ALTER TABLE workflow_inbound_webhooks FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON workflow_inbound_webhooks;
+CREATE POLICY qryvanta_tenant_isolation ON workflow_inbound_webhooks
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
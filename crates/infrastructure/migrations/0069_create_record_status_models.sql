@@ -0,0 +1,103 @@
+CREATE TABLE IF NOT EXISTS entity_status_models (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    status_field_logical_name TEXT NOT NULL,
+    reason_field_logical_name TEXT,
+    initial_status TEXT NOT NULL,
+    statuses JSONB NOT NULL,
+    transitions JSONB NOT NULL,
+    updated_by_subject TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, entity_logical_name),
+    CONSTRAINT chk_entity_status_models_statuses_json_array
+        CHECK (jsonb_typeof(statuses) = 'array'),
+    CONSTRAINT chk_entity_status_models_transitions_json_array
+        CHECK (jsonb_typeof(transitions) = 'array')
+);
+
+CREATE TABLE IF NOT EXISTS runtime_record_status_history (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    record_id TEXT NOT NULL,
+    from_status TEXT,
+    to_status TEXT NOT NULL,
+    from_reason TEXT,
+    to_reason TEXT,
+    changed_by_subject TEXT NOT NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_runtime_record_status_history_record
+    ON runtime_record_status_history (tenant_id, entity_logical_name, record_id, changed_at);
+
+ALTER TABLE entity_status_models ENABLE ROW LEVEL SECURITY;
+ALTER TABLE entity_status_models FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON entity_status_models;
+CREATE POLICY qryvanta_tenant_isolation ON entity_status_models
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE runtime_record_status_history ENABLE ROW LEVEL SECURITY;
+ALTER TABLE runtime_record_status_history FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON runtime_record_status_history;
+CREATE POLICY qryvanta_tenant_isolation ON runtime_record_status_history
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE workflow_definitions
+    DROP CONSTRAINT IF EXISTS chk_workflow_definitions_trigger_type;
+
+ALTER TABLE workflow_definitions
+    ADD CONSTRAINT chk_workflow_definitions_trigger_type
+        CHECK (
+            trigger_type IN (
+                'manual',
+                'runtime_record_created',
+                'runtime_record_updated',
+                'runtime_record_deleted',
+                'runtime_record_status_changed',
+                'schedule_tick',
+                'scheduled',
+                'webhook_received',
+                'form_submitted',
+                'inbound_email_received',
+                'approval_event_received'
+            )
+        );
+
+ALTER TABLE workflow_published_versions
+    DROP CONSTRAINT IF EXISTS chk_workflow_published_versions_trigger_type;
+
+ALTER TABLE workflow_published_versions
+    ADD CONSTRAINT chk_workflow_published_versions_trigger_type
+        CHECK (
+            trigger_type IN (
+                'manual',
+                'runtime_record_created',
+                'runtime_record_updated',
+                'runtime_record_deleted',
+                'runtime_record_status_changed',
+                'schedule_tick',
+                'scheduled',
+                'webhook_received',
+                'form_submitted',
+                'inbound_email_received',
+                'approval_event_received'
+            )
+        );
+
+ALTER TABLE workflow_runtime_trigger_events
+    DROP CONSTRAINT IF EXISTS chk_workflow_runtime_trigger_events_trigger_type;
+
+ALTER TABLE workflow_runtime_trigger_events
+    ADD CONSTRAINT chk_workflow_runtime_trigger_events_trigger_type
+        CHECK (
+            trigger_type IN (
+                'runtime_record_created',
+                'runtime_record_updated',
+                'runtime_record_deleted',
+                'runtime_record_status_changed',
+                'approval_event_received'
+            )
+        );
@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS runtime_relation_lookups (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    field_logical_name TEXT NOT NULL,
+    target_entity_logical_name TEXT NOT NULL,
+    search_field_logical_names JSONB NOT NULL DEFAULT '[]'::jsonb,
+    filter_criteria JSONB,
+    updated_by_subject TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, entity_logical_name, field_logical_name),
+    CONSTRAINT chk_runtime_relation_lookups_search_fields_json_array
+        CHECK (jsonb_typeof(search_field_logical_names) = 'array')
+);
+
+ALTER TABLE runtime_relation_lookups ENABLE ROW LEVEL SECURITY;
+ALTER TABLE runtime_relation_lookups FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON runtime_relation_lookups;
+CREATE POLICY qryvanta_tenant_isolation ON runtime_relation_lookups
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
@@ -0,0 +1,30 @@
+CREATE TABLE IF NOT EXISTS app_personal_views (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    app_logical_name TEXT NOT NULL,
+    entity_logical_name TEXT NOT NULL,
+    owner_subject TEXT NOT NULL,
+    display_name TEXT NOT NULL,
+    columns JSONB NOT NULL,
+    default_sort JSONB,
+    filter_criteria JSONB,
+    is_shared BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT fk_app_personal_views_app
+        FOREIGN KEY (tenant_id, app_logical_name)
+        REFERENCES app_definitions (tenant_id, logical_name)
+        ON DELETE CASCADE,
+    CONSTRAINT chk_app_personal_views_columns_json_array
+        CHECK (jsonb_typeof(columns) = 'array')
+);
+
+CREATE INDEX IF NOT EXISTS idx_app_personal_views_entity
+    ON app_personal_views (tenant_id, app_logical_name, entity_logical_name, owner_subject);
+
+ALTER TABLE app_personal_views ENABLE ROW LEVEL SECURITY;
+ALTER TABLE app_personal_views FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON app_personal_views;
+CREATE POLICY qryvanta_tenant_isolation ON app_personal_views
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
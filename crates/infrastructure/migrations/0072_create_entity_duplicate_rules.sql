@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS entity_duplicate_rules (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    logical_name TEXT NOT NULL,
+    display_name TEXT NOT NULL,
+    match_fields JSONB NOT NULL,
+    action TEXT NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_by_subject TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, entity_logical_name, logical_name),
+    CONSTRAINT chk_entity_duplicate_rules_match_fields_json_array
+        CHECK (jsonb_typeof(match_fields) = 'array'),
+    CONSTRAINT chk_entity_duplicate_rules_action
+        CHECK (action IN ('warn', 'block'))
+);
+
+ALTER TABLE entity_duplicate_rules ENABLE ROW LEVEL SECURITY;
+ALTER TABLE entity_duplicate_rules FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON entity_duplicate_rules;
+CREATE POLICY qryvanta_tenant_isolation ON entity_duplicate_rules
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
@@ -0,0 +1,26 @@
+CREATE TABLE IF NOT EXISTS workflow_bulk_executions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    workflow_logical_name TEXT NOT NULL,
+    entity_logical_name TEXT NOT NULL,
+    view_logical_name TEXT,
+    total_records BIGINT NOT NULL,
+    requested_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_workflow_bulk_executions_total_records
+        CHECK (total_records > 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_workflow_bulk_executions_tenant_created
+    ON workflow_bulk_executions (tenant_id, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_workflow_execution_runs_bulk_execution
+    ON workflow_execution_runs (tenant_id, (trigger_payload ->> 'bulk_execution_id'))
+    WHERE trigger_payload ? 'bulk_execution_id';
+
+ALTER TABLE workflow_bulk_executions ENABLE ROW LEVEL SECURITY;
+ALTER TABLE workflow_bulk_executions FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON workflow_bulk_executions;
+CREATE POLICY qryvanta_tenant_isolation ON workflow_bulk_executions
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
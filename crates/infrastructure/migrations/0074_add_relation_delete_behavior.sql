@@ -0,0 +1,8 @@
+ALTER TABLE runtime_relation_behaviors
+    ADD COLUMN IF NOT EXISTS delete_behavior TEXT NOT NULL DEFAULT 'restrict';
+
+ALTER TABLE runtime_relation_behaviors
+    DROP CONSTRAINT IF EXISTS chk_runtime_relation_behaviors_delete;
+ALTER TABLE runtime_relation_behaviors
+    ADD CONSTRAINT chk_runtime_relation_behaviors_delete
+    CHECK (delete_behavior IN ('restrict', 'cascade', 'set_null', 'reparent'));
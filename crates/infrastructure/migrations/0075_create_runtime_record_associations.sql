@@ -0,0 +1,21 @@
+CREATE TABLE IF NOT EXISTS runtime_record_associations (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    field_logical_name TEXT NOT NULL,
+    record_id UUID NOT NULL REFERENCES runtime_records(id) ON DELETE CASCADE,
+    target_entity_logical_name TEXT NOT NULL,
+    target_record_id UUID NOT NULL REFERENCES runtime_records(id) ON DELETE CASCADE,
+    associated_by_subject TEXT NOT NULL,
+    associated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, entity_logical_name, field_logical_name, record_id, target_record_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_runtime_record_associations_target
+    ON runtime_record_associations (tenant_id, target_entity_logical_name, target_record_id);
+
+ALTER TABLE runtime_record_associations ENABLE ROW LEVEL SECURITY;
+ALTER TABLE runtime_record_associations FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON runtime_record_associations;
+CREATE POLICY qryvanta_tenant_isolation ON runtime_record_associations
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
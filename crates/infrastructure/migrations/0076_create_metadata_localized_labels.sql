@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS metadata_localized_labels (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    component_type TEXT NOT NULL
+        CHECK (component_type IN ('workflow', 'business_rule', 'role', 'app')),
+    component_key TEXT NOT NULL,
+    locale TEXT NOT NULL,
+    display_name TEXT NOT NULL,
+    description TEXT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, component_type, component_key, locale)
+);
+
+ALTER TABLE metadata_localized_labels ENABLE ROW LEVEL SECURITY;
+ALTER TABLE metadata_localized_labels FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON metadata_localized_labels;
+CREATE POLICY qryvanta_tenant_isolation ON metadata_localized_labels
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
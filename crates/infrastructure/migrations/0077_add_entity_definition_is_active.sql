@@ -0,0 +1,2 @@
+ALTER TABLE entity_definitions
+    ADD COLUMN IF NOT EXISTS is_active BOOLEAN NOT NULL DEFAULT true;
@@ -0,0 +1,5 @@
+-- Bootstrap tokens are single-use; a hash may only be registered once so
+-- concurrent API replicas cannot seed the same configured token twice.
+CREATE UNIQUE INDEX IF NOT EXISTS idx_auth_tokens_bootstrap_hash
+    ON auth_tokens (token_hash)
+    WHERE token_type = 'bootstrap';
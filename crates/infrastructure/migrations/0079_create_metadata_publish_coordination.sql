@@ -0,0 +1,51 @@
+CREATE TABLE IF NOT EXISTS metadata_publish_locks (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    scope TEXT NOT NULL,
+    logical_name TEXT NOT NULL,
+    lock_token TEXT NOT NULL,
+    holder_subject TEXT NOT NULL,
+    acquired_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, scope, logical_name),
+    CONSTRAINT chk_metadata_publish_locks_scope
+        CHECK (scope IN ('entity', 'app', 'workflow'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_metadata_publish_locks_token
+    ON metadata_publish_locks (tenant_id, lock_token);
+
+ALTER TABLE metadata_publish_locks ENABLE ROW LEVEL SECURITY;
+ALTER TABLE metadata_publish_locks FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON metadata_publish_locks;
+CREATE POLICY qryvanta_tenant_isolation ON metadata_publish_locks
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+CREATE TABLE IF NOT EXISTS metadata_publish_intents (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    requested_by_subject TEXT NOT NULL,
+    requested_by_display_name TEXT NOT NULL,
+    requested_by_email TEXT,
+    targets JSONB NOT NULL,
+    reviewed_drafts JSONB NOT NULL DEFAULT '[]'::jsonb,
+    status TEXT NOT NULL DEFAULT 'queued',
+    status_message TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    resolved_at TIMESTAMPTZ,
+    CONSTRAINT chk_metadata_publish_intents_status
+        CHECK (status IN ('queued', 'completed', 'conflicted', 'failed', 'cancelled')),
+    CONSTRAINT chk_metadata_publish_intents_resolution
+        CHECK ((status = 'queued') = (resolved_at IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_metadata_publish_intents_queue
+    ON metadata_publish_intents (tenant_id, created_at)
+    WHERE status = 'queued';
+
+ALTER TABLE metadata_publish_intents ENABLE ROW LEVEL SECURITY;
+ALTER TABLE metadata_publish_intents FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON metadata_publish_intents;
+CREATE POLICY qryvanta_tenant_isolation ON metadata_publish_intents
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
@@ -0,0 +1,30 @@
+CREATE TABLE IF NOT EXISTS audit_outbox_events (
+    id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    subject TEXT NOT NULL,
+    action TEXT NOT NULL,
+    resource_type TEXT NOT NULL,
+    resource_id TEXT NOT NULL,
+    detail TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT fk_audit_outbox_events_tenant
+        FOREIGN KEY (tenant_id)
+        REFERENCES tenants (id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_outbox_events_tenant
+    ON audit_outbox_events (tenant_id, id);
+
+ALTER TABLE audit_outbox_events ENABLE ROW LEVEL SECURITY;
+ALTER TABLE audit_outbox_events FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON audit_outbox_events;
+CREATE POLICY qryvanta_tenant_isolation ON audit_outbox_events
+    USING (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('audit_outbox')
+    )
+    WITH CHECK (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('audit_outbox')
+    );
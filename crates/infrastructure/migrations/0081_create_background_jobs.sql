@@ -0,0 +1,47 @@
+CREATE TABLE IF NOT EXISTS background_jobs (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'queued',
+    requested_by_subject TEXT NOT NULL,
+    requested_by_display_name TEXT NOT NULL,
+    requested_by_email TEXT,
+    parameters JSONB NOT NULL DEFAULT '{}'::jsonb,
+    stages JSONB NOT NULL DEFAULT '[]'::jsonb,
+    checkpoint JSONB,
+    cancel_requested BOOLEAN NOT NULL DEFAULT false,
+    attempt_count INTEGER NOT NULL DEFAULT 0,
+    status_message TEXT,
+    lease_token TEXT,
+    lease_expires_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    started_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    finished_at TIMESTAMPTZ,
+    CONSTRAINT chk_background_jobs_kind
+        CHECK (kind IN ('audit_log_purge', 'qrywell_sync')),
+    CONSTRAINT chk_background_jobs_status
+        CHECK (status IN ('queued', 'running', 'completed', 'failed', 'cancelled')),
+    CONSTRAINT chk_background_jobs_finished
+        CHECK ((status IN ('completed', 'failed', 'cancelled')) = (finished_at IS NOT NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_background_jobs_tenant_created
+    ON background_jobs (tenant_id, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_background_jobs_claimable
+    ON background_jobs (created_at)
+    WHERE status IN ('queued', 'running');
+
+ALTER TABLE background_jobs ENABLE ROW LEVEL SECURITY;
+ALTER TABLE background_jobs FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON background_jobs;
+CREATE POLICY qryvanta_tenant_isolation ON background_jobs
+    USING (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('background_jobs')
+    )
+    WITH CHECK (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('background_jobs')
+    );
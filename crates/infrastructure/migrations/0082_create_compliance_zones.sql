@@ -0,0 +1,41 @@
+CREATE TABLE IF NOT EXISTS compliance_zone_assignments (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    subject TEXT NOT NULL,
+    zone TEXT NOT NULL,
+    assigned_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, subject, zone)
+);
+
+CREATE TABLE IF NOT EXISTS compliance_zone_tags (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    record_id TEXT,
+    zone TEXT NOT NULL,
+    tagged_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_compliance_zone_tags_entity
+    ON compliance_zone_tags (tenant_id, entity_logical_name)
+    WHERE record_id IS NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_compliance_zone_tags_record
+    ON compliance_zone_tags (tenant_id, entity_logical_name, record_id)
+    WHERE record_id IS NOT NULL;
+
+ALTER TABLE compliance_zone_assignments ENABLE ROW LEVEL SECURITY;
+ALTER TABLE compliance_zone_assignments FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON compliance_zone_assignments;
+CREATE POLICY qryvanta_tenant_isolation ON compliance_zone_assignments
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE compliance_zone_tags ENABLE ROW LEVEL SECURITY;
+ALTER TABLE compliance_zone_tags FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON compliance_zone_tags;
+CREATE POLICY qryvanta_tenant_isolation ON compliance_zone_tags
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
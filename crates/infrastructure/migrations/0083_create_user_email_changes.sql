@@ -0,0 +1,10 @@
+-- Pending account email changes awaiting confirmation from both addresses.
+CREATE TABLE IF NOT EXISTS user_email_changes (
+    user_id              UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    current_email        TEXT NOT NULL,
+    new_email            TEXT NOT NULL,
+    current_confirmed_at TIMESTAMPTZ,
+    new_confirmed_at     TIMESTAMPTZ,
+    requested_at         TIMESTAMPTZ NOT NULL DEFAULT now(),
+    expires_at           TIMESTAMPTZ NOT NULL
+);
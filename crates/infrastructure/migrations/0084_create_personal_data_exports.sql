@@ -0,0 +1,27 @@
+ALTER TABLE background_jobs DROP CONSTRAINT IF EXISTS chk_background_jobs_kind;
+ALTER TABLE background_jobs ADD CONSTRAINT chk_background_jobs_kind
+    CHECK (kind IN ('audit_log_purge', 'qrywell_sync', 'personal_data_export'));
+
+CREATE TABLE IF NOT EXISTS personal_data_export_parts (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    job_id UUID NOT NULL REFERENCES background_jobs(id) ON DELETE CASCADE,
+    subject TEXT NOT NULL,
+    section TEXT NOT NULL,
+    entity_logical_name TEXT NOT NULL DEFAULT '',
+    item_offset BIGINT NOT NULL,
+    items JSONB NOT NULL DEFAULT '[]'::jsonb,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, job_id, section, entity_logical_name, item_offset),
+    CONSTRAINT chk_personal_data_export_parts_section
+        CHECK (section IN ('profile', 'auth_events', 'owned_records'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_personal_data_export_parts_subject
+    ON personal_data_export_parts (tenant_id, subject);
+
+ALTER TABLE personal_data_export_parts ENABLE ROW LEVEL SECURITY;
+ALTER TABLE personal_data_export_parts FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON personal_data_export_parts;
+CREATE POLICY qryvanta_tenant_isolation ON personal_data_export_parts
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
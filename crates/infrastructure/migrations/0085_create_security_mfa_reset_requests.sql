@@ -0,0 +1,29 @@
+CREATE TABLE IF NOT EXISTS security_mfa_reset_requests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    subject TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    requested_by_subject TEXT NOT NULL,
+    requested_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    approved_by_subject TEXT,
+    approved_at TIMESTAMPTZ,
+    CONSTRAINT chk_security_mfa_reset_requests_four_eyes
+        CHECK (
+            approved_by_subject IS NULL
+            OR (
+                approved_by_subject <> requested_by_subject
+                AND approved_by_subject <> subject
+            )
+        )
+);
+
+CREATE INDEX IF NOT EXISTS idx_security_mfa_reset_requests_subject
+    ON security_mfa_reset_requests (tenant_id, subject, requested_at DESC);
+
+ALTER TABLE security_mfa_reset_requests ENABLE ROW LEVEL SECURITY;
+ALTER TABLE security_mfa_reset_requests FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON security_mfa_reset_requests;
+CREATE POLICY qryvanta_tenant_isolation ON security_mfa_reset_requests
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
@@ -0,0 +1,44 @@
+CREATE TABLE IF NOT EXISTS report_subscriptions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    subject TEXT NOT NULL,
+    subscriber_display_name TEXT NOT NULL,
+    recipient_email TEXT NOT NULL,
+    entity_logical_name TEXT NOT NULL,
+    view_logical_name TEXT NOT NULL,
+    frequency TEXT NOT NULL,
+    format TEXT NOT NULL,
+    delivery_hour_utc SMALLINT NOT NULL,
+    next_delivery_at TIMESTAMPTZ NOT NULL,
+    last_delivered_at TIMESTAMPTZ,
+    suppressed_at TIMESTAMPTZ,
+    suppression_reason TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_report_subscriptions_frequency
+        CHECK (frequency IN ('daily', 'weekly')),
+    CONSTRAINT chk_report_subscriptions_format
+        CHECK (format IN ('csv', 'xlsx')),
+    CONSTRAINT chk_report_subscriptions_delivery_hour
+        CHECK (delivery_hour_utc BETWEEN 0 AND 23)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_report_subscriptions_active_view
+    ON report_subscriptions (tenant_id, subject, entity_logical_name, view_logical_name)
+    WHERE suppressed_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_report_subscriptions_due
+    ON report_subscriptions (next_delivery_at)
+    WHERE suppressed_at IS NULL;
+
+ALTER TABLE report_subscriptions ENABLE ROW LEVEL SECURITY;
+ALTER TABLE report_subscriptions FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON report_subscriptions;
+CREATE POLICY qryvanta_tenant_isolation ON report_subscriptions
+    USING (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('report_subscriptions')
+    )
+    WITH CHECK (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('report_subscriptions')
+    );
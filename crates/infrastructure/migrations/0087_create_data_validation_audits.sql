@@ -0,0 +1,62 @@
+ALTER TABLE background_jobs DROP CONSTRAINT IF EXISTS chk_background_jobs_kind;
+ALTER TABLE background_jobs ADD CONSTRAINT chk_background_jobs_kind
+    CHECK (
+        kind IN (
+            'audit_log_purge',
+            'qrywell_sync',
+            'personal_data_export',
+            'data_validation_audit'
+        )
+    );
+
+CREATE TABLE IF NOT EXISTS data_validation_violations (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    job_id UUID NOT NULL REFERENCES background_jobs(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    page_offset BIGINT NOT NULL,
+    position INTEGER NOT NULL,
+    record_id TEXT NOT NULL,
+    rule TEXT NOT NULL,
+    field_logical_name TEXT,
+    business_rule_logical_name TEXT,
+    message TEXT NOT NULL,
+    PRIMARY KEY (tenant_id, job_id, entity_logical_name, page_offset, position),
+    CONSTRAINT chk_data_validation_violations_rule
+        CHECK (rule IN ('unknown_field', 'invalid_value', 'missing_required_field', 'business_rule'))
+);
+
+ALTER TABLE data_validation_violations ENABLE ROW LEVEL SECURITY;
+ALTER TABLE data_validation_violations FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON data_validation_violations;
+CREATE POLICY qryvanta_tenant_isolation ON data_validation_violations
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+CREATE TABLE IF NOT EXISTS data_validation_schedules (
+    tenant_id UUID PRIMARY KEY REFERENCES tenants(id) ON DELETE CASCADE,
+    interval_days SMALLINT NOT NULL,
+    configured_by_subject TEXT NOT NULL,
+    configured_by_display_name TEXT NOT NULL,
+    configured_by_email TEXT,
+    next_run_at TIMESTAMPTZ NOT NULL,
+    last_run_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_data_validation_schedules_interval
+        CHECK (interval_days BETWEEN 1 AND 90)
+);
+
+CREATE INDEX IF NOT EXISTS idx_data_validation_schedules_due
+    ON data_validation_schedules (next_run_at);
+
+ALTER TABLE data_validation_schedules ENABLE ROW LEVEL SECURITY;
+ALTER TABLE data_validation_schedules FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON data_validation_schedules;
+CREATE POLICY qryvanta_tenant_isolation ON data_validation_schedules
+    USING (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('data_validation_schedules')
+    )
+    WITH CHECK (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('data_validation_schedules')
+    );
@@ -0,0 +1,5 @@
+-- Passkey management: user-facing names and last-use tracking for registered credentials.
+
+ALTER TABLE passkey_credentials
+    ADD COLUMN IF NOT EXISTS name TEXT NOT NULL DEFAULT 'Passkey',
+    ADD COLUMN IF NOT EXISTS last_used_at TIMESTAMPTZ;
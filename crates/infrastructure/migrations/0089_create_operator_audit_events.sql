@@ -0,0 +1,14 @@
+-- Global log of operator console actions. Operators act across tenants, so
+-- the log has no tenant isolation and is never exposed through tenant APIs.
+
+CREATE TABLE IF NOT EXISTS operator_audit_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    operator_id TEXT NOT NULL,
+    action TEXT NOT NULL,
+    tenant_id UUID REFERENCES tenants(id) ON DELETE SET NULL,
+    detail TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_operator_audit_events_created
+    ON operator_audit_events (created_at DESC);
@@ -0,0 +1,23 @@
+-- Delegated app administration: subjects that manage one app without tenant-wide role management.
+CREATE TABLE IF NOT EXISTS app_admin_grants (
+    tenant_id UUID NOT NULL,
+    app_logical_name TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    granted_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, app_logical_name, subject),
+    CONSTRAINT fk_app_admin_grants_app
+        FOREIGN KEY (tenant_id, app_logical_name)
+        REFERENCES app_definitions (tenant_id, logical_name)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_app_admin_grants_subject
+    ON app_admin_grants (tenant_id, subject);
+
+ALTER TABLE app_admin_grants ENABLE ROW LEVEL SECURITY;
+ALTER TABLE app_admin_grants FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON app_admin_grants;
+CREATE POLICY qryvanta_tenant_isolation ON app_admin_grants
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
@@ -0,0 +1,7 @@
+ALTER TABLE workflow_definitions
+    ADD COLUMN IF NOT EXISTS trigger_changed_fields JSONB NOT NULL DEFAULT '[]'::jsonb,
+    ADD COLUMN IF NOT EXISTS trigger_view_logical_name TEXT;
+
+ALTER TABLE workflow_published_versions
+    ADD COLUMN IF NOT EXISTS trigger_changed_fields JSONB NOT NULL DEFAULT '[]'::jsonb,
+    ADD COLUMN IF NOT EXISTS trigger_view_logical_name TEXT;
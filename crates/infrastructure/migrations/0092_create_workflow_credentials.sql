@@ -0,0 +1,24 @@
+-- Named outbound credentials injected into workflow HTTP and webhook steps.
+-- Only secret-provider references are stored; secrets resolve at dispatch time.
+CREATE TABLE IF NOT EXISTS workflow_credentials (
+    tenant_id UUID NOT NULL,
+    logical_name TEXT NOT NULL,
+    display_name TEXT NOT NULL,
+    auth JSONB NOT NULL,
+    allowed_hosts JSONB NOT NULL DEFAULT '[]'::jsonb,
+    updated_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, logical_name),
+    CONSTRAINT fk_workflow_credentials_tenant
+        FOREIGN KEY (tenant_id)
+        REFERENCES tenants (id)
+        ON DELETE CASCADE
+);
+
+ALTER TABLE workflow_credentials ENABLE ROW LEVEL SECURITY;
+ALTER TABLE workflow_credentials FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON workflow_credentials;
+CREATE POLICY qryvanta_tenant_isolation ON workflow_credentials
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
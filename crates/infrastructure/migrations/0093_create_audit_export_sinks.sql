@@ -0,0 +1,39 @@
+CREATE TABLE IF NOT EXISTS audit_export_sinks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    sink_type TEXT NOT NULL,
+    config JSONB NOT NULL,
+    is_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    checkpoint_position BIGINT NOT NULL DEFAULT 0,
+    lease_until TIMESTAMPTZ,
+    last_exported_at TIMESTAMPTZ,
+    last_error TEXT,
+    created_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_audit_export_sinks_sink_type
+        CHECK (sink_type IN ('ndjson_file', 's3', 'syslog', 'https_webhook')),
+    CONSTRAINT chk_audit_export_sinks_checkpoint
+        CHECK (checkpoint_position >= 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_export_sinks_tenant
+    ON audit_export_sinks (tenant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS idx_audit_export_sinks_claimable
+    ON audit_export_sinks (lease_until NULLS FIRST)
+    WHERE is_enabled;
+
+ALTER TABLE audit_export_sinks ENABLE ROW LEVEL SECURITY;
+ALTER TABLE audit_export_sinks FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON audit_export_sinks;
+CREATE POLICY qryvanta_tenant_isolation ON audit_export_sinks
+    USING (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('audit_export')
+    )
+    WITH CHECK (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('audit_export')
+    );
@@ -0,0 +1,45 @@
+-- Tenant announcement banners and the per-user dismissals that hide them.
+CREATE TABLE IF NOT EXISTS tenant_announcements (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL,
+    severity TEXT NOT NULL,
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ,
+    audience_roles TEXT[] NOT NULL DEFAULT '{}',
+    audience_apps TEXT[] NOT NULL DEFAULT '{}',
+    is_dismissible BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_tenant_announcements_severity
+        CHECK (severity IN ('info', 'warning', 'critical')),
+    CONSTRAINT chk_tenant_announcements_schedule
+        CHECK (ends_at IS NULL OR ends_at > starts_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_tenant_announcements_schedule
+    ON tenant_announcements (tenant_id, starts_at DESC);
+
+CREATE TABLE IF NOT EXISTS tenant_announcement_dismissals (
+    announcement_id UUID NOT NULL REFERENCES tenant_announcements(id) ON DELETE CASCADE,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    subject TEXT NOT NULL,
+    dismissed_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (announcement_id, subject)
+);
+
+ALTER TABLE tenant_announcements ENABLE ROW LEVEL SECURITY;
+ALTER TABLE tenant_announcements FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON tenant_announcements;
+CREATE POLICY qryvanta_tenant_isolation ON tenant_announcements
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE tenant_announcement_dismissals ENABLE ROW LEVEL SECURITY;
+ALTER TABLE tenant_announcement_dismissals FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON tenant_announcement_dismissals;
+CREATE POLICY qryvanta_tenant_isolation ON tenant_announcement_dismissals
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
@@ -0,0 +1,22 @@
+CREATE TABLE IF NOT EXISTS audit_chain_anchors (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    chain_position BIGINT NOT NULL,
+    entry_hash TEXT NOT NULL,
+    reason TEXT NOT NULL CHECK (reason IN ('periodic', 'purge')),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, chain_position)
+);
+
+-- Seed periodic anchors for chains that already crossed an interval boundary.
+INSERT INTO audit_chain_anchors (tenant_id, chain_position, entry_hash, reason)
+SELECT tenant_id, chain_position, entry_hash, 'periodic'
+FROM audit_log_entries
+WHERE chain_position % 1000 = 0
+ON CONFLICT (tenant_id, chain_position) DO NOTHING;
+
+ALTER TABLE audit_chain_anchors ENABLE ROW LEVEL SECURITY;
+ALTER TABLE audit_chain_anchors FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON audit_chain_anchors;
+CREATE POLICY qryvanta_tenant_isolation ON audit_chain_anchors
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
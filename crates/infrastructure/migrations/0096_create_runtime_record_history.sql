@@ -0,0 +1,43 @@
+CREATE TABLE IF NOT EXISTS entity_history_policies (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    retention_days INTEGER NOT NULL,
+    updated_by_subject TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, entity_logical_name),
+    CONSTRAINT chk_entity_history_policies_retention_days
+        CHECK (retention_days > 0)
+);
+
+CREATE TABLE IF NOT EXISTS runtime_record_field_history (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    record_id TEXT NOT NULL,
+    record_version BIGINT NOT NULL,
+    changes JSONB NOT NULL,
+    changed_by_subject TEXT NOT NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_runtime_record_field_history_changes_json_array
+        CHECK (jsonb_typeof(changes) = 'array')
+);
+
+CREATE INDEX IF NOT EXISTS idx_runtime_record_field_history_record
+    ON runtime_record_field_history (tenant_id, entity_logical_name, record_id, changed_at);
+
+CREATE INDEX IF NOT EXISTS idx_runtime_record_field_history_entity_changed_at
+    ON runtime_record_field_history (tenant_id, entity_logical_name, changed_at);
+
+ALTER TABLE entity_history_policies ENABLE ROW LEVEL SECURITY;
+ALTER TABLE entity_history_policies FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON entity_history_policies;
+CREATE POLICY qryvanta_tenant_isolation ON entity_history_policies
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE runtime_record_field_history ENABLE ROW LEVEL SECURITY;
+ALTER TABLE runtime_record_field_history FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON runtime_record_field_history;
+CREATE POLICY qryvanta_tenant_isolation ON runtime_record_field_history
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
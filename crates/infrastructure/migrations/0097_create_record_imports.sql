@@ -0,0 +1,75 @@
+CREATE TABLE IF NOT EXISTS record_import_templates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    name TEXT NOT NULL,
+    column_mappings JSONB NOT NULL,
+    updated_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT uq_record_import_templates_name
+        UNIQUE (tenant_id, entity_logical_name, name),
+    CONSTRAINT chk_record_import_templates_column_mappings
+        CHECK (jsonb_typeof(column_mappings) = 'array')
+);
+
+ALTER TABLE record_import_templates ENABLE ROW LEVEL SECURITY;
+ALTER TABLE record_import_templates FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON record_import_templates;
+CREATE POLICY qryvanta_tenant_isolation ON record_import_templates
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+CREATE TABLE IF NOT EXISTS record_import_schedules (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    entity_logical_name TEXT NOT NULL,
+    template_id UUID REFERENCES record_import_templates(id) ON DELETE RESTRICT,
+    source_kind TEXT NOT NULL,
+    source_url TEXT NOT NULL,
+    auth_header_name TEXT,
+    auth_secret_ref TEXT,
+    frequency TEXT NOT NULL,
+    is_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    owner_subject TEXT NOT NULL,
+    owner_display_name TEXT NOT NULL,
+    owner_email TEXT NOT NULL,
+    next_run_at TIMESTAMPTZ NOT NULL,
+    last_run_at TIMESTAMPTZ,
+    last_run_status TEXT,
+    last_file_sha256 TEXT,
+    last_imported_count INTEGER NOT NULL DEFAULT 0,
+    last_failed_count INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT chk_record_import_schedules_source_kind
+        CHECK (source_kind IN ('https', 'sftp')),
+    CONSTRAINT chk_record_import_schedules_frequency
+        CHECK (frequency IN ('hourly', 'daily', 'weekly')),
+    CONSTRAINT chk_record_import_schedules_last_run_status
+        CHECK (last_run_status IS NULL OR last_run_status IN ('imported', 'unchanged', 'failed')),
+    CONSTRAINT chk_record_import_schedules_counts
+        CHECK (last_imported_count >= 0 AND last_failed_count >= 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_record_import_schedules_tenant
+    ON record_import_schedules (tenant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS idx_record_import_schedules_due
+    ON record_import_schedules (next_run_at)
+    WHERE is_enabled;
+
+ALTER TABLE record_import_schedules ENABLE ROW LEVEL SECURITY;
+ALTER TABLE record_import_schedules FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON record_import_schedules;
+CREATE POLICY qryvanta_tenant_isolation ON record_import_schedules
+    USING (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('record_import_schedules')
+    )
+    WITH CHECK (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('record_import_schedules')
+    );
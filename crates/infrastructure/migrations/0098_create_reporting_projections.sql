@@ -0,0 +1,114 @@
+CREATE TABLE IF NOT EXISTS reporting_projections (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    logical_name TEXT NOT NULL,
+    display_name TEXT NOT NULL,
+    root_entity_logical_name TEXT NOT NULL,
+    columns JSONB NOT NULL,
+    source_entity_logical_names TEXT[] NOT NULL,
+    updated_by_subject TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT uq_reporting_projections_logical_name UNIQUE (tenant_id, logical_name),
+    CONSTRAINT chk_reporting_projections_columns CHECK (jsonb_typeof(columns) = 'array')
+);
+
+CREATE INDEX IF NOT EXISTS idx_reporting_projections_sources
+    ON reporting_projections USING GIN (source_entity_logical_names);
+
+ALTER TABLE reporting_projections ENABLE ROW LEVEL SECURITY;
+ALTER TABLE reporting_projections FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON reporting_projections;
+CREATE POLICY qryvanta_tenant_isolation ON reporting_projections
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+CREATE TABLE IF NOT EXISTS reporting_projection_rows (
+    projection_id UUID NOT NULL REFERENCES reporting_projections(id) ON DELETE CASCADE,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    record_id TEXT NOT NULL,
+    parent_record_ids TEXT[] NOT NULL DEFAULT '{}',
+    data JSONB NOT NULL,
+    refreshed_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (projection_id, record_id),
+    CONSTRAINT chk_reporting_projection_rows_data CHECK (jsonb_typeof(data) = 'object')
+);
+
+CREATE INDEX IF NOT EXISTS idx_reporting_projection_rows_data
+    ON reporting_projection_rows USING GIN (data jsonb_path_ops);
+
+CREATE INDEX IF NOT EXISTS idx_reporting_projection_rows_parents
+    ON reporting_projection_rows USING GIN (parent_record_ids);
+
+ALTER TABLE reporting_projection_rows ENABLE ROW LEVEL SECURITY;
+ALTER TABLE reporting_projection_rows FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON reporting_projection_rows;
+CREATE POLICY qryvanta_tenant_isolation ON reporting_projection_rows
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+-- Change feed of runtime record writes, consumed by the reporting projector.
+-- Only writes to entities that feed a projection of the same tenant are captured.
+CREATE TABLE IF NOT EXISTS runtime_record_changes (
+    id BIGSERIAL PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    record_id TEXT NOT NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    leased_until TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_runtime_record_changes_pending
+    ON runtime_record_changes (id, leased_until);
+
+ALTER TABLE runtime_record_changes ENABLE ROW LEVEL SECURITY;
+ALTER TABLE runtime_record_changes FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON runtime_record_changes;
+CREATE POLICY qryvanta_tenant_isolation ON runtime_record_changes
+    USING (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('runtime_record_changes')
+    )
+    WITH CHECK (
+        tenant_id = qryvanta_current_tenant_id()
+        OR qryvanta_rls_scope('runtime_record_changes')
+    );
+
+-- Runtime record writes already run in the record tenant's context, so the
+-- projection lookup and the feed insert pass the tenant policies above.
+CREATE OR REPLACE FUNCTION qryvanta_capture_runtime_record_change()
+RETURNS TRIGGER
+LANGUAGE plpgsql
+AS $$
+DECLARE
+    changed_record runtime_records%ROWTYPE;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        changed_record := OLD;
+    ELSE
+        changed_record := NEW;
+    END IF;
+
+    IF EXISTS (
+        SELECT 1
+        FROM reporting_projections
+        WHERE tenant_id = changed_record.tenant_id
+          AND changed_record.entity_logical_name = ANY (source_entity_logical_names)
+    ) THEN
+        INSERT INTO runtime_record_changes (tenant_id, entity_logical_name, record_id)
+        VALUES (
+            changed_record.tenant_id,
+            changed_record.entity_logical_name,
+            changed_record.id::text
+        );
+    END IF;
+
+    RETURN NULL;
+END;
+$$;
+
+DROP TRIGGER IF EXISTS trg_runtime_records_capture_change ON runtime_records;
+CREATE TRIGGER trg_runtime_records_capture_change
+    AFTER INSERT OR DELETE OR UPDATE OF data ON runtime_records
+    FOR EACH ROW
+    EXECUTE FUNCTION qryvanta_capture_runtime_record_change();
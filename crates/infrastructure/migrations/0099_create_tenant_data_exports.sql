@@ -0,0 +1,53 @@
+ALTER TABLE background_jobs DROP CONSTRAINT IF EXISTS chk_background_jobs_kind;
+ALTER TABLE background_jobs ADD CONSTRAINT chk_background_jobs_kind
+    CHECK (
+        kind IN (
+            'audit_log_purge',
+            'qrywell_sync',
+            'personal_data_export',
+            'data_validation_audit',
+            'tenant_data_export'
+        )
+    );
+
+CREATE TABLE IF NOT EXISTS tenant_data_export_parts (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    job_id UUID NOT NULL REFERENCES background_jobs(id) ON DELETE CASCADE,
+    section TEXT NOT NULL,
+    entity_logical_name TEXT NOT NULL DEFAULT '',
+    item_offset BIGINT NOT NULL,
+    items JSONB NOT NULL DEFAULT '[]'::jsonb,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, job_id, section, entity_logical_name, item_offset),
+    CONSTRAINT chk_tenant_data_export_parts_section
+        CHECK (section IN ('metadata', 'users', 'runtime_records', 'audit_log'))
+);
+
+CREATE TABLE IF NOT EXISTS tenant_data_export_links (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    job_id UUID NOT NULL REFERENCES background_jobs(id) ON DELETE CASCADE,
+    token_hash TEXT NOT NULL,
+    created_by_subject TEXT NOT NULL,
+    generated_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (tenant_id, token_hash)
+);
+
+CREATE INDEX IF NOT EXISTS idx_tenant_data_export_links_job
+    ON tenant_data_export_links (tenant_id, job_id);
+
+ALTER TABLE tenant_data_export_parts ENABLE ROW LEVEL SECURITY;
+ALTER TABLE tenant_data_export_parts FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON tenant_data_export_parts;
+CREATE POLICY qryvanta_tenant_isolation ON tenant_data_export_parts
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE tenant_data_export_links ENABLE ROW LEVEL SECURITY;
+ALTER TABLE tenant_data_export_links FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON tenant_data_export_links;
+CREATE POLICY qryvanta_tenant_isolation ON tenant_data_export_links
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
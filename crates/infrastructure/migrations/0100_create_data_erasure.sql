@@ -0,0 +1,34 @@
+CREATE TABLE IF NOT EXISTS data_erasure_fields (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    entity_logical_name TEXT NOT NULL,
+    field_logical_name TEXT NOT NULL,
+    updated_by_subject TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, entity_logical_name, field_logical_name)
+);
+
+-- Erasure pseudonymizes the actor of audit entries, which changes their
+-- payload hash. A redaction records the hash of the rewritten entry so
+-- verification can tell an erasure from tampering; the stored entry hash
+-- and the chain links stay unchanged.
+CREATE TABLE IF NOT EXISTS audit_entry_redactions (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    chain_position BIGINT NOT NULL,
+    redacted_entry_hash TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, chain_position)
+);
+
+ALTER TABLE data_erasure_fields ENABLE ROW LEVEL SECURITY;
+ALTER TABLE data_erasure_fields FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON data_erasure_fields;
+CREATE POLICY qryvanta_tenant_isolation ON data_erasure_fields
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE audit_entry_redactions ENABLE ROW LEVEL SECURITY;
+ALTER TABLE audit_entry_redactions FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON audit_entry_redactions;
+CREATE POLICY qryvanta_tenant_isolation ON audit_entry_redactions
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
@@ -0,0 +1,20 @@
+ALTER TABLE entity_fields
+    ADD COLUMN IF NOT EXISTS classification TEXT
+        CHECK (classification IN ('pii', 'phi', 'confidential'));
+
+CREATE TABLE IF NOT EXISTS field_masking_rules (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    role_id UUID NOT NULL REFERENCES rbac_roles(id) ON DELETE CASCADE,
+    classification TEXT NOT NULL CHECK (classification IN ('pii', 'phi', 'confidential')),
+    masking_mode TEXT NOT NULL CHECK (masking_mode IN ('full', 'partial', 'hidden')),
+    updated_by_subject TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, role_id, classification)
+);
+
+ALTER TABLE field_masking_rules ENABLE ROW LEVEL SECURITY;
+ALTER TABLE field_masking_rules FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON field_masking_rules;
+CREATE POLICY qryvanta_tenant_isolation ON field_masking_rules
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
@@ -0,0 +1,33 @@
+CREATE TABLE IF NOT EXISTS feature_flags (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    flag_key TEXT NOT NULL CHECK (flag_key ~ '^[a-z0-9_.-]{1,64}$'),
+    description TEXT,
+    enabled BOOLEAN NOT NULL,
+    updated_by_subject TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (tenant_id, flag_key)
+);
+
+CREATE TABLE IF NOT EXISTS feature_flag_role_overrides (
+    tenant_id UUID NOT NULL,
+    flag_key TEXT NOT NULL,
+    role_id UUID NOT NULL REFERENCES rbac_roles(id) ON DELETE CASCADE,
+    enabled BOOLEAN NOT NULL,
+    PRIMARY KEY (tenant_id, flag_key, role_id),
+    FOREIGN KEY (tenant_id, flag_key)
+        REFERENCES feature_flags(tenant_id, flag_key) ON DELETE CASCADE
+);
+
+ALTER TABLE feature_flags ENABLE ROW LEVEL SECURITY;
+ALTER TABLE feature_flags FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON feature_flags;
+CREATE POLICY qryvanta_tenant_isolation ON feature_flags
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
+
+ALTER TABLE feature_flag_role_overrides ENABLE ROW LEVEL SECURITY;
+ALTER TABLE feature_flag_role_overrides FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS qryvanta_tenant_isolation ON feature_flag_role_overrides;
+CREATE POLICY qryvanta_tenant_isolation ON feature_flag_role_overrides
+    USING (tenant_id = qryvanta_current_tenant_id())
+    WITH CHECK (tenant_id = qryvanta_current_tenant_id());
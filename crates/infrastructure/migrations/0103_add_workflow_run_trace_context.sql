@@ -0,0 +1,2 @@
+ALTER TABLE workflow_execution_runs
+    ADD COLUMN IF NOT EXISTS trace_context TEXT;
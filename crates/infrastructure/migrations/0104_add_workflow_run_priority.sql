@@ -0,0 +1,44 @@
+ALTER TABLE workflow_definitions
+    ADD COLUMN IF NOT EXISTS priority SMALLINT NOT NULL DEFAULT 1;
+
+ALTER TABLE workflow_definitions
+    DROP CONSTRAINT IF EXISTS chk_workflow_definitions_priority;
+
+ALTER TABLE workflow_definitions
+    ADD CONSTRAINT chk_workflow_definitions_priority
+        CHECK (priority BETWEEN 0 AND 3);
+
+ALTER TABLE workflow_published_versions
+    ADD COLUMN IF NOT EXISTS priority SMALLINT NOT NULL DEFAULT 1;
+
+ALTER TABLE workflow_published_versions
+    DROP CONSTRAINT IF EXISTS chk_workflow_published_versions_priority;
+
+ALTER TABLE workflow_published_versions
+    ADD CONSTRAINT chk_workflow_published_versions_priority
+        CHECK (priority BETWEEN 0 AND 3);
+
+ALTER TABLE workflow_execution_runs
+    ADD COLUMN IF NOT EXISTS priority SMALLINT NOT NULL DEFAULT 1;
+
+ALTER TABLE workflow_execution_runs
+    DROP CONSTRAINT IF EXISTS chk_workflow_execution_runs_priority;
+
+ALTER TABLE workflow_execution_runs
+    ADD CONSTRAINT chk_workflow_execution_runs_priority
+        CHECK (priority BETWEEN 0 AND 3);
+
+ALTER TABLE workflow_execution_jobs
+    ADD COLUMN IF NOT EXISTS priority SMALLINT NOT NULL DEFAULT 1;
+
+ALTER TABLE workflow_execution_jobs
+    DROP CONSTRAINT IF EXISTS chk_workflow_execution_jobs_priority;
+
+ALTER TABLE workflow_execution_jobs
+    ADD CONSTRAINT chk_workflow_execution_jobs_priority
+        CHECK (priority BETWEEN 0 AND 3);
+
+DROP INDEX IF EXISTS idx_workflow_execution_jobs_claim;
+
+CREATE INDEX IF NOT EXISTS idx_workflow_execution_jobs_claim
+    ON workflow_execution_jobs (status, priority DESC, created_at, available_at, lease_expires_at);
@@ -0,0 +1,13 @@
+ALTER TABLE tenants
+    ADD COLUMN IF NOT EXISTS workflow_max_concurrent_jobs INTEGER;
+
+ALTER TABLE tenants
+    DROP CONSTRAINT IF EXISTS chk_tenants_workflow_max_concurrent_jobs;
+
+ALTER TABLE tenants
+    ADD CONSTRAINT chk_tenants_workflow_max_concurrent_jobs
+        CHECK (workflow_max_concurrent_jobs IS NULL OR workflow_max_concurrent_jobs > 0);
+
+CREATE INDEX IF NOT EXISTS idx_workflow_execution_jobs_tenant_leased
+    ON workflow_execution_jobs (tenant_id)
+    WHERE status = 'leased';
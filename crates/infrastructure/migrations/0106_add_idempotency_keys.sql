@@ -0,0 +1,13 @@
+ALTER TABLE workflow_execution_runs
+    ADD COLUMN IF NOT EXISTS idempotency_key TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_workflow_execution_runs_idempotency_key
+    ON workflow_execution_runs (tenant_id, workflow_logical_name, idempotency_key)
+    WHERE idempotency_key IS NOT NULL;
+
+ALTER TABLE runtime_records
+    ADD COLUMN IF NOT EXISTS idempotency_key TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_runtime_records_idempotency_key
+    ON runtime_records (tenant_id, entity_logical_name, idempotency_key)
+    WHERE idempotency_key IS NOT NULL;
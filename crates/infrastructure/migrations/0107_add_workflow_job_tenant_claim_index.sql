@@ -0,0 +1,4 @@
+-- Serves the per-tenant candidate scan of fair-share job claims.
+CREATE INDEX IF NOT EXISTS idx_workflow_execution_jobs_tenant_claim
+    ON workflow_execution_jobs (tenant_id, priority DESC, created_at)
+    WHERE status IN ('pending', 'leased');
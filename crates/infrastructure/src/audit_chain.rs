@@ -4,6 +4,9 @@ use sha2::{Digest, Sha256};
 
 use qryvanta_core::TenantId;
 
+/// Number of chain positions between periodic audit chain anchors.
+pub(crate) const AUDIT_CHAIN_ANCHOR_INTERVAL: i64 = 1_000;
+
 /// Stable chain payload used for audit-log tamper evidence.
 pub(crate) struct AuditChainInput<'a> {
     pub(crate) tenant_id: TenantId,
@@ -0,0 +1,455 @@
+//! Delivery of exported audit batches to NDJSON files, S3, syslog and webhooks.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::net::{TcpStream, ToSocketAddrs, UdpSocket};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use qryvanta_application::{
+    AuditExportShipper, AuditExportSink, AuditExportSinkConfig, AuditExportSyslogTransport,
+    AuditLogEntry,
+};
+use qryvanta_core::{AppError, AppResult, TenantId, resolve_secret_reference};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+/// Timeout applied to syslog connects and writes.
+const SYSLOG_IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// RFC 5424 priority for facility 13 (log audit) at severity 6 (informational).
+const SYSLOG_PRIORITY: u8 = 13 * 8 + 6;
+
+/// Ships audit export batches to the sink type configured for each sink.
+///
+/// Every sink receives the same NDJSON line per entry, carrying the tenant,
+/// event identifier and chain hashes so consumers can deduplicate
+/// redelivered batches and verify chain continuity.
+pub struct SinkAuditExportShipper {
+    http_client: reqwest::Client,
+    file_root: Option<PathBuf>,
+}
+
+impl SinkAuditExportShipper {
+    /// Creates a shipper; `file_root` enables NDJSON file sinks beneath it.
+    #[must_use]
+    pub fn new(file_root: Option<PathBuf>) -> Self {
+        let http_client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(Duration::from_secs(30))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            http_client,
+            file_root,
+        }
+    }
+
+    async fn ship_to_file(
+        &self,
+        tenant_id: TenantId,
+        sink: &AuditExportSink,
+        body: String,
+    ) -> AppResult<()> {
+        let Some(file_root) = &self.file_root else {
+            return Err(AppError::Validation(
+                "ndjson_file audit export sinks require AUDIT_EXPORT_FILE_ROOT".to_owned(),
+            ));
+        };
+
+        let directory = file_root
+            .join(tenant_id.to_string())
+            .join(sink.sink_id.as_str());
+        let path = directory.join(format!("{}.ndjson", chrono::Utc::now().format("%Y-%m-%d")));
+        run_blocking("append audit export file", move || {
+            fs::create_dir_all(&directory).map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to create audit export directory '{}': {error}",
+                    directory.display()
+                ))
+            })?;
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .and_then(|mut file| file.write_all(body.as_bytes()))
+                .map_err(|error| {
+                    AppError::Internal(format!(
+                        "failed to append audit export file '{}': {error}",
+                        path.display()
+                    ))
+                })
+        })
+        .await
+    }
+
+    async fn ship_to_s3(
+        &self,
+        tenant_id: TenantId,
+        sink: &AuditExportSink,
+        bucket: &str,
+        prefix: Option<&str>,
+        entries: &[AuditLogEntry],
+        body: String,
+    ) -> AppResult<()> {
+        let object_key = s3_object_key(tenant_id, sink, prefix, entries);
+        let destination = format!("s3://{bucket}/{object_key}");
+        run_blocking("upload audit export object", move || {
+            let path =
+                std::env::temp_dir().join(format!("qryvanta-audit-export-{}", Uuid::new_v4()));
+            fs::write(&path, body.as_bytes()).map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to write temporary audit export file '{}': {error}",
+                    path.display()
+                ))
+            })?;
+
+            let output = Command::new("aws")
+                .args(["s3", "cp"])
+                .arg(&path)
+                .arg(destination.as_str())
+                .args(["--content-type", "application/x-ndjson"])
+                .output();
+            let _ = fs::remove_file(&path);
+
+            let output = output.map_err(|error| {
+                AppError::Internal(format!("failed to execute aws s3 cp: {error}"))
+            })?;
+            if !output.status.success() {
+                return Err(AppError::Internal(format!(
+                    "aws s3 cp to '{destination}' failed with status {}: {}",
+                    output.status,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )));
+            }
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn ship_to_syslog(
+        &self,
+        tenant_id: TenantId,
+        host: &str,
+        port: u16,
+        transport: AuditExportSyslogTransport,
+        entries: &[AuditLogEntry],
+    ) -> AppResult<()> {
+        let address = format!("{host}:{port}");
+        let messages = entries
+            .iter()
+            .map(|entry| syslog_message(tenant_id, entry))
+            .collect::<Vec<_>>();
+
+        run_blocking("send audit export syslog messages", move || {
+            let socket_address = address
+                .to_socket_addrs()
+                .ok()
+                .and_then(|mut addresses| addresses.next())
+                .ok_or_else(|| {
+                    AppError::Internal(format!("failed to resolve syslog host '{address}'"))
+                })?;
+            let send_error = |error: std::io::Error| {
+                AppError::Internal(format!("syslog send to '{address}' failed: {error}"))
+            };
+
+            match transport {
+                AuditExportSyslogTransport::Udp => {
+                    let bind_address = if socket_address.is_ipv4() {
+                        "0.0.0.0:0"
+                    } else {
+                        "[::]:0"
+                    };
+                    let socket = UdpSocket::bind(bind_address).map_err(send_error)?;
+                    socket
+                        .set_write_timeout(Some(SYSLOG_IO_TIMEOUT))
+                        .map_err(send_error)?;
+                    for message in &messages {
+                        socket
+                            .send_to(message.as_bytes(), socket_address)
+                            .map_err(send_error)?;
+                    }
+                }
+                AuditExportSyslogTransport::Tcp => {
+                    let mut stream = TcpStream::connect_timeout(&socket_address, SYSLOG_IO_TIMEOUT)
+                        .map_err(send_error)?;
+                    stream
+                        .set_write_timeout(Some(SYSLOG_IO_TIMEOUT))
+                        .map_err(send_error)?;
+                    // RFC 6587 octet counting keeps multi-line JSON intact.
+                    for message in &messages {
+                        stream
+                            .write_all(format!("{} {message}", message.len()).as_bytes())
+                            .map_err(send_error)?;
+                    }
+                    stream.flush().map_err(send_error)?;
+                }
+            }
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn ship_to_webhook(
+        &self,
+        url: &str,
+        auth_header_name: Option<&str>,
+        auth_secret_ref: Option<&str>,
+        body: String,
+    ) -> AppResult<()> {
+        let mut request = self
+            .http_client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/x-ndjson")
+            .body(body);
+
+        if let (Some(header_name), Some(secret_ref)) = (auth_header_name, auth_secret_ref) {
+            let secret_ref = secret_ref.to_owned();
+            let secret = run_blocking("resolve audit export webhook secret", move || {
+                resolve_secret_reference(secret_ref.as_str())
+            })
+            .await?;
+            request = request.header(header_name, secret);
+        }
+
+        let response = request.send().await.map_err(|error| {
+            AppError::Internal(format!("audit export webhook request failed: {error}"))
+        })?;
+        if !response.status().is_success() {
+            return Err(AppError::Internal(format!(
+                "audit export webhook responded with status {}",
+                response.status()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AuditExportShipper for SinkAuditExportShipper {
+    async fn ship(
+        &self,
+        tenant_id: TenantId,
+        sink: &AuditExportSink,
+        entries: &[AuditLogEntry],
+    ) -> AppResult<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        match &sink.config {
+            AuditExportSinkConfig::NdjsonFile => {
+                self.ship_to_file(tenant_id, sink, ndjson_body(tenant_id, entries))
+                    .await
+            }
+            AuditExportSinkConfig::S3 { bucket, prefix } => {
+                self.ship_to_s3(
+                    tenant_id,
+                    sink,
+                    bucket.as_str(),
+                    prefix.as_deref(),
+                    entries,
+                    ndjson_body(tenant_id, entries),
+                )
+                .await
+            }
+            AuditExportSinkConfig::Syslog {
+                host,
+                port,
+                transport,
+            } => {
+                self.ship_to_syslog(tenant_id, host.as_str(), *port, *transport, entries)
+                    .await
+            }
+            AuditExportSinkConfig::HttpsWebhook {
+                url,
+                auth_header_name,
+                auth_secret_ref,
+            } => {
+                self.ship_to_webhook(
+                    url.as_str(),
+                    auth_header_name.as_deref(),
+                    auth_secret_ref.as_deref(),
+                    ndjson_body(tenant_id, entries),
+                )
+                .await
+            }
+        }
+    }
+}
+
+fn export_record(tenant_id: TenantId, entry: &AuditLogEntry) -> Value {
+    let detail = entry.detail.as_deref().map(|detail| {
+        serde_json::from_str::<Value>(detail).unwrap_or_else(|_| Value::from(detail))
+    });
+
+    json!({
+        "tenant_id": tenant_id.to_string(),
+        "event_id": entry.event_id,
+        "chain_position": entry.chain_position,
+        "previous_entry_hash": entry.previous_entry_hash,
+        "entry_hash": entry.entry_hash,
+        "created_at": entry.created_at,
+        "subject": entry.subject,
+        "action": entry.action,
+        "resource_type": entry.resource_type,
+        "resource_id": entry.resource_id,
+        "detail": detail,
+    })
+}
+
+fn ndjson_body(tenant_id: TenantId, entries: &[AuditLogEntry]) -> String {
+    entries
+        .iter()
+        .map(|entry| format!("{}\n", export_record(tenant_id, entry)))
+        .collect()
+}
+
+fn syslog_message(tenant_id: TenantId, entry: &AuditLogEntry) -> String {
+    format!(
+        "<{SYSLOG_PRIORITY}>1 {} qryvanta qryvanta-audit - {} - {}",
+        entry.created_at,
+        entry.action,
+        export_record(tenant_id, entry)
+    )
+}
+
+fn s3_object_key(
+    tenant_id: TenantId,
+    sink: &AuditExportSink,
+    prefix: Option<&str>,
+    entries: &[AuditLogEntry],
+) -> String {
+    let first = entries.first().map_or(0, |entry| entry.chain_position);
+    let last = entries.last().map_or(0, |entry| entry.chain_position);
+    let key = format!("{tenant_id}/{}/{first:020}-{last:020}.ndjson", sink.sink_id);
+
+    match prefix {
+        Some(prefix) => format!("{prefix}/{key}"),
+        None => key,
+    }
+}
+
+async fn run_blocking<T, F>(action: &str, task: F) -> AppResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> AppResult<T> + Send + 'static,
+{
+    tokio::task::spawn_blocking(task)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to {action}: {error}")))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(chain_position: i64, detail: Option<&str>) -> AuditLogEntry {
+        AuditLogEntry {
+            event_id: format!("event-{chain_position}"),
+            subject: "alice".to_owned(),
+            action: "metadata.entity.created".to_owned(),
+            resource_type: "entity".to_owned(),
+            resource_id: "contact".to_owned(),
+            detail: detail.map(ToOwned::to_owned),
+            created_at: "2026-01-02T03:04:05.000Z".to_owned(),
+            chain_position,
+            previous_entry_hash: None,
+            entry_hash: format!("hash-{chain_position}"),
+        }
+    }
+
+    fn sink(sink_id: &str) -> AuditExportSink {
+        AuditExportSink {
+            sink_id: sink_id.to_owned(),
+            name: "Collector".to_owned(),
+            config: AuditExportSinkConfig::NdjsonFile,
+            is_enabled: true,
+            checkpoint_position: 0,
+            last_exported_at: None,
+            last_error: None,
+            created_by_subject: "alice".to_owned(),
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn ndjson_lines_carry_tenant_chain_and_parsed_detail() {
+        let tenant_id = TenantId::new();
+        let body = ndjson_body(
+            tenant_id,
+            &[
+                entry(1, Some(r#"{"name":"contact"}"#)),
+                entry(2, Some("plain")),
+            ],
+        );
+
+        let lines = body
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap_or_else(|_| unreachable!()))
+            .collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert!(body.ends_with('\n'));
+        assert_eq!(lines[0]["tenant_id"], json!(tenant_id.to_string()));
+        assert_eq!(lines[0]["chain_position"], json!(1));
+        assert_eq!(lines[0]["detail"], json!({ "name": "contact" }));
+        assert_eq!(lines[1]["detail"], json!("plain"));
+        assert_eq!(lines[1]["entry_hash"], json!("hash-2"));
+    }
+
+    #[test]
+    fn syslog_messages_and_s3_keys_are_stable() {
+        let tenant_id = TenantId::new();
+        let message = syslog_message(tenant_id, &entry(7, None));
+        assert!(message.starts_with(
+            "<110>1 2026-01-02T03:04:05.000Z qryvanta qryvanta-audit - metadata.entity.created - {"
+        ));
+
+        let key = s3_object_key(
+            tenant_id,
+            &sink("sink-1"),
+            Some("audit"),
+            &[entry(3, None), entry(9, None)],
+        );
+        assert_eq!(
+            key,
+            format!("audit/{tenant_id}/sink-1/00000000000000000003-00000000000000000009.ndjson")
+        );
+    }
+
+    #[tokio::test]
+    async fn file_sinks_append_under_the_configured_root() {
+        let root =
+            std::env::temp_dir().join(format!("qryvanta-audit-export-test-{}", Uuid::new_v4()));
+        let tenant_id = TenantId::new();
+        let shipper = SinkAuditExportShipper::new(Some(root.clone()));
+
+        for position in [1, 2] {
+            let result = shipper
+                .ship(tenant_id, &sink("sink-1"), &[entry(position, None)])
+                .await;
+            assert!(result.is_ok());
+        }
+
+        let directory = root.join(tenant_id.to_string()).join("sink-1");
+        let files = fs::read_dir(&directory)
+            .unwrap_or_else(|_| unreachable!())
+            .filter_map(Result::ok)
+            .collect::<Vec<_>>();
+        assert_eq!(files.len(), 1);
+        let contents = fs::read_to_string(files[0].path()).unwrap_or_else(|_| unreachable!());
+        assert_eq!(contents.lines().count(), 2);
+        let _ = fs::remove_dir_all(&root);
+
+        let unconfigured = SinkAuditExportShipper::new(None)
+            .ship(tenant_id, &sink("sink-1"), &[entry(1, None)])
+            .await;
+        assert!(matches!(unconfigured, Err(AppError::Validation(_))));
+    }
+}
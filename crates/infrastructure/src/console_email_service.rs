@@ -1,7 +1,7 @@
 //! Console email service for development. Logs emails to tracing output.
 
 use async_trait::async_trait;
-use qryvanta_application::EmailService;
+use qryvanta_application::{EmailAttachment, EmailService};
 use qryvanta_core::AppResult;
 use tracing::info;
 
@@ -43,4 +43,36 @@ impl EmailService for ConsoleEmailService {
 
         Ok(())
     }
+
+    async fn send_email_with_attachments(
+        &self,
+        to: &str,
+        subject: &str,
+        text_body: &str,
+        attachments: &[EmailAttachment],
+    ) -> AppResult<()> {
+        let attachment_summary = attachments
+            .iter()
+            .map(|attachment| {
+                format!(
+                    "{} ({}, {} bytes)",
+                    attachment.filename,
+                    attachment.content_type,
+                    attachment.content.len()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        info!(
+            to = to,
+            subject = subject,
+            "--- EMAIL (console) ---\nTo: {}\nSubject: {}\nAttachments: {}\n\n{}\n--- END EMAIL ---",
+            to,
+            subject,
+            attachment_summary,
+            text_body
+        );
+
+        Ok(())
+    }
 }
@@ -1,20 +1,36 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use qryvanta_application::{
-    EmailService, WorkflowActionDispatchRequest, WorkflowActionDispatchType,
-    WorkflowActionDispatcher,
+    EmailService, WorkflowActionDispatchRequest, WorkflowActionDispatchResponse,
+    WorkflowActionDispatchType, WorkflowActionDispatcher,
 };
 use qryvanta_core::{AppError, AppResult, resolve_secret_reference};
+use qryvanta_domain::WorkflowHttpRetryPolicy;
 use serde_json::Value;
+use tokio::sync::Mutex;
+
+mod credentials;
+
+use credentials::{AccessTokenCache, ensure_credential_destination, uses_access_tokens};
+
+/// Upper bound on response body bytes captured into workflow run traces.
+const MAX_CAPTURED_RESPONSE_BYTES: usize = 64 * 1024;
 
 /// HTTP-based implementation for workflow external action dispatch.
+///
+/// Requests carrying a workflow credential go through a managed proxy path:
+/// the destination must be an allowed https host, redirects are not
+/// followed, and the credential header is injected per attempt.
 pub struct HttpWorkflowActionDispatcher {
     http_client: reqwest::Client,
+    credential_http_client: Option<reqwest::Client>,
     email_service: Arc<dyn EmailService>,
     max_attempts: u8,
     retry_backoff_ms: u64,
+    access_tokens: Mutex<AccessTokenCache>,
 }
 
 impl HttpWorkflowActionDispatcher {
@@ -26,18 +42,32 @@ impl HttpWorkflowActionDispatcher {
         max_attempts: u8,
         retry_backoff_ms: u64,
     ) -> Self {
+        let credential_http_client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .ok();
+
         Self {
             http_client,
+            credential_http_client,
             email_service,
             max_attempts: max_attempts.max(1),
             retry_backoff_ms: retry_backoff_ms.max(50),
+            access_tokens: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn default_retry_policy(&self) -> WorkflowHttpRetryPolicy {
+        WorkflowHttpRetryPolicy {
+            max_attempts: self.max_attempts,
+            backoff_ms: self.retry_backoff_ms,
         }
     }
 
     async fn dispatch_http_request(
         &self,
         request: &WorkflowActionDispatchRequest,
-    ) -> AppResult<()> {
+    ) -> AppResult<WorkflowActionDispatchResponse> {
         let payload = request.payload.as_object().ok_or_else(|| {
             AppError::Validation("http_request payload must be an object".to_owned())
         })?;
@@ -67,9 +97,44 @@ impl HttpWorkflowActionDispatcher {
             resolve_secret_reference,
         )
         .await?;
+        if let Some(credential) = &request.credential {
+            ensure_credential_destination(
+                credential,
+                url,
+                headers
+                    .keys()
+                    .chain(resolved_secret_headers.iter().map(|(key, _)| key)),
+            )?;
+        }
         let body = payload.get("body").cloned().unwrap_or(Value::Null);
+        let expected_status = payload
+            .get("expected_status")
+            .and_then(Value::as_u64)
+            .map(|status| {
+                u16::try_from(status).map_err(|_| {
+                    AppError::Validation(format!(
+                        "http_request payload has invalid expected_status {status}"
+                    ))
+                })
+            })
+            .transpose()?;
+        let retry_policy = match payload.get("retry") {
+            Some(retry) if !retry.is_null() => {
+                let retry = serde_json::from_value::<WorkflowHttpRetryPolicy>(retry.clone())
+                    .map_err(|error| {
+                        AppError::Validation(format!(
+                            "http_request payload has invalid retry policy: {error}"
+                        ))
+                    })?;
+                WorkflowHttpRetryPolicy {
+                    max_attempts: retry.max_attempts.max(1),
+                    backoff_ms: retry.backoff_ms,
+                }
+            }
+            _ => self.default_retry_policy(),
+        };
 
-        self.dispatch_with_retry(request, |client| {
+        self.dispatch_with_retry(request, retry_policy, expected_status, |client| {
             let trace_id = workflow_trace_id(request);
             let mut builder = client
                 .request(method.clone(), url)
@@ -96,7 +161,10 @@ impl HttpWorkflowActionDispatcher {
         .await
     }
 
-    async fn dispatch_webhook(&self, request: &WorkflowActionDispatchRequest) -> AppResult<()> {
+    async fn dispatch_webhook(
+        &self,
+        request: &WorkflowActionDispatchRequest,
+    ) -> AppResult<WorkflowActionDispatchResponse> {
         let payload = request
             .payload
             .as_object()
@@ -123,9 +191,18 @@ impl HttpWorkflowActionDispatcher {
             resolve_secret_reference,
         )
         .await?;
+        if let Some(credential) = &request.credential {
+            ensure_credential_destination(
+                credential,
+                endpoint,
+                headers
+                    .keys()
+                    .chain(resolved_secret_headers.iter().map(|(key, _)| key)),
+            )?;
+        }
         let event_payload = payload.get("payload").cloned().unwrap_or(Value::Null);
 
-        self.dispatch_with_retry(request, |client| {
+        self.dispatch_with_retry(request, self.default_retry_policy(), None, |client| {
             let trace_id = workflow_trace_id(request);
             let mut builder = client
                 .post(endpoint)
@@ -183,20 +260,69 @@ impl HttpWorkflowActionDispatcher {
     async fn dispatch_with_retry<F>(
         &self,
         request: &WorkflowActionDispatchRequest,
+        retry_policy: WorkflowHttpRetryPolicy,
+        expected_status: Option<u16>,
         mut build: F,
-    ) -> AppResult<()>
+    ) -> AppResult<WorkflowActionDispatchResponse>
     where
         F: FnMut(&reqwest::Client) -> reqwest::RequestBuilder,
     {
+        let credential = request.credential.as_ref();
+        let http_client = match credential {
+            Some(_) => self.credential_http_client.as_ref().ok_or_else(|| {
+                AppError::Internal("workflow credential HTTP client is unavailable".to_owned())
+            })?,
+            None => &self.http_client,
+        };
         let mut attempt = 0_u8;
         let mut last_error: Option<String> = None;
+        let mut refresh_access_token = false;
+        let mut access_token_refreshed = false;
 
-        while attempt < self.max_attempts {
+        while attempt < retry_policy.max_attempts {
             attempt = attempt.saturating_add(1);
-            let response = build(&self.http_client).send().await;
+            let mut builder = build(http_client);
+            if let Some(credential) = credential {
+                let (header_name, header_value) = self
+                    .credential_header(credential, refresh_access_token)
+                    .await?;
+                builder = builder.header(header_name, header_value);
+                refresh_access_token = false;
+            }
+            let response = builder.send().await;
 
             match response {
-                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response)
+                    if response.status() == reqwest::StatusCode::UNAUTHORIZED
+                        && !access_token_refreshed
+                        && credential.is_some_and(uses_access_tokens) =>
+                {
+                    // A revoked or early-expired token gets one free retry with a fresh token.
+                    access_token_refreshed = true;
+                    refresh_access_token = true;
+                    attempt = attempt.saturating_sub(1);
+                    last_error = Some(format!(
+                        "workflow dispatch '{}' was unauthorized with a cached access token",
+                        request.idempotency_key
+                    ));
+                    continue;
+                }
+                Ok(response)
+                    if expected_status.map_or(response.status().is_success(), |expected| {
+                        response.status().as_u16() == expected
+                    }) =>
+                {
+                    let status_code = response.status().as_u16();
+                    let body = response.bytes().await.map_err(|error| {
+                        AppError::Internal(format!(
+                            "failed to read workflow external dispatch response: {error}"
+                        ))
+                    })?;
+                    return Ok(WorkflowActionDispatchResponse {
+                        status_code,
+                        body: captured_response_body(&body),
+                    });
+                }
                 Ok(response)
                     if response.status().is_server_error()
                         || response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS =>
@@ -213,9 +339,14 @@ impl HttpWorkflowActionDispatcher {
                         .text()
                         .await
                         .unwrap_or_else(|_| "<response body unavailable>".to_owned());
-                    return Err(AppError::Validation(format!(
-                        "workflow external dispatch failed with status {status}: {body}"
-                    )));
+                    return Err(AppError::Validation(match expected_status {
+                        Some(expected) => format!(
+                            "workflow external dispatch returned status {status} but expected {expected}: {body}"
+                        ),
+                        None => format!(
+                            "workflow external dispatch failed with status {status}: {body}"
+                        ),
+                    }));
                 }
                 Err(error) => {
                     last_error = Some(format!(
@@ -224,8 +355,8 @@ impl HttpWorkflowActionDispatcher {
                 }
             }
 
-            if attempt < self.max_attempts {
-                let delay = self.retry_backoff_ms.saturating_mul(u64::from(attempt));
+            if attempt < retry_policy.max_attempts {
+                let delay = retry_policy.backoff_ms.saturating_mul(u64::from(attempt));
                 tokio::time::sleep(Duration::from_millis(delay)).await;
             }
         }
@@ -236,6 +367,22 @@ impl HttpWorkflowActionDispatcher {
     }
 }
 
+/// Parses a response body as JSON, falling back to a (truncated) string.
+fn captured_response_body(body: &[u8]) -> Value {
+    if body.is_empty() {
+        return Value::Null;
+    }
+
+    if body.len() <= MAX_CAPTURED_RESPONSE_BYTES
+        && let Ok(value) = serde_json::from_slice::<Value>(body)
+    {
+        return value;
+    }
+
+    let captured = &body[..body.len().min(MAX_CAPTURED_RESPONSE_BYTES)];
+    Value::String(String::from_utf8_lossy(captured).into_owned())
+}
+
 fn workflow_trace_id(request: &WorkflowActionDispatchRequest) -> String {
     format!("workflow-{}-{}", request.run_id, request.step_path)
 }
@@ -293,18 +440,29 @@ where
 
 #[async_trait]
 impl WorkflowActionDispatcher for HttpWorkflowActionDispatcher {
-    async fn dispatch_action(&self, request: WorkflowActionDispatchRequest) -> AppResult<()> {
+    async fn dispatch_action(
+        &self,
+        request: WorkflowActionDispatchRequest,
+    ) -> AppResult<Option<WorkflowActionDispatchResponse>> {
         match request.dispatch_type {
-            WorkflowActionDispatchType::HttpRequest => self.dispatch_http_request(&request).await,
-            WorkflowActionDispatchType::Webhook => self.dispatch_webhook(&request).await,
-            WorkflowActionDispatchType::Email => self.dispatch_email(&request).await,
+            WorkflowActionDispatchType::HttpRequest => {
+                self.dispatch_http_request(&request).await.map(Some)
+            }
+            WorkflowActionDispatchType::Webhook => self.dispatch_webhook(&request).await.map(Some),
+            WorkflowActionDispatchType::Email => {
+                self.dispatch_email(&request).await?;
+                Ok(None)
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{resolve_secret_headers, resolve_workflow_header_secret_reference};
+    use super::{
+        MAX_CAPTURED_RESPONSE_BYTES, captured_response_body, resolve_secret_headers,
+        resolve_workflow_header_secret_reference,
+    };
     use qryvanta_core::{AppError, AppResult};
     use serde_json::json;
 
@@ -370,4 +528,21 @@ mod tests {
             matches!(result, Err(AppError::Validation(message)) if message == "resolver failed")
         );
     }
+
+    #[test]
+    fn captures_json_text_and_truncated_response_bodies() {
+        assert_eq!(
+            captured_response_body(br#"{"id":"ext-1"}"#),
+            json!({"id": "ext-1"})
+        );
+        assert_eq!(captured_response_body(b"accepted"), json!("accepted"));
+        assert_eq!(captured_response_body(b""), serde_json::Value::Null);
+
+        let oversized = vec![b'a'; MAX_CAPTURED_RESPONSE_BYTES + 10];
+        let captured = captured_response_body(&oversized);
+        assert_eq!(
+            captured.as_str().map(str::len),
+            Some(MAX_CAPTURED_RESPONSE_BYTES)
+        );
+    }
 }
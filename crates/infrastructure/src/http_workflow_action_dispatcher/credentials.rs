@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use qryvanta_application::WorkflowActionCredential;
+use qryvanta_core::{AppError, AppResult, TenantId, resolve_secret_reference};
+use qryvanta_domain::WorkflowCredentialAuth;
+use serde_json::Value;
+
+use super::HttpWorkflowActionDispatcher;
+
+/// Cached tokens are refreshed this long before the endpoint-reported expiry.
+const ACCESS_TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
+/// Lifetime assumed when a token endpoint omits `expires_in`.
+const DEFAULT_ACCESS_TOKEN_LIFETIME: Duration = Duration::from_secs(300);
+
+/// OAuth2 access tokens keyed by tenant credential and its exact configuration.
+pub(super) type AccessTokenCache = HashMap<AccessTokenKey, CachedAccessToken>;
+
+/// Editing a credential changes its key, so stale tokens are never reused.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(super) struct AccessTokenKey {
+    tenant_id: TenantId,
+    logical_name: String,
+    auth: WorkflowCredentialAuth,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct CachedAccessToken {
+    access_token: String,
+    refresh_at: Instant,
+}
+
+impl HttpWorkflowActionDispatcher {
+    /// Returns the header a credential injects, fetching access tokens when needed.
+    pub(super) async fn credential_header(
+        &self,
+        credential: &WorkflowActionCredential,
+        refresh_access_token: bool,
+    ) -> AppResult<(String, String)> {
+        match credential.credential.auth() {
+            WorkflowCredentialAuth::ApiKey {
+                header_name,
+                prefix,
+                secret_ref,
+            } => {
+                let secret =
+                    resolve_credential_secret(secret_ref, resolve_secret_reference).await?;
+                Ok((
+                    header_name.clone(),
+                    match prefix {
+                        Some(prefix) => format!("{prefix} {secret}"),
+                        None => secret,
+                    },
+                ))
+            }
+            WorkflowCredentialAuth::OAuth2ClientCredentials { .. } => {
+                let access_token = self.access_token(credential, refresh_access_token).await?;
+                Ok(("authorization".to_owned(), format!("Bearer {access_token}")))
+            }
+        }
+    }
+
+    async fn access_token(
+        &self,
+        credential: &WorkflowActionCredential,
+        refresh: bool,
+    ) -> AppResult<String> {
+        let key = AccessTokenKey {
+            tenant_id: credential.tenant_id,
+            logical_name: credential.credential.logical_name().as_str().to_owned(),
+            auth: credential.credential.auth().clone(),
+        };
+
+        if !refresh
+            && let Some(cached) = self.access_tokens.lock().await.get(&key)
+            && Instant::now() < cached.refresh_at
+        {
+            return Ok(cached.access_token.clone());
+        }
+
+        let WorkflowCredentialAuth::OAuth2ClientCredentials {
+            token_url,
+            client_id,
+            client_secret_ref,
+            scope,
+        } = &key.auth
+        else {
+            return Err(AppError::Internal(format!(
+                "workflow credential '{}' does not issue access tokens",
+                key.logical_name
+            )));
+        };
+
+        let client_secret =
+            resolve_credential_secret(client_secret_ref, resolve_secret_reference).await?;
+        let mut form = vec![
+            ("grant_type", "client_credentials"),
+            ("client_id", client_id.as_str()),
+            ("client_secret", client_secret.as_str()),
+        ];
+        if let Some(scope) = scope {
+            form.push(("scope", scope.as_str()));
+        }
+
+        let http_client = self.credential_http_client.as_ref().ok_or_else(|| {
+            AppError::Internal("workflow credential HTTP client is unavailable".to_owned())
+        })?;
+        let response = http_client
+            .post(token_url.as_str())
+            .header("Accept", "application/json")
+            .form(&form)
+            .send()
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "workflow credential '{}' token request failed: {error}",
+                    key.logical_name
+                ))
+            })?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(AppError::Validation(format!(
+                "workflow credential '{}' token endpoint returned status {status}",
+                key.logical_name
+            )));
+        }
+        let body = response.bytes().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to read workflow credential '{}' token response: {error}",
+                key.logical_name
+            ))
+        })?;
+
+        let (access_token, lifetime) = parse_access_token_response(&body)?;
+        self.access_tokens.lock().await.insert(
+            key,
+            CachedAccessToken {
+                access_token: access_token.clone(),
+                refresh_at: Instant::now() + lifetime.saturating_sub(ACCESS_TOKEN_REFRESH_MARGIN),
+            },
+        );
+
+        Ok(access_token)
+    }
+}
+
+/// Returns whether the credential authenticates with refreshable access tokens.
+pub(super) fn uses_access_tokens(credential: &WorkflowActionCredential) -> bool {
+    matches!(
+        credential.credential.auth(),
+        WorkflowCredentialAuth::OAuth2ClientCredentials { .. }
+    )
+}
+
+/// Refuses destinations outside the credential allowlist and step headers
+/// that would shadow the injected credential header.
+pub(super) fn ensure_credential_destination<'a>(
+    credential: &WorkflowActionCredential,
+    url: &str,
+    mut step_header_names: impl Iterator<Item = &'a String>,
+) -> AppResult<()> {
+    let credential_name = credential.credential.logical_name().as_str();
+    let url = reqwest::Url::parse(url).map_err(|error| {
+        AppError::Validation(format!(
+            "workflow credential '{credential_name}' destination url is invalid: {error}"
+        ))
+    })?;
+
+    if url.scheme() != "https" {
+        return Err(AppError::Validation(format!(
+            "workflow credential '{credential_name}' is only sent over https"
+        )));
+    }
+
+    let host = url.host_str().unwrap_or_default();
+    if !credential.credential.allows_host(host) {
+        return Err(AppError::Validation(format!(
+            "workflow credential '{credential_name}' does not allow host '{host}'"
+        )));
+    }
+
+    let credential_header = credential.credential.auth().header_name();
+    if step_header_names.any(|name| name.eq_ignore_ascii_case(credential_header)) {
+        return Err(AppError::Validation(format!(
+            "step header '{credential_header}' conflicts with workflow credential '{credential_name}'"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Parses an OAuth2 token response into the access token and its lifetime.
+pub(super) fn parse_access_token_response(body: &[u8]) -> AppResult<(String, Duration)> {
+    let response = serde_json::from_slice::<Value>(body).map_err(|error| {
+        AppError::Validation(format!("token endpoint returned invalid JSON: {error}"))
+    })?;
+
+    let access_token = response
+        .get("access_token")
+        .and_then(Value::as_str)
+        .filter(|token| !token.trim().is_empty())
+        .ok_or_else(|| {
+            AppError::Validation("token endpoint response has no access_token".to_owned())
+        })?;
+
+    if let Some(token_type) = response.get("token_type").and_then(Value::as_str)
+        && !token_type.eq_ignore_ascii_case("bearer")
+    {
+        return Err(AppError::Validation(format!(
+            "token endpoint returned unsupported token_type '{token_type}'"
+        )));
+    }
+
+    let lifetime = response
+        .get("expires_in")
+        .and_then(Value::as_u64)
+        .map_or(DEFAULT_ACCESS_TOKEN_LIFETIME, Duration::from_secs);
+
+    Ok((access_token.to_owned(), lifetime))
+}
+
+async fn resolve_credential_secret<F>(reference: &str, resolver: F) -> AppResult<String>
+where
+    F: Fn(&str) -> AppResult<String> + Send + 'static,
+{
+    let reference = reference.to_owned();
+    tokio::task::spawn_blocking(move || resolver(reference.as_str()))
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to resolve workflow credential secret: {error}"
+            ))
+        })?
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use qryvanta_application::WorkflowActionCredential;
+    use qryvanta_core::TenantId;
+    use qryvanta_domain::{WorkflowCredential, WorkflowCredentialAuth};
+
+    use super::{
+        DEFAULT_ACCESS_TOKEN_LIFETIME, ensure_credential_destination, parse_access_token_response,
+        resolve_credential_secret,
+    };
+
+    fn credential(auth: WorkflowCredentialAuth) -> WorkflowActionCredential {
+        WorkflowActionCredential {
+            tenant_id: TenantId::new(),
+            credential: WorkflowCredential::new(
+                "erp",
+                "ERP",
+                auth,
+                vec!["api.example.com".to_owned(), "*.erp.example.com".to_owned()],
+            )
+            .unwrap_or_else(|_| unreachable!()),
+        }
+    }
+
+    fn api_key() -> WorkflowActionCredential {
+        credential(WorkflowCredentialAuth::ApiKey {
+            header_name: "x-api-key".to_owned(),
+            prefix: None,
+            secret_ref: "op://vault/erp/key".to_owned(),
+        })
+    }
+
+    #[test]
+    fn credential_destinations_must_be_allowed_https_hosts() {
+        let credential = api_key();
+        let no_headers = Vec::<String>::new();
+
+        assert!(
+            ensure_credential_destination(
+                &credential,
+                "https://api.example.com/v1/orders",
+                no_headers.iter()
+            )
+            .is_ok()
+        );
+        assert!(
+            ensure_credential_destination(
+                &credential,
+                "https://eu.erp.example.com/hooks",
+                no_headers.iter()
+            )
+            .is_ok()
+        );
+        assert!(
+            ensure_credential_destination(
+                &credential,
+                "http://api.example.com/v1/orders",
+                no_headers.iter()
+            )
+            .is_err()
+        );
+        assert!(
+            ensure_credential_destination(
+                &credential,
+                "https://attacker.test/collect",
+                no_headers.iter()
+            )
+            .is_err()
+        );
+        assert!(
+            ensure_credential_destination(&credential, "not a url", no_headers.iter()).is_err()
+        );
+    }
+
+    #[test]
+    fn credential_destinations_reject_shadowing_step_headers() {
+        let conflicting = ["X-Api-Key".to_owned()];
+        assert!(
+            ensure_credential_destination(
+                &api_key(),
+                "https://api.example.com/v1",
+                conflicting.iter()
+            )
+            .is_err()
+        );
+
+        let oauth = credential(WorkflowCredentialAuth::OAuth2ClientCredentials {
+            token_url: "https://auth.example.com/token".to_owned(),
+            client_id: "qryvanta".to_owned(),
+            client_secret_ref: "op://vault/erp/client-secret".to_owned(),
+            scope: None,
+        });
+        let authorization = ["Authorization".to_owned()];
+        assert!(
+            ensure_credential_destination(
+                &oauth,
+                "https://api.example.com/v1",
+                authorization.iter()
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn parses_access_token_responses() {
+        let (token, lifetime) = parse_access_token_response(
+            br#"{"access_token":"tok-1","token_type":"Bearer","expires_in":3600}"#,
+        )
+        .unwrap_or_else(|_| unreachable!());
+        assert_eq!(token, "tok-1");
+        assert_eq!(lifetime, Duration::from_secs(3600));
+
+        let (_, lifetime) = parse_access_token_response(br#"{"access_token":"tok-2"}"#)
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(lifetime, DEFAULT_ACCESS_TOKEN_LIFETIME);
+
+        assert!(parse_access_token_response(br#"{"token_type":"bearer"}"#).is_err());
+        assert!(
+            parse_access_token_response(br#"{"access_token":"tok-3","token_type":"mac"}"#).is_err()
+        );
+        assert!(parse_access_token_response(b"<html>").is_err());
+    }
+
+    #[tokio::test]
+    async fn resolves_credential_secrets_with_injected_resolver() {
+        let secret = resolve_credential_secret("op://vault/erp/key", |reference| {
+            Ok(format!("resolved:{reference}"))
+        })
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+        assert_eq!(secret, "resolved:op://vault/erp/key");
+    }
+}
@@ -1,18 +1,25 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use qryvanta_application::{
-    ClaimedRuntimeRecordWorkflowEvent, MetadataRepository, RecordListQuery,
-    RuntimeRecordConditionGroup, RuntimeRecordConditionNode, RuntimeRecordFilter,
-    RuntimeRecordJoinType, RuntimeRecordLogicalMode, RuntimeRecordOperator, RuntimeRecordQuery,
-    RuntimeRecordSort, RuntimeRecordSortDirection, RuntimeRecordWorkflowEventInput,
-    UniqueFieldValue,
+    AuditEvent, ClaimedRuntimeRecordWorkflowEvent, EntityHistoryPolicy, EntitySlugConfig,
+    EntityStatusConfig, MetadataRepository, NewRuntimeRecordHistoryEntry,
+    NewRuntimeRecordStatusChange, PublishedEntitySchemaVersion, RecordListQuery, RelationBehavior,
+    RelationCascadeResult, RelationDeletePlan, RelationLookupConfig, RuntimeRecordAggregateQuery,
+    RuntimeRecordAggregateRow, RuntimeRecordAssociation, RuntimeRecordChangesetMethod,
+    RuntimeRecordChangesetWrite, RuntimeRecordConditionGroup, RuntimeRecordConditionNode,
+    RuntimeRecordFilter, RuntimeRecordHistoryEntry, RuntimeRecordJoinType, RuntimeRecordLink,
+    RuntimeRecordLinkCardinality, RuntimeRecordLogicalMode, RuntimeRecordOperator,
+    RuntimeRecordQuery, RuntimeRecordSort, RuntimeRecordSortDirection, RuntimeRecordStatusChange,
+    RuntimeRecordWorkflowEventInput, UniqueFieldValue,
 };
 use qryvanta_core::TenantId;
 use qryvanta_core::{AppError, AppResult};
 use qryvanta_domain::{
-    BusinessRuleDefinition, EntityDefinition, EntityFieldDefinition, FieldType, FormDefinition,
-    OptionSetDefinition, PublishedEntitySchema, RuntimeRecord, ViewDefinition,
+    BusinessRuleDefinition, DuplicateDetectionRule, EntityDefinition, EntityFieldDefinition,
+    FieldType, FormDefinition, OptionSetDefinition, PublishedEntitySchema, RelationCascadeBehavior,
+    RuntimeRecord, ViewDefinition,
 };
 use serde_json::Value;
 use tokio::sync::RwLock;
@@ -27,13 +34,24 @@ pub struct InMemoryMetadataRepository {
     forms: RwLock<HashMap<(TenantId, String, String), FormDefinition>>,
     views: RwLock<HashMap<(TenantId, String, String), ViewDefinition>>,
     business_rules: RwLock<HashMap<(TenantId, String, String), BusinessRuleDefinition>>,
-    published_schemas: RwLock<HashMap<(TenantId, String), Vec<PublishedEntitySchema>>>,
+    published_schemas: RwLock<HashMap<(TenantId, String), Vec<PublishedEntitySchemaVersion>>>,
     published_form_snapshots: RwLock<HashMap<(TenantId, String, i32), Vec<FormDefinition>>>,
     published_view_snapshots: RwLock<HashMap<(TenantId, String, i32), Vec<ViewDefinition>>>,
     runtime_records: RwLock<HashMap<(TenantId, String, String), RuntimeRecord>>,
     record_owners: RwLock<HashMap<(TenantId, String, String), String>>,
     unique_values: RwLock<HashMap<(TenantId, String, String, String), String>>,
+    record_idempotency_keys: RwLock<HashMap<(TenantId, String, String), String>>,
+    relation_behaviors: RwLock<HashMap<(TenantId, String, String), RelationBehavior>>,
+    relation_lookups: RwLock<HashMap<(TenantId, String, String), RelationLookupConfig>>,
+    slug_configs: RwLock<HashMap<(TenantId, String), EntitySlugConfig>>,
+    status_configs: RwLock<HashMap<(TenantId, String), EntityStatusConfig>>,
+    duplicate_rules: RwLock<HashMap<(TenantId, String, String), DuplicateDetectionRule>>,
+    status_history: RwLock<Vec<(TenantId, RuntimeRecordStatusChange)>>,
+    history_policies: RwLock<HashMap<(TenantId, String), EntityHistoryPolicy>>,
+    record_history: RwLock<Vec<(TenantId, RuntimeRecordHistoryEntry)>>,
     runtime_workflow_events: RwLock<HashMap<String, InMemoryRuntimeWorkflowEvent>>,
+    record_associations: RwLock<Vec<(TenantId, RuntimeRecordAssociation)>>,
+    audit_outbox: RwLock<Vec<AuditEvent>>,
 }
 
 impl InMemoryMetadataRepository {
@@ -53,7 +71,18 @@ impl InMemoryMetadataRepository {
             runtime_records: RwLock::new(HashMap::new()),
             record_owners: RwLock::new(HashMap::new()),
             unique_values: RwLock::new(HashMap::new()),
+            record_idempotency_keys: RwLock::new(HashMap::new()),
+            relation_behaviors: RwLock::new(HashMap::new()),
+            relation_lookups: RwLock::new(HashMap::new()),
+            slug_configs: RwLock::new(HashMap::new()),
+            status_configs: RwLock::new(HashMap::new()),
+            duplicate_rules: RwLock::new(HashMap::new()),
+            status_history: RwLock::new(Vec::new()),
+            history_policies: RwLock::new(HashMap::new()),
+            record_history: RwLock::new(Vec::new()),
             runtime_workflow_events: RwLock::new(HashMap::new()),
+            record_associations: RwLock::new(Vec::new()),
+            audit_outbox: RwLock::new(Vec::new()),
         }
     }
 }
@@ -106,6 +135,10 @@ impl MetadataRepository for InMemoryMetadataRepository {
         self.update_entity_impl(tenant_id, entity).await
     }
 
+    async fn delete_entity(&self, tenant_id: TenantId, logical_name: &str) -> AppResult<()> {
+        self.delete_entity_impl(tenant_id, logical_name).await
+    }
+
     async fn save_field(&self, tenant_id: TenantId, field: EntityFieldDefinition) -> AppResult<()> {
         self.save_field_impl(tenant_id, field).await
     }
@@ -311,6 +344,46 @@ impl MetadataRepository for InMemoryMetadataRepository {
             .await
     }
 
+    async fn find_published_schema(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        self.find_published_schema_impl(tenant_id, entity_logical_name, version)
+            .await
+    }
+
+    async fn list_published_schema_versions(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<PublishedEntitySchemaVersion>> {
+        self.list_published_schema_versions_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn find_published_schema_version(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchemaVersion>> {
+        self.find_published_schema_version_impl(tenant_id, entity_logical_name, version)
+            .await
+    }
+
+    async fn rollback_entity_schema(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+        published_by: &str,
+    ) -> AppResult<PublishedEntitySchema> {
+        self.rollback_entity_schema_impl(tenant_id, entity_logical_name, version, published_by)
+            .await
+    }
+
     async fn save_published_form_snapshots(
         &self,
         tenant_id: TenantId,
@@ -369,6 +442,7 @@ impl MetadataRepository for InMemoryMetadataRepository {
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         self.create_runtime_record_impl(
             tenant_id,
@@ -377,6 +451,7 @@ impl MetadataRepository for InMemoryMetadataRepository {
             unique_values,
             created_by_subject,
             workflow_event,
+            audit_event,
         )
         .await
     }
@@ -390,6 +465,7 @@ impl MetadataRepository for InMemoryMetadataRepository {
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         self.create_runtime_record_with_id_impl(
             tenant_id,
@@ -399,6 +475,47 @@ impl MetadataRepository for InMemoryMetadataRepository {
             unique_values,
             created_by_subject,
             workflow_event,
+            audit_event,
+        )
+        .await
+    }
+
+    async fn create_runtime_record_with_idempotency_key(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        idempotency_key: &str,
+        data: Value,
+        unique_values: Vec<UniqueFieldValue>,
+        created_by_subject: &str,
+        workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+    ) -> AppResult<RuntimeRecord> {
+        self.create_runtime_record_with_idempotency_key_impl(
+            tenant_id,
+            entity_logical_name,
+            record_id,
+            idempotency_key,
+            data,
+            unique_values,
+            created_by_subject,
+            workflow_event,
+            audit_event,
+        )
+        .await
+    }
+
+    async fn find_runtime_record_by_idempotency_key(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        idempotency_key: &str,
+    ) -> AppResult<Option<RuntimeRecord>> {
+        self.find_runtime_record_by_idempotency_key_impl(
+            tenant_id,
+            entity_logical_name,
+            idempotency_key,
         )
         .await
     }
@@ -410,7 +527,9 @@ impl MetadataRepository for InMemoryMetadataRepository {
         record_id: &str,
         data: Value,
         unique_values: Vec<UniqueFieldValue>,
+        expected_version: Option<i64>,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         self.update_runtime_record_impl(
             tenant_id,
@@ -418,11 +537,23 @@ impl MetadataRepository for InMemoryMetadataRepository {
             record_id,
             data,
             unique_values,
+            expected_version,
             workflow_event,
+            audit_event,
         )
         .await
     }
 
+    async fn apply_runtime_record_changeset(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        writes: Vec<RuntimeRecordChangesetWrite>,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        self.apply_runtime_record_changeset_impl(tenant_id, created_by_subject, writes)
+            .await
+    }
+
     async fn list_runtime_records(
         &self,
         tenant_id: TenantId,
@@ -443,6 +574,16 @@ impl MetadataRepository for InMemoryMetadataRepository {
             .await
     }
 
+    async fn aggregate_runtime_records(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        query: RuntimeRecordAggregateQuery,
+    ) -> AppResult<Vec<RuntimeRecordAggregateRow>> {
+        self.aggregate_runtime_records_impl(tenant_id, entity_logical_name, query)
+            .await
+    }
+
     async fn find_runtime_record(
         &self,
         tenant_id: TenantId,
@@ -459,9 +600,18 @@ impl MetadataRepository for InMemoryMetadataRepository {
         entity_logical_name: &str,
         record_id: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+        relation_plan: &RelationDeletePlan,
     ) -> AppResult<()> {
-        self.delete_runtime_record_impl(tenant_id, entity_logical_name, record_id, workflow_event)
-            .await
+        self.delete_runtime_record_impl(
+            tenant_id,
+            entity_logical_name,
+            record_id,
+            workflow_event,
+            audit_event,
+            relation_plan,
+        )
+        .await
     }
 
     async fn claim_runtime_record_workflow_events(
@@ -540,6 +690,238 @@ impl MetadataRepository for InMemoryMetadataRepository {
         .await
     }
 
+    async fn assign_runtime_record_owner(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        owner_subject: &str,
+        cascades: &[RelationBehavior],
+    ) -> AppResult<Option<(String, Vec<RelationCascadeResult>)>> {
+        self.assign_runtime_record_owner_impl(
+            tenant_id,
+            entity_logical_name,
+            record_id,
+            owner_subject,
+            cascades,
+        )
+        .await
+    }
+
+    async fn save_relation_behavior(
+        &self,
+        tenant_id: TenantId,
+        _updated_by_subject: &str,
+        behavior: RelationBehavior,
+    ) -> AppResult<()> {
+        self.save_relation_behavior_impl(tenant_id, behavior).await
+    }
+
+    async fn list_relation_behaviors(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<RelationBehavior>> {
+        self.list_relation_behaviors_impl(tenant_id).await
+    }
+
+    async fn save_relation_lookup_config(
+        &self,
+        tenant_id: TenantId,
+        _updated_by_subject: &str,
+        config: RelationLookupConfig,
+    ) -> AppResult<()> {
+        self.save_relation_lookup_config_impl(tenant_id, config)
+            .await
+    }
+
+    async fn find_relation_lookup_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<Option<RelationLookupConfig>> {
+        self.find_relation_lookup_config_impl(tenant_id, entity_logical_name, field_logical_name)
+            .await
+    }
+
+    async fn delete_relation_lookup_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<bool> {
+        self.delete_relation_lookup_config_impl(tenant_id, entity_logical_name, field_logical_name)
+            .await
+    }
+
+    async fn save_entity_slug_config(
+        &self,
+        tenant_id: TenantId,
+        _updated_by_subject: &str,
+        config: EntitySlugConfig,
+    ) -> AppResult<()> {
+        self.save_entity_slug_config_impl(tenant_id, config).await
+    }
+
+    async fn find_entity_slug_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntitySlugConfig>> {
+        self.find_entity_slug_config_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn save_entity_status_config(
+        &self,
+        tenant_id: TenantId,
+        _updated_by_subject: &str,
+        config: EntityStatusConfig,
+    ) -> AppResult<()> {
+        self.save_entity_status_config_impl(tenant_id, config).await
+    }
+
+    async fn find_entity_status_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityStatusConfig>> {
+        self.find_entity_status_config_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn delete_entity_status_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        self.delete_entity_status_config_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn save_duplicate_detection_rule(
+        &self,
+        tenant_id: TenantId,
+        _updated_by_subject: &str,
+        rule: DuplicateDetectionRule,
+    ) -> AppResult<()> {
+        self.save_duplicate_detection_rule_impl(tenant_id, rule)
+            .await
+    }
+
+    async fn list_duplicate_detection_rules(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<DuplicateDetectionRule>> {
+        self.list_duplicate_detection_rules_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn delete_duplicate_detection_rule(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        logical_name: &str,
+    ) -> AppResult<bool> {
+        self.delete_duplicate_detection_rule_impl(tenant_id, entity_logical_name, logical_name)
+            .await
+    }
+
+    async fn append_runtime_record_status_change(
+        &self,
+        tenant_id: TenantId,
+        change: NewRuntimeRecordStatusChange,
+    ) -> AppResult<RuntimeRecordStatusChange> {
+        self.append_runtime_record_status_change_impl(tenant_id, change)
+            .await
+    }
+
+    async fn list_runtime_record_status_history(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RuntimeRecordStatusChange>> {
+        self.list_runtime_record_status_history_impl(tenant_id, entity_logical_name, record_id)
+            .await
+    }
+
+    async fn save_entity_history_policy(
+        &self,
+        tenant_id: TenantId,
+        _updated_by_subject: &str,
+        policy: EntityHistoryPolicy,
+    ) -> AppResult<()> {
+        self.save_entity_history_policy_impl(tenant_id, policy)
+            .await
+    }
+
+    async fn find_entity_history_policy(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityHistoryPolicy>> {
+        self.find_entity_history_policy_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn delete_entity_history_policy(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        self.delete_entity_history_policy_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn append_runtime_record_history_entry(
+        &self,
+        tenant_id: TenantId,
+        entry: NewRuntimeRecordHistoryEntry,
+        retention_cutoff: Option<DateTime<Utc>>,
+    ) -> AppResult<RuntimeRecordHistoryEntry> {
+        self.append_runtime_record_history_entry_impl(tenant_id, entry, retention_cutoff)
+            .await
+    }
+
+    async fn list_runtime_record_history(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<RuntimeRecordHistoryEntry>> {
+        self.list_runtime_record_history_impl(tenant_id, entity_logical_name, record_id, since)
+            .await
+    }
+
+    async fn purge_runtime_record_history(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        cutoff: DateTime<Utc>,
+    ) -> AppResult<u64> {
+        self.purge_runtime_record_history_impl(tenant_id, entity_logical_name, cutoff)
+            .await
+    }
+
+    async fn find_runtime_record_id_by_unique_value(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        field_value_hash: &str,
+    ) -> AppResult<Option<String>> {
+        self.find_runtime_record_id_by_unique_value_impl(
+            tenant_id,
+            entity_logical_name,
+            field_logical_name,
+            field_value_hash,
+        )
+        .await
+    }
+
     async fn has_relation_reference(
         &self,
         tenant_id: TenantId,
@@ -549,6 +931,41 @@ impl MetadataRepository for InMemoryMetadataRepository {
         self.has_relation_reference_impl(tenant_id, target_entity_logical_name, target_record_id)
             .await
     }
+
+    async fn associate_runtime_records(
+        &self,
+        tenant_id: TenantId,
+        associated_by_subject: &str,
+        association: RuntimeRecordAssociation,
+    ) -> AppResult<bool> {
+        self.associate_runtime_records_impl(tenant_id, associated_by_subject, association)
+            .await
+    }
+
+    async fn disassociate_runtime_records(
+        &self,
+        tenant_id: TenantId,
+        association: &RuntimeRecordAssociation,
+    ) -> AppResult<bool> {
+        self.disassociate_runtime_records_impl(tenant_id, association)
+            .await
+    }
+
+    async fn list_associated_runtime_record_ids(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<String>> {
+        self.list_associated_runtime_record_ids_impl(
+            tenant_id,
+            entity_logical_name,
+            field_logical_name,
+            record_id,
+        )
+        .await
+    }
 }
 
 #[cfg(test)]
@@ -73,6 +73,114 @@ impl InMemoryMetadataRepository {
         Ok(())
     }
 
+    pub(super) async fn delete_entity_impl(
+        &self,
+        tenant_id: TenantId,
+        logical_name: &str,
+    ) -> AppResult<()> {
+        if self
+            .entities
+            .write()
+            .await
+            .remove(&(tenant_id, logical_name.to_owned()))
+            .is_none()
+        {
+            return Err(AppError::NotFound(format!(
+                "entity '{}' does not exist for tenant '{}'",
+                logical_name, tenant_id
+            )));
+        }
+
+        let owned = |key_tenant: &TenantId, key_entity: &String| {
+            *key_tenant == tenant_id && key_entity == logical_name
+        };
+        self.fields
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.option_sets
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.forms
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.views
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.business_rules
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.published_schemas
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity), _| !owned(key_tenant, key_entity));
+        self.published_form_snapshots
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.published_view_snapshots
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.runtime_records
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.record_owners
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.unique_values
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _, _), _| !owned(key_tenant, key_entity));
+        self.relation_behaviors
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.relation_lookups
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.slug_configs
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity), _| !owned(key_tenant, key_entity));
+        self.status_configs
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity), _| !owned(key_tenant, key_entity));
+        self.duplicate_rules
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity, _), _| !owned(key_tenant, key_entity));
+        self.status_history
+            .write()
+            .await
+            .retain(|(key_tenant, change)| !owned(key_tenant, &change.entity_logical_name));
+        self.history_policies
+            .write()
+            .await
+            .retain(|(key_tenant, key_entity), _| !owned(key_tenant, key_entity));
+        self.record_history
+            .write()
+            .await
+            .retain(|(key_tenant, entry)| !owned(key_tenant, &entry.entity_logical_name));
+        self.record_associations
+            .write()
+            .await
+            .retain(|(key_tenant, association)| {
+                !owned(key_tenant, &association.entity_logical_name)
+                    && !owned(key_tenant, &association.target_entity_logical_name)
+            });
+
+        Ok(())
+    }
+
     pub(super) async fn save_field_impl(
         &self,
         tenant_id: TenantId,
@@ -162,8 +270,9 @@ impl InMemoryMetadataRepository {
             return Ok(false);
         };
 
-        Ok(versions.iter().any(|schema| {
-            schema
+        Ok(versions.iter().any(|version| {
+            version
+                .schema
                 .fields()
                 .iter()
                 .any(|field| field.logical_name().as_str() == field_logical_name)
@@ -7,7 +7,7 @@ impl InMemoryMetadataRepository {
         entity: EntityDefinition,
         fields: Vec<EntityFieldDefinition>,
         option_sets: Vec<OptionSetDefinition>,
-        _published_by: &str,
+        published_by: &str,
     ) -> AppResult<PublishedEntitySchema> {
         let mut published_schemas = self.published_schemas.write().await;
         let versions = published_schemas
@@ -16,10 +16,14 @@ impl InMemoryMetadataRepository {
 
         let version = versions
             .last()
-            .map(|schema| schema.version() + 1)
+            .map(|stored| stored.schema.version() + 1)
             .unwrap_or(1);
         let schema = PublishedEntitySchema::new(entity, version, fields, option_sets)?;
-        versions.push(schema.clone());
+        versions.push(PublishedEntitySchemaVersion {
+            schema: schema.clone(),
+            published_by_subject: published_by.to_owned(),
+            published_at: Utc::now(),
+        });
 
         Ok(schema)
     }
@@ -34,7 +38,103 @@ impl InMemoryMetadataRepository {
             .read()
             .await
             .get(&(tenant_id, entity_logical_name.to_owned()))
-            .and_then(|versions| versions.last().cloned()))
+            .and_then(|versions| versions.last().map(|stored| stored.schema.clone())))
+    }
+
+    pub(super) async fn find_published_schema_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        Ok(self
+            .find_published_schema_version_impl(tenant_id, entity_logical_name, version)
+            .await?
+            .map(|stored| stored.schema))
+    }
+
+    pub(super) async fn list_published_schema_versions_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<PublishedEntitySchemaVersion>> {
+        Ok(self
+            .published_schemas
+            .read()
+            .await
+            .get(&(tenant_id, entity_logical_name.to_owned()))
+            .map(|versions| versions.iter().rev().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    pub(super) async fn find_published_schema_version_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchemaVersion>> {
+        Ok(self
+            .published_schemas
+            .read()
+            .await
+            .get(&(tenant_id, entity_logical_name.to_owned()))
+            .and_then(|versions| {
+                versions
+                    .iter()
+                    .find(|stored| stored.schema.version() == version)
+                    .cloned()
+            }))
+    }
+
+    pub(super) async fn rollback_entity_schema_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+        published_by: &str,
+    ) -> AppResult<PublishedEntitySchema> {
+        let mut published_schemas = self.published_schemas.write().await;
+        let versions = published_schemas
+            .entry((tenant_id, entity_logical_name.to_owned()))
+            .or_default();
+
+        let target = versions
+            .iter()
+            .find(|stored| stored.schema.version() == version)
+            .map(|stored| stored.schema.clone())
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "published schema version {version} for entity '{entity_logical_name}' does not exist"
+                ))
+            })?;
+        let next_version = versions
+            .last()
+            .map(|stored| stored.schema.version() + 1)
+            .unwrap_or(1);
+        let schema = PublishedEntitySchema::new(
+            target.entity().clone(),
+            next_version,
+            target.fields().to_vec(),
+            target.option_sets().to_vec(),
+        )?;
+        versions.push(PublishedEntitySchemaVersion {
+            schema: schema.clone(),
+            published_by_subject: published_by.to_owned(),
+            published_at: Utc::now(),
+        });
+
+        let source_key = (tenant_id, entity_logical_name.to_owned(), version);
+        let target_key = (tenant_id, entity_logical_name.to_owned(), next_version);
+        let mut form_snapshots = self.published_form_snapshots.write().await;
+        if let Some(forms) = form_snapshots.get(&source_key).cloned() {
+            form_snapshots.insert(target_key.clone(), forms);
+        }
+        let mut view_snapshots = self.published_view_snapshots.write().await;
+        if let Some(views) = view_snapshots.get(&source_key).cloned() {
+            view_snapshots.insert(target_key, views);
+        }
+
+        Ok(schema)
     }
 
     pub(super) async fn save_published_form_snapshots_impl(
@@ -1,8 +1,14 @@
 use super::*;
 
+mod associations;
+mod duplicates;
+mod history;
+mod lookups;
 mod query;
 mod read;
 mod relations;
+mod slugs;
+mod status;
 mod workflow_events;
 mod write;
 
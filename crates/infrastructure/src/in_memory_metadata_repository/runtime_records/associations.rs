@@ -0,0 +1,63 @@
+use super::*;
+
+impl InMemoryMetadataRepository {
+    pub(in super::super) async fn associate_runtime_records_impl(
+        &self,
+        tenant_id: TenantId,
+        _associated_by_subject: &str,
+        association: RuntimeRecordAssociation,
+    ) -> AppResult<bool> {
+        let mut associations = self.record_associations.write().await;
+        if associations.iter().any(|(stored_tenant_id, stored)| {
+            stored_tenant_id == &tenant_id && same_association(stored, &association)
+        }) {
+            return Ok(false);
+        }
+
+        associations.push((tenant_id, association));
+        Ok(true)
+    }
+
+    pub(in super::super) async fn disassociate_runtime_records_impl(
+        &self,
+        tenant_id: TenantId,
+        association: &RuntimeRecordAssociation,
+    ) -> AppResult<bool> {
+        let mut associations = self.record_associations.write().await;
+        let previous_len = associations.len();
+        associations.retain(|(stored_tenant_id, stored)| {
+            stored_tenant_id != &tenant_id || !same_association(stored, association)
+        });
+
+        Ok(associations.len() < previous_len)
+    }
+
+    pub(in super::super) async fn list_associated_runtime_record_ids_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<String>> {
+        Ok(self
+            .record_associations
+            .read()
+            .await
+            .iter()
+            .filter(|(stored_tenant_id, stored)| {
+                stored_tenant_id == &tenant_id
+                    && stored.entity_logical_name == entity_logical_name
+                    && stored.field_logical_name == field_logical_name
+                    && stored.record_id == record_id
+            })
+            .map(|(_, stored)| stored.target_record_id.clone())
+            .collect())
+    }
+}
+
+fn same_association(left: &RuntimeRecordAssociation, right: &RuntimeRecordAssociation) -> bool {
+    left.entity_logical_name == right.entity_logical_name
+        && left.field_logical_name == right.field_logical_name
+        && left.record_id == right.record_id
+        && left.target_record_id == right.target_record_id
+}
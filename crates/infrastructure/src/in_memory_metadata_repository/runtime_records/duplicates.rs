@@ -0,0 +1,58 @@
+use super::*;
+
+impl InMemoryMetadataRepository {
+    pub(in super::super) async fn save_duplicate_detection_rule_impl(
+        &self,
+        tenant_id: TenantId,
+        rule: DuplicateDetectionRule,
+    ) -> AppResult<()> {
+        self.duplicate_rules.write().await.insert(
+            (
+                tenant_id,
+                rule.entity_logical_name().to_owned(),
+                rule.logical_name().to_owned(),
+            ),
+            rule,
+        );
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn list_duplicate_detection_rules_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<DuplicateDetectionRule>> {
+        let mut rules: Vec<DuplicateDetectionRule> = self
+            .duplicate_rules
+            .read()
+            .await
+            .iter()
+            .filter(|((stored_tenant_id, stored_entity, _), _)| {
+                *stored_tenant_id == tenant_id && stored_entity == entity_logical_name
+            })
+            .map(|(_, rule)| rule.clone())
+            .collect();
+        rules.sort_by(|left, right| left.logical_name().cmp(right.logical_name()));
+
+        Ok(rules)
+    }
+
+    pub(in super::super) async fn delete_duplicate_detection_rule_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(self
+            .duplicate_rules
+            .write()
+            .await
+            .remove(&(
+                tenant_id,
+                entity_logical_name.to_owned(),
+                logical_name.to_owned(),
+            ))
+            .is_some())
+    }
+}
@@ -0,0 +1,110 @@
+use super::*;
+
+impl InMemoryMetadataRepository {
+    pub(in super::super) async fn save_entity_history_policy_impl(
+        &self,
+        tenant_id: TenantId,
+        policy: EntityHistoryPolicy,
+    ) -> AppResult<()> {
+        self.history_policies
+            .write()
+            .await
+            .insert((tenant_id, policy.entity_logical_name.clone()), policy);
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn find_entity_history_policy_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityHistoryPolicy>> {
+        Ok(self
+            .history_policies
+            .read()
+            .await
+            .get(&(tenant_id, entity_logical_name.to_owned()))
+            .cloned())
+    }
+
+    pub(in super::super) async fn delete_entity_history_policy_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(self
+            .history_policies
+            .write()
+            .await
+            .remove(&(tenant_id, entity_logical_name.to_owned()))
+            .is_some())
+    }
+
+    pub(in super::super) async fn append_runtime_record_history_entry_impl(
+        &self,
+        tenant_id: TenantId,
+        entry: NewRuntimeRecordHistoryEntry,
+        retention_cutoff: Option<DateTime<Utc>>,
+    ) -> AppResult<RuntimeRecordHistoryEntry> {
+        let stored = RuntimeRecordHistoryEntry {
+            entry_id: Uuid::new_v4().to_string(),
+            entity_logical_name: entry.entity_logical_name,
+            record_id: entry.record_id,
+            record_version: entry.record_version,
+            changes: entry.changes,
+            changed_by_subject: entry.changed_by_subject,
+            changed_at: Utc::now(),
+        };
+        let mut history = self.record_history.write().await;
+        if let Some(cutoff) = retention_cutoff {
+            history.retain(|(entry_tenant_id, existing)| {
+                *entry_tenant_id != tenant_id
+                    || existing.entity_logical_name != stored.entity_logical_name
+                    || existing.record_id != stored.record_id
+                    || existing.changed_at >= cutoff
+            });
+        }
+        history.push((tenant_id, stored.clone()));
+
+        Ok(stored)
+    }
+
+    pub(in super::super) async fn list_runtime_record_history_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<RuntimeRecordHistoryEntry>> {
+        Ok(self
+            .record_history
+            .read()
+            .await
+            .iter()
+            .filter(|(entry_tenant_id, entry)| {
+                *entry_tenant_id == tenant_id
+                    && entry.entity_logical_name == entity_logical_name
+                    && entry.record_id == record_id
+                    && since.is_none_or(|since| entry.changed_at >= since)
+            })
+            .map(|(_, entry)| entry.clone())
+            .collect())
+    }
+
+    pub(in super::super) async fn purge_runtime_record_history_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        cutoff: DateTime<Utc>,
+    ) -> AppResult<u64> {
+        let mut history = self.record_history.write().await;
+        let before = history.len();
+        history.retain(|(entry_tenant_id, entry)| {
+            *entry_tenant_id != tenant_id
+                || entry.entity_logical_name != entity_logical_name
+                || entry.changed_at >= cutoff
+        });
+
+        Ok(u64::try_from(before - history.len()).unwrap_or(u64::MAX))
+    }
+}
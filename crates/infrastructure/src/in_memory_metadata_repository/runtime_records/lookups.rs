@@ -0,0 +1,56 @@
+use super::*;
+
+impl InMemoryMetadataRepository {
+    pub(in super::super) async fn save_relation_lookup_config_impl(
+        &self,
+        tenant_id: TenantId,
+        config: RelationLookupConfig,
+    ) -> AppResult<()> {
+        self.relation_lookups.write().await.insert(
+            (
+                tenant_id,
+                config.entity_logical_name.clone(),
+                config.field_logical_name.clone(),
+            ),
+            config,
+        );
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn find_relation_lookup_config_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<Option<RelationLookupConfig>> {
+        Ok(self
+            .relation_lookups
+            .read()
+            .await
+            .get(&(
+                tenant_id,
+                entity_logical_name.to_owned(),
+                field_logical_name.to_owned(),
+            ))
+            .cloned())
+    }
+
+    pub(in super::super) async fn delete_relation_lookup_config_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(self
+            .relation_lookups
+            .write()
+            .await
+            .remove(&(
+                tenant_id,
+                entity_logical_name.to_owned(),
+                field_logical_name.to_owned(),
+            ))
+            .is_some())
+    }
+}
@@ -1,5 +1,7 @@
 use std::cmp::Ordering;
 
+use chrono::Utc;
+
 use super::*;
 
 impl InMemoryMetadataRepository {
@@ -11,6 +13,7 @@ impl InMemoryMetadataRepository {
     ) -> AppResult<Vec<RuntimeRecord>> {
         let records = self.runtime_records.read().await;
         let record_owners = self.record_owners.read().await;
+        let associations = self.record_associations.read().await;
         let runtime_index = build_runtime_record_index(&records);
         let mut listed: Vec<RuntimeRecord> = collect_runtime_records_for_scope(
             &records,
@@ -21,17 +24,20 @@ impl InMemoryMetadataRepository {
         )
         .into_iter()
         .filter(|record| {
-            let Some(scope_records) = resolve_runtime_query_scope_records(
+            let Some(combinations) = resolve_runtime_query_scope_records(
                 &query,
                 tenant_id,
                 entity_logical_name,
                 record,
                 &runtime_index,
+                &associations,
             ) else {
                 return false;
             };
 
-            runtime_record_matches_filters(&scope_records, &query)
+            combinations
+                .iter()
+                .any(|scope_records| runtime_record_matches_filters(scope_records, &query))
         })
         .collect();
 
@@ -39,20 +45,26 @@ impl InMemoryMetadataRepository {
             listed.sort_by(|left, right| left.record_id().as_str().cmp(right.record_id().as_str()));
         } else {
             listed.sort_by(|left, right| {
+                // Sorts never target many-to-many scopes, so the first
+                // combination carries every sortable value.
                 let left_scope_records = resolve_runtime_query_scope_records(
                     &query,
                     tenant_id,
                     entity_logical_name,
                     left,
                     &runtime_index,
-                );
+                    &associations,
+                )
+                .and_then(|combinations| combinations.into_iter().next());
                 let right_scope_records = resolve_runtime_query_scope_records(
                     &query,
                     tenant_id,
                     entity_logical_name,
                     right,
                     &runtime_index,
-                );
+                    &associations,
+                )
+                .and_then(|combinations| combinations.into_iter().next());
 
                 let Some(left_scope_records) = left_scope_records else {
                     return Ordering::Greater;
@@ -79,6 +91,19 @@ impl InMemoryMetadataRepository {
             .take(query.limit)
             .collect())
     }
+
+    pub(in super::super) async fn aggregate_runtime_records_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        query: RuntimeRecordAggregateQuery,
+    ) -> AppResult<Vec<RuntimeRecordAggregateRow>> {
+        let records = self
+            .query_runtime_records_impl(tenant_id, entity_logical_name, query.record_query())
+            .await?;
+
+        Ok(query.aggregate_records(&records))
+    }
 }
 
 fn build_runtime_record_index(
@@ -87,59 +112,107 @@ fn build_runtime_record_index(
     records.clone()
 }
 
+/// Resolves every combination of linked scope records reachable from the root
+/// record. Many-to-many links fan out into one combination per associated
+/// target; `None` means the root record is excluded by an inner join.
 fn resolve_runtime_query_scope_records(
     query: &RuntimeRecordQuery,
     tenant_id: TenantId,
     root_entity_logical_name: &str,
     root_record: &RuntimeRecord,
     runtime_index: &HashMap<(TenantId, String, String), RuntimeRecord>,
-) -> Option<HashMap<String, Option<RuntimeRecord>>> {
-    let mut scope_records = HashMap::new();
-    scope_records.insert(String::new(), Some(root_record.clone()));
+    associations: &[(TenantId, RuntimeRecordAssociation)],
+) -> Option<Vec<HashMap<String, Option<RuntimeRecord>>>> {
+    if root_entity_logical_name != root_record.entity_logical_name().as_str() {
+        return None;
+    }
+
+    let mut root_scope_records = HashMap::new();
+    root_scope_records.insert(String::new(), Some(root_record.clone()));
+    let mut combinations = vec![root_scope_records];
 
     for link in &query.links {
         let parent_scope_key = link.parent_alias.clone().unwrap_or_default();
-        let Some(parent_record) = scope_records
-            .get(parent_scope_key.as_str())
-            .and_then(|record| record.clone())
-        else {
-            if link.join_type == RuntimeRecordJoinType::Inner {
-                return None;
+        let mut next_combinations = Vec::new();
+
+        for scope_records in combinations {
+            let linked_records = scope_records
+                .get(parent_scope_key.as_str())
+                .and_then(|record| record.as_ref())
+                .map(|parent_record| {
+                    resolve_linked_records(
+                        link,
+                        tenant_id,
+                        parent_record,
+                        runtime_index,
+                        associations,
+                    )
+                })
+                .unwrap_or_default();
+
+            if linked_records.is_empty() {
+                if link.join_type == RuntimeRecordJoinType::Left {
+                    let mut scope_records = scope_records;
+                    scope_records.insert(link.alias.clone(), None);
+                    next_combinations.push(scope_records);
+                }
+                continue;
             }
 
-            scope_records.insert(link.alias.clone(), None);
-            continue;
-        };
-
-        let relation_target_record_id = parent_record
-            .data()
-            .as_object()
-            .and_then(|data| data.get(link.relation_field_logical_name.as_str()))
-            .and_then(Value::as_str)
-            .map(str::to_owned);
-
-        let linked_record = relation_target_record_id.and_then(|record_id| {
-            runtime_index
-                .get(&(
-                    tenant_id,
-                    link.target_entity_logical_name.clone(),
-                    record_id,
-                ))
-                .cloned()
-        });
+            for linked_record in linked_records {
+                let mut scope_records = scope_records.clone();
+                scope_records.insert(link.alias.clone(), Some(linked_record));
+                next_combinations.push(scope_records);
+            }
+        }
 
-        if linked_record.is_none() && link.join_type == RuntimeRecordJoinType::Inner {
+        if next_combinations.is_empty() {
             return None;
         }
-
-        scope_records.insert(link.alias.clone(), linked_record);
+        combinations = next_combinations;
     }
 
-    if root_entity_logical_name != root_record.entity_logical_name().as_str() {
-        return None;
-    }
+    Some(combinations)
+}
 
-    Some(scope_records)
+fn resolve_linked_records(
+    link: &RuntimeRecordLink,
+    tenant_id: TenantId,
+    parent_record: &RuntimeRecord,
+    runtime_index: &HashMap<(TenantId, String, String), RuntimeRecord>,
+    associations: &[(TenantId, RuntimeRecordAssociation)],
+) -> Vec<RuntimeRecord> {
+    let find_target = |record_id: &str| {
+        runtime_index
+            .get(&(
+                tenant_id,
+                link.target_entity_logical_name.clone(),
+                record_id.to_owned(),
+            ))
+            .cloned()
+    };
+
+    match link.cardinality {
+        RuntimeRecordLinkCardinality::ManyToOne => parent_record
+            .data()
+            .as_object()
+            .and_then(|data| data.get(link.relation_field_logical_name.as_str()))
+            .and_then(Value::as_str)
+            .and_then(find_target)
+            .into_iter()
+            .collect(),
+        RuntimeRecordLinkCardinality::ManyToMany => associations
+            .iter()
+            .filter(|(association_tenant_id, association)| {
+                association_tenant_id == &tenant_id
+                    && association.entity_logical_name
+                        == parent_record.entity_logical_name().as_str()
+                    && association.field_logical_name == link.relation_field_logical_name
+                    && association.record_id == parent_record.record_id().as_str()
+            })
+            .filter_map(|(_, association)| find_target(association.target_record_id.as_str()))
+            .collect(),
+    }
 }
 
 fn runtime_record_matches_filters(
@@ -218,7 +291,7 @@ fn runtime_record_filter_matches_value(
     filter: &RuntimeRecordFilter,
 ) -> bool {
     let Some(value) = value else {
-        return false;
+        return filter.operator == RuntimeRecordOperator::IsNull;
     };
 
     match filter.operator {
@@ -243,11 +316,55 @@ fn runtime_record_filter_matches_value(
             .zip(filter.field_value.as_str())
             .map(|(stored, expected)| stored.contains(expected))
             .unwrap_or(false),
+        RuntimeRecordOperator::BeginsWith => value
+            .as_str()
+            .zip(filter.field_value.as_str())
+            .map(|(stored, expected)| stored.starts_with(expected))
+            .unwrap_or(false),
         RuntimeRecordOperator::In => filter
             .field_value
             .as_array()
             .map(|values| values.iter().any(|candidate| candidate == value))
             .unwrap_or(false),
+        RuntimeRecordOperator::NotIn => filter
+            .field_value
+            .as_array()
+            .map(|values| values.iter().all(|candidate| candidate != value))
+            .unwrap_or(false),
+        RuntimeRecordOperator::Between => match filter.field_value.as_array().map(Vec::as_slice) {
+            Some([low, high]) if !value.is_null() => {
+                !compare_filter_values(value, low, filter).is_lt()
+                    && !compare_filter_values(value, high, filter).is_gt()
+            }
+            _ => false,
+        },
+        RuntimeRecordOperator::Today
+        | RuntimeRecordOperator::Yesterday
+        | RuntimeRecordOperator::ThisMonth
+        | RuntimeRecordOperator::LastNDays => {
+            let Some((start, end)) = filter
+                .operator
+                .relative_date_window(&filter.field_value, Utc::now().date_naive())
+            else {
+                return false;
+            };
+            let Some(date) = value.as_str().and_then(|value| value.get(..10)) else {
+                return false;
+            };
+            let (start, end) = (start.to_string(), end.to_string());
+            date >= start.as_str() && date < end.as_str()
+        }
+        RuntimeRecordOperator::IsNull => is_empty_filter_value(value),
+        RuntimeRecordOperator::IsNotNull => !is_empty_filter_value(value),
+    }
+}
+
+fn is_empty_filter_value(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.is_empty(),
+        Value::Array(values) => values.is_empty(),
+        _ => false,
     }
 }
 
@@ -267,7 +384,7 @@ fn compare_filter_values(
             .zip(expected.as_i64())
             .map(|(left, right)| left.cmp(&right))
             .unwrap_or(Ordering::Equal),
-        FieldType::MultiChoice => Ordering::Equal,
+        FieldType::MultiChoice | FieldType::ManyToMany => Ordering::Equal,
         FieldType::Date | FieldType::DateTime | FieldType::Text | FieldType::Relation => stored
             .as_str()
             .zip(expected.as_str())
@@ -310,7 +427,7 @@ fn compare_values_for_sort(
                 .zip(right.as_i64())
                 .map(|(left, right)| left.cmp(&right))
                 .unwrap_or(Ordering::Equal),
-            FieldType::MultiChoice => Ordering::Equal,
+            FieldType::MultiChoice | FieldType::ManyToMany => Ordering::Equal,
             FieldType::Boolean => left
                 .as_bool()
                 .zip(right.as_bool())
@@ -44,41 +44,134 @@ impl InMemoryMetadataRepository {
             .cloned())
     }
 
+    pub(in super::super) async fn find_runtime_record_by_idempotency_key_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        idempotency_key: &str,
+    ) -> AppResult<Option<RuntimeRecord>> {
+        let record_id = self
+            .record_idempotency_keys
+            .read()
+            .await
+            .get(&(
+                tenant_id,
+                entity_logical_name.to_owned(),
+                idempotency_key.to_owned(),
+            ))
+            .cloned();
+        match record_id {
+            Some(record_id) => {
+                self.find_runtime_record_impl(tenant_id, entity_logical_name, record_id.as_str())
+                    .await
+            }
+            None => Ok(None),
+        }
+    }
+
     pub(in super::super) async fn delete_runtime_record_impl(
         &self,
         tenant_id: TenantId,
         entity_logical_name: &str,
         record_id: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+        relation_plan: &RelationDeletePlan,
     ) -> AppResult<()> {
-        let removed = self
+        if !self
             .runtime_records
-            .write()
+            .read()
             .await
-            .remove(&runtime_record_storage_key(
+            .contains_key(&runtime_record_storage_key(
                 tenant_id,
                 entity_logical_name,
                 record_id,
-            ));
-
-        if removed.is_none() {
+            ))
+        {
             return Err(AppError::NotFound(format!(
                 "runtime record '{}' does not exist for entity '{}'",
                 record_id, entity_logical_name
             )));
         }
 
+        {
+            let mut records = self.runtime_records.write().await;
+            for relinked in &relation_plan.relinked_records {
+                let key = runtime_record_storage_key(
+                    tenant_id,
+                    relinked.entity_logical_name.as_str(),
+                    relinked.record_id.as_str(),
+                );
+                let Some(record) = records.get(&key) else {
+                    continue;
+                };
+                let mut data = record.data().clone();
+                if let Some(object) = data.as_object_mut() {
+                    object.insert(
+                        relinked.field_logical_name.clone(),
+                        relinked
+                            .parent_record_id
+                            .clone()
+                            .map_or(Value::Null, Value::String),
+                    );
+                }
+                let relinked_record = RuntimeRecord::new(
+                    relinked.record_id.as_str(),
+                    relinked.entity_logical_name.as_str(),
+                    data,
+                )?
+                .with_version(record.version() + 1);
+                records.insert(key, relinked_record);
+            }
+        }
+        for deleted in &relation_plan.deleted_records {
+            self.remove_runtime_record(
+                tenant_id,
+                deleted.entity_logical_name.as_str(),
+                deleted.record_id.as_str(),
+                deleted.workflow_event.clone(),
+                None,
+            )
+            .await;
+        }
+        self.remove_runtime_record(
+            tenant_id,
+            entity_logical_name,
+            record_id,
+            workflow_event,
+            audit_event,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn remove_runtime_record(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+    ) {
+        let key = runtime_record_storage_key(tenant_id, entity_logical_name, record_id);
+        if self.runtime_records.write().await.remove(&key).is_none() {
+            return;
+        }
+
         let mut unique_index = self.unique_values.write().await;
         remove_runtime_record_unique_values(&mut unique_index, entity_logical_name, record_id);
+        drop(unique_index);
 
-        self.record_owners
+        self.record_owners.write().await.remove(&key);
+        self.record_associations
             .write()
             .await
-            .remove(&runtime_record_storage_key(
-                tenant_id,
-                entity_logical_name,
-                record_id,
-            ));
+            .retain(|(association_tenant_id, association)| {
+                association_tenant_id != &tenant_id
+                    || (association.record_id != record_id
+                        && association.target_record_id != record_id)
+            });
         self.enqueue_runtime_record_workflow_event_impl(
             tenant_id,
             entity_logical_name,
@@ -86,8 +179,7 @@ impl InMemoryMetadataRepository {
             workflow_event,
         )
         .await;
-
-        Ok(())
+        self.enqueue_audit_outbox_event_impl(audit_event).await;
     }
 
     pub(in super::super) async fn runtime_record_exists_impl(
@@ -15,7 +15,7 @@ impl InMemoryMetadataRepository {
                 continue;
             }
 
-            let Some(schema) = versions.last() else {
+            let Some(schema) = versions.last().map(|version| &version.schema) else {
                 continue;
             };
 
@@ -59,4 +59,41 @@ impl InMemoryMetadataRepository {
 
         Ok(false)
     }
+
+    pub(in super::super) async fn save_relation_behavior_impl(
+        &self,
+        tenant_id: TenantId,
+        behavior: RelationBehavior,
+    ) -> AppResult<()> {
+        self.relation_behaviors.write().await.insert(
+            (
+                tenant_id,
+                behavior.entity_logical_name.clone(),
+                behavior.field_logical_name.clone(),
+            ),
+            behavior,
+        );
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn list_relation_behaviors_impl(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<RelationBehavior>> {
+        let mut behaviors = self
+            .relation_behaviors
+            .read()
+            .await
+            .iter()
+            .filter(|((behavior_tenant_id, _, _), _)| behavior_tenant_id == &tenant_id)
+            .map(|(_, behavior)| behavior.clone())
+            .collect::<Vec<_>>();
+        behaviors.sort_by(|left, right| {
+            (&left.entity_logical_name, &left.field_logical_name)
+                .cmp(&(&right.entity_logical_name, &right.field_logical_name))
+        });
+
+        Ok(behaviors)
+    }
 }
@@ -0,0 +1,49 @@
+use super::*;
+
+impl InMemoryMetadataRepository {
+    pub(in super::super) async fn save_entity_slug_config_impl(
+        &self,
+        tenant_id: TenantId,
+        config: EntitySlugConfig,
+    ) -> AppResult<()> {
+        self.slug_configs
+            .write()
+            .await
+            .insert((tenant_id, config.entity_logical_name.clone()), config);
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn find_entity_slug_config_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntitySlugConfig>> {
+        Ok(self
+            .slug_configs
+            .read()
+            .await
+            .get(&(tenant_id, entity_logical_name.to_owned()))
+            .cloned())
+    }
+
+    pub(in super::super) async fn find_runtime_record_id_by_unique_value_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        field_value_hash: &str,
+    ) -> AppResult<Option<String>> {
+        Ok(self
+            .unique_values
+            .read()
+            .await
+            .get(&(
+                tenant_id,
+                entity_logical_name.to_owned(),
+                field_logical_name.to_owned(),
+                field_value_hash.to_owned(),
+            ))
+            .cloned())
+    }
+}
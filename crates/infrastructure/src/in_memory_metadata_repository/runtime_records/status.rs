@@ -0,0 +1,93 @@
+use super::*;
+
+impl InMemoryMetadataRepository {
+    pub(in super::super) async fn save_entity_status_config_impl(
+        &self,
+        tenant_id: TenantId,
+        config: EntityStatusConfig,
+    ) -> AppResult<()> {
+        self.status_configs
+            .write()
+            .await
+            .insert((tenant_id, config.entity_logical_name.clone()), config);
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn find_entity_status_config_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityStatusConfig>> {
+        Ok(self
+            .status_configs
+            .read()
+            .await
+            .get(&(tenant_id, entity_logical_name.to_owned()))
+            .cloned())
+    }
+
+    pub(in super::super) async fn delete_entity_status_config_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        Ok(self
+            .status_configs
+            .write()
+            .await
+            .remove(&(tenant_id, entity_logical_name.to_owned()))
+            .is_some())
+    }
+
+    pub(in super::super) async fn append_runtime_record_status_change_impl(
+        &self,
+        tenant_id: TenantId,
+        change: NewRuntimeRecordStatusChange,
+    ) -> AppResult<RuntimeRecordStatusChange> {
+        let stored = RuntimeRecordStatusChange {
+            change_id: Uuid::new_v4().to_string(),
+            entity_logical_name: change.entity_logical_name,
+            record_id: change.record_id,
+            from_status: change.from_status,
+            to_status: change.to_status,
+            from_reason: change.from_reason,
+            to_reason: change.to_reason,
+            changed_by_subject: change.changed_by_subject,
+            changed_at: chrono::Utc::now(),
+        };
+        self.status_history
+            .write()
+            .await
+            .push((tenant_id, stored.clone()));
+        self.enqueue_runtime_record_workflow_event_impl(
+            tenant_id,
+            stored.entity_logical_name.as_str(),
+            stored.record_id.as_str(),
+            change.notification,
+        )
+        .await;
+
+        Ok(stored)
+    }
+
+    pub(in super::super) async fn list_runtime_record_status_history_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RuntimeRecordStatusChange>> {
+        Ok(self
+            .status_history
+            .read()
+            .await
+            .iter()
+            .filter(|(change_tenant_id, change)| {
+                *change_tenant_id == tenant_id
+                    && change.entity_logical_name == entity_logical_name
+                    && change.record_id == record_id
+            })
+            .map(|(_, change)| change.clone())
+            .collect())
+    }
+}
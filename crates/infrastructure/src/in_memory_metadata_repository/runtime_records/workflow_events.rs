@@ -1,6 +1,13 @@
 use super::*;
 
 impl InMemoryMetadataRepository {
+    pub(in super::super) async fn enqueue_audit_outbox_event_impl(
+        &self,
+        audit_event: Option<AuditEvent>,
+    ) {
+        self.audit_outbox.write().await.extend(audit_event);
+    }
+
     pub(in super::super) async fn enqueue_runtime_record_workflow_event_impl(
         &self,
         tenant_id: TenantId,
@@ -1,6 +1,7 @@
 use super::*;
 
 impl InMemoryMetadataRepository {
+    #[allow(clippy::too_many_arguments)]
     pub(in super::super) async fn create_runtime_record_impl(
         &self,
         tenant_id: TenantId,
@@ -9,6 +10,7 @@ impl InMemoryMetadataRepository {
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         let generated_record_id = Uuid::new_v4().to_string();
         self.create_runtime_record_with_id_impl(
@@ -19,10 +21,12 @@ impl InMemoryMetadataRepository {
             unique_values,
             created_by_subject,
             workflow_event,
+            audit_event,
         )
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(in super::super) async fn create_runtime_record_with_id_impl(
         &self,
         tenant_id: TenantId,
@@ -32,6 +36,7 @@ impl InMemoryMetadataRepository {
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         let record = RuntimeRecord::new(record_id, entity_logical_name, data)?;
         let record_key =
@@ -77,10 +82,70 @@ impl InMemoryMetadataRepository {
             workflow_event,
         )
         .await;
+        self.enqueue_audit_outbox_event_impl(audit_event).await;
 
         Ok(record)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub(in super::super) async fn create_runtime_record_with_idempotency_key_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        idempotency_key: &str,
+        data: Value,
+        unique_values: Vec<UniqueFieldValue>,
+        created_by_subject: &str,
+        workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+    ) -> AppResult<RuntimeRecord> {
+        let idempotency_storage_key = (
+            tenant_id,
+            entity_logical_name.to_owned(),
+            idempotency_key.to_owned(),
+        );
+        let mut idempotency_keys = self.record_idempotency_keys.write().await;
+        if let Some(existing_record_id) = idempotency_keys.get(&idempotency_storage_key) {
+            let existing_key = runtime_record_storage_key(
+                tenant_id,
+                entity_logical_name,
+                existing_record_id.as_str(),
+            );
+            if self
+                .runtime_records
+                .read()
+                .await
+                .contains_key(&existing_key)
+            {
+                return Err(AppError::Conflict(format!(
+                    "entity '{}' already has a runtime record for this idempotency key",
+                    entity_logical_name
+                )));
+            }
+        }
+
+        let record = self
+            .create_runtime_record_with_id_impl(
+                tenant_id,
+                entity_logical_name,
+                record_id,
+                data,
+                unique_values,
+                created_by_subject,
+                workflow_event,
+                audit_event,
+            )
+            .await?;
+        idempotency_keys.insert(
+            idempotency_storage_key,
+            record.record_id().as_str().to_owned(),
+        );
+
+        Ok(record)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub(in super::super) async fn update_runtime_record_impl(
         &self,
         tenant_id: TenantId,
@@ -88,14 +153,27 @@ impl InMemoryMetadataRepository {
         record_id: &str,
         data: Value,
         unique_values: Vec<UniqueFieldValue>,
+        expected_version: Option<i64>,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         let record_key = runtime_record_storage_key(tenant_id, entity_logical_name, record_id);
 
-        if !self.runtime_records.read().await.contains_key(&record_key) {
-            return Err(AppError::NotFound(format!(
-                "runtime record '{}' does not exist",
-                record_id
+        let current_version = self
+            .runtime_records
+            .read()
+            .await
+            .get(&record_key)
+            .map(RuntimeRecord::version)
+            .ok_or_else(|| {
+                AppError::NotFound(format!("runtime record '{}' does not exist", record_id))
+            })?;
+        if let Some(expected_version) = expected_version
+            && expected_version != current_version
+        {
+            return Err(AppError::Conflict(format!(
+                "runtime record '{}' was modified concurrently: expected version {} but current version is {}",
+                record_id, expected_version, current_version
             )));
         }
 
@@ -117,7 +195,8 @@ impl InMemoryMetadataRepository {
             );
         }
 
-        let updated = RuntimeRecord::new(record_id, entity_logical_name, data)?;
+        let updated = RuntimeRecord::new(record_id, entity_logical_name, data)?
+            .with_version(current_version + 1);
         self.runtime_records
             .write()
             .await
@@ -129,9 +208,169 @@ impl InMemoryMetadataRepository {
             workflow_event,
         )
         .await;
+        self.enqueue_audit_outbox_event_impl(audit_event).await;
 
         Ok(updated)
     }
+
+    pub(in super::super) async fn apply_runtime_record_changeset_impl(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        writes: Vec<RuntimeRecordChangesetWrite>,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        let runtime_records_snapshot = self.runtime_records.read().await.clone();
+        let record_owners_snapshot = self.record_owners.read().await.clone();
+        let unique_values_snapshot = self.unique_values.read().await.clone();
+        let workflow_events_snapshot = self.runtime_workflow_events.read().await.clone();
+        let audit_outbox_snapshot = self.audit_outbox.read().await.clone();
+
+        let mut records = Vec::with_capacity(writes.len());
+        for write in writes {
+            let result = match write.method {
+                RuntimeRecordChangesetMethod::Create => {
+                    self.create_runtime_record_with_id_impl(
+                        tenant_id,
+                        write.entity_logical_name.as_str(),
+                        write.record_id.as_str(),
+                        write.data,
+                        write.unique_values,
+                        created_by_subject,
+                        write.workflow_event,
+                        write.audit_event,
+                    )
+                    .await
+                }
+                RuntimeRecordChangesetMethod::Update => {
+                    self.update_runtime_record_impl(
+                        tenant_id,
+                        write.entity_logical_name.as_str(),
+                        write.record_id.as_str(),
+                        write.data,
+                        write.unique_values,
+                        None,
+                        write.workflow_event,
+                        write.audit_event,
+                    )
+                    .await
+                }
+            };
+
+            match result {
+                Ok(record) => records.push(record),
+                Err(error) => {
+                    *self.runtime_records.write().await = runtime_records_snapshot;
+                    *self.record_owners.write().await = record_owners_snapshot;
+                    *self.unique_values.write().await = unique_values_snapshot;
+                    *self.runtime_workflow_events.write().await = workflow_events_snapshot;
+                    *self.audit_outbox.write().await = audit_outbox_snapshot;
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(records)
+    }
+
+    pub(in super::super) async fn assign_runtime_record_owner_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        owner_subject: &str,
+        cascades: &[RelationBehavior],
+    ) -> AppResult<Option<(String, Vec<RelationCascadeResult>)>> {
+        let record_key = runtime_record_storage_key(tenant_id, entity_logical_name, record_id);
+        let mut runtime_records = self.runtime_records.write().await;
+        if !runtime_records.contains_key(&record_key) {
+            return Ok(None);
+        }
+
+        let mut record_owners = self.record_owners.write().await;
+        let previous_owner = record_owners
+            .insert(record_key.clone(), owner_subject.to_owned())
+            .unwrap_or_default();
+        sync_owner_system_field(
+            &mut runtime_records,
+            &record_key,
+            previous_owner.as_str(),
+            owner_subject,
+        )?;
+
+        let mut results = Vec::with_capacity(cascades.len());
+        for cascade in cascades {
+            let child_keys = runtime_records
+                .iter()
+                .filter(|((record_tenant_id, record_entity, _), record)| {
+                    record_tenant_id == &tenant_id
+                        && record_entity == &cascade.entity_logical_name
+                        && record
+                            .data()
+                            .get(cascade.field_logical_name.as_str())
+                            .and_then(Value::as_str)
+                            == Some(record_id)
+                })
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<_>>();
+
+            let mut affected_records = 0;
+            for child_key in child_keys {
+                let child_owner = record_owners.get(&child_key).cloned().unwrap_or_default();
+                let eligible = match cascade.assign_behavior {
+                    RelationCascadeBehavior::None => false,
+                    RelationCascadeBehavior::CascadeAll => true,
+                    RelationCascadeBehavior::CascadeUserOwned => child_owner == previous_owner,
+                };
+                if eligible && child_owner != owner_subject {
+                    sync_owner_system_field(
+                        &mut runtime_records,
+                        &child_key,
+                        child_owner.as_str(),
+                        owner_subject,
+                    )?;
+                    record_owners.insert(child_key, owner_subject.to_owned());
+                    affected_records += 1;
+                }
+            }
+
+            results.push(RelationCascadeResult {
+                entity_logical_name: cascade.entity_logical_name.clone(),
+                field_logical_name: cascade.field_logical_name.clone(),
+                affected_records,
+            });
+        }
+
+        Ok(Some((previous_owner, results)))
+    }
+}
+
+/// Moves the stamped `owner` system field along with the owner subject.
+fn sync_owner_system_field(
+    runtime_records: &mut HashMap<(TenantId, String, String), RuntimeRecord>,
+    record_key: &(TenantId, String, String),
+    previous_owner: &str,
+    owner_subject: &str,
+) -> AppResult<()> {
+    let Some(record) = runtime_records.get(record_key) else {
+        return Ok(());
+    };
+    if record.data().get("owner").and_then(Value::as_str) != Some(previous_owner) {
+        return Ok(());
+    }
+
+    let mut data = record.data().clone();
+    if let Some(object) = data.as_object_mut() {
+        object.insert("owner".to_owned(), Value::String(owner_subject.to_owned()));
+    }
+    let updated = RuntimeRecord::new(
+        record.record_id().as_str(),
+        record.entity_logical_name().as_str(),
+        data,
+    )?
+    .with_version(record.version() + 1);
+    runtime_records.insert(record_key.clone(), updated);
+
+    Ok(())
 }
 
 fn ensure_unique_values_available(
@@ -1,11 +1,19 @@
 use qryvanta_application::{
-    MetadataRepository, RecordListQuery, RuntimeRecordConditionGroup, RuntimeRecordConditionNode,
-    RuntimeRecordFilter, RuntimeRecordJoinType, RuntimeRecordLink, RuntimeRecordLogicalMode,
-    RuntimeRecordOperator, RuntimeRecordQuery, UniqueFieldValue,
+    AuditEvent, MetadataRepository, RecordListQuery, RelationBehavior, RelationDeletePlan,
+    RuntimeRecordAggregate, RuntimeRecordAggregateFunction, RuntimeRecordAggregateHaving,
+    RuntimeRecordAggregateQuery, RuntimeRecordAggregateSort, RuntimeRecordAggregateSortKey,
+    RuntimeRecordAssociation, RuntimeRecordChangesetMethod, RuntimeRecordChangesetWrite,
+    RuntimeRecordConditionGroup, RuntimeRecordConditionNode, RuntimeRecordFilter,
+    RuntimeRecordGroupBy, RuntimeRecordJoinType, RuntimeRecordLink, RuntimeRecordLinkCardinality,
+    RuntimeRecordLogicalMode, RuntimeRecordOperator, RuntimeRecordQuery,
+    RuntimeRecordSortDirection, UniqueFieldValue,
 };
 use qryvanta_core::{AppError, TenantId};
-use qryvanta_domain::{EntityDefinition, EntityFieldDefinition, FieldType};
-use serde_json::json;
+use qryvanta_domain::{
+    AuditAction, EntityDefinition, EntityFieldDefinition, FieldType, RelationCascadeBehavior,
+    RelationDeleteBehavior,
+};
+use serde_json::{Value, json};
 
 use super::InMemoryMetadataRepository;
 
@@ -104,6 +112,7 @@ async fn runtime_record_unique_constraint_conflict() {
             }],
             "alice",
             None,
+            None,
         )
         .await;
     assert!(first.is_ok());
@@ -119,23 +128,182 @@ async fn runtime_record_unique_constraint_conflict() {
             }],
             "alice",
             None,
+            None,
         )
         .await;
     assert!(second.is_err());
 }
 
+#[tokio::test]
+async fn runtime_record_updates_increment_version_and_reject_stale_expected_version() {
+    let repository = InMemoryMetadataRepository::new();
+    let tenant_id = TenantId::new();
+
+    let created = repository
+        .create_runtime_record(
+            tenant_id,
+            "contact",
+            json!({"name": "Alice"}),
+            Vec::new(),
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(created.version(), 1);
+    let record_id = created.record_id().as_str().to_owned();
+
+    let updated = repository
+        .update_runtime_record(
+            tenant_id,
+            "contact",
+            record_id.as_str(),
+            json!({"name": "Alice Smith"}),
+            Vec::new(),
+            Some(1),
+            None,
+            None,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(updated.version(), 2);
+
+    let stale = repository
+        .update_runtime_record(
+            tenant_id,
+            "contact",
+            record_id.as_str(),
+            json!({"name": "Overwritten"}),
+            Vec::new(),
+            Some(1),
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(stale, Err(AppError::Conflict(_))));
+
+    let unconditional = repository
+        .update_runtime_record(
+            tenant_id,
+            "contact",
+            record_id.as_str(),
+            json!({"name": "Alice Jones"}),
+            Vec::new(),
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(unconditional.version(), 3);
+
+    let stored = repository
+        .find_runtime_record(tenant_id, "contact", record_id.as_str())
+        .await
+        .unwrap_or_default()
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(stored.version(), 3);
+    assert_eq!(stored.data()["name"], json!("Alice Jones"));
+}
+
+#[tokio::test]
+async fn runtime_record_changeset_rolls_back_applied_writes_on_failure() {
+    let repository = InMemoryMetadataRepository::new();
+    let tenant_id = TenantId::new();
+    let unique_email = || {
+        vec![UniqueFieldValue {
+            field_logical_name: "email".to_owned(),
+            field_value_hash: "same".to_owned(),
+        }]
+    };
+    let write = |record_id: &str, unique_values| RuntimeRecordChangesetWrite {
+        method: RuntimeRecordChangesetMethod::Create,
+        entity_logical_name: "contact".to_owned(),
+        record_id: record_id.to_owned(),
+        data: json!({"email": "alice@example.com"}),
+        unique_values,
+        workflow_event: None,
+        audit_event: Some(AuditEvent {
+            tenant_id,
+            subject: "alice".to_owned(),
+            action: AuditAction::RuntimeRecordCreated,
+            resource_type: "runtime_record".to_owned(),
+            resource_id: record_id.to_owned(),
+            detail: None,
+        }),
+    };
+
+    let failed = repository
+        .apply_runtime_record_changeset(
+            tenant_id,
+            "alice",
+            vec![
+                write("00000000-0000-0000-0000-000000000001", unique_email()),
+                write("00000000-0000-0000-0000-000000000002", unique_email()),
+            ],
+        )
+        .await;
+    assert!(matches!(failed, Err(AppError::Conflict(_))));
+    assert!(repository.audit_outbox.read().await.is_empty());
+
+    let listed = repository
+        .list_runtime_records(
+            tenant_id,
+            "contact",
+            RecordListQuery {
+                limit: 10,
+                offset: 0,
+                owner_subject: None,
+                include_inactive: false,
+            },
+        )
+        .await
+        .unwrap_or_default();
+    assert!(listed.is_empty());
+
+    let applied = repository
+        .apply_runtime_record_changeset(
+            tenant_id,
+            "alice",
+            vec![write(
+                "00000000-0000-0000-0000-000000000001",
+                unique_email(),
+            )],
+        )
+        .await;
+    assert!(applied.is_ok());
+    assert_eq!(repository.audit_outbox.read().await.len(), 1);
+}
+
 #[tokio::test]
 async fn list_runtime_records_honors_offset_and_limit() {
     let repository = InMemoryMetadataRepository::new();
     let tenant_id = TenantId::new();
 
     let first = repository
-        .create_runtime_record(tenant_id, "contact", json!({}), Vec::new(), "alice", None)
+        .create_runtime_record(
+            tenant_id,
+            "contact",
+            json!({}),
+            Vec::new(),
+            "alice",
+            None,
+            None,
+        )
         .await;
     assert!(first.is_ok());
 
     let second = repository
-        .create_runtime_record(tenant_id, "contact", json!({}), Vec::new(), "alice", None)
+        .create_runtime_record(
+            tenant_id,
+            "contact",
+            json!({}),
+            Vec::new(),
+            "alice",
+            None,
+            None,
+        )
         .await;
     assert!(second.is_ok());
 
@@ -147,6 +315,7 @@ async fn list_runtime_records_honors_offset_and_limit() {
                 limit: 1,
                 offset: 1,
                 owner_subject: None,
+                include_inactive: false,
             },
         )
         .await;
@@ -189,6 +358,7 @@ async fn runtime_record_queries_do_not_leak_across_tenants() {
             Vec::new(),
             "alice",
             None,
+            None,
         )
         .await;
     assert!(left_record.is_ok());
@@ -202,6 +372,7 @@ async fn runtime_record_queries_do_not_leak_across_tenants() {
                 limit: 50,
                 offset: 0,
                 owner_subject: None,
+                include_inactive: false,
             },
         )
         .await;
@@ -227,6 +398,7 @@ async fn runtime_record_queries_do_not_leak_across_tenants() {
                 links: Vec::new(),
                 sort: Vec::new(),
                 owner_subject: None,
+                include_inactive: false,
             },
         )
         .await;
@@ -251,6 +423,8 @@ async fn runtime_record_queries_do_not_leak_across_tenants() {
             "contact",
             left_record.record_id().as_str(),
             None,
+            None,
+            &RelationDeletePlan::default(),
         )
         .await;
     assert!(matches!(right_delete, Err(AppError::NotFound(_))));
@@ -270,6 +444,7 @@ async fn query_runtime_records_filters_and_paginates() {
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -283,6 +458,7 @@ async fn query_runtime_records_filters_and_paginates() {
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -296,6 +472,7 @@ async fn query_runtime_records_filters_and_paginates() {
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -320,6 +497,7 @@ async fn query_runtime_records_filters_and_paginates() {
                 links: Vec::new(),
                 sort: Vec::new(),
                 owner_subject: None,
+                include_inactive: false,
             },
         )
         .await;
@@ -336,6 +514,282 @@ async fn query_runtime_records_filters_and_paginates() {
     );
 }
 
+#[tokio::test]
+async fn aggregate_runtime_records_groups_filters_and_ranks_groups() {
+    let repository = InMemoryMetadataRepository::new();
+    let tenant_id = TenantId::new();
+
+    for (data, owner) in [
+        (json!({"stage": "open", "amount": 10}), "alice"),
+        (json!({"stage": "open", "amount": 30}), "alice"),
+        (json!({"stage": "won", "amount": 50}), "alice"),
+        (json!({"stage": "lost", "amount": 5}), "bob"),
+        (json!({"stage": "lost", "amount": null}), "alice"),
+    ] {
+        assert!(
+            repository
+                .create_runtime_record(tenant_id, "deal", data, Vec::new(), owner, None, None)
+                .await
+                .is_ok()
+        );
+    }
+
+    let count = |alias: &str| RuntimeRecordAggregate {
+        alias: alias.to_owned(),
+        function: RuntimeRecordAggregateFunction::Count,
+        field_logical_name: None,
+        field_type: None,
+    };
+    let amount = |alias: &str, function| RuntimeRecordAggregate {
+        alias: alias.to_owned(),
+        function,
+        field_logical_name: Some("amount".to_owned()),
+        field_type: Some(FieldType::Number),
+    };
+    let by_stage = RuntimeRecordAggregateQuery {
+        limit: 2,
+        logical_mode: RuntimeRecordLogicalMode::And,
+        where_clause: None,
+        filters: Vec::new(),
+        group_by: vec![RuntimeRecordGroupBy {
+            field_logical_name: "stage".to_owned(),
+            field_type: FieldType::Text,
+        }],
+        aggregates: vec![
+            count("deals"),
+            amount("total", RuntimeRecordAggregateFunction::Sum),
+            amount("smallest", RuntimeRecordAggregateFunction::Min),
+        ],
+        having: Vec::new(),
+        sort: vec![RuntimeRecordAggregateSort {
+            key: RuntimeRecordAggregateSortKey::Aggregate("total".to_owned()),
+            direction: RuntimeRecordSortDirection::Desc,
+        }],
+        owner_subject: None,
+    };
+
+    let top = repository
+        .aggregate_runtime_records(tenant_id, "deal", by_stage.clone())
+        .await
+        .unwrap_or_else(|error| panic!("aggregate failed: {error}"));
+    let number = |value: Option<&Value>| value.and_then(Value::as_f64);
+    assert_eq!(top.len(), 2);
+    assert_eq!(top[0].group.get("stage"), Some(&json!("won")));
+    assert_eq!(number(top[0].values.get("total")), Some(50.0));
+    assert_eq!(top[1].group.get("stage"), Some(&json!("open")));
+    assert_eq!(number(top[1].values.get("deals")), Some(2.0));
+    assert_eq!(number(top[1].values.get("total")), Some(40.0));
+    assert_eq!(number(top[1].values.get("smallest")), Some(10.0));
+
+    let owned = repository
+        .aggregate_runtime_records(
+            tenant_id,
+            "deal",
+            RuntimeRecordAggregateQuery {
+                limit: 10,
+                having: vec![RuntimeRecordAggregateHaving {
+                    aggregate_alias: "deals".to_owned(),
+                    operator: RuntimeRecordOperator::Gte,
+                    value: json!(2),
+                }],
+                sort: Vec::new(),
+                owner_subject: Some("alice".to_owned()),
+                ..by_stage.clone()
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("aggregate failed: {error}"));
+    assert_eq!(owned.len(), 1);
+    assert_eq!(owned[0].group.get("stage"), Some(&json!("open")));
+
+    let overall = repository
+        .aggregate_runtime_records(
+            tenant_id,
+            "deal",
+            RuntimeRecordAggregateQuery {
+                limit: 10,
+                filters: vec![RuntimeRecordFilter {
+                    scope_alias: None,
+                    field_logical_name: "stage".to_owned(),
+                    operator: RuntimeRecordOperator::Eq,
+                    field_type: FieldType::Text,
+                    field_value: json!("lost"),
+                }],
+                group_by: Vec::new(),
+                aggregates: vec![
+                    count("deals"),
+                    RuntimeRecordAggregate {
+                        field_logical_name: Some("amount".to_owned()),
+                        field_type: Some(FieldType::Number),
+                        ..count("priced")
+                    },
+                    amount("average", RuntimeRecordAggregateFunction::Avg),
+                ],
+                sort: Vec::new(),
+                ..by_stage
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("aggregate failed: {error}"));
+    assert_eq!(overall.len(), 1);
+    assert!(overall[0].group.is_empty());
+    assert_eq!(number(overall[0].values.get("deals")), Some(2.0));
+    assert_eq!(number(overall[0].values.get("priced")), Some(1.0));
+    assert_eq!(number(overall[0].values.get("average")), Some(5.0));
+}
+
+#[tokio::test]
+async fn query_runtime_records_supports_null_list_range_prefix_and_relative_date_operators() {
+    let repository = InMemoryMetadataRepository::new();
+    let tenant_id = TenantId::new();
+    let today = chrono::Utc::now().date_naive();
+    let yesterday = today.pred_opt().unwrap_or(today);
+    let long_ago = today - chrono::Days::new(40);
+
+    for (name, score, due) in [
+        ("Alice", json!(10), json!(today.to_string())),
+        (
+            "Albert",
+            json!(25),
+            json!(format!("{yesterday}T08:30:00.000Z")),
+        ),
+        ("Bob", json!(40), json!(long_ago.to_string())),
+        ("Carol", Value::Null, json!("")),
+    ] {
+        assert!(
+            repository
+                .create_runtime_record(
+                    tenant_id,
+                    "contact",
+                    json!({"name": name, "score": score, "due": due}),
+                    Vec::new(),
+                    "alice",
+                    None,
+                    None,
+                )
+                .await
+                .is_ok()
+        );
+    }
+
+    let names = |operator: RuntimeRecordOperator, field: &str, field_type, value: Value| {
+        let repository = &repository;
+        let field = field.to_owned();
+        async move {
+            let mut names: Vec<String> = repository
+                .query_runtime_records(
+                    tenant_id,
+                    "contact",
+                    RuntimeRecordQuery {
+                        limit: 10,
+                        offset: 0,
+                        logical_mode: RuntimeRecordLogicalMode::And,
+                        where_clause: None,
+                        filters: vec![RuntimeRecordFilter {
+                            scope_alias: None,
+                            field_logical_name: field,
+                            operator,
+                            field_type,
+                            field_value: value,
+                        }],
+                        links: Vec::new(),
+                        sort: Vec::new(),
+                        owner_subject: None,
+                        include_inactive: false,
+                    },
+                )
+                .await
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|record| record.data()["name"].as_str().map(str::to_owned))
+                .collect();
+            names.sort();
+            names
+        }
+    };
+
+    assert_eq!(
+        names(
+            RuntimeRecordOperator::IsNull,
+            "score",
+            FieldType::Number,
+            Value::Null
+        )
+        .await,
+        vec!["Carol"]
+    );
+    assert_eq!(
+        names(
+            RuntimeRecordOperator::IsNotNull,
+            "due",
+            FieldType::Date,
+            Value::Null
+        )
+        .await,
+        vec!["Albert", "Alice", "Bob"]
+    );
+    assert_eq!(
+        names(
+            RuntimeRecordOperator::NotIn,
+            "name",
+            FieldType::Text,
+            json!(["Alice", "Bob"])
+        )
+        .await,
+        vec!["Albert", "Carol"]
+    );
+    assert_eq!(
+        names(
+            RuntimeRecordOperator::Between,
+            "score",
+            FieldType::Number,
+            json!([10, 25])
+        )
+        .await,
+        vec!["Albert", "Alice"]
+    );
+    assert_eq!(
+        names(
+            RuntimeRecordOperator::BeginsWith,
+            "name",
+            FieldType::Text,
+            json!("Al")
+        )
+        .await,
+        vec!["Albert", "Alice"]
+    );
+    assert_eq!(
+        names(
+            RuntimeRecordOperator::Today,
+            "due",
+            FieldType::Date,
+            Value::Null
+        )
+        .await,
+        vec!["Alice"]
+    );
+    assert_eq!(
+        names(
+            RuntimeRecordOperator::Yesterday,
+            "due",
+            FieldType::DateTime,
+            Value::Null
+        )
+        .await,
+        vec!["Albert"]
+    );
+    assert_eq!(
+        names(
+            RuntimeRecordOperator::LastNDays,
+            "due",
+            FieldType::Date,
+            json!(7)
+        )
+        .await,
+        vec!["Albert", "Alice"]
+    );
+}
+
 #[tokio::test]
 async fn query_runtime_records_supports_link_entity_alias_filters_and_where_groups() {
     let repository = InMemoryMetadataRepository::new();
@@ -432,6 +886,7 @@ async fn query_runtime_records_supports_link_entity_alias_filters_and_where_grou
             Vec::new(),
             "alice",
             None,
+            None,
         )
         .await;
     assert!(alice_contact.is_ok());
@@ -445,6 +900,7 @@ async fn query_runtime_records_supports_link_entity_alias_filters_and_where_grou
             Vec::new(),
             "alice",
             None,
+            None,
         )
         .await;
     assert!(bob_contact.is_ok());
@@ -459,6 +915,7 @@ async fn query_runtime_records_supports_link_entity_alias_filters_and_where_grou
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -472,6 +929,7 @@ async fn query_runtime_records_supports_link_entity_alias_filters_and_where_grou
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -502,9 +960,11 @@ async fn query_runtime_records_supports_link_entity_alias_filters_and_where_grou
                     relation_field_logical_name: "owner_contact_id".to_owned(),
                     target_entity_logical_name: "contact".to_owned(),
                     join_type: RuntimeRecordJoinType::Inner,
+                    cardinality: RuntimeRecordLinkCardinality::ManyToOne,
                 }],
                 sort: Vec::new(),
                 owner_subject: None,
+                include_inactive: false,
             },
         )
         .await;
@@ -520,6 +980,154 @@ async fn query_runtime_records_supports_link_entity_alias_filters_and_where_grou
     );
 }
 
+#[tokio::test]
+async fn query_runtime_records_traverses_many_to_many_links_once_per_root() {
+    let repository = InMemoryMetadataRepository::new();
+    let tenant_id = TenantId::new();
+
+    let mut deal_ids = Vec::new();
+    for title in ["Alpha", "Beta", "Gamma"] {
+        let deal = repository
+            .create_runtime_record(
+                tenant_id,
+                "deal",
+                json!({"title": title}),
+                Vec::new(),
+                "alice",
+                None,
+                None,
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        deal_ids.push(deal.record_id().as_str().to_owned());
+    }
+    let mut tag_ids = Vec::new();
+    for name in ["urgent", "vip"] {
+        let tag = repository
+            .create_runtime_record(
+                tenant_id,
+                "tag",
+                json!({"name": name}),
+                Vec::new(),
+                "alice",
+                None,
+                None,
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        tag_ids.push(tag.record_id().as_str().to_owned());
+    }
+
+    let association = |record_id: &str, target_record_id: &str| RuntimeRecordAssociation {
+        entity_logical_name: "deal".to_owned(),
+        field_logical_name: "tags".to_owned(),
+        record_id: record_id.to_owned(),
+        target_entity_logical_name: "tag".to_owned(),
+        target_record_id: target_record_id.to_owned(),
+    };
+    for (deal_index, tag_index) in [(0, 0), (0, 1), (1, 1)] {
+        let created = repository
+            .associate_runtime_records(
+                tenant_id,
+                "alice",
+                association(&deal_ids[deal_index], &tag_ids[tag_index]),
+            )
+            .await;
+        assert!(matches!(created, Ok(true)));
+    }
+    let duplicate = repository
+        .associate_runtime_records(tenant_id, "alice", association(&deal_ids[0], &tag_ids[0]))
+        .await;
+    assert!(matches!(duplicate, Ok(false)));
+
+    let tag_query = |join_type, tag_name: Option<&str>| RuntimeRecordQuery {
+        limit: 50,
+        offset: 0,
+        logical_mode: RuntimeRecordLogicalMode::And,
+        where_clause: None,
+        filters: tag_name
+            .map(|tag_name| {
+                vec![RuntimeRecordFilter {
+                    scope_alias: Some("tag".to_owned()),
+                    field_logical_name: "name".to_owned(),
+                    operator: RuntimeRecordOperator::Eq,
+                    field_type: FieldType::Text,
+                    field_value: json!(tag_name),
+                }]
+            })
+            .unwrap_or_default(),
+        links: vec![RuntimeRecordLink {
+            alias: "tag".to_owned(),
+            parent_alias: None,
+            relation_field_logical_name: "tags".to_owned(),
+            target_entity_logical_name: "tag".to_owned(),
+            join_type,
+            cardinality: RuntimeRecordLinkCardinality::ManyToMany,
+        }],
+        sort: Vec::new(),
+        owner_subject: None,
+        include_inactive: false,
+    };
+
+    let vip_deals = repository
+        .query_runtime_records(
+            tenant_id,
+            "deal",
+            tag_query(RuntimeRecordJoinType::Inner, Some("vip")),
+        )
+        .await
+        .unwrap_or_default();
+    assert_eq!(vip_deals.len(), 2);
+
+    let tagged_deals = repository
+        .query_runtime_records(
+            tenant_id,
+            "deal",
+            tag_query(RuntimeRecordJoinType::Inner, None),
+        )
+        .await
+        .unwrap_or_default();
+    assert_eq!(tagged_deals.len(), 2);
+
+    let all_deals = repository
+        .query_runtime_records(
+            tenant_id,
+            "deal",
+            tag_query(RuntimeRecordJoinType::Left, None),
+        )
+        .await
+        .unwrap_or_default();
+    assert_eq!(all_deals.len(), 3);
+
+    assert!(
+        repository
+            .delete_runtime_record(
+                tenant_id,
+                "tag",
+                &tag_ids[0],
+                None,
+                None,
+                &RelationDeletePlan::default(),
+            )
+            .await
+            .is_ok()
+    );
+    let remaining = repository
+        .list_associated_runtime_record_ids(tenant_id, "deal", "tags", &deal_ids[0])
+        .await
+        .unwrap_or_default();
+    assert_eq!(remaining, vec![tag_ids[1].clone()]);
+
+    let removed = repository
+        .disassociate_runtime_records(tenant_id, &association(&deal_ids[1], &tag_ids[1]))
+        .await;
+    assert!(matches!(removed, Ok(true)));
+    let removed_again = repository
+        .disassociate_runtime_records(tenant_id, &association(&deal_ids[1], &tag_ids[1]))
+        .await;
+    assert!(matches!(removed_again, Ok(false)));
+}
+
 #[tokio::test]
 async fn relation_reference_check_detects_incoming_reference() {
     let repository = InMemoryMetadataRepository::new();
@@ -612,6 +1220,7 @@ async fn relation_reference_check_detects_incoming_reference() {
             Vec::new(),
             "alice",
             None,
+            None,
         )
         .await;
     assert!(contact_record.is_ok());
@@ -625,6 +1234,7 @@ async fn relation_reference_check_detects_incoming_reference() {
             Vec::new(),
             "alice",
             None,
+            None,
         )
         .await;
     assert!(deal_record.is_ok());
@@ -749,6 +1359,7 @@ async fn relation_reference_check_does_not_leak_across_tenants() {
             Vec::new(),
             "alice",
             None,
+            None,
         )
         .await;
     assert!(left_contact_record.is_ok());
@@ -763,6 +1374,7 @@ async fn relation_reference_check_does_not_leak_across_tenants() {
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -787,6 +1399,7 @@ async fn relation_reference_check_does_not_leak_across_tenants() {
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -802,3 +1415,83 @@ async fn relation_reference_check_does_not_leak_across_tenants() {
     assert!(in_tenant_reference.is_ok());
     assert!(in_tenant_reference.unwrap_or(false));
 }
+
+#[tokio::test]
+async fn assign_owner_cascades_only_to_children_owned_by_previous_owner() {
+    let repository = InMemoryMetadataRepository::new();
+    let tenant_id = TenantId::new();
+
+    let account = repository
+        .create_runtime_record(
+            tenant_id,
+            "account",
+            json!({"name": "Contoso"}),
+            Vec::new(),
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let account_id = account.record_id().as_str();
+    let mut children = Vec::new();
+    for owner in ["alice", "carol"] {
+        let child = repository
+            .create_runtime_record(
+                tenant_id,
+                "contact",
+                json!({"account_id": account_id}),
+                Vec::new(),
+                owner,
+                None,
+                None,
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        children.push(child);
+    }
+
+    let cascade = RelationBehavior {
+        entity_logical_name: "contact".to_owned(),
+        field_logical_name: "account_id".to_owned(),
+        target_entity_logical_name: "account".to_owned(),
+        assign_behavior: RelationCascadeBehavior::CascadeUserOwned,
+        share_behavior: RelationCascadeBehavior::None,
+        delete_behavior: RelationDeleteBehavior::Restrict,
+    };
+    assert!(
+        repository
+            .save_relation_behavior(tenant_id, "alice", cascade.clone())
+            .await
+            .is_ok()
+    );
+    assert_eq!(
+        repository
+            .list_relation_behaviors(tenant_id)
+            .await
+            .unwrap_or_default(),
+        vec![cascade.clone()]
+    );
+
+    let (previous_owner, results) = repository
+        .assign_runtime_record_owner(tenant_id, "account", account_id, "bob", &[cascade])
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(previous_owner, "alice");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].affected_records, 1);
+
+    for (child, expected_owner) in children.iter().zip(["bob", "carol"]) {
+        let owned = repository
+            .runtime_record_owned_by_subject(
+                tenant_id,
+                "contact",
+                child.record_id().as_str(),
+                expected_owner,
+            )
+            .await
+            .unwrap_or(false);
+        assert!(owned, "expected child owned by {expected_owner}");
+    }
+}
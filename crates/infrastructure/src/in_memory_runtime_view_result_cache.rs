@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use qryvanta_application::{RuntimeViewCacheKey, RuntimeViewCacheLookup, RuntimeViewResultCache};
+use qryvanta_core::{AppResult, TenantId};
+use qryvanta_domain::RuntimeRecord;
+use tokio::sync::RwLock;
+
+/// Upper bound on live entries so per-subject keys cannot grow without limit.
+const MAX_ENTRIES: usize = 10_000;
+
+#[derive(Debug, Clone)]
+struct ViewResultCacheEntry {
+    records: Vec<RuntimeRecord>,
+    expires_at: Instant,
+}
+
+#[derive(Default)]
+struct ViewResultCacheState {
+    entries: HashMap<RuntimeViewCacheKey, ViewResultCacheEntry>,
+    generations: HashMap<(TenantId, String), i64>,
+}
+
+impl ViewResultCacheState {
+    fn generation(&self, tenant_id: TenantId, entity_logical_name: &str) -> i64 {
+        self.generations
+            .get(&(tenant_id, entity_logical_name.to_owned()))
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+/// In-memory cache adapter for view results.
+#[derive(Default)]
+pub struct InMemoryRuntimeViewResultCache {
+    state: RwLock<ViewResultCacheState>,
+}
+
+impl InMemoryRuntimeViewResultCache {
+    /// Creates an empty in-memory view result cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RuntimeViewResultCache for InMemoryRuntimeViewResultCache {
+    async fn get_view_result(
+        &self,
+        key: &RuntimeViewCacheKey,
+    ) -> AppResult<RuntimeViewCacheLookup> {
+        let state = self.state.read().await;
+        let records = state
+            .entries
+            .get(key)
+            .filter(|entry| entry.expires_at > Instant::now())
+            .map(|entry| entry.records.clone());
+
+        Ok(RuntimeViewCacheLookup {
+            records,
+            generation: state.generation(key.tenant_id, key.entity_logical_name.as_str()),
+        })
+    }
+
+    async fn set_view_result(
+        &self,
+        key: RuntimeViewCacheKey,
+        generation: i64,
+        records: Vec<RuntimeRecord>,
+        ttl_seconds: u32,
+    ) -> AppResult<()> {
+        if ttl_seconds == 0 {
+            return Ok(());
+        }
+
+        let now = Instant::now();
+        let expires_at = now
+            .checked_add(Duration::from_secs(u64::from(ttl_seconds)))
+            .unwrap_or(now);
+
+        let mut state = self.state.write().await;
+        // A write committed while the result was computed; it may be stale.
+        if state.generation(key.tenant_id, key.entity_logical_name.as_str()) != generation {
+            return Ok(());
+        }
+
+        if state.entries.len() >= MAX_ENTRIES {
+            state.entries.retain(|_, entry| entry.expires_at > now);
+            if state.entries.len() >= MAX_ENTRIES {
+                return Ok(());
+            }
+        }
+
+        state.entries.insert(
+            key,
+            ViewResultCacheEntry {
+                records,
+                expires_at,
+            },
+        );
+
+        Ok(())
+    }
+
+    async fn invalidate_entity(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<()> {
+        let mut state = self.state.write().await;
+        *state
+            .generations
+            .entry((tenant_id, entity_logical_name.to_owned()))
+            .or_insert(0) += 1;
+        state.entries.retain(|key, _| {
+            !(key.tenant_id == tenant_id && key.entity_logical_name == entity_logical_name)
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use qryvanta_application::{TokenBucketDecision, TokenBucketRepository};
+use qryvanta_core::AppResult;
+use tokio::sync::Mutex;
+
+use crate::token_bucket::{TokenBucketState, full_refill_ms};
+
+/// Number of tracked buckets after which idle, fully refilled buckets are pruned.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Process-local token bucket adapter used when no shared store is configured.
+#[derive(Default)]
+pub struct InMemoryTokenBucketRepository {
+    buckets: Mutex<HashMap<String, TokenBucketState>>,
+}
+
+impl InMemoryTokenBucketRepository {
+    /// Creates an empty in-memory token bucket repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TokenBucketRepository for InMemoryTokenBucketRepository {
+    async fn take_token(
+        &self,
+        key: &str,
+        capacity: u32,
+        refill_per_second: u32,
+    ) -> AppResult<TokenBucketDecision> {
+        let now_ms = u64::try_from(Utc::now().timestamp_millis()).unwrap_or(0);
+        let mut buckets = self.buckets.lock().await;
+
+        if buckets.len() >= PRUNE_THRESHOLD && !buckets.contains_key(key) {
+            let idle_ms = full_refill_ms(capacity, refill_per_second);
+            buckets.retain(|_, bucket| now_ms.saturating_sub(bucket.updated_at_ms) < idle_ms);
+        }
+
+        let decision = buckets
+            .entry(key.to_owned())
+            .or_insert_with(|| TokenBucketState::full(capacity, now_ms))
+            .take(capacity, refill_per_second, now_ms);
+
+        Ok(decision)
+    }
+}
@@ -2,13 +2,15 @@ use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
-use qryvanta_application::{WorkflowQueueStats, WorkflowQueueStatsCache, WorkflowQueueStatsQuery};
+use qryvanta_application::{
+    WorkflowQueueStatsCache, WorkflowQueueStatsQuery, WorkflowQueueStatsSnapshot,
+};
 use qryvanta_core::AppResult;
 use tokio::sync::RwLock;
 
 #[derive(Debug, Clone, Copy)]
 struct QueueStatsCacheEntry {
-    stats: WorkflowQueueStats,
+    snapshot: WorkflowQueueStatsSnapshot,
     expires_at: Instant,
 }
 
@@ -31,12 +33,12 @@ impl WorkflowQueueStatsCache for InMemoryWorkflowQueueStatsCache {
     async fn get_queue_stats(
         &self,
         query: WorkflowQueueStatsQuery,
-    ) -> AppResult<Option<WorkflowQueueStats>> {
+    ) -> AppResult<Option<WorkflowQueueStatsSnapshot>> {
         {
             let entries = self.entries.read().await;
             if let Some(entry) = entries.get(&query) {
                 if entry.expires_at > Instant::now() {
-                    return Ok(Some(entry.stats));
+                    return Ok(Some(entry.snapshot));
                 }
             } else {
                 return Ok(None);
@@ -57,7 +59,7 @@ impl WorkflowQueueStatsCache for InMemoryWorkflowQueueStatsCache {
     async fn set_queue_stats(
         &self,
         query: WorkflowQueueStatsQuery,
-        stats: WorkflowQueueStats,
+        snapshot: WorkflowQueueStatsSnapshot,
         ttl_seconds: u32,
     ) -> AppResult<()> {
         if ttl_seconds == 0 {
@@ -69,11 +71,19 @@ impl WorkflowQueueStatsCache for InMemoryWorkflowQueueStatsCache {
             .checked_add(Duration::from_secs(u64::from(ttl_seconds)))
             .unwrap_or(now);
 
-        self.entries
-            .write()
-            .await
-            .insert(query, QueueStatsCacheEntry { stats, expires_at });
+        self.entries.write().await.insert(
+            query,
+            QueueStatsCacheEntry {
+                snapshot,
+                expires_at,
+            },
+        );
 
         Ok(())
     }
+
+    async fn invalidate_queue_stats(&self) -> AppResult<()> {
+        self.entries.write().await.clear();
+        Ok(())
+    }
 }
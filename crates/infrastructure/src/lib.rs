@@ -5,64 +5,136 @@
 mod aes_secret_encryptor;
 mod argon2_password_hasher;
 mod audit_chain;
+mod audit_export_shipper;
 mod aws_kms_envelope_secret_encryptor;
 mod console_email_service;
 mod http_workflow_action_dispatcher;
 mod in_memory_extension_repository;
 mod in_memory_metadata_repository;
+mod in_memory_runtime_view_result_cache;
+mod in_memory_token_bucket_repository;
 mod in_memory_workflow_queue_stats_cache;
+mod opentelemetry_tracing;
+mod postgres_announcement_repository;
 mod postgres_app_repository;
+mod postgres_audit_export_repository;
 mod postgres_audit_log_repository;
 mod postgres_audit_repository;
 mod postgres_auth_event_repository;
 mod postgres_auth_token_repository;
 mod postgres_authorization_repository;
+mod postgres_background_job_repository;
+mod postgres_compliance_zone_repository;
+mod postgres_contact_consent_repository;
+mod postgres_contact_identity_repository;
+mod postgres_data_erasure_repository;
+mod postgres_data_validation_repository;
+mod postgres_email_change_repository;
 mod postgres_extension_repository;
+mod postgres_feature_flag_repository;
+mod postgres_field_change_approval_repository;
+mod postgres_field_masking_repository;
+mod postgres_legal_hold_repository;
+mod postgres_localized_label_repository;
 mod postgres_metadata_repository;
+mod postgres_operator_console_repository;
 mod postgres_passkey_repository;
+mod postgres_personal_data_export_repository;
+mod postgres_publish_coordination_repository;
 mod postgres_rate_limit_repository;
+mod postgres_record_access_repository;
+mod postgres_record_import_repository;
+mod postgres_record_share_link_repository;
+mod postgres_report_subscription_repository;
+mod postgres_reporting_projection_repository;
 mod postgres_security_admin_repository;
+mod postgres_tenant_data_export_repository;
+mod postgres_tenant_encryption_key_repository;
 mod postgres_tenant_repository;
 mod postgres_tenant_rls;
 mod postgres_user_repository;
 mod postgres_workflow_repository;
+mod record_import_source_fetcher;
 mod redis_rate_limit_repository;
+mod redis_runtime_view_result_cache;
+mod redis_token_bucket_repository;
 mod redis_workflow_queue_stats_cache;
 mod redis_workflow_worker_lease_coordinator;
+mod secret_reference_tenant_key_provider;
 mod smtp_email_service;
+mod token_bucket;
+mod tokio_operation_timer;
 mod tokio_workflow_delay_service;
 mod totp_provider;
 mod wasm_extension_runtime;
 
 pub use aes_secret_encryptor::AesSecretEncryptor;
 pub use argon2_password_hasher::Argon2PasswordHasher;
+pub use audit_export_shipper::SinkAuditExportShipper;
 pub use aws_kms_envelope_secret_encryptor::AwsKmsEnvelopeSecretEncryptor;
 pub use console_email_service::ConsoleEmailService;
 pub use http_workflow_action_dispatcher::HttpWorkflowActionDispatcher;
 pub use in_memory_extension_repository::InMemoryExtensionRepository;
 pub use in_memory_metadata_repository::InMemoryMetadataRepository;
+pub use in_memory_runtime_view_result_cache::InMemoryRuntimeViewResultCache;
+pub use in_memory_token_bucket_repository::InMemoryTokenBucketRepository;
 pub use in_memory_workflow_queue_stats_cache::InMemoryWorkflowQueueStatsCache;
+pub use opentelemetry_tracing::{
+    OpenTelemetryWorkflowTraceContextSource, TracingGuard, continue_trace, current_trace_context,
+    init_tracing,
+};
+pub use postgres_announcement_repository::PostgresAnnouncementRepository;
 pub use postgres_app_repository::PostgresAppRepository;
+pub use postgres_audit_export_repository::PostgresAuditExportRepository;
 pub use postgres_audit_log_repository::PostgresAuditLogRepository;
 pub use postgres_audit_repository::PostgresAuditRepository;
 pub use postgres_auth_event_repository::PostgresAuthEventRepository;
 pub use postgres_auth_token_repository::PostgresAuthTokenRepository;
 pub use postgres_authorization_repository::PostgresAuthorizationRepository;
+pub use postgres_background_job_repository::PostgresBackgroundJobRepository;
+pub use postgres_compliance_zone_repository::PostgresComplianceZoneRepository;
+pub use postgres_contact_consent_repository::PostgresContactConsentRepository;
+pub use postgres_contact_identity_repository::PostgresContactIdentityRepository;
+pub use postgres_data_erasure_repository::PostgresDataErasureRepository;
+pub use postgres_data_validation_repository::PostgresDataValidationRepository;
+pub use postgres_email_change_repository::PostgresEmailChangeRepository;
 pub use postgres_extension_repository::PostgresExtensionRepository;
+pub use postgres_feature_flag_repository::PostgresFeatureFlagRepository;
+pub use postgres_field_change_approval_repository::PostgresFieldChangeApprovalRepository;
+pub use postgres_field_masking_repository::PostgresFieldMaskingRepository;
+pub use postgres_legal_hold_repository::PostgresLegalHoldRepository;
+pub use postgres_localized_label_repository::PostgresLocalizedLabelRepository;
 pub use postgres_metadata_repository::PostgresMetadataRepository;
-pub use postgres_passkey_repository::PostgresPasskeyRepository;
+pub use postgres_operator_console_repository::PostgresOperatorConsoleRepository;
+pub use postgres_passkey_repository::{PasskeyCredentialRecord, PostgresPasskeyRepository};
+pub use postgres_personal_data_export_repository::PostgresPersonalDataExportRepository;
+pub use postgres_publish_coordination_repository::PostgresPublishCoordinationRepository;
 pub use postgres_rate_limit_repository::PostgresRateLimitRepository;
+pub use postgres_record_access_repository::PostgresRecordAccessRepository;
+pub use postgres_record_import_repository::PostgresRecordImportRepository;
+pub use postgres_record_share_link_repository::PostgresRecordShareLinkRepository;
+pub use postgres_report_subscription_repository::PostgresReportSubscriptionRepository;
+pub use postgres_reporting_projection_repository::PostgresReportingProjectionRepository;
 pub use postgres_security_admin_repository::PostgresSecurityAdminRepository;
+pub use postgres_tenant_data_export_repository::PostgresTenantDataExportRepository;
+pub use postgres_tenant_encryption_key_repository::PostgresTenantEncryptionKeyRepository;
 pub use postgres_tenant_repository::PostgresTenantRepository;
 pub use postgres_tenant_rls::{
     begin_qrywell_sync_transaction, begin_tenant_transaction, begin_workflow_worker_transaction,
 };
 pub use postgres_user_repository::PostgresUserRepository;
 pub use postgres_workflow_repository::PostgresWorkflowRepository;
+pub use record_import_source_fetcher::RemoteRecordImportSourceFetcher;
 pub use redis_rate_limit_repository::RedisRateLimitRepository;
+pub use redis_runtime_view_result_cache::RedisRuntimeViewResultCache;
+pub use redis_token_bucket_repository::RedisTokenBucketRepository;
 pub use redis_workflow_queue_stats_cache::RedisWorkflowQueueStatsCache;
-pub use redis_workflow_worker_lease_coordinator::RedisWorkflowWorkerLeaseCoordinator;
+pub use redis_workflow_worker_lease_coordinator::{
+    RedisWorkflowWorkerLeaseCoordinator, WORKFLOW_WORKER_LEASE_KEY_PREFIX,
+};
+pub use secret_reference_tenant_key_provider::SecretReferenceTenantKeyProvider;
 pub use smtp_email_service::{SmtpEmailConfig, SmtpEmailService};
+pub use tokio_operation_timer::TokioOperationTimer;
 pub use tokio_workflow_delay_service::TokioWorkflowDelayService;
 pub use totp_provider::TotpRsProvider;
 pub use wasm_extension_runtime::WasmExtensionRuntime;
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::env;
+
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::trace::{TraceContextExt, TracerProvider};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use qryvanta_application::WorkflowTraceContextSource;
+use qryvanta_core::{AppError, AppResult};
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+const TRACEPARENT_KEY: &str = "traceparent";
+const OTLP_ENDPOINT_ENV_VARS: [&str; 2] = [
+    "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT",
+    "OTEL_EXPORTER_OTLP_ENDPOINT",
+];
+
+/// Keeps the OTLP span exporter alive and flushes it on drop.
+#[must_use = "dropping the guard shuts down span export"]
+pub struct TracingGuard {
+    tracer_provider: Option<SdkTracerProvider>,
+}
+
+impl Drop for TracingGuard {
+    fn drop(&mut self) {
+        if let Some(tracer_provider) = self.tracer_provider.take()
+            && let Err(error) = tracer_provider.shutdown()
+        {
+            eprintln!("failed to flush OpenTelemetry spans: {error}");
+        }
+    }
+}
+
+/// Installs the global tracing subscriber for a binary.
+///
+/// Events are always logged to stdout. Spans are additionally exported over
+/// OTLP/HTTP when `OTEL_EXPORTER_OTLP_ENDPOINT` or
+/// `OTEL_EXPORTER_OTLP_TRACES_ENDPOINT` is set; `OTEL_SERVICE_NAME` overrides
+/// `service_name` in the exported resource.
+pub fn init_tracing(service_name: &str) -> AppResult<TracingGuard> {
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let tracer_provider = otlp_export_configured()
+        .then(|| build_tracer_provider(service_name))
+        .transpose()?;
+    let otel_layer = tracer_provider.as_ref().map(|tracer_provider| {
+        tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer(service_name.to_owned()))
+    });
+
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_target(false)
+                .compact(),
+        )
+        .with(otel_layer)
+        .try_init()
+        .map_err(|error| {
+            AppError::Internal(format!("failed to install tracing subscriber: {error}"))
+        })?;
+
+    Ok(TracingGuard { tracer_provider })
+}
+
+/// Returns the W3C `traceparent` of the current span.
+///
+/// Returns `None` when the span is not part of an OpenTelemetry trace, for
+/// example because OTLP export is not configured.
+#[must_use]
+pub fn current_trace_context() -> Option<String> {
+    let context = Span::current().context();
+    if !context.span().span_context().is_valid() {
+        return None;
+    }
+
+    let mut carrier = HashMap::new();
+    TraceContextPropagator::new().inject_context(&context, &mut carrier);
+    carrier.remove(TRACEPARENT_KEY)
+}
+
+/// Parents `span` on a W3C `traceparent` so it continues that trace.
+///
+/// Must be called before the span is first entered. Malformed trace contexts
+/// are ignored and the span starts a new trace.
+pub fn continue_trace(span: &Span, trace_context: &str) {
+    let carrier = HashMap::from([(TRACEPARENT_KEY.to_owned(), trace_context.to_owned())]);
+    let parent = TraceContextPropagator::new().extract(&carrier);
+    if parent.span().span_context().is_valid() {
+        // Fails only when the span is disabled or OTLP export is not configured.
+        let _ = span.set_parent(parent);
+    }
+}
+
+/// Workflow trace context source backed by the current tracing span.
+pub struct OpenTelemetryWorkflowTraceContextSource;
+
+impl WorkflowTraceContextSource for OpenTelemetryWorkflowTraceContextSource {
+    fn current_trace_context(&self) -> Option<String> {
+        current_trace_context()
+    }
+}
+
+fn otlp_export_configured() -> bool {
+    OTLP_ENDPOINT_ENV_VARS.iter().any(|name| {
+        env::var(name)
+            .map(|value| !value.trim().is_empty())
+            .unwrap_or(false)
+    })
+}
+
+fn build_tracer_provider(service_name: &str) -> AppResult<SdkTracerProvider> {
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .build()
+        .map_err(|error| {
+            AppError::Internal(format!("failed to build OTLP span exporter: {error}"))
+        })?;
+
+    let mut resource = Resource::builder();
+    if env::var_os("OTEL_SERVICE_NAME").is_none() {
+        resource = resource.with_service_name(service_name.to_owned());
+    }
+
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(resource.build())
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry::trace::TracerProvider;
+    use opentelemetry_sdk::trace::SdkTracerProvider;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::{continue_trace, current_trace_context};
+
+    const PARENT_TRACE_CONTEXT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    fn with_tracing<T>(run: impl FnOnce() -> T) -> T {
+        let tracer_provider = SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer("test")));
+        tracing::subscriber::with_default(subscriber, run)
+    }
+
+    #[test]
+    fn continued_trace_is_propagated_from_child_spans() {
+        let trace_context = with_tracing(|| {
+            let span = tracing::info_span!("workflow.job");
+            continue_trace(&span, PARENT_TRACE_CONTEXT);
+            span.in_scope(|| tracing::info_span!("workflow.step").in_scope(current_trace_context))
+        })
+        .unwrap_or_else(|| unreachable!());
+
+        assert!(trace_context.starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+        assert!(trace_context.ends_with("-01"));
+        assert_ne!(trace_context, PARENT_TRACE_CONTEXT);
+    }
+
+    #[test]
+    fn malformed_trace_context_starts_a_new_trace() {
+        let trace_context = with_tracing(|| {
+            let span = tracing::info_span!("workflow.job");
+            continue_trace(&span, "not-a-traceparent");
+            span.in_scope(current_trace_context)
+        })
+        .unwrap_or_else(|| unreachable!());
+
+        assert!(!trace_context.contains("4bf92f3577b34da6a3ce929d0e0e4736"));
+    }
+
+    #[test]
+    fn trace_context_is_absent_without_opentelemetry_layer() {
+        let span = tracing::info_span!("workflow.job");
+        continue_trace(&span, PARENT_TRACE_CONTEXT);
+
+        assert_eq!(span.in_scope(current_trace_context), None);
+    }
+}
@@ -0,0 +1,333 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use qryvanta_application::{
+    Announcement, AnnouncementRepository, AnnouncementSeverity, SaveAnnouncementInput,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+#[cfg(test)]
+mod tests;
+
+/// PostgreSQL-backed repository for tenant announcement banners.
+#[derive(Clone)]
+pub struct PostgresAnnouncementRepository {
+    pool: PgPool,
+}
+
+impl PostgresAnnouncementRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct AnnouncementRow {
+    id: Uuid,
+    title: String,
+    body: String,
+    severity: String,
+    starts_at: DateTime<Utc>,
+    ends_at: Option<DateTime<Utc>>,
+    audience_roles: Vec<String>,
+    audience_apps: Vec<String>,
+    is_dismissible: bool,
+    created_by_subject: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<AnnouncementRow> for Announcement {
+    type Error = AppError;
+
+    fn try_from(row: AnnouncementRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            announcement_id: row.id.to_string(),
+            title: row.title,
+            body: row.body,
+            severity: AnnouncementSeverity::parse(row.severity.as_str())?,
+            starts_at: row.starts_at,
+            ends_at: row.ends_at,
+            audience_roles: row.audience_roles,
+            audience_apps: row.audience_apps,
+            is_dismissible: row.is_dismissible,
+            created_by_subject: row.created_by_subject,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+const ANNOUNCEMENT_COLUMNS: &str = r#"
+    id,
+    title,
+    body,
+    severity,
+    starts_at,
+    ends_at,
+    audience_roles,
+    audience_apps,
+    is_dismissible,
+    created_by_subject,
+    created_at,
+    updated_at
+"#;
+
+fn commit_error(error: sqlx::Error) -> AppError {
+    AppError::Internal(format!(
+        "failed to commit announcement transaction: {error}"
+    ))
+}
+
+#[async_trait]
+impl AnnouncementRepository for PostgresAnnouncementRepository {
+    async fn create_announcement(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        input: SaveAnnouncementInput,
+    ) -> AppResult<Announcement> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, AnnouncementRow>(&format!(
+            r#"
+            INSERT INTO tenant_announcements (
+                tenant_id,
+                title,
+                body,
+                severity,
+                starts_at,
+                ends_at,
+                audience_roles,
+                audience_apps,
+                is_dismissible,
+                created_by_subject
+            )
+            VALUES ($1, $2, $3, $4, COALESCE($5, now()), $6, $7, $8, $9, $10)
+            RETURNING {ANNOUNCEMENT_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(input.title)
+        .bind(input.body)
+        .bind(input.severity.as_str())
+        .bind(input.starts_at)
+        .bind(input.ends_at)
+        .bind(input.audience_roles)
+        .bind(input.audience_apps)
+        .bind(input.is_dismissible)
+        .bind(created_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to create announcement: {error}")))?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        Announcement::try_from(row)
+    }
+
+    async fn update_announcement(
+        &self,
+        tenant_id: TenantId,
+        announcement_id: &str,
+        input: SaveAnnouncementInput,
+    ) -> AppResult<Option<Announcement>> {
+        let Ok(announcement_uuid) = Uuid::parse_str(announcement_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, AnnouncementRow>(&format!(
+            r#"
+            UPDATE tenant_announcements
+            SET title = $3,
+                body = $4,
+                severity = $5,
+                starts_at = COALESCE($6, starts_at),
+                ends_at = $7,
+                audience_roles = $8,
+                audience_apps = $9,
+                is_dismissible = $10,
+                updated_at = now()
+            WHERE tenant_id = $1 AND id = $2
+            RETURNING {ANNOUNCEMENT_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(announcement_uuid)
+        .bind(input.title)
+        .bind(input.body)
+        .bind(input.severity.as_str())
+        .bind(input.starts_at)
+        .bind(input.ends_at)
+        .bind(input.audience_roles)
+        .bind(input.audience_apps)
+        .bind(input.is_dismissible)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to update announcement: {error}")))?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        row.map(Announcement::try_from).transpose()
+    }
+
+    async fn delete_announcement(
+        &self,
+        tenant_id: TenantId,
+        announcement_id: &str,
+    ) -> AppResult<bool> {
+        let Ok(announcement_uuid) = Uuid::parse_str(announcement_id) else {
+            return Ok(false);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM tenant_announcements
+            WHERE tenant_id = $1 AND id = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(announcement_uuid)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to delete announcement: {error}")))?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_announcement(
+        &self,
+        tenant_id: TenantId,
+        announcement_id: &str,
+    ) -> AppResult<Option<Announcement>> {
+        let Ok(announcement_uuid) = Uuid::parse_str(announcement_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, AnnouncementRow>(&format!(
+            r#"
+            SELECT {ANNOUNCEMENT_COLUMNS}
+            FROM tenant_announcements
+            WHERE tenant_id = $1 AND id = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(announcement_uuid)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to find announcement: {error}")))?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        row.map(Announcement::try_from).transpose()
+    }
+
+    async fn list_announcements(&self, tenant_id: TenantId) -> AppResult<Vec<Announcement>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, AnnouncementRow>(&format!(
+            r#"
+            SELECT {ANNOUNCEMENT_COLUMNS}
+            FROM tenant_announcements
+            WHERE tenant_id = $1
+            ORDER BY starts_at DESC, id DESC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list announcements: {error}")))?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.into_iter().map(Announcement::try_from).collect()
+    }
+
+    async fn list_live_announcements_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        now: DateTime<Utc>,
+    ) -> AppResult<Vec<Announcement>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, AnnouncementRow>(&format!(
+            r#"
+            SELECT {ANNOUNCEMENT_COLUMNS}
+            FROM tenant_announcements announcement
+            WHERE announcement.tenant_id = $1
+              AND announcement.starts_at <= $3
+              AND (announcement.ends_at IS NULL OR announcement.ends_at > $3)
+              AND NOT (
+                  announcement.is_dismissible
+                  AND EXISTS (
+                      SELECT 1
+                      FROM tenant_announcement_dismissals dismissal
+                      WHERE dismissal.announcement_id = announcement.id
+                        AND dismissal.subject = $2
+                  )
+              )
+            ORDER BY announcement.starts_at ASC, announcement.id ASC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .bind(now)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list live announcements: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.into_iter().map(Announcement::try_from).collect()
+    }
+
+    async fn dismiss_announcement(
+        &self,
+        tenant_id: TenantId,
+        announcement_id: &str,
+        subject: &str,
+        dismissed_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        let announcement_uuid = Uuid::parse_str(announcement_id).map_err(|_| {
+            AppError::NotFound(format!("announcement '{announcement_id}' does not exist"))
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO tenant_announcement_dismissals (
+                announcement_id,
+                tenant_id,
+                subject,
+                dismissed_at
+            )
+            SELECT id, tenant_id, $3, $4
+            FROM tenant_announcements
+            WHERE tenant_id = $1 AND id = $2
+            ON CONFLICT (announcement_id, subject) DO NOTHING
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(announcement_uuid)
+        .bind(subject)
+        .bind(dismissed_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to dismiss announcement: {error}")))?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+use chrono::{Duration, Utc};
+use qryvanta_application::{AnnouncementRepository, AnnouncementSeverity, SaveAnnouncementInput};
+use qryvanta_core::TenantId;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresAnnouncementRepository;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres announcement tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn ensure_tenant(pool: &PgPool, tenant_id: TenantId, name: &str) {
+    let insert = sqlx::query(
+        r#"
+            INSERT INTO tenants (id, name)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(name)
+    .execute(pool)
+    .await;
+
+    assert!(insert.is_ok());
+}
+
+fn announcement_input(title: &str) -> SaveAnnouncementInput {
+    SaveAnnouncementInput {
+        title: title.to_owned(),
+        body: "Planned maintenance on Saturday.".to_owned(),
+        severity: AnnouncementSeverity::Warning,
+        starts_at: None,
+        ends_at: None,
+        audience_roles: vec!["Sales".to_owned()],
+        audience_apps: Vec::new(),
+        is_dismissible: true,
+    }
+}
+
+#[tokio::test]
+async fn announcements_schedule_dismiss_and_stay_tenant_scoped() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Announcement Tenant").await;
+    ensure_tenant(&pool, other_tenant_id, "Other Announcement Tenant").await;
+    let repository = PostgresAnnouncementRepository::new(pool);
+    let now = Utc::now();
+
+    let live = repository
+        .create_announcement(tenant_id, "alice", announcement_input("Live"))
+        .await
+        .unwrap_or_else(|error| panic!("failed to create announcement: {error}"));
+    assert_eq!(live.audience_roles, vec!["Sales".to_owned()]);
+    assert!(live.starts_at <= Utc::now());
+    repository
+        .create_announcement(
+            tenant_id,
+            "alice",
+            SaveAnnouncementInput {
+                starts_at: Some(now + Duration::days(1)),
+                ..announcement_input("Scheduled")
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to create announcement: {error}"));
+
+    assert_eq!(
+        repository
+            .list_announcements(tenant_id)
+            .await
+            .unwrap_or_else(|error| panic!("failed to list announcements: {error}"))
+            .len(),
+        2
+    );
+    assert!(
+        repository
+            .list_announcements(other_tenant_id)
+            .await
+            .unwrap_or_else(|error| panic!("failed to list announcements: {error}"))
+            .is_empty()
+    );
+    assert!(
+        repository
+            .find_announcement(other_tenant_id, live.announcement_id.as_str())
+            .await
+            .unwrap_or_else(|error| panic!("failed to find announcement: {error}"))
+            .is_none()
+    );
+
+    let live_titles = |announcements: Vec<qryvanta_application::Announcement>| {
+        announcements
+            .into_iter()
+            .map(|announcement| announcement.title)
+            .collect::<Vec<_>>()
+    };
+    let visible = repository
+        .list_live_announcements_for_subject(tenant_id, "bob", Utc::now())
+        .await
+        .unwrap_or_else(|error| panic!("failed to list live announcements: {error}"));
+    assert_eq!(live_titles(visible), vec!["Live".to_owned()]);
+
+    for _ in 0..2 {
+        repository
+            .dismiss_announcement(tenant_id, live.announcement_id.as_str(), "bob", Utc::now())
+            .await
+            .unwrap_or_else(|error| panic!("failed to dismiss announcement: {error}"));
+    }
+    assert!(
+        repository
+            .list_live_announcements_for_subject(tenant_id, "bob", Utc::now())
+            .await
+            .unwrap_or_else(|error| panic!("failed to list live announcements: {error}"))
+            .is_empty()
+    );
+
+    // Making a dismissed banner mandatory shows it again.
+    let updated = repository
+        .update_announcement(
+            tenant_id,
+            live.announcement_id.as_str(),
+            SaveAnnouncementInput {
+                is_dismissible: false,
+                ..announcement_input("Live, mandatory")
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to update announcement: {error}"))
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(updated.starts_at, live.starts_at);
+    let visible = repository
+        .list_live_announcements_for_subject(tenant_id, "bob", Utc::now())
+        .await
+        .unwrap_or_else(|error| panic!("failed to list live announcements: {error}"));
+    assert_eq!(live_titles(visible), vec!["Live, mandatory".to_owned()]);
+
+    assert!(
+        repository
+            .update_announcement(
+                other_tenant_id,
+                live.announcement_id.as_str(),
+                announcement_input("Hijacked"),
+            )
+            .await
+            .unwrap_or_else(|error| panic!("failed to update announcement: {error}"))
+            .is_none()
+    );
+    assert!(
+        !repository
+            .delete_announcement(other_tenant_id, live.announcement_id.as_str())
+            .await
+            .unwrap_or_else(|error| panic!("failed to delete announcement: {error}"))
+    );
+    assert!(
+        repository
+            .delete_announcement(tenant_id, live.announcement_id.as_str())
+            .await
+            .unwrap_or_else(|error| panic!("failed to delete announcement: {error}"))
+    );
+    assert!(
+        !repository
+            .delete_announcement(tenant_id, "not-an-announcement")
+            .await
+            .unwrap_or_else(|error| panic!("failed to delete announcement: {error}"))
+    );
+}
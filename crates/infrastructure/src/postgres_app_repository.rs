@@ -1,11 +1,11 @@
 use async_trait::async_trait;
 
 use crate::begin_tenant_transaction;
-use qryvanta_application::{AppRepository, SubjectEntityPermission};
+use qryvanta_application::{AppAdminGrant, AppRepository, PersonalView, SubjectEntityPermission};
 use qryvanta_core::{AppError, AppResult, TenantId};
 use qryvanta_domain::{
     AppDefinition, AppEntityBinding, AppEntityForm, AppEntityRolePermission, AppEntityView,
-    AppEntityViewMode, AppSitemap,
+    AppEntityViewMode, AppSitemap, ViewColumn, ViewFilterGroup, ViewSort,
 };
 use serde::{Deserialize, Serialize};
 use sqlx::types::Json;
@@ -93,9 +93,32 @@ struct AppSitemapRow {
     definition_json: serde_json::Value,
 }
 
+#[derive(Debug, FromRow)]
+struct PersonalViewRow {
+    id: uuid::Uuid,
+    app_logical_name: String,
+    entity_logical_name: String,
+    owner_subject: String,
+    display_name: String,
+    columns: Json<Vec<ViewColumn>>,
+    default_sort: Option<Json<ViewSort>>,
+    filter_criteria: Option<Json<ViewFilterGroup>>,
+    is_shared: bool,
+}
+
+#[derive(Debug, FromRow)]
+struct AppAdminGrantRow {
+    app_logical_name: String,
+    subject: String,
+    granted_by_subject: String,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+mod admins;
 mod bindings;
 mod definitions;
 mod permissions;
+mod personal_views;
 mod sitemap;
 
 #[async_trait]
@@ -133,6 +156,16 @@ impl AppRepository for PostgresAppRepository {
             .await
     }
 
+    async fn delete_app_entity_binding(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        self.delete_app_entity_binding_impl(tenant_id, app_logical_name, entity_logical_name)
+            .await
+    }
+
     async fn save_sitemap(&self, tenant_id: TenantId, sitemap: AppSitemap) -> AppResult<()> {
         self.save_sitemap_impl(tenant_id, sitemap).await
     }
@@ -206,6 +239,79 @@ impl AppRepository for PostgresAppRepository {
         self.list_subject_entity_permissions_impl(tenant_id, subject, app_logical_name)
             .await
     }
+
+    async fn save_personal_view(&self, tenant_id: TenantId, view: PersonalView) -> AppResult<()> {
+        self.save_personal_view_impl(tenant_id, view).await
+    }
+
+    async fn find_personal_view(
+        &self,
+        tenant_id: TenantId,
+        view_id: &str,
+    ) -> AppResult<Option<PersonalView>> {
+        self.find_personal_view_impl(tenant_id, view_id).await
+    }
+
+    async fn list_personal_views(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+        subject: &str,
+    ) -> AppResult<Vec<PersonalView>> {
+        self.list_personal_views_impl(tenant_id, app_logical_name, entity_logical_name, subject)
+            .await
+    }
+
+    async fn list_entity_personal_views(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<PersonalView>> {
+        self.list_entity_personal_views_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn delete_personal_view(&self, tenant_id: TenantId, view_id: &str) -> AppResult<bool> {
+        self.delete_personal_view_impl(tenant_id, view_id).await
+    }
+
+    async fn grant_app_admin(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        subject: &str,
+        granted_by_subject: &str,
+    ) -> AppResult<AppAdminGrant> {
+        self.grant_app_admin_impl(tenant_id, app_logical_name, subject, granted_by_subject)
+            .await
+    }
+
+    async fn revoke_app_admin(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        subject: &str,
+    ) -> AppResult<bool> {
+        self.revoke_app_admin_impl(tenant_id, app_logical_name, subject)
+            .await
+    }
+
+    async fn list_app_admins(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+    ) -> AppResult<Vec<AppAdminGrant>> {
+        self.list_app_admins_impl(tenant_id, app_logical_name).await
+    }
+
+    async fn list_administered_apps(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<String>> {
+        self.list_administered_apps_impl(tenant_id, subject).await
+    }
 }
 
 fn app_entity_view_mode_from_str(value: &str) -> AppResult<AppEntityViewMode> {
@@ -0,0 +1,177 @@
+use super::*;
+
+impl PostgresAppRepository {
+    pub(super) async fn grant_app_admin_impl(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        subject: &str,
+        granted_by_subject: &str,
+    ) -> AppResult<AppAdminGrant> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO app_admin_grants (
+                tenant_id,
+                app_logical_name,
+                subject,
+                granted_by_subject
+            )
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (tenant_id, app_logical_name, subject) DO NOTHING
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(app_logical_name)
+        .bind(subject)
+        .bind(granted_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to grant administration of app '{}' to subject '{}': {error}",
+                app_logical_name, subject
+            ))
+        })?;
+
+        let row = sqlx::query_as::<_, AppAdminGrantRow>(
+            r#"
+            SELECT app_logical_name, subject, granted_by_subject, created_at
+            FROM app_admin_grants
+            WHERE tenant_id = $1 AND app_logical_name = $2 AND subject = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(app_logical_name)
+        .bind(subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to load administration grant of app '{}' for subject '{}': {error}",
+                app_logical_name, subject
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped app admin grant transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.into())
+    }
+
+    pub(super) async fn revoke_app_admin_impl(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        subject: &str,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM app_admin_grants
+            WHERE tenant_id = $1 AND app_logical_name = $2 AND subject = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(app_logical_name)
+        .bind(subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to revoke administration of app '{}' from subject '{}': {error}",
+                app_logical_name, subject
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped app admin revoke transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub(super) async fn list_app_admins_impl(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+    ) -> AppResult<Vec<AppAdminGrant>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, AppAdminGrantRow>(
+            r#"
+            SELECT app_logical_name, subject, granted_by_subject, created_at
+            FROM app_admin_grants
+            WHERE tenant_id = $1 AND app_logical_name = $2
+            ORDER BY subject
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(app_logical_name)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list administrators of app '{}': {error}",
+                app_logical_name
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped app admin list transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(AppAdminGrant::from).collect())
+    }
+
+    pub(super) async fn list_administered_apps_impl(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<String>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let app_logical_names = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT app_logical_name
+            FROM app_admin_grants
+            WHERE tenant_id = $1 AND subject = $2
+            ORDER BY app_logical_name
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list apps administered by subject '{}': {error}",
+                subject
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped administered apps transaction: {error}"
+            ))
+        })?;
+
+        Ok(app_logical_names)
+    }
+}
+
+impl From<AppAdminGrantRow> for AppAdminGrant {
+    fn from(row: AppAdminGrantRow) -> Self {
+        Self {
+            app_logical_name: row.app_logical_name,
+            subject: row.subject,
+            granted_by_subject: row.granted_by_subject,
+            created_at: row.created_at,
+        }
+    }
+}
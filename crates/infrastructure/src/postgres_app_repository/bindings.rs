@@ -89,6 +89,39 @@ impl PostgresAppRepository {
         Ok(())
     }
 
+    pub(super) async fn delete_app_entity_binding_impl(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM app_entity_bindings
+            WHERE tenant_id = $1 AND app_logical_name = $2 AND entity_logical_name = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(app_logical_name)
+        .bind(entity_logical_name)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete app entity binding '{}.{}' for tenant '{}': {error}",
+                app_logical_name, entity_logical_name, tenant_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped app binding delete transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     pub(super) async fn list_app_entity_bindings_impl(
         &self,
         tenant_id: TenantId,
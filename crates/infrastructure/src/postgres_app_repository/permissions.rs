@@ -178,7 +178,10 @@ impl PostgresAppRepository {
             INNER JOIN rbac_subject_roles subject_roles
                 ON subject_roles.role_id = app_role.role_id
                 AND subject_roles.tenant_id = app.tenant_id
-            WHERE app.tenant_id = $1 AND subject_roles.subject = $2
+            WHERE app.tenant_id = $1
+              AND (subject_roles.subject_type, subject_roles.subject) IN (
+                  SELECT subject_type, subject FROM qryvanta_subject_principals($1, $2)
+              )
             ORDER BY app.display_name, app.logical_name
             "#,
         )
@@ -220,7 +223,9 @@ impl PostgresAppRepository {
                     AND subject_roles.tenant_id = app_role.tenant_id
                 WHERE app_role.tenant_id = $1
                   AND app_role.app_logical_name = $2
-                  AND subject_roles.subject = $3
+                  AND (subject_roles.subject_type, subject_roles.subject) IN (
+                      SELECT subject_type, subject FROM qryvanta_subject_principals($1, $3)
+                  )
             )
             "#,
         )
@@ -267,7 +272,9 @@ impl PostgresAppRepository {
             WHERE p.tenant_id = $1
               AND p.app_logical_name = $2
               AND p.entity_logical_name = $3
-              AND subject_roles.subject = $4
+              AND (subject_roles.subject_type, subject_roles.subject) IN (
+                  SELECT subject_type, subject FROM qryvanta_subject_principals($1, $4)
+              )
             "#,
         )
         .bind(tenant_id.as_uuid())
@@ -322,7 +329,9 @@ impl PostgresAppRepository {
                 AND subject_roles.tenant_id = p.tenant_id
             WHERE p.tenant_id = $1
               AND p.app_logical_name = $2
-              AND subject_roles.subject = $3
+              AND (subject_roles.subject_type, subject_roles.subject) IN (
+                  SELECT subject_type, subject FROM qryvanta_subject_principals($1, $3)
+              )
             GROUP BY p.entity_logical_name
             ORDER BY p.entity_logical_name
             "#,
@@ -0,0 +1,258 @@
+use super::*;
+
+impl PostgresAppRepository {
+    pub(super) async fn save_personal_view_impl(
+        &self,
+        tenant_id: TenantId,
+        view: PersonalView,
+    ) -> AppResult<()> {
+        let view_uuid = uuid::Uuid::parse_str(view.view_id.as_str()).map_err(|error| {
+            AppError::Validation(format!(
+                "invalid personal view id '{}': {error}",
+                view.view_id
+            ))
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO app_personal_views (
+                id,
+                tenant_id,
+                app_logical_name,
+                entity_logical_name,
+                owner_subject,
+                display_name,
+                columns,
+                default_sort,
+                filter_criteria,
+                is_shared,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, now())
+            ON CONFLICT (id)
+            DO UPDATE SET
+                display_name = EXCLUDED.display_name,
+                columns = EXCLUDED.columns,
+                default_sort = EXCLUDED.default_sort,
+                filter_criteria = EXCLUDED.filter_criteria,
+                is_shared = EXCLUDED.is_shared,
+                updated_at = now()
+            "#,
+        )
+        .bind(view_uuid)
+        .bind(tenant_id.as_uuid())
+        .bind(view.app_logical_name.as_str())
+        .bind(view.entity_logical_name.as_str())
+        .bind(view.owner_subject.as_str())
+        .bind(view.display_name.as_str())
+        .bind(Json(&view.columns))
+        .bind(view.default_sort.as_ref().map(Json))
+        .bind(view.filter_criteria.as_ref().map(Json))
+        .bind(view.is_shared)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to save personal view '{}' in tenant '{}': {error}",
+                view.view_id, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped personal view save transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    pub(super) async fn find_personal_view_impl(
+        &self,
+        tenant_id: TenantId,
+        view_id: &str,
+    ) -> AppResult<Option<PersonalView>> {
+        let Ok(view_uuid) = uuid::Uuid::parse_str(view_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, PersonalViewRow>(
+            r#"
+            SELECT
+                id,
+                app_logical_name,
+                entity_logical_name,
+                owner_subject,
+                display_name,
+                columns,
+                default_sort,
+                filter_criteria,
+                is_shared
+            FROM app_personal_views
+            WHERE tenant_id = $1 AND id = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(view_uuid)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find personal view '{}' in tenant '{}': {error}",
+                view_id, tenant_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped personal view lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(PersonalView::from))
+    }
+
+    pub(super) async fn list_personal_views_impl(
+        &self,
+        tenant_id: TenantId,
+        app_logical_name: &str,
+        entity_logical_name: &str,
+        subject: &str,
+    ) -> AppResult<Vec<PersonalView>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, PersonalViewRow>(
+            r#"
+            SELECT
+                id,
+                app_logical_name,
+                entity_logical_name,
+                owner_subject,
+                display_name,
+                columns,
+                default_sort,
+                filter_criteria,
+                is_shared
+            FROM app_personal_views
+            WHERE tenant_id = $1
+              AND app_logical_name = $2
+              AND entity_logical_name = $3
+              AND (owner_subject = $4 OR is_shared)
+            ORDER BY display_name, created_at
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(app_logical_name)
+        .bind(entity_logical_name)
+        .bind(subject)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list personal views for entity '{}' in app '{}': {error}",
+                entity_logical_name, app_logical_name
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped personal view list transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(PersonalView::from).collect())
+    }
+
+    pub(super) async fn list_entity_personal_views_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<PersonalView>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, PersonalViewRow>(
+            r#"
+            SELECT
+                id,
+                app_logical_name,
+                entity_logical_name,
+                owner_subject,
+                display_name,
+                columns,
+                default_sort,
+                filter_criteria,
+                is_shared
+            FROM app_personal_views
+            WHERE tenant_id = $1
+              AND entity_logical_name = $2
+            ORDER BY app_logical_name, display_name, created_at
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list personal views for entity '{}': {error}",
+                entity_logical_name
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped personal view list transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(PersonalView::from).collect())
+    }
+
+    pub(super) async fn delete_personal_view_impl(
+        &self,
+        tenant_id: TenantId,
+        view_id: &str,
+    ) -> AppResult<bool> {
+        let Ok(view_uuid) = uuid::Uuid::parse_str(view_id) else {
+            return Ok(false);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM app_personal_views
+            WHERE tenant_id = $1 AND id = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(view_uuid)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete personal view '{}' in tenant '{}': {error}",
+                view_id, tenant_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped personal view delete transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+impl From<PersonalViewRow> for PersonalView {
+    fn from(row: PersonalViewRow) -> Self {
+        Self {
+            view_id: row.id.to_string(),
+            app_logical_name: row.app_logical_name,
+            entity_logical_name: row.entity_logical_name,
+            owner_subject: row.owner_subject,
+            display_name: row.display_name,
+            columns: row.columns.0,
+            default_sort: row.default_sort.map(|value| value.0),
+            filter_criteria: row.filter_criteria.map(|value| value.0),
+            is_shared: row.is_shared,
+        }
+    }
+}
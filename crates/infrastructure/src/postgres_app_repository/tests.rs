@@ -1,7 +1,9 @@
-use qryvanta_application::AppRepository;
+use qryvanta_application::{AppRepository, PersonalView};
 use qryvanta_core::TenantId;
 use qryvanta_domain::{
     AppDefinition, AppEntityBinding, AppEntityForm, AppEntityView, AppEntityViewMode,
+    FilterOperator, LogicalMode, SortDirection, ViewColumn, ViewFilterCondition, ViewFilterGroup,
+    ViewSort,
 };
 use sqlx::PgPool;
 use sqlx::migrate::Migrator;
@@ -213,3 +215,164 @@ async fn list_bindings_backfills_model_driven_surfaces_from_legacy_columns() {
         ["name".to_owned(), "email".to_owned()]
     );
 }
+
+#[tokio::test]
+async fn personal_views_round_trip_and_respect_sharing() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresAppRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Personal View Tenant").await;
+    let create_app = repository
+        .create_app(
+            tenant_id,
+            AppDefinition::new("sales", "Sales", None).unwrap_or_else(|_| unreachable!()),
+        )
+        .await;
+    assert!(create_app.is_ok());
+
+    let private_view = PersonalView {
+        view_id: uuid::Uuid::new_v4().to_string(),
+        app_logical_name: "sales".to_owned(),
+        entity_logical_name: "account".to_owned(),
+        owner_subject: "alice".to_owned(),
+        display_name: "My accounts".to_owned(),
+        columns: vec![ViewColumn::new("name", 0, None, None).unwrap_or_else(|_| unreachable!())],
+        default_sort: Some(
+            ViewSort::new("name", SortDirection::Desc).unwrap_or_else(|_| unreachable!()),
+        ),
+        filter_criteria: Some(
+            ViewFilterGroup::new(
+                LogicalMode::And,
+                vec![
+                    ViewFilterCondition::new("owner", FilterOperator::Eq, "alice".into())
+                        .unwrap_or_else(|_| unreachable!()),
+                ],
+            )
+            .unwrap_or_else(|_| unreachable!()),
+        ),
+        is_shared: false,
+    };
+    let shared_view = PersonalView {
+        view_id: uuid::Uuid::new_v4().to_string(),
+        owner_subject: "bob".to_owned(),
+        display_name: "Team accounts".to_owned(),
+        default_sort: None,
+        filter_criteria: None,
+        is_shared: true,
+        ..private_view.clone()
+    };
+    for view in [private_view.clone(), shared_view.clone()] {
+        assert!(repository.save_personal_view(tenant_id, view).await.is_ok());
+    }
+
+    let found = repository
+        .find_personal_view(tenant_id, private_view.view_id.as_str())
+        .await
+        .unwrap_or_default();
+    assert_eq!(found, Some(private_view.clone()));
+
+    let alice_views = repository
+        .list_personal_views(tenant_id, "sales", "account", "alice")
+        .await
+        .unwrap_or_default();
+    assert_eq!(alice_views, vec![private_view.clone(), shared_view.clone()]);
+    let carol_views = repository
+        .list_personal_views(tenant_id, "sales", "account", "carol")
+        .await
+        .unwrap_or_default();
+    assert_eq!(carol_views, vec![shared_view.clone()]);
+    let entity_views = repository
+        .list_entity_personal_views(tenant_id, "account")
+        .await
+        .unwrap_or_default();
+    assert_eq!(entity_views, vec![private_view.clone(), shared_view]);
+
+    assert_eq!(
+        repository
+            .delete_personal_view(tenant_id, private_view.view_id.as_str())
+            .await
+            .ok(),
+        Some(true)
+    );
+    assert_eq!(
+        repository
+            .delete_personal_view(tenant_id, private_view.view_id.as_str())
+            .await
+            .ok(),
+        Some(false)
+    );
+}
+
+#[tokio::test]
+async fn app_admin_grants_round_trip_and_keep_original_grantor() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresAppRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "App Admin Tenant").await;
+    for (logical_name, display_name) in [("sales", "Sales"), ("service", "Service")] {
+        let create_app = repository
+            .create_app(
+                tenant_id,
+                AppDefinition::new(logical_name, display_name, None)
+                    .unwrap_or_else(|_| unreachable!()),
+            )
+            .await;
+        assert!(create_app.is_ok());
+    }
+
+    let grant = repository
+        .grant_app_admin(tenant_id, "sales", "maker", "admin")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(grant.granted_by_subject, "admin");
+
+    let repeated = repository
+        .grant_app_admin(tenant_id, "sales", "maker", "other-admin")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(repeated, grant);
+
+    assert!(
+        repository
+            .grant_app_admin(tenant_id, "service", "maker", "admin")
+            .await
+            .is_ok()
+    );
+
+    let administered = repository
+        .list_administered_apps(tenant_id, "maker")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(administered, vec!["sales".to_owned(), "service".to_owned()]);
+
+    let admins = repository
+        .list_app_admins(tenant_id, "sales")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(admins, vec![grant]);
+
+    let revoked = repository
+        .revoke_app_admin(tenant_id, "sales", "maker")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(revoked);
+    let revoked_again = repository
+        .revoke_app_admin(tenant_id, "sales", "maker")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(!revoked_again);
+
+    let other_tenant = TenantId::new();
+    ensure_tenant(&pool, other_tenant, "Other App Admin Tenant").await;
+    let leaked = repository
+        .list_administered_apps(other_tenant, "maker")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(leaked.is_empty());
+}
@@ -0,0 +1,444 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    AuditExportSink, AuditExportSinkConfig, AuditExportSinkRepository, AuditLogEntry,
+    ClaimedAuditExportSink, CreateAuditExportSinkInput,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+use crate::postgres_tenant_rls::begin_audit_export_transaction;
+
+#[cfg(test)]
+mod tests;
+
+const SINK_COLUMNS: &str = "id, tenant_id, name, config, is_enabled, checkpoint_position, \
+     last_exported_at, last_error, created_by_subject, created_at";
+
+/// Longest stored delivery error; collector responses can be arbitrarily large.
+const MAX_STORED_ERROR_LENGTH: usize = 2_000;
+
+/// PostgreSQL-backed repository for audit export sinks and checkpoints.
+#[derive(Clone)]
+pub struct PostgresAuditExportRepository {
+    pool: PgPool,
+}
+
+impl PostgresAuditExportRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct AuditExportSinkRow {
+    id: uuid::Uuid,
+    tenant_id: uuid::Uuid,
+    name: String,
+    config: Value,
+    is_enabled: bool,
+    checkpoint_position: i64,
+    last_exported_at: Option<DateTime<Utc>>,
+    last_error: Option<String>,
+    created_by_subject: String,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct AuditExportEntryRow {
+    event_id: uuid::Uuid,
+    subject: String,
+    action: String,
+    resource_type: String,
+    resource_id: String,
+    detail: Option<String>,
+    created_at: String,
+    chain_position: i64,
+    previous_entry_hash: Option<String>,
+    entry_hash: String,
+}
+
+impl TryFrom<AuditExportSinkRow> for AuditExportSink {
+    type Error = AppError;
+
+    fn try_from(row: AuditExportSinkRow) -> Result<Self, Self::Error> {
+        let config =
+            serde_json::from_value::<AuditExportSinkConfig>(row.config).map_err(|error| {
+                AppError::Internal(format!(
+                    "persisted audit export sink '{}' has invalid config: {error}",
+                    row.id
+                ))
+            })?;
+
+        Ok(Self {
+            sink_id: row.id.to_string(),
+            name: row.name,
+            config,
+            is_enabled: row.is_enabled,
+            checkpoint_position: row.checkpoint_position,
+            last_exported_at: row.last_exported_at,
+            last_error: row.last_error,
+            created_by_subject: row.created_by_subject,
+            created_at: row.created_at,
+        })
+    }
+}
+
+/// Unknown or malformed ids match no sink.
+fn parse_sink_id(sink_id: &str) -> Option<uuid::Uuid> {
+    uuid::Uuid::parse_str(sink_id).ok()
+}
+
+fn commit_error(error: sqlx::Error) -> AppError {
+    AppError::Internal(format!(
+        "failed to commit audit export transaction: {error}"
+    ))
+}
+
+#[async_trait]
+impl AuditExportSinkRepository for PostgresAuditExportRepository {
+    async fn create_sink(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        input: CreateAuditExportSinkInput,
+    ) -> AppResult<AuditExportSink> {
+        let config = serde_json::to_value(&input.config).map_err(|error| {
+            AppError::Internal(format!(
+                "failed to serialize audit export sink config: {error}"
+            ))
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, AuditExportSinkRow>(&format!(
+            r#"
+            INSERT INTO audit_export_sinks (
+                tenant_id,
+                name,
+                sink_type,
+                config,
+                checkpoint_position,
+                created_by_subject
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4,
+                CASE
+                    WHEN $5 THEN 0
+                    ELSE COALESCE(
+                        (SELECT MAX(chain_position) FROM audit_log_entries WHERE tenant_id = $1),
+                        0
+                    )
+                END,
+                $6
+            )
+            RETURNING {SINK_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(input.name.as_str())
+        .bind(input.config.sink_type())
+        .bind(config)
+        .bind(input.backfill)
+        .bind(created_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to create audit export sink: {error}"))
+        })?;
+        transaction.commit().await.map_err(commit_error)?;
+
+        AuditExportSink::try_from(row)
+    }
+
+    async fn list_sinks(&self, tenant_id: TenantId) -> AppResult<Vec<AuditExportSink>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, AuditExportSinkRow>(&format!(
+            r#"
+            SELECT {SINK_COLUMNS}
+            FROM audit_export_sinks
+            WHERE tenant_id = $1
+            ORDER BY created_at ASC, id ASC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list audit export sinks: {error}"))
+        })?;
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.into_iter().map(AuditExportSink::try_from).collect()
+    }
+
+    async fn set_sink_enabled(
+        &self,
+        tenant_id: TenantId,
+        sink_id: &str,
+        is_enabled: bool,
+    ) -> AppResult<Option<AuditExportSink>> {
+        let Some(sink_uuid) = parse_sink_id(sink_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, AuditExportSinkRow>(&format!(
+            r#"
+            UPDATE audit_export_sinks
+            SET is_enabled = $3, updated_at = now()
+            WHERE tenant_id = $1 AND id = $2
+            RETURNING {SINK_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(sink_uuid)
+        .bind(is_enabled)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to update audit export sink '{sink_id}': {error}"
+            ))
+        })?;
+        transaction.commit().await.map_err(commit_error)?;
+
+        row.map(AuditExportSink::try_from).transpose()
+    }
+
+    async fn delete_sink(&self, tenant_id: TenantId, sink_id: &str) -> AppResult<bool> {
+        let Some(sink_uuid) = parse_sink_id(sink_id) else {
+            return Ok(false);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM audit_export_sinks
+            WHERE tenant_id = $1 AND id = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(sink_uuid)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete audit export sink '{sink_id}': {error}"
+            ))
+        })?;
+        transaction.commit().await.map_err(commit_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn claim_sinks(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<ClaimedAuditExportSink>> {
+        let limit = i64::try_from(limit).map_err(|_| {
+            AppError::Validation("audit export claim size is out of range".to_owned())
+        })?;
+
+        let mut transaction = begin_audit_export_transaction(&self.pool).await?;
+        let rows = sqlx::query_as::<_, AuditExportSinkRow>(
+            r#"
+            WITH claimable AS (
+                SELECT id
+                FROM audit_export_sinks
+                WHERE is_enabled AND (lease_until IS NULL OR lease_until <= $1)
+                ORDER BY lease_until ASC NULLS FIRST, id ASC
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE audit_export_sinks
+            SET lease_until = $2
+            FROM claimable
+            WHERE audit_export_sinks.id = claimable.id
+            RETURNING audit_export_sinks.*
+            "#,
+        )
+        .bind(now)
+        .bind(lease_until)
+        .bind(limit)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to claim audit export sinks: {error}"))
+        })?;
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.into_iter()
+            .map(|row| {
+                let tenant_id = TenantId::from_uuid(row.tenant_id);
+                Ok(ClaimedAuditExportSink {
+                    tenant_id,
+                    sink: AuditExportSink::try_from(row)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn list_entries_after(
+        &self,
+        tenant_id: TenantId,
+        chain_position: i64,
+        limit: usize,
+    ) -> AppResult<Vec<AuditLogEntry>> {
+        let limit = i64::try_from(limit.clamp(1, 5_000)).unwrap_or(5_000);
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, AuditExportEntryRow>(
+            r#"
+            SELECT
+                id AS event_id,
+                subject,
+                action,
+                resource_type,
+                resource_id,
+                detail,
+                to_char(created_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS.US"Z"') AS created_at,
+                chain_position,
+                previous_entry_hash,
+                entry_hash
+            FROM audit_log_entries
+            WHERE tenant_id = $1 AND chain_position > $2
+            ORDER BY chain_position ASC
+            LIMIT $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(chain_position)
+        .bind(limit)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list audit entries for export: {error}"
+            ))
+        })?;
+        transaction.commit().await.map_err(commit_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| AuditLogEntry {
+                event_id: row.event_id.to_string(),
+                subject: row.subject,
+                action: row.action,
+                resource_type: row.resource_type,
+                resource_id: row.resource_id,
+                detail: row.detail,
+                created_at: row.created_at,
+                chain_position: row.chain_position,
+                previous_entry_hash: row.previous_entry_hash,
+                entry_hash: row.entry_hash,
+            })
+            .collect())
+    }
+
+    async fn record_checkpoint(
+        &self,
+        tenant_id: TenantId,
+        sink_id: &str,
+        chain_position: i64,
+        exported_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        let Some(sink_uuid) = parse_sink_id(sink_id) else {
+            return Ok(());
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            UPDATE audit_export_sinks
+            SET checkpoint_position = GREATEST(checkpoint_position, $3),
+                last_exported_at = $4,
+                last_error = NULL
+            WHERE tenant_id = $1 AND id = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(sink_uuid)
+        .bind(chain_position)
+        .bind(exported_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to record audit export checkpoint for sink '{sink_id}': {error}"
+            ))
+        })?;
+        transaction.commit().await.map_err(commit_error)
+    }
+
+    async fn record_failure(
+        &self,
+        tenant_id: TenantId,
+        sink_id: &str,
+        error: &str,
+        retry_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        let Some(sink_uuid) = parse_sink_id(sink_id) else {
+            return Ok(());
+        };
+        let error = error
+            .chars()
+            .take(MAX_STORED_ERROR_LENGTH)
+            .collect::<String>();
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            UPDATE audit_export_sinks
+            SET last_error = $3, lease_until = $4
+            WHERE tenant_id = $1 AND id = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(sink_uuid)
+        .bind(error)
+        .bind(retry_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to record audit export failure for sink '{sink_id}': {error}"
+            ))
+        })?;
+        transaction.commit().await.map_err(commit_error)
+    }
+
+    async fn release_sink(&self, tenant_id: TenantId, sink_id: &str) -> AppResult<()> {
+        let Some(sink_uuid) = parse_sink_id(sink_id) else {
+            return Ok(());
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            UPDATE audit_export_sinks
+            SET lease_until = NULL
+            WHERE tenant_id = $1 AND id = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(sink_uuid)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to release audit export sink '{sink_id}': {error}"
+            ))
+        })?;
+        transaction.commit().await.map_err(commit_error)
+    }
+}
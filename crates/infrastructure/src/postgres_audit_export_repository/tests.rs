@@ -0,0 +1,230 @@
+use chrono::{Duration, Utc};
+use qryvanta_application::{
+    AuditExportSinkConfig, AuditExportSinkRepository, AuditExportSyslogTransport,
+    CreateAuditExportSinkInput,
+};
+use qryvanta_core::TenantId;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresAuditExportRepository;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres audit export tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn ensure_tenant(pool: &PgPool, tenant_id: TenantId, name: &str) {
+    let insert = sqlx::query(
+        r#"
+            INSERT INTO tenants (id, name)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(name)
+    .execute(pool)
+    .await;
+
+    assert!(insert.is_ok());
+}
+
+async fn insert_audit_entries(
+    pool: &PgPool,
+    tenant_id: TenantId,
+    positions: std::ops::RangeInclusive<i64>,
+) {
+    for chain_position in positions {
+        let insert = sqlx::query(
+            r#"
+            INSERT INTO audit_log_entries (
+                tenant_id,
+                subject,
+                action,
+                resource_type,
+                resource_id,
+                chain_position,
+                entry_hash
+            )
+            VALUES ($1, 'alice', 'metadata.entity.created', 'entity', 'contact', $2, $3)
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(chain_position)
+        .bind(format!("hash-{chain_position}"))
+        .execute(pool)
+        .await;
+
+        assert!(insert.is_ok());
+    }
+}
+
+fn syslog_input(backfill: bool) -> CreateAuditExportSinkInput {
+    CreateAuditExportSinkInput {
+        name: "Collector".to_owned(),
+        config: AuditExportSinkConfig::Syslog {
+            host: "siem.internal".to_owned(),
+            port: 6514,
+            transport: AuditExportSyslogTransport::Tcp,
+        },
+        backfill,
+    }
+}
+
+#[tokio::test]
+async fn sinks_checkpoint_lease_and_stay_tenant_scoped() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Audit Export Tenant").await;
+    ensure_tenant(&pool, other_tenant_id, "Other Audit Export Tenant").await;
+    insert_audit_entries(&pool, tenant_id, 1..=3).await;
+    let repository = PostgresAuditExportRepository::new(pool.clone());
+
+    let backfill = repository
+        .create_sink(tenant_id, "alice", syslog_input(true))
+        .await
+        .unwrap_or_else(|error| panic!("failed to create audit export sink: {error}"));
+    let tail = repository
+        .create_sink(tenant_id, "alice", syslog_input(false))
+        .await
+        .unwrap_or_else(|error| panic!("failed to create audit export sink: {error}"));
+    assert_eq!(backfill.checkpoint_position, 0);
+    assert_eq!(tail.checkpoint_position, 3);
+    assert_eq!(backfill.config, syslog_input(true).config);
+
+    assert!(
+        repository
+            .list_sinks(other_tenant_id)
+            .await
+            .unwrap_or_else(|error| panic!("failed to list audit export sinks: {error}"))
+            .is_empty()
+    );
+    assert!(
+        repository
+            .set_sink_enabled(other_tenant_id, backfill.sink_id.as_str(), false)
+            .await
+            .unwrap_or_else(|error| panic!("failed to update audit export sink: {error}"))
+            .is_none()
+    );
+
+    let entries = repository
+        .list_entries_after(tenant_id, 1, 10)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list audit entries: {error}"));
+    assert_eq!(
+        entries
+            .iter()
+            .map(|entry| entry.chain_position)
+            .collect::<Vec<_>>(),
+        vec![2, 3]
+    );
+
+    // Claims filter by lease across tenants, so scope assertions to this tenant's sinks.
+    let now = Utc::now();
+    let claimed = repository
+        .claim_sinks(now, now + Duration::minutes(2), 1_000)
+        .await
+        .unwrap_or_else(|error| panic!("failed to claim audit export sinks: {error}"));
+    let claimed_ids = claimed
+        .iter()
+        .filter(|claimed| claimed.tenant_id == tenant_id)
+        .map(|claimed| claimed.sink.sink_id.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(claimed_ids.len(), 2);
+    assert!(claimed_ids.contains(&backfill.sink_id));
+
+    let reclaimed = repository
+        .claim_sinks(now, now + Duration::minutes(2), 1_000)
+        .await
+        .unwrap_or_else(|error| panic!("failed to claim audit export sinks: {error}"));
+    assert!(
+        reclaimed
+            .iter()
+            .all(|claimed| claimed.tenant_id != tenant_id)
+    );
+
+    repository
+        .record_checkpoint(tenant_id, backfill.sink_id.as_str(), 3, Utc::now())
+        .await
+        .unwrap_or_else(|error| panic!("failed to record checkpoint: {error}"));
+    repository
+        .release_sink(tenant_id, backfill.sink_id.as_str())
+        .await
+        .unwrap_or_else(|error| panic!("failed to release sink: {error}"));
+    repository
+        .record_failure(
+            tenant_id,
+            tail.sink_id.as_str(),
+            "connection refused",
+            Utc::now() + Duration::minutes(5),
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to record failure: {error}"));
+
+    let sinks = repository
+        .list_sinks(tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list audit export sinks: {error}"));
+    let stored_backfill = sinks
+        .iter()
+        .find(|sink| sink.sink_id == backfill.sink_id)
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(stored_backfill.checkpoint_position, 3);
+    assert!(stored_backfill.last_exported_at.is_some());
+    let stored_tail = sinks
+        .iter()
+        .find(|sink| sink.sink_id == tail.sink_id)
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(
+        stored_tail.last_error.as_deref(),
+        Some("connection refused")
+    );
+
+    let claimed_after_release = repository
+        .claim_sinks(Utc::now(), Utc::now() + Duration::minutes(2), 1_000)
+        .await
+        .unwrap_or_else(|error| panic!("failed to claim audit export sinks: {error}"));
+    let claimed_ids = claimed_after_release
+        .iter()
+        .filter(|claimed| claimed.tenant_id == tenant_id)
+        .map(|claimed| claimed.sink.sink_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(claimed_ids, vec![backfill.sink_id.as_str()]);
+
+    assert!(
+        repository
+            .delete_sink(tenant_id, backfill.sink_id.as_str())
+            .await
+            .unwrap_or_else(|error| panic!("failed to delete audit export sink: {error}"))
+    );
+    assert!(
+        !repository
+            .delete_sink(tenant_id, "not-a-sink")
+            .await
+            .unwrap_or_else(|error| panic!("failed to delete audit export sink: {error}"))
+    );
+}
@@ -1,10 +1,14 @@
+use std::collections::BTreeMap;
+
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::{FromRow, PgPool};
 
 use crate::audit_chain::{AuditChainInput, compute_audit_entry_hash};
 use crate::begin_tenant_transaction;
 use qryvanta_application::{
-    AuditIntegrityStatus, AuditLogEntry, AuditLogQuery, AuditLogRepository,
+    AuditChainAnchor, AuditChainAnchorReason, AuditChainVerificationScope, AuditIntegrityStatus,
+    AuditLogEntry, AuditLogQuery, AuditLogRepository,
 };
 use qryvanta_core::{AppError, AppResult, TenantId};
 
@@ -37,6 +41,14 @@ struct AuditLogRow {
     entry_hash: String,
 }
 
+#[derive(Debug, FromRow)]
+struct AuditChainAnchorRow {
+    chain_position: i64,
+    entry_hash: String,
+    reason: String,
+    created_at: String,
+}
+
 #[async_trait]
 impl AuditLogRepository for PostgresAuditLogRepository {
     async fn list_recent_entries(
@@ -173,16 +185,44 @@ impl AuditLogRepository for PostgresAuditLogRepository {
         retention_days: u16,
     ) -> AppResult<u64> {
         let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
-        let result = sqlx::query(
+        let purged = sqlx::query_scalar::<_, i64>(
             r#"
-            DELETE FROM audit_log_entries
-            WHERE tenant_id = $1
-              AND created_at < now() - make_interval(days => $2::INTEGER)
+            WITH purged AS (
+                DELETE FROM audit_log_entries
+                WHERE tenant_id = $1
+                  AND created_at < now() - make_interval(days => $2::INTEGER)
+                  AND NOT EXISTS (
+                      SELECT 1
+                      FROM legal_holds
+                      WHERE legal_holds.tenant_id = $1
+                        AND legal_holds.scope_type = 'audit_log'
+                        AND legal_holds.released_at IS NULL
+                        AND audit_log_entries.created_at >= legal_holds.audit_from
+                        AND (
+                            legal_holds.audit_until IS NULL
+                            OR audit_log_entries.created_at < legal_holds.audit_until
+                        )
+                  )
+                RETURNING chain_position, entry_hash
+            ),
+            anchored AS (
+                INSERT INTO audit_chain_anchors (tenant_id, chain_position, entry_hash, reason)
+                SELECT $1, purged.chain_position, purged.entry_hash, 'purge'
+                FROM purged
+                WHERE NOT EXISTS (
+                    SELECT 1
+                    FROM purged AS next_purged
+                    WHERE next_purged.chain_position = purged.chain_position + 1
+                )
+                ON CONFLICT (tenant_id, chain_position) DO UPDATE SET reason = 'purge'
+                RETURNING chain_position
+            )
+            SELECT COUNT(*) FROM purged
             "#,
         )
         .bind(tenant_id.as_uuid())
         .bind(i32::from(retention_days))
-        .execute(&mut *transaction)
+        .fetch_one(&mut *transaction)
         .await
         .map_err(|error| {
             AppError::Internal(format!("failed to purge audit log entries: {error}"))
@@ -193,11 +233,124 @@ impl AuditLogRepository for PostgresAuditLogRepository {
             ))
         })?;
 
-        Ok(result.rows_affected())
+        Ok(u64::try_from(purged).unwrap_or_default())
     }
 
-    async fn verify_integrity(&self, tenant_id: TenantId) -> AppResult<AuditIntegrityStatus> {
+    async fn purge_entries_created_before(
+        &self,
+        tenant_id: TenantId,
+        cutoff: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<u64> {
+        let limit = i64::try_from(limit).map_err(|error| {
+            AppError::Validation(format!("invalid audit purge batch size: {error}"))
+        })?;
+
         let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let purged = sqlx::query_scalar::<_, i64>(
+            r#"
+            WITH purged AS (
+                DELETE FROM audit_log_entries
+                WHERE id IN (
+                    SELECT candidate.id
+                    FROM audit_log_entries AS candidate
+                    WHERE candidate.tenant_id = $1
+                      AND candidate.created_at < $2
+                      AND NOT EXISTS (
+                          SELECT 1
+                          FROM legal_holds
+                          WHERE legal_holds.tenant_id = $1
+                            AND legal_holds.scope_type = 'audit_log'
+                            AND legal_holds.released_at IS NULL
+                            AND candidate.created_at >= legal_holds.audit_from
+                            AND (
+                                legal_holds.audit_until IS NULL
+                                OR candidate.created_at < legal_holds.audit_until
+                            )
+                      )
+                    ORDER BY candidate.chain_position ASC
+                    LIMIT $3
+                )
+                RETURNING chain_position, entry_hash
+            ),
+            anchored AS (
+                INSERT INTO audit_chain_anchors (tenant_id, chain_position, entry_hash, reason)
+                SELECT $1, purged.chain_position, purged.entry_hash, 'purge'
+                FROM purged
+                WHERE NOT EXISTS (
+                    SELECT 1
+                    FROM purged AS next_purged
+                    WHERE next_purged.chain_position = purged.chain_position + 1
+                )
+                ON CONFLICT (tenant_id, chain_position) DO UPDATE SET reason = 'purge'
+                RETURNING chain_position
+            )
+            SELECT COUNT(*) FROM purged
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(cutoff)
+        .bind(limit)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to purge audit log entry batch: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped audit purge transaction: {error}"
+            ))
+        })?;
+
+        Ok(u64::try_from(purged).unwrap_or_default())
+    }
+
+    async fn verify_integrity(
+        &self,
+        tenant_id: TenantId,
+        scope: AuditChainVerificationScope,
+    ) -> AppResult<AuditIntegrityStatus> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let anchors = sqlx::query_as::<_, AuditChainAnchorRow>(
+            r#"
+            SELECT
+                chain_position,
+                entry_hash,
+                reason,
+                to_char(created_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS.US"Z"') AS created_at
+            FROM audit_chain_anchors
+            WHERE tenant_id = $1
+            ORDER BY chain_position ASC
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to load audit chain anchors: {error}"))
+        })?
+        .into_iter()
+        .map(AuditChainAnchor::try_from)
+        .collect::<AppResult<Vec<_>>>()?;
+        let start_anchor = match scope {
+            AuditChainVerificationScope::Full => None,
+            AuditChainVerificationScope::SinceLatestAnchor => anchors.last().cloned(),
+        };
+        let redactions = sqlx::query_as::<_, (i64, String)>(
+            r#"
+            SELECT chain_position, redacted_entry_hash
+            FROM audit_entry_redactions
+            WHERE tenant_id = $1
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to load audit entry redactions: {error}"))
+        })?
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
         let rows = sqlx::query_as::<_, AuditLogRow>(
             r#"
             SELECT
@@ -214,10 +367,16 @@ impl AuditLogRepository for PostgresAuditLogRepository {
                 entry_hash
             FROM audit_log_entries
             WHERE tenant_id = $1
+              AND chain_position >= $2
             ORDER BY chain_position ASC
             "#,
         )
         .bind(tenant_id.as_uuid())
+        .bind(
+            start_anchor
+                .as_ref()
+                .map_or(0, |anchor| anchor.chain_position),
+        )
         .fetch_all(&mut *transaction)
         .await
         .map_err(|error| {
@@ -229,57 +388,169 @@ impl AuditLogRepository for PostgresAuditLogRepository {
             ))
         })?;
 
-        let mut failures = Vec::new();
-        let mut previous_hash: Option<String> = None;
-        let mut latest_chain_position = None;
-        let mut latest_entry_hash = None;
+        Ok(verify_chain_rows(
+            &rows,
+            &anchors,
+            &redactions,
+            start_anchor,
+        ))
+    }
 
-        for (index, row) in rows.iter().enumerate() {
-            let expected_position = i64::try_from(index + 1).unwrap_or(i64::MAX);
-            if row.chain_position != expected_position {
-                failures.push(format!(
-                    "event {} expected chain_position {}, found {}",
-                    row.event_id, expected_position, row.chain_position
-                ));
-            }
+    async fn list_chain_anchors(
+        &self,
+        tenant_id: TenantId,
+        limit: usize,
+    ) -> AppResult<Vec<AuditChainAnchor>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, AuditChainAnchorRow>(
+            r#"
+            SELECT
+                chain_position,
+                entry_hash,
+                reason,
+                to_char(created_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS.US"Z"') AS created_at
+            FROM audit_chain_anchors
+            WHERE tenant_id = $1
+            ORDER BY chain_position DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list audit chain anchors: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped audit anchor transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter().map(AuditChainAnchor::try_from).collect()
+    }
+}
+
+impl TryFrom<AuditChainAnchorRow> for AuditChainAnchor {
+    type Error = AppError;
+
+    fn try_from(row: AuditChainAnchorRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chain_position: row.chain_position,
+            entry_hash: row.entry_hash,
+            reason: AuditChainAnchorReason::parse(row.reason.as_str())?,
+            created_at: row.created_at,
+        })
+    }
+}
+
+/// Re-hashes audit rows in chain order and checks links, gaps and anchors.
+///
+/// A gap is only accepted when an anchor records the hash of the entry just
+/// before it, which is what retention purges write. An entry whose payload no
+/// longer matches its hash is accepted when a data erasure recorded a
+/// redaction with the hash of the rewritten payload.
+fn verify_chain_rows(
+    rows: &[AuditLogRow],
+    anchors: &[AuditChainAnchor],
+    redactions: &BTreeMap<i64, String>,
+    start_anchor: Option<AuditChainAnchor>,
+) -> AuditIntegrityStatus {
+    let anchors_by_position = anchors
+        .iter()
+        .map(|anchor| (anchor.chain_position, anchor))
+        .collect::<BTreeMap<_, _>>();
+    let mut failures = Vec::new();
+    let mut previous: Option<(i64, &str)> = None;
+
+    for (index, row) in rows.iter().enumerate() {
+        let starts_at_anchor = index == 0
+            && start_anchor
+                .as_ref()
+                .is_some_and(|anchor| anchor.chain_position == row.chain_position);
+        let expected_link = match previous {
+            Some((position, hash)) if row.chain_position == position + 1 => Some(Some(hash)),
+            None if row.chain_position == 1 => Some(None),
+            _ => None,
+        };
 
-            if row.previous_entry_hash.as_deref() != previous_hash.as_deref() {
+        if starts_at_anchor {
+            // The anchor itself vouches for this entry; its predecessor is out of scope.
+        } else if let Some(expected_link) = expected_link {
+            if row.previous_entry_hash.as_deref() != expected_link {
                 failures.push(format!(
                     "event {} previous_entry_hash mismatch at chain_position {}",
                     row.event_id, row.chain_position
                 ));
             }
-
-            let computed_hash = compute_audit_entry_hash(&AuditChainInput {
-                tenant_id: TenantId::from_uuid(row.tenant_id),
-                chain_position: row.chain_position,
-                previous_entry_hash: row.previous_entry_hash.as_deref(),
-                subject: &row.subject,
-                action: &row.action,
-                resource_type: &row.resource_type,
-                resource_id: &row.resource_id,
-                detail: row.detail.as_deref(),
-                created_at_utc: &row.created_at,
-            });
-            if row.entry_hash != computed_hash {
+        } else {
+            let anchored_gap = anchors_by_position
+                .get(&(row.chain_position - 1))
+                .is_some_and(|anchor| {
+                    row.previous_entry_hash.as_deref() == Some(anchor.entry_hash.as_str())
+                });
+            if !anchored_gap {
                 failures.push(format!(
-                    "event {} entry_hash mismatch at chain_position {}",
+                    "event {} follows a gap before chain_position {} without a matching anchor",
                     row.event_id, row.chain_position
                 ));
             }
+        }
 
-            previous_hash = Some(row.entry_hash.clone());
-            latest_chain_position = Some(row.chain_position);
-            latest_entry_hash = Some(row.entry_hash.clone());
+        let computed_hash = compute_audit_entry_hash(&AuditChainInput {
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            chain_position: row.chain_position,
+            previous_entry_hash: row.previous_entry_hash.as_deref(),
+            subject: &row.subject,
+            action: &row.action,
+            resource_type: &row.resource_type,
+            resource_id: &row.resource_id,
+            detail: row.detail.as_deref(),
+            created_at_utc: &row.created_at,
+        });
+        if row.entry_hash != computed_hash
+            && redactions.get(&row.chain_position) != Some(&computed_hash)
+        {
+            failures.push(format!(
+                "event {} entry_hash mismatch at chain_position {}",
+                row.event_id, row.chain_position
+            ));
         }
 
-        Ok(AuditIntegrityStatus {
-            is_valid: failures.is_empty(),
-            verified_entries: rows.len(),
-            latest_chain_position,
-            latest_entry_hash,
-            failures,
-        })
+        if let Some(anchor) = anchors_by_position.get(&row.chain_position)
+            && anchor.entry_hash != row.entry_hash
+        {
+            failures.push(format!(
+                "event {} does not match the anchor at chain_position {}",
+                row.event_id, row.chain_position
+            ));
+        }
+
+        previous = Some((row.chain_position, row.entry_hash.as_str()));
+    }
+
+    // Periodic anchors past the last retained entry mean the chain was truncated.
+    let head_position = previous.map_or(0, |(position, _)| position);
+    for anchor in anchors_by_position
+        .range(head_position + 1..)
+        .map(|(_, anchor)| anchor)
+    {
+        if anchor.reason == AuditChainAnchorReason::Periodic {
+            failures.push(format!(
+                "anchor at chain_position {} is past the last retained entry",
+                anchor.chain_position
+            ));
+        }
+    }
+
+    AuditIntegrityStatus {
+        is_valid: failures.is_empty(),
+        verified_entries: rows.len(),
+        latest_chain_position: rows.last().map(|row| row.chain_position),
+        latest_entry_hash: rows.last().map(|row| row.entry_hash.clone()),
+        verified_from_anchor: start_anchor,
+        failures,
     }
 }
 
@@ -1,11 +1,17 @@
-use qryvanta_application::{AuditLogQuery, AuditLogRepository};
+use chrono::{Duration, Utc};
+use qryvanta_application::{
+    AuditChainAnchorReason, AuditChainVerificationScope, AuditEvent, AuditLogQuery,
+    AuditLogRepository, AuditRepository, CreateLegalHoldInput, LegalHoldRepository, LegalHoldScope,
+};
 use qryvanta_core::TenantId;
+use qryvanta_domain::AuditAction;
 use sqlx::PgPool;
 use sqlx::migrate::Migrator;
 use sqlx::postgres::PgPoolOptions;
 
 use super::PostgresAuditLogRepository;
 use crate::audit_chain::{AuditChainInput, compute_audit_entry_hash};
+use crate::{PostgresAuditRepository, PostgresLegalHoldRepository};
 
 static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
 
@@ -185,6 +191,75 @@ async fn export_and_purge_entries_follow_retention_window() {
     assert_eq!(listed[0].chain_position, 2);
 }
 
+#[tokio::test]
+async fn purge_skips_entries_under_active_audit_legal_hold() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresAuditLogRepository::new(pool.clone());
+    let hold_repository = PostgresLegalHoldRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Legal Hold Audit Tenant").await;
+
+    let oldest_hash = insert_audit_entry(
+        &pool,
+        AuditEntrySeed {
+            tenant_id,
+            subject: "alice",
+            action: "runtime.record.created",
+            resource_id: "record-oldest",
+            detail: None,
+            created_at_sql: "now() - interval '90 days'",
+            chain_position: 1,
+            previous_entry_hash: None,
+        },
+    )
+    .await;
+    let _held_hash = insert_audit_entry(
+        &pool,
+        AuditEntrySeed {
+            tenant_id,
+            subject: "alice",
+            action: "runtime.record.updated",
+            resource_id: "record-held",
+            detail: None,
+            created_at_sql: "now() - interval '60 days'",
+            chain_position: 2,
+            previous_entry_hash: Some(oldest_hash.as_str()),
+        },
+    )
+    .await;
+
+    let hold = hold_repository
+        .create_hold(
+            tenant_id,
+            "compliance",
+            CreateLegalHoldInput {
+                name: "Matter 42".to_owned(),
+                reason: "litigation".to_owned(),
+                scope: LegalHoldScope::AuditLog {
+                    from: Utc::now() - Duration::days(75),
+                    until: Some(Utc::now() - Duration::days(45)),
+                },
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to create legal hold: {error}"));
+
+    let purged = repository.purge_entries_older_than(tenant_id, 30).await;
+    assert_eq!(purged.unwrap_or(0), 1);
+
+    let released = hold_repository
+        .release_hold(tenant_id, hold.hold_id.as_str(), "compliance")
+        .await
+        .unwrap_or_else(|error| panic!("failed to release legal hold: {error}"));
+    assert!(released.is_some_and(|hold| !hold.is_active()));
+
+    let purged_after_release = repository.purge_entries_older_than(tenant_id, 30).await;
+    assert_eq!(purged_after_release.unwrap_or(0), 1);
+}
+
 #[tokio::test]
 async fn audit_log_queries_and_purge_are_tenant_scoped() {
     let Some(pool) = test_pool().await else {
@@ -352,7 +427,9 @@ async fn verify_integrity_reports_valid_and_tampered_chains() {
     )
     .await;
 
-    let valid = repository.verify_integrity(tenant_id).await;
+    let valid = repository
+        .verify_integrity(tenant_id, AuditChainVerificationScope::Full)
+        .await;
     assert!(valid.is_ok());
     let valid = valid.unwrap_or_else(|_| unreachable!());
     assert!(valid.is_valid);
@@ -372,7 +449,9 @@ async fn verify_integrity_reports_valid_and_tampered_chains() {
     .await;
     assert!(tamper.is_ok());
 
-    let invalid = repository.verify_integrity(tenant_id).await;
+    let invalid = repository
+        .verify_integrity(tenant_id, AuditChainVerificationScope::Full)
+        .await;
     assert!(invalid.is_ok());
     let invalid = invalid.unwrap_or_else(|_| unreachable!());
     assert!(!invalid.is_valid);
@@ -384,3 +463,232 @@ async fn verify_integrity_reports_valid_and_tampered_chains() {
             .any(|failure| failure.contains("entry_hash mismatch"))
     );
 }
+
+#[tokio::test]
+async fn batched_purge_deletes_oldest_entries_before_the_cutoff() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresAuditLogRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Batched Purge Audit Tenant").await;
+
+    let mut previous_hash: Option<String> = None;
+    for (position, (resource_id, created_at_sql)) in [
+        ("record-1", "now() - interval '90 days'"),
+        ("record-2", "now() - interval '80 days'"),
+        ("record-3", "now() - interval '70 days'"),
+        ("record-4", "now() - interval '1 day'"),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        previous_hash = Some(
+            insert_audit_entry(
+                &pool,
+                AuditEntrySeed {
+                    tenant_id,
+                    subject: "alice",
+                    action: "runtime.record.created",
+                    resource_id,
+                    detail: None,
+                    created_at_sql,
+                    chain_position: i64::try_from(position + 1).unwrap_or(i64::MAX),
+                    previous_entry_hash: previous_hash.as_deref(),
+                },
+            )
+            .await,
+        );
+    }
+
+    let cutoff = Utc::now() - Duration::days(30);
+    let first = repository
+        .purge_entries_created_before(tenant_id, cutoff, 2)
+        .await;
+    assert_eq!(first.unwrap_or(0), 2);
+    let second = repository
+        .purge_entries_created_before(tenant_id, cutoff, 2)
+        .await;
+    assert_eq!(second.unwrap_or(0), 1);
+    let third = repository
+        .purge_entries_created_before(tenant_id, cutoff, 2)
+        .await;
+    assert_eq!(third.unwrap_or(1), 0);
+
+    let listed = repository
+        .list_recent_entries(
+            tenant_id,
+            AuditLogQuery {
+                limit: 100,
+                offset: 0,
+                action: None,
+                subject: None,
+            },
+        )
+        .await
+        .unwrap_or_default();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].resource_id, "record-4");
+}
+
+async fn seed_chain(pool: &PgPool, tenant_id: TenantId, days: &[&str]) -> Vec<String> {
+    let mut hashes: Vec<String> = Vec::new();
+    for (index, day) in days.iter().enumerate() {
+        let created_at_sql = format!("TIMESTAMPTZ '{day}T00:00:00Z'");
+        let resource_id = format!("record-{index}");
+        let hash = insert_audit_entry(
+            pool,
+            AuditEntrySeed {
+                tenant_id,
+                subject: "alice",
+                action: "runtime.record.updated",
+                resource_id: resource_id.as_str(),
+                detail: None,
+                created_at_sql: created_at_sql.as_str(),
+                chain_position: i64::try_from(index + 1).unwrap_or(i64::MAX),
+                previous_entry_hash: hashes.last().map(String::as_str),
+            },
+        )
+        .await;
+        hashes.push(hash);
+    }
+    hashes
+}
+
+#[tokio::test]
+async fn purges_leave_anchors_that_keep_the_chain_verifiable() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresAuditLogRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Audit Anchor Tenant").await;
+    let hashes = seed_chain(
+        &pool,
+        tenant_id,
+        &["2026-01-01", "2026-01-02", "2026-01-03", "2026-01-04"],
+    )
+    .await;
+    let periodic_anchor = sqlx::query(
+        r#"
+            INSERT INTO audit_chain_anchors (tenant_id, chain_position, entry_hash, reason)
+            VALUES ($1, 2, $2, 'periodic')
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(hashes[1].as_str())
+    .execute(&pool)
+    .await;
+    assert!(periodic_anchor.is_ok());
+
+    let cutoff = "2026-01-02T12:00:00Z"
+        .parse::<chrono::DateTime<Utc>>()
+        .unwrap_or_else(|_| unreachable!());
+    let purged = repository
+        .purge_entries_created_before(tenant_id, cutoff, 100)
+        .await
+        .unwrap_or_else(|error| panic!("failed to purge audit entries: {error}"));
+    assert_eq!(purged, 2);
+
+    let anchors = repository
+        .list_chain_anchors(tenant_id, 10)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list audit chain anchors: {error}"));
+    assert_eq!(anchors.len(), 1);
+    assert_eq!(anchors[0].chain_position, 2);
+    assert_eq!(anchors[0].reason, AuditChainAnchorReason::Purge);
+
+    let full = repository
+        .verify_integrity(tenant_id, AuditChainVerificationScope::Full)
+        .await
+        .unwrap_or_else(|error| panic!("failed to verify audit chain: {error}"));
+    assert!(full.is_valid, "{:?}", full.failures);
+    assert_eq!(full.verified_entries, 2);
+    assert_eq!(full.latest_chain_position, Some(4));
+
+    let incremental = repository
+        .verify_integrity(tenant_id, AuditChainVerificationScope::SinceLatestAnchor)
+        .await
+        .unwrap_or_else(|error| panic!("failed to verify audit chain: {error}"));
+    assert!(incremental.is_valid);
+    assert_eq!(
+        incremental
+            .verified_from_anchor
+            .map(|anchor| anchor.chain_position),
+        Some(2)
+    );
+
+    let unanchored_delete =
+        sqlx::query("DELETE FROM audit_log_entries WHERE tenant_id = $1 AND chain_position = 3")
+            .bind(tenant_id.as_uuid())
+            .execute(&pool)
+            .await;
+    assert!(unanchored_delete.is_ok());
+    let truncated_anchor = sqlx::query(
+        r#"
+            INSERT INTO audit_chain_anchors (tenant_id, chain_position, entry_hash, reason)
+            VALUES ($1, 5, 'missing-head', 'periodic')
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .execute(&pool)
+    .await;
+    assert!(truncated_anchor.is_ok());
+
+    let tampered = repository
+        .verify_integrity(tenant_id, AuditChainVerificationScope::Full)
+        .await
+        .unwrap_or_else(|error| panic!("failed to verify audit chain: {error}"));
+    assert!(!tampered.is_valid);
+    assert!(
+        tampered
+            .failures
+            .iter()
+            .any(|failure| failure.contains("without a matching anchor"))
+    );
+    assert!(
+        tampered
+            .failures
+            .iter()
+            .any(|failure| failure.contains("past the last retained entry"))
+    );
+}
+
+#[tokio::test]
+async fn appends_continue_the_chain_after_every_entry_was_purged() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresAuditLogRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Audit Anchor Head Tenant").await;
+    seed_chain(&pool, tenant_id, &["2026-01-01", "2026-01-02"]).await;
+
+    let purged = repository
+        .purge_entries_created_before(tenant_id, Utc::now(), 100)
+        .await
+        .unwrap_or_else(|error| panic!("failed to purge audit entries: {error}"));
+    assert_eq!(purged, 2);
+
+    let appended = PostgresAuditRepository::new(pool.clone())
+        .append_event(AuditEvent {
+            tenant_id,
+            subject: "alice".to_owned(),
+            action: AuditAction::MetadataEntityCreated,
+            resource_type: "entity".to_owned(),
+            resource_id: "contact".to_owned(),
+            detail: None,
+        })
+        .await;
+    assert!(appended.is_ok());
+
+    let status = repository
+        .verify_integrity(tenant_id, AuditChainVerificationScope::Full)
+        .await
+        .unwrap_or_else(|error| panic!("failed to verify audit chain: {error}"));
+    assert!(status.is_valid, "{:?}", status.failures);
+    assert_eq!(status.latest_chain_position, Some(3));
+}
@@ -1,13 +1,18 @@
 use async_trait::async_trait;
 use sqlx::FromRow;
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
 
-use crate::audit_chain::{AuditChainInput, compute_audit_entry_hash};
+use crate::audit_chain::{AUDIT_CHAIN_ANCHOR_INTERVAL, AuditChainInput, compute_audit_entry_hash};
 use crate::begin_tenant_transaction;
-use qryvanta_application::{AuditEvent, AuditRepository};
-use qryvanta_core::{AppError, AppResult};
+use crate::postgres_tenant_rls::{begin_audit_outbox_transaction, stamp_tenant_context};
+use qryvanta_application::{AuditEvent, AuditOutboxRelay, AuditRepository};
+use qryvanta_core::{AppError, AppResult, TenantId};
 
 /// PostgreSQL-backed append-only audit repository.
+///
+/// It also relays events that runtime record writes committed to the audit
+/// outbox into the tenant audit chain.
 #[derive(Clone)]
 pub struct PostgresAuditRepository {
     pool: PgPool,
@@ -27,82 +32,253 @@ struct LatestAuditChainRow {
     entry_hash: String,
 }
 
+#[derive(Debug, FromRow)]
+struct AuditOutboxEventRow {
+    id: i64,
+    tenant_id: Uuid,
+    subject: String,
+    action: String,
+    resource_type: String,
+    resource_id: String,
+    detail: Option<String>,
+}
+
+/// Audit entry appended to the end of one tenant's audit chain.
+struct ChainedAuditEntry<'a> {
+    tenant_id: TenantId,
+    subject: &'a str,
+    action: &'a str,
+    resource_type: &'a str,
+    resource_id: &'a str,
+    detail: Option<&'a str>,
+}
+
 #[async_trait]
 impl AuditRepository for PostgresAuditRepository {
     async fn append_event(&self, event: AuditEvent) -> AppResult<()> {
         let mut transaction = begin_tenant_transaction(&self.pool, event.tenant_id).await?;
-        let latest_chain = sqlx::query_as::<_, LatestAuditChainRow>(
+        append_chained_audit_entry(
+            &mut transaction,
+            &ChainedAuditEntry {
+                tenant_id: event.tenant_id,
+                subject: &event.subject,
+                action: event.action.as_str(),
+                resource_type: &event.resource_type,
+                resource_id: &event.resource_id,
+                detail: event.detail.as_deref(),
+            },
+        )
+        .await?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped audit append transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AuditOutboxRelay for PostgresAuditRepository {
+    async fn relay_pending_events(&self, limit: usize) -> AppResult<usize> {
+        let mut transaction = begin_audit_outbox_transaction(&self.pool).await?;
+        let mut rows = sqlx::query_as::<_, AuditOutboxEventRow>(
             r#"
-            SELECT chain_position, entry_hash
-            FROM audit_log_entries
-            WHERE tenant_id = $1
-            ORDER BY chain_position DESC
-            LIMIT 1
+            DELETE FROM audit_outbox_events
+            WHERE id IN (
+                SELECT id
+                FROM audit_outbox_events
+                ORDER BY id
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING id, tenant_id, subject, action, resource_type, resource_id, detail
             "#,
         )
-        .bind(event.tenant_id.as_uuid())
-        .fetch_optional(&mut *transaction)
+        .bind(i64::try_from(limit).map_err(|error| {
+            AppError::Validation(format!("invalid audit outbox relay limit: {error}"))
+        })?)
+        .fetch_all(&mut *transaction)
         .await
         .map_err(|error| {
+            AppError::Internal(format!("failed to claim audit outbox events: {error}"))
+        })?;
+        rows.sort_by_key(|row| row.id);
+
+        // Appends and outbox deletes commit together, so a crash mid-batch
+        // leaves the events pending instead of losing or duplicating them.
+        for row in &rows {
+            let tenant_id = TenantId::from_uuid(row.tenant_id);
+            stamp_tenant_context(&mut *transaction, tenant_id).await?;
+            append_chained_audit_entry(
+                &mut transaction,
+                &ChainedAuditEntry {
+                    tenant_id,
+                    subject: &row.subject,
+                    action: &row.action,
+                    resource_type: &row.resource_type,
+                    resource_id: &row.resource_id,
+                    detail: row.detail.as_deref(),
+                },
+            )
+            .await?;
+        }
+
+        transaction.commit().await.map_err(|error| {
             AppError::Internal(format!(
-                "failed to query latest tenant audit chain position: {error}"
+                "failed to commit audit outbox relay transaction: {error}"
             ))
         })?;
-        let next_chain_position = latest_chain
-            .as_ref()
-            .map_or(1_i64, |row| row.chain_position + 1);
-        let previous_entry_hash = latest_chain.as_ref().map(|row| row.entry_hash.as_str());
-        let created_at = chrono::Utc::now();
-        let created_at_utc = created_at.format("%Y-%m-%dT%H:%M:%S%.6fZ").to_string();
-        let entry_hash = compute_audit_entry_hash(&AuditChainInput {
-            tenant_id: event.tenant_id,
-            chain_position: next_chain_position,
+
+        Ok(rows.len())
+    }
+}
+
+/// Stores an audit event in the outbox inside the caller's transaction.
+pub(crate) async fn enqueue_audit_outbox_event(
+    transaction: &mut Transaction<'_, Postgres>,
+    audit_event: Option<AuditEvent>,
+) -> AppResult<()> {
+    let Some(audit_event) = audit_event else {
+        return Ok(());
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO audit_outbox_events (
+            tenant_id,
+            subject,
+            action,
+            resource_type,
+            resource_id,
+            detail
+        )
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+    )
+    .bind(audit_event.tenant_id.as_uuid())
+    .bind(audit_event.subject)
+    .bind(audit_event.action.as_str())
+    .bind(audit_event.resource_type)
+    .bind(audit_event.resource_id.as_str())
+    .bind(audit_event.detail)
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!(
+            "failed to enqueue audit outbox event for resource '{}' in tenant '{}': {error}",
+            audit_event.resource_id, audit_event.tenant_id
+        ))
+    })?;
+
+    Ok(())
+}
+
+async fn append_chained_audit_entry(
+    transaction: &mut Transaction<'_, Postgres>,
+    entry: &ChainedAuditEntry<'_>,
+) -> AppResult<()> {
+    // Anchors keep the chain head when retention purged every retained entry.
+    let latest_chain = sqlx::query_as::<_, LatestAuditChainRow>(
+        r#"
+        (
+            SELECT chain_position, entry_hash
+            FROM audit_log_entries
+            WHERE tenant_id = $1
+            ORDER BY chain_position DESC
+            LIMIT 1
+        )
+        UNION ALL
+        (
+            SELECT chain_position, entry_hash
+            FROM audit_chain_anchors
+            WHERE tenant_id = $1
+            ORDER BY chain_position DESC
+            LIMIT 1
+        )
+        ORDER BY chain_position DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(entry.tenant_id.as_uuid())
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!(
+            "failed to query latest tenant audit chain position: {error}"
+        ))
+    })?;
+    let next_chain_position = latest_chain
+        .as_ref()
+        .map_or(1_i64, |row| row.chain_position + 1);
+    let previous_entry_hash = latest_chain.as_ref().map(|row| row.entry_hash.as_str());
+    let created_at = chrono::Utc::now();
+    let created_at_utc = created_at.format("%Y-%m-%dT%H:%M:%S%.6fZ").to_string();
+    let entry_hash = compute_audit_entry_hash(&AuditChainInput {
+        tenant_id: entry.tenant_id,
+        chain_position: next_chain_position,
+        previous_entry_hash,
+        subject: entry.subject,
+        action: entry.action,
+        resource_type: entry.resource_type,
+        resource_id: entry.resource_id,
+        detail: entry.detail,
+        created_at_utc: created_at_utc.as_str(),
+    });
+
+    sqlx::query(
+        r#"
+        INSERT INTO audit_log_entries (
+            tenant_id,
+            subject,
+            action,
+            resource_type,
+            resource_id,
+            detail,
+            created_at,
+            chain_position,
             previous_entry_hash,
-            subject: &event.subject,
-            action: event.action.as_str(),
-            resource_type: &event.resource_type,
-            resource_id: &event.resource_id,
-            detail: event.detail.as_deref(),
-            created_at_utc: created_at_utc.as_str(),
-        });
+            entry_hash
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        "#,
+    )
+    .bind(entry.tenant_id.as_uuid())
+    .bind(entry.subject)
+    .bind(entry.action)
+    .bind(entry.resource_type)
+    .bind(entry.resource_id)
+    .bind(entry.detail)
+    .bind(created_at)
+    .bind(next_chain_position)
+    .bind(previous_entry_hash)
+    .bind(entry_hash.as_str())
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| AppError::Internal(format!("failed to append audit event: {error}")))?;
 
+    if next_chain_position % AUDIT_CHAIN_ANCHOR_INTERVAL == 0 {
         sqlx::query(
             r#"
-            INSERT INTO audit_log_entries (
-                tenant_id,
-                subject,
-                action,
-                resource_type,
-                resource_id,
-                detail,
-                created_at,
-                chain_position,
-                previous_entry_hash,
-                entry_hash
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            INSERT INTO audit_chain_anchors (tenant_id, chain_position, entry_hash, reason)
+            VALUES ($1, $2, $3, 'periodic')
+            ON CONFLICT (tenant_id, chain_position) DO NOTHING
             "#,
         )
-        .bind(event.tenant_id.as_uuid())
-        .bind(event.subject)
-        .bind(event.action.as_str())
-        .bind(event.resource_type)
-        .bind(event.resource_id)
-        .bind(event.detail)
-        .bind(created_at)
+        .bind(entry.tenant_id.as_uuid())
         .bind(next_chain_position)
-        .bind(previous_entry_hash)
         .bind(entry_hash)
-        .execute(&mut *transaction)
+        .execute(&mut **transaction)
         .await
-        .map_err(|error| AppError::Internal(format!("failed to append audit event: {error}")))?;
-
-        transaction.commit().await.map_err(|error| {
-            AppError::Internal(format!(
-                "failed to commit tenant-scoped audit append transaction: {error}"
-            ))
+        .map_err(|error| {
+            AppError::Internal(format!("failed to record audit chain anchor: {error}"))
         })?;
-
-        Ok(())
     }
+
+    Ok(())
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,169 @@
+use qryvanta_application::{AuditEvent, AuditOutboxRelay, MetadataRepository};
+use qryvanta_core::{AppError, TenantId};
+use qryvanta_domain::{AuditAction, EntityDefinition};
+use serde_json::json;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+use uuid::Uuid;
+
+use super::PostgresAuditRepository;
+use crate::{PostgresMetadataRepository, begin_tenant_transaction};
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres audit tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn ensure_tenant(pool: &PgPool, tenant_id: TenantId, name: &str) {
+    let insert = sqlx::query(
+        r#"
+            INSERT INTO tenants (id, name)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(name)
+    .execute(pool)
+    .await;
+
+    assert!(insert.is_ok());
+}
+
+async fn count_tenant_rows(pool: &PgPool, tenant_id: TenantId, table: &str) -> i64 {
+    let mut transaction = begin_tenant_transaction(pool, tenant_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let count = sqlx::query_scalar::<_, i64>(
+        format!("SELECT COUNT(*) FROM {table} WHERE tenant_id = $1").as_str(),
+    )
+    .bind(tenant_id.as_uuid())
+    .fetch_one(&mut *transaction)
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    transaction
+        .commit()
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    count
+}
+
+fn created_event(tenant_id: TenantId, record_id: &str) -> AuditEvent {
+    AuditEvent {
+        tenant_id,
+        subject: "alice".to_owned(),
+        action: AuditAction::RuntimeRecordCreated,
+        resource_type: "runtime_record".to_owned(),
+        resource_id: record_id.to_owned(),
+        detail: Some(format!(
+            "created runtime record '{record_id}' for entity 'contact'"
+        )),
+    }
+}
+
+#[tokio::test]
+async fn outbox_events_commit_with_the_record_and_relay_into_the_audit_chain() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Audit Outbox Tenant").await;
+    let metadata_repository = PostgresMetadataRepository::new(pool.clone());
+    let audit_repository = PostgresAuditRepository::new(pool.clone());
+    let record_id = Uuid::new_v4().to_string();
+    assert!(
+        metadata_repository
+            .save_entity(
+                tenant_id,
+                EntityDefinition::new("contact", "Contact").unwrap_or_else(|_| unreachable!()),
+            )
+            .await
+            .is_ok()
+    );
+
+    assert!(
+        metadata_repository
+            .create_runtime_record_with_id(
+                tenant_id,
+                "contact",
+                record_id.as_str(),
+                json!({"name": "Alice"}),
+                Vec::new(),
+                "alice",
+                None,
+                Some(created_event(tenant_id, record_id.as_str())),
+            )
+            .await
+            .is_ok()
+    );
+
+    // A rolled back write must not leave its audit event behind.
+    let duplicate = metadata_repository
+        .create_runtime_record_with_id(
+            tenant_id,
+            "contact",
+            record_id.as_str(),
+            json!({"name": "Alice again"}),
+            Vec::new(),
+            "alice",
+            None,
+            Some(created_event(tenant_id, record_id.as_str())),
+        )
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+    assert_eq!(
+        count_tenant_rows(&pool, tenant_id, "audit_outbox_events").await,
+        1
+    );
+    assert_eq!(
+        count_tenant_rows(&pool, tenant_id, "audit_log_entries").await,
+        0
+    );
+
+    let relayed = audit_repository
+        .relay_pending_events(100)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(relayed >= 1);
+    assert_eq!(
+        count_tenant_rows(&pool, tenant_id, "audit_outbox_events").await,
+        0
+    );
+
+    let mut transaction = begin_tenant_transaction(&pool, tenant_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let (action, resource_id, chain_position) = sqlx::query_as::<_, (String, String, i64)>(
+        r#"
+        SELECT action, resource_id, chain_position
+        FROM audit_log_entries
+        WHERE tenant_id = $1
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .fetch_one(&mut *transaction)
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    assert_eq!(action, AuditAction::RuntimeRecordCreated.as_str());
+    assert_eq!(resource_id, record_id);
+    assert_eq!(chain_position, 1);
+}
@@ -85,6 +85,14 @@ impl AuthTokenRepository for PostgresAuthTokenRepository {
             .await
     }
 
+    async fn token_exists(&self, token_hash: &str, token_type: AuthTokenType) -> AppResult<bool> {
+        self.token_exists_impl(token_hash, token_type).await
+    }
+
+    async fn invalidate_tokens_of_type(&self, token_type: AuthTokenType) -> AppResult<u64> {
+        self.invalidate_tokens_of_type_impl(token_type).await
+    }
+
     async fn count_recent_tokens(
         &self,
         email: &str,
@@ -27,4 +27,26 @@ impl PostgresAuthTokenRepository {
 
         Ok(row.map(AuthTokenRecord::from))
     }
+
+    pub(super) async fn token_exists_impl(
+        &self,
+        token_hash: &str,
+        token_type: AuthTokenType,
+    ) -> AppResult<bool> {
+        sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM auth_tokens
+                WHERE token_hash = $1
+                  AND token_type = $2
+            )
+            "#,
+        )
+        .bind(token_hash)
+        .bind(token_type.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to look up token: {error}")))
+    }
 }
@@ -25,4 +25,24 @@ impl PostgresAuthTokenRepository {
 
         Ok(())
     }
+
+    pub(super) async fn invalidate_tokens_of_type_impl(
+        &self,
+        token_type: AuthTokenType,
+    ) -> AppResult<u64> {
+        let result = sqlx::query(
+            r#"
+            UPDATE auth_tokens
+            SET used_at = now()
+            WHERE token_type = $1
+              AND used_at IS NULL
+            "#,
+        )
+        .bind(token_type.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to invalidate tokens: {error}")))?;
+
+        Ok(result.rows_affected())
+    }
 }
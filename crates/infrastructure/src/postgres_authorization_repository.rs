@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 
 use crate::begin_tenant_transaction;
-use qryvanta_application::{AuthorizationRepository, RuntimeFieldGrant, TemporaryPermissionGrant};
+use qryvanta_application::{
+    AuthorizationRepository, RuntimeFieldGrant, SubjectAppRolePermission, SubjectRoleGrant,
+    TemporaryPermissionGrant,
+};
 use qryvanta_core::{AppResult, TenantId};
 use qryvanta_domain::Permission;
 
@@ -40,8 +43,10 @@ struct TemporaryPermissionGrantRow {
     expires_at: String,
 }
 
+mod explain;
 mod permissions;
 mod runtime_fields;
+mod teams;
 mod temporary_grants;
 
 #[async_trait]
@@ -74,4 +79,31 @@ impl AuthorizationRepository for PostgresAuthorizationRepository {
         self.find_active_temporary_permission_grant_impl(tenant_id, subject, permission)
             .await
     }
+
+    async fn list_team_peer_subjects(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<String>> {
+        self.list_team_peer_subjects_impl(tenant_id, subject).await
+    }
+
+    async fn list_role_grants_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<SubjectRoleGrant>> {
+        self.list_role_grants_for_subject_impl(tenant_id, subject)
+            .await
+    }
+
+    async fn list_app_role_permissions_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<SubjectAppRolePermission>> {
+        self.list_app_role_permissions_for_subject_impl(tenant_id, subject, entity_logical_name)
+            .await
+    }
 }
@@ -0,0 +1,169 @@
+use std::str::FromStr;
+
+use qryvanta_core::AppError;
+
+use super::*;
+
+#[derive(Debug, FromRow)]
+struct SubjectRoleGrantRow {
+    role_name: String,
+    team_name: Option<String>,
+    permissions: Vec<String>,
+}
+
+#[derive(Debug, FromRow)]
+struct SubjectAppRolePermissionRow {
+    app_logical_name: String,
+    role_name: String,
+    app_accessible: bool,
+    can_read: bool,
+    can_create: bool,
+    can_update: bool,
+    can_delete: bool,
+}
+
+impl PostgresAuthorizationRepository {
+    pub(super) async fn list_role_grants_for_subject_impl(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<SubjectRoleGrant>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, SubjectRoleGrantRow>(
+            r#"
+            SELECT
+                roles.name AS role_name,
+                teams.name AS team_name,
+                COALESCE(
+                    array_agg(grants.permission ORDER BY grants.permission)
+                        FILTER (WHERE grants.permission IS NOT NULL),
+                    ARRAY[]::TEXT[]
+                ) AS permissions
+            FROM rbac_subject_roles AS subject_roles
+            INNER JOIN rbac_roles AS roles
+                ON roles.id = subject_roles.role_id
+            LEFT JOIN security_teams AS teams
+                ON subject_roles.subject_type = 'team'
+                AND teams.tenant_id = subject_roles.tenant_id
+                AND teams.id::TEXT = subject_roles.subject
+            LEFT JOIN rbac_role_grants AS grants
+                ON grants.role_id = roles.id
+            WHERE subject_roles.tenant_id = $1
+                AND (subject_roles.subject_type, subject_roles.subject) IN (
+                    SELECT subject_type, subject FROM qryvanta_subject_principals($1, $2)
+                )
+            GROUP BY roles.name, teams.name
+            ORDER BY roles.name, teams.name NULLS FIRST
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to load role grants for subject '{}' in tenant '{}': {error}",
+                subject, tenant_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped role grant lookup transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(|row| {
+                let permissions = row
+                    .permissions
+                    .iter()
+                    .map(|permission| {
+                        Permission::from_str(permission.as_str()).map_err(|error| {
+                            AppError::Internal(format!(
+                                "failed to decode permission '{}' of role '{}' for tenant '{}': {error}",
+                                permission, row.role_name, tenant_id
+                            ))
+                        })
+                    })
+                    .collect::<AppResult<Vec<_>>>()?;
+
+                Ok(SubjectRoleGrant {
+                    role_name: row.role_name,
+                    team_name: row.team_name,
+                    permissions,
+                })
+            })
+            .collect()
+    }
+
+    pub(super) async fn list_app_role_permissions_for_subject_impl(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<SubjectAppRolePermission>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, SubjectAppRolePermissionRow>(
+            r#"
+            WITH subject_role_ids AS (
+                SELECT DISTINCT subject_roles.role_id
+                FROM rbac_subject_roles AS subject_roles
+                WHERE subject_roles.tenant_id = $1
+                    AND (subject_roles.subject_type, subject_roles.subject) IN (
+                        SELECT subject_type, subject FROM qryvanta_subject_principals($1, $2)
+                    )
+            )
+            SELECT
+                p.app_logical_name,
+                roles.name AS role_name,
+                EXISTS (
+                    SELECT 1
+                    FROM app_role_bindings AS bindings
+                    WHERE bindings.tenant_id = p.tenant_id
+                        AND bindings.app_logical_name = p.app_logical_name
+                        AND bindings.role_id IN (SELECT role_id FROM subject_role_ids)
+                ) AS app_accessible,
+                p.can_read,
+                p.can_create,
+                p.can_update,
+                p.can_delete
+            FROM app_role_entity_permissions AS p
+            INNER JOIN rbac_roles AS roles
+                ON roles.id = p.role_id
+            WHERE p.tenant_id = $1
+                AND p.entity_logical_name = $3
+                AND p.role_id IN (SELECT role_id FROM subject_role_ids)
+            ORDER BY p.app_logical_name, roles.name
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .bind(entity_logical_name)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to load app role permissions for subject '{}' entity '{}' in tenant '{}': {error}",
+                subject, entity_logical_name, tenant_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped app role permission lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| SubjectAppRolePermission {
+                app_logical_name: row.app_logical_name,
+                role_name: row.role_name,
+                app_accessible: row.app_accessible,
+                can_read: row.can_read,
+                can_create: row.can_create,
+                can_update: row.can_update,
+                can_delete: row.can_delete,
+            })
+            .collect())
+    }
+}
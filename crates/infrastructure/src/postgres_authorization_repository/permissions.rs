@@ -18,7 +18,9 @@ impl PostgresAuthorizationRepository {
             INNER JOIN rbac_role_grants AS grants
                 ON grants.role_id = subject_roles.role_id
             WHERE subject_roles.tenant_id = $1
-                AND subject_roles.subject = $2
+                AND (subject_roles.subject_type, subject_roles.subject) IN (
+                    SELECT subject_type, subject FROM qryvanta_subject_principals($1, $2)
+                )
             "#,
         )
         .bind(tenant_id.as_uuid())
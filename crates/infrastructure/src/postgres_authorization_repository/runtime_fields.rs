@@ -12,11 +12,17 @@ impl PostgresAuthorizationRepository {
         let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
         let rows = sqlx::query_as::<_, RuntimeFieldGrantRow>(
             r#"
-            SELECT field_logical_name, can_read, can_write
+            SELECT
+                field_logical_name,
+                bool_or(can_read) AS can_read,
+                bool_or(can_write) AS can_write
             FROM runtime_subject_field_permissions
             WHERE tenant_id = $1
-              AND subject = $2
+              AND (subject_type, subject) IN (
+                  SELECT subject_type, subject FROM qryvanta_subject_principals($1, $2)
+              )
               AND entity_logical_name = $3
+            GROUP BY field_logical_name
             ORDER BY field_logical_name
             "#,
         )
@@ -0,0 +1,43 @@
+use qryvanta_core::AppError;
+
+use super::*;
+
+impl PostgresAuthorizationRepository {
+    pub(super) async fn list_team_peer_subjects_impl(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<String>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let subjects = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT DISTINCT peers.subject
+            FROM security_team_members AS members
+            INNER JOIN security_team_members AS peers
+                ON peers.tenant_id = members.tenant_id
+                AND peers.team_id = members.team_id
+            WHERE members.tenant_id = $1
+              AND members.subject = $2
+              AND peers.subject <> $2
+            ORDER BY peers.subject
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to load team peers for subject '{}' in tenant '{}': {error}",
+                subject, tenant_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped team peer lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(subjects)
+    }
+}
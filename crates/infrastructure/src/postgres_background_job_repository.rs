@@ -0,0 +1,452 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::types::Json;
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    BackgroundJob, BackgroundJobKind, BackgroundJobLease, BackgroundJobRepository,
+    BackgroundJobStage, BackgroundJobStatus, CreateBackgroundJobInput,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+use crate::postgres_tenant_rls::begin_background_job_transaction;
+
+/// PostgreSQL-backed repository for long-running background jobs.
+#[derive(Clone)]
+pub struct PostgresBackgroundJobRepository {
+    pool: PgPool,
+}
+
+impl PostgresBackgroundJobRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BackgroundJobStageDocument {
+    name: String,
+    processed: u64,
+    total: Option<u64>,
+    completed: bool,
+}
+
+impl From<&BackgroundJobStage> for BackgroundJobStageDocument {
+    fn from(stage: &BackgroundJobStage) -> Self {
+        Self {
+            name: stage.name.clone(),
+            processed: stage.processed,
+            total: stage.total,
+            completed: stage.completed,
+        }
+    }
+}
+
+impl From<BackgroundJobStageDocument> for BackgroundJobStage {
+    fn from(document: BackgroundJobStageDocument) -> Self {
+        Self {
+            name: document.name,
+            processed: document.processed,
+            total: document.total,
+            completed: document.completed,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct BackgroundJobRow {
+    id: uuid::Uuid,
+    tenant_id: uuid::Uuid,
+    kind: String,
+    status: String,
+    requested_by_subject: String,
+    requested_by_display_name: String,
+    requested_by_email: Option<String>,
+    parameters: Value,
+    stages: Json<Vec<BackgroundJobStageDocument>>,
+    checkpoint: Option<Value>,
+    cancel_requested: bool,
+    attempt_count: i32,
+    status_message: Option<String>,
+    created_at: DateTime<Utc>,
+    started_at: Option<DateTime<Utc>>,
+    updated_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<BackgroundJobRow> for BackgroundJob {
+    type Error = AppError;
+
+    fn try_from(row: BackgroundJobRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            job_id: row.id.to_string(),
+            kind: BackgroundJobKind::parse(&row.kind)?,
+            status: BackgroundJobStatus::parse(&row.status)?,
+            requested_by_subject: row.requested_by_subject,
+            requested_by_display_name: row.requested_by_display_name,
+            requested_by_email: row.requested_by_email,
+            parameters: row.parameters,
+            stages: row
+                .stages
+                .0
+                .into_iter()
+                .map(BackgroundJobStage::from)
+                .collect(),
+            checkpoint: row.checkpoint,
+            cancel_requested: row.cancel_requested,
+            attempt_count: u32::try_from(row.attempt_count).map_err(|error| {
+                AppError::Internal(format!("invalid background job attempt count: {error}"))
+            })?,
+            status_message: row.status_message,
+            created_at: row.created_at,
+            started_at: row.started_at,
+            updated_at: row.updated_at,
+            finished_at: row.finished_at,
+        })
+    }
+}
+
+const JOB_COLUMNS: &str = r#"
+    id,
+    tenant_id,
+    kind,
+    status,
+    requested_by_subject,
+    requested_by_display_name,
+    requested_by_email,
+    parameters,
+    stages,
+    checkpoint,
+    cancel_requested,
+    attempt_count,
+    status_message,
+    created_at,
+    started_at,
+    updated_at,
+    finished_at
+"#;
+
+fn parse_job_id(job_id: &str) -> AppResult<uuid::Uuid> {
+    uuid::Uuid::parse_str(job_id)
+        .map_err(|_| AppError::NotFound(format!("background job '{job_id}' does not exist")))
+}
+
+fn stage_documents(stages: &[BackgroundJobStage]) -> Vec<BackgroundJobStageDocument> {
+    stages
+        .iter()
+        .map(BackgroundJobStageDocument::from)
+        .collect()
+}
+
+#[async_trait]
+impl BackgroundJobRepository for PostgresBackgroundJobRepository {
+    async fn create_background_job(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        requested_by_display_name: &str,
+        requested_by_email: Option<&str>,
+        input: CreateBackgroundJobInput,
+    ) -> AppResult<BackgroundJob> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, BackgroundJobRow>(&format!(
+            r#"
+            INSERT INTO background_jobs (
+                id,
+                tenant_id,
+                kind,
+                requested_by_subject,
+                requested_by_display_name,
+                requested_by_email,
+                parameters,
+                stages
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING {JOB_COLUMNS}
+            "#
+        ))
+        .bind(uuid::Uuid::new_v4())
+        .bind(tenant_id.as_uuid())
+        .bind(input.kind.as_str())
+        .bind(requested_by_subject)
+        .bind(requested_by_display_name)
+        .bind(requested_by_email)
+        .bind(&input.parameters)
+        .bind(Json(stage_documents(&input.stages)))
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to queue background job: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit background job transaction: {error}"
+            ))
+        })?;
+
+        BackgroundJob::try_from(row)
+    }
+
+    async fn find_background_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Option<BackgroundJob>> {
+        let job_uuid = parse_job_id(job_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, BackgroundJobRow>(&format!(
+            r#"
+            SELECT {JOB_COLUMNS}
+            FROM background_jobs
+            WHERE tenant_id = $1 AND id = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to find background job: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit background job transaction: {error}"
+            ))
+        })?;
+
+        row.map(BackgroundJob::try_from).transpose()
+    }
+
+    async fn list_background_jobs(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: Option<&str>,
+        limit: usize,
+    ) -> AppResult<Vec<BackgroundJob>> {
+        let limit = i64::try_from(limit).map_err(|error| {
+            AppError::Validation(format!("invalid background job list limit: {error}"))
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, BackgroundJobRow>(&format!(
+            r#"
+            SELECT {JOB_COLUMNS}
+            FROM background_jobs
+            WHERE tenant_id = $1
+              AND ($2::TEXT IS NULL OR requested_by_subject = $2)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $3
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(requested_by_subject)
+        .bind(limit)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list background jobs: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit background job transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter().map(BackgroundJob::try_from).collect()
+    }
+
+    async fn request_background_job_cancellation(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Option<BackgroundJob>> {
+        let job_uuid = parse_job_id(job_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, BackgroundJobRow>(&format!(
+            r#"
+            UPDATE background_jobs
+            SET cancel_requested = true,
+                status = CASE WHEN status = 'queued' THEN 'cancelled' ELSE status END,
+                finished_at = CASE WHEN status = 'queued' THEN now() ELSE finished_at END,
+                updated_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND status IN ('queued', 'running')
+            RETURNING {JOB_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to cancel background job: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit background job transaction: {error}"
+            ))
+        })?;
+
+        row.map(BackgroundJob::try_from).transpose()
+    }
+
+    async fn claim_background_job(
+        &self,
+        lease_token: &str,
+        lease_expires_at: DateTime<Utc>,
+    ) -> AppResult<Option<BackgroundJobLease>> {
+        let mut transaction = begin_background_job_transaction(&self.pool).await?;
+        let row = sqlx::query_as::<_, BackgroundJobRow>(&format!(
+            r#"
+            UPDATE background_jobs
+            SET status = 'running',
+                lease_token = $1,
+                lease_expires_at = $2,
+                attempt_count = attempt_count + 1,
+                started_at = COALESCE(started_at, now()),
+                updated_at = now()
+            WHERE id = (
+                SELECT id
+                FROM background_jobs
+                WHERE status = 'queued'
+                   OR (
+                       status = 'running'
+                       AND (lease_expires_at IS NULL OR lease_expires_at <= now())
+                   )
+                ORDER BY created_at ASC, id ASC
+                LIMIT 1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING {JOB_COLUMNS}
+            "#
+        ))
+        .bind(lease_token)
+        .bind(lease_expires_at)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to claim background job: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit background job claim transaction: {error}"
+            ))
+        })?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let tenant_id = TenantId::from_uuid(row.tenant_id);
+
+        Ok(Some(BackgroundJobLease {
+            tenant_id,
+            job: BackgroundJob::try_from(row)?,
+            lease_token: lease_token.to_owned(),
+            expires_at: lease_expires_at,
+        }))
+    }
+
+    async fn save_background_job_progress(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        lease_token: &str,
+        stages: &[BackgroundJobStage],
+        checkpoint: Option<&Value>,
+        lease_expires_at: DateTime<Utc>,
+    ) -> AppResult<Option<BackgroundJob>> {
+        let job_uuid = parse_job_id(job_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, BackgroundJobRow>(&format!(
+            r#"
+            UPDATE background_jobs
+            SET stages = $4,
+                checkpoint = $5,
+                lease_expires_at = $6,
+                updated_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND status = 'running'
+              AND lease_token = $3
+            RETURNING {JOB_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .bind(lease_token)
+        .bind(Json(stage_documents(stages)))
+        .bind(checkpoint)
+        .bind(lease_expires_at)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to save background job progress: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit background job transaction: {error}"
+            ))
+        })?;
+
+        row.map(BackgroundJob::try_from).transpose()
+    }
+
+    async fn finish_background_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        lease_token: &str,
+        status: BackgroundJobStatus,
+        status_message: Option<&str>,
+    ) -> AppResult<Option<BackgroundJob>> {
+        if !status.is_terminal() {
+            return Err(AppError::Validation(format!(
+                "background job cannot finish as '{}'",
+                status.as_str()
+            )));
+        }
+        let job_uuid = parse_job_id(job_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, BackgroundJobRow>(&format!(
+            r#"
+            UPDATE background_jobs
+            SET status = $4,
+                status_message = $5,
+                lease_token = NULL,
+                lease_expires_at = NULL,
+                finished_at = now(),
+                updated_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND status = 'running'
+              AND lease_token = $3
+            RETURNING {JOB_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .bind(lease_token)
+        .bind(status.as_str())
+        .bind(status_message)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to finish background job: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit background job transaction: {error}"
+            ))
+        })?;
+
+        row.map(BackgroundJob::try_from).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests;
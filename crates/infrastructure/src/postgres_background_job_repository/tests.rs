@@ -0,0 +1,182 @@
+use chrono::{Duration, Utc};
+use qryvanta_application::{
+    BackgroundJobKind, BackgroundJobLease, BackgroundJobRepository, BackgroundJobStage,
+    BackgroundJobStatus, CreateBackgroundJobInput,
+};
+use qryvanta_core::TenantId;
+use serde_json::json;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresBackgroundJobRepository;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres background job tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn ensure_tenant(pool: &PgPool, tenant_id: TenantId, name: &str) {
+    let insert = sqlx::query(
+        r#"
+            INSERT INTO tenants (id, name)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(name)
+    .execute(pool)
+    .await;
+
+    assert!(insert.is_ok());
+}
+
+/// Claims jobs until one of `job_id` comes back; jobs of other tests stay leased.
+async fn claim_job(
+    repository: &PostgresBackgroundJobRepository,
+    job_id: &str,
+    lease_token: &str,
+) -> BackgroundJobLease {
+    for _ in 0..100 {
+        let lease = repository
+            .claim_background_job(lease_token, Utc::now() + Duration::seconds(60))
+            .await
+            .unwrap_or_else(|error| panic!("failed to claim background job: {error}"))
+            .unwrap_or_else(|| panic!("background job '{job_id}' was not claimable"));
+        if lease.job.job_id == job_id {
+            return lease;
+        }
+    }
+
+    panic!("background job '{job_id}' was not claimed");
+}
+
+#[tokio::test]
+async fn claimed_jobs_keep_progress_and_resume_after_their_lease_lapses() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresBackgroundJobRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Background Job Tenant").await;
+    ensure_tenant(&pool, other_tenant_id, "Background Job Other Tenant").await;
+
+    let job = repository
+        .create_background_job(
+            tenant_id,
+            "alice",
+            "Alice",
+            Some("alice@example.com"),
+            CreateBackgroundJobInput {
+                kind: BackgroundJobKind::QrywellSync,
+                parameters: json!({ "entity_logical_names": ["contact"] }),
+                stages: vec![BackgroundJobStage::new("contact", None)],
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to create background job: {error}"));
+    assert_eq!(job.status, BackgroundJobStatus::Queued);
+    assert!(
+        repository
+            .find_background_job(other_tenant_id, job.job_id.as_str())
+            .await
+            .unwrap_or_else(|error| panic!("failed to find background job: {error}"))
+            .is_none()
+    );
+
+    let first_lease = claim_job(&repository, job.job_id.as_str(), "lease-one").await;
+    assert_eq!(first_lease.tenant_id, tenant_id);
+    assert_eq!(first_lease.job.status, BackgroundJobStatus::Running);
+    assert_eq!(first_lease.job.attempt_count, 1);
+
+    let mut stages = first_lease.job.stages.clone();
+    stages[0].processed = 200;
+    let checkpoint = json!({ "entity_index": 0, "offset": 200 });
+    let progressed = repository
+        .save_background_job_progress(
+            tenant_id,
+            job.job_id.as_str(),
+            "lease-one",
+            &stages,
+            Some(&checkpoint),
+            Utc::now() - Duration::seconds(1),
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to save background job progress: {error}"))
+        .unwrap_or_else(|| panic!("lease-one should still hold the job"));
+    assert_eq!(progressed.stages[0].processed, 200);
+
+    let resumed = claim_job(&repository, job.job_id.as_str(), "lease-two").await;
+    assert_eq!(resumed.job.attempt_count, 2);
+    assert_eq!(resumed.job.checkpoint, Some(checkpoint));
+    assert!(
+        repository
+            .save_background_job_progress(
+                tenant_id,
+                job.job_id.as_str(),
+                "lease-one",
+                &stages,
+                None,
+                Utc::now() + Duration::seconds(60),
+            )
+            .await
+            .unwrap_or_else(|error| panic!("failed to save background job progress: {error}"))
+            .is_none()
+    );
+
+    let flagged = repository
+        .request_background_job_cancellation(tenant_id, job.job_id.as_str())
+        .await
+        .unwrap_or_else(|error| panic!("failed to cancel background job: {error}"))
+        .unwrap_or_else(|| panic!("running job should accept cancellation"));
+    assert_eq!(flagged.status, BackgroundJobStatus::Running);
+    assert!(flagged.cancel_requested);
+
+    let finished = repository
+        .finish_background_job(
+            tenant_id,
+            job.job_id.as_str(),
+            "lease-two",
+            BackgroundJobStatus::Cancelled,
+            Some("cancelled by alice"),
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to finish background job: {error}"))
+        .unwrap_or_else(|| panic!("lease-two should hold the job"));
+    assert_eq!(finished.status, BackgroundJobStatus::Cancelled);
+    assert!(finished.finished_at.is_some());
+
+    let listed = repository
+        .list_background_jobs(tenant_id, Some("alice"), 10)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list background jobs: {error}"));
+    assert_eq!(listed.len(), 1);
+    assert!(
+        repository
+            .list_background_jobs(tenant_id, Some("bob"), 10)
+            .await
+            .unwrap_or_else(|error| panic!("failed to list background jobs: {error}"))
+            .is_empty()
+    );
+}
@@ -0,0 +1,357 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    ComplianceZoneAssignment, ComplianceZoneRepository, ComplianceZoneTag,
+    SaveComplianceZoneTagInput,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for compliance zone assignments and restrictions.
+#[derive(Clone)]
+pub struct PostgresComplianceZoneRepository {
+    pool: PgPool,
+}
+
+impl PostgresComplianceZoneRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct ComplianceZoneAssignmentRow {
+    subject: String,
+    zone: String,
+    assigned_by_subject: String,
+    created_at: DateTime<Utc>,
+}
+
+impl From<ComplianceZoneAssignmentRow> for ComplianceZoneAssignment {
+    fn from(row: ComplianceZoneAssignmentRow) -> Self {
+        Self {
+            subject: row.subject,
+            zone: row.zone,
+            assigned_by_subject: row.assigned_by_subject,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct ComplianceZoneTagRow {
+    entity_logical_name: String,
+    record_id: Option<String>,
+    zone: String,
+    tagged_by_subject: String,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ComplianceZoneTagRow> for ComplianceZoneTag {
+    fn from(row: ComplianceZoneTagRow) -> Self {
+        Self {
+            entity_logical_name: row.entity_logical_name,
+            record_id: row.record_id,
+            zone: row.zone,
+            tagged_by_subject: row.tagged_by_subject,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+const TAG_COLUMNS: &str = r#"
+    entity_logical_name,
+    record_id,
+    zone,
+    tagged_by_subject,
+    updated_at
+"#;
+
+#[async_trait]
+impl ComplianceZoneRepository for PostgresComplianceZoneRepository {
+    async fn list_zone_assignments(
+        &self,
+        tenant_id: TenantId,
+        subject: Option<&str>,
+    ) -> AppResult<Vec<ComplianceZoneAssignment>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, ComplianceZoneAssignmentRow>(
+            r#"
+            SELECT subject, zone, assigned_by_subject, created_at
+            FROM compliance_zone_assignments
+            WHERE tenant_id = $1
+              AND ($2::TEXT IS NULL OR subject = $2)
+            ORDER BY subject, zone
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list compliance zone assignments: {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit compliance zone transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(ComplianceZoneAssignment::from)
+            .collect())
+    }
+
+    async fn assign_zone(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        zone: &str,
+        assigned_by_subject: &str,
+    ) -> AppResult<ComplianceZoneAssignment> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO compliance_zone_assignments (
+                tenant_id,
+                subject,
+                zone,
+                assigned_by_subject
+            )
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (tenant_id, subject, zone) DO NOTHING
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .bind(zone)
+        .bind(assigned_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to assign compliance zone: {error}"))
+        })?;
+
+        let row = sqlx::query_as::<_, ComplianceZoneAssignmentRow>(
+            r#"
+            SELECT subject, zone, assigned_by_subject, created_at
+            FROM compliance_zone_assignments
+            WHERE tenant_id = $1 AND subject = $2 AND zone = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .bind(zone)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to load compliance zone assignment: {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit compliance zone transaction: {error}"
+            ))
+        })?;
+
+        Ok(ComplianceZoneAssignment::from(row))
+    }
+
+    async fn unassign_zone(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        zone: &str,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM compliance_zone_assignments
+            WHERE tenant_id = $1 AND subject = $2 AND zone = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .bind(zone)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to unassign compliance zone: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit compliance zone transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_zone_tags(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<ComplianceZoneTag>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, ComplianceZoneTagRow>(&format!(
+            r#"
+            SELECT {TAG_COLUMNS}
+            FROM compliance_zone_tags
+            WHERE tenant_id = $1
+              AND ($2::TEXT IS NULL OR entity_logical_name = $2)
+            ORDER BY entity_logical_name, record_id NULLS FIRST
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list compliance zone tags: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit compliance zone transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(ComplianceZoneTag::from).collect())
+    }
+
+    async fn save_zone_tag(
+        &self,
+        tenant_id: TenantId,
+        input: SaveComplianceZoneTagInput,
+        tagged_by_subject: &str,
+    ) -> AppResult<ComplianceZoneTag> {
+        let conflict_target = if input.record_id.is_some() {
+            "(tenant_id, entity_logical_name, record_id) WHERE record_id IS NOT NULL"
+        } else {
+            "(tenant_id, entity_logical_name) WHERE record_id IS NULL"
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, ComplianceZoneTagRow>(&format!(
+            r#"
+            INSERT INTO compliance_zone_tags (
+                id,
+                tenant_id,
+                entity_logical_name,
+                record_id,
+                zone,
+                tagged_by_subject
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT {conflict_target}
+            DO UPDATE SET
+                zone = EXCLUDED.zone,
+                tagged_by_subject = EXCLUDED.tagged_by_subject,
+                updated_at = now()
+            RETURNING {TAG_COLUMNS}
+            "#
+        ))
+        .bind(uuid::Uuid::new_v4())
+        .bind(tenant_id.as_uuid())
+        .bind(input.entity_logical_name)
+        .bind(input.record_id)
+        .bind(input.zone)
+        .bind(tagged_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to save compliance zone tag: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit compliance zone transaction: {error}"
+            ))
+        })?;
+
+        Ok(ComplianceZoneTag::from(row))
+    }
+
+    async fn remove_zone_tag(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: Option<&str>,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM compliance_zone_tags
+            WHERE tenant_id = $1
+              AND entity_logical_name = $2
+              AND record_id IS NOT DISTINCT FROM $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(record_id)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to remove compliance zone tag: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit compliance zone transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_zone_tags(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_ids: &[String],
+    ) -> AppResult<Vec<ComplianceZoneTag>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, ComplianceZoneTagRow>(&format!(
+            r#"
+            SELECT {TAG_COLUMNS}
+            FROM compliance_zone_tags
+            WHERE tenant_id = $1
+              AND entity_logical_name = $2
+              AND (record_id IS NULL OR record_id = ANY($3))
+            ORDER BY record_id NULLS FIRST
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(record_ids)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to find compliance zone tags: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit compliance zone transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(ComplianceZoneTag::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests;
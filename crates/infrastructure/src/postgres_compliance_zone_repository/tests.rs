@@ -0,0 +1,98 @@
+use qryvanta_application::{ComplianceZoneRepository, SaveComplianceZoneTagInput};
+use qryvanta_core::TenantId;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresComplianceZoneRepository;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres compliance zone tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn ensure_tenant(pool: &PgPool, tenant_id: TenantId, name: &str) {
+    let insert = sqlx::query(
+        r#"
+            INSERT INTO tenants (id, name)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(name)
+    .execute(pool)
+    .await;
+
+    assert!(insert.is_ok());
+}
+
+fn tag_input(record_id: Option<&str>, zone: &str) -> SaveComplianceZoneTagInput {
+    SaveComplianceZoneTagInput {
+        entity_logical_name: "contact".to_owned(),
+        record_id: record_id.map(ToOwned::to_owned),
+        zone: zone.to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn zone_tags_upsert_per_target_and_match_entity_and_listed_records() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresComplianceZoneRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Compliance Zone Tenant").await;
+
+    for input in [
+        tag_input(None, "us"),
+        tag_input(None, "eu"),
+        tag_input(Some("record-1"), "ch"),
+        tag_input(Some("record-2"), "us"),
+    ] {
+        let saved = repository.save_zone_tag(tenant_id, input, "alice").await;
+        assert!(saved.is_ok());
+    }
+
+    let tags = repository
+        .find_zone_tags(tenant_id, "contact", &["record-1".to_owned()])
+        .await
+        .unwrap_or_else(|error| panic!("failed to find compliance zone tags: {error}"));
+    let targets = tags
+        .iter()
+        .map(|tag| (tag.record_id.as_deref(), tag.zone.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(targets, vec![(None, "eu"), (Some("record-1"), "ch")]);
+
+    let removed = repository
+        .remove_zone_tag(tenant_id, "contact", None)
+        .await
+        .unwrap_or_else(|error| panic!("failed to remove compliance zone tag: {error}"));
+    assert!(removed);
+
+    let remaining = repository
+        .list_zone_tags(tenant_id, Some("contact"))
+        .await
+        .unwrap_or_else(|error| panic!("failed to list compliance zone tags: {error}"));
+    assert_eq!(remaining.len(), 2);
+    assert!(remaining.iter().all(|tag| tag.record_id.is_some()));
+}
@@ -0,0 +1,392 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    ConsentChannel, ContactConsent, ContactConsentChange, ContactConsentRepository,
+    ContactConsentStatus, RecordContactConsentInput,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for contact consent records and history.
+#[derive(Clone)]
+pub struct PostgresContactConsentRepository {
+    pool: PgPool,
+}
+
+impl PostgresContactConsentRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct ContactConsentRow {
+    tenant_id: uuid::Uuid,
+    contact_record_id: uuid::Uuid,
+    purpose: String,
+    channel: String,
+    status: String,
+    source: String,
+    granted_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+    recorded_by_subject: String,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<ContactConsentRow> for ContactConsent {
+    type Error = AppError;
+
+    fn try_from(row: ContactConsentRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            contact_record_id: row.contact_record_id.to_string(),
+            purpose: row.purpose,
+            channel: parse_stored_channel(row.channel.as_str())?,
+            status: parse_stored_status(row.status.as_str())?,
+            source: row.source,
+            granted_at: row.granted_at,
+            revoked_at: row.revoked_at,
+            recorded_by_subject: row.recorded_by_subject,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct ContactConsentChangeRow {
+    id: uuid::Uuid,
+    contact_record_id: uuid::Uuid,
+    purpose: String,
+    channel: String,
+    previous_status: Option<String>,
+    status: String,
+    source: String,
+    recorded_by_subject: String,
+    recorded_at: DateTime<Utc>,
+}
+
+impl TryFrom<ContactConsentChangeRow> for ContactConsentChange {
+    type Error = AppError;
+
+    fn try_from(row: ContactConsentChangeRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            change_id: row.id.to_string(),
+            contact_record_id: row.contact_record_id.to_string(),
+            purpose: row.purpose,
+            channel: parse_stored_channel(row.channel.as_str())?,
+            previous_status: row
+                .previous_status
+                .as_deref()
+                .map(parse_stored_status)
+                .transpose()?,
+            status: parse_stored_status(row.status.as_str())?,
+            source: row.source,
+            recorded_by_subject: row.recorded_by_subject,
+            recorded_at: row.recorded_at,
+        })
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct EmailConsentRow {
+    matched_contacts: i64,
+    consented_contacts: i64,
+}
+
+fn parse_stored_channel(value: &str) -> AppResult<ConsentChannel> {
+    ConsentChannel::parse(value)
+        .map_err(|_| AppError::Internal(format!("unknown stored consent channel '{value}'")))
+}
+
+fn parse_stored_status(value: &str) -> AppResult<ContactConsentStatus> {
+    ContactConsentStatus::parse(value)
+        .map_err(|_| AppError::Internal(format!("unknown stored consent status '{value}'")))
+}
+
+fn parse_contact_record_id(contact_record_id: &str) -> Option<uuid::Uuid> {
+    uuid::Uuid::parse_str(contact_record_id).ok()
+}
+
+const CHANGE_COLUMNS: &str = r#"
+    id,
+    contact_record_id,
+    purpose,
+    channel,
+    previous_status,
+    status,
+    source,
+    recorded_by_subject,
+    recorded_at
+"#;
+
+#[async_trait]
+impl ContactConsentRepository for PostgresContactConsentRepository {
+    async fn record_consent(
+        &self,
+        tenant_id: TenantId,
+        recorded_by_subject: &str,
+        input: RecordContactConsentInput,
+    ) -> AppResult<ContactConsentChange> {
+        let not_found = || {
+            AppError::NotFound(format!(
+                "contact '{}' does not exist",
+                input.contact_record_id
+            ))
+        };
+        let contact_uuid =
+            parse_contact_record_id(input.contact_record_id.as_str()).ok_or_else(not_found)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let contact_exists = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM runtime_records
+                WHERE tenant_id = $1
+                  AND entity_logical_name = 'contact'
+                  AND id = $2
+            )
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(contact_uuid)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to find contact: {error}")))?;
+        if !contact_exists {
+            return Err(not_found());
+        }
+
+        let previous_status = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT status
+            FROM contact_consents
+            WHERE tenant_id = $1
+              AND contact_record_id = $2
+              AND purpose = $3
+              AND channel = $4
+            FOR UPDATE
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(contact_uuid)
+        .bind(input.purpose.as_str())
+        .bind(input.channel.as_str())
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to load contact consent: {error}")))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO contact_consents (
+                tenant_id,
+                contact_record_id,
+                purpose,
+                channel,
+                status,
+                source,
+                granted_at,
+                revoked_at,
+                recorded_by_subject,
+                updated_at
+            )
+            VALUES (
+                $1, $2, $3, $4, $5, $6,
+                CASE WHEN $5 = 'granted' THEN now() END,
+                CASE WHEN $5 = 'revoked' THEN now() END,
+                $7,
+                now()
+            )
+            ON CONFLICT (tenant_id, contact_record_id, purpose, channel) DO UPDATE
+            SET
+                status = EXCLUDED.status,
+                source = EXCLUDED.source,
+                granted_at = COALESCE(EXCLUDED.granted_at, contact_consents.granted_at),
+                revoked_at = COALESCE(EXCLUDED.revoked_at, contact_consents.revoked_at),
+                recorded_by_subject = EXCLUDED.recorded_by_subject,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(contact_uuid)
+        .bind(input.purpose.as_str())
+        .bind(input.channel.as_str())
+        .bind(input.status.as_str())
+        .bind(input.source.as_str())
+        .bind(recorded_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to save contact consent: {error}")))?;
+
+        let row = sqlx::query_as::<_, ContactConsentChangeRow>(&format!(
+            r#"
+            INSERT INTO contact_consent_changes (
+                id,
+                tenant_id,
+                contact_record_id,
+                purpose,
+                channel,
+                previous_status,
+                status,
+                source,
+                recorded_by_subject
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING {CHANGE_COLUMNS}
+            "#
+        ))
+        .bind(uuid::Uuid::new_v4())
+        .bind(tenant_id.as_uuid())
+        .bind(contact_uuid)
+        .bind(input.purpose.as_str())
+        .bind(input.channel.as_str())
+        .bind(previous_status)
+        .bind(input.status.as_str())
+        .bind(input.source.as_str())
+        .bind(recorded_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to append contact consent change: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact consent transaction: {error}"
+            ))
+        })?;
+
+        ContactConsentChange::try_from(row)
+    }
+
+    async fn list_contact_consents(
+        &self,
+        tenant_id: TenantId,
+        contact_record_id: &str,
+    ) -> AppResult<Vec<ContactConsent>> {
+        let Some(contact_uuid) = parse_contact_record_id(contact_record_id) else {
+            return Ok(Vec::new());
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, ContactConsentRow>(
+            r#"
+            SELECT
+                tenant_id,
+                contact_record_id,
+                purpose,
+                channel,
+                status,
+                source,
+                granted_at,
+                revoked_at,
+                recorded_by_subject,
+                updated_at
+            FROM contact_consents
+            WHERE tenant_id = $1 AND contact_record_id = $2
+            ORDER BY purpose, channel
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(contact_uuid)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list contact consents: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact consent transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter().map(ContactConsent::try_from).collect()
+    }
+
+    async fn list_contact_consent_history(
+        &self,
+        tenant_id: TenantId,
+        contact_record_id: &str,
+    ) -> AppResult<Vec<ContactConsentChange>> {
+        let Some(contact_uuid) = parse_contact_record_id(contact_record_id) else {
+            return Ok(Vec::new());
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, ContactConsentChangeRow>(&format!(
+            r#"
+            SELECT {CHANGE_COLUMNS}
+            FROM contact_consent_changes
+            WHERE tenant_id = $1 AND contact_record_id = $2
+            ORDER BY recorded_at DESC, id DESC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(contact_uuid)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list contact consent history: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact consent transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(ContactConsentChange::try_from)
+            .collect()
+    }
+
+    async fn email_consent_for_address(
+        &self,
+        tenant_id: TenantId,
+        email: &str,
+        purpose: &str,
+    ) -> AppResult<Option<bool>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, EmailConsentRow>(
+            r#"
+            SELECT
+                COUNT(*) AS matched_contacts,
+                COUNT(*) FILTER (WHERE contact_consents.status = 'granted') AS consented_contacts
+            FROM runtime_records
+            LEFT JOIN contact_consents
+                ON contact_consents.tenant_id = runtime_records.tenant_id
+               AND contact_consents.contact_record_id = runtime_records.id
+               AND contact_consents.purpose = $3
+               AND contact_consents.channel = 'email'
+            WHERE runtime_records.tenant_id = $1
+              AND runtime_records.entity_logical_name = 'contact'
+              AND lower(runtime_records.data->>'email') = lower($2)
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(email.trim())
+        .bind(purpose)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to resolve contact email consent: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact consent transaction: {error}"
+            ))
+        })?;
+
+        if row.matched_contacts == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(row.consented_contacts == row.matched_contacts))
+    }
+}
@@ -0,0 +1,510 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    ContactIdentityKeys, ContactIdentityLink, ContactIdentityRepository, ContactIdentitySource,
+    ContactMatchReason, NewContactIdentityLink, SaveContactIdentitySourceInput,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for contact identity sources and master-contact links.
+#[derive(Clone)]
+pub struct PostgresContactIdentityRepository {
+    pool: PgPool,
+}
+
+impl PostgresContactIdentityRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct ContactIdentitySourceRow {
+    entity_logical_name: String,
+    email_field_logical_name: Option<String>,
+    phone_field_logical_name: Option<String>,
+    name_field_logical_names: Vec<String>,
+    updated_by_subject: String,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ContactIdentitySourceRow> for ContactIdentitySource {
+    fn from(row: ContactIdentitySourceRow) -> Self {
+        Self {
+            entity_logical_name: row.entity_logical_name,
+            email_field_logical_name: row.email_field_logical_name,
+            phone_field_logical_name: row.phone_field_logical_name,
+            name_field_logical_names: row.name_field_logical_names,
+            updated_by_subject: row.updated_by_subject,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct ContactIdentityLinkRow {
+    master_contact_id: uuid::Uuid,
+    entity_logical_name: String,
+    record_id: uuid::Uuid,
+    email_key: Option<String>,
+    phone_key: Option<String>,
+    name_key: Option<String>,
+    match_reason: String,
+    confidence: i16,
+    linked_by_subject: String,
+    linked_at: DateTime<Utc>,
+}
+
+impl TryFrom<ContactIdentityLinkRow> for ContactIdentityLink {
+    type Error = AppError;
+
+    fn try_from(row: ContactIdentityLinkRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            master_contact_id: row.master_contact_id.to_string(),
+            entity_logical_name: row.entity_logical_name,
+            record_id: row.record_id.to_string(),
+            keys: ContactIdentityKeys {
+                email: row.email_key,
+                phone: row.phone_key,
+                name: row.name_key,
+            },
+            match_reason: ContactMatchReason::parse(row.match_reason.as_str()).map_err(|_| {
+                AppError::Internal(format!(
+                    "unknown stored contact match reason '{}'",
+                    row.match_reason
+                ))
+            })?,
+            confidence: u8::try_from(row.confidence).map_err(|_| {
+                AppError::Internal(format!(
+                    "invalid stored contact match confidence '{}'",
+                    row.confidence
+                ))
+            })?,
+            linked_by_subject: row.linked_by_subject,
+            linked_at: row.linked_at,
+        })
+    }
+}
+
+fn parse_uuid(value: &str) -> Option<uuid::Uuid> {
+    uuid::Uuid::parse_str(value).ok()
+}
+
+const SOURCE_COLUMNS: &str = r#"
+    entity_logical_name,
+    email_field_logical_name,
+    phone_field_logical_name,
+    name_field_logical_names,
+    updated_by_subject,
+    updated_at
+"#;
+
+const LINK_COLUMNS: &str = r#"
+    master_contact_id,
+    entity_logical_name,
+    record_id,
+    email_key,
+    phone_key,
+    name_key,
+    match_reason,
+    confidence,
+    linked_by_subject,
+    linked_at
+"#;
+
+#[async_trait]
+impl ContactIdentityRepository for PostgresContactIdentityRepository {
+    async fn save_source(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveContactIdentitySourceInput,
+    ) -> AppResult<ContactIdentitySource> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, ContactIdentitySourceRow>(&format!(
+            r#"
+            INSERT INTO contact_identity_sources (
+                tenant_id,
+                entity_logical_name,
+                email_field_logical_name,
+                phone_field_logical_name,
+                name_field_logical_names,
+                updated_by_subject,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, now())
+            ON CONFLICT (tenant_id, entity_logical_name) DO UPDATE
+            SET
+                email_field_logical_name = EXCLUDED.email_field_logical_name,
+                phone_field_logical_name = EXCLUDED.phone_field_logical_name,
+                name_field_logical_names = EXCLUDED.name_field_logical_names,
+                updated_by_subject = EXCLUDED.updated_by_subject,
+                updated_at = EXCLUDED.updated_at
+            RETURNING {SOURCE_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(input.entity_logical_name.as_str())
+        .bind(input.email_field_logical_name.as_deref())
+        .bind(input.phone_field_logical_name.as_deref())
+        .bind(&input.name_field_logical_names)
+        .bind(updated_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to save contact identity source: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact identity transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.into())
+    }
+
+    async fn list_sources(&self, tenant_id: TenantId) -> AppResult<Vec<ContactIdentitySource>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, ContactIdentitySourceRow>(&format!(
+            r#"
+            SELECT {SOURCE_COLUMNS}
+            FROM contact_identity_sources
+            WHERE tenant_id = $1
+            ORDER BY entity_logical_name
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list contact identity sources: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact identity transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(ContactIdentitySource::from).collect())
+    }
+
+    async fn find_source(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<ContactIdentitySource>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, ContactIdentitySourceRow>(&format!(
+            r#"
+            SELECT {SOURCE_COLUMNS}
+            FROM contact_identity_sources
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to find contact identity source: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact identity transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(ContactIdentitySource::from))
+    }
+
+    async fn delete_source(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let deleted = sqlx::query(
+            r#"
+            DELETE FROM contact_identity_sources
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to delete contact identity source: {error}"))
+        })?
+        .rows_affected()
+            > 0;
+
+        sqlx::query(
+            r#"
+            DELETE FROM contact_identity_links
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to delete contact identity links: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact identity transaction: {error}"
+            ))
+        })?;
+
+        Ok(deleted)
+    }
+
+    async fn find_link(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<ContactIdentityLink>> {
+        let Some(record_uuid) = parse_uuid(record_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, ContactIdentityLinkRow>(&format!(
+            r#"
+            SELECT {LINK_COLUMNS}
+            FROM contact_identity_links
+            WHERE tenant_id = $1 AND entity_logical_name = $2 AND record_id = $3
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(record_uuid)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to find contact identity link: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact identity transaction: {error}"
+            ))
+        })?;
+
+        row.map(ContactIdentityLink::try_from).transpose()
+    }
+
+    async fn list_candidate_links(
+        &self,
+        tenant_id: TenantId,
+        keys: &ContactIdentityKeys,
+        limit: usize,
+    ) -> AppResult<Vec<ContactIdentityLink>> {
+        let limit = i64::try_from(limit).map_err(|_| {
+            AppError::Validation("contact identity candidate limit is too large".to_owned())
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, ContactIdentityLinkRow>(&format!(
+            r#"
+            SELECT {LINK_COLUMNS}
+            FROM contact_identity_links
+            WHERE tenant_id = $1
+              AND (email_key = $2 OR phone_key = $3 OR name_block = $4)
+            ORDER BY linked_at, record_id
+            LIMIT $5
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(keys.email.as_deref())
+        .bind(keys.phone.as_deref())
+        .bind(keys.name_block())
+        .bind(limit)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list contact identity candidates: {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact identity transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(ContactIdentityLink::try_from)
+            .collect()
+    }
+
+    async fn save_link(
+        &self,
+        tenant_id: TenantId,
+        linked_by_subject: &str,
+        link: NewContactIdentityLink,
+    ) -> AppResult<ContactIdentityLink> {
+        let record_uuid = parse_uuid(link.record_id.as_str()).ok_or_else(|| {
+            AppError::NotFound(format!(
+                "runtime record '{}' does not exist",
+                link.record_id
+            ))
+        })?;
+        let master_uuid = parse_uuid(link.master_contact_id.as_str()).ok_or_else(|| {
+            AppError::NotFound(format!(
+                "master contact '{}' does not exist",
+                link.master_contact_id
+            ))
+        })?;
+        let name_block = link.keys.name_block();
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, ContactIdentityLinkRow>(&format!(
+            r#"
+            INSERT INTO contact_identity_links (
+                tenant_id,
+                entity_logical_name,
+                record_id,
+                master_contact_id,
+                email_key,
+                phone_key,
+                name_key,
+                name_block,
+                match_reason,
+                confidence,
+                linked_by_subject,
+                linked_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, now())
+            ON CONFLICT (tenant_id, entity_logical_name, record_id) DO UPDATE
+            SET
+                master_contact_id = EXCLUDED.master_contact_id,
+                email_key = EXCLUDED.email_key,
+                phone_key = EXCLUDED.phone_key,
+                name_key = EXCLUDED.name_key,
+                name_block = EXCLUDED.name_block,
+                match_reason = EXCLUDED.match_reason,
+                confidence = EXCLUDED.confidence,
+                linked_by_subject = EXCLUDED.linked_by_subject,
+                linked_at = CASE
+                    WHEN contact_identity_links.master_contact_id = EXCLUDED.master_contact_id
+                        THEN contact_identity_links.linked_at
+                    ELSE EXCLUDED.linked_at
+                END
+            RETURNING {LINK_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(link.entity_logical_name.as_str())
+        .bind(record_uuid)
+        .bind(master_uuid)
+        .bind(link.keys.email.as_deref())
+        .bind(link.keys.phone.as_deref())
+        .bind(link.keys.name.as_deref())
+        .bind(name_block)
+        .bind(link.match_reason.as_str())
+        .bind(i16::from(link.confidence))
+        .bind(linked_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to save contact identity link: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact identity transaction: {error}"
+            ))
+        })?;
+
+        ContactIdentityLink::try_from(row)
+    }
+
+    async fn delete_link(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<bool> {
+        let Some(record_uuid) = parse_uuid(record_id) else {
+            return Ok(false);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let deleted = sqlx::query(
+            r#"
+            DELETE FROM contact_identity_links
+            WHERE tenant_id = $1 AND entity_logical_name = $2 AND record_id = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(record_uuid)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to delete contact identity link: {error}"))
+        })?
+        .rows_affected()
+            > 0;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact identity transaction: {error}"
+            ))
+        })?;
+
+        Ok(deleted)
+    }
+
+    async fn list_master_links(
+        &self,
+        tenant_id: TenantId,
+        master_contact_id: &str,
+    ) -> AppResult<Vec<ContactIdentityLink>> {
+        let Some(master_uuid) = parse_uuid(master_contact_id) else {
+            return Ok(Vec::new());
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, ContactIdentityLinkRow>(&format!(
+            r#"
+            SELECT {LINK_COLUMNS}
+            FROM contact_identity_links
+            WHERE tenant_id = $1 AND master_contact_id = $2
+            ORDER BY linked_at, entity_logical_name, record_id
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(master_uuid)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list master contact links: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit contact identity transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(ContactIdentityLink::try_from)
+            .collect()
+    }
+}
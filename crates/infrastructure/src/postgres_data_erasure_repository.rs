@@ -0,0 +1,747 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool, Postgres, Transaction};
+
+use qryvanta_application::{
+    DataErasureField, DataErasureRepository, SaveDataErasureFieldsInput, SubjectErasure,
+    SubjectErasureOutcome,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::audit_chain::{AuditChainInput, compute_audit_entry_hash};
+use crate::begin_tenant_transaction;
+use crate::postgres_tenant_rls::begin_membership_subject_lookup_transaction;
+
+/// Contact fields overwritten when a subject's contact record is anonymized.
+const CONTACT_ERASED_FIELDS: [&str; 3] = ["subject", "display_name", "email"];
+
+/// Matches `runtime_records` rows covered by an active legal hold.
+const RECORD_UNDER_LEGAL_HOLD: &str = r#"
+    EXISTS (
+        SELECT 1
+        FROM legal_holds
+        WHERE legal_holds.tenant_id = runtime_records.tenant_id
+          AND legal_holds.scope_type = 'runtime_records'
+          AND legal_holds.released_at IS NULL
+          AND legal_holds.entity_logical_name = runtime_records.entity_logical_name
+          AND (
+              cardinality(legal_holds.record_ids) = 0
+              OR runtime_records.id::TEXT = ANY(legal_holds.record_ids)
+          )
+    )
+"#;
+
+/// Matches `audit_log_entries` rows inside an active audit legal hold range.
+const AUDIT_ENTRY_UNDER_LEGAL_HOLD: &str = r#"
+    EXISTS (
+        SELECT 1
+        FROM legal_holds
+        WHERE legal_holds.tenant_id = audit_log_entries.tenant_id
+          AND legal_holds.scope_type = 'audit_log'
+          AND legal_holds.released_at IS NULL
+          AND audit_log_entries.created_at >= legal_holds.audit_from
+          AND (
+              legal_holds.audit_until IS NULL
+              OR audit_log_entries.created_at < legal_holds.audit_until
+          )
+    )
+"#;
+
+/// PostgreSQL-backed repository for personal data erasure.
+#[derive(Clone)]
+pub struct PostgresDataErasureRepository {
+    pool: PgPool,
+}
+
+impl PostgresDataErasureRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct DataErasureFieldRow {
+    entity_logical_name: String,
+    field_logical_name: String,
+    updated_by_subject: String,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<DataErasureFieldRow> for DataErasureField {
+    fn from(row: DataErasureFieldRow) -> Self {
+        Self {
+            entity_logical_name: row.entity_logical_name,
+            field_logical_name: row.field_logical_name,
+            updated_by_subject: row.updated_by_subject,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct SubjectAuditEntryRow {
+    tenant_id: uuid::Uuid,
+    chain_position: i64,
+    previous_entry_hash: Option<String>,
+    action: String,
+    resource_type: String,
+    resource_id: String,
+    detail: Option<String>,
+    created_at: String,
+    entry_hash: String,
+    redacted_entry_hash: Option<String>,
+}
+
+impl SubjectAuditEntryRow {
+    fn hash_with_subject(&self, subject: &str) -> String {
+        compute_audit_entry_hash(&AuditChainInput {
+            tenant_id: TenantId::from_uuid(self.tenant_id),
+            chain_position: self.chain_position,
+            previous_entry_hash: self.previous_entry_hash.as_deref(),
+            subject,
+            action: &self.action,
+            resource_type: &self.resource_type,
+            resource_id: &self.resource_id,
+            detail: self.detail.as_deref(),
+            created_at_utc: &self.created_at,
+        })
+    }
+}
+
+const FIELD_COLUMNS: &str = r#"
+    entity_logical_name,
+    field_logical_name,
+    updated_by_subject,
+    updated_at
+"#;
+
+#[async_trait]
+impl DataErasureRepository for PostgresDataErasureRepository {
+    async fn list_erasure_fields(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<DataErasureField>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, DataErasureFieldRow>(&format!(
+            r#"
+            SELECT {FIELD_COLUMNS}
+            FROM data_erasure_fields
+            WHERE tenant_id = $1
+              AND ($2::TEXT IS NULL OR entity_logical_name = $2)
+            ORDER BY entity_logical_name, field_logical_name
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list data erasure fields: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped data erasure field list transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(DataErasureField::from).collect())
+    }
+
+    async fn save_erasure_fields(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveDataErasureFieldsInput,
+    ) -> AppResult<Vec<DataErasureField>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            DELETE FROM data_erasure_fields
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(input.entity_logical_name.as_str())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to clear data erasure fields: {error}"))
+        })?;
+
+        let rows = sqlx::query_as::<_, DataErasureFieldRow>(&format!(
+            r#"
+            INSERT INTO data_erasure_fields (
+                tenant_id,
+                entity_logical_name,
+                field_logical_name,
+                updated_by_subject,
+                updated_at
+            )
+            SELECT $1, $2, field_logical_name, $4, now()
+            FROM UNNEST($3::TEXT[]) AS field_logical_name
+            RETURNING {FIELD_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(input.entity_logical_name.as_str())
+        .bind(&input.field_logical_names)
+        .bind(updated_by_subject)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to save data erasure fields: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped data erasure field save transaction: {error}"
+            ))
+        })?;
+
+        let mut fields = rows
+            .into_iter()
+            .map(DataErasureField::from)
+            .collect::<Vec<_>>();
+        fields.sort_by(|left, right| left.field_logical_name.cmp(&right.field_logical_name));
+        Ok(fields)
+    }
+
+    async fn erase_subject(
+        &self,
+        tenant_id: TenantId,
+        erasure: &SubjectErasure,
+    ) -> AppResult<Option<SubjectErasureOutcome>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        // The membership goes first: without one there is nothing to erase,
+        // and dropping the transaction leaves the tenant untouched.
+        let membership = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+            UPDATE tenant_memberships
+            SET subject = $3,
+                display_name = $4,
+                email = NULL,
+                user_id = NULL
+            WHERE tenant_id = $1 AND subject = $2
+            RETURNING id
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(erasure.subject.as_str())
+        .bind(erasure.pseudonym.as_str())
+        .bind(erasure.display_name.as_str())
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to erase tenant membership: {error}"))
+        })?;
+        if membership.is_none() {
+            return Ok(None);
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE rbac_subject_roles
+            SET subject = $3
+            WHERE tenant_id = $1 AND subject = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(erasure.subject.as_str())
+        .bind(erasure.pseudonym.as_str())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to pseudonymize role assignments: {error}"))
+        })?;
+
+        let mut outcome = SubjectErasureOutcome {
+            contact_record_id: anonymize_contact(&mut transaction, tenant_id, erasure).await?,
+            ..SubjectErasureOutcome::default()
+        };
+
+        // Field values are erased before ownership is pseudonymized, while the
+        // owned records can still be found by the original subject.
+        for (entity_logical_name, field_logical_names) in &erasure.fields_by_entity {
+            let (erased, scrubbed) = erase_owned_record_fields(
+                &mut transaction,
+                tenant_id,
+                erasure.subject.as_str(),
+                entity_logical_name.as_str(),
+                field_logical_names,
+            )
+            .await?;
+            outcome.records_erased += erased;
+            outcome.history_entries_scrubbed += scrubbed;
+        }
+
+        let (pseudonymized, held) = pseudonymize_record_references(
+            &mut transaction,
+            tenant_id,
+            erasure.subject.as_str(),
+            erasure.pseudonym.as_str(),
+        )
+        .await?;
+        outcome.records_pseudonymized = pseudonymized;
+        outcome.records_held = held;
+
+        let (pseudonymized, retained) =
+            pseudonymize_audit_entries(&mut transaction, tenant_id, erasure).await?;
+        outcome.audit_entries_pseudonymized = pseudonymized;
+        outcome.audit_entries_retained = retained;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped data erasure transaction: {error}"
+            ))
+        })?;
+
+        Ok(Some(outcome))
+    }
+
+    async fn subject_has_memberships(&self, subject: &str) -> AppResult<bool> {
+        let mut transaction =
+            begin_membership_subject_lookup_transaction(&self.pool, subject).await?;
+        let has_memberships = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM tenant_memberships
+                WHERE subject = $1
+            )
+            "#,
+        )
+        .bind(subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to look up subject memberships: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit subject-scoped membership lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(has_memberships)
+    }
+
+    async fn anonymize_auth_events(&self, subject: &str, pseudonym: &str) -> AppResult<u64> {
+        let result = sqlx::query(
+            r#"
+            UPDATE auth_events
+            SET subject = $2,
+                ip_address = NULL,
+                user_agent = NULL
+            WHERE subject = $1
+            "#,
+        )
+        .bind(subject)
+        .bind(pseudonym)
+        .execute(&self.pool)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to anonymize auth events: {error}")))?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+async fn anonymize_contact(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    erasure: &SubjectErasure,
+) -> AppResult<Option<String>> {
+    let contact_record_id = sqlx::query_scalar::<_, uuid::Uuid>(
+        r#"
+        UPDATE tenant_subject_contacts
+        SET subject = $3,
+            updated_at = now()
+        WHERE tenant_id = $1 AND subject = $2
+        RETURNING contact_record_id
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(erasure.subject.as_str())
+    .bind(erasure.pseudonym.as_str())
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(|error| AppError::Internal(format!("failed to erase contact mapping: {error}")))?;
+    let Some(contact_record_id) = contact_record_id else {
+        return Ok(None);
+    };
+
+    let anonymized = sqlx::query(&format!(
+        r#"
+        UPDATE runtime_records
+        SET data = (data - 'email')
+                || jsonb_build_object('subject', $3::TEXT, 'display_name', $4::TEXT),
+            version = version + 1,
+            updated_at = now()
+        WHERE tenant_id = $1
+          AND id = $2
+          AND NOT {RECORD_UNDER_LEGAL_HOLD}
+        "#
+    ))
+    .bind(tenant_id.as_uuid())
+    .bind(contact_record_id)
+    .bind(erasure.pseudonym.as_str())
+    .bind(erasure.display_name.as_str())
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| AppError::Internal(format!("failed to anonymize contact record: {error}")))?
+    .rows_affected();
+    if anonymized == 0 {
+        return Ok(None);
+    }
+
+    let field_logical_names = CONTACT_ERASED_FIELDS.map(str::to_owned);
+    drop_erased_field_traces(
+        transaction,
+        tenant_id,
+        "contact",
+        &[contact_record_id],
+        &field_logical_names,
+    )
+    .await?;
+
+    Ok(Some(contact_record_id.to_string()))
+}
+
+/// Removes configured fields from the subject's records of one entity.
+///
+/// Returns the erased record count and the scrubbed history entry count.
+async fn erase_owned_record_fields(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    subject: &str,
+    entity_logical_name: &str,
+    field_logical_names: &[String],
+) -> AppResult<(u64, u64)> {
+    let record_ids = sqlx::query_scalar::<_, uuid::Uuid>(&format!(
+        r#"
+        UPDATE runtime_records
+        SET data = data - $4::TEXT[],
+            version = version + 1,
+            updated_at = now()
+        WHERE tenant_id = $1
+          AND entity_logical_name = $2
+          AND created_by_subject = $3
+          AND data ?| $4::TEXT[]
+          AND NOT {RECORD_UNDER_LEGAL_HOLD}
+        RETURNING id
+        "#
+    ))
+    .bind(tenant_id.as_uuid())
+    .bind(entity_logical_name)
+    .bind(subject)
+    .bind(field_logical_names)
+    .fetch_all(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!(
+            "failed to erase personal fields of entity '{entity_logical_name}': {error}"
+        ))
+    })?;
+
+    let scrubbed = drop_erased_field_traces(
+        transaction,
+        tenant_id,
+        entity_logical_name,
+        &record_ids,
+        field_logical_names,
+    )
+    .await?;
+
+    Ok((record_ids.len() as u64, scrubbed))
+}
+
+/// Drops unique index entries and history values of erased fields.
+///
+/// History entries left without changes are deleted. Returns the number of
+/// history entries changed or deleted.
+async fn drop_erased_field_traces(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    entity_logical_name: &str,
+    record_ids: &[uuid::Uuid],
+    field_logical_names: &[String],
+) -> AppResult<u64> {
+    if record_ids.is_empty() {
+        return Ok(0);
+    }
+
+    sqlx::query(
+        r#"
+        DELETE FROM runtime_record_unique_values
+        WHERE tenant_id = $1
+          AND entity_logical_name = $2
+          AND record_id = ANY($3::UUID[])
+          AND field_logical_name = ANY($4::TEXT[])
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(entity_logical_name)
+    .bind(record_ids)
+    .bind(field_logical_names)
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| AppError::Internal(format!("failed to drop erased unique values: {error}")))?;
+
+    let scrubbed = sqlx::query(
+        r#"
+        UPDATE runtime_record_field_history
+        SET changes = COALESCE(
+            (
+                SELECT jsonb_agg(change)
+                FROM jsonb_array_elements(changes) AS change
+                WHERE NOT (change ->> 'field_logical_name' = ANY($4::TEXT[]))
+            ),
+            '[]'::JSONB
+        )
+        WHERE tenant_id = $1
+          AND entity_logical_name = $2
+          AND record_id = ANY(SELECT id::TEXT FROM UNNEST($3::UUID[]) AS id)
+          AND EXISTS (
+              SELECT 1
+              FROM jsonb_array_elements(changes) AS change
+              WHERE change ->> 'field_logical_name' = ANY($4::TEXT[])
+          )
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(entity_logical_name)
+    .bind(record_ids)
+    .bind(field_logical_names)
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| AppError::Internal(format!("failed to scrub erased record history: {error}")))?
+    .rows_affected();
+
+    sqlx::query(
+        r#"
+        DELETE FROM runtime_record_field_history
+        WHERE tenant_id = $1
+          AND entity_logical_name = $2
+          AND record_id = ANY(SELECT id::TEXT FROM UNNEST($3::UUID[]) AS id)
+          AND changes = '[]'::JSONB
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(entity_logical_name)
+    .bind(record_ids)
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!("failed to delete emptied record history: {error}"))
+    })?;
+
+    Ok(scrubbed)
+}
+
+/// Replaces the subject in record ownership, system fields and history actors.
+///
+/// Returns the pseudonymized record count and the count of referencing
+/// records left unchanged under a legal hold.
+async fn pseudonymize_record_references(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    subject: &str,
+    pseudonym: &str,
+) -> AppResult<(u64, u64)> {
+    let references_subject = r#"
+        runtime_records.tenant_id = $1
+        AND (
+            runtime_records.created_by_subject = $2
+            OR runtime_records.data ->> 'created_by' = $2
+            OR runtime_records.data ->> 'modified_by' = $2
+            OR runtime_records.data ->> 'owner' = $2
+        )
+    "#;
+
+    let held = sqlx::query_scalar::<_, i64>(&format!(
+        r#"
+        SELECT COUNT(*)
+        FROM runtime_records
+        WHERE {references_subject}
+          AND {RECORD_UNDER_LEGAL_HOLD}
+        "#
+    ))
+    .bind(tenant_id.as_uuid())
+    .bind(subject)
+    .fetch_one(&mut **transaction)
+    .await
+    .map_err(|error| AppError::Internal(format!("failed to count held records: {error}")))?;
+
+    let pseudonymized = sqlx::query(&format!(
+        r#"
+        UPDATE runtime_records
+        SET created_by_subject = CASE
+                WHEN created_by_subject = $2 THEN $3
+                ELSE created_by_subject
+            END,
+            data = data || COALESCE(
+                (
+                    SELECT jsonb_object_agg(system_field.key, to_jsonb($3::TEXT))
+                    FROM jsonb_each_text(data) AS system_field
+                    WHERE system_field.key IN ('created_by', 'modified_by', 'owner')
+                      AND system_field.value = $2
+                ),
+                '{{}}'::JSONB
+            ),
+            version = version + 1,
+            updated_at = now()
+        WHERE {references_subject}
+          AND NOT {RECORD_UNDER_LEGAL_HOLD}
+        "#
+    ))
+    .bind(tenant_id.as_uuid())
+    .bind(subject)
+    .bind(pseudonym)
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!("failed to pseudonymize record references: {error}"))
+    })?
+    .rows_affected();
+
+    sqlx::query(
+        r#"
+        UPDATE runtime_record_field_history
+        SET changed_by_subject = $3
+        WHERE tenant_id = $1 AND changed_by_subject = $2
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(subject)
+    .bind(pseudonym)
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!(
+            "failed to pseudonymize record history actors: {error}"
+        ))
+    })?;
+
+    Ok((pseudonymized, u64::try_from(held).unwrap_or_default()))
+}
+
+/// Replaces the actor of the subject's audit entries with the pseudonym.
+///
+/// Each rewritten entry gets a redaction holding its new payload hash, but
+/// only when the entry still matched its stored hash (or an earlier
+/// redaction): a tampered entry must keep failing verification. Returns the
+/// pseudonymized and retained entry counts.
+async fn pseudonymize_audit_entries(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    erasure: &SubjectErasure,
+) -> AppResult<(u64, u64)> {
+    let total = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*)
+        FROM audit_log_entries
+        WHERE tenant_id = $1 AND subject = $2
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(erasure.subject.as_str())
+    .fetch_one(&mut **transaction)
+    .await
+    .map_err(|error| AppError::Internal(format!("failed to count audit entries: {error}")))?;
+    let total = u64::try_from(total).unwrap_or_default();
+    if !erasure.pseudonymize_audit_log {
+        return Ok((0, total));
+    }
+
+    let rows = sqlx::query_as::<_, SubjectAuditEntryRow>(&format!(
+        r#"
+        SELECT
+            audit_log_entries.tenant_id,
+            audit_log_entries.chain_position,
+            audit_log_entries.previous_entry_hash,
+            audit_log_entries.action,
+            audit_log_entries.resource_type,
+            audit_log_entries.resource_id,
+            audit_log_entries.detail,
+            to_char(audit_log_entries.created_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS.US"Z"') AS created_at,
+            audit_log_entries.entry_hash,
+            audit_entry_redactions.redacted_entry_hash
+        FROM audit_log_entries
+        LEFT JOIN audit_entry_redactions
+            ON audit_entry_redactions.tenant_id = audit_log_entries.tenant_id
+           AND audit_entry_redactions.chain_position = audit_log_entries.chain_position
+        WHERE audit_log_entries.tenant_id = $1
+          AND audit_log_entries.subject = $2
+          AND NOT {AUDIT_ENTRY_UNDER_LEGAL_HOLD}
+        ORDER BY audit_log_entries.chain_position
+        FOR UPDATE OF audit_log_entries
+        "#
+    ))
+    .bind(tenant_id.as_uuid())
+    .bind(erasure.subject.as_str())
+    .fetch_all(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!("failed to load audit entries for erasure: {error}"))
+    })?;
+
+    let mut positions = Vec::with_capacity(rows.len());
+    let mut redacted_positions = Vec::with_capacity(rows.len());
+    let mut redacted_hashes = Vec::with_capacity(rows.len());
+    for row in &rows {
+        positions.push(row.chain_position);
+
+        let current_hash = row.hash_with_subject(erasure.subject.as_str());
+        let intact = current_hash == row.entry_hash
+            || row.redacted_entry_hash.as_deref() == Some(current_hash.as_str());
+        if intact {
+            redacted_positions.push(row.chain_position);
+            redacted_hashes.push(row.hash_with_subject(erasure.pseudonym.as_str()));
+        }
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE audit_log_entries
+        SET subject = $3
+        WHERE tenant_id = $1 AND chain_position = ANY($2::BIGINT[])
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(&positions)
+    .bind(erasure.pseudonym.as_str())
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!("failed to pseudonymize audit entries: {error}"))
+    })?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO audit_entry_redactions (tenant_id, chain_position, redacted_entry_hash)
+        SELECT $1, redacted.chain_position, redacted.redacted_entry_hash
+        FROM UNNEST($2::BIGINT[], $3::TEXT[])
+            AS redacted (chain_position, redacted_entry_hash)
+        ON CONFLICT (tenant_id, chain_position) DO UPDATE
+            SET redacted_entry_hash = EXCLUDED.redacted_entry_hash,
+                created_at = now()
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(&redacted_positions)
+    .bind(&redacted_hashes)
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!("failed to record audit entry redactions: {error}"))
+    })?;
+
+    let pseudonymized = rows.len() as u64;
+    Ok((pseudonymized, total.saturating_sub(pseudonymized)))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,375 @@
+use std::collections::BTreeMap;
+
+use qryvanta_application::{
+    AuditChainVerificationScope, AuditEvent, AuditLogRepository, AuditRepository,
+    DataErasureRepository, SaveDataErasureFieldsInput, SubjectErasure,
+};
+use qryvanta_core::TenantId;
+use qryvanta_domain::AuditAction;
+use serde_json::{Value, json};
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresDataErasureRepository;
+use crate::{PostgresAuditLogRepository, PostgresAuditRepository, begin_tenant_transaction};
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres data erasure tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn execute_in_tenant(pool: &PgPool, tenant_id: TenantId, statement: &str, values: &[&str]) {
+    let mut transaction = begin_tenant_transaction(pool, tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to begin tenant transaction: {error}"));
+    let mut query = sqlx::query(statement).bind(tenant_id.as_uuid());
+    for value in values {
+        query = query.bind(*value);
+    }
+    let executed = query.execute(&mut *transaction).await;
+    assert!(executed.is_ok(), "{statement}: {executed:?}");
+    assert!(transaction.commit().await.is_ok());
+}
+
+async fn seed_tenant(pool: &PgPool, tenant_id: TenantId, members: &[&str]) {
+    let inserted = sqlx::query("INSERT INTO tenants (id, name) VALUES ($1, $2)")
+        .bind(tenant_id.as_uuid())
+        .bind(format!("Data Erasure Tenant {tenant_id}"))
+        .execute(pool)
+        .await;
+    assert!(inserted.is_ok());
+
+    for statement in [
+        "INSERT INTO entity_definitions (tenant_id, logical_name, display_name) VALUES ($1, 'contact', 'Contact')",
+        "INSERT INTO entity_definitions (tenant_id, logical_name, display_name) VALUES ($1, 'account', 'Account')",
+    ] {
+        execute_in_tenant(pool, tenant_id, statement, &[]).await;
+    }
+    for member in members {
+        execute_in_tenant(
+            pool,
+            tenant_id,
+            "INSERT INTO tenant_memberships (tenant_id, subject, display_name, email) VALUES ($1, $2, $2, $2 || '@example.com')",
+            &[member],
+        )
+        .await;
+    }
+}
+
+async fn insert_record(
+    pool: &PgPool,
+    tenant_id: TenantId,
+    entity_logical_name: &str,
+    owner: &str,
+    data: Value,
+) -> uuid::Uuid {
+    let mut transaction = begin_tenant_transaction(pool, tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to begin tenant transaction: {error}"));
+    let record_id = sqlx::query_scalar::<_, uuid::Uuid>(
+        r#"
+        INSERT INTO runtime_records (tenant_id, entity_logical_name, data, created_by_subject)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(entity_logical_name)
+    .bind(data)
+    .bind(owner)
+    .fetch_one(&mut *transaction)
+    .await
+    .unwrap_or_else(|error| panic!("failed to insert runtime record: {error}"));
+    assert!(transaction.commit().await.is_ok());
+
+    record_id
+}
+
+async fn record_row(pool: &PgPool, tenant_id: TenantId, record_id: uuid::Uuid) -> (Value, String) {
+    let mut transaction = begin_tenant_transaction(pool, tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to begin tenant transaction: {error}"));
+    let row = sqlx::query_as::<_, (Value, String)>(
+        "SELECT data, created_by_subject FROM runtime_records WHERE id = $1",
+    )
+    .bind(record_id)
+    .fetch_one(&mut *transaction)
+    .await
+    .unwrap_or_else(|error| panic!("failed to load runtime record: {error}"));
+    assert!(transaction.commit().await.is_ok());
+
+    row
+}
+
+async fn append_audit_event(pool: &PgPool, tenant_id: TenantId, subject: &str) {
+    let appended = PostgresAuditRepository::new(pool.clone())
+        .append_event(AuditEvent {
+            tenant_id,
+            subject: subject.to_owned(),
+            action: AuditAction::RuntimeRecordUpdated,
+            resource_type: "runtime_record".to_owned(),
+            resource_id: "account".to_owned(),
+            detail: None,
+        })
+        .await;
+    assert!(appended.is_ok());
+}
+
+#[tokio::test]
+async fn erasure_pseudonymizes_references_and_removes_configured_fields() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresDataErasureRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let bob = format!("erasure-bob-{tenant_id}");
+    let bob = bob.as_str();
+    seed_tenant(&pool, tenant_id, &[bob, "alice"]).await;
+
+    let contact_id = insert_record(
+        &pool,
+        tenant_id,
+        "contact",
+        bob,
+        json!({ "subject": bob, "display_name": "Bob", "email": "bob@example.com" }),
+    )
+    .await;
+    execute_in_tenant(
+        &pool,
+        tenant_id,
+        "INSERT INTO tenant_subject_contacts (tenant_id, subject, contact_record_id) VALUES ($1, $2, $3::UUID)",
+        &[bob, contact_id.to_string().as_str()],
+    )
+    .await;
+    let owned_id = insert_record(
+        &pool,
+        tenant_id,
+        "account",
+        bob,
+        json!({ "name": "Bob's shop", "phone": "555-0100", "owner": bob, "created_by": bob }),
+    )
+    .await;
+    let modified_id = insert_record(
+        &pool,
+        tenant_id,
+        "account",
+        "alice",
+        json!({ "name": "Alice's shop", "owner": "alice", "modified_by": bob }),
+    )
+    .await;
+    let held_id = insert_record(
+        &pool,
+        tenant_id,
+        "account",
+        bob,
+        json!({ "name": "Held shop", "phone": "555-0199", "owner": bob }),
+    )
+    .await;
+    execute_in_tenant(
+        &pool,
+        tenant_id,
+        r#"
+        INSERT INTO legal_holds (id, tenant_id, name, reason, scope_type, entity_logical_name, record_ids, created_by_subject)
+        VALUES (gen_random_uuid(), $1, 'Litigation', 'Open matter', 'runtime_records', 'account', ARRAY[$2], 'alice')
+        "#,
+        &[held_id.to_string().as_str()],
+    )
+    .await;
+    execute_in_tenant(
+        &pool,
+        tenant_id,
+        r#"
+        INSERT INTO runtime_record_field_history (id, tenant_id, entity_logical_name, record_id, record_version, changes, changed_by_subject)
+        VALUES (
+            gen_random_uuid(), $1, 'account', $2, 2,
+            '[{"field_logical_name": "phone", "new_value": "555-0100"}, {"field_logical_name": "name", "new_value": "Bob''s shop"}]',
+            $3
+        )
+        "#,
+        &[owned_id.to_string().as_str(), bob],
+    )
+    .await;
+    append_audit_event(&pool, tenant_id, bob).await;
+    append_audit_event(&pool, tenant_id, "alice").await;
+    append_audit_event(&pool, tenant_id, bob).await;
+
+    let saved = repository
+        .save_erasure_fields(
+            tenant_id,
+            "alice",
+            SaveDataErasureFieldsInput {
+                entity_logical_name: "account".to_owned(),
+                field_logical_names: vec!["phone".to_owned()],
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to save erasure fields: {error}"));
+    assert_eq!(saved.len(), 1);
+
+    let erasure = SubjectErasure {
+        subject: bob.to_owned(),
+        pseudonym: format!("erased-{tenant_id}"),
+        display_name: "Erased user".to_owned(),
+        fields_by_entity: BTreeMap::from([("account".to_owned(), vec!["phone".to_owned()])]),
+        pseudonymize_audit_log: true,
+    };
+    let pseudonym = erasure.pseudonym.as_str();
+    let outcome = repository
+        .erase_subject(tenant_id, &erasure)
+        .await
+        .unwrap_or_else(|error| panic!("failed to erase subject: {error}"))
+        .unwrap_or_else(|| panic!("subject should be a member"));
+    assert_eq!(outcome.contact_record_id, Some(contact_id.to_string()));
+    assert_eq!(outcome.records_erased, 1);
+    assert_eq!(outcome.records_held, 1);
+    assert_eq!(outcome.records_pseudonymized, 3);
+    assert_eq!(outcome.history_entries_scrubbed, 1);
+    assert_eq!(outcome.audit_entries_pseudonymized, 2);
+    assert_eq!(outcome.audit_entries_retained, 0);
+
+    let (contact, _) = record_row(&pool, tenant_id, contact_id).await;
+    assert_eq!(
+        contact,
+        json!({ "subject": pseudonym, "display_name": "Erased user" })
+    );
+    let (owned, owned_by) = record_row(&pool, tenant_id, owned_id).await;
+    assert_eq!(
+        owned,
+        json!({ "name": "Bob's shop", "owner": pseudonym, "created_by": pseudonym })
+    );
+    assert_eq!(owned_by, pseudonym);
+    let (modified, modified_owner) = record_row(&pool, tenant_id, modified_id).await;
+    assert_eq!(modified["modified_by"], json!(pseudonym));
+    assert_eq!(modified_owner, "alice");
+    let (held, held_owner) = record_row(&pool, tenant_id, held_id).await;
+    assert_eq!(held["phone"], json!("555-0199"));
+    assert_eq!(held_owner, bob);
+
+    let mut transaction = begin_tenant_transaction(&pool, tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to begin tenant transaction: {error}"));
+    let (changes, changed_by) = sqlx::query_as::<_, (Value, String)>(
+        "SELECT changes, changed_by_subject FROM runtime_record_field_history WHERE record_id = $1",
+    )
+    .bind(owned_id.to_string())
+    .fetch_one(&mut *transaction)
+    .await
+    .unwrap_or_else(|error| panic!("failed to load record history: {error}"));
+    assert_eq!(
+        changes,
+        json!([{ "field_logical_name": "name", "new_value": "Bob's shop" }])
+    );
+    assert_eq!(changed_by, pseudonym);
+    let membership = sqlx::query_as::<_, (String, Option<String>)>(
+        "SELECT display_name, email FROM tenant_memberships WHERE subject = $1",
+    )
+    .bind(pseudonym)
+    .fetch_one(&mut *transaction)
+    .await
+    .unwrap_or_else(|error| panic!("failed to load membership: {error}"));
+    assert_eq!(membership, ("Erased user".to_owned(), None));
+    assert!(transaction.commit().await.is_ok());
+
+    // Redactions keep the rewritten audit entries verifiable.
+    let audit_log = PostgresAuditLogRepository::new(pool.clone());
+    let integrity = audit_log
+        .verify_integrity(tenant_id, AuditChainVerificationScope::Full)
+        .await
+        .unwrap_or_else(|error| panic!("failed to verify audit integrity: {error}"));
+    assert!(integrity.is_valid, "{:?}", integrity.failures);
+    assert_eq!(integrity.verified_entries, 3);
+
+    assert!(
+        repository
+            .erase_subject(tenant_id, &erasure)
+            .await
+            .unwrap_or_else(|error| panic!("failed to repeat erasure: {error}"))
+            .is_none()
+    );
+    assert!(
+        !repository
+            .subject_has_memberships(bob)
+            .await
+            .unwrap_or_else(|error| panic!("failed to look up memberships: {error}"))
+    );
+}
+
+#[tokio::test]
+async fn auth_events_and_retained_audit_entries_follow_the_erasure_input() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresDataErasureRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let carol = format!("erasure-carol-{tenant_id}");
+    let carol = carol.as_str();
+    seed_tenant(&pool, tenant_id, &[carol]).await;
+    append_audit_event(&pool, tenant_id, carol).await;
+
+    let inserted = sqlx::query(
+        r#"
+        INSERT INTO auth_events (subject, event_type, outcome, ip_address, user_agent)
+        VALUES ($1, 'login', 'success', '203.0.113.7', 'test-agent')
+        "#,
+    )
+    .bind(carol)
+    .execute(&pool)
+    .await;
+    assert!(inserted.is_ok());
+
+    let outcome = repository
+        .erase_subject(
+            tenant_id,
+            &SubjectErasure {
+                subject: carol.to_owned(),
+                pseudonym: format!("erased-{tenant_id}"),
+                display_name: "Erased user".to_owned(),
+                fields_by_entity: BTreeMap::new(),
+                pseudonymize_audit_log: false,
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to erase subject: {error}"))
+        .unwrap_or_else(|| panic!("subject should be a member"));
+    assert_eq!(outcome.contact_record_id, None);
+    assert_eq!(outcome.audit_entries_pseudonymized, 0);
+    assert_eq!(outcome.audit_entries_retained, 1);
+
+    let pseudonym = format!("erased-{tenant_id}");
+    assert_eq!(
+        repository
+            .anonymize_auth_events(carol, pseudonym.as_str())
+            .await
+            .unwrap_or_else(|error| panic!("failed to anonymize auth events: {error}")),
+        1
+    );
+    let event = sqlx::query_as::<_, (Option<String>, Option<String>)>(
+        "SELECT ip_address, user_agent FROM auth_events WHERE subject = $1",
+    )
+    .bind(pseudonym.as_str())
+    .fetch_one(&pool)
+    .await
+    .unwrap_or_else(|error| panic!("failed to load auth event: {error}"));
+    assert_eq!(event, (None, None));
+}
@@ -0,0 +1,408 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    DataValidationRepository, DataValidationSchedule, DataValidationViolation,
+    DueDataValidationSchedule, RuntimeRecordValidationRule, RuntimeRecordViolation,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+use crate::postgres_tenant_rls::begin_data_validation_schedule_transaction;
+
+const SCHEDULE_COLUMNS: &str = "tenant_id, interval_days, configured_by_subject, \
+     configured_by_display_name, configured_by_email, next_run_at, last_run_at, updated_at";
+
+/// PostgreSQL-backed repository for data validation audit results and schedules.
+#[derive(Clone)]
+pub struct PostgresDataValidationRepository {
+    pool: PgPool,
+}
+
+impl PostgresDataValidationRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct DataValidationViolationRow {
+    entity_logical_name: String,
+    record_id: String,
+    rule: String,
+    field_logical_name: Option<String>,
+    business_rule_logical_name: Option<String>,
+    message: String,
+}
+
+#[derive(Debug, FromRow)]
+struct DataValidationScheduleRow {
+    tenant_id: uuid::Uuid,
+    interval_days: i16,
+    configured_by_subject: String,
+    configured_by_display_name: String,
+    configured_by_email: Option<String>,
+    next_run_at: DateTime<Utc>,
+    last_run_at: Option<DateTime<Utc>>,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct ClaimedDataValidationScheduleRow {
+    scheduled_for: DateTime<Utc>,
+    #[sqlx(flatten)]
+    schedule: DataValidationScheduleRow,
+}
+
+impl TryFrom<DataValidationViolationRow> for DataValidationViolation {
+    type Error = AppError;
+
+    fn try_from(row: DataValidationViolationRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            entity_logical_name: row.entity_logical_name,
+            violation: RuntimeRecordViolation {
+                record_id: row.record_id,
+                rule: RuntimeRecordValidationRule::parse(row.rule.as_str())?,
+                field_logical_name: row.field_logical_name,
+                business_rule_logical_name: row.business_rule_logical_name,
+                message: row.message,
+            },
+        })
+    }
+}
+
+impl TryFrom<DataValidationScheduleRow> for DataValidationSchedule {
+    type Error = AppError;
+
+    fn try_from(row: DataValidationScheduleRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            interval_days: u16::try_from(row.interval_days).map_err(|_| {
+                AppError::Internal("stored data validation interval is out of range".to_owned())
+            })?,
+            configured_by_subject: row.configured_by_subject,
+            configured_by_display_name: row.configured_by_display_name,
+            configured_by_email: row.configured_by_email,
+            next_run_at: row.next_run_at,
+            last_run_at: row.last_run_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+fn parse_job_id(job_id: &str) -> AppResult<uuid::Uuid> {
+    uuid::Uuid::parse_str(job_id)
+        .map_err(|_| AppError::NotFound(format!("background job '{job_id}' does not exist")))
+}
+
+fn commit_error(error: sqlx::Error) -> AppError {
+    AppError::Internal(format!(
+        "failed to commit data validation transaction: {error}"
+    ))
+}
+
+#[async_trait]
+impl DataValidationRepository for PostgresDataValidationRepository {
+    async fn save_page_violations(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        entity_logical_name: &str,
+        page_offset: u64,
+        violations: &[RuntimeRecordViolation],
+    ) -> AppResult<()> {
+        let job_uuid = parse_job_id(job_id)?;
+        let page_offset = i64::try_from(page_offset).map_err(|_| {
+            AppError::Validation("data validation page offset is out of range".to_owned())
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            DELETE FROM data_validation_violations
+            WHERE tenant_id = $1 AND job_id = $2 AND entity_logical_name = $3 AND page_offset = $4
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .bind(entity_logical_name)
+        .bind(page_offset)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to clear data validation violations: {error}"
+            ))
+        })?;
+
+        for (position, violation) in violations.iter().enumerate() {
+            let position = i32::try_from(position).map_err(|_| {
+                AppError::Validation("data validation page holds too many violations".to_owned())
+            })?;
+            sqlx::query(
+                r#"
+                INSERT INTO data_validation_violations (
+                    tenant_id,
+                    job_id,
+                    entity_logical_name,
+                    page_offset,
+                    position,
+                    record_id,
+                    rule,
+                    field_logical_name,
+                    business_rule_logical_name,
+                    message
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                "#,
+            )
+            .bind(tenant_id.as_uuid())
+            .bind(job_uuid)
+            .bind(entity_logical_name)
+            .bind(page_offset)
+            .bind(position)
+            .bind(violation.record_id.as_str())
+            .bind(violation.rule.as_str())
+            .bind(violation.field_logical_name.as_deref())
+            .bind(violation.business_rule_logical_name.as_deref())
+            .bind(violation.message.as_str())
+            .execute(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!("failed to save data validation violation: {error}"))
+            })?;
+        }
+
+        transaction.commit().await.map_err(commit_error)
+    }
+
+    async fn list_violations(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<DataValidationViolation>> {
+        let job_uuid = parse_job_id(job_id)?;
+        let limit = i64::try_from(limit).map_err(|_| {
+            AppError::Validation("data validation violation limit is out of range".to_owned())
+        })?;
+        let offset = i64::try_from(offset).map_err(|_| {
+            AppError::Validation("data validation violation offset is out of range".to_owned())
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, DataValidationViolationRow>(
+            r#"
+            SELECT
+                entity_logical_name,
+                record_id,
+                rule,
+                field_logical_name,
+                business_rule_logical_name,
+                message
+            FROM data_validation_violations
+            WHERE tenant_id = $1 AND job_id = $2
+            ORDER BY entity_logical_name ASC, page_offset ASC, position ASC
+            LIMIT $3 OFFSET $4
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list data validation violations: {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.into_iter()
+            .map(DataValidationViolation::try_from)
+            .collect()
+    }
+
+    async fn find_schedule(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<Option<DataValidationSchedule>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, DataValidationScheduleRow>(&format!(
+            r#"
+            SELECT {SCHEDULE_COLUMNS}
+            FROM data_validation_schedules
+            WHERE tenant_id = $1
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to find data validation schedule: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        row.map(DataValidationSchedule::try_from).transpose()
+    }
+
+    async fn save_schedule(
+        &self,
+        tenant_id: TenantId,
+        schedule: &DataValidationSchedule,
+    ) -> AppResult<DataValidationSchedule> {
+        let interval_days = i16::try_from(schedule.interval_days).map_err(|_| {
+            AppError::Validation("data validation interval is out of range".to_owned())
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, DataValidationScheduleRow>(&format!(
+            r#"
+            INSERT INTO data_validation_schedules (
+                tenant_id,
+                interval_days,
+                configured_by_subject,
+                configured_by_display_name,
+                configured_by_email,
+                next_run_at,
+                last_run_at,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (tenant_id) DO UPDATE SET
+                interval_days = EXCLUDED.interval_days,
+                configured_by_subject = EXCLUDED.configured_by_subject,
+                configured_by_display_name = EXCLUDED.configured_by_display_name,
+                configured_by_email = EXCLUDED.configured_by_email,
+                next_run_at = EXCLUDED.next_run_at,
+                last_run_at = EXCLUDED.last_run_at,
+                updated_at = EXCLUDED.updated_at
+            RETURNING {SCHEDULE_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(interval_days)
+        .bind(schedule.configured_by_subject.as_str())
+        .bind(schedule.configured_by_display_name.as_str())
+        .bind(schedule.configured_by_email.as_deref())
+        .bind(schedule.next_run_at)
+        .bind(schedule.last_run_at)
+        .bind(schedule.updated_at)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to save data validation schedule: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        DataValidationSchedule::try_from(row)
+    }
+
+    async fn delete_schedule(&self, tenant_id: TenantId) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query("DELETE FROM data_validation_schedules WHERE tenant_id = $1")
+            .bind(tenant_id.as_uuid())
+            .execute(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to delete data validation schedule: {error}"
+                ))
+            })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn claim_due_schedules(
+        &self,
+        now: DateTime<Utc>,
+        retry_at: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<DueDataValidationSchedule>> {
+        let limit = i64::try_from(limit).map_err(|_| {
+            AppError::Validation("data validation schedule claim size is out of range".to_owned())
+        })?;
+
+        let mut transaction = begin_data_validation_schedule_transaction(&self.pool).await?;
+        let rows = sqlx::query_as::<_, ClaimedDataValidationScheduleRow>(
+            r#"
+            WITH due AS (
+                SELECT tenant_id, next_run_at AS scheduled_for
+                FROM data_validation_schedules
+                WHERE next_run_at <= $1
+                ORDER BY next_run_at ASC, tenant_id ASC
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE data_validation_schedules
+            SET next_run_at = $2
+            FROM due
+            WHERE data_validation_schedules.tenant_id = due.tenant_id
+            RETURNING due.scheduled_for, data_validation_schedules.*
+            "#,
+        )
+        .bind(now)
+        .bind(retry_at)
+        .bind(limit)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to claim data validation schedules: {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.into_iter()
+            .map(|claimed| {
+                let tenant_id = TenantId::from_uuid(claimed.schedule.tenant_id);
+                Ok(DueDataValidationSchedule {
+                    tenant_id,
+                    scheduled_for: claimed.scheduled_for,
+                    schedule: DataValidationSchedule::try_from(claimed.schedule)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn record_scheduled_run(
+        &self,
+        tenant_id: TenantId,
+        run_at: DateTime<Utc>,
+        next_run_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            UPDATE data_validation_schedules
+            SET last_run_at = $2, next_run_at = $3
+            WHERE tenant_id = $1
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(run_at)
+        .bind(next_run_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to record data validation schedule run: {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)
+    }
+}
+
+#[cfg(test)]
+mod tests;
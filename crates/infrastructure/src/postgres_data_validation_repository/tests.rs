@@ -0,0 +1,205 @@
+use chrono::{Duration, Timelike, Utc};
+use qryvanta_application::{
+    BackgroundJobKind, BackgroundJobRepository, CreateBackgroundJobInput, DataValidationRepository,
+    DataValidationSchedule, RuntimeRecordValidationRule, RuntimeRecordViolation,
+};
+use qryvanta_core::TenantId;
+use serde_json::json;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresDataValidationRepository;
+use crate::PostgresBackgroundJobRepository;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres data validation tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn ensure_tenant(pool: &PgPool, tenant_id: TenantId, name: &str) {
+    let insert = sqlx::query(
+        r#"
+            INSERT INTO tenants (id, name)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(name)
+    .execute(pool)
+    .await;
+
+    assert!(insert.is_ok());
+}
+
+async fn create_audit_job(pool: &PgPool, tenant_id: TenantId) -> String {
+    PostgresBackgroundJobRepository::new(pool.clone())
+        .create_background_job(
+            tenant_id,
+            "auditor",
+            "Auditor",
+            None,
+            CreateBackgroundJobInput {
+                kind: BackgroundJobKind::DataValidationAudit,
+                parameters: json!({ "entity_logical_names": ["contact"] }),
+                stages: Vec::new(),
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to create background job: {error}"))
+        .job_id
+}
+
+fn violation(record_id: &str) -> RuntimeRecordViolation {
+    RuntimeRecordViolation {
+        record_id: record_id.to_owned(),
+        rule: RuntimeRecordValidationRule::MissingRequiredField,
+        field_logical_name: Some("name".to_owned()),
+        business_rule_logical_name: None,
+        message: "missing required field 'name'".to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn page_violations_replace_by_offset_and_stay_within_their_tenant() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresDataValidationRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Data Validation Tenant").await;
+    ensure_tenant(&pool, other_tenant_id, "Data Validation Other Tenant").await;
+    let job_id = create_audit_job(&pool, tenant_id).await;
+
+    for (page_offset, violations) in [
+        (200, vec![violation("stale")]),
+        (0, vec![violation("record-1"), violation("record-2")]),
+        (200, vec![violation("record-201")]),
+    ] {
+        let saved = repository
+            .save_page_violations(
+                tenant_id,
+                job_id.as_str(),
+                "contact",
+                page_offset,
+                &violations,
+            )
+            .await;
+        assert!(saved.is_ok());
+    }
+
+    let listed = repository
+        .list_violations(tenant_id, job_id.as_str(), 10, 0)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list violations: {error}"));
+    let record_ids: Vec<_> = listed
+        .iter()
+        .map(|stored| stored.violation.record_id.as_str())
+        .collect();
+    assert_eq!(record_ids, vec!["record-1", "record-2", "record-201"]);
+    assert_eq!(listed[0].entity_logical_name, "contact");
+    assert_eq!(listed[0].violation, violation("record-1"));
+
+    let paged = repository
+        .list_violations(tenant_id, job_id.as_str(), 1, 2)
+        .await
+        .unwrap_or_else(|error| panic!("failed to page violations: {error}"));
+    assert_eq!(paged.len(), 1);
+    assert_eq!(paged[0].violation.record_id, "record-201");
+
+    let other_tenant = repository
+        .list_violations(other_tenant_id, job_id.as_str(), 10, 0)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list violations: {error}"));
+    assert!(other_tenant.is_empty());
+}
+
+#[tokio::test]
+async fn schedules_are_claimed_across_tenants_and_advanced() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresDataValidationRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Data Validation Schedule Tenant").await;
+
+    let now = Utc::now()
+        .with_nanosecond(0)
+        .unwrap_or_else(|| unreachable!());
+    let saved = repository
+        .save_schedule(
+            tenant_id,
+            &DataValidationSchedule {
+                interval_days: 7,
+                configured_by_subject: "auditor".to_owned(),
+                configured_by_display_name: "Auditor".to_owned(),
+                configured_by_email: Some("auditor@example.com".to_owned()),
+                next_run_at: now - Duration::minutes(1),
+                last_run_at: None,
+                updated_at: now,
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to save schedule: {error}"));
+    assert_eq!(saved.interval_days, 7);
+
+    let retry_at = now + Duration::minutes(30);
+    let claimed = repository
+        .claim_due_schedules(now, retry_at, 100)
+        .await
+        .unwrap_or_else(|error| panic!("failed to claim schedules: {error}"));
+    let claimed: Vec<_> = claimed
+        .into_iter()
+        .filter(|due| due.tenant_id == tenant_id)
+        .collect();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].scheduled_for, saved.next_run_at);
+    assert_eq!(claimed[0].schedule.next_run_at, retry_at);
+
+    let reclaimed = repository
+        .claim_due_schedules(now, retry_at, 100)
+        .await
+        .unwrap_or_else(|error| panic!("failed to claim schedules: {error}"));
+    assert!(reclaimed.iter().all(|due| due.tenant_id != tenant_id));
+
+    let next_run_at = claimed[0].scheduled_for + Duration::days(7);
+    let recorded = repository
+        .record_scheduled_run(tenant_id, now, next_run_at)
+        .await;
+    assert!(recorded.is_ok());
+    let stored = repository
+        .find_schedule(tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to find schedule: {error}"))
+        .unwrap_or_else(|| panic!("schedule missing"));
+    assert_eq!(stored.next_run_at, next_run_at);
+    assert_eq!(stored.last_run_at, Some(now));
+
+    assert_eq!(repository.delete_schedule(tenant_id).await.ok(), Some(true));
+    assert_eq!(
+        repository.delete_schedule(tenant_id).await.ok(),
+        Some(false)
+    );
+}
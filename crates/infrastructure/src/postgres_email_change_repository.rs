@@ -0,0 +1,226 @@
+//! PostgreSQL-backed repository for pending account email changes.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{EmailChangeConfirmation, EmailChangeRepository, PendingEmailChange};
+use qryvanta_core::{AppError, AppResult};
+use qryvanta_domain::UserId;
+
+/// PostgreSQL implementation of the email change repository port.
+#[derive(Clone)]
+pub struct PostgresEmailChangeRepository {
+    pool: PgPool,
+}
+
+impl PostgresEmailChangeRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct PendingEmailChangeRow {
+    user_id: uuid::Uuid,
+    current_email: String,
+    new_email: String,
+    current_confirmed_at: Option<DateTime<Utc>>,
+    new_confirmed_at: Option<DateTime<Utc>>,
+    requested_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl From<PendingEmailChangeRow> for PendingEmailChange {
+    fn from(row: PendingEmailChangeRow) -> Self {
+        Self {
+            user_id: UserId::from_uuid(row.user_id),
+            current_email: row.current_email,
+            new_email: row.new_email,
+            current_confirmed_at: row.current_confirmed_at,
+            new_confirmed_at: row.new_confirmed_at,
+            requested_at: row.requested_at,
+            expires_at: row.expires_at,
+        }
+    }
+}
+
+const PENDING_CHANGE_COLUMNS: &str = r#"
+    user_id,
+    current_email,
+    new_email,
+    current_confirmed_at,
+    new_confirmed_at,
+    requested_at,
+    expires_at
+"#;
+
+#[async_trait]
+impl EmailChangeRepository for PostgresEmailChangeRepository {
+    async fn save_pending_change(&self, change: &PendingEmailChange) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO user_email_changes (
+                user_id,
+                current_email,
+                new_email,
+                current_confirmed_at,
+                new_confirmed_at,
+                requested_at,
+                expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (user_id) DO UPDATE SET
+                current_email = EXCLUDED.current_email,
+                new_email = EXCLUDED.new_email,
+                current_confirmed_at = EXCLUDED.current_confirmed_at,
+                new_confirmed_at = EXCLUDED.new_confirmed_at,
+                requested_at = EXCLUDED.requested_at,
+                expires_at = EXCLUDED.expires_at
+            "#,
+        )
+        .bind(change.user_id.as_uuid())
+        .bind(change.current_email.as_str())
+        .bind(change.new_email.as_str())
+        .bind(change.current_confirmed_at)
+        .bind(change.new_confirmed_at)
+        .bind(change.requested_at)
+        .bind(change.expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to save pending email change: {error}"))
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_pending_change(&self, user_id: UserId) -> AppResult<Option<PendingEmailChange>> {
+        let row = sqlx::query_as::<_, PendingEmailChangeRow>(&format!(
+            r#"
+            SELECT {PENDING_CHANGE_COLUMNS}
+            FROM user_email_changes
+            WHERE user_id = $1
+            "#
+        ))
+        .bind(user_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to find pending email change: {error}"))
+        })?;
+
+        Ok(row.map(PendingEmailChange::from))
+    }
+
+    async fn confirm_pending_change(
+        &self,
+        user_id: UserId,
+        new_email: &str,
+        confirmation: EmailChangeConfirmation,
+    ) -> AppResult<Option<PendingEmailChange>> {
+        let confirmed_column = match confirmation {
+            EmailChangeConfirmation::CurrentAddress => "current_confirmed_at",
+            EmailChangeConfirmation::NewAddress => "new_confirmed_at",
+        };
+        let row = sqlx::query_as::<_, PendingEmailChangeRow>(&format!(
+            r#"
+            UPDATE user_email_changes
+            SET {confirmed_column} = COALESCE({confirmed_column}, now())
+            WHERE user_id = $1
+              AND new_email = $2
+              AND expires_at > now()
+            RETURNING {PENDING_CHANGE_COLUMNS}
+            "#
+        ))
+        .bind(user_id.as_uuid())
+        .bind(new_email)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to confirm pending email change: {error}"))
+        })?;
+
+        Ok(row.map(PendingEmailChange::from))
+    }
+
+    async fn complete_pending_change(&self, user_id: UserId, new_email: &str) -> AppResult<bool> {
+        let mut transaction = self.pool.begin().await.map_err(|error| {
+            AppError::Internal(format!("failed to begin email change transaction: {error}"))
+        })?;
+
+        let removed = sqlx::query(
+            r#"
+            DELETE FROM user_email_changes
+            WHERE user_id = $1
+              AND new_email = $2
+              AND current_confirmed_at IS NOT NULL
+              AND new_confirmed_at IS NOT NULL
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .bind(new_email)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to remove pending email change: {error}"))
+        })?;
+        if removed.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE users
+            SET email = LOWER($2),
+                email_verified = TRUE,
+                auth_sessions_revoked_after = now(),
+                updated_at = now()
+            WHERE id = $1
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .bind(new_email)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            if let sqlx::Error::Database(ref database_error) = error
+                && database_error.code().as_deref() == Some("23505")
+            {
+                return AppError::Conflict("email address is already in use".to_owned());
+            }
+
+            AppError::Internal(format!("failed to apply email change: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit email change transaction: {error}"
+            ))
+        })?;
+
+        Ok(true)
+    }
+
+    async fn delete_pending_change(&self, user_id: UserId) -> AppResult<bool> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM user_email_changes
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id.as_uuid())
+        .execute(&self.pool)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to delete pending email change: {error}"))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests;
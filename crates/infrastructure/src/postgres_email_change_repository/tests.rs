@@ -0,0 +1,167 @@
+use chrono::{Duration, Utc};
+use qryvanta_application::{
+    EmailChangeConfirmation, EmailChangeRepository, PendingEmailChange, UserRepository,
+};
+use qryvanta_core::AppError;
+use qryvanta_domain::UserId;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresEmailChangeRepository;
+use crate::PostgresUserRepository;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres email change tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn create_user(users: &PostgresUserRepository, email: &str) -> UserId {
+    users
+        .create(email, None, true)
+        .await
+        .unwrap_or_else(|error| panic!("failed to create test user: {error}"))
+}
+
+fn pending_change(user_id: UserId, current_email: &str, new_email: &str) -> PendingEmailChange {
+    let requested_at = Utc::now();
+    PendingEmailChange {
+        user_id,
+        current_email: current_email.to_owned(),
+        new_email: new_email.to_owned(),
+        current_confirmed_at: None,
+        new_confirmed_at: None,
+        requested_at,
+        expires_at: requested_at + Duration::hours(24),
+    }
+}
+
+#[tokio::test]
+async fn email_change_completes_after_both_confirmations_and_revokes_sessions() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+    let users = PostgresUserRepository::new(pool.clone());
+    let repository = PostgresEmailChangeRepository::new(pool);
+    let suffix = uuid::Uuid::new_v4();
+    let current_email = format!("change-{suffix}@example.com");
+    let new_email = format!("changed-{suffix}@example.com");
+    let user_id = create_user(&users, &current_email).await;
+
+    let change = pending_change(user_id, &current_email, &new_email);
+    assert!(repository.save_pending_change(&change).await.is_ok());
+
+    let mismatched = repository
+        .confirm_pending_change(
+            user_id,
+            "other@example.com",
+            EmailChangeConfirmation::NewAddress,
+        )
+        .await;
+    assert!(matches!(mismatched, Ok(None)));
+
+    let confirmed = repository
+        .confirm_pending_change(user_id, &new_email, EmailChangeConfirmation::NewAddress)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert!(!confirmed.is_fully_confirmed());
+    assert!(matches!(
+        repository
+            .complete_pending_change(user_id, &new_email)
+            .await,
+        Ok(false)
+    ));
+
+    let confirmed = repository
+        .confirm_pending_change(user_id, &new_email, EmailChangeConfirmation::CurrentAddress)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert!(confirmed.is_fully_confirmed());
+    assert!(matches!(
+        repository
+            .complete_pending_change(user_id, &new_email)
+            .await,
+        Ok(true)
+    ));
+
+    let user = users
+        .find_by_id(user_id)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(user.email, new_email);
+    assert!(user.email_verified);
+    assert!(user.auth_sessions_revoked_after.is_some());
+    assert!(matches!(
+        repository.find_pending_change(user_id).await,
+        Ok(None)
+    ));
+}
+
+#[tokio::test]
+async fn email_change_rejects_expired_confirmations_and_taken_addresses() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+    let users = PostgresUserRepository::new(pool.clone());
+    let repository = PostgresEmailChangeRepository::new(pool);
+    let suffix = uuid::Uuid::new_v4();
+    let current_email = format!("expire-{suffix}@example.com");
+    let taken_email = format!("taken-{suffix}@example.com");
+    let user_id = create_user(&users, &current_email).await;
+
+    let mut expired = pending_change(user_id, &current_email, &taken_email);
+    expired.expires_at = Utc::now() - Duration::minutes(1);
+    assert!(repository.save_pending_change(&expired).await.is_ok());
+    assert!(matches!(
+        repository
+            .confirm_pending_change(user_id, &taken_email, EmailChangeConfirmation::NewAddress)
+            .await,
+        Ok(None)
+    ));
+
+    let mut confirmed = pending_change(user_id, &current_email, &taken_email);
+    confirmed.current_confirmed_at = Some(Utc::now());
+    confirmed.new_confirmed_at = Some(Utc::now());
+    assert!(repository.save_pending_change(&confirmed).await.is_ok());
+    create_user(&users, &taken_email).await;
+    assert!(matches!(
+        repository
+            .complete_pending_change(user_id, &taken_email)
+            .await,
+        Err(AppError::Conflict(_))
+    ));
+    assert!(matches!(
+        repository.find_pending_change(user_id).await,
+        Ok(Some(_))
+    ));
+
+    assert!(matches!(
+        repository.delete_pending_change(user_id).await,
+        Ok(true)
+    ));
+    assert!(matches!(
+        repository.delete_pending_change(user_id).await,
+        Ok(false)
+    ));
+}
@@ -0,0 +1,293 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool, Postgres, Transaction};
+
+use qryvanta_application::{
+    FeatureFlag, FeatureFlagRepository, FeatureFlagRoleOverride, SaveFeatureFlagInput,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for tenant feature flags.
+#[derive(Clone)]
+pub struct PostgresFeatureFlagRepository {
+    pool: PgPool,
+}
+
+impl PostgresFeatureFlagRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct FeatureFlagRow {
+    flag_key: String,
+    description: Option<String>,
+    enabled: bool,
+    updated_by_subject: String,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct FeatureFlagRoleOverrideRow {
+    flag_key: String,
+    role_name: String,
+    enabled: bool,
+}
+
+async fn list_flags_in_transaction(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    flag_key: Option<&str>,
+) -> AppResult<Vec<FeatureFlag>> {
+    let rows = sqlx::query_as::<_, FeatureFlagRow>(
+        r#"
+        SELECT flag_key, description, enabled, updated_by_subject, updated_at
+        FROM feature_flags
+        WHERE tenant_id = $1
+          AND ($2::TEXT IS NULL OR flag_key = $2)
+        ORDER BY flag_key
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(flag_key)
+    .fetch_all(&mut **transaction)
+    .await
+    .map_err(|error| AppError::Internal(format!("failed to list feature flags: {error}")))?;
+
+    let override_rows = sqlx::query_as::<_, FeatureFlagRoleOverrideRow>(
+        r#"
+        SELECT overrides.flag_key, roles.name AS role_name, overrides.enabled
+        FROM feature_flag_role_overrides overrides
+        INNER JOIN rbac_roles roles
+            ON roles.id = overrides.role_id AND roles.tenant_id = overrides.tenant_id
+        WHERE overrides.tenant_id = $1
+          AND ($2::TEXT IS NULL OR overrides.flag_key = $2)
+        ORDER BY overrides.flag_key, roles.name
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(flag_key)
+    .fetch_all(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!(
+            "failed to list feature flag role overrides: {error}"
+        ))
+    })?;
+
+    let mut overrides_by_flag = BTreeMap::<String, Vec<FeatureFlagRoleOverride>>::new();
+    for row in override_rows {
+        overrides_by_flag
+            .entry(row.flag_key)
+            .or_default()
+            .push(FeatureFlagRoleOverride {
+                role_name: row.role_name,
+                enabled: row.enabled,
+            });
+    }
+
+    Ok(rows
+        .into_iter()
+        .map(|row| FeatureFlag {
+            role_overrides: overrides_by_flag.remove(&row.flag_key).unwrap_or_default(),
+            flag_key: row.flag_key,
+            description: row.description,
+            enabled: row.enabled,
+            updated_by_subject: row.updated_by_subject,
+            updated_at: row.updated_at,
+        })
+        .collect())
+}
+
+#[async_trait]
+impl FeatureFlagRepository for PostgresFeatureFlagRepository {
+    async fn list_feature_flags(&self, tenant_id: TenantId) -> AppResult<Vec<FeatureFlag>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let flags = list_flags_in_transaction(&mut transaction, tenant_id, None).await?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped feature flag list transaction: {error}"
+            ))
+        })?;
+
+        Ok(flags)
+    }
+
+    async fn find_feature_flag(
+        &self,
+        tenant_id: TenantId,
+        flag_key: &str,
+    ) -> AppResult<Option<FeatureFlag>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let flag = list_flags_in_transaction(&mut transaction, tenant_id, Some(flag_key))
+            .await?
+            .pop();
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped feature flag lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(flag)
+    }
+
+    async fn save_feature_flag(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveFeatureFlagInput,
+    ) -> AppResult<FeatureFlag> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let role_names = input
+            .role_overrides
+            .iter()
+            .map(|role_override| role_override.role_name.as_str())
+            .collect::<Vec<_>>();
+        let role_ids = sqlx::query_as::<_, (String, uuid::Uuid)>(
+            r#"
+            SELECT name, id
+            FROM rbac_roles
+            WHERE tenant_id = $1 AND name = ANY($2::TEXT[])
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(&role_names)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to look up feature flag override roles for tenant '{tenant_id}': {error}"
+            ))
+        })?
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+        if let Some(missing) = role_names
+            .iter()
+            .find(|role_name| !role_ids.contains_key(**role_name))
+        {
+            return Err(AppError::NotFound(format!(
+                "role '{missing}' does not exist for tenant '{tenant_id}'"
+            )));
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO feature_flags (
+                tenant_id,
+                flag_key,
+                description,
+                enabled,
+                updated_by_subject,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, now())
+            ON CONFLICT (tenant_id, flag_key) DO UPDATE
+            SET description = EXCLUDED.description,
+                enabled = EXCLUDED.enabled,
+                updated_by_subject = EXCLUDED.updated_by_subject,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(input.flag_key.as_str())
+        .bind(input.description.as_deref())
+        .bind(input.enabled)
+        .bind(updated_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to save feature flag: {error}")))?;
+
+        sqlx::query(
+            "DELETE FROM feature_flag_role_overrides WHERE tenant_id = $1 AND flag_key = $2",
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(input.flag_key.as_str())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to clear feature flag role overrides: {error}"
+            ))
+        })?;
+
+        let override_role_ids = input
+            .role_overrides
+            .iter()
+            .filter_map(|role_override| role_ids.get(role_override.role_name.as_str()).copied())
+            .collect::<Vec<_>>();
+        let override_states = input
+            .role_overrides
+            .iter()
+            .map(|role_override| role_override.enabled)
+            .collect::<Vec<_>>();
+        sqlx::query(
+            r#"
+            INSERT INTO feature_flag_role_overrides (tenant_id, flag_key, role_id, enabled)
+            SELECT $1, $2, override_row.role_id, override_row.enabled
+            FROM UNNEST($3::UUID[], $4::BOOLEAN[]) AS override_row(role_id, enabled)
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(input.flag_key.as_str())
+        .bind(&override_role_ids)
+        .bind(&override_states)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to save feature flag role overrides: {error}"
+            ))
+        })?;
+
+        let flag =
+            list_flags_in_transaction(&mut transaction, tenant_id, Some(input.flag_key.as_str()))
+                .await?
+                .pop()
+                .ok_or_else(|| {
+                    AppError::Internal(format!(
+                        "feature flag '{}' was not found after saving",
+                        input.flag_key
+                    ))
+                })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped feature flag save transaction: {error}"
+            ))
+        })?;
+
+        Ok(flag)
+    }
+
+    async fn delete_feature_flag(&self, tenant_id: TenantId, flag_key: &str) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let deleted =
+            sqlx::query("DELETE FROM feature_flags WHERE tenant_id = $1 AND flag_key = $2")
+                .bind(tenant_id.as_uuid())
+                .bind(flag_key)
+                .execute(&mut *transaction)
+                .await
+                .map_err(|error| {
+                    AppError::Internal(format!("failed to delete feature flag: {error}"))
+                })?
+                .rows_affected()
+                > 0;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped feature flag delete transaction: {error}"
+            ))
+        })?;
+
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests;
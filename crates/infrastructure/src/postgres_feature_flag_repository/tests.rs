@@ -0,0 +1,136 @@
+use qryvanta_application::{FeatureFlagRepository, FeatureFlagRoleOverride, SaveFeatureFlagInput};
+use qryvanta_core::{AppError, TenantId};
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresFeatureFlagRepository;
+use crate::begin_tenant_transaction;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres feature flag tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn seed_tenant(pool: &PgPool, tenant_id: TenantId, roles: &[&str]) {
+    let inserted = sqlx::query("INSERT INTO tenants (id, name) VALUES ($1, $2)")
+        .bind(tenant_id.as_uuid())
+        .bind(format!("Feature Flag Tenant {tenant_id}"))
+        .execute(pool)
+        .await;
+    assert!(inserted.is_ok());
+
+    let mut transaction = begin_tenant_transaction(pool, tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to begin tenant transaction: {error}"));
+    for role in roles {
+        let inserted = sqlx::query("INSERT INTO rbac_roles (tenant_id, name) VALUES ($1, $2)")
+            .bind(tenant_id.as_uuid())
+            .bind(*role)
+            .execute(&mut *transaction)
+            .await;
+        assert!(inserted.is_ok());
+    }
+    assert!(transaction.commit().await.is_ok());
+}
+
+fn save_input(enabled: bool, role_overrides: Vec<(&str, bool)>) -> SaveFeatureFlagInput {
+    SaveFeatureFlagInput {
+        flag_key: "workflows".to_owned(),
+        description: Some("Workflow builder rollout".to_owned()),
+        enabled,
+        role_overrides: role_overrides
+            .into_iter()
+            .map(|(role_name, enabled)| FeatureFlagRoleOverride {
+                role_name: role_name.to_owned(),
+                enabled,
+            })
+            .collect(),
+    }
+}
+
+#[tokio::test]
+async fn feature_flags_replace_overrides_and_are_tenant_scoped() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresFeatureFlagRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    seed_tenant(&pool, tenant_id, &["beta", "support"]).await;
+    seed_tenant(&pool, other_tenant_id, &["beta"]).await;
+
+    let saved = repository
+        .save_feature_flag(
+            tenant_id,
+            "alice",
+            save_input(false, vec![("support", false), ("beta", true)]),
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to save feature flag: {error}"));
+    assert!(!saved.enabled);
+    assert_eq!(
+        saved
+            .role_overrides
+            .iter()
+            .map(|role_override| (role_override.role_name.as_str(), role_override.enabled))
+            .collect::<Vec<_>>(),
+        vec![("beta", true), ("support", false)]
+    );
+
+    let replaced = repository
+        .save_feature_flag(tenant_id, "bob", save_input(true, vec![("beta", false)]))
+        .await
+        .unwrap_or_else(|error| panic!("failed to replace feature flag: {error}"));
+    assert!(replaced.enabled);
+    assert_eq!(replaced.updated_by_subject, "bob");
+    assert_eq!(replaced.role_overrides.len(), 1);
+
+    let unknown_role = repository
+        .save_feature_flag(tenant_id, "alice", save_input(true, vec![("sales", true)]))
+        .await;
+    assert!(matches!(unknown_role, Err(AppError::NotFound(_))));
+
+    let other_tenant_flags = repository
+        .list_feature_flags(other_tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list feature flags: {error}"));
+    assert!(other_tenant_flags.is_empty());
+    let found = repository
+        .find_feature_flag(tenant_id, "workflows")
+        .await
+        .unwrap_or_else(|error| panic!("failed to find feature flag: {error}"));
+    assert_eq!(found, Some(replaced));
+
+    assert!(
+        repository
+            .delete_feature_flag(tenant_id, "workflows")
+            .await
+            .unwrap_or_else(|error| panic!("failed to delete feature flag: {error}"))
+    );
+    assert!(
+        !repository
+            .delete_feature_flag(tenant_id, "workflows")
+            .await
+            .unwrap_or_else(|error| panic!("failed to delete feature flag: {error}"))
+    );
+}
@@ -0,0 +1,447 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    DEFAULT_APPROVAL_WINDOW_HOURS, DualControlField, FieldChangeApprovalRepository,
+    NewPendingFieldChange, PendingFieldChange, PendingFieldChangeQuery, PendingFieldChangeStatus,
+    SaveDualControlFieldsInput,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for dual-control fields and pending field changes.
+#[derive(Clone)]
+pub struct PostgresFieldChangeApprovalRepository {
+    pool: PgPool,
+}
+
+impl PostgresFieldChangeApprovalRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct DualControlFieldRow {
+    entity_logical_name: String,
+    field_logical_name: String,
+    approval_window_hours: i32,
+    updated_by_subject: String,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<DualControlFieldRow> for DualControlField {
+    fn from(row: DualControlFieldRow) -> Self {
+        Self {
+            entity_logical_name: row.entity_logical_name,
+            field_logical_name: row.field_logical_name,
+            approval_window_hours: u16::try_from(row.approval_window_hours)
+                .unwrap_or(DEFAULT_APPROVAL_WINDOW_HOURS),
+            updated_by_subject: row.updated_by_subject,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct PendingFieldChangeRow {
+    id: uuid::Uuid,
+    tenant_id: uuid::Uuid,
+    entity_logical_name: String,
+    record_id: String,
+    field_logical_name: String,
+    previous_value: Option<Value>,
+    proposed_value: Option<Value>,
+    requested_by_subject: String,
+    requested_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    status: String,
+    decided_by_subject: Option<String>,
+    decided_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<PendingFieldChangeRow> for PendingFieldChange {
+    type Error = AppError;
+
+    fn try_from(row: PendingFieldChangeRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            change_id: row.id.to_string(),
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            entity_logical_name: row.entity_logical_name,
+            record_id: row.record_id,
+            field_logical_name: row.field_logical_name,
+            previous_value: row.previous_value,
+            proposed_value: row.proposed_value,
+            requested_by_subject: row.requested_by_subject,
+            requested_at: row.requested_at,
+            expires_at: row.expires_at,
+            status: PendingFieldChangeStatus::parse(row.status.as_str())?,
+            decided_by_subject: row.decided_by_subject,
+            decided_at: row.decided_at,
+        })
+    }
+}
+
+const FIELD_COLUMNS: &str = r#"
+    entity_logical_name,
+    field_logical_name,
+    approval_window_hours,
+    updated_by_subject,
+    updated_at
+"#;
+
+const CHANGE_COLUMNS: &str = r#"
+    id,
+    tenant_id,
+    entity_logical_name,
+    record_id,
+    field_logical_name,
+    previous_value,
+    proposed_value,
+    requested_by_subject,
+    requested_at,
+    expires_at,
+    status,
+    decided_by_subject,
+    decided_at
+"#;
+
+#[async_trait]
+impl FieldChangeApprovalRepository for PostgresFieldChangeApprovalRepository {
+    async fn list_dual_control_fields(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: Option<&str>,
+    ) -> AppResult<Vec<DualControlField>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, DualControlFieldRow>(&format!(
+            r#"
+            SELECT {FIELD_COLUMNS}
+            FROM runtime_dual_control_fields
+            WHERE tenant_id = $1
+              AND ($2::TEXT IS NULL OR entity_logical_name = $2)
+            ORDER BY entity_logical_name, field_logical_name
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list dual-control fields: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped dual-control field list transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(DualControlField::from).collect())
+    }
+
+    async fn save_dual_control_fields(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveDualControlFieldsInput,
+    ) -> AppResult<Vec<DualControlField>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            DELETE FROM runtime_dual_control_fields
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(input.entity_logical_name.as_str())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to clear dual-control fields: {error}"))
+        })?;
+
+        let mut fields = Vec::with_capacity(input.fields.len());
+        for field in input.fields {
+            let row = sqlx::query_as::<_, DualControlFieldRow>(&format!(
+                r#"
+                INSERT INTO runtime_dual_control_fields (
+                    tenant_id,
+                    entity_logical_name,
+                    field_logical_name,
+                    approval_window_hours,
+                    updated_by_subject,
+                    updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, now())
+                RETURNING {FIELD_COLUMNS}
+                "#
+            ))
+            .bind(tenant_id.as_uuid())
+            .bind(input.entity_logical_name.as_str())
+            .bind(field.field_logical_name)
+            .bind(i32::from(
+                field
+                    .approval_window_hours
+                    .unwrap_or(DEFAULT_APPROVAL_WINDOW_HOURS),
+            ))
+            .bind(updated_by_subject)
+            .fetch_one(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!("failed to save dual-control field: {error}"))
+            })?;
+            fields.push(DualControlField::from(row));
+        }
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped dual-control field save transaction: {error}"
+            ))
+        })?;
+
+        Ok(fields)
+    }
+
+    async fn create_pending_changes(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        changes: Vec<NewPendingFieldChange>,
+    ) -> AppResult<Vec<PendingFieldChange>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let mut created = Vec::with_capacity(changes.len());
+        for change in changes {
+            let change_id = uuid::Uuid::parse_str(change.change_id.as_str()).map_err(|error| {
+                AppError::Validation(format!(
+                    "invalid pending field change id '{}': {error}",
+                    change.change_id
+                ))
+            })?;
+            let row = sqlx::query_as::<_, PendingFieldChangeRow>(&format!(
+                r#"
+                INSERT INTO runtime_pending_field_changes (
+                    id,
+                    tenant_id,
+                    entity_logical_name,
+                    record_id,
+                    field_logical_name,
+                    previous_value,
+                    proposed_value,
+                    requested_by_subject,
+                    expires_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                RETURNING {CHANGE_COLUMNS}
+                "#
+            ))
+            .bind(change_id)
+            .bind(tenant_id.as_uuid())
+            .bind(change.entity_logical_name.as_str())
+            .bind(change.record_id.as_str())
+            .bind(change.field_logical_name.as_str())
+            .bind(change.previous_value)
+            .bind(change.proposed_value)
+            .bind(requested_by_subject)
+            .bind(change.expires_at)
+            .fetch_one(&mut *transaction)
+            .await
+            .map_err(|error| {
+                if let sqlx::Error::Database(database_error) = &error
+                    && database_error.code().as_deref() == Some("23505")
+                {
+                    return AppError::Conflict(format!(
+                        "field '{}' on runtime record '{}' already has a pending change awaiting approval",
+                        change.field_logical_name, change.record_id
+                    ));
+                }
+
+                AppError::Internal(format!("failed to create pending field change: {error}"))
+            })?;
+
+            if let Some(notification) = change.notification {
+                sqlx::query(
+                    r#"
+                    INSERT INTO workflow_runtime_trigger_events (
+                        tenant_id,
+                        trigger_type,
+                        entity_logical_name,
+                        record_id,
+                        emitted_by_subject,
+                        payload,
+                        status,
+                        created_at,
+                        updated_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, 'pending', now(), now())
+                    "#,
+                )
+                .bind(tenant_id.as_uuid())
+                .bind(notification.trigger.trigger_type())
+                .bind(change.entity_logical_name.as_str())
+                .bind(notification.record_id)
+                .bind(notification.emitted_by_subject)
+                .bind(notification.payload)
+                .execute(&mut *transaction)
+                .await
+                .map_err(|error| {
+                    AppError::Internal(format!(
+                        "failed to enqueue pending field change notification: {error}"
+                    ))
+                })?;
+            }
+
+            created.push(PendingFieldChange::try_from(row)?);
+        }
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped pending field change transaction: {error}"
+            ))
+        })?;
+
+        Ok(created)
+    }
+
+    async fn list_pending_changes(
+        &self,
+        tenant_id: TenantId,
+        query: PendingFieldChangeQuery,
+    ) -> AppResult<Vec<PendingFieldChange>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, PendingFieldChangeRow>(&format!(
+            r#"
+            SELECT {CHANGE_COLUMNS}
+            FROM runtime_pending_field_changes
+            WHERE tenant_id = $1
+              AND ($2::TEXT IS NULL OR entity_logical_name = $2)
+              AND ($3::TEXT IS NULL OR record_id = $3)
+              AND ($4::TEXT IS NULL OR status = $4)
+            ORDER BY requested_at DESC, id DESC
+            LIMIT 500
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(query.entity_logical_name)
+        .bind(query.record_id)
+        .bind(query.status.map(|status| status.as_str()))
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list pending field changes: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped pending field change list transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter().map(PendingFieldChange::try_from).collect()
+    }
+
+    async fn find_pending_change(
+        &self,
+        tenant_id: TenantId,
+        change_id: &str,
+    ) -> AppResult<Option<PendingFieldChange>> {
+        let Ok(change_uuid) = uuid::Uuid::parse_str(change_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, PendingFieldChangeRow>(&format!(
+            r#"
+            SELECT {CHANGE_COLUMNS}
+            FROM runtime_pending_field_changes
+            WHERE tenant_id = $1 AND id = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(change_uuid)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to find pending field change: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped pending field change lookup transaction: {error}"
+            ))
+        })?;
+
+        row.map(PendingFieldChange::try_from).transpose()
+    }
+
+    async fn resolve_pending_change(
+        &self,
+        tenant_id: TenantId,
+        change_id: &str,
+        decided_by_subject: &str,
+        status: PendingFieldChangeStatus,
+    ) -> AppResult<Option<PendingFieldChange>> {
+        let Ok(change_uuid) = uuid::Uuid::parse_str(change_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, PendingFieldChangeRow>(&format!(
+            r#"
+            UPDATE runtime_pending_field_changes
+            SET status = $3,
+                decided_by_subject = $4,
+                decided_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND status = 'pending'
+              AND expires_at > now()
+            RETURNING {CHANGE_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(change_uuid)
+        .bind(status.as_str())
+        .bind(decided_by_subject)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to resolve pending field change: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped pending field change decision transaction: {error}"
+            ))
+        })?;
+
+        row.map(PendingFieldChange::try_from).transpose()
+    }
+
+    async fn expire_stale_changes(&self, tenant_id: TenantId) -> AppResult<u64> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            UPDATE runtime_pending_field_changes
+            SET status = 'expired'
+            WHERE tenant_id = $1
+              AND status = 'pending'
+              AND expires_at <= now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to expire pending field changes: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped pending field change expiry transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,187 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool, Postgres, Transaction};
+
+use qryvanta_application::{FieldMaskingRepository, FieldMaskingRule, SaveFieldMaskingRulesInput};
+use qryvanta_core::{AppError, AppResult, TenantId};
+use qryvanta_domain::{FieldClassification, FieldMaskingMode};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for role-based field masking rules.
+#[derive(Clone)]
+pub struct PostgresFieldMaskingRepository {
+    pool: PgPool,
+}
+
+impl PostgresFieldMaskingRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct FieldMaskingRuleRow {
+    role_name: String,
+    classification: String,
+    masking_mode: String,
+    updated_by_subject: String,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<FieldMaskingRuleRow> for FieldMaskingRule {
+    type Error = AppError;
+
+    fn try_from(row: FieldMaskingRuleRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            role_name: row.role_name,
+            classification: FieldClassification::from_str(row.classification.as_str())?,
+            masking_mode: FieldMaskingMode::from_str(row.masking_mode.as_str())?,
+            updated_by_subject: row.updated_by_subject,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+async fn list_rules_in_transaction(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    role_name: Option<&str>,
+) -> AppResult<Vec<FieldMaskingRule>> {
+    let rows = sqlx::query_as::<_, FieldMaskingRuleRow>(
+        r#"
+        SELECT
+            roles.name AS role_name,
+            rules.classification,
+            rules.masking_mode,
+            rules.updated_by_subject,
+            rules.updated_at
+        FROM field_masking_rules rules
+        INNER JOIN rbac_roles roles
+            ON roles.id = rules.role_id AND roles.tenant_id = rules.tenant_id
+        WHERE rules.tenant_id = $1
+          AND ($2::TEXT IS NULL OR roles.name = $2)
+        ORDER BY roles.name, rules.classification
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(role_name)
+    .fetch_all(&mut **transaction)
+    .await
+    .map_err(|error| AppError::Internal(format!("failed to list field masking rules: {error}")))?;
+
+    rows.into_iter().map(FieldMaskingRule::try_from).collect()
+}
+
+#[async_trait]
+impl FieldMaskingRepository for PostgresFieldMaskingRepository {
+    async fn list_masking_rules(
+        &self,
+        tenant_id: TenantId,
+        role_name: Option<&str>,
+    ) -> AppResult<Vec<FieldMaskingRule>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rules = list_rules_in_transaction(&mut transaction, tenant_id, role_name).await?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped field masking rule list transaction: {error}"
+            ))
+        })?;
+
+        Ok(rules)
+    }
+
+    async fn save_role_masking_rules(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveFieldMaskingRulesInput,
+    ) -> AppResult<Vec<FieldMaskingRule>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let role_id = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+            SELECT id
+            FROM rbac_roles
+            WHERE tenant_id = $1 AND name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(input.role_name.as_str())
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to look up role '{}' for tenant '{}': {error}",
+                input.role_name, tenant_id
+            ))
+        })?
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "role '{}' does not exist for tenant '{}'",
+                input.role_name, tenant_id
+            ))
+        })?;
+
+        sqlx::query("DELETE FROM field_masking_rules WHERE tenant_id = $1 AND role_id = $2")
+            .bind(tenant_id.as_uuid())
+            .bind(role_id)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!("failed to clear field masking rules: {error}"))
+            })?;
+
+        let classifications = input
+            .rules
+            .iter()
+            .map(|rule| rule.classification.as_str())
+            .collect::<Vec<_>>();
+        let masking_modes = input
+            .rules
+            .iter()
+            .map(|rule| rule.masking_mode.as_str())
+            .collect::<Vec<_>>();
+        sqlx::query(
+            r#"
+            INSERT INTO field_masking_rules (
+                tenant_id,
+                role_id,
+                classification,
+                masking_mode,
+                updated_by_subject,
+                updated_at
+            )
+            SELECT $1, $2, rule.classification, rule.masking_mode, $5, now()
+            FROM UNNEST($3::TEXT[], $4::TEXT[]) AS rule(classification, masking_mode)
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(role_id)
+        .bind(&classifications)
+        .bind(&masking_modes)
+        .bind(updated_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to save field masking rules: {error}"))
+        })?;
+
+        let rules =
+            list_rules_in_transaction(&mut transaction, tenant_id, Some(input.role_name.as_str()))
+                .await?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped field masking rule save transaction: {error}"
+            ))
+        })?;
+
+        Ok(rules)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,194 @@
+use qryvanta_application::{
+    FieldMaskingRepository, FieldMaskingRuleInput, MetadataRepository, SaveFieldMaskingRulesInput,
+};
+use qryvanta_core::{AppError, TenantId};
+use qryvanta_domain::{
+    EntityDefinition, EntityFieldDefinition, FieldClassification, FieldMaskingMode, FieldType,
+};
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresFieldMaskingRepository;
+use crate::{PostgresMetadataRepository, begin_tenant_transaction};
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres field masking tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn seed_tenant(pool: &PgPool, tenant_id: TenantId, roles: &[&str]) {
+    let inserted = sqlx::query("INSERT INTO tenants (id, name) VALUES ($1, $2)")
+        .bind(tenant_id.as_uuid())
+        .bind(format!("Field Masking Tenant {tenant_id}"))
+        .execute(pool)
+        .await;
+    assert!(inserted.is_ok());
+
+    let mut transaction = begin_tenant_transaction(pool, tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to begin tenant transaction: {error}"));
+    for role in roles {
+        let inserted = sqlx::query("INSERT INTO rbac_roles (tenant_id, name) VALUES ($1, $2)")
+            .bind(tenant_id.as_uuid())
+            .bind(*role)
+            .execute(&mut *transaction)
+            .await;
+        assert!(inserted.is_ok());
+    }
+    assert!(transaction.commit().await.is_ok());
+}
+
+fn rule(
+    classification: FieldClassification,
+    masking_mode: FieldMaskingMode,
+) -> FieldMaskingRuleInput {
+    FieldMaskingRuleInput {
+        classification,
+        masking_mode,
+    }
+}
+
+#[tokio::test]
+async fn masking_rules_are_replaced_per_role_and_tenant_scoped() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresFieldMaskingRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    seed_tenant(&pool, tenant_id, &["support", "auditor"]).await;
+    seed_tenant(&pool, other_tenant_id, &["support"]).await;
+
+    let saved = repository
+        .save_role_masking_rules(
+            tenant_id,
+            "alice",
+            SaveFieldMaskingRulesInput {
+                role_name: "support".to_owned(),
+                rules: vec![
+                    rule(FieldClassification::Pii, FieldMaskingMode::Partial),
+                    rule(FieldClassification::Phi, FieldMaskingMode::Hidden),
+                ],
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(saved.len(), 2);
+    assert!(saved.iter().all(|rule| rule.role_name == "support"));
+    assert!(
+        repository
+            .save_role_masking_rules(
+                tenant_id,
+                "alice",
+                SaveFieldMaskingRulesInput {
+                    role_name: "auditor".to_owned(),
+                    rules: vec![rule(
+                        FieldClassification::Confidential,
+                        FieldMaskingMode::Full,
+                    )],
+                },
+            )
+            .await
+            .is_ok()
+    );
+
+    let replaced = repository
+        .save_role_masking_rules(
+            tenant_id,
+            "bob",
+            SaveFieldMaskingRulesInput {
+                role_name: "support".to_owned(),
+                rules: vec![rule(FieldClassification::Pii, FieldMaskingMode::Full)],
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(replaced.len(), 1);
+    assert_eq!(replaced[0].masking_mode, FieldMaskingMode::Full);
+    assert_eq!(replaced[0].updated_by_subject, "bob");
+
+    let all = repository
+        .list_masking_rules(tenant_id, None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        all.iter()
+            .map(|rule| (rule.role_name.as_str(), rule.classification))
+            .collect::<Vec<_>>(),
+        vec![
+            ("auditor", FieldClassification::Confidential),
+            ("support", FieldClassification::Pii),
+        ]
+    );
+    let other_tenant = repository
+        .list_masking_rules(other_tenant_id, None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(other_tenant.is_empty());
+
+    let unknown_role = repository
+        .save_role_masking_rules(
+            tenant_id,
+            "alice",
+            SaveFieldMaskingRulesInput {
+                role_name: "ghost".to_owned(),
+                rules: Vec::new(),
+            },
+        )
+        .await;
+    assert!(matches!(unknown_role, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn field_classification_round_trips_through_metadata_repository() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresMetadataRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    seed_tenant(&pool, tenant_id, &[]).await;
+
+    let entity = EntityDefinition::new("contact", "Contact").unwrap_or_else(|_| unreachable!());
+    assert!(repository.save_entity(tenant_id, entity).await.is_ok());
+    let field = EntityFieldDefinition::new(
+        "contact",
+        "national_id",
+        "National Id",
+        FieldType::Text,
+        false,
+        false,
+        None,
+        None,
+    )
+    .unwrap_or_else(|_| unreachable!())
+    .with_classification(Some(FieldClassification::Pii));
+    assert!(repository.save_field(tenant_id, field).await.is_ok());
+
+    let stored = repository
+        .find_field(tenant_id, "contact", "national_id")
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(stored.classification(), Some(FieldClassification::Pii));
+}
@@ -0,0 +1,246 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{CreateLegalHoldInput, LegalHold, LegalHoldRepository, LegalHoldScope};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for compliance legal holds.
+#[derive(Clone)]
+pub struct PostgresLegalHoldRepository {
+    pool: PgPool,
+}
+
+impl PostgresLegalHoldRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct LegalHoldRow {
+    id: uuid::Uuid,
+    tenant_id: uuid::Uuid,
+    name: String,
+    reason: String,
+    scope_type: String,
+    entity_logical_name: Option<String>,
+    record_ids: Vec<String>,
+    audit_from: Option<DateTime<Utc>>,
+    audit_until: Option<DateTime<Utc>>,
+    created_by_subject: String,
+    created_at: DateTime<Utc>,
+    released_by_subject: Option<String>,
+    released_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<LegalHoldRow> for LegalHold {
+    type Error = AppError;
+
+    fn try_from(row: LegalHoldRow) -> Result<Self, Self::Error> {
+        let scope = match (
+            row.scope_type.as_str(),
+            row.entity_logical_name,
+            row.audit_from,
+        ) {
+            ("runtime_records", Some(entity_logical_name), _) => LegalHoldScope::RuntimeRecords {
+                entity_logical_name,
+                record_ids: row.record_ids,
+            },
+            ("audit_log", _, Some(from)) => LegalHoldScope::AuditLog {
+                from,
+                until: row.audit_until,
+            },
+            (scope_type, _, _) => {
+                return Err(AppError::Internal(format!(
+                    "invalid legal hold scope '{scope_type}' for hold '{}'",
+                    row.id
+                )));
+            }
+        };
+
+        Ok(Self {
+            hold_id: row.id.to_string(),
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            name: row.name,
+            reason: row.reason,
+            scope,
+            created_by_subject: row.created_by_subject,
+            created_at: row.created_at,
+            released_by_subject: row.released_by_subject,
+            released_at: row.released_at,
+        })
+    }
+}
+
+const HOLD_COLUMNS: &str = r#"
+    id,
+    tenant_id,
+    name,
+    reason,
+    scope_type,
+    entity_logical_name,
+    record_ids,
+    audit_from,
+    audit_until,
+    created_by_subject,
+    created_at,
+    released_by_subject,
+    released_at
+"#;
+
+#[async_trait]
+impl LegalHoldRepository for PostgresLegalHoldRepository {
+    async fn create_hold(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        input: CreateLegalHoldInput,
+    ) -> AppResult<LegalHold> {
+        let scope_type = input.scope.scope_type();
+        let (entity_logical_name, record_ids, audit_from, audit_until) = match input.scope {
+            LegalHoldScope::RuntimeRecords {
+                entity_logical_name,
+                record_ids,
+            } => (Some(entity_logical_name), record_ids, None, None),
+            LegalHoldScope::AuditLog { from, until } => (None, Vec::new(), Some(from), until),
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, LegalHoldRow>(&format!(
+            r#"
+            INSERT INTO legal_holds (
+                id,
+                tenant_id,
+                name,
+                reason,
+                scope_type,
+                entity_logical_name,
+                record_ids,
+                audit_from,
+                audit_until,
+                created_by_subject
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING {HOLD_COLUMNS}
+            "#
+        ))
+        .bind(uuid::Uuid::new_v4())
+        .bind(tenant_id.as_uuid())
+        .bind(input.name)
+        .bind(input.reason)
+        .bind(scope_type)
+        .bind(entity_logical_name)
+        .bind(record_ids)
+        .bind(audit_from)
+        .bind(audit_until)
+        .bind(created_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to create legal hold: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!("failed to commit legal hold transaction: {error}"))
+        })?;
+
+        LegalHold::try_from(row)
+    }
+
+    async fn list_holds(
+        &self,
+        tenant_id: TenantId,
+        include_released: bool,
+    ) -> AppResult<Vec<LegalHold>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, LegalHoldRow>(&format!(
+            r#"
+            SELECT {HOLD_COLUMNS}
+            FROM legal_holds
+            WHERE tenant_id = $1
+              AND ($2 OR released_at IS NULL)
+            ORDER BY created_at DESC, id DESC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(include_released)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list legal holds: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!("failed to commit legal hold transaction: {error}"))
+        })?;
+
+        rows.into_iter().map(LegalHold::try_from).collect()
+    }
+
+    async fn release_hold(
+        &self,
+        tenant_id: TenantId,
+        hold_id: &str,
+        released_by_subject: &str,
+    ) -> AppResult<Option<LegalHold>> {
+        let hold_uuid = uuid::Uuid::parse_str(hold_id)
+            .map_err(|_| AppError::Validation(format!("invalid legal hold id '{hold_id}'")))?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, LegalHoldRow>(&format!(
+            r#"
+            UPDATE legal_holds
+            SET released_at = now(), released_by_subject = $3
+            WHERE tenant_id = $1 AND id = $2 AND released_at IS NULL
+            RETURNING {HOLD_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(hold_uuid)
+        .bind(released_by_subject)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to release legal hold: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!("failed to commit legal hold transaction: {error}"))
+        })?;
+
+        row.map(LegalHold::try_from).transpose()
+    }
+
+    async fn find_active_record_hold(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<LegalHold>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, LegalHoldRow>(&format!(
+            r#"
+            SELECT {HOLD_COLUMNS}
+            FROM legal_holds
+            WHERE tenant_id = $1
+              AND scope_type = 'runtime_records'
+              AND entity_logical_name = $2
+              AND released_at IS NULL
+              AND (cardinality(record_ids) = 0 OR $3 = ANY(record_ids))
+            ORDER BY created_at ASC
+            LIMIT 1
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(record_id)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to find legal hold: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!("failed to commit legal hold transaction: {error}"))
+        })?;
+
+        row.map(LegalHold::try_from).transpose()
+    }
+}
@@ -0,0 +1,160 @@
+use async_trait::async_trait;
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::LocalizedLabelRepository;
+use qryvanta_core::{AppError, AppResult, TenantId};
+use qryvanta_domain::{LocaleTag, LocalizedComponentType, LocalizedDisplayLabel};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for localized display labels.
+#[derive(Clone)]
+pub struct PostgresLocalizedLabelRepository {
+    pool: PgPool,
+}
+
+impl PostgresLocalizedLabelRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct LocalizedLabelRow {
+    component_type: String,
+    component_key: String,
+    locale: String,
+    display_name: String,
+    description: Option<String>,
+}
+
+impl TryFrom<LocalizedLabelRow> for LocalizedDisplayLabel {
+    type Error = AppError;
+
+    fn try_from(row: LocalizedLabelRow) -> Result<Self, Self::Error> {
+        LocalizedDisplayLabel::new(
+            row.component_type.parse::<LocalizedComponentType>()?,
+            row.component_key,
+            LocaleTag::new(row.locale)?,
+            row.display_name,
+            row.description,
+        )
+    }
+}
+
+#[async_trait]
+impl LocalizedLabelRepository for PostgresLocalizedLabelRepository {
+    async fn save_localized_label(
+        &self,
+        tenant_id: TenantId,
+        label: LocalizedDisplayLabel,
+    ) -> AppResult<()> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO metadata_localized_labels (
+                tenant_id,
+                component_type,
+                component_key,
+                locale,
+                display_name,
+                description
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (tenant_id, component_type, component_key, locale)
+            DO UPDATE SET
+                display_name = EXCLUDED.display_name,
+                description = EXCLUDED.description,
+                updated_at = now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(label.component_type().as_str())
+        .bind(label.component_key().as_str())
+        .bind(label.locale().as_str())
+        .bind(label.display_name().as_str())
+        .bind(label.description())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to save localized label: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit localized label transaction: {error}"
+            ))
+        })
+    }
+
+    async fn delete_localized_label(
+        &self,
+        tenant_id: TenantId,
+        component_type: LocalizedComponentType,
+        component_key: &str,
+        locale: &LocaleTag,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM metadata_localized_labels
+            WHERE tenant_id = $1
+              AND component_type = $2
+              AND component_key = $3
+              AND locale = $4
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(component_type.as_str())
+        .bind(component_key)
+        .bind(locale.as_str())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to delete localized label: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit localized label transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_localized_labels(
+        &self,
+        tenant_id: TenantId,
+        component_type: LocalizedComponentType,
+        component_key: Option<&str>,
+    ) -> AppResult<Vec<LocalizedDisplayLabel>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, LocalizedLabelRow>(
+            r#"
+            SELECT component_type, component_key, locale, display_name, description
+            FROM metadata_localized_labels
+            WHERE tenant_id = $1
+              AND component_type = $2
+              AND ($3::TEXT IS NULL OR component_key = $3)
+            ORDER BY component_key, locale
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(component_type.as_str())
+        .bind(component_key)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list localized labels: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit localized label transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(LocalizedDisplayLabel::try_from)
+            .collect()
+    }
+}
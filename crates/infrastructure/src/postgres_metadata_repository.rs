@@ -2,17 +2,25 @@ use std::str::FromStr;
 
 use crate::{begin_tenant_transaction, begin_workflow_worker_transaction};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use qryvanta_application::{
-    ClaimedRuntimeRecordWorkflowEvent, MetadataRepository, RecordListQuery,
-    RuntimeRecordConditionGroup, RuntimeRecordConditionNode, RuntimeRecordFilter,
-    RuntimeRecordJoinType, RuntimeRecordLogicalMode, RuntimeRecordOperator, RuntimeRecordQuery,
-    RuntimeRecordSort, RuntimeRecordSortDirection, RuntimeRecordWorkflowEventInput,
-    UniqueFieldValue,
+    AuditEvent, ClaimedRuntimeRecordWorkflowEvent, EntityHistoryPolicy, EntitySlugConfig,
+    EntityStatusConfig, MetadataRepository, NewRuntimeRecordHistoryEntry,
+    NewRuntimeRecordStatusChange, PublishedEntitySchemaVersion, RecordListQuery, RelationBehavior,
+    RelationCascadeResult, RelationDeletePlan, RelationLookupConfig, RelationRelinkedRecord,
+    RuntimeRecordAggregateQuery, RuntimeRecordAggregateRow, RuntimeRecordAssociation,
+    RuntimeRecordChangesetMethod, RuntimeRecordChangesetWrite, RuntimeRecordConditionGroup,
+    RuntimeRecordConditionNode, RuntimeRecordFilter, RuntimeRecordHistoryEntry,
+    RuntimeRecordJoinType, RuntimeRecordLinkCardinality, RuntimeRecordLogicalMode,
+    RuntimeRecordOperator, RuntimeRecordQuery, RuntimeRecordSort, RuntimeRecordSortDirection,
+    RuntimeRecordStatusChange, RuntimeRecordWorkflowEventInput, UniqueFieldValue,
 };
 use qryvanta_core::{AppError, AppResult, TenantId};
 use qryvanta_domain::{
-    BusinessRuleDefinition, EntityDefinition, EntityFieldDefinition, FieldType, FormDefinition,
-    OptionSetDefinition, PublishedEntitySchema, RuntimeRecord, ViewDefinition, WorkflowTrigger,
+    BusinessRuleDefinition, DuplicateDetectionRule, EntityDefinition, EntityFieldDefinition,
+    FieldClassification, FieldType, FormDefinition, OptionSetDefinition, PublishedEntitySchema,
+    RelationCascadeBehavior, RelationDeleteBehavior, RuntimeRecord, ViewDefinition,
+    WorkflowTrigger,
 };
 use serde_json::Value;
 use sqlx::{FromRow, PgPool, Postgres};
@@ -39,6 +47,8 @@ struct EntityRow {
     description: Option<String>,
     plural_display_name: Option<String>,
     icon: Option<String>,
+    color: Option<String>,
+    is_active: bool,
 }
 
 #[derive(Debug, FromRow)]
@@ -57,6 +67,35 @@ struct FieldRow {
     max_length: Option<i32>,
     min_value: Option<f64>,
     max_value: Option<f64>,
+    classification: Option<String>,
+}
+
+impl FieldRow {
+    fn into_field_definition(self) -> AppResult<EntityFieldDefinition> {
+        let field_type = FieldType::from_str(self.field_type.as_str())?;
+        let classification = self
+            .classification
+            .as_deref()
+            .map(FieldClassification::from_str)
+            .transpose()?;
+        Ok(EntityFieldDefinition::new_with_details_and_calculation(
+            self.entity_logical_name,
+            self.logical_name,
+            self.display_name,
+            field_type,
+            self.is_required,
+            self.is_unique,
+            self.default_value,
+            self.relation_target_entity,
+            self.option_set_logical_name,
+            self.description,
+            self.calculation_expression,
+            self.max_length,
+            self.min_value,
+            self.max_value,
+        )?
+        .with_classification(classification))
+    }
 }
 
 #[derive(Debug, FromRow)]
@@ -65,6 +104,14 @@ struct PublishedSchemaRow {
     schema_json: Value,
 }
 
+#[derive(Debug, FromRow)]
+struct PublishedSchemaVersionRow {
+    version: i32,
+    schema_json: Value,
+    published_by_subject: String,
+    published_at: DateTime<Utc>,
+}
+
 #[derive(Debug, FromRow)]
 struct OptionSetRow {
     entity_logical_name: String,
@@ -93,11 +140,29 @@ struct LatestSchemaRow {
     schema_json: Value,
 }
 
+#[derive(Debug, FromRow)]
+struct EntitySlugConfigRow {
+    entity_logical_name: String,
+    slug_field_logical_name: String,
+    source_field_logical_name: String,
+}
+
+#[derive(Debug, FromRow)]
+struct RelationBehaviorRow {
+    entity_logical_name: String,
+    field_logical_name: String,
+    target_entity_logical_name: String,
+    assign_behavior: String,
+    share_behavior: String,
+    delete_behavior: String,
+}
+
 #[derive(Debug, FromRow)]
 struct RuntimeRecordRow {
     id: Uuid,
     entity_logical_name: String,
     data: Value,
+    version: i64,
 }
 
 #[derive(Debug, FromRow)]
@@ -139,6 +204,10 @@ impl MetadataRepository for PostgresMetadataRepository {
         self.update_entity_impl(tenant_id, entity).await
     }
 
+    async fn delete_entity(&self, tenant_id: TenantId, logical_name: &str) -> AppResult<()> {
+        self.delete_entity_impl(tenant_id, logical_name).await
+    }
+
     async fn save_field(&self, tenant_id: TenantId, field: EntityFieldDefinition) -> AppResult<()> {
         self.save_field_impl(tenant_id, field).await
     }
@@ -344,6 +413,46 @@ impl MetadataRepository for PostgresMetadataRepository {
             .await
     }
 
+    async fn find_published_schema(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        self.find_published_schema_impl(tenant_id, entity_logical_name, version)
+            .await
+    }
+
+    async fn list_published_schema_versions(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<PublishedEntitySchemaVersion>> {
+        self.list_published_schema_versions_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn find_published_schema_version(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchemaVersion>> {
+        self.find_published_schema_version_impl(tenant_id, entity_logical_name, version)
+            .await
+    }
+
+    async fn rollback_entity_schema(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+        published_by: &str,
+    ) -> AppResult<PublishedEntitySchema> {
+        self.rollback_entity_schema_impl(tenant_id, entity_logical_name, version, published_by)
+            .await
+    }
+
     async fn save_published_form_snapshots(
         &self,
         tenant_id: TenantId,
@@ -402,6 +511,7 @@ impl MetadataRepository for PostgresMetadataRepository {
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         self.create_runtime_record_impl(
             tenant_id,
@@ -410,6 +520,7 @@ impl MetadataRepository for PostgresMetadataRepository {
             unique_values,
             created_by_subject,
             workflow_event,
+            audit_event,
         )
         .await
     }
@@ -423,6 +534,7 @@ impl MetadataRepository for PostgresMetadataRepository {
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         self.create_runtime_record_with_id_impl(
             tenant_id,
@@ -432,6 +544,47 @@ impl MetadataRepository for PostgresMetadataRepository {
             unique_values,
             created_by_subject,
             workflow_event,
+            audit_event,
+        )
+        .await
+    }
+
+    async fn create_runtime_record_with_idempotency_key(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        idempotency_key: &str,
+        data: Value,
+        unique_values: Vec<UniqueFieldValue>,
+        created_by_subject: &str,
+        workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+    ) -> AppResult<RuntimeRecord> {
+        self.create_runtime_record_with_idempotency_key_impl(
+            tenant_id,
+            entity_logical_name,
+            record_id,
+            idempotency_key,
+            data,
+            unique_values,
+            created_by_subject,
+            workflow_event,
+            audit_event,
+        )
+        .await
+    }
+
+    async fn find_runtime_record_by_idempotency_key(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        idempotency_key: &str,
+    ) -> AppResult<Option<RuntimeRecord>> {
+        self.find_runtime_record_by_idempotency_key_impl(
+            tenant_id,
+            entity_logical_name,
+            idempotency_key,
         )
         .await
     }
@@ -443,7 +596,9 @@ impl MetadataRepository for PostgresMetadataRepository {
         record_id: &str,
         data: Value,
         unique_values: Vec<UniqueFieldValue>,
+        expected_version: Option<i64>,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         self.update_runtime_record_impl(
             tenant_id,
@@ -451,11 +606,23 @@ impl MetadataRepository for PostgresMetadataRepository {
             record_id,
             data,
             unique_values,
+            expected_version,
             workflow_event,
+            audit_event,
         )
         .await
     }
 
+    async fn apply_runtime_record_changeset(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        writes: Vec<RuntimeRecordChangesetWrite>,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        self.apply_runtime_record_changeset_impl(tenant_id, created_by_subject, writes)
+            .await
+    }
+
     async fn list_runtime_records(
         &self,
         tenant_id: TenantId,
@@ -476,6 +643,16 @@ impl MetadataRepository for PostgresMetadataRepository {
             .await
     }
 
+    async fn aggregate_runtime_records(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        query: RuntimeRecordAggregateQuery,
+    ) -> AppResult<Vec<RuntimeRecordAggregateRow>> {
+        self.aggregate_runtime_records_impl(tenant_id, entity_logical_name, query)
+            .await
+    }
+
     async fn find_runtime_record(
         &self,
         tenant_id: TenantId,
@@ -492,9 +669,18 @@ impl MetadataRepository for PostgresMetadataRepository {
         entity_logical_name: &str,
         record_id: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+        relation_plan: &RelationDeletePlan,
     ) -> AppResult<()> {
-        self.delete_runtime_record_impl(tenant_id, entity_logical_name, record_id, workflow_event)
-            .await
+        self.delete_runtime_record_impl(
+            tenant_id,
+            entity_logical_name,
+            record_id,
+            workflow_event,
+            audit_event,
+            relation_plan,
+        )
+        .await
     }
 
     async fn claim_runtime_record_workflow_events(
@@ -573,6 +759,241 @@ impl MetadataRepository for PostgresMetadataRepository {
         .await
     }
 
+    async fn assign_runtime_record_owner(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        owner_subject: &str,
+        cascades: &[RelationBehavior],
+    ) -> AppResult<Option<(String, Vec<RelationCascadeResult>)>> {
+        self.assign_runtime_record_owner_impl(
+            tenant_id,
+            entity_logical_name,
+            record_id,
+            owner_subject,
+            cascades,
+        )
+        .await
+    }
+
+    async fn save_relation_behavior(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        behavior: RelationBehavior,
+    ) -> AppResult<()> {
+        self.save_relation_behavior_impl(tenant_id, updated_by_subject, behavior)
+            .await
+    }
+
+    async fn list_relation_behaviors(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<RelationBehavior>> {
+        self.list_relation_behaviors_impl(tenant_id).await
+    }
+
+    async fn save_relation_lookup_config(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        config: RelationLookupConfig,
+    ) -> AppResult<()> {
+        self.save_relation_lookup_config_impl(tenant_id, updated_by_subject, config)
+            .await
+    }
+
+    async fn find_relation_lookup_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<Option<RelationLookupConfig>> {
+        self.find_relation_lookup_config_impl(tenant_id, entity_logical_name, field_logical_name)
+            .await
+    }
+
+    async fn delete_relation_lookup_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<bool> {
+        self.delete_relation_lookup_config_impl(tenant_id, entity_logical_name, field_logical_name)
+            .await
+    }
+
+    async fn save_entity_slug_config(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        config: EntitySlugConfig,
+    ) -> AppResult<()> {
+        self.save_entity_slug_config_impl(tenant_id, updated_by_subject, config)
+            .await
+    }
+
+    async fn find_entity_slug_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntitySlugConfig>> {
+        self.find_entity_slug_config_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn save_entity_status_config(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        config: EntityStatusConfig,
+    ) -> AppResult<()> {
+        self.save_entity_status_config_impl(tenant_id, updated_by_subject, config)
+            .await
+    }
+
+    async fn find_entity_status_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityStatusConfig>> {
+        self.find_entity_status_config_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn delete_entity_status_config(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        self.delete_entity_status_config_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn save_duplicate_detection_rule(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        rule: DuplicateDetectionRule,
+    ) -> AppResult<()> {
+        self.save_duplicate_detection_rule_impl(tenant_id, updated_by_subject, rule)
+            .await
+    }
+
+    async fn list_duplicate_detection_rules(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<DuplicateDetectionRule>> {
+        self.list_duplicate_detection_rules_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn delete_duplicate_detection_rule(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        logical_name: &str,
+    ) -> AppResult<bool> {
+        self.delete_duplicate_detection_rule_impl(tenant_id, entity_logical_name, logical_name)
+            .await
+    }
+
+    async fn append_runtime_record_status_change(
+        &self,
+        tenant_id: TenantId,
+        change: NewRuntimeRecordStatusChange,
+    ) -> AppResult<RuntimeRecordStatusChange> {
+        self.append_runtime_record_status_change_impl(tenant_id, change)
+            .await
+    }
+
+    async fn list_runtime_record_status_history(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RuntimeRecordStatusChange>> {
+        self.list_runtime_record_status_history_impl(tenant_id, entity_logical_name, record_id)
+            .await
+    }
+
+    async fn save_entity_history_policy(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        policy: EntityHistoryPolicy,
+    ) -> AppResult<()> {
+        self.save_entity_history_policy_impl(tenant_id, updated_by_subject, policy)
+            .await
+    }
+
+    async fn find_entity_history_policy(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityHistoryPolicy>> {
+        self.find_entity_history_policy_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn delete_entity_history_policy(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        self.delete_entity_history_policy_impl(tenant_id, entity_logical_name)
+            .await
+    }
+
+    async fn append_runtime_record_history_entry(
+        &self,
+        tenant_id: TenantId,
+        entry: NewRuntimeRecordHistoryEntry,
+        retention_cutoff: Option<DateTime<Utc>>,
+    ) -> AppResult<RuntimeRecordHistoryEntry> {
+        self.append_runtime_record_history_entry_impl(tenant_id, entry, retention_cutoff)
+            .await
+    }
+
+    async fn list_runtime_record_history(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<RuntimeRecordHistoryEntry>> {
+        self.list_runtime_record_history_impl(tenant_id, entity_logical_name, record_id, since)
+            .await
+    }
+
+    async fn purge_runtime_record_history(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        cutoff: DateTime<Utc>,
+    ) -> AppResult<u64> {
+        self.purge_runtime_record_history_impl(tenant_id, entity_logical_name, cutoff)
+            .await
+    }
+
+    async fn find_runtime_record_id_by_unique_value(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        field_value_hash: &str,
+    ) -> AppResult<Option<String>> {
+        self.find_runtime_record_id_by_unique_value_impl(
+            tenant_id,
+            entity_logical_name,
+            field_logical_name,
+            field_value_hash,
+        )
+        .await
+    }
+
     async fn has_relation_reference(
         &self,
         tenant_id: TenantId,
@@ -582,6 +1003,41 @@ impl MetadataRepository for PostgresMetadataRepository {
         self.has_relation_reference_impl(tenant_id, target_entity_logical_name, target_record_id)
             .await
     }
+
+    async fn associate_runtime_records(
+        &self,
+        tenant_id: TenantId,
+        associated_by_subject: &str,
+        association: RuntimeRecordAssociation,
+    ) -> AppResult<bool> {
+        self.associate_runtime_records_impl(tenant_id, associated_by_subject, association)
+            .await
+    }
+
+    async fn disassociate_runtime_records(
+        &self,
+        tenant_id: TenantId,
+        association: &RuntimeRecordAssociation,
+    ) -> AppResult<bool> {
+        self.disassociate_runtime_records_impl(tenant_id, association)
+            .await
+    }
+
+    async fn list_associated_runtime_record_ids(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<String>> {
+        self.list_associated_runtime_record_ids_impl(
+            tenant_id,
+            entity_logical_name,
+            field_logical_name,
+            record_id,
+        )
+        .await
+    }
 }
 
 #[cfg(test)]
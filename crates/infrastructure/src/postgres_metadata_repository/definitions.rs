@@ -1,5 +1,24 @@
 use super::*;
 
+const ENTITY_SCOPED_TABLES_WITHOUT_CASCADE: &[&str] = &[
+    "entity_business_rules",
+    "entity_slug_configs",
+    "entity_status_models",
+    "entity_history_policies",
+    "entity_duplicate_rules",
+    "runtime_relation_behaviors",
+    "runtime_relation_lookups",
+    "runtime_subject_field_permissions",
+    "runtime_dual_control_fields",
+    "runtime_pending_field_changes",
+    "runtime_record_status_history",
+    "runtime_record_field_history",
+    "runtime_record_associations",
+    "app_personal_views",
+    "record_import_schedules",
+    "record_import_templates",
+];
+
 impl PostgresMetadataRepository {
     pub(super) async fn save_entity_impl(
         &self,
@@ -15,9 +34,11 @@ impl PostgresMetadataRepository {
                 display_name,
                 description,
                 plural_display_name,
-                icon
+                icon,
+                color,
+                is_active
             )
-            VALUES ($1, $2, $3, $4, $5, $6)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
             "#,
         )
         .bind(tenant_id.as_uuid())
@@ -26,6 +47,8 @@ impl PostgresMetadataRepository {
         .bind(entity.description())
         .bind(entity.plural_display_name().map(|value| value.as_str()))
         .bind(entity.icon())
+        .bind(entity.color())
+        .bind(entity.is_active())
         .execute(&mut *transaction)
         .await;
 
@@ -63,7 +86,7 @@ impl PostgresMetadataRepository {
         let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
         let rows = sqlx::query_as::<_, EntityRow>(
             r#"
-            SELECT logical_name, display_name, description, plural_display_name, icon
+            SELECT logical_name, display_name, description, plural_display_name, icon, color, is_active
             FROM entity_definitions
             WHERE tenant_id = $1
             ORDER BY logical_name
@@ -89,7 +112,9 @@ impl PostgresMetadataRepository {
                     row.description,
                     row.plural_display_name,
                     row.icon,
+                    row.color,
                 )
+                .map(|entity| entity.with_active(row.is_active))
                 .map_err(|error| {
                     AppError::Internal(format!(
                         "persisted entity definition is invalid for tenant '{}': {error}",
@@ -108,7 +133,7 @@ impl PostgresMetadataRepository {
         let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
         let row = sqlx::query_as::<_, EntityRow>(
             r#"
-            SELECT logical_name, display_name, description, plural_display_name, icon
+            SELECT logical_name, display_name, description, plural_display_name, icon, color, is_active
             FROM entity_definitions
             WHERE tenant_id = $1 AND logical_name = $2
             "#,
@@ -136,7 +161,9 @@ impl PostgresMetadataRepository {
                 row.description,
                 row.plural_display_name,
                 row.icon,
+                row.color,
             )
+            .map(|entity| entity.with_active(row.is_active))
         })
         .transpose()
     }
@@ -153,7 +180,9 @@ impl PostgresMetadataRepository {
             SET display_name = $3,
                 description = $4,
                 plural_display_name = $5,
-                icon = $6
+                icon = $6,
+                color = $7,
+                is_active = $8
             WHERE tenant_id = $1 AND logical_name = $2
             "#,
         )
@@ -163,6 +192,8 @@ impl PostgresMetadataRepository {
         .bind(entity.description())
         .bind(entity.plural_display_name().map(|value| value.as_str()))
         .bind(entity.icon())
+        .bind(entity.color())
+        .bind(entity.is_active())
         .execute(&mut *transaction)
         .await
         .map_err(|error| {
@@ -187,6 +218,65 @@ impl PostgresMetadataRepository {
         Ok(())
     }
 
+    pub(super) async fn delete_entity_impl(
+        &self,
+        tenant_id: TenantId,
+        logical_name: &str,
+    ) -> AppResult<()> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        // Entity-scoped tables without a foreign key to `entity_definitions`;
+        // everything else (fields, forms, views, records) cascades.
+        for table in ENTITY_SCOPED_TABLES_WITHOUT_CASCADE {
+            sqlx::query(&format!(
+                "DELETE FROM {table} WHERE tenant_id = $1 AND entity_logical_name = $2"
+            ))
+            .bind(tenant_id.as_uuid())
+            .bind(logical_name)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to delete '{table}' rows of entity '{}' for tenant '{}': {error}",
+                    logical_name, tenant_id
+                ))
+            })?;
+        }
+
+        let rows_affected = sqlx::query(
+            r#"
+            DELETE FROM entity_definitions
+            WHERE tenant_id = $1 AND logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(logical_name)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete entity definition '{}' for tenant '{}': {error}",
+                logical_name, tenant_id
+            ))
+        })?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(AppError::NotFound(format!(
+                "entity '{}' does not exist for tenant '{}'",
+                logical_name, tenant_id
+            )));
+        }
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped entity delete transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+
     pub(super) async fn save_field_impl(
         &self,
         tenant_id: TenantId,
@@ -211,9 +301,10 @@ impl PostgresMetadataRepository {
                 max_length,
                 min_value,
                 max_value,
+                classification,
                 updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, now())
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, now())
             ON CONFLICT (tenant_id, entity_logical_name, logical_name)
             DO UPDATE SET
                 display_name = EXCLUDED.display_name,
@@ -228,6 +319,7 @@ impl PostgresMetadataRepository {
                 max_length = EXCLUDED.max_length,
                 min_value = EXCLUDED.min_value,
                 max_value = EXCLUDED.max_value,
+                classification = EXCLUDED.classification,
                 updated_at = now()
             "#,
         )
@@ -246,6 +338,7 @@ impl PostgresMetadataRepository {
         .bind(field.max_length())
         .bind(field.min_value())
         .bind(field.max_value())
+        .bind(field.classification().map(|value| value.as_str()))
         .execute(&mut *transaction)
         .await
         .map_err(|error| {
@@ -288,7 +381,8 @@ impl PostgresMetadataRepository {
                 calculation_expression,
                 max_length,
                 min_value,
-                max_value
+                max_value,
+                classification
             FROM entity_fields
             WHERE tenant_id = $1 AND entity_logical_name = $2
             ORDER BY logical_name
@@ -311,25 +405,7 @@ impl PostgresMetadataRepository {
         })?;
 
         rows.into_iter()
-            .map(|row| {
-                let field_type = FieldType::from_str(row.field_type.as_str())?;
-                EntityFieldDefinition::new_with_details_and_calculation(
-                    row.entity_logical_name,
-                    row.logical_name,
-                    row.display_name,
-                    field_type,
-                    row.is_required,
-                    row.is_unique,
-                    row.default_value,
-                    row.relation_target_entity,
-                    row.option_set_logical_name,
-                    row.description,
-                    row.calculation_expression,
-                    row.max_length,
-                    row.min_value,
-                    row.max_value,
-                )
-            })
+            .map(FieldRow::into_field_definition)
             .collect()
     }
 
@@ -356,7 +432,8 @@ impl PostgresMetadataRepository {
                 calculation_expression,
                 max_length,
                 min_value,
-                max_value
+                max_value,
+                classification
             FROM entity_fields
             WHERE tenant_id = $1 AND entity_logical_name = $2 AND logical_name = $3
             "#,
@@ -378,26 +455,7 @@ impl PostgresMetadataRepository {
             ))
         })?;
 
-        row.map(|row| {
-            let field_type = FieldType::from_str(row.field_type.as_str())?;
-            EntityFieldDefinition::new_with_details_and_calculation(
-                row.entity_logical_name,
-                row.logical_name,
-                row.display_name,
-                field_type,
-                row.is_required,
-                row.is_unique,
-                row.default_value,
-                row.relation_target_entity,
-                row.option_set_logical_name,
-                row.description,
-                row.calculation_expression,
-                row.max_length,
-                row.min_value,
-                row.max_value,
-            )
-        })
-        .transpose()
+        row.map(FieldRow::into_field_definition).transpose()
     }
 
     pub(super) async fn delete_field_impl(
@@ -385,4 +385,298 @@ impl PostgresMetadataRepository {
             })
             .collect()
     }
+
+    pub(super) async fn find_published_schema_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchema>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let schema = load_published_schema_version(
+            &mut transaction,
+            tenant_id,
+            entity_logical_name,
+            version,
+        )
+        .await?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit published schema lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(schema)
+    }
+
+    pub(super) async fn list_published_schema_versions_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<PublishedEntitySchemaVersion>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, PublishedSchemaVersionRow>(
+            r#"
+            SELECT version, schema_json, published_by_subject, published_at
+            FROM entity_published_versions
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            ORDER BY version DESC
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list published schema versions for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit published schema history transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(|row| published_schema_version_from_row(row, tenant_id, entity_logical_name))
+            .collect()
+    }
+
+    pub(super) async fn find_published_schema_version_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+    ) -> AppResult<Option<PublishedEntitySchemaVersion>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, PublishedSchemaVersionRow>(
+            r#"
+            SELECT version, schema_json, published_by_subject, published_at
+            FROM entity_published_versions
+            WHERE tenant_id = $1 AND entity_logical_name = $2 AND version = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(version)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to load published schema version {} for entity '{}' in tenant '{}': {error}",
+                version, entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit published schema lookup transaction: {error}"
+            ))
+        })?;
+
+        row.map(|row| published_schema_version_from_row(row, tenant_id, entity_logical_name))
+            .transpose()
+    }
+
+    pub(super) async fn rollback_entity_schema_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        version: i32,
+        published_by: &str,
+    ) -> AppResult<PublishedEntitySchema> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let target = load_published_schema_version(
+            &mut transaction,
+            tenant_id,
+            entity_logical_name,
+            version,
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "published schema version {} does not exist for entity '{}' in tenant '{}'",
+                version, entity_logical_name, tenant_id
+            ))
+        })?;
+
+        let next_version: i32 = sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(MAX(version), 0) + 1
+            FROM entity_published_versions
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to compute next published schema version for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        let schema = PublishedEntitySchema::new(
+            target.entity().clone(),
+            next_version,
+            target.fields().to_vec(),
+            target.option_sets().to_vec(),
+        )?;
+        let schema_json = serde_json::to_value(&schema).map_err(|error| {
+            AppError::Internal(format!(
+                "failed to serialize published schema for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO entity_published_versions (
+                tenant_id,
+                entity_logical_name,
+                version,
+                schema_json,
+                published_by_subject
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(next_version)
+        .bind(schema_json)
+        .bind(published_by)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to persist rolled back schema for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        for snapshot_table in [
+            "entity_form_published_versions",
+            "entity_view_published_versions",
+        ] {
+            sqlx::query(&format!(
+                r#"
+                INSERT INTO {snapshot_table} (
+                    tenant_id,
+                    entity_logical_name,
+                    published_schema_version,
+                    logical_name,
+                    definition_json
+                )
+                SELECT tenant_id, entity_logical_name, $3, logical_name, definition_json
+                FROM {snapshot_table}
+                WHERE tenant_id = $1
+                  AND entity_logical_name = $2
+                  AND published_schema_version = $4
+                "#
+            ))
+            .bind(tenant_id.as_uuid())
+            .bind(entity_logical_name)
+            .bind(next_version)
+            .bind(version)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to copy {snapshot_table} for entity '{}' version {} in tenant '{}': {error}",
+                    entity_logical_name, version, tenant_id
+                ))
+            })?;
+        }
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit schema rollback transaction for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        Ok(schema)
+    }
+}
+
+async fn load_published_schema_version(
+    transaction: &mut sqlx::Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    entity_logical_name: &str,
+    version: i32,
+) -> AppResult<Option<PublishedEntitySchema>> {
+    let row = sqlx::query_as::<_, PublishedSchemaRow>(
+        r#"
+        SELECT version, schema_json
+        FROM entity_published_versions
+        WHERE tenant_id = $1 AND entity_logical_name = $2 AND version = $3
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(entity_logical_name)
+    .bind(version)
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!(
+            "failed to load published schema version {} for entity '{}' in tenant '{}': {error}",
+            version, entity_logical_name, tenant_id
+        ))
+    })?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+
+    let schema: PublishedEntitySchema =
+        serde_json::from_value(row.schema_json).map_err(|error| {
+            AppError::Internal(format!(
+                "persisted published schema is invalid for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+    if schema.version() != row.version {
+        return Err(AppError::Internal(format!(
+            "persisted published schema version mismatch for entity '{}' in tenant '{}'",
+            entity_logical_name, tenant_id
+        )));
+    }
+
+    Ok(Some(schema))
+}
+
+fn published_schema_version_from_row(
+    row: PublishedSchemaVersionRow,
+    tenant_id: TenantId,
+    entity_logical_name: &str,
+) -> AppResult<PublishedEntitySchemaVersion> {
+    let schema: PublishedEntitySchema =
+        serde_json::from_value(row.schema_json).map_err(|error| {
+            AppError::Internal(format!(
+                "persisted published schema is invalid for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+    if schema.version() != row.version {
+        return Err(AppError::Internal(format!(
+            "persisted published schema version mismatch for entity '{}' in tenant '{}'",
+            entity_logical_name, tenant_id
+        )));
+    }
+
+    Ok(PublishedEntitySchemaVersion {
+        schema,
+        published_by_subject: row.published_by_subject,
+        published_at: row.published_at,
+    })
 }
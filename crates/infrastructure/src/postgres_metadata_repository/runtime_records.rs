@@ -1,14 +1,24 @@
 use super::*;
+use crate::postgres_audit_repository::enqueue_audit_outbox_event;
 use std::sync::OnceLock;
 use std::time::Instant;
-use tracing::warn;
+use tracing::{info, warn};
 
+mod aggregate;
+mod associations;
+mod duplicates;
+mod history;
+mod lookups;
 mod query;
 mod read;
 mod relations;
+mod slugs;
+mod status;
 mod workflow_events;
 mod write;
 
+use relations::cascade_runtime_record_owner;
+
 fn runtime_slow_query_threshold_ms() -> u64 {
     static SLOW_QUERY_THRESHOLD_MS: OnceLock<u64> = OnceLock::new();
     *SLOW_QUERY_THRESHOLD_MS.get_or_init(|| {
@@ -48,6 +58,18 @@ fn parse_runtime_record_uuid(record_id: &str) -> AppResult<Uuid> {
 
 fn runtime_record_from_row(row: RuntimeRecordRow) -> AppResult<RuntimeRecord> {
     RuntimeRecord::new(row.id.to_string(), row.entity_logical_name, row.data)
+        .map(|record| record.with_version(row.version))
+}
+
+fn runtime_record_version_conflict(
+    record_id: &str,
+    expected_version: i64,
+    current_version: i64,
+) -> AppError {
+    AppError::Conflict(format!(
+        "runtime record '{}' was modified concurrently: expected version {} but current version is {}",
+        record_id, expected_version, current_version
+    ))
 }
 
 async fn index_unique_values(
@@ -111,6 +133,22 @@ fn runtime_record_workflow_event_from_row(
         "runtime_record_deleted" => WorkflowTrigger::RuntimeRecordDeleted {
             entity_logical_name: row.entity_logical_name,
         },
+        "runtime_record_status_changed" => WorkflowTrigger::RuntimeRecordStatusChanged {
+            entity_logical_name: row.entity_logical_name,
+        },
+        "approval_event_received" => WorkflowTrigger::ApprovalEventReceived {
+            approval_key: row
+                .payload
+                .get("approval_key")
+                .and_then(Value::as_str)
+                .map(ToOwned::to_owned)
+                .ok_or_else(|| {
+                    AppError::Validation(format!(
+                        "approval workflow event '{}' is missing approval_key",
+                        row.id
+                    ))
+                })?,
+        },
         _ => {
             return Err(AppError::Validation(format!(
                 "unknown runtime record workflow trigger type '{}'",
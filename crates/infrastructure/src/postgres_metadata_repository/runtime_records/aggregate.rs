@@ -0,0 +1,267 @@
+use std::collections::BTreeMap;
+
+use qryvanta_application::{
+    RuntimeRecordAggregate, RuntimeRecordAggregateFunction, RuntimeRecordAggregateRow,
+    RuntimeRecordAggregateSortKey,
+};
+use sqlx::{QueryBuilder, Row};
+
+use super::query::{push_runtime_filter_condition, push_runtime_group_condition};
+use super::*;
+
+impl PostgresMetadataRepository {
+    pub(in super::super) async fn aggregate_runtime_records_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        query: RuntimeRecordAggregateQuery,
+    ) -> AppResult<Vec<RuntimeRecordAggregateRow>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let limit = i64::try_from(query.limit).map_err(|error| {
+            AppError::Validation(format!("invalid runtime aggregate query limit: {error}"))
+        })?;
+
+        let root_table_alias = "runtime_root";
+        let scope_table_aliases = BTreeMap::new();
+        let mut builder: QueryBuilder<'_, Postgres> = QueryBuilder::new("SELECT ");
+        for (index, group_by) in query.group_by.iter().enumerate() {
+            if index > 0 {
+                builder.push(", ");
+            }
+            // Missing fields and explicit JSON nulls fall into the same group.
+            builder.push("NULLIF(runtime_root.data -> ");
+            builder.push_bind(group_by.field_logical_name.clone());
+            builder.push(", 'null'::JSONB)");
+        }
+        for (index, aggregate) in query.aggregates.iter().enumerate() {
+            if index > 0 || !query.group_by.is_empty() {
+                builder.push(", ");
+            }
+            builder.push("to_jsonb(");
+            push_aggregate_expression(&mut builder, aggregate);
+            builder.push(')');
+        }
+
+        builder.push(" FROM runtime_records runtime_root WHERE runtime_root.tenant_id = ");
+        builder.push_bind(tenant_id.as_uuid());
+        builder.push(" AND runtime_root.entity_logical_name = ");
+        builder.push_bind(entity_logical_name);
+
+        if let Some(owner_subject) = &query.owner_subject {
+            builder.push(" AND runtime_root.created_by_subject = ");
+            builder.push_bind(owner_subject.clone());
+        }
+
+        if let Some(where_clause) = &query.where_clause {
+            builder.push(" AND ");
+            push_runtime_group_condition(
+                &mut builder,
+                where_clause,
+                &scope_table_aliases,
+                root_table_alias,
+            )?;
+        }
+
+        if !query.filters.is_empty() {
+            builder.push(" AND (");
+            for (index, filter) in query.filters.iter().enumerate() {
+                if index > 0 {
+                    match query.logical_mode {
+                        RuntimeRecordLogicalMode::And => builder.push(" AND "),
+                        RuntimeRecordLogicalMode::Or => builder.push(" OR "),
+                    };
+                }
+                push_runtime_filter_condition(&mut builder, filter, root_table_alias);
+            }
+            builder.push(')');
+        }
+
+        // Grouped and sorted columns are referenced by position because each
+        // bound field name is a distinct parameter to Postgres.
+        let group_positions = (1..=query.group_by.len()).collect::<Vec<_>>();
+        if !group_positions.is_empty() {
+            builder.push(" GROUP BY ");
+            push_positions(&mut builder, &group_positions);
+        }
+
+        for (index, having) in query.having.iter().enumerate() {
+            let Some(aggregate) = query
+                .aggregates
+                .iter()
+                .find(|aggregate| aggregate.alias == having.aggregate_alias)
+            else {
+                return Err(AppError::Validation(format!(
+                    "unknown runtime aggregate alias '{}' in having",
+                    having.aggregate_alias
+                )));
+            };
+            let operator = match having.operator {
+                RuntimeRecordOperator::Eq => "=",
+                RuntimeRecordOperator::Neq => "<>",
+                RuntimeRecordOperator::Gt => ">",
+                RuntimeRecordOperator::Gte => ">=",
+                RuntimeRecordOperator::Lt => "<",
+                RuntimeRecordOperator::Lte => "<=",
+                _ => {
+                    return Err(AppError::Validation(format!(
+                        "runtime aggregate having does not support operator '{}'",
+                        having.operator.as_str()
+                    )));
+                }
+            };
+
+            builder.push(if index == 0 { " HAVING (" } else { " AND (" });
+            push_aggregate_expression(&mut builder, aggregate);
+            builder.push(")::NUMERIC ");
+            builder.push(operator);
+            builder.push(" (");
+            builder.push_bind(having.value.to_string());
+            builder.push(")::NUMERIC");
+        }
+
+        builder.push(" ORDER BY ");
+        let mut sorted_positions = Vec::new();
+        for sort in &query.sort {
+            let position = match &sort.key {
+                RuntimeRecordAggregateSortKey::GroupField(field_logical_name) => query
+                    .group_by
+                    .iter()
+                    .position(|group_by| &group_by.field_logical_name == field_logical_name)
+                    .map(|index| index + 1),
+                RuntimeRecordAggregateSortKey::Aggregate(alias) => query
+                    .aggregates
+                    .iter()
+                    .position(|aggregate| &aggregate.alias == alias)
+                    .map(|index| query.group_by.len() + index + 1),
+            }
+            .ok_or_else(|| AppError::Validation("unknown runtime aggregate sort key".to_owned()))?;
+
+            if !sorted_positions.is_empty() {
+                builder.push(", ");
+            }
+            builder.push(position.to_string());
+            match sort.direction {
+                RuntimeRecordSortDirection::Asc => builder.push(" ASC"),
+                RuntimeRecordSortDirection::Desc => builder.push(" DESC"),
+            };
+            sorted_positions.push(position);
+        }
+        let remaining_group_positions = group_positions
+            .into_iter()
+            .filter(|position| !sorted_positions.contains(position))
+            .collect::<Vec<_>>();
+        if !remaining_group_positions.is_empty() {
+            if !sorted_positions.is_empty() {
+                builder.push(", ");
+            }
+            push_positions(&mut builder, &remaining_group_positions);
+        } else if sorted_positions.is_empty() {
+            builder.push("1");
+        }
+
+        builder.push(" LIMIT ");
+        builder.push_bind(limit);
+
+        let started_at = std::time::Instant::now();
+        let rows_result = builder.build().fetch_all(&mut *transaction).await;
+
+        warn_if_runtime_query_slow(
+            "runtime_records.aggregate",
+            tenant_id,
+            entity_logical_name,
+            started_at,
+        );
+
+        let rows = rows_result.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to aggregate runtime records for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit runtime record aggregate transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(|row| {
+                let column = |index: usize| {
+                    row.try_get::<Option<Value>, _>(index)
+                        .map(Option::unwrap_or_default)
+                        .map_err(|error| {
+                            AppError::Internal(format!(
+                                "failed to decode runtime aggregate column {index}: {error}"
+                            ))
+                        })
+                };
+
+                let mut group = BTreeMap::new();
+                for (index, group_by) in query.group_by.iter().enumerate() {
+                    group.insert(group_by.field_logical_name.clone(), column(index)?);
+                }
+                let mut values = BTreeMap::new();
+                for (index, aggregate) in query.aggregates.iter().enumerate() {
+                    values.insert(
+                        aggregate.alias.clone(),
+                        column(query.group_by.len() + index)?,
+                    );
+                }
+
+                Ok(RuntimeRecordAggregateRow { group, values })
+            })
+            .collect()
+    }
+}
+
+fn push_positions(builder: &mut QueryBuilder<'_, Postgres>, positions: &[usize]) {
+    for (index, position) in positions.iter().enumerate() {
+        if index > 0 {
+            builder.push(", ");
+        }
+        builder.push(position.to_string());
+    }
+}
+
+fn push_aggregate_expression(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    aggregate: &RuntimeRecordAggregate,
+) {
+    let Some(field_logical_name) = aggregate.field_logical_name.clone() else {
+        builder.push("COUNT(*)");
+        return;
+    };
+
+    match aggregate.function {
+        RuntimeRecordAggregateFunction::Count => {
+            builder.push("COUNT(NULLIF(runtime_root.data -> ");
+            builder.push_bind(field_logical_name);
+            builder.push(", 'null'::JSONB))");
+        }
+        RuntimeRecordAggregateFunction::Sum => {
+            builder.push("SUM((runtime_root.data ->> ");
+            builder.push_bind(field_logical_name);
+            builder.push(")::NUMERIC)");
+        }
+        RuntimeRecordAggregateFunction::Avg => {
+            builder.push("AVG((runtime_root.data ->> ");
+            builder.push_bind(field_logical_name);
+            builder.push(")::NUMERIC)::DOUBLE PRECISION");
+        }
+        RuntimeRecordAggregateFunction::Min | RuntimeRecordAggregateFunction::Max => {
+            builder.push(match aggregate.function {
+                RuntimeRecordAggregateFunction::Min => "MIN(",
+                _ => "MAX(",
+            });
+            // Number fields compare numerically; other types compare by their text.
+            let numeric = aggregate.field_type == Some(FieldType::Number);
+            builder.push(if numeric {
+                "(runtime_root.data ->> "
+            } else {
+                "runtime_root.data ->> "
+            });
+            builder.push_bind(field_logical_name);
+            builder.push(if numeric { ")::NUMERIC)" } else { ")" });
+        }
+    }
+}
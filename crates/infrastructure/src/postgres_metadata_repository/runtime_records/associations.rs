@@ -0,0 +1,147 @@
+use super::*;
+
+impl PostgresMetadataRepository {
+    pub(in super::super) async fn associate_runtime_records_impl(
+        &self,
+        tenant_id: TenantId,
+        associated_by_subject: &str,
+        association: RuntimeRecordAssociation,
+    ) -> AppResult<bool> {
+        let record_uuid = parse_runtime_record_uuid(association.record_id.as_str())?;
+        let target_record_uuid = parse_runtime_record_uuid(association.target_record_id.as_str())?;
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let inserted = sqlx::query(
+            r#"
+            INSERT INTO runtime_record_associations (
+                tenant_id,
+                entity_logical_name,
+                field_logical_name,
+                record_id,
+                target_entity_logical_name,
+                target_record_id,
+                associated_by_subject
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (tenant_id, entity_logical_name, field_logical_name, record_id, target_record_id)
+            DO NOTHING
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(association.entity_logical_name.as_str())
+        .bind(association.field_logical_name.as_str())
+        .bind(record_uuid)
+        .bind(association.target_entity_logical_name.as_str())
+        .bind(target_record_uuid)
+        .bind(associated_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to associate runtime record '{}' through '{}.{}' in tenant '{}': {error}",
+                association.record_id,
+                association.entity_logical_name,
+                association.field_logical_name,
+                tenant_id
+            ))
+        })?
+        .rows_affected();
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit runtime record association transaction: {error}"
+            ))
+        })?;
+
+        Ok(inserted > 0)
+    }
+
+    pub(in super::super) async fn disassociate_runtime_records_impl(
+        &self,
+        tenant_id: TenantId,
+        association: &RuntimeRecordAssociation,
+    ) -> AppResult<bool> {
+        let record_uuid = parse_runtime_record_uuid(association.record_id.as_str())?;
+        let target_record_uuid = parse_runtime_record_uuid(association.target_record_id.as_str())?;
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let deleted = sqlx::query(
+            r#"
+            DELETE FROM runtime_record_associations
+            WHERE tenant_id = $1
+              AND entity_logical_name = $2
+              AND field_logical_name = $3
+              AND record_id = $4
+              AND target_record_id = $5
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(association.entity_logical_name.as_str())
+        .bind(association.field_logical_name.as_str())
+        .bind(record_uuid)
+        .bind(target_record_uuid)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to disassociate runtime record '{}' through '{}.{}' in tenant '{}': {error}",
+                association.record_id,
+                association.entity_logical_name,
+                association.field_logical_name,
+                tenant_id
+            ))
+        })?
+        .rows_affected();
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit runtime record disassociation transaction: {error}"
+            ))
+        })?;
+
+        Ok(deleted > 0)
+    }
+
+    pub(in super::super) async fn list_associated_runtime_record_ids_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<String>> {
+        let record_uuid = parse_runtime_record_uuid(record_id)?;
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let target_record_ids = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT target_record_id
+            FROM runtime_record_associations
+            WHERE tenant_id = $1
+              AND entity_logical_name = $2
+              AND field_logical_name = $3
+              AND record_id = $4
+            ORDER BY associated_at, target_record_id
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(field_logical_name)
+        .bind(record_uuid)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list associations of runtime record '{}' through '{}.{}' in tenant '{}': {error}",
+                record_id, entity_logical_name, field_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit runtime record association list transaction: {error}"
+            ))
+        })?;
+
+        Ok(target_record_ids
+            .into_iter()
+            .map(|target_record_id| target_record_id.to_string())
+            .collect())
+    }
+}
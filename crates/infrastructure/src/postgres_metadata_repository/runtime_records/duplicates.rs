@@ -0,0 +1,173 @@
+use qryvanta_domain::{DuplicateDetectionRule, DuplicateMatchField, DuplicateRuleAction};
+
+use super::*;
+
+#[derive(Debug, FromRow)]
+struct DuplicateRuleRow {
+    entity_logical_name: String,
+    logical_name: String,
+    display_name: String,
+    match_fields: Value,
+    action: String,
+    is_active: bool,
+}
+
+impl PostgresMetadataRepository {
+    pub(in super::super) async fn save_duplicate_detection_rule_impl(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        rule: DuplicateDetectionRule,
+    ) -> AppResult<()> {
+        let match_fields = serde_json::to_value(rule.match_fields()).map_err(|error| {
+            AppError::Internal(format!(
+                "failed to serialize duplicate rule match fields: {error}"
+            ))
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO entity_duplicate_rules (
+                tenant_id,
+                entity_logical_name,
+                logical_name,
+                display_name,
+                match_fields,
+                action,
+                is_active,
+                updated_by_subject,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, now())
+            ON CONFLICT (tenant_id, entity_logical_name, logical_name)
+            DO UPDATE SET
+                display_name = EXCLUDED.display_name,
+                match_fields = EXCLUDED.match_fields,
+                action = EXCLUDED.action,
+                is_active = EXCLUDED.is_active,
+                updated_by_subject = EXCLUDED.updated_by_subject,
+                updated_at = now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(rule.entity_logical_name())
+        .bind(rule.logical_name())
+        .bind(rule.display_name())
+        .bind(match_fields)
+        .bind(rule.action().as_str())
+        .bind(rule.is_active())
+        .bind(updated_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to save duplicate rule '{}' for entity '{}' in tenant '{}': {error}",
+                rule.logical_name(),
+                rule.entity_logical_name(),
+                tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit duplicate rule transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn list_duplicate_detection_rules_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<DuplicateDetectionRule>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, DuplicateRuleRow>(
+            r#"
+            SELECT
+                entity_logical_name,
+                logical_name,
+                display_name,
+                match_fields,
+                action,
+                is_active
+            FROM entity_duplicate_rules
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            ORDER BY logical_name
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list duplicate rules for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit duplicate rule list transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter().map(duplicate_rule_from_row).collect()
+    }
+
+    pub(in super::super) async fn delete_duplicate_detection_rule_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        logical_name: &str,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM entity_duplicate_rules
+            WHERE tenant_id = $1 AND entity_logical_name = $2 AND logical_name = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(logical_name)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete duplicate rule '{}' for entity '{}' in tenant '{}': {error}",
+                logical_name, entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit duplicate rule delete transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+fn duplicate_rule_from_row(row: DuplicateRuleRow) -> AppResult<DuplicateDetectionRule> {
+    let match_fields = serde_json::from_value::<Vec<DuplicateMatchField>>(row.match_fields)
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to decode match fields of duplicate rule '{}': {error}",
+                row.logical_name
+            ))
+        })?;
+
+    DuplicateDetectionRule::new(
+        row.entity_logical_name,
+        row.logical_name,
+        row.display_name,
+        match_fields,
+        DuplicateRuleAction::from_str(row.action.as_str())?,
+        row.is_active,
+    )
+}
@@ -0,0 +1,373 @@
+use qryvanta_application::RuntimeRecordFieldChange;
+
+use super::*;
+
+#[derive(Debug, FromRow)]
+struct EntityHistoryPolicyRow {
+    entity_logical_name: String,
+    retention_days: i32,
+}
+
+#[derive(Debug, FromRow)]
+struct RuntimeRecordHistoryEntryRow {
+    id: Uuid,
+    entity_logical_name: String,
+    record_id: String,
+    record_version: i64,
+    changes: Value,
+    changed_by_subject: String,
+    changed_at: DateTime<Utc>,
+}
+
+impl PostgresMetadataRepository {
+    pub(in super::super) async fn save_entity_history_policy_impl(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        policy: EntityHistoryPolicy,
+    ) -> AppResult<()> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO entity_history_policies (
+                tenant_id,
+                entity_logical_name,
+                retention_days,
+                updated_by_subject,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, now())
+            ON CONFLICT (tenant_id, entity_logical_name)
+            DO UPDATE SET
+                retention_days = EXCLUDED.retention_days,
+                updated_by_subject = EXCLUDED.updated_by_subject,
+                updated_at = now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(policy.entity_logical_name.as_str())
+        .bind(i32::from(policy.retention_days))
+        .bind(updated_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to save record history policy for entity '{}' in tenant '{}': {error}",
+                policy.entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit record history policy transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn find_entity_history_policy_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityHistoryPolicy>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, EntityHistoryPolicyRow>(
+            r#"
+            SELECT entity_logical_name, retention_days
+            FROM entity_history_policies
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find record history policy for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit record history policy lookup transaction: {error}"
+            ))
+        })?;
+
+        row.map(entity_history_policy_from_row).transpose()
+    }
+
+    pub(in super::super) async fn delete_entity_history_policy_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM entity_history_policies
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete record history policy for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit record history policy delete transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub(in super::super) async fn append_runtime_record_history_entry_impl(
+        &self,
+        tenant_id: TenantId,
+        entry: NewRuntimeRecordHistoryEntry,
+        retention_cutoff: Option<DateTime<Utc>>,
+    ) -> AppResult<RuntimeRecordHistoryEntry> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        if let Some(cutoff) = retention_cutoff {
+            sqlx::query(
+                r#"
+                DELETE FROM runtime_record_field_history
+                WHERE tenant_id = $1
+                  AND entity_logical_name = $2
+                  AND record_id = $3
+                  AND changed_at < $4
+                "#,
+            )
+            .bind(tenant_id.as_uuid())
+            .bind(entry.entity_logical_name.as_str())
+            .bind(entry.record_id.as_str())
+            .bind(cutoff)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to purge expired history for entity '{}' record '{}' in tenant '{}': {error}",
+                    entry.entity_logical_name, entry.record_id, tenant_id
+                ))
+            })?;
+        }
+
+        let row = sqlx::query_as::<_, RuntimeRecordHistoryEntryRow>(
+            r#"
+            INSERT INTO runtime_record_field_history (
+                id,
+                tenant_id,
+                entity_logical_name,
+                record_id,
+                record_version,
+                changes,
+                changed_by_subject,
+                changed_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, now())
+            RETURNING
+                id,
+                entity_logical_name,
+                record_id,
+                record_version,
+                changes,
+                changed_by_subject,
+                changed_at
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(tenant_id.as_uuid())
+        .bind(entry.entity_logical_name.as_str())
+        .bind(entry.record_id.as_str())
+        .bind(entry.record_version)
+        .bind(field_changes_to_value(&entry.changes))
+        .bind(entry.changed_by_subject.as_str())
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to record history for entity '{}' record '{}' in tenant '{}': {error}",
+                entry.entity_logical_name, entry.record_id, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit record history transaction: {error}"
+            ))
+        })?;
+
+        runtime_record_history_entry_from_row(row)
+    }
+
+    pub(in super::super) async fn list_runtime_record_history_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<RuntimeRecordHistoryEntry>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, RuntimeRecordHistoryEntryRow>(
+            r#"
+            SELECT
+                id,
+                entity_logical_name,
+                record_id,
+                record_version,
+                changes,
+                changed_by_subject,
+                changed_at
+            FROM runtime_record_field_history
+            WHERE tenant_id = $1
+              AND entity_logical_name = $2
+              AND record_id = $3
+              AND ($4::TIMESTAMPTZ IS NULL OR changed_at >= $4)
+            ORDER BY changed_at, record_version, id
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(record_id)
+        .bind(since)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list history for entity '{}' record '{}' in tenant '{}': {error}",
+                entity_logical_name, record_id, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit record history lookup transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(runtime_record_history_entry_from_row)
+            .collect()
+    }
+
+    pub(in super::super) async fn purge_runtime_record_history_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        cutoff: DateTime<Utc>,
+    ) -> AppResult<u64> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM runtime_record_field_history
+            WHERE tenant_id = $1 AND entity_logical_name = $2 AND changed_at < $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(cutoff)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to purge record history for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit record history purge transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+fn entity_history_policy_from_row(row: EntityHistoryPolicyRow) -> AppResult<EntityHistoryPolicy> {
+    let retention_days = u16::try_from(row.retention_days).map_err(|error| {
+        AppError::Internal(format!(
+            "invalid record history retention for entity '{}': {error}",
+            row.entity_logical_name
+        ))
+    })?;
+
+    Ok(EntityHistoryPolicy {
+        entity_logical_name: row.entity_logical_name,
+        retention_days,
+    })
+}
+
+/// Stores absent values as missing keys so they stay distinct from JSON `null`.
+fn field_changes_to_value(changes: &[RuntimeRecordFieldChange]) -> Value {
+    Value::Array(
+        changes
+            .iter()
+            .map(|change| {
+                let mut object = serde_json::Map::new();
+                object.insert(
+                    "field_logical_name".to_owned(),
+                    Value::String(change.field_logical_name.clone()),
+                );
+                if let Some(old_value) = &change.old_value {
+                    object.insert("old_value".to_owned(), old_value.clone());
+                }
+                if let Some(new_value) = &change.new_value {
+                    object.insert("new_value".to_owned(), new_value.clone());
+                }
+                Value::Object(object)
+            })
+            .collect(),
+    )
+}
+
+fn runtime_record_history_entry_from_row(
+    row: RuntimeRecordHistoryEntryRow,
+) -> AppResult<RuntimeRecordHistoryEntry> {
+    let items = row.changes.as_array().ok_or_else(|| {
+        AppError::Internal(format!(
+            "record history entry '{}' does not store a change array",
+            row.id
+        ))
+    })?;
+    let changes = items
+        .iter()
+        .map(|item| {
+            let field_logical_name = item
+                .get("field_logical_name")
+                .and_then(Value::as_str)
+                .ok_or_else(|| {
+                    AppError::Internal(format!(
+                        "record history entry '{}' stores a change without a field",
+                        row.id
+                    ))
+                })?;
+            Ok(RuntimeRecordFieldChange {
+                field_logical_name: field_logical_name.to_owned(),
+                old_value: item.get("old_value").cloned(),
+                new_value: item.get("new_value").cloned(),
+            })
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+
+    Ok(RuntimeRecordHistoryEntry {
+        entry_id: row.id.to_string(),
+        entity_logical_name: row.entity_logical_name,
+        record_id: row.record_id,
+        record_version: row.record_version,
+        changes,
+        changed_by_subject: row.changed_by_subject,
+        changed_at: row.changed_at,
+    })
+}
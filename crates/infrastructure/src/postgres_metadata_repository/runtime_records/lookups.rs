@@ -0,0 +1,189 @@
+use qryvanta_domain::ViewFilterGroup;
+
+use super::*;
+
+#[derive(Debug, FromRow)]
+struct RelationLookupConfigRow {
+    entity_logical_name: String,
+    field_logical_name: String,
+    target_entity_logical_name: String,
+    search_field_logical_names: Value,
+    filter_criteria: Option<Value>,
+}
+
+impl PostgresMetadataRepository {
+    pub(in super::super) async fn save_relation_lookup_config_impl(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        config: RelationLookupConfig,
+    ) -> AppResult<()> {
+        let search_field_logical_names = serde_json::to_value(&config.search_field_logical_names)
+            .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to serialize relation lookup search fields: {error}"
+            ))
+        })?;
+        let filter_criteria = config
+            .filter
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to serialize relation lookup filter: {error}"
+                ))
+            })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO runtime_relation_lookups (
+                tenant_id,
+                entity_logical_name,
+                field_logical_name,
+                target_entity_logical_name,
+                search_field_logical_names,
+                filter_criteria,
+                updated_by_subject,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, now())
+            ON CONFLICT (tenant_id, entity_logical_name, field_logical_name)
+            DO UPDATE SET
+                target_entity_logical_name = EXCLUDED.target_entity_logical_name,
+                search_field_logical_names = EXCLUDED.search_field_logical_names,
+                filter_criteria = EXCLUDED.filter_criteria,
+                updated_by_subject = EXCLUDED.updated_by_subject,
+                updated_at = now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(config.entity_logical_name.as_str())
+        .bind(config.field_logical_name.as_str())
+        .bind(config.target_entity_logical_name.as_str())
+        .bind(search_field_logical_names)
+        .bind(filter_criteria)
+        .bind(updated_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to save relation lookup for '{}.{}' in tenant '{}': {error}",
+                config.entity_logical_name, config.field_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit relation lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn find_relation_lookup_config_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<Option<RelationLookupConfig>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RelationLookupConfigRow>(
+            r#"
+            SELECT
+                entity_logical_name,
+                field_logical_name,
+                target_entity_logical_name,
+                search_field_logical_names,
+                filter_criteria
+            FROM runtime_relation_lookups
+            WHERE tenant_id = $1 AND entity_logical_name = $2 AND field_logical_name = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(field_logical_name)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find relation lookup for '{}.{}' in tenant '{}': {error}",
+                entity_logical_name, field_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit relation lookup transaction: {error}"
+            ))
+        })?;
+
+        row.map(relation_lookup_config_from_row).transpose()
+    }
+
+    pub(in super::super) async fn delete_relation_lookup_config_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM runtime_relation_lookups
+            WHERE tenant_id = $1 AND entity_logical_name = $2 AND field_logical_name = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(field_logical_name)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete relation lookup for '{}.{}' in tenant '{}': {error}",
+                entity_logical_name, field_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit relation lookup delete transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+fn relation_lookup_config_from_row(
+    row: RelationLookupConfigRow,
+) -> AppResult<RelationLookupConfig> {
+    let search_field_logical_names =
+        serde_json::from_value::<Vec<String>>(row.search_field_logical_names).map_err(|error| {
+            AppError::Internal(format!(
+                "failed to decode relation lookup search fields for '{}.{}': {error}",
+                row.entity_logical_name, row.field_logical_name
+            ))
+        })?;
+    let filter = row
+        .filter_criteria
+        .map(serde_json::from_value::<ViewFilterGroup>)
+        .transpose()
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to decode relation lookup filter for '{}.{}': {error}",
+                row.entity_logical_name, row.field_logical_name
+            ))
+        })?;
+
+    Ok(RelationLookupConfig {
+        entity_logical_name: row.entity_logical_name,
+        field_logical_name: row.field_logical_name,
+        target_entity_logical_name: row.target_entity_logical_name,
+        search_field_logical_names,
+        filter,
+    })
+}
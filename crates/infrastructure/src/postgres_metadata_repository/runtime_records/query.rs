@@ -1,5 +1,6 @@
 use std::collections::BTreeMap;
 
+use chrono::Utc;
 use sqlx::{Postgres, QueryBuilder};
 
 use super::*;
@@ -22,41 +23,94 @@ impl PostgresMetadataRepository {
         let root_table_alias = "runtime_root";
         let mut scope_table_aliases = BTreeMap::new();
         let mut builder: QueryBuilder<'_, Postgres> = QueryBuilder::new(
-            "SELECT runtime_root.id, runtime_root.entity_logical_name, runtime_root.data FROM runtime_records runtime_root",
+            "SELECT runtime_root.id, runtime_root.entity_logical_name, runtime_root.data, runtime_root.version FROM runtime_records runtime_root",
         );
 
+        let mut scope_entities = BTreeMap::new();
         for (index, link) in query.links.iter().enumerate() {
             let table_alias = format!("runtime_link_{index}");
-            let parent_table_alias = link
-                .parent_alias
-                .as_deref()
-                .map(|alias| resolve_scope_alias(&scope_table_aliases, alias))
-                .transpose()?
-                .unwrap_or(root_table_alias);
+            let (parent_table_alias, parent_entity_logical_name) =
+                match link.parent_alias.as_deref() {
+                    Some(alias) => (
+                        resolve_scope_alias(&scope_table_aliases, alias)?,
+                        resolve_scope_alias(&scope_entities, alias)?,
+                    ),
+                    None => (root_table_alias, entity_logical_name),
+                };
 
             match link.join_type {
-                RuntimeRecordJoinType::Inner => builder.push(" JOIN runtime_records "),
-                RuntimeRecordJoinType::Left => builder.push(" LEFT JOIN runtime_records "),
+                RuntimeRecordJoinType::Inner => builder.push(" JOIN "),
+                RuntimeRecordJoinType::Left => builder.push(" LEFT JOIN "),
             };
-            builder.push(table_alias.as_str());
-            builder.push(" ON ");
-            builder.push(table_alias.as_str());
-            builder.push(".tenant_id = ");
-            builder.push(root_table_alias);
-            builder.push(".tenant_id AND ");
-            builder.push(table_alias.as_str());
-            builder.push(".entity_logical_name = ");
-            builder.push_bind(link.target_entity_logical_name.clone());
-            builder.push(" AND ");
-            builder.push(table_alias.as_str());
-            builder.push(".id::text = ");
-            builder.push(parent_table_alias);
-            builder.push(".data ->> ");
-            builder.push_bind(link.relation_field_logical_name.clone());
+
+            match link.cardinality {
+                RuntimeRecordLinkCardinality::ManyToOne => {
+                    builder.push("runtime_records ");
+                    builder.push(table_alias.as_str());
+                    builder.push(" ON ");
+                    builder.push(table_alias.as_str());
+                    builder.push(".tenant_id = ");
+                    builder.push(root_table_alias);
+                    builder.push(".tenant_id AND ");
+                    builder.push(table_alias.as_str());
+                    builder.push(".entity_logical_name = ");
+                    builder.push_bind(link.target_entity_logical_name.clone());
+                    builder.push(" AND ");
+                    builder.push(table_alias.as_str());
+                    builder.push(".id::text = ");
+                    builder.push(parent_table_alias);
+                    builder.push(".data ->> ");
+                    builder.push_bind(link.relation_field_logical_name.clone());
+                }
+                RuntimeRecordLinkCardinality::ManyToMany => {
+                    let association_alias = format!("{table_alias}_association");
+                    builder.push("(runtime_record_associations ");
+                    builder.push(association_alias.as_str());
+                    builder.push(" JOIN runtime_records ");
+                    builder.push(table_alias.as_str());
+                    builder.push(" ON ");
+                    builder.push(table_alias.as_str());
+                    builder.push(".tenant_id = ");
+                    builder.push(association_alias.as_str());
+                    builder.push(".tenant_id AND ");
+                    builder.push(table_alias.as_str());
+                    builder.push(".id = ");
+                    builder.push(association_alias.as_str());
+                    builder.push(".target_record_id AND ");
+                    builder.push(table_alias.as_str());
+                    builder.push(".entity_logical_name = ");
+                    builder.push_bind(link.target_entity_logical_name.clone());
+                    builder.push(") ON ");
+                    builder.push(association_alias.as_str());
+                    builder.push(".tenant_id = ");
+                    builder.push(root_table_alias);
+                    builder.push(".tenant_id AND ");
+                    builder.push(association_alias.as_str());
+                    builder.push(".entity_logical_name = ");
+                    builder.push_bind(parent_entity_logical_name.to_owned());
+                    builder.push(" AND ");
+                    builder.push(association_alias.as_str());
+                    builder.push(".field_logical_name = ");
+                    builder.push_bind(link.relation_field_logical_name.clone());
+                    builder.push(" AND ");
+                    builder.push(association_alias.as_str());
+                    builder.push(".record_id = ");
+                    builder.push(parent_table_alias);
+                    builder.push(".id");
+                }
+            }
 
             scope_table_aliases.insert(link.alias.clone(), table_alias);
+            scope_entities.insert(link.alias.clone(), link.target_entity_logical_name.clone());
         }
 
+        // Many-to-many links fan out into one row per association; grouping by
+        // the root primary key keeps each root record once.
+        let group_by_root = query
+            .links
+            .iter()
+            .any(|link| link.cardinality == RuntimeRecordLinkCardinality::ManyToMany);
+
         builder.push(" WHERE ");
         builder.push(root_table_alias);
         builder.push(".tenant_id = ");
@@ -105,6 +159,12 @@ impl PostgresMetadataRepository {
             builder.push(')');
         }
 
+        if group_by_root {
+            builder.push(" GROUP BY ");
+            builder.push(root_table_alias);
+            builder.push(".id");
+        }
+
         if query.sort.is_empty() {
             builder.push(" ORDER BY ");
             builder.push(root_table_alias);
@@ -121,7 +181,8 @@ impl PostgresMetadataRepository {
                     .map(|alias| resolve_scope_alias(&scope_table_aliases, alias))
                     .transpose()?
                     .unwrap_or(root_table_alias);
-                push_runtime_sort_clause(&mut builder, sort, scope_table_alias);
+                let aggregate_scope = group_by_root && scope_table_alias != root_table_alias;
+                push_runtime_sort_clause(&mut builder, sort, scope_table_alias, aggregate_scope);
             }
             builder.push(", ");
             builder.push(root_table_alias);
@@ -174,7 +235,7 @@ fn resolve_scope_alias<'a>(
         })
 }
 
-fn push_runtime_group_condition(
+pub(super) fn push_runtime_group_condition(
     builder: &mut QueryBuilder<'_, Postgres>,
     group: &RuntimeRecordConditionGroup,
     scope_table_aliases: &BTreeMap<String, String>,
@@ -215,23 +276,19 @@ fn push_runtime_group_condition(
     Ok(())
 }
 
-fn push_runtime_filter_condition(
+pub(super) fn push_runtime_filter_condition(
     builder: &mut QueryBuilder<'_, Postgres>,
     filter: &RuntimeRecordFilter,
     scope_table_alias: &str,
 ) {
     match filter.operator {
         RuntimeRecordOperator::Eq => {
-            builder.push(scope_table_alias);
-            builder.push(".data -> ");
-            builder.push_bind(filter.field_logical_name.clone());
+            push_json_field(builder, filter, scope_table_alias);
             builder.push(" = ");
             builder.push_bind(filter.field_value.clone());
         }
         RuntimeRecordOperator::Neq => {
-            builder.push(scope_table_alias);
-            builder.push(".data -> ");
-            builder.push_bind(filter.field_logical_name.clone());
+            push_json_field(builder, filter, scope_table_alias);
             builder.push(" <> ");
             builder.push_bind(filter.field_value.clone());
         }
@@ -247,63 +304,160 @@ fn push_runtime_filter_condition(
                 _ => unreachable!(),
             };
 
-            match filter.field_type {
-                FieldType::Number => {
-                    builder.push("(");
-                    builder.push(scope_table_alias);
-                    builder.push(".data ->> ");
-                    builder.push_bind(filter.field_logical_name.clone());
-                    builder.push(")::NUMERIC ");
-                    builder.push(operator);
-                    builder.push(" (");
-                    builder.push_bind(filter.field_value.to_string());
-                    builder.push(")::NUMERIC");
-                }
+            push_ordered_comparison(
+                builder,
+                filter,
+                scope_table_alias,
+                operator,
+                &filter.field_value,
+            );
+        }
+        RuntimeRecordOperator::Between => {
+            let (low, high) = match filter.field_value.as_array().map(Vec::as_slice) {
+                Some([low, high]) => (low, high),
                 _ => {
-                    builder.push(scope_table_alias);
-                    builder.push(".data ->> ");
-                    builder.push_bind(filter.field_logical_name.clone());
-                    builder.push(' ');
-                    builder.push(operator);
-                    builder.push(' ');
-                    builder.push_bind(filter.field_value.as_str().unwrap_or_default().to_owned());
+                    builder.push("FALSE");
+                    return;
                 }
-            }
+            };
+
+            builder.push('(');
+            push_ordered_comparison(builder, filter, scope_table_alias, ">=", low);
+            builder.push(" AND ");
+            push_ordered_comparison(builder, filter, scope_table_alias, "<=", high);
+            builder.push(')');
         }
         RuntimeRecordOperator::Contains => {
-            builder.push(scope_table_alias);
-            builder.push(".data ->> ");
-            builder.push_bind(filter.field_logical_name.clone());
+            push_text_field(builder, filter, scope_table_alias);
             builder.push(" ILIKE ");
             builder.push_bind(format!(
                 "%{}%",
                 filter.field_value.as_str().unwrap_or_default()
             ));
         }
-        RuntimeRecordOperator::In => {
+        RuntimeRecordOperator::BeginsWith => {
+            builder.push("starts_with(");
+            push_text_field(builder, filter, scope_table_alias);
+            builder.push(", ");
+            builder.push_bind(filter.field_value.as_str().unwrap_or_default().to_owned());
+            builder.push(')');
+        }
+        RuntimeRecordOperator::In | RuntimeRecordOperator::NotIn => {
             let values = filter.field_value.as_array().cloned().unwrap_or_default();
+            if filter.operator == RuntimeRecordOperator::NotIn {
+                builder.push('(');
+                push_json_field(builder, filter, scope_table_alias);
+                builder.push(" IS NOT NULL AND NOT ");
+            }
             builder.push('(');
             for (index, value) in values.iter().enumerate() {
                 if index > 0 {
                     builder.push(" OR ");
                 }
 
-                builder.push(scope_table_alias);
-                builder.push(".data -> ");
-                builder.push_bind(filter.field_logical_name.clone());
+                push_json_field(builder, filter, scope_table_alias);
                 builder.push(" = ");
                 builder.push_bind(value.clone());
             }
             builder.push(')');
+            if filter.operator == RuntimeRecordOperator::NotIn {
+                builder.push(')');
+            }
+        }
+        RuntimeRecordOperator::IsNull | RuntimeRecordOperator::IsNotNull => {
+            if filter.operator == RuntimeRecordOperator::IsNotNull {
+                builder.push("NOT ");
+            }
+            builder.push('(');
+            push_json_field(builder, filter, scope_table_alias);
+            builder.push(" IS NULL OR ");
+            push_json_field(builder, filter, scope_table_alias);
+            builder.push(" IN ('null'::JSONB, '\"\"'::JSONB, '[]'::JSONB))");
+        }
+        RuntimeRecordOperator::Today
+        | RuntimeRecordOperator::Yesterday
+        | RuntimeRecordOperator::ThisMonth
+        | RuntimeRecordOperator::LastNDays => {
+            let Some((start, end)) = filter
+                .operator
+                .relative_date_window(&filter.field_value, Utc::now().date_naive())
+            else {
+                builder.push("FALSE");
+                return;
+            };
+
+            builder.push("(LEFT(");
+            push_text_field(builder, filter, scope_table_alias);
+            builder.push(", 10) >= ");
+            builder.push_bind(start.to_string());
+            builder.push(" AND LEFT(");
+            push_text_field(builder, filter, scope_table_alias);
+            builder.push(", 10) < ");
+            builder.push_bind(end.to_string());
+            builder.push(')');
         }
     }
 }
 
+fn push_json_field(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    filter: &RuntimeRecordFilter,
+    scope_table_alias: &str,
+) {
+    builder.push(scope_table_alias);
+    builder.push(".data -> ");
+    builder.push_bind(filter.field_logical_name.clone());
+}
+
+fn push_text_field(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    filter: &RuntimeRecordFilter,
+    scope_table_alias: &str,
+) {
+    builder.push(scope_table_alias);
+    builder.push(".data ->> ");
+    builder.push_bind(filter.field_logical_name.clone());
+}
+
+fn push_ordered_comparison(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    filter: &RuntimeRecordFilter,
+    scope_table_alias: &str,
+    operator: &str,
+    value: &Value,
+) {
+    match filter.field_type {
+        FieldType::Number => {
+            builder.push("(");
+            push_text_field(builder, filter, scope_table_alias);
+            builder.push(")::NUMERIC ");
+            builder.push(operator);
+            builder.push(" (");
+            builder.push_bind(value.to_string());
+            builder.push(")::NUMERIC");
+        }
+        _ => {
+            push_text_field(builder, filter, scope_table_alias);
+            builder.push(' ');
+            builder.push(operator);
+            builder.push(' ');
+            builder.push_bind(value.as_str().unwrap_or_default().to_owned());
+        }
+    }
+}
+
+/// Pushes one ORDER BY term; `aggregate_scope` wraps linked-scope values in
+/// `MIN` when the query groups by the root record.
 fn push_runtime_sort_clause(
     builder: &mut QueryBuilder<'_, Postgres>,
     sort: &RuntimeRecordSort,
     scope_table_alias: &str,
+    aggregate_scope: bool,
 ) {
+    if aggregate_scope {
+        builder.push("MIN(");
+    }
+
     match sort.field_type {
         FieldType::Number => {
             builder.push("(");
@@ -319,6 +473,10 @@ fn push_runtime_sort_clause(
         }
     }
 
+    if aggregate_scope {
+        builder.push(')');
+    }
+
     builder.push(' ');
     match sort.direction {
         RuntimeRecordSortDirection::Asc => builder.push("ASC"),
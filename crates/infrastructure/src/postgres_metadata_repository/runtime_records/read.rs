@@ -18,7 +18,7 @@ impl PostgresMetadataRepository {
         let started_at = std::time::Instant::now();
         let rows_result = sqlx::query_as::<_, RuntimeRecordRow>(
             r#"
-            SELECT id, entity_logical_name, data
+            SELECT id, entity_logical_name, data, version
             FROM runtime_records
             WHERE tenant_id = $1
               AND entity_logical_name = $2
@@ -68,7 +68,7 @@ impl PostgresMetadataRepository {
 
         let row = sqlx::query_as::<_, RuntimeRecordRow>(
             r#"
-            SELECT id, entity_logical_name, data
+            SELECT id, entity_logical_name, data, version
             FROM runtime_records
             WHERE tenant_id = $1 AND entity_logical_name = $2 AND id = $3
             "#,
@@ -93,49 +93,82 @@ impl PostgresMetadataRepository {
         row.map(runtime_record_from_row).transpose()
     }
 
-    pub(in super::super) async fn delete_runtime_record_impl(
+    pub(in super::super) async fn find_runtime_record_by_idempotency_key_impl(
         &self,
         tenant_id: TenantId,
         entity_logical_name: &str,
-        record_id: &str,
-        workflow_event: Option<RuntimeRecordWorkflowEventInput>,
-    ) -> AppResult<()> {
+        idempotency_key: &str,
+    ) -> AppResult<Option<RuntimeRecord>> {
         let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
-        let record_uuid = parse_runtime_record_uuid(record_id)?;
 
-        let deleted = sqlx::query(
+        let row = sqlx::query_as::<_, RuntimeRecordRow>(
             r#"
-            DELETE FROM runtime_records
-            WHERE tenant_id = $1 AND entity_logical_name = $2 AND id = $3
+            SELECT id, entity_logical_name, data, version
+            FROM runtime_records
+            WHERE tenant_id = $1 AND entity_logical_name = $2 AND idempotency_key = $3
             "#,
         )
         .bind(tenant_id.as_uuid())
         .bind(entity_logical_name)
-        .bind(record_uuid)
-        .execute(&mut *transaction)
+        .bind(idempotency_key)
+        .fetch_optional(&mut *transaction)
         .await
         .map_err(|error| {
             AppError::Internal(format!(
-                "failed to delete runtime record '{}' for entity '{}' in tenant '{}': {error}",
-                record_id, entity_logical_name, tenant_id
+                "failed to find runtime record by idempotency key for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit runtime record lookup transaction: {error}"
             ))
         })?;
 
-        if deleted.rows_affected() == 0 {
-            return Err(AppError::NotFound(format!(
-                "runtime record '{}' does not exist for entity '{}'",
-                record_id, entity_logical_name
-            )));
+        row.map(runtime_record_from_row).transpose()
+    }
+
+    pub(in super::super) async fn delete_runtime_record_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+        relation_plan: &RelationDeletePlan,
+    ) -> AppResult<()> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        for relinked in &relation_plan.relinked_records {
+            relink_runtime_record_row(&mut transaction, tenant_id, relinked).await?;
+        }
+        for deleted in &relation_plan.deleted_records {
+            delete_runtime_record_row(
+                &mut transaction,
+                tenant_id,
+                deleted.entity_logical_name.as_str(),
+                deleted.record_id.as_str(),
+                deleted.workflow_event.clone(),
+                None,
+            )
+            .await?;
         }
 
-        super::write::enqueue_runtime_record_workflow_event(
+        if !delete_runtime_record_row(
             &mut transaction,
             tenant_id,
             entity_logical_name,
             record_id,
             workflow_event,
+            audit_event,
         )
-        .await?;
+        .await?
+        {
+            return Err(AppError::NotFound(format!(
+                "runtime record '{}' does not exist for entity '{}'",
+                record_id, entity_logical_name
+            )));
+        }
 
         transaction.commit().await.map_err(|error| {
             AppError::Internal(format!(
@@ -227,3 +260,85 @@ impl PostgresMetadataRepository {
         Ok(is_owned)
     }
 }
+
+/// Deletes one runtime record row and enqueues its workflow event.
+///
+/// Returns whether a row was deleted.
+async fn delete_runtime_record_row(
+    transaction: &mut sqlx::Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    entity_logical_name: &str,
+    record_id: &str,
+    workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+    audit_event: Option<AuditEvent>,
+) -> AppResult<bool> {
+    let record_uuid = parse_runtime_record_uuid(record_id)?;
+    let deleted = sqlx::query(
+        r#"
+        DELETE FROM runtime_records
+        WHERE tenant_id = $1 AND entity_logical_name = $2 AND id = $3
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(entity_logical_name)
+    .bind(record_uuid)
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!(
+            "failed to delete runtime record '{}' for entity '{}' in tenant '{}': {error}",
+            record_id, entity_logical_name, tenant_id
+        ))
+    })?;
+    if deleted.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    super::write::enqueue_runtime_record_workflow_event(
+        transaction,
+        tenant_id,
+        entity_logical_name,
+        record_id,
+        workflow_event,
+    )
+    .await?;
+    enqueue_audit_outbox_event(transaction, audit_event).await?;
+
+    Ok(true)
+}
+
+/// Points the relation field of a child record at a new parent, or clears it.
+async fn relink_runtime_record_row(
+    transaction: &mut sqlx::Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    relinked: &RelationRelinkedRecord,
+) -> AppResult<()> {
+    let record_uuid = parse_runtime_record_uuid(relinked.record_id.as_str())?;
+    sqlx::query(
+        r#"
+        UPDATE runtime_records
+        SET data = jsonb_set(data, ARRAY[$4::TEXT], COALESCE(to_jsonb($5::TEXT), 'null'::JSONB)),
+            version = version + 1,
+            updated_at = now()
+        WHERE tenant_id = $1 AND entity_logical_name = $2 AND id = $3
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(relinked.entity_logical_name.as_str())
+    .bind(record_uuid)
+    .bind(relinked.field_logical_name.as_str())
+    .bind(relinked.parent_record_id.as_deref())
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!(
+            "failed to relink relation field '{}.{}' of runtime record '{}' in tenant '{}': {error}",
+            relinked.entity_logical_name,
+            relinked.field_logical_name,
+            relinked.record_id,
+            tenant_id
+        ))
+    })?;
+
+    Ok(())
+}
@@ -98,4 +98,190 @@ impl PostgresMetadataRepository {
 
         Ok(false)
     }
+
+    pub(in super::super) async fn save_relation_behavior_impl(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        behavior: RelationBehavior,
+    ) -> AppResult<()> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO runtime_relation_behaviors (
+                tenant_id,
+                entity_logical_name,
+                field_logical_name,
+                target_entity_logical_name,
+                assign_behavior,
+                share_behavior,
+                delete_behavior,
+                updated_by_subject,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, now())
+            ON CONFLICT (tenant_id, entity_logical_name, field_logical_name)
+            DO UPDATE SET
+                target_entity_logical_name = EXCLUDED.target_entity_logical_name,
+                assign_behavior = EXCLUDED.assign_behavior,
+                share_behavior = EXCLUDED.share_behavior,
+                delete_behavior = EXCLUDED.delete_behavior,
+                updated_by_subject = EXCLUDED.updated_by_subject,
+                updated_at = now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(behavior.entity_logical_name.as_str())
+        .bind(behavior.field_logical_name.as_str())
+        .bind(behavior.target_entity_logical_name.as_str())
+        .bind(behavior.assign_behavior.as_str())
+        .bind(behavior.share_behavior.as_str())
+        .bind(behavior.delete_behavior.as_str())
+        .bind(updated_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to save relation behavior for field '{}.{}' in tenant '{}': {error}",
+                behavior.entity_logical_name, behavior.field_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit relation behavior transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn list_relation_behaviors_impl(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<RelationBehavior>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, RelationBehaviorRow>(
+            r#"
+            SELECT
+                entity_logical_name,
+                field_logical_name,
+                target_entity_logical_name,
+                assign_behavior,
+                share_behavior,
+                delete_behavior
+            FROM runtime_relation_behaviors
+            WHERE tenant_id = $1
+            ORDER BY entity_logical_name, field_logical_name
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list relation behaviors for tenant '{}': {error}",
+                tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit relation behavior list transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(RelationBehavior {
+                    entity_logical_name: row.entity_logical_name,
+                    field_logical_name: row.field_logical_name,
+                    target_entity_logical_name: row.target_entity_logical_name,
+                    assign_behavior: RelationCascadeBehavior::from_str(
+                        row.assign_behavior.as_str(),
+                    )?,
+                    share_behavior: RelationCascadeBehavior::from_str(row.share_behavior.as_str())?,
+                    delete_behavior: RelationDeleteBehavior::from_str(
+                        row.delete_behavior.as_str(),
+                    )?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Child records reassigned per statement while cascading an owner change.
+const RELATION_CASCADE_BATCH_SIZE: i64 = 500;
+
+/// Reassigns child records of one relation in batches inside the caller's transaction.
+///
+/// `required_owner` limits the cascade to children owned by that subject.
+pub(super) async fn cascade_runtime_record_owner(
+    transaction: &mut sqlx::Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    cascade: &RelationBehavior,
+    parent_record_id: &str,
+    owner_subject: &str,
+    required_owner: Option<&str>,
+) -> AppResult<u64> {
+    let mut affected_records = 0_u64;
+    loop {
+        let batch_rows = sqlx::query(
+            r#"
+            WITH batch AS (
+                SELECT id
+                FROM runtime_records
+                WHERE tenant_id = $1
+                  AND entity_logical_name = $2
+                  AND data ->> $3 = $4
+                  AND created_by_subject <> $5
+                  AND ($6::TEXT IS NULL OR created_by_subject = $6)
+                LIMIT $7
+                FOR UPDATE
+            )
+            UPDATE runtime_records
+            SET created_by_subject = $5,
+                data = CASE
+                    WHEN runtime_records.data ->> 'owner' = runtime_records.created_by_subject
+                        THEN jsonb_set(runtime_records.data, '{owner}', to_jsonb($5::TEXT))
+                    ELSE runtime_records.data
+                END,
+                version = runtime_records.version + 1,
+                updated_at = now()
+            FROM batch
+            WHERE runtime_records.tenant_id = $1
+              AND runtime_records.id = batch.id
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(cascade.entity_logical_name.as_str())
+        .bind(cascade.field_logical_name.as_str())
+        .bind(parent_record_id)
+        .bind(owner_subject)
+        .bind(required_owner)
+        .bind(RELATION_CASCADE_BATCH_SIZE)
+        .execute(&mut **transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to cascade owner assignment to '{}.{}' in tenant '{}': {error}",
+                cascade.entity_logical_name, cascade.field_logical_name, tenant_id
+            ))
+        })?
+        .rows_affected();
+
+        affected_records += batch_rows;
+        if batch_rows < RELATION_CASCADE_BATCH_SIZE.unsigned_abs() {
+            return Ok(affected_records);
+        }
+
+        info!(
+            tenant_id = %tenant_id,
+            entity_logical_name = cascade.entity_logical_name.as_str(),
+            field_logical_name = cascade.field_logical_name.as_str(),
+            parent_record_id,
+            affected_records,
+            "relation owner cascade in progress"
+        );
+    }
 }
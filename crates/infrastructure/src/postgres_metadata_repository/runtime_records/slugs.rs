@@ -0,0 +1,132 @@
+use super::*;
+
+impl PostgresMetadataRepository {
+    pub(in super::super) async fn save_entity_slug_config_impl(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        config: EntitySlugConfig,
+    ) -> AppResult<()> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO entity_slug_configs (
+                tenant_id,
+                entity_logical_name,
+                slug_field_logical_name,
+                source_field_logical_name,
+                updated_by_subject,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, now())
+            ON CONFLICT (tenant_id, entity_logical_name)
+            DO UPDATE SET
+                slug_field_logical_name = EXCLUDED.slug_field_logical_name,
+                source_field_logical_name = EXCLUDED.source_field_logical_name,
+                updated_by_subject = EXCLUDED.updated_by_subject,
+                updated_at = now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(config.entity_logical_name.as_str())
+        .bind(config.slug_field_logical_name.as_str())
+        .bind(config.source_field_logical_name.as_str())
+        .bind(updated_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to save slug configuration for entity '{}' in tenant '{}': {error}",
+                config.entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit slug configuration transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn find_entity_slug_config_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntitySlugConfig>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, EntitySlugConfigRow>(
+            r#"
+            SELECT
+                entity_logical_name,
+                slug_field_logical_name,
+                source_field_logical_name
+            FROM entity_slug_configs
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find slug configuration for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit slug configuration lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(|row| EntitySlugConfig {
+            entity_logical_name: row.entity_logical_name,
+            slug_field_logical_name: row.slug_field_logical_name,
+            source_field_logical_name: row.source_field_logical_name,
+        }))
+    }
+
+    pub(in super::super) async fn find_runtime_record_id_by_unique_value_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        field_logical_name: &str,
+        field_value_hash: &str,
+    ) -> AppResult<Option<String>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let record_id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT record_id
+            FROM runtime_record_unique_values
+            WHERE tenant_id = $1
+              AND entity_logical_name = $2
+              AND field_logical_name = $3
+              AND field_value_hash = $4
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(field_logical_name)
+        .bind(field_value_hash)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find unique value for field '{}.{}' in tenant '{}': {error}",
+                entity_logical_name, field_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit unique value lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(record_id.map(|record_id| record_id.to_string()))
+    }
+}
@@ -0,0 +1,330 @@
+use chrono::{DateTime, Utc};
+use qryvanta_domain::{RecordStatusModel, RecordStatusOption, RecordStatusTransition};
+
+use super::*;
+
+#[derive(Debug, FromRow)]
+struct EntityStatusConfigRow {
+    entity_logical_name: String,
+    status_field_logical_name: String,
+    reason_field_logical_name: Option<String>,
+    initial_status: String,
+    statuses: Value,
+    transitions: Value,
+}
+
+#[derive(Debug, FromRow)]
+struct RuntimeRecordStatusChangeRow {
+    id: Uuid,
+    entity_logical_name: String,
+    record_id: String,
+    from_status: Option<String>,
+    to_status: String,
+    from_reason: Option<String>,
+    to_reason: Option<String>,
+    changed_by_subject: String,
+    changed_at: DateTime<Utc>,
+}
+
+impl PostgresMetadataRepository {
+    pub(in super::super) async fn save_entity_status_config_impl(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        config: EntityStatusConfig,
+    ) -> AppResult<()> {
+        let statuses = serde_json::to_value(config.model.statuses()).map_err(|error| {
+            AppError::Internal(format!(
+                "failed to serialize status model statuses: {error}"
+            ))
+        })?;
+        let transitions = serde_json::to_value(config.model.transitions()).map_err(|error| {
+            AppError::Internal(format!(
+                "failed to serialize status model transitions: {error}"
+            ))
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO entity_status_models (
+                tenant_id,
+                entity_logical_name,
+                status_field_logical_name,
+                reason_field_logical_name,
+                initial_status,
+                statuses,
+                transitions,
+                updated_by_subject,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, now())
+            ON CONFLICT (tenant_id, entity_logical_name)
+            DO UPDATE SET
+                status_field_logical_name = EXCLUDED.status_field_logical_name,
+                reason_field_logical_name = EXCLUDED.reason_field_logical_name,
+                initial_status = EXCLUDED.initial_status,
+                statuses = EXCLUDED.statuses,
+                transitions = EXCLUDED.transitions,
+                updated_by_subject = EXCLUDED.updated_by_subject,
+                updated_at = now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(config.entity_logical_name.as_str())
+        .bind(config.model.status_field_logical_name())
+        .bind(config.model.reason_field_logical_name())
+        .bind(config.model.initial_status())
+        .bind(statuses)
+        .bind(transitions)
+        .bind(updated_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to save status model for entity '{}' in tenant '{}': {error}",
+                config.entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit status model transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    pub(in super::super) async fn find_entity_status_config_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Option<EntityStatusConfig>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, EntityStatusConfigRow>(
+            r#"
+            SELECT
+                entity_logical_name,
+                status_field_logical_name,
+                reason_field_logical_name,
+                initial_status,
+                statuses,
+                transitions
+            FROM entity_status_models
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find status model for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit status model lookup transaction: {error}"
+            ))
+        })?;
+
+        row.map(entity_status_config_from_row).transpose()
+    }
+
+    pub(in super::super) async fn delete_entity_status_config_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM entity_status_models
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete status model for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit status model delete transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub(in super::super) async fn append_runtime_record_status_change_impl(
+        &self,
+        tenant_id: TenantId,
+        change: NewRuntimeRecordStatusChange,
+    ) -> AppResult<RuntimeRecordStatusChange> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RuntimeRecordStatusChangeRow>(
+            r#"
+            INSERT INTO runtime_record_status_history (
+                id,
+                tenant_id,
+                entity_logical_name,
+                record_id,
+                from_status,
+                to_status,
+                from_reason,
+                to_reason,
+                changed_by_subject,
+                changed_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, now())
+            RETURNING
+                id,
+                entity_logical_name,
+                record_id,
+                from_status,
+                to_status,
+                from_reason,
+                to_reason,
+                changed_by_subject,
+                changed_at
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(tenant_id.as_uuid())
+        .bind(change.entity_logical_name.as_str())
+        .bind(change.record_id.as_str())
+        .bind(change.from_status.as_deref())
+        .bind(change.to_status.as_str())
+        .bind(change.from_reason.as_deref())
+        .bind(change.to_reason.as_deref())
+        .bind(change.changed_by_subject.as_str())
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to record status change for entity '{}' record '{}' in tenant '{}': {error}",
+                change.entity_logical_name, change.record_id, tenant_id
+            ))
+        })?;
+
+        super::write::enqueue_runtime_record_workflow_event(
+            &mut transaction,
+            tenant_id,
+            change.entity_logical_name.as_str(),
+            change.record_id.as_str(),
+            change.notification,
+        )
+        .await?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit status change transaction: {error}"
+            ))
+        })?;
+
+        Ok(runtime_record_status_change_from_row(row))
+    }
+
+    pub(in super::super) async fn list_runtime_record_status_history_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RuntimeRecordStatusChange>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, RuntimeRecordStatusChangeRow>(
+            r#"
+            SELECT
+                id,
+                entity_logical_name,
+                record_id,
+                from_status,
+                to_status,
+                from_reason,
+                to_reason,
+                changed_by_subject,
+                changed_at
+            FROM runtime_record_status_history
+            WHERE tenant_id = $1 AND entity_logical_name = $2 AND record_id = $3
+            ORDER BY changed_at, id
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(record_id)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list status history for entity '{}' record '{}' in tenant '{}': {error}",
+                entity_logical_name, record_id, tenant_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit status history lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(runtime_record_status_change_from_row)
+            .collect())
+    }
+}
+
+fn entity_status_config_from_row(row: EntityStatusConfigRow) -> AppResult<EntityStatusConfig> {
+    let statuses =
+        serde_json::from_value::<Vec<RecordStatusOption>>(row.statuses).map_err(|error| {
+            AppError::Internal(format!(
+                "failed to decode status model statuses for entity '{}': {error}",
+                row.entity_logical_name
+            ))
+        })?;
+    let transitions = serde_json::from_value::<Vec<RecordStatusTransition>>(row.transitions)
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to decode status model transitions for entity '{}': {error}",
+                row.entity_logical_name
+            ))
+        })?;
+
+    Ok(EntityStatusConfig {
+        model: RecordStatusModel::new(
+            row.status_field_logical_name,
+            row.reason_field_logical_name,
+            row.initial_status,
+            statuses,
+            transitions,
+        )?,
+        entity_logical_name: row.entity_logical_name,
+    })
+}
+
+fn runtime_record_status_change_from_row(
+    row: RuntimeRecordStatusChangeRow,
+) -> RuntimeRecordStatusChange {
+    RuntimeRecordStatusChange {
+        change_id: row.id.to_string(),
+        entity_logical_name: row.entity_logical_name,
+        record_id: row.record_id,
+        from_status: row.from_status,
+        to_status: row.to_status,
+        from_reason: row.from_reason,
+        to_reason: row.to_reason,
+        changed_by_subject: row.changed_by_subject,
+        changed_at: row.changed_at,
+    }
+}
@@ -1,6 +1,9 @@
 use super::*;
 
+const RUNTIME_RECORD_IDEMPOTENCY_KEY_INDEX: &str = "idx_runtime_records_idempotency_key";
+
 impl PostgresMetadataRepository {
+    #[allow(clippy::too_many_arguments)]
     pub(in super::super) async fn create_runtime_record_impl(
         &self,
         tenant_id: TenantId,
@@ -9,20 +12,24 @@ impl PostgresMetadataRepository {
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         let generated_record_id = Uuid::new_v4();
         self.create_runtime_record_with_id_uuid_impl(
             tenant_id,
             entity_logical_name,
             generated_record_id,
+            None,
             data,
             unique_values,
             created_by_subject,
             workflow_event,
+            audit_event,
         )
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(in super::super) async fn create_runtime_record_with_id_impl(
         &self,
         tenant_id: TenantId,
@@ -32,76 +39,76 @@ impl PostgresMetadataRepository {
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
         let parsed_record_id = parse_runtime_record_uuid(record_id)?;
         self.create_runtime_record_with_id_uuid_impl(
             tenant_id,
             entity_logical_name,
             parsed_record_id,
+            None,
             data,
             unique_values,
             created_by_subject,
             workflow_event,
+            audit_event,
         )
         .await
     }
 
-    async fn create_runtime_record_with_id_uuid_impl(
+    #[allow(clippy::too_many_arguments)]
+    pub(in super::super) async fn create_runtime_record_with_idempotency_key_impl(
         &self,
         tenant_id: TenantId,
         entity_logical_name: &str,
-        record_id: Uuid,
+        record_id: &str,
+        idempotency_key: &str,
         data: Value,
         unique_values: Vec<UniqueFieldValue>,
         created_by_subject: &str,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
-        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
-
-        let created = sqlx::query_as::<_, RuntimeRecordRow>(
-            r#"
-            INSERT INTO runtime_records (id, tenant_id, entity_logical_name, data, created_by_subject)
-            VALUES ($1, $2, $3, $4, $5)
-            RETURNING id, entity_logical_name, data
-            "#,
-        )
-        .bind(record_id)
-        .bind(tenant_id.as_uuid())
-        .bind(entity_logical_name)
-        .bind(&data)
-        .bind(created_by_subject)
-        .fetch_one(&mut *transaction)
-        .await
-        .map_err(|error| {
-            if let sqlx::Error::Database(database_error) = &error
-                && database_error.code().as_deref() == Some("23505")
-            {
-                return AppError::Conflict(format!(
-                    "runtime record '{}' already exists for entity '{}'",
-                    record_id, entity_logical_name
-                ));
-            }
-            AppError::Internal(format!(
-                "failed to create runtime record for entity '{}' in tenant '{}': {error}",
-                entity_logical_name, tenant_id
-            ))
-        })?;
-
-        index_unique_values(
-            &mut transaction,
+        let parsed_record_id = parse_runtime_record_uuid(record_id)?;
+        self.create_runtime_record_with_id_uuid_impl(
             tenant_id,
             entity_logical_name,
-            created.id,
-            &unique_values,
+            parsed_record_id,
+            Some(idempotency_key),
+            data,
+            unique_values,
+            created_by_subject,
+            workflow_event,
+            audit_event,
         )
-        .await?;
-        let created_record_id = created.id.to_string();
-        enqueue_runtime_record_workflow_event(
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn create_runtime_record_with_id_uuid_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: Uuid,
+        idempotency_key: Option<&str>,
+        data: Value,
+        unique_values: Vec<UniqueFieldValue>,
+        created_by_subject: &str,
+        workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
+    ) -> AppResult<RuntimeRecord> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let created = insert_runtime_record(
             &mut transaction,
             tenant_id,
             entity_logical_name,
-            created_record_id.as_str(),
+            record_id,
+            idempotency_key,
+            data,
+            unique_values,
+            created_by_subject,
             workflow_event,
+            audit_event,
         )
         .await?;
 
@@ -112,9 +119,10 @@ impl PostgresMetadataRepository {
             ))
         })?;
 
-        runtime_record_from_row(created)
+        Ok(created)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(in super::super) async fn update_runtime_record_impl(
         &self,
         tenant_id: TenantId,
@@ -122,84 +130,372 @@ impl PostgresMetadataRepository {
         record_id: &str,
         data: Value,
         unique_values: Vec<UniqueFieldValue>,
+        expected_version: Option<i64>,
         workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+        audit_event: Option<AuditEvent>,
     ) -> AppResult<RuntimeRecord> {
-        let record_uuid = parse_runtime_record_uuid(record_id)?;
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let updated = update_runtime_record_row(
+            &mut transaction,
+            tenant_id,
+            entity_logical_name,
+            record_id,
+            data,
+            unique_values,
+            expected_version,
+            workflow_event,
+            audit_event,
+        )
+        .await?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit runtime record update transaction for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
+            ))
+        })?;
+
+        Ok(updated)
+    }
+
+    pub(in super::super) async fn apply_runtime_record_changeset_impl(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        writes: Vec<RuntimeRecordChangesetWrite>,
+    ) -> AppResult<Vec<RuntimeRecord>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let mut records = Vec::with_capacity(writes.len());
+        for write in writes {
+            let record = match write.method {
+                RuntimeRecordChangesetMethod::Create => {
+                    let record_uuid = parse_runtime_record_uuid(write.record_id.as_str())?;
+                    insert_runtime_record(
+                        &mut transaction,
+                        tenant_id,
+                        write.entity_logical_name.as_str(),
+                        record_uuid,
+                        None,
+                        write.data,
+                        write.unique_values,
+                        created_by_subject,
+                        write.workflow_event,
+                        write.audit_event,
+                    )
+                    .await?
+                }
+                RuntimeRecordChangesetMethod::Update => {
+                    update_runtime_record_row(
+                        &mut transaction,
+                        tenant_id,
+                        write.entity_logical_name.as_str(),
+                        write.record_id.as_str(),
+                        write.data,
+                        write.unique_values,
+                        None,
+                        write.workflow_event,
+                        write.audit_event,
+                    )
+                    .await?
+                }
+            };
+            records.push(record);
+        }
 
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit runtime record changeset transaction in tenant '{}': {error}",
+                tenant_id
+            ))
+        })?;
+
+        Ok(records)
+    }
+
+    pub(in super::super) async fn assign_runtime_record_owner_impl(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        owner_subject: &str,
+        cascades: &[RelationBehavior],
+    ) -> AppResult<Option<(String, Vec<RelationCascadeResult>)>> {
         let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let record_uuid = parse_runtime_record_uuid(record_id)?;
 
-        let updated = sqlx::query_as::<_, RuntimeRecordRow>(
+        let previous_owner = sqlx::query_scalar::<_, String>(
             r#"
+            WITH previous AS (
+                SELECT id, created_by_subject
+                FROM runtime_records
+                WHERE tenant_id = $1
+                  AND entity_logical_name = $2
+                  AND id = $3
+                FOR UPDATE
+            )
             UPDATE runtime_records
-            SET data = $4,
+            SET created_by_subject = $4,
+                data = CASE
+                    WHEN runtime_records.data ->> 'owner' = previous.created_by_subject
+                        THEN jsonb_set(runtime_records.data, '{owner}', to_jsonb($4::TEXT))
+                    ELSE runtime_records.data
+                END,
+                version = runtime_records.version + 1,
                 updated_at = now()
-            WHERE tenant_id = $1 AND entity_logical_name = $2 AND id = $3
-            RETURNING id, entity_logical_name, data
+            FROM previous
+            WHERE runtime_records.tenant_id = $1
+              AND runtime_records.id = previous.id
+            RETURNING previous.created_by_subject
             "#,
         )
         .bind(tenant_id.as_uuid())
         .bind(entity_logical_name)
         .bind(record_uuid)
-        .bind(&data)
+        .bind(owner_subject)
         .fetch_optional(&mut *transaction)
         .await
         .map_err(|error| {
             AppError::Internal(format!(
-                "failed to update runtime record '{}' for entity '{}' in tenant '{}': {error}",
-                record_id, entity_logical_name, tenant_id
-            ))
-        })?
-        .ok_or_else(|| {
-            AppError::NotFound(format!(
-                "runtime record '{}' does not exist for entity '{}'",
-                record_id, entity_logical_name
+                "failed to assign runtime record owner for entity '{}' in tenant '{}': {error}",
+                entity_logical_name, tenant_id
             ))
         })?;
+        let Some(previous_owner) = previous_owner else {
+            return Ok(None);
+        };
 
-        sqlx::query(
-            r#"
-            DELETE FROM runtime_record_unique_values
-            WHERE tenant_id = $1 AND entity_logical_name = $2 AND record_id = $3
-            "#,
-        )
-        .bind(tenant_id.as_uuid())
-        .bind(entity_logical_name)
-        .bind(record_uuid)
-        .execute(&mut *transaction)
-        .await
-        .map_err(|error| {
+        let mut results = Vec::with_capacity(cascades.len());
+        for cascade in cascades {
+            let required_owner = match cascade.assign_behavior {
+                RelationCascadeBehavior::None => continue,
+                RelationCascadeBehavior::CascadeAll => None,
+                RelationCascadeBehavior::CascadeUserOwned => Some(previous_owner.as_str()),
+            };
+            let affected_records = cascade_runtime_record_owner(
+                &mut transaction,
+                tenant_id,
+                cascade,
+                record_id,
+                owner_subject,
+                required_owner,
+            )
+            .await?;
+            results.push(RelationCascadeResult {
+                entity_logical_name: cascade.entity_logical_name.clone(),
+                field_logical_name: cascade.field_logical_name.clone(),
+                affected_records,
+            });
+        }
+
+        transaction.commit().await.map_err(|error| {
             AppError::Internal(format!(
-                "failed to clear unique field index for runtime record '{}' in entity '{}' and tenant '{}': {error}",
-                record_id, entity_logical_name, tenant_id
+                "failed to commit runtime record owner assignment transaction: {error}"
             ))
         })?;
 
-        index_unique_values(
-            &mut transaction,
-            tenant_id,
-            entity_logical_name,
-            record_uuid,
-            &unique_values,
-        )
-        .await?;
-        enqueue_runtime_record_workflow_event(
-            &mut transaction,
+        Ok(Some((previous_owner, results)))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn insert_runtime_record(
+    transaction: &mut sqlx::Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    entity_logical_name: &str,
+    record_id: Uuid,
+    idempotency_key: Option<&str>,
+    data: Value,
+    unique_values: Vec<UniqueFieldValue>,
+    created_by_subject: &str,
+    workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+    audit_event: Option<AuditEvent>,
+) -> AppResult<RuntimeRecord> {
+    let created = sqlx::query_as::<_, RuntimeRecordRow>(
+        r#"
+        INSERT INTO runtime_records (
+            id,
             tenant_id,
             entity_logical_name,
-            record_id,
-            workflow_event,
+            data,
+            created_by_subject,
+            idempotency_key
         )
-        .await?;
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, entity_logical_name, data, version
+        "#,
+    )
+    .bind(record_id)
+    .bind(tenant_id.as_uuid())
+    .bind(entity_logical_name)
+    .bind(&data)
+    .bind(created_by_subject)
+    .bind(idempotency_key)
+    .fetch_one(&mut **transaction)
+    .await
+    .map_err(|error| {
+        if let sqlx::Error::Database(database_error) = &error
+            && database_error.code().as_deref() == Some("23505")
+        {
+            if database_error.constraint() == Some(RUNTIME_RECORD_IDEMPOTENCY_KEY_INDEX) {
+                return AppError::Conflict(format!(
+                    "entity '{}' already has a runtime record for this idempotency key",
+                    entity_logical_name
+                ));
+            }
+            return AppError::Conflict(format!(
+                "runtime record '{}' already exists for entity '{}'",
+                record_id, entity_logical_name
+            ));
+        }
+        AppError::Internal(format!(
+            "failed to create runtime record for entity '{}' in tenant '{}': {error}",
+            entity_logical_name, tenant_id
+        ))
+    })?;
 
-        transaction.commit().await.map_err(|error| {
-            AppError::Internal(format!(
-                "failed to commit runtime record update transaction for entity '{}' in tenant '{}': {error}",
-                entity_logical_name, tenant_id
-            ))
-        })?;
+    index_unique_values(
+        transaction,
+        tenant_id,
+        entity_logical_name,
+        created.id,
+        &unique_values,
+    )
+    .await?;
+    let created_record_id = created.id.to_string();
+    enqueue_runtime_record_workflow_event(
+        transaction,
+        tenant_id,
+        entity_logical_name,
+        created_record_id.as_str(),
+        workflow_event,
+    )
+    .await?;
+    enqueue_audit_outbox_event(transaction, audit_event).await?;
 
-        runtime_record_from_row(updated)
-    }
+    runtime_record_from_row(created)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn update_runtime_record_row(
+    transaction: &mut sqlx::Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    entity_logical_name: &str,
+    record_id: &str,
+    data: Value,
+    unique_values: Vec<UniqueFieldValue>,
+    expected_version: Option<i64>,
+    workflow_event: Option<RuntimeRecordWorkflowEventInput>,
+    audit_event: Option<AuditEvent>,
+) -> AppResult<RuntimeRecord> {
+    let record_uuid = parse_runtime_record_uuid(record_id)?;
+
+    let updated = sqlx::query_as::<_, RuntimeRecordRow>(
+        r#"
+        UPDATE runtime_records
+        SET data = $4,
+            version = version + 1,
+            updated_at = now()
+        WHERE tenant_id = $1 AND entity_logical_name = $2 AND id = $3
+          AND ($5::BIGINT IS NULL OR version = $5)
+        RETURNING id, entity_logical_name, data, version
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(entity_logical_name)
+    .bind(record_uuid)
+    .bind(&data)
+    .bind(expected_version)
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!(
+            "failed to update runtime record '{}' for entity '{}' in tenant '{}': {error}",
+            record_id, entity_logical_name, tenant_id
+        ))
+    })?;
+
+    let Some(updated) = updated else {
+        return Err(
+            match (
+                expected_version,
+                runtime_record_version(transaction, tenant_id, entity_logical_name, record_uuid)
+                    .await?,
+            ) {
+                (Some(expected_version), Some(current_version)) => {
+                    runtime_record_version_conflict(record_id, expected_version, current_version)
+                }
+                _ => AppError::NotFound(format!(
+                    "runtime record '{}' does not exist for entity '{}'",
+                    record_id, entity_logical_name
+                )),
+            },
+        );
+    };
+
+    sqlx::query(
+        r#"
+        DELETE FROM runtime_record_unique_values
+        WHERE tenant_id = $1 AND entity_logical_name = $2 AND record_id = $3
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(entity_logical_name)
+    .bind(record_uuid)
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!(
+            "failed to clear unique field index for runtime record '{}' in entity '{}' and tenant '{}': {error}",
+            record_id, entity_logical_name, tenant_id
+        ))
+    })?;
+
+    index_unique_values(
+        transaction,
+        tenant_id,
+        entity_logical_name,
+        record_uuid,
+        &unique_values,
+    )
+    .await?;
+    enqueue_runtime_record_workflow_event(
+        transaction,
+        tenant_id,
+        entity_logical_name,
+        record_id,
+        workflow_event,
+    )
+    .await?;
+    enqueue_audit_outbox_event(transaction, audit_event).await?;
+
+    runtime_record_from_row(updated)
+}
+
+async fn runtime_record_version(
+    transaction: &mut sqlx::Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    entity_logical_name: &str,
+    record_uuid: Uuid,
+) -> AppResult<Option<i64>> {
+    sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT version
+        FROM runtime_records
+        WHERE tenant_id = $1 AND entity_logical_name = $2 AND id = $3
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(entity_logical_name)
+    .bind(record_uuid)
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!(
+            "failed to read version of runtime record '{}' for entity '{}' in tenant '{}': {error}",
+            record_uuid, entity_logical_name, tenant_id
+        ))
+    })
 }
 
 pub(super) async fn enqueue_runtime_record_workflow_event(
@@ -1,21 +1,32 @@
 use qryvanta_application::{
-    MetadataRepository, RecordListQuery, RuntimeRecordConditionGroup, RuntimeRecordConditionNode,
-    RuntimeRecordFilter, RuntimeRecordJoinType, RuntimeRecordLink, RuntimeRecordLogicalMode,
-    RuntimeRecordOperator, RuntimeRecordQuery, RuntimeRecordWorkflowEventInput,
+    EntityHistoryPolicy, EntityStatusConfig, MetadataRepository, NewRuntimeRecordHistoryEntry,
+    NewRuntimeRecordStatusChange, OperationDeadline, RecordListQuery, RelationDeletePlan,
+    RelationDeletedRecord, RelationLookupConfig, RelationRelinkedRecord, RuntimeRecordAggregate,
+    RuntimeRecordAggregateFunction, RuntimeRecordAggregateHaving, RuntimeRecordAggregateQuery,
+    RuntimeRecordAggregateSort, RuntimeRecordAggregateSortKey, RuntimeRecordAssociation,
+    RuntimeRecordConditionGroup, RuntimeRecordConditionNode, RuntimeRecordFieldChange,
+    RuntimeRecordFilter, RuntimeRecordGroupBy, RuntimeRecordJoinType, RuntimeRecordLink,
+    RuntimeRecordLinkCardinality, RuntimeRecordLogicalMode, RuntimeRecordOperator,
+    RuntimeRecordQuery, RuntimeRecordSort, RuntimeRecordSortDirection,
+    RuntimeRecordWorkflowEventInput, TimedOperation,
 };
 use qryvanta_core::{AppError, TenantId};
 use qryvanta_domain::{
     BusinessRuleAction, BusinessRuleActionType, BusinessRuleCondition, BusinessRuleDefinition,
-    BusinessRuleDefinitionInput, BusinessRuleOperator, BusinessRuleScope, EntityDefinition,
-    EntityFieldDefinition, FieldType, FormDefinition, FormFieldPlacement, FormSection, FormTab,
-    FormType, OptionSetDefinition, OptionSetItem, ViewColumn, ViewDefinition, ViewType,
+    BusinessRuleDefinitionInput, BusinessRuleOperator, BusinessRuleScope, DuplicateDetectionRule,
+    DuplicateMatchField, DuplicateMatchMode, DuplicateRuleAction, EntityDefinition,
+    EntityFieldDefinition, FieldType, FilterOperator, FormDefinition, FormFieldPlacement,
+    FormSection, FormTab, FormType, LogicalMode, OptionSetDefinition, OptionSetItem,
+    RecordStatusModel, RecordStatusOption, RecordStatusTransition, ViewColumn, ViewDefinition,
+    ViewFilterCondition, ViewFilterGroup, ViewType,
 };
-use serde_json::json;
+use serde_json::{Value, json};
 use sqlx::PgPool;
 use sqlx::migrate::Migrator;
 use sqlx::postgres::PgPoolOptions;
 
 use super::PostgresMetadataRepository;
+use crate::begin_tenant_transaction;
 
 static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
 
@@ -182,6 +193,7 @@ async fn runtime_record_queries_are_tenant_scoped() {
             Vec::new(),
             "alice",
             None,
+            None,
         )
         .await;
     assert!(left_record.is_ok());
@@ -195,6 +207,7 @@ async fn runtime_record_queries_are_tenant_scoped() {
                 limit: 50,
                 offset: 0,
                 owner_subject: None,
+                include_inactive: false,
             },
         )
         .await;
@@ -220,6 +233,7 @@ async fn runtime_record_queries_are_tenant_scoped() {
                 links: Vec::new(),
                 sort: Vec::new(),
                 owner_subject: None,
+                include_inactive: false,
             },
         )
         .await;
@@ -244,11 +258,168 @@ async fn runtime_record_queries_are_tenant_scoped() {
             "contact",
             left_record.record_id().as_str(),
             None,
+            None,
+            &RelationDeletePlan::default(),
         )
         .await;
     assert!(matches!(right_delete, Err(AppError::NotFound(_))));
 }
 
+#[tokio::test]
+async fn runtime_records_are_unique_per_entity_idempotency_key() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresMetadataRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Idempotency Tenant").await;
+    for logical_name in ["contact", "account"] {
+        let entity = EntityDefinition::new(logical_name, logical_name);
+        assert!(
+            repository
+                .save_entity(tenant_id, entity.unwrap_or_else(|_| unreachable!()))
+                .await
+                .is_ok()
+        );
+    }
+
+    let created = repository
+        .create_runtime_record_with_idempotency_key(
+            tenant_id,
+            "contact",
+            uuid::Uuid::new_v4().to_string().as_str(),
+            "import-1",
+            json!({"name": "Alice"}),
+            Vec::new(),
+            "alice",
+            None,
+            None,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let replayed = repository
+        .create_runtime_record_with_idempotency_key(
+            tenant_id,
+            "contact",
+            uuid::Uuid::new_v4().to_string().as_str(),
+            "import-1",
+            json!({"name": "Alice"}),
+            Vec::new(),
+            "alice",
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(replayed, Err(AppError::Conflict(_))));
+
+    let found = repository
+        .find_runtime_record_by_idempotency_key(tenant_id, "contact", "import-1")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        found.map(|record| record.record_id().as_str().to_owned()),
+        Some(created.record_id().as_str().to_owned())
+    );
+
+    let other_entity = repository
+        .create_runtime_record_with_idempotency_key(
+            tenant_id,
+            "account",
+            uuid::Uuid::new_v4().to_string().as_str(),
+            "import-1",
+            json!({"name": "Acme"}),
+            Vec::new(),
+            "alice",
+            None,
+            None,
+        )
+        .await;
+    assert!(other_entity.is_ok());
+
+    let unknown = repository
+        .find_runtime_record_by_idempotency_key(tenant_id, "contact", "import-2")
+        .await;
+    assert!(matches!(unknown, Ok(None)));
+}
+
+#[tokio::test]
+async fn delete_runtime_record_applies_relation_plan_in_one_transaction() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresMetadataRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Relation Delete Plan Tenant").await;
+
+    let entity = EntityDefinition::new("account", "Account").unwrap_or_else(|_| unreachable!());
+    assert!(repository.save_entity(tenant_id, entity).await.is_ok());
+
+    let mut record_ids = Vec::new();
+    for data in [
+        json!({"name": "Contoso"}),
+        json!({"name": "Contoso West", "parent_id": "pending"}),
+        json!({"name": "Contoso East", "parent_id": "pending"}),
+    ] {
+        let record = repository
+            .create_runtime_record(tenant_id, "account", data, Vec::new(), "alice", None, None)
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        record_ids.push(record.record_id().as_str().to_owned());
+    }
+    let [parent_id, relinked_id, cascaded_id] = record_ids.as_slice() else {
+        unreachable!();
+    };
+    let relation_plan = RelationDeletePlan {
+        deleted_records: vec![RelationDeletedRecord {
+            entity_logical_name: "account".to_owned(),
+            record_id: cascaded_id.clone(),
+            workflow_event: None,
+        }],
+        relinked_records: vec![RelationRelinkedRecord {
+            entity_logical_name: "account".to_owned(),
+            record_id: relinked_id.clone(),
+            field_logical_name: "parent_id".to_owned(),
+            parent_record_id: None,
+        }],
+    };
+
+    assert!(
+        repository
+            .delete_runtime_record(tenant_id, "account", parent_id, None, None, &relation_plan)
+            .await
+            .is_ok()
+    );
+    let relinked = repository
+        .find_runtime_record(tenant_id, "account", relinked_id)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(relinked.data()["parent_id"], Value::Null);
+    assert_eq!(relinked.data()["name"], json!("Contoso West"));
+    assert_eq!(relinked.version(), 2);
+    for record_id in [parent_id, cascaded_id] {
+        let deleted = repository
+            .find_runtime_record(tenant_id, "account", record_id)
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert!(deleted.is_none());
+    }
+
+    let missing_parent = repository
+        .delete_runtime_record(tenant_id, "account", parent_id, None, None, &relation_plan)
+        .await;
+    assert!(matches!(missing_parent, Err(AppError::NotFound(_))));
+    let unchanged = repository
+        .find_runtime_record(tenant_id, "account", relinked_id)
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(unchanged.version(), 2);
+}
+
 #[tokio::test]
 async fn metadata_components_are_tenant_scoped() {
     let Some(pool) = test_pool().await else {
@@ -356,6 +527,7 @@ async fn runtime_record_workflow_events_can_be_released_reclaimed_and_completed(
                 }),
                 emitted_by_subject: "alice".to_owned(),
             }),
+            None,
         )
         .await
         .unwrap_or_else(|_| unreachable!());
@@ -423,6 +595,367 @@ async fn runtime_record_workflow_events_can_be_released_reclaimed_and_completed(
     assert!(final_claim.is_empty());
 }
 
+#[tokio::test]
+async fn status_models_and_history_round_trip_and_enqueue_notifications() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresMetadataRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Record Status Tenant").await;
+
+    let model = RecordStatusModel::new(
+        "status",
+        Some("status_reason".to_owned()),
+        "open",
+        vec![
+            RecordStatusOption {
+                value: "open".to_owned(),
+                label: "Open".to_owned(),
+                reasons: vec!["new".to_owned()],
+                inactive: false,
+            },
+            RecordStatusOption {
+                value: "closed".to_owned(),
+                label: "Closed".to_owned(),
+                reasons: vec!["done".to_owned()],
+                inactive: false,
+            },
+        ],
+        vec![RecordStatusTransition {
+            from: "open".to_owned(),
+            to: "closed".to_owned(),
+        }],
+    )
+    .unwrap_or_else(|_| unreachable!());
+    let config = EntityStatusConfig {
+        entity_logical_name: "ticket".to_owned(),
+        model,
+    };
+    assert!(
+        repository
+            .save_entity_status_config(tenant_id, "alice", config.clone())
+            .await
+            .is_ok()
+    );
+    assert_eq!(
+        repository
+            .find_entity_status_config(tenant_id, "ticket")
+            .await
+            .unwrap_or_else(|_| unreachable!()),
+        Some(config)
+    );
+
+    let change = repository
+        .append_runtime_record_status_change(
+            tenant_id,
+            NewRuntimeRecordStatusChange {
+                entity_logical_name: "ticket".to_owned(),
+                record_id: "record-1".to_owned(),
+                from_status: Some("open".to_owned()),
+                to_status: "closed".to_owned(),
+                from_reason: Some("new".to_owned()),
+                to_reason: Some("done".to_owned()),
+                changed_by_subject: "alice".to_owned(),
+                notification: Some(RuntimeRecordWorkflowEventInput {
+                    trigger: qryvanta_domain::WorkflowTrigger::RuntimeRecordStatusChanged {
+                        entity_logical_name: "ticket".to_owned(),
+                    },
+                    record_id: "record-1".to_owned(),
+                    payload: json!({"status": "closed", "previous_status": "open"}),
+                    emitted_by_subject: "alice".to_owned(),
+                }),
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(change.to_status, "closed");
+
+    let history = repository
+        .list_runtime_record_status_history(tenant_id, "ticket", "record-1")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(history, vec![change]);
+
+    let claimed = repository
+        .claim_runtime_record_workflow_events("worker-1", 10, 60, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(
+        claimed[0].trigger,
+        qryvanta_domain::WorkflowTrigger::RuntimeRecordStatusChanged {
+            entity_logical_name: "ticket".to_owned(),
+        }
+    );
+
+    assert!(
+        repository
+            .delete_entity_status_config(tenant_id, "ticket")
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+    assert!(
+        !repository
+            .delete_entity_status_config(tenant_id, "ticket")
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+}
+
+#[tokio::test]
+async fn runtime_record_history_round_trips_and_honors_retention() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresMetadataRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Record History Tenant").await;
+
+    let policy = EntityHistoryPolicy {
+        entity_logical_name: "ticket".to_owned(),
+        retention_days: 30,
+    };
+    assert!(
+        repository
+            .save_entity_history_policy(tenant_id, "alice", policy.clone())
+            .await
+            .is_ok()
+    );
+    assert_eq!(
+        repository
+            .find_entity_history_policy(tenant_id, "ticket")
+            .await
+            .unwrap_or_else(|_| unreachable!()),
+        Some(policy)
+    );
+
+    let changes = vec![
+        RuntimeRecordFieldChange {
+            field_logical_name: "notes".to_owned(),
+            old_value: Some(json!("draft")),
+            new_value: None,
+        },
+        RuntimeRecordFieldChange {
+            field_logical_name: "owner".to_owned(),
+            old_value: Some(Value::Null),
+            new_value: Some(json!("bob")),
+        },
+    ];
+    let entry = repository
+        .append_runtime_record_history_entry(
+            tenant_id,
+            NewRuntimeRecordHistoryEntry {
+                entity_logical_name: "ticket".to_owned(),
+                record_id: "record-1".to_owned(),
+                record_version: 2,
+                changes: changes.clone(),
+                changed_by_subject: "alice".to_owned(),
+            },
+            None,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(entry.changes, changes);
+
+    let history = repository
+        .list_runtime_record_history(tenant_id, "ticket", "record-1", None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(history, vec![entry.clone()]);
+
+    let future = entry.changed_at + chrono::Duration::seconds(1);
+    assert!(
+        repository
+            .list_runtime_record_history(tenant_id, "ticket", "record-1", Some(future))
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+
+    let newer = repository
+        .append_runtime_record_history_entry(
+            tenant_id,
+            NewRuntimeRecordHistoryEntry {
+                entity_logical_name: "ticket".to_owned(),
+                record_id: "record-1".to_owned(),
+                record_version: 3,
+                changes: vec![RuntimeRecordFieldChange {
+                    field_logical_name: "notes".to_owned(),
+                    old_value: None,
+                    new_value: Some(json!("final")),
+                }],
+                changed_by_subject: "alice".to_owned(),
+            },
+            Some(future),
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        repository
+            .list_runtime_record_history(tenant_id, "ticket", "record-1", None)
+            .await
+            .unwrap_or_else(|_| unreachable!()),
+        vec![newer.clone()]
+    );
+
+    assert_eq!(
+        repository
+            .purge_runtime_record_history(
+                tenant_id,
+                "ticket",
+                newer.changed_at + chrono::Duration::seconds(1),
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!()),
+        1
+    );
+    assert!(
+        repository
+            .delete_entity_history_policy(tenant_id, "ticket")
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+    assert!(
+        repository
+            .find_entity_history_policy(tenant_id, "ticket")
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn relation_lookup_configs_round_trip() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresMetadataRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Relation Lookup Tenant").await;
+
+    let filter = ViewFilterGroup::new(
+        LogicalMode::And,
+        vec![
+            ViewFilterCondition::new("status", FilterOperator::Eq, json!("active"))
+                .unwrap_or_else(|_| unreachable!()),
+        ],
+    )
+    .unwrap_or_else(|_| unreachable!());
+    let config = RelationLookupConfig {
+        entity_logical_name: "contact".to_owned(),
+        field_logical_name: "account_id".to_owned(),
+        target_entity_logical_name: "account".to_owned(),
+        search_field_logical_names: vec!["name".to_owned(), "code".to_owned()],
+        filter: Some(filter),
+    };
+    assert!(
+        repository
+            .save_relation_lookup_config(tenant_id, "alice", config.clone())
+            .await
+            .is_ok()
+    );
+    assert_eq!(
+        repository
+            .find_relation_lookup_config(tenant_id, "contact", "account_id")
+            .await
+            .unwrap_or_else(|_| unreachable!()),
+        Some(config)
+    );
+
+    assert!(
+        repository
+            .delete_relation_lookup_config(tenant_id, "contact", "account_id")
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+    assert_eq!(
+        repository
+            .find_relation_lookup_config(tenant_id, "contact", "account_id")
+            .await
+            .unwrap_or_else(|_| unreachable!()),
+        None
+    );
+}
+
+#[tokio::test]
+async fn duplicate_detection_rules_round_trip() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresMetadataRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Duplicate Rule Tenant").await;
+
+    let rule = |logical_name: &str, action: DuplicateRuleAction| {
+        DuplicateDetectionRule::new(
+            "account",
+            logical_name,
+            "Same account",
+            vec![
+                DuplicateMatchField {
+                    field_logical_name: "name".to_owned(),
+                    match_mode: DuplicateMatchMode::Fuzzy,
+                },
+                DuplicateMatchField {
+                    field_logical_name: "city".to_owned(),
+                    match_mode: DuplicateMatchMode::Exact,
+                },
+            ],
+            action,
+            true,
+        )
+        .unwrap_or_else(|_| unreachable!())
+    };
+    for saved in [
+        rule("same_name", DuplicateRuleAction::Warn),
+        rule("same_name", DuplicateRuleAction::Block),
+        rule("another_name", DuplicateRuleAction::Warn),
+    ] {
+        assert!(
+            repository
+                .save_duplicate_detection_rule(tenant_id, "alice", saved)
+                .await
+                .is_ok()
+        );
+    }
+    assert_eq!(
+        repository
+            .list_duplicate_detection_rules(tenant_id, "account")
+            .await
+            .unwrap_or_else(|_| unreachable!()),
+        vec![
+            rule("another_name", DuplicateRuleAction::Warn),
+            rule("same_name", DuplicateRuleAction::Block),
+        ]
+    );
+
+    assert!(
+        repository
+            .delete_duplicate_detection_rule(tenant_id, "account", "same_name")
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+    assert!(
+        !repository
+            .delete_duplicate_detection_rule(tenant_id, "account", "same_name")
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+    assert_eq!(
+        repository
+            .list_duplicate_detection_rules(tenant_id, "account")
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .len(),
+        1
+    );
+}
+
 #[tokio::test]
 async fn query_runtime_records_filters_and_paginates() {
     let Some(pool) = test_pool().await else {
@@ -451,6 +984,7 @@ async fn query_runtime_records_filters_and_paginates() {
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -464,6 +998,7 @@ async fn query_runtime_records_filters_and_paginates() {
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -477,6 +1012,7 @@ async fn query_runtime_records_filters_and_paginates() {
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -501,6 +1037,7 @@ async fn query_runtime_records_filters_and_paginates() {
                 links: Vec::new(),
                 sort: Vec::new(),
                 owner_subject: None,
+                include_inactive: false,
             },
         )
         .await;
@@ -517,6 +1054,144 @@ async fn query_runtime_records_filters_and_paginates() {
     );
 }
 
+#[tokio::test]
+async fn aggregate_runtime_records_groups_filters_and_ranks_groups() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresMetadataRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Aggregate Tenant").await;
+
+    let entity = EntityDefinition::new("deal", "Deal");
+    assert!(entity.is_ok());
+    assert!(
+        repository
+            .save_entity(tenant_id, entity.unwrap_or_else(|_| unreachable!()))
+            .await
+            .is_ok()
+    );
+
+    for (data, owner) in [
+        (json!({"stage": "open", "amount": 10}), "alice"),
+        (json!({"stage": "open", "amount": 30}), "alice"),
+        (json!({"stage": "won", "amount": 50}), "alice"),
+        (json!({"stage": "lost", "amount": 5}), "bob"),
+        (json!({"stage": "lost", "amount": null}), "alice"),
+    ] {
+        assert!(
+            repository
+                .create_runtime_record(tenant_id, "deal", data, Vec::new(), owner, None, None)
+                .await
+                .is_ok()
+        );
+    }
+
+    let count = |alias: &str| RuntimeRecordAggregate {
+        alias: alias.to_owned(),
+        function: RuntimeRecordAggregateFunction::Count,
+        field_logical_name: None,
+        field_type: None,
+    };
+    let amount = |alias: &str, function| RuntimeRecordAggregate {
+        alias: alias.to_owned(),
+        function,
+        field_logical_name: Some("amount".to_owned()),
+        field_type: Some(FieldType::Number),
+    };
+    let by_stage = RuntimeRecordAggregateQuery {
+        limit: 2,
+        logical_mode: RuntimeRecordLogicalMode::And,
+        where_clause: None,
+        filters: Vec::new(),
+        group_by: vec![RuntimeRecordGroupBy {
+            field_logical_name: "stage".to_owned(),
+            field_type: FieldType::Text,
+        }],
+        aggregates: vec![
+            count("deals"),
+            amount("total", RuntimeRecordAggregateFunction::Sum),
+            amount("smallest", RuntimeRecordAggregateFunction::Min),
+        ],
+        having: Vec::new(),
+        sort: vec![RuntimeRecordAggregateSort {
+            key: RuntimeRecordAggregateSortKey::Aggregate("total".to_owned()),
+            direction: RuntimeRecordSortDirection::Desc,
+        }],
+        owner_subject: None,
+    };
+
+    let top = repository
+        .aggregate_runtime_records(tenant_id, "deal", by_stage.clone())
+        .await
+        .unwrap_or_else(|error| panic!("aggregate failed: {error}"));
+    let number = |value: Option<&Value>| value.and_then(Value::as_f64);
+    assert_eq!(top.len(), 2);
+    assert_eq!(top[0].group.get("stage"), Some(&json!("won")));
+    assert_eq!(number(top[0].values.get("total")), Some(50.0));
+    assert_eq!(top[1].group.get("stage"), Some(&json!("open")));
+    assert_eq!(number(top[1].values.get("deals")), Some(2.0));
+    assert_eq!(number(top[1].values.get("total")), Some(40.0));
+    assert_eq!(number(top[1].values.get("smallest")), Some(10.0));
+
+    let owned = repository
+        .aggregate_runtime_records(
+            tenant_id,
+            "deal",
+            RuntimeRecordAggregateQuery {
+                limit: 10,
+                having: vec![RuntimeRecordAggregateHaving {
+                    aggregate_alias: "deals".to_owned(),
+                    operator: RuntimeRecordOperator::Gte,
+                    value: json!(2),
+                }],
+                sort: Vec::new(),
+                owner_subject: Some("alice".to_owned()),
+                ..by_stage.clone()
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("aggregate failed: {error}"));
+    assert_eq!(owned.len(), 1);
+    assert_eq!(owned[0].group.get("stage"), Some(&json!("open")));
+
+    let overall = repository
+        .aggregate_runtime_records(
+            tenant_id,
+            "deal",
+            RuntimeRecordAggregateQuery {
+                limit: 10,
+                filters: vec![RuntimeRecordFilter {
+                    scope_alias: None,
+                    field_logical_name: "stage".to_owned(),
+                    operator: RuntimeRecordOperator::Eq,
+                    field_type: FieldType::Text,
+                    field_value: json!("lost"),
+                }],
+                group_by: Vec::new(),
+                aggregates: vec![
+                    count("deals"),
+                    RuntimeRecordAggregate {
+                        field_logical_name: Some("amount".to_owned()),
+                        field_type: Some(FieldType::Number),
+                        ..count("priced")
+                    },
+                    amount("average", RuntimeRecordAggregateFunction::Avg),
+                ],
+                sort: Vec::new(),
+                ..by_stage
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("aggregate failed: {error}"));
+    assert_eq!(overall.len(), 1);
+    assert!(overall[0].group.is_empty());
+    assert_eq!(number(overall[0].values.get("deals")), Some(2.0));
+    assert_eq!(number(overall[0].values.get("priced")), Some(1.0));
+    assert_eq!(number(overall[0].values.get("average")), Some(5.0));
+}
+
 #[tokio::test]
 async fn query_runtime_records_supports_link_entity_alias_filters_and_where_groups() {
     let Some(pool) = test_pool().await else {
@@ -618,6 +1293,7 @@ async fn query_runtime_records_supports_link_entity_alias_filters_and_where_grou
             Vec::new(),
             "alice",
             None,
+            None,
         )
         .await;
     assert!(alice_contact.is_ok());
@@ -631,6 +1307,7 @@ async fn query_runtime_records_supports_link_entity_alias_filters_and_where_grou
             Vec::new(),
             "alice",
             None,
+            None,
         )
         .await;
     assert!(bob_contact.is_ok());
@@ -645,6 +1322,7 @@ async fn query_runtime_records_supports_link_entity_alias_filters_and_where_grou
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -658,6 +1336,7 @@ async fn query_runtime_records_supports_link_entity_alias_filters_and_where_grou
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -688,9 +1367,11 @@ async fn query_runtime_records_supports_link_entity_alias_filters_and_where_grou
                     relation_field_logical_name: "owner_contact_id".to_owned(),
                     target_entity_logical_name: "contact".to_owned(),
                     join_type: RuntimeRecordJoinType::Inner,
+                    cardinality: RuntimeRecordLinkCardinality::ManyToOne,
                 }],
                 sort: Vec::new(),
                 owner_subject: None,
+                include_inactive: false,
             },
         )
         .await;
@@ -706,6 +1387,166 @@ async fn query_runtime_records_supports_link_entity_alias_filters_and_where_grou
     );
 }
 
+#[tokio::test]
+async fn query_runtime_records_traverses_many_to_many_links_once_per_root() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresMetadataRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Many To Many Tenant").await;
+
+    for (logical_name, display_name) in [("deal", "Deal"), ("tag", "Tag")] {
+        let entity =
+            EntityDefinition::new(logical_name, display_name).unwrap_or_else(|_| unreachable!());
+        assert!(repository.save_entity(tenant_id, entity).await.is_ok());
+    }
+
+    let mut deal_ids = Vec::new();
+    for title in ["Alpha", "Beta", "Gamma"] {
+        let deal = repository
+            .create_runtime_record(
+                tenant_id,
+                "deal",
+                json!({"title": title}),
+                Vec::new(),
+                "alice",
+                None,
+                None,
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        deal_ids.push(deal.record_id().as_str().to_owned());
+    }
+    let mut tag_ids = Vec::new();
+    for name in ["urgent", "vip"] {
+        let tag = repository
+            .create_runtime_record(
+                tenant_id,
+                "tag",
+                json!({"name": name}),
+                Vec::new(),
+                "alice",
+                None,
+                None,
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        tag_ids.push(tag.record_id().as_str().to_owned());
+    }
+
+    let association = |record_id: &str, target_record_id: &str| RuntimeRecordAssociation {
+        entity_logical_name: "deal".to_owned(),
+        field_logical_name: "tags".to_owned(),
+        record_id: record_id.to_owned(),
+        target_entity_logical_name: "tag".to_owned(),
+        target_record_id: target_record_id.to_owned(),
+    };
+    for (deal_index, tag_index) in [(0, 0), (0, 1), (1, 1)] {
+        let created = repository
+            .associate_runtime_records(
+                tenant_id,
+                "alice",
+                association(&deal_ids[deal_index], &tag_ids[tag_index]),
+            )
+            .await;
+        assert!(matches!(created, Ok(true)));
+    }
+    let duplicate = repository
+        .associate_runtime_records(tenant_id, "alice", association(&deal_ids[0], &tag_ids[0]))
+        .await;
+    assert!(matches!(duplicate, Ok(false)));
+
+    let tag_query = |join_type, tag_name: Option<&str>| RuntimeRecordQuery {
+        limit: 50,
+        offset: 0,
+        logical_mode: RuntimeRecordLogicalMode::And,
+        where_clause: None,
+        filters: tag_name
+            .map(|tag_name| {
+                vec![RuntimeRecordFilter {
+                    scope_alias: Some("tag".to_owned()),
+                    field_logical_name: "name".to_owned(),
+                    operator: RuntimeRecordOperator::Eq,
+                    field_type: FieldType::Text,
+                    field_value: json!(tag_name),
+                }]
+            })
+            .unwrap_or_default(),
+        links: vec![RuntimeRecordLink {
+            alias: "tag".to_owned(),
+            parent_alias: None,
+            relation_field_logical_name: "tags".to_owned(),
+            target_entity_logical_name: "tag".to_owned(),
+            join_type,
+            cardinality: RuntimeRecordLinkCardinality::ManyToMany,
+        }],
+        sort: vec![RuntimeRecordSort {
+            scope_alias: None,
+            field_logical_name: "title".to_owned(),
+            field_type: FieldType::Text,
+            direction: RuntimeRecordSortDirection::Asc,
+        }],
+        owner_subject: None,
+        include_inactive: false,
+    };
+    let titles = |records: Vec<qryvanta_domain::RuntimeRecord>| {
+        records
+            .iter()
+            .filter_map(|record| record.data().get("title").and_then(Value::as_str))
+            .map(str::to_owned)
+            .collect::<Vec<_>>()
+    };
+
+    let vip_deals = repository
+        .query_runtime_records(
+            tenant_id,
+            "deal",
+            tag_query(RuntimeRecordJoinType::Inner, Some("vip")),
+        )
+        .await;
+    assert!(vip_deals.is_ok());
+    assert_eq!(titles(vip_deals.unwrap_or_default()), vec!["Alpha", "Beta"]);
+
+    let all_deals = repository
+        .query_runtime_records(
+            tenant_id,
+            "deal",
+            tag_query(RuntimeRecordJoinType::Left, None),
+        )
+        .await;
+    assert!(all_deals.is_ok());
+    assert_eq!(
+        titles(all_deals.unwrap_or_default()),
+        vec!["Alpha", "Beta", "Gamma"]
+    );
+
+    assert!(
+        repository
+            .delete_runtime_record(
+                tenant_id,
+                "tag",
+                &tag_ids[0],
+                None,
+                None,
+                &RelationDeletePlan::default(),
+            )
+            .await
+            .is_ok()
+    );
+    let remaining = repository
+        .list_associated_runtime_record_ids(tenant_id, "deal", "tags", &deal_ids[0])
+        .await
+        .unwrap_or_default();
+    assert_eq!(remaining, vec![tag_ids[1].clone()]);
+
+    let removed = repository
+        .disassociate_runtime_records(tenant_id, &association(&deal_ids[1], &tag_ids[1]))
+        .await;
+    assert!(matches!(removed, Ok(true)));
+}
+
 #[tokio::test]
 async fn relation_reference_check_does_not_leak_across_tenants() {
     let Some(pool) = test_pool().await else {
@@ -825,6 +1666,7 @@ async fn relation_reference_check_does_not_leak_across_tenants() {
             Vec::new(),
             "alice",
             None,
+            None,
         )
         .await;
     assert!(left_contact_record.is_ok());
@@ -839,6 +1681,7 @@ async fn relation_reference_check_does_not_leak_across_tenants() {
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -863,6 +1706,7 @@ async fn relation_reference_check_does_not_leak_across_tenants() {
                 Vec::new(),
                 "alice",
                 None,
+                None,
             )
             .await
             .is_ok()
@@ -878,3 +1722,162 @@ async fn relation_reference_check_does_not_leak_across_tenants() {
     assert!(in_tenant_reference.is_ok());
     assert!(in_tenant_reference.unwrap_or(false));
 }
+
+#[tokio::test]
+async fn rollback_entity_schema_republishes_version_with_snapshots() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresMetadataRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Schema Rollback Tenant").await;
+
+    let contact = EntityDefinition::new("contact", "Contact").unwrap_or_else(|_| unreachable!());
+    assert!(
+        repository
+            .save_entity(tenant_id, contact.clone())
+            .await
+            .is_ok()
+    );
+    let name = EntityFieldDefinition::new(
+        "contact",
+        "name",
+        "Name",
+        FieldType::Text,
+        true,
+        false,
+        None,
+        None,
+    )
+    .unwrap_or_else(|_| unreachable!());
+    let nickname = EntityFieldDefinition::new(
+        "contact",
+        "nickname",
+        "Nickname",
+        FieldType::Text,
+        false,
+        false,
+        None,
+        None,
+    )
+    .unwrap_or_else(|_| unreachable!());
+
+    let first = repository
+        .publish_entity_schema(
+            tenant_id,
+            contact.clone(),
+            vec![name.clone()],
+            Vec::new(),
+            "alice",
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(
+        repository
+            .save_published_form_snapshots(
+                tenant_id,
+                "contact",
+                first.version(),
+                &[minimal_form("contact", "main_form")],
+            )
+            .await
+            .is_ok()
+    );
+    assert!(
+        repository
+            .publish_entity_schema(
+                tenant_id,
+                contact,
+                vec![name, nickname],
+                Vec::new(),
+                "alice"
+            )
+            .await
+            .is_ok()
+    );
+
+    let rolled_back = repository
+        .rollback_entity_schema(tenant_id, "contact", first.version(), "alice")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(rolled_back.version(), 3);
+    assert_eq!(rolled_back.fields().len(), 1);
+
+    let latest = repository
+        .latest_published_schema(tenant_id, "contact")
+        .await
+        .unwrap_or_default()
+        .map(|schema| schema.version());
+    assert_eq!(latest, Some(3));
+    let forms = repository
+        .list_latest_published_form_snapshots(tenant_id, "contact")
+        .await
+        .unwrap_or_default();
+    assert_eq!(forms.len(), 1);
+    assert!(
+        repository
+            .find_published_schema(tenant_id, "contact", 2)
+            .await
+            .unwrap_or_default()
+            .is_some()
+    );
+
+    let history = repository
+        .list_published_schema_versions(tenant_id, "contact")
+        .await
+        .unwrap_or_default();
+    assert_eq!(
+        history
+            .iter()
+            .map(|stored| stored.schema.version())
+            .collect::<Vec<_>>(),
+        vec![3, 2, 1]
+    );
+    assert!(
+        history
+            .iter()
+            .all(|stored| stored.published_by_subject == "alice")
+    );
+
+    let missing = repository
+        .rollback_entity_schema(tenant_id, "contact", 9, "alice")
+        .await;
+    assert!(matches!(missing, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn operation_budget_cancels_statements_in_tenant_transactions() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+    let tenant_id = TenantId::new();
+
+    let deadline = OperationDeadline::start(
+        TimedOperation::RuntimeQuery,
+        std::time::Duration::from_millis(50),
+    );
+    let result = deadline
+        .scope(async {
+            let mut transaction = begin_tenant_transaction(&pool, tenant_id).await?;
+            sqlx::query("SELECT pg_sleep(5)")
+                .execute(&mut *transaction)
+                .await
+                .map_err(|error| AppError::Internal(error.to_string()))?;
+            Ok::<_, AppError>(())
+        })
+        .await;
+    let Err(AppError::Internal(message)) = result else {
+        panic!("expected cancelled statement, got {result:?}");
+    };
+    assert!(message.contains("statement timeout"), "{message}");
+
+    let mut transaction = begin_tenant_transaction(&pool, tenant_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let statement_timeout: String = sqlx::query_scalar("SHOW statement_timeout")
+        .fetch_one(&mut *transaction)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(statement_timeout, "0");
+}
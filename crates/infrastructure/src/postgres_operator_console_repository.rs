@@ -0,0 +1,239 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{OperatorAuditEvent, OperatorConsoleRepository, OperatorTenantUsage};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for operator console reads and the operator log.
+///
+/// Tenant usage is counted inside one tenant-scoped transaction per tenant,
+/// so the console never needs a row-level security bypass.
+#[derive(Clone)]
+pub struct PostgresOperatorConsoleRepository {
+    pool: PgPool,
+}
+
+impl PostgresOperatorConsoleRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct TenantRow {
+    id: uuid::Uuid,
+    name: String,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct TenantUsageRow {
+    member_count: i64,
+    entity_count: i64,
+    runtime_record_count: i64,
+    pending_workflow_jobs: i64,
+    failed_workflow_jobs_last_day: i64,
+    expired_workflow_leases: i64,
+    last_activity_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, FromRow)]
+struct OperatorAuditEventRow {
+    id: uuid::Uuid,
+    operator_id: String,
+    action: String,
+    tenant_id: Option<uuid::Uuid>,
+    detail: String,
+    created_at: DateTime<Utc>,
+}
+
+impl From<OperatorAuditEventRow> for OperatorAuditEvent {
+    fn from(row: OperatorAuditEventRow) -> Self {
+        Self {
+            event_id: row.id.to_string(),
+            operator_id: row.operator_id,
+            action: row.action,
+            tenant_id: row.tenant_id.map(TenantId::from_uuid),
+            detail: row.detail,
+            created_at: row.created_at,
+        }
+    }
+}
+
+fn commit_error(error: sqlx::Error) -> AppError {
+    AppError::Internal(format!(
+        "failed to commit operator console transaction: {error}"
+    ))
+}
+
+#[async_trait]
+impl OperatorConsoleRepository for PostgresOperatorConsoleRepository {
+    async fn list_tenant_usage(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<OperatorTenantUsage>> {
+        let limit = i64::try_from(limit).map_err(|_| {
+            AppError::Validation("operator tenant limit is out of range".to_owned())
+        })?;
+        let offset = i64::try_from(offset).map_err(|_| {
+            AppError::Validation("operator tenant offset is out of range".to_owned())
+        })?;
+
+        let tenants = sqlx::query_as::<_, TenantRow>(
+            r#"
+            SELECT id, name, created_at
+            FROM tenants
+            ORDER BY created_at ASC, id ASC
+            LIMIT $1 OFFSET $2
+            "#,
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list tenants: {error}")))?;
+
+        let mut usage = Vec::with_capacity(tenants.len());
+        for tenant in tenants {
+            let tenant_id = TenantId::from_uuid(tenant.id);
+            let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+            let counts = sqlx::query_as::<_, TenantUsageRow>(
+                r#"
+                SELECT
+                    (SELECT COUNT(*) FROM tenant_memberships WHERE tenant_id = $1) AS member_count,
+                    (SELECT COUNT(*) FROM entity_definitions WHERE tenant_id = $1) AS entity_count,
+                    (SELECT COUNT(*) FROM runtime_records WHERE tenant_id = $1)
+                        AS runtime_record_count,
+                    (
+                        SELECT COUNT(*)
+                        FROM workflow_execution_jobs
+                        WHERE tenant_id = $1 AND status = 'pending'
+                    ) AS pending_workflow_jobs,
+                    (
+                        SELECT COUNT(*)
+                        FROM workflow_execution_jobs
+                        WHERE tenant_id = $1
+                          AND status = 'failed'
+                          AND updated_at > now() - INTERVAL '1 day'
+                    ) AS failed_workflow_jobs_last_day,
+                    (
+                        SELECT COUNT(*)
+                        FROM workflow_execution_jobs
+                        WHERE tenant_id = $1 AND status = 'leased' AND lease_expires_at < now()
+                    ) AS expired_workflow_leases,
+                    (SELECT MAX(created_at) FROM audit_log_entries WHERE tenant_id = $1)
+                        AS last_activity_at
+                "#,
+            )
+            .bind(tenant.id)
+            .fetch_one(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to load usage for tenant '{tenant_id}': {error}"
+                ))
+            })?;
+            transaction.commit().await.map_err(commit_error)?;
+
+            usage.push(OperatorTenantUsage {
+                tenant_id,
+                name: tenant.name,
+                created_at: tenant.created_at,
+                member_count: counts.member_count,
+                entity_count: counts.entity_count,
+                runtime_record_count: counts.runtime_record_count,
+                pending_workflow_jobs: counts.pending_workflow_jobs,
+                failed_workflow_jobs_last_day: counts.failed_workflow_jobs_last_day,
+                expired_workflow_leases: counts.expired_workflow_leases,
+                last_activity_at: counts.last_activity_at,
+            });
+        }
+
+        Ok(usage)
+    }
+
+    async fn find_tenant_name(&self, tenant_id: TenantId) -> AppResult<Option<String>> {
+        sqlx::query_scalar::<_, String>("SELECT name FROM tenants WHERE id = $1")
+            .bind(tenant_id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|error| AppError::Internal(format!("failed to find tenant: {error}")))
+    }
+
+    async fn list_entity_logical_names(&self, tenant_id: TenantId) -> AppResult<Vec<String>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let names = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT logical_name
+            FROM entity_definitions
+            WHERE tenant_id = $1
+            ORDER BY logical_name ASC
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list tenant entities: {error}")))?;
+        transaction.commit().await.map_err(commit_error)?;
+
+        Ok(names)
+    }
+
+    async fn append_audit_event(
+        &self,
+        operator_id: &str,
+        action: &str,
+        tenant_id: Option<TenantId>,
+        detail: &str,
+    ) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO operator_audit_events (operator_id, action, tenant_id, detail)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(operator_id)
+        .bind(action)
+        .bind(tenant_id.map(|tenant_id| tenant_id.as_uuid()))
+        .bind(detail)
+        .execute(&self.pool)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to append operator audit event: {error}"))
+        })?;
+
+        Ok(())
+    }
+
+    async fn list_audit_events(&self, limit: usize) -> AppResult<Vec<OperatorAuditEvent>> {
+        let limit = i64::try_from(limit).map_err(|_| {
+            AppError::Validation("operator audit log limit is out of range".to_owned())
+        })?;
+
+        let rows = sqlx::query_as::<_, OperatorAuditEventRow>(
+            r#"
+            SELECT id, operator_id, action, tenant_id, detail, created_at
+            FROM operator_audit_events
+            ORDER BY created_at DESC, id DESC
+            LIMIT $1
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list operator audit events: {error}"))
+        })?;
+
+        Ok(rows.into_iter().map(OperatorAuditEvent::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests;
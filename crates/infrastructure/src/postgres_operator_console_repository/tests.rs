@@ -0,0 +1,166 @@
+use chrono::{Duration, Utc};
+use qryvanta_application::{AuditEvent, AuditRepository, OperatorConsoleRepository};
+use qryvanta_core::TenantId;
+use qryvanta_domain::AuditAction;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresOperatorConsoleRepository;
+use crate::{PostgresAuditRepository, begin_tenant_transaction};
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres operator console tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn seed_tenant_rows(pool: &PgPool, tenant_id: TenantId) {
+    let mut transaction = begin_tenant_transaction(pool, tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to begin tenant transaction: {error}"));
+    for statement in [
+        "INSERT INTO tenant_memberships (tenant_id, subject, display_name) VALUES ($1, 'alice', 'Alice')",
+        "INSERT INTO tenant_memberships (tenant_id, subject, display_name) VALUES ($1, 'bob', 'Bob')",
+        "INSERT INTO entity_definitions (tenant_id, logical_name, display_name) VALUES ($1, 'contact', 'Contact')",
+        "INSERT INTO entity_definitions (tenant_id, logical_name, display_name) VALUES ($1, 'account', 'Account')",
+        "INSERT INTO runtime_records (tenant_id, entity_logical_name, data, created_by_subject) \
+         VALUES ($1, 'contact', '{}'::jsonb, 'alice')",
+    ] {
+        let inserted = sqlx::query(statement)
+            .bind(tenant_id.as_uuid())
+            .execute(&mut *transaction)
+            .await;
+        assert!(inserted.is_ok(), "{statement}: {inserted:?}");
+    }
+    assert!(transaction.commit().await.is_ok());
+
+    let audited = PostgresAuditRepository::new(pool.clone())
+        .append_event(AuditEvent {
+            tenant_id,
+            subject: "alice".to_owned(),
+            action: AuditAction::MetadataEntityCreated,
+            resource_type: "entity_definition".to_owned(),
+            resource_id: "contact".to_owned(),
+            detail: None,
+        })
+        .await;
+    assert!(audited.is_ok());
+}
+
+#[tokio::test]
+async fn tenant_usage_counts_each_tenant_and_operator_log_is_global() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresOperatorConsoleRepository::new(pool.clone());
+    let busy_tenant = TenantId::new();
+    let idle_tenant = TenantId::new();
+    let created_at = Utc::now() + Duration::days(365 * 1000);
+    for (tenant_id, name, created_at) in [
+        (busy_tenant, "Operator Busy Tenant", created_at),
+        (
+            idle_tenant,
+            "Operator Idle Tenant",
+            created_at + Duration::seconds(1),
+        ),
+    ] {
+        let inserted =
+            sqlx::query("INSERT INTO tenants (id, name, created_at) VALUES ($1, $2, $3)")
+                .bind(tenant_id.as_uuid())
+                .bind(name)
+                .bind(created_at)
+                .execute(&pool)
+                .await;
+        assert!(inserted.is_ok());
+    }
+    seed_tenant_rows(&pool, busy_tenant).await;
+
+    let offset = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM tenants WHERE created_at < $1")
+        .bind(created_at)
+        .fetch_one(&pool)
+        .await
+        .unwrap_or_else(|error| panic!("failed to count tenants: {error}"));
+    let usage = repository
+        .list_tenant_usage(
+            2,
+            usize::try_from(offset).unwrap_or_else(|_| unreachable!()),
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to list tenant usage: {error}"));
+    assert_eq!(usage.len(), 2);
+    assert_eq!(usage[0].tenant_id, busy_tenant);
+    assert_eq!(usage[0].name, "Operator Busy Tenant");
+    assert_eq!(usage[0].member_count, 2);
+    assert_eq!(usage[0].entity_count, 2);
+    assert_eq!(usage[0].runtime_record_count, 1);
+    assert_eq!(usage[0].pending_workflow_jobs, 0);
+    assert!(usage[0].last_activity_at.is_some());
+    assert_eq!(usage[1].tenant_id, idle_tenant);
+    assert_eq!(usage[1].member_count, 0);
+    assert!(usage[1].last_activity_at.is_none());
+
+    assert_eq!(
+        repository
+            .find_tenant_name(idle_tenant)
+            .await
+            .unwrap_or_else(|error| panic!("failed to find tenant: {error}"))
+            .as_deref(),
+        Some("Operator Idle Tenant")
+    );
+    assert!(
+        repository
+            .find_tenant_name(TenantId::new())
+            .await
+            .unwrap_or_else(|error| panic!("failed to find tenant: {error}"))
+            .is_none()
+    );
+    assert_eq!(
+        repository
+            .list_entity_logical_names(busy_tenant)
+            .await
+            .unwrap_or_else(|error| panic!("failed to list entities: {error}")),
+        vec!["account".to_owned(), "contact".to_owned()]
+    );
+
+    let operator_id = format!("ops-{}", uuid::Uuid::new_v4());
+    for (action, tenant_id) in [
+        ("list_tenants", None),
+        ("flush_view_cache", Some(busy_tenant)),
+    ] {
+        let appended = repository
+            .append_audit_event(operator_id.as_str(), action, tenant_id, "detail")
+            .await;
+        assert!(appended.is_ok());
+    }
+    let events: Vec<_> = repository
+        .list_audit_events(500)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list operator log: {error}"))
+        .into_iter()
+        .filter(|event| event.operator_id == operator_id)
+        .collect();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].action, "flush_view_cache");
+    assert_eq!(events[0].tenant_id, Some(busy_tenant));
+    assert_eq!(events[1].action, "list_tenants");
+    assert_eq!(events[1].tenant_id, None);
+}
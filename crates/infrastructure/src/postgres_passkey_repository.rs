@@ -1,5 +1,22 @@
+use chrono::{DateTime, Utc};
 use qryvanta_core::{AppError, AppResult};
 use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Registered passkey with its user-facing management metadata.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PasskeyCredentialRecord {
+    /// Stable passkey identifier.
+    pub id: Uuid,
+    /// User-chosen display name.
+    pub name: String,
+    /// Serialized WebAuthn credential state.
+    pub credential_json: String,
+    /// Registration timestamp.
+    pub created_at: DateTime<Utc>,
+    /// Last successful assertion, if the passkey was ever used.
+    pub last_used_at: Option<DateTime<Utc>>,
+}
 
 /// PostgreSQL-backed passkey credential persistence.
 #[derive(Clone)]
@@ -14,11 +31,31 @@ impl PostgresPasskeyRepository {
         Self { pool }
     }
 
-    /// Lists all passkey credential payloads for a subject.
-    pub async fn list_by_subject(&self, subject: &str) -> AppResult<Vec<String>> {
-        let records = sqlx::query_scalar::<_, String>(
+    /// Persists a passkey credential payload for a subject.
+    pub async fn insert_for_subject(&self, subject: &str, credential_json: &str) -> AppResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO passkey_credentials (subject, credential_json)
+            VALUES ($1, $2::jsonb)
+            "#,
+        )
+        .bind(subject)
+        .bind(credential_json)
+        .execute(&self.pool)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to insert passkey: {error}")))?;
+
+        Ok(())
+    }
+
+    /// Lists the registered passkeys of a subject with their management metadata.
+    pub async fn list_records_by_subject(
+        &self,
+        subject: &str,
+    ) -> AppResult<Vec<PasskeyCredentialRecord>> {
+        sqlx::query_as::<_, PasskeyCredentialRecord>(
             r#"
-            SELECT credential_json::text
+            SELECT id, name, credential_json::text AS credential_json, created_at, last_used_at
             FROM passkey_credentials
             WHERE subject = $1
             ORDER BY created_at ASC
@@ -27,67 +64,76 @@ impl PostgresPasskeyRepository {
         .bind(subject)
         .fetch_all(&self.pool)
         .await
-        .map_err(|error| AppError::Internal(format!("failed to list passkeys: {error}")))?;
-
-        Ok(records)
+        .map_err(|error| AppError::Internal(format!("failed to list passkeys: {error}")))
     }
 
-    /// Persists a passkey credential payload for a subject.
-    pub async fn insert_for_subject(&self, subject: &str, credential_json: &str) -> AppResult<()> {
+    /// Stores the updated credential state after a successful assertion and
+    /// marks the passkey as used.
+    pub async fn record_use_for_subject(
+        &self,
+        subject: &str,
+        passkey_id: Uuid,
+        credential_json: &str,
+    ) -> AppResult<()> {
         sqlx::query(
             r#"
-            INSERT INTO passkey_credentials (subject, credential_json)
-            VALUES ($1, $2::jsonb)
+            UPDATE passkey_credentials
+            SET credential_json = $3::jsonb,
+                last_used_at = now()
+            WHERE subject = $1 AND id = $2
             "#,
         )
         .bind(subject)
+        .bind(passkey_id)
         .bind(credential_json)
         .execute(&self.pool)
         .await
-        .map_err(|error| AppError::Internal(format!("failed to insert passkey: {error}")))?;
+        .map_err(|error| AppError::Internal(format!("failed to update passkey: {error}")))?;
 
         Ok(())
     }
 
-    /// Replaces all passkeys for a subject with the supplied payloads.
-    pub async fn replace_for_subject(
+    /// Renames one passkey of a subject. Returns `None` when the subject has
+    /// no passkey with that id.
+    pub async fn rename_for_subject(
         &self,
         subject: &str,
-        passkeys_json: &[String],
-    ) -> AppResult<()> {
-        let mut transaction =
-            self.pool.begin().await.map_err(|error| {
-                AppError::Internal(format!("failed to begin transaction: {error}"))
-            })?;
+        passkey_id: Uuid,
+        name: &str,
+    ) -> AppResult<Option<PasskeyCredentialRecord>> {
+        sqlx::query_as::<_, PasskeyCredentialRecord>(
+            r#"
+            UPDATE passkey_credentials
+            SET name = $3
+            WHERE subject = $1 AND id = $2
+            RETURNING id, name, credential_json::text AS credential_json, created_at, last_used_at
+            "#,
+        )
+        .bind(subject)
+        .bind(passkey_id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to rename passkey: {error}")))
+    }
 
-        sqlx::query(
+    /// Deletes one passkey of a subject. Returns whether a passkey was removed.
+    pub async fn delete_for_subject(&self, subject: &str, passkey_id: Uuid) -> AppResult<bool> {
+        let result = sqlx::query(
             r#"
             DELETE FROM passkey_credentials
-            WHERE subject = $1
+            WHERE subject = $1 AND id = $2
             "#,
         )
         .bind(subject)
-        .execute(&mut *transaction)
+        .bind(passkey_id)
+        .execute(&self.pool)
         .await
-        .map_err(|error| AppError::Internal(format!("failed to delete passkeys: {error}")))?;
+        .map_err(|error| AppError::Internal(format!("failed to delete passkey: {error}")))?;
 
-        for credential_json in passkeys_json {
-            sqlx::query(
-                r#"
-                INSERT INTO passkey_credentials (subject, credential_json)
-                VALUES ($1, $2::jsonb)
-                "#,
-            )
-            .bind(subject)
-            .bind(credential_json)
-            .execute(&mut *transaction)
-            .await
-            .map_err(|error| AppError::Internal(format!("failed to upsert passkey: {error}")))?;
-        }
-
-        transaction
-            .commit()
-            .await
-            .map_err(|error| AppError::Internal(format!("failed to commit transaction: {error}")))
+        Ok(result.rows_affected() > 0)
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,103 @@
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+use uuid::Uuid;
+
+use super::PostgresPasskeyRepository;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres passkey tests: {error}");
+    }
+
+    Some(pool)
+}
+
+#[tokio::test]
+async fn passkeys_are_renamed_used_and_deleted_per_subject() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+    let repository = PostgresPasskeyRepository::new(pool);
+    let subject = Uuid::new_v4().to_string();
+    let other_subject = Uuid::new_v4().to_string();
+
+    repository
+        .insert_for_subject(subject.as_str(), r#"{"cred":{"counter":0}}"#)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    repository
+        .insert_for_subject(other_subject.as_str(), r#"{"cred":{"counter":0}}"#)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let records = repository
+        .list_records_by_subject(subject.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].name, "Passkey");
+    assert!(records[0].last_used_at.is_none());
+    let passkey_id = records[0].id;
+
+    let foreign_rename = repository
+        .rename_for_subject(other_subject.as_str(), passkey_id, "Stolen")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(foreign_rename.is_none());
+
+    let renamed = repository
+        .rename_for_subject(subject.as_str(), passkey_id, "Work laptop")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(
+        renamed.map(|record| record.name).as_deref(),
+        Some("Work laptop")
+    );
+
+    repository
+        .record_use_for_subject(subject.as_str(), passkey_id, r#"{"cred":{"counter":1}}"#)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let used = repository
+        .list_records_by_subject(subject.as_str())
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(used[0].last_used_at.is_some());
+    assert!(used[0].credential_json.contains("\"counter\": 1"));
+
+    assert!(
+        !repository
+            .delete_for_subject(other_subject.as_str(), passkey_id)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+    assert!(
+        repository
+            .delete_for_subject(subject.as_str(), passkey_id)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+    );
+    assert!(
+        repository
+            .list_records_by_subject(subject.as_str())
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+}
@@ -0,0 +1,235 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    PersonalAuthEventEntry, PersonalDataExportPart, PersonalDataExportRepository,
+    PersonalDataExportSection,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for personal data export archives.
+#[derive(Clone)]
+pub struct PostgresPersonalDataExportRepository {
+    pool: PgPool,
+}
+
+impl PostgresPersonalDataExportRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct PersonalDataExportPartRow {
+    section: String,
+    entity_logical_name: String,
+    item_offset: i64,
+    items: Value,
+}
+
+impl TryFrom<PersonalDataExportPartRow> for PersonalDataExportPart {
+    type Error = AppError;
+
+    fn try_from(row: PersonalDataExportPartRow) -> Result<Self, Self::Error> {
+        let items = match row.items {
+            Value::Array(items) => items,
+            _ => {
+                return Err(AppError::Internal(
+                    "personal data export part items must be a JSON array".to_owned(),
+                ));
+            }
+        };
+
+        Ok(Self {
+            section: PersonalDataExportSection::parse(row.section.as_str())?,
+            entity_logical_name: (!row.entity_logical_name.is_empty())
+                .then_some(row.entity_logical_name),
+            item_offset: u64::try_from(row.item_offset).unwrap_or(0),
+            items,
+        })
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct PersonalAuthEventRow {
+    event_type: String,
+    outcome: String,
+    ip_address: Option<String>,
+    user_agent: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+fn parse_job_id(job_id: &str) -> AppResult<uuid::Uuid> {
+    uuid::Uuid::parse_str(job_id)
+        .map_err(|_| AppError::NotFound(format!("personal data export '{job_id}' does not exist")))
+}
+
+#[async_trait]
+impl PersonalDataExportRepository for PostgresPersonalDataExportRepository {
+    async fn save_export_part(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        subject: &str,
+        part: &PersonalDataExportPart,
+    ) -> AppResult<()> {
+        let job_uuid = parse_job_id(job_id)?;
+        let item_offset = i64::try_from(part.item_offset).map_err(|_| {
+            AppError::Validation("personal data export offset is out of range".to_owned())
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO personal_data_export_parts (
+                tenant_id,
+                job_id,
+                subject,
+                section,
+                entity_logical_name,
+                item_offset,
+                items
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (tenant_id, job_id, section, entity_logical_name, item_offset)
+            DO UPDATE SET items = EXCLUDED.items, created_at = now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .bind(subject)
+        .bind(part.section.as_str())
+        .bind(part.entity_logical_name.as_deref().unwrap_or_default())
+        .bind(item_offset)
+        .bind(Value::Array(part.items.clone()))
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to save personal data export part: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit personal data export transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    async fn list_export_parts(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Vec<PersonalDataExportPart>> {
+        let job_uuid = parse_job_id(job_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, PersonalDataExportPartRow>(
+            r#"
+            SELECT section, entity_logical_name, item_offset, items
+            FROM personal_data_export_parts
+            WHERE tenant_id = $1 AND job_id = $2
+            ORDER BY section, entity_logical_name, item_offset
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list personal data export parts: {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit personal data export transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(PersonalDataExportPart::try_from)
+            .collect()
+    }
+
+    async fn delete_exports_for_subject(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<u64> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM personal_data_export_parts
+            WHERE tenant_id = $1 AND subject = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to delete personal data exports: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit personal data export transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn list_auth_events_for_subject(
+        &self,
+        subject: &str,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<PersonalAuthEventEntry>> {
+        let limit = i64::try_from(limit)
+            .map_err(|_| AppError::Validation("auth event page size is out of range".to_owned()))?;
+        let offset = i64::try_from(offset)
+            .map_err(|_| AppError::Validation("auth event offset is out of range".to_owned()))?;
+
+        let rows = sqlx::query_as::<_, PersonalAuthEventRow>(
+            r#"
+            SELECT event_type, outcome, ip_address, user_agent, created_at
+            FROM auth_events
+            WHERE subject = $1
+            ORDER BY created_at, id
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(subject)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list auth events for subject: {error}"))
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| PersonalAuthEventEntry {
+                event_type: row.event_type,
+                outcome: row.outcome,
+                ip_address: row.ip_address,
+                user_agent: row.user_agent,
+                created_at: row.created_at,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,181 @@
+use qryvanta_application::{
+    AuthEvent, AuthEventRepository, BackgroundJobKind, BackgroundJobRepository,
+    CreateBackgroundJobInput, PersonalDataExportPart, PersonalDataExportRepository,
+    PersonalDataExportSection,
+};
+use qryvanta_core::TenantId;
+use qryvanta_domain::{AuthEventOutcome, AuthEventType};
+use serde_json::json;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresPersonalDataExportRepository;
+use crate::{PostgresAuthEventRepository, PostgresBackgroundJobRepository};
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres personal data export tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn ensure_tenant(pool: &PgPool, tenant_id: TenantId, name: &str) {
+    let insert = sqlx::query(
+        r#"
+            INSERT INTO tenants (id, name)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(name)
+    .execute(pool)
+    .await;
+
+    assert!(insert.is_ok());
+}
+
+async fn create_export_job(pool: &PgPool, tenant_id: TenantId, subject: &str) -> String {
+    PostgresBackgroundJobRepository::new(pool.clone())
+        .create_background_job(
+            tenant_id,
+            subject,
+            subject,
+            None,
+            CreateBackgroundJobInput {
+                kind: BackgroundJobKind::PersonalDataExport,
+                parameters: json!({ "entity_logical_names": [] }),
+                stages: Vec::new(),
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to create background job: {error}"))
+        .job_id
+}
+
+fn records_part(offset: u64, record_id: &str) -> PersonalDataExportPart {
+    PersonalDataExportPart {
+        section: PersonalDataExportSection::OwnedRecords,
+        entity_logical_name: Some("contact".to_owned()),
+        item_offset: offset,
+        items: vec![json!({ "record_id": record_id })],
+    }
+}
+
+#[tokio::test]
+async fn export_parts_replace_by_position_and_stay_within_their_tenant() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresPersonalDataExportRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Personal Data Export Tenant").await;
+    ensure_tenant(&pool, other_tenant_id, "Personal Data Export Other Tenant").await;
+    let subject = format!("export-{}", uuid::Uuid::new_v4());
+    let job_id = create_export_job(&pool, tenant_id, subject.as_str()).await;
+
+    let profile = PersonalDataExportPart {
+        section: PersonalDataExportSection::Profile,
+        entity_logical_name: None,
+        item_offset: 0,
+        items: vec![json!({ "subject": subject })],
+    };
+    for part in [
+        &records_part(1, "stale"),
+        &records_part(0, "record-1"),
+        &records_part(1, "record-2"),
+        &profile,
+    ] {
+        let saved = repository
+            .save_export_part(tenant_id, job_id.as_str(), subject.as_str(), part)
+            .await;
+        assert!(saved.is_ok());
+    }
+
+    let parts = repository
+        .list_export_parts(tenant_id, job_id.as_str())
+        .await
+        .unwrap_or_else(|error| panic!("failed to list export parts: {error}"));
+    assert_eq!(
+        parts,
+        vec![
+            records_part(0, "record-1"),
+            records_part(1, "record-2"),
+            profile
+        ]
+    );
+    assert!(
+        repository
+            .list_export_parts(other_tenant_id, job_id.as_str())
+            .await
+            .unwrap_or_else(|error| panic!("failed to list export parts: {error}"))
+            .is_empty()
+    );
+
+    let deleted = repository
+        .delete_exports_for_subject(tenant_id, subject.as_str())
+        .await
+        .unwrap_or_else(|error| panic!("failed to delete exports: {error}"));
+    assert_eq!(deleted, 3);
+}
+
+#[tokio::test]
+async fn auth_events_are_listed_oldest_first_per_subject() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresPersonalDataExportRepository::new(pool.clone());
+    let events = PostgresAuthEventRepository::new(pool.clone());
+    let subject = format!("export-{}", uuid::Uuid::new_v4());
+    for event_type in [AuthEventType::PasskeyLogin, AuthEventType::SessionLogout] {
+        let appended = events
+            .append_event(AuthEvent {
+                subject: Some(subject.clone()),
+                event_type,
+                outcome: AuthEventOutcome::Success,
+                ip_address: Some("203.0.113.7".to_owned()),
+                user_agent: None,
+            })
+            .await;
+        assert!(appended.is_ok());
+    }
+
+    let first_page = repository
+        .list_auth_events_for_subject(subject.as_str(), 1, 0)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list auth events: {error}"));
+    let second_page = repository
+        .list_auth_events_for_subject(subject.as_str(), 1, 1)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list auth events: {error}"));
+    assert_eq!(first_page.len(), 1);
+    assert_eq!(
+        first_page[0].event_type,
+        AuthEventType::PasskeyLogin.as_str()
+    );
+    assert_eq!(
+        second_page[0].event_type,
+        AuthEventType::SessionLogout.as_str()
+    );
+    assert_eq!(second_page[0].ip_address.as_deref(), Some("203.0.113.7"));
+}
@@ -0,0 +1,484 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    CreatePublishIntentInput, PUBLISH_LOCKED_PREFIX, PublishCoordinationRepository, PublishIntent,
+    PublishIntentStatus, PublishLock, PublishLockLease, PublishTarget, PublishTargetScope,
+    ReviewedDraft,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for publish locks and queued publish intents.
+#[derive(Clone)]
+pub struct PostgresPublishCoordinationRepository {
+    pool: PgPool,
+}
+
+impl PostgresPublishCoordinationRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct PublishLockRow {
+    scope: String,
+    logical_name: String,
+    holder_subject: String,
+    acquired_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl TryFrom<PublishLockRow> for PublishLock {
+    type Error = AppError;
+
+    fn try_from(row: PublishLockRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            target: PublishTarget::new(PublishTargetScope::parse(&row.scope)?, row.logical_name),
+            holder_subject: row.holder_subject,
+            acquired_at: row.acquired_at,
+            expires_at: row.expires_at,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PublishTargetDocument {
+    scope: String,
+    logical_name: String,
+}
+
+impl From<&PublishTarget> for PublishTargetDocument {
+    fn from(target: &PublishTarget) -> Self {
+        Self {
+            scope: target.scope.as_str().to_owned(),
+            logical_name: target.logical_name.clone(),
+        }
+    }
+}
+
+impl TryFrom<PublishTargetDocument> for PublishTarget {
+    type Error = AppError;
+
+    fn try_from(document: PublishTargetDocument) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            PublishTargetScope::parse(&document.scope)?,
+            document.logical_name,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReviewedDraftDocument {
+    scope: String,
+    logical_name: String,
+    draft_fingerprint: String,
+}
+
+#[derive(Debug, FromRow)]
+struct PublishIntentRow {
+    id: uuid::Uuid,
+    requested_by_subject: String,
+    requested_by_display_name: String,
+    requested_by_email: Option<String>,
+    targets: Json<Vec<PublishTargetDocument>>,
+    reviewed_drafts: Json<Vec<ReviewedDraftDocument>>,
+    status: String,
+    status_message: Option<String>,
+    created_at: DateTime<Utc>,
+    resolved_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<PublishIntentRow> for PublishIntent {
+    type Error = AppError;
+
+    fn try_from(row: PublishIntentRow) -> Result<Self, Self::Error> {
+        let targets = row
+            .targets
+            .0
+            .into_iter()
+            .map(PublishTarget::try_from)
+            .collect::<AppResult<Vec<_>>>()?;
+        let reviewed_drafts = row
+            .reviewed_drafts
+            .0
+            .into_iter()
+            .map(|document| {
+                Ok(ReviewedDraft {
+                    target: PublishTarget::new(
+                        PublishTargetScope::parse(&document.scope)?,
+                        document.logical_name,
+                    ),
+                    draft_fingerprint: document.draft_fingerprint,
+                })
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+
+        Ok(Self {
+            intent_id: row.id.to_string(),
+            requested_by_subject: row.requested_by_subject,
+            requested_by_display_name: row.requested_by_display_name,
+            requested_by_email: row.requested_by_email,
+            targets,
+            reviewed_drafts,
+            status: PublishIntentStatus::parse(&row.status)?,
+            status_message: row.status_message,
+            created_at: row.created_at,
+            resolved_at: row.resolved_at,
+        })
+    }
+}
+
+const LOCK_COLUMNS: &str = r#"
+    scope,
+    logical_name,
+    holder_subject,
+    acquired_at,
+    expires_at
+"#;
+
+const INTENT_COLUMNS: &str = r#"
+    id,
+    requested_by_subject,
+    requested_by_display_name,
+    requested_by_email,
+    targets,
+    reviewed_drafts,
+    status,
+    status_message,
+    created_at,
+    resolved_at
+"#;
+
+fn parse_intent_id(intent_id: &str) -> AppResult<uuid::Uuid> {
+    uuid::Uuid::parse_str(intent_id)
+        .map_err(|_| AppError::Validation(format!("invalid publish intent id '{intent_id}'")))
+}
+
+#[async_trait]
+impl PublishCoordinationRepository for PostgresPublishCoordinationRepository {
+    async fn acquire_publish_locks(
+        &self,
+        tenant_id: TenantId,
+        lease: &PublishLockLease,
+    ) -> AppResult<Vec<PublishLock>> {
+        let scopes: Vec<&str> = lease
+            .targets
+            .iter()
+            .map(|target| target.scope.as_str())
+            .collect();
+        let logical_names: Vec<&str> = lease
+            .targets
+            .iter()
+            .map(|target| target.logical_name.as_str())
+            .collect();
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            DELETE FROM metadata_publish_locks
+            WHERE tenant_id = $1 AND expires_at <= now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to discard expired publish locks: {error}"))
+        })?;
+
+        let acquired = sqlx::query_scalar::<_, String>(
+            r#"
+            INSERT INTO metadata_publish_locks (
+                tenant_id,
+                scope,
+                logical_name,
+                lock_token,
+                holder_subject,
+                expires_at
+            )
+            SELECT $1, target.scope, target.logical_name, $4, $5, $6
+            FROM UNNEST($2::TEXT[], $3::TEXT[]) AS target(scope, logical_name)
+            ON CONFLICT (tenant_id, scope, logical_name) DO UPDATE
+            SET expires_at = EXCLUDED.expires_at
+            WHERE metadata_publish_locks.lock_token = EXCLUDED.lock_token
+            RETURNING logical_name
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(&scopes)
+        .bind(&logical_names)
+        .bind(lease.lock_token.as_str())
+        .bind(lease.holder_subject.as_str())
+        .bind(lease.expires_at)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to acquire publish locks: {error}")))?;
+
+        if acquired.len() == lease.targets.len() {
+            transaction.commit().await.map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to commit publish lock transaction: {error}"
+                ))
+            })?;
+            return Ok(Vec::new());
+        }
+
+        let blocking_rows = sqlx::query_as::<_, PublishLockRow>(&format!(
+            r#"
+            SELECT {LOCK_COLUMNS}
+            FROM metadata_publish_locks
+            WHERE tenant_id = $1
+              AND lock_token <> $4
+              AND (scope, logical_name) IN (
+                  SELECT target.scope, target.logical_name
+                  FROM UNNEST($2::TEXT[], $3::TEXT[]) AS target(scope, logical_name)
+              )
+            ORDER BY scope, logical_name
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(&scopes)
+        .bind(&logical_names)
+        .bind(lease.lock_token.as_str())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list blocking publish locks: {error}"))
+        })?;
+
+        transaction.rollback().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to roll back publish lock transaction: {error}"
+            ))
+        })?;
+
+        // The blocking run released its locks between both statements.
+        if blocking_rows.is_empty() {
+            return Err(AppError::Conflict(format!(
+                "{PUBLISH_LOCKED_PREFIX}; retry the publish"
+            )));
+        }
+
+        blocking_rows
+            .into_iter()
+            .map(PublishLock::try_from)
+            .collect()
+    }
+
+    async fn release_publish_locks(&self, tenant_id: TenantId, lock_token: &str) -> AppResult<()> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            DELETE FROM metadata_publish_locks
+            WHERE tenant_id = $1 AND lock_token = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(lock_token)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to release publish locks: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit publish lock transaction: {error}"
+            ))
+        })
+    }
+
+    async fn list_publish_locks(&self, tenant_id: TenantId) -> AppResult<Vec<PublishLock>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, PublishLockRow>(&format!(
+            r#"
+            SELECT {LOCK_COLUMNS}
+            FROM metadata_publish_locks
+            WHERE tenant_id = $1 AND expires_at > now()
+            ORDER BY acquired_at ASC, scope, logical_name
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list publish locks: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit publish lock transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter().map(PublishLock::try_from).collect()
+    }
+
+    async fn create_publish_intent(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        requested_by_display_name: &str,
+        requested_by_email: Option<&str>,
+        input: CreatePublishIntentInput,
+    ) -> AppResult<PublishIntent> {
+        let targets: Vec<PublishTargetDocument> = input
+            .targets
+            .iter()
+            .map(PublishTargetDocument::from)
+            .collect();
+        let reviewed_drafts: Vec<ReviewedDraftDocument> = input
+            .reviewed_drafts
+            .into_iter()
+            .map(|reviewed| ReviewedDraftDocument {
+                scope: reviewed.target.scope.as_str().to_owned(),
+                logical_name: reviewed.target.logical_name,
+                draft_fingerprint: reviewed.draft_fingerprint,
+            })
+            .collect();
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, PublishIntentRow>(&format!(
+            r#"
+            INSERT INTO metadata_publish_intents (
+                id,
+                tenant_id,
+                requested_by_subject,
+                requested_by_display_name,
+                requested_by_email,
+                targets,
+                reviewed_drafts
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING {INTENT_COLUMNS}
+            "#
+        ))
+        .bind(uuid::Uuid::new_v4())
+        .bind(tenant_id.as_uuid())
+        .bind(requested_by_subject)
+        .bind(requested_by_display_name)
+        .bind(requested_by_email)
+        .bind(Json(targets))
+        .bind(Json(reviewed_drafts))
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to queue publish intent: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit publish intent transaction: {error}"
+            ))
+        })?;
+
+        PublishIntent::try_from(row)
+    }
+
+    async fn list_publish_intents(
+        &self,
+        tenant_id: TenantId,
+        include_resolved: bool,
+    ) -> AppResult<Vec<PublishIntent>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, PublishIntentRow>(&format!(
+            r#"
+            SELECT {INTENT_COLUMNS}
+            FROM metadata_publish_intents
+            WHERE tenant_id = $1
+              AND ($2 OR status = 'queued')
+            ORDER BY created_at ASC, id ASC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(include_resolved)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list publish intents: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit publish intent transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter().map(PublishIntent::try_from).collect()
+    }
+
+    async fn find_publish_intent(
+        &self,
+        tenant_id: TenantId,
+        intent_id: &str,
+    ) -> AppResult<Option<PublishIntent>> {
+        let intent_uuid = parse_intent_id(intent_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, PublishIntentRow>(&format!(
+            r#"
+            SELECT {INTENT_COLUMNS}
+            FROM metadata_publish_intents
+            WHERE tenant_id = $1 AND id = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(intent_uuid)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to find publish intent: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit publish intent transaction: {error}"
+            ))
+        })?;
+
+        row.map(PublishIntent::try_from).transpose()
+    }
+
+    async fn resolve_publish_intent(
+        &self,
+        tenant_id: TenantId,
+        intent_id: &str,
+        status: PublishIntentStatus,
+        status_message: Option<&str>,
+    ) -> AppResult<Option<PublishIntent>> {
+        if status == PublishIntentStatus::Queued {
+            return Err(AppError::Validation(
+                "publish intents cannot be resolved back to queued".to_owned(),
+            ));
+        }
+        let intent_uuid = parse_intent_id(intent_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, PublishIntentRow>(&format!(
+            r#"
+            UPDATE metadata_publish_intents
+            SET status = $3, status_message = $4, resolved_at = now()
+            WHERE tenant_id = $1 AND id = $2 AND status = 'queued'
+            RETURNING {INTENT_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(intent_uuid)
+        .bind(status.as_str())
+        .bind(status_message)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to resolve publish intent: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit publish intent transaction: {error}"
+            ))
+        })?;
+
+        row.map(PublishIntent::try_from).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,216 @@
+use chrono::{Duration, Utc};
+use qryvanta_application::{
+    CreatePublishIntentInput, PublishCoordinationRepository, PublishIntentStatus, PublishLockLease,
+    PublishTarget, PublishTargetScope, ReviewedDraft,
+};
+use qryvanta_core::TenantId;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresPublishCoordinationRepository;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres publish coordination tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn ensure_tenant(pool: &PgPool, tenant_id: TenantId, name: &str) {
+    let insert = sqlx::query(
+        r#"
+            INSERT INTO tenants (id, name)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(name)
+    .execute(pool)
+    .await;
+
+    assert!(insert.is_ok());
+}
+
+fn lease(
+    token: &str,
+    holder: &str,
+    targets: Vec<PublishTarget>,
+    ttl: Duration,
+) -> PublishLockLease {
+    PublishLockLease {
+        lock_token: token.to_owned(),
+        holder_subject: holder.to_owned(),
+        targets,
+        expires_at: Utc::now() + ttl,
+    }
+}
+
+fn entity(logical_name: &str) -> PublishTarget {
+    PublishTarget::new(PublishTargetScope::Entity, logical_name)
+}
+
+#[tokio::test]
+async fn publish_locks_are_all_or_nothing_and_expire() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Publish Lock Tenant").await;
+    let repository = PostgresPublishCoordinationRepository::new(pool);
+
+    let alice = lease(
+        "alice-run",
+        "alice",
+        vec![entity("contact")],
+        Duration::minutes(5),
+    );
+    let blocking = repository
+        .acquire_publish_locks(tenant_id, &alice)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(blocking.is_empty());
+
+    let bob = lease(
+        "bob-run",
+        "bob",
+        vec![entity("account"), entity("contact")],
+        Duration::minutes(5),
+    );
+    let blocking = repository
+        .acquire_publish_locks(tenant_id, &bob)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(blocking.len(), 1);
+    assert_eq!(blocking[0].holder_subject, "alice");
+    assert_eq!(blocking[0].target, entity("contact"));
+
+    let locks = repository
+        .list_publish_locks(tenant_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(locks.len(), 1, "blocked lease must not keep partial locks");
+
+    repository
+        .release_publish_locks(tenant_id, "alice-run")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let expired = lease(
+        "carol-run",
+        "carol",
+        vec![entity("lead")],
+        -Duration::minutes(1),
+    );
+    assert!(
+        repository
+            .acquire_publish_locks(tenant_id, &expired)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+    assert!(
+        repository
+            .acquire_publish_locks(tenant_id, &bob)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+    let lead = lease(
+        "dave-run",
+        "dave",
+        vec![entity("lead")],
+        Duration::minutes(5),
+    );
+    assert!(
+        repository
+            .acquire_publish_locks(tenant_id, &lead)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty(),
+        "expired locks must not block new runs"
+    );
+}
+
+#[tokio::test]
+async fn publish_intents_resolve_only_once() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Publish Intent Tenant").await;
+    let repository = PostgresPublishCoordinationRepository::new(pool);
+
+    let intent = repository
+        .create_publish_intent(
+            tenant_id,
+            "alice",
+            "Alice",
+            Some("alice@example.com"),
+            CreatePublishIntentInput {
+                targets: vec![entity("contact")],
+                reviewed_drafts: vec![ReviewedDraft {
+                    target: entity("contact"),
+                    draft_fingerprint: "abc123".to_owned(),
+                }],
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(intent.status, PublishIntentStatus::Queued);
+    assert_eq!(intent.reviewed_drafts[0].draft_fingerprint, "abc123");
+
+    let resolved = repository
+        .resolve_publish_intent(
+            tenant_id,
+            intent.intent_id.as_str(),
+            PublishIntentStatus::Completed,
+            None,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(resolved.is_some_and(|intent| intent.resolved_at.is_some()));
+
+    let again = repository
+        .resolve_publish_intent(
+            tenant_id,
+            intent.intent_id.as_str(),
+            PublishIntentStatus::Cancelled,
+            None,
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(again.is_none());
+
+    assert!(
+        repository
+            .list_publish_intents(tenant_id, false)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+    assert_eq!(
+        repository
+            .list_publish_intents(tenant_id, true)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .len(),
+        1
+    );
+}
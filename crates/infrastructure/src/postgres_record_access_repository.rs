@@ -0,0 +1,912 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool, Postgres, Transaction};
+use tracing::info;
+
+use qryvanta_application::{
+    DEFAULT_RECORD_SHARE_HOURS, NewRecordAccessRequest, RecordAccessRepository,
+    RecordAccessRequest, RecordAccessRequestQuery, RecordAccessRequestStatus, RecordShare,
+    RelationBehavior, RelationCascadeResult, RuntimeRecordWorkflowEventInput,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+use qryvanta_domain::{RelationCascadeBehavior, RelationDeleteBehavior};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for record access requests and record shares.
+#[derive(Clone)]
+pub struct PostgresRecordAccessRepository {
+    pool: PgPool,
+}
+
+impl PostgresRecordAccessRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct RecordAccessRequestRow {
+    id: uuid::Uuid,
+    tenant_id: uuid::Uuid,
+    entity_logical_name: String,
+    record_id: String,
+    requested_by_subject: String,
+    reason: String,
+    duration_hours: i32,
+    routed_to_subject: String,
+    status: String,
+    requested_at: DateTime<Utc>,
+    decided_by_subject: Option<String>,
+    decided_at: Option<DateTime<Utc>>,
+    share_id: Option<uuid::Uuid>,
+}
+
+impl TryFrom<RecordAccessRequestRow> for RecordAccessRequest {
+    type Error = AppError;
+
+    fn try_from(row: RecordAccessRequestRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            request_id: row.id.to_string(),
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            entity_logical_name: row.entity_logical_name,
+            record_id: row.record_id,
+            requested_by_subject: row.requested_by_subject,
+            reason: row.reason,
+            duration_hours: u16::try_from(row.duration_hours).unwrap_or(DEFAULT_RECORD_SHARE_HOURS),
+            routed_to_subject: row.routed_to_subject,
+            status: RecordAccessRequestStatus::parse(row.status.as_str())?,
+            requested_at: row.requested_at,
+            decided_by_subject: row.decided_by_subject,
+            decided_at: row.decided_at,
+            share_id: row.share_id.map(|share_id| share_id.to_string()),
+        })
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct RecordShareRow {
+    id: uuid::Uuid,
+    tenant_id: uuid::Uuid,
+    entity_logical_name: String,
+    record_id: String,
+    subject: String,
+    request_id: uuid::Uuid,
+    granted_by_subject: String,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    revoked_by_subject: Option<String>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<RecordShareRow> for RecordShare {
+    fn from(row: RecordShareRow) -> Self {
+        Self {
+            share_id: row.id.to_string(),
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            entity_logical_name: row.entity_logical_name,
+            record_id: row.record_id,
+            subject: row.subject,
+            request_id: row.request_id.to_string(),
+            granted_by_subject: row.granted_by_subject,
+            created_at: row.created_at,
+            expires_at: row.expires_at,
+            revoked_by_subject: row.revoked_by_subject,
+            revoked_at: row.revoked_at,
+        }
+    }
+}
+
+const REQUEST_COLUMNS: &str = r#"
+    request.id,
+    request.tenant_id,
+    request.entity_logical_name,
+    request.record_id,
+    request.requested_by_subject,
+    request.reason,
+    request.duration_hours,
+    request.routed_to_subject,
+    request.status,
+    request.requested_at,
+    request.decided_by_subject,
+    request.decided_at,
+    (
+        SELECT share.id
+        FROM runtime_record_shares share
+        WHERE share.tenant_id = request.tenant_id
+          AND share.request_id = request.id
+          AND share.cascaded_from_share_id IS NULL
+        LIMIT 1
+    ) AS share_id
+"#;
+
+const SHARE_COLUMNS: &str = r#"
+    id,
+    tenant_id,
+    entity_logical_name,
+    record_id,
+    subject,
+    request_id,
+    granted_by_subject,
+    created_at,
+    expires_at,
+    revoked_by_subject,
+    revoked_at
+"#;
+
+async fn enqueue_notification(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    entity_logical_name: &str,
+    notification: RuntimeRecordWorkflowEventInput,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO workflow_runtime_trigger_events (
+            tenant_id,
+            trigger_type,
+            entity_logical_name,
+            record_id,
+            emitted_by_subject,
+            payload,
+            status,
+            created_at,
+            updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, 'pending', now(), now())
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(notification.trigger.trigger_type())
+    .bind(entity_logical_name)
+    .bind(notification.record_id)
+    .bind(notification.emitted_by_subject)
+    .bind(notification.payload)
+    .execute(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!(
+            "failed to enqueue record access notification: {error}"
+        ))
+    })?;
+
+    Ok(())
+}
+
+/// Child records shared per statement while cascading an approved share.
+const SHARE_CASCADE_BATCH_SIZE: i64 = 500;
+
+/// Shares child records of one relation in batches inside the approval transaction.
+///
+/// `required_owner` limits the cascade to children owned by that subject.
+async fn cascade_record_share(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    cascade: &RelationBehavior,
+    parent: &RecordShareRow,
+    required_owner: Option<&str>,
+) -> AppResult<u64> {
+    let mut affected_records = 0_u64;
+    loop {
+        let batch_rows = sqlx::query(
+            r#"
+            INSERT INTO runtime_record_shares (
+                id,
+                tenant_id,
+                entity_logical_name,
+                record_id,
+                subject,
+                request_id,
+                granted_by_subject,
+                expires_at,
+                cascaded_from_share_id
+            )
+            SELECT
+                gen_random_uuid(),
+                child.tenant_id,
+                child.entity_logical_name,
+                child.id::TEXT,
+                $5,
+                $6,
+                $7,
+                $8,
+                $9
+            FROM runtime_records child
+            WHERE child.tenant_id = $1
+              AND child.entity_logical_name = $2
+              AND child.data ->> $3 = $4
+              AND child.created_by_subject <> $5
+              AND ($10::TEXT IS NULL OR child.created_by_subject = $10)
+              AND NOT EXISTS (
+                  SELECT 1
+                  FROM runtime_record_shares existing
+                  WHERE existing.tenant_id = child.tenant_id
+                    AND existing.entity_logical_name = child.entity_logical_name
+                    AND existing.record_id = child.id::TEXT
+                    AND existing.subject = $5
+                    AND existing.revoked_at IS NULL
+                    AND existing.expires_at > now()
+              )
+            LIMIT $11
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(cascade.entity_logical_name.as_str())
+        .bind(cascade.field_logical_name.as_str())
+        .bind(parent.record_id.as_str())
+        .bind(parent.subject.as_str())
+        .bind(parent.request_id)
+        .bind(parent.granted_by_subject.as_str())
+        .bind(parent.expires_at)
+        .bind(parent.id)
+        .bind(required_owner)
+        .bind(SHARE_CASCADE_BATCH_SIZE)
+        .execute(&mut **transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to cascade record share to '{}.{}': {error}",
+                cascade.entity_logical_name, cascade.field_logical_name
+            ))
+        })?
+        .rows_affected();
+
+        affected_records += batch_rows;
+        if batch_rows < SHARE_CASCADE_BATCH_SIZE.unsigned_abs() {
+            return Ok(affected_records);
+        }
+
+        info!(
+            tenant_id = %tenant_id,
+            entity_logical_name = cascade.entity_logical_name.as_str(),
+            field_logical_name = cascade.field_logical_name.as_str(),
+            parent_share_id = %parent.id,
+            affected_records,
+            "relation share cascade in progress"
+        );
+    }
+}
+
+async fn find_request_in_transaction(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    request_uuid: uuid::Uuid,
+) -> AppResult<Option<RecordAccessRequest>> {
+    let row = sqlx::query_as::<_, RecordAccessRequestRow>(&format!(
+        r#"
+        SELECT {REQUEST_COLUMNS}
+        FROM runtime_record_access_requests request
+        WHERE request.tenant_id = $1 AND request.id = $2
+        "#
+    ))
+    .bind(tenant_id.as_uuid())
+    .bind(request_uuid)
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(|error| {
+        AppError::Internal(format!("failed to find record access request: {error}"))
+    })?;
+
+    row.map(RecordAccessRequest::try_from).transpose()
+}
+
+#[async_trait]
+impl RecordAccessRepository for PostgresRecordAccessRepository {
+    async fn find_record_owner(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Option<String>> {
+        let Ok(record_uuid) = uuid::Uuid::parse_str(record_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let owner = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT created_by_subject
+            FROM runtime_records
+            WHERE tenant_id = $1
+              AND entity_logical_name = $2
+              AND id = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(record_uuid)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find owner of runtime record '{}' for entity '{}': {error}",
+                record_id, entity_logical_name
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record owner lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(owner)
+    }
+
+    async fn create_request(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        request: NewRecordAccessRequest,
+    ) -> AppResult<RecordAccessRequest> {
+        let request_uuid = uuid::Uuid::parse_str(request.request_id.as_str()).map_err(|error| {
+            AppError::Validation(format!(
+                "invalid record access request id '{}': {error}",
+                request.request_id
+            ))
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO runtime_record_access_requests (
+                id,
+                tenant_id,
+                entity_logical_name,
+                record_id,
+                requested_by_subject,
+                reason,
+                duration_hours,
+                routed_to_subject
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(request_uuid)
+        .bind(tenant_id.as_uuid())
+        .bind(request.entity_logical_name.as_str())
+        .bind(request.record_id.as_str())
+        .bind(requested_by_subject)
+        .bind(request.reason.as_str())
+        .bind(i32::from(request.duration_hours))
+        .bind(request.routed_to_subject.as_str())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            if let sqlx::Error::Database(database_error) = &error
+                && database_error.code().as_deref() == Some("23505")
+            {
+                return AppError::Conflict(format!(
+                    "subject '{}' already has a pending access request for runtime record '{}'",
+                    requested_by_subject, request.record_id
+                ));
+            }
+
+            AppError::Internal(format!("failed to create record access request: {error}"))
+        })?;
+
+        if let Some(notification) = request.notification {
+            enqueue_notification(
+                &mut transaction,
+                tenant_id,
+                request.entity_logical_name.as_str(),
+                notification,
+            )
+            .await?;
+        }
+
+        let created = find_request_in_transaction(&mut transaction, tenant_id, request_uuid)
+            .await?
+            .ok_or_else(|| {
+                AppError::Internal(format!(
+                    "record access request '{}' was not persisted",
+                    request.request_id
+                ))
+            })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record access request transaction: {error}"
+            ))
+        })?;
+
+        Ok(created)
+    }
+
+    async fn list_requests(
+        &self,
+        tenant_id: TenantId,
+        query: RecordAccessRequestQuery,
+    ) -> AppResult<Vec<RecordAccessRequest>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, RecordAccessRequestRow>(&format!(
+            r#"
+            SELECT {REQUEST_COLUMNS}
+            FROM runtime_record_access_requests request
+            WHERE request.tenant_id = $1
+              AND ($2::TEXT IS NULL OR request.entity_logical_name = $2)
+              AND ($3::TEXT IS NULL OR request.record_id = $3)
+              AND ($4::TEXT IS NULL OR request.status = $4)
+              AND (
+                  $5::TEXT IS NULL
+                  OR request.requested_by_subject = $5
+                  OR request.routed_to_subject = $5
+              )
+            ORDER BY request.requested_at DESC, request.id DESC
+            LIMIT 500
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(query.entity_logical_name)
+        .bind(query.record_id)
+        .bind(query.status.map(|status| status.as_str()))
+        .bind(query.involving_subject)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list record access requests: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record access request list transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(RecordAccessRequest::try_from)
+            .collect()
+    }
+
+    async fn find_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+    ) -> AppResult<Option<RecordAccessRequest>> {
+        let Ok(request_uuid) = uuid::Uuid::parse_str(request_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let request =
+            find_request_in_transaction(&mut transaction, tenant_id, request_uuid).await?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record access request lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(request)
+    }
+
+    async fn list_share_cascades(
+        &self,
+        tenant_id: TenantId,
+        target_entity_logical_name: &str,
+    ) -> AppResult<Vec<RelationBehavior>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, (String, String, String, String, String)>(
+            r#"
+            SELECT
+                entity_logical_name,
+                field_logical_name,
+                assign_behavior,
+                share_behavior,
+                delete_behavior
+            FROM runtime_relation_behaviors
+            WHERE tenant_id = $1
+              AND target_entity_logical_name = $2
+              AND share_behavior <> 'none'
+            ORDER BY entity_logical_name, field_logical_name
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(target_entity_logical_name)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list share cascades for entity '{}': {error}",
+                target_entity_logical_name
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped share cascade lookup transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(
+                |(
+                    entity_logical_name,
+                    field_logical_name,
+                    assign_behavior,
+                    share_behavior,
+                    delete_behavior,
+                )| {
+                    Ok(RelationBehavior {
+                        entity_logical_name,
+                        field_logical_name,
+                        target_entity_logical_name: target_entity_logical_name.to_owned(),
+                        assign_behavior: RelationCascadeBehavior::from_str(
+                            assign_behavior.as_str(),
+                        )?,
+                        share_behavior: RelationCascadeBehavior::from_str(share_behavior.as_str())?,
+                        delete_behavior: RelationDeleteBehavior::from_str(
+                            delete_behavior.as_str(),
+                        )?,
+                    })
+                },
+            )
+            .collect()
+    }
+
+    async fn approve_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+        decided_by_subject: &str,
+        share_expires_at: DateTime<Utc>,
+        share_cascades: &[RelationBehavior],
+        notification: Option<RuntimeRecordWorkflowEventInput>,
+    ) -> AppResult<Option<(RecordAccessRequest, RecordShare, Vec<RelationCascadeResult>)>> {
+        let Ok(request_uuid) = uuid::Uuid::parse_str(request_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let decided = sqlx::query_as::<_, (String, String, String)>(
+            r#"
+            UPDATE runtime_record_access_requests
+            SET status = 'approved',
+                decided_by_subject = $3,
+                decided_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND status = 'pending'
+            RETURNING entity_logical_name, record_id, requested_by_subject
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(request_uuid)
+        .bind(decided_by_subject)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to approve record access request: {error}"))
+        })?;
+        let Some((entity_logical_name, record_id, requested_by_subject)) = decided else {
+            transaction.commit().await.map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to commit tenant-scoped record access approval transaction: {error}"
+                ))
+            })?;
+            return Ok(None);
+        };
+
+        let share_row = sqlx::query_as::<_, RecordShareRow>(&format!(
+            r#"
+            INSERT INTO runtime_record_shares (
+                id,
+                tenant_id,
+                entity_logical_name,
+                record_id,
+                subject,
+                request_id,
+                granted_by_subject,
+                expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING {SHARE_COLUMNS}
+            "#
+        ))
+        .bind(uuid::Uuid::new_v4())
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name.as_str())
+        .bind(record_id.as_str())
+        .bind(requested_by_subject.as_str())
+        .bind(request_uuid)
+        .bind(decided_by_subject)
+        .bind(share_expires_at)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to create record share: {error}")))?;
+
+        let mut cascades = Vec::with_capacity(share_cascades.len());
+        if !share_cascades.is_empty() {
+            let parent_owner = sqlx::query_scalar::<_, String>(
+                r#"
+                SELECT created_by_subject
+                FROM runtime_records
+                WHERE tenant_id = $1
+                  AND entity_logical_name = $2
+                  AND id = $3
+                "#,
+            )
+            .bind(tenant_id.as_uuid())
+            .bind(entity_logical_name.as_str())
+            .bind(uuid::Uuid::parse_str(record_id.as_str()).ok())
+            .fetch_optional(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to find owner of shared runtime record '{}': {error}",
+                    record_id
+                ))
+            })?
+            .unwrap_or_default();
+
+            for cascade in share_cascades {
+                let required_owner = match cascade.share_behavior {
+                    RelationCascadeBehavior::None => continue,
+                    RelationCascadeBehavior::CascadeAll => None,
+                    RelationCascadeBehavior::CascadeUserOwned => Some(parent_owner.as_str()),
+                };
+                let affected_records = cascade_record_share(
+                    &mut transaction,
+                    tenant_id,
+                    cascade,
+                    &share_row,
+                    required_owner,
+                )
+                .await?;
+                cascades.push(RelationCascadeResult {
+                    entity_logical_name: cascade.entity_logical_name.clone(),
+                    field_logical_name: cascade.field_logical_name.clone(),
+                    affected_records,
+                });
+            }
+        }
+
+        if let Some(notification) = notification {
+            enqueue_notification(
+                &mut transaction,
+                tenant_id,
+                entity_logical_name.as_str(),
+                notification,
+            )
+            .await?;
+        }
+
+        let approved = find_request_in_transaction(&mut transaction, tenant_id, request_uuid)
+            .await?
+            .ok_or_else(|| {
+                AppError::Internal(format!(
+                    "record access request '{request_id}' disappeared during approval"
+                ))
+            })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record access approval transaction: {error}"
+            ))
+        })?;
+
+        Ok(Some((approved, RecordShare::from(share_row), cascades)))
+    }
+
+    async fn reject_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+        decided_by_subject: &str,
+        notification: Option<RuntimeRecordWorkflowEventInput>,
+    ) -> AppResult<Option<RecordAccessRequest>> {
+        let Ok(request_uuid) = uuid::Uuid::parse_str(request_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let entity_logical_name = sqlx::query_scalar::<_, String>(
+            r#"
+            UPDATE runtime_record_access_requests
+            SET status = 'rejected',
+                decided_by_subject = $3,
+                decided_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND status = 'pending'
+            RETURNING entity_logical_name
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(request_uuid)
+        .bind(decided_by_subject)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to reject record access request: {error}"))
+        })?;
+
+        let rejected = match entity_logical_name {
+            Some(entity_logical_name) => {
+                if let Some(notification) = notification {
+                    enqueue_notification(
+                        &mut transaction,
+                        tenant_id,
+                        entity_logical_name.as_str(),
+                        notification,
+                    )
+                    .await?;
+                }
+                find_request_in_transaction(&mut transaction, tenant_id, request_uuid).await?
+            }
+            None => None,
+        };
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record access rejection transaction: {error}"
+            ))
+        })?;
+
+        Ok(rejected)
+    }
+
+    async fn has_active_share(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+        subject: &str,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let is_shared = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM runtime_record_shares
+                WHERE tenant_id = $1
+                  AND entity_logical_name = $2
+                  AND record_id = $3
+                  AND subject = $4
+                  AND revoked_at IS NULL
+                  AND expires_at > now()
+            )
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(record_id)
+        .bind(subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to evaluate record share for runtime record '{}': {error}",
+                record_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record share lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(is_shared)
+    }
+
+    async fn list_active_shares(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RecordShare>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, RecordShareRow>(&format!(
+            r#"
+            SELECT {SHARE_COLUMNS}
+            FROM runtime_record_shares
+            WHERE tenant_id = $1
+              AND entity_logical_name = $2
+              AND record_id = $3
+              AND revoked_at IS NULL
+              AND expires_at > now()
+            ORDER BY created_at DESC, id DESC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(record_id)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list record shares: {error}")))?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record share list transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(RecordShare::from).collect())
+    }
+
+    async fn find_share(
+        &self,
+        tenant_id: TenantId,
+        share_id: &str,
+    ) -> AppResult<Option<RecordShare>> {
+        let Ok(share_uuid) = uuid::Uuid::parse_str(share_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RecordShareRow>(&format!(
+            r#"
+            SELECT {SHARE_COLUMNS}
+            FROM runtime_record_shares
+            WHERE tenant_id = $1 AND id = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(share_uuid)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to find record share: {error}")))?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record share lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(RecordShare::from))
+    }
+
+    async fn revoke_share(
+        &self,
+        tenant_id: TenantId,
+        share_id: &str,
+        revoked_by_subject: &str,
+    ) -> AppResult<Option<RecordShare>> {
+        let Ok(share_uuid) = uuid::Uuid::parse_str(share_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RecordShareRow>(&format!(
+            r#"
+            UPDATE runtime_record_shares
+            SET revoked_by_subject = $3,
+                revoked_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND revoked_at IS NULL
+              AND expires_at > now()
+            RETURNING {SHARE_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(share_uuid)
+        .bind(revoked_by_subject)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to revoke record share: {error}")))?;
+
+        if row.is_some() {
+            sqlx::query(
+                r#"
+                UPDATE runtime_record_shares
+                SET revoked_by_subject = $3,
+                    revoked_at = now()
+                WHERE tenant_id = $1
+                  AND cascaded_from_share_id = $2
+                  AND revoked_at IS NULL
+                "#,
+            )
+            .bind(tenant_id.as_uuid())
+            .bind(share_uuid)
+            .bind(revoked_by_subject)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!("failed to revoke cascaded record shares: {error}"))
+            })?;
+        }
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record share revocation transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(RecordShare::from))
+    }
+}
@@ -0,0 +1,619 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    DueRecordImportSchedule, NewRecordImportSchedule, RecordImportColumnMapping,
+    RecordImportFrequency, RecordImportMappingTemplate, RecordImportRepository,
+    RecordImportRunStatus, RecordImportSchedule, RecordImportScheduleRun, RecordImportSourceKind,
+    SaveRecordImportMappingTemplateInput,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+use crate::postgres_tenant_rls::begin_record_import_schedule_transaction;
+
+const TEMPLATE_COLUMNS: &str =
+    "id, entity_logical_name, name, column_mappings, updated_by_subject, updated_at";
+
+const SCHEDULE_COLUMNS: &str = "id, tenant_id, name, entity_logical_name, template_id, \
+     source_kind, source_url, auth_header_name, auth_secret_ref, frequency, is_enabled, \
+     owner_subject, owner_display_name, owner_email, next_run_at, last_run_at, last_run_status, \
+     last_file_sha256, last_imported_count, last_failed_count, last_error, created_at";
+
+/// PostgreSQL-backed repository for import mapping templates and recurring imports.
+#[derive(Clone)]
+pub struct PostgresRecordImportRepository {
+    pool: PgPool,
+}
+
+impl PostgresRecordImportRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct RecordImportTemplateRow {
+    id: uuid::Uuid,
+    entity_logical_name: String,
+    name: String,
+    column_mappings: Value,
+    updated_by_subject: String,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct RecordImportScheduleRow {
+    id: uuid::Uuid,
+    tenant_id: uuid::Uuid,
+    name: String,
+    entity_logical_name: String,
+    template_id: Option<uuid::Uuid>,
+    source_kind: String,
+    source_url: String,
+    auth_header_name: Option<String>,
+    auth_secret_ref: Option<String>,
+    frequency: String,
+    is_enabled: bool,
+    owner_subject: String,
+    owner_display_name: String,
+    owner_email: String,
+    next_run_at: DateTime<Utc>,
+    last_run_at: Option<DateTime<Utc>>,
+    last_run_status: Option<String>,
+    last_file_sha256: Option<String>,
+    last_imported_count: i32,
+    last_failed_count: i32,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct ClaimedRecordImportScheduleRow {
+    scheduled_for: DateTime<Utc>,
+    #[sqlx(flatten)]
+    schedule: RecordImportScheduleRow,
+}
+
+impl TryFrom<RecordImportTemplateRow> for RecordImportMappingTemplate {
+    type Error = AppError;
+
+    fn try_from(row: RecordImportTemplateRow) -> Result<Self, Self::Error> {
+        let invalid_mappings = || {
+            AppError::Internal(format!(
+                "record import template '{}' stores invalid column mappings",
+                row.id
+            ))
+        };
+        let column_mappings = row
+            .column_mappings
+            .as_array()
+            .ok_or_else(invalid_mappings)?
+            .iter()
+            .map(|item| {
+                let text = |key: &str| {
+                    item.get(key)
+                        .and_then(Value::as_str)
+                        .map(str::to_owned)
+                        .ok_or_else(invalid_mappings)
+                };
+                Ok(RecordImportColumnMapping {
+                    source_column: text("source_column")?,
+                    field_logical_name: text("field_logical_name")?,
+                })
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+
+        Ok(Self {
+            template_id: row.id.to_string(),
+            entity_logical_name: row.entity_logical_name,
+            name: row.name,
+            column_mappings,
+            updated_by_subject: row.updated_by_subject,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+impl TryFrom<RecordImportScheduleRow> for RecordImportSchedule {
+    type Error = AppError;
+
+    fn try_from(row: RecordImportScheduleRow) -> Result<Self, Self::Error> {
+        let count = |value: i32| {
+            u32::try_from(value).map_err(|_| {
+                AppError::Internal("stored record import row count is out of range".to_owned())
+            })
+        };
+
+        Ok(Self {
+            schedule_id: row.id.to_string(),
+            name: row.name,
+            entity_logical_name: row.entity_logical_name,
+            template_id: row.template_id.map(|template_id| template_id.to_string()),
+            source_kind: RecordImportSourceKind::parse(row.source_kind.as_str())?,
+            source_url: row.source_url,
+            auth_header_name: row.auth_header_name,
+            auth_secret_ref: row.auth_secret_ref,
+            frequency: RecordImportFrequency::parse(row.frequency.as_str())?,
+            is_enabled: row.is_enabled,
+            owner_subject: row.owner_subject,
+            owner_display_name: row.owner_display_name,
+            owner_email: row.owner_email,
+            next_run_at: row.next_run_at,
+            last_run_at: row.last_run_at,
+            last_run_status: row
+                .last_run_status
+                .as_deref()
+                .map(RecordImportRunStatus::parse)
+                .transpose()?,
+            last_file_sha256: row.last_file_sha256,
+            last_imported_count: count(row.last_imported_count)?,
+            last_failed_count: count(row.last_failed_count)?,
+            last_error: row.last_error,
+            created_at: row.created_at,
+        })
+    }
+}
+
+fn parse_id(id: &str) -> Option<uuid::Uuid> {
+    uuid::Uuid::parse_str(id).ok()
+}
+
+fn column_mappings_to_value(column_mappings: &[RecordImportColumnMapping]) -> Value {
+    Value::Array(
+        column_mappings
+            .iter()
+            .map(|mapping| {
+                serde_json::json!({
+                    "source_column": mapping.source_column,
+                    "field_logical_name": mapping.field_logical_name,
+                })
+            })
+            .collect(),
+    )
+}
+
+fn count_to_i32(count: u32) -> AppResult<i32> {
+    i32::try_from(count)
+        .map_err(|_| AppError::Validation("record import row count is out of range".to_owned()))
+}
+
+fn template_save_error(
+    error: sqlx::Error,
+    input: &SaveRecordImportMappingTemplateInput,
+) -> AppError {
+    if let sqlx::Error::Database(database_error) = &error
+        && database_error.code().as_deref() == Some("23505")
+    {
+        return AppError::Conflict(format!(
+            "import template '{}' already exists for entity '{}'",
+            input.name, input.entity_logical_name
+        ));
+    }
+    AppError::Internal(format!("failed to save record import template: {error}"))
+}
+
+fn commit_error(error: sqlx::Error) -> AppError {
+    AppError::Internal(format!(
+        "failed to commit record import transaction: {error}"
+    ))
+}
+
+#[async_trait]
+impl RecordImportRepository for PostgresRecordImportRepository {
+    async fn list_templates(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<Vec<RecordImportMappingTemplate>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, RecordImportTemplateRow>(&format!(
+            r#"
+            SELECT {TEMPLATE_COLUMNS}
+            FROM record_import_templates
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            ORDER BY name ASC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list record import templates for entity '{entity_logical_name}': {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.into_iter()
+            .map(RecordImportMappingTemplate::try_from)
+            .collect()
+    }
+
+    async fn find_template(
+        &self,
+        tenant_id: TenantId,
+        template_id: &str,
+    ) -> AppResult<Option<RecordImportMappingTemplate>> {
+        let Some(template_uuid) = parse_id(template_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RecordImportTemplateRow>(&format!(
+            r#"
+            SELECT {TEMPLATE_COLUMNS}
+            FROM record_import_templates
+            WHERE tenant_id = $1 AND id = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(template_uuid)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to find record import template: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        row.map(RecordImportMappingTemplate::try_from).transpose()
+    }
+
+    async fn create_template(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        input: SaveRecordImportMappingTemplateInput,
+    ) -> AppResult<RecordImportMappingTemplate> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RecordImportTemplateRow>(&format!(
+            r#"
+            INSERT INTO record_import_templates (
+                tenant_id,
+                entity_logical_name,
+                name,
+                column_mappings,
+                updated_by_subject
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING {TEMPLATE_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(input.entity_logical_name.as_str())
+        .bind(input.name.as_str())
+        .bind(column_mappings_to_value(&input.column_mappings))
+        .bind(updated_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| template_save_error(error, &input))?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        RecordImportMappingTemplate::try_from(row)
+    }
+
+    async fn update_template(
+        &self,
+        tenant_id: TenantId,
+        template_id: &str,
+        updated_by_subject: &str,
+        input: SaveRecordImportMappingTemplateInput,
+    ) -> AppResult<Option<RecordImportMappingTemplate>> {
+        let Some(template_uuid) = parse_id(template_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RecordImportTemplateRow>(&format!(
+            r#"
+            UPDATE record_import_templates
+            SET
+                name = $4,
+                column_mappings = $5,
+                updated_by_subject = $6,
+                updated_at = now()
+            WHERE tenant_id = $1 AND id = $2 AND entity_logical_name = $3
+            RETURNING {TEMPLATE_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(template_uuid)
+        .bind(input.entity_logical_name.as_str())
+        .bind(input.name.as_str())
+        .bind(column_mappings_to_value(&input.column_mappings))
+        .bind(updated_by_subject)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| template_save_error(error, &input))?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        row.map(RecordImportMappingTemplate::try_from).transpose()
+    }
+
+    async fn delete_template(&self, tenant_id: TenantId, template_id: &str) -> AppResult<bool> {
+        let Some(template_uuid) = parse_id(template_id) else {
+            return Ok(false);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result =
+            sqlx::query("DELETE FROM record_import_templates WHERE tenant_id = $1 AND id = $2")
+                .bind(tenant_id.as_uuid())
+                .bind(template_uuid)
+                .execute(&mut *transaction)
+                .await
+                .map_err(|error| {
+                    if let sqlx::Error::Database(database_error) = &error
+                        && database_error.code().as_deref() == Some("23503")
+                    {
+                        return AppError::Conflict(format!(
+                            "import template '{template_id}' is used by a recurring import"
+                        ));
+                    }
+                    AppError::Internal(format!("failed to delete record import template: {error}"))
+                })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_schedules(&self, tenant_id: TenantId) -> AppResult<Vec<RecordImportSchedule>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, RecordImportScheduleRow>(&format!(
+            r#"
+            SELECT {SCHEDULE_COLUMNS}
+            FROM record_import_schedules
+            WHERE tenant_id = $1
+            ORDER BY created_at ASC, id ASC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list recurring imports: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.into_iter()
+            .map(RecordImportSchedule::try_from)
+            .collect()
+    }
+
+    async fn create_schedule(
+        &self,
+        tenant_id: TenantId,
+        schedule: NewRecordImportSchedule,
+    ) -> AppResult<RecordImportSchedule> {
+        let input = &schedule.input;
+        let template_uuid = input
+            .template_id
+            .as_deref()
+            .map(|template_id| {
+                parse_id(template_id).ok_or_else(|| {
+                    AppError::NotFound(format!("import template '{template_id}' does not exist"))
+                })
+            })
+            .transpose()?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RecordImportScheduleRow>(&format!(
+            r#"
+            INSERT INTO record_import_schedules (
+                tenant_id,
+                name,
+                entity_logical_name,
+                template_id,
+                source_kind,
+                source_url,
+                auth_header_name,
+                auth_secret_ref,
+                frequency,
+                owner_subject,
+                owner_display_name,
+                owner_email,
+                next_run_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            RETURNING {SCHEDULE_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(input.name.as_str())
+        .bind(input.entity_logical_name.as_str())
+        .bind(template_uuid)
+        .bind(input.source_kind.as_str())
+        .bind(input.source_url.as_str())
+        .bind(input.auth_header_name.as_deref())
+        .bind(input.auth_secret_ref.as_deref())
+        .bind(input.frequency.as_str())
+        .bind(schedule.owner_subject.as_str())
+        .bind(schedule.owner_display_name.as_str())
+        .bind(schedule.owner_email.as_str())
+        .bind(schedule.next_run_at)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to create recurring import: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        RecordImportSchedule::try_from(row)
+    }
+
+    async fn set_schedule_enabled(
+        &self,
+        tenant_id: TenantId,
+        schedule_id: &str,
+        is_enabled: bool,
+        next_run_at: DateTime<Utc>,
+    ) -> AppResult<Option<RecordImportSchedule>> {
+        let Some(schedule_uuid) = parse_id(schedule_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RecordImportScheduleRow>(&format!(
+            r#"
+            UPDATE record_import_schedules
+            SET
+                is_enabled = $3,
+                next_run_at = CASE WHEN $3 AND NOT is_enabled THEN $4 ELSE next_run_at END,
+                updated_at = now()
+            WHERE tenant_id = $1 AND id = $2
+            RETURNING {SCHEDULE_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(schedule_uuid)
+        .bind(is_enabled)
+        .bind(next_run_at)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to update recurring import: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        row.map(RecordImportSchedule::try_from).transpose()
+    }
+
+    async fn delete_schedule(&self, tenant_id: TenantId, schedule_id: &str) -> AppResult<bool> {
+        let Some(schedule_uuid) = parse_id(schedule_id) else {
+            return Ok(false);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result =
+            sqlx::query("DELETE FROM record_import_schedules WHERE tenant_id = $1 AND id = $2")
+                .bind(tenant_id.as_uuid())
+                .bind(schedule_uuid)
+                .execute(&mut *transaction)
+                .await
+                .map_err(|error| {
+                    AppError::Internal(format!("failed to delete recurring import: {error}"))
+                })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn claim_due_schedules(
+        &self,
+        now: DateTime<Utc>,
+        retry_at: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<DueRecordImportSchedule>> {
+        let limit = i64::try_from(limit).map_err(|_| {
+            AppError::Validation("recurring import claim size is out of range".to_owned())
+        })?;
+
+        let mut transaction = begin_record_import_schedule_transaction(&self.pool).await?;
+        let rows = sqlx::query_as::<_, ClaimedRecordImportScheduleRow>(
+            r#"
+            WITH due AS (
+                SELECT id, next_run_at AS scheduled_for
+                FROM record_import_schedules
+                WHERE is_enabled AND next_run_at <= $1
+                ORDER BY next_run_at ASC, id ASC
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE record_import_schedules
+            SET next_run_at = $2
+            FROM due
+            WHERE record_import_schedules.id = due.id
+            RETURNING due.scheduled_for, record_import_schedules.*
+            "#,
+        )
+        .bind(now)
+        .bind(retry_at)
+        .bind(limit)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to claim recurring imports: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.into_iter()
+            .map(|claimed| {
+                let tenant_id = TenantId::from_uuid(claimed.schedule.tenant_id);
+                Ok(DueRecordImportSchedule {
+                    tenant_id,
+                    scheduled_for: claimed.scheduled_for,
+                    schedule: RecordImportSchedule::try_from(claimed.schedule)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn record_schedule_run(
+        &self,
+        tenant_id: TenantId,
+        schedule_id: &str,
+        run: RecordImportScheduleRun,
+    ) -> AppResult<()> {
+        let Some(schedule_uuid) = parse_id(schedule_id) else {
+            return Err(AppError::NotFound(format!(
+                "recurring import '{schedule_id}' does not exist"
+            )));
+        };
+
+        // Unchanged runs keep the previous counts; failed runs keep the last
+        // imported file so the next fetch is still compared against it.
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            UPDATE record_import_schedules
+            SET
+                last_run_at = $3,
+                last_run_status = $4,
+                next_run_at = $5,
+                last_file_sha256 = CASE WHEN $4 = 'imported'
+                    THEN COALESCE($6, last_file_sha256) ELSE last_file_sha256 END,
+                last_imported_count = CASE WHEN $4 = 'imported'
+                    THEN $7 ELSE last_imported_count END,
+                last_failed_count = CASE WHEN $4 = 'imported'
+                    THEN $8 ELSE last_failed_count END,
+                last_error = CASE WHEN $4 = 'unchanged' THEN last_error ELSE $9 END,
+                updated_at = now()
+            WHERE tenant_id = $1 AND id = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(schedule_uuid)
+        .bind(run.ran_at)
+        .bind(run.status.as_str())
+        .bind(run.next_run_at)
+        .bind(run.file_sha256.as_deref())
+        .bind(count_to_i32(run.imported_count)?)
+        .bind(count_to_i32(run.failed_count)?)
+        .bind(run.error.as_deref())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to record recurring import run: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)
+    }
+}
+
+#[cfg(test)]
+mod tests;
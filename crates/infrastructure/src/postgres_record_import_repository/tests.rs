@@ -0,0 +1,292 @@
+use chrono::{Duration, Utc};
+use qryvanta_application::{
+    CreateRecordImportScheduleInput, NewRecordImportSchedule, RecordImportColumnMapping,
+    RecordImportFrequency, RecordImportRepository, RecordImportRunStatus, RecordImportScheduleRun,
+    RecordImportSourceKind, SaveRecordImportMappingTemplateInput,
+};
+use qryvanta_core::{AppError, TenantId};
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresRecordImportRepository;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres record import tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn ensure_tenant(pool: &PgPool, tenant_id: TenantId, name: &str) {
+    let insert = sqlx::query(
+        r#"
+            INSERT INTO tenants (id, name)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(name)
+    .execute(pool)
+    .await;
+
+    assert!(insert.is_ok());
+}
+
+fn template_input(name: &str) -> SaveRecordImportMappingTemplateInput {
+    SaveRecordImportMappingTemplateInput {
+        entity_logical_name: "account".to_owned(),
+        name: name.to_owned(),
+        column_mappings: vec![
+            RecordImportColumnMapping {
+                source_column: "Company".to_owned(),
+                field_logical_name: "name".to_owned(),
+            },
+            RecordImportColumnMapping {
+                source_column: "Staff".to_owned(),
+                field_logical_name: "employees".to_owned(),
+            },
+        ],
+    }
+}
+
+fn new_schedule(
+    template_id: Option<String>,
+    next_run_at: chrono::DateTime<Utc>,
+) -> NewRecordImportSchedule {
+    NewRecordImportSchedule {
+        input: CreateRecordImportScheduleInput {
+            name: "Nightly accounts".to_owned(),
+            entity_logical_name: "account".to_owned(),
+            template_id,
+            source_kind: RecordImportSourceKind::Sftp,
+            source_url: "sftp://importer@files.example.com/outbox/accounts.csv".to_owned(),
+            auth_header_name: None,
+            auth_secret_ref: Some("op://vault/sftp/password".to_owned()),
+            frequency: RecordImportFrequency::Daily,
+        },
+        owner_subject: "alice".to_owned(),
+        owner_display_name: "Alice".to_owned(),
+        owner_email: "alice@example.com".to_owned(),
+        next_run_at,
+    }
+}
+
+#[tokio::test]
+async fn templates_round_trip_and_stay_tenant_scoped() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+    let repository = PostgresRecordImportRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Record Import Tenant").await;
+    ensure_tenant(&pool, other_tenant_id, "Record Import Other Tenant").await;
+
+    let template = repository
+        .create_template(tenant_id, "alice", template_input("CRM export"))
+        .await
+        .unwrap_or_else(|error| panic!("failed to create template: {error}"));
+    assert_eq!(template.column_mappings.len(), 2);
+    assert_eq!(template.column_mappings[1].field_logical_name, "employees");
+
+    let duplicate = repository
+        .create_template(tenant_id, "alice", template_input("CRM export"))
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+
+    let mut renamed = template_input("ERP export");
+    renamed.column_mappings.pop();
+    let updated = repository
+        .update_template(tenant_id, template.template_id.as_str(), "bob", renamed)
+        .await
+        .unwrap_or_else(|error| panic!("failed to update template: {error}"))
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(updated.name, "ERP export");
+    assert_eq!(updated.column_mappings.len(), 1);
+    assert_eq!(updated.updated_by_subject, "bob");
+
+    assert!(
+        repository
+            .find_template(other_tenant_id, template.template_id.as_str())
+            .await
+            .unwrap_or_else(|error| panic!("failed to find template: {error}"))
+            .is_none()
+    );
+    assert!(
+        repository
+            .find_template(tenant_id, "not-a-uuid")
+            .await
+            .unwrap_or_else(|error| panic!("failed to find template: {error}"))
+            .is_none()
+    );
+    assert_eq!(
+        repository
+            .list_templates(tenant_id, "account")
+            .await
+            .unwrap_or_else(|error| panic!("failed to list templates: {error}"))
+            .len(),
+        1
+    );
+
+    let schedule = repository
+        .create_schedule(
+            tenant_id,
+            new_schedule(Some(template.template_id.clone()), Utc::now()),
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to create schedule: {error}"));
+    assert!(matches!(
+        repository
+            .delete_template(tenant_id, template.template_id.as_str())
+            .await,
+        Err(AppError::Conflict(_))
+    ));
+
+    assert!(
+        repository
+            .delete_schedule(tenant_id, schedule.schedule_id.as_str())
+            .await
+            .unwrap_or_else(|error| panic!("failed to delete schedule: {error}"))
+    );
+    assert!(
+        repository
+            .delete_template(tenant_id, template.template_id.as_str())
+            .await
+            .unwrap_or_else(|error| panic!("failed to delete template: {error}"))
+    );
+}
+
+#[tokio::test]
+async fn due_schedules_are_claimed_across_tenants_and_record_runs() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+    let repository = PostgresRecordImportRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Record Import Claim Tenant").await;
+
+    let now = Utc::now();
+    let due = repository
+        .create_schedule(tenant_id, new_schedule(None, now - Duration::minutes(5)))
+        .await
+        .unwrap_or_else(|error| panic!("failed to create schedule: {error}"));
+    let later = repository
+        .create_schedule(tenant_id, new_schedule(None, now + Duration::hours(1)))
+        .await
+        .unwrap_or_else(|error| panic!("failed to create schedule: {error}"));
+    let paused = repository
+        .create_schedule(tenant_id, new_schedule(None, now - Duration::minutes(5)))
+        .await
+        .unwrap_or_else(|error| panic!("failed to create schedule: {error}"));
+    repository
+        .set_schedule_enabled(tenant_id, paused.schedule_id.as_str(), false, now)
+        .await
+        .unwrap_or_else(|error| panic!("failed to pause schedule: {error}"));
+
+    let retry_at = now + Duration::minutes(30);
+    let claimed = repository
+        .claim_due_schedules(now, retry_at, 1000)
+        .await
+        .unwrap_or_else(|error| panic!("failed to claim schedules: {error}"));
+    let ours = claimed
+        .iter()
+        .filter(|claimed| claimed.tenant_id == tenant_id)
+        .collect::<Vec<_>>();
+    assert_eq!(ours.len(), 1);
+    assert_eq!(ours[0].schedule.schedule_id, due.schedule_id);
+    assert_eq!(
+        ours[0].schedule.auth_secret_ref.as_deref(),
+        Some("op://vault/sftp/password")
+    );
+    assert!(
+        repository
+            .claim_due_schedules(now, retry_at, 1000)
+            .await
+            .unwrap_or_else(|error| panic!("failed to claim schedules: {error}"))
+            .iter()
+            .all(|claimed| claimed.tenant_id != tenant_id)
+    );
+
+    let next_run_at = now + Duration::days(1);
+    repository
+        .record_schedule_run(
+            tenant_id,
+            due.schedule_id.as_str(),
+            RecordImportScheduleRun {
+                status: RecordImportRunStatus::Imported,
+                file_sha256: Some("abc123".to_owned()),
+                imported_count: 8,
+                failed_count: 2,
+                error: None,
+                ran_at: now,
+                next_run_at,
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to record run: {error}"));
+    repository
+        .record_schedule_run(
+            tenant_id,
+            due.schedule_id.as_str(),
+            RecordImportScheduleRun {
+                status: RecordImportRunStatus::Failed,
+                file_sha256: None,
+                imported_count: 0,
+                failed_count: 0,
+                error: Some("connection refused".to_owned()),
+                ran_at: now,
+                next_run_at,
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to record run: {error}"));
+
+    let schedules = repository
+        .list_schedules(tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list schedules: {error}"));
+    assert_eq!(schedules.len(), 3);
+    let recorded = schedules
+        .iter()
+        .find(|schedule| schedule.schedule_id == due.schedule_id)
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(
+        recorded.last_run_status,
+        Some(RecordImportRunStatus::Failed)
+    );
+    assert_eq!(recorded.last_file_sha256.as_deref(), Some("abc123"));
+    assert_eq!(recorded.last_imported_count, 8);
+    assert_eq!(recorded.last_failed_count, 2);
+    assert_eq!(recorded.last_error.as_deref(), Some("connection refused"));
+    assert!(recorded.next_run_at > now + Duration::hours(23));
+
+    let resumed = repository
+        .set_schedule_enabled(tenant_id, paused.schedule_id.as_str(), true, now)
+        .await
+        .unwrap_or_else(|error| panic!("failed to resume schedule: {error}"))
+        .unwrap_or_else(|| unreachable!());
+    assert!(resumed.is_enabled);
+    assert!(
+        schedules
+            .iter()
+            .any(|schedule| schedule.schedule_id == later.schedule_id && schedule.is_enabled)
+    );
+}
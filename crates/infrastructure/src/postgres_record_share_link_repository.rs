@@ -0,0 +1,400 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    NewRecordShareLink, RecordShareLink, RecordShareLinkRepository, RecordShareLinkView,
+    RecordShareLinkViewContext, RecordShareLinkViewOutcome,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for external record share links and their access log.
+#[derive(Clone)]
+pub struct PostgresRecordShareLinkRepository {
+    pool: PgPool,
+}
+
+impl PostgresRecordShareLinkRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct RecordShareLinkRow {
+    id: uuid::Uuid,
+    tenant_id: uuid::Uuid,
+    entity_logical_name: String,
+    record_id: String,
+    field_logical_names: Vec<String>,
+    password_hash: Option<String>,
+    created_by_subject: String,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    revoked_by_subject: Option<String>,
+    revoked_at: Option<DateTime<Utc>>,
+    view_count: i64,
+    last_viewed_at: Option<DateTime<Utc>>,
+}
+
+impl From<RecordShareLinkRow> for RecordShareLink {
+    fn from(row: RecordShareLinkRow) -> Self {
+        Self {
+            link_id: row.id.to_string(),
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            entity_logical_name: row.entity_logical_name,
+            record_id: row.record_id,
+            field_logical_names: row.field_logical_names,
+            password_hash: row.password_hash,
+            created_by_subject: row.created_by_subject,
+            created_at: row.created_at,
+            expires_at: row.expires_at,
+            revoked_by_subject: row.revoked_by_subject,
+            revoked_at: row.revoked_at,
+            view_count: row.view_count,
+            last_viewed_at: row.last_viewed_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct RecordShareLinkViewRow {
+    link_id: uuid::Uuid,
+    outcome: String,
+    ip_address: Option<String>,
+    user_agent: Option<String>,
+    viewed_at: DateTime<Utc>,
+}
+
+impl TryFrom<RecordShareLinkViewRow> for RecordShareLinkView {
+    type Error = AppError;
+
+    fn try_from(row: RecordShareLinkViewRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            link_id: row.link_id.to_string(),
+            outcome: RecordShareLinkViewOutcome::parse(row.outcome.as_str())?,
+            ip_address: row.ip_address,
+            user_agent: row.user_agent,
+            viewed_at: row.viewed_at,
+        })
+    }
+}
+
+const LINK_COLUMNS: &str = r#"
+    id,
+    tenant_id,
+    entity_logical_name,
+    record_id,
+    field_logical_names,
+    password_hash,
+    created_by_subject,
+    created_at,
+    expires_at,
+    revoked_by_subject,
+    revoked_at,
+    view_count,
+    last_viewed_at
+"#;
+
+#[async_trait]
+impl RecordShareLinkRepository for PostgresRecordShareLinkRepository {
+    async fn create_link(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        link: NewRecordShareLink,
+    ) -> AppResult<RecordShareLink> {
+        let link_uuid = uuid::Uuid::parse_str(link.link_id.as_str()).map_err(|error| {
+            AppError::Validation(format!(
+                "record share link id must be a valid UUID: {error}"
+            ))
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RecordShareLinkRow>(&format!(
+            r#"
+            INSERT INTO runtime_record_share_links (
+                id,
+                tenant_id,
+                entity_logical_name,
+                record_id,
+                field_logical_names,
+                token_hash,
+                password_hash,
+                created_by_subject,
+                expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING {LINK_COLUMNS}
+            "#
+        ))
+        .bind(link_uuid)
+        .bind(tenant_id.as_uuid())
+        .bind(link.entity_logical_name.as_str())
+        .bind(link.record_id.as_str())
+        .bind(&link.field_logical_names)
+        .bind(link.token_hash.as_str())
+        .bind(link.password_hash.as_deref())
+        .bind(created_by_subject)
+        .bind(link.expires_at)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to create record share link: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record share link create transaction: {error}"
+            ))
+        })?;
+
+        Ok(RecordShareLink::from(row))
+    }
+
+    async fn list_links(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        record_id: &str,
+    ) -> AppResult<Vec<RecordShareLink>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, RecordShareLinkRow>(&format!(
+            r#"
+            SELECT {LINK_COLUMNS}
+            FROM runtime_record_share_links
+            WHERE tenant_id = $1
+              AND entity_logical_name = $2
+              AND record_id = $3
+            ORDER BY created_at DESC, id DESC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(record_id)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list record share links: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record share link list transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(RecordShareLink::from).collect())
+    }
+
+    async fn find_link(
+        &self,
+        tenant_id: TenantId,
+        link_id: &str,
+    ) -> AppResult<Option<RecordShareLink>> {
+        let Ok(link_uuid) = uuid::Uuid::parse_str(link_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RecordShareLinkRow>(&format!(
+            r#"
+            SELECT {LINK_COLUMNS}
+            FROM runtime_record_share_links
+            WHERE tenant_id = $1 AND id = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(link_uuid)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to find record share link: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record share link lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(RecordShareLink::from))
+    }
+
+    async fn find_link_by_token_hash(
+        &self,
+        tenant_id: TenantId,
+        token_hash: &str,
+    ) -> AppResult<Option<RecordShareLink>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RecordShareLinkRow>(&format!(
+            r#"
+            SELECT {LINK_COLUMNS}
+            FROM runtime_record_share_links
+            WHERE tenant_id = $1 AND token_hash = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(token_hash)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find record share link by token: {error}"
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record share link token lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(RecordShareLink::from))
+    }
+
+    async fn revoke_link(
+        &self,
+        tenant_id: TenantId,
+        link_id: &str,
+        revoked_by_subject: &str,
+    ) -> AppResult<Option<RecordShareLink>> {
+        let Ok(link_uuid) = uuid::Uuid::parse_str(link_id) else {
+            return Ok(None);
+        };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, RecordShareLinkRow>(&format!(
+            r#"
+            UPDATE runtime_record_share_links
+            SET revoked_by_subject = $3,
+                revoked_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND revoked_at IS NULL
+            RETURNING {LINK_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(link_uuid)
+        .bind(revoked_by_subject)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to revoke record share link: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record share link revocation transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(RecordShareLink::from))
+    }
+
+    async fn record_view(
+        &self,
+        tenant_id: TenantId,
+        link_id: &str,
+        outcome: RecordShareLinkViewOutcome,
+        context: RecordShareLinkViewContext,
+    ) -> AppResult<()> {
+        let link_uuid = uuid::Uuid::parse_str(link_id).map_err(|error| {
+            AppError::Validation(format!(
+                "record share link id must be a valid UUID: {error}"
+            ))
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO runtime_record_share_link_views (
+                tenant_id,
+                link_id,
+                outcome,
+                ip_address,
+                user_agent
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(link_uuid)
+        .bind(outcome.as_str())
+        .bind(context.ip_address.as_deref())
+        .bind(context.user_agent.as_deref())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to log record share link view: {error}"))
+        })?;
+
+        if outcome == RecordShareLinkViewOutcome::Viewed {
+            sqlx::query(
+                r#"
+                UPDATE runtime_record_share_links
+                SET view_count = view_count + 1,
+                    last_viewed_at = now()
+                WHERE tenant_id = $1 AND id = $2
+                "#,
+            )
+            .bind(tenant_id.as_uuid())
+            .bind(link_uuid)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to update record share link view counters: {error}"
+                ))
+            })?;
+        }
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record share link view transaction: {error}"
+            ))
+        })
+    }
+
+    async fn list_views(
+        &self,
+        tenant_id: TenantId,
+        link_id: &str,
+        limit: usize,
+    ) -> AppResult<Vec<RecordShareLinkView>> {
+        let Ok(link_uuid) = uuid::Uuid::parse_str(link_id) else {
+            return Ok(Vec::new());
+        };
+        let limit = i64::try_from(limit).map_err(|error| {
+            AppError::Validation(format!("invalid record share link view limit: {error}"))
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, RecordShareLinkViewRow>(
+            r#"
+            SELECT link_id, outcome, ip_address, user_agent, viewed_at
+            FROM runtime_record_share_link_views
+            WHERE tenant_id = $1 AND link_id = $2
+            ORDER BY viewed_at DESC, id DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(link_uuid)
+        .bind(limit)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list record share link views: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record share link view list transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(RecordShareLinkView::try_from)
+            .collect()
+    }
+}
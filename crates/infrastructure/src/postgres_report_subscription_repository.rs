@@ -0,0 +1,398 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    DueReportSubscription, NewReportSubscription, ReportSubscription, ReportSubscriptionFormat,
+    ReportSubscriptionFrequency, ReportSubscriptionRepository,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+use crate::postgres_tenant_rls::begin_report_subscription_transaction;
+
+const SUBSCRIPTION_COLUMNS: &str = "id, tenant_id, subject, subscriber_display_name, \
+     recipient_email, entity_logical_name, view_logical_name, frequency, format, \
+     delivery_hour_utc, next_delivery_at, last_delivered_at, suppressed_at, \
+     suppression_reason, created_at";
+
+/// PostgreSQL-backed repository for scheduled report subscriptions.
+#[derive(Clone)]
+pub struct PostgresReportSubscriptionRepository {
+    pool: PgPool,
+}
+
+impl PostgresReportSubscriptionRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct ReportSubscriptionRow {
+    id: uuid::Uuid,
+    tenant_id: uuid::Uuid,
+    subject: String,
+    subscriber_display_name: String,
+    recipient_email: String,
+    entity_logical_name: String,
+    view_logical_name: String,
+    frequency: String,
+    format: String,
+    delivery_hour_utc: i16,
+    next_delivery_at: DateTime<Utc>,
+    last_delivered_at: Option<DateTime<Utc>>,
+    suppressed_at: Option<DateTime<Utc>>,
+    suppression_reason: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct ClaimedReportSubscriptionRow {
+    scheduled_for: DateTime<Utc>,
+    #[sqlx(flatten)]
+    subscription: ReportSubscriptionRow,
+}
+
+impl TryFrom<ReportSubscriptionRow> for ReportSubscription {
+    type Error = AppError;
+
+    fn try_from(row: ReportSubscriptionRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            subscription_id: row.id.to_string(),
+            subject: row.subject,
+            subscriber_display_name: row.subscriber_display_name,
+            recipient_email: row.recipient_email,
+            entity_logical_name: row.entity_logical_name,
+            view_logical_name: row.view_logical_name,
+            frequency: ReportSubscriptionFrequency::parse(row.frequency.as_str())?,
+            format: ReportSubscriptionFormat::parse(row.format.as_str())?,
+            delivery_hour_utc: u8::try_from(row.delivery_hour_utc).map_err(|_| {
+                AppError::Internal("stored report delivery hour is out of range".to_owned())
+            })?,
+            next_delivery_at: row.next_delivery_at,
+            last_delivered_at: row.last_delivered_at,
+            suppressed_at: row.suppressed_at,
+            suppression_reason: row.suppression_reason,
+            created_at: row.created_at,
+        })
+    }
+}
+
+fn parse_subscription_id(subscription_id: &str) -> AppResult<uuid::Uuid> {
+    uuid::Uuid::parse_str(subscription_id).map_err(|_| {
+        AppError::NotFound(format!(
+            "report subscription '{subscription_id}' does not exist"
+        ))
+    })
+}
+
+fn commit_error(error: sqlx::Error) -> AppError {
+    AppError::Internal(format!(
+        "failed to commit report subscription transaction: {error}"
+    ))
+}
+
+#[async_trait]
+impl ReportSubscriptionRepository for PostgresReportSubscriptionRepository {
+    async fn create_subscription(
+        &self,
+        tenant_id: TenantId,
+        subscription: NewReportSubscription,
+        tenant_limit: usize,
+    ) -> AppResult<ReportSubscription> {
+        let tenant_limit = i64::try_from(tenant_limit).map_err(|_| {
+            AppError::Validation("report subscription limit is out of range".to_owned())
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        // Serialise creations per tenant so concurrent requests cannot overshoot the cap.
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext('report_subscriptions:' || $1::text))")
+            .bind(tenant_id.as_uuid())
+            .execute(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to lock report subscriptions for tenant: {error}"
+                ))
+            })?;
+
+        let active_count = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*)
+            FROM report_subscriptions
+            WHERE tenant_id = $1 AND suppressed_at IS NULL
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to count report subscriptions: {error}"))
+        })?;
+
+        if active_count >= tenant_limit {
+            return Err(AppError::Conflict(format!(
+                "tenant already has the maximum of {tenant_limit} active report subscriptions"
+            )));
+        }
+
+        let row = sqlx::query_as::<_, ReportSubscriptionRow>(&format!(
+            r#"
+            INSERT INTO report_subscriptions (
+                tenant_id,
+                subject,
+                subscriber_display_name,
+                recipient_email,
+                entity_logical_name,
+                view_logical_name,
+                frequency,
+                format,
+                delivery_hour_utc,
+                next_delivery_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING {SUBSCRIPTION_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(subscription.subject.as_str())
+        .bind(subscription.subscriber_display_name.as_str())
+        .bind(subscription.recipient_email.as_str())
+        .bind(subscription.entity_logical_name.as_str())
+        .bind(subscription.view_logical_name.as_str())
+        .bind(subscription.frequency.as_str())
+        .bind(subscription.format.as_str())
+        .bind(i16::from(subscription.delivery_hour_utc))
+        .bind(subscription.next_delivery_at)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            if let sqlx::Error::Database(database_error) = &error
+                && database_error.code().as_deref() == Some("23505")
+            {
+                return AppError::Conflict(format!(
+                    "already subscribed to view '{}.{}'",
+                    subscription.entity_logical_name, subscription.view_logical_name
+                ));
+            }
+            AppError::Internal(format!("failed to create report subscription: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        ReportSubscription::try_from(row)
+    }
+
+    async fn list_subscriptions(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+    ) -> AppResult<Vec<ReportSubscription>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, ReportSubscriptionRow>(&format!(
+            r#"
+            SELECT {SUBSCRIPTION_COLUMNS}
+            FROM report_subscriptions
+            WHERE tenant_id = $1 AND subject = $2
+            ORDER BY created_at DESC, id DESC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list report subscriptions: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.into_iter().map(ReportSubscription::try_from).collect()
+    }
+
+    async fn delete_subscription(
+        &self,
+        tenant_id: TenantId,
+        subject: &str,
+        subscription_id: &str,
+    ) -> AppResult<()> {
+        let subscription_uuid = parse_subscription_id(subscription_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM report_subscriptions
+            WHERE tenant_id = $1 AND subject = $2 AND id = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .bind(subscription_uuid)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to delete report subscription: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!(
+                "report subscription '{subscription_id}' does not exist"
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn suppress_subscriptions_for_view(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+        view_logical_name: &str,
+        reason: &str,
+    ) -> AppResult<u64> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            UPDATE report_subscriptions
+            SET suppressed_at = now(), suppression_reason = $4
+            WHERE tenant_id = $1
+              AND entity_logical_name = $2
+              AND view_logical_name = $3
+              AND suppressed_at IS NULL
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(entity_logical_name)
+        .bind(view_logical_name)
+        .bind(reason)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to suppress report subscriptions: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn suppress_subscription(
+        &self,
+        tenant_id: TenantId,
+        subscription_id: &str,
+        reason: &str,
+    ) -> AppResult<()> {
+        let subscription_uuid = parse_subscription_id(subscription_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            UPDATE report_subscriptions
+            SET suppressed_at = now(), suppression_reason = $3
+            WHERE tenant_id = $1 AND id = $2 AND suppressed_at IS NULL
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subscription_uuid)
+        .bind(reason)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to suppress report subscription: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)
+    }
+
+    async fn claim_due_subscriptions(
+        &self,
+        now: DateTime<Utc>,
+        retry_at: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<DueReportSubscription>> {
+        let limit = i64::try_from(limit).map_err(|_| {
+            AppError::Validation("report subscription claim size is out of range".to_owned())
+        })?;
+
+        let mut transaction = begin_report_subscription_transaction(&self.pool).await?;
+        let rows = sqlx::query_as::<_, ClaimedReportSubscriptionRow>(
+            r#"
+            WITH due AS (
+                SELECT id, next_delivery_at AS scheduled_for
+                FROM report_subscriptions
+                WHERE suppressed_at IS NULL AND next_delivery_at <= $1
+                ORDER BY next_delivery_at ASC, id ASC
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE report_subscriptions
+            SET next_delivery_at = $2
+            FROM due
+            WHERE report_subscriptions.id = due.id
+            RETURNING due.scheduled_for, report_subscriptions.*
+            "#,
+        )
+        .bind(now)
+        .bind(retry_at)
+        .bind(limit)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to claim report subscriptions: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.into_iter()
+            .map(|claimed| {
+                let tenant_id = TenantId::from_uuid(claimed.subscription.tenant_id);
+                Ok(DueReportSubscription {
+                    tenant_id,
+                    scheduled_for: claimed.scheduled_for,
+                    subscription: ReportSubscription::try_from(claimed.subscription)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn record_delivery(
+        &self,
+        tenant_id: TenantId,
+        subscription_id: &str,
+        delivered_at: DateTime<Utc>,
+        next_delivery_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        let subscription_uuid = parse_subscription_id(subscription_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            UPDATE report_subscriptions
+            SET last_delivered_at = $3, next_delivery_at = $4
+            WHERE tenant_id = $1 AND id = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subscription_uuid)
+        .bind(delivered_at)
+        .bind(next_delivery_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to record report subscription delivery: {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)
+    }
+}
+
+#[cfg(test)]
+mod tests;
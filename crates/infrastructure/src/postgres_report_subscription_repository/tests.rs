@@ -0,0 +1,162 @@
+use chrono::{Duration, Timelike, Utc};
+use qryvanta_application::{
+    NewReportSubscription, ReportSubscriptionFormat, ReportSubscriptionFrequency,
+    ReportSubscriptionRepository,
+};
+use qryvanta_core::{AppError, TenantId};
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresReportSubscriptionRepository;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres report subscription tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn ensure_tenant(pool: &PgPool, tenant_id: TenantId, name: &str) {
+    let insert = sqlx::query(
+        r#"
+            INSERT INTO tenants (id, name)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(name)
+    .execute(pool)
+    .await;
+
+    assert!(insert.is_ok());
+}
+
+fn subscription(view_logical_name: &str) -> NewReportSubscription {
+    NewReportSubscription {
+        subject: "alice".to_owned(),
+        subscriber_display_name: "Alice".to_owned(),
+        recipient_email: "alice@example.com".to_owned(),
+        entity_logical_name: "contact".to_owned(),
+        view_logical_name: view_logical_name.to_owned(),
+        frequency: ReportSubscriptionFrequency::Daily,
+        format: ReportSubscriptionFormat::Xlsx,
+        delivery_hour_utc: 6,
+        next_delivery_at: Utc::now() - Duration::minutes(1),
+    }
+}
+
+#[tokio::test]
+async fn subscriptions_are_capped_claimed_and_suppressed_per_tenant() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Report Tenant").await;
+    ensure_tenant(&pool, other_tenant_id, "Other Report Tenant").await;
+    let repository = PostgresReportSubscriptionRepository::new(pool);
+
+    let created = repository
+        .create_subscription(tenant_id, subscription("open_contacts"), 2)
+        .await
+        .unwrap_or_else(|error| panic!("failed to create subscription: {error}"));
+    assert_eq!(created.format, ReportSubscriptionFormat::Xlsx);
+
+    let duplicate = repository
+        .create_subscription(tenant_id, subscription("open_contacts"), 2)
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+
+    let second = repository
+        .create_subscription(tenant_id, subscription("all_contacts"), 2)
+        .await;
+    assert!(second.is_ok());
+    let over_limit = repository
+        .create_subscription(tenant_id, subscription("vip_contacts"), 2)
+        .await;
+    assert!(matches!(over_limit, Err(AppError::Conflict(_))));
+
+    let other_tenant_list = repository
+        .list_subscriptions(other_tenant_id, "alice")
+        .await
+        .unwrap_or_else(|error| panic!("failed to list subscriptions: {error}"));
+    assert!(other_tenant_list.is_empty());
+
+    let suppressed = repository
+        .suppress_subscriptions_for_view(tenant_id, "contact", "all_contacts", "view deleted")
+        .await;
+    assert_eq!(suppressed.ok(), Some(1));
+
+    let now = Utc::now();
+    let retry_at = (now + Duration::minutes(30))
+        .with_nanosecond(0)
+        .unwrap_or_else(|| unreachable!());
+    let claimed = repository
+        .claim_due_subscriptions(now, retry_at, 100)
+        .await
+        .unwrap_or_else(|error| panic!("failed to claim subscriptions: {error}"));
+    let claimed: Vec<_> = claimed
+        .into_iter()
+        .filter(|due| due.tenant_id == tenant_id)
+        .collect();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(
+        claimed[0].subscription.subscription_id,
+        created.subscription_id
+    );
+    assert_eq!(claimed[0].scheduled_for, created.next_delivery_at);
+    assert_eq!(claimed[0].subscription.next_delivery_at, retry_at);
+
+    let reclaimed = repository
+        .claim_due_subscriptions(now, retry_at, 100)
+        .await
+        .unwrap_or_else(|error| panic!("failed to claim subscriptions: {error}"));
+    assert!(reclaimed.iter().all(|due| due.tenant_id != tenant_id));
+
+    let next_delivery_at = claimed[0].scheduled_for + Duration::days(1);
+    let recorded = repository
+        .record_delivery(
+            tenant_id,
+            created.subscription_id.as_str(),
+            now,
+            next_delivery_at,
+        )
+        .await;
+    assert!(recorded.is_ok());
+
+    let listed = repository
+        .list_subscriptions(tenant_id, "alice")
+        .await
+        .unwrap_or_else(|error| panic!("failed to list subscriptions: {error}"));
+    assert_eq!(listed.len(), 2);
+    let delivered = listed
+        .iter()
+        .find(|stored| stored.subscription_id == created.subscription_id)
+        .unwrap_or_else(|| panic!("delivered subscription missing"));
+    assert_eq!(delivered.next_delivery_at, next_delivery_at);
+    assert!(delivered.last_delivered_at.is_some());
+
+    let deleted = repository
+        .delete_subscription(tenant_id, "mallory", created.subscription_id.as_str())
+        .await;
+    assert!(matches!(deleted, Err(AppError::NotFound(_))));
+}
@@ -0,0 +1,534 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    NewReportingProjection, ReportingProjection, ReportingProjectionColumn,
+    ReportingProjectionQuery, ReportingProjectionRepository, ReportingProjectionRow,
+    ReportingProjectionRowUpdate, RuntimeRecordChange,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+use crate::postgres_tenant_rls::begin_runtime_record_change_transaction;
+
+const PROJECTION_COLUMNS: &str = "id, logical_name, display_name, root_entity_logical_name, columns, updated_by_subject, updated_at";
+
+/// PostgreSQL-backed repository for reporting projections and the runtime
+/// record change feed that maintains them.
+#[derive(Clone)]
+pub struct PostgresReportingProjectionRepository {
+    pool: PgPool,
+}
+
+impl PostgresReportingProjectionRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct ReportingProjectionDbRow {
+    id: uuid::Uuid,
+    logical_name: String,
+    display_name: String,
+    root_entity_logical_name: String,
+    columns: Value,
+    updated_by_subject: String,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct ReportingProjectionDataRow {
+    record_id: String,
+    data: Value,
+    refreshed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct RuntimeRecordChangeRow {
+    id: i64,
+    tenant_id: uuid::Uuid,
+    entity_logical_name: String,
+    record_id: String,
+}
+
+impl TryFrom<ReportingProjectionDbRow> for ReportingProjection {
+    type Error = AppError;
+
+    fn try_from(row: ReportingProjectionDbRow) -> Result<Self, Self::Error> {
+        let Value::Array(columns) = row.columns else {
+            return Err(AppError::Internal(format!(
+                "reporting projection '{}' has malformed columns",
+                row.logical_name
+            )));
+        };
+        let columns = columns
+            .iter()
+            .map(|column| {
+                let text = |key: &str| column.get(key).and_then(Value::as_str).map(str::to_owned);
+                Some(ReportingProjectionColumn {
+                    column_name: text("column_name")?,
+                    relation_field_logical_name: text("relation_field_logical_name"),
+                    parent_entity_logical_name: text("parent_entity_logical_name"),
+                    field_logical_name: text("field_logical_name")?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                AppError::Internal(format!(
+                    "reporting projection '{}' has malformed columns",
+                    row.logical_name
+                ))
+            })?;
+
+        Ok(Self {
+            projection_id: row.id.to_string(),
+            logical_name: row.logical_name,
+            display_name: row.display_name,
+            root_entity_logical_name: row.root_entity_logical_name,
+            columns,
+            updated_by_subject: row.updated_by_subject,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+fn columns_to_value(columns: &[ReportingProjectionColumn]) -> Value {
+    Value::Array(
+        columns
+            .iter()
+            .map(|column| {
+                serde_json::json!({
+                    "column_name": column.column_name,
+                    "relation_field_logical_name": column.relation_field_logical_name,
+                    "parent_entity_logical_name": column.parent_entity_logical_name,
+                    "field_logical_name": column.field_logical_name,
+                })
+            })
+            .collect(),
+    )
+}
+
+fn parse_projection_id(projection_id: &str) -> AppResult<uuid::Uuid> {
+    uuid::Uuid::parse_str(projection_id).map_err(|_| {
+        AppError::NotFound(format!(
+            "reporting projection '{projection_id}' does not exist"
+        ))
+    })
+}
+
+fn commit_error(error: sqlx::Error) -> AppError {
+    AppError::Internal(format!(
+        "failed to commit reporting projection transaction: {error}"
+    ))
+}
+
+#[async_trait]
+impl ReportingProjectionRepository for PostgresReportingProjectionRepository {
+    async fn list_projections(&self, tenant_id: TenantId) -> AppResult<Vec<ReportingProjection>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, ReportingProjectionDbRow>(&format!(
+            r#"
+            SELECT {PROJECTION_COLUMNS}
+            FROM reporting_projections
+            WHERE tenant_id = $1
+            ORDER BY logical_name ASC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list reporting projections: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.into_iter()
+            .map(ReportingProjection::try_from)
+            .collect()
+    }
+
+    async fn find_projection(
+        &self,
+        tenant_id: TenantId,
+        logical_name: &str,
+    ) -> AppResult<Option<ReportingProjection>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, ReportingProjectionDbRow>(&format!(
+            r#"
+            SELECT {PROJECTION_COLUMNS}
+            FROM reporting_projections
+            WHERE tenant_id = $1 AND logical_name = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(logical_name)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find reporting projection '{logical_name}': {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        row.map(ReportingProjection::try_from).transpose()
+    }
+
+    async fn save_projection(
+        &self,
+        tenant_id: TenantId,
+        updated_by_subject: &str,
+        projection: NewReportingProjection,
+    ) -> AppResult<ReportingProjection> {
+        let source_entity_logical_names = projection.source_entity_logical_names();
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, ReportingProjectionDbRow>(&format!(
+            r#"
+            INSERT INTO reporting_projections (
+                tenant_id,
+                logical_name,
+                display_name,
+                root_entity_logical_name,
+                columns,
+                source_entity_logical_names,
+                updated_by_subject
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (tenant_id, logical_name) DO UPDATE
+            SET
+                display_name = EXCLUDED.display_name,
+                root_entity_logical_name = EXCLUDED.root_entity_logical_name,
+                columns = EXCLUDED.columns,
+                source_entity_logical_names = EXCLUDED.source_entity_logical_names,
+                updated_by_subject = EXCLUDED.updated_by_subject,
+                updated_at = now()
+            RETURNING {PROJECTION_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(projection.logical_name.as_str())
+        .bind(projection.display_name.as_str())
+        .bind(projection.root_entity_logical_name.as_str())
+        .bind(columns_to_value(&projection.columns))
+        .bind(&source_entity_logical_names)
+        .bind(updated_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to save reporting projection '{}': {error}",
+                projection.logical_name
+            ))
+        })?;
+
+        sqlx::query(
+            "DELETE FROM reporting_projection_rows WHERE tenant_id = $1 AND projection_id = $2",
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(row.id)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to reset reporting projection rows: {error}"
+            ))
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO runtime_record_changes (tenant_id, entity_logical_name, record_id)
+            SELECT tenant_id, entity_logical_name, id::text
+            FROM runtime_records
+            WHERE tenant_id = $1 AND entity_logical_name = $2
+            ORDER BY created_at ASC, id ASC
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(projection.root_entity_logical_name.as_str())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to queue reporting projection rebuild: {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        ReportingProjection::try_from(row)
+    }
+
+    async fn delete_projection(&self, tenant_id: TenantId, logical_name: &str) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            "DELETE FROM reporting_projections WHERE tenant_id = $1 AND logical_name = $2",
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(logical_name)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete reporting projection '{logical_name}': {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn upsert_projection_row(
+        &self,
+        tenant_id: TenantId,
+        projection_id: &str,
+        row: ReportingProjectionRowUpdate,
+    ) -> AppResult<()> {
+        let projection_uuid = parse_projection_id(projection_id)?;
+
+        // The projection may have been deleted while the row was rebuilt; the
+        // insert then matches no projection and is skipped.
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO reporting_projection_rows (
+                projection_id,
+                tenant_id,
+                record_id,
+                parent_record_ids,
+                data
+            )
+            SELECT id, tenant_id, $3, $4, $5
+            FROM reporting_projections
+            WHERE tenant_id = $1 AND id = $2
+            ON CONFLICT (projection_id, record_id) DO UPDATE
+            SET
+                parent_record_ids = EXCLUDED.parent_record_ids,
+                data = EXCLUDED.data,
+                refreshed_at = now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(projection_uuid)
+        .bind(row.record_id.as_str())
+        .bind(&row.parent_record_ids)
+        .bind(&row.values)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to write reporting projection row: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)
+    }
+
+    async fn delete_projection_row(
+        &self,
+        tenant_id: TenantId,
+        projection_id: &str,
+        record_id: &str,
+    ) -> AppResult<()> {
+        let projection_uuid = parse_projection_id(projection_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            DELETE FROM reporting_projection_rows
+            WHERE tenant_id = $1 AND projection_id = $2 AND record_id = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(projection_uuid)
+        .bind(record_id)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete reporting projection row: {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)
+    }
+
+    async fn list_projection_rows_joining(
+        &self,
+        tenant_id: TenantId,
+        projection_id: &str,
+        parent_record_id: &str,
+    ) -> AppResult<Vec<String>> {
+        let projection_uuid = parse_projection_id(projection_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let record_ids = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT record_id
+            FROM reporting_projection_rows
+            WHERE tenant_id = $1 AND projection_id = $2 AND parent_record_ids @> ARRAY[$3]
+            ORDER BY record_id ASC
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(projection_uuid)
+        .bind(parent_record_id)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list reporting projection rows joining a parent record: {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        Ok(record_ids)
+    }
+
+    async fn query_projection_rows(
+        &self,
+        tenant_id: TenantId,
+        projection_id: &str,
+        query: &ReportingProjectionQuery,
+    ) -> AppResult<Vec<ReportingProjectionRow>> {
+        let projection_uuid = parse_projection_id(projection_id)?;
+        let limit = i64::try_from(query.limit).map_err(|_| {
+            AppError::Validation("reporting projection query limit is out of range".to_owned())
+        })?;
+        let offset = i64::try_from(query.offset).map_err(|_| {
+            AppError::Validation("reporting projection query offset is out of range".to_owned())
+        })?;
+        let filter = Value::Object(
+            query
+                .filters
+                .iter()
+                .map(|filter| (filter.column_name.clone(), filter.value.clone()))
+                .collect(),
+        );
+        let direction = if query.sort_descending { "DESC" } else { "ASC" };
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, ReportingProjectionDataRow>(&format!(
+            r#"
+            SELECT record_id, data, refreshed_at
+            FROM reporting_projection_rows
+            WHERE tenant_id = $1 AND projection_id = $2 AND data @> $3
+            ORDER BY NULLIF(data -> $4, 'null'::jsonb) {direction} NULLS LAST, record_id {direction}
+            LIMIT $5 OFFSET $6
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(projection_uuid)
+        .bind(filter)
+        .bind(query.sort_column.as_deref())
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to query reporting projection rows: {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ReportingProjectionRow {
+                record_id: row.record_id,
+                values: row.data,
+                refreshed_at: row.refreshed_at,
+            })
+            .collect())
+    }
+
+    async fn claim_runtime_record_changes(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: usize,
+    ) -> AppResult<Vec<RuntimeRecordChange>> {
+        let limit = i64::try_from(limit).map_err(|_| {
+            AppError::Validation("runtime record change claim size is out of range".to_owned())
+        })?;
+
+        let mut transaction = begin_runtime_record_change_transaction(&self.pool).await?;
+        let mut rows = sqlx::query_as::<_, RuntimeRecordChangeRow>(
+            r#"
+            WITH pending AS (
+                SELECT id
+                FROM runtime_record_changes
+                WHERE leased_until IS NULL OR leased_until <= $1
+                ORDER BY id ASC
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE runtime_record_changes
+            SET leased_until = $2
+            FROM pending
+            WHERE runtime_record_changes.id = pending.id
+            RETURNING
+                runtime_record_changes.id,
+                runtime_record_changes.tenant_id,
+                runtime_record_changes.entity_logical_name,
+                runtime_record_changes.record_id
+            "#,
+        )
+        .bind(now)
+        .bind(lease_until)
+        .bind(limit)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to claim runtime record changes: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(commit_error)?;
+
+        rows.sort_by_key(|row| row.id);
+        Ok(rows
+            .into_iter()
+            .map(|row| RuntimeRecordChange {
+                change_id: row.id,
+                tenant_id: TenantId::from_uuid(row.tenant_id),
+                entity_logical_name: row.entity_logical_name,
+                record_id: row.record_id,
+            })
+            .collect())
+    }
+
+    async fn acknowledge_runtime_record_changes(
+        &self,
+        tenant_id: TenantId,
+        change_ids: &[i64],
+    ) -> AppResult<()> {
+        if change_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query("DELETE FROM runtime_record_changes WHERE tenant_id = $1 AND id = ANY($2)")
+            .bind(tenant_id.as_uuid())
+            .bind(change_ids)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to acknowledge runtime record changes: {error}"
+                ))
+            })?;
+
+        transaction.commit().await.map_err(commit_error)
+    }
+}
+
+#[cfg(test)]
+mod tests;
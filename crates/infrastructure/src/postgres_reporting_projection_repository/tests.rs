@@ -0,0 +1,400 @@
+use chrono::{Duration, Utc};
+use qryvanta_application::{
+    NewReportingProjection, ReportingProjectionColumn, ReportingProjectionFilter,
+    ReportingProjectionQuery, ReportingProjectionRepository, ReportingProjectionRowUpdate,
+};
+use qryvanta_core::TenantId;
+use serde_json::json;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresReportingProjectionRepository;
+use crate::begin_tenant_transaction;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres reporting projection tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn seed_tenant(pool: &PgPool, tenant_id: TenantId) {
+    let inserted = sqlx::query("INSERT INTO tenants (id, name) VALUES ($1, $2)")
+        .bind(tenant_id.as_uuid())
+        .bind(format!("Reporting Projection Tenant {tenant_id}"))
+        .execute(pool)
+        .await;
+    assert!(inserted.is_ok());
+
+    let mut transaction = begin_tenant_transaction(pool, tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to begin tenant transaction: {error}"));
+    for statement in [
+        "INSERT INTO entity_definitions (tenant_id, logical_name, display_name) VALUES ($1, 'contact', 'Contact')",
+        "INSERT INTO entity_definitions (tenant_id, logical_name, display_name) VALUES ($1, 'account', 'Account')",
+    ] {
+        let inserted = sqlx::query(statement)
+            .bind(tenant_id.as_uuid())
+            .execute(&mut *transaction)
+            .await;
+        assert!(inserted.is_ok(), "{statement}: {inserted:?}");
+    }
+    assert!(transaction.commit().await.is_ok());
+}
+
+async fn insert_record(
+    pool: &PgPool,
+    tenant_id: TenantId,
+    entity_logical_name: &str,
+    data: serde_json::Value,
+) -> String {
+    let mut transaction = begin_tenant_transaction(pool, tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to begin tenant transaction: {error}"));
+    let record_id = sqlx::query_scalar::<_, uuid::Uuid>(
+        r#"
+        INSERT INTO runtime_records (tenant_id, entity_logical_name, data, created_by_subject)
+        VALUES ($1, $2, $3, 'alice')
+        RETURNING id
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(entity_logical_name)
+    .bind(data)
+    .fetch_one(&mut *transaction)
+    .await
+    .unwrap_or_else(|error| panic!("failed to insert runtime record: {error}"));
+    assert!(transaction.commit().await.is_ok());
+
+    record_id.to_string()
+}
+
+async fn execute_for_tenant(pool: &PgPool, tenant_id: TenantId, statement: &str, record_id: &str) {
+    let mut transaction = begin_tenant_transaction(pool, tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to begin tenant transaction: {error}"));
+    let executed = sqlx::query(statement)
+        .bind(tenant_id.as_uuid())
+        .bind(uuid::Uuid::parse_str(record_id).unwrap_or_else(|_| unreachable!()))
+        .execute(&mut *transaction)
+        .await;
+    assert!(executed.is_ok(), "{statement}: {executed:?}");
+    assert!(transaction.commit().await.is_ok());
+}
+
+async fn pending_changes(pool: &PgPool, tenant_id: TenantId) -> Vec<(String, String)> {
+    let mut transaction = begin_tenant_transaction(pool, tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to begin tenant transaction: {error}"));
+    let changes = sqlx::query_as::<_, (String, String)>(
+        "SELECT entity_logical_name, record_id FROM runtime_record_changes WHERE tenant_id = $1 ORDER BY id",
+    )
+    .bind(tenant_id.as_uuid())
+    .fetch_all(&mut *transaction)
+    .await
+    .unwrap_or_else(|error| panic!("failed to list runtime record changes: {error}"));
+    assert!(transaction.commit().await.is_ok());
+
+    changes
+}
+
+fn contact_accounts() -> NewReportingProjection {
+    NewReportingProjection {
+        logical_name: "contact_accounts".to_owned(),
+        display_name: "Contacts with accounts".to_owned(),
+        root_entity_logical_name: "contact".to_owned(),
+        columns: vec![
+            ReportingProjectionColumn {
+                column_name: "contact_name".to_owned(),
+                relation_field_logical_name: None,
+                parent_entity_logical_name: None,
+                field_logical_name: "full_name".to_owned(),
+            },
+            ReportingProjectionColumn {
+                column_name: "account_name".to_owned(),
+                relation_field_logical_name: Some("account_id".to_owned()),
+                parent_entity_logical_name: Some("account".to_owned()),
+                field_logical_name: "name".to_owned(),
+            },
+        ],
+    }
+}
+
+#[tokio::test]
+async fn change_feed_captures_source_entity_writes_once_a_projection_exists() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresReportingProjectionRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    seed_tenant(&pool, tenant_id).await;
+
+    let account_id = insert_record(&pool, tenant_id, "account", json!({"name": "Acme"})).await;
+    let contact_id = insert_record(
+        &pool,
+        tenant_id,
+        "contact",
+        json!({"full_name": "Ada", "account_id": account_id}),
+    )
+    .await;
+    assert!(pending_changes(&pool, tenant_id).await.is_empty());
+
+    let projection = repository
+        .save_projection(tenant_id, "alice", contact_accounts())
+        .await
+        .unwrap_or_else(|error| panic!("failed to save projection: {error}"));
+    assert_eq!(projection.columns, contact_accounts().columns);
+    assert_eq!(
+        pending_changes(&pool, tenant_id).await,
+        vec![("contact".to_owned(), contact_id.clone())]
+    );
+
+    execute_for_tenant(
+        &pool,
+        tenant_id,
+        "UPDATE runtime_records SET data = '{\"name\": \"Acme Corp\"}'::jsonb WHERE tenant_id = $1 AND id = $2",
+        account_id.as_str(),
+    )
+    .await;
+    execute_for_tenant(
+        &pool,
+        tenant_id,
+        "UPDATE runtime_records SET updated_at = now() WHERE tenant_id = $1 AND id = $2",
+        account_id.as_str(),
+    )
+    .await;
+    execute_for_tenant(
+        &pool,
+        tenant_id,
+        "DELETE FROM runtime_records WHERE tenant_id = $1 AND id = $2",
+        contact_id.as_str(),
+    )
+    .await;
+    assert_eq!(
+        pending_changes(&pool, tenant_id).await,
+        vec![
+            ("contact".to_owned(), contact_id.clone()),
+            ("account".to_owned(), account_id.clone()),
+            ("contact".to_owned(), contact_id.clone()),
+        ]
+    );
+
+    let now = Utc::now();
+    let claimed = repository
+        .claim_runtime_record_changes(now, now + Duration::minutes(5), 10_000)
+        .await
+        .unwrap_or_else(|error| panic!("failed to claim changes: {error}"))
+        .into_iter()
+        .filter(|change| change.tenant_id == tenant_id)
+        .collect::<Vec<_>>();
+    assert_eq!(claimed.len(), 3);
+    assert!(
+        claimed
+            .windows(2)
+            .all(|pair| pair[0].change_id < pair[1].change_id)
+    );
+    let reclaimed = repository
+        .claim_runtime_record_changes(now, now + Duration::minutes(5), 10_000)
+        .await
+        .unwrap_or_else(|error| panic!("failed to claim changes: {error}"));
+    assert!(reclaimed.iter().all(|change| change.tenant_id != tenant_id));
+
+    let acknowledged = repository
+        .acknowledge_runtime_record_changes(
+            tenant_id,
+            &claimed
+                .iter()
+                .map(|change| change.change_id)
+                .collect::<Vec<_>>(),
+        )
+        .await;
+    assert!(acknowledged.is_ok());
+    assert!(pending_changes(&pool, tenant_id).await.is_empty());
+
+    assert!(
+        repository
+            .delete_projection(tenant_id, "contact_accounts")
+            .await
+            .unwrap_or(false)
+    );
+    insert_record(&pool, tenant_id, "contact", json!({"full_name": "Grace"})).await;
+    assert!(pending_changes(&pool, tenant_id).await.is_empty());
+}
+
+#[tokio::test]
+async fn projection_rows_are_queried_joined_and_tenant_scoped() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresReportingProjectionRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    seed_tenant(&pool, tenant_id).await;
+    seed_tenant(&pool, other_tenant_id).await;
+
+    let projection = repository
+        .save_projection(tenant_id, "alice", contact_accounts())
+        .await
+        .unwrap_or_else(|error| panic!("failed to save projection: {error}"));
+    for (record_id, parent_record_ids, values) in [
+        (
+            "c1",
+            vec!["a1"],
+            json!({"contact_name": "Ada", "account_name": "Acme"}),
+        ),
+        (
+            "c2",
+            vec!["a2"],
+            json!({"contact_name": "Grace", "account_name": "Globex"}),
+        ),
+        (
+            "c3",
+            vec!["a1"],
+            json!({"contact_name": "Linus", "account_name": "Acme"}),
+        ),
+        (
+            "c4",
+            Vec::new(),
+            json!({"contact_name": "Barbara", "account_name": null}),
+        ),
+    ] {
+        let upserted = repository
+            .upsert_projection_row(
+                tenant_id,
+                projection.projection_id.as_str(),
+                ReportingProjectionRowUpdate {
+                    record_id: record_id.to_owned(),
+                    parent_record_ids: parent_record_ids.into_iter().map(str::to_owned).collect(),
+                    values,
+                },
+            )
+            .await;
+        assert!(upserted.is_ok());
+    }
+
+    let joining = repository
+        .list_projection_rows_joining(tenant_id, projection.projection_id.as_str(), "a1")
+        .await
+        .unwrap_or_default();
+    assert_eq!(joining, vec!["c1".to_owned(), "c3".to_owned()]);
+
+    let acme = repository
+        .query_projection_rows(
+            tenant_id,
+            projection.projection_id.as_str(),
+            &ReportingProjectionQuery {
+                filters: vec![ReportingProjectionFilter {
+                    column_name: "account_name".to_owned(),
+                    value: json!("Acme"),
+                }],
+                sort_column: Some("contact_name".to_owned()),
+                sort_descending: true,
+                limit: 10,
+                offset: 0,
+            },
+        )
+        .await
+        .unwrap_or_default();
+    assert_eq!(
+        acme.iter()
+            .map(|row| row.record_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["c3", "c1"]
+    );
+
+    let by_account = repository
+        .query_projection_rows(
+            tenant_id,
+            projection.projection_id.as_str(),
+            &ReportingProjectionQuery {
+                filters: Vec::new(),
+                sort_column: Some("account_name".to_owned()),
+                sort_descending: false,
+                limit: 3,
+                offset: 1,
+            },
+        )
+        .await
+        .unwrap_or_default();
+    assert_eq!(
+        by_account
+            .iter()
+            .map(|row| row.record_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["c3", "c2", "c4"]
+    );
+
+    assert!(
+        repository
+            .delete_projection_row(tenant_id, projection.projection_id.as_str(), "c4")
+            .await
+            .is_ok()
+    );
+    let other_tenant_rows = repository
+        .query_projection_rows(
+            other_tenant_id,
+            projection.projection_id.as_str(),
+            &ReportingProjectionQuery {
+                filters: Vec::new(),
+                sort_column: None,
+                sort_descending: false,
+                limit: 10,
+                offset: 0,
+            },
+        )
+        .await
+        .unwrap_or_default();
+    assert!(other_tenant_rows.is_empty());
+    assert!(
+        repository
+            .list_projections(other_tenant_id)
+            .await
+            .unwrap_or_default()
+            .is_empty()
+    );
+
+    assert!(
+        repository
+            .delete_projection(tenant_id, "contact_accounts")
+            .await
+            .unwrap_or(false)
+    );
+    assert!(
+        repository
+            .find_projection(tenant_id, "contact_accounts")
+            .await
+            .unwrap_or_default()
+            .is_none()
+    );
+    let upsert_after_delete = repository
+        .upsert_projection_row(
+            tenant_id,
+            projection.projection_id.as_str(),
+            ReportingProjectionRowUpdate {
+                record_id: "c1".to_owned(),
+                parent_record_ids: Vec::new(),
+                values: json!({}),
+            },
+        )
+        .await;
+    assert!(upsert_after_delete.is_ok());
+}
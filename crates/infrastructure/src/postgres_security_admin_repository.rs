@@ -6,12 +6,14 @@ use async_trait::async_trait;
 use sqlx::{FromRow, PgPool, Postgres, Transaction};
 
 use qryvanta_application::{
-    AuditRetentionPolicy, CreateRoleInput, CreateTemporaryAccessGrantInput, RoleAssignment,
-    RoleDefinition, RuntimeFieldPermissionEntry, SaveRuntimeFieldPermissionsInput,
-    SecurityAdminRepository, TemporaryAccessGrant, TemporaryAccessGrantQuery,
+    AuditRetentionPolicy, CreateMfaResetRequestInput, CreateRoleInput,
+    CreateTemporaryAccessGrantInput, MfaResetRequest, RoleAssignment, RoleDefinition,
+    RuntimeFieldPermissionEntry, RuntimeRecordOwnerDirectory, SaveRuntimeFieldPermissionsInput,
+    SecurityAdminRepository, SecurityTeam, SecurityTeamMember, TemporaryAccessGrant,
+    TemporaryAccessGrantQuery, WorkflowConcurrencyPolicy,
 };
 use qryvanta_core::{AppError, AppResult, TenantId};
-use qryvanta_domain::{Permission, RegistrationMode};
+use qryvanta_domain::{Permission, RegistrationMode, SubjectType, TeamDefinition};
 
 /// PostgreSQL-backed repository for role administration.
 #[derive(Clone)]
@@ -37,6 +39,7 @@ struct RoleRow {
 
 #[derive(Debug, FromRow)]
 struct RoleAssignmentRow {
+    subject_type: String,
     subject: String,
     role_id: uuid::Uuid,
     role_name: String,
@@ -45,6 +48,7 @@ struct RoleAssignmentRow {
 
 #[derive(Debug, FromRow)]
 struct RuntimeFieldPermissionRow {
+    subject_type: String,
     subject: String,
     entity_logical_name: String,
     field_logical_name: String,
@@ -53,6 +57,24 @@ struct RuntimeFieldPermissionRow {
     updated_at: String,
 }
 
+#[derive(Debug, FromRow)]
+struct SecurityTeamRow {
+    team_id: uuid::Uuid,
+    name: String,
+    description: Option<String>,
+    member_count: i64,
+    created_by_subject: String,
+    created_at: String,
+}
+
+#[derive(Debug, FromRow)]
+struct SecurityTeamMemberRow {
+    team_name: String,
+    subject: String,
+    added_by_subject: String,
+    added_at: String,
+}
+
 #[derive(Debug, FromRow)]
 struct TemporaryAccessGrantRow {
     grant_id: uuid::Uuid,
@@ -64,9 +86,23 @@ struct TemporaryAccessGrantRow {
     permission: Option<String>,
 }
 
+#[derive(Debug, FromRow)]
+struct MfaResetRequestRow {
+    request_id: uuid::Uuid,
+    subject: String,
+    reason: String,
+    requested_by_subject: String,
+    requested_at: String,
+    expires_at: String,
+    approved_by_subject: Option<String>,
+    approved_at: Option<String>,
+}
+
 mod governance;
+mod mfa_reset;
 mod roles;
 mod runtime_permissions;
+mod teams;
 mod temporary_access;
 
 #[async_trait]
@@ -86,20 +122,22 @@ impl SecurityAdminRepository for PostgresSecurityAdminRepository {
     async fn assign_role_to_subject(
         &self,
         tenant_id: TenantId,
+        subject_type: SubjectType,
         subject: &str,
         role_name: &str,
     ) -> AppResult<()> {
-        self.assign_role_to_subject_impl(tenant_id, subject, role_name)
+        self.assign_role_to_subject_impl(tenant_id, subject_type, subject, role_name)
             .await
     }
 
     async fn remove_role_from_subject(
         &self,
         tenant_id: TenantId,
+        subject_type: SubjectType,
         subject: &str,
         role_name: &str,
     ) -> AppResult<()> {
-        self.remove_role_from_subject_impl(tenant_id, subject, role_name)
+        self.remove_role_from_subject_impl(tenant_id, subject_type, subject, role_name)
             .await
     }
 
@@ -126,6 +164,49 @@ impl SecurityAdminRepository for PostgresSecurityAdminRepository {
             .await
     }
 
+    async fn create_team(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        team: TeamDefinition,
+    ) -> AppResult<SecurityTeam> {
+        self.create_team_impl(tenant_id, created_by_subject, team)
+            .await
+    }
+
+    async fn list_teams(&self, tenant_id: TenantId) -> AppResult<Vec<SecurityTeam>> {
+        self.list_teams_impl(tenant_id).await
+    }
+
+    async fn add_team_member(
+        &self,
+        tenant_id: TenantId,
+        added_by_subject: &str,
+        team_name: &str,
+        subject: &str,
+    ) -> AppResult<SecurityTeamMember> {
+        self.add_team_member_impl(tenant_id, added_by_subject, team_name, subject)
+            .await
+    }
+
+    async fn remove_team_member(
+        &self,
+        tenant_id: TenantId,
+        team_name: &str,
+        subject: &str,
+    ) -> AppResult<()> {
+        self.remove_team_member_impl(tenant_id, team_name, subject)
+            .await
+    }
+
+    async fn list_team_members(
+        &self,
+        tenant_id: TenantId,
+        team_name: &str,
+    ) -> AppResult<Vec<SecurityTeamMember>> {
+        self.list_team_members_impl(tenant_id, team_name).await
+    }
+
     async fn create_temporary_access_grant(
         &self,
         tenant_id: TenantId,
@@ -161,6 +242,45 @@ impl SecurityAdminRepository for PostgresSecurityAdminRepository {
             .await
     }
 
+    async fn create_mfa_reset_request(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        input: CreateMfaResetRequestInput,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<MfaResetRequest> {
+        self.create_mfa_reset_request_impl(tenant_id, requested_by_subject, input, expires_at)
+            .await
+    }
+
+    async fn find_mfa_reset_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+    ) -> AppResult<Option<MfaResetRequest>> {
+        self.find_mfa_reset_request_impl(tenant_id, request_id)
+            .await
+    }
+
+    async fn approve_mfa_reset_request(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+        approved_by_subject: &str,
+    ) -> AppResult<MfaResetRequest> {
+        self.approve_mfa_reset_request_impl(tenant_id, request_id, approved_by_subject)
+            .await
+    }
+
+    async fn list_mfa_reset_requests(
+        &self,
+        tenant_id: TenantId,
+        pending_only: bool,
+    ) -> AppResult<Vec<MfaResetRequest>> {
+        self.list_mfa_reset_requests_impl(tenant_id, pending_only)
+            .await
+    }
+
     async fn registration_mode(&self, tenant_id: TenantId) -> AppResult<RegistrationMode> {
         self.registration_mode_impl(tenant_id).await
     }
@@ -186,6 +306,34 @@ impl SecurityAdminRepository for PostgresSecurityAdminRepository {
         self.set_audit_retention_policy_impl(tenant_id, retention_days)
             .await
     }
+
+    async fn workflow_concurrency_policy(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<WorkflowConcurrencyPolicy> {
+        self.workflow_concurrency_policy_impl(tenant_id).await
+    }
+
+    async fn set_workflow_concurrency_policy(
+        &self,
+        tenant_id: TenantId,
+        max_concurrent_jobs: Option<u32>,
+    ) -> AppResult<WorkflowConcurrencyPolicy> {
+        self.set_workflow_concurrency_policy_impl(tenant_id, max_concurrent_jobs)
+            .await
+    }
+}
+
+#[async_trait]
+impl RuntimeRecordOwnerDirectory for PostgresSecurityAdminRepository {
+    async fn is_assignable_owner(
+        &self,
+        tenant_id: TenantId,
+        owner_subject: &str,
+    ) -> AppResult<bool> {
+        self.is_assignable_owner_impl(tenant_id, owner_subject)
+            .await
+    }
 }
 
 fn aggregate_roles(rows: Vec<RoleRow>, tenant_id: TenantId) -> AppResult<Vec<RoleDefinition>> {
@@ -226,6 +374,14 @@ fn map_role_conflict(error: sqlx::Error, role_name: &str) -> AppError {
     AppError::Internal(format!("failed to create role: {error}"))
 }
 
+fn parse_subject_type(value: &str, tenant_id: TenantId) -> AppResult<SubjectType> {
+    SubjectType::from_str(value).map_err(|error| {
+        AppError::Internal(format!(
+            "invalid stored subject type '{value}' for tenant '{tenant_id}': {error}"
+        ))
+    })
+}
+
 fn aggregate_temporary_access_grants(
     rows: Vec<TemporaryAccessGrantRow>,
     tenant_id: TenantId,
@@ -303,9 +459,9 @@ pub async fn assign_owner_role_grants(
 
     sqlx::query(
         r#"
-        INSERT INTO rbac_subject_roles (tenant_id, subject, role_id)
-        VALUES ($1, $2, $3)
-        ON CONFLICT (tenant_id, subject, role_id) DO NOTHING
+        INSERT INTO rbac_subject_roles (tenant_id, subject_type, subject, role_id)
+        VALUES ($1, 'user', $2, $3)
+        ON CONFLICT (tenant_id, subject_type, subject, role_id) DO NOTHING
         "#,
     )
     .bind(tenant_id.as_uuid())
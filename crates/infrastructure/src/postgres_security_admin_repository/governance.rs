@@ -126,4 +126,82 @@ impl PostgresSecurityAdminRepository {
             })?,
         })
     }
+
+    pub(super) async fn workflow_concurrency_policy_impl(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<WorkflowConcurrencyPolicy> {
+        let max_concurrent_jobs = sqlx::query_scalar::<_, Option<i32>>(
+            r#"
+            SELECT workflow_max_concurrent_jobs
+            FROM tenants
+            WHERE id = $1
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to resolve tenant workflow concurrency policy: {error}"
+            ))
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("tenant '{}' not found", tenant_id)))?;
+
+        workflow_concurrency_policy_from_stored(tenant_id, max_concurrent_jobs)
+    }
+
+    pub(super) async fn set_workflow_concurrency_policy_impl(
+        &self,
+        tenant_id: TenantId,
+        max_concurrent_jobs: Option<u32>,
+    ) -> AppResult<WorkflowConcurrencyPolicy> {
+        let max_concurrent_jobs = max_concurrent_jobs
+            .map(|value| {
+                i32::try_from(value).map_err(|_| {
+                    AppError::Validation(format!(
+                        "workflow max_concurrent_jobs '{value}' is too large"
+                    ))
+                })
+            })
+            .transpose()?;
+        let stored_limit = sqlx::query_scalar::<_, Option<i32>>(
+            r#"
+            UPDATE tenants
+            SET workflow_max_concurrent_jobs = $2
+            WHERE id = $1
+            RETURNING workflow_max_concurrent_jobs
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(max_concurrent_jobs)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to update tenant workflow concurrency policy: {error}"
+            ))
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("tenant '{}' not found", tenant_id)))?;
+
+        workflow_concurrency_policy_from_stored(tenant_id, stored_limit)
+    }
+}
+
+fn workflow_concurrency_policy_from_stored(
+    tenant_id: TenantId,
+    stored_limit: Option<i32>,
+) -> AppResult<WorkflowConcurrencyPolicy> {
+    Ok(WorkflowConcurrencyPolicy {
+        max_concurrent_jobs: stored_limit
+            .map(|value| {
+                u32::try_from(value).map_err(|_| {
+                    AppError::Internal(format!(
+                        "invalid stored workflow max_concurrent_jobs '{}' for tenant '{}'",
+                        value, tenant_id
+                    ))
+                })
+            })
+            .transpose()?,
+    })
 }
@@ -0,0 +1,247 @@
+use super::*;
+
+const MFA_RESET_REQUEST_COLUMNS: &str = r#"
+    id AS request_id,
+    subject,
+    reason,
+    requested_by_subject,
+    to_char(requested_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS requested_at,
+    to_char(expires_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS expires_at,
+    approved_by_subject,
+    CASE
+        WHEN approved_at IS NULL THEN NULL
+        ELSE to_char(approved_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"')
+    END AS approved_at
+"#;
+
+impl PostgresSecurityAdminRepository {
+    pub(super) async fn create_mfa_reset_request_impl(
+        &self,
+        tenant_id: TenantId,
+        requested_by_subject: &str,
+        input: CreateMfaResetRequestInput,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> AppResult<MfaResetRequest> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let is_member = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM tenant_memberships
+                WHERE tenant_id = $1
+                  AND subject = $2
+            )
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(input.subject.as_str())
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to verify mfa reset subject: {error}"))
+        })?;
+        if !is_member {
+            return Err(AppError::NotFound(format!(
+                "subject '{}' is not a member of this tenant",
+                input.subject
+            )));
+        }
+
+        let has_pending = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM security_mfa_reset_requests
+                WHERE tenant_id = $1
+                  AND subject = $2
+                  AND approved_at IS NULL
+                  AND expires_at > now()
+            )
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(input.subject.as_str())
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to check pending mfa reset requests: {error}"
+            ))
+        })?;
+        if has_pending {
+            return Err(AppError::Conflict(format!(
+                "an mfa reset for '{}' is already pending approval",
+                input.subject
+            )));
+        }
+
+        let row = sqlx::query_as::<_, MfaResetRequestRow>(&format!(
+            r#"
+            INSERT INTO security_mfa_reset_requests (
+                tenant_id,
+                subject,
+                reason,
+                requested_by_subject,
+                expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING {MFA_RESET_REQUEST_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(input.subject.as_str())
+        .bind(input.reason.trim())
+        .bind(requested_by_subject)
+        .bind(expires_at)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to create mfa reset request: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped mfa reset request transaction: {error}"
+            ))
+        })?;
+
+        Ok(MfaResetRequest::from(row))
+    }
+
+    pub(super) async fn find_mfa_reset_request_impl(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+    ) -> AppResult<Option<MfaResetRequest>> {
+        let parsed_request_id = parse_mfa_reset_request_id(request_id)?;
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let row = sqlx::query_as::<_, MfaResetRequestRow>(&format!(
+            r#"
+            SELECT {MFA_RESET_REQUEST_COLUMNS}
+            FROM security_mfa_reset_requests
+            WHERE tenant_id = $1
+              AND id = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(parsed_request_id)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to load mfa reset request: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped mfa reset lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(MfaResetRequest::from))
+    }
+
+    pub(super) async fn approve_mfa_reset_request_impl(
+        &self,
+        tenant_id: TenantId,
+        request_id: &str,
+        approved_by_subject: &str,
+    ) -> AppResult<MfaResetRequest> {
+        let parsed_request_id = parse_mfa_reset_request_id(request_id)?;
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let row = sqlx::query_as::<_, MfaResetRequestRow>(&format!(
+            r#"
+            UPDATE security_mfa_reset_requests
+            SET approved_by_subject = $3,
+                approved_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND approved_at IS NULL
+              AND expires_at > now()
+              AND requested_by_subject <> $3
+              AND subject <> $3
+            RETURNING {MFA_RESET_REQUEST_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(parsed_request_id)
+        .bind(approved_by_subject)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to approve mfa reset request: {error}"))
+        })?
+        .ok_or_else(|| {
+            AppError::Conflict(format!(
+                "mfa reset request '{request_id}' is not pending approval"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped mfa reset approval transaction: {error}"
+            ))
+        })?;
+
+        Ok(MfaResetRequest::from(row))
+    }
+
+    pub(super) async fn list_mfa_reset_requests_impl(
+        &self,
+        tenant_id: TenantId,
+        pending_only: bool,
+    ) -> AppResult<Vec<MfaResetRequest>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let rows = sqlx::query_as::<_, MfaResetRequestRow>(&format!(
+            r#"
+            SELECT {MFA_RESET_REQUEST_COLUMNS}
+            FROM security_mfa_reset_requests
+            WHERE tenant_id = $1
+              AND (
+                  $2::BOOLEAN = false
+                  OR (approved_at IS NULL AND expires_at > now())
+              )
+            ORDER BY requested_at DESC
+            LIMIT 200
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(pending_only)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list mfa reset requests: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped mfa reset list transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(MfaResetRequest::from).collect())
+    }
+}
+
+impl From<MfaResetRequestRow> for MfaResetRequest {
+    fn from(row: MfaResetRequestRow) -> Self {
+        Self {
+            request_id: row.request_id.to_string(),
+            subject: row.subject,
+            reason: row.reason,
+            requested_by_subject: row.requested_by_subject,
+            requested_at: row.requested_at,
+            expires_at: row.expires_at,
+            approved_by_subject: row.approved_by_subject,
+            approved_at: row.approved_at,
+        }
+    }
+}
+
+fn parse_mfa_reset_request_id(request_id: &str) -> AppResult<uuid::Uuid> {
+    uuid::Uuid::parse_str(request_id)
+        .map_err(|_| AppError::Validation(format!("invalid mfa reset request id '{request_id}'")))
+}
@@ -1,3 +1,4 @@
+use super::teams::{require_tenant_member, resolve_subject_key};
 use super::*;
 
 impl PostgresSecurityAdminRepository {
@@ -85,6 +86,7 @@ impl PostgresSecurityAdminRepository {
     pub(super) async fn assign_role_to_subject_impl(
         &self,
         tenant_id: TenantId,
+        subject_type: SubjectType,
         subject: &str,
         role_name: &str,
     ) -> AppResult<()> {
@@ -105,35 +107,22 @@ impl PostgresSecurityAdminRepository {
         .map_err(|error| AppError::Internal(format!("failed to resolve role: {error}")))?
         .ok_or_else(|| AppError::NotFound(format!("role '{role_name}' was not found")))?;
 
-        let membership_exists = sqlx::query_scalar::<_, i64>(
-            r#"
-            SELECT COUNT(*)
-            FROM tenant_memberships
-            WHERE tenant_id = $1
-                AND subject = $2
-            "#,
-        )
-        .bind(tenant_id.as_uuid())
-        .bind(subject)
-        .fetch_one(&mut *transaction)
-        .await
-        .map_err(|error| AppError::Internal(format!("failed to resolve membership: {error}")))?;
-
-        if membership_exists == 0 {
-            return Err(AppError::NotFound(format!(
-                "subject '{subject}' does not belong to tenant '{tenant_id}'"
-            )));
+        if subject_type == SubjectType::User {
+            require_tenant_member(&mut transaction, tenant_id, subject).await?;
         }
+        let subject_key =
+            resolve_subject_key(&mut transaction, tenant_id, subject_type, subject).await?;
 
         sqlx::query(
             r#"
-            INSERT INTO rbac_subject_roles (tenant_id, subject, role_id)
-            VALUES ($1, $2, $3)
-            ON CONFLICT (tenant_id, subject, role_id) DO NOTHING
+            INSERT INTO rbac_subject_roles (tenant_id, subject_type, subject, role_id)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (tenant_id, subject_type, subject, role_id) DO NOTHING
             "#,
         )
         .bind(tenant_id.as_uuid())
-        .bind(subject)
+        .bind(subject_type.as_str())
+        .bind(subject_key)
         .bind(role_id)
         .execute(&mut *transaction)
         .await
@@ -149,22 +138,27 @@ impl PostgresSecurityAdminRepository {
     pub(super) async fn remove_role_from_subject_impl(
         &self,
         tenant_id: TenantId,
+        subject_type: SubjectType,
         subject: &str,
         role_name: &str,
     ) -> AppResult<()> {
         let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let subject_key =
+            resolve_subject_key(&mut transaction, tenant_id, subject_type, subject).await?;
         let rows_affected = sqlx::query(
             r#"
             DELETE FROM rbac_subject_roles AS subject_roles
             USING rbac_roles AS roles
             WHERE subject_roles.role_id = roles.id
                 AND subject_roles.tenant_id = $1
-                AND subject_roles.subject = $2
-                AND roles.name = $3
+                AND subject_roles.subject_type = $2
+                AND subject_roles.subject = $3
+                AND roles.name = $4
             "#,
         )
         .bind(tenant_id.as_uuid())
-        .bind(subject)
+        .bind(subject_type.as_str())
+        .bind(subject_key)
         .bind(role_name)
         .execute(&mut *transaction)
         .await
@@ -194,15 +188,19 @@ impl PostgresSecurityAdminRepository {
         let rows = sqlx::query_as::<_, RoleAssignmentRow>(
             r#"
             SELECT
-                subject_roles.subject,
+                subject_roles.subject_type,
+                COALESCE(teams.name, subject_roles.subject) AS subject,
                 subject_roles.role_id,
                 roles.name AS role_name,
                 to_char(subject_roles.created_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS assigned_at
             FROM rbac_subject_roles AS subject_roles
             INNER JOIN rbac_roles AS roles
                 ON roles.id = subject_roles.role_id
+            LEFT JOIN security_teams AS teams
+                ON subject_roles.subject_type = 'team'
+                AND teams.id::TEXT = subject_roles.subject
             WHERE subject_roles.tenant_id = $1
-            ORDER BY subject_roles.subject, roles.name
+            ORDER BY subject_roles.subject_type DESC, subject, roles.name
             "#,
         )
         .bind(tenant_id.as_uuid())
@@ -215,14 +213,16 @@ impl PostgresSecurityAdminRepository {
             ))
         })?;
 
-        Ok(rows
-            .into_iter()
-            .map(|row| RoleAssignment {
-                subject: row.subject,
-                role_id: row.role_id.to_string(),
-                role_name: row.role_name,
-                assigned_at: row.assigned_at,
+        rows.into_iter()
+            .map(|row| {
+                Ok(RoleAssignment {
+                    subject_type: parse_subject_type(row.subject_type.as_str(), tenant_id)?,
+                    subject: row.subject,
+                    role_id: row.role_id.to_string(),
+                    role_name: row.role_name,
+                    assigned_at: row.assigned_at,
+                })
             })
-            .collect())
+            .collect()
     }
 }
@@ -1,3 +1,4 @@
+use super::teams::resolve_subject_key;
 use super::*;
 
 impl PostgresSecurityAdminRepository {
@@ -7,17 +8,26 @@ impl PostgresSecurityAdminRepository {
         input: SaveRuntimeFieldPermissionsInput,
     ) -> AppResult<Vec<RuntimeFieldPermissionEntry>> {
         let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let subject_key = resolve_subject_key(
+            &mut transaction,
+            tenant_id,
+            input.subject_type,
+            input.subject.as_str(),
+        )
+        .await?;
 
         sqlx::query(
             r#"
             DELETE FROM runtime_subject_field_permissions
             WHERE tenant_id = $1
-              AND subject = $2
-              AND entity_logical_name = $3
+              AND subject_type = $2
+              AND subject = $3
+              AND entity_logical_name = $4
             "#,
         )
         .bind(tenant_id.as_uuid())
-        .bind(input.subject.as_str())
+        .bind(input.subject_type.as_str())
+        .bind(subject_key.as_str())
         .bind(input.entity_logical_name.as_str())
         .execute(&mut *transaction)
         .await
@@ -33,14 +43,15 @@ impl PostgresSecurityAdminRepository {
                 r#"
                 INSERT INTO runtime_subject_field_permissions (
                     tenant_id,
+                    subject_type,
                     subject,
                     entity_logical_name,
                     field_logical_name,
                     can_read,
                     can_write
                 )
-                VALUES ($1, $2, $3, $4, $5, $6)
-                ON CONFLICT (tenant_id, subject, entity_logical_name, field_logical_name)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (tenant_id, subject_type, subject, entity_logical_name, field_logical_name)
                 DO UPDATE
                 SET can_read = EXCLUDED.can_read,
                     can_write = EXCLUDED.can_write,
@@ -48,7 +59,8 @@ impl PostgresSecurityAdminRepository {
                 "#,
             )
             .bind(tenant_id.as_uuid())
-            .bind(input.subject.as_str())
+            .bind(input.subject_type.as_str())
+            .bind(subject_key.as_str())
             .bind(input.entity_logical_name.as_str())
             .bind(field.field_logical_name.as_str())
             .bind(field.can_read)
@@ -66,7 +78,8 @@ impl PostgresSecurityAdminRepository {
         let rows = sqlx::query_as::<_, RuntimeFieldPermissionRow>(
             r#"
             SELECT
-                subject,
+                subject_type,
+                $5::TEXT AS subject,
                 entity_logical_name,
                 field_logical_name,
                 can_read,
@@ -74,14 +87,17 @@ impl PostgresSecurityAdminRepository {
                 to_char(updated_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS updated_at
             FROM runtime_subject_field_permissions
             WHERE tenant_id = $1
-              AND subject = $2
-              AND entity_logical_name = $3
+              AND subject_type = $2
+              AND subject = $3
+              AND entity_logical_name = $4
             ORDER BY field_logical_name
             "#,
         )
         .bind(tenant_id.as_uuid())
-        .bind(input.subject.as_str())
+        .bind(input.subject_type.as_str())
+        .bind(subject_key.as_str())
         .bind(input.entity_logical_name.as_str())
+        .bind(input.subject.as_str())
         .fetch_all(&mut *transaction)
         .await
         .map_err(|error| {
@@ -95,17 +111,9 @@ impl PostgresSecurityAdminRepository {
             AppError::Internal(format!("failed to commit transaction: {error}"))
         })?;
 
-        Ok(rows
-            .into_iter()
-            .map(|row| RuntimeFieldPermissionEntry {
-                subject: row.subject,
-                entity_logical_name: row.entity_logical_name,
-                field_logical_name: row.field_logical_name,
-                can_read: row.can_read,
-                can_write: row.can_write,
-                updated_at: row.updated_at,
-            })
-            .collect())
+        rows.into_iter()
+            .map(|row| runtime_field_permission_from_row(row, tenant_id))
+            .collect()
     }
 
     pub(super) async fn list_runtime_field_permissions_impl(
@@ -118,17 +126,25 @@ impl PostgresSecurityAdminRepository {
         let rows = sqlx::query_as::<_, RuntimeFieldPermissionRow>(
             r#"
             SELECT
+                permissions.subject_type,
+                COALESCE(teams.name, permissions.subject) AS subject,
+                permissions.entity_logical_name,
+                permissions.field_logical_name,
+                permissions.can_read,
+                permissions.can_write,
+                to_char(permissions.updated_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS updated_at
+            FROM runtime_subject_field_permissions AS permissions
+            LEFT JOIN security_teams AS teams
+                ON permissions.subject_type = 'team'
+                AND teams.id::TEXT = permissions.subject
+            WHERE permissions.tenant_id = $1
+              AND ($2::TEXT IS NULL OR COALESCE(teams.name, permissions.subject) = $2)
+              AND ($3::TEXT IS NULL OR permissions.entity_logical_name = $3)
+            ORDER BY
+                permissions.subject_type DESC,
                 subject,
-                entity_logical_name,
-                field_logical_name,
-                can_read,
-                can_write,
-                to_char(updated_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS updated_at
-            FROM runtime_subject_field_permissions
-            WHERE tenant_id = $1
-              AND ($2::TEXT IS NULL OR subject = $2)
-              AND ($3::TEXT IS NULL OR entity_logical_name = $3)
-            ORDER BY subject, entity_logical_name, field_logical_name
+                permissions.entity_logical_name,
+                permissions.field_logical_name
             "#,
         )
         .bind(tenant_id.as_uuid())
@@ -145,16 +161,23 @@ impl PostgresSecurityAdminRepository {
             ))
         })?;
 
-        Ok(rows
-            .into_iter()
-            .map(|row| RuntimeFieldPermissionEntry {
-                subject: row.subject,
-                entity_logical_name: row.entity_logical_name,
-                field_logical_name: row.field_logical_name,
-                can_read: row.can_read,
-                can_write: row.can_write,
-                updated_at: row.updated_at,
-            })
-            .collect())
+        rows.into_iter()
+            .map(|row| runtime_field_permission_from_row(row, tenant_id))
+            .collect()
     }
 }
+
+fn runtime_field_permission_from_row(
+    row: RuntimeFieldPermissionRow,
+    tenant_id: TenantId,
+) -> AppResult<RuntimeFieldPermissionEntry> {
+    Ok(RuntimeFieldPermissionEntry {
+        subject_type: parse_subject_type(row.subject_type.as_str(), tenant_id)?,
+        subject: row.subject,
+        entity_logical_name: row.entity_logical_name,
+        field_logical_name: row.field_logical_name,
+        can_read: row.can_read,
+        can_write: row.can_write,
+        updated_at: row.updated_at,
+    })
+}
@@ -0,0 +1,340 @@
+use super::*;
+
+impl PostgresSecurityAdminRepository {
+    pub(super) async fn create_team_impl(
+        &self,
+        tenant_id: TenantId,
+        created_by_subject: &str,
+        team: TeamDefinition,
+    ) -> AppResult<SecurityTeam> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, SecurityTeamRow>(
+            r#"
+            INSERT INTO security_teams (tenant_id, name, description, created_by_subject)
+            VALUES ($1, $2, $3, $4)
+            RETURNING
+                id AS team_id,
+                name,
+                description,
+                0::BIGINT AS member_count,
+                created_by_subject,
+                to_char(created_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS created_at
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(team.name().as_str())
+        .bind(team.description())
+        .bind(created_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| map_team_conflict(error, team.name().as_str()))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped team create transaction: {error}"
+            ))
+        })?;
+
+        Ok(team_from_row(row))
+    }
+
+    pub(super) async fn list_teams_impl(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<SecurityTeam>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, SecurityTeamRow>(
+            r#"
+            SELECT
+                teams.id AS team_id,
+                teams.name,
+                teams.description,
+                COUNT(members.subject)::BIGINT AS member_count,
+                teams.created_by_subject,
+                to_char(teams.created_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS created_at
+            FROM security_teams AS teams
+            LEFT JOIN security_team_members AS members
+                ON members.team_id = teams.id
+                AND members.tenant_id = teams.tenant_id
+            WHERE teams.tenant_id = $1
+            GROUP BY teams.id
+            ORDER BY teams.name
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list teams: {error}")))?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped team list transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(team_from_row).collect())
+    }
+
+    pub(super) async fn add_team_member_impl(
+        &self,
+        tenant_id: TenantId,
+        added_by_subject: &str,
+        team_name: &str,
+        subject: &str,
+    ) -> AppResult<SecurityTeamMember> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let team_id = resolve_team_id(&mut transaction, tenant_id, team_name).await?;
+        require_tenant_member(&mut transaction, tenant_id, subject).await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO security_team_members (tenant_id, team_id, subject, added_by_subject)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (tenant_id, team_id, subject) DO NOTHING
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(team_id)
+        .bind(subject)
+        .bind(added_by_subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to add team member: {error}")))?;
+
+        let row = sqlx::query_as::<_, SecurityTeamMemberRow>(
+            r#"
+            SELECT
+                $3::TEXT AS team_name,
+                subject,
+                added_by_subject,
+                to_char(added_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS added_at
+            FROM security_team_members
+            WHERE tenant_id = $1
+                AND team_id = $2
+                AND subject = $4
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(team_id)
+        .bind(team_name)
+        .bind(subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to load team member: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped team member transaction: {error}"
+            ))
+        })?;
+
+        Ok(team_member_from_row(row))
+    }
+
+    pub(super) async fn remove_team_member_impl(
+        &self,
+        tenant_id: TenantId,
+        team_name: &str,
+        subject: &str,
+    ) -> AppResult<()> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows_affected = sqlx::query(
+            r#"
+            DELETE FROM security_team_members AS members
+            USING security_teams AS teams
+            WHERE members.team_id = teams.id
+                AND members.tenant_id = $1
+                AND teams.name = $2
+                AND members.subject = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(team_name)
+        .bind(subject)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to remove team member: {error}")))?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(AppError::NotFound(format!(
+                "subject '{subject}' is not a member of team '{team_name}'"
+            )));
+        }
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped team member removal transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    pub(super) async fn list_team_members_impl(
+        &self,
+        tenant_id: TenantId,
+        team_name: &str,
+    ) -> AppResult<Vec<SecurityTeamMember>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let team_id = resolve_team_id(&mut transaction, tenant_id, team_name).await?;
+        let rows = sqlx::query_as::<_, SecurityTeamMemberRow>(
+            r#"
+            SELECT
+                $3::TEXT AS team_name,
+                subject,
+                added_by_subject,
+                to_char(added_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS added_at
+            FROM security_team_members
+            WHERE tenant_id = $1
+                AND team_id = $2
+            ORDER BY subject
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(team_id)
+        .bind(team_name)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list team members: {error}")))?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped team member list transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows.into_iter().map(team_member_from_row).collect())
+    }
+
+    pub(super) async fn is_assignable_owner_impl(
+        &self,
+        tenant_id: TenantId,
+        owner_subject: &str,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let assignable = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM tenant_memberships
+                WHERE tenant_id = $1
+                    AND subject = $2
+            ) OR EXISTS (
+                SELECT 1
+                FROM security_teams
+                WHERE tenant_id = $1
+                    AND name = $2
+            )
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(owner_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to resolve record owner subject: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped record owner lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(assignable)
+    }
+}
+
+/// Resolves the stored `subject` value for a user subject or a team name.
+///
+/// Team grants store the team id rather than its display name.
+pub(super) async fn resolve_subject_key(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    subject_type: SubjectType,
+    subject: &str,
+) -> AppResult<String> {
+    match subject_type {
+        SubjectType::User => Ok(subject.to_owned()),
+        SubjectType::Team => resolve_team_id(transaction, tenant_id, subject)
+            .await
+            .map(|team_id| team_id.to_string()),
+    }
+}
+
+pub(super) async fn require_tenant_member(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    subject: &str,
+) -> AppResult<()> {
+    let membership_exists = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*)
+        FROM tenant_memberships
+        WHERE tenant_id = $1
+            AND subject = $2
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(subject)
+    .fetch_one(&mut **transaction)
+    .await
+    .map_err(|error| AppError::Internal(format!("failed to resolve membership: {error}")))?;
+
+    if membership_exists == 0 {
+        return Err(AppError::NotFound(format!(
+            "subject '{subject}' does not belong to tenant '{tenant_id}'"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn resolve_team_id(
+    transaction: &mut Transaction<'_, Postgres>,
+    tenant_id: TenantId,
+    team_name: &str,
+) -> AppResult<uuid::Uuid> {
+    sqlx::query_scalar::<_, uuid::Uuid>(
+        r#"
+        SELECT id
+        FROM security_teams
+        WHERE tenant_id = $1 AND name = $2
+        LIMIT 1
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(team_name)
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(|error| AppError::Internal(format!("failed to resolve team: {error}")))?
+    .ok_or_else(|| AppError::NotFound(format!("team '{team_name}' was not found")))
+}
+
+fn team_from_row(row: SecurityTeamRow) -> SecurityTeam {
+    SecurityTeam {
+        team_id: row.team_id.to_string(),
+        name: row.name,
+        description: row.description,
+        member_count: u32::try_from(row.member_count).unwrap_or(u32::MAX),
+        created_by_subject: row.created_by_subject,
+        created_at: row.created_at,
+    }
+}
+
+fn team_member_from_row(row: SecurityTeamMemberRow) -> SecurityTeamMember {
+    SecurityTeamMember {
+        team_name: row.team_name,
+        subject: row.subject,
+        added_by_subject: row.added_by_subject,
+        added_at: row.added_at,
+    }
+}
+
+fn map_team_conflict(error: sqlx::Error, team_name: &str) -> AppError {
+    if let sqlx::Error::Database(database_error) = &error
+        && database_error.code().as_deref() == Some("23505")
+    {
+        return AppError::Conflict(format!("team '{team_name}' already exists"));
+    }
+
+    AppError::Internal(format!("failed to create team: {error}"))
+}
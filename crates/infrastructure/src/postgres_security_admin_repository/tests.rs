@@ -1,14 +1,16 @@
 use qryvanta_application::{
-    CreateTemporaryAccessGrantInput, SaveRuntimeFieldPermissionsInput, SecurityAdminRepository,
-    TemporaryAccessGrantQuery,
+    AuthorizationRepository, CreateMfaResetRequestInput, CreateRoleInput,
+    CreateTemporaryAccessGrantInput, RuntimeRecordOwnerDirectory, SaveRuntimeFieldPermissionsInput,
+    SecurityAdminRepository, TemporaryAccessGrantQuery,
 };
 use qryvanta_core::{AppError, TenantId};
-use qryvanta_domain::Permission;
+use qryvanta_domain::{Permission, SubjectType, TeamDefinition};
 use sqlx::PgPool;
 use sqlx::migrate::Migrator;
 use sqlx::postgres::PgPoolOptions;
 
 use super::PostgresSecurityAdminRepository;
+use crate::{PostgresAuthorizationRepository, begin_tenant_transaction};
 
 static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
 
@@ -63,6 +65,7 @@ async fn save_runtime_field_permissions_replaces_existing_entries() {
         .save_runtime_field_permissions(
             tenant_id,
             SaveRuntimeFieldPermissionsInput {
+                subject_type: SubjectType::User,
                 subject: "alice".to_owned(),
                 entity_logical_name: "contact".to_owned(),
                 fields: vec![
@@ -87,6 +90,7 @@ async fn save_runtime_field_permissions_replaces_existing_entries() {
         .save_runtime_field_permissions(
             tenant_id,
             SaveRuntimeFieldPermissionsInput {
+                subject_type: SubjectType::User,
                 subject: "alice".to_owned(),
                 entity_logical_name: "contact".to_owned(),
                 fields: vec![qryvanta_application::RuntimeFieldPermissionInput {
@@ -236,6 +240,41 @@ async fn audit_retention_policy_round_trip_succeeds() {
     );
 }
 
+#[tokio::test]
+async fn workflow_concurrency_policy_round_trip_succeeds() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresSecurityAdminRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Workflow Concurrency Tenant").await;
+
+    let current_policy = repository
+        .workflow_concurrency_policy(tenant_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(current_policy.max_concurrent_jobs, None);
+
+    let capped_policy = repository
+        .set_workflow_concurrency_policy(tenant_id, Some(12))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(capped_policy.max_concurrent_jobs, Some(12));
+
+    let reloaded_policy = repository
+        .workflow_concurrency_policy(tenant_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(reloaded_policy, capped_policy);
+
+    let cleared_policy = repository
+        .set_workflow_concurrency_policy(tenant_id, None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(cleared_policy.max_concurrent_jobs, None);
+}
+
 #[tokio::test]
 async fn security_admin_runtime_permissions_and_temporary_grants_are_tenant_scoped() {
     let Some(pool) = test_pool().await else {
@@ -252,6 +291,7 @@ async fn security_admin_runtime_permissions_and_temporary_grants_are_tenant_scop
         .save_runtime_field_permissions(
             left_tenant,
             SaveRuntimeFieldPermissionsInput {
+                subject_type: SubjectType::User,
                 subject: "alice".to_owned(),
                 entity_logical_name: "contact".to_owned(),
                 fields: vec![qryvanta_application::RuntimeFieldPermissionInput {
@@ -268,6 +308,7 @@ async fn security_admin_runtime_permissions_and_temporary_grants_are_tenant_scop
         .save_runtime_field_permissions(
             right_tenant,
             SaveRuntimeFieldPermissionsInput {
+                subject_type: SubjectType::User,
                 subject: "alice".to_owned(),
                 entity_logical_name: "contact".to_owned(),
                 fields: vec![qryvanta_application::RuntimeFieldPermissionInput {
@@ -364,3 +405,273 @@ async fn security_admin_runtime_permissions_and_temporary_grants_are_tenant_scop
         365
     );
 }
+
+#[tokio::test]
+async fn record_owners_must_be_tenant_members_or_teams() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresSecurityAdminRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Owner Tenant").await;
+    ensure_tenant(&pool, other_tenant_id, "Other Owner Tenant").await;
+
+    let mut transaction = begin_tenant_transaction(&pool, tenant_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let membership = sqlx::query(
+        r#"
+        INSERT INTO tenant_memberships (tenant_id, subject, display_name)
+        VALUES ($1, 'bob', 'Bob')
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .execute(&mut *transaction)
+    .await;
+    assert!(membership.is_ok());
+    assert!(transaction.commit().await.is_ok());
+    let team = TeamDefinition::new("Support", None).unwrap_or_else(|_| unreachable!());
+    assert!(
+        repository
+            .create_team(tenant_id, "admin", team)
+            .await
+            .is_ok()
+    );
+
+    for (owner_subject, expected) in [("bob", true), ("Support", true), ("bbo", false)] {
+        assert_eq!(
+            repository
+                .is_assignable_owner(tenant_id, owner_subject)
+                .await
+                .ok(),
+            Some(expected),
+            "owner '{owner_subject}'"
+        );
+    }
+    assert_eq!(
+        repository
+            .is_assignable_owner(other_tenant_id, "bob")
+            .await
+            .ok(),
+        Some(false)
+    );
+}
+
+#[tokio::test]
+async fn team_role_assignments_and_field_grants_apply_to_members() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresSecurityAdminRepository::new(pool.clone());
+    let authorization_repository = PostgresAuthorizationRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Team Tenant").await;
+
+    let mut transaction = begin_tenant_transaction(&pool, tenant_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let membership = sqlx::query(
+        r#"
+        INSERT INTO tenant_memberships (tenant_id, subject, display_name)
+        VALUES ($1, 'bob', 'Bob')
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .execute(&mut *transaction)
+    .await;
+    assert!(membership.is_ok());
+    assert!(transaction.commit().await.is_ok());
+
+    let team = TeamDefinition::new("Field Sales", None).unwrap_or_else(|_| unreachable!());
+    assert!(
+        repository
+            .create_team(tenant_id, "admin", team.clone())
+            .await
+            .is_ok()
+    );
+    assert!(matches!(
+        repository.create_team(tenant_id, "admin", team).await,
+        Err(AppError::Conflict(_))
+    ));
+    assert!(
+        repository
+            .add_team_member(tenant_id, "admin", "Field Sales", "bob")
+            .await
+            .is_ok()
+    );
+
+    assert!(
+        repository
+            .create_role(
+                tenant_id,
+                CreateRoleInput {
+                    name: "sales_reader".to_owned(),
+                    permissions: vec![Permission::RuntimeRecordRead],
+                },
+            )
+            .await
+            .is_ok()
+    );
+    assert!(
+        repository
+            .assign_role_to_subject(tenant_id, SubjectType::Team, "Field Sales", "sales_reader")
+            .await
+            .is_ok()
+    );
+
+    let saved = repository
+        .save_runtime_field_permissions(
+            tenant_id,
+            SaveRuntimeFieldPermissionsInput {
+                subject_type: SubjectType::Team,
+                subject: "Field Sales".to_owned(),
+                entity_logical_name: "contact".to_owned(),
+                fields: vec![qryvanta_application::RuntimeFieldPermissionInput {
+                    field_logical_name: "email".to_owned(),
+                    can_read: true,
+                    can_write: false,
+                }],
+            },
+        )
+        .await
+        .unwrap_or_default();
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0].subject, "Field Sales");
+
+    let assignments = repository
+        .list_role_assignments(tenant_id)
+        .await
+        .unwrap_or_default();
+    assert!(assignments.iter().any(|assignment| {
+        assignment.subject_type == SubjectType::Team && assignment.subject == "Field Sales"
+    }));
+
+    let permissions = authorization_repository
+        .list_permissions_for_subject(tenant_id, "bob")
+        .await
+        .unwrap_or_default();
+    assert_eq!(permissions, vec![Permission::RuntimeRecordRead]);
+
+    let field_grants = authorization_repository
+        .list_runtime_field_grants_for_subject(tenant_id, "bob", "contact")
+        .await
+        .unwrap_or_default();
+    assert_eq!(field_grants.len(), 1);
+    assert!(field_grants[0].can_read);
+
+    let role_grants = authorization_repository
+        .list_role_grants_for_subject(tenant_id, "bob")
+        .await
+        .unwrap_or_default();
+    assert_eq!(role_grants.len(), 1);
+    assert_eq!(role_grants[0].role_name, "sales_reader");
+    assert_eq!(role_grants[0].team_name.as_deref(), Some("Field Sales"));
+    assert_eq!(
+        role_grants[0].permissions,
+        vec![Permission::RuntimeRecordRead]
+    );
+
+    assert!(
+        repository
+            .remove_team_member(tenant_id, "Field Sales", "bob")
+            .await
+            .is_ok()
+    );
+    let permissions = authorization_repository
+        .list_permissions_for_subject(tenant_id, "bob")
+        .await
+        .unwrap_or_default();
+    assert!(permissions.is_empty());
+}
+
+#[tokio::test]
+async fn mfa_reset_requests_enforce_membership_and_four_eyes_approval() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresSecurityAdminRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Mfa Reset Tenant").await;
+    ensure_tenant(&pool, other_tenant_id, "Other Mfa Reset Tenant").await;
+
+    let mut transaction = begin_tenant_transaction(&pool, tenant_id)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let membership = sqlx::query(
+        r#"
+        INSERT INTO tenant_memberships (tenant_id, subject, display_name)
+        VALUES ($1, 'carol', 'Carol')
+        "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .execute(&mut *transaction)
+    .await;
+    assert!(membership.is_ok());
+    assert!(transaction.commit().await.is_ok());
+
+    let expires_at = chrono::Utc::now() + chrono::Duration::hours(24);
+    let input = |subject: &str| CreateMfaResetRequestInput {
+        subject: subject.to_owned(),
+        reason: "lost authenticator device".to_owned(),
+    };
+
+    let non_member = repository
+        .create_mfa_reset_request(tenant_id, "alice", input("mallory"), expires_at)
+        .await;
+    assert!(matches!(non_member, Err(AppError::NotFound(_))));
+
+    let foreign_tenant = repository
+        .create_mfa_reset_request(other_tenant_id, "alice", input("carol"), expires_at)
+        .await;
+    assert!(matches!(foreign_tenant, Err(AppError::NotFound(_))));
+
+    let request = repository
+        .create_mfa_reset_request(tenant_id, "alice", input("carol"), expires_at)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(request.subject, "carol");
+    assert!(request.approved_at.is_none());
+
+    let duplicate = repository
+        .create_mfa_reset_request(tenant_id, "bob", input("carol"), expires_at)
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+
+    let self_approval = repository
+        .approve_mfa_reset_request(tenant_id, request.request_id.as_str(), "alice")
+        .await;
+    assert!(matches!(self_approval, Err(AppError::Conflict(_))));
+
+    let subject_approval = repository
+        .approve_mfa_reset_request(tenant_id, request.request_id.as_str(), "carol")
+        .await;
+    assert!(matches!(subject_approval, Err(AppError::Conflict(_))));
+
+    let foreign_lookup = repository
+        .find_mfa_reset_request(other_tenant_id, request.request_id.as_str())
+        .await;
+    assert!(matches!(foreign_lookup, Ok(None)));
+
+    let approved = repository
+        .approve_mfa_reset_request(tenant_id, request.request_id.as_str(), "bob")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(approved.approved_by_subject.as_deref(), Some("bob"));
+    assert!(approved.approved_at.is_some());
+
+    let pending = repository
+        .list_mfa_reset_requests(tenant_id, true)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(pending.is_empty());
+    let all = repository
+        .list_mfa_reset_requests(tenant_id, false)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(all.len(), 1);
+}
@@ -0,0 +1,428 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    AuditLogEntry, NewTenantDataExportLink, TenantDataExportLink, TenantDataExportPart,
+    TenantDataExportRepository, TenantDataExportSection, TenantMemberEntry,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for tenant data export archives.
+#[derive(Clone)]
+pub struct PostgresTenantDataExportRepository {
+    pool: PgPool,
+}
+
+impl PostgresTenantDataExportRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct TenantDataExportPartRow {
+    section: String,
+    entity_logical_name: String,
+    item_offset: i64,
+    items: Value,
+}
+
+impl TryFrom<TenantDataExportPartRow> for TenantDataExportPart {
+    type Error = AppError;
+
+    fn try_from(row: TenantDataExportPartRow) -> Result<Self, Self::Error> {
+        let items = match row.items {
+            Value::Array(items) => items,
+            _ => {
+                return Err(AppError::Internal(
+                    "tenant data export part items must be a JSON array".to_owned(),
+                ));
+            }
+        };
+
+        Ok(Self {
+            section: TenantDataExportSection::parse(row.section.as_str())?,
+            entity_logical_name: (!row.entity_logical_name.is_empty())
+                .then_some(row.entity_logical_name),
+            item_offset: u64::try_from(row.item_offset).unwrap_or(0),
+            items,
+        })
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct TenantMemberRow {
+    subject: String,
+    display_name: String,
+    email: Option<String>,
+    user_id: Option<uuid::Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct AuditEntryRow {
+    event_id: uuid::Uuid,
+    subject: String,
+    action: String,
+    resource_type: String,
+    resource_id: String,
+    detail: Option<String>,
+    created_at: String,
+    chain_position: i64,
+    previous_entry_hash: Option<String>,
+    entry_hash: String,
+}
+
+#[derive(Debug, FromRow)]
+struct TenantDataExportLinkRow {
+    id: uuid::Uuid,
+    job_id: uuid::Uuid,
+    created_by_subject: String,
+    generated_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<TenantDataExportLinkRow> for TenantDataExportLink {
+    fn from(row: TenantDataExportLinkRow) -> Self {
+        Self {
+            link_id: row.id.to_string(),
+            job_id: row.job_id.to_string(),
+            created_by_subject: row.created_by_subject,
+            generated_at: row.generated_at,
+            expires_at: row.expires_at,
+            created_at: row.created_at,
+        }
+    }
+}
+
+fn parse_job_id(job_id: &str) -> AppResult<uuid::Uuid> {
+    uuid::Uuid::parse_str(job_id)
+        .map_err(|_| AppError::NotFound(format!("tenant data export '{job_id}' does not exist")))
+}
+
+fn page_bounds(limit: usize, offset: usize) -> AppResult<(i64, i64)> {
+    let limit = i64::try_from(limit).map_err(|_| {
+        AppError::Validation("tenant data export page size is out of range".to_owned())
+    })?;
+    let offset = i64::try_from(offset).map_err(|_| {
+        AppError::Validation("tenant data export offset is out of range".to_owned())
+    })?;
+
+    Ok((limit, offset))
+}
+
+#[async_trait]
+impl TenantDataExportRepository for PostgresTenantDataExportRepository {
+    async fn save_export_part(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        part: &TenantDataExportPart,
+    ) -> AppResult<()> {
+        let job_uuid = parse_job_id(job_id)?;
+        let item_offset = i64::try_from(part.item_offset).map_err(|_| {
+            AppError::Validation("tenant data export offset is out of range".to_owned())
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            INSERT INTO tenant_data_export_parts (
+                tenant_id,
+                job_id,
+                section,
+                entity_logical_name,
+                item_offset,
+                items
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (tenant_id, job_id, section, entity_logical_name, item_offset)
+            DO UPDATE SET items = EXCLUDED.items, created_at = now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .bind(part.section.as_str())
+        .bind(part.entity_logical_name.as_deref().unwrap_or_default())
+        .bind(item_offset)
+        .bind(Value::Array(part.items.clone()))
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to save tenant data export part: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant data export transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    async fn list_export_parts(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+    ) -> AppResult<Vec<TenantDataExportPart>> {
+        let job_uuid = parse_job_id(job_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, TenantDataExportPartRow>(
+            r#"
+            SELECT section, entity_logical_name, item_offset, items
+            FROM tenant_data_export_parts
+            WHERE tenant_id = $1 AND job_id = $2
+            ORDER BY section, entity_logical_name, item_offset
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list tenant data export parts: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant data export transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(TenantDataExportPart::try_from)
+            .collect()
+    }
+
+    async fn delete_other_exports(&self, tenant_id: TenantId, keep_job_id: &str) -> AppResult<u64> {
+        let keep_job_uuid = parse_job_id(keep_job_id)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            DELETE FROM tenant_data_export_links
+            WHERE tenant_id = $1 AND job_id <> $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(keep_job_uuid)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete tenant data export links: {error}"
+            ))
+        })?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM tenant_data_export_parts
+            WHERE tenant_id = $1 AND job_id <> $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(keep_job_uuid)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to delete tenant data exports: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant data export transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn list_tenant_members(
+        &self,
+        tenant_id: TenantId,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<TenantMemberEntry>> {
+        let (limit, offset) = page_bounds(limit, offset)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, TenantMemberRow>(
+            r#"
+            SELECT subject, display_name, email, user_id, created_at
+            FROM tenant_memberships
+            WHERE tenant_id = $1
+            ORDER BY created_at, id
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to list tenant members: {error}")))?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant data export transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TenantMemberEntry {
+                subject: row.subject,
+                display_name: row.display_name,
+                email: row.email,
+                user_id: row.user_id.map(|user_id| user_id.to_string()),
+                created_at: row.created_at,
+            })
+            .collect())
+    }
+
+    async fn list_audit_entries(
+        &self,
+        tenant_id: TenantId,
+        limit: usize,
+        offset: usize,
+    ) -> AppResult<Vec<AuditLogEntry>> {
+        let (limit, offset) = page_bounds(limit, offset)?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, AuditEntryRow>(
+            r#"
+            SELECT
+                id AS event_id,
+                subject,
+                action,
+                resource_type,
+                resource_id,
+                detail,
+                to_char(created_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS.US"Z"') AS created_at,
+                chain_position,
+                previous_entry_hash,
+                entry_hash
+            FROM audit_log_entries
+            WHERE tenant_id = $1
+            ORDER BY chain_position
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list audit entries for export: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant data export transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| AuditLogEntry {
+                event_id: row.event_id.to_string(),
+                subject: row.subject,
+                action: row.action,
+                resource_type: row.resource_type,
+                resource_id: row.resource_id,
+                detail: row.detail,
+                created_at: row.created_at,
+                chain_position: row.chain_position,
+                previous_entry_hash: row.previous_entry_hash,
+                entry_hash: row.entry_hash,
+            })
+            .collect())
+    }
+
+    async fn create_download_link(
+        &self,
+        tenant_id: TenantId,
+        link: NewTenantDataExportLink,
+    ) -> AppResult<TenantDataExportLink> {
+        let job_uuid = parse_job_id(link.job_id.as_str())?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, TenantDataExportLinkRow>(
+            r#"
+            INSERT INTO tenant_data_export_links (
+                id,
+                tenant_id,
+                job_id,
+                token_hash,
+                created_by_subject,
+                generated_at,
+                expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, job_id, created_by_subject, generated_at, expires_at, created_at
+            "#,
+        )
+        .bind(uuid::Uuid::new_v4())
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .bind(link.token_hash)
+        .bind(link.created_by_subject)
+        .bind(link.generated_at)
+        .bind(link.expires_at)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to create tenant data export link: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant data export transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.into())
+    }
+
+    async fn find_download_link(
+        &self,
+        tenant_id: TenantId,
+        token_hash: &str,
+    ) -> AppResult<Option<TenantDataExportLink>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, TenantDataExportLinkRow>(
+            r#"
+            SELECT id, job_id, created_by_subject, generated_at, expires_at, created_at
+            FROM tenant_data_export_links
+            WHERE tenant_id = $1 AND token_hash = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(token_hash)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to find tenant data export link: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant data export transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(TenantDataExportLink::from))
+    }
+}
+
+#[cfg(test)]
+mod tests;
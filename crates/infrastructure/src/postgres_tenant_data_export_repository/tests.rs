@@ -0,0 +1,228 @@
+use chrono::Utc;
+use qryvanta_application::{
+    AuditEvent, AuditRepository, BackgroundJobKind, BackgroundJobRepository,
+    CreateBackgroundJobInput, NewTenantDataExportLink, TenantDataExportPart,
+    TenantDataExportRepository, TenantDataExportSection,
+};
+use qryvanta_core::TenantId;
+use qryvanta_domain::AuditAction;
+use serde_json::json;
+use sqlx::PgPool;
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPoolOptions;
+
+use super::PostgresTenantDataExportRepository;
+use crate::{PostgresAuditRepository, PostgresBackgroundJobRepository, begin_tenant_transaction};
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+async fn test_pool() -> Option<PgPool> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        return None;
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(2)
+        .connect(database_url.as_str())
+        .await
+    {
+        Ok(pool) => pool,
+        Err(error) => panic!("failed to connect to DATABASE_URL in test: {error}"),
+    };
+
+    if let Err(error) = MIGRATOR.run(&pool).await {
+        panic!("failed to run migrations for postgres tenant data export tests: {error}");
+    }
+
+    Some(pool)
+}
+
+async fn ensure_tenant(pool: &PgPool, tenant_id: TenantId, name: &str) {
+    let insert = sqlx::query(
+        r#"
+            INSERT INTO tenants (id, name)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+    )
+    .bind(tenant_id.as_uuid())
+    .bind(name)
+    .execute(pool)
+    .await;
+
+    assert!(insert.is_ok());
+}
+
+async fn create_export_job(pool: &PgPool, tenant_id: TenantId) -> String {
+    PostgresBackgroundJobRepository::new(pool.clone())
+        .create_background_job(
+            tenant_id,
+            "admin",
+            "Admin",
+            None,
+            CreateBackgroundJobInput {
+                kind: BackgroundJobKind::TenantDataExport,
+                parameters: json!({ "entity_logical_names": [] }),
+                stages: Vec::new(),
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to create background job: {error}"))
+        .job_id
+}
+
+fn records_part(offset: u64, record_id: &str) -> TenantDataExportPart {
+    TenantDataExportPart {
+        section: TenantDataExportSection::RuntimeRecords,
+        entity_logical_name: Some("contact".to_owned()),
+        item_offset: offset,
+        items: vec![json!({ "record_id": record_id })],
+    }
+}
+
+#[tokio::test]
+async fn parts_and_links_are_replaced_by_newer_exports() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresTenantDataExportRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Tenant Data Export Tenant").await;
+    ensure_tenant(&pool, other_tenant_id, "Tenant Data Export Other Tenant").await;
+    let job_id = create_export_job(&pool, tenant_id).await;
+
+    for part in [
+        &records_part(1, "stale"),
+        &records_part(0, "record-1"),
+        &records_part(1, "record-2"),
+    ] {
+        let saved = repository
+            .save_export_part(tenant_id, job_id.as_str(), part)
+            .await;
+        assert!(saved.is_ok());
+    }
+    assert_eq!(
+        repository
+            .list_export_parts(tenant_id, job_id.as_str())
+            .await
+            .unwrap_or_else(|error| panic!("failed to list export parts: {error}")),
+        vec![records_part(0, "record-1"), records_part(1, "record-2")]
+    );
+
+    let generated_at = Utc::now();
+    let link = repository
+        .create_download_link(
+            tenant_id,
+            NewTenantDataExportLink {
+                job_id: job_id.clone(),
+                token_hash: "token-hash".to_owned(),
+                created_by_subject: "admin".to_owned(),
+                generated_at,
+                expires_at: generated_at + chrono::Duration::hours(1),
+            },
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to create link: {error}"));
+    assert_eq!(link.job_id, job_id);
+    assert_eq!(
+        repository
+            .find_download_link(tenant_id, "token-hash")
+            .await
+            .unwrap_or_else(|error| panic!("failed to find link: {error}")),
+        Some(link)
+    );
+    assert!(
+        repository
+            .find_download_link(other_tenant_id, "token-hash")
+            .await
+            .unwrap_or_else(|error| panic!("failed to find link: {error}"))
+            .is_none()
+    );
+
+    let kept = repository
+        .delete_other_exports(tenant_id, job_id.as_str())
+        .await
+        .unwrap_or_else(|error| panic!("failed to delete exports: {error}"));
+    assert_eq!(kept, 0);
+
+    let newer_job_id = create_export_job(&pool, tenant_id).await;
+    let deleted = repository
+        .delete_other_exports(tenant_id, newer_job_id.as_str())
+        .await
+        .unwrap_or_else(|error| panic!("failed to delete exports: {error}"));
+    assert_eq!(deleted, 2);
+    assert!(
+        repository
+            .find_download_link(tenant_id, "token-hash")
+            .await
+            .unwrap_or_else(|error| panic!("failed to find link: {error}"))
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn members_and_audit_entries_are_listed_in_order() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresTenantDataExportRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Tenant Data Export Members Tenant").await;
+
+    let mut transaction = begin_tenant_transaction(&pool, tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to begin transaction: {error}"));
+    for (subject, age_minutes) in [("second", 1), ("first", 2)] {
+        let inserted = sqlx::query(
+            r#"
+            INSERT INTO tenant_memberships (tenant_id, subject, display_name, email, created_at)
+            VALUES ($1, $2, $2, NULL, now() - make_interval(mins => $3))
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(subject)
+        .bind(age_minutes)
+        .execute(&mut *transaction)
+        .await;
+        assert!(inserted.is_ok());
+    }
+    assert!(transaction.commit().await.is_ok());
+
+    let audit = PostgresAuditRepository::new(pool.clone());
+    for resource_id in ["record-1", "record-2"] {
+        let appended = audit
+            .append_event(AuditEvent {
+                tenant_id,
+                subject: "admin".to_owned(),
+                action: AuditAction::TenantDataExportDownloaded,
+                resource_type: "tenant_data_export".to_owned(),
+                resource_id: resource_id.to_owned(),
+                detail: None,
+            })
+            .await;
+        assert!(appended.is_ok());
+    }
+
+    let members = repository
+        .list_tenant_members(tenant_id, 10, 0)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list members: {error}"));
+    assert_eq!(
+        members
+            .iter()
+            .map(|member| member.subject.as_str())
+            .collect::<Vec<_>>(),
+        vec!["first", "second"]
+    );
+
+    let second_entry = repository
+        .list_audit_entries(tenant_id, 1, 1)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list audit entries: {error}"));
+    assert_eq!(second_entry.len(), 1);
+    assert_eq!(second_entry[0].resource_id, "record-2");
+    assert_eq!(second_entry[0].chain_position, 2);
+}
@@ -0,0 +1,229 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+use qryvanta_application::{
+    TenantEncryptionKey, TenantEncryptionKeyRepository, TenantEncryptionKeyStatus,
+};
+use qryvanta_core::{AppError, AppResult, TenantId};
+
+use crate::begin_tenant_transaction;
+
+/// PostgreSQL-backed repository for tenant-supplied encryption key metadata.
+#[derive(Clone)]
+pub struct PostgresTenantEncryptionKeyRepository {
+    pool: PgPool,
+}
+
+impl PostgresTenantEncryptionKeyRepository {
+    /// Creates a repository with the provided connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct TenantEncryptionKeyRow {
+    tenant_id: uuid::Uuid,
+    version: i32,
+    secret_reference: Option<String>,
+    key_fingerprint: String,
+    status: String,
+    created_by_subject: String,
+    created_at: DateTime<Utc>,
+    retired_at: Option<DateTime<Utc>>,
+    shredded_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<TenantEncryptionKeyRow> for TenantEncryptionKey {
+    type Error = AppError;
+
+    fn try_from(row: TenantEncryptionKeyRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            version: u32::try_from(row.version).map_err(|_| {
+                AppError::Internal(format!(
+                    "invalid tenant encryption key version '{}'",
+                    row.version
+                ))
+            })?,
+            secret_reference: row.secret_reference,
+            key_fingerprint: row.key_fingerprint,
+            status: TenantEncryptionKeyStatus::parse(row.status.as_str())?,
+            created_by_subject: row.created_by_subject,
+            created_at: row.created_at,
+            retired_at: row.retired_at,
+            shredded_at: row.shredded_at,
+        })
+    }
+}
+
+const KEY_COLUMNS: &str = r#"
+    tenant_id,
+    version,
+    secret_reference,
+    key_fingerprint,
+    status,
+    created_by_subject,
+    created_at,
+    retired_at,
+    shredded_at
+"#;
+
+#[async_trait]
+impl TenantEncryptionKeyRepository for PostgresTenantEncryptionKeyRepository {
+    async fn list_keys(&self, tenant_id: TenantId) -> AppResult<Vec<TenantEncryptionKey>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, TenantEncryptionKeyRow>(&format!(
+            r#"
+            SELECT {KEY_COLUMNS}
+            FROM tenant_encryption_keys
+            WHERE tenant_id = $1
+            ORDER BY version DESC
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list tenant encryption keys: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant encryption key transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter()
+            .map(TenantEncryptionKey::try_from)
+            .collect()
+    }
+
+    async fn find_key(
+        &self,
+        tenant_id: TenantId,
+        version: u32,
+    ) -> AppResult<Option<TenantEncryptionKey>> {
+        let version = i32::try_from(version).map_err(|_| {
+            AppError::Validation(format!("invalid tenant encryption key version '{version}'"))
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, TenantEncryptionKeyRow>(&format!(
+            r#"
+            SELECT {KEY_COLUMNS}
+            FROM tenant_encryption_keys
+            WHERE tenant_id = $1 AND version = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(version)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to find tenant encryption key: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant encryption key transaction: {error}"
+            ))
+        })?;
+
+        row.map(TenantEncryptionKey::try_from).transpose()
+    }
+
+    async fn insert_active_key(
+        &self,
+        tenant_id: TenantId,
+        secret_reference: &str,
+        key_fingerprint: &str,
+        created_by_subject: &str,
+    ) -> AppResult<TenantEncryptionKey> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        sqlx::query(
+            r#"
+            UPDATE tenant_encryption_keys
+            SET status = 'retired', retired_at = now()
+            WHERE tenant_id = $1 AND status = 'active'
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to retire tenant encryption key: {error}"))
+        })?;
+
+        let row = sqlx::query_as::<_, TenantEncryptionKeyRow>(&format!(
+            r#"
+            INSERT INTO tenant_encryption_keys (
+                tenant_id,
+                version,
+                secret_reference,
+                key_fingerprint,
+                status,
+                created_by_subject
+            )
+            SELECT
+                $1,
+                COALESCE(MAX(version), 0) + 1,
+                $2,
+                $3,
+                'active',
+                $4
+            FROM tenant_encryption_keys
+            WHERE tenant_id = $1
+            RETURNING {KEY_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(secret_reference)
+        .bind(key_fingerprint)
+        .bind(created_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to insert tenant encryption key: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant encryption key transaction: {error}"
+            ))
+        })?;
+
+        TenantEncryptionKey::try_from(row)
+    }
+
+    async fn shred_keys(&self, tenant_id: TenantId) -> AppResult<u64> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            UPDATE tenant_encryption_keys
+            SET
+                status = 'shredded',
+                secret_reference = NULL,
+                shredded_at = now()
+            WHERE tenant_id = $1 AND status <> 'shredded'
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to shred tenant encryption keys: {error}"))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant encryption key transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected())
+    }
+}
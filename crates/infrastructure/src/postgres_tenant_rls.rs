@@ -1,3 +1,6 @@
+use std::time::Duration;
+
+use qryvanta_application::remaining_operation_budget;
 use qryvanta_core::{AppError, AppResult, TenantId};
 use sqlx::{Executor, PgPool, Postgres, Transaction};
 
@@ -7,9 +10,20 @@ const RLS_SUBJECT_SETTING: &str = "qryvanta.rls_subject";
 const WORKFLOW_QUEUE_SCOPE: &str = "workflow_queue";
 const QRYWELL_SYNC_SCOPE: &str = "qrywell_sync";
 const MEMBERSHIP_SUBJECT_LOOKUP_SCOPE: &str = "membership_subject_lookup";
+const AUDIT_OUTBOX_SCOPE: &str = "audit_outbox";
+const BACKGROUND_JOBS_SCOPE: &str = "background_jobs";
+const REPORT_SUBSCRIPTIONS_SCOPE: &str = "report_subscriptions";
+const DATA_VALIDATION_SCHEDULES_SCOPE: &str = "data_validation_schedules";
+const AUDIT_EXPORT_SCOPE: &str = "audit_export";
+const RECORD_IMPORT_SCHEDULES_SCOPE: &str = "record_import_schedules";
+const RUNTIME_RECORD_CHANGES_SCOPE: &str = "runtime_record_changes";
 
 /// Begins a transaction and stamps the current tenant into the PostgreSQL
 /// session so row-level security policies can enforce tenant isolation.
+///
+/// Inside a budgeted application operation the remaining budget becomes the
+/// transaction's `statement_timeout`, so PostgreSQL cancels statements that
+/// outlive it.
 pub async fn begin_tenant_transaction(
     pool: &PgPool,
     tenant_id: TenantId,
@@ -21,6 +35,9 @@ pub async fn begin_tenant_transaction(
     })?;
 
     stamp_tenant_context(&mut *transaction, tenant_id).await?;
+    if let Some(remaining) = remaining_operation_budget() {
+        set_statement_timeout(&mut *transaction, remaining).await?;
+    }
 
     Ok(transaction)
 }
@@ -41,6 +58,62 @@ pub async fn begin_qrywell_sync_transaction(pool: &PgPool) -> AppResult<Transact
     begin_rls_scope_transaction(pool, QRYWELL_SYNC_SCOPE).await
 }
 
+/// Begins a transaction with the audit outbox bypass scope enabled so the
+/// relay can drain pending outbox events across tenants.
+pub(crate) async fn begin_audit_outbox_transaction(
+    pool: &PgPool,
+) -> AppResult<Transaction<'_, Postgres>> {
+    begin_rls_scope_transaction(pool, AUDIT_OUTBOX_SCOPE).await
+}
+
+/// Begins a transaction with the background job bypass scope enabled so job
+/// runners can claim queued and interrupted jobs across tenants.
+pub(crate) async fn begin_background_job_transaction(
+    pool: &PgPool,
+) -> AppResult<Transaction<'_, Postgres>> {
+    begin_rls_scope_transaction(pool, BACKGROUND_JOBS_SCOPE).await
+}
+
+/// Begins a transaction with the report subscription bypass scope enabled so
+/// the dispatcher can claim due subscriptions across tenants.
+pub(crate) async fn begin_report_subscription_transaction(
+    pool: &PgPool,
+) -> AppResult<Transaction<'_, Postgres>> {
+    begin_rls_scope_transaction(pool, REPORT_SUBSCRIPTIONS_SCOPE).await
+}
+
+/// Begins a transaction with the data validation schedule bypass scope
+/// enabled so the scheduler can claim due schedules across tenants.
+pub(crate) async fn begin_data_validation_schedule_transaction(
+    pool: &PgPool,
+) -> AppResult<Transaction<'_, Postgres>> {
+    begin_rls_scope_transaction(pool, DATA_VALIDATION_SCHEDULES_SCOPE).await
+}
+
+/// Begins a transaction with the audit export bypass scope enabled so the
+/// shipper can claim export sinks across tenants.
+pub(crate) async fn begin_audit_export_transaction(
+    pool: &PgPool,
+) -> AppResult<Transaction<'_, Postgres>> {
+    begin_rls_scope_transaction(pool, AUDIT_EXPORT_SCOPE).await
+}
+
+/// Begins a transaction with the recurring import bypass scope enabled so
+/// the scheduler can claim due imports across tenants.
+pub(crate) async fn begin_record_import_schedule_transaction(
+    pool: &PgPool,
+) -> AppResult<Transaction<'_, Postgres>> {
+    begin_rls_scope_transaction(pool, RECORD_IMPORT_SCHEDULES_SCOPE).await
+}
+
+/// Begins a transaction with the runtime record change feed bypass scope
+/// enabled so the reporting projector can claim changes across tenants.
+pub(crate) async fn begin_runtime_record_change_transaction(
+    pool: &PgPool,
+) -> AppResult<Transaction<'_, Postgres>> {
+    begin_rls_scope_transaction(pool, RUNTIME_RECORD_CHANGES_SCOPE).await
+}
+
 /// Begins a transaction with a single-subject membership lookup scope enabled.
 pub(crate) async fn begin_membership_subject_lookup_transaction<'a>(
     pool: &'a PgPool,
@@ -68,6 +141,21 @@ where
     Ok(())
 }
 
+async fn set_statement_timeout<'e, E>(executor: E, timeout: Duration) -> AppResult<()>
+where
+    E: Executor<'e, Database = Postgres>,
+{
+    // Zero disables the timeout in PostgreSQL, so a spent budget still gets 1 ms.
+    let timeout_ms = timeout.as_millis().max(1);
+    sqlx::query("SELECT set_config('statement_timeout', $1, true)")
+        .bind(format!("{timeout_ms}ms"))
+        .execute(executor)
+        .await
+        .map_err(|error| AppError::Internal(format!("failed to set statement timeout: {error}")))?;
+
+    Ok(())
+}
+
 async fn begin_rls_scope_transaction<'a>(
     pool: &'a PgPool,
     scope: &str,
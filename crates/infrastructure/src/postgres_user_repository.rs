@@ -28,6 +28,7 @@ struct UserRow {
     email_verified: bool,
     password_hash: Option<String>,
     totp_enabled: bool,
+    passkeys_enrolled: bool,
     totp_secret_enc: Option<Vec<u8>>,
     recovery_codes_hash: Option<serde_json::Value>,
     totp_pending_secret_enc: Option<Vec<u8>>,
@@ -47,6 +48,7 @@ impl From<UserRow> for UserRecord {
             email_verified: row.email_verified,
             password_hash: row.password_hash,
             totp_enabled: row.totp_enabled,
+            passkeys_enrolled: row.passkeys_enrolled,
             totp_secret_enc: row.totp_secret_enc,
             recovery_codes_hash: row.recovery_codes_hash,
             totp_pending_secret_enc: row.totp_pending_secret_enc,
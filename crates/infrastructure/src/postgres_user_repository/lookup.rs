@@ -8,7 +8,12 @@ impl PostgresUserRepository {
                    totp_secret_enc, recovery_codes_hash,
                    totp_pending_secret_enc, recovery_codes_pending_hash,
                    failed_login_count, locked_until, password_changed_at,
-                   auth_sessions_revoked_after, default_tenant_id
+                   auth_sessions_revoked_after, default_tenant_id,
+                   EXISTS (
+                       SELECT 1
+                       FROM passkey_credentials
+                       WHERE passkey_credentials.subject = users.id::text
+                   ) AS passkeys_enrolled
             FROM users
             WHERE LOWER(email) = LOWER($1)
             LIMIT 1
@@ -29,7 +34,12 @@ impl PostgresUserRepository {
                    totp_secret_enc, recovery_codes_hash,
                    totp_pending_secret_enc, recovery_codes_pending_hash,
                    failed_login_count, locked_until, password_changed_at,
-                   auth_sessions_revoked_after, default_tenant_id
+                   auth_sessions_revoked_after, default_tenant_id,
+                   EXISTS (
+                       SELECT 1
+                       FROM passkey_credentials
+                       WHERE passkey_credentials.subject = users.id::text
+                   ) AS passkeys_enrolled
             FROM users
             WHERE id = $1
             LIMIT 1
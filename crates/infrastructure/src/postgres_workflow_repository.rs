@@ -2,15 +2,17 @@ use crate::{begin_tenant_transaction, begin_workflow_worker_transaction};
 use async_trait::async_trait;
 use qryvanta_application::{
     ClaimedWorkflowJob, ClaimedWorkflowScheduleTick, CompleteWorkflowRunInput,
-    CreateWorkflowRunInput, WorkflowClaimPartition, WorkflowQueueStats, WorkflowQueueStatsQuery,
-    WorkflowRepository, WorkflowRun, WorkflowRunAttempt, WorkflowRunAttemptStatus,
-    WorkflowRunListQuery, WorkflowRunStatus, WorkflowRunStepTrace, WorkflowScheduledTrigger,
+    CreateWorkflowRunInput, NewWorkflowBulkExecution, SuspendWorkflowRunInput,
+    WorkflowBulkExecution, WorkflowBulkExecutionRunCounts, WorkflowClaimPartition,
+    WorkflowQueueStats, WorkflowQueueStatsQuery, WorkflowRepository, WorkflowRun,
+    WorkflowRunAttempt, WorkflowRunAttemptStatus, WorkflowRunListQuery, WorkflowRunStatus,
+    WorkflowRunStepTrace, WorkflowScheduleKind, WorkflowScheduledTrigger, WorkflowStuckJob,
     WorkflowWorkerHeartbeatInput,
 };
 use qryvanta_core::{AppError, AppResult, TenantId};
 use qryvanta_domain::{
-    WorkflowDefinition, WorkflowDefinitionInput, WorkflowLifecycleState, WorkflowStep,
-    WorkflowTrigger,
+    WorkflowDefinition, WorkflowDefinitionInput, WorkflowLifecycleState, WorkflowRunPriority,
+    WorkflowStep, WorkflowTrigger, WorkflowTriggerFilter,
 };
 use serde_json::Value;
 use sqlx::{FromRow, PgPool};
@@ -36,8 +38,12 @@ struct WorkflowDefinitionRow {
     description: Option<String>,
     trigger_type: String,
     trigger_entity_logical_name: Option<String>,
+    trigger_filters: Value,
+    trigger_changed_fields: Value,
+    trigger_view_logical_name: Option<String>,
     steps: Value,
     max_attempts: i16,
+    priority: i16,
     lifecycle_state: String,
     current_published_version: Option<i32>,
 }
@@ -55,6 +61,9 @@ struct WorkflowRunRow {
     dead_letter_reason: Option<String>,
     started_at: chrono::DateTime<chrono::Utc>,
     finished_at: Option<chrono::DateTime<chrono::Utc>>,
+    resume_at: Option<chrono::DateTime<chrono::Utc>>,
+    parent_run_id: Option<uuid::Uuid>,
+    priority: i16,
 }
 
 #[derive(Debug, FromRow)]
@@ -75,13 +84,20 @@ struct ClaimedWorkflowJobRow {
     workflow_version: i32,
     lease_token: String,
     trigger_payload: Value,
+    continuation: Option<Value>,
+    trace_context: Option<String>,
+    run_priority: i16,
     logical_name: String,
     display_name: String,
     description: Option<String>,
     trigger_type: String,
     trigger_entity_logical_name: Option<String>,
+    trigger_filters: Value,
+    trigger_changed_fields: Value,
+    trigger_view_logical_name: Option<String>,
     steps: Value,
     max_attempts: i16,
+    priority: i16,
     lifecycle_state: String,
     current_published_version: Option<i32>,
 }
@@ -95,9 +111,22 @@ struct WorkflowQueueStatsRow {
     expired_leases: i64,
 }
 
+#[derive(Debug, FromRow)]
+struct WorkflowStuckJobRow {
+    job_id: uuid::Uuid,
+    run_id: uuid::Uuid,
+    workflow_logical_name: String,
+    workflow_version: i32,
+    leased_by: Option<String>,
+    lease_expires_at: chrono::DateTime<chrono::Utc>,
+    run_attempts: i32,
+    last_error: Option<String>,
+}
+
 #[derive(Debug, FromRow)]
 struct WorkflowScheduledTriggerRow {
     tenant_id: uuid::Uuid,
+    trigger_type: String,
     schedule_key: String,
 }
 
@@ -111,7 +140,10 @@ struct ClaimedWorkflowScheduleTickRow {
     lease_token: String,
 }
 
+mod bulk_executions;
+mod credentials;
 mod definitions;
+mod inbound_webhooks;
 mod queue;
 mod runs;
 
@@ -293,6 +325,56 @@ impl WorkflowRepository for PostgresWorkflowRepository {
             .await
     }
 
+    async fn suspend_run_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        worker_id: &str,
+        lease_token: &str,
+        input: SuspendWorkflowRunInput,
+    ) -> AppResult<WorkflowRun> {
+        self.suspend_run_job_impl(tenant_id, job_id, worker_id, lease_token, input)
+            .await
+    }
+
+    async fn list_stuck_jobs(
+        &self,
+        tenant_id: TenantId,
+        limit: usize,
+    ) -> AppResult<Vec<WorkflowStuckJob>> {
+        self.list_stuck_jobs_impl(tenant_id, limit).await
+    }
+
+    async fn release_stuck_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        reason: &str,
+    ) -> AppResult<bool> {
+        self.release_stuck_job_impl(tenant_id, job_id, reason).await
+    }
+
+    async fn release_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        worker_id: &str,
+        lease_token: &str,
+        reason: &str,
+    ) -> AppResult<bool> {
+        self.release_job_impl(tenant_id, job_id, worker_id, lease_token, reason)
+            .await
+    }
+
+    async fn fail_stuck_job(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        reason: &str,
+    ) -> AppResult<Option<WorkflowRun>> {
+        self.fail_stuck_job_impl(tenant_id, job_id, reason).await
+    }
+
     async fn upsert_worker_heartbeat(
         &self,
         worker_id: &str,
@@ -333,6 +415,16 @@ impl WorkflowRepository for PostgresWorkflowRepository {
         self.find_run_impl(tenant_id, run_id).await
     }
 
+    async fn find_run_by_idempotency_key(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+        idempotency_key: &str,
+    ) -> AppResult<Option<WorkflowRun>> {
+        self.find_run_by_idempotency_key_impl(tenant_id, workflow_logical_name, idempotency_key)
+            .await
+    }
+
     async fn list_run_attempts(
         &self,
         tenant_id: TenantId,
@@ -340,6 +432,32 @@ impl WorkflowRepository for PostgresWorkflowRepository {
     ) -> AppResult<Vec<WorkflowRunAttempt>> {
         self.list_run_attempts_impl(tenant_id, run_id).await
     }
+
+    async fn create_bulk_execution(
+        &self,
+        tenant_id: TenantId,
+        execution: NewWorkflowBulkExecution,
+    ) -> AppResult<WorkflowBulkExecution> {
+        self.create_bulk_execution_impl(tenant_id, execution).await
+    }
+
+    async fn find_bulk_execution(
+        &self,
+        tenant_id: TenantId,
+        bulk_execution_id: &str,
+    ) -> AppResult<Option<WorkflowBulkExecution>> {
+        self.find_bulk_execution_impl(tenant_id, bulk_execution_id)
+            .await
+    }
+
+    async fn bulk_execution_run_counts(
+        &self,
+        tenant_id: TenantId,
+        bulk_execution_id: &str,
+    ) -> AppResult<WorkflowBulkExecutionRunCounts> {
+        self.bulk_execution_run_counts_impl(tenant_id, bulk_execution_id)
+            .await
+    }
 }
 
 fn workflow_definition_from_row(row: WorkflowDefinitionRow) -> AppResult<WorkflowDefinition> {
@@ -355,7 +473,13 @@ fn workflow_definition_from_row(row: WorkflowDefinitionRow) -> AppResult<Workflo
         max_attempts: u16::try_from(row.max_attempts).map_err(|error| {
             AppError::Validation(format!("invalid workflow max_attempts value: {error}"))
         })?,
-    })?;
+    })?
+    .with_trigger_filters(workflow_trigger_filters_from_json(row.trigger_filters)?)?
+    .with_trigger_scope(
+        workflow_trigger_changed_fields_from_json(row.trigger_changed_fields)?,
+        row.trigger_view_logical_name,
+    )?
+    .with_priority(WorkflowRunPriority::from_rank(row.priority)?);
 
     workflow.with_publish_state(
         WorkflowLifecycleState::parse(row.lifecycle_state.as_str())?,
@@ -369,6 +493,30 @@ fn workflow_steps_to_json(steps: &[WorkflowStep]) -> AppResult<Value> {
     })
 }
 
+fn workflow_trigger_filters_to_json(filters: &[WorkflowTriggerFilter]) -> AppResult<Value> {
+    serde_json::to_value(filters).map_err(|error| {
+        AppError::Validation(format!(
+            "failed to serialize workflow trigger filters: {error}"
+        ))
+    })
+}
+
+fn workflow_trigger_filters_from_json(value: Value) -> AppResult<Vec<WorkflowTriggerFilter>> {
+    serde_json::from_value(value).map_err(|error| {
+        AppError::Validation(format!(
+            "failed to deserialize workflow trigger filters: {error}"
+        ))
+    })
+}
+
+fn workflow_trigger_changed_fields_from_json(value: Value) -> AppResult<Vec<String>> {
+    serde_json::from_value(value).map_err(|error| {
+        AppError::Validation(format!(
+            "failed to deserialize workflow trigger changed fields: {error}"
+        ))
+    })
+}
+
 fn workflow_steps_from_json(value: Value) -> AppResult<Vec<WorkflowStep>> {
     serde_json::from_value(value).map_err(|error| {
         AppError::Validation(format!("failed to deserialize workflow steps: {error}"))
@@ -401,9 +549,18 @@ fn workflow_trigger_parts(trigger: &WorkflowTrigger) -> (&'static str, Option<&s
         WorkflowTrigger::RuntimeRecordDeleted {
             entity_logical_name,
         } => ("runtime_record_deleted", Some(entity_logical_name.as_str())),
+        WorkflowTrigger::RuntimeRecordStatusChanged {
+            entity_logical_name,
+        } => (
+            "runtime_record_status_changed",
+            Some(entity_logical_name.as_str()),
+        ),
         WorkflowTrigger::ScheduleTick { schedule_key } => {
             ("schedule_tick", Some(schedule_key.as_str()))
         }
+        WorkflowTrigger::Scheduled { cron_expression } => {
+            ("scheduled", Some(cron_expression.as_str()))
+        }
         WorkflowTrigger::WebhookReceived { webhook_key } => {
             ("webhook_received", Some(webhook_key.as_str()))
         }
@@ -459,6 +616,18 @@ fn workflow_trigger_from_parts(
                 entity_logical_name: entity_logical_name.to_owned(),
             })
         }
+        "runtime_record_status_changed" => {
+            let entity_logical_name = trigger_entity_logical_name.ok_or_else(|| {
+                AppError::Validation(
+                    "runtime_record_status_changed trigger requires trigger_entity_logical_name"
+                        .to_owned(),
+                )
+            })?;
+
+            Ok(WorkflowTrigger::RuntimeRecordStatusChanged {
+                entity_logical_name: entity_logical_name.to_owned(),
+            })
+        }
         "schedule_tick" => {
             let schedule_key = trigger_entity_logical_name.ok_or_else(|| {
                 AppError::Validation(
@@ -470,6 +639,17 @@ fn workflow_trigger_from_parts(
                 schedule_key: schedule_key.to_owned(),
             })
         }
+        "scheduled" => {
+            let cron_expression = trigger_entity_logical_name.ok_or_else(|| {
+                AppError::Validation(
+                    "scheduled trigger requires trigger_entity_logical_name".to_owned(),
+                )
+            })?;
+
+            Ok(WorkflowTrigger::Scheduled {
+                cron_expression: cron_expression.to_owned(),
+            })
+        }
         "webhook_received" => {
             let webhook_key = trigger_entity_logical_name.ok_or_else(|| {
                 AppError::Validation(
@@ -530,8 +710,12 @@ fn claimed_workflow_job_from_row(row: ClaimedWorkflowJobRow) -> AppResult<Claime
         description: row.description,
         trigger_type: row.trigger_type,
         trigger_entity_logical_name: row.trigger_entity_logical_name,
+        trigger_filters: row.trigger_filters,
+        trigger_changed_fields: row.trigger_changed_fields,
+        trigger_view_logical_name: row.trigger_view_logical_name,
         steps: row.steps,
         max_attempts: row.max_attempts,
+        priority: row.priority,
         lifecycle_state: row.lifecycle_state,
         current_published_version: row.current_published_version,
     })?;
@@ -544,6 +728,17 @@ fn claimed_workflow_job_from_row(row: ClaimedWorkflowJobRow) -> AppResult<Claime
         workflow,
         trigger_payload: row.trigger_payload,
         lease_token: row.lease_token,
+        continuation: row
+            .continuation
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to decode workflow run continuation: {error}"
+                ))
+            })?,
+        trace_context: row.trace_context,
+        priority: WorkflowRunPriority::from_rank(row.run_priority)?,
     })
 }
 
@@ -560,6 +755,9 @@ fn workflow_run_from_row(row: WorkflowRunRow) -> AppResult<WorkflowRun> {
         dead_letter_reason: row.dead_letter_reason,
         started_at: row.started_at,
         finished_at: row.finished_at,
+        resume_at: row.resume_at,
+        parent_run_id: row.parent_run_id.map(|run_id| run_id.to_string()),
+        priority: WorkflowRunPriority::from_rank(row.priority)?,
     })
 }
 
@@ -0,0 +1,169 @@
+use super::*;
+
+#[derive(Debug, FromRow)]
+struct WorkflowBulkExecutionRow {
+    id: uuid::Uuid,
+    workflow_logical_name: String,
+    entity_logical_name: String,
+    view_logical_name: Option<String>,
+    total_records: i64,
+    requested_by_subject: String,
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct WorkflowBulkExecutionRunCountsRow {
+    pending: i64,
+    succeeded: i64,
+    dead_lettered: i64,
+    last_finished_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl PostgresWorkflowRepository {
+    pub(super) async fn create_bulk_execution_impl(
+        &self,
+        tenant_id: TenantId,
+        execution: NewWorkflowBulkExecution,
+    ) -> AppResult<WorkflowBulkExecution> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, WorkflowBulkExecutionRow>(
+            r#"
+            INSERT INTO workflow_bulk_executions (
+                tenant_id,
+                workflow_logical_name,
+                entity_logical_name,
+                view_logical_name,
+                total_records,
+                requested_by_subject
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING
+                id,
+                workflow_logical_name,
+                entity_logical_name,
+                view_logical_name,
+                total_records,
+                requested_by_subject,
+                created_at
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(execution.workflow_logical_name)
+        .bind(execution.entity_logical_name)
+        .bind(execution.view_logical_name)
+        .bind(execution.total_records)
+        .bind(execution.requested_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to create bulk workflow execution for tenant '{}': {error}",
+                tenant_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped bulk workflow execution create transaction: {error}"
+            ))
+        })?;
+
+        Ok(workflow_bulk_execution_from_row(row))
+    }
+
+    pub(super) async fn find_bulk_execution_impl(
+        &self,
+        tenant_id: TenantId,
+        bulk_execution_id: &str,
+    ) -> AppResult<Option<WorkflowBulkExecution>> {
+        let Ok(bulk_execution_id) = uuid::Uuid::parse_str(bulk_execution_id) else {
+            return Ok(None);
+        };
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, WorkflowBulkExecutionRow>(
+            r#"
+            SELECT
+                id,
+                workflow_logical_name,
+                entity_logical_name,
+                view_logical_name,
+                total_records,
+                requested_by_subject,
+                created_at
+            FROM workflow_bulk_executions
+            WHERE tenant_id = $1 AND id = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(bulk_execution_id)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find bulk workflow execution for tenant '{}': {error}",
+                tenant_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped bulk workflow execution lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(workflow_bulk_execution_from_row))
+    }
+
+    pub(super) async fn bulk_execution_run_counts_impl(
+        &self,
+        tenant_id: TenantId,
+        bulk_execution_id: &str,
+    ) -> AppResult<WorkflowBulkExecutionRunCounts> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, WorkflowBulkExecutionRunCountsRow>(
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE status IN ('running', 'waiting')) AS pending,
+                COUNT(*) FILTER (WHERE status = 'succeeded') AS succeeded,
+                COUNT(*) FILTER (WHERE status = 'dead_lettered') AS dead_lettered,
+                MAX(finished_at) AS last_finished_at
+            FROM workflow_execution_runs
+            WHERE tenant_id = $1
+              AND trigger_payload ? 'bulk_execution_id'
+              AND trigger_payload ->> 'bulk_execution_id' = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(bulk_execution_id)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to count bulk workflow execution runs for tenant '{}': {error}",
+                tenant_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped bulk workflow execution count transaction: {error}"
+            ))
+        })?;
+
+        Ok(WorkflowBulkExecutionRunCounts {
+            pending: row.pending,
+            succeeded: row.succeeded,
+            dead_lettered: row.dead_lettered,
+            last_finished_at: row.last_finished_at,
+        })
+    }
+}
+
+fn workflow_bulk_execution_from_row(row: WorkflowBulkExecutionRow) -> WorkflowBulkExecution {
+    WorkflowBulkExecution {
+        bulk_execution_id: row.id.to_string(),
+        workflow_logical_name: row.workflow_logical_name,
+        entity_logical_name: row.entity_logical_name,
+        view_logical_name: row.view_logical_name,
+        total_records: row.total_records,
+        requested_by_subject: row.requested_by_subject,
+        created_at: row.created_at,
+    }
+}
@@ -0,0 +1,199 @@
+use chrono::{DateTime, Utc};
+use qryvanta_application::{WorkflowCredentialRecord, WorkflowCredentialRepository};
+use qryvanta_domain::{WorkflowCredential, WorkflowCredentialAuth};
+
+use super::*;
+
+#[derive(Debug, FromRow)]
+struct WorkflowCredentialRow {
+    logical_name: String,
+    display_name: String,
+    auth: Value,
+    allowed_hosts: Value,
+    updated_by_subject: String,
+    updated_at: DateTime<Utc>,
+}
+
+const WORKFLOW_CREDENTIAL_COLUMNS: &str = r#"
+    logical_name,
+    display_name,
+    auth,
+    allowed_hosts,
+    updated_by_subject,
+    updated_at
+"#;
+
+fn workflow_credential_from_row(row: WorkflowCredentialRow) -> AppResult<WorkflowCredentialRecord> {
+    let auth = serde_json::from_value::<WorkflowCredentialAuth>(row.auth).map_err(|error| {
+        AppError::Internal(format!(
+            "persisted workflow credential '{}' has invalid auth: {error}",
+            row.logical_name
+        ))
+    })?;
+    let allowed_hosts =
+        serde_json::from_value::<Vec<String>>(row.allowed_hosts).map_err(|error| {
+            AppError::Internal(format!(
+                "persisted workflow credential '{}' has invalid allowed hosts: {error}",
+                row.logical_name
+            ))
+        })?;
+
+    Ok(WorkflowCredentialRecord {
+        credential: WorkflowCredential::new(
+            row.logical_name,
+            row.display_name,
+            auth,
+            allowed_hosts,
+        )?,
+        updated_by_subject: row.updated_by_subject,
+        updated_at: row.updated_at,
+    })
+}
+
+#[async_trait]
+impl WorkflowCredentialRepository for PostgresWorkflowRepository {
+    async fn save_workflow_credential(
+        &self,
+        tenant_id: TenantId,
+        credential: WorkflowCredential,
+        updated_by_subject: &str,
+    ) -> AppResult<WorkflowCredentialRecord> {
+        let auth = serde_json::to_value(credential.auth()).map_err(|error| {
+            AppError::Internal(format!(
+                "failed to serialize workflow credential auth: {error}"
+            ))
+        })?;
+
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, WorkflowCredentialRow>(&format!(
+            r#"
+            INSERT INTO workflow_credentials (
+                tenant_id,
+                logical_name,
+                display_name,
+                auth,
+                allowed_hosts,
+                updated_by_subject
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (tenant_id, logical_name) DO UPDATE
+            SET display_name = EXCLUDED.display_name,
+                auth = EXCLUDED.auth,
+                allowed_hosts = EXCLUDED.allowed_hosts,
+                updated_by_subject = EXCLUDED.updated_by_subject,
+                updated_at = now()
+            RETURNING {WORKFLOW_CREDENTIAL_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(credential.logical_name().as_str())
+        .bind(credential.display_name().as_str())
+        .bind(auth)
+        .bind(Value::from(credential.allowed_hosts().to_vec()))
+        .bind(updated_by_subject)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to save workflow credential '{}': {error}",
+                credential.logical_name().as_str()
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped workflow credential save transaction: {error}"
+            ))
+        })?;
+
+        workflow_credential_from_row(row)
+    }
+
+    async fn list_workflow_credentials(
+        &self,
+        tenant_id: TenantId,
+    ) -> AppResult<Vec<WorkflowCredentialRecord>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let rows = sqlx::query_as::<_, WorkflowCredentialRow>(&format!(
+            r#"
+            SELECT {WORKFLOW_CREDENTIAL_COLUMNS}
+            FROM workflow_credentials
+            WHERE tenant_id = $1
+            ORDER BY logical_name
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to list workflow credentials: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped workflow credential list transaction: {error}"
+            ))
+        })?;
+
+        rows.into_iter().map(workflow_credential_from_row).collect()
+    }
+
+    async fn find_workflow_credential(
+        &self,
+        tenant_id: TenantId,
+        logical_name: &str,
+    ) -> AppResult<Option<WorkflowCredentialRecord>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, WorkflowCredentialRow>(&format!(
+            r#"
+            SELECT {WORKFLOW_CREDENTIAL_COLUMNS}
+            FROM workflow_credentials
+            WHERE tenant_id = $1 AND logical_name = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(logical_name)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find workflow credential '{logical_name}': {error}"
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped workflow credential read transaction: {error}"
+            ))
+        })?;
+
+        row.map(workflow_credential_from_row).transpose()
+    }
+
+    async fn delete_workflow_credential(
+        &self,
+        tenant_id: TenantId,
+        logical_name: &str,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM workflow_credentials
+            WHERE tenant_id = $1 AND logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(logical_name)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete workflow credential '{logical_name}': {error}"
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped workflow credential delete transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
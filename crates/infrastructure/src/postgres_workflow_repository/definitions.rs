@@ -9,6 +9,8 @@ impl PostgresWorkflowRepository {
         let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
         let (trigger_type, trigger_entity) = workflow_trigger_parts(workflow.trigger());
         let steps = workflow_steps_to_json(workflow.steps())?;
+        let trigger_filters = workflow_trigger_filters_to_json(workflow.trigger_filters())?;
+        let trigger_changed_fields = Value::from(workflow.trigger_changed_fields().to_vec());
 
         let result = sqlx::query(
             r#"
@@ -19,19 +21,27 @@ impl PostgresWorkflowRepository {
                 description,
                 trigger_type,
                 trigger_entity_logical_name,
+                trigger_filters,
+                trigger_changed_fields,
+                trigger_view_logical_name,
                 steps,
                 max_attempts,
+                priority,
                 updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, now())
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, now())
             ON CONFLICT (tenant_id, logical_name)
             DO UPDATE SET
                 display_name = EXCLUDED.display_name,
                 description = EXCLUDED.description,
                 trigger_type = EXCLUDED.trigger_type,
                 trigger_entity_logical_name = EXCLUDED.trigger_entity_logical_name,
+                trigger_filters = EXCLUDED.trigger_filters,
+                trigger_changed_fields = EXCLUDED.trigger_changed_fields,
+                trigger_view_logical_name = EXCLUDED.trigger_view_logical_name,
                 steps = EXCLUDED.steps,
                 max_attempts = EXCLUDED.max_attempts,
+                priority = EXCLUDED.priority,
                 updated_at = now()
             "#,
         )
@@ -41,10 +51,14 @@ impl PostgresWorkflowRepository {
         .bind(workflow.description())
         .bind(trigger_type)
         .bind(trigger_entity)
+        .bind(trigger_filters)
+        .bind(trigger_changed_fields)
+        .bind(workflow.trigger_view_logical_name())
         .bind(steps)
         .bind(i16::try_from(workflow.max_attempts()).map_err(|error| {
             AppError::Validation(format!("invalid workflow max_attempts value: {error}"))
         })?)
+        .bind(workflow.priority().rank())
         .execute(&mut *transaction)
         .await;
 
@@ -78,8 +92,12 @@ impl PostgresWorkflowRepository {
                 description,
                 trigger_type,
                 trigger_entity_logical_name,
+                trigger_filters,
+                trigger_changed_fields,
+                trigger_view_logical_name,
                 steps,
                 max_attempts,
+                priority,
                 lifecycle_state,
                 current_published_version
             FROM workflow_definitions
@@ -119,8 +137,12 @@ impl PostgresWorkflowRepository {
                 description,
                 trigger_type,
                 trigger_entity_logical_name,
+                trigger_filters,
+                trigger_changed_fields,
+                trigger_view_logical_name,
                 steps,
                 max_attempts,
+                priority,
                 lifecycle_state,
                 current_published_version
             FROM workflow_definitions
@@ -160,8 +182,12 @@ impl PostgresWorkflowRepository {
                 versions.description,
                 versions.trigger_type,
                 versions.trigger_entity_logical_name,
+                versions.trigger_filters,
+                versions.trigger_changed_fields,
+                versions.trigger_view_logical_name,
                 versions.steps,
                 versions.max_attempts,
+                versions.priority,
                 definitions.lifecycle_state,
                 definitions.current_published_version
             FROM workflow_definitions definitions
@@ -207,8 +233,12 @@ impl PostgresWorkflowRepository {
                 versions.description,
                 versions.trigger_type,
                 versions.trigger_entity_logical_name,
+                versions.trigger_filters,
+                versions.trigger_changed_fields,
+                versions.trigger_view_logical_name,
                 versions.steps,
                 versions.max_attempts,
+                versions.priority,
                 CASE
                     WHEN definitions.current_published_version = versions.version
                         THEN definitions.lifecycle_state
@@ -259,8 +289,12 @@ impl PostgresWorkflowRepository {
                 description,
                 trigger_type,
                 trigger_entity_logical_name,
+                trigger_filters,
+                trigger_changed_fields,
+                trigger_view_logical_name,
                 steps,
                 max_attempts,
+                priority,
                 lifecycle_state,
                 current_published_version
             FROM workflow_definitions
@@ -297,12 +331,16 @@ impl PostgresWorkflowRepository {
                 description,
                 trigger_type,
                 trigger_entity_logical_name,
+                trigger_filters,
+                trigger_changed_fields,
+                trigger_view_logical_name,
                 steps,
                 max_attempts,
+                priority,
                 published_by_subject,
                 published_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, now())
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, now())
             "#,
         )
         .bind(tenant_id.as_uuid())
@@ -312,8 +350,12 @@ impl PostgresWorkflowRepository {
         .bind(draft.description)
         .bind(draft.trigger_type)
         .bind(draft.trigger_entity_logical_name)
+        .bind(draft.trigger_filters)
+        .bind(draft.trigger_changed_fields)
+        .bind(draft.trigger_view_logical_name)
         .bind(draft.steps)
         .bind(draft.max_attempts)
+        .bind(draft.priority)
         .bind(published_by)
         .execute(&mut *transaction)
         .await
@@ -338,8 +380,12 @@ impl PostgresWorkflowRepository {
                 description,
                 trigger_type,
                 trigger_entity_logical_name,
+                trigger_filters,
+                trigger_changed_fields,
+                trigger_view_logical_name,
                 steps,
                 max_attempts,
+                priority,
                 lifecycle_state,
                 current_published_version
             "#,
@@ -379,8 +425,12 @@ impl PostgresWorkflowRepository {
                 description,
                 trigger_type,
                 trigger_entity_logical_name,
+                trigger_filters,
+                trigger_changed_fields,
+                trigger_view_logical_name,
                 steps,
                 max_attempts,
+                priority,
                 lifecycle_state,
                 current_published_version
             FROM workflow_definitions
@@ -425,8 +475,12 @@ impl PostgresWorkflowRepository {
                 description,
                 trigger_type,
                 trigger_entity_logical_name,
+                trigger_filters,
+                trigger_changed_fields,
+                trigger_view_logical_name,
                 steps,
                 max_attempts,
+                priority,
                 lifecycle_state,
                 current_published_version
             "#,
@@ -467,8 +521,12 @@ impl PostgresWorkflowRepository {
                 versions.description,
                 versions.trigger_type,
                 versions.trigger_entity_logical_name,
+                versions.trigger_filters,
+                versions.trigger_changed_fields,
+                versions.trigger_view_logical_name,
                 versions.steps,
                 versions.max_attempts,
+                versions.priority,
                 definitions.lifecycle_state,
                 definitions.current_published_version
             FROM workflow_definitions definitions
@@ -0,0 +1,225 @@
+use chrono::{DateTime, Utc};
+use qryvanta_application::{
+    NewWorkflowInboundWebhook, WorkflowInboundWebhook, WorkflowInboundWebhookCredentials,
+    WorkflowInboundWebhookRepository,
+};
+
+use super::*;
+
+#[derive(Debug, FromRow)]
+struct WorkflowInboundWebhookRow {
+    workflow_logical_name: String,
+    payload_schema: Option<Value>,
+    created_by_subject: String,
+    created_at: DateTime<Utc>,
+    delivery_count: i64,
+    last_received_at: Option<DateTime<Utc>>,
+    signing_secret_ciphertext: Vec<u8>,
+}
+
+impl From<WorkflowInboundWebhookRow> for WorkflowInboundWebhookCredentials {
+    fn from(row: WorkflowInboundWebhookRow) -> Self {
+        Self {
+            webhook: WorkflowInboundWebhook {
+                workflow_logical_name: row.workflow_logical_name,
+                payload_schema: row.payload_schema,
+                created_by_subject: row.created_by_subject,
+                created_at: row.created_at,
+                delivery_count: row.delivery_count,
+                last_received_at: row.last_received_at,
+            },
+            signing_secret_ciphertext: row.signing_secret_ciphertext,
+        }
+    }
+}
+
+const INBOUND_WEBHOOK_COLUMNS: &str = r#"
+    workflow_logical_name,
+    payload_schema,
+    created_by_subject,
+    created_at,
+    delivery_count,
+    last_received_at,
+    signing_secret_ciphertext
+"#;
+
+#[async_trait]
+impl WorkflowInboundWebhookRepository for PostgresWorkflowRepository {
+    async fn save_inbound_webhook(
+        &self,
+        tenant_id: TenantId,
+        webhook: NewWorkflowInboundWebhook,
+    ) -> AppResult<WorkflowInboundWebhook> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, WorkflowInboundWebhookRow>(&format!(
+            r#"
+            INSERT INTO workflow_inbound_webhooks (
+                tenant_id,
+                workflow_logical_name,
+                token_hash,
+                signing_secret_ciphertext,
+                payload_schema,
+                created_by_subject
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (tenant_id, workflow_logical_name) DO UPDATE
+            SET token_hash = EXCLUDED.token_hash,
+                signing_secret_ciphertext = EXCLUDED.signing_secret_ciphertext,
+                payload_schema = EXCLUDED.payload_schema,
+                created_by_subject = EXCLUDED.created_by_subject,
+                created_at = now(),
+                delivery_count = 0,
+                last_received_at = NULL
+            RETURNING {INBOUND_WEBHOOK_COLUMNS}
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(webhook.workflow_logical_name.as_str())
+        .bind(webhook.token_hash.as_str())
+        .bind(webhook.signing_secret_ciphertext)
+        .bind(webhook.payload_schema)
+        .bind(webhook.created_by_subject.as_str())
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to save inbound webhook for workflow '{}': {error}",
+                webhook.workflow_logical_name
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped inbound webhook save transaction: {error}"
+            ))
+        })?;
+
+        Ok(WorkflowInboundWebhookCredentials::from(row).webhook)
+    }
+
+    async fn find_inbound_webhook(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+    ) -> AppResult<Option<WorkflowInboundWebhook>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, WorkflowInboundWebhookRow>(&format!(
+            r#"
+            SELECT {INBOUND_WEBHOOK_COLUMNS}
+            FROM workflow_inbound_webhooks
+            WHERE tenant_id = $1 AND workflow_logical_name = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(workflow_logical_name)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find inbound webhook for workflow '{}': {error}",
+                workflow_logical_name
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped inbound webhook read transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(|row| WorkflowInboundWebhookCredentials::from(row).webhook))
+    }
+
+    async fn find_inbound_webhook_by_token_hash(
+        &self,
+        tenant_id: TenantId,
+        token_hash: &str,
+    ) -> AppResult<Option<WorkflowInboundWebhookCredentials>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let row = sqlx::query_as::<_, WorkflowInboundWebhookRow>(&format!(
+            r#"
+            SELECT {INBOUND_WEBHOOK_COLUMNS}
+            FROM workflow_inbound_webhooks
+            WHERE tenant_id = $1 AND token_hash = $2
+            "#
+        ))
+        .bind(tenant_id.as_uuid())
+        .bind(token_hash)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!("failed to find inbound webhook by token: {error}"))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped inbound webhook token lookup transaction: {error}"
+            ))
+        })?;
+
+        Ok(row.map(WorkflowInboundWebhookCredentials::from))
+    }
+
+    async fn delete_inbound_webhook(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+    ) -> AppResult<bool> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        let result = sqlx::query(
+            r#"
+            DELETE FROM workflow_inbound_webhooks
+            WHERE tenant_id = $1 AND workflow_logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(workflow_logical_name)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to delete inbound webhook for workflow '{}': {error}",
+                workflow_logical_name
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped inbound webhook delete transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn record_inbound_webhook_delivery(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+        received_at: DateTime<Utc>,
+    ) -> AppResult<()> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+        sqlx::query(
+            r#"
+            UPDATE workflow_inbound_webhooks
+            SET delivery_count = delivery_count + 1,
+                last_received_at = $3
+            WHERE tenant_id = $1 AND workflow_logical_name = $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(workflow_logical_name)
+        .bind(received_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to record inbound webhook delivery for workflow '{}': {error}",
+                workflow_logical_name
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped inbound webhook delivery transaction: {error}"
+            ))
+        })?;
+
+        Ok(())
+    }
+}
@@ -11,6 +11,7 @@ impl PostgresWorkflowRepository {
             r#"
             SELECT DISTINCT
                 definitions.tenant_id,
+                versions.trigger_type,
                 versions.trigger_entity_logical_name AS schedule_key
             FROM workflow_definitions definitions
             INNER JOIN workflow_published_versions versions
@@ -18,10 +19,10 @@ impl PostgresWorkflowRepository {
                AND versions.logical_name = definitions.logical_name
                AND versions.version = definitions.current_published_version
             WHERE definitions.lifecycle_state = 'published'
-              AND versions.trigger_type = 'schedule_tick'
+              AND versions.trigger_type IN ('schedule_tick', 'scheduled')
               AND versions.trigger_entity_logical_name IS NOT NULL
               AND ($1::UUID IS NULL OR definitions.tenant_id = $1)
-            ORDER BY definitions.tenant_id, schedule_key
+            ORDER BY definitions.tenant_id, versions.trigger_type, schedule_key
             "#,
         )
         .bind(tenant_filter.map(|value| value.as_uuid()))
@@ -256,10 +257,22 @@ impl PostgresWorkflowRepository {
                 tenant_id,
                 run_id,
                 status,
+                priority,
                 created_at,
                 updated_at
             )
-            VALUES ($1, $2, 'pending', now(), now())
+            VALUES (
+                $1,
+                $2,
+                'pending',
+                (
+                    SELECT priority
+                    FROM workflow_execution_runs
+                    WHERE tenant_id = $1 AND id = $2
+                ),
+                now(),
+                now()
+            )
             ON CONFLICT (run_id)
             DO NOTHING
             "#,
@@ -307,26 +320,148 @@ impl PostgresWorkflowRepository {
 
         let mut transaction = begin_workflow_worker_transaction(&self.pool).await?;
 
-        let claim_rows = sqlx::query_as::<_, ClaimedWorkflowJobRow>(
+        // Claims for a capped tenant run one at a time so each sees the leases
+        // committed by the previous one. Tenants already being claimed by
+        // another worker are skipped, like locked job rows. The lock is taken
+        // in its own statement so the claim below reads leases from a snapshot
+        // taken after it.
+        let claimable_capped_tenant_ids = sqlx::query_scalar::<_, uuid::Uuid>(
             r#"
-            WITH candidate_jobs AS (
-                SELECT id
-                FROM workflow_execution_jobs
-                WHERE (
-                        status = 'pending'
-                        OR (status = 'leased' AND lease_expires_at < now())
+            WITH capped_tenants AS MATERIALIZED (
+                SELECT tenants.id
+                FROM tenants
+                WHERE tenants.workflow_max_concurrent_jobs IS NOT NULL
+                  AND ($1::UUID IS NULL OR tenants.id = $1)
+                  AND (
+                        $2::INT IS NULL
+                        OR mod(
+                            (hashtext(tenants.id::text)::BIGINT & 2147483647),
+                            $2::BIGINT
+                        ) = $3::BIGINT
                       )
-                  AND ($6::UUID IS NULL OR tenant_id = $6)
+                  AND EXISTS (
+                        SELECT 1
+                        FROM workflow_execution_jobs jobs
+                        WHERE jobs.tenant_id = tenants.id
+                          AND jobs.status IN ('pending', 'leased')
+                          AND (
+                                (jobs.status = 'pending' AND jobs.available_at <= now())
+                                OR (jobs.status = 'leased' AND jobs.lease_expires_at < now())
+                              )
+                      )
+            )
+            SELECT id
+            FROM capped_tenants
+            WHERE pg_try_advisory_xact_lock(hashtext('workflow_claims:' || id::text))
+            "#,
+        )
+        .bind(tenant_filter.map(|value| value.as_uuid()))
+        .bind(partition_count)
+        .bind(partition_index)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to lock capped workflow tenants for worker '{worker_id}': {error}"
+            ))
+        })?;
+
+        // Each tenant contributes at most `limit` candidates, or fewer when its
+        // concurrency cap is nearly used, read in claim order from the partial
+        // index idx_workflow_execution_jobs_tenant_claim. Fair-share rounds are
+        // ranked over those bounded candidates only, so a claim poll costs
+        // tenants x limit rows instead of the whole backlog. The ranking only
+        // decides how many jobs each tenant gets; exactly that many rows are
+        // then locked per tenant with SKIP LOCKED, so concurrent claimers move
+        // past each other's rows and no row is locked without being leased.
+        let claim_rows = sqlx::query_as::<_, ClaimedWorkflowJobRow>(
+            r#"
+            WITH tenant_candidates AS (
+                SELECT
+                    candidates.id,
+                    candidates.tenant_id,
+                    candidates.priority,
+                    candidates.created_at
+                FROM tenants
+                CROSS JOIN LATERAL (
+                    SELECT count(*) AS leased_jobs
+                    FROM workflow_execution_jobs leased
+                    WHERE tenants.workflow_max_concurrent_jobs IS NOT NULL
+                      AND leased.tenant_id = tenants.id
+                      AND leased.status = 'leased'
+                      AND leased.lease_expires_at >= now()
+                ) tenant_leases
+                CROSS JOIN LATERAL (
+                    SELECT jobs.id, jobs.tenant_id, jobs.priority, jobs.created_at
+                    FROM workflow_execution_jobs jobs
+                    WHERE jobs.tenant_id = tenants.id
+                      AND jobs.status IN ('pending', 'leased')
+                      AND (
+                            (jobs.status = 'pending' AND jobs.available_at <= now())
+                            OR (jobs.status = 'leased' AND jobs.lease_expires_at < now())
+                          )
+                    ORDER BY jobs.priority DESC, jobs.created_at ASC
+                    LIMIT GREATEST(
+                        LEAST(
+                            $1::BIGINT,
+                            COALESCE(
+                                tenants.workflow_max_concurrent_jobs - tenant_leases.leased_jobs,
+                                $1::BIGINT
+                            )
+                        ),
+                        0
+                    )
+                ) candidates
+                WHERE ($6::UUID IS NULL OR tenants.id = $6)
                   AND (
                         $4::INT IS NULL
                         OR mod(
-                            (hashtext(tenant_id::text)::BIGINT & 2147483647),
+                            (hashtext(tenants.id::text)::BIGINT & 2147483647),
                             $4::BIGINT
                         ) = $5::BIGINT
                       )
-                ORDER BY created_at ASC
-                LIMIT $1
-                FOR UPDATE SKIP LOCKED
+                  AND (
+                        tenants.workflow_max_concurrent_jobs IS NULL
+                        OR tenants.id = ANY($7::UUID[])
+                      )
+            ),
+            ranked_jobs AS (
+                SELECT
+                    tenant_id,
+                    priority,
+                    created_at,
+                    row_number() OVER (
+                        PARTITION BY tenant_id, priority
+                        ORDER BY created_at ASC
+                    ) AS fair_share_round
+                FROM tenant_candidates
+            ),
+            tenant_quotas AS (
+                SELECT selected_jobs.tenant_id, count(*) AS quota
+                FROM (
+                    SELECT tenant_id
+                    FROM ranked_jobs
+                    ORDER BY priority DESC, fair_share_round ASC, created_at ASC
+                    LIMIT $1
+                ) selected_jobs
+                GROUP BY selected_jobs.tenant_id
+            ),
+            candidate_jobs AS (
+                SELECT locked_jobs.id
+                FROM tenant_quotas
+                CROSS JOIN LATERAL (
+                    SELECT jobs.id
+                    FROM workflow_execution_jobs jobs
+                    WHERE jobs.tenant_id = tenant_quotas.tenant_id
+                      AND jobs.status IN ('pending', 'leased')
+                      AND (
+                            (jobs.status = 'pending' AND jobs.available_at <= now())
+                            OR (jobs.status = 'leased' AND jobs.lease_expires_at < now())
+                          )
+                    ORDER BY jobs.priority DESC, jobs.created_at ASC
+                    LIMIT tenant_quotas.quota
+                    FOR UPDATE OF jobs SKIP LOCKED
+                ) locked_jobs
             ),
             leased_jobs AS (
                 UPDATE workflow_execution_jobs jobs
@@ -348,13 +483,20 @@ impl PostgresWorkflowRepository {
                 runs.workflow_version,
                 leased_jobs.lease_token,
                 runs.trigger_payload,
+                runs.continuation,
+                runs.trace_context,
+                runs.priority AS run_priority,
                 versions.logical_name,
                 versions.display_name,
                 versions.description,
                 versions.trigger_type,
                 versions.trigger_entity_logical_name,
+                versions.trigger_filters,
+                versions.trigger_changed_fields,
+                versions.trigger_view_logical_name,
                 versions.steps,
                 versions.max_attempts,
+                versions.priority,
                 definitions.lifecycle_state,
                 definitions.current_published_version
             FROM leased_jobs
@@ -368,7 +510,7 @@ impl PostgresWorkflowRepository {
                 ON versions.tenant_id = runs.tenant_id
                AND versions.logical_name = runs.workflow_logical_name
                AND versions.version = runs.workflow_version
-            ORDER BY runs.started_at ASC
+            ORDER BY runs.priority DESC, runs.started_at ASC
             "#,
         )
         .bind(i64::try_from(limit).map_err(|error| {
@@ -381,6 +523,7 @@ impl PostgresWorkflowRepository {
         .bind(partition_count)
         .bind(partition_index)
         .bind(tenant_filter.map(|value| value.as_uuid()))
+        .bind(claimable_capped_tenant_ids)
         .fetch_all(&mut *transaction)
         .await
         .map_err(|error| {
@@ -389,6 +532,32 @@ impl PostgresWorkflowRepository {
             ))
         })?;
 
+        let resumed_run_ids = claim_rows
+            .iter()
+            .filter(|row| row.continuation.is_some())
+            .map(|row| row.run_id)
+            .collect::<Vec<_>>();
+        if !resumed_run_ids.is_empty() {
+            sqlx::query(
+                r#"
+                UPDATE workflow_execution_runs
+                SET
+                    status = 'running',
+                    resume_at = NULL
+                WHERE id = ANY($1)
+                  AND status = 'waiting'
+                "#,
+            )
+            .bind(resumed_run_ids)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to resume waiting workflow runs for worker '{worker_id}': {error}"
+                ))
+            })?;
+        }
+
         transaction.commit().await.map_err(|error| {
             AppError::Internal(format!(
                 "failed to commit workflow job claim transaction: {error}"
@@ -512,6 +681,370 @@ impl PostgresWorkflowRepository {
         Ok(())
     }
 
+    pub(super) async fn suspend_run_job_impl(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        worker_id: &str,
+        lease_token: &str,
+        input: SuspendWorkflowRunInput,
+    ) -> AppResult<WorkflowRun> {
+        let job_uuid = uuid::Uuid::parse_str(job_id).map_err(|error| {
+            AppError::Validation(format!("invalid workflow job id '{job_id}': {error}"))
+        })?;
+        let run_uuid = uuid::Uuid::parse_str(input.run_id.as_str()).map_err(|error| {
+            AppError::Validation(format!(
+                "invalid workflow run id '{}': {error}",
+                input.run_id
+            ))
+        })?;
+        let resume_at = input.continuation.resume_at;
+        let continuation = serde_json::to_value(&input.continuation).map_err(|error| {
+            AppError::Internal(format!(
+                "failed to encode workflow run continuation for run '{}': {error}",
+                input.run_id
+            ))
+        })?;
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE workflow_execution_jobs
+            SET
+                status = 'pending',
+                leased_by = NULL,
+                lease_token = NULL,
+                lease_expires_at = NULL,
+                available_at = $5,
+                updated_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND leased_by = $3
+              AND lease_token = $4
+              AND status = 'leased'
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .bind(worker_id)
+        .bind(lease_token)
+        .bind(resume_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to defer workflow job '{job_id}' for tenant '{tenant_id}' worker '{worker_id}': {error}"
+            ))
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::Conflict(format!(
+                "workflow job '{job_id}' is not currently leased by worker '{worker_id}' with matching lease token"
+            )));
+        }
+
+        let row = sqlx::query_as::<_, WorkflowRunRow>(
+            r#"
+            UPDATE workflow_execution_runs
+            SET
+                status = 'waiting',
+                attempts = $3,
+                resume_at = $4,
+                continuation = $5
+            WHERE tenant_id = $1 AND id = $2
+            RETURNING
+                id,
+                workflow_logical_name,
+                workflow_version,
+                trigger_type,
+                trigger_entity_logical_name,
+                trigger_payload,
+                status,
+                attempts,
+                dead_letter_reason,
+                started_at,
+                finished_at,
+                resume_at,
+                parent_run_id,
+                priority
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(run_uuid)
+        .bind(input.attempts)
+        .bind(resume_at)
+        .bind(continuation)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to suspend workflow run '{}' for tenant '{tenant_id}': {error}",
+                input.run_id
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped workflow run suspension transaction: {error}"
+            ))
+        })?;
+
+        workflow_run_from_row(row)
+    }
+
+    pub(super) async fn list_stuck_jobs_impl(
+        &self,
+        tenant_id: TenantId,
+        limit: usize,
+    ) -> AppResult<Vec<WorkflowStuckJob>> {
+        let limit = i64::try_from(limit).map_err(|error| {
+            AppError::Validation(format!("invalid stuck workflow job limit: {error}"))
+        })?;
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let rows = sqlx::query_as::<_, WorkflowStuckJobRow>(
+            r#"
+            SELECT
+                jobs.id AS job_id,
+                jobs.run_id,
+                runs.workflow_logical_name,
+                runs.workflow_version,
+                jobs.leased_by,
+                jobs.lease_expires_at,
+                runs.attempts AS run_attempts,
+                jobs.last_error
+            FROM workflow_execution_jobs jobs
+            INNER JOIN workflow_execution_runs runs
+                ON runs.tenant_id = jobs.tenant_id
+               AND runs.id = jobs.run_id
+            WHERE jobs.tenant_id = $1
+              AND jobs.status = 'leased'
+              AND jobs.lease_expires_at < now()
+            ORDER BY jobs.lease_expires_at, jobs.id
+            LIMIT $2
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(limit)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to list stuck workflow jobs for tenant '{tenant_id}': {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped stuck workflow job transaction: {error}"
+            ))
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| WorkflowStuckJob {
+                job_id: row.job_id.to_string(),
+                run_id: row.run_id.to_string(),
+                workflow_logical_name: row.workflow_logical_name,
+                workflow_version: row.workflow_version,
+                leased_by: row.leased_by,
+                lease_expires_at: row.lease_expires_at,
+                run_attempts: row.run_attempts,
+                last_error: row.last_error,
+            })
+            .collect())
+    }
+
+    pub(super) async fn release_stuck_job_impl(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        reason: &str,
+    ) -> AppResult<bool> {
+        let job_uuid = uuid::Uuid::parse_str(job_id).map_err(|error| {
+            AppError::Validation(format!("invalid workflow job id '{job_id}': {error}"))
+        })?;
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE workflow_execution_jobs
+            SET
+                status = 'pending',
+                leased_by = NULL,
+                lease_token = NULL,
+                lease_expires_at = NULL,
+                available_at = now(),
+                last_error = $3,
+                updated_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND status = 'leased'
+              AND lease_expires_at < now()
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .bind(reason)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to release stuck workflow job '{job_id}' for tenant '{tenant_id}': {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped stuck workflow job release transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub(super) async fn release_job_impl(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        worker_id: &str,
+        lease_token: &str,
+        reason: &str,
+    ) -> AppResult<bool> {
+        let job_uuid = uuid::Uuid::parse_str(job_id).map_err(|error| {
+            AppError::Validation(format!("invalid workflow job id '{job_id}': {error}"))
+        })?;
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE workflow_execution_jobs
+            SET
+                status = 'pending',
+                leased_by = NULL,
+                lease_token = NULL,
+                lease_expires_at = NULL,
+                available_at = now(),
+                last_error = $5,
+                updated_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND leased_by = $3
+              AND lease_token = $4
+              AND status = 'leased'
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .bind(worker_id)
+        .bind(lease_token)
+        .bind(reason)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to release workflow job '{job_id}' for tenant '{tenant_id}' worker '{worker_id}': {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped workflow job release transaction: {error}"
+            ))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub(super) async fn fail_stuck_job_impl(
+        &self,
+        tenant_id: TenantId,
+        job_id: &str,
+        reason: &str,
+    ) -> AppResult<Option<WorkflowRun>> {
+        let job_uuid = uuid::Uuid::parse_str(job_id).map_err(|error| {
+            AppError::Validation(format!("invalid workflow job id '{job_id}': {error}"))
+        })?;
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let run_uuid = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+            UPDATE workflow_execution_jobs
+            SET
+                status = 'failed',
+                leased_by = NULL,
+                lease_token = NULL,
+                lease_expires_at = NULL,
+                last_error = $3,
+                updated_at = now()
+            WHERE tenant_id = $1
+              AND id = $2
+              AND status = 'leased'
+              AND lease_expires_at < now()
+            RETURNING run_id
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(job_uuid)
+        .bind(reason)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to fail stuck workflow job '{job_id}' for tenant '{tenant_id}': {error}"
+            ))
+        })?;
+        let Some(run_uuid) = run_uuid else {
+            return Ok(None);
+        };
+
+        let row = sqlx::query_as::<_, WorkflowRunRow>(
+            r#"
+            UPDATE workflow_execution_runs
+            SET
+                status = 'dead_lettered',
+                dead_letter_reason = $3,
+                finished_at = now(),
+                resume_at = NULL,
+                continuation = NULL
+            WHERE tenant_id = $1 AND id = $2
+            RETURNING
+                id,
+                workflow_logical_name,
+                workflow_version,
+                trigger_type,
+                trigger_entity_logical_name,
+                trigger_payload,
+                status,
+                attempts,
+                dead_letter_reason,
+                started_at,
+                finished_at,
+                resume_at,
+                parent_run_id,
+                priority
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(run_uuid)
+        .bind(reason)
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to dead-letter workflow run '{run_uuid}' for tenant '{tenant_id}': {error}"
+            ))
+        })?;
+
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped stuck workflow job failure transaction: {error}"
+            ))
+        })?;
+
+        workflow_run_from_row(row).map(Some)
+    }
+
     pub(super) async fn upsert_worker_heartbeat_impl(
         &self,
         worker_id: &str,
@@ -608,7 +1141,10 @@ impl PostgresWorkflowRepository {
         let queue_stats = sqlx::query_as::<_, WorkflowQueueStatsRow>(
             r#"
             SELECT
-                COALESCE(SUM(CASE WHEN status = 'pending' THEN 1 ELSE 0 END), 0) AS pending_jobs,
+                COALESCE(
+                    SUM(CASE WHEN status = 'pending' AND available_at <= now() THEN 1 ELSE 0 END),
+                    0
+                ) AS pending_jobs,
                 COALESCE(SUM(CASE WHEN status = 'leased' THEN 1 ELSE 0 END), 0) AS leased_jobs,
                 COALESCE(SUM(CASE WHEN status = 'completed' THEN 1 ELSE 0 END), 0) AS completed_jobs,
                 COALESCE(SUM(CASE WHEN status = 'failed' THEN 1 ELSE 0 END), 0) AS failed_jobs,
@@ -629,10 +1165,12 @@ impl PostgresWorkflowRepository {
                         $1::BIGINT
                     ) = $2::BIGINT
                   )
+              AND ($3::UUID IS NULL OR tenant_id = $3)
             "#,
         )
         .bind(partition_count)
         .bind(partition_index)
+        .bind(query.tenant_id.map(|tenant_id| tenant_id.as_uuid()))
         .fetch_one(&mut *transaction)
         .await
         .map_err(|error| {
@@ -682,8 +1220,19 @@ impl PostgresWorkflowRepository {
 fn workflow_scheduled_trigger_from_row(
     row: WorkflowScheduledTriggerRow,
 ) -> AppResult<WorkflowScheduledTrigger> {
+    let kind = match row.trigger_type.as_str() {
+        "schedule_tick" => WorkflowScheduleKind::ScheduleTick,
+        "scheduled" => WorkflowScheduleKind::Cron,
+        other => {
+            return Err(AppError::Internal(format!(
+                "unexpected workflow schedule trigger type '{other}'"
+            )));
+        }
+    };
+
     Ok(WorkflowScheduledTrigger {
         tenant_id: TenantId::from_uuid(row.tenant_id),
+        kind,
         schedule_key: row.schedule_key,
     })
 }
@@ -6,6 +6,16 @@ impl PostgresWorkflowRepository {
         tenant_id: TenantId,
         input: CreateWorkflowRunInput,
     ) -> AppResult<WorkflowRun> {
+        let parent_run_id = input
+            .parent_run_id
+            .as_deref()
+            .map(|run_id| {
+                uuid::Uuid::parse_str(run_id).map_err(|error| {
+                    AppError::Validation(format!("invalid workflow run id '{run_id}': {error}"))
+                })
+            })
+            .transpose()?;
+        let workflow_logical_name = input.workflow_logical_name.clone();
         let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
         let row = sqlx::query_as::<_, WorkflowRunRow>(
             r#"
@@ -18,9 +28,13 @@ impl PostgresWorkflowRepository {
                 trigger_payload,
                 status,
                 attempts,
-                started_at
+                started_at,
+                parent_run_id,
+                trace_context,
+                priority,
+                idempotency_key
             )
-            VALUES ($1, $2, $3, $4, $5, $6, 'running', 0, now())
+            VALUES ($1, $2, $3, $4, $5, $6, 'running', 0, now(), $7, $8, $9, $10)
             RETURNING
                 id,
                 workflow_logical_name,
@@ -32,7 +46,10 @@ impl PostgresWorkflowRepository {
                 attempts,
                 dead_letter_reason,
                 started_at,
-                finished_at
+                finished_at,
+                resume_at,
+                parent_run_id,
+                priority
             "#,
         )
         .bind(tenant_id.as_uuid())
@@ -41,9 +58,22 @@ impl PostgresWorkflowRepository {
         .bind(input.trigger_type)
         .bind(input.trigger_entity_logical_name)
         .bind(input.trigger_payload)
+        .bind(parent_run_id)
+        .bind(input.trace_context)
+        .bind(input.priority.rank())
+        .bind(input.idempotency_key)
         .fetch_one(&mut *transaction)
         .await
         .map_err(|error| {
+            if let sqlx::Error::Database(database_error) = &error
+                && database_error.code().as_deref() == Some("23505")
+            {
+                return AppError::Conflict(format!(
+                    "workflow '{}' already has a run for this idempotency key",
+                    workflow_logical_name
+                ));
+            }
+
             AppError::Internal(format!(
                 "failed to create workflow run for tenant '{}': {error}",
                 tenant_id
@@ -131,7 +161,9 @@ impl PostgresWorkflowRepository {
                 status = $3,
                 attempts = $4,
                 dead_letter_reason = $5,
-                finished_at = now()
+                finished_at = now(),
+                resume_at = NULL,
+                continuation = NULL
             WHERE tenant_id = $1 AND id = $2
             RETURNING
                 id,
@@ -144,7 +176,10 @@ impl PostgresWorkflowRepository {
                 attempts,
                 dead_letter_reason,
                 started_at,
-                finished_at
+                finished_at,
+                resume_at,
+                parent_run_id,
+                priority
             "#,
         )
         .bind(tenant_id.as_uuid())
@@ -188,7 +223,10 @@ impl PostgresWorkflowRepository {
                 attempts,
                 dead_letter_reason,
                 started_at,
-                finished_at
+                finished_at,
+                resume_at,
+                parent_run_id,
+                priority
             FROM workflow_execution_runs
             WHERE tenant_id = $1
               AND ($2::TEXT IS NULL OR workflow_logical_name = $2)
@@ -244,7 +282,10 @@ impl PostgresWorkflowRepository {
                 attempts,
                 dead_letter_reason,
                 started_at,
-                finished_at
+                finished_at,
+                resume_at,
+                parent_run_id,
+                priority
             FROM workflow_execution_runs
             WHERE tenant_id = $1 AND id = $2
             "#,
@@ -268,6 +309,57 @@ impl PostgresWorkflowRepository {
         row.map(workflow_run_from_row).transpose()
     }
 
+    pub(super) async fn find_run_by_idempotency_key_impl(
+        &self,
+        tenant_id: TenantId,
+        workflow_logical_name: &str,
+        idempotency_key: &str,
+    ) -> AppResult<Option<WorkflowRun>> {
+        let mut transaction = begin_tenant_transaction(&self.pool, tenant_id).await?;
+
+        let row = sqlx::query_as::<_, WorkflowRunRow>(
+            r#"
+            SELECT
+                id,
+                workflow_logical_name,
+                workflow_version,
+                trigger_type,
+                trigger_entity_logical_name,
+                trigger_payload,
+                status,
+                attempts,
+                dead_letter_reason,
+                started_at,
+                finished_at,
+                resume_at,
+                parent_run_id,
+                priority
+            FROM workflow_execution_runs
+            WHERE tenant_id = $1
+              AND workflow_logical_name = $2
+              AND idempotency_key = $3
+            "#,
+        )
+        .bind(tenant_id.as_uuid())
+        .bind(workflow_logical_name)
+        .bind(idempotency_key)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| {
+            AppError::Internal(format!(
+                "failed to find workflow run by idempotency key for workflow '{}' in tenant '{}': {error}",
+                workflow_logical_name, tenant_id
+            ))
+        })?;
+        transaction.commit().await.map_err(|error| {
+            AppError::Internal(format!(
+                "failed to commit tenant-scoped workflow run find transaction: {error}"
+            ))
+        })?;
+
+        row.map(workflow_run_from_row).transpose()
+    }
+
     pub(super) async fn list_run_attempts_impl(
         &self,
         tenant_id: TenantId,
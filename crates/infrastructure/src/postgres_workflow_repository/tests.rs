@@ -1,10 +1,15 @@
 use chrono::Utc;
 use qryvanta_application::{
-    CreateWorkflowRunInput, WorkflowQueueStatsQuery, WorkflowRepository, WorkflowRunAttempt,
-    WorkflowRunAttemptStatus,
+    CompleteWorkflowRunInput, CreateWorkflowRunInput, NewWorkflowBulkExecution,
+    NewWorkflowInboundWebhook, WorkflowClaimPartition, WorkflowCredentialRepository,
+    WorkflowInboundWebhookRepository, WorkflowQueueStatsQuery, WorkflowRepository,
+    WorkflowRunAttempt, WorkflowRunAttemptStatus, WorkflowRunStatus,
+};
+use qryvanta_core::{AppError, TenantId};
+use qryvanta_domain::{
+    WorkflowCredential, WorkflowCredentialAuth, WorkflowDefinition, WorkflowDefinitionInput,
+    WorkflowRunPriority, WorkflowStep, WorkflowTrigger,
 };
-use qryvanta_core::TenantId;
-use qryvanta_domain::{WorkflowDefinition, WorkflowDefinitionInput, WorkflowStep, WorkflowTrigger};
 use serde_json::json;
 use sqlx::PgPool;
 use sqlx::migrate::Migrator;
@@ -163,6 +168,43 @@ async fn workflow_repository_persists_expanded_trigger_types() {
     );
 }
 
+#[tokio::test]
+async fn workflow_repository_persists_trigger_scope_in_published_versions() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresWorkflowRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Workflow Trigger Scope Tenant").await;
+
+    let trigger = WorkflowTrigger::RuntimeRecordUpdated {
+        entity_logical_name: "contact".to_owned(),
+    };
+    let workflow =
+        workflow_with_trigger("contact_scoped_ops", "Contact Scoped Ops", trigger.clone())
+            .with_trigger_scope(
+                vec!["status".to_owned(), "owner".to_owned()],
+                Some("active_contacts".to_owned()),
+            )
+            .unwrap_or_else(|_| unreachable!());
+    let _ = save_and_publish_workflow(&repository, tenant_id, workflow).await;
+
+    let enabled = repository
+        .list_enabled_workflows_for_trigger(tenant_id, &trigger)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list enabled workflows: {error}"));
+    assert_eq!(enabled.len(), 1);
+    assert_eq!(
+        enabled[0].trigger_changed_fields(),
+        &["status".to_owned(), "owner".to_owned()]
+    );
+    assert_eq!(
+        enabled[0].trigger_view_logical_name(),
+        Some("active_contacts")
+    );
+}
+
 #[tokio::test]
 async fn workflow_repository_reads_are_tenant_scoped() {
     let Some(pool) = test_pool().await else {
@@ -187,6 +229,10 @@ async fn workflow_repository_reads_are_tenant_scoped() {
                 trigger_type: "manual".to_owned(),
                 trigger_entity_logical_name: None,
                 trigger_payload: json!({"source": "test"}),
+                parent_run_id: None,
+                trace_context: None,
+                priority: WorkflowRunPriority::Normal,
+                idempotency_key: None,
             },
         )
         .await;
@@ -261,6 +307,12 @@ async fn workflow_job_claims_use_operational_bypass_across_tenants() {
                 trigger_type: "manual".to_owned(),
                 trigger_entity_logical_name: None,
                 trigger_payload: json!({"tenant": "left"}),
+                parent_run_id: None,
+                trace_context: Some(
+                    "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_owned(),
+                ),
+                priority: WorkflowRunPriority::Normal,
+                idempotency_key: None,
             },
         )
         .await
@@ -274,6 +326,10 @@ async fn workflow_job_claims_use_operational_bypass_across_tenants() {
                 trigger_type: "manual".to_owned(),
                 trigger_entity_logical_name: None,
                 trigger_payload: json!({"tenant": "right"}),
+                parent_run_id: None,
+                trace_context: None,
+                priority: WorkflowRunPriority::Normal,
+                idempotency_key: None,
             },
         )
         .await
@@ -296,6 +352,14 @@ async fn workflow_job_claims_use_operational_bypass_across_tenants() {
     assert!(claimed.is_ok());
     let mut claimed = claimed.unwrap_or_default();
     claimed.sort_by_key(|job| job.tenant_id.to_string());
+    let left_job = claimed
+        .iter()
+        .find(|job| job.run_id == left_run.run_id)
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(
+        left_job.trace_context.as_deref(),
+        Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01")
+    );
     let claimed_tenant_ids: Vec<TenantId> = claimed.into_iter().map(|job| job.tenant_id).collect();
 
     assert!(claimed_tenant_ids.len() >= 2);
@@ -306,6 +370,7 @@ async fn workflow_job_claims_use_operational_bypass_across_tenants() {
         .queue_stats(WorkflowQueueStatsQuery {
             active_window_seconds: 120,
             partition: None,
+            tenant_id: None,
         })
         .await;
     assert!(queue_stats.is_ok());
@@ -338,6 +403,10 @@ async fn workflow_job_claims_reclaim_expired_leases_with_new_fencing_tokens() {
                 trigger_type: "manual".to_owned(),
                 trigger_entity_logical_name: None,
                 trigger_payload: json!({"source": "lease-reclaim"}),
+                parent_run_id: None,
+                trace_context: None,
+                priority: WorkflowRunPriority::Normal,
+                idempotency_key: None,
             },
         )
         .await
@@ -373,10 +442,12 @@ async fn workflow_job_claims_reclaim_expired_leases_with_new_fencing_tokens() {
         .queue_stats(WorkflowQueueStatsQuery {
             active_window_seconds: 120,
             partition: None,
+            tenant_id: Some(tenant_id),
         })
         .await
         .unwrap_or_else(|_| unreachable!());
-    assert!(queue_stats.expired_leases >= 1);
+    assert_eq!(queue_stats.expired_leases, 1);
+    assert_eq!(queue_stats.leased_jobs, 1);
 
     let second_claim = repository
         .claim_jobs("worker-2", 1, 60, None, Some(tenant_id))
@@ -489,3 +560,897 @@ async fn workflow_schedule_ticks_reclaim_expired_leases_with_new_fencing_tokens(
         .await;
     assert!(recovered_complete.is_ok());
 }
+
+#[tokio::test]
+async fn workflow_credentials_upsert_and_stay_tenant_scoped() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresWorkflowRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Workflow Credential Tenant").await;
+    ensure_tenant(&pool, other_tenant_id, "Workflow Credential Other Tenant").await;
+
+    let credential = |hosts: Vec<String>| {
+        WorkflowCredential::new(
+            "erp",
+            "ERP",
+            WorkflowCredentialAuth::OAuth2ClientCredentials {
+                token_url: "https://auth.example.com/token".to_owned(),
+                client_id: "qryvanta".to_owned(),
+                client_secret_ref: "op://vault/erp/client-secret".to_owned(),
+                scope: Some("orders.write".to_owned()),
+            },
+            hosts,
+        )
+        .unwrap_or_else(|_| unreachable!())
+    };
+
+    repository
+        .save_workflow_credential(
+            tenant_id,
+            credential(vec!["api.example.com".to_owned()]),
+            "alice",
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to save workflow credential: {error}"));
+    let updated = repository
+        .save_workflow_credential(
+            tenant_id,
+            credential(vec![
+                "api.example.com".to_owned(),
+                "*.erp.example.com".to_owned(),
+            ]),
+            "bob",
+        )
+        .await
+        .unwrap_or_else(|error| panic!("failed to update workflow credential: {error}"));
+    assert_eq!(updated.updated_by_subject, "bob");
+    assert_eq!(updated.credential.allowed_hosts().len(), 2);
+
+    let listed = repository
+        .list_workflow_credentials(tenant_id)
+        .await
+        .unwrap_or_else(|error| panic!("failed to list workflow credentials: {error}"));
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].credential, updated.credential);
+
+    assert!(
+        repository
+            .find_workflow_credential(other_tenant_id, "erp")
+            .await
+            .unwrap_or_else(|error| panic!("failed to find workflow credential: {error}"))
+            .is_none()
+    );
+    assert!(
+        !repository
+            .delete_workflow_credential(other_tenant_id, "erp")
+            .await
+            .unwrap_or_else(|error| panic!("failed to delete workflow credential: {error}"))
+    );
+    assert!(
+        repository
+            .delete_workflow_credential(tenant_id, "erp")
+            .await
+            .unwrap_or_else(|error| panic!("failed to delete workflow credential: {error}"))
+    );
+    assert!(
+        repository
+            .find_workflow_credential(tenant_id, "erp")
+            .await
+            .unwrap_or_else(|error| panic!("failed to find workflow credential: {error}"))
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn inbound_webhooks_rotate_record_deliveries_and_stay_tenant_scoped() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresWorkflowRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Inbound Webhook Tenant").await;
+    ensure_tenant(&pool, other_tenant_id, "Inbound Webhook Other Tenant").await;
+    let _ = save_and_publish_workflow(
+        &repository,
+        tenant_id,
+        workflow("order_intake", "Order Intake"),
+    )
+    .await;
+
+    let new_webhook = |token_hash: &str| NewWorkflowInboundWebhook {
+        workflow_logical_name: "order_intake".to_owned(),
+        payload_schema: Some(json!({"type": "object"})),
+        token_hash: token_hash.to_owned(),
+        signing_secret_ciphertext: vec![1, 2, 3],
+        created_by_subject: "maker".to_owned(),
+    };
+    let saved = repository
+        .save_inbound_webhook(tenant_id, new_webhook("first-hash"))
+        .await
+        .unwrap_or_else(|error| panic!("failed to save inbound webhook: {error}"));
+    assert_eq!(saved.payload_schema, Some(json!({"type": "object"})));
+    assert_eq!(saved.delivery_count, 0);
+
+    assert!(
+        repository
+            .record_inbound_webhook_delivery(tenant_id, "order_intake", Utc::now())
+            .await
+            .is_ok()
+    );
+    let credentials = repository
+        .find_inbound_webhook_by_token_hash(tenant_id, "first-hash")
+        .await
+        .unwrap_or_else(|error| panic!("failed to find inbound webhook: {error}"))
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(credentials.signing_secret_ciphertext, vec![1, 2, 3]);
+    assert_eq!(credentials.webhook.delivery_count, 1);
+    assert!(credentials.webhook.last_received_at.is_some());
+
+    let other_tenant_lookup = repository
+        .find_inbound_webhook_by_token_hash(other_tenant_id, "first-hash")
+        .await;
+    assert!(matches!(other_tenant_lookup, Ok(None)));
+
+    let rotated = repository
+        .save_inbound_webhook(tenant_id, new_webhook("second-hash"))
+        .await
+        .unwrap_or_else(|error| panic!("failed to rotate inbound webhook: {error}"));
+    assert_eq!(rotated.delivery_count, 0);
+    assert!(matches!(
+        repository
+            .find_inbound_webhook_by_token_hash(tenant_id, "first-hash")
+            .await,
+        Ok(None)
+    ));
+
+    assert!(matches!(
+        repository
+            .delete_inbound_webhook(tenant_id, "order_intake")
+            .await,
+        Ok(true)
+    ));
+    assert!(matches!(
+        repository
+            .find_inbound_webhook(tenant_id, "order_intake")
+            .await,
+        Ok(None)
+    ));
+    assert!(matches!(
+        repository
+            .delete_inbound_webhook(tenant_id, "order_intake")
+            .await,
+        Ok(false)
+    ));
+}
+
+#[tokio::test]
+async fn bulk_executions_count_their_runs_and_stay_tenant_scoped() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresWorkflowRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    let other_tenant = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Workflow Bulk Tenant").await;
+    ensure_tenant(&pool, other_tenant, "Workflow Bulk Other Tenant").await;
+
+    let workflow =
+        save_and_publish_workflow(&repository, tenant_id, workflow("bulk_ops", "Bulk Ops")).await;
+    let execution = repository
+        .create_bulk_execution(
+            tenant_id,
+            NewWorkflowBulkExecution {
+                workflow_logical_name: "bulk_ops".to_owned(),
+                entity_logical_name: "contact".to_owned(),
+                view_logical_name: Some("active_contacts".to_owned()),
+                total_records: 2,
+                requested_by_subject: "maker".to_owned(),
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let mut run_ids = Vec::new();
+    for record_id in ["contact-1", "contact-2"] {
+        let run = repository
+            .create_run(
+                tenant_id,
+                CreateWorkflowRunInput {
+                    workflow_logical_name: "bulk_ops".to_owned(),
+                    workflow_version: workflow.published_version().unwrap_or_default(),
+                    trigger_type: "manual".to_owned(),
+                    trigger_entity_logical_name: None,
+                    trigger_payload: json!({
+                        "record_id": record_id,
+                        "bulk_execution_id": execution.bulk_execution_id,
+                    }),
+                    parent_run_id: None,
+                    trace_context: None,
+                    priority: WorkflowRunPriority::Normal,
+                    idempotency_key: None,
+                },
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        run_ids.push(run.run_id);
+    }
+    repository
+        .complete_run(
+            tenant_id,
+            CompleteWorkflowRunInput {
+                run_id: run_ids[0].clone(),
+                status: WorkflowRunStatus::Succeeded,
+                attempts: 1,
+                dead_letter_reason: None,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+
+    let found = repository
+        .find_bulk_execution(tenant_id, execution.bulk_execution_id.as_str())
+        .await
+        .unwrap_or_default();
+    assert_eq!(found, Some(execution.clone()));
+
+    let counts = repository
+        .bulk_execution_run_counts(tenant_id, execution.bulk_execution_id.as_str())
+        .await
+        .unwrap_or_default();
+    assert_eq!(counts.succeeded, 1);
+    assert_eq!(counts.pending, 1);
+    assert_eq!(counts.dead_lettered, 0);
+    assert!(counts.last_finished_at.is_some());
+
+    let other_tenant_lookup = repository
+        .find_bulk_execution(other_tenant, execution.bulk_execution_id.as_str())
+        .await
+        .unwrap_or_default();
+    assert_eq!(other_tenant_lookup, None);
+    let malformed_lookup = repository
+        .find_bulk_execution(tenant_id, "not-a-uuid")
+        .await
+        .unwrap_or_default();
+    assert_eq!(malformed_lookup, None);
+}
+
+#[tokio::test]
+async fn stuck_jobs_are_listed_released_and_failed_only_after_lease_expiry() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresWorkflowRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Workflow Stuck Job Tenant").await;
+
+    let workflow = save_and_publish_workflow(
+        &repository,
+        tenant_id,
+        workflow("stuck_job_recovery", "Stuck Job Recovery"),
+    )
+    .await;
+    let run = repository
+        .create_run(
+            tenant_id,
+            CreateWorkflowRunInput {
+                workflow_logical_name: "stuck_job_recovery".to_owned(),
+                workflow_version: workflow.published_version().unwrap_or_default(),
+                trigger_type: "manual".to_owned(),
+                trigger_entity_logical_name: None,
+                trigger_payload: json!({"source": "stuck-job"}),
+                parent_run_id: None,
+                trace_context: None,
+                priority: WorkflowRunPriority::Normal,
+                idempotency_key: None,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(
+        repository
+            .enqueue_run_job(tenant_id, run.run_id.as_str())
+            .await
+            .is_ok()
+    );
+
+    let claim = repository
+        .claim_jobs("worker-crashed", 1, 60, None, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(claim.len(), 1);
+    let job_id = claim[0].job_id.clone();
+
+    let active_release = repository
+        .release_stuck_job(tenant_id, job_id.as_str(), "operator release")
+        .await;
+    assert!(matches!(active_release, Ok(false)));
+
+    let expire_lease = || async {
+        sqlx::query(
+            r#"
+            UPDATE workflow_execution_jobs
+            SET lease_expires_at = now() - interval '5 minutes'
+            WHERE id = $1
+            "#,
+        )
+        .bind(Uuid::parse_str(job_id.as_str()).unwrap_or_else(|_| unreachable!()))
+        .execute(&pool)
+        .await
+        .is_ok()
+    };
+    assert!(expire_lease().await);
+
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, other_tenant_id, "Workflow Stuck Job Other Tenant").await;
+    assert!(
+        repository
+            .list_stuck_jobs(other_tenant_id, 10)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+
+    let stuck = repository
+        .list_stuck_jobs(tenant_id, 10)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(stuck.len(), 1);
+    assert_eq!(stuck[0].job_id, job_id);
+    assert_eq!(stuck[0].run_id, run.run_id);
+    assert_eq!(stuck[0].leased_by.as_deref(), Some("worker-crashed"));
+
+    let released = repository
+        .release_stuck_job(tenant_id, job_id.as_str(), "operator release")
+        .await;
+    assert!(matches!(released, Ok(true)));
+
+    let reclaimed = repository
+        .claim_jobs("worker-crashed", 1, 60, None, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(reclaimed.len(), 1);
+    assert!(expire_lease().await);
+
+    let failed_run = repository
+        .fail_stuck_job(tenant_id, job_id.as_str(), "poison payload")
+        .await
+        .unwrap_or_else(|_| unreachable!())
+        .unwrap_or_else(|| unreachable!());
+    assert_eq!(failed_run.status, WorkflowRunStatus::DeadLettered);
+    assert_eq!(
+        failed_run.dead_letter_reason.as_deref(),
+        Some("poison payload")
+    );
+    assert!(
+        repository
+            .list_stuck_jobs(tenant_id, 10)
+            .await
+            .unwrap_or_else(|_| unreachable!())
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn leased_jobs_are_released_only_by_their_lease_holder() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresWorkflowRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Workflow Job Release Tenant").await;
+
+    let workflow = save_and_publish_workflow(
+        &repository,
+        tenant_id,
+        workflow("job_release_drain", "Job Release Drain"),
+    )
+    .await;
+    let run = repository
+        .create_run(
+            tenant_id,
+            CreateWorkflowRunInput {
+                workflow_logical_name: "job_release_drain".to_owned(),
+                workflow_version: workflow.published_version().unwrap_or_default(),
+                trigger_type: "manual".to_owned(),
+                trigger_entity_logical_name: None,
+                trigger_payload: json!({"source": "job-release"}),
+                parent_run_id: None,
+                trace_context: None,
+                priority: WorkflowRunPriority::Normal,
+                idempotency_key: None,
+            },
+        )
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(
+        repository
+            .enqueue_run_job(tenant_id, run.run_id.as_str())
+            .await
+            .is_ok()
+    );
+
+    let claim = repository
+        .claim_jobs("worker-draining", 1, 300, None, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(claim.len(), 1);
+    let job_id = claim[0].job_id.clone();
+    let lease_token = claim[0].lease_token.clone();
+
+    let other_worker_release = repository
+        .release_job(
+            tenant_id,
+            job_id.as_str(),
+            "worker-other",
+            lease_token.as_str(),
+            "worker shutdown",
+        )
+        .await;
+    assert!(matches!(other_worker_release, Ok(false)));
+
+    let stale_token_release = repository
+        .release_job(
+            tenant_id,
+            job_id.as_str(),
+            "worker-draining",
+            "stale-token",
+            "worker shutdown",
+        )
+        .await;
+    assert!(matches!(stale_token_release, Ok(false)));
+
+    let released = repository
+        .release_job(
+            tenant_id,
+            job_id.as_str(),
+            "worker-draining",
+            lease_token.as_str(),
+            "worker shutdown",
+        )
+        .await;
+    assert!(matches!(released, Ok(true)));
+
+    let reclaimed = repository
+        .claim_jobs("worker-successor", 1, 300, None, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(reclaimed.len(), 1);
+    assert_eq!(reclaimed[0].job_id, job_id);
+    assert_ne!(reclaimed[0].lease_token, lease_token);
+
+    let stale_release = repository
+        .release_job(
+            tenant_id,
+            job_id.as_str(),
+            "worker-draining",
+            lease_token.as_str(),
+            "worker shutdown",
+        )
+        .await;
+    assert!(matches!(stale_release, Ok(false)));
+}
+
+#[tokio::test]
+async fn concurrent_worker_claims_never_lease_the_same_job() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresWorkflowRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Workflow Concurrent Claim Tenant").await;
+
+    let workflow = save_and_publish_workflow(
+        &repository,
+        tenant_id,
+        workflow("concurrent_claims", "Concurrent Claims"),
+    )
+    .await;
+    for index in 0..6 {
+        let run = repository
+            .create_run(
+                tenant_id,
+                CreateWorkflowRunInput {
+                    workflow_logical_name: "concurrent_claims".to_owned(),
+                    workflow_version: workflow.published_version().unwrap_or_default(),
+                    trigger_type: "manual".to_owned(),
+                    trigger_entity_logical_name: None,
+                    trigger_payload: json!({"index": index}),
+                    parent_run_id: None,
+                    trace_context: None,
+                    priority: WorkflowRunPriority::Normal,
+                    idempotency_key: None,
+                },
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert!(
+            repository
+                .enqueue_run_job(tenant_id, run.run_id.as_str())
+                .await
+                .is_ok()
+        );
+    }
+
+    let (first_claim, second_claim, third_claim) = tokio::join!(
+        repository.claim_jobs("worker-db-1", 4, 60, None, Some(tenant_id)),
+        repository.claim_jobs("worker-db-2", 4, 60, None, Some(tenant_id)),
+        repository.claim_jobs("worker-db-3", 4, 60, None, Some(tenant_id)),
+    );
+    let mut claimed_job_ids = [first_claim, second_claim, third_claim]
+        .into_iter()
+        .flat_map(|claim| claim.unwrap_or_else(|_| unreachable!()))
+        .map(|job| job.job_id)
+        .collect::<Vec<_>>();
+    assert_eq!(claimed_job_ids.len(), 6);
+    claimed_job_ids.sort();
+    claimed_job_ids.dedup();
+    assert_eq!(claimed_job_ids.len(), 6);
+}
+
+#[tokio::test]
+async fn claims_lease_higher_priority_jobs_before_earlier_jobs() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresWorkflowRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Workflow Priority Claim Tenant").await;
+
+    let bulk_workflow = save_and_publish_workflow(
+        &repository,
+        tenant_id,
+        workflow("bulk_backfill", "Bulk Backfill").with_priority(WorkflowRunPriority::Low),
+    )
+    .await;
+    let urgent_workflow = save_and_publish_workflow(
+        &repository,
+        tenant_id,
+        workflow("urgent_escalation", "Urgent Escalation")
+            .with_priority(WorkflowRunPriority::Urgent),
+    )
+    .await;
+    assert_eq!(bulk_workflow.priority(), WorkflowRunPriority::Low);
+    assert_eq!(urgent_workflow.priority(), WorkflowRunPriority::Urgent);
+
+    let mut run_ids = Vec::new();
+    for workflow in [&bulk_workflow, &bulk_workflow, &urgent_workflow] {
+        let run = repository
+            .create_run(
+                tenant_id,
+                CreateWorkflowRunInput {
+                    workflow_logical_name: workflow.logical_name().as_str().to_owned(),
+                    workflow_version: workflow.published_version().unwrap_or_default(),
+                    trigger_type: "manual".to_owned(),
+                    trigger_entity_logical_name: None,
+                    trigger_payload: json!({}),
+                    parent_run_id: None,
+                    trace_context: None,
+                    priority: workflow.priority(),
+                    idempotency_key: None,
+                },
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(run.priority, workflow.priority());
+        assert!(
+            repository
+                .enqueue_run_job(tenant_id, run.run_id.as_str())
+                .await
+                .is_ok()
+        );
+        run_ids.push(run.run_id);
+    }
+
+    let claimed = repository
+        .claim_jobs("worker-priority", 2, 60, None, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(claimed.len(), 2);
+    assert_eq!(claimed[0].run_id, run_ids[2]);
+    assert_eq!(claimed[0].priority, WorkflowRunPriority::Urgent);
+    assert_eq!(claimed[0].workflow.priority(), WorkflowRunPriority::Urgent);
+    assert_eq!(claimed[1].run_id, run_ids[0]);
+    assert_eq!(claimed[1].priority, WorkflowRunPriority::Low);
+}
+
+async fn enqueue_manual_runs(
+    repository: &PostgresWorkflowRepository,
+    tenant_id: TenantId,
+    workflow: &WorkflowDefinition,
+    count: usize,
+) -> Vec<String> {
+    let mut run_ids = Vec::new();
+    for _ in 0..count {
+        let run = repository
+            .create_run(
+                tenant_id,
+                CreateWorkflowRunInput {
+                    workflow_logical_name: workflow.logical_name().as_str().to_owned(),
+                    workflow_version: workflow.published_version().unwrap_or_default(),
+                    trigger_type: "manual".to_owned(),
+                    trigger_entity_logical_name: None,
+                    trigger_payload: json!({}),
+                    parent_run_id: None,
+                    trace_context: None,
+                    priority: workflow.priority(),
+                    idempotency_key: None,
+                },
+            )
+            .await
+            .unwrap_or_else(|_| unreachable!());
+        assert!(
+            repository
+                .enqueue_run_job(tenant_id, run.run_id.as_str())
+                .await
+                .is_ok()
+        );
+        run_ids.push(run.run_id);
+    }
+
+    run_ids
+}
+
+#[tokio::test]
+async fn claims_respect_tenant_concurrency_caps() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresWorkflowRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Workflow Concurrency Cap Tenant").await;
+    let cap_update =
+        sqlx::query("UPDATE tenants SET workflow_max_concurrent_jobs = 2 WHERE id = $1")
+            .bind(tenant_id.as_uuid())
+            .execute(&pool)
+            .await;
+    assert!(cap_update.is_ok());
+
+    let capped_workflow = save_and_publish_workflow(
+        &repository,
+        tenant_id,
+        workflow("capped_sync", "Capped Sync"),
+    )
+    .await;
+    let run_ids = enqueue_manual_runs(&repository, tenant_id, &capped_workflow, 4).await;
+
+    let claimed = repository
+        .claim_jobs("worker-cap-a", 10, 60, None, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(claimed.len(), 2);
+    assert_eq!(claimed[0].run_id, run_ids[0]);
+    assert_eq!(claimed[1].run_id, run_ids[1]);
+
+    let saturated = repository
+        .claim_jobs("worker-cap-b", 10, 60, None, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(saturated.is_empty());
+
+    let released = repository
+        .complete_job(
+            tenant_id,
+            claimed[0].job_id.as_str(),
+            "worker-cap-a",
+            claimed[0].lease_token.as_str(),
+        )
+        .await;
+    assert!(released.is_ok());
+
+    let refilled = repository
+        .claim_jobs("worker-cap-b", 10, 60, None, Some(tenant_id))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(refilled.len(), 1);
+    assert_eq!(refilled[0].run_id, run_ids[2]);
+}
+
+#[tokio::test]
+async fn concurrent_claims_never_exceed_tenant_concurrency_caps() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresWorkflowRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Workflow Concurrent Cap Tenant").await;
+    let cap_update =
+        sqlx::query("UPDATE tenants SET workflow_max_concurrent_jobs = 2 WHERE id = $1")
+            .bind(tenant_id.as_uuid())
+            .execute(&pool)
+            .await;
+    assert!(cap_update.is_ok());
+
+    let capped_workflow = save_and_publish_workflow(
+        &repository,
+        tenant_id,
+        workflow("concurrent_capped_sync", "Concurrent Capped Sync"),
+    )
+    .await;
+    enqueue_manual_runs(&repository, tenant_id, &capped_workflow, 20).await;
+
+    for _ in 0..10 {
+        let (first_claim, second_claim) = tokio::join!(
+            repository.claim_jobs("worker-cap-a", 10, 60, None, Some(tenant_id)),
+            repository.claim_jobs("worker-cap-b", 10, 60, None, Some(tenant_id)),
+        );
+        let first_claim = first_claim.unwrap_or_else(|_| unreachable!());
+        let second_claim = second_claim.unwrap_or_else(|_| unreachable!());
+
+        let leased_jobs = sqlx::query_scalar::<_, i64>(
+            "SELECT count(*) FROM workflow_execution_jobs WHERE tenant_id = $1 AND status = 'leased'",
+        )
+        .bind(tenant_id.as_uuid())
+        .fetch_one(&pool)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+        assert!(
+            leased_jobs <= 2,
+            "{leased_jobs} jobs leased under a cap of 2"
+        );
+        assert!(first_claim.len() + second_claim.len() <= 2);
+
+        for (worker_id, job) in first_claim
+            .iter()
+            .map(|job| ("worker-cap-a", job))
+            .chain(second_claim.iter().map(|job| ("worker-cap-b", job)))
+        {
+            let completed = repository
+                .complete_job(
+                    tenant_id,
+                    job.job_id.as_str(),
+                    worker_id,
+                    job.lease_token.as_str(),
+                )
+                .await;
+            assert!(completed.is_ok());
+        }
+    }
+}
+
+#[tokio::test]
+async fn claims_share_capacity_round_robin_across_tenants() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresWorkflowRepository::new(pool.clone());
+    let busy_tenant_id = TenantId::new();
+    ensure_tenant(&pool, busy_tenant_id, "Workflow Fair Share Busy Tenant").await;
+
+    // A wide partition isolates both tenants from jobs left by other tests.
+    let partition_count = 20_000_i32;
+    let partition_index = sqlx::query_scalar::<_, i64>(
+        "SELECT mod((hashtext($1::text)::BIGINT & 2147483647), $2::BIGINT)",
+    )
+    .bind(busy_tenant_id.as_uuid())
+    .bind(partition_count)
+    .fetch_one(&pool)
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    let quiet_tenant_uuid = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        SELECT candidate
+        FROM (
+            SELECT gen_random_uuid() AS candidate
+            FROM generate_series(1, 400000)
+        ) candidates
+        WHERE mod((hashtext(candidate::text)::BIGINT & 2147483647), $1::BIGINT) = $2
+        LIMIT 1
+        "#,
+    )
+    .bind(partition_count)
+    .bind(partition_index)
+    .fetch_one(&pool)
+    .await
+    .unwrap_or_else(|_| unreachable!());
+    let quiet_tenant_id = TenantId::from_uuid(quiet_tenant_uuid);
+    ensure_tenant(&pool, quiet_tenant_id, "Workflow Fair Share Quiet Tenant").await;
+
+    let busy_workflow = save_and_publish_workflow(
+        &repository,
+        busy_tenant_id,
+        workflow("bulk_import", "Bulk Import"),
+    )
+    .await;
+    let quiet_workflow = save_and_publish_workflow(
+        &repository,
+        quiet_tenant_id,
+        workflow("welcome_email", "Welcome Email"),
+    )
+    .await;
+    let busy_run_ids = enqueue_manual_runs(&repository, busy_tenant_id, &busy_workflow, 3).await;
+    let quiet_run_ids = enqueue_manual_runs(&repository, quiet_tenant_id, &quiet_workflow, 1).await;
+
+    let partition = WorkflowClaimPartition::new(
+        u32::try_from(partition_count).unwrap_or_else(|_| unreachable!()),
+        u32::try_from(partition_index).unwrap_or_else(|_| unreachable!()),
+    )
+    .unwrap_or_else(|_| unreachable!());
+    let claimed = repository
+        .claim_jobs("worker-fair-share", 2, 60, Some(partition), None)
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(claimed.len(), 2);
+
+    let mut claimed_run_ids: Vec<_> = claimed.iter().map(|job| job.run_id.clone()).collect();
+    claimed_run_ids.sort();
+    let mut expected_run_ids = vec![busy_run_ids[0].clone(), quiet_run_ids[0].clone()];
+    expected_run_ids.sort();
+    assert_eq!(claimed_run_ids, expected_run_ids);
+}
+
+#[tokio::test]
+async fn runs_are_unique_per_workflow_idempotency_key() {
+    let Some(pool) = test_pool().await else {
+        return;
+    };
+
+    let repository = PostgresWorkflowRepository::new(pool.clone());
+    let tenant_id = TenantId::new();
+    ensure_tenant(&pool, tenant_id, "Workflow Idempotency Tenant").await;
+    let invoice_workflow = save_and_publish_workflow(
+        &repository,
+        tenant_id,
+        workflow("send_invoice", "Send Invoice"),
+    )
+    .await;
+    let reminder_workflow = save_and_publish_workflow(
+        &repository,
+        tenant_id,
+        workflow("send_reminder", "Send Reminder"),
+    )
+    .await;
+    let run_input = |workflow: &WorkflowDefinition| CreateWorkflowRunInput {
+        workflow_logical_name: workflow.logical_name().as_str().to_owned(),
+        workflow_version: workflow.published_version().unwrap_or_default(),
+        trigger_type: "manual".to_owned(),
+        trigger_entity_logical_name: None,
+        trigger_payload: json!({}),
+        parent_run_id: None,
+        trace_context: None,
+        priority: workflow.priority(),
+        idempotency_key: Some("invoice-42".to_owned()),
+    };
+
+    let first = repository
+        .create_run(tenant_id, run_input(&invoice_workflow))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    let duplicate = repository
+        .create_run(tenant_id, run_input(&invoice_workflow))
+        .await;
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+
+    let found = repository
+        .find_run_by_idempotency_key(tenant_id, "send_invoice", "invoice-42")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_eq!(found.map(|run| run.run_id), Some(first.run_id.clone()));
+
+    let other_workflow_run = repository
+        .create_run(tenant_id, run_input(&reminder_workflow))
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert_ne!(other_workflow_run.run_id, first.run_id);
+
+    let other_tenant_id = TenantId::new();
+    ensure_tenant(&pool, other_tenant_id, "Workflow Idempotency Other Tenant").await;
+    let other_tenant_lookup = repository
+        .find_run_by_idempotency_key(other_tenant_id, "send_invoice", "invoice-42")
+        .await
+        .unwrap_or_else(|_| unreachable!());
+    assert!(other_tenant_lookup.is_none());
+}
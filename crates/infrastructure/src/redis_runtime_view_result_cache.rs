@@ -0,0 +1,148 @@
+//! Redis-backed view result cache.
+//!
+//! Every entity has its own generation counter so invalidation is a single
+//! `INCR`: readers move to the new generation and stale entries expire by ttl.
+
+use async_trait::async_trait;
+use qryvanta_application::{RuntimeViewCacheKey, RuntimeViewCacheLookup, RuntimeViewResultCache};
+use qryvanta_core::{AppError, AppResult, TenantId};
+use qryvanta_domain::RuntimeRecord;
+use redis::AsyncCommands;
+use redis::aio::MultiplexedConnection;
+
+/// Redis implementation of the view result cache port.
+#[derive(Clone)]
+pub struct RedisRuntimeViewResultCache {
+    client: redis::Client,
+    key_prefix: String,
+}
+
+impl RedisRuntimeViewResultCache {
+    /// Creates a cache adapter with a configured Redis client and key prefix.
+    #[must_use]
+    pub fn new(client: redis::Client, key_prefix: impl Into<String>) -> Self {
+        Self {
+            client,
+            key_prefix: key_prefix.into(),
+        }
+    }
+
+    fn generation_key(&self, tenant_id: TenantId, entity_logical_name: &str) -> String {
+        format!(
+            "{}:generation:{tenant_id}:{entity_logical_name}",
+            self.key_prefix
+        )
+    }
+
+    fn key_for(&self, generation: i64, key: &RuntimeViewCacheKey) -> String {
+        format!(
+            "{}:entry:{}:{}:gen={generation}:view={}:query={}:subject={}",
+            self.key_prefix,
+            key.tenant_id,
+            key.entity_logical_name,
+            key.view_logical_name,
+            key.query_fingerprint,
+            key.subject
+        )
+    }
+
+    async fn connection(&self) -> AppResult<MultiplexedConnection> {
+        self.client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|error| AppError::Internal(format!("failed to connect to redis: {error}")))
+    }
+
+    async fn current_generation(
+        &self,
+        connection: &mut MultiplexedConnection,
+        key: &RuntimeViewCacheKey,
+    ) -> AppResult<i64> {
+        let generation: Option<i64> = connection
+            .get(self.generation_key(key.tenant_id, key.entity_logical_name.as_str()))
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to read view result cache generation: {error}"
+                ))
+            })?;
+
+        Ok(generation.unwrap_or(0))
+    }
+}
+
+#[async_trait]
+impl RuntimeViewResultCache for RedisRuntimeViewResultCache {
+    async fn get_view_result(
+        &self,
+        key: &RuntimeViewCacheKey,
+    ) -> AppResult<RuntimeViewCacheLookup> {
+        let mut connection = self.connection().await?;
+        let generation = self.current_generation(&mut connection, key).await?;
+
+        let encoded: Option<String> = connection
+            .get(self.key_for(generation, key))
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!("failed to read view result cache entry: {error}"))
+            })?;
+        let records = encoded
+            .as_deref()
+            .map(|encoded| {
+                serde_json::from_str::<Vec<RuntimeRecord>>(encoded).map_err(|error| {
+                    AppError::Internal(format!("invalid view result cache entry: {error}"))
+                })
+            })
+            .transpose()?;
+
+        Ok(RuntimeViewCacheLookup {
+            records,
+            generation,
+        })
+    }
+
+    async fn set_view_result(
+        &self,
+        key: RuntimeViewCacheKey,
+        generation: i64,
+        records: Vec<RuntimeRecord>,
+        ttl_seconds: u32,
+    ) -> AppResult<()> {
+        if ttl_seconds == 0 {
+            return Ok(());
+        }
+
+        // Entries land under the generation the result was computed at, so a
+        // result that raced a write is written where no reader looks.
+        let value = serde_json::to_string(&records).map_err(|error| {
+            AppError::Internal(format!("failed to encode view result cache entry: {error}"))
+        })?;
+        let mut connection = self.connection().await?;
+        connection
+            .set_ex(
+                self.key_for(generation, &key),
+                value,
+                u64::from(ttl_seconds),
+            )
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!("failed to write view result cache entry: {error}"))
+            })
+    }
+
+    async fn invalidate_entity(
+        &self,
+        tenant_id: TenantId,
+        entity_logical_name: &str,
+    ) -> AppResult<()> {
+        let mut connection = self.connection().await?;
+        let _: i64 = connection
+            .incr(self.generation_key(tenant_id, entity_logical_name), 1)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!("failed to invalidate view result cache: {error}"))
+            })?;
+
+        Ok(())
+    }
+}
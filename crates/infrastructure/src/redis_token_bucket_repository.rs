@@ -0,0 +1,103 @@
+//! Redis-backed token bucket repository.
+
+use async_trait::async_trait;
+use qryvanta_application::{TokenBucketDecision, TokenBucketRepository};
+use qryvanta_core::{AppError, AppResult};
+use redis::Script;
+
+use crate::token_bucket::{
+    MILLITOKENS_PER_TOKEN, capacity_millitokens, decision_from_millitokens, full_refill_ms,
+};
+
+// Uses the Redis server clock so buckets stay consistent across API replicas.
+const TAKE_TOKEN_SCRIPT: &str = r#"
+local key = KEYS[1]
+local capacity = tonumber(ARGV[1])
+local refill_per_ms = tonumber(ARGV[2])
+local cost = tonumber(ARGV[3])
+local ttl_ms = tonumber(ARGV[4])
+
+local time = redis.call('TIME')
+local now_ms = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+
+local state = redis.call('HMGET', key, 'tokens', 'updated_at')
+local tokens = tonumber(state[1])
+local updated_at = tonumber(state[2])
+if tokens == nil or updated_at == nil then
+  tokens = capacity
+  updated_at = now_ms
+end
+
+if now_ms > updated_at then
+  tokens = math.min(capacity, tokens + (now_ms - updated_at) * refill_per_ms)
+  updated_at = now_ms
+end
+
+local allowed = 0
+if tokens >= cost then
+  tokens = tokens - cost
+  allowed = 1
+end
+
+redis.call('HSET', key, 'tokens', tokens, 'updated_at', updated_at)
+redis.call('PEXPIRE', key, ttl_ms)
+return {allowed, tokens}
+"#;
+
+/// Redis implementation of the token bucket repository port.
+#[derive(Clone)]
+pub struct RedisTokenBucketRepository {
+    client: redis::Client,
+    key_prefix: String,
+}
+
+impl RedisTokenBucketRepository {
+    /// Creates a repository with a configured Redis client and key prefix.
+    #[must_use]
+    pub fn new(client: redis::Client, key_prefix: impl Into<String>) -> Self {
+        Self {
+            client,
+            key_prefix: key_prefix.into(),
+        }
+    }
+
+    fn key_for(&self, key: &str) -> String {
+        format!("{}:{key}", self.key_prefix)
+    }
+}
+
+#[async_trait]
+impl TokenBucketRepository for RedisTokenBucketRepository {
+    async fn take_token(
+        &self,
+        key: &str,
+        capacity: u32,
+        refill_per_second: u32,
+    ) -> AppResult<TokenBucketDecision> {
+        let mut connection = self
+            .client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|error| AppError::Internal(format!("failed to connect to redis: {error}")))?;
+
+        // Idle buckets expire once they would have refilled completely.
+        let ttl_ms = full_refill_ms(capacity, refill_per_second).saturating_add(1_000);
+        let (allowed, millitokens): (i64, i64) = Script::new(TAKE_TOKEN_SCRIPT)
+            .key(self.key_for(key))
+            .arg(capacity_millitokens(capacity))
+            .arg(refill_per_second)
+            .arg(MILLITOKENS_PER_TOKEN)
+            .arg(ttl_ms)
+            .invoke_async(&mut connection)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!("failed to take redis token bucket token: {error}"))
+            })?;
+
+        Ok(decision_from_millitokens(
+            allowed == 1,
+            u64::try_from(millitokens).unwrap_or(0),
+            refill_per_second,
+        ))
+    }
+}
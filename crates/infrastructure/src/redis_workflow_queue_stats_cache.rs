@@ -1,9 +1,17 @@
 //! Redis-backed workflow queue stats cache.
+//!
+//! Entries are keyed by a generation counter so invalidation is a single
+//! `INCR`: readers move to the new generation and stale entries expire by ttl.
 
 use async_trait::async_trait;
-use qryvanta_application::{WorkflowQueueStats, WorkflowQueueStatsCache, WorkflowQueueStatsQuery};
+use chrono::{DateTime, Utc};
+use qryvanta_application::{
+    WorkflowQueueStats, WorkflowQueueStatsCache, WorkflowQueueStatsQuery,
+    WorkflowQueueStatsSnapshot,
+};
 use qryvanta_core::{AppError, AppResult};
 use redis::AsyncCommands;
+use redis::aio::MultiplexedConnection;
 
 /// Redis implementation of the workflow queue stats cache port.
 #[derive(Clone)]
@@ -22,49 +30,93 @@ impl RedisWorkflowQueueStatsCache {
         }
     }
 
-    fn key_for(&self, query: WorkflowQueueStatsQuery) -> String {
-        match query.partition {
+    fn generation_key(&self) -> String {
+        format!("{}:generation", self.key_prefix)
+    }
+
+    fn key_for(&self, generation: i64, query: WorkflowQueueStatsQuery) -> String {
+        let partition = match query.partition {
             Some(partition) => format!(
-                "{}:window={}:partition={}:{}",
-                self.key_prefix,
-                query.active_window_seconds,
+                "{}:{}",
                 partition.partition_count(),
                 partition.partition_index()
             ),
-            None => format!(
-                "{}:window={}:partition=none",
-                self.key_prefix, query.active_window_seconds
-            ),
-        }
+            None => "none".to_owned(),
+        };
+        let tenant = match query.tenant_id {
+            Some(tenant_id) => tenant_id.to_string(),
+            None => "all".to_owned(),
+        };
+
+        format!(
+            "{}:gen={generation}:window={}:partition={partition}:tenant={tenant}",
+            self.key_prefix, query.active_window_seconds
+        )
+    }
+
+    async fn connection(&self) -> AppResult<MultiplexedConnection> {
+        self.client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|error| AppError::Internal(format!("failed to connect to redis: {error}")))
+    }
+
+    async fn current_generation(&self, connection: &mut MultiplexedConnection) -> AppResult<i64> {
+        let generation: Option<i64> =
+            connection
+                .get(self.generation_key())
+                .await
+                .map_err(|error| {
+                    AppError::Internal(format!(
+                        "failed to read workflow queue stats cache generation: {error}"
+                    ))
+                })?;
+
+        Ok(generation.unwrap_or(0))
     }
 
-    fn encode_stats(stats: WorkflowQueueStats) -> String {
+    fn encode_snapshot(snapshot: WorkflowQueueStatsSnapshot) -> String {
+        let stats = snapshot.stats;
         format!(
-            "{},{},{},{},{},{}",
+            "{},{},{},{},{},{},{}",
             stats.pending_jobs,
             stats.leased_jobs,
             stats.completed_jobs,
             stats.failed_jobs,
             stats.expired_leases,
-            stats.active_workers
+            stats.active_workers,
+            snapshot.computed_at.timestamp_millis()
         )
     }
 
-    fn decode_stats(value: &str) -> AppResult<WorkflowQueueStats> {
+    fn decode_snapshot(value: &str) -> AppResult<WorkflowQueueStatsSnapshot> {
         let parts: Vec<&str> = value.split(',').collect();
-        if parts.len() != 6 {
+        if parts.len() != 7 {
             return Err(AppError::Internal(format!(
                 "invalid workflow queue stats cache value '{value}'"
             )));
         }
 
-        Ok(WorkflowQueueStats {
-            pending_jobs: parse_metric(parts[0], "pending_jobs")?,
-            leased_jobs: parse_metric(parts[1], "leased_jobs")?,
-            completed_jobs: parse_metric(parts[2], "completed_jobs")?,
-            failed_jobs: parse_metric(parts[3], "failed_jobs")?,
-            expired_leases: parse_metric(parts[4], "expired_leases")?,
-            active_workers: parse_metric(parts[5], "active_workers")?,
+        let computed_at_millis = parse_metric(parts[6], "computed_at")?;
+        let computed_at = DateTime::<Utc>::from_timestamp_millis(computed_at_millis).ok_or_else(
+            || {
+                AppError::Internal(format!(
+                    "invalid workflow queue stats cache field 'computed_at' value '{computed_at_millis}'"
+                ))
+            },
+        )?;
+
+        Ok(WorkflowQueueStatsSnapshot {
+            stats: WorkflowQueueStats {
+                pending_jobs: parse_metric(parts[0], "pending_jobs")?,
+                leased_jobs: parse_metric(parts[1], "leased_jobs")?,
+                completed_jobs: parse_metric(parts[2], "completed_jobs")?,
+                failed_jobs: parse_metric(parts[3], "failed_jobs")?,
+                expired_leases: parse_metric(parts[4], "expired_leases")?,
+                active_workers: parse_metric(parts[5], "active_workers")?,
+            },
+            computed_at,
+            served_from_cache: true,
         })
     }
 }
@@ -74,13 +126,10 @@ impl WorkflowQueueStatsCache for RedisWorkflowQueueStatsCache {
     async fn get_queue_stats(
         &self,
         query: WorkflowQueueStatsQuery,
-    ) -> AppResult<Option<WorkflowQueueStats>> {
-        let key = self.key_for(query);
-        let mut connection = self
-            .client
-            .get_multiplexed_async_connection()
-            .await
-            .map_err(|error| AppError::Internal(format!("failed to connect to redis: {error}")))?;
+    ) -> AppResult<Option<WorkflowQueueStatsSnapshot>> {
+        let mut connection = self.connection().await?;
+        let generation = self.current_generation(&mut connection).await?;
+        let key = self.key_for(generation, query);
 
         let encoded: Option<String> = connection.get(key).await.map_err(|error| {
             AppError::Internal(format!(
@@ -88,26 +137,23 @@ impl WorkflowQueueStatsCache for RedisWorkflowQueueStatsCache {
             ))
         })?;
 
-        encoded.as_deref().map(Self::decode_stats).transpose()
+        encoded.as_deref().map(Self::decode_snapshot).transpose()
     }
 
     async fn set_queue_stats(
         &self,
         query: WorkflowQueueStatsQuery,
-        stats: WorkflowQueueStats,
+        snapshot: WorkflowQueueStatsSnapshot,
         ttl_seconds: u32,
     ) -> AppResult<()> {
         if ttl_seconds == 0 {
             return Ok(());
         }
 
-        let key = self.key_for(query);
-        let value = Self::encode_stats(stats);
-        let mut connection = self
-            .client
-            .get_multiplexed_async_connection()
-            .await
-            .map_err(|error| AppError::Internal(format!("failed to connect to redis: {error}")))?;
+        let mut connection = self.connection().await?;
+        let generation = self.current_generation(&mut connection).await?;
+        let key = self.key_for(generation, query);
+        let value = Self::encode_snapshot(snapshot);
 
         connection
             .set_ex(key, value, u64::from(ttl_seconds))
@@ -118,6 +164,20 @@ impl WorkflowQueueStatsCache for RedisWorkflowQueueStatsCache {
                 ))
             })
     }
+
+    async fn invalidate_queue_stats(&self) -> AppResult<()> {
+        let mut connection = self.connection().await?;
+        let _: i64 = connection
+            .incr(self.generation_key(), 1)
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!(
+                    "failed to invalidate workflow queue stats cache: {error}"
+                ))
+            })?;
+
+        Ok(())
+    }
 }
 
 fn parse_metric(value: &str, metric_name: &str) -> AppResult<i64> {
@@ -1,10 +1,15 @@
 //! Redis-backed distributed lease coordinator for workflow workers.
 
 use async_trait::async_trait;
-use qryvanta_application::{WorkflowWorkerLease, WorkflowWorkerLeaseCoordinator};
+use qryvanta_application::{
+    WorkflowWorkerLease, WorkflowWorkerLeaseCoordinator, WorkflowWorkerLeaseOwnership,
+};
 use qryvanta_core::{AppError, AppResult};
 use redis::{AsyncCommands, Script};
 
+/// Key prefix shared by workers acquiring leases and operators inspecting them.
+pub const WORKFLOW_WORKER_LEASE_KEY_PREFIX: &str = "qryvanta:workflow_worker_lease";
+
 const RELEASE_LEASE_SCRIPT: &str = r#"
 if redis.call('GET', KEYS[1]) == ARGV[1] then
   return redis.call('DEL', KEYS[1])
@@ -157,4 +162,40 @@ impl WorkflowWorkerLeaseCoordinator for RedisWorkflowWorkerLeaseCoordinator {
 
         Ok(renewed > 0)
     }
+
+    async fn inspect_lease(
+        &self,
+        scope_key: &str,
+    ) -> AppResult<Option<WorkflowWorkerLeaseOwnership>> {
+        let key = self.key_for(scope_key);
+
+        let mut connection = self
+            .client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|error| AppError::Internal(format!("failed to connect to redis: {error}")))?;
+
+        let token: Option<String> = connection
+            .get(key.as_str())
+            .await
+            .map_err(|error| AppError::Internal(format!("failed to read worker lease: {error}")))?;
+        let Some(token) = token else {
+            return Ok(None);
+        };
+        let ttl_seconds: i64 = connection.ttl(key.as_str()).await.map_err(|error| {
+            AppError::Internal(format!("failed to read worker lease ttl: {error}"))
+        })?;
+
+        // Tokens are `{holder_id}:{uuid}`; holder ids may contain colons.
+        let holder_id = token
+            .rsplit_once(':')
+            .map_or(token.as_str(), |(holder_id, _)| holder_id)
+            .to_owned();
+
+        Ok(Some(WorkflowWorkerLeaseOwnership {
+            scope_key: scope_key.to_owned(),
+            holder_id,
+            expires_in_seconds: (ttl_seconds >= 0).then_some(ttl_seconds),
+        }))
+    }
 }
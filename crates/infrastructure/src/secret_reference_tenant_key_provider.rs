@@ -0,0 +1,129 @@
+//! Tenant key material resolved through the configured secrets provider.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use qryvanta_application::{TenantKeyMaterial, TenantKeyMaterialProvider};
+use qryvanta_core::{AppError, AppResult, resolve_secret_reference, secret_fingerprint};
+
+use crate::aes_secret_encryptor::AesSecretEncryptor;
+
+type SecretResolver = fn(&str) -> AppResult<String>;
+
+/// Resolves tenant key references into AES-256-GCM encryptors.
+///
+/// Referenced secrets must contain a hex-encoded 32-byte key.
+#[derive(Clone)]
+pub struct SecretReferenceTenantKeyProvider {
+    resolver: SecretResolver,
+}
+
+impl SecretReferenceTenantKeyProvider {
+    /// Creates a provider backed by the secrets CLI integration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            resolver: resolve_secret_reference,
+        }
+    }
+
+    #[cfg(test)]
+    fn with_resolver(resolver: SecretResolver) -> Self {
+        Self { resolver }
+    }
+}
+
+impl Default for SecretReferenceTenantKeyProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TenantKeyMaterialProvider for SecretReferenceTenantKeyProvider {
+    async fn load_key_material(&self, secret_reference: &str) -> AppResult<TenantKeyMaterial> {
+        let resolver = self.resolver;
+        let secret_reference = secret_reference.to_owned();
+        let key_hex = tokio::task::spawn_blocking(move || resolver(secret_reference.as_str()))
+            .await
+            .map_err(|error| {
+                AppError::Internal(format!("failed to resolve tenant encryption key: {error}"))
+            })??;
+
+        parse_tenant_key_material(key_hex.trim())
+    }
+}
+
+fn parse_tenant_key_material(key_hex: &str) -> AppResult<TenantKeyMaterial> {
+    let decoded = hex::decode(key_hex).map_err(|error| {
+        AppError::Validation(format!(
+            "tenant encryption key must be hex-encoded: {error}"
+        ))
+    })?;
+
+    let key: [u8; 32] = decoded.as_slice().try_into().map_err(|_| {
+        AppError::Validation(
+            "tenant encryption key must be exactly 32 bytes (64 hex chars)".to_owned(),
+        )
+    })?;
+
+    if key.iter().all(|byte| *byte == 0) {
+        return Err(AppError::Validation(
+            "tenant encryption key must not be all zeroes".to_owned(),
+        ));
+    }
+
+    Ok(TenantKeyMaterial {
+        encryptor: Arc::new(AesSecretEncryptor::new(&key)),
+        key_fingerprint: secret_fingerprint("TENANT_ENCRYPTION_KEY", &hex::encode(key)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve_fixture(reference: &str) -> AppResult<String> {
+        match reference {
+            "aws-sm://valid" => Ok(format!("{}\n", "ab".repeat(32))),
+            "aws-sm://zero" => Ok("00".repeat(32)),
+            "aws-sm://short" => Ok("abcd".to_owned()),
+            _ => Err(AppError::Validation("unknown reference".to_owned())),
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_hex_key_material_and_fingerprints_it() {
+        let provider = SecretReferenceTenantKeyProvider::with_resolver(resolve_fixture);
+        let material = provider
+            .load_key_material("aws-sm://valid")
+            .await
+            .unwrap_or_else(|_| unreachable!());
+
+        let ciphertext = material
+            .encryptor
+            .encrypt(b"secret")
+            .unwrap_or_else(|_| unreachable!());
+        assert_eq!(
+            material
+                .encryptor
+                .decrypt(&ciphertext)
+                .unwrap_or_else(|_| unreachable!()),
+            b"secret"
+        );
+        assert_eq!(
+            material.key_fingerprint,
+            secret_fingerprint("TENANT_ENCRYPTION_KEY", &"ab".repeat(32))
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_weak_or_malformed_key_material() {
+        let provider = SecretReferenceTenantKeyProvider::with_resolver(resolve_fixture);
+
+        for reference in ["aws-sm://zero", "aws-sm://short"] {
+            let result = provider.load_key_material(reference).await;
+            assert!(matches!(result, Err(AppError::Validation(_))));
+        }
+    }
+}
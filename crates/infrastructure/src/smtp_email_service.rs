@@ -1,10 +1,10 @@
 //! SMTP email service using the `lettre` crate.
 
 use async_trait::async_trait;
-use lettre::message::{Mailbox, MultiPart, SinglePart, header::ContentType};
+use lettre::message::{Attachment, Mailbox, MultiPart, SinglePart, header::ContentType};
 use lettre::transport::smtp::authentication::Credentials;
 use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
-use qryvanta_application::EmailService;
+use qryvanta_application::{EmailAttachment, EmailService};
 use qryvanta_core::{AppError, AppResult};
 
 /// SMTP email service configuration.
@@ -55,15 +55,12 @@ impl SmtpEmailService {
     }
 }
 
-#[async_trait]
-impl EmailService for SmtpEmailService {
-    async fn send_email(
+impl SmtpEmailService {
+    fn message_builder(
         &self,
         to: &str,
         subject: &str,
-        text_body: &str,
-        html_body: Option<&str>,
-    ) -> AppResult<()> {
+    ) -> AppResult<lettre::message::MessageBuilder> {
         if subject.contains('\r') || subject.contains('\n') {
             return Err(AppError::Validation(
                 "email subject must not contain newline characters".to_owned(),
@@ -74,10 +71,32 @@ impl EmailService for SmtpEmailService {
             .parse()
             .map_err(|error| AppError::Validation(format!("invalid recipient address: {error}")))?;
 
-        let message_builder = Message::builder()
+        Ok(Message::builder()
             .from(self.from_address.clone())
             .to(to_mailbox)
-            .subject(subject);
+            .subject(subject))
+    }
+
+    async fn deliver(&self, message: Message) -> AppResult<()> {
+        self.mailer
+            .send(message)
+            .await
+            .map_err(|error| AppError::Internal(format!("failed to send email: {error}")))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EmailService for SmtpEmailService {
+    async fn send_email(
+        &self,
+        to: &str,
+        subject: &str,
+        text_body: &str,
+        html_body: Option<&str>,
+    ) -> AppResult<()> {
+        let message_builder = self.message_builder(to, subject)?;
 
         let plain_text = SinglePart::builder()
             .header(ContentType::TEXT_PLAIN)
@@ -101,11 +120,41 @@ impl EmailService for SmtpEmailService {
                 .map_err(|error| AppError::Internal(format!("failed to build email: {error}")))?
         };
 
-        self.mailer
-            .send(message)
-            .await
-            .map_err(|error| AppError::Internal(format!("failed to send email: {error}")))?;
+        self.deliver(message).await
+    }
 
-        Ok(())
+    async fn send_email_with_attachments(
+        &self,
+        to: &str,
+        subject: &str,
+        text_body: &str,
+        attachments: &[EmailAttachment],
+    ) -> AppResult<()> {
+        let message_builder = self.message_builder(to, subject)?;
+
+        let mut multipart = MultiPart::mixed().singlepart(
+            SinglePart::builder()
+                .header(ContentType::TEXT_PLAIN)
+                .body(text_body.to_owned()),
+        );
+        for attachment in attachments {
+            let content_type =
+                ContentType::parse(attachment.content_type.as_str()).map_err(|error| {
+                    AppError::Validation(format!(
+                        "invalid attachment content type '{}': {error}",
+                        attachment.content_type
+                    ))
+                })?;
+            multipart = multipart.singlepart(
+                Attachment::new(attachment.filename.clone())
+                    .body(attachment.content.clone(), content_type),
+            );
+        }
+
+        let message = message_builder
+            .multipart(multipart)
+            .map_err(|error| AppError::Internal(format!("failed to build email: {error}")))?;
+
+        self.deliver(message).await
     }
 }
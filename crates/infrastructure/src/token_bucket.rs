@@ -0,0 +1,119 @@
+//! Token bucket arithmetic shared by token bucket repository adapters.
+//!
+//! Buckets are tracked in millitokens so a refill rate in tokens per second
+//! equals millitokens per elapsed millisecond and stays integer-only.
+
+use qryvanta_application::TokenBucketDecision;
+
+/// Millitokens consumed by one request.
+pub(crate) const MILLITOKENS_PER_TOKEN: u64 = 1_000;
+
+/// Stored state for one token bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TokenBucketState {
+    pub(crate) millitokens: u64,
+    pub(crate) updated_at_ms: u64,
+}
+
+impl TokenBucketState {
+    /// Creates a full bucket observed at `now_ms`.
+    pub(crate) fn full(capacity: u32, now_ms: u64) -> Self {
+        Self {
+            millitokens: capacity_millitokens(capacity),
+            updated_at_ms: now_ms,
+        }
+    }
+
+    /// Refills the bucket up to `now_ms` and takes one token when available.
+    pub(crate) fn take(
+        &mut self,
+        capacity: u32,
+        refill_per_second: u32,
+        now_ms: u64,
+    ) -> TokenBucketDecision {
+        let elapsed_ms = now_ms.saturating_sub(self.updated_at_ms);
+        self.millitokens = self
+            .millitokens
+            .saturating_add(elapsed_ms.saturating_mul(u64::from(refill_per_second)))
+            .min(capacity_millitokens(capacity));
+        self.updated_at_ms = self.updated_at_ms.max(now_ms);
+
+        let allowed = self.millitokens >= MILLITOKENS_PER_TOKEN;
+        if allowed {
+            self.millitokens -= MILLITOKENS_PER_TOKEN;
+        }
+
+        decision_from_millitokens(allowed, self.millitokens, refill_per_second)
+    }
+}
+
+/// Converts one remaining millitoken balance into a take decision.
+pub(crate) fn decision_from_millitokens(
+    allowed: bool,
+    millitokens: u64,
+    refill_per_second: u32,
+) -> TokenBucketDecision {
+    let retry_after_ms = if allowed {
+        0
+    } else if refill_per_second == 0 {
+        u64::MAX
+    } else {
+        MILLITOKENS_PER_TOKEN
+            .saturating_sub(millitokens)
+            .div_ceil(u64::from(refill_per_second))
+    };
+
+    TokenBucketDecision {
+        allowed,
+        remaining_tokens: u32::try_from(millitokens / MILLITOKENS_PER_TOKEN).unwrap_or(u32::MAX),
+        retry_after_ms,
+    }
+}
+
+/// Returns the bucket capacity in millitokens.
+pub(crate) fn capacity_millitokens(capacity: u32) -> u64 {
+    u64::from(capacity).saturating_mul(MILLITOKENS_PER_TOKEN)
+}
+
+/// Returns how long an idle bucket needs to refill completely.
+pub(crate) fn full_refill_ms(capacity: u32, refill_per_second: u32) -> u64 {
+    if refill_per_second == 0 {
+        return 60 * 60 * 1_000;
+    }
+
+    capacity_millitokens(capacity).div_ceil(u64::from(refill_per_second))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TokenBucketState;
+
+    #[test]
+    fn bucket_allows_burst_then_refills_at_rate() {
+        let mut bucket = TokenBucketState::full(2, 0);
+
+        assert!(bucket.take(2, 4, 0).allowed);
+        assert!(bucket.take(2, 4, 0).allowed);
+
+        let rejected = bucket.take(2, 4, 0);
+        assert!(!rejected.allowed);
+        assert_eq!(rejected.remaining_tokens, 0);
+        assert_eq!(rejected.retry_after_ms, 250);
+
+        let partial = bucket.take(2, 4, 100);
+        assert!(!partial.allowed);
+        assert_eq!(partial.retry_after_ms, 150);
+
+        assert!(bucket.take(2, 4, 250).allowed);
+    }
+
+    #[test]
+    fn refill_never_exceeds_capacity() {
+        let mut bucket = TokenBucketState::full(3, 0);
+        assert!(bucket.take(3, 10, 0).allowed);
+
+        let decision = bucket.take(3, 10, 60_000);
+        assert!(decision.allowed);
+        assert_eq!(decision.remaining_tokens, 2);
+    }
+}
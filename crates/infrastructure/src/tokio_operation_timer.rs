@@ -0,0 +1,14 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use qryvanta_application::OperationTimer;
+
+/// Tokio-based operation timer adapter.
+pub struct TokioOperationTimer;
+
+#[async_trait]
+impl OperationTimer for TokioOperationTimer {
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
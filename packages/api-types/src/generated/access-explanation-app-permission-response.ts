@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * App role permission evaluated by an access explanation.
+ */
+export type AccessExplanationAppPermissionResponse = { app_logical_name: string, role_name: string, 
+/**
+ * Whether any role of the subject is bound to the app.
+ */
+app_accessible: boolean, can_read: boolean, can_create: boolean, can_update: boolean, can_delete: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Field grant evaluated by an access explanation.
+ */
+export type AccessExplanationFieldGrantResponse = { field_logical_name: string, can_read: boolean, can_write: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Tenant permission evaluated by an access explanation.
+ */
+export type AccessExplanationPermissionCheckResponse = { permission: string, granted: boolean, granting_roles: Array<string>, temporary_grant_id: string | null, temporary_grant_expires_at: string | null, };
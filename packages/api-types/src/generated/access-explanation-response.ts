@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AccessExplanationAppPermissionResponse } from "./access-explanation-app-permission-response";
+import type { AccessExplanationFieldGrantResponse } from "./access-explanation-field-grant-response";
+import type { AccessExplanationPermissionCheckResponse } from "./access-explanation-permission-check-response";
+import type { AccessExplanationRoleResponse } from "./access-explanation-role-response";
+
+/**
+ * Evaluation trace explaining whether a subject can perform a record action.
+ */
+export type AccessExplanationResponse = { subject: string, entity_logical_name: string, action: "read" | "create" | "update" | "delete", decision: "allowed" | "denied", roles: Array<AccessExplanationRoleResponse>, permission_checks: Array<AccessExplanationPermissionCheckResponse>, app_permissions: Array<AccessExplanationAppPermissionResponse>, field_grants: Array<AccessExplanationFieldGrantResponse>, 
+/**
+ * Human-readable evaluation steps, in order.
+ */
+trace: Array<string>, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Role held by the subject of an access explanation.
+ */
+export type AccessExplanationRoleResponse = { role_name: string, 
+/**
+ * Team the role is assigned through, `null` for direct assignments.
+ */
+team_name: string | null, permissions: Array<string>, };
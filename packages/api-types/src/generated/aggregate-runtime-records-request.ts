@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RuntimeRecordAggregateHavingRequest } from "./runtime-record-aggregate-having-request";
+import type { RuntimeRecordAggregateRequest } from "./runtime-record-aggregate-request";
+import type { RuntimeRecordAggregateSortRequest } from "./runtime-record-aggregate-sort-request";
+import type { RuntimeRecordQueryFilterRequest } from "./runtime-record-query-filter-request";
+import type { RuntimeRecordQueryGroupRequest } from "./runtime-record-query-group-request";
+
+/**
+ * Incoming runtime record aggregate query payload.
+ */
+export type AggregateRuntimeRecordsRequest = { 
+/**
+ * Maximum number of groups returned after sorting.
+ */
+limit: number | null, logical_mode: "and" | "or" | null, where: RuntimeRecordQueryGroupRequest | null, conditions: Array<RuntimeRecordQueryFilterRequest> | null, 
+/**
+ * Grouped field logical names; omitted to aggregate all matching records.
+ */
+group_by: Array<string> | null, aggregates: Array<RuntimeRecordAggregateRequest>, having: Array<RuntimeRecordAggregateHavingRequest> | null, sort: Array<RuntimeRecordAggregateSortRequest> | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Announcement banner with its schedule and audience.
+ */
+export type AnnouncementResponse = { announcement_id: string, title: string, body: string, severity: "info" | "warning" | "critical", starts_at: string, ends_at: string | null, audience_roles: Array<string>, audience_apps: Array<string>, is_dismissible: boolean, created_by_subject: string, created_at: string, updated_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One served API version.
+ */
+export type ApiVersionDescriptorResponse = { version: string, status: string, path_prefix: string, deprecated_at: string | null, sunset_at: string | null, successor_version: string | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ApiVersionDescriptorResponse } from "./api-version-descriptor-response";
+
+/**
+ * Served API versions and their deprecation schedules.
+ */
+export type ApiVersionsResponse = { current_version: string, default_version: string, versions: Array<ApiVersionDescriptorResponse>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a delegated app administrator.
+ */
+export type AppAdminGrantResponse = { app_logical_name: string, subject: string, granted_by_subject: string, created_at: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AppEntityCapabilitySummaryResponse } from "./app-entity-capability-summary-response";
+
+/**
+ * Bulk capability introspection for every entity bound to an app.
+ */
+export type AppEntityCapabilitiesBulkResponse = { app_logical_name: string, entities: Array<AppEntityCapabilitySummaryResponse>, };
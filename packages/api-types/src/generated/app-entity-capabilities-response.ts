@@ -3,4 +3,4 @@
 /**
  * API representation of effective app entity capabilities for the current subject.
  */
-export type AppEntityCapabilitiesResponse = { entity_logical_name: string, can_read: boolean, can_create: boolean, can_update: boolean, can_delete: boolean, };
+export type AppEntityCapabilitiesResponse = { entity_logical_name: string, can_read: boolean, can_create: boolean, can_update: boolean, can_delete: boolean, icon: string | null, color: string | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AppEntityFieldPermissionSummaryResponse } from "./app-entity-field-permission-summary-response";
+import type { AppEntityFormDto } from "./app-entity-form-dto";
+import type { AppEntityViewDto } from "./app-entity-view-dto";
+import type { AppEntityViewModeDto } from "./app-entity-view-mode";
+
+/**
+ * Effective capabilities, surfaces, and default modes for one app entity.
+ */
+export type AppEntityCapabilitySummaryResponse = { navigation_label: string | null, forms: Array<AppEntityFormDto>, list_views: Array<AppEntityViewDto>, default_form_logical_name: string, default_list_view_logical_name: string, default_view_mode: AppEntityViewModeDto, field_permissions: AppEntityFieldPermissionSummaryResponse, entity_logical_name: string, can_read: boolean, can_create: boolean, can_update: boolean, can_delete: boolean, icon: string | null, color: string | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Field-level runtime access summary for one workspace entity.
+ */
+export type AppEntityFieldPermissionSummaryResponse = { 
+/**
+ * Whether explicit field grants restrict the subject; when `false` all fields are accessible.
+ */
+restricted: boolean, readable_field_logical_names: Array<string>, writable_field_logical_names: Array<string>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Published icon and color of an entity reachable from app navigation.
+ */
+export type AppNavigationEntityResponse = { entity_logical_name: string, display_name: string, icon: string | null, color: string | null, };
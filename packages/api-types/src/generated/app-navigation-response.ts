@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AppNavigationEntityResponse } from "./app-navigation-entity-response";
+import type { AppSitemapAreaDto } from "./app-sitemap-area-dto";
+
+/**
+ * Worker-facing app navigation with entity presentation metadata.
+ */
+export type AppNavigationResponse = { app_logical_name: string, areas: Array<AppSitemapAreaDto>, entities: Array<AppNavigationEntityResponse>, };
@@ -4,4 +4,4 @@ import type { AppBindingDiffResponse } from "./app-binding-diff-response";
 /**
  * App-level publish diff summary.
  */
-export type AppPublishDiffResponse = { app_logical_name: string, bindings: Array<AppBindingDiffResponse>, };
+export type AppPublishDiffResponse = { app_logical_name: string, draft_fingerprint: string, bindings: Array<AppBindingDiffResponse>, };
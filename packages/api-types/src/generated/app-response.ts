@@ -1,6 +1,11 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LocalizedLabelResponse } from "./localized-label-response";
 
 /**
  * API representation of an app definition.
  */
-export type AppResponse = { logical_name: string, display_name: string, description: string | null, };
+export type AppResponse = { logical_name: string, display_name: string, description: string | null, 
+/**
+ * Label matching the caller's `Accept-Language` preferences, when one exists.
+ */
+localized_label: LocalizedLabelResponse | null, };
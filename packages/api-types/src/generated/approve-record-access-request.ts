@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for approving a record access request.
+ */
+export type ApproveRecordAccessRequest = { duration_hours: number | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload assigning a subject to a compliance zone.
+ */
+export type AssignComplianceZoneRequest = { subject: string, zone: string, };
@@ -3,4 +3,8 @@
 /**
  * Incoming payload for role assignment.
  */
-export type AssignRoleRequest = { subject: string, role_name: string, };
+export type AssignRoleRequest = { 
+/**
+ * `user` (default) or `team`; team subjects are addressed by team name.
+ */
+subject_type: string | null, subject: string, role_name: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming runtime record owner reassignment payload.
+ */
+export type AssignRuntimeRecordOwnerRequest = { owner_subject: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming many-to-many record association payload.
+ */
+export type AssociateRuntimeRecordRequest = { target_record_id: string, };
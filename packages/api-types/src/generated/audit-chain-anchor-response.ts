@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of one tenant audit chain anchor.
+ */
+export type AuditChainAnchorResponse = { chain_position: bigint, entry_hash: string, reason: "periodic" | "purge", created_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of an audit export sink and its delivery checkpoint.
+ */
+export type AuditExportSinkResponse = { sink_id: string, name: string, sink_type: string, bucket: string | null, prefix: string | null, host: string | null, port: number | null, transport: string | null, url: string | null, auth_header_name: string | null, auth_secret_ref: string | null, is_enabled: boolean, checkpoint_position: bigint, last_exported_at: string | null, last_error: string | null, created_by_subject: string, created_at: string, };
@@ -1,6 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AuditChainAnchorResponse } from "./audit-chain-anchor-response";
 
 /**
  * API representation of tenant audit-chain verification status.
  */
-export type AuditIntegrityStatusResponse = { is_valid: boolean, verified_entries: number, latest_chain_position: bigint | null, latest_entry_hash: string | null, failures: Array<string>, };
+export type AuditIntegrityStatusResponse = { is_valid: boolean, verified_entries: number, latest_chain_position: bigint | null, latest_entry_hash: string | null, verified_from_anchor: AuditChainAnchorResponse | null, failures: Array<string>, };
@@ -3,4 +3,8 @@
 /**
  * Incoming payload for email/password login.
  */
-export type AuthLoginRequest = { email: string, password: string, };
+export type AuthLoginRequest = { email: string, password: string, 
+/**
+ * Tenant to enter directly, skipping the tenant selection step.
+ */
+tenant_id?: string, };
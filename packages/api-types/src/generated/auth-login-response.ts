@@ -1,6 +1,15 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TenantOptionResponse } from "./tenant-option-response";
 
 /**
  * Auth status response for login and challenge flows.
  */
-export type AuthLoginResponse = { status: string, requires_totp: boolean, };
+export type AuthLoginResponse = { status: string, requires_totp: boolean, 
+/**
+ * Second factors accepted when `status` is `mfa_required`.
+ */
+mfa_methods: Array<"totp" | "recovery" | "passkey">, 
+/**
+ * Tenants to choose from when `status` is `tenant_selection_required`.
+ */
+tenants: Array<TenantOptionResponse>, };
@@ -1,6 +1,14 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
 /**
- * Incoming payload for TOTP or recovery code verification.
+ * Incoming payload for TOTP, recovery code, or passkey verification.
  */
-export type AuthMfaVerifyRequest = { code: string, method: string | null, };
+export type AuthMfaVerifyRequest = { 
+/**
+ * TOTP or recovery code; ignored for the `passkey` method.
+ */
+code: string, method: string | null, 
+/**
+ * WebAuthn assertion answering the passkey MFA challenge.
+ */
+credential?: unknown, };
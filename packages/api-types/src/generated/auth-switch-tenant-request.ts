@@ -1,6 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
 /**
- * Incoming payload for authenticated tenant switching.
+ * Incoming payload for authenticated tenant switching and login tenant selection.
  */
 export type AuthSwitchTenantRequest = { tenant_id: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackgroundJobStageResponse } from "./background-job-stage-response";
+
+/**
+ * Uniform status of a long-running background job.
+ */
+export type BackgroundJobResponse = { job_id: string, kind: "audit_log_purge" | "qrywell_sync" | "personal_data_export" | "data_validation_audit" | "tenant_data_export", status: "queued" | "running" | "completed" | "failed" | "cancelled", requested_by_subject: string, progress_percent: number, stages: Array<BackgroundJobStageResponse>, cancel_requested: boolean, attempt_count: number, resumed: boolean, status_message: string | null, created_at: string, started_at: string | null, updated_at: string, finished_at: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Progress counters of one background job stage.
+ */
+export type BackgroundJobStageResponse = { name: string, processed: number, total: number | null, completed: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Newly issued bootstrap token, returned once to the rotating operator.
+ */
+export type BootstrapTokenRotationResponse = { token: string, expires_at: string, };
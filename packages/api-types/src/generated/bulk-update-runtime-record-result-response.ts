@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ErrorResponse } from "./error-response";
+import type { RuntimeRecordResponse } from "./runtime-record-response";
+
+/**
+ * API representation of one record's bulk edit outcome.
+ */
+export type BulkUpdateRuntimeRecordResultResponse = { record_id: string, status: "updated" | "failed", record: RuntimeRecordResponse | null, error: ErrorResponse | null, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming runtime record bulk edit payload.
+ */
+export type BulkUpdateRuntimeRecordsRequest = { 
+/**
+ * Records selected in the view.
+ */
+record_ids: Array<string>, 
+/**
+ * Field values applied to every selected record; other fields keep their values.
+ */
+values: Record<string, unknown>, };
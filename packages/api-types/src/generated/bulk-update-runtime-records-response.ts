@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BulkUpdateRuntimeRecordResultResponse } from "./bulk-update-runtime-record-result-response";
+
+/**
+ * API representation of a runtime record bulk edit.
+ */
+export type BulkUpdateRuntimeRecordsResponse = { updated: number, failed: number, results: Array<BulkUpdateRuntimeRecordResultResponse>, };
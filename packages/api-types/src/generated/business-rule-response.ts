@@ -1,6 +1,11 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LocalizedLabelResponse } from "./localized-label-response";
 
 /**
  * API response for standalone business rules.
  */
-export type BusinessRuleResponse = { entity_logical_name: string, logical_name: string, display_name: string, scope: string, form_logical_name: string | null, conditions: unknown[], actions: unknown[], is_active: boolean, };
+export type BusinessRuleResponse = { entity_logical_name: string, logical_name: string, display_name: string, scope: string, form_logical_name: string | null, conditions: unknown[], actions: unknown[], is_active: boolean, 
+/**
+ * Label matching the caller's `Accept-Language` preferences, when one exists.
+ */
+localized_label: LocalizedLabelResponse | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * UI capability flags derived from the subject's permissions in a tenant.
+ */
+export type CapabilityFlagsResponse = { can_design_entities: boolean, can_manage_security: boolean, can_publish: boolean, can_manage_workflows: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a compliance zone assignment.
+ */
+export type ComplianceZoneAssignmentResponse = { subject: string, zone: string, assigned_by_subject: string, created_at: string, };
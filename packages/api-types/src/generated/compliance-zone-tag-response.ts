@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a compliance zone restriction.
+ */
+export type ComplianceZoneTagResponse = { entity_logical_name: string, record_id: string | null, zone: string, tagged_by_subject: string, updated_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for configuring or rotating a workflow inbound webhook.
+ */
+export type ConfigureWorkflowInboundWebhookRequest = { payload_schema: Record<string, unknown> | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of confirming one side of an email change.
+ */
+export type ConfirmEmailChangeResponse = { 
+/**
+ * `awaiting_other_address` until both addresses confirm, then `completed`.
+ */
+status: string, };
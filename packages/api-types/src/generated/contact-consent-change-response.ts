@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of one contact consent history entry.
+ */
+export type ContactConsentChangeResponse = { change_id: string, contact_record_id: string, purpose: string, channel: string, previous_status: string | null, status: string, source: string, recorded_by_subject: string, recorded_at: string, };
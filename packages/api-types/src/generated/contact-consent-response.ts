@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of the current consent state for one purpose and channel.
+ */
+export type ContactConsentResponse = { contact_record_id: string, purpose: string, channel: string, status: string, source: string, granted_at: string | null, revoked_at: string | null, recorded_by_subject: string, updated_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a record's link to its master contact.
+ */
+export type ContactIdentityLinkResponse = { master_contact_id: string, entity_logical_name: string, record_id: string, email_key: string | null, phone_key: string | null, name_key: string | null, match_reason: string, confidence: number, linked_by_subject: string, linked_at: string, };
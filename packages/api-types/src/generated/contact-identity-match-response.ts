@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ContactIdentityLinkResponse } from "./contact-identity-link-response";
+
+/**
+ * API representation of a linked record matching a contact lookup.
+ */
+export type ContactIdentityMatchResponse = { link: ContactIdentityLinkResponse, match_reason: string, confidence: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a contact identity source rebuild.
+ */
+export type ContactIdentityRebuildResponse = { entity_logical_name: string, scanned_records: number, matched_records: number, };
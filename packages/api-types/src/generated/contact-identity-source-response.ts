@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of an entity taking part in contact matching.
+ */
+export type ContactIdentitySourceResponse = { entity_logical_name: string, email_field_logical_name: string | null, phone_field_logical_name: string | null, name_field_logical_names: Array<string>, updated_by_subject: string, updated_at: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for creating an audit export sink.
+ *
+ * `sink_type` selects which destination fields apply: `s3` uses `bucket`
+ * and `prefix`, `syslog` uses `host`, `port` and `transport`, and
+ * `https_webhook` uses `url` with optional auth header fields.
+ */
+export type CreateAuditExportSinkRequest = { name: string, sink_type: string, bucket: string | null, prefix: string | null, host: string | null, port: number | null, transport: string | null, url: string | null, auth_header_name: string | null, auth_secret_ref: string | null, backfill: boolean | null, };
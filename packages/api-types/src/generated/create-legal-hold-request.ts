@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for placing a legal hold.
+ */
+export type CreateLegalHoldRequest = { name: string, reason: string, scope_type: string, entity_logical_name: string | null, record_ids: Array<string> | null, audit_from: string | null, audit_until: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for scheduling a recurring import.
+ */
+export type CreateRecordImportScheduleRequest = { name: string, entity_logical_name: string, template_id: string | null, source_kind: "https" | "sftp", source_url: string, auth_header_name: string | null, auth_secret_ref: string | null, frequency: "hourly" | "daily" | "weekly", };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for creating an external share link for a runtime record.
+ */
+export type CreateRecordShareLinkRequest = { field_logical_names: Array<string>, expires_in_hours: number | null, password: string | null, };
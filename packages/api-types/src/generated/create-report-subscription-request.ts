@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for subscribing to a saved view.
+ */
+export type CreateReportSubscriptionRequest = { entity_logical_name: string, view_logical_name: string, frequency: "daily" | "weekly", format: "csv" | "xlsx", delivery_hour_utc: number, };
@@ -3,4 +3,8 @@
 /**
  * Incoming runtime record create payload.
  */
-export type CreateRuntimeRecordRequest = { data: Record<string, unknown>, };
+export type CreateRuntimeRecordRequest = { data: Record<string, unknown>, 
+/**
+ * Confirms the write despite matches of warning duplicate rules.
+ */
+ignore_duplicate_warnings?: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for security team creation.
+ */
+export type CreateSecurityTeamRequest = { name: string, description: string | null, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecordShareLinkResponse } from "./record-share-link-response";
+
+/**
+ * API representation of a newly created share link.
+ *
+ * `share_path` embeds the raw token and is only returned once.
+ */
+export type CreatedRecordShareLinkResponse = { link: RecordShareLinkResponse, share_path: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WorkflowInboundWebhookResponse } from "./workflow-inbound-webhook-response";
+
+/**
+ * Newly configured inbound webhook with credentials that are only shown once.
+ */
+export type CreatedWorkflowInboundWebhookResponse = { webhook: WorkflowInboundWebhookResponse, webhook_path: string, signing_secret: string, };
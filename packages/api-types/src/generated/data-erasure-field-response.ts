@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a personal data field removed on erasure.
+ */
+export type DataErasureFieldResponse = { entity_logical_name: string, field_logical_name: string, updated_by_subject: string, updated_at: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a completed data erasure.
+ */
+export type DataErasureReportResponse = { pseudonym: string, contact_record_id: string | null, records_pseudonymized: bigint, records_erased: bigint, records_held: bigint, history_entries_scrubbed: bigint, audit_entries_pseudonymized: bigint, audit_entries_retained: bigint, auth_events_anonymized: bigint, 
+/**
+ * `true` when auth events were kept because the subject belongs to another tenant.
+ */
+auth_events_retained: boolean, erased_at: string, };
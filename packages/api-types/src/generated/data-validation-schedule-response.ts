@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Recurring data validation audit of the tenant.
+ */
+export type DataValidationScheduleResponse = { interval_days: number, configured_by_subject: string, next_run_at: string, last_run_at: string | null, updated_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Stored record that failed a data validation audit check.
+ */
+export type DataValidationViolationResponse = { entity_logical_name: string, record_id: string, rule: "unknown_field" | "invalid_value" | "missing_required_field" | "business_rule", field_logical_name: string | null, business_rule_logical_name: string | null, message: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming dual-control field item.
+ */
+export type DualControlFieldRequest = { field_logical_name: string, approval_window_hours: number | null, };
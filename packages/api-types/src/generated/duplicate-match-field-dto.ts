@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API transport representation of one field compared by a duplicate rule.
+ */
+export type DuplicateMatchFieldDto = { field_logical_name: string, match_mode: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for requesting an account email change.
+ */
+export type EmailChangeRequest = { new_email: string, 
+/**
+ * Current password; may be omitted after a recent step-up verification.
+ */
+current_password?: string, };
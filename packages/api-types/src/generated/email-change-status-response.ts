@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PendingEmailChangeResponse } from "./pending-email-change-response";
+
+/**
+ * Email change state shown on the profile.
+ */
+export type EmailChangeStatusResponse = { pending: PendingEmailChangeResponse | null, };
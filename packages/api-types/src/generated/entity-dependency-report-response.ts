@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EntityDependencyResponse } from "./entity-dependency-response";
+
+/**
+ * Dependency report gating entity deletion.
+ */
+export type EntityDependencyReportResponse = { entity_logical_name: string, is_active: boolean, can_delete: boolean, dependencies: Array<EntityDependencyResponse>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of an entity record history retention policy.
+ */
+export type EntityHistoryPolicyResponse = { entity_logical_name: string, retention_days: number, };
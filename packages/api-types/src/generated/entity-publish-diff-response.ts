@@ -5,4 +5,4 @@ import type { PublishSurfaceDeltaItemResponse } from "./publish-surface-delta-it
 /**
  * Entity-level publish diff summary.
  */
-export type EntityPublishDiffResponse = { entity_logical_name: string, published_schema_exists: boolean, field_diff: Array<PublishFieldDiffItemResponse>, forms: Array<PublishSurfaceDeltaItemResponse>, views: Array<PublishSurfaceDeltaItemResponse>, };
+export type EntityPublishDiffResponse = { entity_logical_name: string, draft_fingerprint: string, published_schema_exists: boolean, field_diff: Array<PublishFieldDiffItemResponse>, forms: Array<PublishSurfaceDeltaItemResponse>, views: Array<PublishSurfaceDeltaItemResponse>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Compatibility report for republishing an earlier schema version.
+ */
+export type EntitySchemaRollbackChecksResponse = { entity_logical_name: string, current_version: number, target_version: number, can_rollback: boolean, orphaned_fields: Array<string>, errors: Array<string>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of an entity slug configuration.
+ */
+export type EntitySlugConfigResponse = { entity_logical_name: string, slug_field_logical_name: string, source_field_logical_name: string, };
@@ -3,4 +3,9 @@
 /**
  * API error payload.
  */
-export type ErrorResponse = { code: string, message: string, };
+export type ErrorResponse = { code: string, message: string, 
+/**
+ * Structured context for errors clients can act on, such as the
+ * candidates of a duplicate-record conflict.
+ */
+details?: unknown, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a feature flag resolved for the caller.
+ */
+export type EvaluatedFeatureFlagResponse = { flag_key: string, enabled: boolean, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RuntimeRecordChangesetOperationRequest } from "./runtime-record-changeset-operation-request";
+
+/**
+ * Incoming multi-entity runtime record changeset payload.
+ */
+export type ExecuteRuntimeRecordChangesetRequest = { operations: Array<RuntimeRecordChangesetOperationRequest>, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for failing one stuck workflow job.
+ */
+export type FailWorkflowJobRequest = { 
+/**
+ * Dead-letter reason recorded on the run.
+ */
+reason: string | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FeatureFlagRoleOverrideResponse } from "./feature-flag-role-override-response";
+
+/**
+ * API representation of a tenant feature flag.
+ */
+export type FeatureFlagResponse = { flag_key: string, description: string | null, enabled: boolean, role_overrides: Array<FeatureFlagRoleOverrideResponse>, updated_by_subject: string, updated_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Flag state for holders of one role.
+ */
+export type FeatureFlagRoleOverrideRequest = { role_name: string, enabled: boolean, };
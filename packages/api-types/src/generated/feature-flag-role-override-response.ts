@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a role override of a feature flag.
+ */
+export type FeatureFlagRoleOverrideResponse = { role_name: string, enabled: boolean, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FieldImpactResponse } from "./field-impact-response";
+
+/**
+ * Impact analysis reviewed before updating or deleting a field.
+ */
+export type FieldImpactReportResponse = { entity_logical_name: string, field_logical_name: string, highest_severity: string | null, impacts: Array<FieldImpactResponse>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One component referencing a field.
+ */
+export type FieldImpactResponse = { severity: string, kind: string, component_key: string, detail: string, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One classification and its masking mode in a role's masking rules.
+ */
+export type FieldMaskingRuleRequest = { 
+/**
+ * `pii`, `phi` or `confidential`.
+ */
+classification: string, 
+/**
+ * `full`, `partial` or `hidden`.
+ */
+masking_mode: string, };
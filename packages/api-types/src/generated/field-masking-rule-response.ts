@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of how a role sees one field classification.
+ */
+export type FieldMaskingRuleResponse = { role_name: string, classification: string, masking_mode: string, updated_by_subject: string, updated_at: string, };
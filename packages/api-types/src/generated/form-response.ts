@@ -1,6 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FormScriptEventsDto } from "./form-script-events-dto";
 
 /**
  * API response for standalone forms.
  */
-export type FormResponse = { entity_logical_name: string, logical_name: string, display_name: string, form_type: string, tabs: unknown[], header_fields: Array<string>, };
+export type FormResponse = { entity_logical_name: string, logical_name: string, display_name: string, form_type: string, tabs: unknown[], header_fields: Array<string>, script_events: FormScriptEventsDto, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Client scripting hooks declared for a form.
+ */
+export type FormScriptEventsDto = { on_load_actions: Array<string>, on_change_fields: Array<string>, on_save_validators: Array<string>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for granting delegated app administration.
+ */
+export type GrantAppAdminRequest = { subject: string, };
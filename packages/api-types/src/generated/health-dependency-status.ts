@@ -3,4 +3,4 @@
 /**
  * One runtime dependency health status.
  */
-export type HealthDependencyStatus = { status: string, detail: string | null, };
+export type HealthDependencyStatus = { status: "ok" | "error" | "disabled", detail: string | null, latency_ms: number | null, };
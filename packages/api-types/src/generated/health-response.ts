@@ -2,6 +2,6 @@
 import type { HealthDependencyStatus } from "./health-dependency-status";
 
 /**
- * Health response payload.
+ * Readiness response payload with one probe result per dependency.
  */
-export type HealthResponse = { status: string, ready: boolean, postgres: HealthDependencyStatus, redis: HealthDependencyStatus, };
+export type HealthResponse = { status: "ok" | "degraded", ready: boolean, checked_at: string, postgres: HealthDependencyStatus, redis: HealthDependencyStatus, smtp: HealthDependencyStatus, session_store: HealthDependencyStatus, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a legal hold.
+ */
+export type LegalHoldResponse = { hold_id: string, name: string, reason: string, scope_type: string, entity_logical_name: string | null, record_ids: Array<string>, audit_from: string | null, audit_until: string | null, created_by_subject: string, created_at: string, released_by_subject: string | null, released_at: string | null, is_active: boolean, };
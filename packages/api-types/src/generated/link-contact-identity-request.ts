@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for manually linking a record to a master contact.
+ */
+export type LinkContactIdentityRequest = { master_contact_id: string, };
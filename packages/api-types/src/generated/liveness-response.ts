@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Liveness response payload; never probes dependencies.
+ */
+export type LivenessResponse = { status: "ok", version: string, uptime_seconds: number, };
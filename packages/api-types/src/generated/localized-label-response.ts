@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a localized display label.
+ */
+export type LocalizedLabelResponse = { component_type: string, component_key: string, locale: string, display_name: string, description: string | null, };
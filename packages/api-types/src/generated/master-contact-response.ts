@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ContactIdentityLinkResponse } from "./contact-identity-link-response";
+
+/**
+ * API representation of all records resolved to one master contact.
+ */
+export type MasterContactResponse = { master_contact_id: string, links: Array<ContactIdentityLinkResponse>, };
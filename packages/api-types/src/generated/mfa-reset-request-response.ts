@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of an administrator MFA reset request.
+ */
+export type MfaResetRequestResponse = { request_id: string, subject: string, reason: string, requested_by_subject: string, requested_at: string, expires_at: string, approved_by_subject: string | null, approved_at: string | null, };
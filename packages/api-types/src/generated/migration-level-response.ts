@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Applied versus embedded database migration level.
+ */
+export type MigrationLevelResponse = { applied_version: number | null, applied_description: string | null, expected_version: number, pending_count: number, up_to_date: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Entry of the global operator log.
+ */
+export type OperatorAuditEventResponse = { event_id: string, operator_id: string, action: string, tenant_id: string | null, detail: string, created_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of a tenant maintenance action.
+ */
+export type OperatorMaintenanceResponse = { tenant_id: string, action: "release_stuck_workflow_jobs" | "fail_stuck_workflow_jobs" | "flush_view_cache", affected: number, };
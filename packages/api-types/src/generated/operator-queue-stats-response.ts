@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Workflow queue and worker stats across all tenants.
+ */
+export type OperatorQueueStatsResponse = { pending_jobs: number, leased_jobs: number, completed_jobs: number, failed_jobs: number, expired_leases: number, active_workers: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Tenant with health and usage counters for the operator console.
+ */
+export type OperatorTenantResponse = { tenant_id: string, name: string, created_at: string, health: "healthy" | "degraded", member_count: number, entity_count: number, runtime_record_count: number, pending_workflow_jobs: number, failed_workflow_jobs_last_day: number, expired_workflow_leases: number, last_activity_at: string | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OperatorUserMembershipResponse } from "./operator-user-membership-response";
+
+/**
+ * User found by an operator email search.
+ */
+export type OperatorUserLookupResponse = { user_id: string, email: string, email_verified: boolean, mfa_enabled: boolean, locked_until: string | null, memberships: Array<OperatorUserMembershipResponse>, };
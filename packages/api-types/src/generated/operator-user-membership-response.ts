@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Tenant membership of a user found by an operator search.
+ */
+export type OperatorUserMembershipResponse = { tenant_id: string, tenant_name: string, display_name: string, email: string | null, };
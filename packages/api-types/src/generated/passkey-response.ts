@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Registered passkey shown on the security profile.
+ */
+export type PasskeyResponse = { passkey_id: string, name: string, created_at: string, last_used_at: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Email change awaiting confirmation from the current and new addresses.
+ */
+export type PendingEmailChangeResponse = { current_email: string, new_email: string, current_address_confirmed: boolean, new_address_confirmed: boolean, requested_at: string, expires_at: string, };
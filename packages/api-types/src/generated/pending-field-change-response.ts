@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a dual-control field change awaiting or past approval.
+ */
+export type PendingFieldChangeResponse = { change_id: string, entity_logical_name: string, record_id: string, field_logical_name: string, previous_value: unknown, proposed_value: unknown, requested_by_subject: string, requested_at: string, expires_at: string, status: "pending" | "approved" | "rejected" | "expired", decided_by_subject: string | null, decided_at: string | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Permission that custom roles can grant.
+ */
+export type PermissionCatalogEntryResponse = { permission: string, description: string, 
+/**
+ * Permissions a role must also grant alongside this one.
+ */
+implies: Array<string>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PermissionCatalogEntryResponse } from "./permission-catalog-entry-response";
+
+/**
+ * Permissions of one functional area, in catalog order.
+ */
+export type PermissionCatalogGroupResponse = { group: string, label: string, permissions: Array<PermissionCatalogEntryResponse>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API response for personal views.
+ */
+export type PersonalViewResponse = { view_id: string, app_logical_name: string, entity_logical_name: string, owner_subject: string, display_name: string, columns: unknown[], default_sort: unknown | null, filter_criteria: unknown | null, is_shared: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Queued or resolved publish intent.
+ */
+export type PublishIntentResponse = { intent_id: string, requested_by_subject: string, status: string, status_message: string | null, entity_logical_names: Array<string>, app_logical_names: Array<string>, workflow_logical_names: Array<string>, created_at: string, resolved_at: string | null, };
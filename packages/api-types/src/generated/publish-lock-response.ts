@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PublishCheckScopeDto } from "./publish-check-scope-dto";
+
+/**
+ * Publish lock held by a running workspace publish.
+ */
+export type PublishLockResponse = { scope: PublishCheckScopeDto, logical_name: string, holder_subject: string, acquired_at: string, expires_at: string, };
@@ -5,4 +5,8 @@ import type { OptionSetResponse } from "./option-set-response";
 /**
  * API representation of a published schema snapshot.
  */
-export type PublishedSchemaResponse = { entity_logical_name: string, entity_display_name: string, version: number, fields: Array<FieldResponse>, option_sets: Array<OptionSetResponse>, };
+export type PublishedSchemaResponse = { entity_logical_name: string, entity_display_name: string, entity_icon: string | null, entity_color: string | null, version: number, fields: Array<FieldResponse>, 
+/**
+ * Read-only platform fields (`created_by`, `created_at`, `modified_by`, `modified_at`, `owner`).
+ */
+system_fields: Array<FieldResponse>, option_sets: Array<OptionSetResponse>, };
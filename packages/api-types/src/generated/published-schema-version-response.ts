@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PublishedSchemaResponse } from "./published-schema-response";
+
+/**
+ * One stored published schema version with its publish metadata.
+ */
+export type PublishedSchemaVersionResponse = { published_by_subject: string, published_at: string, checksum_sha256: string, schema: PublishedSchemaResponse, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Summary of one stored published schema version.
+ */
+export type PublishedSchemaVersionSummaryResponse = { version: number, published_by_subject: string, published_at: string, field_count: number, checksum_sha256: string, };
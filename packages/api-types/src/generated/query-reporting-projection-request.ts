@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReportingProjectionFilterRequest } from "./reporting-projection-filter-request";
+
+/**
+ * Incoming payload for paging through projection rows.
+ */
+export type QueryReportingProjectionRequest = { filters: Array<ReportingProjectionFilterRequest>, sort_column: string | null, sort_descending: boolean, limit: number | null, offset: number | null, };
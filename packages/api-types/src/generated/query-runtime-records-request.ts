@@ -11,4 +11,8 @@ export type QueryRuntimeRecordsRequest = { limit: number | null, offset: number
 /**
  * Legacy exact-match map; converted to `eq` conditions when present.
  */
-filters: Record<string, unknown> | null, };
+filters: Record<string, unknown> | null, 
+/**
+ * Returns records in inactive statuses too; defaults to `false`.
+ */
+include_inactive: boolean | null, };
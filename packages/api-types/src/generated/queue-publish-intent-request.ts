@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReviewedDraftFingerprintDto } from "./reviewed-draft-fingerprint-dto";
+
+/**
+ * Request payload for queueing a publish behind the locks of another run.
+ */
+export type QueuePublishIntentRequest = { entity_logical_names: Array<string>, app_logical_names: Array<string>, workflow_logical_names: Array<string>, reviewed_drafts: Array<ReviewedDraftFingerprintDto>, };
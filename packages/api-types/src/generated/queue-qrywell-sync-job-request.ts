@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for queueing a Qrywell sync job.
+ */
+export type QueueQrywellSyncJobRequest = { 
+/**
+ * Entities to sync; every entity when omitted.
+ */
+entity_logical_names: Array<string> | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming runtime record quick-create payload.
+ */
+export type QuickCreateRuntimeRecordRequest = { 
+/**
+ * Optional published quick-create form; defaults to the entity's first one.
+ */
+form_logical_name: string | null, data: Record<string, unknown>, };
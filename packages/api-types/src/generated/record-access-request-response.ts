@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a record access request.
+ */
+export type RecordAccessRequestResponse = { request_id: string, entity_logical_name: string, record_id: string, requested_by_subject: string, reason: string, duration_hours: number, routed_to_subject: string, status: "pending" | "approved" | "rejected", requested_at: string, decided_by_subject: string | null, decided_at: string | null, share_id: string | null, };
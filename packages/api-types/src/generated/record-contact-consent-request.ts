@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for capturing a contact consent change.
+ */
+export type RecordContactConsentRequest = { purpose: string, channel: string, status: string, source: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Source column mapped onto an entity field.
+ */
+export type RecordImportColumnMappingDto = { source_column: string, field_logical_name: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Row of an import file that was not imported.
+ */
+export type RecordImportRowErrorResponse = { line_number: number, message: string, };
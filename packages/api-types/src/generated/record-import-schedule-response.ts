@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Recurring import of the tenant with its last run.
+ */
+export type RecordImportScheduleResponse = { schedule_id: string, name: string, entity_logical_name: string, template_id: string | null, source_kind: "https" | "sftp", source_url: string, auth_header_name: string | null, auth_secret_ref: string | null, frequency: "hourly" | "daily" | "weekly", is_enabled: boolean, owner_subject: string, owner_email: string, next_run_at: string, last_run_at: string | null, last_run_status: "imported" | "unchanged" | "failed" | null, last_imported_count: number, last_failed_count: number, last_error: string | null, created_at: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecordImportRowErrorResponse } from "./record-import-row-error-response";
+
+/**
+ * Outcome of importing one CSV file.
+ */
+export type RecordImportSummaryResponse = { imported_count: number, failed_count: number, row_errors: Array<RecordImportRowErrorResponse>, };
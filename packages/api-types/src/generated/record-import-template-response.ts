@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecordImportColumnMappingDto } from "./record-import-column-mapping-dto";
+
+/**
+ * Saved import mapping template of one entity.
+ */
+export type RecordImportTemplateResponse = { template_id: string, entity_logical_name: string, name: string, column_mappings: Array<RecordImportColumnMappingDto>, updated_by_subject: string, updated_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of an external record share link.
+ */
+export type RecordShareLinkResponse = { link_id: string, entity_logical_name: string, record_id: string, field_logical_names: Array<string>, password_protected: boolean, created_by_subject: string, created_at: string, expires_at: string, revoked_by_subject: string | null, revoked_at: string | null, view_count: bigint, last_viewed_at: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of one external access attempt on a share link.
+ */
+export type RecordShareLinkViewResponse = { link_id: string, outcome: "viewed" | "password_required" | "invalid_password" | "expired" | "revoked" | "unavailable", ip_address: string | null, user_agent: string | null, viewed_at: string, };
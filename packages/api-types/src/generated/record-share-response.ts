@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a time-boxed record share.
+ */
+export type RecordShareResponse = { share_id: string, entity_logical_name: string, record_id: string, subject: string, request_id: string, granted_by_subject: string, created_at: string, expires_at: string, revoked_by_subject: string | null, revoked_at: string | null, };
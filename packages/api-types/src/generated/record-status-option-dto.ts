@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API transport representation of one status in a status model.
+ */
+export type RecordStatusOptionDto = { value: string, label: string, reasons: Array<string>, inactive: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of relation cascade behaviors for one relation field.
+ */
+export type RelationBehaviorResponse = { entity_logical_name: string, field_logical_name: string, target_entity_logical_name: string, assign_behavior: string, share_behavior: string, delete_behavior: string, };
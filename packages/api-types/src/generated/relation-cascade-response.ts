@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of child records reached through one relation cascade.
+ */
+export type RelationCascadeResponse = { entity_logical_name: string, field_logical_name: string, affected_records: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of the lookup search configuration of a relation field.
+ */
+export type RelationLookupConfigResponse = { entity_logical_name: string, field_logical_name: string, target_entity_logical_name: string, search_field_logical_names: Array<string>, filter: unknown | null, };
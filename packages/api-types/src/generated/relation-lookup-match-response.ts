@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of one relation lookup search result.
+ */
+export type RelationLookupMatchResponse = { record_id: string, label: string, data: Record<string, unknown>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Upstream release advisory for the running build.
+ */
+export type ReleaseAdvisoryResponse = { status: string, latest_version: string | null, security_fixes_available: boolean, notes_url: string | null, checked_at: string | null, detail: string | null, };
@@ -3,4 +3,4 @@
 /**
  * Incoming payload for role unassignment.
  */
-export type RemoveRoleAssignmentRequest = { subject: string, role_name: string, };
+export type RemoveRoleAssignmentRequest = { subject_type: string | null, subject: string, role_name: string, };
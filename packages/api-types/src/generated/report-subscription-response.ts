@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Scheduled report subscription owned by the caller.
+ */
+export type ReportSubscriptionResponse = { subscription_id: string, entity_logical_name: string, view_logical_name: string, frequency: "daily" | "weekly", format: "csv" | "xlsx", delivery_hour_utc: number, recipient_email: string, next_delivery_at: string, last_delivered_at: string | null, suppressed_at: string | null, suppression_reason: string | null, created_at: string, };
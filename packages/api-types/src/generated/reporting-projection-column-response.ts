@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Column of a saved reporting projection.
+ */
+export type ReportingProjectionColumnResponse = { column_name: string, relation_field_logical_name: string | null, parent_entity_logical_name: string | null, field_logical_name: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Equality filter on one projection column.
+ */
+export type ReportingProjectionFilterRequest = { column_name: string, value: unknown, };
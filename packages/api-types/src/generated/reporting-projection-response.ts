@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReportingProjectionColumnResponse } from "./reporting-projection-column-response";
+
+/**
+ * Reporting projection definition.
+ */
+export type ReportingProjectionResponse = { projection_id: string, logical_name: string, display_name: string, root_entity_logical_name: string, source_entity_logical_names: Array<string>, columns: Array<ReportingProjectionColumnResponse>, updated_by_subject: string, updated_at: string, };
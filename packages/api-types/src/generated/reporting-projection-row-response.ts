@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One row of a reporting projection.
+ */
+export type ReportingProjectionRowResponse = { record_id: string, values: Record<string, unknown>, refreshed_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for requesting access to a runtime record.
+ */
+export type RequestRecordAccessRequest = { reason: string, duration_hours: number | null, };
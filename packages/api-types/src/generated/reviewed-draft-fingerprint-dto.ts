@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PublishCheckScopeDto } from "./publish-check-scope-dto";
+
+/**
+ * Draft fingerprint of one component as shown in the publish diff.
+ */
+export type ReviewedDraftFingerprintDto = { scope: PublishCheckScopeDto, logical_name: string, draft_fingerprint: string, };
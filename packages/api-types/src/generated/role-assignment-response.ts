@@ -3,4 +3,4 @@
 /**
  * API representation of a role assignment.
  */
-export type RoleAssignmentResponse = { subject: string, role_id: string, role_name: string, assigned_at: string, };
+export type RoleAssignmentResponse = { subject_type: string, subject: string, role_id: string, role_name: string, assigned_at: string, };
@@ -1,6 +1,11 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LocalizedLabelResponse } from "./localized-label-response";
 
 /**
  * API representation of an RBAC role.
  */
-export type RoleResponse = { role_id: string, name: string, is_system: boolean, permissions: Array<string>, };
+export type RoleResponse = { role_id: string, name: string, is_system: boolean, permissions: Array<string>, 
+/**
+ * Label matching the caller's `Accept-Language` preferences, when one exists.
+ */
+localized_label: LocalizedLabelResponse | null, };
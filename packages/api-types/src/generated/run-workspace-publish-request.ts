@@ -1,6 +1,11 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReviewedDraftFingerprintDto } from "./reviewed-draft-fingerprint-dto";
 
 /**
  * Request payload for selective workspace publish execution.
  */
-export type RunWorkspacePublishRequest = { entity_logical_names: Array<string>, app_logical_names: Array<string>, workflow_logical_names: Array<string>, dry_run: boolean, };
+export type RunWorkspacePublishRequest = { entity_logical_names: Array<string>, app_logical_names: Array<string>, workflow_logical_names: Array<string>, dry_run: boolean, 
+/**
+ * Draft fingerprints from the reviewed diff; the run fails if any draft changed since.
+ */
+reviewed_drafts: Array<ReviewedDraftFingerprintDto>, };
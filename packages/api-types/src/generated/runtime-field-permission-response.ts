@@ -3,4 +3,4 @@
 /**
  * API representation of runtime field permission entry.
  */
-export type RuntimeFieldPermissionResponse = { subject: string, entity_logical_name: string, field_logical_name: string, can_read: boolean, can_write: boolean, updated_at: string, };
+export type RuntimeFieldPermissionResponse = { subject_type: string, subject: string, entity_logical_name: string, field_logical_name: string, can_read: boolean, can_write: boolean, updated_at: string, };
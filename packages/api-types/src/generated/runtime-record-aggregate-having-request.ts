@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Condition on an aggregate value in a runtime record aggregate query.
+ */
+export type RuntimeRecordAggregateHavingRequest = { aggregate_alias: string, operator: "eq" | "neq" | "gt" | "gte" | "lt" | "lte", value: number, };
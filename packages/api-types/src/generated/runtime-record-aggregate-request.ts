@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One named aggregate in a runtime record aggregate query.
+ */
+export type RuntimeRecordAggregateRequest = { alias: string, function: "count" | "sum" | "avg" | "min" | "max", 
+/**
+ * Aggregated field; omitted for `count` to count records.
+ */
+field_logical_name: string | null, };
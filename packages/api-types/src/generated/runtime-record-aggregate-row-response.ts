@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of one runtime record aggregate group.
+ */
+export type RuntimeRecordAggregateRowResponse = { group: Record<string, unknown>, values: Record<string, unknown>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Sort entry in a runtime record aggregate query; set exactly one of the keys.
+ */
+export type RuntimeRecordAggregateSortRequest = { field_logical_name: string | null, aggregate_alias: string | null, direction: "asc" | "desc" | null, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One operation in a runtime record changeset.
+ *
+ * String values equal to `$<content_id>` resolve to the record id created by
+ * an earlier operation with that content id.
+ */
+export type RuntimeRecordChangesetOperationRequest = { content_id: string | null, method: "create" | "update", entity_logical_name: string, record_id: string | null, data: Record<string, unknown>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RuntimeRecordResponse } from "./runtime-record-response";
+
+/**
+ * API representation of one applied runtime record changeset operation.
+ */
+export type RuntimeRecordChangesetResultResponse = { content_id: string | null, method: "create" | "update", record: RuntimeRecordResponse, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of one field changed by a runtime record update.
+ */
+export type RuntimeRecordFieldChangeResponse = { field_logical_name: string, 
+/**
+ * Value before the update; omitted when the field was unset.
+ */
+old_value?: unknown, 
+/**
+ * Value after the update; omitted when the field was cleared.
+ */
+new_value?: unknown, };
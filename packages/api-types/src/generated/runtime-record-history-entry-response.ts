@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RuntimeRecordFieldChangeResponse } from "./runtime-record-field-change-response";
+
+/**
+ * API representation of one entry of a runtime record's field history.
+ */
+export type RuntimeRecordHistoryEntryResponse = { entry_id: string, entity_logical_name: string, record_id: string, record_version: number, changes: Array<RuntimeRecordFieldChangeResponse>, changed_by_subject: string, changed_at: string, };
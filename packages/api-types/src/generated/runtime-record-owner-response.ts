@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RelationCascadeResponse } from "./relation-cascade-response";
+
+/**
+ * API representation of a runtime record ownership transfer.
+ */
+export type RuntimeRecordOwnerResponse = { entity_logical_name: string, record_id: string, previous_owner_subject: string, owner_subject: string, cascades: Array<RelationCascadeResponse>, };
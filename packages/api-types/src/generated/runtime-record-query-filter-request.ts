@@ -3,4 +3,4 @@
 /**
  * Incoming runtime record query payload.
  */
-export type RuntimeRecordQueryFilterRequest = { scope_alias: string | null, field_logical_name: string, operator: "eq" | "neq" | "gt" | "gte" | "lt" | "lte" | "contains" | "in", field_value: unknown, };
+export type RuntimeRecordQueryFilterRequest = { scope_alias: string | null, field_logical_name: string, operator: "eq" | "neq" | "gt" | "gte" | "lt" | "lte" | "contains" | "in" | "not_in" | "is_null" | "is_not_null" | "between" | "begins_with" | "today" | "yesterday" | "this_month" | "last_n_days", field_value: unknown, };
@@ -3,4 +3,8 @@
 /**
  * API representation of a runtime record.
  */
-export type RuntimeRecordResponse = { record_id: string, entity_logical_name: string, data: Record<string, unknown>, };
+export type RuntimeRecordResponse = { record_id: string, entity_logical_name: string, data: Record<string, unknown>, 
+/**
+ * Record version, incremented on every data change.
+ */
+version: number, };
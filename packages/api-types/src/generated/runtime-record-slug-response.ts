@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a resolved runtime record slug.
+ */
+export type RuntimeRecordSlugResponse = { entity_logical_name: string, slug: string, record_id: string, };
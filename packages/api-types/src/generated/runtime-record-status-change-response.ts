@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of one entry of a runtime record's status history.
+ */
+export type RuntimeRecordStatusChangeResponse = { change_id: string, entity_logical_name: string, record_id: string, from_status: string | null, to_status: string, from_reason: string | null, to_reason: string | null, changed_by_subject: string, changed_at: string, };
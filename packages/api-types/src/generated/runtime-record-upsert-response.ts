@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RuntimeRecordResponse } from "./runtime-record-response";
+
+/**
+ * API representation of a runtime record upsert by alternate key.
+ */
+export type RuntimeRecordUpsertResponse = { outcome: "created" | "updated", record: RuntimeRecordResponse, };
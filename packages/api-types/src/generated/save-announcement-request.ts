@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for publishing or editing an announcement banner.
+ */
+export type SaveAnnouncementRequest = { title: string, body: string, severity: "info" | "warning" | "critical", starts_at: string | null, ends_at: string | null, audience_roles: Array<string>, audience_apps: Array<string>, is_dismissible: boolean, };
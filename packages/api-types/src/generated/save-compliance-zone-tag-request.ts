@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload restricting an entity, or one of its records, to a compliance zone.
+ */
+export type SaveComplianceZoneTagRequest = { entity_logical_name: string, record_id: string | null, zone: string, };
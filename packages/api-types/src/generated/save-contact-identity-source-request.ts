@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for registering an entity as a contact identity source.
+ */
+export type SaveContactIdentitySourceRequest = { email_field_logical_name: string | null, phone_field_logical_name: string | null, name_field_logical_names: Array<string>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for replacing an entity's erasure field set.
+ */
+export type SaveDataErasureFieldsRequest = { field_logical_names: Array<string>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DualControlFieldRequest } from "./dual-control-field-request";
+
+/**
+ * Incoming payload replacing the dual-control field set for an entity.
+ */
+export type SaveDualControlFieldsRequest = { fields: Array<DualControlFieldRequest>, };
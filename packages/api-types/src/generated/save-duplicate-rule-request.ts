@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DuplicateMatchFieldDto } from "./duplicate-match-field-dto";
+
+/**
+ * Incoming payload for an entity duplicate-detection rule.
+ */
+export type SaveDuplicateRuleRequest = { display_name: string, match_fields: Array<DuplicateMatchFieldDto>, action: string, is_active: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for an entity record history retention policy.
+ */
+export type SaveEntityHistoryPolicyRequest = { retention_days: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for an entity slug configuration.
+ */
+export type SaveEntitySlugConfigRequest = { slug_field_logical_name: string, source_field_logical_name: string, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecordStatusOptionDto } from "./record-status-option-dto";
+import type { RecordStatusTransitionDto } from "./record-status-transition-dto";
+
+/**
+ * Incoming payload for an entity status model.
+ */
+export type SaveEntityStatusModelRequest = { status_field_logical_name: string, reason_field_logical_name: string | null, initial_status: string, statuses: Array<RecordStatusOptionDto>, transitions: Array<RecordStatusTransitionDto>, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FeatureFlagRoleOverrideRequest } from "./feature-flag-role-override-request";
+
+/**
+ * Incoming payload for creating or updating a feature flag.
+ */
+export type SaveFeatureFlagRequest = { description?: string, 
+/**
+ * Flag state for subjects without an overridden role.
+ */
+enabled: boolean, 
+/**
+ * Replaces the existing role overrides.
+ */
+role_overrides: Array<FeatureFlagRoleOverrideRequest>, };
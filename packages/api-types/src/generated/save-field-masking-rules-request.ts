@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FieldMaskingRuleRequest } from "./field-masking-rule-request";
+
+/**
+ * Incoming payload for replacing the masking rules of a role.
+ */
+export type SaveFieldMaskingRulesRequest = { rules: Array<FieldMaskingRuleRequest>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for saving a localized display label.
+ */
+export type SaveLocalizedLabelRequest = { display_name: string, description: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for personal view create/update.
+ */
+export type SavePersonalViewRequest = { display_name: string, columns: unknown[], default_sort: unknown | null, filter_criteria: unknown | null, is_shared: boolean, };
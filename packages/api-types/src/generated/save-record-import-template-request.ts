@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecordImportColumnMappingDto } from "./record-import-column-mapping-dto";
+
+/**
+ * Incoming payload for creating or updating an import mapping template.
+ */
+export type SaveRecordImportTemplateRequest = { name: string, column_mappings: Array<RecordImportColumnMappingDto>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for relation cascade behaviors.
+ */
+export type SaveRelationBehaviorRequest = { assign_behavior: string, share_behavior: string, 
+/**
+ * Behavior applied to child records when the parent is deleted.
+ * Defaults to `restrict`.
+ */
+delete_behavior?: string, };
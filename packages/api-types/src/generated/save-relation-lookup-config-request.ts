@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload for relation lookup search configuration.
+ */
+export type SaveRelationLookupConfigRequest = { search_field_logical_names: Array<string>, filter: unknown | null, };
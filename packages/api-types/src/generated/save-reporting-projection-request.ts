@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReportingProjectionColumnRequest } from "./reporting-projection-column-request";
+
+/**
+ * Incoming payload for creating or redefining a reporting projection.
+ */
+export type SaveReportingProjectionRequest = { display_name: string, root_entity_logical_name: string, columns: Array<ReportingProjectionColumnRequest>, };
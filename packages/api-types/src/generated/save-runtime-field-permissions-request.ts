@@ -4,4 +4,8 @@ import type { RuntimeFieldPermissionInputRequest } from "./runtime-field-permiss
 /**
  * Incoming payload for runtime subject field permission updates.
  */
-export type SaveRuntimeFieldPermissionsRequest = { subject: string, entity_logical_name: string, fields: Array<RuntimeFieldPermissionInputRequest>, };
+export type SaveRuntimeFieldPermissionsRequest = { 
+/**
+ * `user` (default) or `team`; team subjects are addressed by team name.
+ */
+subject_type: string | null, subject: string, entity_logical_name: string, fields: Array<RuntimeFieldPermissionInputRequest>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WorkflowCredentialAuthDto } from "./workflow-credential-auth-dto";
+
+/**
+ * Incoming payload for creating or replacing a workflow credential.
+ */
+export type SaveWorkflowCredentialRequest = { display_name: string, auth: WorkflowCredentialAuthDto, allowed_hosts: Array<string>, };
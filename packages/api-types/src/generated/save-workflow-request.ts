@@ -1,7 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { WorkflowStepDto } from "./workflow-step-dto";
+import type { WorkflowTriggerFilterDto } from "./workflow-trigger-filter-dto";
 
 /**
  * Incoming payload for workflow create/update.
  */
-export type SaveWorkflowRequest = { logical_name: string, display_name: string, description: string | null, trigger_type: string, trigger_entity_logical_name: string | null, steps: Array<WorkflowStepDto>, max_attempts: number | null, };
+export type SaveWorkflowRequest = { logical_name: string, display_name: string, description: string | null, trigger_type: string, trigger_entity_logical_name: string | null, steps: Array<WorkflowStepDto>, max_attempts: number | null, trigger_filters: Array<WorkflowTriggerFilterDto>, trigger_changed_fields: Array<string>, trigger_view_logical_name: string | null, priority: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a security team member.
+ */
+export type SecurityTeamMemberResponse = { team_name: string, subject: string, added_by_subject: string, added_at: string, };
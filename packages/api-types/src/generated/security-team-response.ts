@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a security team.
+ */
+export type SecurityTeamResponse = { team_id: string, name: string, description: string | null, member_count: number, created_by_subject: string, created_at: string, };
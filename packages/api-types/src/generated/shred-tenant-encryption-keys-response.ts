@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of tenant encryption key crypto-shredding result.
+ */
+export type ShredTenantEncryptionKeysResponse = { shredded_versions: bigint, };
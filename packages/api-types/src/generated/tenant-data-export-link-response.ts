@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Newly issued download link of a tenant data export.
+ *
+ * `download_path` embeds the raw token and is only returned once.
+ */
+export type TenantDataExportLinkResponse = { link_id: string, job_id: string, download_path: string, expires_at: string, created_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of one tenant encryption key version.
+ */
+export type TenantEncryptionKeyResponse = { version: number, secret_reference: string | null, key_fingerprint: string, status: string, created_by_subject: string, created_at: string, retired_at: string | null, shredded_at: string | null, };
@@ -1,6 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CapabilityFlagsResponse } from "./capability-flags-response";
 
 /**
  * One tenant available to the authenticated user.
  */
-export type TenantOptionResponse = { tenant_id: string, tenant_name: string, display_name: string, email: string | null, accessible_surfaces: Array<string>, is_current: boolean, is_default: boolean, };
+export type TenantOptionResponse = { tenant_id: string, tenant_name: string, display_name: string, email: string | null, accessible_surfaces: Array<string>, capabilities: CapabilityFlagsResponse, is_current: boolean, is_default: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload pausing or resuming an audit export sink.
+ */
+export type UpdateAuditExportSinkRequest = { is_enabled: boolean, };
@@ -3,4 +3,4 @@
 /**
  * Incoming payload for entity update.
  */
-export type UpdateEntityRequest = { display_name: string, description: string | null, plural_display_name: string | null, icon: string | null, };
+export type UpdateEntityRequest = { display_name: string, description: string | null, plural_display_name: string | null, icon: string | null, color: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming payload pausing or resuming a recurring import.
+ */
+export type UpdateRecordImportScheduleRequest = { is_enabled: boolean, };
@@ -3,4 +3,12 @@
 /**
  * Incoming runtime record update payload.
  */
-export type UpdateRuntimeRecordRequest = { data: Record<string, unknown>, };
+export type UpdateRuntimeRecordRequest = { data: Record<string, unknown>, 
+/**
+ * Version the client last read; a stale version fails with a conflict.
+ */
+expected_version: number | null, 
+/**
+ * Confirms the write despite matches of warning duplicate rules.
+ */
+ignore_duplicate_warnings?: boolean, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Incoming runtime record upsert-by-alternate-key payload.
+ */
+export type UpsertRuntimeRecordRequest = { 
+/**
+ * Record payload; the key field may be omitted and is set from the path.
+ */
+data: Record<string, unknown>, 
+/**
+ * Confirms the write despite matches of warning duplicate rules.
+ */
+ignore_duplicate_warnings?: boolean, };
@@ -1,7 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CapabilityFlagsResponse } from "./capability-flags-response";
 import type { TenantOptionResponse } from "./tenant-option-response";
 
 /**
  * API representation of the authenticated user.
  */
-export type UserIdentityResponse = { subject: string, display_name: string, email: string | null, tenant_id: string, accessible_surfaces: Array<string>, available_tenants: Array<TenantOptionResponse>, };
+export type UserIdentityResponse = { subject: string, display_name: string, email: string | null, tenant_id: string, accessible_surfaces: Array<string>, capabilities: CapabilityFlagsResponse, available_tenants: Array<TenantOptionResponse>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MigrationLevelResponse } from "./migration-level-response";
+import type { ReleaseAdvisoryResponse } from "./release-advisory-response";
+
+/**
+ * Build, schema and release currency of one API instance.
+ */
+export type VersionResponse = { version: string, commit: string | null, migrations: MigrationLevelResponse, advisory: ReleaseAdvisoryResponse, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Record count a bulk workflow execution would target.
+ */
+export type WorkflowBulkExecutionPreviewResponse = { workflow_logical_name: string, entity_logical_name: string, view_logical_name: string | null, record_count: number, max_records: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { QueryRuntimeRecordsRequest } from "./query-runtime-records-request";
+
+/**
+ * Incoming payload selecting the records of a bulk workflow execution.
+ *
+ * Records are selected by a saved view or by a runtime record query; when
+ * neither is set every active record of the entity is selected.
+ */
+export type WorkflowBulkExecutionRequest = { entity_logical_name: string, view_logical_name: string | null, 
+/**
+ * Runtime record query; `limit` sets the page size used to resolve it.
+ */
+query: QueryRuntimeRecordsRequest | null, 
+/**
+ * Record count confirmed from a preview; required to start the execution.
+ */
+expected_record_count: number | null, };
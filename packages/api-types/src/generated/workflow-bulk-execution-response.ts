@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of one bulk workflow execution and its run summary.
+ */
+export type WorkflowBulkExecutionResponse = { bulk_execution_id: string, workflow_logical_name: string, entity_logical_name: string, view_logical_name: string | null, total_records: number, requested_by_subject: string, created_at: string, pending_runs: number, succeeded_runs: number, dead_lettered_runs: number, completed_at: string | null, };
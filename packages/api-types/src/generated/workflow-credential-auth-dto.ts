@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Authentication scheme of a workflow credential; secrets are secret-provider references.
+ */
+export type WorkflowCredentialAuthDto = { "type": "api_key", header_name: string, prefix: string | null, secret_ref: string, } | { "type": "oauth2_client_credentials", token_url: string, client_id: string, client_secret_ref: string, scope: string | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WorkflowCredentialAuthDto } from "./workflow-credential-auth-dto";
+
+/**
+ * API representation of a workflow credential.
+ */
+export type WorkflowCredentialResponse = { logical_name: string, display_name: string, auth: WorkflowCredentialAuthDto, allowed_hosts: Array<string>, updated_by_subject: string, updated_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Per-step retry policy for HTTP request steps.
+ */
+export type WorkflowHttpRetryPolicyDto = { max_attempts: number, backoff_ms: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of a workflow inbound webhook without its credentials.
+ */
+export type WorkflowInboundWebhookResponse = { workflow_logical_name: string, payload_schema: Record<string, unknown> | null, created_by_subject: string, created_at: string, delivery_count: number, last_received_at: string | null, };
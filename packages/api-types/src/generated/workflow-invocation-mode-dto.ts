@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Invoke workflow step modes exposed through workflow DTOs.
+ */
+export type WorkflowInvocationModeDto = "synchronous" | "enqueued";
@@ -3,4 +3,4 @@
 /**
  * Workflow-level draft-vs-published diff summary.
  */
-export type WorkflowPublishDiffResponse = { workflow_logical_name: string, lifecycle_state: string, published_version: number | null, published_workflow_exists: boolean, draft_trigger_type: string, published_trigger_type: string | null, draft_step_count: number, published_step_count: number, has_changes: boolean, };
+export type WorkflowPublishDiffResponse = { workflow_logical_name: string, draft_fingerprint: string, lifecycle_state: string, published_version: number | null, published_workflow_exists: boolean, draft_trigger_type: string, published_trigger_type: string | null, draft_step_count: number, published_step_count: number, has_changes: boolean, };
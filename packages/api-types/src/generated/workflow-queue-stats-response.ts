@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Tenant-scoped workflow queue stats with cache freshness metadata.
+ */
+export type WorkflowQueueStatsResponse = { pending_jobs: number, leased_jobs: number, completed_jobs: number, failed_jobs: number, expired_leases: number, active_workers: number, computed_at: string, age_seconds: number, served_from_cache: boolean, cache_ttl_seconds: number, };
@@ -1,7 +1,13 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LocalizedLabelResponse } from "./localized-label-response";
 import type { WorkflowStepDto } from "./workflow-step-dto";
+import type { WorkflowTriggerFilterDto } from "./workflow-trigger-filter-dto";
 
 /**
  * API representation of one workflow definition.
  */
-export type WorkflowResponse = { logical_name: string, display_name: string, description: string | null, trigger_type: string, trigger_entity_logical_name: string | null, steps: Array<WorkflowStepDto>, max_attempts: number, lifecycle_state: string, published_version: number | null, is_enabled: boolean, };
+export type WorkflowResponse = { logical_name: string, display_name: string, description: string | null, trigger_type: string, trigger_entity_logical_name: string | null, trigger_filters: Array<WorkflowTriggerFilterDto>, trigger_changed_fields: Array<string>, trigger_view_logical_name: string | null, steps: Array<WorkflowStepDto>, max_attempts: number, priority: string, lifecycle_state: string, published_version: number | null, is_enabled: boolean, 
+/**
+ * Label matching the caller's `Accept-Language` preferences, when one exists.
+ */
+localized_label: LocalizedLabelResponse | null, };
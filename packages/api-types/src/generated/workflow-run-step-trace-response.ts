@@ -3,4 +3,4 @@
 /**
  * API representation of one workflow step execution trace.
  */
-export type WorkflowRunStepTraceResponse = { step_path: string, step_type: string, status: string, input_payload: Record<string, unknown>, output_payload: Record<string, unknown>, error_message: string | null, duration_ms: bigint | null, };
+export type WorkflowRunStepTraceResponse = { step_path: string, step_type: string, status: string, input_payload: Record<string, unknown>, output_payload: Record<string, unknown>, error_message: string | null, duration_ms: bigint | null, retries: number, };
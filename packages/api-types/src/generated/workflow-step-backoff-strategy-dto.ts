@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Backoff strategies for workflow step retry policies.
+ */
+export type WorkflowStepBackoffStrategyDto = "fixed" | "linear" | "exponential";
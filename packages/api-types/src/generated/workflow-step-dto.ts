@@ -1,7 +1,10 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { WorkflowConditionOperatorDto } from "./workflow-condition-operator-dto";
+import type { WorkflowHttpRetryPolicyDto } from "./workflow-http-retry-policy-dto";
+import type { WorkflowInvocationModeDto } from "./workflow-invocation-mode-dto";
+import type { WorkflowStepRetryPolicyDto } from "./workflow-step-retry-policy-dto";
 
 /**
  * One workflow canvas step shape used for API transport.
  */
-export type WorkflowStepDto = { "type": "log_message", message: string, } | { "type": "create_runtime_record", entity_logical_name: string, data: Record<string, unknown>, } | { "type": "update_runtime_record", entity_logical_name: string, record_id: string, data: Record<string, unknown>, } | { "type": "delete_runtime_record", entity_logical_name: string, record_id: string, } | { "type": "send_email", to: string, subject: string, body: string, html_body: string | null, } | { "type": "http_request", method: string, url: string, headers: Record<string, string> | null, header_secret_refs: Record<string, string> | null, body: unknown | null, } | { "type": "webhook", endpoint: string, event: string, headers: Record<string, string> | null, header_secret_refs: Record<string, string> | null, payload: Record<string, unknown>, } | { "type": "assign_owner", entity_logical_name: string, record_id: string, owner_id: string, reason: string | null, } | { "type": "approval_request", entity_logical_name: string, record_id: string, request_type: string, requested_by: string | null, approver_id: string | null, reason: string | null, payload: Record<string, unknown> | null, } | { "type": "delay", duration_ms: number, reason: string | null, } | { "type": "condition", field_path: string, operator: WorkflowConditionOperatorDto, value: unknown | null, then_label: string | null, else_label: string | null, then_steps: Array<WorkflowStepDto>, else_steps: Array<WorkflowStepDto>, };
+export type WorkflowStepDto = { "type": "log_message", message: string, } | { "type": "create_runtime_record", entity_logical_name: string, data: Record<string, unknown>, output_key: string | null, retry_policy: WorkflowStepRetryPolicyDto | null, } | { "type": "update_runtime_record", entity_logical_name: string, record_id: string, data: Record<string, unknown>, retry_policy: WorkflowStepRetryPolicyDto | null, } | { "type": "delete_runtime_record", entity_logical_name: string, record_id: string, retry_policy: WorkflowStepRetryPolicyDto | null, } | { "type": "send_email", to: string, subject: string, body: string, html_body: string | null, retry_policy: WorkflowStepRetryPolicyDto | null, } | { "type": "http_request", method: string, url: string, headers: Record<string, string> | null, header_secret_refs: Record<string, string> | null, credential: string | null, body: unknown | null, expected_status: number | null, retry: WorkflowHttpRetryPolicyDto | null, output_key: string | null, retry_policy: WorkflowStepRetryPolicyDto | null, } | { "type": "webhook", endpoint: string, event: string, headers: Record<string, string> | null, header_secret_refs: Record<string, string> | null, credential: string | null, payload: Record<string, unknown>, retry_policy: WorkflowStepRetryPolicyDto | null, } | { "type": "assign_owner", entity_logical_name: string, record_id: string, owner_id: string, reason: string | null, retry_policy: WorkflowStepRetryPolicyDto | null, } | { "type": "approval_request", entity_logical_name: string, record_id: string, request_type: string, requested_by: string | null, approver_id: string | null, reason: string | null, payload: Record<string, unknown> | null, retry_policy: WorkflowStepRetryPolicyDto | null, } | { "type": "delay", duration_ms: number, reason: string | null, } | { "type": "wait", duration_seconds: number | null, until_field_path: string | null, reason: string | null, } | { "type": "invoke_workflow", workflow_logical_name: string, payload: Record<string, unknown>, mode: WorkflowInvocationModeDto, output_key: string | null, retry_policy: WorkflowStepRetryPolicyDto | null, } | { "type": "try_catch", try_steps: Array<WorkflowStepDto>, catch_steps: Array<WorkflowStepDto>, compensation_steps: Array<WorkflowStepDto>, } | { "type": "condition", field_path: string, operator: WorkflowConditionOperatorDto, value: unknown | null, then_label: string | null, else_label: string | null, then_steps: Array<WorkflowStepDto>, else_steps: Array<WorkflowStepDto>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Error classes a workflow step retry policy can retry.
+ */
+export type WorkflowStepRetryErrorClassDto = "validation" | "not_found" | "conflict" | "unauthorized" | "forbidden" | "rate_limited" | "internal";
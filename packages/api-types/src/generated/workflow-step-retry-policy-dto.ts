@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WorkflowStepBackoffStrategyDto } from "./workflow-step-backoff-strategy-dto";
+import type { WorkflowStepRetryErrorClassDto } from "./workflow-step-retry-error-class-dto";
+
+/**
+ * Retry policy applied when a workflow step fails.
+ */
+export type WorkflowStepRetryPolicyDto = { max_attempts: number, backoff: WorkflowStepBackoffStrategyDto, backoff_ms: number, 
+/**
+ * Retried error classes; rate-limited and internal errors when omitted.
+ */
+retry_on: Array<WorkflowStepRetryErrorClassDto> | null, };
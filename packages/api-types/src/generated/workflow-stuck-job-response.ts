@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * API representation of one queued job still leased after its lease expired.
+ */
+export type WorkflowStuckJobResponse = { job_id: string, run_id: string, workflow_logical_name: string, workflow_version: number, leased_by: string | null, lease_expires_at: string, run_attempts: number, last_error: string | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WorkflowTriggerFilterOperatorDto } from "./workflow-trigger-filter-operator-dto";
+
+/**
+ * One record-change filter evaluated before a triggered run is enqueued.
+ */
+export type WorkflowTriggerFilterDto = { field_logical_name: string, operator: WorkflowTriggerFilterOperatorDto, value: unknown, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Record-change trigger filter operators exposed through workflow DTOs.
+ */
+export type WorkflowTriggerFilterOperatorDto = "changed" | "equals" | "not_equals" | "changed_from" | "changed_to";
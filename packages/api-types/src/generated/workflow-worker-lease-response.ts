@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Current holder of one worker coordination scope.
+ */
+export type WorkflowWorkerLeaseResponse = { scope_key: string, 
+/**
+ * Worker holding the lease; `null` when the scope is not leased.
+ */
+holder_id: string | null, expires_in_seconds: number | null, };
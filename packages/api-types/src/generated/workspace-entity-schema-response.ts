@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FieldResponse } from "./field-response";
+import type { OptionSetResponse } from "./option-set-response";
+import type { WorkspaceFormScriptEventsResponse } from "./workspace-form-script-events-response";
+
+/**
+ * Worker-facing published schema with the form scripting hooks clients must run.
+ */
+export type WorkspaceEntitySchemaResponse = { form_script_events: Array<WorkspaceFormScriptEventsResponse>, entity_logical_name: string, entity_display_name: string, entity_icon: string | null, entity_color: string | null, version: number, fields: Array<FieldResponse>, 
+/**
+ * Read-only platform fields (`created_by`, `created_at`, `modified_by`, `modified_at`, `owner`).
+ */
+system_fields: Array<FieldResponse>, option_sets: Array<OptionSetResponse>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FormScriptEventsDto } from "./form-script-events-dto";
+
+/**
+ * Scripting hooks declared by one published form.
+ */
+export type WorkspaceFormScriptEventsResponse = { form_logical_name: string, form_type: string, script_events: FormScriptEventsDto, };
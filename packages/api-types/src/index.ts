@@ -1,10 +1,27 @@
-export * from "./generated/assign-role-request";
 export * from "./generated/accept-invite-request";
+export * from "./generated/access-explanation-app-permission-response";
+export * from "./generated/access-explanation-field-grant-response";
+export * from "./generated/access-explanation-permission-check-response";
+export * from "./generated/access-explanation-response";
+export * from "./generated/access-explanation-role-response";
+export * from "./generated/assign-compliance-zone-request";
+export * from "./generated/assign-role-request";
+export * from "./generated/assign-runtime-record-owner-request";
+export * from "./generated/associate-runtime-record-request";
+export * from "./generated/add-security-team-member-request";
+export * from "./generated/announcement-response";
+export * from "./generated/app-admin-grant-response";
 export * from "./generated/app-entity-binding-response";
+export * from "./generated/app-entity-capabilities-bulk-response";
 export * from "./generated/app-entity-capabilities-response";
+export * from "./generated/app-entity-capability-summary-response";
+export * from "./generated/app-entity-field-permission-summary-response";
 export * from "./generated/app-entity-form-dto";
 export * from "./generated/app-entity-view-dto";
 export * from "./generated/app-entity-view-mode";
+export * from "./generated/app-navigation-entity-response";
+export * from "./generated/app-navigation-response";
+export * from "./generated/approve-record-access-request";
 export * from "./generated/app-sitemap-area-dto";
 export * from "./generated/app-sitemap-group-dto";
 export * from "./generated/app-sitemap-response";
@@ -19,52 +36,164 @@ export * from "./generated/auth-mfa-verify-request";
 export * from "./generated/auth-register-request";
 export * from "./generated/auth-step-up-request";
 export * from "./generated/auth-switch-tenant-request";
+export * from "./generated/audit-chain-anchor-response";
+export * from "./generated/audit-export-sink-response";
 export * from "./generated/audit-integrity-status-response";
 export * from "./generated/audit-log-entry-response";
+export * from "./generated/background-job-response";
+export * from "./generated/background-job-stage-response";
+export * from "./generated/bootstrap-token-rotation-response";
+export * from "./generated/confirm-email-change-request";
+export * from "./generated/confirm-email-change-response";
+export * from "./generated/email-change-request";
+export * from "./generated/email-change-status-response";
 export * from "./generated/audit-purge-result-response";
 export * from "./generated/audit-retention-policy-response";
+export * from "./generated/workflow-concurrency-policy-response";
 export * from "./generated/bind-app-entity-request";
 export * from "./generated/business-rule-response";
+export * from "./generated/capability-flags-response";
 export * from "./generated/chart-aggregation-dto";
 export * from "./generated/chart-response";
 export * from "./generated/chart-type-dto";
+export * from "./generated/compliance-zone-assignment-response";
+export * from "./generated/compliance-zone-tag-response";
+export * from "./generated/contact-consent-change-response";
+export * from "./generated/contact-consent-response";
+export * from "./generated/contact-identity-link-response";
+export * from "./generated/contact-identity-match-response";
+export * from "./generated/contact-identity-rebuild-response";
+export * from "./generated/contact-identity-source-response";
+export * from "./generated/data-validation-schedule-response";
+export * from "./generated/data-validation-violation-response";
 export * from "./generated/create-app-request";
 export * from "./generated/create-business-rule-request";
 export * from "./generated/create-entity-request";
 export * from "./generated/create-field-request";
 export * from "./generated/create-form-request";
+export * from "./generated/create-legal-hold-request";
+export * from "./generated/create-audit-export-sink-request";
+export * from "./generated/create-mfa-reset-request";
 export * from "./generated/create-option-set-request";
+export * from "./generated/create-record-share-link-request";
+export * from "./generated/create-report-subscription-request";
 export * from "./generated/create-role-request";
 export * from "./generated/create-runtime-record-request";
+export * from "./generated/quick-create-runtime-record-request";
+export * from "./generated/execute-runtime-record-changeset-request";
+export * from "./generated/runtime-record-changeset-operation-request";
+export * from "./generated/runtime-record-changeset-result-response";
+export * from "./generated/upsert-runtime-record-request";
+export * from "./generated/runtime-record-upsert-response";
+export * from "./generated/bulk-update-runtime-records-request";
+export * from "./generated/bulk-update-runtime-record-result-response";
+export * from "./generated/bulk-update-runtime-records-response";
+export * from "./generated/create-security-team-request";
 export * from "./generated/create-temporary-access-grant-request";
 export * from "./generated/create-view-request";
+export * from "./generated/created-record-share-link-response";
+export * from "./generated/data-erasure-field-response";
+export * from "./generated/data-erasure-report-response";
+export * from "./generated/dual-control-field-request";
+export * from "./generated/dual-control-field-response";
+export * from "./generated/entity-dependency-report-response";
+export * from "./generated/entity-dependency-response";
+export * from "./generated/entity-icon-catalog-response";
 export * from "./generated/entity-response";
+export * from "./generated/entity-schema-rollback-checks-response";
+export * from "./generated/erase-subject-data-request";
 export * from "./generated/error-response";
+export * from "./generated/evaluated-feature-flag-response";
 export * from "./generated/execute-workflow-request";
+export * from "./generated/configure-workflow-inbound-webhook-request";
+export * from "./generated/workflow-inbound-webhook-response";
+export * from "./generated/created-workflow-inbound-webhook-response";
+export * from "./generated/workflow-credential-auth-dto";
+export * from "./generated/save-workflow-credential-request";
+export * from "./generated/workflow-credential-response";
 export * from "./generated/retry-workflow-step-request";
 export * from "./generated/retry-workflow-step-strategy-dto";
+export * from "./generated/feature-flag-response";
+export * from "./generated/feature-flag-role-override-request";
+export * from "./generated/feature-flag-role-override-response";
+export * from "./generated/field-impact-report-response";
+export * from "./generated/field-impact-response";
+export * from "./generated/field-masking-rule-request";
+export * from "./generated/field-masking-rule-response";
 export * from "./generated/field-response";
 export * from "./generated/form-response";
+export * from "./generated/form-script-events-dto";
 export * from "./generated/generic-message-response";
+export * from "./generated/grant-app-admin-request";
 export * from "./generated/health-dependency-status";
 export * from "./generated/health-response";
 export * from "./generated/invite-request";
+export * from "./generated/legal-hold-response";
+export * from "./generated/link-contact-identity-request";
+export * from "./generated/liveness-response";
+export * from "./generated/localized-label-response";
+export * from "./generated/master-contact-response";
+export * from "./generated/migration-level-response";
+export * from "./generated/mfa-reset-request-response";
 export * from "./generated/option-set-item-dto";
 export * from "./generated/option-set-response";
+export * from "./generated/passkey-response";
+export * from "./generated/pending-email-change-response";
+export * from "./generated/pending-field-change-response";
+export * from "./generated/permission-catalog-entry-response";
+export * from "./generated/permission-catalog-group-response";
 export * from "./generated/publish-check-category-dto";
 export * from "./generated/publish-check-issue-response";
 export * from "./generated/publish-check-scope-dto";
+export * from "./generated/operator-audit-event-response";
+export * from "./generated/operator-maintenance-response";
+export * from "./generated/operator-queue-stats-response";
+export * from "./generated/operator-tenant-response";
+export * from "./generated/operator-user-lookup-response";
+export * from "./generated/operator-user-membership-response";
 export * from "./generated/publish-check-severity-dto";
 export * from "./generated/publish-field-diff-item-response";
 export * from "./generated/publish-surface-delta-item-response";
 export * from "./generated/publish-surface-diff-item-response";
 export * from "./generated/publish-checks-response";
+export * from "./generated/publish-intent-response";
+export * from "./generated/publish-lock-response";
 export * from "./generated/published-schema-response";
+export * from "./generated/published-schema-version-response";
+export * from "./generated/published-schema-version-summary-response";
 export * from "./generated/query-runtime-records-request";
+export * from "./generated/aggregate-runtime-records-request";
 export * from "./generated/revoke-temporary-access-grant-request";
+export * from "./generated/run-operator-maintenance-request";
+export * from "./generated/record-access-request-response";
+export * from "./generated/record-import-column-mapping-dto";
+export * from "./generated/record-import-row-error-response";
+export * from "./generated/record-import-schedule-response";
+export * from "./generated/record-import-summary-response";
+export * from "./generated/record-import-template-response";
+export * from "./generated/create-record-import-schedule-request";
+export * from "./generated/save-record-import-template-request";
+export * from "./generated/update-record-import-schedule-request";
+export * from "./generated/report-subscription-response";
+export * from "./generated/reporting-projection-column-request";
+export * from "./generated/reporting-projection-column-response";
+export * from "./generated/reporting-projection-filter-request";
+export * from "./generated/reporting-projection-response";
+export * from "./generated/reporting-projection-row-response";
+export * from "./generated/query-reporting-projection-request";
+export * from "./generated/save-reporting-projection-request";
+export * from "./generated/tenant-data-export-link-response";
+export * from "./generated/record-contact-consent-request";
+export * from "./generated/record-share-link-response";
+export * from "./generated/record-share-link-view-response";
+export * from "./generated/record-share-response";
+export * from "./generated/request-record-access-request";
 export * from "./generated/remove-role-assignment-request";
+export * from "./generated/rename-passkey-request";
+export * from "./generated/release-advisory-response";
 export * from "./generated/role-assignment-response";
 export * from "./generated/role-response";
+export * from "./generated/runtime-record-owner-response";
 export * from "./generated/runtime-record-response";
 export * from "./generated/runtime-field-permission-input-request";
 export * from "./generated/runtime-field-permission-response";
@@ -72,24 +201,56 @@ export * from "./generated/runtime-record-query-filter-request";
 export * from "./generated/runtime-record-query-group-request";
 export * from "./generated/runtime-record-query-link-entity-request";
 export * from "./generated/runtime-record-query-sort-request";
+export * from "./generated/runtime-record-aggregate-request";
+export * from "./generated/runtime-record-aggregate-having-request";
+export * from "./generated/runtime-record-aggregate-sort-request";
+export * from "./generated/runtime-record-aggregate-row-response";
 export * from "./generated/run-workspace-publish-request";
 export * from "./generated/run-workspace-publish-response";
+export * from "./generated/queue-publish-intent-request";
+export * from "./generated/queue-data-validation-audit-request";
+export * from "./generated/queue-qrywell-sync-job-request";
+export * from "./generated/reviewed-draft-fingerprint-dto";
+export * from "./generated/save-announcement-request";
+export * from "./generated/save-compliance-zone-tag-request";
+export * from "./generated/save-contact-identity-source-request";
+export * from "./generated/save-data-validation-schedule-request";
+export * from "./generated/save-data-erasure-fields-request";
+export * from "./generated/save-dual-control-fields-request";
+export * from "./generated/save-feature-flag-request";
+export * from "./generated/save-field-masking-rules-request";
+export * from "./generated/save-localized-label-request";
 export * from "./generated/save-runtime-field-permissions-request";
 export * from "./generated/save-app-role-entity-permission-request";
 export * from "./generated/save-app-sitemap-request";
+export * from "./generated/save-personal-view-request";
 export * from "./generated/save-workflow-request";
+export * from "./generated/security-team-member-response";
+export * from "./generated/security-team-response";
+export * from "./generated/shred-tenant-encryption-keys-request";
+export * from "./generated/shred-tenant-encryption-keys-response";
 export * from "./generated/temporary-access-grant-response";
 export * from "./generated/update-runtime-record-request";
 export * from "./generated/update-entity-request";
 export * from "./generated/update-field-request";
+export * from "./generated/update-audit-export-sink-request";
 export * from "./generated/update-audit-retention-policy-request";
+export * from "./generated/tenant-encryption-key-request";
+export * from "./generated/tenant-encryption-key-response";
 export * from "./generated/tenant-registration-mode-response";
 export * from "./generated/tenant-option-response";
 export * from "./generated/update-tenant-registration-mode-request";
+export * from "./generated/update-workflow-concurrency-policy-request";
 export * from "./generated/user-identity-response";
+export * from "./generated/version-response";
+export * from "./generated/api-versions-response";
+export * from "./generated/api-version-descriptor-response";
 export * from "./generated/view-response";
 export * from "./generated/workflow-response";
 export * from "./generated/workspace-dashboard-response";
+export * from "./generated/personal-view-response";
+export * from "./generated/workspace-entity-schema-response";
+export * from "./generated/workspace-form-script-events-response";
 export * from "./generated/workspace-publish-checks-response";
 export * from "./generated/workspace-publish-diff-request";
 export * from "./generated/workspace-publish-diff-response";
@@ -99,8 +260,20 @@ export * from "./generated/app-publish-diff-response";
 export * from "./generated/app-binding-diff-response";
 export * from "./generated/workflow-publish-diff-response";
 export * from "./generated/workflow-condition-operator-dto";
+export * from "./generated/workflow-http-retry-policy-dto";
+export * from "./generated/workflow-invocation-mode-dto";
+export * from "./generated/workflow-step-backoff-strategy-dto";
+export * from "./generated/workflow-step-retry-error-class-dto";
+export * from "./generated/workflow-step-retry-policy-dto";
 export * from "./generated/workflow-step-dto";
 export * from "./generated/workflow-run-response";
+export * from "./generated/workflow-bulk-execution-request";
+export * from "./generated/workflow-bulk-execution-preview-response";
+export * from "./generated/workflow-bulk-execution-response";
+export * from "./generated/workflow-queue-stats-response";
+export * from "./generated/workflow-stuck-job-response";
+export * from "./generated/fail-workflow-job-request";
+export * from "./generated/workflow-worker-lease-response";
 export * from "./generated/workflow-run-attempt-response";
 export * from "./generated/workflow-run-step-trace-response";
 export * from "./generated/workflow-run-replay-response";
@@ -120,3 +293,24 @@ export * from "./generated/qrywell-sync-failed-job-response";
 export * from "./generated/qrywell-sync-health-response";
 export * from "./generated/qrywell-sync-request";
 export * from "./generated/qrywell-sync-response";
+export * from "./generated/relation-behavior-response";
+export * from "./generated/relation-cascade-response";
+export * from "./generated/save-relation-behavior-request";
+export * from "./generated/relation-lookup-config-response";
+export * from "./generated/relation-lookup-match-response";
+export * from "./generated/save-relation-lookup-config-request";
+export * from "./generated/entity-slug-config-response";
+export * from "./generated/runtime-record-slug-response";
+export * from "./generated/save-entity-slug-config-request";
+export * from "./generated/entity-status-model-response";
+export * from "./generated/record-status-option-dto";
+export * from "./generated/record-status-transition-dto";
+export * from "./generated/runtime-record-status-change-response";
+export * from "./generated/save-entity-status-model-request";
+export * from "./generated/entity-history-policy-response";
+export * from "./generated/runtime-record-field-change-response";
+export * from "./generated/runtime-record-history-entry-response";
+export * from "./generated/save-entity-history-policy-request";
+export * from "./generated/duplicate-match-field-dto";
+export * from "./generated/duplicate-rule-response";
+export * from "./generated/save-duplicate-rule-request";